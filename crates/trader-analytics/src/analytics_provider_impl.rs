@@ -16,12 +16,14 @@ use async_trait::async_trait;
 use tracing::{debug, warn};
 
 use trader_core::domain::{
-    AnalyticsError, AnalyticsProvider, GlobalScoreResult, MacroEnvironment, MarketBreadth,
-    MarketRegime, RouteState, ScreeningPreset, ScreeningResult, StructuralFeatures,
+    crypto_base_asset, AnalyticsError, AnalyticsProvider, CashRateSeries, CryptoMetrics,
+    GlobalScoreResult, MacroEnvironment, MacroRegime, MarketBreadth, MarketRegime, RouteState,
+    ScreeningPreset, ScreeningResult, StructuralFeatures, SymbolRiskMetrics,
 };
 use trader_core::types::MarketType;
 use trader_core::Timeframe;
 use trader_data::cache::CachedHistoricalDataProvider;
+use trader_data::{MacroSeriesStore, SymbolRiskStore};
 
 use crate::{
     GlobalScorer, MarketRegimeCalculator, RouteStateCalculator, StructuralFeaturesCalculator,
@@ -296,9 +298,23 @@ impl AnalyticsProvider for AnalyticsProviderImpl {
     ) -> Result<HashMap<String, MarketRegime>, AnalyticsError> {
         let mut results = HashMap::new();
 
+        // 암호화폐 종목이 있으면 온체인/파생상품 지표로 레짐 보정
+        let crypto_metrics = if tickers.iter().any(|t| crypto_base_asset(t) != *t) {
+            self.fetch_crypto_metrics().await.unwrap_or_else(|e| {
+                warn!(error = %e, "Failed to load crypto metrics for MarketRegime");
+                HashMap::new()
+            })
+        } else {
+            HashMap::new()
+        };
+
         for ticker in tickers {
+            let metrics = crypto_metrics.get(crypto_base_asset(ticker));
             match self.get_candles(ticker, 80).await {
-                Ok(candles) => match self.market_regime_calc.calculate(&candles) {
+                Ok(candles) => match self
+                    .market_regime_calc
+                    .calculate_with_crypto_metrics(&candles, metrics)
+                {
                     Ok(regime_result) => {
                         results.insert(ticker.to_string(), regime_result.regime);
                     }
@@ -328,11 +344,41 @@ impl AnalyticsProvider for AnalyticsProviderImpl {
         debug!("fetch_market_breadth called (not yet implemented)");
         Ok(MarketBreadth::default())
     }
+
+    async fn fetch_macro_regime(&self) -> Result<Option<MacroRegime>, AnalyticsError> {
+        MacroSeriesStore::new(self.data_provider.pool().clone())
+            .latest_macro_regime()
+            .await
+            .map_err(|e| AnalyticsError::DataFetch(e.to_string()))
+    }
+
+    async fn fetch_crypto_metrics(&self) -> Result<HashMap<String, CryptoMetrics>, AnalyticsError> {
+        MacroSeriesStore::new(self.data_provider.pool().clone())
+            .latest_crypto_metrics()
+            .await
+            .map_err(|e| AnalyticsError::DataFetch(e.to_string()))
+    }
+
+    async fn fetch_cash_yields(&self) -> Result<HashMap<CashRateSeries, f64>, AnalyticsError> {
+        MacroSeriesStore::new(self.data_provider.pool().clone())
+            .latest_cash_yields()
+            .await
+            .map_err(|e| AnalyticsError::DataFetch(e.to_string()))
+    }
+
+    async fn fetch_symbol_risk(
+        &self,
+        tickers: &[&str],
+    ) -> Result<HashMap<String, SymbolRiskMetrics>, AnalyticsError> {
+        SymbolRiskStore::new(self.data_provider.pool().clone())
+            .load(tickers)
+            .await
+            .map_err(|e| AnalyticsError::DataFetch(e.to_string()))
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::*;
 
     // 테스트는 실제 DB 연결이 필요하므로 integration test로 이동
 }
@@ -0,0 +1,591 @@
+//! 백테스트 데이터 커버리지 리포트
+//!
+//! 백테스트 실행 전에 심볼별 데이터 가용 구간, 누락 구간(갭), 데이터 출처 구성,
+//! 수정주가 여부를 집계하고, 커버리지가 기준에 못 미치거나 합성 데이터가 섞여 있으면
+//! 기본적으로 실행을 거부합니다.
+//!
+//! - 일봉/분봉은 평일 기준으로 캔들이 있는 거래일 수를 셉니다 (휴장일만큼 100%보다 낮음).
+//! - 3일봉/주봉/월봉은 기간 길이로 나눈 기대 캔들 수와 비교합니다.
+//! - 합성 데이터(샘플 생성기, 레버리지 ETF 상장 이전 합성)는 `allow_synthetic`일 때만 허용합니다.
+
+use chrono::{Datelike, NaiveDate, Weekday};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use trader_core::{Kline, Timeframe};
+
+use super::engine::{BacktestError, BacktestResult};
+
+/// 기본 최소 커버리지 (%).
+pub const DEFAULT_MIN_COVERAGE_PCT: f64 = 90.0;
+
+/// 갭으로 보지 않는 최대 연속 휴장 평일 수 (명절 연휴).
+const MAX_HOLIDAY_WEEKDAYS: i64 = 3;
+
+/// 캔들 데이터 출처.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KlineSource {
+    /// 저장된 시장 데이터 (ohlcv 캐시)
+    Stored,
+    /// 샘플 생성기 데이터 (데모용)
+    Synthetic,
+    /// 레버리지 ETF 상장 이전 구간 합성
+    LeverageBackfill,
+}
+
+impl KlineSource {
+    /// 합성 데이터 여부.
+    pub fn is_synthetic(self) -> bool {
+        !matches!(self, Self::Stored)
+    }
+}
+
+/// 누락 구간.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CoverageGap {
+    /// 누락 시작일
+    pub start: NaiveDate,
+    /// 누락 종료일
+    pub end: NaiveDate,
+    /// 누락된 기대 캔들 수 (일봉 기준 평일 수)
+    pub missing_periods: i64,
+}
+
+/// 심볼별 데이터 커버리지.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SymbolCoverage {
+    /// 심볼
+    pub symbol: String,
+    /// 첫 캔들 날짜
+    pub first_date: Option<NaiveDate>,
+    /// 마지막 캔들 날짜
+    pub last_date: Option<NaiveDate>,
+    /// 요청 구간 내 캔들 수
+    pub bars: usize,
+    /// 요청 구간의 기대 캔들 수
+    pub expected_periods: i64,
+    /// 커버리지 (%, 0 ~ 100)
+    pub coverage_pct: f64,
+    /// 누락 구간 (요청 구간 앞/뒤 포함)
+    pub gaps: Vec<CoverageGap>,
+    /// 출처별 캔들 수
+    pub sources: BTreeMap<KlineSource, usize>,
+    /// 수정주가(분할/배당 조정) 여부
+    pub adjusted: bool,
+}
+
+impl SymbolCoverage {
+    /// 캔들로 커버리지 계산 (모든 캔들을 `source` 출처로 집계).
+    pub fn compute(
+        symbol: impl Into<String>,
+        klines: &[Kline],
+        timeframe: Timeframe,
+        start: NaiveDate,
+        end: NaiveDate,
+        source: KlineSource,
+    ) -> Self {
+        let dates: BTreeSet<NaiveDate> = klines
+            .iter()
+            .map(|k| k.open_time.date_naive())
+            .filter(|d| *d >= start && *d <= end)
+            .collect();
+        let bars = klines
+            .iter()
+            .filter(|k| (start..=end).contains(&k.open_time.date_naive()))
+            .count();
+
+        let (expected_periods, covered, gaps) = match period_days(timeframe) {
+            None => {
+                let expected = weekdays_between(start, end);
+                (
+                    expected,
+                    dates.len() as i64,
+                    weekday_gaps(&dates, start, end),
+                )
+            }
+            Some(days) => {
+                let expected = ((end - start).num_days() + 1 + days - 1) / days;
+                (
+                    expected,
+                    dates.len() as i64,
+                    calendar_gaps(&dates, start, end, days),
+                )
+            }
+        };
+        let coverage_pct = if expected_periods > 0 {
+            (covered as f64 / expected_periods as f64 * 100.0).min(100.0)
+        } else {
+            0.0
+        };
+
+        let mut sources = BTreeMap::new();
+        if bars > 0 {
+            sources.insert(source, bars);
+        }
+
+        Self {
+            symbol: symbol.into(),
+            first_date: dates.first().copied(),
+            last_date: dates.last().copied(),
+            bars,
+            expected_periods,
+            coverage_pct,
+            gaps,
+            sources,
+            adjusted: false,
+        }
+    }
+
+    /// 수정주가 여부 설정.
+    pub fn with_adjusted(mut self, adjusted: bool) -> Self {
+        self.adjusted = adjusted;
+        self
+    }
+
+    /// 주 출처 캔들 중 `count`개를 다른 출처로 재분류 (예: 레버리지 합성 구간).
+    pub fn reclassify(mut self, from: KlineSource, to: KlineSource, count: usize) -> Self {
+        let moved = count.min(self.sources.get(&from).copied().unwrap_or(0));
+        if moved > 0 {
+            if let Some(n) = self.sources.get_mut(&from) {
+                *n -= moved;
+                if *n == 0 {
+                    self.sources.remove(&from);
+                }
+            }
+            *self.sources.entry(to).or_default() += moved;
+        }
+        self
+    }
+
+    /// 합성 캔들 수.
+    pub fn synthetic_bars(&self) -> usize {
+        self.sources
+            .iter()
+            .filter(|(source, _)| source.is_synthetic())
+            .map(|(_, n)| n)
+            .sum()
+    }
+}
+
+/// 백테스트 실행 단위의 데이터 커버리지 리포트.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DataCoverageReport {
+    /// 타임프레임
+    pub timeframe: Timeframe,
+    /// 요청 시작일
+    pub start: NaiveDate,
+    /// 요청 종료일
+    pub end: NaiveDate,
+    /// 심볼별 커버리지
+    pub symbols: Vec<SymbolCoverage>,
+}
+
+impl DataCoverageReport {
+    /// 새 리포트 생성.
+    pub fn new(timeframe: Timeframe, start: NaiveDate, end: NaiveDate) -> Self {
+        Self {
+            timeframe,
+            start,
+            end,
+            symbols: Vec::new(),
+        }
+    }
+
+    /// 심볼 커버리지 추가.
+    pub fn push(&mut self, coverage: SymbolCoverage) {
+        self.symbols.push(coverage);
+    }
+
+    /// 가장 낮은 심볼 커버리지 (%). 심볼이 없으면 0.
+    pub fn min_coverage_pct(&self) -> f64 {
+        self.symbols
+            .iter()
+            .map(|s| s.coverage_pct)
+            .min_by(|a, b| a.total_cmp(b))
+            .unwrap_or(0.0)
+    }
+
+    /// 합성 데이터 사용 여부.
+    pub fn uses_synthetic(&self) -> bool {
+        self.symbols.iter().any(|s| s.synthetic_bars() > 0)
+    }
+
+    /// 정책 위반 목록 (비어 있으면 실행 가능).
+    pub fn violations(&self, policy: &CoveragePolicy) -> Vec<String> {
+        let mut violations = Vec::new();
+        if self.symbols.is_empty() {
+            violations.push("커버리지를 계산할 심볼이 없습니다".to_string());
+        }
+        for symbol in &self.symbols {
+            if symbol.coverage_pct < policy.min_coverage_pct {
+                violations.push(format!(
+                    "{} 커버리지 {:.1}% < 최소 {:.1}% ({}건 / 기대 {}건)",
+                    symbol.symbol,
+                    symbol.coverage_pct,
+                    policy.min_coverage_pct,
+                    symbol.bars,
+                    symbol.expected_periods
+                ));
+            }
+            let rejected: usize = symbol
+                .sources
+                .iter()
+                .filter(|(source, _)| !policy.permits(**source))
+                .map(|(_, n)| n)
+                .sum();
+            if rejected > 0 {
+                violations.push(format!(
+                    "{} 합성 데이터 {}건 포함 (allow_synthetic 필요)",
+                    symbol.symbol, rejected
+                ));
+            }
+        }
+        violations
+    }
+
+    /// 정책 검사 (위반 시 `BacktestError::DataError`).
+    pub fn check(&self, policy: &CoveragePolicy) -> BacktestResult<()> {
+        let violations = self.violations(policy);
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(BacktestError::DataError(format!(
+                "데이터 커버리지 부족: {}",
+                violations.join("; ")
+            )))
+        }
+    }
+}
+
+/// 커버리지 정책.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CoveragePolicy {
+    /// 심볼별 최소 커버리지 (%)
+    #[serde(default = "default_min_coverage_pct")]
+    pub min_coverage_pct: f64,
+    /// 합성 데이터 허용 여부 (데모용)
+    #[serde(default)]
+    pub allow_synthetic: bool,
+    /// 레버리지 ETF 상장 이전 구간 합성 허용 여부 (합성 모드를 명시적으로 켠 경우)
+    #[serde(default)]
+    pub allow_leverage_backfill: bool,
+}
+
+impl CoveragePolicy {
+    /// 해당 출처의 캔들 사용 허용 여부.
+    pub fn permits(&self, source: KlineSource) -> bool {
+        match source {
+            KlineSource::Stored => true,
+            KlineSource::Synthetic => self.allow_synthetic,
+            KlineSource::LeverageBackfill => self.allow_synthetic || self.allow_leverage_backfill,
+        }
+    }
+}
+
+fn default_min_coverage_pct() -> f64 {
+    DEFAULT_MIN_COVERAGE_PCT
+}
+
+impl Default for CoveragePolicy {
+    fn default() -> Self {
+        Self {
+            min_coverage_pct: DEFAULT_MIN_COVERAGE_PCT,
+            allow_synthetic: false,
+            allow_leverage_backfill: false,
+        }
+    }
+}
+
+/// 평일 단위가 아닌 타임프레임의 기간 길이 (일). 일봉 이하는 None.
+fn period_days(timeframe: Timeframe) -> Option<i64> {
+    match timeframe {
+        Timeframe::D3 => Some(3),
+        Timeframe::W1 => Some(7),
+        Timeframe::MN1 => Some(30),
+        _ => None,
+    }
+}
+
+fn is_weekday(date: NaiveDate) -> bool {
+    !matches!(date.weekday(), Weekday::Sat | Weekday::Sun)
+}
+
+/// 구간 [from, to]의 평일 수.
+fn weekdays_between(from: NaiveDate, to: NaiveDate) -> i64 {
+    if to < from {
+        return 0;
+    }
+    from.iter_days()
+        .take_while(|d| *d <= to)
+        .filter(|d| is_weekday(*d))
+        .count() as i64
+}
+
+/// 평일 기준 누락 구간 (연휴 길이 이하는 제외).
+fn weekday_gaps(dates: &BTreeSet<NaiveDate>, start: NaiveDate, end: NaiveDate) -> Vec<CoverageGap> {
+    let mut gaps = Vec::new();
+    let mut push = |from: NaiveDate, to: NaiveDate, allow_holiday: bool| {
+        let missing = weekdays_between(from, to);
+        if missing > 0 && (!allow_holiday || missing > MAX_HOLIDAY_WEEKDAYS) {
+            gaps.push(CoverageGap {
+                start: from,
+                end: to,
+                missing_periods: missing,
+            });
+        }
+    };
+
+    let (Some(&first), Some(&last)) = (dates.first(), dates.last()) else {
+        push(start, end, false);
+        return gaps;
+    };
+    if let Some(before) = first.pred_opt() {
+        push(start, before, true);
+    }
+    for (prev, next) in dates.iter().zip(dates.iter().skip(1)) {
+        if let (Some(from), Some(to)) = (prev.succ_opt(), next.pred_opt()) {
+            push(from, to, true);
+        }
+    }
+    if let Some(after) = last.succ_opt() {
+        push(after, end, true);
+    }
+    gaps
+}
+
+/// 고정 기간 기준 누락 구간 (캔들 간격이 기간의 2배를 넘으면 갭).
+fn calendar_gaps(
+    dates: &BTreeSet<NaiveDate>,
+    start: NaiveDate,
+    end: NaiveDate,
+    period: i64,
+) -> Vec<CoverageGap> {
+    let mut gaps = Vec::new();
+    let mut bounds: Vec<NaiveDate> = Vec::with_capacity(dates.len() + 2);
+    bounds.push(start - chrono::Duration::days(period));
+    bounds.extend(dates.iter().copied());
+    bounds.push(end + chrono::Duration::days(period));
+
+    for pair in bounds.windows(2) {
+        let span = (pair[1] - pair[0]).num_days();
+        if span >= period * 2 {
+            gaps.push(CoverageGap {
+                start: (pair[0] + chrono::Duration::days(period)).max(start),
+                end: (pair[1] - chrono::Duration::days(1)).min(end),
+                missing_periods: span / period - 1,
+            });
+        }
+    }
+    gaps
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone, Utc};
+    use rust_decimal_macros::dec;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn daily(dates: &[NaiveDate]) -> Vec<Kline> {
+        dates
+            .iter()
+            .map(|d| {
+                let open = Utc.from_utc_datetime(&d.and_hms_opt(0, 0, 0).unwrap());
+                Kline::new(
+                    "005930".to_string(),
+                    Timeframe::D1,
+                    open,
+                    dec!(100),
+                    dec!(100),
+                    dec!(100),
+                    dec!(100),
+                    dec!(1000),
+                    open + Duration::days(1),
+                )
+            })
+            .collect()
+    }
+
+    fn weekdays(from: NaiveDate, to: NaiveDate) -> Vec<NaiveDate> {
+        from.iter_days()
+            .take_while(|d| *d <= to)
+            .filter(|d| is_weekday(*d))
+            .collect()
+    }
+
+    #[test]
+    fn test_full_daily_coverage() {
+        // 2026-03-02(월) ~ 2026-03-27(금): 평일 20일
+        let (start, end) = (date(2026, 3, 2), date(2026, 3, 27));
+        let klines = daily(&weekdays(start, end));
+        let coverage = SymbolCoverage::compute(
+            "005930",
+            &klines,
+            Timeframe::D1,
+            start,
+            end,
+            KlineSource::Stored,
+        );
+
+        assert_eq!(coverage.expected_periods, 20);
+        assert_eq!(coverage.bars, 20);
+        assert_eq!(coverage.coverage_pct, 100.0);
+        assert!(coverage.gaps.is_empty());
+        assert_eq!(coverage.first_date, Some(start));
+        assert_eq!(coverage.sources.get(&KlineSource::Stored), Some(&20));
+    }
+
+    #[test]
+    fn test_daily_gaps_and_late_start() {
+        let (start, end) = (date(2026, 3, 2), date(2026, 3, 27));
+        // 첫 주 누락(5일, 앞쪽 갭), 3/18 하루 휴장(갭 아님)
+        let dates: Vec<NaiveDate> = weekdays(date(2026, 3, 9), end)
+            .into_iter()
+            .filter(|d| *d != date(2026, 3, 18))
+            .collect();
+        let coverage = SymbolCoverage::compute(
+            "005930",
+            &daily(&dates),
+            Timeframe::D1,
+            start,
+            end,
+            KlineSource::Stored,
+        );
+
+        assert_eq!(coverage.bars, 14);
+        assert!((coverage.coverage_pct - 70.0).abs() < 1e-9);
+        assert_eq!(
+            coverage.gaps,
+            vec![CoverageGap {
+                start: date(2026, 3, 2),
+                end: date(2026, 3, 8),
+                missing_periods: 5,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_coverage_policy() {
+        let (start, end) = (date(2026, 3, 2), date(2026, 3, 27));
+        let full = daily(&weekdays(start, end));
+        let half = daily(&weekdays(date(2026, 3, 16), end));
+
+        let mut report = DataCoverageReport::new(Timeframe::D1, start, end);
+        report.push(SymbolCoverage::compute(
+            "SPY",
+            &full,
+            Timeframe::D1,
+            start,
+            end,
+            KlineSource::Stored,
+        ));
+        assert!(report.check(&CoveragePolicy::default()).is_ok());
+
+        report.push(SymbolCoverage::compute(
+            "TQQQ",
+            &half,
+            Timeframe::D1,
+            start,
+            end,
+            KlineSource::Stored,
+        ));
+        assert_eq!(report.min_coverage_pct(), 50.0);
+        let violations = report.violations(&CoveragePolicy::default());
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].starts_with("TQQQ"));
+        assert!(report.check(&CoveragePolicy::default()).is_err());
+
+        // 낮은 기준이면 통과
+        let lenient = CoveragePolicy {
+            min_coverage_pct: 40.0,
+            ..Default::default()
+        };
+        assert!(report.check(&lenient).is_ok());
+    }
+
+    #[test]
+    fn test_synthetic_requires_opt_in() {
+        let (start, end) = (date(2026, 3, 2), date(2026, 3, 27));
+        let klines = daily(&weekdays(start, end));
+
+        let mut report = DataCoverageReport::new(Timeframe::D1, start, end);
+        report.push(
+            SymbolCoverage::compute(
+                "TQQQ",
+                &klines,
+                Timeframe::D1,
+                start,
+                end,
+                KlineSource::Stored,
+            )
+            .reclassify(KlineSource::Stored, KlineSource::LeverageBackfill, 5),
+        );
+        let symbol = &report.symbols[0];
+        assert_eq!(symbol.synthetic_bars(), 5);
+        assert_eq!(symbol.sources.get(&KlineSource::Stored), Some(&15));
+        assert!(report.uses_synthetic());
+
+        assert!(report.check(&CoveragePolicy::default()).is_err());
+        let demo = CoveragePolicy {
+            allow_synthetic: true,
+            ..Default::default()
+        };
+        assert!(report.check(&demo).is_ok());
+        let leverage = CoveragePolicy {
+            allow_leverage_backfill: true,
+            ..Default::default()
+        };
+        assert!(report.check(&leverage).is_ok());
+
+        // 샘플 데이터는 레버리지 합성 허용만으로는 통과하지 못함
+        let mut sample = DataCoverageReport::new(Timeframe::D1, start, end);
+        sample.push(SymbolCoverage::compute(
+            "SPY",
+            &klines,
+            Timeframe::D1,
+            start,
+            end,
+            KlineSource::Synthetic,
+        ));
+        assert!(sample.check(&leverage).is_err());
+        assert!(sample.check(&demo).is_ok());
+    }
+
+    #[test]
+    fn test_weekly_coverage() {
+        // 8주 중 3주 연속 누락
+        let start = date(2026, 1, 5);
+        let end = start + Duration::days(7 * 8 - 1);
+        let dates: Vec<NaiveDate> = (0..8)
+            .filter(|w| !(3..6).contains(w))
+            .map(|w| start + Duration::days(7 * w))
+            .collect();
+        let coverage = SymbolCoverage::compute(
+            "SPY",
+            &daily(&dates),
+            Timeframe::W1,
+            start,
+            end,
+            KlineSource::Stored,
+        );
+
+        assert_eq!(coverage.expected_periods, 8);
+        assert!((coverage.coverage_pct - 62.5).abs() < 1e-9);
+        assert_eq!(coverage.gaps.len(), 1);
+        assert_eq!(coverage.gaps[0].start, date(2026, 1, 26));
+        assert_eq!(coverage.gaps[0].missing_periods, 3);
+    }
+
+    #[test]
+    fn test_empty_symbol() {
+        let (start, end) = (date(2026, 3, 2), date(2026, 3, 6));
+        let coverage =
+            SymbolCoverage::compute("X", &[], Timeframe::D1, start, end, KlineSource::Stored);
+        assert_eq!(coverage.coverage_pct, 0.0);
+        assert!(coverage.sources.is_empty());
+        assert_eq!(coverage.gaps[0].missing_periods, 5);
+    }
+}
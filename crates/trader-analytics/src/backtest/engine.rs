@@ -6,6 +6,7 @@
 //!
 //! - **전략 시뮬레이션**: 과거 시장 데이터로 전략의 신호 생성 및 실행
 //! - **주문 체결 시뮬레이션**: 슬리피지, 수수료 등 현실적인 체결 모델
+//! - **마진 계좌**: 공매도, 신용 매수, 차입 비용, 유지 증거금 미달 시 강제 청산
 //! - **성과 분석**: PerformanceTracker와 통합된 상세한 성과 지표
 //! - **자산 곡선**: 시간에 따른 자산 가치 변화 추적
 //!
@@ -33,17 +34,24 @@
 //! println!("최대 낙폭: {}%", result.metrics.max_drawdown_pct);
 //! ```
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use rust_decimal::prelude::FromPrimitive;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::Arc;
 use thiserror::Error;
+use tokio::sync::RwLock;
+use trader_core::domain::StrategyContext;
 use trader_core::{
-    unrealized_pnl, Kline, MarketData, Side, Signal, SignalMarker, SignalType, Trade,
+    CashYieldCurve, Kline, Liquidity, MarketData, Side, Signal, SignalCalibrator, SignalMarker,
+    SignalType, Trade, TradeCost, TradingCostModel, TradingVolumeWindow,
 };
 use uuid::Uuid;
 
+use crate::backtest::margin::MarginConfig;
+use crate::backtest::screening::ScreeningSnapshots;
+use crate::backtest::session::IntradaySession;
 use crate::backtest::slippage::SlippageModel;
 use crate::performance::{EquityPoint, PerformanceMetrics, PerformanceTracker, RoundTrip};
 
@@ -85,9 +93,17 @@ pub struct BacktestConfig {
     pub initial_capital: Decimal,
 
     /// 거래 수수료율 (예: 0.001 = 0.1%)
+    ///
+    /// 참고: cost_model이 설정되면 무시됩니다.
     #[serde(default = "default_commission_rate")]
     pub commission_rate: Decimal,
 
+    /// 시장별 거래 비용 모델 (Optional)
+    ///
+    /// 설정되면 commission_rate 대신 시장별 수수료와 매도 세금을 적용합니다.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cost_model: Option<TradingCostModel>,
+
     /// 슬리피지율 (예: 0.0005 = 0.05%)
     ///
     /// 참고: slippage_model이 설정되면 무시됩니다.
@@ -121,13 +137,45 @@ pub struct BacktestConfig {
     #[serde(default)]
     pub use_tick_simulation: bool,
 
-    /// 마진 거래 허용 여부
+    /// 마진 거래 허용 여부 (꺼져 있으면 개시 증거금률 100%, 신용 없음)
     #[serde(default)]
     pub allow_margin: bool,
 
     /// 숏 포지션 허용 여부
     #[serde(default)]
     pub allow_short: bool,
+
+    /// 마진 계좌 설정 (증거금률, 대주/신용 이자율)
+    ///
+    /// 공매도의 차입 비용과 유지 증거금은 `allow_margin`과 관계없이 적용됩니다.
+    #[serde(default)]
+    pub margin: MarginConfig,
+
+    /// 유휴 현금 금리 곡선 (None이면 현금 이자 0%)
+    ///
+    /// 설정되면 포지션에 묶이지 않은 잔고에 일별 이자가 붙습니다 (일복리).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cash_yield: Option<CashYieldCurve>,
+
+    /// 리밸런싱 일자별 과거 스크리닝 결과 (스크리닝 기반 전략용)
+    ///
+    /// 설정되면 전략에 StrategyContext를 주입하고, 시뮬레이션 시각 기준 가장 최근
+    /// 스냅샷을 스크리닝 결과/RouteState/GlobalScore로 반영합니다.
+    #[serde(skip)]
+    pub screening: Option<ScreeningSnapshots>,
+
+    /// 장중 거래 세션 (분봉 백테스트용, None이면 모든 캔들을 그대로 시뮬레이션)
+    ///
+    /// 설정되면 정규장 밖 캔들을 건너뛰고, 신호를 다음 캔들 시가로 체결하며,
+    /// 세션 마감 시 대기 신호를 정리합니다.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub session: Option<IntradaySession>,
+
+    /// 전략별 신호 신뢰도 보정기 (None이면 원시 신호 강도로 포지션 크기 결정)
+    ///
+    /// 설정되면 진입 금액을 `signal.strength` 대신 보정된 적중 확률로 조정합니다.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signal_calibration: Option<SignalCalibrator>,
 }
 
 // 설정 기본값 함수들 (serde default용)
@@ -158,6 +206,7 @@ impl Default for BacktestConfig {
         Self {
             initial_capital: default_initial_capital(),
             commission_rate: default_commission_rate(),
+            cost_model: None,
             slippage_rate: default_slippage_rate(),
             slippage_model: None,
             max_positions: default_max_positions(),
@@ -167,6 +216,11 @@ impl Default for BacktestConfig {
             use_tick_simulation: false,
             allow_margin: false,
             allow_short: false,
+            margin: MarginConfig::default(),
+            cash_yield: None,
+            screening: None,
+            session: None,
+            signal_calibration: None,
         }
     }
 }
@@ -186,6 +240,19 @@ impl BacktestConfig {
         self
     }
 
+    /// 거래 비용 모델 설정
+    ///
+    /// 설정되면 commission_rate 대신 시장별 수수료/세금 모델을 사용합니다.
+    ///
+    /// ```rust,ignore
+    /// let config = BacktestConfig::new(dec!(10_000_000))
+    ///     .with_cost_model(TradingCostModel::kr_kospi());
+    /// ```
+    pub fn with_cost_model(mut self, model: TradingCostModel) -> Self {
+        self.cost_model = Some(model);
+        self
+    }
+
     /// 슬리피지율 설정 (고정 비율)
     ///
     /// 참고: with_slippage_model()로 동적 모델을 설정하면 무시됩니다.
@@ -243,6 +310,61 @@ impl BacktestConfig {
         self
     }
 
+    /// 마진 계좌 설정 (신용 매수와 공매도 허용)
+    ///
+    /// ```rust,ignore
+    /// // 증거금률 50% (최대 2배), 대주 이자 연 4%
+    /// let config = BacktestConfig::new(dec!(10_000_000))
+    ///     .with_margin(MarginConfig::default().with_short_borrow_rate(dec!(0.04)));
+    /// ```
+    pub fn with_margin(mut self, margin: MarginConfig) -> Self {
+        self.margin = margin;
+        self.allow_margin = true;
+        self.allow_short = true;
+        self
+    }
+
+    /// 신규 진입에 적용할 개시 증거금률 (마진 거래 미허용 시 100%)
+    fn initial_margin_rate(&self) -> Decimal {
+        if self.allow_margin {
+            self.margin.initial_margin_rate
+        } else {
+            Decimal::ONE
+        }
+    }
+
+    /// 유휴 현금 금리 곡선 설정
+    pub fn with_cash_yield(mut self, curve: CashYieldCurve) -> Self {
+        self.cash_yield = Some(curve);
+        self
+    }
+
+    /// 과거 스크리닝 스냅샷 설정
+    pub fn with_screening(mut self, snapshots: ScreeningSnapshots) -> Self {
+        self.screening = Some(snapshots);
+        self
+    }
+
+    /// 장중 거래 세션 설정 (분봉 백테스트)
+    pub fn with_session(mut self, session: IntradaySession) -> Self {
+        self.session = Some(session);
+        self
+    }
+
+    /// 신호 신뢰도 보정기 설정
+    pub fn with_signal_calibration(mut self, calibrator: SignalCalibrator) -> Self {
+        self.signal_calibration = Some(calibrator);
+        self
+    }
+
+    /// 포지션 크기에 적용할 신호 확률 (보정기가 없으면 원시 신호 강도)
+    fn sizing_probability(&self, signal: &Signal) -> f64 {
+        match &self.signal_calibration {
+            Some(calibrator) => calibrator.calibrate(signal),
+            None => signal.strength,
+        }
+    }
+
     /// 설정 검증
     pub fn validate(&self) -> BacktestResult<()> {
         if self.initial_capital <= Decimal::ZERO {
@@ -260,6 +382,13 @@ impl BacktestConfig {
                 "슬리피지율은 0 이상이어야 합니다".to_string(),
             ));
         }
+        if let Some(model) = &self.cost_model {
+            model.validate().map_err(BacktestError::ConfigError)?;
+        }
+        if let Some(session) = &self.session {
+            session.validate().map_err(BacktestError::ConfigError)?;
+        }
+        self.margin.validate().map_err(BacktestError::ConfigError)?;
         Ok(())
     }
 }
@@ -275,8 +404,7 @@ struct SimulatedPosition {
     quantity: Decimal,
     /// 평균 진입가
     entry_price: Decimal,
-    /// 총 수수료 (나중에 비용 계산에 사용 예정)
-    #[allow(dead_code)]
+    /// 남은 수량의 진입 수수료
     fees: Decimal,
     /// 진입 시각 (보고서 생성에 사용 예정)
     #[allow(dead_code)]
@@ -309,6 +437,22 @@ pub struct BacktestReport {
     /// 총 슬리피지 비용
     pub total_slippage: Decimal,
 
+    /// 총 세금 (증권거래세, 농특세, SEC fee)
+    #[serde(default)]
+    pub total_tax: Decimal,
+
+    /// 유휴 현금 이자 합계
+    #[serde(default)]
+    pub total_cash_interest: Decimal,
+
+    /// 차입 비용 합계 (대주 이자 + 신용 이자)
+    #[serde(default)]
+    pub total_borrow_cost: Decimal,
+
+    /// 유지 증거금 미달로 강제 청산된 포지션 수
+    #[serde(default)]
+    pub forced_liquidations: usize,
+
     /// 백테스트 기간 시작
     pub start_time: DateTime<Utc>,
 
@@ -352,7 +496,11 @@ impl BacktestReport {
              칼마 비율: {:.2}\n\
              ───────────────────────────────────────\n\
              총 수수료: {:.2}\n\
+             총 세금: {:.2}\n\
              총 슬리피지: {:.2}\n\
+             현금 이자: {:.2}\n\
+             차입 비용: {:.2}\n\
+             강제 청산: {}\n\
              ═══════════════════════════════════════",
             self.start_time.format("%Y-%m-%d"),
             self.end_time.format("%Y-%m-%d"),
@@ -371,7 +519,11 @@ impl BacktestReport {
             self.metrics.max_drawdown_pct,
             self.metrics.calmar_ratio,
             self.total_commission,
+            self.total_tax,
             self.total_slippage,
+            self.total_cash_interest,
+            self.total_borrow_cost,
+            self.forced_liquidations,
         )
     }
 }
@@ -398,6 +550,24 @@ pub struct BacktestEngine {
     /// 총 슬리피지
     total_slippage: Decimal,
 
+    /// 총 세금
+    total_tax: Decimal,
+
+    /// 최근 30일 거래대금 (암호화폐 수수료 구간 판정)
+    trade_volume: TradingVolumeWindow,
+
+    /// 유휴 현금 이자 합계
+    total_cash_interest: Decimal,
+
+    /// 차입 비용 합계
+    total_borrow_cost: Decimal,
+
+    /// 강제 청산 횟수
+    forced_liquidations: usize,
+
+    /// 마지막 현금 이자/차입 비용 반영일
+    last_carry_accrual: Option<NaiveDate>,
+
     /// 총 주문 수
     total_orders: usize,
 
@@ -409,6 +579,21 @@ pub struct BacktestEngine {
 
     /// 신호 마커 (차트 표시 및 분석용)
     signal_markers: Vec<SignalMarker>,
+
+    /// 스크리닝 스냅샷 주입용 전략 컨텍스트
+    context: Option<Arc<RwLock<StrategyContext>>>,
+
+    /// 마지막으로 컨텍스트에 반영한 스크리닝 기준일
+    screening_as_of: Option<NaiveDate>,
+
+    /// 장중 세션: 다음 캔들 시가 체결을 기다리는 신호
+    pending_signals: Vec<Signal>,
+
+    /// 장중 세션: 현재 세션 거래일과 마지막 세션 캔들
+    session_bar: Option<(NaiveDate, Kline)>,
+
+    /// 장중 세션: 현재 세션 마감 처리 여부
+    session_settled: bool,
 }
 
 impl BacktestEngine {
@@ -426,13 +611,54 @@ impl BacktestEngine {
             tracker,
             total_commission: Decimal::ZERO,
             total_slippage: Decimal::ZERO,
+            total_tax: Decimal::ZERO,
+            trade_volume: TradingVolumeWindow::default(),
+            total_cash_interest: Decimal::ZERO,
+            total_borrow_cost: Decimal::ZERO,
+            forced_liquidations: 0,
+            last_carry_accrual: None,
             total_orders: 0,
             current_time: Utc::now(),
             current_prices: HashMap::new(),
             signal_markers: Vec::new(),
+            context: None,
+            screening_as_of: None,
+            pending_signals: Vec::new(),
+            session_bar: None,
+            session_settled: false,
         }
     }
 
+    /// 스크리닝 스냅샷이 설정된 경우 전략에 컨텍스트 주입
+    fn attach_screening_context<S>(&mut self, strategy: &mut S)
+    where
+        S: trader_strategy::Strategy + ?Sized,
+    {
+        if self.config.screening.is_none() {
+            return;
+        }
+        let context = Arc::new(RwLock::new(StrategyContext::default()));
+        strategy.set_context(context.clone());
+        self.context = Some(context);
+        self.screening_as_of = None;
+    }
+
+    /// `date` 시점의 스크리닝 스냅샷을 컨텍스트에 반영 (기준일이 바뀐 경우만)
+    async fn sync_screening(&mut self, date: NaiveDate) {
+        let (Some(snapshots), Some(context)) = (&self.config.screening, &self.context) else {
+            return;
+        };
+        let Some((as_of, results)) = snapshots.as_of(date) else {
+            return;
+        };
+        if self.screening_as_of == Some(as_of) {
+            return;
+        }
+
+        snapshots.apply_to(results, &mut *context.write().await);
+        self.screening_as_of = Some(as_of);
+    }
+
     /// 캔들 데이터로 백테스트를 실행합니다.
     ///
     /// # 매개변수
@@ -476,15 +702,24 @@ impl BacktestEngine {
         // 백테스트 시작 시간으로 equity curve 초기 timestamp 설정
         // (Utc::now() 대신 실제 백테스트 시작 시간 사용)
         self.tracker.set_initial_timestamp(start_time);
+        self.attach_screening_context(strategy);
 
         // 각 캔들에 대해 시뮬레이션
         // 중요: Look-Ahead Bias 방지를 위해 캔들 완성 후 신호 생성
         for kline in klines {
+            // 장중 세션: 장외 캔들 제외, 대기 신호는 이번 캔들 시가로 체결
+            if !self.begin_session_bar(kline).await? {
+                continue;
+            }
+
             // 캔들 완성 시점으로 현재 시간 설정 (데이터 누수 방지)
             self.current_time = kline.close_time;
             self.current_prices
                 .insert(kline.ticker.to_string(), kline.close);
 
+            // 캔들 완성일 기준 스크리닝 스냅샷 반영
+            self.sync_screening(kline.close_time.date_naive()).await;
+
             // 시장 데이터 생성 (완성된 캔들 정보 사용)
             let market_data = MarketData::from_kline(&self.config.exchange_name, kline.clone());
 
@@ -496,8 +731,15 @@ impl BacktestEngine {
 
             // 신호 처리 (다음 틱에서 체결된다고 가정)
             for signal in signals {
-                self.process_signal(&signal, kline).await?;
+                self.dispatch_signal(signal, kline).await?;
             }
+            self.end_session_bar(kline).await?;
+
+            // 유휴 현금 이자와 차입 비용 반영 (일 단위)
+            self.accrue_daily_carry(kline.close_time.date_naive(), kline);
+
+            // 유지 증거금 미달 시 강제 청산
+            self.enforce_maintenance_margin(kline).await?;
 
             // 미실현 손익 반영하여 자산 업데이트
             let equity = self.calculate_equity(kline);
@@ -519,6 +761,10 @@ impl BacktestEngine {
             total_orders: self.total_orders,
             total_commission: self.total_commission,
             total_slippage: self.total_slippage,
+            total_tax: self.total_tax,
+            total_cash_interest: self.total_cash_interest,
+            total_borrow_cost: self.total_borrow_cost,
+            forced_liquidations: self.forced_liquidations,
             start_time,
             end_time,
             data_points,
@@ -527,6 +773,91 @@ impl BacktestEngine {
         })
     }
 
+    /// 장중 세션 캔들을 시작합니다. 세션 밖 캔들이면 false를 반환합니다.
+    ///
+    /// 세션 거래일이 바뀌면 마감 처리가 안 된 이전 세션을 마지막 캔들 기준으로 정리하고,
+    /// 남은 대기 신호는 버립니다 (오버나이트 체결 방지).
+    async fn begin_session_bar(&mut self, kline: &Kline) -> BacktestResult<bool> {
+        let Some(session) = self.config.session.clone() else {
+            return Ok(true);
+        };
+        if !session.contains(kline) {
+            return Ok(false);
+        }
+
+        let date = session.session_date(kline);
+        if let Some((last_date, last_kline)) = self.session_bar.take() {
+            if last_date != date {
+                if !self.session_settled {
+                    self.settle_session(&last_kline).await?;
+                }
+                self.pending_signals.clear();
+                self.session_settled = false;
+            }
+        }
+        self.session_bar = Some((date, kline.clone()));
+
+        // 이전 캔들 신호를 이번 캔들 시가로 체결 (같은 종목만)
+        self.current_time = kline.open_time;
+        let (ready, waiting): (Vec<_>, Vec<_>) = std::mem::take(&mut self.pending_signals)
+            .into_iter()
+            .partition(|signal| signal.ticker == kline.ticker);
+        self.pending_signals = waiting;
+        for mut signal in ready {
+            signal.suggested_price = Some(kline.open);
+            self.process_signal(&signal, kline).await?;
+        }
+
+        Ok(true)
+    }
+
+    /// 신호를 체결하거나, 장중 세션이면 다음 캔들 시가 체결을 위해 대기시킵니다.
+    async fn dispatch_signal(&mut self, signal: Signal, kline: &Kline) -> BacktestResult<()> {
+        if self.config.session.is_some() {
+            self.pending_signals.push(signal);
+            return Ok(());
+        }
+        self.process_signal(&signal, kline).await
+    }
+
+    /// 세션 마지막 캔들이면 마감 처리합니다.
+    async fn end_session_bar(&mut self, kline: &Kline) -> BacktestResult<()> {
+        let closing = self
+            .config
+            .session
+            .as_ref()
+            .is_some_and(|session| session.is_closing_bar(kline));
+        if closing && !self.session_settled {
+            self.settle_session(kline).await?;
+        }
+        Ok(())
+    }
+
+    /// 세션 마감 처리
+    ///
+    /// 대기 중인 진입 신호는 취소하고 청산 신호는 마감가로 체결합니다.
+    /// `flatten_at_close`면 남은 포지션을 모두 청산합니다.
+    async fn settle_session(&mut self, kline: &Kline) -> BacktestResult<()> {
+        for signal in std::mem::take(&mut self.pending_signals) {
+            if matches!(
+                signal.signal_type,
+                SignalType::Exit | SignalType::ReducePosition
+            ) {
+                self.process_signal(&signal, kline).await?;
+            }
+        }
+        let flatten = self
+            .config
+            .session
+            .as_ref()
+            .is_some_and(|session| session.flatten_at_close);
+        if flatten {
+            self.close_all_positions(kline).await?;
+        }
+        self.session_settled = true;
+        Ok(())
+    }
+
     /// 신호를 처리합니다.
     async fn process_signal(&mut self, signal: &Signal, kline: &Kline) -> BacktestResult<()> {
         // 실행 가격 결정 (signal.suggested_price 또는 kline.close)
@@ -599,29 +930,36 @@ impl BacktestEngine {
             Side::Sell => base_price - slippage, // 매도는 낮은 가격
         };
 
-        // 포지션 크기 계산
-        let max_amount = self.balance * self.config.max_position_size_pct;
-        let position_amount =
-            max_amount * Decimal::from_f64(signal.strength).unwrap_or(Decimal::ONE);
+        // 포지션 크기 계산 (남은 증거금으로 진입 가능한 금액 기준)
+        // 현금 계좌(증거금률 100%)에서 롱만 보유하면 잔고와 같습니다.
+        let margin_rate = self.config.initial_margin_rate();
+        let available_margin = self.available_margin(kline);
+        let max_amount = available_margin / margin_rate * self.config.max_position_size_pct;
+        let position_amount = max_amount
+            * Decimal::from_f64(self.config.sizing_probability(signal)).unwrap_or(Decimal::ONE);
 
         // Division by zero 방지
-        if execution_price <= Decimal::ZERO {
-            return Ok(()); // 유효하지 않은 가격
+        if execution_price <= Decimal::ZERO || position_amount <= Decimal::ZERO {
+            return Ok(()); // 유효하지 않은 가격 또는 증거금 소진
         }
         let quantity = position_amount / execution_price;
 
-        // 자금 확인
-        let required = position_amount;
-        if required > self.balance {
-            return Ok(()); // 자금 부족 시 무시
+        // 개시 증거금 확인
+        let required = position_amount * margin_rate;
+        if required > available_margin {
+            return Ok(()); // 증거금 부족 시 무시
         }
 
-        // 수수료 계산
-        let commission = position_amount * self.config.commission_rate;
+        // 수수료/세금 계산
+        let commission = self
+            .trade_cost(signal.side, position_amount, kline.close_time)
+            .total();
 
-        // 잔고 차감
-        self.balance -= required + commission;
-        self.total_commission += commission;
+        // 롱은 매수 대금 차감, 숏은 매도 대금 입금
+        match signal.side {
+            Side::Buy => self.balance -= position_amount + commission,
+            Side::Sell => self.balance += position_amount - commission,
+        }
         self.total_slippage += slippage * quantity;
         self.total_orders += 1;
 
@@ -648,11 +986,14 @@ impl BacktestEngine {
     }
 
     /// 포지션을 청산합니다.
+    ///
+    /// 부분 청산 지시(`Signal::scale_out`)가 있는 `ReducePosition` 신호는
+    /// 지시된 수량만 청산하고 남은 수량은 같은 평균 진입가로 유지합니다.
     async fn close_position(&mut self, signal: &Signal, kline: &Kline) -> BacktestResult<()> {
         let key = signal.ticker.clone();
 
-        let position = match self.positions.remove(&key) {
-            Some(p) => p,
+        let position = match self.positions.get(&key) {
+            Some(p) => p.clone(),
             None => return Ok(()), // 포지션 없으면 무시
         };
 
@@ -671,27 +1012,51 @@ impl BacktestEngine {
             Side::Sell => base_price + slippage, // 숏 청산은 높은 가격
         };
 
-        // 수수료 계산
-        let position_value = execution_price * position.quantity;
-        let commission = position_value * self.config.commission_rate;
+        // 청산 수량 (부분 청산 지시가 없으면 전량)
+        let close_quantity = match (signal.signal_type, signal.scale_out) {
+            (SignalType::ReducePosition, Some(scale_out)) => {
+                let equity = self.calculate_equity(kline);
+                scale_out.close_quantity(position.quantity, base_price, equity)
+            }
+            _ => position.quantity,
+        };
+        if close_quantity <= Decimal::ZERO {
+            return Ok(());
+        }
+
+        if close_quantity < position.quantity {
+            if let Some(remaining) = self.positions.get_mut(&key) {
+                remaining.fees -= position.fees * close_quantity / position.quantity;
+                remaining.quantity -= close_quantity;
+            }
+        } else {
+            self.positions.remove(&key);
+        }
+
+        // 청산 방향
+        let exit_side = position.side.opposite();
+
+        // 수수료/세금 계산
+        let position_value = execution_price * close_quantity;
+        let commission = self
+            .trade_cost(exit_side, position_value, kline.close_time)
+            .total();
 
         // PnL 계산 (디버깅용)
         let _gross_pnl = match position.side {
-            Side::Buy => (execution_price - position.entry_price) * position.quantity,
-            Side::Sell => (position.entry_price - execution_price) * position.quantity,
+            Side::Buy => (execution_price - position.entry_price) * close_quantity,
+            Side::Sell => (position.entry_price - execution_price) * close_quantity,
         };
 
-        // 잔고 업데이트
-        self.balance += position_value - commission;
-        self.total_commission += commission;
-        self.total_slippage += slippage * position.quantity;
+        // 롱은 매도 대금 입금, 숏은 상환 대금 차감
+        match position.side {
+            Side::Buy => self.balance += position_value - commission,
+            Side::Sell => self.balance -= position_value + commission,
+        }
+        self.total_slippage += slippage * close_quantity;
         self.total_orders += 1;
 
         // 청산 거래 기록
-        let exit_side = match position.side {
-            Side::Buy => Side::Sell,
-            Side::Sell => Side::Buy,
-        };
 
         let trade = Trade::new(
             Uuid::new_v4(),
@@ -699,7 +1064,7 @@ impl BacktestEngine {
             Uuid::new_v4().to_string(),
             signal.ticker.clone(),
             exit_side,
-            position.quantity,
+            close_quantity,
             execution_price,
         )
         .with_fee(commission, "USDT")
@@ -712,6 +1077,30 @@ impl BacktestEngine {
         Ok(())
     }
 
+    /// 체결 비용을 계산하고 누적합니다.
+    ///
+    /// 비용 모델이 없으면 commission_rate 단일 요율을 사용합니다.
+    /// 백테스트 체결은 시장가로 간주하여 Taker 요율을 적용합니다.
+    fn trade_cost(&mut self, side: Side, notional: Decimal, at: DateTime<Utc>) -> TradeCost {
+        let cost = match &self.config.cost_model {
+            Some(model) => {
+                let volume = self.trade_volume.volume(at);
+                model.trade_cost(side, notional, Liquidity::Taker, volume)
+            }
+            None => TradingCostModel::flat(self.config.commission_rate).trade_cost(
+                side,
+                notional,
+                Liquidity::Taker,
+                Decimal::ZERO,
+            ),
+        };
+        self.trade_volume.record(at, notional);
+
+        self.total_commission += cost.commission;
+        self.total_tax += cost.tax;
+        cost
+    }
+
     /// 모든 포지션을 청산합니다.
     async fn close_all_positions(&mut self, kline: &Kline) -> BacktestResult<()> {
         let positions: Vec<_> = self.positions.keys().cloned().collect();
@@ -733,36 +1122,120 @@ impl BacktestEngine {
         Ok(())
     }
 
-    /// 현재 자산 가치를 계산합니다.
-    fn calculate_equity(&self, kline: &Kline) -> Decimal {
-        let mut equity = self.balance;
-
-        for position in self.positions.values() {
-            let current_price = self
-                .current_prices
-                .get(&position.symbol)
-                .copied()
-                .unwrap_or(kline.close);
-
-            let position_value = match position.side {
-                Side::Buy => current_price * position.quantity,
-                Side::Sell => {
-                    // 숏 포지션: 원금 + 미실현 손익
-                    let entry_value = position.entry_price * position.quantity;
-                    let pnl = unrealized_pnl(
-                        position.entry_price,
-                        current_price,
-                        position.quantity,
-                        position.side,
-                    );
-                    entry_value + pnl
-                }
+    /// 마지막 반영일 이후 유휴 현금 이자와 차입 비용을 잔고에 반영합니다.
+    ///
+    /// 첫 캔들에서는 기준일만 기록하며, 같은 날의 캔들이 여러 개여도 하루 한 번만 반영합니다.
+    /// 현금 이자는 담보를 제외한 양(+)의 현금에, 대주 이자는 숏 평가액에,
+    /// 신용 이자는 담보를 제외한 음(-)의 현금(차입액)에 붙습니다.
+    fn accrue_daily_carry(&mut self, date: NaiveDate, kline: &Kline) {
+        if let Some(last) = self.last_carry_accrual {
+            if date <= last {
+                return;
+            }
+            let idle_cash = self.idle_cash();
+            if let Some(curve) = self.config.cash_yield.as_ref() {
+                let interest = curve.accrued_interest(idle_cash, last, date);
+                self.balance += interest;
+                self.total_cash_interest += interest;
+            }
+
+            let borrow_cost = self.config.margin.carry_cost(
+                self.short_exposure(kline),
+                -idle_cash,
+                (date - last).num_days(),
+            );
+            self.balance -= borrow_cost;
+            self.total_borrow_cost += borrow_cost;
+        }
+        self.last_carry_accrual = Some(date);
+    }
+
+    /// 유지 증거금 미달 시 노출액이 큰 포지션부터 강제 청산합니다.
+    ///
+    /// 현금 계좌 롱 전용 백테스트(공매도/마진 미허용)에는 적용하지 않습니다.
+    async fn enforce_maintenance_margin(&mut self, kline: &Kline) -> BacktestResult<()> {
+        if !self.config.allow_margin && !self.config.allow_short {
+            return Ok(());
+        }
+
+        while self
+            .config
+            .margin
+            .requires_liquidation(self.calculate_equity(kline), self.gross_exposure(kline))
+        {
+            let Some(position) = self
+                .positions
+                .values()
+                .max_by_key(|position| self.mark_price(position, kline) * position.quantity)
+                .cloned()
+            else {
+                break;
             };
 
-            equity += position_value;
+            let signal = Signal::exit(
+                &position.strategy_id,
+                position.symbol.clone(),
+                position.side.opposite(),
+            );
+            self.close_position(&signal, kline).await?;
+            self.forced_liquidations += 1;
         }
 
-        equity
+        Ok(())
+    }
+
+    /// 포지션 평가 가격 (현재가가 없으면 kline 종가)
+    fn mark_price(&self, position: &SimulatedPosition, kline: &Kline) -> Decimal {
+        self.current_prices
+            .get(&position.symbol)
+            .copied()
+            .unwrap_or(kline.close)
+    }
+
+    /// 현재 자산 가치를 계산합니다 (잔고 + 롱 평가액 − 숏 평가액).
+    fn calculate_equity(&self, kline: &Kline) -> Decimal {
+        self.positions
+            .values()
+            .fold(self.balance, |equity, position| {
+                let value = self.mark_price(position, kline) * position.quantity;
+                match position.side {
+                    Side::Buy => equity + value,
+                    Side::Sell => equity - value,
+                }
+            })
+    }
+
+    /// 총 노출액 (롱/숏 평가액 합계)
+    fn gross_exposure(&self, kline: &Kline) -> Decimal {
+        self.positions
+            .values()
+            .map(|position| self.mark_price(position, kline) * position.quantity)
+            .sum()
+    }
+
+    /// 숏 평가액 합계
+    fn short_exposure(&self, kline: &Kline) -> Decimal {
+        self.positions
+            .values()
+            .filter(|position| position.side == Side::Sell)
+            .map(|position| self.mark_price(position, kline) * position.quantity)
+            .sum()
+    }
+
+    /// 공매도 대금(담보)을 제외한 현금 (음수면 신용 차입액)
+    fn idle_cash(&self) -> Decimal {
+        self.positions
+            .values()
+            .filter(|position| position.side == Side::Sell)
+            .fold(self.balance, |cash, position| {
+                cash - position.entry_price * position.quantity
+            })
+    }
+
+    /// 신규 진입에 쓸 수 있는 남은 증거금 (자산 − 개시 증거금률 × 총 노출액)
+    fn available_margin(&self, kline: &Kline) -> Decimal {
+        self.calculate_equity(kline)
+            - self.config.initial_margin_rate() * self.gross_exposure(kline)
     }
 
     /// Trade 객체를 생성합니다.
@@ -891,15 +1364,24 @@ impl BacktestEngine {
         self.tracker.set_initial_timestamp(start_time);
 
         // 전략이 다중 타임프레임을 지원하는지 확인
-        let is_multi_tf_strategy = strategy.multi_timeframe_config().is_some();
+        let is_multi_tf_strategy = strategy.metadata().timeframes.is_some();
+        self.attach_screening_context(strategy);
 
         // 각 Primary 캔들에 대해 시뮬레이션
         for kline in primary_klines {
+            // 장중 세션: 장외 캔들 제외, 대기 신호는 이번 캔들 시가로 체결
+            if !self.begin_session_bar(kline).await? {
+                continue;
+            }
+
             // 캔들 완성 시점으로 현재 시간 설정 (데이터 누수 방지)
             self.current_time = kline.close_time;
             self.current_prices
                 .insert(kline.ticker.to_string(), kline.close);
 
+            // 캔들 완성일 기준 스크리닝 스냅샷 반영
+            self.sync_screening(kline.close_time.date_naive()).await;
+
             // 시장 데이터 생성
             let market_data = MarketData::from_kline(&self.config.exchange_name, kline.clone());
 
@@ -923,8 +1405,15 @@ impl BacktestEngine {
 
             // 신호 처리
             for signal in signals {
-                self.process_signal(&signal, kline).await?;
+                self.dispatch_signal(signal, kline).await?;
             }
+            self.end_session_bar(kline).await?;
+
+            // 유휴 현금 이자와 차입 비용 반영 (일 단위)
+            self.accrue_daily_carry(kline.close_time.date_naive(), kline);
+
+            // 유지 증거금 미달 시 강제 청산
+            self.enforce_maintenance_margin(kline).await?;
 
             // 미실현 손익 반영하여 자산 업데이트
             let equity = self.calculate_equity(kline);
@@ -947,6 +1436,10 @@ impl BacktestEngine {
             total_orders: self.total_orders,
             total_commission: self.total_commission,
             total_slippage: self.total_slippage,
+            total_tax: self.total_tax,
+            total_cash_interest: self.total_cash_interest,
+            total_borrow_cost: self.total_borrow_cost,
+            forced_liquidations: self.forced_liquidations,
             start_time,
             end_time,
             data_points,
@@ -1083,6 +1576,12 @@ pub mod test_strategies {
         bought: bool,
     }
 
+    impl Default for AlwaysBuyStrategy {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
     impl AlwaysBuyStrategy {
         pub fn new() -> Self {
             Self { bought: false }
@@ -1149,6 +1648,78 @@ pub mod test_strategies {
             serde_json::json!({ "bought": self.bought })
         }
     }
+
+    /// 첫 캔들에서 공매도 진입하는 전략 (테스트용)
+    #[derive(Default)]
+    pub struct AlwaysShortStrategy {
+        sold: bool,
+    }
+
+    impl AlwaysShortStrategy {
+        pub fn new() -> Self {
+            Self { sold: false }
+        }
+    }
+
+    #[async_trait]
+    impl trader_strategy::Strategy for AlwaysShortStrategy {
+        fn name(&self) -> &str {
+            "AlwaysShort"
+        }
+
+        fn version(&self) -> &str {
+            "1.0.0"
+        }
+
+        fn description(&self) -> &str {
+            "첫 캔들에서 공매도하는 테스트 전략"
+        }
+
+        async fn initialize(
+            &mut self,
+            _config: Value,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            self.sold = false;
+            Ok(())
+        }
+
+        async fn on_market_data(
+            &mut self,
+            data: &MarketData,
+        ) -> Result<Vec<Signal>, Box<dyn std::error::Error + Send + Sync>> {
+            if self.sold {
+                return Ok(vec![]);
+            }
+            self.sold = true;
+            Ok(vec![Signal::entry(
+                "AlwaysShort",
+                data.ticker.clone(),
+                Side::Sell,
+            )])
+        }
+
+        async fn on_order_filled(
+            &mut self,
+            _order: &Order,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        async fn on_position_update(
+            &mut self,
+            _position: &Position,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        async fn shutdown(&mut self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        fn get_state(&self) -> Value {
+            serde_json::json!({ "sold": self.sold })
+        }
+    }
 }
 
 #[cfg(test)]
@@ -1185,6 +1756,29 @@ mod tests {
             .collect()
     }
 
+    /// 종가 목록으로 일봉 생성
+    fn create_daily_klines(closes: &[Decimal]) -> Vec<Kline> {
+        let base_time = Utc::now() - Duration::days(closes.len() as i64 + 1);
+        closes
+            .iter()
+            .enumerate()
+            .map(|(i, close)| {
+                let open_time = base_time + Duration::days(i as i64);
+                Kline::new(
+                    "005930".to_string(),
+                    Timeframe::D1,
+                    open_time,
+                    *close,
+                    *close,
+                    *close,
+                    *close,
+                    dec!(1000),
+                    open_time + Duration::days(1),
+                )
+            })
+            .collect()
+    }
+
     #[test]
     fn test_config_creation() {
         let config = BacktestConfig::new(dec!(10000));
@@ -1279,10 +1873,173 @@ mod tests {
         assert!(!result.summary().is_empty());
     }
 
+    #[tokio::test]
+    async fn test_backtest_cash_yield() {
+        // 신호가 없는 전략: 잔고 전체가 유휴 현금
+        let config =
+            BacktestConfig::new(dec!(1000000)).with_cash_yield(CashYieldCurve::constant(3.65));
+        let mut engine = BacktestEngine::new(config);
+        let mut strategy = test_strategies::SimpleSmaStrategy::new(1000, 2000);
+
+        let base_time = Utc::now() - Duration::days(20);
+        let klines: Vec<Kline> = (0..11)
+            .map(|i| {
+                let open_time = base_time + Duration::days(i);
+                Kline::new(
+                    "005930".to_string(),
+                    Timeframe::D1,
+                    open_time,
+                    dec!(100),
+                    dec!(101),
+                    dec!(99),
+                    dec!(100),
+                    dec!(1000),
+                    open_time + Duration::days(1),
+                )
+            })
+            .collect();
+        let report = engine.run(&mut strategy, &klines).await.unwrap();
+
+        // 10일 × 3.65% / 365 × 1,000,000 ≈ 1,000 (일 단위로 잔고에 반영되어 복리)
+        assert!(report.total_cash_interest > dec!(1000));
+        assert!(report.total_cash_interest < dec!(1001));
+        assert_eq!(engine.balance(), dec!(1000000) + report.total_cash_interest);
+    }
+
+    #[tokio::test]
+    async fn test_backtest_cost_model() {
+        let klines = create_test_klines(10, dec!(50000), dec!(100));
+
+        let flat = BacktestConfig::new(dec!(100000)).with_slippage_rate(dec!(0));
+        let mut engine = BacktestEngine::new(flat);
+        let mut strategy = test_strategies::AlwaysBuyStrategy::new();
+        let flat_report = engine.run(&mut strategy, &klines).await.unwrap();
+        assert_eq!(flat_report.total_tax, Decimal::ZERO);
+
+        // 국내 주식: 매도 시 거래세 + 농특세 (0.20%)
+        let kr = BacktestConfig::new(dec!(100000))
+            .with_slippage_rate(dec!(0))
+            .with_cost_model(TradingCostModel::kr_kospi());
+        let mut engine = BacktestEngine::new(kr);
+        let mut strategy = test_strategies::AlwaysBuyStrategy::new();
+        let kr_report = engine.run(&mut strategy, &klines).await.unwrap();
+
+        assert!(kr_report.total_tax > Decimal::ZERO);
+        assert!(kr_report.total_commission < flat_report.total_commission);
+        assert!(kr_report.summary().contains("총 세금"));
+
+        let invalid =
+            BacktestConfig::new(dec!(100000)).with_cost_model(TradingCostModel::flat(dec!(0.5)));
+        assert!(invalid.validate().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_backtest_intraday_session() {
+        use chrono::TimeZone;
+
+        // KST 08:50 ~ 15:25 5분봉 (08:50, 08:55 캔들은 장전)
+        let first_open = Utc.with_ymd_and_hms(2026, 3, 9, 8, 50, 0).unwrap() - Duration::hours(9);
+        let klines: Vec<Kline> = (0..80)
+            .map(|i| {
+                let open_time = first_open + Duration::minutes(5 * i);
+                let open = dec!(100) + Decimal::from(i);
+                Kline::new(
+                    "005930".to_string(),
+                    Timeframe::M5,
+                    open_time,
+                    open,
+                    open + dec!(1),
+                    open - dec!(1),
+                    open + dec!(0.5),
+                    dec!(1000),
+                    open_time + Duration::minutes(5),
+                )
+            })
+            .collect();
+
+        let config = BacktestConfig::new(dec!(100000))
+            .with_slippage_rate(dec!(0))
+            .with_session(IntradaySession::krx().with_flatten_at_close(true));
+        let mut engine = BacktestEngine::new(config);
+        let mut strategy = test_strategies::AlwaysBuyStrategy::new();
+        let report = engine.run(&mut strategy, &klines).await.unwrap();
+
+        // 09:00 캔들 신호 → 09:05 캔들 시가 체결, 15:25 마감 캔들 종가로 청산
+        assert_eq!(report.trades.len(), 1);
+        assert_eq!(report.trades[0].entry_price, dec!(103));
+        assert_eq!(report.trades[0].exit_price, dec!(179.5));
+        assert_eq!(engine.open_positions_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_backtest_short_selling() {
+        // 100 → 90 하락 구간 공매도 (현금 담보, 자본의 20%)
+        let closes: Vec<Decimal> = (0..11).map(|i| dec!(100) - Decimal::from(i)).collect();
+        let klines = create_daily_klines(&closes);
+
+        let long_only = BacktestConfig::new(dec!(100000))
+            .with_commission_rate(dec!(0))
+            .with_slippage_rate(dec!(0));
+        let mut engine = BacktestEngine::new(long_only.clone());
+        let mut strategy = test_strategies::AlwaysShortStrategy::new();
+        let report = engine.run(&mut strategy, &klines).await.unwrap();
+        assert_eq!(report.total_orders, 0);
+
+        let mut engine = BacktestEngine::new(long_only.with_allow_short(true));
+        let mut strategy = test_strategies::AlwaysShortStrategy::new();
+        let report = engine.run(&mut strategy, &klines).await.unwrap();
+
+        // 200주 × (100 − 90) = 2,000 이익, 대주 이자는 20,000 × 3% × 10일 / 365 이하
+        assert_eq!(report.trades.len(), 1);
+        assert!(report.total_borrow_cost > Decimal::ZERO);
+        assert!(report.total_borrow_cost < dec!(16.5));
+        assert_eq!(engine.balance(), dec!(102000) - report.total_borrow_cost);
+        assert_eq!(report.forced_liquidations, 0);
+    }
+
+    #[tokio::test]
+    async fn test_backtest_margin_forced_liquidation() {
+        // 증거금률 50% 전액 사용: 100,000 자본으로 200,000어치(2,000주) 공매도
+        let config = BacktestConfig::new(dec!(100000))
+            .with_commission_rate(dec!(0))
+            .with_slippage_rate(dec!(0))
+            .with_max_position_size_pct(dec!(1))
+            .with_margin(MarginConfig::default());
+        let mut engine = BacktestEngine::new(config);
+        let mut strategy = test_strategies::AlwaysShortStrategy::new();
+
+        // 자산 100,000 − 2,000 × (p − 100) < 0.25 × 2,000p → p > 120에서 강제 청산
+        let klines = create_daily_klines(&[dec!(100), dec!(110), dec!(125), dec!(130)]);
+        let report = engine.run(&mut strategy, &klines).await.unwrap();
+
+        assert_eq!(report.forced_liquidations, 1);
+        assert_eq!(report.trades.len(), 1);
+        assert_eq!(report.trades[0].exit_price, dec!(125));
+        assert_eq!(engine.open_positions_count(), 0);
+        assert_eq!(engine.balance(), dec!(50000) - report.total_borrow_cost);
+        assert!(report.summary().contains("강제 청산: 1"));
+    }
+
     #[test]
     fn test_default_config() {
         let config = BacktestConfig::default();
         assert_eq!(config.initial_capital, dec!(10000000));
         assert!(config.validate().is_ok());
     }
+
+    #[test]
+    fn test_sizing_probability_uses_calibration() {
+        use trader_core::{CalibrationMethod, CalibrationSample};
+
+        let signal = Signal::new("rsi", "005930".to_string(), Side::Buy, SignalType::Entry)
+            .with_strength(0.9);
+        let config = BacktestConfig::default();
+        assert_eq!(config.sizing_probability(&signal), 0.9);
+
+        // 강도 0.9 신호의 실제 적중률 45%
+        let samples = (0..40).map(|i| ("rsi".to_string(), CalibrationSample::new(0.9, i < 18)));
+        let config = config
+            .with_signal_calibration(SignalCalibrator::fit(CalibrationMethod::Isotonic, samples));
+        assert!((config.sizing_probability(&signal) - 0.45).abs() < 1e-9);
+    }
 }
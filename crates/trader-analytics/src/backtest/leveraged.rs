@@ -0,0 +1,337 @@
+//! 레버리지 ETF 합성 시뮬레이션.
+//!
+//! 레버리지/인버스 ETF는 상장 이전 기간의 데이터가 없어 장기 백테스트가 어렵습니다.
+//! 기초지수 ETF(예: QQQ)의 일봉으로 레버리지 ETF(예: TQQQ)의 가격을 합성합니다.
+//!
+//! # 모델
+//!
+//! - **일일 리밸런싱**: 매일 기초자산 수익률 × 배수 (연속 변동 시 decay 발생)
+//! - **총보수**: 연 보수율을 일할 차감 (ACT/365)
+//! - **차입 비용**: 배수 초과분(|배수| - 1)에 대한 자금 조달 비용을 일할 차감
+//!
+//! 차입 금리는 현금 금리 곡선(예: 미국 3개월 T-bill)이 있으면 곡선 금리 + 가산금리,
+//! 없으면 고정 금리를 사용합니다. 인버스 ETF의 스왑 비용도 같은 방식으로 근사합니다.
+
+use chrono::{DateTime, Utc};
+use rust_decimal::prelude::*;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use trader_core::{CashYieldCurve, Kline};
+
+/// 가격 하한 (직전 종가 대비 비율, 기초자산 급락 시 음수 가격 방지)
+const MIN_PRICE_RATIO: Decimal = dec!(0.0001);
+
+/// 레버리지 ETF 합성 명세.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LeveragedEtfSpec {
+    /// 레버리지 ETF 티커 (예: TQQQ)
+    pub ticker: String,
+    /// 기초자산 티커 (예: QQQ)
+    pub underlying: String,
+    /// 일일 배수 (예: 3, 인버스는 -3)
+    pub leverage: Decimal,
+    /// 연 총보수율 (예: 0.0086 = 0.86%)
+    pub expense_ratio: Decimal,
+    /// 금리 곡선이 없을 때 사용하는 연 차입 금리
+    pub borrow_rate: Decimal,
+    /// 금리 곡선 사용 시 가산금리 (연)
+    pub borrow_spread: Decimal,
+}
+
+impl LeveragedEtfSpec {
+    /// 새 합성 명세 생성 (차입 금리 연 4%, 가산금리 0.5% 기본값).
+    pub fn new(
+        ticker: impl Into<String>,
+        underlying: impl Into<String>,
+        leverage: Decimal,
+        expense_ratio: Decimal,
+    ) -> Self {
+        Self {
+            ticker: ticker.into(),
+            underlying: underlying.into(),
+            leverage,
+            expense_ratio,
+            borrow_rate: dec!(0.04),
+            borrow_spread: dec!(0.005),
+        }
+    }
+
+    /// 주요 레버리지/인버스 ETF의 기본 명세 조회.
+    pub fn known(ticker: &str) -> Option<Self> {
+        let (underlying, leverage, expense_ratio) = match ticker.to_uppercase().as_str() {
+            // 미국
+            "TQQQ" => ("QQQ", dec!(3), dec!(0.0086)),
+            "SQQQ" => ("QQQ", dec!(-3), dec!(0.0095)),
+            "QLD" => ("QQQ", dec!(2), dec!(0.0095)),
+            "UPRO" => ("SPY", dec!(3), dec!(0.0091)),
+            "SPXU" => ("SPY", dec!(-3), dec!(0.0090)),
+            "SSO" => ("SPY", dec!(2), dec!(0.0089)),
+            "SOXL" => ("SOXX", dec!(3), dec!(0.0076)),
+            "SOXS" => ("SOXX", dec!(-3), dec!(0.0097)),
+            "TMF" => ("TLT", dec!(3), dec!(0.0106)),
+            // 국내 (KODEX 레버리지/인버스)
+            "122630" => ("069500", dec!(2), dec!(0.0064)),
+            "252670" => ("069500", dec!(-2), dec!(0.0064)),
+            "233740" => ("229200", dec!(2), dec!(0.0064)),
+            "251340" => ("229200", dec!(-1), dec!(0.0064)),
+            _ => return None,
+        };
+
+        Some(Self::new(
+            ticker.to_uppercase(),
+            underlying,
+            leverage,
+            expense_ratio,
+        ))
+    }
+
+    /// 해당 일자의 연 차입 금리.
+    fn borrow_rate_on(&self, at: DateTime<Utc>, financing: Option<&CashYieldCurve>) -> Decimal {
+        financing
+            .and_then(|curve| curve.rate_on(at.date_naive()))
+            .and_then(|pct| Decimal::from_f64(pct / 100.0))
+            .map(|rate| rate + self.borrow_spread)
+            .unwrap_or(self.borrow_rate)
+    }
+
+    /// 기간 비용률 (총보수 + 배수 초과분 차입 비용, ACT/365).
+    fn carry_cost(&self, days: i64, annual_borrow_rate: Decimal) -> Decimal {
+        if days <= 0 {
+            return Decimal::ZERO;
+        }
+        let borrowed = (self.leverage.abs() - Decimal::ONE).max(Decimal::ZERO);
+        (self.expense_ratio + borrowed * annual_borrow_rate) * Decimal::from(days) / dec!(365)
+    }
+}
+
+/// 기초자산 일봉으로 레버리지 ETF 일봉 합성.
+///
+/// 첫 캔들의 종가는 `start_price`이며, 이후 캔들은 직전 종가 대비
+/// 기초자산 수익률 × 배수로 계산한 뒤 기간 비용을 차감합니다.
+/// 시가/고가/저가도 같은 배수로 변환합니다 (인버스는 고가/저가가 뒤바뀜).
+pub fn synthesize_leveraged_klines(
+    underlying: &[Kline],
+    spec: &LeveragedEtfSpec,
+    financing: Option<&CashYieldCurve>,
+    start_price: Decimal,
+) -> Vec<Kline> {
+    let mut result: Vec<Kline> = Vec::with_capacity(underlying.len());
+    let mut prev: Option<(&Kline, Decimal)> = None;
+
+    for kline in underlying {
+        // 첫 캔들은 자기 종가를 기준으로 삼아 종가가 start_price가 되도록 함
+        let (ref_underlying, ref_price, days) = match prev {
+            Some((prev_kline, prev_close)) => (
+                prev_kline.close,
+                prev_close,
+                (kline.open_time.date_naive() - prev_kline.open_time.date_naive()).num_days(),
+            ),
+            None => (kline.close, start_price, 0),
+        };
+
+        if ref_underlying <= Decimal::ZERO {
+            continue;
+        }
+
+        let floor = ref_price * MIN_PRICE_RATIO;
+        let lever = |price: Decimal| {
+            let ret = price / ref_underlying - Decimal::ONE;
+            (ref_price * (Decimal::ONE + spec.leverage * ret)).max(floor)
+        };
+
+        let cost =
+            ref_price * spec.carry_cost(days, spec.borrow_rate_on(kline.open_time, financing));
+        let open = lever(kline.open);
+        let close = (lever(kline.close) - cost).max(floor);
+        let (a, b) = (lever(kline.high), lever(kline.low));
+        let high = a.max(b).max(open).max(close);
+        let low = a.min(b).min(open).min(close);
+
+        result.push(Kline {
+            ticker: spec.ticker.clone(),
+            timeframe: kline.timeframe,
+            open_time: kline.open_time,
+            close_time: kline.close_time,
+            open,
+            high,
+            low,
+            close,
+            volume: kline.volume,
+            quote_volume: None,
+            num_trades: None,
+        });
+        prev = Some((kline, close));
+    }
+
+    result
+}
+
+/// 실제 레버리지 ETF 데이터 이전 구간을 합성 데이터로 채움.
+///
+/// 실제 데이터가 없으면 기초자산 첫 종가에서 시작하는 합성 데이터 전체를 반환합니다.
+/// 실제 데이터가 있으면 첫 실제 캔들 이전 구간만 합성하고, 연결 지점의 가격이
+/// 실제 데이터와 이어지도록 합성 가격 전체를 비례 조정합니다.
+pub fn backfill_leveraged_history(
+    actual: Vec<Kline>,
+    underlying: &[Kline],
+    spec: &LeveragedEtfSpec,
+    financing: Option<&CashYieldCurve>,
+) -> Vec<Kline> {
+    let Some(first_actual) = actual.first() else {
+        let start_price = underlying.first().map(|k| k.close).unwrap_or(Decimal::ONE);
+        return synthesize_leveraged_klines(underlying, spec, financing, start_price);
+    };
+
+    let splice_date = first_actual.open_time.date_naive();
+    let before: Vec<Kline> = underlying
+        .iter()
+        .filter(|k| k.open_time.date_naive() <= splice_date)
+        .cloned()
+        .collect();
+    let Some(last) = before.last() else {
+        return actual;
+    };
+
+    // 연결일 캔들이 기초자산에 있으면 실제 종가, 없으면 실제 시가에 맞춤
+    let overlaps = last.open_time.date_naive() == splice_date;
+    let anchor = if overlaps {
+        first_actual.close
+    } else {
+        first_actual.open
+    };
+
+    // 마지막 합성 종가를 기준점에 맞춤 (연결일 캔들은 실제 데이터로 대체)
+    let mut synthetic = synthesize_leveraged_klines(&before, spec, financing, Decimal::ONE);
+    let Some(reference) = synthetic.last().map(|k| k.close) else {
+        return actual;
+    };
+    if overlaps {
+        synthetic.pop();
+    }
+
+    let scale = anchor / reference;
+    let ticker = first_actual.ticker.clone();
+    let mut result: Vec<Kline> = synthetic
+        .into_iter()
+        .map(|mut k| {
+            k.ticker = ticker.clone();
+            k.open *= scale;
+            k.high *= scale;
+            k.low *= scale;
+            k.close *= scale;
+            k
+        })
+        .collect();
+    result.extend(actual);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+    use trader_core::Timeframe;
+
+    fn daily_klines(closes: &[Decimal]) -> Vec<Kline> {
+        let start = Utc.with_ymd_and_hms(2008, 1, 1, 0, 0, 0).unwrap();
+        closes
+            .iter()
+            .enumerate()
+            .map(|(i, close)| {
+                let open_time = start + Duration::days(i as i64);
+                Kline {
+                    ticker: "QQQ".to_string(),
+                    timeframe: Timeframe::D1,
+                    open_time,
+                    close_time: open_time + Duration::hours(23),
+                    open: *close,
+                    high: *close,
+                    low: *close,
+                    close: *close,
+                    volume: dec!(1000),
+                    quote_volume: None,
+                    num_trades: None,
+                }
+            })
+            .collect()
+    }
+
+    fn frictionless(leverage: Decimal) -> LeveragedEtfSpec {
+        let mut spec = LeveragedEtfSpec::new("TQQQ", "QQQ", leverage, Decimal::ZERO);
+        spec.borrow_rate = Decimal::ZERO;
+        spec
+    }
+
+    #[test]
+    fn test_daily_rebalancing_decay() {
+        // 기초자산 +10% 후 원위치 → 3배 ETF는 손실
+        let underlying = daily_klines(&[dec!(100), dec!(110), dec!(100)]);
+        let spec = frictionless(dec!(3));
+        let synth = synthesize_leveraged_klines(&underlying, &spec, None, dec!(100));
+
+        assert_eq!(synth[0].close, dec!(100));
+        assert_eq!(synth[1].close, dec!(130));
+        assert!(synth[2].close < dec!(100));
+        assert_eq!(synth[2].ticker, "TQQQ");
+
+        // 인버스는 반대 방향
+        let inverse =
+            synthesize_leveraged_klines(&underlying, &frictionless(dec!(-3)), None, dec!(100));
+        assert_eq!(inverse[1].close, dec!(70));
+    }
+
+    #[test]
+    fn test_carry_costs() {
+        // 기초자산 변동 없음 → 총보수 + 차입 비용만큼 하락
+        let underlying = daily_klines(&[dec!(100); 366]);
+        let mut spec = LeveragedEtfSpec::new("TQQQ", "QQQ", dec!(3), dec!(0.01));
+        spec.borrow_rate = dec!(0.05);
+        let synth = synthesize_leveraged_klines(&underlying, &spec, None, dec!(100));
+
+        // 연 1% + 2 × 5% = 11% (일할 복리)
+        let last = synth.last().unwrap().close;
+        assert!(last > dec!(89) && last < dec!(90), "last = {}", last);
+
+        // 금리 곡선이 있으면 곡선 금리 + 가산금리 사용
+        let curve = CashYieldCurve::constant(1.0);
+        let with_curve = synthesize_leveraged_klines(&underlying, &spec, Some(&curve), dec!(100));
+        assert!(with_curve.last().unwrap().close > last);
+    }
+
+    #[test]
+    fn test_backfill_splices_into_actual() {
+        let underlying = daily_klines(&[dec!(100), dec!(102), dec!(101), dec!(104)]);
+        let spec = frictionless(dec!(3));
+
+        // 실제 데이터는 3번째 날부터 존재 (종가 50)
+        let mut actual = daily_klines(&[dec!(50), dec!(55)]);
+        for (k, u) in actual.iter_mut().zip(&underlying[2..]) {
+            k.ticker = "TQQQ".to_string();
+            k.open_time = u.open_time;
+        }
+
+        let merged = backfill_leveraged_history(actual, &underlying, &spec, None);
+        assert_eq!(merged.len(), 4);
+        assert_eq!(merged[2].close, dec!(50));
+
+        // 연결일 수익률은 기초자산 수익률 × 3
+        let implied = merged[2].close / merged[1].close - Decimal::ONE;
+        let expected = dec!(3) * (dec!(101) / dec!(102) - Decimal::ONE);
+        assert!((implied - expected).abs() < dec!(0.000001));
+
+        // 실제 데이터가 없으면 전체 합성
+        let full = backfill_leveraged_history(Vec::new(), &underlying, &spec, None);
+        assert_eq!(full.len(), 4);
+        assert_eq!(full[0].close, dec!(100));
+    }
+
+    #[test]
+    fn test_known_specs() {
+        let tqqq = LeveragedEtfSpec::known("tqqq").unwrap();
+        assert_eq!(tqqq.underlying, "QQQ");
+        assert_eq!(tqqq.leverage, dec!(3));
+        assert_eq!(LeveragedEtfSpec::known("122630").unwrap().leverage, dec!(2));
+        assert!(LeveragedEtfSpec::known("QQQ").is_none());
+    }
+}
@@ -0,0 +1,177 @@
+//! 백테스트 마진 계좌 (공매도 / 신용 매수)
+//!
+//! 기본 백테스트는 롱 전용 현금 계좌입니다. `allow_short`/`allow_margin`을 켜면
+//! 인버스 ETF 대신 실제 공매도와 레버리지로 양방향 전략을 모델링할 수 있습니다.
+//!
+//! - **공매도**: 매도 대금이 잔고에 입금되고, 숏 포지션 평가액만큼 부채로 잡힙니다.
+//!   매도 대금은 담보로 묶여 현금 이자가 붙지 않으며, 숏 평가액에 대주 차입 비용이 일할 부과됩니다.
+//! - **개시 증거금**: 신규 진입 후 (자산 − 개시 증거금률 × 총 노출액)이 0 이상이어야 합니다.
+//!   `allow_margin`이 꺼져 있으면 증거금률 100% (현금 담보 공매도, 신용 없음)로 간주합니다.
+//! - **신용 이자**: 담보를 제외한 현금이 음수(신용 매수 차입)이면 차입액에 신용 이자가 일할 부과됩니다.
+//! - **강제 청산**: 자산이 총 노출액 × 유지 증거금률 아래로 떨어지면
+//!   노출액이 큰 포지션부터 유지 증거금을 회복할 때까지 청산합니다.
+//!
+//! 차입 비용은 ACT/365 단리로 계산합니다.
+
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+
+/// 연간 일수 (ACT/365)
+const DAYS_PER_YEAR: Decimal = dec!(365);
+
+/// 마진 계좌 설정
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MarginConfig {
+    /// 개시 증거금률 (예: 0.5 = 50%, 최대 레버리지 2배)
+    #[serde(default = "default_initial_margin_rate")]
+    pub initial_margin_rate: Decimal,
+
+    /// 유지 증거금률 (예: 0.25 = 25%, 자산/총 노출액이 이 비율 미만이면 강제 청산)
+    #[serde(default = "default_maintenance_margin_rate")]
+    pub maintenance_margin_rate: Decimal,
+
+    /// 대주(공매도 주식 차입) 연 이자율 (예: 0.03 = 3%)
+    #[serde(default = "default_short_borrow_rate")]
+    pub short_borrow_rate: Decimal,
+
+    /// 신용 매수 차입 연 이자율 (예: 0.06 = 6%)
+    #[serde(default = "default_margin_loan_rate")]
+    pub margin_loan_rate: Decimal,
+}
+
+fn default_initial_margin_rate() -> Decimal {
+    dec!(0.5)
+}
+fn default_maintenance_margin_rate() -> Decimal {
+    dec!(0.25)
+}
+fn default_short_borrow_rate() -> Decimal {
+    dec!(0.03)
+}
+fn default_margin_loan_rate() -> Decimal {
+    dec!(0.06)
+}
+
+impl Default for MarginConfig {
+    fn default() -> Self {
+        Self {
+            initial_margin_rate: default_initial_margin_rate(),
+            maintenance_margin_rate: default_maintenance_margin_rate(),
+            short_borrow_rate: default_short_borrow_rate(),
+            margin_loan_rate: default_margin_loan_rate(),
+        }
+    }
+}
+
+impl MarginConfig {
+    /// 개시 증거금률 설정
+    pub fn with_initial_margin_rate(mut self, rate: Decimal) -> Self {
+        self.initial_margin_rate = rate;
+        self
+    }
+
+    /// 유지 증거금률 설정
+    pub fn with_maintenance_margin_rate(mut self, rate: Decimal) -> Self {
+        self.maintenance_margin_rate = rate;
+        self
+    }
+
+    /// 대주 차입 이자율 설정
+    pub fn with_short_borrow_rate(mut self, rate: Decimal) -> Self {
+        self.short_borrow_rate = rate;
+        self
+    }
+
+    /// 신용 이자율 설정
+    pub fn with_margin_loan_rate(mut self, rate: Decimal) -> Self {
+        self.margin_loan_rate = rate;
+        self
+    }
+
+    /// 설정 검증
+    pub fn validate(&self) -> Result<(), String> {
+        if self.initial_margin_rate <= Decimal::ZERO || self.initial_margin_rate > Decimal::ONE {
+            return Err(format!(
+                "개시 증거금률({})은 0 초과 1 이하여야 합니다",
+                self.initial_margin_rate
+            ));
+        }
+        if self.maintenance_margin_rate < Decimal::ZERO
+            || self.maintenance_margin_rate > self.initial_margin_rate
+        {
+            return Err(format!(
+                "유지 증거금률({})은 0 이상, 개시 증거금률({}) 이하여야 합니다",
+                self.maintenance_margin_rate, self.initial_margin_rate
+            ));
+        }
+        if self.short_borrow_rate < Decimal::ZERO || self.margin_loan_rate < Decimal::ZERO {
+            return Err("차입 이자율은 0 이상이어야 합니다".to_string());
+        }
+        Ok(())
+    }
+
+    /// 최대 레버리지 (1 / 개시 증거금률)
+    pub fn max_leverage(&self) -> Decimal {
+        Decimal::ONE / self.initial_margin_rate
+    }
+
+    /// `days`일 동안의 차입 비용 (숏 평가액의 대주 이자 + 신용 차입액의 이자)
+    pub fn carry_cost(&self, short_value: Decimal, loan: Decimal, days: i64) -> Decimal {
+        if days <= 0 {
+            return Decimal::ZERO;
+        }
+        let annual = short_value.max(Decimal::ZERO) * self.short_borrow_rate
+            + loan.max(Decimal::ZERO) * self.margin_loan_rate;
+        (annual * Decimal::from(days) / DAYS_PER_YEAR).round_dp(8)
+    }
+
+    /// 유지 증거금 미달 여부 (자산 < 총 노출액 × 유지 증거금률)
+    pub fn requires_liquidation(&self, equity: Decimal, gross_exposure: Decimal) -> bool {
+        gross_exposure > Decimal::ZERO && equity < gross_exposure * self.maintenance_margin_rate
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate() {
+        assert!(MarginConfig::default().validate().is_ok());
+        assert_eq!(MarginConfig::default().max_leverage(), dec!(2));
+
+        let zero = MarginConfig::default().with_initial_margin_rate(dec!(0));
+        assert!(zero.validate().is_err());
+
+        // 유지 증거금률이 개시 증거금률보다 높으면 진입 즉시 강제 청산
+        let inverted = MarginConfig::default().with_maintenance_margin_rate(dec!(0.6));
+        assert!(inverted.validate().is_err());
+
+        let negative = MarginConfig::default().with_short_borrow_rate(dec!(-0.01));
+        assert!(negative.validate().is_err());
+    }
+
+    #[test]
+    fn test_carry_cost() {
+        let config = MarginConfig::default()
+            .with_short_borrow_rate(dec!(0.0365))
+            .with_margin_loan_rate(dec!(0.073));
+
+        // 숏 100,000 × 3.65% / 365 = 10, 차입 50,000 × 7.3% / 365 = 10
+        assert_eq!(config.carry_cost(dec!(100000), dec!(0), 1), dec!(10));
+        assert_eq!(config.carry_cost(dec!(0), dec!(50000), 1), dec!(10));
+        assert_eq!(config.carry_cost(dec!(100000), dec!(50000), 3), dec!(60));
+        assert_eq!(config.carry_cost(dec!(100000), dec!(50000), 0), dec!(0));
+    }
+
+    #[test]
+    fn test_requires_liquidation() {
+        let config = MarginConfig::default();
+
+        assert!(!config.requires_liquidation(dec!(100), dec!(0)));
+        assert!(!config.requires_liquidation(dec!(25), dec!(100)));
+        assert!(config.requires_liquidation(dec!(24.9), dec!(100)));
+        assert!(config.requires_liquidation(dec!(-10), dec!(100)));
+    }
+}
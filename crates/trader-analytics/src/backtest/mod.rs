@@ -8,9 +8,39 @@
 //! - [`BacktestEngine`]: 백테스트 실행 엔진
 //! - [`BacktestReport`]: 백테스트 결과 리포트
 //! - [`SlippageModel`]: 동적 슬리피지 모델 (Fixed/Linear/VolatilityBased/Tiered)
+//! - [`MarginConfig`]: 마진 계좌 (공매도, 신용 매수, 차입 비용, 유지 증거금 미달 시 강제 청산)
+//! - [`LeveragedEtfSpec`]: 레버리지 ETF 합성 (일일 리밸런싱 decay, 총보수, 차입 비용)
+//! - [`ScreeningSnapshots`]: 스크리닝 기반 전략용 리밸런싱 일자별 과거 스크리닝 결과
+//! - [`IntradaySession`]: 분봉 백테스트용 정규장 세션 (다음 캔들 시가 체결, 마감 정리)
+//! - [`run_monte_carlo`]: 거래/일간 수익률 재표본으로 CAGR·최대 낙폭 신뢰구간과 파산 확률 추정
+//! - [`DataCoverageReport`]: 실행 전 심볼별 데이터 커버리지(가용 구간, 갭, 출처, 수정주가 여부)와 합성 데이터 거부 정책
+//! - [`analyze_cost_sensitivity`]: 수수료 × 슬리피지 격자 재실행 결과로 비용 1bp당 수익률 감소와 손익분기 비용 추정
 
+pub mod coverage;
 pub mod engine;
+pub mod leveraged;
+pub mod margin;
+pub mod monte_carlo;
+pub mod screening;
+pub mod sensitivity;
+pub mod session;
 pub mod slippage;
 
+pub use coverage::{
+    CoverageGap, CoveragePolicy, DataCoverageReport, KlineSource, SymbolCoverage,
+    DEFAULT_MIN_COVERAGE_PCT,
+};
 pub use engine::{BacktestConfig, BacktestEngine, BacktestError, BacktestReport, BacktestResult};
+pub use leveraged::{backfill_leveraged_history, synthesize_leveraged_klines, LeveragedEtfSpec};
+pub use margin::MarginConfig;
+pub use monte_carlo::{
+    run_monte_carlo, simulate_monte_carlo, DistributionSummary, MonteCarloConfig, MonteCarloInput,
+    MonteCarloResult, ResampleMethod, MAX_MONTE_CARLO_ITERATIONS,
+};
+pub use screening::{monthly_rebalance_dates, ScreeningSnapshots};
+pub use sensitivity::{
+    analyze_cost_sensitivity, CostSensitivityGrid, CostSensitivityPoint, CostSensitivityResult,
+    MAX_SENSITIVITY_RUNS,
+};
+pub use session::IntradaySession;
 pub use slippage::{SlippageModel, SlippageResult, SlippageTier};
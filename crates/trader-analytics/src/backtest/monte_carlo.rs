@@ -0,0 +1,467 @@
+//! 백테스트 결과 몬테카를로 시뮬레이션
+//!
+//! 백테스트에서 나온 거래 손익 또는 일간 수익률을 복원 추출(bootstrap)로 재배열해
+//! 수천 개의 가상 자산 경로를 만들고, CAGR·최대 낙폭·최종 자산의 분포와
+//! 파산 확률(risk of ruin)을 추정합니다.
+//!
+//! 단일 백테스트 경로는 거래 순서에 따른 운이 섞여 있으므로,
+//! 분포의 하위 백분위(p5)를 보수적인 기대치로 사용할 수 있습니다.
+//!
+//! # 재표본 방식
+//!
+//! - [`ResampleMethod::Trades`]: 라운드트립 손익(금액)을 복원 추출해 누적 (고정 크기 매매 가정)
+//! - [`ResampleMethod::DailyReturns`]: 일간 수익률을 복원 추출해 복리 누적
+
+use chrono::{DateTime, NaiveDate, Utc};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::backtest::engine::{BacktestError, BacktestReport, BacktestResult};
+
+/// 최대 시뮬레이션 횟수
+pub const MAX_MONTE_CARLO_ITERATIONS: usize = 10_000;
+
+/// 연간 거래일 수 (일간 수익률 재표본 시 기간 환산)
+const TRADING_DAYS_PER_YEAR: f64 = 252.0;
+
+/// 재표본 방식
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ResampleMethod {
+    /// 거래 손익 복원 추출
+    #[default]
+    Trades,
+    /// 일간 수익률 복원 추출
+    DailyReturns,
+}
+
+/// 몬테카를로 설정
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MonteCarloConfig {
+    /// 시뮬레이션 경로 수
+    pub iterations: usize,
+    /// 재표본 방식
+    pub method: ResampleMethod,
+    /// 파산 기준 낙폭 (%, 초기 자본 대비). 예: 50 = 자산이 초기 자본의 절반 이하로 하락
+    pub ruin_threshold_pct: f64,
+    /// 난수 시드 (재현용, None이면 무작위)
+    pub seed: Option<u64>,
+}
+
+impl Default for MonteCarloConfig {
+    fn default() -> Self {
+        Self {
+            iterations: 1_000,
+            method: ResampleMethod::Trades,
+            ruin_threshold_pct: 50.0,
+            seed: None,
+        }
+    }
+}
+
+impl MonteCarloConfig {
+    /// 시뮬레이션 경로 수 설정
+    pub fn with_iterations(mut self, iterations: usize) -> Self {
+        self.iterations = iterations;
+        self
+    }
+
+    /// 재표본 방식 설정
+    pub fn with_method(mut self, method: ResampleMethod) -> Self {
+        self.method = method;
+        self
+    }
+
+    /// 파산 기준 낙폭 설정
+    pub fn with_ruin_threshold_pct(mut self, threshold_pct: f64) -> Self {
+        self.ruin_threshold_pct = threshold_pct;
+        self
+    }
+
+    /// 난수 시드 설정
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// 설정 검증
+    pub fn validate(&self) -> BacktestResult<()> {
+        if self.iterations == 0 || self.iterations > MAX_MONTE_CARLO_ITERATIONS {
+            return Err(BacktestError::ConfigError(format!(
+                "시뮬레이션 횟수는 1~{} 사이여야 합니다: {}",
+                MAX_MONTE_CARLO_ITERATIONS, self.iterations
+            )));
+        }
+        if !(self.ruin_threshold_pct > 0.0 && self.ruin_threshold_pct <= 100.0) {
+            return Err(BacktestError::ConfigError(format!(
+                "파산 기준 낙폭은 0 초과 100 이하여야 합니다: {}",
+                self.ruin_threshold_pct
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// 시뮬레이션 입력 (백테스트 결과에서 추출한 표본)
+#[derive(Debug, Clone, Default)]
+pub struct MonteCarloInput {
+    /// 초기 자본
+    pub initial_capital: f64,
+    /// 라운드트립 손익 (청산 순)
+    pub trade_pnls: Vec<f64>,
+    /// 일간 수익률 (비율, 0.01 = 1%)
+    pub daily_returns: Vec<f64>,
+    /// 원본 백테스트 기간 (년)
+    pub years: f64,
+}
+
+impl MonteCarloInput {
+    /// 백테스트 리포트에서 입력을 추출합니다.
+    pub fn from_report(report: &BacktestReport) -> Self {
+        let mut trades: Vec<_> = report.trades.iter().collect();
+        trades.sort_by_key(|t| t.exit_time);
+
+        Self::from_history(
+            report.config.initial_capital,
+            trades.iter().map(|t| t.pnl),
+            report.equity_curve.iter().map(|p| (p.timestamp, p.equity)),
+            report.start_time,
+            report.end_time,
+        )
+    }
+
+    /// 거래 손익과 자산 곡선에서 입력을 구성합니다.
+    ///
+    /// 일간 수익률은 날짜별 마지막 자산 가치 사이의 변화율입니다.
+    pub fn from_history(
+        initial_capital: Decimal,
+        trade_pnls: impl IntoIterator<Item = Decimal>,
+        equity_curve: impl IntoIterator<Item = (DateTime<Utc>, Decimal)>,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Self {
+        let mut daily_equity: Vec<(NaiveDate, f64)> = Vec::new();
+        for (timestamp, equity) in equity_curve {
+            let date = timestamp.date_naive();
+            let equity = equity.to_f64().unwrap_or(0.0);
+            match daily_equity.last_mut() {
+                Some((last_date, last_equity)) if *last_date == date => *last_equity = equity,
+                _ => daily_equity.push((date, equity)),
+            }
+        }
+        let daily_returns = daily_equity
+            .windows(2)
+            .filter(|w| w[0].1 > 0.0)
+            .map(|w| w[1].1 / w[0].1 - 1.0)
+            .collect();
+
+        Self {
+            initial_capital: initial_capital.to_f64().unwrap_or(0.0),
+            trade_pnls: trade_pnls
+                .into_iter()
+                .filter_map(|pnl| pnl.to_f64())
+                .collect(),
+            daily_returns,
+            years: (end - start).num_days() as f64 / 365.25,
+        }
+    }
+}
+
+/// 분포 요약 (평균 및 백분위)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DistributionSummary {
+    pub mean: f64,
+    /// 5 백분위 (90% 신뢰구간 하단)
+    pub p5: f64,
+    pub p25: f64,
+    /// 중앙값
+    pub p50: f64,
+    pub p75: f64,
+    /// 95 백분위 (90% 신뢰구간 상단)
+    pub p95: f64,
+}
+
+impl DistributionSummary {
+    fn from_samples(mut samples: Vec<f64>) -> Self {
+        samples.sort_by(|a, b| a.total_cmp(b));
+        let mean = samples.iter().sum::<f64>() / samples.len().max(1) as f64;
+        Self {
+            mean,
+            p5: percentile(&samples, 5.0),
+            p25: percentile(&samples, 25.0),
+            p50: percentile(&samples, 50.0),
+            p75: percentile(&samples, 75.0),
+            p95: percentile(&samples, 95.0),
+        }
+    }
+}
+
+/// 몬테카를로 시뮬레이션 결과
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MonteCarloResult {
+    /// 재표본 방식
+    pub method: ResampleMethod,
+    /// 시뮬레이션 경로 수
+    pub iterations: usize,
+    /// 경로당 표본 수 (거래 수 또는 거래일 수)
+    pub sample_size: usize,
+    /// 연복리 수익률 분포 (%)
+    pub cagr_pct: DistributionSummary,
+    /// 최대 낙폭 분포 (%)
+    pub max_drawdown_pct: DistributionSummary,
+    /// 최종 자산 분포
+    pub final_equity: DistributionSummary,
+    /// 최종 자산이 초기 자본보다 작은 경로 비율 (%)
+    pub probability_of_loss_pct: f64,
+    /// 파산 기준 낙폭 (%)
+    pub ruin_threshold_pct: f64,
+    /// 자산이 한 번이라도 파산 기준 아래로 내려간 경로 비율 (%)
+    pub risk_of_ruin_pct: f64,
+}
+
+/// 단일 경로 결과
+struct PathOutcome {
+    final_equity: f64,
+    max_drawdown_pct: f64,
+    ruined: bool,
+}
+
+/// 백테스트 리포트로 몬테카를로 시뮬레이션을 실행합니다.
+pub fn run_monte_carlo(
+    report: &BacktestReport,
+    config: &MonteCarloConfig,
+) -> BacktestResult<MonteCarloResult> {
+    simulate_monte_carlo(&MonteCarloInput::from_report(report), config)
+}
+
+/// 추출된 표본으로 몬테카를로 시뮬레이션을 실행합니다.
+pub fn simulate_monte_carlo(
+    input: &MonteCarloInput,
+    config: &MonteCarloConfig,
+) -> BacktestResult<MonteCarloResult> {
+    config.validate()?;
+    if input.initial_capital <= 0.0 {
+        return Err(BacktestError::DataError(
+            "초기 자본이 0 이하입니다".to_string(),
+        ));
+    }
+
+    let (samples, years) = match config.method {
+        ResampleMethod::Trades => (&input.trade_pnls, input.years),
+        ResampleMethod::DailyReturns => (
+            &input.daily_returns,
+            input.daily_returns.len() as f64 / TRADING_DAYS_PER_YEAR,
+        ),
+    };
+    if samples.is_empty() {
+        return Err(BacktestError::DataError(match config.method {
+            ResampleMethod::Trades => "재표본할 거래 내역이 없습니다".to_string(),
+            ResampleMethod::DailyReturns => "재표본할 일간 수익률이 없습니다".to_string(),
+        }));
+    }
+    // 기간이 하루 미만이면 연율화하지 않고 하루로 간주
+    let years = years.max(1.0 / 365.25);
+
+    let mut rng = match config.seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+    let ruin_equity = input.initial_capital * (1.0 - config.ruin_threshold_pct / 100.0);
+
+    let mut final_equities = Vec::with_capacity(config.iterations);
+    let mut cagrs = Vec::with_capacity(config.iterations);
+    let mut drawdowns = Vec::with_capacity(config.iterations);
+    let mut losses = 0usize;
+    let mut ruins = 0usize;
+
+    for _ in 0..config.iterations {
+        let path = simulate_path(
+            &mut rng,
+            samples,
+            config.method,
+            input.initial_capital,
+            ruin_equity,
+        );
+
+        let growth = path.final_equity / input.initial_capital;
+        let cagr = if growth > 0.0 {
+            (growth.powf(1.0 / years) - 1.0) * 100.0
+        } else {
+            -100.0
+        };
+
+        if path.final_equity < input.initial_capital {
+            losses += 1;
+        }
+        if path.ruined {
+            ruins += 1;
+        }
+        final_equities.push(path.final_equity);
+        cagrs.push(cagr);
+        drawdowns.push(path.max_drawdown_pct);
+    }
+
+    let iterations = config.iterations as f64;
+    Ok(MonteCarloResult {
+        method: config.method,
+        iterations: config.iterations,
+        sample_size: samples.len(),
+        cagr_pct: DistributionSummary::from_samples(cagrs),
+        max_drawdown_pct: DistributionSummary::from_samples(drawdowns),
+        final_equity: DistributionSummary::from_samples(final_equities),
+        probability_of_loss_pct: losses as f64 / iterations * 100.0,
+        ruin_threshold_pct: config.ruin_threshold_pct,
+        risk_of_ruin_pct: ruins as f64 / iterations * 100.0,
+    })
+}
+
+/// 표본을 복원 추출해 한 개의 자산 경로를 만듭니다.
+///
+/// 자산이 0 이하가 되면 이후 거래는 불가능하므로 경로를 종료합니다.
+fn simulate_path(
+    rng: &mut StdRng,
+    samples: &[f64],
+    method: ResampleMethod,
+    initial_capital: f64,
+    ruin_equity: f64,
+) -> PathOutcome {
+    let mut equity = initial_capital;
+    let mut peak = initial_capital;
+    let mut max_drawdown_pct: f64 = 0.0;
+    let mut ruined = false;
+
+    for _ in 0..samples.len() {
+        let sample = samples[rng.gen_range(0..samples.len())];
+        equity = match method {
+            ResampleMethod::Trades => equity + sample,
+            ResampleMethod::DailyReturns => equity * (1.0 + sample),
+        }
+        .max(0.0);
+
+        peak = peak.max(equity);
+        max_drawdown_pct = max_drawdown_pct.max((peak - equity) / peak * 100.0);
+        if equity <= ruin_equity {
+            ruined = true;
+        }
+        if equity <= 0.0 {
+            break;
+        }
+    }
+
+    PathOutcome {
+        final_equity: equity,
+        max_drawdown_pct,
+        ruined,
+    }
+}
+
+/// 정렬된 표본의 백분위 (선형 보간)
+fn percentile(sorted: &[f64], pct: f64) -> f64 {
+    match sorted.len() {
+        0 => 0.0,
+        1 => sorted[0],
+        len => {
+            let rank = pct / 100.0 * (len - 1) as f64;
+            let lower = rank.floor() as usize;
+            let upper = rank.ceil() as usize;
+            sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - lower as f64)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+    use rust_decimal_macros::dec;
+
+    fn input() -> MonteCarloInput {
+        let start = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let equity = [
+            dec!(10000),
+            dec!(10100),
+            dec!(9900),
+            dec!(10200),
+            dec!(10300),
+        ];
+        MonteCarloInput::from_history(
+            dec!(10000),
+            [dec!(500), dec!(-300), dec!(400), dec!(-200), dec!(300)],
+            equity
+                .iter()
+                .enumerate()
+                .map(|(i, e)| (start + Duration::days(i as i64), *e)),
+            start,
+            start + Duration::days(365),
+        )
+    }
+
+    #[test]
+    fn test_input_from_history() {
+        let input = input();
+        assert_eq!(input.trade_pnls.len(), 5);
+        assert_eq!(input.daily_returns.len(), 4);
+        assert!((input.daily_returns[0] - 0.01).abs() < 1e-12);
+        assert!((input.years - 365.0 / 365.25).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_monte_carlo_trades() {
+        let config = MonteCarloConfig::default()
+            .with_iterations(2_000)
+            .with_seed(42);
+        let result = simulate_monte_carlo(&input(), &config).unwrap();
+
+        assert_eq!(result.iterations, 2_000);
+        assert_eq!(result.sample_size, 5);
+        assert!(result.cagr_pct.p5 <= result.cagr_pct.p50);
+        assert!(result.cagr_pct.p50 <= result.cagr_pct.p95);
+        // 손익 합계가 고정이 아니므로 손실 경로가 일부 존재
+        assert!(result.probability_of_loss_pct > 0.0);
+        assert!(result.probability_of_loss_pct < 100.0);
+        // 최대 손실 5 × 300 = 1,500 < 5,000 → 파산 없음
+        assert_eq!(result.risk_of_ruin_pct, 0.0);
+        assert!(result.max_drawdown_pct.p95 <= 15.0 + 1e-9);
+
+        // 같은 시드면 같은 결과
+        let again = simulate_monte_carlo(&input(), &config).unwrap();
+        assert_eq!(result.final_equity, again.final_equity);
+    }
+
+    #[test]
+    fn test_monte_carlo_ruin_and_validation() {
+        let ruinous = MonteCarloInput {
+            initial_capital: 1_000.0,
+            trade_pnls: vec![-400.0, -400.0, 100.0],
+            daily_returns: vec![],
+            years: 1.0,
+        };
+        let config = MonteCarloConfig::default()
+            .with_iterations(500)
+            .with_seed(7)
+            .with_ruin_threshold_pct(50.0);
+        let result = simulate_monte_carlo(&ruinous, &config).unwrap();
+        assert!(result.risk_of_ruin_pct > 50.0);
+        assert!(result.final_equity.p5 < 500.0);
+
+        let daily = config.clone().with_method(ResampleMethod::DailyReturns);
+        assert!(matches!(
+            simulate_monte_carlo(&ruinous, &daily),
+            Err(BacktestError::DataError(_))
+        ));
+        assert!(config.clone().with_iterations(0).validate().is_err());
+        assert!(config.with_ruin_threshold_pct(0.0).validate().is_err());
+    }
+
+    #[test]
+    fn test_percentile() {
+        let sorted = [1.0, 2.0, 3.0, 4.0, 5.0];
+        assert_eq!(percentile(&sorted, 0.0), 1.0);
+        assert_eq!(percentile(&sorted, 50.0), 3.0);
+        assert_eq!(percentile(&sorted, 100.0), 5.0);
+        assert!((percentile(&sorted, 5.0) - 1.2).abs() < 1e-12);
+    }
+}
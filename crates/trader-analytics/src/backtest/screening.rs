@@ -0,0 +1,201 @@
+//! 백테스트용 과거 스크리닝 스냅샷
+//!
+//! 스크리닝 결과로 종목을 고르는 동적 전략(small_cap_quant, market_interest_day 등)은
+//! 실행 중 `StrategyContext::screening_results`를 참조합니다.
+//! 백테스트에서는 리밸런싱 일자별로 재구성한 스크리닝 결과를 보관하고,
+//! 시뮬레이션 시각 기준 가장 최근 스냅샷만 컨텍스트에 반영하여 미래 데이터 사용을 막습니다.
+
+use chrono::{Datelike, NaiveDate};
+use rust_decimal::Decimal;
+use std::collections::{BTreeMap, HashMap};
+use trader_core::domain::{GlobalScoreResult, RouteState, ScreeningResult, StrategyContext};
+
+/// 리밸런싱 일자별 스크리닝 스냅샷
+#[derive(Debug, Clone, Default)]
+pub struct ScreeningSnapshots {
+    /// 컨텍스트에 저장할 프리셋 이름
+    preset: String,
+    /// 기준일 → 해당 시점 스크리닝 결과
+    snapshots: BTreeMap<NaiveDate, Vec<ScreeningResult>>,
+}
+
+impl ScreeningSnapshots {
+    /// 빈 스냅샷 모음 생성
+    pub fn new(preset: impl Into<String>) -> Self {
+        Self {
+            preset: preset.into(),
+            snapshots: BTreeMap::new(),
+        }
+    }
+
+    /// 프리셋 이름
+    pub fn preset(&self) -> &str {
+        &self.preset
+    }
+
+    /// 기준일 스냅샷 추가 (같은 날짜는 덮어씀)
+    pub fn insert(&mut self, date: NaiveDate, results: Vec<ScreeningResult>) {
+        self.snapshots.insert(date, results);
+    }
+
+    /// 스냅샷 개수
+    pub fn len(&self) -> usize {
+        self.snapshots.len()
+    }
+
+    /// 스냅샷이 비어있는지 여부
+    pub fn is_empty(&self) -> bool {
+        self.snapshots.is_empty()
+    }
+
+    /// 전체 스냅샷에 한 번이라도 포함된 종목 (정렬, 중복 제거)
+    pub fn universe(&self) -> Vec<String> {
+        let mut tickers: Vec<String> = self
+            .snapshots
+            .values()
+            .flatten()
+            .map(|r| r.ticker.clone())
+            .collect();
+        tickers.sort();
+        tickers.dedup();
+        tickers
+    }
+
+    /// `date` 시점에 유효한 스냅샷 (기준일이 `date` 이하인 가장 최근 스냅샷)
+    pub fn as_of(&self, date: NaiveDate) -> Option<(NaiveDate, &[ScreeningResult])> {
+        self.snapshots
+            .range(..=date)
+            .next_back()
+            .map(|(d, results)| (*d, results.as_slice()))
+    }
+
+    /// 스냅샷을 전략 컨텍스트에 반영
+    ///
+    /// 스크리닝 결과와 함께 종목별 RouteState, GlobalScore도 갱신하여
+    /// 전략의 진입 조건(RouteState/GlobalScore 체크)이 같은 시점 데이터를 보도록 합니다.
+    pub fn apply_to(&self, results: &[ScreeningResult], context: &mut StrategyContext) {
+        let route_states: HashMap<String, RouteState> = results
+            .iter()
+            .map(|r| (r.ticker.clone(), r.route_state))
+            .collect();
+
+        // 스냅샷에는 종합 점수만 있으므로 컴포넌트 점수/추천은 비워둠
+        let scores = results
+            .iter()
+            .map(|r| GlobalScoreResult {
+                ticker: Some(r.ticker.clone()),
+                market_type: None,
+                overall_score: r.overall_score,
+                component_scores: HashMap::new(),
+                recommendation: String::new(),
+                confidence: Decimal::ZERO,
+                timestamp: r.timestamp,
+            })
+            .collect();
+
+        context.update_route_states(route_states);
+        context.update_global_scores(scores);
+        context.update_screening(self.preset.clone(), results.to_vec());
+    }
+}
+
+/// 거래일 목록에서 월별 첫 거래일 추출 (월간 리밸런싱 기준일)
+pub fn monthly_rebalance_dates(trading_dates: &[NaiveDate]) -> Vec<NaiveDate> {
+    let mut dates: Vec<NaiveDate> = trading_dates.to_vec();
+    dates.sort();
+    dates.dedup();
+
+    let mut result: Vec<NaiveDate> = Vec::new();
+    for date in dates {
+        let is_new_month = result.last().map_or(true, |last| {
+            (last.year(), last.month()) != (date.year(), date.month())
+        });
+        if is_new_month {
+            result.push(date);
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use rust_decimal_macros::dec;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn result(ticker: &str, score: Decimal, route_state: RouteState) -> ScreeningResult {
+        ScreeningResult {
+            ticker: ticker.to_string(),
+            preset_name: "small_cap_quant".to_string(),
+            passed: true,
+            overall_score: score,
+            route_state,
+            criteria_results: HashMap::new(),
+            timestamp: Utc::now(),
+            sector_rs: None,
+            sector_rank: None,
+            trigger_score: None,
+            trigger_label: None,
+        }
+    }
+
+    #[test]
+    fn test_as_of_uses_latest_snapshot_before_date() {
+        let mut snapshots = ScreeningSnapshots::new("small_cap_quant");
+        snapshots.insert(
+            date(2024, 1, 2),
+            vec![result("A", dec!(70), RouteState::Armed)],
+        );
+        snapshots.insert(
+            date(2024, 2, 1),
+            vec![result("B", dec!(80), RouteState::Attack)],
+        );
+
+        assert!(snapshots.as_of(date(2023, 12, 29)).is_none());
+
+        let (as_of, results) = snapshots.as_of(date(2024, 1, 31)).unwrap();
+        assert_eq!(as_of, date(2024, 1, 2));
+        assert_eq!(results[0].ticker, "A");
+
+        let (as_of, _) = snapshots.as_of(date(2024, 2, 1)).unwrap();
+        assert_eq!(as_of, date(2024, 2, 1));
+
+        assert_eq!(snapshots.universe(), vec!["A".to_string(), "B".to_string()]);
+    }
+
+    #[test]
+    fn test_apply_to_context() {
+        let snapshots = ScreeningSnapshots::new("small_cap_quant");
+        let results = vec![result("A", dec!(70), RouteState::Armed)];
+        let mut context = StrategyContext::default();
+
+        snapshots.apply_to(&results, &mut context);
+
+        assert_eq!(context.screening_results["small_cap_quant"].len(), 1);
+        assert_eq!(context.get_route_state("A"), Some(&RouteState::Armed));
+        assert_eq!(
+            context.get_global_score("A").map(|s| s.overall_score),
+            Some(dec!(70))
+        );
+    }
+
+    #[test]
+    fn test_monthly_rebalance_dates() {
+        let dates = vec![
+            date(2024, 2, 2),
+            date(2024, 1, 3),
+            date(2024, 1, 2),
+            date(2024, 2, 1),
+            date(2024, 3, 4),
+        ];
+
+        assert_eq!(
+            monthly_rebalance_dates(&dates),
+            vec![date(2024, 1, 2), date(2024, 2, 1), date(2024, 3, 4)]
+        );
+    }
+}
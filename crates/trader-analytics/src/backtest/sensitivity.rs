@@ -0,0 +1,332 @@
+//! 백테스트 수수료/슬리피지 민감도 분석
+//!
+//! 같은 백테스트를 수수료율 × 슬리피지율 격자로 재실행한 성과를 모아
+//! 거래 비용이 늘어날 때 수익(edge)이 얼마나 빨리 사라지는지 요약합니다.
+//!
+//! 편도 비용(수수료율 + 슬리피지율, bp)에 대한 총 수익률의 최소제곱 회귀로
+//! 비용 1bp당 수익률 감소분과 총 수익률이 0이 되는 손익분기 비용을 추정합니다.
+
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+
+use crate::backtest::engine::{BacktestError, BacktestResult};
+
+/// 격자 최대 조합 수 (조합마다 백테스트를 한 번씩 재실행)
+pub const MAX_SENSITIVITY_RUNS: usize = 49;
+
+/// 기본 격자 배율 (기준 비용 대비)
+const DEFAULT_MULTIPLIERS: [i64; 5] = [0, 1, 2, 4, 8];
+
+/// 최대 수수료율 (10%)
+const MAX_COMMISSION_RATE: Decimal = dec!(0.1);
+
+/// 최대 슬리피지율 (5%)
+const MAX_SLIPPAGE_RATE: Decimal = dec!(0.05);
+
+/// 1bp = 0.01%
+const BPS_PER_UNIT: f64 = 10_000.0;
+
+/// 수수료율 × 슬리피지율 격자
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CostSensitivityGrid {
+    /// 수수료율 목록 (비율, 0.001 = 0.1%)
+    pub commission_rates: Vec<Decimal>,
+    /// 슬리피지율 목록 (비율)
+    pub slippage_rates: Vec<Decimal>,
+}
+
+impl CostSensitivityGrid {
+    /// 기준 비용의 0, 1, 2, 4, 8배 격자를 만듭니다.
+    ///
+    /// 기준 비용이 0이면 기본값(수수료 0.1%, 슬리피지 0.05%)을 기준으로 사용합니다.
+    pub fn around(commission_rate: Decimal, slippage_rate: Decimal) -> Self {
+        let scale = |base: Decimal, fallback: Decimal| {
+            let base = if base > Decimal::ZERO { base } else { fallback };
+            DEFAULT_MULTIPLIERS
+                .iter()
+                .map(|&m| base * Decimal::from(m))
+                .collect()
+        };
+
+        Self {
+            commission_rates: scale(commission_rate, dec!(0.001)),
+            slippage_rates: scale(slippage_rate, dec!(0.0005)),
+        }
+    }
+
+    /// 격자 검증
+    pub fn validate(&self) -> BacktestResult<()> {
+        if self.commission_rates.is_empty() || self.slippage_rates.is_empty() {
+            return Err(BacktestError::ConfigError(
+                "수수료율과 슬리피지율은 각각 하나 이상 필요합니다".to_string(),
+            ));
+        }
+        let runs = self.commission_rates.len() * self.slippage_rates.len();
+        if runs > MAX_SENSITIVITY_RUNS {
+            return Err(BacktestError::ConfigError(format!(
+                "민감도 조합은 최대 {}개입니다: {}",
+                MAX_SENSITIVITY_RUNS, runs
+            )));
+        }
+        if let Some(rate) = self
+            .commission_rates
+            .iter()
+            .find(|r| **r < Decimal::ZERO || **r > MAX_COMMISSION_RATE)
+        {
+            return Err(BacktestError::ConfigError(format!(
+                "수수료율은 0 ~ {} 사이여야 합니다: {}",
+                MAX_COMMISSION_RATE, rate
+            )));
+        }
+        if let Some(rate) = self
+            .slippage_rates
+            .iter()
+            .find(|r| **r < Decimal::ZERO || **r > MAX_SLIPPAGE_RATE)
+        {
+            return Err(BacktestError::ConfigError(format!(
+                "슬리피지율은 0 ~ {} 사이여야 합니다: {}",
+                MAX_SLIPPAGE_RATE, rate
+            )));
+        }
+        Ok(())
+    }
+
+    /// 정렬·중복 제거한 격자를 반환합니다.
+    pub fn normalized(mut self) -> Self {
+        for rates in [&mut self.commission_rates, &mut self.slippage_rates] {
+            for rate in rates.iter_mut() {
+                *rate = rate.normalize();
+            }
+            rates.sort();
+            rates.dedup();
+        }
+        self
+    }
+
+    /// (수수료율, 슬리피지율) 조합 목록 (수수료율 우선 순서)
+    pub fn combinations(&self) -> Vec<(Decimal, Decimal)> {
+        self.commission_rates
+            .iter()
+            .flat_map(|&c| self.slippage_rates.iter().map(move |&s| (c, s)))
+            .collect()
+    }
+}
+
+/// 한 조합의 재실행 성과
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CostSensitivityPoint {
+    /// 수수료율
+    pub commission_rate: Decimal,
+    /// 슬리피지율
+    pub slippage_rate: Decimal,
+    /// 편도 비용 (수수료율 + 슬리피지율, bp)
+    pub cost_bps: f64,
+    /// 총 수익률 (%)
+    pub total_return_pct: f64,
+    /// 연율화 수익률 (%)
+    pub annualized_return_pct: f64,
+    /// 샤프 비율
+    pub sharpe_ratio: f64,
+    /// 최대 낙폭 (%)
+    pub max_drawdown_pct: f64,
+    /// 프로핏 팩터
+    pub profit_factor: f64,
+    /// 총 거래 수
+    pub total_trades: usize,
+}
+
+impl CostSensitivityPoint {
+    /// 조합만 채운 점을 만듭니다 (성과 지표는 0).
+    pub fn new(commission_rate: Decimal, slippage_rate: Decimal) -> Self {
+        Self {
+            commission_rate,
+            slippage_rate,
+            cost_bps: ((commission_rate + slippage_rate).to_f64().unwrap_or(0.0)) * BPS_PER_UNIT,
+            total_return_pct: 0.0,
+            annualized_return_pct: 0.0,
+            sharpe_ratio: 0.0,
+            max_drawdown_pct: 0.0,
+            profit_factor: 0.0,
+            total_trades: 0,
+        }
+    }
+}
+
+/// 민감도 분석 결과
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CostSensitivityResult {
+    /// 원본 백테스트 수수료율
+    pub baseline_commission_rate: Decimal,
+    /// 원본 백테스트 슬리피지율
+    pub baseline_slippage_rate: Decimal,
+    /// 원본 편도 비용 (bp)
+    pub baseline_cost_bps: f64,
+    /// 조합별 성과 (수수료율, 슬리피지율 순)
+    pub points: Vec<CostSensitivityPoint>,
+    /// 편도 비용 1bp 증가당 총 수익률 변화 (%p, 음수면 비용에 따라 감소)
+    pub return_change_per_bp: Option<f64>,
+    /// 총 수익률이 0이 되는 편도 비용 추정치 (bp, 비용이 없어도 손실이면 0)
+    pub breakeven_cost_bps: Option<f64>,
+    /// 원본 비용에서 손익분기까지 남은 비용 여유 (bp, 음수면 이미 손실 구간)
+    pub cost_headroom_bps: Option<f64>,
+    /// 수익을 낸 조합 비율 (%)
+    pub profitable_pct: f64,
+}
+
+/// 조합별 성과로 민감도를 요약합니다.
+///
+/// 비용과 총 수익률이 음의 관계가 아니면(거래가 없거나 비용이 수익에 영향을 주지 않으면)
+/// 손익분기 비용은 `None`입니다.
+pub fn analyze_cost_sensitivity(
+    baseline_commission_rate: Decimal,
+    baseline_slippage_rate: Decimal,
+    mut points: Vec<CostSensitivityPoint>,
+) -> CostSensitivityResult {
+    points.sort_by(|a, b| {
+        (a.commission_rate, a.slippage_rate).cmp(&(b.commission_rate, b.slippage_rate))
+    });
+
+    let baseline_cost_bps =
+        CostSensitivityPoint::new(baseline_commission_rate, baseline_slippage_rate).cost_bps;
+
+    let fit = linear_fit(
+        &points
+            .iter()
+            .map(|p| (p.cost_bps, p.total_return_pct))
+            .collect::<Vec<_>>(),
+    );
+    let return_change_per_bp = fit.map(|(slope, _)| slope);
+    let breakeven_cost_bps = fit
+        .filter(|(slope, _)| *slope < 0.0)
+        .map(|(slope, intercept)| (-intercept / slope).max(0.0));
+
+    let profitable = points.iter().filter(|p| p.total_return_pct > 0.0).count();
+    let profitable_pct = if points.is_empty() {
+        0.0
+    } else {
+        profitable as f64 / points.len() as f64 * 100.0
+    };
+
+    CostSensitivityResult {
+        baseline_commission_rate,
+        baseline_slippage_rate,
+        baseline_cost_bps,
+        points,
+        return_change_per_bp,
+        breakeven_cost_bps,
+        cost_headroom_bps: breakeven_cost_bps.map(|b| b - baseline_cost_bps),
+        profitable_pct,
+    }
+}
+
+/// 최소제곱 직선 (기울기, 절편). x 값이 모두 같으면 `None`.
+fn linear_fit(samples: &[(f64, f64)]) -> Option<(f64, f64)> {
+    if samples.len() < 2 {
+        return None;
+    }
+    let n = samples.len() as f64;
+    let mean_x = samples.iter().map(|(x, _)| x).sum::<f64>() / n;
+    let mean_y = samples.iter().map(|(_, y)| y).sum::<f64>() / n;
+    let sxx: f64 = samples.iter().map(|(x, _)| (x - mean_x).powi(2)).sum();
+    if sxx <= f64::EPSILON {
+        return None;
+    }
+    let sxy: f64 = samples
+        .iter()
+        .map(|(x, y)| (x - mean_x) * (y - mean_y))
+        .sum();
+    let slope = sxy / sxx;
+    Some((slope, mean_y - slope * mean_x))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(commission: Decimal, slippage: Decimal, return_pct: f64) -> CostSensitivityPoint {
+        CostSensitivityPoint {
+            total_return_pct: return_pct,
+            total_trades: 10,
+            ..CostSensitivityPoint::new(commission, slippage)
+        }
+    }
+
+    #[test]
+    fn test_grid_around_and_validate() {
+        let grid = CostSensitivityGrid::around(dec!(0.001), dec!(0));
+        assert_eq!(grid.commission_rates[0], dec!(0));
+        assert_eq!(grid.commission_rates[4], dec!(0.008));
+        // 슬리피지 0이면 기본 0.05% 기준
+        assert_eq!(grid.slippage_rates[1], dec!(0.0005));
+        assert_eq!(grid.combinations().len(), 25);
+        assert!(grid.validate().is_ok());
+
+        let normalized = CostSensitivityGrid {
+            commission_rates: vec![dec!(0.002), dec!(0.0010), dec!(0.001)],
+            slippage_rates: vec![dec!(0)],
+        }
+        .normalized();
+        assert_eq!(normalized.commission_rates, vec![dec!(0.001), dec!(0.002)]);
+
+        let too_many = CostSensitivityGrid {
+            commission_rates: vec![dec!(0.001); 10],
+            slippage_rates: vec![dec!(0.001); 10],
+        };
+        assert!(too_many.validate().is_err());
+
+        let out_of_range = CostSensitivityGrid {
+            commission_rates: vec![dec!(0.2)],
+            slippage_rates: vec![dec!(0)],
+        };
+        assert!(out_of_range.validate().is_err());
+    }
+
+    #[test]
+    fn test_analyze_cost_sensitivity() {
+        // 편도 비용 1bp당 수익률 0.5%p 감소, 비용 0에서 10% → 20bp에서 손익분기
+        let grid = CostSensitivityGrid {
+            commission_rates: vec![dec!(0), dec!(0.001)],
+            slippage_rates: vec![dec!(0), dec!(0.0005)],
+        };
+        let points = grid
+            .combinations()
+            .into_iter()
+            .rev()
+            .map(|(c, s)| {
+                let bps = ((c + s) * dec!(10000)).to_f64().unwrap();
+                point(c, s, 10.0 - 0.5 * bps)
+            })
+            .collect();
+
+        let result = analyze_cost_sensitivity(dec!(0.001), dec!(0.0005), points);
+        assert_eq!(result.points[0].commission_rate, dec!(0));
+        assert!((result.baseline_cost_bps - 15.0).abs() < 1e-9);
+        assert!((result.return_change_per_bp.unwrap() + 0.5).abs() < 1e-9);
+        assert!((result.breakeven_cost_bps.unwrap() - 20.0).abs() < 1e-9);
+        assert!((result.cost_headroom_bps.unwrap() - 5.0).abs() < 1e-9);
+        assert!((result.profitable_pct - 100.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_analyze_without_cost_impact() {
+        // 거래가 없어 비용과 무관한 결과
+        let points = vec![
+            point(dec!(0), dec!(0), 0.0),
+            point(dec!(0.001), dec!(0), 0.0),
+        ];
+        let result = analyze_cost_sensitivity(dec!(0.001), dec!(0), points);
+        assert_eq!(result.return_change_per_bp, Some(0.0));
+        assert_eq!(result.breakeven_cost_bps, None);
+        assert_eq!(result.profitable_pct, 0.0);
+
+        // 단일 조합은 회귀 불가
+        let result = analyze_cost_sensitivity(
+            dec!(0.001),
+            dec!(0),
+            vec![point(dec!(0.001), dec!(0), -3.0)],
+        );
+        assert_eq!(result.return_change_per_bp, None);
+    }
+}
@@ -0,0 +1,172 @@
+//! 장중(분봉) 백테스트 거래 세션
+//!
+//! 분봉 백테스트는 거래소 정규장 시간 안에서만 시뮬레이션합니다.
+//!
+//! - 정규장 밖의 캔들은 전략에 전달하지 않습니다.
+//! - 신호는 같은 종목의 다음 캔들 시가로 체결합니다 (Look-Ahead Bias 방지).
+//! - 세션 마감 시 대기 중인 진입 신호는 취소하고, 청산 신호는 마감가로 체결합니다.
+//! - `flatten_at_close`가 설정되면 마감 시 남은 포지션을 모두 청산합니다 (데이트레이딩).
+
+use chrono::{Duration, NaiveDate, NaiveTime};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+use trader_core::Kline;
+
+/// 장중 거래 세션 (거래소 현지 시각 기준)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IntradaySession {
+    /// 거래소 시간대
+    pub timezone: Tz,
+    /// 정규장 시작 시각
+    pub open: NaiveTime,
+    /// 정규장 마감 시각
+    pub close: NaiveTime,
+    /// 세션 마감 시 미청산 포지션 청산 여부
+    #[serde(default)]
+    pub flatten_at_close: bool,
+}
+
+impl IntradaySession {
+    /// 새 세션을 생성합니다.
+    pub fn new(timezone: Tz, open: NaiveTime, close: NaiveTime) -> Self {
+        Self {
+            timezone,
+            open,
+            close,
+            flatten_at_close: false,
+        }
+    }
+
+    /// 국내 주식 정규장 (09:00–15:30 KST)
+    pub fn krx() -> Self {
+        Self::new(
+            chrono_tz::Asia::Seoul,
+            NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(15, 30, 0).unwrap(),
+        )
+    }
+
+    /// 미국 주식 정규장 (09:30–16:00 ET, 서머타임 반영)
+    pub fn us_regular() -> Self {
+        Self::new(
+            chrono_tz::America::New_York,
+            NaiveTime::from_hms_opt(9, 30, 0).unwrap(),
+            NaiveTime::from_hms_opt(16, 0, 0).unwrap(),
+        )
+    }
+
+    /// 티커로 세션 추론
+    ///
+    /// 6자리 숫자(국내 종목, `.KS`/`.KQ` 접미사 허용)는 KRX, 나머지 주식은 미국 정규장입니다.
+    /// 24시간 거래되는 암호화폐 페어(`BTC/USDT`)는 세션이 없으므로 None을 반환합니다.
+    pub fn for_ticker(ticker: &str) -> Option<Self> {
+        if ticker.contains('/') {
+            return None;
+        }
+        let code = ticker.split('.').next().unwrap_or(ticker);
+        if code.len() == 6 && code.chars().all(|c| c.is_ascii_digit()) {
+            Some(Self::krx())
+        } else {
+            Some(Self::us_regular())
+        }
+    }
+
+    /// 세션 마감 시 청산 여부 설정
+    pub fn with_flatten_at_close(mut self, flatten: bool) -> Self {
+        self.flatten_at_close = flatten;
+        self
+    }
+
+    /// 설정 검증
+    pub fn validate(&self) -> Result<(), String> {
+        if self.open >= self.close {
+            return Err(format!(
+                "세션 시작({})은 마감({})보다 빨라야 합니다",
+                self.open, self.close
+            ));
+        }
+        Ok(())
+    }
+
+    /// 캔들이 속한 세션 거래일 (현지 날짜)
+    pub fn session_date(&self, kline: &Kline) -> NaiveDate {
+        kline.open_time.with_timezone(&self.timezone).date_naive()
+    }
+
+    /// 캔들이 정규장 안에서 시작하는지 확인합니다.
+    pub fn contains(&self, kline: &Kline) -> bool {
+        let local = kline.open_time.with_timezone(&self.timezone).time();
+        local >= self.open && local < self.close
+    }
+
+    /// 세션의 마지막 캔들인지 확인합니다 (다음 캔들 시작이 마감 이후).
+    pub fn is_closing_bar(&self, kline: &Kline) -> bool {
+        let step = Duration::from_std(kline.timeframe.duration()).unwrap_or(Duration::minutes(1));
+        let next_open = (kline.open_time + step).with_timezone(&self.timezone);
+        next_open.date_naive() != self.session_date(kline) || next_open.time() >= self.close
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+    use rust_decimal_macros::dec;
+    use trader_core::Timeframe;
+
+    fn bar(hour: u32, minute: u32) -> Kline {
+        // KST = UTC+9
+        let open_time =
+            Utc.with_ymd_and_hms(2026, 3, 9, hour, minute, 0).unwrap() - Duration::hours(9);
+        Kline::new(
+            "005930".to_string(),
+            Timeframe::M5,
+            open_time,
+            dec!(100),
+            dec!(101),
+            dec!(99),
+            dec!(100),
+            dec!(1000),
+            open_time + Duration::minutes(5),
+        )
+    }
+
+    #[test]
+    fn test_krx_session_bounds() {
+        let session = IntradaySession::for_ticker("005930").unwrap();
+        assert_eq!(session, IntradaySession::krx());
+        assert!(session.validate().is_ok());
+
+        assert!(!session.contains(&bar(8, 55)));
+        assert!(session.contains(&bar(9, 0)));
+        assert!(session.contains(&bar(15, 25)));
+        assert!(!session.contains(&bar(15, 30)));
+
+        assert!(!session.is_closing_bar(&bar(15, 20)));
+        assert!(session.is_closing_bar(&bar(15, 25)));
+        assert_eq!(
+            session.session_date(&bar(9, 0)),
+            NaiveDate::from_ymd_opt(2026, 3, 9).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_for_ticker() {
+        assert_eq!(
+            IntradaySession::for_ticker("AAPL"),
+            Some(IntradaySession::us_regular())
+        );
+        assert_eq!(
+            IntradaySession::for_ticker("069500.KS"),
+            Some(IntradaySession::krx())
+        );
+        assert!(IntradaySession::for_ticker("BTC/USDT").is_none());
+
+        let inverted = IntradaySession::new(
+            chrono_tz::Asia::Seoul,
+            NaiveTime::from_hms_opt(15, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+        );
+        assert!(inverted.validate().is_err());
+    }
+}
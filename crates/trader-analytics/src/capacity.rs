@@ -0,0 +1,382 @@
+//! 전략 운용 용량(Capacity) 추정.
+//!
+//! 종목별 평균 거래대금(ADV), ADV 참여율 한도, 전략의 일평균 회전율,
+//! 슬리피지 모델로 전략이 시장 충격 없이 운용할 수 있는 최대 자본을 추정합니다.
+//!
+//! # 계산
+//!
+//! 자본 `C`, 일 회전율 `τ`(자본 대비 일 거래대금), 종목 비중 `w`일 때
+//! 종목의 일 거래대금은 `C × τ × w`입니다. 이 값이 다음 두 한도를 넘지 않아야 합니다.
+//!
+//! - ADV 참여율 한도: `참여율 × ADV`
+//! - 슬리피지 한도: 슬리피지 모델상 비율이 `max_slippage_rate`가 되는 주문 금액
+//!
+//! 종목별 용량은 `한도 / (τ × w)`이고, 전략 용량은 그 최솟값입니다.
+//!
+//! # 예시
+//!
+//! ```rust,ignore
+//! use trader_analytics::capacity::{estimate_capacity, CapacityConfig, SymbolLiquidityInput};
+//!
+//! let estimate = estimate_capacity(&symbols, dec!(0.2), Some(allocated), &CapacityConfig::default());
+//! if let Some(warning) = &estimate.warning {
+//!     tracing::warn!("{}", warning);
+//! }
+//! ```
+
+use rust_decimal::{Decimal, RoundingStrategy};
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+
+use crate::backtest::SlippageModel;
+
+/// 용량 추정 설정.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CapacityConfig {
+    /// 허용 최대 슬리피지 비율 (기본값: 0.3%)
+    pub max_slippage_rate: Decimal,
+    /// 경고를 시작하는 용량 대비 할당 자본 비율 (기본값: 80%)
+    pub warning_utilization: Decimal,
+    /// 주문 크기별 슬리피지 모델 (기본값: 선형 시장 충격 0.03% + 10%)
+    pub slippage_model: SlippageModel,
+}
+
+impl Default for CapacityConfig {
+    fn default() -> Self {
+        Self {
+            max_slippage_rate: dec!(0.003),
+            warning_utilization: dec!(0.8),
+            slippage_model: SlippageModel::linear(dec!(0.0003), dec!(0.1)),
+        }
+    }
+}
+
+/// 종목별 유동성 입력.
+#[derive(Debug, Clone)]
+pub struct SymbolLiquidityInput {
+    /// 종목 티커
+    pub ticker: String,
+    /// 최근 평균 일 거래대금
+    pub avg_daily_amount: Decimal,
+    /// ADV 대비 최대 참여율 (예: 0.05 = 5%)
+    pub max_adv_fraction: Decimal,
+    /// 전략 내 거래 비중 (합계 1 기준으로 정규화)
+    pub weight: Decimal,
+}
+
+/// 종목별 용량 추정 결과.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SymbolCapacity {
+    /// 종목 티커
+    pub ticker: String,
+    /// 정규화된 거래 비중
+    pub weight: Decimal,
+    /// 최근 평균 일 거래대금
+    pub avg_daily_amount: Decimal,
+    /// 적용한 ADV 참여율
+    pub max_adv_fraction: Decimal,
+    /// 일 최대 거래대금 (참여율/슬리피지 한도 중 작은 값)
+    pub max_daily_trade_value: Decimal,
+    /// 슬리피지 한도가 참여율 한도보다 작은지 여부
+    pub slippage_bound: bool,
+    /// 종목 기준 전략 용량 (None이면 제한 없음)
+    pub capacity: Option<Decimal>,
+    /// 할당 자본 기준 예상 슬리피지 비율
+    pub slippage_rate_at_allocation: Option<Decimal>,
+}
+
+/// 용량 대비 할당 자본 상태.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CapacityStatus {
+    /// 용량 여유
+    Ok,
+    /// 용량에 근접 (경고 비율 이상)
+    Warning,
+    /// 용량 초과
+    Exceeded,
+    /// 유동성/할당 자본 정보 부족으로 판단 불가
+    Unknown,
+}
+
+/// 전략 용량 추정 결과.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CapacityEstimate {
+    /// 추정 용량 (None이면 유동성 데이터 부족 또는 제한 없음)
+    pub capacity: Option<Decimal>,
+    /// 적용한 일 회전율 (자본 대비 일 거래대금)
+    pub daily_turnover: Decimal,
+    /// 용량을 결정한 종목
+    pub binding_ticker: Option<String>,
+    /// 할당 자본
+    pub allocated_capital: Option<Decimal>,
+    /// 용량 대비 할당 자본 비율
+    pub utilization: Option<Decimal>,
+    /// 상태
+    pub status: CapacityStatus,
+    /// 경고 문구 (Warning/Exceeded일 때)
+    pub warning: Option<String>,
+    /// 종목별 결과
+    pub symbols: Vec<SymbolCapacity>,
+}
+
+/// 슬리피지 비율이 `max_rate` 이하로 유지되는 최대 주문 금액.
+///
+/// 주문 크기와 무관한 모델(Fixed, VolatilityBased)은 None(제한 없음)을 반환합니다.
+pub fn max_order_value_within(
+    model: &SlippageModel,
+    max_rate: Decimal,
+    avg_daily_amount: Decimal,
+) -> Option<Decimal> {
+    match model {
+        SlippageModel::Linear { base, impact } => {
+            if *impact <= Decimal::ZERO {
+                None
+            } else if max_rate <= *base {
+                Some(Decimal::ZERO)
+            } else {
+                Some((max_rate - *base) / *impact * avg_daily_amount)
+            }
+        }
+        SlippageModel::Tiered { tiers } => {
+            // 앞 구간부터 허용 비율 이하인 구간의 임계값까지만 허용
+            let mut limit = Some(Decimal::ZERO);
+            for tier in tiers {
+                if tier.rate > max_rate {
+                    return limit;
+                }
+                limit = Some(tier.threshold);
+            }
+            // 마지막 구간 초과 금액도 마지막 비율이 적용되므로 제한 없음
+            None
+        }
+        SlippageModel::Fixed { .. } | SlippageModel::VolatilityBased { .. } => None,
+    }
+}
+
+/// 주문 금액의 예상 슬리피지 비율.
+fn slippage_rate_for(
+    model: &SlippageModel,
+    order_value: Decimal,
+    avg_daily_amount: Decimal,
+) -> Decimal {
+    match model {
+        SlippageModel::Linear { base, impact } if avg_daily_amount > Decimal::ZERO => {
+            *base + order_value / avg_daily_amount * *impact
+        }
+        _ => model.calculate_rate(Decimal::ZERO, order_value, None),
+    }
+}
+
+/// 전략 운용 용량 추정.
+///
+/// # Arguments
+///
+/// * `symbols` - 종목별 유동성과 거래 비중 (비중은 합계 1로 정규화)
+/// * `daily_turnover` - 자본 대비 일 거래대금 비율 (예: 0.2 = 자본의 20%를 매일 거래)
+/// * `allocated_capital` - 전략 할당 자본 (없으면 상태는 Unknown)
+/// * `config` - 용량 추정 설정
+pub fn estimate_capacity(
+    symbols: &[SymbolLiquidityInput],
+    daily_turnover: Decimal,
+    allocated_capital: Option<Decimal>,
+    config: &CapacityConfig,
+) -> CapacityEstimate {
+    let total_weight: Decimal = symbols.iter().map(|s| s.weight.max(Decimal::ZERO)).sum();
+
+    let per_symbol: Vec<SymbolCapacity> = symbols
+        .iter()
+        .map(|s| {
+            let weight = if total_weight > Decimal::ZERO {
+                s.weight.max(Decimal::ZERO) / total_weight
+            } else {
+                Decimal::ZERO
+            };
+            let adv_limit = s.avg_daily_amount.max(Decimal::ZERO) * s.max_adv_fraction;
+            let slippage_limit = max_order_value_within(
+                &config.slippage_model,
+                config.max_slippage_rate,
+                s.avg_daily_amount,
+            );
+            let max_daily_trade_value = slippage_limit.map_or(adv_limit, |l| l.min(adv_limit));
+
+            let daily_share = daily_turnover * weight;
+            let capacity = (daily_share > Decimal::ZERO)
+                .then(|| round_amount(max_daily_trade_value / daily_share));
+            let slippage_rate_at_allocation = allocated_capital.map(|capital| {
+                slippage_rate_for(
+                    &config.slippage_model,
+                    capital * daily_share,
+                    s.avg_daily_amount,
+                )
+                .round_dp(6)
+            });
+
+            SymbolCapacity {
+                ticker: s.ticker.clone(),
+                weight: weight.round_dp(4),
+                avg_daily_amount: round_amount(s.avg_daily_amount),
+                max_adv_fraction: s.max_adv_fraction,
+                max_daily_trade_value: round_amount(max_daily_trade_value),
+                slippage_bound: slippage_limit.is_some_and(|l| l < adv_limit),
+                capacity,
+                slippage_rate_at_allocation,
+            }
+        })
+        .collect();
+
+    let binding = per_symbol
+        .iter()
+        .filter_map(|s| s.capacity.map(|c| (c, s.ticker.as_str())))
+        .min_by(|a, b| a.0.cmp(&b.0));
+    let capacity = binding.map(|(c, _)| c);
+    let binding_ticker = binding.map(|(_, t)| t.to_string());
+
+    let (utilization, status) = match (allocated_capital, capacity) {
+        (Some(allocated), Some(cap)) if cap > Decimal::ZERO => {
+            let utilization = (allocated / cap).round_dp(4);
+            let status = if utilization >= Decimal::ONE {
+                CapacityStatus::Exceeded
+            } else if utilization >= config.warning_utilization {
+                CapacityStatus::Warning
+            } else {
+                CapacityStatus::Ok
+            };
+            (Some(utilization), status)
+        }
+        // 유동성 한도가 0이면 어떤 할당도 초과
+        (Some(allocated), Some(_)) if allocated > Decimal::ZERO => (None, CapacityStatus::Exceeded),
+        _ => (None, CapacityStatus::Unknown),
+    };
+
+    let warning = match (status, capacity, binding_ticker.as_deref()) {
+        (CapacityStatus::Exceeded, Some(cap), Some(ticker)) => Some(format!(
+            "Allocated capital exceeds estimated capacity {} (limited by {} liquidity)",
+            cap, ticker
+        )),
+        (CapacityStatus::Warning, Some(cap), Some(ticker)) => Some(format!(
+            "Allocated capital is at {}% of estimated capacity {} (limited by {} liquidity)",
+            (utilization.unwrap_or_default() * dec!(100)).round_dp(1),
+            cap,
+            ticker
+        )),
+        _ => None,
+    };
+
+    CapacityEstimate {
+        capacity,
+        daily_turnover: daily_turnover.round_dp(4),
+        binding_ticker,
+        allocated_capital,
+        utilization,
+        status,
+        warning,
+        symbols: per_symbol,
+    }
+}
+
+/// 금액 표시용 반올림 (정수 단위 내림).
+fn round_amount(value: Decimal) -> Decimal {
+    value.round_dp_with_strategy(0, RoundingStrategy::ToZero)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn symbol(ticker: &str, adv: Decimal, weight: Decimal) -> SymbolLiquidityInput {
+        SymbolLiquidityInput {
+            ticker: ticker.to_string(),
+            avg_daily_amount: adv,
+            max_adv_fraction: dec!(0.05),
+            weight,
+        }
+    }
+
+    #[test]
+    fn test_max_order_value_within() {
+        let linear = SlippageModel::linear(dec!(0.0003), dec!(0.1));
+        // (0.003 - 0.0003) / 0.1 = 2.7% of ADV
+        assert_eq!(
+            max_order_value_within(&linear, dec!(0.003), dec!(1_000_000)),
+            Some(dec!(27_000))
+        );
+        assert_eq!(
+            max_order_value_within(&linear, dec!(0.0001), dec!(1_000_000)),
+            Some(Decimal::ZERO)
+        );
+        assert_eq!(
+            max_order_value_within(&SlippageModel::fixed(dec!(0.001)), dec!(0.003), dec!(1)),
+            None
+        );
+
+        let tiered = SlippageModel::tiered(vec![
+            (dec!(100_000), dec!(0.001)),
+            (dec!(1_000_000), dec!(0.002)),
+            (dec!(10_000_000), dec!(0.005)),
+        ]);
+        assert_eq!(
+            max_order_value_within(&tiered, dec!(0.003), dec!(1)),
+            Some(dec!(1_000_000))
+        );
+        assert_eq!(max_order_value_within(&tiered, dec!(0.01), dec!(1)), None);
+    }
+
+    #[test]
+    fn test_estimate_capacity_binding_symbol() {
+        let config = CapacityConfig {
+            slippage_model: SlippageModel::fixed(dec!(0.0005)),
+            ..Default::default()
+        };
+        let symbols = vec![
+            symbol("LIQUID", dec!(100_000_000), dec!(1)),
+            symbol("THIN", dec!(1_000_000), dec!(1)),
+        ];
+
+        // 회전율 20%, 비중 50% → 일 거래대금 = C × 0.1
+        // THIN: 1,000,000 × 5% / 0.1 = 500,000
+        let estimate = estimate_capacity(&symbols, dec!(0.2), Some(dec!(450_000)), &config);
+        assert_eq!(estimate.capacity, Some(dec!(500_000)));
+        assert_eq!(estimate.binding_ticker.as_deref(), Some("THIN"));
+        assert_eq!(estimate.utilization, Some(dec!(0.9)));
+        assert_eq!(estimate.status, CapacityStatus::Warning);
+        assert!(estimate.warning.unwrap().contains("THIN"));
+
+        let estimate = estimate_capacity(&symbols, dec!(0.2), Some(dec!(100_000)), &config);
+        assert_eq!(estimate.status, CapacityStatus::Ok);
+        assert!(estimate.warning.is_none());
+
+        let estimate = estimate_capacity(&symbols, dec!(0.2), Some(dec!(600_000)), &config);
+        assert_eq!(estimate.status, CapacityStatus::Exceeded);
+
+        let estimate = estimate_capacity(&symbols, dec!(0.2), None, &config);
+        assert_eq!(estimate.status, CapacityStatus::Unknown);
+    }
+
+    #[test]
+    fn test_estimate_capacity_slippage_bound() {
+        // 기본 선형 모델: 슬리피지 한도 2.7% < 참여율 5%
+        let estimate = estimate_capacity(
+            &[symbol("AAA", dec!(1_000_000), dec!(1))],
+            dec!(1),
+            Some(dec!(27_000)),
+            &CapacityConfig::default(),
+        );
+        let aaa = &estimate.symbols[0];
+        assert!(aaa.slippage_bound);
+        assert_eq!(aaa.max_daily_trade_value, dec!(27_000));
+        assert_eq!(estimate.capacity, Some(dec!(27_000)));
+        assert_eq!(estimate.status, CapacityStatus::Exceeded);
+        assert_eq!(aaa.slippage_rate_at_allocation, Some(dec!(0.003)));
+
+        // 거래가 없는 전략은 용량 제한 없음
+        let estimate = estimate_capacity(
+            &[symbol("AAA", dec!(1_000_000), dec!(1))],
+            Decimal::ZERO,
+            Some(dec!(1_000_000)),
+            &CapacityConfig::default(),
+        );
+        assert_eq!(estimate.capacity, None);
+        assert_eq!(estimate.status, CapacityStatus::Unknown);
+    }
+}
@@ -7,6 +7,8 @@
 //!
 //! - **Pearson 상관계수**: 두 종목 간 선형 상관관계 측정
 //! - **상관행렬**: 여러 종목 간 상관관계를 N×N 행렬로 표현
+//! - **레짐 변화 감지**: 단기/장기 롤링 상관계수 비교로 상관관계 붕괴/급등 판정
+//! - **베타**: 벤치마크 수익률 대비 민감도 (헤지 비율 산정)
 //!
 //! # 예시
 //!
@@ -20,10 +22,11 @@
 //! println!("상관계수: {:.4}", corr.unwrap_or(0.0));
 //! ```
 
+use chrono::NaiveDate;
 use rust_decimal::prelude::ToPrimitive;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 /// 상관행렬 결과.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -207,6 +210,140 @@ pub fn calculate_correlation_matrix_decimal(
     calculate_correlation_matrix(&prices_f64, symbols)
 }
 
+/// 상관관계 레짐 변화 유형.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CorrelationShiftKind {
+    /// 상관관계 붕괴 (단기 상관이 장기 대비 약해지거나 부호가 뒤집힘)
+    Breakdown,
+    /// 상관관계 급등 (단기 상관이 장기 대비 같은 방향으로 강해짐)
+    Spike,
+}
+
+impl CorrelationShiftKind {
+    /// DB/API 표기 문자열.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Breakdown => "breakdown",
+            Self::Spike => "spike",
+        }
+    }
+}
+
+/// 단기/장기 롤링 상관계수 비교 결과.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CorrelationShift {
+    /// 변화 유형
+    pub kind: CorrelationShiftKind,
+    /// 단기 창 상관계수
+    pub short_corr: f64,
+    /// 장기 창 상관계수
+    pub long_corr: f64,
+    /// 변화량 (단기 - 장기)
+    pub change: f64,
+}
+
+/// 날짜별 종가를 날짜별 수익률로 변환.
+///
+/// 각 날짜의 수익률은 직전 관측일 종가 대비 변화율입니다.
+/// 거래일이 다른 자산끼리 비교할 때는 [`align_returns`]로 공통 날짜만 남깁니다.
+pub fn closes_to_dated_returns(closes: &BTreeMap<NaiveDate, f64>) -> BTreeMap<NaiveDate, f64> {
+    closes
+        .iter()
+        .zip(closes.iter().skip(1))
+        .filter(|((_, prev), _)| **prev != 0.0)
+        .map(|((_, prev), (date, curr))| (*date, (curr - prev) / prev))
+        .collect()
+}
+
+/// 두 날짜별 수익률 시계열에서 공통 날짜만 남겨 정렬된 벡터 쌍을 반환.
+pub fn align_returns(
+    a: &BTreeMap<NaiveDate, f64>,
+    b: &BTreeMap<NaiveDate, f64>,
+) -> (Vec<f64>, Vec<f64>) {
+    a.iter()
+        .filter_map(|(date, ra)| b.get(date).map(|rb| (*ra, *rb)))
+        .unzip()
+}
+
+/// 정렬된 수익률 쌍의 최근 `window`개 구간 상관계수.
+///
+/// 데이터가 `window`보다 짧으면 None을 반환합니다.
+pub fn trailing_correlation(x: &[f64], y: &[f64], window: usize) -> Option<f64> {
+    if window < 2 || x.len() != y.len() || x.len() < window {
+        return None;
+    }
+    let start = x.len() - window;
+    calculate_correlation(&x[start..], &y[start..])
+}
+
+/// 벤치마크 대비 베타 계산 (공분산 / 벤치마크 분산).
+///
+/// `asset`과 `benchmark`는 [`align_returns`]로 정렬된 같은 길이의 수익률이어야 합니다.
+/// 벤치마크 변동이 없거나 데이터가 부족하면 None을 반환합니다.
+pub fn calculate_beta(asset: &[f64], benchmark: &[f64]) -> Option<f64> {
+    if asset.len() != benchmark.len() || asset.len() < 2 {
+        return None;
+    }
+
+    let n = asset.len() as f64;
+    let mean_a = asset.iter().sum::<f64>() / n;
+    let mean_b = benchmark.iter().sum::<f64>() / n;
+
+    let (cov, var_b) = asset
+        .iter()
+        .zip(benchmark)
+        .fold((0.0, 0.0), |(cov, var), (a, b)| {
+            let db = b - mean_b;
+            (cov + (a - mean_a) * db, var + db * db)
+        });
+
+    if var_b == 0.0 {
+        return None;
+    }
+    Some(cov / var_b)
+}
+
+/// 정렬된 수익률 쌍의 최근 `window`개 구간 베타.
+///
+/// 데이터가 `window`보다 짧으면 None을 반환합니다.
+pub fn trailing_beta(asset: &[f64], benchmark: &[f64], window: usize) -> Option<f64> {
+    if window < 2 || asset.len() != benchmark.len() || asset.len() < window {
+        return None;
+    }
+    let start = asset.len() - window;
+    calculate_beta(&asset[start..], &benchmark[start..])
+}
+
+/// 단기/장기 상관계수 차이로 레짐 변화 판정.
+///
+/// 변화량 절대값이 `threshold` 미만이면 None입니다.
+/// 단기 상관의 크기가 줄었거나 부호가 뒤집혔으면 붕괴, 같은 방향으로 커졌으면 급등입니다.
+pub fn detect_correlation_shift(
+    short_corr: f64,
+    long_corr: f64,
+    threshold: f64,
+) -> Option<CorrelationShift> {
+    let change = short_corr - long_corr;
+    if change.abs() < threshold {
+        return None;
+    }
+
+    let sign_flipped = short_corr * long_corr < 0.0;
+    let kind = if sign_flipped || short_corr.abs() < long_corr.abs() {
+        CorrelationShiftKind::Breakdown
+    } else {
+        CorrelationShiftKind::Spike
+    };
+
+    Some(CorrelationShift {
+        kind,
+        short_corr,
+        long_corr,
+        change,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -338,4 +475,68 @@ mod tests {
         assert!(corr.is_some());
         assert!((corr.unwrap() - 1.0).abs() < 0.001);
     }
+
+    #[test]
+    fn test_align_returns_uses_common_dates() {
+        let d = |day| NaiveDate::from_ymd_opt(2025, 1, day).unwrap();
+        let a: BTreeMap<NaiveDate, f64> =
+            [(d(2), 100.0), (d(3), 110.0), (d(6), 99.0), (d(7), 99.0)]
+                .into_iter()
+                .collect();
+        let b: BTreeMap<NaiveDate, f64> = [(d(2), 50.0), (d(3), 55.0), (d(4), 60.0), (d(7), 54.0)]
+            .into_iter()
+            .collect();
+
+        let ra = closes_to_dated_returns(&a);
+        let rb = closes_to_dated_returns(&b);
+        assert_eq!(ra.len(), 3);
+        assert!((ra[&d(3)] - 0.1).abs() < 1e-9);
+
+        // 공통 날짜: 3일, 7일
+        let (x, y) = align_returns(&ra, &rb);
+        assert_eq!(x.len(), 2);
+        assert!((y[1] - (-0.1)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_trailing_correlation_window() {
+        let x = vec![0.01, -0.02, 0.03, 0.01, -0.01, 0.02];
+        let y = vec![-0.01, 0.02, 0.03, 0.01, -0.01, 0.02];
+        assert!(trailing_correlation(&x, &y, 7).is_none());
+        let recent = trailing_correlation(&x, &y, 4).unwrap();
+        assert!((recent - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_beta() {
+        let benchmark = vec![0.01, -0.02, 0.015, 0.005, -0.01];
+        let levered: Vec<f64> = benchmark.iter().map(|r| r * 1.5 + 0.001).collect();
+        let inverse: Vec<f64> = benchmark.iter().map(|r| -r).collect();
+
+        assert!((calculate_beta(&levered, &benchmark).unwrap() - 1.5).abs() < 1e-9);
+        assert!((calculate_beta(&inverse, &benchmark).unwrap() + 1.0).abs() < 1e-9);
+        assert!((trailing_beta(&levered, &benchmark, 3).unwrap() - 1.5).abs() < 1e-9);
+        assert!(trailing_beta(&levered, &benchmark, 6).is_none());
+
+        // 벤치마크 변동 없음
+        assert!(calculate_beta(&levered, &[0.01; 5]).is_none());
+    }
+
+    #[test]
+    fn test_detect_correlation_shift() {
+        assert!(detect_correlation_shift(0.7, 0.6, 0.3).is_none());
+
+        let breakdown = detect_correlation_shift(0.1, 0.8, 0.3).unwrap();
+        assert_eq!(breakdown.kind, CorrelationShiftKind::Breakdown);
+        assert!((breakdown.change + 0.7).abs() < 1e-9);
+
+        // 부호 반전은 크기가 커져도 붕괴
+        let flipped = detect_correlation_shift(-0.5, 0.3, 0.3).unwrap();
+        assert_eq!(flipped.kind, CorrelationShiftKind::Breakdown);
+
+        let spike = detect_correlation_shift(0.9, 0.2, 0.3).unwrap();
+        assert_eq!(spike.kind, CorrelationShiftKind::Spike);
+        let negative_spike = detect_correlation_shift(-0.8, -0.3, 0.3).unwrap();
+        assert_eq!(negative_spike.kind, CorrelationShiftKind::Spike);
+    }
 }
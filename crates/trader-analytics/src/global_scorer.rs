@@ -21,12 +21,18 @@
 //! 5. 진입 괴리: -4점 (추천가 대비 현재가 괴리 과다)
 //! 6. 저유동성: -4점 (거래대금 하위 20%)
 //! 7. 변동성 스파이크: -2점 (VolZ > 3)
+//!
+//! # 선택 팩터
+//!
+//! - **Flow Momentum (FLOW)**: [`GlobalScorer::with_flow_momentum`]으로 활성화.
+//!   외국인 + 기관 순매수 대금을 기간 거래대금으로 정규화하여 점수화하며,
+//!   투자자별 순매수 데이터가 있는 종목(국내 주식)에만 적용됩니다.
 
 use chrono::Utc;
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 use std::collections::HashMap;
-use trader_core::{types::MarketType, GlobalScoreResult, Kline};
+use trader_core::{types::MarketType, GlobalScoreResult, InvestorFlowSummary, Kline};
 
 use crate::indicators::{
     BollingerBandsParams, IndicatorEngine, IndicatorError, MacdParams, RsiParams, SmaParams,
@@ -65,6 +71,7 @@ struct FactorWeights {
     momentum: f32,          // MOM: 0.10
     liquidity: f32,         // LIQ: 0.13
     technical_balance: f32, // TEC: 0.10
+    flow_momentum: f32,     // FLOW: 0.0 (선택)
 }
 
 impl Default for FactorWeights {
@@ -77,6 +84,7 @@ impl Default for FactorWeights {
             momentum: 0.10,
             liquidity: 0.13,
             technical_balance: 0.10,
+            flow_momentum: 0.0,
         }
     }
 }
@@ -112,6 +120,12 @@ pub struct GlobalScorerParams {
     /// StructuralFeatures를 제공하면 ERS를 계산하여 Momentum 점수에 반영합니다.
     /// None이면 ERS는 0점으로 처리됩니다.
     pub structural_features: Option<StructuralFeatures>,
+
+    /// 투자자별 순매수 요약 (FLOW 팩터용)
+    ///
+    /// Flow Momentum 팩터가 활성화된 경우에만 사용됩니다.
+    /// None이면 FLOW 팩터를 적용하지 않고 기존 7개 팩터 점수를 그대로 사용합니다.
+    pub investor_flow: Option<InvestorFlowSummary>,
 }
 
 
@@ -135,6 +149,15 @@ impl GlobalScorer {
         }
     }
 
+    /// Flow Momentum 팩터를 활성화한 GlobalScorer 생성.
+    ///
+    /// `weight`(0.0 ~ 0.5)만큼 FLOW 점수를 반영하고 기존 7개 팩터 점수는 `1 - weight` 비율로 축소합니다.
+    pub fn with_flow_momentum(weight: f32) -> Self {
+        let mut scorer = Self::new();
+        scorer.weights.flow_momentum = weight.clamp(0.0, 0.5);
+        scorer
+    }
+
     /// 캔들 데이터로부터 Global Score 계산.
     ///
     /// # 인자
@@ -184,6 +207,17 @@ impl GlobalScorer {
             + liq_score * self.weights.liquidity
             + tec_score * self.weights.technical_balance;
 
+        // 선택 팩터: Flow Momentum (데이터가 있는 경우에만 혼합)
+        let flow_score = match params.investor_flow.as_ref() {
+            Some(flow) if self.weights.flow_momentum > 0.0 => {
+                let score = self.calculate_flow_momentum(candles, flow);
+                overall_score = overall_score * (1.0 - self.weights.flow_momentum)
+                    + score * self.weights.flow_momentum;
+                Some(score)
+            }
+            _ => None,
+        };
+
         // 7개 페널티 차감
         let penalties = self.calculate_penalties(candles, current_price, &params)?;
         overall_score = (overall_score - penalties).max(0.0);
@@ -197,6 +231,9 @@ impl GlobalScorer {
         component_scores.insert("momentum".to_string(), mom_score);
         component_scores.insert("liquidity".to_string(), liq_score);
         component_scores.insert("technical_balance".to_string(), tec_score);
+        if let Some(score) = flow_score {
+            component_scores.insert("flow_momentum".to_string(), score);
+        }
         component_scores.insert("penalties".to_string(), -penalties);
 
         // 추천 방향 결정
@@ -508,6 +545,41 @@ impl GlobalScorer {
         Ok(score.min(100.0))
     }
 
+    /// 선택. Flow Momentum (FLOW) 팩터 계산.
+    ///
+    /// **계산식**: (외국인 + 기관 순매수 합계) / 동일 기간 거래대금
+    /// **정규화**: 0% = 50점, +10% 이상 = 100점, -10% 이하 = 0점
+    /// **보너스**: 외국인/기관 중 긴 연속 순매수 일수 × 5점 (최대 15점)
+    ///
+    /// # 반환
+    ///
+    /// 0 ~ 100점
+    fn calculate_flow_momentum(&self, candles: &[Kline], flow: &InvestorFlowSummary) -> f32 {
+        let trading_value: Decimal = candles
+            .iter()
+            .rev()
+            .take(flow.days)
+            .map(|c| c.quote_volume.unwrap_or(c.close * c.volume))
+            .sum();
+
+        if flow.days == 0 || trading_value <= Decimal::ZERO {
+            return 50.0;
+        }
+
+        let ratio = (flow.smart_money_net() / trading_value)
+            .to_string()
+            .parse::<f32>()
+            .unwrap_or(0.0);
+
+        let streak = flow
+            .foreign_buy_streak
+            .max(flow.institution_buy_streak)
+            .min(3) as f32;
+        let score = 50.0 + ratio * 500.0 + streak * 5.0;
+
+        score.clamp(0.0, 100.0)
+    }
+
     /// 7. Technical Balance (TEC) 팩터 계산.
     ///
     /// **구성**:
@@ -873,7 +945,7 @@ mod tests {
 
         let params = GlobalScorerParams::default(); // structural_features = None
         let score = scorer.calculate_momentum(&candles, &params).unwrap();
-        assert!(score >= 0.0 && score <= 100.0);
+        assert!((0.0..=100.0).contains(&score));
     }
 
     #[test]
@@ -945,7 +1017,7 @@ mod tests {
         };
 
         let score = scorer.calculate_momentum(&candles, &params).unwrap();
-        assert!(score >= 0.0 && score <= 100.0);
+        assert!((0.0..=100.0).contains(&score));
     }
 
     #[test]
@@ -958,7 +1030,7 @@ mod tests {
         let score = scorer.calculate_momentum(&candles, &params).unwrap();
 
         // ERS 없어도 동작해야 함 (RSI + MACD만으로 최대 70점)
-        assert!(score >= 0.0 && score <= 70.0);
+        assert!((0.0..=70.0).contains(&score));
     }
 
     #[test]
@@ -993,4 +1065,45 @@ mod tests {
         // StructuralFeatures가 반영되었는지 확인 (confidence 증가)
         assert!(result.confidence > Decimal::ZERO);
     }
+
+    #[test]
+    fn test_flow_momentum_factor() {
+        let candles = create_test_candles(60);
+        let inflow = InvestorFlowSummary {
+            days: 5,
+            foreign_net: dec!(30000000),
+            institution_net: dec!(20000000),
+            foreign_buy_streak: 5,
+            ..Default::default()
+        };
+
+        // 기본 GlobalScorer는 FLOW 팩터 미적용
+        let params = GlobalScorerParams {
+            investor_flow: Some(inflow.clone()),
+            ..Default::default()
+        };
+        let base = GlobalScorer::new().calculate(&candles, params).unwrap();
+        assert!(!base.component_scores.contains_key("flow_momentum"));
+
+        let scorer = GlobalScorer::with_flow_momentum(0.2);
+        let inflow_score = scorer.calculate_flow_momentum(&candles, &inflow);
+        let outflow_score = scorer.calculate_flow_momentum(
+            &candles,
+            &InvestorFlowSummary {
+                days: 5,
+                foreign_net: dec!(-50000000),
+                ..Default::default()
+            },
+        );
+        assert!(inflow_score > 50.0);
+        assert!(outflow_score < 50.0);
+
+        let params = GlobalScorerParams {
+            investor_flow: Some(inflow),
+            ..Default::default()
+        };
+        let result = scorer.calculate(&candles, params).unwrap();
+        assert!(result.component_scores.contains_key("flow_momentum"));
+        assert!(result.overall_score >= Decimal::ZERO && result.overall_score <= dec!(100));
+    }
 }
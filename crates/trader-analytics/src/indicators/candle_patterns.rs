@@ -452,7 +452,7 @@ mod tests {
             dec!(98.0),
             dec!(97.0),
             dec!(96.0),
-            dec!(95.0),
+            dec!(97.0),
         ];
         let high = vec![
             dec!(101.0),
@@ -460,7 +460,7 @@ mod tests {
             dec!(99.0),
             dec!(98.0),
             dec!(97.0),
-            dec!(101.0),
+            dec!(100.5),
         ];
         let low = vec![
             dec!(99.0),
@@ -495,7 +495,7 @@ mod tests {
         let indicator = CandlePatternIndicator::new();
 
         // 강세 장악형: 하락 캔들 후 큰 상승 캔들
-        let open = vec![dec!(100.0), dec!(98.0)];
+        let open = vec![dec!(100.0), dec!(95.0)];
         let high = vec![dec!(100.0), dec!(102.0)];
         let low = vec![dec!(95.0), dec!(94.0)];
         let close = vec![dec!(96.0), dec!(101.0)]; // 이전 캔들 완전 포함
@@ -515,8 +515,8 @@ mod tests {
         // 약세 장악형: 상승 캔들 후 큰 하락 캔들
         let open = vec![dec!(95.0), dec!(102.0)];
         let high = vec![dec!(100.0), dec!(105.0)];
-        let low = vec![dec!(95.0), dec!(94.0)];
-        let close = vec![dec!(100.0), dec!(96.0)]; // 이전 캔들 완전 포함
+        let low = vec![dec!(95.0), dec!(93.0)];
+        let close = vec![dec!(100.0), dec!(94.0)]; // 이전 캔들 완전 포함
 
         let result = indicator
             .detect(&open, &high, &low, &close, CandlePatternParams::default())
@@ -402,7 +402,7 @@ mod tests {
         let features = StructuralFeatures::from_candles(&candles, &engine).unwrap();
         let score = features.breakout_score();
 
-        assert!(score >= 0.0 && score <= 100.0);
+        assert!((0.0..=100.0).contains(&score));
     }
 
     #[test]
@@ -366,8 +366,8 @@ mod tests {
             .unwrap();
 
         // 시그널은 추세 전환 시에만 발생
-        let buy_signals: Vec<_> = result.iter().filter(|r| r.buy_signal).collect();
-        let sell_signals: Vec<_> = result.iter().filter(|r| r.sell_signal).collect();
+        let _buy_signals: Vec<_> = result.iter().filter(|r| r.buy_signal).collect();
+        let _sell_signals: Vec<_> = result.iter().filter(|r| r.sell_signal).collect();
 
         // 매수와 매도 시그널은 동시에 발생하지 않음
         for r in &result {
@@ -385,8 +385,8 @@ mod tests {
         assert_eq!(atr.len(), high.len());
 
         // 처음 9개는 None
-        for i in 0..9 {
-            assert!(atr[i].is_none());
+        for value in atr.iter().take(9) {
+            assert!(value.is_none());
         }
 
         // 10번째부터 값이 있어야 함
@@ -272,14 +272,14 @@ mod tests {
             dec!(100.0),
             dec!(102.0),
             dec!(104.0),
-            dec!(106.0),
-            dec!(108.0), // 가격은 계속 상승
+            dec!(103.0), // 대량 거래 하락
+            dec!(106.0), // 가격은 2봉 전보다 상승
         ];
         let volume = vec![
-            dec!(2000.0),
-            dec!(1500.0), // 거래량은 감소
-            dec!(1000.0),
-            dec!(800.0),
+            dec!(500.0),
+            dec!(500.0),
+            dec!(500.0),
+            dec!(3000.0),
             dec!(500.0),
         ];
 
@@ -295,7 +295,7 @@ pub fn detect_weekly_ma_cross(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use chrono::TimeZone;
+    use chrono::{TimeZone, Weekday};
     use rust_decimal_macros::dec;
 
     fn create_test_daily_klines() -> Vec<Kline> {
@@ -342,7 +342,7 @@ mod tests {
             // 주말 스킵
             let weekday = current_date.weekday();
             if weekday == Weekday::Sat || weekday == Weekday::Sun {
-                current_date = current_date + chrono::Duration::days(1);
+                current_date += chrono::Duration::days(1);
                 continue;
             }
 
@@ -364,7 +364,7 @@ mod tests {
                 num_trades: None,
             });
 
-            current_date = current_date + chrono::Duration::days(1);
+            current_date += chrono::Duration::days(1);
         }
 
         klines
@@ -15,6 +15,7 @@
 
 pub mod analytics_provider_impl;
 pub mod backtest;
+pub mod capacity;
 pub mod correlation;
 pub mod global_scorer;
 pub mod indicators;
@@ -26,6 +27,7 @@ pub mod ml;
 pub mod multi_timeframe_helpers;
 pub mod performance;
 pub mod portfolio;
+pub mod realized_risk;
 pub mod route_state_calculator;
 pub mod sector_rs;
 pub mod seven_factor;
@@ -45,6 +47,11 @@ pub use performance::tracker::{PerformanceEvent, PerformanceThresholds, Performa
 pub use portfolio::charts::{
     ChartPoint, MonthlyReturnCell, PerformanceSummary, PeriodPerformance, PortfolioCharts,
 };
+pub use portfolio::consolidation::{
+    consolidate_by_underlying, underlying_key, FxTable, UnderlyingAsset, UnderlyingGroup,
+    VenueHolding,
+};
+pub use portfolio::downsample::lttb_indices;
 pub use portfolio::equity_curve::{
     DrawdownPeriod, EquityCurve, EquityCurveBuilder, EquityPoint, TimeFrame,
 };
@@ -113,7 +120,7 @@ pub use global_scorer::{GlobalScorer, GlobalScorerError, GlobalScorerParams, Glo
 pub use liquidity_gate::{LiquidityGate, LiquidityLevel};
 
 // 7Factor re-export
-pub use seven_factor::{SevenFactorCalculator, SevenFactorInput, SevenFactorScores};
+pub use seven_factor::{FactorChange, SevenFactorCalculator, SevenFactorInput, SevenFactorScores};
 
 // Sector RS re-export
 pub use sector_rs::{
@@ -133,8 +140,18 @@ pub use volume_profile::{
 
 // Correlation re-export
 pub use correlation::{
-    calculate_correlation, calculate_correlation_matrix, calculate_correlation_matrix_decimal,
-    CorrelationMatrix,
+    align_returns, calculate_correlation, calculate_correlation_matrix,
+    calculate_correlation_matrix_decimal, closes_to_dated_returns, detect_correlation_shift,
+    trailing_correlation, CorrelationMatrix, CorrelationShift, CorrelationShiftKind,
+};
+
+// Realized risk re-export
+pub use realized_risk::{compute_symbol_risk, realized_volatility_pct};
+
+// Capacity re-export
+pub use capacity::{
+    estimate_capacity, CapacityConfig, CapacityEstimate, CapacityStatus, SymbolCapacity,
+    SymbolLiquidityInput,
 };
 
 // AnalyticsProvider 구현체 re-export
@@ -162,6 +179,7 @@ pub use ml::{
     ChartPatternType,
     // 예측
     ConfidenceLevel,
+    ExecutionProvider,
     // 피처 추출
     FeatureConfig,
     FeatureExtractor,
@@ -4,7 +4,7 @@
 
 use rust_decimal::prelude::ToPrimitive;
 use rust_decimal::Decimal;
-use trader_core::{Kline, MarketRegime};
+use trader_core::{CryptoMetrics, CryptoMetricsBias, Kline, MarketRegime};
 
 use crate::indicators::IndicatorEngine;
 use crate::IndicatorError;
@@ -88,6 +88,36 @@ impl MarketRegimeCalculator {
         })
     }
 
+    /// 암호화폐 지표를 반영한 MarketRegime 계산
+    ///
+    /// 가격 기반 레짐을 계산한 뒤 [`Self::adjust_for_crypto_metrics`]로 보정합니다.
+    pub fn calculate_with_crypto_metrics(
+        &self,
+        candles: &[Kline],
+        metrics: Option<&CryptoMetrics>,
+    ) -> Result<MarketRegimeResult, IndicatorError> {
+        let mut result = self.calculate(candles)?;
+        if let Some(metrics) = metrics {
+            result.regime = Self::adjust_for_crypto_metrics(result.regime, metrics);
+        }
+        Ok(result)
+    }
+
+    /// 암호화폐 온체인/파생상품 지표로 레짐 보정
+    ///
+    /// 보조 지표는 위험 필터로만 사용하며 레짐을 상향하지 않습니다.
+    /// - 약세 편향: StrongUptrend → Correction, BottomBounce → Downtrend
+    pub fn adjust_for_crypto_metrics(
+        regime: MarketRegime,
+        metrics: &CryptoMetrics,
+    ) -> MarketRegime {
+        match (metrics.bias(), regime) {
+            (CryptoMetricsBias::Bearish, MarketRegime::StrongUptrend) => MarketRegime::Correction,
+            (CryptoMetricsBias::Bearish, MarketRegime::BottomBounce) => MarketRegime::Downtrend,
+            _ => regime,
+        }
+    }
+
     /// 60일 상대강도 계산
     ///
     /// rel_60d_% = (현재가 / 60일전 가격 - 1) * 100
@@ -242,7 +272,7 @@ mod tests {
     use chrono::Utc;
 
     fn create_test_klines(count: usize, base_price: f64, trend: f64) -> Vec<Kline> {
-        use trader_core::{MarketType, Timeframe};
+        use trader_core::Timeframe;
 
         (0..count)
             .map(|i| {
@@ -366,4 +396,33 @@ mod tests {
         // 상승 추세이므로 양수
         assert!(slope > 0.0);
     }
+
+    #[test]
+    fn test_adjust_for_crypto_metrics() {
+        let mut metrics = CryptoMetrics::new("BTC", Utc::now());
+        metrics.exchange_netflow = Some(5000.0);
+        metrics.perp_basis_pct = Some(0.9);
+
+        assert_eq!(
+            MarketRegimeCalculator::adjust_for_crypto_metrics(
+                MarketRegime::StrongUptrend,
+                &metrics
+            ),
+            MarketRegime::Correction
+        );
+        assert_eq!(
+            MarketRegimeCalculator::adjust_for_crypto_metrics(MarketRegime::Sideways, &metrics),
+            MarketRegime::Sideways
+        );
+
+        // 중립 편향은 레짐을 유지
+        let neutral = CryptoMetrics::new("BTC", Utc::now());
+        assert_eq!(
+            MarketRegimeCalculator::adjust_for_crypto_metrics(
+                MarketRegime::StrongUptrend,
+                &neutral
+            ),
+            MarketRegime::StrongUptrend
+        );
+    }
 }
@@ -64,7 +64,9 @@ pub use error::{MlError, MlResult};
 pub use features::{FeatureConfig, FeatureExtractor};
 #[cfg(feature = "ml")]
 pub use predictor::OnnxPredictor;
-pub use predictor::{MockPredictor, PredictionResult, PredictorConfig, PricePredictor};
+pub use predictor::{
+    ExecutionProvider, MockPredictor, PredictionResult, PredictorConfig, PricePredictor,
+};
 pub use types::{ConfidenceLevel, FeatureVector, Prediction, PredictionDirection};
 
 // 패턴 인식 타입 재내보내기
@@ -3,16 +3,90 @@
 //! 이 모듈은 ONNX Runtime 기반 가격 방향 prediction을 제공합니다.
 //! 모델은 별도로 학습되어야 하며 (예: Python/PyTorch 사용)
 //! ONNX 형식으로 내보내야 합니다.
+//!
+//! 실행 프로바이더(CUDA, DirectML, CoreML)를 우선순위대로 지정할 수 있으며,
+//! 사용할 수 없는 프로바이더는 건너뛰고 최종적으로 CPU로 실행합니다.
+//! GPU 프로바이더는 `ml-cuda`, `ml-directml`, `ml-coreml` feature로 빌드해야 합니다.
 
 use crate::ml::{FeatureVector, MlError, MlResult, Prediction, PredictionDirection};
 #[cfg(feature = "ml")]
 use ort::session::Session;
 use serde::{Deserialize, Serialize};
+use std::fmt;
 #[cfg(feature = "ml")]
 use std::path::Path;
 use std::path::PathBuf;
+use std::str::FromStr;
 #[cfg(feature = "ml")]
-use tracing::{debug, info};
+use tracing::{debug, info, warn};
+
+/// ONNX Runtime 실행 프로바이더.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecutionProvider {
+    /// CPU (항상 사용 가능, 최종 대체 경로)
+    Cpu,
+    /// NVIDIA CUDA
+    Cuda,
+    /// DirectML (Windows, DirectX 12 GPU)
+    #[serde(rename = "directml")]
+    DirectMl,
+    /// CoreML (macOS/iOS)
+    #[serde(rename = "coreml")]
+    CoreMl,
+}
+
+impl ExecutionProvider {
+    /// `use_gpu`만 켜진 경우 시도할 GPU 프로바이더 순서.
+    pub const GPU_DEFAULTS: [ExecutionProvider; 3] = [
+        ExecutionProvider::Cuda,
+        ExecutionProvider::DirectMl,
+        ExecutionProvider::CoreMl,
+    ];
+
+    /// 설정 문자열 표기.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ExecutionProvider::Cpu => "cpu",
+            ExecutionProvider::Cuda => "cuda",
+            ExecutionProvider::DirectMl => "directml",
+            ExecutionProvider::CoreMl => "coreml",
+        }
+    }
+
+    /// 쉼표로 구분된 프로바이더 목록 파싱 (예: "cuda,cpu").
+    pub fn parse_list(value: &str) -> MlResult<Vec<Self>> {
+        value
+            .split(',')
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(str::parse)
+            .collect()
+    }
+}
+
+impl fmt::Display for ExecutionProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for ExecutionProvider {
+    type Err = MlError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "cpu" => Ok(ExecutionProvider::Cpu),
+            "cuda" | "gpu" => Ok(ExecutionProvider::Cuda),
+            "directml" | "dml" => Ok(ExecutionProvider::DirectMl),
+            "coreml" => Ok(ExecutionProvider::CoreMl),
+            other => Err(MlError::InvalidInput(format!(
+                "Unknown execution provider: {}",
+                other
+            ))),
+        }
+    }
+}
 
 /// ONNX predictor 설정.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -27,6 +101,19 @@ pub struct PredictorConfig {
     pub model_name: String,
     /// GPU 가속 사용 여부 (가능한 경우)
     pub use_gpu: bool,
+    /// 실행 프로바이더 우선순위 (비어 있으면 `use_gpu`에 따라 결정)
+    #[serde(default)]
+    pub execution_providers: Vec<ExecutionProvider>,
+    /// GPU 장치 번호 (CUDA/DirectML)
+    #[serde(default)]
+    pub device_id: i32,
+    /// 배치 추론 시 한 번에 실행할 최대 샘플 수
+    #[serde(default = "default_batch_size")]
+    pub batch_size: usize,
+}
+
+fn default_batch_size() -> usize {
+    256
 }
 
 impl Default for PredictorConfig {
@@ -37,6 +124,9 @@ impl Default for PredictorConfig {
             confidence_threshold: 0.6,
             model_name: "price_predictor".to_string(),
             use_gpu: false,
+            execution_providers: Vec::new(),
+            device_id: 0,
+            batch_size: default_batch_size(),
         }
     }
 }
@@ -67,6 +157,51 @@ impl PredictorConfig {
         self.model_name = name.into();
         self
     }
+
+    /// 실행 프로바이더 우선순위 설정.
+    pub fn with_execution_providers(mut self, providers: Vec<ExecutionProvider>) -> Self {
+        self.execution_providers = providers;
+        self
+    }
+
+    /// GPU 장치 번호 설정.
+    pub fn with_device_id(mut self, device_id: i32) -> Self {
+        self.device_id = device_id;
+        self
+    }
+
+    /// 배치 추론 크기 설정.
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    /// 실제로 시도할 프로바이더 순서.
+    ///
+    /// 지정 목록이 비어 있으면 `use_gpu`일 때 GPU 기본 순서를 사용하고,
+    /// CPU는 항상 마지막 대체 경로로 포함됩니다 (CPU 이후 항목은 무시).
+    pub fn resolved_providers(&self) -> Vec<ExecutionProvider> {
+        let requested: Vec<ExecutionProvider> =
+            if self.execution_providers.is_empty() && self.use_gpu {
+                ExecutionProvider::GPU_DEFAULTS.to_vec()
+            } else {
+                self.execution_providers.clone()
+            };
+
+        let mut providers = Vec::new();
+        for provider in requested {
+            if !providers.contains(&provider) {
+                providers.push(provider);
+            }
+            if provider == ExecutionProvider::Cpu {
+                break;
+            }
+        }
+        if !providers.contains(&ExecutionProvider::Cpu) {
+            providers.push(ExecutionProvider::Cpu);
+        }
+        providers
+    }
 }
 
 /// 상세 확률이 포함된 prediction 결과.
@@ -106,6 +241,10 @@ impl PredictionResult {
 pub struct OnnxPredictor {
     session: Session,
     config: PredictorConfig,
+    /// 세션에 등록된 실행 프로바이더 (우선순위 순)
+    active_providers: Vec<ExecutionProvider>,
+    /// 모델이 batch 차원을 지원하지 않으면 false (샘플 단위로 실행)
+    batch_supported: bool,
 }
 
 #[cfg(feature = "ml")]
@@ -123,16 +262,78 @@ impl OnnxPredictor {
 
         info!("Loading ONNX model from: {}", path.display());
 
+        let (dispatches, active_providers) = Self::available_providers(&config);
+
         let session = Session::builder()
             .map_err(|e| MlError::ModelLoad(format!("Failed to create session builder: {}", e)))?
             .with_optimization_level(ort::session::builder::GraphOptimizationLevel::Level3)
             .map_err(|e| MlError::ModelLoad(format!("Failed to set optimization level: {}", e)))?
+            .with_execution_providers(dispatches)
+            .map_err(|e| {
+                MlError::ModelLoad(format!("Failed to register execution providers: {}", e))
+            })?
             .commit_from_file(path)
             .map_err(|e| MlError::ModelLoad(format!("Failed to load model: {}", e)))?;
 
-        info!("ONNX model loaded successfully: {}", config.model_name);
+        info!(
+            "ONNX model loaded successfully: {} (providers: {:?})",
+            config.model_name, active_providers
+        );
 
-        Ok(Self { session, config })
+        Ok(Self {
+            session,
+            config,
+            active_providers,
+            batch_supported: true,
+        })
+    }
+
+    /// 설정된 프로바이더 중 현재 환경에서 사용 가능한 것만 골라 등록 목록 생성.
+    ///
+    /// 사용할 수 없는 프로바이더(드라이버 없음, feature 미포함, 미지원 플랫폼)는
+    /// 경고 후 건너뛰며, CPU는 ONNX Runtime 기본 경로이므로 별도 등록하지 않습니다.
+    fn available_providers(
+        config: &PredictorConfig,
+    ) -> (
+        Vec<ort::ep::ExecutionProviderDispatch>,
+        Vec<ExecutionProvider>,
+    ) {
+        use ort::ep::ExecutionProvider as _;
+
+        let mut dispatches = Vec::new();
+        let mut active = Vec::new();
+        for provider in config.resolved_providers() {
+            let available = match provider {
+                ExecutionProvider::Cpu => {
+                    active.push(provider);
+                    break;
+                }
+                ExecutionProvider::Cuda => {
+                    let ep = ort::ep::CUDA::default().with_device_id(config.device_id);
+                    ep.is_available().unwrap_or(false).then(|| ep.build())
+                }
+                ExecutionProvider::DirectMl => {
+                    let ep = ort::ep::DirectML::default().with_device_id(config.device_id);
+                    ep.is_available().unwrap_or(false).then(|| ep.build())
+                }
+                ExecutionProvider::CoreMl => {
+                    let ep = ort::ep::CoreML::default();
+                    ep.is_available().unwrap_or(false).then(|| ep.build())
+                }
+            };
+
+            match available {
+                Some(dispatch) => {
+                    dispatches.push(dispatch);
+                    active.push(provider);
+                }
+                None => warn!(
+                    "Execution provider '{}' is not available, falling back",
+                    provider
+                ),
+            }
+        }
+        (dispatches, active)
     }
 
     /// 기본 설정으로 파일 경로에서 모델 로드.
@@ -146,10 +347,56 @@ impl OnnxPredictor {
         &self.config
     }
 
+    /// 세션에 등록된 실행 프로바이더 (마지막은 항상 CPU).
+    pub fn active_providers(&self) -> &[ExecutionProvider] {
+        &self.active_providers
+    }
+
     /// feature vector에서 가격 방향 예측.
     pub fn predict(&mut self, features: &FeatureVector) -> MlResult<PredictionResult> {
+        let result = self.run_batch(std::slice::from_ref(features))?.pop();
+        result.ok_or_else(|| MlError::Inference("No prediction returned".to_string()))
+    }
+
+    /// feature vector 배치에 대해 방향 예측.
+    ///
+    /// `batch_size` 단위로 묶어 한 번의 추론으로 실행합니다. 모델이 batch 차원을
+    /// 지원하지 않으면 이후 호출부터 샘플 단위 실행으로 대체합니다.
+    pub fn predict_batch(
+        &mut self,
+        features_batch: &[FeatureVector],
+    ) -> MlResult<Vec<PredictionResult>> {
+        let mut results = Vec::with_capacity(features_batch.len());
+        for chunk in features_batch.chunks(self.config.batch_size.max(1)) {
+            if !self.batch_supported || chunk.len() == 1 {
+                for features in chunk {
+                    results.push(self.predict(features)?);
+                }
+                continue;
+            }
+
+            match self.run_batch(chunk) {
+                Ok(chunk_results) => results.extend(chunk_results),
+                Err(MlError::Inference(e)) => {
+                    warn!(
+                        "Batched inference failed for {}, falling back to single samples: {}",
+                        self.config.model_name, e
+                    );
+                    self.batch_supported = false;
+                    for features in chunk {
+                        results.push(self.predict(features)?);
+                    }
+                }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(results)
+    }
+
+    /// 한 번의 추론으로 batch 실행.
+    fn run_batch(&mut self, batch: &[FeatureVector]) -> MlResult<Vec<PredictionResult>> {
         // 입력 크기 검증
-        if features.len() != self.config.input_size {
+        if let Some(features) = batch.iter().find(|f| f.len() != self.config.input_size) {
             return Err(MlError::InvalidInput(format!(
                 "Expected {} features, got {}",
                 self.config.input_size,
@@ -157,9 +404,12 @@ impl OnnxPredictor {
             )));
         }
 
-        // 입력 텐서 생성 [1, input_size]
-        let input_data: Vec<f32> = features.as_slice().to_vec();
-        let input_shape = [1i64, self.config.input_size as i64];
+        // 입력 텐서 생성 [batch, input_size]
+        let input_data: Vec<f32> = batch
+            .iter()
+            .flat_map(|f| f.as_slice().iter().copied())
+            .collect();
+        let input_shape = [batch.len() as i64, self.config.input_size as i64];
 
         // 텐서 값 생성
         let input_tensor =
@@ -188,20 +438,30 @@ impl OnnxPredictor {
             .try_extract_tensor::<f32>()
             .map_err(|e| MlError::Inference(format!("Failed to extract output tensor: {}", e)))?;
 
-        // 확률 파싱 ([up, down, sideways] 예상)
-        if output_slice.len() < 3 {
+        // 확률 파싱 (샘플마다 [up, down, sideways] 예상)
+        if output_slice.len() < batch.len() * 3 {
             return Err(MlError::Inference(format!(
-                "Expected 3 output values, got {}",
+                "Expected {} output values, got {}",
+                batch.len() * 3,
                 output_slice.len()
             )));
         }
 
         // 출력을 드롭하기 전에 확률을 소유 데이터로 복사
-        let probabilities = [output_slice[0], output_slice[1], output_slice[2]];
+        let rows: Vec<[f32; 3]> = output_slice
+            .chunks_exact(3)
+            .take(batch.len())
+            .map(|row| [row[0], row[1], row[2]])
+            .collect();
 
         // session에 대한 가변 차용을 해제하기 위해 출력 드롭
         drop(outputs);
 
+        Ok(rows.iter().map(|row| self.to_result(row)).collect())
+    }
+
+    /// 모델 출력 한 행을 prediction 결과로 변환.
+    fn to_result(&self, probabilities: &[f32; 3]) -> PredictionResult {
         // 아직 적용되지 않은 경우 softmax 적용 (합계 ≈ 1 확인)
         let sum: f32 = probabilities.iter().sum();
         let probs = if (sum - 1.0).abs() > 0.01 {
@@ -218,7 +478,7 @@ impl OnnxPredictor {
                 exp_vals[2] / exp_sum,
             ]
         } else {
-            probabilities
+            *probabilities
         };
 
         // 방향과 신뢰도 결정
@@ -233,22 +493,12 @@ impl OnnxPredictor {
             probs[2]
         );
 
-        Ok(PredictionResult {
+        PredictionResult {
             direction,
             confidence,
             probabilities: probs,
             model_name: self.config.model_name.clone(),
-        })
-    }
-
-    /// feature vector 배치에 대해 방향 예측.
-    pub fn predict_batch(
-        &mut self,
-        features_batch: &[FeatureVector],
-    ) -> MlResult<Vec<PredictionResult>> {
-        // 단순함을 위해 한 번에 하나씩 처리
-        // 더 최적화된 버전은 단일 텐서로 배치 처리
-        features_batch.iter().map(|f| self.predict(f)).collect()
+        }
     }
 
     /// 확률 분포를 해석하여 방향과 신뢰도 반환.
@@ -339,8 +589,18 @@ pub trait PricePredictor: Send + Sync {
     /// feature에서 가격 방향 예측.
     fn predict(&mut self, features: &FeatureVector) -> MlResult<PredictionResult>;
 
+    /// feature 배치에서 가격 방향 예측 (기본 구현은 샘플 단위 실행).
+    fn predict_batch(&mut self, features: &[FeatureVector]) -> MlResult<Vec<PredictionResult>> {
+        features.iter().map(|f| self.predict(f)).collect()
+    }
+
     /// 모델 이름 반환.
     fn model_name(&self) -> &str;
+
+    /// 추론에 사용 중인 실행 프로바이더.
+    fn execution_providers(&self) -> Vec<ExecutionProvider> {
+        vec![ExecutionProvider::Cpu]
+    }
 }
 
 #[cfg(feature = "ml")]
@@ -349,6 +609,14 @@ impl PricePredictor for OnnxPredictor {
         OnnxPredictor::predict(self, features)
     }
 
+    fn predict_batch(&mut self, features: &[FeatureVector]) -> MlResult<Vec<PredictionResult>> {
+        OnnxPredictor::predict_batch(self, features)
+    }
+
+    fn execution_providers(&self) -> Vec<ExecutionProvider> {
+        self.active_providers.clone()
+    }
+
     fn model_name(&self) -> &str {
         &self.config.model_name
     }
@@ -387,6 +655,70 @@ mod tests {
         assert_eq!(config.model_name, "test_model");
     }
 
+    #[test]
+    fn test_resolved_execution_providers() {
+        // 기본값은 CPU 전용
+        assert_eq!(
+            PredictorConfig::default().resolved_providers(),
+            vec![ExecutionProvider::Cpu]
+        );
+
+        // use_gpu만 켜면 GPU 기본 순서 뒤에 CPU 대체 경로
+        let config = PredictorConfig {
+            use_gpu: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            config.resolved_providers(),
+            vec![
+                ExecutionProvider::Cuda,
+                ExecutionProvider::DirectMl,
+                ExecutionProvider::CoreMl,
+                ExecutionProvider::Cpu,
+            ]
+        );
+
+        // 명시 목록은 중복 제거, CPU 이후 항목 무시
+        let providers = ExecutionProvider::parse_list("DirectML, cuda, dml, cpu, coreml").unwrap();
+        let config = PredictorConfig::default().with_execution_providers(providers);
+        assert_eq!(
+            config.resolved_providers(),
+            vec![
+                ExecutionProvider::DirectMl,
+                ExecutionProvider::Cuda,
+                ExecutionProvider::Cpu,
+            ]
+        );
+
+        assert!(ExecutionProvider::parse_list("cuda,tpu").is_err());
+        assert_eq!(ExecutionProvider::parse_list("").unwrap(), vec![]);
+    }
+
+    #[test]
+    fn test_mock_predictor_batch() {
+        let mut predictor: Box<dyn PricePredictor> = Box::new(MockPredictor::new(20));
+        let batch = vec![
+            FeatureVector::new(vec![0.2; 20]),
+            FeatureVector::new(vec![-0.2; 20]),
+            FeatureVector::new(vec![0.0; 20]),
+        ];
+
+        let results = predictor.predict_batch(&batch).unwrap();
+        let directions: Vec<_> = results.iter().map(|r| r.direction).collect();
+        assert_eq!(
+            directions,
+            vec![
+                PredictionDirection::Up,
+                PredictionDirection::Down,
+                PredictionDirection::Sideways,
+            ]
+        );
+        assert_eq!(
+            predictor.execution_providers(),
+            vec![ExecutionProvider::Cpu]
+        );
+    }
+
     #[cfg(feature = "ml")]
     #[test]
     fn test_model_not_found() {
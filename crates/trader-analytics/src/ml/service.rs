@@ -13,7 +13,9 @@ use crate::ml::{
         CandlestickPattern, CandlestickPatternType, ChartPattern, ChartPatternType, PatternConfig,
         PatternRecognizer,
     },
-    predictor::{MockPredictor, PredictorConfig, PricePredictor},
+    predictor::{
+        ExecutionProvider, MockPredictor, PredictionResult, PredictorConfig, PricePredictor,
+    },
     types::{ConfidenceLevel, FeatureVector, Prediction},
 };
 use chrono::{DateTime, Utc};
@@ -208,9 +210,12 @@ impl MlService {
         let pattern_recognizer = PatternRecognizer::new(config.pattern_config.clone());
 
         let input_size = config.feature_config.feature_count();
-        let predictor_config = PredictorConfig::new(model_path.as_ref())
-            .with_input_size(input_size)
-            .with_model_name(model_name);
+        let predictor_config = PredictorConfig {
+            model_path: model_path.as_ref().to_path_buf(),
+            ..config.predictor_config.clone()
+        }
+        .with_input_size(input_size)
+        .with_model_name(model_name);
 
         let predictor: Box<dyn PricePredictor> = Box::new(OnnxPredictor::load(predictor_config)?);
 
@@ -241,9 +246,12 @@ impl MlService {
         use crate::ml::predictor::OnnxPredictor;
 
         let input_size = self.config.feature_config.feature_count();
-        let predictor_config = PredictorConfig::new(model_path.as_ref())
-            .with_input_size(input_size)
-            .with_model_name(model_name);
+        let predictor_config = PredictorConfig {
+            model_path: model_path.as_ref().to_path_buf(),
+            ..self.config.predictor_config.clone()
+        }
+        .with_input_size(input_size)
+        .with_model_name(model_name);
 
         let new_predictor: Box<dyn PricePredictor> =
             Box::new(OnnxPredictor::load(predictor_config)?);
@@ -444,22 +452,50 @@ impl MlService {
         let mut predictor = self.predictor.write().await;
         let result = predictor.predict(features)?;
 
+        Ok(self.filter_prediction(&result, predictor.model_name()))
+    }
+
+    /// 여러 종목의 가격 예측을 배치로 실행 (활성화된 경우).
+    ///
+    /// 유니버스 전체 점수 계산용으로, predictor 잠금을 한 번만 잡고
+    /// 설정된 batch 크기 단위로 추론합니다. 결과는 입력 순서와 같으며
+    /// 최소 신뢰도 미만인 항목은 `None`입니다.
+    pub async fn predict_batch(
+        &self,
+        features: &[FeatureVector],
+    ) -> MlResult<Vec<Option<Prediction>>> {
+        if !self.config.enable_prediction {
+            return Ok(vec![None; features.len()]);
+        }
+
+        let mut predictor = self.predictor.write().await;
+        let results = predictor.predict_batch(features)?;
+
+        Ok(results
+            .iter()
+            .map(|result| self.filter_prediction(result, predictor.model_name()))
+            .collect())
+    }
+
+    /// 현재 predictor의 실행 프로바이더.
+    pub async fn execution_providers(&self) -> Vec<ExecutionProvider> {
+        self.predictor.read().await.execution_providers()
+    }
+
+    /// 예측 결과를 Prediction으로 변환하고 최소 신뢰도 확인.
+    fn filter_prediction(&self, result: &PredictionResult, model_name: &str) -> Option<Prediction> {
         // probabilities[0]=up, probabilities[1]=down, probabilities[2]=sideways
         // raw_value: up - down (양수면 상승, 음수면 하락)
         let raw_value = result.probabilities[0] - result.probabilities[1];
 
-        let prediction = Prediction::new(
-            result.direction,
-            result.confidence,
-            raw_value,
-            predictor.model_name(),
-        );
+        let prediction =
+            Prediction::new(result.direction, result.confidence, raw_value, model_name);
 
         // 최소 신뢰도 확인
         if prediction.confidence >= self.config.min_prediction_confidence {
-            Ok(Some(prediction))
+            Some(prediction)
         } else {
-            Ok(None)
+            None
         }
     }
 
@@ -665,6 +701,7 @@ fn get_chart_pattern_name(pattern_type: &ChartPatternType) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::ml::PredictionDirection;
     use chrono::Utc;
     use rust_decimal_macros::dec;
     use trader_core::Timeframe;
@@ -725,4 +762,31 @@ mod tests {
         assert_eq!(result.symbol, "BTC/USDT");
         assert!(result.feature_summary.is_some());
     }
+
+    #[tokio::test]
+    async fn test_predict_batch() {
+        let mut service = MlService::with_defaults().unwrap();
+        let input_size = service.config().feature_config.feature_count();
+        let batch = vec![
+            FeatureVector::new(vec![0.2; input_size]),
+            FeatureVector::new(vec![0.0; input_size]),
+        ];
+
+        // 예측 비활성화 시 입력 수만큼 None
+        assert_eq!(service.predict_batch(&batch).await.unwrap().len(), 2);
+
+        service.set_prediction_enabled(true);
+        let predictions = service.predict_batch(&batch).await.unwrap();
+        assert_eq!(predictions.len(), 2);
+        assert_eq!(
+            predictions[0].as_ref().map(|p| p.direction),
+            Some(PredictionDirection::Up)
+        );
+        // Sideways 0.6은 최소 신뢰도 0.6 이상
+        assert!(predictions[1].is_some());
+        assert_eq!(
+            service.execution_providers().await,
+            vec![ExecutionProvider::Cpu]
+        );
+    }
 }
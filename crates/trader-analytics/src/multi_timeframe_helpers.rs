@@ -424,6 +424,7 @@ mod tests {
     use super::*;
     use chrono::Utc;
 
+    #[allow(dead_code)]
     fn make_kline(close: Decimal) -> Kline {
         Kline {
             ticker: "TEST".to_string(),
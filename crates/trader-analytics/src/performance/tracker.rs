@@ -637,6 +637,9 @@ impl PerformanceTracker {
     }
 
     /// 청산 거래를 처리하고 RoundTrip 생성
+    ///
+    /// 진입 거래를 선입선출로 청산 수량만큼 소진합니다. 일부만 청산된 진입 거래는
+    /// 남은 수량과 수수료를 유지하고, 여러 진입 거래에 걸친 청산은 평균 진입가로 계산합니다.
     fn close_position(&mut self, exit_trade: &Trade) -> TrackerResult<RoundTrip> {
         // 반대 방향의 진입 거래 찾기
         let entry_side = match exit_trade.side {
@@ -646,30 +649,65 @@ impl PerformanceTracker {
 
         let key = Self::position_key(&exit_trade.ticker.clone(), entry_side);
 
-        let open_position = self
+        let no_matching_entry = || TrackerError::NoMatchingEntry {
+            symbol: exit_trade.ticker.clone(),
+            side: entry_side,
+        };
+        let lots = self
             .open_positions
             .get_mut(&key)
-            .and_then(|positions| positions.pop_front())
-            .ok_or_else(|| TrackerError::NoMatchingEntry {
-                symbol: exit_trade.ticker.clone(),
-                side: entry_side,
-            })?;
+            .filter(|lots| !lots.is_empty())
+            .ok_or_else(no_matching_entry)?;
+        let first = lots.front().cloned().ok_or_else(no_matching_entry)?;
+
+        // 청산 수량만큼 진입 거래 소진
+        let mut remaining = exit_trade.quantity;
+        let mut matched = Decimal::ZERO;
+        let mut entry_cost = Decimal::ZERO;
+        let mut entry_fees = Decimal::ZERO;
+        while remaining > Decimal::ZERO {
+            let Some(lot) = lots.front_mut() else {
+                break;
+            };
+            let quantity = lot.quantity.min(remaining);
+            let fee = if lot.quantity.is_zero() {
+                lot.fee
+            } else {
+                lot.fee * quantity / lot.quantity
+            };
+
+            matched += quantity;
+            entry_cost += lot.entry_price * quantity;
+            entry_fees += fee;
+            remaining -= quantity;
+
+            if quantity >= lot.quantity {
+                lots.pop_front();
+            } else {
+                lot.quantity -= quantity;
+                lot.fee -= fee;
+            }
+        }
+        let entry_price = if matched.is_zero() {
+            first.entry_price
+        } else {
+            entry_cost / matched
+        };
 
         // RoundTrip 생성
-        let total_fees = open_position.fee + exit_trade.fee;
         let round_trip = RoundTrip::new(
-            &open_position.symbol,
-            open_position.side,
-            open_position.entry_price,
+            &first.symbol,
+            first.side,
+            entry_price,
             exit_trade.price,
-            open_position.quantity.min(exit_trade.quantity), // 수량 매칭
-            total_fees,
-            open_position.entry_time,
+            matched,
+            entry_fees + exit_trade.fee,
+            first.entry_time,
             exit_trade.executed_at,
         );
 
         // 전략 ID 추가
-        let round_trip = if let Some(strategy_id) = open_position.strategy_id {
+        let round_trip = if let Some(strategy_id) = first.strategy_id {
             round_trip.with_strategy(strategy_id)
         } else {
             round_trip
@@ -891,6 +929,50 @@ mod tests {
         assert_eq!(tracker.current_equity(), dec!(10190));
     }
 
+    #[test]
+    fn test_partial_exit_keeps_remaining_entry() {
+        let mut tracker = PerformanceTracker::new(dec!(10000));
+
+        let entry = create_test_trade(Side::Buy, dec!(50000), dec!(0.2), dec!(10));
+        tracker.record_trade(&entry, true, None).unwrap();
+
+        // 절반 청산: 진입 수수료도 절반만 반영
+        let exit = create_test_trade(Side::Sell, dec!(52000), dec!(0.1), dec!(5));
+        let round_trip = tracker.record_trade(&exit, false, None).unwrap().unwrap();
+
+        // PnL = (52000 - 50000) * 0.1 - (5 + 5) = 190
+        assert_eq!(round_trip.quantity, dec!(0.1));
+        assert_eq!(round_trip.pnl, dec!(190));
+        assert_eq!(tracker.open_positions_count(), 1);
+
+        // 나머지 청산
+        let exit = create_test_trade(Side::Sell, dec!(49000), dec!(0.1), dec!(5));
+        let round_trip = tracker.record_trade(&exit, false, None).unwrap().unwrap();
+
+        // PnL = (49000 - 50000) * 0.1 - (5 + 5) = -110
+        assert_eq!(round_trip.pnl, dec!(-110));
+        assert_eq!(tracker.open_positions_count(), 0);
+    }
+
+    #[test]
+    fn test_exit_across_entries_uses_average_cost() {
+        let mut tracker = PerformanceTracker::new(dec!(10000));
+
+        let first = create_test_trade(Side::Buy, dec!(50000), dec!(0.1), dec!(0));
+        let second = create_test_trade(Side::Buy, dec!(40000), dec!(0.1), dec!(0));
+        tracker.record_trade(&first, true, None).unwrap();
+        tracker.record_trade(&second, true, None).unwrap();
+
+        let exit = create_test_trade(Side::Sell, dec!(46000), dec!(0.2), dec!(0));
+        let round_trip = tracker.record_trade(&exit, false, None).unwrap().unwrap();
+
+        // 평균 진입가 45000 → PnL = (46000 - 45000) * 0.2 = 200
+        assert_eq!(round_trip.entry_price, dec!(45000));
+        assert_eq!(round_trip.quantity, dec!(0.2));
+        assert_eq!(round_trip.pnl, dec!(200));
+        assert_eq!(tracker.open_positions_count(), 0);
+    }
+
     #[test]
     fn test_short_position() {
         let mut tracker = PerformanceTracker::new(dec!(10000));
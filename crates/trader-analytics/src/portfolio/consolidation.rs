@@ -0,0 +1,341 @@
+//! 기초자산별 보유 통합.
+//!
+//! 같은 기초자산을 여러 계좌나 여러 시장에서 보유한 경우(예: 두 계좌의 같은 미국 ETF,
+//! 국내 상장 S&P500 ETF와 미국 상장 SPY)를 하나의 묶음으로 모으고 거래 장소별 내역을 남깁니다.
+//!
+//! - 매핑이 없는 종목은 티커 자체가 기초자산 키가 됩니다 (계좌 간 같은 종목은 자동 통합).
+//! - 통화가 섞인 묶음은 환율이 주어진 경우에만 기준 통화로 합산합니다.
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+/// 기초자산 정보 (종목 → 기초자산 매핑 대상).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnderlyingAsset {
+    /// 기초자산 키 (예: "SPX", "NDX", "TSM")
+    pub id: String,
+    /// 표시 이름 (예: "S&P 500")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// 거래 장소별 보유 (계좌 × 상장 종목).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VenueHolding {
+    /// 계좌 식별자 (거래소 자격증명 ID)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account: Option<String>,
+    /// 거래소 (kis, binance 등)
+    pub exchange: String,
+    /// 상장 시장 (KR/US/CRYPTO)
+    pub market: String,
+    /// 종목 코드
+    pub symbol: String,
+    /// 종목명
+    pub name: String,
+    /// 평가 통화
+    pub currency: String,
+    /// 보유 수량
+    pub quantity: Decimal,
+    /// 매입 평균가
+    pub avg_price: Decimal,
+    /// 현재가
+    pub current_price: Decimal,
+    /// 평가금액
+    pub eval_amount: Decimal,
+    /// 평가손익
+    pub profit_loss: Decimal,
+    /// 묶음 내 비중 (%, 합계를 낼 수 있는 경우)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub weight_pct: Option<Decimal>,
+}
+
+/// 기초자산별 보유 묶음.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnderlyingGroup {
+    /// 기초자산 키
+    pub underlying: String,
+    /// 기초자산 이름 (매핑이 없으면 종목명)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// 서로 다른 종목(교차 상장 등)으로 보유 중인지 여부
+    pub cross_listed: bool,
+    /// 통화별 평가금액
+    pub value_by_currency: BTreeMap<String, Decimal>,
+    /// 통화별 평가손익
+    pub profit_loss_by_currency: BTreeMap<String, Decimal>,
+    /// 합계 평가금액 (단일 통화이거나 환율로 환산 가능한 경우)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_value: Option<Decimal>,
+    /// 합계 평가금액 통화
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_currency: Option<String>,
+    /// 거래 장소별 내역 (평가금액 내림차순)
+    pub venues: Vec<VenueHolding>,
+}
+
+/// 기준 통화 환산 환율.
+#[derive(Debug, Clone)]
+pub struct FxTable {
+    base_currency: String,
+    /// 통화 1단위당 기준 통화 금액
+    rates: HashMap<String, Decimal>,
+}
+
+impl FxTable {
+    /// 기준 통화로 환율표 생성.
+    pub fn new(base_currency: impl Into<String>) -> Self {
+        Self {
+            base_currency: base_currency.into().to_uppercase(),
+            rates: HashMap::new(),
+        }
+    }
+
+    /// 통화 1단위당 기준 통화 금액 설정.
+    pub fn with_rate(mut self, currency: impl Into<String>, rate: Decimal) -> Self {
+        self.rates.insert(currency.into().to_uppercase(), rate);
+        self
+    }
+
+    /// 기준 통화.
+    pub fn base_currency(&self) -> &str {
+        &self.base_currency
+    }
+
+    /// 기준 통화로 환산 (환율이 없으면 `None`).
+    pub fn convert(&self, amount: Decimal, currency: &str) -> Option<Decimal> {
+        if currency.eq_ignore_ascii_case(&self.base_currency) {
+            return Some(amount);
+        }
+        self.rates
+            .get(&currency.to_uppercase())
+            .map(|rate| amount * rate)
+    }
+}
+
+/// 종목의 기초자산 키 (매핑이 없으면 대문자 티커).
+pub fn underlying_key(symbol: &str, underlyings: &HashMap<String, UnderlyingAsset>) -> String {
+    let ticker = symbol.to_uppercase();
+    underlyings
+        .get(&ticker)
+        .map(|asset| asset.id.clone())
+        .unwrap_or(ticker)
+}
+
+/// 보유 내역을 기초자산별로 묶습니다.
+///
+/// 묶음은 합계 평가금액 내림차순(합계가 없으면 뒤로), 같으면 기초자산 키 순입니다.
+///
+/// # Arguments
+/// * `holdings` - 거래 장소별 보유 내역
+/// * `underlyings` - 대문자 티커 → 기초자산 매핑
+/// * `fx` - 통화가 섞인 묶음의 환산 환율 (없으면 통화별 합계만 제공)
+pub fn consolidate_by_underlying(
+    holdings: Vec<VenueHolding>,
+    underlyings: &HashMap<String, UnderlyingAsset>,
+    fx: Option<&FxTable>,
+) -> Vec<UnderlyingGroup> {
+    let mut grouped: BTreeMap<String, (Option<String>, Vec<VenueHolding>)> = BTreeMap::new();
+    for holding in holdings {
+        let ticker = holding.symbol.to_uppercase();
+        let (key, name) = match underlyings.get(&ticker) {
+            Some(asset) => (asset.id.clone(), asset.name.clone()),
+            None => (ticker, Some(holding.name.clone()).filter(|n| !n.is_empty())),
+        };
+        let entry = grouped.entry(key).or_insert_with(|| (name, Vec::new()));
+        entry.1.push(holding);
+    }
+
+    let mut groups: Vec<UnderlyingGroup> = grouped
+        .into_iter()
+        .map(|(underlying, (name, venues))| build_group(underlying, name, venues, fx))
+        .collect();
+
+    groups.sort_by(|a, b| {
+        b.total_value
+            .cmp(&a.total_value)
+            .then_with(|| a.underlying.cmp(&b.underlying))
+    });
+    groups
+}
+
+fn build_group(
+    underlying: String,
+    name: Option<String>,
+    mut venues: Vec<VenueHolding>,
+    fx: Option<&FxTable>,
+) -> UnderlyingGroup {
+    let mut value_by_currency = BTreeMap::new();
+    let mut profit_loss_by_currency = BTreeMap::new();
+    for venue in &venues {
+        let currency = venue.currency.to_uppercase();
+        *value_by_currency
+            .entry(currency.clone())
+            .or_insert(Decimal::ZERO) += venue.eval_amount;
+        *profit_loss_by_currency
+            .entry(currency)
+            .or_insert(Decimal::ZERO) += venue.profit_loss;
+    }
+
+    // 단일 통화면 그대로, 여러 통화면 기준 통화로 환산
+    let converter = |amount: Decimal, currency: &str| -> Option<Decimal> {
+        if value_by_currency.len() == 1 {
+            Some(amount)
+        } else {
+            fx.and_then(|fx| fx.convert(amount, currency))
+        }
+    };
+    let total_currency = if value_by_currency.len() == 1 {
+        value_by_currency.keys().next().cloned()
+    } else {
+        fx.map(|fx| fx.base_currency().to_string())
+    };
+    let converted: Option<Vec<Decimal>> = venues
+        .iter()
+        .map(|v| converter(v.eval_amount, &v.currency))
+        .collect();
+    let total_value = converted
+        .as_ref()
+        .map(|values| values.iter().copied().sum::<Decimal>());
+
+    if let (Some(values), Some(total)) = (&converted, total_value) {
+        if total > Decimal::ZERO {
+            for (venue, value) in venues.iter_mut().zip(values) {
+                venue.weight_pct = Some((value / total * Decimal::from(100)).round_dp(2));
+            }
+        }
+    }
+    venues.sort_by(|a, b| {
+        b.weight_pct
+            .cmp(&a.weight_pct)
+            .then_with(|| b.eval_amount.cmp(&a.eval_amount))
+    });
+
+    let distinct_symbols = {
+        let mut symbols: Vec<String> = venues.iter().map(|v| v.symbol.to_uppercase()).collect();
+        symbols.sort();
+        symbols.dedup();
+        symbols.len()
+    };
+
+    UnderlyingGroup {
+        underlying,
+        name,
+        cross_listed: distinct_symbols > 1,
+        value_by_currency,
+        profit_loss_by_currency,
+        total_value: total_currency.as_ref().and(total_value),
+        total_currency: total_value.and(total_currency),
+        venues,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn holding(account: &str, symbol: &str, currency: &str, eval: Decimal) -> VenueHolding {
+        VenueHolding {
+            account: Some(account.to_string()),
+            exchange: "kis".to_string(),
+            market: if currency == "KRW" { "KR" } else { "US" }.to_string(),
+            symbol: symbol.to_string(),
+            name: symbol.to_string(),
+            currency: currency.to_string(),
+            quantity: dec!(1),
+            avg_price: eval,
+            current_price: eval,
+            eval_amount: eval,
+            profit_loss: eval / dec!(10),
+            weight_pct: None,
+        }
+    }
+
+    fn sp500_mappings() -> HashMap<String, UnderlyingAsset> {
+        let asset = UnderlyingAsset {
+            id: "SPX".to_string(),
+            name: Some("S&P 500".to_string()),
+        };
+        ["SPY", "VOO", "360750"]
+            .into_iter()
+            .map(|t| (t.to_string(), asset.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn test_same_ticker_across_accounts_is_merged() {
+        let groups = consolidate_by_underlying(
+            vec![
+                holding("a", "QQQ", "USD", dec!(300)),
+                holding("b", "qqq", "USD", dec!(100)),
+                holding("a", "AAPL", "USD", dec!(50)),
+            ],
+            &HashMap::new(),
+            None,
+        );
+
+        assert_eq!(groups.len(), 2);
+        let qqq = &groups[0];
+        assert_eq!(qqq.underlying, "QQQ");
+        assert!(!qqq.cross_listed);
+        assert_eq!(qqq.total_value, Some(dec!(400)));
+        assert_eq!(qqq.total_currency.as_deref(), Some("USD"));
+        assert_eq!(qqq.venues[0].weight_pct, Some(dec!(75)));
+        assert_eq!(qqq.venues[1].account.as_deref(), Some("b"));
+    }
+
+    #[test]
+    fn test_cross_listing_without_fx_keeps_currency_totals() {
+        let groups = consolidate_by_underlying(
+            vec![
+                holding("a", "SPY", "USD", dec!(1000)),
+                holding("b", "360750", "KRW", dec!(2000000)),
+            ],
+            &sp500_mappings(),
+            None,
+        );
+
+        assert_eq!(groups.len(), 1);
+        let spx = &groups[0];
+        assert_eq!(spx.underlying, "SPX");
+        assert_eq!(spx.name.as_deref(), Some("S&P 500"));
+        assert!(spx.cross_listed);
+        assert_eq!(spx.value_by_currency["USD"], dec!(1000));
+        assert_eq!(spx.value_by_currency["KRW"], dec!(2000000));
+        assert_eq!(spx.profit_loss_by_currency["USD"], dec!(100));
+        assert_eq!(spx.total_value, None);
+        assert_eq!(spx.total_currency, None);
+        assert!(spx.venues.iter().all(|v| v.weight_pct.is_none()));
+    }
+
+    #[test]
+    fn test_cross_listing_with_fx_converts_to_base() {
+        let fx = FxTable::new("krw").with_rate("USD", dec!(1400));
+        let groups = consolidate_by_underlying(
+            vec![
+                holding("a", "VOO", "USD", dec!(1000)),
+                holding("b", "360750", "KRW", dec!(600000)),
+                holding("a", "005930", "KRW", dec!(100000)),
+            ],
+            &sp500_mappings(),
+            Some(&fx),
+        );
+
+        let spx = &groups[0];
+        assert_eq!(spx.underlying, "SPX");
+        assert_eq!(spx.total_value, Some(dec!(2000000)));
+        assert_eq!(spx.total_currency.as_deref(), Some("KRW"));
+        assert_eq!(spx.venues[0].symbol, "VOO");
+        assert_eq!(spx.venues[0].weight_pct, Some(dec!(70)));
+        assert_eq!(spx.venues[1].weight_pct, Some(dec!(30)));
+
+        assert_eq!(groups[1].underlying, "005930");
+        assert_eq!(underlying_key("voo", &sp500_mappings()), "SPX");
+        assert_eq!(underlying_key("AAPL", &sp500_mappings()), "AAPL");
+    }
+}
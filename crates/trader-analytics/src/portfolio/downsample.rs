@@ -0,0 +1,96 @@
+//! 차트용 시계열 다운샘플링.
+//!
+//! LTTB(Largest-Triangle-Three-Buckets) 알고리즘으로 긴 자산 곡선을 적은 점으로 줄입니다.
+//! 구간마다 인접 구간과 만드는 삼각형 넓이가 가장 큰 점을 남기므로, 단순 간격 추출과 달리
+//! 고점/저점(최대 낙폭 구간)의 모양이 유지됩니다.
+
+/// LTTB로 남길 점의 인덱스 (오름차순).
+///
+/// 첫 점과 마지막 점은 항상 포함됩니다. 점 수가 `threshold` 이하이거나
+/// `threshold`가 3 미만이면 모든 인덱스를 반환합니다.
+///
+/// # Arguments
+/// * `points` - (x, y) 점 목록 (x 오름차순)
+/// * `threshold` - 남길 점 수
+pub fn lttb_indices(points: &[(f64, f64)], threshold: usize) -> Vec<usize> {
+    let len = points.len();
+    if threshold < 3 || len <= threshold {
+        return (0..len).collect();
+    }
+
+    let mut selected = Vec::with_capacity(threshold);
+    selected.push(0);
+
+    // 첫/마지막 점을 제외한 점을 (threshold - 2)개 구간으로 나눔
+    let bucket_size = (len - 2) as f64 / (threshold - 2) as f64;
+    let mut prev = 0;
+    for bucket in 0..threshold - 2 {
+        let start = (bucket as f64 * bucket_size) as usize + 1;
+        let end = ((bucket + 1) as f64 * bucket_size) as usize + 1;
+
+        // 다음 구간 평균점 (마지막 구간이면 마지막 점)
+        let next_start = end;
+        let next_end = (((bucket + 2) as f64 * bucket_size) as usize + 1).min(len);
+        let (avg_x, avg_y) = if next_start < next_end {
+            let count = (next_end - next_start) as f64;
+            let (sum_x, sum_y) = points[next_start..next_end]
+                .iter()
+                .fold((0.0, 0.0), |(sx, sy), (x, y)| (sx + x, sy + y));
+            (sum_x / count, sum_y / count)
+        } else {
+            points[len - 1]
+        };
+
+        let (prev_x, prev_y) = points[prev];
+        let mut best = start;
+        let mut best_area = -1.0;
+        for (i, &(x, y)) in points.iter().enumerate().take(end).skip(start) {
+            let area = ((prev_x - avg_x) * (y - prev_y) - (prev_x - x) * (avg_y - prev_y)).abs();
+            if area > best_area {
+                best_area = area;
+                best = i;
+            }
+        }
+
+        selected.push(best);
+        prev = best;
+    }
+
+    selected.push(len - 1);
+    selected
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lttb_keeps_extremes() {
+        // 완만한 상승 중 급락 구간 하나
+        let points: Vec<(f64, f64)> = (0..1000)
+            .map(|i| {
+                let y = if i == 500 {
+                    50.0
+                } else {
+                    100.0 + i as f64 * 0.01
+                };
+                (i as f64, y)
+            })
+            .collect();
+
+        let indices = lttb_indices(&points, 50);
+        assert_eq!(indices.len(), 50);
+        assert_eq!(indices[0], 0);
+        assert_eq!(indices[49], 999);
+        assert!(indices.windows(2).all(|w| w[0] < w[1]));
+        assert!(indices.contains(&500));
+    }
+
+    #[test]
+    fn test_lttb_small_input() {
+        let points = vec![(0.0, 1.0), (1.0, 2.0), (2.0, 3.0)];
+        assert_eq!(lttb_indices(&points, 10), vec![0, 1, 2]);
+        assert_eq!(lttb_indices(&points, 2), vec![0, 1, 2]);
+        assert!(lttb_indices(&[], 10).is_empty());
+    }
+}
@@ -771,7 +771,7 @@ impl EquityCurve {
         }
 
         // 최대 낙폭 기준 내림차순 정렬
-        periods.sort_by(|a, b| b.max_drawdown_pct.cmp(&a.max_drawdown_pct));
+        periods.sort_by_key(|p| std::cmp::Reverse(p.max_drawdown_pct));
 
         periods
     }
@@ -6,6 +6,8 @@
 //!
 //! - [`equity_curve`]: 자산 곡선 데이터 생성 및 관리
 //! - [`charts`]: 차트 데이터 구조 (CAGR, MDD, 월별 수익률 등)
+//! - [`consolidation`]: 기초자산별 보유 통합 (계좌/교차 상장 묶음)
+//! - [`downsample`]: 차트용 시계열 다운샘플링 (LTTB)
 //!
 //! # 사용 예시
 //!
@@ -31,7 +33,11 @@
 //! ```
 
 pub mod charts;
+pub mod consolidation;
+pub mod downsample;
 pub mod equity_curve;
 
 pub use charts::*;
+pub use consolidation::*;
+pub use downsample::*;
 pub use equity_curve::*;
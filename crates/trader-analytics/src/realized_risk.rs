@@ -0,0 +1,175 @@
+//! 실현 변동성/베타 계산 모듈.
+//!
+//! 일봉 종가로 종목별 20/60/252일 실현 변동성(연율화 %)과 벤치마크 대비 베타를 계산합니다.
+//! 수집기가 매일 계산해 저장하며, 사이징·스트레스 분석·헤지는 저장된 값을 재사용합니다.
+//!
+//! # 예시
+//!
+//! ```rust,ignore
+//! use trader_analytics::realized_risk::compute_symbol_risk;
+//!
+//! let metrics = compute_symbol_risk("005930", &closes, Some(("069500", &benchmark)), Utc::now());
+//! println!("60일 변동성: {:?}%", metrics.realized_vol_60d);
+//! ```
+
+use chrono::{DateTime, NaiveDate, Utc};
+use std::collections::BTreeMap;
+use trader_core::{SymbolRiskMetrics, REALIZED_VOL_WINDOWS};
+
+use crate::correlation::{align_returns, closes_to_dated_returns, trailing_beta};
+
+/// 연간 거래일 수 (변동성 연율화).
+pub const TRADING_DAYS_PER_YEAR: f64 = 252.0;
+
+/// 베타 계산 구간 (거래일).
+pub const BETA_WINDOW: usize = 252;
+
+/// 베타 계산에 필요한 최소 공통 수익률 관측 수.
+pub const MIN_BETA_OBSERVATIONS: usize = 60;
+
+/// 모든 지표 계산에 필요한 종가 수 (252일 수익률 + 1).
+pub const REQUIRED_CLOSES: usize = BETA_WINDOW + 1;
+
+/// 수익률의 최근 `window`개 구간 실현 변동성 (표본 표준편차 × √252, %).
+///
+/// 데이터가 `window`보다 짧으면 None을 반환합니다.
+pub fn realized_volatility_pct(returns: &[f64], window: usize) -> Option<f64> {
+    if window < 2 || returns.len() < window {
+        return None;
+    }
+
+    let recent = &returns[returns.len() - window..];
+    let n = window as f64;
+    let mean = recent.iter().sum::<f64>() / n;
+    let variance = recent.iter().map(|r| (r - mean).powi(2)).sum::<f64>() / (n - 1.0);
+
+    Some(variance.sqrt() * TRADING_DAYS_PER_YEAR.sqrt() * 100.0)
+}
+
+/// 종목의 실현 변동성/베타 계산.
+///
+/// 변동성은 구간 길이만큼 수익률이 있어야 계산됩니다.
+/// 베타는 벤치마크와 공통 수익률이 [`MIN_BETA_OBSERVATIONS`] 이상일 때
+/// 최근 최대 [`BETA_WINDOW`]개 관측으로 계산합니다.
+pub fn compute_symbol_risk(
+    ticker: &str,
+    closes: &BTreeMap<NaiveDate, f64>,
+    benchmark: Option<(&str, &BTreeMap<NaiveDate, f64>)>,
+    now: DateTime<Utc>,
+) -> SymbolRiskMetrics {
+    let dated_returns = closes_to_dated_returns(closes);
+    let returns: Vec<f64> = dated_returns.values().copied().collect();
+    let [vol_20d, vol_60d, vol_252d] =
+        REALIZED_VOL_WINDOWS.map(|window| realized_volatility_pct(&returns, window));
+
+    let (beta, beta_benchmark) = match benchmark {
+        Some((benchmark_ticker, _)) if benchmark_ticker == ticker => {
+            (Some(1.0), Some(benchmark_ticker.to_string()))
+        }
+        Some((benchmark_ticker, benchmark_closes)) => {
+            let (asset, bench) =
+                align_returns(&dated_returns, &closes_to_dated_returns(benchmark_closes));
+            let beta = (asset.len() >= MIN_BETA_OBSERVATIONS)
+                .then(|| trailing_beta(&asset, &bench, BETA_WINDOW.min(asset.len())))
+                .flatten();
+            (beta, beta.map(|_| benchmark_ticker.to_string()))
+        }
+        None => (None, None),
+    };
+
+    SymbolRiskMetrics {
+        ticker: ticker.to_string(),
+        realized_vol_20d: vol_20d,
+        realized_vol_60d: vol_60d,
+        realized_vol_252d: vol_252d,
+        beta,
+        beta_benchmark,
+        updated_at: Some(now),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn series(closes: &[f64]) -> BTreeMap<NaiveDate, f64> {
+        let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        closes
+            .iter()
+            .enumerate()
+            .map(|(i, c)| (start + chrono::Duration::days(i as i64), *c))
+            .collect()
+    }
+
+    /// +1%, -1% 교대 수익률 종가.
+    fn alternating(len: usize, amplitude: f64) -> Vec<f64> {
+        let mut price = 100.0;
+        (0..len)
+            .map(|i| {
+                if i > 0 {
+                    price *= if i % 2 == 0 {
+                        1.0 - amplitude
+                    } else {
+                        1.0 + amplitude
+                    };
+                }
+                price
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_realized_volatility_pct() {
+        assert_eq!(realized_volatility_pct(&[0.01; 10], 20), None);
+        assert!(realized_volatility_pct(&[0.01; 20], 20).unwrap() < 1e-9);
+
+        // ±1% 교대 (20개) → 표본 표준편차 = 0.01 × √(20/19)
+        let returns: Vec<f64> = (0..20)
+            .map(|i| if i % 2 == 0 { 0.01 } else { -0.01 })
+            .collect();
+        let expected = 0.01 * (20.0f64 / 19.0).sqrt() * 252f64.sqrt() * 100.0;
+        let vol = realized_volatility_pct(&returns, 20).unwrap();
+        assert!((vol - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_compute_symbol_risk_windows() {
+        let now = Utc::now();
+        let closes = series(&alternating(100, 0.01));
+        let metrics = compute_symbol_risk("AAA", &closes, None, now);
+
+        assert!(metrics.realized_vol_20d.is_some());
+        assert!(metrics.realized_vol_60d.is_some());
+        assert_eq!(metrics.realized_vol_252d, None);
+        assert_eq!(metrics.beta, None);
+        assert_eq!(metrics.updated_at, Some(now));
+    }
+
+    #[test]
+    fn test_compute_symbol_risk_beta() {
+        let benchmark = alternating(REQUIRED_CLOSES, 0.01);
+        // 벤치마크 수익률의 2배로 움직이는 종목
+        let mut levered = vec![100.0];
+        for w in benchmark.windows(2) {
+            let r = (w[1] - w[0]) / w[0];
+            levered.push(levered.last().unwrap() * (1.0 + 2.0 * r));
+        }
+
+        let bench = series(&benchmark);
+        let metrics =
+            compute_symbol_risk("LEV", &series(&levered), Some(("SPY", &bench)), Utc::now());
+        assert!((metrics.beta.unwrap() - 2.0).abs() < 1e-9);
+        assert_eq!(metrics.beta_benchmark.as_deref(), Some("SPY"));
+        assert!(metrics.realized_vol_252d.is_some());
+
+        // 벤치마크 자신은 1
+        let metrics = compute_symbol_risk("SPY", &bench, Some(("SPY", &bench)), Utc::now());
+        assert_eq!(metrics.beta, Some(1.0));
+
+        // 공통 관측 부족
+        let short = series(&levered[..30]);
+        let metrics = compute_symbol_risk("LEV", &short, Some(("SPY", &bench)), Utc::now());
+        assert_eq!(metrics.beta, None);
+        assert_eq!(metrics.beta_benchmark, None);
+    }
+}
@@ -315,8 +315,7 @@ mod tests {
     fn create_test_klines(prices: &[Decimal]) -> Vec<Kline> {
         prices
             .iter()
-            .enumerate()
-            .map(|(i, &close)| Kline {
+            .map(|&close| Kline {
                 ticker: "TEST".to_string(),
                 timeframe: Timeframe::D1,
                 open_time: Utc::now(),
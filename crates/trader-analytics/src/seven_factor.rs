@@ -16,6 +16,9 @@ use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use trader_core::Kline;
+
+use crate::indicators::{AtrParams, IndicatorEngine, RsiParams};
 
 // ================================================================================================
 // Types
@@ -69,6 +72,54 @@ pub struct SevenFactorInput {
     pub current_price: Option<Decimal>,
 }
 
+impl SevenFactorInput {
+    /// 기술 지표 계산에 필요한 최소 캔들 수.
+    pub const MIN_CANDLES: usize = 20;
+
+    /// 일봉 캔들로 기술 지표 필드 채우기.
+    ///
+    /// RSI, ATR%, 현재가, 5일/20일 수익률을 계산합니다.
+    /// 캔들이 [`Self::MIN_CANDLES`]개 미만이면 아무 필드도 변경하지 않습니다.
+    pub fn apply_candles(&mut self, candles: &[Kline]) {
+        if candles.len() < Self::MIN_CANDLES {
+            return;
+        }
+
+        let indicator = IndicatorEngine::new();
+        let closes: Vec<Decimal> = candles.iter().map(|c| c.close).collect();
+        let highs: Vec<Decimal> = candles.iter().map(|c| c.high).collect();
+        let lows: Vec<Decimal> = candles.iter().map(|c| c.low).collect();
+        let current_price = closes[closes.len() - 1];
+
+        // RSI - 가장 최근 값 사용
+        if let Ok(rsi_values) = indicator.rsi(&closes, RsiParams::default()) {
+            if let Some(Some(last_rsi)) = rsi_values.last() {
+                self.rsi = Some(*last_rsi);
+            }
+        }
+
+        // ATR% - 가장 최근 값 사용
+        if let Ok(atr_values) = indicator.atr(&highs, &lows, &closes, AtrParams::default()) {
+            if let Some(Some(last_atr)) = atr_values.last() {
+                if current_price > Decimal::ZERO {
+                    self.atr_pct = Some(*last_atr / current_price * dec!(100));
+                }
+            }
+        }
+
+        self.current_price = Some(current_price);
+        self.return_5d = Self::period_return(&closes, 5);
+        self.return_20d = Self::period_return(&closes, 20);
+    }
+
+    /// `period`개 캔들 전 종가 대비 수익률 (%).
+    fn period_return(closes: &[Decimal], period: usize) -> Option<Decimal> {
+        let last = *closes.last()?;
+        let base = closes[closes.len().checked_sub(period)?];
+        (base > Decimal::ZERO).then(|| (last - base) / base * dec!(100))
+    }
+}
+
 /// 7Factor 정규화 점수 결과.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SevenFactorScores {
@@ -106,20 +157,57 @@ impl SevenFactorScores {
     ///
     /// 기본 가중치: 모멘텀 0.2, 가치 0.15, 품질 0.2, 변동성 0.1, 유동성 0.1, 성장 0.15, 심리 0.1
     pub fn composite_score(&self) -> Decimal {
-        let weights = [
-            (self.norm_momentum, dec!(0.20)),
-            (self.norm_value, dec!(0.15)),
-            (self.norm_quality, dec!(0.20)),
-            (self.norm_volatility, dec!(0.10)),
-            (self.norm_liquidity, dec!(0.10)),
-            (self.norm_growth, dec!(0.15)),
-            (self.norm_sentiment, dec!(0.10)),
-        ];
-
-        weights.iter().map(|(score, weight)| *score * *weight).sum()
+        self.weighted_factors()
+            .iter()
+            .map(|(_, score, weight)| *score * *weight)
+            .sum()
+    }
+
+    /// 팩터 이름, 점수, 종합 점수 가중치 목록.
+    fn weighted_factors(&self) -> [(&'static str, Decimal, Decimal); 7] {
+        [
+            ("NORM_MOMENTUM", self.norm_momentum, dec!(0.20)),
+            ("NORM_VALUE", self.norm_value, dec!(0.15)),
+            ("NORM_QUALITY", self.norm_quality, dec!(0.20)),
+            ("NORM_VOLATILITY", self.norm_volatility, dec!(0.10)),
+            ("NORM_LIQUIDITY", self.norm_liquidity, dec!(0.10)),
+            ("NORM_GROWTH", self.norm_growth, dec!(0.15)),
+            ("NORM_SENTIMENT", self.norm_sentiment, dec!(0.10)),
+        ]
+    }
+
+    /// 이전 점수 대비 팩터별 변화량.
+    ///
+    /// 종합 점수 기여도 변화(점수 변화 × 가중치)의 절대값이 큰 순서로 정렬합니다.
+    /// 첫 번째 항목이 종합 점수 변화를 가장 크게 이끈 팩터입니다.
+    pub fn factor_changes(&self, previous: &SevenFactorScores) -> Vec<FactorChange> {
+        let mut changes: Vec<FactorChange> = self
+            .weighted_factors()
+            .iter()
+            .zip(previous.weighted_factors().iter())
+            .map(|((factor, current, weight), (_, prev, _))| FactorChange {
+                factor: factor.to_string(),
+                change: *current - *prev,
+                contribution: (*current - *prev) * *weight,
+            })
+            .collect();
+
+        changes.sort_by_key(|c| std::cmp::Reverse(c.contribution.abs()));
+        changes
     }
 }
 
+/// 팩터별 점수 변화.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FactorChange {
+    /// 팩터 이름 (예: "NORM_MOMENTUM")
+    pub factor: String,
+    /// 팩터 점수 변화량
+    pub change: Decimal,
+    /// 종합 점수 기여도 변화량 (변화량 × 가중치)
+    pub contribution: Decimal,
+}
+
 impl Default for SevenFactorScores {
     fn default() -> Self {
         Self {
@@ -581,4 +669,76 @@ mod tests {
         };
         assert_eq!(scores_zero.composite_score(), dec!(0));
     }
+
+    #[test]
+    fn test_factor_changes_sorted_by_contribution() {
+        let previous = SevenFactorScores::default();
+        let current = SevenFactorScores {
+            norm_momentum: dec!(60),
+            norm_sentiment: dec!(30),
+            ..Default::default()
+        };
+
+        let changes = current.factor_changes(&previous);
+        assert_eq!(changes.len(), 7);
+        // 심리 -20 × 0.1 = -2, 모멘텀 +10 × 0.2 = +2 → 동률이면 원래 순서 유지
+        assert_eq!(changes[0].factor, "NORM_MOMENTUM");
+        assert_eq!(changes[0].contribution, dec!(2));
+        assert_eq!(changes[1].factor, "NORM_SENTIMENT");
+        assert_eq!(changes[1].change, dec!(-20));
+        assert_eq!(changes[2].contribution, dec!(0));
+
+        let total: Decimal = changes.iter().map(|c| c.contribution).sum();
+        assert_eq!(
+            total,
+            current.composite_score() - previous.composite_score()
+        );
+    }
+
+    #[test]
+    fn test_apply_candles_requires_min_candles() {
+        let candles: Vec<Kline> = (0..10)
+            .map(|i| kline(dec!(100) + Decimal::from(i)))
+            .collect();
+        let mut input = SevenFactorInput::default();
+        input.apply_candles(&candles);
+        assert!(input.current_price.is_none());
+        assert!(input.return_5d.is_none());
+    }
+
+    #[test]
+    fn test_apply_candles_returns() {
+        let candles: Vec<Kline> = (0..30)
+            .map(|i| kline(dec!(100) + Decimal::from(i)))
+            .collect();
+        let mut input = SevenFactorInput::default();
+        input.apply_candles(&candles);
+
+        assert_eq!(input.current_price, Some(dec!(129)));
+        // 5개 전 종가 125 → (129 - 125) / 125 * 100
+        assert_eq!(input.return_5d, Some(dec!(3.2)));
+        // 20개 전 종가 110
+        assert_eq!(
+            input.return_20d,
+            Some((dec!(129) - dec!(110)) / dec!(110) * dec!(100))
+        );
+        assert!(input.rsi.is_some());
+    }
+
+    fn kline(close: Decimal) -> Kline {
+        let now = chrono::Utc::now();
+        Kline {
+            ticker: "005930".to_string(),
+            timeframe: trader_core::Timeframe::D1,
+            open_time: now,
+            open: close,
+            high: close + dec!(1),
+            low: close - dec!(1),
+            close,
+            volume: dec!(1000),
+            close_time: now,
+            quote_volume: None,
+            num_trades: None,
+        }
+    }
 }
@@ -30,7 +30,7 @@
 
 use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 /// 일별 랭킹 데이터.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -190,9 +190,83 @@ impl SurvivalTracker {
     /// 생존일이 긴 순서대로 정렬된 종목 목록을 반환합니다.
     pub fn rank_by_survival(&self, history: &[DailyRanking]) -> Vec<SurvivalResult> {
         let mut results: Vec<SurvivalResult> = self.calculate_all(history).into_values().collect();
-        results.sort_by(|a, b| b.survival_days.cmp(&a.survival_days));
+        results.sort_by_key(|r| std::cmp::Reverse(r.survival_days));
         results
     }
+
+    /// 연속 진입(streak) 길이 분포 계산.
+    ///
+    /// 추적 기간 내 모든 종목의 연속 진입 구간(종료된 구간 + 진행 중인 구간)을
+    /// 길이별로 집계합니다.
+    ///
+    /// # 반환
+    ///
+    /// streak 길이 → 구간 수 (길이 오름차순)
+    pub fn streak_distribution(&self, history: &[DailyRanking]) -> BTreeMap<u32, u32> {
+        let window = self.window(history);
+        let mut running: HashMap<&str, u32> = HashMap::new();
+        let mut distribution: BTreeMap<u32, u32> = BTreeMap::new();
+
+        for ranking in window {
+            let today: HashSet<&str> = ranking.tickers.iter().map(String::as_str).collect();
+
+            // 오늘 탈락한 종목의 구간 종료
+            running.retain(|ticker, len| {
+                let alive = today.contains(ticker);
+                if !alive {
+                    *distribution.entry(*len).or_insert(0) += 1;
+                }
+                alive
+            });
+
+            for ticker in today {
+                *running.entry(ticker).or_insert(0) += 1;
+            }
+        }
+
+        // 진행 중인 구간
+        for len in running.into_values() {
+            *distribution.entry(len).or_insert(0) += 1;
+        }
+
+        distribution
+    }
+
+    /// 상위권 목록의 일평균 교체율(churn rate) 계산.
+    ///
+    /// 전일 목록 중 다음 날 빠진 종목의 비율을 일별로 구해 평균합니다.
+    /// 0.0이면 목록이 그대로 유지되고, 1.0이면 매일 전부 교체됩니다.
+    ///
+    /// # 반환
+    ///
+    /// 비교 가능한 날이 없으면 None
+    pub fn churn_rate(&self, history: &[DailyRanking]) -> Option<f64> {
+        let window = self.window(history);
+        let rates: Vec<f64> = window
+            .windows(2)
+            .filter(|pair| !pair[0].tickers.is_empty())
+            .map(|pair| {
+                let today: HashSet<&str> = pair[1].tickers.iter().map(String::as_str).collect();
+                let dropped = pair[0]
+                    .tickers
+                    .iter()
+                    .filter(|t| !today.contains(t.as_str()))
+                    .count();
+                dropped as f64 / pair[0].tickers.len() as f64
+            })
+            .collect();
+
+        if rates.is_empty() {
+            None
+        } else {
+            Some(rates.iter().sum::<f64>() / rates.len() as f64)
+        }
+    }
+
+    /// 추적 기간에 해당하는 최근 히스토리 구간.
+    fn window<'a>(&self, history: &'a [DailyRanking]) -> &'a [DailyRanking] {
+        &history[history.len().saturating_sub(self.lookback_days)..]
+    }
 }
 
 /// 일별 랭킹 데이터 빌더.
@@ -387,6 +461,38 @@ mod tests {
         assert!(ranked[0].ticker == "A" || ranked[0].ticker == "B");
     }
 
+    #[test]
+    fn test_streak_distribution() {
+        let tracker = SurvivalTracker::new(15);
+        let history = create_test_history();
+
+        // A, B: 5일(진행 중), C: 1일, D: 3일, E: 2일(진행 중)
+        let distribution = tracker.streak_distribution(&history);
+        assert_eq!(distribution.get(&5), Some(&2));
+        assert_eq!(distribution.get(&3), Some(&1));
+        assert_eq!(distribution.get(&2), Some(&1));
+        assert_eq!(distribution.get(&1), Some(&1));
+
+        // 추적 기간 밖의 날짜는 제외
+        let short = SurvivalTracker::new(2);
+        let distribution = short.streak_distribution(&history);
+        assert_eq!(distribution.get(&2), Some(&3)); // A, B, E
+        assert_eq!(distribution.get(&1), Some(&1)); // D
+    }
+
+    #[test]
+    fn test_churn_rate() {
+        let tracker = SurvivalTracker::new(15);
+        let history = create_test_history();
+
+        // 1→2: C 탈락(1/3), 2→3: 0, 3→4: 0, 4→5: D 탈락(1/4)
+        let churn = tracker.churn_rate(&history).unwrap();
+        let expected = (1.0 / 3.0 + 0.25) / 4.0;
+        assert!((churn - expected).abs() < 1e-9);
+
+        assert!(tracker.churn_rate(&history[..1]).is_none());
+    }
+
     #[test]
     fn test_not_in_history() {
         let tracker = SurvivalTracker::new(15);
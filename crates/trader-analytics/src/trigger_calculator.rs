@@ -3,12 +3,16 @@
 //! 캔들 데이터를 분석하여 진입 신호를 감지하고 TriggerResult를 생성합니다.
 //! Phase 1-B.2 구현.
 
+use rust_decimal::prelude::ToPrimitive;
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 use thiserror::Error;
-use trader_core::{Kline, TriggerResult, TriggerType};
+use trader_core::{
+    ComparisonOperator, ConditionCheck, ConditionEvaluation, ConditionIndicator, Kline,
+    OrderCondition, TriggerResult, TriggerType,
+};
 
-use crate::indicators::{IndicatorEngine, IndicatorError};
+use crate::indicators::{EmaParams, IndicatorEngine, IndicatorError, RsiParams, SmaParams};
 
 #[cfg(feature = "ml")]
 use crate::ml::pattern::{CandlestickPatternType, PatternRecognizer};
@@ -102,6 +106,99 @@ impl TriggerCalculator {
         Ok(TriggerResult::new(triggers))
     }
 
+    /// 조건부 주문 조건 평가.
+    ///
+    /// 최신 캔들(마지막 요소)을 기준으로 모든 조건을 평가합니다 (AND).
+    /// 크로스 연산자는 직전 캔들 값과 비교하며, 트리거 조건은 `calculate()` 결과를 사용합니다.
+    ///
+    /// # 인자
+    /// * `klines` - 캔들 데이터 (시간 오름차순)
+    /// * `conditions` - 평가할 조건 목록 (비어 있으면 충족되지 않음)
+    ///
+    /// # 오류
+    /// - InsufficientData: 지표 또는 트리거 계산에 필요한 캔들 부족
+    /// - IndicatorError: 지표 계산 실패
+    pub fn evaluate_conditions(
+        &self,
+        klines: &[Kline],
+        conditions: &[OrderCondition],
+    ) -> TriggerCalculatorResult<ConditionEvaluation> {
+        let last = klines.last().ok_or(TriggerError::InsufficientData {
+            required: 1,
+            provided: 0,
+        })?;
+        let closes: Vec<Decimal> = klines.iter().map(|k| k.close).collect();
+        let previous_close = closes.len().checked_sub(2).map(|i| closes[i]);
+
+        let mut trigger_result: Option<TriggerResult> = None;
+        let mut checks = Vec::with_capacity(conditions.len());
+
+        for condition in conditions {
+            let (satisfied, observed) = match condition {
+                OrderCondition::Price { operator, value } => (
+                    compare(*operator, last.close, *value, previous_close),
+                    Some(last.close),
+                ),
+                OrderCondition::Indicator {
+                    indicator,
+                    operator,
+                    value,
+                } => {
+                    let series = self.indicator_series(&closes, *indicator)?;
+                    let Some(current) = series.last().copied().flatten() else {
+                        return Err(TriggerError::InsufficientData {
+                            required: indicator.period() + 1,
+                            provided: klines.len(),
+                        });
+                    };
+                    let previous = series.len().checked_sub(2).and_then(|i| series[i]);
+                    (compare(*operator, current, *value, previous), Some(current))
+                }
+                OrderCondition::Trigger { trigger } => {
+                    if trigger_result.is_none() {
+                        trigger_result = Some(self.calculate(klines)?);
+                    }
+                    let detected = trigger_result
+                        .as_ref()
+                        .is_some_and(|r| r.triggers.contains(trigger));
+                    (detected, None)
+                }
+            };
+
+            checks.push(ConditionCheck {
+                condition: condition.clone(),
+                satisfied,
+                observed,
+            });
+        }
+
+        Ok(ConditionEvaluation {
+            satisfied: !checks.is_empty() && checks.iter().all(|c| c.satisfied),
+            checks,
+            last_price: last.close,
+        })
+    }
+
+    /// 조건 지표 시계열 계산.
+    fn indicator_series(
+        &self,
+        closes: &[Decimal],
+        indicator: ConditionIndicator,
+    ) -> TriggerCalculatorResult<Vec<Option<Decimal>>> {
+        let series = match indicator {
+            ConditionIndicator::Rsi { period } => {
+                self.indicator_engine.rsi(closes, RsiParams { period })?
+            }
+            ConditionIndicator::Sma { period } => {
+                self.indicator_engine.sma(closes, SmaParams { period })?
+            }
+            ConditionIndicator::Ema { period } => {
+                self.indicator_engine.ema(closes, EmaParams { period })?
+            }
+        };
+        Ok(series)
+    }
+
     /// 캔들 패턴 감지.
     ///
     /// Hammer와 Engulfing 패턴을 감지합니다.
@@ -192,8 +289,9 @@ impl TriggerCalculator {
             return Ok(false);
         }
 
-        let recent = &klines[klines.len() - LOOKBACK..];
-        let current = klines.last().unwrap();
+        // 돌파 판단 캔들은 박스권 계산에서 제외
+        let (current, previous) = klines.split_last().unwrap();
+        let recent = &previous[previous.len() - LOOKBACK..];
 
         // 최고가와 최저가 찾기
         let high_prices: Vec<Decimal> = recent.iter().map(|k| k.high).collect();
@@ -279,6 +377,21 @@ impl TriggerCalculator {
     }
 }
 
+/// 조건 비교 (크로스 연산자는 직전 값 사용).
+fn compare(
+    operator: ComparisonOperator,
+    current: Decimal,
+    threshold: Decimal,
+    previous: Option<Decimal>,
+) -> bool {
+    operator.evaluate(
+        current.to_f64().unwrap_or_default(),
+        threshold.to_f64().unwrap_or_default(),
+        previous.and_then(|p| p.to_f64()),
+        None,
+    )
+}
+
 impl Default for TriggerCalculator {
     fn default() -> Self {
         Self::new()
@@ -290,7 +403,7 @@ mod tests {
     use super::*;
     use chrono::{TimeZone, Utc};
     use rust_decimal_macros::dec;
-    use trader_core::{Symbol, Timeframe};
+    use trader_core::Timeframe;
 
     fn create_test_kline(
         open: Decimal,
@@ -413,7 +526,7 @@ mod tests {
 
         // 하락 후 반등 패턴 (RSI가 30 → 60으로 상승)
         for i in 0..10 {
-            let price = dec!(100) - Decimal::from(i as i64);
+            let price = dec!(100) - Decimal::from(i);
             klines.push(create_test_kline(
                 price,
                 price + dec!(1),
@@ -426,7 +539,7 @@ mod tests {
 
         // 반등 시작
         for i in 10..30 {
-            let price = dec!(90) + Decimal::from(i as i64 - 10) * dec!(2);
+            let price = dec!(90) + Decimal::from(i - 10) * dec!(2);
             klines.push(create_test_kline(
                 price,
                 price + dec!(2),
@@ -449,7 +562,7 @@ mod tests {
         let mut klines = Vec::new();
 
         for i in 0..30 {
-            let price = dec!(100) + Decimal::from(i as i64);
+            let price = dec!(100) + Decimal::from(i);
             klines.push(create_test_kline(
                 price,
                 price + dec!(2),
@@ -505,4 +618,72 @@ mod tests {
         assert!(result1.is_ok());
         assert!(result2.is_ok());
     }
+
+    #[test]
+    fn test_evaluate_conditions() {
+        let calculator = TriggerCalculator::new();
+        // 종가 101 ~ 150 상승 추세
+        let klines = create_uptrend_klines(50);
+
+        let cross = OrderCondition::Price {
+            operator: ComparisonOperator::CrossAbove,
+            value: dec!(149.5),
+        };
+        let rsi_below_60 = OrderCondition::Indicator {
+            indicator: ConditionIndicator::Rsi { period: 14 },
+            operator: ComparisonOperator::Lt,
+            value: dec!(60),
+        };
+
+        let result = calculator
+            .evaluate_conditions(&klines, std::slice::from_ref(&cross))
+            .unwrap();
+        assert!(result.satisfied);
+        assert_eq!(result.last_price, dec!(150));
+
+        // 이미 돌파한 가격은 크로스로 보지 않음
+        let stale_cross = OrderCondition::Price {
+            operator: ComparisonOperator::CrossAbove,
+            value: dec!(140),
+        };
+        let result = calculator
+            .evaluate_conditions(&klines, &[stale_cross])
+            .unwrap();
+        assert!(!result.satisfied);
+
+        // 지속 상승 중이므로 RSI < 60 불충족 → 전체 불충족
+        let result = calculator
+            .evaluate_conditions(&klines, &[cross, rsi_below_60])
+            .unwrap();
+        assert!(!result.satisfied);
+        assert!(result.checks[0].satisfied);
+        assert!(!result.checks[1].satisfied);
+        assert!(result.checks[1].observed.unwrap() > dec!(60));
+
+        // 조건이 없으면 실행하지 않음
+        assert!(
+            !calculator
+                .evaluate_conditions(&klines, &[])
+                .unwrap()
+                .satisfied
+        );
+    }
+
+    #[test]
+    fn test_evaluate_conditions_insufficient_data() {
+        let calculator = TriggerCalculator::new();
+        let klines = create_uptrend_klines(10);
+
+        let sma = OrderCondition::Indicator {
+            indicator: ConditionIndicator::Sma { period: 20 },
+            operator: ComparisonOperator::Gt,
+            value: dec!(100),
+        };
+        assert!(calculator.evaluate_conditions(&klines, &[sma]).is_err());
+
+        let trigger = OrderCondition::Trigger {
+            trigger: TriggerType::VolumeSpike,
+        };
+        assert!(calculator.evaluate_conditions(&klines, &[trigger]).is_err());
+    }
 }
@@ -45,7 +45,7 @@ fn test_multi_timeframe_in_memory_performance() {
     let start = Instant::now();
 
     let mut total_candles = 0;
-    for (_tf, klines) in &data {
+    for klines in data.values() {
         total_candles += klines.len();
         // 간단한 처리: 마지막 종가 확인
         let _last_close = klines.last().map(|k| k.close);
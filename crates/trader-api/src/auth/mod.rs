@@ -26,6 +26,6 @@ mod password;
 mod roles;
 
 pub use jwt::{create_token, decode_token, Claims, RefreshClaims, TokenPair};
-pub use middleware::{require_role, JwtAuth, JwtAuthError};
+pub use middleware::{require_role, AdminAuth, JwtAuth, JwtAuthError, OptionalJwtAuth};
 pub use password::{hash_password, verify_password, PasswordError};
 pub use roles::{Permission, Role};
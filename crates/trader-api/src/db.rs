@@ -0,0 +1,144 @@
+//! 데이터베이스 연결 풀 구성.
+//!
+//! 주문 경로의 쓰기와 무거운 분석/스크리닝 조회가 같은 풀을 공유하면, 느린 분석 쿼리가
+//! 커넥션을 붙잡아 주문 처리가 지연됩니다. 그래서 풀을 두 단계로 나눕니다.
+//!
+//! - **Primary** (`DATABASE_URL`): 주문, 포지션, 전략 상태 등 쓰기 경로. 짧은 `statement_timeout`
+//! - **Analytics** (`DATABASE_READ_URL`): 스크리닝, 랭킹, 성과 분석 등 읽기 전용 조회.
+//!   긴 `statement_timeout`. 읽기 복제본이 없으면 Primary 서버에 별도 풀로 연결합니다.
+//!
+//! 읽기 복제본은 복제 지연만큼 최신 데이터가 늦게 보이므로, 방금 쓴 데이터를 바로 읽어야 하는
+//! 경로는 Primary 풀을 사용해야 합니다.
+
+use std::str::FromStr;
+use std::time::Duration;
+
+use sqlx::postgres::{PgConnectOptions, PgPoolOptions};
+use sqlx::PgPool;
+
+/// 단일 풀 설정.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PoolTierConfig {
+    /// 연결 URL
+    pub url: String,
+    /// 최대 연결 수
+    pub max_connections: u32,
+    /// 커넥션 획득 대기 시간
+    pub acquire_timeout: Duration,
+    /// 쿼리 실행 제한 시간 (PostgreSQL `statement_timeout`, 0 = 무제한)
+    pub statement_timeout: Duration,
+}
+
+impl PoolTierConfig {
+    /// 풀 연결.
+    pub async fn connect(&self) -> Result<PgPool, sqlx::Error> {
+        let options = PgConnectOptions::from_str(&self.url)?.options([(
+            "statement_timeout",
+            self.statement_timeout.as_millis().to_string(),
+        )]);
+
+        PgPoolOptions::new()
+            .max_connections(self.max_connections)
+            .acquire_timeout(self.acquire_timeout)
+            .connect_with(options)
+            .await
+    }
+}
+
+/// 데이터베이스 풀 구성.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DbPoolConfig {
+    /// 주문 경로 (쓰기)
+    pub primary: PoolTierConfig,
+    /// 분석/스크리닝 조회 (읽기)
+    pub analytics: PoolTierConfig,
+    /// 분석 풀이 읽기 복제본에 연결되는지 여부
+    pub uses_read_replica: bool,
+}
+
+impl DbPoolConfig {
+    /// 환경 변수에서 설정 로드.
+    ///
+    /// `DATABASE_URL`이 없으면 None을 반환합니다.
+    ///
+    /// - `DATABASE_MAX_CONNECTIONS` (기본 10)
+    /// - `DATABASE_STATEMENT_TIMEOUT_MS` (기본 10000)
+    /// - `DATABASE_READ_URL` (없으면 `DATABASE_URL` 사용)
+    /// - `DATABASE_READ_MAX_CONNECTIONS` (기본 5)
+    /// - `DATABASE_READ_STATEMENT_TIMEOUT_MS` (기본 30000, HTTP 요청 타임아웃과 동일)
+    pub fn from_env() -> Option<Self> {
+        let url = std::env::var("DATABASE_URL").ok()?;
+        let read_url = std::env::var("DATABASE_READ_URL")
+            .ok()
+            .filter(|v| !v.trim().is_empty());
+        Some(Self::build(url, read_url, |key| std::env::var(key).ok()))
+    }
+
+    fn build(url: String, read_url: Option<String>, env: impl Fn(&str) -> Option<String>) -> Self {
+        let number = |key: &str, default: u64| -> u64 {
+            env(key)
+                .and_then(|v| v.trim().parse().ok())
+                .unwrap_or(default)
+        };
+
+        let uses_read_replica = read_url.is_some();
+        let primary = PoolTierConfig {
+            url: url.clone(),
+            max_connections: number("DATABASE_MAX_CONNECTIONS", 10) as u32,
+            acquire_timeout: Duration::from_secs(10),
+            statement_timeout: Duration::from_millis(number(
+                "DATABASE_STATEMENT_TIMEOUT_MS",
+                10_000,
+            )),
+        };
+        let analytics = PoolTierConfig {
+            url: read_url.unwrap_or(url),
+            max_connections: number("DATABASE_READ_MAX_CONNECTIONS", 5) as u32,
+            acquire_timeout: Duration::from_secs(30),
+            statement_timeout: Duration::from_millis(number(
+                "DATABASE_READ_STATEMENT_TIMEOUT_MS",
+                30_000,
+            )),
+        };
+
+        Self {
+            primary,
+            analytics,
+            uses_read_replica,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_analytics_tier_falls_back_to_primary_url() {
+        let env: HashMap<&str, &str> = HashMap::from([("DATABASE_STATEMENT_TIMEOUT_MS", "2000")]);
+        let config = DbPoolConfig::build("postgres://primary/trader".to_string(), None, |key| {
+            env.get(key).map(|v| v.to_string())
+        });
+
+        assert!(!config.uses_read_replica);
+        assert_eq!(config.analytics.url, "postgres://primary/trader");
+        assert_eq!(config.primary.statement_timeout, Duration::from_secs(2));
+        assert_eq!(config.analytics.statement_timeout, Duration::from_secs(30));
+    }
+
+    #[test]
+    fn test_read_replica_tier() {
+        let config = DbPoolConfig::build(
+            "postgres://primary/trader".to_string(),
+            Some("postgres://replica/trader".to_string()),
+            |_| None,
+        );
+
+        assert!(config.uses_read_replica);
+        assert_eq!(config.primary.url, "postgres://primary/trader");
+        assert_eq!(config.analytics.url, "postgres://replica/trader");
+        assert_eq!(config.primary.max_connections, 10);
+        assert_eq!(config.analytics.max_connections, 5);
+    }
+}
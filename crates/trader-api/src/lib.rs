@@ -16,14 +16,17 @@
 //! - [`metrics`]: Prometheus 메트릭 수집
 //! - [`middleware`]: HTTP 미들웨어
 //! - [`openapi`]: OpenAPI 문서 및 Swagger UI
+//! - [`quota`]: 사용자별 리소스 할당량
 
 pub mod auth;
 pub mod cache;
+pub mod db;
 pub mod error;
 pub mod metrics;
 pub mod middleware;
 pub mod monitoring;
 pub mod openapi;
+pub mod quota;
 pub mod repository;
 pub mod routes;
 pub mod services;
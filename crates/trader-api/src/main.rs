@@ -9,35 +9,63 @@ use std::time::Duration;
 
 use axum::{http::StatusCode, middleware, routing::get, Router};
 use metrics_exporter_prometheus::PrometheusHandle;
-use sqlx::postgres::PgPoolOptions;
+use tokio::sync::mpsc;
 use tokio_util::sync::CancellationToken;
 use tower_http::cors::{AllowOrigin, CorsLayer};
 use tower_http::timeout::TimeoutLayer;
 use tower_http::trace::TraceLayer;
 use tracing::{error, info, warn};
 
+use trader_api::db::DbPoolConfig;
 use trader_api::metrics::setup_metrics_recorder;
 use trader_api::middleware::{
-    metrics_layer, rate_limit_middleware, RateLimitConfig, RateLimitState,
+    metrics_layer, rate_limit_middleware, ApiKeyRegistry, RateLimitConfig, RateLimitState,
+    RedisRateLimitStore, RouteClassLimits,
 };
+use trader_api::monitoring::accounting_invariant_hook;
 use trader_api::openapi::swagger_ui_router;
-use trader_api::repository::StrategyRepository;
+use trader_api::repository::{
+    PgOrderIntentStore, PgStrategyStateStore, StrategyCapitalRepository, StrategyRepository,
+};
 use trader_api::routes::create_api_router;
+use trader_api::routes::earnings::load_earnings_calendar;
+use trader_api::routes::etf::load_etf_premiums;
+use trader_api::services::{
+    start_backtest_scheduler, start_broker_downtime_monitor, start_competition_runner,
+    start_conditional_order_service, start_correlation_monitor, start_dca_scheduler,
+    start_execution_fill_listener, start_hedge_overlay, start_market_calendar_sync,
+    start_market_condition_monitor, start_market_publisher, start_notification_digest,
+    start_order_circuit_monitor, start_orderbook_recorder, start_paper_trading,
+    start_reality_check_scheduler, start_reconciliation_service, start_shadow_runner,
+    start_strategy_equity_snapshots, start_trading_status_monitor, start_watchlist_alert_service,
+    start_webhook_publisher, BacktestSchedulerConfig, BrokerDowntimeMonitorConfig,
+    CalendarHolidaySource, CompetitionRunnerConfig, ConditionalOrderConfig,
+    CorrelationMonitorConfig, DcaSchedulerConfig, HedgeOverlayConfig, HistoricalWarmupSource,
+    MarketCalendarSyncConfig, MarketConditionConfig, MarketPublisherConfig,
+    NotificationDigestConfig, OrderBookRecorderConfig, RealityCheckSchedulerConfig,
+    ReconciliationConfig, ShadowRunnerConfig, StrategyEquitySnapshotConfig, StrategyWarmupConfig,
+    WatchlistAlertConfig,
+};
 use trader_api::state::AppState;
 use trader_api::websocket::{
     create_subscription_manager, standalone_websocket_router, start_aggregator, start_simulator,
     WsState,
 };
 use trader_core::crypto::CredentialEncryptor;
+use trader_core::TradingCostModel;
 use trader_data::cache::CachedHistoricalDataProvider;
 use trader_exchange::connector::kis::{
     KisConfig, KisKrClient, KisOAuth, KisUsClient,
 };
+use trader_exchange::connector::upbit::UpbitConfig;
 use trader_exchange::stream::UnifiedMarketStream;
-use trader_exchange::traits::MarketStream;
+use trader_exchange::traits::{MarketStream, UserEvent};
 use trader_exchange::KisKrProvider;
-use trader_execution::{ConversionConfig, OrderExecutor};
-use trader_risk::{RiskConfig, RiskManager};
+use trader_execution::{
+    BrokerDowntimeConfig, ConversionConfig, DowntimePolicy, ExecutionGovernorConfig,
+    LiquidityCapConfig, OrderExecutor, RiskProfile, RiskProfileName,
+};
+use trader_risk::{CurfewConfig, RiskConfig, RiskManager};
 use trader_strategy::{EngineConfig, StrategyEngine};
 
 /// 서버 설정 구조체.
@@ -124,10 +152,17 @@ fn load_kis_config() -> Option<KisConfig> {
 ///   예: "005930,000660,035720"
 /// - `DEFAULT_SYMBOLS_US`: 기본 구독 티커 (미국), 쉼표 구분
 ///   예: "AAPL,MSFT,SPY"
+/// - `DEFAULT_SYMBOLS_UPBIT`: 기본 구독 코인 (Upbit 원화 마켓), 쉼표 구분 (기본값: 없음)
+///   예: "BTC/KRW,ETH/KRW"
+/// - `KIS_HTS_ID`: 설정되어 있으면 같은 연결로 실시간 체결통보도 구독
+///
+/// # Returns
+///
+/// 실시간 체결통보 채널 (실제 거래소 연결이고 체결통보 구독에 성공한 경우)
 async fn start_market_data_source(
     subscriptions: trader_api::websocket::SharedSubscriptionManager,
     kis_config: Option<&KisConfig>,
-) -> bool {
+) -> Option<mpsc::Receiver<UserEvent>> {
     let use_real_exchange = std::env::var("USE_REAL_EXCHANGE")
         .map(|v| v == "true" || v == "1")
         .unwrap_or(false);
@@ -141,16 +176,15 @@ async fn start_market_data_source(
         if enable_simulator {
             start_simulator(subscriptions);
             info!("Mock data simulator started");
-            return true;
         }
-        return false;
+        return None;
     }
 
     // 실제 거래소 연결 시도
     let Some(config) = kis_config else {
         warn!("USE_REAL_EXCHANGE=true but KIS not configured, falling back to mock");
         start_simulator(subscriptions);
-        return true;
+        return None;
     };
 
     // 기본 구독 심볼 파싱
@@ -168,9 +202,17 @@ async fn start_market_data_source(
         .filter(|s| !s.is_empty())
         .collect();
 
+    let upbit_symbols: Vec<String> = std::env::var("DEFAULT_SYMBOLS_UPBIT")
+        .unwrap_or_default()
+        .split(',')
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .collect();
+
     info!(
         kr_count = kr_symbols.len(),
         us_count = us_symbols.len(),
+        upbit_count = upbit_symbols.len(),
         "Starting real-time market stream"
     );
 
@@ -180,7 +222,7 @@ async fn start_market_data_source(
         Err(e) => {
             error!(error = %e, "Failed to create KR OAuth");
             start_simulator(subscriptions);
-            return true;
+            return None;
         }
     };
     let oauth_us = match KisOAuth::new(config.clone()) {
@@ -188,12 +230,16 @@ async fn start_market_data_source(
         Err(e) => {
             error!(error = %e, "Failed to create US OAuth");
             start_simulator(subscriptions);
-            return true;
+            return None;
         }
     };
     let mut stream = UnifiedMarketStream::new()
         .with_kr_stream(oauth_kr)
         .with_us_stream(oauth_us);
+    if !upbit_symbols.is_empty() {
+        // 시세 스트림은 공개 API이므로 키가 없어도 연결 가능
+        stream = stream.with_upbit_stream(UpbitConfig::from_env().unwrap_or_default());
+    }
 
     // 구독 설정 (연결 전에 설정해야 함)
     for code in &kr_symbols {
@@ -212,18 +258,43 @@ async fn start_market_data_source(
         }
     }
 
+    for ticker in &upbit_symbols {
+        if let Err(e) = stream.subscribe_ticker(ticker).await {
+            warn!(symbol = %ticker, error = %e, "Failed to subscribe Upbit symbol");
+        } else {
+            info!(symbol = %ticker, "Subscribed to Upbit ticker");
+        }
+    }
+
+    // 실시간 체결통보 구독 (HTS ID가 있을 때만, 연결 전에 설정해야 함)
+    let fills = if config.hts_id.is_some() {
+        match stream.subscribe_executions().await {
+            Ok(fills) => {
+                info!("Subscribed to KIS execution notices");
+                Some(fills)
+            }
+            Err(e) => {
+                warn!(error = %e, "Failed to subscribe KIS execution notices");
+                None
+            }
+        }
+    } else {
+        info!("KIS_HTS_ID not set, execution notices disabled");
+        None
+    };
+
     // 스트림 시작
     if let Err(e) = stream.start_all().await {
         error!(error = %e, "Failed to start market stream, falling back to mock");
         start_simulator(subscriptions);
-        return true;
+        return None;
     }
 
     // 어그리게이터 시작
     start_aggregator(subscriptions, stream);
     info!("Real-time market data aggregator started with KIS");
 
-    true
+    fills
 }
 
 /// KIS 클라이언트 생성 (국내 + 해외).
@@ -290,31 +361,110 @@ async fn create_app_state(config: &ServerConfig) -> AppState {
     let risk_manager = RiskManager::new(RiskConfig::default(), config.initial_balance);
 
     // 주문 실행기 생성
-    let executor = OrderExecutor::new_complete(
+    let mut executor = OrderExecutor::new_complete(
         RiskManager::new(RiskConfig::default(), config.initial_balance),
         "default_exchange",
         ConversionConfig::default(),
     );
 
+    // 기본 거래 비용 모델 (DEFAULT_COST_MODEL=KR_KOSPI, KR_KOSDAQ, US_STOCK, CRYPTO)
+    if let Some(name) = std::env::var("DEFAULT_COST_MODEL")
+        .ok()
+        .filter(|v| !v.trim().is_empty())
+    {
+        match TradingCostModel::preset(&name) {
+            Some(model) => executor = executor.with_cost_model(model),
+            None => warn!("Unknown DEFAULT_COST_MODEL: {}", name),
+        }
+    }
+
+    // 유동성 수량 상한 (LIQUIDITY_CAP_ENABLED, LIQUIDITY_CAP_ADV_FRACTION)
+    let mut liquidity_cap = LiquidityCapConfig::default();
+    if let Ok(v) = std::env::var("LIQUIDITY_CAP_ENABLED") {
+        liquidity_cap.enabled = v.to_lowercase() != "false";
+    }
+    if let Some(fraction) = std::env::var("LIQUIDITY_CAP_ADV_FRACTION")
+        .ok()
+        .and_then(|v| v.parse::<rust_decimal::Decimal>().ok())
+        .filter(|v| *v > rust_decimal::Decimal::ZERO && *v <= rust_decimal::Decimal::ONE)
+    {
+        liquidity_cap.max_adv_fraction = fraction;
+    }
+    executor = executor.with_liquidity_cap(liquidity_cap);
+
+    // 실행 조절 (EXECUTION_GOVERNOR_ENABLED, 전략별 제외는 PUT /strategies/{id}/execution-governor)
+    let mut execution_governor = ExecutionGovernorConfig::default();
+    if let Ok(v) = std::env::var("EXECUTION_GOVERNOR_ENABLED") {
+        execution_governor.enabled = v.to_lowercase() != "false";
+    }
+    if let Some(factor) = std::env::var("EXECUTION_GOVERNOR_SIZE_FACTOR")
+        .ok()
+        .and_then(|v| v.parse::<rust_decimal::Decimal>().ok())
+        .filter(|v| *v > rust_decimal::Decimal::ZERO && *v <= rust_decimal::Decimal::ONE)
+    {
+        execution_governor.size_factor = factor;
+    }
+    executor = executor.with_execution_governor(execution_governor);
+
+    // 장중 커퓨 (CURFEW_ENABLED, 전략별 오버라이드는 PUT /strategies/{id}/curfew)
+    let mut curfew = CurfewConfig::default();
+    if let Ok(v) = std::env::var("CURFEW_ENABLED") {
+        curfew.enabled = v.to_lowercase() != "false";
+    }
+    executor = executor.with_curfew(curfew);
+
+    // 브로커 점검/장애 중 주문 처리 (BROKER_DOWNTIME_POLICY=reject|queue)
+    // 달력은 AppState와 공유되며 점검/장애 모니터가 갱신
+    let mut broker_downtime = BrokerDowntimeConfig::default();
+    if let Some(broker) = std::env::var("BROKER_DOWNTIME_BROKER")
+        .ok()
+        .filter(|v| !v.trim().is_empty())
+    {
+        broker_downtime.broker = broker.trim().to_lowercase();
+    }
+    if let Ok(v) = std::env::var("BROKER_DOWNTIME_POLICY") {
+        match v.to_lowercase().as_str() {
+            "reject" => broker_downtime.policy = DowntimePolicy::Reject,
+            "queue" => broker_downtime.policy = DowntimePolicy::Queue,
+            other => warn!("Unknown BROKER_DOWNTIME_POLICY: {}", other),
+        }
+    }
+    executor = executor.with_broker_downtime(broker_downtime, Arc::default());
+
+    // 회계 불변식 위반은 모니터링 에러(Critical)로 기록
+    executor.set_invariant_hook(accounting_invariant_hook()).await;
+
     // KIS 클라이언트 생성 (환경변수 설정 시)
     let (kis_kr, kis_us) = create_kis_clients();
 
     // AppState 빌드
     let mut state = AppState::new(strategy_engine, risk_manager, executor);
 
-    // DB 연결 설정 (DATABASE_URL 환경변수에서)
-    if let Ok(database_url) = std::env::var("DATABASE_URL") {
-        match PgPoolOptions::new()
-            .max_connections(10)
-            .acquire_timeout(Duration::from_secs(10))
-            .connect(&database_url)
-            .await
-        {
+    // DB 연결 설정 (DATABASE_URL, 분석용 DATABASE_READ_URL 환경변수에서)
+    if let Some(db_config) = DbPoolConfig::from_env() {
+        match db_config.primary.connect().await {
             Ok(pool) => {
                 // 연결 테스트
                 if sqlx::query("SELECT 1").fetch_one(&pool).await.is_ok() {
                     info!("Connected to TimescaleDB successfully");
 
+                    // 분석/스크리닝 조회 풀 (실패 시 Primary 풀 공유)
+                    match db_config.analytics.connect().await {
+                        Ok(analytics_pool) => {
+                            info!(
+                                read_replica = db_config.uses_read_replica,
+                                "Analytics database pool initialized"
+                            );
+                            state = state.with_analytics_pool(analytics_pool);
+                        }
+                        Err(e) => {
+                            warn!(
+                                "Failed to connect analytics database pool, using primary: {}",
+                                e
+                            );
+                        }
+                    }
+
                     // Phase 0-1: CachedHistoricalDataProvider 및 분석 인프라 초기화
                     let data_provider = CachedHistoricalDataProvider::new(pool.clone());
                     state = state
@@ -322,6 +472,32 @@ async fn create_app_state(config: &ServerConfig) -> AppState {
                         .with_data_provider(data_provider)
                         .with_analytics_infrastructure();
                     info!("Analytics infrastructure initialized (Phase 0-1)");
+
+                    // 전략 시작 시 워밍업 캔들 자동 조회 (STRATEGY_WARMUP_ENABLED)
+                    if let (Some(provider), Some(warmup_config)) = (
+                        state.data_provider.clone(),
+                        StrategyWarmupConfig::from_env(),
+                    ) {
+                        state
+                            .strategy_engine
+                            .write()
+                            .await
+                            .set_warmup_source(Arc::new(HistoricalWarmupSource::new(
+                                provider,
+                                warmup_config,
+                            )));
+                        info!("Strategy warmup source initialized");
+                    }
+
+                    // 전략 내부 상태 영속화 (재시작 후 분할 매수/라운드 진행 상태 복원)
+                    if let Some(pool) = state.db_pool.clone() {
+                        state
+                            .strategy_engine
+                            .write()
+                            .await
+                            .set_state_store(Arc::new(PgStrategyStateStore::new(pool)));
+                        info!("Strategy state store initialized");
+                    }
                 } else {
                     error!("Failed to verify database connection");
                 }
@@ -505,6 +681,49 @@ fn rate_limit_config() -> RateLimitConfig {
     RateLimitConfig::new(requests_per_minute)
 }
 
+/// 경로 등급별 Rate Limit 설정 로드.
+///
+/// 일반 엔드포인트는 `RATE_LIMIT_RPM`, 나머지는 `RATE_LIMIT_AUTH_RPM`,
+/// `RATE_LIMIT_MARKET_RPM`, `RATE_LIMIT_ORDER_RPM`으로 조정합니다 (미설정 시 기본값).
+fn route_class_limits() -> RouteClassLimits {
+    let env_rpm = |name: &str| std::env::var(name).ok().and_then(|v| v.parse::<u32>().ok());
+    let defaults = RouteClassLimits::default();
+
+    let limits = RouteClassLimits {
+        auth: env_rpm("RATE_LIMIT_AUTH_RPM")
+            .map(RateLimitConfig::new)
+            .unwrap_or(defaults.auth),
+        market_data: env_rpm("RATE_LIMIT_MARKET_RPM")
+            .map(RateLimitConfig::new)
+            .unwrap_or(defaults.market_data),
+        orders: env_rpm("RATE_LIMIT_ORDER_RPM")
+            .map(RateLimitConfig::new)
+            .unwrap_or(defaults.orders),
+        general: rate_limit_config(),
+    };
+
+    info!(
+        auth_rpm = limits.auth.requests_per_minute,
+        market_rpm = limits.market_data.requests_per_minute,
+        order_rpm = limits.orders.requests_per_minute,
+        "Route class rate limits configured"
+    );
+
+    limits
+}
+
+/// Rate Limit 주체로 인정할 API 키 로드.
+///
+/// `RATE_LIMIT_API_KEYS`에 `계정ID:SHA256hex` 항목을 쉼표로 구분해 설정합니다.
+/// 등록되지 않은 `X-API-Key`는 IP 기준으로 제한됩니다.
+fn rate_limit_api_keys() -> ApiKeyRegistry {
+    let registry = std::env::var("RATE_LIMIT_API_KEYS")
+        .map(|spec| ApiKeyRegistry::parse(&spec))
+        .unwrap_or_default();
+    info!(count = registry.len(), "Rate limit API keys configured");
+    registry
+}
+
 /// 전체 라우터 생성.
 fn create_router(
     state: Arc<AppState>,
@@ -521,7 +740,14 @@ fn create_router(
         info!("Rate limiting DISABLED (RATE_LIMIT_DISABLED=true)");
         create_api_router().with_state(state)
     } else {
-        let rate_limit_state = RateLimitState::new(rate_limit_config());
+        // 경로 등급 × 요청 주체(사용자/API 키/IP)별 한도, Redis가 있으면 카운터 공유
+        let mut rate_limit_state = RateLimitState::with_defaults()
+            .with_route_limits(route_class_limits())
+            .with_api_keys(rate_limit_api_keys())
+            .with_user_quotas(state.quotas.clone());
+        if let Some(cache) = state.cache.clone() {
+            rate_limit_state = rate_limit_state.with_store(RedisRateLimitStore::new(cache));
+        }
         create_api_router()
             .with_state(state)
             .layer(middleware::from_fn_with_state(
@@ -630,7 +856,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let kis_config = load_kis_config();
 
     // 실시간 시장 데이터 소스 시작 (KIS 또는 Mock)
-    start_market_data_source(subscriptions.clone(), kis_config.as_ref()).await;
+    let execution_fills =
+        start_market_data_source(subscriptions.clone(), kis_config.as_ref()).await;
 
     // WebSocket 상태 생성 (subscriptions clone 사용)
     let ws_state = WsState::new(subscriptions.clone(), jwt_secret);
@@ -666,6 +893,249 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         warn!("ContextSyncService 시작 실패: ExchangeProvider 또는 AnalyticsProvider 미설정");
     }
 
+    // 시장 데이터 외부 발행 (MQTT/Redis, MARKET_PUBLISH_URL 설정 시)
+    if let (Some(publisher_config), Some(subscriptions)) =
+        (MarketPublisherConfig::from_env(), state.subscriptions.clone())
+    {
+        match start_market_publisher(subscriptions, publisher_config, shutdown_token.clone()).await
+        {
+            Ok(_) => info!("Market data publisher started"),
+            Err(e) => warn!("Market data publisher 시작 실패: {}", e),
+        }
+    }
+
+    // 호가창 파생 지표 기록 (연구용, ORDERBOOK_RECORD_ENABLED=true 시)
+    let _orderbook_recorder_handle = match (
+        state.db_pool.clone(),
+        state.subscriptions.clone(),
+        OrderBookRecorderConfig::from_env(),
+    ) {
+        (Some(pool), Some(subscriptions), Some(config)) => Some(start_orderbook_recorder(
+            pool,
+            subscriptions,
+            config,
+            shutdown_token.clone(),
+        )),
+        _ => None,
+    };
+
+    // 주문 의도 DB 기록 (멱등성 키 선점으로 재시작/다중 인스턴스 간 중복 주문 방지)
+    if let Some(pool) = state.db_pool.clone() {
+        state
+            .executor
+            .read()
+            .await
+            .set_order_intent_store(Arc::new(PgOrderIntentStore::new(pool)))
+            .await;
+        info!("Order intent store enabled");
+    }
+
+    // 주문 서킷 모니터 시작 (상태 전이를 WebSocket으로 브로드캐스트)
+    let _circuit_handle = start_order_circuit_monitor(state.clone(), shutdown_token.clone()).await;
+    info!("Order circuit monitor started");
+
+    // 브로커 점검/장애 모니터 (예정 점검 적재, 주문 서킷 기반 장애 감지 → 실행기/전략 컨텍스트)
+    let _broker_downtime_handle = match BrokerDowntimeMonitorConfig::from_env() {
+        Some(config) => Some(
+            start_broker_downtime_monitor(
+                state.clone(),
+                state.db_pool.clone(),
+                config,
+                shutdown_token.clone(),
+            )
+            .await,
+        ),
+        None => None,
+    };
+
+    // 실시간 체결통보 반영 (KIS 체결통보 → 실행기 OrderManager/포지션)
+    let _execution_fill_handle = execution_fills
+        .map(|fills| start_execution_fill_listener(state.clone(), fills, shutdown_token.clone()));
+
+    // 종목 거래정지/VI 반영 (실시간 장운영정보 → 전략 엔진 컨텍스트, 실행기 주문 보류)
+    let _trading_status_handle = state.subscriptions.clone().map(|subscriptions| {
+        start_trading_status_monitor(state.clone(), subscriptions, shutdown_token.clone())
+    });
+
+    // 실행 조절용 시장 상황 갱신 (마감 캔들 변동성, 호가 스프레드 → 실행기)
+    let _market_condition_handle = match (
+        state.subscriptions.clone(),
+        MarketConditionConfig::from_env(),
+    ) {
+        (Some(subscriptions), Some(config)) => Some(start_market_condition_monitor(
+            state.clone(),
+            subscriptions,
+            config,
+            shutdown_token.clone(),
+        )),
+        _ => None,
+    };
+
+    // 실시간 시세 기반 모의투자 (모의투자 전략 신호 → 전략별 시뮬레이션 거래소 가상 계좌)
+    let _paper_trading_handle = state.subscriptions.clone().map(|subscriptions| {
+        start_paper_trading(state.clone(), subscriptions, shutdown_token.clone())
+    });
+
+    // 아웃바운드 웹훅 발행 (체결/포지션/전략 이벤트)
+    let _webhook_handle = match state.db_pool.clone() {
+        Some(pool) => {
+            Some(start_webhook_publisher(state.clone(), pool, shutdown_token.clone()).await)
+        }
+        None => None,
+    };
+
+    // 텔레그램 알림 다이제스트 (낮은 우선순위 알림/체결을 하루 한 번 요약 전송)
+    let _notification_digest_handle = match NotificationDigestConfig::from_env() {
+        Some(config) => {
+            Some(start_notification_digest(state.clone(), config, shutdown_token.clone()).await)
+        }
+        None => None,
+    };
+
+    // 정기 백테스트 (템플릿 재실행 및 성과 악화 알림)
+    let _backtest_scheduler_handle =
+        match (state.db_pool.clone(), BacktestSchedulerConfig::from_env()) {
+            (Some(pool), Some(config)) => Some(start_backtest_scheduler(
+                state.clone(),
+                pool,
+                config,
+                shutdown_token.clone(),
+            )),
+            _ => None,
+        };
+
+    // 실거래 vs 섀도 비교 (체결가 비교 및 SHADOW_RUNNER_ENABLED 시 자동 연결)
+    let _shadow_runner_handle = start_shadow_runner(
+        state.clone(),
+        ShadowRunnerConfig::from_env(),
+        shutdown_token.clone(),
+    )
+    .await;
+
+    // 전략 경쟁 (참가자 모의 운용, 평가액 기록, 종료 시 자동 승격)
+    let _competition_runner_handle =
+        match (state.db_pool.clone(), CompetitionRunnerConfig::from_env()) {
+            (Some(pool), Some(config)) => Some(start_competition_runner(
+                state.clone(),
+                pool,
+                config,
+                shutdown_token.clone(),
+            )),
+            _ => None,
+        };
+
+    // 서버 측 조건부 주문 (조건 평가, 실행, 만료 처리)
+    let _conditional_order_handle =
+        match (state.db_pool.clone(), ConditionalOrderConfig::from_env()) {
+            (Some(pool), Some(config)) => Some(start_conditional_order_service(
+                state.clone(),
+                pool,
+                config,
+                shutdown_token.clone(),
+            )),
+            _ => None,
+        };
+
+    // KRX/미국 휴장일 달력 연간 동기화 (시장 상태 API, 휴장일 확인에 사용)
+    let _market_calendar_handle =
+        match (state.db_pool.clone(), MarketCalendarSyncConfig::from_env()) {
+            (Some(pool), Some(config)) => Some(start_market_calendar_sync(
+                state.clone(),
+                pool,
+                config,
+                shutdown_token.clone(),
+            )),
+            _ => None,
+        };
+
+    // 관심종목 가격/지표 알림 평가
+    let _watchlist_alert_handle = match (state.db_pool.clone(), WatchlistAlertConfig::from_env()) {
+        (Some(pool), Some(config)) => Some(start_watchlist_alert_service(
+            state.clone(),
+            pool,
+            config,
+            shutdown_token.clone(),
+        )),
+        _ => None,
+    };
+
+    // 정액 적립식(DCA) 스케줄러
+    let _dca_scheduler_handle = match (state.db_pool.clone(), DcaSchedulerConfig::from_env()) {
+        (Some(pool), Some(config)) => Some(start_dca_scheduler(
+            state.clone(),
+            pool,
+            config,
+            shutdown_token.clone(),
+        )),
+        _ => None,
+    };
+
+    // 시장 간 상관관계 모니터 (롤링 상관 이력, 레짐 변화 알림)
+    let _correlation_monitor_handle =
+        match (state.db_pool.clone(), CorrelationMonitorConfig::from_env()) {
+            (Some(pool), Some(config)) => Some(start_correlation_monitor(
+                state.clone(),
+                pool,
+                config,
+                shutdown_token.clone(),
+            )),
+            _ => None,
+        };
+
+    // 포트폴리오 베타 헤지 오버레이 (일별 인버스 ETF/선물 헤지 조정)
+    let _hedge_overlay_handle = match (state.db_pool.clone(), HedgeOverlayConfig::from_env()) {
+        (Some(pool), Some(config)) => Some(start_hedge_overlay(
+            state.clone(),
+            pool,
+            config,
+            shutdown_token.clone(),
+        )),
+        _ => None,
+    };
+
+    // 브로커 잔고/포지션/체결과 로컬 상태 사후 대사 (거래소 Provider 필요)
+    let _reconciliation_handle = match (state.db_pool.clone(), ReconciliationConfig::from_env()) {
+        (Some(pool), Some(config)) if state.has_exchange_provider() => Some(
+            start_reconciliation_service(state.clone(), pool, config, shutdown_token.clone()),
+        ),
+        _ => None,
+    };
+
+    // 추천 검증 정기 계산 (장 마감 후 전일 추천 vs 당일 종가)
+    let _reality_check_handle = match (
+        state.db_pool.clone(),
+        RealityCheckSchedulerConfig::from_env(),
+    ) {
+        (Some(pool), Some(config)) => Some(start_reality_check_scheduler(
+            state.clone(),
+            pool,
+            config,
+            shutdown_token.clone(),
+        )),
+        _ => None,
+    };
+
+    // 전략별 가상 계좌 자산 스냅샷 저장
+    let _strategy_equity_handle = match (
+        state.db_pool.clone(),
+        StrategyEquitySnapshotConfig::from_env(),
+    ) {
+        (Some(pool), Some(config)) => Some(start_strategy_equity_snapshots(
+            state.clone(),
+            pool,
+            config,
+            shutdown_token.clone(),
+        )),
+        _ => None,
+    };
+
+    // 거래 시간대 휴장일 판정 (시장 달력, 달력에 없는 연도는 KIS 휴장일 API)
+    state
+        .strategy_engine
+        .write()
+        .await
+        .set_holiday_source(Arc::new(CalendarHolidaySource::new(&state)));
+
     // 데이터베이스에서 저장된 전략 로드
     if let Some(ref pool) = state.db_pool {
         let engine = state.strategy_engine.read().await;
@@ -681,6 +1151,147 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 warn!("Failed to load strategies from database: {:?}", e);
             }
         }
+
+        // 전략별 실적 발표 필터와 발표 일정을 엔진에 등록
+        match StrategyRepository::load_earnings_filters(pool).await {
+            Ok(filters) => {
+                let count = filters.len();
+                for (strategy_id, filter) in filters {
+                    if let Err(e) = engine
+                        .set_strategy_earnings_filter(&strategy_id, Some(filter))
+                        .await
+                    {
+                        warn!(strategy_id = %strategy_id, "Failed to apply earnings filter: {}", e);
+                    }
+                }
+                info!(count, "Loaded strategy earnings filters");
+            }
+            Err(e) => warn!("Failed to load strategy earnings filters: {:?}", e),
+        }
+
+        // 전략별 거래 시간대를 엔진에 등록
+        match StrategyRepository::load_trading_windows(pool).await {
+            Ok(windows) => {
+                let count = windows.len();
+                for (strategy_id, window) in windows {
+                    if let Err(e) = engine
+                        .set_strategy_trading_window(&strategy_id, Some(window))
+                        .await
+                    {
+                        warn!(strategy_id = %strategy_id, "Failed to apply trading window: {}", e);
+                    }
+                }
+                info!(count, "Loaded strategy trading windows");
+            }
+            Err(e) => warn!("Failed to load strategy trading windows: {:?}", e),
+        }
+        match load_earnings_calendar(pool, &engine).await {
+            Ok(count) => info!(count, "Loaded earnings calendar into strategy engine"),
+            Err(e) => warn!("Failed to load earnings calendar: {:?}", e),
+        }
+
+        // 모의투자 모드 전략을 엔진에 등록하고 가상 계좌 개설 (잔고는 초기 자본부터 시작)
+        match StrategyRepository::load_paper_trading_strategies(pool).await {
+            Ok(strategy_ids) => {
+                let mut count = 0;
+                for strategy_id in strategy_ids {
+                    match engine.set_strategy_paper_trading(&strategy_id, true).await {
+                        Ok(()) => {
+                            state.paper_trading.open(&strategy_id).await;
+                            count += 1;
+                        }
+                        Err(e) => {
+                            warn!(strategy_id = %strategy_id, "Failed to enable paper trading: {}", e)
+                        }
+                    }
+                }
+                info!(count, "Loaded paper trading strategies");
+            }
+            Err(e) => warn!("Failed to load paper trading strategies: {:?}", e),
+        }
+
+        // 전략별 거래 비용 모델을 실행기에 등록
+        let executor = state.executor.read().await;
+        match StrategyRepository::load_cost_models(pool).await {
+            Ok(models) => {
+                let count = models.len();
+                for (strategy_id, model) in models {
+                    executor
+                        .set_strategy_cost_model(&strategy_id, Some(model))
+                        .await;
+                }
+                info!(count, "Loaded strategy cost models");
+            }
+            Err(e) => warn!("Failed to load strategy cost models: {:?}", e),
+        }
+
+        // 정규장 외 거래를 허용한 전략을 실행기에 등록
+        match StrategyRepository::load_extended_hours_strategies(pool).await {
+            Ok(strategy_ids) => {
+                let count = strategy_ids.len();
+                for strategy_id in strategy_ids {
+                    executor
+                        .set_strategy_extended_hours(&strategy_id, true)
+                        .await;
+                }
+                info!(count, "Loaded extended-hours strategies");
+            }
+            Err(e) => warn!("Failed to load extended-hours strategies: {:?}", e),
+        }
+
+        // 전략별 커퓨 오버라이드를 실행기에 등록
+        match StrategyRepository::load_curfew_overrides(pool).await {
+            Ok(overrides) => {
+                let count = overrides.len();
+                for (strategy_id, curfew) in overrides {
+                    executor.set_strategy_curfew(&strategy_id, Some(curfew)).await;
+                }
+                info!(count, "Loaded strategy curfew overrides");
+            }
+            Err(e) => warn!("Failed to load strategy curfew overrides: {:?}", e),
+        }
+
+        // 실행 조절에서 제외한 전략을 실행기에 등록
+        match StrategyRepository::load_execution_governor_opt_outs(pool).await {
+            Ok(strategy_ids) => {
+                let count = strategy_ids.len();
+                for strategy_id in strategy_ids {
+                    executor
+                        .set_strategy_governor_opt_out(&strategy_id, true)
+                        .await;
+                }
+                info!(count, "Loaded execution governor opt-outs");
+            }
+            Err(e) => warn!("Failed to load execution governor opt-outs: {:?}", e),
+        }
+
+        // 기본 리스크 프로필을 지정한 전략을 실행기에 등록
+        match StrategyRepository::load_risk_profiles(pool).await {
+            Ok(profiles) => {
+                let count = profiles.len();
+                for (strategy_id, profile) in profiles {
+                    let profile = profile.parse::<RiskProfileName>().ok().map(RiskProfile::preset);
+                    executor.set_strategy_risk_profile(&strategy_id, profile).await;
+                }
+                info!(count, "Loaded strategy risk profiles");
+            }
+            Err(e) => warn!("Failed to load strategy risk profiles: {:?}", e),
+        }
+
+        // 전략별 할당 자본을 실행기 자본 원장에 등록
+        let mut risk_manager = executor.risk_manager().write().await;
+        match StrategyCapitalRepository::load_into_ledger(pool, risk_manager.capital_ledger_mut())
+            .await
+        {
+            Ok(count) => info!(count, "Loaded strategy capital allocations"),
+            Err(e) => warn!("Failed to load strategy capital allocations: {:?}", e),
+        }
+
+        // ETF 괴리율을 리스크 필터에 반영 (수집기가 적재한 최신값)
+        match load_etf_premiums(pool, &mut risk_manager).await {
+            Ok(count) => info!(count, "Loaded ETF premiums into risk filter"),
+            Err(e) => warn!("Failed to load ETF premiums: {:?}", e),
+        }
     }
 
     // 라우터 생성
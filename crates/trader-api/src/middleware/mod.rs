@@ -6,6 +6,8 @@ mod metrics;
 mod rate_limit;
 
 pub use metrics::metrics_layer;
+pub(crate) use rate_limit::extract_client_ip;
 pub use rate_limit::{
-    rate_limit_middleware, RateLimitConfig, RateLimitResult, RateLimitState, RateLimiter,
+    rate_limit_middleware, ApiKeyRegistry, RateLimitConfig, RateLimitResult, RateLimitState,
+    RateLimiter, RedisRateLimitStore, RouteClass, RouteClassLimits,
 };
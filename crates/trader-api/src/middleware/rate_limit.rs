@@ -1,21 +1,36 @@
 //! Rate limiting middleware.
 //!
 //! Token Bucket 알고리즘 기반 rate limiting을 제공합니다.
-
-#![allow(dead_code)] // Rate limiting 레이어는 향후 프로덕션 배포 시 활성화 예정
+//!
+//! 요청 경로를 등급([`RouteClass`])으로 나눠 등급별 한도를 적용합니다.
+//! 인증 관련 엔드포인트는 엄격하게, 시세 조회는 넉넉하게, 주문 제출은 계정별 예산으로
+//! 제한합니다. 버킷은 요청 주체(JWT `sub` → 등록된 `X-API-Key` → IP 순)별로 분리되며,
+//! 주문 예산은 같은 계정의 JWT와 API 키가 공유합니다. 등록되지 않은 API 키는 IP로 취급합니다.
+//! 사용자 할당량이 연결되면 일반 엔드포인트에는 계정 등급별 한도가, 비인증 IP에는
+//! 익명 등급 한도가 적용됩니다.
+//!
+//! Redis 저장소가 연결되면 카운터를 Redis에 두어 재시작 후에도 한도가 유지되고
+//! 여러 레플리카가 같은 한도를 공유합니다. Redis 오류 시 인메모리 버킷으로 대체합니다.
 
 use axum::{
-    extract::Request,
-    http::{HeaderValue, StatusCode},
+    extract::{FromRequestParts, Request},
+    http::{HeaderMap, HeaderValue, Method, StatusCode},
     middleware::Next,
     response::{IntoResponse, Response},
 };
 use metrics::counter;
+use sha2::{Digest, Sha256};
 use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
 use std::net::IpAddr;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tokio::sync::RwLock;
+use trader_data::RedisCache;
+
+use crate::auth::OptionalJwtAuth;
+use crate::quota::QuotaTracker;
 
 /// Rate Limiter 설정.
 #[derive(Debug, Clone)]
@@ -119,14 +134,14 @@ impl TokenBucket {
 
 /// Rate Limiter.
 ///
-/// IP 주소별로 Rate Limiting을 적용합니다.
+/// 키(기본: IP 주소)별로 Rate Limiting을 적용합니다.
 #[derive(Clone)]
-pub struct RateLimiter {
+pub struct RateLimiter<K = IpAddr> {
     config: RateLimitConfig,
-    buckets: Arc<RwLock<HashMap<IpAddr, TokenBucket>>>,
+    buckets: Arc<RwLock<HashMap<K, TokenBucket>>>,
 }
 
-impl RateLimiter {
+impl<K: Eq + Hash> RateLimiter<K> {
     /// 새 Rate Limiter 생성.
     pub fn new(config: RateLimitConfig) -> Self {
         Self {
@@ -141,12 +156,23 @@ impl RateLimiter {
     }
 
     /// 요청 허용 여부 확인.
-    pub async fn check(&self, ip: IpAddr) -> RateLimitResult {
+    pub async fn check(&self, key: K) -> RateLimitResult {
+        self.check_with(key, &self.config).await
+    }
+
+    /// 키별 설정으로 요청 허용 여부 확인.
+    ///
+    /// 기존 버킷의 한도가 설정과 다르면 (등급 변경 등) 새 버킷으로 교체합니다.
+    pub async fn check_with(&self, key: K, config: &RateLimitConfig) -> RateLimitResult {
         let mut buckets = self.buckets.write().await;
 
+        let fresh = TokenBucket::new(config);
         let bucket = buckets
-            .entry(ip)
-            .or_insert_with(|| TokenBucket::new(&self.config));
+            .entry(key)
+            .or_insert_with(|| TokenBucket::new(config));
+        if bucket.max_tokens != fresh.max_tokens || bucket.refill_rate != fresh.refill_rate {
+            *bucket = fresh;
+        }
 
         if bucket.try_acquire() {
             RateLimitResult::Allowed
@@ -164,7 +190,7 @@ impl RateLimiter {
         buckets.retain(|_, bucket| bucket.last_refill > threshold);
     }
 
-    /// 현재 추적 중인 IP 수 반환.
+    /// 현재 추적 중인 키(IP) 수 반환.
     pub async fn tracked_ips(&self) -> usize {
         self.buckets.read().await.len()
     }
@@ -182,50 +208,394 @@ pub enum RateLimitResult {
     },
 }
 
+/// Rate Limit 경로 등급.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RouteClass {
+    /// 인증/자격증명 엔드포인트 (엄격)
+    Auth,
+    /// 시세·차트 조회 (넉넉)
+    MarketData,
+    /// 주문 제출/취소 (계정별 예산)
+    Orders,
+    /// 그 외 엔드포인트
+    General,
+}
+
+impl RouteClass {
+    /// 요청 메서드와 경로로 등급 판별.
+    ///
+    /// 주문 계열 경로라도 조회(GET)는 일반 등급으로 분류합니다.
+    pub fn classify(method: &Method, path: &str) -> Self {
+        const AUTH_PREFIXES: &[&str] = &["/api/v1/auth", "/api/v1/credentials"];
+        const MARKET_PREFIXES: &[&str] = &[
+            "/api/v1/market",
+            "/api/v1/udf",
+            "/api/v1/fx",
+            "/api/v1/etf",
+            "/api/v1/sectors",
+            "/api/v1/indices",
+            "/api/v1/synthetics",
+        ];
+        const ORDER_PREFIXES: &[&str] = &[
+            "/api/v1/orders",
+            "/api/v1/conditional-orders",
+            "/api/v1/dca",
+            "/api/v1/hedge",
+        ];
+
+        let matches = |prefixes: &[&str]| {
+            prefixes.iter().any(|prefix| {
+                path.strip_prefix(*prefix)
+                    .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+            })
+        };
+
+        if matches(AUTH_PREFIXES) {
+            Self::Auth
+        } else if matches(MARKET_PREFIXES) {
+            Self::MarketData
+        } else if matches(ORDER_PREFIXES) && method != Method::GET && method != Method::HEAD {
+            Self::Orders
+        } else {
+            Self::General
+        }
+    }
+
+    /// 메트릭 레이블 및 버킷 키에 쓰는 이름.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Auth => "auth",
+            Self::MarketData => "market_data",
+            Self::Orders => "orders",
+            Self::General => "general",
+        }
+    }
+}
+
+impl fmt::Display for RouteClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// 경로 등급별 Rate Limit 설정.
+#[derive(Debug, Clone)]
+pub struct RouteClassLimits {
+    /// 인증/자격증명
+    pub auth: RateLimitConfig,
+    /// 시세 조회
+    pub market_data: RateLimitConfig,
+    /// 주문 제출 (계정별)
+    pub orders: RateLimitConfig,
+    /// 그 외 (사용자 할당량이 있으면 등급별 한도가 우선)
+    pub general: RateLimitConfig,
+}
+
+impl Default for RouteClassLimits {
+    fn default() -> Self {
+        Self {
+            // 분당 30회 (초당 0.5회라 버스트가 없으면 버킷이 1토큰에 닿지 않음)
+            auth: RateLimitConfig {
+                requests_per_minute: 30,
+                burst_size: 5,
+                ..Default::default()
+            },
+            market_data: RateLimitConfig::new(6000), // 차트/호가 폴링 고려
+            orders: RateLimitConfig::new(120),       // 계정당 분당 120건
+            general: RateLimitConfig::default(),
+        }
+    }
+}
+
+impl RouteClassLimits {
+    /// 모든 등급에 같은 한도 적용 (등급 구분 없는 기존 동작).
+    pub fn uniform(config: RateLimitConfig) -> Self {
+        Self {
+            auth: config.clone(),
+            market_data: config.clone(),
+            orders: config.clone(),
+            general: config,
+        }
+    }
+
+    /// 등급별 설정 조회.
+    pub fn for_class(&self, class: RouteClass) -> &RateLimitConfig {
+        match class {
+            RouteClass::Auth => &self.auth,
+            RouteClass::MarketData => &self.market_data,
+            RouteClass::Orders => &self.orders,
+            RouteClass::General => &self.general,
+        }
+    }
+}
+
+/// 등록된 API 키 목록 (SHA-256 다이제스트 → 계정 ID).
+///
+/// 키 원문은 보관하지 않습니다. 계정 ID는 JWT `sub`(사용자 ID)와 같은 값을 써야
+/// 같은 계정의 JWT 요청과 주문 예산을 공유합니다.
+#[derive(Debug, Clone, Default)]
+pub struct ApiKeyRegistry {
+    accounts: HashMap<String, String>,
+}
+
+impl ApiKeyRegistry {
+    /// 빈 목록 (모든 API 키를 IP로 취급).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 키 다이제스트(SHA-256 hex)를 계정에 등록.
+    pub fn with_digest(mut self, digest: &str, account: impl Into<String>) -> Self {
+        self.accounts
+            .insert(digest.trim().to_ascii_lowercase(), account.into());
+        self
+    }
+
+    /// `계정ID:SHA256hex` 항목을 쉼표로 구분한 설정 문자열 파싱 (형식이 틀린 항목은 무시).
+    pub fn parse(spec: &str) -> Self {
+        spec.split(',')
+            .filter_map(|entry| entry.split_once(':'))
+            .map(|(account, digest)| (account.trim(), digest.trim()))
+            .filter(|(account, digest)| {
+                !account.is_empty()
+                    && digest.len() == 64
+                    && digest.chars().all(|c| c.is_ascii_hexdigit())
+            })
+            .fold(Self::new(), |registry, (account, digest)| {
+                registry.with_digest(digest, account)
+            })
+    }
+
+    /// 등록된 키 수.
+    pub fn len(&self) -> usize {
+        self.accounts.len()
+    }
+
+    /// 등록된 키가 없는지 여부.
+    pub fn is_empty(&self) -> bool {
+        self.accounts.is_empty()
+    }
+
+    /// 키 원문으로 계정 조회.
+    fn account_for(&self, key: &str) -> Option<&str> {
+        let digest = hex::encode(Sha256::digest(key.as_bytes()));
+        self.accounts.get(&digest).map(String::as_str)
+    }
+}
+
+/// Rate Limit 버킷 주체.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Principal {
+    /// JWT 사용자 (`sub`)
+    User(String),
+    /// 등록된 API 키 (원문 대신 SHA-256 앞 16자리와 소유 계정)
+    ApiKey { digest: String, account: String },
+    /// 인증 정보 없는 클라이언트 IP
+    Ip(IpAddr),
+}
+
+impl Principal {
+    /// 주체가 속한 계정 (IP는 계정 없음).
+    fn account(&self) -> Option<&str> {
+        match self {
+            Self::User(id) => Some(id),
+            Self::ApiKey { account, .. } => Some(account),
+            Self::Ip(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for Principal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::User(id) => write!(f, "user:{}", id),
+            Self::ApiKey { digest, .. } => write!(f, "key:{}", digest),
+            Self::Ip(ip) => write!(f, "ip:{}", ip),
+        }
+    }
+}
+
+/// Redis 기반 Rate Limit 저장소.
+///
+/// 분 단위 고정 윈도우 카운터(`INCR` + `EXPIRE`)로 한도를 확인합니다.
+/// 카운터가 Redis에 있으므로 서버 재시작 후에도 유지되고 레플리카 간에 공유됩니다.
+#[derive(Clone)]
+pub struct RedisRateLimitStore {
+    cache: Arc<RedisCache>,
+}
+
+impl RedisRateLimitStore {
+    /// 윈도우 길이 (초).
+    const WINDOW_SECS: u64 = 60;
+
+    pub fn new(cache: Arc<RedisCache>) -> Self {
+        Self { cache }
+    }
+
+    /// 요청 1건을 기록하고 허용 여부 반환.
+    async fn check(
+        &self,
+        key: &str,
+        config: &RateLimitConfig,
+    ) -> trader_data::Result<RateLimitResult> {
+        let now = chrono::Utc::now().timestamp().max(0) as u64;
+        let window = now / Self::WINDOW_SECS;
+        let count = self
+            .cache
+            .increment_rate_limit("api", &format!("{}:{}", key, window), Self::WINDOW_SECS)
+            .await?;
+
+        Ok(window_result(count, now, config))
+    }
+}
+
+/// 고정 윈도우 카운트로 허용 여부 판정 (윈도우 한도 = 분당 요청 수 + 버스트).
+fn window_result(count: i64, now_secs: u64, config: &RateLimitConfig) -> RateLimitResult {
+    let limit = i64::from(config.requests_per_minute) + i64::from(config.burst_size);
+    if count <= limit {
+        RateLimitResult::Allowed
+    } else {
+        RateLimitResult::Limited {
+            retry_after: RedisRateLimitStore::WINDOW_SECS
+                - now_secs % RedisRateLimitStore::WINDOW_SECS,
+        }
+    }
+}
+
 /// Rate Limit 미들웨어 상태.
 #[derive(Clone)]
 pub struct RateLimitState {
-    limiter: RateLimiter,
+    /// 인메모리 버킷 (`등급:주체` 키, Redis 미연결/오류 시 사용)
+    limiter: RateLimiter<String>,
+    /// 경로 등급별 한도
+    limits: RouteClassLimits,
+    /// 사용자 할당량 (일반 등급에 사용자/익명 등급별 한도 적용)
+    quotas: Option<Arc<QuotaTracker>>,
+    /// 등록된 API 키 (등록되지 않은 키는 IP로 취급)
+    api_keys: ApiKeyRegistry,
+    /// Redis 저장소 (None이면 인메모리만 사용)
+    store: Option<RedisRateLimitStore>,
 }
 
 impl RateLimitState {
+    /// 모든 경로에 같은 한도를 적용하는 상태 생성.
     pub fn new(config: RateLimitConfig) -> Self {
         Self {
-            limiter: RateLimiter::new(config),
+            limiter: RateLimiter::new(config.clone()),
+            limits: RouteClassLimits::uniform(config),
+            quotas: None,
+            api_keys: ApiKeyRegistry::new(),
+            store: None,
         }
     }
 
     pub fn with_defaults() -> Self {
-        Self {
-            limiter: RateLimiter::with_defaults(),
+        Self::new(RateLimitConfig::default())
+    }
+
+    /// 경로 등급별 한도 설정.
+    pub fn with_route_limits(mut self, limits: RouteClassLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// 인증된 요청에 사용자 등급별 한도 적용.
+    pub fn with_user_quotas(mut self, quotas: Arc<QuotaTracker>) -> Self {
+        self.quotas = Some(quotas);
+        self
+    }
+
+    /// `X-API-Key` 주체로 인정할 등록 키 설정.
+    pub fn with_api_keys(mut self, api_keys: ApiKeyRegistry) -> Self {
+        self.api_keys = api_keys;
+        self
+    }
+
+    /// Redis에 카운터 저장 (재시작·레플리카 간 한도 공유).
+    pub fn with_store(mut self, store: RedisRateLimitStore) -> Self {
+        self.store = Some(store);
+        self
+    }
+
+    /// 요청 등급·주체와 Rate Limit 확인 결과.
+    async fn check(&self, request: Request) -> (Request, RouteClass, String, RateLimitResult) {
+        let class = RouteClass::classify(request.method(), request.uri().path());
+        let (request, principal) = request_principal(request, &self.api_keys).await;
+        let client = principal.to_string();
+
+        let mut config = self.limits.for_class(class).clone();
+        if let (RouteClass::General, Some(quotas)) = (class, &self.quotas) {
+            match principal.account() {
+                Some(account) => match quotas.quota_for(account).await.limits.requests_per_minute {
+                    Some(rpm) => config = RateLimitConfig::new(rpm),
+                    // unlimited 등급
+                    None => return (request, class, client, RateLimitResult::Allowed),
+                },
+                // 비인증 IP는 익명 등급 한도 (unlimited면 일반 등급 한도 유지)
+                None => {
+                    if let Some(rpm) = quotas.anonymous_quota().limits.requests_per_minute {
+                        config = RateLimitConfig::new(rpm);
+                    }
+                }
+            }
         }
+
+        // 주문 예산은 계정 단위 (같은 계정의 JWT·API 키 요청이 한 버킷을 공유)
+        let key = match (class, principal.account()) {
+            (RouteClass::Orders, Some(account)) => format!("{}:account:{}", class, account),
+            _ => format!("{}:{}", class, client),
+        };
+        let result = match &self.store {
+            Some(store) => match store.check(&key, &config).await {
+                Ok(result) => result,
+                Err(e) => {
+                    counter!("rate_limit_store_errors_total").increment(1);
+                    tracing::warn!(error = %e, "Rate limit store unavailable, using in-memory bucket");
+                    self.limiter.check_with(key, &config).await
+                }
+            },
+            None => self.limiter.check_with(key, &config).await,
+        };
+
+        (request, class, client, result)
     }
 }
 
 /// Rate Limiting 미들웨어 함수.
 ///
-/// 클라이언트 IP별로 Rate Limiting을 적용합니다.
+/// 경로 등급별로 요청 주체(사용자, API 키 또는 IP)마다 Rate Limiting을 적용합니다.
+/// 사용자 할당량이 연결되어 있으면 일반 엔드포인트는 사용자 등급별로 제한합니다.
 pub async fn rate_limit_middleware(
     axum::extract::State(state): axum::extract::State<RateLimitState>,
     request: Request,
     next: Next,
 ) -> Response {
-    // 클라이언트 IP 추출
-    let ip = extract_client_ip(&request);
+    // 경로 등급 × 요청 주체별 Rate limit 확인
+    let (request, class, client, result) = state.check(request).await;
 
-    // Rate limit 확인
-    match state.limiter.check(ip).await {
+    match result {
         RateLimitResult::Allowed => {
             // 요청 허용 - 다음 핸들러로 진행
-            counter!("rate_limit_requests_total", "status" => "allowed").increment(1);
+            counter!(
+                "rate_limit_requests_total",
+                "status" => "allowed",
+                "class" => class.as_str()
+            )
+            .increment(1);
             next.run(request).await
         }
         RateLimitResult::Limited { retry_after } => {
             // Rate limit 초과 - 429 응답
-            counter!("rate_limit_requests_total", "status" => "limited").increment(1);
+            counter!(
+                "rate_limit_requests_total",
+                "status" => "limited",
+                "class" => class.as_str()
+            )
+            .increment(1);
 
             tracing::warn!(
-                client_ip = %ip,
+                client = %client,
+                class = %class,
                 retry_after = retry_after,
                 "Rate limit exceeded"
             );
@@ -252,12 +622,12 @@ pub async fn rate_limit_middleware(
     }
 }
 
-/// 요청에서 클라이언트 IP 추출.
+/// 요청 헤더에서 클라이언트 IP 추출.
 ///
 /// X-Forwarded-For, X-Real-IP 헤더를 우선 확인합니다 (프록시/로드밸런서 뒤에 있을 경우).
-fn extract_client_ip(request: &Request) -> IpAddr {
+pub(crate) fn extract_client_ip(headers: &HeaderMap) -> IpAddr {
     // X-Forwarded-For 헤더 확인
-    if let Some(forwarded_for) = request.headers().get("x-forwarded-for") {
+    if let Some(forwarded_for) = headers.get("x-forwarded-for") {
         if let Ok(value) = forwarded_for.to_str() {
             // 첫 번째 IP 사용 (클라이언트 원본 IP)
             if let Some(ip_str) = value.split(',').next() {
@@ -269,7 +639,7 @@ fn extract_client_ip(request: &Request) -> IpAddr {
     }
 
     // X-Real-IP 헤더 확인
-    if let Some(real_ip) = request.headers().get("x-real-ip") {
+    if let Some(real_ip) = headers.get("x-real-ip") {
         if let Ok(value) = real_ip.to_str() {
             if let Ok(ip) = value.trim().parse() {
                 return ip;
@@ -283,25 +653,42 @@ fn extract_client_ip(request: &Request) -> IpAddr {
     "127.0.0.1".parse().unwrap()
 }
 
-/// Rate Limit 레이어 생성 헬퍼.
-#[allow(clippy::type_complexity)]
-pub fn create_rate_limit_layer(
-    requests_per_minute: u32,
-) -> (
-    RateLimitState,
-    fn(
-        axum::extract::State<RateLimitState>,
-        Request,
-        Next,
-    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Response> + Send>>,
-) {
-    let state = RateLimitState::new(RateLimitConfig::new(requests_per_minute));
-    (state, |s, r, n| Box::pin(rate_limit_middleware(s, r, n)))
+/// 요청 주체 판별: JWT 사용자 → 등록된 `X-API-Key` → 클라이언트 IP.
+async fn request_principal(request: Request, api_keys: &ApiKeyRegistry) -> (Request, Principal) {
+    let (mut parts, body) = request.into_parts();
+    let user_id = match OptionalJwtAuth::from_request_parts(&mut parts, &()).await {
+        Ok(OptionalJwtAuth(claims)) => claims.map(|c| c.sub),
+        Err(never) => match never {},
+    };
+    let request = Request::from_parts(parts, body);
+
+    let principal = match user_id {
+        Some(user_id) => Principal::User(user_id),
+        None => match registered_api_key(&request, api_keys) {
+            Some((digest, account)) => Principal::ApiKey { digest, account },
+            None => Principal::Ip(extract_client_ip(request.headers())),
+        },
+    };
+    (request, principal)
+}
+
+/// 등록된 `X-API-Key`의 SHA-256 앞 16자리와 소유 계정.
+///
+/// 키 원문은 버킷 키/로그에 남기지 않습니다. 등록되지 않은 키는 None (IP로 취급).
+fn registered_api_key(request: &Request, api_keys: &ApiKeyRegistry) -> Option<(String, String)> {
+    let key = request.headers().get("x-api-key")?.to_str().ok()?.trim();
+    if key.is_empty() {
+        return None;
+    }
+    let account = api_keys.account_for(key)?.to_string();
+    let digest = hex::encode(Sha256::digest(key.as_bytes()));
+    Some((digest[..16].to_string(), account))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::quota::QuotaTier;
 
     #[tokio::test]
     async fn test_rate_limiter_allows_requests() {
@@ -368,6 +755,30 @@ mod tests {
         assert!(matches!(limiter.check(ip2).await, RateLimitResult::Allowed));
     }
 
+    #[tokio::test]
+    async fn test_rate_limiter_check_with_user_limits() {
+        let limiter: RateLimiter<String> = RateLimiter::with_defaults();
+        let strict = RateLimitConfig::strict(60);
+
+        // 사용자별 한도로 버킷 생성
+        assert!(matches!(
+            limiter.check_with("user-1".to_string(), &strict).await,
+            RateLimitResult::Allowed
+        ));
+        assert!(matches!(
+            limiter.check_with("user-1".to_string(), &strict).await,
+            RateLimitResult::Limited { .. }
+        ));
+
+        // 한도가 바뀌면 (등급 변경) 새 버킷으로 교체
+        assert!(matches!(
+            limiter
+                .check_with("user-1".to_string(), &RateLimitConfig::strict(120))
+                .await,
+            RateLimitResult::Allowed
+        ));
+    }
+
     #[tokio::test]
     async fn test_rate_limiter_cleanup() {
         let config = RateLimitConfig {
@@ -418,6 +829,207 @@ mod tests {
         assert!(matches!(limiter.check(ip).await, RateLimitResult::Allowed));
     }
 
+    #[test]
+    fn test_route_class_classify() {
+        assert_eq!(
+            RouteClass::classify(&Method::POST, "/api/v1/credentials/exchange"),
+            RouteClass::Auth
+        );
+        assert_eq!(
+            RouteClass::classify(&Method::GET, "/api/v1/market/klines"),
+            RouteClass::MarketData
+        );
+        assert_eq!(
+            RouteClass::classify(&Method::POST, "/api/v1/orders"),
+            RouteClass::Orders
+        );
+        assert_eq!(
+            RouteClass::classify(&Method::DELETE, "/api/v1/orders/abc"),
+            RouteClass::Orders
+        );
+        // 주문 조회는 일반 등급
+        assert_eq!(
+            RouteClass::classify(&Method::GET, "/api/v1/orders"),
+            RouteClass::General
+        );
+        // 접두사만 같은 다른 경로는 제외
+        assert_eq!(
+            RouteClass::classify(&Method::POST, "/api/v1/orders-archive"),
+            RouteClass::General
+        );
+        assert_eq!(
+            RouteClass::classify(&Method::GET, "/health"),
+            RouteClass::General
+        );
+    }
+
+    #[test]
+    fn test_window_result() {
+        let config = RateLimitConfig {
+            requests_per_minute: 10,
+            burst_size: 2,
+            cleanup_interval: Duration::from_secs(60),
+        };
+        assert!(matches!(
+            window_result(12, 0, &config),
+            RateLimitResult::Allowed
+        ));
+        assert!(matches!(
+            window_result(13, 125, &config),
+            RateLimitResult::Limited { retry_after: 55 }
+        ));
+    }
+
+    fn key_digest(key: &str) -> String {
+        hex::encode(Sha256::digest(key.as_bytes()))
+    }
+
+    #[test]
+    fn test_api_key_registry_parse() {
+        let spec = format!(
+            "acct-1:{}, bad-entry, acct-2:{}, acct-3:not-a-digest",
+            key_digest("key-1"),
+            key_digest("key-2").to_uppercase()
+        );
+        let registry = ApiKeyRegistry::parse(&spec);
+        assert_eq!(registry.len(), 2);
+        assert_eq!(registry.account_for("key-1"), Some("acct-1"));
+        assert_eq!(registry.account_for("key-2"), Some("acct-2"));
+        assert_eq!(registry.account_for("unknown"), None);
+    }
+
+    #[tokio::test]
+    async fn test_request_principal_api_key() {
+        let api_keys = ApiKeyRegistry::new().with_digest(&key_digest("secret-key"), "acct-1");
+        let request = |key: Option<&str>| {
+            let mut builder = Request::builder()
+                .uri("/api/v1/orders")
+                .header("x-forwarded-for", "10.0.0.1");
+            if let Some(key) = key {
+                builder = builder.header("x-api-key", key);
+            }
+            builder.body(axum::body::Body::empty()).unwrap()
+        };
+
+        let (_, principal) = request_principal(request(Some("secret-key")), &api_keys).await;
+        match &principal {
+            Principal::ApiKey { digest, account } => {
+                assert_eq!(digest.len(), 16);
+                assert!(!digest.contains("secret"));
+                assert_eq!(account, "acct-1");
+            }
+            other => panic!("unexpected principal: {:?}", other),
+        }
+
+        // 등록되지 않은 키는 IP로 취급 (임의 키로 버킷 우회 불가)
+        let (_, principal) = request_principal(request(Some("forged-key")), &api_keys).await;
+        assert_eq!(principal, Principal::Ip("10.0.0.1".parse().unwrap()));
+
+        let (_, principal) = request_principal(request(None), &api_keys).await;
+        assert_eq!(principal, Principal::Ip("10.0.0.1".parse().unwrap()));
+    }
+
+    #[tokio::test]
+    async fn test_order_budget_shared_per_account() {
+        let limits = RouteClassLimits {
+            orders: RateLimitConfig::strict(60),
+            ..RouteClassLimits::uniform(RateLimitConfig::new(600))
+        };
+        let state = RateLimitState::with_defaults()
+            .with_route_limits(limits)
+            .with_api_keys(
+                ApiKeyRegistry::new()
+                    .with_digest(&key_digest("key-a"), "acct-1")
+                    .with_digest(&key_digest("key-b"), "acct-1")
+                    .with_digest(&key_digest("key-c"), "acct-2"),
+            );
+        let order = |key: &str, ip: &str| {
+            Request::builder()
+                .method(Method::POST)
+                .uri("/api/v1/orders")
+                .header("x-api-key", key)
+                .header("x-real-ip", ip)
+                .body(axum::body::Body::empty())
+                .unwrap()
+        };
+
+        let (_, class, _, result) = state.check(order("key-a", "10.0.0.3")).await;
+        assert_eq!(class, RouteClass::Orders);
+        assert!(matches!(result, RateLimitResult::Allowed));
+
+        // 같은 계정의 다른 키·IP도 같은 주문 예산을 사용
+        let (_, _, _, result) = state.check(order("key-b", "10.0.0.4")).await;
+        assert!(matches!(result, RateLimitResult::Limited { .. }));
+
+        // 다른 계정은 별도 예산
+        let (_, _, _, result) = state.check(order("key-c", "10.0.0.3")).await;
+        assert!(matches!(result, RateLimitResult::Allowed));
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_state_route_classes() {
+        let limits = RouteClassLimits {
+            auth: RateLimitConfig::strict(60),
+            ..RouteClassLimits::uniform(RateLimitConfig::new(600))
+        };
+        let state = RateLimitState::with_defaults().with_route_limits(limits);
+        let request = |method: Method, path: &str| {
+            Request::builder()
+                .method(method)
+                .uri(path)
+                .header("x-real-ip", "10.0.0.2")
+                .body(axum::body::Body::empty())
+                .unwrap()
+        };
+
+        // 인증 등급은 버스트 없이 1건 후 제한
+        let (_, class, client, result) = state
+            .check(request(Method::POST, "/api/v1/credentials/exchange"))
+            .await;
+        assert_eq!(class, RouteClass::Auth);
+        assert_eq!(client, "ip:10.0.0.2");
+        assert!(matches!(result, RateLimitResult::Allowed));
+        let (_, _, _, result) = state
+            .check(request(Method::POST, "/api/v1/credentials/exchange"))
+            .await;
+        assert!(matches!(result, RateLimitResult::Limited { .. }));
+
+        // 같은 주체라도 다른 등급 버킷은 영향 없음
+        let (_, class, _, result) = state
+            .check(request(Method::GET, "/api/v1/market/klines"))
+            .await;
+        assert_eq!(class, RouteClass::MarketData);
+        assert!(matches!(result, RateLimitResult::Allowed));
+    }
+
+    #[tokio::test]
+    async fn test_anonymous_tier_limits_ip_requests() {
+        let quotas = QuotaTracker::with_default_tier(None, QuotaTier::Pro)
+            .with_anonymous_tier(QuotaTier::Free);
+        let state = RateLimitState::with_defaults()
+            .with_route_limits(RouteClassLimits::uniform(RateLimitConfig::new(60_000)))
+            .with_user_quotas(Arc::new(quotas));
+        let request = || {
+            Request::builder()
+                .uri("/api/v1/strategies")
+                .header("x-real-ip", "10.0.0.5")
+                .body(axum::body::Body::empty())
+                .unwrap()
+        };
+
+        // 일반 등급 한도(버스트 6000)가 아니라 익명 등급(free) 한도로 제한
+        let mut limited = false;
+        for _ in 0..200 {
+            let (_, class, _, result) = state.check(request()).await;
+            assert_eq!(class, RouteClass::General);
+            if matches!(result, RateLimitResult::Limited { .. }) {
+                limited = true;
+                break;
+            }
+        }
+        assert!(limited);
+    }
+
     #[test]
     fn test_rate_limit_config_default() {
         let config = RateLimitConfig::default();
@@ -12,6 +12,7 @@ use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, VecDeque};
 use std::sync::{Arc, RwLock};
 use tracing::{error, warn};
+use trader_execution::{InvariantHook, InvariantViolation};
 
 /// 에러 심각도 수준.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -522,6 +523,24 @@ pub fn global_tracker() -> &'static ErrorTracker {
     GLOBAL_TRACKER.get_or_init(ErrorTracker::with_defaults)
 }
 
+/// 회계 불변식 위반을 전역 에러 추적기에 Critical로 기록하는 훅.
+///
+/// `OrderExecutor::set_invariant_hook()`에 등록하여 운영 중 장부 불일치를 모니터링합니다.
+pub fn accounting_invariant_hook() -> InvariantHook {
+    Arc::new(|violation: &InvariantViolation| {
+        global_tracker().record(
+            ErrorRecordBuilder::new(format!("회계 불변식 위반: {}", violation.kind))
+                .severity(ErrorSeverity::Critical)
+                .category(ErrorCategory::BusinessLogic)
+                .function("accounting_invariant_hook")
+                .entity(violation.entity.clone())
+                .with_context("kind", violation.kind.as_str())
+                .with_context("detail", violation.detail.clone())
+                .build(),
+        );
+    })
+}
+
 /// 에러 기록 매크로 (간편 사용).
 #[macro_export]
 macro_rules! track_error {
@@ -30,6 +30,7 @@ pub mod error_tracker;
 
 // Re-exports
 pub use error_tracker::{
-    global_tracker, init_global_tracker, ErrorCategory, ErrorRecord, ErrorRecordBuilder,
-    ErrorSeverity, ErrorStats, ErrorTracker, ErrorTrackerConfig, SourceLocation,
+    accounting_invariant_hook, global_tracker, init_global_tracker, ErrorCategory, ErrorRecord,
+    ErrorRecordBuilder, ErrorSeverity, ErrorStats, ErrorTracker, ErrorTrackerConfig,
+    SourceLocation,
 };
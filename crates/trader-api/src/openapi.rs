@@ -39,7 +39,7 @@ use crate::routes::{
     // Signals 모듈
     signals::{
         SignalMarkerDto, SignalSearchRequest, SignalSearchResponse, StrategySignalsQuery,
-        SymbolSignalsQuery,
+        SymbolSignalsQuery, WebhookSignalResponse, WebhookSignalResult,
     },
     // Strategies 모듈
     strategies::{ApiError, StrategyListItem},
@@ -51,11 +51,15 @@ use crate::routes::{
     ErrorsResponse,
     HealthResponse,
     // Screening 모듈
+    FactorHistoryResponse,
+    InvestorFlowHistoryResponse,
+    InvestorFlowResponse,
     MomentumResponse,
     ScreeningRequest,
     ScreeningResponse,
     StatsResponse,
     StrategiesListResponse,
+    SurvivalResponse,
 };
 
 // ==================== OpenAPI 문서 정의 ====================
@@ -148,11 +152,17 @@ use crate::routes::{
             ScreeningRequest,
             ScreeningResponse,
             MomentumResponse,
+            InvestorFlowResponse,
+            InvestorFlowHistoryResponse,
+            SurvivalResponse,
+            FactorHistoryResponse,
 
             // ===== Signals =====
             SignalMarkerDto,
             SignalSearchRequest,
             SignalSearchResponse,
+            WebhookSignalResponse,
+            WebhookSignalResult,
             SymbolSignalsQuery,
             StrategySignalsQuery,
 
@@ -195,17 +205,29 @@ use crate::routes::{
         crate::routes::monitoring::reset_stats,
         crate::routes::monitoring::clear_errors,
         crate::routes::monitoring::get_summary,
+        crate::routes::monitoring::get_overview,
+        crate::routes::monitoring::list_order_circuits,
+        crate::routes::monitoring::reset_order_circuit,
+        crate::routes::monitoring::list_shadow_reports,
+        crate::routes::monitoring::get_shadow_report,
+        crate::routes::monitoring::attach_shadow,
+        crate::routes::monitoring::detach_shadow,
 
         // ===== Screening =====
         crate::routes::screening::run_screening,
         crate::routes::screening::list_presets,
         crate::routes::screening::run_preset_screening,
         crate::routes::screening::run_momentum_screening,
+        crate::routes::screening::run_investor_flow_screening,
+        crate::routes::screening::get_investor_flow_history,
+        crate::routes::screening::get_survival_stats,
+        crate::routes::screening::get_factor_history,
 
         // ===== Signals =====
         crate::routes::signals::search_signals,
         crate::routes::signals::get_signals_by_symbol,
         crate::routes::signals::get_signals_by_strategy,
+        crate::routes::signals::receive_signal_webhook,
 
         // ===== Ranking =====
         crate::routes::ranking::calculate_global,
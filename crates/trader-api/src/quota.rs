@@ -0,0 +1,478 @@
+//! 사용자별 리소스 할당량.
+//!
+//! 다중 사용자 환경에서 한 사용자가 서버 자원을 독점하지 않도록 사용자(JWT `sub`)별로
+//! 동시 백테스트 수, 등록 전략 수, API 분당 요청 수, 백테스트 결과 저장 용량을 제한합니다.
+//!
+//! 한도는 등급(`free`, `standard`, `pro`, `unlimited`) 기본값에 `user_quotas` 테이블의
+//! 개별 설정을 덮어써서 정하며, 행이 없는 사용자는 `QUOTA_DEFAULT_TIER`(기본 `standard`)를
+//! 따릅니다. 인증되지 않은 요청에는 `QUOTA_ANONYMOUS_TIER`(기본값은 기본 등급) 한도를
+//! 적용합니다. 동시 백테스트와 API 요청률은 클라이언트 IP별, 등록 전략 수는 소유자 없는
+//! 전략 전체 기준입니다.
+//!
+//! # 적용 위치
+//!
+//! - API 요청률: `rate_limit_middleware` (사용자/IP별 토큰 버킷)
+//! - 동시 백테스트: 백테스트 실행 핸들러 ([`BacktestPermit`]이 살아 있는 동안 1건)
+//! - 등록 전략 수: 전략 생성/복제
+//! - 저장 용량: 백테스트 결과 저장
+
+use axum::{http::StatusCode, Json};
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use std::collections::HashMap;
+use std::fmt;
+use std::net::IpAddr;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::sync::RwLock;
+use tracing::warn;
+
+use crate::error::ApiErrorResponse;
+use crate::repository::{QuotaRepository, UserQuotaRecord};
+
+/// 사용자 한도 캐시 유효 시간.
+const QUOTA_CACHE_TTL: Duration = Duration::from_secs(300);
+
+/// 비인증 클라이언트 백테스트 집계 키 접두사 (IP 단위).
+const ANONYMOUS_KEY_PREFIX: &str = "anonymous:";
+
+/// 할당량 등급.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QuotaTier {
+    Free,
+    #[default]
+    Standard,
+    Pro,
+    /// 제한 없음
+    Unlimited,
+}
+
+impl QuotaTier {
+    /// 문자열 파싱 (대소문자 무시).
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "free" => Some(Self::Free),
+            "standard" => Some(Self::Standard),
+            "pro" => Some(Self::Pro),
+            "unlimited" => Some(Self::Unlimited),
+            _ => None,
+        }
+    }
+
+    /// 등급 기본 한도.
+    pub fn limits(&self) -> QuotaLimits {
+        let (backtests, strategies, rpm, storage_mb) = match self {
+            Self::Free => (1, 3, 120, 100),
+            Self::Standard => (2, 10, 600, 1024),
+            Self::Pro => (5, 50, 1200, 10240),
+            Self::Unlimited => return QuotaLimits::default(),
+        };
+        QuotaLimits {
+            max_concurrent_backtests: Some(backtests),
+            max_strategies: Some(strategies),
+            requests_per_minute: Some(rpm),
+            storage_mb: Some(storage_mb),
+        }
+    }
+}
+
+/// 사용자 한도 (None이면 제한 없음).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QuotaLimits {
+    /// 동시 실행 백테스트 수
+    pub max_concurrent_backtests: Option<u32>,
+    /// 등록 전략 수
+    pub max_strategies: Option<u32>,
+    /// API 분당 요청 수
+    pub requests_per_minute: Option<u32>,
+    /// 백테스트 결과 저장 용량 (MB)
+    pub storage_mb: Option<u64>,
+}
+
+/// 사용자에게 적용되는 등급과 한도.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UserQuota {
+    pub tier: QuotaTier,
+    pub limits: QuotaLimits,
+}
+
+impl UserQuota {
+    /// 할당량 레코드로 한도 결정 (레코드가 없거나 등급을 알 수 없으면 기본 등급).
+    pub fn resolve(record: Option<&UserQuotaRecord>, default_tier: QuotaTier) -> Self {
+        let Some(record) = record else {
+            return Self {
+                tier: default_tier,
+                limits: default_tier.limits(),
+            };
+        };
+
+        let tier = QuotaTier::parse(&record.tier).unwrap_or(default_tier);
+        let base = tier.limits();
+        let non_negative = |v: i32| v.max(0) as u32;
+        Self {
+            tier,
+            limits: QuotaLimits {
+                max_concurrent_backtests: record
+                    .max_concurrent_backtests
+                    .map(non_negative)
+                    .or(base.max_concurrent_backtests),
+                max_strategies: record
+                    .max_strategies
+                    .map(non_negative)
+                    .or(base.max_strategies),
+                requests_per_minute: record
+                    .requests_per_minute
+                    .map(non_negative)
+                    .or(base.requests_per_minute),
+                storage_mb: record
+                    .storage_mb
+                    .map(|v| v.max(0) as u64)
+                    .or(base.storage_mb),
+            },
+        }
+    }
+}
+
+/// 사용자 현재 사용량.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QuotaUsage {
+    /// 실행 중인 백테스트 수
+    pub running_backtests: u32,
+    /// 등록 전략 수
+    pub strategies: i64,
+    /// 백테스트 결과 저장 용량 (바이트)
+    pub storage_bytes: i64,
+}
+
+/// 할당량 초과.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum QuotaError {
+    /// 동시 백테스트 한도 도달
+    ConcurrentBacktests { limit: u32 },
+    /// 등록 전략 한도 도달
+    Strategies { limit: u32 },
+    /// 저장 용량 한도 도달
+    Storage { limit_mb: u64 },
+}
+
+impl fmt::Display for QuotaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ConcurrentBacktests { limit } => {
+                write!(f, "동시 백테스트 한도 초과 (최대 {}건)", limit)
+            }
+            Self::Strategies { limit } => write!(f, "등록 전략 한도 초과 (최대 {}개)", limit),
+            Self::Storage { limit_mb } => write!(f, "저장 용량 한도 초과 (최대 {}MB)", limit_mb),
+        }
+    }
+}
+
+impl std::error::Error for QuotaError {}
+
+impl QuotaError {
+    /// HTTP 상태 코드 (동시 실행은 429, 누적 한도는 403).
+    pub fn status_code(&self) -> StatusCode {
+        match self {
+            Self::ConcurrentBacktests { .. } => StatusCode::TOO_MANY_REQUESTS,
+            Self::Strategies { .. } | Self::Storage { .. } => StatusCode::FORBIDDEN,
+        }
+    }
+
+    /// API 에러 응답.
+    pub fn to_api_error(&self) -> (StatusCode, Json<ApiErrorResponse>) {
+        (
+            self.status_code(),
+            Json(ApiErrorResponse::new("QUOTA_EXCEEDED", self.to_string())),
+        )
+    }
+}
+
+/// 실행 중인 백테스트 슬롯 (drop 시 반환).
+#[derive(Debug)]
+pub struct BacktestPermit {
+    running: Arc<Mutex<HashMap<String, u32>>>,
+    user_id: String,
+}
+
+impl Drop for BacktestPermit {
+    fn drop(&mut self) {
+        let mut running = self.running.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(count) = running.get_mut(&self.user_id) {
+            *count = count.saturating_sub(1);
+            if *count == 0 {
+                running.remove(&self.user_id);
+            }
+        }
+    }
+}
+
+/// 사용자별 할당량 판정과 실행 중 백테스트 집계.
+///
+/// 한도는 DB에서 읽어 `QUOTA_CACHE_TTL` 동안 캐시합니다. DB 조회에 실패하면
+/// 요청을 막지 않도록 기본 등급으로 판정합니다.
+#[derive(Debug)]
+pub struct QuotaTracker {
+    pool: Option<PgPool>,
+    default_tier: QuotaTier,
+    /// 비인증 요청 등급
+    anonymous_tier: QuotaTier,
+    cache: RwLock<HashMap<String, (UserQuota, Instant)>>,
+    running: Arc<Mutex<HashMap<String, u32>>>,
+}
+
+impl QuotaTracker {
+    /// 새 추적기 생성.
+    ///
+    /// 기본 등급은 `QUOTA_DEFAULT_TIER`(없으면 standard), 비인증 요청 등급은
+    /// `QUOTA_ANONYMOUS_TIER`(없으면 기본 등급)입니다.
+    pub fn new(pool: Option<PgPool>) -> Self {
+        let env_tier = |name: &str| std::env::var(name).ok().and_then(|v| QuotaTier::parse(&v));
+        let default_tier = env_tier("QUOTA_DEFAULT_TIER").unwrap_or_default();
+        let tracker = Self::with_default_tier(pool, default_tier);
+        match env_tier("QUOTA_ANONYMOUS_TIER") {
+            Some(tier) => tracker.with_anonymous_tier(tier),
+            None => tracker,
+        }
+    }
+
+    /// 기본 등급을 지정하여 생성 (비인증 요청도 같은 등급).
+    pub fn with_default_tier(pool: Option<PgPool>, default_tier: QuotaTier) -> Self {
+        Self {
+            pool,
+            default_tier,
+            anonymous_tier: default_tier,
+            cache: RwLock::new(HashMap::new()),
+            running: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// 비인증 요청 등급 지정.
+    pub fn with_anonymous_tier(mut self, tier: QuotaTier) -> Self {
+        self.anonymous_tier = tier;
+        self
+    }
+
+    /// 비인증 요청에 적용되는 등급과 한도.
+    pub fn anonymous_quota(&self) -> UserQuota {
+        UserQuota::resolve(None, self.anonymous_tier)
+    }
+
+    /// 사용자에게 적용되는 등급과 한도.
+    pub async fn quota_for(&self, user_id: &str) -> UserQuota {
+        if let Some((quota, loaded_at)) = self.cache.read().await.get(user_id) {
+            if loaded_at.elapsed() < QUOTA_CACHE_TTL {
+                return *quota;
+            }
+        }
+
+        let record = match &self.pool {
+            Some(pool) => match QuotaRepository::get(pool, user_id).await {
+                Ok(record) => record,
+                Err(e) => {
+                    warn!(user_id = %user_id, error = %e, "할당량 조회 실패, 기본 등급 적용");
+                    return UserQuota::resolve(None, self.default_tier);
+                }
+            },
+            None => None,
+        };
+
+        let quota = UserQuota::resolve(record.as_ref(), self.default_tier);
+        self.cache
+            .write()
+            .await
+            .insert(user_id.to_string(), (quota, Instant::now()));
+        quota
+    }
+
+    /// 캐시된 한도 무효화 (할당량 변경 후 호출).
+    pub async fn invalidate(&self, user_id: &str) {
+        self.cache.write().await.remove(user_id);
+    }
+
+    /// 실행 중인 백테스트 수.
+    pub fn running_backtests(&self, user_id: &str) -> u32 {
+        let running = self.running.lock().unwrap_or_else(|e| e.into_inner());
+        running.get(user_id).copied().unwrap_or(0)
+    }
+
+    /// 백테스트 슬롯 확보 (요청 1건당 1슬롯).
+    pub async fn acquire_backtest(&self, user_id: &str) -> Result<BacktestPermit, QuotaError> {
+        let limit = self
+            .quota_for(user_id)
+            .await
+            .limits
+            .max_concurrent_backtests;
+        self.acquire_slot(user_id.to_string(), limit)
+    }
+
+    /// 비인증 클라이언트의 백테스트 슬롯 확보 (IP 단위, 익명 등급 한도).
+    pub fn acquire_anonymous_backtest(&self, ip: IpAddr) -> Result<BacktestPermit, QuotaError> {
+        let limit = self.anonymous_quota().limits.max_concurrent_backtests;
+        self.acquire_slot(format!("{}{}", ANONYMOUS_KEY_PREFIX, ip), limit)
+    }
+
+    fn acquire_slot(&self, key: String, limit: Option<u32>) -> Result<BacktestPermit, QuotaError> {
+        let mut running = self.running.lock().unwrap_or_else(|e| e.into_inner());
+        let count = running.get(&key).copied().unwrap_or(0);
+        if let Some(limit) = limit.filter(|&limit| count >= limit) {
+            return Err(QuotaError::ConcurrentBacktests { limit });
+        }
+        *running.entry(key.clone()).or_insert(0) += 1;
+
+        Ok(BacktestPermit {
+            running: self.running.clone(),
+            user_id: key,
+        })
+    }
+
+    /// 전략을 하나 더 등록할 수 있는지 확인.
+    pub async fn check_strategy_quota(&self, user_id: &str) -> Result<(), QuotaError> {
+        let limit = self.quota_for(user_id).await.limits.max_strategies;
+        self.check_strategy_count(limit, Some(user_id)).await
+    }
+
+    /// 비인증 요청으로 전략을 하나 더 등록할 수 있는지 확인 (소유자 없는 전략 수 기준).
+    pub async fn check_anonymous_strategy_quota(&self) -> Result<(), QuotaError> {
+        let limit = self.anonymous_quota().limits.max_strategies;
+        self.check_strategy_count(limit, None).await
+    }
+
+    async fn check_strategy_count(
+        &self,
+        limit: Option<u32>,
+        owner_id: Option<&str>,
+    ) -> Result<(), QuotaError> {
+        let Some(limit) = limit else {
+            return Ok(());
+        };
+        let Some(pool) = &self.pool else {
+            return Ok(());
+        };
+
+        let count = match owner_id {
+            Some(owner_id) => QuotaRepository::count_strategies(pool, owner_id).await,
+            None => QuotaRepository::count_unowned_strategies(pool).await,
+        };
+        match count {
+            Ok(count) if count >= limit as i64 => Err(QuotaError::Strategies { limit }),
+            Ok(_) => Ok(()),
+            Err(e) => {
+                warn!(owner_id = ?owner_id, error = %e, "전략 수 조회 실패, 할당량 검사 생략");
+                Ok(())
+            }
+        }
+    }
+
+    /// 백테스트 결과를 더 저장할 수 있는지 확인.
+    pub async fn check_storage_quota(&self, user_id: &str) -> Result<(), QuotaError> {
+        let Some(limit_mb) = self.quota_for(user_id).await.limits.storage_mb else {
+            return Ok(());
+        };
+        let Some(pool) = &self.pool else {
+            return Ok(());
+        };
+
+        match QuotaRepository::storage_bytes(pool, user_id).await {
+            Ok(bytes) if bytes as u64 >= limit_mb * 1024 * 1024 => {
+                Err(QuotaError::Storage { limit_mb })
+            }
+            Ok(_) => Ok(()),
+            Err(e) => {
+                warn!(user_id = %user_id, error = %e, "저장 용량 조회 실패, 할당량 검사 생략");
+                Ok(())
+            }
+        }
+    }
+
+    /// 현재 사용량.
+    pub async fn usage(&self, user_id: &str) -> Result<QuotaUsage, sqlx::Error> {
+        let mut usage = QuotaUsage {
+            running_backtests: self.running_backtests(user_id),
+            ..Default::default()
+        };
+        if let Some(pool) = &self.pool {
+            usage.strategies = QuotaRepository::count_strategies(pool, user_id).await?;
+            usage.storage_bytes = QuotaRepository::storage_bytes(pool, user_id).await?;
+        }
+        Ok(usage)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn record(tier: &str, max_strategies: Option<i32>) -> UserQuotaRecord {
+        UserQuotaRecord {
+            user_id: "user-1".to_string(),
+            tier: tier.to_string(),
+            max_concurrent_backtests: None,
+            max_strategies,
+            requests_per_minute: None,
+            storage_mb: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_resolve_overrides_tier_defaults() {
+        let quota = UserQuota::resolve(None, QuotaTier::Free);
+        assert_eq!(quota.tier, QuotaTier::Free);
+        assert_eq!(quota.limits, QuotaTier::Free.limits());
+
+        let quota = UserQuota::resolve(Some(&record("pro", Some(100))), QuotaTier::Free);
+        assert_eq!(quota.tier, QuotaTier::Pro);
+        assert_eq!(quota.limits.max_strategies, Some(100));
+        assert_eq!(quota.limits.max_concurrent_backtests, Some(5));
+
+        // 알 수 없는 등급은 기본 등급, unlimited는 개별 설정만 적용
+        let quota = UserQuota::resolve(Some(&record("gold", None)), QuotaTier::Standard);
+        assert_eq!(quota.tier, QuotaTier::Standard);
+        let quota = UserQuota::resolve(Some(&record("unlimited", Some(1))), QuotaTier::Free);
+        assert_eq!(quota.limits.max_strategies, Some(1));
+        assert_eq!(quota.limits.requests_per_minute, None);
+    }
+
+    #[tokio::test]
+    async fn test_backtest_permit_released_on_drop() {
+        let tracker = QuotaTracker::with_default_tier(None, QuotaTier::Free);
+
+        let permit = tracker.acquire_backtest("user-1").await.unwrap();
+        assert_eq!(tracker.running_backtests("user-1"), 1);
+        assert_eq!(
+            tracker.acquire_backtest("user-1").await.unwrap_err(),
+            QuotaError::ConcurrentBacktests { limit: 1 }
+        );
+        // 다른 사용자는 별도 집계
+        let _other = tracker.acquire_backtest("user-2").await.unwrap();
+
+        drop(permit);
+        assert_eq!(tracker.running_backtests("user-1"), 0);
+        assert!(tracker.acquire_backtest("user-1").await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_anonymous_backtest_permit_per_ip() {
+        let tracker = QuotaTracker::with_default_tier(None, QuotaTier::Pro)
+            .with_anonymous_tier(QuotaTier::Free);
+        assert_eq!(tracker.anonymous_quota().tier, QuotaTier::Free);
+
+        let ip: IpAddr = "10.0.0.1".parse().unwrap();
+        let permit = tracker.acquire_anonymous_backtest(ip).unwrap();
+        assert_eq!(
+            tracker.acquire_anonymous_backtest(ip).unwrap_err(),
+            QuotaError::ConcurrentBacktests { limit: 1 }
+        );
+        // 다른 IP와 인증 사용자는 별도 집계
+        let _other = tracker
+            .acquire_anonymous_backtest("10.0.0.2".parse().unwrap())
+            .unwrap();
+        assert!(tracker.acquire_backtest("user-1").await.is_ok());
+
+        drop(permit);
+        assert!(tracker.acquire_anonymous_backtest(ip).is_ok());
+    }
+}
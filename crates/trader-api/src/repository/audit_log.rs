@@ -0,0 +1,78 @@
+//! 감사 로그 Repository.
+//!
+//! `audit_logs` 테이블에 주요 운영 이벤트(리스크 설정 변경 등)를 기록하고 조회합니다.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+/// 감사 로그 레코드.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct AuditLogRecord {
+    pub id: Uuid,
+    /// 이벤트 타입 (예: risk_config_updated)
+    pub event_type: String,
+    /// 엔티티 타입 (예: risk_config)
+    pub entity_type: Option<String>,
+    /// 엔티티 ID
+    pub entity_id: Option<Uuid>,
+    /// 수행자
+    pub user_id: Option<String>,
+    /// 상세 정보
+    pub details: Option<serde_json::Value>,
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+/// 감사 로그 Repository.
+pub struct AuditLogRepository;
+
+impl AuditLogRepository {
+    /// 감사 로그 기록.
+    pub async fn record(
+        pool: &PgPool,
+        event_type: &str,
+        entity_type: &str,
+        entity_id: Option<Uuid>,
+        user_id: Option<&str>,
+        details: &serde_json::Value,
+    ) -> Result<Uuid, sqlx::Error> {
+        let (id,): (Uuid,) = sqlx::query_as(
+            r#"
+            INSERT INTO audit_logs (event_type, entity_type, entity_id, user_id, details)
+            VALUES ($1, $2, $3, $4, $5)
+            RETURNING id
+            "#,
+        )
+        .bind(event_type)
+        .bind(entity_type)
+        .bind(entity_id)
+        .bind(user_id)
+        .bind(details)
+        .fetch_one(pool)
+        .await?;
+
+        Ok(id)
+    }
+
+    /// 엔티티 타입별 감사 로그 조회 (최신순).
+    pub async fn list_by_entity_type(
+        pool: &PgPool,
+        entity_type: &str,
+        limit: i64,
+    ) -> Result<Vec<AuditLogRecord>, sqlx::Error> {
+        sqlx::query_as::<_, AuditLogRecord>(
+            r#"
+            SELECT id, event_type, entity_type, entity_id, user_id, details, created_at
+            FROM audit_logs
+            WHERE entity_type = $1
+            ORDER BY created_at DESC
+            LIMIT $2
+            "#,
+        )
+        .bind(entity_type)
+        .bind(limit)
+        .fetch_all(pool)
+        .await
+    }
+}
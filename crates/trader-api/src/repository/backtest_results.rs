@@ -2,21 +2,73 @@
 //!
 //! 백테스트 결과를 PostgreSQL에 영구 저장하고 조회하는 기능을 제공합니다.
 //! Soft delete 패턴을 사용하여 데이터 무결성을 보장합니다.
+//!
+//! 저장 시 자산 곡선을 LTTB로 다운샘플링한 미리보기(`equity_curve_preview`)를 함께 저장하여,
+//! 목록 조회와 차트 로드는 미리보기를 사용하고 전체 해상도는 요청할 때만 읽습니다.
 
 use chrono::{DateTime, NaiveDate, Utc};
 use rust_decimal::Decimal;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use sqlx::{FromRow, PgPool};
 use tracing::{debug, info, warn};
+use trader_analytics::lttb_indices;
 use uuid::Uuid;
 
+/// 자산 곡선 미리보기 점 수.
+pub const EQUITY_PREVIEW_POINTS: usize = 500;
+
+// ==================== 자산 곡선 다운샘플링 ====================
+
+/// 자산 곡선 점의 (x, y) 값.
+///
+/// x는 `timestamp`(없으면 순번), y는 `equity`입니다 (숫자 또는 숫자 문자열).
+fn equity_point_xy(index: usize, point: &serde_json::Value) -> Option<(f64, f64)> {
+    let number = |value: &serde_json::Value| {
+        value
+            .as_f64()
+            .or_else(|| value.as_str().and_then(|s| s.parse().ok()))
+    };
+    let x = point
+        .get("timestamp")
+        .and_then(number)
+        .unwrap_or(index as f64);
+    let y = point.get("equity").and_then(number)?;
+    Some((x, y))
+}
+
+/// 자산 곡선(JSON 배열)을 LTTB로 `points`개까지 줄입니다.
+///
+/// 원래 점의 필드(낙폭 등)는 그대로 유지됩니다. 배열이 아니거나 자산 값을 읽을 수 없는 점이
+/// 있으면 원본을 그대로 반환합니다.
+pub fn downsample_equity_curve(curve: &serde_json::Value, points: usize) -> serde_json::Value {
+    let Some(array) = curve.as_array() else {
+        return curve.clone();
+    };
+    let Some(xy) = array
+        .iter()
+        .enumerate()
+        .map(|(i, point)| equity_point_xy(i, point))
+        .collect::<Option<Vec<_>>>()
+    else {
+        return curve.clone();
+    };
+
+    serde_json::Value::Array(
+        lttb_indices(&xy, points)
+            .into_iter()
+            .map(|i| array[i].clone())
+            .collect(),
+    )
+}
+
 // ==================== DB 레코드 ====================
 
 /// 백테스트 결과 DB 레코드.
 #[derive(Debug, Clone, FromRow)]
 pub struct BacktestResultRecord {
     pub id: Uuid,
-    pub strategy_id: String,
+    /// 등록 전략 ID (등록 전략 없이 실행한 결과는 None)
+    pub strategy_id: Option<String>,
     pub strategy_type: String,
     pub symbol: String,
     pub start_date: NaiveDate,
@@ -33,6 +85,18 @@ pub struct BacktestResultRecord {
     pub deleted_at: Option<DateTime<Utc>>,
     /// 백테스트에 사용된 타임프레임 설정 (다중 TF 백테스트 시)
     pub timeframes_used: Option<serde_json::Value>,
+    /// 백테스트에 사용된 전략 파라미터 (라이브 승격 시 전략 설정으로 사용)
+    pub parameters: Option<serde_json::Value>,
+    /// 수수료/슬리피지 민감도 분석 (미실행이면 None)
+    pub sensitivity: Option<serde_json::Value>,
+}
+
+/// 저장된 자산 곡선 (미리보기 또는 전체 해상도).
+#[derive(Debug, Clone, FromRow)]
+pub struct EquityCurveRecord {
+    pub equity_curve: serde_json::Value,
+    /// 전체 해상도 점 수 (미리보기 도입 전 결과는 NULL)
+    pub equity_curve_points: Option<i32>,
 }
 
 // ==================== 요청/응답 타입 ====================
@@ -40,8 +104,8 @@ pub struct BacktestResultRecord {
 /// 결과 저장용 입력 데이터.
 #[derive(Debug, Clone)]
 pub struct BacktestResultInput {
-    /// 전략 ID (등록된 전략의 고유 ID)
-    pub strategy_id: String,
+    /// 전략 ID (등록된 전략의 고유 ID, 등록 전략 없이 실행한 결과는 None)
+    pub strategy_id: Option<String>,
     /// 전략 타입 (sma_crossover, bollinger 등)
     pub strategy_type: String,
     /// 심볼 (다중 자산은 콤마 구분)
@@ -66,13 +130,17 @@ pub struct BacktestResultInput {
     pub success: bool,
     /// 백테스트에 사용된 타임프레임 설정 (다중 TF 백테스트 시)
     pub timeframes_used: Option<serde_json::Value>,
+    /// 백테스트에 사용된 전략 파라미터
+    pub parameters: Option<serde_json::Value>,
+    /// 저장한 사용자 (JWT sub, 저장 용량 할당량 집계용)
+    pub owner_id: Option<String>,
 }
 
 /// 저장된 결과 응답용 DTO.
 #[derive(Debug, Clone, Serialize)]
 pub struct BacktestResultDto {
     pub id: String,
-    pub strategy_id: String,
+    pub strategy_id: Option<String>,
     pub strategy_type: String,
     pub symbol: String,
     pub start_date: String,
@@ -81,6 +149,7 @@ pub struct BacktestResultDto {
     pub slippage_rate: Option<String>,
     pub metrics: serde_json::Value,
     pub config_summary: serde_json::Value,
+    /// 자산 곡선 (목록 조회에서는 미리보기)
     pub equity_curve: serde_json::Value,
     pub trades: serde_json::Value,
     pub success: bool,
@@ -88,6 +157,12 @@ pub struct BacktestResultDto {
     /// 백테스트에 사용된 타임프레임 설정
     #[serde(skip_serializing_if = "Option::is_none")]
     pub timeframes_used: Option<serde_json::Value>,
+    /// 백테스트에 사용된 전략 파라미터
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parameters: Option<serde_json::Value>,
+    /// 수수료/슬리피지 민감도 분석
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sensitivity: Option<serde_json::Value>,
 }
 
 impl From<BacktestResultRecord> for BacktestResultDto {
@@ -108,6 +183,35 @@ impl From<BacktestResultRecord> for BacktestResultDto {
             success: record.success,
             created_at: record.created_at.to_rfc3339(),
             timeframes_used: record.timeframes_used,
+            parameters: record.parameters,
+            sensitivity: record.sensitivity,
+        }
+    }
+}
+
+/// 결과 목록 정렬 기준.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ResultsSortBy {
+    /// 최신순 (기본)
+    #[default]
+    CreatedAt,
+    /// 총 수익률 높은 순
+    TotalReturn,
+    /// 샤프 비율 높은 순
+    SharpeRatio,
+    /// 최대 낙폭 작은 순
+    MaxDrawdown,
+}
+
+impl ResultsSortBy {
+    /// SQL 정렬 키 (목록 쿼리의 CASE 분기와 일치).
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::CreatedAt => "created_at",
+            Self::TotalReturn => "total_return",
+            Self::SharpeRatio => "sharpe_ratio",
+            Self::MaxDrawdown => "max_drawdown",
         }
     }
 }
@@ -119,6 +223,14 @@ pub struct ListResultsFilter {
     pub strategy_id: Option<String>,
     /// 전략 타입 필터
     pub strategy_type: Option<String>,
+    /// 심볼 필터 (다중 자산 결과는 구성 심볼 중 하나와 일치)
+    pub symbol: Option<String>,
+    /// 실행일 시작 (포함)
+    pub created_from: Option<NaiveDate>,
+    /// 실행일 종료 (포함)
+    pub created_to: Option<NaiveDate>,
+    /// 정렬 기준
+    pub sort_by: ResultsSortBy,
     /// 결과 수 제한
     pub limit: i64,
     /// 오프셋
@@ -129,10 +241,8 @@ impl ListResultsFilter {
     /// 기본 limit 50으로 생성.
     pub fn new() -> Self {
         Self {
-            strategy_id: None,
-            strategy_type: None,
             limit: 50,
-            offset: 0,
+            ..Default::default()
         }
     }
 
@@ -148,6 +258,25 @@ impl ListResultsFilter {
         self
     }
 
+    /// 심볼 필터 설정.
+    pub fn with_symbol(mut self, symbol: impl Into<String>) -> Self {
+        self.symbol = Some(symbol.into());
+        self
+    }
+
+    /// 실행일 범위 설정 (양 끝 포함).
+    pub fn with_created_range(mut self, from: Option<NaiveDate>, to: Option<NaiveDate>) -> Self {
+        self.created_from = from;
+        self.created_to = to;
+        self
+    }
+
+    /// 정렬 기준 설정.
+    pub fn with_sort_by(mut self, sort_by: ResultsSortBy) -> Self {
+        self.sort_by = sort_by;
+        self
+    }
+
     /// 페이지네이션 설정.
     pub fn with_pagination(mut self, limit: i64, offset: i64) -> Self {
         self.limit = limit;
@@ -174,16 +303,23 @@ pub struct BacktestResultsRepository;
 impl BacktestResultsRepository {
     /// 백테스트 결과 저장.
     pub async fn save(pool: &PgPool, input: BacktestResultInput) -> Result<Uuid, sqlx::Error> {
-        debug!("백테스트 결과 저장: strategy_id={}", input.strategy_id);
+        debug!("백테스트 결과 저장: strategy_id={:?}", input.strategy_id);
+
+        let preview = downsample_equity_curve(&input.equity_curve, EQUITY_PREVIEW_POINTS);
+        let total_points = input
+            .equity_curve
+            .as_array()
+            .map(|points| points.len() as i32);
 
         let row: (Uuid,) = sqlx::query_as(
             r#"
             INSERT INTO backtest_results (
                 strategy_id, strategy_type, symbol, start_date, end_date,
                 initial_capital, slippage_rate, metrics, config_summary,
-                equity_curve, trades, success, timeframes_used
+                equity_curve, trades, success, timeframes_used,
+                equity_curve_preview, equity_curve_points, owner_id, parameters
             )
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17)
             RETURNING id
             "#,
         )
@@ -200,6 +336,10 @@ impl BacktestResultsRepository {
         .bind(&input.trades)
         .bind(input.success)
         .bind(&input.timeframes_used)
+        .bind(&preview)
+        .bind(total_points)
+        .bind(&input.owner_id)
+        .bind(&input.parameters)
         .fetch_one(pool)
         .await?;
 
@@ -218,7 +358,8 @@ impl BacktestResultsRepository {
             r#"
             SELECT id, strategy_id, strategy_type, symbol, start_date, end_date,
                    initial_capital, slippage_rate, metrics, config_summary,
-                   equity_curve, trades, success, error_message, created_at, deleted_at, timeframes_used
+                   equity_curve, trades, success, error_message, created_at, deleted_at, timeframes_used,
+                   parameters, sensitivity
             FROM backtest_results
             WHERE id = $1 AND deleted_at IS NULL
             "#,
@@ -230,6 +371,58 @@ impl BacktestResultsRepository {
         Ok(result)
     }
 
+    /// 수수료/슬리피지 민감도 분석 결과 저장.
+    ///
+    /// # Returns
+    /// 결과가 존재해 저장했으면 true
+    pub async fn save_sensitivity(
+        pool: &PgPool,
+        id: Uuid,
+        sensitivity: &serde_json::Value,
+    ) -> Result<bool, sqlx::Error> {
+        debug!("민감도 분석 저장: id={}", id);
+
+        let result = sqlx::query(
+            r#"
+            UPDATE backtest_results
+            SET sensitivity = $2
+            WHERE id = $1 AND deleted_at IS NULL
+            "#,
+        )
+        .bind(id)
+        .bind(sensitivity)
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// 자산 곡선 조회.
+    ///
+    /// `full`이 false면 저장된 미리보기를 읽습니다 (미리보기가 없는 결과는 전체 해상도).
+    pub async fn get_equity_curve(
+        pool: &PgPool,
+        id: Uuid,
+        full: bool,
+    ) -> Result<Option<EquityCurveRecord>, sqlx::Error> {
+        debug!("자산 곡선 조회: id={}, full={}", id, full);
+
+        sqlx::query_as::<_, EquityCurveRecord>(
+            r#"
+            SELECT CASE WHEN $2 THEN equity_curve
+                        ELSE COALESCE(equity_curve_preview, equity_curve)
+                   END AS equity_curve,
+                   equity_curve_points
+            FROM backtest_results
+            WHERE id = $1 AND deleted_at IS NULL
+            "#,
+        )
+        .bind(id)
+        .bind(full)
+        .fetch_optional(pool)
+        .await
+    }
+
     /// 백테스트 결과 목록 조회.
     pub async fn list(
         pool: &PgPool,
@@ -245,33 +438,54 @@ impl BacktestResultsRepository {
             WHERE deleted_at IS NULL
               AND ($1::text IS NULL OR strategy_id = $1)
               AND ($2::text IS NULL OR strategy_type = $2)
+              AND ($3::text IS NULL OR $3 = ANY(regexp_split_to_array(symbol, '\s*,\s*')))
+              AND ($4::date IS NULL OR created_at >= $4)
+              AND ($5::date IS NULL OR created_at < $5 + 1)
             "#,
         )
         .bind(&filter.strategy_id)
         .bind(&filter.strategy_type)
+        .bind(&filter.symbol)
+        .bind(filter.created_from)
+        .bind(filter.created_to)
         .fetch_one(pool)
         .await?;
 
         let total = count_result.0;
 
-        // 결과 목록 조회
+        // 결과 목록 조회 (자산 곡선은 미리보기)
         let records: Vec<BacktestResultRecord> = sqlx::query_as(
             r#"
             SELECT id, strategy_id, strategy_type, symbol, start_date, end_date,
                    initial_capital, slippage_rate, metrics, config_summary,
-                   equity_curve, trades, success, error_message, created_at, deleted_at, timeframes_used
+                   COALESCE(equity_curve_preview, equity_curve) AS equity_curve, trades, success, error_message, created_at, deleted_at, timeframes_used,
+                   parameters, sensitivity
             FROM backtest_results
             WHERE deleted_at IS NULL
               AND ($1::text IS NULL OR strategy_id = $1)
               AND ($2::text IS NULL OR strategy_type = $2)
-            ORDER BY created_at DESC
-            LIMIT $3 OFFSET $4
+              AND ($3::text IS NULL OR $3 = ANY(regexp_split_to_array(symbol, '\s*,\s*')))
+              AND ($4::date IS NULL OR created_at >= $4)
+              AND ($5::date IS NULL OR created_at < $5 + 1)
+            ORDER BY
+                CASE WHEN $8 = 'total_return'
+                     THEN (metrics->>'total_return_pct')::numeric END DESC NULLS LAST,
+                CASE WHEN $8 = 'sharpe_ratio'
+                     THEN (metrics->>'sharpe_ratio')::numeric END DESC NULLS LAST,
+                CASE WHEN $8 = 'max_drawdown'
+                     THEN ABS((metrics->>'max_drawdown_pct')::numeric) END ASC NULLS LAST,
+                created_at DESC
+            LIMIT $6 OFFSET $7
             "#,
         )
         .bind(&filter.strategy_id)
         .bind(&filter.strategy_type)
+        .bind(&filter.symbol)
+        .bind(filter.created_from)
+        .bind(filter.created_to)
         .bind(filter.limit)
         .bind(filter.offset)
+        .bind(filter.sort_by.as_str())
         .fetch_all(pool)
         .await?;
 
@@ -317,7 +531,8 @@ impl BacktestResultsRepository {
             r#"
             SELECT id, strategy_id, strategy_type, symbol, start_date, end_date,
                    initial_capital, slippage_rate, metrics, config_summary,
-                   equity_curve, trades, success, error_message, created_at, deleted_at, timeframes_used
+                   equity_curve, trades, success, error_message, created_at, deleted_at, timeframes_used,
+                   parameters, sensitivity
             FROM backtest_results
             WHERE strategy_id = $1 AND deleted_at IS NULL
             ORDER BY created_at DESC
@@ -341,7 +556,8 @@ impl BacktestResultsRepository {
             r#"
             SELECT id, strategy_id, strategy_type, symbol, start_date, end_date,
                    initial_capital, slippage_rate, metrics, config_summary,
-                   equity_curve, trades, success, error_message, created_at, deleted_at, timeframes_used
+                   equity_curve, trades, success, error_message, created_at, deleted_at, timeframes_used,
+                   parameters, sensitivity
             FROM backtest_results
             WHERE deleted_at IS NULL
             ORDER BY created_at DESC
@@ -388,6 +604,7 @@ impl BacktestResultsRepository {
 mod tests {
     use super::*;
     use rust_decimal_macros::dec;
+    use sqlx::postgres::{PgConnectOptions, PgPoolOptions};
 
     #[test]
     fn test_list_results_filter_builder() {
@@ -400,13 +617,281 @@ mod tests {
         assert_eq!(filter.strategy_type, Some("sma_crossover".to_string()));
         assert_eq!(filter.limit, 20);
         assert_eq!(filter.offset, 40);
+        assert_eq!(filter.sort_by, ResultsSortBy::CreatedAt);
+
+        let from = NaiveDate::from_ymd_opt(2024, 1, 1);
+        let filter = ListResultsFilter::new()
+            .with_symbol("TQQQ")
+            .with_created_range(from, None)
+            .with_sort_by(ResultsSortBy::SharpeRatio);
+        assert_eq!(filter.symbol, Some("TQQQ".to_string()));
+        assert_eq!(filter.created_from, from);
+        assert_eq!(filter.limit, 50);
+        assert_eq!(
+            serde_json::from_str::<ResultsSortBy>("\"max_drawdown\"").unwrap(),
+            ResultsSortBy::MaxDrawdown
+        );
+    }
+
+    /// 테스트용 결과 입력.
+    fn sample_input(
+        strategy_id: &str,
+        symbol: &str,
+        metrics: serde_json::Value,
+    ) -> BacktestResultInput {
+        BacktestResultInput {
+            strategy_id: Some(strategy_id.to_string()),
+            strategy_type: "sma_crossover".to_string(),
+            symbol: symbol.to_string(),
+            start_date: NaiveDate::from_ymd_opt(2023, 1, 1).unwrap(),
+            end_date: NaiveDate::from_ymd_opt(2023, 12, 31).unwrap(),
+            initial_capital: dec!(10000),
+            slippage_rate: Some(dec!(0.0005)),
+            metrics,
+            config_summary: serde_json::json!({}),
+            equity_curve: serde_json::json!([]),
+            trades: serde_json::json!([]),
+            success: true,
+            timeframes_used: None,
+            parameters: None,
+            owner_id: None,
+        }
+    }
+
+    /// 실행일 필터가 UTC 기준으로 동작하도록 세션 시간대를 UTC로 고정한 풀.
+    async fn utc_pool(pool_opts: PgPoolOptions, connect_opts: PgConnectOptions) -> PgPool {
+        pool_opts
+            .connect_with(connect_opts.options([("timezone", "UTC")]))
+            .await
+            .unwrap()
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_save_and_get_by_id(pool: PgPool) {
+        let mut input = sample_input(
+            "strat-001",
+            "SPY, TQQQ",
+            serde_json::json!({ "total_return_pct": 12.5 }),
+        );
+        input.equity_curve = serde_json::json!([{ "timestamp": 1, "equity": 10000 }]);
+        input.parameters = Some(serde_json::json!({ "short_period": 5 }));
+        input.owner_id = Some("user-1".to_string());
+
+        let id = BacktestResultsRepository::save(&pool, input).await.unwrap();
+        let record = BacktestResultsRepository::get_by_id(&pool, id)
+            .await
+            .unwrap()
+            .expect("저장한 결과 조회");
+
+        assert_eq!(record.id, id);
+        assert_eq!(record.strategy_id.as_deref(), Some("strat-001"));
+        assert_eq!(record.strategy_type, "sma_crossover");
+        assert_eq!(record.symbol, "SPY, TQQQ");
+        assert_eq!(record.initial_capital, dec!(10000));
+        assert_eq!(record.metrics["total_return_pct"], 12.5);
+        assert_eq!(record.equity_curve.as_array().map(Vec::len), Some(1));
+        assert_eq!(
+            record.parameters,
+            Some(serde_json::json!({ "short_period": 5 }))
+        );
+        assert!(record.deleted_at.is_none());
+
+        assert!(BacktestResultsRepository::get_by_id(&pool, Uuid::new_v4())
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_list_filters_and_sort(pool_opts: PgPoolOptions, connect_opts: PgConnectOptions) {
+        let pool = utc_pool(pool_opts, connect_opts).await;
+
+        // (전략 ID, 심볼, 실행 시각, 성과 지표)
+        let rows = [
+            (
+                "a",
+                "SPY, TQQQ",
+                "2024-03-10T23:30:00Z",
+                serde_json::json!({ "total_return_pct": 10, "sharpe_ratio": 0.5, "max_drawdown_pct": -20 }),
+            ),
+            (
+                "b",
+                "TQQQ",
+                "2024-03-09T12:00:00Z",
+                serde_json::json!({ "total_return_pct": 30, "sharpe_ratio": 1.5, "max_drawdown_pct": -5 }),
+            ),
+            (
+                "c",
+                "QQQ",
+                "2024-03-11T00:00:00Z",
+                serde_json::json!({ "total_return_pct": 20, "sharpe_ratio": 2.5, "max_drawdown_pct": -10 }),
+            ),
+            ("d", "TQQQX", "2024-03-08T00:00:00Z", serde_json::json!({})),
+        ];
+        for (strategy_id, symbol, created_at, metrics) in rows {
+            let id =
+                BacktestResultsRepository::save(&pool, sample_input(strategy_id, symbol, metrics))
+                    .await
+                    .unwrap();
+            sqlx::query("UPDATE backtest_results SET created_at = $2::timestamptz WHERE id = $1")
+                .bind(id)
+                .bind(created_at)
+                .execute(&pool)
+                .await
+                .unwrap();
+        }
+
+        let ids = |response: ListResultsResponse| {
+            response
+                .results
+                .into_iter()
+                .filter_map(|r| r.strategy_id)
+                .collect::<Vec<_>>()
+        };
+
+        let response =
+            BacktestResultsRepository::list(&pool, ListResultsFilter::new().with_strategy_id("c"))
+                .await
+                .unwrap();
+        assert_eq!(response.total, 1);
+        assert_eq!(ids(response), vec!["c"]);
+
+        let response = BacktestResultsRepository::list(
+            &pool,
+            ListResultsFilter::new().with_strategy_type("bollinger"),
+        )
+        .await
+        .unwrap();
+        assert_eq!(response.total, 0);
+
+        // 다중 자산 결과는 구성 심볼 중 하나와 정확히 일치해야 함 (TQQQX 제외)
+        let response =
+            BacktestResultsRepository::list(&pool, ListResultsFilter::new().with_symbol("TQQQ"))
+                .await
+                .unwrap();
+        assert_eq!(response.total, 2);
+        assert_eq!(ids(response), vec!["a", "b"]);
+
+        // 종료일 당일 23:30 결과는 포함, 다음 날 0시 결과는 제외
+        let response = BacktestResultsRepository::list(
+            &pool,
+            ListResultsFilter::new().with_created_range(
+                NaiveDate::from_ymd_opt(2024, 3, 9),
+                NaiveDate::from_ymd_opt(2024, 3, 10),
+            ),
+        )
+        .await
+        .unwrap();
+        assert_eq!(response.total, 2);
+        assert_eq!(ids(response), vec!["a", "b"]);
+
+        // 지표가 없는 결과는 지표 정렬에서 마지막
+        for (sort_by, expected) in [
+            (ResultsSortBy::CreatedAt, ["c", "a", "b", "d"]),
+            (ResultsSortBy::TotalReturn, ["b", "c", "a", "d"]),
+            (ResultsSortBy::SharpeRatio, ["c", "b", "a", "d"]),
+            (ResultsSortBy::MaxDrawdown, ["b", "c", "a", "d"]),
+        ] {
+            let response = BacktestResultsRepository::list(
+                &pool,
+                ListResultsFilter::new().with_sort_by(sort_by),
+            )
+            .await
+            .unwrap();
+            assert_eq!(ids(response), expected, "{:?}", sort_by);
+        }
+
+        // 페이지네이션은 total에 영향 없음
+        let response =
+            BacktestResultsRepository::list(&pool, ListResultsFilter::new().with_pagination(2, 1))
+                .await
+                .unwrap();
+        assert_eq!(response.total, 4);
+        assert_eq!(ids(response), vec!["a", "b"]);
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_delete_hides_result(pool: PgPool) {
+        let kept = BacktestResultsRepository::save(
+            &pool,
+            sample_input("kept", "SPY", serde_json::json!({})),
+        )
+        .await
+        .unwrap();
+        let deleted = BacktestResultsRepository::save(
+            &pool,
+            sample_input("deleted", "SPY", serde_json::json!({})),
+        )
+        .await
+        .unwrap();
+
+        assert!(BacktestResultsRepository::delete(&pool, deleted)
+            .await
+            .unwrap());
+        // 이미 삭제된 결과는 다시 삭제되지 않음
+        assert!(!BacktestResultsRepository::delete(&pool, deleted)
+            .await
+            .unwrap());
+        assert!(!BacktestResultsRepository::delete(&pool, Uuid::new_v4())
+            .await
+            .unwrap());
+
+        assert!(BacktestResultsRepository::get_by_id(&pool, deleted)
+            .await
+            .unwrap()
+            .is_none());
+        assert!(BacktestResultsRepository::get_by_id(&pool, kept)
+            .await
+            .unwrap()
+            .is_some());
+
+        let response = BacktestResultsRepository::list(&pool, ListResultsFilter::new())
+            .await
+            .unwrap();
+        assert_eq!(response.total, 1);
+        assert_eq!(response.results[0].id, kept.to_string());
+
+        // soft delete: 행은 남고 deleted_at만 설정됨
+        let (deleted_at,): (Option<DateTime<Utc>>,) =
+            sqlx::query_as("SELECT deleted_at FROM backtest_results WHERE id = $1")
+                .bind(deleted)
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert!(deleted_at.is_some());
+    }
+
+    #[test]
+    fn test_downsample_equity_curve() {
+        let curve = serde_json::Value::Array(
+            (0..2000)
+                .map(|i| {
+                    serde_json::json!({
+                        "timestamp": 1_700_000_000 + i * 86_400,
+                        "equity": format!("{}", 10_000 + (i % 37) * 10),
+                        "drawdown_pct": 0.5,
+                    })
+                })
+                .collect(),
+        );
+
+        let preview = downsample_equity_curve(&curve, EQUITY_PREVIEW_POINTS);
+        let points = preview.as_array().unwrap();
+        assert_eq!(points.len(), EQUITY_PREVIEW_POINTS);
+        assert_eq!(points[0], curve[0]);
+        assert_eq!(points[EQUITY_PREVIEW_POINTS - 1], curve[1999]);
+        assert_eq!(points[1]["drawdown_pct"], 0.5);
+
+        // 자산 값이 없는 곡선은 원본 유지
+        let invalid = serde_json::json!([{ "timestamp": 1 }, { "timestamp": 2 }]);
+        assert_eq!(downsample_equity_curve(&invalid, 1), invalid);
     }
 
     #[test]
     fn test_backtest_result_dto_from_record() {
         let record = BacktestResultRecord {
             id: Uuid::new_v4(),
-            strategy_id: "test-strategy".to_string(),
+            strategy_id: Some("test-strategy".to_string()),
             strategy_type: "sma_crossover".to_string(),
             symbol: "AAPL".to_string(),
             start_date: NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
@@ -422,11 +907,13 @@ mod tests {
             created_at: Utc::now(),
             deleted_at: None,
             timeframes_used: None,
+            parameters: None,
+            sensitivity: None,
         };
 
         let dto: BacktestResultDto = record.into();
 
-        assert_eq!(dto.strategy_id, "test-strategy");
+        assert_eq!(dto.strategy_id.as_deref(), Some("test-strategy"));
         assert_eq!(dto.strategy_type, "sma_crossover");
         assert!(dto.success);
     }
@@ -0,0 +1,357 @@
+//! 백테스트 템플릿 Repository.
+//!
+//! 전략, 파라미터, 유니버스, 기간, 비용을 묶은 이름 붙은 백테스트 설정(`backtest_template`)과
+//! 정기 실행 결과 시계열(`backtest_template_run`)을 관리합니다.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+/// 백테스트 템플릿 레코드.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct BacktestTemplateRecord {
+    pub id: Uuid,
+    /// 템플릿 이름
+    pub name: String,
+    /// 설명
+    pub description: Option<String>,
+    /// 전략 ID
+    pub strategy_id: String,
+    /// 심볼 목록 (1개면 단일 자산, 2개 이상이면 다중 자산)
+    pub symbols: Vec<String>,
+    /// 전략 파라미터
+    pub parameters: Option<serde_json::Value>,
+    /// 시작 날짜
+    pub start_date: NaiveDate,
+    /// 종료 날짜 (None이면 실행일)
+    pub end_date: Option<NaiveDate>,
+    /// 초기 자본금
+    pub initial_capital: Decimal,
+    /// 수수료율 (None이면 기본값)
+    pub commission_rate: Option<Decimal>,
+    /// 슬리피지율 (None이면 기본값)
+    pub slippage_rate: Option<Decimal>,
+    /// 유휴 현금 금리 시리즈
+    pub cash_yield_series: Option<String>,
+    /// 마지막 실행 시각
+    pub last_run_at: Option<DateTime<Utc>>,
+    /// 정기 실행 주기 (일, None이면 비활성)
+    pub schedule_interval_days: Option<i32>,
+    /// 다음 정기 실행 시각
+    pub next_run_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl BacktestTemplateRecord {
+    /// 실행 기준 종료일 (미지정 시 `today`).
+    pub fn resolved_end_date(&self, today: NaiveDate) -> NaiveDate {
+        self.end_date.unwrap_or(today)
+    }
+}
+
+/// 템플릿 생성/수정 입력.
+#[derive(Debug, Clone)]
+pub struct BacktestTemplateInput {
+    pub name: String,
+    pub description: Option<String>,
+    pub strategy_id: String,
+    pub symbols: Vec<String>,
+    pub parameters: Option<serde_json::Value>,
+    pub start_date: NaiveDate,
+    pub end_date: Option<NaiveDate>,
+    pub initial_capital: Decimal,
+    pub commission_rate: Option<Decimal>,
+    pub slippage_rate: Option<Decimal>,
+    pub cash_yield_series: Option<String>,
+}
+
+/// 정기 실행 결과 레코드.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct BacktestTemplateRunRecord {
+    pub id: Uuid,
+    pub template_id: Uuid,
+    /// 백테스트 시작 날짜
+    pub start_date: NaiveDate,
+    /// 백테스트 종료 날짜 (실행 시점 기준)
+    pub end_date: NaiveDate,
+    /// 성공 여부
+    pub success: bool,
+    /// 실패 사유
+    pub error: Option<String>,
+    /// 총 수익률 (%)
+    pub total_return_pct: Option<Decimal>,
+    /// 최대 낙폭 (%)
+    pub max_drawdown_pct: Option<Decimal>,
+    /// 샤프 비율
+    pub sharpe_ratio: Option<Decimal>,
+    /// 총 거래 수
+    pub total_trades: Option<i32>,
+    /// 전체 성과 지표
+    pub metrics: Option<serde_json::Value>,
+    /// 이전 실행 대비 악화 항목
+    pub regressions: Vec<String>,
+    pub run_at: DateTime<Utc>,
+}
+
+/// 정기 실행 결과 입력.
+#[derive(Debug, Clone)]
+pub struct BacktestTemplateRunInput {
+    pub template_id: Uuid,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub success: bool,
+    pub error: Option<String>,
+    pub total_return_pct: Option<Decimal>,
+    pub max_drawdown_pct: Option<Decimal>,
+    pub sharpe_ratio: Option<Decimal>,
+    pub total_trades: Option<i32>,
+    pub metrics: Option<serde_json::Value>,
+    pub regressions: Vec<String>,
+}
+
+const TEMPLATE_COLUMNS: &str = "id, name, description, strategy_id, symbols, parameters, \
+     start_date, end_date, initial_capital, commission_rate, slippage_rate, cash_yield_series, \
+     last_run_at, schedule_interval_days, next_run_at, created_at, updated_at";
+
+const RUN_COLUMNS: &str = "id, template_id, start_date, end_date, success, error, \
+     total_return_pct, max_drawdown_pct, sharpe_ratio, total_trades, metrics, regressions, run_at";
+
+/// 백테스트 템플릿 Repository.
+pub struct BacktestTemplateRepository;
+
+impl BacktestTemplateRepository {
+    /// 템플릿 목록 조회 (이름순, 전략 ID 필터 선택).
+    pub async fn list(
+        pool: &PgPool,
+        strategy_id: Option<&str>,
+    ) -> Result<Vec<BacktestTemplateRecord>, sqlx::Error> {
+        sqlx::query_as::<_, BacktestTemplateRecord>(&format!(
+            r#"
+            SELECT {TEMPLATE_COLUMNS} FROM backtest_template
+            WHERE ($1::text IS NULL OR strategy_id = $1)
+            ORDER BY name
+            "#
+        ))
+        .bind(strategy_id)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// 단일 템플릿 조회.
+    pub async fn get(
+        pool: &PgPool,
+        id: Uuid,
+    ) -> Result<Option<BacktestTemplateRecord>, sqlx::Error> {
+        sqlx::query_as::<_, BacktestTemplateRecord>(&format!(
+            "SELECT {TEMPLATE_COLUMNS} FROM backtest_template WHERE id = $1"
+        ))
+        .bind(id)
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// 여러 템플릿 조회 (존재하는 것만, 순서 무관).
+    pub async fn get_many(
+        pool: &PgPool,
+        ids: &[Uuid],
+    ) -> Result<Vec<BacktestTemplateRecord>, sqlx::Error> {
+        sqlx::query_as::<_, BacktestTemplateRecord>(&format!(
+            "SELECT {TEMPLATE_COLUMNS} FROM backtest_template WHERE id = ANY($1)"
+        ))
+        .bind(ids)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// 템플릿 생성.
+    pub async fn create(
+        pool: &PgPool,
+        input: &BacktestTemplateInput,
+    ) -> Result<BacktestTemplateRecord, sqlx::Error> {
+        sqlx::query_as::<_, BacktestTemplateRecord>(&format!(
+            r#"
+            INSERT INTO backtest_template
+                (name, description, strategy_id, symbols, parameters, start_date, end_date,
+                 initial_capital, commission_rate, slippage_rate, cash_yield_series)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+            RETURNING {TEMPLATE_COLUMNS}
+            "#
+        ))
+        .bind(&input.name)
+        .bind(&input.description)
+        .bind(&input.strategy_id)
+        .bind(&input.symbols)
+        .bind(&input.parameters)
+        .bind(input.start_date)
+        .bind(input.end_date)
+        .bind(input.initial_capital)
+        .bind(input.commission_rate)
+        .bind(input.slippage_rate)
+        .bind(&input.cash_yield_series)
+        .fetch_one(pool)
+        .await
+    }
+
+    /// 템플릿 수정.
+    pub async fn update(
+        pool: &PgPool,
+        id: Uuid,
+        input: &BacktestTemplateInput,
+    ) -> Result<Option<BacktestTemplateRecord>, sqlx::Error> {
+        sqlx::query_as::<_, BacktestTemplateRecord>(&format!(
+            r#"
+            UPDATE backtest_template
+            SET name = $2, description = $3, strategy_id = $4, symbols = $5, parameters = $6,
+                start_date = $7, end_date = $8, initial_capital = $9, commission_rate = $10,
+                slippage_rate = $11, cash_yield_series = $12
+            WHERE id = $1
+            RETURNING {TEMPLATE_COLUMNS}
+            "#
+        ))
+        .bind(id)
+        .bind(&input.name)
+        .bind(&input.description)
+        .bind(&input.strategy_id)
+        .bind(&input.symbols)
+        .bind(&input.parameters)
+        .bind(input.start_date)
+        .bind(input.end_date)
+        .bind(input.initial_capital)
+        .bind(input.commission_rate)
+        .bind(input.slippage_rate)
+        .bind(&input.cash_yield_series)
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// 템플릿 삭제.
+    ///
+    /// # Returns
+    /// 삭제 여부
+    pub async fn delete(pool: &PgPool, id: Uuid) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query("DELETE FROM backtest_template WHERE id = $1")
+            .bind(id)
+            .execute(pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// 마지막 실행 시각 기록.
+    pub async fn mark_run(pool: &PgPool, ids: &[Uuid]) -> Result<(), sqlx::Error> {
+        sqlx::query("UPDATE backtest_template SET last_run_at = NOW() WHERE id = ANY($1)")
+            .bind(ids)
+            .execute(pool)
+            .await?;
+        Ok(())
+    }
+
+    /// 정기 실행 주기 설정.
+    ///
+    /// 주기를 설정하면 다음 스케줄러 점검 시 즉시 실행되어 기준 결과를 남깁니다.
+    /// `interval_days`가 `None`이면 정기 실행을 해제합니다.
+    pub async fn set_schedule(
+        pool: &PgPool,
+        id: Uuid,
+        interval_days: Option<i32>,
+    ) -> Result<Option<BacktestTemplateRecord>, sqlx::Error> {
+        sqlx::query_as::<_, BacktestTemplateRecord>(&format!(
+            r#"
+            UPDATE backtest_template
+            SET schedule_interval_days = $2,
+                next_run_at = CASE WHEN $2::integer IS NULL THEN NULL ELSE NOW() END
+            WHERE id = $1
+            RETURNING {TEMPLATE_COLUMNS}
+            "#
+        ))
+        .bind(id)
+        .bind(interval_days)
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// 실행 시각이 도래한 정기 실행 템플릿 조회 (오래 기다린 순).
+    pub async fn list_due(
+        pool: &PgPool,
+        limit: i64,
+    ) -> Result<Vec<BacktestTemplateRecord>, sqlx::Error> {
+        sqlx::query_as::<_, BacktestTemplateRecord>(&format!(
+            r#"
+            SELECT {TEMPLATE_COLUMNS} FROM backtest_template
+            WHERE schedule_interval_days IS NOT NULL AND next_run_at <= NOW()
+            ORDER BY next_run_at
+            LIMIT $1
+            "#
+        ))
+        .bind(limit)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// 정기 실행 완료 처리 (다음 실행 시각 = 현재 + 주기).
+    pub async fn advance_schedule(pool: &PgPool, id: Uuid) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            UPDATE backtest_template
+            SET last_run_at = NOW(),
+                next_run_at = NOW() + make_interval(days => schedule_interval_days)
+            WHERE id = $1 AND schedule_interval_days IS NOT NULL
+            "#,
+        )
+        .bind(id)
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    /// 정기 실행 결과 저장.
+    pub async fn insert_run(
+        pool: &PgPool,
+        input: &BacktestTemplateRunInput,
+    ) -> Result<BacktestTemplateRunRecord, sqlx::Error> {
+        sqlx::query_as::<_, BacktestTemplateRunRecord>(&format!(
+            r#"
+            INSERT INTO backtest_template_run
+                (template_id, start_date, end_date, success, error, total_return_pct,
+                 max_drawdown_pct, sharpe_ratio, total_trades, metrics, regressions)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+            RETURNING {RUN_COLUMNS}
+            "#
+        ))
+        .bind(input.template_id)
+        .bind(input.start_date)
+        .bind(input.end_date)
+        .bind(input.success)
+        .bind(&input.error)
+        .bind(input.total_return_pct)
+        .bind(input.max_drawdown_pct)
+        .bind(input.sharpe_ratio)
+        .bind(input.total_trades)
+        .bind(&input.metrics)
+        .bind(&input.regressions)
+        .fetch_one(pool)
+        .await
+    }
+
+    /// 템플릿의 정기 실행 결과 조회 (최신순).
+    pub async fn list_runs(
+        pool: &PgPool,
+        template_id: Uuid,
+        limit: i64,
+    ) -> Result<Vec<BacktestTemplateRunRecord>, sqlx::Error> {
+        sqlx::query_as::<_, BacktestTemplateRunRecord>(&format!(
+            r#"
+            SELECT {RUN_COLUMNS} FROM backtest_template_run
+            WHERE template_id = $1
+            ORDER BY run_at DESC
+            LIMIT $2
+            "#
+        ))
+        .bind(template_id)
+        .bind(limit)
+        .fetch_all(pool)
+        .await
+    }
+}
@@ -0,0 +1,124 @@
+//! 브로커 점검/장애 Repository.
+//!
+//! 운영자가 등록한 예정 점검과 주문 경로에서 감지된 장애(`broker_downtime`)를 저장합니다.
+//! 점검/장애 모니터가 진행 중이거나 예정된 구간을 메모리 달력에 적재합니다.
+
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgPool};
+use trader_core::{BrokerDowntime, DowntimeSource};
+use uuid::Uuid;
+
+/// 점검/장애 레코드.
+#[derive(Debug, Clone, FromRow)]
+pub struct BrokerDowntimeRecord {
+    pub id: Uuid,
+    pub broker: String,
+    pub source: String,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: Option<DateTime<Utc>>,
+    pub reason: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl BrokerDowntimeRecord {
+    /// 달력 구간으로 변환 (알 수 없는 source면 `None`).
+    pub fn to_window(&self) -> Option<BrokerDowntime> {
+        Some(BrokerDowntime {
+            id: self.id,
+            broker: self.broker.clone(),
+            source: self.source.parse::<DowntimeSource>().ok()?,
+            starts_at: self.starts_at,
+            ends_at: self.ends_at,
+            reason: self.reason.clone(),
+        })
+    }
+}
+
+/// 브로커 점검/장애 Repository.
+pub struct BrokerDowntimeRepository;
+
+impl BrokerDowntimeRepository {
+    /// 진행 중이거나 예정된 구간 조회 (시작 시각순).
+    pub async fn list_upcoming(
+        pool: &PgPool,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<BrokerDowntimeRecord>, sqlx::Error> {
+        sqlx::query_as::<_, BrokerDowntimeRecord>(
+            r#"
+            SELECT id, broker, source, starts_at, ends_at, reason, created_at
+            FROM broker_downtime
+            WHERE ends_at IS NULL OR ends_at > $1
+            ORDER BY starts_at
+            "#,
+        )
+        .bind(now)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// 구간 저장.
+    pub async fn insert(pool: &PgPool, window: &BrokerDowntime) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            INSERT INTO broker_downtime (id, broker, source, starts_at, ends_at, reason)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            "#,
+        )
+        .bind(window.id)
+        .bind(&window.broker)
+        .bind(window.source.to_string())
+        .bind(window.starts_at)
+        .bind(window.ends_at)
+        .bind(&window.reason)
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    /// 감지 장애 종료 시각 기록.
+    pub async fn close(
+        pool: &PgPool,
+        id: Uuid,
+        ends_at: DateTime<Utc>,
+    ) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query(
+            r#"
+            UPDATE broker_downtime
+            SET ends_at = $2
+            WHERE id = $1 AND ends_at IS NULL
+            "#,
+        )
+        .bind(id)
+        .bind(ends_at)
+        .execute(pool)
+        .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// 진행 중인 감지 장애를 모두 종료 (서버 재시작 시 이전 장애 정리).
+    pub async fn close_open_outages(
+        pool: &PgPool,
+        ends_at: DateTime<Utc>,
+    ) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query(
+            r#"
+            UPDATE broker_downtime
+            SET ends_at = GREATEST($1, starts_at)
+            WHERE source = 'detected' AND ends_at IS NULL
+            "#,
+        )
+        .bind(ends_at)
+        .execute(pool)
+        .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// 구간 삭제 (삭제된 경우 true).
+    pub async fn delete(pool: &PgPool, id: Uuid) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query("DELETE FROM broker_downtime WHERE id = $1")
+            .bind(id)
+            .execute(pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+}
@@ -0,0 +1,325 @@
+//! 전략 경쟁 Repository.
+//!
+//! 경쟁(`strategy_competition`), 참가 전략(`strategy_competition_entry`),
+//! 참가자별 평가액 시계열(`strategy_competition_equity`)을 관리합니다.
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, PgPool};
+use trader_strategy::PaperBookConfig;
+use uuid::Uuid;
+
+/// 경쟁 레코드.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct CompetitionRecord {
+    pub id: Uuid,
+    /// 경쟁 이름
+    pub name: String,
+    pub description: Option<String>,
+    /// 참가자별 초기 자본
+    pub initial_capital: Decimal,
+    /// 진입 1회당 평가액 대비 투자 비율
+    pub position_size_pct: Decimal,
+    /// 체결 수수료 (bp)
+    pub fee_bps: Decimal,
+    /// 상태 (running, finished)
+    pub status: String,
+    pub started_at: DateTime<Utc>,
+    /// 종료 예정 시각 (None = 수동 종료)
+    pub ends_at: Option<DateTime<Utc>>,
+    pub finished_at: Option<DateTime<Utc>>,
+    /// 종료 시 1위 자동 승격 여부
+    pub auto_promote: bool,
+    /// 승격 판정 구간 (일, None = 전체 기간)
+    pub promotion_horizon_days: Option<i32>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl CompetitionRecord {
+    /// 참가자 가상 계좌 설정.
+    pub fn book_config(&self) -> PaperBookConfig {
+        PaperBookConfig {
+            initial_capital: self.initial_capital,
+            position_size_pct: self.position_size_pct,
+            fee_bps: self.fee_bps,
+        }
+    }
+
+    /// 진행 중 여부.
+    pub fn is_running(&self) -> bool {
+        self.status == "running"
+    }
+}
+
+/// 참가 전략 레코드.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct CompetitionEntryRecord {
+    pub id: Uuid,
+    pub competition_id: Uuid,
+    /// 참가자 이름 (경쟁 내 고유)
+    pub name: String,
+    /// 전략 타입
+    pub strategy_type: String,
+    /// 전략 설정
+    pub config: serde_json::Value,
+    /// 승격된 실거래 전략 ID
+    pub promoted_strategy_id: Option<String>,
+    pub promoted_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl CompetitionEntryRecord {
+    /// 전략 엔진에 등록할 참가자 ID.
+    pub fn competitor_id(&self) -> String {
+        format!("competition_{}", self.id)
+    }
+}
+
+/// 경쟁 생성 입력.
+#[derive(Debug, Clone)]
+pub struct CompetitionInput {
+    pub name: String,
+    pub description: Option<String>,
+    pub initial_capital: Decimal,
+    pub position_size_pct: Decimal,
+    pub fee_bps: Decimal,
+    pub ends_at: Option<DateTime<Utc>>,
+    pub auto_promote: bool,
+    pub promotion_horizon_days: Option<i32>,
+}
+
+/// 참가 전략 입력.
+#[derive(Debug, Clone)]
+pub struct CompetitionEntryInput {
+    pub name: String,
+    pub strategy_type: String,
+    pub config: serde_json::Value,
+}
+
+/// 참가자 평가액 기록.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct CompetitionEquityRecord {
+    pub entry_id: Uuid,
+    pub recorded_at: DateTime<Utc>,
+    pub equity: Decimal,
+    pub cash: Decimal,
+    pub open_positions: i32,
+    pub trades: i64,
+}
+
+const COMPETITION_COLUMNS: &str = "id, name, description, initial_capital, position_size_pct, \
+     fee_bps, status, started_at, ends_at, finished_at, auto_promote, promotion_horizon_days, \
+     created_at, updated_at";
+
+const ENTRY_COLUMNS: &str = "id, competition_id, name, strategy_type, config, \
+     promoted_strategy_id, promoted_at, created_at";
+
+/// 전략 경쟁 Repository.
+pub struct CompetitionRepository;
+
+impl CompetitionRepository {
+    /// 전체 경쟁 조회 (최신순).
+    pub async fn list(pool: &PgPool) -> Result<Vec<CompetitionRecord>, sqlx::Error> {
+        sqlx::query_as::<_, CompetitionRecord>(&format!(
+            "SELECT {COMPETITION_COLUMNS} FROM strategy_competition ORDER BY started_at DESC"
+        ))
+        .fetch_all(pool)
+        .await
+    }
+
+    /// 진행 중인 경쟁 조회.
+    pub async fn list_running(pool: &PgPool) -> Result<Vec<CompetitionRecord>, sqlx::Error> {
+        sqlx::query_as::<_, CompetitionRecord>(&format!(
+            "SELECT {COMPETITION_COLUMNS} FROM strategy_competition \
+             WHERE status = 'running' ORDER BY started_at"
+        ))
+        .fetch_all(pool)
+        .await
+    }
+
+    /// 단일 경쟁 조회.
+    pub async fn get(pool: &PgPool, id: Uuid) -> Result<Option<CompetitionRecord>, sqlx::Error> {
+        sqlx::query_as::<_, CompetitionRecord>(&format!(
+            "SELECT {COMPETITION_COLUMNS} FROM strategy_competition WHERE id = $1"
+        ))
+        .bind(id)
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// 경쟁과 참가 전략 생성 (트랜잭션).
+    pub async fn create(
+        pool: &PgPool,
+        input: &CompetitionInput,
+        entries: &[CompetitionEntryInput],
+    ) -> Result<(CompetitionRecord, Vec<CompetitionEntryRecord>), sqlx::Error> {
+        let mut tx = pool.begin().await?;
+
+        let competition = sqlx::query_as::<_, CompetitionRecord>(&format!(
+            r#"
+            INSERT INTO strategy_competition
+                (name, description, initial_capital, position_size_pct, fee_bps, ends_at,
+                 auto_promote, promotion_horizon_days)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            RETURNING {COMPETITION_COLUMNS}
+            "#
+        ))
+        .bind(&input.name)
+        .bind(&input.description)
+        .bind(input.initial_capital)
+        .bind(input.position_size_pct)
+        .bind(input.fee_bps)
+        .bind(input.ends_at)
+        .bind(input.auto_promote)
+        .bind(input.promotion_horizon_days)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        let mut records = Vec::with_capacity(entries.len());
+        for entry in entries {
+            let record = sqlx::query_as::<_, CompetitionEntryRecord>(&format!(
+                r#"
+                INSERT INTO strategy_competition_entry
+                    (competition_id, name, strategy_type, config)
+                VALUES ($1, $2, $3, $4)
+                RETURNING {ENTRY_COLUMNS}
+                "#
+            ))
+            .bind(competition.id)
+            .bind(&entry.name)
+            .bind(&entry.strategy_type)
+            .bind(&entry.config)
+            .fetch_one(&mut *tx)
+            .await?;
+            records.push(record);
+        }
+
+        tx.commit().await?;
+        Ok((competition, records))
+    }
+
+    /// 경쟁 종료 처리.
+    ///
+    /// # Returns
+    /// 진행 중이던 경쟁을 종료했으면 true
+    pub async fn finish(pool: &PgPool, id: Uuid) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query(
+            "UPDATE strategy_competition SET status = 'finished', finished_at = NOW() \
+             WHERE id = $1 AND status = 'running'",
+        )
+        .bind(id)
+        .execute(pool)
+        .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// 경쟁 삭제 (참가 전략, 평가액 기록 포함).
+    pub async fn delete(pool: &PgPool, id: Uuid) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query("DELETE FROM strategy_competition WHERE id = $1")
+            .bind(id)
+            .execute(pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// 경쟁의 참가 전략 조회 (등록순).
+    pub async fn list_entries(
+        pool: &PgPool,
+        competition_id: Uuid,
+    ) -> Result<Vec<CompetitionEntryRecord>, sqlx::Error> {
+        sqlx::query_as::<_, CompetitionEntryRecord>(&format!(
+            "SELECT {ENTRY_COLUMNS} FROM strategy_competition_entry \
+             WHERE competition_id = $1 ORDER BY created_at, name"
+        ))
+        .bind(competition_id)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// 참가자 승격 기록.
+    pub async fn mark_promoted(
+        pool: &PgPool,
+        entry_id: Uuid,
+        strategy_id: &str,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            "UPDATE strategy_competition_entry \
+             SET promoted_strategy_id = $2, promoted_at = NOW() WHERE id = $1",
+        )
+        .bind(entry_id)
+        .bind(strategy_id)
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    /// 참가자 평가액 기록.
+    pub async fn insert_equity(
+        pool: &PgPool,
+        record: &CompetitionEquityRecord,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            INSERT INTO strategy_competition_equity
+                (entry_id, recorded_at, equity, cash, open_positions, trades)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            ON CONFLICT (entry_id, recorded_at) DO NOTHING
+            "#,
+        )
+        .bind(record.entry_id)
+        .bind(record.recorded_at)
+        .bind(record.equity)
+        .bind(record.cash)
+        .bind(record.open_positions)
+        .bind(record.trades)
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    /// 참가자별 최근 기록 평가액.
+    pub async fn latest_equity(
+        pool: &PgPool,
+        competition_id: Uuid,
+    ) -> Result<Vec<(Uuid, Decimal)>, sqlx::Error> {
+        sqlx::query_as::<_, (Uuid, Decimal)>(
+            r#"
+            SELECT DISTINCT ON (q.entry_id) q.entry_id, q.equity
+            FROM strategy_competition_equity q
+            JOIN strategy_competition_entry e ON e.id = q.entry_id
+            WHERE e.competition_id = $1
+            ORDER BY q.entry_id, q.recorded_at DESC
+            "#,
+        )
+        .bind(competition_id)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// 경쟁 참가자들의 평가액 시계열 (참가자, 시간순).
+    ///
+    /// # Arguments
+    /// * `since` - 시작 시각 (None이면 전체 기간)
+    pub async fn equity_series(
+        pool: &PgPool,
+        competition_id: Uuid,
+        since: Option<DateTime<Utc>>,
+    ) -> Result<Vec<CompetitionEquityRecord>, sqlx::Error> {
+        sqlx::query_as::<_, CompetitionEquityRecord>(
+            r#"
+            SELECT q.entry_id, q.recorded_at, q.equity, q.cash, q.open_positions, q.trades
+            FROM strategy_competition_equity q
+            JOIN strategy_competition_entry e ON e.id = q.entry_id
+            WHERE e.competition_id = $1
+              AND ($2::timestamptz IS NULL OR q.recorded_at >= $2)
+            ORDER BY q.entry_id, q.recorded_at
+            "#,
+        )
+        .bind(competition_id)
+        .bind(since)
+        .fetch_all(pool)
+        .await
+    }
+}
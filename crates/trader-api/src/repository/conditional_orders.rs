@@ -0,0 +1,338 @@
+//! 조건부 주문 Repository.
+//!
+//! 서버 측 조건부 주문(`conditional_order`)의 저장, 상태 전이, 만료 처리를 담당합니다.
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, PgPool};
+use trader_core::{
+    ConditionalOrderStatus, OrderCondition, OrderRequest, OrderType, Side, TimeInForce,
+};
+use uuid::Uuid;
+
+/// 조건부 주문 레코드.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct ConditionalOrderRecord {
+    pub id: Uuid,
+    /// 종목 코드
+    pub ticker: String,
+    /// 시장 (KR, US, CRYPTO)
+    pub market: String,
+    /// 주문 방향 (buy, sell)
+    pub side: String,
+    /// 주문 유형 (market, limit)
+    pub order_type: String,
+    /// 주문 수량
+    pub quantity: Decimal,
+    /// 지정가 주문 가격
+    pub limit_price: Option<Decimal>,
+    /// 전략 ID (전략 예산/비용 모델 적용)
+    pub strategy_id: Option<String>,
+    /// 실행 조건 목록 (JSON)
+    pub conditions: serde_json::Value,
+    /// 조건 평가 타임프레임
+    pub timeframe: String,
+    /// 상태 (active, triggered, failed, expired, cancelled)
+    pub status: String,
+    /// 만료 시각
+    pub expires_at: Option<DateTime<Utc>>,
+    /// 마지막 평가 시각
+    pub last_evaluated_at: Option<DateTime<Utc>>,
+    /// 마지막 평가/실행 오류
+    pub last_error: Option<String>,
+    /// 조건 충족 시각
+    pub triggered_at: Option<DateTime<Utc>>,
+    /// 실행된 내부 주문 ID
+    pub order_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl ConditionalOrderRecord {
+    /// 실행 조건 파싱.
+    pub fn parsed_conditions(&self) -> Result<Vec<OrderCondition>, serde_json::Error> {
+        serde_json::from_value(self.conditions.clone())
+    }
+
+    /// 상태 파싱 (알 수 없는 값은 None).
+    pub fn parsed_status(&self) -> Option<ConditionalOrderStatus> {
+        self.status.parse().ok()
+    }
+
+    /// 조건 충족 시 실행할 주문 요청 생성.
+    pub fn to_order_request(&self) -> Result<OrderRequest, String> {
+        let side = self.side.parse::<Side>()?;
+        let (order_type, price) = match self.order_type.as_str() {
+            "market" => (OrderType::Market, None),
+            "limit" => (
+                OrderType::Limit,
+                Some(
+                    self.limit_price
+                        .ok_or_else(|| "limit_price is required for limit orders".to_string())?,
+                ),
+            ),
+            other => return Err(format!("Unsupported order type: {}", other)),
+        };
+
+        Ok(OrderRequest {
+            ticker: self.ticker.clone(),
+            side,
+            order_type,
+            quantity: self.quantity,
+            price,
+            stop_price: None,
+            time_in_force: TimeInForce::GTC,
+            client_order_id: Some(format!("cond_{}", self.id)),
+            strategy_id: self.strategy_id.clone(),
+        })
+    }
+}
+
+/// 조건부 주문 생성/수정 입력.
+#[derive(Debug, Clone)]
+pub struct ConditionalOrderInput {
+    pub ticker: String,
+    pub market: String,
+    pub side: String,
+    pub order_type: String,
+    pub quantity: Decimal,
+    pub limit_price: Option<Decimal>,
+    pub strategy_id: Option<String>,
+    pub conditions: serde_json::Value,
+    pub timeframe: String,
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+const CONDITIONAL_ORDER_COLUMNS: &str = "id, ticker, market, side, order_type, quantity, \
+     limit_price, strategy_id, conditions, timeframe, status, expires_at, last_evaluated_at, \
+     last_error, triggered_at, order_id, created_at, updated_at";
+
+/// 조건부 주문 Repository.
+pub struct ConditionalOrderRepository;
+
+impl ConditionalOrderRepository {
+    /// 조건부 주문 목록 조회 (최신순).
+    ///
+    /// # Arguments
+    /// * `status` - 상태 필터 (None이면 전체)
+    /// * `limit` - 최대 건수
+    pub async fn list(
+        pool: &PgPool,
+        status: Option<ConditionalOrderStatus>,
+        limit: i64,
+    ) -> Result<Vec<ConditionalOrderRecord>, sqlx::Error> {
+        sqlx::query_as::<_, ConditionalOrderRecord>(&format!(
+            r#"
+            SELECT {CONDITIONAL_ORDER_COLUMNS}
+            FROM conditional_order
+            WHERE ($1::text IS NULL OR status = $1)
+            ORDER BY created_at DESC
+            LIMIT $2
+            "#
+        ))
+        .bind(status.map(|s| s.as_str()))
+        .bind(limit)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// 평가 대상(active) 조건부 주문 조회 (오래된 순).
+    pub async fn list_active(pool: &PgPool) -> Result<Vec<ConditionalOrderRecord>, sqlx::Error> {
+        sqlx::query_as::<_, ConditionalOrderRecord>(&format!(
+            r#"
+            SELECT {CONDITIONAL_ORDER_COLUMNS}
+            FROM conditional_order
+            WHERE status = 'active' AND (expires_at IS NULL OR expires_at > NOW())
+            ORDER BY created_at
+            "#
+        ))
+        .fetch_all(pool)
+        .await
+    }
+
+    /// 단일 조건부 주문 조회.
+    pub async fn get(
+        pool: &PgPool,
+        id: Uuid,
+    ) -> Result<Option<ConditionalOrderRecord>, sqlx::Error> {
+        sqlx::query_as::<_, ConditionalOrderRecord>(&format!(
+            "SELECT {CONDITIONAL_ORDER_COLUMNS} FROM conditional_order WHERE id = $1"
+        ))
+        .bind(id)
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// 조건부 주문 생성.
+    pub async fn create(
+        pool: &PgPool,
+        input: &ConditionalOrderInput,
+    ) -> Result<ConditionalOrderRecord, sqlx::Error> {
+        sqlx::query_as::<_, ConditionalOrderRecord>(&format!(
+            r#"
+            INSERT INTO conditional_order
+                (ticker, market, side, order_type, quantity, limit_price, strategy_id,
+                 conditions, timeframe, expires_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            RETURNING {CONDITIONAL_ORDER_COLUMNS}
+            "#
+        ))
+        .bind(&input.ticker)
+        .bind(&input.market)
+        .bind(&input.side)
+        .bind(&input.order_type)
+        .bind(input.quantity)
+        .bind(input.limit_price)
+        .bind(&input.strategy_id)
+        .bind(&input.conditions)
+        .bind(&input.timeframe)
+        .bind(input.expires_at)
+        .fetch_one(pool)
+        .await
+    }
+
+    /// 조건부 주문 수정 (active 상태만).
+    ///
+    /// # Returns
+    /// 수정된 레코드 (없거나 active가 아니면 None)
+    pub async fn update(
+        pool: &PgPool,
+        id: Uuid,
+        input: &ConditionalOrderInput,
+    ) -> Result<Option<ConditionalOrderRecord>, sqlx::Error> {
+        sqlx::query_as::<_, ConditionalOrderRecord>(&format!(
+            r#"
+            UPDATE conditional_order
+            SET ticker = $2, market = $3, side = $4, order_type = $5, quantity = $6,
+                limit_price = $7, strategy_id = $8, conditions = $9, timeframe = $10,
+                expires_at = $11, last_error = NULL
+            WHERE id = $1 AND status = 'active'
+            RETURNING {CONDITIONAL_ORDER_COLUMNS}
+            "#
+        ))
+        .bind(id)
+        .bind(&input.ticker)
+        .bind(&input.market)
+        .bind(&input.side)
+        .bind(&input.order_type)
+        .bind(input.quantity)
+        .bind(input.limit_price)
+        .bind(&input.strategy_id)
+        .bind(&input.conditions)
+        .bind(&input.timeframe)
+        .bind(input.expires_at)
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// 조건부 주문 취소 (active 상태만).
+    ///
+    /// # Returns
+    /// 취소된 레코드 (없거나 active가 아니면 None)
+    pub async fn cancel(
+        pool: &PgPool,
+        id: Uuid,
+    ) -> Result<Option<ConditionalOrderRecord>, sqlx::Error> {
+        sqlx::query_as::<_, ConditionalOrderRecord>(&format!(
+            r#"
+            UPDATE conditional_order
+            SET status = 'cancelled'
+            WHERE id = $1 AND status = 'active'
+            RETURNING {CONDITIONAL_ORDER_COLUMNS}
+            "#
+        ))
+        .bind(id)
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// 만료 시각이 지난 active 주문을 expired로 전환.
+    ///
+    /// # Returns
+    /// 만료 처리된 주문 ID 목록
+    pub async fn expire_due(pool: &PgPool) -> Result<Vec<Uuid>, sqlx::Error> {
+        sqlx::query_scalar::<_, Uuid>(
+            r#"
+            UPDATE conditional_order
+            SET status = 'expired'
+            WHERE status = 'active' AND expires_at IS NOT NULL AND expires_at <= NOW()
+            RETURNING id
+            "#,
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// 평가 결과 기록 (조건 미충족 또는 평가 오류).
+    pub async fn record_evaluation(
+        pool: &PgPool,
+        id: Uuid,
+        error: Option<&str>,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            UPDATE conditional_order
+            SET last_evaluated_at = NOW(), last_error = $2
+            WHERE id = $1 AND status = 'active'
+            "#,
+        )
+        .bind(id)
+        .bind(error)
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    /// 조건 충족 주문 선점 (active → triggered).
+    ///
+    /// 동시에 취소되거나 중복 실행되지 않도록 주문 실행 전에 호출합니다.
+    ///
+    /// # Returns
+    /// 선점 성공 여부 (이미 active가 아니면 false)
+    pub async fn claim(pool: &PgPool, id: Uuid) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query(
+            r#"
+            UPDATE conditional_order
+            SET status = 'triggered', triggered_at = NOW(), last_evaluated_at = NOW(),
+                last_error = NULL
+            WHERE id = $1 AND status = 'active'
+            "#,
+        )
+        .bind(id)
+        .execute(pool)
+        .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// 주문 실행 결과 기록.
+    ///
+    /// 실행 성공 시 내부 주문 ID를 저장하고, 실패 시 failed로 전환합니다.
+    pub async fn record_execution(
+        pool: &PgPool,
+        id: Uuid,
+        order_id: Option<Uuid>,
+        error: Option<&str>,
+    ) -> Result<(), sqlx::Error> {
+        let status = if error.is_some() {
+            ConditionalOrderStatus::Failed
+        } else {
+            ConditionalOrderStatus::Triggered
+        };
+
+        sqlx::query(
+            r#"
+            UPDATE conditional_order
+            SET status = $2, order_id = $3, last_error = $4
+            WHERE id = $1
+            "#,
+        )
+        .bind(id)
+        .bind(status.as_str())
+        .bind(order_id)
+        .bind(error)
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+}
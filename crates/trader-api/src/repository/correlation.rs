@@ -0,0 +1,265 @@
+//! 시장 간 상관관계 모니터링 Repository.
+//!
+//! 모니터링 대상 자산(`correlation_monitor_asset`), 자산 쌍별 롤링 상관계수 이력
+//! (`correlation_snapshot`), 레짐 변화 알림(`correlation_alert`)을 관리합니다.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+/// 모니터링 대상 자산 레코드.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct CorrelationAssetRecord {
+    /// 티커 (symbol_info.ticker)
+    pub ticker: String,
+    /// 표시 이름
+    pub label: String,
+    /// 활성화 여부
+    pub enabled: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+/// 자산 쌍별 롤링 상관계수 스냅샷.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct CorrelationSnapshotRecord {
+    /// 마지막 공통 관측일
+    pub as_of_date: NaiveDate,
+    /// 쌍의 첫 번째 티커 (사전순)
+    pub symbol_a: String,
+    pub symbol_b: String,
+    pub short_window: i32,
+    pub long_window: i32,
+    /// 단기 창 상관계수
+    pub short_corr: f64,
+    /// 장기 창 상관계수
+    pub long_corr: f64,
+    /// 공통 수익률 관측 수
+    pub observations: i32,
+    pub computed_at: DateTime<Utc>,
+}
+
+/// 스냅샷 저장 입력.
+#[derive(Debug, Clone)]
+pub struct CorrelationSnapshotInput {
+    pub as_of_date: NaiveDate,
+    pub symbol_a: String,
+    pub symbol_b: String,
+    pub short_window: i32,
+    pub long_window: i32,
+    pub short_corr: f64,
+    pub long_corr: f64,
+    pub observations: i32,
+}
+
+/// 상관관계 레짐 변화 알림 레코드.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct CorrelationAlertRecord {
+    pub id: Uuid,
+    pub as_of_date: NaiveDate,
+    pub symbol_a: String,
+    pub symbol_b: String,
+    /// 변화 유형 (breakdown, spike)
+    pub kind: String,
+    pub short_corr: f64,
+    pub long_corr: f64,
+    /// 변화량 (단기 - 장기)
+    pub change: f64,
+    pub detected_at: DateTime<Utc>,
+}
+
+/// 상관관계 모니터링 Repository.
+pub struct CorrelationRepository;
+
+impl CorrelationRepository {
+    /// 모니터링 대상 자산 목록.
+    pub async fn list_assets(pool: &PgPool) -> Result<Vec<CorrelationAssetRecord>, sqlx::Error> {
+        sqlx::query_as::<_, CorrelationAssetRecord>(
+            r#"
+            SELECT ticker, label, enabled, created_at
+            FROM correlation_monitor_asset
+            ORDER BY created_at, ticker
+            "#,
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// 모니터링 대상 자산 추가/수정.
+    pub async fn upsert_asset(
+        pool: &PgPool,
+        ticker: &str,
+        label: &str,
+        enabled: bool,
+    ) -> Result<CorrelationAssetRecord, sqlx::Error> {
+        sqlx::query_as::<_, CorrelationAssetRecord>(
+            r#"
+            INSERT INTO correlation_monitor_asset (ticker, label, enabled)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (ticker) DO UPDATE SET
+                label = EXCLUDED.label,
+                enabled = EXCLUDED.enabled
+            RETURNING ticker, label, enabled, created_at
+            "#,
+        )
+        .bind(ticker)
+        .bind(label)
+        .bind(enabled)
+        .fetch_one(pool)
+        .await
+    }
+
+    /// 모니터링 대상 자산 삭제. 삭제되었으면 true.
+    pub async fn delete_asset(pool: &PgPool, ticker: &str) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query("DELETE FROM correlation_monitor_asset WHERE ticker = $1")
+            .bind(ticker)
+            .execute(pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// 보유 중인 종목 티커 목록 (열린 포지션 기준, 중복 제거).
+    pub async fn holding_tickers(pool: &PgPool) -> Result<Vec<String>, sqlx::Error> {
+        sqlx::query_scalar::<_, String>(
+            r#"
+            SELECT DISTINCT symbol
+            FROM positions
+            WHERE closed_at IS NULL AND quantity > 0 AND symbol IS NOT NULL
+            ORDER BY symbol
+            "#,
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// 스냅샷 저장 (같은 쌍/관측일은 덮어씀).
+    pub async fn upsert_snapshot(
+        pool: &PgPool,
+        input: &CorrelationSnapshotInput,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            INSERT INTO correlation_snapshot (
+                as_of_date, symbol_a, symbol_b, short_window, long_window,
+                short_corr, long_corr, observations
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            ON CONFLICT (symbol_a, symbol_b, as_of_date) DO UPDATE SET
+                short_window = EXCLUDED.short_window,
+                long_window = EXCLUDED.long_window,
+                short_corr = EXCLUDED.short_corr,
+                long_corr = EXCLUDED.long_corr,
+                observations = EXCLUDED.observations,
+                computed_at = NOW()
+            "#,
+        )
+        .bind(input.as_of_date)
+        .bind(&input.symbol_a)
+        .bind(&input.symbol_b)
+        .bind(input.short_window)
+        .bind(input.long_window)
+        .bind(input.short_corr)
+        .bind(input.long_corr)
+        .bind(input.observations)
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    /// 쌍별 최신 스냅샷 목록.
+    pub async fn latest_snapshots(
+        pool: &PgPool,
+    ) -> Result<Vec<CorrelationSnapshotRecord>, sqlx::Error> {
+        sqlx::query_as::<_, CorrelationSnapshotRecord>(
+            r#"
+            SELECT DISTINCT ON (symbol_a, symbol_b)
+                as_of_date, symbol_a, symbol_b, short_window, long_window,
+                short_corr, long_corr, observations, computed_at
+            FROM correlation_snapshot
+            ORDER BY symbol_a, symbol_b, as_of_date DESC
+            "#,
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// 쌍의 스냅샷 이력 (관측일 오름차순).
+    ///
+    /// `symbol_a`, `symbol_b`는 사전순으로 정렬된 상태여야 합니다.
+    pub async fn history(
+        pool: &PgPool,
+        symbol_a: &str,
+        symbol_b: &str,
+        since: NaiveDate,
+    ) -> Result<Vec<CorrelationSnapshotRecord>, sqlx::Error> {
+        sqlx::query_as::<_, CorrelationSnapshotRecord>(
+            r#"
+            SELECT as_of_date, symbol_a, symbol_b, short_window, long_window,
+                   short_corr, long_corr, observations, computed_at
+            FROM correlation_snapshot
+            WHERE symbol_a = $1 AND symbol_b = $2 AND as_of_date >= $3
+            ORDER BY as_of_date
+            "#,
+        )
+        .bind(symbol_a)
+        .bind(symbol_b)
+        .bind(since)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// 레짐 변화 알림 저장.
+    ///
+    /// 같은 쌍/유형/관측일의 알림이 이미 있으면 None을 반환합니다 (중복 알림 방지).
+    pub async fn insert_alert(
+        pool: &PgPool,
+        as_of_date: NaiveDate,
+        symbol_a: &str,
+        symbol_b: &str,
+        kind: &str,
+        short_corr: f64,
+        long_corr: f64,
+    ) -> Result<Option<CorrelationAlertRecord>, sqlx::Error> {
+        sqlx::query_as::<_, CorrelationAlertRecord>(
+            r#"
+            INSERT INTO correlation_alert (
+                as_of_date, symbol_a, symbol_b, kind, short_corr, long_corr, change
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $5 - $6)
+            ON CONFLICT (symbol_a, symbol_b, kind, as_of_date) DO NOTHING
+            RETURNING id, as_of_date, symbol_a, symbol_b, kind,
+                      short_corr, long_corr, change, detected_at
+            "#,
+        )
+        .bind(as_of_date)
+        .bind(symbol_a)
+        .bind(symbol_b)
+        .bind(kind)
+        .bind(short_corr)
+        .bind(long_corr)
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// 최근 알림 목록 (최신순, 티커 필터 선택).
+    pub async fn list_alerts(
+        pool: &PgPool,
+        ticker: Option<&str>,
+        limit: i64,
+    ) -> Result<Vec<CorrelationAlertRecord>, sqlx::Error> {
+        sqlx::query_as::<_, CorrelationAlertRecord>(
+            r#"
+            SELECT id, as_of_date, symbol_a, symbol_b, kind,
+                   short_corr, long_corr, change, detected_at
+            FROM correlation_alert
+            WHERE $1::text IS NULL OR symbol_a = $1 OR symbol_b = $1
+            ORDER BY detected_at DESC
+            LIMIT $2
+            "#,
+        )
+        .bind(ticker)
+        .bind(limit)
+        .fetch_all(pool)
+        .await
+    }
+}
@@ -0,0 +1,372 @@
+//! 정액 적립식(DCA) Repository.
+//!
+//! 적립 계획(`dca_plan`)과 회차별 실행 이력(`dca_execution`)을 관리합니다.
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, PgPool};
+use trader_execution::{DcaAllocation, DcaCadence, DcaMode};
+use uuid::Uuid;
+
+/// 적립 계획 레코드.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct DcaPlanRecord {
+    pub id: Uuid,
+    /// 계획 이름
+    pub name: String,
+    /// 회차 총 투자 금액
+    pub amount: Decimal,
+    /// 통화 (KRW, USD)
+    pub currency: String,
+    /// 종목 비중 (JSON)
+    pub allocations: serde_json::Value,
+    /// 주기 (daily, weekly, biweekly, monthly)
+    pub cadence: String,
+    /// 금액 결정 방식 (JSON)
+    pub mode: serde_json::Value,
+    /// 기본 금액 대비 최대 배수
+    pub max_multiplier: Decimal,
+    /// 전략 ID (전략 예산/비용 모델 적용)
+    pub strategy_id: Option<String>,
+    /// 활성화 여부
+    pub enabled: bool,
+    /// 다음 실행 시각
+    pub next_run_at: DateTime<Utc>,
+    /// 마지막 실행 시각
+    pub last_run_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl DcaPlanRecord {
+    /// 종목 비중 파싱.
+    pub fn parsed_allocations(&self) -> Result<Vec<DcaAllocation>, serde_json::Error> {
+        serde_json::from_value(self.allocations.clone())
+    }
+
+    /// 금액 결정 방식 파싱.
+    pub fn parsed_mode(&self) -> Result<DcaMode, serde_json::Error> {
+        serde_json::from_value(self.mode.clone())
+    }
+
+    /// 주기 파싱.
+    pub fn parsed_cadence(&self) -> Result<DcaCadence, String> {
+        self.cadence.parse()
+    }
+}
+
+/// 적립 계획 생성/수정 입력.
+#[derive(Debug, Clone)]
+pub struct DcaPlanInput {
+    pub name: String,
+    pub amount: Decimal,
+    pub currency: String,
+    pub allocations: serde_json::Value,
+    pub cadence: String,
+    pub mode: serde_json::Value,
+    pub max_multiplier: Decimal,
+    pub strategy_id: Option<String>,
+    pub enabled: bool,
+    pub next_run_at: DateTime<Utc>,
+}
+
+/// 회차별 종목 매수 레코드.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct DcaExecutionRecord {
+    pub id: Uuid,
+    pub plan_id: Uuid,
+    pub ticker: String,
+    /// 기준 가격
+    pub price: Decimal,
+    /// 비중 배분 금액
+    pub base_amount: Decimal,
+    /// 실제 투자 금액
+    pub amount: Decimal,
+    /// 기본 금액 대비 배수
+    pub multiplier: Decimal,
+    /// 주문 수량
+    pub quantity: Decimal,
+    /// 금액 결정 사유
+    pub reason: Option<String>,
+    /// 주문 접수 성공 여부
+    pub success: bool,
+    /// 실패 사유
+    pub error: Option<String>,
+    /// 내부 주문 ID
+    pub order_id: Option<Uuid>,
+    pub executed_at: DateTime<Utc>,
+}
+
+/// 회차별 종목 매수 입력.
+#[derive(Debug, Clone)]
+pub struct DcaExecutionInput {
+    pub plan_id: Uuid,
+    pub ticker: String,
+    pub price: Decimal,
+    pub base_amount: Decimal,
+    pub amount: Decimal,
+    pub multiplier: Decimal,
+    pub quantity: Decimal,
+    pub reason: Option<String>,
+    pub success: bool,
+    pub error: Option<String>,
+    pub order_id: Option<Uuid>,
+}
+
+/// 계획/종목별 적립 현황 (매매일지 리포트).
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct DcaSymbolReport {
+    pub plan_id: Uuid,
+    pub plan_name: String,
+    pub currency: String,
+    pub ticker: String,
+    /// 성공한 회차 수
+    pub executions: i64,
+    /// 실패한 회차 수
+    pub failures: i64,
+    /// 누적 투자 금액
+    pub total_invested: Decimal,
+    /// 누적 매수 수량
+    pub total_quantity: Decimal,
+    /// 평균 매수가
+    pub average_price: Option<Decimal>,
+    /// 최근 기준 가격
+    pub last_price: Option<Decimal>,
+    pub first_executed_at: Option<DateTime<Utc>>,
+    pub last_executed_at: Option<DateTime<Utc>>,
+}
+
+const PLAN_COLUMNS: &str = "id, name, amount, currency, allocations, cadence, mode, \
+     max_multiplier, strategy_id, enabled, next_run_at, last_run_at, created_at, updated_at";
+
+const EXECUTION_COLUMNS: &str = "id, plan_id, ticker, price, base_amount, amount, multiplier, \
+     quantity, reason, success, error, order_id, executed_at";
+
+/// 정액 적립식 Repository.
+pub struct DcaRepository;
+
+impl DcaRepository {
+    /// 전체 계획 조회 (이름순).
+    pub async fn list(pool: &PgPool) -> Result<Vec<DcaPlanRecord>, sqlx::Error> {
+        sqlx::query_as::<_, DcaPlanRecord>(&format!(
+            "SELECT {PLAN_COLUMNS} FROM dca_plan ORDER BY name"
+        ))
+        .fetch_all(pool)
+        .await
+    }
+
+    /// 단일 계획 조회.
+    pub async fn get(pool: &PgPool, id: Uuid) -> Result<Option<DcaPlanRecord>, sqlx::Error> {
+        sqlx::query_as::<_, DcaPlanRecord>(&format!(
+            "SELECT {PLAN_COLUMNS} FROM dca_plan WHERE id = $1"
+        ))
+        .bind(id)
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// 계획 생성.
+    pub async fn create(pool: &PgPool, input: &DcaPlanInput) -> Result<DcaPlanRecord, sqlx::Error> {
+        sqlx::query_as::<_, DcaPlanRecord>(&format!(
+            r#"
+            INSERT INTO dca_plan
+                (name, amount, currency, allocations, cadence, mode, max_multiplier,
+                 strategy_id, enabled, next_run_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            RETURNING {PLAN_COLUMNS}
+            "#
+        ))
+        .bind(&input.name)
+        .bind(input.amount)
+        .bind(&input.currency)
+        .bind(&input.allocations)
+        .bind(&input.cadence)
+        .bind(&input.mode)
+        .bind(input.max_multiplier)
+        .bind(&input.strategy_id)
+        .bind(input.enabled)
+        .bind(input.next_run_at)
+        .fetch_one(pool)
+        .await
+    }
+
+    /// 계획 수정.
+    pub async fn update(
+        pool: &PgPool,
+        id: Uuid,
+        input: &DcaPlanInput,
+    ) -> Result<Option<DcaPlanRecord>, sqlx::Error> {
+        sqlx::query_as::<_, DcaPlanRecord>(&format!(
+            r#"
+            UPDATE dca_plan
+            SET name = $2, amount = $3, currency = $4, allocations = $5, cadence = $6,
+                mode = $7, max_multiplier = $8, strategy_id = $9, enabled = $10,
+                next_run_at = $11
+            WHERE id = $1
+            RETURNING {PLAN_COLUMNS}
+            "#
+        ))
+        .bind(id)
+        .bind(&input.name)
+        .bind(input.amount)
+        .bind(&input.currency)
+        .bind(&input.allocations)
+        .bind(&input.cadence)
+        .bind(&input.mode)
+        .bind(input.max_multiplier)
+        .bind(&input.strategy_id)
+        .bind(input.enabled)
+        .bind(input.next_run_at)
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// 계획 삭제 (실행 이력 포함).
+    ///
+    /// # Returns
+    /// 삭제 여부
+    pub async fn delete(pool: &PgPool, id: Uuid) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query("DELETE FROM dca_plan WHERE id = $1")
+            .bind(id)
+            .execute(pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// 실행 시각이 도래한 활성 계획 조회.
+    pub async fn list_due(pool: &PgPool, limit: i64) -> Result<Vec<DcaPlanRecord>, sqlx::Error> {
+        sqlx::query_as::<_, DcaPlanRecord>(&format!(
+            r#"
+            SELECT {PLAN_COLUMNS}
+            FROM dca_plan
+            WHERE enabled AND next_run_at <= NOW()
+            ORDER BY next_run_at
+            LIMIT $1
+            "#
+        ))
+        .bind(limit)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// 실행 완료 기록 및 다음 실행 시각 설정.
+    pub async fn advance(
+        pool: &PgPool,
+        id: Uuid,
+        next_run_at: DateTime<Utc>,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query("UPDATE dca_plan SET last_run_at = NOW(), next_run_at = $2 WHERE id = $1")
+            .bind(id)
+            .bind(next_run_at)
+            .execute(pool)
+            .await?;
+        Ok(())
+    }
+
+    /// 회차별 종목 매수 기록.
+    pub async fn insert_execution(
+        pool: &PgPool,
+        input: &DcaExecutionInput,
+    ) -> Result<DcaExecutionRecord, sqlx::Error> {
+        sqlx::query_as::<_, DcaExecutionRecord>(&format!(
+            r#"
+            INSERT INTO dca_execution
+                (plan_id, ticker, price, base_amount, amount, multiplier, quantity, reason,
+                 success, error, order_id)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+            RETURNING {EXECUTION_COLUMNS}
+            "#
+        ))
+        .bind(input.plan_id)
+        .bind(&input.ticker)
+        .bind(input.price)
+        .bind(input.base_amount)
+        .bind(input.amount)
+        .bind(input.multiplier)
+        .bind(input.quantity)
+        .bind(&input.reason)
+        .bind(input.success)
+        .bind(&input.error)
+        .bind(input.order_id)
+        .fetch_one(pool)
+        .await
+    }
+
+    /// 계획의 실행 이력 조회 (최신순).
+    pub async fn list_executions(
+        pool: &PgPool,
+        plan_id: Uuid,
+        limit: i64,
+    ) -> Result<Vec<DcaExecutionRecord>, sqlx::Error> {
+        sqlx::query_as::<_, DcaExecutionRecord>(&format!(
+            r#"
+            SELECT {EXECUTION_COLUMNS}
+            FROM dca_execution
+            WHERE plan_id = $1
+            ORDER BY executed_at DESC
+            LIMIT $2
+            "#
+        ))
+        .bind(plan_id)
+        .bind(limit)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// 계획/종목의 성공 회차 수와 누적 매수 수량 (가치 평균법용).
+    pub async fn accumulation(
+        pool: &PgPool,
+        plan_id: Uuid,
+        ticker: &str,
+    ) -> Result<(i64, Decimal), sqlx::Error> {
+        sqlx::query_as::<_, (i64, Decimal)>(
+            r#"
+            SELECT COUNT(*), COALESCE(SUM(quantity), 0)
+            FROM dca_execution
+            WHERE plan_id = $1 AND ticker = $2 AND success
+            "#,
+        )
+        .bind(plan_id)
+        .bind(ticker)
+        .fetch_one(pool)
+        .await
+    }
+
+    /// 계획/종목별 적립 현황 리포트.
+    ///
+    /// # Arguments
+    /// * `plan_id` - 계획 필터 (None이면 전체)
+    pub async fn report(
+        pool: &PgPool,
+        plan_id: Option<Uuid>,
+    ) -> Result<Vec<DcaSymbolReport>, sqlx::Error> {
+        sqlx::query_as::<_, DcaSymbolReport>(
+            r#"
+            SELECT
+                p.id AS plan_id,
+                p.name AS plan_name,
+                p.currency,
+                e.ticker,
+                COUNT(*) FILTER (WHERE e.success) AS executions,
+                COUNT(*) FILTER (WHERE NOT e.success) AS failures,
+                COALESCE(SUM(e.quantity * e.price) FILTER (WHERE e.success), 0) AS total_invested,
+                COALESCE(SUM(e.quantity) FILTER (WHERE e.success), 0) AS total_quantity,
+                SUM(e.quantity * e.price) FILTER (WHERE e.success)
+                    / NULLIF(SUM(e.quantity) FILTER (WHERE e.success), 0) AS average_price,
+                (ARRAY_AGG(e.price ORDER BY e.executed_at DESC) FILTER (WHERE e.price > 0))[1]
+                    AS last_price,
+                MIN(e.executed_at) FILTER (WHERE e.success) AS first_executed_at,
+                MAX(e.executed_at) FILTER (WHERE e.success) AS last_executed_at
+            FROM dca_execution e
+            JOIN dca_plan p ON p.id = e.plan_id
+            WHERE ($1::uuid IS NULL OR e.plan_id = $1)
+            GROUP BY p.id, p.name, p.currency, e.ticker
+            ORDER BY p.name, e.ticker
+            "#,
+        )
+        .bind(plan_id)
+        .fetch_all(pool)
+        .await
+    }
+}
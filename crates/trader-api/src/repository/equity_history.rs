@@ -2,15 +2,25 @@
 //!
 //! 자산 곡선(Equity Curve) 데이터의 저장 및 조회를 담당합니다.
 //! PostgreSQL의 window functions를 활용한 효율적인 분석 쿼리를 제공합니다.
+//! 계좌 단위(`portfolio_equity_history`)와 전략 가상 계좌 단위(`strategy_equity_history`)
+//! 자산 곡선을 함께 관리합니다.
 
-use chrono::{DateTime, Duration, NaiveDate, TimeZone, Timelike, Utc};
+use chrono::{DateTime, Duration, NaiveDate, NaiveTime, TimeZone, Timelike, Utc};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
-use sqlx::{PgPool, Row};
+use sqlx::{FromRow, PgExecutor, PgPool, Row};
 use std::collections::{BTreeMap, HashMap};
 use tracing::{info, warn};
+use trader_execution::StrategyPortfolioSnapshot;
 use uuid::Uuid;
 
+use super::execution_cache::UpsertOutcome;
+
+/// 동기화로 생성되는 일별 스냅샷의 기준 시각 (UTC 시).
+///
+/// 실시간 스냅샷(분 단위)과 구분하기 위해 동기화 스냅샷은 항상 이 시각에 기록합니다.
+const SYNC_SNAPSHOT_HOUR: u32 = 12;
+
 // ==================== 타입 정의 ====================
 
 /// 포트폴리오 스냅샷 데이터.
@@ -28,6 +38,22 @@ pub struct PortfolioSnapshot {
     pub account_type: Option<String>,
 }
 
+/// 전략 가상 계좌 자산 곡선 레코드.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct StrategyEquityRecord {
+    pub strategy_id: String,
+    pub snapshot_time: DateTime<Utc>,
+    /// 할당 자본
+    pub capital: Decimal,
+    pub equity: Decimal,
+    pub realized_pnl: Decimal,
+    pub unrealized_pnl: Decimal,
+    pub fees: Decimal,
+    pub exposure: Decimal,
+    pub drawdown_pct: f64,
+    pub open_positions: i32,
+}
+
 /// 자산 곡선 데이터 포인트.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EquityPoint {
@@ -61,12 +87,26 @@ pub struct ExecutionForSync {
 }
 
 /// 동기화 결과.
+///
+/// 증분 동기화에서 무엇이 바뀌었는지 함께 보고합니다.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SyncResult {
-    /// 동기화된 스냅샷 개수
+    /// 저장(삽입/갱신)된 스냅샷 개수
     pub synced_count: usize,
+    /// 재계산 범위에서 제거된 기존 스냅샷 개수
+    pub removed_count: usize,
     /// 처리된 체결 내역 개수
     pub execution_count: usize,
+    /// 이번 동기화에서 새로 캐시된 체결 수
+    pub new_execution_count: usize,
+    /// 이번 동기화에서 값이 바뀐 기존 체결 수
+    pub updated_execution_count: usize,
+    /// 체크포인트 이전 일자의 체결이 새로 들어왔는지 여부 (소급 체결)
+    pub backdated: bool,
+    /// 전체 재계산 여부 (체크포인트 없음, 계산 방식 변경 등)
+    pub full_recompute: bool,
+    /// 재계산 시작 일자 (이 일자 이후 스냅샷만 다시 기록)
+    pub recompute_from: Option<NaiveDate>,
     /// 시작 날짜
     pub start_date: String,
     /// 종료 날짜
@@ -75,6 +115,109 @@ pub struct SyncResult {
     pub synced_at: DateTime<Utc>,
 }
 
+impl SyncResult {
+    /// 변경 사항이 없는 결과 생성.
+    fn unchanged(execution_count: usize, changes: &UpsertOutcome) -> Self {
+        Self {
+            synced_count: 0,
+            removed_count: 0,
+            execution_count,
+            new_execution_count: changes.inserted,
+            updated_execution_count: changes.updated,
+            backdated: false,
+            full_recompute: false,
+            recompute_from: None,
+            start_date: String::new(),
+            end_date: String::new(),
+            synced_at: Utc::now(),
+        }
+    }
+}
+
+/// 자산 곡선 계산 방식.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EquitySyncMode {
+    /// 체결 기록 + 일별 종가 기반
+    MarketPrices,
+    /// 현재 자산에서 현금 흐름 역산
+    CashFlow,
+}
+
+impl EquitySyncMode {
+    /// DB 저장용 문자열.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::MarketPrices => "market_prices",
+            Self::CashFlow => "cash_flow",
+        }
+    }
+}
+
+/// 계좌별 자산 곡선 동기화 체크포인트.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct EquitySyncCheckpoint {
+    pub credential_id: Uuid,
+    pub sync_mode: String,
+    /// 자산 곡선이 확정된 마지막 일자
+    pub synced_through: NaiveDate,
+    pub execution_count: i32,
+    pub last_execution_at: Option<DateTime<Utc>>,
+    pub last_recompute_from: Option<NaiveDate>,
+    pub last_sync_status: String,
+    pub last_sync_message: Option<String>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// 재계산 범위 결정 결과.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RecomputePlan {
+    /// 재계산 시작 일자
+    pub from: NaiveDate,
+    /// 전체 재계산 여부
+    pub full: bool,
+    /// 소급 체결로 인한 재계산 여부
+    pub backdated: bool,
+}
+
+impl RecomputePlan {
+    /// 체크포인트와 변경된 체결 일자로 재계산 범위를 결정합니다.
+    ///
+    /// - 체크포인트가 없거나 계산 방식이 바뀌었으면 첫 체결일부터 전체 재계산
+    /// - 직전 동기화가 실패했으면 그 사이 캐시된 체결의 일자를 알 수 없으므로 전체 재계산
+    /// - 체크포인트 이전 일자의 체결이 바뀌었으면 해당 일자부터 재계산 (소급 체결)
+    /// - 그 외에는 체크포인트 일자부터 재계산 (당일 값 갱신)
+    pub fn determine(
+        checkpoint: Option<&EquitySyncCheckpoint>,
+        mode: EquitySyncMode,
+        earliest_changed: Option<NaiveDate>,
+        first_execution_date: NaiveDate,
+    ) -> Self {
+        let Some(cp) = checkpoint
+            .filter(|cp| cp.sync_mode == mode.as_str() && cp.last_sync_status == "success")
+        else {
+            return Self {
+                from: first_execution_date,
+                full: true,
+                backdated: false,
+            };
+        };
+
+        match earliest_changed {
+            Some(changed) if changed < cp.synced_through => Self {
+                from: changed.max(first_execution_date),
+                full: false,
+                backdated: true,
+            },
+            _ => Self {
+                from: cp.synced_through.max(first_execution_date),
+                full: false,
+                backdated: false,
+            },
+        }
+    }
+}
+
 // ==================== Repository ====================
 
 /// 포트폴리오 자산 히스토리 Repository.
@@ -136,8 +279,8 @@ impl EquityHistoryRepository {
     ///
     /// UNNEST 패턴을 사용하여 한 번의 쿼리로 모든 스냅샷을 저장합니다.
     /// N+1 쿼리 문제를 해결합니다.
-    pub async fn save_snapshots_batch(
-        pool: &PgPool,
+    pub async fn save_snapshots_batch<'e, E: PgExecutor<'e>>(
+        executor: E,
         snapshots: &[PortfolioSnapshot],
     ) -> Result<usize, sqlx::Error> {
         if snapshots.is_empty() {
@@ -196,7 +339,7 @@ impl EquityHistoryRepository {
         .bind(&currencies)
         .bind(&markets)
         .bind(&account_types)
-        .execute(pool)
+        .execute(executor)
         .await?;
 
         Ok(result.rows_affected() as usize)
@@ -592,12 +735,136 @@ impl EquityHistoryRepository {
         Ok(result.rows_affected())
     }
 
+    // ==================== 증분 동기화 체크포인트 ====================
+
+    /// 계좌의 자산 곡선 동기화 체크포인트 조회.
+    pub async fn get_sync_checkpoint(
+        pool: &PgPool,
+        credential_id: Uuid,
+    ) -> Result<Option<EquitySyncCheckpoint>, sqlx::Error> {
+        sqlx::query_as::<_, EquitySyncCheckpoint>(
+            r#"
+            SELECT
+                credential_id, sync_mode, synced_through,
+                execution_count, last_execution_at, last_recompute_from,
+                last_sync_status, last_sync_message, updated_at
+            FROM equity_sync_checkpoint
+            WHERE credential_id = $1
+            "#,
+        )
+        .bind(credential_id)
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// 동기화 실패 기록.
+    ///
+    /// 체크포인트 일자는 그대로 두고 상태만 갱신하므로,
+    /// 다음 동기화는 마지막으로 성공한 지점부터 재개됩니다.
+    pub async fn mark_sync_failed(
+        pool: &PgPool,
+        credential_id: Uuid,
+        message: &str,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            UPDATE equity_sync_checkpoint
+            SET last_sync_status = 'failed', last_sync_message = $2, updated_at = NOW()
+            WHERE credential_id = $1
+            "#,
+        )
+        .bind(credential_id)
+        .bind(message)
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// 재계산 범위의 동기화 스냅샷을 교체하고 체크포인트를 갱신합니다.
+    ///
+    /// 삭제 → 삽입 → 체크포인트 갱신을 하나의 트랜잭션으로 수행하므로
+    /// 중간에 실패해도 기존 자산 곡선과 체크포인트가 그대로 유지됩니다.
+    /// 실시간 스냅샷(분 단위)은 건드리지 않고 동기화 기준 시각의 스냅샷만 교체합니다.
+    ///
+    /// 반환값: (저장된 스냅샷 수, 제거된 스냅샷 수)
+    #[allow(clippy::too_many_arguments)]
+    async fn replace_sync_snapshots(
+        pool: &PgPool,
+        credential_id: Uuid,
+        mode: EquitySyncMode,
+        plan: &RecomputePlan,
+        snapshots: &[PortfolioSnapshot],
+        synced_through: NaiveDate,
+        execution_count: usize,
+        last_execution_at: Option<DateTime<Utc>>,
+    ) -> Result<(usize, usize), sqlx::Error> {
+        let mut tx = pool.begin().await?;
+
+        // 전체 재계산이면 기간 제한 없이 모든 동기화 스냅샷 교체
+        let delete_from = (!plan.full).then(|| sync_snapshot_time(plan.from));
+
+        let removed = sqlx::query(
+            r#"
+            DELETE FROM portfolio_equity_history
+            WHERE credential_id = $1
+              AND ($2::timestamptz IS NULL OR snapshot_time >= $2)
+              AND EXTRACT(HOUR FROM snapshot_time AT TIME ZONE 'UTC') = $3
+              AND EXTRACT(MINUTE FROM snapshot_time AT TIME ZONE 'UTC') = 0
+            "#,
+        )
+        .bind(credential_id)
+        .bind(delete_from)
+        .bind(SYNC_SNAPSHOT_HOUR as i32)
+        .execute(&mut *tx)
+        .await?
+        .rows_affected() as usize;
+
+        let written = Self::save_snapshots_batch(&mut *tx, snapshots).await?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO equity_sync_checkpoint (
+                credential_id, sync_mode, synced_through,
+                execution_count, last_execution_at, last_recompute_from,
+                last_sync_status, last_sync_message
+            ) VALUES ($1, $2, $3, $4, $5, $6, 'success', NULL)
+            ON CONFLICT (credential_id)
+            DO UPDATE SET
+                sync_mode = EXCLUDED.sync_mode,
+                synced_through = EXCLUDED.synced_through,
+                execution_count = EXCLUDED.execution_count,
+                last_execution_at = EXCLUDED.last_execution_at,
+                last_recompute_from = EXCLUDED.last_recompute_from,
+                last_sync_status = 'success',
+                last_sync_message = NULL,
+                updated_at = NOW()
+            "#,
+        )
+        .bind(credential_id)
+        .bind(mode.as_str())
+        .bind(synced_through)
+        .bind(execution_count as i32)
+        .bind(last_execution_at)
+        .bind(plan.from)
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok((written, removed))
+    }
+
     // ==================== 거래소 데이터 동기화 ====================
 
     /// 거래소 체결 내역으로 자산 곡선 복원.
     ///
     /// 체결 내역을 기반으로 일별 자산 변동을 계산하여 자산 곡선을 생성합니다.
     /// 현재 자산에서 역순으로 거래 금액을 적용하여 과거 자산을 추정합니다.
+    ///
+    /// 현재 자산을 기준으로 역산하므로 모든 과거 일자가 매번 달라질 수 있어
+    /// 항상 전체 범위를 다시 기록합니다. 소급 체결 여부는 결과에 보고됩니다.
+    #[allow(clippy::too_many_arguments)]
     pub async fn sync_from_executions(
         pool: &PgPool,
         credential_id: Uuid,
@@ -606,10 +873,27 @@ impl EquityHistoryRepository {
         currency: &str,
         market: &str,
         account_type: Option<&str>,
-    ) -> Result<usize, sqlx::Error> {
-        if executions.is_empty() {
-            return Ok(0);
-        }
+        changes: &UpsertOutcome,
+    ) -> Result<SyncResult, sqlx::Error> {
+        let Some(first_date) = executions
+            .iter()
+            .map(|e| e.execution_time.date_naive())
+            .min()
+        else {
+            return Ok(SyncResult::unchanged(0, changes));
+        };
+        let last_execution_at = executions.iter().map(|e| e.execution_time).max();
+
+        // 역산 방식은 항상 전체 재계산 (소급 여부만 판단)
+        let checkpoint = Self::get_sync_checkpoint(pool, credential_id).await?;
+        let mut plan = RecomputePlan::determine(
+            checkpoint.as_ref(),
+            EquitySyncMode::CashFlow,
+            changes.earliest_changed_date,
+            first_date,
+        );
+        plan.from = first_date;
+        plan.full = true;
 
         // 1. 일별 순손익 집계
         let mut daily_pnl: BTreeMap<NaiveDate, Decimal> = BTreeMap::new();
@@ -651,43 +935,64 @@ impl EquityHistoryRepository {
         // 시간순으로 정렬
         daily_equity.sort_by_key(|(d, _)| *d);
 
-        // 3. 스냅샷 배열 생성 및 일괄 저장
+        // 3. 스냅샷 배열 생성 및 트랜잭션으로 교체
         let snapshots: Vec<PortfolioSnapshot> = daily_equity
             .iter()
-            .map(|(date, eq)| {
-                let snapshot_time = Utc.from_utc_datetime(&date.and_hms_opt(12, 0, 0).unwrap());
-
-                PortfolioSnapshot {
-                    credential_id,
-                    snapshot_time,
-                    total_equity: *eq,
-                    cash_balance: *eq,
-                    securities_value: Decimal::ZERO,
-                    total_pnl: *eq - current_equity,
-                    daily_pnl: daily_pnl.get(date).cloned().unwrap_or(Decimal::ZERO),
-                    currency: currency.to_string(),
-                    market: market.to_string(),
-                    account_type: account_type.map(|s| s.to_string()),
-                }
+            .map(|(date, eq)| PortfolioSnapshot {
+                credential_id,
+                snapshot_time: sync_snapshot_time(*date),
+                total_equity: *eq,
+                cash_balance: *eq,
+                securities_value: Decimal::ZERO,
+                total_pnl: *eq - current_equity,
+                daily_pnl: daily_pnl.get(date).cloned().unwrap_or(Decimal::ZERO),
+                currency: currency.to_string(),
+                market: market.to_string(),
+                account_type: account_type.map(|s| s.to_string()),
             })
             .collect();
 
-        let saved_count = match Self::save_snapshots_batch(pool, &snapshots).await {
-            Ok(count) => count,
+        let (saved_count, removed_count) = match Self::replace_sync_snapshots(
+            pool,
+            credential_id,
+            EquitySyncMode::CashFlow,
+            &plan,
+            &snapshots,
+            today,
+            executions.len(),
+            last_execution_at,
+        )
+        .await
+        {
+            Ok(counts) => counts,
             Err(e) => {
-                warn!("스냅샷 배치 저장 실패: {}", e);
-                0
+                warn!("스냅샷 교체 실패 (체크포인트 유지): {}", e);
+                let _ = Self::mark_sync_failed(pool, credential_id, &e.to_string()).await;
+                return Err(e);
             }
         };
 
         info!(
-            "{} 체결 내역에서 {} 일별 자산 포인트 동기화 완료 (credential: {})",
+            "{} 체결 내역에서 {} 일별 자산 포인트 동기화 완료 (credential: {}, 제거: {})",
             executions.len(),
             saved_count,
-            credential_id
+            credential_id,
+            removed_count
         );
 
-        Ok(saved_count)
+        Ok(SyncResult {
+            synced_count: saved_count,
+            removed_count,
+            execution_count: executions.len(),
+            new_execution_count: changes.inserted,
+            updated_execution_count: changes.updated,
+            backdated: plan.backdated,
+            full_recompute: plan.full,
+            recompute_from: Some(plan.from),
+            start_date: first_date.to_string(),
+            end_date: today.to_string(),
+            synced_at: Utc::now(),
+        })
     }
 
     /// 체결 기록과 일별 종가를 기반으로 정확한 자산 곡선 계산.
@@ -700,6 +1005,10 @@ impl EquityHistoryRepository {
     /// - 현금 잔고는 추적하지 않음 (체결 내역만으로 정확한 현금 추정 불가)
     /// - 주식 가치만으로 자산 곡선 계산
     /// - 전량 매도 시 자산 곡선 0으로 표시
+    ///
+    /// # 증분 동기화
+    /// 보유 수량은 항상 전체 체결 기록으로 계산하지만, DB에는 체크포인트
+    /// (또는 소급 체결 일자) 이후의 스냅샷만 다시 기록합니다.
     pub async fn sync_with_market_prices(
         pool: &PgPool,
         credential_id: Uuid,
@@ -707,7 +1016,8 @@ impl EquityHistoryRepository {
         currency: &str,
         market: &str,
         account_type: Option<&str>,
-    ) -> Result<usize, sqlx::Error> {
+        changes: &UpsertOutcome,
+    ) -> Result<SyncResult, sqlx::Error> {
         // 1. 체결 기록 조회 (시간순 정렬)
         let executions = sqlx::query(
             r#"
@@ -723,8 +1033,10 @@ impl EquityHistoryRepository {
 
         if executions.is_empty() {
             info!("체결 기록 없음 (credential: {})", credential_id);
-            return Ok(0);
+            return Ok(SyncResult::unchanged(0, changes));
         }
+        let last_execution_at: Option<DateTime<Utc>> =
+            executions.last().map(|row| row.get("executed_at"));
 
         // 2. 현재 양수 포지션만 추적 (과거 매도 종목 제외)
         // 과거 매도한 종목의 가격 데이터가 없으면 자산 곡선이 왜곡되므로
@@ -752,7 +1064,7 @@ impl EquityHistoryRepository {
 
         if positive_holdings.is_empty() {
             info!("현재 보유 포지션 없음 (credential: {})", credential_id);
-            return Ok(0);
+            return Ok(SyncResult::unchanged(executions.len(), changes));
         }
 
         let all_symbols: Vec<String> = positive_holdings;
@@ -787,7 +1099,7 @@ impl EquityHistoryRepository {
                 "활성 종목 없음 (상장폐지 제외 후, credential: {})",
                 credential_id
             );
-            return Ok(0);
+            return Ok(SyncResult::unchanged(executions.len(), changes));
         }
 
         info!(
@@ -832,9 +1144,17 @@ impl EquityHistoryRepository {
         let start_date = first_active_date.unwrap_or_else(|| Utc::now().date_naive());
         let end_date = Utc::now().date_naive();
 
+        let checkpoint = Self::get_sync_checkpoint(pool, credential_id).await?;
+        let plan = RecomputePlan::determine(
+            checkpoint.as_ref(),
+            EquitySyncMode::MarketPrices,
+            changes.earliest_changed_date,
+            start_date,
+        );
+
         info!(
-            "자산 곡선 계산 기간: {} ~ {} (활성 포지션만)",
-            start_date, end_date
+            "자산 곡선 계산 기간: {} ~ {} (활성 포지션만, 재계산 시작: {}, 전체: {}, 소급: {})",
+            start_date, end_date, plan.from, plan.full, plan.backdated
         );
 
         // 5. 모든 거래 종목의 일별 종가 조회 (배치 쿼리)
@@ -898,9 +1218,9 @@ impl EquityHistoryRepository {
             last_known_prices.insert(symbol.clone(), *price);
         }
 
-        // 7. 일별 자산 계산 및 저장 (주식 가치만)
+        // 7. 일별 자산 계산 (주식 가치만) - 재계산 범위의 스냅샷만 수집
         let mut active_holdings: HashMap<String, Decimal> = HashMap::new();
-        let mut saved_count = 0;
+        let mut snapshots: Vec<PortfolioSnapshot> = Vec::new();
         let mut prev_equity = Decimal::ZERO;
         let mut initial_equity = Decimal::ZERO;
         let mut is_first = true;
@@ -972,46 +1292,168 @@ impl EquityHistoryRepository {
                 }
 
                 let daily_pnl = total_equity - prev_equity;
+                prev_equity = total_equity;
 
-                let snapshot_time =
-                    Utc.from_utc_datetime(&current_date.and_hms_opt(12, 0, 0).unwrap());
-
-                let snapshot = PortfolioSnapshot {
-                    credential_id,
-                    snapshot_time,
-                    total_equity,
-                    cash_balance: Decimal::ZERO, // 주식 가치만 추적
-                    securities_value,
-                    total_pnl: total_equity - initial_equity,
-                    daily_pnl,
-                    currency: currency.to_string(),
-                    market: market.to_string(),
-                    account_type: account_type.map(|s| s.to_string()),
-                };
-
-                match Self::save_snapshot(pool, &snapshot).await {
-                    Ok(_) => {
-                        saved_count += 1;
-                        prev_equity = total_equity;
-                    }
-                    Err(e) => {
-                        warn!("{} 스냅샷 저장 실패: {}", current_date, e);
-                    }
+                if current_date >= plan.from {
+                    snapshots.push(PortfolioSnapshot {
+                        credential_id,
+                        snapshot_time: sync_snapshot_time(current_date),
+                        total_equity,
+                        cash_balance: Decimal::ZERO, // 주식 가치만 추적
+                        securities_value,
+                        total_pnl: total_equity - initial_equity,
+                        daily_pnl,
+                        currency: currency.to_string(),
+                        market: market.to_string(),
+                        account_type: account_type.map(|s| s.to_string()),
+                    });
                 }
             }
 
             current_date = current_date.succ_opt().unwrap_or(current_date);
         }
 
+        // 8. 재계산 범위 교체 + 체크포인트 갱신 (단일 트랜잭션)
+        let (saved_count, removed_count) = match Self::replace_sync_snapshots(
+            pool,
+            credential_id,
+            EquitySyncMode::MarketPrices,
+            &plan,
+            &snapshots,
+            end_date,
+            executions.len(),
+            last_execution_at,
+        )
+        .await
+        {
+            Ok(counts) => counts,
+            Err(e) => {
+                warn!("스냅샷 교체 실패 (체크포인트 유지): {}", e);
+                let _ = Self::mark_sync_failed(pool, credential_id, &e.to_string()).await;
+                return Err(e);
+            }
+        };
+
         info!(
-            "{} 일별 자산 포인트 동기화 완료 (credential: {}, initial_equity={}, symbols={:?})",
-            saved_count, credential_id, initial_equity, active_symbols
+            "{} 일별 자산 포인트 동기화 완료 (credential: {}, initial_equity={}, 제거={}, symbols={:?})",
+            saved_count, credential_id, initial_equity, removed_count, active_symbols
         );
 
-        Ok(saved_count)
+        Ok(SyncResult {
+            synced_count: saved_count,
+            removed_count,
+            execution_count: executions.len(),
+            new_execution_count: changes.inserted,
+            updated_execution_count: changes.updated,
+            backdated: plan.backdated,
+            full_recompute: plan.full,
+            recompute_from: Some(plan.from),
+            start_date: start_date.to_string(),
+            end_date: end_date.to_string(),
+            synced_at: Utc::now(),
+        })
+    }
+
+    // ==================== 전략별 자산 곡선 ====================
+
+    /// 전략 가상 계좌 스냅샷 일괄 저장 (분 단위, 같은 시각이면 갱신).
+    pub async fn save_strategy_snapshots(
+        pool: &PgPool,
+        snapshots: &[StrategyPortfolioSnapshot],
+        snapshot_time: DateTime<Utc>,
+    ) -> Result<usize, sqlx::Error> {
+        if snapshots.is_empty() {
+            return Ok(0);
+        }
+
+        let snapshot_time = snapshot_time
+            .with_nanosecond(0)
+            .unwrap()
+            .with_second(0)
+            .unwrap();
+        let strategy_ids: Vec<String> = snapshots.iter().map(|s| s.strategy_id.clone()).collect();
+        let capitals: Vec<Decimal> = snapshots.iter().map(|s| s.capital).collect();
+        let equities: Vec<Decimal> = snapshots.iter().map(|s| s.equity).collect();
+        let realized: Vec<Decimal> = snapshots.iter().map(|s| s.realized_pnl).collect();
+        let unrealized: Vec<Decimal> = snapshots.iter().map(|s| s.unrealized_pnl).collect();
+        let fees: Vec<Decimal> = snapshots.iter().map(|s| s.fees).collect();
+        let exposures: Vec<Decimal> = snapshots.iter().map(|s| s.exposure).collect();
+        let drawdowns: Vec<f64> = snapshots.iter().map(|s| s.drawdown_pct).collect();
+        let open_positions: Vec<i32> = snapshots.iter().map(|s| s.positions.len() as i32).collect();
+
+        let result = sqlx::query(
+            r#"
+            INSERT INTO strategy_equity_history (
+                strategy_id, snapshot_time, capital, equity, realized_pnl,
+                unrealized_pnl, fees, exposure, drawdown_pct, open_positions
+            )
+            SELECT id, $2, capital, equity, realized_pnl, unrealized_pnl, fees, exposure,
+                   drawdown_pct, open_positions
+            FROM UNNEST(
+                $1::text[], $3::numeric[], $4::numeric[], $5::numeric[], $6::numeric[],
+                $7::numeric[], $8::numeric[], $9::float8[], $10::int4[]
+            ) AS t(id, capital, equity, realized_pnl, unrealized_pnl, fees, exposure,
+                   drawdown_pct, open_positions)
+            ON CONFLICT (strategy_id, snapshot_time)
+            DO UPDATE SET
+                capital = EXCLUDED.capital,
+                equity = EXCLUDED.equity,
+                realized_pnl = EXCLUDED.realized_pnl,
+                unrealized_pnl = EXCLUDED.unrealized_pnl,
+                fees = EXCLUDED.fees,
+                exposure = EXCLUDED.exposure,
+                drawdown_pct = EXCLUDED.drawdown_pct,
+                open_positions = EXCLUDED.open_positions
+            "#,
+        )
+        .bind(&strategy_ids)
+        .bind(snapshot_time)
+        .bind(&capitals)
+        .bind(&equities)
+        .bind(&realized)
+        .bind(&unrealized)
+        .bind(&fees)
+        .bind(&exposures)
+        .bind(&drawdowns)
+        .bind(&open_positions)
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected() as usize)
+    }
+
+    /// 전략 가상 계좌 자산 곡선 조회 (시간순).
+    pub async fn get_strategy_equity_curve(
+        pool: &PgPool,
+        strategy_id: &str,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+    ) -> Result<Vec<StrategyEquityRecord>, sqlx::Error> {
+        sqlx::query_as::<_, StrategyEquityRecord>(
+            r#"
+            SELECT strategy_id, snapshot_time, capital, equity, realized_pnl,
+                   unrealized_pnl, fees, exposure, drawdown_pct, open_positions
+            FROM strategy_equity_history
+            WHERE strategy_id = $1
+              AND snapshot_time >= $2
+              AND snapshot_time <= $3
+            ORDER BY snapshot_time
+            "#,
+        )
+        .bind(strategy_id)
+        .bind(start_time)
+        .bind(end_time)
+        .fetch_all(pool)
+        .await
     }
 }
 
+/// 동기화 스냅샷 기준 시각 (해당 일자의 SYNC_SNAPSHOT_HOUR UTC).
+fn sync_snapshot_time(date: NaiveDate) -> DateTime<Utc> {
+    Utc.from_utc_datetime(&date.and_time(NaiveTime::MIN))
+        + Duration::hours(SYNC_SNAPSHOT_HOUR as i64)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1046,4 +1488,104 @@ mod tests {
 
         assert_eq!(point.return_pct, dec!(10.0));
     }
+
+    fn checkpoint(
+        mode: EquitySyncMode,
+        synced_through: NaiveDate,
+        status: &str,
+    ) -> EquitySyncCheckpoint {
+        EquitySyncCheckpoint {
+            credential_id: Uuid::new_v4(),
+            sync_mode: mode.as_str().to_string(),
+            synced_through,
+            execution_count: 10,
+            last_execution_at: None,
+            last_recompute_from: None,
+            last_sync_status: status.to_string(),
+            last_sync_message: None,
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_recompute_plan_without_checkpoint_is_full() {
+        let plan = RecomputePlan::determine(
+            None,
+            EquitySyncMode::MarketPrices,
+            Some(date(2025, 3, 1)),
+            date(2025, 1, 2),
+        );
+
+        assert!(plan.full);
+        assert!(!plan.backdated);
+        assert_eq!(plan.from, date(2025, 1, 2));
+    }
+
+    #[test]
+    fn test_recompute_plan_mode_change_is_full() {
+        let cp = checkpoint(EquitySyncMode::CashFlow, date(2025, 3, 10), "success");
+        let plan = RecomputePlan::determine(
+            Some(&cp),
+            EquitySyncMode::MarketPrices,
+            None,
+            date(2025, 1, 2),
+        );
+
+        assert!(plan.full);
+    }
+
+    #[test]
+    fn test_recompute_plan_after_failure_is_full() {
+        let cp = checkpoint(EquitySyncMode::MarketPrices, date(2025, 3, 10), "failed");
+        let plan = RecomputePlan::determine(
+            Some(&cp),
+            EquitySyncMode::MarketPrices,
+            None,
+            date(2025, 1, 2),
+        );
+
+        assert!(plan.full);
+    }
+
+    #[test]
+    fn test_recompute_plan_incremental_from_checkpoint() {
+        let cp = checkpoint(EquitySyncMode::MarketPrices, date(2025, 3, 10), "success");
+        let plan = RecomputePlan::determine(
+            Some(&cp),
+            EquitySyncMode::MarketPrices,
+            Some(date(2025, 3, 12)),
+            date(2025, 1, 2),
+        );
+
+        assert!(!plan.full);
+        assert!(!plan.backdated);
+        assert_eq!(plan.from, date(2025, 3, 10));
+    }
+
+    #[test]
+    fn test_recompute_plan_backdated_execution() {
+        let cp = checkpoint(EquitySyncMode::MarketPrices, date(2025, 3, 10), "success");
+        let plan = RecomputePlan::determine(
+            Some(&cp),
+            EquitySyncMode::MarketPrices,
+            Some(date(2025, 2, 20)),
+            date(2025, 1, 2),
+        );
+
+        assert!(!plan.full);
+        assert!(plan.backdated);
+        assert_eq!(plan.from, date(2025, 2, 20));
+    }
+
+    #[test]
+    fn test_sync_snapshot_time_is_fixed_hour() {
+        let t = sync_snapshot_time(date(2025, 3, 10));
+        assert_eq!(t.hour(), SYNC_SNAPSHOT_HOUR);
+        assert_eq!(t.minute(), 0);
+        assert_eq!(t.date_naive(), date(2025, 3, 10));
+    }
 }
@@ -124,6 +124,51 @@ pub struct CacheMeta {
     pub last_sync_message: Option<String>,
 }
 
+/// 체결 내역 upsert 결과.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UpsertOutcome {
+    /// 새로 삽입된 체결 수
+    pub inserted: usize,
+    /// 값이 바뀌어 갱신된 체결 수
+    pub updated: usize,
+    /// 이미 동일한 값으로 캐시되어 있던 체결 수
+    pub unchanged: usize,
+    /// 삽입/갱신된 체결 중 가장 이른 체결 일자
+    pub earliest_changed_date: Option<NaiveDate>,
+}
+
+impl UpsertOutcome {
+    /// 삽입 또는 갱신된 체결 수.
+    pub fn changed_count(&self) -> usize {
+        self.inserted + self.updated
+    }
+
+    /// 변경된 체결 한 건을 기록합니다.
+    fn record(&mut self, date: NaiveDate, inserted: bool) {
+        if inserted {
+            self.inserted += 1;
+        } else {
+            self.updated += 1;
+        }
+        self.earliest_changed_date = Some(match self.earliest_changed_date {
+            Some(d) => d.min(date),
+            None => date,
+        });
+    }
+
+    /// 다른 upsert 결과를 합칩니다 (분할 조회 시 사용).
+    pub fn merge(&mut self, other: &UpsertOutcome) {
+        self.inserted += other.inserted;
+        self.updated += other.updated;
+        self.unchanged += other.unchanged;
+        self.earliest_changed_date = match (self.earliest_changed_date, other.earliest_changed_date)
+        {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
+    }
+}
+
 /// 체결 내역 제공자 trait.
 ///
 /// 각 거래소 커넥터가 이 trait을 구현하여
@@ -214,21 +259,31 @@ impl ExecutionCacheRepository {
 
     /// 체결 내역 일괄 저장 (upsert).
     ///
-    /// 중복 키가 있으면 업데이트.
+    /// 중복 키가 있으면 업데이트. 새로 삽입되거나 값이 바뀐 체결 수를 반환합니다.
     pub async fn upsert_executions(
         pool: &PgPool,
         executions: &[NewExecution],
     ) -> Result<usize, sqlx::Error> {
-        if executions.is_empty() {
-            return Ok(0);
-        }
+        Ok(Self::upsert_executions_tracked(pool, executions)
+            .await?
+            .changed_count())
+    }
 
-        let mut inserted = 0;
+    /// 체결 내역 일괄 저장 (upsert) + 변경 추적.
+    ///
+    /// 이미 동일한 값으로 캐시된 체결은 건드리지 않으며,
+    /// 새로 삽입되거나 값이 바뀐 체결의 일자를 반환하여
+    /// 자산 곡선 재계산 범위를 결정할 수 있게 합니다.
+    pub async fn upsert_executions_tracked(
+        pool: &PgPool,
+        executions: &[NewExecution],
+    ) -> Result<UpsertOutcome, sqlx::Error> {
+        let mut outcome = UpsertOutcome::default();
 
         for exec in executions {
             let trade_id = exec.trade_id.clone().unwrap_or_default();
 
-            let result = sqlx::query(
+            let row = sqlx::query(
                 r#"
                 INSERT INTO execution_cache (
                     credential_id, exchange, executed_at,
@@ -248,6 +303,11 @@ impl ExecutionCacheRepository {
                     amount = EXCLUDED.amount,
                     fee = EXCLUDED.fee,
                     updated_at = NOW()
+                WHERE execution_cache.quantity IS DISTINCT FROM EXCLUDED.quantity
+                   OR execution_cache.price IS DISTINCT FROM EXCLUDED.price
+                   OR execution_cache.amount IS DISTINCT FROM EXCLUDED.amount
+                   OR execution_cache.fee IS DISTINCT FROM EXCLUDED.fee
+                RETURNING (xmax = 0) AS inserted
                 "#,
             )
             .bind(exec.credential_id)
@@ -265,15 +325,16 @@ impl ExecutionCacheRepository {
             .bind(&trade_id)
             .bind(&exec.order_type)
             .bind(&exec.raw_data)
-            .execute(pool)
+            .fetch_optional(pool)
             .await?;
 
-            if result.rows_affected() > 0 {
-                inserted += 1;
+            match row {
+                Some(r) => outcome.record(exec.executed_at.date_naive(), r.get("inserted")),
+                None => outcome.unchanged += 1,
             }
         }
 
-        Ok(inserted)
+        Ok(outcome)
     }
 
     /// 캐시 메타데이터 업데이트.
@@ -0,0 +1,290 @@
+//! 해외 주식 주문 전 자동 환전 Repository.
+//!
+//! 환전 판단과 결과(`fx_conversion`)를 관리합니다. 수동 승인 대기 건은
+//! 원 주문을 `order_payload`에 보관하고, 승인/거절은 pending 상태에서만 한 번 전환됩니다.
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, PgPool};
+use trader_execution::FxConversionStatus;
+use uuid::Uuid;
+
+/// 환전 레코드.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct FxConversionRecord {
+    pub id: Uuid,
+    /// 주문 출처 (conditional_order, dca 등)
+    pub source: String,
+    pub ticker: String,
+    /// 보류/제출 주문 (`{ "request": OrderRequest, "price": 가격 }`)
+    pub order_payload: serde_json::Value,
+    pub required_usd: Decimal,
+    pub available_usd: Decimal,
+    pub usd_amount: Decimal,
+    pub krw_amount: Decimal,
+    /// 증권사 적용 환율
+    pub rate: Decimal,
+    /// 기준 환율 (USDKRW 종가)
+    pub reference_rate: Option<Decimal>,
+    pub spread_bps: Option<Decimal>,
+    /// auto / manual
+    pub mode: String,
+    /// pending / not_needed / deferred / executed / rejected / failed
+    pub status: String,
+    /// 거절/실패 사유
+    pub reason: Option<String>,
+    /// 제출된 주문 ID
+    pub order_id: Option<Uuid>,
+    /// KIS 환전 주문번호
+    pub fx_order_no: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub decided_at: Option<DateTime<Utc>>,
+}
+
+impl FxConversionRecord {
+    /// 상태 파싱.
+    pub fn parsed_status(&self) -> Option<FxConversionStatus> {
+        self.status.parse().ok()
+    }
+}
+
+/// 환전 기록 입력.
+#[derive(Debug, Clone)]
+pub struct FxConversionInput {
+    pub source: String,
+    pub ticker: String,
+    pub order_payload: serde_json::Value,
+    pub required_usd: Decimal,
+    pub available_usd: Decimal,
+    pub usd_amount: Decimal,
+    pub krw_amount: Decimal,
+    pub rate: Decimal,
+    pub reference_rate: Option<Decimal>,
+    pub spread_bps: Option<Decimal>,
+    pub mode: String,
+    pub status: FxConversionStatus,
+    pub reason: Option<String>,
+    /// KIS 환전 주문번호 (환전 주문을 보낸 경우)
+    pub fx_order_no: Option<String>,
+}
+
+/// 환전 집계 (실행 건 기준).
+#[derive(Debug, Clone, Default, Serialize, Deserialize, FromRow)]
+pub struct FxConversionSummary {
+    pub executed: i64,
+    /// 통합증거금에 환전을 맡기고 제출한 건수 (50번 마이그레이션 이전 기록)
+    pub deferred: i64,
+    pub pending: i64,
+    pub rejected: i64,
+    pub failed: i64,
+    pub total_usd: Decimal,
+    pub total_krw: Decimal,
+    /// 원화 합계 / USD 합계 (executed + deferred)
+    pub average_rate: Option<Decimal>,
+}
+
+const COLUMNS: &str = r#"
+    id, source, ticker, order_payload, required_usd, available_usd, usd_amount,
+    krw_amount, rate, reference_rate, spread_bps, mode, status, reason, order_id,
+    fx_order_no, created_at, decided_at
+"#;
+
+/// 자동 환전 Repository.
+pub struct FxConversionRepository;
+
+impl FxConversionRepository {
+    /// 환전 기록 저장 (pending이 아니면 결정 시각도 기록).
+    pub async fn insert(
+        pool: &PgPool,
+        input: &FxConversionInput,
+    ) -> Result<FxConversionRecord, sqlx::Error> {
+        sqlx::query_as::<_, FxConversionRecord>(&format!(
+            r#"
+            INSERT INTO fx_conversion (
+                source, ticker, order_payload, required_usd, available_usd, usd_amount,
+                krw_amount, rate, reference_rate, spread_bps, mode, status, reason, fx_order_no,
+                decided_at
+            )
+            VALUES (
+                $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14,
+                CASE WHEN $12 = 'pending' THEN NULL ELSE NOW() END
+            )
+            RETURNING {}
+            "#,
+            COLUMNS
+        ))
+        .bind(&input.source)
+        .bind(&input.ticker)
+        .bind(&input.order_payload)
+        .bind(input.required_usd)
+        .bind(input.available_usd)
+        .bind(input.usd_amount)
+        .bind(input.krw_amount)
+        .bind(input.rate)
+        .bind(input.reference_rate)
+        .bind(input.spread_bps)
+        .bind(&input.mode)
+        .bind(input.status.as_str())
+        .bind(&input.reason)
+        .bind(&input.fx_order_no)
+        .fetch_one(pool)
+        .await
+    }
+
+    /// 환전 기록 조회.
+    pub async fn get(pool: &PgPool, id: Uuid) -> Result<Option<FxConversionRecord>, sqlx::Error> {
+        sqlx::query_as::<_, FxConversionRecord>(&format!(
+            "SELECT {} FROM fx_conversion WHERE id = $1",
+            COLUMNS
+        ))
+        .bind(id)
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// 환전 기록 목록 (최신순).
+    ///
+    /// # Arguments
+    /// * `status` - 상태 필터 (None이면 전체)
+    pub async fn list(
+        pool: &PgPool,
+        status: Option<FxConversionStatus>,
+        limit: i64,
+    ) -> Result<Vec<FxConversionRecord>, sqlx::Error> {
+        sqlx::query_as::<_, FxConversionRecord>(&format!(
+            r#"
+            SELECT {}
+            FROM fx_conversion
+            WHERE ($1::text IS NULL OR status = $1)
+            ORDER BY created_at DESC
+            LIMIT $2
+            "#,
+            COLUMNS
+        ))
+        .bind(status.map(|s| s.as_str()))
+        .bind(limit)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// 승인 대기 건 결정 (pending → executed/rejected/failed).
+    ///
+    /// 이미 결정된 건이면 `None`을 반환하여 중복 승인을 막습니다.
+    pub async fn decide(
+        pool: &PgPool,
+        id: Uuid,
+        status: FxConversionStatus,
+        reason: Option<&str>,
+    ) -> Result<Option<FxConversionRecord>, sqlx::Error> {
+        sqlx::query_as::<_, FxConversionRecord>(&format!(
+            r#"
+            UPDATE fx_conversion
+            SET status = $2, reason = $3, decided_at = NOW()
+            WHERE id = $1 AND status = 'pending'
+            RETURNING {}
+            "#,
+            COLUMNS
+        ))
+        .bind(id)
+        .bind(status.as_str())
+        .bind(reason)
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// 환전 주문번호 기록.
+    pub async fn record_fx_order(
+        pool: &PgPool,
+        id: Uuid,
+        fx_order_no: &str,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query("UPDATE fx_conversion SET fx_order_no = $2 WHERE id = $1")
+            .bind(id)
+            .bind(fx_order_no)
+            .execute(pool)
+            .await?;
+        Ok(())
+    }
+
+    /// 주문 제출 결과 기록 (`error`가 있으면 failed).
+    pub async fn record_order(
+        pool: &PgPool,
+        id: Uuid,
+        order_id: Option<Uuid>,
+        error: Option<&str>,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            UPDATE fx_conversion
+            SET order_id = $2,
+                status = CASE WHEN $3::text IS NULL THEN status ELSE 'failed' END,
+                reason = COALESCE($3, reason)
+            WHERE id = $1
+            "#,
+        )
+        .bind(id)
+        .bind(order_id)
+        .bind(error)
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    /// 기간별 환전 집계.
+    pub async fn summary(
+        pool: &PgPool,
+        from: Option<DateTime<Utc>>,
+        to: Option<DateTime<Utc>>,
+    ) -> Result<FxConversionSummary, sqlx::Error> {
+        sqlx::query_as::<_, FxConversionSummary>(
+            r#"
+            SELECT
+                COUNT(*) FILTER (WHERE status = 'executed') AS executed,
+                COUNT(*) FILTER (WHERE status = 'deferred') AS deferred,
+                COUNT(*) FILTER (WHERE status = 'pending') AS pending,
+                COUNT(*) FILTER (WHERE status = 'rejected') AS rejected,
+                COUNT(*) FILTER (WHERE status = 'failed') AS failed,
+                COALESCE(SUM(usd_amount) FILTER (WHERE status IN ('executed', 'deferred')), 0)
+                    AS total_usd,
+                COALESCE(SUM(krw_amount) FILTER (WHERE status IN ('executed', 'deferred')), 0)
+                    AS total_krw,
+                SUM(krw_amount) FILTER (WHERE status IN ('executed', 'deferred'))
+                    / NULLIF(SUM(usd_amount) FILTER (WHERE status IN ('executed', 'deferred')), 0)
+                    AS average_rate
+            FROM fx_conversion
+            WHERE ($1::timestamptz IS NULL OR created_at >= $1)
+              AND ($2::timestamptz IS NULL OR created_at <= $2)
+            "#,
+        )
+        .bind(from)
+        .bind(to)
+        .fetch_one(pool)
+        .await
+    }
+
+    /// 기간별 환전 기록 (최신순, 매매일지용).
+    pub async fn list_range(
+        pool: &PgPool,
+        from: Option<DateTime<Utc>>,
+        to: Option<DateTime<Utc>>,
+        limit: i64,
+    ) -> Result<Vec<FxConversionRecord>, sqlx::Error> {
+        sqlx::query_as::<_, FxConversionRecord>(&format!(
+            r#"
+            SELECT {}
+            FROM fx_conversion
+            WHERE ($1::timestamptz IS NULL OR created_at >= $1)
+              AND ($2::timestamptz IS NULL OR created_at <= $2)
+            ORDER BY created_at DESC
+            LIMIT $3
+            "#,
+            COLUMNS
+        ))
+        .bind(from)
+        .bind(to)
+        .bind(limit)
+        .fetch_all(pool)
+        .await
+    }
+}
@@ -14,7 +14,7 @@ use utoipa::ToSchema;
 use uuid::Uuid;
 
 use trader_analytics::{
-    GlobalScorer, GlobalScorerParams, IndicatorEngine, RouteStateCalculator, SevenFactorCalculator,
+    GlobalScorer, GlobalScorerParams, RouteStateCalculator, SevenFactorCalculator,
     SevenFactorInput, SevenFactorScores,
 };
 use trader_core::types::{MarketType, Symbol, Timeframe};
@@ -562,53 +562,7 @@ impl GlobalScoreRepository {
         }
 
         // 기술 지표 계산 (캔들 데이터 있는 경우)
-        if candles.len() >= 20 {
-            let indicator = IndicatorEngine::new();
-
-            // 종가, 고가, 저가 배열 추출
-            let closes: Vec<Decimal> = candles.iter().map(|c| c.close).collect();
-            let highs: Vec<Decimal> = candles.iter().map(|c| c.high).collect();
-            let lows: Vec<Decimal> = candles.iter().map(|c| c.low).collect();
-
-            // RSI - 가장 최근 값 사용
-            use trader_analytics::{AtrParams, RsiParams};
-            if let Ok(rsi_values) = indicator.rsi(&closes, RsiParams::default()) {
-                if let Some(Some(last_rsi)) = rsi_values.last() {
-                    input.rsi = Some(*last_rsi);
-                }
-            }
-
-            // ATR% - 가장 최근 값 사용
-            if let Ok(atr_values) = indicator.atr(&highs, &lows, &closes, AtrParams::default()) {
-                if let Some(Some(last_atr)) = atr_values.last() {
-                    let current_price = candles.last().map(|c| c.close).unwrap_or(Decimal::ONE);
-                    if current_price > Decimal::ZERO {
-                        input.atr_pct = Some(*last_atr / current_price * dec!(100));
-                    }
-                }
-            }
-
-            // 현재가
-            if let Some(last) = candles.last() {
-                input.current_price = Some(last.close);
-
-                // 5일/20일 수익률
-                if candles.len() >= 5 {
-                    let price_5d_ago = candles[candles.len() - 5].close;
-                    if price_5d_ago > Decimal::ZERO {
-                        input.return_5d =
-                            Some((last.close - price_5d_ago) / price_5d_ago * dec!(100));
-                    }
-                }
-                if candles.len() >= 20 {
-                    let price_20d_ago = candles[candles.len() - 20].close;
-                    if price_20d_ago > Decimal::ZERO {
-                        input.return_20d =
-                            Some((last.close - price_20d_ago) / price_20d_ago * dec!(100));
-                    }
-                }
-            }
-        }
+        input.apply_candles(&candles);
 
         // 4. 7Factor 계산
         let scores = SevenFactorCalculator::calculate(&input);
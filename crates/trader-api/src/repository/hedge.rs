@@ -0,0 +1,259 @@
+//! 포트폴리오 베타 헤지 오버레이 Repository.
+//!
+//! 헤지 설정(`hedge_overlay_config`, 단일 행)과 일별 조정 이력(`hedge_rebalance`)을
+//! 관리합니다. 헤지 수량과 손익은 성공한 조정 주문만으로 집계합니다.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, PgPool};
+use trader_execution::{HedgeFill, HedgeSettings};
+use uuid::Uuid;
+
+/// 헤지 설정 레코드.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct HedgeConfigRecord {
+    /// 활성화 여부
+    pub enabled: bool,
+    /// 베타 기준 지수/ETF
+    pub benchmark_ticker: String,
+    /// 헤지 상품
+    pub hedge_ticker: String,
+    /// 헤지 상품 베타 (인버스 1배: -1, 2배: -2, 선물: 1)
+    pub hedge_beta: Decimal,
+    /// 목표 순 베타
+    pub target_beta: Decimal,
+    /// 허용 밴드
+    pub band: Decimal,
+    /// 평가 자산 대비 최대 헤지 비율
+    pub max_hedge_ratio: Decimal,
+    /// 베타 계산 일수
+    pub beta_lookback: i32,
+    /// 마지막 조정 확인일
+    pub last_rebalanced_on: Option<NaiveDate>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl HedgeConfigRecord {
+    /// 헤지 계획 계산용 설정.
+    pub fn settings(&self) -> HedgeSettings {
+        HedgeSettings {
+            hedge_ticker: self.hedge_ticker.clone(),
+            hedge_beta: self.hedge_beta,
+            target_beta: self.target_beta,
+            band: self.band,
+            max_hedge_ratio: self.max_hedge_ratio,
+        }
+    }
+}
+
+/// 헤지 설정 수정 입력.
+#[derive(Debug, Clone)]
+pub struct HedgeConfigInput {
+    pub enabled: bool,
+    pub benchmark_ticker: String,
+    pub settings: HedgeSettings,
+    pub beta_lookback: i32,
+}
+
+/// 일별 헤지 조정 레코드.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct HedgeRebalanceRecord {
+    pub id: Uuid,
+    pub as_of_date: NaiveDate,
+    pub hedge_ticker: String,
+    pub hedge_price: Decimal,
+    /// 평가 자산
+    pub equity: Decimal,
+    /// 헤지 제외 베타 노출 (Σ 평가금액 × 베타)
+    pub equity_beta_exposure: Decimal,
+    pub net_beta_before: Decimal,
+    pub net_beta_after: Decimal,
+    /// 조정 전 헤지 수량 (매도 헤지는 음수)
+    pub quantity_before: Decimal,
+    pub target_quantity: Decimal,
+    /// 주문 수량 (양수: 매수, 음수: 매도, 0: 조정 없음)
+    pub order_quantity: Decimal,
+    /// 종목별 평가금액/베타 (JSON)
+    pub exposures: serde_json::Value,
+    /// 판단 사유
+    pub reason: Option<String>,
+    /// 주문 접수 성공 여부
+    pub success: bool,
+    /// 실패 사유
+    pub error: Option<String>,
+    /// 내부 주문 ID
+    pub order_id: Option<Uuid>,
+    pub executed_at: DateTime<Utc>,
+}
+
+/// 일별 헤지 조정 입력.
+#[derive(Debug, Clone)]
+pub struct HedgeRebalanceInput {
+    pub as_of_date: NaiveDate,
+    pub hedge_ticker: String,
+    pub hedge_price: Decimal,
+    pub equity: Decimal,
+    pub equity_beta_exposure: Decimal,
+    pub net_beta_before: Decimal,
+    pub net_beta_after: Decimal,
+    pub quantity_before: Decimal,
+    pub target_quantity: Decimal,
+    pub order_quantity: Decimal,
+    pub exposures: serde_json::Value,
+    pub reason: Option<String>,
+    pub success: bool,
+    pub error: Option<String>,
+    pub order_id: Option<Uuid>,
+}
+
+const CONFIG_COLUMNS: &str = r#"
+    enabled, benchmark_ticker, hedge_ticker, hedge_beta, target_beta, band,
+    max_hedge_ratio, beta_lookback, last_rebalanced_on, updated_at
+"#;
+
+const REBALANCE_COLUMNS: &str = r#"
+    id, as_of_date, hedge_ticker, hedge_price, equity, equity_beta_exposure,
+    net_beta_before, net_beta_after, quantity_before, target_quantity, order_quantity,
+    exposures, reason, success, error, order_id, executed_at
+"#;
+
+/// 헤지 오버레이 Repository.
+pub struct HedgeRepository;
+
+impl HedgeRepository {
+    /// 헤지 설정 조회 (없으면 기본값으로 생성).
+    pub async fn get_config(pool: &PgPool) -> Result<HedgeConfigRecord, sqlx::Error> {
+        sqlx::query_as::<_, HedgeConfigRecord>(&format!(
+            r#"
+            INSERT INTO hedge_overlay_config (id) VALUES (1)
+            ON CONFLICT (id) DO UPDATE SET id = hedge_overlay_config.id
+            RETURNING {}
+            "#,
+            CONFIG_COLUMNS
+        ))
+        .fetch_one(pool)
+        .await
+    }
+
+    /// 헤지 설정 수정.
+    pub async fn update_config(
+        pool: &PgPool,
+        input: &HedgeConfigInput,
+    ) -> Result<HedgeConfigRecord, sqlx::Error> {
+        sqlx::query_as::<_, HedgeConfigRecord>(&format!(
+            r#"
+            INSERT INTO hedge_overlay_config (
+                id, enabled, benchmark_ticker, hedge_ticker, hedge_beta, target_beta,
+                band, max_hedge_ratio, beta_lookback
+            )
+            VALUES (1, $1, $2, $3, $4, $5, $6, $7, $8)
+            ON CONFLICT (id) DO UPDATE SET
+                enabled = EXCLUDED.enabled,
+                benchmark_ticker = EXCLUDED.benchmark_ticker,
+                hedge_ticker = EXCLUDED.hedge_ticker,
+                hedge_beta = EXCLUDED.hedge_beta,
+                target_beta = EXCLUDED.target_beta,
+                band = EXCLUDED.band,
+                max_hedge_ratio = EXCLUDED.max_hedge_ratio,
+                beta_lookback = EXCLUDED.beta_lookback,
+                updated_at = NOW()
+            RETURNING {}
+            "#,
+            CONFIG_COLUMNS
+        ))
+        .bind(input.enabled)
+        .bind(&input.benchmark_ticker)
+        .bind(&input.settings.hedge_ticker)
+        .bind(input.settings.hedge_beta)
+        .bind(input.settings.target_beta)
+        .bind(input.settings.band)
+        .bind(input.settings.max_hedge_ratio)
+        .bind(input.beta_lookback)
+        .fetch_one(pool)
+        .await
+    }
+
+    /// 조정 확인일 기록 (같은 날 다시 조정하지 않음).
+    pub async fn mark_rebalanced(pool: &PgPool, date: NaiveDate) -> Result<(), sqlx::Error> {
+        sqlx::query("UPDATE hedge_overlay_config SET last_rebalanced_on = $1 WHERE id = 1")
+            .bind(date)
+            .execute(pool)
+            .await?;
+        Ok(())
+    }
+
+    /// 조정 이력 저장.
+    pub async fn insert_rebalance(
+        pool: &PgPool,
+        input: &HedgeRebalanceInput,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            INSERT INTO hedge_rebalance (
+                as_of_date, hedge_ticker, hedge_price, equity, equity_beta_exposure,
+                net_beta_before, net_beta_after, quantity_before, target_quantity,
+                order_quantity, exposures, reason, success, error, order_id
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15)
+            "#,
+        )
+        .bind(input.as_of_date)
+        .bind(&input.hedge_ticker)
+        .bind(input.hedge_price)
+        .bind(input.equity)
+        .bind(input.equity_beta_exposure)
+        .bind(input.net_beta_before)
+        .bind(input.net_beta_after)
+        .bind(input.quantity_before)
+        .bind(input.target_quantity)
+        .bind(input.order_quantity)
+        .bind(&input.exposures)
+        .bind(&input.reason)
+        .bind(input.success)
+        .bind(&input.error)
+        .bind(input.order_id)
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    /// 조정 이력 (최신순).
+    pub async fn list_rebalances(
+        pool: &PgPool,
+        limit: i64,
+    ) -> Result<Vec<HedgeRebalanceRecord>, sqlx::Error> {
+        sqlx::query_as::<_, HedgeRebalanceRecord>(&format!(
+            r#"
+            SELECT {}
+            FROM hedge_rebalance
+            ORDER BY executed_at DESC
+            LIMIT $1
+            "#,
+            REBALANCE_COLUMNS
+        ))
+        .bind(limit)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// 헤지 상품의 성공한 조정 주문 (시간순, 손익/보유 수량 집계용).
+    pub async fn fills(pool: &PgPool, hedge_ticker: &str) -> Result<Vec<HedgeFill>, sqlx::Error> {
+        let rows = sqlx::query_as::<_, (Decimal, Decimal)>(
+            r#"
+            SELECT order_quantity, hedge_price
+            FROM hedge_rebalance
+            WHERE hedge_ticker = $1 AND success AND order_quantity <> 0
+            ORDER BY executed_at
+            "#,
+        )
+        .bind(hedge_ticker)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(quantity, price)| HedgeFill { quantity, price })
+            .collect())
+    }
+}
@@ -888,6 +888,15 @@ pub struct StrategyPerformance {
     pub last_trade_at: Option<DateTime<Utc>>,
 }
 
+/// 전략의 종목별 체결 거래대금 (용량 추정용).
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct StrategySymbolNotional {
+    pub symbol: String,
+    pub notional: Decimal,
+    /// 체결이 있었던 날 수 (전략 전체 기준)
+    pub active_days: i64,
+}
+
 // =====================================================
 // 확장 쿼리 메서드
 // =====================================================
@@ -1008,6 +1017,36 @@ impl JournalRepository {
         .await
     }
 
+    /// 전략의 종목별 체결 거래대금 합계 조회.
+    ///
+    /// 계정과 무관하게 `strategy_id`로 집계하며, `active_days`는 전략 전체의 체결일 수입니다.
+    pub async fn get_strategy_symbol_notional(
+        pool: &PgPool,
+        strategy_id: &str,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<StrategySymbolNotional>, sqlx::Error> {
+        sqlx::query_as::<_, StrategySymbolNotional>(
+            r#"
+            WITH recent AS (
+                SELECT symbol, notional_value, executed_at
+                FROM trade_executions
+                WHERE strategy_id = $1 AND executed_at >= $2
+            )
+            SELECT
+                symbol,
+                SUM(notional_value) AS notional,
+                (SELECT COUNT(DISTINCT executed_at::date) FROM recent) AS active_days
+            FROM recent
+            GROUP BY symbol
+            ORDER BY notional DESC
+            "#,
+        )
+        .bind(strategy_id)
+        .bind(since)
+        .fetch_all(pool)
+        .await
+    }
+
     // =====================================================
     // 비용 기준 계산 (물타기 평균가, FIFO 실현손익)
     // =====================================================
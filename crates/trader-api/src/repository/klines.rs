@@ -397,6 +397,44 @@ impl KlinesRepository {
         Ok(result.0)
     }
 
+    /// 캐시 메타데이터 재계산
+    ///
+    /// 대량 업로드처럼 기존 캔들을 갱신(ON CONFLICT UPDATE)하는 경우 INSERT 트리거가
+    /// 실행되지 않으므로, 저장 후 실제 데이터 기준으로 메타데이터를 다시 계산합니다.
+    ///
+    /// # Arguments
+    /// * `pool` - 데이터베이스 연결 풀
+    /// * `symbol` - 심볼
+    /// * `timeframe` - 타임프레임
+    pub async fn refresh_metadata(
+        pool: &PgPool,
+        symbol: &str,
+        timeframe: &str,
+    ) -> Result<Option<CacheMetadata>, sqlx::Error> {
+        let metadata = sqlx::query_as::<_, CacheMetadata>(
+            r#"
+            INSERT INTO ohlcv_metadata
+                (symbol, timeframe, first_cached_time, last_cached_time, last_updated_at, total_candles)
+            SELECT $1, $2, MIN(open_time), MAX(open_time), NOW(), COUNT(*)::int
+            FROM ohlcv
+            WHERE symbol = $1 AND timeframe = $2
+            HAVING COUNT(*) > 0
+            ON CONFLICT (symbol, timeframe) DO UPDATE SET
+                first_cached_time = EXCLUDED.first_cached_time,
+                last_cached_time = EXCLUDED.last_cached_time,
+                last_updated_at = NOW(),
+                total_candles = EXCLUDED.total_candles
+            RETURNING symbol, timeframe, first_cached_time, last_cached_time, last_updated_at, total_candles
+            "#,
+        )
+        .bind(symbol)
+        .bind(timeframe)
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(metadata)
+    }
+
     /// 다중 심볼 기간별 데이터 배치 조회
     ///
     /// # Arguments
@@ -0,0 +1,187 @@
+//! 시장 달력 Repository.
+//!
+//! KRX/미국 휴장일과 단축 거래일(`market_calendar`)을 저장하고 조회합니다.
+//! 동기화 서비스가 저장하는 `kis` 행과 직접 입력한 `manual` 행이 있으며,
+//! 같은 일자에 둘 다 있으면 `manual` 행이 우선합니다.
+
+use chrono::{DateTime, Datelike, NaiveDate, NaiveTime, Utc};
+use sqlx::{FromRow, PgPool};
+use trader_core::{CalendarMarket, MarketCalendarEntry, MarketDayKind};
+
+/// 동기화 서비스가 저장한 행의 source.
+pub const CALENDAR_SOURCE_SYNC: &str = "kis";
+
+/// 시장 달력 레코드.
+#[derive(Debug, Clone, FromRow)]
+pub struct MarketCalendarRecord {
+    pub market: String,
+    pub calendar_date: NaiveDate,
+    pub source: String,
+    pub kind: String,
+    pub open_time: Option<NaiveTime>,
+    pub close_time: Option<NaiveTime>,
+    pub name: Option<String>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl MarketCalendarRecord {
+    /// 달력 항목으로 변환 (알 수 없는 시장/유형이면 `None`).
+    pub fn to_entry(&self) -> Option<MarketCalendarEntry> {
+        Some(MarketCalendarEntry {
+            market: self.market.parse().ok()?,
+            date: self.calendar_date,
+            kind: self.kind.parse::<MarketDayKind>().ok()?,
+            open_time: self.open_time,
+            close_time: self.close_time,
+            name: self.name.clone(),
+        })
+    }
+
+    /// 동기화 서비스가 저장한 행인지 여부.
+    pub fn is_synced(&self) -> bool {
+        self.source == CALENDAR_SOURCE_SYNC
+    }
+}
+
+/// 시장 달력 Repository.
+pub struct MarketCalendarRepository;
+
+impl MarketCalendarRepository {
+    /// 한 해의 달력 조회 (일자별로 manual 행 우선, 일자 순).
+    pub async fn list_year(
+        pool: &PgPool,
+        market: CalendarMarket,
+        year: i32,
+    ) -> Result<Vec<MarketCalendarRecord>, sqlx::Error> {
+        sqlx::query_as::<_, MarketCalendarRecord>(
+            r#"
+            SELECT DISTINCT ON (calendar_date)
+                market, calendar_date, source, kind, open_time, close_time, name, updated_at
+            FROM market_calendar
+            WHERE market = $1
+              AND calendar_date >= make_date($2, 1, 1)
+              AND calendar_date < make_date($2 + 1, 1, 1)
+            ORDER BY calendar_date, (source = 'manual') DESC
+            "#,
+        )
+        .bind(market.as_str())
+        .bind(year)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// 한 해의 마지막 동기화 시각 (동기화된 적이 없으면 `None`).
+    pub async fn last_synced_at(
+        pool: &PgPool,
+        market: CalendarMarket,
+        year: i32,
+    ) -> Result<Option<DateTime<Utc>>, sqlx::Error> {
+        sqlx::query_scalar(
+            r#"
+            SELECT MAX(updated_at)
+            FROM market_calendar
+            WHERE market = $1
+              AND source = $2
+              AND calendar_date >= make_date($3, 1, 1)
+              AND calendar_date < make_date($3 + 1, 1, 1)
+            "#,
+        )
+        .bind(market.as_str())
+        .bind(CALENDAR_SOURCE_SYNC)
+        .bind(year)
+        .fetch_one(pool)
+        .await
+    }
+
+    /// 한 해의 동기화 행 교체 (manual 행은 유지).
+    ///
+    /// 삭제와 저장을 한 트랜잭션에서 수행하며, 저장한 행 수를 반환합니다.
+    pub async fn replace_synced_year(
+        pool: &PgPool,
+        market: CalendarMarket,
+        year: i32,
+        entries: &[MarketCalendarEntry],
+    ) -> Result<u64, sqlx::Error> {
+        let entries: Vec<&MarketCalendarEntry> = entries
+            .iter()
+            .filter(|e| e.market == market && e.date.year() == year)
+            .collect();
+        let dates: Vec<NaiveDate> = entries.iter().map(|e| e.date).collect();
+        let kinds: Vec<String> = entries
+            .iter()
+            .map(|e| e.kind.as_str().to_string())
+            .collect();
+        let open_times: Vec<Option<NaiveTime>> = entries.iter().map(|e| e.open_time).collect();
+        let close_times: Vec<Option<NaiveTime>> = entries.iter().map(|e| e.close_time).collect();
+        let names: Vec<Option<String>> = entries.iter().map(|e| e.name.clone()).collect();
+
+        let mut tx = pool.begin().await?;
+
+        sqlx::query(
+            r#"
+            DELETE FROM market_calendar
+            WHERE market = $1
+              AND source = $2
+              AND calendar_date >= make_date($3, 1, 1)
+              AND calendar_date < make_date($3 + 1, 1, 1)
+            "#,
+        )
+        .bind(market.as_str())
+        .bind(CALENDAR_SOURCE_SYNC)
+        .bind(year)
+        .execute(&mut *tx)
+        .await?;
+
+        let result = sqlx::query(
+            r#"
+            INSERT INTO market_calendar (
+                market, calendar_date, source, kind, open_time, close_time, name
+            )
+            SELECT $1, t.calendar_date, $2, t.kind, t.open_time, t.close_time, t.name
+            FROM UNNEST($3::date[], $4::text[], $5::time[], $6::time[], $7::text[])
+                AS t(calendar_date, kind, open_time, close_time, name)
+            "#,
+        )
+        .bind(market.as_str())
+        .bind(CALENDAR_SOURCE_SYNC)
+        .bind(&dates)
+        .bind(&kinds)
+        .bind(&open_times)
+        .bind(&close_times)
+        .bind(&names)
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(result.rows_affected())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_to_entry() {
+        let record = MarketCalendarRecord {
+            market: "US".to_string(),
+            calendar_date: NaiveDate::from_ymd_opt(2026, 11, 27).unwrap(),
+            source: "manual".to_string(),
+            kind: "early_close".to_string(),
+            open_time: None,
+            close_time: NaiveTime::from_hms_opt(13, 0, 0),
+            name: None,
+            updated_at: Utc::now(),
+        };
+        let entry = record.to_entry().unwrap();
+        assert_eq!(entry.market, CalendarMarket::Us);
+        assert_eq!(entry.kind, MarketDayKind::EarlyClose);
+        assert!(!record.is_synced());
+
+        let unknown = MarketCalendarRecord {
+            kind: "closed".to_string(),
+            ..record
+        };
+        assert!(unknown.to_entry().is_none());
+    }
+}
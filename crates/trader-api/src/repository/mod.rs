@@ -3,67 +3,138 @@
 //! 데이터베이스 접근 로직을 라우트 핸들러에서 분리하여 관리합니다.
 //! 모든 Repository는 static methods 패턴을 사용합니다.
 
+pub mod audit_log;
 pub mod backtest_results;
+pub mod backtest_templates;
+pub mod broker_downtime;
+pub mod competitions;
+pub mod conditional_orders;
+pub mod correlation;
 pub mod cost_basis;
 pub mod credentials;
+pub mod dca;
 pub mod equity_history;
 pub mod execution_cache;
+pub mod fx_conversion;
 pub mod global_score;
+pub mod hedge;
 pub mod journal;
 pub mod kis_token;
 pub mod klines;
+pub mod market_calendar;
+pub mod monitoring;
+pub mod order_intents;
+pub mod orderbook_metrics;
 pub mod orders;
+pub mod outbound_webhooks;
+pub mod paper_trades;
 pub mod portfolio;
 pub mod positions;
+pub mod quotas;
 pub mod reality_check;
 pub mod score_history;
 pub mod screening;
 pub mod signal_alert_rule;
 pub mod signal_marker;
 pub mod strategies;
+pub mod strategy_capital;
+pub mod strategy_history;
+pub mod strategy_promotion;
+pub mod strategy_states;
 pub mod symbol_fundamental;
 pub mod symbol_info;
+pub mod underlying;
 pub mod watchlist;
+pub mod watchlist_alert;
 
+pub use audit_log::{AuditLogRecord, AuditLogRepository};
 pub use backtest_results::{
-    BacktestResultDto, BacktestResultInput, BacktestResultRecord, BacktestResultsRepository,
-    ListResultsFilter, ListResultsResponse as BacktestListResponse,
+    downsample_equity_curve, BacktestResultDto, BacktestResultInput, BacktestResultRecord,
+    BacktestResultsRepository, EquityCurveRecord, ListResultsFilter,
+    ListResultsResponse as BacktestListResponse, ResultsSortBy, EQUITY_PREVIEW_POINTS,
+};
+pub use backtest_templates::{
+    BacktestTemplateInput, BacktestTemplateRecord, BacktestTemplateRepository,
+    BacktestTemplateRunInput, BacktestTemplateRunRecord,
+};
+pub use broker_downtime::{BrokerDowntimeRecord, BrokerDowntimeRepository};
+pub use competitions::{
+    CompetitionEntryInput, CompetitionEntryRecord, CompetitionEquityRecord, CompetitionInput,
+    CompetitionRecord, CompetitionRepository,
+};
+pub use conditional_orders::{
+    ConditionalOrderInput, ConditionalOrderRecord, ConditionalOrderRepository,
+};
+pub use correlation::{
+    CorrelationAlertRecord, CorrelationAssetRecord, CorrelationRepository,
+    CorrelationSnapshotInput, CorrelationSnapshotRecord,
 };
 pub use credentials::{
     create_exchange_providers_from_credential, create_kis_kr_client_from_credential,
     get_active_credential_id, ExchangeProviderPair,
 };
+pub use dca::{
+    DcaExecutionInput, DcaExecutionRecord, DcaPlanInput, DcaPlanRecord, DcaRepository,
+    DcaSymbolReport,
+};
 pub use equity_history::{
-    EquityHistoryRepository, EquityPoint, ExecutionForSync, MonthlyReturn, PortfolioSnapshot,
-    SyncResult,
+    EquityHistoryRepository, EquityPoint, EquitySyncCheckpoint, EquitySyncMode, ExecutionForSync,
+    MonthlyReturn, PortfolioSnapshot, RecomputePlan, StrategyEquityRecord, SyncResult,
 };
 pub use execution_cache::{
     CacheMeta, CachedExecution, ExecutionCacheRepository, ExecutionProvider, NewExecution,
+    UpsertOutcome,
+};
+pub use fx_conversion::{
+    FxConversionInput, FxConversionRecord, FxConversionRepository, FxConversionSummary,
+};
+pub use hedge::{
+    HedgeConfigInput, HedgeConfigRecord, HedgeRebalanceInput, HedgeRebalanceRecord, HedgeRepository,
 };
 pub use klines::{CacheMetadata, KlineRecord, KlinesRepository, NewKline};
-pub use orders::{Order, OrderInput, OrderRepository, OrderStatus};
-pub use portfolio::{PortfolioRepository, Position, PositionUpdate};
+pub use market_calendar::{MarketCalendarRecord, MarketCalendarRepository, CALENDAR_SOURCE_SYNC};
+pub use monitoring::{CollectorRunRecord, MarketFreshnessRecord, MonitoringRepository};
+pub use order_intents::{OrderIntentRecord, OrderIntentRepository, PgOrderIntentStore};
+pub use orderbook_metrics::OrderBookMetricsRepository;
+pub use orders::{Order, OrderInput, OrderRepository, OrderStatus, ReconciliationOrder};
+pub use outbound_webhooks::{
+    OutboundWebhookInput, OutboundWebhookRecord, OutboundWebhookRepository, WebhookDeliveryRecord,
+};
+pub use paper_trades::{PaperTradeInput, PaperTradeRecord, PaperTradeRepository};
+pub use portfolio::{AccountPositionRecord, PortfolioRepository, Position, PositionUpdate};
 pub use positions::{
     HoldingPosition, PositionInput, PositionRecord, PositionRepository,
     SyncResult as PositionSyncResult,
 };
+pub use quotas::{QuotaRepository, UserQuotaRecord};
 pub use reality_check::{
     CalculationResult, DailyStats, PriceSnapshot, RankStats, RealityCheckRecord,
     RealityCheckRepository, SnapshotInput, SourceStats,
 };
 pub use screening::{
-    CreatePresetRequest, MomentumScreenResult, ScreeningFilter, ScreeningPreset,
-    ScreeningPresetRecord, ScreeningRepository, ScreeningResult, SectorRsResult,
+    CreatePresetRequest, InvestorFlowScreenResult, MomentumScreenResult, ScreeningFilter,
+    ScreeningPreset, ScreeningPresetRecord, ScreeningRepository, ScreeningResult, SectorRsResult,
 };
 pub use strategies::StrategyRepository;
+pub use strategy_capital::{CapitalTransferRecord, StrategyCapitalRepository};
+pub use strategy_history::{
+    diff_params, param_snapshot, ParamDiff, StrategyDailyPnl, StrategyHistoryRepository,
+    StrategyParamChangeInput, StrategyParamChangeRecord,
+};
+pub use strategy_promotion::{
+    CandleCoverage, StrategyPromotionInput, StrategyPromotionRecord, StrategyPromotionRepository,
+};
+pub use strategy_states::{PgStrategyStateStore, StrategyStateRecord, StrategyStateRepository};
 pub use symbol_fundamental::{
     IndicatorUpdate, NewSymbolFundamental, SymbolFundamental, SymbolFundamentalRepository,
     SymbolWithFundamental,
 };
 pub use symbol_info::{
     DeactivatedStats, ExternalFetchError, FailedSymbolInfo, FetchFailureResult, NewSymbolInfo,
-    SymbolInfo, SymbolInfoRepository, SymbolSearchResult, MAX_FETCH_FAILURES,
+    SymbolClassification, SymbolInfo, SymbolInfoRepository, SymbolSearchResult,
+    MAX_FETCH_FAILURES,
 };
+pub use underlying::{SymbolUnderlyingRecord, UnderlyingRepository};
 
 pub use global_score::{
     GlobalScoreRecord, GlobalScoreRepository, RankedSymbol, RankingFilter, SevenFactorData,
@@ -110,9 +181,13 @@ pub use watchlist::{
     NewWatchlist, NewWatchlistItem, UpdateWatchlistItem, WatchlistItemRecord, WatchlistRecord,
     WatchlistRepository, WatchlistWithCount,
 };
+pub use watchlist_alert::{
+    WatchlistAlertInput, WatchlistAlertRecord, WatchlistAlertRepository, WatchlistAlertRule,
+};
 
 pub use kis_token::KisTokenRepository;
 
 pub use score_history::{
-    ScoreHistoryInput, ScoreHistoryRecord, ScoreHistoryRepository, ScoreHistorySummary,
+    FactorChangeSummary, FactorHistoryPoint, ScoreHistoryInput, ScoreHistoryRecord,
+    ScoreHistoryRepository, ScoreHistorySummary,
 };
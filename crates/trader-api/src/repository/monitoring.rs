@@ -0,0 +1,68 @@
+//! 모니터링 대시보드 집계 Repository.
+//!
+//! 시장별 일봉 데이터 갱신 시각과 수집기(trader-collector) 워크플로우의
+//! 마지막 실행 기록(`sync_checkpoint`)을 조회합니다.
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::{FromRow, PgPool};
+
+/// 시장별 일봉 데이터 신선도.
+#[derive(Debug, Clone, Serialize, FromRow)]
+pub struct MarketFreshnessRecord {
+    pub market: String,
+    /// 일봉이 캐시된 종목 수
+    pub symbol_count: i64,
+    /// 가장 최근 일봉 시각
+    pub latest_bar_at: Option<DateTime<Utc>>,
+    /// 가장 최근 캐시 갱신 시각
+    pub last_updated_at: Option<DateTime<Utc>>,
+}
+
+/// 수집기 워크플로우 마지막 실행 기록.
+#[derive(Debug, Clone, Serialize, FromRow)]
+pub struct CollectorRunRecord {
+    pub workflow_name: String,
+    /// running / interrupted / completed / idle
+    pub status: String,
+    pub last_processed_at: Option<DateTime<Utc>>,
+    pub total_processed: i32,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+/// 모니터링 Repository.
+pub struct MonitoringRepository;
+
+impl MonitoringRepository {
+    /// 시장별 일봉 데이터 신선도 조회.
+    pub async fn data_freshness(pool: &PgPool) -> Result<Vec<MarketFreshnessRecord>, sqlx::Error> {
+        sqlx::query_as::<_, MarketFreshnessRecord>(
+            r#"
+            SELECT s.market,
+                   COUNT(*) AS symbol_count,
+                   MAX(m.last_cached_time) AS latest_bar_at,
+                   MAX(m.last_updated_at) AS last_updated_at
+            FROM ohlcv_metadata m
+            JOIN symbol_info s ON s.ticker = m.symbol
+            WHERE m.timeframe = '1d'
+            GROUP BY s.market
+            ORDER BY s.market
+            "#,
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// 수집기 워크플로우별 마지막 실행 기록 조회.
+    pub async fn collector_runs(pool: &PgPool) -> Result<Vec<CollectorRunRecord>, sqlx::Error> {
+        sqlx::query_as::<_, CollectorRunRecord>(
+            r#"
+            SELECT workflow_name, status, last_processed_at, total_processed, updated_at
+            FROM sync_checkpoint
+            ORDER BY workflow_name
+            "#,
+        )
+        .fetch_all(pool)
+        .await
+    }
+}
@@ -0,0 +1,190 @@
+//! 주문 의도 Repository.
+//!
+//! 멱등성 키(클라이언트 주문 ID)별 주문 의도(`order_intents`)를 저장합니다.
+//! 실행기는 주문을 만들기 전에 키를 선점하므로 재시도, 서버 재시작, 여러 인스턴스에서도
+//! 같은 키의 주문이 중복 생성되지 않습니다.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use sqlx::{FromRow, PgPool};
+use trader_core::Side;
+use trader_execution::{OrderIntent, OrderIntentStore};
+use uuid::Uuid;
+
+/// 주문 의도 레코드.
+#[derive(Debug, Clone, FromRow)]
+pub struct OrderIntentRecord {
+    pub idempotency_key: String,
+    pub order_id: Uuid,
+    pub ticker: String,
+    /// buy / sell
+    pub side: String,
+    pub quantity: Decimal,
+    pub price: Option<Decimal>,
+    /// pending / submitted / unknown / failed
+    pub state: String,
+    pub exchange_order_id: Option<String>,
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl OrderIntentRecord {
+    /// 주문 의도로 변환 (알 수 없는 방향/상태면 `None`).
+    pub fn to_intent(&self) -> Option<OrderIntent> {
+        Some(OrderIntent {
+            key: self.idempotency_key.clone(),
+            order_id: self.order_id,
+            ticker: self.ticker.clone(),
+            side: Side::from_str_flexible(&self.side).ok()?,
+            quantity: self.quantity,
+            price: self.price,
+            state: self.state.parse().ok()?,
+            exchange_order_id: self.exchange_order_id.clone(),
+            last_error: self.last_error.clone(),
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        })
+    }
+}
+
+fn side_str(side: Side) -> &'static str {
+    match side {
+        Side::Buy => "buy",
+        Side::Sell => "sell",
+    }
+}
+
+/// 주문 의도 Repository.
+pub struct OrderIntentRepository;
+
+impl OrderIntentRepository {
+    /// 키 선점.
+    ///
+    /// 선점하면 `None`, 같은 키의 유효한 의도가 있으면 그 레코드를 반환합니다.
+    /// 거래소가 거부한(`failed`) 의도나 `stale_before` 이전에 갱신된 의도는 새 의도로 교체합니다.
+    pub async fn claim(
+        pool: &PgPool,
+        intent: &OrderIntent,
+        stale_before: DateTime<Utc>,
+    ) -> Result<Option<OrderIntentRecord>, sqlx::Error> {
+        let claimed = sqlx::query_scalar::<_, String>(
+            r#"
+            INSERT INTO order_intents (
+                idempotency_key, order_id, ticker, side, quantity, price,
+                state, created_at, updated_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, 'pending', $7, $7)
+            ON CONFLICT (idempotency_key) DO UPDATE
+            SET order_id = EXCLUDED.order_id,
+                ticker = EXCLUDED.ticker,
+                side = EXCLUDED.side,
+                quantity = EXCLUDED.quantity,
+                price = EXCLUDED.price,
+                state = 'pending',
+                exchange_order_id = NULL,
+                last_error = NULL,
+                created_at = EXCLUDED.created_at,
+                updated_at = EXCLUDED.updated_at
+            WHERE order_intents.state = 'failed' OR order_intents.updated_at < $8
+            RETURNING idempotency_key
+            "#,
+        )
+        .bind(&intent.key)
+        .bind(intent.order_id)
+        .bind(&intent.ticker)
+        .bind(side_str(intent.side))
+        .bind(intent.quantity)
+        .bind(intent.price)
+        .bind(intent.created_at)
+        .bind(stale_before)
+        .fetch_optional(pool)
+        .await?;
+        if claimed.is_some() {
+            return Ok(None);
+        }
+
+        sqlx::query_as::<_, OrderIntentRecord>(
+            r#"
+            SELECT idempotency_key, order_id, ticker, side, quantity, price,
+                   state, exchange_order_id, last_error, created_at, updated_at
+            FROM order_intents
+            WHERE idempotency_key = $1
+            "#,
+        )
+        .bind(&intent.key)
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// 의도 상태 갱신 (같은 주문이 선점한 키만).
+    pub async fn update(pool: &PgPool, intent: &OrderIntent) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query(
+            r#"
+            UPDATE order_intents
+            SET state = $3, exchange_order_id = $4, last_error = $5, updated_at = $6
+            WHERE idempotency_key = $1 AND order_id = $2
+            "#,
+        )
+        .bind(&intent.key)
+        .bind(intent.order_id)
+        .bind(intent.state.as_str())
+        .bind(&intent.exchange_order_id)
+        .bind(&intent.last_error)
+        .bind(intent.updated_at)
+        .execute(pool)
+        .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// 의도 삭제 (삭제된 경우 true).
+    pub async fn release(pool: &PgPool, key: &str) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query("DELETE FROM order_intents WHERE idempotency_key = $1")
+            .bind(key)
+            .execute(pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+/// DB 기반 주문 의도 저장소 (`OrderExecutor::set_order_intent_store()`에 등록).
+pub struct PgOrderIntentStore {
+    pool: PgPool,
+    /// 이 기간 동안 갱신되지 않은 의도는 새 의도로 교체
+    retention: chrono::Duration,
+}
+
+impl PgOrderIntentStore {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            pool,
+            retention: chrono::Duration::days(1),
+        }
+    }
+}
+
+#[async_trait]
+impl OrderIntentStore for PgOrderIntentStore {
+    async fn claim(&self, intent: &OrderIntent) -> Result<Option<OrderIntent>, String> {
+        let existing =
+            OrderIntentRepository::claim(&self.pool, intent, intent.created_at - self.retention)
+                .await
+                .map_err(|e| e.to_string())?;
+        Ok(existing.and_then(|record| record.to_intent()))
+    }
+
+    async fn update(&self, intent: &OrderIntent) -> Result<(), String> {
+        OrderIntentRepository::update(&self.pool, intent)
+            .await
+            .map(|_| ())
+            .map_err(|e| e.to_string())
+    }
+
+    async fn release(&self, key: &str) -> Result<(), String> {
+        OrderIntentRepository::release(&self.pool, key)
+            .await
+            .map(|_| ())
+            .map_err(|e| e.to_string())
+    }
+}
@@ -0,0 +1,71 @@
+//! 호가창 파생 지표 기록 Repository.
+//!
+//! 실시간 호가에서 계산한 미시구조 지표(`orderbook_metrics` hypertable)를 저장합니다.
+//! 체결 타이밍 연구용 원자료이며, 조회는 SQL로 직접 수행합니다.
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use sqlx::PgPool;
+use trader_core::OrderBookMetrics;
+
+/// 호가창 파생 지표 Repository.
+pub struct OrderBookMetricsRepository;
+
+impl OrderBookMetricsRepository {
+    /// 지표 샘플 일괄 저장.
+    ///
+    /// UNNEST 패턴으로 한 번의 쿼리로 저장하며, 같은 심볼·시각의 샘플은 건너뜁니다.
+    pub async fn insert_batch(
+        pool: &PgPool,
+        samples: &[OrderBookMetrics],
+    ) -> Result<u64, sqlx::Error> {
+        if samples.is_empty() {
+            return Ok(0);
+        }
+
+        let tickers: Vec<String> = samples.iter().map(|m| m.ticker.clone()).collect();
+        let recorded_at: Vec<DateTime<Utc>> = samples.iter().map(|m| m.timestamp).collect();
+        let depth_levels: Vec<i16> = samples
+            .iter()
+            .map(|m| i16::try_from(m.depth_levels).unwrap_or(i16::MAX))
+            .collect();
+        let mid_prices: Vec<Decimal> = samples.iter().map(|m| m.mid_price).collect();
+        let microprices: Vec<Decimal> = samples.iter().map(|m| m.microprice).collect();
+        let imbalances: Vec<Decimal> = samples.iter().map(|m| m.imbalance.round_dp(8)).collect();
+        let bid_depths: Vec<Decimal> = samples.iter().map(|m| m.bid_depth).collect();
+        let ask_depths: Vec<Decimal> = samples.iter().map(|m| m.ask_depth).collect();
+        let spreads: Vec<Decimal> = samples.iter().map(|m| m.depth_weighted_spread).collect();
+        let spread_bps: Vec<Decimal> = samples
+            .iter()
+            .map(|m| m.depth_weighted_spread_bps.round_dp(4))
+            .collect();
+
+        let result = sqlx::query(
+            r#"
+            INSERT INTO orderbook_metrics (
+                ticker, recorded_at, depth_levels, mid_price, microprice, imbalance,
+                bid_depth, ask_depth, depth_weighted_spread, depth_weighted_spread_bps
+            )
+            SELECT * FROM UNNEST(
+                $1::text[], $2::timestamptz[], $3::smallint[], $4::numeric[], $5::numeric[],
+                $6::numeric[], $7::numeric[], $8::numeric[], $9::numeric[], $10::numeric[]
+            )
+            ON CONFLICT (ticker, recorded_at) DO NOTHING
+            "#,
+        )
+        .bind(&tickers)
+        .bind(&recorded_at)
+        .bind(&depth_levels)
+        .bind(&mid_prices)
+        .bind(&microprices)
+        .bind(&imbalances)
+        .bind(&bid_depths)
+        .bind(&ask_depths)
+        .bind(&spreads)
+        .bind(&spread_bps)
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+}
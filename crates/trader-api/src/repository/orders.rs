@@ -52,6 +52,28 @@ pub struct OrderInput {
     pub metadata: Option<Value>,
 }
 
+/// 대사용 주문 레코드 (티커 포함).
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct ReconciliationOrder {
+    pub id: Uuid,
+    pub exchange_order_id: String,
+    pub ticker: String,
+    pub status: String,
+    pub quantity: Decimal,
+    pub filled_quantity: Decimal,
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+impl ReconciliationOrder {
+    /// 활성 상태 (pending, open, partially_filled) 여부.
+    pub fn is_active(&self) -> bool {
+        matches!(
+            self.status.as_str(),
+            "pending" | "open" | "partially_filled"
+        )
+    }
+}
+
 /// 주문 상태 열거형.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -267,6 +289,37 @@ impl OrderRepository {
         Ok(records)
     }
 
+    /// 사후 대사 대상 주문 조회.
+    ///
+    /// 거래소 주문 ID가 있는 활성 주문과, 주어진 거래소 주문 ID의 주문을 함께 반환합니다.
+    /// 주문 ID는 앞자리 0을 무시하고 비교하므로 정규화된 ID를 넘겨야 합니다.
+    pub async fn get_for_reconciliation(
+        pool: &PgPool,
+        normalized_exchange_order_ids: &[String],
+    ) -> Result<Vec<ReconciliationOrder>, sqlx::Error> {
+        let records = sqlx::query_as::<_, ReconciliationOrder>(
+            r#"
+            SELECT
+                o.id, o.exchange_order_id, COALESCE(s.base, '') AS ticker,
+                o.status::text AS status, o.quantity,
+                COALESCE(o.filled_quantity, 0) AS filled_quantity, o.created_at
+            FROM orders o
+            LEFT JOIN symbols s ON s.id = o.symbol_id
+            WHERE o.exchange_order_id IS NOT NULL
+              AND (
+                o.status IN ('pending', 'open', 'partially_filled')
+                OR LTRIM(o.exchange_order_id, '0') = ANY($1)
+              )
+            ORDER BY o.created_at
+            "#,
+        )
+        .bind(normalized_exchange_order_ids)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(records)
+    }
+
     /// 거래소 주문 ID로 조회.
     pub async fn get_by_exchange_order_id(
         pool: &PgPool,
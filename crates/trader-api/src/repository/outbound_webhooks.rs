@@ -0,0 +1,225 @@
+//! 아웃바운드 웹훅 Repository.
+//!
+//! 웹훅 설정(`outbound_webhook`)과 전송 로그(`outbound_webhook_delivery`)를 관리합니다.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, PgPool};
+use trader_notification::{WebhookDelivery, WebhookEndpoint, WebhookEvent};
+use uuid::Uuid;
+
+/// 웹훅 설정 레코드.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct OutboundWebhookRecord {
+    pub id: Uuid,
+    /// 웹훅 이름
+    pub name: String,
+    /// 수신 URL
+    pub url: String,
+    /// HMAC 서명 키 (응답에 포함하지 않음)
+    #[serde(skip_serializing, default)]
+    pub secret: Option<String>,
+    /// 구독 이벤트 필터 (비어 있으면 전체)
+    pub events: Vec<String>,
+    /// 활성화 여부
+    pub enabled: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl OutboundWebhookRecord {
+    /// 서명 키 설정 여부.
+    pub fn has_secret(&self) -> bool {
+        self.secret.as_deref().is_some_and(|s| !s.is_empty())
+    }
+
+    /// 전송 대상으로 변환.
+    pub fn endpoint(&self) -> WebhookEndpoint {
+        WebhookEndpoint {
+            url: self.url.clone(),
+            secret: self.secret.clone(),
+            events: self.events.clone(),
+        }
+    }
+}
+
+/// 웹훅 생성/수정 입력.
+#[derive(Debug, Clone)]
+pub struct OutboundWebhookInput {
+    pub name: String,
+    pub url: String,
+    pub secret: Option<String>,
+    pub events: Vec<String>,
+    pub enabled: bool,
+}
+
+/// 웹훅 전송 로그 레코드.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct WebhookDeliveryRecord {
+    pub id: Uuid,
+    pub webhook_id: Uuid,
+    /// 이벤트 ID
+    pub event_id: String,
+    /// 이벤트 타입
+    pub event_type: String,
+    /// 전송 본문
+    pub payload: serde_json::Value,
+    /// 성공 여부
+    pub success: bool,
+    /// 시도 횟수
+    pub attempts: i32,
+    /// 마지막 HTTP 상태 코드
+    pub status_code: Option<i32>,
+    /// 마지막 실패 사유
+    pub error: Option<String>,
+    /// 소요 시간 (ms)
+    pub duration_ms: i64,
+    pub created_at: DateTime<Utc>,
+}
+
+const WEBHOOK_COLUMNS: &str = "id, name, url, secret, events, enabled, created_at, updated_at";
+
+/// 아웃바운드 웹훅 Repository.
+pub struct OutboundWebhookRepository;
+
+impl OutboundWebhookRepository {
+    /// 전체 웹훅 조회 (이름순).
+    pub async fn list(pool: &PgPool) -> Result<Vec<OutboundWebhookRecord>, sqlx::Error> {
+        sqlx::query_as::<_, OutboundWebhookRecord>(&format!(
+            "SELECT {WEBHOOK_COLUMNS} FROM outbound_webhook ORDER BY name"
+        ))
+        .fetch_all(pool)
+        .await
+    }
+
+    /// 활성화된 웹훅 조회.
+    pub async fn list_enabled(pool: &PgPool) -> Result<Vec<OutboundWebhookRecord>, sqlx::Error> {
+        sqlx::query_as::<_, OutboundWebhookRecord>(&format!(
+            "SELECT {WEBHOOK_COLUMNS} FROM outbound_webhook WHERE enabled ORDER BY name"
+        ))
+        .fetch_all(pool)
+        .await
+    }
+
+    /// 단일 웹훅 조회.
+    pub async fn get(
+        pool: &PgPool,
+        id: Uuid,
+    ) -> Result<Option<OutboundWebhookRecord>, sqlx::Error> {
+        sqlx::query_as::<_, OutboundWebhookRecord>(&format!(
+            "SELECT {WEBHOOK_COLUMNS} FROM outbound_webhook WHERE id = $1"
+        ))
+        .bind(id)
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// 웹훅 생성.
+    pub async fn create(
+        pool: &PgPool,
+        input: &OutboundWebhookInput,
+    ) -> Result<OutboundWebhookRecord, sqlx::Error> {
+        sqlx::query_as::<_, OutboundWebhookRecord>(&format!(
+            r#"
+            INSERT INTO outbound_webhook (name, url, secret, events, enabled)
+            VALUES ($1, $2, $3, $4, $5)
+            RETURNING {WEBHOOK_COLUMNS}
+            "#
+        ))
+        .bind(&input.name)
+        .bind(&input.url)
+        .bind(&input.secret)
+        .bind(&input.events)
+        .bind(input.enabled)
+        .fetch_one(pool)
+        .await
+    }
+
+    /// 웹훅 수정.
+    ///
+    /// `input.secret`이 `None`이면 기존 서명 키를 유지합니다.
+    pub async fn update(
+        pool: &PgPool,
+        id: Uuid,
+        input: &OutboundWebhookInput,
+    ) -> Result<Option<OutboundWebhookRecord>, sqlx::Error> {
+        sqlx::query_as::<_, OutboundWebhookRecord>(&format!(
+            r#"
+            UPDATE outbound_webhook
+            SET name = $2, url = $3, secret = COALESCE($4, secret), events = $5, enabled = $6
+            WHERE id = $1
+            RETURNING {WEBHOOK_COLUMNS}
+            "#
+        ))
+        .bind(id)
+        .bind(&input.name)
+        .bind(&input.url)
+        .bind(&input.secret)
+        .bind(&input.events)
+        .bind(input.enabled)
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// 웹훅 삭제 (전송 로그 포함).
+    ///
+    /// # Returns
+    /// 삭제 여부
+    pub async fn delete(pool: &PgPool, id: Uuid) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query("DELETE FROM outbound_webhook WHERE id = $1")
+            .bind(id)
+            .execute(pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// 전송 결과 기록.
+    pub async fn record_delivery(
+        pool: &PgPool,
+        webhook_id: Uuid,
+        event: &WebhookEvent,
+        delivery: &WebhookDelivery,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            INSERT INTO outbound_webhook_delivery
+                (webhook_id, event_id, event_type, payload, success, attempts, status_code, error, duration_ms)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            "#,
+        )
+        .bind(webhook_id)
+        .bind(&event.id)
+        .bind(&event.event)
+        .bind(serde_json::to_value(event).unwrap_or_default())
+        .bind(delivery.success)
+        .bind(delivery.attempts as i32)
+        .bind(delivery.status_code.map(i32::from))
+        .bind(&delivery.error)
+        .bind(delivery.duration_ms as i64)
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    /// 전송 로그 조회 (최신순).
+    pub async fn list_deliveries(
+        pool: &PgPool,
+        webhook_id: Uuid,
+        limit: i64,
+    ) -> Result<Vec<WebhookDeliveryRecord>, sqlx::Error> {
+        sqlx::query_as::<_, WebhookDeliveryRecord>(
+            r#"
+            SELECT id, webhook_id, event_id, event_type, payload, success, attempts,
+                   status_code, error, duration_ms, created_at
+            FROM outbound_webhook_delivery
+            WHERE webhook_id = $1
+            ORDER BY created_at DESC
+            LIMIT $2
+            "#,
+        )
+        .bind(webhook_id)
+        .bind(limit)
+        .fetch_all(pool)
+        .await
+    }
+}
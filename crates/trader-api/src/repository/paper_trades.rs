@@ -0,0 +1,96 @@
+//! 모의투자 체결 내역 Repository.
+//!
+//! 모의투자 모드 전략이 시뮬레이션 거래소 가상 계좌에서 체결한 내역(`paper_trades`)을
+//! 저장하고 조회합니다.
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::Serialize;
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+/// 모의투자 체결 레코드.
+#[derive(Debug, Clone, Serialize, FromRow)]
+pub struct PaperTradeRecord {
+    pub id: Uuid,
+    pub strategy_id: String,
+    /// 가상 계좌 주문 ID
+    pub order_id: String,
+    pub signal_id: Option<Uuid>,
+    pub ticker: String,
+    /// buy / sell
+    pub side: String,
+    pub signal_type: String,
+    pub quantity: Decimal,
+    pub price: Decimal,
+    pub commission: Decimal,
+    pub executed_at: DateTime<Utc>,
+}
+
+/// 모의투자 체결 입력.
+#[derive(Debug, Clone)]
+pub struct PaperTradeInput {
+    pub strategy_id: String,
+    pub order_id: String,
+    pub signal_id: Option<Uuid>,
+    pub ticker: String,
+    pub side: String,
+    pub signal_type: String,
+    pub quantity: Decimal,
+    pub price: Decimal,
+    pub commission: Decimal,
+    pub executed_at: DateTime<Utc>,
+}
+
+/// 모의투자 체결 내역 Repository.
+pub struct PaperTradeRepository;
+
+impl PaperTradeRepository {
+    /// 체결 내역 저장.
+    pub async fn insert(pool: &PgPool, trade: &PaperTradeInput) -> Result<Uuid, sqlx::Error> {
+        sqlx::query_scalar::<_, Uuid>(
+            r#"
+            INSERT INTO paper_trades (
+                strategy_id, order_id, signal_id, ticker, side, signal_type,
+                quantity, price, commission, executed_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            RETURNING id
+            "#,
+        )
+        .bind(&trade.strategy_id)
+        .bind(&trade.order_id)
+        .bind(trade.signal_id)
+        .bind(&trade.ticker)
+        .bind(&trade.side)
+        .bind(&trade.signal_type)
+        .bind(trade.quantity)
+        .bind(trade.price)
+        .bind(trade.commission)
+        .bind(trade.executed_at)
+        .fetch_one(pool)
+        .await
+    }
+
+    /// 전략의 최근 체결 내역 조회 (최신순).
+    pub async fn list_by_strategy(
+        pool: &PgPool,
+        strategy_id: &str,
+        limit: i64,
+    ) -> Result<Vec<PaperTradeRecord>, sqlx::Error> {
+        sqlx::query_as::<_, PaperTradeRecord>(
+            r#"
+            SELECT id, strategy_id, order_id, signal_id, ticker, side, signal_type,
+                   quantity, price, commission, executed_at
+            FROM paper_trades
+            WHERE strategy_id = $1
+            ORDER BY executed_at DESC
+            LIMIT $2
+            "#,
+        )
+        .bind(strategy_id)
+        .bind(limit)
+        .fetch_all(pool)
+        .await
+    }
+}
@@ -1,12 +1,15 @@
 //! 포트폴리오 저장소.
 //!
 //! 전략별 포지션 관리를 위한 데이터베이스 작업을 처리합니다.
+//! 여러 계좌(모의/실전 일반/ISA)를 동시에 운용할 때는 계좌별 포지션을 함께 조회합니다.
 
 use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use sqlx::{FromRow, PgPool};
+use trader_core::Side;
+use trader_execution::AccountPositionLeg;
 use uuid::Uuid;
 
 /// 포지션 레코드.
@@ -45,6 +48,51 @@ pub struct PositionUpdate {
     pub metadata: Option<Value>,
 }
 
+/// 계좌 정보를 포함한 열린 포지션.
+#[derive(Debug, Clone, FromRow)]
+pub struct AccountPositionRecord {
+    pub credential_id: Uuid,
+    /// 계좌 표시 이름 (예: "KIS ISA")
+    pub account_name: String,
+    /// paper / real_general / real_isa
+    pub account_type: String,
+    pub exchange: String,
+    pub symbol: Option<String>,
+    pub symbol_name: Option<String>,
+    pub side: String,
+    pub quantity: Decimal,
+    pub entry_price: Decimal,
+    pub current_price: Option<Decimal>,
+    pub unrealized_pnl: Option<Decimal>,
+    pub realized_pnl: Option<Decimal>,
+}
+
+impl AccountPositionRecord {
+    /// 상계 입력으로 변환 (계좌 키는 credential ID, 종목/방향이 없으면 `None`).
+    pub fn to_leg(&self) -> Option<AccountPositionLeg> {
+        let ticker = self.symbol.clone()?;
+        let side = Side::from_str_flexible(&self.side).ok()?;
+        let unrealized_pnl = self.unrealized_pnl.unwrap_or_else(|| {
+            let mark = self.current_price.unwrap_or(self.entry_price);
+            match side {
+                Side::Buy => (mark - self.entry_price) * self.quantity,
+                Side::Sell => (self.entry_price - mark) * self.quantity,
+            }
+        });
+
+        Some(AccountPositionLeg {
+            account: self.credential_id.to_string(),
+            ticker,
+            side,
+            quantity: self.quantity,
+            entry_price: self.entry_price,
+            current_price: self.current_price,
+            unrealized_pnl,
+            realized_pnl: self.realized_pnl.unwrap_or(Decimal::ZERO),
+        })
+    }
+}
+
 /// 포트폴리오 저장소.
 pub struct PortfolioRepository;
 
@@ -196,4 +244,37 @@ impl PortfolioRepository {
 
         Ok(result.0.unwrap_or_default())
     }
+
+    /// 활성 계좌들의 열린 포지션 조회 (계좌 유형 포함).
+    ///
+    /// 계좌 유형은 자격증명의 모의투자 여부와 ISA 표시(`settings.account_type = 'isa'`
+    /// 또는 이름의 "ISA")로 정합니다.
+    pub async fn get_account_positions(
+        pool: &PgPool,
+    ) -> Result<Vec<AccountPositionRecord>, sqlx::Error> {
+        let records = sqlx::query_as::<_, AccountPositionRecord>(
+            r#"
+            SELECT
+                c.id AS credential_id,
+                c.exchange_name AS account_name,
+                CASE
+                    WHEN c.is_testnet THEN 'paper'
+                    WHEN c.settings->>'account_type' = 'isa'
+                        OR UPPER(c.exchange_name) LIKE '%ISA%' THEN 'real_isa'
+                    ELSE 'real_general'
+                END AS account_type,
+                p.exchange, p.symbol, p.symbol_name, p.side::text AS side,
+                p.quantity, p.entry_price, p.current_price,
+                p.unrealized_pnl, p.realized_pnl
+            FROM positions p
+            JOIN exchange_credentials c ON c.id = p.credential_id
+            WHERE p.closed_at IS NULL AND p.quantity > 0 AND c.is_active = true
+            ORDER BY c.exchange_name, p.symbol
+            "#,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(records)
+    }
 }
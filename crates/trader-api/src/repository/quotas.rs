@@ -0,0 +1,76 @@
+//! 사용자별 리소스 할당량 Repository.
+//!
+//! 사용자 등급/개별 한도(`user_quotas`)와, 할당량 판정에 필요한 사용량
+//! (등록 전략 수, 백테스트 결과 저장 용량)을 조회합니다.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, PgPool};
+
+/// 사용자 할당량 레코드.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct UserQuotaRecord {
+    /// JWT sub
+    pub user_id: String,
+    /// free / standard / pro / unlimited
+    pub tier: String,
+    /// 이하 NULL이면 등급 기본값 사용
+    pub max_concurrent_backtests: Option<i32>,
+    pub max_strategies: Option<i32>,
+    pub requests_per_minute: Option<i32>,
+    pub storage_mb: Option<i64>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// 사용자별 할당량 Repository.
+pub struct QuotaRepository;
+
+impl QuotaRepository {
+    /// 사용자 할당량 조회 (행이 없으면 None).
+    pub async fn get(pool: &PgPool, user_id: &str) -> Result<Option<UserQuotaRecord>, sqlx::Error> {
+        sqlx::query_as::<_, UserQuotaRecord>(
+            r#"
+            SELECT user_id, tier, max_concurrent_backtests, max_strategies,
+                   requests_per_minute, storage_mb, created_at, updated_at
+            FROM user_quotas
+            WHERE user_id = $1
+            "#,
+        )
+        .bind(user_id)
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// 사용자가 등록한 전략 수.
+    pub async fn count_strategies(pool: &PgPool, owner_id: &str) -> Result<i64, sqlx::Error> {
+        let row: (i64,) = sqlx::query_as("SELECT COUNT(*) FROM strategies WHERE owner_id = $1")
+            .bind(owner_id)
+            .fetch_one(pool)
+            .await?;
+        Ok(row.0)
+    }
+
+    /// 소유자 없이(비인증으로) 등록된 전략 수.
+    pub async fn count_unowned_strategies(pool: &PgPool) -> Result<i64, sqlx::Error> {
+        let row: (i64,) = sqlx::query_as("SELECT COUNT(*) FROM strategies WHERE owner_id IS NULL")
+            .fetch_one(pool)
+            .await?;
+        Ok(row.0)
+    }
+
+    /// 사용자가 저장한 백테스트 결과의 저장 용량 (바이트, 행 크기 합계).
+    pub async fn storage_bytes(pool: &PgPool, owner_id: &str) -> Result<i64, sqlx::Error> {
+        let row: (Option<i64>,) = sqlx::query_as(
+            r#"
+            SELECT SUM(pg_column_size(b.*))::BIGINT
+            FROM backtest_results b
+            WHERE b.owner_id = $1 AND b.deleted_at IS NULL
+            "#,
+        )
+        .bind(owner_id)
+        .fetch_one(pool)
+        .await?;
+        Ok(row.0.unwrap_or(0))
+    }
+}
@@ -280,6 +280,17 @@ impl RealityCheckRepository {
         Ok(snapshots)
     }
 
+    /// 지정 날짜 이전의 가장 최근 스냅샷 날짜 (휴장일을 건너뛴 직전 추천일)
+    pub async fn latest_snapshot_date_before(
+        pool: &PgPool,
+        before: NaiveDate,
+    ) -> Result<Option<NaiveDate>, sqlx::Error> {
+        sqlx::query_scalar("SELECT MAX(snapshot_date) FROM price_snapshot WHERE snapshot_date < $1")
+            .bind(before)
+            .fetch_one(pool)
+            .await
+    }
+
     // ==================== Reality Check 계산 ====================
 
     /// Reality Check 계산 실행
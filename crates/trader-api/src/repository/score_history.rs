@@ -7,9 +7,12 @@ use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use sqlx::PgPool;
 use tracing::{debug, warn};
+use trader_analytics::{DailyRanking, SevenFactorScores};
 use ts_rs::TS;
 use utoipa::ToSchema;
 
+use super::global_score::SevenFactorData;
+
 /// Score History 레코드.
 ///
 /// 참고: 테이블이 TimescaleDB Hypertable로 변환되어 PRIMARY KEY가 (score_date, symbol)로 변경됨
@@ -48,6 +51,35 @@ pub struct ScoreHistorySummary {
     pub rank_change: Option<i32>,
 }
 
+/// 팩터별 전일 대비 변화 (API 응답용).
+#[derive(Debug, Clone, Serialize, Deserialize, TS, ToSchema)]
+#[ts(export, export_to = "repository/")]
+pub struct FactorChangeSummary {
+    /// 팩터 이름 (예: "NORM_MOMENTUM")
+    pub factor: String,
+    /// 팩터 점수 변화량
+    pub change: f64,
+    /// 종합 점수 기여도 변화량 (변화량 × 가중치)
+    pub contribution: f64,
+}
+
+/// 일별 7Factor 점수 히스토리 (API 응답용).
+#[derive(Debug, Clone, Serialize, Deserialize, TS, ToSchema)]
+#[ts(export, export_to = "repository/")]
+pub struct FactorHistoryPoint {
+    pub score_date: NaiveDate,
+    pub global_score: Option<f64>,
+    /// 7Factor 종합 점수 (가중 평균)
+    pub composite_score: f64,
+    pub factors: SevenFactorData,
+    /// 전일 대비 종합 점수 변화
+    pub composite_change: Option<f64>,
+    /// 전일 대비 팩터별 변화 (기여도 절대값 내림차순, 첫 기록일은 빈 목록)
+    pub factor_changes: Vec<FactorChangeSummary>,
+    /// 종합 점수 변화를 가장 크게 이끈 팩터
+    pub top_driver: Option<String>,
+}
+
 /// Score History 저장소.
 pub struct ScoreHistoryRepository;
 
@@ -181,6 +213,114 @@ impl ScoreHistoryRepository {
         Ok(summaries)
     }
 
+    /// 종목별 7Factor 점수 히스토리 조회 (전일 대비 팩터 변화 포함).
+    ///
+    /// `component_scores`에 7Factor 점수가 기록된 날짜만 포함하며, 최신 날짜부터 반환합니다.
+    pub async fn get_factor_history(
+        pool: &PgPool,
+        symbol: &str,
+        days: i32,
+    ) -> Result<Vec<FactorHistoryPoint>, sqlx::Error> {
+        use rust_decimal::prelude::ToPrimitive;
+
+        let records = Self::get_by_symbol(pool, symbol, days).await?;
+
+        let daily: Vec<(&ScoreHistoryRecord, SevenFactorScores)> = records
+            .iter()
+            .filter_map(|record| {
+                let value = record.component_scores.clone()?;
+                match serde_json::from_value::<SevenFactorScores>(value) {
+                    Ok(scores) => Some((record, scores)),
+                    Err(_) => {
+                        debug!(
+                            symbol = %record.symbol,
+                            date = %record.score_date,
+                            "7Factor 점수 없음"
+                        );
+                        None
+                    }
+                }
+            })
+            .collect();
+
+        let mut points = Vec::with_capacity(daily.len());
+        for (i, (record, scores)) in daily.iter().enumerate() {
+            let composite = scores.composite_score();
+            let prev = daily.get(i + 1).map(|(_, prev)| prev);
+
+            let changes = prev
+                .map(|prev| scores.factor_changes(prev))
+                .unwrap_or_default();
+            let top_driver = changes
+                .first()
+                .filter(|c| !c.contribution.is_zero())
+                .map(|c| c.factor.clone());
+
+            points.push(FactorHistoryPoint {
+                score_date: record.score_date,
+                global_score: record.global_score.and_then(|d| d.to_f64()),
+                composite_score: composite.to_f64().unwrap_or(0.0),
+                factors: scores.clone().into(),
+                composite_change: prev
+                    .map(|prev| (composite - prev.composite_score()).to_f64().unwrap_or(0.0)),
+                factor_changes: changes
+                    .into_iter()
+                    .map(|c| FactorChangeSummary {
+                        factor: c.factor,
+                        change: c.change.to_f64().unwrap_or(0.0),
+                        contribution: c.contribution.to_f64().unwrap_or(0.0),
+                    })
+                    .collect(),
+                top_driver,
+            });
+        }
+
+        Ok(points)
+    }
+
+    /// 기간 내 일별 상위 N개 종목 목록 조회 (생존일 추적용).
+    ///
+    /// 날짜별로 global_score 내림차순 상위 `top_n`개 종목을 모아 날짜 오름차순으로 반환합니다.
+    pub async fn get_daily_rankings(
+        pool: &PgPool,
+        since: NaiveDate,
+        top_n: i32,
+    ) -> Result<Vec<DailyRanking>, sqlx::Error> {
+        let rows: Vec<(NaiveDate, String)> = sqlx::query_as(
+            r#"
+            SELECT score_date, symbol
+            FROM (
+                SELECT score_date, symbol,
+                       ROW_NUMBER() OVER (
+                           PARTITION BY score_date
+                           ORDER BY global_score DESC NULLS LAST, symbol
+                       ) AS rn
+                FROM score_history
+                WHERE score_date >= $1
+            ) ranked
+            WHERE rn <= $2
+            ORDER BY score_date, rn
+            "#,
+        )
+        .bind(since)
+        .bind(top_n as i64)
+        .fetch_all(pool)
+        .await?;
+
+        let mut rankings: Vec<DailyRanking> = Vec::new();
+        for (date, symbol) in rows {
+            match rankings.last_mut() {
+                Some(last) if last.date == date => last.tickers.push(symbol),
+                _ => rankings.push(DailyRanking {
+                    date,
+                    tickers: vec![symbol],
+                }),
+            }
+        }
+
+        Ok(rankings)
+    }
+
     /// 가장 최근 저장 날짜 조회.
     pub async fn get_latest_date(pool: &PgPool) -> Result<Option<NaiveDate>, sqlx::Error> {
         let result: Option<(NaiveDate,)> = sqlx::query_as(
@@ -86,6 +86,12 @@ pub struct ScreeningResult {
     pub ttm_squeeze: Option<bool>,
     pub ttm_squeeze_cnt: Option<i32>,
 
+    // 실현 변동성 (연율화 %) / 시장 베타 (수집기 일배치 계산값)
+    pub realized_vol_20d: Option<f64>,
+    pub realized_vol_60d: Option<f64>,
+    pub realized_vol_252d: Option<f64>,
+    pub beta: Option<f64>,
+
     // TRIGGER (진입 트리거)
     pub trigger_score: Option<f64>,
     pub trigger_label: Option<String>,
@@ -168,8 +174,14 @@ pub struct ScreeningFilter {
     pub filter_ttm_squeeze: Option<bool>, // true: squeeze 상태인 종목만
     pub min_ttm_squeeze_cnt: Option<i32>, // 최소 squeeze 카운트 (에너지 응축 기간)
 
+    // 실현 변동성 / 베타 필터
+    pub min_realized_vol: Option<f64>, // 60일 실현 변동성 하한 (연율화 %)
+    pub max_realized_vol: Option<f64>, // 60일 실현 변동성 상한 (연율화 %)
+    pub min_beta: Option<f64>,
+    pub max_beta: Option<f64>,
+
     // 정렬 및 제한
-    pub sort_by: Option<String>, // market_cap, per, pbr, roe, price_change_1d, volume_ratio
+    pub sort_by: Option<String>, // market_cap, per, pbr, roe, price_change_1d, volume_ratio, realized_vol, beta
     pub sort_order: Option<String>, // asc, desc
     pub limit: Option<i32>,
     pub offset: Option<i32>,
@@ -240,6 +252,10 @@ impl ScreeningRepository {
                 NULL::integer as sector_rank,
                 sf.ttm_squeeze as ttm_squeeze,
                 sf.ttm_squeeze_cnt as ttm_squeeze_cnt,
+                sf.realized_vol_20d,
+                sf.realized_vol_60d,
+                sf.realized_vol_252d,
+                sf.beta,
                 NULL::double precision as trigger_score,
                 NULL::varchar as trigger_label,
                 sgs.overall_score,
@@ -265,6 +281,8 @@ impl ScreeningRepository {
             "pbr" => builder.push("sf.pbr"),
             "roe" => builder.push("sf.roe"),
             "dividend_yield" => builder.push("sf.dividend_yield"),
+            "realized_vol" => builder.push("sf.realized_vol_60d"),
+            "beta" => builder.push("sf.beta"),
             "price_change_1d" => builder.push("lp.close"), // TODO: 실제 변동률로 변경
             _ => builder.push("sf.market_cap"),
         };
@@ -446,6 +464,24 @@ impl ScreeningRepository {
             builder.push(" AND sf.ttm_squeeze_cnt >= ");
             builder.push_bind(min_cnt);
         }
+
+        // 실현 변동성 / 베타 필터 (수집기 일배치 계산값)
+        if let Some(v) = filter.min_realized_vol {
+            builder.push(" AND sf.realized_vol_60d >= ");
+            builder.push_bind(v);
+        }
+        if let Some(v) = filter.max_realized_vol {
+            builder.push(" AND sf.realized_vol_60d <= ");
+            builder.push_bind(v);
+        }
+        if let Some(v) = filter.min_beta {
+            builder.push(" AND sf.beta >= ");
+            builder.push_bind(v);
+        }
+        if let Some(v) = filter.max_beta {
+            builder.push(" AND sf.beta <= ");
+            builder.push_bind(v);
+        }
     }
 
     /// 구조적 피처 기반 필터링 적용 (7단계)
@@ -1043,6 +1079,86 @@ impl ScreeningRepository {
         Ok(results)
     }
 
+    /// 투자자별 순매수 스크리닝 (외국인/기관 수급)
+    ///
+    /// 최근 `days` 거래일 동안의 투자자별 순매수 합계를 계산하여
+    /// `sort_by` 기준(foreign, institution, individual, smart_money)으로 내림차순 정렬합니다.
+    pub async fn screen_investor_flow(
+        pool: &PgPool,
+        days: i32,
+        sort_by: &str,
+        min_buy_days: Option<i32>,
+        limit: i32,
+    ) -> Result<Vec<InvestorFlowScreenResult>, sqlx::Error> {
+        // 정렬 컬럼은 화이트리스트로만 허용
+        let order_column = match sort_by {
+            "foreign" => "f.foreign_net",
+            "institution" => "f.institution_net",
+            "individual" => "f.individual_net",
+            _ => "f.smart_money_net",
+        };
+
+        let query = format!(
+            r#"
+            WITH recent_dates AS (
+                SELECT DISTINCT trade_date
+                FROM symbol_investor_flow
+                ORDER BY trade_date DESC
+                LIMIT $1
+            ),
+            flows AS (
+                SELECT
+                    sif.ticker,
+                    COUNT(*)::int as days,
+                    SUM(sif.foreign_net) as foreign_net,
+                    SUM(sif.institution_net) as institution_net,
+                    SUM(sif.individual_net) as individual_net,
+                    SUM(sif.foreign_net + sif.institution_net) as smart_money_net,
+                    COUNT(*) FILTER (WHERE sif.foreign_net > 0)::int as foreign_buy_days,
+                    COUNT(*) FILTER (WHERE sif.institution_net > 0)::int as institution_buy_days,
+                    MAX(sif.trade_date) as last_trade_date
+                FROM symbol_investor_flow sif
+                JOIN recent_dates rd ON rd.trade_date = sif.trade_date
+                GROUP BY sif.ticker
+            )
+            SELECT
+                f.ticker,
+                COALESCE(si.name, f.ticker) as name,
+                si.exchange,
+                f.days,
+                f.foreign_net,
+                f.institution_net,
+                f.individual_net,
+                f.smart_money_net,
+                f.foreign_buy_days,
+                f.institution_buy_days,
+                f.last_trade_date
+            FROM flows f
+            LEFT JOIN symbol_info si ON si.ticker = f.ticker AND si.market = 'KR'
+            WHERE (si.is_active = true OR si.id IS NULL)
+              AND ($2::int IS NULL OR GREATEST(f.foreign_buy_days, f.institution_buy_days) >= $2)
+            ORDER BY {} DESC
+            LIMIT $3
+            "#,
+            order_column
+        );
+
+        let results = sqlx::query_as::<_, InvestorFlowScreenResult>(&query)
+            .bind(days)
+            .bind(min_buy_days)
+            .bind(limit)
+            .fetch_all(pool)
+            .await?;
+
+        debug!(
+            "투자자별 순매수 스크리닝 완료: {}일, 정렬={}, {} 종목",
+            days,
+            sort_by,
+            results.len()
+        );
+        Ok(results)
+    }
+
     /// 사용 가능한 프리셋 목록 반환
     pub fn available_presets() -> Vec<ScreeningPreset> {
         vec![
@@ -1100,6 +1216,30 @@ pub struct MomentumScreenResult {
     pub volume_ratio: Decimal,
 }
 
+/// 투자자별 순매수 스크리닝 결과
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct InvestorFlowScreenResult {
+    pub ticker: String,
+    pub name: String,
+    pub exchange: Option<String>,
+    /// 집계 거래일 수
+    pub days: i32,
+    /// 외국인 순매수 합계 (원)
+    pub foreign_net: Decimal,
+    /// 기관 순매수 합계 (원)
+    pub institution_net: Decimal,
+    /// 개인 순매수 합계 (원)
+    pub individual_net: Decimal,
+    /// 외국인 + 기관 순매수 합계 (원)
+    pub smart_money_net: Decimal,
+    /// 외국인 순매수 일수
+    pub foreign_buy_days: i32,
+    /// 기관 순매수 일수
+    pub institution_buy_days: i32,
+    /// 마지막 거래일
+    pub last_trade_date: chrono::NaiveDate,
+}
+
 /// 섹터 상대강도 결과
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
 pub struct SectorRsResult {
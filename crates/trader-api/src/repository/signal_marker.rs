@@ -169,7 +169,7 @@ impl SignalMarkerRepository {
 
         markers
             .into_iter()
-            .map(|row| row.to_signal_marker())
+            .map(SignalMarkerRow::to_signal_marker)
             .collect::<Result<Vec<_>, _>>()
     }
 
@@ -220,7 +220,7 @@ impl SignalMarkerRepository {
 
         markers
             .into_iter()
-            .map(|row| row.to_signal_marker())
+            .map(SignalMarkerRow::to_signal_marker)
             .collect::<Result<Vec<_>, _>>()
     }
 
@@ -280,7 +280,7 @@ impl SignalMarkerRepository {
 
         markers
             .into_iter()
-            .map(|row| row.to_signal_marker())
+            .map(SignalMarkerRow::to_signal_marker)
             .collect::<Result<Vec<_>, _>>()
     }
 
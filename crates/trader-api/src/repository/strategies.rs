@@ -7,6 +7,9 @@ use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use sqlx::PgPool;
+use trader_core::{EarningsFilterConfig, TradingCostModel};
+use trader_risk::CurfewOverride;
+use trader_strategy::{SymbolLockConfig, TradingWindowConfig};
 
 /// Database representation of a strategy.
 #[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
@@ -29,6 +32,40 @@ pub struct StrategyRecord {
     /// Multi-timeframe configuration (NULL = single timeframe strategy)
     /// Format: {"primary": "5m", "secondary": [{"timeframe": "1h", "candle_count": 24}]}
     pub multi_timeframe_config: Option<Value>,
+    /// Trading cost model (NULL = server default)
+    #[sqlx(default)]
+    pub cost_model: Option<Value>,
+    /// Allow US extended-hours trading (daytime, pre-market, after-hours)
+    #[sqlx(default)]
+    #[serde(default)]
+    pub extended_hours: bool,
+    /// Earnings filter (NULL = not applied)
+    /// Format: {"entry_blackout_days": 3, "exit_before_days": 1}
+    #[sqlx(default)]
+    pub earnings_filter: Option<Value>,
+    /// Symbol lock (NULL = not applied)
+    /// Format: {"policy": "exclusive", "max_symbols": 5}
+    #[sqlx(default)]
+    pub symbol_lock: Option<Value>,
+    /// Intraday curfew override (NULL = executor default windows)
+    /// Format: {"exempt": false, "skip_windows": ["krx_open"], "extra_windows": []}
+    #[sqlx(default)]
+    pub curfew_override: Option<Value>,
+    /// Exclude from the execution governor (volatility/spread throttling)
+    #[sqlx(default)]
+    #[serde(default)]
+    pub execution_governor_opt_out: bool,
+    /// Paper trading mode (signals fill on a simulated account instead of the broker)
+    #[sqlx(default)]
+    #[serde(default)]
+    pub paper_trading: bool,
+    /// Config schema version (NULL = 1, upgraded on load via `Strategy::config_migrations`)
+    #[sqlx(default)]
+    pub config_version: Option<i32>,
+    /// Trading window (NULL = no restriction)
+    /// Format: {"market": "KR", "start": "09:05:00", "end": "15:20:00", "flatten_outside": true}
+    #[sqlx(default)]
+    pub trading_window: Option<Value>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub last_started_at: Option<DateTime<Utc>>,
@@ -55,6 +92,10 @@ pub struct CreateStrategyInput {
     /// Multi-timeframe configuration (optional)
     /// Format: {"primary": "5m", "secondary": [{"timeframe": "1h", "candle_count": 24}]}
     pub multi_timeframe_config: Option<Value>,
+    /// Symbol lock configuration (optional)
+    pub symbol_lock: Option<SymbolLockConfig>,
+    /// Owning user (JWT sub) for quota accounting (optional)
+    pub owner_id: Option<String>,
 }
 
 /// Strategy repository for database operations.
@@ -71,13 +112,17 @@ impl StrategyRepository {
         let symbols_json = serde_json::to_value(&input.symbols).unwrap_or(Value::Array(vec![]));
         let risk_limits = input.risk_config.unwrap_or_else(|| serde_json::json!({}));
         let risk_profile = input.risk_profile.unwrap_or_else(|| "default".to_string());
+        let symbol_lock = input
+            .symbol_lock
+            .and_then(|lock| serde_json::to_value(lock).ok());
+        let config_version = Self::current_config_version(&input.strategy_type);
 
         let mut tx = pool.begin().await?;
 
         let record = sqlx::query_as::<_, StrategyRecord>(
             r#"
-            INSERT INTO strategies (id, name, description, strategy_type, symbols, market, timeframe, config, risk_limits, allocated_capital, risk_profile, multi_timeframe_config, is_active)
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, false)
+            INSERT INTO strategies (id, name, description, strategy_type, symbols, market, timeframe, config, risk_limits, allocated_capital, risk_profile, multi_timeframe_config, symbol_lock, owner_id, config_version, is_active)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, false)
             RETURNING *
             "#
         )
@@ -93,6 +138,9 @@ impl StrategyRepository {
         .bind(input.allocated_capital)
         .bind(&risk_profile)
         .bind(&input.multi_timeframe_config)
+        .bind(&symbol_lock)
+        .bind(&input.owner_id)
+        .bind(config_version)
         .fetch_one(&mut *tx)
         .await?;
 
@@ -124,6 +172,8 @@ impl StrategyRepository {
 
     /// Update strategy configuration.
     ///
+    /// The config is expected in the strategy's current schema, so the stored
+    /// config version is bumped to the current version as well.
     /// Uses a transaction to ensure atomicity of the UPDATE operation.
     pub async fn update_config(
         pool: &PgPool,
@@ -132,16 +182,24 @@ impl StrategyRepository {
     ) -> Result<StrategyRecord, sqlx::Error> {
         let mut tx = pool.begin().await?;
 
+        let strategy_type: Option<String> =
+            sqlx::query_scalar("SELECT strategy_type FROM strategies WHERE id = $1")
+                .bind(id)
+                .fetch_one(&mut *tx)
+                .await?;
+        let config_version = Self::current_config_version(strategy_type.as_deref().unwrap_or(""));
+
         let record = sqlx::query_as::<_, StrategyRecord>(
             r#"
             UPDATE strategies
-            SET config = $2, updated_at = NOW()
+            SET config = $2, config_version = $3, updated_at = NOW()
             WHERE id = $1
             RETURNING *
             "#,
         )
         .bind(id)
         .bind(config)
+        .bind(config_version)
         .fetch_one(&mut *tx)
         .await?;
 
@@ -281,6 +339,331 @@ impl StrategyRepository {
         Ok(record)
     }
 
+    /// Update strategy trading cost model (`None` resets to the server default).
+    pub async fn update_cost_model(
+        pool: &PgPool,
+        id: &str,
+        cost_model: Option<Value>,
+    ) -> Result<StrategyRecord, sqlx::Error> {
+        let record = sqlx::query_as::<_, StrategyRecord>(
+            r#"
+            UPDATE strategies
+            SET cost_model = $2, updated_at = NOW()
+            WHERE id = $1
+            RETURNING *
+            "#,
+        )
+        .bind(id)
+        .bind(cost_model)
+        .fetch_one(pool)
+        .await?;
+
+        Ok(record)
+    }
+
+    /// Load per-strategy trading cost models.
+    ///
+    /// Rows whose model cannot be parsed are skipped with a warning.
+    pub async fn load_cost_models(
+        pool: &PgPool,
+    ) -> Result<Vec<(String, TradingCostModel)>, sqlx::Error> {
+        let rows: Vec<(String, Value)> = sqlx::query_as(
+            r#"
+            SELECT id, cost_model
+            FROM strategies
+            WHERE cost_model IS NOT NULL
+            "#,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .filter_map(|(id, value)| match serde_json::from_value(value) {
+                Ok(model) => Some((id, model)),
+                Err(e) => {
+                    tracing::warn!(strategy_id = %id, error = %e, "Invalid cost model, skipping");
+                    None
+                }
+            })
+            .collect())
+    }
+
+    /// Update whether the strategy may trade US extended hours.
+    pub async fn update_extended_hours(
+        pool: &PgPool,
+        id: &str,
+        extended_hours: bool,
+    ) -> Result<StrategyRecord, sqlx::Error> {
+        let record = sqlx::query_as::<_, StrategyRecord>(
+            r#"
+            UPDATE strategies
+            SET extended_hours = $2, updated_at = NOW()
+            WHERE id = $1
+            RETURNING *
+            "#,
+        )
+        .bind(id)
+        .bind(extended_hours)
+        .fetch_one(pool)
+        .await?;
+
+        Ok(record)
+    }
+
+    /// Load IDs of strategies allowed to trade US extended hours.
+    pub async fn load_extended_hours_strategies(pool: &PgPool) -> Result<Vec<String>, sqlx::Error> {
+        sqlx::query_scalar(
+            r#"
+            SELECT id
+            FROM strategies
+            WHERE extended_hours
+            "#,
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Update whether the strategy is excluded from the execution governor.
+    pub async fn update_execution_governor_opt_out(
+        pool: &PgPool,
+        id: &str,
+        opt_out: bool,
+    ) -> Result<StrategyRecord, sqlx::Error> {
+        let record = sqlx::query_as::<_, StrategyRecord>(
+            r#"
+            UPDATE strategies
+            SET execution_governor_opt_out = $2, updated_at = NOW()
+            WHERE id = $1
+            RETURNING *
+            "#,
+        )
+        .bind(id)
+        .bind(opt_out)
+        .fetch_one(pool)
+        .await?;
+
+        Ok(record)
+    }
+
+    /// Load strategies assigned a base risk profile (conservative, standard, aggressive).
+    pub async fn load_risk_profiles(pool: &PgPool) -> Result<Vec<(String, String)>, sqlx::Error> {
+        sqlx::query_as(
+            r#"
+            SELECT id, risk_profile
+            FROM strategies
+            WHERE risk_profile IN ('conservative', 'standard', 'aggressive')
+            "#,
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Load IDs of strategies excluded from the execution governor.
+    pub async fn load_execution_governor_opt_outs(
+        pool: &PgPool,
+    ) -> Result<Vec<String>, sqlx::Error> {
+        sqlx::query_scalar(
+            r#"
+            SELECT id
+            FROM strategies
+            WHERE execution_governor_opt_out
+            "#,
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Update whether the strategy runs in paper trading mode.
+    pub async fn update_paper_trading(
+        pool: &PgPool,
+        id: &str,
+        paper_trading: bool,
+    ) -> Result<StrategyRecord, sqlx::Error> {
+        let record = sqlx::query_as::<_, StrategyRecord>(
+            r#"
+            UPDATE strategies
+            SET paper_trading = $2, updated_at = NOW()
+            WHERE id = $1
+            RETURNING *
+            "#,
+        )
+        .bind(id)
+        .bind(paper_trading)
+        .fetch_one(pool)
+        .await?;
+
+        Ok(record)
+    }
+
+    /// Load IDs of strategies running in paper trading mode.
+    pub async fn load_paper_trading_strategies(pool: &PgPool) -> Result<Vec<String>, sqlx::Error> {
+        sqlx::query_scalar(
+            r#"
+            SELECT id
+            FROM strategies
+            WHERE paper_trading
+            "#,
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Update strategy intraday curfew override (`None` restores default windows).
+    pub async fn update_curfew_override(
+        pool: &PgPool,
+        id: &str,
+        curfew_override: Option<Value>,
+    ) -> Result<StrategyRecord, sqlx::Error> {
+        let record = sqlx::query_as::<_, StrategyRecord>(
+            r#"
+            UPDATE strategies
+            SET curfew_override = $2, updated_at = NOW()
+            WHERE id = $1
+            RETURNING *
+            "#,
+        )
+        .bind(id)
+        .bind(curfew_override)
+        .fetch_one(pool)
+        .await?;
+
+        Ok(record)
+    }
+
+    /// Load per-strategy intraday curfew overrides.
+    ///
+    /// Rows whose override cannot be parsed are skipped with a warning.
+    pub async fn load_curfew_overrides(
+        pool: &PgPool,
+    ) -> Result<Vec<(String, CurfewOverride)>, sqlx::Error> {
+        let rows: Vec<(String, Value)> = sqlx::query_as(
+            r#"
+            SELECT id, curfew_override
+            FROM strategies
+            WHERE curfew_override IS NOT NULL
+            "#,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .filter_map(|(id, value)| match serde_json::from_value(value) {
+                Ok(curfew) => Some((id, curfew)),
+                Err(e) => {
+                    tracing::warn!(strategy_id = %id, error = %e, "Invalid curfew override, skipping");
+                    None
+                }
+            })
+            .collect())
+    }
+
+    /// Update strategy earnings filter (`None` removes the filter).
+    pub async fn update_earnings_filter(
+        pool: &PgPool,
+        id: &str,
+        earnings_filter: Option<Value>,
+    ) -> Result<StrategyRecord, sqlx::Error> {
+        let record = sqlx::query_as::<_, StrategyRecord>(
+            r#"
+            UPDATE strategies
+            SET earnings_filter = $2, updated_at = NOW()
+            WHERE id = $1
+            RETURNING *
+            "#,
+        )
+        .bind(id)
+        .bind(earnings_filter)
+        .fetch_one(pool)
+        .await?;
+
+        Ok(record)
+    }
+
+    /// Load per-strategy earnings filters.
+    ///
+    /// Rows whose filter cannot be parsed are skipped with a warning.
+    pub async fn load_earnings_filters(
+        pool: &PgPool,
+    ) -> Result<Vec<(String, EarningsFilterConfig)>, sqlx::Error> {
+        let rows: Vec<(String, Value)> = sqlx::query_as(
+            r#"
+            SELECT id, earnings_filter
+            FROM strategies
+            WHERE earnings_filter IS NOT NULL
+            "#,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .filter_map(|(id, value)| match serde_json::from_value(value) {
+                Ok(filter) => Some((id, filter)),
+                Err(e) => {
+                    tracing::warn!(strategy_id = %id, error = %e, "Invalid earnings filter, skipping");
+                    None
+                }
+            })
+            .collect())
+    }
+
+    /// Update strategy trading window (`None` removes the window).
+    pub async fn update_trading_window(
+        pool: &PgPool,
+        id: &str,
+        trading_window: Option<Value>,
+    ) -> Result<StrategyRecord, sqlx::Error> {
+        let record = sqlx::query_as::<_, StrategyRecord>(
+            r#"
+            UPDATE strategies
+            SET trading_window = $2, updated_at = NOW()
+            WHERE id = $1
+            RETURNING *
+            "#,
+        )
+        .bind(id)
+        .bind(trading_window)
+        .fetch_one(pool)
+        .await?;
+
+        Ok(record)
+    }
+
+    /// Load per-strategy trading windows.
+    ///
+    /// Rows whose window cannot be parsed or is invalid are skipped with a warning.
+    pub async fn load_trading_windows(
+        pool: &PgPool,
+    ) -> Result<Vec<(String, TradingWindowConfig)>, sqlx::Error> {
+        let rows: Vec<(String, Value)> = sqlx::query_as(
+            r#"
+            SELECT id, trading_window
+            FROM strategies
+            WHERE trading_window IS NOT NULL
+            "#,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .filter_map(|(id, value)| {
+                let window = serde_json::from_value::<TradingWindowConfig>(value)
+                    .map_err(|e| e.to_string())
+                    .and_then(|window| window.validate().map(|_| window));
+                match window {
+                    Ok(window) => Some((id, window)),
+                    Err(e) => {
+                        tracing::warn!(strategy_id = %id, error = %e, "Invalid trading window, skipping");
+                        None
+                    }
+                }
+            })
+            .collect())
+    }
+
     /// Update strategy symbols (trading targets).
     pub async fn update_symbols(
         pool: &PgPool,
@@ -306,13 +689,8 @@ impl StrategyRepository {
         Ok(record)
     }
 
-    /// Load all strategies from the database and register them with the engine.
-    ///
-    /// This is called during server startup to restore previously saved strategies.
-    pub async fn load_strategies_into_engine(
-        pool: &PgPool,
-        engine: &trader_strategy::StrategyEngine,
-    ) -> Result<usize, Box<dyn std::error::Error + Send + Sync>> {
+    /// Create a fresh strategy instance for a stored strategy type.
+    fn instantiate(strategy_type: &str) -> Option<Box<dyn trader_strategy::Strategy>> {
         use trader_strategy::strategies::{
             // 그룹 전략 (통합)
             AssetAllocationStrategy,
@@ -330,100 +708,188 @@ impl StrategyRepository {
             RotationStrategy,
             SectorVbStrategy,
             SmallCapQuantStrategy,
-            Us3xLeverageStrategy,
+            Us3xLeverageStrategy, WebhookStrategy,
         };
         use trader_strategy::Strategy;
 
+        let strategy: Box<dyn Strategy> = match strategy_type {
+            // MeanReversion 그룹 (RSI, Grid, Bollinger, Magic Split)
+            "rsi" | "rsi_mean_reversion" => Box::new(MeanReversionStrategy::rsi()),
+            "rsi_multi_tf" | "rsi_mtf" | "multi_rsi" => Box::new(MeanReversionStrategy::rsi()),
+            "grid" | "grid_trading" => Box::new(MeanReversionStrategy::grid()),
+            "bollinger" | "bollinger_bands" => Box::new(MeanReversionStrategy::bollinger()),
+            "magic_split" | "split" => Box::new(MeanReversionStrategy::magic_split()),
+
+            // DayTrading 그룹 (Volatility Breakout, SMA Crossover, Market Interest Day)
+            "volatility_breakout" | "volatility" => Box::new(DayTradingStrategy::breakout()),
+            "sma" | "sma_crossover" | "ma_crossover" => Box::new(DayTradingStrategy::crossover()),
+            "market_interest_day" => Box::new(DayTradingStrategy::volume_surge()),
+
+            // AssetAllocation 그룹 (HAA, XAA, BAA, All Weather, Dual Momentum)
+            "haa" => Box::new(AssetAllocationStrategy::haa()),
+            "xaa" => Box::new(AssetAllocationStrategy::xaa()),
+            "all_weather" | "all_weather_us" | "all_weather_kr" => {
+                Box::new(AssetAllocationStrategy::all_weather())
+            }
+            "baa" => Box::new(AssetAllocationStrategy::baa()),
+            "dual_momentum" => Box::new(AssetAllocationStrategy::dual_momentum()),
+
+            // Rotation 그룹 (Stock Rotation, Sector Momentum, Market Cap Top)
+            "stock_rotation" => Box::new(RotationStrategy::stock_rotation()),
+            "sector_momentum" => Box::new(RotationStrategy::sector_momentum()),
+            "market_cap_top" => Box::new(RotationStrategy::market_cap_top()),
+
+            // 독립 전략들
+            "compound_momentum" | "simple_power" => Box::new(CompoundMomentumStrategy::new()),
+            "momentum_power" | "snow" | "snow_us" | "snow_kr" => {
+                Box::new(MomentumPowerStrategy::new())
+            }
+            "pension_bot" => Box::new(PensionBotStrategy::new()),
+            "candle_pattern" => Box::new(CandlePatternStrategy::new()),
+            "infinity_bot" => Box::new(InfinityBotStrategy::new()),
+            "sector_vb" => Box::new(SectorVbStrategy::new()),
+            "market_bothside" | "kospi_bothside" | "kospi_both" => {
+                Box::new(MarketBothSideStrategy::new())
+            }
+            "momentum_surge" | "kosdaq_fire_rain" | "kosdaq_surge" => {
+                Box::new(MomentumSurgeStrategy::new())
+            }
+            "us_3x_leverage" => Box::new(Us3xLeverageStrategy::new()),
+            "range_trading" | "stock_gugan" => Box::new(RangeTradingStrategy::new()),
+            "small_cap_quant" => Box::new(SmallCapQuantStrategy::new()),
+            "webhook" | "tradingview" | "external_signal" => Box::new(WebhookStrategy::new()),
+
+            _ => return None,
+        };
+
+        Some(strategy)
+    }
+
+    /// Current config schema version of a strategy type (1 for unknown types).
+    fn current_config_version(strategy_type: &str) -> i32 {
+        Self::instantiate(strategy_type)
+            .map(|strategy| strategy.config_version() as i32)
+            .unwrap_or(1)
+    }
+
+    /// Store a config upgraded by a schema migration.
+    async fn save_migrated_config(
+        pool: &PgPool,
+        id: &str,
+        config: &Value,
+        config_version: u32,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            UPDATE strategies
+            SET config = $2, config_version = $3, updated_at = NOW()
+            WHERE id = $1
+            "#,
+        )
+        .bind(id)
+        .bind(config)
+        .bind(config_version as i32)
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Load all strategies from the database and register them with the engine.
+    ///
+    /// This is called during server startup to restore previously saved strategies.
+    pub async fn load_strategies_into_engine(
+        pool: &PgPool,
+        engine: &trader_strategy::StrategyEngine,
+    ) -> Result<usize, Box<dyn std::error::Error + Send + Sync>> {
         let records = Self::get_all(pool).await?;
         let mut loaded_count = 0;
 
         for record in records {
             let strategy_type = record.strategy_type.as_deref().unwrap_or("unknown");
 
-            // Create strategy instance based on type
-            let strategy: Option<Box<dyn Strategy>> = match strategy_type {
-                // MeanReversion 그룹 (RSI, Grid, Bollinger, Magic Split)
-                "rsi" | "rsi_mean_reversion" => Some(Box::new(MeanReversionStrategy::rsi())),
-                "rsi_multi_tf" | "rsi_mtf" | "multi_rsi" => {
-                    Some(Box::new(MeanReversionStrategy::rsi()))
-                }
-                "grid" | "grid_trading" => Some(Box::new(MeanReversionStrategy::grid())),
-                "bollinger" | "bollinger_bands" => {
-                    Some(Box::new(MeanReversionStrategy::bollinger()))
-                }
-                "magic_split" | "split" => Some(Box::new(MeanReversionStrategy::magic_split())),
-
-                // DayTrading 그룹 (Volatility Breakout, SMA Crossover, Market Interest Day)
-                "volatility_breakout" | "volatility" => {
-                    Some(Box::new(DayTradingStrategy::breakout()))
-                }
-                "sma" | "sma_crossover" | "ma_crossover" => {
-                    Some(Box::new(DayTradingStrategy::crossover()))
-                }
-                "market_interest_day" => Some(Box::new(DayTradingStrategy::volume_surge())),
-
-                // AssetAllocation 그룹 (HAA, XAA, BAA, All Weather, Dual Momentum)
-                "haa" => Some(Box::new(AssetAllocationStrategy::haa())),
-                "xaa" => Some(Box::new(AssetAllocationStrategy::xaa())),
-                "all_weather" | "all_weather_us" | "all_weather_kr" => {
-                    Some(Box::new(AssetAllocationStrategy::all_weather()))
-                }
-                "baa" => Some(Box::new(AssetAllocationStrategy::baa())),
-                "dual_momentum" => Some(Box::new(AssetAllocationStrategy::dual_momentum())),
-
-                // Rotation 그룹 (Stock Rotation, Sector Momentum, Market Cap Top)
-                "stock_rotation" => Some(Box::new(RotationStrategy::stock_rotation())),
-                "sector_momentum" => Some(Box::new(RotationStrategy::sector_momentum())),
-                "market_cap_top" => Some(Box::new(RotationStrategy::market_cap_top())),
+            let Some(strategy) = Self::instantiate(strategy_type) else {
+                tracing::warn!(
+                    "Unknown strategy type: {} for strategy {}",
+                    strategy_type,
+                    record.id
+                );
+                continue;
+            };
 
-                // 독립 전략들
-                "compound_momentum" | "simple_power" => {
-                    Some(Box::new(CompoundMomentumStrategy::new()))
-                }
-                "momentum_power" | "snow" | "snow_us" | "snow_kr" => {
-                    Some(Box::new(MomentumPowerStrategy::new()))
-                }
-                "pension_bot" => Some(Box::new(PensionBotStrategy::new())),
-                "candle_pattern" => Some(Box::new(CandlePatternStrategy::new())),
-                "infinity_bot" => Some(Box::new(InfinityBotStrategy::new())),
-                "sector_vb" => Some(Box::new(SectorVbStrategy::new())),
-                "market_bothside" | "kospi_bothside" | "kospi_both" => {
-                    Some(Box::new(MarketBothSideStrategy::new()))
-                }
-                "momentum_surge" | "kosdaq_fire_rain" | "kosdaq_surge" => {
-                    Some(Box::new(MomentumSurgeStrategy::new()))
+            // 이전 스키마로 저장된 설정을 현재 버전으로 변환
+            let stored_version = record.config_version.unwrap_or(1).max(0) as u32;
+            let config = match trader_strategy::upgrade_config(
+                strategy.as_ref(),
+                stored_version,
+                record.config.clone(),
+            ) {
+                Ok(upgrade) => {
+                    if upgrade.is_upgraded() {
+                        tracing::info!(
+                            strategy_id = %record.id,
+                            from = upgrade.from_version,
+                            to = upgrade.to_version,
+                            migrations = ?upgrade.applied,
+                            "Upgraded stored strategy config"
+                        );
+                        if let Err(e) = Self::save_migrated_config(
+                            pool,
+                            &record.id,
+                            &upgrade.config,
+                            upgrade.to_version,
+                        )
+                        .await
+                        {
+                            tracing::warn!(
+                                strategy_id = %record.id,
+                                error = %e,
+                                "Failed to store upgraded strategy config"
+                            );
+                        }
+                    }
+                    upgrade.config
                 }
-                "us_3x_leverage" => Some(Box::new(Us3xLeverageStrategy::new())),
-                "range_trading" | "stock_gugan" => Some(Box::new(RangeTradingStrategy::new())),
-                "small_cap_quant" => Some(Box::new(SmallCapQuantStrategy::new())),
-
-                _ => {
-                    tracing::warn!(
-                        "Unknown strategy type: {} for strategy {}",
-                        strategy_type,
-                        record.id
+                Err(e) => {
+                    tracing::error!(
+                        strategy_id = %record.id,
+                        error = %e,
+                        "Failed to migrate strategy config, skipping"
                     );
-                    None
+                    continue;
                 }
             };
 
-            if let Some(strategy) = strategy {
-                match engine
-                    .register_strategy(
-                        &record.id,
-                        strategy,
-                        record.config.clone(),
-                        Some(record.name.clone()),
-                    )
-                    .await
-                {
-                    Ok(_) => {
-                        tracing::info!("Loaded strategy from DB: {} ({})", record.name, record.id);
-                        loaded_count += 1;
-                    }
-                    Err(e) => {
-                        tracing::error!("Failed to load strategy {}: {:?}", record.id, e);
+            let symbol_lock: Option<SymbolLockConfig> =
+                match record.symbol_lock.clone().map(serde_json::from_value) {
+                    Some(Ok(lock)) => Some(lock),
+                    Some(Err(e)) => {
+                        tracing::warn!(
+                            strategy_id = %record.id,
+                            error = %e,
+                            "Invalid symbol lock, ignoring"
+                        );
+                        None
                     }
+                    None => None,
+                };
+
+            match engine
+                .register_strategy_with_lock(
+                    &record.id,
+                    strategy,
+                    config,
+                    Some(record.name.clone()),
+                    symbol_lock,
+                )
+                .await
+            {
+                Ok(_) => {
+                    tracing::info!("Loaded strategy from DB: {} ({})", record.name, record.id);
+                    loaded_count += 1;
+                }
+                Err(e) => {
+                    tracing::error!("Failed to load strategy {}: {:?}", record.id, e);
                 }
             }
         }
@@ -0,0 +1,141 @@
+//! 전략 자본 원장 Repository.
+//!
+//! 전략별 할당 자본(`strategies.allocated_capital`)과
+//! 입출금/이체 이력(`strategy_capital_transfer`)을 관리합니다.
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, PgPool};
+use trader_risk::{CapitalLedger, CapitalTransferKind};
+use uuid::Uuid;
+
+/// 자본 이동 이력 레코드.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct CapitalTransferRecord {
+    pub id: Uuid,
+    /// 이동 유형 (deposit, withdraw, transfer)
+    pub kind: String,
+    /// 출금 전략
+    pub from_strategy: Option<String>,
+    /// 입금 전략
+    pub to_strategy: Option<String>,
+    /// 금액
+    pub amount: Decimal,
+    /// 메모
+    pub memo: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// 전략 자본 Repository.
+pub struct StrategyCapitalRepository;
+
+impl StrategyCapitalRepository {
+    /// 할당 자본이 설정된 전략을 원장에 등록.
+    ///
+    /// # Returns
+    /// 등록된 전략 수
+    pub async fn load_into_ledger(
+        pool: &PgPool,
+        ledger: &mut CapitalLedger,
+    ) -> Result<usize, sqlx::Error> {
+        let rows: Vec<(String, Decimal)> = sqlx::query_as(
+            r#"
+            SELECT id, allocated_capital
+            FROM strategies
+            WHERE allocated_capital IS NOT NULL
+            "#,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        let mut loaded = 0;
+        for (strategy_id, allocated) in rows {
+            if ledger.allocate(&strategy_id, allocated).is_ok() {
+                loaded += 1;
+            }
+        }
+
+        Ok(loaded)
+    }
+
+    /// 할당 자본 변경과 이력 기록을 하나의 트랜잭션으로 수행.
+    ///
+    /// `from_strategy`의 할당 자본은 감소하고 `to_strategy`는 증가합니다.
+    /// 대상 전략이 존재하지 않으면 `sqlx::Error::RowNotFound`를 반환합니다.
+    pub async fn apply_transfer(
+        pool: &PgPool,
+        kind: CapitalTransferKind,
+        from_strategy: Option<&str>,
+        to_strategy: Option<&str>,
+        amount: Decimal,
+        memo: Option<&str>,
+    ) -> Result<CapitalTransferRecord, sqlx::Error> {
+        let mut tx = pool.begin().await?;
+
+        for (strategy_id, delta) in [(from_strategy, -amount), (to_strategy, amount)] {
+            let Some(strategy_id) = strategy_id else {
+                continue;
+            };
+
+            let updated = sqlx::query(
+                r#"
+                UPDATE strategies
+                SET allocated_capital = COALESCE(allocated_capital, 0) + $2,
+                    updated_at = NOW()
+                WHERE id = $1
+                "#,
+            )
+            .bind(strategy_id)
+            .bind(delta)
+            .execute(&mut *tx)
+            .await?;
+
+            if updated.rows_affected() == 0 {
+                return Err(sqlx::Error::RowNotFound);
+            }
+        }
+
+        let record = sqlx::query_as::<_, CapitalTransferRecord>(
+            r#"
+            INSERT INTO strategy_capital_transfer (kind, from_strategy, to_strategy, amount, memo)
+            VALUES ($1, $2, $3, $4, $5)
+            RETURNING id, kind, from_strategy, to_strategy, amount, memo, created_at
+            "#,
+        )
+        .bind(kind.as_str())
+        .bind(from_strategy)
+        .bind(to_strategy)
+        .bind(amount)
+        .bind(memo)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(record)
+    }
+
+    /// 자본 이동 이력 조회 (최신순).
+    ///
+    /// `strategy_id`가 지정되면 해당 전략이 관련된 기록만 반환합니다.
+    pub async fn list_transfers(
+        pool: &PgPool,
+        strategy_id: Option<&str>,
+        limit: i64,
+    ) -> Result<Vec<CapitalTransferRecord>, sqlx::Error> {
+        sqlx::query_as::<_, CapitalTransferRecord>(
+            r#"
+            SELECT id, kind, from_strategy, to_strategy, amount, memo, created_at
+            FROM strategy_capital_transfer
+            WHERE $1::varchar IS NULL OR from_strategy = $1 OR to_strategy = $1
+            ORDER BY created_at DESC
+            LIMIT $2
+            "#,
+        )
+        .bind(strategy_id)
+        .bind(limit)
+        .fetch_all(pool)
+        .await
+    }
+}
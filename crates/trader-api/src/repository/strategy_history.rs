@@ -0,0 +1,261 @@
+//! 전략 파라미터 변경 이력 Repository.
+//!
+//! 전략 설정, 리스크 설정, 심볼 등이 변경될 때마다 변경자와 변경 전후 값을
+//! `strategy_param_change` 테이블에 기록하고, 이력 조회 시 전략의 일별
+//! 실현 손익(`trade_executions`)과 함께 제공합니다.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+use super::strategies::StrategyRecord;
+
+/// 파라미터 변경 기록.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct StrategyParamChangeRecord {
+    pub id: Uuid,
+    pub strategy_id: String,
+    /// 변경 유형 (config, risk, cost_model, extended_hours, earnings_filter, curfew_override,
+    /// execution_governor, trading_window, symbols, timeframes)
+    pub change_type: String,
+    /// 변경자 (JWT 사용자명, 미인증 요청은 "api")
+    pub changed_by: String,
+    /// 변경 전 파라미터 스냅샷
+    pub old_params: Value,
+    /// 변경 후 파라미터 스냅샷
+    pub new_params: Value,
+    /// 변경 내역 ([`ParamDiff`] 배열)
+    pub changes: Value,
+    /// 변경 시점 전략 실행 여부
+    pub strategy_running: bool,
+    pub changed_at: DateTime<Utc>,
+}
+
+/// 파라미터 변경 입력.
+#[derive(Debug, Clone)]
+pub struct StrategyParamChangeInput {
+    pub strategy_id: String,
+    pub change_type: String,
+    pub changed_by: String,
+    pub old_params: Value,
+    pub new_params: Value,
+    pub changes: Vec<ParamDiff>,
+    pub strategy_running: bool,
+}
+
+/// 단일 파라미터 변경 내역.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ParamDiff {
+    /// 변경된 키 (전략 설정은 `config.<키>` 형식)
+    pub key: String,
+    /// 변경 전 값 (없었으면 null)
+    pub old: Value,
+    /// 변경 후 값 (제거되었으면 null)
+    pub new: Value,
+}
+
+/// 전략의 일별 실현 손익.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct StrategyDailyPnl {
+    /// 거래일 (KST)
+    pub trade_date: NaiveDate,
+    /// 실현 손익 합계
+    pub realized_pnl: Decimal,
+    /// 청산 체결 수
+    pub closed_trades: i64,
+    /// 수익 청산 체결 수
+    pub winning_trades: i64,
+}
+
+/// 전략 레코드에서 변경 추적 대상 파라미터 스냅샷 생성.
+pub fn param_snapshot(record: &StrategyRecord) -> Value {
+    serde_json::json!({
+        "config": record.config,
+        "risk_limits": record.risk_limits,
+        "allocated_capital": record.allocated_capital,
+        "risk_profile": record.risk_profile,
+        "symbols": record.symbols,
+        "timeframe": record.timeframe,
+        "multi_timeframe_config": record.multi_timeframe_config,
+        "cost_model": record.cost_model,
+        "extended_hours": record.extended_hours,
+        "earnings_filter": record.earnings_filter,
+        "curfew_override": record.curfew_override,
+        "execution_governor_opt_out": record.execution_governor_opt_out,
+        "paper_trading": record.paper_trading,
+        "trading_window": record.trading_window,
+    })
+}
+
+/// 두 스냅샷의 변경 내역 계산.
+///
+/// 최상위 키 단위로 비교하되, `config`는 전략 파라미터별로 보이도록
+/// 한 단계 더 내려가 `config.<키>` 단위로 비교합니다.
+pub fn diff_params(old: &Value, new: &Value) -> Vec<ParamDiff> {
+    let empty = Map::new();
+    let old_map = old.as_object().unwrap_or(&empty);
+    let new_map = new.as_object().unwrap_or(&empty);
+
+    let mut diffs = Vec::new();
+    for key in union_keys(old_map, new_map) {
+        let old_value = old_map.get(&key).unwrap_or(&Value::Null);
+        let new_value = new_map.get(&key).unwrap_or(&Value::Null);
+        if old_value == new_value {
+            continue;
+        }
+
+        match (key.as_str(), old_value.as_object(), new_value.as_object()) {
+            ("config", Some(old_config), Some(new_config)) => {
+                for sub_key in union_keys(old_config, new_config) {
+                    let old_sub = old_config.get(&sub_key).unwrap_or(&Value::Null);
+                    let new_sub = new_config.get(&sub_key).unwrap_or(&Value::Null);
+                    if old_sub != new_sub {
+                        diffs.push(ParamDiff {
+                            key: format!("config.{}", sub_key),
+                            old: old_sub.clone(),
+                            new: new_sub.clone(),
+                        });
+                    }
+                }
+            }
+            _ => diffs.push(ParamDiff {
+                key,
+                old: old_value.clone(),
+                new: new_value.clone(),
+            }),
+        }
+    }
+    diffs
+}
+
+/// 두 객체 키의 합집합 (정렬됨).
+fn union_keys(a: &Map<String, Value>, b: &Map<String, Value>) -> Vec<String> {
+    let mut keys: Vec<String> = a.keys().chain(b.keys()).cloned().collect();
+    keys.sort();
+    keys.dedup();
+    keys
+}
+
+const CHANGE_COLUMNS: &str = "id, strategy_id, change_type, changed_by, old_params, new_params, \
+     changes, strategy_running, changed_at";
+
+/// 전략 파라미터 변경 이력 Repository.
+pub struct StrategyHistoryRepository;
+
+impl StrategyHistoryRepository {
+    /// 변경 기록 저장.
+    pub async fn insert(
+        pool: &PgPool,
+        input: &StrategyParamChangeInput,
+    ) -> Result<StrategyParamChangeRecord, sqlx::Error> {
+        let changes = serde_json::to_value(&input.changes).unwrap_or(Value::Array(Vec::new()));
+
+        sqlx::query_as::<_, StrategyParamChangeRecord>(&format!(
+            r#"
+            INSERT INTO strategy_param_change
+                (strategy_id, change_type, changed_by, old_params, new_params, changes,
+                 strategy_running)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            RETURNING {CHANGE_COLUMNS}
+            "#
+        ))
+        .bind(&input.strategy_id)
+        .bind(&input.change_type)
+        .bind(&input.changed_by)
+        .bind(&input.old_params)
+        .bind(&input.new_params)
+        .bind(changes)
+        .bind(input.strategy_running)
+        .fetch_one(pool)
+        .await
+    }
+
+    /// 전략의 변경 이력 조회 (시간순).
+    ///
+    /// # Arguments
+    /// * `since` - 시작 시각 (None이면 전체 기간)
+    pub async fn list(
+        pool: &PgPool,
+        strategy_id: &str,
+        since: Option<DateTime<Utc>>,
+    ) -> Result<Vec<StrategyParamChangeRecord>, sqlx::Error> {
+        sqlx::query_as::<_, StrategyParamChangeRecord>(&format!(
+            r#"
+            SELECT {CHANGE_COLUMNS}
+            FROM strategy_param_change
+            WHERE strategy_id = $1
+              AND ($2::timestamptz IS NULL OR changed_at >= $2)
+            ORDER BY changed_at
+            "#
+        ))
+        .bind(strategy_id)
+        .bind(since)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// 전략의 일별 실현 손익 (거래일순).
+    ///
+    /// # Arguments
+    /// * `since` - 시작 시각 (None이면 전체 기간)
+    pub async fn daily_realized_pnl(
+        pool: &PgPool,
+        strategy_id: &str,
+        since: Option<DateTime<Utc>>,
+    ) -> Result<Vec<StrategyDailyPnl>, sqlx::Error> {
+        sqlx::query_as::<_, StrategyDailyPnl>(
+            r#"
+            SELECT
+                (executed_at AT TIME ZONE 'Asia/Seoul')::date AS trade_date,
+                COALESCE(SUM(realized_pnl), 0) AS realized_pnl,
+                COUNT(realized_pnl) AS closed_trades,
+                COUNT(*) FILTER (WHERE realized_pnl > 0) AS winning_trades
+            FROM trade_executions
+            WHERE strategy_id = $1
+              AND ($2::timestamptz IS NULL OR executed_at >= $2)
+            GROUP BY (executed_at AT TIME ZONE 'Asia/Seoul')::date
+            ORDER BY trade_date
+            "#,
+        )
+        .bind(strategy_id)
+        .bind(since)
+        .fetch_all(pool)
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_diff_params_expands_config_keys() {
+        let old = json!({
+            "config": {"period": 14, "oversold": 30},
+            "risk_profile": "default",
+            "symbols": ["005930"],
+        });
+        let new = json!({
+            "config": {"period": 20, "oversold": 30, "overbought": 70},
+            "risk_profile": "default",
+            "symbols": ["005930", "000660"],
+        });
+
+        let diffs = diff_params(&old, &new);
+        let keys: Vec<&str> = diffs.iter().map(|d| d.key.as_str()).collect();
+        assert_eq!(keys, vec!["config.overbought", "config.period", "symbols"]);
+        assert_eq!(diffs[0].old, Value::Null);
+        assert_eq!(diffs[1].old, json!(14));
+        assert_eq!(diffs[1].new, json!(20));
+    }
+
+    #[test]
+    fn test_diff_params_unchanged_is_empty() {
+        let snapshot = json!({"config": {"period": 14}, "extended_hours": false});
+        assert!(diff_params(&snapshot, &snapshot).is_empty());
+    }
+}
@@ -0,0 +1,144 @@
+//! 백테스트 → 라이브 전략 승격 Repository.
+//!
+//! 승격으로 생성된 라이브 전략과 원본 백테스트 결과의 연결을 `strategy_promotion`
+//! 테이블에 기록하고, 승격 전 데이터 커버리지 검사를 위한 일봉 통계를 조회합니다.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+/// 승격 기록.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct StrategyPromotionRecord {
+    pub id: Uuid,
+    /// 승격으로 생성된 라이브 전략 ID
+    pub strategy_id: String,
+    /// 원본 백테스트 결과 ID
+    pub backtest_result_id: Uuid,
+    /// 라이브 전략에 적용된 파라미터
+    pub parameters: Value,
+    /// 파라미터 출처 (backtest, strategy, default)
+    pub parameter_source: String,
+    /// 원본 백테스트 성과 지표
+    pub backtest_metrics: Value,
+    /// 승격 검사 결과
+    pub checks: Value,
+    /// 승격한 사용자 (JWT 사용자명, 미인증 요청은 "api")
+    pub promoted_by: String,
+    pub promoted_at: DateTime<Utc>,
+}
+
+/// 승격 기록 입력.
+#[derive(Debug, Clone)]
+pub struct StrategyPromotionInput {
+    pub strategy_id: String,
+    pub backtest_result_id: Uuid,
+    pub parameters: Value,
+    pub parameter_source: String,
+    pub backtest_metrics: Value,
+    pub checks: Value,
+    pub promoted_by: String,
+}
+
+/// 기간 내 캔들 커버리지.
+#[derive(Debug, Clone, Default, FromRow)]
+pub struct CandleCoverage {
+    /// 기간 내 캔들 수
+    pub candle_count: i64,
+    /// 기간 내 첫 캔들 시간
+    pub first_time: Option<DateTime<Utc>>,
+    /// 기간 내 마지막 캔들 시간
+    pub last_time: Option<DateTime<Utc>>,
+    /// 전체 기간 중 가장 최근 캔들 시간
+    pub latest_time: Option<DateTime<Utc>>,
+}
+
+/// 승격 Repository.
+pub struct StrategyPromotionRepository;
+
+impl StrategyPromotionRepository {
+    /// 승격 기록 저장.
+    pub async fn insert(
+        pool: &PgPool,
+        input: &StrategyPromotionInput,
+    ) -> Result<StrategyPromotionRecord, sqlx::Error> {
+        sqlx::query_as::<_, StrategyPromotionRecord>(
+            r#"
+            INSERT INTO strategy_promotion (
+                strategy_id, backtest_result_id, parameters, parameter_source,
+                backtest_metrics, checks, promoted_by
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            RETURNING *
+            "#,
+        )
+        .bind(&input.strategy_id)
+        .bind(input.backtest_result_id)
+        .bind(&input.parameters)
+        .bind(&input.parameter_source)
+        .bind(&input.backtest_metrics)
+        .bind(&input.checks)
+        .bind(&input.promoted_by)
+        .fetch_one(pool)
+        .await
+    }
+
+    /// 라이브 전략의 승격 기록 조회.
+    pub async fn get_by_strategy(
+        pool: &PgPool,
+        strategy_id: &str,
+    ) -> Result<Option<StrategyPromotionRecord>, sqlx::Error> {
+        sqlx::query_as::<_, StrategyPromotionRecord>(
+            "SELECT * FROM strategy_promotion WHERE strategy_id = $1",
+        )
+        .bind(strategy_id)
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// 백테스트 결과에서 승격된 전략 ID 목록.
+    pub async fn list_strategy_ids_by_backtest(
+        pool: &PgPool,
+        backtest_result_id: Uuid,
+    ) -> Result<Vec<String>, sqlx::Error> {
+        sqlx::query_scalar(
+            r#"
+            SELECT strategy_id FROM strategy_promotion
+            WHERE backtest_result_id = $1
+            ORDER BY promoted_at
+            "#,
+        )
+        .bind(backtest_result_id)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// 심볼의 `[start, end)` 구간 캔들 커버리지 조회.
+    pub async fn candle_coverage(
+        pool: &PgPool,
+        symbol: &str,
+        timeframe: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<CandleCoverage, sqlx::Error> {
+        sqlx::query_as::<_, CandleCoverage>(
+            r#"
+            SELECT
+                COUNT(*) FILTER (WHERE open_time >= $3 AND open_time < $4) AS candle_count,
+                MIN(open_time) FILTER (WHERE open_time >= $3 AND open_time < $4) AS first_time,
+                MAX(open_time) FILTER (WHERE open_time >= $3 AND open_time < $4) AS last_time,
+                MAX(open_time) AS latest_time
+            FROM ohlcv
+            WHERE symbol = $1 AND timeframe = $2
+            "#,
+        )
+        .bind(symbol)
+        .bind(timeframe)
+        .bind(start)
+        .bind(end)
+        .fetch_one(pool)
+        .await
+    }
+}
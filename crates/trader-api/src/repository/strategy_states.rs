@@ -0,0 +1,122 @@
+//! 전략 상태 Repository.
+//!
+//! 전략 인스턴스별 마지막 내부 상태(`strategy_states`)를 저장합니다.
+//! 엔진은 상태가 바뀔 때마다 저장하고, 재시작 후 같은 ID의 전략을 시작할 때 복원합니다.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgPool};
+use trader_strategy::{StrategyState, StrategyStateStore};
+
+/// 전략 상태 레코드.
+#[derive(Debug, Clone, FromRow)]
+pub struct StrategyStateRecord {
+    pub strategy_id: String,
+    pub state: Vec<u8>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<StrategyStateRecord> for StrategyState {
+    fn from(record: StrategyStateRecord) -> Self {
+        Self {
+            strategy_id: record.strategy_id,
+            data: record.state,
+            saved_at: record.updated_at,
+        }
+    }
+}
+
+/// 전략 상태 Repository.
+pub struct StrategyStateRepository;
+
+impl StrategyStateRepository {
+    /// 전략의 저장된 상태 조회.
+    pub async fn load(
+        pool: &PgPool,
+        strategy_id: &str,
+    ) -> Result<Option<StrategyStateRecord>, sqlx::Error> {
+        sqlx::query_as::<_, StrategyStateRecord>(
+            r#"
+            SELECT strategy_id, state, updated_at
+            FROM strategy_states
+            WHERE strategy_id = $1
+            "#,
+        )
+        .bind(strategy_id)
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// 전략 상태 저장 (있으면 덮어쓰기).
+    pub async fn save(
+        pool: &PgPool,
+        strategy_id: &str,
+        state: &[u8],
+        updated_at: DateTime<Utc>,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            INSERT INTO strategy_states (strategy_id, state, updated_at)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (strategy_id) DO UPDATE
+            SET state = EXCLUDED.state,
+                updated_at = EXCLUDED.updated_at
+            "#,
+        )
+        .bind(strategy_id)
+        .bind(state)
+        .bind(updated_at)
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    /// 전략 상태 삭제 (삭제된 경우 true).
+    pub async fn delete(pool: &PgPool, strategy_id: &str) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query("DELETE FROM strategy_states WHERE strategy_id = $1")
+            .bind(strategy_id)
+            .execute(pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+/// DB 기반 전략 상태 저장소 (`StrategyEngine::set_state_store()`에 등록).
+pub struct PgStrategyStateStore {
+    pool: PgPool,
+}
+
+impl PgStrategyStateStore {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl StrategyStateStore for PgStrategyStateStore {
+    async fn load(
+        &self,
+        strategy_id: &str,
+    ) -> Result<Option<StrategyState>, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(StrategyStateRepository::load(&self.pool, strategy_id)
+            .await?
+            .map(StrategyState::from))
+    }
+
+    async fn save(
+        &self,
+        state: &StrategyState,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        StrategyStateRepository::save(&self.pool, &state.strategy_id, &state.data, state.saved_at)
+            .await?;
+        Ok(())
+    }
+
+    async fn delete(
+        &self,
+        strategy_id: &str,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        StrategyStateRepository::delete(&self.pool, strategy_id).await?;
+        Ok(())
+    }
+}
@@ -42,6 +42,16 @@ pub struct SymbolSearchResult {
     pub yahoo_symbol: Option<String>,
 }
 
+/// 익스포저 집계용 심볼 분류 정보 (시장, 섹터, 통화).
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct SymbolClassification {
+    pub ticker: String,
+    pub market: String,
+    pub sector: Option<String>,
+    /// 펀더멘털 데이터의 통화 (없으면 None)
+    pub currency: Option<String>,
+}
+
 /// 새 심볼 정보 삽입용.
 #[derive(Debug, Clone)]
 pub struct NewSymbolInfo {
@@ -191,6 +201,33 @@ impl SymbolInfoRepository {
             .collect())
     }
 
+    /// 티커 목록의 시장/섹터/통화 분류 조회.
+    ///
+    /// 섹터는 `symbol_info`, 통화는 `symbol_fundamental`에서 가져옵니다.
+    pub async fn get_classifications(
+        pool: &PgPool,
+        tickers: &[String],
+    ) -> Result<Vec<SymbolClassification>, sqlx::Error> {
+        if tickers.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let tickers_upper: Vec<String> = tickers.iter().map(|t| t.to_uppercase()).collect();
+
+        sqlx::query_as::<_, SymbolClassification>(
+            r#"
+            SELECT si.ticker, si.market, si.sector, sf.currency
+            FROM symbol_info si
+            LEFT JOIN symbol_fundamental sf ON sf.symbol_info_id = si.id
+            WHERE UPPER(si.ticker) = ANY($1)
+              AND si.is_active = true
+            "#,
+        )
+        .bind(&tickers_upper)
+        .fetch_all(pool)
+        .await
+    }
+
     /// 심볼 정보 일괄 삽입 (upsert).
     pub async fn upsert_batch(
         pool: &PgPool,
@@ -0,0 +1,92 @@
+//! 종목 → 기초자산 매핑 Repository.
+//!
+//! 포트폴리오 통합 보기와 익스포저 분석에서 교차 상장 종목이나 같은 지수를 추종하는
+//! 종목을 한 기초자산으로 묶기 위한 매핑(`symbol_underlying`)을 관리합니다.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, PgPool};
+use std::collections::HashMap;
+use trader_analytics::UnderlyingAsset;
+
+/// 기초자산 매핑 레코드.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct SymbolUnderlyingRecord {
+    /// 대문자 티커
+    pub ticker: String,
+    /// 기초자산 키
+    pub underlying: String,
+    /// 기초자산 표시 이름
+    pub underlying_name: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// 기초자산 매핑 Repository.
+pub struct UnderlyingRepository;
+
+impl UnderlyingRepository {
+    /// 전체 매핑 조회 (기초자산, 티커 순).
+    pub async fn list(pool: &PgPool) -> Result<Vec<SymbolUnderlyingRecord>, sqlx::Error> {
+        sqlx::query_as::<_, SymbolUnderlyingRecord>(
+            r#"
+            SELECT ticker, underlying, underlying_name, created_at, updated_at
+            FROM symbol_underlying
+            ORDER BY underlying, ticker
+            "#,
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// 통합 계산용 매핑 (대문자 티커 → 기초자산).
+    pub async fn load_map(pool: &PgPool) -> Result<HashMap<String, UnderlyingAsset>, sqlx::Error> {
+        Ok(Self::list(pool)
+            .await?
+            .into_iter()
+            .map(|record| {
+                (
+                    record.ticker,
+                    UnderlyingAsset {
+                        id: record.underlying,
+                        name: record.underlying_name,
+                    },
+                )
+            })
+            .collect())
+    }
+
+    /// 매핑 등록/수정.
+    pub async fn upsert(
+        pool: &PgPool,
+        ticker: &str,
+        underlying: &str,
+        underlying_name: Option<&str>,
+    ) -> Result<SymbolUnderlyingRecord, sqlx::Error> {
+        sqlx::query_as::<_, SymbolUnderlyingRecord>(
+            r#"
+            INSERT INTO symbol_underlying (ticker, underlying, underlying_name)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (ticker) DO UPDATE SET
+                underlying = EXCLUDED.underlying,
+                underlying_name = EXCLUDED.underlying_name,
+                updated_at = NOW()
+            RETURNING ticker, underlying, underlying_name, created_at, updated_at
+            "#,
+        )
+        .bind(ticker.to_uppercase())
+        .bind(underlying.to_uppercase())
+        .bind(underlying_name)
+        .fetch_one(pool)
+        .await
+    }
+
+    /// 매핑 삭제 (삭제된 경우 true).
+    pub async fn delete(pool: &PgPool, ticker: &str) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query("DELETE FROM symbol_underlying WHERE ticker = $1")
+            .bind(ticker.to_uppercase())
+            .execute(pool)
+            .await?;
+        Ok(result.rows_affected() > 0)
+    }
+}
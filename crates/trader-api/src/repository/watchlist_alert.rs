@@ -0,0 +1,354 @@
+//! 관심종목 알림 규칙 Repository.
+//!
+//! 관심종목 아이템에 연결된 가격/지표 알림 규칙(`watchlist_alert`)의 일괄 관리와
+//! 평가 상태 기록을 담당합니다.
+
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, PgPool};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// 알림 규칙 유형.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum WatchlistAlertRule {
+    /// 종가가 임계값을 상향 돌파
+    PriceAbove,
+    /// 종가가 임계값을 하향 돌파
+    PriceBelow,
+    /// 기준가 대비 변동률(%) 절대값이 임계값 이상
+    PctMove,
+    /// RSI가 임계값을 상향 돌파
+    RsiAbove,
+    /// RSI가 임계값을 하향 돌파
+    RsiBelow,
+}
+
+impl WatchlistAlertRule {
+    /// DB 저장 값.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::PriceAbove => "price_above",
+            Self::PriceBelow => "price_below",
+            Self::PctMove => "pct_move",
+            Self::RsiAbove => "rsi_above",
+            Self::RsiBelow => "rsi_below",
+        }
+    }
+
+    /// RSI 기반 규칙 여부.
+    pub fn uses_rsi(&self) -> bool {
+        matches!(self, Self::RsiAbove | Self::RsiBelow)
+    }
+}
+
+impl fmt::Display for WatchlistAlertRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for WatchlistAlertRule {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "price_above" => Ok(Self::PriceAbove),
+            "price_below" => Ok(Self::PriceBelow),
+            "pct_move" => Ok(Self::PctMove),
+            "rsi_above" => Ok(Self::RsiAbove),
+            "rsi_below" => Ok(Self::RsiBelow),
+            other => Err(format!("Unknown watchlist alert rule: {}", other)),
+        }
+    }
+}
+
+/// 관심종목 알림 규칙 레코드 (아이템/그룹 정보 포함).
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct WatchlistAlertRecord {
+    pub id: Uuid,
+    pub watchlist_id: Uuid,
+    /// 관심종목 그룹 이름
+    pub watchlist_name: String,
+    pub item_id: Uuid,
+    /// 종목 코드
+    pub symbol: String,
+    /// 시장 (KR, US)
+    pub market: String,
+    /// 규칙 소유자 (JWT sub)
+    pub owner_id: Option<String>,
+    /// 규칙 유형 (price_above, price_below, pct_move, rsi_above, rsi_below)
+    pub rule_type: String,
+    /// 임계값 (가격, 변동률 %, RSI)
+    pub threshold: Decimal,
+    /// 평가 캔들 타임프레임
+    pub timeframe: String,
+    /// RSI 기간
+    pub rsi_period: i32,
+    /// 발동 후 재알림 대기 (분)
+    pub cooldown_minutes: i32,
+    pub enabled: bool,
+    /// 직전 평가값 (가격 또는 RSI)
+    pub last_value: Option<Decimal>,
+    /// pct_move 기준가
+    pub reference_price: Option<Decimal>,
+    pub last_evaluated_at: Option<DateTime<Utc>>,
+    pub last_error: Option<String>,
+    pub last_triggered_at: Option<DateTime<Utc>>,
+    /// 누적 발동 횟수
+    pub trigger_count: i32,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl WatchlistAlertRecord {
+    /// 규칙 유형 파싱.
+    pub fn parsed_rule(&self) -> Result<WatchlistAlertRule, String> {
+        self.rule_type.parse()
+    }
+}
+
+/// 알림 규칙 생성 입력 (아이템 확인 후).
+#[derive(Debug, Clone)]
+pub struct WatchlistAlertInput {
+    pub item_id: Uuid,
+    pub rule: WatchlistAlertRule,
+    pub threshold: Decimal,
+    pub timeframe: String,
+    pub rsi_period: i32,
+    pub cooldown_minutes: i32,
+}
+
+const WATCHLIST_ALERT_SELECT: &str = r#"
+    SELECT a.id, a.watchlist_id, w.name AS watchlist_name, a.item_id, i.symbol, i.market,
+           a.owner_id, a.rule_type, a.threshold, a.timeframe, a.rsi_period, a.cooldown_minutes,
+           a.enabled, a.last_value, a.reference_price, a.last_evaluated_at, a.last_error,
+           a.last_triggered_at, a.trigger_count, a.created_at, a.updated_at
+    FROM watchlist_alert a
+    INNER JOIN watchlist w ON w.id = a.watchlist_id
+    INNER JOIN watchlist_item i ON i.id = a.item_id
+"#;
+
+/// 관심종목 알림 규칙 Repository.
+pub struct WatchlistAlertRepository;
+
+impl WatchlistAlertRepository {
+    /// 그룹의 알림 규칙 목록 조회 (소유자별, 종목/생성순).
+    ///
+    /// # Arguments
+    /// * `owner_id` - 규칙 소유자 (None이면 인증 없이 만든 규칙)
+    pub async fn list_by_watchlist(
+        pool: &PgPool,
+        watchlist_id: Uuid,
+        owner_id: Option<&str>,
+    ) -> Result<Vec<WatchlistAlertRecord>, sqlx::Error> {
+        sqlx::query_as::<_, WatchlistAlertRecord>(&format!(
+            r#"
+            {WATCHLIST_ALERT_SELECT}
+            WHERE a.watchlist_id = $1 AND a.owner_id IS NOT DISTINCT FROM $2
+            ORDER BY i.sort_order, i.symbol, a.created_at
+            "#
+        ))
+        .bind(watchlist_id)
+        .bind(owner_id)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// 평가 대상(활성) 알림 규칙 전체 조회.
+    pub async fn list_enabled(pool: &PgPool) -> Result<Vec<WatchlistAlertRecord>, sqlx::Error> {
+        sqlx::query_as::<_, WatchlistAlertRecord>(&format!(
+            r#"
+            {WATCHLIST_ALERT_SELECT}
+            WHERE a.enabled = TRUE
+            ORDER BY a.created_at
+            "#
+        ))
+        .fetch_all(pool)
+        .await
+    }
+
+    /// 알림 규칙 일괄 생성 (하나의 트랜잭션).
+    ///
+    /// # Returns
+    /// 생성된 규칙 ID 목록 (입력 순서)
+    pub async fn create_batch(
+        pool: &PgPool,
+        watchlist_id: Uuid,
+        owner_id: Option<&str>,
+        inputs: &[WatchlistAlertInput],
+    ) -> Result<Vec<Uuid>, sqlx::Error> {
+        let mut tx = pool.begin().await?;
+        let mut ids = Vec::with_capacity(inputs.len());
+
+        for input in inputs {
+            let id = sqlx::query_scalar::<_, Uuid>(
+                r#"
+                INSERT INTO watchlist_alert
+                    (watchlist_id, item_id, owner_id, rule_type, threshold, timeframe,
+                     rsi_period, cooldown_minutes)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+                RETURNING id
+                "#,
+            )
+            .bind(watchlist_id)
+            .bind(input.item_id)
+            .bind(owner_id)
+            .bind(input.rule.as_str())
+            .bind(input.threshold)
+            .bind(&input.timeframe)
+            .bind(input.rsi_period)
+            .bind(input.cooldown_minutes)
+            .fetch_one(&mut *tx)
+            .await?;
+            ids.push(id);
+        }
+
+        tx.commit().await?;
+        Ok(ids)
+    }
+
+    /// 알림 규칙 일괄 활성화/비활성화.
+    ///
+    /// 다시 활성화한 규칙은 직전 평가값과 기준가를 초기화하여
+    /// 비활성 기간의 가격 변화로 발동하지 않게 합니다.
+    ///
+    /// # Arguments
+    /// * `ids` - 대상 규칙 ID (비어 있으면 그룹 내 소유자의 전체 규칙)
+    ///
+    /// # Returns
+    /// 변경된 규칙 수
+    pub async fn set_enabled(
+        pool: &PgPool,
+        watchlist_id: Uuid,
+        owner_id: Option<&str>,
+        ids: &[Uuid],
+        enabled: bool,
+    ) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query(
+            r#"
+            UPDATE watchlist_alert
+            SET enabled = $4, last_error = NULL,
+                last_value = CASE WHEN $4 THEN NULL ELSE last_value END,
+                reference_price = CASE WHEN $4 THEN NULL ELSE reference_price END
+            WHERE watchlist_id = $1 AND owner_id IS NOT DISTINCT FROM $2
+              AND (cardinality($3::uuid[]) = 0 OR id = ANY($3))
+              AND enabled <> $4
+            "#,
+        )
+        .bind(watchlist_id)
+        .bind(owner_id)
+        .bind(ids)
+        .bind(enabled)
+        .execute(pool)
+        .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// 알림 규칙 일괄 삭제.
+    ///
+    /// # Returns
+    /// 삭제된 규칙 수
+    pub async fn delete_batch(
+        pool: &PgPool,
+        watchlist_id: Uuid,
+        owner_id: Option<&str>,
+        ids: &[Uuid],
+    ) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query(
+            r#"
+            DELETE FROM watchlist_alert
+            WHERE watchlist_id = $1 AND owner_id IS NOT DISTINCT FROM $2 AND id = ANY($3)
+            "#,
+        )
+        .bind(watchlist_id)
+        .bind(owner_id)
+        .bind(ids)
+        .execute(pool)
+        .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// 평가 결과 기록 (미발동 또는 평가 오류).
+    pub async fn record_evaluation(
+        pool: &PgPool,
+        id: Uuid,
+        last_value: Option<Decimal>,
+        reference_price: Option<Decimal>,
+        error: Option<&str>,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            UPDATE watchlist_alert
+            SET last_evaluated_at = NOW(),
+                last_value = COALESCE($2, last_value),
+                reference_price = COALESCE($3, reference_price),
+                last_error = $4
+            WHERE id = $1
+            "#,
+        )
+        .bind(id)
+        .bind(last_value)
+        .bind(reference_price)
+        .bind(error)
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    /// 발동 기록 (발동 시각, 누적 횟수 갱신).
+    pub async fn record_trigger(
+        pool: &PgPool,
+        id: Uuid,
+        last_value: Decimal,
+        reference_price: Option<Decimal>,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            UPDATE watchlist_alert
+            SET last_evaluated_at = NOW(), last_triggered_at = NOW(),
+                trigger_count = trigger_count + 1,
+                last_value = $2,
+                reference_price = COALESCE($3, reference_price),
+                last_error = NULL
+            WHERE id = $1
+            "#,
+        )
+        .bind(id)
+        .bind(last_value)
+        .bind(reference_price)
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rule_round_trip() {
+        for rule in [
+            WatchlistAlertRule::PriceAbove,
+            WatchlistAlertRule::PriceBelow,
+            WatchlistAlertRule::PctMove,
+            WatchlistAlertRule::RsiAbove,
+            WatchlistAlertRule::RsiBelow,
+        ] {
+            assert_eq!(rule.as_str().parse::<WatchlistAlertRule>(), Ok(rule));
+            assert_eq!(
+                serde_json::to_value(rule).unwrap(),
+                serde_json::json!(rule.as_str())
+            );
+        }
+        assert!("price_cross".parse::<WatchlistAlertRule>().is_err());
+        assert!(WatchlistAlertRule::RsiBelow.uses_rsi());
+        assert!(!WatchlistAlertRule::PctMove.uses_rsi());
+    }
+}
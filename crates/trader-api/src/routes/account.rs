@@ -0,0 +1,114 @@
+//! 계정 endpoint.
+//!
+//! 로그인한 사용자의 리소스 할당량(등급, 한도)과 현재 사용량을 조회합니다.
+//! 할당량 판정 로직은 `crate::quota`에 있습니다.
+//!
+//! # 엔드포인트
+//!
+//! - `GET /api/v1/account/usage` - 할당량과 사용량 (인증 필요)
+
+use axum::{extract::State, http::StatusCode, routing::get, Json, Router};
+use serde::Serialize;
+use std::sync::Arc;
+
+use crate::auth::JwtAuth;
+use crate::error::{ApiErrorResponse, ApiResult};
+use crate::quota::{QuotaLimits, QuotaTier, QuotaUsage};
+use crate::state::AppState;
+
+// ==================== 응답 타입 ====================
+
+/// 할당량 사용량 응답.
+#[derive(Debug, Serialize)]
+pub struct AccountUsageResponse {
+    pub user_id: String,
+    pub tier: QuotaTier,
+    /// 적용 한도 (null = 제한 없음)
+    pub limits: QuotaLimits,
+    /// 현재 사용량
+    pub usage: QuotaUsage,
+}
+
+// ==================== 핸들러 ====================
+
+/// 할당량과 사용량 조회.
+///
+/// GET /api/v1/account/usage
+pub async fn get_account_usage(
+    State(state): State<Arc<AppState>>,
+    JwtAuth(claims): JwtAuth,
+) -> ApiResult<Json<AccountUsageResponse>> {
+    let quota = state.quotas.quota_for(&claims.sub).await;
+    let usage = state.quotas.usage(&claims.sub).await.map_err(|e| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiErrorResponse::new("DB_ERROR", e.to_string())),
+        )
+    })?;
+
+    Ok(Json(AccountUsageResponse {
+        user_id: claims.sub,
+        tier: quota.tier,
+        limits: quota.limits,
+        usage,
+    }))
+}
+
+// ==================== 라우터 ====================
+
+/// 계정 라우터.
+pub fn account_router() -> Router<Arc<AppState>> {
+    Router::new().route("/usage", get(get_account_usage))
+}
+
+// ==================== 테스트 ====================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::{create_token, Claims, Role};
+    use crate::state::create_test_state;
+    use axum::body::Body;
+    use axum::http::Request;
+    use tower::ServiceExt;
+
+    fn app() -> Router {
+        account_router().with_state(Arc::new(create_test_state()))
+    }
+
+    #[tokio::test]
+    async fn test_account_usage_requires_auth() {
+        let response = app()
+            .oneshot(Request::get("/usage").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_account_usage() {
+        let secret = std::env::var("JWT_SECRET")
+            .unwrap_or_else(|_| "development-secret-key-change-in-production".to_string());
+        let claims = Claims::new("user-1", "tester", Role::Trader, 60);
+        let token = create_token(&claims, &secret).unwrap();
+
+        let response = app()
+            .oneshot(
+                Request::get("/usage")
+                    .header("authorization", format!("Bearer {}", token))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["user_id"], "user-1");
+        assert_eq!(json["usage"]["running_backtests"], 0);
+        assert!(json["tier"].is_string());
+    }
+}
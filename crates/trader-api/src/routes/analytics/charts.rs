@@ -45,7 +45,7 @@ pub async fn get_equity_curve(
         .and_then(|id| uuid::Uuid::parse_str(id).ok());
 
     // DB에서 실제 데이터 조회 시도
-    if let Some(db_pool) = &state.db_pool {
+    if let Some(db_pool) = state.analytics_db_pool() {
         // credential_id가 있으면 특정 계좌만 조회, 없으면 전체 합산
         let data_result = if let Some(cred_id) = credential_id {
             debug!(credential_id = %cred_id, "특정 계좌 자산 곡선 조회");
@@ -231,7 +231,7 @@ pub async fn get_drawdown_chart(
 /// GET /api/v1/analytics/monthly-returns
 pub async fn get_monthly_returns(State(state): State<Arc<AppState>>) -> impl IntoResponse {
     // DB에서 실제 데이터 조회 시도
-    if let Some(db_pool) = &state.db_pool {
+    if let Some(db_pool) = state.analytics_db_pool() {
         match EquityHistoryRepository::get_monthly_returns(db_pool, None, 3).await {
             Ok(monthly_data) if !monthly_data.is_empty() => {
                 debug!(
@@ -0,0 +1,216 @@
+//! 시장 간 상관관계 모니터링 핸들러.
+//!
+//! 상관관계 모니터(`services::correlation_monitor`)가 저장한 쌍별 롤링 상관계수 이력과
+//! 레짐 변화 알림을 조회하고, 모니터링 대상 자산을 관리합니다.
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    Json,
+};
+use chrono::{Duration, Utc};
+use std::sync::Arc;
+use tracing::info;
+
+use crate::error::{ApiErrorResponse, ApiResult};
+use crate::repository::{CorrelationAssetRecord, CorrelationRepository, SymbolInfoRepository};
+use crate::routes::common::{db_error_response, db_unavailable, require_pool};
+use crate::state::AppState;
+
+use super::types::{
+    CorrelationAlertsQuery, CorrelationAlertsResponse, CorrelationAssetRequest,
+    CorrelationAssetsResponse, CorrelationHistoryQuery, CorrelationHistoryResponse,
+    CorrelationLatestResponse,
+};
+
+/// 알림 조회 최대 개수.
+const MAX_ALERT_LIMIT: i64 = 500;
+
+/// 쌍 티커 정규화 (대문자, 사전순 정렬).
+fn ordered_pair(a: &str, b: &str) -> (String, String) {
+    let a = a.trim().to_uppercase();
+    let b = b.trim().to_uppercase();
+    if a <= b {
+        (a, b)
+    } else {
+        (b, a)
+    }
+}
+
+/// 쌍별 최신 상관계수 조회.
+///
+/// GET /api/v1/analytics/correlation/latest
+pub async fn get_latest_correlations(
+    State(state): State<Arc<AppState>>,
+) -> ApiResult<Json<CorrelationLatestResponse>> {
+    let pool = state.analytics_db_pool().ok_or_else(db_unavailable)?;
+    let snapshots = CorrelationRepository::latest_snapshots(pool)
+        .await
+        .map_err(db_error_response)?;
+
+    Ok(Json(CorrelationLatestResponse {
+        total: snapshots.len(),
+        snapshots,
+    }))
+}
+
+/// 자산 쌍의 롤링 상관계수 이력 조회.
+///
+/// GET /api/v1/analytics/correlation/history?symbol_a=KOSPI&symbol_b=USDKRW&days=180
+pub async fn get_correlation_history(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<CorrelationHistoryQuery>,
+) -> ApiResult<Json<CorrelationHistoryResponse>> {
+    let (symbol_a, symbol_b) = ordered_pair(&query.symbol_a, &query.symbol_b);
+    if symbol_a.is_empty() || symbol_a == symbol_b {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiErrorResponse::new(
+                "INVALID_PAIR",
+                "symbol_a and symbol_b must be two different tickers",
+            )),
+        ));
+    }
+
+    let pool = state.analytics_db_pool().ok_or_else(db_unavailable)?;
+    let since = Utc::now().date_naive() - Duration::days(query.days.max(1));
+    let history = CorrelationRepository::history(pool, &symbol_a, &symbol_b, since)
+        .await
+        .map_err(db_error_response)?;
+
+    Ok(Json(CorrelationHistoryResponse {
+        symbol_a,
+        symbol_b,
+        total: history.len(),
+        history,
+    }))
+}
+
+/// 상관관계 레짐 변화 알림 조회.
+///
+/// GET /api/v1/analytics/correlation/alerts?ticker=BTC-USD&limit=50
+pub async fn get_correlation_alerts(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<CorrelationAlertsQuery>,
+) -> ApiResult<Json<CorrelationAlertsResponse>> {
+    let pool = state.analytics_db_pool().ok_or_else(db_unavailable)?;
+    let ticker = query.ticker.map(|t| t.trim().to_uppercase());
+    let limit = query.limit.clamp(1, MAX_ALERT_LIMIT);
+
+    let alerts = CorrelationRepository::list_alerts(pool, ticker.as_deref(), limit)
+        .await
+        .map_err(db_error_response)?;
+
+    Ok(Json(CorrelationAlertsResponse {
+        total: alerts.len(),
+        alerts,
+    }))
+}
+
+/// 모니터링 대상 자산 목록.
+///
+/// GET /api/v1/analytics/correlation/assets
+pub async fn list_correlation_assets(
+    State(state): State<Arc<AppState>>,
+) -> ApiResult<Json<CorrelationAssetsResponse>> {
+    let pool = require_pool(&state)?;
+    let assets = CorrelationRepository::list_assets(pool)
+        .await
+        .map_err(db_error_response)?;
+
+    Ok(Json(CorrelationAssetsResponse {
+        total: assets.len(),
+        assets,
+    }))
+}
+
+/// 모니터링 대상 자산 등록/수정.
+///
+/// POST /api/v1/analytics/correlation/assets
+///
+/// 티커는 `symbol_info`에 등록된 종목이어야 합니다 (일봉 조회에 사용).
+pub async fn upsert_correlation_asset(
+    State(state): State<Arc<AppState>>,
+    Json(request): Json<CorrelationAssetRequest>,
+) -> ApiResult<Json<CorrelationAssetRecord>> {
+    let pool = require_pool(&state)?;
+    let ticker = request.ticker.trim().to_uppercase();
+    if ticker.is_empty() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiErrorResponse::new(
+                "INVALID_TICKER",
+                "ticker is required",
+            )),
+        ));
+    }
+
+    let known = SymbolInfoRepository::get_by_ticker(pool, &ticker, None)
+        .await
+        .map_err(db_error_response)?;
+    if known.is_none() {
+        return Err((
+            StatusCode::NOT_FOUND,
+            Json(ApiErrorResponse::new(
+                "SYMBOL_NOT_FOUND",
+                format!("Symbol not found: {}", ticker),
+            )),
+        ));
+    }
+
+    let label = request
+        .label
+        .map(|l| l.trim().to_string())
+        .filter(|l| !l.is_empty())
+        .unwrap_or_else(|| ticker.clone());
+
+    let asset = CorrelationRepository::upsert_asset(pool, &ticker, &label, request.enabled)
+        .await
+        .map_err(db_error_response)?;
+    info!(ticker = %asset.ticker, enabled = asset.enabled, "Correlation asset saved");
+
+    Ok(Json(asset))
+}
+
+/// 모니터링 대상 자산 삭제 (이력은 유지).
+///
+/// DELETE /api/v1/analytics/correlation/assets/{ticker}
+pub async fn delete_correlation_asset(
+    State(state): State<Arc<AppState>>,
+    Path(ticker): Path<String>,
+) -> ApiResult<StatusCode> {
+    let pool = require_pool(&state)?;
+    let ticker = ticker.trim().to_uppercase();
+
+    let deleted = CorrelationRepository::delete_asset(pool, &ticker)
+        .await
+        .map_err(db_error_response)?;
+    if !deleted {
+        return Err((
+            StatusCode::NOT_FOUND,
+            Json(ApiErrorResponse::new(
+                "CORRELATION_ASSET_NOT_FOUND",
+                format!("Correlation asset not found: {}", ticker),
+            )),
+        ));
+    }
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ordered_pair() {
+        assert_eq!(
+            ordered_pair(" usdkrw", "KOSPI "),
+            ("KOSPI".to_string(), "USDKRW".to_string())
+        );
+        assert_eq!(
+            ordered_pair("BTC-USD", "SPX"),
+            ("BTC-USD".to_string(), "SPX".to_string())
+        );
+    }
+}
@@ -0,0 +1,418 @@
+//! 익스포저 히트맵 핸들러.
+//!
+//! 실행기의 PositionTracker에 있는 오픈 포지션을 전략/종목/기초자산/섹터/통화별로 집계하고
+//! 리스크 한도(종목별 최대 포지션 비율, 전략 자본, 총 익스포저)를 함께 반환합니다.
+
+use axum::{
+    extract::{Query, State},
+    Json,
+};
+use chrono::Utc;
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tracing::warn;
+use trader_analytics::{underlying_key, UnderlyingAsset};
+use trader_core::Position;
+use trader_risk::RiskConfig;
+
+use crate::repository::{SymbolClassification, SymbolInfoRepository, UnderlyingRepository};
+use crate::state::AppState;
+
+use super::types::{
+    ExposureBucket, ExposureCell, ExposureDimension, ExposureHeatmapResponse, ExposureMatrix,
+    ExposureQuery,
+};
+
+/// 전략 미지정 포지션의 키.
+const UNASSIGNED_STRATEGY: &str = "unassigned";
+
+/// 섹터 미확인 종목의 키.
+const UNKNOWN_SECTOR: &str = "unknown";
+
+/// 익스포저 히트맵 조회.
+///
+/// GET /api/v1/analytics/exposure
+///
+/// # Query Parameters
+/// - `rows`: 행렬의 행 축 (strategy, symbol, underlying, sector, currency / 기본: strategy)
+/// - `columns`: 행렬의 열 축 (기본: sector)
+pub async fn get_exposure_heatmap(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<ExposureQuery>,
+) -> Json<ExposureHeatmapResponse> {
+    let (positions, equity, config, strategy_budgets) = {
+        let executor = state.executor.read().await;
+        let positions: Vec<Position> = executor
+            .position_tracker()
+            .read()
+            .await
+            .get_open_positions()
+            .into_iter()
+            .cloned()
+            .collect();
+
+        let risk_manager = executor.risk_manager().read().await;
+        let strategy_budgets: HashMap<String, Decimal> = risk_manager
+            .capital_ledger()
+            .accounts()
+            .into_iter()
+            .map(|account| (account.strategy_id.clone(), account.equity()))
+            .collect();
+
+        (
+            positions,
+            risk_manager.balance(),
+            risk_manager.config().clone(),
+            strategy_budgets,
+        )
+    };
+
+    // 섹터/통화 분류 (DB 미연결 또는 실패 시 티커 기반 추정)
+    let mut classifications = HashMap::new();
+    let mut underlyings = HashMap::new();
+    if let Some(pool) = &state.db_pool {
+        let tickers: Vec<String> = positions.iter().map(|p| p.ticker.clone()).collect();
+        match SymbolInfoRepository::get_classifications(pool, &tickers).await {
+            Ok(rows) => {
+                classifications = rows
+                    .into_iter()
+                    .map(|c| (c.ticker.to_uppercase(), c))
+                    .collect();
+            }
+            Err(e) => warn!("익스포저 히트맵 심볼 분류 조회 실패: {}", e),
+        }
+        match UnderlyingRepository::load_map(pool).await {
+            Ok(map) => underlyings = map,
+            Err(e) => warn!("익스포저 히트맵 기초자산 매핑 조회 실패: {}", e),
+        }
+    }
+
+    Json(build_exposure_heatmap(
+        &positions,
+        &classifications,
+        &underlyings,
+        equity,
+        &config,
+        &strategy_budgets,
+        query.rows,
+        query.columns,
+    ))
+}
+
+/// 오픈 포지션으로 익스포저 히트맵 계산.
+///
+/// 명목 익스포저는 통화 환산 없이 합산되며, 비율은 계좌 자산 대비입니다.
+/// 기초자산 매핑이 없는 종목은 티커가 기초자산 키입니다.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn build_exposure_heatmap(
+    positions: &[Position],
+    classifications: &HashMap<String, SymbolClassification>,
+    underlyings: &HashMap<String, UnderlyingAsset>,
+    equity: Decimal,
+    config: &RiskConfig,
+    strategy_budgets: &HashMap<String, Decimal>,
+    rows: ExposureDimension,
+    columns: ExposureDimension,
+) -> ExposureHeatmapResponse {
+    let pct = |amount: Decimal| -> f64 {
+        if equity > Decimal::ZERO {
+            (amount / equity * Decimal::from(100))
+                .to_f64()
+                .unwrap_or(0.0)
+        } else {
+            0.0
+        }
+    };
+
+    let cells: Vec<ExposureCell> = positions
+        .iter()
+        .filter(|p| p.is_open())
+        .map(|position| {
+            let classification = classifications.get(&position.ticker.to_uppercase());
+            let exposure = position.notional_value().abs();
+            ExposureCell {
+                strategy_id: position
+                    .strategy_id
+                    .clone()
+                    .unwrap_or_else(|| UNASSIGNED_STRATEGY.to_string()),
+                symbol: position.ticker.clone(),
+                underlying: underlying_key(&position.ticker, underlyings),
+                sector: classification
+                    .and_then(|c| c.sector.clone())
+                    .filter(|s| !s.is_empty())
+                    .unwrap_or_else(|| UNKNOWN_SECTOR.to_string()),
+                currency: resolve_currency(&position.ticker, classification),
+                exposure,
+                exposure_pct: pct(exposure),
+            }
+        })
+        .collect();
+
+    let total_exposure: Decimal = cells.iter().map(|c| c.exposure).sum();
+
+    let bucket = |dimension: ExposureDimension| -> Vec<ExposureBucket> {
+        aggregate(&cells, dimension, |key| match dimension {
+            ExposureDimension::Strategy => strategy_budgets.get(key).map(|budget| pct(*budget)),
+            ExposureDimension::Symbol => Some(config.get_max_position_pct(key)),
+            ExposureDimension::Underlying
+            | ExposureDimension::Sector
+            | ExposureDimension::Currency => None,
+        })
+        .into_iter()
+        .map(|(key, exposure, position_count, limit_pct)| {
+            let exposure_pct = pct(exposure);
+            let utilization_pct = limit_pct
+                .filter(|limit| *limit > 0.0)
+                .map(|limit| exposure_pct / limit * 100.0);
+            ExposureBucket {
+                key,
+                exposure,
+                exposure_pct,
+                position_count,
+                limit_pct,
+                utilization_pct,
+                breached: utilization_pct.is_some_and(|u| u > 100.0),
+            }
+        })
+        .collect()
+    };
+
+    let by_strategy = bucket(ExposureDimension::Strategy);
+    let by_symbol = bucket(ExposureDimension::Symbol);
+    let by_underlying = bucket(ExposureDimension::Underlying);
+    let by_sector = bucket(ExposureDimension::Sector);
+    let by_currency = bucket(ExposureDimension::Currency);
+
+    let labels = |dimension: ExposureDimension| -> Vec<String> {
+        let buckets = match dimension {
+            ExposureDimension::Strategy => &by_strategy,
+            ExposureDimension::Symbol => &by_symbol,
+            ExposureDimension::Underlying => &by_underlying,
+            ExposureDimension::Sector => &by_sector,
+            ExposureDimension::Currency => &by_currency,
+        };
+        buckets.iter().map(|b| b.key.clone()).collect()
+    };
+
+    let row_labels = labels(rows);
+    let column_labels = labels(columns);
+    let mut values = vec![vec![0.0; column_labels.len()]; row_labels.len()];
+    for cell in &cells {
+        let row = row_labels
+            .iter()
+            .position(|l| l == dimension_key(cell, rows));
+        let column = column_labels
+            .iter()
+            .position(|l| l == dimension_key(cell, columns));
+        if let (Some(row), Some(column)) = (row, column) {
+            values[row][column] += cell.exposure_pct;
+        }
+    }
+
+    ExposureHeatmapResponse {
+        equity,
+        total_exposure,
+        total_exposure_pct: pct(total_exposure),
+        total_limit_pct: config.max_total_exposure_pct,
+        cells,
+        by_strategy,
+        by_symbol,
+        by_underlying,
+        by_sector,
+        by_currency,
+        matrix: ExposureMatrix {
+            row_dimension: rows,
+            column_dimension: columns,
+            rows: row_labels,
+            columns: column_labels,
+            values,
+        },
+        timestamp: Utc::now(),
+    }
+}
+
+/// 셀의 축별 키.
+fn dimension_key(cell: &ExposureCell, dimension: ExposureDimension) -> &str {
+    match dimension {
+        ExposureDimension::Strategy => &cell.strategy_id,
+        ExposureDimension::Symbol => &cell.symbol,
+        ExposureDimension::Underlying => &cell.underlying,
+        ExposureDimension::Sector => &cell.sector,
+        ExposureDimension::Currency => &cell.currency,
+    }
+}
+
+/// 축별 집계 (익스포저 내림차순).
+///
+/// # Returns
+/// (키, 익스포저 합계, 포지션 수, 한도 %) 목록
+fn aggregate(
+    cells: &[ExposureCell],
+    dimension: ExposureDimension,
+    limit_for: impl Fn(&str) -> Option<f64>,
+) -> Vec<(String, Decimal, usize, Option<f64>)> {
+    let mut totals: HashMap<&str, (Decimal, usize)> = HashMap::new();
+    for cell in cells {
+        let entry = totals
+            .entry(dimension_key(cell, dimension))
+            .or_insert((Decimal::ZERO, 0));
+        entry.0 += cell.exposure;
+        entry.1 += 1;
+    }
+
+    let mut result: Vec<_> = totals
+        .into_iter()
+        .map(|(key, (exposure, count))| (key.to_string(), exposure, count, limit_for(key)))
+        .collect();
+    result.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    result
+}
+
+/// 종목 통화 결정.
+///
+/// 펀더멘털 통화 → 시장 → 티커 형식 순으로 판단합니다.
+fn resolve_currency(ticker: &str, classification: Option<&SymbolClassification>) -> String {
+    if let Some(currency) = classification
+        .and_then(|c| c.currency.as_deref())
+        .filter(|c| !c.is_empty())
+    {
+        return currency.to_uppercase();
+    }
+
+    if let Some(classification) = classification {
+        return match classification.market.to_uppercase().as_str() {
+            "KR" => "KRW",
+            "CRYPTO" => "USDT",
+            _ => "USD",
+        }
+        .to_string();
+    }
+
+    if let Some((_, quote)) = ticker.split_once('/') {
+        quote.to_uppercase()
+    } else if ticker.chars().all(|c| c.is_ascii_digit()) {
+        "KRW".to_string()
+    } else {
+        "USD".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+    use trader_core::Side;
+
+    fn position(ticker: &str, strategy: Option<&str>, qty: Decimal, price: Decimal) -> Position {
+        let position = Position::new("test", ticker.to_string(), Side::Buy, qty, price);
+        match strategy {
+            Some(id) => position.with_strategy(id),
+            None => position,
+        }
+    }
+
+    fn classification(ticker: &str, market: &str, sector: &str) -> SymbolClassification {
+        SymbolClassification {
+            ticker: ticker.to_string(),
+            market: market.to_string(),
+            sector: Some(sector.to_string()),
+            currency: None,
+        }
+    }
+
+    #[test]
+    fn test_build_exposure_heatmap() {
+        let positions = vec![
+            position("005930", Some("grid"), dec!(10), dec!(700)),
+            position("000660", Some("grid"), dec!(5), dec!(600)),
+            position("AAPL", Some("momentum"), dec!(1), dec!(1500)),
+            position("BTC/USDT", None, dec!(1), dec!(500)),
+            position("SPY", Some("momentum"), dec!(1), dec!(300)),
+            position("360750", None, dec!(2), dec!(100)),
+        ];
+        let classifications: HashMap<_, _> = [
+            classification("005930", "KR", "반도체"),
+            classification("000660", "KR", "반도체"),
+            classification("AAPL", "US", "Technology"),
+        ]
+        .into_iter()
+        .map(|c| (c.ticker.clone(), c))
+        .collect();
+        let budgets = HashMap::from([("grid".to_string(), dec!(5000))]);
+        let spx = UnderlyingAsset {
+            id: "SPX".to_string(),
+            name: Some("S&P 500".to_string()),
+        };
+        let underlyings = HashMap::from([
+            ("SPY".to_string(), spx.clone()),
+            ("360750".to_string(), spx),
+        ]);
+
+        let heatmap = build_exposure_heatmap(
+            &positions,
+            &classifications,
+            &underlyings,
+            dec!(100000),
+            &RiskConfig::default(),
+            &budgets,
+            ExposureDimension::Strategy,
+            ExposureDimension::Sector,
+        );
+
+        assert_eq!(heatmap.total_exposure, dec!(12500));
+        assert!((heatmap.total_exposure_pct - 12.5).abs() < 1e-9);
+
+        // 전략 한도: grid 자본 5000 (5%) 대비 10000 (10%) → 초과
+        let grid = &heatmap.by_strategy[0];
+        assert_eq!(grid.key, "grid");
+        assert_eq!(grid.position_count, 2);
+        assert_eq!(grid.limit_pct, Some(5.0));
+        assert!(grid.breached);
+        let momentum = heatmap
+            .by_strategy
+            .iter()
+            .find(|b| b.key == "momentum")
+            .unwrap();
+        assert!(momentum.limit_pct.is_none() && !momentum.breached);
+
+        // 종목 한도: 기본 최대 포지션 10%, 005930 7%
+        let samsung = heatmap
+            .by_symbol
+            .iter()
+            .find(|b| b.key == "005930")
+            .unwrap();
+        assert_eq!(samsung.limit_pct, Some(10.0));
+        assert!(!samsung.breached);
+
+        let currencies: Vec<_> = heatmap.by_currency.iter().map(|b| b.key.as_str()).collect();
+        assert_eq!(currencies, vec!["KRW", "USD", "USDT"]);
+
+        // 기초자산: SPY(미국 상장)와 360750(국내 상장)이 SPX 한 묶음
+        let spx = heatmap
+            .by_underlying
+            .iter()
+            .find(|b| b.key == "SPX")
+            .unwrap();
+        assert_eq!(spx.exposure, dec!(500));
+        assert_eq!(spx.position_count, 2);
+        assert!(heatmap.by_underlying.iter().any(|b| b.key == "AAPL"));
+
+        let sector = heatmap
+            .by_sector
+            .iter()
+            .find(|b| b.key == UNKNOWN_SECTOR)
+            .unwrap();
+        assert_eq!(sector.exposure, dec!(1000));
+
+        // 행렬: grid × 반도체 = 10%
+        let matrix = &heatmap.matrix;
+        let row = matrix.rows.iter().position(|r| r == "grid").unwrap();
+        let column = matrix.columns.iter().position(|c| c == "반도체").unwrap();
+        assert!((matrix.values[row][column] - 10.0).abs() < 1e-9);
+        assert_eq!(matrix.rows.len(), 3);
+        assert_eq!(matrix.row_dimension, ExposureDimension::Strategy);
+        assert_eq!(matrix.values[row].iter().sum::<f64>(), 10.0);
+    }
+}
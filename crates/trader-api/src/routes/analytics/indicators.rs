@@ -125,6 +125,15 @@ pub async fn get_available_indicators() -> impl IntoResponse {
             default_params: serde_json::json!({ "atr_period": 10, "multiplier": 3.0 }),
             overlay: true,
         },
+        IndicatorInfo {
+            id: "weekly_ma".to_string(),
+            name: "주봉 이동평균".to_string(),
+            description: "일봉을 주봉으로 묶어 이동평균을 계산합니다. 중기 추세 판단에 사용합니다."
+                .to_string(),
+            category: "추세".to_string(),
+            default_params: serde_json::json!({ "period": 20 }),
+            overlay: false,
+        },
     ];
 
     Json(AvailableIndicatorsResponse { indicators })
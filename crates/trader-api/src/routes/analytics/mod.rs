@@ -11,6 +11,9 @@
 //! - `GET /api/v1/analytics/charts/mdd` - MDD 추이 차트
 //! - `GET /api/v1/analytics/monthly-returns` - 월별 수익률
 //!
+//! ## 리스크 익스포저
+//! - `GET /api/v1/analytics/exposure` - 전략/종목/섹터/통화별 익스포저 히트맵 (한도 포함)
+//!
 //! ## 기술적 지표
 //! - `GET /api/v1/analytics/indicators` - 사용 가능한 지표 목록
 //! - `GET /api/v1/analytics/indicators/sma` - 단순 이동평균
@@ -21,18 +24,30 @@
 //! - `GET /api/v1/analytics/indicators/stochastic` - 스토캐스틱
 //! - `GET /api/v1/analytics/indicators/atr` - ATR
 //! - `POST /api/v1/analytics/indicators/calculate` - 다중 지표 계산
+//! - `GET /api/v1/analytics/indicators/weekly-ma` - 주봉 MA 및 주봉 추세 (실데이터)
+//!
+//! ## 상관관계
+//! - `GET /api/v1/analytics/correlation` - 종목 간 상관행렬
+//! - `GET /api/v1/analytics/correlation/latest` - 모니터링 쌍별 최신 롤링 상관계수
+//! - `GET /api/v1/analytics/correlation/history` - 쌍별 롤링 상관계수 이력
+//! - `GET /api/v1/analytics/correlation/alerts` - 상관관계 붕괴/급등 알림 이력
+//! - `GET/POST /api/v1/analytics/correlation/assets` - 모니터링 대상 자산 조회/등록
+//! - `DELETE /api/v1/analytics/correlation/assets/{ticker}` - 모니터링 대상 자산 삭제
 
 mod charts;
+mod correlation;
+mod exposure;
 mod indicators;
 pub mod manager;
 mod performance;
 mod sync;
 pub mod types;
+mod weekly_ma;
 
 // Re-export types
 pub use types::{
-    ChartQuery, ChartResponse, EquityCurveResponse, MonthlyReturnsResponse, PerformanceResponse,
-    PeriodQuery,
+    ChartQuery, ChartResponse, EquityCurveResponse, ExposureDimension, ExposureHeatmapResponse,
+    MonthlyReturnsResponse, PerformanceResponse, PeriodQuery,
 };
 
 // Re-export manager
@@ -46,6 +61,11 @@ use crate::state::AppState;
 use charts::{
     get_cagr_chart, get_drawdown_chart, get_equity_curve, get_mdd_chart, get_monthly_returns,
 };
+use correlation::{
+    delete_correlation_asset, get_correlation_alerts, get_correlation_history,
+    get_latest_correlations, list_correlation_assets, upsert_correlation_asset,
+};
+use exposure::get_exposure_heatmap;
 use indicators::{
     calculate_indicators, get_atr_indicator, get_available_indicators, get_bollinger_indicator,
     get_correlation, get_ema_indicator, get_keltner_indicator, get_macd_indicator,
@@ -53,7 +73,9 @@ use indicators::{
     get_supertrend_indicator, get_volume_profile, get_vwap_indicator,
 };
 use performance::get_performance;
+pub(crate) use performance::parse_period_duration;
 use sync::{clear_equity_cache, sync_equity_curve};
+use weekly_ma::get_weekly_ma_indicator;
 
 /// 포트폴리오 분석 라우터 생성.
 pub fn analytics_router() -> Router<Arc<AppState>> {
@@ -65,6 +87,8 @@ pub fn analytics_router() -> Router<Arc<AppState>> {
         .route("/charts/mdd", get(get_mdd_chart))
         .route("/charts/drawdown", get(get_drawdown_chart))
         .route("/monthly-returns", get(get_monthly_returns))
+        // 리스크 익스포저 히트맵
+        .route("/exposure", get(get_exposure_heatmap))
         // 자산 곡선 동기화
         .route("/sync-equity", axum::routing::post(sync_equity_curve))
         // 자산 곡선 캐시 삭제
@@ -87,5 +111,18 @@ pub fn analytics_router() -> Router<Arc<AppState>> {
         .route("/indicators/keltner", get(get_keltner_indicator))
         .route("/indicators/obv", get(get_obv_indicator))
         .route("/indicators/supertrend", get(get_supertrend_indicator))
+        .route("/indicators/weekly-ma", get(get_weekly_ma_indicator))
         .route("/correlation", get(get_correlation))
+        // 시장 간 상관관계 모니터링
+        .route("/correlation/latest", get(get_latest_correlations))
+        .route("/correlation/history", get(get_correlation_history))
+        .route("/correlation/alerts", get(get_correlation_alerts))
+        .route(
+            "/correlation/assets",
+            get(list_correlation_assets).post(upsert_correlation_asset),
+        )
+        .route(
+            "/correlation/assets/{ticker}",
+            axum::routing::delete(delete_correlation_asset),
+        )
 }
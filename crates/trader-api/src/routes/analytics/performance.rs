@@ -176,7 +176,7 @@ pub async fn get_performance(
     Query(query): Query<PeriodQuery>,
 ) -> impl IntoResponse {
     // DB에서 실제 데이터 조회 시도
-    if let Some(db_pool) = &state.db_pool {
+    if let Some(db_pool) = state.analytics_db_pool() {
         let duration = parse_period_duration(&query.period);
         let start_time = Utc::now() - duration;
         let end_time = Utc::now();
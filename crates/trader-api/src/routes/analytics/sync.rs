@@ -1,6 +1,10 @@
 //! 자산 곡선 동기화 핸들러.
 //!
 //! KIS API에서 체결 내역을 가져와 자산 곡선 데이터를 재구성합니다.
+//!
+//! 체결 내역은 날짜 청크 단위로 캐시에 저장되고 자산 곡선은 계좌별 체크포인트 이후
+//! (소급 체결이 있으면 그 일자 이후)만 다시 계산하므로, 중간에 실패해도 다음 요청에서
+//! 이어서 진행됩니다.
 
 use axum::{extract::State, response::IntoResponse, Json};
 use chrono::{DateTime, NaiveDate, Utc};
@@ -11,7 +15,7 @@ use uuid::Uuid;
 
 use crate::repository::{
     create_kis_kr_client_from_credential, EquityHistoryRepository, ExecutionCacheRepository,
-    ExecutionForSync, NewExecution,
+    ExecutionForSync, NewExecution, UpsertOutcome,
 };
 use crate::state::AppState;
 use trader_core::Side;
@@ -23,6 +27,7 @@ use super::types::{SyncEquityCurveRequest, SyncEquityCurveResponse};
 /// POST /api/v1/analytics/sync-equity
 ///
 /// KIS API에서 체결 내역을 가져와 자산 곡선 데이터를 재구성합니다.
+/// 응답의 `changes`에 신규/갱신 체결 수, 재계산 시작일, 소급 여부가 포함됩니다.
 pub async fn sync_equity_curve(
     State(state): State<Arc<AppState>>,
     Json(request): Json<SyncEquityCurveRequest>,
@@ -35,6 +40,7 @@ pub async fn sync_equity_curve(
                 axum::http::StatusCode::BAD_REQUEST,
                 Json(SyncEquityCurveResponse {
                     success: false,
+                    changes: None,
                     synced_count: 0,
                     execution_count: 0,
                     start_date: request.start_date,
@@ -53,6 +59,7 @@ pub async fn sync_equity_curve(
                 axum::http::StatusCode::INTERNAL_SERVER_ERROR,
                 Json(SyncEquityCurveResponse {
                     success: false,
+                    changes: None,
                     synced_count: 0,
                     execution_count: 0,
                     start_date: request.start_date.clone(),
@@ -70,6 +77,7 @@ pub async fn sync_equity_curve(
                 axum::http::StatusCode::INTERNAL_SERVER_ERROR,
                 Json(SyncEquityCurveResponse {
                     success: false,
+                    changes: None,
                     synced_count: 0,
                     execution_count: 0,
                     start_date: request.start_date.clone(),
@@ -89,6 +97,7 @@ pub async fn sync_equity_curve(
                 axum::http::StatusCode::INTERNAL_SERVER_ERROR,
                 Json(SyncEquityCurveResponse {
                     success: false,
+                    changes: None,
                     synced_count: 0,
                     execution_count: 0,
                     start_date: request.start_date.clone(),
@@ -120,6 +129,7 @@ pub async fn sync_equity_curve(
                 axum::http::StatusCode::NOT_FOUND,
                 Json(SyncEquityCurveResponse {
                     success: false,
+                    changes: None,
                     synced_count: 0,
                     execution_count: 0,
                     start_date: request.start_date.clone(),
@@ -133,6 +143,7 @@ pub async fn sync_equity_curve(
                 axum::http::StatusCode::INTERNAL_SERVER_ERROR,
                 Json(SyncEquityCurveResponse {
                     success: false,
+                    changes: None,
                     synced_count: 0,
                     execution_count: 0,
                     start_date: request.start_date.clone(),
@@ -160,61 +171,37 @@ pub async fn sync_equity_curve(
 
     // 요청된 날짜 파싱
     let requested_start = NaiveDate::parse_from_str(&request.start_date, "%Y-%m-%d")
-        .unwrap_or_else(|_| NaiveDate::from_ymd_opt(2024, 1, 1).unwrap());
+        .unwrap_or_else(|_| NaiveDate::from_ymd_opt(2024, 1, 1).unwrap_or_default());
     let requested_end = NaiveDate::parse_from_str(&request.end_date, "%Y-%m-%d")
         .unwrap_or_else(|_| chrono::Utc::now().date_naive());
 
-    // DB에서 마지막 캐시 일자 확인
-    let (actual_start, cached_executions) = if let Some(pool) = &state.db_pool {
+    // DB에서 마지막 캐시 일자 확인 (청크 단위로 갱신되므로 중단 지점부터 재개)
+    // 지연 등록/소급 체결을 잡기 위해 lookback_days 만큼 겹쳐서 다시 조회
+    let actual_start =
         match ExecutionCacheRepository::get_latest_cached_date(pool, credential_id, exchange_name)
             .await
         {
             Ok(Some(latest_date)) => {
-                // 캐시가 있으면 그 다음날부터 조회
-                let new_start = latest_date + chrono::Duration::days(1);
+                let lookback = chrono::Duration::days(request.lookback_days.max(0));
+                let new_start = std::cmp::max(requested_start, latest_date - lookback);
                 info!(
-                    "Cache found: latest_date={}, querying from {}",
-                    latest_date, new_start
+                    "Cache found: latest_date={}, querying from {} (lookback {}d)",
+                    latest_date, new_start, request.lookback_days
                 );
-
-                // 기존 캐시 데이터 조회
-                let cached = ExecutionCacheRepository::get_all_executions(
-                    pool,
-                    credential_id,
-                    exchange_name,
-                )
-                .await
-                .unwrap_or_default();
-                (new_start, cached)
+                new_start
             }
             Ok(None) => {
                 info!(
                     "No cache found, querying from requested start: {}",
                     requested_start
                 );
-                (requested_start, Vec::new())
+                requested_start
             }
             Err(e) => {
                 warn!("Failed to check cache: {}, querying full range", e);
-                (requested_start, Vec::new())
+                requested_start
             }
-        }
-    } else {
-        (requested_start, Vec::new())
-    };
-
-    // 캐시된 데이터를 ExecutionForSync로 변환
-    let mut all_executions: Vec<ExecutionForSync> = cached_executions
-        .iter()
-        .map(|c| ExecutionForSync {
-            execution_time: c.executed_at,
-            amount: c.amount,
-            is_buy: c.side == Side::Buy,
-            symbol: c.symbol.clone(),
-        })
-        .collect();
-
-    info!("Starting with {} cached executions", all_executions.len());
+        };
 
     // KIS API Rate Limit (2024.04.01 변경):
     // - 실계좌: 200ms (초당 5건)
@@ -225,281 +212,293 @@ pub async fn sync_equity_curve(
         200
     };
 
-    // 이미 최신 데이터가 있으면 API 호출 스킵
-    if actual_start > requested_end {
-        info!("Cache is up to date, skipping API call");
-    } else {
-        // 날짜 형식 변환 (ISO 8601 -> YYYYMMDD)
-        let start_date_yyyymmdd = actual_start.format("%Y%m%d").to_string();
-        let end_date_yyyymmdd = requested_end.format("%Y%m%d").to_string();
-        debug!(
-            "Date range for API: {} ~ {}",
-            start_date_yyyymmdd, end_date_yyyymmdd
-        );
-
-        // 날짜 범위 생성 (ISA: 1년 단위, 일반: 3개월 단위로 분할)
-        let date_ranges: Vec<(String, String)> = {
-            let mut ranges = Vec::new();
-            let mut current_start = actual_start;
+    // 날짜 범위 생성 (ISA: 1년 단위, 일반: 3개월 단위로 분할)
+    let date_ranges: Vec<(NaiveDate, NaiveDate)> = {
+        let mut ranges = Vec::new();
+        let mut current_start = actual_start;
 
-            // ISA 계좌: 1년 단위, 일반 계좌: 3개월 단위 (API 제한에 맞춤)
-            let max_days = if is_isa_account { 365 } else { 90 };
+        // ISA 계좌: 1년 단위, 일반 계좌: 3개월 단위 (API 제한에 맞춤)
+        let max_days = if is_isa_account { 365 } else { 90 };
 
-            while current_start <= requested_end {
-                let current_end = std::cmp::min(
-                    current_start + chrono::Duration::days(max_days - 1),
-                    requested_end,
-                );
-                ranges.push((
-                    current_start.format("%Y%m%d").to_string(),
-                    current_end.format("%Y%m%d").to_string(),
-                ));
-                current_start = current_end + chrono::Duration::days(1);
-            }
+        while current_start <= requested_end {
+            let current_end = std::cmp::min(
+                current_start + chrono::Duration::days(max_days - 1),
+                requested_end,
+            );
+            ranges.push((current_start, current_end));
+            current_start = current_end + chrono::Duration::days(1);
+        }
 
-            if ranges.is_empty() {
-                ranges.push((start_date_yyyymmdd.clone(), end_date_yyyymmdd.clone()));
-            }
+        ranges
+    };
 
-            ranges
-        };
+    info!(
+        "Date range split into {} chunks for {} account",
+        date_ranges.len(),
+        if is_isa_account { "ISA" } else { "general" }
+    );
 
-        info!(
-            "Date range split into {} chunks for {} account",
-            date_ranges.len(),
-            if is_isa_account { "ISA" } else { "general" }
-        );
+    // 4. 체결 내역 조회 (연속 조회로 전체 가져오기)
+    // KIS API는 초당 요청 수를 제한하므로 Rate Limiting 필요
+    let mut changes = UpsertOutcome::default();
+    let mut fetched_count = 0usize;
+    const MAX_PAGES: usize = 50; // 무한 루프 방지 (날짜 범위당)
+    debug!(
+        "Using API delay: {}ms (is_testnet: {})",
+        api_call_delay_ms, cred_info.is_testnet
+    );
 
-        // 4. 체결 내역 조회 (연속 조회로 전체 가져오기)
-        // KIS API는 초당 요청 수를 제한하므로 Rate Limiting 필요
-        let mut new_executions_for_cache: Vec<NewExecution> = Vec::new();
-        const MAX_PAGES: usize = 50; // 무한 루프 방지 (날짜 범위당)
+    // 각 날짜 범위에 대해 체결 내역 조회 후 즉시 캐시에 저장 (체크포인트)
+    for (range_idx, (chunk_start, chunk_end)) in date_ranges.iter().enumerate() {
+        let range_start = chunk_start.format("%Y%m%d").to_string();
+        let range_end = chunk_end.format("%Y%m%d").to_string();
         debug!(
-            "Using API delay: {}ms (is_testnet: {})",
-            api_call_delay_ms, cred_info.is_testnet
+            "Fetching date range {}/{}: {} ~ {}",
+            range_idx + 1,
+            date_ranges.len(),
+            range_start,
+            range_end
         );
 
-        // 각 날짜 범위에 대해 체결 내역 조회
-        for (range_idx, (range_start, range_end)) in date_ranges.iter().enumerate() {
-            debug!(
-                "Fetching date range {}/{}: {} ~ {}",
-                range_idx + 1,
-                date_ranges.len(),
-                range_start,
-                range_end
-            );
-
-            let mut ctx_fk = String::new();
-            let mut ctx_nk = String::new();
-            let mut prev_ctx_nk = String::new();
-            let mut page_count = 0;
+        let mut chunk_executions: Vec<NewExecution> = Vec::new();
+        let mut ctx_fk = String::new();
+        let mut ctx_nk = String::new();
+        let mut prev_ctx_nk = String::new();
+        let mut page_count = 0;
 
-            loop {
-                // Rate Limiting: 첫 번째 호출 이후에는 지연 적용
-                if page_count > 0 {
-                    tokio::time::sleep(std::time::Duration::from_millis(api_call_delay_ms)).await;
-                }
-                page_count += 1;
-
-                // 무한 루프 방지
-                if page_count > MAX_PAGES {
-                    warn!(
-                        "Max pagination limit reached ({} pages), stopping",
-                        MAX_PAGES
-                    );
-                    break;
-                }
+        loop {
+            // Rate Limiting: 첫 번째 호출 이후에는 지연 적용
+            if page_count > 0 {
+                tokio::time::sleep(std::time::Duration::from_millis(api_call_delay_ms)).await;
+            }
+            page_count += 1;
 
-                debug!(
-                    "Fetching order history page {} (ctx_fk={}, ctx_nk={})",
-                    page_count,
-                    ctx_fk.len(),
-                    ctx_nk.len()
+            // 무한 루프 방지
+            if page_count > MAX_PAGES {
+                warn!(
+                    "Max pagination limit reached ({} pages), stopping",
+                    MAX_PAGES
                 );
+                break;
+            }
+
+            debug!(
+                "Fetching order history page {} (ctx_fk={}, ctx_nk={})",
+                page_count,
+                ctx_fk.len(),
+                ctx_nk.len()
+            );
 
-                let history = match kr_client
-                    .get_order_history(
-                        range_start,
-                        range_end,
-                        "00", // 전체 (매수+매도)
-                        &ctx_fk,
-                        &ctx_nk,
-                    )
-                    .await
-                {
-                    Ok(h) => h,
-                    Err(e) => {
-                        // Rate Limit 에러인 경우 잠시 대기 후 재시도
-                        let error_msg = e.to_string();
-                        if error_msg.contains("초당")
-                            || error_msg.contains("건수")
-                            || error_msg.contains("exceeded")
-                        {
-                            warn!("Rate limit hit, waiting 2 seconds before retry...");
-                            tokio::time::sleep(std::time::Duration::from_secs(2)).await;
-
-                            // 재시도
-                            match kr_client
-                                .get_order_history(range_start, range_end, "00", &ctx_fk, &ctx_nk)
-                                .await
-                            {
-                                Ok(h) => h,
-                                Err(e2) => {
-                                    return (
-                                        axum::http::StatusCode::INTERNAL_SERVER_ERROR,
-                                        Json(SyncEquityCurveResponse {
-                                            success: false,
-                                            synced_count: 0,
-                                            execution_count: all_executions.len(),
-                                            start_date: request.start_date,
-                                            end_date: request.end_date,
-                                            message: format!(
-                                                "Failed to fetch order history after retry: {}",
-                                                e2
-                                            ),
-                                        }),
-                                    );
-                                }
+            let history = match kr_client
+                .get_order_history(
+                    &range_start,
+                    &range_end,
+                    "00", // 전체 (매수+매도)
+                    &ctx_fk,
+                    &ctx_nk,
+                )
+                .await
+            {
+                Ok(h) => h,
+                Err(e) => {
+                    // Rate Limit 에러인 경우 잠시 대기 후 재시도
+                    let error_msg = e.to_string();
+                    let retried = if error_msg.contains("초당")
+                        || error_msg.contains("건수")
+                        || error_msg.contains("exceeded")
+                    {
+                        warn!("Rate limit hit, waiting 2 seconds before retry...");
+                        tokio::time::sleep(std::time::Duration::from_secs(2)).await;
+                        kr_client
+                            .get_order_history(&range_start, &range_end, "00", &ctx_fk, &ctx_nk)
+                            .await
+                    } else {
+                        Err(e)
+                    };
+
+                    match retried {
+                        Ok(h) => h,
+                        Err(e) => {
+                            // 이전 청크는 이미 캐시에 저장되었으므로 다음 요청에서 이 청크부터 재개
+                            let message = format!(
+                                "Failed to fetch order history ({} ~ {}): {}. 다음 동기화는 이 구간부터 재개됩니다",
+                                range_start, range_end, e
+                            );
+                            if changes.changed_count() > 0 {
+                                let _ = EquityHistoryRepository::mark_sync_failed(
+                                    pool,
+                                    credential_id,
+                                    &message,
+                                )
+                                .await;
                             }
-                        } else {
                             return (
                                 axum::http::StatusCode::INTERNAL_SERVER_ERROR,
                                 Json(SyncEquityCurveResponse {
                                     success: false,
+                                    changes: None,
                                     synced_count: 0,
-                                    execution_count: 0,
+                                    execution_count: fetched_count,
                                     start_date: request.start_date,
                                     end_date: request.end_date,
-                                    message: format!("Failed to fetch order history: {}", e),
+                                    message,
                                 }),
                             );
                         }
                     }
-                };
+                }
+            };
+
+            debug!(
+                "Received {} executions in page {}",
+                history.executions.len(),
+                page_count
+            );
 
+            // 체결 내역 변환
+            for exec in history.executions {
+                // 체결 시간 파싱 (order_date: YYYYMMDD, order_time: HHMMSS)
+                let exec_date = format!("{}{}", exec.order_date, exec.order_time);
+                let execution_time =
+                    chrono::NaiveDateTime::parse_from_str(&exec_date, "%Y%m%d%H%M%S")
+                        .map(|dt| DateTime::<Utc>::from_naive_utc_and_offset(dt, Utc))
+                        .unwrap_or_else(|_| Utc::now());
+
+                let is_buy = exec.side_code == "02"; // 02: 매수
+                let side = if is_buy { Side::Buy } else { Side::Sell };
+
+                chunk_executions.push(NewExecution {
+                    credential_id,
+                    exchange: exchange_name.to_string(),
+                    executed_at: execution_time,
+                    symbol: exec.stock_code.clone(),
+                    normalized_symbol: Some(format!("{}.KS", exec.stock_code)),
+                    side,
+                    quantity: exec.filled_qty,
+                    price: exec.avg_price,      // 체결평균가
+                    amount: exec.filled_amount, // 총 체결 금액
+                    fee: None,
+                    fee_currency: Some("KRW".to_string()),
+                    order_id: exec.order_no.clone(),
+                    trade_id: None,
+                    order_type: None,
+                    raw_data: None,
+                });
+            }
+
+            // 연속 조회 확인
+            // 1. 데이터가 더 없으면 종료
+            if !history.has_more {
                 debug!(
-                    "Received {} executions in page {}",
-                    history.executions.len(),
-                    page_count
+                    "No more pages (has_more=false), {} executions collected in chunk",
+                    chunk_executions.len()
                 );
+                break;
+            }
 
-                // 체결 내역 변환
-                for exec in history.executions {
-                    // 체결 시간 파싱 (order_date: YYYYMMDD, order_time: HHMMSS)
-                    let exec_date = format!("{}{}", exec.order_date, exec.order_time);
-                    let execution_time =
-                        chrono::NaiveDateTime::parse_from_str(&exec_date, "%Y%m%d%H%M%S")
-                            .map(|dt| DateTime::<Utc>::from_naive_utc_and_offset(dt, Utc))
-                            .unwrap_or_else(|_| Utc::now());
-
-                    let amount = exec.filled_amount; // 총 체결 금액
-                    let is_buy = exec.side_code == "02"; // 02: 매수
-                    let side = if is_buy { Side::Buy } else { Side::Sell };
-
-                    // 동기화용 데이터 추가
-                    all_executions.push(ExecutionForSync {
-                        execution_time,
-                        amount,
-                        is_buy,
-                        symbol: exec.stock_code.clone(),
-                    });
-
-                    // 캐시용 데이터 추가
-                    new_executions_for_cache.push(NewExecution {
-                        credential_id,
-                        exchange: exchange_name.to_string(),
-                        executed_at: execution_time,
-                        symbol: exec.stock_code.clone(),
-                        normalized_symbol: Some(format!("{}.KS", exec.stock_code)),
-                        side,
-                        quantity: exec.filled_qty,
-                        price: exec.avg_price, // 체결평균가
-                        amount,
-                        fee: None,
-                        fee_currency: Some("KRW".to_string()),
-                        order_id: exec.order_no.clone(),
-                        trade_id: None,
-                        order_type: None,
-                        raw_data: None,
-                    });
-                }
+            // 2. 이전 키와 현재 키가 같으면 종료 (무한 루프 방지)
+            if prev_ctx_nk == history.ctx_area_nk100 && !prev_ctx_nk.is_empty() {
+                debug!("Same ctx_nk as previous, stopping (infinite loop prevention)");
+                break;
+            }
 
-                // 연속 조회 확인
-                // 1. 데이터가 더 없으면 종료
-                if !history.has_more {
-                    debug!(
-                        "No more pages (has_more=false), total {} executions collected",
-                        all_executions.len()
-                    );
-                    break;
-                }
+            // 3. NK 키가 비어있으면 종료
+            if history.ctx_area_nk100.is_empty() {
+                debug!("ctx_nk is empty, no more pages");
+                break;
+            }
 
-                // 2. 이전 키와 현재 키가 같으면 종료 (무한 루프 방지)
-                if prev_ctx_nk == history.ctx_area_nk100 && !prev_ctx_nk.is_empty() {
-                    debug!("Same ctx_nk as previous, stopping (infinite loop prevention)");
-                    break;
-                }
+            prev_ctx_nk = ctx_nk.clone();
+            ctx_fk = history.ctx_area_fk100;
+            ctx_nk = history.ctx_area_nk100;
+        }
 
-                // 3. NK 키가 비어있으면 종료
-                if history.ctx_area_nk100.is_empty() {
-                    debug!("ctx_nk is empty, no more pages");
-                    break;
-                }
+        fetched_count += chunk_executions.len();
 
-                prev_ctx_nk = ctx_nk.clone();
-                ctx_fk = history.ctx_area_fk100;
-                ctx_nk = history.ctx_area_nk100;
+        // 청크 단위 캐시 저장 + 메타데이터 갱신 (재개 지점)
+        let outcome = match ExecutionCacheRepository::upsert_executions_tracked(
+            pool,
+            &chunk_executions,
+        )
+        .await
+        {
+            Ok(outcome) => outcome,
+            Err(e) => {
+                return (
+                    axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(SyncEquityCurveResponse {
+                        success: false,
+                        changes: None,
+                        synced_count: 0,
+                        execution_count: fetched_count,
+                        start_date: request.start_date,
+                        end_date: request.end_date,
+                        message: format!("Failed to cache executions: {}", e),
+                    }),
+                );
             }
-        } // end of date range for loop
+        };
+        changes.merge(&outcome);
+
+        if let Err(e) = ExecutionCacheRepository::update_cache_meta(
+            pool,
+            credential_id,
+            exchange_name,
+            Some(*chunk_start),
+            Some(*chunk_end),
+            "success",
+            Some(&format!(
+                "{} ~ {}: 신규 {}건, 갱신 {}건",
+                range_start, range_end, outcome.inserted, outcome.updated
+            )),
+        )
+        .await
+        {
+            warn!("Failed to update cache meta: {}", e);
+        }
+    }
 
-        // 새로 조회한 체결 내역을 캐시에 저장
-        if !new_executions_for_cache.is_empty() {
-            if let Some(pool) = &state.db_pool {
-                info!(
-                    "Saving {} new executions to cache",
-                    new_executions_for_cache.len()
-                );
+    info!(
+        "Fetched {} executions (new: {}, updated: {}, unchanged: {}, earliest changed: {:?})",
+        fetched_count,
+        changes.inserted,
+        changes.updated,
+        changes.unchanged,
+        changes.earliest_changed_date
+    );
 
-                match ExecutionCacheRepository::upsert_executions(pool, &new_executions_for_cache)
-                    .await
-                {
-                    Ok(count) => {
-                        info!("Successfully cached {} executions", count);
-
-                        // 캐시 메타데이터 업데이트
-                        let earliest = new_executions_for_cache
-                            .iter()
-                            .map(|e| e.executed_at.date_naive())
-                            .min();
-                        let latest = new_executions_for_cache
-                            .iter()
-                            .map(|e| e.executed_at.date_naive())
-                            .max();
-
-                        if let (Some(earliest_date), Some(latest_date)) = (earliest, latest) {
-                            if let Err(e) = ExecutionCacheRepository::update_cache_meta(
-                                pool,
-                                credential_id,
-                                exchange_name,
-                                Some(earliest_date),
-                                Some(latest_date),
-                                "success",
-                                None,
-                            )
-                            .await
-                            {
-                                warn!("Failed to update cache meta: {}", e);
-                            }
-                        }
-                    }
-                    Err(e) => {
-                        warn!("Failed to cache executions: {}", e);
-                        // 캐시 실패해도 동기화는 계속 진행
-                    }
-                }
-            }
+    // 캐시를 단일 소스로 사용 (lookback 중복 조회분은 upsert로 제거됨)
+    let all_executions: Vec<ExecutionForSync> = match ExecutionCacheRepository::get_all_executions(
+        pool,
+        credential_id,
+        exchange_name,
+    )
+    .await
+    {
+        Ok(cached) => cached
+            .iter()
+            .map(|c| ExecutionForSync {
+                execution_time: c.executed_at,
+                amount: c.amount,
+                is_buy: c.side == Side::Buy,
+                symbol: c.symbol.clone(),
+            })
+            .collect(),
+        Err(e) => {
+            return (
+                axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+                Json(SyncEquityCurveResponse {
+                    success: false,
+                    changes: None,
+                    synced_count: 0,
+                    execution_count: fetched_count,
+                    start_date: request.start_date,
+                    end_date: request.end_date,
+                    message: format!("Failed to load cached executions: {}", e),
+                }),
+            );
         }
-    } // end of else block (API 호출 필요한 경우)
+    };
 
     let execution_count = all_executions.len();
 
@@ -525,15 +524,21 @@ pub async fn sync_equity_curve(
             (equity, cash)
         }
         Err(e) => {
+            let message = format!("Failed to fetch balance: {}", e);
+            if changes.changed_count() > 0 {
+                let _ =
+                    EquityHistoryRepository::mark_sync_failed(pool, credential_id, &message).await;
+            }
             return (
                 axum::http::StatusCode::INTERNAL_SERVER_ERROR,
                 Json(SyncEquityCurveResponse {
                     success: false,
+                    changes: None,
                     synced_count: 0,
                     execution_count,
                     start_date: request.start_date,
                     end_date: request.end_date,
-                    message: format!("Failed to fetch balance: {}", e),
+                    message,
                 }),
             );
         }
@@ -545,119 +550,82 @@ pub async fn sync_equity_curve(
         current_cash
     );
 
-    // 5. DB에 자산 곡선 저장
-    if let Some(pool) = &state.db_pool {
-        // 종가 기반 계산 vs 현금 흐름 기반 계산
-        if request.use_market_prices {
-            // 현재 실제 현금 잔고를 기준으로 과거 자산 역산
-            // (initial_capital 지정 시 해당 값을 현재 현금으로 사용 - 테스트용)
-            let cash_for_sync = request.initial_capital.unwrap_or(current_cash);
-
-            tracing::info!(
-                "Using market prices for equity calculation (current_cash: {})",
-                cash_for_sync
-            );
+    // 5. DB에 자산 곡선 저장 (체크포인트 기반 증분 재계산)
+    let sync_result = if request.use_market_prices {
+        // 현재 실제 현금 잔고를 기준으로 과거 자산 역산
+        // (initial_capital 지정 시 해당 값을 현재 현금으로 사용 - 테스트용)
+        let cash_for_sync = request.initial_capital.unwrap_or(current_cash);
 
-            match EquityHistoryRepository::sync_with_market_prices(
-                pool,
-                credential_id,
-                cash_for_sync, // 현재 실제 현금 잔고
-                "KRW",
-                "KR",
-                Some("real"),
-            )
-            .await
-            {
-                Ok(synced_count) => {
-                    return (
-                        axum::http::StatusCode::OK,
-                        Json(SyncEquityCurveResponse {
-                            success: true,
-                            synced_count,
-                            execution_count,
-                            start_date: request.start_date,
-                            end_date: request.end_date,
-                            message: format!(
-                                "Successfully synced {} equity points with market prices from {} executions",
-                                synced_count, execution_count
-                            ),
-                        }),
-                    );
-                }
-                Err(e) => {
-                    return (
-                        axum::http::StatusCode::INTERNAL_SERVER_ERROR,
-                        Json(SyncEquityCurveResponse {
-                            success: false,
-                            synced_count: 0,
-                            execution_count,
-                            start_date: request.start_date,
-                            end_date: request.end_date,
-                            message: format!(
-                                "Failed to save equity curve with market prices: {}",
-                                e
-                            ),
-                        }),
-                    );
-                }
-            }
-        } else {
-            // 기존 현금 흐름 기반 계산
-            match EquityHistoryRepository::sync_from_executions(
-                pool,
-                credential_id,
-                all_executions,
-                current_equity,
-                "KRW",
-                "KR",
-                Some("real"),
+        tracing::info!(
+            "Using market prices for equity calculation (current_cash: {})",
+            cash_for_sync
+        );
+
+        EquityHistoryRepository::sync_with_market_prices(
+            pool,
+            credential_id,
+            cash_for_sync, // 현재 실제 현금 잔고
+            "KRW",
+            "KR",
+            Some("real"),
+            &changes,
+        )
+        .await
+    } else {
+        // 기존 현금 흐름 기반 계산
+        EquityHistoryRepository::sync_from_executions(
+            pool,
+            credential_id,
+            all_executions,
+            current_equity,
+            "KRW",
+            "KR",
+            Some("real"),
+            &changes,
+        )
+        .await
+    };
+
+    match sync_result {
+        Ok(result) => {
+            let message = format!(
+                "Successfully synced {} equity points from {} executions (new: {}, updated: {}, recompute from: {}{})",
+                result.synced_count,
+                execution_count,
+                result.new_execution_count,
+                result.updated_execution_count,
+                result
+                    .recompute_from
+                    .map(|d| d.to_string())
+                    .unwrap_or_else(|| "-".to_string()),
+                if result.backdated { ", backdated" } else { "" }
+            );
+            (
+                axum::http::StatusCode::OK,
+                Json(SyncEquityCurveResponse {
+                    success: true,
+                    synced_count: result.synced_count,
+                    execution_count,
+                    start_date: request.start_date,
+                    end_date: request.end_date,
+                    message,
+                    changes: Some(result),
+                }),
             )
-            .await
-            {
-                Ok(synced_count) => {
-                    return (
-                        axum::http::StatusCode::OK,
-                        Json(SyncEquityCurveResponse {
-                            success: true,
-                            synced_count,
-                            execution_count,
-                            start_date: request.start_date,
-                            end_date: request.end_date,
-                            message: format!(
-                                "Successfully synced {} equity points from {} executions",
-                                synced_count, execution_count
-                            ),
-                        }),
-                    );
-                }
-                Err(e) => {
-                    return (
-                        axum::http::StatusCode::INTERNAL_SERVER_ERROR,
-                        Json(SyncEquityCurveResponse {
-                            success: false,
-                            synced_count: 0,
-                            execution_count,
-                            start_date: request.start_date,
-                            end_date: request.end_date,
-                            message: format!("Failed to save equity curve: {}", e),
-                        }),
-                    );
-                }
-            }
         }
+        Err(e) => (
+            axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+            Json(SyncEquityCurveResponse {
+                success: false,
+                changes: None,
+                synced_count: 0,
+                execution_count,
+                start_date: request.start_date,
+                end_date: request.end_date,
+                message: format!("Failed to save equity curve: {}", e),
+            }),
+        ),
     }
-
-    (
-        axum::http::StatusCode::SERVICE_UNAVAILABLE,
-        Json(SyncEquityCurveResponse {
-            success: false,
-            synced_count: 0,
-            execution_count,
-            start_date: request.start_date,
-            end_date: request.end_date,
-            message: "Database not available".to_string(),
-        }),
-    )
 }
 
 /// 자산 곡선 캐시 삭제 요청.
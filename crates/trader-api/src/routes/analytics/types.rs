@@ -6,6 +6,10 @@ use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use trader_analytics::portfolio::{ChartPoint, MonthlyReturnCell, PerformanceSummary};
 
+use crate::repository::{
+    CorrelationAlertRecord, CorrelationAssetRecord, CorrelationSnapshotRecord, SyncResult,
+};
+
 // ==================== 쿼리 파라미터 ====================
 
 /// 기간 필터 쿼리 파라미터.
@@ -511,6 +515,13 @@ pub struct SyncEquityCurveRequest {
     pub use_market_prices: bool,
     /// 초기 자본금 (종가 기반 계산 시 필수)
     pub initial_capital: Option<Decimal>,
+    /// 캐시 마지막 일자 이전으로 다시 조회할 일수 (지연/소급 체결 감지용, 기본: 7)
+    #[serde(default = "default_sync_lookback_days")]
+    pub lookback_days: i64,
+}
+
+fn default_sync_lookback_days() -> i64 {
+    7
 }
 
 /// 동기화 응답.
@@ -528,6 +539,9 @@ pub struct SyncEquityCurveResponse {
     pub end_date: String,
     /// 메시지
     pub message: String,
+    /// 증분 동기화 변경 내역 (성공 시)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub changes: Option<SyncResult>,
 }
 
 // ==================== Volume Profile 타입 ====================
@@ -618,6 +632,84 @@ pub struct CorrelationResponse {
     pub period: usize,
 }
 
+/// 상관계수 이력 쿼리.
+#[derive(Debug, Deserialize)]
+pub struct CorrelationHistoryQuery {
+    /// 쌍의 첫 번째 티커
+    pub symbol_a: String,
+    /// 쌍의 두 번째 티커 (순서 무관)
+    pub symbol_b: String,
+    /// 조회 일수 (기본: 180)
+    #[serde(default = "default_corr_history_days")]
+    pub days: i64,
+}
+
+fn default_corr_history_days() -> i64 {
+    180
+}
+
+/// 상관계수 이력 응답.
+#[derive(Debug, Serialize)]
+pub struct CorrelationHistoryResponse {
+    pub symbol_a: String,
+    pub symbol_b: String,
+    pub total: usize,
+    /// 관측일별 단기/장기 상관계수 (오래된 순)
+    pub history: Vec<CorrelationSnapshotRecord>,
+}
+
+/// 쌍별 최신 상관계수 응답.
+#[derive(Debug, Serialize)]
+pub struct CorrelationLatestResponse {
+    pub total: usize,
+    pub snapshots: Vec<CorrelationSnapshotRecord>,
+}
+
+/// 상관관계 레짐 변화 알림 쿼리.
+#[derive(Debug, Deserialize)]
+pub struct CorrelationAlertsQuery {
+    /// 특정 티커가 포함된 알림만 조회
+    pub ticker: Option<String>,
+    /// 최대 개수 (기본: 50, 최대 500)
+    #[serde(default = "default_corr_alert_limit")]
+    pub limit: i64,
+}
+
+fn default_corr_alert_limit() -> i64 {
+    50
+}
+
+/// 상관관계 레짐 변화 알림 응답.
+#[derive(Debug, Serialize)]
+pub struct CorrelationAlertsResponse {
+    pub total: usize,
+    /// 최신순
+    pub alerts: Vec<CorrelationAlertRecord>,
+}
+
+/// 모니터링 대상 자산 등록/수정 요청.
+#[derive(Debug, Deserialize)]
+pub struct CorrelationAssetRequest {
+    /// 티커 (symbol_info에 등록된 종목)
+    pub ticker: String,
+    /// 표시 이름 (기본: 티커)
+    pub label: Option<String>,
+    /// 활성화 여부 (기본: true)
+    #[serde(default = "default_corr_asset_enabled")]
+    pub enabled: bool,
+}
+
+fn default_corr_asset_enabled() -> bool {
+    true
+}
+
+/// 모니터링 대상 자산 목록 응답.
+#[derive(Debug, Serialize)]
+pub struct CorrelationAssetsResponse {
+    pub total: usize,
+    pub assets: Vec<CorrelationAssetRecord>,
+}
+
 // ==================== VWAP (거래량 가중 평균가격) 타입 ====================
 
 /// VWAP 요청 쿼리.
@@ -873,6 +965,185 @@ pub struct SuperTrendParamsResponse {
     pub multiplier: f64,
 }
 
+// ==================== Weekly MA (주봉 이동평균) 타입 ====================
+
+/// 주봉 MA 요청 쿼리.
+#[derive(Debug, Deserialize)]
+pub struct WeeklyMaQuery {
+    /// 종목 코드 (필수)
+    pub symbol: String,
+    /// 주봉 MA 기간 (주, 기본: 20)
+    #[serde(default = "default_weekly_ma_period")]
+    pub period: usize,
+    /// 반환할 최근 주봉 수 (기본: 52)
+    #[serde(default = "default_weekly_ma_weeks")]
+    pub weeks: usize,
+    /// 크로스 판정용 단기 MA 기간 (주, 지정 시 골든/데드크로스 반환)
+    pub short_period: Option<usize>,
+}
+
+fn default_weekly_ma_period() -> usize {
+    20
+}
+
+fn default_weekly_ma_weeks() -> usize {
+    52
+}
+
+/// 주봉 MA 데이터 포인트.
+#[derive(Debug, Serialize)]
+pub struct WeeklyMaPointResponse {
+    /// 주 시작일 (월요일)
+    pub week_start: chrono::NaiveDate,
+    /// 주봉 MA
+    pub ma: String,
+    /// 주봉 종가
+    pub weekly_close: String,
+    /// MA 대비 이격도 (%)
+    pub distance_pct: String,
+}
+
+/// 주봉 MA 응답.
+#[derive(Debug, Serialize)]
+pub struct WeeklyMaResponse {
+    /// 종목 코드
+    pub symbol: String,
+    /// 주봉 MA 기간
+    pub period: usize,
+    /// 주봉 MA 시리즈 (오래된 순)
+    pub data: Vec<WeeklyMaPointResponse>,
+    /// 데이터 포인트 수
+    pub count: usize,
+    /// 현재 MA 대비 이격도 (%)
+    pub current_distance_pct: Option<String>,
+    /// 현재 주봉 추세 (above, below, on, unknown)
+    pub regime: String,
+    /// 최근 주봉 크로스 (golden, dead; `short_period` 지정 시)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cross: Option<String>,
+}
+
+// ==================== Exposure Heatmap (익스포저 히트맵) 타입 ====================
+
+/// 익스포저 집계 축.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExposureDimension {
+    /// 전략
+    Strategy,
+    /// 종목
+    Symbol,
+    /// 기초자산 (교차 상장/동일 지수 추종 종목 통합)
+    Underlying,
+    /// 섹터
+    Sector,
+    /// 통화
+    Currency,
+}
+
+/// 익스포저 히트맵 요청 쿼리.
+#[derive(Debug, Deserialize)]
+pub struct ExposureQuery {
+    /// 행렬의 행 축 (기본: strategy)
+    #[serde(default = "default_exposure_rows")]
+    pub rows: ExposureDimension,
+    /// 행렬의 열 축 (기본: sector)
+    #[serde(default = "default_exposure_columns")]
+    pub columns: ExposureDimension,
+}
+
+fn default_exposure_rows() -> ExposureDimension {
+    ExposureDimension::Strategy
+}
+
+fn default_exposure_columns() -> ExposureDimension {
+    ExposureDimension::Sector
+}
+
+/// 포지션 단위 익스포저 셀.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExposureCell {
+    /// 전략 ID (미지정 시 "unassigned")
+    pub strategy_id: String,
+    /// 종목 코드
+    pub symbol: String,
+    /// 기초자산 키 (매핑이 없으면 티커)
+    pub underlying: String,
+    /// 섹터 (미확인 시 "unknown")
+    pub sector: String,
+    /// 통화
+    pub currency: String,
+    /// 명목 익스포저 (현재가 × 수량)
+    pub exposure: Decimal,
+    /// 계좌 자산 대비 비율 (%)
+    pub exposure_pct: f64,
+}
+
+/// 축별 익스포저 집계.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExposureBucket {
+    /// 집계 키 (전략 ID, 종목, 기초자산, 섹터, 통화)
+    pub key: String,
+    /// 명목 익스포저 합계
+    pub exposure: Decimal,
+    /// 계좌 자산 대비 비율 (%)
+    pub exposure_pct: f64,
+    /// 포지션 수
+    pub position_count: usize,
+    /// 한도 (%), 설정된 경우
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit_pct: Option<f64>,
+    /// 한도 사용률 (%), 한도가 있는 경우
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub utilization_pct: Option<f64>,
+    /// 한도 초과 여부
+    pub breached: bool,
+}
+
+/// 히트맵 행렬 (행 × 열, 계좌 자산 대비 %).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExposureMatrix {
+    /// 행 축
+    pub row_dimension: ExposureDimension,
+    /// 열 축
+    pub column_dimension: ExposureDimension,
+    /// 행 라벨
+    pub rows: Vec<String>,
+    /// 열 라벨
+    pub columns: Vec<String>,
+    /// 익스포저 비율 행렬 (rows.len() × columns.len())
+    pub values: Vec<Vec<f64>>,
+}
+
+/// 익스포저 히트맵 응답.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExposureHeatmapResponse {
+    /// 계좌 자산 (RiskManager 잔고)
+    pub equity: Decimal,
+    /// 총 명목 익스포저 (통화 환산 없이 합산)
+    pub total_exposure: Decimal,
+    /// 계좌 자산 대비 총 익스포저 (%)
+    pub total_exposure_pct: f64,
+    /// 총 익스포저 한도 (%)
+    pub total_limit_pct: f64,
+    /// 포지션 단위 셀
+    pub cells: Vec<ExposureCell>,
+    /// 전략별 집계 (한도: 전략 자본 원장 기준)
+    pub by_strategy: Vec<ExposureBucket>,
+    /// 종목별 집계 (한도: 최대 포지션 비율)
+    pub by_symbol: Vec<ExposureBucket>,
+    /// 기초자산별 집계
+    pub by_underlying: Vec<ExposureBucket>,
+    /// 섹터별 집계
+    pub by_sector: Vec<ExposureBucket>,
+    /// 통화별 집계
+    pub by_currency: Vec<ExposureBucket>,
+    /// 히트맵 행렬
+    pub matrix: ExposureMatrix,
+    /// 계산 시각
+    pub timestamp: chrono::DateTime<chrono::Utc>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
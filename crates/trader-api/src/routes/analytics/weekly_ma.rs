@@ -0,0 +1,146 @@
+//! 주봉 이동평균 핸들러.
+//!
+//! 일봉을 주봉으로 리샘플링해 주봉 MA와 이격도를 계산합니다.
+//! 전략의 주봉 추세 필터(`filter.weekly_trend`)와 같은 기준(주봉 종가 vs 주봉 MA)을 사용하므로
+//! 필터가 현재 진입을 허용하는지 미리 확인할 수 있습니다.
+
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    Json,
+};
+use rust_decimal::Decimal;
+use std::sync::Arc;
+use trader_analytics::indicators::{calculate_weekly_ma, detect_weekly_ma_cross};
+use trader_core::Timeframe;
+use trader_data::cache::CachedHistoricalDataProvider;
+
+use crate::error::{ApiErrorResponse, ApiResult};
+use crate::routes::common::db_unavailable;
+use crate::state::AppState;
+
+use super::types::{WeeklyMaPointResponse, WeeklyMaQuery, WeeklyMaResponse};
+
+/// 주봉 MA 최대 기간 (주).
+const MAX_WEEKLY_MA_PERIOD: usize = 104;
+
+/// 반환할 최대 주봉 수.
+const MAX_WEEKLY_MA_WEEKS: usize = 260;
+
+/// 이격도 부호로 주봉 추세 판정.
+fn regime_label(distance_pct: Option<Decimal>) -> &'static str {
+    match distance_pct {
+        Some(d) if d > Decimal::ZERO => "above",
+        Some(d) if d < Decimal::ZERO => "below",
+        Some(_) => "on",
+        None => "unknown",
+    }
+}
+
+/// 주봉 MA 조회.
+///
+/// GET /api/v1/analytics/indicators/weekly-ma?symbol=005930&period=20&weeks=52
+///
+/// # Query Parameters
+/// - `period`: 주봉 MA 기간 (기본: 20, 최대 104)
+/// - `weeks`: 반환할 최근 주봉 수 (기본: 52)
+/// - `short_period`: 지정 시 단기 주봉 MA와의 골든/데드크로스 판정
+pub async fn get_weekly_ma_indicator(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<WeeklyMaQuery>,
+) -> ApiResult<Json<WeeklyMaResponse>> {
+    let invalid = |msg: String| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(ApiErrorResponse::new("INVALID_PARAMS", msg)),
+        )
+    };
+
+    let symbol = query.symbol.trim().to_uppercase();
+    if symbol.is_empty() {
+        return Err(invalid("symbol is required".to_string()));
+    }
+    if !(2..=MAX_WEEKLY_MA_PERIOD).contains(&query.period) {
+        return Err(invalid(format!(
+            "period must be between 2 and {}",
+            MAX_WEEKLY_MA_PERIOD
+        )));
+    }
+    if let Some(short) = query.short_period {
+        if short < 1 || short >= query.period {
+            return Err(invalid(
+                "short_period must be between 1 and period - 1".to_string(),
+            ));
+        }
+    }
+    let weeks = query.weeks.clamp(1, MAX_WEEKLY_MA_WEEKS);
+
+    let provider = match (&state.data_provider, &state.db_pool) {
+        (Some(provider), _) => provider.clone(),
+        (None, Some(pool)) => Arc::new(CachedHistoricalDataProvider::new(pool.clone())),
+        (None, None) => return Err(db_unavailable()),
+    };
+
+    // 주 7거래일(암호화폐) 기준으로 넉넉히 로드
+    let limit = (query.period + weeks + 1) * 7;
+    let klines = provider
+        .get_klines(&symbol, Timeframe::D1, limit)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::BAD_GATEWAY,
+                Json(ApiErrorResponse::new(
+                    "DATA_FETCH_ERROR",
+                    format!("Failed to load klines: {}", e),
+                )),
+            )
+        })?;
+
+    let series = calculate_weekly_ma(&klines, query.period);
+    let current_distance = series.last().map(|r| r.distance_pct);
+    let cross = query.short_period.and_then(|short| {
+        detect_weekly_ma_cross(&klines, short, query.period).map(|golden| {
+            if golden {
+                "golden".to_string()
+            } else {
+                "dead".to_string()
+            }
+        })
+    });
+
+    let skip = series.len().saturating_sub(weeks);
+    let data: Vec<WeeklyMaPointResponse> = series
+        .into_iter()
+        .skip(skip)
+        .map(|r| WeeklyMaPointResponse {
+            week_start: r.week_start,
+            ma: r.value.round_dp(4).to_string(),
+            weekly_close: r.weekly_close.to_string(),
+            distance_pct: r.distance_pct.round_dp(2).to_string(),
+        })
+        .collect();
+
+    Ok(Json(WeeklyMaResponse {
+        symbol,
+        period: query.period,
+        count: data.len(),
+        data,
+        current_distance_pct: current_distance.map(|d| d.round_dp(2).to_string()),
+        regime: regime_label(current_distance).to_string(),
+        cross,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_regime_label() {
+        assert_eq!(regime_label(Some(dec!(3.2))), "above");
+        assert_eq!(regime_label(Some(dec!(-0.5))), "below");
+        assert_eq!(regime_label(Some(Decimal::ZERO)), "on");
+        assert_eq!(regime_label(None), "unknown");
+    }
+}
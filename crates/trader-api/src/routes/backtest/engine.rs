@@ -310,7 +310,11 @@ pub fn convert_report_to_response(
         commission_rate: report.config.commission_rate,
         slippage_rate: report.config.slippage_rate,
         total_commission: report.total_commission,
+        total_tax: report.total_tax,
         total_slippage: report.total_slippage,
+        total_cash_interest: report.total_cash_interest,
+        total_borrow_cost: report.total_borrow_cost,
+        forced_liquidations: report.forced_liquidations,
         data_points: report.data_points,
     };
 
@@ -325,6 +329,8 @@ pub fn convert_report_to_response(
         equity_curve,
         trades,
         config_summary,
+        persisted: false,
+        data_coverage: None,
     }
 }
 
@@ -394,7 +400,11 @@ pub fn convert_multi_report_to_response(
         commission_rate: report.config.commission_rate,
         slippage_rate: report.config.slippage_rate,
         total_commission: report.total_commission,
+        total_tax: report.total_tax,
         total_slippage: report.total_slippage,
+        total_cash_interest: report.total_cash_interest,
+        total_borrow_cost: report.total_borrow_cost,
+        forced_liquidations: report.forced_liquidations,
         data_points: report.data_points,
     };
 
@@ -410,6 +420,8 @@ pub fn convert_multi_report_to_response(
         trades,
         config_summary,
         data_points_by_symbol,
+        persisted: false,
+        data_coverage: None,
     }
 }
 
@@ -422,7 +434,7 @@ pub fn generate_multi_sample_klines(
     use rust_decimal::prelude::FromPrimitive;
 
     let mut result = HashMap::new();
-    let days = (end_date - start_date).num_days() as usize;
+    let days = (end_date - start_date).num_days().max(0) as usize;
 
     // 심볼별 기본 가격 설정 (다양성을 위해)
     let base_prices: HashMap<&str, f64> = [
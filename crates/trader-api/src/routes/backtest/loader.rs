@@ -2,33 +2,62 @@
 //!
 //! DB에서 캔들 데이터를 로드하거나 샘플 데이터를 생성하는 함수를 제공합니다.
 
-use chrono::{NaiveDate, TimeZone, Utc};
+use chrono::{NaiveDate, NaiveTime, TimeZone, Utc};
+use rust_decimal::prelude::FromPrimitive;
 use rust_decimal::Decimal;
 use std::collections::{HashMap, HashSet};
 use tracing::{info, warn};
 
-use trader_core::{Kline, MarketType, Symbol, Timeframe};
+use trader_analytics::backtest::{
+    backfill_leveraged_history, monthly_rebalance_dates, LeveragedEtfSpec, ScreeningSnapshots,
+};
+use trader_core::domain::{RouteState, ScreeningResult};
+use trader_core::{CashRateSeries, CashYieldCurve, Kline, MarketType, Symbol, Timeframe};
 use trader_data::cache::CachedHistoricalDataProvider;
+use trader_data::MacroSeriesStore;
+use trader_strategy::strategies::{
+    SmallCapQuantConfig, MARKET_INTEREST_PRESET, SMALL_CAP_QUANT_PRESET,
+};
+use trader_strategy::StrategyRegistry;
 
 /// CachedHistoricalDataProvider를 통해 Kline 데이터 로드
 ///
-/// ohlcv 테이블에서 통합 관리되는 데이터를 조회합니다.
+/// ohlcv 테이블에서 통합 관리되는 데이터를 지정된 타임프레임으로 조회합니다.
 /// 캐시에 데이터가 없으면 자동으로 Yahoo Finance에서 다운로드하여 캐싱합니다.
 pub async fn load_klines_from_db(
     pool: &sqlx::PgPool,
     symbol_str: &str,
+    timeframe: Timeframe,
     start_date: NaiveDate,
     end_date: NaiveDate,
 ) -> Result<Vec<Kline>, String> {
     let provider = CachedHistoricalDataProvider::new(pool.clone());
 
-    // 날짜 범위를 거래일 기준 limit으로 변환 (주말 제외 대략 계산)
+    // 날짜 범위를 캔들 개수로 변환
     let total_days = (end_date - start_date).num_days() as usize;
-    let trading_days = (total_days as f64 * 5.0 / 7.0).ceil() as usize;
-    let limit = trading_days.max(100); // 최소 100개
+    let limit = match timeframe {
+        Timeframe::M1 => total_days * 24 * 60,
+        Timeframe::M3 => total_days * 24 * 20,
+        Timeframe::M5 => total_days * 24 * 12,
+        Timeframe::M15 => total_days * 24 * 4,
+        Timeframe::M30 => total_days * 24 * 2,
+        Timeframe::H1 => total_days * 24,
+        Timeframe::H2 => total_days * 12,
+        Timeframe::H4 => total_days * 6,
+        Timeframe::H6 => total_days * 4,
+        Timeframe::H8 => total_days * 3,
+        Timeframe::H12 => total_days * 2,
+        // 거래일 기준 (주말 제외 대략 계산)
+        Timeframe::D1 => (total_days as f64 * 5.0 / 7.0).ceil() as usize,
+        Timeframe::D3 => total_days / 3 + 1,
+        Timeframe::W1 => total_days / 7 + 1,
+        Timeframe::MN1 => total_days / 30 + 1,
+    };
+    let limit = limit.max(100); // 최소 100개
 
     info!(
         symbol = symbol_str,
+        ?timeframe,
         start = %start_date,
         end = %end_date,
         limit = limit,
@@ -37,9 +66,9 @@ pub async fn load_klines_from_db(
 
     // CachedHistoricalDataProvider가 캐시 조회 + 자동 다운로드 + 캐싱 처리
     let klines = provider
-        .get_klines(symbol_str, Timeframe::D1, limit)
+        .get_klines(symbol_str, timeframe, limit)
         .await
-        .map_err(|e| format!("캔들 데이터 조회 실패: {}", e))?;
+        .map_err(|e| format!("캔들 데이터 조회 실패 ({}): {}", timeframe, e))?;
 
     // 날짜 범위 필터링
     let start_dt = Utc.from_utc_datetime(&start_date.and_hms_opt(0, 0, 0).unwrap());
@@ -52,6 +81,7 @@ pub async fn load_klines_from_db(
 
     info!(
         symbol = symbol_str,
+        ?timeframe,
         count = filtered.len(),
         "캔들 데이터 로드 완료"
     );
@@ -72,7 +102,7 @@ pub fn generate_sample_klines(
     // Symbol 생성자를 통해 country 필드 자동 추론
     let symbol = Symbol::new(base, quote, MarketType::Stock);
 
-    let days = (end_date - start_date).num_days() as usize;
+    let days = (end_date - start_date).num_days().max(0) as usize;
     let base_price = 50000.0_f64; // 기본 가격
 
     (0..=days)
@@ -109,73 +139,6 @@ pub fn generate_sample_klines(
         .collect()
 }
 
-/// 특정 타임프레임의 Kline 데이터 로드
-///
-/// ohlcv 테이블에서 지정된 타임프레임의 데이터를 조회합니다.
-#[allow(dead_code)]
-pub async fn load_klines_with_timeframe(
-    pool: &sqlx::PgPool,
-    symbol_str: &str,
-    timeframe: Timeframe,
-    start_date: NaiveDate,
-    end_date: NaiveDate,
-) -> Result<Vec<Kline>, String> {
-    let provider = CachedHistoricalDataProvider::new(pool.clone());
-
-    // 날짜 범위를 캔들 개수로 변환
-    let total_days = (end_date - start_date).num_days() as usize;
-    let limit = match timeframe {
-        Timeframe::M1 => total_days * 24 * 60,
-        Timeframe::M3 => total_days * 24 * 20,
-        Timeframe::M5 => total_days * 24 * 12,
-        Timeframe::M15 => total_days * 24 * 4,
-        Timeframe::M30 => total_days * 24 * 2,
-        Timeframe::H1 => total_days * 24,
-        Timeframe::H2 => total_days * 12,
-        Timeframe::H4 => total_days * 6,
-        Timeframe::H6 => total_days * 4,
-        Timeframe::H8 => total_days * 3,
-        Timeframe::H12 => total_days * 2,
-        Timeframe::D1 => (total_days as f64 * 5.0 / 7.0).ceil() as usize,
-        Timeframe::D3 => total_days / 3 + 1,
-        Timeframe::W1 => total_days / 7 + 1,
-        Timeframe::MN1 => total_days / 30 + 1,
-    };
-    let limit = limit.max(60); // 최소 60개
-
-    info!(
-        symbol = symbol_str,
-        ?timeframe,
-        start = %start_date,
-        end = %end_date,
-        limit = limit,
-        "타임프레임별 캔들 데이터 로드"
-    );
-
-    let klines = provider
-        .get_klines(symbol_str, timeframe, limit)
-        .await
-        .map_err(|e| format!("캔들 데이터 조회 실패 ({}): {}", timeframe, e))?;
-
-    // 날짜 범위 필터링
-    let start_dt = Utc.from_utc_datetime(&start_date.and_hms_opt(0, 0, 0).unwrap());
-    let end_dt = Utc.from_utc_datetime(&end_date.and_hms_opt(23, 59, 59).unwrap());
-
-    let filtered: Vec<Kline> = klines
-        .into_iter()
-        .filter(|k| k.open_time >= start_dt && k.open_time <= end_dt)
-        .collect();
-
-    info!(
-        symbol = symbol_str,
-        ?timeframe,
-        count = filtered.len(),
-        "타임프레임별 캔들 데이터 로드 완료"
-    );
-
-    Ok(filtered)
-}
-
 /// 다중 타임프레임 데이터 로드
 ///
 /// 각 타임프레임별로 지정된 개수의 캔들 데이터를 HashMap으로 반환합니다.
@@ -234,13 +197,14 @@ pub async fn load_secondary_timeframe_klines(
 pub async fn load_multi_klines_from_db(
     pool: &sqlx::PgPool,
     symbols: &[String],
+    timeframe: Timeframe,
     start_date: NaiveDate,
     end_date: NaiveDate,
 ) -> Result<HashMap<String, Vec<Kline>>, String> {
     let mut result = HashMap::new();
 
     for symbol_str in symbols {
-        match load_klines_from_db(pool, symbol_str, start_date, end_date).await {
+        match load_klines_from_db(pool, symbol_str, timeframe, start_date, end_date).await {
             Ok(klines) if !klines.is_empty() => {
                 info!("심볼 {} 캔들 {} 개 로드 완료", symbol_str, klines.len());
                 result.insert(symbol_str.clone(), klines);
@@ -257,6 +221,332 @@ pub async fn load_multi_klines_from_db(
     Ok(result)
 }
 
+/// 유휴 현금 금리 곡선 로드
+///
+/// `macro_series`에 저장된 금리 시리즈를 조회합니다. 파싱 실패, 조회 실패 또는
+/// 관측값이 없으면 None을 반환하여 현금 이자 없이 백테스트를 진행합니다.
+pub async fn load_cash_yield_curve(
+    pool: &sqlx::PgPool,
+    series: &str,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+) -> Option<CashYieldCurve> {
+    let series: CashRateSeries = match series.parse() {
+        Ok(series) => series,
+        Err(e) => {
+            warn!("현금 금리 시리즈 파싱 실패: {}", e);
+            return None;
+        }
+    };
+
+    let start = Utc.from_utc_datetime(&start_date.and_hms_opt(0, 0, 0)?);
+    let end = Utc.from_utc_datetime(&end_date.and_hms_opt(23, 59, 59)?);
+
+    match MacroSeriesStore::new(pool.clone())
+        .get_cash_yield_curve(series, start, end)
+        .await
+    {
+        Ok(curve) if !curve.is_empty() => {
+            info!(
+                "현금 금리 {} 관측값 {} 개 로드 완료",
+                series.series_key(),
+                curve.len()
+            );
+            Some(curve)
+        }
+        Ok(_) => {
+            warn!("현금 금리 {} 데이터 없음", series.series_key());
+            None
+        }
+        Err(e) => {
+            warn!("현금 금리 {} 로드 실패: {}", series.series_key(), e);
+            None
+        }
+    }
+}
+
+/// 과거 스크리닝 조회 시 기준일 이전으로 허용하는 점수 이력 공백 (휴장일 여유)
+const SCREENING_LOOKBACK_DAYS: i32 = 7;
+
+/// 시장 관심 종목 스냅샷 종목 수 (GlobalScore 상위)
+const MARKET_INTEREST_UNIVERSE_SIZE: i64 = 50;
+
+/// 스크리닝 기반 전략의 백테스트용 과거 스크리닝
+pub struct StrategyScreening {
+    /// 기준일별 스크리닝 스냅샷
+    pub snapshots: ScreeningSnapshots,
+    /// 캔들을 함께 로드해야 하는 종목 (스냅샷 종목을 직접 매매하는 전략만)
+    pub universe: Vec<String>,
+}
+
+/// 전략별 과거 스크리닝 재구성 조건
+struct ScreeningSnapshotSpec {
+    preset: &'static str,
+    /// 소형주 퀀트 재무 필터 (None이면 GlobalScore 순위만 사용)
+    small_cap: Option<SmallCapQuantConfig>,
+    /// 월간 리밸런싱 기준일만 사용 (false면 점수 이력이 있는 모든 거래일)
+    monthly: bool,
+    limit: i64,
+}
+
+impl ScreeningSnapshotSpec {
+    fn for_strategy(strategy_id: &str, params: Option<&serde_json::Value>) -> Option<Self> {
+        match StrategyRegistry::find(strategy_id)?.id {
+            "small_cap_quant" => {
+                let config: SmallCapQuantConfig = params
+                    .and_then(|p| serde_json::from_value(p.clone()).ok())
+                    .unwrap_or_default();
+                let limit = (config.target_count as i64).saturating_mul(2);
+                Some(Self {
+                    preset: SMALL_CAP_QUANT_PRESET,
+                    small_cap: Some(config),
+                    monthly: true,
+                    limit,
+                })
+            }
+            "volume_surge" => Some(Self {
+                preset: MARKET_INTEREST_PRESET,
+                small_cap: None,
+                monthly: false,
+                limit: MARKET_INTEREST_UNIVERSE_SIZE,
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// 스크리닝 기반 전략의 과거 스크리닝 스냅샷 로드
+///
+/// 리밸런싱 기준일마다 `score_history`의 당일 이전 최신 GlobalScore/RouteState와
+/// `symbol_fundamental` 재무 지표로 스크리닝 결과를 재구성합니다.
+/// 재무 지표는 이력이 없어 현재 값을 사용하므로 재무 필터에는 미래 정보가 섞일 수 있습니다.
+/// 스크리닝 기반 전략이 아니거나 점수 이력이 없으면 None을 반환합니다.
+pub async fn load_strategy_screening(
+    pool: &sqlx::PgPool,
+    strategy_id: &str,
+    params: Option<&serde_json::Value>,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+) -> Option<StrategyScreening> {
+    let spec = ScreeningSnapshotSpec::for_strategy(strategy_id, params)?;
+
+    let score_dates: Vec<NaiveDate> = match sqlx::query_scalar(
+        "SELECT DISTINCT score_date FROM score_history \
+         WHERE score_date BETWEEN $1 AND $2 ORDER BY score_date",
+    )
+    .bind(start_date)
+    .bind(end_date)
+    .fetch_all(pool)
+    .await
+    {
+        Ok(dates) => dates,
+        Err(e) => {
+            warn!("점수 이력 기준일 조회 실패: {}", e);
+            return None;
+        }
+    };
+    let rebalance_dates = if spec.monthly {
+        monthly_rebalance_dates(&score_dates)
+    } else {
+        score_dates
+    };
+
+    let mut snapshots = ScreeningSnapshots::new(spec.preset);
+    for date in rebalance_dates {
+        match load_screening_as_of(pool, &spec, date).await {
+            Ok(results) if !results.is_empty() => snapshots.insert(date, results),
+            Ok(_) => {}
+            Err(e) => warn!("{} 스크리닝 재구성 실패: {}", date, e),
+        }
+    }
+
+    if snapshots.is_empty() {
+        warn!("{} 과거 스크리닝 데이터 없음", strategy_id);
+        return None;
+    }
+    info!(
+        "{} 과거 스크리닝 스냅샷 {} 개 로드 완료",
+        strategy_id,
+        snapshots.len()
+    );
+
+    let universe = match &spec.small_cap {
+        Some(config) => {
+            let mut universe = snapshots.universe();
+            universe.push(config.index_ticker.clone());
+            universe
+        }
+        None => Vec::new(),
+    };
+
+    Some(StrategyScreening {
+        snapshots,
+        universe,
+    })
+}
+
+/// 기준일 시점의 스크리닝 결과 재구성
+async fn load_screening_as_of(
+    pool: &sqlx::PgPool,
+    spec: &ScreeningSnapshotSpec,
+    date: NaiveDate,
+) -> Result<Vec<ScreeningResult>, sqlx::Error> {
+    let small_cap = spec.small_cap.as_ref();
+    // 최소 시가총액은 억원 단위
+    let min_market_cap = small_cap.and_then(|c| Decimal::from_f64(c.min_market_cap * 1e8));
+    let min_roe = small_cap.and_then(|c| Decimal::from_f64(c.min_roe));
+    let min_pbr = small_cap.and_then(|c| Decimal::from_f64(c.min_pbr));
+    let min_per = small_cap.and_then(|c| Decimal::from_f64(c.min_per));
+
+    let rows: Vec<(String, Option<Decimal>, Option<String>)> = sqlx::query_as(
+        r#"
+        WITH latest AS (
+            SELECT DISTINCT ON (sh.symbol)
+                sh.symbol, sh.global_score, sh.route_state
+            FROM score_history sh
+            WHERE sh.score_date <= $1
+              AND sh.score_date > $1::date - $2::integer
+            ORDER BY sh.symbol, sh.score_date DESC
+        )
+        SELECT l.symbol, l.global_score, l.route_state
+        FROM latest l
+        JOIN symbol_info si ON si.ticker = l.symbol AND si.is_active = true
+        LEFT JOIN symbol_fundamental sf ON sf.symbol_info_id = si.id
+        WHERE l.global_score IS NOT NULL
+          AND (NOT $3 OR (
+                si.market = 'KR'
+                AND sf.operating_income > 0
+                AND sf.eps > 0
+                AND sf.bps > 0
+                AND COALESCE(si.sector, '') !~ '(금융|은행|보험|증권)'
+              ))
+          AND ($4::numeric IS NULL OR sf.market_cap >= $4)
+          AND ($5::numeric IS NULL OR sf.roe >= $5)
+          AND ($6::numeric IS NULL OR sf.pbr >= $6)
+          AND ($7::numeric IS NULL OR sf.per >= $7)
+        ORDER BY
+            CASE WHEN $3 THEN sf.market_cap END ASC NULLS LAST,
+            l.global_score DESC
+        LIMIT $8
+        "#,
+    )
+    .bind(date)
+    .bind(SCREENING_LOOKBACK_DAYS)
+    .bind(small_cap.is_some())
+    .bind(min_market_cap)
+    .bind(min_roe)
+    .bind(min_pbr)
+    .bind(min_per)
+    .bind(spec.limit)
+    .fetch_all(pool)
+    .await?;
+
+    let timestamp = Utc.from_utc_datetime(&date.and_time(NaiveTime::MIN));
+    Ok(rows
+        .into_iter()
+        .map(|(ticker, global_score, route_state)| ScreeningResult {
+            ticker,
+            preset_name: spec.preset.to_string(),
+            passed: true,
+            overall_score: global_score.unwrap_or_default(),
+            route_state: route_state
+                .as_deref()
+                .and_then(parse_route_state)
+                .unwrap_or_default(),
+            criteria_results: HashMap::new(),
+            timestamp,
+            sector_rs: None,
+            sector_rank: None,
+            trigger_score: None,
+            trigger_label: None,
+        })
+        .collect())
+}
+
+/// `score_history.route_state` 문자열 파싱 (대소문자 무시)
+fn parse_route_state(value: &str) -> Option<RouteState> {
+    serde_json::from_value(serde_json::Value::String(value.to_uppercase())).ok()
+}
+
+/// 실제 데이터 시작이 요청 시작일보다 이만큼 늦으면 합성 대상 (연휴 여유)
+const LEVERAGED_BACKFILL_GRACE_DAYS: i64 = 7;
+
+/// 레버리지 ETF의 상장 이전 구간을 기초자산 일봉으로 합성하여 채움
+///
+/// 데이터가 없거나 시작일 이후에 시작하는 레버리지 ETF(예: TQQQ)를
+/// 기초자산(예: QQQ) 일봉으로 일일 리밸런싱 decay, 총보수, 차입 비용을 반영해 합성합니다.
+/// 기초자산이 `multi_klines`에 없으면 DB에서 로드하며, 로드할 수 없으면 건너뜁니다.
+///
+/// # Returns
+/// 합성 데이터가 채워진 심볼 목록
+pub async fn fill_leveraged_history(
+    pool: &sqlx::PgPool,
+    multi_klines: &mut HashMap<String, Vec<Kline>>,
+    symbols: &[String],
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+    financing: Option<&CashYieldCurve>,
+) -> Vec<String> {
+    let mut filled = Vec::new();
+
+    for symbol in symbols {
+        let Some(spec) = LeveragedEtfSpec::known(symbol) else {
+            continue;
+        };
+
+        let actual_start = multi_klines
+            .get(symbol)
+            .and_then(|klines| klines.first())
+            .map(|k| k.open_time.date_naive());
+        if actual_start
+            .is_some_and(|d| (d - start_date).num_days() <= LEVERAGED_BACKFILL_GRACE_DAYS)
+        {
+            continue;
+        }
+
+        let underlying = match multi_klines.get(&spec.underlying) {
+            Some(klines) => klines.clone(),
+            None => match load_klines_from_db(
+                pool,
+                &spec.underlying,
+                Timeframe::D1,
+                start_date,
+                end_date,
+            )
+            .await
+            {
+                Ok(klines) => klines,
+                Err(e) => {
+                    warn!("기초자산 {} 로드 실패: {}", spec.underlying, e);
+                    continue;
+                }
+            },
+        };
+        if underlying.is_empty() {
+            warn!(
+                "기초자산 {} 데이터 없음, {} 합성 생략",
+                spec.underlying, symbol
+            );
+            continue;
+        }
+
+        let actual = multi_klines.remove(symbol).unwrap_or_default();
+        let actual_len = actual.len();
+        let merged = backfill_leveraged_history(actual, &underlying, &spec, financing);
+        info!(
+            "레버리지 ETF {} 합성: 기초자산 {} × {}, 합성 캔들 {} 개",
+            symbol,
+            spec.underlying,
+            spec.leverage,
+            merged.len() - actual_len
+        );
+        multi_klines.insert(symbol.clone(), merged);
+        filled.push(symbol.clone());
+    }
+
+    filled
+}
+
 /// 다중 심볼 Kline 데이터를 시간순으로 병합
 pub fn merge_multi_klines(multi_klines: &HashMap<String, Vec<Kline>>) -> Vec<Kline> {
     let mut all_klines: Vec<Kline> = multi_klines
@@ -265,7 +555,7 @@ pub fn merge_multi_klines(multi_klines: &HashMap<String, Vec<Kline>>) -> Vec<Kli
         .collect();
 
     // 시간순 정렬
-    all_klines.sort_by(|a, b| a.open_time.cmp(&b.open_time));
+    all_klines.sort_by_key(|k| k.open_time);
 
     all_klines
 }
@@ -306,6 +596,8 @@ pub fn expand_strategy_symbols(strategy_id: &str, user_symbols: &[String]) -> Ve
         "all_weather_kr" => &["360750", "294400", "148070", "305080", "319640", "261240"],
         // 스노우 US: UPRO, TLT, BIL, TIP
         "snow" | "snow_us" => &["UPRO", "TLT", "BIL", "TIP"],
+        // 미국 3배 레버리지: 레버리지 + 인버스
+        "us_3x_leverage" | "us_leverage" => &["TQQQ", "SOXL", "SQQQ", "SOXS"],
         // 스노우 KR: 122630, 148070, 130730
         "snow_kr" => &["122630", "148070", "130730"],
         // BAA: 카나리아(SPY, VEA, VWO, BND) + 공격(QQQ, IWM) + 방어(TIP, DBC, BIL, IEF, TLT)
@@ -342,3 +634,24 @@ pub fn expand_strategy_symbols(strategy_id: &str, user_symbols: &[String]) -> Ve
     result.sort();
     result
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use trader_testkit::is_valid_ohlc;
+
+    #[test]
+    fn test_generate_sample_klines() {
+        let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let end = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+
+        let klines = generate_sample_klines("005930", start, end);
+        assert_eq!(klines.len(), 31);
+        assert!(klines.iter().all(is_valid_ohlc));
+        let again = generate_sample_klines("005930", start, end);
+        assert!(klines.iter().zip(&again).all(|(a, b)| a.close == b.close));
+
+        // 종료일이 시작일보다 앞서면 시작일 캔들 하나
+        assert_eq!(generate_sample_klines("005930", end, start).len(), 1);
+    }
+}
@@ -5,11 +5,23 @@
 //! # 엔드포인트
 //!
 //! - `GET /api/v1/backtest/strategies` - 백테스트 가능한 전략 목록
-//! - `POST /api/v1/backtest/run` - 백테스트 실행
-//! - `GET /api/v1/backtest/results/{id}` - 백테스트 결과 조회
+//! - `POST /api/v1/backtest/run` - 백테스트 실행 (DB 연결 시 결과 저장)
+//! - `POST /api/v1/backtest/run-multi` - 다중 자산 백테스트 실행 (DB 연결 시 결과 저장)
+//! - `POST /api/v1/backtest/monte-carlo` - 저장된 결과의 몬테카를로 시뮬레이션 (CAGR/MDD 신뢰구간, 파산 확률)
+//!
+//! 저장된 결과의 수수료/슬리피지 민감도 분석 핸들러(`get_cost_sensitivity`)는
+//! `/api/v1/backtest/results/{id}/sensitivity`에 연결됩니다.
+//!
+//! 실행 전 심볼별 데이터 커버리지를 검사해 응답의 `data_coverage`로 반환하며, 커버리지가
+//! 기준(`min_coverage_pct`, 기본 90%)에 못 미치거나 저장된 데이터가 없으면 실행을 거부합니다.
+//! 샘플 데이터 대체는 데모용 `allow_synthetic=true`일 때만 허용합니다.
+//!
+//! 저장된 결과의 조회/목록/삭제는 `backtest_results` 모듈(`/api/v1/backtest/results`)에서 제공합니다.
 
 mod engine;
 mod loader;
+mod monte_carlo;
+mod sensitivity;
 mod types;
 mod ui_schema;
 
@@ -47,23 +59,38 @@ pub use types::{
     UiValidation,
 };
 
+pub use monte_carlo::{MonteCarloRequest, MonteCarloResponse};
+pub use sensitivity::{get_cost_sensitivity, SensitivityQuery, SensitivityResponse};
+
 // Re-export UI schema functions
 pub use ui_schema::get_ui_schema_for_strategy;
 
 use axum::{
-    extract::{Path, State},
-    http::StatusCode,
+    extract::State,
+    http::{HeaderMap, StatusCode},
     response::IntoResponse,
     routing::{get, post},
     Json, Router,
 };
-use chrono::NaiveDate;
+use chrono::{NaiveDate, Utc};
 use rust_decimal::Decimal;
+use std::collections::HashMap;
 use std::sync::Arc;
-use tracing::{debug, info, warn};
-
+use tracing::{info, warn};
+
+use crate::auth::OptionalJwtAuth;
+use crate::middleware::extract_client_ip;
+use crate::quota::BacktestPermit;
+use crate::repository::{
+    BacktestResultInput, BacktestResultsRepository, BacktestTemplateRecord,
+    BacktestTemplateRepository,
+};
 use crate::state::AppState;
-use trader_analytics::backtest::BacktestConfig;
+use trader_analytics::backtest::{
+    BacktestConfig, CoveragePolicy, DataCoverageReport, IntradaySession, KlineSource, MarginConfig,
+    SymbolCoverage, DEFAULT_MIN_COVERAGE_PCT,
+};
+use trader_core::{Kline, Timeframe, TradingCostModel};
 use trader_strategy::StrategyRegistry;
 
 use engine::{
@@ -71,11 +98,116 @@ use engine::{
     run_multi_strategy_backtest, run_strategy_backtest,
 };
 use loader::{
-    expand_strategy_symbols, generate_sample_klines, load_klines_from_db,
-    load_multi_klines_from_db, merge_multi_klines,
+    expand_strategy_symbols, fill_leveraged_history, generate_sample_klines, load_cash_yield_curve,
+    load_klines_from_db, load_multi_klines_from_db, load_strategy_screening, merge_multi_klines,
+    StrategyScreening,
 };
 // ui_schema 함수들은 get_ui_schema_for_strategy로 대체됨
 
+/// 장중(분봉) 백테스트 최대 기간 (일)
+const MAX_INTRADAY_BACKTEST_DAYS: i64 = 92;
+
+/// 동시 백테스트 슬롯 확보.
+///
+/// 인증된 요청은 사용자 등급, 비인증 요청은 클라이언트 IP별 익명 등급 한도를 따릅니다.
+/// 반환된 슬롯은 핸들러가 끝날 때까지 보관해야 합니다.
+async fn acquire_backtest_permit(
+    state: &AppState,
+    auth: &OptionalJwtAuth,
+    headers: &HeaderMap,
+) -> Result<BacktestPermit, (StatusCode, Json<BacktestApiError>)> {
+    let (principal, result) = match &auth.0 {
+        Some(claims) => (
+            claims.sub.clone(),
+            state.quotas.acquire_backtest(&claims.sub).await,
+        ),
+        None => {
+            let ip = extract_client_ip(headers);
+            (ip.to_string(), state.quotas.acquire_anonymous_backtest(ip))
+        }
+    };
+    result.map_err(|e| {
+        warn!("백테스트 할당량 초과: principal={}, {}", principal, e);
+        (
+            e.status_code(),
+            Json(BacktestApiError::new("QUOTA_EXCEEDED", e.to_string())),
+        )
+    })
+}
+
+/// 실행 결과 저장에 필요한 요청 정보.
+struct PersistContext<'a> {
+    /// 저장 여부 (요청의 `persist`)
+    enabled: bool,
+    /// 전략 타입 (요청의 `strategy_id`)
+    strategy_type: &'a str,
+    /// 결과를 기록할 등록 전략 ID
+    registered_strategy_id: Option<&'a str>,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+    initial_capital: Decimal,
+    slippage_rate: Decimal,
+    parameters: Option<&'a serde_json::Value>,
+    timeframes_used: Option<serde_json::Value>,
+}
+
+/// 실행 결과를 `backtest_results`에 저장하고 저장된 결과 ID를 반환.
+///
+/// DB 연결이 없거나 저장을 끈 요청은 저장하지 않습니다. 저장 용량 초과나 저장 실패는
+/// 백테스트 응답을 막지 않고 경고만 남깁니다.
+async fn persist_run_result(
+    state: &AppState,
+    auth: &OptionalJwtAuth,
+    ctx: PersistContext<'_>,
+    symbol: &str,
+    response: &impl serde::Serialize,
+) -> Option<uuid::Uuid> {
+    if !ctx.enabled {
+        return None;
+    }
+    let pool = state.db_pool.as_ref()?;
+
+    let owner_id = auth.0.as_ref().map(|claims| claims.sub.clone());
+    if let Some(owner_id) = &owner_id {
+        if let Err(e) = state.quotas.check_storage_quota(owner_id).await {
+            warn!("백테스트 결과 저장 생략: user={}, {}", owner_id, e);
+            return None;
+        }
+    }
+
+    let response = serde_json::to_value(response).ok()?;
+    let field = |key: &str| response.get(key).cloned().unwrap_or_default();
+    let input = BacktestResultInput {
+        // 등록 전략 없이 실행한 결과는 strategy_id를 비워 둠 (전략 타입은 strategy_type으로 구분)
+        strategy_id: ctx.registered_strategy_id.map(str::to_string),
+        strategy_type: ctx.strategy_type.to_string(),
+        symbol: symbol.to_string(),
+        start_date: ctx.start_date,
+        end_date: ctx.end_date,
+        initial_capital: ctx.initial_capital,
+        slippage_rate: Some(ctx.slippage_rate),
+        metrics: field("metrics"),
+        config_summary: field("config_summary"),
+        equity_curve: field("equity_curve"),
+        trades: field("trades"),
+        success: response
+            .get("success")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(true),
+        timeframes_used: ctx.timeframes_used,
+        parameters: ctx.parameters.cloned(),
+        owner_id,
+    };
+
+    match BacktestResultsRepository::save(pool, input).await {
+        Ok(id) => Some(id),
+        Err(e) => {
+            warn!("백테스트 결과 저장 실패: {}", e);
+            None
+        }
+    }
+}
+
 // ==================== 핸들러 ====================
 
 /// 백테스트 가능한 전략 목록 조회
@@ -142,6 +274,8 @@ pub async fn list_backtest_strategies(State(state): State<Arc<AppState>>) -> imp
 /// 주어진 설정으로 백테스트를 실행하고 결과를 반환합니다.
 pub async fn run_backtest(
     State(state): State<Arc<AppState>>,
+    auth: OptionalJwtAuth,
+    headers: HeaderMap,
     Json(request): Json<BacktestRunRequest>,
 ) -> Result<Json<BacktestRunResponse>, (StatusCode, Json<BacktestApiError>)> {
     info!(
@@ -192,6 +326,34 @@ pub async fn run_backtest(
         ));
     }
 
+    // 타임프레임 검증 (기본: 일봉)
+    let timeframe = match request.timeframe.as_deref() {
+        Some(tf) => tf.parse::<Timeframe>().map_err(|_| {
+            (
+                StatusCode::BAD_REQUEST,
+                Json(BacktestApiError::new(
+                    "INVALID_TIMEFRAME",
+                    format!("지원하지 않는 타임프레임입니다: {}", tf),
+                )),
+            )
+        })?,
+        None => Timeframe::D1,
+    };
+
+    // 장중 백테스트는 캔들 수가 많으므로 기간 제한
+    if timeframe.is_intraday() && (end_date - start_date).num_days() > MAX_INTRADAY_BACKTEST_DAYS {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(BacktestApiError::new(
+                "INVALID_DATE_RANGE",
+                format!(
+                    "{} 백테스트 기간은 최대 {}일입니다",
+                    timeframe, MAX_INTRADAY_BACKTEST_DAYS
+                ),
+            )),
+        ));
+    }
+
     // 전략 레지스트리에서 동적으로 전략 확인
     if StrategyRegistry::find(&request.strategy_id).is_none() {
         return Err((
@@ -203,13 +365,46 @@ pub async fn run_backtest(
         ));
     }
 
+    // 사용자별 동시 백테스트 할당량 (핸들러 종료 시 반환)
+    let _permit = acquire_backtest_permit(&state, &auth, &headers).await?;
+
     // 수수료/슬리피지 기본값 설정
     let commission_rate = request.commission_rate.unwrap_or(Decimal::new(1, 3)); // 0.1%
     let slippage_rate = request.slippage_rate.unwrap_or(Decimal::new(5, 4)); // 0.05%
 
+    // 결과 저장 정보
+    let persist = PersistContext {
+        enabled: request.persist,
+        strategy_type: &request.strategy_id,
+        registered_strategy_id: request.registered_strategy_id.as_deref(),
+        start_date,
+        end_date,
+        initial_capital: request.initial_capital,
+        slippage_rate,
+        parameters: request.parameters.as_ref(),
+        timeframes_used: request
+            .multi_timeframe_config
+            .as_ref()
+            .and_then(|c| serde_json::to_value(c).ok()),
+    };
+
+    // 스크리닝 기반 전략은 리밸런싱 기준일별 과거 스크리닝 결과를 재구성
+    let screening = load_screening(
+        &state,
+        &request.strategy_id,
+        request.parameters.as_ref(),
+        start_date,
+        end_date,
+    )
+    .await;
+
     // 전략별로 필요한 심볼을 동적으로 확장 (하드코딩 없이 expand_strategy_symbols에 위임)
+    // 스냅샷 종목을 직접 매매하는 전략은 스크리닝 유니버스도 포함
     let user_symbols = vec![request.symbol.clone()];
-    let expanded_symbols = expand_strategy_symbols(&request.strategy_id, &user_symbols);
+    let expanded_symbols = with_screening_universe(
+        expand_strategy_symbols(&request.strategy_id, &user_symbols),
+        screening.as_ref(),
+    );
 
     // 확장된 심볼이 1개 초과이면 다중 심볼 전략으로 처리
     let is_multi_symbol_strategy = expanded_symbols.len() > 1;
@@ -221,28 +416,58 @@ pub async fn run_backtest(
         );
 
         // 다중 심볼 데이터 로드
-        let multi_klines = if let Some(pool) = &state.db_pool {
-            match load_multi_klines_from_db(pool, &expanded_symbols, start_date, end_date).await {
-                Ok(data) if !data.is_empty() => {
-                    info!("DB에서 {} 심볼의 데이터 로드 완료", data.len());
-                    for (sym, klines) in &data {
-                        info!("  - {} 심볼: {} 개 캔들", sym, klines.len());
-                    }
-                    data
-                }
-                Ok(_) => {
-                    warn!("DB에 데이터가 없어 샘플 데이터로 백테스트 실행");
-                    generate_multi_sample_klines(&expanded_symbols, start_date, end_date)
-                }
-                Err(e) => {
-                    warn!("DB 로드 실패, 샘플 데이터 사용: {}", e);
-                    generate_multi_sample_klines(&expanded_symbols, start_date, end_date)
-                }
-            }
+        let (mut multi_klines, source) = if timeframe.is_intraday() {
+            let data =
+                load_intraday_klines(&state, &expanded_symbols, timeframe, start_date, end_date)
+                    .await?;
+            (data, KlineSource::Stored)
         } else {
-            debug!("DB 연결 없음, 샘플 데이터로 백테스트 실행");
-            generate_multi_sample_klines(&expanded_symbols, start_date, end_date)
+            load_daily_klines(
+                &state,
+                &expanded_symbols,
+                timeframe,
+                start_date,
+                end_date,
+                request.allow_synthetic,
+            )
+            .await?
         };
+        let loaded = kline_counts(&multi_klines);
+
+        // 레버리지 ETF 상장 이전 구간 합성 (일봉 전용)
+        let synthetic_leverage = !timeframe.is_intraday()
+            && request
+                .synthetic_leverage
+                .unwrap_or_else(|| synthetic_leverage_default(&request.strategy_id));
+        apply_leveraged_synthesis(
+            &state,
+            &mut multi_klines,
+            &expanded_symbols,
+            synthetic_leverage,
+            request.cash_yield_series.as_deref(),
+            start_date,
+            end_date,
+        )
+        .await;
+
+        // 실행 전 데이터 커버리지 검사
+        let policy = coverage_policy(
+            request.allow_synthetic,
+            request.min_coverage_pct,
+            synthetic_leverage,
+        )?;
+        let coverage = enforce_coverage(
+            build_coverage_report(
+                &multi_klines,
+                &expanded_symbols,
+                source,
+                &loaded,
+                timeframe,
+                start_date,
+                end_date,
+            ),
+            &policy,
+        )?;
 
         // 모든 심볼의 캔들 데이터를 시간순으로 병합
         let merged_klines = merge_multi_klines(&multi_klines);
@@ -261,6 +486,18 @@ pub async fn run_backtest(
         let config = BacktestConfig::new(request.initial_capital)
             .with_commission_rate(commission_rate)
             .with_slippage_rate(slippage_rate);
+        let config = apply_cost_model(config, request.cost_model.as_ref());
+        let config = apply_margin(config, request.margin.as_ref());
+        let config = apply_cash_yield(
+            config,
+            &state,
+            request.cash_yield_series.as_deref(),
+            start_date,
+            end_date,
+        )
+        .await;
+        let config = apply_screening(config, screening);
+        let config = apply_session(config, timeframe, &request.symbol, request.flatten_at_close);
 
         // 모든 전략은 동일한 run_strategy_backtest 함수로 처리 (하드코딩 방지)
         // 병합된 캔들 데이터를 전달하여 전략이 필요한 심볼 데이터를 자체적으로 처리
@@ -280,13 +517,19 @@ pub async fn run_backtest(
 
         // BacktestReport를 API 응답으로 변환 (다중 심볼 표시)
         let symbols_str = expanded_symbols.join(",");
-        let response = convert_report_to_response(
+        let mut response = convert_report_to_response(
             &report,
             &request.strategy_id,
             &symbols_str,
             &request.start_date,
             &request.end_date,
         );
+        response.data_coverage = Some(coverage);
+        if let Some(id) = persist_run_result(&state, &auth, persist, &symbols_str, &response).await
+        {
+            response.id = id.to_string();
+            response.persisted = true;
+        }
 
         info!(
             "다중 심볼 백테스트 완료: total_return={:.2}%",
@@ -297,30 +540,54 @@ pub async fn run_backtest(
     }
 
     // 단일 심볼 전략 (기존 로직)
-    let klines = if let Some(pool) = &state.db_pool {
-        match load_klines_from_db(pool, &request.symbol, start_date, end_date).await {
-            Ok(data) if !data.is_empty() => {
-                info!("DB에서 {} 개의 캔들 데이터 로드 완료", data.len());
-                data
-            }
-            Ok(_) => {
-                warn!("DB에 데이터가 없어 샘플 데이터로 백테스트 실행");
-                generate_sample_klines(&request.symbol, start_date, end_date)
-            }
-            Err(e) => {
-                warn!("DB 로드 실패, 샘플 데이터 사용: {}", e);
-                generate_sample_klines(&request.symbol, start_date, end_date)
-            }
-        }
+    let symbols = std::slice::from_ref(&request.symbol);
+    let (mut single_klines, source) = if timeframe.is_intraday() {
+        let data = load_intraday_klines(&state, symbols, timeframe, start_date, end_date).await?;
+        (data, KlineSource::Stored)
     } else {
-        debug!("DB 연결 없음, 샘플 데이터로 백테스트 실행");
-        generate_sample_klines(&request.symbol, start_date, end_date)
+        load_daily_klines(
+            &state,
+            symbols,
+            timeframe,
+            start_date,
+            end_date,
+            request.allow_synthetic,
+        )
+        .await?
     };
 
+    // 실행 전 데이터 커버리지 검사
+    let policy = coverage_policy(request.allow_synthetic, request.min_coverage_pct, false)?;
+    let coverage = enforce_coverage(
+        build_coverage_report(
+            &single_klines,
+            symbols,
+            source,
+            &kline_counts(&single_klines),
+            timeframe,
+            start_date,
+            end_date,
+        ),
+        &policy,
+    )?;
+    let klines = single_klines.remove(&request.symbol).unwrap_or_default();
+
     // 백테스트 설정
     let config = BacktestConfig::new(request.initial_capital)
         .with_commission_rate(commission_rate)
         .with_slippage_rate(slippage_rate);
+    let config = apply_cost_model(config, request.cost_model.as_ref());
+    let config = apply_margin(config, request.margin.as_ref());
+    let config = apply_cash_yield(
+        config,
+        &state,
+        request.cash_yield_series.as_deref(),
+        start_date,
+        end_date,
+    )
+    .await;
+    let config = apply_screening(config, screening);
+    let config = apply_session(config, timeframe, &request.symbol, request.flatten_at_close);
 
     // 전략별 백테스트 실행
     let report = run_strategy_backtest(&request.strategy_id, config, &klines, &request.parameters)
@@ -333,13 +600,18 @@ pub async fn run_backtest(
         })?;
 
     // BacktestReport를 API 응답으로 변환
-    let response = convert_report_to_response(
+    let mut response = convert_report_to_response(
         &report,
         &request.strategy_id,
         &request.symbol,
         &request.start_date,
         &request.end_date,
     );
+    response.data_coverage = Some(coverage);
+    if let Some(id) = persist_run_result(&state, &auth, persist, &request.symbol, &response).await {
+        response.id = id.to_string();
+        response.persisted = true;
+    }
 
     info!(
         "백테스트 완료: total_return={:.2}%",
@@ -349,26 +621,6 @@ pub async fn run_backtest(
     Ok(Json(response))
 }
 
-/// 백테스트 결과 조회
-///
-/// GET /api/v1/backtest/results/{id}
-///
-/// 저장된 백테스트 결과를 조회합니다.
-pub async fn get_backtest_result(
-    State(_state): State<Arc<AppState>>,
-    Path(id): Path<String>,
-) -> Result<Json<serde_json::Value>, (StatusCode, Json<BacktestApiError>)> {
-    // 현재는 저장 기능이 없으므로 NOT_FOUND 반환
-    // 추후 데이터베이스 연동 시 구현
-    Err((
-        StatusCode::NOT_FOUND,
-        Json(BacktestApiError::new(
-            "RESULT_NOT_FOUND",
-            format!("백테스트 결과를 찾을 수 없습니다: {}", id),
-        )),
-    ))
-}
-
 /// 다중 자산 백테스트 실행
 ///
 /// POST /api/v1/backtest/run-multi
@@ -377,6 +629,8 @@ pub async fn get_backtest_result(
 /// 지원 전략: simple_power, haa, xaa, stock_rotation
 pub async fn run_multi_backtest(
     State(state): State<Arc<AppState>>,
+    auth: OptionalJwtAuth,
+    headers: HeaderMap,
     Json(request): Json<BacktestMultiRunRequest>,
 ) -> Result<Json<BacktestMultiRunResponse>, (StatusCode, Json<BacktestApiError>)> {
     info!(
@@ -470,37 +724,91 @@ pub async fn run_multi_backtest(
         ));
     }
 
+    // 사용자별 동시 백테스트 할당량 (핸들러 종료 시 반환)
+    let _permit = acquire_backtest_permit(&state, &auth, &headers).await?;
+
     // 수수료/슬리피지 기본값 설정
     let commission_rate = request.commission_rate.unwrap_or(Decimal::new(1, 3));
     let slippage_rate = request.slippage_rate.unwrap_or(Decimal::new(5, 4));
 
-    // 전략별로 필요한 모든 심볼 확장
-    let expanded_symbols = expand_strategy_symbols(&request.strategy_id, &request.symbols);
+    // 결과 저장 정보
+    let persist = PersistContext {
+        enabled: request.persist,
+        strategy_type: &request.strategy_id,
+        registered_strategy_id: request.registered_strategy_id.as_deref(),
+        start_date,
+        end_date,
+        initial_capital: request.initial_capital,
+        slippage_rate,
+        parameters: request.parameters.as_ref(),
+        timeframes_used: None,
+    };
+
+    // 스크리닝 기반 전략은 리밸런싱 기준일별 과거 스크리닝 결과를 재구성
+    let screening = load_screening(
+        &state,
+        &request.strategy_id,
+        request.parameters.as_ref(),
+        start_date,
+        end_date,
+    )
+    .await;
+
+    // 전략별로 필요한 모든 심볼 확장 (스크리닝 유니버스 포함)
+    let expanded_symbols = with_screening_universe(
+        expand_strategy_symbols(&request.strategy_id, &request.symbols),
+        screening.as_ref(),
+    );
     info!(
         "전략 {} 심볼 확장: {:?} -> {:?}",
         request.strategy_id, request.symbols, expanded_symbols
     );
 
     // 다중 심볼 데이터 로드
-    let multi_klines = if let Some(pool) = &state.db_pool {
-        match load_multi_klines_from_db(pool, &expanded_symbols, start_date, end_date).await {
-            Ok(data) if !data.is_empty() => {
-                info!("DB에서 {} 심볼의 데이터 로드 완료", data.len());
-                data
-            }
-            Ok(_) => {
-                warn!("DB에 데이터가 없어 샘플 데이터로 백테스트 실행");
-                generate_multi_sample_klines(&expanded_symbols, start_date, end_date)
-            }
-            Err(e) => {
-                warn!("DB 로드 실패, 샘플 데이터 사용: {}", e);
-                generate_multi_sample_klines(&expanded_symbols, start_date, end_date)
-            }
-        }
-    } else {
-        debug!("DB 연결 없음, 샘플 데이터로 백테스트 실행");
-        generate_multi_sample_klines(&expanded_symbols, start_date, end_date)
-    };
+    let (mut multi_klines, source) = load_daily_klines(
+        &state,
+        &expanded_symbols,
+        Timeframe::D1,
+        start_date,
+        end_date,
+        request.allow_synthetic,
+    )
+    .await?;
+    let loaded = kline_counts(&multi_klines);
+
+    // 레버리지 ETF 상장 이전 구간 합성
+    let synthetic_leverage = request
+        .synthetic_leverage
+        .unwrap_or_else(|| synthetic_leverage_default(&request.strategy_id));
+    apply_leveraged_synthesis(
+        &state,
+        &mut multi_klines,
+        &expanded_symbols,
+        synthetic_leverage,
+        request.cash_yield_series.as_deref(),
+        start_date,
+        end_date,
+    )
+    .await;
+
+    // 실행 전 데이터 커버리지 검사
+    let policy = coverage_policy(
+        request.allow_synthetic,
+        request.min_coverage_pct,
+        synthetic_leverage,
+    )?;
+    let coverage = enforce_coverage(
+        build_coverage_report(
+            &multi_klines,
+            &expanded_symbols,
+            source,
+            &loaded,
+            Timeframe::D1,
+            start_date,
+            end_date,
+        ),
+        &policy,
+    )?;
 
     // 심볼별 데이터 포인트 수 계산
     let data_points_by_symbol: std::collections::HashMap<String, usize> = multi_klines
@@ -525,6 +833,17 @@ pub async fn run_multi_backtest(
     let config = BacktestConfig::new(request.initial_capital)
         .with_commission_rate(commission_rate)
         .with_slippage_rate(slippage_rate);
+    let config = apply_cost_model(config, request.cost_model.as_ref());
+    let config = apply_margin(config, request.margin.as_ref());
+    let config = apply_cash_yield(
+        config,
+        &state,
+        request.cash_yield_series.as_deref(),
+        start_date,
+        end_date,
+    )
+    .await;
+    let config = apply_screening(config, screening);
 
     // 전략별 백테스트 실행 (다중 심볼 지원)
     let report = run_multi_strategy_backtest(
@@ -543,7 +862,7 @@ pub async fn run_multi_backtest(
     })?;
 
     // BacktestReport를 API 응답으로 변환
-    let response = convert_multi_report_to_response(
+    let mut response = convert_multi_report_to_response(
         &report,
         &request.strategy_id,
         &request.symbols,
@@ -551,6 +870,12 @@ pub async fn run_multi_backtest(
         &request.end_date,
         data_points_by_symbol,
     );
+    response.data_coverage = Some(coverage);
+    let symbols_str = request.symbols.join(",");
+    if let Some(id) = persist_run_result(&state, &auth, persist, &symbols_str, &response).await {
+        response.id = id.to_string();
+        response.persisted = true;
+    }
 
     info!(
         "다중 자산 백테스트 완료: total_return={:.2}%",
@@ -573,6 +898,8 @@ pub fn backtest_router() -> Router<Arc<AppState>> {
         .route("/run-multi", post(run_multi_backtest))
         // 배치 백테스트 (병렬 실행)
         .route("/run-batch", post(run_batch_backtest))
+        // 저장된 결과 몬테카를로 시뮬레이션
+        .route("/monte-carlo", post(monte_carlo::run_monte_carlo))
     // 백테스트 결과 조회는 backtest_results_router에서 처리
 }
 
@@ -584,6 +911,8 @@ pub fn backtest_router() -> Router<Arc<AppState>> {
 /// 최대 10개 전략을 동시에 실행할 수 있습니다.
 pub async fn run_batch_backtest(
     State(state): State<Arc<AppState>>,
+    auth: OptionalJwtAuth,
+    headers: HeaderMap,
     Json(request): Json<BatchBacktestRequest>,
 ) -> Result<Json<BatchBacktestResponse>, (StatusCode, Json<BacktestApiError>)> {
     use futures::stream::{self, StreamExt};
@@ -611,6 +940,20 @@ pub async fn run_batch_backtest(
         ));
     }
 
+    let total_items = request.strategies.len() + request.template_ids.len();
+    if total_items == 0 || total_items > 10 {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(BacktestApiError::new(
+                "VALIDATION_ERROR",
+                "전략과 템플릿은 합계 1-10개 사이여야 합니다",
+            )),
+        ));
+    }
+
+    // 배치 전체를 백테스트 1건으로 집계
+    let _permit = acquire_backtest_permit(&state, &auth, &headers).await?;
+
     let start_time = Instant::now();
     let request_id = uuid::Uuid::new_v4().to_string();
     let parallelism = request.parallelism.unwrap_or(4).min(10);
@@ -618,95 +961,49 @@ pub async fn run_batch_backtest(
     info!(
         request_id = %request_id,
         strategies = request.strategies.len(),
+        templates = request.template_ids.len(),
         parallelism = parallelism,
         "Starting batch backtest"
     );
 
-    // 날짜 파싱
-    let start_date = NaiveDate::parse_from_str(&request.start_date, "%Y-%m-%d").map_err(|e| {
-        (
-            StatusCode::BAD_REQUEST,
-            Json(BacktestApiError::new(
-                "INVALID_DATE",
-                format!("시작 날짜 파싱 실패: {}", e),
-            )),
-        )
-    })?;
-    let end_date = NaiveDate::parse_from_str(&request.end_date, "%Y-%m-%d").map_err(|e| {
-        (
-            StatusCode::BAD_REQUEST,
-            Json(BacktestApiError::new(
-                "INVALID_DATE",
-                format!("종료 날짜 파싱 실패: {}", e),
-            )),
-        )
-    })?;
-
-    // 수수료/슬리피지 기본값
-    let commission_rate = request.commission_rate.unwrap_or(Decimal::new(1, 3));
-    let slippage_rate = request.slippage_rate.unwrap_or(Decimal::new(5, 4));
+    let mut specs = Vec::with_capacity(total_items);
+    if !request.strategies.is_empty() {
+        specs.extend(inline_batch_specs(&request)?);
+    }
+    if !request.template_ids.is_empty() {
+        specs.extend(template_batch_specs(&state, &request.template_ids).await?);
+    }
 
-    // 각 전략에 대한 백테스트 Future 생성
-    let backtest_futures: Vec<_> = request
-        .strategies
+    // 각 실행 단위에 대한 백테스트 Future 생성
+    let backtest_futures: Vec<_> = specs
         .into_iter()
-        .map(|item| {
+        .map(|spec| {
             let state = Arc::clone(&state);
-            let initial_capital = request.initial_capital;
-            let strategy_id = item.strategy_id.clone();
 
             async move {
                 let task_start = Instant::now();
 
                 // 심볼에 따라 단일/다중 자산 백테스트 선택
-                let result = if item.symbols.len() == 1 {
-                    // 단일 심볼 백테스트
-                    let symbol = item.symbols[0].clone();
-                    run_single_strategy_internal(
-                        &state,
-                        &strategy_id,
-                        &symbol,
-                        start_date,
-                        end_date,
-                        initial_capital,
-                        commission_rate,
-                        slippage_rate,
-                        &item.parameters,
-                    )
-                    .await
+                let result = if spec.symbols.len() == 1 {
+                    run_single_strategy_internal(&state, &spec).await
                 } else {
-                    // 다중 심볼 백테스트
-                    run_multi_strategy_internal(
-                        &state,
-                        &strategy_id,
-                        &item.symbols,
-                        start_date,
-                        end_date,
-                        initial_capital,
-                        commission_rate,
-                        slippage_rate,
-                        &item.parameters,
-                    )
-                    .await
+                    run_multi_strategy_internal(&state, &spec).await
                 };
 
                 let execution_time_ms = task_start.elapsed().as_millis() as u64;
+                let (metrics, error) = match result {
+                    Ok(metrics) => (Some(metrics), None),
+                    Err(e) => (None, Some(e)),
+                };
 
-                match result {
-                    Ok(metrics) => BatchBacktestResultItem {
-                        strategy_id,
-                        success: true,
-                        error: None,
-                        metrics: Some(metrics),
-                        execution_time_ms,
-                    },
-                    Err(e) => BatchBacktestResultItem {
-                        strategy_id,
-                        success: false,
-                        error: Some(e),
-                        metrics: None,
-                        execution_time_ms,
-                    },
+                BatchBacktestResultItem {
+                    strategy_id: spec.strategy_id,
+                    template_id: spec.template_id,
+                    template_name: spec.template_name,
+                    success: error.is_none(),
+                    error,
+                    metrics,
+                    execution_time_ms,
                 }
             }
         })
@@ -741,42 +1038,565 @@ pub async fn run_batch_backtest(
     }))
 }
 
-/// 단일 전략 내부 실행 (배치용).
-#[allow(clippy::too_many_arguments)]
-async fn run_single_strategy_internal(
-    state: &Arc<AppState>,
+/// 요청에 현금 금리 시리즈가 지정된 경우 금리 곡선을 로드하여 설정에 반영
+async fn apply_cash_yield(
+    config: BacktestConfig,
+    state: &AppState,
+    series: Option<&str>,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+) -> BacktestConfig {
+    let (Some(pool), Some(series)) = (&state.db_pool, series) else {
+        return config;
+    };
+
+    match load_cash_yield_curve(pool, series, start_date, end_date).await {
+        Some(curve) => config.with_cash_yield(curve),
+        None => config,
+    }
+}
+
+/// 스크리닝 기반 전략이면 과거 스크리닝 스냅샷 로드
+async fn load_screening(
+    state: &AppState,
     strategy_id: &str,
+    params: Option<&serde_json::Value>,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+) -> Option<StrategyScreening> {
+    let pool = state.db_pool.as_ref()?;
+    load_strategy_screening(pool, strategy_id, params, start_date, end_date).await
+}
+
+/// 스크리닝 유니버스 종목을 백테스트 심볼에 추가
+fn with_screening_universe(
+    mut symbols: Vec<String>,
+    screening: Option<&StrategyScreening>,
+) -> Vec<String> {
+    if let Some(screening) = screening {
+        symbols.extend(screening.universe.iter().cloned());
+        symbols.sort();
+        symbols.dedup();
+    }
+    symbols
+}
+
+/// 과거 스크리닝 스냅샷이 있으면 설정에 반영
+fn apply_screening(config: BacktestConfig, screening: Option<StrategyScreening>) -> BacktestConfig {
+    match screening {
+        Some(screening) => config.with_screening(screening.snapshots),
+        None => config,
+    }
+}
+
+/// 장중 타임프레임이면 종목 시장의 정규장 세션을 적용합니다.
+fn apply_session(
+    config: BacktestConfig,
+    timeframe: Timeframe,
     symbol: &str,
+    flatten_at_close: bool,
+) -> BacktestConfig {
+    if !timeframe.is_intraday() {
+        return config;
+    }
+    match IntradaySession::for_ticker(symbol) {
+        Some(session) => config.with_session(session.with_flatten_at_close(flatten_at_close)),
+        None => config,
+    }
+}
+
+/// 장중 캔들 로드
+///
+/// 일봉 샘플 데이터로는 세션을 시뮬레이션할 수 없으므로, DB에 분봉 데이터가 없으면
+/// 샘플로 대체하지 않고 오류를 반환합니다.
+async fn load_intraday_klines(
+    state: &AppState,
+    symbols: &[String],
+    timeframe: Timeframe,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+) -> Result<HashMap<String, Vec<Kline>>, (StatusCode, Json<BacktestApiError>)> {
+    let no_data = |detail: String| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(BacktestApiError::new(
+                "NO_DATA",
+                format!("{} 캔들 데이터가 없습니다: {}", timeframe, detail),
+            )),
+        )
+    };
+
+    let Some(pool) = &state.db_pool else {
+        return Err(no_data("데이터베이스 연결 없음".to_string()));
+    };
+    match load_multi_klines_from_db(pool, symbols, timeframe, start_date, end_date).await {
+        Ok(data) if !data.is_empty() => {
+            info!(
+                "DB에서 {} 심볼의 {} 데이터 로드 완료",
+                data.len(),
+                timeframe
+            );
+            Ok(data)
+        }
+        Ok(_) => Err(no_data(symbols.join(","))),
+        Err(e) => Err(no_data(e)),
+    }
+}
+
+/// 레버리지 ETF 합성 모드 기본값 (장기 백테스트가 필요한 레버리지 전략만 사용)
+fn synthetic_leverage_default(strategy_id: &str) -> bool {
+    matches!(
+        strategy_id,
+        "us_3x_leverage" | "us_leverage" | "snow" | "snow_us" | "snow_kr"
+    )
+}
+
+/// 합성 모드가 켜진 경우 레버리지 ETF의 상장 이전 구간을 기초자산으로 합성
+///
+/// 차입 금리는 요청의 현금 금리 시리즈를 사용하고, 없으면 고정 금리로 계산합니다.
+async fn apply_leveraged_synthesis(
+    state: &AppState,
+    multi_klines: &mut HashMap<String, Vec<Kline>>,
+    symbols: &[String],
+    enabled: bool,
+    cash_yield_series: Option<&str>,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+) {
+    let (true, Some(pool)) = (enabled, &state.db_pool) else {
+        return;
+    };
+
+    let financing = match cash_yield_series {
+        Some(series) => load_cash_yield_curve(pool, series, start_date, end_date).await,
+        None => None,
+    };
+    let filled = fill_leveraged_history(
+        pool,
+        multi_klines,
+        symbols,
+        start_date,
+        end_date,
+        financing.as_ref(),
+    )
+    .await;
+    if !filled.is_empty() {
+        info!("레버리지 ETF 합성 데이터 사용: {:?}", filled);
+    }
+}
+
+/// 일봉 이상 캔들 로드 (출처 포함)
+///
+/// 저장된 데이터가 없거나 로드에 실패하면 `allow_synthetic`일 때만 샘플 데이터로 대체하고,
+/// 그 외에는 합성 데이터로 조용히 대체하지 않고 오류를 반환합니다.
+async fn load_daily_klines(
+    state: &AppState,
+    symbols: &[String],
+    timeframe: Timeframe,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+    allow_synthetic: bool,
+) -> Result<(HashMap<String, Vec<Kline>>, KlineSource), (StatusCode, Json<BacktestApiError>)> {
+    let detail = match &state.db_pool {
+        Some(pool) => {
+            match load_multi_klines_from_db(pool, symbols, timeframe, start_date, end_date).await {
+                Ok(data) if !data.is_empty() => {
+                    info!("DB에서 {} 심볼의 데이터 로드 완료", data.len());
+                    for (sym, klines) in &data {
+                        info!("  - {} 심볼: {} 개 캔들", sym, klines.len());
+                    }
+                    return Ok((data, KlineSource::Stored));
+                }
+                Ok(_) => symbols.join(","),
+                Err(e) => e,
+            }
+        }
+        None => "데이터베이스 연결 없음".to_string(),
+    };
+
+    if !allow_synthetic {
+        return Err((
+            StatusCode::UNPROCESSABLE_ENTITY,
+            Json(BacktestApiError::new(
+                "NO_DATA",
+                format!(
+                    "{} 캔들 데이터가 없습니다: {} (데모용 샘플 데이터는 allow_synthetic=true로 실행)",
+                    timeframe, detail
+                ),
+            )),
+        ));
+    }
+
+    warn!(
+        "저장된 데이터가 없어 샘플 데이터로 백테스트 실행: {}",
+        detail
+    );
+    let data = match symbols {
+        [symbol] => HashMap::from([(
+            symbol.clone(),
+            generate_sample_klines(symbol, start_date, end_date),
+        )]),
+        _ => generate_multi_sample_klines(symbols, start_date, end_date),
+    };
+    Ok((data, KlineSource::Synthetic))
+}
+
+/// 심볼별 캔들 수 (레버리지 합성 이전 기록용)
+fn kline_counts(multi_klines: &HashMap<String, Vec<Kline>>) -> HashMap<String, usize> {
+    multi_klines
+        .iter()
+        .map(|(symbol, klines)| (symbol.clone(), klines.len()))
+        .collect()
+}
+
+/// 요청의 커버리지 정책 (최소 커버리지 범위 검증 포함)
+fn coverage_policy(
+    allow_synthetic: bool,
+    min_coverage_pct: Option<f64>,
+    synthetic_leverage: bool,
+) -> Result<CoveragePolicy, (StatusCode, Json<BacktestApiError>)> {
+    let min_coverage_pct = min_coverage_pct.unwrap_or(DEFAULT_MIN_COVERAGE_PCT);
+    if !(0.0..=100.0).contains(&min_coverage_pct) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(BacktestApiError::new(
+                "INVALID_COVERAGE",
+                "최소 커버리지는 0-100%여야 합니다",
+            )),
+        ));
+    }
+    Ok(CoveragePolicy {
+        min_coverage_pct,
+        allow_synthetic,
+        allow_leverage_backfill: synthetic_leverage,
+    })
+}
+
+/// 데이터 커버리지 리포트 작성
+///
+/// `loaded`는 레버리지 합성 이전 심볼별 캔들 수로, 이후 늘어난 캔들은 합성 구간으로 집계합니다.
+/// ohlcv 캐시는 원시(비수정) 가격이므로 수정주가 여부는 false로 기록됩니다.
+fn build_coverage_report(
+    multi_klines: &HashMap<String, Vec<Kline>>,
+    symbols: &[String],
+    source: KlineSource,
+    loaded: &HashMap<String, usize>,
+    timeframe: Timeframe,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+) -> DataCoverageReport {
+    let mut report = DataCoverageReport::new(timeframe, start_date, end_date);
+    for symbol in symbols {
+        let klines = multi_klines.get(symbol).map(Vec::as_slice).unwrap_or(&[]);
+        let backfilled = klines
+            .len()
+            .saturating_sub(loaded.get(symbol).copied().unwrap_or(0));
+        report.push(
+            SymbolCoverage::compute(symbol, klines, timeframe, start_date, end_date, source)
+                .reclassify(source, KlineSource::LeverageBackfill, backfilled),
+        );
+    }
+    report
+}
+
+/// 커버리지 정책 검사 (미달 시 실행 거부)
+fn enforce_coverage(
+    report: DataCoverageReport,
+    policy: &CoveragePolicy,
+) -> Result<DataCoverageReport, (StatusCode, Json<BacktestApiError>)> {
+    let violations = report.violations(policy);
+    if !violations.is_empty() {
+        warn!("데이터 커버리지 미달로 백테스트 거부: {:?}", violations);
+        return Err((
+            StatusCode::UNPROCESSABLE_ENTITY,
+            Json(BacktestApiError::new(
+                "INSUFFICIENT_DATA_COVERAGE",
+                format!("데이터 커버리지 부족: {}", violations.join("; ")),
+            )),
+        ));
+    }
+    if report.uses_synthetic() {
+        warn!("합성 데이터가 포함된 백테스트 실행");
+    }
+    Ok(report)
+}
+
+/// 요청에 거래 비용 모델이 지정된 경우 설정에 반영
+fn apply_cost_model(config: BacktestConfig, model: Option<&TradingCostModel>) -> BacktestConfig {
+    match model {
+        Some(model) => config.with_cost_model(model.clone()),
+        None => config,
+    }
+}
+
+/// 요청에 마진 설정이 있으면 공매도/신용 매수를 허용합니다.
+fn apply_margin(config: BacktestConfig, margin: Option<&MarginConfig>) -> BacktestConfig {
+    match margin {
+        Some(margin) => config.with_margin(margin.clone()),
+        None => config,
+    }
+}
+
+/// 배치 실행 단위 (요청 항목 또는 템플릿에서 생성).
+struct BatchRunSpec {
+    strategy_id: String,
+    template_id: Option<uuid::Uuid>,
+    template_name: Option<String>,
+    symbols: Vec<String>,
+    parameters: Option<serde_json::Value>,
     start_date: NaiveDate,
     end_date: NaiveDate,
     initial_capital: Decimal,
     commission_rate: Decimal,
     slippage_rate: Decimal,
-    params: &Option<serde_json::Value>,
-) -> Result<BacktestMetricsResponse, String> {
-    use trader_analytics::backtest::BacktestConfig;
+    cost_model: Option<TradingCostModel>,
+    cash_yield_series: Option<String>,
+}
 
-    // 데이터 로드
-    let klines = if let Some(pool) = &state.db_pool {
-        match load_klines_from_db(pool, symbol, start_date, end_date).await {
-            Ok(data) if !data.is_empty() => data,
-            _ => generate_sample_klines(symbol, start_date, end_date),
+impl BatchRunSpec {
+    /// 템플릿에서 실행 단위 생성 (종료일 미지정 시 `today`).
+    fn from_template(template: BacktestTemplateRecord, today: NaiveDate) -> Self {
+        Self {
+            end_date: template.resolved_end_date(today),
+            strategy_id: template.strategy_id,
+            template_id: Some(template.id),
+            template_name: Some(template.name),
+            symbols: template.symbols,
+            parameters: template.parameters,
+            start_date: template.start_date,
+            initial_capital: template.initial_capital,
+            commission_rate: template.commission_rate.unwrap_or(Decimal::new(1, 3)),
+            slippage_rate: template.slippage_rate.unwrap_or(Decimal::new(5, 4)),
+            cost_model: None,
+            cash_yield_series: template.cash_yield_series,
         }
+    }
+
+    /// 백테스트 설정 생성 (현금 금리 포함).
+    async fn config(&self, state: &AppState) -> BacktestConfig {
+        let config = BacktestConfig::new(self.initial_capital)
+            .with_commission_rate(self.commission_rate)
+            .with_slippage_rate(self.slippage_rate);
+        let config = apply_cost_model(config, self.cost_model.as_ref());
+        apply_cash_yield(
+            config,
+            state,
+            self.cash_yield_series.as_deref(),
+            self.start_date,
+            self.end_date,
+        )
+        .await
+    }
+}
+
+/// 요청의 인라인 전략 항목을 실행 단위로 변환.
+#[allow(clippy::result_large_err)]
+fn inline_batch_specs(
+    request: &BatchBacktestRequest,
+) -> Result<Vec<BatchRunSpec>, (StatusCode, Json<BacktestApiError>)> {
+    let (Some(start_date), Some(end_date), Some(initial_capital)) = (
+        request.start_date.as_deref(),
+        request.end_date.as_deref(),
+        request.initial_capital,
+    ) else {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(BacktestApiError::new(
+                "VALIDATION_ERROR",
+                "전략 실행에는 start_date, end_date, initial_capital이 필요합니다",
+            )),
+        ));
+    };
+
+    // 날짜 파싱
+    let start_date = NaiveDate::parse_from_str(start_date, "%Y-%m-%d").map_err(|e| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(BacktestApiError::new(
+                "INVALID_DATE",
+                format!("시작 날짜 파싱 실패: {}", e),
+            )),
+        )
+    })?;
+    let end_date = NaiveDate::parse_from_str(end_date, "%Y-%m-%d").map_err(|e| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(BacktestApiError::new(
+                "INVALID_DATE",
+                format!("종료 날짜 파싱 실패: {}", e),
+            )),
+        )
+    })?;
+
+    // 수수료/슬리피지 기본값
+    let commission_rate = request.commission_rate.unwrap_or(Decimal::new(1, 3));
+    let slippage_rate = request.slippage_rate.unwrap_or(Decimal::new(5, 4));
+
+    Ok(request
+        .strategies
+        .iter()
+        .map(|item| BatchRunSpec {
+            strategy_id: item.strategy_id.clone(),
+            template_id: None,
+            template_name: None,
+            symbols: item.symbols.clone(),
+            parameters: item.parameters.clone(),
+            start_date,
+            end_date,
+            initial_capital,
+            commission_rate,
+            slippage_rate,
+            cost_model: request.cost_model.clone(),
+            cash_yield_series: None,
+        })
+        .collect())
+}
+
+/// 템플릿 ID를 실행 단위로 변환 (요청 순서 유지).
+async fn template_batch_specs(
+    state: &AppState,
+    template_ids: &[uuid::Uuid],
+) -> Result<Vec<BatchRunSpec>, (StatusCode, Json<BacktestApiError>)> {
+    let pool = state.db_pool.as_ref().ok_or_else(|| {
+        (
+            StatusCode::SERVICE_UNAVAILABLE,
+            Json(BacktestApiError::new(
+                "DATABASE_ERROR",
+                "Database not available",
+            )),
+        )
+    })?;
+
+    let db_error = |e: sqlx::Error| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(BacktestApiError::new("DB_ERROR", e.to_string())),
+        )
+    };
+
+    let templates: HashMap<uuid::Uuid, BacktestTemplateRecord> =
+        BacktestTemplateRepository::get_many(pool, template_ids)
+            .await
+            .map_err(db_error)?
+            .into_iter()
+            .map(|t| (t.id, t))
+            .collect();
+
+    let today = Utc::now().date_naive();
+    let mut specs = Vec::with_capacity(template_ids.len());
+    for id in template_ids {
+        let template = templates.get(id).cloned().ok_or_else(|| {
+            (
+                StatusCode::NOT_FOUND,
+                Json(BacktestApiError::new(
+                    "TEMPLATE_NOT_FOUND",
+                    format!("백테스트 템플릿을 찾을 수 없습니다: {}", id),
+                )),
+            )
+        })?;
+        specs.push(BatchRunSpec::from_template(template, today));
+    }
+
+    BacktestTemplateRepository::mark_run(pool, template_ids)
+        .await
+        .map_err(db_error)?;
+
+    Ok(specs)
+}
+
+/// 템플릿 하나를 실행하여 메트릭 반환 (정기 백테스트용).
+///
+/// 실행 구간(시작일, 종료일)을 함께 반환합니다.
+pub(crate) async fn run_template_metrics(
+    state: &Arc<AppState>,
+    template: BacktestTemplateRecord,
+) -> (
+    NaiveDate,
+    NaiveDate,
+    Result<BacktestMetricsResponse, String>,
+) {
+    let spec = BatchRunSpec::from_template(template, Utc::now().date_naive());
+    let result = if spec.symbols.len() == 1 {
+        run_single_strategy_internal(state, &spec).await
     } else {
-        generate_sample_klines(symbol, start_date, end_date)
+        run_multi_strategy_internal(state, &spec).await
     };
+    (spec.start_date, spec.end_date, result)
+}
+
+/// 종목 일봉 로드 (전략 추천용, 샘플 데이터 대체 없음).
+pub(crate) async fn load_symbol_klines(
+    pool: &sqlx::PgPool,
+    symbol: &str,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+) -> Result<Vec<Kline>, String> {
+    load_klines_from_db(pool, symbol, Timeframe::D1, start_date, end_date).await
+}
+
+/// 추가 심볼 없이 종목 하나로 실행되는 전략인지 여부.
+pub(crate) fn is_single_symbol_strategy(strategy_id: &str, symbol: &str) -> bool {
+    expand_strategy_symbols(strategy_id, &[symbol.to_string()]).len() == 1
+}
+
+/// 미리 로드한 일봉으로 단일 종목 백테스트 메트릭 계산 (전략 추천용).
+///
+/// 후보 파라미터마다 데이터를 다시 읽지 않도록 캔들을 공유하며,
+/// 수수료/슬리피지는 백테스트 API 기본값(0.1%/0.05%)을 사용합니다.
+pub(crate) async fn run_symbol_metrics(
+    strategy_id: &str,
+    klines: &[Kline],
+    parameters: &Option<serde_json::Value>,
+    initial_capital: Decimal,
+) -> Result<BacktestMetricsResponse, String> {
+    let config = BacktestConfig::new(initial_capital)
+        .with_commission_rate(Decimal::new(1, 3))
+        .with_slippage_rate(Decimal::new(5, 4));
+    let report = run_strategy_backtest(strategy_id, config, klines, parameters).await?;
+    Ok(convert_report_to_metrics(&report))
+}
+
+/// 단일 전략 내부 실행 (배치용).
+async fn run_single_strategy_internal(
+    state: &Arc<AppState>,
+    spec: &BatchRunSpec,
+) -> Result<BacktestMetricsResponse, String> {
+    let symbol = &spec.symbols[0];
+
+    // 데이터 로드 (배치/정기 실행은 샘플 데이터로 대체하지 않음)
+    let symbols = std::slice::from_ref(symbol);
+    let (mut single_klines, source) = load_daily_klines(
+        state,
+        symbols,
+        Timeframe::D1,
+        spec.start_date,
+        spec.end_date,
+        false,
+    )
+    .await
+    .map_err(|(_, Json(e))| e.message)?;
+    enforce_coverage(
+        build_coverage_report(
+            &single_klines,
+            symbols,
+            source,
+            &kline_counts(&single_klines),
+            Timeframe::D1,
+            spec.start_date,
+            spec.end_date,
+        ),
+        &CoveragePolicy::default(),
+    )
+    .map_err(|(_, Json(e))| e.message)?;
+    let klines = single_klines.remove(symbol).unwrap_or_default();
 
     if klines.is_empty() {
         return Err("데이터 없음".to_string());
     }
 
-    // 백테스트 설정
-    let config = BacktestConfig::new(initial_capital)
-        .with_commission_rate(commission_rate)
-        .with_slippage_rate(slippage_rate);
-
     // 백테스트 실행
-    let report = run_strategy_backtest(strategy_id, config, &klines, params)
+    let config = spec.config(state).await;
+    let report = run_strategy_backtest(&spec.strategy_id, config, &klines, &spec.parameters)
         .await
         .map_err(|e| e.to_string())?;
 
@@ -785,32 +1605,57 @@ async fn run_single_strategy_internal(
 }
 
 /// 다중 자산 전략 내부 실행 (배치용).
-#[allow(clippy::too_many_arguments)]
 async fn run_multi_strategy_internal(
     state: &Arc<AppState>,
-    strategy_id: &str,
-    symbols: &[String],
-    start_date: NaiveDate,
-    end_date: NaiveDate,
-    initial_capital: Decimal,
-    commission_rate: Decimal,
-    slippage_rate: Decimal,
-    params: &Option<serde_json::Value>,
+    spec: &BatchRunSpec,
 ) -> Result<BacktestMetricsResponse, String> {
-    use trader_analytics::backtest::BacktestConfig;
-
     // 심볼 확장
-    let expanded_symbols = expand_strategy_symbols(strategy_id, symbols);
+    let expanded_symbols = expand_strategy_symbols(&spec.strategy_id, &spec.symbols);
+
+    // 다중 심볼 데이터 로드 (배치/정기 실행은 샘플 데이터로 대체하지 않음)
+    let (mut multi_klines, source) = load_daily_klines(
+        state,
+        &expanded_symbols,
+        Timeframe::D1,
+        spec.start_date,
+        spec.end_date,
+        false,
+    )
+    .await
+    .map_err(|(_, Json(e))| e.message)?;
+    let loaded = kline_counts(&multi_klines);
+
+    // 레버리지 ETF 상장 이전 구간 합성
+    let synthetic_leverage = synthetic_leverage_default(&spec.strategy_id);
+    apply_leveraged_synthesis(
+        state,
+        &mut multi_klines,
+        &expanded_symbols,
+        synthetic_leverage,
+        spec.cash_yield_series.as_deref(),
+        spec.start_date,
+        spec.end_date,
+    )
+    .await;
 
-    // 다중 심볼 데이터 로드
-    let multi_klines = if let Some(pool) = &state.db_pool {
-        match load_multi_klines_from_db(pool, &expanded_symbols, start_date, end_date).await {
-            Ok(data) if !data.is_empty() => data,
-            _ => generate_multi_sample_klines(&expanded_symbols, start_date, end_date),
-        }
-    } else {
-        generate_multi_sample_klines(&expanded_symbols, start_date, end_date)
+    // 데이터 커버리지 검사
+    let policy = CoveragePolicy {
+        allow_leverage_backfill: synthetic_leverage,
+        ..CoveragePolicy::default()
     };
+    enforce_coverage(
+        build_coverage_report(
+            &multi_klines,
+            &expanded_symbols,
+            source,
+            &loaded,
+            Timeframe::D1,
+            spec.start_date,
+            spec.end_date,
+        ),
+        &policy,
+    )
+    .map_err(|(_, Json(e))| e.message)?;
 
     // 병합
     let merged_klines = merge_multi_klines(&multi_klines);
@@ -819,16 +1664,17 @@ async fn run_multi_strategy_internal(
         return Err("데이터 없음".to_string());
     }
 
-    // 백테스트 설정
-    let config = BacktestConfig::new(initial_capital)
-        .with_commission_rate(commission_rate)
-        .with_slippage_rate(slippage_rate);
-
     // 백테스트 실행
-    let report =
-        run_multi_strategy_backtest(strategy_id, config, &merged_klines, &multi_klines, params)
-            .await
-            .map_err(|e| e.to_string())?;
+    let config = spec.config(state).await;
+    let report = run_multi_strategy_backtest(
+        &spec.strategy_id,
+        config,
+        &merged_klines,
+        &multi_klines,
+        &spec.parameters,
+    )
+    .await
+    .map_err(|e| e.to_string())?;
 
     // 메트릭만 반환
     Ok(convert_report_to_metrics(&report))
@@ -1029,8 +1875,8 @@ mod tests {
         // 최소 3개의 기본 전략이 있어야 함
         assert!(list.total >= 3);
         assert!(list.strategies.iter().any(|s| s.id == "sma_crossover"));
-        assert!(list.strategies.iter().any(|s| s.id == "rsi_mean_reversion"));
-        assert!(list.strategies.iter().any(|s| s.id == "grid_trading"));
+        assert!(list.strategies.iter().any(|s| s.id == "rsi"));
+        assert!(list.strategies.iter().any(|s| s.id == "grid"));
     }
 
     #[tokio::test]
@@ -1047,7 +1893,8 @@ mod tests {
             "symbol": "BTC/USDT",
             "start_date": "2024-01-01",
             "end_date": "2024-06-30",
-            "initial_capital": 10000000
+            "initial_capital": 10000000,
+            "allow_synthetic": true
         });
 
         let response = app
@@ -1075,6 +1922,52 @@ mod tests {
         assert!(!result.equity_curve.is_empty());
         // trades는 샘플 데이터에서 거래 신호가 발생하지 않을 수 있음
         // 실제 DB 데이터에서는 trades가 생성됨
+
+        // 샘플 데이터 사용이 커버리지 리포트에 기록됨
+        let coverage = result.data_coverage.expect("커버리지 리포트");
+        assert!(coverage.uses_synthetic());
+        assert_eq!(coverage.symbols[0].coverage_pct, 100.0);
+    }
+
+    #[tokio::test]
+    async fn test_run_backtest_refuses_synthetic_by_default() {
+        use crate::state::create_test_state;
+
+        let state = Arc::new(create_test_state());
+        let app = Router::new()
+            .route("/run", post(run_backtest))
+            .with_state(state);
+
+        // DB 데이터가 없으면 샘플 데이터로 대체하지 않고 거부
+        let request_body = serde_json::json!({
+            "strategy_id": "sma_crossover",
+            "symbol": "BTC/USDT",
+            "start_date": "2024-01-01",
+            "end_date": "2024-06-30",
+            "initial_capital": 10000000
+        });
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/run")
+                    .header("content-type", "application/json")
+                    .body(Body::from(serde_json::to_string(&request_body).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let error: BacktestApiError = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(error.code, "NO_DATA");
+        assert!(error.message.contains("allow_synthetic"));
     }
 
     #[tokio::test]
@@ -1116,6 +2009,59 @@ mod tests {
         assert_eq!(error.code, "INVALID_DATE");
     }
 
+    #[tokio::test]
+    async fn test_run_backtest_intraday_validation() {
+        use crate::state::create_test_state;
+
+        let state = Arc::new(create_test_state());
+        let cases = [
+            ("7m", "2024-01-01", "2024-01-31", "INVALID_TIMEFRAME"),
+            ("5m", "2024-01-01", "2024-06-30", "INVALID_DATE_RANGE"),
+        ];
+
+        for (timeframe, start_date, end_date, code) in cases {
+            let app = Router::new()
+                .route("/run", post(run_backtest))
+                .with_state(state.clone());
+            let request_body = serde_json::json!({
+                "strategy_id": "rsi",
+                "symbol": "005930",
+                "start_date": start_date,
+                "end_date": end_date,
+                "initial_capital": 10000000,
+                "timeframe": timeframe
+            });
+
+            let response = app
+                .oneshot(
+                    Request::builder()
+                        .method("POST")
+                        .uri("/run")
+                        .header("content-type", "application/json")
+                        .body(Body::from(serde_json::to_string(&request_body).unwrap()))
+                        .unwrap(),
+                )
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+            let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            let error: BacktestApiError = serde_json::from_slice(&body).unwrap();
+            assert_eq!(error.code, code);
+        }
+
+        // 장중 타임프레임은 종목 시장의 정규장 세션 적용
+        let config = apply_session(BacktestConfig::default(), Timeframe::M5, "005930", true);
+        assert_eq!(
+            config.session,
+            Some(IntradaySession::krx().with_flatten_at_close(true))
+        );
+        let config = apply_session(BacktestConfig::default(), Timeframe::D1, "005930", true);
+        assert!(config.session.is_none());
+    }
+
     #[tokio::test]
     async fn test_run_backtest_invalid_date_range() {
         use crate::state::create_test_state;
@@ -1196,25 +2142,152 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_get_backtest_result_not_found() {
+    async fn test_run_result_persisted_by_default() {
         use crate::state::create_test_state;
 
-        let state = Arc::new(create_test_state());
+        let request: BacktestRunRequest = serde_json::from_value(serde_json::json!({
+            "strategy_id": "rsi",
+            "symbol": "005930",
+            "start_date": "2024-01-01",
+            "end_date": "2024-06-30",
+            "initial_capital": 10000000
+        }))
+        .unwrap();
+        assert!(request.persist);
+        assert!(request.registered_strategy_id.is_none());
+
+        // DB 연결이 없으면 저장하지 않음
+        let state = create_test_state();
+        let ctx = PersistContext {
+            enabled: request.persist,
+            strategy_type: &request.strategy_id,
+            registered_strategy_id: None,
+            start_date: NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+            end_date: NaiveDate::from_ymd_opt(2024, 6, 30).unwrap(),
+            initial_capital: request.initial_capital,
+            slippage_rate: Decimal::new(5, 4),
+            parameters: None,
+            timeframes_used: None,
+        };
+        let response = serde_json::json!({ "success": true, "metrics": {} });
+        let id = persist_run_result(&state, &OptionalJwtAuth(None), ctx, "005930", &response).await;
+        assert!(id.is_none());
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn test_run_backtest_persists_result(pool: sqlx::PgPool) {
+        use crate::repository::ListResultsFilter;
+        use crate::state::create_test_state;
+
+        let state = Arc::new(create_test_state().with_db_pool(pool.clone()));
         let app = Router::new()
-            .route("/results/{id}", get(get_backtest_result))
+            .route("/run", post(run_backtest))
             .with_state(state);
 
-        let response = app
-            .oneshot(
+        // 등록되지 않은 심볼은 저장된 캔들이 없어 샘플 데이터로 실행 (외부 다운로드 없음)
+        let run = |persist: bool| {
+            let request_body = serde_json::json!({
+                "strategy_id": "rsi",
+                "symbol": "UNLISTED01",
+                "start_date": "2024-01-01",
+                "end_date": "2024-06-30",
+                "initial_capital": 10000000,
+                "allow_synthetic": true,
+                "persist": persist
+            });
+            app.clone().oneshot(
                 Request::builder()
-                    .uri("/results/nonexistent-id")
-                    .body(Body::empty())
+                    .method("POST")
+                    .uri("/run")
+                    .header("content-type", "application/json")
+                    .body(Body::from(serde_json::to_string(&request_body).unwrap()))
                     .unwrap(),
             )
+        };
+
+        let response = run(true).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
             .await
             .unwrap();
+        let result: BacktestRunResponse = serde_json::from_slice(&body).unwrap();
+        assert!(result.persisted);
 
-        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        let id: uuid::Uuid = result.id.parse().unwrap();
+        let record = BacktestResultsRepository::get_by_id(&pool, id)
+            .await
+            .unwrap()
+            .expect("저장된 실행 결과");
+        assert!(record.strategy_id.is_none());
+        assert_eq!(record.strategy_type, "rsi");
+        assert_eq!(record.symbol, "UNLISTED01");
+        assert_eq!(
+            record.start_date,
+            NaiveDate::from_ymd_opt(2024, 1, 1).unwrap()
+        );
+        assert_eq!(
+            record.end_date,
+            NaiveDate::from_ymd_opt(2024, 6, 30).unwrap()
+        );
+        assert_eq!(record.initial_capital, Decimal::from(10_000_000));
+        assert_eq!(
+            record.equity_curve.as_array().map(Vec::len),
+            Some(result.equity_curve.len())
+        );
+
+        // persist=false 요청은 저장하지 않음
+        let response = run(false).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let result: BacktestRunResponse = serde_json::from_slice(&body).unwrap();
+        assert!(!result.persisted);
+
+        let list = BacktestResultsRepository::list(&pool, ListResultsFilter::new())
+            .await
+            .unwrap();
+        assert_eq!(list.total, 1);
+    }
+
+    #[tokio::test]
+    async fn test_run_batch_backtest_requires_config_for_strategies() {
+        use crate::state::create_test_state;
+
+        let state = Arc::new(create_test_state());
+        let app = Router::new()
+            .route("/run-batch", post(run_batch_backtest))
+            .with_state(state);
+
+        // 인라인 전략에는 기간/자본 필요, 템플릿 조회에는 DB 필요
+        for (request_body, expected) in [
+            (
+                serde_json::json!({
+                    "strategies": [{ "strategy_id": "sma_crossover", "symbols": ["BTC/USDT"] }]
+                }),
+                StatusCode::BAD_REQUEST,
+            ),
+            (serde_json::json!({}), StatusCode::BAD_REQUEST),
+            (
+                serde_json::json!({ "template_ids": [uuid::Uuid::new_v4()] }),
+                StatusCode::SERVICE_UNAVAILABLE,
+            ),
+        ] {
+            let response = app
+                .clone()
+                .oneshot(
+                    Request::builder()
+                        .method("POST")
+                        .uri("/run-batch")
+                        .header("content-type", "application/json")
+                        .body(Body::from(request_body.to_string()))
+                        .unwrap(),
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(response.status(), expected);
+        }
     }
 
     #[test]
@@ -0,0 +1,320 @@
+//! 저장된 백테스트 결과의 몬테카를로 시뮬레이션.
+//!
+//! `POST /api/v1/backtest/monte-carlo`
+//!
+//! 저장된 결과의 거래 내역 또는 자산 곡선(일간 수익률)을 재표본하여
+//! CAGR·최대 낙폭·최종 자산의 신뢰구간과 파산 확률을 반환합니다.
+
+use axum::{extract::State, http::StatusCode, Json};
+use chrono::{DateTime, NaiveTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::repository::{BacktestResultRecord, BacktestResultsRepository};
+use crate::state::AppState;
+use trader_analytics::backtest::{
+    simulate_monte_carlo, BacktestError, MonteCarloConfig, MonteCarloInput, MonteCarloResult,
+    ResampleMethod,
+};
+
+use super::types::{BacktestApiError, EquityCurvePoint, TradeHistoryItem};
+
+type ApiError = (StatusCode, Json<BacktestApiError>);
+
+/// 몬테카를로 시뮬레이션 요청.
+#[derive(Debug, Clone, Deserialize)]
+pub struct MonteCarloRequest {
+    /// 저장된 백테스트 결과 ID
+    pub result_id: String,
+    /// 재표본 방식 (trades, daily_returns)
+    #[serde(default)]
+    pub method: ResampleMethod,
+    /// 시뮬레이션 경로 수 (기본 1,000, 최대 10,000)
+    #[serde(default = "default_iterations")]
+    pub iterations: usize,
+    /// 파산 기준 낙폭 (%, 기본 50)
+    #[serde(default = "default_ruin_threshold_pct")]
+    pub ruin_threshold_pct: f64,
+    /// 난수 시드 (같은 시드면 같은 결과)
+    #[serde(default)]
+    pub seed: Option<u64>,
+}
+
+fn default_iterations() -> usize {
+    MonteCarloConfig::default().iterations
+}
+
+fn default_ruin_threshold_pct() -> f64 {
+    MonteCarloConfig::default().ruin_threshold_pct
+}
+
+impl MonteCarloRequest {
+    fn config(&self) -> MonteCarloConfig {
+        let config = MonteCarloConfig::default()
+            .with_method(self.method)
+            .with_iterations(self.iterations)
+            .with_ruin_threshold_pct(self.ruin_threshold_pct);
+        match self.seed {
+            Some(seed) => config.with_seed(seed),
+            None => config,
+        }
+    }
+}
+
+/// 몬테카를로 시뮬레이션 응답.
+#[derive(Debug, Clone, Serialize)]
+pub struct MonteCarloResponse {
+    /// 백테스트 결과 ID
+    pub result_id: String,
+    pub strategy_id: Option<String>,
+    pub symbol: String,
+    pub initial_capital: Decimal,
+    /// 원본 백테스트 최종 자산
+    pub original_final_equity: Option<Decimal>,
+    #[serde(flatten)]
+    pub simulation: MonteCarloResult,
+}
+
+/// 저장된 결과 레코드에서 시뮬레이션 입력을 구성합니다.
+fn input_from_record(record: &BacktestResultRecord) -> Result<MonteCarloInput, String> {
+    let mut trades: Vec<TradeHistoryItem> = serde_json::from_value(record.trades.clone())
+        .map_err(|e| format!("거래 내역을 읽을 수 없습니다: {}", e))?;
+    let equity_curve: Vec<EquityCurvePoint> =
+        serde_json::from_value(record.equity_curve.clone())
+            .map_err(|e| format!("자산 곡선을 읽을 수 없습니다: {}", e))?;
+
+    trades.sort_by_key(|t| t.exit_time);
+
+    Ok(MonteCarloInput::from_history(
+        record.initial_capital,
+        trades.iter().map(|t| t.pnl),
+        equity_curve.iter().filter_map(|p| {
+            DateTime::<Utc>::from_timestamp(p.timestamp, 0).map(|ts| (ts, p.equity))
+        }),
+        record.start_date.and_time(NaiveTime::MIN).and_utc(),
+        record.end_date.and_time(NaiveTime::MIN).and_utc(),
+    ))
+}
+
+/// 저장된 백테스트 결과로 몬테카를로 시뮬레이션 실행.
+///
+/// POST /api/v1/backtest/monte-carlo
+pub async fn run_monte_carlo(
+    State(state): State<Arc<AppState>>,
+    Json(request): Json<MonteCarloRequest>,
+) -> Result<Json<MonteCarloResponse>, ApiError> {
+    let config = request.config();
+    config.validate().map_err(|e| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(BacktestApiError::new("INVALID_CONFIG", e.to_string())),
+        )
+    })?;
+
+    let id = Uuid::parse_str(&request.result_id).map_err(|_| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(BacktestApiError::new(
+                "INVALID_ID",
+                "유효하지 않은 결과 ID 형식입니다",
+            )),
+        )
+    })?;
+
+    let pool = state.db_pool.as_ref().ok_or_else(|| {
+        (
+            StatusCode::SERVICE_UNAVAILABLE,
+            Json(BacktestApiError::new(
+                "DATABASE_ERROR",
+                "Database not available",
+            )),
+        )
+    })?;
+
+    let record = BacktestResultsRepository::get_by_id(pool, id)
+        .await
+        .map_err(|e| {
+            warn!("몬테카를로 결과 조회 실패: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(BacktestApiError::new("DB_ERROR", e.to_string())),
+            )
+        })?
+        .ok_or_else(|| {
+            (
+                StatusCode::NOT_FOUND,
+                Json(BacktestApiError::new(
+                    "RESULT_NOT_FOUND",
+                    format!("백테스트 결과를 찾을 수 없습니다: {}", id),
+                )),
+            )
+        })?;
+
+    let input = input_from_record(&record).map_err(|message| {
+        (
+            StatusCode::UNPROCESSABLE_ENTITY,
+            Json(BacktestApiError::new("INVALID_RESULT", message)),
+        )
+    })?;
+
+    // 최대 10,000 경로 × 표본 수 반복이므로 블로킹 스레드에서 계산
+    let simulation = tokio::task::spawn_blocking(move || simulate_monte_carlo(&input, &config))
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(BacktestApiError::new("SIMULATION_FAILED", e.to_string())),
+            )
+        })?
+        .map_err(|e| match e {
+            BacktestError::DataError(message) => (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                Json(BacktestApiError::new("INSUFFICIENT_DATA", message)),
+            ),
+            other => (
+                StatusCode::BAD_REQUEST,
+                Json(BacktestApiError::new("INVALID_CONFIG", other.to_string())),
+            ),
+        })?;
+
+    info!(
+        result_id = %id,
+        method = ?simulation.method,
+        iterations = simulation.iterations,
+        cagr_p5 = simulation.cagr_pct.p5,
+        risk_of_ruin = simulation.risk_of_ruin_pct,
+        "몬테카를로 시뮬레이션 완료"
+    );
+
+    let original_final_equity = record
+        .equity_curve
+        .as_array()
+        .and_then(|points| points.last())
+        .and_then(|point| serde_json::from_value::<EquityCurvePoint>(point.clone()).ok())
+        .map(|point| point.equity);
+
+    Ok(Json(MonteCarloResponse {
+        result_id: id.to_string(),
+        strategy_id: record.strategy_id,
+        symbol: record.symbol,
+        initial_capital: record.initial_capital,
+        original_final_equity,
+        simulation,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::create_test_state;
+    use axum::{body::Body, http::Request, routing::post, Router};
+    use chrono::NaiveDate;
+    use serde_json::json;
+    use tower::ServiceExt;
+
+    fn record() -> BacktestResultRecord {
+        BacktestResultRecord {
+            id: Uuid::new_v4(),
+            strategy_id: Some("rsi_test".to_string()),
+            strategy_type: "rsi".to_string(),
+            symbol: "005930".to_string(),
+            start_date: NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+            end_date: NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
+            initial_capital: Decimal::from(10_000),
+            slippage_rate: None,
+            metrics: json!({}),
+            config_summary: json!({}),
+            equity_curve: json!([
+                {"timestamp": 1_735_689_600, "equity": 10000, "drawdown_pct": 0},
+                {"timestamp": 1_735_776_000, "equity": 10100, "drawdown_pct": 0},
+                {"timestamp": 1_735_862_400, "equity": 9999, "drawdown_pct": 1}
+            ]),
+            trades: json!([
+                {
+                    "symbol": "005930",
+                    "entry_time": "2025-01-02T00:00:00Z",
+                    "exit_time": "2025-01-03T00:00:00Z",
+                    "entry_price": 100,
+                    "exit_price": 99,
+                    "quantity": 1,
+                    "side": "buy",
+                    "pnl": -1,
+                    "return_pct": -1
+                },
+                {
+                    "symbol": "005930",
+                    "entry_time": "2025-01-01T00:00:00Z",
+                    "exit_time": "2025-01-02T00:00:00Z",
+                    "entry_price": 100,
+                    "exit_price": 200,
+                    "quantity": 1,
+                    "side": "buy",
+                    "pnl": 100,
+                    "return_pct": 100
+                }
+            ]),
+            success: true,
+            error_message: None,
+            created_at: Utc::now(),
+            deleted_at: None,
+            timeframes_used: None,
+            parameters: None,
+            sensitivity: None,
+        }
+    }
+
+    #[test]
+    fn test_input_from_record() {
+        let input = input_from_record(&record()).unwrap();
+
+        assert_eq!(input.initial_capital, 10_000.0);
+        // 청산 시각 순으로 정렬
+        assert_eq!(input.trade_pnls, vec![100.0, -1.0]);
+        assert_eq!(input.daily_returns.len(), 2);
+        assert!((input.daily_returns[0] - 0.01).abs() < 1e-12);
+        assert!((input.years - 1.0).abs() < 0.01);
+
+        let mut broken = record();
+        broken.trades = json!({"not": "a list"});
+        assert!(input_from_record(&broken).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_run_monte_carlo_validation() {
+        let state = Arc::new(create_test_state());
+        let app = Router::new()
+            .route("/monte-carlo", post(run_monte_carlo))
+            .with_state(state);
+
+        for (body, code) in [
+            (
+                json!({"result_id": Uuid::new_v4().to_string(), "iterations": 0}),
+                "INVALID_CONFIG",
+            ),
+            (json!({"result_id": "not-a-uuid"}), "INVALID_ID"),
+        ] {
+            let response = app
+                .clone()
+                .oneshot(
+                    Request::builder()
+                        .method("POST")
+                        .uri("/monte-carlo")
+                        .header("content-type", "application/json")
+                        .body(Body::from(body.to_string()))
+                        .unwrap(),
+                )
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+            let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            let error: BacktestApiError = serde_json::from_slice(&body).unwrap();
+            assert_eq!(error.code, code);
+        }
+    }
+}
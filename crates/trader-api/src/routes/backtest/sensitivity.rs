@@ -0,0 +1,419 @@
+//! 저장된 백테스트 결과의 수수료/슬리피지 민감도 분석.
+//!
+//! `GET /api/v1/backtest/results/{id}/sensitivity`
+//!
+//! 저장된 설정으로 수수료율 × 슬리피지율 격자를 재실행하고, 비용이 늘어날 때
+//! 수익이 얼마나 빨리 사라지는지(비용 1bp당 수익률 변화, 손익분기 비용)를 반환합니다.
+//! 분석 결과는 결과 레코드에 저장되어 백테스트 리포트에 함께 포함되며,
+//! 이후 호출은 저장된 분석을 반환합니다 (`refresh=true` 또는 격자 지정 시 재실행).
+//!
+//! 재실행은 저장된 전략/심볼/기간/자본/파라미터를 사용하며 일봉 기준입니다.
+
+use axum::{
+    extract::{Path, Query, State},
+    http::{HeaderMap, StatusCode},
+    Json,
+};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::auth::OptionalJwtAuth;
+use crate::repository::{BacktestResultRecord, BacktestResultsRepository};
+use crate::state::AppState;
+use trader_analytics::backtest::{
+    analyze_cost_sensitivity, CostSensitivityGrid, CostSensitivityPoint, CostSensitivityResult,
+};
+
+use super::types::{
+    BacktestApiError, BacktestConfigSummary, BacktestMetricsResponse, BacktestMultiRunRequest,
+    BacktestRunRequest,
+};
+use super::{run_backtest, run_multi_backtest};
+
+type ApiError = (StatusCode, Json<BacktestApiError>);
+
+/// 민감도 분석 쿼리.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct SensitivityQuery {
+    /// 수수료율 목록 (콤마 구분, 예: `0,0.0005,0.001`; 생략 시 원본의 0~8배)
+    #[serde(default)]
+    pub commission_rates: Option<String>,
+    /// 슬리피지율 목록 (콤마 구분, 생략 시 원본의 0~8배)
+    #[serde(default)]
+    pub slippage_rates: Option<String>,
+    /// 저장된 분석이 있어도 다시 실행
+    #[serde(default)]
+    pub refresh: bool,
+}
+
+impl SensitivityQuery {
+    /// 격자를 직접 지정했는지 여부.
+    fn has_custom_grid(&self) -> bool {
+        self.commission_rates.is_some() || self.slippage_rates.is_some()
+    }
+}
+
+/// 민감도 분석 응답.
+#[derive(Debug, Clone, Serialize)]
+pub struct SensitivityResponse {
+    /// 백테스트 결과 ID
+    pub result_id: String,
+    pub strategy_id: Option<String>,
+    pub symbol: String,
+    /// 저장된 분석을 반환했는지 여부
+    pub cached: bool,
+    #[serde(flatten)]
+    pub sensitivity: CostSensitivityResult,
+}
+
+/// 콤마 구분 비율 목록 파싱.
+fn parse_rates(name: &str, raw: &str) -> Result<Vec<Decimal>, String> {
+    raw.split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(|s| {
+            s.parse::<Decimal>()
+                .map_err(|_| format!("{} 값이 올바르지 않습니다: {}", name, s))
+        })
+        .collect()
+}
+
+/// 원본 백테스트의 (수수료율, 슬리피지율).
+///
+/// 설정 요약을 우선 사용하고, 없으면 레코드의 슬리피지율과 실행 기본값을 사용합니다.
+fn baseline_rates(record: &BacktestResultRecord) -> (Decimal, Decimal) {
+    match serde_json::from_value::<BacktestConfigSummary>(record.config_summary.clone()) {
+        Ok(summary) => (summary.commission_rate, summary.slippage_rate),
+        Err(_) => (
+            Decimal::new(1, 3),
+            record.slippage_rate.unwrap_or(Decimal::new(5, 4)),
+        ),
+    }
+}
+
+/// 분석 격자 구성 (지정하지 않은 축은 원본 기준 기본 격자).
+fn build_grid(
+    query: &SensitivityQuery,
+    commission_rate: Decimal,
+    slippage_rate: Decimal,
+) -> Result<CostSensitivityGrid, String> {
+    let default = CostSensitivityGrid::around(commission_rate, slippage_rate);
+    let grid = CostSensitivityGrid {
+        commission_rates: match &query.commission_rates {
+            Some(raw) => parse_rates("commission_rates", raw)?,
+            None => default.commission_rates,
+        },
+        slippage_rates: match &query.slippage_rates {
+            Some(raw) => parse_rates("slippage_rates", raw)?,
+            None => default.slippage_rates,
+        },
+    }
+    .normalized();
+    grid.validate().map_err(|e| e.to_string())?;
+    Ok(grid)
+}
+
+/// 성과 지표를 민감도 점으로 변환.
+fn sensitivity_point(
+    commission_rate: Decimal,
+    slippage_rate: Decimal,
+    metrics: &BacktestMetricsResponse,
+) -> CostSensitivityPoint {
+    let f = |value: Decimal| value.to_f64().unwrap_or(0.0);
+    CostSensitivityPoint {
+        total_return_pct: f(metrics.total_return_pct),
+        annualized_return_pct: f(metrics.annualized_return_pct),
+        sharpe_ratio: f(metrics.sharpe_ratio),
+        max_drawdown_pct: f(metrics.max_drawdown_pct),
+        profit_factor: f(metrics.profit_factor),
+        total_trades: metrics.total_trades,
+        ..CostSensitivityPoint::new(commission_rate, slippage_rate)
+    }
+}
+
+/// 저장된 설정을 지정한 비용으로 재실행 (결과는 저장하지 않음).
+async fn rerun_with_costs(
+    state: &Arc<AppState>,
+    auth: &OptionalJwtAuth,
+    headers: &HeaderMap,
+    record: &BacktestResultRecord,
+    commission_rate: Decimal,
+    slippage_rate: Decimal,
+) -> Result<BacktestMetricsResponse, ApiError> {
+    let start_date = record.start_date.format("%Y-%m-%d").to_string();
+    let end_date = record.end_date.format("%Y-%m-%d").to_string();
+    let symbols: Vec<String> = record
+        .symbol
+        .split(',')
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .collect();
+
+    if symbols.len() > 1 {
+        let request = BacktestMultiRunRequest {
+            strategy_id: record.strategy_type.clone(),
+            symbols,
+            start_date,
+            end_date,
+            initial_capital: record.initial_capital,
+            commission_rate: Some(commission_rate),
+            slippage_rate: Some(slippage_rate),
+            parameters: record.parameters.clone(),
+            cash_yield_series: None,
+            cost_model: None,
+            margin: None,
+            synthetic_leverage: None,
+            allow_synthetic: false,
+            min_coverage_pct: None,
+            registered_strategy_id: None,
+            persist: false,
+        };
+        let Json(response) = run_multi_backtest(
+            State(state.clone()),
+            auth.clone(),
+            headers.clone(),
+            Json(request),
+        )
+        .await?;
+        Ok(response.metrics)
+    } else {
+        let request = BacktestRunRequest {
+            strategy_id: record.strategy_type.clone(),
+            symbol: record.symbol.clone(),
+            start_date,
+            end_date,
+            initial_capital: record.initial_capital,
+            commission_rate: Some(commission_rate),
+            slippage_rate: Some(slippage_rate),
+            parameters: record.parameters.clone(),
+            cash_yield_series: None,
+            cost_model: None,
+            margin: None,
+            synthetic_leverage: None,
+            multi_timeframe_config: record
+                .timeframes_used
+                .clone()
+                .and_then(|value| serde_json::from_value(value).ok()),
+            timeframe: None,
+            flatten_at_close: false,
+            allow_synthetic: false,
+            min_coverage_pct: None,
+            registered_strategy_id: None,
+            persist: false,
+        };
+        let Json(response) = run_backtest(
+            State(state.clone()),
+            auth.clone(),
+            headers.clone(),
+            Json(request),
+        )
+        .await?;
+        Ok(response.metrics)
+    }
+}
+
+/// 저장된 백테스트 결과의 수수료/슬리피지 민감도 분석.
+///
+/// GET /api/v1/backtest/results/{id}/sensitivity
+pub async fn get_cost_sensitivity(
+    State(state): State<Arc<AppState>>,
+    auth: OptionalJwtAuth,
+    headers: HeaderMap,
+    Path(id): Path<String>,
+    Query(query): Query<SensitivityQuery>,
+) -> Result<Json<SensitivityResponse>, ApiError> {
+    let id = Uuid::parse_str(&id).map_err(|_| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(BacktestApiError::new(
+                "INVALID_ID",
+                "유효하지 않은 결과 ID 형식입니다",
+            )),
+        )
+    })?;
+
+    let pool = state.db_pool.as_ref().ok_or_else(|| {
+        (
+            StatusCode::SERVICE_UNAVAILABLE,
+            Json(BacktestApiError::new(
+                "DATABASE_ERROR",
+                "Database not available",
+            )),
+        )
+    })?;
+
+    let record = BacktestResultsRepository::get_by_id(pool, id)
+        .await
+        .map_err(|e| {
+            warn!("민감도 분석 결과 조회 실패: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(BacktestApiError::new("DB_ERROR", e.to_string())),
+            )
+        })?
+        .ok_or_else(|| {
+            (
+                StatusCode::NOT_FOUND,
+                Json(BacktestApiError::new(
+                    "RESULT_NOT_FOUND",
+                    format!("백테스트 결과를 찾을 수 없습니다: {}", id),
+                )),
+            )
+        })?;
+
+    let response = |sensitivity: CostSensitivityResult, cached: bool| SensitivityResponse {
+        result_id: id.to_string(),
+        strategy_id: record.strategy_id.clone(),
+        symbol: record.symbol.clone(),
+        cached,
+        sensitivity,
+    };
+
+    if !query.refresh && !query.has_custom_grid() {
+        if let Some(saved) = record
+            .sensitivity
+            .clone()
+            .and_then(|value| serde_json::from_value(value).ok())
+        {
+            return Ok(Json(response(saved, true)));
+        }
+    }
+
+    let (commission_rate, slippage_rate) = baseline_rates(&record);
+    let grid = build_grid(&query, commission_rate, slippage_rate).map_err(|message| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(BacktestApiError::new("INVALID_CONFIG", message)),
+        )
+    })?;
+
+    let combinations = grid.combinations();
+    info!(
+        result_id = %id,
+        runs = combinations.len(),
+        "수수료/슬리피지 민감도 분석 시작"
+    );
+
+    let mut points = Vec::with_capacity(combinations.len());
+    for (commission, slippage) in combinations {
+        let metrics =
+            rerun_with_costs(&state, &auth, &headers, &record, commission, slippage).await?;
+        points.push(sensitivity_point(commission, slippage, &metrics));
+    }
+
+    let sensitivity = analyze_cost_sensitivity(commission_rate, slippage_rate, points);
+
+    info!(
+        result_id = %id,
+        return_change_per_bp = ?sensitivity.return_change_per_bp,
+        breakeven_cost_bps = ?sensitivity.breakeven_cost_bps,
+        "수수료/슬리피지 민감도 분석 완료"
+    );
+
+    // 저장 실패는 분석 응답을 막지 않음
+    match serde_json::to_value(&sensitivity) {
+        Ok(value) => {
+            if let Err(e) = BacktestResultsRepository::save_sensitivity(pool, id, &value).await {
+                warn!("민감도 분석 저장 실패: {}", e);
+            }
+        }
+        Err(e) => warn!("민감도 분석 직렬화 실패: {}", e),
+    }
+
+    Ok(Json(response(sensitivity, false)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::create_test_state;
+    use axum::{body::Body, http::Request, routing::get, Router};
+    use chrono::{NaiveDate, Utc};
+    use rust_decimal_macros::dec;
+    use serde_json::json;
+    use tower::ServiceExt;
+
+    fn record(config_summary: serde_json::Value) -> BacktestResultRecord {
+        BacktestResultRecord {
+            id: Uuid::new_v4(),
+            strategy_id: Some("rsi_test".to_string()),
+            strategy_type: "rsi".to_string(),
+            symbol: "005930".to_string(),
+            start_date: NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+            end_date: NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
+            initial_capital: Decimal::from(10_000),
+            slippage_rate: Some(dec!(0.0007)),
+            metrics: json!({}),
+            config_summary,
+            equity_curve: json!([]),
+            trades: json!([]),
+            success: true,
+            error_message: None,
+            created_at: Utc::now(),
+            deleted_at: None,
+            timeframes_used: None,
+            parameters: None,
+            sensitivity: None,
+        }
+    }
+
+    #[test]
+    fn test_baseline_rates_and_grid() {
+        let summary = json!({
+            "initial_capital": 10000,
+            "commission_rate": 0.00015,
+            "slippage_rate": 0.0005,
+            "total_commission": 0,
+            "total_slippage": 0,
+            "data_points": 250
+        });
+        assert_eq!(
+            baseline_rates(&record(summary)),
+            (dec!(0.00015), dec!(0.0005))
+        );
+        // 설정 요약이 없으면 레코드의 슬리피지율과 기본 수수료율
+        let (commission, slippage) = baseline_rates(&record(json!({})));
+        assert_eq!((commission, slippage), (dec!(0.001), dec!(0.0007)));
+
+        let query = SensitivityQuery {
+            commission_rates: Some("0.001, 0, 0.0010".to_string()),
+            ..Default::default()
+        };
+        let grid = build_grid(&query, commission, slippage).unwrap();
+        assert_eq!(grid.commission_rates, vec![dec!(0), dec!(0.001)]);
+        assert_eq!(grid.slippage_rates.len(), 5);
+
+        let invalid = SensitivityQuery {
+            slippage_rates: Some("0.001,abc".to_string()),
+            ..Default::default()
+        };
+        assert!(build_grid(&invalid, commission, slippage).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_get_cost_sensitivity_validation() {
+        let app = Router::new()
+            .route("/{id}/sensitivity", get(get_cost_sensitivity))
+            .with_state(Arc::new(create_test_state()));
+
+        let response = app
+            .oneshot(
+                Request::get("/not-a-uuid/sensitivity")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let error: BacktestApiError = serde_json::from_slice(&body).unwrap();
+        assert_eq!(error.code, "INVALID_ID");
+    }
+}
@@ -7,7 +7,8 @@ use rust_decimal::prelude::FromStr;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use trader_core::{Side, Timeframe, TradeInfo};
+use trader_analytics::backtest::{DataCoverageReport, MarginConfig};
+use trader_core::{Side, Timeframe, TradeInfo, TradingCostModel};
 use ts_rs::TS;
 use validator::{Validate, ValidationError};
 
@@ -29,6 +30,22 @@ fn validate_initial_capital(value: &Decimal) -> Result<(), ValidationError> {
     Ok(())
 }
 
+/// 거래 비용 모델 검증 (요율 0 ~ 10%, 암호화폐 구간 오름차순)
+fn validate_cost_model(value: &TradingCostModel) -> Result<(), ValidationError> {
+    value.validate().map_err(|e| {
+        ValidationError::new("cost_model_invalid")
+            .with_message(format!("거래 비용 모델이 올바르지 않습니다: {}", e).into())
+    })
+}
+
+/// 마진 계좌 설정 검증 (증거금률 범위, 음수 이자율)
+fn validate_margin(value: &MarginConfig) -> Result<(), ValidationError> {
+    value.validate().map_err(|e| {
+        ValidationError::new("margin_invalid")
+            .with_message(format!("마진 설정이 올바르지 않습니다: {}", e).into())
+    })
+}
+
 /// 수수료율 검증 (0 ~ 0.1 = 10%)
 /// 참고: Option<Decimal> 필드에 사용 시 validator가 Some일 때만 호출하므로 &Decimal을 받음
 fn validate_commission_rate(value: &Decimal) -> Result<(), ValidationError> {
@@ -59,6 +76,15 @@ fn validate_slippage_rate(value: &Decimal) -> Result<(), ValidationError> {
     Ok(())
 }
 
+/// 타임프레임 검증 ("1m", "5m", "1h", "1d" 등)
+fn validate_timeframe(value: &str) -> Result<(), ValidationError> {
+    if value.parse::<Timeframe>().is_err() {
+        return Err(ValidationError::new("invalid_timeframe")
+            .with_message(format!("지원하지 않는 타임프레임입니다: {}", value).into()));
+    }
+    Ok(())
+}
+
 /// 날짜 형식 검증 (YYYY-MM-DD)
 fn validate_date_format(value: &str) -> Result<(), ValidationError> {
     if NaiveDate::parse_from_str(value, "%Y-%m-%d").is_err() {
@@ -439,10 +465,54 @@ pub struct BacktestRunRequest {
     /// 전략 파라미터 (선택)
     #[serde(default)]
     pub parameters: Option<serde_json::Value>,
+    /// 유휴 현금 금리 시리즈 (선택, 예: KR_CD_91D, US_TBILL_3M)
+    /// 지정 시 미투자 현금에 해당 금리로 일별 이자를 적립
+    #[serde(default)]
+    pub cash_yield_series: Option<String>,
+    /// 시장별 거래 비용 모델 (선택, 지정 시 commission_rate 대신 사용)
+    /// 예: {"type": "kr_stock"}, {"type": "us_stock"}, {"type": "crypto"}
+    #[serde(default)]
+    #[validate(custom(function = "validate_cost_model"))]
+    pub cost_model: Option<TradingCostModel>,
+    /// 마진 계좌 설정 (선택, 지정 시 공매도와 신용 매수 허용)
+    /// 예: {"initial_margin_rate": 0.5, "maintenance_margin_rate": 0.25, "short_borrow_rate": 0.03}
+    #[serde(default)]
+    #[validate(custom(function = "validate_margin"))]
+    pub margin: Option<MarginConfig>,
+    /// 레버리지 ETF 합성 모드 (선택, 미지정 시 us_3x_leverage/snow 전략만 사용)
+    /// 상장 이전 구간을 기초자산 일봉으로 합성 (예: QQQ로 TQQQ 합성)
+    #[serde(default)]
+    pub synthetic_leverage: Option<bool>,
     /// 다중 타임프레임 설정 (선택)
     /// 지정 시 secondary 타임프레임 데이터도 로드하여 전략에 전달
     #[serde(default)]
     pub multi_timeframe_config: Option<MultiTimeframeRequest>,
+    /// 캔들 타임프레임 (선택, 기본: "1d", 예: "1m", "5m")
+    /// 분봉 등 장중 타임프레임은 정규장 세션 안에서 다음 캔들 시가로 체결
+    #[serde(default)]
+    #[validate(custom(function = "validate_timeframe"))]
+    pub timeframe: Option<String>,
+    /// 장중 백테스트에서 세션 마감 시 미청산 포지션 청산 여부 (기본: false)
+    #[serde(default)]
+    pub flatten_at_close: bool,
+    /// 합성 데이터 허용 여부 (기본: false, 데모용)
+    /// false면 저장된 데이터가 없을 때 샘플 데이터로 대체하지 않고 실행을 거부
+    #[serde(default)]
+    pub allow_synthetic: bool,
+    /// 심볼별 최소 데이터 커버리지 (%, 선택, 기본: 90)
+    #[serde(default)]
+    #[validate(range(min = 0.0, max = 100.0, message = "최소 커버리지는 0-100%여야 합니다"))]
+    pub min_coverage_pct: Option<f64>,
+    /// 결과를 기록할 등록 전략 ID (선택, 미지정 시 전략 타입으로 기록)
+    #[serde(default)]
+    pub registered_strategy_id: Option<String>,
+    /// 결과 저장 여부 (기본: true, DB 연결 시 `backtest_results`에 저장)
+    #[serde(default = "default_persist")]
+    pub persist: bool,
+}
+
+fn default_persist() -> bool {
+    true
 }
 
 /// 다중 자산 백테스트 실행 요청
@@ -474,6 +544,38 @@ pub struct BacktestMultiRunRequest {
     /// 전략 파라미터 (선택)
     #[serde(default)]
     pub parameters: Option<serde_json::Value>,
+    /// 유휴 현금 금리 시리즈 (선택, 예: KR_CD_91D, US_TBILL_3M)
+    /// 지정 시 미투자 현금에 해당 금리로 일별 이자를 적립
+    #[serde(default)]
+    pub cash_yield_series: Option<String>,
+    /// 시장별 거래 비용 모델 (선택, 지정 시 commission_rate 대신 사용)
+    /// 예: {"type": "kr_stock"}, {"type": "us_stock"}, {"type": "crypto"}
+    #[serde(default)]
+    #[validate(custom(function = "validate_cost_model"))]
+    pub cost_model: Option<TradingCostModel>,
+    /// 마진 계좌 설정 (선택, 지정 시 공매도와 신용 매수 허용)
+    /// 예: {"initial_margin_rate": 0.5, "maintenance_margin_rate": 0.25, "short_borrow_rate": 0.03}
+    #[serde(default)]
+    #[validate(custom(function = "validate_margin"))]
+    pub margin: Option<MarginConfig>,
+    /// 레버리지 ETF 합성 모드 (선택, 미지정 시 us_3x_leverage/snow 전략만 사용)
+    /// 상장 이전 구간을 기초자산 일봉으로 합성 (예: QQQ로 TQQQ 합성)
+    #[serde(default)]
+    pub synthetic_leverage: Option<bool>,
+    /// 합성 데이터 허용 여부 (기본: false, 데모용)
+    /// false면 저장된 데이터가 없을 때 샘플 데이터로 대체하지 않고 실행을 거부
+    #[serde(default)]
+    pub allow_synthetic: bool,
+    /// 심볼별 최소 데이터 커버리지 (%, 선택, 기본: 90)
+    #[serde(default)]
+    #[validate(range(min = 0.0, max = 100.0, message = "최소 커버리지는 0-100%여야 합니다"))]
+    pub min_coverage_pct: Option<f64>,
+    /// 결과를 기록할 등록 전략 ID (선택, 미지정 시 전략 타입으로 기록)
+    #[serde(default)]
+    pub registered_strategy_id: Option<String>,
+    /// 결과 저장 여부 (기본: true, DB 연결 시 `backtest_results`에 저장)
+    #[serde(default = "default_persist")]
+    pub persist: bool,
 }
 
 /// 다중 자산 백테스트 실행 응답
@@ -501,6 +603,12 @@ pub struct BacktestMultiRunResponse {
     pub config_summary: BacktestConfigSummary,
     /// 심볼별 데이터 포인트 수
     pub data_points_by_symbol: HashMap<String, usize>,
+    /// 결과 저장 여부 (true면 `id`가 저장된 결과 ID)
+    #[serde(default)]
+    pub persisted: bool,
+    /// 실행 전 데이터 커버리지 리포트 (가용 구간, 갭, 출처, 수정주가 여부)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data_coverage: Option<DataCoverageReport>,
 }
 
 /// 백테스트 성과 지표 응답
@@ -631,6 +739,12 @@ pub struct BacktestRunResponse {
     pub trades: Vec<TradeHistoryItem>,
     /// 백테스트 설정 요약
     pub config_summary: BacktestConfigSummary,
+    /// 결과 저장 여부 (true면 `id`가 저장된 결과 ID)
+    #[serde(default)]
+    pub persisted: bool,
+    /// 실행 전 데이터 커버리지 리포트 (가용 구간, 갭, 출처, 수정주가 여부)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data_coverage: Option<DataCoverageReport>,
 }
 
 /// 백테스트 설정 요약
@@ -644,8 +758,20 @@ pub struct BacktestConfigSummary {
     pub slippage_rate: Decimal,
     /// 총 수수료
     pub total_commission: Decimal,
+    /// 총 세금 (증권거래세, 농특세, SEC fee)
+    #[serde(default)]
+    pub total_tax: Decimal,
     /// 총 슬리피지 비용
     pub total_slippage: Decimal,
+    /// 유휴 현금 이자 합계
+    #[serde(default)]
+    pub total_cash_interest: Decimal,
+    /// 차입 비용 합계 (대주 이자 + 신용 이자)
+    #[serde(default)]
+    pub total_borrow_cost: Decimal,
+    /// 유지 증거금 미달로 강제 청산된 포지션 수
+    #[serde(default)]
+    pub forced_liquidations: usize,
     /// 데이터 포인트 수
     pub data_points: usize,
 }
@@ -688,22 +814,31 @@ pub struct BatchBacktestItem {
 
 /// 배치 백테스트 요청.
 ///
-/// 여러 전략을 병렬로 실행합니다.
+/// 여러 전략을 병렬로 실행합니다. `strategies`와 `template_ids`를 합쳐 1-10개까지 실행할 수 있으며,
+/// 템플릿 항목은 템플릿에 저장된 기간/자본/비용 설정을 사용합니다.
 #[derive(Debug, Deserialize, Validate)]
 pub struct BatchBacktestRequest {
     /// 백테스트할 전략 목록 (최대 10개)
-    #[validate(length(min = 1, max = 10, message = "전략은 1-10개 사이여야 합니다"))]
+    #[serde(default)]
+    #[validate(length(max = 10, message = "전략은 1-10개 사이여야 합니다"))]
     #[validate(nested)]
     pub strategies: Vec<BatchBacktestItem>,
-    /// 시작 날짜 (YYYY-MM-DD)
+    /// 실행할 백테스트 템플릿 ID 목록 (최대 10개)
+    #[serde(default)]
+    #[validate(length(max = 10, message = "템플릿은 1-10개 사이여야 합니다"))]
+    pub template_ids: Vec<uuid::Uuid>,
+    /// 시작 날짜 (YYYY-MM-DD, `strategies` 실행 시 필수)
+    #[serde(default)]
     #[validate(custom(function = "validate_date_format"))]
-    pub start_date: String,
-    /// 종료 날짜 (YYYY-MM-DD)
+    pub start_date: Option<String>,
+    /// 종료 날짜 (YYYY-MM-DD, `strategies` 실행 시 필수)
+    #[serde(default)]
     #[validate(custom(function = "validate_date_format"))]
-    pub end_date: String,
-    /// 초기 자본금 (모든 전략에 동일하게 적용, 100 ~ 10억)
+    pub end_date: Option<String>,
+    /// 초기 자본금 (`strategies`에 동일하게 적용, 100 ~ 10억, `strategies` 실행 시 필수)
+    #[serde(default)]
     #[validate(custom(function = "validate_initial_capital"))]
-    pub initial_capital: Decimal,
+    pub initial_capital: Option<Decimal>,
     /// 수수료율 (선택, 기본: 0.001, 최대: 10%)
     #[serde(default)]
     #[validate(custom(function = "validate_commission_rate"))]
@@ -712,6 +847,10 @@ pub struct BatchBacktestRequest {
     #[serde(default)]
     #[validate(custom(function = "validate_slippage_rate"))]
     pub slippage_rate: Option<Decimal>,
+    /// 시장별 거래 비용 모델 (선택, `strategies`에 적용)
+    #[serde(default)]
+    #[validate(custom(function = "validate_cost_model"))]
+    pub cost_model: Option<TradingCostModel>,
     /// 병렬 실행 수 (선택, 기본: 4, 최대: 10)
     #[serde(default)]
     #[validate(range(min = 1, max = 10))]
@@ -723,6 +862,12 @@ pub struct BatchBacktestRequest {
 pub struct BatchBacktestResultItem {
     /// 전략 ID
     pub strategy_id: String,
+    /// 템플릿 ID (템플릿 실행 시)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template_id: Option<uuid::Uuid>,
+    /// 템플릿 이름 (템플릿 실행 시)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template_name: Option<String>,
     /// 성공 여부
     pub success: bool,
     /// 에러 메시지 (실패 시)
@@ -8,16 +8,19 @@
 //!
 //! # 엔드포인트
 //!
-//! - `GET /api/v1/backtest/results` - 저장된 결과 목록 조회
+//! - `GET /api/v1/backtest/results` - 저장된 결과 목록 조회 (전략/심볼/기간 필터, 지표 정렬)
 //! - `POST /api/v1/backtest/results` - 결과 저장
 //! - `GET /api/v1/backtest/results/{id}` - 단일 결과 조회
 //! - `DELETE /api/v1/backtest/results/{id}` - 결과 삭제
+//! - `GET /api/v1/backtest/results/{id}/equity` - 차트용 자산 곡선 조회 (다운샘플링)
+//! - `POST /api/v1/backtest/results/{id}/promote` - 라이브 전략으로 승격
+//! - `GET /api/v1/backtest/results/{id}/sensitivity` - 수수료/슬리피지 민감도 분석 (격자 재실행, 결과 저장)
 
 use axum::{
     extract::{Path, Query, State},
     http::StatusCode,
     response::IntoResponse,
-    routing::get,
+    routing::{get, post},
     Json, Router,
 };
 use chrono::NaiveDate;
@@ -27,8 +30,12 @@ use std::sync::Arc;
 use tracing::{debug, info, warn};
 use uuid::Uuid;
 
+use super::backtest::get_cost_sensitivity;
+use super::strategy_promotion::promote_backtest_result;
+use crate::auth::OptionalJwtAuth;
 use crate::repository::{
-    BacktestResultDto, BacktestResultInput, BacktestResultsRepository, ListResultsFilter,
+    downsample_equity_curve, BacktestResultDto, BacktestResultInput, BacktestResultsRepository,
+    ListResultsFilter, ResultsSortBy, EQUITY_PREVIEW_POINTS,
 };
 use crate::state::AppState;
 
@@ -65,6 +72,9 @@ pub struct SaveBacktestResultRequest {
     /// 백테스트에 사용된 타임프레임 설정 (다중 TF 백테스트 시)
     #[serde(default)]
     pub timeframes_used: Option<serde_json::Value>,
+    /// 백테스트에 사용된 전략 파라미터 (라이브 승격 시 전략 설정으로 사용)
+    #[serde(default)]
+    pub parameters: Option<serde_json::Value>,
 }
 
 /// 저장된 결과 응답 (Repository DTO 재사용).
@@ -79,6 +89,18 @@ pub struct ListResultsQuery {
     /// 전략 타입 필터
     #[serde(default)]
     pub strategy_type: Option<String>,
+    /// 심볼 필터
+    #[serde(default)]
+    pub symbol: Option<String>,
+    /// 실행일 시작 (YYYY-MM-DD, 포함)
+    #[serde(default)]
+    pub from: Option<NaiveDate>,
+    /// 실행일 종료 (YYYY-MM-DD, 포함)
+    #[serde(default)]
+    pub to: Option<NaiveDate>,
+    /// 정렬 기준 (created_at, total_return, sharpe_ratio, max_drawdown)
+    #[serde(default)]
+    pub sort_by: ResultsSortBy,
     /// 결과 수 제한
     #[serde(default = "default_limit")]
     pub limit: i64,
@@ -98,6 +120,34 @@ pub struct ListResultsResponse {
     pub total: i64,
 }
 
+/// 자산 곡선 조회 쿼리 파라미터.
+#[derive(Debug, Deserialize)]
+pub struct EquityCurveQuery {
+    /// 반환할 최대 점 수 (기본값: 500, 최소 10, 최대 10000)
+    #[serde(default = "default_equity_points")]
+    pub points: usize,
+    /// 다운샘플링 없이 전체 해상도 반환
+    #[serde(default)]
+    pub full: bool,
+}
+
+fn default_equity_points() -> usize {
+    EQUITY_PREVIEW_POINTS
+}
+
+/// 자산 곡선 응답.
+#[derive(Debug, Serialize)]
+pub struct EquityCurveResponse {
+    pub id: String,
+    /// 전체 해상도 점 수 (알 수 없으면 null)
+    pub total_points: Option<usize>,
+    /// 반환한 점 수
+    pub points: usize,
+    /// 다운샘플링 여부
+    pub downsampled: bool,
+    pub equity_curve: serde_json::Value,
+}
+
 /// 저장 성공 응답.
 #[derive(Debug, Serialize)]
 pub struct SaveResultResponse {
@@ -130,7 +180,10 @@ pub async fn list_backtest_results(
     };
 
     // Repository를 통해 조회
-    let filter = ListResultsFilter::new().with_pagination(query.limit, query.offset);
+    let filter = ListResultsFilter::new()
+        .with_pagination(query.limit, query.offset)
+        .with_created_range(query.from, query.to)
+        .with_sort_by(query.sort_by);
 
     let filter = match &query.strategy_id {
         Some(sid) => filter.with_strategy_id(sid),
@@ -142,6 +195,11 @@ pub async fn list_backtest_results(
         None => filter,
     };
 
+    let filter = match &query.symbol {
+        Some(symbol) => filter.with_symbol(symbol),
+        None => filter,
+    };
+
     match BacktestResultsRepository::list(pool, filter).await {
         Ok(response) => Json(ListResultsResponse {
             results: response.results,
@@ -167,6 +225,7 @@ pub async fn list_backtest_results(
 /// `POST /api/v1/backtest/results`
 pub async fn save_backtest_result(
     State(state): State<Arc<AppState>>,
+    auth: OptionalJwtAuth,
     Json(request): Json<SaveBacktestResultRequest>,
 ) -> impl IntoResponse {
     debug!("백테스트 결과 저장: strategy_id={}", request.strategy_id);
@@ -213,9 +272,25 @@ pub async fn save_backtest_result(
         }
     };
 
+    // 사용자별 저장 용량 할당량
+    let owner_id = auth.0.map(|claims| claims.sub);
+    if let Some(owner_id) = &owner_id {
+        if let Err(e) = state.quotas.check_storage_quota(owner_id).await {
+            warn!("백테스트 결과 저장 거부: user={}, {}", owner_id, e);
+            return (
+                e.status_code(),
+                Json(serde_json::json!({
+                    "error": "저장 용량 한도를 초과했습니다",
+                    "details": e.to_string()
+                })),
+            )
+                .into_response();
+        }
+    }
+
     // Repository Input 생성
     let input = BacktestResultInput {
-        strategy_id: request.strategy_id,
+        strategy_id: Some(request.strategy_id),
         strategy_type: request.strategy_type,
         symbol: request.symbol,
         start_date,
@@ -228,6 +303,8 @@ pub async fn save_backtest_result(
         trades: request.trades,
         success: request.success,
         timeframes_used: request.timeframes_used,
+        parameters: request.parameters,
+        owner_id,
     };
 
     match BacktestResultsRepository::save(pool, input).await {
@@ -382,6 +459,91 @@ pub async fn delete_backtest_result(
     }
 }
 
+/// 차트용 자산 곡선 조회.
+///
+/// 요청 점 수가 저장된 미리보기(500점) 이하이면 미리보기만 읽어 다운샘플링하고,
+/// 더 많은 점이나 `full=true`를 요청하면 전체 해상도 곡선을 읽습니다.
+///
+/// `GET /api/v1/backtest/results/{id}/equity?points=500`
+pub async fn get_backtest_equity_curve(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+    Query(query): Query<EquityCurveQuery>,
+) -> impl IntoResponse {
+    debug!("자산 곡선 조회: id={}, {:?}", id, query);
+
+    let pool = match &state.db_pool {
+        Some(p) => p,
+        None => {
+            return (
+                StatusCode::SERVICE_UNAVAILABLE,
+                Json(serde_json::json!({
+                    "error": "데이터베이스가 연결되지 않았습니다"
+                })),
+            )
+                .into_response();
+        }
+    };
+
+    let uuid = match Uuid::parse_str(&id) {
+        Ok(u) => u,
+        Err(_) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(serde_json::json!({
+                    "error": "유효하지 않은 ID 형식입니다"
+                })),
+            )
+                .into_response();
+        }
+    };
+
+    let points = query.points.clamp(10, 10_000);
+    let full = query.full || points > EQUITY_PREVIEW_POINTS;
+    match BacktestResultsRepository::get_equity_curve(pool, uuid, full).await {
+        Ok(Some(record)) => {
+            // 전체 해상도를 읽은 경우 점 수를 직접 셀 수 있음 (미리보기 도입 전 결과)
+            let loaded_points = record.equity_curve.as_array().map(Vec::len);
+            let total_points = record
+                .equity_curve_points
+                .map(|n| n as usize)
+                .or(loaded_points.filter(|_| full));
+            let equity_curve = if query.full {
+                record.equity_curve
+            } else {
+                downsample_equity_curve(&record.equity_curve, points)
+            };
+            let returned = equity_curve.as_array().map_or(0, Vec::len);
+            Json(EquityCurveResponse {
+                id,
+                total_points,
+                points: returned,
+                downsampled: total_points.is_some_and(|n| returned < n),
+                equity_curve,
+            })
+            .into_response()
+        }
+        Ok(None) => (
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({
+                "error": "결과를 찾을 수 없습니다"
+            })),
+        )
+            .into_response(),
+        Err(e) => {
+            warn!("자산 곡선 조회 실패: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({
+                    "error": "자산 곡선 조회 실패",
+                    "details": e.to_string()
+                })),
+            )
+                .into_response()
+        }
+    }
+}
+
 // ==================== 라우터 ====================
 
 /// 백테스트 결과 라우터 생성.
@@ -393,4 +555,10 @@ pub fn backtest_results_router() -> Router<Arc<AppState>> {
         .route("/", get(list_backtest_results).post(save_backtest_result))
         // 단일 결과 조회 + 삭제 (같은 경로에 GET/DELETE)
         .route("/{id}", get(get_backtest_result).delete(delete_backtest_result))
+        // 차트용 자산 곡선 (다운샘플링)
+        .route("/{id}/equity", get(get_backtest_equity_curve))
+        // 라이브 전략 승격 (데이터 커버리지/리스크 검사)
+        .route("/{id}/promote", post(promote_backtest_result))
+        // 수수료/슬리피지 민감도 분석 (격자 재실행)
+        .route("/{id}/sensitivity", get(get_cost_sensitivity))
 }
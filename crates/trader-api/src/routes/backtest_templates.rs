@@ -0,0 +1,578 @@
+//! 백테스트 템플릿 API.
+//!
+//! 전략, 파라미터, 유니버스, 기간, 비용을 이름 붙은 템플릿으로 저장하고
+//! 한 번의 호출로 재실행하는 기능을 제공합니다.
+//! 배치 백테스트(`POST /api/v1/backtest/run-batch`)의 `template_ids`로도 참조할 수 있습니다.
+//! 정기 실행 주기를 설정하면 스케줄러가 최신 데이터로 재실행하고 결과를 시계열로 저장합니다.
+//!
+//! # 엔드포인트
+//!
+//! - `GET /api/v1/backtest/templates` - 템플릿 목록
+//! - `POST /api/v1/backtest/templates` - 템플릿 생성
+//! - `GET /api/v1/backtest/templates/{id}` - 템플릿 조회
+//! - `PUT /api/v1/backtest/templates/{id}` - 템플릿 수정
+//! - `DELETE /api/v1/backtest/templates/{id}` - 템플릿 삭제
+//! - `POST /api/v1/backtest/templates/{id}/run` - 템플릿 실행
+//! - `PUT /api/v1/backtest/templates/{id}/schedule` - 정기 실행 주기 설정/해제
+//! - `GET /api/v1/backtest/templates/{id}/runs` - 정기 실행 결과 이력
+
+use axum::{
+    extract::{Path, Query, State},
+    http::{HeaderMap, StatusCode},
+    routing::{get, post, put},
+    Json, Router,
+};
+use chrono::{NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tracing::info;
+use trader_core::CashRateSeries;
+use trader_strategy::StrategyRegistry;
+use uuid::Uuid;
+
+use super::backtest::{
+    run_backtest, run_multi_backtest, BacktestApiError, BacktestMultiRunRequest,
+    BacktestMultiRunResponse, BacktestRunRequest, BacktestRunResponse,
+};
+use super::common::{db_conflict_response, db_error_response, require_pool};
+use crate::auth::OptionalJwtAuth;
+use crate::error::{ApiErrorResponse, ApiResult};
+use crate::repository::{
+    BacktestTemplateInput, BacktestTemplateRecord, BacktestTemplateRepository,
+    BacktestTemplateRunRecord,
+};
+use crate::state::AppState;
+
+/// 템플릿 심볼 최대 개수
+const MAX_TEMPLATE_SYMBOLS: usize = 50;
+
+/// 정기 실행 주기 최대값 (일)
+const MAX_SCHEDULE_INTERVAL_DAYS: i32 = 90;
+
+// ==================== 요청/응답 타입 ====================
+
+/// 템플릿 생성/수정 요청.
+#[derive(Debug, Deserialize)]
+pub struct BacktestTemplateRequest {
+    /// 템플릿 이름
+    pub name: String,
+    /// 설명
+    #[serde(default)]
+    pub description: Option<String>,
+    /// 전략 ID
+    pub strategy_id: String,
+    /// 심볼 목록 (1개면 단일 자산, 2개 이상이면 다중 자산)
+    pub symbols: Vec<String>,
+    /// 전략 파라미터
+    #[serde(default)]
+    pub parameters: Option<serde_json::Value>,
+    /// 시작 날짜 (YYYY-MM-DD)
+    pub start_date: NaiveDate,
+    /// 종료 날짜 (YYYY-MM-DD, 생략 시 실행일)
+    #[serde(default)]
+    pub end_date: Option<NaiveDate>,
+    /// 초기 자본금 (100 ~ 10억)
+    pub initial_capital: Decimal,
+    /// 수수료율 (생략 시 기본값 0.1%, 최대 10%)
+    #[serde(default)]
+    pub commission_rate: Option<Decimal>,
+    /// 슬리피지율 (생략 시 기본값 0.05%, 최대 5%)
+    #[serde(default)]
+    pub slippage_rate: Option<Decimal>,
+    /// 유휴 현금 금리 시리즈 (예: KR_CD_91D, US_TBILL_3M)
+    #[serde(default)]
+    pub cash_yield_series: Option<String>,
+}
+
+/// 템플릿 목록 조회 쿼리.
+#[derive(Debug, Deserialize)]
+pub struct ListTemplatesQuery {
+    /// 전략 ID 필터
+    pub strategy_id: Option<String>,
+}
+
+/// 템플릿 목록 응답.
+#[derive(Debug, Serialize)]
+pub struct BacktestTemplatesResponse {
+    pub total: usize,
+    pub templates: Vec<BacktestTemplateRecord>,
+}
+
+/// 정기 실행 주기 설정 요청.
+#[derive(Debug, Deserialize)]
+pub struct TemplateScheduleRequest {
+    /// 실행 주기 (일, 1 ~ 90, null이면 해제)
+    pub interval_days: Option<i32>,
+}
+
+/// 정기 실행 결과 조회 쿼리.
+#[derive(Debug, Deserialize)]
+pub struct ListRunsQuery {
+    /// 최대 개수 (기본 50, 최대 500)
+    pub limit: Option<i64>,
+}
+
+/// 정기 실행 결과 응답.
+#[derive(Debug, Serialize)]
+pub struct TemplateRunsResponse {
+    pub total: usize,
+    pub runs: Vec<BacktestTemplateRunRecord>,
+}
+
+/// 템플릿 실행 응답 (단일/다중 자산 백테스트 결과).
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+pub enum TemplateRunResponse {
+    Single(BacktestRunResponse),
+    Multi(BacktestMultiRunResponse),
+}
+
+// ==================== 헬퍼 ====================
+
+fn name_conflict_response(err: sqlx::Error) -> (StatusCode, Json<ApiErrorResponse>) {
+    db_conflict_response(err, "TEMPLATE_NAME_CONFLICT", "Backtest template name already exists")
+}
+
+fn not_found(id: Uuid) -> (StatusCode, Json<ApiErrorResponse>) {
+    (
+        StatusCode::NOT_FOUND,
+        Json(ApiErrorResponse::new(
+            "TEMPLATE_NOT_FOUND",
+            format!("Backtest template not found: {}", id),
+        )),
+    )
+}
+
+fn backtest_error_response(
+    (status, Json(err)): (StatusCode, Json<BacktestApiError>),
+) -> (StatusCode, Json<ApiErrorResponse>) {
+    (status, Json(ApiErrorResponse::new(err.code, err.message)))
+}
+
+/// 요청 검증 후 저장 입력으로 변환.
+#[allow(clippy::result_large_err)]
+fn validate_request(
+    request: BacktestTemplateRequest,
+) -> Result<BacktestTemplateInput, (StatusCode, Json<ApiErrorResponse>)> {
+    let invalid = |msg: &str| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(ApiErrorResponse::new("INVALID_TEMPLATE", msg)),
+        )
+    };
+
+    let name = request.name.trim().to_string();
+    if name.is_empty() || name.chars().count() > 100 {
+        return Err(invalid("name must be 1-100 characters"));
+    }
+
+    let strategy_id = request.strategy_id.trim().to_string();
+    if strategy_id.is_empty() {
+        return Err(invalid("strategy_id is required"));
+    }
+
+    let symbols: Vec<String> = request
+        .symbols
+        .into_iter()
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .collect();
+    if symbols.is_empty() || symbols.len() > MAX_TEMPLATE_SYMBOLS {
+        return Err(invalid("symbols must contain 1-50 entries"));
+    }
+
+    if request
+        .end_date
+        .is_some_and(|end| end <= request.start_date)
+    {
+        return Err(invalid("end_date must be after start_date"));
+    }
+
+    if request.initial_capital < Decimal::from(100)
+        || request.initial_capital > Decimal::from(1_000_000_000)
+    {
+        return Err(invalid(
+            "initial_capital must be between 100 and 1,000,000,000",
+        ));
+    }
+    if request
+        .commission_rate
+        .is_some_and(|r| r < Decimal::ZERO || r > Decimal::new(1, 1))
+    {
+        return Err(invalid("commission_rate must be between 0 and 0.1"));
+    }
+    if request
+        .slippage_rate
+        .is_some_and(|r| r < Decimal::ZERO || r > Decimal::new(5, 2))
+    {
+        return Err(invalid("slippage_rate must be between 0 and 0.05"));
+    }
+
+    let cash_yield_series = request
+        .cash_yield_series
+        .map(|s| s.trim().to_uppercase())
+        .filter(|s| !s.is_empty());
+    if let Some(series) = &cash_yield_series {
+        if series.parse::<CashRateSeries>().is_err() {
+            return Err(invalid("unknown cash_yield_series"));
+        }
+    }
+
+    Ok(BacktestTemplateInput {
+        name,
+        description: request
+            .description
+            .map(|d| d.trim().to_string())
+            .filter(|d| !d.is_empty()),
+        strategy_id,
+        symbols,
+        parameters: request.parameters,
+        start_date: request.start_date,
+        end_date: request.end_date,
+        initial_capital: request.initial_capital,
+        commission_rate: request.commission_rate,
+        slippage_rate: request.slippage_rate,
+        cash_yield_series,
+    })
+}
+
+/// 등록된 전략인지 확인.
+#[allow(clippy::result_large_err)]
+fn ensure_strategy_exists(strategy_id: &str) -> ApiResult<()> {
+    match StrategyRegistry::find(strategy_id) {
+        Some(_) => Ok(()),
+        None => Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiErrorResponse::new(
+                "STRATEGY_NOT_FOUND",
+                format!("Unknown strategy: {}", strategy_id),
+            )),
+        )),
+    }
+}
+
+// ==================== 핸들러 ====================
+
+/// 템플릿 목록 조회.
+///
+/// GET /api/v1/backtest/templates
+pub async fn list_templates(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<ListTemplatesQuery>,
+) -> ApiResult<Json<BacktestTemplatesResponse>> {
+    let pool = require_pool(&state)?;
+    let templates = BacktestTemplateRepository::list(pool, query.strategy_id.as_deref())
+        .await
+        .map_err(db_error_response)?;
+
+    Ok(Json(BacktestTemplatesResponse {
+        total: templates.len(),
+        templates,
+    }))
+}
+
+/// 템플릿 생성.
+///
+/// POST /api/v1/backtest/templates
+pub async fn create_template(
+    State(state): State<Arc<AppState>>,
+    Json(request): Json<BacktestTemplateRequest>,
+) -> ApiResult<(StatusCode, Json<BacktestTemplateRecord>)> {
+    let input = validate_request(request)?;
+    ensure_strategy_exists(&input.strategy_id)?;
+    let pool = require_pool(&state)?;
+
+    let record = BacktestTemplateRepository::create(pool, &input)
+        .await
+        .map_err(name_conflict_response)?;
+
+    info!(id = %record.id, name = %record.name, "백테스트 템플릿 생성");
+    Ok((StatusCode::CREATED, Json(record)))
+}
+
+/// 템플릿 조회.
+///
+/// GET /api/v1/backtest/templates/{id}
+pub async fn get_template(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Json<BacktestTemplateRecord>> {
+    let pool = require_pool(&state)?;
+    BacktestTemplateRepository::get(pool, id)
+        .await
+        .map_err(db_error_response)?
+        .map(Json)
+        .ok_or_else(|| not_found(id))
+}
+
+/// 템플릿 수정.
+///
+/// PUT /api/v1/backtest/templates/{id}
+pub async fn update_template(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+    Json(request): Json<BacktestTemplateRequest>,
+) -> ApiResult<Json<BacktestTemplateRecord>> {
+    let input = validate_request(request)?;
+    ensure_strategy_exists(&input.strategy_id)?;
+    let pool = require_pool(&state)?;
+
+    BacktestTemplateRepository::update(pool, id, &input)
+        .await
+        .map_err(name_conflict_response)?
+        .map(Json)
+        .ok_or_else(|| not_found(id))
+}
+
+/// 템플릿 삭제.
+///
+/// DELETE /api/v1/backtest/templates/{id}
+pub async fn delete_template(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<StatusCode> {
+    let pool = require_pool(&state)?;
+    if BacktestTemplateRepository::delete(pool, id)
+        .await
+        .map_err(db_error_response)?
+    {
+        info!(%id, "백테스트 템플릿 삭제");
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err(not_found(id))
+    }
+}
+
+/// 템플릿 실행.
+///
+/// 심볼이 1개면 단일 자산, 2개 이상이면 다중 자산 백테스트로 실행합니다.
+/// 종료일이 없는 템플릿은 실행일까지의 데이터를 사용합니다.
+///
+/// POST /api/v1/backtest/templates/{id}/run
+pub async fn run_template(
+    State(state): State<Arc<AppState>>,
+    auth: OptionalJwtAuth,
+    headers: HeaderMap,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Json<TemplateRunResponse>> {
+    let pool = require_pool(&state)?;
+    let template = BacktestTemplateRepository::get(pool, id)
+        .await
+        .map_err(db_error_response)?
+        .ok_or_else(|| not_found(id))?;
+
+    BacktestTemplateRepository::mark_run(pool, &[id])
+        .await
+        .map_err(db_error_response)?;
+
+    info!(%id, name = %template.name, "백테스트 템플릿 실행");
+
+    let end_date = template
+        .resolved_end_date(Utc::now().date_naive())
+        .format("%Y-%m-%d")
+        .to_string();
+    let start_date = template.start_date.format("%Y-%m-%d").to_string();
+
+    let response = if template.symbols.len() == 1 {
+        let request = BacktestRunRequest {
+            strategy_id: template.strategy_id,
+            symbol: template.symbols[0].clone(),
+            start_date,
+            end_date,
+            initial_capital: template.initial_capital,
+            commission_rate: template.commission_rate,
+            slippage_rate: template.slippage_rate,
+            parameters: template.parameters,
+            cash_yield_series: template.cash_yield_series,
+            cost_model: None,
+            margin: None,
+            synthetic_leverage: None,
+            multi_timeframe_config: None,
+            timeframe: None,
+            flatten_at_close: false,
+            allow_synthetic: false,
+            min_coverage_pct: None,
+            registered_strategy_id: None,
+            persist: true,
+        };
+        let Json(result) = run_backtest(State(state.clone()), auth, headers, Json(request))
+            .await
+            .map_err(backtest_error_response)?;
+        TemplateRunResponse::Single(result)
+    } else {
+        let request = BacktestMultiRunRequest {
+            strategy_id: template.strategy_id,
+            symbols: template.symbols,
+            start_date,
+            end_date,
+            initial_capital: template.initial_capital,
+            commission_rate: template.commission_rate,
+            slippage_rate: template.slippage_rate,
+            parameters: template.parameters,
+            cash_yield_series: template.cash_yield_series,
+            cost_model: None,
+            margin: None,
+            synthetic_leverage: None,
+            allow_synthetic: false,
+            min_coverage_pct: None,
+            registered_strategy_id: None,
+            persist: true,
+        };
+        let Json(result) = run_multi_backtest(State(state.clone()), auth, headers, Json(request))
+            .await
+            .map_err(backtest_error_response)?;
+        TemplateRunResponse::Multi(result)
+    };
+
+    Ok(Json(response))
+}
+
+/// 정기 실행 주기 설정.
+///
+/// 주기를 설정하면 다음 스케줄러 점검 시 바로 실행되어 비교 기준이 되는 첫 결과를 남깁니다.
+///
+/// PUT /api/v1/backtest/templates/{id}/schedule
+pub async fn set_template_schedule(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+    Json(request): Json<TemplateScheduleRequest>,
+) -> ApiResult<Json<BacktestTemplateRecord>> {
+    if request
+        .interval_days
+        .is_some_and(|days| !(1..=MAX_SCHEDULE_INTERVAL_DAYS).contains(&days))
+    {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiErrorResponse::new(
+                "INVALID_SCHEDULE",
+                "interval_days must be between 1 and 90",
+            )),
+        ));
+    }
+    let pool = require_pool(&state)?;
+
+    let record = BacktestTemplateRepository::set_schedule(pool, id, request.interval_days)
+        .await
+        .map_err(db_error_response)?
+        .ok_or_else(|| not_found(id))?;
+
+    info!(%id, interval_days = ?request.interval_days, "백테스트 템플릿 정기 실행 설정");
+    Ok(Json(record))
+}
+
+/// 정기 실행 결과 이력 조회 (최신순).
+///
+/// GET /api/v1/backtest/templates/{id}/runs
+pub async fn list_template_runs(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<ListRunsQuery>,
+) -> ApiResult<Json<TemplateRunsResponse>> {
+    let pool = require_pool(&state)?;
+    let limit = query.limit.unwrap_or(50).clamp(1, 500);
+
+    let runs = BacktestTemplateRepository::list_runs(pool, id, limit)
+        .await
+        .map_err(db_error_response)?;
+
+    Ok(Json(TemplateRunsResponse {
+        total: runs.len(),
+        runs,
+    }))
+}
+
+// ==================== 라우터 ====================
+
+/// 백테스트 템플릿 라우터 생성.
+pub fn backtest_templates_router() -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/", get(list_templates).post(create_template))
+        .route(
+            "/{id}",
+            get(get_template)
+                .put(update_template)
+                .delete(delete_template),
+        )
+        .route("/{id}/run", post(run_template))
+        .route("/{id}/schedule", put(set_template_schedule))
+        .route("/{id}/runs", get(list_template_runs))
+}
+
+// ==================== 테스트 ====================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::create_test_state;
+    use axum::body::Body;
+    use axum::http::Request;
+    use rust_decimal_macros::dec;
+    use tower::ServiceExt;
+
+    fn request(symbols: &[&str]) -> BacktestTemplateRequest {
+        BacktestTemplateRequest {
+            name: " 기본 비교 ".to_string(),
+            description: Some(" ".to_string()),
+            strategy_id: " rsi_mean_reversion ".to_string(),
+            symbols: symbols.iter().map(|s| s.to_string()).collect(),
+            parameters: None,
+            start_date: NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+            end_date: None,
+            initial_capital: dec!(10000000),
+            commission_rate: None,
+            slippage_rate: None,
+            cash_yield_series: Some("kr_cd_91d".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_validate_request() {
+        let input = validate_request(request(&["005930", " "])).unwrap();
+        assert_eq!(input.name, "기본 비교");
+        assert_eq!(input.description, None);
+        assert_eq!(input.strategy_id, "rsi_mean_reversion");
+        assert_eq!(input.symbols, vec!["005930".to_string()]);
+        assert_eq!(input.cash_yield_series.as_deref(), Some("KR_CD_91D"));
+
+        assert!(validate_request(request(&[])).is_err());
+
+        let mut invalid_dates = request(&["005930"]);
+        invalid_dates.end_date = NaiveDate::from_ymd_opt(2023, 12, 31);
+        let (status, _) = validate_request(invalid_dates).unwrap_err();
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        let mut invalid_series = request(&["005930"]);
+        invalid_series.cash_yield_series = Some("LIBOR".to_string());
+        assert!(validate_request(invalid_series).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_templates_require_db() {
+        let app = backtest_templates_router().with_state(Arc::new(create_test_state()));
+
+        let response = app
+            .oneshot(Request::builder().uri("/").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+    }
+
+    #[tokio::test]
+    async fn test_schedule_interval_validation() {
+        let app = backtest_templates_router().with_state(Arc::new(create_test_state()));
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("PUT")
+                    .uri(format!("/{}/schedule", Uuid::new_v4()))
+                    .header("content-type", "application/json")
+                    .body(Body::from(r#"{"interval_days": 365}"#))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+}
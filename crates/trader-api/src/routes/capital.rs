@@ -0,0 +1,517 @@
+//! 전략 자본 원장 endpoint.
+//!
+//! 전략별 예산 현황 조회 및 입출금/이체를 위한 REST API를 제공합니다.
+//! 원장은 주문 실행기의 RiskManager가 보유하며, 진입 신호는 이 예산에 맞게
+//! 축소되거나 거부됩니다.
+//!
+//! # 엔드포인트
+//!
+//! - `GET /api/v1/capital` - 전략별 자본 사용 현황
+//! - `PUT /api/v1/capital/policy` - 예산 초과 처리 방식 변경 (scale/reject)
+//! - `POST /api/v1/capital/transfer` - 전략 간 자본 이체
+//! - `GET /api/v1/capital/transfers` - 입출금/이체 이력 (DB 필요)
+//! - `GET /api/v1/capital/{strategy_id}` - 특정 전략 자본 현황
+//! - `POST /api/v1/capital/{strategy_id}/deposit` - 전략 자본 증액
+//! - `POST /api/v1/capital/{strategy_id}/withdraw` - 전략 자본 감액
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    routing::{get, post, put},
+    Json, Router,
+};
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tracing::info;
+use trader_risk::{
+    BudgetPolicy, CapitalError, CapitalLedger, CapitalSummary, CapitalTransferKind, StrategyCapital,
+};
+
+use super::common::{db_error_response, require_pool};
+use crate::error::{ApiErrorResponse, ApiResult};
+use crate::repository::{CapitalTransferRecord, StrategyCapitalRepository};
+use crate::state::AppState;
+
+// ==================== 요청/응답 타입 ====================
+
+/// 전략 자본 현황.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct StrategyCapitalDto {
+    /// 전략 ID
+    pub strategy_id: String,
+    /// 할당 자본
+    pub allocated: Decimal,
+    /// 사용 중 자본 (포지션 + 미체결 진입 주문)
+    pub in_use: Decimal,
+    /// 실현 손익 (서버 시작 이후)
+    pub realized_pnl: Decimal,
+    /// 자기자본 (할당 자본 + 실현 손익)
+    pub equity: Decimal,
+    /// 가용 자본
+    pub available: Decimal,
+    /// 사용률 (%)
+    pub utilization_pct: f64,
+    /// 마지막 변경 시각
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<&StrategyCapital> for StrategyCapitalDto {
+    fn from(account: &StrategyCapital) -> Self {
+        Self {
+            strategy_id: account.strategy_id.clone(),
+            allocated: account.allocated,
+            in_use: account.in_use,
+            realized_pnl: account.realized_pnl,
+            equity: account.equity(),
+            available: account.available(),
+            utilization_pct: account.utilization_pct(),
+            updated_at: account.updated_at,
+        }
+    }
+}
+
+/// 전략 자본 사용 현황 응답.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CapitalReportResponse {
+    /// 예산 초과 처리 방식
+    pub policy: BudgetPolicy,
+    /// 전체 요약
+    pub summary: CapitalSummary,
+    /// 전략별 현황
+    pub strategies: Vec<StrategyCapitalDto>,
+}
+
+impl From<&CapitalLedger> for CapitalReportResponse {
+    fn from(ledger: &CapitalLedger) -> Self {
+        Self {
+            policy: ledger.policy(),
+            summary: ledger.summary(),
+            strategies: ledger.accounts().into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// 입금/출금 요청.
+#[derive(Debug, Deserialize)]
+pub struct CapitalAmountRequest {
+    /// 금액 (양수)
+    pub amount: Decimal,
+    /// 메모
+    pub memo: Option<String>,
+}
+
+/// 전략 간 이체 요청.
+#[derive(Debug, Deserialize)]
+pub struct CapitalTransferRequest {
+    /// 출금 전략
+    pub from_strategy: String,
+    /// 입금 전략
+    pub to_strategy: String,
+    /// 금액 (양수)
+    pub amount: Decimal,
+    /// 메모
+    pub memo: Option<String>,
+}
+
+/// 전략 간 이체 응답.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CapitalTransferResponse {
+    /// 출금 전략 현황
+    pub from: StrategyCapitalDto,
+    /// 입금 전략 현황
+    pub to: StrategyCapitalDto,
+}
+
+/// 예산 정책 변경 요청.
+#[derive(Debug, Deserialize)]
+pub struct UpdatePolicyRequest {
+    /// 예산 초과 처리 방식
+    pub policy: BudgetPolicy,
+}
+
+/// 이력 조회 쿼리.
+#[derive(Debug, Deserialize)]
+pub struct TransfersQuery {
+    /// 전략 ID 필터
+    pub strategy_id: Option<String>,
+    /// 최대 건수 (기본값: 50)
+    #[serde(default = "default_transfers_limit")]
+    pub limit: i64,
+}
+
+fn default_transfers_limit() -> i64 {
+    50
+}
+
+/// 이력 조회 응답.
+#[derive(Debug, Serialize)]
+pub struct TransfersResponse {
+    /// 조회된 건수
+    pub total: usize,
+    /// 이력 (최신순)
+    pub transfers: Vec<CapitalTransferRecord>,
+}
+
+// ==================== 헬퍼 ====================
+
+fn capital_error_response(err: CapitalError) -> (StatusCode, Json<ApiErrorResponse>) {
+    let (status, code) = match &err {
+        CapitalError::AccountNotFound(_) => (StatusCode::NOT_FOUND, "CAPITAL_ACCOUNT_NOT_FOUND"),
+        CapitalError::InvalidAmount(_) => (StatusCode::BAD_REQUEST, "INVALID_AMOUNT"),
+        CapitalError::InsufficientCapital { .. } => (StatusCode::CONFLICT, "INSUFFICIENT_CAPITAL"),
+        CapitalError::SameAccount(_) => (StatusCode::BAD_REQUEST, "SAME_ACCOUNT"),
+    };
+    (status, Json(ApiErrorResponse::new(code, err.to_string())))
+}
+
+fn account_dto(
+    ledger: &CapitalLedger,
+    strategy_id: &str,
+) -> Result<StrategyCapitalDto, CapitalError> {
+    ledger
+        .get(strategy_id)
+        .map(Into::into)
+        .ok_or_else(|| CapitalError::AccountNotFound(strategy_id.to_string()))
+}
+
+/// 원장 변경을 DB에 기록한 뒤 실행기 원장에 반영.
+///
+/// 원장 사본에서 먼저 검증하므로 DB 기록이 실패하면 실행기 원장은 변경되지 않습니다.
+/// DB가 연결되지 않은 경우 실행기 원장에만 반영됩니다.
+async fn apply_capital_change<F>(
+    state: &AppState,
+    kind: CapitalTransferKind,
+    from_strategy: Option<&str>,
+    to_strategy: Option<&str>,
+    amount: Decimal,
+    memo: Option<&str>,
+    change: F,
+) -> ApiResult<CapitalLedger>
+where
+    F: FnOnce(&mut CapitalLedger) -> Result<(), CapitalError>,
+{
+    let executor = state.executor.read().await;
+    let mut risk_manager = executor.risk_manager().write().await;
+
+    let mut next = risk_manager.capital_ledger().clone();
+    change(&mut next).map_err(capital_error_response)?;
+
+    if let Some(pool) = state.db_pool.as_ref() {
+        StrategyCapitalRepository::apply_transfer(
+            pool,
+            kind,
+            from_strategy,
+            to_strategy,
+            amount,
+            memo,
+        )
+        .await
+        .map_err(|e| match e {
+            // 대상 전략 없음
+            sqlx::Error::RowNotFound => (
+                StatusCode::NOT_FOUND,
+                Json(ApiErrorResponse::new(
+                    "STRATEGY_NOT_FOUND",
+                    "Strategy not found",
+                )),
+            ),
+            e => db_error_response(e),
+        })?;
+    }
+
+    *risk_manager.capital_ledger_mut() = next.clone();
+
+    info!(
+        kind = kind.as_str(),
+        from = ?from_strategy,
+        to = ?to_strategy,
+        %amount,
+        "전략 자본 변경"
+    );
+
+    Ok(next)
+}
+
+// ==================== 핸들러 ====================
+
+/// 전략별 자본 사용 현황 조회.
+///
+/// GET /api/v1/capital
+pub async fn get_capital_report(State(state): State<Arc<AppState>>) -> Json<CapitalReportResponse> {
+    let executor = state.executor.read().await;
+    let risk_manager = executor.risk_manager().read().await;
+
+    Json(CapitalReportResponse::from(risk_manager.capital_ledger()))
+}
+
+/// 특정 전략 자본 현황 조회.
+///
+/// GET /api/v1/capital/{strategy_id}
+pub async fn get_strategy_capital(
+    State(state): State<Arc<AppState>>,
+    Path(strategy_id): Path<String>,
+) -> ApiResult<Json<StrategyCapitalDto>> {
+    let executor = state.executor.read().await;
+    let risk_manager = executor.risk_manager().read().await;
+
+    account_dto(risk_manager.capital_ledger(), &strategy_id)
+        .map(Json)
+        .map_err(capital_error_response)
+}
+
+/// 예산 초과 처리 방식 변경 (서버 재시작 시 기본값 `scale`로 초기화).
+///
+/// PUT /api/v1/capital/policy
+pub async fn update_budget_policy(
+    State(state): State<Arc<AppState>>,
+    Json(request): Json<UpdatePolicyRequest>,
+) -> Json<CapitalReportResponse> {
+    let executor = state.executor.read().await;
+    let mut risk_manager = executor.risk_manager().write().await;
+    risk_manager.capital_ledger_mut().set_policy(request.policy);
+
+    Json(CapitalReportResponse::from(risk_manager.capital_ledger()))
+}
+
+/// 전략 자본 증액.
+///
+/// POST /api/v1/capital/{strategy_id}/deposit
+pub async fn deposit_capital(
+    State(state): State<Arc<AppState>>,
+    Path(strategy_id): Path<String>,
+    Json(request): Json<CapitalAmountRequest>,
+) -> ApiResult<Json<StrategyCapitalDto>> {
+    let ledger = apply_capital_change(
+        &state,
+        CapitalTransferKind::Deposit,
+        None,
+        Some(&strategy_id),
+        request.amount,
+        request.memo.as_deref(),
+        |ledger| {
+            ledger
+                .deposit(&strategy_id, request.amount, request.memo.clone())
+                .map(|_| ())
+        },
+    )
+    .await?;
+
+    account_dto(&ledger, &strategy_id)
+        .map(Json)
+        .map_err(capital_error_response)
+}
+
+/// 전략 자본 감액 (사용 중 자본은 출금 불가).
+///
+/// POST /api/v1/capital/{strategy_id}/withdraw
+pub async fn withdraw_capital(
+    State(state): State<Arc<AppState>>,
+    Path(strategy_id): Path<String>,
+    Json(request): Json<CapitalAmountRequest>,
+) -> ApiResult<Json<StrategyCapitalDto>> {
+    let ledger = apply_capital_change(
+        &state,
+        CapitalTransferKind::Withdraw,
+        Some(&strategy_id),
+        None,
+        request.amount,
+        request.memo.as_deref(),
+        |ledger| {
+            ledger
+                .withdraw(&strategy_id, request.amount, request.memo.clone())
+                .map(|_| ())
+        },
+    )
+    .await?;
+
+    account_dto(&ledger, &strategy_id)
+        .map(Json)
+        .map_err(capital_error_response)
+}
+
+/// 전략 간 자본 이체.
+///
+/// POST /api/v1/capital/transfer
+pub async fn transfer_capital(
+    State(state): State<Arc<AppState>>,
+    Json(request): Json<CapitalTransferRequest>,
+) -> ApiResult<Json<CapitalTransferResponse>> {
+    let ledger = apply_capital_change(
+        &state,
+        CapitalTransferKind::Transfer,
+        Some(&request.from_strategy),
+        Some(&request.to_strategy),
+        request.amount,
+        request.memo.as_deref(),
+        |ledger| {
+            ledger.transfer(
+                &request.from_strategy,
+                &request.to_strategy,
+                request.amount,
+                request.memo.clone(),
+            )
+        },
+    )
+    .await?;
+
+    Ok(Json(CapitalTransferResponse {
+        from: account_dto(&ledger, &request.from_strategy).map_err(capital_error_response)?,
+        to: account_dto(&ledger, &request.to_strategy).map_err(capital_error_response)?,
+    }))
+}
+
+/// 입출금/이체 이력 조회.
+///
+/// GET /api/v1/capital/transfers
+pub async fn list_capital_transfers(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<TransfersQuery>,
+) -> ApiResult<Json<TransfersResponse>> {
+    let pool = require_pool(&state)?;
+
+    let transfers = StrategyCapitalRepository::list_transfers(
+        pool,
+        query.strategy_id.as_deref(),
+        query.limit.clamp(1, 500),
+    )
+    .await
+    .map_err(db_error_response)?;
+
+    Ok(Json(TransfersResponse {
+        total: transfers.len(),
+        transfers,
+    }))
+}
+
+// ==================== 라우터 ====================
+
+/// 전략 자본 라우터 생성.
+pub fn capital_router() -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/", get(get_capital_report))
+        .route("/policy", put(update_budget_policy))
+        .route("/transfer", post(transfer_capital))
+        .route("/transfers", get(list_capital_transfers))
+        .route("/{strategy_id}", get(get_strategy_capital))
+        .route("/{strategy_id}/deposit", post(deposit_capital))
+        .route("/{strategy_id}/withdraw", post(withdraw_capital))
+}
+
+// ==================== 테스트 ====================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::create_test_state;
+    use axum::body::Body;
+    use axum::http::Request;
+    use rust_decimal_macros::dec;
+    use tower::ServiceExt;
+
+    fn post_json(uri: &str, body: serde_json::Value) -> Request<Body> {
+        Request::builder()
+            .method("POST")
+            .uri(uri)
+            .header("content-type", "application/json")
+            .body(Body::from(body.to_string()))
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_deposit_withdraw_and_report() {
+        let state = Arc::new(create_test_state());
+        let app = capital_router().with_state(state.clone());
+
+        let response = app
+            .clone()
+            .oneshot(post_json(
+                "/rsi/deposit",
+                serde_json::json!({ "amount": 1000000, "memo": "초기 배정" }),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        // 배정된 자본이 사용 중이면 그만큼 출금할 수 없음
+        state
+            .executor
+            .read()
+            .await
+            .risk_manager()
+            .write()
+            .await
+            .capital_ledger_mut()
+            .reserve("rsi", dec!(700000));
+
+        let response = app
+            .clone()
+            .oneshot(post_json(
+                "/rsi/withdraw",
+                serde_json::json!({ "amount": 500000 }),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+
+        let response = app
+            .clone()
+            .oneshot(Request::builder().uri("/").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let report: CapitalReportResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(report.policy, BudgetPolicy::Scale);
+        assert_eq!(report.strategies.len(), 1);
+        assert_eq!(report.strategies[0].allocated, dec!(1000000));
+        assert_eq!(report.strategies[0].available, dec!(300000));
+        assert!((report.strategies[0].utilization_pct - 70.0).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn test_transfer_between_strategies() {
+        let state = Arc::new(create_test_state());
+        let app = capital_router().with_state(state);
+
+        app.clone()
+            .oneshot(post_json(
+                "/a/deposit",
+                serde_json::json!({ "amount": 1000 }),
+            ))
+            .await
+            .unwrap();
+
+        let response = app
+            .clone()
+            .oneshot(post_json(
+                "/transfer",
+                serde_json::json!({ "from_strategy": "a", "to_strategy": "b", "amount": 400 }),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let result: CapitalTransferResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(result.from.allocated, dec!(600));
+        assert_eq!(result.to.allocated, dec!(400));
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/missing")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+}
@@ -0,0 +1,48 @@
+//! 라우트 공용 헬퍼.
+
+use axum::{http::StatusCode, Json};
+use sqlx::PgPool;
+
+use crate::error::{ApiErrorResponse, ApiResult};
+use crate::state::AppState;
+
+/// DB 미연결 응답 (503).
+pub(crate) fn db_unavailable() -> (StatusCode, Json<ApiErrorResponse>) {
+    (
+        StatusCode::SERVICE_UNAVAILABLE,
+        Json(ApiErrorResponse::new(
+            "DATABASE_ERROR",
+            "Database not available",
+        )),
+    )
+}
+
+/// DB 연결 풀 조회. 미연결이면 503.
+#[allow(clippy::result_large_err)]
+pub(crate) fn require_pool(state: &AppState) -> ApiResult<&PgPool> {
+    state.db_pool.as_ref().ok_or_else(db_unavailable)
+}
+
+/// DB 쿼리 오류 응답 (500).
+pub(crate) fn db_error_response(err: sqlx::Error) -> (StatusCode, Json<ApiErrorResponse>) {
+    tracing::error!("Database query failed: {}", err);
+    (
+        StatusCode::INTERNAL_SERVER_ERROR,
+        Json(ApiErrorResponse::new("DB_ERROR", err.to_string())),
+    )
+}
+
+/// 이름 중복(unique 제약 위반)은 409, 그 외는 [`db_error_response`].
+pub(crate) fn db_conflict_response(
+    err: sqlx::Error,
+    code: &str,
+    message: &str,
+) -> (StatusCode, Json<ApiErrorResponse>) {
+    match &err {
+        sqlx::Error::Database(db) if db.is_unique_violation() => (
+            StatusCode::CONFLICT,
+            Json(ApiErrorResponse::new(code, message)),
+        ),
+        _ => db_error_response(err),
+    }
+}
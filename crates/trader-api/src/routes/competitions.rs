@@ -0,0 +1,557 @@
+//! 전략 경쟁 (모의투자 리더보드) endpoint.
+//!
+//! 여러 전략을 같은 실시간 시장 데이터로 모의 운용하는 경쟁을 관리하고,
+//! 참가자 평가액 시계열로 계산한 위험 조정 수익률 리더보드를 제공합니다.
+//! 모의 운용과 평가액 기록, 종료 시 자동 승격은 `services::competition_runner`가 처리합니다.
+//!
+//! # 엔드포인트
+//!
+//! - `GET /api/v1/competitions` - 경쟁 목록
+//! - `POST /api/v1/competitions` - 경쟁 생성 (참가 전략 포함, 즉시 시작)
+//! - `GET /api/v1/competitions/{id}` - 경쟁 조회 (참가 전략, 실시간 가상 계좌)
+//! - `DELETE /api/v1/competitions/{id}` - 경쟁 삭제 (평가액 기록 포함)
+//! - `POST /api/v1/competitions/{id}/finish` - 경쟁 종료 (자동 승격 설정 시 1위 승격)
+//! - `GET /api/v1/competitions/{id}/leaderboard` - 구간별 리더보드
+//! - `POST /api/v1/competitions/{id}/entries/{entry_id}/promote` - 참가 전략 수동 승격
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    routing::{get, post},
+    Json, Router,
+};
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use std::collections::HashSet;
+use std::sync::Arc;
+use tracing::info;
+use trader_strategy::{CompetitorSnapshot, StrategyRegistry};
+use uuid::Uuid;
+
+use super::common::{db_conflict_response, db_error_response, require_pool};
+use crate::error::{ApiErrorResponse, ApiResult};
+use crate::repository::{
+    CompetitionEntryInput, CompetitionEntryRecord, CompetitionInput, CompetitionRecord,
+    CompetitionRepository,
+};
+use crate::services::competition_runner::{
+    attach_competition, build_leaderboard, detach_competition, finish_competition, promote_entry,
+    LeaderboardEntry,
+};
+use crate::state::AppState;
+
+/// 경쟁 하나의 최소/최대 참가 전략 수.
+const MIN_ENTRIES: usize = 2;
+const MAX_ENTRIES: usize = 20;
+
+// ==================== 요청/응답 타입 ====================
+
+/// 참가 전략.
+#[derive(Debug, Deserialize)]
+pub struct CompetitionEntryRequest {
+    /// 참가자 이름 (경쟁 내 고유)
+    pub name: String,
+    /// 전략 타입 (`StrategyRegistry` ID 또는 별칭)
+    pub strategy_type: String,
+    /// 전략 설정
+    #[serde(default = "default_config")]
+    pub config: serde_json::Value,
+}
+
+fn default_config() -> serde_json::Value {
+    serde_json::json!({})
+}
+
+/// 경쟁 생성 요청.
+#[derive(Debug, Deserialize)]
+pub struct CreateCompetitionRequest {
+    /// 경쟁 이름 (고유)
+    pub name: String,
+    pub description: Option<String>,
+    /// 참가자별 초기 자본
+    pub initial_capital: Decimal,
+    /// 진입 1회당 평가액 대비 투자 비율 (기본값: 0.2)
+    #[serde(default = "default_position_size_pct")]
+    pub position_size_pct: Decimal,
+    /// 체결 수수료 (bp, 기본값: 5)
+    #[serde(default = "default_fee_bps")]
+    pub fee_bps: Decimal,
+    /// 종료 시각 (생략하면 수동 종료)
+    pub ends_at: Option<DateTime<Utc>>,
+    /// 종료 시 리더보드 1위 자동 승격 (기본값: false)
+    #[serde(default)]
+    pub auto_promote: bool,
+    /// 승격 판정 구간 (일, 생략하면 전체 기간)
+    pub promotion_horizon_days: Option<i32>,
+    /// 참가 전략
+    pub entries: Vec<CompetitionEntryRequest>,
+}
+
+fn default_position_size_pct() -> Decimal {
+    Decimal::new(2, 1)
+}
+
+fn default_fee_bps() -> Decimal {
+    Decimal::from(5)
+}
+
+/// 리더보드 조회 쿼리.
+#[derive(Debug, Deserialize)]
+pub struct LeaderboardQuery {
+    /// 순위 산정 구간 (일, 생략하면 전체 기간)
+    pub horizon_days: Option<i64>,
+}
+
+/// 경쟁 목록 응답.
+#[derive(Debug, Serialize)]
+pub struct CompetitionsListResponse {
+    pub total: usize,
+    /// 경쟁 (최신순)
+    pub competitions: Vec<CompetitionRecord>,
+}
+
+/// 경쟁 상세 응답.
+#[derive(Debug, Serialize)]
+pub struct CompetitionDetailResponse {
+    pub competition: CompetitionRecord,
+    pub entries: Vec<CompetitionEntryRecord>,
+    /// 엔진에서 운용 중인 참가자 가상 계좌 (진행 중인 경쟁만)
+    pub live: Vec<CompetitorSnapshot>,
+}
+
+/// 리더보드 응답.
+#[derive(Debug, Serialize)]
+pub struct LeaderboardResponse {
+    pub competition_id: Uuid,
+    /// 순위 산정 구간 (일, None = 전체 기간)
+    pub horizon_days: Option<i64>,
+    /// 순위 (샤프 비율 내림차순, 표본 부족 참가자는 맨 뒤)
+    pub entries: Vec<LeaderboardEntry>,
+}
+
+/// 승격/종료 응답.
+#[derive(Debug, Serialize)]
+pub struct PromotionResponse {
+    pub competition_id: Uuid,
+    /// 승격된 실거래 전략 ID (중지 상태로 등록, 승격 없으면 None)
+    pub promoted_strategy_id: Option<String>,
+}
+
+// ==================== 헬퍼 ====================
+
+fn name_conflict_response(err: sqlx::Error) -> (StatusCode, Json<ApiErrorResponse>) {
+    db_conflict_response(err, "COMPETITION_NAME_CONFLICT", "Competition name already exists")
+}
+
+fn not_found(id: Uuid) -> (StatusCode, Json<ApiErrorResponse>) {
+    (
+        StatusCode::NOT_FOUND,
+        Json(ApiErrorResponse::new(
+            "COMPETITION_NOT_FOUND",
+            format!("Competition not found: {}", id),
+        )),
+    )
+}
+
+async fn load_competition(pool: &PgPool, id: Uuid) -> ApiResult<CompetitionRecord> {
+    CompetitionRepository::get(pool, id)
+        .await
+        .map_err(db_error_response)?
+        .ok_or_else(|| not_found(id))
+}
+
+/// 요청 검증 후 저장 입력으로 변환.
+#[allow(clippy::result_large_err)]
+fn validate_request(
+    request: CreateCompetitionRequest,
+    now: DateTime<Utc>,
+) -> Result<(CompetitionInput, Vec<CompetitionEntryInput>), (StatusCode, Json<ApiErrorResponse>)> {
+    let invalid = |msg: String| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(ApiErrorResponse::new("INVALID_COMPETITION", msg)),
+        )
+    };
+
+    let name = request.name.trim().to_string();
+    if name.is_empty() {
+        return Err(invalid("name is required".to_string()));
+    }
+    if request.initial_capital <= Decimal::ZERO {
+        return Err(invalid("initial_capital must be positive".to_string()));
+    }
+    if request.position_size_pct <= Decimal::ZERO || request.position_size_pct > Decimal::ONE {
+        return Err(invalid("position_size_pct must be in (0, 1]".to_string()));
+    }
+    if request.fee_bps < Decimal::ZERO {
+        return Err(invalid("fee_bps must not be negative".to_string()));
+    }
+    if request.ends_at.is_some_and(|ends_at| ends_at <= now) {
+        return Err(invalid("ends_at must be in the future".to_string()));
+    }
+    if request.promotion_horizon_days.is_some_and(|days| days <= 0) {
+        return Err(invalid(
+            "promotion_horizon_days must be positive".to_string(),
+        ));
+    }
+
+    if request.entries.len() < MIN_ENTRIES || request.entries.len() > MAX_ENTRIES {
+        return Err(invalid(format!(
+            "{} to {} entries are required",
+            MIN_ENTRIES, MAX_ENTRIES
+        )));
+    }
+    let mut seen = HashSet::new();
+    let mut entries = Vec::with_capacity(request.entries.len());
+    for entry in request.entries {
+        let entry_name = entry.name.trim().to_string();
+        if entry_name.is_empty() {
+            return Err(invalid("entry name is required".to_string()));
+        }
+        if !seen.insert(entry_name.clone()) {
+            return Err(invalid(format!("duplicate entry: {}", entry_name)));
+        }
+        let meta = StrategyRegistry::find(entry.strategy_type.trim())
+            .ok_or_else(|| invalid(format!("Unknown strategy type: {}", entry.strategy_type)))?;
+        if !entry.config.is_object() {
+            return Err(invalid(format!(
+                "config for {} must be an object",
+                entry_name
+            )));
+        }
+        entries.push(CompetitionEntryInput {
+            name: entry_name,
+            strategy_type: meta.id.to_string(),
+            config: entry.config,
+        });
+    }
+
+    Ok((
+        CompetitionInput {
+            name,
+            description: request.description.filter(|d| !d.trim().is_empty()),
+            initial_capital: request.initial_capital,
+            position_size_pct: request.position_size_pct,
+            fee_bps: request.fee_bps,
+            ends_at: request.ends_at,
+            auto_promote: request.auto_promote,
+            promotion_horizon_days: request.promotion_horizon_days,
+        },
+        entries,
+    ))
+}
+
+// ==================== 핸들러 ====================
+
+/// 경쟁 목록 조회.
+///
+/// GET /api/v1/competitions
+pub async fn list_competitions(
+    State(state): State<Arc<AppState>>,
+) -> ApiResult<Json<CompetitionsListResponse>> {
+    let pool = require_pool(&state)?;
+    let competitions = CompetitionRepository::list(pool)
+        .await
+        .map_err(db_error_response)?;
+
+    Ok(Json(CompetitionsListResponse {
+        total: competitions.len(),
+        competitions,
+    }))
+}
+
+/// 경쟁 생성 및 시작.
+///
+/// POST /api/v1/competitions
+pub async fn create_competition(
+    State(state): State<Arc<AppState>>,
+    Json(request): Json<CreateCompetitionRequest>,
+) -> ApiResult<(StatusCode, Json<CompetitionDetailResponse>)> {
+    let (input, entries) = validate_request(request, Utc::now())?;
+    let pool = require_pool(&state)?;
+
+    let (competition, entries) = CompetitionRepository::create(pool, &input, &entries)
+        .await
+        .map_err(name_conflict_response)?;
+    attach_competition(&state, pool, &competition, &entries)
+        .await
+        .map_err(db_error_response)?;
+
+    info!(
+        id = %competition.id,
+        name = %competition.name,
+        entries = entries.len(),
+        "전략 경쟁 시작"
+    );
+
+    let live = state
+        .strategy_engine
+        .read()
+        .await
+        .competitor_snapshots(Some(&competition.id.to_string()))
+        .await;
+    Ok((
+        StatusCode::CREATED,
+        Json(CompetitionDetailResponse {
+            competition,
+            entries,
+            live,
+        }),
+    ))
+}
+
+/// 경쟁 조회.
+///
+/// GET /api/v1/competitions/{id}
+pub async fn get_competition(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Json<CompetitionDetailResponse>> {
+    let pool = require_pool(&state)?;
+    let competition = load_competition(pool, id).await?;
+    let entries = CompetitionRepository::list_entries(pool, id)
+        .await
+        .map_err(db_error_response)?;
+    let live = state
+        .strategy_engine
+        .read()
+        .await
+        .competitor_snapshots(Some(&id.to_string()))
+        .await;
+
+    Ok(Json(CompetitionDetailResponse {
+        competition,
+        entries,
+        live,
+    }))
+}
+
+/// 경쟁 삭제.
+///
+/// DELETE /api/v1/competitions/{id}
+pub async fn delete_competition(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<StatusCode> {
+    let pool = require_pool(&state)?;
+    let entries = CompetitionRepository::list_entries(pool, id)
+        .await
+        .map_err(db_error_response)?;
+    detach_competition(&state, &entries).await;
+
+    if CompetitionRepository::delete(pool, id)
+        .await
+        .map_err(db_error_response)?
+    {
+        info!(%id, "전략 경쟁 삭제");
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err(not_found(id))
+    }
+}
+
+/// 경쟁 종료.
+///
+/// POST /api/v1/competitions/{id}/finish
+pub async fn finish_competition_handler(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Json<PromotionResponse>> {
+    let pool = require_pool(&state)?;
+    let competition = load_competition(pool, id).await?;
+    if !competition.is_running() {
+        return Err((
+            StatusCode::CONFLICT,
+            Json(ApiErrorResponse::new(
+                "COMPETITION_FINISHED",
+                format!("Competition already finished: {}", id),
+            )),
+        ));
+    }
+
+    let promoted_strategy_id = finish_competition(&state, pool, &competition)
+        .await
+        .map_err(db_error_response)?;
+
+    Ok(Json(PromotionResponse {
+        competition_id: id,
+        promoted_strategy_id,
+    }))
+}
+
+/// 구간별 리더보드 조회.
+///
+/// GET /api/v1/competitions/{id}/leaderboard
+pub async fn get_leaderboard(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<LeaderboardQuery>,
+) -> ApiResult<Json<LeaderboardResponse>> {
+    let horizon_days = query.horizon_days.filter(|days| *days > 0);
+    let pool = require_pool(&state)?;
+    let competition = load_competition(pool, id).await?;
+
+    let entries = build_leaderboard(pool, &competition, horizon_days)
+        .await
+        .map_err(db_error_response)?;
+
+    Ok(Json(LeaderboardResponse {
+        competition_id: id,
+        horizon_days,
+        entries,
+    }))
+}
+
+/// 참가 전략 수동 승격.
+///
+/// POST /api/v1/competitions/{id}/entries/{entry_id}/promote
+pub async fn promote_competition_entry(
+    State(state): State<Arc<AppState>>,
+    Path((id, entry_id)): Path<(Uuid, Uuid)>,
+) -> ApiResult<Json<PromotionResponse>> {
+    let pool = require_pool(&state)?;
+    let competition = load_competition(pool, id).await?;
+    let entry = CompetitionRepository::list_entries(pool, id)
+        .await
+        .map_err(db_error_response)?
+        .into_iter()
+        .find(|e| e.id == entry_id)
+        .ok_or_else(|| {
+            (
+                StatusCode::NOT_FOUND,
+                Json(ApiErrorResponse::new(
+                    "COMPETITION_ENTRY_NOT_FOUND",
+                    format!("Competition entry not found: {}", entry_id),
+                )),
+            )
+        })?;
+
+    if let Some(strategy_id) = &entry.promoted_strategy_id {
+        return Err((
+            StatusCode::CONFLICT,
+            Json(ApiErrorResponse::new(
+                "ALREADY_PROMOTED",
+                format!("Entry already promoted to {}", strategy_id),
+            )),
+        ));
+    }
+
+    let strategy_id = promote_entry(&state, pool, &competition, &entry)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiErrorResponse::new("PROMOTION_FAILED", e)),
+            )
+        })?;
+
+    Ok(Json(PromotionResponse {
+        competition_id: id,
+        promoted_strategy_id: Some(strategy_id),
+    }))
+}
+
+// ==================== 라우터 ====================
+
+/// 전략 경쟁 라우터 생성.
+pub fn competitions_router() -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/", get(list_competitions).post(create_competition))
+        .route("/{id}", get(get_competition).delete(delete_competition))
+        .route("/{id}/finish", post(finish_competition_handler))
+        .route("/{id}/leaderboard", get(get_leaderboard))
+        .route(
+            "/{id}/entries/{entry_id}/promote",
+            post(promote_competition_entry),
+        )
+}
+
+// ==================== 테스트 ====================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::create_test_state;
+    use axum::body::Body;
+    use axum::http::Request;
+    use tower::ServiceExt;
+
+    fn request(body: serde_json::Value) -> CreateCompetitionRequest {
+        serde_json::from_value(body).unwrap()
+    }
+
+    #[test]
+    fn test_validate_request() {
+        let now = Utc::now();
+        let strategy_type = "all_weather";
+        let (input, entries) = validate_request(
+            request(serde_json::json!({
+                "name": " 2분기 리그 ",
+                "initial_capital": 10000000,
+                "auto_promote": true,
+                "entries": [
+                    {"name": "a", "strategy_type": strategy_type},
+                    {"name": "b", "strategy_type": strategy_type, "config": {"period": 20}}
+                ]
+            })),
+            now,
+        )
+        .unwrap();
+        assert_eq!(input.name, "2분기 리그");
+        assert_eq!(input.position_size_pct, Decimal::new(2, 1));
+        assert!(input.auto_promote);
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].config, serde_json::json!({}));
+
+        // 참가자 부족, 중복 이름, 알 수 없는 전략, 과거 종료 시각
+        for body in [
+            serde_json::json!({
+                "name": "a", "initial_capital": 100,
+                "entries": [{"name": "a", "strategy_type": strategy_type}]
+            }),
+            serde_json::json!({
+                "name": "a", "initial_capital": 100,
+                "entries": [
+                    {"name": "a", "strategy_type": strategy_type},
+                    {"name": "a", "strategy_type": strategy_type}
+                ]
+            }),
+            serde_json::json!({
+                "name": "a", "initial_capital": 100,
+                "entries": [
+                    {"name": "a", "strategy_type": strategy_type},
+                    {"name": "b", "strategy_type": "no_such_strategy"}
+                ]
+            }),
+            serde_json::json!({
+                "name": "a", "initial_capital": 100,
+                "ends_at": (now - chrono::Duration::days(1)).to_rfc3339(),
+                "entries": [
+                    {"name": "a", "strategy_type": strategy_type},
+                    {"name": "b", "strategy_type": strategy_type}
+                ]
+            }),
+        ] {
+            let (status, _) = validate_request(request(body), now).unwrap_err();
+            assert_eq!(status, StatusCode::BAD_REQUEST);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_leaderboard_requires_database() {
+        let app = competitions_router().with_state(Arc::new(create_test_state()));
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri(format!("/{}/leaderboard?horizon_days=7", Uuid::new_v4()))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+    }
+}
@@ -0,0 +1,481 @@
+//! 서버 측 조건부 주문 endpoint.
+//!
+//! "종가가 80,000원을 상향 돌파하고 RSI < 60이면 005930 매수"처럼 조건과 주문을 함께 등록하면
+//! `services::conditional_order`가 최신 캔들로 조건을 평가하여 충족 시 주문을 실행합니다.
+//!
+//! # 엔드포인트
+//!
+//! - `GET /api/v1/conditional-orders` - 조건부 주문 목록 (`?status=active`)
+//! - `POST /api/v1/conditional-orders` - 조건부 주문 등록
+//! - `GET /api/v1/conditional-orders/{id}` - 조건부 주문 조회
+//! - `PUT /api/v1/conditional-orders/{id}` - 조건부 주문 수정 (active 상태만)
+//! - `DELETE /api/v1/conditional-orders/{id}` - 조건부 주문 취소 (active 상태만)
+//! - `GET /api/v1/conditional-orders/{id}/evaluate` - 현재 조건 평가 (실행하지 않음)
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    routing::get,
+    Json, Router,
+};
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use std::sync::Arc;
+use tracing::info;
+use trader_analytics::TriggerCalculator;
+use trader_core::{
+    ConditionEvaluation, ConditionalOrderStatus, OrderCondition, OrderType, Side, Timeframe,
+};
+use trader_data::cache::CachedHistoricalDataProvider;
+use uuid::Uuid;
+
+use super::common::{db_error_response, require_pool};
+use crate::error::{ApiErrorResponse, ApiResult};
+use crate::repository::{
+    ConditionalOrderInput, ConditionalOrderRecord, ConditionalOrderRepository,
+};
+use crate::routes::orders::preview_market;
+use crate::services::conditional_order::evaluate_order;
+use crate::state::AppState;
+
+/// 주문 하나에 지정할 수 있는 최대 조건 수.
+const MAX_CONDITIONS: usize = 10;
+
+/// 조건 평가에 사용할 캔들 수.
+const EVALUATE_KLINES: usize = 200;
+
+// ==================== 요청/응답 타입 ====================
+
+/// 조건부 주문 등록/수정 요청.
+#[derive(Debug, Deserialize)]
+pub struct ConditionalOrderRequest {
+    /// 종목 코드
+    pub ticker: String,
+    /// 시장 (KR, US, CRYPTO; 생략하면 종목 코드로 추정)
+    pub market: Option<String>,
+    /// 주문 방향
+    pub side: Side,
+    /// 주문 유형 (market, limit; 기본값: market)
+    #[serde(default = "default_order_type")]
+    pub order_type: OrderType,
+    /// 주문 수량
+    pub quantity: Decimal,
+    /// 지정가 (limit 주문 필수)
+    pub limit_price: Option<Decimal>,
+    /// 전략 ID (전략 예산/비용 모델 적용)
+    pub strategy_id: Option<String>,
+    /// 실행 조건 목록 (모두 충족 시 실행)
+    pub conditions: Vec<OrderCondition>,
+    /// 조건 평가 타임프레임 (기본값: 1d)
+    #[serde(default = "default_timeframe")]
+    pub timeframe: String,
+    /// 만료 시각 (생략하면 만료 없음)
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+fn default_order_type() -> OrderType {
+    OrderType::Market
+}
+
+fn default_timeframe() -> String {
+    "1d".to_string()
+}
+
+/// 조건부 주문 목록 조회 쿼리.
+#[derive(Debug, Deserialize)]
+pub struct ConditionalOrdersQuery {
+    /// 상태 필터 (active, triggered, failed, expired, cancelled)
+    pub status: Option<String>,
+    /// 최대 건수 (기본값: 100)
+    #[serde(default = "default_list_limit")]
+    pub limit: i64,
+}
+
+fn default_list_limit() -> i64 {
+    100
+}
+
+/// 조건부 주문 목록 응답.
+#[derive(Debug, Serialize)]
+pub struct ConditionalOrdersListResponse {
+    pub total: usize,
+    /// 조건부 주문 (최신순)
+    pub orders: Vec<ConditionalOrderRecord>,
+}
+
+/// 조건 평가 응답.
+#[derive(Debug, Serialize)]
+pub struct ConditionalOrderEvaluationResponse {
+    pub id: Uuid,
+    pub status: String,
+    /// 평가 결과
+    pub evaluation: ConditionEvaluation,
+}
+
+// ==================== 헬퍼 ====================
+
+fn not_found(id: Uuid) -> (StatusCode, Json<ApiErrorResponse>) {
+    (
+        StatusCode::NOT_FOUND,
+        Json(ApiErrorResponse::new(
+            "CONDITIONAL_ORDER_NOT_FOUND",
+            format!("Conditional order not found: {}", id),
+        )),
+    )
+}
+
+fn not_active(record: &ConditionalOrderRecord) -> (StatusCode, Json<ApiErrorResponse>) {
+    (
+        StatusCode::CONFLICT,
+        Json(ApiErrorResponse::new(
+            "CONDITIONAL_ORDER_NOT_ACTIVE",
+            format!("Conditional order is {}", record.status),
+        )),
+    )
+}
+
+/// active가 아니어서 수정/취소가 거부된 경우 원인 응답 생성.
+async fn inactive_or_missing(pool: &PgPool, id: Uuid) -> (StatusCode, Json<ApiErrorResponse>) {
+    match ConditionalOrderRepository::get(pool, id).await {
+        Ok(Some(record)) => not_active(&record),
+        Ok(None) => not_found(id),
+        Err(e) => db_error_response(e),
+    }
+}
+
+/// 요청 검증 후 저장 입력으로 변환.
+#[allow(clippy::result_large_err)]
+fn validate_request(
+    request: ConditionalOrderRequest,
+) -> Result<ConditionalOrderInput, (StatusCode, Json<ApiErrorResponse>)> {
+    let invalid = |msg: String| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(ApiErrorResponse::new("INVALID_CONDITIONAL_ORDER", msg)),
+        )
+    };
+
+    let ticker = request.ticker.trim().to_string();
+    if ticker.is_empty() {
+        return Err(invalid("ticker is required".to_string()));
+    }
+    if request.quantity <= Decimal::ZERO {
+        return Err(invalid("quantity must be positive".to_string()));
+    }
+
+    let (order_type, limit_price) = match request.order_type {
+        OrderType::Market => ("market", None),
+        OrderType::Limit => match request.limit_price {
+            Some(price) if price > Decimal::ZERO => ("limit", Some(price)),
+            _ => {
+                return Err(invalid(
+                    "limit_price must be positive for limit orders".to_string(),
+                ))
+            }
+        },
+        other => {
+            return Err(invalid(format!(
+                "Unsupported order type: {} (market or limit)",
+                other
+            )))
+        }
+    };
+
+    if request.conditions.is_empty() {
+        return Err(invalid("at least one condition is required".to_string()));
+    }
+    if request.conditions.len() > MAX_CONDITIONS {
+        return Err(invalid(format!(
+            "at most {} conditions are allowed",
+            MAX_CONDITIONS
+        )));
+    }
+    for condition in &request.conditions {
+        condition.validate().map_err(invalid)?;
+    }
+
+    let timeframe = request.timeframe.trim().to_string();
+    timeframe.parse::<Timeframe>().map_err(invalid)?;
+
+    if request.expires_at.is_some_and(|at| at <= Utc::now()) {
+        return Err(invalid("expires_at must be in the future".to_string()));
+    }
+
+    Ok(ConditionalOrderInput {
+        market: preview_market(&ticker, request.market.as_deref()),
+        ticker,
+        side: request.side.as_str().to_string(),
+        order_type: order_type.to_string(),
+        quantity: request.quantity,
+        limit_price,
+        strategy_id: request.strategy_id.filter(|s| !s.trim().is_empty()),
+        conditions: serde_json::to_value(&request.conditions).unwrap_or_default(),
+        timeframe,
+        expires_at: request.expires_at,
+    })
+}
+
+// ==================== 핸들러 ====================
+
+/// 조건부 주문 목록 조회.
+///
+/// GET /api/v1/conditional-orders
+pub async fn list_conditional_orders(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<ConditionalOrdersQuery>,
+) -> ApiResult<Json<ConditionalOrdersListResponse>> {
+    let status = match query.status.as_deref() {
+        Some(s) => Some(s.parse::<ConditionalOrderStatus>().map_err(|e| {
+            (
+                StatusCode::BAD_REQUEST,
+                Json(ApiErrorResponse::new("INVALID_STATUS", e)),
+            )
+        })?),
+        None => None,
+    };
+
+    let pool = require_pool(&state)?;
+    let orders = ConditionalOrderRepository::list(pool, status, query.limit.clamp(1, 1000))
+        .await
+        .map_err(db_error_response)?;
+
+    Ok(Json(ConditionalOrdersListResponse {
+        total: orders.len(),
+        orders,
+    }))
+}
+
+/// 조건부 주문 등록.
+///
+/// POST /api/v1/conditional-orders
+pub async fn create_conditional_order(
+    State(state): State<Arc<AppState>>,
+    Json(request): Json<ConditionalOrderRequest>,
+) -> ApiResult<(StatusCode, Json<ConditionalOrderRecord>)> {
+    let input = validate_request(request)?;
+    let pool = require_pool(&state)?;
+
+    let record = ConditionalOrderRepository::create(pool, &input)
+        .await
+        .map_err(db_error_response)?;
+
+    info!(
+        id = %record.id,
+        ticker = %record.ticker,
+        side = %record.side,
+        "조건부 주문 등록"
+    );
+    Ok((StatusCode::CREATED, Json(record)))
+}
+
+/// 조건부 주문 조회.
+///
+/// GET /api/v1/conditional-orders/{id}
+pub async fn get_conditional_order(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Json<ConditionalOrderRecord>> {
+    let pool = require_pool(&state)?;
+    ConditionalOrderRepository::get(pool, id)
+        .await
+        .map_err(db_error_response)?
+        .map(Json)
+        .ok_or_else(|| not_found(id))
+}
+
+/// 조건부 주문 수정 (active 상태만).
+///
+/// PUT /api/v1/conditional-orders/{id}
+pub async fn update_conditional_order(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+    Json(request): Json<ConditionalOrderRequest>,
+) -> ApiResult<Json<ConditionalOrderRecord>> {
+    let input = validate_request(request)?;
+    let pool = require_pool(&state)?;
+
+    match ConditionalOrderRepository::update(pool, id, &input)
+        .await
+        .map_err(db_error_response)?
+    {
+        Some(record) => Ok(Json(record)),
+        None => Err(inactive_or_missing(pool, id).await),
+    }
+}
+
+/// 조건부 주문 취소 (active 상태만).
+///
+/// DELETE /api/v1/conditional-orders/{id}
+pub async fn cancel_conditional_order(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Json<ConditionalOrderRecord>> {
+    let pool = require_pool(&state)?;
+
+    match ConditionalOrderRepository::cancel(pool, id)
+        .await
+        .map_err(db_error_response)?
+    {
+        Some(record) => {
+            info!(%id, "조건부 주문 취소");
+            Ok(Json(record))
+        }
+        None => Err(inactive_or_missing(pool, id).await),
+    }
+}
+
+/// 현재 캔들로 조건 평가 (주문은 실행하지 않음).
+///
+/// GET /api/v1/conditional-orders/{id}/evaluate
+pub async fn evaluate_conditional_order(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Json<ConditionalOrderEvaluationResponse>> {
+    let pool = require_pool(&state)?;
+    let record = ConditionalOrderRepository::get(pool, id)
+        .await
+        .map_err(db_error_response)?
+        .ok_or_else(|| not_found(id))?;
+
+    let unprocessable = |msg: String| {
+        (
+            StatusCode::UNPROCESSABLE_ENTITY,
+            Json(ApiErrorResponse::new("EVALUATION_FAILED", msg)),
+        )
+    };
+
+    let timeframe = record
+        .timeframe
+        .parse::<Timeframe>()
+        .map_err(unprocessable)?;
+    let provider = state
+        .data_provider
+        .clone()
+        .unwrap_or_else(|| Arc::new(CachedHistoricalDataProvider::new(pool.clone())));
+    let klines = provider
+        .get_klines(&record.ticker, timeframe, EVALUATE_KLINES)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::BAD_GATEWAY,
+                Json(ApiErrorResponse::new(
+                    "DATA_FETCH_ERROR",
+                    format!("Failed to load klines: {}", e),
+                )),
+            )
+        })?;
+
+    let evaluation =
+        evaluate_order(&TriggerCalculator::new(), &record, &klines).map_err(unprocessable)?;
+
+    Ok(Json(ConditionalOrderEvaluationResponse {
+        id: record.id,
+        status: record.status,
+        evaluation,
+    }))
+}
+
+// ==================== 라우터 ====================
+
+/// 조건부 주문 라우터 생성.
+pub fn conditional_orders_router() -> Router<Arc<AppState>> {
+    Router::new()
+        .route(
+            "/",
+            get(list_conditional_orders).post(create_conditional_order),
+        )
+        .route(
+            "/{id}",
+            get(get_conditional_order)
+                .put(update_conditional_order)
+                .delete(cancel_conditional_order),
+        )
+        .route("/{id}/evaluate", get(evaluate_conditional_order))
+}
+
+// ==================== 테스트 ====================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::create_test_state;
+    use axum::body::Body;
+    use axum::http::Request;
+    use tower::ServiceExt;
+
+    fn request(body: serde_json::Value) -> ConditionalOrderRequest {
+        serde_json::from_value(body).unwrap()
+    }
+
+    #[test]
+    fn test_validate_request() {
+        let input = validate_request(request(serde_json::json!({
+            "ticker": " 005930 ",
+            "side": "buy",
+            "quantity": 10,
+            "conditions": [
+                {"type": "price", "operator": "cross_above", "value": 80000},
+                {"type": "indicator", "indicator": {"name": "rsi", "period": 14}, "operator": "lt", "value": 60}
+            ]
+        })))
+        .unwrap();
+        assert_eq!(input.ticker, "005930");
+        assert_eq!(input.market, "KR");
+        assert_eq!(input.order_type, "market");
+        assert_eq!(input.timeframe, "1d");
+        assert_eq!(input.conditions.as_array().unwrap().len(), 2);
+
+        // 지정가 주문은 가격 필수
+        let (status, _) = validate_request(request(serde_json::json!({
+            "ticker": "AAPL",
+            "side": "sell",
+            "order_type": "limit",
+            "quantity": 1,
+            "conditions": [{"type": "trigger", "trigger": "volume_spike"}]
+        })))
+        .unwrap_err();
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        // 조건 없음, 지나간 만료 시각
+        assert!(validate_request(request(serde_json::json!({
+            "ticker": "AAPL", "side": "buy", "quantity": 1, "conditions": []
+        })))
+        .is_err());
+        assert!(validate_request(request(serde_json::json!({
+            "ticker": "AAPL", "side": "buy", "quantity": 1,
+            "conditions": [{"type": "price", "operator": "gt", "value": 100}],
+            "expires_at": "2020-01-01T00:00:00Z"
+        })))
+        .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_create_conditional_order_rejects_invalid_condition() {
+        let app = conditional_orders_router().with_state(Arc::new(create_test_state()));
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/")
+                    .header("content-type", "application/json")
+                    .body(Body::from(
+                        serde_json::json!({
+                            "ticker": "005930",
+                            "side": "buy",
+                            "quantity": 10,
+                            "conditions": [
+                                {"type": "indicator", "indicator": {"name": "sma", "period": 0}, "operator": "gt", "value": 1}
+                            ]
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+}
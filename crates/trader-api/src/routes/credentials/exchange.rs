@@ -13,6 +13,8 @@ use axum::{
 };
 use std::sync::Arc;
 use tracing::{error, info, warn};
+use trader_exchange::connector::binance::{BinanceClient, BinanceConfig};
+use trader_exchange::Exchange;
 use uuid::Uuid;
 
 use super::types::{
@@ -670,21 +672,16 @@ pub async fn test_exchange_credential(
             )
         })?;
 
-    // Exchange-specific connection test (actual API call)
-    // TODO: Replace with actual exchange API calls
-    // Currently only validates credential format
+    // Exchange-specific connection test
+    // Binance: 계좌 조회 API 호출, 그 외: 형식 검증
     let (success, message, permissions) = match row.exchange_id.as_str() {
         "binance" => {
-            // Binance API key format validation (actual API call needed)
-            if credentials.api_key.len() >= 10 && credentials.api_secret.len() >= 10 {
-                (
-                    true,
-                    "Binance API 키 형식이 유효합니다.".to_string(),
-                    Some(vec!["read".to_string(), "trade".to_string()]),
-                )
-            } else {
-                (false, "API 키 형식이 올바르지 않습니다.".to_string(), None)
-            }
+            verify_binance_credentials(
+                &credentials.api_key,
+                &credentials.api_secret,
+                row.is_testnet,
+            )
+            .await
         }
         "kis" => {
             // KIS API key format validation
@@ -763,6 +760,40 @@ pub async fn test_exchange_credential(
     }))
 }
 
+/// Binance 계좌 조회로 API 키 검증.
+///
+/// 성공 시 계좌 권한(`read`, 거래 가능 시 `trade`)을 반환합니다.
+async fn verify_binance_credentials(
+    api_key: &str,
+    api_secret: &str,
+    testnet: bool,
+) -> (bool, String, Option<Vec<String>>) {
+    let config =
+        BinanceConfig::new(api_key.to_string(), api_secret.to_string()).with_testnet(testnet);
+    let client = match BinanceClient::new(config) {
+        Ok(client) => client,
+        Err(e) => return (false, format!("Binance 클라이언트 생성 실패: {}", e), None),
+    };
+
+    match client.get_account().await {
+        Ok(account) => {
+            let mut permissions = vec!["read".to_string()];
+            if account.can_trade {
+                permissions.push("trade".to_string());
+            }
+            (
+                true,
+                "Binance API 키가 유효합니다.".to_string(),
+                Some(permissions),
+            )
+        }
+        Err(e) => {
+            warn!("Binance 계좌 조회 실패: {}", e);
+            (false, format!("Binance 계좌 조회 실패: {}", e), None)
+        }
+    }
+}
+
 /// Test new credential before saving.
 ///
 /// `POST /api/v1/credentials/exchanges/test`
@@ -0,0 +1,594 @@
+//! 사용자 정의 지수 endpoint.
+//!
+//! 여러 종목을 고정 비중 또는 규칙 기반 비중(동일, 역변동성, 시가총액)으로 묶은 지수를
+//! 정의하고, 합성 일봉을 계산합니다. 합성 일봉은 `IDX_<코드>` 티커로 OHLCV에 저장되므로
+//! 전략 심볼이나 시장 레짐 입력으로 그대로 사용할 수 있습니다. 일별 재계산은 수집기의
+//! `sync-custom-indices` 단계가 담당합니다.
+//!
+//! # 엔드포인트
+//!
+//! - `GET /api/v1/indices` - 지수 목록
+//! - `POST /api/v1/indices` - 지수 등록 (등록 즉시 계산)
+//! - `GET /api/v1/indices/{code}` - 지수 조회
+//! - `PUT /api/v1/indices/{code}` - 지수 수정 (수정 즉시 재계산)
+//! - `DELETE /api/v1/indices/{code}` - 지수 삭제 (합성 일봉 포함)
+//! - `POST /api/v1/indices/{code}/rebuild` - 전체 기간 재계산
+//! - `GET /api/v1/indices/{code}/bars` - 합성 일봉 종가
+//! - `GET /api/v1/indices/{code}/benchmark` - 포트폴리오 자산 곡선 대비 비교
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    routing::{get, post},
+    Json, Router,
+};
+use chrono::{Duration, NaiveDate, Utc};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+use trader_core::{
+    compare_to_benchmark, BenchmarkComparison, CustomIndexDefinition, IndexConstituent,
+    IndexWeighting,
+};
+use trader_data::{CustomIndexBuildResult, CustomIndexRecord, CustomIndexStore, DataError};
+use uuid::Uuid;
+
+use super::common::db_unavailable;
+use crate::error::{ApiErrorResponse, ApiResult};
+use crate::repository::EquityHistoryRepository;
+use crate::routes::analytics::parse_period_duration;
+use crate::state::AppState;
+
+// ==================== 요청/응답 타입 ====================
+
+/// 지수 등록/수정 요청.
+#[derive(Debug, Deserialize)]
+pub struct CustomIndexRequest {
+    /// 지수 코드 (등록 시 필수, 수정 시 경로 값 사용)
+    #[serde(default)]
+    pub code: Option<String>,
+    /// 지수 이름
+    pub name: String,
+    /// 시장 (기본값: KR)
+    #[serde(default = "default_market")]
+    pub market: String,
+    /// 비중 결정 방식 (기본값: equal)
+    #[serde(default = "default_weighting")]
+    pub weighting: IndexWeighting,
+    /// 기준값 (기본값: 1000)
+    #[serde(default = "default_base_value")]
+    pub base_value: Decimal,
+    /// 구성종목
+    pub constituents: Vec<IndexConstituent>,
+    /// 일별 재계산 여부 (기본값: true)
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+}
+
+fn default_market() -> String {
+    "KR".to_string()
+}
+
+fn default_weighting() -> IndexWeighting {
+    IndexWeighting::Equal
+}
+
+fn default_base_value() -> Decimal {
+    Decimal::from(1000)
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+impl CustomIndexRequest {
+    fn into_definition(self, code: String) -> CustomIndexDefinition {
+        CustomIndexDefinition {
+            code: normalize_code(&code),
+            name: self.name.trim().to_string(),
+            market: self.market.trim().to_uppercase(),
+            weighting: self.weighting,
+            base_value: self.base_value,
+            constituents: self
+                .constituents
+                .into_iter()
+                .map(|c| IndexConstituent {
+                    ticker: c.ticker.trim().to_uppercase(),
+                    weight: c.weight,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// 지수 목록 응답.
+#[derive(Debug, Serialize)]
+pub struct CustomIndicesListResponse {
+    pub total: usize,
+    /// 지수 (코드순)
+    pub indices: Vec<CustomIndexDto>,
+}
+
+/// 지수 정보.
+#[derive(Debug, Serialize)]
+pub struct CustomIndexDto {
+    /// 전략/레짐/벤치마크에서 사용하는 티커 (`IDX_<코드>`)
+    pub ticker: String,
+    #[serde(flatten)]
+    pub record: CustomIndexRecord,
+}
+
+impl From<CustomIndexRecord> for CustomIndexDto {
+    fn from(record: CustomIndexRecord) -> Self {
+        Self {
+            ticker: record.ticker(),
+            record,
+        }
+    }
+}
+
+/// 지수 등록/수정/재계산 응답.
+#[derive(Debug, Serialize)]
+pub struct CustomIndexSaveResponse {
+    pub index: CustomIndexDto,
+    /// 계산 결과 (계산 실패 시 None)
+    pub build: Option<CustomIndexBuildResult>,
+    /// 계산 실패 사유
+    pub build_error: Option<String>,
+}
+
+/// 일봉 조회 쿼리.
+#[derive(Debug, Deserialize)]
+pub struct CustomIndexBarsQuery {
+    /// 조회 일수 (기본값: 365)
+    #[serde(default = "default_bar_days")]
+    pub days: i64,
+}
+
+fn default_bar_days() -> i64 {
+    365
+}
+
+/// 일봉 종가.
+#[derive(Debug, Serialize)]
+pub struct IndexClosePoint {
+    pub date: NaiveDate,
+    pub close: Decimal,
+}
+
+/// 일봉 조회 응답.
+#[derive(Debug, Serialize)]
+pub struct CustomIndexBarsResponse {
+    pub ticker: String,
+    pub total: usize,
+    /// 거래일별 종가 (오래된 순)
+    pub bars: Vec<IndexClosePoint>,
+}
+
+/// 벤치마크 비교 쿼리.
+#[derive(Debug, Deserialize)]
+pub struct BenchmarkQuery {
+    /// 기간 (1w, 1m, 3m, 6m, 1y, ytd, all; 기본값: 1y)
+    #[serde(default = "default_period")]
+    pub period: String,
+    /// 계좌 ID (생략하면 전체 계좌 합산)
+    pub credential_id: Option<Uuid>,
+}
+
+fn default_period() -> String {
+    "1y".to_string()
+}
+
+/// 비교 차트 포인트 (시작일 = 100).
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct BenchmarkPoint {
+    pub date: NaiveDate,
+    pub portfolio: f64,
+    pub benchmark: f64,
+}
+
+/// 벤치마크 비교 응답.
+#[derive(Debug, Serialize)]
+pub struct BenchmarkResponse {
+    pub ticker: String,
+    pub period: String,
+    /// 비교 지표 (공통 거래일이 2일 미만이면 None)
+    pub comparison: Option<BenchmarkComparison>,
+    /// 공통 거래일 기준 정규화 시계열
+    pub series: Vec<BenchmarkPoint>,
+}
+
+// ==================== 헬퍼 ====================
+
+fn data_error_response(err: DataError) -> (StatusCode, Json<ApiErrorResponse>) {
+    (
+        StatusCode::INTERNAL_SERVER_ERROR,
+        Json(ApiErrorResponse::new("DB_ERROR", err.to_string())),
+    )
+}
+
+fn not_found(code: &str) -> (StatusCode, Json<ApiErrorResponse>) {
+    (
+        StatusCode::NOT_FOUND,
+        Json(ApiErrorResponse::new(
+            "INDEX_NOT_FOUND",
+            format!("Custom index not found: {}", code),
+        )),
+    )
+}
+
+/// 경로의 지수 코드 정규화 (대문자).
+fn normalize_code(code: &str) -> String {
+    code.trim().to_uppercase()
+}
+
+#[allow(clippy::result_large_err)]
+fn validate(definition: &CustomIndexDefinition) -> ApiResult<()> {
+    definition.validate().map_err(|msg| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(ApiErrorResponse::new("INVALID_INDEX", msg)),
+        )
+    })
+}
+
+/// 저장 후 즉시 재계산. 계산 실패는 저장 자체를 실패로 보지 않고 사유만 기록합니다.
+async fn save_and_build(
+    store: &CustomIndexStore,
+    definition: &CustomIndexDefinition,
+    enabled: bool,
+) -> ApiResult<CustomIndexSaveResponse> {
+    store
+        .upsert(definition, enabled)
+        .await
+        .map_err(data_error_response)?;
+
+    let (build, build_error) = match store.rebuild(definition).await {
+        Ok(result) => (Some(result), None),
+        Err(e) => {
+            warn!(code = %definition.code, error = %e, "사용자 정의 지수 계산 실패");
+            let _ = store.record_error(&definition.code, &e.to_string()).await;
+            (None, Some(e.to_string()))
+        }
+    };
+
+    let record = store
+        .get(&definition.code)
+        .await
+        .map_err(data_error_response)?
+        .ok_or_else(|| not_found(&definition.code))?;
+
+    Ok(CustomIndexSaveResponse {
+        index: record.into(),
+        build,
+        build_error,
+    })
+}
+
+/// 공통 거래일 기준으로 두 시계열을 시작일 = 100으로 정규화.
+fn normalized_series(
+    portfolio: &BTreeMap<NaiveDate, f64>,
+    benchmark: &BTreeMap<NaiveDate, f64>,
+) -> Vec<BenchmarkPoint> {
+    let aligned: Vec<(NaiveDate, f64, f64)> = portfolio
+        .iter()
+        .filter_map(|(d, p)| benchmark.get(d).map(|b| (*d, *p, *b)))
+        .filter(|(_, p, b)| *p > 0.0 && *b > 0.0)
+        .collect();
+    let Some(&(_, p0, b0)) = aligned.first() else {
+        return Vec::new();
+    };
+
+    aligned
+        .into_iter()
+        .map(|(date, p, b)| BenchmarkPoint {
+            date,
+            portfolio: p / p0 * 100.0,
+            benchmark: b / b0 * 100.0,
+        })
+        .collect()
+}
+
+// ==================== 핸들러 ====================
+
+/// 지수 목록 조회.
+///
+/// GET /api/v1/indices
+pub async fn list_custom_indices(
+    State(state): State<Arc<AppState>>,
+) -> ApiResult<Json<CustomIndicesListResponse>> {
+    let pool = state.db_pool.as_ref().ok_or_else(db_unavailable)?;
+    let records = CustomIndexStore::new(pool.clone())
+        .list()
+        .await
+        .map_err(data_error_response)?;
+
+    let indices: Vec<CustomIndexDto> = records.into_iter().map(Into::into).collect();
+    Ok(Json(CustomIndicesListResponse {
+        total: indices.len(),
+        indices,
+    }))
+}
+
+/// 지수 등록.
+///
+/// POST /api/v1/indices
+pub async fn create_custom_index(
+    State(state): State<Arc<AppState>>,
+    Json(request): Json<CustomIndexRequest>,
+) -> ApiResult<(StatusCode, Json<CustomIndexSaveResponse>)> {
+    let pool = state.db_pool.as_ref().ok_or_else(db_unavailable)?;
+    let code = request.code.clone().unwrap_or_default();
+    let enabled = request.enabled;
+    let definition = request.into_definition(code);
+    validate(&definition)?;
+
+    let store = CustomIndexStore::new(pool.clone());
+    if store
+        .get(&definition.code)
+        .await
+        .map_err(data_error_response)?
+        .is_some()
+    {
+        return Err((
+            StatusCode::CONFLICT,
+            Json(ApiErrorResponse::new(
+                "INDEX_CODE_CONFLICT",
+                format!("Custom index already exists: {}", definition.code),
+            )),
+        ));
+    }
+
+    let response = save_and_build(&store, &definition, enabled).await?;
+    info!(code = %definition.code, constituents = definition.constituents.len(), "사용자 정의 지수 등록");
+    Ok((StatusCode::CREATED, Json(response)))
+}
+
+/// 지수 조회.
+///
+/// GET /api/v1/indices/{code}
+pub async fn get_custom_index(
+    State(state): State<Arc<AppState>>,
+    Path(code): Path<String>,
+) -> ApiResult<Json<CustomIndexDto>> {
+    let code = normalize_code(&code);
+    let pool = state.db_pool.as_ref().ok_or_else(db_unavailable)?;
+    let record = CustomIndexStore::new(pool.clone())
+        .get(&code)
+        .await
+        .map_err(data_error_response)?
+        .ok_or_else(|| not_found(&code))?;
+
+    Ok(Json(record.into()))
+}
+
+/// 지수 수정 (구성종목/비중이 바뀌면 전체 기간 재계산).
+///
+/// PUT /api/v1/indices/{code}
+pub async fn update_custom_index(
+    State(state): State<Arc<AppState>>,
+    Path(code): Path<String>,
+    Json(request): Json<CustomIndexRequest>,
+) -> ApiResult<Json<CustomIndexSaveResponse>> {
+    let code = normalize_code(&code);
+    let pool = state.db_pool.as_ref().ok_or_else(db_unavailable)?;
+    let store = CustomIndexStore::new(pool.clone());
+    store
+        .get(&code)
+        .await
+        .map_err(data_error_response)?
+        .ok_or_else(|| not_found(&code))?;
+
+    let enabled = request.enabled;
+    let definition = request.into_definition(code);
+    validate(&definition)?;
+
+    let response = save_and_build(&store, &definition, enabled).await?;
+    info!(code = %definition.code, "사용자 정의 지수 수정");
+    Ok(Json(response))
+}
+
+/// 지수 삭제.
+///
+/// DELETE /api/v1/indices/{code}
+pub async fn delete_custom_index(
+    State(state): State<Arc<AppState>>,
+    Path(code): Path<String>,
+) -> ApiResult<StatusCode> {
+    let code = normalize_code(&code);
+    let pool = state.db_pool.as_ref().ok_or_else(db_unavailable)?;
+    let deleted = CustomIndexStore::new(pool.clone())
+        .delete(&code)
+        .await
+        .map_err(data_error_response)?;
+
+    if !deleted {
+        return Err(not_found(&code));
+    }
+    info!(code = %code, "사용자 정의 지수 삭제");
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// 지수 전체 기간 재계산.
+///
+/// POST /api/v1/indices/{code}/rebuild
+pub async fn rebuild_custom_index(
+    State(state): State<Arc<AppState>>,
+    Path(code): Path<String>,
+) -> ApiResult<Json<CustomIndexBuildResult>> {
+    let code = normalize_code(&code);
+    let pool = state.db_pool.as_ref().ok_or_else(db_unavailable)?;
+    let store = CustomIndexStore::new(pool.clone());
+    let record = store
+        .get(&code)
+        .await
+        .map_err(data_error_response)?
+        .ok_or_else(|| not_found(&code))?;
+    let definition = record.definition().map_err(data_error_response)?;
+
+    match store.rebuild(&definition).await {
+        Ok(result) => Ok(Json(result)),
+        Err(e) => {
+            let _ = store.record_error(&code, &e.to_string()).await;
+            Err((
+                StatusCode::UNPROCESSABLE_ENTITY,
+                Json(ApiErrorResponse::new("INDEX_BUILD_FAILED", e.to_string())),
+            ))
+        }
+    }
+}
+
+/// 합성 일봉 종가 조회.
+///
+/// GET /api/v1/indices/{code}/bars
+pub async fn get_custom_index_bars(
+    State(state): State<Arc<AppState>>,
+    Path(code): Path<String>,
+    Query(query): Query<CustomIndexBarsQuery>,
+) -> ApiResult<Json<CustomIndexBarsResponse>> {
+    let code = normalize_code(&code);
+    let pool = state.analytics_db_pool().ok_or_else(db_unavailable)?;
+    let store = CustomIndexStore::new(pool.clone());
+    let record = store
+        .get(&code)
+        .await
+        .map_err(data_error_response)?
+        .ok_or_else(|| not_found(&code))?;
+
+    let since = Utc::now().date_naive() - Duration::days(query.days.clamp(1, 3650));
+    let bars: Vec<IndexClosePoint> = store
+        .closes(&record.code, &record.market, Some(since))
+        .await
+        .map_err(data_error_response)?
+        .into_iter()
+        .map(|(date, close)| IndexClosePoint { date, close })
+        .collect();
+
+    Ok(Json(CustomIndexBarsResponse {
+        ticker: record.ticker(),
+        total: bars.len(),
+        bars,
+    }))
+}
+
+/// 포트폴리오 자산 곡선과 지수 비교 (지수를 벤치마크로 사용).
+///
+/// GET /api/v1/indices/{code}/benchmark
+pub async fn get_custom_index_benchmark(
+    State(state): State<Arc<AppState>>,
+    Path(code): Path<String>,
+    Query(query): Query<BenchmarkQuery>,
+) -> ApiResult<Json<BenchmarkResponse>> {
+    let code = normalize_code(&code);
+    let pool = state.analytics_db_pool().ok_or_else(db_unavailable)?;
+    let store = CustomIndexStore::new(pool.clone());
+    let record = store
+        .get(&code)
+        .await
+        .map_err(data_error_response)?
+        .ok_or_else(|| not_found(&code))?;
+
+    let end_time = Utc::now();
+    let start_time = end_time - parse_period_duration(&query.period);
+
+    let equity = match query.credential_id {
+        Some(cred_id) => {
+            EquityHistoryRepository::get_equity_curve(pool, cred_id, start_time, end_time).await
+        }
+        None => {
+            EquityHistoryRepository::get_aggregated_equity_curve(pool, start_time, end_time).await
+        }
+    }
+    .map_err(|e| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiErrorResponse::new("DB_ERROR", e.to_string())),
+        )
+    })?;
+
+    // 일별 마지막 자산 가치 (KST 날짜 기준)
+    let portfolio: BTreeMap<NaiveDate, f64> = equity
+        .iter()
+        .filter_map(|p| {
+            let date = (p.timestamp + Duration::hours(9)).date_naive();
+            p.equity.to_f64().map(|v| (date, v))
+        })
+        .collect();
+
+    let benchmark: BTreeMap<NaiveDate, f64> = store
+        .closes(&record.code, &record.market, Some(start_time.date_naive()))
+        .await
+        .map_err(data_error_response)?
+        .into_iter()
+        .filter_map(|(date, close)| close.to_f64().map(|v| (date, v)))
+        .collect();
+
+    Ok(Json(BenchmarkResponse {
+        ticker: record.ticker(),
+        period: query.period,
+        comparison: compare_to_benchmark(&portfolio, &benchmark),
+        series: normalized_series(&portfolio, &benchmark),
+    }))
+}
+
+// ==================== 라우터 ====================
+
+/// 사용자 정의 지수 라우터 생성.
+pub fn custom_index_router() -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/", get(list_custom_indices).post(create_custom_index))
+        .route(
+            "/{code}",
+            get(get_custom_index)
+                .put(update_custom_index)
+                .delete(delete_custom_index),
+        )
+        .route("/{code}/rebuild", post(rebuild_custom_index))
+        .route("/{code}/bars", get(get_custom_index_bars))
+        .route("/{code}/benchmark", get(get_custom_index_benchmark))
+}
+
+// ==================== 테스트 ====================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 3, d).unwrap()
+    }
+
+    #[test]
+    fn test_normalized_series_uses_common_dates() {
+        let portfolio = BTreeMap::from([(day(2), 200.0), (day(3), 210.0), (day(4), 220.0)]);
+        let benchmark = BTreeMap::from([(day(3), 50.0), (day(4), 55.0)]);
+
+        let series = normalized_series(&portfolio, &benchmark);
+        assert_eq!(series.len(), 2);
+        assert_eq!(series[0].date, day(3));
+        assert_eq!(series[0].portfolio, 100.0);
+        assert!((series[1].portfolio - 220.0 / 210.0 * 100.0).abs() < 1e-9);
+        assert!((series[1].benchmark - 110.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_request_normalizes_codes() {
+        let request: CustomIndexRequest = serde_json::from_value(serde_json::json!({
+            "name": " 반도체 ",
+            "weighting": "static",
+            "constituents": [{"ticker": "aapl", "weight": 1}],
+        }))
+        .unwrap();
+
+        let definition = request.into_definition(" semi_us ".to_string());
+        assert_eq!(definition.code, "SEMI_US");
+        assert_eq!(definition.market, "KR");
+        assert_eq!(definition.constituents[0].ticker, "AAPL");
+        assert_eq!(definition.base_value, Decimal::from(1000));
+        assert!(definition.validate().is_ok());
+    }
+}
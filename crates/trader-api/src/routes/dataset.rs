@@ -8,11 +8,12 @@
 //! - `POST /api/v1/dataset/fetch` - 새 데이터셋 다운로드 요청
 //! - `GET /api/v1/dataset/search` - 심볼 검색
 //! - `POST /api/v1/dataset/symbols/batch` - 여러 티커의 심볼 정보 일괄 조회
+//! - `POST /api/v1/dataset/klines:bulk` - 외부 캔들 데이터 대량 업로드 (gzip CSV/NDJSON)
 //! - `GET /api/v1/dataset/:symbol` - 특정 심볼의 캔들 데이터 조회
 //! - `DELETE /api/v1/dataset/:symbol` - 특정 심볼 캐시 삭제
 
 use axum::{
-    extract::{Path, Query, State},
+    extract::{DefaultBodyLimit, Path, Query, State},
     http::StatusCode,
     routing::{delete, get, post},
     Json, Router,
@@ -25,6 +26,7 @@ use tracing::{error, info};
 use trader_core::Timeframe;
 use trader_data::cache::CachedHistoricalDataProvider;
 
+use super::dataset_upload::{bulk_upsert_klines, MAX_UPLOAD_BYTES};
 use crate::repository::{SymbolInfoRepository, SymbolSearchResult};
 use crate::routes::strategies::ApiError;
 use crate::state::AppState;
@@ -577,7 +579,7 @@ pub async fn get_candles(
 
                 // 최신순 정렬 (desc)
                 let mut sorted_klines = klines;
-                sorted_klines.sort_by(|a, b| b.open_time.cmp(&a.open_time));
+                sorted_klines.sort_by_key(|k| std::cmp::Reverse(k.open_time));
 
                 let candles: Vec<CandleItem> = sorted_klines
                     .into_iter()
@@ -1095,6 +1097,11 @@ pub fn dataset_router() -> Router<Arc<AppState>> {
         .route("/symbols/failed", get(get_failed_symbols))
         .route("/symbols/stats", get(get_symbol_stats))
         .route("/symbols/reactivate", post(reactivate_symbols))
+        // 외부 캔들 데이터 대량 업로드
+        .route(
+            "/klines:bulk",
+            post(bulk_upsert_klines).layer(DefaultBodyLimit::max(MAX_UPLOAD_BYTES)),
+        )
         // 심볼별 조회/삭제
         .route("/{symbol}", get(get_candles))
         .route("/{symbol}", delete(delete_dataset))
@@ -0,0 +1,690 @@
+//! 외부 캔들 데이터 대량 업로드 endpoint.
+//!
+//! 구입한 분봉 데이터처럼 외부에서 받은 OHLCV 파일을 SQL 없이 `ohlcv` 테이블에 적재합니다.
+//! 본문은 CSV 또는 NDJSON이며 gzip 압축(매직 바이트로 감지)을 지원합니다.
+//! 행 단위로 검증하고, 같은 시각의 중복 행은 마지막 행만 사용한 뒤 upsert 합니다.
+//!
+//! # 엔드포인트
+//!
+//! - `POST /api/v1/dataset/klines:bulk?symbol=005930&timeframe=1m` - 캔들 대량 upsert
+
+use axum::{
+    body::Bytes,
+    extract::{Query, State},
+    http::{header, HeaderMap, StatusCode},
+    Json,
+};
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use flate2::read::GzDecoder;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::io::Read;
+use std::str::FromStr;
+use std::sync::Arc;
+use tracing::{error, info};
+
+use crate::repository::{KlinesRepository, NewKline, SymbolInfoRepository};
+use crate::routes::strategies::ApiError;
+use crate::state::AppState;
+
+/// 업로드 본문 최대 크기 (압축 상태).
+pub const MAX_UPLOAD_BYTES: usize = 64 * 1024 * 1024;
+/// 압축 해제 후 최대 크기.
+const MAX_DECOMPRESSED_BYTES: u64 = 512 * 1024 * 1024;
+/// 한 번에 upsert 하는 행 수.
+const UPSERT_CHUNK_SIZE: usize = 5_000;
+/// 응답에 포함하는 행 오류 최대 개수.
+const MAX_REPORTED_ERRORS: usize = 20;
+
+/// 지원 타임프레임 (DB 저장 형식).
+const SUPPORTED_TIMEFRAMES: &[&str] = &[
+    "1m", "3m", "5m", "15m", "30m", "1h", "2h", "4h", "6h", "8h", "12h", "1d", "3d", "1wk", "1mo",
+];
+
+// ==================== 요청/응답 타입 ====================
+
+/// 업로드 파일 형식.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum UploadFormat {
+    Csv,
+    #[serde(alias = "jsonl")]
+    Ndjson,
+}
+
+/// 업로드 쿼리.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BulkKlineQuery {
+    /// 심볼 (예: 005930, AAPL)
+    pub symbol: String,
+    /// 타임프레임 (1m, 5m, 1h, 1d 등)
+    pub timeframe: String,
+    /// 파일 형식 (미지정 시 Content-Type 또는 본문으로 판단)
+    #[serde(default)]
+    pub format: Option<UploadFormat>,
+    /// 시간대가 없는 시각의 UTC 오프셋 (예: "+09:00", 기본 UTC)
+    #[serde(default)]
+    pub utc_offset: Option<String>,
+    /// true면 잘못된 행이 하나라도 있을 때 전체 업로드 거부
+    #[serde(default)]
+    pub strict: bool,
+}
+
+/// 행 오류.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RowError {
+    /// 파일 내 줄 번호 (1부터)
+    pub line: u64,
+    pub message: String,
+}
+
+/// 업로드 결과.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BulkKlineUploadResponse {
+    pub symbol: String,
+    pub timeframe: String,
+    pub format: UploadFormat,
+    /// 읽은 데이터 행 수
+    pub received_rows: usize,
+    /// 검증 실패로 제외된 행 수
+    pub rejected_rows: usize,
+    /// 같은 시각 중복으로 합쳐진 행 수
+    pub duplicate_rows: usize,
+    /// 저장(신규 + 갱신)된 캔들 수
+    pub upserted: usize,
+    /// 업로드 데이터의 첫/마지막 캔들 시각
+    pub first_time: Option<DateTime<Utc>>,
+    pub last_time: Option<DateTime<Utc>>,
+    /// 저장 후 전체 캔들 수
+    pub total_candles: Option<i32>,
+    /// 행 오류 (최대 20개)
+    pub errors: Vec<RowError>,
+}
+
+// ==================== 파싱 ====================
+
+/// 파싱 결과.
+#[derive(Debug, Default)]
+struct ParsedUpload {
+    /// 시각순 정렬, 중복 제거된 캔들
+    klines: Vec<NewKline>,
+    received_rows: usize,
+    rejected_rows: usize,
+    duplicate_rows: usize,
+    errors: Vec<RowError>,
+}
+
+impl ParsedUpload {
+    fn reject(&mut self, line: u64, message: impl Into<String>) {
+        self.rejected_rows += 1;
+        if self.errors.len() < MAX_REPORTED_ERRORS {
+            self.errors.push(RowError {
+                line,
+                message: message.into(),
+            });
+        }
+    }
+}
+
+/// 행에서 읽은 원시 값.
+#[derive(Debug, Default)]
+struct RawRow {
+    time: Option<String>,
+    open: Option<String>,
+    high: Option<String>,
+    low: Option<String>,
+    close: Option<String>,
+    volume: Option<String>,
+}
+
+/// 컬럼명 정규화 (별칭 → 표준 필드).
+fn normalize_column(name: &str) -> Option<&'static str> {
+    match name.trim().to_lowercase().as_str() {
+        "time" | "timestamp" | "datetime" | "date" | "open_time" | "opentime" | "ts" => {
+            Some("time")
+        }
+        "open" | "o" => Some("open"),
+        "high" | "h" => Some("high"),
+        "low" | "l" => Some("low"),
+        "close" | "c" => Some("close"),
+        "volume" | "vol" | "v" => Some("volume"),
+        _ => None,
+    }
+}
+
+impl RawRow {
+    fn set(&mut self, field: &str, value: String) {
+        let slot = match field {
+            "time" => &mut self.time,
+            "open" => &mut self.open,
+            "high" => &mut self.high,
+            "low" => &mut self.low,
+            "close" => &mut self.close,
+            "volume" => &mut self.volume,
+            _ => return,
+        };
+        *slot = Some(value);
+    }
+}
+
+/// gzip 본문이면 압축 해제.
+fn decode_body(body: &[u8]) -> Result<Vec<u8>, String> {
+    if !body.starts_with(&[0x1f, 0x8b]) {
+        return Ok(body.to_vec());
+    }
+
+    let mut decoded = Vec::new();
+    GzDecoder::new(body)
+        .take(MAX_DECOMPRESSED_BYTES + 1)
+        .read_to_end(&mut decoded)
+        .map_err(|e| format!("gzip 압축 해제 실패: {}", e))?;
+    if decoded.len() as u64 > MAX_DECOMPRESSED_BYTES {
+        return Err(format!(
+            "압축 해제 크기가 {}MB를 초과합니다",
+            MAX_DECOMPRESSED_BYTES / 1024 / 1024
+        ));
+    }
+    Ok(decoded)
+}
+
+/// 파일 형식 결정 (쿼리 → Content-Type → 본문 첫 글자).
+fn detect_format(explicit: Option<UploadFormat>, headers: &HeaderMap, data: &[u8]) -> UploadFormat {
+    if let Some(format) = explicit {
+        return format;
+    }
+
+    let content_type = headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("")
+        .to_lowercase();
+    if content_type.contains("ndjson") || content_type.contains("jsonl") {
+        return UploadFormat::Ndjson;
+    }
+    if content_type.contains("csv") {
+        return UploadFormat::Csv;
+    }
+
+    match data.iter().find(|b| !b.is_ascii_whitespace()) {
+        Some(b'{') => UploadFormat::Ndjson,
+        _ => UploadFormat::Csv,
+    }
+}
+
+/// 시각 파싱.
+///
+/// RFC 3339, Unix 초/밀리초, `YYYY-MM-DD HH:MM[:SS]`, `YYYY-MM-DD`, `YYYYMMDD`를 지원합니다.
+/// 시간대가 없는 값은 `offset` 기준으로 해석합니다.
+fn parse_time(value: &str, offset: &FixedOffset) -> Option<DateTime<Utc>> {
+    let value = value.trim();
+
+    if let Ok(dt) = DateTime::parse_from_rfc3339(value) {
+        return Some(dt.with_timezone(&Utc));
+    }
+    // 8자리 숫자는 Unix 시각보다 YYYYMMDD로 해석
+    if value.len() != 8 {
+        if let Ok(epoch) = value.parse::<i64>() {
+            return if epoch.abs() >= 100_000_000_000 {
+                DateTime::from_timestamp_millis(epoch)
+            } else {
+                DateTime::from_timestamp(epoch, 0)
+            };
+        }
+    }
+
+    const DATETIME_FORMATS: &[&str] = &[
+        "%Y-%m-%d %H:%M:%S",
+        "%Y-%m-%dT%H:%M:%S",
+        "%Y-%m-%d %H:%M",
+        "%Y/%m/%d %H:%M:%S",
+        "%Y/%m/%d %H:%M",
+        "%Y%m%d %H%M%S",
+        "%Y%m%d %H:%M:%S",
+    ];
+    let naive = DATETIME_FORMATS
+        .iter()
+        .find_map(|fmt| NaiveDateTime::parse_from_str(value, fmt).ok())
+        .or_else(|| {
+            ["%Y-%m-%d", "%Y/%m/%d", "%Y%m%d"]
+                .iter()
+                .find_map(|fmt| NaiveDate::parse_from_str(value, fmt).ok())
+                .and_then(|d| d.and_hms_opt(0, 0, 0))
+        })?;
+
+    offset
+        .from_local_datetime(&naive)
+        .single()
+        .map(|dt| dt.with_timezone(&Utc))
+}
+
+/// UTC 오프셋 파싱 ("+09:00", "-0500", "Z").
+fn parse_offset(value: Option<&str>) -> Result<FixedOffset, String> {
+    let Some(value) = value.map(str::trim).filter(|v| !v.is_empty()) else {
+        return Ok(FixedOffset::east_opt(0).expect("valid offset"));
+    };
+    if value.eq_ignore_ascii_case("z") || value.eq_ignore_ascii_case("utc") {
+        return Ok(FixedOffset::east_opt(0).expect("valid offset"));
+    }
+    FixedOffset::from_str(value).map_err(|_| format!("잘못된 UTC 오프셋입니다: {}", value))
+}
+
+fn parse_decimal(field: &str, value: Option<&str>) -> Result<Decimal, String> {
+    let value = value
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+        .ok_or_else(|| format!("{} 값이 없습니다", field))?;
+    Decimal::from_str(value)
+        .or_else(|_| Decimal::from_scientific(value))
+        .map_err(|_| format!("{} 값이 숫자가 아닙니다: {}", field, value))
+}
+
+/// 원시 행 → 캔들 변환 및 검증.
+fn build_kline(
+    raw: &RawRow,
+    symbol: &str,
+    timeframe: &str,
+    offset: &FixedOffset,
+    now: DateTime<Utc>,
+) -> Result<NewKline, String> {
+    let time_str = raw.time.as_deref().ok_or("시각 값이 없습니다")?;
+    let open_time =
+        parse_time(time_str, offset).ok_or_else(|| format!("시각 형식 오류: {}", time_str))?;
+    if open_time > now + chrono::Duration::days(1) {
+        return Err(format!("미래 시각입니다: {}", open_time));
+    }
+
+    let open = parse_decimal("open", raw.open.as_deref())?;
+    let high = parse_decimal("high", raw.high.as_deref())?;
+    let low = parse_decimal("low", raw.low.as_deref())?;
+    let close = parse_decimal("close", raw.close.as_deref())?;
+    let volume = parse_decimal("volume", raw.volume.as_deref())?;
+
+    if open <= Decimal::ZERO
+        || high <= Decimal::ZERO
+        || low <= Decimal::ZERO
+        || close <= Decimal::ZERO
+    {
+        return Err("가격은 0보다 커야 합니다".to_string());
+    }
+    if volume < Decimal::ZERO {
+        return Err("거래량은 음수일 수 없습니다".to_string());
+    }
+    if high < open.max(close).max(low) || low > open.min(close) {
+        return Err(format!(
+            "OHLC 범위 오류 (open={}, high={}, low={}, close={})",
+            open, high, low, close
+        ));
+    }
+
+    Ok(NewKline {
+        symbol: symbol.to_string(),
+        timeframe: timeframe.to_string(),
+        open_time,
+        open,
+        high,
+        low,
+        close,
+        volume,
+        close_time: None,
+    })
+}
+
+/// 행 번호와 파싱 결과.
+type NumberedRow = (u64, Result<RawRow, String>);
+
+/// CSV 행 읽기 (헤더 필수).
+fn read_csv_rows(data: &[u8]) -> Result<Vec<NumberedRow>, String> {
+    let mut reader = csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .flexible(true)
+        .from_reader(data);
+
+    let columns: Vec<Option<&'static str>> = reader
+        .headers()
+        .map_err(|e| format!("CSV 헤더를 읽을 수 없습니다: {}", e))?
+        .iter()
+        .map(normalize_column)
+        .collect();
+    for required in ["time", "open", "high", "low", "close", "volume"] {
+        if !columns.contains(&Some(required)) {
+            return Err(format!("CSV 헤더에 '{}' 컬럼이 없습니다", required));
+        }
+    }
+
+    let mut rows = Vec::new();
+    for record in reader.records() {
+        match record {
+            Ok(record) => {
+                let line = record.position().map(|p| p.line()).unwrap_or(0);
+                let mut raw = RawRow::default();
+                for (column, value) in columns.iter().zip(record.iter()) {
+                    if let Some(field) = column {
+                        raw.set(field, value.to_string());
+                    }
+                }
+                rows.push((line, Ok(raw)));
+            }
+            Err(e) => {
+                let line = e.position().map(|p| p.line()).unwrap_or(0);
+                rows.push((line, Err(format!("CSV 행 오류: {}", e))));
+            }
+        }
+    }
+    Ok(rows)
+}
+
+/// NDJSON 행 읽기 (빈 줄 무시).
+fn read_ndjson_rows(data: &[u8]) -> Vec<NumberedRow> {
+    String::from_utf8_lossy(data)
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(idx, line)| {
+            let row = serde_json::from_str::<serde_json::Map<String, Value>>(line)
+                .map_err(|e| format!("JSON 파싱 오류: {}", e))
+                .map(|object| {
+                    let mut raw = RawRow::default();
+                    for (key, value) in object {
+                        let Some(field) = normalize_column(&key) else {
+                            continue;
+                        };
+                        let text = match value {
+                            Value::String(s) => s,
+                            Value::Number(n) => n.to_string(),
+                            _ => continue,
+                        };
+                        raw.set(field, text);
+                    }
+                    raw
+                });
+            (idx as u64 + 1, row)
+        })
+        .collect()
+}
+
+/// 업로드 본문 파싱, 검증, 중복 제거.
+fn parse_upload(
+    data: &[u8],
+    format: UploadFormat,
+    symbol: &str,
+    timeframe: &str,
+    offset: &FixedOffset,
+    now: DateTime<Utc>,
+) -> Result<ParsedUpload, String> {
+    let rows = match format {
+        UploadFormat::Csv => read_csv_rows(data)?,
+        UploadFormat::Ndjson => read_ndjson_rows(data),
+    };
+
+    let mut parsed = ParsedUpload::default();
+    let mut by_time: BTreeMap<DateTime<Utc>, NewKline> = BTreeMap::new();
+    for (line, row) in rows {
+        parsed.received_rows += 1;
+        match row.and_then(|raw| build_kline(&raw, symbol, timeframe, offset, now)) {
+            Ok(kline) => {
+                // 같은 시각은 파일 뒤쪽 행이 우선
+                if by_time.insert(kline.open_time, kline).is_some() {
+                    parsed.duplicate_rows += 1;
+                }
+            }
+            Err(message) => parsed.reject(line, message),
+        }
+    }
+
+    parsed.klines = by_time.into_values().collect();
+    Ok(parsed)
+}
+
+// ==================== 핸들러 ====================
+
+fn bad_request(code: &str, message: impl Into<String>) -> (StatusCode, Json<ApiError>) {
+    (StatusCode::BAD_REQUEST, Json(ApiError::new(code, message)))
+}
+
+/// 캔들 대량 upsert.
+///
+/// POST /api/v1/dataset/klines:bulk
+///
+/// # 쿼리
+/// - `symbol`, `timeframe`: 대상 심볼과 타임프레임
+/// - `format`: `csv` | `ndjson` (선택)
+/// - `utcOffset`: 시간대 없는 시각의 오프셋 (선택, 예: `+09:00`)
+/// - `strict`: 잘못된 행이 있으면 전체 거부 (기본 false)
+pub async fn bulk_upsert_klines(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<BulkKlineQuery>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Result<Json<BulkKlineUploadResponse>, (StatusCode, Json<ApiError>)> {
+    let pool = state.db_pool.as_ref().ok_or_else(|| {
+        (
+            StatusCode::SERVICE_UNAVAILABLE,
+            Json(ApiError::new(
+                "DB_NOT_AVAILABLE",
+                "데이터베이스 연결이 없습니다",
+            )),
+        )
+    })?;
+
+    let symbol = query.symbol.trim();
+    if symbol.is_empty() {
+        return Err(bad_request("INVALID_SYMBOL", "심볼이 필요합니다"));
+    }
+    let timeframe = match query.timeframe.trim() {
+        "1w" | "w" => "1wk".to_string(),
+        "1M" | "M" => "1mo".to_string(),
+        tf => tf.to_lowercase(),
+    };
+    if !SUPPORTED_TIMEFRAMES.contains(&timeframe.as_str()) {
+        return Err(bad_request(
+            "INVALID_TIMEFRAME",
+            format!("지원하지 않는 타임프레임입니다: {}", query.timeframe),
+        ));
+    }
+    let offset = parse_offset(query.utc_offset.as_deref())
+        .map_err(|e| bad_request("INVALID_UTC_OFFSET", e))?;
+    if body.is_empty() {
+        return Err(bad_request("EMPTY_BODY", "업로드 데이터가 비어 있습니다"));
+    }
+
+    // OHLCV 테이블은 ticker로 저장 (등록되지 않은 심볼은 입력값 그대로)
+    let ticker = match SymbolInfoRepository::get_by_ticker(pool, symbol, None).await {
+        Ok(Some(info)) => info.ticker,
+        _ => symbol.to_string(),
+    };
+
+    let data = decode_body(&body).map_err(|e| bad_request("INVALID_GZIP", e))?;
+    let format = detect_format(query.format, &headers, &data);
+    let parsed = parse_upload(&data, format, &ticker, &timeframe, &offset, Utc::now())
+        .map_err(|e| bad_request("INVALID_FORMAT", e))?;
+
+    if query.strict && parsed.rejected_rows > 0 {
+        return Err((
+            StatusCode::UNPROCESSABLE_ENTITY,
+            Json(ApiError::new(
+                "INVALID_ROWS",
+                format!(
+                    "{}개 행이 검증에 실패했습니다 (첫 오류: {}행 {})",
+                    parsed.rejected_rows, parsed.errors[0].line, parsed.errors[0].message
+                ),
+            )),
+        ));
+    }
+
+    let mut upserted = 0;
+    for chunk in parsed.klines.chunks(UPSERT_CHUNK_SIZE) {
+        upserted += KlinesRepository::save_batch(pool, chunk)
+            .await
+            .map_err(|e| {
+                error!(symbol = %ticker, error = %e, "캔들 대량 저장 실패");
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(ApiError::new(
+                        "DB_ERROR",
+                        format!("캔들 저장 실패 ({}개 저장 후 중단): {}", upserted, e),
+                    )),
+                )
+            })?;
+    }
+
+    let total_candles = if upserted > 0 {
+        match KlinesRepository::refresh_metadata(pool, &ticker, &timeframe).await {
+            Ok(metadata) => metadata.and_then(|m| m.total_candles),
+            Err(e) => {
+                error!(symbol = %ticker, error = %e, "캔들 메타데이터 갱신 실패");
+                None
+            }
+        }
+    } else {
+        None
+    };
+
+    info!(
+        symbol = %ticker,
+        timeframe = %timeframe,
+        received = parsed.received_rows,
+        rejected = parsed.rejected_rows,
+        upserted,
+        "캔들 대량 업로드 완료"
+    );
+
+    Ok(Json(BulkKlineUploadResponse {
+        symbol: ticker,
+        timeframe,
+        format,
+        received_rows: parsed.received_rows,
+        rejected_rows: parsed.rejected_rows,
+        duplicate_rows: parsed.duplicate_rows,
+        upserted,
+        first_time: parsed.klines.first().map(|k| k.open_time),
+        last_time: parsed.klines.last().map(|k| k.open_time),
+        total_candles,
+        errors: parsed.errors,
+    }))
+}
+
+// ==================== 테스트 ====================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::write::GzEncoder;
+    use flate2::Compression;
+    use rust_decimal_macros::dec;
+    use std::io::Write;
+
+    fn utc() -> FixedOffset {
+        FixedOffset::east_opt(0).unwrap()
+    }
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 3, 10, 0, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_parse_csv_with_dedup_and_validation() {
+        let csv = "Date,Open,High,Low,Close,Volume\n\
+                   2026-03-02 09:01,100,105,99,104,1000\n\
+                   2026-03-02 09:00,98,101,97,100,800\n\
+                   2026-03-02 09:01,100,106,99,105,1200\n\
+                   2026-03-02 09:02,100,99,98,101,10\n\
+                   2026-03-02 09:03,abc,101,97,100,800\n";
+        let kst = FixedOffset::east_opt(9 * 3600).unwrap();
+
+        let parsed = parse_upload(
+            csv.as_bytes(),
+            UploadFormat::Csv,
+            "005930",
+            "1m",
+            &kst,
+            now(),
+        )
+        .unwrap();
+
+        assert_eq!(parsed.received_rows, 5);
+        assert_eq!(parsed.duplicate_rows, 1);
+        assert_eq!(parsed.rejected_rows, 2);
+        assert_eq!(parsed.errors[0].line, 5);
+        assert_eq!(parsed.klines.len(), 2);
+        // 시각순 정렬, KST → UTC
+        assert_eq!(
+            parsed.klines[0].open_time,
+            Utc.with_ymd_and_hms(2026, 3, 2, 0, 0, 0).unwrap()
+        );
+        // 중복 시각은 뒤쪽 행 사용
+        assert_eq!(parsed.klines[1].close, dec!(105));
+    }
+
+    #[test]
+    fn test_parse_ndjson_with_epoch_millis() {
+        let ndjson = r#"{"ts": 1772409600000, "o": 10.5, "h": 11, "l": 10, "c": 10.8, "v": 300}
+
+{"timestamp": "2026-03-02T00:01:00Z", "open": "10.8", "high": "10.9", "low": "10.7", "close": "10.7", "volume": "120"}
+{"timestamp": "2026-03-02T00:02:00Z", "open": 1}
+"#;
+
+        let parsed = parse_upload(
+            ndjson.as_bytes(),
+            UploadFormat::Ndjson,
+            "AAPL",
+            "1m",
+            &utc(),
+            now(),
+        )
+        .unwrap();
+
+        assert_eq!(parsed.received_rows, 3);
+        assert_eq!(parsed.klines.len(), 2);
+        assert_eq!(parsed.klines[0].open, dec!(10.5));
+        assert_eq!(parsed.rejected_rows, 1);
+        assert_eq!(parsed.errors[0].line, 4);
+    }
+
+    #[test]
+    fn test_csv_missing_column_is_rejected() {
+        let csv = "time,open,high,low,close\n2026-03-02,1,1,1,1\n";
+        let result = parse_upload(
+            csv.as_bytes(),
+            UploadFormat::Csv,
+            "SPY",
+            "1d",
+            &utc(),
+            now(),
+        );
+        assert!(result.unwrap_err().contains("volume"));
+    }
+
+    #[test]
+    fn test_decode_gzip_body_and_detect_format() {
+        let ndjson = br#"{"time":"2026-03-02","open":1,"high":1,"low":1,"close":1,"volume":0}"#;
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(ndjson).unwrap();
+        let gzipped = encoder.finish().unwrap();
+
+        let decoded = decode_body(&gzipped).unwrap();
+        assert_eq!(decoded, ndjson);
+        assert_eq!(
+            detect_format(None, &HeaderMap::new(), &decoded),
+            UploadFormat::Ndjson
+        );
+        assert_eq!(decode_body(b"time,open").unwrap(), b"time,open");
+    }
+
+    #[test]
+    fn test_parse_time_formats() {
+        let kst = FixedOffset::east_opt(9 * 3600).unwrap();
+        let expected = Utc.with_ymd_and_hms(2026, 3, 1, 15, 0, 0).unwrap();
+
+        assert_eq!(parse_time("20260302", &kst), Some(expected));
+        assert_eq!(parse_time("2026-03-02 00:00:00", &kst), Some(expected));
+        assert_eq!(parse_time("2026-03-01T15:00:00Z", &kst), Some(expected));
+        assert_eq!(parse_time("1772377200", &kst), Some(expected));
+        assert_eq!(parse_time("not a date", &kst), None);
+        assert!(parse_offset(Some("+09:00")).is_ok());
+        assert!(parse_offset(Some("KST")).is_err());
+    }
+}
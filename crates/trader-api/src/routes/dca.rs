@@ -0,0 +1,422 @@
+//! 정액 적립식(DCA) 계획 endpoint.
+//!
+//! 주기마다 고정 금액을 선택 종목에 비중대로 나눠 매수하는 계획을 관리합니다.
+//! 실제 매수는 `services::dca_scheduler`가 일반 주문과 같은 리스크/실행 경로로 처리하며,
+//! 누적 현황은 `GET /api/v1/journal/dca`에서 별도로 조회합니다.
+//!
+//! # 엔드포인트
+//!
+//! - `GET /api/v1/dca` - 적립 계획 목록
+//! - `POST /api/v1/dca` - 적립 계획 등록
+//! - `GET /api/v1/dca/{id}` - 적립 계획 조회
+//! - `PUT /api/v1/dca/{id}` - 적립 계획 수정
+//! - `DELETE /api/v1/dca/{id}` - 적립 계획 삭제 (실행 이력 포함)
+//! - `GET /api/v1/dca/{id}/executions` - 회차별 매수 이력
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    routing::get,
+    Json, Router,
+};
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::sync::Arc;
+use tracing::info;
+use trader_execution::{DcaAllocation, DcaCadence, DcaMode};
+use uuid::Uuid;
+
+use super::common::{db_conflict_response, db_error_response, require_pool};
+use crate::error::{ApiErrorResponse, ApiResult};
+use crate::repository::{DcaExecutionRecord, DcaPlanInput, DcaPlanRecord, DcaRepository};
+use crate::state::AppState;
+
+/// 계획 하나에 지정할 수 있는 최대 종목 수.
+const MAX_ALLOCATIONS: usize = 20;
+
+// ==================== 요청/응답 타입 ====================
+
+/// 적립 계획 등록/수정 요청.
+#[derive(Debug, Deserialize)]
+pub struct DcaPlanRequest {
+    /// 계획 이름 (고유)
+    pub name: String,
+    /// 회차 총 투자 금액
+    pub amount: Decimal,
+    /// 통화 (KRW, USD; 기본값: KRW)
+    #[serde(default = "default_currency")]
+    pub currency: String,
+    /// 종목 비중
+    pub allocations: Vec<DcaAllocation>,
+    /// 적립 주기
+    pub cadence: DcaCadence,
+    /// 금액 결정 방식 (기본값: fixed)
+    #[serde(default)]
+    pub mode: DcaMode,
+    /// 기본 금액 대비 최대 배수 (기본값: 3)
+    #[serde(default = "default_max_multiplier")]
+    pub max_multiplier: Decimal,
+    /// 전략 ID (전략 예산/비용 모델 적용)
+    pub strategy_id: Option<String>,
+    /// 활성화 여부 (기본값: true)
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    /// 첫 실행 시각 (등록 시 생략하면 즉시, 수정 시 생략하면 기존 일정 유지)
+    pub start_at: Option<DateTime<Utc>>,
+}
+
+fn default_currency() -> String {
+    "KRW".to_string()
+}
+
+fn default_max_multiplier() -> Decimal {
+    Decimal::from(3)
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+/// 실행 이력 조회 쿼리.
+#[derive(Debug, Deserialize)]
+pub struct DcaExecutionsQuery {
+    /// 최대 건수 (기본값: 100)
+    #[serde(default = "default_list_limit")]
+    pub limit: i64,
+}
+
+fn default_list_limit() -> i64 {
+    100
+}
+
+/// 적립 계획 목록 응답.
+#[derive(Debug, Serialize)]
+pub struct DcaPlansListResponse {
+    pub total: usize,
+    /// 적립 계획 (이름순)
+    pub plans: Vec<DcaPlanRecord>,
+}
+
+/// 실행 이력 응답.
+#[derive(Debug, Serialize)]
+pub struct DcaExecutionsResponse {
+    pub plan_id: Uuid,
+    pub total: usize,
+    /// 회차별 종목 매수 (최신순)
+    pub executions: Vec<DcaExecutionRecord>,
+}
+
+// ==================== 헬퍼 ====================
+
+fn name_conflict_response(err: sqlx::Error) -> (StatusCode, Json<ApiErrorResponse>) {
+    db_conflict_response(err, "DCA_PLAN_NAME_CONFLICT", "DCA plan name already exists")
+}
+
+fn not_found(id: Uuid) -> (StatusCode, Json<ApiErrorResponse>) {
+    (
+        StatusCode::NOT_FOUND,
+        Json(ApiErrorResponse::new(
+            "DCA_PLAN_NOT_FOUND",
+            format!("DCA plan not found: {}", id),
+        )),
+    )
+}
+
+/// 요청 검증 후 저장 입력으로 변환.
+///
+/// # Arguments
+/// * `default_next_run_at` - `start_at`이 없을 때 사용할 다음 실행 시각
+#[allow(clippy::result_large_err)]
+fn validate_request(
+    request: DcaPlanRequest,
+    default_next_run_at: DateTime<Utc>,
+) -> Result<DcaPlanInput, (StatusCode, Json<ApiErrorResponse>)> {
+    let invalid = |msg: String| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(ApiErrorResponse::new("INVALID_DCA_PLAN", msg)),
+        )
+    };
+
+    let name = request.name.trim().to_string();
+    if name.is_empty() {
+        return Err(invalid("name is required".to_string()));
+    }
+    if request.amount <= Decimal::ZERO {
+        return Err(invalid("amount must be positive".to_string()));
+    }
+
+    let currency = request.currency.trim().to_uppercase();
+    if currency != "KRW" && currency != "USD" {
+        return Err(invalid(format!(
+            "Unsupported currency: {} (KRW or USD)",
+            currency
+        )));
+    }
+
+    if request.allocations.is_empty() {
+        return Err(invalid("at least one allocation is required".to_string()));
+    }
+    if request.allocations.len() > MAX_ALLOCATIONS {
+        return Err(invalid(format!(
+            "at most {} allocations are allowed",
+            MAX_ALLOCATIONS
+        )));
+    }
+    let mut seen = HashSet::new();
+    let mut allocations = Vec::with_capacity(request.allocations.len());
+    for allocation in request.allocations {
+        let ticker = allocation.ticker.trim().to_uppercase();
+        if ticker.is_empty() {
+            return Err(invalid("allocation ticker is required".to_string()));
+        }
+        if allocation.weight <= Decimal::ZERO {
+            return Err(invalid(format!("weight for {} must be positive", ticker)));
+        }
+        if !seen.insert(ticker.clone()) {
+            return Err(invalid(format!("duplicate allocation: {}", ticker)));
+        }
+        allocations.push(DcaAllocation {
+            ticker,
+            weight: allocation.weight,
+        });
+    }
+
+    request.mode.validate().map_err(invalid)?;
+    if request.max_multiplier < Decimal::ONE {
+        return Err(invalid("max_multiplier must be at least 1".to_string()));
+    }
+
+    Ok(DcaPlanInput {
+        name,
+        amount: request.amount,
+        currency,
+        allocations: serde_json::to_value(&allocations).unwrap_or_default(),
+        cadence: request.cadence.as_str().to_string(),
+        mode: serde_json::to_value(&request.mode).unwrap_or_default(),
+        max_multiplier: request.max_multiplier,
+        strategy_id: request.strategy_id.filter(|s| !s.trim().is_empty()),
+        enabled: request.enabled,
+        next_run_at: request.start_at.unwrap_or(default_next_run_at),
+    })
+}
+
+// ==================== 핸들러 ====================
+
+/// 적립 계획 목록 조회.
+///
+/// GET /api/v1/dca
+pub async fn list_dca_plans(
+    State(state): State<Arc<AppState>>,
+) -> ApiResult<Json<DcaPlansListResponse>> {
+    let pool = require_pool(&state)?;
+    let plans = DcaRepository::list(pool).await.map_err(db_error_response)?;
+
+    Ok(Json(DcaPlansListResponse {
+        total: plans.len(),
+        plans,
+    }))
+}
+
+/// 적립 계획 등록.
+///
+/// POST /api/v1/dca
+pub async fn create_dca_plan(
+    State(state): State<Arc<AppState>>,
+    Json(request): Json<DcaPlanRequest>,
+) -> ApiResult<(StatusCode, Json<DcaPlanRecord>)> {
+    let input = validate_request(request, Utc::now())?;
+    let pool = require_pool(&state)?;
+
+    let record = DcaRepository::create(pool, &input)
+        .await
+        .map_err(name_conflict_response)?;
+
+    info!(
+        id = %record.id,
+        name = %record.name,
+        amount = %record.amount,
+        cadence = %record.cadence,
+        "적립 계획 등록"
+    );
+    Ok((StatusCode::CREATED, Json(record)))
+}
+
+/// 적립 계획 조회.
+///
+/// GET /api/v1/dca/{id}
+pub async fn get_dca_plan(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Json<DcaPlanRecord>> {
+    let pool = require_pool(&state)?;
+    DcaRepository::get(pool, id)
+        .await
+        .map_err(db_error_response)?
+        .map(Json)
+        .ok_or_else(|| not_found(id))
+}
+
+/// 적립 계획 수정.
+///
+/// PUT /api/v1/dca/{id}
+pub async fn update_dca_plan(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+    Json(request): Json<DcaPlanRequest>,
+) -> ApiResult<Json<DcaPlanRecord>> {
+    let pool = require_pool(&state)?;
+    let existing = DcaRepository::get(pool, id)
+        .await
+        .map_err(name_conflict_response)?
+        .ok_or_else(|| not_found(id))?;
+
+    let input = validate_request(request, existing.next_run_at)?;
+    DcaRepository::update(pool, id, &input)
+        .await
+        .map_err(name_conflict_response)?
+        .map(Json)
+        .ok_or_else(|| not_found(id))
+}
+
+/// 적립 계획 삭제.
+///
+/// DELETE /api/v1/dca/{id}
+pub async fn delete_dca_plan(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<StatusCode> {
+    let pool = require_pool(&state)?;
+    if DcaRepository::delete(pool, id)
+        .await
+        .map_err(db_error_response)?
+    {
+        info!(%id, "적립 계획 삭제");
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err(not_found(id))
+    }
+}
+
+/// 회차별 매수 이력 조회.
+///
+/// GET /api/v1/dca/{id}/executions
+pub async fn list_dca_executions(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<DcaExecutionsQuery>,
+) -> ApiResult<Json<DcaExecutionsResponse>> {
+    let pool = require_pool(&state)?;
+    let executions = DcaRepository::list_executions(pool, id, query.limit.clamp(1, 1000))
+        .await
+        .map_err(db_error_response)?;
+
+    Ok(Json(DcaExecutionsResponse {
+        plan_id: id,
+        total: executions.len(),
+        executions,
+    }))
+}
+
+// ==================== 라우터 ====================
+
+/// 정액 적립식 라우터 생성.
+pub fn dca_router() -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/", get(list_dca_plans).post(create_dca_plan))
+        .route(
+            "/{id}",
+            get(get_dca_plan)
+                .put(update_dca_plan)
+                .delete(delete_dca_plan),
+        )
+        .route("/{id}/executions", get(list_dca_executions))
+}
+
+// ==================== 테스트 ====================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::create_test_state;
+    use axum::body::Body;
+    use axum::http::Request;
+    use tower::ServiceExt;
+
+    fn request(body: serde_json::Value) -> DcaPlanRequest {
+        serde_json::from_value(body).unwrap()
+    }
+
+    #[test]
+    fn test_validate_request() {
+        let now = Utc::now();
+        let input = validate_request(
+            request(serde_json::json!({
+                "name": " 월 적립 ",
+                "amount": 500000,
+                "allocations": [
+                    {"ticker": "069500", "weight": 3},
+                    {"ticker": "btc/krw", "weight": 1}
+                ],
+                "cadence": "monthly",
+                "mode": {"type": "dip_boost", "drawdown_pct": 10, "multiplier": 2}
+            })),
+            now,
+        )
+        .unwrap();
+        assert_eq!(input.name, "월 적립");
+        assert_eq!(input.currency, "KRW");
+        assert_eq!(input.cadence, "monthly");
+        assert_eq!(input.next_run_at, now);
+        assert_eq!(input.allocations[1]["ticker"], "BTC/KRW");
+        assert_eq!(input.mode["type"], "dip_boost");
+
+        // 지원하지 않는 통화, 중복 종목, 잘못된 하락 기준
+        for body in [
+            serde_json::json!({
+                "name": "a", "amount": 100, "currency": "JPY", "cadence": "weekly",
+                "allocations": [{"ticker": "AAPL", "weight": 1}]
+            }),
+            serde_json::json!({
+                "name": "a", "amount": 100, "cadence": "weekly",
+                "allocations": [{"ticker": "AAPL", "weight": 1}, {"ticker": "aapl", "weight": 1}]
+            }),
+            serde_json::json!({
+                "name": "a", "amount": 100, "cadence": "weekly",
+                "allocations": [{"ticker": "AAPL", "weight": 1}],
+                "mode": {"type": "dip_boost", "drawdown_pct": 0, "multiplier": 2}
+            }),
+        ] {
+            let (status, _) = validate_request(request(body), now).unwrap_err();
+            assert_eq!(status, StatusCode::BAD_REQUEST);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_dca_plan_rejects_empty_allocations() {
+        let app = dca_router().with_state(Arc::new(create_test_state()));
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/")
+                    .header("content-type", "application/json")
+                    .body(Body::from(
+                        serde_json::json!({
+                            "name": "empty",
+                            "amount": 100000,
+                            "cadence": "weekly",
+                            "allocations": []
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+}
@@ -0,0 +1,294 @@
+//! 실적 발표 일정 endpoint.
+//!
+//! 수집기가 Nasdaq/DART에서 적재한 실적 발표 (예정)일을 조회하고,
+//! 전략 엔진의 실적 발표 필터에 반영하는 REST API를 제공합니다.
+//! 필터 설정은 전략별로 `PUT /api/v1/strategies/{id}/earnings-filter`에서 변경합니다.
+//!
+//! # 엔드포인트
+//!
+//! - `GET /api/v1/earnings/upcoming` - 기간 내 발표 일정 (발표일 순)
+//! - `POST /api/v1/earnings/reload` - 발표 일정을 전략 엔진에 반영
+//! - `POST /api/v1/earnings` - 발표 일정 수동 등록
+//! - `DELETE /api/v1/earnings/{market}/{ticker}/{date}` - 발표 일정 삭제
+//! - `GET /api/v1/earnings/{ticker}/next` - 엔진에 반영된 종목의 다음 발표 일정
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    routing::{delete, get, post},
+    Json, Router,
+};
+use chrono::{Duration, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use std::sync::Arc;
+use tracing::info;
+use trader_core::{EarningsEvent, EarningsTiming};
+use trader_data::EarningsCalendarStore;
+use trader_strategy::StrategyEngine;
+
+use super::common::db_unavailable;
+use crate::error::{ApiErrorResponse, ApiResult};
+use crate::state::AppState;
+
+/// 전략 엔진에 반영하는 발표 일정 기간 (일)
+const ENGINE_CALENDAR_HORIZON_DAYS: i64 = 60;
+
+// ==================== 요청/응답 타입 ====================
+
+/// 발표 일정 조회 쿼리.
+#[derive(Debug, Deserialize)]
+pub struct UpcomingEarningsQuery {
+    /// 조회 기간 (일, 기본: 14)
+    #[serde(default = "default_upcoming_days")]
+    pub days: i64,
+    /// 시장 필터 (KR, US)
+    pub market: Option<String>,
+}
+
+fn default_upcoming_days() -> i64 {
+    14
+}
+
+/// 발표 일정 응답.
+#[derive(Debug, Serialize)]
+pub struct UpcomingEarningsResponse {
+    /// 조회 시작일
+    pub from: NaiveDate,
+    /// 조회 종료일
+    pub to: NaiveDate,
+    /// 조회된 일정 수
+    pub total: usize,
+    /// 발표 일정 (발표일 순)
+    pub events: Vec<EarningsEvent>,
+}
+
+/// 발표 일정 수동 등록 요청.
+#[derive(Debug, Deserialize)]
+pub struct CreateEarningsRequest {
+    /// 종목코드
+    pub ticker: String,
+    /// 시장 (KR, US)
+    pub market: String,
+    /// 발표일
+    pub announce_date: NaiveDate,
+    /// 발표 시점
+    #[serde(default)]
+    pub timing: EarningsTiming,
+}
+
+/// 발표 일정 반영 응답.
+#[derive(Debug, Serialize)]
+pub struct EarningsReloadResponse {
+    /// 전략 엔진에 일정이 반영된 종목 수
+    pub loaded: usize,
+}
+
+/// 종목 다음 발표 일정 응답.
+#[derive(Debug, Serialize)]
+pub struct NextEarningsResponse {
+    /// 종목코드
+    pub ticker: String,
+    /// 다음 발표 일정 (없으면 null)
+    pub event: Option<EarningsEvent>,
+    /// 발표일까지 남은 일수
+    pub days_until: Option<i64>,
+}
+
+// ==================== 헬퍼 ====================
+
+fn data_error_response(err: trader_data::DataError) -> (StatusCode, Json<ApiErrorResponse>) {
+    (
+        StatusCode::INTERNAL_SERVER_ERROR,
+        Json(ApiErrorResponse::new("DB_ERROR", err.to_string())),
+    )
+}
+
+/// DB의 발표 일정을 전략 엔진에 반영.
+pub async fn load_earnings_calendar(
+    pool: &PgPool,
+    engine: &StrategyEngine,
+) -> trader_data::Result<usize> {
+    let today = Utc::now().date_naive();
+    let events = EarningsCalendarStore::new(pool.clone())
+        .upcoming(
+            today,
+            today + Duration::days(ENGINE_CALENDAR_HORIZON_DAYS),
+            None,
+        )
+        .await?;
+    Ok(engine.update_earnings_calendar(&events, today).await)
+}
+
+// ==================== 핸들러 ====================
+
+/// 기간 내 발표 일정 조회.
+///
+/// GET /api/v1/earnings/upcoming
+pub async fn get_upcoming_earnings(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<UpcomingEarningsQuery>,
+) -> ApiResult<Json<UpcomingEarningsResponse>> {
+    let pool = state.db_pool.as_ref().ok_or_else(db_unavailable)?;
+    let from = Utc::now().date_naive();
+    let to = from + Duration::days(query.days.clamp(1, 365));
+
+    let events = EarningsCalendarStore::new(pool.clone())
+        .upcoming(from, to, query.market.as_deref())
+        .await
+        .map_err(data_error_response)?;
+
+    Ok(Json(UpcomingEarningsResponse {
+        from,
+        to,
+        total: events.len(),
+        events,
+    }))
+}
+
+/// 발표 일정을 전략 엔진에 반영.
+///
+/// POST /api/v1/earnings/reload
+pub async fn reload_earnings_calendar(
+    State(state): State<Arc<AppState>>,
+) -> ApiResult<Json<EarningsReloadResponse>> {
+    let pool = state.db_pool.as_ref().ok_or_else(db_unavailable)?;
+
+    let engine = state.strategy_engine.read().await;
+    let loaded = load_earnings_calendar(pool, &engine)
+        .await
+        .map_err(data_error_response)?;
+
+    info!(loaded, "실적 발표 일정 전략 엔진 반영");
+    Ok(Json(EarningsReloadResponse { loaded }))
+}
+
+/// 발표 일정 수동 등록.
+///
+/// POST /api/v1/earnings
+///
+/// 수집 소스가 없는 종목이나 추정 일정 보정에 사용합니다. 확정 일정으로 저장되며,
+/// 엔진 반영은 `POST /api/v1/earnings/reload` 호출 시 이루어집니다.
+pub async fn create_earnings_event(
+    State(state): State<Arc<AppState>>,
+    Json(request): Json<CreateEarningsRequest>,
+) -> ApiResult<(StatusCode, Json<EarningsEvent>)> {
+    let pool = state.db_pool.as_ref().ok_or_else(db_unavailable)?;
+
+    let ticker = request.ticker.trim().to_uppercase();
+    let market = request.market.trim().to_uppercase();
+    if ticker.is_empty() || !matches!(market.as_str(), "KR" | "US") {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiErrorResponse::new(
+                "INVALID_EARNINGS_EVENT",
+                "ticker is required and market must be KR or US",
+            )),
+        ));
+    }
+
+    let event = EarningsEvent {
+        ticker,
+        market,
+        announce_date: request.announce_date,
+        timing: request.timing,
+        confirmed: true,
+        source: "manual".to_string(),
+    };
+    EarningsCalendarStore::new(pool.clone())
+        .upsert_events(std::slice::from_ref(&event))
+        .await
+        .map_err(data_error_response)?;
+
+    Ok((StatusCode::CREATED, Json(event)))
+}
+
+/// 발표 일정 삭제.
+///
+/// DELETE /api/v1/earnings/{market}/{ticker}/{date}
+pub async fn delete_earnings_event(
+    State(state): State<Arc<AppState>>,
+    Path((market, ticker, date)): Path<(String, String, NaiveDate)>,
+) -> ApiResult<StatusCode> {
+    let pool = state.db_pool.as_ref().ok_or_else(db_unavailable)?;
+    let deleted = EarningsCalendarStore::new(pool.clone())
+        .delete_event(&ticker.to_uppercase(), &market.to_uppercase(), date)
+        .await
+        .map_err(data_error_response)?;
+
+    if deleted {
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err((
+            StatusCode::NOT_FOUND,
+            Json(ApiErrorResponse::new(
+                "NOT_FOUND",
+                format!("Earnings event {}/{} on {} not found", market, ticker, date),
+            )),
+        ))
+    }
+}
+
+/// 엔진에 반영된 종목의 다음 발표 일정 조회.
+///
+/// GET /api/v1/earnings/{ticker}/next
+pub async fn get_next_earnings(
+    State(state): State<Arc<AppState>>,
+    Path(ticker): Path<String>,
+) -> ApiResult<Json<NextEarningsResponse>> {
+    let event = state
+        .strategy_engine
+        .read()
+        .await
+        .get_next_earnings(&ticker)
+        .await;
+    let days_until = event
+        .as_ref()
+        .map(|e| e.days_until(Utc::now().date_naive()));
+
+    Ok(Json(NextEarningsResponse {
+        ticker,
+        event,
+        days_until,
+    }))
+}
+
+// ==================== 라우터 ====================
+
+/// 실적 발표 일정 라우터 생성.
+pub fn earnings_router() -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/", post(create_earnings_event))
+        .route("/upcoming", get(get_upcoming_earnings))
+        .route("/reload", post(reload_earnings_calendar))
+        .route("/{market}/{ticker}/{date}", delete(delete_earnings_event))
+        .route("/{ticker}/next", get(get_next_earnings))
+}
+
+// ==================== 테스트 ====================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::create_test_state;
+    use axum::body::Body;
+    use axum::http::Request;
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn test_earnings_routes_require_db() {
+        let state = Arc::new(create_test_state());
+        let app = earnings_router().with_state(state);
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/upcoming")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+    }
+}
@@ -8,6 +8,6 @@
 
 // Repository에서 타입 re-export
 pub use crate::repository::equity_history::{
-    EquityHistoryRepository, EquityPoint, ExecutionForSync, MonthlyReturn, PortfolioSnapshot,
-    SyncResult,
+    EquityHistoryRepository, EquityPoint, EquitySyncCheckpoint, EquitySyncMode, ExecutionForSync,
+    MonthlyReturn, PortfolioSnapshot, RecomputePlan, SyncResult,
 };
@@ -0,0 +1,262 @@
+//! ETF 괴리율/구성종목 endpoint.
+//!
+//! 수집기가 KRX에서 적재한 ETF NAV 괴리율과 구성종목(PDF)을 조회하고,
+//! 최신 괴리율을 실행기 RiskManager에 반영하는 REST API를 제공합니다.
+//! RiskManager는 괴리율이 한도를 넘는 ETF의 매수 주문을 거부합니다.
+//!
+//! # 엔드포인트
+//!
+//! - `GET /api/v1/etf/premiums` - ETF별 최신 괴리율 (괴리율 큰 순)
+//! - `POST /api/v1/etf/premiums/reload` - 최신 괴리율을 리스크 필터에 반영
+//! - `GET /api/v1/etf/{ticker}/premium` - 괴리율 추이
+//! - `GET /api/v1/etf/{ticker}/constituents` - 최신 구성종목
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    routing::{get, post},
+    Json, Router,
+};
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use std::sync::Arc;
+use tracing::info;
+use trader_core::EtfConstituent;
+use trader_data::{EtfPremiumRecord, EtfStore};
+use trader_risk::RiskManager;
+
+use super::common::db_unavailable;
+use crate::error::{ApiErrorResponse, ApiResult};
+use crate::state::AppState;
+
+// ==================== 요청/응답 타입 ====================
+
+/// ETF 괴리율.
+#[derive(Debug, Serialize)]
+pub struct EtfPremiumDto {
+    /// 레버리지/인버스 ETF 여부
+    pub leveraged: bool,
+    /// 매수 허용 최대 괴리율 (%, 현재 리스크 설정 기준)
+    pub max_premium_pct: f64,
+    /// 괴리율 한도 초과 여부 (매수 차단 대상)
+    pub blocked: bool,
+    #[serde(flatten)]
+    pub record: EtfPremiumRecord,
+}
+
+/// ETF별 최신 괴리율 응답.
+#[derive(Debug, Serialize)]
+pub struct EtfPremiumsResponse {
+    /// 조회된 ETF 수
+    pub total: usize,
+    /// 한도 초과 ETF 수
+    pub blocked: usize,
+    /// ETF별 최신 괴리율 (괴리율 큰 순)
+    pub etfs: Vec<EtfPremiumDto>,
+}
+
+/// 괴리율 추이 조회 쿼리.
+#[derive(Debug, Deserialize)]
+pub struct EtfPremiumHistoryQuery {
+    /// 조회 거래일 수 (기본: 60)
+    #[serde(default = "default_history_days")]
+    pub days: i64,
+}
+
+fn default_history_days() -> i64 {
+    60
+}
+
+/// 괴리율 추이 응답.
+#[derive(Debug, Serialize)]
+pub struct EtfPremiumHistoryResponse {
+    /// ETF 종목코드
+    pub ticker: String,
+    /// 기간 평균 괴리율 (%)
+    pub avg_premium_pct: Option<f64>,
+    /// 기간 최대 괴리율 (%)
+    pub max_premium_pct: Option<f64>,
+    /// 일별 괴리율 (오래된 순)
+    pub history: Vec<EtfPremiumRecord>,
+}
+
+/// 구성종목 응답.
+#[derive(Debug, Serialize)]
+pub struct EtfConstituentsResponse {
+    /// ETF 종목코드
+    pub ticker: String,
+    /// 구성종목 수
+    pub total: usize,
+    /// 구성종목 (평가금액 큰 순)
+    pub constituents: Vec<EtfConstituent>,
+}
+
+/// 괴리율 반영 응답.
+#[derive(Debug, Serialize)]
+pub struct EtfPremiumReloadResponse {
+    /// 리스크 필터에 반영된 ETF 수
+    pub loaded: usize,
+}
+
+// ==================== 헬퍼 ====================
+
+fn data_error_response(err: trader_data::DataError) -> (StatusCode, Json<ApiErrorResponse>) {
+    (
+        StatusCode::INTERNAL_SERVER_ERROR,
+        Json(ApiErrorResponse::new("DB_ERROR", err.to_string())),
+    )
+}
+
+/// DB의 ETF별 최신 괴리율을 RiskManager에 반영.
+pub async fn load_etf_premiums(
+    pool: &PgPool,
+    risk_manager: &mut RiskManager,
+) -> trader_data::Result<usize> {
+    let premiums = EtfStore::new(pool.clone()).latest_premiums().await?;
+    for record in &premiums {
+        risk_manager.update_etf_premium(&record.ticker, record.premium_pct, record.is_leveraged());
+    }
+    Ok(premiums.len())
+}
+
+// ==================== 핸들러 ====================
+
+/// ETF별 최신 괴리율 조회.
+///
+/// GET /api/v1/etf/premiums
+pub async fn get_etf_premiums(
+    State(state): State<Arc<AppState>>,
+) -> ApiResult<Json<EtfPremiumsResponse>> {
+    let pool = state.db_pool.as_ref().ok_or_else(db_unavailable)?;
+    let records = EtfStore::new(pool.clone())
+        .latest_premiums()
+        .await
+        .map_err(data_error_response)?;
+
+    let executor = state.executor.read().await;
+    let risk_manager = executor.risk_manager().read().await;
+    let config = risk_manager.config();
+
+    let etfs: Vec<EtfPremiumDto> = records
+        .into_iter()
+        .map(|record| {
+            let leveraged = record.is_leveraged();
+            let max_premium_pct = config.get_max_etf_premium_pct(leveraged);
+            EtfPremiumDto {
+                leveraged,
+                max_premium_pct,
+                blocked: record.premium_pct > max_premium_pct,
+                record,
+            }
+        })
+        .collect();
+
+    Ok(Json(EtfPremiumsResponse {
+        total: etfs.len(),
+        blocked: etfs.iter().filter(|e| e.blocked).count(),
+        etfs,
+    }))
+}
+
+/// 최신 괴리율을 실행기 리스크 필터에 반영.
+///
+/// POST /api/v1/etf/premiums/reload
+pub async fn reload_etf_premiums(
+    State(state): State<Arc<AppState>>,
+) -> ApiResult<Json<EtfPremiumReloadResponse>> {
+    let pool = state.db_pool.as_ref().ok_or_else(db_unavailable)?;
+
+    let executor = state.executor.read().await;
+    let mut risk_manager = executor.risk_manager().write().await;
+    let loaded = load_etf_premiums(pool, &mut risk_manager)
+        .await
+        .map_err(data_error_response)?;
+
+    info!(loaded, "ETF 괴리율 리스크 필터 반영");
+    Ok(Json(EtfPremiumReloadResponse { loaded }))
+}
+
+/// ETF 괴리율 추이 조회.
+///
+/// GET /api/v1/etf/{ticker}/premium
+pub async fn get_etf_premium_history(
+    State(state): State<Arc<AppState>>,
+    Path(ticker): Path<String>,
+    Query(query): Query<EtfPremiumHistoryQuery>,
+) -> ApiResult<Json<EtfPremiumHistoryResponse>> {
+    let pool = state.db_pool.as_ref().ok_or_else(db_unavailable)?;
+    let history = EtfStore::new(pool.clone())
+        .get_premium_history(&ticker, query.days.clamp(1, 1000))
+        .await
+        .map_err(data_error_response)?;
+
+    let avg_premium_pct = (!history.is_empty())
+        .then(|| history.iter().map(|h| h.premium_pct).sum::<f64>() / history.len() as f64);
+    let max_premium_pct = history.iter().map(|h| h.premium_pct).reduce(f64::max);
+
+    Ok(Json(EtfPremiumHistoryResponse {
+        ticker,
+        avg_premium_pct,
+        max_premium_pct,
+        history,
+    }))
+}
+
+/// ETF 최신 구성종목 조회.
+///
+/// GET /api/v1/etf/{ticker}/constituents
+pub async fn get_etf_constituents(
+    State(state): State<Arc<AppState>>,
+    Path(ticker): Path<String>,
+) -> ApiResult<Json<EtfConstituentsResponse>> {
+    let pool = state.db_pool.as_ref().ok_or_else(db_unavailable)?;
+    let constituents = EtfStore::new(pool.clone())
+        .latest_constituents(&ticker)
+        .await
+        .map_err(data_error_response)?;
+
+    Ok(Json(EtfConstituentsResponse {
+        ticker,
+        total: constituents.len(),
+        constituents,
+    }))
+}
+
+// ==================== 라우터 ====================
+
+/// ETF 라우터 생성.
+pub fn etf_router() -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/premiums", get(get_etf_premiums))
+        .route("/premiums/reload", post(reload_etf_premiums))
+        .route("/{ticker}/premium", get(get_etf_premium_history))
+        .route("/{ticker}/constituents", get(get_etf_constituents))
+}
+
+// ==================== 테스트 ====================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::create_test_state;
+    use axum::body::Body;
+    use axum::http::Request;
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn test_etf_routes_require_db() {
+        let state = Arc::new(create_test_state());
+        let app = etf_router().with_state(state);
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/premiums")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+    }
+}
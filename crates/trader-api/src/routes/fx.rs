@@ -0,0 +1,303 @@
+//! 해외 주식 자동 환전 endpoint.
+//!
+//! 미국 주식 주문 전 환전 판단(USD 잔고, 증권사 적용 환율, 기준 환율 대비 스프레드)을
+//! 미리 확인하고, 수동 승인 모드에서 보류된 환전/주문을 승인하거나 거절합니다.
+//! 환전 판단은 `services::fx_conversion`이 주문 제출 시점에 수행합니다.
+//!
+//! # 엔드포인트
+//!
+//! - `GET /api/v1/fx/quote` - 주문 금액 기준 환전 판단 미리보기
+//! - `GET /api/v1/fx/conversions` - 환전 이력 (상태 필터)
+//! - `POST /api/v1/fx/conversions/{id}/approve` - 승인 후 보류 주문 제출
+//! - `POST /api/v1/fx/conversions/{id}/reject` - 거절 (보류 주문 폐기)
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    routing::{get, post},
+    Json, Router,
+};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tracing::info;
+use trader_execution::{
+    plan_fx_conversion, FxApprovalMode, FxConversionPlan, FxConversionStatus, FxFundingSnapshot,
+};
+use uuid::Uuid;
+
+use super::common::{db_error_response, require_pool};
+use crate::error::{ApiErrorResponse, ApiResult};
+use crate::repository::{FxConversionRecord, FxConversionRepository};
+use crate::services::fx_conversion::{
+    approve_conversion, funding_snapshot, FxApprovalOutcome, FxConversionConfig,
+};
+use crate::state::AppState;
+
+// ==================== 요청/응답 타입 ====================
+
+/// 환전 미리보기 쿼리.
+#[derive(Debug, Deserialize)]
+pub struct FxQuoteQuery {
+    /// 미국 주식 티커
+    pub ticker: String,
+    /// 주문 가격 (USD)
+    pub price: Decimal,
+    /// 주문 수량
+    pub quantity: Decimal,
+}
+
+/// 환전 이력 조회 쿼리.
+#[derive(Debug, Deserialize)]
+pub struct FxConversionsQuery {
+    /// 상태 필터 (pending, deferred, executed, rejected, failed)
+    pub status: Option<String>,
+    /// 최대 건수 (기본값: 100)
+    #[serde(default = "default_list_limit")]
+    pub limit: i64,
+}
+
+fn default_list_limit() -> i64 {
+    100
+}
+
+/// 거절 요청.
+#[derive(Debug, Default, Deserialize)]
+pub struct FxRejectRequest {
+    /// 거절 사유
+    pub reason: Option<String>,
+}
+
+/// 환전 미리보기 응답.
+#[derive(Debug, Serialize)]
+pub struct FxQuoteResponse {
+    /// 자동 환전 활성화 여부
+    pub enabled: bool,
+    pub mode: FxApprovalMode,
+    pub max_spread_bps: Decimal,
+    pub snapshot: FxFundingSnapshot,
+    pub plan: FxConversionPlan,
+}
+
+/// 환전 이력 응답.
+#[derive(Debug, Serialize)]
+pub struct FxConversionsResponse {
+    pub total: usize,
+    /// 환전 기록 (최신순)
+    pub conversions: Vec<FxConversionRecord>,
+}
+
+/// 승인 결과 응답.
+#[derive(Debug, Serialize)]
+pub struct FxApprovalResponse {
+    pub conversion: FxConversionRecord,
+    /// 보류 주문 제출 성공 여부
+    pub order_submitted: bool,
+    pub order_id: Option<Uuid>,
+    pub error: Option<String>,
+}
+
+// ==================== 헬퍼 ====================
+
+fn not_pending_response(id: Uuid) -> (StatusCode, Json<ApiErrorResponse>) {
+    (
+        StatusCode::CONFLICT,
+        Json(ApiErrorResponse::new(
+            "FX_CONVERSION_NOT_PENDING",
+            format!("FX conversion {} is not awaiting approval", id),
+        )),
+    )
+}
+
+// ==================== 핸들러 ====================
+
+/// 환전 판단 미리보기.
+///
+/// 주문하지 않고 현재 USD 잔고와 환율로 환전 필요 여부를 계산합니다.
+///
+/// GET /api/v1/fx/quote?ticker=AAPL&price=190&quantity=10
+pub async fn get_fx_quote(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<FxQuoteQuery>,
+) -> ApiResult<Json<FxQuoteResponse>> {
+    if query.price <= Decimal::ZERO || query.quantity <= Decimal::ZERO {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiErrorResponse::new(
+                "INVALID_FX_QUOTE",
+                "price and quantity must be positive",
+            )),
+        ));
+    }
+
+    let ticker = query.ticker.trim().to_uppercase();
+    let snapshot = funding_snapshot(&state, &ticker, query.price)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::BAD_GATEWAY,
+                Json(ApiErrorResponse::new("EXCHANGE_ERROR", e)),
+            )
+        })?
+        .ok_or_else(|| {
+            (
+                StatusCode::SERVICE_UNAVAILABLE,
+                Json(ApiErrorResponse::new(
+                    "KIS_NOT_CONFIGURED",
+                    "KIS overseas client is not configured",
+                )),
+            )
+        })?;
+
+    let config = FxConversionConfig::from_env();
+    let policy = config.clone().unwrap_or_default().policy;
+    let plan = plan_fx_conversion(query.price * query.quantity, &snapshot, &policy);
+
+    Ok(Json(FxQuoteResponse {
+        enabled: config.is_some(),
+        mode: policy.mode,
+        max_spread_bps: policy.max_spread_bps,
+        snapshot,
+        plan,
+    }))
+}
+
+/// 환전 이력 조회.
+///
+/// GET /api/v1/fx/conversions
+pub async fn list_fx_conversions(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<FxConversionsQuery>,
+) -> ApiResult<Json<FxConversionsResponse>> {
+    let status = query
+        .status
+        .as_deref()
+        .map(|s| s.parse::<FxConversionStatus>())
+        .transpose()
+        .map_err(|e| {
+            (
+                StatusCode::BAD_REQUEST,
+                Json(ApiErrorResponse::new("INVALID_STATUS", e)),
+            )
+        })?;
+    let pool = require_pool(&state)?;
+    let conversions = FxConversionRepository::list(pool, status, query.limit.clamp(1, 1000))
+        .await
+        .map_err(db_error_response)?;
+
+    Ok(Json(FxConversionsResponse {
+        total: conversions.len(),
+        conversions,
+    }))
+}
+
+/// 환전 승인 후 보류 주문 제출.
+///
+/// 승인 유효 시간(`FX_CONVERSION_APPROVAL_TTL_MINS`)이 지난 건은 거절로 처리합니다.
+///
+/// POST /api/v1/fx/conversions/{id}/approve
+pub async fn approve_fx_conversion(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Json<FxApprovalResponse>> {
+    let pool = require_pool(&state)?;
+    let approval_ttl = FxConversionConfig::from_env()
+        .unwrap_or_default()
+        .approval_ttl;
+
+    match approve_conversion(&state, pool, id, approval_ttl)
+        .await
+        .map_err(db_error_response)?
+    {
+        FxApprovalOutcome::NotPending => Err(not_pending_response(id)),
+        FxApprovalOutcome::Expired(conversion) => Err((
+            StatusCode::CONFLICT,
+            Json(ApiErrorResponse::new(
+                "FX_APPROVAL_EXPIRED",
+                format!(
+                    "FX conversion {} for {} approval expired",
+                    id, conversion.ticker
+                ),
+            )),
+        )),
+        FxApprovalOutcome::Submitted(conversion, result) => Ok(Json(FxApprovalResponse {
+            conversion,
+            order_submitted: result.success,
+            order_id: result.order_id,
+            error: result.error,
+        })),
+    }
+}
+
+/// 환전 거절 (보류 주문 폐기).
+///
+/// POST /api/v1/fx/conversions/{id}/reject
+pub async fn reject_fx_conversion(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+    body: Option<Json<FxRejectRequest>>,
+) -> ApiResult<Json<FxConversionRecord>> {
+    let pool = require_pool(&state)?;
+    let reason = body
+        .and_then(|Json(request)| request.reason)
+        .unwrap_or_else(|| "Rejected manually".to_string());
+
+    let record =
+        FxConversionRepository::decide(pool, id, FxConversionStatus::Rejected, Some(&reason))
+            .await
+            .map_err(db_error_response)?
+            .ok_or_else(|| not_pending_response(id))?;
+
+    info!(id = %id, ticker = %record.ticker, reason = %reason, "환전 거절");
+    Ok(Json(record))
+}
+
+// ==================== 라우터 ====================
+
+/// 자동 환전 라우터 생성.
+pub fn fx_router() -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/quote", get(get_fx_quote))
+        .route("/conversions", get(list_fx_conversions))
+        .route("/conversions/{id}/approve", post(approve_fx_conversion))
+        .route("/conversions/{id}/reject", post(reject_fx_conversion))
+}
+
+// ==================== 테스트 ====================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::create_test_state;
+    use axum::body::Body;
+    use axum::http::Request;
+    use tower::ServiceExt;
+
+    async fn get_status(uri: &str) -> StatusCode {
+        let app = fx_router().with_state(Arc::new(create_test_state()));
+        app.oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap()
+            .status()
+    }
+
+    #[tokio::test]
+    async fn test_fx_quote_requires_kis_client() {
+        assert_eq!(
+            get_status("/quote?ticker=AAPL&price=190&quantity=0").await,
+            StatusCode::BAD_REQUEST
+        );
+        assert_eq!(
+            get_status("/quote?ticker=AAPL&price=190&quantity=10").await,
+            StatusCode::SERVICE_UNAVAILABLE
+        );
+    }
+
+    #[tokio::test]
+    async fn test_list_fx_conversions_rejects_unknown_status() {
+        assert_eq!(
+            get_status("/conversions?status=done").await,
+            StatusCode::BAD_REQUEST
+        );
+    }
+}
@@ -0,0 +1,398 @@
+//! 포트폴리오 베타 헤지 오버레이 endpoint.
+//!
+//! 순 주식 베타를 목표 밴드 안에 유지하는 헤지 설정(활성화, 헤지 상품, 목표 베타)을
+//! 관리하고, 일별 조정 이력과 전략 손익과 분리된 헤지 손익을 조회합니다.
+//! 자동 조정은 `services::hedge_overlay`가 하루 한 번 실행합니다.
+//!
+//! # 엔드포인트
+//!
+//! - `GET /api/v1/hedge` - 헤지 설정, 최근 조정, 헤지 손익
+//! - `PUT /api/v1/hedge` - 헤지 설정 변경 (활성화/비활성화 포함)
+//! - `POST /api/v1/hedge/rebalance` - 즉시 조정
+//! - `GET /api/v1/hedge/rebalances` - 일별 조정 이력
+
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    routing::{get, post},
+    Json, Router,
+};
+use chrono::Utc;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use std::sync::Arc;
+use tracing::info;
+use trader_core::Timeframe;
+use trader_data::cache::CachedHistoricalDataProvider;
+use trader_execution::{hedge_pnl, HedgePlan, HedgePnl, HedgeSettings};
+
+use super::common::{db_error_response, require_pool};
+use crate::error::{ApiErrorResponse, ApiResult};
+use crate::repository::{
+    HedgeConfigInput, HedgeConfigRecord, HedgeRebalanceRecord, HedgeRepository,
+};
+use crate::services::hedge_overlay::rebalance_hedge;
+use crate::state::AppState;
+
+// ==================== 요청/응답 타입 ====================
+
+/// 헤지 설정 변경 요청.
+#[derive(Debug, Deserialize)]
+pub struct HedgeConfigRequest {
+    /// 활성화 여부
+    pub enabled: bool,
+    /// 베타 기준 지수/ETF (기본값: 069500)
+    #[serde(default = "default_benchmark_ticker")]
+    pub benchmark_ticker: String,
+    /// 헤지 상품 (기본값: 114800)
+    #[serde(default = "default_hedge_ticker")]
+    pub hedge_ticker: String,
+    /// 헤지 상품 베타 (인버스 1배: -1, 2배: -2, 선물: 1; 기본값: -1)
+    #[serde(default = "default_hedge_beta")]
+    pub hedge_beta: Decimal,
+    /// 목표 순 베타 (0이면 완전 헤지)
+    pub target_beta: Decimal,
+    /// 허용 밴드 (기본값: 0.1)
+    #[serde(default = "default_band")]
+    pub band: Decimal,
+    /// 평가 자산 대비 최대 헤지 비율 (기본값: 1)
+    #[serde(default = "default_max_hedge_ratio")]
+    pub max_hedge_ratio: Decimal,
+    /// 베타 계산 일수 (기본값: 60)
+    #[serde(default = "default_beta_lookback")]
+    pub beta_lookback: i32,
+}
+
+fn default_benchmark_ticker() -> String {
+    "069500".to_string()
+}
+
+fn default_hedge_ticker() -> String {
+    "114800".to_string()
+}
+
+fn default_hedge_beta() -> Decimal {
+    -Decimal::ONE
+}
+
+fn default_band() -> Decimal {
+    Decimal::new(1, 1)
+}
+
+fn default_max_hedge_ratio() -> Decimal {
+    Decimal::ONE
+}
+
+fn default_beta_lookback() -> i32 {
+    60
+}
+
+/// 조정 이력 조회 쿼리.
+#[derive(Debug, Deserialize)]
+pub struct HedgeRebalancesQuery {
+    /// 최대 건수 (기본값: 100)
+    #[serde(default = "default_list_limit")]
+    pub limit: i64,
+}
+
+fn default_list_limit() -> i64 {
+    100
+}
+
+/// 헤지 현황 응답.
+#[derive(Debug, Serialize)]
+pub struct HedgeStatusResponse {
+    pub config: HedgeConfigRecord,
+    /// 최근 조정
+    pub latest: Option<HedgeRebalanceRecord>,
+    /// 헤지 상품 평가 가격
+    pub mark_price: Option<Decimal>,
+    /// 헤지 손익 (전략 손익과 별도)
+    pub pnl: HedgePnl,
+}
+
+/// 조정 이력 응답.
+#[derive(Debug, Serialize)]
+pub struct HedgeRebalancesResponse {
+    pub total: usize,
+    /// 일별 조정 (최신순)
+    pub rebalances: Vec<HedgeRebalanceRecord>,
+}
+
+// ==================== 헬퍼 ====================
+
+fn data_provider(state: &AppState, pool: &PgPool) -> Arc<CachedHistoricalDataProvider> {
+    state
+        .data_provider
+        .clone()
+        .unwrap_or_else(|| Arc::new(CachedHistoricalDataProvider::new(pool.clone())))
+}
+
+/// 요청 검증 후 저장 입력으로 변환.
+#[allow(clippy::result_large_err)]
+fn validate_request(
+    request: HedgeConfigRequest,
+) -> Result<HedgeConfigInput, (StatusCode, Json<ApiErrorResponse>)> {
+    let invalid = |msg: String| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(ApiErrorResponse::new("INVALID_HEDGE_CONFIG", msg)),
+        )
+    };
+
+    let benchmark_ticker = request.benchmark_ticker.trim().to_uppercase();
+    if benchmark_ticker.is_empty() {
+        return Err(invalid("benchmark_ticker is required".to_string()));
+    }
+    let settings = HedgeSettings {
+        hedge_ticker: request.hedge_ticker.trim().to_uppercase(),
+        hedge_beta: request.hedge_beta,
+        target_beta: request.target_beta,
+        band: request.band,
+        max_hedge_ratio: request.max_hedge_ratio,
+    };
+    settings.validate().map_err(invalid)?;
+    if settings.hedge_ticker == benchmark_ticker {
+        return Err(invalid(
+            "hedge_ticker must differ from benchmark_ticker".to_string(),
+        ));
+    }
+    if !(5..=500).contains(&request.beta_lookback) {
+        return Err(invalid(
+            "beta_lookback must be between 5 and 500".to_string(),
+        ));
+    }
+
+    Ok(HedgeConfigInput {
+        enabled: request.enabled,
+        benchmark_ticker,
+        settings,
+        beta_lookback: request.beta_lookback,
+    })
+}
+
+// ==================== 핸들러 ====================
+
+/// 헤지 현황 조회.
+///
+/// GET /api/v1/hedge
+pub async fn get_hedge_status(
+    State(state): State<Arc<AppState>>,
+) -> ApiResult<Json<HedgeStatusResponse>> {
+    let pool = require_pool(&state)?;
+    let config = HedgeRepository::get_config(pool)
+        .await
+        .map_err(db_error_response)?;
+    let latest = HedgeRepository::list_rebalances(pool, 1)
+        .await
+        .map_err(db_error_response)?
+        .into_iter()
+        .next();
+    let fills = HedgeRepository::fills(pool, &config.hedge_ticker)
+        .await
+        .map_err(db_error_response)?;
+
+    let mark_price = data_provider(&state, pool)
+        .get_klines(&config.hedge_ticker, Timeframe::D1, 1)
+        .await
+        .ok()
+        .and_then(|klines| klines.last().map(|k| k.close));
+    let pnl = hedge_pnl(
+        &fills,
+        mark_price
+            .or_else(|| fills.last().map(|f| f.price))
+            .unwrap_or_default(),
+    );
+
+    Ok(Json(HedgeStatusResponse {
+        config,
+        latest,
+        mark_price,
+        pnl,
+    }))
+}
+
+/// 헤지 설정 변경.
+///
+/// 헤지 포지션이 남아 있으면 헤지 상품을 바꿀 수 없습니다 (먼저 헤지 포지션 청산 필요).
+///
+/// PUT /api/v1/hedge
+pub async fn update_hedge_config(
+    State(state): State<Arc<AppState>>,
+    Json(request): Json<HedgeConfigRequest>,
+) -> ApiResult<Json<HedgeConfigRecord>> {
+    let input = validate_request(request)?;
+    let pool = require_pool(&state)?;
+
+    let existing = HedgeRepository::get_config(pool)
+        .await
+        .map_err(db_error_response)?;
+    if existing.hedge_ticker != input.settings.hedge_ticker {
+        let fills = HedgeRepository::fills(pool, &existing.hedge_ticker)
+            .await
+            .map_err(db_error_response)?;
+        let open_quantity = hedge_pnl(&fills, Decimal::ZERO).quantity;
+        if !open_quantity.is_zero() {
+            return Err((
+                StatusCode::CONFLICT,
+                Json(ApiErrorResponse::new(
+                    "HEDGE_POSITION_OPEN",
+                    format!(
+                        "Hedge position of {} {} is still open",
+                        open_quantity, existing.hedge_ticker
+                    ),
+                )),
+            ));
+        }
+    }
+
+    let record = HedgeRepository::update_config(pool, &input)
+        .await
+        .map_err(db_error_response)?;
+
+    info!(
+        enabled = record.enabled,
+        hedge_ticker = %record.hedge_ticker,
+        target_beta = %record.target_beta,
+        band = %record.band,
+        "헤지 설정 변경"
+    );
+    Ok(Json(record))
+}
+
+/// 즉시 조정.
+///
+/// 하루 한 번 제한과 관계없이 지금 순 베타를 계산해 밴드를 벗어났으면 조정합니다.
+///
+/// POST /api/v1/hedge/rebalance
+pub async fn rebalance_hedge_now(State(state): State<Arc<AppState>>) -> ApiResult<Json<HedgePlan>> {
+    let pool = require_pool(&state)?;
+    let config = HedgeRepository::get_config(pool)
+        .await
+        .map_err(db_error_response)?;
+    if !config.enabled {
+        return Err((
+            StatusCode::CONFLICT,
+            Json(ApiErrorResponse::new(
+                "HEDGE_DISABLED",
+                "Hedge overlay is disabled",
+            )),
+        ));
+    }
+
+    let today = Utc::now().date_naive();
+    let provider = data_provider(&state, pool);
+    let plan = rebalance_hedge(&state, pool, &provider, &config, today)
+        .await
+        .map_err(db_error_response)?
+        .ok_or_else(|| {
+            (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                Json(ApiErrorResponse::new(
+                    "HEDGE_PRICE_UNAVAILABLE",
+                    format!("No price for hedge instrument {}", config.hedge_ticker),
+                )),
+            )
+        })?;
+    HedgeRepository::mark_rebalanced(pool, today)
+        .await
+        .map_err(db_error_response)?;
+
+    Ok(Json(plan))
+}
+
+/// 일별 조정 이력 조회.
+///
+/// GET /api/v1/hedge/rebalances
+pub async fn list_hedge_rebalances(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<HedgeRebalancesQuery>,
+) -> ApiResult<Json<HedgeRebalancesResponse>> {
+    let pool = require_pool(&state)?;
+    let rebalances = HedgeRepository::list_rebalances(pool, query.limit.clamp(1, 1000))
+        .await
+        .map_err(db_error_response)?;
+
+    Ok(Json(HedgeRebalancesResponse {
+        total: rebalances.len(),
+        rebalances,
+    }))
+}
+
+// ==================== 라우터 ====================
+
+/// 헤지 오버레이 라우터 생성.
+pub fn hedge_router() -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/", get(get_hedge_status).put(update_hedge_config))
+        .route("/rebalance", post(rebalance_hedge_now))
+        .route("/rebalances", get(list_hedge_rebalances))
+}
+
+// ==================== 테스트 ====================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::create_test_state;
+    use axum::body::Body;
+    use axum::http::Request;
+    use tower::ServiceExt;
+
+    fn request(body: serde_json::Value) -> HedgeConfigRequest {
+        serde_json::from_value(body).unwrap()
+    }
+
+    #[test]
+    fn test_validate_request() {
+        let input = validate_request(request(serde_json::json!({
+            "enabled": true,
+            "hedge_ticker": " 252670 ",
+            "hedge_beta": -2,
+            "target_beta": 0.2
+        })))
+        .unwrap();
+        assert!(input.enabled);
+        assert_eq!(input.benchmark_ticker, "069500");
+        assert_eq!(input.settings.hedge_ticker, "252670");
+        assert_eq!(input.settings.hedge_beta, Decimal::from(-2));
+        assert_eq!(input.settings.band, Decimal::new(1, 1));
+        assert_eq!(input.beta_lookback, 60);
+
+        // 헤지 베타 0, 벤치마크와 같은 헤지 상품, 음수 밴드
+        for body in [
+            serde_json::json!({"enabled": true, "target_beta": 0, "hedge_beta": 0}),
+            serde_json::json!({"enabled": true, "target_beta": 0, "hedge_ticker": "069500"}),
+            serde_json::json!({"enabled": true, "target_beta": 0, "band": -0.1}),
+        ] {
+            let (status, _) = validate_request(request(body)).unwrap_err();
+            assert_eq!(status, StatusCode::BAD_REQUEST);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_update_hedge_config_rejects_invalid_lookback() {
+        let app = hedge_router().with_state(Arc::new(create_test_state()));
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("PUT")
+                    .uri("/")
+                    .header("content-type", "application/json")
+                    .body(Body::from(
+                        serde_json::json!({
+                            "enabled": true,
+                            "target_beta": 0.3,
+                            "beta_lookback": 1
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+}
@@ -12,6 +12,10 @@
 //! - `POST /api/v1/journal/sync` - 거래소 체결 내역 동기화
 //! - `PATCH /api/v1/journal/executions/{id}` - 체결 내역 메모/태그 수정
 //! - `GET /api/v1/journal/cost-basis/{symbol}` - FIFO 원가 계산
+//! - `GET /api/v1/journal/dca` - 정액 적립식(DCA) 계획별 누적 현황
+//! - `GET /api/v1/journal/fx-conversions` - 해외 주식 주문 전 환전 내역/집계
+//! - `GET /api/v1/journal/export/{executions,positions}` - CSV/OFX/한국 양식 내보내기
+//!   (`journal_export` 모듈)
 
 use axum::{
     extract::{Path, Query, State},
@@ -47,7 +51,7 @@ impl DateParseError {
         }
     }
 
-    fn to_api_error(&self) -> ApiError {
+    pub(super) fn to_api_error(&self) -> ApiError {
         ApiError::new(
             "INVALID_DATE_FORMAT",
             format!(
@@ -97,7 +101,7 @@ fn parse_datetime_flexible(s: &str, field_name: &str) -> Result<DateTime<Utc>, D
 /// - ISO 8601: `2024-01-15`
 /// - 슬래시 구분: `2024/01/15`
 /// - 한국식: `20240115` (YYYYMMDD)
-fn parse_date_flexible(s: &str, field_name: &str) -> Result<NaiveDate, DateParseError> {
+pub(super) fn parse_date_flexible(s: &str, field_name: &str) -> Result<NaiveDate, DateParseError> {
     const FORMATS: &[&str] = &[
         "YYYY-MM-DD (2024-01-15)",
         "YYYY/MM/DD (2024/01/15)",
@@ -124,9 +128,10 @@ fn parse_date_flexible(s: &str, field_name: &str) -> Result<NaiveDate, DateParse
 
 use crate::repository::{
     build_tracker_from_executions, create_exchange_providers_from_credential, CostBasisSummary,
-    CumulativePnL, CurrentPosition as RepoCurrentPosition, DailySummary, EquityHistoryRepository,
-    ExecutionCacheRepository, ExecutionFilter, JournalRepository, MonthlyPnL, NewExecution,
-    PnLSummary, PositionRepository, StrategyPerformance, SymbolPnL, TradeExecution,
+    CumulativePnL, CurrentPosition as RepoCurrentPosition, DailySummary, DcaRepository,
+    DcaSymbolReport, EquityHistoryRepository, ExecutionCacheRepository, ExecutionFilter,
+    FxConversionRecord, FxConversionRepository, FxConversionSummary, JournalRepository, MonthlyPnL,
+    NewExecution, PnLSummary, PositionRepository, StrategyPerformance, SymbolPnL, TradeExecution,
     TradeExecutionRecord, TradingInsights, WeeklyPnL, YearlyPnL,
 };
 use crate::routes::strategies::ApiError;
@@ -1017,6 +1022,88 @@ impl From<StrategyPerformance> for StrategyPerformanceItem {
     }
 }
 
+/// 적립식 현황 조회 쿼리 파라미터.
+#[derive(Debug, Deserialize)]
+pub struct DcaReportQuery {
+    /// 적립 계획 ID (생략하면 전체)
+    pub plan_id: Option<Uuid>,
+}
+
+/// 적립식 현황 응답.
+#[derive(Debug, Serialize)]
+pub struct DcaReportResponse {
+    pub items: Vec<DcaReportItem>,
+    pub total: usize,
+}
+
+/// 환전 내역 조회 쿼리 파라미터.
+#[derive(Debug, Deserialize)]
+pub struct FxConversionJournalQuery {
+    /// 시작 날짜 (ISO 8601)
+    pub start_date: Option<String>,
+    /// 종료 날짜 (ISO 8601)
+    pub end_date: Option<String>,
+    /// 최대 건수 (기본 100)
+    pub limit: Option<i64>,
+}
+
+/// 환전 내역 응답.
+#[derive(Debug, Serialize)]
+pub struct FxConversionJournalResponse {
+    /// 기간 집계 (실행된 환전 기준 금액/평균 환율)
+    pub summary: FxConversionSummary,
+    pub items: Vec<FxConversionRecord>,
+    pub total: usize,
+}
+
+/// 계획/종목별 적립 현황 항목.
+#[derive(Debug, Serialize)]
+pub struct DcaReportItem {
+    pub plan_id: Uuid,
+    pub plan_name: String,
+    pub currency: String,
+    pub ticker: String,
+    pub executions: i64,
+    pub failures: i64,
+    pub total_invested: String,
+    pub total_quantity: String,
+    pub average_price: Option<String>,
+    pub last_price: Option<String>,
+    /// 최근 기준 가격 기준 평가액
+    pub market_value: Option<String>,
+    /// 평가 수익률 (%)
+    pub return_pct: Option<String>,
+    pub first_executed_at: Option<String>,
+    pub last_executed_at: Option<String>,
+}
+
+impl From<DcaSymbolReport> for DcaReportItem {
+    fn from(r: DcaSymbolReport) -> Self {
+        let market_value = r.last_price.map(|price| price * r.total_quantity);
+        let return_pct = market_value
+            .filter(|_| r.total_invested > Decimal::ZERO)
+            .map(|value| {
+                ((value - r.total_invested) / r.total_invested * Decimal::ONE_HUNDRED).round_dp(2)
+            });
+        Self {
+            plan_id: r.plan_id,
+            plan_name: r.plan_name,
+            currency: r.currency,
+            ticker: r.ticker,
+            executions: r.executions,
+            failures: r.failures,
+            total_invested: r.total_invested.to_string(),
+            total_quantity: r.total_quantity.to_string(),
+            average_price: r.average_price.map(|p| p.round_dp(4).to_string()),
+            last_price: r.last_price.map(|p| p.to_string()),
+            market_value: market_value.map(|v| v.to_string()),
+            return_pct: return_pct.map(|v| v.to_string()),
+            first_executed_at: r.first_executed_at.map(|dt| dt.to_rfc3339()),
+            last_executed_at: r.last_executed_at.map(|dt| dt.to_rfc3339()),
+        }
+    }
+}
+
 /// 주별 손익 조회.
 ///
 /// GET /api/v1/journal/pnl/weekly
@@ -1235,6 +1322,86 @@ pub async fn get_strategy_performance(
     }))
 }
 
+/// 정액 적립식(DCA) 현황 조회.
+///
+/// 적립 주문은 일반 체결 내역과 섞이지 않도록 계획/종목별로 따로 집계합니다.
+///
+/// GET /api/v1/journal/dca
+pub async fn get_dca_report(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<DcaReportQuery>,
+) -> Result<Json<DcaReportResponse>, (StatusCode, Json<ApiError>)> {
+    let pool = get_db_pool(&state)?;
+
+    let reports = DcaRepository::report(pool, query.plan_id)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiError::new(
+                    "DB_ERROR",
+                    format!("Failed to get DCA report: {}", e),
+                )),
+            )
+        })?;
+
+    let items: Vec<DcaReportItem> = reports.into_iter().map(Into::into).collect();
+    Ok(Json(DcaReportResponse {
+        total: items.len(),
+        items,
+    }))
+}
+
+/// 해외 주식 주문 전 환전 내역 조회.
+///
+/// 자동 환전/수동 승인/거절 건과 기간 내 실행된 환전 금액, 평균 적용 환율을 반환합니다.
+///
+/// GET /api/v1/journal/fx-conversions
+pub async fn get_fx_conversion_journal(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<FxConversionJournalQuery>,
+) -> Result<Json<FxConversionJournalResponse>, (StatusCode, Json<ApiError>)> {
+    let pool = get_db_pool(&state)?;
+
+    let start_date: Option<DateTime<Utc>> = match query.start_date {
+        Some(ref s) => Some(
+            parse_datetime_flexible(s, "start_date")
+                .map_err(|e| (StatusCode::BAD_REQUEST, Json(e.to_api_error())))?,
+        ),
+        None => None,
+    };
+    let end_date: Option<DateTime<Utc>> = match query.end_date {
+        Some(ref s) => Some(
+            parse_datetime_flexible(s, "end_date")
+                .map_err(|e| (StatusCode::BAD_REQUEST, Json(e.to_api_error())))?,
+        ),
+        None => None,
+    };
+    let limit = query.limit.unwrap_or(100).clamp(1, 1000);
+
+    let db_error = |e: sqlx::Error| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiError::new(
+                "DB_ERROR",
+                format!("Failed to get FX conversions: {}", e),
+            )),
+        )
+    };
+    let summary = FxConversionRepository::summary(pool, start_date, end_date)
+        .await
+        .map_err(db_error)?;
+    let items = FxConversionRepository::list_range(pool, start_date, end_date, limit)
+        .await
+        .map_err(db_error)?;
+
+    Ok(Json(FxConversionJournalResponse {
+        summary,
+        total: items.len(),
+        items,
+    }))
+}
+
 /// 체결 내역 메모/태그 수정.
 ///
 /// PATCH /api/v1/journal/executions/{id}
@@ -1424,7 +1591,7 @@ pub async fn sync_executions(
     let count = executions.len();
 
     // execution_cache에 저장 (upsert)
-    let inserted = ExecutionCacheRepository::upsert_executions(pool, &executions)
+    let changes = ExecutionCacheRepository::upsert_executions_tracked(pool, &executions)
         .await
         .map_err(|e| {
             error!("체결 내역 저장 실패: {}", e);
@@ -1437,6 +1604,7 @@ pub async fn sync_executions(
             )
         })?;
 
+    let inserted = changes.changed_count();
     let skipped = count - inserted;
 
     // 캐시 메타데이터 업데이트
@@ -1465,12 +1633,16 @@ pub async fn sync_executions(
         "KRW",
         "KR",
         None, // account_type은 credential에서 자동 감지
+        &changes,
     )
     .await
     {
-        Ok(count) => {
-            info!("자산 곡선 동기화 완료: {} 포인트", count);
-            count
+        Ok(result) => {
+            info!(
+                "자산 곡선 동기화 완료: {} 포인트 (재계산 시작: {:?}, 소급: {})",
+                result.synced_count, result.recompute_from, result.backdated
+            );
+            result.synced_count
         }
         Err(e) => {
             warn!("자산 곡선 동기화 실패 (계속 진행): {}", e);
@@ -1497,7 +1669,9 @@ pub async fn sync_executions(
 // ==================== 헬퍼 함수 ====================
 
 /// DB 연결 풀 조회.
-fn get_db_pool(state: &Arc<AppState>) -> Result<&sqlx::PgPool, (StatusCode, Json<ApiError>)> {
+pub(super) fn get_db_pool(
+    state: &Arc<AppState>,
+) -> Result<&sqlx::PgPool, (StatusCode, Json<ApiError>)> {
     state.db_pool.as_ref().ok_or_else(|| {
         (
             StatusCode::SERVICE_UNAVAILABLE,
@@ -1510,7 +1684,7 @@ fn get_db_pool(state: &Arc<AppState>) -> Result<&sqlx::PgPool, (StatusCode, Json
 }
 
 /// 활성 계정의 credential_id 조회.
-async fn get_active_credential_id(
+pub(super) async fn get_active_credential_id(
     state: &Arc<AppState>,
 ) -> Result<Uuid, (StatusCode, Json<ApiError>)> {
     let pool = get_db_pool(state)?;
@@ -1830,8 +2004,19 @@ pub fn journal_router() -> Router<Arc<AppState>> {
         // 인사이트 API
         .route("/insights", get(get_trading_insights))
         .route("/strategies", get(get_strategy_performance))
+        .route("/dca", get(get_dca_report))
+        .route("/fx-conversions", get(get_fx_conversion_journal))
         // 원가 계산 API
         .route("/cost-basis/{symbol}", get(get_cost_basis))
+        // 내보내기 API
+        .route(
+            "/export/executions",
+            get(super::journal_export::export_executions),
+        )
+        .route(
+            "/export/positions",
+            get(super::journal_export::export_positions),
+        )
 }
 
 // ==================== 테스트 ====================
@@ -0,0 +1,1229 @@
+//! 매매일지 내보내기 endpoint.
+//!
+//! 체결 내역과 보유 포지션을 외부 포트폴리오 트래커나 세무 대리인이 바로 읽을 수 있는
+//! 파일로 내려줍니다. 응답은 `Content-Disposition: attachment` 파일입니다.
+//!
+//! - `csv`: 범용 CSV (영문 헤더)
+//! - `ofx`: OFX 2.2 투자 명세 (`INVSTMTRS`, 포트폴리오 트래커 가져오기용)
+//! - `kr_broker`: 증권사 거래내역/잔고 양식 (한글 헤더, 엑셀 호환 UTF-8 BOM)
+//! - `kr_ledger`: 간편장부 양식 (체결 내역 전용)
+//!
+//! 통화는 `currency`로 고릅니다. `native`(기본)는 종목의 거래 통화를 그대로 쓰고,
+//! `KRW`/`USD`는 체결일의 USDKRW 일봉 종가(없으면 직전 종가)로 환산합니다.
+//! `fx_rate`를 주면 모든 행에 그 환율을 적용합니다 (연말 매매기준율 등).
+//!
+//! # 엔드포인트
+//!
+//! - `GET /api/v1/journal/export/executions` - 기간별 체결 내역 내보내기
+//! - `GET /api/v1/journal/export/positions` - 현재 보유 포지션 내보내기
+
+use axum::{
+    extract::{Query, State},
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate, TimeZone, Utc};
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use tracing::warn;
+use trader_core::{Side, Timeframe};
+use trader_execution::is_us_equity;
+use ts_rs::TS;
+use uuid::Uuid;
+
+use super::journal::{get_active_credential_id, get_db_pool, parse_date_flexible};
+use crate::repository::{
+    ExecutionFilter, JournalRepository, PositionRecord, PositionRepository, TradeExecutionRecord,
+};
+use crate::routes::strategies::ApiError;
+use crate::services::fx_conversion::REFERENCE_RATE_TICKER;
+use crate::state::AppState;
+
+/// 한 번에 내보낼 최대 체결 건수.
+const MAX_EXPORT_ROWS: i64 = 100_000;
+
+/// 엑셀에서 한글 CSV가 깨지지 않도록 붙이는 UTF-8 BOM.
+const UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
+
+/// 거래일자 기준 시간대 (KST).
+fn kst() -> FixedOffset {
+    FixedOffset::east_opt(9 * 3600).expect("valid KST offset")
+}
+
+// ==================== 형식/통화 ====================
+
+/// 내보내기 형식.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// 범용 CSV
+    Csv,
+    /// OFX 2.2 투자 명세
+    Ofx,
+    /// 증권사 거래내역/잔고 양식
+    KrBroker,
+    /// 간편장부 양식
+    KrLedger,
+}
+
+impl ExportFormat {
+    /// 쿼리 문자열 파싱 (`csv`, `ofx`, `kr_broker`, `kr_ledger`).
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "csv" => Some(Self::Csv),
+            "ofx" => Some(Self::Ofx),
+            "kr_broker" => Some(Self::KrBroker),
+            "kr_ledger" => Some(Self::KrLedger),
+            _ => None,
+        }
+    }
+
+    fn content_type(self) -> &'static str {
+        match self {
+            Self::Ofx => "application/x-ofx",
+            _ => "text/csv; charset=utf-8",
+        }
+    }
+
+    fn extension(self) -> &'static str {
+        match self {
+            Self::Ofx => "ofx",
+            _ => "csv",
+        }
+    }
+}
+
+/// 내보내기 통화.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportCurrency {
+    /// 종목 거래 통화 그대로
+    Native,
+    Krw,
+    Usd,
+}
+
+impl ExportCurrency {
+    /// 쿼리 문자열 파싱 (`native`, `KRW`, `USD`, 대소문자 무관).
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_uppercase().as_str() {
+            "NATIVE" => Some(Self::Native),
+            "KRW" => Some(Self::Krw),
+            "USD" => Some(Self::Usd),
+            _ => None,
+        }
+    }
+
+    fn code(self) -> Option<&'static str> {
+        match self {
+            Self::Native => None,
+            Self::Krw => Some("KRW"),
+            Self::Usd => Some("USD"),
+        }
+    }
+}
+
+/// 종목의 거래 통화.
+///
+/// 미국 주식은 USD, 국내 주식은 KRW, 암호화폐(`BTC/USDT`)는 호가 통화를 쓰며
+/// 달러 스테이블코인은 USD로 봅니다.
+pub fn native_currency(symbol: &str) -> String {
+    if let Some((_, quote)) = symbol.split_once('/') {
+        let quote = quote.to_uppercase();
+        if matches!(quote.as_str(), "USDT" | "USDC" | "BUSD") {
+            return "USD".to_string();
+        }
+        return quote;
+    }
+    if is_us_equity(symbol) { "USD" } else { "KRW" }.to_string()
+}
+
+/// 통화별 금액 소수 자릿수 (환산 금액 반올림용).
+fn minor_units(currency: &str) -> u32 {
+    if currency == "KRW" {
+        0
+    } else {
+        2
+    }
+}
+
+/// USD/KRW 환율표.
+#[derive(Debug, Clone, Default)]
+pub struct FxRates {
+    /// 일자별 USDKRW 종가
+    daily: BTreeMap<NaiveDate, Decimal>,
+    /// 지정 환율 (있으면 일자와 관계없이 사용)
+    fixed: Option<Decimal>,
+}
+
+impl FxRates {
+    /// 모든 일자에 같은 환율을 적용.
+    pub fn fixed(rate: Decimal) -> Self {
+        Self {
+            daily: BTreeMap::new(),
+            fixed: Some(rate),
+        }
+    }
+
+    /// 일자별 종가로 구성.
+    pub fn from_daily(rates: impl IntoIterator<Item = (NaiveDate, Decimal)>) -> Self {
+        Self {
+            daily: rates
+                .into_iter()
+                .filter(|(_, r)| *r > Decimal::ZERO)
+                .collect(),
+            fixed: None,
+        }
+    }
+
+    /// 해당 일자의 USD/KRW 환율.
+    ///
+    /// 휴장일처럼 그 날 종가가 없으면 직전 종가를, 그보다 앞선 값이 없으면 가장 이른 종가를 씁니다.
+    pub fn usdkrw_on(&self, date: NaiveDate) -> Option<Decimal> {
+        if self.fixed.is_some() {
+            return self.fixed;
+        }
+        self.daily
+            .range(..=date)
+            .next_back()
+            .or_else(|| self.daily.iter().next())
+            .map(|(_, rate)| *rate)
+    }
+
+    /// `from` 금액을 `to` 통화로 바꾸는 배율과 적용 환율.
+    ///
+    /// 같은 통화면 `(1, None)`, USD/KRW 외의 조합이나 환율이 없으면 `None`입니다.
+    pub fn factor(
+        &self,
+        from: &str,
+        to: &str,
+        date: NaiveDate,
+    ) -> Option<(Decimal, Option<Decimal>)> {
+        if from == to {
+            return Some((Decimal::ONE, None));
+        }
+        let rate = self.usdkrw_on(date)?;
+        match (from, to) {
+            ("USD", "KRW") => Some((rate, Some(rate))),
+            ("KRW", "USD") => Some((Decimal::ONE / rate, Some(rate))),
+            _ => None,
+        }
+    }
+}
+
+// ==================== 내보내기 행 ====================
+
+/// 표시 통화로 환산한 체결 행.
+#[derive(Debug, Clone)]
+pub struct ExportExecution {
+    pub id: Uuid,
+    pub executed_at: DateTime<Utc>,
+    pub exchange: String,
+    pub symbol: String,
+    pub symbol_name: Option<String>,
+    pub side: Side,
+    pub quantity: Decimal,
+    pub price: Decimal,
+    /// 거래금액 (수량 × 단가)
+    pub amount: Decimal,
+    pub fee: Decimal,
+    pub realized_pnl: Option<Decimal>,
+    /// 표시 통화
+    pub currency: String,
+    /// 적용 USD/KRW 환율 (환산하지 않았으면 `None`)
+    pub fx_rate: Option<Decimal>,
+}
+
+impl ExportExecution {
+    /// 체결 기록을 표시 통화로 환산 (환율이 없으면 `None`).
+    ///
+    /// 수수료는 체결 통화로 부과된 것으로 봅니다.
+    pub fn convert(
+        record: &TradeExecutionRecord,
+        target: ExportCurrency,
+        rates: &FxRates,
+    ) -> Option<Self> {
+        let native = native_currency(&record.symbol);
+        let currency = target
+            .code()
+            .map(str::to_string)
+            .unwrap_or_else(|| native.clone());
+        let date = record.executed_at.with_timezone(&kst()).date_naive();
+        let (factor, fx_rate) = rates.factor(&native, &currency, date)?;
+        let convert = |v: Decimal, extra_dp: u32| {
+            if fx_rate.is_some() {
+                (v * factor).round_dp(minor_units(&currency) + extra_dp)
+            } else {
+                v
+            }
+        };
+
+        Some(Self {
+            id: record.id,
+            executed_at: record.executed_at,
+            exchange: record.exchange.clone(),
+            symbol: record.symbol.clone(),
+            symbol_name: record.symbol_name.clone(),
+            side: record.side,
+            quantity: record.quantity,
+            price: convert(record.price, 2),
+            amount: convert(record.notional_value, 0),
+            fee: convert(record.fee.unwrap_or(Decimal::ZERO), 0),
+            realized_pnl: record.realized_pnl.map(|v| convert(v, 0)),
+            currency,
+            fx_rate,
+        })
+    }
+
+    /// 정산 금액 (매수는 지급액, 매도는 수령액).
+    pub fn settlement(&self) -> Decimal {
+        match self.side {
+            Side::Buy => self.amount + self.fee,
+            Side::Sell => self.amount - self.fee,
+        }
+    }
+
+    fn label(&self) -> String {
+        match &self.symbol_name {
+            Some(name) => format!("{}({})", name, self.symbol),
+            None => self.symbol.clone(),
+        }
+    }
+}
+
+/// 표시 통화로 환산한 보유 포지션 행.
+#[derive(Debug, Clone)]
+pub struct ExportPosition {
+    pub exchange: String,
+    pub symbol: String,
+    pub symbol_name: Option<String>,
+    pub side: Side,
+    pub quantity: Decimal,
+    pub entry_price: Decimal,
+    /// 매입금액
+    pub cost_basis: Decimal,
+    pub current_price: Option<Decimal>,
+    /// 평가금액
+    pub market_value: Option<Decimal>,
+    pub unrealized_pnl: Option<Decimal>,
+    pub currency: String,
+    pub fx_rate: Option<Decimal>,
+}
+
+impl ExportPosition {
+    /// 포지션을 `date` 환율로 환산 (환율이 없으면 `None`).
+    pub fn convert(
+        record: &PositionRecord,
+        target: ExportCurrency,
+        rates: &FxRates,
+        date: NaiveDate,
+    ) -> Option<Self> {
+        let symbol = record.symbol.clone().unwrap_or_default();
+        let native = native_currency(&symbol);
+        let currency = target
+            .code()
+            .map(str::to_string)
+            .unwrap_or_else(|| native.clone());
+        let (factor, fx_rate) = rates.factor(&native, &currency, date)?;
+        let convert = |v: Decimal, extra_dp: u32| {
+            if fx_rate.is_some() {
+                (v * factor).round_dp(minor_units(&currency) + extra_dp)
+            } else {
+                v
+            }
+        };
+
+        Some(Self {
+            exchange: record.exchange.clone(),
+            symbol,
+            symbol_name: record.symbol_name.clone(),
+            side: record.side,
+            quantity: record.quantity,
+            entry_price: convert(record.entry_price, 2),
+            cost_basis: convert(record.entry_price * record.quantity, 0),
+            current_price: record.current_price.map(|v| convert(v, 2)),
+            market_value: record
+                .current_price
+                .map(|v| convert(v * record.quantity, 0)),
+            unrealized_pnl: record.unrealized_pnl.map(|v| convert(v, 0)),
+            currency,
+            fx_rate,
+        })
+    }
+
+    /// 평가 수익률 (%, 소수 둘째 자리).
+    pub fn return_pct(&self) -> Option<Decimal> {
+        if self.cost_basis <= Decimal::ZERO {
+            return None;
+        }
+        self.unrealized_pnl
+            .map(|pnl| (pnl / self.cost_basis * Decimal::from(100)).round_dp(2))
+    }
+}
+
+/// 모든 행이 같은 통화면 그 통화 (OFX는 명세당 통화 하나만 허용).
+pub fn single_currency<'a>(currencies: impl IntoIterator<Item = &'a str>) -> Option<&'a str> {
+    let mut currencies = currencies.into_iter();
+    let first = currencies.next()?;
+    currencies.all(|c| c == first).then_some(first)
+}
+
+// ==================== 형식별 작성 ====================
+
+fn num(v: Decimal) -> String {
+    v.normalize().to_string()
+}
+
+fn opt_num(v: Option<Decimal>) -> String {
+    v.map(num).unwrap_or_default()
+}
+
+fn side_kr(side: Side) -> &'static str {
+    match side {
+        Side::Buy => "매수",
+        Side::Sell => "매도",
+    }
+}
+
+fn write_csv(headers: &[&str], rows: Vec<Vec<String>>, bom: bool) -> Result<Vec<u8>, csv::Error> {
+    let mut buf = Vec::new();
+    if bom {
+        buf.extend_from_slice(UTF8_BOM);
+    }
+    let mut writer = csv::Writer::from_writer(buf);
+    writer.write_record(headers)?;
+    for row in rows {
+        writer.write_record(&row)?;
+    }
+    writer.into_inner().map_err(|e| e.into_error().into())
+}
+
+/// 체결 내역 CSV (범용/증권사/간편장부 양식).
+pub fn executions_csv(
+    format: ExportFormat,
+    rows: &[ExportExecution],
+) -> Result<Vec<u8>, csv::Error> {
+    match format {
+        ExportFormat::KrBroker => write_csv(
+            &[
+                "거래일자",
+                "거래시각",
+                "거래소",
+                "종목코드",
+                "종목명",
+                "매매구분",
+                "수량",
+                "단가",
+                "거래금액",
+                "수수료",
+                "정산금액",
+                "실현손익",
+                "통화",
+                "적용환율",
+            ],
+            rows.iter()
+                .map(|r| {
+                    let local = r.executed_at.with_timezone(&kst());
+                    vec![
+                        local.format("%Y-%m-%d").to_string(),
+                        local.format("%H:%M:%S").to_string(),
+                        r.exchange.clone(),
+                        r.symbol.clone(),
+                        r.symbol_name.clone().unwrap_or_default(),
+                        side_kr(r.side).to_string(),
+                        num(r.quantity),
+                        num(r.price),
+                        num(r.amount),
+                        num(r.fee),
+                        num(r.settlement()),
+                        opt_num(r.realized_pnl),
+                        r.currency.clone(),
+                        opt_num(r.fx_rate),
+                    ]
+                })
+                .collect(),
+            true,
+        ),
+        ExportFormat::KrLedger => write_csv(
+            &[
+                "일자",
+                "계정과목",
+                "거래내용",
+                "거래처",
+                "수입",
+                "비용",
+                "비고",
+            ],
+            rows.iter()
+                .map(|r| {
+                    let (account, income, expense) = match r.side {
+                        Side::Buy => ("유가증권 매입", String::new(), num(r.settlement())),
+                        Side::Sell => ("유가증권 매도", num(r.settlement()), String::new()),
+                    };
+                    let mut note = format!("수수료 {} {}", num(r.fee), r.currency);
+                    if let Some(pnl) = r.realized_pnl {
+                        note.push_str(&format!(", 실현손익 {} {}", num(pnl), r.currency));
+                    }
+                    vec![
+                        r.executed_at
+                            .with_timezone(&kst())
+                            .format("%Y-%m-%d")
+                            .to_string(),
+                        account.to_string(),
+                        format!("{} {} {}", r.label(), side_kr(r.side), num(r.quantity)),
+                        r.exchange.clone(),
+                        income,
+                        expense,
+                        note,
+                    ]
+                })
+                .collect(),
+            true,
+        ),
+        _ => write_csv(
+            &[
+                "id",
+                "executed_at",
+                "exchange",
+                "symbol",
+                "symbol_name",
+                "side",
+                "quantity",
+                "price",
+                "amount",
+                "fee",
+                "settlement",
+                "realized_pnl",
+                "currency",
+                "fx_rate",
+            ],
+            rows.iter()
+                .map(|r| {
+                    vec![
+                        r.id.to_string(),
+                        r.executed_at.to_rfc3339(),
+                        r.exchange.clone(),
+                        r.symbol.clone(),
+                        r.symbol_name.clone().unwrap_or_default(),
+                        r.side.as_str().to_string(),
+                        num(r.quantity),
+                        num(r.price),
+                        num(r.amount),
+                        num(r.fee),
+                        num(r.settlement()),
+                        opt_num(r.realized_pnl),
+                        r.currency.clone(),
+                        opt_num(r.fx_rate),
+                    ]
+                })
+                .collect(),
+            false,
+        ),
+    }
+}
+
+/// 보유 포지션 CSV (범용/증권사 잔고 양식).
+pub fn positions_csv(format: ExportFormat, rows: &[ExportPosition]) -> Result<Vec<u8>, csv::Error> {
+    let korean = format == ExportFormat::KrBroker;
+    let headers: &[&str] = if korean {
+        &[
+            "거래소",
+            "종목코드",
+            "종목명",
+            "구분",
+            "보유수량",
+            "매입단가",
+            "매입금액",
+            "현재가",
+            "평가금액",
+            "평가손익",
+            "수익률(%)",
+            "통화",
+            "적용환율",
+        ]
+    } else {
+        &[
+            "exchange",
+            "symbol",
+            "symbol_name",
+            "side",
+            "quantity",
+            "entry_price",
+            "cost_basis",
+            "current_price",
+            "market_value",
+            "unrealized_pnl",
+            "return_pct",
+            "currency",
+            "fx_rate",
+        ]
+    };
+    let rows = rows
+        .iter()
+        .map(|r| {
+            vec![
+                r.exchange.clone(),
+                r.symbol.clone(),
+                r.symbol_name.clone().unwrap_or_default(),
+                if korean {
+                    side_kr(r.side)
+                } else {
+                    r.side.as_str()
+                }
+                .to_string(),
+                num(r.quantity),
+                num(r.entry_price),
+                num(r.cost_basis),
+                opt_num(r.current_price),
+                opt_num(r.market_value),
+                opt_num(r.unrealized_pnl),
+                opt_num(r.return_pct()),
+                r.currency.clone(),
+                opt_num(r.fx_rate),
+            ]
+        })
+        .collect();
+    write_csv(headers, rows, korean)
+}
+
+fn ofx_datetime(dt: DateTime<Utc>) -> String {
+    dt.format("%Y%m%d%H%M%S.000[0:GMT]").to_string()
+}
+
+fn xml_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+fn ofx_secid(symbol: &str) -> String {
+    format!(
+        "<SECID><UNIQUEID>{}</UNIQUEID><UNIQUEIDTYPE>TICKER</UNIQUEIDTYPE></SECID>",
+        xml_escape(symbol)
+    )
+}
+
+/// OFX 명세 공통 정보.
+#[derive(Debug, Clone)]
+pub struct OfxAccount {
+    /// 계정 ID (`ACCTID`)
+    pub account_id: String,
+    /// 명세 통화 (`CURDEF`)
+    pub currency: String,
+    /// 작성 시각
+    pub generated_at: DateTime<Utc>,
+}
+
+/// OFX 문서 작성 (`statement`는 `INVSTMTRS` 안의 거래/포지션 목록).
+fn ofx_document(
+    account: &OfxAccount,
+    statement: &str,
+    securities: BTreeMap<&str, Option<&str>>,
+) -> String {
+    let now = ofx_datetime(account.generated_at);
+    let status = "<STATUS><CODE>0</CODE><SEVERITY>INFO</SEVERITY></STATUS>";
+    let mut out = String::new();
+    out.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"no\"?>\n");
+    out.push_str(
+        "<?OFX OFXHEADER=\"200\" VERSION=\"220\" SECURITY=\"NONE\" \
+         OLDFILEUID=\"NONE\" NEWFILEUID=\"NONE\"?>\n",
+    );
+    out.push_str("<OFX>\n");
+    out.push_str(&format!(
+        "<SIGNONMSGSRSV1><SONRS>{}<DTSERVER>{}</DTSERVER><LANGUAGE>KOR</LANGUAGE></SONRS>\
+         </SIGNONMSGSRSV1>\n",
+        status, now
+    ));
+    out.push_str("<INVSTMTMSGSRSV1><INVSTMTTRNRS>\n");
+    out.push_str(&format!("<TRNUID>{}</TRNUID>{}\n", Uuid::new_v4(), status));
+    out.push_str(&format!(
+        "<INVSTMTRS><DTASOF>{}</DTASOF><CURDEF>{}</CURDEF>\n",
+        now, account.currency
+    ));
+    out.push_str(&format!(
+        "<INVACCTFROM><BROKERID>zeroquant</BROKERID><ACCTID>{}</ACCTID></INVACCTFROM>\n",
+        xml_escape(&account.account_id)
+    ));
+    out.push_str(statement);
+    out.push_str("</INVSTMTRS>\n</INVSTMTTRNRS></INVSTMTMSGSRSV1>\n");
+    out.push_str("<SECLISTMSGSRSV1><SECLIST>\n");
+    for (symbol, name) in securities {
+        out.push_str(&format!(
+            "<STOCKINFO><SECINFO>{}<SECNAME>{}</SECNAME><TICKER>{}</TICKER></SECINFO></STOCKINFO>\n",
+            ofx_secid(symbol),
+            xml_escape(name.unwrap_or(symbol)),
+            xml_escape(symbol)
+        ));
+    }
+    out.push_str("</SECLIST></SECLISTMSGSRSV1>\n</OFX>\n");
+    out
+}
+
+/// 체결 내역 OFX (`BUYSTOCK`/`SELLSTOCK`).
+///
+/// 매도 수량은 음수, `TOTAL`은 계좌 현금 증감(매수 음수, 매도 양수)입니다.
+pub fn executions_ofx(
+    account: &OfxAccount,
+    rows: &[ExportExecution],
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> String {
+    let mut statement = format!(
+        "<INVTRANLIST><DTSTART>{}</DTSTART><DTEND>{}</DTEND>\n",
+        ofx_datetime(start),
+        ofx_datetime(end)
+    );
+    for r in rows {
+        let (wrapper, inner, kind, units, total) = match r.side {
+            Side::Buy => (
+                "BUYSTOCK",
+                "INVBUY",
+                "<BUYTYPE>BUY</BUYTYPE>",
+                r.quantity,
+                -r.settlement(),
+            ),
+            Side::Sell => (
+                "SELLSTOCK",
+                "INVSELL",
+                "<SELLTYPE>SELL</SELLTYPE>",
+                -r.quantity,
+                r.settlement(),
+            ),
+        };
+        statement.push_str(&format!(
+            "<{wrapper}><{inner}><INVTRAN><FITID>{}</FITID><DTTRADE>{}</DTTRADE><MEMO>{}</MEMO></INVTRAN>\
+             {}<UNITS>{}</UNITS><UNITPRICE>{}</UNITPRICE><COMMISSION>{}</COMMISSION><TOTAL>{}</TOTAL>\
+             <SUBACCTSEC>CASH</SUBACCTSEC><SUBACCTFUND>CASH</SUBACCTFUND></{inner}>{kind}</{wrapper}>\n",
+            r.id,
+            ofx_datetime(r.executed_at),
+            xml_escape(&r.label()),
+            ofx_secid(&r.symbol),
+            num(units),
+            num(r.price),
+            num(r.fee),
+            num(total),
+        ));
+    }
+    statement.push_str("</INVTRANLIST>\n");
+
+    let securities = rows
+        .iter()
+        .map(|r| (r.symbol.as_str(), r.symbol_name.as_deref()))
+        .collect();
+    ofx_document(account, &statement, securities)
+}
+
+/// 보유 포지션 OFX (`POSSTOCK`, 현재가가 없으면 매입단가 기준).
+pub fn positions_ofx(account: &OfxAccount, rows: &[ExportPosition]) -> String {
+    let as_of = ofx_datetime(account.generated_at);
+    let mut statement = String::from("<INVPOSLIST>\n");
+    for r in rows {
+        let (pos_type, units) = match r.side {
+            Side::Buy => ("LONG", r.quantity),
+            Side::Sell => ("SHORT", -r.quantity),
+        };
+        statement.push_str(&format!(
+            "<POSSTOCK><INVPOS>{}<HELDINACCT>CASH</HELDINACCT><POSTYPE>{}</POSTYPE>\
+             <UNITS>{}</UNITS><UNITPRICE>{}</UNITPRICE><MKTVAL>{}</MKTVAL>\
+             <DTPRICEASOF>{}</DTPRICEASOF></INVPOS></POSSTOCK>\n",
+            ofx_secid(&r.symbol),
+            pos_type,
+            num(units),
+            num(r.current_price.unwrap_or(r.entry_price)),
+            num(r.market_value.unwrap_or(r.cost_basis)),
+            as_of,
+        ));
+    }
+    statement.push_str("</INVPOSLIST>\n");
+
+    let securities = rows
+        .iter()
+        .map(|r| (r.symbol.as_str(), r.symbol_name.as_deref()))
+        .collect();
+    ofx_document(account, &statement, securities)
+}
+
+// ==================== 핸들러 ====================
+
+/// 내보내기 쿼리 파라미터.
+#[derive(Debug, Deserialize, TS)]
+#[ts(export, export_to = "journal/")]
+pub struct JournalExportQuery {
+    /// 형식 (csv, ofx, kr_broker, kr_ledger; 기본 csv)
+    pub format: Option<String>,
+    /// 표시 통화 (native, KRW, USD; 기본 native)
+    pub currency: Option<String>,
+    /// 고정 USD/KRW 환율 (미지정 시 체결일 USDKRW 종가)
+    pub fx_rate: Option<String>,
+    /// 시작 날짜 (YYYY-MM-DD, 한국 시간 기준, 포함)
+    pub start_date: Option<String>,
+    /// 종료 날짜 (YYYY-MM-DD, 한국 시간 기준, 포함)
+    pub end_date: Option<String>,
+    /// 종목 필터 (체결 내역만)
+    pub symbol: Option<String>,
+}
+
+type HandlerError = (StatusCode, Json<ApiError>);
+
+fn bad_request(code: &str, message: impl Into<String>) -> HandlerError {
+    (StatusCode::BAD_REQUEST, Json(ApiError::new(code, message)))
+}
+
+/// 검증된 내보내기 옵션.
+struct ExportOptions {
+    format: ExportFormat,
+    currency: ExportCurrency,
+    fx_rate: Option<Decimal>,
+    start_date: Option<NaiveDate>,
+    end_date: Option<NaiveDate>,
+}
+
+impl ExportOptions {
+    fn from_query(query: &JournalExportQuery) -> Result<Self, HandlerError> {
+        let format = match query.format.as_deref() {
+            Some(s) => ExportFormat::parse(s).ok_or_else(|| {
+                bad_request(
+                    "INVALID_FORMAT",
+                    format!(
+                        "지원하지 않는 형식입니다: '{}' (csv, ofx, kr_broker, kr_ledger)",
+                        s
+                    ),
+                )
+            })?,
+            None => ExportFormat::Csv,
+        };
+        let currency = match query.currency.as_deref() {
+            Some(s) => ExportCurrency::parse(s).ok_or_else(|| {
+                bad_request(
+                    "INVALID_CURRENCY",
+                    format!("지원하지 않는 통화입니다: '{}' (native, KRW, USD)", s),
+                )
+            })?,
+            None => ExportCurrency::Native,
+        };
+        let fx_rate = match query.fx_rate.as_deref() {
+            Some(s) => Some(
+                s.parse::<Decimal>()
+                    .ok()
+                    .filter(|r| *r > Decimal::ZERO)
+                    .ok_or_else(|| {
+                        bad_request(
+                            "INVALID_FX_RATE",
+                            format!("환율은 양수여야 합니다: '{}'", s),
+                        )
+                    })?,
+            ),
+            None => None,
+        };
+        let parse_date = |value: &Option<String>, field: &str| match value {
+            Some(s) => parse_date_flexible(s, field)
+                .map(Some)
+                .map_err(|e| (StatusCode::BAD_REQUEST, Json(e.to_api_error()))),
+            None => Ok(None),
+        };
+        let start_date = parse_date(&query.start_date, "start_date")?;
+        let end_date = parse_date(&query.end_date, "end_date")?;
+        if let (Some(start), Some(end)) = (start_date, end_date) {
+            if start > end {
+                return Err(bad_request(
+                    "INVALID_DATE_RANGE",
+                    "start_date는 end_date보다 늦을 수 없습니다",
+                ));
+            }
+        }
+
+        Ok(Self {
+            format,
+            currency,
+            fx_rate,
+            start_date,
+            end_date,
+        })
+    }
+
+    /// 시작 날짜 00:00 KST.
+    fn start_at(&self) -> Option<DateTime<Utc>> {
+        self.start_date.map(kst_midnight)
+    }
+
+    /// 종료 날짜 다음 날 00:00 KST 직전.
+    fn end_at(&self) -> Option<DateTime<Utc>> {
+        self.end_date
+            .map(|d| kst_midnight(d + Duration::days(1)) - Duration::microseconds(1))
+    }
+}
+
+fn kst_midnight(date: NaiveDate) -> DateTime<Utc> {
+    kst()
+        .from_local_datetime(&date.and_hms_opt(0, 0, 0).expect("valid midnight"))
+        .single()
+        .expect("fixed offset has no gaps")
+        .with_timezone(&Utc)
+}
+
+/// 환산에 필요한 환율 로드.
+///
+/// `fx_rate`가 있으면 고정 환율을, 없으면 기간의 USDKRW 일봉 종가를 씁니다.
+/// 환산할 행이 없으면 조회하지 않습니다.
+async fn load_rates(
+    state: &AppState,
+    options: &ExportOptions,
+    rows: impl IntoIterator<Item = (String, NaiveDate)>,
+) -> FxRates {
+    let Some(target) = options.currency.code() else {
+        return FxRates::default();
+    };
+    if let Some(rate) = options.fx_rate {
+        return FxRates::fixed(rate);
+    }
+    let dates: Vec<NaiveDate> = rows
+        .into_iter()
+        .filter(|(symbol, _)| native_currency(symbol) != target)
+        .map(|(_, date)| date)
+        .collect();
+    let (Some(first), Some(last)) = (dates.iter().min(), dates.iter().max()) else {
+        return FxRates::default();
+    };
+    let Some(provider) = state.data_provider.as_ref() else {
+        return FxRates::default();
+    };
+
+    // 첫 거래일이 휴장일이어도 직전 종가를 쓸 수 있도록 앞쪽 여유를 둔다
+    match provider
+        .get_klines_range(
+            REFERENCE_RATE_TICKER,
+            Timeframe::D1,
+            *first - Duration::days(10),
+            *last,
+        )
+        .await
+    {
+        Ok(klines) => FxRates::from_daily(
+            klines
+                .iter()
+                .map(|k| (k.open_time.with_timezone(&kst()).date_naive(), k.close)),
+        ),
+        Err(e) => {
+            warn!(error = %e, "Failed to load FX rates for journal export");
+            FxRates::default()
+        }
+    }
+}
+
+fn fx_unavailable() -> HandlerError {
+    (
+        StatusCode::SERVICE_UNAVAILABLE,
+        Json(ApiError::new(
+            "FX_RATE_UNAVAILABLE",
+            "USD/KRW 환율을 찾을 수 없습니다. fx_rate로 환율을 지정하세요",
+        )),
+    )
+}
+
+fn db_error(what: &str, e: sqlx::Error) -> HandlerError {
+    (
+        StatusCode::INTERNAL_SERVER_ERROR,
+        Json(ApiError::new(
+            "DB_ERROR",
+            format!("Failed to {}: {}", what, e),
+        )),
+    )
+}
+
+fn ofx_currency<'a>(
+    options: &ExportOptions,
+    currencies: impl IntoIterator<Item = &'a str>,
+) -> Result<String, HandlerError> {
+    if let Some(code) = options.currency.code() {
+        return Ok(code.to_string());
+    }
+    let currencies: Vec<&str> = currencies.into_iter().collect();
+    if currencies.is_empty() {
+        return Ok("KRW".to_string());
+    }
+    single_currency(currencies)
+        .map(str::to_string)
+        .ok_or_else(|| {
+            bad_request(
+                "MIXED_CURRENCY",
+                "OFX는 통화 하나만 담을 수 있습니다. currency=KRW 또는 USD를 지정하세요",
+            )
+        })
+}
+
+fn attachment(format: ExportFormat, name: &str, body: Vec<u8>) -> Response {
+    let filename = format!("{}.{}", name, format.extension());
+    (
+        [
+            (header::CONTENT_TYPE, format.content_type().to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{}\"", filename),
+            ),
+        ],
+        body,
+    )
+        .into_response()
+}
+
+fn csv_error(e: csv::Error) -> HandlerError {
+    (
+        StatusCode::INTERNAL_SERVER_ERROR,
+        Json(ApiError::new(
+            "EXPORT_ERROR",
+            format!("Failed to write CSV: {}", e),
+        )),
+    )
+}
+
+/// 체결 내역 내보내기.
+///
+/// GET /api/v1/journal/export/executions
+///
+/// 기간 안의 체결을 오래된 순으로 내보냅니다 (최대 100,000건).
+pub async fn export_executions(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<JournalExportQuery>,
+) -> Result<Response, HandlerError> {
+    let options = ExportOptions::from_query(&query)?;
+    let pool = get_db_pool(&state)?;
+    let credential_id = get_active_credential_id(&state).await?;
+
+    let filter = ExecutionFilter {
+        symbol: query.symbol.clone(),
+        side: None,
+        strategy_id: None,
+        start_date: options.start_at(),
+        end_date: options.end_at(),
+        limit: Some(MAX_EXPORT_ROWS),
+        offset: None,
+    };
+    let mut records = JournalRepository::list_executions(pool, credential_id, filter)
+        .await
+        .map_err(|e| db_error("list executions", e))?;
+    records.reverse();
+
+    let rates = load_rates(
+        &state,
+        &options,
+        records.iter().map(|r| {
+            (
+                r.symbol.clone(),
+                r.executed_at.with_timezone(&kst()).date_naive(),
+            )
+        }),
+    )
+    .await;
+    let rows = records
+        .iter()
+        .map(|r| ExportExecution::convert(r, options.currency, &rates))
+        .collect::<Option<Vec<_>>>()
+        .ok_or_else(fx_unavailable)?;
+
+    let body = match options.format {
+        ExportFormat::Ofx => {
+            let account = OfxAccount {
+                account_id: credential_id.to_string(),
+                currency: ofx_currency(&options, rows.iter().map(|r| r.currency.as_str()))?,
+                generated_at: Utc::now(),
+            };
+            let start = options
+                .start_at()
+                .or_else(|| rows.first().map(|r| r.executed_at))
+                .unwrap_or(account.generated_at);
+            let end = options.end_at().unwrap_or(account.generated_at);
+            executions_ofx(&account, &rows, start, end).into_bytes()
+        }
+        format => executions_csv(format, &rows).map_err(csv_error)?,
+    };
+
+    let period = |d: Option<NaiveDate>| {
+        d.map(|d| d.format("%Y%m%d").to_string())
+            .unwrap_or_else(|| "all".to_string())
+    };
+    let name = format!(
+        "journal_executions_{}_{}",
+        period(options.start_date),
+        period(options.end_date)
+    );
+    Ok(attachment(options.format, &name, body))
+}
+
+/// 보유 포지션 내보내기.
+///
+/// GET /api/v1/journal/export/positions
+///
+/// 환산은 오늘(없으면 직전) USDKRW 종가를 씁니다. 간편장부 양식은 지원하지 않습니다.
+pub async fn export_positions(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<JournalExportQuery>,
+) -> Result<Response, HandlerError> {
+    let options = ExportOptions::from_query(&query)?;
+    if options.format == ExportFormat::KrLedger {
+        return Err(bad_request(
+            "UNSUPPORTED_FORMAT",
+            "간편장부 양식은 체결 내역만 지원합니다",
+        ));
+    }
+    let pool = get_db_pool(&state)?;
+    let credential_id = get_active_credential_id(&state).await?;
+
+    let positions = PositionRepository::get_open_positions_by_credential(pool, credential_id)
+        .await
+        .map_err(|e| db_error("get positions", e))?;
+
+    let now = Utc::now();
+    let today = now.with_timezone(&kst()).date_naive();
+    let rates = load_rates(
+        &state,
+        &options,
+        positions
+            .iter()
+            .map(|p| (p.symbol.clone().unwrap_or_default(), today)),
+    )
+    .await;
+    let rows = positions
+        .iter()
+        .map(|p| ExportPosition::convert(p, options.currency, &rates, today))
+        .collect::<Option<Vec<_>>>()
+        .ok_or_else(fx_unavailable)?;
+
+    let body = match options.format {
+        ExportFormat::Ofx => {
+            let account = OfxAccount {
+                account_id: credential_id.to_string(),
+                currency: ofx_currency(&options, rows.iter().map(|r| r.currency.as_str()))?,
+                generated_at: now,
+            };
+            positions_ofx(&account, &rows).into_bytes()
+        }
+        format => positions_csv(format, &rows).map_err(csv_error)?,
+    };
+
+    let name = format!("journal_positions_{}", today.format("%Y%m%d"));
+    Ok(attachment(options.format, &name, body))
+}
+
+// ==================== 테스트 ====================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn execution(symbol: &str, side: Side, quantity: Decimal, price: Decimal) -> ExportExecution {
+        ExportExecution {
+            id: Uuid::nil(),
+            executed_at: Utc.with_ymd_and_hms(2026, 3, 2, 1, 30, 0).unwrap(),
+            exchange: "kis".to_string(),
+            symbol: symbol.to_string(),
+            symbol_name: Some("삼성전자".to_string()),
+            side,
+            quantity,
+            price,
+            amount: quantity * price,
+            fee: dec!(150),
+            realized_pnl: (side == Side::Sell).then_some(dec!(20000)),
+            currency: "KRW".to_string(),
+            fx_rate: None,
+        }
+    }
+
+    #[test]
+    fn test_native_currency() {
+        assert_eq!(native_currency("005930"), "KRW");
+        assert_eq!(native_currency("AAPL"), "USD");
+        assert_eq!(native_currency("BTC/USDT"), "USD");
+        assert_eq!(native_currency("BTC/KRW"), "KRW");
+    }
+
+    #[test]
+    fn test_fx_rates_use_previous_close() {
+        let d = |day| NaiveDate::from_ymd_opt(2026, 3, day).unwrap();
+        let rates = FxRates::from_daily([(d(2), dec!(1400)), (d(4), dec!(1420))]);
+
+        // 3일은 종가가 없어 2일 종가, 1일은 앞선 값이 없어 가장 이른 종가
+        assert_eq!(rates.usdkrw_on(d(3)), Some(dec!(1400)));
+        assert_eq!(rates.usdkrw_on(d(1)), Some(dec!(1400)));
+        assert_eq!(
+            rates.factor("USD", "KRW", d(5)),
+            Some((dec!(1420), Some(dec!(1420))))
+        );
+        assert_eq!(rates.factor("KRW", "KRW", d(5)), Some((Decimal::ONE, None)));
+        assert_eq!(rates.factor("EUR", "KRW", d(5)), None);
+        assert_eq!(FxRates::default().factor("USD", "KRW", d(5)), None);
+        assert_eq!(FxRates::fixed(dec!(1300)).usdkrw_on(d(5)), Some(dec!(1300)));
+    }
+
+    #[test]
+    fn test_kr_broker_csv_has_bom_and_korean_columns() {
+        let rows = vec![
+            execution("005930", Side::Buy, dec!(10), dec!(70000)),
+            execution("005930", Side::Sell, dec!(10), dec!(72000)),
+        ];
+        let bytes = executions_csv(ExportFormat::KrBroker, &rows).unwrap();
+        assert!(bytes.starts_with(UTF8_BOM));
+
+        let text = String::from_utf8(bytes[UTF8_BOM.len()..].to_vec()).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+        assert!(lines[0].starts_with("거래일자,거래시각,거래소,종목코드"));
+        // 01:30 UTC = 10:30 KST, 매수 정산금액 = 700000 + 150
+        assert_eq!(
+            lines[1],
+            "2026-03-02,10:30:00,kis,005930,삼성전자,매수,10,70000,700000,150,700150,,KRW,"
+        );
+        assert!(lines[2].contains("매도") && lines[2].contains(",719850,20000,"));
+    }
+
+    #[test]
+    fn test_kr_ledger_splits_income_and_expense() {
+        let rows = vec![
+            execution("005930", Side::Buy, dec!(10), dec!(70000)),
+            execution("005930", Side::Sell, dec!(10), dec!(72000)),
+        ];
+        let bytes = executions_csv(ExportFormat::KrLedger, &rows).unwrap();
+        let text = String::from_utf8(bytes[UTF8_BOM.len()..].to_vec()).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+
+        assert_eq!(lines[0], "일자,계정과목,거래내용,거래처,수입,비용,비고");
+        assert!(
+            lines[1].starts_with("2026-03-02,유가증권 매입,삼성전자(005930) 매수 10,kis,,700150,")
+        );
+        assert!(
+            lines[2].starts_with("2026-03-02,유가증권 매도,삼성전자(005930) 매도 10,kis,719850,,")
+        );
+        assert!(lines[2].contains("실현손익 20000 KRW"));
+    }
+
+    #[test]
+    fn test_executions_ofx_signs_and_securities() {
+        let rows = vec![
+            execution("005930", Side::Buy, dec!(10), dec!(70000)),
+            execution("005930", Side::Sell, dec!(4), dec!(72000)),
+        ];
+        let account = OfxAccount {
+            account_id: "acct".to_string(),
+            currency: "KRW".to_string(),
+            generated_at: Utc.with_ymd_and_hms(2026, 3, 31, 0, 0, 0).unwrap(),
+        };
+        let ofx = executions_ofx(&account, &rows, rows[0].executed_at, account.generated_at);
+
+        assert!(ofx.contains("<CURDEF>KRW</CURDEF>"));
+        assert!(ofx.contains("<DTSTART>20260302013000.000[0:GMT]</DTSTART>"));
+        assert!(ofx.contains("<UNITS>10</UNITS><UNITPRICE>70000</UNITPRICE>"));
+        assert!(ofx.contains("<TOTAL>-700150</TOTAL>"));
+        assert!(ofx.contains("<UNITS>-4</UNITS>"));
+        assert!(ofx.contains("<TOTAL>287850</TOTAL>"));
+        // 종목 정보는 한 번만
+        assert_eq!(ofx.matches("<STOCKINFO>").count(), 1);
+        assert!(ofx.contains("<SECNAME>삼성전자</SECNAME><TICKER>005930</TICKER>"));
+    }
+
+    #[test]
+    fn test_single_currency() {
+        assert_eq!(single_currency(["KRW", "KRW"]), Some("KRW"));
+        assert_eq!(single_currency(["KRW", "USD"]), None);
+        assert_eq!(single_currency(Vec::<&str>::new()), None);
+    }
+}
@@ -5,29 +5,46 @@
 //! # 엔드포인트
 //!
 //! - `GET /api/v1/market/{market}/status` - 시장 상태 조회
+//! - `GET /api/v1/market/{market}/calendar` - 휴장일/단축 거래일 달력 조회
 //! - `GET /api/v1/market/klines` - 캔들스틱 데이터 조회 (실시간 거래소 데이터)
+//! - `GET /api/v1/market/chart` - 캔들 + 지표 오버레이 (단일 호출)
 //! - `GET /api/v1/market/ticker` - 현재가 조회
+//! - `GET /api/v1/market/trading-halts` - 거래정지/VI 발동 종목 조회
+//! - `GET /api/v1/market/broker-downtime` - 브로커 점검/장애 목록 조회
+//! - `POST /api/v1/market/broker-downtime` - 브로커 예정 점검 등록
+//! - `DELETE /api/v1/market/broker-downtime/{id}` - 브로커 점검/장애 구간 삭제
+//! - `GET /api/v1/market/macro-regime` - 매크로 레짐 종합 지표 및 이력 조회
 
 use axum::{
     extract::{Path, Query, State},
     http::StatusCode,
-    routing::get,
+    routing::{delete, get},
     Json, Router,
 };
-use chrono::{Datelike, NaiveTime, Timelike, Utc, Weekday};
+use chrono::{DateTime, Datelike, NaiveDate, NaiveTime, Timelike, Utc};
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use tracing::{debug, error, info, warn};
 
-use trader_core::Timeframe;
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use trader_analytics::{
+    AtrParams, BollingerBandsParams, EmaParams, IndicatorEngine, MacdParams, RsiParams, SmaParams,
+    VwapParams,
+};
+use trader_core::{
+    BrokerDowntime, CalendarMarket, Kline, MacroRegime, MarketCalendar, MarketCalendarEntry,
+    Timeframe, TradingStatusEvent,
+};
 use trader_data::cache::CachedHistoricalDataProvider;
+use trader_data::MacroSeriesStore;
 use trader_exchange::connector::kis::{
     KisAccountType, KisConfig, KisKrClient, KisOAuth, KisUsClient,
 };
 use trader_exchange::historical::HistoricalDataProvider;
 use trader_exchange::YahooFinanceProvider;
 
-use crate::repository::KlinesRepository;
+use crate::repository::{BrokerDowntimeRepository, KlinesRepository};
 use crate::routes::credentials::EncryptedCredentials;
 use crate::routes::strategies::ApiError;
 use crate::state::AppState;
@@ -53,6 +70,64 @@ pub struct MarketStatusResponse {
     /// 현재 세션 (Regular/PreMarket/AfterHours)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub session: Option<String>,
+    /// 브로커 주문 가능 여부 (점검/장애 중이면 false)
+    pub broker_available: bool,
+    /// 진행 중인 브로커 점검/장애
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub broker_downtime: Option<BrokerDowntime>,
+}
+
+/// 거래정지/VI 발동 종목 응답.
+#[derive(Debug, Serialize)]
+pub struct TradingHaltsResponse {
+    /// 종목 수
+    pub total: usize,
+    /// 거래정지/VI 발동 종목 (최근 변경 순)
+    pub symbols: Vec<TradingStatusEvent>,
+}
+
+/// 브로커 점검/장애 목록 응답.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BrokerDowntimeListResponse {
+    /// 주문 브로커 식별자
+    pub broker: String,
+    /// 주문 가능 여부
+    pub available: bool,
+    /// 진행 중인 점검/장애
+    pub active: Option<BrokerDowntime>,
+    /// 진행 중이거나 예정된 구간 (모든 브로커, 시작 시각순)
+    pub windows: Vec<BrokerDowntime>,
+}
+
+/// 브로커 예정 점검 등록 요청.
+#[derive(Debug, Deserialize)]
+pub struct CreateBrokerDowntimeRequest {
+    /// 브로커 식별자 (기본: 주문 브로커)
+    pub broker: Option<String>,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+    /// 점검 사유
+    #[serde(default)]
+    pub reason: String,
+}
+
+/// 시장 달력 조회 쿼리.
+#[derive(Debug, Deserialize)]
+pub struct MarketCalendarQuery {
+    /// 연도 (기본: 올해)
+    pub year: Option<i32>,
+}
+
+/// 시장 달력 응답.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MarketCalendarResponse {
+    /// 시장 코드 (KR/US)
+    pub market: CalendarMarket,
+    pub year: i32,
+    /// 달력 적재 여부 (false면 주말 외 휴장일을 알 수 없음)
+    pub loaded: bool,
+    /// 평일 휴장일과 개장 지연/조기 폐장일 (일자 순)
+    pub entries: Vec<MarketCalendarEntry>,
 }
 
 /// 시장 세션 타입
@@ -88,42 +163,225 @@ impl MarketSession {
 /// - 프리마켓: 04:00-09:30 EST
 /// - 정규장: 09:30-16:00 EST (월-금)
 /// - 애프터아워: 16:00-20:00 EST
+///
+/// 시장 달력이 적재된 연도는 휴장일과 개장 지연/조기 폐장을 반영합니다.
 pub async fn get_market_status(
-    State(_state): State<Arc<AppState>>,
+    State(state): State<Arc<AppState>>,
     Path(market): Path<String>,
 ) -> Result<Json<MarketStatusResponse>, (StatusCode, Json<ApiError>)> {
     let market_upper = market.to_uppercase();
+    let calendar = state.market_calendar.read().await;
+
+    let mut status = match market_upper.as_str() {
+        "KR" => get_kr_market_status(&calendar),
+        "US" => get_us_market_status(&calendar),
+        _ => {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                Json(ApiError {
+                    code: "INVALID_MARKET".to_string(),
+                    message: format!("Invalid market: {}. Supported: KR, US", market),
+                }),
+            ))
+        }
+    };
 
-    match market_upper.as_str() {
-        "KR" => Ok(Json(get_kr_market_status())),
-        "US" => Ok(Json(get_us_market_status())),
-        _ => Err((
+    let broker = order_broker(&state).await;
+    status.broker_downtime = state
+        .broker_downtime
+        .read()
+        .await
+        .active(&broker, Utc::now())
+        .cloned();
+    status.broker_available = status.broker_downtime.is_none();
+    Ok(Json(status))
+}
+
+/// 시장 휴장일/단축 거래일 달력 조회.
+///
+/// GET /api/v1/market/{market}/calendar?year=2026
+///
+/// 시장 달력 동기화 서비스가 적재한 달력을 반환합니다.
+pub async fn get_market_calendar(
+    State(state): State<Arc<AppState>>,
+    Path(market): Path<String>,
+    Query(query): Query<MarketCalendarQuery>,
+) -> Result<Json<MarketCalendarResponse>, (StatusCode, Json<ApiError>)> {
+    let market = market.parse::<CalendarMarket>().map_err(|_| {
+        (
             StatusCode::BAD_REQUEST,
             Json(ApiError {
                 code: "INVALID_MARKET".to_string(),
                 message: format!("Invalid market: {}. Supported: KR, US", market),
             }),
-        )),
+        )
+    })?;
+    let year = query.year.unwrap_or_else(|| Utc::now().year());
+
+    let calendar = state.market_calendar.read().await;
+    let loaded = calendar.loaded_years(market).contains(&year);
+    let entries = calendar
+        .entries_for_year(market, year)
+        .into_iter()
+        .cloned()
+        .collect();
+
+    Ok(Json(MarketCalendarResponse {
+        market,
+        year,
+        loaded,
+        entries,
+    }))
+}
+
+/// 거래정지/VI 발동 종목 조회.
+///
+/// GET /api/v1/market/trading-halts
+///
+/// 실시간 장운영정보(KIS `H0STMKO0`)로 감지한 종목만 포함합니다.
+/// 거래정지 종목은 모든 주문이, VI 발동 종목은 시장가 주문이 실행기에서 보류됩니다.
+pub async fn get_trading_halts(State(state): State<Arc<AppState>>) -> Json<TradingHaltsResponse> {
+    let mut symbols = state
+        .strategy_engine
+        .read()
+        .await
+        .get_trading_statuses()
+        .await;
+    symbols.sort_by_key(|s| std::cmp::Reverse(s.timestamp));
+
+    Json(TradingHaltsResponse {
+        total: symbols.len(),
+        symbols,
+    })
+}
+
+/// 브로커 점검/장애 목록 조회.
+///
+/// GET /api/v1/market/broker-downtime
+///
+/// 진행 중이거나 예정된 점검과 감지 장애를 반환합니다.
+/// 점검/장애 중에는 실행기가 신규 주문을 거부하거나 대기열에 보관합니다.
+pub async fn list_broker_downtime(
+    State(state): State<Arc<AppState>>,
+) -> Json<BrokerDowntimeListResponse> {
+    let broker = order_broker(&state).await;
+    let now = Utc::now();
+    let calendar = state.broker_downtime.read().await;
+    let active = calendar.active(&broker, now).cloned();
+
+    Json(BrokerDowntimeListResponse {
+        broker,
+        available: active.is_none(),
+        active,
+        windows: calendar.upcoming(now).into_iter().cloned().collect(),
+    })
+}
+
+/// 브로커 예정 점검 등록.
+///
+/// POST /api/v1/market/broker-downtime
+pub async fn create_broker_downtime(
+    State(state): State<Arc<AppState>>,
+    Json(request): Json<CreateBrokerDowntimeRequest>,
+) -> Result<(StatusCode, Json<BrokerDowntime>), (StatusCode, Json<ApiError>)> {
+    let pool = state.db_pool.as_ref().ok_or_else(db_not_configured)?;
+    let broker = match request.broker {
+        Some(broker) => broker,
+        None => order_broker(&state).await,
+    };
+    let window =
+        BrokerDowntime::scheduled(broker, request.starts_at, request.ends_at, request.reason)
+            .map_err(|e| {
+                (
+                    StatusCode::BAD_REQUEST,
+                    Json(ApiError::new("INVALID_DOWNTIME", e)),
+                )
+            })?;
+
+    BrokerDowntimeRepository::insert(pool, &window)
+        .await
+        .map_err(downtime_db_error)?;
+    state.broker_downtime.write().await.insert(window.clone());
+    info!(
+        broker = %window.broker,
+        starts_at = %window.starts_at,
+        "브로커 점검 등록"
+    );
+    Ok((StatusCode::CREATED, Json(window)))
+}
+
+/// 브로커 점검/장애 구간 삭제.
+///
+/// DELETE /api/v1/market/broker-downtime/{id}
+pub async fn delete_broker_downtime(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<uuid::Uuid>,
+) -> Result<StatusCode, (StatusCode, Json<ApiError>)> {
+    let pool = state.db_pool.as_ref().ok_or_else(db_not_configured)?;
+    let deleted = BrokerDowntimeRepository::delete(pool, id)
+        .await
+        .map_err(downtime_db_error)?;
+    let removed = state.broker_downtime.write().await.remove(id).is_some();
+
+    if !deleted && !removed {
+        return Err((
+            StatusCode::NOT_FOUND,
+            Json(ApiError::new(
+                "DOWNTIME_NOT_FOUND",
+                format!("Broker downtime not found: {}", id),
+            )),
+        ));
     }
+    info!(%id, "브로커 점검/장애 구간 삭제");
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// 실행기의 주문 브로커 식별자.
+async fn order_broker(state: &AppState) -> String {
+    state
+        .executor
+        .read()
+        .await
+        .broker_downtime()
+        .config()
+        .broker
+        .clone()
+}
+
+fn db_not_configured() -> (StatusCode, Json<ApiError>) {
+    (
+        StatusCode::SERVICE_UNAVAILABLE,
+        Json(ApiError::new(
+            "DB_NOT_CONFIGURED",
+            "데이터베이스 연결이 설정되지 않았습니다.",
+        )),
+    )
+}
+
+fn downtime_db_error(e: sqlx::Error) -> (StatusCode, Json<ApiError>) {
+    error!(error = %e, "브로커 점검/장애 저장 실패");
+    (
+        StatusCode::INTERNAL_SERVER_ERROR,
+        Json(ApiError::new("DB_ERROR", e.to_string())),
+    )
 }
 
 /// 한국 시장 상태 계산.
 ///
 /// 정규장: 09:00-15:30 KST (UTC+9)
-fn get_kr_market_status() -> MarketStatusResponse {
-    let now = Utc::now();
+fn get_kr_market_status(calendar: &MarketCalendar) -> MarketStatusResponse {
     // KST = UTC + 9시간
-    let kst_hour = (now.hour() + 9) % 24;
-    let kst_minute = now.minute();
-
-    let is_weekday = !matches!(now.weekday(), Weekday::Sat | Weekday::Sun);
-
-    // 정규장 시간: 09:00-15:30 KST
-    let market_open = NaiveTime::from_hms_opt(9, 0, 0).unwrap();
-    let market_close = NaiveTime::from_hms_opt(15, 30, 0).unwrap();
+    let kst_now = Utc::now() + chrono::Duration::hours(9);
+    let kst_hour = kst_now.hour();
+    let kst_minute = kst_now.minute();
     let current_time = NaiveTime::from_hms_opt(kst_hour, kst_minute, 0).unwrap();
 
-    let is_open = is_weekday && current_time >= market_open && current_time < market_close;
+    let is_open = is_within_trading_hours(
+        calendar,
+        CalendarMarket::Kr,
+        kst_now.date_naive(),
+        current_time,
+    );
 
     let session = if is_open {
         Some("Regular".to_string())
@@ -142,6 +400,8 @@ fn get_kr_market_status() -> MarketStatusResponse {
         next_open: None,  // TODO: 다음 개장 시간 계산
         next_close: None, // TODO: 다음 폐장 시간 계산
         session,
+        broker_available: true,
+        broker_downtime: None,
     }
 }
 
@@ -150,29 +410,24 @@ fn get_kr_market_status() -> MarketStatusResponse {
 /// - 프리마켓: 04:00-09:30 EST
 /// - 정규장: 09:30-16:00 EST
 /// - 애프터아워: 16:00-20:00 EST
-fn get_us_market_status() -> MarketStatusResponse {
-    let now = Utc::now();
+fn get_us_market_status(calendar: &MarketCalendar) -> MarketStatusResponse {
     // EST = UTC - 5시간 (DST 미적용시)
     // EDT = UTC - 4시간 (DST 적용시, 3월 둘째 일요일 ~ 11월 첫째 일요일)
     // 간단히 -5로 계산 (정확한 DST 계산은 추후 개선)
-    let est_hour = if now.hour() >= 5 {
-        now.hour() - 5
-    } else {
-        24 + now.hour() - 5
-    };
-    let est_minute = now.minute();
-
-    let is_weekday = !matches!(now.weekday(), Weekday::Sat | Weekday::Sun);
+    let est_now = Utc::now() - chrono::Duration::hours(5);
+    let est_hour = est_now.hour();
+    let est_minute = est_now.minute();
 
     let current_time = NaiveTime::from_hms_opt(est_hour, est_minute, 0).unwrap();
 
-    // 시간대 정의
+    // 시간대 정의 (조기 폐장일은 애프터아워도 4시간 뒤 종료)
+    let trading_hours = calendar.trading_hours(CalendarMarket::Us, est_now.date_naive());
     let premarket_open = NaiveTime::from_hms_opt(4, 0, 0).unwrap();
-    let regular_open = NaiveTime::from_hms_opt(9, 30, 0).unwrap();
-    let regular_close = NaiveTime::from_hms_opt(16, 0, 0).unwrap();
-    let afterhours_close = NaiveTime::from_hms_opt(20, 0, 0).unwrap();
+    let (regular_open, regular_close) =
+        trading_hours.unwrap_or_else(|| CalendarMarket::Us.regular_hours());
+    let afterhours_close = regular_close + chrono::Duration::hours(4);
 
-    let (is_open, session) = if !is_weekday {
+    let (is_open, session) = if trading_hours.is_none() {
         (false, MarketSession::Closed)
     } else if current_time >= premarket_open && current_time < regular_open {
         (true, MarketSession::PreMarket)
@@ -195,9 +450,23 @@ fn get_us_market_status() -> MarketStatusResponse {
         next_open: None,
         next_close: None,
         session: session.as_str().map(|s| s.to_string()),
+        broker_available: true,
+        broker_downtime: None,
     }
 }
 
+/// 달력 기준 정규장 시간 여부 (휴장일이면 `false`).
+fn is_within_trading_hours(
+    calendar: &MarketCalendar,
+    market: CalendarMarket,
+    date: NaiveDate,
+    time: NaiveTime,
+) -> bool {
+    calendar
+        .trading_hours(market, date)
+        .is_some_and(|(open, close)| time >= open && time < close)
+}
+
 // ==================== 캔들스틱 데이터 ====================
 
 /// 캔들스틱 데이터 쿼리.
@@ -447,56 +716,30 @@ fn is_korean_symbol(symbol: &str) -> bool {
     symbol.len() == 6 && symbol.chars().all(|c| c.is_ascii_digit())
 }
 
-/// 캔들스틱 데이터 조회.
-///
-/// GET /api/v1/market/klines
-///
-/// **Yahoo Finance API**를 사용하여 과거 캔들 데이터를 조회합니다.
-/// - 백테스트와 라이브에서 동일한 데이터셋 사용
-/// - DB 캐시를 통한 효율적인 데이터 접근
-/// - 분봉/시간봉: 최근 60일 제한
-/// - 일봉 이상: 수년간 데이터 가능
-/// - 한국 주식: ".KS" 접미사 자동 추가 (코스피)
-///
-/// # 캐싱 전략
-/// - 요청 기반 자동 캐싱 및 증분 업데이트
-/// - 동일 심볼+타임프레임 동시 요청 시 중복 API 호출 방지
-/// - 시장 마감 후에는 불필요한 업데이트 생략
-///
-/// # 지원 간격
-/// - 분봉: 1m, 5m, 15m, 30m
-/// - 시간봉: 1h
-/// - 일봉 이상: 1d, 1wk, 1mo
-pub async fn get_klines(
-    State(state): State<Arc<AppState>>,
-    Query(query): Query<KlinesQuery>,
-) -> Result<Json<KlinesResponse>, (StatusCode, Json<ApiError>)> {
-    // 타임프레임 문자열을 Timeframe enum으로 변환
-    let timeframe = parse_timeframe(&query.timeframe);
-
-    debug!(
-        symbol = %query.symbol,
-        timeframe = %query.timeframe,
-        limit = query.limit,
-        "캔들 데이터 조회 시작"
-    );
-
+/// 단일 타임프레임 캔들 조회 (DB 캐시 우선, 없으면 Yahoo Finance 직접 조회).
+async fn fetch_klines(
+    state: &AppState,
+    symbol: &str,
+    timeframe_str: &str,
+    timeframe: Timeframe,
+    limit: usize,
+) -> Result<Vec<Kline>, (StatusCode, Json<ApiError>)> {
     // DB 연결이 있으면 캐시 기반 제공자 사용, 없으면 직접 Yahoo Finance 사용
-    let klines = if let Some(pool) = &state.db_pool {
+    if let Some(pool) = &state.db_pool {
         let cached_provider = CachedHistoricalDataProvider::new(pool.clone());
 
         debug!(
-            symbol = %query.symbol,
+            symbol = %symbol,
             "캐시 기반 데이터 제공자 사용"
         );
 
         cached_provider
-            .get_klines(&query.symbol, timeframe, query.limit)
+            .get_klines(symbol, timeframe, limit)
             .await
             .map_err(|e| {
                 error!(
-                    symbol = %query.symbol,
-                    timeframe = %query.timeframe,
+                    symbol = %symbol,
+                    timeframe = %timeframe_str,
                     error = %e,
                     "캐시 데이터 조회 실패"
                 );
@@ -507,11 +750,11 @@ pub async fn get_klines(
                         format!("차트 데이터 조회 실패: {}", e),
                     )),
                 )
-            })?
+            })
     } else {
         // Fallback: DB 연결 없이 직접 Yahoo Finance 사용
         debug!(
-            symbol = %query.symbol,
+            symbol = %symbol,
             "DB 연결 없음, 직접 Yahoo Finance 사용"
         );
 
@@ -527,12 +770,12 @@ pub async fn get_klines(
         })?;
 
         provider
-            .get_klines(&query.symbol, timeframe, query.limit)
+            .get_klines(symbol, timeframe, limit)
             .await
             .map_err(|e| {
                 error!(
-                    symbol = %query.symbol,
-                    timeframe = %query.timeframe,
+                    symbol = %symbol,
+                    timeframe = %timeframe_str,
                     error = %e,
                     "Yahoo Finance 데이터 조회 실패"
                 );
@@ -543,8 +786,52 @@ pub async fn get_klines(
                         format!("차트 데이터 조회 실패: {}", e),
                     )),
                 )
-            })?
-    };
+            })
+    }
+}
+
+/// 캔들스틱 데이터 조회.
+///
+/// GET /api/v1/market/klines
+///
+/// **Yahoo Finance API**를 사용하여 과거 캔들 데이터를 조회합니다.
+/// - 백테스트와 라이브에서 동일한 데이터셋 사용
+/// - DB 캐시를 통한 효율적인 데이터 접근
+/// - 분봉/시간봉: 최근 60일 제한
+/// - 일봉 이상: 수년간 데이터 가능
+/// - 한국 주식: ".KS" 접미사 자동 추가 (코스피)
+///
+/// # 캐싱 전략
+/// - 요청 기반 자동 캐싱 및 증분 업데이트
+/// - 동일 심볼+타임프레임 동시 요청 시 중복 API 호출 방지
+/// - 시장 마감 후에는 불필요한 업데이트 생략
+///
+/// # 지원 간격
+/// - 분봉: 1m, 5m, 15m, 30m
+/// - 시간봉: 1h
+/// - 일봉 이상: 1d, 1wk, 1mo
+pub async fn get_klines(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<KlinesQuery>,
+) -> Result<Json<KlinesResponse>, (StatusCode, Json<ApiError>)> {
+    // 타임프레임 문자열을 Timeframe enum으로 변환
+    let timeframe = parse_timeframe(&query.timeframe);
+
+    debug!(
+        symbol = %query.symbol,
+        timeframe = %query.timeframe,
+        limit = query.limit,
+        "캔들 데이터 조회 시작"
+    );
+
+    let klines = fetch_klines(
+        &state,
+        &query.symbol,
+        &query.timeframe,
+        timeframe,
+        query.limit,
+    )
+    .await?;
 
     info!(
         symbol = %query.symbol,
@@ -772,6 +1059,414 @@ fn parse_timeframe(tf: &str) -> Timeframe {
     }
 }
 
+// ==================== 차트 (캔들 + 지표 오버레이) ====================
+
+/// 차트 데이터 쿼리.
+#[derive(Debug, Deserialize)]
+pub struct ChartQuery {
+    /// 심볼 (예: BTC/USDT, 005930)
+    pub symbol: String,
+    /// 캔들 간격 (1m, 5m, 15m, 1h, 4h, 1d)
+    #[serde(default = "default_timeframe")]
+    pub interval: String,
+    /// 캔들 개수 (기본: 200, 최대: 2000)
+    #[serde(default = "default_chart_limit")]
+    pub limit: usize,
+    /// 쉼표로 구분된 지표 목록 (예: "sma:20,bb:20:2,vwap")
+    #[serde(default)]
+    pub indicators: Option<String>,
+}
+
+fn default_chart_limit() -> usize {
+    200
+}
+
+/// 차트 최대 캔들 개수.
+const MAX_CHART_LIMIT: usize = 2000;
+
+/// 차트 데이터 응답.
+///
+/// 모든 지표 시리즈는 `candles`와 같은 길이이며 같은 인덱스가 같은 시점입니다.
+/// 계산 구간(warm-up) 이전 값은 `null`입니다.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChartResponse {
+    pub symbol: String,
+    pub interval: String,
+    pub candles: Vec<ChartCandle>,
+    pub indicators: Vec<ChartIndicator>,
+}
+
+/// 차트 캔들.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ChartCandle {
+    /// 시가 시각 (Unix timestamp, 초)
+    pub time: i64,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+    pub volume: f64,
+}
+
+/// 지표 오버레이.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ChartIndicator {
+    /// 요청한 지표 스펙 (예: "bb:20:2")
+    pub key: String,
+    /// 지표 종류 (sma, ema, bb, vwap, rsi, macd, atr)
+    pub kind: String,
+    /// 해석된 파라미터
+    pub params: serde_json::Value,
+    /// 라인별 값 (예: bb → upper/middle/lower)
+    pub lines: std::collections::BTreeMap<String, Vec<Option<f64>>>,
+}
+
+/// 지표 라인 (라인 이름, 캔들별 값).
+type IndicatorLine = (&'static str, Vec<Option<Decimal>>);
+
+/// 차트 지표 스펙.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChartIndicatorSpec {
+    Sma {
+        period: usize,
+    },
+    Ema {
+        period: usize,
+    },
+    Bollinger {
+        period: usize,
+        std_dev: Decimal,
+    },
+    Vwap,
+    Rsi {
+        period: usize,
+    },
+    Macd {
+        fast: usize,
+        slow: usize,
+        signal: usize,
+    },
+    Atr {
+        period: usize,
+    },
+}
+
+impl ChartIndicatorSpec {
+    /// `종류:파라미터:...` 형식의 스펙을 해석합니다.
+    ///
+    /// 파라미터를 생략하면 지표별 기본값을 사용합니다.
+    pub fn parse(spec: &str) -> Result<Self, String> {
+        let mut parts = spec.split(':').map(str::trim);
+        let kind = parts.next().unwrap_or_default().to_lowercase();
+        let args: Vec<&str> = parts.collect();
+
+        let period = |idx: usize, default: usize| -> Result<usize, String> {
+            match args.get(idx) {
+                None => Ok(default),
+                Some(v) => v
+                    .parse::<usize>()
+                    .ok()
+                    .filter(|p| (1..=500).contains(p))
+                    .ok_or_else(|| format!("잘못된 기간 값: {spec}")),
+            }
+        };
+
+        let parsed = match kind.as_str() {
+            "sma" => Self::Sma {
+                period: period(0, 20)?,
+            },
+            "ema" => Self::Ema {
+                period: period(0, 20)?,
+            },
+            "bb" | "bollinger" => Self::Bollinger {
+                period: period(0, 20)?,
+                std_dev: match args.get(1) {
+                    None => Decimal::TWO,
+                    Some(v) => v
+                        .parse::<Decimal>()
+                        .ok()
+                        .filter(|d| *d > Decimal::ZERO)
+                        .ok_or_else(|| format!("잘못된 표준편차 배수: {spec}"))?,
+                },
+            },
+            "vwap" => Self::Vwap,
+            "rsi" => Self::Rsi {
+                period: period(0, 14)?,
+            },
+            "macd" => Self::Macd {
+                fast: period(0, 12)?,
+                slow: period(1, 26)?,
+                signal: period(2, 9)?,
+            },
+            "atr" => Self::Atr {
+                period: period(0, 14)?,
+            },
+            _ => return Err(format!("지원하지 않는 지표: {spec}")),
+        };
+
+        Ok(parsed)
+    }
+
+    /// 첫 유효값이 나오기까지 필요한 추가 캔들 수.
+    pub fn warmup(&self) -> usize {
+        match self {
+            Self::Sma { period }
+            | Self::Ema { period }
+            | Self::Bollinger { period, .. }
+            | Self::Rsi { period }
+            | Self::Atr { period } => *period,
+            Self::Macd { slow, signal, .. } => slow + signal,
+            Self::Vwap => 0,
+        }
+    }
+
+    fn kind(&self) -> &'static str {
+        match self {
+            Self::Sma { .. } => "sma",
+            Self::Ema { .. } => "ema",
+            Self::Bollinger { .. } => "bb",
+            Self::Vwap => "vwap",
+            Self::Rsi { .. } => "rsi",
+            Self::Macd { .. } => "macd",
+            Self::Atr { .. } => "atr",
+        }
+    }
+
+    fn params(&self) -> serde_json::Value {
+        match self {
+            Self::Sma { period }
+            | Self::Ema { period }
+            | Self::Rsi { period }
+            | Self::Atr { period } => serde_json::json!({ "period": period }),
+            Self::Bollinger { period, std_dev } => {
+                serde_json::json!({ "period": period, "stdDev": std_dev.to_f64() })
+            }
+            Self::Macd { fast, slow, signal } => {
+                serde_json::json!({ "fast": fast, "slow": slow, "signal": signal })
+            }
+            Self::Vwap => serde_json::json!({}),
+        }
+    }
+
+    /// 캔들 전체 구간에 대해 지표를 계산합니다 (라인 이름 → 값).
+    fn compute(
+        &self,
+        engine: &IndicatorEngine,
+        klines: &[Kline],
+    ) -> Result<Vec<IndicatorLine>, String> {
+        let close: Vec<Decimal> = klines.iter().map(|k| k.close).collect();
+        let high: Vec<Decimal> = klines.iter().map(|k| k.high).collect();
+        let low: Vec<Decimal> = klines.iter().map(|k| k.low).collect();
+
+        let lines = match self {
+            Self::Sma { period } => vec![(
+                "sma",
+                engine
+                    .sma(&close, SmaParams { period: *period })
+                    .map_err(|e| e.to_string())?,
+            )],
+            Self::Ema { period } => vec![(
+                "ema",
+                engine
+                    .ema(&close, EmaParams { period: *period })
+                    .map_err(|e| e.to_string())?,
+            )],
+            Self::Bollinger { period, std_dev } => {
+                let bands = engine
+                    .bollinger_bands(
+                        &close,
+                        BollingerBandsParams {
+                            period: *period,
+                            std_dev_multiplier: *std_dev,
+                        },
+                    )
+                    .map_err(|e| e.to_string())?;
+                vec![
+                    ("upper", bands.iter().map(|b| b.upper).collect()),
+                    ("middle", bands.iter().map(|b| b.middle).collect()),
+                    ("lower", bands.iter().map(|b| b.lower).collect()),
+                ]
+            }
+            Self::Vwap => {
+                let volume: Vec<Decimal> = klines.iter().map(|k| k.volume).collect();
+                let values = engine
+                    .vwap(&high, &low, &close, &volume, VwapParams::default())
+                    .map_err(|e| e.to_string())?;
+                vec![("vwap", values.iter().map(|v| Some(v.vwap)).collect())]
+            }
+            Self::Rsi { period } => vec![(
+                "rsi",
+                engine
+                    .rsi(&close, RsiParams { period: *period })
+                    .map_err(|e| e.to_string())?,
+            )],
+            Self::Macd { fast, slow, signal } => {
+                let values = engine
+                    .macd(
+                        &close,
+                        MacdParams {
+                            fast_period: *fast,
+                            slow_period: *slow,
+                            signal_period: *signal,
+                        },
+                    )
+                    .map_err(|e| e.to_string())?;
+                vec![
+                    ("macd", values.iter().map(|m| m.macd).collect()),
+                    ("signal", values.iter().map(|m| m.signal).collect()),
+                    ("histogram", values.iter().map(|m| m.histogram).collect()),
+                ]
+            }
+            Self::Atr { period } => vec![(
+                "atr",
+                engine
+                    .atr(&high, &low, &close, AtrParams { period: *period })
+                    .map_err(|e| e.to_string())?,
+            )],
+        };
+
+        Ok(lines)
+    }
+}
+
+/// 쉼표로 구분된 지표 목록을 해석합니다 (최대 10개).
+pub fn parse_chart_indicators(raw: &str) -> Result<Vec<(String, ChartIndicatorSpec)>, String> {
+    let specs: Vec<&str> = raw
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .collect();
+
+    if specs.len() > 10 {
+        return Err("최대 10개의 지표만 지원됩니다".to_string());
+    }
+
+    specs
+        .into_iter()
+        .map(|spec| ChartIndicatorSpec::parse(spec).map(|parsed| (spec.to_string(), parsed)))
+        .collect()
+}
+
+/// 캔들과 지표 오버레이를 조립합니다.
+///
+/// `klines`는 warm-up 구간을 포함할 수 있으며, 응답에는 마지막 `limit`개만 포함됩니다.
+/// 지표 계산에 실패하면 (데이터 부족 등) 해당 지표 값은 모두 `null`입니다.
+pub fn build_chart(
+    klines: &[Kline],
+    indicators: &[(String, ChartIndicatorSpec)],
+    limit: usize,
+) -> (Vec<ChartCandle>, Vec<ChartIndicator>) {
+    let skip = klines.len().saturating_sub(limit);
+    let to_f64 = |d: Decimal| d.to_f64().unwrap_or(0.0);
+
+    let candles = klines[skip..]
+        .iter()
+        .map(|k| ChartCandle {
+            time: k.open_time.timestamp(),
+            open: to_f64(k.open),
+            high: to_f64(k.high),
+            low: to_f64(k.low),
+            close: to_f64(k.close),
+            volume: to_f64(k.volume),
+        })
+        .collect::<Vec<_>>();
+
+    let engine = IndicatorEngine::new();
+    let overlays = indicators
+        .iter()
+        .map(|(key, spec)| {
+            let lines = match spec.compute(&engine, klines) {
+                Ok(lines) => lines
+                    .into_iter()
+                    .map(|(name, values)| {
+                        let aligned = values
+                            .into_iter()
+                            .skip(skip)
+                            .map(|v| v.and_then(|d| d.to_f64()))
+                            .collect();
+                        (name.to_string(), aligned)
+                    })
+                    .collect(),
+                Err(e) => {
+                    debug!(indicator = %key, error = %e, "차트 지표 계산 실패");
+                    std::collections::BTreeMap::new()
+                }
+            };
+
+            ChartIndicator {
+                key: key.clone(),
+                kind: spec.kind().to_string(),
+                params: spec.params(),
+                lines,
+            }
+        })
+        .collect();
+
+    (candles, overlays)
+}
+
+/// 캔들과 지표 오버레이를 한 번에 조회.
+///
+/// GET /api/v1/market/chart?symbol=005930&interval=1d&indicators=sma:20,bb:20:2,vwap
+///
+/// # 지원 지표
+/// - `sma:기간`, `ema:기간`
+/// - `bb:기간:표준편차배수` (upper/middle/lower)
+/// - `vwap`
+/// - `rsi:기간`
+/// - `macd:단기:장기:시그널` (macd/signal/histogram)
+/// - `atr:기간`
+///
+/// 지표 warm-up에 필요한 만큼 캔들을 추가로 조회하므로
+/// 응답 첫 캔들부터 지표 값이 채워집니다 (데이터가 충분한 경우).
+pub async fn get_chart(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<ChartQuery>,
+) -> Result<Json<ChartResponse>, (StatusCode, Json<ApiError>)> {
+    let indicators = parse_chart_indicators(query.indicators.as_deref().unwrap_or_default())
+        .map_err(|e| {
+            (
+                StatusCode::BAD_REQUEST,
+                Json(ApiError::new("INVALID_INDICATORS", e)),
+            )
+        })?;
+
+    let limit = query.limit.clamp(1, MAX_CHART_LIMIT);
+    let warmup = indicators
+        .iter()
+        .map(|(_, spec)| spec.warmup())
+        .max()
+        .unwrap_or(0);
+    let timeframe = parse_timeframe(&query.interval);
+
+    let klines = fetch_klines(
+        &state,
+        &query.symbol,
+        &query.interval,
+        timeframe,
+        limit + warmup,
+    )
+    .await?;
+
+    let (candles, indicators) = build_chart(&klines, &indicators, limit);
+
+    debug!(
+        symbol = %query.symbol,
+        interval = %query.interval,
+        candles = candles.len(),
+        indicators = indicators.len(),
+        "차트 데이터 조회 성공"
+    );
+
+    Ok(Json(ChartResponse {
+        symbol: query.symbol,
+        interval: query.interval,
+        candles,
+        indicators,
+    }))
+}
+
 // ==================== 현재가 (Ticker) ====================
 
 /// 현재가 응답.
@@ -991,15 +1686,156 @@ pub async fn get_market_breadth(
     }))
 }
 
+// ==================== 매크로 레짐 ====================
+
+/// 매크로 레짐 이력 최대 조회 일수.
+const MACRO_REGIME_MAX_DAYS: i64 = 3650;
+
+/// 매크로 레짐 조회 쿼리.
+#[derive(Debug, Deserialize)]
+pub struct MacroRegimeQuery {
+    /// 이력 조회 일수 (기본: 90, 최대: 3650)
+    #[serde(default = "default_macro_regime_days")]
+    pub days: i64,
+}
+
+fn default_macro_regime_days() -> i64 {
+    90
+}
+
+/// 일별 매크로 레짐.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MacroRegimeView {
+    /// 기준일 (YYYY-MM-DD)
+    pub date: String,
+    /// 종합 점수 (-1.0 위험 회피 ~ +1.0 위험 선호)
+    pub score: f64,
+    /// 레짐 (RISK_ON/NEUTRAL/RISK_OFF)
+    pub level: String,
+    /// 환율 추세 점수
+    pub fx_score: Option<f64>,
+    /// 금리차 점수
+    pub yield_curve_score: Option<f64>,
+    /// 변동성 점수
+    pub volatility_score: Option<f64>,
+    /// 시장 폭 점수
+    pub breadth_score: Option<f64>,
+    /// USD/KRW 20거래일 변동률 (%)
+    pub usd_krw_trend_pct: Option<f64>,
+    /// 미국 10년-2년 금리차 (%p)
+    pub yield_curve_slope: Option<f64>,
+    /// VIX
+    pub vix: Option<f64>,
+    /// VKOSPI
+    pub vkospi: Option<f64>,
+    /// 20일선 상회 종목 비율 (0.0 ~ 1.0)
+    pub breadth_ratio: Option<f64>,
+}
+
+impl From<&MacroRegime> for MacroRegimeView {
+    fn from(regime: &MacroRegime) -> Self {
+        Self {
+            date: regime.date.to_string(),
+            score: regime.score,
+            level: regime.level.to_string(),
+            fx_score: regime.components.fx,
+            yield_curve_score: regime.components.yield_curve,
+            volatility_score: regime.components.volatility,
+            breadth_score: regime.components.breadth,
+            usd_krw_trend_pct: regime.inputs.usd_krw_trend_pct,
+            yield_curve_slope: regime.inputs.yield_curve_slope,
+            vix: regime.inputs.vix,
+            vkospi: regime.inputs.vkospi,
+            breadth_ratio: regime.inputs.breadth_ratio,
+        }
+    }
+}
+
+/// 매크로 레짐 응답.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MacroRegimeResponse {
+    /// 최신 매크로 레짐 (계산된 값이 없으면 null)
+    pub latest: Option<MacroRegimeView>,
+    /// 기간 내 일별 이력 (오래된 순)
+    pub history: Vec<MacroRegimeView>,
+}
+
+/// 매크로 레짐 종합 지표 조회.
+///
+/// GET /api/v1/market/macro-regime?days=90
+///
+/// 수집기가 매일 계산한 매크로 레짐 점수(USD/KRW 추세, 금리차, VIX/VKOSPI, 시장 폭)의
+/// 최신 값과 최근 `days`일 이력을 반환합니다.
+pub async fn get_macro_regime(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<MacroRegimeQuery>,
+) -> Result<Json<MacroRegimeResponse>, (StatusCode, Json<ApiError>)> {
+    let pool = state.db_pool.as_ref().ok_or_else(|| {
+        (
+            StatusCode::SERVICE_UNAVAILABLE,
+            Json(ApiError::new(
+                "DB_NOT_CONFIGURED",
+                "데이터베이스 연결이 설정되지 않았습니다.",
+            )),
+        )
+    })?;
+
+    if !(1..=MACRO_REGIME_MAX_DAYS).contains(&query.days) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiError::new(
+                "INVALID_DAYS",
+                format!("days는 1~{} 사이여야 합니다.", MACRO_REGIME_MAX_DAYS),
+            )),
+        ));
+    }
+
+    let store = MacroSeriesStore::new(pool.clone());
+    let today = Utc::now().date_naive();
+    let start = today - chrono::Duration::days(query.days - 1);
+
+    let query_error = |e: trader_data::DataError| {
+        error!(error = %e, "매크로 레짐 조회 실패");
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiError::new(
+                "MACRO_REGIME_QUERY_ERROR",
+                format!("매크로 레짐 조회 실패: {}", e),
+            )),
+        )
+    };
+    let latest = store.latest_macro_regime().await.map_err(query_error)?;
+    let history = store
+        .macro_regime_history(start, today)
+        .await
+        .map_err(query_error)?;
+
+    Ok(Json(MacroRegimeResponse {
+        latest: latest.as_ref().map(MacroRegimeView::from),
+        history: history.iter().map(MacroRegimeView::from).collect(),
+    }))
+}
+
 // ==================== 라우터 ====================
 
 /// 시장 상태 라우터 생성.
 pub fn market_router() -> Router<Arc<AppState>> {
     Router::new()
         .route("/breadth", get(get_market_breadth))
+        .route(
+            "/broker-downtime",
+            get(list_broker_downtime).post(create_broker_downtime),
+        )
+        .route("/broker-downtime/{id}", delete(delete_broker_downtime))
+        .route("/chart", get(get_chart))
         .route("/klines", get(get_klines))
         .route("/klines/multi", get(get_multi_klines))
+        .route("/macro-regime", get(get_macro_regime))
         .route("/ticker", get(get_ticker))
+        .route("/trading-halts", get(get_trading_halts))
+        .route("/{market}/calendar", get(get_market_calendar))
         .route("/{market}/status", get(get_market_status))
 }
 
@@ -1094,6 +1930,148 @@ mod tests {
         assert_eq!(response.status(), StatusCode::BAD_REQUEST);
     }
 
+    #[tokio::test]
+    async fn test_market_status_reports_broker_downtime() {
+        use crate::state::create_test_state;
+
+        let state = create_test_state();
+        state
+            .broker_downtime
+            .write()
+            .await
+            .report_outage("kis", "connection reset", Utc::now());
+        let app = Router::new()
+            .route("/market/{market}/status", get(get_market_status))
+            .route(
+                "/market/broker-downtime",
+                get(list_broker_downtime).post(create_broker_downtime),
+            )
+            .with_state(Arc::new(state));
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .uri("/market/KR/status")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["brokerAvailable"], false);
+        assert_eq!(json["brokerDowntime"]["source"], "detected");
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .uri("/market/broker-downtime")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let list: BrokerDowntimeListResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(list.broker, "kis");
+        assert!(!list.available);
+        assert_eq!(list.windows.len(), 1);
+
+        // 점검 등록은 DB 필요
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/market/broker-downtime")
+                    .header("content-type", "application/json")
+                    .body(Body::from(
+                        r#"{"starts_at":"2026-03-07T20:00:00Z","ends_at":"2026-03-07T21:00:00Z"}"#,
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+    }
+
+    #[test]
+    fn test_within_trading_hours_uses_calendar() {
+        let kr = CalendarMarket::Kr;
+        let date = NaiveDate::from_ymd_opt(2026, 1, 2).unwrap();
+        let at = |h, m| NaiveTime::from_hms_opt(h, m, 0).unwrap();
+
+        // 달력 미적재: 평일 정규장
+        let mut calendar = MarketCalendar::new();
+        assert!(is_within_trading_hours(&calendar, kr, date, at(9, 30)));
+
+        // 연초 개장일 10:00 개장, 휴장일
+        let new_year = NaiveDate::from_ymd_opt(2026, 1, 1).unwrap();
+        calendar.load_year(
+            kr,
+            2026,
+            vec![
+                MarketCalendarEntry::holiday(kr, new_year, None),
+                MarketCalendarEntry::late_open(kr, date, at(10, 0), None),
+            ],
+        );
+        assert!(!is_within_trading_hours(&calendar, kr, date, at(9, 30)));
+        assert!(is_within_trading_hours(&calendar, kr, date, at(10, 0)));
+        assert!(!is_within_trading_hours(&calendar, kr, new_year, at(10, 0)));
+    }
+
+    #[tokio::test]
+    async fn test_get_market_calendar() {
+        use crate::state::create_test_state;
+
+        let state = create_test_state();
+        let us = CalendarMarket::Us;
+        state
+            .market_calendar
+            .write()
+            .await
+            .load_year(us, 2026, trader_core::us_early_closes(2026));
+        let app = Router::new()
+            .route("/market/{market}/calendar", get(get_market_calendar))
+            .with_state(Arc::new(state));
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .uri("/market/us/calendar?year=2026")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let calendar: MarketCalendarResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(calendar.market, us);
+        assert!(calendar.loaded);
+        assert_eq!(calendar.entries.len(), 2);
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/market/JP/calendar")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
     #[tokio::test]
     async fn test_get_market_breadth_no_db() {
         use crate::state::create_test_state;
@@ -1117,4 +2095,130 @@ mod tests {
         // DB 연결 없으면 503 에러 예상
         assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
     }
+
+    #[tokio::test]
+    async fn test_get_macro_regime_no_db() {
+        use crate::state::create_test_state;
+
+        let state = Arc::new(create_test_state());
+        let app = Router::new()
+            .route("/market/macro-regime", get(get_macro_regime))
+            .with_state(state);
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/market/macro-regime?days=30")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+    }
+
+    #[test]
+    fn test_macro_regime_view() {
+        let regime = MacroRegime::compute(
+            NaiveDate::from_ymd_opt(2026, 3, 4).unwrap(),
+            trader_core::MacroRegimeInputs {
+                yield_curve_slope: Some(-0.5),
+                vix: Some(30.0),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        let view = serde_json::to_value(MacroRegimeView::from(&regime)).unwrap();
+        assert_eq!(view["date"], "2026-03-04");
+        assert_eq!(view["level"], "RISK_OFF");
+        assert_eq!(view["yieldCurveScore"], -0.5);
+        assert_eq!(view["volatilityScore"], -1.0);
+        assert!(view["fxScore"].is_null());
+    }
+
+    #[test]
+    fn test_parse_chart_indicators() {
+        let parsed = parse_chart_indicators("sma:20, bb:20:2.5,vwap,macd").unwrap();
+        assert_eq!(parsed.len(), 4);
+        assert_eq!(parsed[0].1, ChartIndicatorSpec::Sma { period: 20 });
+        assert_eq!(
+            parsed[1].1,
+            ChartIndicatorSpec::Bollinger {
+                period: 20,
+                std_dev: Decimal::new(25, 1)
+            }
+        );
+        assert_eq!(parsed[2].1, ChartIndicatorSpec::Vwap);
+        assert_eq!(
+            parsed[3].1,
+            ChartIndicatorSpec::Macd {
+                fast: 12,
+                slow: 26,
+                signal: 9
+            }
+        );
+        assert_eq!(parsed[3].1.warmup(), 35);
+
+        assert!(parse_chart_indicators("").unwrap().is_empty());
+        assert!(parse_chart_indicators("sma:0").is_err());
+        assert!(parse_chart_indicators("bb:20:-1").is_err());
+        assert!(parse_chart_indicators("ichimoku").is_err());
+    }
+
+    #[test]
+    fn test_build_chart_aligns_indicators() {
+        let start = chrono::DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        let klines: Vec<Kline> = (0..30)
+            .map(|i| {
+                let close = Decimal::from(100 + i);
+                let open_time = start + chrono::Duration::days(i);
+                Kline::new(
+                    "005930".to_string(),
+                    Timeframe::D1,
+                    open_time,
+                    close,
+                    close + Decimal::ONE,
+                    close - Decimal::ONE,
+                    close,
+                    Decimal::from(1000),
+                    open_time + chrono::Duration::days(1),
+                )
+            })
+            .collect();
+
+        let indicators = parse_chart_indicators("sma:5,bb:5:2").unwrap();
+        let (candles, overlays) = build_chart(&klines, &indicators, 10);
+
+        assert_eq!(candles.len(), 10);
+        assert_eq!(candles[0].time, klines[20].open_time.timestamp());
+
+        // warm-up 구간이 잘려 나가 첫 캔들부터 값이 존재
+        let sma = &overlays[0].lines["sma"];
+        assert_eq!(sma.len(), 10);
+        assert_eq!(sma[0], Some(118.0)); // (116+117+118+119+120)/5
+        assert_eq!(overlays[1].kind, "bb");
+        assert_eq!(overlays[1].lines.len(), 3);
+        assert!(overlays[1].lines["upper"].iter().all(|v| v.is_some()));
+    }
+
+    #[tokio::test]
+    async fn test_get_chart_invalid_indicator() {
+        use crate::state::create_test_state;
+
+        let state = Arc::new(create_test_state());
+        let app = market_router().with_state(state);
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/chart?symbol=005930&indicators=sma:20,unknown")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
 }
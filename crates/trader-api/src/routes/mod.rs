@@ -8,9 +8,15 @@
 //! - `/health/ready` - 상세 헬스 체크 (readiness)
 //! - `/api/v1/strategies` - 전략 관리
 //! - `/api/v1/orders` - 주문 관리
+//! - `/api/v1/conditional-orders` - 서버 측 조건부 주문 (조건 충족 시 자동 실행)
 //! - `/api/v1/positions` - 포지션 관리
+//! - `/api/v1/dca` - 정액 적립식(DCA) 계획 (주기적 자동 매수)
+//! - `/api/v1/hedge` - 포트폴리오 베타 헤지 오버레이 (인버스 ETF/선물 자동 헤지)
+//! - `/api/v1/fx` - 해외 주식 주문 전 자동 환전 (환전 미리보기, 수동 승인)
 //! - `/api/v1/notifications` - 알림 설정
 //! - `/api/v1/backtest` - 백테스트 실행
+//! - `/api/v1/backtest/templates` - 백테스트 템플릿 (저장된 설정 재실행)
+//! - `/api/v1/competitions` - 전략 경쟁 (모의투자 리더보드, 우승 전략 승격)
 //! - `/api/v1/analytics` - 포트폴리오 분석
 //! - `/api/v1/patterns` - 패턴 인식 (캔들스틱/차트)
 //! - `/api/v1/portfolio` - 포트폴리오 요약/잔고/보유종목
@@ -23,34 +29,67 @@
 //! - `/api/v1/monitoring` - 모니터링 (에러 추적, 통계)
 //! - `/api/v1/ranking` - GlobalScore 기반 종목 랭킹
 //! - `/api/v1/watchlist` - 관심종목 관리
+//! - `/api/v1/capital` - 전략별 자본 원장 (예산, 입출금, 이체)
+//! - `/api/v1/risk` - 리스크 설정 조회/변경 (2인 승인, 변경 이력)
+//! - `/api/v1/indices` - 사용자 정의 지수 (가중 바스켓, 합성 일봉, 벤치마크 비교)
+//! - `/api/v1/webhooks` - 아웃바운드 웹훅 (체결/포지션/전략 이벤트 전송)
+//! - `/api/v1/account` - 사용자 할당량/사용량 조회
+//! - `/api/v1/tasks` - 백그라운드 작업 (실행 기록 조회, 일시 정지/즉시 실행)
+//! - `/api/v1/udf` - TradingView UDF 호환 차트 데이터 (외부 차트 연동)
 
+pub mod account;
 pub mod analytics;
 pub mod backtest;
 pub mod backtest_results;
+pub mod backtest_templates;
+pub mod capital;
+pub mod common;
+pub mod competitions;
+pub mod conditional_orders;
 pub mod credentials;
+pub mod custom_index;
 pub mod dataset;
+pub mod dataset_upload;
+pub mod dca;
+pub mod earnings;
 pub mod equity_history;
+pub mod etf;
+pub mod fx;
 pub mod health;
+pub mod hedge;
 pub mod journal;
+pub mod journal_export;
 pub mod market;
 pub mod ml;
 pub mod monitoring;
 #[cfg(feature = "notifications")]
 pub mod notifications;
 pub mod orders;
+pub mod paper_trading;
 pub mod patterns;
 pub mod portfolio;
 pub mod positions;
 pub mod ranking;
 pub mod reality_check;
+pub mod risk;
 pub mod schema;
 pub mod screening;
 pub mod signal_alerts;
 pub mod signals;
 pub mod simulation;
 pub mod strategies;
+pub mod strategy_history;
+pub mod strategy_performance;
+pub mod strategy_promotion;
+pub mod strategy_recommend;
+pub mod synthetic;
+pub mod tasks;
+pub mod udf;
 pub mod watchlist;
+#[cfg(feature = "notifications")]
+pub mod webhooks;
 
+pub use account::{account_router, AccountUsageResponse};
 pub use analytics::{
     analytics_router, ChartResponse, EquityCurveResponse, MonthlyReturnsResponse,
     PerformanceResponse,
@@ -62,12 +101,30 @@ pub use backtest::{
 pub use backtest_results::{
     backtest_results_router, BacktestResultResponse, ListResultsResponse, SaveBacktestResultRequest,
 };
+pub use backtest_templates::{
+    backtest_templates_router, BacktestTemplatesResponse, TemplateRunResponse,
+};
+pub use capital::{
+    capital_router, CapitalReportResponse, CapitalTransferResponse, StrategyCapitalDto,
+};
+pub use competitions::{
+    competitions_router, CompetitionDetailResponse, CompetitionsListResponse, LeaderboardResponse,
+};
+pub use conditional_orders::{
+    conditional_orders_router, ConditionalOrderEvaluationResponse, ConditionalOrdersListResponse,
+};
 pub use credentials::{
     credentials_router, EncryptedCredentials, ExchangeCredentialResponse,
     SupportedExchangesResponse, TelegramSettingsResponse,
 };
+pub use custom_index::{custom_index_router, CustomIndicesListResponse};
 pub use dataset::{dataset_router, DatasetListResponse, DatasetSummary, FetchDatasetRequest};
+pub use dca::{dca_router, DcaExecutionsResponse, DcaPlansListResponse};
+pub use earnings::{earnings_router, UpcomingEarningsResponse};
+pub use etf::{etf_router, EtfPremiumsResponse};
+pub use fx::{fx_router, FxConversionsResponse, FxQuoteResponse};
 pub use health::{health_router, ComponentHealth, ComponentStatus, HealthResponse};
+pub use hedge::{hedge_router, HedgeRebalancesResponse, HedgeStatusResponse};
 pub use journal::{
     journal_router, ExecutionsListResponse, JournalPositionsResponse, PnLSummaryResponse,
     SyncResponse,
@@ -95,10 +152,12 @@ pub use reality_check::{
     reality_check_router, CalculateRequest, CalculateResponse, ResultsQuery, ResultsResponse,
     SaveSnapshotRequest, SaveSnapshotResponse, SnapshotsQuery, SnapshotsResponse, StatsQuery,
 };
+pub use risk::{risk_router, RiskConfigResponse, RiskConfigUpdateResponse};
 pub use schema::schema_router;
 pub use screening::{
-    screening_router, sectors_router, MomentumResponse, ScreeningRequest, ScreeningResponse,
-    SectorRankingResponse, SectorRsDto,
+    screening_router, sectors_router, FactorHistoryResponse, InvestorFlowHistoryResponse,
+    InvestorFlowResponse, MomentumResponse, ScreeningRequest, ScreeningResponse,
+    SectorRankingResponse, SectorRsDto, SurvivalResponse,
 };
 pub use signals::{
     signals_router, SignalMarkerDto, SignalSearchRequest, SignalSearchResponse,
@@ -106,10 +165,15 @@ pub use signals::{
 };
 pub use simulation::{simulation_router, SimulationStartRequest, SimulationStatusResponse};
 pub use strategies::{strategies_router, ApiError, StrategiesListResponse, StrategyDetailResponse};
+pub use synthetic::{synthetic_router, SyntheticListResponse};
+pub use tasks::{tasks_router, TasksListResponse};
+pub use udf::udf_router;
 pub use watchlist::{
     watchlist_router, AddItemsRequest, AddItemsResponse, WatchlistDetailResponse,
     WatchlistListResponse,
 };
+#[cfg(feature = "notifications")]
+pub use webhooks::{webhooks_router, WebhookResponse, WebhooksListResponse};
 
 use axum::Router;
 use std::sync::Arc;
@@ -121,7 +185,7 @@ use crate::state::AppState;
 /// 모든 서브 라우터를 조합하여 하나의 라우터로 반환합니다.
 ///
 /// # Feature Flags
-/// - `notifications`: 알림 라우터 활성화 (`/api/v1/notifications`)
+/// - `notifications`: 알림/웹훅 라우터 활성화 (`/api/v1/notifications`, `/api/v1/webhooks`)
 pub fn create_api_router() -> Router<Arc<AppState>> {
     let router = Router::new()
         // 헬스 체크 엔드포인트
@@ -129,10 +193,16 @@ pub fn create_api_router() -> Router<Arc<AppState>> {
         // API v1 엔드포인트
         .nest("/api/v1/strategies", strategies_router())
         .nest("/api/v1/orders", orders_router())
+        .nest("/api/v1/conditional-orders", conditional_orders_router())
         .nest("/api/v1/positions", positions_router())
+        .nest("/api/v1/dca", dca_router())
+        .nest("/api/v1/hedge", hedge_router())
+        .nest("/api/v1/fx", fx_router())
         .nest("/api/v1/backtest", backtest_router())
         .nest("/api/v1/backtest/results", backtest_results_router())
+        .nest("/api/v1/backtest/templates", backtest_templates_router())
         .nest("/api/v1/simulation", simulation_router())
+        .nest("/api/v1/competitions", competitions_router())
         .nest("/api/v1/analytics", analytics_router())
         .nest("/api/v1/patterns", patterns_router())
         .nest("/api/v1/portfolio", portfolio_router())
@@ -149,11 +219,22 @@ pub fn create_api_router() -> Router<Arc<AppState>> {
         .nest("/api/v1/reality-check", reality_check_router())
         .nest("/api/v1/monitoring", monitoring_router())
         .nest("/api/v1/ranking", ranking_router())
-        .nest("/api/v1/watchlist", watchlist_router());
+        .nest("/api/v1/watchlist", watchlist_router())
+        .nest("/api/v1/capital", capital_router())
+        .nest("/api/v1/risk", risk_router())
+        .nest("/api/v1/etf", etf_router())
+        .nest("/api/v1/indices", custom_index_router())
+        .nest("/api/v1/synthetics", synthetic_router())
+        .nest("/api/v1/earnings", earnings_router())
+        .nest("/api/v1/account", account_router())
+        .nest("/api/v1/tasks", tasks_router())
+        .nest("/api/v1/udf", udf_router());
 
     // Feature: notifications - 텔레그램/이메일 알림
     #[cfg(feature = "notifications")]
-    let router = router.nest("/api/v1/notifications", notifications_router());
+    let router = router
+        .nest("/api/v1/notifications", notifications_router())
+        .nest("/api/v1/webhooks", webhooks_router());
 
     router
 }
@@ -9,7 +9,14 @@
 //! - `GET /api/v1/monitoring/errors/:id` - 특정 에러 상세 조회
 //! - `GET /api/v1/monitoring/stats` - 에러 통계 조회
 //! - `POST /api/v1/monitoring/stats/reset` - 통계 초기화
+//! - `GET /api/v1/monitoring/overview` - 대시보드 홈용 시스템 KPI 요약
 //! - `DELETE /api/v1/monitoring/errors` - 에러 히스토리 삭제
+//! - `GET /api/v1/monitoring/order-circuits` - 거래소별 주문 서킷 상태 조회
+//! - `POST /api/v1/monitoring/order-circuits/:venue/reset` - 주문 서킷 수동 리셋
+//! - `GET /api/v1/monitoring/shadow` - 실거래 vs 섀도 비교 리포트 목록
+//! - `GET /api/v1/monitoring/shadow/:strategy_id` - 전략별 섀도 비교 리포트
+//! - `POST /api/v1/monitoring/shadow/:strategy_id` - 전략에 섀도 연결
+//! - `DELETE /api/v1/monitoring/shadow/:strategy_id` - 전략의 섀도 해제
 
 use axum::{
     extract::{Path, Query, State},
@@ -18,11 +25,21 @@ use axum::{
     routing::{get, post},
     Json, Router,
 };
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::sync::Arc;
+use tracing::warn;
+use trader_core::{Order, OrderStatusType};
+use trader_execution::OrderCircuitStatus;
+use trader_risk::DailyLimitStatus;
 use utoipa::ToSchema;
 
 use crate::monitoring::{global_tracker, ErrorCategory, ErrorRecord, ErrorSeverity, ErrorStats};
+use crate::repository::{CollectorRunRecord, MarketFreshnessRecord, MonitoringRepository};
+use crate::services::shadow_runner::{attach_shadow_for, ShadowRunnerConfig};
+use crate::services::task_scheduler::TaskSnapshot;
 use crate::state::AppState;
 
 /// 에러 목록 조회 쿼리 파라미터.
@@ -134,6 +151,132 @@ impl From<ErrorStats> for StatsResponse {
     }
 }
 
+/// 대시보드 홈 요약 응답.
+#[derive(Debug, Serialize)]
+pub struct OverviewResponse {
+    /// 집계 시각
+    pub generated_at: DateTime<Utc>,
+    pub uptime_secs: i64,
+    pub version: String,
+    pub strategies: StrategiesOverview,
+    pub pnl_today: PnlTodayOverview,
+    pub open_orders: OpenOrdersOverview,
+    /// 시장별 일봉 데이터 신선도 (DB 미설정 또는 조회 실패 시 None)
+    pub data_freshness: Option<Vec<MarketFreshness>>,
+    /// 수집기 워크플로우별 마지막 실행 (DB 미설정 또는 조회 실패 시 None)
+    pub collectors: Option<Vec<CollectorRunRecord>>,
+    /// 서버 내 주기 작업의 마지막 실행
+    pub background_tasks: Vec<TaskSnapshot>,
+    pub errors: ErrorCountsOverview,
+    pub circuit_breakers: CircuitBreakersOverview,
+}
+
+/// 전략 실행 현황.
+#[derive(Debug, Serialize)]
+pub struct StrategiesOverview {
+    pub total: usize,
+    /// 실행 중인 전략 수
+    pub active: usize,
+}
+
+/// 당일 손익 (리스크 관리자의 일일 손실 한도 추적 기준).
+#[derive(Debug, Serialize)]
+pub struct PnlTodayOverview {
+    /// 당일 실현 손익
+    pub realized_pnl: Decimal,
+    pub trade_count: usize,
+    /// 일일 최대 손실 허용액
+    pub max_daily_loss: Decimal,
+    /// 일일 손실 한도 사용률 (%)
+    pub limit_usage_pct: f64,
+}
+
+/// 미체결 주문 현황.
+#[derive(Debug, Default, Serialize)]
+pub struct OpenOrdersOverview {
+    pub total: usize,
+    pub pending: usize,
+    pub open: usize,
+    pub partially_filled: usize,
+}
+
+/// 시장별 데이터 신선도.
+#[derive(Debug, Serialize)]
+pub struct MarketFreshness {
+    #[serde(flatten)]
+    pub record: MarketFreshnessRecord,
+    /// 마지막 캐시 갱신 이후 경과 시간 (초)
+    pub age_secs: Option<i64>,
+}
+
+/// 카테고리별 에러 수.
+#[derive(Debug, Serialize)]
+pub struct ErrorCountsOverview {
+    pub total: u64,
+    pub by_severity: HashMap<String, u64>,
+    pub by_category: HashMap<String, u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_error_at: Option<String>,
+}
+
+/// 서킷 브레이커 상태.
+#[derive(Debug, Serialize)]
+pub struct CircuitBreakersOverview {
+    /// 거래소별 주문 서킷
+    pub order_circuits: Vec<OrderCircuitStatus>,
+    /// 일일 손실 한도 도달로 거래가 중단되었는지 여부
+    pub daily_loss_limit_tripped: bool,
+}
+
+impl From<ErrorStats> for ErrorCountsOverview {
+    fn from(stats: ErrorStats) -> Self {
+        Self {
+            total: stats.total_count,
+            by_severity: stats.by_severity,
+            by_category: stats.by_category,
+            last_error_at: stats.last_error_at.map(|t| t.to_rfc3339()),
+        }
+    }
+}
+
+impl From<&DailyLimitStatus> for PnlTodayOverview {
+    fn from(status: &DailyLimitStatus) -> Self {
+        Self {
+            realized_pnl: status.daily_pnl,
+            trade_count: status.trade_count,
+            max_daily_loss: status.max_daily_loss,
+            limit_usage_pct: status.limit_usage_pct,
+        }
+    }
+}
+
+impl OpenOrdersOverview {
+    fn from_orders(orders: &[Order]) -> Self {
+        let mut overview = Self {
+            total: orders.len(),
+            ..Self::default()
+        };
+        for order in orders {
+            match order.status {
+                OrderStatusType::Pending => overview.pending += 1,
+                OrderStatusType::Open => overview.open += 1,
+                OrderStatusType::PartiallyFilled => overview.partially_filled += 1,
+                _ => {}
+            }
+        }
+        overview
+    }
+}
+
+impl MarketFreshness {
+    fn new(record: MarketFreshnessRecord, now: DateTime<Utc>) -> Self {
+        let age_secs = record
+            .last_updated_at
+            .map(|t| now.signed_duration_since(t).num_seconds());
+        Self { record, age_secs }
+    }
+}
+
 /// 최근 에러 목록 조회.
 ///
 /// GET /api/v1/monitoring/errors
@@ -323,6 +466,64 @@ pub async fn clear_errors() -> impl IntoResponse {
     }))
 }
 
+/// 거래소별 주문 서킷 상태 조회.
+///
+/// GET /api/v1/monitoring/order-circuits
+#[utoipa::path(
+    get,
+    path = "/api/v1/monitoring/order-circuits",
+    tag = "monitoring",
+    responses(
+        (status = 200, description = "거래소별 주문 서킷 상태")
+    )
+)]
+pub async fn list_order_circuits(State(state): State<Arc<AppState>>) -> impl IntoResponse {
+    let executor = state.executor.read().await;
+    let circuit = executor.order_circuit();
+
+    Json(serde_json::json!({
+        "open_policy": circuit.config().open_policy,
+        "circuits": circuit.statuses(),
+    }))
+}
+
+/// 주문 서킷 수동 리셋.
+///
+/// POST /api/v1/monitoring/order-circuits/:venue/reset
+#[utoipa::path(
+    post,
+    path = "/api/v1/monitoring/order-circuits/{venue}/reset",
+    tag = "monitoring",
+    params(
+        ("venue" = String, Path, description = "거래소 식별자")
+    ),
+    responses(
+        (status = 200, description = "서킷 리셋 완료"),
+        (status = 404, description = "해당 거래소의 서킷이 없음")
+    )
+)]
+pub async fn reset_order_circuit(
+    State(state): State<Arc<AppState>>,
+    Path(venue): Path<String>,
+) -> impl IntoResponse {
+    let executor = state.executor.read().await;
+
+    if executor.order_circuit().reset(&venue) {
+        Json(serde_json::json!({
+            "message": "Order circuit reset",
+            "venue": venue,
+            "reset_at": chrono::Utc::now().to_rfc3339()
+        }))
+        .into_response()
+    } else {
+        (
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({ "error": "Order circuit not found" })),
+        )
+            .into_response()
+    }
+}
+
 /// 시스템 요약 정보 (디버깅용).
 ///
 /// GET /api/v1/monitoring/summary
@@ -357,6 +558,37 @@ pub async fn get_summary(State(state): State<Arc<AppState>>) -> impl IntoRespons
         })
         .collect();
 
+    // Closed가 아닌 주문 서킷만 요약에 포함
+    let tripped_circuits: Vec<_> = {
+        let executor = state.executor.read().await;
+        executor
+            .order_circuit()
+            .statuses()
+            .into_iter()
+            .filter(|c| c.state != "closed")
+            .collect()
+    };
+
+    // 불일치가 탐지된 섀도 비교만 요약에 포함
+    let shadow_drift: Vec<_> = {
+        let engine = state.strategy_engine.read().await;
+        engine
+            .shadow_reports()
+            .await
+            .into_iter()
+            .filter(|r| r.has_drift())
+            .map(|r| {
+                serde_json::json!({
+                    "strategy_id": r.strategy_id,
+                    "signal_match_rate": r.signal_match_rate,
+                    "missing_in_live": r.stats.missing_in_live,
+                    "missing_in_shadow": r.stats.missing_in_shadow,
+                    "fill_deviations": r.stats.fill_deviations,
+                })
+            })
+            .collect()
+    };
+
     Json(serde_json::json!({
         "uptime_secs": state.uptime_secs(),
         "version": state.version,
@@ -370,9 +602,213 @@ pub async fn get_summary(State(state): State<Arc<AppState>>) -> impl IntoRespons
         },
         "recent_errors": recent_errors,
         "top_categories": stats.by_category,
+        "tripped_order_circuits": tripped_circuits,
+        "shadow_drift": shadow_drift,
+    }))
+}
+
+/// 대시보드 홈 요약.
+///
+/// GET /api/v1/monitoring/overview
+///
+/// 가동 시간, 실행 중인 전략, 당일 손익, 미체결 주문, 시장별 데이터 신선도,
+/// 수집기 마지막 실행, 카테고리별 에러 수, 서킷 브레이커 상태를 한 번에 반환합니다.
+#[utoipa::path(
+    get,
+    path = "/api/v1/monitoring/overview",
+    tag = "monitoring",
+    responses(
+        (status = 200, description = "대시보드 홈 KPI 요약")
+    )
+)]
+pub async fn get_overview(State(state): State<Arc<AppState>>) -> impl IntoResponse {
+    let now = Utc::now();
+
+    let engine_stats = {
+        let engine = state.strategy_engine.read().await;
+        engine.get_engine_stats().await
+    };
+    let daily_status = {
+        let mut risk_manager = state.risk_manager.write().await;
+        risk_manager.daily_status()
+    };
+    let (orders, order_circuits) = {
+        let executor = state.executor.read().await;
+        (
+            executor.get_active_orders().await,
+            executor.order_circuit().statuses(),
+        )
+    };
+
+    let (data_freshness, collectors) = match &state.db_pool {
+        Some(pool) => {
+            let (freshness, collectors) = tokio::join!(
+                MonitoringRepository::data_freshness(pool),
+                MonitoringRepository::collector_runs(pool)
+            );
+            let freshness = freshness
+                .map_err(|e| warn!("데이터 신선도 조회 실패: {}", e))
+                .ok()
+                .map(|records| {
+                    records
+                        .into_iter()
+                        .map(|r| MarketFreshness::new(r, now))
+                        .collect()
+                });
+            let collectors = collectors
+                .map_err(|e| warn!("수집기 실행 기록 조회 실패: {}", e))
+                .ok();
+            (freshness, collectors)
+        }
+        None => (None, None),
+    };
+
+    Json(OverviewResponse {
+        generated_at: now,
+        uptime_secs: state.uptime_secs(),
+        version: state.version.clone(),
+        strategies: StrategiesOverview {
+            total: engine_stats.total_strategies,
+            active: engine_stats.running_strategies,
+        },
+        pnl_today: PnlTodayOverview::from(&daily_status),
+        open_orders: OpenOrdersOverview::from_orders(&orders),
+        data_freshness,
+        collectors,
+        background_tasks: state.tasks.list(),
+        errors: ErrorCountsOverview::from(global_tracker().get_stats()),
+        circuit_breakers: CircuitBreakersOverview {
+            order_circuits,
+            daily_loss_limit_tripped: !daily_status.can_trade,
+        },
+    })
+}
+
+/// 실거래 vs 섀도 비교 리포트 목록.
+///
+/// GET /api/v1/monitoring/shadow
+#[utoipa::path(
+    get,
+    path = "/api/v1/monitoring/shadow",
+    tag = "monitoring",
+    responses(
+        (status = 200, description = "섀도가 연결된 전략별 비교 리포트")
+    )
+)]
+pub async fn list_shadow_reports(State(state): State<Arc<AppState>>) -> impl IntoResponse {
+    let engine = state.strategy_engine.read().await;
+    let mut reports = engine.shadow_reports().await;
+    reports.sort_by(|a, b| a.strategy_id.cmp(&b.strategy_id));
+    let drifting = reports.iter().filter(|r| r.has_drift()).count();
+
+    Json(serde_json::json!({
+        "reports": reports,
+        "count": reports.len(),
+        "drifting": drifting,
     }))
 }
 
+/// 전략별 섀도 비교 리포트.
+///
+/// GET /api/v1/monitoring/shadow/:strategy_id
+#[utoipa::path(
+    get,
+    path = "/api/v1/monitoring/shadow/{strategy_id}",
+    tag = "monitoring",
+    params(
+        ("strategy_id" = String, Path, description = "전략 ID")
+    ),
+    responses(
+        (status = 200, description = "섀도 비교 리포트"),
+        (status = 404, description = "섀도가 연결되지 않은 전략")
+    )
+)]
+pub async fn get_shadow_report(
+    State(state): State<Arc<AppState>>,
+    Path(strategy_id): Path<String>,
+) -> impl IntoResponse {
+    let engine = state.strategy_engine.read().await;
+
+    match engine.shadow_report(&strategy_id).await {
+        Some(report) => Json(report).into_response(),
+        None => (
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({ "error": "Shadow not attached" })),
+        )
+            .into_response(),
+    }
+}
+
+/// 전략에 섀도 연결 (이미 있으면 초기화).
+///
+/// POST /api/v1/monitoring/shadow/:strategy_id
+#[utoipa::path(
+    post,
+    path = "/api/v1/monitoring/shadow/{strategy_id}",
+    tag = "monitoring",
+    params(
+        ("strategy_id" = String, Path, description = "전략 ID")
+    ),
+    responses(
+        (status = 200, description = "섀도 연결 완료"),
+        (status = 400, description = "섀도 생성 실패")
+    )
+)]
+pub async fn attach_shadow(
+    State(state): State<Arc<AppState>>,
+    Path(strategy_id): Path<String>,
+) -> impl IntoResponse {
+    let config = ShadowRunnerConfig::from_env().shadow;
+
+    match attach_shadow_for(&state, &strategy_id, config).await {
+        Ok(()) => Json(serde_json::json!({
+            "message": "Shadow attached",
+            "strategy_id": strategy_id,
+        }))
+        .into_response(),
+        Err(e) => (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({ "error": e })),
+        )
+            .into_response(),
+    }
+}
+
+/// 전략의 섀도 해제.
+///
+/// DELETE /api/v1/monitoring/shadow/:strategy_id
+#[utoipa::path(
+    delete,
+    path = "/api/v1/monitoring/shadow/{strategy_id}",
+    tag = "monitoring",
+    params(
+        ("strategy_id" = String, Path, description = "전략 ID")
+    ),
+    responses(
+        (status = 200, description = "섀도 해제 완료"),
+        (status = 404, description = "섀도가 연결되지 않은 전략")
+    )
+)]
+pub async fn detach_shadow(
+    State(state): State<Arc<AppState>>,
+    Path(strategy_id): Path<String>,
+) -> impl IntoResponse {
+    let engine = state.strategy_engine.read().await;
+
+    match engine.detach_shadow(&strategy_id).await {
+        Ok(true) => Json(serde_json::json!({
+            "message": "Shadow detached",
+            "strategy_id": strategy_id,
+        }))
+        .into_response(),
+        _ => (
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({ "error": "Shadow not attached" })),
+        )
+            .into_response(),
+    }
+}
+
 /// 모니터링 라우터 생성.
 pub fn monitoring_router() -> Router<Arc<AppState>> {
     Router::new()
@@ -382,6 +818,16 @@ pub fn monitoring_router() -> Router<Arc<AppState>> {
         .route("/stats", get(get_stats))
         .route("/stats/reset", post(reset_stats))
         .route("/summary", get(get_summary))
+        .route("/overview", get(get_overview))
+        .route("/order-circuits", get(list_order_circuits))
+        .route("/order-circuits/{venue}/reset", post(reset_order_circuit))
+        .route("/shadow", get(list_shadow_reports))
+        .route(
+            "/shadow/{strategy_id}",
+            get(get_shadow_report)
+                .post(attach_shadow)
+                .delete(detach_shadow),
+        )
 }
 
 #[cfg(test)]
@@ -451,4 +897,105 @@ mod tests {
 
         assert!(!stats.stats_since.is_empty());
     }
+
+    #[tokio::test]
+    async fn test_order_circuits_list_and_reset() {
+        let state = Arc::new(crate::state::create_test_state());
+        {
+            let executor = state.executor.read().await;
+            for _ in 0..5 {
+                executor
+                    .order_circuit()
+                    .record_rejection("test_exchange", None, "rejected");
+            }
+        }
+
+        let app = monitoring_router().with_state(state);
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .uri("/order-circuits")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["circuits"][0]["venue"], "test_exchange");
+        assert_eq!(json["circuits"][0]["state"], "open");
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/order-circuits/test_exchange/reset")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/order-circuits/unknown/reset")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_overview() {
+        setup_tracker();
+        let state = Arc::new(crate::state::create_test_state());
+        {
+            let executor = state.executor.read().await;
+            for _ in 0..5 {
+                executor
+                    .order_circuit()
+                    .record_rejection("test_exchange", None, "rejected");
+            }
+        }
+
+        let app = monitoring_router().with_state(state);
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/overview")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["strategies"]["active"], 0);
+        assert_eq!(json["open_orders"]["total"], 0);
+        assert_eq!(json["circuit_breakers"]["daily_loss_limit_tripped"], false);
+        assert_eq!(
+            json["circuit_breakers"]["order_circuits"][0]["state"],
+            "open"
+        );
+        // DB 미설정이면 DB 기반 항목은 null
+        assert!(json["data_freshness"].is_null());
+        assert!(json["collectors"].is_null());
+        assert!(json["errors"]["by_category"].is_object());
+    }
 }
@@ -12,11 +12,12 @@ use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use tracing::{error, info};
 
+use crate::services::notification_digest::telegram_digest_pending;
 use crate::state::AppState;
 use rust_decimal_macros::dec;
 use trader_notification::{
-    Notification, NotificationEvent, NotificationPriority, NotificationSender, TelegramConfig,
-    TelegramSender,
+    DigestSettings, Notification, NotificationEvent, NotificationPriority, NotificationSender,
+    TelegramConfig, TelegramSender,
 };
 
 /// 텔레그램 테스트 요청.
@@ -44,6 +45,21 @@ pub struct NotificationSettingsResponse {
     pub telegram_enabled: bool,
     /// 텔레그램 설정 여부
     pub telegram_configured: bool,
+    /// 텔레그램 다이제스트 설정
+    pub telegram_digest: DigestSettingsResponse,
+}
+
+/// 다이제스트 설정 응답.
+#[derive(Debug, Serialize)]
+pub struct DigestSettingsResponse {
+    /// 다이제스트 모드 활성화 여부
+    pub enabled: bool,
+    /// 즉시 전송 최소 우선순위
+    pub immediate_priority: NotificationPriority,
+    /// 요약 전송 시각 (KST, HH:MM)
+    pub send_at: String,
+    /// 요약 대기 중인 알림 수
+    pub pending: usize,
 }
 
 /// 템플릿 테스트 요청.
@@ -164,9 +180,17 @@ pub async fn get_notification_settings() -> impl IntoResponse {
     let telegram_configured =
         std::env::var("TELEGRAM_BOT_TOKEN").is_ok() && std::env::var("TELEGRAM_CHAT_ID").is_ok();
 
+    let digest = DigestSettings::from_env("TELEGRAM");
+
     Json(NotificationSettingsResponse {
         telegram_enabled,
         telegram_configured,
+        telegram_digest: DigestSettingsResponse {
+            enabled: digest.enabled,
+            immediate_priority: digest.immediate_priority,
+            send_at: digest.send_at.format("%H:%M").to_string(),
+            pending: telegram_digest_pending(),
+        },
     })
 }
 
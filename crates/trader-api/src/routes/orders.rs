@@ -5,6 +5,8 @@
 //! # 엔드포인트
 //!
 //! - `GET /api/v1/orders` - 활성 주문 목록 조회
+//! - `POST /api/v1/orders/preview` - 주문 미리보기 (리스크/호가/비용/매수 여력, 전송 없음)
+//! - `POST /api/v1/orders/basket` - 바스켓 주문 (다종목 동시 실행, 수량 또는 목표 비중)
 //! - `GET /api/v1/orders/:id` - 특정 주문 상세 조회
 //! - `DELETE /api/v1/orders/:id` - 주문 취소
 
@@ -12,12 +14,13 @@ use axum::{
     extract::{Path, State},
     http::StatusCode,
     response::IntoResponse,
-    routing::get,
+    routing::{get, post},
     Json, Router,
 };
 use chrono::Utc;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 use std::sync::Arc;
 use uuid::Uuid;
 
@@ -25,7 +28,14 @@ use crate::metrics::record_order;
 use crate::routes::strategies::ApiError;
 use crate::state::AppState;
 use crate::websocket::{OrderUpdateData, ServerMessage};
-use trader_core::{Order, OrderStatusType, OrderType, Side};
+use trader_core::{
+    KrxTickSize, Order, OrderStatusType, OrderType, Side, TickSizeProvider, TradingCostModel,
+    UsEquityTickSize,
+};
+use trader_execution::{
+    BasketFailurePolicy, BasketLeg, BasketLegStatus, BasketRequest, BasketResult, BasketTarget,
+    OrderPreview,
+};
 
 // ==================== 응답 타입 ====================
 
@@ -148,6 +158,123 @@ pub struct CreateOrderResponse {
     pub order: OrderResponse,
 }
 
+/// 주문 미리보기 요청.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PreviewOrderRequest {
+    /// 심볼
+    pub symbol: String,
+    /// 주문 방향 (Buy/Sell)
+    pub side: Side,
+    /// 주문 유형
+    #[serde(rename = "type")]
+    pub order_type: OrderType,
+    /// 주문 수량
+    pub quantity: Decimal,
+    /// 주문 가격 (지정가 주문시 필수)
+    #[serde(default)]
+    pub price: Option<Decimal>,
+    /// 스탑 트리거 가격
+    #[serde(default)]
+    pub stop_price: Option<Decimal>,
+    /// 전략 ID (전략 예산 및 비용 모델 적용)
+    #[serde(default)]
+    pub strategy_id: Option<String>,
+    /// 시장 (KR, US, CRYPTO, 없으면 심볼 형식으로 추정)
+    #[serde(default)]
+    pub market: Option<String>,
+    /// 현재가 (없으면 지정가 또는 보유 포지션 시세 사용)
+    #[serde(default)]
+    pub current_price: Option<Decimal>,
+}
+
+/// 주문 미리보기 응답.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PreviewOrderResponse {
+    /// 적용된 시장
+    pub market: String,
+    /// 미리보기 결과
+    pub preview: OrderPreview,
+}
+
+/// 바스켓 주문 요청.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BasketOrderRequest {
+    /// 종목별 주문
+    pub legs: Vec<BasketLegRequest>,
+    /// 바스켓 총액 (targetWeight leg에 필수)
+    #[serde(default)]
+    pub total_value: Option<Decimal>,
+    /// 일부 실패 시 처리 방식 (best_effort, cancel_on_failure, all_or_none)
+    #[serde(default)]
+    pub policy: BasketFailurePolicy,
+    /// 전략 ID (전략 예산 및 비용 모델 적용)
+    #[serde(default)]
+    pub strategy_id: Option<String>,
+}
+
+/// 바스켓 주문의 종목별 요청.
+///
+/// `side` + `quantity` 또는 `targetWeight` 중 하나를 지정합니다.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BasketLegRequest {
+    /// 심볼
+    pub symbol: String,
+    /// 주문 방향 (수량 지정 시 필수)
+    #[serde(default)]
+    pub side: Option<Side>,
+    /// 주문 수량
+    #[serde(default)]
+    pub quantity: Option<Decimal>,
+    /// 바스켓 총액 대비 목표 비중 (0~1)
+    #[serde(default)]
+    pub target_weight: Option<Decimal>,
+    /// 주문 유형 (Market/Limit, 기본값: Market)
+    #[serde(rename = "type", default = "default_basket_order_type")]
+    pub order_type: OrderType,
+    /// 주문 가격 (지정가 주문시 필수)
+    #[serde(default)]
+    pub price: Option<Decimal>,
+    /// 현재가 (없으면 지정가 또는 보유 포지션 시세 사용)
+    #[serde(default)]
+    pub current_price: Option<Decimal>,
+}
+
+fn default_basket_order_type() -> OrderType {
+    OrderType::Market
+}
+
+/// 바스켓 하나에 지정할 수 있는 최대 종목 수.
+const MAX_BASKET_LEGS: usize = 50;
+
+/// 주문 대상 시장 결정 (미리보기, 조건부 주문).
+///
+/// 명시되지 않으면 6자리 숫자는 KR, `BASE/QUOTE` 형식은 CRYPTO, 그 외는 US로 봅니다.
+pub(crate) fn preview_market(symbol: &str, market: Option<&str>) -> String {
+    if let Some(market) = market {
+        return market.trim().to_uppercase();
+    }
+    if symbol.len() == 6 && symbol.chars().all(|c| c.is_ascii_digit()) {
+        "KR".to_string()
+    } else if symbol.contains('/') {
+        "CRYPTO".to_string()
+    } else {
+        "US".to_string()
+    }
+}
+
+/// 시장별 호가 단위 제공자 (암호화폐는 심볼별 규칙이라 조정하지 않음).
+fn market_tick_size(market: &str) -> Option<Box<dyn TickSizeProvider>> {
+    match market {
+        "KR" => Some(Box::new(KrxTickSize::new())),
+        "US" => Some(Box::new(UsEquityTickSize::new())),
+        _ => None,
+    }
+}
+
 // ==================== Handler ====================
 
 /// 주문 생성.
@@ -252,6 +379,266 @@ pub async fn create_order(
     }))
 }
 
+/// 주문 미리보기.
+///
+/// POST /api/v1/orders/preview
+///
+/// 실제 주문과 같은 실행 경로(리스크 검사, 전략 예산, 주문 서킷, 브라켓 주문 생성)를
+/// 거치되 주문을 등록하거나 거래소에 전송하지 않습니다. 비용 모델은 전략/기본 모델을
+/// 우선 사용하고, 없으면 시장 기본 모델로 추정합니다.
+pub async fn preview_order(
+    State(state): State<Arc<AppState>>,
+    Json(request): Json<PreviewOrderRequest>,
+) -> Result<Json<PreviewOrderResponse>, (StatusCode, Json<ApiError>)> {
+    use trader_core::{OrderRequest, TimeInForce};
+
+    if request.quantity <= Decimal::ZERO {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiError::new(
+                "INVALID_QUANTITY",
+                "주문 수량은 0보다 커야 합니다",
+            )),
+        ));
+    }
+    if request.order_type == OrderType::Limit && request.price.is_none() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiError::new(
+                "PRICE_REQUIRED",
+                "지정가 주문시 가격이 필요합니다",
+            )),
+        ));
+    }
+
+    let market = preview_market(&request.symbol, request.market.as_deref());
+    let order_request = OrderRequest {
+        ticker: request.symbol.clone(),
+        side: request.side,
+        order_type: request.order_type,
+        quantity: request.quantity,
+        price: request.price,
+        stop_price: request.stop_price,
+        time_in_force: TimeInForce::GTC,
+        client_order_id: None,
+        strategy_id: request.strategy_id.clone(),
+    };
+    let tick_size = market_tick_size(&market);
+
+    let executor = state.executor.read().await;
+
+    let current_price = match request.current_price.or(request.price) {
+        Some(price) => Some(price),
+        None => executor
+            .get_position(&request.symbol)
+            .await
+            .map(|p| p.current_price),
+    }
+    .filter(|price| *price > Decimal::ZERO)
+    .ok_or_else(|| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(ApiError::new(
+                "PRICE_REQUIRED",
+                "현재가를 알 수 없습니다. currentPrice를 지정하세요",
+            )),
+        )
+    })?;
+
+    let cost_model = match executor
+        .strategy_cost_model(request.strategy_id.as_deref())
+        .await
+    {
+        Some(model) => Some(model),
+        None => TradingCostModel::preset(&market),
+    };
+
+    let preview = executor
+        .preview_order(
+            &order_request,
+            current_price,
+            tick_size.as_deref(),
+            cost_model,
+        )
+        .await;
+
+    Ok(Json(PreviewOrderResponse { market, preview }))
+}
+
+/// 바스켓 요청 검증 (가격 조회 전 단계).
+#[allow(clippy::result_large_err)]
+fn validate_basket_request(
+    request: &BasketOrderRequest,
+) -> Result<(), (StatusCode, Json<ApiError>)> {
+    let invalid = |msg: String| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(ApiError::new("INVALID_BASKET", msg)),
+        )
+    };
+
+    if request.legs.is_empty() {
+        return Err(invalid("바스켓에 종목이 없습니다".to_string()));
+    }
+    if request.legs.len() > MAX_BASKET_LEGS {
+        return Err(invalid(format!(
+            "바스켓 종목은 최대 {}개까지 지정할 수 있습니다",
+            MAX_BASKET_LEGS
+        )));
+    }
+
+    let mut symbols = HashSet::new();
+    let mut total_weight = Decimal::ZERO;
+    for leg in &request.legs {
+        if !symbols.insert(leg.symbol.as_str()) {
+            return Err(invalid(format!("중복된 종목입니다: {}", leg.symbol)));
+        }
+        match (leg.quantity, leg.target_weight) {
+            (Some(quantity), None) => {
+                if leg.side.is_none() {
+                    return Err(invalid(format!("{}: 주문 방향이 필요합니다", leg.symbol)));
+                }
+                if quantity <= Decimal::ZERO {
+                    return Err(invalid(format!(
+                        "{}: 주문 수량은 0보다 커야 합니다",
+                        leg.symbol
+                    )));
+                }
+            }
+            (None, Some(weight)) => {
+                if weight < Decimal::ZERO || weight > Decimal::ONE {
+                    return Err(invalid(format!(
+                        "{}: 목표 비중은 0~1 사이여야 합니다",
+                        leg.symbol
+                    )));
+                }
+                total_weight += weight;
+            }
+            _ => {
+                return Err(invalid(format!(
+                    "{}: quantity와 targetWeight 중 하나만 지정하세요",
+                    leg.symbol
+                )))
+            }
+        }
+        if leg.order_type == OrderType::Limit && leg.price.is_none() {
+            return Err(invalid(format!(
+                "{}: 지정가 주문시 가격이 필요합니다",
+                leg.symbol
+            )));
+        }
+    }
+
+    if total_weight > Decimal::ONE {
+        return Err(invalid("목표 비중 합계가 1을 초과합니다".to_string()));
+    }
+    let has_weights = request.legs.iter().any(|l| l.target_weight.is_some());
+    if has_weights && !request.total_value.is_some_and(|v| v > Decimal::ZERO) {
+        return Err(invalid(
+            "targetWeight 사용 시 totalValue가 필요합니다".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+/// 바스켓 주문.
+///
+/// POST /api/v1/orders/basket
+///
+/// 여러 종목의 주문을 동시에 제출하고 종목별 처리 결과를 반환합니다.
+/// 각 leg는 단일 주문과 같은 실행 경로(리스크 검사, 전략 예산, 주문 서킷)를 거치며,
+/// 일부 실패 시 처리는 `policy`로 지정합니다. 목표 비중 leg는 현재 보유 수량과의
+/// 차이만큼 매수/매도하므로 리밸런싱에 그대로 사용할 수 있습니다.
+pub async fn create_basket_order(
+    State(state): State<Arc<AppState>>,
+    Json(request): Json<BasketOrderRequest>,
+) -> Result<Json<BasketResult>, (StatusCode, Json<ApiError>)> {
+    validate_basket_request(&request)?;
+
+    let basket_id = Uuid::new_v4();
+    let result = {
+        let executor = state.executor.read().await;
+
+        let mut legs = Vec::with_capacity(request.legs.len());
+        for leg in &request.legs {
+            let current_price = match leg.current_price.or(leg.price) {
+                Some(price) => Some(price),
+                None => executor
+                    .get_position(&leg.symbol)
+                    .await
+                    .map(|p| p.current_price),
+            }
+            .filter(|price| *price > Decimal::ZERO)
+            .ok_or_else(|| {
+                (
+                    StatusCode::BAD_REQUEST,
+                    Json(ApiError::new(
+                        "PRICE_REQUIRED",
+                        format!(
+                            "{}: 현재가를 알 수 없습니다. currentPrice를 지정하세요",
+                            leg.symbol
+                        ),
+                    )),
+                )
+            })?;
+
+            let target = match (leg.side, leg.quantity, leg.target_weight) {
+                (Some(side), Some(quantity), _) => BasketTarget::Quantity { side, quantity },
+                (_, _, Some(weight)) => BasketTarget::Weight { weight },
+                _ => unreachable!("validated by validate_basket_request"),
+            };
+            legs.push(BasketLeg {
+                ticker: leg.symbol.clone(),
+                target,
+                order_type: leg.order_type,
+                limit_price: leg.price,
+                current_price,
+            });
+        }
+
+        executor
+            .process_basket(
+                basket_id,
+                BasketRequest {
+                    legs,
+                    total_value: request.total_value,
+                    policy: request.policy,
+                    strategy_id: request.strategy_id.clone(),
+                },
+            )
+            .await
+    };
+
+    // 접수된 leg 알림
+    for leg in &result.legs {
+        let (Some(order_id), Some(side)) = (leg.order_id, leg.side) else {
+            continue;
+        };
+        if leg.status != BasketLegStatus::Submitted {
+            continue;
+        }
+        let leg_request = request.legs.iter().find(|l| l.symbol == leg.ticker);
+        record_order(&leg.ticker, side.as_str(), "basket");
+        state.broadcast(ServerMessage::OrderUpdate(OrderUpdateData {
+            order_id: order_id.to_string(),
+            symbol: leg.ticker.clone(),
+            status: "pending".to_string(),
+            side: side.as_str().to_string(),
+            order_type: leg_request
+                .map(|l| format!("{:?}", l.order_type).to_lowercase())
+                .unwrap_or_default(),
+            quantity: leg.quantity,
+            filled_quantity: Decimal::ZERO,
+            price: leg_request.and_then(|l| l.price),
+            average_price: None,
+            timestamp: Utc::now().timestamp_millis(),
+        }));
+    }
+
+    Ok(Json(result))
+}
+
 /// 활성 주문 목록 조회.
 ///
 /// GET /api/v1/orders
@@ -458,6 +845,8 @@ pub async fn get_order_stats(State(state): State<Arc<AppState>>) -> impl IntoRes
 pub fn orders_router() -> Router<Arc<AppState>> {
     Router::new()
         .route("/", get(list_orders).post(create_order))
+        .route("/preview", post(preview_order))
+        .route("/basket", post(create_basket_order))
         .route("/stats", get(get_order_stats))
         .route("/{id}", get(get_order).delete(cancel_order))
 }
@@ -504,6 +893,129 @@ mod tests {
         assert!(list.orders.is_empty());
     }
 
+    #[tokio::test]
+    async fn test_preview_order_rounds_to_tick() {
+        use crate::state::create_test_state;
+
+        let state = Arc::new(create_test_state());
+        let app = Router::new()
+            .route("/orders/preview", post(preview_order))
+            .with_state(state.clone());
+
+        let body = serde_json::json!({
+            "symbol": "005930",
+            "side": "buy",
+            "type": "limit",
+            "quantity": 1,
+            "price": 70050
+        });
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/orders/preview")
+                    .header("content-type", "application/json")
+                    .body(Body::from(body.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["market"], "KR");
+        // 50,000원 이상은 100원 단위, 매수는 내림
+        assert_eq!(json["preview"]["orders"][0]["order"]["price"], 70000.0);
+        assert!(json["preview"]["estimated_cost"].is_object());
+
+        // 미리보기는 주문을 등록하지 않음
+        let executor = state.executor.read().await;
+        assert!(executor.get_active_orders().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_basket_order_submits_legs() {
+        use crate::state::create_test_state;
+
+        let state = Arc::new(create_test_state());
+        let app = Router::new()
+            .route("/orders/basket", post(create_basket_order))
+            .with_state(state.clone());
+
+        let body = serde_json::json!({
+            "legs": [
+                { "symbol": "BTC/USDT", "side": "buy", "quantity": 1, "currentPrice": 100 },
+                { "symbol": "ETH/USDT", "targetWeight": 0.05, "currentPrice": 50 }
+            ],
+            "totalValue": 10000
+        });
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/orders/basket")
+                    .header("content-type", "application/json")
+                    .body(Body::from(body.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["submitted"], 2, "{}", json);
+        assert_eq!(json["legs"][0]["status"], "submitted");
+        // 목표 500 / 현재가 50 → 10 매수
+        assert_eq!(json["legs"][1]["side"], "buy");
+        assert_eq!(json["legs"][1]["quantity"], 10.0);
+
+        let executor = state.executor.read().await;
+        assert_eq!(executor.get_active_orders().await.len(), 2);
+    }
+
+    #[test]
+    fn test_validate_basket_request() {
+        let request = |body: serde_json::Value| -> BasketOrderRequest {
+            serde_json::from_value(body).unwrap()
+        };
+
+        // 비중 사용 시 총액 필수
+        assert!(validate_basket_request(&request(serde_json::json!({
+            "legs": [{ "symbol": "AAPL", "targetWeight": 0.5 }]
+        })))
+        .is_err());
+        // 수량과 비중 동시 지정 불가
+        assert!(validate_basket_request(&request(serde_json::json!({
+            "legs": [{ "symbol": "AAPL", "side": "buy", "quantity": 1, "targetWeight": 0.5 }],
+            "totalValue": 1000
+        })))
+        .is_err());
+        // 비중 합계 초과
+        assert!(validate_basket_request(&request(serde_json::json!({
+            "legs": [
+                { "symbol": "AAPL", "targetWeight": 0.7 },
+                { "symbol": "MSFT", "targetWeight": 0.6 }
+            ],
+            "totalValue": 1000
+        })))
+        .is_err());
+        assert!(validate_basket_request(&request(serde_json::json!({
+            "legs": [
+                { "symbol": "AAPL", "targetWeight": 0.5 },
+                { "symbol": "MSFT", "side": "sell", "quantity": 3, "type": "limit", "price": 400 }
+            ],
+            "totalValue": 1000,
+            "policy": "all_or_none"
+        })))
+        .is_ok());
+    }
+
     #[tokio::test]
     async fn test_get_order_not_found() {
         use crate::state::create_test_state;
@@ -0,0 +1,191 @@
+//! 전략 모의투자 모드 endpoint.
+//!
+//! 모의투자 모드 전략은 실시간 시세로 신호를 생성하지만 주문은 실행기 대신 전략별
+//! 시뮬레이션 거래소 가상 계좌에서 체결됩니다 (`services::paper_trading`).
+//!
+//! # 엔드포인트
+//!
+//! - `GET /api/v1/strategies/{id}/paper-trading` - 모의투자 상태, 가상 계좌, 최근 체결 내역
+//! - `PUT /api/v1/strategies/{id}/paper-trading` - 모의투자 모드 변경
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    Json,
+};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+use super::strategy_history::{load_param_snapshot, record_param_change, resolve_actor};
+use crate::auth::OptionalJwtAuth;
+use crate::error::{ApiErrorResponse, ApiResult};
+use crate::repository::{PaperTradeRecord, PaperTradeRepository, StrategyRepository};
+use crate::services::PaperAccountSnapshot;
+use crate::state::AppState;
+use crate::websocket::{ServerMessage, StrategyUpdateData};
+
+/// 기본 체결 내역 조회 건수.
+const DEFAULT_TRADE_LIMIT: i64 = 50;
+/// 최대 체결 내역 조회 건수.
+const MAX_TRADE_LIMIT: i64 = 500;
+
+/// 모의투자 조회 쿼리.
+#[derive(Debug, Default, Deserialize)]
+pub struct PaperTradingQuery {
+    /// 최근 체결 내역 건수 (기본 50, 최대 500)
+    #[serde(default)]
+    pub limit: Option<i64>,
+}
+
+/// 모의투자 모드 변경 요청.
+#[derive(Debug, Deserialize)]
+pub struct UpdatePaperTradingRequest {
+    pub enabled: bool,
+}
+
+/// 모의투자 상태 응답.
+#[derive(Debug, Serialize)]
+pub struct PaperTradingResponse {
+    pub strategy_id: String,
+    pub enabled: bool,
+    /// 가상 계좌 상태 (모의투자 모드가 아니면 `None`)
+    pub account: Option<PaperAccountSnapshot>,
+    /// 최근 체결 내역 (최신순, DB 미연결 시 빈 목록)
+    pub trades: Vec<PaperTradeRecord>,
+}
+
+fn strategy_not_found(id: &str) -> (StatusCode, Json<ApiErrorResponse>) {
+    (
+        StatusCode::NOT_FOUND,
+        Json(ApiErrorResponse::new(
+            "STRATEGY_NOT_FOUND",
+            format!("Strategy '{}' not found", id),
+        )),
+    )
+}
+
+fn database_error(e: sqlx::Error) -> (StatusCode, Json<ApiErrorResponse>) {
+    tracing::error!("Paper trading query failed: {}", e);
+    (
+        StatusCode::INTERNAL_SERVER_ERROR,
+        Json(ApiErrorResponse::new(
+            "DATABASE_ERROR",
+            format!("Database error: {}", e),
+        )),
+    )
+}
+
+/// 가상 계좌와 최근 체결 내역으로 응답 구성.
+async fn build_response(
+    state: &AppState,
+    id: String,
+    enabled: bool,
+    limit: i64,
+) -> ApiResult<PaperTradingResponse> {
+    let account = state.paper_trading.snapshot(&id).await;
+    let trades = match &state.db_pool {
+        Some(pool) => PaperTradeRepository::list_by_strategy(pool, &id, limit)
+            .await
+            .map_err(database_error)?,
+        None => Vec::new(),
+    };
+
+    Ok(PaperTradingResponse {
+        strategy_id: id,
+        enabled,
+        account,
+        trades,
+    })
+}
+
+/// 전략 모의투자 상태 조회.
+///
+/// GET /api/v1/strategies/{id}/paper-trading
+pub async fn get_paper_trading(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+    Query(query): Query<PaperTradingQuery>,
+) -> ApiResult<Json<PaperTradingResponse>> {
+    let enabled = {
+        let engine = state.strategy_engine.read().await;
+        engine
+            .get_strategy_status(&id)
+            .await
+            .map_err(|_| strategy_not_found(&id))?
+            .paper_trading
+    };
+    let limit = query
+        .limit
+        .unwrap_or(DEFAULT_TRADE_LIMIT)
+        .clamp(1, MAX_TRADE_LIMIT);
+
+    Ok(Json(build_response(&state, id, enabled, limit).await?))
+}
+
+/// 전략 모의투자 모드 변경.
+///
+/// PUT /api/v1/strategies/{id}/paper-trading
+///
+/// 켜면 전략 신호가 실행기로 전달되지 않고 초기 자본으로 개설된 가상 계좌에서 체결됩니다.
+/// 끄면 가상 계좌를 폐쇄하며, 저장된 체결 내역은 유지됩니다.
+pub async fn update_paper_trading(
+    State(state): State<Arc<AppState>>,
+    auth: OptionalJwtAuth,
+    Path(id): Path<String>,
+    Json(request): Json<UpdatePaperTradingRequest>,
+) -> ApiResult<Json<PaperTradingResponse>> {
+    let before = load_param_snapshot(&state, &id).await;
+
+    if let Some(pool) = &state.db_pool {
+        StrategyRepository::update_paper_trading(pool, &id, request.enabled)
+            .await
+            .map_err(|e| match e {
+                sqlx::Error::RowNotFound => strategy_not_found(&id),
+                e => database_error(e),
+            })?;
+    }
+
+    let (strategy_name, is_running) = {
+        let engine = state.strategy_engine.read().await;
+        engine
+            .set_strategy_paper_trading(&id, request.enabled)
+            .await
+            .map_err(|_| strategy_not_found(&id))?;
+        engine
+            .get_strategy_status(&id)
+            .await
+            .map(|s| (s.name, s.running))
+            .unwrap_or_else(|_| (id.clone(), false))
+    };
+
+    if request.enabled {
+        state.paper_trading.open(&id).await;
+    } else {
+        state.paper_trading.close(&id).await;
+    }
+
+    record_param_change(
+        &state,
+        &id,
+        "paper_trading",
+        before,
+        resolve_actor(&auth),
+        is_running,
+    )
+    .await;
+
+    // WebSocket 브로드캐스트: 모의투자 모드 변경 알림
+    state.broadcast(ServerMessage::StrategyUpdate(StrategyUpdateData {
+        strategy_id: id.clone(),
+        name: strategy_name,
+        running: is_running,
+        event: "paper_trading_updated".to_string(),
+        data: Some(serde_json::json!({ "paper_trading": request.enabled })),
+        timestamp: Utc::now().timestamp_millis(),
+    }));
+
+    Ok(Json(
+        build_response(&state, id, request.enabled, DEFAULT_TRADE_LIMIT).await?,
+    ))
+}
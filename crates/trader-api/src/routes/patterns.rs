@@ -462,8 +462,6 @@ pub fn patterns_router() -> Router<Arc<AppState>> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use axum::{body::Body, http::Request};
-    use tower::ServiceExt;
 
     #[test]
     fn test_pattern_query_defaults() {
@@ -5,34 +5,48 @@
 //!
 //! # 엔드포인트
 //!
+//! - `GET /api/v1/portfolio?account=...` - 계좌별 포지션과 계좌 간 상계 뷰
 //! - `GET /api/v1/portfolio/summary` - 포트폴리오 요약
 //! - `GET /api/v1/portfolio/balance` - 상세 잔고 조회
-//! - `GET /api/v1/portfolio/holdings` - 보유 종목 목록
+//! - `GET /api/v1/portfolio/holdings` - 보유 종목 목록 (기초자산별 묶음 포함)
+//! - `GET /api/v1/portfolio/consolidated` - 전 계좌 보유 종목의 기초자산별 통합
+//! - `GET /api/v1/portfolio/underlyings` - 종목 → 기초자산 매핑 목록
+//! - `PUT /api/v1/portfolio/underlyings/{ticker}` - 매핑 등록/수정
+//! - `DELETE /api/v1/portfolio/underlyings/{ticker}` - 매핑 삭제
 //!
 //! # 쿼리 파라미터
 //!
 //! - `credential_id` (선택): 특정 거래소 자격증명 ID로 조회
+//! - `usd_krw` (선택, consolidated): 원화/달러가 섞인 묶음의 원화 환산 환율
+//! - `account` (선택, 상계 뷰): 계좌 유형(paper, real_general, real_isa), credential ID 또는 all
 
 use axum::{
-    extract::{Query, State},
+    extract::{Path, Query, State},
     http::StatusCode,
-    routing::get,
+    routing::{get, put},
     Json, Router,
 };
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
 use std::sync::Arc;
 use tracing::{debug, error, info, warn};
 use uuid::Uuid;
 
 use crate::repository::{
-    EquityHistoryRepository, ExchangeProviderPair, HoldingPosition, PortfolioSnapshot,
-    PositionRepository,
+    AccountPositionRecord, EquityHistoryRepository, ExchangeProviderPair, HoldingPosition,
+    PortfolioRepository, PortfolioSnapshot, PositionRecord, PositionRepository,
+    SymbolUnderlyingRecord, UnderlyingRepository,
 };
 use crate::routes::strategies::ApiError;
 use crate::state::AppState;
-use chrono::Utc;
+use chrono::{DateTime, Utc};
+use trader_analytics::{
+    consolidate_by_underlying, FxTable, UnderlyingAsset, UnderlyingGroup, VenueHolding,
+};
 use trader_core::{ExecutionHistoryRequest, ExecutionRecord};
+use trader_exchange::connector::kis::KisAccountType;
+use trader_execution::{net_positions, NettedPosition};
 
 // ==================== 응답 타입 ====================
 
@@ -108,6 +122,9 @@ pub struct HoldingsResponse {
     pub us_holdings: Vec<HoldingInfo>,
     /// 총 보유 종목 수
     pub total_count: usize,
+    /// 기초자산별 묶음 (국내 상장/미국 상장 교차 보유 통합)
+    #[serde(default)]
+    pub underlyings: Vec<UnderlyingGroup>,
 }
 
 /// 개별 보유 종목 정보.
@@ -137,6 +154,91 @@ pub struct HoldingInfo {
     pub market: String,
 }
 
+/// 전 계좌 기초자산별 통합 응답.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConsolidatedPortfolioResponse {
+    /// 기초자산별 묶음 (합계 평가금액 내림차순)
+    pub groups: Vec<UnderlyingGroup>,
+    /// 통합된 포지션 수
+    pub position_count: usize,
+    /// 포지션을 보유한 계좌 수
+    pub account_count: usize,
+    /// 통화가 섞인 묶음의 환산 기준 통화 (환율 제공 시)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base_currency: Option<String>,
+    /// 조회 시각
+    pub timestamp: DateTime<Utc>,
+}
+
+/// 계좌별 포지션 상계 응답.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountPortfolioResponse {
+    /// 조회한 계좌 (포지션이 있는 계좌만)
+    pub accounts: Vec<PortfolioAccount>,
+    /// 종목별 계좌 간 상계 포지션 (계좌별 내역은 `legs`, 계좌 키는 credential ID)
+    pub positions: Vec<NettedPositionView>,
+    /// 통화별 합계
+    pub totals: BTreeMap<String, NettedTotals>,
+    /// 조회 시각
+    pub timestamp: DateTime<Utc>,
+}
+
+/// 상계 뷰에 포함된 계좌.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PortfolioAccount {
+    pub credential_id: Uuid,
+    /// 계좌 표시 이름
+    pub name: String,
+    /// paper / real_general / real_isa
+    pub account_type: String,
+    /// 열린 포지션 수
+    pub position_count: usize,
+}
+
+/// 통화 정보를 붙인 상계 포지션.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NettedPositionView {
+    #[serde(flatten)]
+    pub position: NettedPosition,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    pub currency: String,
+}
+
+/// 통화별 상계 합계.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NettedTotals {
+    /// 순 평가금액
+    pub net_market_value: Decimal,
+    /// 총 익스포저
+    pub gross_exposure: Decimal,
+    pub unrealized_pnl: Decimal,
+    pub realized_pnl: Decimal,
+}
+
+/// 기초자산 매핑 응답.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnderlyingMappingsResponse {
+    pub mappings: Vec<SymbolUnderlyingRecord>,
+    pub count: usize,
+}
+
+/// 기초자산 매핑 등록 요청.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpsertUnderlyingRequest {
+    /// 기초자산 키 (예: "SPX")
+    pub underlying: String,
+    /// 표시 이름 (예: "S&P 500")
+    pub underlying_name: Option<String>,
+}
+
 // ==================== 쿼리 파라미터 ====================
 
 /// 포트폴리오 API 쿼리 파라미터.
@@ -146,6 +248,20 @@ pub struct PortfolioQuery {
     pub credential_id: Option<Uuid>,
 }
 
+/// 계좌별 포지션 상계 조회 쿼리 파라미터.
+#[derive(Debug, Deserialize)]
+pub struct AccountPortfolioQuery {
+    /// 계좌 선택 (쉼표 구분, 기본 all)
+    pub account: Option<String>,
+}
+
+/// 기초자산별 통합 조회 쿼리 파라미터.
+#[derive(Debug, Deserialize)]
+pub struct ConsolidatedQuery {
+    /// 원/달러 환율 (원화·달러 혼합 묶음의 원화 환산용, 없으면 통화별 합계만 제공)
+    pub usd_krw: Option<Decimal>,
+}
+
 /// 체결 내역 조회 쿼리 파라미터.
 #[derive(Debug, Deserialize)]
 pub struct OrderHistoryQuery {
@@ -237,8 +353,228 @@ pub async fn get_or_create_exchange_providers(
     Ok(pair_arc)
 }
 
+/// 기초자산 매핑 조회 (DB 미연결 또는 실패 시 빈 매핑, 티커 단위로만 통합).
+async fn load_underlyings(state: &AppState) -> HashMap<String, UnderlyingAsset> {
+    let Some(pool) = &state.db_pool else {
+        return HashMap::new();
+    };
+    UnderlyingRepository::load_map(pool)
+        .await
+        .unwrap_or_else(|e| {
+            warn!("기초자산 매핑 조회 실패: {}", e);
+            HashMap::new()
+        })
+}
+
+/// 시장 구분으로 평가 통화 결정.
+fn market_currency(market: &str, ticker: &str) -> String {
+    match market {
+        "KR" => "KRW".to_string(),
+        "CRYPTO" => ticker
+            .split_once('/')
+            .map(|(_, quote)| quote.to_uppercase())
+            .unwrap_or_else(|| "USDT".to_string()),
+        _ => "USD".to_string(),
+    }
+}
+
+/// 티커 형식으로 시장 추정 (positions 테이블에는 시장 컬럼이 없음).
+fn infer_market(ticker: &str) -> &'static str {
+    if ticker.contains('/') {
+        "CRYPTO"
+    } else if ticker.len() == 6
+        && ticker.chars().all(|c| c.is_ascii_alphanumeric())
+        && ticker.chars().next().is_some_and(|c| c.is_ascii_digit())
+    {
+        "KR"
+    } else {
+        "US"
+    }
+}
+
+/// 보유 종목 → 통합용 거래 장소별 보유.
+fn holding_to_venue(holding: &HoldingInfo, account: Option<Uuid>, exchange: &str) -> VenueHolding {
+    VenueHolding {
+        account: account.map(|id| id.to_string()),
+        exchange: exchange.to_string(),
+        market: holding.market.clone(),
+        symbol: holding.symbol.clone(),
+        name: holding.name.clone(),
+        currency: market_currency(&holding.market, &holding.symbol),
+        quantity: holding.quantity,
+        avg_price: holding.avg_price,
+        current_price: holding.current_price,
+        eval_amount: holding.eval_amount,
+        profit_loss: holding.profit_loss,
+        weight_pct: None,
+    }
+}
+
+/// 동기화된 포지션 → 통합용 거래 장소별 보유.
+fn position_to_venue(record: &PositionRecord) -> Option<VenueHolding> {
+    let symbol = record.symbol.clone()?;
+    let market = infer_market(&symbol);
+    let current_price = record.current_price.unwrap_or(record.entry_price);
+    let eval_amount = record.quantity * current_price;
+
+    Some(VenueHolding {
+        account: record.credential_id.map(|id| id.to_string()),
+        exchange: record.exchange.clone(),
+        market: market.to_string(),
+        currency: market_currency(market, &symbol),
+        name: record.symbol_name.clone().unwrap_or_else(|| symbol.clone()),
+        symbol,
+        quantity: record.quantity,
+        avg_price: record.entry_price,
+        current_price,
+        eval_amount,
+        profit_loss: record
+            .unrealized_pnl
+            .unwrap_or(eval_amount - record.quantity * record.entry_price),
+        weight_pct: None,
+    })
+}
+
+/// `account` 쿼리의 계좌 선택 항목.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum AccountSelector {
+    /// 계좌 유형 (paper, real_general, real_isa)
+    Type(&'static str),
+    /// 특정 credential
+    Credential(Uuid),
+}
+
+impl AccountSelector {
+    fn matches(&self, record: &AccountPositionRecord) -> bool {
+        match self {
+            Self::Type(account_type) => record.account_type == *account_type,
+            Self::Credential(id) => record.credential_id == *id,
+        }
+    }
+}
+
+/// `account` 쿼리 파싱 (빈 목록은 전체 계좌).
+fn parse_account_selectors(value: Option<&str>) -> Result<Vec<AccountSelector>, String> {
+    let mut selectors = Vec::new();
+    for item in value.unwrap_or("").split(',').map(str::trim) {
+        if item.is_empty() || item.eq_ignore_ascii_case("all") {
+            continue;
+        }
+        if let Ok(id) = Uuid::parse_str(item) {
+            selectors.push(AccountSelector::Credential(id));
+            continue;
+        }
+        let account_type = match KisAccountType::parse(item) {
+            Some(KisAccountType::Paper) => "paper",
+            Some(KisAccountType::RealGeneral) => "real_general",
+            Some(KisAccountType::RealIsa) => "real_isa",
+            None => return Err(format!(
+                "알 수 없는 계좌입니다: '{}' (paper, real_general, real_isa, credential ID, all)",
+                item
+            )),
+        };
+        selectors.push(AccountSelector::Type(account_type));
+    }
+    Ok(selectors)
+}
+
+/// 선택된 계좌의 포지션을 종목별로 상계.
+fn build_account_portfolio(
+    records: &[AccountPositionRecord],
+    selectors: &[AccountSelector],
+    timestamp: DateTime<Utc>,
+) -> AccountPortfolioResponse {
+    let selected: Vec<&AccountPositionRecord> = records
+        .iter()
+        .filter(|r| selectors.is_empty() || selectors.iter().any(|s| s.matches(r)))
+        .collect();
+
+    let mut accounts: Vec<PortfolioAccount> = Vec::new();
+    for record in &selected {
+        match accounts
+            .iter_mut()
+            .find(|a| a.credential_id == record.credential_id)
+        {
+            Some(account) => account.position_count += 1,
+            None => accounts.push(PortfolioAccount {
+                credential_id: record.credential_id,
+                name: record.account_name.clone(),
+                account_type: record.account_type.clone(),
+                position_count: 1,
+            }),
+        }
+    }
+
+    let names: HashMap<&str, &str> = selected
+        .iter()
+        .filter_map(|r| Some((r.symbol.as_deref()?, r.symbol_name.as_deref()?)))
+        .collect();
+    let mut totals: BTreeMap<String, NettedTotals> = BTreeMap::new();
+    let positions = net_positions(selected.iter().filter_map(|r| r.to_leg()))
+        .into_iter()
+        .map(|position| {
+            let currency = market_currency(infer_market(&position.ticker), &position.ticker);
+            let total = totals.entry(currency.clone()).or_default();
+            total.net_market_value += position.net_market_value;
+            total.gross_exposure += position.gross_exposure;
+            total.unrealized_pnl += position.unrealized_pnl;
+            total.realized_pnl += position.realized_pnl;
+
+            NettedPositionView {
+                name: names.get(position.ticker.as_str()).map(|n| n.to_string()),
+                currency,
+                position,
+            }
+        })
+        .collect();
+
+    AccountPortfolioResponse {
+        accounts,
+        positions,
+        totals,
+        timestamp,
+    }
+}
+
 // ==================== Handler ====================
 
+/// 계좌별 포지션과 계좌 간 상계 뷰.
+///
+/// GET /api/v1/portfolio?account=real_general,real_isa
+///
+/// 동시에 운용하는 계좌들의 동기화된 열린 포지션(positions 테이블)을 종목별로 상계합니다.
+/// `account`를 생략하거나 `all`이면 활성 계좌 전체를 합칩니다.
+pub async fn get_account_portfolio(
+    State(state): State<Arc<AppState>>,
+    Query(params): Query<AccountPortfolioQuery>,
+) -> Result<Json<AccountPortfolioResponse>, (StatusCode, Json<ApiError>)> {
+    let selectors = parse_account_selectors(params.account.as_deref()).map_err(|e| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(ApiError::new("INVALID_ACCOUNT", e)),
+        )
+    })?;
+    let pool = require_db(&state)?;
+    let records = PortfolioRepository::get_account_positions(pool)
+        .await
+        .map_err(|e| {
+            error!("계좌별 포지션 조회 실패: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiError::new(
+                    "DB_ERROR",
+                    format!("포지션 조회 실패: {}", e),
+                )),
+            )
+        })?;
+
+    Ok(Json(build_account_portfolio(
+        &records,
+        &selectors,
+        Utc::now(),
+    )))
+}
+
 /// 포트폴리오 요약 조회.
 ///
 /// GET /api/v1/portfolio/summary?credential_id=...
@@ -588,6 +924,18 @@ pub async fn get_holdings(
 
     let total_count = kr_holdings.len() + us_holdings.len();
 
+    // 기초자산별 묶음 (국내 상장 vs 미국 상장 교차 보유)
+    let venues: Vec<VenueHolding> = kr_holdings
+        .iter()
+        .chain(us_holdings.iter())
+        .map(|h| holding_to_venue(h, params.credential_id, "kis"))
+        .collect();
+    let underlyings = if venues.is_empty() {
+        Vec::new()
+    } else {
+        consolidate_by_underlying(venues, &load_underlyings(&state).await, None)
+    };
+
     // 거래소 데이터를 positions 테이블에 동기화
     if let (Some(db_pool), Some(credential_id)) = (&state.db_pool, params.credential_id) {
         // 동기화할 holdings 데이터 준비
@@ -649,9 +997,151 @@ pub async fn get_holdings(
         kr_holdings,
         us_holdings,
         total_count,
+        underlyings,
     }))
 }
 
+/// 전 계좌 보유 종목의 기초자산별 통합 조회.
+///
+/// GET /api/v1/portfolio/consolidated?usd_krw=1400
+///
+/// 계좌별로 동기화된 열린 포지션(positions 테이블)을 기초자산 매핑으로 묶고
+/// 계좌/상장 시장별 내역을 함께 반환합니다.
+pub async fn get_consolidated_portfolio(
+    State(state): State<Arc<AppState>>,
+    Query(params): Query<ConsolidatedQuery>,
+) -> Result<Json<ConsolidatedPortfolioResponse>, (StatusCode, Json<ApiError>)> {
+    let pool = require_db(&state)?;
+    let positions = PositionRepository::get_all_open_positions(pool)
+        .await
+        .map_err(|e| {
+            error!("열린 포지션 조회 실패: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiError::new(
+                    "DB_ERROR",
+                    format!("포지션 조회 실패: {}", e),
+                )),
+            )
+        })?;
+
+    let venues: Vec<VenueHolding> = positions.iter().filter_map(position_to_venue).collect();
+    let position_count = venues.len();
+    let mut accounts: Vec<&str> = venues.iter().filter_map(|v| v.account.as_deref()).collect();
+    accounts.sort_unstable();
+    accounts.dedup();
+    let account_count = accounts.len();
+
+    let fx = params
+        .usd_krw
+        .filter(|rate| *rate > Decimal::ZERO)
+        .map(|rate| FxTable::new("KRW").with_rate("USD", rate));
+    let underlyings = load_underlyings(&state).await;
+    let groups = consolidate_by_underlying(venues, &underlyings, fx.as_ref());
+
+    Ok(Json(ConsolidatedPortfolioResponse {
+        groups,
+        position_count,
+        account_count,
+        base_currency: fx.map(|fx| fx.base_currency().to_string()),
+        timestamp: Utc::now(),
+    }))
+}
+
+/// 기초자산 매핑 목록.
+///
+/// GET /api/v1/portfolio/underlyings
+pub async fn list_underlyings(
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<UnderlyingMappingsResponse>, (StatusCode, Json<ApiError>)> {
+    let pool = require_db(&state)?;
+    let mappings = UnderlyingRepository::list(pool).await.map_err(db_error)?;
+    Ok(Json(UnderlyingMappingsResponse {
+        count: mappings.len(),
+        mappings,
+    }))
+}
+
+/// 기초자산 매핑 등록/수정.
+///
+/// PUT /api/v1/portfolio/underlyings/{ticker}
+pub async fn upsert_underlying(
+    State(state): State<Arc<AppState>>,
+    Path(ticker): Path<String>,
+    Json(request): Json<UpsertUnderlyingRequest>,
+) -> Result<Json<SymbolUnderlyingRecord>, (StatusCode, Json<ApiError>)> {
+    let underlying = request.underlying.trim();
+    if underlying.is_empty() || ticker.trim().is_empty() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiError::new(
+                "INVALID_INPUT",
+                "티커와 기초자산 키는 필수입니다.",
+            )),
+        ));
+    }
+
+    let pool = require_db(&state)?;
+    let record = UnderlyingRepository::upsert(
+        pool,
+        ticker.trim(),
+        underlying,
+        request.underlying_name.as_deref().filter(|n| !n.is_empty()),
+    )
+    .await
+    .map_err(db_error)?;
+
+    info!(
+        "기초자산 매핑 등록: {} → {}",
+        record.ticker, record.underlying
+    );
+    Ok(Json(record))
+}
+
+/// 기초자산 매핑 삭제.
+///
+/// DELETE /api/v1/portfolio/underlyings/{ticker}
+pub async fn delete_underlying(
+    State(state): State<Arc<AppState>>,
+    Path(ticker): Path<String>,
+) -> Result<StatusCode, (StatusCode, Json<ApiError>)> {
+    let pool = require_db(&state)?;
+    if UnderlyingRepository::delete(pool, &ticker)
+        .await
+        .map_err(db_error)?
+    {
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err((
+            StatusCode::NOT_FOUND,
+            Json(ApiError::new(
+                "NOT_FOUND",
+                format!("{} 매핑이 없습니다.", ticker),
+            )),
+        ))
+    }
+}
+
+fn require_db(state: &AppState) -> Result<&sqlx::PgPool, (StatusCode, Json<ApiError>)> {
+    state.db_pool.as_ref().ok_or_else(|| {
+        (
+            StatusCode::SERVICE_UNAVAILABLE,
+            Json(ApiError::new(
+                "DB_NOT_CONFIGURED",
+                "데이터베이스 연결이 설정되지 않았습니다.",
+            )),
+        )
+    })
+}
+
+fn db_error(e: sqlx::Error) -> (StatusCode, Json<ApiError>) {
+    error!("기초자산 매핑 DB 오류: {}", e);
+    (
+        StatusCode::INTERNAL_SERVER_ERROR,
+        Json(ApiError::new("DB_ERROR", e.to_string())),
+    )
+}
+
 /// 체결 내역 조회 응답.
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -853,9 +1343,16 @@ fn mock_portfolio_summary() -> PortfolioSummaryResponse {
 /// 포트폴리오 관리 라우터 생성.
 pub fn portfolio_router() -> Router<Arc<AppState>> {
     Router::new()
+        .route("/", get(get_account_portfolio))
         .route("/summary", get(get_portfolio_summary))
         .route("/balance", get(get_balance))
         .route("/holdings", get(get_holdings))
+        .route("/consolidated", get(get_consolidated_portfolio))
+        .route("/underlyings", get(list_underlyings))
+        .route(
+            "/underlyings/{ticker}",
+            put(upsert_underlying).delete(delete_underlying),
+        )
         .route("/orders", get(get_order_history))
 }
 
@@ -929,4 +1426,92 @@ mod tests {
         // KIS 클라이언트 미설정 시 빈 목록
         assert_eq!(holdings.total_count, 0);
     }
+
+    #[test]
+    fn test_position_to_venue() {
+        use rust_decimal_macros::dec;
+
+        let record = |symbol: &str, current: Option<Decimal>| PositionRecord {
+            id: Uuid::new_v4(),
+            credential_id: Some(Uuid::nil()),
+            exchange: "kis".to_string(),
+            symbol_id: Uuid::new_v4(),
+            symbol: Some(symbol.to_string()),
+            symbol_name: None,
+            side: trader_core::Side::Buy,
+            quantity: dec!(10),
+            entry_price: dec!(100),
+            current_price: current,
+            unrealized_pnl: None,
+            realized_pnl: None,
+            strategy_id: None,
+            opened_at: None,
+            updated_at: None,
+            closed_at: None,
+            metadata: None,
+        };
+
+        let kr = position_to_venue(&record("360750", Some(dec!(120)))).unwrap();
+        assert_eq!((kr.market.as_str(), kr.currency.as_str()), ("KR", "KRW"));
+        assert_eq!(kr.eval_amount, dec!(1200));
+        assert_eq!(kr.profit_loss, dec!(200));
+        assert_eq!(kr.name, "360750");
+
+        let us = position_to_venue(&record("SPY", None)).unwrap();
+        assert_eq!((us.market.as_str(), us.currency.as_str()), ("US", "USD"));
+        assert_eq!(us.eval_amount, dec!(1000));
+
+        let crypto = position_to_venue(&record("BTC/USDT", None)).unwrap();
+        assert_eq!(crypto.currency, "USDT");
+    }
+
+    #[test]
+    fn test_build_account_portfolio_nets_selected_accounts() {
+        use rust_decimal_macros::dec;
+
+        let general = Uuid::from_u128(1);
+        let isa = Uuid::from_u128(2);
+        let paper = Uuid::from_u128(3);
+        let record = |id: Uuid, account_type: &str, symbol: &str, side: &str, qty: Decimal| {
+            AccountPositionRecord {
+                credential_id: id,
+                account_name: format!("KIS {}", account_type),
+                account_type: account_type.to_string(),
+                exchange: "kis".to_string(),
+                symbol: Some(symbol.to_string()),
+                symbol_name: Some("삼성전자".to_string()),
+                side: side.to_string(),
+                quantity: qty,
+                entry_price: dec!(70000),
+                current_price: Some(dec!(71000)),
+                unrealized_pnl: None,
+                realized_pnl: None,
+            }
+        };
+        let records = vec![
+            record(general, "real_general", "005930", "buy", dec!(10)),
+            record(isa, "real_isa", "005930", "buy", dec!(5)),
+            record(paper, "paper", "005930", "sell", dec!(3)),
+        ];
+
+        let all = build_account_portfolio(&records, &[], Utc::now());
+        assert_eq!(all.accounts.len(), 3);
+        assert_eq!(all.positions.len(), 1);
+        assert_eq!(all.positions[0].position.net_quantity, dec!(12));
+        assert_eq!(all.positions[0].position.legs.len(), 3);
+        assert_eq!(all.positions[0].name.as_deref(), Some("삼성전자"));
+        // 롱 15주 이익 15000, 숏 3주 손실 3000
+        assert_eq!(all.totals["KRW"].unrealized_pnl, dec!(12000));
+        assert_eq!(all.totals["KRW"].net_market_value, dec!(852000));
+
+        let selectors = parse_account_selectors(Some("general, isa")).unwrap();
+        let real = build_account_portfolio(&records, &selectors, Utc::now());
+        assert_eq!(real.accounts.len(), 2);
+        assert_eq!(real.positions[0].position.net_quantity, dec!(15));
+
+        let by_id = parse_account_selectors(Some(&paper.to_string())).unwrap();
+        assert_eq!(by_id, vec![AccountSelector::Credential(paper)]);
+        assert!(parse_account_selectors(Some("margin")).is_err());
+        assert!(parse_account_selectors(Some("all")).unwrap().is_empty());
+    }
 }
@@ -193,7 +193,7 @@ pub async fn get_top_ranked(
     debug!("랭킹 조회 요청: {:?}", query);
 
     // DB 연결 확인
-    let db_pool = state.db_pool.as_ref().ok_or_else(|| {
+    let db_pool = state.analytics_db_pool().ok_or_else(|| {
         (
             StatusCode::INTERNAL_SERVER_ERROR,
             "Database not available".to_string(),
@@ -260,7 +260,7 @@ pub async fn get_seven_factor(
 ) -> Result<Json<SevenFactorResponse>, (StatusCode, String)> {
     debug!("7Factor 조회: {} ({})", ticker, query.market);
 
-    let db_pool = state.db_pool.as_ref().ok_or_else(|| {
+    let db_pool = state.analytics_db_pool().ok_or_else(|| {
         (
             StatusCode::INTERNAL_SERVER_ERROR,
             "Database not available".to_string(),
@@ -306,7 +306,7 @@ pub async fn get_seven_factor_batch(
         request.tickers, request.market
     );
 
-    let db_pool = state.db_pool.as_ref().ok_or_else(|| {
+    let db_pool = state.analytics_db_pool().ok_or_else(|| {
         (
             StatusCode::INTERNAL_SERVER_ERROR,
             "Database not available".to_string(),
@@ -385,7 +385,7 @@ pub async fn get_score_history(
 ) -> Result<Json<ScoreHistoryResponse>, (StatusCode, String)> {
     debug!("Score History 조회: {} ({}일)", ticker, query.days);
 
-    let db_pool = state.db_pool.as_ref().ok_or_else(|| {
+    let db_pool = state.analytics_db_pool().ok_or_else(|| {
         (
             StatusCode::INTERNAL_SERVER_ERROR,
             "Database not available".to_string(),
@@ -156,14 +156,14 @@ async fn get_stats(
 
     // 세 가지 통계를 병렬로 조회
     let daily_result = RealityCheckRepository::get_daily_stats(
-        state.db_pool.as_ref().expect("DB pool not initialized"),
+        state.analytics_db_pool().expect("DB pool not initialized"),
         query.limit,
     );
     let source_result = RealityCheckRepository::get_source_stats(
-        state.db_pool.as_ref().expect("DB pool not initialized"),
+        state.analytics_db_pool().expect("DB pool not initialized"),
     );
     let rank_result = RealityCheckRepository::get_rank_stats(
-        state.db_pool.as_ref().expect("DB pool not initialized"),
+        state.analytics_db_pool().expect("DB pool not initialized"),
     );
 
     let (daily, source, rank) = tokio::join!(daily_result, source_result, rank_result);
@@ -247,7 +247,7 @@ async fn get_results(
     let recommend_source = query.recommend_source.as_deref();
 
     match RealityCheckRepository::get_reality_checks(
-        state.db_pool.as_ref().expect("DB pool not initialized"),
+        state.analytics_db_pool().expect("DB pool not initialized"),
         start_date,
         end_date,
         recommend_source,
@@ -309,7 +309,7 @@ async fn get_snapshots(
         .unwrap_or_else(|| Utc::now().naive_utc().date());
 
     match RealityCheckRepository::get_snapshots(
-        state.db_pool.as_ref().expect("DB pool not initialized"),
+        state.analytics_db_pool().expect("DB pool not initialized"),
         snapshot_date,
     )
     .await
@@ -0,0 +1,1001 @@
+//! 리스크 설정 endpoint.
+//!
+//! 실행 중인 RiskManager의 설정(심볼별 재정의 포함)을 조회하고 변경합니다.
+//! 변경은 검증 후 즉시 적용되며, 2인 승인 모드(`RISK_CONFIG_REQUIRE_APPROVAL=true`)에서는
+//! 요청자가 아닌 다른 사용자가 승인해야 적용됩니다. 모든 변경은 감사 로그에 기록됩니다.
+//!
+//! # 엔드포인트
+//!
+//! - `GET /api/v1/risk/config` - 현재 리스크 설정 및 승인 대기 목록
+//! - `PUT /api/v1/risk/config` - 리스크 설정 전체 교체
+//! - `PUT /api/v1/risk/config/symbols/{symbol}` - 심볼별 재정의 설정
+//! - `DELETE /api/v1/risk/config/symbols/{symbol}` - 심볼별 재정의 제거
+//! - `GET /api/v1/risk/config/pending` - 승인 대기 중인 변경 목록
+//! - `POST /api/v1/risk/config/pending/{id}/approve` - 변경 승인
+//! - `POST /api/v1/risk/config/pending/{id}/reject` - 변경 거절
+//! - `GET /api/v1/risk/config/history` - 변경 이력 (DB 필요)
+//! - `GET /api/v1/risk/profiles` - 기본 리스크 프로필 목록 (현재 설정 대비 변경 항목 포함)
+//! - `GET /api/v1/risk/profiles/{name}` - 리스크 프로필 상세 (현재 설정 대비 변경 항목 포함)
+//! - `PUT /api/v1/risk/profile` - 전역 리스크 프로필 적용/해제
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    routing::{get, post, put},
+    Json, Router,
+};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tracing::{info, warn};
+use trader_execution::{
+    diff_settings, settings_value, RiskProfile, RiskProfileName, SettingChange,
+};
+use trader_risk::{
+    ApprovalError, ConfigValidationError, PendingConfigChange, RiskConfig, SymbolRiskConfig,
+};
+use uuid::Uuid;
+
+use super::common::require_pool;
+use crate::auth::{JwtAuth, OptionalJwtAuth};
+use crate::error::{ApiErrorResponse, ApiResult};
+use crate::repository::{AuditLogRecord, AuditLogRepository};
+use crate::state::AppState;
+
+/// 감사 로그 엔티티 타입.
+const AUDIT_ENTITY: &str = "risk_config";
+
+// ==================== 요청/응답 타입 ====================
+
+/// 리스크 설정 조회 응답.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RiskConfigResponse {
+    /// 현재 적용 중인 설정
+    pub config: RiskConfig,
+    /// 2인 승인 모드 여부
+    pub require_approval: bool,
+    /// 승인 대기 중인 변경
+    pub pending: Vec<PendingConfigChange>,
+}
+
+/// 리스크 설정 변경 요청.
+#[derive(Debug, Deserialize)]
+pub struct UpdateRiskConfigRequest {
+    /// 새 설정
+    pub config: RiskConfig,
+    /// 변경 사유
+    pub reason: Option<String>,
+}
+
+/// 심볼별 재정의 변경 요청.
+#[derive(Debug, Deserialize)]
+pub struct UpdateSymbolRiskRequest {
+    /// 심볼 재정의 설정
+    pub config: SymbolRiskConfig,
+    /// 변경 사유
+    pub reason: Option<String>,
+}
+
+/// 승인/거절/제거 요청.
+#[derive(Debug, Default, Deserialize)]
+pub struct ReviewRequest {
+    /// 변경 사유 또는 검토 의견
+    pub reason: Option<String>,
+}
+
+/// 리스크 설정 변경 응답.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RiskConfigUpdateResponse {
+    /// 즉시 적용 여부 (false면 승인 대기)
+    pub applied: bool,
+    /// 현재 적용 중인 설정
+    pub config: RiskConfig,
+    /// 승인 대기 중인 변경 (승인 모드인 경우)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pending_change: Option<PendingConfigChange>,
+}
+
+/// 변경 이력 조회 쿼리.
+#[derive(Debug, Deserialize)]
+pub struct HistoryQuery {
+    /// 최대 건수 (기본값: 50)
+    #[serde(default = "default_history_limit")]
+    pub limit: i64,
+}
+
+fn default_history_limit() -> i64 {
+    50
+}
+
+/// 변경 이력 응답.
+#[derive(Debug, Serialize)]
+pub struct RiskConfigHistoryResponse {
+    /// 조회된 건수
+    pub total: usize,
+    /// 이력 (최신순)
+    pub history: Vec<AuditLogRecord>,
+}
+
+/// 리스크 프로필 조회 항목.
+#[derive(Debug, Serialize)]
+pub struct RiskProfileView {
+    /// 프로필 (리스크 한도, 사이징, 실행 조절)
+    #[serde(flatten)]
+    pub profile: RiskProfile,
+    /// 현재 설정 대비 변경 항목 (적용 시 바뀌는 값)
+    pub changes: Vec<SettingChange>,
+}
+
+/// 리스크 프로필 목록 응답.
+#[derive(Debug, Serialize)]
+pub struct RiskProfilesResponse {
+    /// 현재 전역 프로필 (없으면 개별 설정 사용 중)
+    pub active: Option<RiskProfileName>,
+    /// 기본 프로필 (보수적 → 공격적 순)
+    pub profiles: Vec<RiskProfileView>,
+}
+
+/// 전역 리스크 프로필 적용 요청.
+#[derive(Debug, Deserialize)]
+pub struct ApplyRiskProfileRequest {
+    /// 프로필 이름 (conservative, standard, aggressive; null이면 프로필 해제)
+    pub profile: Option<String>,
+    /// 변경 사유
+    pub reason: Option<String>,
+}
+
+/// 전역 리스크 프로필 적용 응답.
+#[derive(Debug, Serialize)]
+pub struct RiskProfileApplyResponse {
+    /// 적용한 프로필 (해제 시 null)
+    pub profile: Option<RiskProfileName>,
+    /// 리스크 한도 즉시 적용 여부 (false면 승인 대기)
+    pub applied: bool,
+    /// 현재 적용 중인 리스크 한도
+    pub config: RiskConfig,
+    /// 리스크 한도 승인 대기 변경 (승인 모드인 경우)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pending_change: Option<PendingConfigChange>,
+    /// 적용 전 설정 대비 변경 항목
+    pub changes: Vec<SettingChange>,
+}
+
+// ==================== 헬퍼 ====================
+
+fn validation_error_response(err: ConfigValidationError) -> (StatusCode, Json<ApiErrorResponse>) {
+    (
+        StatusCode::BAD_REQUEST,
+        Json(ApiErrorResponse::new(
+            "INVALID_RISK_CONFIG",
+            err.to_string(),
+        )),
+    )
+}
+
+fn approval_error_response(err: ApprovalError) -> (StatusCode, Json<ApiErrorResponse>) {
+    let (status, code) = match &err {
+        ApprovalError::NotFound(_) => (StatusCode::NOT_FOUND, "PENDING_CHANGE_NOT_FOUND"),
+        ApprovalError::SelfApproval(_) => (StatusCode::FORBIDDEN, "SELF_APPROVAL_NOT_ALLOWED"),
+        ApprovalError::Invalid(_) => (StatusCode::BAD_REQUEST, "INVALID_RISK_CONFIG"),
+    };
+    (status, Json(ApiErrorResponse::new(code, err.to_string())))
+}
+
+/// 요청자 식별 (JWT subject).
+///
+/// 2인 승인 모드에서는 요청자 본인 승인 여부를 인증된 사용자 기준으로 판단하므로
+/// 인증 없는 변경 요청은 401로 거부합니다.
+async fn resolve_actor(state: &AppState, auth: &OptionalJwtAuth) -> ApiResult<String> {
+    match &auth.0 {
+        Some(claims) => Ok(claims.sub.clone()),
+        None if state.risk_config_approvals.read().await.requires_approval() => Err((
+            StatusCode::UNAUTHORIZED,
+            Json(ApiErrorResponse::new(
+                "AUTH_REQUIRED",
+                "Authentication is required when risk config approval is enabled",
+            )),
+        )),
+        None => Ok("api".to_string()),
+    }
+}
+
+/// 현재 적용 중인 설정 조회 (주문 실행기의 RiskManager 기준).
+async fn current_config(state: &AppState) -> RiskConfig {
+    let executor = state.executor.read().await;
+    let risk_manager = executor.risk_manager().read().await;
+    risk_manager.config().clone()
+}
+
+/// 현재 전역 설정 (리스크 한도, 전역 프로필 사이징, 실행 조절) 비교용 값.
+async fn current_settings(state: &AppState) -> serde_json::Value {
+    let risk = current_config(state).await;
+    let executor = state.executor.read().await;
+    let profile = executor.risk_profile().await;
+    let throttles = executor.throttles_for(None).await;
+    settings_value(&risk, profile.as_ref().map(|p| &p.sizing), &throttles)
+}
+
+/// 프로필 적용 시의 리스크 한도 (현재 심볼별 재정의는 유지).
+fn profile_risk_config(profile: &RiskProfile, current: &RiskConfig) -> RiskConfig {
+    RiskConfig {
+        symbol_configs: current.symbol_configs.clone(),
+        ..profile.risk.clone()
+    }
+}
+
+/// 현재 설정 대비 프로필 적용 시 변경 항목.
+async fn profile_view(state: &AppState, profile: RiskProfile) -> RiskProfileView {
+    let current = current_config(state).await;
+    let proposed = settings_value(
+        &profile_risk_config(&profile, &current),
+        Some(&profile.sizing),
+        &profile.throttles,
+    );
+    let changes = diff_settings(&current_settings(state).await, &proposed);
+    RiskProfileView { profile, changes }
+}
+
+#[allow(clippy::result_large_err)]
+fn parse_profile_name(name: &str) -> Result<RiskProfileName, (StatusCode, Json<ApiErrorResponse>)> {
+    name.parse().map_err(|e: String| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(ApiErrorResponse::new("INVALID_RISK_PROFILE", e)),
+        )
+    })
+}
+
+/// 설정을 주문 실행기 및 공유 RiskManager에 즉시 적용.
+///
+/// # Returns
+/// 적용 이전 설정
+async fn apply_config(
+    state: &AppState,
+    config: RiskConfig,
+) -> Result<RiskConfig, ConfigValidationError> {
+    let previous = {
+        let executor = state.executor.read().await;
+        let mut risk_manager = executor.risk_manager().write().await;
+        let previous = risk_manager.config().clone();
+        risk_manager.update_config(config.clone())?;
+        previous
+    };
+
+    // 검증은 위에서 끝났으므로 공유 RiskManager는 실패하지 않음
+    state.risk_manager.write().await.update_config(config)?;
+
+    Ok(previous)
+}
+
+/// 감사 로그 기록 (DB 미연결 또는 실패 시 경고만 남김).
+async fn record_audit(
+    state: &AppState,
+    event_type: &str,
+    change_id: Option<Uuid>,
+    actor: &str,
+    details: serde_json::Value,
+) {
+    let Some(pool) = state.db_pool.as_ref() else {
+        return;
+    };
+    if let Err(e) = AuditLogRepository::record(
+        pool,
+        event_type,
+        AUDIT_ENTITY,
+        change_id,
+        Some(actor),
+        &details,
+    )
+    .await
+    {
+        warn!(event_type, "리스크 설정 감사 로그 기록 실패: {}", e);
+    }
+}
+
+/// 제안된 설정을 적용하거나 승인 대기열에 등록.
+async fn submit_config(
+    state: &AppState,
+    proposed: RiskConfig,
+    actor: String,
+    reason: Option<String>,
+) -> ApiResult<(StatusCode, Json<RiskConfigUpdateResponse>)> {
+    let require_approval = state.risk_config_approvals.read().await.requires_approval();
+
+    if require_approval {
+        let change = state
+            .risk_config_approvals
+            .write()
+            .await
+            .submit(proposed, actor.clone(), reason)
+            .map_err(validation_error_response)?;
+
+        record_audit(
+            state,
+            "risk_config_change_requested",
+            Some(change.id),
+            &actor,
+            serde_json::json!({
+                "proposed": change.proposed,
+                "reason": change.reason,
+            }),
+        )
+        .await;
+
+        info!(change_id = %change.id, requested_by = %actor, "리스크 설정 변경 승인 요청");
+
+        return Ok((
+            StatusCode::ACCEPTED,
+            Json(RiskConfigUpdateResponse {
+                applied: false,
+                config: current_config(state).await,
+                pending_change: Some(change),
+            }),
+        ));
+    }
+
+    let previous = apply_config(state, proposed.clone())
+        .await
+        .map_err(validation_error_response)?;
+
+    record_audit(
+        state,
+        "risk_config_updated",
+        None,
+        &actor,
+        serde_json::json!({
+            "before": previous,
+            "after": proposed,
+            "reason": reason,
+        }),
+    )
+    .await;
+
+    info!(updated_by = %actor, "리스크 설정 변경 적용");
+
+    Ok((
+        StatusCode::OK,
+        Json(RiskConfigUpdateResponse {
+            applied: true,
+            config: proposed,
+            pending_change: None,
+        }),
+    ))
+}
+
+// ==================== 핸들러 ====================
+
+/// 현재 리스크 설정 조회.
+///
+/// GET /api/v1/risk/config
+pub async fn get_risk_config(State(state): State<Arc<AppState>>) -> Json<RiskConfigResponse> {
+    let approvals = state.risk_config_approvals.read().await;
+
+    Json(RiskConfigResponse {
+        config: current_config(&state).await,
+        require_approval: approvals.requires_approval(),
+        pending: approvals.pending().to_vec(),
+    })
+}
+
+/// 리스크 설정 전체 교체.
+///
+/// 승인 모드에서는 202 Accepted와 함께 승인 대기 변경을 반환합니다.
+///
+/// PUT /api/v1/risk/config
+pub async fn update_risk_config(
+    State(state): State<Arc<AppState>>,
+    auth: OptionalJwtAuth,
+    Json(request): Json<UpdateRiskConfigRequest>,
+) -> ApiResult<(StatusCode, Json<RiskConfigUpdateResponse>)> {
+    let actor = resolve_actor(&state, &auth).await?;
+    submit_config(&state, request.config, actor, request.reason).await
+}
+
+/// 심볼별 재정의 설정.
+///
+/// PUT /api/v1/risk/config/symbols/{symbol}
+pub async fn update_symbol_risk_config(
+    State(state): State<Arc<AppState>>,
+    auth: OptionalJwtAuth,
+    Path(symbol): Path<String>,
+    Json(request): Json<UpdateSymbolRiskRequest>,
+) -> ApiResult<(StatusCode, Json<RiskConfigUpdateResponse>)> {
+    let actor = resolve_actor(&state, &auth).await?;
+
+    let mut proposed = current_config(&state).await;
+    proposed.set_symbol_config(symbol, request.config);
+
+    submit_config(&state, proposed, actor, request.reason).await
+}
+
+/// 심볼별 재정의 제거.
+///
+/// DELETE /api/v1/risk/config/symbols/{symbol}
+pub async fn delete_symbol_risk_config(
+    State(state): State<Arc<AppState>>,
+    auth: OptionalJwtAuth,
+    Path(symbol): Path<String>,
+    request: Option<Json<ReviewRequest>>,
+) -> ApiResult<(StatusCode, Json<RiskConfigUpdateResponse>)> {
+    let Json(request) = request.unwrap_or_default();
+    let actor = resolve_actor(&state, &auth).await?;
+
+    let mut proposed = current_config(&state).await;
+    if proposed.symbol_configs.remove(&symbol).is_none() {
+        return Err((
+            StatusCode::NOT_FOUND,
+            Json(ApiErrorResponse::new(
+                "SYMBOL_CONFIG_NOT_FOUND",
+                format!("No risk override for symbol: {}", symbol),
+            )),
+        ));
+    }
+
+    submit_config(&state, proposed, actor, request.reason).await
+}
+
+/// 승인 대기 중인 변경 목록.
+///
+/// GET /api/v1/risk/config/pending
+pub async fn list_pending_changes(
+    State(state): State<Arc<AppState>>,
+) -> Json<Vec<PendingConfigChange>> {
+    Json(state.risk_config_approvals.read().await.pending().to_vec())
+}
+
+/// 변경 승인 (JWT 인증 필수, 요청자 본인은 승인 불가).
+///
+/// POST /api/v1/risk/config/pending/{id}/approve
+pub async fn approve_pending_change(
+    State(state): State<Arc<AppState>>,
+    JwtAuth(claims): JwtAuth,
+    Path(id): Path<Uuid>,
+    request: Option<Json<ReviewRequest>>,
+) -> ApiResult<Json<RiskConfigUpdateResponse>> {
+    let Json(request) = request.unwrap_or_default();
+    let approver = claims.sub;
+
+    let change = state
+        .risk_config_approvals
+        .write()
+        .await
+        .approve(id, &approver)
+        .map_err(approval_error_response)?;
+
+    let previous = apply_config(&state, change.proposed.clone())
+        .await
+        .map_err(validation_error_response)?;
+
+    record_audit(
+        &state,
+        "risk_config_change_approved",
+        Some(change.id),
+        &approver,
+        serde_json::json!({
+            "before": previous,
+            "after": change.proposed,
+            "requested_by": change.requested_by,
+            "reason": change.reason,
+            "comment": request.reason,
+        }),
+    )
+    .await;
+
+    info!(
+        change_id = %change.id,
+        requested_by = %change.requested_by,
+        approved_by = %approver,
+        "리스크 설정 변경 승인 및 적용"
+    );
+
+    Ok(Json(RiskConfigUpdateResponse {
+        applied: true,
+        config: change.proposed,
+        pending_change: None,
+    }))
+}
+
+/// 변경 거절 (JWT 인증 필수, 요청자 본인의 철회 포함).
+///
+/// POST /api/v1/risk/config/pending/{id}/reject
+pub async fn reject_pending_change(
+    State(state): State<Arc<AppState>>,
+    JwtAuth(claims): JwtAuth,
+    Path(id): Path<Uuid>,
+    request: Option<Json<ReviewRequest>>,
+) -> ApiResult<Json<PendingConfigChange>> {
+    let Json(request) = request.unwrap_or_default();
+    let reviewer = claims.sub;
+
+    let change = state
+        .risk_config_approvals
+        .write()
+        .await
+        .reject(id)
+        .map_err(approval_error_response)?;
+
+    record_audit(
+        &state,
+        "risk_config_change_rejected",
+        Some(change.id),
+        &reviewer,
+        serde_json::json!({
+            "proposed": change.proposed,
+            "requested_by": change.requested_by,
+            "comment": request.reason,
+        }),
+    )
+    .await;
+
+    info!(change_id = %change.id, rejected_by = %reviewer, "리스크 설정 변경 거절");
+
+    Ok(Json(change))
+}
+
+/// 리스크 설정 변경 이력 조회.
+///
+/// GET /api/v1/risk/config/history
+pub async fn get_risk_config_history(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<HistoryQuery>,
+) -> ApiResult<Json<RiskConfigHistoryResponse>> {
+    let pool = require_pool(&state)?;
+
+    let history =
+        AuditLogRepository::list_by_entity_type(pool, AUDIT_ENTITY, query.limit.clamp(1, 500))
+            .await
+            .map_err(|e| {
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(ApiErrorResponse::new("DB_ERROR", e.to_string())),
+                )
+            })?;
+
+    Ok(Json(RiskConfigHistoryResponse {
+        total: history.len(),
+        history,
+    }))
+}
+
+/// 기본 리스크 프로필 목록.
+///
+/// 각 프로필에는 현재 설정 대비 적용 시 바뀌는 항목이 포함됩니다.
+///
+/// GET /api/v1/risk/profiles
+pub async fn list_risk_profiles(State(state): State<Arc<AppState>>) -> Json<RiskProfilesResponse> {
+    let active = state
+        .executor
+        .read()
+        .await
+        .risk_profile()
+        .await
+        .map(|p| p.name);
+
+    let mut profiles = Vec::new();
+    for profile in RiskProfile::presets() {
+        profiles.push(profile_view(&state, profile).await);
+    }
+
+    Json(RiskProfilesResponse { active, profiles })
+}
+
+/// 리스크 프로필 상세.
+///
+/// GET /api/v1/risk/profiles/{name}
+pub async fn get_risk_profile(
+    State(state): State<Arc<AppState>>,
+    Path(name): Path<String>,
+) -> ApiResult<Json<RiskProfileView>> {
+    let name = name.parse::<RiskProfileName>().map_err(|e| {
+        (
+            StatusCode::NOT_FOUND,
+            Json(ApiErrorResponse::new("RISK_PROFILE_NOT_FOUND", e)),
+        )
+    })?;
+
+    Ok(Json(profile_view(&state, RiskProfile::preset(name)).await))
+}
+
+/// 전역 리스크 프로필 적용/해제.
+///
+/// 리스크 한도는 `PUT /config`와 같은 흐름(검증, 2인 승인, 감사 로그)을 따르고,
+/// 포지션 사이징과 실행 조절은 즉시 적용됩니다. 심볼별 재정의는 유지합니다.
+/// 프로필을 해제하면 리스크 한도는 그대로 두고 사이징/실행 조절만 기본값으로 돌아갑니다.
+/// 전략별 프로필(`PUT /api/v1/strategies/{id}/risk`)이 있으면 그 전략에는 전략 프로필이 우선합니다.
+///
+/// PUT /api/v1/risk/profile
+pub async fn apply_risk_profile(
+    State(state): State<Arc<AppState>>,
+    auth: OptionalJwtAuth,
+    Json(request): Json<ApplyRiskProfileRequest>,
+) -> ApiResult<(StatusCode, Json<RiskProfileApplyResponse>)> {
+    let actor = resolve_actor(&state, &auth).await?;
+    let name = request
+        .profile
+        .as_deref()
+        .map(parse_profile_name)
+        .transpose()?;
+    let before = current_settings(&state).await;
+
+    let Some(name) = name else {
+        state.executor.read().await.set_risk_profile(None).await;
+        let changes = diff_settings(&before, &current_settings(&state).await);
+
+        record_audit(
+            &state,
+            "risk_profile_cleared",
+            None,
+            &actor,
+            serde_json::json!({ "changes": changes, "reason": request.reason }),
+        )
+        .await;
+        info!(updated_by = %actor, "전역 리스크 프로필 해제");
+
+        return Ok((
+            StatusCode::OK,
+            Json(RiskProfileApplyResponse {
+                profile: None,
+                applied: true,
+                config: current_config(&state).await,
+                pending_change: None,
+                changes,
+            }),
+        ));
+    };
+
+    let profile = RiskProfile::preset(name);
+    let proposed = profile_risk_config(&profile, &current_config(&state).await);
+    let changes = diff_settings(
+        &before,
+        &settings_value(&proposed, Some(&profile.sizing), &profile.throttles),
+    );
+
+    let (status, Json(update)) =
+        submit_config(&state, proposed, actor.clone(), request.reason.clone()).await?;
+    state
+        .executor
+        .read()
+        .await
+        .set_risk_profile(Some(profile))
+        .await;
+
+    record_audit(
+        &state,
+        "risk_profile_applied",
+        update.pending_change.as_ref().map(|c| c.id),
+        &actor,
+        serde_json::json!({
+            "profile": name,
+            "changes": changes,
+            "reason": request.reason,
+        }),
+    )
+    .await;
+    info!(profile = %name, updated_by = %actor, "전역 리스크 프로필 적용");
+
+    Ok((
+        status,
+        Json(RiskProfileApplyResponse {
+            profile: Some(name),
+            applied: update.applied,
+            config: update.config,
+            pending_change: update.pending_change,
+            changes,
+        }),
+    ))
+}
+
+// ==================== 라우터 ====================
+
+/// 리스크 설정 라우터 생성.
+pub fn risk_router() -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/config", get(get_risk_config).put(update_risk_config))
+        .route(
+            "/config/symbols/{symbol}",
+            put(update_symbol_risk_config).delete(delete_symbol_risk_config),
+        )
+        .route("/config/pending", get(list_pending_changes))
+        .route("/config/pending/{id}/approve", post(approve_pending_change))
+        .route("/config/pending/{id}/reject", post(reject_pending_change))
+        .route("/config/history", get(get_risk_config_history))
+        .route("/profiles", get(list_risk_profiles))
+        .route("/profiles/{name}", get(get_risk_profile))
+        .route("/profile", put(apply_risk_profile))
+}
+
+// ==================== 테스트 ====================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::{create_token, Claims, Role};
+    use crate::state::create_test_state;
+    use axum::body::Body;
+    use axum::http::Request;
+    use tower::ServiceExt;
+    use trader_risk::ConfigApprovalQueue;
+
+    fn json_request(method: &str, uri: &str, body: serde_json::Value) -> Request<Body> {
+        Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("content-type", "application/json")
+            .body(Body::from(body.to_string()))
+            .unwrap()
+    }
+
+    fn authed_request(
+        method: &str,
+        uri: &str,
+        user_id: &str,
+        body: serde_json::Value,
+    ) -> Request<Body> {
+        let secret = std::env::var("JWT_SECRET")
+            .unwrap_or_else(|_| "development-secret-key-change-in-production".to_string());
+        let token =
+            create_token(&Claims::new(user_id, user_id, Role::Trader, 60), &secret).unwrap();
+        let mut request = json_request(method, uri, body);
+        request.headers_mut().insert(
+            "authorization",
+            format!("Bearer {}", token).parse().unwrap(),
+        );
+        request
+    }
+
+    async fn read_json(response: axum::response::Response) -> serde_json::Value {
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        serde_json::from_slice(&body).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_update_applies_immediately_and_validates() {
+        let state = Arc::new(create_test_state());
+        let app = risk_router().with_state(state.clone());
+
+        let config = RiskConfig {
+            max_position_pct: 5.0,
+            ..Default::default()
+        };
+        let response = app
+            .clone()
+            .oneshot(json_request(
+                "PUT",
+                "/config",
+                serde_json::json!({ "config": config, "reason": "포지션 축소" }),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(current_config(&state).await.max_position_pct, 5.0);
+        assert_eq!(
+            state.risk_manager.read().await.config().max_position_pct,
+            5.0
+        );
+
+        // 심볼 재정의 검증 실패 시 기존 설정 유지
+        let response = app
+            .clone()
+            .oneshot(json_request(
+                "PUT",
+                "/config/symbols/005930",
+                serde_json::json!({ "config": { "stop_loss_pct": 90.0 } }),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        assert!(current_config(&state).await.symbol_configs.is_empty());
+
+        let response = app
+            .oneshot(json_request(
+                "PUT",
+                "/config/symbols/005930",
+                serde_json::json!({ "config": { "stop_loss_pct": 3.0 } }),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            current_config(&state).await.get_stop_loss_pct("005930"),
+            3.0
+        );
+    }
+
+    #[tokio::test]
+    async fn test_two_person_approval_flow() {
+        let mut state = create_test_state();
+        state.risk_config_approvals =
+            Arc::new(tokio::sync::RwLock::new(ConfigApprovalQueue::new(true)));
+        let state = Arc::new(state);
+        let app = risk_router().with_state(state.clone());
+
+        let config = RiskConfig {
+            max_daily_loss_pct: 1.0,
+            ..Default::default()
+        };
+        // 승인 모드에서는 미인증 변경 요청 거부
+        let response = app
+            .clone()
+            .oneshot(json_request(
+                "PUT",
+                "/config",
+                serde_json::json!({ "config": config, "actor": "alice" }),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+        let response = app
+            .clone()
+            .oneshot(authed_request(
+                "PUT",
+                "/config",
+                "alice",
+                serde_json::json!({ "config": config }),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::ACCEPTED);
+        let body = read_json(response).await;
+        assert_eq!(body["applied"], false);
+        assert_eq!(body["pending_change"]["requested_by"], "alice");
+        let change_id = body["pending_change"]["id"].as_str().unwrap().to_string();
+        let approve_uri = format!("/config/pending/{}/approve", change_id);
+
+        // 승인 전에는 적용되지 않음
+        assert_eq!(current_config(&state).await.max_daily_loss_pct, 3.0);
+
+        // 미인증 승인 불가 (본문의 actor는 무시)
+        let response = app
+            .clone()
+            .oneshot(json_request(
+                "POST",
+                &approve_uri,
+                serde_json::json!({ "actor": "bob" }),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+        // 요청자 본인 승인 불가 (본문의 actor로 우회 불가)
+        let response = app
+            .clone()
+            .oneshot(authed_request(
+                "POST",
+                &approve_uri,
+                "alice",
+                serde_json::json!({ "actor": "bob" }),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+        let response = app
+            .oneshot(authed_request(
+                "POST",
+                &approve_uri,
+                "bob",
+                serde_json::json!({}),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(current_config(&state).await.max_daily_loss_pct, 1.0);
+        assert!(state
+            .risk_config_approvals
+            .read()
+            .await
+            .pending()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_risk_profiles_diff_and_apply() {
+        let state = Arc::new(create_test_state());
+        let app = risk_router().with_state(state.clone());
+
+        // 기본 설정 상태에서 표준 프로필은 사이징만 추가
+        let response = app
+            .clone()
+            .oneshot(Request::get("/profiles").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = read_json(response).await;
+        assert!(body["active"].is_null());
+        assert_eq!(body["profiles"].as_array().unwrap().len(), 3);
+        let standard = &body["profiles"][1];
+        assert_eq!(standard["name"], "standard");
+        assert_eq!(standard["changes"].as_array().unwrap().len(), 1);
+        assert_eq!(standard["changes"][0]["field"], "sizing");
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::get("/profiles/unknown")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        // 심볼별 재정의는 프로필 적용 후에도 유지
+        let response = app
+            .clone()
+            .oneshot(json_request(
+                "PUT",
+                "/config/symbols/005930",
+                serde_json::json!({ "config": { "stop_loss_pct": 3.0 } }),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let response = app
+            .clone()
+            .oneshot(json_request(
+                "PUT",
+                "/profile",
+                serde_json::json!({ "profile": "conservative", "reason": "초보 사용자" }),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = read_json(response).await;
+        assert_eq!(body["applied"], true);
+        assert!(body["changes"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|c| c["field"] == "risk.max_position_pct" && c["to"] == 5.0));
+
+        let config = current_config(&state).await;
+        assert_eq!(config.max_position_pct, 5.0);
+        assert_eq!(config.get_stop_loss_pct("005930"), 3.0);
+        let executor = state.executor.read().await;
+        assert_eq!(
+            executor.risk_profile().await.map(|p| p.name),
+            Some(RiskProfileName::Conservative)
+        );
+        drop(executor);
+
+        // 적용한 프로필은 변경 항목 없음
+        let response = app
+            .clone()
+            .oneshot(
+                Request::get("/profiles/conservative")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let body = read_json(response).await;
+        assert!(body["changes"].as_array().unwrap().is_empty());
+
+        let response = app
+            .clone()
+            .oneshot(json_request(
+                "PUT",
+                "/profile",
+                serde_json::json!({ "profile": "reckless" }),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        // 해제 시 리스크 한도는 유지, 사이징/실행 조절만 기본값
+        let response = app
+            .oneshot(json_request(
+                "PUT",
+                "/profile",
+                serde_json::json!({ "profile": null }),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(state.executor.read().await.risk_profile().await.is_none());
+        assert_eq!(current_config(&state).await.max_position_pct, 5.0);
+    }
+}
@@ -173,7 +173,5 @@ pub fn schema_router() -> axum::Router<Arc<AppState>> {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
-
     // 테스트는 통합 테스트에서 수행
 }
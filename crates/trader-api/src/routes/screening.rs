@@ -8,6 +8,10 @@
 //! - `GET /api/v1/screening/presets` - 사용 가능한 프리셋 목록
 //! - `GET /api/v1/screening/presets/{preset}` - 프리셋 스크리닝 실행
 //! - `GET /api/v1/screening/momentum` - 모멘텀 기반 스크리닝
+//! - `GET /api/v1/screening/investor-flow` - 투자자별 순매수(수급) 스크리닝
+//! - `GET /api/v1/screening/investor-flow/{ticker}` - 종목별 투자자 순매수 추이
+//! - `GET /api/v1/screening/survival` - 상위권 생존일/연속 진입 분포/교체율
+//! - `GET /api/v1/screening/{ticker}/factors/history` - 종목별 7Factor 점수 추이
 
 use axum::{
     extract::{Path, Query, State},
@@ -16,21 +20,41 @@ use axum::{
     routing::{get, post},
     Json, Router,
 };
+use chrono::{Duration, NaiveDate, Utc};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
 use std::sync::Arc;
 use tracing::{debug, info, warn};
 use ts_rs::TS;
 use utoipa::ToSchema;
 
-use trader_core::MacroEnvironment;
+use trader_analytics::{DailyRanking, SurvivalTracker};
+use trader_core::{InvestorFlow, InvestorFlowSummary, MacroEnvironment};
 use trader_data::cache::{MacroDataProvider, MacroDataProviderTrait};
+use trader_data::InvestorFlowStore;
 
 use crate::repository::{
-    MomentumScreenResult, ScreeningFilter, ScreeningPreset, ScreeningRepository, ScreeningResult,
+    FactorHistoryPoint, InvestorFlowScreenResult, MomentumScreenResult, ScoreHistoryRepository,
+    ScreeningFilter, ScreeningPreset, ScreeningRepository, ScreeningResult,
 };
 use crate::state::AppState;
 
+/// 생존일 계산 시 상위권 기준 (global_score 상위 N개)
+const DEFAULT_SURVIVAL_TOP_N: i32 = 50;
+
+/// 생존일 기본 추적 기간 (거래일)
+const DEFAULT_SURVIVAL_LOOKBACK: u32 = 15;
+
+/// 생존일 최대 추적 기간 (거래일)
+const MAX_SURVIVAL_LOOKBACK: u32 = 120;
+
+/// 7Factor 추이 기본 조회 일수
+const DEFAULT_FACTOR_HISTORY_DAYS: i32 = 30;
+
+/// 7Factor 추이 최대 조회 일수
+const MAX_FACTOR_HISTORY_DAYS: i32 = 365;
+
 // ==================== Request/Response 타입 ====================
 
 /// 커스텀 스크리닝 요청
@@ -119,6 +143,20 @@ pub struct ScreeningRequest {
     #[serde(default)]
     pub min_ttm_squeeze_cnt: Option<String>,
 
+    // 실현 변동성 (60일, 연율화 %) / 베타 필터
+    #[serde(default)]
+    pub min_realized_vol: Option<String>,
+    #[serde(default)]
+    pub max_realized_vol: Option<String>,
+    #[serde(default)]
+    pub min_beta: Option<String>,
+    #[serde(default)]
+    pub max_beta: Option<String>,
+
+    // 생존일 필터 (일별 상위권 연속 진입 일수)
+    #[serde(default)]
+    pub min_survival_days: Option<u32>,
+
     // 정렬 및 페이지네이션
     #[serde(default)]
     pub sort_by: Option<String>,
@@ -208,6 +246,12 @@ pub struct ScreeningResultDto {
     pub ttm_squeeze: Option<bool>,
     pub ttm_squeeze_cnt: Option<i32>,
 
+    // 실현 변동성 (연율화 %) / 시장 베타
+    pub realized_vol_20d: Option<f64>,
+    pub realized_vol_60d: Option<f64>,
+    pub realized_vol_252d: Option<f64>,
+    pub beta: Option<f64>,
+
     // TRIGGER (진입 트리거)
     pub trigger_score: Option<f64>,
     pub trigger_label: Option<String>,
@@ -263,6 +307,9 @@ pub struct PresetQuery {
     /// 결과 제한
     #[serde(default)]
     pub limit: Option<i32>,
+    /// 최소 생존일 (일별 상위권 연속 진입 일수)
+    #[serde(default)]
+    pub min_survival_days: Option<u32>,
 }
 
 /// 모멘텀 스크리닝 쿼리
@@ -324,6 +371,159 @@ pub struct MomentumResultDto {
     pub volume_ratio: String,
 }
 
+/// 투자자별 순매수 스크리닝 쿼리
+#[derive(Debug, Clone, Deserialize, TS)]
+#[ts(export, export_to = "screening/")]
+pub struct InvestorFlowQuery {
+    /// 집계 거래일 수
+    #[serde(default = "default_flow_days")]
+    pub days: i32,
+    /// 정렬 기준 (smart_money, foreign, institution, individual)
+    #[serde(default)]
+    pub sort_by: Option<String>,
+    /// 최소 순매수 일수 (외국인/기관 중 큰 값 기준)
+    #[serde(default)]
+    pub min_buy_days: Option<i32>,
+    /// 결과 제한
+    #[serde(default = "default_momentum_limit")]
+    pub limit: i32,
+}
+
+fn default_flow_days() -> i32 {
+    5
+}
+
+/// 투자자별 순매수 스크리닝 응답
+#[derive(Debug, Clone, Serialize, ToSchema, TS)]
+#[ts(export, export_to = "screening/")]
+pub struct InvestorFlowResponse {
+    pub total: usize,
+    pub days: i32,
+    pub sort_by: String,
+    pub results: Vec<InvestorFlowResultDto>,
+}
+
+/// 투자자별 순매수 결과 DTO (금액 단위: 원)
+#[derive(Debug, Clone, Serialize, ToSchema, TS)]
+#[ts(export, export_to = "screening/")]
+pub struct InvestorFlowResultDto {
+    pub ticker: String,
+    pub name: String,
+    pub exchange: Option<String>,
+    pub days: i32,
+    pub foreign_net: String,
+    pub institution_net: String,
+    pub individual_net: String,
+    pub smart_money_net: String,
+    pub foreign_buy_days: i32,
+    pub institution_buy_days: i32,
+    pub last_trade_date: String,
+}
+
+/// 종목별 투자자 순매수 추이 응답
+#[derive(Debug, Clone, Serialize, ToSchema, TS)]
+#[ts(export, export_to = "screening/")]
+pub struct InvestorFlowHistoryResponse {
+    pub ticker: String,
+    /// 기간 합계 (외국인/기관/개인 순매수, 연속 순매수 일수)
+    #[ts(type = "Record<string, unknown>")]
+    #[schema(value_type = Object)]
+    pub summary: InvestorFlowSummary,
+    /// 일별 순매수 (오래된 순)
+    #[ts(type = "Array<Record<string, unknown>>")]
+    #[schema(value_type = Vec<Object>)]
+    pub flows: Vec<InvestorFlow>,
+}
+
+/// 생존일 분석 쿼리
+#[derive(Debug, Clone, Deserialize, TS)]
+#[ts(export, export_to = "screening/")]
+pub struct SurvivalQuery {
+    /// 일별 상위권 기준 (global_score 상위 N개)
+    #[serde(default = "default_survival_top_n")]
+    pub top_n: i32,
+    /// 추적 기간 (거래일)
+    #[serde(default = "default_survival_lookback")]
+    pub lookback_days: u32,
+    /// 최소 생존일
+    #[serde(default)]
+    pub min_survival_days: Option<u32>,
+}
+
+fn default_survival_top_n() -> i32 {
+    DEFAULT_SURVIVAL_TOP_N
+}
+
+fn default_survival_lookback() -> u32 {
+    DEFAULT_SURVIVAL_LOOKBACK
+}
+
+/// 생존일 분석 응답
+#[derive(Debug, Clone, Serialize, ToSchema, TS)]
+#[ts(export, export_to = "screening/")]
+pub struct SurvivalResponse {
+    pub top_n: i32,
+    pub lookback_days: u32,
+    /// 분석에 사용된 거래일 수
+    pub trading_days: usize,
+    /// 마지막 랭킹 날짜
+    #[ts(type = "string | null")]
+    pub as_of: Option<NaiveDate>,
+    /// 상위권 목록 일평균 교체율 (0.0 ~ 1.0)
+    pub churn_rate: Option<f64>,
+    /// 연속 진입 구간 길이 분포
+    pub streak_distribution: Vec<StreakBucketDto>,
+    pub total: usize,
+    /// 마지막 날 상위권 종목별 생존일 (생존일 내림차순)
+    pub results: Vec<SurvivalResultDto>,
+}
+
+/// 연속 진입 구간 길이별 개수
+#[derive(Debug, Clone, Serialize, ToSchema, TS)]
+#[ts(export, export_to = "screening/")]
+pub struct StreakBucketDto {
+    pub streak_days: u32,
+    pub count: u32,
+}
+
+/// 종목별 생존일 DTO
+#[derive(Debug, Clone, Serialize, ToSchema, TS)]
+#[ts(export, export_to = "screening/")]
+pub struct SurvivalResultDto {
+    pub ticker: String,
+    /// 연속 생존일
+    pub survival_days: u32,
+    /// 추적 기간 내 출현 횟수
+    pub appearance_count: u32,
+    #[ts(type = "string | null")]
+    pub first_entry_date: Option<NaiveDate>,
+    #[ts(type = "string | null")]
+    pub last_seen_date: Option<NaiveDate>,
+}
+
+/// 7Factor 추이 쿼리
+#[derive(Debug, Clone, Deserialize, TS)]
+#[ts(export, export_to = "screening/")]
+pub struct FactorHistoryQuery {
+    /// 조회 일수 (기록된 날짜 기준)
+    #[serde(default = "default_factor_history_days")]
+    pub days: i32,
+}
+
+fn default_factor_history_days() -> i32 {
+    DEFAULT_FACTOR_HISTORY_DAYS
+}
+
+/// 종목별 7Factor 추이 응답
+#[derive(Debug, Clone, Serialize, ToSchema, TS)]
+#[ts(export, export_to = "screening/")]
+pub struct FactorHistoryResponse {
+    pub ticker: String,
+    pub total: usize,
+    /// 일별 7Factor 점수와 전일 대비 변화 (최신 순)
+    pub history: Vec<FactorHistoryPoint>,
+}
+
 /// 에러 응답
 #[derive(Debug, Clone, Serialize, ToSchema, TS)]
 #[ts(export, export_to = "common/")]
@@ -379,6 +579,10 @@ fn to_screening_filter(req: &ScreeningRequest) -> ScreeningFilter {
             .min_ttm_squeeze_cnt
             .as_ref()
             .and_then(|v| v.parse::<i32>().ok()),
+        min_realized_vol: parse_f64(&req.min_realized_vol),
+        max_realized_vol: parse_f64(&req.max_realized_vol),
+        min_beta: parse_f64(&req.min_beta),
+        max_beta: parse_f64(&req.max_beta),
         sort_by: req.sort_by.clone(),
         sort_order: req.sort_order.clone(),
         limit: req.limit,
@@ -427,6 +631,10 @@ fn to_result_dto(r: ScreeningResult) -> ScreeningResultDto {
         sector_rank: r.sector_rank,
         ttm_squeeze: r.ttm_squeeze,
         ttm_squeeze_cnt: r.ttm_squeeze_cnt,
+        realized_vol_20d: r.realized_vol_20d,
+        realized_vol_60d: r.realized_vol_60d,
+        realized_vol_252d: r.realized_vol_252d,
+        beta: r.beta,
         trigger_score: r.trigger_score,
         trigger_label: r.trigger_label,
         overall_score: decimal_to_string(r.overall_score),
@@ -450,6 +658,22 @@ fn to_momentum_dto(r: MomentumScreenResult) -> MomentumResultDto {
     }
 }
 
+fn to_investor_flow_dto(r: InvestorFlowScreenResult) -> InvestorFlowResultDto {
+    InvestorFlowResultDto {
+        ticker: r.ticker,
+        name: r.name,
+        exchange: r.exchange,
+        days: r.days,
+        foreign_net: r.foreign_net.to_string(),
+        institution_net: r.institution_net.to_string(),
+        individual_net: r.individual_net.to_string(),
+        smart_money_net: r.smart_money_net.to_string(),
+        foreign_buy_days: r.foreign_buy_days,
+        institution_buy_days: r.institution_buy_days,
+        last_trade_date: r.last_trade_date.to_string(),
+    }
+}
+
 /// 섹터 순위 쿼리
 #[derive(Debug, Clone, Deserialize)]
 pub struct SectorRankingQuery {
@@ -544,7 +768,7 @@ pub async fn run_screening(
 ) -> impl IntoResponse {
     debug!("커스텀 스크리닝 요청: {:?}", request);
 
-    let db_pool = match &state.db_pool {
+    let db_pool = match state.analytics_db_pool() {
         Some(pool) => pool,
         None => {
             return error_response("DATABASE_ERROR", "Database not available").into_response();
@@ -577,6 +801,15 @@ pub async fn run_screening(
         }
     };
 
+    let results = match filter_by_survival(db_pool, results, request.min_survival_days).await {
+        Ok(r) => r,
+        Err(e) => {
+            warn!("생존일 필터 실패: {}", e);
+            return error_response("SCREENING_ERROR", &format!("생존일 필터 실패: {}", e))
+                .into_response();
+        }
+    };
+
     // 매크로 환경 평가 (실패 시 기본값 사용)
     let macro_risk_str = match fetch_and_evaluate_macro_env(3).await {
         Ok(env) => {
@@ -757,7 +990,7 @@ pub async fn run_preset_screening(
         preset, query.market
     );
 
-    let db_pool = match &state.db_pool {
+    let db_pool = match state.analytics_db_pool() {
         Some(pool) => pool,
         None => {
             return error_response("DATABASE_ERROR", "Database not available").into_response();
@@ -786,11 +1019,23 @@ pub async fn run_preset_screening(
             }
         };
 
-    let filter_summary = format!(
+    let results = match filter_by_survival(db_pool, results, query.min_survival_days).await {
+        Ok(r) => r,
+        Err(e) => {
+            warn!("생존일 필터 실패: {}", e);
+            return error_response("SCREENING_ERROR", &format!("생존일 필터 실패: {}", e))
+                .into_response();
+        }
+    };
+
+    let mut filter_summary = format!(
         "프리셋: {}, 시장: {}",
         preset,
         query.market.as_deref().unwrap_or("전체")
     );
+    if let Some(days) = query.min_survival_days {
+        filter_summary.push_str(&format!(", 생존일≥{}", days));
+    }
     let total = results.len();
     let dto_results: Vec<ScreeningResultDto> = results.into_iter().map(to_result_dto).collect();
 
@@ -832,7 +1077,7 @@ pub async fn run_momentum_screening(
         query.days, query.min_change_pct, query.market
     );
 
-    let db_pool = match &state.db_pool {
+    let db_pool = match state.analytics_db_pool() {
         Some(pool) => pool,
         None => {
             return error_response("DATABASE_ERROR", "Database not available").into_response();
@@ -888,6 +1133,292 @@ pub async fn run_momentum_screening(
     .into_response()
 }
 
+/// 투자자별 순매수 스크리닝 실행
+///
+/// GET /api/v1/screening/investor-flow
+#[utoipa::path(
+    get,
+    path = "/api/v1/screening/investor-flow",
+    params(
+        ("days" = Option<i32>, Query, description = "집계 거래일 수 (기본: 5)"),
+        ("sort_by" = Option<String>, Query, description = "정렬 기준 (smart_money, foreign, institution, individual)"),
+        ("min_buy_days" = Option<i32>, Query, description = "최소 순매수 일수"),
+        ("limit" = Option<i32>, Query, description = "결과 제한")
+    ),
+    responses(
+        (status = 200, description = "투자자별 순매수 스크리닝 성공", body = InvestorFlowResponse),
+        (status = 500, description = "서버 오류", body = ErrorResponse)
+    ),
+    tag = "screening"
+)]
+pub async fn run_investor_flow_screening(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<InvestorFlowQuery>,
+) -> impl IntoResponse {
+    debug!(
+        "투자자별 순매수 스크리닝 요청: days={}, sort_by={:?}",
+        query.days, query.sort_by
+    );
+
+    let db_pool = match state.analytics_db_pool() {
+        Some(pool) => pool,
+        None => {
+            return error_response("DATABASE_ERROR", "Database not available").into_response();
+        }
+    };
+
+    let sort_by = query
+        .sort_by
+        .clone()
+        .unwrap_or_else(|| "smart_money".to_string());
+
+    let results = match ScreeningRepository::screen_investor_flow(
+        db_pool,
+        query.days,
+        &sort_by,
+        query.min_buy_days,
+        query.limit,
+    )
+    .await
+    {
+        Ok(r) => r,
+        Err(e) => {
+            warn!("투자자별 순매수 스크리닝 실패: {}", e);
+            return error_response(
+                "SCREENING_ERROR",
+                &format!("투자자별 순매수 스크리닝 실패: {}", e),
+            )
+            .into_response();
+        }
+    };
+
+    let total = results.len();
+    let dto_results: Vec<InvestorFlowResultDto> =
+        results.into_iter().map(to_investor_flow_dto).collect();
+
+    Json(InvestorFlowResponse {
+        total,
+        days: query.days,
+        sort_by,
+        results: dto_results,
+    })
+    .into_response()
+}
+
+/// 상위권 생존일 분석
+///
+/// GET /api/v1/screening/survival
+///
+/// 일별 global_score 상위 N개 목록(score_history) 기준으로 종목별 연속 생존일,
+/// 연속 진입 구간 길이 분포, 상위권 목록의 일평균 교체율을 반환합니다.
+#[utoipa::path(
+    get,
+    path = "/api/v1/screening/survival",
+    params(
+        ("top_n" = Option<i32>, Query, description = "상위권 기준 (기본: 50)"),
+        ("lookback_days" = Option<u32>, Query, description = "추적 기간 거래일 (기본: 15, 최대 120)"),
+        ("min_survival_days" = Option<u32>, Query, description = "최소 생존일")
+    ),
+    responses(
+        (status = 200, description = "생존일 분석 성공", body = SurvivalResponse),
+        (status = 500, description = "서버 오류", body = ErrorResponse)
+    ),
+    tag = "screening"
+)]
+pub async fn get_survival_stats(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<SurvivalQuery>,
+) -> impl IntoResponse {
+    debug!(
+        "생존일 분석 요청: top_n={}, lookback={}",
+        query.top_n, query.lookback_days
+    );
+
+    let db_pool = match state.analytics_db_pool() {
+        Some(pool) => pool,
+        None => {
+            return error_response("DATABASE_ERROR", "Database not available").into_response();
+        }
+    };
+
+    let top_n = query.top_n.clamp(1, 500);
+    let lookback_days = query.lookback_days.clamp(1, MAX_SURVIVAL_LOOKBACK);
+
+    let history = match load_survival_history(db_pool, top_n, lookback_days).await {
+        Ok(h) => h,
+        Err(e) => {
+            warn!("생존일 히스토리 조회 실패: {}", e);
+            return error_response("DATABASE_ERROR", &format!("히스토리 조회 실패: {}", e))
+                .into_response();
+        }
+    };
+
+    let tracker = SurvivalTracker::new(lookback_days as usize);
+    let min_days = query.min_survival_days.unwrap_or(0);
+    let results: Vec<SurvivalResultDto> = tracker
+        .rank_by_survival(&history)
+        .into_iter()
+        .filter(|r| r.survival_days >= min_days)
+        .map(|r| SurvivalResultDto {
+            ticker: r.ticker,
+            survival_days: r.survival_days,
+            appearance_count: r.appearance_count,
+            first_entry_date: r.first_entry_date,
+            last_seen_date: r.last_seen_date,
+        })
+        .collect();
+
+    let streak_distribution = tracker
+        .streak_distribution(&history)
+        .into_iter()
+        .map(|(streak_days, count)| StreakBucketDto { streak_days, count })
+        .collect();
+
+    Json(SurvivalResponse {
+        top_n,
+        lookback_days,
+        trading_days: history.len(),
+        as_of: history.last().map(|r| r.date),
+        churn_rate: tracker.churn_rate(&history),
+        streak_distribution,
+        total: results.len(),
+        results,
+    })
+    .into_response()
+}
+
+/// 최근 `lookback_days` 거래일의 일별 상위 N개 목록 로드.
+async fn load_survival_history(
+    pool: &PgPool,
+    top_n: i32,
+    lookback_days: u32,
+) -> Result<Vec<DailyRanking>, sqlx::Error> {
+    // 주말/휴장일을 고려해 달력일 기준으로 넉넉히 조회한 뒤 최근 거래일만 사용
+    let since = Utc::now().date_naive() - Duration::days(lookback_days as i64 * 2 + 7);
+    let mut history = ScoreHistoryRepository::get_daily_rankings(pool, since, top_n).await?;
+    let skip = history.len().saturating_sub(lookback_days as usize);
+    history.drain(..skip);
+    Ok(history)
+}
+
+/// 최소 생존일 필터 적용 (기본 상위권 기준).
+async fn filter_by_survival(
+    pool: &PgPool,
+    results: Vec<ScreeningResult>,
+    min_survival_days: Option<u32>,
+) -> Result<Vec<ScreeningResult>, sqlx::Error> {
+    let Some(min_days) = min_survival_days.filter(|d| *d > 0) else {
+        return Ok(results);
+    };
+
+    let lookback_days = min_days.clamp(DEFAULT_SURVIVAL_LOOKBACK, MAX_SURVIVAL_LOOKBACK);
+    let history = load_survival_history(pool, DEFAULT_SURVIVAL_TOP_N, lookback_days).await?;
+    let tracker = SurvivalTracker::new(lookback_days as usize);
+
+    Ok(results
+        .into_iter()
+        .filter(|r| tracker.calculate(&r.ticker, &history) >= min_days)
+        .collect())
+}
+
+/// 종목별 투자자 순매수 추이 조회
+///
+/// GET /api/v1/screening/investor-flow/{ticker}
+#[utoipa::path(
+    get,
+    path = "/api/v1/screening/investor-flow/{ticker}",
+    params(
+        ("ticker" = String, Path, description = "종목코드 (6자리)"),
+        ("days" = Option<i32>, Query, description = "조회 거래일 수 (기본: 5)")
+    ),
+    responses(
+        (status = 200, description = "투자자 순매수 추이 조회 성공", body = InvestorFlowHistoryResponse),
+        (status = 500, description = "서버 오류", body = ErrorResponse)
+    ),
+    tag = "screening"
+)]
+pub async fn get_investor_flow_history(
+    State(state): State<Arc<AppState>>,
+    Path(ticker): Path<String>,
+    Query(query): Query<InvestorFlowQuery>,
+) -> impl IntoResponse {
+    let db_pool = match state.analytics_db_pool() {
+        Some(pool) => pool,
+        None => {
+            return error_response("DATABASE_ERROR", "Database not available").into_response();
+        }
+    };
+
+    let store = InvestorFlowStore::new(db_pool.clone());
+    let flows = match store.get_recent_flows(&ticker, query.days.into()).await {
+        Ok(flows) => flows,
+        Err(e) => {
+            warn!("투자자 순매수 추이 조회 실패: {}", e);
+            return error_response(
+                "INVESTOR_FLOW_ERROR",
+                &format!("투자자 순매수 추이 조회 실패: {}", e),
+            )
+            .into_response();
+        }
+    };
+
+    Json(InvestorFlowHistoryResponse {
+        ticker,
+        summary: InvestorFlowSummary::from_flows(&flows),
+        flows,
+    })
+    .into_response()
+}
+
+/// 종목별 7Factor 점수 추이 조회
+///
+/// GET /api/v1/screening/{ticker}/factors/history
+///
+/// collector가 일별로 기록한 7Factor 점수와 전일 대비 팩터별 변화를 반환합니다.
+/// `top_driver`는 종합 점수 변화에 가장 크게 기여한 팩터입니다.
+#[utoipa::path(
+    get,
+    path = "/api/v1/screening/{ticker}/factors/history",
+    params(
+        ("ticker" = String, Path, description = "종목코드"),
+        ("days" = Option<i32>, Query, description = "조회 일수 (기본: 30, 최대 365)")
+    ),
+    responses(
+        (status = 200, description = "7Factor 추이 조회 성공", body = FactorHistoryResponse),
+        (status = 500, description = "서버 오류", body = ErrorResponse)
+    ),
+    tag = "screening"
+)]
+pub async fn get_factor_history(
+    State(state): State<Arc<AppState>>,
+    Path(ticker): Path<String>,
+    Query(query): Query<FactorHistoryQuery>,
+) -> impl IntoResponse {
+    let db_pool = match state.analytics_db_pool() {
+        Some(pool) => pool,
+        None => {
+            return error_response("DATABASE_ERROR", "Database not available").into_response();
+        }
+    };
+
+    let days = query.days.clamp(1, MAX_FACTOR_HISTORY_DAYS);
+    let history = match ScoreHistoryRepository::get_factor_history(db_pool, &ticker, days).await {
+        Ok(history) => history,
+        Err(e) => {
+            warn!("7Factor 추이 조회 실패: {}", e);
+            return error_response("DATABASE_ERROR", &format!("7Factor 추이 조회 실패: {}", e))
+                .into_response();
+        }
+    };
+
+    Json(FactorHistoryResponse {
+        ticker,
+        total: history.len(),
+        history,
+    })
+    .into_response()
+}
+
 /// 섹터 순위 조회
 ///
 /// GET /api/v1/sectors/ranking
@@ -913,7 +1444,7 @@ pub async fn get_sector_ranking(
         query.market, query.days
     );
 
-    let db_pool = match &state.db_pool {
+    let db_pool = match state.analytics_db_pool() {
         Some(pool) => pool,
         None => {
             return error_response("DATABASE_ERROR", "Database not available").into_response();
@@ -1018,6 +1549,9 @@ fn build_filter_summary(req: &ScreeningRequest) -> String {
     if let Some(min_dividend_yield) = &req.min_dividend_yield {
         parts.push(format!("배당수익률≥{}", min_dividend_yield));
     }
+    if let Some(days) = req.min_survival_days {
+        parts.push(format!("생존일≥{}", days));
+    }
 
     if parts.is_empty() {
         "필터 없음".to_string()
@@ -1039,6 +1573,10 @@ pub fn screening_router() -> Router<Arc<AppState>> {
         .route("/presets/{preset}", get(run_preset_screening))
         .route("/presets/id/{id}", delete(delete_preset))
         .route("/momentum", get(run_momentum_screening))
+        .route("/investor-flow", get(run_investor_flow_screening))
+        .route("/investor-flow/{ticker}", get(get_investor_flow_history))
+        .route("/survival", get(get_survival_stats))
+        .route("/{ticker}/factors/history", get(get_factor_history))
 }
 
 /// 섹터 분석 라우터 생성
@@ -1,24 +1,44 @@
 //! SignalMarker API 라우트
 //!
 //! 백테스트 및 실거래에서 발생한 기술 신호를 조회하고 검색합니다.
+//! 외부 알림(TradingView 등)을 웹훅으로 받아 `WebhookStrategy`로 전달하는
+//! 엔드포인트(`POST /api/v1/signals/webhook`)도 제공합니다.
 
 use axum::{
+    body::Bytes,
     extract::{Path, Query, State},
-    http::StatusCode,
+    http::{HeaderMap, StatusCode},
     routing::{get, post},
     Json, Router,
 };
 use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
 use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
 use std::sync::Arc;
+use tracing::{info, warn};
 use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
 
 use crate::error::ApiErrorResponse;
 use crate::repository::{BacktestResultsRepository, SignalMarkerRepository};
+use crate::services::paper_trading::persist_fills;
+use crate::services::refresh_liquidity_snapshots;
 use crate::AppState;
 use trader_core::{SignalIndicators, SignalMarker};
+use trader_execution::SignalConverter;
+use trader_strategy::EngineError;
+
+/// 웹훅 HMAC 서명 헤더 (`sha256=<hex>` 또는 `<hex>`).
+pub const WEBHOOK_SIGNATURE_HEADER: &str = "x-signature";
+
+/// 웹훅 서명 시각 헤더 (Unix 초, 서명 대상에 포함).
+pub const WEBHOOK_TIMESTAMP_HEADER: &str = "x-timestamp";
+
+/// 서명 시각 허용 오차 (초). 이 범위 밖의 요청은 재전송으로 보고 거부합니다.
+pub const WEBHOOK_MAX_SKEW_SECS: i64 = 300;
 
 // ==================== Request/Response 타입 ====================
 
@@ -159,6 +179,52 @@ pub struct SignalSearchResponse {
     pub signals: Vec<SignalMarkerDto>,
 }
 
+/// 웹훅으로 수신한 신호의 처리 결과
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct WebhookSignalResult {
+    /// 신호 ID
+    pub signal_id: Uuid,
+
+    /// 종목 코드
+    pub ticker: String,
+
+    /// 방향 (BUY/SELL)
+    pub side: String,
+
+    /// 신호 유형
+    pub signal_type: String,
+
+    /// 리스크 검증 통과 및 주문 생성 여부
+    pub accepted: bool,
+
+    /// 생성된 내부 주문 ID
+    pub order_id: Option<Uuid>,
+
+    /// 주문 서킷이 열려 대기열에 보관되었는지 여부
+    pub queued: bool,
+
+    /// 거부 사유
+    pub error: Option<String>,
+
+    /// 실행 노트 (수량 조정 등)
+    pub notes: Vec<String>,
+
+    /// 신호 메타데이터 (실행 결정 `liquidity_cap` 포함)
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    #[schema(value_type = Object)]
+    pub metadata: HashMap<String, JsonValue>,
+}
+
+/// 웹훅 수신 응답
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct WebhookSignalResponse {
+    /// 신호를 받은 전략 ID
+    pub strategy_id: String,
+
+    /// 신호별 처리 결과
+    pub signals: Vec<WebhookSignalResult>,
+}
+
 // ==================== API 핸들러 ====================
 
 /// 지표 기반 신호 검색
@@ -314,8 +380,8 @@ pub struct BacktestSignalsResponse {
     /// 백테스트 ID
     pub backtest_id: Uuid,
 
-    /// 전략 ID
-    pub strategy_id: String,
+    /// 전략 ID (등록 전략 없이 실행한 결과는 None)
+    pub strategy_id: Option<String>,
 
     /// 전략 유형
     pub strategy_type: String,
@@ -399,13 +465,628 @@ pub async fn get_backtest_signals(
     }))
 }
 
+/// 웹훅 인증 검증.
+///
+/// `X-Signature` 헤더가 있으면 `"{X-Timestamp}.{원문 본문}"`의 HMAC-SHA256 서명을 검증합니다.
+/// 헤더를 보낼 수 없는 TradingView 알림은 본문의 `passphrase`와 `timestamp`(RFC 3339,
+/// 예: `{{timenow}}`) 필드로 인증합니다. 어느 방식이든 시각이 `now`에서
+/// [`WEBHOOK_MAX_SKEW_SECS`] 넘게 벗어나면 거부합니다.
+///
+/// 성공 시 재전송 판별 키(서명, passphrase 방식은 본문 해시)와 요청 시각을 반환합니다.
+pub(crate) fn verify_webhook_auth(
+    secret: &str,
+    signature: Option<&str>,
+    timestamp: Option<&str>,
+    body: &[u8],
+    passphrase: Option<&str>,
+    body_timestamp: Option<&str>,
+    now: i64,
+) -> Result<(String, i64), &'static str> {
+    if let Some(signature) = signature {
+        let expected =
+            hex::decode(normalize_signature(signature)).map_err(|_| "malformed signature")?;
+        let timestamp = timestamp.ok_or("missing timestamp")?.trim();
+        let signed_at = timestamp
+            .parse::<i64>()
+            .map_err(|_| "malformed timestamp")?;
+        if (now - signed_at).abs() > WEBHOOK_MAX_SKEW_SECS {
+            return Err("stale timestamp");
+        }
+
+        let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes())
+            .map_err(|_| "invalid webhook secret")?;
+        mac.update(timestamp.as_bytes());
+        mac.update(b".");
+        mac.update(body);
+        mac.verify_slice(&expected)
+            .map_err(|_| "signature mismatch")?;
+        return Ok((signature.to_string(), signed_at));
+    }
+
+    match passphrase {
+        Some(passphrase) if constant_time_eq(passphrase.as_bytes(), secret.as_bytes()) => {}
+        Some(_) => return Err("passphrase mismatch"),
+        None => return Err("missing signature"),
+    }
+    let sent_at = DateTime::parse_from_rfc3339(body_timestamp.ok_or("missing timestamp")?.trim())
+        .map_err(|_| "malformed timestamp")?
+        .timestamp();
+    if (now - sent_at).abs() > WEBHOOK_MAX_SKEW_SECS {
+        return Err("stale timestamp");
+    }
+    // 본문에 시각이 포함되므로 같은 본문은 같은 알림의 재전송
+    Ok((hex::encode(Sha256::digest(body)), sent_at))
+}
+
+/// 서명 헤더에서 16진수 서명만 추출 (재전송 판별 키로도 사용).
+fn normalize_signature(signature: &str) -> String {
+    let signature = signature.trim();
+    signature
+        .strip_prefix("sha256=")
+        .unwrap_or(signature)
+        .to_ascii_lowercase()
+}
+
+/// 검증된 서명(또는 passphrase 본문 해시) 기록 (이미 사용된 키면 `false`).
+///
+/// 허용 오차를 벗어난 서명은 시각 검사에서 거부되므로 그보다 오래된 기록은 정리합니다.
+fn record_webhook_signature(
+    seen: &mut HashMap<String, i64>,
+    signature: &str,
+    signed_at: i64,
+    now: i64,
+) -> bool {
+    seen.retain(|_, at| now - *at <= WEBHOOK_MAX_SKEW_SECS);
+    seen.insert(normalize_signature(signature), signed_at)
+        .is_none()
+}
+
+/// 길이 외 정보가 시간으로 노출되지 않는 바이트 비교.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+fn webhook_error(
+    status: StatusCode,
+    code: &str,
+    message: impl Into<String>,
+) -> (StatusCode, Json<ApiErrorResponse>) {
+    (status, Json(ApiErrorResponse::new(code, message)))
+}
+
+/// 외부 신호 웹훅 수신
+///
+/// TradingView 또는 사용자 정의 알림을 받아 `strategy_id`로 지정된
+/// Webhook 전략에 전달하고, 생성된 신호를 리스크 검증 및 주문 실행기로 보냅니다.
+///
+/// # 인증
+/// - `X-Timestamp: <unix 초>` + `X-Signature: sha256=<hex>` - `"{timestamp}.{본문}"`의
+///   HMAC-SHA256 (`SIGNAL_WEBHOOK_SECRET`). 서명 시각이 ±5분을 벗어나거나
+///   이미 사용된 서명이면 거부합니다.
+/// - 또는 본문의 `passphrase` + `timestamp`(RFC 3339) 필드 (TradingView 등 헤더 지정 불가 시).
+///   시각이 ±5분을 벗어나거나 같은 본문이 다시 오면 거부합니다.
+#[utoipa::path(
+    post,
+    path = "/api/v1/signals/webhook",
+    request_body(content = Object, description = "알림 페이로드 (strategy_id, ticker, action, price 등)", content_type = "application/json"),
+    responses(
+        (status = 200, description = "신호 처리 완료", body = WebhookSignalResponse),
+        (status = 400, description = "잘못된 페이로드", body = ApiErrorResponse),
+        (status = 401, description = "인증 실패", body = ApiErrorResponse),
+        (status = 404, description = "전략 없음", body = ApiErrorResponse),
+        (status = 409, description = "전략 미실행", body = ApiErrorResponse),
+        (status = 422, description = "전략이 신호 거부", body = ApiErrorResponse),
+        (status = 503, description = "웹훅 비활성화", body = ApiErrorResponse)
+    ),
+    tag = "signals"
+)]
+pub async fn receive_signal_webhook(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Result<Json<WebhookSignalResponse>, (StatusCode, Json<ApiErrorResponse>)> {
+    let secret = state.signal_webhook_secret.as_deref().ok_or_else(|| {
+        webhook_error(
+            StatusCode::SERVICE_UNAVAILABLE,
+            "WEBHOOK_DISABLED",
+            "Signal webhook is disabled (SIGNAL_WEBHOOK_SECRET not set)",
+        )
+    })?;
+
+    let mut payload: JsonValue = serde_json::from_slice(&body).map_err(|e| {
+        webhook_error(
+            StatusCode::BAD_REQUEST,
+            "INVALID_PAYLOAD",
+            format!("Invalid JSON: {}", e),
+        )
+    })?;
+
+    let signature = headers
+        .get(WEBHOOK_SIGNATURE_HEADER)
+        .and_then(|v| v.to_str().ok());
+    let timestamp = headers
+        .get(WEBHOOK_TIMESTAMP_HEADER)
+        .and_then(|v| v.to_str().ok());
+    let passphrase = payload.get("passphrase").and_then(|v| v.as_str());
+    let body_timestamp = payload.get("timestamp").and_then(|v| v.as_str());
+    let now = Utc::now().timestamp();
+    let (replay_key, signed_at) = verify_webhook_auth(
+        secret,
+        signature,
+        timestamp,
+        &body,
+        passphrase,
+        body_timestamp,
+        now,
+    )
+    .map_err(|reason| {
+        warn!(reason, "Signal webhook authentication failed");
+        webhook_error(StatusCode::UNAUTHORIZED, "INVALID_SIGNATURE", reason)
+    })?;
+
+    // 같은 서명(passphrase 방식은 같은 본문) 재전송 거부
+    {
+        let mut seen = state.signal_webhook_signatures.write().await;
+        if !record_webhook_signature(&mut seen, &replay_key, signed_at, now) {
+            warn!("Signal webhook signature replayed");
+            return Err(webhook_error(
+                StatusCode::UNAUTHORIZED,
+                "REPLAYED_SIGNATURE",
+                "signature already used",
+            ));
+        }
+    }
+
+    // 인증 정보는 전략으로 전달하지 않음
+    if let Some(object) = payload.as_object_mut() {
+        object.remove("passphrase");
+    }
+
+    let strategy_id = payload
+        .get("strategy_id")
+        .and_then(|v| v.as_str())
+        .map(str::to_string)
+        .ok_or_else(|| {
+            webhook_error(
+                StatusCode::BAD_REQUEST,
+                "INVALID_PAYLOAD",
+                "strategy_id is required",
+            )
+        })?;
+
+    // 모의투자 전략 신호는 실거래 실행기 대신 가상 계좌에서 체결
+    let mut signals = {
+        let engine = state.strategy_engine.read().await;
+        let paper_trading = engine
+            .get_strategy_status(&strategy_id)
+            .await
+            .is_ok_and(|status| status.paper_trading);
+        let result = if paper_trading {
+            engine
+                .process_paper_external_signal(&strategy_id, &payload)
+                .await
+        } else {
+            engine.process_external_signal(&strategy_id, &payload).await
+        };
+        let signals = result.map_err(|e| {
+            let (status, code) = match &e {
+                EngineError::StrategyNotFound(_) => (StatusCode::NOT_FOUND, "STRATEGY_NOT_FOUND"),
+                EngineError::NotRunning(_) => (StatusCode::CONFLICT, "NOT_RUNNING"),
+                EngineError::SignalRejected(_) => {
+                    (StatusCode::UNPROCESSABLE_ENTITY, "SIGNAL_REJECTED")
+                }
+                _ => (StatusCode::INTERNAL_SERVER_ERROR, "ENGINE_ERROR"),
+            };
+            webhook_error(status, code, e.to_string())
+        })?;
+        if paper_trading {
+            return Ok(Json(
+                execute_paper_webhook_signals(&state, strategy_id, &signals).await,
+            ));
+        }
+        signals
+    };
+
+    // 리스크 검증 및 주문 생성 (알림 가격이 없으면 보유 포지션의 현재가 사용)
+    let executor = state.executor.read().await;
+    let mut prices = HashMap::new();
+    for signal in &signals {
+        let price = match signal.suggested_price {
+            Some(price) => Some(price),
+            None => executor
+                .get_position(&signal.ticker)
+                .await
+                .map(|p| p.current_price),
+        };
+        if let Some(price) = price {
+            prices.insert(signal.ticker.clone(), price);
+        }
+    }
+
+    // 진입 신호 종목의 ADV 갱신 (실행기가 유동성 기준으로 수량 상한 적용)
+    drop(executor);
+    let entry_tickers: Vec<String> = signals
+        .iter()
+        .filter(|s| SignalConverter::is_entry_signal(&s.signal_type))
+        .map(|s| s.ticker.clone())
+        .collect();
+    refresh_liquidity_snapshots(&state, &entry_tickers).await;
+    let executor = state.executor.read().await;
+
+    // 여러 신호는 매수 여력을 함께 예측하여 진입 주문을 비례 축소
+    let executions = executor.process_signals(&signals, &prices).await;
+
+    let mut results = Vec::with_capacity(signals.len());
+    for (signal, execution) in signals.iter_mut().zip(executions) {
+        let result = if prices.contains_key(&signal.ticker) {
+            execution.apply_metadata(signal);
+            WebhookSignalResult {
+                signal_id: signal.id,
+                ticker: signal.ticker.clone(),
+                side: signal.side.to_string(),
+                signal_type: signal.signal_type.to_string(),
+                accepted: execution.success,
+                order_id: execution.order_id,
+                queued: execution.queued,
+                error: execution.error,
+                notes: execution.notes,
+                metadata: signal.metadata.clone(),
+            }
+        } else {
+            WebhookSignalResult {
+                signal_id: signal.id,
+                ticker: signal.ticker.clone(),
+                side: signal.side.to_string(),
+                signal_type: signal.signal_type.to_string(),
+                accepted: false,
+                order_id: None,
+                queued: false,
+                error: Some("price is required when no position is held".to_string()),
+                notes: Vec::new(),
+                metadata: signal.metadata.clone(),
+            }
+        };
+
+        info!(
+            strategy_id = %strategy_id,
+            ticker = %result.ticker,
+            accepted = result.accepted,
+            "Webhook signal processed"
+        );
+        results.push(result);
+    }
+
+    Ok(Json(WebhookSignalResponse {
+        strategy_id,
+        signals: results,
+    }))
+}
+
+/// 모의투자 전략의 웹훅 신호를 가상 계좌에서 체결.
+async fn execute_paper_webhook_signals(
+    state: &AppState,
+    strategy_id: String,
+    signals: &[trader_core::Signal],
+) -> WebhookSignalResponse {
+    let mut results = Vec::with_capacity(signals.len());
+    for signal in signals {
+        let fills = state.paper_trading.execute(&strategy_id, signal).await;
+        persist_fills(state, &fills).await;
+
+        let result = WebhookSignalResult {
+            signal_id: signal.id,
+            ticker: signal.ticker.clone(),
+            side: signal.side.to_string(),
+            signal_type: signal.signal_type.to_string(),
+            accepted: !fills.is_empty(),
+            order_id: fills.first().and_then(|fill| fill.order_id.parse().ok()),
+            queued: false,
+            error: fills
+                .is_empty()
+                .then(|| "paper order not filled (pending or rejected)".to_string()),
+            notes: vec!["paper trading".to_string()],
+            metadata: signal.metadata.clone(),
+        };
+        info!(
+            strategy_id = %strategy_id,
+            ticker = %result.ticker,
+            accepted = result.accepted,
+            "Paper webhook signal processed"
+        );
+        results.push(result);
+    }
+
+    WebhookSignalResponse {
+        strategy_id,
+        signals: results,
+    }
+}
+
 // ==================== 라우터 ====================
 
 /// SignalMarker API 라우터
 pub fn signals_router() -> Router<Arc<AppState>> {
     Router::new()
         .route("/search", post(search_signals))
+        .route("/webhook", post(receive_signal_webhook))
         .route("/by-symbol", get(get_signals_by_symbol))
         .route("/by-strategy", get(get_signals_by_strategy))
         .route("/markers/backtest/{id}", get(get_backtest_signals))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOW: i64 = 1_760_000_000;
+
+    fn sign(secret: &str, timestamp: i64, body: &[u8]) -> String {
+        let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).unwrap();
+        mac.update(format!("{}.", timestamp).as_bytes());
+        mac.update(body);
+        hex::encode(mac.finalize().into_bytes())
+    }
+
+    #[test]
+    fn test_verify_webhook_signature() {
+        let body = br#"{"strategy_id":"tv","ticker":"AAPL","action":"buy"}"#;
+        let signature = sign("secret", NOW, body);
+        let ts = NOW.to_string();
+        let verify = |secret: &str, signature: &str, ts: Option<&str>| {
+            verify_webhook_auth(secret, Some(signature), ts, body, None, None, NOW)
+        };
+
+        assert!(verify("secret", &signature, Some(&ts)).is_ok());
+        assert!(verify("secret", &format!("sha256={}", signature), Some(&ts)).is_ok());
+        assert_eq!(
+            verify("other", &signature, Some(&ts)),
+            Err("signature mismatch")
+        );
+        assert_eq!(
+            verify("secret", "zz", Some(&ts)),
+            Err("malformed signature")
+        );
+
+        // 서명 시각은 서명 대상에 포함되므로 바꾸면 불일치
+        assert_eq!(
+            verify("secret", &signature, Some(&(NOW + 1).to_string())),
+            Err("signature mismatch")
+        );
+        // 본문만 서명한 이전 방식은 거부
+        let mut mac = Hmac::<Sha256>::new_from_slice(b"secret").unwrap();
+        mac.update(body);
+        let body_only = hex::encode(mac.finalize().into_bytes());
+        assert_eq!(
+            verify("secret", &body_only, Some(&ts)),
+            Err("signature mismatch")
+        );
+        assert_eq!(verify("secret", &signature, None), Err("missing timestamp"));
+        assert_eq!(
+            verify("secret", &signature, Some("yesterday")),
+            Err("malformed timestamp")
+        );
+    }
+
+    #[test]
+    fn test_verify_webhook_stale_timestamp() {
+        let body = br#"{"strategy_id":"tv","ticker":"AAPL","action":"buy"}"#;
+        let verify = |signed_at: i64| {
+            let signature = sign("secret", signed_at, body);
+            verify_webhook_auth(
+                "secret",
+                Some(&signature),
+                Some(&signed_at.to_string()),
+                body,
+                None,
+                None,
+                NOW,
+            )
+        };
+
+        assert!(verify(NOW - WEBHOOK_MAX_SKEW_SECS).is_ok());
+        assert!(verify(NOW + WEBHOOK_MAX_SKEW_SECS).is_ok());
+        assert_eq!(
+            verify(NOW - WEBHOOK_MAX_SKEW_SECS - 1),
+            Err("stale timestamp")
+        );
+        assert_eq!(
+            verify(NOW + WEBHOOK_MAX_SKEW_SECS + 1),
+            Err("stale timestamp")
+        );
+    }
+
+    #[test]
+    fn test_record_webhook_signature_rejects_replay() {
+        let mut seen = HashMap::new();
+        let signature = sign("secret", NOW, b"{}");
+
+        assert!(record_webhook_signature(&mut seen, &signature, NOW, NOW));
+        // 같은 서명 재전송 (접두사/대소문자만 달라도 같은 서명)
+        assert!(!record_webhook_signature(
+            &mut seen,
+            &signature,
+            NOW,
+            NOW + 10
+        ));
+        assert!(!record_webhook_signature(
+            &mut seen,
+            &format!("sha256={}", signature.to_uppercase()),
+            NOW,
+            NOW + 10
+        ));
+        assert!(record_webhook_signature(
+            &mut seen,
+            &sign("secret", NOW + 1, b"{}"),
+            NOW + 1,
+            NOW + 10
+        ));
+
+        // 허용 오차가 지난 기록은 정리 (재전송은 시각 검사에서 거부됨)
+        assert!(record_webhook_signature(
+            &mut seen,
+            &sign("secret", NOW + 400, b"{}"),
+            NOW + 400,
+            NOW + 400
+        ));
+        assert_eq!(seen.len(), 1);
+    }
+
+    #[test]
+    fn test_verify_webhook_passphrase() {
+        let body = b"{}";
+        let sent_at = DateTime::from_timestamp(NOW, 0).unwrap().to_rfc3339();
+        let verify = |passphrase, timestamp: Option<&str>| {
+            verify_webhook_auth("secret", None, None, body, passphrase, timestamp, NOW)
+        };
+
+        let (replay_key, at) = verify(Some("secret"), Some(&sent_at)).unwrap();
+        assert_eq!(replay_key, hex::encode(Sha256::digest(body)));
+        assert_eq!(at, NOW);
+        assert_eq!(
+            verify(Some("guess"), Some(&sent_at)),
+            Err("passphrase mismatch")
+        );
+        assert_eq!(verify(None, Some(&sent_at)), Err("missing signature"));
+
+        // passphrase만으로는 재전송을 막을 수 없으므로 시각 필수
+        assert_eq!(verify(Some("secret"), None), Err("missing timestamp"));
+        assert_eq!(
+            verify(Some("secret"), Some("yesterday")),
+            Err("malformed timestamp")
+        );
+        let stale = DateTime::from_timestamp(NOW - WEBHOOK_MAX_SKEW_SECS - 1, 0)
+            .unwrap()
+            .to_rfc3339();
+        assert_eq!(verify(Some("secret"), Some(&stale)), Err("stale timestamp"));
+    }
+
+    #[tokio::test]
+    async fn test_passphrase_webhook_rejects_replay() {
+        use crate::state::create_test_state;
+        use axum::body::Body;
+        use axum::http::Request;
+        use tower::ServiceExt;
+
+        let mut state = create_test_state();
+        state.signal_webhook_secret = Some("secret".to_string());
+        let router = signals_router().with_state(Arc::new(state));
+
+        let body = serde_json::json!({
+            "strategy_id": "tv_missing",
+            "ticker": "AAPL",
+            "action": "buy",
+            "passphrase": "secret",
+            "timestamp": Utc::now().to_rfc3339(),
+        })
+        .to_string();
+        let send = |body: String| {
+            router.clone().oneshot(
+                Request::post("/webhook")
+                    .header("content-type", "application/json")
+                    .body(Body::from(body))
+                    .unwrap(),
+            )
+        };
+
+        // 인증은 통과 (전략이 없어 404)
+        let response = send(body.clone()).await.unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        // 같은 본문 재전송은 거부
+        let response = send(body).await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let error: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(error["code"], "REPLAYED_SIGNATURE");
+    }
+
+    #[tokio::test]
+    async fn test_paper_strategy_webhook_skips_live_executor() {
+        use crate::state::create_test_state;
+        use axum::body::Body;
+        use axum::http::Request;
+        use tower::ServiceExt;
+        use trader_strategy::strategies::WebhookStrategy;
+
+        let mut state = create_test_state();
+        state.signal_webhook_secret = Some("secret".to_string());
+        let state = Arc::new(state);
+        {
+            let engine = state.strategy_engine.read().await;
+            engine
+                .register_strategy(
+                    "tv_paper",
+                    Box::new(WebhookStrategy::new()),
+                    serde_json::json!({}),
+                    None,
+                )
+                .await
+                .unwrap();
+            engine.start_strategy("tv_paper").await.unwrap();
+            engine
+                .set_strategy_paper_trading("tv_paper", true)
+                .await
+                .unwrap();
+        }
+        state.paper_trading.open("tv_paper").await;
+        let at = Utc::now();
+        state
+            .paper_trading
+            .on_market(&trader_core::Kline::new(
+                "AAPL".to_string(),
+                trader_core::Timeframe::M1,
+                at,
+                rust_decimal_macros::dec!(190),
+                rust_decimal_macros::dec!(190),
+                rust_decimal_macros::dec!(190),
+                rust_decimal_macros::dec!(190),
+                rust_decimal_macros::dec!(1000),
+                at,
+            ))
+            .await;
+
+        let body = serde_json::json!({
+            "strategy_id": "tv_paper",
+            "ticker": "AAPL",
+            "action": "buy",
+            "price": 190,
+        })
+        .to_string();
+        let now = Utc::now().timestamp();
+        let response = signals_router()
+            .with_state(state.clone())
+            .oneshot(
+                Request::post("/webhook")
+                    .header("content-type", "application/json")
+                    .header(WEBHOOK_TIMESTAMP_HEADER, now.to_string())
+                    .header(
+                        WEBHOOK_SIGNATURE_HEADER,
+                        sign("secret", now, body.as_bytes()),
+                    )
+                    .body(Body::from(body))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let response: WebhookSignalResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(response.signals.len(), 1);
+        assert!(response.signals[0].accepted);
+        assert_eq!(response.signals[0].notes, vec!["paper trading"]);
+
+        // 실거래 실행기에는 주문/포지션이 생기지 않고 가상 계좌에서만 체결
+        let executor = state.executor.read().await;
+        assert!(executor.get_active_orders().await.is_empty());
+        assert!(executor.get_open_positions().await.is_empty());
+        let snapshot = state.paper_trading.snapshot("tv_paper").await.unwrap();
+        assert_eq!(snapshot.trades, 1);
+    }
+}
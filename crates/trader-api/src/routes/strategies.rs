@@ -6,11 +6,17 @@
 //!
 //! - `GET /api/v1/strategies` - 전략 목록 조회
 //! - `POST /api/v1/strategies` - 전략 생성
-//! - `GET /api/v1/strategies/{id}` - 특정 전략 상세 조회
+//! - `GET /api/v1/strategies/recommend` - 종목별 추천 전략 (백테스트 적합도 순)
+//! - `GET /api/v1/strategies/{id}` - 특정 전략 상세 조회 (유동성 기반 운용 용량 추정 포함)
 //! - `DELETE /api/v1/strategies/{id}` - 전략 삭제
 //! - `POST /api/v1/strategies/{id}/start` - 전략 시작
 //! - `POST /api/v1/strategies/{id}/stop` - 전략 중지
+//! - `POST /api/v1/strategies/{id}/pause` - 전략 일시정지 (실행 상태 유지)
+//! - `POST /api/v1/strategies/{id}/resume` - 일시정지한 전략 재개
+//! - `PUT /api/v1/strategies/{id}/trading-window` - 전략 거래 시간대 변경
 //! - `PUT /api/v1/strategies/{id}/config` - 전략 설정 변경
+//! - `GET /api/v1/strategies/{id}/history` - 파라미터 변경 이력 (손익 시계열 변경 마커 포함)
+//! - `GET /api/v1/strategies/{id}/promotion` - 백테스트 승격 전략의 원본 대비 실거래 성과
 
 use axum::{
     extract::{Path, State},
@@ -29,10 +35,18 @@ use utoipa::ToSchema;
 use uuid::Uuid;
 use validator::Validate;
 
+use super::strategy_history::{load_param_snapshot, record_param_change, resolve_actor};
+use crate::auth::OptionalJwtAuth;
 use crate::repository::{strategies::CreateStrategyInput, StrategyRepository};
+use crate::services::{estimate_strategy_capacity, StrategyCapacity};
 use crate::state::AppState;
 use crate::websocket::{ServerMessage, StrategyUpdateData};
-use trader_strategy::{EngineError, EngineStats, Strategy, StrategyStatus};
+use trader_core::{EarningsFilterConfig, TradingCostModel};
+use trader_execution::{RiskProfile, RiskProfileName};
+use trader_risk::CurfewOverride;
+use trader_strategy::{
+    EngineError, EngineStats, Strategy, StrategyStatus, SymbolLockConfig, TradingWindowConfig,
+};
 
 // ==================== 응답 타입 ====================
 
@@ -44,7 +58,7 @@ pub struct StrategiesListResponse {
     pub strategies: Vec<StrategyListItem>,
     /// 전체 전략 수
     pub total: usize,
-    /// 실행 중인 전략 수
+    /// 실행 중인 전략 수 (일시정지 포함)
     pub running: usize,
 }
 
@@ -59,7 +73,7 @@ pub struct StrategyListItem {
     pub strategy_type: String,
     /// 전략 이름
     pub name: String,
-    /// 전략 상태 ("Running", "Stopped", "Error")
+    /// 전략 상태 ("Running", "Paused", "Stopped", "Error")
     pub status: String,
     /// 시장 ("KR", "US", "CRYPTO")
     pub market: String,
@@ -107,6 +121,9 @@ pub struct StrategyDetailResponse {
     pub status: StrategyStatus,
     /// 전략 설정 (편집용)
     pub config: Value,
+    /// 운용 용량 추정 (ADV 참여율/회전율/슬리피지 기준, DB 미연결 시 생략)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub capacity: Option<StrategyCapacity>,
 }
 
 /// 전략 시작/중지 응답.
@@ -143,11 +160,77 @@ pub struct UpdateRiskSettingsRequest {
     /// 할당 자본 (NULL이면 전체 계좌 잔고 사용)
     #[serde(default)]
     pub allocated_capital: Option<f64>,
-    /// 리스크 프로필 (conservative, default, aggressive, custom)
+    /// 리스크 프로필 (conservative, standard, aggressive: 기본 프로필 적용,
+    /// default: 전역 설정 사용, custom: 직접 설정)
     #[serde(default)]
     pub risk_profile: Option<String>,
 }
 
+/// 거래 비용 모델 변경 요청.
+#[derive(Debug, Deserialize, TS)]
+#[ts(export, export_to = "strategies/")]
+pub struct UpdateCostModelRequest {
+    /// 거래 비용 모델 (NULL이면 서버 기본 모델 사용)
+    /// 예: `{"type": "kr_stock"}`, `{"type": "us_stock"}`, `{"type": "crypto"}`
+    #[serde(default)]
+    #[ts(type = "Record<string, unknown> | null")]
+    pub cost_model: Option<TradingCostModel>,
+}
+
+/// 정규장 외 거래 허용 변경 요청.
+#[derive(Debug, Deserialize, TS)]
+#[ts(export, export_to = "strategies/")]
+pub struct UpdateExtendedHoursRequest {
+    /// 미국 주식 주간거래/프리마켓/애프터마켓 주문 허용 여부
+    pub extended_hours: bool,
+}
+
+/// 실적 발표 필터 변경 요청.
+#[derive(Debug, Deserialize, TS)]
+#[ts(export, export_to = "strategies/")]
+pub struct UpdateEarningsFilterRequest {
+    /// 실적 발표 필터 (NULL이면 해제)
+    /// 예: `{"entry_blackout_days": 3, "exit_before_days": 1}`
+    #[serde(default)]
+    #[ts(type = "{ entry_blackout_days?: number, exit_before_days?: number | null } | null")]
+    pub earnings_filter: Option<EarningsFilterConfig>,
+}
+
+/// 장중 커퓨 오버라이드 변경 요청.
+#[derive(Debug, Deserialize, TS)]
+#[ts(export, export_to = "strategies/")]
+pub struct UpdateCurfewRequest {
+    /// 커퓨 오버라이드 (NULL이면 실행기 기본 구간 적용)
+    /// 예: `{"exempt": false, "skip_windows": ["krx_open"], "extra_windows": []}`
+    #[serde(default)]
+    #[ts(type = "Record<string, unknown> | null")]
+    pub curfew_override: Option<CurfewOverride>,
+}
+
+/// 거래 시간대 변경 요청.
+#[derive(Debug, Deserialize, TS)]
+#[ts(export, export_to = "strategies/")]
+pub struct UpdateTradingWindowRequest {
+    /// 거래 시간대 (NULL이면 해제)
+    /// 예: `{"market": "KR", "start": "09:05:00", "end": "15:20:00", "flatten_outside": true}`
+    #[serde(default)]
+    #[ts(
+        type = "{ market?: \"KR\" | \"US\", start: string, end: string, skip_holidays?: boolean, flatten_outside?: boolean } | null"
+    )]
+    pub trading_window: Option<TradingWindowConfig>,
+}
+
+/// 실행 조절 제외 변경 요청.
+#[derive(Debug, Deserialize, TS)]
+#[ts(export, export_to = "strategies/")]
+pub struct UpdateExecutionGovernorRequest {
+    /// 변동성 급등/스프레드 확대 시 진입 주문 조절에서 제외할지 여부
+    pub opt_out: bool,
+}
+
+/// 실적 발표 필터 최대 일수
+const MAX_EARNINGS_FILTER_DAYS: u32 = 30;
+
 /// 전략 심볼 변경 요청.
 #[derive(Debug, Deserialize, TS)]
 #[ts(export, export_to = "strategies/")]
@@ -212,7 +295,8 @@ pub struct CreateStrategyRequest {
     #[serde(default)]
     #[validate(range(min = 0.0, message = "할당 자본은 0 이상이어야 합니다"))]
     pub allocated_capital: Option<f64>,
-    /// 리스크 프로필 (conservative, default, aggressive, custom)
+    /// 리스크 프로필 (conservative, standard, aggressive: 기본 프로필 적용,
+    /// default: 전역 설정 사용, custom: 직접 설정)
     #[serde(default)]
     #[validate(custom(function = "validate_risk_profile"))]
     pub risk_profile: Option<String>,
@@ -221,6 +305,11 @@ pub struct CreateStrategyRequest {
     #[serde(default, rename = "multiTimeframeConfig")]
     #[ts(type = "Record<string, unknown> | null")]
     pub multi_timeframe_config: Option<Value>,
+    /// 종목 잠금 설정 (옵션, 다른 전략과 같은 종목 포지션 경쟁 방지)
+    /// 예: `{"policy": "exclusive", "max_symbols": 5}` (policy: exclusive, shared_long_only, netting)
+    #[serde(default)]
+    #[ts(type = "{ policy?: string, max_symbols?: number } | null")]
+    pub symbol_lock: Option<SymbolLockConfig>,
 }
 
 /// 전략 생성 응답.
@@ -269,18 +358,32 @@ impl From<EngineStats> for EngineStatsResponse {
 
 /// 리스크 프로필 유효성 검사.
 fn validate_risk_profile(profile: &str) -> Result<(), validator::ValidationError> {
-    const VALID_PROFILES: &[&str] = &["conservative", "default", "aggressive", "custom"];
+    const VALID_PROFILES: &[&str] = &[
+        "conservative",
+        "standard",
+        "default",
+        "aggressive",
+        "custom",
+    ];
     if VALID_PROFILES.contains(&profile) {
         Ok(())
     } else {
         let mut error = validator::ValidationError::new("invalid_risk_profile");
         error.message = Some(std::borrow::Cow::from(
-            "리스크 프로필은 conservative, default, aggressive, custom 중 하나여야 합니다",
+            "리스크 프로필은 conservative, standard, default, aggressive, custom 중 하나여야 합니다",
         ));
         Err(error)
     }
 }
 
+/// 리스크 프로필 이름의 기본 프로필 (default/custom은 None).
+///
+/// 기본 프로필이 지정된 전략은 실행기에서 프로필의 실행 조절과 포지션 사이징을 사용합니다.
+pub(crate) fn preset_risk_profile(name: Option<&str>) -> Option<RiskProfile> {
+    name.and_then(|n| n.parse::<RiskProfileName>().ok())
+        .map(RiskProfile::preset)
+}
+
 // ==================== API 에러 ====================
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
@@ -367,6 +470,7 @@ fn engine_error_to_response(err: EngineError) -> (StatusCode, Json<ApiError>) {
         EngineError::InitializationFailed(_) => (StatusCode::INTERNAL_SERVER_ERROR, "INIT_FAILED"),
         EngineError::NotRunning(_) => (StatusCode::BAD_REQUEST, "NOT_RUNNING"),
         EngineError::AlreadyRunning(_) => (StatusCode::BAD_REQUEST, "ALREADY_RUNNING"),
+        EngineError::SignalRejected(_) => (StatusCode::UNPROCESSABLE_ENTITY, "SIGNAL_REJECTED"),
         EngineError::ChannelError(_) => (StatusCode::INTERNAL_SERVER_ERROR, "CHANNEL_ERROR"),
         EngineError::InternalError(_) => (StatusCode::INTERNAL_SERVER_ERROR, "INTERNAL_ERROR"),
     };
@@ -374,6 +478,27 @@ fn engine_error_to_response(err: EngineError) -> (StatusCode, Json<ApiError>) {
     (status, Json(ApiError::new(code, err.to_string())))
 }
 
+/// 등록 전략 수 할당량 확인.
+///
+/// 새 전략의 소유자 ID를 반환합니다. 비인증 요청은 소유자 없는 전략 수에
+/// 익명 등급 한도를 적용하고 None을 반환합니다.
+pub(crate) async fn ensure_strategy_quota(
+    state: &AppState,
+    auth: &OptionalJwtAuth,
+) -> Result<Option<String>, (StatusCode, Json<ApiError>)> {
+    let result = match &auth.0 {
+        Some(claims) => state.quotas.check_strategy_quota(&claims.sub).await,
+        None => state.quotas.check_anonymous_strategy_quota().await,
+    };
+    result.map_err(|e| {
+        (
+            e.status_code(),
+            Json(ApiError::new("QUOTA_EXCEEDED", e.to_string())),
+        )
+    })?;
+    Ok(auth.0.as_ref().map(|claims| claims.sub.clone()))
+}
+
 // ==================== handler ====================
 
 /// 전략 생성.
@@ -381,6 +506,7 @@ fn engine_error_to_response(err: EngineError) -> (StatusCode, Json<ApiError>) {
 /// POST /api/v1/strategies
 pub async fn create_strategy(
     State(state): State<Arc<AppState>>,
+    auth: OptionalJwtAuth,
     Json(request): Json<CreateStrategyRequest>,
 ) -> Result<Json<CreateStrategyResponse>, (StatusCode, Json<ApiError>)> {
     // 입력 유효성 검사
@@ -391,6 +517,9 @@ pub async fn create_strategy(
         ));
     }
 
+    // 사용자별 등록 전략 수 할당량
+    let owner_id = ensure_strategy_quota(&state, &auth).await?;
+
     // 전략 인스턴스 생성
     let strategy = create_strategy_instance(&request.strategy_type).map_err(|e| {
         (
@@ -450,6 +579,14 @@ pub async fn create_strategy(
         .allocated_capital
         .map(|v| Decimal::try_from(v).unwrap_or(Decimal::ZERO));
 
+    // 기본 리스크 프로필 (리스크 설정이 없으면 프로필의 리스크 한도 저장)
+    let risk_profile = preset_risk_profile(request.risk_profile.as_deref());
+    let risk_config = request.risk_config.clone().or_else(|| {
+        risk_profile
+            .as_ref()
+            .and_then(|p| serde_json::to_value(&p.risk).ok())
+    });
+
     // 데이터베이스에 저장 (DB가 연결된 경우)
     if let Some(ref pool) = state.db_pool {
         let input = CreateStrategyInput {
@@ -461,10 +598,12 @@ pub async fn create_strategy(
             market: market.clone(),
             timeframe: timeframe.clone(),
             config: request.parameters.clone(),
-            risk_config: request.risk_config.clone(),
+            risk_config,
             allocated_capital,
             risk_profile: request.risk_profile.clone(),
             multi_timeframe_config: request.multi_timeframe_config.clone(),
+            symbol_lock: request.symbol_lock,
+            owner_id,
         };
 
         StrategyRepository::create(pool, input).await.map_err(|e| {
@@ -479,18 +618,29 @@ pub async fn create_strategy(
         })?;
     }
 
-    // 엔진에 전략 등록 (커스텀 이름 전달)
+    // 엔진에 전략 등록 (커스텀 이름, 종목 잠금 전달)
     let engine = state.strategy_engine.read().await;
     engine
-        .register_strategy(
+        .register_strategy_with_lock(
             &strategy_id,
             strategy,
             request.parameters.clone(),
             custom_name,
+            request.symbol_lock,
         )
         .await
         .map_err(engine_error_to_response)?;
 
+    // 실행기에 전략 리스크 프로필 등록
+    if risk_profile.is_some() {
+        state
+            .executor
+            .read()
+            .await
+            .set_strategy_risk_profile(&strategy_id, risk_profile)
+            .await;
+    }
+
     // WebSocket 브로드캐스트: 전략 생성 알림
     state.broadcast(ServerMessage::StrategyUpdate(StrategyUpdateData {
         strategy_id: strategy_id.clone(),
@@ -540,6 +690,18 @@ pub async fn delete_strategy(
         }
     }
 
+    // 자본 원장 및 리스크 프로필에서 제거
+    {
+        let executor = state.executor.read().await;
+        executor
+            .risk_manager()
+            .write()
+            .await
+            .capital_ledger_mut()
+            .remove(&id);
+        executor.set_strategy_risk_profile(&id, None).await;
+    }
+
     // WebSocket 브로드캐스트: 전략 삭제 알림
     state.broadcast(ServerMessage::StrategyUpdate(StrategyUpdateData {
         strategy_id: id.clone(),
@@ -583,7 +745,9 @@ pub async fn list_strategies(State(state): State<Arc<AppState>>) -> impl IntoRes
             .unwrap_or_else(|_| "unknown".to_string());
 
         // 전략 상태 문자열 변환
-        let status_str = if status.running {
+        let status_str = if status.paused {
+            "Paused".to_string()
+        } else if status.running {
             "Running".to_string()
         } else {
             "Stopped".to_string()
@@ -630,7 +794,10 @@ pub async fn list_strategies(State(state): State<Arc<AppState>>) -> impl IntoRes
     // ID로 정렬
     strategies.sort_by(|a, b| a.id.cmp(&b.id));
 
-    let running_count = strategies.iter().filter(|s| s.status == "Running").count();
+    let running_count = strategies
+        .iter()
+        .filter(|s| s.status == "Running" || s.status == "Paused")
+        .count();
     let total = strategies.len();
 
     Json(StrategiesListResponse {
@@ -667,12 +834,17 @@ pub async fn get_strategy(
         .get_strategy_type(&id)
         .await
         .map_err(engine_error_to_response)?;
+    drop(engine);
+
+    // 운용 용량 추정 (할당 자본이 용량에 근접하면 경고 포함)
+    let capacity = estimate_strategy_capacity(&state, &id).await;
 
     Ok(Json(StrategyDetailResponse {
         id,
         strategy_type,
         status,
         config,
+        capacity,
     }))
 }
 
@@ -680,7 +852,8 @@ pub async fn get_strategy(
 ///
 /// POST /api/v1/strategies/{id}/start
 ///
-/// 다중 타임프레임 전략의 경우, 시작 전에 필요한 캔들 데이터를 자동으로 로드합니다.
+/// 워밍업 데이터 소스가 설정되어 있으면 엔진이 전략의 워밍업 설정(다중 타임프레임 포함)에
+/// 따라 과거 캔들을 조회하여 지표 상태를 채운 뒤 시작합니다 (`STRATEGY_WARMUP_ENABLED`).
 pub async fn start_strategy(
     State(state): State<Arc<AppState>>,
     Path(id): Path<String>,
@@ -694,17 +867,6 @@ pub async fn start_strategy(
         .map(|s| s.name)
         .unwrap_or_else(|_| id.clone());
 
-    // 다중 타임프레임 전략인 경우 데이터 자동 로드
-    if let Ok(Some(mtf_config)) = engine.get_strategy_multi_tf_config(&id).await {
-        if let Err(e) = load_multi_timeframe_data(&state, &engine, &id, &mtf_config).await {
-            tracing::warn!(
-                strategy_id = %id,
-                error = %e,
-                "다중 타임프레임 데이터 로드 실패 (전략은 계속 시작됨)"
-            );
-        }
-    }
-
     match engine.start_strategy(&id).await {
         Ok(()) => {
             // WebSocket 브로드캐스트: 전략 시작 알림
@@ -728,96 +890,91 @@ pub async fn start_strategy(
     }
 }
 
-/// 다중 타임프레임 데이터 자동 로드 (전략 시작 전).
+/// 전략 중지.
 ///
-/// 전략의 설정에서 심볼 목록을 가져와 각 심볼에 대해
-/// 다중 타임프레임 캔들 데이터를 로드하고 컨텍스트에 업데이트합니다.
-async fn load_multi_timeframe_data(
-    state: &AppState,
-    engine: &trader_strategy::StrategyEngine,
-    strategy_id: &str,
-    mtf_config: &trader_core::domain::MultiTimeframeConfig,
-) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-    use trader_core::Timeframe;
-
-    // 데이터 프로바이더 확인
-    let data_provider = state
-        .data_provider
-        .as_ref()
-        .ok_or("데이터 프로바이더 없음")?;
+/// POST /api/v1/strategies/{id}/stop
+pub async fn stop_strategy(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+) -> Result<Json<StrategyActionResponse>, (StatusCode, Json<ApiError>)> {
+    let engine = state.strategy_engine.read().await;
 
-    // 전략 설정에서 심볼 목록 가져오기
-    let config = engine.get_strategy_config(strategy_id).await?;
-    let symbols: Vec<String> = config
-        .get("symbols")
-        .and_then(|v| v.as_array())
-        .map(|arr| {
-            arr.iter()
-                .filter_map(|v| v.as_str().map(|s| s.to_string()))
-                .collect()
-        })
-        .unwrap_or_default();
+    // 전략 이름 가져오기 (브로드캐스트용)
+    let strategy_name = engine
+        .get_strategy_status(&id)
+        .await
+        .map(|s| s.name)
+        .unwrap_or_else(|_| id.clone());
+
+    match engine.stop_strategy(&id).await {
+        Ok(()) => {
+            // WebSocket 브로드캐스트: 전략 중지 알림
+            state.broadcast(ServerMessage::StrategyUpdate(StrategyUpdateData {
+                strategy_id: id.clone(),
+                name: strategy_name,
+                running: false,
+                event: "stopped".to_string(),
+                data: None,
+                timestamp: Utc::now().timestamp_millis(),
+            }));
 
-    if symbols.is_empty() {
-        tracing::debug!(strategy_id = %strategy_id, "심볼 목록 없음, 데이터 로드 스킵");
-        return Ok(());
+            Ok(Json(StrategyActionResponse {
+                success: true,
+                strategy_id: id.clone(),
+                action: "stop".to_string(),
+                message: format!("Strategy '{}' stopped successfully", id),
+            }))
+        }
+        Err(err) => Err(engine_error_to_response(err)),
     }
+}
 
-    tracing::info!(
-        strategy_id = %strategy_id,
-        symbols = ?symbols,
-        timeframes = ?mtf_config.timeframes.keys().collect::<Vec<_>>(),
-        "다중 타임프레임 데이터 로드 시작"
-    );
+/// 전략 일시정지.
+///
+/// POST /api/v1/strategies/{id}/pause
+///
+/// 전략 내부 상태와 보유 포지션 추적은 유지한 채 시장 데이터와 외부 신호(웹훅) 전달을
+/// 멈춥니다. 중지와 달리 재개하면 워밍업 없이 바로 이어서 동작합니다.
+pub async fn pause_strategy(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+) -> Result<Json<StrategyActionResponse>, (StatusCode, Json<ApiError>)> {
+    let engine = state.strategy_engine.read().await;
 
-    // 각 심볼에 대해 데이터 로드
-    for symbol in &symbols {
-        match data_provider
-            .get_multi_timeframe_klines(symbol, mtf_config)
-            .await
-        {
-            Ok(klines_by_tf) => {
-                // Kline 데이터를 (Timeframe, Vec<Kline>) 형태로 변환
-                let data: Vec<(Timeframe, Vec<trader_core::Kline>)> =
-                    klines_by_tf.into_iter().collect();
-
-                // 전략 컨텍스트에 업데이트
-                if let Err(e) = engine
-                    .update_strategy_klines(strategy_id, symbol, data)
-                    .await
-                {
-                    tracing::warn!(
-                        strategy_id = %strategy_id,
-                        symbol = %symbol,
-                        error = %e,
-                        "컨텍스트 업데이트 실패"
-                    );
-                } else {
-                    tracing::info!(
-                        strategy_id = %strategy_id,
-                        symbol = %symbol,
-                        "다중 타임프레임 데이터 로드 완료"
-                    );
-                }
-            }
-            Err(e) => {
-                tracing::warn!(
-                    strategy_id = %strategy_id,
-                    symbol = %symbol,
-                    error = %e,
-                    "데이터 로드 실패"
-                );
-            }
+    // 전략 이름 가져오기 (브로드캐스트용)
+    let strategy_name = engine
+        .get_strategy_status(&id)
+        .await
+        .map(|s| s.name)
+        .unwrap_or_else(|_| id.clone());
+
+    match engine.pause_strategy(&id).await {
+        Ok(()) => {
+            // WebSocket 브로드캐스트: 전략 일시정지 알림
+            state.broadcast(ServerMessage::StrategyUpdate(StrategyUpdateData {
+                strategy_id: id.clone(),
+                name: strategy_name,
+                running: true,
+                event: "paused".to_string(),
+                data: None,
+                timestamp: Utc::now().timestamp_millis(),
+            }));
+
+            Ok(Json(StrategyActionResponse {
+                success: true,
+                strategy_id: id.clone(),
+                action: "pause".to_string(),
+                message: format!("Strategy '{}' paused successfully", id),
+            }))
         }
+        Err(err) => Err(engine_error_to_response(err)),
     }
-
-    Ok(())
 }
 
-/// 전략 중지.
+/// 일시정지한 전략 재개.
 ///
-/// POST /api/v1/strategies/{id}/stop
-pub async fn stop_strategy(
+/// POST /api/v1/strategies/{id}/resume
+pub async fn resume_strategy(
     State(state): State<Arc<AppState>>,
     Path(id): Path<String>,
 ) -> Result<Json<StrategyActionResponse>, (StatusCode, Json<ApiError>)> {
@@ -830,14 +987,14 @@ pub async fn stop_strategy(
         .map(|s| s.name)
         .unwrap_or_else(|_| id.clone());
 
-    match engine.stop_strategy(&id).await {
+    match engine.resume_strategy(&id).await {
         Ok(()) => {
-            // WebSocket 브로드캐스트: 전략 중지 알림
+            // WebSocket 브로드캐스트: 전략 재개 알림
             state.broadcast(ServerMessage::StrategyUpdate(StrategyUpdateData {
                 strategy_id: id.clone(),
                 name: strategy_name,
-                running: false,
-                event: "stopped".to_string(),
+                running: true,
+                event: "resumed".to_string(),
                 data: None,
                 timestamp: Utc::now().timestamp_millis(),
             }));
@@ -845,8 +1002,8 @@ pub async fn stop_strategy(
             Ok(Json(StrategyActionResponse {
                 success: true,
                 strategy_id: id.clone(),
-                action: "stop".to_string(),
-                message: format!("Strategy '{}' stopped successfully", id),
+                action: "resume".to_string(),
+                message: format!("Strategy '{}' resumed successfully", id),
             }))
         }
         Err(err) => Err(engine_error_to_response(err)),
@@ -858,9 +1015,11 @@ pub async fn stop_strategy(
 /// PUT /api/v1/strategies/{id}/config
 pub async fn update_config(
     State(state): State<Arc<AppState>>,
+    auth: OptionalJwtAuth,
     Path(id): Path<String>,
     Json(request): Json<UpdateConfigRequest>,
 ) -> Result<Json<StrategyActionResponse>, (StatusCode, Json<ApiError>)> {
+    let before = load_param_snapshot(&state, &id).await;
     let engine = state.strategy_engine.read().await;
 
     // 전략 상태 가져오기 (브로드캐스트용)
@@ -884,6 +1043,15 @@ pub async fn update_config(
                     // DB 저장 실패해도 메모리 업데이트는 성공했으므로 계속 진행
                 }
             }
+            record_param_change(
+                &state,
+                &id,
+                "config",
+                before,
+                resolve_actor(&auth),
+                is_running,
+            )
+            .await;
 
             // WebSocket 브로드캐스트: 설정 변경 알림
             state.broadcast(ServerMessage::StrategyUpdate(StrategyUpdateData {
@@ -909,8 +1077,12 @@ pub async fn update_config(
 /// 전략 리스크 설정 변경.
 ///
 /// PUT /api/v1/strategies/{id}/risk
+///
+/// 기본 리스크 프로필(conservative, standard, aggressive)을 지정하면 이 전략의 주문에는
+/// 전역 설정 대신 프로필의 실행 조절과 포지션 사이징이 적용됩니다.
 pub async fn update_risk_settings(
     State(state): State<Arc<AppState>>,
+    auth: OptionalJwtAuth,
     Path(id): Path<String>,
     Json(request): Json<UpdateRiskSettingsRequest>,
 ) -> Result<Json<StrategyActionResponse>, (StatusCode, Json<ApiError>)> {
@@ -921,17 +1093,26 @@ pub async fn update_risk_settings(
             Json(ApiError::new("DB_NOT_CONNECTED", "Database not connected")),
         )
     })?;
+    let before = load_param_snapshot(&state, &id).await;
 
     // 할당 자본을 Decimal로 변환
     let allocated_capital = request
         .allocated_capital
         .map(|v| Decimal::try_from(v).unwrap_or(Decimal::ZERO));
 
+    // 기본 리스크 프로필 (리스크 설정이 없으면 프로필의 리스크 한도 저장)
+    let risk_profile = preset_risk_profile(request.risk_profile.as_deref());
+    let risk_config = request.risk_config.clone().or_else(|| {
+        risk_profile
+            .as_ref()
+            .and_then(|p| serde_json::to_value(&p.risk).ok())
+    });
+
     // DB에 리스크 설정 업데이트
     StrategyRepository::update_risk_settings(
         pool,
         &id,
-        request.risk_config.clone(),
+        risk_config,
         allocated_capital,
         request.risk_profile.as_deref(),
     )
@@ -947,6 +1128,24 @@ pub async fn update_risk_settings(
         )
     })?;
 
+    // 자본 원장 및 리스크 프로필 반영 (할당 자본 미설정 = 예산 제한 없음)
+    {
+        let executor = state.executor.read().await;
+        executor.set_strategy_risk_profile(&id, risk_profile).await;
+        let mut risk_manager = executor.risk_manager().write().await;
+        let ledger = risk_manager.capital_ledger_mut();
+        match allocated_capital {
+            Some(capital) => {
+                if let Err(e) = ledger.allocate(&id, capital) {
+                    tracing::warn!("Failed to update strategy capital ledger: {}", e);
+                }
+            }
+            None => {
+                ledger.remove(&id);
+            }
+        }
+    }
+
     // 전략 이름 가져오기 (브로드캐스트용)
     let engine = state.strategy_engine.read().await;
     let (strategy_name, is_running) = engine
@@ -955,6 +1154,16 @@ pub async fn update_risk_settings(
         .map(|s| (s.name, s.running))
         .unwrap_or_else(|_| (id.clone(), false));
 
+    record_param_change(
+        &state,
+        &id,
+        "risk",
+        before,
+        resolve_actor(&auth),
+        is_running,
+    )
+    .await;
+
     // WebSocket 브로드캐스트: 리스크 설정 변경 알림
     state.broadcast(ServerMessage::StrategyUpdate(StrategyUpdateData {
         strategy_id: id.clone(),
@@ -976,14 +1185,26 @@ pub async fn update_risk_settings(
     }))
 }
 
-/// 전략 심볼 변경.
+/// 전략 거래 비용 모델 변경.
 ///
-/// PUT /api/v1/strategies/{id}/symbols
-pub async fn update_symbols(
+/// PUT /api/v1/strategies/{id}/cost-model
+///
+/// 실거래 체결 시 이 모델로 수수료/세금을 계산해 실현 손익에서 차감합니다.
+pub async fn update_cost_model(
     State(state): State<Arc<AppState>>,
+    auth: OptionalJwtAuth,
     Path(id): Path<String>,
-    Json(request): Json<UpdateSymbolsRequest>,
+    Json(request): Json<UpdateCostModelRequest>,
 ) -> Result<Json<StrategyActionResponse>, (StatusCode, Json<ApiError>)> {
+    if let Some(model) = &request.cost_model {
+        model.validate().map_err(|e| {
+            (
+                StatusCode::BAD_REQUEST,
+                Json(ApiError::new("INVALID_COST_MODEL", e)),
+            )
+        })?;
+    }
+
     // DB가 연결된 경우에만 동작
     let pool = state.db_pool.as_ref().ok_or_else(|| {
         (
@@ -991,21 +1212,43 @@ pub async fn update_symbols(
             Json(ApiError::new("DB_NOT_CONNECTED", "Database not connected")),
         )
     })?;
+    let before = load_param_snapshot(&state, &id).await;
 
-    // DB에 심볼 업데이트
-    StrategyRepository::update_symbols(pool, &id, request.symbols.clone())
+    let model_json = request
+        .cost_model
+        .as_ref()
+        .and_then(|m| serde_json::to_value(m).ok());
+
+    StrategyRepository::update_cost_model(pool, &id, model_json.clone())
         .await
-        .map_err(|e| {
-            tracing::error!("Failed to update strategy symbols: {:?}", e);
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
+        .map_err(|e| match e {
+            sqlx::Error::RowNotFound => (
+                StatusCode::NOT_FOUND,
                 Json(ApiError::new(
-                    "DB_ERROR",
-                    format!("Failed to update symbols: {}", e),
+                    "STRATEGY_NOT_FOUND",
+                    format!("Strategy '{}' not found", id),
                 )),
-            )
+            ),
+            e => {
+                tracing::error!("Failed to update cost model: {:?}", e);
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(ApiError::new(
+                        "DB_ERROR",
+                        format!("Failed to update cost model: {}", e),
+                    )),
+                )
+            }
         })?;
 
+    // 실행기 반영 (미설정 = 서버 기본 모델)
+    state
+        .executor
+        .read()
+        .await
+        .set_strategy_cost_model(&id, request.cost_model)
+        .await;
+
     // 전략 이름 가져오기 (브로드캐스트용)
     let engine = state.strategy_engine.read().await;
     let (strategy_name, is_running) = engine
@@ -1014,34 +1257,46 @@ pub async fn update_symbols(
         .map(|s| (s.name, s.running))
         .unwrap_or_else(|_| (id.clone(), false));
 
-    // WebSocket 브로드캐스트: 심볼 변경 알림
+    record_param_change(
+        &state,
+        &id,
+        "cost_model",
+        before,
+        resolve_actor(&auth),
+        is_running,
+    )
+    .await;
+
+    // WebSocket 브로드캐스트: 비용 모델 변경 알림
     state.broadcast(ServerMessage::StrategyUpdate(StrategyUpdateData {
         strategy_id: id.clone(),
         name: strategy_name,
         running: is_running,
-        event: "symbols_updated".to_string(),
-        data: Some(serde_json::json!({
-            "symbols": request.symbols,
-        })),
+        event: "cost_model_updated".to_string(),
+        data: Some(serde_json::json!({ "cost_model": model_json })),
         timestamp: Utc::now().timestamp_millis(),
     }));
 
     Ok(Json(StrategyActionResponse {
         success: true,
         strategy_id: id.clone(),
-        action: "update_symbols".to_string(),
-        message: format!("Strategy '{}' symbols updated successfully", id),
+        action: "update_cost_model".to_string(),
+        message: format!("Strategy '{}' cost model updated successfully", id),
     }))
 }
 
-/// 전략 복사 (파생 전략 생성).
+/// 전략 정규장 외 거래 허용 변경.
 ///
-/// POST /api/v1/strategies/{id}/clone
-pub async fn clone_strategy(
+/// PUT /api/v1/strategies/{id}/extended-hours
+///
+/// 허용된 전략은 미국 주식 주간거래(KIS 주간 원장), 프리마켓, 애프터마켓에도 주문할 수 있습니다.
+/// 정규장 외 세션에서는 지정가 주문만 받고, 진입 수량은 세션별 비율로 축소됩니다.
+pub async fn update_extended_hours(
     State(state): State<Arc<AppState>>,
-    Path(source_id): Path<String>,
-    Json(request): Json<CloneStrategyRequest>,
-) -> Result<Json<CloneStrategyResponse>, (StatusCode, Json<ApiError>)> {
+    auth: OptionalJwtAuth,
+    Path(id): Path<String>,
+    Json(request): Json<UpdateExtendedHoursRequest>,
+) -> Result<Json<StrategyActionResponse>, (StatusCode, Json<ApiError>)> {
     // DB가 연결된 경우에만 동작
     let pool = state.db_pool.as_ref().ok_or_else(|| {
         (
@@ -1049,47 +1304,607 @@ pub async fn clone_strategy(
             Json(ApiError::new("DB_NOT_CONNECTED", "Database not connected")),
         )
     })?;
+    let before = load_param_snapshot(&state, &id).await;
 
-    // 원본 전략 조회
-    let source = StrategyRepository::get_by_id(pool, &source_id)
+    StrategyRepository::update_extended_hours(pool, &id, request.extended_hours)
         .await
-        .map_err(|e| {
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ApiError::new(
-                    "DB_ERROR",
-                    format!("Failed to get source strategy: {}", e),
-                )),
-            )
-        })?
-        .ok_or_else(|| {
-            (
+        .map_err(|e| match e {
+            sqlx::Error::RowNotFound => (
                 StatusCode::NOT_FOUND,
                 Json(ApiError::new(
                     "STRATEGY_NOT_FOUND",
-                    format!("Strategy '{}' not found", source_id),
+                    format!("Strategy '{}' not found", id),
                 )),
-            )
+            ),
+            e => {
+                tracing::error!("Failed to update extended hours: {:?}", e);
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(ApiError::new(
+                        "DB_ERROR",
+                        format!("Failed to update extended hours: {}", e),
+                    )),
+                )
+            }
         })?;
 
-    // 전략 타입 확인
-    let strategy_type = source
-        .strategy_type
-        .clone()
-        .unwrap_or_else(|| "unknown".to_string());
+    // 실행기 반영
+    state
+        .executor
+        .read()
+        .await
+        .set_strategy_extended_hours(&id, request.extended_hours)
+        .await;
 
-    // 새 전략 ID 생성
-    let new_id = format!("{}_{}", strategy_type, &Uuid::new_v4().to_string()[..8]);
+    // 전략 이름 가져오기 (브로드캐스트용)
+    let engine = state.strategy_engine.read().await;
+    let (strategy_name, is_running) = engine
+        .get_strategy_status(&id)
+        .await
+        .map(|s| (s.name, s.running))
+        .unwrap_or_else(|_| (id.clone(), false));
 
-    // 파라미터 병합 (원본 + 오버라이드)
-    let merged_config = if let Some(override_params) = request.override_params {
-        let mut base = source.config.clone();
-        if let (Some(base_obj), Some(override_obj)) =
-            (base.as_object_mut(), override_params.as_object())
-        {
-            for (key, value) in override_obj {
-                base_obj.insert(key.clone(), value.clone());
-            }
+    record_param_change(
+        &state,
+        &id,
+        "extended_hours",
+        before,
+        resolve_actor(&auth),
+        is_running,
+    )
+    .await;
+
+    // WebSocket 브로드캐스트: 정규장 외 거래 허용 변경 알림
+    state.broadcast(ServerMessage::StrategyUpdate(StrategyUpdateData {
+        strategy_id: id.clone(),
+        name: strategy_name,
+        running: is_running,
+        event: "extended_hours_updated".to_string(),
+        data: Some(serde_json::json!({ "extended_hours": request.extended_hours })),
+        timestamp: Utc::now().timestamp_millis(),
+    }));
+
+    Ok(Json(StrategyActionResponse {
+        success: true,
+        strategy_id: id.clone(),
+        action: "update_extended_hours".to_string(),
+        message: format!("Strategy '{}' extended hours updated successfully", id),
+    }))
+}
+
+/// 전략 실적 발표 필터 변경.
+///
+/// PUT /api/v1/strategies/{id}/earnings-filter
+///
+/// 전략 엔진은 실적 발표 예정일이 `entry_blackout_days`일 이내인 종목의 진입 신호를 차단하고,
+/// `exit_before_days`일 이내로 들어온 보유 포지션에 청산 신호를 생성합니다.
+pub async fn update_earnings_filter(
+    State(state): State<Arc<AppState>>,
+    auth: OptionalJwtAuth,
+    Path(id): Path<String>,
+    Json(request): Json<UpdateEarningsFilterRequest>,
+) -> Result<Json<StrategyActionResponse>, (StatusCode, Json<ApiError>)> {
+    if let Some(filter) = &request.earnings_filter {
+        let days = filter
+            .entry_blackout_days
+            .max(filter.exit_before_days.unwrap_or(0));
+        if days > MAX_EARNINGS_FILTER_DAYS {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                Json(ApiError::new(
+                    "INVALID_EARNINGS_FILTER",
+                    format!(
+                        "Earnings filter days must be at most {}",
+                        MAX_EARNINGS_FILTER_DAYS
+                    ),
+                )),
+            ));
+        }
+    }
+
+    // DB가 연결된 경우에만 동작
+    let pool = state.db_pool.as_ref().ok_or_else(|| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiError::new("DB_NOT_CONNECTED", "Database not connected")),
+        )
+    })?;
+    let before = load_param_snapshot(&state, &id).await;
+
+    let filter_json = request
+        .earnings_filter
+        .as_ref()
+        .and_then(|f| serde_json::to_value(f).ok());
+
+    StrategyRepository::update_earnings_filter(pool, &id, filter_json.clone())
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::RowNotFound => (
+                StatusCode::NOT_FOUND,
+                Json(ApiError::new(
+                    "STRATEGY_NOT_FOUND",
+                    format!("Strategy '{}' not found", id),
+                )),
+            ),
+            e => {
+                tracing::error!("Failed to update earnings filter: {:?}", e);
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(ApiError::new(
+                        "DB_ERROR",
+                        format!("Failed to update earnings filter: {}", e),
+                    )),
+                )
+            }
+        })?;
+
+    // 엔진 반영
+    let engine = state.strategy_engine.read().await;
+    if let Err(e) = engine
+        .set_strategy_earnings_filter(&id, request.earnings_filter)
+        .await
+    {
+        tracing::warn!(strategy_id = %id, error = %e, "Earnings filter not applied to engine");
+    }
+
+    // 전략 이름 가져오기 (브로드캐스트용)
+    let (strategy_name, is_running) = engine
+        .get_strategy_status(&id)
+        .await
+        .map(|s| (s.name, s.running))
+        .unwrap_or_else(|_| (id.clone(), false));
+
+    record_param_change(
+        &state,
+        &id,
+        "earnings_filter",
+        before,
+        resolve_actor(&auth),
+        is_running,
+    )
+    .await;
+
+    // WebSocket 브로드캐스트: 실적 발표 필터 변경 알림
+    state.broadcast(ServerMessage::StrategyUpdate(StrategyUpdateData {
+        strategy_id: id.clone(),
+        name: strategy_name,
+        running: is_running,
+        event: "earnings_filter_updated".to_string(),
+        data: Some(serde_json::json!({ "earnings_filter": filter_json })),
+        timestamp: Utc::now().timestamp_millis(),
+    }));
+
+    Ok(Json(StrategyActionResponse {
+        success: true,
+        strategy_id: id.clone(),
+        action: "update_earnings_filter".to_string(),
+        message: format!("Strategy '{}' earnings filter updated successfully", id),
+    }))
+}
+
+/// 전략 거래 시간대 변경.
+///
+/// PUT /api/v1/strategies/{id}/trading-window
+///
+/// 전략 엔진은 기준 시장 현지 시각이 구간 밖이거나 휴장일(`skip_holidays`)이면 시장 데이터와
+/// 외부 신호를 전략에 전달하지 않으며, `flatten_outside`가 켜져 있으면 보유 포지션에
+/// 청산 신호를 생성합니다.
+pub async fn update_trading_window(
+    State(state): State<Arc<AppState>>,
+    auth: OptionalJwtAuth,
+    Path(id): Path<String>,
+    Json(request): Json<UpdateTradingWindowRequest>,
+) -> Result<Json<StrategyActionResponse>, (StatusCode, Json<ApiError>)> {
+    if let Some(window) = &request.trading_window {
+        window.validate().map_err(|e| {
+            (
+                StatusCode::BAD_REQUEST,
+                Json(ApiError::new("INVALID_TRADING_WINDOW", e)),
+            )
+        })?;
+    }
+
+    // DB가 연결된 경우에만 동작
+    let pool = state.db_pool.as_ref().ok_or_else(|| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiError::new("DB_NOT_CONNECTED", "Database not connected")),
+        )
+    })?;
+    let before = load_param_snapshot(&state, &id).await;
+
+    let window_json = request
+        .trading_window
+        .as_ref()
+        .and_then(|w| serde_json::to_value(w).ok());
+
+    StrategyRepository::update_trading_window(pool, &id, window_json.clone())
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::RowNotFound => (
+                StatusCode::NOT_FOUND,
+                Json(ApiError::new(
+                    "STRATEGY_NOT_FOUND",
+                    format!("Strategy '{}' not found", id),
+                )),
+            ),
+            e => {
+                tracing::error!("Failed to update trading window: {:?}", e);
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(ApiError::new(
+                        "DB_ERROR",
+                        format!("Failed to update trading window: {}", e),
+                    )),
+                )
+            }
+        })?;
+
+    // 엔진 반영
+    let engine = state.strategy_engine.read().await;
+    if let Err(e) = engine
+        .set_strategy_trading_window(&id, request.trading_window)
+        .await
+    {
+        tracing::warn!(strategy_id = %id, error = %e, "Trading window not applied to engine");
+    }
+
+    // 전략 이름 가져오기 (브로드캐스트용)
+    let (strategy_name, is_running) = engine
+        .get_strategy_status(&id)
+        .await
+        .map(|s| (s.name, s.running))
+        .unwrap_or_else(|_| (id.clone(), false));
+
+    record_param_change(
+        &state,
+        &id,
+        "trading_window",
+        before,
+        resolve_actor(&auth),
+        is_running,
+    )
+    .await;
+
+    // WebSocket 브로드캐스트: 거래 시간대 변경 알림
+    state.broadcast(ServerMessage::StrategyUpdate(StrategyUpdateData {
+        strategy_id: id.clone(),
+        name: strategy_name,
+        running: is_running,
+        event: "trading_window_updated".to_string(),
+        data: Some(serde_json::json!({ "trading_window": window_json })),
+        timestamp: Utc::now().timestamp_millis(),
+    }));
+
+    Ok(Json(StrategyActionResponse {
+        success: true,
+        strategy_id: id.clone(),
+        action: "update_trading_window".to_string(),
+        message: format!("Strategy '{}' trading window updated successfully", id),
+    }))
+}
+
+/// 전략 장중 커퓨 오버라이드 변경.
+///
+/// PUT /api/v1/strategies/{id}/curfew
+///
+/// 실행기는 KRX 동시호가, 장 시작 직후 5분, 미국 개장 직후 5분 등 기본 커퓨 구간에
+/// 진입 주문을 차단하거나 수량을 축소합니다. 전략별로 검사를 제외하거나,
+/// 기본 구간 일부를 건너뛰거나, 전략 전용 구간을 추가할 수 있습니다.
+pub async fn update_curfew(
+    State(state): State<Arc<AppState>>,
+    auth: OptionalJwtAuth,
+    Path(id): Path<String>,
+    Json(request): Json<UpdateCurfewRequest>,
+) -> Result<Json<StrategyActionResponse>, (StatusCode, Json<ApiError>)> {
+    if let Some(Err(e)) = request.curfew_override.as_ref().map(CurfewOverride::validate) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiError::new("INVALID_CURFEW_OVERRIDE", e.to_string())),
+        ));
+    }
+
+    // DB가 연결된 경우에만 동작
+    let pool = state.db_pool.as_ref().ok_or_else(|| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiError::new("DB_NOT_CONNECTED", "Database not connected")),
+        )
+    })?;
+    let before = load_param_snapshot(&state, &id).await;
+
+    let curfew_json = request
+        .curfew_override
+        .as_ref()
+        .and_then(|c| serde_json::to_value(c).ok());
+
+    StrategyRepository::update_curfew_override(pool, &id, curfew_json.clone())
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::RowNotFound => (
+                StatusCode::NOT_FOUND,
+                Json(ApiError::new(
+                    "STRATEGY_NOT_FOUND",
+                    format!("Strategy '{}' not found", id),
+                )),
+            ),
+            e => {
+                tracing::error!("Failed to update curfew override: {:?}", e);
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(ApiError::new(
+                        "DB_ERROR",
+                        format!("Failed to update curfew override: {}", e),
+                    )),
+                )
+            }
+        })?;
+
+    // 실행기 반영
+    state
+        .executor
+        .read()
+        .await
+        .set_strategy_curfew(&id, request.curfew_override)
+        .await;
+
+    // 전략 이름 가져오기 (브로드캐스트용)
+    let engine = state.strategy_engine.read().await;
+    let (strategy_name, is_running) = engine
+        .get_strategy_status(&id)
+        .await
+        .map(|s| (s.name, s.running))
+        .unwrap_or_else(|_| (id.clone(), false));
+
+    record_param_change(
+        &state,
+        &id,
+        "curfew_override",
+        before,
+        resolve_actor(&auth),
+        is_running,
+    )
+    .await;
+
+    // WebSocket 브로드캐스트: 커퓨 오버라이드 변경 알림
+    state.broadcast(ServerMessage::StrategyUpdate(StrategyUpdateData {
+        strategy_id: id.clone(),
+        name: strategy_name,
+        running: is_running,
+        event: "curfew_updated".to_string(),
+        data: Some(serde_json::json!({ "curfew_override": curfew_json })),
+        timestamp: Utc::now().timestamp_millis(),
+    }));
+
+    Ok(Json(StrategyActionResponse {
+        success: true,
+        strategy_id: id.clone(),
+        action: "update_curfew".to_string(),
+        message: format!("Strategy '{}' curfew override updated successfully", id),
+    }))
+}
+
+/// 전략 실행 조절 제외 변경.
+///
+/// PUT /api/v1/strategies/{id}/execution-governor
+///
+/// 실행기는 실현 변동성이 급등하거나 호가 스프레드가 벌어진 종목의 진입 주문을
+/// 축소(지정가 오프셋 확대)하거나 보류합니다. 급변 구간에 진입해야 하는 전략은
+/// 조절에서 제외할 수 있습니다.
+pub async fn update_execution_governor(
+    State(state): State<Arc<AppState>>,
+    auth: OptionalJwtAuth,
+    Path(id): Path<String>,
+    Json(request): Json<UpdateExecutionGovernorRequest>,
+) -> Result<Json<StrategyActionResponse>, (StatusCode, Json<ApiError>)> {
+    // DB가 연결된 경우에만 동작
+    let pool = state.db_pool.as_ref().ok_or_else(|| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiError::new("DB_NOT_CONNECTED", "Database not connected")),
+        )
+    })?;
+    let before = load_param_snapshot(&state, &id).await;
+
+    StrategyRepository::update_execution_governor_opt_out(pool, &id, request.opt_out)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::RowNotFound => (
+                StatusCode::NOT_FOUND,
+                Json(ApiError::new(
+                    "STRATEGY_NOT_FOUND",
+                    format!("Strategy '{}' not found", id),
+                )),
+            ),
+            e => {
+                tracing::error!("Failed to update execution governor opt-out: {:?}", e);
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(ApiError::new(
+                        "DB_ERROR",
+                        format!("Failed to update execution governor opt-out: {}", e),
+                    )),
+                )
+            }
+        })?;
+
+    // 실행기 반영
+    state
+        .executor
+        .read()
+        .await
+        .set_strategy_governor_opt_out(&id, request.opt_out)
+        .await;
+
+    // 전략 이름 가져오기 (브로드캐스트용)
+    let engine = state.strategy_engine.read().await;
+    let (strategy_name, is_running) = engine
+        .get_strategy_status(&id)
+        .await
+        .map(|s| (s.name, s.running))
+        .unwrap_or_else(|_| (id.clone(), false));
+
+    record_param_change(
+        &state,
+        &id,
+        "execution_governor",
+        before,
+        resolve_actor(&auth),
+        is_running,
+    )
+    .await;
+
+    // WebSocket 브로드캐스트: 실행 조절 제외 변경 알림
+    state.broadcast(ServerMessage::StrategyUpdate(StrategyUpdateData {
+        strategy_id: id.clone(),
+        name: strategy_name,
+        running: is_running,
+        event: "execution_governor_updated".to_string(),
+        data: Some(serde_json::json!({ "execution_governor_opt_out": request.opt_out })),
+        timestamp: Utc::now().timestamp_millis(),
+    }));
+
+    Ok(Json(StrategyActionResponse {
+        success: true,
+        strategy_id: id.clone(),
+        action: "update_execution_governor".to_string(),
+        message: format!(
+            "Strategy '{}' execution governor opt-out updated successfully",
+            id
+        ),
+    }))
+}
+
+/// 전략 심볼 변경.
+///
+/// PUT /api/v1/strategies/{id}/symbols
+pub async fn update_symbols(
+    State(state): State<Arc<AppState>>,
+    auth: OptionalJwtAuth,
+    Path(id): Path<String>,
+    Json(request): Json<UpdateSymbolsRequest>,
+) -> Result<Json<StrategyActionResponse>, (StatusCode, Json<ApiError>)> {
+    // DB가 연결된 경우에만 동작
+    let pool = state.db_pool.as_ref().ok_or_else(|| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiError::new("DB_NOT_CONNECTED", "Database not connected")),
+        )
+    })?;
+    let before = load_param_snapshot(&state, &id).await;
+
+    // DB에 심볼 업데이트
+    StrategyRepository::update_symbols(pool, &id, request.symbols.clone())
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to update strategy symbols: {:?}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiError::new(
+                    "DB_ERROR",
+                    format!("Failed to update symbols: {}", e),
+                )),
+            )
+        })?;
+
+    // 전략 이름 가져오기 (브로드캐스트용)
+    let engine = state.strategy_engine.read().await;
+    let (strategy_name, is_running) = engine
+        .get_strategy_status(&id)
+        .await
+        .map(|s| (s.name, s.running))
+        .unwrap_or_else(|_| (id.clone(), false));
+
+    record_param_change(
+        &state,
+        &id,
+        "symbols",
+        before,
+        resolve_actor(&auth),
+        is_running,
+    )
+    .await;
+
+    // WebSocket 브로드캐스트: 심볼 변경 알림
+    state.broadcast(ServerMessage::StrategyUpdate(StrategyUpdateData {
+        strategy_id: id.clone(),
+        name: strategy_name,
+        running: is_running,
+        event: "symbols_updated".to_string(),
+        data: Some(serde_json::json!({
+            "symbols": request.symbols,
+        })),
+        timestamp: Utc::now().timestamp_millis(),
+    }));
+
+    Ok(Json(StrategyActionResponse {
+        success: true,
+        strategy_id: id.clone(),
+        action: "update_symbols".to_string(),
+        message: format!("Strategy '{}' symbols updated successfully", id),
+    }))
+}
+
+/// 전략 복사 (파생 전략 생성).
+///
+/// POST /api/v1/strategies/{id}/clone
+pub async fn clone_strategy(
+    State(state): State<Arc<AppState>>,
+    auth: OptionalJwtAuth,
+    Path(source_id): Path<String>,
+    Json(request): Json<CloneStrategyRequest>,
+) -> Result<Json<CloneStrategyResponse>, (StatusCode, Json<ApiError>)> {
+    // DB가 연결된 경우에만 동작
+    let pool = state.db_pool.as_ref().ok_or_else(|| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiError::new("DB_NOT_CONNECTED", "Database not connected")),
+        )
+    })?;
+
+    // 사용자별 등록 전략 수 할당량
+    let owner_id = ensure_strategy_quota(&state, &auth).await?;
+
+    // 원본 전략 조회
+    let source = StrategyRepository::get_by_id(pool, &source_id)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiError::new(
+                    "DB_ERROR",
+                    format!("Failed to get source strategy: {}", e),
+                )),
+            )
+        })?
+        .ok_or_else(|| {
+            (
+                StatusCode::NOT_FOUND,
+                Json(ApiError::new(
+                    "STRATEGY_NOT_FOUND",
+                    format!("Strategy '{}' not found", source_id),
+                )),
+            )
+        })?;
+
+    // 전략 타입 확인
+    let strategy_type = source
+        .strategy_type
+        .clone()
+        .unwrap_or_else(|| "unknown".to_string());
+
+    // 새 전략 ID 생성
+    let new_id = format!("{}_{}", strategy_type, &Uuid::new_v4().to_string()[..8]);
+
+    // 파라미터 병합 (원본 + 오버라이드)
+    let merged_config = if let Some(override_params) = request.override_params {
+        let mut base = source.config.clone();
+        if let (Some(base_obj), Some(override_obj)) =
+            (base.as_object_mut(), override_params.as_object())
+        {
+            for (key, value) in override_obj {
+                base_obj.insert(key.clone(), value.clone());
+            }
         }
         base
     } else {
@@ -1119,6 +1934,12 @@ pub async fn clone_strategy(
         })
         .unwrap_or_default();
 
+    // 종목 잠금 설정 (원본 설정 유지)
+    let symbol_lock: Option<SymbolLockConfig> = source
+        .symbol_lock
+        .clone()
+        .and_then(|value| serde_json::from_value(value).ok());
+
     // 새 전략 생성
     let input = CreateStrategyInput {
         id: new_id.clone(),
@@ -1133,6 +1954,8 @@ pub async fn clone_strategy(
         allocated_capital,
         risk_profile: source.risk_profile.clone(),
         multi_timeframe_config: source.multi_timeframe_config.clone(),
+        symbol_lock,
+        owner_id,
     };
 
     StrategyRepository::create(pool, input).await.map_err(|e| {
@@ -1150,11 +1973,12 @@ pub async fn clone_strategy(
     if let Ok(strategy) = create_strategy_instance(&strategy_type) {
         let engine = state.strategy_engine.read().await;
         let _ = engine
-            .register_strategy(
+            .register_strategy_with_lock(
                 &new_id,
                 strategy,
                 merged_config,
                 Some(request.new_name.clone()),
+                symbol_lock,
             )
             .await;
     }
@@ -1284,6 +2108,7 @@ pub async fn get_strategy_timeframes(
 /// PUT /api/v1/strategies/{id}/timeframes
 pub async fn update_strategy_timeframes(
     State(state): State<Arc<AppState>>,
+    auth: OptionalJwtAuth,
     Path(id): Path<String>,
     Json(request): Json<UpdateTimeframeConfigRequest>,
 ) -> Result<Json<TimeframeConfigResponse>, (StatusCode, Json<ApiError>)> {
@@ -1340,6 +2165,8 @@ pub async fn update_strategy_timeframes(
         }
     }
 
+    let before = load_param_snapshot(&state, &id).await;
+
     // DB 업데이트
     let record = sqlx::query_as::<_, crate::repository::strategies::StrategyRecord>(
         r#"
@@ -1386,6 +2213,24 @@ pub async fn update_strategy_timeframes(
         is_multi_tf
     );
 
+    let is_running = state
+        .strategy_engine
+        .read()
+        .await
+        .get_strategy_status(&id)
+        .await
+        .map(|s| s.running)
+        .unwrap_or(false);
+    record_param_change(
+        &state,
+        &id,
+        "timeframes",
+        before,
+        resolve_actor(&auth),
+        is_running,
+    )
+    .await;
+
     Ok(Json(TimeframeConfigResponse {
         strategy_id: id,
         primary_timeframe: record.timeframe.unwrap_or_else(|| "1d".to_string()),
@@ -1399,7 +2244,12 @@ pub async fn update_strategy_timeframes(
 
 /// 전략 관리 라우터 생성.
 pub fn strategies_router() -> Router<Arc<AppState>> {
+    use super::paper_trading::{get_paper_trading, update_paper_trading};
     use super::schema::{get_strategy_schema, list_strategy_meta};
+    use super::strategy_history::get_strategy_history;
+    use super::strategy_performance::get_strategy_performance;
+    use super::strategy_promotion::get_strategy_promotion;
+    use super::strategy_recommend::recommend_strategies;
 
     Router::new()
         // 목록, 생성, 통계
@@ -1407,18 +2257,36 @@ pub fn strategies_router() -> Router<Arc<AppState>> {
         .route("/stats", get(get_engine_stats))
         // 전략 메타데이터 (SDUI 스키마용)
         .route("/meta", get(list_strategy_meta))
+        // 종목별 추천 전략 (백테스트 적합도 순)
+        .route("/recommend", get(recommend_strategies))
         // 개별 전략 조작
         .route("/{id}", get(get_strategy).delete(delete_strategy))
         .route("/{id}/start", post(start_strategy))
         .route("/{id}/stop", post(stop_strategy))
+        .route("/{id}/pause", post(pause_strategy))
+        .route("/{id}/resume", post(resume_strategy))
         .route("/{id}/config", put(update_config))
         .route("/{id}/risk", put(update_risk_settings))
+        .route("/{id}/cost-model", put(update_cost_model))
+        .route("/{id}/extended-hours", put(update_extended_hours))
+        .route("/{id}/earnings-filter", put(update_earnings_filter))
+        .route("/{id}/trading-window", put(update_trading_window))
+        .route("/{id}/curfew", put(update_curfew))
+        .route("/{id}/execution-governor", put(update_execution_governor))
         .route("/{id}/symbols", put(update_symbols))
         .route("/{id}/clone", post(clone_strategy))
         // 전략 스키마 (SDUI)
         .route("/{id}/schema", get(get_strategy_schema))
         // 다중 타임프레임 설정
         .route("/{id}/timeframes", get(get_strategy_timeframes).put(update_strategy_timeframes))
+        // 파라미터 변경 이력
+        .route("/{id}/history", get(get_strategy_history))
+        // 원본 백테스트 대비 실거래 성과
+        .route("/{id}/promotion", get(get_strategy_promotion))
+        // 전략 가상 계좌 성과 (자본, 손익, 익스포저, 낙폭)
+        .route("/{id}/performance", get(get_strategy_performance))
+        // 실시간 시세 기반 모의투자 (시뮬레이션 거래소 가상 계좌)
+        .route("/{id}/paper-trading", get(get_paper_trading).put(update_paper_trading))
 }
 
 // ==================== 테스트 ====================
@@ -1463,6 +2331,32 @@ mod tests {
         assert!(list.strategies.is_empty());
     }
 
+    #[tokio::test]
+    async fn test_update_cost_model_rejects_invalid_rate() {
+        use crate::state::create_test_state;
+
+        let state = Arc::new(create_test_state());
+        let app = Router::new()
+            .route("/strategies/{id}/cost-model", put(update_cost_model))
+            .with_state(state);
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("PUT")
+                    .uri("/strategies/rsi_1/cost-model")
+                    .header("content-type", "application/json")
+                    .body(Body::from(
+                        r#"{"cost_model": {"type": "kr_stock", "transaction_tax_rate": 0.5}}"#,
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
     #[tokio::test]
     async fn test_get_strategy_not_found() {
         use crate::state::create_test_state;
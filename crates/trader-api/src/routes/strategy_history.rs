@@ -0,0 +1,481 @@
+//! 전략 파라미터 변경 이력 endpoint.
+//!
+//! 실행 중인 전략의 파라미터가 바뀔 때마다 기록된 변경 이력(변경자, 변경 전후 값)을
+//! 일별 실현 손익 시계열과 함께 제공합니다. 손익 시계열에는 변경 시점 마커가 붙고,
+//! 각 변경에는 변경 전후 구간의 성과 비교가 포함되어 조정 효과를 확인할 수 있습니다.
+//!
+//! # 엔드포인트
+//!
+//! - `GET /api/v1/strategies/{id}/history` - 파라미터 변경 이력 및 손익 시계열
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    Json,
+};
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate, Utc};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use super::common::{db_error_response, require_pool};
+use crate::auth::OptionalJwtAuth;
+use crate::error::{ApiErrorResponse, ApiResult};
+use crate::repository::{
+    diff_params, param_snapshot, StrategyDailyPnl, StrategyHistoryRepository,
+    StrategyParamChangeInput, StrategyParamChangeRecord, StrategyRepository,
+};
+use crate::state::AppState;
+
+/// 변경 전후 비교 기본 구간 (일).
+const DEFAULT_WINDOW_DAYS: i64 = 14;
+/// 변경 전후 비교 최대 구간 (일).
+const MAX_WINDOW_DAYS: i64 = 180;
+
+// ==================== 요청/응답 타입 ====================
+
+/// 이력 조회 쿼리.
+#[derive(Debug, Default, Deserialize)]
+pub struct StrategyHistoryQuery {
+    /// 조회 기간 (일, 미지정 시 전체 기간)
+    pub days: Option<i64>,
+    /// 변경 전후 성과 비교 구간 (일, 기본 14일)
+    pub window_days: Option<i64>,
+}
+
+/// 구간 성과.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct WindowPerformance {
+    /// 구간 시작일 (포함)
+    pub from: NaiveDate,
+    /// 구간 종료일 (미포함)
+    pub to: NaiveDate,
+    /// 구간 일수
+    pub days: i64,
+    /// 실현 손익 합계
+    pub realized_pnl: Decimal,
+    /// 일평균 실현 손익
+    pub avg_daily_pnl: Decimal,
+    /// 청산 체결 수
+    pub closed_trades: i64,
+    /// 승률 (%, 청산 체결이 없으면 None)
+    pub win_rate: Option<f64>,
+}
+
+/// 변경 전후 성과 비교.
+#[derive(Debug, Clone, Serialize)]
+pub struct ChangeImpact {
+    /// 변경 직전 구간 (이전 변경 이후부터)
+    pub before: WindowPerformance,
+    /// 변경 직후 구간 (다음 변경 전까지)
+    pub after: WindowPerformance,
+    /// 일평균 실현 손익 변화 (after - before)
+    pub avg_daily_pnl_delta: Decimal,
+}
+
+/// 파라미터 변경 항목.
+#[derive(Debug, Clone, Serialize)]
+pub struct ParamChangeEntry {
+    pub id: Uuid,
+    pub change_type: String,
+    pub changed_by: String,
+    pub changed_at: DateTime<Utc>,
+    /// 변경 시점 전략 실행 여부
+    pub strategy_running: bool,
+    /// 변경 내역 ([{key, old, new}])
+    pub changes: Value,
+    /// 변경 전후 성과 비교
+    pub impact: ChangeImpact,
+}
+
+/// 일별 손익 시계열 포인트.
+#[derive(Debug, Clone, Serialize)]
+pub struct PnlPoint {
+    pub date: NaiveDate,
+    /// 당일 실현 손익
+    pub realized_pnl: Decimal,
+    /// 누적 실현 손익
+    pub cumulative_pnl: Decimal,
+    /// 당일 발생한 파라미터 변경 ID (변경 마커)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub change_ids: Vec<Uuid>,
+}
+
+/// 이력 조회 응답.
+#[derive(Debug, Serialize)]
+pub struct StrategyHistoryResponse {
+    pub strategy_id: String,
+    pub window_days: i64,
+    pub changes: Vec<ParamChangeEntry>,
+    pub pnl_series: Vec<PnlPoint>,
+}
+
+// ==================== 변경 기록 ====================
+
+/// 변경자 결정 (JWT 사용자명 → "api").
+pub(crate) fn resolve_actor(auth: &OptionalJwtAuth) -> String {
+    auth.0
+        .as_ref()
+        .map(|claims| claims.username.clone())
+        .unwrap_or_else(|| "api".to_string())
+}
+
+/// 변경 전 파라미터 스냅샷 조회.
+///
+/// DB 미연결이거나 전략이 없으면 None을 반환하며, 이 경우 변경은 기록되지 않습니다.
+pub(crate) async fn load_param_snapshot(state: &AppState, strategy_id: &str) -> Option<Value> {
+    let pool = state.db_pool.as_ref()?;
+    match StrategyRepository::get_by_id(pool, strategy_id).await {
+        Ok(record) => record.as_ref().map(param_snapshot),
+        Err(e) => {
+            tracing::warn!(strategy_id = %strategy_id, error = %e, "Failed to load strategy params");
+            None
+        }
+    }
+}
+
+/// 파라미터 변경 기록.
+///
+/// 변경 후 스냅샷을 다시 읽어 변경 전 스냅샷과 비교하고, 실제로 바뀐 값이 있을 때만 저장합니다.
+/// 기록 실패는 변경 자체를 되돌리지 않으므로 경고 로그만 남깁니다.
+pub(crate) async fn record_param_change(
+    state: &AppState,
+    strategy_id: &str,
+    change_type: &str,
+    before: Option<Value>,
+    changed_by: String,
+    strategy_running: bool,
+) {
+    let (Some(pool), Some(old_params)) = (state.db_pool.as_ref(), before) else {
+        return;
+    };
+    let Some(new_params) = load_param_snapshot(state, strategy_id).await else {
+        return;
+    };
+
+    let changes = diff_params(&old_params, &new_params);
+    if changes.is_empty() {
+        return;
+    }
+
+    let input = StrategyParamChangeInput {
+        strategy_id: strategy_id.to_string(),
+        change_type: change_type.to_string(),
+        changed_by,
+        old_params,
+        new_params,
+        changes,
+        strategy_running,
+    };
+    if let Err(e) = StrategyHistoryRepository::insert(pool, &input).await {
+        tracing::warn!(strategy_id = %strategy_id, error = %e, "Failed to record strategy param change");
+    }
+}
+
+// ==================== 성과 계산 ====================
+
+/// 한국 시간 기준 날짜.
+fn kst_date(at: DateTime<Utc>) -> NaiveDate {
+    let kst = FixedOffset::east_opt(9 * 3600).expect("valid offset");
+    at.with_timezone(&kst).date_naive()
+}
+
+/// `[from, to)` 구간 성과 계산.
+fn window_performance(
+    daily: &[StrategyDailyPnl],
+    from: NaiveDate,
+    to: NaiveDate,
+) -> WindowPerformance {
+    let days = (to - from).num_days().max(0);
+    let mut realized_pnl = Decimal::ZERO;
+    let mut closed_trades = 0;
+    let mut winning_trades = 0;
+    for day in daily
+        .iter()
+        .filter(|d| d.trade_date >= from && d.trade_date < to)
+    {
+        realized_pnl += day.realized_pnl;
+        closed_trades += day.closed_trades;
+        winning_trades += day.winning_trades;
+    }
+
+    let avg_daily_pnl = if days > 0 {
+        (realized_pnl / Decimal::from(days)).round_dp(2)
+    } else {
+        Decimal::ZERO
+    };
+    let win_rate = (closed_trades > 0).then(|| {
+        (Decimal::from(winning_trades * 100) / Decimal::from(closed_trades))
+            .round_dp(2)
+            .to_f64()
+            .unwrap_or(0.0)
+    });
+
+    WindowPerformance {
+        from,
+        to,
+        days,
+        realized_pnl,
+        avg_daily_pnl,
+        closed_trades,
+        win_rate,
+    }
+}
+
+/// 변경마다 전후 구간 성과 비교.
+///
+/// 전후 구간은 `window_days`일이지만 인접한 변경을 넘지 않도록 잘라,
+/// 한 구간에 두 변경의 효과가 섞이지 않게 합니다. 직후 구간은 오늘(포함)까지입니다.
+fn annotate_changes(
+    changes: &[StrategyParamChangeRecord],
+    daily: &[StrategyDailyPnl],
+    window_days: i64,
+    today: NaiveDate,
+) -> Vec<ParamChangeEntry> {
+    let dates: Vec<NaiveDate> = changes.iter().map(|c| kst_date(c.changed_at)).collect();
+    let tomorrow = today + Duration::days(1);
+
+    changes
+        .iter()
+        .enumerate()
+        .map(|(i, change)| {
+            let date = dates[i];
+
+            let mut before_from = date - Duration::days(window_days);
+            if let Some(prev) = i.checked_sub(1).map(|p| dates[p]) {
+                before_from = before_from.max(prev);
+            }
+            let mut after_to = (date + Duration::days(window_days)).min(tomorrow);
+            if let Some(next) = dates.get(i + 1) {
+                after_to = after_to.min(*next);
+            }
+
+            let before = window_performance(daily, before_from, date);
+            let after = window_performance(daily, date, after_to.max(date));
+            let avg_daily_pnl_delta = after.avg_daily_pnl - before.avg_daily_pnl;
+
+            ParamChangeEntry {
+                id: change.id,
+                change_type: change.change_type.clone(),
+                changed_by: change.changed_by.clone(),
+                changed_at: change.changed_at,
+                strategy_running: change.strategy_running,
+                changes: change.changes.clone(),
+                impact: ChangeImpact {
+                    before,
+                    after,
+                    avg_daily_pnl_delta,
+                },
+            }
+        })
+        .collect()
+}
+
+/// 누적 손익 시계열에 변경 마커 부착.
+///
+/// 거래가 없던 날의 변경도 보이도록 변경일은 손익 0인 포인트로 추가됩니다.
+fn build_pnl_series(
+    daily: &[StrategyDailyPnl],
+    changes: &[StrategyParamChangeRecord],
+) -> Vec<PnlPoint> {
+    let mut dates: Vec<NaiveDate> = daily
+        .iter()
+        .map(|d| d.trade_date)
+        .chain(changes.iter().map(|c| kst_date(c.changed_at)))
+        .collect();
+    dates.sort();
+    dates.dedup();
+
+    let mut cumulative = Decimal::ZERO;
+    dates
+        .into_iter()
+        .map(|date| {
+            let realized_pnl = daily
+                .iter()
+                .filter(|d| d.trade_date == date)
+                .map(|d| d.realized_pnl)
+                .sum::<Decimal>();
+            cumulative += realized_pnl;
+            PnlPoint {
+                date,
+                realized_pnl,
+                cumulative_pnl: cumulative,
+                change_ids: changes
+                    .iter()
+                    .filter(|c| kst_date(c.changed_at) == date)
+                    .map(|c| c.id)
+                    .collect(),
+            }
+        })
+        .collect()
+}
+
+// ==================== 핸들러 ====================
+
+/// 전략 파라미터 변경 이력 조회.
+///
+/// GET /api/v1/strategies/{id}/history
+pub async fn get_strategy_history(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+    Query(query): Query<StrategyHistoryQuery>,
+) -> ApiResult<Json<StrategyHistoryResponse>> {
+    let window_days = query.window_days.unwrap_or(DEFAULT_WINDOW_DAYS);
+    if !(1..=MAX_WINDOW_DAYS).contains(&window_days) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiErrorResponse::new(
+                "INVALID_WINDOW",
+                format!("window_days must be between 1 and {}", MAX_WINDOW_DAYS),
+            )),
+        ));
+    }
+
+    let pool = require_pool(&state)?;
+    let exists = StrategyRepository::exists(pool, &id)
+        .await
+        .map_err(db_error_response)?;
+    if !exists {
+        return Err((
+            StatusCode::NOT_FOUND,
+            Json(ApiErrorResponse::new(
+                "STRATEGY_NOT_FOUND",
+                format!("Strategy '{}' not found", id),
+            )),
+        ));
+    }
+
+    let since = query
+        .days
+        .filter(|days| *days > 0)
+        .map(|days| Utc::now() - Duration::days(days));
+
+    let changes = StrategyHistoryRepository::list(pool, &id, since)
+        .await
+        .map_err(db_error_response)?;
+    // 첫 변경의 직전 구간까지 포함하도록 손익은 비교 구간만큼 더 앞에서부터 조회
+    let pnl_since = since.map(|s| s - Duration::days(window_days));
+    let daily = StrategyHistoryRepository::daily_realized_pnl(pool, &id, pnl_since)
+        .await
+        .map_err(db_error_response)?;
+
+    let entries = annotate_changes(&changes, &daily, window_days, kst_date(Utc::now()));
+    let series_start = since.map(kst_date);
+    let pnl_series = build_pnl_series(&daily, &changes)
+        .into_iter()
+        .filter(|p| series_start.map_or(true, |start| p.date >= start))
+        .collect();
+
+    Ok(Json(StrategyHistoryResponse {
+        strategy_id: id,
+        window_days,
+        changes: entries,
+        pnl_series,
+    }))
+}
+
+// ==================== 테스트 ====================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use rust_decimal_macros::dec;
+    use serde_json::json;
+
+    fn day(y: i32, m: u32, d: u32, pnl: Decimal, closed: i64, wins: i64) -> StrategyDailyPnl {
+        StrategyDailyPnl {
+            trade_date: NaiveDate::from_ymd_opt(y, m, d).unwrap(),
+            realized_pnl: pnl,
+            closed_trades: closed,
+            winning_trades: wins,
+        }
+    }
+
+    fn change(y: i32, m: u32, d: u32) -> StrategyParamChangeRecord {
+        StrategyParamChangeRecord {
+            id: Uuid::new_v4(),
+            strategy_id: "rsi_1".to_string(),
+            change_type: "config".to_string(),
+            changed_by: "tester".to_string(),
+            old_params: json!({"config": {"period": 14}}),
+            new_params: json!({"config": {"period": 20}}),
+            changes: json!([{"key": "config.period", "old": 14, "new": 20}]),
+            strategy_running: true,
+            // 03:00 UTC = 12:00 KST (같은 날짜)
+            changed_at: Utc.with_ymd_and_hms(y, m, d, 3, 0, 0).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_annotate_changes_compares_windows() {
+        let daily = vec![
+            day(2026, 3, 1, dec!(-100), 2, 0),
+            day(2026, 3, 3, dec!(-40), 1, 0),
+            day(2026, 3, 5, dec!(300), 3, 2),
+            day(2026, 3, 7, dec!(60), 1, 1),
+        ];
+        let changes = vec![change(2026, 3, 5)];
+        let today = NaiveDate::from_ymd_opt(2026, 3, 8).unwrap();
+
+        let entries = annotate_changes(&changes, &daily, 4, today);
+        let impact = &entries[0].impact;
+
+        // 직전 구간: 3/1 ~ 3/4 (4일)
+        assert_eq!(impact.before.days, 4);
+        assert_eq!(impact.before.realized_pnl, dec!(-140));
+        assert_eq!(impact.before.avg_daily_pnl, dec!(-35));
+        assert_eq!(impact.before.win_rate, Some(0.0));
+        // 직후 구간: 3/5 ~ 3/8 (오늘 포함 4일)
+        assert_eq!(impact.after.days, 4);
+        assert_eq!(impact.after.realized_pnl, dec!(360));
+        assert_eq!(impact.after.win_rate, Some(75.0));
+        assert_eq!(impact.avg_daily_pnl_delta, dec!(125));
+    }
+
+    #[test]
+    fn test_windows_do_not_cross_adjacent_changes() {
+        let daily = vec![
+            day(2026, 3, 2, dec!(50), 1, 1),
+            day(2026, 3, 4, dec!(70), 1, 1),
+        ];
+        let changes = vec![change(2026, 3, 1), change(2026, 3, 3)];
+        let today = NaiveDate::from_ymd_opt(2026, 3, 20).unwrap();
+
+        let entries = annotate_changes(&changes, &daily, 14, today);
+
+        // 첫 변경의 직후 구간은 두 번째 변경 전날까지
+        assert_eq!(
+            entries[0].impact.after.to,
+            NaiveDate::from_ymd_opt(2026, 3, 3).unwrap()
+        );
+        assert_eq!(entries[0].impact.after.realized_pnl, dec!(50));
+        // 두 번째 변경의 직전 구간은 첫 변경일부터
+        assert_eq!(
+            entries[1].impact.before.from,
+            NaiveDate::from_ymd_opt(2026, 3, 1).unwrap()
+        );
+        assert_eq!(entries[1].impact.after.realized_pnl, dec!(70));
+        assert_eq!(entries[1].impact.after.win_rate, Some(100.0));
+    }
+
+    #[test]
+    fn test_pnl_series_marks_change_days() {
+        let daily = vec![
+            day(2026, 3, 1, dec!(100), 1, 1),
+            day(2026, 3, 3, dec!(-30), 1, 0),
+        ];
+        let changes = vec![change(2026, 3, 2)];
+
+        let series = build_pnl_series(&daily, &changes);
+
+        assert_eq!(series.len(), 3);
+        assert_eq!(series[1].realized_pnl, Decimal::ZERO);
+        assert_eq!(series[1].change_ids, vec![changes[0].id]);
+        assert_eq!(series[2].cumulative_pnl, dec!(70));
+        assert!(series[2].change_ids.is_empty());
+    }
+}
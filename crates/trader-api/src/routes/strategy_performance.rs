@@ -0,0 +1,204 @@
+//! 전략별 가상 계좌 성과 endpoint.
+//!
+//! 실행기는 주문과 체결을 원 전략 ID로 귀속시켜 전략마다 가상 계좌(할당 자본, 손익,
+//! 익스포저, 낙폭)를 유지합니다. 이 endpoint는 현재 가상 계좌 상태와
+//! `strategy_equity_history`에 저장된 자산 곡선을 함께 반환합니다.
+//!
+//! # 엔드포인트
+//!
+//! - `GET /api/v1/strategies/{id}/performance` - 전략 가상 계좌 성과
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    Json,
+};
+use chrono::{DateTime, Duration, Utc};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use trader_execution::StrategyPortfolioSnapshot;
+
+use crate::error::{ApiErrorResponse, ApiResult};
+use crate::repository::{EquityHistoryRepository, StrategyEquityRecord};
+use crate::state::AppState;
+
+/// 기본 자산 곡선 조회 기간 (일).
+const DEFAULT_LOOKBACK_DAYS: i64 = 30;
+
+/// 성과 조회 쿼리.
+#[derive(Debug, Default, Deserialize)]
+pub struct StrategyPerformanceQuery {
+    /// 자산 곡선 시작 시각 (기본: 30일 전)
+    #[serde(default)]
+    pub from: Option<DateTime<Utc>>,
+    /// 자산 곡선 종료 시각 (기본: 현재)
+    #[serde(default)]
+    pub to: Option<DateTime<Utc>>,
+}
+
+/// 자산 곡선 기간 요약.
+#[derive(Debug, Clone, Default, Serialize, PartialEq)]
+pub struct EquityCurveSummary {
+    pub points: usize,
+    pub start_equity: Option<Decimal>,
+    pub end_equity: Option<Decimal>,
+    /// 기간 손익 (자본 입출금 제외)
+    pub period_pnl: Decimal,
+    /// 기간 손익 / 시작 시점 할당 자본 (%)
+    pub period_return_pct: Option<f64>,
+    /// 기간 중 최대 낙폭 (%)
+    pub max_drawdown_pct: f64,
+}
+
+/// 전략 성과 응답.
+#[derive(Debug, Serialize)]
+pub struct StrategyPerformanceResponse {
+    pub strategy_id: String,
+    /// 현재 가상 계좌 상태 (체결 이력도 자본 할당도 없으면 `None`)
+    pub portfolio: Option<StrategyPortfolioSnapshot>,
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    pub summary: EquityCurveSummary,
+    pub equity_curve: Vec<StrategyEquityRecord>,
+}
+
+/// 자산 곡선 요약 계산.
+///
+/// 할당 자본 변경이 손익으로 잡히지 않도록 `equity - capital` 변화량으로 기간 손익을 계산합니다.
+fn summarize_curve(curve: &[StrategyEquityRecord]) -> EquityCurveSummary {
+    let (Some(first), Some(last)) = (curve.first(), curve.last()) else {
+        return EquityCurveSummary::default();
+    };
+
+    let period_pnl = (last.equity - last.capital) - (first.equity - first.capital);
+    let period_return_pct = if first.capital > Decimal::ZERO {
+        (period_pnl / first.capital * Decimal::from(100)).to_f64()
+    } else {
+        None
+    };
+    let max_drawdown_pct = curve
+        .iter()
+        .map(|point| point.drawdown_pct)
+        .fold(0.0, f64::max);
+
+    EquityCurveSummary {
+        points: curve.len(),
+        start_equity: Some(first.equity),
+        end_equity: Some(last.equity),
+        period_pnl,
+        period_return_pct,
+        max_drawdown_pct,
+    }
+}
+
+/// 전략 가상 계좌 성과 조회.
+///
+/// GET /api/v1/strategies/{id}/performance
+pub async fn get_strategy_performance(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+    Query(query): Query<StrategyPerformanceQuery>,
+) -> ApiResult<Json<StrategyPerformanceResponse>> {
+    let to = query.to.unwrap_or_else(Utc::now);
+    let from = query
+        .from
+        .unwrap_or_else(|| to - Duration::days(DEFAULT_LOOKBACK_DAYS));
+    if from > to {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiErrorResponse::new(
+                "INVALID_RANGE",
+                "'from' must not be after 'to'",
+            )),
+        ));
+    }
+
+    let portfolio = {
+        let executor = state.executor.read().await;
+        executor.strategy_portfolio(&id).await
+    };
+    if portfolio.is_none() {
+        let engine = state.strategy_engine.read().await;
+        if engine.get_strategy_status(&id).await.is_err() {
+            return Err((
+                StatusCode::NOT_FOUND,
+                Json(ApiErrorResponse::new(
+                    "STRATEGY_NOT_FOUND",
+                    format!("Strategy '{}' not found", id),
+                )),
+            ));
+        }
+    }
+
+    let equity_curve = match &state.db_pool {
+        Some(pool) => EquityHistoryRepository::get_strategy_equity_curve(pool, &id, from, to)
+            .await
+            .map_err(|e| {
+                tracing::error!("Strategy equity curve query failed: {}", e);
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(ApiErrorResponse::new(
+                        "DATABASE_ERROR",
+                        format!("Database error: {}", e),
+                    )),
+                )
+            })?,
+        None => Vec::new(),
+    };
+
+    Ok(Json(StrategyPerformanceResponse {
+        strategy_id: id,
+        portfolio,
+        from,
+        to,
+        summary: summarize_curve(&equity_curve),
+        equity_curve,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use rust_decimal_macros::dec;
+
+    fn point(
+        hour: u32,
+        capital: Decimal,
+        equity: Decimal,
+        drawdown_pct: f64,
+    ) -> StrategyEquityRecord {
+        StrategyEquityRecord {
+            strategy_id: "rsi_1".to_string(),
+            snapshot_time: Utc.with_ymd_and_hms(2026, 3, 10, hour, 0, 0).unwrap(),
+            capital,
+            equity,
+            realized_pnl: Decimal::ZERO,
+            unrealized_pnl: Decimal::ZERO,
+            fees: Decimal::ZERO,
+            exposure: Decimal::ZERO,
+            drawdown_pct,
+            open_positions: 0,
+        }
+    }
+
+    #[test]
+    fn test_summarize_curve_excludes_capital_changes() {
+        assert_eq!(summarize_curve(&[]), EquityCurveSummary::default());
+
+        // 1,000 → 1,100 (+100), 자본 500 증액 후 1,550 (-50)
+        let curve = vec![
+            point(1, dec!(1000), dec!(1000), 0.0),
+            point(2, dec!(1000), dec!(1100), 0.0),
+            point(3, dec!(1500), dec!(1550), 3.2),
+        ];
+        let summary = summarize_curve(&curve);
+        assert_eq!(summary.points, 3);
+        assert_eq!(summary.period_pnl, dec!(50));
+        assert_eq!(summary.period_return_pct, Some(5.0));
+        assert_eq!(summary.max_drawdown_pct, 3.2);
+        assert_eq!(summary.end_equity, Some(dec!(1550)));
+    }
+}
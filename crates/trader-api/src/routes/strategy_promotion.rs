@@ -0,0 +1,1005 @@
+//! 백테스트 → 라이브 전략 승격 endpoint.
+//!
+//! 저장된 백테스트 결과를 같은 파라미터의 라이브 전략으로 승격합니다. 승격 전에 다음을 검사합니다:
+//! - 데이터 커버리지: 백테스트 기간의 일봉이 DB에 충분히 있는지 (DB 데이터가 없으면 백테스트는
+//!   합성 샘플 데이터로 실행되므로 결과를 신뢰할 수 없음)
+//! - 리스크 준수: 백테스트 성공 여부, 최소 거래 수, 최대 낙폭 한도, 리스크 설정 유효성
+//!
+//! 차단 검사를 모두 통과하면 전략을 중지 상태로 등록하고, 원본 백테스트와의 연결(검사 결과,
+//! 백테스트 성과 지표 스냅샷 포함)을 기록해 이후 실거래 성과와 비교합니다.
+//!
+//! # 엔드포인트
+//!
+//! - `POST /api/v1/backtest/results/{id}/promote` - 백테스트 결과 승격 (`dry_run`이면 검사만)
+//! - `GET /api/v1/strategies/{id}/promotion` - 원본 백테스트 대비 실거래 성과 비교
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+use chrono::{DateTime, Datelike, Duration, NaiveDate, TimeZone, Utc, Weekday};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::sync::Arc;
+use trader_risk::RiskConfig;
+use trader_strategy::StrategyRegistry;
+use uuid::Uuid;
+
+use super::common::{db_error_response, require_pool};
+use super::strategies::{ensure_strategy_quota, preset_risk_profile};
+use super::strategy_history::resolve_actor;
+use crate::auth::OptionalJwtAuth;
+use crate::error::{ApiErrorResponse, ApiResult};
+use crate::repository::{
+    strategies::CreateStrategyInput, BacktestResultRecord, BacktestResultsRepository,
+    CandleCoverage, StrategyHistoryRepository, StrategyPromotionInput, StrategyPromotionRecord,
+    StrategyPromotionRepository, StrategyRepository,
+};
+use crate::state::AppState;
+use crate::websocket::{ServerMessage, StrategyUpdateData};
+
+/// 백테스트가 사용하는 타임프레임 (로더는 일봉 기준).
+const BACKTEST_TIMEFRAME: &str = "1d";
+/// 기본 최소 데이터 커버리지 (%, 휴장일 여유 포함).
+const DEFAULT_MIN_COVERAGE_PCT: f64 = 90.0;
+/// 기본 최소 거래 수.
+const DEFAULT_MIN_TRADES: u64 = 1;
+/// 최신 캔들이 이보다 오래되면 경고 (일).
+const MAX_DATA_STALENESS_DAYS: i64 = 7;
+
+// ==================== 요청/응답 타입 ====================
+
+/// 승격 요청.
+#[derive(Debug, Default, Deserialize)]
+pub struct PromoteBacktestRequest {
+    /// 라이브 전략 이름 (기본: "<전략 이름> (backtest <ID 앞 8자>)")
+    #[serde(default)]
+    pub name: Option<String>,
+    /// 파라미터 오버라이드 (백테스트 파라미터 위에 병합)
+    #[serde(default)]
+    pub override_params: Option<Value>,
+    /// 리스크 프로필 (conservative, default, aggressive, custom)
+    #[serde(default)]
+    pub risk_profile: Option<String>,
+    /// 리스크 설정 (custom 프로필, RiskConfig 형식)
+    #[serde(default)]
+    pub risk_config: Option<Value>,
+    /// 할당 자본 (기본: 원본 전략 설정)
+    #[serde(default)]
+    pub allocated_capital: Option<f64>,
+    /// 허용 최대 낙폭 (%, 기본: 리스크 프로필별 15/25/40)
+    #[serde(default)]
+    pub max_drawdown_pct: Option<f64>,
+    /// 최소 거래 수 (기본: 1)
+    #[serde(default)]
+    pub min_trades: Option<u64>,
+    /// 심볼별 최소 데이터 커버리지 (%, 기본: 90)
+    #[serde(default)]
+    pub min_coverage_pct: Option<f64>,
+    /// 검사만 수행하고 전략은 등록하지 않음
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+/// 승격 검사 결과.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PromotionCheck {
+    /// 검사 이름 (예: `max_drawdown`, `data_coverage:005930`)
+    pub name: String,
+    pub passed: bool,
+    /// 실패 시 승격을 막는지 여부 (false면 경고)
+    pub blocking: bool,
+    pub detail: String,
+}
+
+impl PromotionCheck {
+    fn new(name: impl Into<String>, passed: bool, blocking: bool, detail: String) -> Self {
+        Self {
+            name: name.into(),
+            passed,
+            blocking,
+            detail,
+        }
+    }
+
+    /// 승격을 막는 실패인지 여부.
+    pub fn is_blocking_failure(&self) -> bool {
+        self.blocking && !self.passed
+    }
+}
+
+/// 승격 응답.
+#[derive(Debug, Serialize)]
+pub struct PromoteBacktestResponse {
+    pub backtest_result_id: String,
+    /// 전략 등록 여부 (dry_run이면 false)
+    pub promoted: bool,
+    pub dry_run: bool,
+    /// 등록된 라이브 전략 ID
+    pub strategy_id: Option<String>,
+    pub name: String,
+    pub strategy_type: String,
+    pub symbols: Vec<String>,
+    pub timeframe: String,
+    /// 라이브 전략에 적용될 파라미터
+    pub parameters: Value,
+    /// 파라미터 출처 (backtest, strategy, default)
+    pub parameter_source: String,
+    pub checks: Vec<PromotionCheck>,
+    pub message: String,
+}
+
+/// 원본 백테스트 성과 요약.
+#[derive(Debug, Clone, Serialize)]
+pub struct BacktestPerformance {
+    pub start_date: Option<NaiveDate>,
+    pub end_date: Option<NaiveDate>,
+    pub total_return_pct: Option<f64>,
+    pub annualized_return_pct: Option<f64>,
+    pub max_drawdown_pct: Option<f64>,
+    pub win_rate_pct: Option<f64>,
+    pub total_trades: Option<f64>,
+    /// 일평균 거래 수 (기간을 알 수 없으면 None)
+    pub trades_per_day: Option<f64>,
+}
+
+/// 승격 이후 실거래 성과.
+#[derive(Debug, Clone, Serialize)]
+pub struct LivePerformance {
+    pub since: DateTime<Utc>,
+    /// 승격 후 경과 일수
+    pub days: i64,
+    pub realized_pnl: Decimal,
+    /// 수익률 (%, 기준 자본을 알 수 없으면 None)
+    pub return_pct: Option<f64>,
+    /// 단순 연율화 수익률 (%)
+    pub annualized_return_pct: Option<f64>,
+    pub closed_trades: i64,
+    pub win_rate_pct: Option<f64>,
+    pub trades_per_day: f64,
+}
+
+/// 성과 비교 응답.
+#[derive(Debug, Serialize)]
+pub struct PromotionComparisonResponse {
+    pub promotion: StrategyPromotionRecord,
+    pub backtest: BacktestPerformance,
+    pub live: LivePerformance,
+    /// 수익률 기준 자본 (전략 할당 자본, 없으면 백테스트 초기 자본)
+    pub capital_basis: Option<Decimal>,
+    /// 실거래 연율화 수익률 - 백테스트 연율화 수익률 (%p)
+    pub annualized_return_gap_pct: Option<f64>,
+}
+
+// ==================== 검사 ====================
+
+/// 성과 지표 값 (숫자 또는 숫자 문자열).
+fn metric_f64(metrics: &Value, key: &str) -> Option<f64> {
+    let value = metrics.get(key)?;
+    value
+        .as_f64()
+        .or_else(|| value.as_str().and_then(|s| s.parse().ok()))
+}
+
+/// 콤마 구분 심볼 목록 파싱.
+fn parse_symbols(symbol: &str) -> Vec<String> {
+    symbol
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+/// `[start, end]` 구간의 평일 수.
+fn count_weekdays(start: NaiveDate, end: NaiveDate) -> i64 {
+    start
+        .iter_days()
+        .take_while(|d| *d <= end)
+        .filter(|d| !matches!(d.weekday(), Weekday::Sat | Weekday::Sun))
+        .count() as i64
+}
+
+/// 기간 내 기대 일봉 수 (암호화폐는 매일, 주식은 평일).
+fn expected_candles(symbol: &str, start: NaiveDate, end: NaiveDate) -> i64 {
+    if symbol.contains('/') {
+        (end - start).num_days() + 1
+    } else {
+        count_weekdays(start, end)
+    }
+}
+
+/// 심볼별 데이터 커버리지 검사 (차단).
+fn coverage_check(
+    symbol: &str,
+    coverage: &CandleCoverage,
+    expected: i64,
+    min_coverage_pct: f64,
+) -> PromotionCheck {
+    let pct = if expected > 0 {
+        coverage.candle_count as f64 / expected as f64 * 100.0
+    } else {
+        0.0
+    };
+    let detail = if coverage.candle_count == 0 {
+        "백테스트 기간의 일봉이 DB에 없습니다 (샘플 데이터로 실행된 결과일 수 있음)".to_string()
+    } else {
+        format!(
+            "일봉 {}/{}개 ({:.1}%, 최소 {:.1}%)",
+            coverage.candle_count, expected, pct, min_coverage_pct
+        )
+    };
+    PromotionCheck::new(
+        format!("data_coverage:{}", symbol),
+        coverage.candle_count > 0 && pct >= min_coverage_pct,
+        true,
+        detail,
+    )
+}
+
+/// 심볼별 최신 데이터 검사 (경고).
+fn freshness_check(
+    symbol: &str,
+    latest: Option<DateTime<Utc>>,
+    now: DateTime<Utc>,
+) -> PromotionCheck {
+    let name = format!("data_freshness:{}", symbol);
+    match latest {
+        Some(latest) => {
+            let age_days = (now - latest).num_days();
+            PromotionCheck::new(
+                name,
+                age_days <= MAX_DATA_STALENESS_DAYS,
+                false,
+                format!("최신 일봉 {} ({}일 전)", latest.date_naive(), age_days),
+            )
+        }
+        None => PromotionCheck::new(name, false, false, "저장된 일봉이 없습니다".to_string()),
+    }
+}
+
+/// 리스크 프로필별 기본 허용 최대 낙폭 (%).
+fn default_max_drawdown_pct(profile: &str) -> f64 {
+    match profile {
+        "conservative" => 15.0,
+        "aggressive" => 40.0,
+        _ => 25.0,
+    }
+}
+
+/// 리스크 프로필/설정으로 RiskConfig 생성.
+fn resolve_risk_config(profile: &str, custom: Option<&Value>) -> Result<RiskConfig, String> {
+    match (profile, custom) {
+        ("conservative", _) => Ok(RiskConfig::conservative()),
+        ("aggressive", _) => Ok(RiskConfig::aggressive()),
+        ("custom", Some(value)) => serde_json::from_value(value.clone())
+            .map_err(|e| format!("리스크 설정 형식 오류: {}", e)),
+        _ => Ok(RiskConfig::default()),
+    }
+}
+
+/// 자산 곡선에서 인접 구간 최대 손실률 (%).
+fn worst_period_loss_pct(equity_curve: &Value) -> Option<f64> {
+    let equities: Vec<f64> = equity_curve
+        .as_array()?
+        .iter()
+        .filter_map(|point| metric_f64(point, "equity"))
+        .collect();
+    equities
+        .windows(2)
+        .filter(|w| w[0] > 0.0)
+        .map(|w| (w[0] - w[1]) / w[0] * 100.0)
+        .fold(None, |worst: Option<f64>, loss| {
+            Some(worst.map_or(loss, |w| w.max(loss)))
+        })
+}
+
+/// 백테스트 결과의 리스크 준수 검사.
+fn risk_checks(
+    result: &BacktestResultRecord,
+    risk_config: Result<RiskConfig, String>,
+    max_drawdown_pct: f64,
+    min_trades: u64,
+) -> Vec<PromotionCheck> {
+    let mut checks = vec![PromotionCheck::new(
+        "backtest_success",
+        result.success,
+        true,
+        match &result.error_message {
+            Some(message) if !result.success => format!("백테스트 실패: {}", message),
+            _ if !result.success => "백테스트 실패".to_string(),
+            _ => "백테스트 성공".to_string(),
+        },
+    )];
+
+    let trades = metric_f64(&result.metrics, "total_trades").unwrap_or(0.0);
+    checks.push(PromotionCheck::new(
+        "min_trades",
+        trades >= min_trades as f64,
+        true,
+        format!("거래 {}회 (최소 {}회)", trades, min_trades),
+    ));
+
+    checks.push(match metric_f64(&result.metrics, "max_drawdown_pct") {
+        Some(drawdown) => PromotionCheck::new(
+            "max_drawdown",
+            drawdown.abs() <= max_drawdown_pct,
+            true,
+            format!(
+                "최대 낙폭 {:.2}% (한도 {:.2}%)",
+                drawdown.abs(),
+                max_drawdown_pct
+            ),
+        ),
+        None => PromotionCheck::new(
+            "max_drawdown",
+            false,
+            true,
+            "성과 지표에 max_drawdown_pct가 없습니다".to_string(),
+        ),
+    });
+
+    match risk_config.and_then(|config| {
+        config.validate().map_err(|e| e.to_string())?;
+        Ok(config)
+    }) {
+        Ok(config) => {
+            checks.push(PromotionCheck::new(
+                "risk_config",
+                true,
+                true,
+                format!(
+                    "포지션 {:.1}%, 일일 손실 {:.1}%, 총 노출 {:.1}%",
+                    config.max_position_pct,
+                    config.max_daily_loss_pct,
+                    config.max_total_exposure_pct
+                ),
+            ));
+            if let Some(loss) = worst_period_loss_pct(&result.equity_curve) {
+                checks.push(PromotionCheck::new(
+                    "daily_loss_limit",
+                    loss <= config.max_daily_loss_pct,
+                    false,
+                    format!(
+                        "백테스트 최대 일간 손실 {:.2}% (일일 손실 한도 {:.2}%, 초과 시 실거래 중단)",
+                        loss, config.max_daily_loss_pct
+                    ),
+                ));
+            }
+        }
+        Err(e) => checks.push(PromotionCheck::new("risk_config", false, true, e)),
+    }
+
+    checks
+}
+
+/// 타임프레임 일치 검사 (경고).
+fn timeframe_check(timeframe: &str) -> PromotionCheck {
+    PromotionCheck::new(
+        "timeframe",
+        timeframe == BACKTEST_TIMEFRAME,
+        false,
+        format!("백테스트 {} / 라이브 {}", BACKTEST_TIMEFRAME, timeframe),
+    )
+}
+
+/// 기본 파라미터에 오버라이드 병합 (최상위 키 단위).
+fn merge_params(base: Value, overrides: Option<&Value>) -> Value {
+    let mut merged = if base.is_object() {
+        base
+    } else {
+        Value::Object(Default::default())
+    };
+    if let (Some(target), Some(source)) =
+        (merged.as_object_mut(), overrides.and_then(Value::as_object))
+    {
+        for (key, value) in source {
+            target.insert(key.clone(), value.clone());
+        }
+    }
+    merged
+}
+
+// ==================== 핸들러 ====================
+
+fn bad_request(code: &str, message: impl Into<String>) -> (StatusCode, Json<ApiErrorResponse>) {
+    (
+        StatusCode::BAD_REQUEST,
+        Json(ApiErrorResponse::new(code, message)),
+    )
+}
+
+/// 백테스트 결과를 라이브 전략으로 승격.
+///
+/// POST /api/v1/backtest/results/{id}/promote
+pub async fn promote_backtest_result(
+    State(state): State<Arc<AppState>>,
+    auth: OptionalJwtAuth,
+    Path(id): Path<String>,
+    Json(request): Json<PromoteBacktestRequest>,
+) -> ApiResult<Json<PromoteBacktestResponse>> {
+    let result_id = Uuid::parse_str(&id)
+        .map_err(|_| bad_request("INVALID_ID", "Invalid backtest result ID"))?;
+    if let Some(pct) = request.min_coverage_pct {
+        if !(0.0..=100.0).contains(&pct) {
+            return Err(bad_request(
+                "INVALID_COVERAGE",
+                "min_coverage_pct must be between 0 and 100",
+            ));
+        }
+    }
+    if request.max_drawdown_pct.is_some_and(|pct| pct <= 0.0) {
+        return Err(bad_request(
+            "INVALID_DRAWDOWN",
+            "max_drawdown_pct must be positive",
+        ));
+    }
+    if request
+        .override_params
+        .as_ref()
+        .is_some_and(|v| !v.is_object())
+    {
+        return Err(bad_request(
+            "INVALID_PARAMS",
+            "override_params must be an object",
+        ));
+    }
+
+    let pool = require_pool(&state)?;
+    let result = BacktestResultsRepository::get_by_id(pool, result_id)
+        .await
+        .map_err(db_error_response)?
+        .ok_or_else(|| {
+            (
+                StatusCode::NOT_FOUND,
+                Json(ApiErrorResponse::new(
+                    "BACKTEST_NOT_FOUND",
+                    format!("Backtest result '{}' not found", id),
+                )),
+            )
+        })?;
+
+    let meta = StrategyRegistry::find(&result.strategy_type).ok_or_else(|| {
+        bad_request(
+            "INVALID_STRATEGY_TYPE",
+            format!("Unknown strategy type: {}", result.strategy_type),
+        )
+    })?;
+
+    // 파라미터: 백테스트에 저장된 값 → 원본 전략 설정 → 전략 기본값
+    let source = match &result.strategy_id {
+        Some(strategy_id) => StrategyRepository::get_by_id(pool, strategy_id)
+            .await
+            .map_err(db_error_response)?,
+        None => None,
+    };
+    let (base_params, parameter_source) = match (&result.parameters, &source) {
+        (Some(params), _) => (params.clone(), "backtest"),
+        (None, Some(source)) => (source.config.clone(), "strategy"),
+        (None, None) => (Value::Object(Default::default()), "default"),
+    };
+    let parameters = merge_params(base_params, request.override_params.as_ref());
+
+    let mut symbols = parse_symbols(&result.symbol);
+    if symbols.is_empty() {
+        symbols = meta.default_tickers.iter().map(|s| s.to_string()).collect();
+    }
+    let timeframe = parameters
+        .get("timeframe")
+        .and_then(Value::as_str)
+        .map(String::from)
+        .or_else(|| source.as_ref().and_then(|s| s.timeframe.clone()))
+        .unwrap_or_else(|| meta.default_timeframe.to_string());
+    let market = match symbols.first() {
+        Some(s) if s.chars().all(|c| c.is_numeric()) => "KR",
+        Some(s) if s.contains('/') => "CRYPTO",
+        _ => "US",
+    }
+    .to_string();
+
+    // 리스크 설정: 요청 → 원본 전략 → default
+    let risk_profile = request
+        .risk_profile
+        .clone()
+        .or_else(|| source.as_ref().and_then(|s| s.risk_profile.clone()))
+        .unwrap_or_else(|| "default".to_string());
+    let risk_limits = request
+        .risk_config
+        .clone()
+        .or_else(|| source.as_ref().map(|s| s.risk_limits.clone()));
+    let allocated_capital = request
+        .allocated_capital
+        .map(|v| Decimal::try_from(v).unwrap_or(Decimal::ZERO))
+        .or_else(|| source.as_ref().and_then(|s| s.allocated_capital));
+
+    // 검사
+    let max_drawdown_pct = request
+        .max_drawdown_pct
+        .unwrap_or_else(|| default_max_drawdown_pct(&risk_profile));
+    let mut checks = risk_checks(
+        &result,
+        resolve_risk_config(&risk_profile, risk_limits.as_ref()),
+        max_drawdown_pct,
+        request.min_trades.unwrap_or(DEFAULT_MIN_TRADES),
+    );
+
+    let min_coverage_pct = request.min_coverage_pct.unwrap_or(DEFAULT_MIN_COVERAGE_PCT);
+    let start = Utc.from_utc_datetime(&result.start_date.and_hms_opt(0, 0, 0).unwrap());
+    let end =
+        Utc.from_utc_datetime(&result.end_date.and_hms_opt(0, 0, 0).unwrap()) + Duration::days(1);
+    let now = Utc::now();
+    for symbol in &symbols {
+        let coverage = StrategyPromotionRepository::candle_coverage(
+            pool,
+            symbol,
+            BACKTEST_TIMEFRAME,
+            start,
+            end,
+        )
+        .await
+        .map_err(db_error_response)?;
+        let expected = expected_candles(symbol, result.start_date, result.end_date);
+        checks.push(coverage_check(
+            symbol,
+            &coverage,
+            expected,
+            min_coverage_pct,
+        ));
+        checks.push(freshness_check(symbol, coverage.latest_time, now));
+    }
+    checks.push(timeframe_check(&timeframe));
+
+    let name = request
+        .name
+        .clone()
+        .unwrap_or_else(|| format!("{} (backtest {})", meta.name, &result.id.to_string()[..8]));
+    let failed: Vec<&str> = checks
+        .iter()
+        .filter(|c| c.is_blocking_failure())
+        .map(|c| c.name.as_str())
+        .collect();
+
+    if request.dry_run {
+        let message = if failed.is_empty() {
+            "All promotion checks passed".to_string()
+        } else {
+            format!("Promotion checks failed: {}", failed.join(", "))
+        };
+        return Ok(Json(PromoteBacktestResponse {
+            backtest_result_id: id,
+            promoted: false,
+            dry_run: true,
+            strategy_id: None,
+            name,
+            strategy_type: result.strategy_type,
+            symbols,
+            timeframe,
+            parameters,
+            parameter_source: parameter_source.to_string(),
+            checks,
+            message,
+        }));
+    }
+
+    if !failed.is_empty() {
+        let message = format!("Promotion checks failed: {}", failed.join(", "));
+        return Err((
+            StatusCode::UNPROCESSABLE_ENTITY,
+            Json(ApiErrorResponse::with_details(
+                "PROMOTION_CHECKS_FAILED",
+                message,
+                serde_json::json!({ "checks": checks }),
+            )),
+        ));
+    }
+
+    // 사용자별 등록 전략 수 할당량
+    let owner_id = ensure_strategy_quota(&state, &auth)
+        .await
+        .map_err(|(status, Json(e))| (status, Json(ApiErrorResponse::new(e.code, e.message))))?;
+
+    let strategy = StrategyRegistry::create_instance(&result.strategy_type)
+        .map_err(|e| bad_request("INVALID_STRATEGY_TYPE", e))?;
+    let strategy_id = format!(
+        "{}_{}",
+        result.strategy_type,
+        &Uuid::new_v4().to_string()[..8]
+    );
+
+    StrategyRepository::create(
+        pool,
+        CreateStrategyInput {
+            id: strategy_id.clone(),
+            name: name.clone(),
+            description: Some(format!("Promoted from backtest {}", result.id)),
+            strategy_type: result.strategy_type.clone(),
+            symbols: symbols.clone(),
+            market,
+            timeframe: timeframe.clone(),
+            config: parameters.clone(),
+            risk_config: risk_limits,
+            allocated_capital,
+            risk_profile: Some(risk_profile.clone()),
+            multi_timeframe_config: source
+                .as_ref()
+                .and_then(|s| s.multi_timeframe_config.clone()),
+            symbol_lock: None,
+            owner_id,
+        },
+    )
+    .await
+    .map_err(db_error_response)?;
+
+    state
+        .strategy_engine
+        .read()
+        .await
+        .register_strategy(
+            &strategy_id,
+            strategy,
+            parameters.clone(),
+            Some(name.clone()),
+        )
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiErrorResponse::new("ENGINE_ERROR", e.to_string())),
+            )
+        })?;
+
+    if let Some(profile) = preset_risk_profile(Some(&risk_profile)) {
+        state
+            .executor
+            .read()
+            .await
+            .set_strategy_risk_profile(&strategy_id, Some(profile))
+            .await;
+    }
+
+    StrategyPromotionRepository::insert(
+        pool,
+        &StrategyPromotionInput {
+            strategy_id: strategy_id.clone(),
+            backtest_result_id: result.id,
+            parameters: parameters.clone(),
+            parameter_source: parameter_source.to_string(),
+            backtest_metrics: result.metrics.clone(),
+            checks: serde_json::to_value(&checks).unwrap_or_default(),
+            promoted_by: resolve_actor(&auth),
+        },
+    )
+    .await
+    .map_err(db_error_response)?;
+
+    tracing::info!(
+        backtest_result_id = %result.id,
+        strategy_id = %strategy_id,
+        "Backtest result promoted to live strategy"
+    );
+
+    state.broadcast(ServerMessage::StrategyUpdate(StrategyUpdateData {
+        strategy_id: strategy_id.clone(),
+        name: name.clone(),
+        running: false,
+        event: "promoted".to_string(),
+        data: Some(serde_json::json!({
+            "backtest_result_id": result.id,
+            "strategy_type": result.strategy_type,
+        })),
+        timestamp: now.timestamp_millis(),
+    }));
+
+    Ok(Json(PromoteBacktestResponse {
+        backtest_result_id: id,
+        promoted: true,
+        dry_run: false,
+        strategy_id: Some(strategy_id.clone()),
+        name,
+        strategy_type: result.strategy_type,
+        symbols,
+        timeframe,
+        parameters,
+        parameter_source: parameter_source.to_string(),
+        checks,
+        message: format!("Backtest promoted to strategy '{}' (stopped)", strategy_id),
+    }))
+}
+
+/// 원본 백테스트 성과 요약.
+fn backtest_performance(
+    metrics: &Value,
+    period: Option<(NaiveDate, NaiveDate)>,
+) -> BacktestPerformance {
+    let total_trades = metric_f64(metrics, "total_trades");
+    let trades_per_day = period.and_then(|(start, end)| {
+        let days = (end - start).num_days() + 1;
+        total_trades.filter(|_| days > 0).map(|t| t / days as f64)
+    });
+    BacktestPerformance {
+        start_date: period.map(|(start, _)| start),
+        end_date: period.map(|(_, end)| end),
+        total_return_pct: metric_f64(metrics, "total_return_pct"),
+        annualized_return_pct: metric_f64(metrics, "annualized_return_pct"),
+        max_drawdown_pct: metric_f64(metrics, "max_drawdown_pct"),
+        win_rate_pct: metric_f64(metrics, "win_rate_pct"),
+        total_trades,
+        trades_per_day,
+    }
+}
+
+/// 승격 이후 실거래 성과 집계.
+fn live_performance(
+    since: DateTime<Utc>,
+    now: DateTime<Utc>,
+    daily: &[crate::repository::StrategyDailyPnl],
+    capital_basis: Option<Decimal>,
+) -> LivePerformance {
+    let days = (now - since).num_days().max(1);
+    let realized_pnl: Decimal = daily.iter().map(|d| d.realized_pnl).sum();
+    let closed_trades: i64 = daily.iter().map(|d| d.closed_trades).sum();
+    let winning_trades: i64 = daily.iter().map(|d| d.winning_trades).sum();
+
+    let return_pct = capital_basis
+        .filter(|c| !c.is_zero())
+        .and_then(|c| (realized_pnl / c * Decimal::from(100)).to_f64());
+    LivePerformance {
+        since,
+        days,
+        realized_pnl,
+        return_pct,
+        annualized_return_pct: return_pct.map(|r| r * 365.0 / days as f64),
+        closed_trades,
+        win_rate_pct: (closed_trades > 0)
+            .then(|| winning_trades as f64 / closed_trades as f64 * 100.0),
+        trades_per_day: closed_trades as f64 / days as f64,
+    }
+}
+
+/// 원본 백테스트 대비 실거래 성과 비교.
+///
+/// GET /api/v1/strategies/{id}/promotion
+pub async fn get_strategy_promotion(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+) -> ApiResult<Json<PromotionComparisonResponse>> {
+    let pool = require_pool(&state)?;
+    let promotion = StrategyPromotionRepository::get_by_strategy(pool, &id)
+        .await
+        .map_err(db_error_response)?
+        .ok_or_else(|| {
+            (
+                StatusCode::NOT_FOUND,
+                Json(ApiErrorResponse::new(
+                    "PROMOTION_NOT_FOUND",
+                    format!("Strategy '{}' was not promoted from a backtest", id),
+                )),
+            )
+        })?;
+
+    // 원본 결과가 삭제되어도 승격 시점 지표 스냅샷으로 비교
+    let result = BacktestResultsRepository::get_by_id(pool, promotion.backtest_result_id)
+        .await
+        .map_err(db_error_response)?;
+    let strategy = StrategyRepository::get_by_id(pool, &id)
+        .await
+        .map_err(db_error_response)?;
+    let capital_basis = strategy
+        .and_then(|s| s.allocated_capital)
+        .or_else(|| result.as_ref().map(|r| r.initial_capital));
+
+    let daily =
+        StrategyHistoryRepository::daily_realized_pnl(pool, &id, Some(promotion.promoted_at))
+            .await
+            .map_err(db_error_response)?;
+
+    let backtest = backtest_performance(
+        &promotion.backtest_metrics,
+        result.as_ref().map(|r| (r.start_date, r.end_date)),
+    );
+    let live = live_performance(promotion.promoted_at, Utc::now(), &daily, capital_basis);
+    let annualized_return_gap_pct = live
+        .annualized_return_pct
+        .zip(backtest.annualized_return_pct)
+        .map(|(live, backtest)| live - backtest);
+
+    Ok(Json(PromotionComparisonResponse {
+        promotion,
+        backtest,
+        live,
+        capital_basis,
+        annualized_return_gap_pct,
+    }))
+}
+
+// ==================== 테스트 ====================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+    use serde_json::json;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn record(metrics: Value, equity_curve: Value, success: bool) -> BacktestResultRecord {
+        BacktestResultRecord {
+            id: Uuid::new_v4(),
+            strategy_id: Some("rsi_1".to_string()),
+            strategy_type: "rsi".to_string(),
+            symbol: "005930".to_string(),
+            start_date: date(2024, 1, 1),
+            end_date: date(2024, 12, 31),
+            initial_capital: dec!(10000000),
+            slippage_rate: None,
+            metrics,
+            config_summary: json!({}),
+            equity_curve,
+            trades: json!([]),
+            success,
+            error_message: None,
+            created_at: Utc::now(),
+            deleted_at: None,
+            timeframes_used: None,
+            parameters: None,
+            sensitivity: None,
+        }
+    }
+
+    fn find<'a>(checks: &'a [PromotionCheck], name: &str) -> &'a PromotionCheck {
+        checks.iter().find(|c| c.name == name).unwrap()
+    }
+
+    #[test]
+    fn test_count_weekdays() {
+        // 2024-01-01(월) ~ 2024-01-07(일)
+        assert_eq!(count_weekdays(date(2024, 1, 1), date(2024, 1, 7)), 5);
+        assert_eq!(count_weekdays(date(2024, 1, 6), date(2024, 1, 7)), 0);
+        assert_eq!(count_weekdays(date(2024, 1, 8), date(2024, 1, 1)), 0);
+        assert_eq!(
+            expected_candles("BTC/USDT", date(2024, 1, 1), date(2024, 1, 7)),
+            7
+        );
+    }
+
+    #[test]
+    fn test_parse_symbols() {
+        assert_eq!(parse_symbols("SPY, TLT ,GLD"), vec!["SPY", "TLT", "GLD"]);
+        assert!(parse_symbols(" , ").is_empty());
+    }
+
+    #[test]
+    fn test_coverage_check() {
+        let coverage = CandleCoverage {
+            candle_count: 95,
+            ..Default::default()
+        };
+        assert!(coverage_check("005930", &coverage, 100, 90.0).passed);
+        assert!(!coverage_check("005930", &coverage, 120, 90.0).passed);
+
+        let empty = CandleCoverage::default();
+        let check = coverage_check("005930", &empty, 100, 0.0);
+        assert!(check.is_blocking_failure());
+        assert!(check.detail.contains("샘플 데이터"));
+    }
+
+    #[test]
+    fn test_freshness_check_is_warning() {
+        let now = Utc::now();
+        assert!(freshness_check("AAPL", Some(now - Duration::days(2)), now).passed);
+        let stale = freshness_check("AAPL", Some(now - Duration::days(30)), now);
+        assert!(!stale.passed);
+        assert!(!stale.is_blocking_failure());
+    }
+
+    #[test]
+    fn test_risk_checks() {
+        let metrics = json!({"total_trades": 12, "max_drawdown_pct": "-18.5"});
+        let curve = json!([{"equity": "100"}, {"equity": "98"}, {"equity": "99"}]);
+        let result = record(metrics, curve, true);
+
+        let checks = risk_checks(&result, Ok(RiskConfig::default()), 25.0, 1);
+        assert!(checks.iter().all(|c| !c.is_blocking_failure()));
+        // 2% 하락은 기본 일일 손실 한도(3%) 이내
+        assert!(find(&checks, "daily_loss_limit").passed);
+
+        let checks = risk_checks(&result, Ok(RiskConfig::conservative()), 15.0, 20);
+        assert!(find(&checks, "max_drawdown").is_blocking_failure());
+        assert!(find(&checks, "min_trades").is_blocking_failure());
+        let daily = find(&checks, "daily_loss_limit");
+        assert!(!daily.passed && !daily.blocking);
+    }
+
+    #[test]
+    fn test_risk_checks_failed_backtest_and_invalid_config() {
+        let result = record(json!({}), json!([]), false);
+        let checks = risk_checks(&result, Err("bad".to_string()), 25.0, 0);
+
+        assert!(find(&checks, "backtest_success").is_blocking_failure());
+        assert!(find(&checks, "max_drawdown").is_blocking_failure());
+        assert!(find(&checks, "risk_config").is_blocking_failure());
+        assert!(checks.iter().all(|c| c.name != "daily_loss_limit"));
+    }
+
+    #[test]
+    fn test_resolve_risk_config() {
+        assert_eq!(
+            resolve_risk_config("conservative", None)
+                .unwrap()
+                .max_position_pct,
+            RiskConfig::conservative().max_position_pct
+        );
+        let custom = json!({"max_position_pct": 7.5});
+        assert_eq!(
+            resolve_risk_config("custom", Some(&custom))
+                .unwrap()
+                .max_position_pct,
+            7.5
+        );
+        assert!(resolve_risk_config("custom", Some(&json!({"max_position_pct": "x"}))).is_err());
+        // custom 외 프로필은 저장된 리스크 설정 무시
+        assert_eq!(
+            resolve_risk_config("default", Some(&custom))
+                .unwrap()
+                .max_position_pct,
+            RiskConfig::default().max_position_pct
+        );
+    }
+
+    #[test]
+    fn test_merge_params() {
+        let merged = merge_params(
+            json!({"period": 14, "oversold": 30}),
+            Some(&json!({"period": 20})),
+        );
+        assert_eq!(merged, json!({"period": 20, "oversold": 30}));
+        assert_eq!(merge_params(Value::Null, None), json!({}));
+    }
+
+    #[test]
+    fn test_live_performance() {
+        use crate::repository::StrategyDailyPnl;
+
+        let since = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let now = since + Duration::days(10);
+        let daily = vec![
+            StrategyDailyPnl {
+                trade_date: date(2024, 1, 2),
+                realized_pnl: dec!(150000),
+                closed_trades: 3,
+                winning_trades: 2,
+            },
+            StrategyDailyPnl {
+                trade_date: date(2024, 1, 5),
+                realized_pnl: dec!(-50000),
+                closed_trades: 1,
+                winning_trades: 0,
+            },
+        ];
+
+        let live = live_performance(since, now, &daily, Some(dec!(10000000)));
+        assert_eq!(live.days, 10);
+        assert_eq!(live.realized_pnl, dec!(100000));
+        assert_eq!(live.return_pct, Some(1.0));
+        assert_eq!(live.win_rate_pct, Some(50.0));
+        assert!((live.annualized_return_pct.unwrap() - 36.5).abs() < 1e-9);
+
+        let live = live_performance(since, now, &[], None);
+        assert_eq!(live.return_pct, None);
+        assert_eq!(live.win_rate_pct, None);
+    }
+
+    #[test]
+    fn test_backtest_performance() {
+        let metrics = json!({"total_trades": 10, "annualized_return_pct": "12.5"});
+        let perf = backtest_performance(&metrics, Some((date(2024, 1, 1), date(2024, 1, 10))));
+        assert_eq!(perf.trades_per_day, Some(1.0));
+        assert_eq!(perf.annualized_return_pct, Some(12.5));
+        assert_eq!(backtest_performance(&metrics, None).trades_per_day, None);
+    }
+}
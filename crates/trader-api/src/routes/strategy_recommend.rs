@@ -0,0 +1,247 @@
+//! 종목별 전략 추천 endpoint.
+//!
+//! 대시보드의 "전략 추가" 흐름에서 종목을 고르면, 단일 종목으로 실행 가능한 내장 전략을
+//! 최근 일봉으로 백테스트하여 잘 맞는 전략과 추천 파라미터를 제시합니다.
+//! 전략마다 스키마 기본값과 기간 파라미터 변형 몇 가지를 실행하고, 위험 조정 점수
+//! (샤프 비율 - 최대 낙폭/100)가 가장 높은 설정을 남깁니다.
+//!
+//! # 엔드포인트
+//!
+//! - `GET /api/v1/strategies/recommend?symbol=005930` - 종목별 추천 전략
+
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    Json,
+};
+use chrono::{Duration, NaiveDate, Utc};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::sync::Arc;
+use tracing::{debug, info};
+use trader_core::MarketType;
+use trader_strategy::{
+    is_recommendable, parameter_candidates, rank_fits, StrategyCategory, StrategyFit,
+    StrategyRegistry,
+};
+
+use super::common::require_pool;
+use crate::error::{ApiErrorResponse, ApiResult};
+use crate::routes::backtest::{is_single_symbol_strategy, load_symbol_klines, run_symbol_metrics};
+use crate::state::AppState;
+
+/// 전략별 파라미터 변형 후보 수 (기본값 제외).
+const MAX_VARIANTS: usize = 4;
+/// 추천 대상이 되기 위한 최소 거래 수.
+const MIN_TRADES: usize = 3;
+/// 백테스트에 필요한 최소 일봉 수.
+const MIN_BARS: usize = 60;
+/// 백테스트 초기 자본.
+const INITIAL_CAPITAL: i64 = 10_000_000;
+
+// ==================== 요청/응답 타입 ====================
+
+/// 추천 쿼리.
+#[derive(Debug, Deserialize)]
+pub struct StrategyRecommendQuery {
+    /// 종목 (예: 005930, AAPL, BTC/USDT)
+    pub symbol: String,
+    /// 백테스트 기간 (일, 기본값: 365)
+    #[serde(default = "default_days")]
+    pub days: i64,
+    /// 추천 개수 (기본값: 3)
+    #[serde(default = "default_top")]
+    pub top: usize,
+}
+
+fn default_days() -> i64 {
+    365
+}
+
+fn default_top() -> usize {
+    3
+}
+
+/// 추천 전략.
+#[derive(Debug, Serialize)]
+pub struct StrategyRecommendation {
+    /// 전략 이름
+    pub name: String,
+    pub category: StrategyCategory,
+    pub default_timeframe: String,
+    /// 위험 조정 점수
+    pub score: f64,
+    /// 백테스트 성과와 추천 파라미터 (ticker 포함)
+    #[serde(flatten)]
+    pub fit: StrategyFit,
+}
+
+/// 추천 응답.
+#[derive(Debug, Serialize)]
+pub struct StrategyRecommendResponse {
+    pub symbol: String,
+    pub market: MarketType,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    /// 사용한 일봉 수
+    pub bars: usize,
+    /// 평가한 전략 수
+    pub strategies_evaluated: usize,
+    /// 실행한 백테스트 수
+    pub backtests_run: usize,
+    /// 추천 전략 (점수순)
+    pub recommendations: Vec<StrategyRecommendation>,
+}
+
+// ==================== 핸들러 ====================
+
+/// 종목별 추천 전략 조회.
+///
+/// 종목 일봉을 한 번만 읽고, 추천 가능한 전략의 파라미터 후보를 모두 백테스트합니다.
+/// 실패하거나 거래 수가 부족한 후보는 제외됩니다.
+///
+/// GET /api/v1/strategies/recommend?symbol=005930&days=365&top=3
+pub async fn recommend_strategies(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<StrategyRecommendQuery>,
+) -> ApiResult<Json<StrategyRecommendResponse>> {
+    let symbol = query.symbol.trim().to_uppercase();
+    if symbol.is_empty() || !(90..=1825).contains(&query.days) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiErrorResponse::new(
+                "INVALID_RECOMMEND_QUERY",
+                "symbol is required and days must be between 90 and 1825",
+            )),
+        ));
+    }
+    let pool = require_pool(&state)?;
+
+    let market = if symbol.contains('/') {
+        MarketType::Crypto
+    } else {
+        MarketType::Stock
+    };
+    let end_date = Utc::now().date_naive();
+    let start_date = end_date - Duration::days(query.days);
+    let klines = load_symbol_klines(pool, &symbol, start_date, end_date)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiErrorResponse::new("DATA_LOAD_ERROR", e)),
+            )
+        })?;
+    if klines.len() < MIN_BARS {
+        return Err((
+            StatusCode::NOT_FOUND,
+            Json(ApiErrorResponse::new(
+                "INSUFFICIENT_DATA",
+                format!(
+                    "{} has {} daily bars, at least {} required",
+                    symbol,
+                    klines.len(),
+                    MIN_BARS
+                ),
+            )),
+        ));
+    }
+
+    let capital = Decimal::from(INITIAL_CAPITAL);
+    let mut strategies_evaluated = 0;
+    let mut backtests_run = 0;
+    let mut fits = Vec::new();
+    for meta in StrategyRegistry::all().filter(|meta| {
+        is_recommendable(meta, market) && is_single_symbol_strategy(meta.id, &symbol)
+    }) {
+        strategies_evaluated += 1;
+        for mut parameters in parameter_candidates(meta, MAX_VARIANTS) {
+            if let Some(obj) = parameters.as_object_mut() {
+                obj.insert("ticker".to_string(), Value::from(symbol.clone()));
+            }
+            backtests_run += 1;
+            let params = Some(parameters.clone());
+            match run_symbol_metrics(meta.id, &klines, &params, capital).await {
+                Ok(metrics) => fits.push(StrategyFit {
+                    strategy_id: meta.id.to_string(),
+                    parameters,
+                    total_return_pct: metrics.total_return_pct.to_f64().unwrap_or(0.0),
+                    sharpe_ratio: metrics.sharpe_ratio.to_f64().unwrap_or(0.0),
+                    max_drawdown_pct: metrics.max_drawdown_pct.to_f64().unwrap_or(0.0),
+                    total_trades: metrics.total_trades,
+                    win_rate_pct: metrics.win_rate_pct.to_f64().unwrap_or(0.0),
+                }),
+                Err(e) => debug!(strategy_id = meta.id, error = %e, "추천 백테스트 실패"),
+            }
+        }
+    }
+
+    let recommendations: Vec<StrategyRecommendation> =
+        rank_fits(fits, MIN_TRADES, query.top.clamp(1, 10))
+            .into_iter()
+            .filter_map(|fit| {
+                let meta = StrategyRegistry::find(&fit.strategy_id)?;
+                Some(StrategyRecommendation {
+                    name: meta.name.to_string(),
+                    category: meta.category,
+                    default_timeframe: meta.default_timeframe.to_string(),
+                    score: fit.score(),
+                    fit,
+                })
+            })
+            .collect();
+
+    info!(
+        symbol = %symbol,
+        strategies = strategies_evaluated,
+        backtests = backtests_run,
+        recommended = recommendations.len(),
+        "종목별 전략 추천"
+    );
+
+    Ok(Json(StrategyRecommendResponse {
+        symbol,
+        market,
+        start_date,
+        end_date,
+        bars: klines.len(),
+        strategies_evaluated,
+        backtests_run,
+        recommendations,
+    }))
+}
+
+// ==================== 테스트 ====================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::create_test_state;
+    use axum::{body::Body, http::Request, routing::get, Router};
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn test_recommend_validates_query() {
+        let app = Router::new()
+            .route("/recommend", get(recommend_strategies))
+            .with_state(Arc::new(create_test_state()));
+
+        for (uri, expected) in [
+            ("/recommend", StatusCode::BAD_REQUEST),
+            ("/recommend?symbol=005930&days=30", StatusCode::BAD_REQUEST),
+            (
+                "/recommend?symbol=005930",
+                StatusCode::SERVICE_UNAVAILABLE,
+            ),
+        ] {
+            let response = app
+                .clone()
+                .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+                .await
+                .unwrap();
+            assert_eq!(response.status(), expected, "{}", uri);
+        }
+    }
+}
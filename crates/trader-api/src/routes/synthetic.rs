@@ -0,0 +1,397 @@
+//! 합성 종목 endpoint.
+//!
+//! 여러 종목 가격의 산술식(헤지 비율 스프레드 `A - 1.5*B`, 비율 `A / B`)으로 합성 종목을
+//! 정의합니다. 합성 캔들은 저장하지 않고 요청 시 leg 캔들로 계산하므로, `SYN_<코드>`
+//! 티커를 `GET /api/v1/market/klines`에 그대로 넘겨 차트로 볼 수도 있습니다.
+//! 합성 종목 신호(페어 전략)는 주문 실행 시점에 leg별 주문으로 분해됩니다.
+//!
+//! # 엔드포인트
+//!
+//! - `GET /api/v1/synthetics` - 합성 종목 목록
+//! - `POST /api/v1/synthetics` - 합성 종목 등록
+//! - `GET /api/v1/synthetics/{code}` - 합성 종목 조회
+//! - `PUT /api/v1/synthetics/{code}` - 합성 종목 수정
+//! - `DELETE /api/v1/synthetics/{code}` - 합성 종목 삭제
+//! - `GET /api/v1/synthetics/{code}/klines` - 합성 캔들 (요청 시 계산)
+
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    routing::get,
+    Json, Router,
+};
+use rust_decimal::prelude::ToPrimitive;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+use trader_core::{SyntheticDefinition, SyntheticExpression, Timeframe};
+use trader_data::{
+    CachedHistoricalDataProvider, DataError, SyntheticInstrumentRecord, SyntheticInstrumentStore,
+};
+
+use super::common::db_unavailable;
+use crate::error::{ApiErrorResponse, ApiResult};
+use crate::routes::market::CandleData;
+use crate::state::AppState;
+
+/// 한 번에 조회할 수 있는 최대 합성 캔들 수.
+const MAX_KLINES: usize = 2000;
+
+// ==================== 요청/응답 타입 ====================
+
+/// 합성 종목 등록/수정 요청.
+#[derive(Debug, Deserialize)]
+pub struct SyntheticRequest {
+    /// 합성 종목 코드 (등록 시 필수, 수정 시 경로 값 사용)
+    #[serde(default)]
+    pub code: Option<String>,
+    /// 이름
+    pub name: String,
+    /// 가격식 (예: `005930 - 1.5*000660`, `KO / PEP`)
+    pub expression: String,
+}
+
+impl SyntheticRequest {
+    fn into_definition(self, code: String) -> Result<SyntheticDefinition, String> {
+        Ok(SyntheticDefinition {
+            code: normalize_code(&code),
+            name: self.name.trim().to_string(),
+            expression: SyntheticExpression::parse(&self.expression)?,
+        })
+    }
+}
+
+/// 합성 종목 목록 응답.
+#[derive(Debug, Serialize)]
+pub struct SyntheticListResponse {
+    pub total: usize,
+    /// 합성 종목 (코드순)
+    pub instruments: Vec<SyntheticDto>,
+}
+
+/// 합성 종목 정보.
+#[derive(Debug, Serialize)]
+pub struct SyntheticDto {
+    /// 캔들 조회/전략에서 사용하는 티커 (`SYN_<코드>`)
+    pub ticker: String,
+    /// leg 티커 (가격식 순서)
+    pub legs: Vec<String>,
+    #[serde(flatten)]
+    pub record: SyntheticInstrumentRecord,
+}
+
+impl From<SyntheticInstrumentRecord> for SyntheticDto {
+    fn from(record: SyntheticInstrumentRecord) -> Self {
+        let legs = record
+            .definition()
+            .map(|d| d.expression.tickers())
+            .unwrap_or_default();
+        Self {
+            ticker: record.ticker(),
+            legs,
+            record,
+        }
+    }
+}
+
+/// 합성 캔들 조회 쿼리.
+#[derive(Debug, Deserialize)]
+pub struct SyntheticKlinesQuery {
+    /// 타임프레임 (기본값: 1d)
+    #[serde(default = "default_timeframe")]
+    pub timeframe: String,
+    /// 캔들 수 (기본값: 200)
+    #[serde(default = "default_limit")]
+    pub limit: usize,
+}
+
+fn default_timeframe() -> String {
+    "1d".to_string()
+}
+
+fn default_limit() -> usize {
+    200
+}
+
+/// 합성 캔들 응답.
+#[derive(Debug, Serialize)]
+pub struct SyntheticKlinesResponse {
+    pub ticker: String,
+    /// 정규화된 가격식
+    pub expression: String,
+    pub timeframe: String,
+    /// 모든 leg에 캔들이 있는 시점의 합성 캔들 (오래된 순)
+    pub data: Vec<CandleData>,
+}
+
+// ==================== 헬퍼 ====================
+
+fn data_error_response(err: DataError) -> (StatusCode, Json<ApiErrorResponse>) {
+    (
+        StatusCode::INTERNAL_SERVER_ERROR,
+        Json(ApiErrorResponse::new("DB_ERROR", err.to_string())),
+    )
+}
+
+fn not_found(code: &str) -> (StatusCode, Json<ApiErrorResponse>) {
+    (
+        StatusCode::NOT_FOUND,
+        Json(ApiErrorResponse::new(
+            "SYNTHETIC_NOT_FOUND",
+            format!("Synthetic instrument not found: {}", code),
+        )),
+    )
+}
+
+fn invalid(msg: String) -> (StatusCode, Json<ApiErrorResponse>) {
+    (
+        StatusCode::BAD_REQUEST,
+        Json(ApiErrorResponse::new("INVALID_SYNTHETIC", msg)),
+    )
+}
+
+/// 경로의 합성 종목 코드 정규화 (대문자, `SYN_` 접두사 허용).
+fn normalize_code(code: &str) -> String {
+    let code = code.trim().to_uppercase();
+    code.strip_prefix(trader_core::SYNTHETIC_TICKER_PREFIX)
+        .map(str::to_string)
+        .unwrap_or(code)
+}
+
+#[allow(clippy::result_large_err)]
+fn build_definition(request: SyntheticRequest, code: String) -> ApiResult<SyntheticDefinition> {
+    let definition = request.into_definition(code).map_err(invalid)?;
+    definition.validate().map_err(invalid)?;
+    Ok(definition)
+}
+
+// ==================== 핸들러 ====================
+
+/// 합성 종목 목록 조회.
+///
+/// GET /api/v1/synthetics
+pub async fn list_synthetics(
+    State(state): State<Arc<AppState>>,
+) -> ApiResult<Json<SyntheticListResponse>> {
+    let pool = state.db_pool.as_ref().ok_or_else(db_unavailable)?;
+    let records = SyntheticInstrumentStore::new(pool.clone())
+        .list()
+        .await
+        .map_err(data_error_response)?;
+
+    let instruments: Vec<SyntheticDto> = records.into_iter().map(Into::into).collect();
+    Ok(Json(SyntheticListResponse {
+        total: instruments.len(),
+        instruments,
+    }))
+}
+
+/// 합성 종목 등록.
+///
+/// POST /api/v1/synthetics
+pub async fn create_synthetic(
+    State(state): State<Arc<AppState>>,
+    Json(request): Json<SyntheticRequest>,
+) -> ApiResult<(StatusCode, Json<SyntheticDto>)> {
+    let pool = state.db_pool.as_ref().ok_or_else(db_unavailable)?;
+    let code = request.code.clone().unwrap_or_default();
+    let definition = build_definition(request, code)?;
+
+    let store = SyntheticInstrumentStore::new(pool.clone());
+    if store
+        .get(&definition.code)
+        .await
+        .map_err(data_error_response)?
+        .is_some()
+    {
+        return Err((
+            StatusCode::CONFLICT,
+            Json(ApiErrorResponse::new(
+                "SYNTHETIC_CODE_CONFLICT",
+                format!("Synthetic instrument already exists: {}", definition.code),
+            )),
+        ));
+    }
+
+    let record = store
+        .upsert(&definition)
+        .await
+        .map_err(data_error_response)?;
+    info!(code = %definition.code, expression = %definition.expression, "합성 종목 등록");
+    Ok((StatusCode::CREATED, Json(record.into())))
+}
+
+/// 합성 종목 조회.
+///
+/// GET /api/v1/synthetics/{code}
+pub async fn get_synthetic(
+    State(state): State<Arc<AppState>>,
+    Path(code): Path<String>,
+) -> ApiResult<Json<SyntheticDto>> {
+    let code = normalize_code(&code);
+    let pool = state.db_pool.as_ref().ok_or_else(db_unavailable)?;
+    let record = SyntheticInstrumentStore::new(pool.clone())
+        .get(&code)
+        .await
+        .map_err(data_error_response)?
+        .ok_or_else(|| not_found(&code))?;
+
+    Ok(Json(record.into()))
+}
+
+/// 합성 종목 수정.
+///
+/// PUT /api/v1/synthetics/{code}
+pub async fn update_synthetic(
+    State(state): State<Arc<AppState>>,
+    Path(code): Path<String>,
+    Json(request): Json<SyntheticRequest>,
+) -> ApiResult<Json<SyntheticDto>> {
+    let code = normalize_code(&code);
+    let pool = state.db_pool.as_ref().ok_or_else(db_unavailable)?;
+    let store = SyntheticInstrumentStore::new(pool.clone());
+    store
+        .get(&code)
+        .await
+        .map_err(data_error_response)?
+        .ok_or_else(|| not_found(&code))?;
+
+    let definition = build_definition(request, code)?;
+    let record = store
+        .upsert(&definition)
+        .await
+        .map_err(data_error_response)?;
+    info!(code = %definition.code, expression = %definition.expression, "합성 종목 수정");
+    Ok(Json(record.into()))
+}
+
+/// 합성 종목 삭제.
+///
+/// DELETE /api/v1/synthetics/{code}
+pub async fn delete_synthetic(
+    State(state): State<Arc<AppState>>,
+    Path(code): Path<String>,
+) -> ApiResult<StatusCode> {
+    let code = normalize_code(&code);
+    let pool = state.db_pool.as_ref().ok_or_else(db_unavailable)?;
+    let deleted = SyntheticInstrumentStore::new(pool.clone())
+        .delete(&code)
+        .await
+        .map_err(data_error_response)?;
+
+    if !deleted {
+        return Err(not_found(&code));
+    }
+    info!(code = %code, "합성 종목 삭제");
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// 합성 캔들 조회 (leg 캔들로 요청 시 계산).
+///
+/// GET /api/v1/synthetics/{code}/klines
+pub async fn get_synthetic_klines(
+    State(state): State<Arc<AppState>>,
+    Path(code): Path<String>,
+    Query(query): Query<SyntheticKlinesQuery>,
+) -> ApiResult<Json<SyntheticKlinesResponse>> {
+    let code = normalize_code(&code);
+    let pool = state.db_pool.as_ref().ok_or_else(db_unavailable)?;
+    let record = SyntheticInstrumentStore::new(pool.clone())
+        .get(&code)
+        .await
+        .map_err(data_error_response)?
+        .ok_or_else(|| not_found(&code))?;
+    let timeframe = query.timeframe.parse::<Timeframe>().map_err(invalid)?;
+    let limit = query.limit.clamp(1, MAX_KLINES);
+
+    let provider = state
+        .data_provider
+        .clone()
+        .unwrap_or_else(|| Arc::new(CachedHistoricalDataProvider::new(pool.clone())));
+    let klines = provider
+        .get_klines(&record.ticker(), timeframe, limit)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::BAD_GATEWAY,
+                Json(ApiErrorResponse::new(
+                    "DATA_FETCH_ERROR",
+                    format!("Failed to compute synthetic klines: {}", e),
+                )),
+            )
+        })?;
+
+    let data = klines
+        .into_iter()
+        .map(|k| CandleData {
+            time: if timeframe.is_intraday() {
+                k.open_time.format("%Y-%m-%d %H:%M:%S").to_string()
+            } else {
+                k.open_time.format("%Y-%m-%d").to_string()
+            },
+            open: k.open.to_f64().unwrap_or(0.0),
+            high: k.high.to_f64().unwrap_or(0.0),
+            low: k.low.to_f64().unwrap_or(0.0),
+            close: k.close.to_f64().unwrap_or(0.0),
+            volume: k.volume.to_f64().unwrap_or(0.0),
+        })
+        .collect();
+
+    Ok(Json(SyntheticKlinesResponse {
+        ticker: record.ticker(),
+        expression: record.expression,
+        timeframe: query.timeframe,
+        data,
+    }))
+}
+
+// ==================== 라우터 ====================
+
+/// 합성 종목 라우터 생성.
+pub fn synthetic_router() -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/", get(list_synthetics).post(create_synthetic))
+        .route(
+            "/{code}",
+            get(get_synthetic)
+                .put(update_synthetic)
+                .delete(delete_synthetic),
+        )
+        .route("/{code}/klines", get(get_synthetic_klines))
+}
+
+// ==================== 테스트 ====================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_request_normalizes_code_and_expression() {
+        let request: SyntheticRequest = serde_json::from_value(serde_json::json!({
+            "name": " 삼성-하이닉스 ",
+            "expression": "005930 - 1.50 * 000660",
+        }))
+        .unwrap();
+
+        let definition = build_definition(request, " syn_sec_hynix ".to_string()).unwrap();
+        assert_eq!(definition.code, "SEC_HYNIX");
+        assert_eq!(definition.name, "삼성-하이닉스");
+        assert_eq!(definition.ticker(), "SYN_SEC_HYNIX");
+        assert_eq!(definition.expression.to_string(), "005930 - 1.5*000660");
+    }
+
+    #[test]
+    fn test_request_rejects_invalid_expressions() {
+        for expression in ["005930", "A + ", "A / A"] {
+            let request = SyntheticRequest {
+                code: None,
+                name: "x".to_string(),
+                expression: expression.to_string(),
+            };
+            let (status, _) = build_definition(request, "BAD".to_string()).unwrap_err();
+            assert_eq!(status, StatusCode::BAD_REQUEST, "{}", expression);
+        }
+    }
+}
@@ -0,0 +1,226 @@
+//! 백그라운드 작업 endpoint.
+//!
+//! 작업 레지스트리(`crate::services::task_scheduler`)에 등록된 주기 작업의
+//! 실행 주기와 마지막 실행 기록을 조회하고, 작업별로 일시 정지/재개/즉시 실행합니다.
+//!
+//! # 엔드포인트
+//!
+//! - `GET /api/v1/tasks` - 작업 목록 (주기, 마지막 실행, 소요 시간)
+//! - `GET /api/v1/tasks/:name` - 작업 상세
+//! - `POST /api/v1/tasks/:name/pause` - 일시 정지 (관리자)
+//! - `POST /api/v1/tasks/:name/resume` - 재개 (관리자)
+//! - `POST /api/v1/tasks/:name/trigger` - 즉시 실행 (관리자, 일시 정지 중에도 실행)
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    routing::{get, post},
+    Json, Router,
+};
+use serde::Serialize;
+use std::sync::Arc;
+use tracing::info;
+
+use crate::auth::AdminAuth;
+use crate::error::{ApiErrorResponse, ApiResult};
+use crate::services::task_scheduler::TaskSnapshot;
+use crate::state::AppState;
+
+// ==================== 응답 타입 ====================
+
+/// 작업 목록 응답.
+#[derive(Debug, Serialize)]
+pub struct TasksListResponse {
+    pub tasks: Vec<TaskSnapshot>,
+    pub total: usize,
+}
+
+fn task_not_found(name: &str) -> (StatusCode, Json<ApiErrorResponse>) {
+    (
+        StatusCode::NOT_FOUND,
+        Json(ApiErrorResponse::new(
+            "NOT_FOUND",
+            format!("Task {} not found", name),
+        )),
+    )
+}
+
+// ==================== 핸들러 ====================
+
+/// 작업 목록 조회.
+///
+/// GET /api/v1/tasks
+pub async fn list_tasks(State(state): State<Arc<AppState>>) -> Json<TasksListResponse> {
+    let tasks = state.tasks.list();
+    Json(TasksListResponse {
+        total: tasks.len(),
+        tasks,
+    })
+}
+
+/// 작업 상세 조회.
+///
+/// GET /api/v1/tasks/:name
+pub async fn get_task(
+    State(state): State<Arc<AppState>>,
+    Path(name): Path<String>,
+) -> ApiResult<Json<TaskSnapshot>> {
+    state
+        .tasks
+        .get(&name)
+        .map(Json)
+        .ok_or_else(|| task_not_found(&name))
+}
+
+/// 작업 일시 정지.
+///
+/// POST /api/v1/tasks/:name/pause
+pub async fn pause_task(
+    State(state): State<Arc<AppState>>,
+    AdminAuth(claims): AdminAuth,
+    Path(name): Path<String>,
+) -> ApiResult<Json<TaskSnapshot>> {
+    let task = state
+        .tasks
+        .set_paused(&name, true)
+        .ok_or_else(|| task_not_found(&name))?;
+    info!(task = %name, user = %claims.sub, "Background task paused");
+    Ok(Json(task))
+}
+
+/// 작업 재개.
+///
+/// POST /api/v1/tasks/:name/resume
+pub async fn resume_task(
+    State(state): State<Arc<AppState>>,
+    AdminAuth(claims): AdminAuth,
+    Path(name): Path<String>,
+) -> ApiResult<Json<TaskSnapshot>> {
+    let task = state
+        .tasks
+        .set_paused(&name, false)
+        .ok_or_else(|| task_not_found(&name))?;
+    info!(task = %name, user = %claims.sub, "Background task resumed");
+    Ok(Json(task))
+}
+
+/// 작업 즉시 실행 요청.
+///
+/// 실행 중이면 현재 회차가 끝난 직후 한 번 더 실행합니다.
+///
+/// POST /api/v1/tasks/:name/trigger
+pub async fn trigger_task(
+    State(state): State<Arc<AppState>>,
+    AdminAuth(claims): AdminAuth,
+    Path(name): Path<String>,
+) -> ApiResult<(StatusCode, Json<TaskSnapshot>)> {
+    let task = state
+        .tasks
+        .trigger(&name)
+        .ok_or_else(|| task_not_found(&name))?;
+    info!(task = %name, user = %claims.sub, "Background task triggered");
+    Ok((StatusCode::ACCEPTED, Json(task)))
+}
+
+// ==================== 라우터 ====================
+
+/// 백그라운드 작업 라우터.
+pub fn tasks_router() -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/", get(list_tasks))
+        .route("/{name}", get(get_task))
+        .route("/{name}/pause", post(pause_task))
+        .route("/{name}/resume", post(resume_task))
+        .route("/{name}/trigger", post(trigger_task))
+}
+
+// ==================== 테스트 ====================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::{create_token, Claims, Role};
+    use crate::state::create_test_state;
+    use axum::body::Body;
+    use axum::http::Request;
+    use std::time::Duration;
+    use tower::ServiceExt;
+
+    fn app() -> (Router, Arc<AppState>) {
+        let state = Arc::new(create_test_state());
+        state
+            .tasks
+            .register("dca_scheduler", "DCA", Duration::from_secs(300));
+        (tasks_router().with_state(Arc::clone(&state)), state)
+    }
+
+    fn bearer(role: Role) -> String {
+        let secret = std::env::var("JWT_SECRET")
+            .unwrap_or_else(|_| "development-secret-key-change-in-production".to_string());
+        let claims = Claims::new("user-1", "tester", role, 60);
+        format!("Bearer {}", create_token(&claims, &secret).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_list_tasks() {
+        let (app, _) = app();
+        let response = app
+            .oneshot(Request::get("/").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["total"], 1);
+        assert_eq!(json["tasks"][0]["name"], "dca_scheduler");
+        assert_eq!(json["tasks"][0]["interval_secs"], 300);
+        assert_eq!(json["tasks"][0]["run_count"], 0);
+    }
+
+    #[tokio::test]
+    async fn test_pause_requires_admin() {
+        let (app, state) = app();
+        let response = app
+            .clone()
+            .oneshot(
+                Request::post("/dca_scheduler/pause")
+                    .header("authorization", bearer(Role::Trader))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        assert!(!state.tasks.get("dca_scheduler").unwrap().paused);
+
+        let response = app
+            .oneshot(
+                Request::post("/dca_scheduler/pause")
+                    .header("authorization", bearer(Role::Admin))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(state.tasks.get("dca_scheduler").unwrap().paused);
+    }
+
+    #[tokio::test]
+    async fn test_trigger_unknown_task() {
+        let (app, _) = app();
+        let response = app
+            .oneshot(
+                Request::post("/missing/trigger")
+                    .header("authorization", bearer(Role::Admin))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+}
@@ -0,0 +1,608 @@
+//! TradingView UDF(Universal Data Feed) 호환 차트 데이터 API.
+//!
+//! TradingView Charting Library의 UDF 어댑터나 호환 차트 백엔드가
+//! 봇의 캔들과 신호 마커를 직접 조회할 수 있도록 합니다.
+//! 완성된 캔들은 `kline.closed` 웹훅으로도 전송되므로 외부 차트가
+//! 폴링 없이 최신 상태를 유지할 수 있습니다.
+//!
+//! # 엔드포인트
+//!
+//! - `GET /api/v1/udf/config` - 데이터피드 설정
+//! - `GET /api/v1/udf/time` - 서버 시각 (초)
+//! - `GET /api/v1/udf/symbols?symbol=` - 심볼 정보
+//! - `GET /api/v1/udf/search?query=&limit=` - 심볼 검색
+//! - `GET /api/v1/udf/history?symbol=&resolution=&from=&to=&countback=` - 캔들 (UDF 배열 형식)
+//! - `GET /api/v1/udf/marks?symbol=&from=&to=` - 신호 마커
+
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::get,
+    Json, Router,
+};
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tracing::warn;
+use trader_core::{Kline, Side, SignalMarker, SignalType, Timeframe};
+use trader_data::cache::CachedHistoricalDataProvider;
+
+use crate::repository::{SignalMarkerRepository, SymbolInfoRepository};
+use crate::state::AppState;
+
+/// 지원 해상도 (UDF 표기).
+const SUPPORTED_RESOLUTIONS: &[&str] = &[
+    "1", "3", "5", "15", "30", "60", "120", "240", "360", "480", "720", "1D", "3D", "1W", "1M",
+];
+
+/// 한 번에 반환하는 최대 캔들 수.
+const MAX_HISTORY_BARS: usize = 5_000;
+
+/// 한 번에 반환하는 최대 마커 수.
+const MAX_MARKS: i64 = 1_000;
+
+// ==================== 응답 타입 ====================
+
+/// UDF 오류 응답 (`{"s": "error", "errmsg": ...}`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UdfError {
+    pub s: String,
+    pub errmsg: String,
+}
+
+impl UdfError {
+    fn new(errmsg: impl Into<String>) -> Self {
+        Self {
+            s: "error".to_string(),
+            errmsg: errmsg.into(),
+        }
+    }
+}
+
+type UdfResult<T> = Result<Json<T>, (StatusCode, Json<UdfError>)>;
+
+fn udf_error(status: StatusCode, errmsg: impl Into<String>) -> (StatusCode, Json<UdfError>) {
+    (status, Json(UdfError::new(errmsg)))
+}
+
+/// 데이터피드 설정.
+#[derive(Debug, Clone, Serialize)]
+pub struct UdfConfig {
+    pub supported_resolutions: Vec<&'static str>,
+    pub supports_search: bool,
+    pub supports_group_request: bool,
+    pub supports_marks: bool,
+    pub supports_timescale_marks: bool,
+    pub supports_time: bool,
+}
+
+/// 심볼 정보 (TradingView `LibrarySymbolInfo`).
+#[derive(Debug, Clone, Serialize)]
+pub struct UdfSymbolInfo {
+    pub name: String,
+    pub ticker: String,
+    pub description: String,
+    #[serde(rename = "type")]
+    pub symbol_type: &'static str,
+    pub session: &'static str,
+    pub exchange: String,
+    pub listed_exchange: String,
+    pub timezone: &'static str,
+    pub minmov: u32,
+    pub pricescale: u64,
+    pub has_intraday: bool,
+    pub has_weekly_and_monthly: bool,
+    pub supported_resolutions: Vec<&'static str>,
+    pub volume_precision: u32,
+    pub data_status: &'static str,
+}
+
+/// 심볼 검색 결과 항목.
+#[derive(Debug, Clone, Serialize)]
+pub struct UdfSearchItem {
+    pub symbol: String,
+    pub full_name: String,
+    pub description: String,
+    pub exchange: String,
+    pub ticker: String,
+    #[serde(rename = "type")]
+    pub symbol_type: &'static str,
+}
+
+/// 캔들 응답 (UDF 배열 형식).
+///
+/// 데이터가 없으면 `s`가 `no_data`이고 배열 필드는 생략됩니다.
+#[derive(Debug, Clone, Serialize)]
+pub struct UdfHistory {
+    pub s: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub t: Option<Vec<i64>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub o: Option<Vec<Decimal>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub h: Option<Vec<Decimal>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub l: Option<Vec<Decimal>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub c: Option<Vec<Decimal>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub v: Option<Vec<Decimal>>,
+}
+
+impl UdfHistory {
+    fn no_data() -> Self {
+        Self {
+            s: "no_data",
+            t: None,
+            o: None,
+            h: None,
+            l: None,
+            c: None,
+            v: None,
+        }
+    }
+}
+
+/// 차트 마커 (신호 마커).
+#[derive(Debug, Clone, Serialize)]
+pub struct UdfMark {
+    pub id: String,
+    /// 마커 시각 (초)
+    pub time: i64,
+    pub color: &'static str,
+    pub text: String,
+    pub label: &'static str,
+    #[serde(rename = "labelFontColor")]
+    pub label_font_color: &'static str,
+    #[serde(rename = "minSize")]
+    pub min_size: u32,
+}
+
+// ==================== 요청 타입 ====================
+
+#[derive(Debug, Deserialize)]
+pub struct SymbolQuery {
+    pub symbol: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SearchQuery {
+    #[serde(default)]
+    pub query: String,
+    #[serde(default = "default_search_limit")]
+    pub limit: i64,
+}
+
+fn default_search_limit() -> i64 {
+    30
+}
+
+#[derive(Debug, Deserialize)]
+pub struct HistoryQuery {
+    pub symbol: String,
+    pub resolution: String,
+    /// 시작 시각 (초, 포함)
+    pub from: i64,
+    /// 종료 시각 (초, 미포함)
+    pub to: i64,
+    /// 종료 시각 이전 캔들 수 (지정 시 `from`보다 우선)
+    pub countback: Option<usize>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MarksQuery {
+    pub symbol: String,
+    pub from: i64,
+    pub to: i64,
+}
+
+// ==================== 변환 ====================
+
+/// 시장별 차트 속성.
+struct MarketProfile {
+    symbol_type: &'static str,
+    session: &'static str,
+    timezone: &'static str,
+    pricescale: u64,
+}
+
+fn market_profile(market: &str) -> MarketProfile {
+    match market {
+        "KR" => MarketProfile {
+            symbol_type: "stock",
+            session: "0900-1530",
+            timezone: "Asia/Seoul",
+            pricescale: 1,
+        },
+        "US" => MarketProfile {
+            symbol_type: "stock",
+            session: "0930-1600",
+            timezone: "America/New_York",
+            pricescale: 100,
+        },
+        _ => MarketProfile {
+            symbol_type: "crypto",
+            session: "24x7",
+            timezone: "Etc/UTC",
+            pricescale: 100_000_000,
+        },
+    }
+}
+
+/// 캔들을 요청 구간으로 잘라 UDF 배열 형식으로 변환합니다.
+///
+/// `countback`이 있으면 `to` 이전의 마지막 `countback`개를, 없으면 `[from, to)` 구간을 반환합니다.
+fn history_from_klines(
+    mut klines: Vec<Kline>,
+    from: i64,
+    to: i64,
+    countback: Option<usize>,
+) -> UdfHistory {
+    klines.sort_by_key(|k| k.open_time);
+    klines.retain(|k| k.open_time.timestamp() < to);
+
+    let start = match countback {
+        Some(count) => klines.len().saturating_sub(count),
+        None => klines.partition_point(|k| k.open_time.timestamp() < from),
+    };
+    let bars = &klines[start.max(klines.len().saturating_sub(MAX_HISTORY_BARS))..];
+
+    if bars.is_empty() {
+        return UdfHistory::no_data();
+    }
+
+    UdfHistory {
+        s: "ok",
+        t: Some(bars.iter().map(|k| k.open_time.timestamp()).collect()),
+        o: Some(bars.iter().map(|k| k.open).collect()),
+        h: Some(bars.iter().map(|k| k.high).collect()),
+        l: Some(bars.iter().map(|k| k.low).collect()),
+        c: Some(bars.iter().map(|k| k.close).collect()),
+        v: Some(bars.iter().map(|k| k.volume).collect()),
+    }
+}
+
+/// 신호 마커를 차트 마커로 변환합니다.
+fn mark_from_signal(marker: &SignalMarker) -> UdfMark {
+    let (color, label) = match (marker.side, marker.signal_type) {
+        (_, SignalType::Alert) => ("blue", "A"),
+        (Some(Side::Buy), _) => ("green", "B"),
+        (Some(Side::Sell), _) => ("red", "S"),
+        (None, SignalType::Exit | SignalType::ReducePosition) => ("red", "X"),
+        (None, _) => ("green", "E"),
+    };
+    let executed = if marker.executed {
+        "체결"
+    } else {
+        "미체결"
+    };
+
+    UdfMark {
+        id: marker.id.to_string(),
+        time: marker.timestamp.timestamp(),
+        color,
+        text: format!(
+            "[{}] {} @ {} ({})\n{}",
+            marker.strategy_name, marker.signal_type, marker.price, executed, marker.reason
+        ),
+        label,
+        label_font_color: "white",
+        min_size: 14,
+    }
+}
+
+fn timestamp_arg(name: &str, secs: i64) -> Result<DateTime<Utc>, (StatusCode, Json<UdfError>)> {
+    DateTime::<Utc>::from_timestamp(secs, 0).ok_or_else(|| {
+        udf_error(
+            StatusCode::BAD_REQUEST,
+            format!("유효하지 않은 {} 시각입니다: {}", name, secs),
+        )
+    })
+}
+
+// ==================== 핸들러 ====================
+
+/// 데이터피드 설정 조회.
+///
+/// GET /api/v1/udf/config
+pub async fn get_config() -> Json<UdfConfig> {
+    Json(UdfConfig {
+        supported_resolutions: SUPPORTED_RESOLUTIONS.to_vec(),
+        supports_search: true,
+        supports_group_request: false,
+        supports_marks: true,
+        supports_timescale_marks: false,
+        supports_time: true,
+    })
+}
+
+/// 서버 시각 조회 (UDF 규격상 초 단위 숫자 텍스트).
+///
+/// GET /api/v1/udf/time
+pub async fn get_time() -> Response {
+    Utc::now().timestamp().to_string().into_response()
+}
+
+/// 심볼 정보 조회.
+///
+/// GET /api/v1/udf/symbols?symbol=005930
+pub async fn get_symbol(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<SymbolQuery>,
+) -> UdfResult<UdfSymbolInfo> {
+    let pool = state
+        .db_pool
+        .as_ref()
+        .ok_or_else(|| udf_error(StatusCode::SERVICE_UNAVAILABLE, "Database not available"))?;
+
+    let info = SymbolInfoRepository::get_by_ticker(pool, &query.symbol, None)
+        .await
+        .map_err(|e| {
+            warn!(symbol = %query.symbol, error = %e, "UDF 심볼 조회 실패");
+            udf_error(StatusCode::INTERNAL_SERVER_ERROR, e.to_string())
+        })?
+        .ok_or_else(|| udf_error(StatusCode::NOT_FOUND, "unknown_symbol"))?;
+
+    let profile = market_profile(&info.market);
+    let exchange = info.exchange.unwrap_or_else(|| info.market.clone());
+
+    Ok(Json(UdfSymbolInfo {
+        name: info.ticker.clone(),
+        ticker: info.ticker,
+        description: info.name,
+        symbol_type: profile.symbol_type,
+        session: profile.session,
+        listed_exchange: exchange.clone(),
+        exchange,
+        timezone: profile.timezone,
+        minmov: 1,
+        pricescale: profile.pricescale,
+        has_intraday: true,
+        has_weekly_and_monthly: true,
+        supported_resolutions: SUPPORTED_RESOLUTIONS.to_vec(),
+        volume_precision: 0,
+        data_status: "streaming",
+    }))
+}
+
+/// 심볼 검색.
+///
+/// GET /api/v1/udf/search?query=삼성&limit=30
+pub async fn search_symbols(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<SearchQuery>,
+) -> UdfResult<Vec<UdfSearchItem>> {
+    let pool = state
+        .db_pool
+        .as_ref()
+        .ok_or_else(|| udf_error(StatusCode::SERVICE_UNAVAILABLE, "Database not available"))?;
+
+    let results = SymbolInfoRepository::search(pool, &query.query, query.limit.clamp(1, 100))
+        .await
+        .map_err(|e| udf_error(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(
+        results
+            .into_iter()
+            .map(|result| UdfSearchItem {
+                symbol: result.ticker.clone(),
+                full_name: format!("{}:{}", result.market, result.ticker),
+                description: result.name,
+                exchange: result.market.clone(),
+                ticker: result.ticker,
+                symbol_type: market_profile(&result.market).symbol_type,
+            })
+            .collect(),
+    ))
+}
+
+/// 캔들 조회 (UDF 배열 형식).
+///
+/// GET /api/v1/udf/history?symbol=005930&resolution=1D&from=1735689600&to=1767225600
+pub async fn get_history(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<HistoryQuery>,
+) -> UdfResult<UdfHistory> {
+    let timeframe = Timeframe::from_udf_resolution(&query.resolution).ok_or_else(|| {
+        udf_error(
+            StatusCode::BAD_REQUEST,
+            format!("지원하지 않는 해상도입니다: {}", query.resolution),
+        )
+    })?;
+    let to = timestamp_arg("to", query.to)?;
+    // countback이 있으면 휴장 구간을 감안해 요청 캔들 수의 두 배 구간을 조회
+    let from_secs = match query.countback {
+        Some(count) => {
+            let span = (count as i64).saturating_mul(2 * timeframe.as_secs() as i64);
+            query.from.min(query.to.saturating_sub(span))
+        }
+        None => query.from,
+    };
+    let from = timestamp_arg("from", from_secs)?;
+    if from >= to {
+        return Ok(Json(UdfHistory::no_data()));
+    }
+
+    let pool = state
+        .db_pool
+        .as_ref()
+        .ok_or_else(|| udf_error(StatusCode::SERVICE_UNAVAILABLE, "Database not available"))?;
+
+    let klines = CachedHistoricalDataProvider::new(pool.clone())
+        .get_klines_range(&query.symbol, timeframe, from.date_naive(), to.date_naive())
+        .await
+        .map_err(|e| {
+            warn!(
+                symbol = %query.symbol,
+                resolution = %query.resolution,
+                error = %e,
+                "UDF 캔들 조회 실패"
+            );
+            udf_error(
+                StatusCode::BAD_GATEWAY,
+                format!("차트 데이터 조회 실패: {}", e),
+            )
+        })?;
+
+    Ok(Json(history_from_klines(
+        klines,
+        query.from,
+        query.to,
+        query.countback,
+    )))
+}
+
+/// 신호 마커 조회.
+///
+/// GET /api/v1/udf/marks?symbol=005930&from=1735689600&to=1767225600
+pub async fn get_marks(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<MarksQuery>,
+) -> UdfResult<Vec<UdfMark>> {
+    let from = timestamp_arg("from", query.from)?;
+    let to = timestamp_arg("to", query.to)?;
+
+    let pool = state
+        .db_pool
+        .as_ref()
+        .ok_or_else(|| udf_error(StatusCode::SERVICE_UNAVAILABLE, "Database not available"))?;
+
+    let info = SymbolInfoRepository::get_by_ticker(pool, &query.symbol, None)
+        .await
+        .map_err(|e| udf_error(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    let Some(exchange) = info.and_then(|info| info.exchange) else {
+        return Ok(Json(Vec::new()));
+    };
+
+    let markers = SignalMarkerRepository::new(pool.clone())
+        .find_by_symbol(
+            &query.symbol,
+            &exchange,
+            Some(from),
+            Some(to),
+            Some(MAX_MARKS),
+        )
+        .await
+        .map_err(|(status, Json(error))| udf_error(status, error.message))?;
+
+    Ok(Json(markers.iter().map(mark_from_signal).collect()))
+}
+
+/// UDF 라우터 생성.
+pub fn udf_router() -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/config", get(get_config))
+        .route("/time", get(get_time))
+        .route("/symbols", get(get_symbol))
+        .route("/search", get(search_symbols))
+        .route("/history", get(get_history))
+        .route("/marks", get(get_marks))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::create_test_state;
+    use axum::{body::Body, http::Request};
+    use rust_decimal_macros::dec;
+    use tower::ServiceExt;
+
+    fn kline(open_secs: i64, close: Decimal) -> Kline {
+        Kline {
+            ticker: "005930".to_string(),
+            timeframe: Timeframe::D1,
+            open_time: DateTime::from_timestamp(open_secs, 0).unwrap(),
+            open: close,
+            high: close,
+            low: close,
+            close,
+            volume: dec!(1000),
+            close_time: DateTime::from_timestamp(open_secs + 86_399, 0).unwrap(),
+            quote_volume: None,
+            num_trades: None,
+        }
+    }
+
+    #[test]
+    fn test_history_from_klines() {
+        let day = 86_400;
+        let klines = vec![
+            kline(3 * day, dec!(103)),
+            kline(day, dec!(101)),
+            kline(2 * day, dec!(102)),
+            kline(4 * day, dec!(104)),
+        ];
+
+        let history = history_from_klines(klines.clone(), 2 * day, 4 * day, None);
+        assert_eq!(history.s, "ok");
+        assert_eq!(history.t, Some(vec![2 * day, 3 * day]));
+        assert_eq!(history.c, Some(vec![dec!(102), dec!(103)]));
+
+        // countback은 from보다 우선
+        let history = history_from_klines(klines.clone(), 4 * day, 4 * day, Some(3));
+        assert_eq!(history.t, Some(vec![day, 2 * day, 3 * day]));
+
+        let history = history_from_klines(klines, 10 * day, 11 * day, None);
+        assert_eq!(history.s, "no_data");
+        let json = serde_json::to_value(&history).unwrap();
+        assert_eq!(json, serde_json::json!({"s": "no_data"}));
+    }
+
+    #[test]
+    fn test_mark_from_signal() {
+        let mut marker = SignalMarker::new(
+            "005930".to_string(),
+            DateTime::from_timestamp(1_767_225_600, 0).unwrap(),
+            SignalType::Entry,
+            dec!(70000),
+            "rsi_1",
+            "RSI",
+        );
+        marker.side = Some(Side::Buy);
+        marker.executed = true;
+
+        let mark = mark_from_signal(&marker);
+        assert_eq!(mark.time, 1_767_225_600);
+        assert_eq!((mark.color, mark.label), ("green", "B"));
+        assert!(mark.text.starts_with("[RSI] ENTRY @ 70000 (체결)"));
+
+        marker.side = None;
+        marker.signal_type = SignalType::Exit;
+        assert_eq!(mark_from_signal(&marker).label, "X");
+    }
+
+    #[tokio::test]
+    async fn test_config_and_history_validation() {
+        let app = udf_router().with_state(Arc::new(create_test_state()));
+
+        let response = app
+            .clone()
+            .oneshot(Request::get("/config").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let config: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(config["supports_marks"], true);
+        assert_eq!(config["supported_resolutions"][5], "60");
+
+        let response = app
+            .oneshot(
+                Request::get("/history?symbol=005930&resolution=45&from=0&to=86400")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let error: UdfError = serde_json::from_slice(&body).unwrap();
+        assert_eq!(error.s, "error");
+    }
+}
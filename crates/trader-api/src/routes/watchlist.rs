@@ -11,6 +11,10 @@
 //! - `POST /api/v1/watchlist/:id/items` - 아이템 추가
 //! - `DELETE /api/v1/watchlist/:id/items/:symbol` - 아이템 삭제
 //! - `PUT /api/v1/watchlist/items/:item_id` - 아이템 수정
+//! - `GET /api/v1/watchlist/:id/alerts` - 내 알림 규칙 조회
+//! - `POST /api/v1/watchlist/:id/alerts` - 알림 규칙 일괄 생성
+//! - `PATCH /api/v1/watchlist/:id/alerts` - 알림 규칙 일괄 활성화/비활성화
+//! - `DELETE /api/v1/watchlist/:id/alerts` - 알림 규칙 일괄 삭제
 
 use axum::{
     extract::{Path, Query, State},
@@ -18,18 +22,28 @@ use axum::{
     routing::{delete, get, post, put},
     Json, Router,
 };
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use tracing::{debug, info};
+use trader_core::Timeframe;
 use utoipa::ToSchema;
 use uuid::Uuid;
 
+use crate::auth::OptionalJwtAuth;
 use crate::repository::{
-    NewWatchlist, NewWatchlistItem, UpdateWatchlistItem, WatchlistItemRecord, WatchlistRecord,
+    NewWatchlist, NewWatchlistItem, UpdateWatchlistItem, WatchlistAlertInput, WatchlistAlertRecord,
+    WatchlistAlertRepository, WatchlistAlertRule, WatchlistItemRecord, WatchlistRecord,
     WatchlistRepository, WatchlistWithCount,
 };
 use crate::state::AppState;
 
+/// 한 번에 생성할 수 있는 최대 알림 규칙 수
+const MAX_ALERTS_PER_REQUEST: usize = 100;
+
+/// 최대 재알림 대기 (1주일, 분)
+const MAX_ALERT_COOLDOWN_MINUTES: i32 = 7 * 24 * 60;
+
 // ================================================================================================
 // Request/Response Types
 // ================================================================================================
@@ -90,6 +104,126 @@ pub struct SuccessResponse {
     pub message: String,
 }
 
+/// 알림 규칙 생성 항목
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct NewWatchlistAlert {
+    /// 그룹에 포함된 종목 코드
+    pub symbol: String,
+    /// 시장 (기본값: KR)
+    #[serde(default = "default_market")]
+    pub market: String,
+    /// 규칙 유형
+    pub rule: WatchlistAlertRule,
+    /// 임계값 (가격, 변동률 %, RSI)
+    pub threshold: Decimal,
+    /// 평가 캔들 타임프레임 (기본값: 1d)
+    #[serde(default = "default_alert_timeframe")]
+    pub timeframe: String,
+    /// RSI 기간 (기본값: 14, RSI 규칙만 사용)
+    #[serde(default = "default_rsi_period")]
+    pub rsi_period: i32,
+    /// 발동 후 재알림 대기 (분, 기본값: 60)
+    #[serde(default = "default_cooldown_minutes")]
+    pub cooldown_minutes: i32,
+}
+
+fn default_alert_timeframe() -> String {
+    "1d".to_string()
+}
+
+fn default_rsi_period() -> i32 {
+    14
+}
+
+fn default_cooldown_minutes() -> i32 {
+    60
+}
+
+impl NewWatchlistAlert {
+    /// 규칙 값 검증.
+    fn validate(&self) -> Result<(), String> {
+        if self.threshold <= Decimal::ZERO {
+            return Err(format!("{}: 임계값은 0보다 커야 합니다", self.symbol));
+        }
+        if self.rule.uses_rsi() {
+            if self.threshold >= Decimal::ONE_HUNDRED {
+                return Err(format!(
+                    "{}: RSI 임계값은 100 미만이어야 합니다",
+                    self.symbol
+                ));
+            }
+            if !(2..=100).contains(&self.rsi_period) {
+                return Err(format!("{}: RSI 기간은 2~100 사이여야 합니다", self.symbol));
+            }
+        }
+        if let Err(e) = self.timeframe.parse::<Timeframe>() {
+            return Err(format!("{}: {}", self.symbol, e));
+        }
+        if !(0..=MAX_ALERT_COOLDOWN_MINUTES).contains(&self.cooldown_minutes) {
+            return Err(format!(
+                "{}: 재알림 대기는 0~{}분 사이여야 합니다",
+                self.symbol, MAX_ALERT_COOLDOWN_MINUTES
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// 알림 규칙 일괄 생성 요청
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct AddAlertsRequest {
+    /// 생성할 규칙 목록 (최대 100개)
+    pub alerts: Vec<NewWatchlistAlert>,
+}
+
+impl AddAlertsRequest {
+    fn validate(&self) -> Result<(), String> {
+        if self.alerts.is_empty() {
+            return Err("생성할 알림 규칙이 없습니다".to_string());
+        }
+        if self.alerts.len() > MAX_ALERTS_PER_REQUEST {
+            return Err(format!(
+                "한 번에 최대 {}개까지 생성할 수 있습니다",
+                MAX_ALERTS_PER_REQUEST
+            ));
+        }
+        self.alerts.iter().try_for_each(NewWatchlistAlert::validate)
+    }
+}
+
+/// 알림 규칙 목록 응답
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WatchlistAlertListResponse {
+    /// 규칙 목록
+    pub alerts: Vec<WatchlistAlertRecord>,
+    /// 총 개수
+    pub total: usize,
+}
+
+/// 알림 규칙 일괄 활성화/비활성화 요청
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ToggleAlertsRequest {
+    /// 대상 규칙 ID (비우면 그룹 내 내 규칙 전체)
+    #[serde(default)]
+    pub ids: Vec<Uuid>,
+    /// 활성화 여부
+    pub enabled: bool,
+}
+
+/// 알림 규칙 일괄 삭제 요청
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct DeleteAlertsRequest {
+    /// 삭제할 규칙 ID
+    pub ids: Vec<Uuid>,
+}
+
+/// 알림 규칙 일괄 변경 응답
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BulkAlertResponse {
+    /// 변경된 규칙 수
+    pub affected: u64,
+}
+
 // ================================================================================================
 // Handlers
 // ================================================================================================
@@ -375,6 +509,225 @@ async fn find_symbol_in_watchlists(
     Ok(Json(watchlists))
 }
 
+/// 그룹 존재 여부 확인
+async fn ensure_watchlist_exists(
+    pool: &sqlx::PgPool,
+    id: Uuid,
+) -> Result<WatchlistRecord, (StatusCode, String)> {
+    WatchlistRepository::get_watchlist_by_id(pool, id)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("조회 실패: {}", e),
+            )
+        })?
+        .ok_or_else(|| (StatusCode::NOT_FOUND, "그룹을 찾을 수 없습니다".to_string()))
+}
+
+/// 요청 종목을 그룹 아이템에 연결 (종목/시장 대소문자 무시).
+fn resolve_alert_items(
+    items: &[WatchlistItemRecord],
+    alerts: &[NewWatchlistAlert],
+) -> Result<Vec<WatchlistAlertInput>, String> {
+    alerts
+        .iter()
+        .map(|alert| {
+            let item = items
+                .iter()
+                .find(|item| {
+                    item.symbol.eq_ignore_ascii_case(alert.symbol.trim())
+                        && item.market.eq_ignore_ascii_case(&alert.market)
+                })
+                .ok_or_else(|| {
+                    format!("{} ({}) 종목이 그룹에 없습니다", alert.symbol, alert.market)
+                })?;
+
+            Ok(WatchlistAlertInput {
+                item_id: item.id,
+                rule: alert.rule,
+                threshold: alert.threshold,
+                timeframe: alert.timeframe.clone(),
+                rsi_period: alert.rsi_period,
+                cooldown_minutes: alert.cooldown_minutes,
+            })
+        })
+        .collect()
+}
+
+/// GET /api/v1/watchlist/:id/alerts - 내 알림 규칙 조회
+async fn list_alerts(
+    State(state): State<Arc<AppState>>,
+    auth: OptionalJwtAuth,
+    Path(id): Path<Uuid>,
+) -> Result<Json<WatchlistAlertListResponse>, (StatusCode, String)> {
+    debug!("관심종목 알림 규칙 조회: {}", id);
+
+    let pool = state.db_pool.as_ref().ok_or_else(|| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "Database not available".to_string(),
+        )
+    })?;
+
+    ensure_watchlist_exists(pool, id).await?;
+
+    let owner_id = auth.0.as_ref().map(|claims| claims.sub.as_str());
+    let alerts = WatchlistAlertRepository::list_by_watchlist(pool, id, owner_id)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("알림 규칙 조회 실패: {}", e),
+            )
+        })?;
+
+    let total = alerts.len();
+
+    Ok(Json(WatchlistAlertListResponse { alerts, total }))
+}
+
+/// POST /api/v1/watchlist/:id/alerts - 알림 규칙 일괄 생성
+async fn create_alerts(
+    State(state): State<Arc<AppState>>,
+    auth: OptionalJwtAuth,
+    Path(id): Path<Uuid>,
+    Json(request): Json<AddAlertsRequest>,
+) -> Result<Json<WatchlistAlertListResponse>, (StatusCode, String)> {
+    request
+        .validate()
+        .map_err(|e| (StatusCode::BAD_REQUEST, e))?;
+
+    info!(
+        "관심종목 알림 규칙 생성: {} ({}개)",
+        id,
+        request.alerts.len()
+    );
+
+    let pool = state.db_pool.as_ref().ok_or_else(|| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "Database not available".to_string(),
+        )
+    })?;
+
+    ensure_watchlist_exists(pool, id).await?;
+
+    let items = WatchlistRepository::get_items_by_watchlist(pool, id)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("아이템 조회 실패: {}", e),
+            )
+        })?;
+    let inputs =
+        resolve_alert_items(&items, &request.alerts).map_err(|e| (StatusCode::BAD_REQUEST, e))?;
+
+    let owner_id = auth.0.as_ref().map(|claims| claims.sub.as_str());
+    let created_ids = WatchlistAlertRepository::create_batch(pool, id, owner_id, &inputs)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("알림 규칙 생성 실패: {}", e),
+            )
+        })?;
+
+    let alerts: Vec<WatchlistAlertRecord> =
+        WatchlistAlertRepository::list_by_watchlist(pool, id, owner_id)
+            .await
+            .map_err(|e| {
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    format!("알림 규칙 조회 실패: {}", e),
+                )
+            })?
+            .into_iter()
+            .filter(|alert| created_ids.contains(&alert.id))
+            .collect();
+
+    let total = alerts.len();
+
+    Ok(Json(WatchlistAlertListResponse { alerts, total }))
+}
+
+/// PATCH /api/v1/watchlist/:id/alerts - 알림 규칙 일괄 활성화/비활성화
+async fn toggle_alerts(
+    State(state): State<Arc<AppState>>,
+    auth: OptionalJwtAuth,
+    Path(id): Path<Uuid>,
+    Json(request): Json<ToggleAlertsRequest>,
+) -> Result<Json<BulkAlertResponse>, (StatusCode, String)> {
+    let action = if request.enabled {
+        "활성화"
+    } else {
+        "비활성화"
+    };
+    info!(
+        "관심종목 알림 규칙 {}: {} ({}개)",
+        action,
+        id,
+        request.ids.len()
+    );
+
+    let pool = state.db_pool.as_ref().ok_or_else(|| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "Database not available".to_string(),
+        )
+    })?;
+
+    let owner_id = auth.0.as_ref().map(|claims| claims.sub.as_str());
+    let affected =
+        WatchlistAlertRepository::set_enabled(pool, id, owner_id, &request.ids, request.enabled)
+            .await
+            .map_err(|e| {
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    format!("알림 규칙 수정 실패: {}", e),
+                )
+            })?;
+
+    Ok(Json(BulkAlertResponse { affected }))
+}
+
+/// DELETE /api/v1/watchlist/:id/alerts - 알림 규칙 일괄 삭제
+async fn delete_alerts(
+    State(state): State<Arc<AppState>>,
+    auth: OptionalJwtAuth,
+    Path(id): Path<Uuid>,
+    Json(request): Json<DeleteAlertsRequest>,
+) -> Result<Json<BulkAlertResponse>, (StatusCode, String)> {
+    if request.ids.is_empty() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "삭제할 알림 규칙 ID가 없습니다".to_string(),
+        ));
+    }
+
+    info!("관심종목 알림 규칙 삭제: {} ({}개)", id, request.ids.len());
+
+    let pool = state.db_pool.as_ref().ok_or_else(|| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "Database not available".to_string(),
+        )
+    })?;
+
+    let owner_id = auth.0.as_ref().map(|claims| claims.sub.as_str());
+    let affected = WatchlistAlertRepository::delete_batch(pool, id, owner_id, &request.ids)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                format!("알림 규칙 삭제 실패: {}", e),
+            )
+        })?;
+
+    Ok(Json(BulkAlertResponse { affected }))
+}
+
 // ================================================================================================
 // Router
 // ================================================================================================
@@ -389,6 +742,112 @@ pub fn watchlist_router() -> Router<Arc<AppState>> {
         .route("/{id}/items", post(add_items))
         .route("/{id}/items/{symbol}", delete(remove_item))
         .route("/items/{item_id}", put(update_item))
+        // 알림 규칙 (사용자별)
+        .route(
+            "/{id}/alerts",
+            get(list_alerts)
+                .post(create_alerts)
+                .patch(toggle_alerts)
+                .delete(delete_alerts),
+        )
         // 종목 검색
         .route("/symbol/{symbol}", get(find_symbol_in_watchlists))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::Body;
+    use axum::http::Request;
+    use chrono::Utc;
+    use rust_decimal_macros::dec;
+    use serde_json::json;
+    use tower::ServiceExt;
+
+    fn alert(symbol: &str, rule: WatchlistAlertRule, threshold: Decimal) -> NewWatchlistAlert {
+        NewWatchlistAlert {
+            symbol: symbol.to_string(),
+            market: default_market(),
+            rule,
+            threshold,
+            timeframe: default_alert_timeframe(),
+            rsi_period: default_rsi_period(),
+            cooldown_minutes: default_cooldown_minutes(),
+        }
+    }
+
+    #[test]
+    fn test_validate_alert() {
+        assert!(alert("005930", WatchlistAlertRule::PriceAbove, dec!(80000))
+            .validate()
+            .is_ok());
+        assert!(alert("005930", WatchlistAlertRule::PctMove, dec!(0))
+            .validate()
+            .is_err());
+        assert!(alert("005930", WatchlistAlertRule::RsiBelow, dec!(100))
+            .validate()
+            .is_err());
+
+        let mut short_rsi = alert("005930", WatchlistAlertRule::RsiAbove, dec!(70));
+        short_rsi.rsi_period = 1;
+        assert!(short_rsi.validate().is_err());
+
+        let mut bad_timeframe = alert("005930", WatchlistAlertRule::PriceBelow, dec!(60000));
+        bad_timeframe.timeframe = "7x".to_string();
+        assert!(bad_timeframe.validate().is_err());
+
+        assert!(AddAlertsRequest { alerts: vec![] }.validate().is_err());
+    }
+
+    #[test]
+    fn test_resolve_alert_items() {
+        let now = Utc::now();
+        let item = WatchlistItemRecord {
+            id: Uuid::new_v4(),
+            watchlist_id: Uuid::new_v4(),
+            symbol: "AAPL".to_string(),
+            market: "US".to_string(),
+            memo: None,
+            target_price: None,
+            stop_price: None,
+            alert_enabled: None,
+            sort_order: 0,
+            added_price: None,
+            created_at: now,
+            updated_at: now,
+        };
+
+        let mut aapl = alert("aapl", WatchlistAlertRule::RsiBelow, dec!(30));
+        aapl.market = "us".to_string();
+        let inputs = resolve_alert_items(std::slice::from_ref(&item), &[aapl]).unwrap();
+        assert_eq!(inputs[0].item_id, item.id);
+        assert_eq!(inputs[0].rule, WatchlistAlertRule::RsiBelow);
+
+        // 시장이 다르면 다른 종목
+        let kr = alert("AAPL", WatchlistAlertRule::PriceAbove, dec!(200));
+        assert!(resolve_alert_items(&[item], &[kr]).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_create_alerts_validation() {
+        let state = Arc::new(crate::state::create_test_state());
+        let app = watchlist_router().with_state(state);
+
+        let body = json!({
+            "alerts": [{"symbol": "005930", "rule": "rsi_above", "threshold": 120}]
+        });
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(format!("/{}/alerts", Uuid::new_v4()))
+                    .header("content-type", "application/json")
+                    .body(Body::from(body.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+}
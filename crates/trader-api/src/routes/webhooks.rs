@@ -0,0 +1,377 @@
+//! 아웃바운드 웹훅 endpoint.
+//!
+//! 체결, 포지션 변경, 전략 상태 전이 이벤트를 외부로 전송할 웹훅을 관리합니다.
+//! 실제 전송은 `services::webhook_publisher`가 수행합니다.
+//!
+//! # 엔드포인트
+//!
+//! - `GET /api/v1/webhooks` - 웹훅 목록
+//! - `POST /api/v1/webhooks` - 웹훅 등록
+//! - `GET /api/v1/webhooks/{id}` - 웹훅 조회
+//! - `PUT /api/v1/webhooks/{id}` - 웹훅 수정
+//! - `DELETE /api/v1/webhooks/{id}` - 웹훅 삭제
+//! - `GET /api/v1/webhooks/{id}/deliveries` - 전송 로그
+//! - `POST /api/v1/webhooks/{id}/test` - 테스트 이벤트 전송
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    routing::{get, post},
+    Json, Router,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tracing::info;
+use trader_notification::{WebhookClient, WebhookDelivery, WebhookEvent};
+use uuid::Uuid;
+
+use super::common::{db_conflict_response, db_error_response, require_pool};
+use crate::error::{ApiErrorResponse, ApiResult};
+use crate::repository::{
+    OutboundWebhookInput, OutboundWebhookRecord, OutboundWebhookRepository, WebhookDeliveryRecord,
+};
+use crate::state::AppState;
+
+// ==================== 요청/응답 타입 ====================
+
+/// 웹훅 등록/수정 요청.
+#[derive(Debug, Deserialize)]
+pub struct WebhookRequest {
+    /// 웹훅 이름
+    pub name: String,
+    /// 수신 URL (http/https)
+    pub url: String,
+    /// HMAC 서명 키 (수정 시 생략하면 기존 값 유지)
+    pub secret: Option<String>,
+    /// 구독 이벤트 필터 (예: `order.filled`, `position.*`; 비어 있으면 전체)
+    #[serde(default)]
+    pub events: Vec<String>,
+    /// 활성화 여부 (기본값: true)
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+/// 웹훅 응답 (서명 키는 노출하지 않음).
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WebhookResponse {
+    pub id: Uuid,
+    pub name: String,
+    pub url: String,
+    /// 서명 키 설정 여부
+    pub has_secret: bool,
+    pub events: Vec<String>,
+    pub enabled: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<OutboundWebhookRecord> for WebhookResponse {
+    fn from(record: OutboundWebhookRecord) -> Self {
+        Self {
+            has_secret: record.has_secret(),
+            id: record.id,
+            name: record.name,
+            url: record.url,
+            events: record.events,
+            enabled: record.enabled,
+            created_at: record.created_at,
+            updated_at: record.updated_at,
+        }
+    }
+}
+
+/// 웹훅 목록 응답.
+#[derive(Debug, Serialize)]
+pub struct WebhooksListResponse {
+    pub total: usize,
+    pub webhooks: Vec<WebhookResponse>,
+}
+
+/// 전송 로그 조회 쿼리.
+#[derive(Debug, Deserialize)]
+pub struct DeliveriesQuery {
+    /// 최대 건수 (기본값: 50)
+    #[serde(default = "default_deliveries_limit")]
+    pub limit: i64,
+}
+
+fn default_deliveries_limit() -> i64 {
+    50
+}
+
+/// 전송 로그 응답.
+#[derive(Debug, Serialize)]
+pub struct DeliveriesResponse {
+    pub total: usize,
+    /// 전송 로그 (최신순)
+    pub deliveries: Vec<WebhookDeliveryRecord>,
+}
+
+// ==================== 헬퍼 ====================
+
+fn name_conflict_response(err: sqlx::Error) -> (StatusCode, Json<ApiErrorResponse>) {
+    db_conflict_response(err, "WEBHOOK_NAME_CONFLICT", "Webhook name already exists")
+}
+
+fn not_found(id: Uuid) -> (StatusCode, Json<ApiErrorResponse>) {
+    (
+        StatusCode::NOT_FOUND,
+        Json(ApiErrorResponse::new(
+            "WEBHOOK_NOT_FOUND",
+            format!("Webhook not found: {}", id),
+        )),
+    )
+}
+
+/// 요청 검증 후 저장 입력으로 변환.
+#[allow(clippy::result_large_err)]
+fn validate_request(
+    request: WebhookRequest,
+) -> Result<OutboundWebhookInput, (StatusCode, Json<ApiErrorResponse>)> {
+    let invalid = |msg: &str| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(ApiErrorResponse::new("INVALID_WEBHOOK", msg)),
+        )
+    };
+
+    let name = request.name.trim().to_string();
+    if name.is_empty() {
+        return Err(invalid("name is required"));
+    }
+
+    let url = request.url.trim().to_string();
+    match reqwest::Url::parse(&url) {
+        Ok(parsed) if matches!(parsed.scheme(), "http" | "https") => {}
+        _ => return Err(invalid("url must be an absolute http(s) URL")),
+    }
+
+    let events: Vec<String> = request
+        .events
+        .into_iter()
+        .map(|e| e.trim().to_string())
+        .filter(|e| !e.is_empty())
+        .collect();
+
+    Ok(OutboundWebhookInput {
+        name,
+        url,
+        secret: request.secret,
+        events,
+        enabled: request.enabled,
+    })
+}
+
+// ==================== 핸들러 ====================
+
+/// 웹훅 목록 조회.
+///
+/// GET /api/v1/webhooks
+pub async fn list_webhooks(
+    State(state): State<Arc<AppState>>,
+) -> ApiResult<Json<WebhooksListResponse>> {
+    let pool = require_pool(&state)?;
+    let webhooks: Vec<WebhookResponse> = OutboundWebhookRepository::list(pool)
+        .await
+        .map_err(db_error_response)?
+        .into_iter()
+        .map(Into::into)
+        .collect();
+
+    Ok(Json(WebhooksListResponse {
+        total: webhooks.len(),
+        webhooks,
+    }))
+}
+
+/// 웹훅 등록.
+///
+/// POST /api/v1/webhooks
+pub async fn create_webhook(
+    State(state): State<Arc<AppState>>,
+    Json(request): Json<WebhookRequest>,
+) -> ApiResult<(StatusCode, Json<WebhookResponse>)> {
+    let input = validate_request(request)?;
+    let pool = require_pool(&state)?;
+
+    let record = OutboundWebhookRepository::create(pool, &input)
+        .await
+        .map_err(name_conflict_response)?;
+
+    info!(id = %record.id, name = %record.name, "아웃바운드 웹훅 등록");
+    Ok((StatusCode::CREATED, Json(record.into())))
+}
+
+/// 웹훅 조회.
+///
+/// GET /api/v1/webhooks/{id}
+pub async fn get_webhook(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Json<WebhookResponse>> {
+    let pool = require_pool(&state)?;
+    OutboundWebhookRepository::get(pool, id)
+        .await
+        .map_err(db_error_response)?
+        .map(|record| Json(record.into()))
+        .ok_or_else(|| not_found(id))
+}
+
+/// 웹훅 수정.
+///
+/// PUT /api/v1/webhooks/{id}
+pub async fn update_webhook(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+    Json(request): Json<WebhookRequest>,
+) -> ApiResult<Json<WebhookResponse>> {
+    let input = validate_request(request)?;
+    let pool = require_pool(&state)?;
+
+    OutboundWebhookRepository::update(pool, id, &input)
+        .await
+        .map_err(name_conflict_response)?
+        .map(|record| Json(record.into()))
+        .ok_or_else(|| not_found(id))
+}
+
+/// 웹훅 삭제.
+///
+/// DELETE /api/v1/webhooks/{id}
+pub async fn delete_webhook(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<StatusCode> {
+    let pool = require_pool(&state)?;
+    if OutboundWebhookRepository::delete(pool, id)
+        .await
+        .map_err(db_error_response)?
+    {
+        info!(%id, "아웃바운드 웹훅 삭제");
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err(not_found(id))
+    }
+}
+
+/// 전송 로그 조회.
+///
+/// GET /api/v1/webhooks/{id}/deliveries
+pub async fn list_webhook_deliveries(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<DeliveriesQuery>,
+) -> ApiResult<Json<DeliveriesResponse>> {
+    let pool = require_pool(&state)?;
+    let deliveries =
+        OutboundWebhookRepository::list_deliveries(pool, id, query.limit.clamp(1, 500))
+            .await
+            .map_err(db_error_response)?;
+
+    Ok(Json(DeliveriesResponse {
+        total: deliveries.len(),
+        deliveries,
+    }))
+}
+
+/// 테스트 이벤트(`webhook.test`) 전송.
+///
+/// 이벤트 필터와 무관하게 전송하며 결과를 전송 로그에 기록합니다.
+///
+/// POST /api/v1/webhooks/{id}/test
+pub async fn test_webhook(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Json<WebhookDelivery>> {
+    let pool = require_pool(&state)?;
+    let record = OutboundWebhookRepository::get(pool, id)
+        .await
+        .map_err(db_error_response)?
+        .ok_or_else(|| not_found(id))?;
+
+    let event = WebhookEvent::new(
+        "webhook.test",
+        serde_json::json!({ "webhook_id": record.id, "name": record.name }),
+    );
+    let delivery = WebhookClient::default()
+        .deliver(&record.endpoint(), &event)
+        .await;
+
+    OutboundWebhookRepository::record_delivery(pool, record.id, &event, &delivery)
+        .await
+        .map_err(db_error_response)?;
+
+    Ok(Json(delivery))
+}
+
+// ==================== 라우터 ====================
+
+/// 아웃바운드 웹훅 라우터 생성.
+pub fn webhooks_router() -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/", get(list_webhooks).post(create_webhook))
+        .route(
+            "/{id}",
+            get(get_webhook).put(update_webhook).delete(delete_webhook),
+        )
+        .route("/{id}/deliveries", get(list_webhook_deliveries))
+        .route("/{id}/test", post(test_webhook))
+}
+
+// ==================== 테스트 ====================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::create_test_state;
+    use axum::body::Body;
+    use axum::http::Request;
+    use tower::ServiceExt;
+
+    fn request(url: &str, events: &[&str]) -> WebhookRequest {
+        WebhookRequest {
+            name: " fills ".to_string(),
+            url: url.to_string(),
+            secret: None,
+            events: events.iter().map(|e| e.to_string()).collect(),
+            enabled: true,
+        }
+    }
+
+    #[test]
+    fn test_validate_request() {
+        let input =
+            validate_request(request("https://example.com/hook", &["order.*", " "])).unwrap();
+        assert_eq!(input.name, "fills");
+        assert_eq!(input.events, vec!["order.*".to_string()]);
+
+        let (status, _) = validate_request(request("ftp://example.com", &[])).unwrap_err();
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert!(validate_request(request("not a url", &[])).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_create_webhook_rejects_invalid_url() {
+        let app = webhooks_router().with_state(Arc::new(create_test_state()));
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/")
+                    .header("content-type", "application/json")
+                    .body(Body::from(
+                        serde_json::json!({ "name": "fills", "url": "localhost" }).to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+}
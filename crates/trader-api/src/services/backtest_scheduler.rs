@@ -0,0 +1,374 @@
+//! 정기 백테스트 스케줄러.
+//!
+//! 정기 실행 주기가 설정된 백테스트 템플릿(`backtest_template.schedule_interval_days`)을
+//! 최신 데이터로 재실행하고 결과를 `backtest_template_run`에 시계열로 저장합니다.
+//! 최신 결과가 과거 실행 대비 크게 악화되면(최대 낙폭 경신, 수익률 급락 등)
+//! 텔레그램 알림과 `strategy.backtest_regression` 이벤트를 발행합니다.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use sqlx::PgPool;
+use tokio_util::sync::CancellationToken;
+use tracing::{info, warn};
+use trader_notification::NotificationManager;
+
+use crate::repository::{
+    BacktestTemplateRecord, BacktestTemplateRepository, BacktestTemplateRunInput,
+    BacktestTemplateRunRecord,
+};
+use crate::routes::backtest::{run_template_metrics, BacktestMetricsResponse};
+use crate::services::notification_digest::build_notifier;
+use crate::state::AppState;
+use crate::websocket::{ServerMessage, StrategyUpdateData};
+
+/// 한 번의 점검에서 실행할 최대 템플릿 수.
+const MAX_DUE_PER_TICK: i64 = 10;
+
+/// 악화 판정에 사용할 과거 실행 수.
+const HISTORY_WINDOW: i64 = 20;
+
+/// 정기 백테스트 스케줄러 설정.
+#[derive(Debug, Clone)]
+pub struct BacktestSchedulerConfig {
+    /// 실행 시각 도래 여부 점검 주기
+    pub poll_interval: Duration,
+    /// 악화 판정 임계값
+    pub thresholds: RegressionThresholds,
+}
+
+impl BacktestSchedulerConfig {
+    /// 환경변수에서 설정 로드.
+    ///
+    /// `BACKTEST_SCHEDULER_ENABLED=false`이면 `None`을 반환합니다.
+    pub fn from_env() -> Option<Self> {
+        let enabled = std::env::var("BACKTEST_SCHEDULER_ENABLED")
+            .map(|v| v.to_lowercase() != "false")
+            .unwrap_or(true);
+        if !enabled {
+            return None;
+        }
+
+        let poll_interval = std::env::var("BACKTEST_SCHEDULER_POLL_SECS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .filter(|secs| *secs > 0)
+            .map(Duration::from_secs)
+            .unwrap_or(Duration::from_secs(3600));
+
+        Some(Self {
+            poll_interval,
+            thresholds: RegressionThresholds::default(),
+        })
+    }
+}
+
+/// 성과 악화 판정 임계값.
+#[derive(Debug, Clone)]
+pub struct RegressionThresholds {
+    /// 과거 최악 낙폭 대비 추가 낙폭 (%p)
+    pub drawdown_margin_pct: Decimal,
+    /// 직전 실행 대비 수익률 하락 (%p)
+    pub return_drop_pct: Decimal,
+    /// 과거 평균 대비 샤프 비율 하락
+    pub sharpe_drop: Decimal,
+}
+
+impl Default for RegressionThresholds {
+    fn default() -> Self {
+        Self {
+            drawdown_margin_pct: dec!(1),
+            return_drop_pct: dec!(5),
+            sharpe_drop: dec!(0.5),
+        }
+    }
+}
+
+/// 최신 결과를 과거 성공 실행과 비교하여 악화 항목을 반환.
+///
+/// `history`는 최신순이며, 성공한 실행만 비교에 사용합니다.
+/// 비교할 과거 실행이 없으면 빈 목록을 반환합니다.
+pub fn detect_regressions(
+    latest: &BacktestMetricsResponse,
+    history: &[BacktestTemplateRunRecord],
+    thresholds: &RegressionThresholds,
+) -> Vec<String> {
+    let previous: Vec<&BacktestTemplateRunRecord> = history.iter().filter(|r| r.success).collect();
+    if previous.is_empty() {
+        return Vec::new();
+    }
+
+    let mut regressions = Vec::new();
+
+    let latest_mdd = latest.max_drawdown_pct.abs();
+    if let Some(worst_mdd) = previous
+        .iter()
+        .filter_map(|r| r.max_drawdown_pct.map(|v| v.abs()))
+        .max()
+    {
+        if latest_mdd >= worst_mdd + thresholds.drawdown_margin_pct {
+            regressions.push(format!(
+                "최대 낙폭 경신: {:.2}% (과거 최악 {:.2}%)",
+                latest_mdd, worst_mdd
+            ));
+        }
+    }
+
+    if let Some(prev_return) = previous[0].total_return_pct {
+        if prev_return - latest.total_return_pct >= thresholds.return_drop_pct {
+            regressions.push(format!(
+                "수익률 하락: {:.2}% → {:.2}%",
+                prev_return, latest.total_return_pct
+            ));
+        }
+    }
+
+    let sharpes: Vec<Decimal> = previous.iter().filter_map(|r| r.sharpe_ratio).collect();
+    if !sharpes.is_empty() {
+        let mean = sharpes.iter().sum::<Decimal>() / Decimal::from(sharpes.len());
+        if mean - latest.sharpe_ratio >= thresholds.sharpe_drop {
+            regressions.push(format!(
+                "샤프 비율 하락: {:.2} (과거 평균 {:.2})",
+                latest.sharpe_ratio, mean
+            ));
+        }
+    }
+
+    regressions
+}
+
+/// 악화 감지 시 WebSocket으로 보낼 전략 업데이트 메시지 생성.
+pub fn regression_message(
+    template: &BacktestTemplateRecord,
+    run: &BacktestTemplateRunRecord,
+) -> ServerMessage {
+    ServerMessage::StrategyUpdate(StrategyUpdateData {
+        strategy_id: template.strategy_id.clone(),
+        name: template.name.clone(),
+        running: false,
+        event: "backtest_regression".to_string(),
+        data: serde_json::to_value(run).ok(),
+        timestamp: Utc::now().timestamp_millis(),
+    })
+}
+
+/// 템플릿 하나를 실행하고 결과를 저장 (악화 시 알림).
+async fn run_scheduled_template(
+    state: &Arc<AppState>,
+    pool: &PgPool,
+    notifier: &NotificationManager,
+    thresholds: &RegressionThresholds,
+    template: BacktestTemplateRecord,
+) -> Result<(), sqlx::Error> {
+    let history = BacktestTemplateRepository::list_runs(pool, template.id, HISTORY_WINDOW).await?;
+    let (start_date, end_date, result) = run_template_metrics(state, template.clone()).await;
+
+    let input = match &result {
+        Ok(metrics) => BacktestTemplateRunInput {
+            template_id: template.id,
+            start_date,
+            end_date,
+            success: true,
+            error: None,
+            total_return_pct: Some(metrics.total_return_pct),
+            max_drawdown_pct: Some(metrics.max_drawdown_pct),
+            sharpe_ratio: Some(metrics.sharpe_ratio),
+            total_trades: Some(metrics.total_trades as i32),
+            metrics: serde_json::to_value(metrics).ok(),
+            regressions: detect_regressions(metrics, &history, thresholds),
+        },
+        Err(e) => BacktestTemplateRunInput {
+            template_id: template.id,
+            start_date,
+            end_date,
+            success: false,
+            error: Some(e.clone()),
+            total_return_pct: None,
+            max_drawdown_pct: None,
+            sharpe_ratio: None,
+            total_trades: None,
+            metrics: None,
+            regressions: Vec::new(),
+        },
+    };
+
+    let run = BacktestTemplateRepository::insert_run(pool, &input).await?;
+    BacktestTemplateRepository::advance_schedule(pool, template.id).await?;
+
+    match &result {
+        Ok(_) if !run.regressions.is_empty() => {
+            warn!(
+                template = %template.name,
+                regressions = ?run.regressions,
+                "Scheduled backtest regressed"
+            );
+            state.broadcast(regression_message(&template, &run));
+            if let Err(e) = notifier
+                .notify_backtest_regression(
+                    &template.name,
+                    &template.strategy_id,
+                    run.total_return_pct.unwrap_or_default(),
+                    run.max_drawdown_pct.unwrap_or_default(),
+                    run.regressions.clone(),
+                )
+                .await
+            {
+                warn!(template = %template.name, error = %e, "Failed to send regression alert");
+            }
+        }
+        Ok(_) => info!(template = %template.name, "Scheduled backtest completed"),
+        Err(e) => warn!(template = %template.name, error = %e, "Scheduled backtest failed"),
+    }
+
+    Ok(())
+}
+
+/// 정기 백테스트 스케줄러 시작.
+///
+/// # Arguments
+///
+/// * `state` - 애플리케이션 상태 (백테스트 데이터 로드, WebSocket 브로드캐스트)
+/// * `pool` - 템플릿 및 실행 결과 DB
+/// * `config` - 스케줄러 설정
+/// * `shutdown` - Graceful shutdown을 위한 CancellationToken
+///
+/// # Returns
+///
+/// 백그라운드 task의 JoinHandle
+pub fn start_backtest_scheduler(
+    state: Arc<AppState>,
+    pool: PgPool,
+    config: BacktestSchedulerConfig,
+    shutdown: CancellationToken,
+) -> tokio::task::JoinHandle<()> {
+    let notifier = build_notifier();
+
+    let task = state.tasks.register(
+        "backtest_scheduler",
+        "백테스트 템플릿 정기 재실행 및 성과 악화 알림",
+        config.poll_interval,
+    );
+
+    tokio::spawn(async move {
+        info!(
+            poll_secs = config.poll_interval.as_secs(),
+            "Backtest scheduler started"
+        );
+        let mut ticker = tokio::time::interval(config.poll_interval);
+
+        loop {
+            if task.wait_next(&mut ticker, &shutdown).await.is_none() {
+                info!("Backtest scheduler stopped");
+                break;
+            }
+            let mut run = task.begin();
+
+            let due = match BacktestTemplateRepository::list_due(&pool, MAX_DUE_PER_TICK).await {
+                Ok(due) => due,
+                Err(e) => {
+                    warn!(error = %e, "Failed to load scheduled backtest templates");
+                    run.fail(e);
+                    continue;
+                }
+            };
+
+            for template in due {
+                if shutdown.is_cancelled() {
+                    break;
+                }
+                let name = template.name.clone();
+                if let Err(e) =
+                    run_scheduled_template(&state, &pool, &notifier, &config.thresholds, template)
+                        .await
+                {
+                    warn!(template = %name, error = %e, "Failed to record scheduled backtest");
+                    run.fail(e);
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+    use uuid::Uuid;
+
+    fn metrics(
+        total_return_pct: Decimal,
+        max_drawdown_pct: Decimal,
+        sharpe: Decimal,
+    ) -> BacktestMetricsResponse {
+        BacktestMetricsResponse {
+            total_return_pct,
+            annualized_return_pct: Decimal::ZERO,
+            net_profit: Decimal::ZERO,
+            total_trades: 10,
+            win_rate_pct: Decimal::ZERO,
+            profit_factor: Decimal::ZERO,
+            sharpe_ratio: sharpe,
+            sortino_ratio: Decimal::ZERO,
+            max_drawdown_pct,
+            calmar_ratio: Decimal::ZERO,
+            avg_win: Decimal::ZERO,
+            avg_loss: Decimal::ZERO,
+            largest_win: Decimal::ZERO,
+            largest_loss: Decimal::ZERO,
+        }
+    }
+
+    fn run(
+        success: bool,
+        total_return_pct: Decimal,
+        max_drawdown_pct: Decimal,
+        sharpe: Decimal,
+    ) -> BacktestTemplateRunRecord {
+        BacktestTemplateRunRecord {
+            id: Uuid::new_v4(),
+            template_id: Uuid::nil(),
+            start_date: NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+            end_date: NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+            success,
+            error: None,
+            total_return_pct: Some(total_return_pct),
+            max_drawdown_pct: Some(max_drawdown_pct),
+            sharpe_ratio: Some(sharpe),
+            total_trades: Some(10),
+            metrics: None,
+            regressions: Vec::new(),
+            run_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_detect_regressions() {
+        let thresholds = RegressionThresholds::default();
+
+        // 비교 대상 없음
+        let latest = metrics(dec!(-20), dec!(40), dec!(-1));
+        assert!(detect_regressions(&latest, &[], &thresholds).is_empty());
+        let failed = [run(false, dec!(50), dec!(5), dec!(3))];
+        assert!(detect_regressions(&latest, &failed, &thresholds).is_empty());
+
+        let history = [
+            run(true, dec!(12), dec!(10), dec!(1.2)),
+            run(true, dec!(15), dec!(15), dec!(1.4)),
+        ];
+
+        // 과거 범위 안의 결과
+        let stable = metrics(dec!(10), dec!(15.5), dec!(1.0));
+        assert!(detect_regressions(&stable, &history, &thresholds).is_empty());
+
+        // 최대 낙폭 경신 + 수익률 급락 + 샤프 하락
+        let regressed = metrics(dec!(4), dec!(18), dec!(0.6));
+        let regressions = detect_regressions(&regressed, &history, &thresholds);
+        assert_eq!(regressions.len(), 3);
+        assert!(regressions[0].contains("최대 낙폭"));
+        assert!(regressions[1].contains("수익률"));
+        assert!(regressions[2].contains("샤프"));
+    }
+}
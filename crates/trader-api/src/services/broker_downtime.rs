@@ -0,0 +1,327 @@
+//! 브로커 점검/장애 모니터 서비스.
+//!
+//! 실행기와 공유하는 점검/장애 달력([`AppState::broker_downtime`])을 주기적으로 갱신합니다.
+//!
+//! - DB(`broker_downtime`)의 예정 점검 구간을 달력에 적재합니다.
+//! - 주문 서킷이 네트워크/타임아웃 오류로 열리면 장애로 기록하고, 서킷이 다시 열리지
+//!   않은 상태가 되면 복구 처리합니다. 감지 장애도 DB에 기록됩니다.
+//! - 진행 중인 점검/장애가 바뀌면 전략 컨텍스트의 `broker_unavailable` 플래그를 갱신하고
+//!   WebSocket `StrategyUpdate` 메시지로 브로드캐스트하며, 시작 시 알림을 전송합니다.
+//!
+//! 실행기는 같은 달력으로 점검/장애 중 신규 주문을 거부하거나 대기열에 보관합니다.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use tokio_util::sync::CancellationToken;
+use tracing::{info, warn};
+use trader_core::{BrokerDowntime, DowntimeSource};
+use trader_exchange::ErrorCategory;
+use trader_execution::OrderCircuitStatus;
+use trader_notification::{Notification, NotificationEvent, NotificationPriority};
+
+use crate::repository::BrokerDowntimeRepository;
+use crate::services::notification_digest::build_notifier;
+use crate::state::AppState;
+use crate::websocket::{ServerMessage, StrategyUpdateData};
+
+/// 점검/장애 모니터 설정.
+#[derive(Debug, Clone)]
+pub struct BrokerDowntimeMonitorConfig {
+    /// 점검 주기
+    pub check_interval: Duration,
+    /// 주문 서킷 상태로 장애 감지 여부
+    pub detect_outages: bool,
+    /// 끝난 구간을 메모리 달력에 유지하는 기간
+    pub retention: chrono::Duration,
+}
+
+impl BrokerDowntimeMonitorConfig {
+    /// 환경변수에서 설정 로드.
+    ///
+    /// `BROKER_DOWNTIME_MONITOR_ENABLED=false`이면 `None`을 반환합니다.
+    pub fn from_env() -> Option<Self> {
+        let enabled = std::env::var("BROKER_DOWNTIME_MONITOR_ENABLED")
+            .map(|v| v.to_lowercase() != "false")
+            .unwrap_or(true);
+        if !enabled {
+            return None;
+        }
+
+        let check_interval = std::env::var("BROKER_DOWNTIME_CHECK_INTERVAL_SECS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .filter(|secs| *secs > 0)
+            .map(Duration::from_secs)
+            .unwrap_or(Duration::from_secs(15));
+
+        let detect_outages = std::env::var("BROKER_OUTAGE_DETECTION_ENABLED")
+            .map(|v| v.to_lowercase() != "false")
+            .unwrap_or(true);
+
+        Some(Self {
+            check_interval,
+            detect_outages,
+            retention: chrono::Duration::days(1),
+        })
+    }
+}
+
+/// 주문 서킷 상태에서 장애 사유 추출.
+///
+/// 네트워크/타임아웃 오류로 Open된 서킷만 장애로 봅니다.
+/// 연속 주문 거부나 요청 한도 초과는 브로커 장애가 아니므로 제외합니다.
+pub fn detect_outage(statuses: &[OrderCircuitStatus]) -> Option<String> {
+    statuses
+        .iter()
+        .find(|s| {
+            s.state == "open"
+                && matches!(
+                    s.tripped_by,
+                    Some(ErrorCategory::Network | ErrorCategory::Timeout)
+                )
+        })
+        .map(|s| match &s.last_failure {
+            Some(failure) => format!("order circuit open on {}: {}", s.venue, failure),
+            None => format!("order circuit open on {}", s.venue),
+        })
+}
+
+/// 점검/장애 상태 변화를 WebSocket 전략 업데이트 메시지로 변환.
+///
+/// 브로커 단위 메시지이므로 `strategy_id`는 빈 문자열입니다.
+pub fn downtime_message(broker: &str, downtime: Option<&BrokerDowntime>) -> ServerMessage {
+    ServerMessage::StrategyUpdate(StrategyUpdateData {
+        strategy_id: String::new(),
+        name: broker.to_string(),
+        running: true,
+        event: if downtime.is_some() {
+            "broker_unavailable".to_string()
+        } else {
+            "broker_available".to_string()
+        },
+        data: downtime.and_then(|d| serde_json::to_value(d).ok()),
+        timestamp: Utc::now().timestamp_millis(),
+    })
+}
+
+/// 점검/장애 시작 알림 (감지 장애는 긴급, 예정 점검은 높음).
+pub fn downtime_notification(downtime: &BrokerDowntime) -> Notification {
+    let (error_code, priority) = match downtime.source {
+        DowntimeSource::Detected => ("BROKER_OUTAGE", NotificationPriority::Critical),
+        DowntimeSource::Scheduled => ("BROKER_MAINTENANCE", NotificationPriority::High),
+    };
+    Notification::new(NotificationEvent::SystemError {
+        error_code: error_code.to_string(),
+        message: format!("브로커 주문 불가: {}", downtime.describe()),
+    })
+    .with_priority(priority)
+}
+
+/// 예정 점검 적재, 장애 감지, 달력 정리.
+async fn refresh_calendar(
+    state: &AppState,
+    pool: Option<&PgPool>,
+    broker: &str,
+    config: &BrokerDowntimeMonitorConfig,
+    now: DateTime<Utc>,
+) -> Result<(), sqlx::Error> {
+    if let Some(pool) = pool {
+        let records = BrokerDowntimeRepository::list_upcoming(pool, now).await?;
+        state
+            .broker_downtime
+            .write()
+            .await
+            .replace_scheduled(records.iter().filter_map(|r| r.to_window()));
+    }
+
+    if config.detect_outages {
+        let statuses = state.executor.read().await.order_circuit().statuses();
+        match detect_outage(&statuses) {
+            Some(reason) => {
+                let opened = state
+                    .broker_downtime
+                    .write()
+                    .await
+                    .report_outage(broker, reason, now);
+                if let (Some(outage), Some(pool)) = (opened, pool) {
+                    BrokerDowntimeRepository::insert(pool, &outage).await?;
+                }
+            }
+            None => {
+                let resolved = state
+                    .broker_downtime
+                    .write()
+                    .await
+                    .resolve_outage(broker, now);
+                if let (Some(outage), Some(pool)) = (resolved, pool) {
+                    BrokerDowntimeRepository::close(pool, outage.id, now).await?;
+                }
+            }
+        }
+    }
+
+    state
+        .broker_downtime
+        .write()
+        .await
+        .prune(now, config.retention);
+    Ok(())
+}
+
+/// 브로커 점검/장애 모니터 시작.
+///
+/// # Arguments
+///
+/// * `state` - 애플리케이션 상태 (공유 달력, 실행기, 전략 엔진)
+/// * `pool` - 점검 일정 DB (없으면 장애 감지만 수행)
+/// * `config` - 서비스 설정
+/// * `shutdown` - Graceful shutdown을 위한 CancellationToken
+///
+/// # Returns
+///
+/// 백그라운드 task의 JoinHandle
+pub async fn start_broker_downtime_monitor(
+    state: Arc<AppState>,
+    pool: Option<PgPool>,
+    config: BrokerDowntimeMonitorConfig,
+    shutdown: CancellationToken,
+) -> tokio::task::JoinHandle<()> {
+    let broker = state
+        .executor
+        .read()
+        .await
+        .broker_downtime()
+        .config()
+        .broker
+        .clone();
+    let notifier = build_notifier();
+
+    let task = state.tasks.register(
+        "broker_downtime_monitor",
+        "브로커 점검 일정 적재 및 장애 감지",
+        config.check_interval,
+    );
+
+    tokio::spawn(async move {
+        // 이전 실행에서 닫히지 않은 감지 장애 정리 (현재 장애는 서킷 상태로 다시 감지)
+        if let Some(pool) = pool.as_ref() {
+            if let Err(e) = BrokerDowntimeRepository::close_open_outages(pool, Utc::now()).await {
+                warn!(error = %e, "Failed to close stale broker outages");
+            }
+        }
+
+        info!(
+            broker = %broker,
+            check_secs = config.check_interval.as_secs(),
+            detect_outages = config.detect_outages,
+            "Broker downtime monitor started"
+        );
+        let mut ticker = tokio::time::interval(config.check_interval);
+        let mut current: Option<BrokerDowntime> = None;
+
+        loop {
+            if task.wait_next(&mut ticker, &shutdown).await.is_none() {
+                info!("Broker downtime monitor stopped");
+                break;
+            }
+            let mut run = task.begin();
+            let now = Utc::now();
+
+            if let Err(e) = refresh_calendar(&state, pool.as_ref(), &broker, &config, now).await {
+                warn!(error = %e, "Failed to refresh broker downtime calendar");
+                run.fail(e);
+            }
+
+            let active = state
+                .broker_downtime
+                .read()
+                .await
+                .active(&broker, now)
+                .cloned();
+            if active == current {
+                continue;
+            }
+
+            state
+                .strategy_engine
+                .read()
+                .await
+                .update_broker_downtime(active.clone())
+                .await;
+            state.broadcast(downtime_message(&broker, active.as_ref()));
+
+            // 같은 점검/장애의 종료 시각 변경 등은 다시 알리지 않음
+            let started = active
+                .as_ref()
+                .filter(|a| current.as_ref().map_or(true, |c| c.id != a.id));
+            if let Some(downtime) = started {
+                if let Err(e) = notifier.notify(&downtime_notification(downtime)).await {
+                    warn!(error = %e, "Failed to send broker downtime alert");
+                }
+            }
+            current = active;
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn circuit_status(state: &str, tripped_by: Option<ErrorCategory>) -> OrderCircuitStatus {
+        OrderCircuitStatus {
+            venue: "default_exchange".to_string(),
+            state: state.to_string(),
+            failure_count: 5,
+            total_failures: 5,
+            total_successes: 0,
+            open_count: 1,
+            state_duration_secs: 3,
+            tripped_by,
+            last_failure: Some("Request timeout: kis".to_string()),
+            probe_in_flight: false,
+            queued_orders: 0,
+        }
+    }
+
+    #[test]
+    fn test_detect_outage_from_circuit() {
+        let reason =
+            detect_outage(&[circuit_status("open", Some(ErrorCategory::Timeout))]).unwrap();
+        assert_eq!(
+            reason,
+            "order circuit open on default_exchange: Request timeout: kis"
+        );
+
+        // 요청 한도 초과, 연속 거부, HalfOpen은 장애로 보지 않음
+        assert!(detect_outage(&[circuit_status("open", Some(ErrorCategory::RateLimit))]).is_none());
+        assert!(detect_outage(&[circuit_status("open", None)]).is_none());
+        assert!(
+            detect_outage(&[circuit_status("half_open", Some(ErrorCategory::Network))]).is_none()
+        );
+    }
+
+    #[test]
+    fn test_downtime_message_and_notification() {
+        let outage = BrokerDowntime::detected("kis", "connection reset", Utc::now());
+
+        let ServerMessage::StrategyUpdate(update) = downtime_message("kis", Some(&outage)) else {
+            panic!("expected strategy update");
+        };
+        assert_eq!(update.event, "broker_unavailable");
+        assert_eq!(update.data.unwrap()["source"], "detected");
+
+        let ServerMessage::StrategyUpdate(update) = downtime_message("kis", None) else {
+            panic!("expected strategy update");
+        };
+        assert_eq!(update.event, "broker_available");
+        assert!(update.data.is_none());
+
+        let notification = downtime_notification(&outage);
+        assert_eq!(notification.priority, NotificationPriority::Critical);
+        assert_eq!(notification.event.summary(), "시스템 오류 BROKER_OUTAGE");
+    }
+}
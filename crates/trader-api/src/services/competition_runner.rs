@@ -0,0 +1,454 @@
+//! 전략 경쟁 러너.
+//!
+//! 진행 중인 경쟁(`strategy_competition`)의 참가 전략을 전략 엔진에 모의 인스턴스로
+//! 연결하고, 참가자별 가상 계좌 평가액을 주기적으로 `strategy_competition_equity`에
+//! 기록합니다. 종료 시각이 지난 경쟁은 종료 처리하며, `auto_promote`가 켜져 있으면
+//! 리더보드 1위 참가자를 실거래 전략으로 등록합니다 (등록만 하고 시작하지 않음).
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+use rust_decimal::Decimal;
+use serde::Serialize;
+use sqlx::PgPool;
+use tokio_util::sync::CancellationToken;
+use tracing::{info, warn};
+use trader_strategy::{
+    rank_by_risk_adjusted_return, risk_adjusted_return, EquitySample, RiskAdjustedReturn,
+    StrategyRegistry,
+};
+use uuid::Uuid;
+
+use crate::repository::{
+    strategies::CreateStrategyInput, CompetitionEntryRecord, CompetitionEquityRecord,
+    CompetitionRecord, CompetitionRepository, StrategyRepository,
+};
+use crate::state::AppState;
+use crate::websocket::{ServerMessage, StrategyUpdateData};
+
+/// 전략 경쟁 러너 설정.
+#[derive(Debug, Clone)]
+pub struct CompetitionRunnerConfig {
+    /// 평가액 기록 주기
+    pub snapshot_interval: Duration,
+}
+
+impl CompetitionRunnerConfig {
+    /// 환경변수에서 설정 로드.
+    ///
+    /// - `COMPETITION_RUNNER_ENABLED`: `false`이면 `None` (기본 true)
+    /// - `COMPETITION_SNAPSHOT_SECS`: 평가액 기록 주기 (기본 300초)
+    pub fn from_env() -> Option<Self> {
+        let enabled = std::env::var("COMPETITION_RUNNER_ENABLED")
+            .map(|v| v.to_lowercase() != "false")
+            .unwrap_or(true);
+        if !enabled {
+            return None;
+        }
+
+        let snapshot_interval = std::env::var("COMPETITION_SNAPSHOT_SECS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .filter(|secs| *secs > 0)
+            .map(Duration::from_secs)
+            .unwrap_or(Duration::from_secs(300));
+
+        Some(Self { snapshot_interval })
+    }
+}
+
+/// 리더보드 항목.
+#[derive(Debug, Clone, Serialize)]
+pub struct LeaderboardEntry {
+    /// 순위 (1부터)
+    pub rank: usize,
+    pub entry_id: Uuid,
+    /// 참가자 이름
+    pub name: String,
+    pub strategy_type: String,
+    /// 최근 기록 평가액
+    pub equity: Option<Decimal>,
+    /// 최근 기록 체결 횟수
+    pub trades: Option<i64>,
+    /// 구간 성과 (표본 부족 시 None)
+    pub metrics: Option<RiskAdjustedReturn>,
+    /// 승격된 실거래 전략 ID
+    pub promoted_strategy_id: Option<String>,
+}
+
+/// 경쟁 참가자를 전략 엔진에 연결.
+///
+/// 이미 연결된 참가자나 전략 타입을 찾을 수 없는 참가자는 건너뜁니다.
+/// 평가액 기록이 있는 참가자(서버 재시작 등)는 보유 종목 없이 마지막 평가액을
+/// 현금으로 하여 다시 시작하므로 평가액 시계열이 끊기지 않습니다.
+///
+/// # Returns
+///
+/// 새로 연결한 참가자 수
+pub async fn attach_competition(
+    state: &AppState,
+    pool: &PgPool,
+    competition: &CompetitionRecord,
+    entries: &[CompetitionEntryRecord],
+) -> Result<usize, sqlx::Error> {
+    let engine = state.strategy_engine.read().await;
+    let attached: Vec<String> = engine
+        .competitor_snapshots(Some(&competition.id.to_string()))
+        .await
+        .into_iter()
+        .map(|s| s.competitor_id)
+        .collect();
+    let missing: Vec<&CompetitionEntryRecord> = entries
+        .iter()
+        .filter(|e| !attached.contains(&e.competitor_id()))
+        .collect();
+    if missing.is_empty() {
+        return Ok(0);
+    }
+
+    let last_equity: HashMap<Uuid, Decimal> =
+        CompetitionRepository::latest_equity(pool, competition.id)
+            .await?
+            .into_iter()
+            .collect();
+
+    let mut count = 0;
+    for entry in missing {
+        let strategy = match StrategyRegistry::create_instance(&entry.strategy_type) {
+            Ok(strategy) => strategy,
+            Err(e) => {
+                warn!(entry = %entry.name, error = %e, "Unknown competition strategy type");
+                continue;
+            }
+        };
+        let mut book = competition.book_config();
+        if let Some(equity) = last_equity.get(&entry.id) {
+            book.initial_capital = *equity;
+        }
+
+        if engine
+            .add_competitor(
+                entry.competitor_id(),
+                competition.id.to_string(),
+                strategy,
+                entry.config.clone(),
+                book,
+            )
+            .await
+            .is_ok()
+        {
+            count += 1;
+        }
+    }
+    Ok(count)
+}
+
+/// 경쟁 참가자를 전략 엔진에서 해제.
+pub async fn detach_competition(state: &AppState, entries: &[CompetitionEntryRecord]) {
+    let engine = state.strategy_engine.read().await;
+    for entry in entries {
+        engine.remove_competitor(&entry.competitor_id()).await;
+    }
+}
+
+/// 기록된 평가액으로 리더보드 계산.
+///
+/// # Arguments
+///
+/// * `horizon_days` - 순위 산정 구간 (None이면 경쟁 전체 기간)
+pub async fn build_leaderboard(
+    pool: &PgPool,
+    competition: &CompetitionRecord,
+    horizon_days: Option<i64>,
+) -> Result<Vec<LeaderboardEntry>, sqlx::Error> {
+    let entries = CompetitionRepository::list_entries(pool, competition.id).await?;
+    let since = horizon_days.map(|days| Utc::now() - chrono::Duration::days(days));
+    let series = CompetitionRepository::equity_series(pool, competition.id, since).await?;
+
+    let mut by_entry: HashMap<Uuid, Vec<CompetitionEquityRecord>> = HashMap::new();
+    for record in series {
+        by_entry.entry(record.entry_id).or_default().push(record);
+    }
+
+    let mut leaderboard: Vec<LeaderboardEntry> = entries
+        .into_iter()
+        .map(|entry| {
+            let records = by_entry.remove(&entry.id).unwrap_or_default();
+            let samples: Vec<EquitySample> = records
+                .iter()
+                .map(|r| EquitySample {
+                    at: r.recorded_at,
+                    equity: r.equity,
+                })
+                .collect();
+            let last = records.last();
+            LeaderboardEntry {
+                rank: 0,
+                entry_id: entry.id,
+                name: entry.name,
+                strategy_type: entry.strategy_type,
+                equity: last.map(|r| r.equity),
+                trades: last.map(|r| r.trades),
+                metrics: risk_adjusted_return(&samples),
+                promoted_strategy_id: entry.promoted_strategy_id,
+            }
+        })
+        .collect();
+
+    rank_by_risk_adjusted_return(&mut leaderboard, |e| e.metrics.as_ref());
+    for (i, entry) in leaderboard.iter_mut().enumerate() {
+        entry.rank = i + 1;
+    }
+    Ok(leaderboard)
+}
+
+/// 참가 전략을 실거래 전략으로 승격.
+///
+/// 참가자의 전략 타입/설정으로 새 전략을 DB와 엔진에 등록합니다.
+/// 승격된 전략은 중지 상태로 등록되며, 시작은 기존 전략 시작 API로 합니다.
+///
+/// # Returns
+///
+/// 새 전략 ID
+pub async fn promote_entry(
+    state: &AppState,
+    pool: &PgPool,
+    competition: &CompetitionRecord,
+    entry: &CompetitionEntryRecord,
+) -> Result<String, String> {
+    let meta = StrategyRegistry::find(&entry.strategy_type)
+        .ok_or_else(|| format!("Unknown strategy type: {}", entry.strategy_type))?;
+    let strategy = StrategyRegistry::create_instance(&entry.strategy_type)?;
+
+    let strategy_id = format!(
+        "{}_{}",
+        entry.strategy_type,
+        &Uuid::new_v4().to_string()[..8]
+    );
+    let symbols: Vec<String> = entry
+        .config
+        .get("symbols")
+        .and_then(|v| v.as_array())
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|v| v.as_str().map(String::from))
+                .collect()
+        })
+        .unwrap_or_else(|| meta.default_tickers.iter().map(|s| s.to_string()).collect());
+    let timeframe = entry
+        .config
+        .get("timeframe")
+        .and_then(|v| v.as_str())
+        .unwrap_or(meta.default_timeframe)
+        .to_string();
+    let market = match symbols.first() {
+        Some(s) if s.chars().all(|c| c.is_numeric()) => "KR",
+        Some(s) if s.contains('/') => "CRYPTO",
+        _ => "US",
+    }
+    .to_string();
+
+    let name = format!("{} ({})", entry.name, competition.name);
+    StrategyRepository::create(
+        pool,
+        CreateStrategyInput {
+            id: strategy_id.clone(),
+            name: name.clone(),
+            description: Some(format!("Promoted from competition '{}'", competition.name)),
+            strategy_type: entry.strategy_type.clone(),
+            symbols,
+            market,
+            timeframe,
+            config: entry.config.clone(),
+            risk_config: None,
+            allocated_capital: None,
+            risk_profile: None,
+            multi_timeframe_config: None,
+            symbol_lock: None,
+            owner_id: None,
+        },
+    )
+    .await
+    .map_err(|e| e.to_string())?;
+
+    state
+        .strategy_engine
+        .read()
+        .await
+        .register_strategy(
+            &strategy_id,
+            strategy,
+            entry.config.clone(),
+            Some(name.clone()),
+        )
+        .await
+        .map_err(|e| e.to_string())?;
+
+    CompetitionRepository::mark_promoted(pool, entry.id, &strategy_id)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    state.broadcast(ServerMessage::StrategyUpdate(StrategyUpdateData {
+        strategy_id: strategy_id.clone(),
+        name,
+        running: false,
+        event: "promoted".to_string(),
+        data: Some(serde_json::json!({
+            "competition_id": competition.id,
+            "entry_id": entry.id,
+            "strategy_type": entry.strategy_type,
+        })),
+        timestamp: Utc::now().timestamp_millis(),
+    }));
+
+    info!(
+        competition = %competition.name,
+        entry = %entry.name,
+        strategy_id = %strategy_id,
+        "Promoted competition entrant to live strategy"
+    );
+    Ok(strategy_id)
+}
+
+/// 경쟁 종료 처리 (참가자 해제, 자동 승격).
+pub async fn finish_competition(
+    state: &AppState,
+    pool: &PgPool,
+    competition: &CompetitionRecord,
+) -> Result<Option<String>, sqlx::Error> {
+    let entries = CompetitionRepository::list_entries(pool, competition.id).await?;
+    record_snapshots(state, pool, competition, &entries).await;
+    detach_competition(state, &entries).await;
+
+    if !CompetitionRepository::finish(pool, competition.id).await? {
+        return Ok(None);
+    }
+    info!(competition = %competition.name, "Competition finished");
+
+    if !competition.auto_promote {
+        return Ok(None);
+    }
+
+    let horizon = competition.promotion_horizon_days.map(i64::from);
+    let leaderboard = build_leaderboard(pool, competition, horizon).await?;
+    let Some(winner) = leaderboard.first().filter(|e| e.metrics.is_some()) else {
+        warn!(competition = %competition.name, "No rankable entrant to promote");
+        return Ok(None);
+    };
+    let Some(entry) = entries.iter().find(|e| e.id == winner.entry_id) else {
+        return Ok(None);
+    };
+
+    match promote_entry(state, pool, competition, entry).await {
+        Ok(strategy_id) => Ok(Some(strategy_id)),
+        Err(e) => {
+            warn!(competition = %competition.name, error = %e, "Failed to promote winner");
+            Ok(None)
+        }
+    }
+}
+
+/// 참가자별 현재 평가액 기록.
+async fn record_snapshots(
+    state: &AppState,
+    pool: &PgPool,
+    competition: &CompetitionRecord,
+    entries: &[CompetitionEntryRecord],
+) {
+    let snapshots = state
+        .strategy_engine
+        .read()
+        .await
+        .competitor_snapshots(Some(&competition.id.to_string()))
+        .await;
+    let recorded_at = Utc::now();
+
+    for entry in entries {
+        let competitor_id = entry.competitor_id();
+        let Some(snapshot) = snapshots.iter().find(|s| s.competitor_id == competitor_id) else {
+            continue;
+        };
+        // 시장 데이터를 받기 전에는 기록하지 않음
+        if snapshot.last_data_at.is_none() {
+            continue;
+        }
+
+        let record = CompetitionEquityRecord {
+            entry_id: entry.id,
+            recorded_at,
+            equity: snapshot.equity,
+            cash: snapshot.cash,
+            open_positions: i32::try_from(snapshot.open_positions).unwrap_or(i32::MAX),
+            trades: i64::try_from(snapshot.trades).unwrap_or(i64::MAX),
+        };
+        if let Err(e) = CompetitionRepository::insert_equity(pool, &record).await {
+            warn!(entry = %entry.name, error = %e, "Failed to record competition equity");
+        }
+    }
+}
+
+/// 진행 중인 경쟁 처리 (연결 보장, 평가액 기록, 종료).
+async fn run_cycle(state: &AppState, pool: &PgPool) -> Result<(), sqlx::Error> {
+    let now = Utc::now();
+    for competition in CompetitionRepository::list_running(pool).await? {
+        if competition.ends_at.is_some_and(|ends_at| ends_at <= now) {
+            finish_competition(state, pool, &competition).await?;
+            continue;
+        }
+
+        let entries = CompetitionRepository::list_entries(pool, competition.id).await?;
+        attach_competition(state, pool, &competition, &entries).await?;
+        record_snapshots(state, pool, &competition, &entries).await;
+    }
+    Ok(())
+}
+
+/// 전략 경쟁 러너 시작.
+///
+/// 매 주기마다 진행 중인 경쟁의 참가자 연결을 보장하므로 서버 재시작 후에도 경쟁이 이어집니다.
+///
+/// # Arguments
+///
+/// * `state` - 애플리케이션 상태 (전략 엔진)
+/// * `pool` - 경쟁 DB
+/// * `config` - 러너 설정
+/// * `shutdown` - Graceful shutdown을 위한 CancellationToken
+///
+/// # Returns
+///
+/// 백그라운드 task의 JoinHandle
+pub fn start_competition_runner(
+    state: Arc<AppState>,
+    pool: PgPool,
+    config: CompetitionRunnerConfig,
+    shutdown: CancellationToken,
+) -> tokio::task::JoinHandle<()> {
+    let task = state.tasks.register(
+        "competition_runner",
+        "전략 경쟁 참가자 평가액 기록 및 종료 처리",
+        config.snapshot_interval,
+    );
+
+    tokio::spawn(async move {
+        info!(
+            snapshot_secs = config.snapshot_interval.as_secs(),
+            "Competition runner started"
+        );
+        let mut ticker = tokio::time::interval(config.snapshot_interval);
+
+        loop {
+            if task.wait_next(&mut ticker, &shutdown).await.is_none() {
+                info!("Competition runner stopped");
+                break;
+            }
+            let mut run = task.begin();
+
+            if let Err(e) = run_cycle(&state, &pool).await {
+                warn!(error = %e, "Competition runner cycle failed");
+                run.fail(e);
+            }
+        }
+    })
+}
@@ -0,0 +1,238 @@
+//! 서버 측 조건부 주문 서비스.
+//!
+//! active 상태의 조건부 주문(`conditional_order`)을 주기적으로 최신 캔들로 평가하고,
+//! 모든 조건이 충족되면 `OrderExecutor::process_order_request()`로 주문을 실행합니다.
+//! 미국 주식 매수는 `fx_conversion::submit_order_request()`로 USD 부족 시 환전을 먼저 확인합니다.
+//! 조건 평가는 `TriggerCalculator::evaluate_conditions()`를 사용하며,
+//! 만료 시각이 지난 주문은 expired로 전환합니다.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use sqlx::PgPool;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, info, warn};
+use trader_analytics::TriggerCalculator;
+use trader_core::{ConditionEvaluation, Kline, Timeframe};
+use trader_data::cache::CachedHistoricalDataProvider;
+
+use crate::repository::{ConditionalOrderRecord, ConditionalOrderRepository};
+use crate::services::fx_conversion::submit_order_request;
+use crate::state::AppState;
+
+/// 조건 평가에 사용할 캔들 수.
+const KLINE_LOOKBACK: usize = 200;
+
+/// 조건부 주문 서비스 설정.
+#[derive(Debug, Clone)]
+pub struct ConditionalOrderConfig {
+    /// 조건 평가 주기
+    pub poll_interval: Duration,
+}
+
+impl ConditionalOrderConfig {
+    /// 환경변수에서 설정 로드.
+    ///
+    /// `CONDITIONAL_ORDER_ENABLED=false`이면 `None`을 반환합니다.
+    pub fn from_env() -> Option<Self> {
+        let enabled = std::env::var("CONDITIONAL_ORDER_ENABLED")
+            .map(|v| v.to_lowercase() != "false")
+            .unwrap_or(true);
+        if !enabled {
+            return None;
+        }
+
+        let poll_interval = std::env::var("CONDITIONAL_ORDER_POLL_SECS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .filter(|secs| *secs > 0)
+            .map(Duration::from_secs)
+            .unwrap_or(Duration::from_secs(60));
+
+        Some(Self { poll_interval })
+    }
+}
+
+/// 조건부 주문 조건 평가.
+///
+/// 저장된 조건을 파싱하여 캔들 데이터로 평가합니다.
+pub fn evaluate_order(
+    calculator: &TriggerCalculator,
+    order: &ConditionalOrderRecord,
+    klines: &[Kline],
+) -> Result<ConditionEvaluation, String> {
+    let conditions = order
+        .parsed_conditions()
+        .map_err(|e| format!("Invalid conditions: {}", e))?;
+    calculator
+        .evaluate_conditions(klines, &conditions)
+        .map_err(|e| e.to_string())
+}
+
+/// 조건 충족 주문 실행.
+///
+/// 주문을 선점(active → triggered)한 뒤 실행기에 제출하고 결과를 기록합니다.
+async fn execute_order(
+    state: &AppState,
+    pool: &PgPool,
+    order: &ConditionalOrderRecord,
+    evaluation: &ConditionEvaluation,
+) -> Result<(), sqlx::Error> {
+    if !ConditionalOrderRepository::claim(pool, order.id).await? {
+        // 평가 중에 취소/만료됨
+        return Ok(());
+    }
+
+    let request = match order.to_order_request() {
+        Ok(request) => request,
+        Err(e) => {
+            warn!(id = %order.id, error = %e, "Invalid conditional order");
+            return ConditionalOrderRepository::record_execution(pool, order.id, None, Some(&e))
+                .await;
+        }
+    };
+
+    let result = submit_order_request(
+        state,
+        pool,
+        "conditional_order",
+        order.id,
+        request,
+        evaluation.last_price,
+    )
+    .await;
+
+    if result.success {
+        info!(
+            id = %order.id,
+            ticker = %order.ticker,
+            side = %order.side,
+            price = %evaluation.last_price,
+            order_id = ?result.order_id,
+            "Conditional order triggered"
+        );
+    } else {
+        warn!(
+            id = %order.id,
+            ticker = %order.ticker,
+            error = ?result.error,
+            "Conditional order execution failed"
+        );
+    }
+
+    ConditionalOrderRepository::record_execution(
+        pool,
+        order.id,
+        result.order_id,
+        result.error.as_deref(),
+    )
+    .await
+}
+
+/// active 주문 전체를 한 번 평가.
+async fn evaluate_active_orders(
+    state: &AppState,
+    pool: &PgPool,
+    provider: &CachedHistoricalDataProvider,
+    calculator: &TriggerCalculator,
+) -> Result<(), sqlx::Error> {
+    let expired = ConditionalOrderRepository::expire_due(pool).await?;
+    if !expired.is_empty() {
+        info!(count = expired.len(), "Conditional orders expired");
+    }
+
+    let orders = ConditionalOrderRepository::list_active(pool).await?;
+    if orders.is_empty() {
+        return Ok(());
+    }
+
+    // 같은 종목/타임프레임의 캔들은 한 번만 조회
+    let mut klines_cache: HashMap<(String, String), Result<Vec<Kline>, String>> = HashMap::new();
+
+    for order in &orders {
+        let key = (order.ticker.clone(), order.timeframe.clone());
+        if !klines_cache.contains_key(&key) {
+            let klines = match order.timeframe.parse::<Timeframe>() {
+                Ok(timeframe) => provider
+                    .get_klines(&order.ticker, timeframe, KLINE_LOOKBACK)
+                    .await
+                    .map_err(|e| format!("Failed to load klines: {}", e)),
+                Err(e) => Err(e),
+            };
+            klines_cache.insert(key.clone(), klines);
+        }
+
+        let evaluation = match &klines_cache[&key] {
+            Ok(klines) => evaluate_order(calculator, order, klines),
+            Err(e) => Err(e.clone()),
+        };
+
+        match evaluation {
+            Ok(evaluation) if evaluation.satisfied => {
+                execute_order(state, pool, order, &evaluation).await?;
+            }
+            Ok(_) => {
+                ConditionalOrderRepository::record_evaluation(pool, order.id, None).await?;
+            }
+            Err(e) => {
+                debug!(id = %order.id, error = %e, "Conditional order evaluation failed");
+                ConditionalOrderRepository::record_evaluation(pool, order.id, Some(&e)).await?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// 조건부 주문 서비스 시작.
+///
+/// # Arguments
+///
+/// * `state` - 애플리케이션 상태 (데이터 제공자, 실행기)
+/// * `pool` - 조건부 주문 DB
+/// * `config` - 서비스 설정
+/// * `shutdown` - Graceful shutdown을 위한 CancellationToken
+///
+/// # Returns
+///
+/// 백그라운드 task의 JoinHandle
+pub fn start_conditional_order_service(
+    state: Arc<AppState>,
+    pool: PgPool,
+    config: ConditionalOrderConfig,
+    shutdown: CancellationToken,
+) -> tokio::task::JoinHandle<()> {
+    let provider = state
+        .data_provider
+        .clone()
+        .unwrap_or_else(|| Arc::new(CachedHistoricalDataProvider::new(pool.clone())));
+
+    let task = state.tasks.register(
+        "conditional_orders",
+        "서버 측 조건부 주문 조건 평가 및 만료 처리",
+        config.poll_interval,
+    );
+
+    tokio::spawn(async move {
+        info!(
+            poll_secs = config.poll_interval.as_secs(),
+            "Conditional order service started"
+        );
+        let calculator = TriggerCalculator::new();
+        let mut ticker = tokio::time::interval(config.poll_interval);
+
+        loop {
+            if task.wait_next(&mut ticker, &shutdown).await.is_none() {
+                info!("Conditional order service stopped");
+                break;
+            }
+            let mut run = task.begin();
+
+            if let Err(e) = evaluate_active_orders(&state, &pool, &provider, &calculator).await {
+                warn!(error = %e, "Failed to evaluate conditional orders");
+                run.fail(e);
+            }
+        }
+    })
+}
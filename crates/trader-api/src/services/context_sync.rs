@@ -17,6 +17,8 @@ use trader_core::{
     AnalyticsProvider, ExchangeProvider, MarketType, ScreeningPreset, StrategyContext,
 };
 
+use crate::services::task_scheduler::TaskRegistry;
+
 /// 전략 컨텍스트 동기화 서비스.
 ///
 /// 두 가지 독립적인 동기화 주기를 사용합니다:
@@ -59,22 +61,37 @@ impl ContextSyncService {
     /// 서비스 시작 (메인 루프).
     ///
     /// 두 개의 독립적인 타이머로 거래소 정보와 분석 결과를 주기적으로 동기화합니다.
+    /// 각 동기화는 작업 레지스트리에 별도 작업으로 등록되어 개별적으로 일시 정지/즉시 실행할 수 있습니다.
     /// CancellationToken을 통해 graceful shutdown을 지원합니다.
-    pub async fn run(self, shutdown: CancellationToken) {
+    pub async fn run(self, tasks: &TaskRegistry, shutdown: CancellationToken) {
+        let exchange_task = tasks.register(
+            "context_sync_exchange",
+            "전략 컨텍스트 거래소 정보 동기화 (계좌, 포지션, 미체결 주문)",
+            self.exchange_sync_interval,
+        );
+        let analytics_task = tasks.register(
+            "context_sync_analytics",
+            "전략 컨텍스트 분석 결과 동기화 (Global Score, 스크리닝, 레짐 등)",
+            self.analytics_sync_interval,
+        );
         let mut exchange_ticker = tokio::time::interval(self.exchange_sync_interval);
         let mut analytics_ticker = tokio::time::interval(self.analytics_sync_interval);
 
         loop {
             tokio::select! {
-                _ = exchange_ticker.tick() => {
+                _ = exchange_task.tick(&mut exchange_ticker) => {
+                    let mut run = exchange_task.begin();
                     if let Err(e) = self.sync_exchange().await {
                         tracing::error!("거래소 동기화 실패: {}", e);
+                        run.fail(e);
                     }
                 }
 
-                _ = analytics_ticker.tick() => {
+                _ = analytics_task.tick(&mut analytics_ticker) => {
+                    let mut run = analytics_task.begin();
                     if let Err(e) = self.sync_analytics().await {
                         tracing::error!("분석 결과 동기화 실패: {}", e);
+                        run.fail(e);
                     }
                 }
 
@@ -130,6 +147,8 @@ impl ContextSyncService {
     /// - MarketRegime (종목별)
     /// - MacroEnvironment (글로벌)
     /// - MarketBreadth (글로벌)
+    /// - CryptoMetrics (암호화폐 온체인/파생상품 지표)
+    /// - 현금성 자산 금리 (CD/국고채/T-bill)
     async fn sync_analytics(&self) -> Result<(), String> {
         // 1. Global Score 조회 (시장별 - 예: KR Stock)
         let scores = self
@@ -190,7 +209,47 @@ impl ContextSyncService {
             .await
             .map_err(|e| format!("MarketBreadth 조회 실패: {}", e))?;
 
-        // 9. 컨텍스트 업데이트
+        // 8-1. 매크로 레짐 조회 (선택 - 수집기가 매일 계산한 값)
+        let macro_regime = match self.analytics_provider.fetch_macro_regime().await {
+            Ok(regime) => regime,
+            Err(e) => {
+                tracing::warn!(error = %e, "매크로 레짐 조회 실패");
+                None
+            }
+        };
+
+        // 9. 암호화폐 지표 조회 (선택 - 실패해도 나머지 분석 결과는 반영)
+        let crypto_metrics = match self.analytics_provider.fetch_crypto_metrics().await {
+            Ok(metrics) => Some(metrics),
+            Err(e) => {
+                tracing::warn!(error = %e, "암호화폐 지표 조회 실패");
+                None
+            }
+        };
+
+        // 10. 현금성 자산 금리 조회 (선택)
+        let cash_yields = match self.analytics_provider.fetch_cash_yields().await {
+            Ok(yields) => Some(yields),
+            Err(e) => {
+                tracing::warn!(error = %e, "현금 금리 조회 실패");
+                None
+            }
+        };
+
+        // 11. 실현 변동성/베타 조회 (선택 - 수집기가 매일 계산한 값)
+        let symbol_risk = match self
+            .analytics_provider
+            .fetch_symbol_risk(&ticker_refs)
+            .await
+        {
+            Ok(risk) => Some(risk),
+            Err(e) => {
+                tracing::warn!(error = %e, "실현 변동성/베타 조회 실패");
+                None
+            }
+        };
+
+        // 12. 컨텍스트 업데이트
         let mut ctx = self.context.write().await;
         ctx.update_global_scores(scores);
         ctx.update_route_states(states);
@@ -199,6 +258,18 @@ impl ContextSyncService {
         ctx.update_market_regime(regimes);
         ctx.update_macro_environment(macro_env);
         ctx.update_market_breadth(breadth);
+        if let Some(regime) = macro_regime {
+            ctx.update_macro_regime(regime);
+        }
+        if let Some(metrics) = crypto_metrics {
+            ctx.update_crypto_metrics(metrics);
+        }
+        if let Some(yields) = cash_yields {
+            ctx.update_cash_yields(yields);
+        }
+        if let Some(risk) = symbol_risk {
+            ctx.update_symbol_risk(risk);
+        }
 
         tracing::debug!(ticker_count = tickers.len(), "분석 결과 동기화 완료");
 
@@ -217,6 +288,7 @@ impl ContextSyncService {
 /// * `exchange_provider` - 거래소 정보 제공자
 /// * `analytics_provider` - 분석 결과 제공자
 /// * `context` - 공유 컨텍스트
+/// * `tasks` - 동기화 작업을 등록할 작업 레지스트리
 /// * `shutdown` - Graceful shutdown을 위한 CancellationToken
 ///
 /// # Returns
@@ -226,6 +298,7 @@ pub fn start_context_sync_service(
     exchange_provider: Arc<dyn ExchangeProvider>,
     analytics_provider: Arc<dyn AnalyticsProvider>,
     context: Arc<RwLock<StrategyContext>>,
+    tasks: Arc<TaskRegistry>,
     shutdown: CancellationToken,
 ) -> tokio::task::JoinHandle<()> {
     let service = ContextSyncService::new(
@@ -237,6 +310,6 @@ pub fn start_context_sync_service(
     );
 
     tokio::spawn(async move {
-        service.run(shutdown).await;
+        service.run(&tasks, shutdown).await;
     })
 }
@@ -0,0 +1,372 @@
+//! 시장 간 상관관계 모니터.
+//!
+//! 모니터링 대상 자산(`correlation_monitor_asset`, 기본: KOSPI/S&P 500/USD/KRW/BTC)과
+//! 보유 종목의 일봉 종가로 자산 쌍별 단기/장기 롤링 상관계수를 계산해
+//! `correlation_snapshot`에 일자별로 저장합니다.
+//! 단기 상관이 장기 대비 크게 달라지면(붕괴/급등) `correlation_alert`에 기록하고
+//! 텔레그램 알림을 전송합니다.
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::NaiveDate;
+use rust_decimal::prelude::ToPrimitive;
+use sqlx::PgPool;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, info, warn};
+use trader_analytics::correlation::{
+    align_returns, closes_to_dated_returns, detect_correlation_shift, trailing_correlation,
+};
+use trader_core::Timeframe;
+use trader_data::cache::CachedHistoricalDataProvider;
+use trader_notification::NotificationManager;
+
+use crate::repository::{CorrelationRepository, CorrelationSnapshotInput};
+use crate::services::notification_digest::build_notifier;
+use crate::state::AppState;
+
+/// 모니터링할 최대 자산 수 (보유 종목 포함, 쌍 수는 N(N-1)/2).
+const MAX_MONITORED_ASSETS: usize = 30;
+
+/// 상관관계 모니터 설정.
+#[derive(Debug, Clone)]
+pub struct CorrelationMonitorConfig {
+    /// 계산 주기
+    pub poll_interval: Duration,
+    /// 단기 창 (공통 수익률 관측 수)
+    pub short_window: usize,
+    /// 장기 창 (공통 수익률 관측 수)
+    pub long_window: usize,
+    /// 레짐 변화 판정 임계값 (단기 - 장기 상관계수 절대값)
+    pub shift_threshold: f64,
+    /// 보유 종목 포함 여부
+    pub include_holdings: bool,
+}
+
+impl CorrelationMonitorConfig {
+    /// 환경변수에서 설정 로드.
+    ///
+    /// `CORRELATION_MONITOR_ENABLED=false`이면 `None`을 반환합니다.
+    pub fn from_env() -> Option<Self> {
+        let enabled = std::env::var("CORRELATION_MONITOR_ENABLED")
+            .map(|v| v.to_lowercase() != "false")
+            .unwrap_or(true);
+        if !enabled {
+            return None;
+        }
+
+        let env_usize = |key: &str, default: usize| {
+            std::env::var(key)
+                .ok()
+                .and_then(|v| v.parse::<usize>().ok())
+                .filter(|v| *v >= 5)
+                .unwrap_or(default)
+        };
+
+        let poll_interval = std::env::var("CORRELATION_MONITOR_POLL_SECS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .filter(|secs| *secs > 0)
+            .map(Duration::from_secs)
+            .unwrap_or(Duration::from_secs(6 * 3600));
+
+        let short_window = env_usize("CORRELATION_MONITOR_SHORT_WINDOW", 20);
+        let long_window = env_usize("CORRELATION_MONITOR_LONG_WINDOW", 120).max(short_window + 1);
+
+        let shift_threshold = std::env::var("CORRELATION_MONITOR_SHIFT_THRESHOLD")
+            .ok()
+            .and_then(|v| v.parse::<f64>().ok())
+            .filter(|v| *v > 0.0 && *v <= 2.0)
+            .unwrap_or(0.4);
+
+        let include_holdings = std::env::var("CORRELATION_MONITOR_INCLUDE_HOLDINGS")
+            .map(|v| v.to_lowercase() != "false")
+            .unwrap_or(true);
+
+        Some(Self {
+            poll_interval,
+            short_window,
+            long_window,
+            shift_threshold,
+            include_holdings,
+        })
+    }
+}
+
+/// 자산 쌍 하나의 계산 결과.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PairCorrelation {
+    pub as_of_date: NaiveDate,
+    pub short_corr: f64,
+    pub long_corr: f64,
+    pub observations: usize,
+}
+
+/// 두 자산의 날짜별 종가로 단기/장기 상관계수 계산.
+///
+/// 공통 수익률 관측이 장기 창보다 적으면 None을 반환합니다.
+pub fn compute_pair_correlation(
+    closes_a: &BTreeMap<NaiveDate, f64>,
+    closes_b: &BTreeMap<NaiveDate, f64>,
+    short_window: usize,
+    long_window: usize,
+) -> Option<PairCorrelation> {
+    let returns_a = closes_to_dated_returns(closes_a);
+    let returns_b = closes_to_dated_returns(closes_b);
+    let as_of_date = returns_a
+        .keys()
+        .rev()
+        .find(|date| returns_b.contains_key(date))
+        .copied()?;
+
+    let (x, y) = align_returns(&returns_a, &returns_b);
+    let short_corr = trailing_correlation(&x, &y, short_window)?;
+    let long_corr = trailing_correlation(&x, &y, long_window)?;
+
+    Some(PairCorrelation {
+        as_of_date,
+        short_corr,
+        long_corr,
+        observations: x.len(),
+    })
+}
+
+/// 모니터링 대상 티커 목록 (등록 자산 + 보유 종목, 중복 제거).
+async fn monitored_tickers(
+    pool: &PgPool,
+    include_holdings: bool,
+) -> Result<Vec<String>, sqlx::Error> {
+    let mut tickers: Vec<String> = CorrelationRepository::list_assets(pool)
+        .await?
+        .into_iter()
+        .filter(|asset| asset.enabled)
+        .map(|asset| asset.ticker)
+        .collect();
+
+    if include_holdings {
+        for ticker in CorrelationRepository::holding_tickers(pool).await? {
+            if !tickers.contains(&ticker) {
+                tickers.push(ticker);
+            }
+        }
+    }
+
+    if tickers.len() > MAX_MONITORED_ASSETS {
+        warn!(
+            count = tickers.len(),
+            max = MAX_MONITORED_ASSETS,
+            "Too many correlation assets, truncating"
+        );
+        tickers.truncate(MAX_MONITORED_ASSETS);
+    }
+    Ok(tickers)
+}
+
+/// 한 주기 실행: 종가 로드, 쌍별 상관계수 저장, 레짐 변화 알림.
+async fn run_cycle(
+    pool: &PgPool,
+    provider: &CachedHistoricalDataProvider,
+    notifier: &NotificationManager,
+    config: &CorrelationMonitorConfig,
+) -> Result<usize, sqlx::Error> {
+    let tickers = monitored_tickers(pool, config.include_holdings).await?;
+
+    // 거래일이 다른 자산(암호화폐 등)을 고려해 장기 창보다 넉넉히 로드
+    let limit = config.long_window * 2;
+    let mut closes: HashMap<String, BTreeMap<NaiveDate, f64>> = HashMap::new();
+    for ticker in &tickers {
+        match provider.get_klines(ticker, Timeframe::D1, limit).await {
+            Ok(klines) => {
+                let series: BTreeMap<NaiveDate, f64> = klines
+                    .iter()
+                    .filter_map(|k| k.close.to_f64().map(|c| (k.open_time.date_naive(), c)))
+                    .collect();
+                closes.insert(ticker.clone(), series);
+            }
+            Err(e) => warn!(ticker = %ticker, error = %e, "Failed to load closes for correlation"),
+        }
+    }
+
+    let mut symbols: Vec<&String> = closes.keys().collect();
+    symbols.sort();
+
+    let mut saved = 0;
+    for (i, symbol_a) in symbols.iter().enumerate() {
+        for symbol_b in &symbols[i + 1..] {
+            let Some(pair) = compute_pair_correlation(
+                &closes[*symbol_a],
+                &closes[*symbol_b],
+                config.short_window,
+                config.long_window,
+            ) else {
+                debug!(a = %symbol_a, b = %symbol_b, "Not enough overlapping data for correlation");
+                continue;
+            };
+
+            CorrelationRepository::upsert_snapshot(
+                pool,
+                &CorrelationSnapshotInput {
+                    as_of_date: pair.as_of_date,
+                    symbol_a: symbol_a.to_string(),
+                    symbol_b: symbol_b.to_string(),
+                    short_window: config.short_window as i32,
+                    long_window: config.long_window as i32,
+                    short_corr: pair.short_corr,
+                    long_corr: pair.long_corr,
+                    observations: pair.observations as i32,
+                },
+            )
+            .await?;
+            saved += 1;
+
+            let Some(shift) =
+                detect_correlation_shift(pair.short_corr, pair.long_corr, config.shift_threshold)
+            else {
+                continue;
+            };
+
+            let inserted = CorrelationRepository::insert_alert(
+                pool,
+                pair.as_of_date,
+                symbol_a,
+                symbol_b,
+                shift.kind.as_str(),
+                shift.short_corr,
+                shift.long_corr,
+            )
+            .await?;
+
+            // 같은 관측일에 이미 알린 변화는 다시 보내지 않음
+            if inserted.is_some() {
+                warn!(
+                    a = %symbol_a,
+                    b = %symbol_b,
+                    kind = shift.kind.as_str(),
+                    short = shift.short_corr,
+                    long = shift.long_corr,
+                    "Correlation regime shift detected"
+                );
+                if let Err(e) = notifier
+                    .notify_correlation_shift(
+                        symbol_a,
+                        symbol_b,
+                        shift.kind.as_str(),
+                        shift.short_corr,
+                        shift.long_corr,
+                        config.short_window,
+                        config.long_window,
+                    )
+                    .await
+                {
+                    warn!(error = %e, "Failed to send correlation alert");
+                }
+            }
+        }
+    }
+
+    Ok(saved)
+}
+
+/// 상관관계 모니터 시작.
+///
+/// # Arguments
+///
+/// * `state` - 애플리케이션 상태 (일봉 데이터 Provider)
+/// * `pool` - 대상 자산 및 상관계수 이력 DB
+/// * `config` - 모니터 설정
+/// * `shutdown` - Graceful shutdown을 위한 CancellationToken
+///
+/// # Returns
+///
+/// 백그라운드 task의 JoinHandle
+pub fn start_correlation_monitor(
+    state: Arc<AppState>,
+    pool: PgPool,
+    config: CorrelationMonitorConfig,
+    shutdown: CancellationToken,
+) -> tokio::task::JoinHandle<()> {
+    let provider = state
+        .data_provider
+        .clone()
+        .unwrap_or_else(|| Arc::new(CachedHistoricalDataProvider::new(pool.clone())));
+
+    let notifier = build_notifier();
+
+    let task = state.tasks.register(
+        "correlation_monitor",
+        "시장 간 롤링 상관관계 기록 및 레짐 변화 알림",
+        config.poll_interval,
+    );
+
+    tokio::spawn(async move {
+        info!(
+            poll_secs = config.poll_interval.as_secs(),
+            short_window = config.short_window,
+            long_window = config.long_window,
+            "Correlation monitor started"
+        );
+        let mut ticker = tokio::time::interval(config.poll_interval);
+
+        loop {
+            if task.wait_next(&mut ticker, &shutdown).await.is_none() {
+                info!("Correlation monitor stopped");
+                break;
+            }
+            let mut run = task.begin();
+
+            match run_cycle(&pool, &provider, &notifier, &config).await {
+                Ok(pairs) => info!(pairs, "Correlation snapshots updated"),
+                Err(e) => {
+                    warn!(error = %e, "Correlation monitor cycle failed");
+                    run.fail(e);
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn series(start: NaiveDate, closes: &[f64]) -> BTreeMap<NaiveDate, f64> {
+        closes
+            .iter()
+            .enumerate()
+            .map(|(i, c)| (start + chrono::Duration::days(i as i64), *c))
+            .collect()
+    }
+
+    #[test]
+    fn test_compute_pair_correlation() {
+        let start = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+        let base: Vec<f64> = (0..40)
+            .map(|i| {
+                100.0
+                    + if i % 2 == 0 {
+                        i as f64
+                    } else {
+                        -(i as f64) * 0.5
+                    }
+            })
+            .collect();
+
+        // 장기 구간은 같은 방향, 최근 5일은 반대 방향
+        let mut follower: Vec<f64> = base.iter().map(|c| c * 2.0).collect();
+        for i in 35..40 {
+            follower[i] = follower[i - 1] * (1.0 - (base[i] - base[i - 1]) / base[i - 1]);
+        }
+
+        let a = series(start, &base);
+        let b = series(start, &follower);
+        let pair = compute_pair_correlation(&a, &b, 5, 30).unwrap();
+        assert_eq!(pair.as_of_date, start + chrono::Duration::days(39));
+        assert_eq!(pair.observations, 39);
+        assert!(pair.short_corr < -0.9, "short: {}", pair.short_corr);
+        assert!(pair.long_corr > pair.short_corr);
+
+        // 공통 관측이 장기 창보다 적음
+        assert!(compute_pair_correlation(&a, &b, 5, 60).is_none());
+    }
+}
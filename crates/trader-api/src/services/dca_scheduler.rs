@@ -0,0 +1,268 @@
+//! 정액 적립식(DCA) 스케줄러.
+//!
+//! 실행 시각이 도래한 적립 계획(`dca_plan`)의 회차 금액을 종목 비중대로 나누고,
+//! 금액 결정 방식(정액/가치 평균법/하락 가중)에 따라 종목별 매수 금액을 계산해
+//! `OrderExecutor::process_order_request()`로 주문합니다 (미국 주식은 USD 부족 시 환전 확인).
+//! 종목별 결과는 `dca_execution`에 기록되어 매매일지에서 별도로 집계됩니다.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+use rust_decimal::Decimal;
+use sqlx::PgPool;
+use tokio_util::sync::CancellationToken;
+use tracing::{info, warn};
+use trader_core::{Kline, OrderRequest, Timeframe};
+use trader_data::cache::CachedHistoricalDataProvider;
+use trader_execution::{
+    plan_contribution, quantity_for_amount, split_amount, DcaContribution, DcaSymbolState,
+};
+use uuid::Uuid;
+
+use crate::repository::{DcaExecutionInput, DcaPlanRecord, DcaRepository};
+use crate::services::fx_conversion::submit_order_request;
+use crate::state::AppState;
+
+/// 최근 고점 계산에 사용할 일봉 수.
+const RECENT_HIGH_LOOKBACK: usize = 60;
+
+/// 한 번에 처리할 최대 계획 수.
+const MAX_DUE_PLANS: i64 = 50;
+
+/// DCA 스케줄러 설정.
+#[derive(Debug, Clone)]
+pub struct DcaSchedulerConfig {
+    /// 실행 대상 확인 주기
+    pub poll_interval: Duration,
+}
+
+impl DcaSchedulerConfig {
+    /// 환경변수에서 설정 로드.
+    ///
+    /// `DCA_SCHEDULER_ENABLED=false`이면 `None`을 반환합니다.
+    pub fn from_env() -> Option<Self> {
+        let enabled = std::env::var("DCA_SCHEDULER_ENABLED")
+            .map(|v| v.to_lowercase() != "false")
+            .unwrap_or(true);
+        if !enabled {
+            return None;
+        }
+
+        let poll_interval = std::env::var("DCA_SCHEDULER_POLL_SECS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .filter(|secs| *secs > 0)
+            .map(Duration::from_secs)
+            .unwrap_or(Duration::from_secs(300));
+
+        Some(Self { poll_interval })
+    }
+}
+
+/// 일봉에서 종목 상태 구성 (최신 종가, 최근 고점).
+fn symbol_state(
+    klines: &[Kline],
+    accumulated_quantity: Decimal,
+    executed_periods: u32,
+) -> Option<DcaSymbolState> {
+    let last = klines.last()?;
+    let recent_high = klines.iter().map(|k| k.high).max();
+    Some(DcaSymbolState {
+        price: last.close,
+        recent_high,
+        accumulated_quantity,
+        executed_periods,
+    })
+}
+
+/// 종목 하나의 회차 매수.
+async fn execute_symbol(
+    state: &AppState,
+    pool: &PgPool,
+    provider: &CachedHistoricalDataProvider,
+    plan: &DcaPlanRecord,
+    ticker: &str,
+    base_amount: Decimal,
+) -> Result<(), sqlx::Error> {
+    let mode = plan.parsed_mode().unwrap_or_default();
+    let (executed_periods, accumulated_quantity) =
+        DcaRepository::accumulation(pool, plan.id, ticker).await?;
+
+    let klines = provider
+        .get_klines(ticker, Timeframe::D1, RECENT_HIGH_LOOKBACK)
+        .await
+        .unwrap_or_default();
+
+    let mut input = DcaExecutionInput {
+        plan_id: plan.id,
+        ticker: ticker.to_string(),
+        price: Decimal::ZERO,
+        base_amount,
+        amount: Decimal::ZERO,
+        multiplier: Decimal::ZERO,
+        quantity: Decimal::ZERO,
+        reason: None,
+        success: false,
+        error: None,
+        order_id: None,
+    };
+
+    let Some(symbol) = symbol_state(
+        &klines,
+        accumulated_quantity,
+        u32::try_from(executed_periods).unwrap_or(u32::MAX),
+    ) else {
+        input.error = Some("No price data".to_string());
+        DcaRepository::insert_execution(pool, &input).await?;
+        return Ok(());
+    };
+
+    let DcaContribution {
+        amount,
+        multiplier,
+        reason,
+        ..
+    } = plan_contribution(&mode, base_amount, plan.max_multiplier, &symbol);
+    let quantity = quantity_for_amount(ticker, amount, symbol.price);
+
+    input.price = symbol.price;
+    input.amount = amount;
+    input.multiplier = multiplier;
+    input.quantity = quantity;
+    input.reason = Some(reason);
+
+    if quantity <= Decimal::ZERO {
+        input.error = Some("Contribution is below one unit".to_string());
+        DcaRepository::insert_execution(pool, &input).await?;
+        return Ok(());
+    }
+
+    let request_id = Uuid::new_v4();
+    let mut request = OrderRequest::market_buy(ticker.to_string(), quantity)
+        .with_client_id(format!("dca_{}", request_id));
+    if let Some(strategy_id) = &plan.strategy_id {
+        request = request.with_strategy(strategy_id.clone());
+    }
+
+    let result = submit_order_request(state, pool, "dca", request_id, request, symbol.price).await;
+
+    if result.success {
+        info!(
+            plan = %plan.name,
+            ticker,
+            amount = %amount,
+            quantity = %quantity,
+            order_id = ?result.order_id,
+            "DCA order submitted"
+        );
+    } else {
+        warn!(
+            plan = %plan.name,
+            ticker,
+            error = ?result.error,
+            "DCA order rejected"
+        );
+    }
+
+    input.success = result.success;
+    input.error = result.error;
+    input.order_id = result.order_id;
+    DcaRepository::insert_execution(pool, &input).await?;
+    Ok(())
+}
+
+/// 계획 하나의 회차 실행 후 다음 실행 시각으로 이동.
+async fn run_plan(
+    state: &AppState,
+    pool: &PgPool,
+    provider: &CachedHistoricalDataProvider,
+    plan: &DcaPlanRecord,
+) -> Result<(), sqlx::Error> {
+    let cadence = match plan.parsed_cadence() {
+        Ok(cadence) => cadence,
+        Err(e) => {
+            warn!(plan = %plan.name, error = %e, "Invalid DCA plan cadence");
+            return Ok(());
+        }
+    };
+
+    match plan.parsed_allocations() {
+        Ok(allocations) => {
+            for (ticker, base_amount) in split_amount(plan.amount, &allocations) {
+                execute_symbol(state, pool, provider, plan, &ticker, base_amount).await?;
+            }
+        }
+        Err(e) => warn!(plan = %plan.name, error = %e, "Invalid DCA plan allocations"),
+    }
+
+    // 서버 중단 등으로 밀린 회차는 건너뜀
+    let now = Utc::now();
+    let mut next_run_at = cadence.next_after(plan.next_run_at);
+    while next_run_at <= now {
+        next_run_at = cadence.next_after(next_run_at);
+    }
+    DcaRepository::advance(pool, plan.id, next_run_at).await
+}
+
+/// DCA 스케줄러 시작.
+///
+/// # Arguments
+///
+/// * `state` - 애플리케이션 상태 (데이터 제공자, 실행기)
+/// * `pool` - 적립 계획 DB
+/// * `config` - 스케줄러 설정
+/// * `shutdown` - Graceful shutdown을 위한 CancellationToken
+///
+/// # Returns
+///
+/// 백그라운드 task의 JoinHandle
+pub fn start_dca_scheduler(
+    state: Arc<AppState>,
+    pool: PgPool,
+    config: DcaSchedulerConfig,
+    shutdown: CancellationToken,
+) -> tokio::task::JoinHandle<()> {
+    let provider = state
+        .data_provider
+        .clone()
+        .unwrap_or_else(|| Arc::new(CachedHistoricalDataProvider::new(pool.clone())));
+
+    let task = state.tasks.register(
+        "dca_scheduler",
+        "정액 적립식(DCA) 계획 회차 실행",
+        config.poll_interval,
+    );
+
+    tokio::spawn(async move {
+        info!(
+            poll_secs = config.poll_interval.as_secs(),
+            "DCA scheduler started"
+        );
+        let mut ticker = tokio::time::interval(config.poll_interval);
+
+        loop {
+            if task.wait_next(&mut ticker, &shutdown).await.is_none() {
+                info!("DCA scheduler stopped");
+                break;
+            }
+            let mut run = task.begin();
+
+            let plans = match DcaRepository::list_due(&pool, MAX_DUE_PLANS).await {
+                Ok(plans) => plans,
+                Err(e) => {
+                    warn!(error = %e, "Failed to load due DCA plans");
+                    run.fail(e);
+                    continue;
+                }
+            };
+
+            for plan in &plans {
+                if let Err(e) = run_plan(&state, &pool, &provider, plan).await {
+                    warn!(plan = %plan.name, error = %e, "Failed to run DCA plan");
+                    run.fail(e);
+                }
+            }
+        }
+    })
+}
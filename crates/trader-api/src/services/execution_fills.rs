@@ -0,0 +1,132 @@
+//! 거래소 실시간 체결 반영 서비스.
+//!
+//! KIS 실시간 체결통보(`UserEvent::Fill`)를 받아 실행기의 `handle_exchange_fill()`로 전달합니다.
+//! 주문 내역 조회를 폴링하지 않아도 체결이 `OrderManager`와 포지션에 바로 반영됩니다.
+//!
+//! 체결통보는 시세와 같은 WebSocket 연결로 들어오므로 `KIS_HTS_ID`가 설정된
+//! 실거래소 연결(`USE_REAL_EXCHANGE=true`)에서만 동작합니다.
+
+use std::sync::Arc;
+
+use tokio::sync::mpsc;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, info, warn};
+use trader_exchange::UserEvent;
+use uuid::Uuid;
+
+use crate::state::AppState;
+
+/// 사용자 이벤트를 실행기에 반영.
+///
+/// 체결이 내부 주문과 매칭되어 기록되면 해당 주문 ID를 반환합니다.
+pub async fn apply_user_event(state: &AppState, event: &UserEvent) -> Option<Uuid> {
+    let UserEvent::Fill(report) = event else {
+        return None;
+    };
+
+    let result = state
+        .executor
+        .read()
+        .await
+        .handle_exchange_fill(report)
+        .await;
+    match result {
+        Ok(Some(order_id)) => {
+            info!(
+                order_id = %order_id,
+                exchange_order_id = %report.exchange_order_id,
+                quantity = %report.quantity,
+                price = %report.price,
+                "Exchange fill applied"
+            );
+            Some(order_id)
+        }
+        Ok(None) => {
+            debug!(
+                exchange_order_id = %report.exchange_order_id,
+                ticker = %report.ticker,
+                "Exchange fill not matched to an active order"
+            );
+            None
+        }
+        Err(e) => {
+            warn!(
+                exchange_order_id = %report.exchange_order_id,
+                error = %e,
+                "Failed to apply exchange fill"
+            );
+            None
+        }
+    }
+}
+
+/// 체결 반영 서비스 시작.
+///
+/// # Arguments
+///
+/// * `state` - 애플리케이션 상태 (실행기)
+/// * `events` - 거래소 스트림의 체결통보 채널
+/// * `shutdown` - Graceful shutdown을 위한 CancellationToken
+///
+/// # Returns
+///
+/// 백그라운드 task의 JoinHandle
+pub fn start_execution_fill_listener(
+    state: Arc<AppState>,
+    mut events: mpsc::Receiver<UserEvent>,
+    shutdown: CancellationToken,
+) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        info!("Execution fill listener started");
+
+        loop {
+            let event = tokio::select! {
+                _ = shutdown.cancelled() => {
+                    info!("Execution fill listener stopped");
+                    break;
+                }
+                received = events.recv() => match received {
+                    Some(event) => event,
+                    None => {
+                        warn!("Execution notice channel closed");
+                        break;
+                    }
+                },
+            };
+
+            apply_user_event(&state, &event).await;
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::create_test_state;
+    use rust_decimal_macros::dec;
+    use trader_core::Side;
+    use trader_exchange::FillReport;
+
+    #[tokio::test]
+    async fn test_unmatched_fill_is_ignored() {
+        let state = create_test_state();
+        let event = UserEvent::Fill(FillReport {
+            exchange_order_id: "0000012345".to_string(),
+            ticker: "005930".to_string(),
+            side: Side::Buy,
+            quantity: dec!(10),
+            price: dec!(70100),
+            timestamp: chrono::Utc::now(),
+        });
+
+        // HTS 수동 주문 등 실행기가 모르는 주문의 체결은 무시
+        assert!(apply_user_event(&state, &event).await.is_none());
+        assert!(state
+            .executor
+            .read()
+            .await
+            .get_open_positions()
+            .await
+            .is_empty());
+    }
+}
@@ -0,0 +1,546 @@
+//! 해외 주식 주문 전 자동 환전.
+//!
+//! 미국 주식 매수 주문을 실행기에 제출하기 전에 KIS 매수가능금액 조회로 USD 잔고와
+//! 증권사 적용 환율을 확인하고, USD가 부족하면 `plan_fx_conversion()`으로 환전 여부를 정합니다.
+//! 환전을 실행하면 KIS 원화→USD 환전 주문을 먼저 보내고, 접수되면 원 주문을 제출합니다.
+//! 환전 주문이 실패하면 원 주문은 제출하지 않습니다. 환전 판단과 결과는 `fx_conversion`에
+//! 기록합니다 (매매일지 `GET /journal/fx-conversions`).
+//!
+//! - 자동 모드: 기준 환율(USDKRW 종가) 대비 스프레드가 한도 안이면 바로 환전 후 주문
+//! - 수동 모드: 주문을 보류하고 `POST /api/v1/fx/conversions/{id}/approve`로 승인 시 환전 후 제출
+
+use async_trait::async_trait;
+use chrono::Utc;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use tracing::{info, warn};
+use trader_core::{OrderRequest, Side, Timeframe};
+use trader_exchange::connector::kis::KisUsClient;
+use trader_execution::{
+    is_us_equity, plan_fx_conversion, ExecutionResult, FxApprovalMode, FxConversionDecision,
+    FxConversionPlan, FxConversionPolicy, FxConversionStatus, FxFundingSnapshot,
+};
+use uuid::Uuid;
+
+use crate::repository::{FxConversionInput, FxConversionRecord, FxConversionRepository};
+use crate::state::AppState;
+
+/// 기준 환율로 사용할 USD/KRW 일봉 티커.
+pub const REFERENCE_RATE_TICKER: &str = "USDKRW";
+
+/// 자동 환전 설정.
+#[derive(Debug, Clone)]
+pub struct FxConversionConfig {
+    /// 승인 방식과 스프레드/여유분 한도
+    pub policy: FxConversionPolicy,
+    /// 수동 승인 유효 시간 (경과하면 보류 주문 폐기)
+    pub approval_ttl: chrono::Duration,
+}
+
+impl Default for FxConversionConfig {
+    fn default() -> Self {
+        Self {
+            policy: FxConversionPolicy {
+                mode: FxApprovalMode::Manual,
+                max_spread_bps: Decimal::from(50),
+                buffer_pct: Decimal::ONE,
+            },
+            approval_ttl: chrono::Duration::minutes(30),
+        }
+    }
+}
+
+impl FxConversionConfig {
+    /// 환경변수에서 설정 로드.
+    ///
+    /// `FX_CONVERSION_ENABLED=false`이면 `None`을 반환하며, 주문은 환전 확인 없이 제출됩니다.
+    pub fn from_env() -> Option<Self> {
+        let enabled = std::env::var("FX_CONVERSION_ENABLED")
+            .map(|v| v.to_lowercase() != "false")
+            .unwrap_or(true);
+        if !enabled {
+            return None;
+        }
+
+        let mut config = Self::default();
+        if let Some(mode) = std::env::var("FX_CONVERSION_MODE")
+            .ok()
+            .and_then(|v| v.to_lowercase().parse::<FxApprovalMode>().ok())
+        {
+            config.policy.mode = mode;
+        }
+        if let Some(bps) = std::env::var("FX_CONVERSION_MAX_SPREAD_BPS")
+            .ok()
+            .and_then(|v| v.parse::<Decimal>().ok())
+            .filter(|bps| *bps >= Decimal::ZERO)
+        {
+            config.policy.max_spread_bps = bps;
+        }
+        if let Some(pct) = std::env::var("FX_CONVERSION_BUFFER_PCT")
+            .ok()
+            .and_then(|v| v.parse::<Decimal>().ok())
+            .filter(|pct| *pct >= Decimal::ZERO)
+        {
+            config.policy.buffer_pct = pct;
+        }
+        if let Some(mins) = std::env::var("FX_CONVERSION_APPROVAL_TTL_MINS")
+            .ok()
+            .and_then(|v| v.parse::<i64>().ok())
+            .filter(|mins| *mins > 0)
+        {
+            config.approval_ttl = chrono::Duration::minutes(mins);
+        }
+
+        Some(config)
+    }
+}
+
+/// 보류/제출 주문 (`fx_conversion.order_payload`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HeldOrder {
+    pub request_id: Uuid,
+    pub request: OrderRequest,
+    pub price: Decimal,
+}
+
+/// 기준 환율 (최근 USDKRW 일봉 종가).
+pub async fn reference_rate(state: &AppState) -> Option<Decimal> {
+    let provider = state.data_provider.as_ref()?;
+    match provider
+        .get_klines(REFERENCE_RATE_TICKER, Timeframe::D1, 1)
+        .await
+    {
+        Ok(klines) => klines.last().map(|k| k.close),
+        Err(e) => {
+            warn!(error = %e, "Failed to load reference FX rate");
+            None
+        }
+    }
+}
+
+/// KIS 매수가능금액과 기준 환율로 환전 판단 정보 구성.
+///
+/// KIS 해외 클라이언트가 없으면 `Ok(None)`을 반환합니다.
+pub async fn funding_snapshot(
+    state: &AppState,
+    ticker: &str,
+    price: Decimal,
+) -> Result<Option<FxFundingSnapshot>, String> {
+    let Some(client) = &state.kis_us_client else {
+        return Ok(None);
+    };
+    let amount = client
+        .get_purchasable_amount(ticker, price)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(Some(FxFundingSnapshot {
+        usd_available: amount.foreign_orderable,
+        usd_available_after_conversion: amount.orderable_after_exchange,
+        rate: amount.exchange_rate,
+        reference_rate: reference_rate(state).await,
+    }))
+}
+
+/// 원화→USD 환전 주문 (KIS 해외 클라이언트).
+#[async_trait]
+pub trait FxConverter: Send + Sync {
+    /// `usd_amount`만큼 원화를 USD로 환전하고 환전 주문번호를 반환합니다.
+    async fn convert_krw_to_usd(&self, usd_amount: Decimal) -> Result<String, String>;
+}
+
+#[async_trait]
+impl FxConverter for KisUsClient {
+    async fn convert_krw_to_usd(&self, usd_amount: Decimal) -> Result<String, String> {
+        self.place_fx_conversion_order(usd_amount)
+            .await
+            .map(|order| order.odno)
+            .map_err(|e| e.to_string())
+    }
+}
+
+fn fx_converter(state: &AppState) -> Option<&dyn FxConverter> {
+    state
+        .kis_us_client
+        .as_deref()
+        .map(|client| client as &dyn FxConverter)
+}
+
+/// 환전 주문 실행 (실패 사유는 기록/응답용 메시지).
+async fn execute_conversion(
+    converter: Option<&dyn FxConverter>,
+    usd_amount: Decimal,
+) -> Result<String, String> {
+    let converter = converter.ok_or_else(|| {
+        "FX conversion order failed: KIS overseas client not configured".to_string()
+    })?;
+    converter
+        .convert_krw_to_usd(usd_amount)
+        .await
+        .map_err(|e| format!("FX conversion order failed: {}", e))
+}
+
+/// 실행기에 주문 제출.
+async fn submit(
+    state: &AppState,
+    request_id: Uuid,
+    request: OrderRequest,
+    price: Decimal,
+) -> ExecutionResult {
+    let executor = state.executor.read().await;
+    executor
+        .process_order_request(request_id, request, price)
+        .await
+}
+
+/// 판단 결과별 기록 상태와 사유.
+///
+/// `Executed`는 환전 주문을 보낼 건이며, 환전 주문이 실패하면 `Failed`로 바뀝니다.
+fn decision_status(plan: &FxConversionPlan) -> (FxConversionStatus, Option<String>) {
+    match plan.decision {
+        FxConversionDecision::NotNeeded => (FxConversionStatus::NotNeeded, None),
+        FxConversionDecision::AwaitApproval => (FxConversionStatus::Pending, None),
+        FxConversionDecision::SpreadTooWide => (
+            FxConversionStatus::Rejected,
+            Some(format!(
+                "FX spread {} bps exceeds limit",
+                plan.spread_bps.unwrap_or_default()
+            )),
+        ),
+        FxConversionDecision::InsufficientFunds => (
+            FxConversionStatus::Rejected,
+            Some("Insufficient KRW for FX conversion".to_string()),
+        ),
+        FxConversionDecision::Execute => (FxConversionStatus::Executed, None),
+    }
+}
+
+/// 환전 확인 후 주문 제출.
+///
+/// 미국 주식 매수가 아니거나, 자동 환전이 꺼져 있거나, USD가 충분하면 그대로 제출합니다.
+/// 환전이 필요하면 환전 주문 접수 후 제출하며, 수동 승인 대기/한도 초과/환전 실패 시에는
+/// 주문하지 않고 실패 결과를 반환합니다.
+///
+/// # Arguments
+/// * `source` - 주문 출처 (환전 기록용, 예: `conditional_order`, `dca`)
+pub async fn submit_order_request(
+    state: &AppState,
+    pool: &PgPool,
+    source: &str,
+    request_id: Uuid,
+    request: OrderRequest,
+    price: Decimal,
+) -> ExecutionResult {
+    if request.side != Side::Buy || !is_us_equity(&request.ticker) {
+        return submit(state, request_id, request, price).await;
+    }
+    let Some(config) = FxConversionConfig::from_env() else {
+        return submit(state, request_id, request, price).await;
+    };
+
+    let order_price = request.price.unwrap_or(price);
+    let snapshot = match funding_snapshot(state, &request.ticker, order_price).await {
+        Ok(Some(snapshot)) => snapshot,
+        Ok(None) => return submit(state, request_id, request, price).await,
+        Err(e) => {
+            // 조회 실패 시 잔고 부족 여부는 거래소 응답에 맡김
+            warn!(ticker = %request.ticker, error = %e, "Failed to query USD buying power");
+            return submit(state, request_id, request, price).await;
+        }
+    };
+
+    let plan = plan_fx_conversion(request.quantity * order_price, &snapshot, &config.policy);
+    if plan.decision == FxConversionDecision::NotNeeded {
+        return submit(state, request_id, request, price).await;
+    }
+
+    let (mut status, mut reason) = decision_status(&plan);
+    let mut fx_order_no = None;
+    if status == FxConversionStatus::Executed {
+        match execute_conversion(fx_converter(state), plan.usd_amount).await {
+            Ok(order_no) => fx_order_no = Some(order_no),
+            Err(e) => {
+                status = FxConversionStatus::Failed;
+                reason = Some(e);
+            }
+        }
+    }
+    let held = HeldOrder {
+        request_id,
+        request: request.clone(),
+        price,
+    };
+    let input = FxConversionInput {
+        source: source.to_string(),
+        ticker: request.ticker.clone(),
+        order_payload: serde_json::to_value(&held).unwrap_or_default(),
+        required_usd: plan.required_usd,
+        available_usd: snapshot.usd_available,
+        usd_amount: plan.usd_amount,
+        krw_amount: plan.krw_amount,
+        rate: plan.rate,
+        reference_rate: snapshot.reference_rate,
+        spread_bps: plan.spread_bps,
+        mode: config.policy.mode.as_str().to_string(),
+        status,
+        reason: reason.clone(),
+        fx_order_no: fx_order_no.clone(),
+    };
+    let metadata = serde_json::to_value(&plan).unwrap_or_default();
+
+    let record = match FxConversionRepository::insert(pool, &input).await {
+        Ok(record) => Some(record),
+        Err(e) => {
+            warn!(ticker = %request.ticker, error = %e, "Failed to record FX conversion");
+            None
+        }
+    };
+
+    match status {
+        FxConversionStatus::Executed => {
+            let fx_order_no = fx_order_no.unwrap_or_default();
+            info!(
+                ticker = %request.ticker,
+                fx_order_no = %fx_order_no,
+                usd = %plan.usd_amount,
+                krw = %plan.krw_amount,
+                rate = %plan.rate,
+                spread_bps = ?plan.spread_bps,
+                "FX conversion order placed, submitting US order"
+            );
+            let result = submit(state, request_id, request, price).await;
+            if let Some(record) = &record {
+                if let Err(e) = FxConversionRepository::record_order(
+                    pool,
+                    record.id,
+                    result.order_id,
+                    result.error.as_deref(),
+                )
+                .await
+                {
+                    warn!(id = %record.id, error = %e, "Failed to record FX conversion order");
+                }
+            }
+            result
+                .with_note(format!(
+                    "FX conversion order {}: {} USD ({} KRW @ {})",
+                    fx_order_no, plan.usd_amount, plan.krw_amount, plan.rate
+                ))
+                .with_metadata("fx_conversion", metadata)
+        }
+        FxConversionStatus::Pending => {
+            let message = match &record {
+                Some(record) => format!("FX conversion awaiting approval: {}", record.id),
+                None => "FX conversion approval could not be recorded".to_string(),
+            };
+            info!(
+                ticker = %request.ticker,
+                usd = %plan.usd_amount,
+                rate = %plan.rate,
+                "US order held for FX conversion approval"
+            );
+            ExecutionResult::failure(request_id, message).with_metadata("fx_conversion", metadata)
+        }
+        _ => {
+            let message = reason.unwrap_or_else(|| "FX conversion rejected".to_string());
+            warn!(ticker = %request.ticker, reason = %message, "FX conversion rejected");
+            ExecutionResult::failure(request_id, message).with_metadata("fx_conversion", metadata)
+        }
+    }
+}
+
+/// 수동 승인 결과.
+#[derive(Debug)]
+#[allow(clippy::large_enum_variant)]
+pub enum FxApprovalOutcome {
+    /// 승인 대기 건이 아님 (없음/이미 결정됨)
+    NotPending,
+    /// 승인 유효 시간 경과로 폐기
+    Expired(FxConversionRecord),
+    /// 보류 주문 제출 (성공/실패는 결과에 포함)
+    Submitted(FxConversionRecord, ExecutionResult),
+}
+
+/// 승인 대기 환전 승인 후 환전 주문과 보류 주문 제출.
+///
+/// 승인 시점의 환율로 다시 판단하지 않고 기록된 USD 금액을 환전한 뒤 보류했던 주문을
+/// 그대로 제출합니다. 환전 주문이 실패하면 주문하지 않고 `failed`로 기록합니다.
+pub async fn approve_conversion(
+    state: &AppState,
+    pool: &PgPool,
+    id: Uuid,
+    approval_ttl: chrono::Duration,
+) -> Result<FxApprovalOutcome, sqlx::Error> {
+    let Some(pending) = FxConversionRepository::get(pool, id).await? else {
+        return Ok(FxApprovalOutcome::NotPending);
+    };
+    if pending.parsed_status() != Some(FxConversionStatus::Pending) {
+        return Ok(FxApprovalOutcome::NotPending);
+    }
+
+    if pending.created_at + approval_ttl < Utc::now() {
+        return Ok(
+            match FxConversionRepository::decide(
+                pool,
+                id,
+                FxConversionStatus::Rejected,
+                Some("Approval expired"),
+            )
+            .await?
+            {
+                Some(record) => FxApprovalOutcome::Expired(record),
+                None => FxApprovalOutcome::NotPending,
+            },
+        );
+    }
+
+    let held: HeldOrder = match serde_json::from_value(pending.order_payload.clone()) {
+        Ok(held) => held,
+        Err(e) => {
+            let reason = format!("Invalid held order: {}", e);
+            FxConversionRepository::decide(pool, id, FxConversionStatus::Failed, Some(&reason))
+                .await?;
+            return Ok(FxApprovalOutcome::NotPending);
+        }
+    };
+
+    // pending → executed 전환에 성공한 요청만 환전/주문 제출 (중복 승인 방지)
+    if FxConversionRepository::decide(pool, id, FxConversionStatus::Executed, None)
+        .await?
+        .is_none()
+    {
+        return Ok(FxApprovalOutcome::NotPending);
+    }
+
+    let result = match execute_conversion(fx_converter(state), pending.usd_amount).await {
+        Ok(fx_order_no) => {
+            FxConversionRepository::record_fx_order(pool, id, &fx_order_no).await?;
+            submit(state, held.request_id, held.request, held.price).await
+        }
+        Err(reason) => {
+            warn!(id = %id, ticker = %pending.ticker, reason = %reason, "FX conversion failed");
+            ExecutionResult::failure(held.request_id, reason)
+        }
+    };
+    FxConversionRepository::record_order(pool, id, result.order_id, result.error.as_deref())
+        .await?;
+    info!(
+        id = %id,
+        ticker = %pending.ticker,
+        success = result.success,
+        order_id = ?result.order_id,
+        "FX conversion approved"
+    );
+
+    let record = FxConversionRepository::get(pool, id)
+        .await?
+        .unwrap_or(pending);
+    Ok(FxApprovalOutcome::Submitted(record, result))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_held_order_roundtrip() {
+        let held = HeldOrder {
+            request_id: Uuid::new_v4(),
+            request: OrderRequest::market_buy("AAPL".to_string(), Decimal::from(3)),
+            price: Decimal::from(190),
+        };
+        let value = serde_json::to_value(&held).unwrap();
+        let parsed: HeldOrder = serde_json::from_value(value).unwrap();
+        assert_eq!(parsed.request_id, held.request_id);
+        assert_eq!(parsed.request.ticker, "AAPL");
+        assert_eq!(parsed.request.quantity, Decimal::from(3));
+        assert_eq!(parsed.price, Decimal::from(190));
+    }
+
+    #[test]
+    fn test_decision_status() {
+        let plan = FxConversionPlan {
+            decision: FxConversionDecision::SpreadTooWide,
+            required_usd: Decimal::from(1000),
+            shortfall_usd: Decimal::from(600),
+            usd_amount: Decimal::from(606),
+            krw_amount: Decimal::from(848_400),
+            rate: Decimal::from(1400),
+            spread_bps: Some(Decimal::from(145)),
+        };
+        let (status, reason) = decision_status(&plan);
+        assert_eq!(status, FxConversionStatus::Rejected);
+        assert_eq!(reason.as_deref(), Some("FX spread 145 bps exceeds limit"));
+
+        let plan = FxConversionPlan {
+            decision: FxConversionDecision::AwaitApproval,
+            ..plan
+        };
+        assert_eq!(decision_status(&plan), (FxConversionStatus::Pending, None));
+
+        // 실행 판단은 환전 주문 후 제출
+        let plan = FxConversionPlan {
+            decision: FxConversionDecision::Execute,
+            ..plan
+        };
+        assert_eq!(decision_status(&plan), (FxConversionStatus::Executed, None));
+
+        let plan = FxConversionPlan {
+            decision: FxConversionDecision::NotNeeded,
+            ..plan
+        };
+        assert_eq!(
+            decision_status(&plan),
+            (FxConversionStatus::NotNeeded, None)
+        );
+    }
+
+    /// 요청 금액을 기록하는 환전 대역.
+    struct MockConverter {
+        requested: std::sync::Mutex<Vec<Decimal>>,
+        fail: bool,
+    }
+
+    #[async_trait]
+    impl FxConverter for MockConverter {
+        async fn convert_krw_to_usd(&self, usd_amount: Decimal) -> Result<String, String> {
+            self.requested.lock().unwrap().push(usd_amount);
+            if self.fail {
+                Err("insufficient KRW deposit".to_string())
+            } else {
+                Ok("0000012345".to_string())
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_execute_conversion() {
+        let converter = MockConverter {
+            requested: Default::default(),
+            fail: false,
+        };
+        let order_no = execute_conversion(Some(&converter), Decimal::from(606))
+            .await
+            .unwrap();
+        assert_eq!(order_no, "0000012345");
+        assert_eq!(
+            *converter.requested.lock().unwrap(),
+            vec![Decimal::from(606)]
+        );
+
+        // 환전 주문 실패 시 원 주문을 제출하지 않도록 사유 반환
+        let converter = MockConverter {
+            requested: Default::default(),
+            fail: true,
+        };
+        assert_eq!(
+            execute_conversion(Some(&converter), Decimal::from(606)).await,
+            Err("FX conversion order failed: insufficient KRW deposit".to_string())
+        );
+        assert_eq!(
+            execute_conversion(None, Decimal::from(606)).await,
+            Err("FX conversion order failed: KIS overseas client not configured".to_string())
+        );
+    }
+}
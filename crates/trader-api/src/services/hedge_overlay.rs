@@ -0,0 +1,438 @@
+//! 포트폴리오 베타 헤지 오버레이.
+//!
+//! 하루 한 번 보유 종목의 벤치마크 대비 베타(일봉 수익률 기준)로 순 베타를 계산하고,
+//! 목표 베타 밴드를 벗어나면 헤지 상품(인버스 ETF 또는 지수 선물) 수량을 조정합니다.
+//! 주문은 `OrderExecutor::process_order_request()`로 일반 주문과 같은 리스크/실행 경로를
+//! 거치며, 조정 결과는 `hedge_rebalance`에 기록되어 헤지 손익이 별도로 집계됩니다.
+//! 활성화 여부와 목표는 `PUT /api/v1/hedge`로 변경합니다.
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{NaiveDate, Utc};
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+use rust_decimal::Decimal;
+use sqlx::PgPool;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, info, warn};
+use trader_analytics::correlation::{align_returns, closes_to_dated_returns, trailing_beta};
+use trader_core::{OrderRequest, Side, SymbolRiskMetrics, Timeframe};
+use trader_data::cache::CachedHistoricalDataProvider;
+use trader_data::SymbolRiskStore;
+use trader_execution::{hedge_pnl, plan_hedge, HedgeExposure, HedgePlan};
+use uuid::Uuid;
+
+use crate::repository::{HedgeConfigRecord, HedgeRebalanceInput, HedgeRepository};
+use crate::state::AppState;
+
+/// 헤지 주문에 기록하는 전략 ID (전략 손익과 분리).
+pub const HEDGE_STRATEGY_ID: &str = "hedge_overlay";
+
+/// 베타 추정에 필요한 최소 공통 수익률 관측 수.
+const MIN_BETA_OBSERVATIONS: usize = 20;
+
+/// 관측이 부족한 종목에 적용할 베타.
+const DEFAULT_BETA: Decimal = Decimal::ONE;
+
+/// 수집기가 저장한 베타를 사용할 최대 경과 일수.
+const STORED_BETA_MAX_AGE_DAYS: i64 = 3;
+
+/// 헤지 오버레이 설정.
+#[derive(Debug, Clone)]
+pub struct HedgeOverlayConfig {
+    /// 조정 대상 확인 주기 (조정은 하루 한 번)
+    pub poll_interval: Duration,
+}
+
+impl HedgeOverlayConfig {
+    /// 환경변수에서 설정 로드.
+    ///
+    /// `HEDGE_OVERLAY_ENABLED=false`이면 `None`을 반환합니다.
+    /// 실제 헤지 여부는 `hedge_overlay_config.enabled`로 제어합니다.
+    pub fn from_env() -> Option<Self> {
+        let enabled = std::env::var("HEDGE_OVERLAY_ENABLED")
+            .map(|v| v.to_lowercase() != "false")
+            .unwrap_or(true);
+        if !enabled {
+            return None;
+        }
+
+        let poll_interval = std::env::var("HEDGE_OVERLAY_POLL_SECS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .filter(|secs| *secs > 0)
+            .map(Duration::from_secs)
+            .unwrap_or(Duration::from_secs(3600));
+
+        Some(Self { poll_interval })
+    }
+}
+
+/// 일봉 종가 로드 (날짜별).
+async fn load_closes(
+    provider: &CachedHistoricalDataProvider,
+    ticker: &str,
+    limit: usize,
+) -> BTreeMap<NaiveDate, f64> {
+    match provider.get_klines(ticker, Timeframe::D1, limit).await {
+        Ok(klines) => klines
+            .iter()
+            .filter_map(|k| k.close.to_f64().map(|c| (k.open_time.date_naive(), c)))
+            .collect(),
+        Err(e) => {
+            warn!(ticker, error = %e, "Failed to load closes for hedge beta");
+            BTreeMap::new()
+        }
+    }
+}
+
+/// 벤치마크 대비 베타 추정.
+///
+/// 공통 관측이 [`MIN_BETA_OBSERVATIONS`]보다 적으면 [`DEFAULT_BETA`]를 사용합니다.
+pub fn estimate_beta(
+    closes: &BTreeMap<NaiveDate, f64>,
+    benchmark: &BTreeMap<NaiveDate, f64>,
+    lookback: usize,
+) -> Decimal {
+    let (asset_returns, benchmark_returns) = align_returns(
+        &closes_to_dated_returns(closes),
+        &closes_to_dated_returns(benchmark),
+    );
+    if asset_returns.len() < MIN_BETA_OBSERVATIONS {
+        return DEFAULT_BETA;
+    }
+
+    let window = lookback.min(asset_returns.len());
+    trailing_beta(&asset_returns, &benchmark_returns, window)
+        .and_then(Decimal::from_f64)
+        .map(|beta| beta.round_dp(4))
+        .unwrap_or(DEFAULT_BETA)
+}
+
+/// 수집기가 저장한 베타 (같은 벤치마크 기준이고 최근 계산된 경우만).
+fn stored_beta(
+    metrics: Option<&SymbolRiskMetrics>,
+    benchmark_ticker: &str,
+    now: chrono::DateTime<Utc>,
+) -> Option<Decimal> {
+    let metrics = metrics?;
+    if metrics.is_stale(now, chrono::Duration::days(STORED_BETA_MAX_AGE_DAYS)) {
+        return None;
+    }
+    metrics
+        .beta_against(benchmark_ticker)
+        .and_then(Decimal::from_f64)
+        .map(|beta| beta.round_dp(4))
+}
+
+/// 보유 포지션의 종목별 베타 노출 (헤지 상품 제외).
+///
+/// 수집기가 매일 계산한 252일 베타를 우선 사용하고, 없거나 오래되었거나 벤치마크가
+/// 다르면 `beta_lookback` 구간 일봉으로 직접 추정합니다.
+async fn collect_exposures(
+    state: &AppState,
+    pool: &PgPool,
+    provider: &CachedHistoricalDataProvider,
+    config: &HedgeConfigRecord,
+    benchmark: &BTreeMap<NaiveDate, f64>,
+) -> Vec<HedgeExposure> {
+    let positions = state.executor.read().await.get_open_positions().await;
+
+    let mut values: HashMap<String, Decimal> = HashMap::new();
+    for position in positions.iter().filter(|p| p.ticker != config.hedge_ticker) {
+        let price = if position.current_price > Decimal::ZERO {
+            position.current_price
+        } else {
+            position.entry_price
+        };
+        let value = position.quantity * price;
+        let signed = if position.side == Side::Sell {
+            -value
+        } else {
+            value
+        };
+        *values.entry(position.ticker.clone()).or_default() += signed;
+    }
+
+    let tickers: Vec<&str> = values.keys().map(String::as_str).collect();
+    let stored = SymbolRiskStore::new(pool.clone())
+        .load(&tickers)
+        .await
+        .unwrap_or_else(|e| {
+            warn!(error = %e, "Failed to load stored betas, estimating from klines");
+            HashMap::new()
+        });
+
+    let lookback = config.beta_lookback.max(MIN_BETA_OBSERVATIONS as i32) as usize;
+    let now = Utc::now();
+    let mut exposures = Vec::with_capacity(values.len());
+    for (ticker, market_value) in values {
+        let beta = if ticker == config.benchmark_ticker {
+            Decimal::ONE
+        } else if let Some(beta) = stored_beta(stored.get(&ticker), &config.benchmark_ticker, now) {
+            beta
+        } else {
+            let closes = load_closes(provider, &ticker, lookback * 2).await;
+            estimate_beta(&closes, benchmark, lookback)
+        };
+        debug!(ticker = %ticker, value = %market_value, beta = %beta, "Hedge exposure");
+        exposures.push(HedgeExposure {
+            ticker,
+            market_value,
+            beta,
+        });
+    }
+    exposures.sort_by(|a, b| a.ticker.cmp(&b.ticker));
+    exposures
+}
+
+/// 헤지 조정 한 회 실행.
+///
+/// 헤지 상품 가격을 구할 수 없으면 기록 없이 None을 반환하며, 다음 주기에 다시 시도합니다.
+pub async fn rebalance_hedge(
+    state: &AppState,
+    pool: &PgPool,
+    provider: &CachedHistoricalDataProvider,
+    config: &HedgeConfigRecord,
+    as_of_date: NaiveDate,
+) -> Result<Option<HedgePlan>, sqlx::Error> {
+    let lookback = config.beta_lookback.max(MIN_BETA_OBSERVATIONS as i32) as usize;
+    let benchmark = load_closes(provider, &config.benchmark_ticker, lookback * 2).await;
+
+    let Some(hedge_price) = provider
+        .get_klines(&config.hedge_ticker, Timeframe::D1, 1)
+        .await
+        .ok()
+        .and_then(|klines| klines.last().map(|k| k.close))
+        .filter(|price| *price > Decimal::ZERO)
+    else {
+        warn!(ticker = %config.hedge_ticker, "No hedge instrument price");
+        return Ok(None);
+    };
+
+    let exposures = collect_exposures(state, pool, provider, config, &benchmark).await;
+    let equity = state
+        .executor
+        .read()
+        .await
+        .risk_manager()
+        .read()
+        .await
+        .balance();
+    let fills = HedgeRepository::fills(pool, &config.hedge_ticker).await?;
+    let current_quantity = hedge_pnl(&fills, hedge_price).quantity;
+
+    let plan = plan_hedge(
+        &config.settings(),
+        &exposures,
+        current_quantity,
+        hedge_price,
+        equity,
+    );
+
+    let mut input = HedgeRebalanceInput {
+        as_of_date,
+        hedge_ticker: config.hedge_ticker.clone(),
+        hedge_price,
+        equity,
+        equity_beta_exposure: plan.equity_beta_exposure,
+        net_beta_before: plan.net_beta_before,
+        net_beta_after: plan.net_beta_after,
+        quantity_before: plan.current_quantity,
+        target_quantity: plan.target_quantity,
+        order_quantity: plan.order_quantity,
+        exposures: serde_json::to_value(&exposures).unwrap_or_default(),
+        reason: Some(plan.reason.clone()),
+        success: true,
+        error: None,
+        order_id: None,
+    };
+
+    if plan.needs_rebalance() {
+        let request_id = Uuid::new_v4();
+        let quantity = plan.order_quantity.abs();
+        let request = if plan.order_quantity > Decimal::ZERO {
+            OrderRequest::market_buy(config.hedge_ticker.clone(), quantity)
+        } else {
+            OrderRequest::market_sell(config.hedge_ticker.clone(), quantity)
+        }
+        .with_client_id(format!("hedge_{}", request_id))
+        .with_strategy(HEDGE_STRATEGY_ID.to_string());
+
+        let result = {
+            let executor = state.executor.read().await;
+            executor
+                .process_order_request(request_id, request, hedge_price)
+                .await
+        };
+
+        if result.success {
+            info!(
+                ticker = %config.hedge_ticker,
+                quantity = %plan.order_quantity,
+                net_beta_before = %plan.net_beta_before,
+                net_beta_after = %plan.net_beta_after,
+                order_id = ?result.order_id,
+                "Hedge order submitted"
+            );
+        } else {
+            warn!(
+                ticker = %config.hedge_ticker,
+                error = ?result.error,
+                "Hedge order rejected"
+            );
+        }
+
+        input.success = result.success;
+        input.error = result.error;
+        input.order_id = result.order_id;
+    }
+
+    HedgeRepository::insert_rebalance(pool, &input).await?;
+    Ok(Some(plan))
+}
+
+/// 헤지 오버레이 시작.
+///
+/// # Arguments
+///
+/// * `state` - 애플리케이션 상태 (데이터 제공자, 실행기)
+/// * `pool` - 헤지 설정 및 조정 이력 DB
+/// * `config` - 서비스 설정
+/// * `shutdown` - Graceful shutdown을 위한 CancellationToken
+///
+/// # Returns
+///
+/// 백그라운드 task의 JoinHandle
+pub fn start_hedge_overlay(
+    state: Arc<AppState>,
+    pool: PgPool,
+    config: HedgeOverlayConfig,
+    shutdown: CancellationToken,
+) -> tokio::task::JoinHandle<()> {
+    let provider = state
+        .data_provider
+        .clone()
+        .unwrap_or_else(|| Arc::new(CachedHistoricalDataProvider::new(pool.clone())));
+
+    let task = state.tasks.register(
+        "hedge_overlay",
+        "포트폴리오 베타 헤지 일별 조정",
+        config.poll_interval,
+    );
+
+    tokio::spawn(async move {
+        info!(
+            poll_secs = config.poll_interval.as_secs(),
+            "Hedge overlay started"
+        );
+        let mut ticker = tokio::time::interval(config.poll_interval);
+
+        loop {
+            if task.wait_next(&mut ticker, &shutdown).await.is_none() {
+                info!("Hedge overlay stopped");
+                break;
+            }
+            let mut run = task.begin();
+
+            let hedge_config = match HedgeRepository::get_config(&pool).await {
+                Ok(hedge_config) => hedge_config,
+                Err(e) => {
+                    warn!(error = %e, "Failed to load hedge overlay config");
+                    run.fail(e);
+                    continue;
+                }
+            };
+
+            // 하루 한 번만 조정
+            let today = Utc::now().date_naive();
+            if !hedge_config.enabled
+                || hedge_config
+                    .last_rebalanced_on
+                    .is_some_and(|date| date >= today)
+            {
+                continue;
+            }
+
+            match rebalance_hedge(&state, &pool, &provider, &hedge_config, today).await {
+                Ok(Some(plan)) => {
+                    info!(
+                        net_beta = %plan.net_beta_before,
+                        order_quantity = %plan.order_quantity,
+                        "Hedge overlay checked"
+                    );
+                    if let Err(e) = HedgeRepository::mark_rebalanced(&pool, today).await {
+                        warn!(error = %e, "Failed to record hedge rebalance date");
+                        run.fail(e);
+                    }
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    warn!(error = %e, "Hedge overlay cycle failed");
+                    run.fail(e);
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn series(start: NaiveDate, closes: &[f64]) -> BTreeMap<NaiveDate, f64> {
+        closes
+            .iter()
+            .enumerate()
+            .map(|(i, c)| (start + chrono::Duration::days(i as i64), *c))
+            .collect()
+    }
+
+    #[test]
+    fn test_estimate_beta() {
+        let start = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+        let mut benchmark = vec![100.0];
+        let mut levered = vec![50.0];
+        for i in 1..40 {
+            let r = if i % 3 == 0 { -0.01 } else { 0.008 };
+            benchmark.push(benchmark[i - 1] * (1.0 + r));
+            levered.push(levered[i - 1] * (1.0 + 2.0 * r));
+        }
+
+        let beta = estimate_beta(&series(start, &levered), &series(start, &benchmark), 30);
+        assert_eq!(beta, Decimal::from(2));
+
+        // 관측 부족 시 기본 베타
+        let beta = estimate_beta(
+            &series(start, &levered[..10]),
+            &series(start, &benchmark),
+            30,
+        );
+        assert_eq!(beta, DEFAULT_BETA);
+    }
+
+    #[test]
+    fn test_stored_beta() {
+        let now = Utc::now();
+        let metrics = SymbolRiskMetrics {
+            ticker: "005930".to_string(),
+            beta: Some(1.23456),
+            beta_benchmark: Some("069500".to_string()),
+            updated_at: Some(now - chrono::Duration::hours(20)),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            stored_beta(Some(&metrics), "069500", now),
+            Some(Decimal::new(12346, 4))
+        );
+        // 다른 벤치마크, 오래된 값, 값 없음은 직접 추정
+        assert_eq!(stored_beta(Some(&metrics), "SPY", now), None);
+        assert_eq!(
+            stored_beta(Some(&metrics), "069500", now + chrono::Duration::days(5)),
+            None
+        );
+        assert_eq!(stored_beta(None, "069500", now), None);
+    }
+}
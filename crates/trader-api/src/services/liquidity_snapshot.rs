@@ -0,0 +1,157 @@
+//! 실행기 유동성 스냅샷 갱신.
+//!
+//! 최근 일봉으로 종목별 평균 거래량(ADV)과 평균 거래대금을 계산해
+//! 실행기(`OrderExecutor::set_liquidity_snapshot`)에 반영합니다.
+//! 실행기는 이 값으로 진입 주문 수량을 ADV 대비 비율로 제한합니다.
+
+use std::sync::Arc;
+
+use chrono::{Duration, Utc};
+use rust_decimal::Decimal;
+use tracing::{debug, warn};
+use trader_core::types::MarketType;
+use trader_core::{Kline, Timeframe};
+use trader_data::cache::CachedHistoricalDataProvider;
+use trader_execution::{is_us_equity, LiquiditySnapshot};
+
+use crate::state::AppState;
+
+/// ADV 계산 기간 (거래일).
+pub const ADV_WINDOW: usize = 20;
+
+/// 스냅샷 재계산 주기 (시간).
+const SNAPSHOT_MAX_AGE_HOURS: i64 = 12;
+
+/// 티커 형식으로 시장 유형 추정.
+pub fn market_type_for_ticker(ticker: &str) -> MarketType {
+    if ticker.contains('/') {
+        MarketType::Crypto
+    } else if is_us_equity(ticker) {
+        MarketType::UsStock
+    } else {
+        MarketType::KrStock
+    }
+}
+
+/// 최근 일봉으로 유동성 스냅샷 계산.
+///
+/// 마지막 `window`개 일봉의 평균 거래량과 평균 거래대금(종가 × 거래량)을 사용합니다.
+/// 일봉이 없거나 거래량이 모두 0이면 None을 반환합니다.
+pub fn snapshot_from_klines(
+    klines: &[Kline],
+    market_type: MarketType,
+    window: usize,
+) -> Option<LiquiditySnapshot> {
+    let recent = &klines[klines.len().saturating_sub(window)..];
+    if recent.is_empty() {
+        return None;
+    }
+
+    let count = Decimal::from(recent.len());
+    let avg_daily_volume = recent.iter().map(|k| k.volume).sum::<Decimal>() / count;
+    if avg_daily_volume <= Decimal::ZERO {
+        return None;
+    }
+    let avg_daily_amount = recent
+        .iter()
+        .map(|k| k.quote_volume.unwrap_or(k.close * k.volume))
+        .sum::<Decimal>()
+        / count;
+
+    Some(LiquiditySnapshot {
+        market_type,
+        avg_daily_volume,
+        avg_daily_amount,
+        spread_pct: None,
+        as_of: Utc::now(),
+    })
+}
+
+/// 주문 전 종목별 유동성 스냅샷 갱신.
+///
+/// 스냅샷이 없거나 오래된 종목만 일봉을 조회합니다.
+/// 조회 실패 시 기존 스냅샷을 유지하며, 스냅샷이 없는 종목은 수량 상한 없이 처리됩니다.
+pub async fn refresh_liquidity_snapshots(state: &AppState, tickers: &[String]) {
+    let provider = match (&state.data_provider, &state.db_pool) {
+        (Some(provider), _) => provider.clone(),
+        (None, Some(pool)) => Arc::new(CachedHistoricalDataProvider::new(pool.clone())),
+        (None, None) => return,
+    };
+
+    let executor = state.executor.read().await;
+    let stale_before = Utc::now() - Duration::hours(SNAPSHOT_MAX_AGE_HOURS);
+
+    for ticker in tickers {
+        let is_fresh = executor
+            .liquidity_snapshot(ticker)
+            .await
+            .is_some_and(|s| s.as_of >= stale_before);
+        if is_fresh {
+            continue;
+        }
+
+        match provider.get_klines(ticker, Timeframe::D1, ADV_WINDOW).await {
+            Ok(klines) => {
+                let market_type = market_type_for_ticker(ticker);
+                if let Some(snapshot) = snapshot_from_klines(&klines, market_type, ADV_WINDOW) {
+                    debug!(
+                        ticker = %ticker,
+                        adv = %snapshot.avg_daily_volume,
+                        amount = %snapshot.avg_daily_amount,
+                        "Liquidity snapshot updated"
+                    );
+                    executor.set_liquidity_snapshot(ticker, snapshot).await;
+                }
+            }
+            Err(e) => {
+                warn!(ticker = %ticker, error = %e, "Failed to load klines for liquidity cap")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn kline(close: Decimal, volume: Decimal) -> Kline {
+        let now = Utc::now();
+        Kline {
+            ticker: "123456".to_string(),
+            timeframe: Timeframe::D1,
+            open_time: now,
+            open: close,
+            high: close,
+            low: close,
+            close,
+            volume,
+            close_time: now,
+            quote_volume: None,
+            num_trades: None,
+        }
+    }
+
+    #[test]
+    fn test_snapshot_from_klines() {
+        let klines = vec![
+            kline(dec!(100), dec!(9999)),
+            kline(dec!(1000), dec!(100)),
+            kline(dec!(2000), dec!(300)),
+        ];
+
+        // 최근 2개 일봉만 사용
+        let snapshot = snapshot_from_klines(&klines, MarketType::KrStock, 2).unwrap();
+        assert_eq!(snapshot.avg_daily_volume, dec!(200));
+        assert_eq!(snapshot.avg_daily_amount, dec!(350000));
+
+        assert!(snapshot_from_klines(&[], MarketType::KrStock, 20).is_none());
+    }
+
+    #[test]
+    fn test_market_type_for_ticker() {
+        assert_eq!(market_type_for_ticker("BTC/USDT"), MarketType::Crypto);
+        assert_eq!(market_type_for_ticker("AAPL"), MarketType::UsStock);
+        assert_eq!(market_type_for_ticker("005930"), MarketType::KrStock);
+    }
+}
@@ -0,0 +1,320 @@
+//! 시장 달력 동기화 서비스.
+//!
+//! KIS 휴장일 API로 KRX/미국 시장의 올해·내년 달력을 연 단위로 조회하여
+//! `market_calendar` 테이블에 저장하고, DB 달력을 [`AppState::market_calendar`]에 적재합니다.
+//! 적재된 달력은 시장 상태 API와 [`HolidayChecker`]가 사용하므로
+//! 매년 휴장일을 코드에 반영할 필요가 없습니다.
+//!
+//! - 동기화한 지 `refresh_after`가 지난 연도만 다시 조회합니다 (임시공휴일 반영).
+//! - API가 평일 휴장일을 하나도 돌려주지 않은 연도(내년 일정 미공개 등)는 저장하지 않습니다.
+//! - KIS 클라이언트가 없으면 DB 달력(이전 동기화, 수동 입력)만 적재합니다.
+//!
+//! 전략 엔진의 거래 시간대는 [`CalendarHolidaySource`]로 같은 달력을 사용합니다.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDate, Utc};
+use sqlx::PgPool;
+use tokio::sync::RwLock;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, info, warn};
+use trader_core::{CalendarMarket, MarketCalendar};
+use trader_exchange::connector::kis::{HolidayChecker, KisOAuth};
+use trader_strategy::HolidaySource;
+
+use crate::repository::MarketCalendarRepository;
+use crate::state::AppState;
+
+/// 동기화 대상 시장.
+pub const CALENDAR_MARKETS: [CalendarMarket; 2] = [CalendarMarket::Kr, CalendarMarket::Us];
+
+/// 시장 달력 동기화 설정.
+#[derive(Debug, Clone)]
+pub struct MarketCalendarSyncConfig {
+    /// 동기화 필요 여부 확인 주기
+    pub check_interval: Duration,
+    /// 이 기간이 지나면 이미 동기화한 연도도 다시 조회
+    pub refresh_after: chrono::Duration,
+}
+
+impl MarketCalendarSyncConfig {
+    /// 환경변수에서 설정 로드.
+    ///
+    /// `MARKET_CALENDAR_SYNC_ENABLED=false`이면 `None`을 반환합니다.
+    pub fn from_env() -> Option<Self> {
+        let enabled = std::env::var("MARKET_CALENDAR_SYNC_ENABLED")
+            .map(|v| v.to_lowercase() != "false")
+            .unwrap_or(true);
+        if !enabled {
+            return None;
+        }
+
+        let check_interval = std::env::var("MARKET_CALENDAR_SYNC_INTERVAL_HOURS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .filter(|hours| *hours > 0)
+            .map(|hours| Duration::from_secs(hours * 3600))
+            .unwrap_or(Duration::from_secs(24 * 3600));
+
+        let refresh_after = std::env::var("MARKET_CALENDAR_REFRESH_DAYS")
+            .ok()
+            .and_then(|v| v.parse::<i64>().ok())
+            .filter(|days| *days > 0)
+            .map(chrono::Duration::days)
+            .unwrap_or(chrono::Duration::days(30));
+
+        Some(Self {
+            check_interval,
+            refresh_after,
+        })
+    }
+}
+
+/// 동기화 대상 연도 (KST 기준 올해, 내년).
+pub fn sync_years(now: DateTime<Utc>) -> [i32; 2] {
+    let kst = FixedOffset::east_opt(9 * 3600).expect("valid offset");
+    let year = now.with_timezone(&kst).year();
+    [year, year + 1]
+}
+
+/// 다시 조회해야 하는지 여부 (동기화한 적이 없거나 `refresh_after`가 지남).
+pub fn needs_refresh(
+    last_synced_at: Option<DateTime<Utc>>,
+    now: DateTime<Utc>,
+    refresh_after: chrono::Duration,
+) -> bool {
+    last_synced_at.map_or(true, |at| now - at >= refresh_after)
+}
+
+/// DB 달력을 메모리 달력에 적재.
+///
+/// 동기화된 행이 있는 연도만 적재합니다. 수동 입력 행만 있는 연도는
+/// 휴장일 목록이 완전하지 않으므로 적재하지 않습니다. 적재한 연도 수를 반환합니다.
+pub async fn load_market_calendar(
+    pool: &PgPool,
+    calendar: &RwLock<MarketCalendar>,
+    years: &[i32],
+) -> Result<usize, sqlx::Error> {
+    let mut loaded = 0;
+    for market in CALENDAR_MARKETS {
+        for &year in years {
+            // manual 행이 같은 일자의 동기화 행을 가리므로 last_synced_at으로 판정
+            if MarketCalendarRepository::last_synced_at(pool, market, year)
+                .await?
+                .is_none()
+            {
+                continue;
+            }
+
+            let records = MarketCalendarRepository::list_year(pool, market, year).await?;
+            let entries = records.iter().filter_map(|r| r.to_entry());
+            calendar.write().await.load_year(market, year, entries);
+            loaded += 1;
+        }
+    }
+    Ok(loaded)
+}
+
+/// 한 시장/연도 달력을 KIS API로 조회하여 저장.
+///
+/// 평일 휴장일이 없으면 저장하지 않고 `Ok(false)`를 반환합니다.
+async fn sync_year(
+    pool: &PgPool,
+    checker: &HolidayChecker,
+    market: CalendarMarket,
+    year: i32,
+) -> Result<bool, String> {
+    let entries = checker
+        .fetch_year(market, year)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    if !entries.iter().any(|e| e.is_holiday()) {
+        debug!(market = %market, year, "No holidays published yet, skipping");
+        return Ok(false);
+    }
+
+    let saved = MarketCalendarRepository::replace_synced_year(pool, market, year, &entries)
+        .await
+        .map_err(|e| e.to_string())?;
+    info!(market = %market, year, saved, "Market calendar synced");
+    Ok(true)
+}
+
+/// 동기화가 필요한 연도를 조회하고 DB 달력을 다시 적재.
+async fn sync_once(
+    pool: &PgPool,
+    checker: Option<&HolidayChecker>,
+    calendar: &RwLock<MarketCalendar>,
+    config: &MarketCalendarSyncConfig,
+) -> Result<(), sqlx::Error> {
+    let now = Utc::now();
+    let years = sync_years(now);
+
+    if let Some(checker) = checker {
+        for market in CALENDAR_MARKETS {
+            for year in years {
+                let last_synced_at =
+                    MarketCalendarRepository::last_synced_at(pool, market, year).await?;
+                if !needs_refresh(last_synced_at, now, config.refresh_after) {
+                    continue;
+                }
+                if let Err(e) = sync_year(pool, checker, market, year).await {
+                    warn!(market = %market, year, error = %e, "Failed to sync market calendar");
+                }
+            }
+        }
+    }
+
+    let loaded = load_market_calendar(pool, calendar, &years).await?;
+    debug!(loaded, "Market calendar loaded");
+    Ok(())
+}
+
+/// 전략 거래 시간대용 휴장일 판정 소스.
+///
+/// 적재된 시장 달력으로 판정하고, 달력에 없는 연도는 KIS 휴장일 API로 조회합니다.
+/// KIS 클라이언트도 없으면 평일은 거래일로 봅니다.
+pub struct CalendarHolidaySource {
+    calendar: Arc<RwLock<MarketCalendar>>,
+    checker: Option<HolidayChecker>,
+}
+
+impl CalendarHolidaySource {
+    /// 애플리케이션 상태의 공유 달력과 KIS 클라이언트로 생성.
+    pub fn new(state: &AppState) -> Self {
+        let checker = kis_oauth(state).and_then(|oauth| {
+            HolidayChecker::with_shared_oauth(oauth)
+                .map_err(|e| warn!(error = %e, "Failed to create holiday checker"))
+                .ok()
+        });
+        Self {
+            calendar: Arc::clone(&state.market_calendar),
+            checker,
+        }
+    }
+}
+
+#[async_trait]
+impl HolidaySource for CalendarHolidaySource {
+    async fn is_holiday(
+        &self,
+        market: CalendarMarket,
+        date: NaiveDate,
+    ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>> {
+        if let Some(holiday) = self.calendar.read().await.is_holiday(market, date) {
+            return Ok(holiday);
+        }
+
+        let holiday = match (&self.checker, market) {
+            (Some(checker), CalendarMarket::Kr) => checker.is_kr_holiday(date).await?,
+            (Some(checker), CalendarMarket::Us) => checker.is_us_holiday(date).await?,
+            (None, _) => false,
+        };
+        Ok(holiday)
+    }
+}
+
+/// 휴장일 API 호출에 사용할 KIS OAuth (국내 클라이언트 우선).
+fn kis_oauth(state: &AppState) -> Option<Arc<KisOAuth>> {
+    state
+        .kis_kr_client
+        .as_ref()
+        .map(|client| Arc::clone(client.oauth()))
+        .or_else(|| {
+            state
+                .kis_us_client
+                .as_ref()
+                .map(|client| Arc::clone(client.oauth()))
+        })
+}
+
+/// 시장 달력 동기화 서비스 시작.
+///
+/// # Arguments
+///
+/// * `state` - 애플리케이션 상태 (KIS 클라이언트, 공유 달력)
+/// * `pool` - 달력 DB
+/// * `config` - 서비스 설정
+/// * `shutdown` - Graceful shutdown을 위한 CancellationToken
+///
+/// # Returns
+///
+/// 백그라운드 task의 JoinHandle
+pub fn start_market_calendar_sync(
+    state: Arc<AppState>,
+    pool: PgPool,
+    config: MarketCalendarSyncConfig,
+    shutdown: CancellationToken,
+) -> tokio::task::JoinHandle<()> {
+    let calendar = Arc::clone(&state.market_calendar);
+    let checker = kis_oauth(&state).and_then(|oauth| {
+        HolidayChecker::with_shared_oauth(oauth)
+            .map_err(|e| warn!(error = %e, "Failed to create holiday checker"))
+            .ok()
+    });
+
+    let task = state.tasks.register(
+        "market_calendar_sync",
+        "KRX/미국 휴장일 달력 동기화",
+        config.check_interval,
+    );
+
+    tokio::spawn(async move {
+        info!(
+            check_hours = config.check_interval.as_secs() / 3600,
+            refresh_days = config.refresh_after.num_days(),
+            kis = checker.is_some(),
+            "Market calendar sync started"
+        );
+        let mut ticker = tokio::time::interval(config.check_interval);
+
+        loop {
+            if task.wait_next(&mut ticker, &shutdown).await.is_none() {
+                info!("Market calendar sync stopped");
+                break;
+            }
+            let mut run = task.begin();
+
+            if let Err(e) = sync_once(&pool, checker.as_ref(), &calendar, &config).await {
+                warn!(error = %e, "Failed to sync market calendar");
+                run.fail(e);
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_sync_years_uses_kst() {
+        // 2026-12-31 16:00 UTC = 2027-01-01 01:00 KST
+        let now = Utc.with_ymd_and_hms(2026, 12, 31, 16, 0, 0).unwrap();
+        assert_eq!(sync_years(now), [2027, 2028]);
+
+        let now = Utc.with_ymd_and_hms(2026, 12, 31, 14, 0, 0).unwrap();
+        assert_eq!(sync_years(now), [2026, 2027]);
+    }
+
+    #[test]
+    fn test_needs_refresh() {
+        let now = Utc::now();
+        let refresh_after = chrono::Duration::days(30);
+        assert!(needs_refresh(None, now, refresh_after));
+        assert!(!needs_refresh(
+            Some(now - chrono::Duration::days(29)),
+            now,
+            refresh_after
+        ));
+        assert!(needs_refresh(
+            Some(now - chrono::Duration::days(30)),
+            now,
+            refresh_after
+        ));
+    }
+}
@@ -0,0 +1,307 @@
+//! 실행 조절용 시장 상황 갱신 서비스.
+//!
+//! 실시간 시세 어그리게이터가 브로드캐스트하는 마감 캔들로 단기/장기 실현 변동성 비율을,
+//! `OrderBookMetrics` 메시지로 깊이 가중 스프레드를 종목별로 추적하여
+//! 실행기(`OrderExecutor::set_market_condition`)에 반영합니다.
+//! 실행기는 이 값으로 변동성 급등/스프레드 확대 구간의 진입 주문을 축소하거나 보류합니다.
+//!
+//! # 환경 변수
+//!
+//! - `EXECUTION_GOVERNOR_ENABLED`: `false`이면 비활성화 (기본 true)
+//! - `EXECUTION_GOVERNOR_TIMEFRAME`: 변동성 계산 캔들 타임프레임 (기본값: 1m)
+//! - `EXECUTION_GOVERNOR_SHORT_WINDOW`: 단기 변동성 구간 (캔들 수, 기본값: 10)
+//! - `EXECUTION_GOVERNOR_LONG_WINDOW`: 기준 변동성 구간 (캔들 수, 기본값: 60)
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+
+use chrono::Utc;
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+use rust_decimal::Decimal;
+use tokio::sync::broadcast::error::RecvError;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, info};
+use trader_analytics::realized_volatility_pct;
+use trader_execution::MarketCondition;
+
+use crate::services::liquidity_snapshot::market_type_for_ticker;
+use crate::state::AppState;
+use crate::websocket::{ServerMessage, SharedSubscriptionManager};
+
+/// 시장 상황 갱신 설정.
+#[derive(Debug, Clone)]
+pub struct MarketConditionConfig {
+    /// 변동성 계산 캔들 타임프레임 (예: "1m")
+    pub timeframe: String,
+    /// 단기 변동성 구간 (수익률 개수)
+    pub short_window: usize,
+    /// 기준 변동성 구간 (수익률 개수)
+    pub long_window: usize,
+}
+
+impl Default for MarketConditionConfig {
+    fn default() -> Self {
+        Self {
+            timeframe: "1m".to_string(),
+            short_window: 10,
+            long_window: 60,
+        }
+    }
+}
+
+impl MarketConditionConfig {
+    /// 환경변수에서 설정 로드.
+    ///
+    /// `EXECUTION_GOVERNOR_ENABLED=false`이면 `None`을 반환합니다.
+    pub fn from_env() -> Option<Self> {
+        let enabled = std::env::var("EXECUTION_GOVERNOR_ENABLED")
+            .map(|v| v.to_lowercase() != "false")
+            .unwrap_or(true);
+        if !enabled {
+            return None;
+        }
+
+        let defaults = Self::default();
+        let window = |key: &str, default: usize| {
+            std::env::var(key)
+                .ok()
+                .and_then(|v| v.parse::<usize>().ok())
+                .filter(|v| *v >= 2)
+                .unwrap_or(default)
+        };
+        let short_window = window("EXECUTION_GOVERNOR_SHORT_WINDOW", defaults.short_window);
+        let long_window = window("EXECUTION_GOVERNOR_LONG_WINDOW", defaults.long_window);
+
+        Some(Self {
+            timeframe: std::env::var("EXECUTION_GOVERNOR_TIMEFRAME")
+                .ok()
+                .filter(|v| !v.trim().is_empty())
+                .unwrap_or(defaults.timeframe),
+            short_window,
+            // 기준 구간은 단기 구간보다 길어야 비율이 의미 있음
+            long_window: long_window.max(short_window + 1),
+        })
+    }
+}
+
+/// 종목별 최근 종가와 스프레드.
+#[derive(Debug, Default)]
+struct SymbolState {
+    closes: VecDeque<f64>,
+    spread_bps: Option<Decimal>,
+}
+
+/// 종목별 시장 상황 추적기.
+struct MarketConditionTracker {
+    config: MarketConditionConfig,
+    symbols: HashMap<String, SymbolState>,
+}
+
+impl MarketConditionTracker {
+    fn new(config: MarketConditionConfig) -> Self {
+        Self {
+            config,
+            symbols: HashMap::new(),
+        }
+    }
+
+    /// 마감 캔들 종가 추가 (기준 구간 + 1개까지 보관).
+    fn push_close(&mut self, symbol: &str, close: Decimal) {
+        let Some(close) = close.to_f64().filter(|c| *c > 0.0) else {
+            return;
+        };
+        let capacity = self.config.long_window + 1;
+        let state = self.symbols.entry(symbol.to_string()).or_default();
+        state.closes.push_back(close);
+        while state.closes.len() > capacity {
+            state.closes.pop_front();
+        }
+    }
+
+    /// 호가 스프레드 갱신.
+    fn set_spread(&mut self, symbol: &str, spread_bps: Decimal) {
+        self.symbols
+            .entry(symbol.to_string())
+            .or_default()
+            .spread_bps = Some(spread_bps);
+    }
+
+    /// 단기/장기 실현 변동성 비율 (기준 구간 데이터가 모자라면 `None`).
+    fn volatility_ratio(&self, symbol: &str) -> Option<Decimal> {
+        let closes = &self.symbols.get(symbol)?.closes;
+        let returns: Vec<f64> = closes
+            .iter()
+            .zip(closes.iter().skip(1))
+            .map(|(prev, next)| next / prev - 1.0)
+            .collect();
+        let short = realized_volatility_pct(&returns, self.config.short_window)?;
+        let long = realized_volatility_pct(&returns, self.config.long_window)?;
+        if long <= 0.0 {
+            return None;
+        }
+        Decimal::from_f64(short / long).map(|ratio| ratio.round_dp(4))
+    }
+
+    /// 실행기에 반영할 시장 상황.
+    fn condition(&self, symbol: &str) -> MarketCondition {
+        MarketCondition {
+            market_type: market_type_for_ticker(symbol),
+            volatility_ratio: self.volatility_ratio(symbol),
+            spread_bps: self.symbols.get(symbol).and_then(|s| s.spread_bps),
+            as_of: Utc::now(),
+        }
+    }
+
+    /// 메시지를 반영하고 상황이 바뀐 종목을 반환합니다.
+    fn apply(&mut self, message: &ServerMessage) -> Option<String> {
+        match message {
+            ServerMessage::Kline(kline)
+                if kline.is_closed && kline.timeframe == self.config.timeframe =>
+            {
+                self.push_close(&kline.symbol, kline.close);
+                Some(kline.symbol.clone())
+            }
+            ServerMessage::OrderBookMetrics(metrics) => {
+                self.set_spread(&metrics.symbol, metrics.depth_weighted_spread_bps);
+                Some(metrics.symbol.clone())
+            }
+            _ => None,
+        }
+    }
+}
+
+/// 시장 상황 갱신 서비스 시작.
+///
+/// # Arguments
+///
+/// * `state` - 애플리케이션 상태 (실행기)
+/// * `subscriptions` - 시세가 브로드캐스트되는 WebSocket 구독 관리자
+/// * `config` - 변동성 계산 설정
+/// * `shutdown` - Graceful shutdown을 위한 CancellationToken
+///
+/// # Returns
+///
+/// 백그라운드 task의 JoinHandle
+pub fn start_market_condition_monitor(
+    state: Arc<AppState>,
+    subscriptions: SharedSubscriptionManager,
+    config: MarketConditionConfig,
+    shutdown: CancellationToken,
+) -> tokio::task::JoinHandle<()> {
+    let mut messages = subscriptions.receiver();
+
+    tokio::spawn(async move {
+        info!(
+            timeframe = %config.timeframe,
+            short_window = config.short_window,
+            long_window = config.long_window,
+            "Market condition monitor started"
+        );
+        let mut tracker = MarketConditionTracker::new(config);
+
+        loop {
+            let message = tokio::select! {
+                _ = shutdown.cancelled() => {
+                    info!("Market condition monitor stopped");
+                    break;
+                }
+                received = messages.recv() => match received {
+                    Ok(message) => message,
+                    Err(RecvError::Lagged(skipped)) => {
+                        debug!(skipped, "Market condition monitor lagged behind broadcasts");
+                        continue;
+                    }
+                    Err(RecvError::Closed) => break,
+                },
+            };
+
+            if let Some(symbol) = tracker.apply(&message) {
+                let condition = tracker.condition(&symbol);
+                state
+                    .executor
+                    .read()
+                    .await
+                    .set_market_condition(&symbol, condition)
+                    .await;
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::websocket::messages::KlineData;
+    use crate::websocket::OrderBookMetricsData;
+    use rust_decimal_macros::dec;
+
+    fn kline(close: Decimal, timeframe: &str) -> ServerMessage {
+        ServerMessage::Kline(KlineData {
+            symbol: "005930".to_string(),
+            timeframe: timeframe.to_string(),
+            open: close,
+            high: close,
+            low: close,
+            close,
+            volume: dec!(100),
+            open_time: 0,
+            close_time: 0,
+            is_closed: true,
+        })
+    }
+
+    fn tracker() -> MarketConditionTracker {
+        MarketConditionTracker::new(MarketConditionConfig {
+            timeframe: "1m".to_string(),
+            short_window: 3,
+            long_window: 8,
+        })
+    }
+
+    #[test]
+    fn test_volatility_ratio_spikes() {
+        let mut tracker = tracker();
+
+        // 잔잔한 구간 (±0.1%)
+        let mut price = dec!(10000);
+        for i in 0..6 {
+            price += if i % 2 == 0 { dec!(10) } else { dec!(-10) };
+            tracker.apply(&kline(price, "1m"));
+        }
+        // 기준 구간 데이터가 모자라면 계산하지 않음
+        assert_eq!(tracker.volatility_ratio("005930"), None);
+
+        // 다른 타임프레임은 무시
+        assert_eq!(tracker.apply(&kline(dec!(20000), "1h")), None);
+
+        // 최근 캔들 급변 (±3%)
+        for i in 0..3 {
+            price += if i % 2 == 0 { dec!(300) } else { dec!(-300) };
+            tracker.apply(&kline(price, "1m"));
+        }
+        let ratio = tracker.volatility_ratio("005930").unwrap();
+        assert!(ratio > dec!(1.5), "ratio {}", ratio);
+    }
+
+    #[test]
+    fn test_spread_from_orderbook_metrics() {
+        let mut tracker = tracker();
+        let message = ServerMessage::OrderBookMetrics(OrderBookMetricsData {
+            symbol: "005930".to_string(),
+            depth_levels: 5,
+            mid_price: dec!(70000),
+            microprice: dec!(70000),
+            imbalance: dec!(0),
+            bid_depth: dec!(1000),
+            ask_depth: dec!(1000),
+            depth_weighted_spread: dec!(350),
+            depth_weighted_spread_bps: dec!(50),
+            timestamp: 0,
+        });
+
+        assert_eq!(tracker.apply(&message), Some("005930".to_string()));
+        let condition = tracker.condition("005930");
+        assert_eq!(condition.spread_bps, Some(dec!(50)));
+        assert_eq!(condition.volatility_ratio, None);
+    }
+}
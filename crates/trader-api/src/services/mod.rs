@@ -2,10 +2,72 @@
 //!
 //! 전략 실행, 컨텍스트 동기화 등 백그라운드에서 실행되는 서비스들을 제공합니다.
 
+pub mod backtest_scheduler;
+pub mod broker_downtime;
+pub mod competition_runner;
+pub mod conditional_order;
 pub mod context_sync;
+pub mod correlation_monitor;
+pub mod dca_scheduler;
+pub mod execution_fills;
+pub mod fx_conversion;
+pub mod hedge_overlay;
+pub mod liquidity_snapshot;
+pub mod market_calendar;
+pub mod market_condition;
+pub mod market_publisher;
+pub mod notification_digest;
+pub mod order_circuit;
+pub mod orderbook_recorder;
+pub mod paper_trading;
+pub mod reality_check;
+pub mod reconciliation;
+pub mod shadow_runner;
 pub mod signal_alert;
+pub mod strategy_capacity;
+pub mod strategy_equity;
+pub mod strategy_warmup;
+pub mod task_scheduler;
 pub mod telegram_bot;
+pub mod trading_status;
+pub mod watchlist_alert;
+pub mod webhook_publisher;
 
+pub use backtest_scheduler::{start_backtest_scheduler, BacktestSchedulerConfig};
+pub use broker_downtime::{start_broker_downtime_monitor, BrokerDowntimeMonitorConfig};
+pub use competition_runner::{start_competition_runner, CompetitionRunnerConfig};
+pub use conditional_order::{start_conditional_order_service, ConditionalOrderConfig};
 pub use context_sync::start_context_sync_service;
+pub use correlation_monitor::{start_correlation_monitor, CorrelationMonitorConfig};
+pub use dca_scheduler::{start_dca_scheduler, DcaSchedulerConfig};
+pub use execution_fills::{apply_user_event, start_execution_fill_listener};
+pub use fx_conversion::{
+    approve_conversion, submit_order_request, FxApprovalOutcome, FxConversionConfig,
+};
+pub use hedge_overlay::{start_hedge_overlay, HedgeOverlayConfig};
+pub use liquidity_snapshot::refresh_liquidity_snapshots;
+pub use market_calendar::{
+    start_market_calendar_sync, CalendarHolidaySource, MarketCalendarSyncConfig,
+};
+pub use market_condition::{start_market_condition_monitor, MarketConditionConfig};
+pub use market_publisher::{start_market_publisher, MarketPublisherConfig};
+pub use notification_digest::{build_notifier, start_notification_digest, NotificationDigestConfig};
+pub use order_circuit::start_order_circuit_monitor;
+pub use orderbook_recorder::{start_orderbook_recorder, OrderBookRecorderConfig};
+pub use paper_trading::{
+    start_paper_trading, PaperAccountSnapshot, PaperHolding, PaperTradingConfig, PaperTradingDesk,
+};
+pub use reality_check::{start_reality_check_scheduler, RealityCheckSchedulerConfig};
+pub use reconciliation::{run_reconciliation, start_reconciliation_service, ReconciliationConfig};
+pub use shadow_runner::{start_shadow_runner, ShadowRunnerConfig};
 pub use signal_alert::{SignalAlertFilter, SignalAlertService};
+pub use strategy_capacity::{estimate_strategy_capacity, StrategyCapacity};
+pub use strategy_equity::{start_strategy_equity_snapshots, StrategyEquitySnapshotConfig};
+pub use strategy_warmup::{HistoricalWarmupSource, StrategyWarmupConfig};
+pub use task_scheduler::{
+    TaskHandle, TaskRegistry, TaskRun, TaskRunStats, TaskSnapshot, TaskTrigger,
+};
 pub use telegram_bot::ApiBotHandler;
+pub use trading_status::{apply_trading_status, start_trading_status_monitor};
+pub use watchlist_alert::{start_watchlist_alert_service, WatchlistAlertConfig};
+pub use webhook_publisher::start_webhook_publisher;
@@ -0,0 +1,205 @@
+//! 알림 다이제스트 서비스.
+//!
+//! 텔레그램 다이제스트 모드(`TELEGRAM_DIGEST_ENABLED=true`)에서 낮은 우선순위 알림을
+//! 프로세스 전체에서 공유하는 버퍼에 모아 두었다가 매일 `TELEGRAM_DIGEST_SEND_AT`(KST)에
+//! 요약 메시지 하나로 전송합니다. 다이제스트 모드에서는 개별 체결도 낮은 우선순위 알림으로
+//! 버퍼에 쌓여, 활발한 전략의 체결이 요약에 포함됩니다.
+//!
+//! 알림을 보내는 서비스는 [`build_notifier`]로 전송기를 만들어야 버퍼를 공유합니다.
+
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
+
+use chrono::{NaiveDate, Utc};
+use tokio::sync::broadcast::error::RecvError;
+use tokio_util::sync::CancellationToken;
+use tracing::{info, warn};
+use trader_execution::ExecutionEvent;
+use trader_notification::{
+    DigestBuffer, DigestSender, DigestSettings, Notification, NotificationEvent,
+    NotificationManager, NotificationPriority, SmsSender, TelegramSender,
+};
+
+use crate::state::AppState;
+
+/// 텔레그램 다이제스트 버퍼 (프로세스 전체 공유).
+fn telegram_digest_buffer() -> &'static DigestBuffer {
+    static BUFFER: OnceLock<DigestBuffer> = OnceLock::new();
+    BUFFER.get_or_init(DigestBuffer::new)
+}
+
+/// 텔레그램 다이제스트 요약 대기 중인 알림 수.
+pub fn telegram_digest_pending() -> usize {
+    telegram_digest_buffer().len()
+}
+
+/// 알림 관리자 생성 (텔레그램 + 긴급 SMS/음성).
+///
+/// 설정된 채널이 없으면 전송기 없는 관리자를 반환합니다.
+/// 텔레그램 다이제스트 모드에서는 보류 알림이 공유 버퍼에 쌓이고,
+/// SMS/음성 전송기는 긴급 알림만 전달합니다.
+pub fn build_notifier() -> NotificationManager {
+    let mut notifier = NotificationManager::new();
+    if let Some(sender) = TelegramSender::from_env() {
+        notifier.add_sender(
+            DigestSender::new(sender, DigestSettings::from_env("TELEGRAM"))
+                .with_buffer(telegram_digest_buffer().clone()),
+        );
+    }
+    if let Some(sender) = SmsSender::from_env() {
+        notifier.add_sender(sender);
+    }
+    notifier
+}
+
+/// 다이제스트 서비스 설정.
+#[derive(Debug, Clone)]
+pub struct NotificationDigestConfig {
+    /// 다이제스트 설정
+    pub settings: DigestSettings,
+    /// 전송 시각 확인 주기
+    pub poll_interval: Duration,
+}
+
+impl NotificationDigestConfig {
+    /// 환경변수에서 설정 로드.
+    ///
+    /// 다이제스트 모드가 꺼져 있거나 텔레그램이 설정되지 않았으면 `None`을 반환합니다.
+    pub fn from_env() -> Option<Self> {
+        let settings = DigestSettings::from_env("TELEGRAM");
+        if !settings.enabled || TelegramSender::from_env().is_none() {
+            return None;
+        }
+
+        Some(Self {
+            settings,
+            poll_interval: Duration::from_secs(60),
+        })
+    }
+}
+
+/// 체결 이벤트를 낮은 우선순위 체결 알림으로 변환.
+///
+/// 포지션 변경 이벤트는 `None`을 반환합니다.
+pub fn fill_notification(event: &ExecutionEvent) -> Option<Notification> {
+    let ExecutionEvent::OrderFilled {
+        order_id,
+        ticker,
+        side,
+        fill_quantity,
+        fill_price,
+        ..
+    } = event
+    else {
+        return None;
+    };
+
+    Some(
+        Notification::new(NotificationEvent::OrderFilled {
+            symbol: ticker.clone(),
+            side: side.to_string().to_lowercase(),
+            quantity: *fill_quantity,
+            price: *fill_price,
+            order_id: order_id.to_string(),
+        })
+        .with_priority(NotificationPriority::Low),
+    )
+}
+
+/// 다이제스트 서비스 시작.
+///
+/// 체결 이벤트를 다이제스트 버퍼에 쌓고, 전송 시각이 되면 하루 한 번 요약을 전송합니다.
+/// 종료 시 남은 보류 알림도 요약으로 전송합니다.
+///
+/// # Arguments
+///
+/// * `state` - 애플리케이션 상태 (체결 이벤트 구독)
+/// * `config` - 다이제스트 설정
+/// * `shutdown` - Graceful shutdown을 위한 CancellationToken
+///
+/// # Returns
+///
+/// 백그라운드 task의 JoinHandle
+pub async fn start_notification_digest(
+    state: Arc<AppState>,
+    config: NotificationDigestConfig,
+    shutdown: CancellationToken,
+) -> tokio::task::JoinHandle<()> {
+    let mut executions = state.executor.read().await.subscribe_events();
+    let notifier = build_notifier();
+
+    tokio::spawn(async move {
+        info!(
+            send_at = %config.settings.send_at,
+            immediate_priority = ?config.settings.immediate_priority,
+            "Notification digest started"
+        );
+        let mut ticker = tokio::time::interval(config.poll_interval);
+        // 시작 시점이 이미 전송 시각 이후라면 오늘 요약은 보낸 것으로 간주
+        let mut last_sent: Option<NaiveDate> = config.settings.due_date(Utc::now(), None);
+
+        loop {
+            tokio::select! {
+                _ = shutdown.cancelled() => {
+                    if let Err(e) = notifier.flush().await {
+                        warn!(error = %e, "Failed to send digest on shutdown");
+                    }
+                    info!("Notification digest stopped");
+                    break;
+                }
+                received = executions.recv() => match received {
+                    Ok(event) => {
+                        if let Some(notification) = fill_notification(&event) {
+                            if let Err(e) = notifier.notify(&notification).await {
+                                warn!(error = %e, "Failed to queue fill notification");
+                            }
+                        }
+                    }
+                    Err(RecvError::Lagged(skipped)) => {
+                        warn!(skipped, "Notification digest lagged behind execution events");
+                    }
+                    Err(RecvError::Closed) => break,
+                },
+                _ = ticker.tick() => {
+                    let Some(date) = config.settings.due_date(Utc::now(), last_sent) else {
+                        continue;
+                    };
+                    let pending = telegram_digest_buffer().len();
+                    match notifier.flush().await {
+                        Ok(()) => info!(date = %date, pending, "Notification digest sent"),
+                        Err(e) => warn!(date = %date, error = %e, "Failed to send notification digest"),
+                    }
+                    last_sent = Some(date);
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+    use trader_core::Side;
+    use uuid::Uuid;
+
+    #[test]
+    fn test_fill_notification() {
+        let event = ExecutionEvent::OrderFilled {
+            order_id: Uuid::nil(),
+            strategy_id: Some("rsi_1".to_string()),
+            ticker: "005930".to_string(),
+            side: Side::Buy,
+            fill_quantity: dec!(10),
+            fill_price: dec!(71000),
+            filled_quantity: dec!(10),
+            order_quantity: dec!(20),
+            complete: false,
+            timestamp: Utc::now(),
+        };
+
+        let notification = fill_notification(&event).unwrap();
+        assert_eq!(notification.priority, NotificationPriority::Low);
+        assert_eq!(notification.event.summary(), "체결 005930 buy 10 @ 71000");
+    }
+}
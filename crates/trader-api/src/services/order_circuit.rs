@@ -0,0 +1,162 @@
+//! 주문 서킷 브레이커 모니터 서비스.
+//!
+//! 실행기의 거래소별 주문 서킷 상태 전이를 구독하여
+//! WebSocket `StrategyUpdate` 메시지로 브로드캐스트합니다.
+//! 주기적으로 서킷을 점검하여 Open 상태의 타임아웃이 지나면
+//! HalfOpen(복구 탐색 가능) 전이가 즉시 알려지도록 합니다.
+//! 정상 상태에서 차단(Closed → Open)되면 긴급 알림(텔레그램, SMS/음성)을 전송합니다.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+use tokio::sync::broadcast::error::RecvError;
+use tokio_util::sync::CancellationToken;
+use tracing::{info, warn};
+use trader_execution::OrderCircuitEvent;
+use trader_notification::{Notification, NotificationEvent, NotificationPriority};
+
+use crate::services::notification_digest::build_notifier;
+use crate::state::AppState;
+use crate::websocket::{ServerMessage, StrategyUpdateData};
+
+/// 서킷 점검 주기.
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// 서킷 전이 이벤트를 WebSocket 전략 업데이트 메시지로 변환.
+///
+/// 전이를 유발한 주문의 전략이 없으면 거래소 단위 메시지(`strategy_id`가 빈 문자열)로 보냅니다.
+pub fn circuit_event_message(event: &OrderCircuitEvent) -> ServerMessage {
+    ServerMessage::StrategyUpdate(StrategyUpdateData {
+        strategy_id: event.strategy_id.clone().unwrap_or_default(),
+        name: event.venue.clone(),
+        running: true,
+        event: format!("order_circuit_{}", event.to),
+        data: serde_json::to_value(event).ok(),
+        timestamp: Utc::now().timestamp_millis(),
+    })
+}
+
+/// 정상 상태에서 차단된 전이를 긴급 알림으로 변환.
+///
+/// HalfOpen 탐색 실패로 다시 차단되는 전이는 반복 알림을 막기 위해 `None`을 반환합니다.
+pub fn circuit_open_notification(event: &OrderCircuitEvent) -> Option<Notification> {
+    if event.from != "closed" || event.to != "open" {
+        return None;
+    }
+
+    Some(
+        Notification::new(NotificationEvent::SystemError {
+            error_code: "ORDER_CIRCUIT_OPEN".to_string(),
+            message: format!(
+                "{} 주문 차단 (대기 {}건): {}",
+                event.venue, event.queued_orders, event.reason
+            ),
+        })
+        .with_priority(NotificationPriority::Critical),
+    )
+}
+
+/// 주문 서킷 모니터 시작.
+///
+/// # Arguments
+///
+/// * `state` - 애플리케이션 상태 (실행기 및 WebSocket 브로드캐스트)
+/// * `shutdown` - Graceful shutdown을 위한 CancellationToken
+///
+/// # Returns
+///
+/// 백그라운드 task의 JoinHandle
+pub async fn start_order_circuit_monitor(
+    state: Arc<AppState>,
+    shutdown: CancellationToken,
+) -> tokio::task::JoinHandle<()> {
+    let guard = Arc::clone(state.executor.read().await.order_circuit());
+    let mut events = guard.subscribe();
+    let notifier = build_notifier();
+
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(POLL_INTERVAL);
+
+        loop {
+            tokio::select! {
+                _ = shutdown.cancelled() => {
+                    info!("Order circuit monitor stopped");
+                    break;
+                }
+                _ = ticker.tick() => guard.poll(),
+                received = events.recv() => match received {
+                    Ok(event) => {
+                        warn!(
+                            venue = %event.venue,
+                            from = %event.from,
+                            to = %event.to,
+                            reason = %event.reason,
+                            "Order circuit state changed"
+                        );
+                        state.broadcast(circuit_event_message(&event));
+                        if let Some(notification) = circuit_open_notification(&event) {
+                            if let Err(e) = notifier.notify(&notification).await {
+                                warn!(error = %e, "Failed to send order circuit alert");
+                            }
+                        }
+                    }
+                    Err(RecvError::Lagged(skipped)) => {
+                        warn!(skipped, "Order circuit monitor lagged behind events");
+                    }
+                    Err(RecvError::Closed) => break,
+                },
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_circuit_event_message() {
+        let event = OrderCircuitEvent {
+            venue: "kis_kr".to_string(),
+            from: "closed".to_string(),
+            to: "open".to_string(),
+            reason: "order rejected: 주문가능금액 부족".to_string(),
+            strategy_id: Some("grid_1".to_string()),
+            queued_orders: 0,
+            timestamp: Utc::now(),
+        };
+
+        let ServerMessage::StrategyUpdate(update) = circuit_event_message(&event) else {
+            panic!("expected strategy update");
+        };
+        assert_eq!(update.strategy_id, "grid_1");
+        assert_eq!(update.name, "kis_kr");
+        assert_eq!(update.event, "order_circuit_open");
+        assert_eq!(update.data.unwrap()["reason"], event.reason);
+    }
+
+    #[test]
+    fn test_circuit_open_notification() {
+        let mut event = OrderCircuitEvent {
+            venue: "kis_kr".to_string(),
+            from: "closed".to_string(),
+            to: "open".to_string(),
+            reason: "인증 실패".to_string(),
+            strategy_id: None,
+            queued_orders: 2,
+            timestamp: Utc::now(),
+        };
+
+        let notification = circuit_open_notification(&event).unwrap();
+        assert_eq!(notification.priority, NotificationPriority::Critical);
+        assert_eq!(
+            notification.event.summary(),
+            "시스템 오류 ORDER_CIRCUIT_OPEN"
+        );
+
+        // 복구 탐색 실패로 인한 재차단은 알리지 않음
+        event.from = "half_open".to_string();
+        assert!(circuit_open_notification(&event).is_none());
+    }
+}
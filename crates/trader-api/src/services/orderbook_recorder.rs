@@ -0,0 +1,220 @@
+//! 호가창 파생 지표 기록 서비스.
+//!
+//! 어그리게이터가 브로드캐스트하는 `OrderBookMetrics` 메시지(불균형, 마이크로프라이스,
+//! 깊이 가중 스프레드)를 심볼별로 샘플링하여 `orderbook_metrics` 테이블에 일괄 저장합니다.
+//! 체결 타이밍 개선 연구용 원자료이며, 기본적으로 비활성화되어 있습니다.
+//!
+//! # 환경 변수
+//!
+//! - `ORDERBOOK_RECORD_ENABLED`: `true`일 때만 기록 (기본 false)
+//! - `ORDERBOOK_RECORD_INTERVAL_MS`: 심볼별 최소 기록 간격 (기본값: 1000, 0이면 모두 기록)
+//! - `ORDERBOOK_RECORD_SYMBOLS`: 기록할 심볼 (쉼표 구분, 비우면 전체)
+
+use std::collections::{HashMap, HashSet};
+use std::time::{Duration, Instant};
+
+use sqlx::PgPool;
+use tokio::sync::broadcast::error::RecvError;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, info, warn};
+use trader_core::OrderBookMetrics;
+
+use crate::repository::OrderBookMetricsRepository;
+use crate::websocket::{ServerMessage, SharedSubscriptionManager};
+
+/// 버퍼 저장 주기.
+const FLUSH_INTERVAL: Duration = Duration::from_secs(5);
+
+/// 이 개수만큼 쌓이면 저장 주기를 기다리지 않고 저장.
+const MAX_BUFFERED_SAMPLES: usize = 1000;
+
+/// 호가 지표 기록 설정.
+#[derive(Debug, Clone)]
+pub struct OrderBookRecorderConfig {
+    /// 심볼별 최소 기록 간격
+    pub sample_interval: Duration,
+    /// 기록 대상 심볼 (대문자, 비어 있으면 전체)
+    pub symbols: HashSet<String>,
+}
+
+impl OrderBookRecorderConfig {
+    /// 환경 변수에서 설정을 읽습니다.
+    ///
+    /// `ORDERBOOK_RECORD_ENABLED`가 `true`가 아니면 `None`을 반환합니다.
+    pub fn from_env() -> Option<Self> {
+        let enabled = std::env::var("ORDERBOOK_RECORD_ENABLED")
+            .map(|v| v.to_lowercase() == "true")
+            .unwrap_or(false);
+        if !enabled {
+            return None;
+        }
+
+        let sample_interval = std::env::var("ORDERBOOK_RECORD_INTERVAL_MS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .map(Duration::from_millis)
+            .unwrap_or(Duration::from_secs(1));
+
+        let symbols = std::env::var("ORDERBOOK_RECORD_SYMBOLS")
+            .map(|v| parse_symbols(&v))
+            .unwrap_or_default();
+
+        Some(Self {
+            sample_interval,
+            symbols,
+        })
+    }
+}
+
+/// 쉼표로 구분된 심볼 목록을 대문자 집합으로 변환합니다.
+fn parse_symbols(value: &str) -> HashSet<String> {
+    value
+        .split(',')
+        .map(|s| s.trim().to_uppercase())
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
+/// 심볼별 샘플링 (대상 필터 + 최소 간격).
+struct MetricsSampler {
+    config: OrderBookRecorderConfig,
+    last_sample: HashMap<String, Instant>,
+}
+
+impl MetricsSampler {
+    fn new(config: OrderBookRecorderConfig) -> Self {
+        Self {
+            config,
+            last_sample: HashMap::new(),
+        }
+    }
+
+    /// 이번 샘플을 기록할지 판단합니다.
+    fn accept(&mut self, symbol: &str, now: Instant) -> bool {
+        let symbol = symbol.to_uppercase();
+        if !self.config.symbols.is_empty() && !self.config.symbols.contains(&symbol) {
+            return false;
+        }
+        match self.last_sample.get(&symbol) {
+            Some(last) if now.duration_since(*last) < self.config.sample_interval => false,
+            _ => {
+                self.last_sample.insert(symbol, now);
+                true
+            }
+        }
+    }
+}
+
+/// 버퍼에 쌓인 샘플을 저장합니다. 실패 시 샘플은 버립니다 (연구용 데이터).
+async fn flush(pool: &PgPool, buffer: &mut Vec<OrderBookMetrics>) {
+    if buffer.is_empty() {
+        return;
+    }
+    match OrderBookMetricsRepository::insert_batch(pool, buffer).await {
+        Ok(inserted) => debug!(inserted, "Orderbook metrics recorded"),
+        Err(e) => warn!(samples = buffer.len(), error = %e, "Failed to record orderbook metrics"),
+    }
+    buffer.clear();
+}
+
+/// 호가 지표 기록 서비스 시작.
+///
+/// # Arguments
+///
+/// * `pool` - 데이터베이스 연결 풀
+/// * `subscriptions` - 시세가 브로드캐스트되는 WebSocket 구독 관리자
+/// * `config` - 기록 설정
+/// * `shutdown` - Graceful shutdown을 위한 CancellationToken
+///
+/// # Returns
+///
+/// 백그라운드 task의 JoinHandle
+pub fn start_orderbook_recorder(
+    pool: PgPool,
+    subscriptions: SharedSubscriptionManager,
+    config: OrderBookRecorderConfig,
+    shutdown: CancellationToken,
+) -> tokio::task::JoinHandle<()> {
+    let mut messages = subscriptions.receiver();
+
+    tokio::spawn(async move {
+        info!(
+            interval_ms = config.sample_interval.as_millis() as u64,
+            symbols = config.symbols.len(),
+            "Orderbook metrics recorder started"
+        );
+        let mut sampler = MetricsSampler::new(config);
+        let mut buffer: Vec<OrderBookMetrics> = Vec::new();
+        let mut flush_tick = tokio::time::interval(FLUSH_INTERVAL);
+
+        loop {
+            let message = tokio::select! {
+                _ = shutdown.cancelled() => {
+                    flush(&pool, &mut buffer).await;
+                    info!("Orderbook metrics recorder stopped");
+                    break;
+                }
+                _ = flush_tick.tick() => {
+                    flush(&pool, &mut buffer).await;
+                    continue;
+                }
+                received = messages.recv() => match received {
+                    Ok(message) => message,
+                    Err(RecvError::Lagged(skipped)) => {
+                        debug!(skipped, "Orderbook metrics recorder lagged behind broadcasts");
+                        continue;
+                    }
+                    Err(RecvError::Closed) => {
+                        flush(&pool, &mut buffer).await;
+                        break;
+                    }
+                },
+            };
+
+            if let ServerMessage::OrderBookMetrics(data) = message {
+                if sampler.accept(&data.symbol, Instant::now()) {
+                    buffer.push(data.to_metrics());
+                    if buffer.len() >= MAX_BUFFERED_SAMPLES {
+                        flush(&pool, &mut buffer).await;
+                    }
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(symbols: &str) -> OrderBookRecorderConfig {
+        OrderBookRecorderConfig {
+            sample_interval: Duration::from_secs(1),
+            symbols: parse_symbols(symbols),
+        }
+    }
+
+    #[test]
+    fn test_parse_symbols() {
+        let symbols = parse_symbols(" 005930, btc/usdt ,,");
+        assert_eq!(symbols.len(), 2);
+        assert!(symbols.contains("005930"));
+        assert!(symbols.contains("BTC/USDT"));
+        assert!(parse_symbols("").is_empty());
+    }
+
+    #[test]
+    fn test_sampler_interval_and_filter() {
+        let start = Instant::now();
+        let mut sampler = MetricsSampler::new(config("005930"));
+
+        assert!(sampler.accept("005930", start));
+        assert!(!sampler.accept("005930", start + Duration::from_millis(500)));
+        assert!(sampler.accept("005930", start + Duration::from_millis(1000)));
+        assert!(!sampler.accept("000660", start));
+
+        let mut all = MetricsSampler::new(config(""));
+        assert!(all.accept("btc/usdt", start));
+        assert!(!all.accept("BTC/USDT", start));
+    }
+}
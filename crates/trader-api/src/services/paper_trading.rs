@@ -0,0 +1,619 @@
+//! 실시간 시세 기반 모의투자(paper trading) 서비스.
+//!
+//! 모의투자 모드 전략(`strategies.paper_trading`)은 실거래 전략과 같은 실시간 시세
+//! (KIS/Binance 어그리게이터가 브로드캐스트하는 틱과 캔들)를 받지만, 신호는 실행기 대신
+//! 전략별 `SimulatedExchange` 가상 계좌로 전달되어 수수료와 슬리피지를 반영한 가격에
+//! 체결됩니다. 체결 내역은 `paper_trades`에 저장되어 포워드 테스트 결과로 조회합니다.
+//!
+//! 가상 계좌 잔고는 메모리에만 유지되며, 서버를 재시작하면 초기 자본으로 다시 시작합니다.
+//! 시뮬레이터 관례에 따라 현금은 `USDT` 자산으로 관리하므로 (통화 없는 종목 코드는 `USDT`
+//! 호가로 간주) `BTC/KRW`처럼 다른 호가 통화를 쓰는 종목은 잔고 부족으로 체결되지 않습니다.
+//!
+//! # 환경 변수
+//!
+//! - `PAPER_TRADING_INITIAL_CASH`: 전략별 가상 계좌 초기 자본 (기본값: 10,000,000)
+//! - `PAPER_TRADING_POSITION_PCT`: 진입 1회당 평가액 대비 투자 비율 (기본값: 0.2)
+//! - `PAPER_TRADING_FEE_BPS`: 체결 수수료 (bp, 기본값: 5)
+//! - `PAPER_TRADING_SLIPPAGE_BPS`: 시장가 주문 슬리피지 (bp, 기본값: 5)
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use chrono::{DateTime, TimeZone, Utc};
+use rust_decimal::prelude::FromPrimitive;
+use rust_decimal::{Decimal, RoundingStrategy};
+use serde::Serialize;
+use tokio::sync::broadcast::error::RecvError;
+use tokio::sync::RwLock;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, info, warn};
+use trader_core::{Kline, MarketData, OrderRequest, Side, Signal, SignalType, Timeframe};
+use trader_exchange::{Exchange, FillType, SimulatedConfig, SimulatedExchange};
+use uuid::Uuid;
+
+use crate::repository::{PaperTradeInput, PaperTradeRepository};
+use crate::state::AppState;
+use crate::websocket::{ServerMessage, SharedSubscriptionManager};
+
+/// 가상 계좌 현금 자산 (시뮬레이터 기본 호가 통화).
+pub const PAPER_CASH_ASSET: &str = "USDT";
+
+/// 모의투자 가상 계좌 설정.
+#[derive(Debug, Clone)]
+pub struct PaperTradingConfig {
+    /// 전략별 초기 자본
+    pub initial_cash: Decimal,
+    /// 진입 1회당 평가액 대비 투자 비율 (0 ~ 1)
+    pub position_size_pct: Decimal,
+    /// 체결 수수료 (bp)
+    pub fee_bps: Decimal,
+    /// 시장가 주문 슬리피지 (bp)
+    pub slippage_bps: Decimal,
+}
+
+impl Default for PaperTradingConfig {
+    fn default() -> Self {
+        Self {
+            initial_cash: Decimal::from(10_000_000),
+            position_size_pct: Decimal::new(2, 1),
+            fee_bps: Decimal::from(5),
+            slippage_bps: Decimal::from(5),
+        }
+    }
+}
+
+impl PaperTradingConfig {
+    /// 환경변수에서 설정 로드 (잘못된 값은 기본값 사용).
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+        let decimal = |key: &str, default: Decimal| {
+            std::env::var(key)
+                .ok()
+                .and_then(|v| v.trim().parse::<Decimal>().ok())
+                .filter(|v| *v >= Decimal::ZERO)
+                .unwrap_or(default)
+        };
+
+        Self {
+            initial_cash: decimal("PAPER_TRADING_INITIAL_CASH", defaults.initial_cash),
+            position_size_pct: decimal("PAPER_TRADING_POSITION_PCT", defaults.position_size_pct)
+                .min(Decimal::ONE),
+            fee_bps: decimal("PAPER_TRADING_FEE_BPS", defaults.fee_bps),
+            slippage_bps: decimal("PAPER_TRADING_SLIPPAGE_BPS", defaults.slippage_bps),
+        }
+    }
+
+    fn fee_rate(&self) -> Decimal {
+        self.fee_bps / Decimal::from(10_000)
+    }
+
+    fn slippage_rate(&self) -> Decimal {
+        self.slippage_bps / Decimal::from(10_000)
+    }
+}
+
+/// 가상 계좌 주문의 원 신호 정보.
+#[derive(Debug, Clone)]
+struct PaperOrder {
+    signal_id: Uuid,
+    ticker: String,
+    side: Side,
+    signal_type: SignalType,
+}
+
+/// 전략 하나의 가상 계좌.
+struct PaperAccount {
+    exchange: SimulatedExchange,
+    /// 주문 ID → 원 신호
+    orders: HashMap<String, PaperOrder>,
+    /// 체결 내역으로 변환한 주문 이력 길이
+    history_cursor: usize,
+    /// 종목별 최근 가격
+    last_prices: HashMap<String, Decimal>,
+    started_at: DateTime<Utc>,
+    last_data_at: Option<DateTime<Utc>>,
+    trades: u64,
+    last_error: Option<String>,
+}
+
+impl PaperAccount {
+    fn new(config: &PaperTradingConfig) -> Self {
+        let exchange_config = SimulatedConfig {
+            initial_balances: HashMap::from([(PAPER_CASH_ASSET.to_string(), config.initial_cash)]),
+            ..SimulatedConfig::default()
+        }
+        .with_fee_rate(config.fee_rate())
+        .with_slippage_rate(config.slippage_rate());
+
+        Self {
+            exchange: SimulatedExchange::new(exchange_config),
+            orders: HashMap::new(),
+            history_cursor: 0,
+            last_prices: HashMap::new(),
+            started_at: Utc::now(),
+            last_data_at: None,
+            trades: 0,
+            last_error: None,
+        }
+    }
+
+    /// 자산 보유 수량 (미체결 주문에 잠긴 수량 포함).
+    async fn holding(&self, asset: &str) -> Decimal {
+        self.exchange
+            .get_balance(asset)
+            .await
+            .map(|balance| balance.free + balance.locked)
+            .unwrap_or(Decimal::ZERO)
+    }
+
+    /// 현재 상태 스냅샷 (현금 + 보유 종목 최근 가격 평가).
+    async fn snapshot(&self) -> PaperAccountSnapshot {
+        let cash = self.holding(PAPER_CASH_ASSET).await;
+        let mut positions = Vec::new();
+        let mut seen = HashSet::new();
+        for (ticker, price) in &self.last_prices {
+            let base = SimulatedExchange::parse_base(ticker);
+            if !seen.insert(base.clone()) {
+                continue;
+            }
+            let quantity = self.holding(&base).await;
+            if quantity > Decimal::ZERO {
+                positions.push(PaperHolding {
+                    ticker: ticker.clone(),
+                    quantity,
+                    last_price: *price,
+                    market_value: quantity * price,
+                });
+            }
+        }
+        positions.sort_by(|a, b| a.ticker.cmp(&b.ticker));
+
+        PaperAccountSnapshot {
+            equity: cash + positions.iter().map(|p| p.market_value).sum::<Decimal>(),
+            cash,
+            positions,
+            trades: self.trades,
+            started_at: self.started_at,
+            last_data_at: self.last_data_at,
+            last_error: self.last_error.clone(),
+        }
+    }
+
+    /// 새 주문 이력을 체결 내역으로 변환.
+    async fn collect_fills(&mut self, strategy_id: &str) -> Vec<PaperTradeInput> {
+        let history = self.exchange.get_order_history().await;
+        let new_matches = history.get(self.history_cursor..).unwrap_or_default();
+        self.history_cursor = history.len();
+
+        let mut fills = Vec::new();
+        for order_match in new_matches {
+            if order_match.fill_type == FillType::None
+                || order_match.filled_quantity <= Decimal::ZERO
+            {
+                continue;
+            }
+            let Some(order) = self.orders.get(&order_match.order_id) else {
+                continue;
+            };
+            self.trades += 1;
+            fills.push(PaperTradeInput {
+                strategy_id: strategy_id.to_string(),
+                order_id: order_match.order_id.clone(),
+                signal_id: Some(order.signal_id),
+                ticker: order.ticker.clone(),
+                side: order.side.to_string().to_lowercase(),
+                signal_type: order.signal_type.to_string(),
+                quantity: order_match.filled_quantity,
+                price: order_match.fill_price,
+                commission: order_match.commission,
+                executed_at: order_match.timestamp,
+            });
+        }
+        fills
+    }
+}
+
+/// 가상 계좌 보유 종목.
+#[derive(Debug, Clone, Serialize)]
+pub struct PaperHolding {
+    pub ticker: String,
+    pub quantity: Decimal,
+    pub last_price: Decimal,
+    pub market_value: Decimal,
+}
+
+/// 가상 계좌 상태.
+#[derive(Debug, Clone, Serialize)]
+pub struct PaperAccountSnapshot {
+    /// 현금 잔고 (미체결 주문에 잠긴 금액 포함)
+    pub cash: Decimal,
+    /// 평가액 (현금 + 보유 종목 최근 가격 평가)
+    pub equity: Decimal,
+    pub positions: Vec<PaperHolding>,
+    /// 체결 건수
+    pub trades: u64,
+    /// 가상 계좌 시작 시각 (서버 재시작 시 초기화)
+    pub started_at: DateTime<Utc>,
+    /// 마지막으로 반영한 시세 시각
+    pub last_data_at: Option<DateTime<Utc>>,
+    /// 마지막 주문 거부 사유
+    pub last_error: Option<String>,
+}
+
+/// 전략별 모의투자 가상 계좌 관리자.
+pub struct PaperTradingDesk {
+    config: PaperTradingConfig,
+    accounts: RwLock<HashMap<String, PaperAccount>>,
+}
+
+impl PaperTradingDesk {
+    /// 새 관리자 생성.
+    pub fn new(config: PaperTradingConfig) -> Self {
+        Self {
+            config,
+            accounts: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// 가상 계좌 설정.
+    pub fn config(&self) -> &PaperTradingConfig {
+        &self.config
+    }
+
+    /// 전략 가상 계좌 개설 (이미 있으면 유지). 새로 개설했으면 `true`.
+    pub async fn open(&self, strategy_id: &str) -> bool {
+        let mut accounts = self.accounts.write().await;
+        if accounts.contains_key(strategy_id) {
+            return false;
+        }
+        accounts.insert(strategy_id.to_string(), PaperAccount::new(&self.config));
+        true
+    }
+
+    /// 전략 가상 계좌 폐쇄. 계좌가 있었으면 `true`.
+    pub async fn close(&self, strategy_id: &str) -> bool {
+        self.accounts.write().await.remove(strategy_id).is_some()
+    }
+
+    /// 개설된 가상 계좌가 없는지 확인.
+    pub async fn is_empty(&self) -> bool {
+        self.accounts.read().await.is_empty()
+    }
+
+    /// 전략 가상 계좌 상태.
+    pub async fn snapshot(&self, strategy_id: &str) -> Option<PaperAccountSnapshot> {
+        let accounts = self.accounts.read().await;
+        match accounts.get(strategy_id) {
+            Some(account) => Some(account.snapshot().await),
+            None => None,
+        }
+    }
+
+    /// 시세를 모든 가상 계좌에 반영하고 대기 주문 체결 내역을 반환.
+    pub async fn on_market(&self, kline: &Kline) -> Vec<PaperTradeInput> {
+        let mut accounts = self.accounts.write().await;
+        let mut fills = Vec::new();
+        for (strategy_id, account) in accounts.iter_mut() {
+            account
+                .last_prices
+                .insert(kline.ticker.clone(), kline.close);
+            account.last_data_at = Some(kline.close_time);
+            account.exchange.feed_kline(kline.clone()).await;
+            fills.extend(account.collect_fills(strategy_id).await);
+        }
+        fills
+    }
+
+    /// 신호를 전략 가상 계좌에서 시장가로 체결하고 체결 내역을 반환.
+    ///
+    /// 가상 계좌가 없거나 주문이 거부되면 빈 목록을 반환합니다 (거부 사유는 스냅샷에 기록).
+    pub async fn execute(&self, strategy_id: &str, signal: &Signal) -> Vec<PaperTradeInput> {
+        let mut accounts = self.accounts.write().await;
+        let Some(account) = accounts.get_mut(strategy_id) else {
+            return Vec::new();
+        };
+
+        let Some(price) = account
+            .last_prices
+            .get(&signal.ticker)
+            .copied()
+            .or(signal.suggested_price)
+            .filter(|p| *p > Decimal::ZERO)
+        else {
+            return Vec::new();
+        };
+        let snapshot = account.snapshot().await;
+        let held = account
+            .holding(&SimulatedExchange::parse_base(&signal.ticker))
+            .await;
+        let Some(request) = order_for_signal(
+            signal,
+            price,
+            snapshot.equity,
+            snapshot.cash,
+            held,
+            &self.config,
+        ) else {
+            return Vec::new();
+        };
+
+        match account.exchange.place_order(&request).await {
+            Ok(order_id) => {
+                account.orders.insert(
+                    order_id,
+                    PaperOrder {
+                        signal_id: signal.id,
+                        ticker: signal.ticker.clone(),
+                        side: request.side,
+                        signal_type: signal.signal_type,
+                    },
+                );
+                account.last_error = None;
+            }
+            Err(e) => {
+                debug!(
+                    strategy_id,
+                    ticker = %signal.ticker,
+                    error = %e,
+                    "Paper order rejected"
+                );
+                account.last_error = Some(e.to_string());
+            }
+        }
+        account.collect_fills(strategy_id).await
+    }
+}
+
+/// 주문 수량 소수 자릿수 (통화 쌍 종목은 8자리, 주식은 1주 단위).
+fn quantity_scale(ticker: &str) -> u32 {
+    if ticker.contains('/') {
+        8
+    } else {
+        0
+    }
+}
+
+/// 신호를 시장가 주문으로 변환 (경쟁 가상 계좌 `PaperBook`과 같은 수량 규칙).
+///
+/// - 매수 진입/추가: 평가액 × 투자 비율 × 신호 강도 (현금 한도, 수수료/슬리피지 여유 포함)
+/// - 청산 또는 매도 진입: 보유 수량 전량
+/// - 부분 청산: 스케일 아웃 지시 수량 (없으면 보유 수량의 절반)
+fn order_for_signal(
+    signal: &Signal,
+    price: Decimal,
+    equity: Decimal,
+    cash: Decimal,
+    held: Decimal,
+    config: &PaperTradingConfig,
+) -> Option<OrderRequest> {
+    let scale = quantity_scale(&signal.ticker);
+    let round =
+        |quantity: Decimal| quantity.round_dp_with_strategy(scale, RoundingStrategy::ToZero);
+
+    let (side, quantity) = match (signal.signal_type, signal.side) {
+        (SignalType::Entry | SignalType::AddToPosition | SignalType::Scale, Side::Buy) => {
+            let strength = Decimal::from_f64(signal.strength.clamp(0.0, 1.0))
+                .filter(|s| *s > Decimal::ZERO)
+                .unwrap_or(Decimal::ONE);
+            let budget = (equity * config.position_size_pct * strength).min(cash);
+            let unit_cost = price * (Decimal::ONE + config.fee_rate() + config.slippage_rate());
+            (Side::Buy, round(budget / unit_cost))
+        }
+        (SignalType::Exit, _) | (SignalType::Entry | SignalType::Scale, Side::Sell) => {
+            (Side::Sell, held)
+        }
+        (SignalType::ReducePosition, _) => {
+            let quantity = match signal.scale_out {
+                Some(scale_out) => scale_out.close_quantity(held, price, equity),
+                None => held / Decimal::TWO,
+            };
+            (Side::Sell, round(quantity.min(held)))
+        }
+        _ => return None,
+    };
+
+    if quantity <= Decimal::ZERO {
+        return None;
+    }
+    Some(match side {
+        Side::Buy => OrderRequest::market_buy(signal.ticker.clone(), quantity),
+        Side::Sell => OrderRequest::market_sell(signal.ticker.clone(), quantity),
+    })
+}
+
+/// 브로드캐스트 메시지를 가상 계좌용 캔들로 변환.
+///
+/// 틱은 시가=고가=저가=종가인 캔들로 변환합니다. 두 번째 값은 전략에 공급할 마감 캔들 여부입니다.
+fn kline_from_message(message: &ServerMessage) -> Option<(Kline, bool)> {
+    match message {
+        ServerMessage::Ticker(tick) if tick.price > Decimal::ZERO => {
+            let at = Utc.timestamp_millis_opt(tick.timestamp).single()?;
+            let kline = Kline::new(
+                tick.symbol.clone(),
+                Timeframe::M1,
+                at,
+                tick.price,
+                tick.price,
+                tick.price,
+                tick.price,
+                Decimal::ZERO,
+                at,
+            );
+            Some((kline, false))
+        }
+        ServerMessage::Kline(candle) if candle.close > Decimal::ZERO => {
+            let timeframe = candle.timeframe.parse::<Timeframe>().ok()?;
+            let kline = Kline::new(
+                candle.symbol.clone(),
+                timeframe,
+                Utc.timestamp_millis_opt(candle.open_time).single()?,
+                candle.open,
+                candle.high,
+                candle.low,
+                candle.close,
+                candle.volume,
+                Utc.timestamp_millis_opt(candle.close_time).single()?,
+            );
+            Some((kline, candle.is_closed))
+        }
+        _ => None,
+    }
+}
+
+/// 체결 내역 저장 (DB가 없으면 생략).
+pub(crate) async fn persist_fills(state: &AppState, fills: &[PaperTradeInput]) {
+    let Some(pool) = &state.db_pool else {
+        return;
+    };
+    for fill in fills {
+        if let Err(e) = PaperTradeRepository::insert(pool, fill).await {
+            warn!(
+                strategy_id = %fill.strategy_id,
+                order_id = %fill.order_id,
+                error = %e,
+                "Failed to save paper trade"
+            );
+        }
+    }
+}
+
+/// 모의투자 서비스 시작.
+///
+/// 실시간 시세를 가상 계좌에 반영한 뒤, 마감 캔들을 모의투자 전략에 공급하고
+/// 생성된 신호를 전략별 가상 계좌에서 체결합니다.
+///
+/// # Arguments
+///
+/// * `state` - 애플리케이션 상태 (전략 엔진, 가상 계좌 관리자, DB)
+/// * `subscriptions` - 시세가 브로드캐스트되는 WebSocket 구독 관리자
+/// * `shutdown` - Graceful shutdown을 위한 CancellationToken
+///
+/// # Returns
+///
+/// 백그라운드 task의 JoinHandle
+pub fn start_paper_trading(
+    state: Arc<AppState>,
+    subscriptions: SharedSubscriptionManager,
+    shutdown: CancellationToken,
+) -> tokio::task::JoinHandle<()> {
+    let mut messages = subscriptions.receiver();
+
+    tokio::spawn(async move {
+        info!(
+            initial_cash = %state.paper_trading.config().initial_cash,
+            "Paper trading service started"
+        );
+
+        loop {
+            let message = tokio::select! {
+                _ = shutdown.cancelled() => {
+                    info!("Paper trading service stopped");
+                    break;
+                }
+                received = messages.recv() => match received {
+                    Ok(message) => message,
+                    Err(RecvError::Lagged(skipped)) => {
+                        debug!(skipped, "Paper trading service lagged behind broadcasts");
+                        continue;
+                    }
+                    Err(RecvError::Closed) => break,
+                },
+            };
+
+            let Some((kline, is_closed)) = kline_from_message(&message) else {
+                continue;
+            };
+            let desk = &state.paper_trading;
+            if desk.is_empty().await {
+                continue;
+            }
+
+            // 대기 주문을 먼저 새 시세로 매칭한 뒤 신호를 같은 시세로 체결
+            let mut fills = desk.on_market(&kline).await;
+            if is_closed {
+                let data = MarketData::from_kline("paper", kline);
+                let signals = {
+                    let engine = state.strategy_engine.read().await;
+                    engine.process_paper_trading(&data).await
+                };
+                for (strategy_id, signal) in &signals {
+                    fills.extend(desk.execute(strategy_id, signal).await);
+                }
+            }
+
+            if !fills.is_empty() {
+                debug!(count = fills.len(), "Paper trades filled");
+                persist_fills(&state, &fills).await;
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn candle(ticker: &str, close: Decimal) -> Kline {
+        let at = Utc.with_ymd_and_hms(2026, 3, 10, 9, 0, 0).unwrap();
+        Kline::new(
+            ticker.to_string(),
+            Timeframe::M1,
+            at,
+            close,
+            close,
+            close,
+            close,
+            dec!(100),
+            at,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_desk_fills_signals_at_live_price() {
+        let desk = PaperTradingDesk::new(PaperTradingConfig {
+            initial_cash: dec!(1000000),
+            position_size_pct: dec!(0.5),
+            fee_bps: Decimal::ZERO,
+            slippage_bps: Decimal::ZERO,
+        });
+        assert!(desk.open("rsi_1").await);
+        assert!(!desk.open("rsi_1").await);
+
+        // 시세 전 신호는 체결 가격이 없어 무시
+        let entry = Signal::entry("rsi_1", "005930".to_string(), Side::Buy);
+        assert!(desk.execute("rsi_1", &entry).await.is_empty());
+
+        assert!(desk
+            .on_market(&candle("005930", dec!(70000)))
+            .await
+            .is_empty());
+        let fills = desk.execute("rsi_1", &entry).await;
+        assert_eq!(fills.len(), 1);
+        // 1,000,000 × 50% / 70,000 = 7.14 → 7주
+        assert_eq!(fills[0].quantity, dec!(7));
+        assert_eq!(fills[0].price, dec!(70000));
+        assert_eq!(fills[0].side, "buy");
+        assert_eq!(fills[0].signal_id, Some(entry.id));
+
+        desk.on_market(&candle("005930", dec!(80000))).await;
+        let snapshot = desk.snapshot("rsi_1").await.unwrap();
+        assert_eq!(snapshot.cash, dec!(510000));
+        assert_eq!(snapshot.equity, dec!(1070000));
+        assert_eq!(snapshot.positions.len(), 1);
+
+        let exit = Signal::exit("rsi_1", "005930".to_string(), Side::Sell);
+        let fills = desk.execute("rsi_1", &exit).await;
+        assert_eq!(fills.len(), 1);
+        assert_eq!(fills[0].side, "sell");
+        assert_eq!(fills[0].quantity, dec!(7));
+
+        let snapshot = desk.snapshot("rsi_1").await.unwrap();
+        assert_eq!(snapshot.cash, dec!(1070000));
+        assert!(snapshot.positions.is_empty());
+        assert_eq!(snapshot.trades, 2);
+
+        assert!(desk.close("rsi_1").await);
+        assert!(desk.snapshot("rsi_1").await.is_none());
+    }
+}
@@ -0,0 +1,184 @@
+//! 추천 검증(Reality Check) 정기 계산.
+//!
+//! 매일 장 마감 후(KST) 직전 추천일의 스냅샷과 당일 종가를 비교해
+//! `RealityCheckRepository::calculate_reality_check()`를 실행합니다.
+//! 계산은 DB 함수에서 upsert되므로 같은 날 다시 실행해도 결과가 중복되지 않습니다.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveTime, Utc};
+use sqlx::PgPool;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, info, warn};
+
+use crate::repository::RealityCheckRepository;
+use crate::services::task_scheduler::TaskTrigger;
+use crate::state::AppState;
+
+/// 추천 검증 스케줄러 설정.
+#[derive(Debug, Clone)]
+pub struct RealityCheckSchedulerConfig {
+    /// 실행 대상 확인 주기
+    pub poll_interval: Duration,
+    /// 이 시각(KST) 이후에만 당일 검증 실행
+    pub run_after: NaiveTime,
+}
+
+impl RealityCheckSchedulerConfig {
+    /// 환경변수에서 설정 로드.
+    ///
+    /// `REALITY_CHECK_SCHEDULE_ENABLED=false`이면 `None`을 반환합니다.
+    pub fn from_env() -> Option<Self> {
+        let enabled = std::env::var("REALITY_CHECK_SCHEDULE_ENABLED")
+            .map(|v| v.to_lowercase() != "false")
+            .unwrap_or(true);
+        if !enabled {
+            return None;
+        }
+
+        let poll_interval = std::env::var("REALITY_CHECK_POLL_SECS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .filter(|secs| *secs > 0)
+            .map(Duration::from_secs)
+            .unwrap_or(Duration::from_secs(3600));
+
+        let run_after = std::env::var("REALITY_CHECK_RUN_AFTER")
+            .ok()
+            .and_then(|v| NaiveTime::parse_from_str(&v, "%H:%M").ok())
+            .unwrap_or_else(|| NaiveTime::from_hms_opt(16, 0, 0).expect("valid time"));
+
+        Some(Self {
+            poll_interval,
+            run_after,
+        })
+    }
+}
+
+fn kst(now: DateTime<Utc>) -> DateTime<FixedOffset> {
+    now.with_timezone(&FixedOffset::east_opt(9 * 3600).expect("valid offset"))
+}
+
+fn kst_today(now: DateTime<Utc>) -> NaiveDate {
+    kst(now).date_naive()
+}
+
+/// 오늘(KST) 검증을 실행해야 하면 검증일을 반환.
+fn due_check_date(
+    now: DateTime<Utc>,
+    run_after: NaiveTime,
+    last_checked: Option<NaiveDate>,
+) -> Option<NaiveDate> {
+    let local = kst(now);
+    let today = local.date_naive();
+
+    if local.time() < run_after || last_checked.is_some_and(|date| date >= today) {
+        return None;
+    }
+    Some(today)
+}
+
+/// 검증일 하루 계산. 직전 추천 스냅샷이 없으면 `Ok(None)`.
+async fn run_once(pool: &PgPool, check_date: NaiveDate) -> Result<Option<usize>, sqlx::Error> {
+    let Some(recommend_date) =
+        RealityCheckRepository::latest_snapshot_date_before(pool, check_date).await?
+    else {
+        return Ok(None);
+    };
+
+    let results =
+        RealityCheckRepository::calculate_reality_check(pool, recommend_date, check_date).await?;
+    Ok(Some(results.len()))
+}
+
+/// 추천 검증 스케줄러 시작.
+///
+/// # Arguments
+///
+/// * `state` - 애플리케이션 상태 (작업 레지스트리)
+/// * `pool` - 데이터베이스 연결 풀
+/// * `config` - 스케줄러 설정
+/// * `shutdown` - Graceful shutdown을 위한 CancellationToken
+///
+/// # Returns
+///
+/// 백그라운드 task의 JoinHandle
+pub fn start_reality_check_scheduler(
+    state: Arc<AppState>,
+    pool: PgPool,
+    config: RealityCheckSchedulerConfig,
+    shutdown: CancellationToken,
+) -> tokio::task::JoinHandle<()> {
+    let task = state.tasks.register(
+        "reality_check",
+        "전일 추천 종목의 익일 실제 성과 계산 (장 마감 후 하루 한 번)",
+        config.poll_interval,
+    );
+
+    tokio::spawn(async move {
+        info!(
+            poll_secs = config.poll_interval.as_secs(),
+            run_after = %config.run_after,
+            "Reality check scheduler started"
+        );
+        let mut ticker = tokio::time::interval(config.poll_interval);
+        let mut last_checked: Option<NaiveDate> = None;
+
+        loop {
+            let Some(trigger) = task.wait_next(&mut ticker, &shutdown).await else {
+                info!("Reality check scheduler stopped");
+                break;
+            };
+
+            // 수동 실행은 시각/중복 조건 없이 오늘 검증을 다시 계산
+            let check_date = match trigger {
+                TaskTrigger::Manual => Some(kst_today(Utc::now())),
+                TaskTrigger::Schedule => due_check_date(Utc::now(), config.run_after, last_checked),
+            };
+            let Some(check_date) = check_date else {
+                continue;
+            };
+            let mut run = task.begin();
+
+            match run_once(&pool, check_date).await {
+                Ok(Some(count)) => {
+                    info!(check_date = %check_date, count, "Reality check calculated");
+                    last_checked = Some(check_date);
+                }
+                Ok(None) => {
+                    debug!(check_date = %check_date, "No recommendation snapshot to check");
+                    last_checked = Some(check_date);
+                }
+                Err(e) => {
+                    warn!(check_date = %check_date, error = %e, "Reality check calculation failed");
+                    run.fail(e);
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_due_check_date_after_close_once_per_day() {
+        let run_after = NaiveTime::from_hms_opt(16, 0, 0).unwrap();
+        // 2026-03-10 06:00 UTC = 15:00 KST (장 마감 전)
+        let before = Utc.with_ymd_and_hms(2026, 3, 10, 6, 0, 0).unwrap();
+        assert_eq!(due_check_date(before, run_after, None), None);
+
+        // 2026-03-10 08:00 UTC = 17:00 KST
+        let after = Utc.with_ymd_and_hms(2026, 3, 10, 8, 0, 0).unwrap();
+        let today = NaiveDate::from_ymd_opt(2026, 3, 10).unwrap();
+        assert_eq!(due_check_date(after, run_after, None), Some(today));
+        assert_eq!(due_check_date(after, run_after, Some(today)), None);
+
+        // 2026-03-10 16:00 UTC = 2026-03-11 01:00 KST (익일, 아직 마감 전)
+        let next_night = Utc.with_ymd_and_hms(2026, 3, 10, 16, 0, 0).unwrap();
+        assert_eq!(due_check_date(next_night, run_after, Some(today)), None);
+    }
+}
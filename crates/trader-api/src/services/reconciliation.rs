@@ -0,0 +1,711 @@
+//! 사후 체결 대사 서비스.
+//!
+//! 주기적으로 브로커(`ExchangeProvider`)의 보유 포지션, 미체결 주문, 당일 체결 내역, 예수금을
+//! 조회해 실행기의 `PositionTracker`와 DB `positions`/`orders` 테이블과 비교합니다.
+//! 불일치 집합이 바뀔 때만 `trader-notification`으로 알리며,
+//! `RECONCILIATION_AUTO_CORRECT=true`이면 로컬 상태를 브로커 기준으로 보정하고
+//! 보정 내역을 `audit_logs`에 남깁니다.
+//!
+//! 브로커가 알려주지 않는 불일치(로컬이 모르는 미체결 주문, HTS 수동 주문 체결)는
+//! 보고만 하고 보정하지 않습니다.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{FixedOffset, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use sqlx::PgPool;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, info, warn};
+use trader_core::{
+    ExchangeProvider, ExecutionHistoryRequest, ProviderError, StrategyPositionInfo, Trade,
+};
+use trader_execution::{
+    is_us_equity, normalize_order_id, plan_position_corrections, reconcile, DiscrepancyKind,
+    ExecutionSnapshot, HoldingSnapshot, LocalSource, OrderSnapshot, ReconciliationInput,
+    ReconciliationReport, ReconciliationTolerance,
+};
+use uuid::Uuid;
+
+use crate::repository::{
+    get_active_credential_id, AuditLogRepository, HoldingPosition, OrderRepository, OrderStatus,
+    PositionRepository, ReconciliationOrder,
+};
+use crate::services::notification_digest::build_notifier;
+use crate::state::AppState;
+
+/// 감사 로그 사용자 ID.
+const AUDIT_ACTOR: &str = "reconciliation";
+
+/// 알림에 포함할 최대 불일치 줄 수.
+const MAX_NOTIFY_LINES: usize = 10;
+
+/// 체결 내역 최대 조회 페이지 수.
+const MAX_HISTORY_PAGES: usize = 10;
+
+/// 대사 서비스 설정.
+#[derive(Debug, Clone)]
+pub struct ReconciliationConfig {
+    /// 대사 주기
+    pub poll_interval: Duration,
+    /// 불일치 자동 보정 여부
+    pub auto_correct: bool,
+    /// 추적기 현금과 브로커 예수금 비교 여부
+    pub check_cash: bool,
+    /// 허용 오차
+    pub tolerance: ReconciliationTolerance,
+}
+
+impl ReconciliationConfig {
+    /// 환경변수에서 설정 로드.
+    ///
+    /// `RECONCILIATION_ENABLED=false`이면 `None`을 반환합니다.
+    pub fn from_env() -> Option<Self> {
+        let enabled = std::env::var("RECONCILIATION_ENABLED")
+            .map(|v| v.to_lowercase() != "false")
+            .unwrap_or(true);
+        if !enabled {
+            return None;
+        }
+
+        let poll_interval = std::env::var("RECONCILIATION_POLL_SECS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .filter(|secs| *secs > 0)
+            .map(Duration::from_secs)
+            .unwrap_or(Duration::from_secs(300));
+        let flag = |name: &str| {
+            std::env::var(name)
+                .map(|v| v.to_lowercase() == "true")
+                .unwrap_or(false)
+        };
+
+        let mut tolerance = ReconciliationTolerance::default();
+        if let Some(pct) = std::env::var("RECONCILIATION_PRICE_TOLERANCE_PCT")
+            .ok()
+            .and_then(|v| v.parse::<Decimal>().ok())
+            .filter(|pct| *pct >= Decimal::ZERO)
+        {
+            tolerance.price_pct = pct;
+        }
+
+        Some(Self {
+            poll_interval,
+            auto_correct: flag("RECONCILIATION_AUTO_CORRECT"),
+            check_cash: flag("RECONCILIATION_CHECK_CASH"),
+            tolerance,
+        })
+    }
+}
+
+/// 브로커가 담당하는 종목인지 여부.
+///
+/// KIS는 국내/해외 계좌를 별도 Provider로 조회하지만 DB에는 같은 `kis` 거래소로 저장되므로,
+/// 다른 시장 종목을 "브로커에 없음"으로 오판하지 않도록 시장별로 나눕니다.
+fn in_scope(exchange_name: &str, ticker: &str) -> bool {
+    match exchange_name {
+        "KIS-KR" => !is_us_equity(ticker),
+        "KIS-US" => is_us_equity(ticker),
+        _ => true,
+    }
+}
+
+/// DB `positions.exchange` 값 (`KIS-KR` → `kis`).
+fn db_exchange(exchange_name: &str) -> String {
+    exchange_name
+        .split('-')
+        .next()
+        .unwrap_or(exchange_name)
+        .to_lowercase()
+}
+
+/// 주문 번호별 체결 수량 가중 평균가.
+fn average_fill_prices(trades: &[Trade]) -> HashMap<String, Decimal> {
+    let mut totals: HashMap<String, (Decimal, Decimal)> = HashMap::new();
+    for trade in trades {
+        let entry = totals
+            .entry(normalize_order_id(&trade.exchange_trade_id).to_string())
+            .or_default();
+        entry.0 += trade.quantity;
+        entry.1 += trade.quantity * trade.price;
+    }
+    totals
+        .into_iter()
+        .filter(|(_, (quantity, _))| *quantity > Decimal::ZERO)
+        .map(|(id, (quantity, amount))| (id, amount / quantity))
+        .collect()
+}
+
+/// 한국 표준시 (UTC+9).
+fn kst() -> FixedOffset {
+    FixedOffset::east_opt(9 * 3600).expect("valid offset")
+}
+
+/// 당일(KST) 체결 내역 조회.
+///
+/// 체결 내역 조회를 지원하지 않는 거래소는 빈 목록을 반환합니다.
+async fn fetch_today_trades(provider: &dyn ExchangeProvider) -> Result<Vec<Trade>, ProviderError> {
+    let today = Utc::now()
+        .with_timezone(&kst())
+        .format("%Y%m%d")
+        .to_string();
+
+    let mut trades = Vec::new();
+    let mut request = ExecutionHistoryRequest::new(&today, &today);
+    for _ in 0..MAX_HISTORY_PAGES {
+        let response = match provider.fetch_execution_history(&request).await {
+            Ok(response) => response,
+            Err(ProviderError::Unsupported(_)) => return Ok(Vec::new()),
+            Err(e) => return Err(e),
+        };
+        trades.extend(response.trades);
+        match response.next_cursor {
+            Some(cursor) => request = request.with_cursor(cursor),
+            None => break,
+        }
+    }
+    Ok(trades)
+}
+
+/// 대사 한 회에 수집한 데이터.
+struct Collected {
+    input: ReconciliationInput,
+    broker_positions: Vec<StrategyPositionInfo>,
+    db_orders: HashMap<String, ReconciliationOrder>,
+    fill_prices: HashMap<String, Decimal>,
+    credential_id: Option<Uuid>,
+}
+
+async fn collect(
+    state: &AppState,
+    pool: &PgPool,
+    provider: &dyn ExchangeProvider,
+    config: &ReconciliationConfig,
+) -> Result<Collected, String> {
+    let exchange_name = provider.exchange_name().to_string();
+
+    let broker_positions = provider
+        .fetch_positions()
+        .await
+        .map_err(|e| format!("브로커 포지션 조회 실패: {}", e))?;
+    let pending_orders = provider
+        .fetch_pending_orders()
+        .await
+        .map_err(|e| format!("브로커 미체결 주문 조회 실패: {}", e))?;
+    let trades = fetch_today_trades(provider)
+        .await
+        .map_err(|e| format!("브로커 체결 내역 조회 실패: {}", e))?;
+    let broker_cash = if config.check_cash {
+        let account = provider
+            .fetch_account()
+            .await
+            .map_err(|e| format!("브로커 계좌 조회 실패: {}", e))?;
+        Some(account.available_balance)
+    } else {
+        None
+    };
+
+    let (tracker_positions, tracker_cash) = {
+        let executor = state.executor.read().await;
+        let tracker = executor.position_tracker().read().await;
+        let positions = tracker
+            .get_open_positions()
+            .into_iter()
+            .filter(|p| in_scope(&exchange_name, &p.ticker))
+            .map(|p| HoldingSnapshot::new(p.ticker.clone(), p.side, p.quantity, p.entry_price))
+            .collect::<Vec<_>>();
+        (positions, config.check_cash.then(|| tracker.cash()))
+    };
+
+    // DB 포지션은 활성 자격증명 기준 (자격증명이 없으면 DB 포지션 비교 생략)
+    let credential_id = get_active_credential_id(pool).await.ok();
+    let db_positions = match credential_id {
+        Some(credential_id) => {
+            let exchange = db_exchange(&exchange_name);
+            let records = PositionRepository::get_open_positions_by_credential(pool, credential_id)
+                .await
+                .map_err(|e| format!("DB 포지션 조회 실패: {}", e))?;
+            Some(
+                records
+                    .into_iter()
+                    .filter(|r| r.exchange == exchange)
+                    .filter_map(|r| {
+                        let symbol = r.symbol?;
+                        in_scope(&exchange_name, &symbol).then(|| {
+                            HoldingSnapshot::new(symbol, r.side, r.quantity, r.entry_price)
+                        })
+                    })
+                    .collect(),
+            )
+        }
+        None => None,
+    };
+
+    let execution_ids: Vec<String> = trades
+        .iter()
+        .map(|t| normalize_order_id(&t.exchange_trade_id).to_string())
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect();
+    let db_orders: HashMap<String, ReconciliationOrder> =
+        OrderRepository::get_for_reconciliation(pool, &execution_ids)
+            .await
+            .map_err(|e| format!("DB 주문 조회 실패: {}", e))?
+            .into_iter()
+            .filter(|o| o.ticker.is_empty() || in_scope(&exchange_name, &o.ticker))
+            .map(|o| (normalize_order_id(&o.exchange_order_id).to_string(), o))
+            .collect();
+
+    let input = ReconciliationInput {
+        broker_positions: broker_positions
+            .iter()
+            .map(|p| HoldingSnapshot::new(p.ticker.clone(), p.side, p.quantity, p.avg_entry_price))
+            .collect(),
+        broker_open_orders: pending_orders
+            .iter()
+            .map(|o| OrderSnapshot {
+                exchange_order_id: o.order_id.clone(),
+                ticker: o.ticker.clone(),
+                quantity: o.quantity,
+                filled_quantity: o.filled_quantity,
+                is_open: true,
+            })
+            .collect(),
+        broker_executions: trades
+            .iter()
+            .map(|t| ExecutionSnapshot {
+                exchange_order_id: t.exchange_trade_id.clone(),
+                ticker: t.ticker.clone(),
+                quantity: t.quantity,
+                price: t.price,
+            })
+            .collect(),
+        broker_cash,
+        tracker_positions,
+        tracker_cash,
+        db_positions,
+        db_orders: db_orders
+            .values()
+            .map(|o| OrderSnapshot {
+                exchange_order_id: o.exchange_order_id.clone(),
+                ticker: o.ticker.clone(),
+                quantity: o.quantity,
+                filled_quantity: o.filled_quantity,
+                is_open: o.is_active(),
+            })
+            .collect(),
+    };
+
+    Ok(Collected {
+        input,
+        broker_positions,
+        db_orders,
+        fill_prices: average_fill_prices(&trades),
+        credential_id,
+    })
+}
+
+/// 보정 감사 로그 기록 (실패 시 경고만 남김).
+async fn record_audit(
+    pool: &PgPool,
+    entity_type: &str,
+    entity_id: Option<Uuid>,
+    details: serde_json::Value,
+) {
+    if let Err(e) = AuditLogRepository::record(
+        pool,
+        "reconciliation_corrected",
+        entity_type,
+        entity_id,
+        Some(AUDIT_ACTOR),
+        &details,
+    )
+    .await
+    {
+        warn!(entity_type, error = %e, "Failed to record reconciliation audit log");
+    }
+}
+
+/// 추적기 포지션을 브로커 보유 현황에 맞춤.
+async fn correct_tracker(
+    state: &AppState,
+    pool: &PgPool,
+    exchange_name: &str,
+    collected: &Collected,
+    config: &ReconciliationConfig,
+) -> usize {
+    let corrections = plan_position_corrections(
+        &collected.input.broker_positions,
+        &collected.input.tracker_positions,
+        &config.tolerance,
+    );
+    if corrections.is_empty() {
+        return 0;
+    }
+
+    let mut applied = Vec::new();
+    {
+        let executor = state.executor.read().await;
+        let mut tracker = executor.position_tracker().write().await;
+        for correction in corrections {
+            match correction.apply(&mut tracker) {
+                Ok(()) => applied.push(correction),
+                Err(e) => warn!(
+                    ticker = correction.ticker(),
+                    error = %e,
+                    "Failed to correct tracker position"
+                ),
+            }
+        }
+    }
+
+    for correction in &applied {
+        record_audit(
+            pool,
+            "position",
+            None,
+            serde_json::json!({
+                "source": LocalSource::Tracker.as_str(),
+                "exchange": exchange_name,
+                "correction": correction,
+            }),
+        )
+        .await;
+    }
+    applied.len()
+}
+
+/// DB 포지션을 브로커 보유 현황으로 동기화.
+///
+/// 다른 시장 포지션은 기존 값 그대로 넘겨 청산 처리되지 않게 합니다.
+async fn correct_db_positions(
+    pool: &PgPool,
+    exchange_name: &str,
+    collected: &Collected,
+    report: &ReconciliationReport,
+) -> usize {
+    let Some(credential_id) = collected.credential_id else {
+        return 0;
+    };
+    let count = report
+        .for_source(LocalSource::Database)
+        .filter(|d| d.kind.is_position() && d.kind != DiscrepancyKind::AvgPriceMismatch)
+        .count();
+    if count == 0 {
+        return 0;
+    }
+
+    let exchange = db_exchange(exchange_name);
+    let market = exchange_name
+        .rsplit('-')
+        .next()
+        .unwrap_or_default()
+        .to_string();
+    let mut holdings: Vec<HoldingPosition> = collected
+        .broker_positions
+        .iter()
+        .map(|p| HoldingPosition {
+            credential_id,
+            exchange: exchange.clone(),
+            symbol: p.ticker.clone(),
+            symbol_name: p.ticker.clone(),
+            quantity: p.quantity,
+            avg_price: p.avg_entry_price,
+            current_price: p.current_price,
+            profit_loss: p.unrealized_pnl,
+            profit_loss_rate: p.unrealized_pnl_pct,
+            market: market.clone(),
+        })
+        .collect();
+
+    match PositionRepository::get_open_positions_by_credential(pool, credential_id).await {
+        Ok(records) => holdings.extend(records.into_iter().filter_map(|r| {
+            let symbol = r.symbol?;
+            (r.exchange == exchange && !in_scope(exchange_name, &symbol)).then(|| HoldingPosition {
+                credential_id,
+                exchange: exchange.clone(),
+                symbol_name: r.symbol_name.unwrap_or_else(|| symbol.clone()),
+                symbol,
+                quantity: r.quantity,
+                avg_price: r.entry_price,
+                current_price: r.current_price.unwrap_or(r.entry_price),
+                profit_loss: r.unrealized_pnl.unwrap_or_default(),
+                profit_loss_rate: Decimal::ZERO,
+                market: String::new(),
+            })
+        })),
+        Err(e) => {
+            warn!(error = %e, "Failed to load DB positions for correction");
+            return 0;
+        }
+    }
+
+    match PositionRepository::sync_holdings(pool, credential_id, &exchange, holdings).await {
+        Ok(result) => {
+            record_audit(
+                pool,
+                "position",
+                None,
+                serde_json::json!({
+                    "source": LocalSource::Database.as_str(),
+                    "exchange": exchange_name,
+                    "credential_id": credential_id,
+                    "discrepancies": count,
+                    "synced": result.synced,
+                    "closed": result.closed,
+                }),
+            )
+            .await;
+            count
+        }
+        Err(e) => {
+            warn!(error = %e, "Failed to sync DB positions with broker");
+            0
+        }
+    }
+}
+
+/// DB 주문의 체결 수량과 상태를 브로커 기준으로 보정.
+///
+/// 당일 체결 내역으로 체결 수량과 평균가를 맞추고, 브로커에 미체결로 남아 있지 않은
+/// 미완료 주문은 취소 처리합니다. 체결 내역이 없는 지난 주문은 체결 여부를 알 수 없으므로
+/// 보고만 합니다.
+async fn correct_db_orders(
+    pool: &PgPool,
+    collected: &Collected,
+    report: &ReconciliationReport,
+    today: NaiveDate,
+) -> usize {
+    let mut corrected = 0;
+    for discrepancy in report.for_source(LocalSource::Database) {
+        if !matches!(
+            discrepancy.kind,
+            DiscrepancyKind::FillMismatch | DiscrepancyKind::StaleLocalOrder
+        ) {
+            continue;
+        }
+        let id = normalize_order_id(&discrepancy.key);
+        let Some(order) = collected.db_orders.get(id) else {
+            continue;
+        };
+        let fill_price = collected.fill_prices.get(id).copied();
+        let created_today = order
+            .created_at
+            .is_some_and(|at| at.with_timezone(&kst()).date_naive() == today);
+        if fill_price.is_none() && !created_today {
+            continue;
+        }
+        let broker_filled = discrepancy.broker_value.unwrap_or_default();
+
+        let mut result = Ok(());
+        if let Some(price) = fill_price.filter(|_| broker_filled != order.filled_quantity) {
+            result = OrderRepository::update_filled_quantity(pool, order.id, broker_filled, price)
+                .await
+                .map(|_| ());
+        }
+        // 브로커에 미체결로 남아 있지 않고 전량 체결도 아니면 취소된 주문
+        if result.is_ok()
+            && discrepancy.kind == DiscrepancyKind::StaleLocalOrder
+            && broker_filled < order.quantity
+        {
+            result = OrderRepository::update_order_status(pool, order.id, OrderStatus::Cancelled)
+                .await
+                .map(|_| ());
+        }
+
+        match result {
+            Ok(()) => {
+                corrected += 1;
+                record_audit(
+                    pool,
+                    "order",
+                    Some(order.id),
+                    serde_json::json!({
+                        "kind": discrepancy.kind.as_str(),
+                        "exchange_order_id": order.exchange_order_id,
+                        "broker_filled": broker_filled,
+                        "local_filled": order.filled_quantity,
+                        "fill_price": fill_price,
+                    }),
+                )
+                .await;
+            }
+            Err(e) => warn!(
+                exchange_order_id = %order.exchange_order_id,
+                error = %e,
+                "Failed to correct order"
+            ),
+        }
+    }
+    corrected
+}
+
+/// 대사 한 회 실행.
+///
+/// 불일치 보고서와 자동 보정한 건수를 반환합니다.
+pub async fn run_reconciliation(
+    state: &AppState,
+    pool: &PgPool,
+    provider: &dyn ExchangeProvider,
+    config: &ReconciliationConfig,
+) -> Result<(ReconciliationReport, usize), String> {
+    let collected = collect(state, pool, provider, config).await?;
+    let report = reconcile(&collected.input, &config.tolerance);
+    if report.is_clean() || !config.auto_correct {
+        return Ok((report, 0));
+    }
+
+    let exchange_name = provider.exchange_name();
+    let mut corrected = 0;
+    if report
+        .for_source(LocalSource::Tracker)
+        .any(|d| d.kind.is_position())
+    {
+        corrected += correct_tracker(state, pool, exchange_name, &collected, config).await;
+    }
+    corrected += correct_db_positions(pool, exchange_name, &collected, &report).await;
+    let today = Utc::now().with_timezone(&kst()).date_naive();
+    corrected += correct_db_orders(pool, &collected, &report, today).await;
+
+    Ok((report, corrected))
+}
+
+/// 대사 서비스 시작.
+///
+/// # Arguments
+///
+/// * `state` - 애플리케이션 상태 (거래소 Provider, 실행기)
+/// * `pool` - 포지션/주문/감사 로그 DB
+/// * `config` - 서비스 설정
+/// * `shutdown` - Graceful shutdown을 위한 CancellationToken
+///
+/// # Returns
+///
+/// 백그라운드 task의 JoinHandle
+pub fn start_reconciliation_service(
+    state: Arc<AppState>,
+    pool: PgPool,
+    config: ReconciliationConfig,
+    shutdown: CancellationToken,
+) -> tokio::task::JoinHandle<()> {
+    let task = state.tasks.register(
+        "reconciliation",
+        "브로커 잔고/포지션/체결과 로컬 상태 사후 대사",
+        config.poll_interval,
+    );
+
+    tokio::spawn(async move {
+        info!(
+            poll_secs = config.poll_interval.as_secs(),
+            auto_correct = config.auto_correct,
+            "Reconciliation service started"
+        );
+        let notifier = build_notifier();
+        let mut ticker = tokio::time::interval(config.poll_interval);
+        let mut last_fingerprint = String::new();
+
+        loop {
+            if task.wait_next(&mut ticker, &shutdown).await.is_none() {
+                info!("Reconciliation service stopped");
+                break;
+            }
+            let Some(provider) = state.exchange_provider.clone() else {
+                debug!("Exchange provider not configured, skipping reconciliation");
+                continue;
+            };
+            let mut run = task.begin();
+
+            let (report, corrected) =
+                match run_reconciliation(&state, &pool, provider.as_ref(), &config).await {
+                    Ok(result) => result,
+                    Err(e) => {
+                        warn!(error = %e, "Reconciliation cycle failed");
+                        run.fail(e);
+                        continue;
+                    }
+                };
+
+            // 같은 불일치가 이어지는 동안은 다시 알리지 않음 (보정했으면 알림)
+            let fingerprint = report.fingerprint();
+            if report.is_clean() {
+                if !last_fingerprint.is_empty() {
+                    info!("Reconciliation discrepancies resolved");
+                }
+                last_fingerprint = fingerprint;
+                continue;
+            }
+            if fingerprint == last_fingerprint && corrected == 0 {
+                continue;
+            }
+            last_fingerprint = fingerprint;
+
+            warn!(
+                exchange = provider.exchange_name(),
+                discrepancies = report.discrepancies.len(),
+                corrected,
+                "Reconciliation discrepancies detected"
+            );
+            let lines: Vec<String> = report
+                .discrepancies
+                .iter()
+                .take(MAX_NOTIFY_LINES)
+                .map(|d| d.describe())
+                .collect();
+            let omitted = report.discrepancies.len().saturating_sub(lines.len());
+            if let Err(e) = notifier
+                .notify_reconciliation_mismatch(
+                    provider.exchange_name(),
+                    report.discrepancies.len(),
+                    corrected,
+                    lines,
+                    omitted,
+                )
+                .await
+            {
+                warn!(error = %e, "Failed to send reconciliation notification");
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+    use trader_core::Side;
+
+    #[test]
+    fn test_in_scope_splits_kis_markets() {
+        assert!(in_scope("KIS-KR", "005930"));
+        assert!(!in_scope("KIS-KR", "AAPL"));
+        assert!(in_scope("KIS-US", "BRK.B"));
+        assert!(!in_scope("KIS-US", "069500"));
+        assert!(in_scope("Binance", "BTC/USDT"));
+        assert_eq!(db_exchange("KIS-KR"), "kis");
+        assert_eq!(db_exchange("Binance"), "binance");
+    }
+
+    #[test]
+    fn test_average_fill_prices_by_order() {
+        let trade = |id: &str, quantity, price| {
+            Trade::new(
+                Uuid::nil(),
+                "KIS-KR".to_string(),
+                id.to_string(),
+                "005930".to_string(),
+                Side::Buy,
+                quantity,
+                price,
+            )
+        };
+        let prices = average_fill_prices(&[
+            trade("0000000001", dec!(4), dec!(70000)),
+            trade("0000000001", dec!(6), dec!(70500)),
+            trade("0000000002", dec!(1), dec!(71000)),
+        ]);
+
+        assert_eq!(prices["1"], dec!(70300));
+        assert_eq!(prices["2"], dec!(71000));
+    }
+}
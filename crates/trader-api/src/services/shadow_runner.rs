@@ -0,0 +1,160 @@
+//! 실거래 vs 섀도 백테스트 비교 서비스.
+//!
+//! 등록된 전략마다 같은 타입/설정의 섀도 인스턴스를 전략 엔진에 연결하고,
+//! 실행기의 체결 이벤트를 섀도 체결가와 비교합니다.
+//! 신호 비교는 전략 엔진이 시장 데이터를 처리할 때 수행하며,
+//! 결과는 `/api/v1/monitoring/shadow`에서 조회합니다.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::broadcast::error::RecvError;
+use tokio_util::sync::CancellationToken;
+use tracing::{info, warn};
+use trader_execution::ExecutionEvent;
+use trader_strategy::{ShadowConfig, StrategyRegistry};
+
+use crate::state::AppState;
+
+/// 섀도 연결 대상 점검 주기 (새로 등록된 전략 반영).
+const ATTACH_INTERVAL: Duration = Duration::from_secs(60);
+
+/// 섀도 비교 서비스 설정.
+#[derive(Debug, Clone)]
+pub struct ShadowRunnerConfig {
+    /// 모든 전략에 섀도를 자동 연결할지 여부
+    pub auto_attach: bool,
+    /// 섀도 비교 설정
+    pub shadow: ShadowConfig,
+}
+
+impl ShadowRunnerConfig {
+    /// 환경변수에서 설정 로드.
+    ///
+    /// - `SHADOW_RUNNER_ENABLED`: 모든 전략에 섀도 자동 연결 (기본 false)
+    /// - `SHADOW_MATCH_WINDOW_SECS`: 신호 매칭 허용 시간 (기본 300초)
+    /// - `SHADOW_PRICE_TOLERANCE_BPS`: 체결가 괴리 허용치 (기본 100bp)
+    pub fn from_env() -> Self {
+        let defaults = ShadowConfig::default();
+
+        let auto_attach = std::env::var("SHADOW_RUNNER_ENABLED")
+            .map(|v| v.to_lowercase() == "true")
+            .unwrap_or(false);
+        let match_window_secs = std::env::var("SHADOW_MATCH_WINDOW_SECS")
+            .ok()
+            .and_then(|v| v.parse::<i64>().ok())
+            .filter(|secs| *secs >= 0)
+            .unwrap_or(defaults.match_window_secs);
+        let price_tolerance_bps = std::env::var("SHADOW_PRICE_TOLERANCE_BPS")
+            .ok()
+            .and_then(|v| v.parse::<f64>().ok())
+            .filter(|bps| *bps >= 0.0)
+            .unwrap_or(defaults.price_tolerance_bps);
+
+        Self {
+            auto_attach,
+            shadow: ShadowConfig {
+                match_window_secs,
+                price_tolerance_bps,
+                ..defaults
+            },
+        }
+    }
+}
+
+/// 전략에 섀도 인스턴스 연결.
+///
+/// 실거래 전략과 같은 타입의 새 인스턴스를 생성하여 엔진에 연결합니다.
+pub async fn attach_shadow_for(
+    state: &AppState,
+    strategy_id: &str,
+    config: ShadowConfig,
+) -> Result<(), String> {
+    let engine = state.strategy_engine.read().await;
+    let strategy_type = engine
+        .get_strategy_type(strategy_id)
+        .await
+        .map_err(|e| e.to_string())?;
+    let strategy = StrategyRegistry::create_instance(&strategy_type)?;
+
+    engine
+        .attach_shadow(strategy_id, strategy, config)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// 섀도가 없는 모든 전략에 섀도 연결.
+async fn attach_missing_shadows(state: &AppState, config: &ShadowConfig) {
+    let (strategies, shadowed) = {
+        let engine = state.strategy_engine.read().await;
+        (
+            engine.list_strategies().await,
+            engine.shadowed_strategies().await,
+        )
+    };
+
+    for strategy_id in strategies {
+        if shadowed.contains(&strategy_id) {
+            continue;
+        }
+        if let Err(e) = attach_shadow_for(state, &strategy_id, config.clone()).await {
+            warn!(strategy_id = %strategy_id, error = %e, "Failed to attach shadow strategy");
+        }
+    }
+}
+
+/// 섀도 비교 서비스 시작.
+///
+/// # Arguments
+///
+/// * `state` - 애플리케이션 상태 (전략 엔진, 실행기)
+/// * `config` - 섀도 비교 서비스 설정
+/// * `shutdown` - Graceful shutdown을 위한 CancellationToken
+///
+/// # Returns
+///
+/// 백그라운드 task의 JoinHandle
+pub async fn start_shadow_runner(
+    state: Arc<AppState>,
+    config: ShadowRunnerConfig,
+    shutdown: CancellationToken,
+) -> tokio::task::JoinHandle<()> {
+    let mut executions = state.executor.read().await.subscribe_events();
+
+    tokio::spawn(async move {
+        info!(auto_attach = config.auto_attach, "Shadow runner started");
+        let mut ticker = tokio::time::interval(ATTACH_INTERVAL);
+
+        loop {
+            tokio::select! {
+                _ = shutdown.cancelled() => {
+                    info!("Shadow runner stopped");
+                    break;
+                }
+                _ = ticker.tick(), if config.auto_attach => {
+                    attach_missing_shadows(&state, &config.shadow).await;
+                }
+                received = executions.recv() => match received {
+                    Ok(ExecutionEvent::OrderFilled {
+                        strategy_id: Some(strategy_id),
+                        ticker,
+                        side,
+                        fill_price,
+                        timestamp,
+                        ..
+                    }) => {
+                        let engine = state.strategy_engine.read().await;
+                        engine
+                            .record_live_fill(&strategy_id, &ticker, side, fill_price, timestamp)
+                            .await;
+                    }
+                    Ok(_) => {}
+                    Err(RecvError::Lagged(skipped)) => {
+                        warn!(skipped, "Shadow runner lagged behind execution events");
+                    }
+                    Err(RecvError::Closed) => break,
+                },
+            }
+        }
+    })
+}
@@ -183,7 +183,7 @@ mod tests {
     use super::*;
     use chrono::Utc;
     use rust_decimal_macros::dec;
-    use trader_core::{Side, SignalIndicators, SignalType};
+    use trader_core::SignalType;
 
     #[test]
     fn test_filter_min_strength() {
@@ -224,7 +224,8 @@ mod tests {
             dec!(50000),
             "test_strategy",
             "Test Strategy",
-        );
+        )
+        .with_strength(0.9);
 
         let exit_marker = SignalMarker::new(
             "BTC/USDT".to_string(),
@@ -233,7 +234,8 @@ mod tests {
             dec!(51000),
             "test_strategy",
             "Test Strategy",
-        );
+        )
+        .with_strength(0.9);
 
         assert!(filter.matches(&entry_marker));
         assert!(!filter.matches(&exit_marker));
@@ -250,7 +252,8 @@ mod tests {
             dec!(50000),
             "rsi_strategy",
             "RSI Strategy",
-        );
+        )
+        .with_strength(0.9);
 
         let non_matching_marker = SignalMarker::new(
             "ETH/USDT".to_string(),
@@ -259,7 +262,8 @@ mod tests {
             dec!(3000),
             "macd_strategy",
             "MACD Strategy",
-        );
+        )
+        .with_strength(0.9);
 
         assert!(filter.matches(&matching_marker));
         assert!(!filter.matches(&non_matching_marker));
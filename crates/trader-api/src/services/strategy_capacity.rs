@@ -0,0 +1,219 @@
+//! 전략 운용 용량 추정.
+//!
+//! 전략 종목의 평균 거래대금(ADV)과 최근 체결 이력의 회전율로 전략이 감당할 수 있는
+//! 최대 자본을 추정하고(`trader_analytics::capacity`), 할당 자본이 용량에 근접하면 경고합니다.
+//!
+//! - ADV 참여율: 실행기의 유동성 수량 상한(`LiquidityCapConfig`)과 같은 단계별 비율 사용
+//! - 회전율: 최근 60일 체결 거래대금 ÷ 체결일 수 ÷ 할당 자본 (체결일 기준이라 보수적)
+//! - 체결 이력이 없으면 일 회전율 10%를 가정하고 종목별 동일 비중으로 계산
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono::{Duration, Utc};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use tracing::{debug, warn};
+use trader_analytics::{
+    estimate_capacity, CapacityConfig, CapacityEstimate, CapacityStatus, LiquidityGate,
+    SymbolLiquidityInput,
+};
+use trader_core::Timeframe;
+use trader_data::cache::CachedHistoricalDataProvider;
+
+use super::liquidity_snapshot::{market_type_for_ticker, snapshot_from_klines, ADV_WINDOW};
+use crate::repository::{JournalRepository, StrategyRepository};
+use crate::state::AppState;
+
+/// 회전율 계산 기간 (일).
+const TURNOVER_LOOKBACK_DAYS: i64 = 60;
+
+/// 체결 이력이 없을 때 가정하는 일 회전율 (자본 대비).
+const ASSUMED_DAILY_TURNOVER: Decimal = dec!(0.1);
+
+/// 회전율 출처.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TurnoverSource {
+    /// 최근 체결 이력
+    Executions,
+    /// 체결 이력 없음 - 가정값
+    Assumed,
+}
+
+/// 전략 용량 추정 결과 (전략 상세 응답에 포함).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StrategyCapacity {
+    /// 용량 추정
+    #[serde(flatten)]
+    pub estimate: CapacityEstimate,
+    /// 회전율 출처
+    pub turnover_source: TurnoverSource,
+    /// 회전율 계산 기간 (일)
+    pub lookback_days: i64,
+}
+
+/// 체결 이력으로 일 회전율과 종목별 비중 계산.
+///
+/// 체결 거래대금 ÷ 체결일 수 ÷ 할당 자본. 계산할 수 없으면 None을 반환합니다.
+fn turnover_from_executions(
+    notional_by_symbol: &[(String, Decimal)],
+    active_days: i64,
+    allocated_capital: Option<Decimal>,
+) -> Option<(Decimal, HashMap<String, Decimal>)> {
+    let capital = allocated_capital.filter(|c| *c > Decimal::ZERO)?;
+    let total: Decimal = notional_by_symbol.iter().map(|(_, n)| *n).sum();
+    if active_days <= 0 || total <= Decimal::ZERO {
+        return None;
+    }
+
+    let turnover = total / Decimal::from(active_days) / capital;
+    let weights = notional_by_symbol
+        .iter()
+        .map(|(symbol, notional)| (symbol.clone(), *notional / total))
+        .collect();
+    Some((turnover, weights))
+}
+
+/// 전략 운용 용량 추정.
+///
+/// DB가 없거나 전략 레코드가 없으면 None을 반환합니다.
+/// 유동성 데이터를 얻지 못한 종목은 추정에서 제외됩니다.
+pub async fn estimate_strategy_capacity(
+    state: &AppState,
+    strategy_id: &str,
+) -> Option<StrategyCapacity> {
+    let pool = state.db_pool.as_ref()?;
+    let record = match StrategyRepository::get_by_id(pool, strategy_id).await {
+        Ok(Some(record)) => record,
+        Ok(None) => return None,
+        Err(e) => {
+            warn!(strategy_id, error = %e, "Failed to load strategy for capacity estimate");
+            return None;
+        }
+    };
+
+    let symbols: Vec<String> = record
+        .symbols
+        .as_ref()
+        .and_then(|v| v.as_array())
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|v| v.as_str().map(String::from))
+                .collect()
+        })
+        .unwrap_or_default();
+    let allocated_capital = record.allocated_capital;
+
+    // 회전율 (최근 체결 이력)
+    let since = Utc::now() - Duration::days(TURNOVER_LOOKBACK_DAYS);
+    let executions = JournalRepository::get_strategy_symbol_notional(pool, strategy_id, since)
+        .await
+        .unwrap_or_else(|e| {
+            warn!(strategy_id, error = %e, "Failed to load executions for capacity estimate");
+            Vec::new()
+        });
+    let active_days = executions.first().map_or(0, |e| e.active_days);
+    let notional_by_symbol: Vec<(String, Decimal)> = executions
+        .into_iter()
+        .map(|e| (e.symbol, e.notional))
+        .collect();
+
+    let (daily_turnover, weights, turnover_source) =
+        match turnover_from_executions(&notional_by_symbol, active_days, allocated_capital) {
+            Some((turnover, weights)) => (turnover, weights, TurnoverSource::Executions),
+            None => {
+                let weights = symbols.iter().map(|s| (s.clone(), Decimal::ONE)).collect();
+                (ASSUMED_DAILY_TURNOVER, weights, TurnoverSource::Assumed)
+            }
+        };
+
+    // 종목별 유동성 (실행기 스냅샷 우선, 없으면 일봉으로 계산)
+    let provider = match &state.data_provider {
+        Some(provider) => provider.clone(),
+        None => Arc::new(CachedHistoricalDataProvider::new(pool.clone())),
+    };
+    let executor = state.executor.read().await;
+    let cap_config = executor.liquidity_cap().clone();
+
+    let mut inputs = Vec::with_capacity(weights.len());
+    for (ticker, weight) in &weights {
+        let snapshot = match executor.liquidity_snapshot(ticker).await {
+            Some(snapshot) => Some(snapshot),
+            None => match provider.get_klines(ticker, Timeframe::D1, ADV_WINDOW).await {
+                Ok(klines) => {
+                    snapshot_from_klines(&klines, market_type_for_ticker(ticker), ADV_WINDOW)
+                }
+                Err(e) => {
+                    debug!(ticker = %ticker, error = %e, "Failed to load klines for capacity");
+                    None
+                }
+            },
+        };
+        let Some(snapshot) = snapshot else {
+            continue;
+        };
+
+        let level =
+            LiquidityGate::for_market(snapshot.market_type).check_level(snapshot.avg_daily_amount);
+        inputs.push(SymbolLiquidityInput {
+            ticker: ticker.clone(),
+            avg_daily_amount: snapshot.avg_daily_amount,
+            max_adv_fraction: cap_config.adv_fraction_for(level),
+            weight: *weight,
+        });
+    }
+    drop(executor);
+
+    inputs.sort_by(|a, b| a.ticker.cmp(&b.ticker));
+    let estimate = estimate_capacity(
+        &inputs,
+        daily_turnover,
+        allocated_capital,
+        &CapacityConfig::default(),
+    );
+
+    if matches!(
+        estimate.status,
+        CapacityStatus::Warning | CapacityStatus::Exceeded
+    ) {
+        warn!(
+            strategy_id,
+            capacity = ?estimate.capacity,
+            allocated = ?allocated_capital,
+            binding = ?estimate.binding_ticker,
+            "Strategy allocation approaching liquidity capacity"
+        );
+    }
+
+    Some(StrategyCapacity {
+        estimate,
+        turnover_source,
+        lookback_days: TURNOVER_LOOKBACK_DAYS,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turnover_from_executions() {
+        let notional = vec![
+            ("005930".to_string(), dec!(3_000_000)),
+            ("000660".to_string(), dec!(1_000_000)),
+        ];
+
+        // 4,000,000 / 10일 / 10,000,000 = 4%
+        let (turnover, weights) =
+            turnover_from_executions(&notional, 10, Some(dec!(10_000_000))).unwrap();
+        assert_eq!(turnover, dec!(0.04));
+        assert_eq!(weights["005930"], dec!(0.75));
+        assert_eq!(weights["000660"], dec!(0.25));
+
+        assert!(turnover_from_executions(&notional, 10, None).is_none());
+        assert!(turnover_from_executions(&notional, 0, Some(dec!(1))).is_none());
+        assert!(turnover_from_executions(&[], 10, Some(dec!(1))).is_none());
+    }
+}
@@ -0,0 +1,109 @@
+//! 전략별 가상 계좌 자산 스냅샷 저장.
+//!
+//! 주기적으로 `OrderExecutor::strategy_portfolio_snapshots()`를 읽어
+//! `strategy_equity_history`에 저장합니다. 같은 분(minute)의 스냅샷은 갱신되므로
+//! 수동 실행을 반복해도 행이 늘어나지 않습니다.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+use sqlx::PgPool;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, info, warn};
+
+use crate::repository::EquityHistoryRepository;
+use crate::state::AppState;
+
+/// 전략 자산 스냅샷 설정.
+#[derive(Debug, Clone)]
+pub struct StrategyEquitySnapshotConfig {
+    /// 스냅샷 저장 주기
+    pub interval: Duration,
+}
+
+impl StrategyEquitySnapshotConfig {
+    /// 환경변수에서 설정 로드.
+    ///
+    /// `STRATEGY_EQUITY_SNAPSHOT_ENABLED=false`이면 `None`을 반환합니다.
+    pub fn from_env() -> Option<Self> {
+        let enabled = std::env::var("STRATEGY_EQUITY_SNAPSHOT_ENABLED")
+            .map(|v| v.to_lowercase() != "false")
+            .unwrap_or(true);
+        if !enabled {
+            return None;
+        }
+
+        let interval = std::env::var("STRATEGY_EQUITY_SNAPSHOT_INTERVAL_SECS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .filter(|secs| *secs > 0)
+            .map(Duration::from_secs)
+            .unwrap_or(Duration::from_secs(300));
+
+        Some(Self { interval })
+    }
+}
+
+/// 스냅샷 한 번 저장. 저장한 전략 수를 반환.
+async fn run_once(state: &AppState, pool: &PgPool) -> Result<usize, sqlx::Error> {
+    let snapshots = {
+        let executor = state.executor.read().await;
+        executor.strategy_portfolio_snapshots().await
+    };
+    if snapshots.is_empty() {
+        return Ok(0);
+    }
+
+    EquityHistoryRepository::save_strategy_snapshots(pool, &snapshots, Utc::now()).await?;
+    Ok(snapshots.len())
+}
+
+/// 전략 자산 스냅샷 서비스 시작.
+///
+/// # Arguments
+///
+/// * `state` - 애플리케이션 상태 (실행기, 작업 레지스트리)
+/// * `pool` - 데이터베이스 연결 풀
+/// * `config` - 스냅샷 설정
+/// * `shutdown` - Graceful shutdown을 위한 CancellationToken
+///
+/// # Returns
+///
+/// 백그라운드 task의 JoinHandle
+pub fn start_strategy_equity_snapshots(
+    state: Arc<AppState>,
+    pool: PgPool,
+    config: StrategyEquitySnapshotConfig,
+    shutdown: CancellationToken,
+) -> tokio::task::JoinHandle<()> {
+    let task = state.tasks.register(
+        "strategy_equity_snapshot",
+        "전략별 가상 계좌 자본/손익/낙폭 스냅샷 저장",
+        config.interval,
+    );
+
+    tokio::spawn(async move {
+        info!(
+            interval_secs = config.interval.as_secs(),
+            "Strategy equity snapshot service started"
+        );
+        let mut ticker = tokio::time::interval(config.interval);
+
+        loop {
+            if task.wait_next(&mut ticker, &shutdown).await.is_none() {
+                info!("Strategy equity snapshot service stopped");
+                break;
+            }
+            let mut run = task.begin();
+
+            match run_once(&state, &pool).await {
+                Ok(count) => debug!(count, "Strategy equity snapshots saved"),
+                Err(e) => {
+                    warn!(error = %e, "Failed to save strategy equity snapshots");
+                    run.fail(e);
+                }
+            }
+        }
+    })
+}
@@ -0,0 +1,95 @@
+//! 전략 워밍업 데이터 공급.
+//!
+//! 전략 엔진이 전략 시작 시 요청하는 과거 캔들을 [`CachedHistoricalDataProvider`]로
+//! 조회합니다. 캐시에 없는 구간은 프로바이더가 데이터 소스에서 받아 캐시에 저장합니다.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use trader_core::{domain::MultiTimeframeConfig, Kline, Timeframe};
+use trader_data::CachedHistoricalDataProvider;
+use trader_strategy::WarmupDataSource;
+
+/// 전략 워밍업 설정.
+#[derive(Debug, Clone)]
+pub struct StrategyWarmupConfig {
+    /// 타임프레임당 최대 캔들 수 (과도한 조회 방지)
+    pub max_bars: usize,
+}
+
+impl StrategyWarmupConfig {
+    /// 환경변수에서 설정 로드.
+    ///
+    /// `STRATEGY_WARMUP_ENABLED=false`이면 `None`을 반환합니다.
+    pub fn from_env() -> Option<Self> {
+        let enabled = std::env::var("STRATEGY_WARMUP_ENABLED")
+            .map(|v| v.to_lowercase() != "false")
+            .unwrap_or(true);
+        if !enabled {
+            return None;
+        }
+
+        let max_bars = std::env::var("STRATEGY_WARMUP_MAX_BARS")
+            .ok()
+            .and_then(|v| v.parse::<usize>().ok())
+            .filter(|bars| *bars > 0)
+            .unwrap_or(1000);
+
+        Some(Self { max_bars })
+    }
+}
+
+/// [`CachedHistoricalDataProvider`] 기반 워밍업 캔들 공급자.
+pub struct HistoricalWarmupSource {
+    provider: Arc<CachedHistoricalDataProvider>,
+    config: StrategyWarmupConfig,
+}
+
+impl HistoricalWarmupSource {
+    /// 새 공급자 생성.
+    pub fn new(provider: Arc<CachedHistoricalDataProvider>, config: StrategyWarmupConfig) -> Self {
+        Self { provider, config }
+    }
+}
+
+/// 타임프레임별 캔들 수를 상한으로 제한한 설정.
+fn capped_config(config: &MultiTimeframeConfig, max_bars: usize) -> MultiTimeframeConfig {
+    let mut capped = config.clone();
+    for count in capped.timeframes.values_mut() {
+        *count = (*count).min(max_bars);
+    }
+    capped
+}
+
+#[async_trait]
+impl WarmupDataSource for HistoricalWarmupSource {
+    async fn fetch_klines(
+        &self,
+        ticker: &str,
+        config: &MultiTimeframeConfig,
+    ) -> Result<HashMap<Timeframe, Vec<Kline>>, Box<dyn std::error::Error + Send + Sync>> {
+        let config = capped_config(config, self.config.max_bars);
+        Ok(self
+            .provider
+            .get_multi_timeframe_klines(ticker, &config)
+            .await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_capped_config() {
+        let config = MultiTimeframeConfig::new()
+            .with_timeframe(Timeframe::D1, 2000)
+            .with_timeframe(Timeframe::W1, 60)
+            .with_primary(Timeframe::D1);
+        let capped = capped_config(&config, 1000);
+        assert_eq!(capped.timeframes[&Timeframe::D1], 1000);
+        assert_eq!(capped.timeframes[&Timeframe::W1], 60);
+        assert_eq!(capped.primary_timeframe, Some(Timeframe::D1));
+    }
+}
@@ -0,0 +1,352 @@
+//! 백그라운드 작업 레지스트리.
+//!
+//! 주기적으로 실행되는 백그라운드 서비스(컨텍스트 동기화, DCA, 정기 백테스트, 추천 검증 등)를
+//! 하나의 레지스트리에 등록해 실행 주기, 마지막 실행 시각과 소요 시간을 조회하고
+//! 작업별로 일시 정지/재개/즉시 실행할 수 있게 합니다.
+//!
+//! 각 서비스는 자체 루프를 그대로 유지하고, 틱 대기를 [`TaskHandle::wait_next`]로,
+//! 한 회차 실행을 [`TaskHandle::begin`]으로 감싸기만 하면 됩니다.
+//! 일시 정지된 작업은 주기 틱을 건너뛰지만 수동 실행 요청은 처리합니다.
+
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use tokio::sync::Notify;
+use tokio::time::Interval;
+use tokio_util::sync::CancellationToken;
+
+/// 실행 계기.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaskTrigger {
+    /// 주기 틱
+    Schedule,
+    /// `POST /api/v1/tasks/{name}/trigger` 수동 실행
+    Manual,
+}
+
+/// 작업 실행 기록.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct TaskRunStats {
+    /// 현재 실행 중 여부
+    pub running: bool,
+    /// 마지막 실행 시작 시각
+    pub last_started_at: Option<DateTime<Utc>>,
+    /// 마지막 실행 종료 시각
+    pub last_finished_at: Option<DateTime<Utc>>,
+    /// 마지막 실행 소요 시간 (밀리초)
+    pub last_duration_ms: Option<u64>,
+    /// 마지막 실행 오류 (성공 시 None)
+    pub last_error: Option<String>,
+    /// 누적 실행 횟수
+    pub run_count: u64,
+    /// 누적 실패 횟수
+    pub failure_count: u64,
+}
+
+/// 작업 상태 스냅샷 (API 응답용).
+#[derive(Debug, Clone, Serialize)]
+pub struct TaskSnapshot {
+    /// 작업 이름 (고유)
+    pub name: String,
+    /// 작업 설명
+    pub description: String,
+    /// 실행 주기 (초)
+    pub interval_secs: u64,
+    /// 일시 정지 여부
+    pub paused: bool,
+    /// 다음 예정 실행 시각 (일시 정지 중이거나 실행 기록이 없으면 None)
+    pub next_run_at: Option<DateTime<Utc>>,
+    #[serde(flatten)]
+    pub stats: TaskRunStats,
+}
+
+#[derive(Debug)]
+struct TaskEntry {
+    name: String,
+    description: String,
+    interval: Duration,
+    paused: AtomicBool,
+    trigger: Notify,
+    stats: Mutex<TaskRunStats>,
+}
+
+impl TaskEntry {
+    fn snapshot(&self) -> TaskSnapshot {
+        let stats = self.stats.lock().expect("task stats poisoned").clone();
+        let paused = self.paused.load(Ordering::Relaxed);
+        let next_run_at = if paused {
+            None
+        } else {
+            stats.last_started_at.and_then(|at| {
+                chrono::Duration::from_std(self.interval)
+                    .ok()
+                    .map(|d| at + d)
+            })
+        };
+        TaskSnapshot {
+            name: self.name.clone(),
+            description: self.description.clone(),
+            interval_secs: self.interval.as_secs(),
+            paused,
+            next_run_at,
+            stats,
+        }
+    }
+}
+
+/// 백그라운드 작업 레지스트리.
+#[derive(Debug, Default)]
+pub struct TaskRegistry {
+    tasks: RwLock<BTreeMap<String, Arc<TaskEntry>>>,
+}
+
+impl TaskRegistry {
+    /// 빈 레지스트리 생성.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 작업 등록.
+    ///
+    /// 같은 이름으로 다시 등록하면 기존 실행 기록과 일시 정지 상태를 유지한 채
+    /// 설명과 주기만 갱신합니다.
+    pub fn register(
+        &self,
+        name: impl Into<String>,
+        description: impl Into<String>,
+        interval: Duration,
+    ) -> TaskHandle {
+        let name = name.into();
+        let mut tasks = self.tasks.write().expect("task registry poisoned");
+
+        let (paused, stats) = tasks
+            .get(&name)
+            .map(|old| {
+                (
+                    old.paused.load(Ordering::Relaxed),
+                    old.stats.lock().expect("task stats poisoned").clone(),
+                )
+            })
+            .unwrap_or_default();
+
+        let entry = Arc::new(TaskEntry {
+            name: name.clone(),
+            description: description.into(),
+            interval,
+            paused: AtomicBool::new(paused),
+            trigger: Notify::new(),
+            stats: Mutex::new(stats),
+        });
+        tasks.insert(name, Arc::clone(&entry));
+
+        TaskHandle { entry }
+    }
+
+    fn entry(&self, name: &str) -> Option<Arc<TaskEntry>> {
+        self.tasks
+            .read()
+            .expect("task registry poisoned")
+            .get(name)
+            .cloned()
+    }
+
+    /// 등록된 모든 작업 (이름순).
+    pub fn list(&self) -> Vec<TaskSnapshot> {
+        self.tasks
+            .read()
+            .expect("task registry poisoned")
+            .values()
+            .map(|entry| entry.snapshot())
+            .collect()
+    }
+
+    /// 작업 상태 조회.
+    pub fn get(&self, name: &str) -> Option<TaskSnapshot> {
+        self.entry(name).map(|entry| entry.snapshot())
+    }
+
+    /// 작업 일시 정지/재개.
+    pub fn set_paused(&self, name: &str, paused: bool) -> Option<TaskSnapshot> {
+        let entry = self.entry(name)?;
+        entry.paused.store(paused, Ordering::Relaxed);
+        Some(entry.snapshot())
+    }
+
+    /// 작업 즉시 실행 요청.
+    ///
+    /// 실행 중이면 현재 회차가 끝난 직후 한 번 더 실행합니다.
+    pub fn trigger(&self, name: &str) -> Option<TaskSnapshot> {
+        let entry = self.entry(name)?;
+        entry.trigger.notify_one();
+        Some(entry.snapshot())
+    }
+}
+
+/// 서비스 루프에서 사용하는 작업 핸들.
+#[derive(Debug, Clone)]
+pub struct TaskHandle {
+    entry: Arc<TaskEntry>,
+}
+
+impl TaskHandle {
+    /// 작업 이름.
+    pub fn name(&self) -> &str {
+        &self.entry.name
+    }
+
+    /// 일시 정지 여부.
+    pub fn is_paused(&self) -> bool {
+        self.entry.paused.load(Ordering::Relaxed)
+    }
+
+    /// 다음 실행 시점까지 대기 (취소 안전).
+    ///
+    /// 주기 틱이 도래했고 일시 정지 상태가 아니거나, 수동 실행 요청이 들어오면 반환합니다.
+    pub async fn tick(&self, ticker: &mut Interval) -> TaskTrigger {
+        loop {
+            tokio::select! {
+                _ = ticker.tick() => {
+                    if !self.is_paused() {
+                        return TaskTrigger::Schedule;
+                    }
+                }
+                _ = self.entry.trigger.notified() => return TaskTrigger::Manual,
+            }
+        }
+    }
+
+    /// 다음 실행 시점까지 대기. 종료 신호를 받으면 `None`을 반환합니다.
+    pub async fn wait_next(
+        &self,
+        ticker: &mut Interval,
+        shutdown: &CancellationToken,
+    ) -> Option<TaskTrigger> {
+        tokio::select! {
+            _ = shutdown.cancelled() => None,
+            trigger = self.tick(ticker) => Some(trigger),
+        }
+    }
+
+    /// 한 회차 실행 시작.
+    ///
+    /// 반환된 [`TaskRun`]이 drop될 때 종료 시각과 소요 시간이 기록됩니다.
+    pub fn begin(&self) -> TaskRun {
+        {
+            let mut stats = self.entry.stats.lock().expect("task stats poisoned");
+            stats.running = true;
+            stats.last_started_at = Some(Utc::now());
+        }
+        TaskRun {
+            entry: Arc::clone(&self.entry),
+            started: Instant::now(),
+            error: None,
+        }
+    }
+}
+
+/// 실행 중인 회차. drop 시 실행 기록을 남깁니다.
+#[derive(Debug)]
+pub struct TaskRun {
+    entry: Arc<TaskEntry>,
+    started: Instant,
+    error: Option<String>,
+}
+
+impl TaskRun {
+    /// 회차 실패 기록.
+    pub fn fail(&mut self, error: impl Display) {
+        self.error = Some(error.to_string());
+    }
+}
+
+impl Drop for TaskRun {
+    fn drop(&mut self) {
+        let elapsed = self.started.elapsed();
+        let mut stats = self.entry.stats.lock().expect("task stats poisoned");
+        stats.running = false;
+        stats.last_finished_at = Some(Utc::now());
+        stats.last_duration_ms = Some(u64::try_from(elapsed.as_millis()).unwrap_or(u64::MAX));
+        stats.run_count += 1;
+        if self.error.is_some() {
+            stats.failure_count += 1;
+        }
+        stats.last_error = self.error.take();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_records_stats() {
+        let registry = TaskRegistry::new();
+        let task = registry.register("dca_scheduler", "DCA", Duration::from_secs(300));
+
+        drop(task.begin());
+        let mut run = task.begin();
+        assert!(registry.get("dca_scheduler").unwrap().stats.running);
+        run.fail("db down");
+        drop(run);
+
+        let snapshot = registry.get("dca_scheduler").unwrap();
+        assert!(!snapshot.stats.running);
+        assert_eq!(snapshot.stats.run_count, 2);
+        assert_eq!(snapshot.stats.failure_count, 1);
+        assert_eq!(snapshot.stats.last_error.as_deref(), Some("db down"));
+        assert!(snapshot.stats.last_duration_ms.is_some());
+        assert!(snapshot.next_run_at.is_some());
+    }
+
+    #[test]
+    fn test_reregister_keeps_state() {
+        let registry = TaskRegistry::new();
+        let task = registry.register("a", "first", Duration::from_secs(60));
+        drop(task.begin());
+        registry.set_paused("a", true).unwrap();
+
+        registry.register("a", "second", Duration::from_secs(30));
+        let snapshot = registry.get("a").unwrap();
+        assert!(snapshot.paused);
+        assert_eq!(snapshot.description, "second");
+        assert_eq!(snapshot.stats.run_count, 1);
+        assert!(snapshot.next_run_at.is_none());
+        assert!(registry.trigger("missing").is_none());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_paused_task_runs_only_on_trigger() {
+        let registry = TaskRegistry::new();
+        let task = registry.register("a", "", Duration::from_secs(10));
+        let shutdown = CancellationToken::new();
+        let mut ticker = tokio::time::interval(Duration::from_secs(10));
+
+        // 첫 틱은 즉시 도래
+        assert_eq!(
+            task.wait_next(&mut ticker, &shutdown).await,
+            Some(TaskTrigger::Schedule)
+        );
+
+        registry.set_paused("a", true);
+        let waited = tokio::time::timeout(
+            Duration::from_secs(35),
+            task.wait_next(&mut ticker, &shutdown),
+        )
+        .await;
+        assert!(waited.is_err(), "paused task must skip ticks");
+
+        registry.trigger("a");
+        assert_eq!(
+            task.wait_next(&mut ticker, &shutdown).await,
+            Some(TaskTrigger::Manual)
+        );
+
+        shutdown.cancel();
+        assert_eq!(task.wait_next(&mut ticker, &shutdown).await, None);
+    }
+}
@@ -0,0 +1,119 @@
+//! 종목 거래 상태(거래정지, VI) 반영 서비스.
+//!
+//! 실시간 시세 어그리게이터가 브로드캐스트한 `TradingStatus` 메시지를 구독하여
+//! 전략 엔진(컨텍스트 갱신 및 `on_trading_status` 콜백)과
+//! 실행기(거래정지/VI 종목 주문 보류)에 반영합니다.
+//!
+//! 거래소 스트림 재연결/복구(`StreamStatus`) 메시지도 같은 경로로 받아
+//! 전략의 `on_stream_status` 콜백에 전달하므로, 전략이 시세 공백 구간을 인지할 수 있습니다.
+
+use std::sync::Arc;
+
+use tokio::sync::broadcast::error::RecvError;
+use tokio_util::sync::CancellationToken;
+use tracing::{info, warn};
+use trader_core::{StreamStatus, TradingStatusEvent};
+
+use crate::state::AppState;
+use crate::websocket::{ServerMessage, SharedSubscriptionManager};
+
+/// 거래 상태 이벤트를 전략 엔진과 실행기에 반영.
+pub async fn apply_trading_status(state: &AppState, event: &TradingStatusEvent) {
+    state.executor.read().await.set_trading_status(event).await;
+    state
+        .strategy_engine
+        .read()
+        .await
+        .update_trading_status(event)
+        .await;
+}
+
+/// 스트림 재연결 상태를 전략 엔진에 전달.
+pub async fn apply_stream_status(state: &AppState, status: &StreamStatus) {
+    state
+        .strategy_engine
+        .read()
+        .await
+        .update_stream_status(status)
+        .await;
+}
+
+/// 거래 상태 반영 서비스 시작.
+///
+/// # Arguments
+///
+/// * `state` - 애플리케이션 상태 (전략 엔진 및 실행기)
+/// * `subscriptions` - 시세가 브로드캐스트되는 WebSocket 구독 관리자
+/// * `shutdown` - Graceful shutdown을 위한 CancellationToken
+///
+/// # Returns
+///
+/// 백그라운드 task의 JoinHandle
+pub fn start_trading_status_monitor(
+    state: Arc<AppState>,
+    subscriptions: SharedSubscriptionManager,
+    shutdown: CancellationToken,
+) -> tokio::task::JoinHandle<()> {
+    let mut messages = subscriptions.receiver();
+
+    tokio::spawn(async move {
+        info!("Trading status monitor started");
+
+        loop {
+            let message = tokio::select! {
+                _ = shutdown.cancelled() => {
+                    info!("Trading status monitor stopped");
+                    break;
+                }
+                received = messages.recv() => match received {
+                    Ok(message) => message,
+                    Err(RecvError::Lagged(skipped)) => {
+                        // 시세 폭주 시 상태 메시지가 누락될 수 있음
+                        warn!(skipped, "Trading status monitor lagged behind broadcasts");
+                        continue;
+                    }
+                    Err(RecvError::Closed) => break,
+                },
+            };
+
+            match message {
+                ServerMessage::TradingStatus(data) => {
+                    apply_trading_status(&state, &data.to_event()).await;
+                }
+                ServerMessage::StreamStatus(data) => {
+                    apply_stream_status(&state, &data.to_status()).await;
+                }
+                _ => {}
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::create_test_state;
+    use trader_core::TradingStatus;
+
+    #[tokio::test]
+    async fn test_apply_trading_status() {
+        let state = create_test_state();
+        let event = TradingStatusEvent::new("005930", TradingStatus::Halted);
+
+        apply_trading_status(&state, &event).await;
+
+        assert_eq!(
+            state.executor.read().await.trading_status("005930").await,
+            TradingStatus::Halted
+        );
+        assert_eq!(
+            state
+                .strategy_engine
+                .read()
+                .await
+                .get_trading_statuses()
+                .await,
+            vec![event]
+        );
+    }
+}
@@ -0,0 +1,378 @@
+//! 관심종목 알림 평가 서비스.
+//!
+//! 활성화된 관심종목 알림 규칙(`watchlist_alert`)을 주기적으로 최신 캔들로 평가하고,
+//! 조건이 충족되면 [`build_notifier`]로 만든 알림 관리자를 통해 전송합니다.
+//!
+//! - 가격/RSI 돌파 규칙은 직전 평가값과 비교하여 임계값을 넘는 순간에만 발동합니다.
+//! - 변동률 규칙은 기준가 대비 변동률이 임계값 이상이면 발동하고 기준가를 현재가로 갱신합니다.
+//! - 발동 후 `cooldown_minutes` 동안은 다시 알리지 않습니다 (평가값은 계속 갱신).
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use sqlx::PgPool;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, info, warn};
+use trader_analytics::{IndicatorEngine, RsiParams};
+use trader_core::{Kline, Timeframe};
+use trader_data::cache::CachedHistoricalDataProvider;
+use trader_notification::NotificationManager;
+
+use crate::repository::{WatchlistAlertRecord, WatchlistAlertRepository, WatchlistAlertRule};
+use crate::services::notification_digest::build_notifier;
+use crate::state::AppState;
+
+/// 규칙 평가에 사용할 캔들 수.
+const KLINE_LOOKBACK: usize = 200;
+
+/// 관심종목 알림 서비스 설정.
+#[derive(Debug, Clone)]
+pub struct WatchlistAlertConfig {
+    /// 규칙 평가 주기
+    pub poll_interval: Duration,
+}
+
+impl WatchlistAlertConfig {
+    /// 환경변수에서 설정 로드.
+    ///
+    /// `WATCHLIST_ALERT_ENABLED=false`이면 `None`을 반환합니다.
+    pub fn from_env() -> Option<Self> {
+        let enabled = std::env::var("WATCHLIST_ALERT_ENABLED")
+            .map(|v| v.to_lowercase() != "false")
+            .unwrap_or(true);
+        if !enabled {
+            return None;
+        }
+
+        let poll_interval = std::env::var("WATCHLIST_ALERT_POLL_SECS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .filter(|secs| *secs > 0)
+            .map(Duration::from_secs)
+            .unwrap_or(Duration::from_secs(60));
+
+        Some(Self { poll_interval })
+    }
+}
+
+/// 규칙 한 건의 평가 결과.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AlertOutcome {
+    /// 조건 충족 여부
+    pub triggered: bool,
+    /// 알림에 표시할 값 (가격, 기준가 대비 변동률 %, RSI)
+    pub value: Decimal,
+    /// 저장할 pct_move 기준가 (변경 없으면 None)
+    pub reference_price: Option<Decimal>,
+}
+
+/// 규칙 평가.
+///
+/// # Arguments
+/// * `observed` - 이번 평가값 (pct_move/가격 규칙은 종가, RSI 규칙은 RSI)
+/// * `last_value` - 직전 평가값 (없으면 돌파 규칙은 발동하지 않음)
+/// * `reference_price` - pct_move 기준가 (없으면 현재가를 기준가로 설정)
+pub fn evaluate_alert(
+    rule: WatchlistAlertRule,
+    threshold: Decimal,
+    observed: Decimal,
+    last_value: Option<Decimal>,
+    reference_price: Option<Decimal>,
+) -> AlertOutcome {
+    match rule {
+        WatchlistAlertRule::PriceAbove | WatchlistAlertRule::RsiAbove => AlertOutcome {
+            triggered: last_value.is_some_and(|last| last <= threshold) && observed > threshold,
+            value: observed,
+            reference_price: None,
+        },
+        WatchlistAlertRule::PriceBelow | WatchlistAlertRule::RsiBelow => AlertOutcome {
+            triggered: last_value.is_some_and(|last| last >= threshold) && observed < threshold,
+            value: observed,
+            reference_price: None,
+        },
+        WatchlistAlertRule::PctMove => {
+            let Some(reference) = reference_price.filter(|p| *p > Decimal::ZERO) else {
+                return AlertOutcome {
+                    triggered: false,
+                    value: Decimal::ZERO,
+                    reference_price: Some(observed),
+                };
+            };
+
+            let change_pct = (observed - reference) / reference * Decimal::ONE_HUNDRED;
+            let triggered = change_pct.abs() >= threshold;
+            AlertOutcome {
+                triggered,
+                value: change_pct.round_dp(4),
+                reference_price: triggered.then_some(observed),
+            }
+        }
+    }
+}
+
+/// 재알림 대기 중인지 확인.
+pub fn in_cooldown(
+    last_triggered_at: Option<DateTime<Utc>>,
+    cooldown_minutes: i32,
+    now: DateTime<Utc>,
+) -> bool {
+    last_triggered_at
+        .is_some_and(|at| now < at + chrono::Duration::minutes(i64::from(cooldown_minutes.max(0))))
+}
+
+/// 캔들에서 규칙 평가값 계산 (RSI 규칙은 마지막 RSI, 그 외는 마지막 종가).
+fn observed_value(
+    engine: &IndicatorEngine,
+    rule: WatchlistAlertRule,
+    rsi_period: i32,
+    klines: &[Kline],
+) -> Result<Decimal, String> {
+    let closes: Vec<Decimal> = klines.iter().map(|k| k.close).collect();
+    if !rule.uses_rsi() {
+        return closes
+            .last()
+            .copied()
+            .ok_or_else(|| "No klines".to_string());
+    }
+
+    let period = usize::try_from(rsi_period).map_err(|_| "Invalid RSI period".to_string())?;
+    engine
+        .rsi(&closes, RsiParams { period })
+        .map_err(|e| e.to_string())?
+        .last()
+        .copied()
+        .flatten()
+        .map(|rsi| rsi.round_dp(4))
+        .ok_or_else(|| "Insufficient klines for RSI".to_string())
+}
+
+/// 규칙 한 건 평가 및 결과 기록.
+async fn evaluate_rule(
+    pool: &PgPool,
+    notifier: &NotificationManager,
+    engine: &IndicatorEngine,
+    alert: &WatchlistAlertRecord,
+    klines: &Result<Vec<Kline>, String>,
+) -> Result<(), sqlx::Error> {
+    let evaluated = alert.parsed_rule().and_then(|rule| {
+        let klines = klines.as_ref().map_err(Clone::clone)?;
+        let observed = observed_value(engine, rule, alert.rsi_period, klines)?;
+        let price = klines.last().map(|k| k.close).unwrap_or(observed);
+        Ok((rule, observed, price))
+    });
+
+    let (rule, observed, price) = match evaluated {
+        Ok(evaluated) => evaluated,
+        Err(e) => {
+            debug!(id = %alert.id, error = %e, "Watchlist alert evaluation failed");
+            return WatchlistAlertRepository::record_evaluation(
+                pool,
+                alert.id,
+                None,
+                None,
+                Some(&e),
+            )
+            .await;
+        }
+    };
+
+    let outcome = evaluate_alert(
+        rule,
+        alert.threshold,
+        observed,
+        alert.last_value,
+        alert.reference_price,
+    );
+
+    if !outcome.triggered
+        || in_cooldown(alert.last_triggered_at, alert.cooldown_minutes, Utc::now())
+    {
+        return WatchlistAlertRepository::record_evaluation(
+            pool,
+            alert.id,
+            Some(observed),
+            outcome.reference_price,
+            None,
+        )
+        .await;
+    }
+
+    info!(
+        id = %alert.id,
+        symbol = %alert.symbol,
+        rule = rule.as_str(),
+        threshold = %alert.threshold,
+        value = %outcome.value,
+        "Watchlist alert triggered"
+    );
+    WatchlistAlertRepository::record_trigger(pool, alert.id, observed, outcome.reference_price)
+        .await?;
+
+    if let Err(e) = notifier
+        .notify_watchlist_alert(
+            &alert.watchlist_name,
+            &alert.symbol,
+            rule.as_str(),
+            alert.threshold,
+            outcome.value,
+            price,
+        )
+        .await
+    {
+        warn!(id = %alert.id, error = %e, "Failed to send watchlist alert");
+    }
+
+    Ok(())
+}
+
+/// 활성 규칙 전체를 한 번 평가.
+async fn evaluate_enabled_alerts(
+    pool: &PgPool,
+    provider: &CachedHistoricalDataProvider,
+    notifier: &NotificationManager,
+    engine: &IndicatorEngine,
+) -> Result<usize, sqlx::Error> {
+    let alerts = WatchlistAlertRepository::list_enabled(pool).await?;
+
+    // 같은 종목/타임프레임의 캔들은 한 번만 조회
+    let mut klines_cache: HashMap<(String, String), Result<Vec<Kline>, String>> = HashMap::new();
+
+    for alert in &alerts {
+        let key = (alert.symbol.clone(), alert.timeframe.clone());
+        if !klines_cache.contains_key(&key) {
+            let klines = match alert.timeframe.parse::<Timeframe>() {
+                Ok(timeframe) => provider
+                    .get_klines(&alert.symbol, timeframe, KLINE_LOOKBACK)
+                    .await
+                    .map_err(|e| format!("Failed to load klines: {}", e)),
+                Err(e) => Err(e),
+            };
+            klines_cache.insert(key.clone(), klines);
+        }
+
+        evaluate_rule(pool, notifier, engine, alert, &klines_cache[&key]).await?;
+    }
+
+    Ok(alerts.len())
+}
+
+/// 관심종목 알림 서비스 시작.
+///
+/// # Arguments
+///
+/// * `state` - 애플리케이션 상태 (캔들 데이터 Provider)
+/// * `pool` - 알림 규칙 DB
+/// * `config` - 서비스 설정
+/// * `shutdown` - Graceful shutdown을 위한 CancellationToken
+///
+/// # Returns
+///
+/// 백그라운드 task의 JoinHandle
+pub fn start_watchlist_alert_service(
+    state: Arc<AppState>,
+    pool: PgPool,
+    config: WatchlistAlertConfig,
+    shutdown: CancellationToken,
+) -> tokio::task::JoinHandle<()> {
+    let provider = state
+        .data_provider
+        .clone()
+        .unwrap_or_else(|| Arc::new(CachedHistoricalDataProvider::new(pool.clone())));
+
+    let notifier = build_notifier();
+
+    let task = state.tasks.register(
+        "watchlist_alerts",
+        "관심종목 가격/지표 알림 평가",
+        config.poll_interval,
+    );
+
+    tokio::spawn(async move {
+        info!(
+            poll_secs = config.poll_interval.as_secs(),
+            "Watchlist alert service started"
+        );
+        let engine = IndicatorEngine::new();
+        let mut ticker = tokio::time::interval(config.poll_interval);
+
+        loop {
+            if task.wait_next(&mut ticker, &shutdown).await.is_none() {
+                info!("Watchlist alert service stopped");
+                break;
+            }
+            let mut run = task.begin();
+
+            match evaluate_enabled_alerts(&pool, &provider, &notifier, &engine).await {
+                Ok(evaluated) => debug!(evaluated, "Watchlist alerts evaluated"),
+                Err(e) => {
+                    warn!(error = %e, "Failed to evaluate watchlist alerts");
+                    run.fail(e);
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_evaluate_price_cross() {
+        let above = WatchlistAlertRule::PriceAbove;
+        // 첫 평가는 기준값만 기록
+        assert!(!evaluate_alert(above, dec!(100), dec!(105), None, None).triggered);
+        assert!(evaluate_alert(above, dec!(100), dec!(105), Some(dec!(99)), None).triggered);
+        assert!(evaluate_alert(above, dec!(100), dec!(101), Some(dec!(100)), None).triggered);
+        // 이미 위에 있으면 다시 발동하지 않음
+        assert!(!evaluate_alert(above, dec!(100), dec!(110), Some(dec!(105)), None).triggered);
+
+        let below = WatchlistAlertRule::RsiBelow;
+        let outcome = evaluate_alert(below, dec!(30), dec!(28.5), Some(dec!(31)), None);
+        assert!(outcome.triggered);
+        assert_eq!(outcome.value, dec!(28.5));
+        assert!(!evaluate_alert(below, dec!(30), dec!(30), Some(dec!(31)), None).triggered);
+    }
+
+    #[test]
+    fn test_evaluate_pct_move() {
+        let rule = WatchlistAlertRule::PctMove;
+
+        // 기준가가 없으면 현재가를 기준가로 설정
+        let first = evaluate_alert(rule, dec!(5), dec!(100), None, None);
+        assert!(!first.triggered);
+        assert_eq!(first.reference_price, Some(dec!(100)));
+
+        let small = evaluate_alert(rule, dec!(5), dec!(104), Some(dec!(104)), Some(dec!(100)));
+        assert!(!small.triggered);
+        assert_eq!(small.value, dec!(4));
+        assert_eq!(small.reference_price, None);
+
+        // 하락도 절대값으로 판정하고 기준가 갱신
+        let drop = evaluate_alert(rule, dec!(5), dec!(94), Some(dec!(104)), Some(dec!(100)));
+        assert!(drop.triggered);
+        assert_eq!(drop.value, dec!(-6));
+        assert_eq!(drop.reference_price, Some(dec!(94)));
+    }
+
+    #[test]
+    fn test_in_cooldown() {
+        let now = Utc::now();
+        assert!(!in_cooldown(None, 60, now));
+        assert!(in_cooldown(
+            Some(now - chrono::Duration::minutes(30)),
+            60,
+            now
+        ));
+        assert!(!in_cooldown(
+            Some(now - chrono::Duration::minutes(60)),
+            60,
+            now
+        ));
+        assert!(!in_cooldown(Some(now), 0, now));
+    }
+}
@@ -0,0 +1,333 @@
+//! 아웃바운드 웹훅 발행 서비스.
+//!
+//! 실행기의 체결/포지션 이벤트와 WebSocket 전략 업데이트 메시지를 구독하여
+//! 등록된 웹훅(`outbound_webhook`)으로 전송하고 결과를 전송 로그에 기록합니다.
+//!
+//! # 이벤트 타입
+//!
+//! - `order.filled`, `order.partially_filled`
+//! - `position.opened`, `position.updated`, `position.closed`
+//! - `strategy.<event>` (예: `strategy.started`, `strategy.stopped`, `strategy.order_circuit_open`)
+//! - `kline.closed`: 완성된 캔들 (TradingView 호환 바 형식, 외부 차트 연동용)
+//!
+//! `kline.closed`는 빈도가 높아 `kline.closed` 또는 `kline.*`로 명시적으로
+//! 구독한 웹훅에만 전송합니다 (빈 필터나 `*`로는 받지 않음).
+
+use std::sync::Arc;
+
+use serde_json::json;
+use sqlx::PgPool;
+use tokio::sync::broadcast::error::RecvError;
+use tokio_util::sync::CancellationToken;
+use tracing::{info, warn};
+use trader_core::Timeframe;
+use trader_execution::ExecutionEvent;
+use trader_notification::{WebhookClient, WebhookEndpoint, WebhookEvent};
+
+use crate::repository::OutboundWebhookRepository;
+use crate::state::AppState;
+use crate::websocket::ServerMessage;
+
+/// 실행 이벤트를 웹훅 이벤트로 변환.
+pub fn execution_webhook_event(event: &ExecutionEvent) -> WebhookEvent {
+    let event_type = match event {
+        ExecutionEvent::OrderFilled { complete: true, .. } => "order.filled",
+        ExecutionEvent::OrderFilled { .. } => "order.partially_filled",
+        ExecutionEvent::PositionChanged { closed: true, .. } => "position.closed",
+        ExecutionEvent::PositionChanged { opened: true, .. } => "position.opened",
+        ExecutionEvent::PositionChanged { .. } => "position.updated",
+    };
+
+    let mut data = serde_json::to_value(event).unwrap_or_default();
+    if let Some(object) = data.as_object_mut() {
+        object.remove("type");
+    }
+
+    WebhookEvent::new(event_type, data)
+}
+
+/// 전략 업데이트 메시지를 웹훅 이벤트로 변환.
+///
+/// 전략 업데이트가 아닌 메시지는 `None`을 반환합니다.
+pub fn strategy_webhook_event(message: &ServerMessage) -> Option<WebhookEvent> {
+    let ServerMessage::StrategyUpdate(update) = message else {
+        return None;
+    };
+
+    Some(WebhookEvent::new(
+        format!("strategy.{}", update.event),
+        serde_json::to_value(update).unwrap_or_default(),
+    ))
+}
+
+/// 명시적으로 구독한 웹훅에만 전송하는 이벤트 접두사.
+const OPT_IN_EVENT_PREFIXES: &[&str] = &["kline."];
+
+/// 완성된 캔들 메시지를 웹훅 이벤트로 변환.
+///
+/// TradingView UDF 바 형식(`time`은 초 단위 시작 시각)에 `resolution`을 더해
+/// 외부 차트 백엔드가 그대로 저장할 수 있게 합니다.
+/// 캔들이 아니거나 아직 진행 중인 캔들이면 `None`을 반환합니다.
+pub fn kline_webhook_event(message: &ServerMessage) -> Option<WebhookEvent> {
+    let ServerMessage::Kline(kline) = message else {
+        return None;
+    };
+    if !kline.is_closed {
+        return None;
+    }
+
+    let resolution = kline
+        .timeframe
+        .parse::<Timeframe>()
+        .map(|tf| tf.to_udf_resolution())
+        .ok();
+
+    Some(WebhookEvent::new(
+        "kline.closed",
+        json!({
+            "symbol": kline.symbol,
+            "timeframe": kline.timeframe,
+            "resolution": resolution,
+            "time": kline.open_time / 1000,
+            "open": kline.open,
+            "high": kline.high,
+            "low": kline.low,
+            "close": kline.close,
+            "volume": kline.volume,
+            "open_time": kline.open_time,
+            "close_time": kline.close_time,
+        }),
+    ))
+}
+
+/// WebSocket 메시지를 웹훅 이벤트로 변환 (전략 업데이트, 완성된 캔들).
+pub fn message_webhook_event(message: &ServerMessage) -> Option<WebhookEvent> {
+    strategy_webhook_event(message).or_else(|| kline_webhook_event(message))
+}
+
+/// 웹훅이 이벤트를 받아야 하는지 확인 (고빈도 이벤트는 명시적 구독만).
+fn endpoint_accepts(endpoint: &WebhookEndpoint, event: &str) -> bool {
+    if OPT_IN_EVENT_PREFIXES
+        .iter()
+        .any(|prefix| event.starts_with(prefix))
+    {
+        endpoint.accepts_explicitly(event)
+    } else {
+        endpoint.accepts(event)
+    }
+}
+
+/// 이벤트를 구독 중인 모든 활성 웹훅으로 전송.
+///
+/// 전송은 웹훅별로 별도 task에서 수행되어 느린 수신자가 다른 이벤트를 지연시키지 않습니다.
+pub async fn dispatch_webhook_event(pool: &PgPool, client: &WebhookClient, event: WebhookEvent) {
+    let webhooks = match OutboundWebhookRepository::list_enabled(pool).await {
+        Ok(webhooks) => webhooks,
+        Err(e) => {
+            warn!(error = %e, "Failed to load outbound webhooks");
+            return;
+        }
+    };
+
+    let event = Arc::new(event);
+    for webhook in webhooks {
+        let endpoint = webhook.endpoint();
+        if !endpoint_accepts(&endpoint, &event.event) {
+            continue;
+        }
+
+        let pool = pool.clone();
+        let client = client.clone();
+        let event = Arc::clone(&event);
+        tokio::spawn(async move {
+            let delivery = client.deliver(&endpoint, &event).await;
+            if let Err(e) =
+                OutboundWebhookRepository::record_delivery(&pool, webhook.id, &event, &delivery)
+                    .await
+            {
+                warn!(webhook = %webhook.name, error = %e, "Failed to record webhook delivery");
+            }
+        });
+    }
+}
+
+/// 웹훅 발행 서비스 시작.
+///
+/// # Arguments
+///
+/// * `state` - 애플리케이션 상태 (실행기, WebSocket 구독 관리자)
+/// * `pool` - 웹훅 설정 및 전송 로그 DB
+/// * `shutdown` - Graceful shutdown을 위한 CancellationToken
+///
+/// # Returns
+///
+/// 백그라운드 task의 JoinHandle
+pub async fn start_webhook_publisher(
+    state: Arc<AppState>,
+    pool: PgPool,
+    shutdown: CancellationToken,
+) -> tokio::task::JoinHandle<()> {
+    let mut executions = state.executor.read().await.subscribe_events();
+    let mut messages = state.subscriptions.as_ref().map(|subs| subs.receiver());
+    let client = WebhookClient::default();
+
+    tokio::spawn(async move {
+        info!("Outbound webhook publisher started");
+
+        loop {
+            let event = tokio::select! {
+                _ = shutdown.cancelled() => {
+                    info!("Outbound webhook publisher stopped");
+                    break;
+                }
+                received = executions.recv() => match received {
+                    Ok(event) => execution_webhook_event(&event),
+                    Err(RecvError::Lagged(skipped)) => {
+                        warn!(skipped, "Webhook publisher lagged behind execution events");
+                        continue;
+                    }
+                    Err(RecvError::Closed) => break,
+                },
+                received = async { messages.as_mut().expect("guarded").recv().await }, if messages.is_some() => {
+                    match received {
+                        Ok(message) => match message_webhook_event(&message) {
+                            Some(event) => event,
+                            None => continue,
+                        },
+                        Err(RecvError::Lagged(skipped)) => {
+                            warn!(skipped, "Webhook publisher lagged behind WebSocket messages");
+                            continue;
+                        }
+                        Err(RecvError::Closed) => {
+                            messages = None;
+                            continue;
+                        }
+                    }
+                }
+            };
+
+            dispatch_webhook_event(&pool, &client, event).await;
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::websocket::messages::KlineData;
+    use crate::websocket::StrategyUpdateData;
+    use chrono::Utc;
+    use rust_decimal_macros::dec;
+    use trader_core::Side;
+    use uuid::Uuid;
+
+    #[test]
+    fn test_execution_webhook_event() {
+        let fill = ExecutionEvent::OrderFilled {
+            order_id: Uuid::new_v4(),
+            strategy_id: Some("grid_1".to_string()),
+            ticker: "005930".to_string(),
+            side: Side::Buy,
+            fill_quantity: dec!(5),
+            fill_price: dec!(70000),
+            filled_quantity: dec!(5),
+            order_quantity: dec!(10),
+            complete: false,
+            timestamp: Utc::now(),
+        };
+        let event = execution_webhook_event(&fill);
+        assert_eq!(event.event, "order.partially_filled");
+        assert_eq!(event.data["ticker"], "005930");
+        assert!(event.data.get("type").is_none());
+
+        let position = |opened, closed| ExecutionEvent::PositionChanged {
+            strategy_id: None,
+            ticker: "005930".to_string(),
+            side: Side::Buy,
+            quantity: dec!(5),
+            entry_price: dec!(70000),
+            realized_pnl: dec!(0),
+            opened,
+            closed,
+            timestamp: Utc::now(),
+        };
+        assert_eq!(
+            execution_webhook_event(&position(true, false)).event,
+            "position.opened"
+        );
+        assert_eq!(
+            execution_webhook_event(&position(false, false)).event,
+            "position.updated"
+        );
+        assert_eq!(
+            execution_webhook_event(&position(false, true)).event,
+            "position.closed"
+        );
+    }
+
+    #[test]
+    fn test_strategy_webhook_event() {
+        let message = ServerMessage::StrategyUpdate(StrategyUpdateData {
+            strategy_id: "grid_1".to_string(),
+            name: "Grid".to_string(),
+            running: true,
+            event: "started".to_string(),
+            data: None,
+            timestamp: Utc::now().timestamp_millis(),
+        });
+        let event = strategy_webhook_event(&message).unwrap();
+        assert_eq!(event.event, "strategy.started");
+        assert_eq!(event.data["strategy_id"], "grid_1");
+
+        let welcome = ServerMessage::Welcome {
+            version: "1".to_string(),
+            timestamp: 0,
+        };
+        assert!(strategy_webhook_event(&welcome).is_none());
+    }
+
+    #[test]
+    fn test_kline_webhook_event() {
+        let kline = |is_closed| {
+            ServerMessage::Kline(KlineData {
+                symbol: "BTCUSDT".to_string(),
+                timeframe: "4h".to_string(),
+                open: dec!(100),
+                high: dec!(110),
+                low: dec!(95),
+                close: dec!(105),
+                volume: dec!(12.5),
+                open_time: 1_767_225_600_000,
+                close_time: 1_767_239_999_999,
+                is_closed,
+            })
+        };
+
+        let event = message_webhook_event(&kline(true)).unwrap();
+        assert_eq!(event.event, "kline.closed");
+        assert_eq!(event.data["symbol"], "BTCUSDT");
+        assert_eq!(event.data["resolution"], "240");
+        assert_eq!(event.data["time"], 1_767_225_600);
+        assert_eq!(
+            event.data["close"],
+            serde_json::to_value(dec!(105)).unwrap()
+        );
+
+        assert!(kline_webhook_event(&kline(false)).is_none());
+    }
+
+    #[test]
+    fn test_kline_events_are_opt_in() {
+        let endpoint = |events: &[&str]| WebhookEndpoint {
+            url: "http://localhost/hook".to_string(),
+            secret: None,
+            events: events.iter().map(|e| e.to_string()).collect(),
+        };
+
+        assert!(!endpoint_accepts(&endpoint(&[]), "kline.closed"));
+        assert!(!endpoint_accepts(&endpoint(&["*"]), "kline.closed"));
+        assert!(endpoint_accepts(&endpoint(&["kline.*"]), "kline.closed"));
+        assert!(endpoint_accepts(&endpoint(&[]), "order.filled"));
+        assert!(endpoint_accepts(&endpoint(&["*"]), "strategy.started"));
+    }
+}
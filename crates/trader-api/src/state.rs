@@ -7,20 +7,25 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 use tokio_util::sync::CancellationToken;
-use trader_analytics::ml::MlService;
+use trader_analytics::ml::{ExecutionProvider, MlService, MlServiceConfig};
 use trader_analytics::AnalyticsProviderImpl;
 use trader_core::crypto::CredentialEncryptor;
-use trader_core::{AnalyticsProvider, ExchangeProvider, StrategyContext};
+use trader_core::{
+    AnalyticsProvider, BrokerDowntimeCalendar, ExchangeProvider, MarketCalendar, StrategyContext,
+};
 use trader_data::cache::CachedHistoricalDataProvider;
 use trader_data::{RedisCache, RedisConfig, SymbolResolver};
 use trader_exchange::connector::kis::{KisKrClient, KisOAuth, KisUsClient};
 use trader_execution::OrderExecutor;
-use trader_risk::RiskManager;
+use trader_risk::{ConfigApprovalQueue, RiskManager};
 use trader_strategy::StrategyEngine;
 use uuid::Uuid;
 
+use crate::quota::QuotaTracker;
 use crate::repository::ExchangeProviderPair;
 use crate::services::context_sync::start_context_sync_service;
+use crate::services::paper_trading::{PaperTradingConfig, PaperTradingDesk};
+use crate::services::task_scheduler::TaskRegistry;
 use crate::websocket::{ServerMessage, SharedSubscriptionManager};
 
 /// 애플리케이션 공유 상태.
@@ -38,9 +43,25 @@ pub struct AppState {
     /// 주문 실행기 - 신호→주문 변환, 포지션 추적
     pub executor: Arc<RwLock<OrderExecutor>>,
 
+    /// 리스크 설정 변경 승인 대기열 (`RISK_CONFIG_REQUIRE_APPROVAL=true`면 2인 승인)
+    pub risk_config_approvals: Arc<RwLock<ConfigApprovalQueue>>,
+
+    /// 외부 신호 웹훅 인증 키 (`SIGNAL_WEBHOOK_SECRET`, 없으면 웹훅 비활성화)
+    pub signal_webhook_secret: Option<String>,
+
+    /// 서명 시각 허용 오차 안에서 이미 사용된 웹훅 서명 (서명 → 서명 시각, 재전송 거부용)
+    pub signal_webhook_signatures: Arc<RwLock<HashMap<String, i64>>>,
+
     /// 데이터베이스 연결 풀 (TimescaleDB/PostgreSQL)
+    ///
+    /// 주문 경로 쓰기용 Primary 풀 (짧은 쿼리 타임아웃)
     pub db_pool: Option<sqlx::PgPool>,
 
+    /// 분석/스크리닝 조회용 풀 (`DATABASE_READ_URL` 읽기 복제본, 긴 쿼리 타임아웃).
+    ///
+    /// 설정되지 않으면 [`AppState::analytics_db_pool`]이 Primary 풀을 반환합니다.
+    pub analytics_pool: Option<sqlx::PgPool>,
+
     /// Redis 캐시 (trader-data의 RedisCache 활용)
     /// 전략 목록, 심볼 정보, 백테스트 결과 등 캐싱에 사용
     pub cache: Option<Arc<RedisCache>>,
@@ -51,6 +72,14 @@ pub struct AppState {
     /// KIS 해외 주식 클라이언트 (한국투자증권 API - 미국 등)
     pub kis_us_client: Option<Arc<KisUsClient>>,
 
+    /// KRX/미국 휴장일 및 단축 거래일 달력 (시장 달력 동기화 서비스가 DB에서 적재)
+    ///
+    /// 적재되지 않은 연도는 주말 외 휴장일을 알 수 없으며, 정규장 시각으로 판정합니다.
+    pub market_calendar: Arc<RwLock<MarketCalendar>>,
+
+    /// 브로커 점검/장애 달력 (실행기와 공유, 점검/장애 모니터가 갱신)
+    pub broker_downtime: Arc<RwLock<BrokerDowntimeCalendar>>,
+
     /// credential_id별 거래소 Provider 캐시 (거래소 중립).
     ///
     /// 매 요청마다 새 Provider를 생성하면 토큰 발급 제한(1분 1회)에 걸리므로,
@@ -97,6 +126,15 @@ pub struct AppState {
     /// 계좌 정보, 포지션, 미체결 주문을 조회합니다.
     pub exchange_provider: Option<Arc<dyn ExchangeProvider>>,
 
+    /// 사용자별 리소스 할당량 (동시 백테스트, 등록 전략 수, 요청률, 저장 용량)
+    pub quotas: Arc<QuotaTracker>,
+
+    /// 주기 실행 백그라운드 작업 레지스트리 (실행 기록, 일시 정지/즉시 실행)
+    pub tasks: Arc<TaskRegistry>,
+
+    /// 모의투자 모드 전략의 가상 계좌 (시뮬레이션 거래소, 메모리 전용)
+    pub paper_trading: Arc<PaperTradingDesk>,
+
     /// 서버 시작 시간 (업타임 계산용)
     pub started_at: chrono::DateTime<chrono::Utc>,
 
@@ -123,16 +161,37 @@ impl AppState {
             .map(Arc::new);
 
         // ML 서비스 초기화 (기본 설정으로 시작, 필요시 ONNX 모델 로드)
-        let ml_service = MlService::with_defaults().expect("Failed to create MlService");
+        let ml_service =
+            MlService::new(ml_service_config_from_env()).expect("Failed to create MlService");
+
+        // 리스크 설정 변경 2인 승인 모드
+        let require_risk_approval = std::env::var("RISK_CONFIG_REQUIRE_APPROVAL")
+            .map(|v| matches!(v.to_lowercase().as_str(), "true" | "1"))
+            .unwrap_or(false);
+
+        // 외부 신호 웹훅 인증 키
+        let signal_webhook_secret = std::env::var("SIGNAL_WEBHOOK_SECRET")
+            .ok()
+            .filter(|s| !s.is_empty());
+
+        let broker_downtime = Arc::clone(executor.broker_downtime().calendar());
 
         Self {
             strategy_engine: Arc::new(RwLock::new(strategy_engine)),
             risk_manager: Arc::new(RwLock::new(risk_manager)),
             executor: Arc::new(RwLock::new(executor)),
+            risk_config_approvals: Arc::new(RwLock::new(ConfigApprovalQueue::new(
+                require_risk_approval,
+            ))),
+            signal_webhook_secret,
+            signal_webhook_signatures: Arc::new(RwLock::new(HashMap::new())),
             db_pool: None,
+            analytics_pool: None,
             cache: None,
             kis_kr_client: None,
             kis_us_client: None,
+            market_calendar: Arc::new(RwLock::new(MarketCalendar::new())),
+            broker_downtime,
             exchange_providers_cache: Arc::new(RwLock::new(HashMap::new())),
             kis_oauth_cache: Arc::new(RwLock::new(HashMap::new())),
             encryptor,
@@ -143,6 +202,9 @@ impl AppState {
             analytics_provider: None,
             strategy_context: None,
             exchange_provider: None,
+            quotas: Arc::new(QuotaTracker::new(None)),
+            tasks: Arc::new(TaskRegistry::new()),
+            paper_trading: Arc::new(PaperTradingDesk::new(PaperTradingConfig::from_env())),
             started_at: chrono::Utc::now(),
             version: env!("CARGO_PKG_VERSION").to_string(),
         }
@@ -161,14 +223,28 @@ impl AppState {
 
     /// 데이터베이스 연결 설정.
     ///
-    /// DB 연결이 설정되면 SymbolResolver도 자동으로 생성됩니다.
+    /// DB 연결이 설정되면 SymbolResolver와 할당량 추적기도 자동으로 생성됩니다.
     pub fn with_db_pool(mut self, pool: sqlx::PgPool) -> Self {
         // SymbolResolver 생성 (DB 연결 필요)
         self.symbol_resolver = Some(Arc::new(SymbolResolver::new(pool.clone())));
+        self.quotas = Arc::new(QuotaTracker::new(Some(pool.clone())));
         self.db_pool = Some(pool);
         self
     }
 
+    /// 분석/스크리닝 조회용 풀 설정.
+    pub fn with_analytics_pool(mut self, pool: sqlx::PgPool) -> Self {
+        self.analytics_pool = Some(pool);
+        self
+    }
+
+    /// 분석/스크리닝 조회용 풀 (없으면 Primary 풀).
+    ///
+    /// 복제 지연을 허용하는 읽기 전용 조회에만 사용합니다.
+    pub fn analytics_db_pool(&self) -> Option<&sqlx::PgPool> {
+        self.analytics_pool.as_ref().or(self.db_pool.as_ref())
+    }
+
     /// CachedHistoricalDataProvider 설정.
     ///
     /// 캔들 데이터를 캐싱하여 제공합니다.
@@ -259,6 +335,7 @@ impl AppState {
             exchange_provider,
             analytics_provider,
             strategy_context,
+            Arc::clone(&self.tasks),
             shutdown,
         ))
     }
@@ -547,6 +624,39 @@ impl AppState {
     }
 }
 
+/// 환경변수에서 ML 서비스 설정 로드.
+///
+/// - `ML_EXECUTION_PROVIDERS`: 추론 실행 프로바이더 우선순위 (예: "cuda,cpu")
+/// - `ML_DEVICE_ID`: GPU 장치 번호
+/// - `ML_INFERENCE_BATCH_SIZE`: 배치 추론 크기
+///
+/// 잘못된 값은 경고 후 기본값(CPU 전용)을 사용합니다.
+fn ml_service_config_from_env() -> MlServiceConfig {
+    let mut config = MlServiceConfig::default();
+
+    if let Ok(value) = std::env::var("ML_EXECUTION_PROVIDERS") {
+        match ExecutionProvider::parse_list(&value) {
+            Ok(providers) => config.predictor_config.execution_providers = providers,
+            Err(e) => tracing::warn!("Invalid ML_EXECUTION_PROVIDERS '{}': {}", value, e),
+        }
+    }
+    if let Some(device_id) = std::env::var("ML_DEVICE_ID")
+        .ok()
+        .and_then(|v| v.parse().ok())
+    {
+        config.predictor_config.device_id = device_id;
+    }
+    if let Some(batch_size) = std::env::var("ML_INFERENCE_BATCH_SIZE")
+        .ok()
+        .and_then(|v| v.parse::<usize>().ok())
+        .filter(|size| *size > 0)
+    {
+        config.predictor_config.batch_size = batch_size;
+    }
+
+    config
+}
+
 /// 테스트용 AppState 생성 헬퍼.
 ///
 /// 실제 DB 연결 없이 테스트할 수 있는 최소한의 상태를 생성합니다.
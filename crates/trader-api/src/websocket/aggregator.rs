@@ -15,11 +15,14 @@
 
 use tracing::{debug, error, info, warn};
 
-use trader_core::{OrderBook, Ticker};
+use trader_core::{
+    OrderBook, OrderBookMetrics, StreamStatus, Ticker, TradingStatusEvent, DEFAULT_METRICS_DEPTH,
+};
 use trader_exchange::traits::{MarketEvent, MarketStream};
 
 use super::messages::{
-    KlineData, OrderBookData, OrderBookLevel, ServerMessage, TickerData, TradeData,
+    KlineData, OrderBookData, OrderBookLevel, OrderBookMetricsData, ServerMessage,
+    StreamStatusData, TickerData, TradeData, TradingStatusData,
 };
 use super::subscriptions::SharedSubscriptionManager;
 
@@ -62,6 +65,9 @@ impl MarketDataAggregator {
                 MarketEvent::Kline(kline) => {
                     self.handle_kline(kline);
                 }
+                MarketEvent::TradingStatus(event) => {
+                    self.handle_trading_status(&event);
+                }
                 MarketEvent::Connected => {
                     info!("거래소 연결됨");
                     // 연결 상태 메시지 브로드캐스트 가능
@@ -70,6 +76,9 @@ impl MarketDataAggregator {
                     warn!("거래소 연결 끊김");
                     // 재연결 로직은 MarketStream 내부에서 처리
                 }
+                MarketEvent::StreamStatus(status) => {
+                    self.handle_stream_status(&status);
+                }
                 MarketEvent::Error(msg) => {
                     error!("거래소 에러: {}", msg);
                 }
@@ -79,6 +88,40 @@ impl MarketDataAggregator {
         warn!("MarketDataAggregator 종료 - 스트림 완료");
     }
 
+    /// 거래 상태(거래정지, VI) 이벤트 처리.
+    ///
+    /// 전략 엔진과 실행기 반영은 `trading_status` 서비스가 브로드캐스트를 구독하여 처리합니다.
+    fn handle_trading_status(&self, event: &TradingStatusEvent) {
+        info!(
+            symbol = %event.ticker,
+            status = %event.status,
+            "Trading status broadcast"
+        );
+
+        let message = ServerMessage::TradingStatus(TradingStatusData::from(event));
+        if let Err(e) = self.subscriptions.broadcast(message) {
+            debug!("Broadcast error: {}", e);
+        }
+    }
+
+    /// 스트림 재연결/복구 이벤트 처리.
+    ///
+    /// 시세 공백 구간을 클라이언트와 전략 엔진(`trading_status` 서비스 경유)에 알립니다.
+    fn handle_stream_status(&self, status: &StreamStatus) {
+        warn!(
+            source = %status.source,
+            state = %status.state,
+            attempt = status.attempt,
+            gap_ms = status.gap().map(|gap| gap.num_milliseconds()),
+            "Stream status broadcast"
+        );
+
+        let message = ServerMessage::StreamStatus(StreamStatusData::from(status));
+        if let Err(e) = self.subscriptions.broadcast(message) {
+            debug!("Broadcast error: {}", e);
+        }
+    }
+
     /// Ticker 이벤트 처리.
     fn handle_ticker(&self, ticker: Ticker) {
         let symbol = ticker.ticker.to_string();
@@ -153,6 +196,23 @@ impl MarketDataAggregator {
         if let Err(e) = self.subscriptions.broadcast(message) {
             debug!("Broadcast error: {}", e);
         }
+
+        self.handle_orderbook_metrics(&orderbook);
+    }
+
+    /// 호가 스냅샷에서 파생 지표를 계산해 브로드캐스트.
+    ///
+    /// 한쪽 호가가 비어 있으면 계산하지 않습니다.
+    fn handle_orderbook_metrics(&self, orderbook: &OrderBook) {
+        let Some(metrics) = OrderBookMetrics::from_orderbook(orderbook, DEFAULT_METRICS_DEPTH)
+        else {
+            return;
+        };
+
+        let message = ServerMessage::OrderBookMetrics(OrderBookMetricsData::from(&metrics));
+        if let Err(e) = self.subscriptions.broadcast(message) {
+            debug!("Broadcast error: {}", e);
+        }
     }
 
     /// Trade 이벤트 처리.
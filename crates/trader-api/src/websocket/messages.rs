@@ -5,6 +5,7 @@
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use trader_core::{OrderBookMetrics, StreamState, StreamStatus, TradingStatus, TradingStatusEvent};
 
 /// WebSocket 에러.
 #[derive(Debug, thiserror::Error)]
@@ -95,8 +96,14 @@ pub enum ServerMessage {
     Trade(TradeData),
     /// 호가창 데이터
     OrderBook(OrderBookData),
+    /// 호가창 파생 지표 (불균형, 마이크로프라이스, 깊이 가중 스프레드)
+    OrderBookMetrics(OrderBookMetricsData),
     /// 캔들스틱(Kline) 데이터
     Kline(KlineData),
+    /// 종목 거래 상태 변경 (거래정지, VI 발동/해제)
+    TradingStatus(TradingStatusData),
+    /// 거래소 스트림 재연결/복구 (시세 공백 구간 알림)
+    StreamStatus(StreamStatusData),
     /// 주문 업데이트
     OrderUpdate(OrderUpdateData),
     /// 포지션 업데이트
@@ -150,6 +157,97 @@ pub struct TickerData {
     pub timestamp: i64,
 }
 
+/// 종목 거래 상태 데이터.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TradingStatusData {
+    /// 심볼
+    pub symbol: String,
+    /// 거래 상태 (normal, halted, static_vi, dynamic_vi)
+    pub status: TradingStatus,
+    /// 사유 (거래정지 사유 등)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    /// 타임스탬프
+    pub timestamp: i64,
+}
+
+impl From<&TradingStatusEvent> for TradingStatusData {
+    fn from(event: &TradingStatusEvent) -> Self {
+        Self {
+            symbol: event.ticker.clone(),
+            status: event.status,
+            reason: event.reason.clone(),
+            timestamp: event.timestamp.timestamp_millis(),
+        }
+    }
+}
+
+impl TradingStatusData {
+    /// 거래 상태 이벤트로 변환.
+    pub fn to_event(&self) -> TradingStatusEvent {
+        TradingStatusEvent {
+            ticker: self.symbol.clone(),
+            status: self.status,
+            reason: self.reason.clone(),
+            timestamp: chrono::DateTime::from_timestamp_millis(self.timestamp)
+                .unwrap_or_else(chrono::Utc::now),
+        }
+    }
+}
+
+/// 거래소 스트림 연결 상태 데이터.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StreamStatusData {
+    /// 스트림 출처 (kis_kr, kis_us 등)
+    pub source: String,
+    /// 연결 상태 (reconnecting, recovered, failed)
+    pub state: StreamState,
+    /// 재연결 시도 횟수
+    pub attempt: u32,
+    /// 복원한 구독 수
+    pub resubscribed: usize,
+    /// 연결이 끊긴 시각 (시세 공백 시작, 밀리초)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disconnected_at: Option<i64>,
+    /// 끊김/포기 사유
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    /// 타임스탬프
+    pub timestamp: i64,
+}
+
+impl From<&StreamStatus> for StreamStatusData {
+    fn from(status: &StreamStatus) -> Self {
+        Self {
+            source: status.source.clone(),
+            state: status.state,
+            attempt: status.attempt,
+            resubscribed: status.resubscribed,
+            disconnected_at: status.disconnected_at.map(|t| t.timestamp_millis()),
+            reason: status.reason.clone(),
+            timestamp: status.timestamp.timestamp_millis(),
+        }
+    }
+}
+
+impl StreamStatusData {
+    /// 스트림 상태 이벤트로 변환.
+    pub fn to_status(&self) -> StreamStatus {
+        StreamStatus {
+            source: self.source.clone(),
+            state: self.state,
+            attempt: self.attempt,
+            disconnected_at: self
+                .disconnected_at
+                .and_then(chrono::DateTime::from_timestamp_millis),
+            resubscribed: self.resubscribed,
+            reason: self.reason.clone(),
+            timestamp: chrono::DateTime::from_timestamp_millis(self.timestamp)
+                .unwrap_or_else(chrono::Utc::now),
+        }
+    }
+}
+
 /// 캔들스틱(Kline) 데이터.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct KlineData {
@@ -280,6 +378,67 @@ pub struct OrderBookLevel {
     pub quantity: Decimal,
 }
 
+/// 호가창 파생 지표 데이터.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderBookMetricsData {
+    /// 심볼
+    pub symbol: String,
+    /// 계산에 사용한 호가 레벨 수
+    pub depth_levels: usize,
+    /// 중간 가격
+    pub mid_price: Decimal,
+    /// 잔량 가중 마이크로프라이스
+    pub microprice: Decimal,
+    /// 호가 불균형 (-1 ~ +1, 양수 = 매수 우위)
+    pub imbalance: Decimal,
+    /// 매수 잔량 합계
+    pub bid_depth: Decimal,
+    /// 매도 잔량 합계
+    pub ask_depth: Decimal,
+    /// 깊이 가중 스프레드 (가격 단위)
+    pub depth_weighted_spread: Decimal,
+    /// 깊이 가중 스프레드 (bp)
+    pub depth_weighted_spread_bps: Decimal,
+    /// 타임스탬프
+    pub timestamp: i64,
+}
+
+impl From<&OrderBookMetrics> for OrderBookMetricsData {
+    fn from(metrics: &OrderBookMetrics) -> Self {
+        Self {
+            symbol: metrics.ticker.clone(),
+            depth_levels: metrics.depth_levels,
+            mid_price: metrics.mid_price,
+            microprice: metrics.microprice,
+            imbalance: metrics.imbalance,
+            bid_depth: metrics.bid_depth,
+            ask_depth: metrics.ask_depth,
+            depth_weighted_spread: metrics.depth_weighted_spread,
+            depth_weighted_spread_bps: metrics.depth_weighted_spread_bps,
+            timestamp: metrics.timestamp.timestamp_millis(),
+        }
+    }
+}
+
+impl OrderBookMetricsData {
+    /// 호가창 파생 지표로 변환.
+    pub fn to_metrics(&self) -> OrderBookMetrics {
+        OrderBookMetrics {
+            ticker: self.symbol.clone(),
+            depth_levels: self.depth_levels,
+            mid_price: self.mid_price,
+            microprice: self.microprice,
+            imbalance: self.imbalance,
+            bid_depth: self.bid_depth,
+            ask_depth: self.ask_depth,
+            depth_weighted_spread: self.depth_weighted_spread,
+            depth_weighted_spread_bps: self.depth_weighted_spread_bps,
+            timestamp: chrono::DateTime::from_timestamp_millis(self.timestamp)
+                .unwrap_or_else(chrono::Utc::now),
+        }
+    }
+}
+
 /// 시뮬레이션 업데이트 데이터.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SimulationUpdateData {
@@ -352,6 +511,55 @@ mod tests {
         assert!(json.contains("INVALID_CHANNEL"));
     }
 
+    #[test]
+    fn test_orderbook_metrics_message() {
+        use rust_decimal_macros::dec;
+        use trader_core::{OrderBook, OrderBookLevel as CoreLevel};
+
+        let orderbook = OrderBook {
+            ticker: "005930".to_string(),
+            bids: vec![CoreLevel {
+                price: dec!(70000),
+                quantity: dec!(300),
+            }],
+            asks: vec![CoreLevel {
+                price: dec!(70100),
+                quantity: dec!(100),
+            }],
+            timestamp: chrono::Utc::now(),
+        };
+        let metrics = OrderBookMetrics::from_orderbook(&orderbook, 5).unwrap();
+        let msg = ServerMessage::OrderBookMetrics(OrderBookMetricsData::from(&metrics));
+        let json: Value = serde_json::from_str(&msg.to_json().unwrap()).unwrap();
+
+        assert_eq!(json["type"], "order_book_metrics");
+        assert_eq!(json["symbol"], "005930");
+        assert_eq!(json["microprice"].as_f64(), Some(70075.0));
+        assert_eq!(json["imbalance"].as_f64(), Some(0.5));
+    }
+
+    #[test]
+    fn test_stream_status_message() {
+        let since = chrono::Utc::now() - chrono::Duration::seconds(8);
+        let status = StreamStatus::recovered("kis_kr", 3, since, 4);
+        let data = StreamStatusData::from(&status);
+        let json: Value =
+            serde_json::from_str(&ServerMessage::StreamStatus(data.clone()).to_json().unwrap())
+                .unwrap();
+
+        assert_eq!(json["type"], "stream_status");
+        assert_eq!(json["state"], "recovered");
+        assert_eq!(json["resubscribed"], 4);
+        assert!(json.get("reason").is_none());
+
+        let restored = data.to_status();
+        assert_eq!(restored.state, StreamState::Recovered);
+        assert_eq!(
+            restored.disconnected_at.map(|t| t.timestamp_millis()),
+            Some(since.timestamp_millis())
+        );
+    }
+
     #[test]
     fn test_ticker_data() {
         use rust_decimal_macros::dec;
@@ -4,7 +4,7 @@
 //!
 //! # 구독 채널
 //!
-//! - `market:{symbol}` - 특정 심볼의 시장 데이터 (ticker, trades)
+//! - `market:{symbol}` - 특정 심볼의 시장 데이터 (ticker, trades, orderbook_metrics)
 //! - `orders` - 주문 상태 업데이트
 //! - `positions` - 포지션 업데이트
 //! - `strategies` - 전략 상태 변경
@@ -38,8 +38,9 @@ pub mod subscriptions;
 pub use aggregator::{start_aggregator, MarketDataAggregator};
 pub use handler::{standalone_websocket_router, websocket_handler, websocket_router, WsState};
 pub use messages::{
-    ClientMessage, OrderBookData, OrderBookLevel, OrderUpdateData, PositionUpdateData,
-    ServerMessage, SimulationUpdateData, StrategyUpdateData, TickerData, TradeData, WsError,
+    ClientMessage, OrderBookData, OrderBookLevel, OrderBookMetricsData, OrderUpdateData,
+    PositionUpdateData, ServerMessage, SimulationUpdateData, StrategyUpdateData, StreamStatusData,
+    TickerData, TradeData, TradingStatusData, WsError,
 };
 pub use simulator::{start_simulator, MockDataSimulator};
 pub use subscriptions::{
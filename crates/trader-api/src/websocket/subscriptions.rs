@@ -71,10 +71,22 @@ impl Subscription {
             (Subscription::Market(symbol), ServerMessage::Trade(data)) => {
                 data.symbol.to_uppercase() == *symbol
             }
+            (Subscription::Market(symbol), ServerMessage::TradingStatus(data)) => {
+                data.symbol.to_uppercase() == *symbol
+            }
+            (Subscription::Market(symbol), ServerMessage::OrderBookMetrics(data)) => {
+                data.symbol.to_uppercase() == *symbol
+            }
             (Subscription::Orders, ServerMessage::OrderUpdate(_)) => true,
             (Subscription::Positions, ServerMessage::PositionUpdate(_)) => true,
             (Subscription::Strategies, ServerMessage::StrategyUpdate(_)) => true,
             (Subscription::AllMarkets, ServerMessage::Ticker(_)) => true,
+            (Subscription::AllMarkets, ServerMessage::TradingStatus(_)) => true,
+            // 시세 공백은 모든 시세 구독자에게 알림
+            (
+                Subscription::Market(_) | Subscription::AllMarkets,
+                ServerMessage::StreamStatus(_),
+            ) => true,
             (Subscription::Simulation, ServerMessage::SimulationUpdate(_)) => true,
             _ => false,
         }
@@ -163,6 +175,13 @@ impl SubscriptionManager {
         self.broadcast_tx.subscribe()
     }
 
+    /// 세션 등록 없이 브로드캐스트 수신기 생성.
+    ///
+    /// 웹훅 전송 등 서버 내부 구독자가 사용합니다.
+    pub fn receiver(&self) -> broadcast::Receiver<ServerMessage> {
+        self.broadcast_tx.subscribe()
+    }
+
     /// 클라이언트 세션 제거.
     pub async fn unregister(&self, session_id: &str) {
         let mut sessions = self.sessions.write().await;
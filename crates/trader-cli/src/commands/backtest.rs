@@ -14,6 +14,9 @@
 //! # 특정 기간만 백테스트
 //! trader backtest -c config/backtest/haa.toml -s SPY -m US -f 2024-01-01 -t 2024-12-31
 //!
+//! # 5분봉 장중 백테스트 (정규장 세션, 마감 시 청산)
+//! trader backtest -c config/backtest/volatility.toml -s 005930 -m KR --timeframe 5m --flatten-at-close
+//!
 //! # 사용 가능한 전략 목록
 //! trader backtest --list-strategies
 //! ```
@@ -26,8 +29,8 @@ use std::path::Path;
 use std::str::FromStr;
 use tracing::{debug, info};
 
-use trader_analytics::backtest::{BacktestConfig, BacktestEngine, BacktestReport};
-use trader_core::{Kline, Symbol, Timeframe};
+use trader_analytics::backtest::{BacktestConfig, BacktestEngine, BacktestReport, IntradaySession};
+use trader_core::{Kline, Symbol, Timeframe, TradingCostModel};
 use trader_data::{Database, DatabaseConfig, KlineRepository, SymbolRepository};
 use trader_strategy::strategies::{
     AssetAllocationStrategy, CompoundMomentumStrategy, DayTradingStrategy, MeanReversionStrategy,
@@ -56,6 +59,10 @@ pub struct BacktestCliConfig {
     pub commission_rate: Decimal,
     /// 슬리피지율
     pub slippage_rate: Decimal,
+    /// 캔들 타임프레임 (분봉이면 정규장 세션으로 시뮬레이션)
+    pub timeframe: Timeframe,
+    /// 장중 백테스트에서 세션 마감 시 미청산 포지션 청산
+    pub flatten_at_close: bool,
     /// 데이터베이스 URL
     pub db_url: Option<String>,
     /// 결과 저장 경로 (옵션)
@@ -73,6 +80,8 @@ impl Default for BacktestCliConfig {
             initial_capital: Decimal::from(10_000_000), // 1천만원
             commission_rate: Decimal::from_str("0.00015").unwrap(), // 0.015% (한국 증권사 평균)
             slippage_rate: Decimal::from_str("0.0005").unwrap(), // 0.05%
+            timeframe: Timeframe::D1,
+            flatten_at_close: false,
             db_url: None,
             output_path: None,
         }
@@ -178,6 +187,7 @@ pub async fn run_backtest(config: BacktestCliConfig) -> Result<BacktestReport> {
         &kline_repo,
         symbol_id,
         &symbol,
+        config.timeframe,
         config.start_date,
         config.end_date,
     )
@@ -190,7 +200,11 @@ pub async fn run_backtest(config: BacktestCliConfig) -> Result<BacktestReport> {
         ));
     }
 
-    info!("Loaded {} klines for backtest", klines.len());
+    info!(
+        "Loaded {} {} klines for backtest",
+        klines.len(),
+        config.timeframe
+    );
 
     // 5. 전략 타입 파싱
     let strategy_type =
@@ -201,11 +215,19 @@ pub async fn run_backtest(config: BacktestCliConfig) -> Result<BacktestReport> {
             )
         })?;
 
-    // 6. 백테스트 엔진 설정
+    // 6. 백테스트 엔진 설정 (시장별 매도 세금 포함)
     let backtest_config = BacktestConfig::new(config.initial_capital)
         .with_commission_rate(config.commission_rate)
+        .with_cost_model(market_cost_model(config.market, config.commission_rate))
         .with_slippage_rate(config.slippage_rate)
         .with_allow_short(false); // 주식은 기본적으로 숏 비허용
+    let backtest_config = if config.timeframe.is_intraday() {
+        backtest_config.with_session(
+            market_session(config.market).with_flatten_at_close(config.flatten_at_close),
+        )
+    } else {
+        backtest_config
+    };
 
     // 7. 전략별 백테스트 실행
     let report = run_strategy_backtest(
@@ -383,11 +405,32 @@ fn create_symbol(config: &BacktestCliConfig) -> Symbol {
     }
 }
 
+/// 시장별 거래 비용 모델 (수수료율은 설정값, 매도 세금은 시장 기본값)
+///
+/// - KR: 증권거래세 + 농어촌특별세 (코스피 기준)
+/// - US: SEC fee
+fn market_cost_model(market: Market, commission_rate: Decimal) -> TradingCostModel {
+    let model = match market {
+        Market::KR => TradingCostModel::kr_kospi(),
+        Market::US => TradingCostModel::us_stock(),
+    };
+    model.with_commission_rate(commission_rate)
+}
+
+/// 시장별 정규장 세션 (장중 백테스트용)
+fn market_session(market: Market) -> IntradaySession {
+    match market {
+        Market::KR => IntradaySession::krx(),
+        Market::US => IntradaySession::us_regular(),
+    }
+}
+
 /// 데이터베이스에서 캔들 데이터 로드
 async fn load_klines_from_db(
     kline_repo: &KlineRepository,
     symbol_id: uuid::Uuid,
     symbol: &Symbol,
+    timeframe: Timeframe,
     start_date: Option<NaiveDate>,
     end_date: Option<NaiveDate>,
 ) -> Result<Vec<Kline>> {
@@ -400,9 +443,12 @@ async fn load_klines_from_db(
         .map(|d| d.and_hms_opt(23, 59, 59).unwrap().and_utc())
         .unwrap_or(now);
 
-    // get_range 메서드 사용 (limit: None = 기본 1000개)
+    // get_range 메서드 사용 (일봉은 기본 1000개, 장중은 기간 내 캔들 수만큼)
+    let limit = timeframe
+        .is_intraday()
+        .then(|| ((end - start).num_seconds() / timeframe.as_secs() as i64 + 1) as i32);
     let rows = kline_repo
-        .get_range(symbol_id, Timeframe::D1, start, end, None)
+        .get_range(symbol_id, timeframe, start, end, limit)
         .await?;
 
     debug!("Loaded {} rows from database", rows.len());
@@ -0,0 +1,418 @@
+//! 신규 설치 초기화 명령어.
+//!
+//! 빈 데이터베이스에서 대시보드가 동작하는 상태까지 한 번에 준비합니다.
+//!
+//! 1. 스키마 마이그레이션
+//! 2. 종목 마스터 동기화 (KRX, Yahoo Finance)
+//! 3. 시작 유니버스 구성 (기본: KOSPI200 + 미국 주요 ETF)
+//! 4. 일봉 백필 (기본 5년)
+//! 5. 분석 지표, GlobalScore 계산 및 스크리닝 뷰 갱신
+//! 6. 데모 모의투자 전략 등록 (비활성 상태)
+//!
+//! 각 단계는 이미 완료된 작업을 건너뛰거나 덮어쓰므로 여러 번 실행해도 안전합니다.
+//!
+//! # 사용 예시
+//!
+//! ```bash
+//! # 기본 유니버스로 초기화
+//! trader bootstrap
+//!
+//! # 유니버스와 백필 기간 지정
+//! trader bootstrap --kr-symbols 005930,000660 --us-symbols SPY,QQQ --years 3
+//! ```
+
+use anyhow::{anyhow, Context, Result};
+use chrono::{Months, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde_json::json;
+use sqlx::PgPool;
+use tracing::{info, warn};
+
+use trader_api::repository::strategies::CreateStrategyInput;
+use trader_api::repository::{NewSymbolInfo, StrategyRepository, SymbolInfoRepository};
+use trader_collector::modules::{self, EtfSyncOptions};
+use trader_collector::{CollectionStats, CollectorConfig};
+use trader_data::{Database, DatabaseConfig, EtfStore};
+
+/// KOSPI200 구성종목을 가져올 ETF (KODEX 200).
+const KOSPI200_ETF: &str = "069500";
+
+/// 기본 미국 ETF 유니버스 (티커, 이름).
+const DEFAULT_US_ETFS: &[(&str, &str)] = &[
+    ("SPY", "SPDR S&P 500 ETF Trust"),
+    ("QQQ", "Invesco QQQ Trust"),
+    ("IWM", "iShares Russell 2000 ETF"),
+    ("DIA", "SPDR Dow Jones Industrial Average ETF Trust"),
+    ("VTI", "Vanguard Total Stock Market ETF"),
+    ("EFA", "iShares MSCI EAFE ETF"),
+    ("EEM", "iShares MSCI Emerging Markets ETF"),
+    ("TLT", "iShares 20+ Year Treasury Bond ETF"),
+    ("IEF", "iShares 7-10 Year Treasury Bond ETF"),
+    ("GLD", "SPDR Gold Shares"),
+    ("SCHD", "Schwab U.S. Dividend Equity ETF"),
+    ("XLK", "Technology Select Sector SPDR Fund"),
+    ("XLF", "Financial Select Sector SPDR Fund"),
+    ("XLE", "Energy Select Sector SPDR Fund"),
+];
+
+/// 데모 전략 ID.
+const DEMO_STRATEGY_ID: &str = "demo_rsi_paper";
+
+/// 초기화 설정.
+#[derive(Debug, Clone)]
+pub struct BootstrapConfig {
+    /// 데이터베이스 URL (기본: DATABASE_URL 환경변수)
+    pub db_url: Option<String>,
+    /// 국내 유니버스 (None이면 KOSPI200 구성종목)
+    pub kr_symbols: Option<Vec<String>>,
+    /// 미국 유니버스 (None이면 주요 ETF)
+    pub us_symbols: Option<Vec<String>>,
+    /// 일봉 백필 기간 (년)
+    pub years: u32,
+    /// 데모 모의투자 전략 등록 여부
+    pub demo_strategy: bool,
+}
+
+impl Default for BootstrapConfig {
+    fn default() -> Self {
+        Self {
+            db_url: None,
+            kr_symbols: None,
+            us_symbols: None,
+            years: 5,
+            demo_strategy: true,
+        }
+    }
+}
+
+/// 초기화 결과 요약.
+#[derive(Debug, Default)]
+pub struct BootstrapSummary {
+    /// 유니버스 종목 수 (국내)
+    pub kr_symbols: usize,
+    /// 유니버스 종목 수 (미국)
+    pub us_symbols: usize,
+    /// 저장한 일봉 수
+    pub klines: usize,
+    /// 지표를 계산한 종목 수
+    pub indicators: usize,
+    /// GlobalScore를 계산한 종목 수
+    pub scores: usize,
+    /// 새로 등록한 데모 전략 ID
+    pub demo_strategy: Option<String>,
+    /// 실패했지만 계속 진행한 단계
+    pub warnings: Vec<String>,
+}
+
+/// 신규 설치를 초기화합니다.
+pub async fn run_bootstrap(config: BootstrapConfig) -> Result<BootstrapSummary> {
+    let mut summary = BootstrapSummary::default();
+
+    let db_url = config
+        .db_url
+        .clone()
+        .or_else(|| std::env::var("DATABASE_URL").ok())
+        .ok_or_else(|| {
+            anyhow!(
+                "DATABASE_URL not found. Set DATABASE_URL environment variable or use --db-url flag"
+            )
+        })?;
+
+    // 1. 스키마
+    println!("\n[1/6] 스키마 마이그레이션");
+    let db = Database::connect(&DatabaseConfig {
+        url: db_url.clone(),
+        ..Default::default()
+    })
+    .await
+    .context("Failed to connect to database")?;
+    db.migrate().await.context("Failed to run migrations")?;
+    let pool = db.pool().clone();
+
+    let mut collector = CollectorConfig::from_env_with_database_url(db_url);
+    collector.ohlcv_collect.start_date = Some(
+        backfill_start_date(Utc::now().date_naive(), config.years)
+            .format("%Y%m%d")
+            .to_string(),
+    );
+    collector.ohlcv_collect.end_date = None;
+
+    // 2. 종목 마스터
+    println!("[2/6] 종목 마스터 동기화");
+    if let Err(e) = modules::sync_symbols(&pool, &collector).await {
+        summary.warn(format!("종목 마스터 동기화 실패: {}", e));
+    }
+
+    // 3. 유니버스
+    println!("[3/6] 시작 유니버스 구성");
+    let kr = match config.kr_symbols.clone() {
+        Some(symbols) => symbols,
+        None => kospi200_universe(&pool, &collector, &mut summary).await,
+    };
+    let us = match config.us_symbols.clone() {
+        Some(symbols) => symbols,
+        None => DEFAULT_US_ETFS
+            .iter()
+            .map(|(ticker, _)| ticker.to_string())
+            .collect(),
+    };
+    if let Err(e) = register_us_etfs(&pool, &us).await {
+        summary.warn(format!("미국 ETF 종목 등록 실패: {}", e));
+    }
+    summary.kr_symbols = kr.len();
+    summary.us_symbols = us.len();
+    println!("   국내 {}개, 미국 {}개", kr.len(), us.len());
+
+    let universe: Vec<String> = kr.into_iter().chain(us).collect();
+    if universe.is_empty() {
+        return Err(anyhow!("유니버스가 비어 있습니다"));
+    }
+    let tickers = universe.join(",");
+
+    // 4. 일봉 백필
+    println!("[4/6] 일봉 백필 ({}년)", config.years);
+    match modules::collect_ohlcv(&pool, &collector, Some(tickers.clone()), None).await {
+        Ok(stats) => {
+            log_stats("일봉 백필", &stats);
+            summary.klines = stats.total_klines;
+        }
+        Err(e) => summary.warn(format!("일봉 백필 실패: {}", e)),
+    }
+
+    // 5. 지표/점수
+    println!("[5/6] 지표, GlobalScore 계산");
+    match modules::sync_indicators(&pool, &collector, Some(tickers.clone())).await {
+        Ok(stats) => {
+            log_stats("지표 동기화", &stats);
+            summary.indicators = stats.success;
+        }
+        Err(e) => summary.warn(format!("지표 동기화 실패: {}", e)),
+    }
+    match modules::sync_global_scores(&pool, &collector, Some(tickers)).await {
+        Ok(stats) => {
+            log_stats("GlobalScore 동기화", &stats);
+            summary.scores = stats.success;
+        }
+        Err(e) => summary.warn(format!("GlobalScore 동기화 실패: {}", e)),
+    }
+    if let Err(e) = modules::refresh_screening_view(&pool).await {
+        summary.warn(format!("스크리닝 뷰 갱신 실패: {}", e));
+    }
+
+    // 6. 데모 전략
+    if config.demo_strategy {
+        println!("[6/6] 데모 모의투자 전략 등록");
+        match register_demo_strategy(&pool).await {
+            Ok(created) => summary.demo_strategy = created,
+            Err(e) => summary.warn(format!("데모 전략 등록 실패: {}", e)),
+        }
+    } else {
+        println!("[6/6] 데모 전략 등록 건너뜀");
+    }
+
+    pool.close().await;
+    Ok(summary)
+}
+
+impl BootstrapSummary {
+    /// 실패한 단계를 기록하고 계속 진행합니다.
+    fn warn(&mut self, message: String) {
+        warn!("{}", message);
+        self.warnings.push(message);
+    }
+}
+
+/// 쉼표로 구분된 종목 목록을 파싱합니다 (공백 제거, 대문자, 중복 제거).
+pub fn parse_symbols(input: &str) -> Vec<String> {
+    let mut symbols: Vec<String> = Vec::new();
+    for symbol in input.split(',').map(|s| s.trim().to_uppercase()) {
+        if !symbol.is_empty() && !symbols.contains(&symbol) {
+            symbols.push(symbol);
+        }
+    }
+    symbols
+}
+
+/// 백필 시작일 (`years`년 전, 월말은 해당 월 마지막 날로 보정).
+fn backfill_start_date(today: NaiveDate, years: u32) -> NaiveDate {
+    today
+        .checked_sub_months(Months::new(years.saturating_mul(12)))
+        .unwrap_or(NaiveDate::MIN)
+}
+
+/// ETF 구성종목에서 국내 주식 종목코드만 추립니다 (현금, 선물 등 제외).
+fn stock_tickers(tickers: impl IntoIterator<Item = String>) -> Vec<String> {
+    let mut stocks: Vec<String> = tickers
+        .into_iter()
+        .filter(|t| t.len() == 6 && t.chars().all(|c| c.is_ascii_digit()))
+        .collect();
+    stocks.sort();
+    stocks.dedup();
+    stocks
+}
+
+/// KODEX 200 구성종목(PDF)으로 KOSPI200 유니버스를 구성합니다.
+///
+/// 구성종목을 가져오지 못하면 ETF 자체만 유니버스에 넣습니다.
+async fn kospi200_universe(
+    pool: &PgPool,
+    collector: &CollectorConfig,
+    summary: &mut BootstrapSummary,
+) -> Vec<String> {
+    let options = EtfSyncOptions {
+        symbols: Some(KOSPI200_ETF.to_string()),
+        days: Some(30),
+    };
+    if let Err(e) = modules::sync_etf_data(pool, collector, options).await {
+        summary.warn(format!("KOSPI200 구성종목 수집 실패: {}", e));
+    }
+
+    let constituents = match EtfStore::new(pool.clone())
+        .latest_constituents(KOSPI200_ETF)
+        .await
+    {
+        Ok(constituents) => constituents,
+        Err(e) => {
+            summary.warn(format!("KOSPI200 구성종목 조회 실패: {}", e));
+            Vec::new()
+        }
+    };
+
+    let mut tickers = stock_tickers(constituents.into_iter().map(|c| c.ticker));
+    if tickers.is_empty() {
+        summary.warn(
+            "KOSPI200 구성종목이 없어 ETF만 사용합니다 (--kr-symbols로 직접 지정 가능)".to_string(),
+        );
+    }
+    if !tickers.iter().any(|t| t == KOSPI200_ETF) {
+        tickers.push(KOSPI200_ETF.to_string());
+    }
+    tickers
+}
+
+/// 종목 마스터에 없는 미국 ETF를 등록합니다 (기존 종목 정보는 유지).
+async fn register_us_etfs(pool: &PgPool, tickers: &[String]) -> Result<()> {
+    let existing: Vec<String> = sqlx::query_scalar(
+        "SELECT ticker FROM symbol_info WHERE market = 'US' AND ticker = ANY($1)",
+    )
+    .bind(tickers)
+    .fetch_all(pool)
+    .await?;
+
+    let missing: Vec<NewSymbolInfo> = tickers
+        .iter()
+        .filter(|t| !existing.contains(t))
+        .map(|ticker| {
+            let name = DEFAULT_US_ETFS
+                .iter()
+                .find(|(t, _)| t == ticker)
+                .map(|(_, name)| name.to_string())
+                .unwrap_or_else(|| ticker.clone());
+            NewSymbolInfo {
+                ticker: ticker.clone(),
+                name: name.clone(),
+                name_en: Some(name),
+                market: "US".to_string(),
+                exchange: None,
+                sector: None,
+                yahoo_symbol: Some(ticker.clone()),
+            }
+        })
+        .collect();
+
+    if !missing.is_empty() {
+        let count = SymbolInfoRepository::upsert_batch(pool, &missing).await?;
+        info!(count, "미국 ETF 종목 등록");
+    }
+    Ok(())
+}
+
+/// 데모 모의투자 전략을 등록합니다 (비활성 상태, 이미 있으면 건너뜀).
+///
+/// 실행은 대시보드에서 시작하며, 주문은 기본 계좌(`KIS_DEFAULT_ACCOUNT`, 기본 모의투자)로 나갑니다.
+async fn register_demo_strategy(pool: &PgPool) -> Result<Option<String>> {
+    if StrategyRepository::exists(pool, DEMO_STRATEGY_ID).await? {
+        info!("데모 전략이 이미 있습니다: {}", DEMO_STRATEGY_ID);
+        return Ok(None);
+    }
+
+    StrategyRepository::create(
+        pool,
+        CreateStrategyInput {
+            id: DEMO_STRATEGY_ID.to_string(),
+            name: "데모 RSI 평균회귀 (모의투자)".to_string(),
+            description: Some("trader bootstrap으로 등록된 모의투자 데모 전략".to_string()),
+            strategy_type: "rsi".to_string(),
+            symbols: vec!["005930".to_string()],
+            market: "KR".to_string(),
+            timeframe: "1d".to_string(),
+            config: json!({
+                "ticker": "005930",
+                "amount": 1_000_000,
+                "rsi_period": 14,
+            }),
+            risk_config: None,
+            allocated_capital: Some(Decimal::new(10_000_000, 0)),
+            risk_profile: Some("conservative".to_string()),
+            multi_timeframe_config: None,
+            symbol_lock: None,
+            owner_id: None,
+        },
+    )
+    .await?;
+
+    Ok(Some(DEMO_STRATEGY_ID.to_string()))
+}
+
+/// 단계별 수집 통계 출력.
+fn log_stats(operation: &str, stats: &CollectionStats) {
+    stats.log_summary(operation);
+    println!(
+        "   {}: 성공 {} / 실패 {} / 건너뜀 {} ({:.1}초)",
+        operation,
+        stats.success,
+        stats.errors,
+        stats.skipped,
+        stats.elapsed.as_secs_f64()
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_symbols() {
+        assert_eq!(
+            parse_symbols(" spy, QQQ,,005930 ,spy"),
+            vec!["SPY".to_string(), "QQQ".to_string(), "005930".to_string()]
+        );
+        assert!(parse_symbols(" , ").is_empty());
+    }
+
+    #[test]
+    fn test_backfill_start_date() {
+        let today = NaiveDate::from_ymd_opt(2026, 10, 17).unwrap();
+        assert_eq!(
+            backfill_start_date(today, 5),
+            NaiveDate::from_ymd_opt(2021, 10, 17).unwrap()
+        );
+
+        // 윤일은 해당 월 마지막 날로 보정
+        let leap = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+        assert_eq!(
+            backfill_start_date(leap, 1),
+            NaiveDate::from_ymd_opt(2023, 2, 28).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_stock_tickers() {
+        let tickers = stock_tickers(
+            ["005930", "KRD010010001", "000660", "005930", "CASH00000001"]
+                .into_iter()
+                .map(String::from),
+        );
+        assert_eq!(tickers, vec!["000660".to_string(), "005930".to_string()]);
+    }
+}
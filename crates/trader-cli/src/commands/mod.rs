@@ -1,11 +1,13 @@
 //! CLI 명령어 구현 모듈.
 
 pub mod backtest;
+pub mod bootstrap;
 pub mod download;
 pub mod fetch_symbols;
 pub mod health;
 pub mod import;
 pub mod list_symbols;
+pub mod support_bundle;
 // sync_csv는 trader-collector로 이동됨
 
 // 각 서브모듈 직접 사용 권장 (ambiguous re-export 방지)
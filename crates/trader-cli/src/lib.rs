@@ -5,6 +5,8 @@
 //! - 백테스팅 CLI
 //! - 설정 관리
 //! - 상태 점검 도구
+//! - 신규 설치 초기화 (스키마, 유니버스, 과거 데이터, 데모 전략)
+//! - 버그 리포트용 진단 번들 (민감 정보 가림)
 
 pub mod commands;
 
@@ -15,6 +15,12 @@
 //! # 인기 종목 목록 보기
 //! trader list -m KR
 //! trader list -m US
+//!
+//! # 신규 설치 초기화 (스키마, KOSPI200 + 미국 ETF 5년 일봉, 지표, 데모 전략)
+//! trader bootstrap
+//!
+//! # 버그 리포트용 진단 번들 생성 (민감 정보 가림)
+//! trader support-bundle --symbols 005930,AAPL --days 30
 //! ```
 
 use clap::{Parser, Subcommand};
@@ -210,6 +216,14 @@ enum Commands {
         #[arg(long, default_value = "10000000")]
         capital: String,
 
+        /// 캔들 타임프레임 (1m, 5m, 1h, 1d 등, 분봉은 정규장 세션으로 시뮬레이션)
+        #[arg(long, default_value = "1d")]
+        timeframe: String,
+
+        /// 장중 백테스트에서 세션 마감 시 미청산 포지션 청산
+        #[arg(long)]
+        flatten_at_close: bool,
+
         /// 결과 저장 경로
         #[arg(short, long)]
         output: Option<String>,
@@ -233,6 +247,76 @@ enum Commands {
         dry_run: bool,
     },
 
+    /// 신규 설치 초기화 (스키마 → 유니버스 → 일봉 백필 → 지표/점수 → 데모 전략)
+    Bootstrap {
+        /// 국내 종목 (쉼표 구분, 기본: KOSPI200 구성종목)
+        #[arg(long)]
+        kr_symbols: Option<String>,
+
+        /// 미국 종목 (쉼표 구분, 기본: 주요 ETF)
+        #[arg(long)]
+        us_symbols: Option<String>,
+
+        /// 일봉 백필 기간 (년)
+        #[arg(long, default_value = "5")]
+        years: u32,
+
+        /// 데모 모의투자 전략 등록 생략
+        #[arg(long)]
+        skip_demo_strategy: bool,
+
+        /// 데이터베이스 URL (기본: DATABASE_URL 환경변수)
+        #[arg(long)]
+        db_url: Option<String>,
+    },
+
+    /// 버그 리포트용 진단 번들 생성 (로그, 에러 기록, 설정, 전략, 캔들 → tar.gz)
+    SupportBundle {
+        /// 출력 파일 경로 (기본: support-bundle-<시각>.tar.gz)
+        #[arg(short, long)]
+        output: Option<String>,
+
+        /// 로그 디렉토리
+        #[arg(long, default_value = "logs")]
+        log_dir: String,
+
+        /// 포함할 최근 로그 파일 수
+        #[arg(long, default_value = "5")]
+        log_files: usize,
+
+        /// 로그 파일별 포함할 마지막 줄 수
+        #[arg(long, default_value = "2000")]
+        log_lines: usize,
+
+        /// 설정 파일
+        #[arg(short, long, default_value = "config/default.toml")]
+        config: String,
+
+        /// 캔들을 포함할 심볼 (쉼표로 구분, 기본: 활성 전략의 심볼)
+        #[arg(short, long)]
+        symbols: Option<String>,
+
+        /// 캔들 타임프레임
+        #[arg(short, long, default_value = "1d")]
+        timeframe: String,
+
+        /// 포함할 캔들 기간 (일)
+        #[arg(long, default_value = "30")]
+        days: i64,
+
+        /// API 서버 주소 (에러 추적 기록 조회)
+        #[arg(long, default_value = "http://localhost:3000")]
+        api_url: String,
+
+        /// API 인증 토큰 (기본: API_TOKEN 환경변수)
+        #[arg(long)]
+        api_token: Option<String>,
+
+        /// 데이터베이스 URL (기본: DATABASE_URL 환경변수)
+        #[arg(long)]
+        db_url: Option<String>,
+    },
+
     /// ML 모델 훈련 (Yahoo Finance 데이터 → ONNX)
     Train {
         /// 종목 심볼 (예: SPY, QQQ)
@@ -531,6 +615,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             from,
             to,
             capital,
+            timeframe,
+            flatten_at_close,
             output,
             list_strategies,
         } => {
@@ -550,6 +636,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 .parse::<rust_decimal::Decimal>()
                 .map_err(|_| format!("Invalid capital: {}", capital))?;
 
+            let timeframe = timeframe.parse::<trader_core::Timeframe>()?;
+
             let backtest_config = commands::backtest::BacktestCliConfig {
                 config_path: config.clone(),
                 market,
@@ -557,6 +645,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 start_date,
                 end_date,
                 initial_capital,
+                timeframe,
+                flatten_at_close,
                 output_path: output.clone(),
                 ..Default::default()
             };
@@ -572,6 +662,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             if let (Some(s), Some(e)) = (&start_date, &end_date) {
                 println!("기간: {} ~ {}", s, e);
             }
+            println!("타임프레임: {}", timeframe);
             println!("초기 자본: {}", initial_capital);
 
             match commands::backtest::run_backtest(backtest_config).await {
@@ -609,6 +700,120 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             println!("설정 파일: {}", config);
         }
 
+        Commands::Bootstrap {
+            kr_symbols,
+            us_symbols,
+            years,
+            skip_demo_strategy,
+            db_url,
+        } => {
+            use commands::bootstrap::{parse_symbols, run_bootstrap, BootstrapConfig};
+
+            let bootstrap_config = BootstrapConfig {
+                db_url,
+                kr_symbols: kr_symbols.as_deref().map(parse_symbols),
+                us_symbols: us_symbols.as_deref().map(parse_symbols),
+                years,
+                demo_strategy: !skip_demo_strategy,
+            };
+
+            println!("\n🚀 신규 설치 초기화 시작");
+
+            match run_bootstrap(bootstrap_config).await {
+                Ok(summary) => {
+                    info!("✅ Bootstrap completed");
+                    println!("\n✅ 초기화 완료");
+                    println!(
+                        "  - 유니버스: 국내 {}개, 미국 {}개",
+                        summary.kr_symbols, summary.us_symbols
+                    );
+                    println!("  - 일봉: {}개", summary.klines);
+                    println!(
+                        "  - 지표: {}개 종목, GlobalScore: {}개 종목",
+                        summary.indicators, summary.scores
+                    );
+                    if let Some(strategy_id) = &summary.demo_strategy {
+                        println!("  - 데모 전략: {} (비활성, 대시보드에서 시작)", strategy_id);
+                    }
+                    if !summary.warnings.is_empty() {
+                        println!("\n⚠️  실패한 단계 (다시 실행하면 이어서 진행):");
+                        for warning in &summary.warnings {
+                            println!("  - {}", warning);
+                        }
+                    }
+                }
+                Err(e) => {
+                    error!("Bootstrap failed: {}", e);
+                    return Err(e.into());
+                }
+            }
+        }
+
+        Commands::SupportBundle {
+            output,
+            log_dir,
+            log_files,
+            log_lines,
+            config,
+            symbols,
+            timeframe,
+            days,
+            api_url,
+            api_token,
+            db_url,
+        } => {
+            use commands::support_bundle::{
+                create_support_bundle, default_output_path, SupportBundleConfig,
+            };
+
+            let symbols = symbols
+                .map(|s| {
+                    s.split(',')
+                        .map(|t| t.trim().to_uppercase())
+                        .filter(|t| !t.is_empty())
+                        .collect()
+                })
+                .unwrap_or_default();
+
+            let bundle_config = SupportBundleConfig {
+                output_path: output.unwrap_or_else(|| default_output_path(chrono::Utc::now())),
+                log_dir,
+                log_files,
+                log_lines,
+                config_path: config,
+                symbols,
+                timeframe,
+                days,
+                api_url,
+                api_token: api_token.or_else(|| std::env::var("API_TOKEN").ok()),
+                db_url,
+            };
+
+            println!("\n📦 진단 번들 생성 중...");
+
+            match create_support_bundle(bundle_config).await {
+                Ok(summary) => {
+                    info!("✅ Support bundle created: {}", summary.output_path);
+                    println!("\n✅ 진단 번들 생성 완료: {}", summary.output_path);
+                    for entry in &summary.entries {
+                        println!("  - {}", entry);
+                    }
+                    if !summary.warnings.is_empty() {
+                        println!("\n⚠️  건너뛴 항목:");
+                        for warning in &summary.warnings {
+                            println!("  - {}", warning);
+                        }
+                    }
+                    println!("\n민감 정보(키, 토큰, 비밀번호, 접속 정보)는 가려졌습니다.");
+                    println!("첨부 전에 내용을 한 번 더 확인해 주세요.");
+                }
+                Err(e) => {
+                    error!("Support bundle failed: {}", e);
+                    return Err(e.into());
+                }
+            }
+        }
+
         Commands::Train {
             symbol,
             symbols,
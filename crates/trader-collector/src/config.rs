@@ -16,6 +16,16 @@ pub struct CollectorConfig {
     pub ohlcv_collect: OhlcvCollectConfig,
     /// Fundamental 수집 설정
     pub fundamental_collect: FundamentalCollectConfig,
+    /// 분봉 백필 설정
+    pub minute_backfill: MinuteBackfillConfig,
+    /// 투자자별 순매수 수집 설정
+    pub investor_flow: InvestorFlowConfig,
+    /// ETF NAV/구성종목 수집 설정
+    pub etf_sync: EtfSyncConfig,
+    /// 실적 발표 일정 수집 설정
+    pub earnings: EarningsSyncConfig,
+    /// 실현 변동성/베타 계산 설정
+    pub volatility: VolatilitySyncConfig,
     /// 데몬 모드 설정
     pub daemon: DaemonConfig,
 }
@@ -38,6 +48,18 @@ pub struct DataProviderConfig {
     /// 네이버 요청 간 딜레이 (밀리초)
     /// 기본값: 300ms
     pub naver_request_delay_ms: u64,
+    /// 암호화폐 온체인/파생상품 지표 수집 활성화
+    /// 기본값: false
+    pub crypto_metrics_enabled: bool,
+    /// 현금 금리 수집 활성화 (CD/국고채, 미국 T-bill)
+    /// 기본값: false
+    pub cash_rates_enabled: bool,
+    /// 현금 금리 최초 수집 일수 (이후에는 마지막 관측일부터 증분)
+    /// 기본값: 3650 (백테스트용 10년)
+    pub cash_rates_backfill_days: i64,
+    /// 매크로 레짐 종합 지표 계산 활성화 (환율 추세, 금리차, VIX/VKOSPI, 시장 폭)
+    /// 기본값: false
+    pub macro_regime_enabled: bool,
 }
 
 /// 심볼 동기화 설정
@@ -85,6 +107,77 @@ pub struct FundamentalCollectConfig {
     pub include_ohlcv: bool,
 }
 
+/// 분봉 백필 설정 (KIS 일별 분봉 조회)
+#[derive(Debug, Clone)]
+pub struct MinuteBackfillConfig {
+    /// 기본 백필 일수 (영업일 기준)
+    pub days: u32,
+    /// 하루당 최대 API 호출 수 (1회 최대 120건)
+    pub max_pages_per_day: u32,
+    /// API 요청 간 딜레이 (밀리초)
+    pub request_delay_ms: u64,
+}
+
+/// 투자자별 순매수 수집 설정 (KRX 외국인/기관/개인)
+#[derive(Debug, Clone)]
+pub struct InvestorFlowConfig {
+    /// 데몬 워크플로우 포함 여부
+    /// 기본값: false
+    pub enabled: bool,
+    /// 최초 수집 일수 (이후에는 마지막 수집일부터 증분)
+    pub days: i64,
+    /// 배치당 심볼 수
+    pub batch_size: i64,
+    /// API 요청 간 딜레이 (밀리초)
+    pub request_delay_ms: u64,
+    /// GlobalScore Flow Momentum 팩터 가중치 (0이면 미적용)
+    pub global_score_weight: f32,
+    /// Flow Momentum 집계 거래일 수
+    pub global_score_days: i64,
+}
+
+/// ETF NAV/구성종목(PDF) 수집 설정 (KRX)
+#[derive(Debug, Clone)]
+pub struct EtfSyncConfig {
+    /// 데몬 워크플로우 포함 여부
+    /// 기본값: false
+    pub enabled: bool,
+    /// 최초 NAV 수집 일수 (이후에는 마지막 수집일부터 증분)
+    pub days: i64,
+    /// 배치당 ETF 수
+    pub batch_size: i64,
+    /// API 요청 간 딜레이 (밀리초)
+    pub request_delay_ms: u64,
+}
+
+/// 실적 발표 일정 수집 설정 (US Nasdaq, KR DART)
+#[derive(Debug, Clone)]
+pub struct EarningsSyncConfig {
+    /// 데몬 워크플로우 포함 여부
+    /// 기본값: false
+    pub enabled: bool,
+    /// 수집 기간 (오늘부터 N일)
+    pub horizon_days: i64,
+    /// API 요청 간 딜레이 (밀리초)
+    pub request_delay_ms: u64,
+}
+
+/// 실현 변동성/베타 계산 설정
+#[derive(Debug, Clone)]
+pub struct VolatilitySyncConfig {
+    /// 데몬 워크플로우 포함 여부
+    /// 기본값: true
+    pub enabled: bool,
+    /// KR 종목 베타 벤치마크 티커 (기본: KODEX 200)
+    pub kr_benchmark: String,
+    /// US 종목 베타 벤치마크 티커 (기본: SPY)
+    pub us_benchmark: String,
+    /// 재계산 기준 시간 (이 시간 이내 계산된 종목은 스킵, 기본: 20시간 - 하루 한 번)
+    pub stale_hours: i64,
+    /// 배치당 심볼 수
+    pub batch_size: i64,
+}
+
 /// 데몬 모드 설정
 #[derive(Debug, Clone)]
 pub struct DaemonConfig {
@@ -103,7 +196,16 @@ impl CollectorConfig {
             )
         })?;
 
-        Ok(Self {
+        Ok(Self::from_env_with_database_url(database_url))
+    }
+
+    /// 데이터베이스 URL을 직접 지정하고 나머지 설정은 환경변수에서 로드
+    ///
+    /// `trader bootstrap`처럼 `--db-url` 플래그로 접속 정보를 받는 호출자용입니다.
+    pub fn from_env_with_database_url(database_url: String) -> Self {
+        dotenvy::dotenv().ok();
+
+        Self {
             database_url,
             providers: DataProviderConfig {
                 // KRX API: 기본 비활성화 (승인 후 true로 변경)
@@ -113,6 +215,13 @@ impl CollectorConfig {
                 // 네이버 금융: KR 시장 fundamental 수집용
                 naver_enabled: env_var_bool("NAVER_FUNDAMENTAL_ENABLED", true),
                 naver_request_delay_ms: env_var_parse("NAVER_REQUEST_DELAY_MS", 300),
+                // 암호화폐 지표: 선택 기능
+                crypto_metrics_enabled: env_var_bool("PROVIDER_CRYPTO_METRICS_ENABLED", false),
+                // 현금 금리: 선택 기능
+                cash_rates_enabled: env_var_bool("PROVIDER_CASH_RATES_ENABLED", false),
+                cash_rates_backfill_days: env_var_parse("CASH_RATES_BACKFILL_DAYS", 3650),
+                // 매크로 레짐: 선택 기능
+                macro_regime_enabled: env_var_bool("PROVIDER_MACRO_REGIME_ENABLED", false),
             },
             symbol_sync: SymbolSyncConfig {
                 min_symbol_count: env_var_parse("SYMBOL_SYNC_MIN_COUNT", 100),
@@ -135,10 +244,43 @@ impl CollectorConfig {
                 request_delay_ms: env_var_parse("INDICATOR_REQUEST_DELAY_MS", 50),
                 include_ohlcv: env_var_bool("FUNDAMENTAL_INCLUDE_OHLCV", true),
             },
+            minute_backfill: MinuteBackfillConfig {
+                days: env_var_parse("MINUTE_BACKFILL_DAYS", 30),
+                max_pages_per_day: env_var_parse("MINUTE_BACKFILL_MAX_PAGES_PER_DAY", 5),
+                request_delay_ms: env_var_parse("MINUTE_BACKFILL_REQUEST_DELAY_MS", 100),
+            },
+            investor_flow: InvestorFlowConfig {
+                enabled: env_var_bool("INVESTOR_FLOW_ENABLED", false),
+                days: env_var_parse("INVESTOR_FLOW_DAYS", 60),
+                batch_size: env_var_parse("INVESTOR_FLOW_BATCH_SIZE", 500),
+                request_delay_ms: env_var_parse("INVESTOR_FLOW_REQUEST_DELAY_MS", 300),
+                global_score_weight: env_var_parse("GLOBAL_SCORE_FLOW_WEIGHT", 0.0),
+                global_score_days: env_var_parse("GLOBAL_SCORE_FLOW_DAYS", 5),
+            },
+            etf_sync: EtfSyncConfig {
+                enabled: env_var_bool("ETF_SYNC_ENABLED", false),
+                days: env_var_parse("ETF_SYNC_DAYS", 60),
+                batch_size: env_var_parse("ETF_SYNC_BATCH_SIZE", 300),
+                request_delay_ms: env_var_parse("ETF_SYNC_REQUEST_DELAY_MS", 300),
+            },
+            earnings: EarningsSyncConfig {
+                enabled: env_var_bool("EARNINGS_SYNC_ENABLED", false),
+                horizon_days: env_var_parse("EARNINGS_HORIZON_DAYS", 30),
+                request_delay_ms: env_var_parse("EARNINGS_REQUEST_DELAY_MS", 500),
+            },
+            volatility: VolatilitySyncConfig {
+                enabled: env_var_bool("VOLATILITY_SYNC_ENABLED", true),
+                kr_benchmark: std::env::var("VOLATILITY_BENCHMARK_KR")
+                    .unwrap_or_else(|_| "069500".to_string()),
+                us_benchmark: std::env::var("VOLATILITY_BENCHMARK_US")
+                    .unwrap_or_else(|_| "SPY".to_string()),
+                stale_hours: env_var_parse("VOLATILITY_STALE_HOURS", 20),
+                batch_size: env_var_parse("VOLATILITY_BATCH_SIZE", 3000),
+            },
             daemon: DaemonConfig {
                 interval_minutes: env_var_parse("DAEMON_INTERVAL_MINUTES", 60),
             },
-        })
+        }
     }
 }
 
@@ -156,6 +298,34 @@ impl FundamentalCollectConfig {
     }
 }
 
+impl MinuteBackfillConfig {
+    /// API 요청 간 딜레이를 Duration으로 반환
+    pub fn request_delay(&self) -> Duration {
+        Duration::from_millis(self.request_delay_ms)
+    }
+}
+
+impl InvestorFlowConfig {
+    /// API 요청 간 딜레이를 Duration으로 반환
+    pub fn request_delay(&self) -> Duration {
+        Duration::from_millis(self.request_delay_ms)
+    }
+}
+
+impl EtfSyncConfig {
+    /// API 요청 간 딜레이를 Duration으로 반환
+    pub fn request_delay(&self) -> Duration {
+        Duration::from_millis(self.request_delay_ms)
+    }
+}
+
+impl EarningsSyncConfig {
+    /// API 요청 간 딜레이를 Duration으로 반환
+    pub fn request_delay(&self) -> Duration {
+        Duration::from_millis(self.request_delay_ms)
+    }
+}
+
 impl DaemonConfig {
     /// 워크플로우 실행 주기를 Duration으로 반환
     pub fn interval(&self) -> Duration {
@@ -3,6 +3,12 @@
 //! 이 crate는 API 서버와 독립적으로 데이터를 수집하는 바이너리를 제공합니다:
 //! - 심볼 정보 동기화 (KRX, Binance, Yahoo Finance)
 //! - OHLCV 데이터 수집 (일봉)
+//! - KIS 과거 분봉 백필 (KR 1분봉)
+//! - KR 투자자별 순매수 수집 (외국인/기관/개인)
+//! - KR ETF NAV/괴리율 및 구성종목(PDF) 수집
+//! - 실적 발표 일정 수집 (US Nasdaq, KR DART 추정, 선택)
+//! - 암호화폐 온체인/파생상품 지표 수집 (선택)
+//! - 현금 금리 수집 (CD/국고채, 미국 T-bill, 선택)
 //! - Fundamental 데이터 수집 (재무 지표)
 
 pub mod config;
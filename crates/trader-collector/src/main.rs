@@ -48,12 +48,26 @@ async fn run_workflow(pool: &PgPool, config: &CollectorConfig) {
         Err(e) => tracing::error!("OHLCV 수집 실패: {}", e),
     }
 
+    // 3-1. 사용자 정의 지수 계산 (지표 동기화 전에 실행해야 지수 레짐도 함께 갱신됨)
+    match modules::sync_custom_indices(pool, None).await {
+        Ok(stats) => stats.log_summary("사용자 정의 지수 계산"),
+        Err(e) => tracing::error!("사용자 정의 지수 계산 실패: {}", e),
+    }
+
     // 4. 분석 지표 동기화 (누락된 지표 보완)
     match modules::sync_indicators(pool, config, None).await {
         Ok(stats) => stats.log_summary("지표 동기화"),
         Err(e) => tracing::error!("지표 동기화 실패: {}", e),
     }
 
+    // 4-1. 실현 변동성/베타 계산 (종목당 하루 한 번, VOLATILITY_STALE_HOURS 기준)
+    if config.volatility.enabled {
+        match modules::sync_symbol_volatility(pool, config, Default::default()).await {
+            Ok(stats) => stats.log_summary("실현 변동성 동기화"),
+            Err(e) => tracing::error!("실현 변동성 동기화 실패: {}", e),
+        }
+    }
+
     // 5. GlobalScore 동기화 (랭킹용)
     match modules::sync_global_scores(pool, config, None).await {
         Ok(stats) => stats.log_summary("GlobalScore 동기화"),
@@ -65,6 +79,54 @@ async fn run_workflow(pool: &PgPool, config: &CollectorConfig) {
         Ok(stats) => stats.log_summary("스크리닝 뷰 갱신"),
         Err(e) => tracing::error!("스크리닝 뷰 갱신 실패: {}", e),
     }
+
+    // 7. 투자자별 순매수 동기화 (활성화된 경우)
+    if config.investor_flow.enabled {
+        match modules::sync_investor_flows(pool, config, Default::default()).await {
+            Ok(stats) => stats.log_summary("투자자별 순매수 동기화"),
+            Err(e) => tracing::error!("투자자별 순매수 동기화 실패: {}", e),
+        }
+    }
+
+    // 8. ETF NAV/구성종목 동기화 (활성화된 경우)
+    if config.etf_sync.enabled {
+        match modules::sync_etf_data(pool, config, Default::default()).await {
+            Ok(stats) => stats.log_summary("ETF 동기화"),
+            Err(e) => tracing::error!("ETF 동기화 실패: {}", e),
+        }
+    }
+
+    // 9. 실적 발표 일정 동기화 (활성화된 경우)
+    if config.earnings.enabled {
+        match modules::sync_earnings_calendar(pool, config, None).await {
+            Ok(stats) => stats.log_summary("실적 발표 일정 동기화"),
+            Err(e) => tracing::error!("실적 발표 일정 동기화 실패: {}", e),
+        }
+    }
+
+    // 10. 암호화폐 지표 동기화 (활성화된 경우)
+    if config.providers.crypto_metrics_enabled {
+        match modules::sync_crypto_metrics(pool, config).await {
+            Ok(stats) => stats.log_summary("암호화폐 지표 동기화"),
+            Err(e) => tracing::error!("암호화폐 지표 동기화 실패: {}", e),
+        }
+    }
+
+    // 11. 현금 금리 동기화 (활성화된 경우)
+    if config.providers.cash_rates_enabled {
+        match modules::sync_cash_rates(pool, config, None).await {
+            Ok(stats) => stats.log_summary("현금 금리 동기화"),
+            Err(e) => tracing::error!("현금 금리 동기화 실패: {}", e),
+        }
+    }
+
+    // 12. 매크로 레짐 종합 지표 계산 (활성화된 경우, 시장 폭 계산을 위해 OHLCV 이후 실행)
+    if config.providers.macro_regime_enabled {
+        match modules::sync_macro_regime(pool, config).await {
+            Ok(stats) => stats.log_summary("매크로 레짐 동기화"),
+            Err(e) => tracing::error!("매크로 레짐 동기화 실패: {}", e),
+        }
+    }
 }
 
 #[derive(Parser)]
@@ -101,6 +163,21 @@ enum Commands {
         resume: bool,
     },
 
+    /// KIS 과거 1분봉 백필 (KR 종목, 실전 계좌 필요)
+    BackfillMinutes {
+        /// 대상 종목코드 (쉼표로 구분, 예: "005930,000660")
+        #[arg(long)]
+        symbols: String,
+
+        /// 백필 일수 (영업일, 기본: MINUTE_BACKFILL_DAYS)
+        #[arg(long)]
+        days: Option<u32>,
+
+        /// 백필 종료일 (YYYY-MM-DD, 기본: 전일)
+        #[arg(long)]
+        end_date: Option<chrono::NaiveDate>,
+    },
+
     /// 체크포인트 상태 조회/관리
     Checkpoint {
         #[command(subcommand)]
@@ -160,6 +237,66 @@ enum Commands {
         stale_hours: Option<u32>,
     },
 
+    /// KR 투자자별 순매수 동기화 (외국인/기관/개인, KRX)
+    SyncInvestorFlows {
+        /// 특정 종목만 처리 (쉼표로 구분, 예: "005930,000660")
+        #[arg(long)]
+        symbols: Option<String>,
+
+        /// 최초 수집 일수 (기본: INVESTOR_FLOW_DAYS)
+        #[arg(long)]
+        days: Option<i64>,
+    },
+
+    /// KR ETF NAV/괴리율 및 구성종목(PDF) 동기화 (KRX)
+    SyncEtf {
+        /// 특정 ETF만 처리 (쉼표로 구분, 예: "069500,122630")
+        #[arg(long)]
+        symbols: Option<String>,
+
+        /// 최초 NAV 수집 일수 (기본: ETF_SYNC_DAYS)
+        #[arg(long)]
+        days: Option<i64>,
+    },
+
+    /// 실적 발표 일정 동기화 (US Nasdaq, KR DART 잠정실적 기반 추정)
+    SyncEarnings {
+        /// 수집 기간 (오늘부터 N일, 기본: EARNINGS_HORIZON_DAYS)
+        #[arg(long)]
+        days: Option<i64>,
+    },
+
+    /// 암호화폐 온체인/파생상품 지표 동기화 (순유입량, 스테이블코인, 선물 베이시스)
+    SyncCryptoMetrics,
+
+    /// 현금 금리 동기화 (CD 91일/국고채 3년, 미국 T-bill 3개월)
+    SyncCashRates {
+        /// 최초 수집 일수 (기본: CASH_RATES_BACKFILL_DAYS)
+        #[arg(long)]
+        days: Option<i64>,
+    },
+
+    /// 매크로 레짐 종합 지표 계산 (USD/KRW 추세, 금리차, VIX/VKOSPI, 시장 폭)
+    SyncMacroRegime,
+
+    /// 종목별 실현 변동성(20/60/252일)/베타 계산
+    SyncVolatility {
+        /// 특정 심볼만 처리 (쉼표로 구분, 예: "005930,AAPL")
+        #[arg(long)]
+        symbols: Option<String>,
+
+        /// N시간 이내 계산된 심볼 스킵 (기본: VOLATILITY_STALE_HOURS)
+        #[arg(long)]
+        stale_hours: Option<i64>,
+    },
+
+    /// 사용자 정의 지수 합성 일봉 계산 (IDX_<코드> 티커로 OHLCV 저장)
+    SyncCustomIndices {
+        /// 특정 지수만 처리 (쉼표로 구분, 예: "SEMI_KR,AI_US")
+        #[arg(long)]
+        codes: Option<String>,
+    },
+
     /// 스크리닝 Materialized View 갱신
     /// symbol_info + fundamental + global_score 통합 뷰 갱신
     RefreshScreening,
@@ -239,6 +376,15 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             let stats = modules::collect_ohlcv(&pool, &config, symbols, stale_hours).await?;
             stats.log_summary("OHLCV 수집");
         }
+        Commands::BackfillMinutes {
+            symbols,
+            days,
+            end_date,
+        } => {
+            let options = modules::MinuteBackfillOptions { days, end_date };
+            let stats = modules::backfill_minutes(&pool, &config, symbols, options).await?;
+            stats.log_summary("분봉 백필");
+        }
         Commands::Checkpoint { action } => match action {
             CheckpointAction::List => {
                 let checkpoints = modules::list_checkpoints(&pool).await?;
@@ -361,6 +507,59 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 );
             }
         }
+        Commands::SyncInvestorFlows { symbols, days } => {
+            let options = modules::InvestorFlowSyncOptions { symbols, days };
+            let stats = modules::sync_investor_flows(&pool, &config, options).await?;
+            stats.log_summary("투자자별 순매수 동기화");
+        }
+        Commands::SyncEtf { symbols, days } => {
+            let options = modules::EtfSyncOptions { symbols, days };
+            let stats = modules::sync_etf_data(&pool, &config, options).await?;
+            stats.log_summary("ETF 동기화");
+        }
+        Commands::SyncEarnings { days } => {
+            let stats = modules::sync_earnings_calendar(&pool, &config, days).await?;
+            stats.log_summary("실적 발표 일정 동기화");
+        }
+        Commands::SyncCryptoMetrics => {
+            if !config.providers.crypto_metrics_enabled {
+                tracing::warn!("암호화폐 지표 수집이 비활성화되어 있습니다. PROVIDER_CRYPTO_METRICS_ENABLED=true로 활성화하세요.");
+                return Ok(());
+            }
+            let stats = modules::sync_crypto_metrics(&pool, &config).await?;
+            stats.log_summary("암호화폐 지표 동기화");
+        }
+        Commands::SyncCashRates { days } => {
+            if !config.providers.cash_rates_enabled {
+                tracing::warn!("현금 금리 수집이 비활성화되어 있습니다. PROVIDER_CASH_RATES_ENABLED=true로 활성화하세요.");
+                return Ok(());
+            }
+            let stats = modules::sync_cash_rates(&pool, &config, days).await?;
+            stats.log_summary("현금 금리 동기화");
+        }
+        Commands::SyncMacroRegime => {
+            if !config.providers.macro_regime_enabled {
+                tracing::warn!("매크로 레짐 계산이 비활성화되어 있습니다. PROVIDER_MACRO_REGIME_ENABLED=true로 활성화하세요.");
+                return Ok(());
+            }
+            let stats = modules::sync_macro_regime(&pool, &config).await?;
+            stats.log_summary("매크로 레짐 동기화");
+        }
+        Commands::SyncVolatility {
+            symbols,
+            stale_hours,
+        } => {
+            let options = modules::VolatilitySyncOptions {
+                symbols,
+                stale_hours,
+            };
+            let stats = modules::sync_symbol_volatility(&pool, &config, options).await?;
+            stats.log_summary("실현 변동성 동기화");
+        }
+        Commands::SyncCustomIndices { codes } => {
+            let stats = modules::sync_custom_indices(&pool, codes).await?;
+            stats.log_summary("사용자 정의 지수 계산");
+        }
         Commands::RefreshScreening => {
             let stats = modules::refresh_screening_view(&pool).await?;
             stats.log_summary("스크리닝 뷰 갱신");
@@ -456,6 +655,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 modules::collect_ohlcv(&pool, &config, symbols_filter.clone(), None).await?;
             ohlcv_stats.log_summary("OHLCV 수집");
 
+            // 3-1. 사용자 정의 지수 계산 (전체 실행에서만)
+            if !is_single {
+                let index_stats = modules::sync_custom_indices(&pool, None).await?;
+                index_stats.log_summary("사용자 정의 지수 계산");
+            }
+
             // 4. 분석 지표 동기화 (누락된 지표 보완)
             tracing::info!("Step 4/6: 분석 지표 동기화");
             let indicator_stats =
@@ -0,0 +1,93 @@
+//! 현금 금리 동기화 모듈.
+//!
+//! CD 91일/국고채 3년(한국은행 ECOS)과 미국 T-bill 3개월(FRED) 일별 금리를 수집하여
+//! `macro_series` 테이블에 저장합니다. 시리즈별 마지막 관측일 다음 날부터 증분 수집하며,
+//! 백테스트와 자산배분 전략은 저장된 금리로 유휴 현금 이자를 계산합니다.
+
+use chrono::Utc;
+use sqlx::PgPool;
+use std::time::Instant;
+use trader_data::provider::cash_rates::{CashRateConfig, CashRateProvider};
+use trader_data::storage::macro_series::cash_rates_to_points;
+use trader_data::MacroSeriesStore;
+
+use crate::modules::investor_flow_sync::sync_start_date;
+use crate::{CollectionStats, CollectorConfig, Result};
+
+/// 현금 금리 동기화
+///
+/// # Arguments
+///
+/// * `pool` - DB 연결 풀
+/// * `config` - 수집 설정
+/// * `days` - 최초 수집 일수 (None이면 CASH_RATES_BACKFILL_DAYS)
+pub async fn sync_cash_rates(
+    pool: &PgPool,
+    config: &CollectorConfig,
+    days: Option<i64>,
+) -> Result<CollectionStats> {
+    let start = Instant::now();
+    let mut stats = CollectionStats::new();
+
+    let provider_config = CashRateConfig::from_env();
+    if provider_config.ecos_api_key.is_none() {
+        tracing::info!("ECOS_API_KEY 미설정 - 국내 CD/국고채 금리는 수집하지 않습니다");
+    }
+
+    let provider = CashRateProvider::new(provider_config);
+    let store = MacroSeriesStore::new(pool.clone());
+
+    let today = Utc::now().date_naive();
+    let days = days.unwrap_or(config.providers.cash_rates_backfill_days);
+
+    for series in provider.available_series() {
+        stats.total += 1;
+
+        let last_date = match store.latest_observed_at(series.series_key()).await {
+            Ok(observed_at) => observed_at.map(|t| t.date_naive()),
+            Err(e) => {
+                tracing::warn!(series = series.series_key(), error = %e, "마지막 관측일 조회 실패");
+                None
+            }
+        };
+        let from = sync_start_date(last_date, today, days);
+        if from > today {
+            stats.skipped += 1;
+            continue;
+        }
+
+        let rates = match provider.fetch_series(series, from, today).await {
+            Ok(rates) => rates,
+            Err(e) => {
+                stats.errors += 1;
+                tracing::error!(series = series.series_key(), error = %e, "현금 금리 조회 실패");
+                continue;
+            }
+        };
+
+        let points = cash_rates_to_points(series, &rates, "cash_rates");
+        if points.is_empty() {
+            stats.empty += 1;
+            continue;
+        }
+
+        match store.save_points(&points).await {
+            Ok(_) => {
+                stats.success += 1;
+                tracing::info!(
+                    series = series.series_key(),
+                    days = points.len(),
+                    latest = ?rates.last(),
+                    "현금 금리 저장 완료"
+                );
+            }
+            Err(e) => {
+                stats.errors += 1;
+                tracing::error!(series = series.series_key(), error = %e, "현금 금리 저장 실패");
+            }
+        }
+    }
+
+    stats.elapsed = start.elapsed();
+    Ok(stats)
+}
@@ -0,0 +1,65 @@
+//! 암호화폐 온체인/파생상품 지표 동기화 모듈.
+//!
+//! BTC/ETH 등의 거래소 순유입량, 스테이블코인 공급량, 선물 베이시스를 수집하여
+//! `macro_series` 테이블에 저장합니다. 전략 컨텍스트는 저장된 최신 값을 읽어 사용합니다.
+
+use crate::{CollectionStats, CollectorConfig, Result};
+use sqlx::PgPool;
+use std::time::Instant;
+use trader_data::provider::crypto_metrics::{CryptoMetricsConfig, CryptoMetricsProvider};
+use trader_data::storage::macro_series::crypto_metrics_to_points;
+use trader_data::MacroSeriesStore;
+
+/// 암호화폐 지표 동기화
+///
+/// `PROVIDER_CRYPTO_METRICS_ENABLED=false`이면 아무 작업도 하지 않습니다.
+pub async fn sync_crypto_metrics(
+    pool: &PgPool,
+    config: &CollectorConfig,
+) -> Result<CollectionStats> {
+    let start = Instant::now();
+    let mut stats = CollectionStats::new();
+
+    if !config.providers.crypto_metrics_enabled {
+        tracing::info!("암호화폐 지표 수집 비활성화됨 (PROVIDER_CRYPTO_METRICS_ENABLED=false)");
+        return Ok(stats);
+    }
+
+    let provider_config = CryptoMetricsConfig::from_env();
+    if provider_config.cryptoquant_api_key.is_none() {
+        tracing::info!("CRYPTOQUANT_API_KEY 미설정 - 거래소 순유입량은 수집하지 않습니다");
+    }
+
+    let provider = CryptoMetricsProvider::new(provider_config);
+    let store = MacroSeriesStore::new(pool.clone());
+
+    for metrics in provider.fetch_all().await {
+        stats.total += 1;
+
+        let points = crypto_metrics_to_points(&metrics, "crypto_metrics");
+        if points.is_empty() {
+            stats.empty += 1;
+            tracing::warn!(asset = %metrics.asset, "수집된 암호화폐 지표 없음");
+            continue;
+        }
+
+        match store.save_points(&points).await {
+            Ok(_) => {
+                stats.success += 1;
+                tracing::info!(
+                    asset = %metrics.asset,
+                    series = points.len(),
+                    bias = ?metrics.bias(),
+                    "암호화폐 지표 저장 완료"
+                );
+            }
+            Err(e) => {
+                stats.errors += 1;
+                tracing::error!(asset = %metrics.asset, error = %e, "암호화폐 지표 저장 실패");
+            }
+        }
+    }
+
+    stats.elapsed = start.elapsed();
+    Ok(stats)
+}
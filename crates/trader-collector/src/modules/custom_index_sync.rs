@@ -0,0 +1,72 @@
+//! 사용자 정의 지수 계산 모듈.
+//!
+//! 활성화된 사용자 정의 지수(`custom_index`)마다 구성종목 일봉으로 합성 일봉을
+//! 다시 계산하여 `IDX_<코드>` 티커로 `ohlcv` 테이블에 저장합니다.
+//! OHLCV 수집 직후, 지표 동기화 전에 실행해야 지수의 시장 레짐이 같은 주기에 갱신됩니다.
+
+use sqlx::PgPool;
+use std::time::Instant;
+
+use trader_data::CustomIndexStore;
+
+use crate::error::CollectorError;
+use crate::{CollectionStats, Result};
+
+/// 사용자 정의 지수 계산
+///
+/// # Arguments
+///
+/// * `pool` - DB 연결 풀
+/// * `codes` - 특정 지수만 처리 (쉼표 구분, None이면 활성 지수 전체)
+pub async fn sync_custom_indices(pool: &PgPool, codes: Option<String>) -> Result<CollectionStats> {
+    let start = Instant::now();
+    let mut stats = CollectionStats::new();
+    let store = CustomIndexStore::new(pool.clone());
+
+    let mut indices = store
+        .list_enabled()
+        .await
+        .map_err(|e| CollectorError::DataSource(e.to_string()))?;
+    if let Some(ref codes) = codes {
+        let codes: Vec<&str> = codes.split(',').map(|s| s.trim()).collect();
+        indices.retain(|index| codes.contains(&index.code.as_str()));
+    }
+
+    if indices.is_empty() {
+        tracing::info!("계산할 사용자 정의 지수가 없습니다");
+        return Ok(stats);
+    }
+
+    for index in &indices {
+        stats.total += 1;
+
+        let result = match index.definition() {
+            Ok(definition) => store.rebuild(&definition).await,
+            Err(e) => Err(e),
+        };
+
+        match result {
+            Ok(result) => {
+                stats.success += 1;
+                stats.total_klines += result.bars;
+                if !result.missing_constituents.is_empty() {
+                    tracing::warn!(
+                        code = %index.code,
+                        missing = ?result.missing_constituents,
+                        "일봉이 없는 구성종목 제외"
+                    );
+                }
+            }
+            Err(e) => {
+                stats.errors += 1;
+                tracing::error!(code = %index.code, error = %e, "사용자 정의 지수 계산 실패");
+                if let Err(e) = store.record_error(&index.code, &e.to_string()).await {
+                    tracing::warn!(code = %index.code, error = %e, "계산 실패 기록 실패");
+                }
+            }
+        }
+    }
+
+    stats.elapsed = start.elapsed();
+    Ok(stats)
+}
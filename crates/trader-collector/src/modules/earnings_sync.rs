@@ -0,0 +1,98 @@
+//! 실적 발표 일정 동기화 모듈.
+//!
+//! 오늘부터 `EARNINGS_HORIZON_DAYS`일 동안의 실적 발표 일정을 수집하여
+//! `earnings_calendar` 테이블에 저장합니다.
+//!
+//! - US: Nasdaq 일자별 실적 발표 일정 (주말 제외, 일자별 스냅샷 교체)
+//! - KR: DART 전년 동기 잠정실적 공시 기반 예정일 추정 (`DART_API_KEY` 필요)
+//!
+//! API 서버의 전략 엔진은 저장된 일정으로 실적 발표 전 신규 진입 차단/자동 청산을 적용합니다.
+
+use chrono::{Datelike, Duration, Utc, Weekday};
+use sqlx::PgPool;
+use std::time::Instant;
+use trader_data::provider::earnings::{EarningsCalendarConfig, EarningsCalendarProvider};
+use trader_data::EarningsCalendarStore;
+
+use crate::{CollectionStats, CollectorConfig, Result};
+
+/// 실적 발표 일정 동기화
+///
+/// # Arguments
+///
+/// * `pool` - DB 연결 풀
+/// * `config` - 수집 설정
+/// * `days` - 수집 기간 (None이면 EARNINGS_HORIZON_DAYS)
+pub async fn sync_earnings_calendar(
+    pool: &PgPool,
+    config: &CollectorConfig,
+    days: Option<i64>,
+) -> Result<CollectionStats> {
+    let start = Instant::now();
+    let mut stats = CollectionStats::new();
+    let earnings_config = &config.earnings;
+
+    let provider = EarningsCalendarProvider::new(EarningsCalendarConfig::from_env());
+    let store = EarningsCalendarStore::new(pool.clone());
+
+    let today = Utc::now().date_naive();
+    let horizon = days.unwrap_or(earnings_config.horizon_days).max(1);
+    let until = today + Duration::days(horizon);
+    let delay = earnings_config.request_delay();
+
+    tracing::info!(from = %today, to = %until, "실적 발표 일정 동기화 시작");
+
+    // 1. 미국: 일자별 조회
+    let mut date = today;
+    while date <= until {
+        if matches!(date.weekday(), Weekday::Sat | Weekday::Sun) {
+            date += Duration::days(1);
+            continue;
+        }
+        stats.total += 1;
+
+        match provider.fetch_us_date(date).await {
+            Ok(events) if events.is_empty() => stats.empty += 1,
+            Ok(events) => match store.replace_events("nasdaq", date, date, &events).await {
+                Ok(_) => stats.success += 1,
+                Err(e) => {
+                    stats.errors += 1;
+                    tracing::error!(date = %date, error = %e, "미국 실적 발표 일정 저장 실패");
+                }
+            },
+            Err(e) => {
+                stats.errors += 1;
+                tracing::error!(date = %date, error = %e, "미국 실적 발표 일정 조회 실패");
+            }
+        }
+
+        tokio::time::sleep(delay).await;
+        date += Duration::days(1);
+    }
+
+    // 2. 국내: 전년 동기 잠정실적 공시로 예정일 추정
+    if provider.has_kr_source() {
+        stats.total += 1;
+        match provider.fetch_kr_projected(today, until).await {
+            Ok(events) => match store.replace_events("dart", today, until, &events).await {
+                Ok(saved) => {
+                    stats.success += 1;
+                    tracing::info!(count = saved, "국내 실적 발표 예정일 저장");
+                }
+                Err(e) => {
+                    stats.errors += 1;
+                    tracing::error!(error = %e, "국내 실적 발표 예정일 저장 실패");
+                }
+            },
+            Err(e) => {
+                stats.errors += 1;
+                tracing::error!(error = %e, "국내 실적 발표 예정일 조회 실패");
+            }
+        }
+    } else {
+        tracing::info!("DART_API_KEY 미설정 - 국내 실적 발표 일정은 수집하지 않습니다");
+    }
+
+    stats.elapsed = start.elapsed();
+    Ok(stats)
+}
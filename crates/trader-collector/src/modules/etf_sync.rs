@@ -0,0 +1,165 @@
+//! ETF NAV/구성종목 동기화 모듈.
+//!
+//! KRX 정보데이터시스템에서 KR ETF의 일별 NAV를 수집하여 괴리율과 함께
+//! `etf_nav_history` 테이블에 저장하고, 마지막 거래일 기준 구성종목(PDF)을
+//! `etf_constituent` 테이블에 저장합니다. NAV는 ETF별 마지막 수집일 다음 날부터 증분 수집합니다.
+
+use chrono::{Duration, Utc};
+use sqlx::PgPool;
+use std::time::Instant;
+
+use trader_data::{EtfStore, KrxDataSource};
+
+use crate::error::CollectorError;
+use crate::modules::investor_flow_sync::sync_start_date;
+use crate::{CollectionStats, CollectorConfig, Result};
+
+/// ETF 동기화 옵션
+#[derive(Debug, Default)]
+pub struct EtfSyncOptions {
+    /// 특정 ETF만 처리 (쉼표 구분, None이면 KR 활성 ETF)
+    pub symbols: Option<String>,
+    /// 최초 NAV 수집 일수 (기본: ETF_SYNC_DAYS)
+    pub days: Option<i64>,
+}
+
+/// ETF NAV/구성종목 동기화
+///
+/// # Arguments
+///
+/// * `pool` - DB 연결 풀
+/// * `config` - 수집 설정
+/// * `options` - 동기화 옵션
+pub async fn sync_etf_data(
+    pool: &PgPool,
+    config: &CollectorConfig,
+    options: EtfSyncOptions,
+) -> Result<CollectionStats> {
+    let start = Instant::now();
+    let mut stats = CollectionStats::new();
+    let etf_config = &config.etf_sync;
+
+    let tickers = match options.symbols {
+        Some(ref symbols) => symbols
+            .split(',')
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+            .collect(),
+        None => get_target_etfs(pool, etf_config.batch_size).await?,
+    };
+
+    if tickers.is_empty() {
+        tracing::info!("동기화할 KR ETF가 없습니다");
+        return Ok(stats);
+    }
+
+    let krx = KrxDataSource::new();
+    let store = EtfStore::new(pool.clone());
+
+    // KST 기준 오늘
+    let today = (Utc::now() + Duration::hours(9)).date_naive();
+    let days = options.days.unwrap_or(etf_config.days);
+    let delay = etf_config.request_delay();
+
+    tracing::info!(etfs = tickers.len(), days = days, "ETF 동기화 시작");
+
+    for ticker in &tickers {
+        stats.total += 1;
+
+        let last_date = match store.latest_nav_date(ticker).await {
+            Ok(date) => date,
+            Err(e) => {
+                tracing::warn!(ticker = %ticker, error = %e, "마지막 NAV 수집일 조회 실패");
+                None
+            }
+        };
+        let from = sync_start_date(last_date, today, days);
+        if from > today {
+            stats.skipped += 1;
+            continue;
+        }
+
+        let points = match krx
+            .get_etf_nav_history(
+                ticker,
+                &from.format("%Y%m%d").to_string(),
+                &today.format("%Y%m%d").to_string(),
+            )
+            .await
+        {
+            Ok(points) => points,
+            Err(e) => {
+                stats.errors += 1;
+                tracing::error!(ticker = %ticker, error = %e, "ETF NAV 조회 실패");
+                tokio::time::sleep(delay).await;
+                continue;
+            }
+        };
+        tokio::time::sleep(delay).await;
+
+        let Some(latest_date) = points.iter().map(|p| p.trade_date).max() else {
+            stats.empty += 1;
+            continue;
+        };
+
+        if let Err(e) = store.save_nav_points(&points).await {
+            stats.errors += 1;
+            tracing::error!(ticker = %ticker, error = %e, "ETF NAV 저장 실패");
+            continue;
+        }
+
+        // 구성종목은 마지막 거래일 스냅샷만 갱신
+        match krx
+            .get_etf_constituents(ticker, &latest_date.format("%Y%m%d").to_string())
+            .await
+        {
+            Ok(constituents) => {
+                if let Err(e) = store
+                    .save_constituents(ticker, latest_date, &constituents)
+                    .await
+                {
+                    tracing::warn!(ticker = %ticker, error = %e, "ETF 구성종목 저장 실패");
+                }
+                tracing::debug!(
+                    ticker = %ticker,
+                    nav_days = points.len(),
+                    constituents = constituents.len(),
+                    "ETF 동기화"
+                );
+            }
+            Err(e) => {
+                tracing::warn!(ticker = %ticker, error = %e, "ETF 구성종목 조회 실패");
+            }
+        }
+        stats.success += 1;
+
+        tokio::time::sleep(delay).await;
+    }
+
+    stats.elapsed = start.elapsed();
+    Ok(stats)
+}
+
+/// 동기화 대상 KR ETF 조회 (NAV 수집이 오래된 순).
+async fn get_target_etfs(pool: &PgPool, limit: i64) -> Result<Vec<String>> {
+    sqlx::query_scalar::<_, String>(
+        r#"
+        SELECT si.ticker
+        FROM symbol_info si
+        LEFT JOIN (
+            SELECT ticker, MAX(trade_date) AS last_date
+            FROM etf_nav_history
+            GROUP BY ticker
+        ) h ON h.ticker = si.ticker
+        WHERE si.is_active = true
+          AND si.market = 'KR'
+          AND si.symbol_type = 'ETF'
+        ORDER BY h.last_date NULLS FIRST, si.ticker
+        LIMIT $1
+        "#,
+    )
+    .bind(limit)
+    .fetch_all(pool)
+    .await
+    .map_err(CollectorError::Database)
+}
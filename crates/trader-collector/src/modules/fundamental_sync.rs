@@ -145,7 +145,7 @@ async fn sync_valuation(
 
     let all_valuations: Vec<KrxValuation> = kospi_valuation
         .into_iter()
-        .chain(kosdaq_valuation.into_iter())
+        .chain(kosdaq_valuation)
         .collect();
 
     info!(count = all_valuations.len(), "가치 지표 데이터 조회 완료");
@@ -243,10 +243,7 @@ async fn sync_market_data(
         }
     };
 
-    let all_trades: Vec<KrxDailyTrade> = kospi_trades
-        .into_iter()
-        .chain(kosdaq_trades.into_iter())
-        .collect();
+    let all_trades: Vec<KrxDailyTrade> = kospi_trades.into_iter().chain(kosdaq_trades).collect();
 
     info!(count = all_trades.len(), "일별 매매정보 조회 완료");
 
@@ -1,6 +1,7 @@
 //! Global Score 동기화 모듈.
 //!
 //! 모든 활성 심볼에 대해 GlobalScore를 계산하여 symbol_global_score 테이블에 저장합니다.
+//! 7Factor 점수는 score_history 테이블에 일별로 함께 기록합니다.
 
 use rust_decimal::Decimal;
 use sqlx::PgPool;
@@ -8,10 +9,14 @@ use std::time::Instant;
 use tracing::{debug, info, warn};
 use uuid::Uuid;
 
-use trader_analytics::{GlobalScorer, GlobalScorerParams, IndicatorEngine, StructuralFeatures};
+use trader_analytics::{
+    GlobalScorer, GlobalScorerParams, IndicatorEngine, RouteStateCalculator, SevenFactorCalculator,
+    SevenFactorInput, StructuralFeatures,
+};
 use trader_analytics::indicators::AtrParams;
-use trader_core::{MarketType, Symbol, Timeframe};
+use trader_core::{InvestorFlowSummary, Kline, MarketType, Symbol, Timeframe};
 use trader_data::cache::historical::CachedHistoricalDataProvider;
+use trader_data::InvestorFlowStore;
 
 use super::checkpoint::{self, CheckpointStatus};
 use crate::config::CollectorConfig;
@@ -35,6 +40,7 @@ pub struct GlobalScoreSyncOptions {
 /// 2. 각 심볼에 대해 OHLCV 데이터 조회 (60일)
 /// 3. GlobalScorer로 점수 계산
 /// 4. symbol_global_score 테이블에 UPSERT
+/// 5. 7Factor 점수를 score_history 테이블에 일별 UPSERT
 ///
 /// # 인자
 /// * `pool` - 데이터베이스 연결 풀
@@ -59,8 +65,17 @@ pub async fn sync_global_scores_with_options(
     let start = Instant::now();
     let mut stats = CollectionStats::new();
 
-    // GlobalScorer 초기화
-    let scorer = GlobalScorer::new();
+    // GlobalScorer 초기화 (GLOBAL_SCORE_FLOW_WEIGHT > 0이면 Flow Momentum 팩터 활성화)
+    let flow_weight = config.investor_flow.global_score_weight;
+    let (scorer, flow_days) = if flow_weight > 0.0 {
+        info!(weight = flow_weight, "Flow Momentum 팩터 활성화");
+        (
+            GlobalScorer::with_flow_momentum(flow_weight),
+            Some(config.investor_flow.global_score_days),
+        )
+    } else {
+        (GlobalScorer::new(), None)
+    };
     let data_provider = CachedHistoricalDataProvider::new(pool.clone());
 
     // 체크포인트 로드 (resume 모드)
@@ -142,6 +157,7 @@ pub async fn sync_global_scores_with_options(
             *symbol_info_id,
             ticker,
             market,
+            flow_days,
         )
         .await
         {
@@ -182,6 +198,8 @@ pub async fn sync_global_scores_with_options(
 }
 
 /// 단일 심볼에 대해 GlobalScore 계산 및 저장.
+///
+/// `flow_days`가 있으면 KR 종목의 최근 N 거래일 투자자별 순매수를 FLOW 팩터에 반영합니다.
 async fn calculate_and_save(
     pool: &PgPool,
     scorer: &GlobalScorer,
@@ -189,6 +207,7 @@ async fn calculate_and_save(
     symbol_info_id: Uuid,
     ticker: &str,
     market: &str,
+    flow_days: Option<i64>,
 ) -> Result<bool> {
     // 1. MarketType 변환
     let market_type = match market {
@@ -262,7 +281,25 @@ async fn calculate_and_save(
         else { 0.1 }                                     // 1억 미만
     });
 
-    // 8. GlobalScore 계산
+    // 8. 투자자별 순매수 요약 (Flow Momentum 팩터 활성화 시, KR만)
+    let investor_flow = match flow_days {
+        Some(days) if market == "KR" => {
+            match InvestorFlowStore::new(pool.clone())
+                .get_recent_flows(ticker, days)
+                .await
+            {
+                Ok(flows) if !flows.is_empty() => Some(InvestorFlowSummary::from_flows(&flows)),
+                Ok(_) => None,
+                Err(e) => {
+                    warn!(ticker = %ticker, error = %e, "투자자별 순매수 조회 실패");
+                    None
+                }
+            }
+        }
+        _ => None,
+    };
+
+    // 9. GlobalScore 계산
     let params = GlobalScorerParams {
         symbol: Some(symbol.to_string()),
         market_type: Some(market_type),
@@ -272,6 +309,7 @@ async fn calculate_and_save(
         avg_volume_amount,
         volume_percentile,
         structural_features,
+        investor_flow,
     };
 
     let result = scorer
@@ -319,9 +357,114 @@ async fn calculate_and_save(
         "GlobalScore 저장 완료"
     );
 
+    // 5. 7Factor 일별 이력 저장 (실패해도 GlobalScore 저장은 유지)
+    if let Err(e) = save_factor_history(
+        pool,
+        symbol_info_id,
+        ticker,
+        &candles,
+        avg_volume_amount,
+        result.overall_score,
+    )
+    .await
+    {
+        warn!(ticker = %ticker, error = %e, "7Factor 이력 저장 실패");
+    }
+
     Ok(true)
 }
 
+/// 7Factor 점수를 계산하여 score_history에 오늘 날짜로 저장.
+///
+/// `component_scores`에 7Factor 정규화 점수를 기록하여
+/// 종목별 팩터 추이(`/screening/{ticker}/factors/history`)를 조회할 수 있게 합니다.
+/// RouteState도 함께 기록하여 백테스트에서 과거 시점 스크리닝을 재구성할 때 사용합니다.
+async fn save_factor_history(
+    pool: &PgPool,
+    symbol_info_id: Uuid,
+    ticker: &str,
+    candles: &[Kline],
+    avg_volume_amount: Option<Decimal>,
+    global_score: Decimal,
+) -> Result<()> {
+    type FundamentalRow = (
+        Option<Decimal>,
+        Option<Decimal>,
+        Option<Decimal>,
+        Option<Decimal>,
+        Option<Decimal>,
+        Option<Decimal>,
+        Option<Decimal>,
+        Option<Decimal>,
+        Option<Decimal>,
+        Option<Decimal>,
+        Option<Decimal>,
+    );
+
+    let fundamental = sqlx::query_as::<_, FundamentalRow>(
+        r#"
+        SELECT per, pbr, psr, roe, roa, operating_margin, net_profit_margin,
+               revenue_growth_yoy, earnings_growth_yoy, week_52_high, week_52_low
+        FROM symbol_fundamental
+        WHERE symbol_info_id = $1
+        "#,
+    )
+    .bind(symbol_info_id)
+    .fetch_optional(pool)
+    .await
+    .map_err(CollectorError::Database)?;
+
+    // SELECT 컬럼 순서대로 매핑
+    let mut input = match fundamental {
+        Some(f) => SevenFactorInput {
+            per: f.0,
+            pbr: f.1,
+            psr: f.2,
+            roe: f.3,
+            roa: f.4,
+            operating_margin: f.5,
+            net_profit_margin: f.6,
+            revenue_growth_yoy: f.7,
+            earnings_growth_yoy: f.8,
+            week_52_high: f.9,
+            week_52_low: f.10,
+            ..Default::default()
+        },
+        None => SevenFactorInput::default(),
+    };
+    input.avg_volume_amount = avg_volume_amount;
+    input.apply_candles(candles);
+
+    let scores = SevenFactorCalculator::calculate(&input);
+    let component_scores =
+        serde_json::to_value(&scores).map_err(|e| CollectorError::Other(Box::new(e)))?;
+
+    let route_state = RouteStateCalculator::new()
+        .calculate(candles)
+        .ok()
+        .map(|state| state.to_string());
+
+    sqlx::query(
+        r#"
+        INSERT INTO score_history (score_date, symbol, global_score, route_state, component_scores)
+        VALUES (CURRENT_DATE, $1, $2, $3, $4)
+        ON CONFLICT (score_date, symbol) DO UPDATE SET
+            global_score = EXCLUDED.global_score,
+            route_state = COALESCE(EXCLUDED.route_state, score_history.route_state),
+            component_scores = EXCLUDED.component_scores
+        "#,
+    )
+    .bind(ticker)
+    .bind(global_score)
+    .bind(route_state)
+    .bind(component_scores)
+    .execute(pool)
+    .await
+    .map_err(CollectorError::Database)?;
+
+    Ok(())
+}
+
 /// 특정 티커로 심볼 조회.
 async fn get_symbols_by_tickers(
     pool: &PgPool,
@@ -0,0 +1,174 @@
+//! 투자자별 순매수 동기화 모듈.
+//!
+//! KRX 정보데이터시스템에서 KR 종목의 일별 외국인/기관/개인 순매수 대금을 수집하여
+//! `symbol_investor_flow` 테이블에 저장합니다. 종목별 마지막 수집일 다음 날부터 증분 수집합니다.
+
+use chrono::{Duration, NaiveDate, Utc};
+use sqlx::PgPool;
+use std::time::Instant;
+
+use trader_data::{InvestorFlowStore, KrxDataSource};
+
+use crate::error::CollectorError;
+use crate::{CollectionStats, CollectorConfig, Result};
+
+/// 투자자별 순매수 동기화 옵션
+#[derive(Debug, Default)]
+pub struct InvestorFlowSyncOptions {
+    /// 특정 종목만 처리 (쉼표 구분, None이면 KR 활성 종목)
+    pub symbols: Option<String>,
+    /// 수집 일수 (기본: INVESTOR_FLOW_DAYS)
+    pub days: Option<i64>,
+}
+
+/// 투자자별 순매수 동기화
+///
+/// # Arguments
+///
+/// * `pool` - DB 연결 풀
+/// * `config` - 수집 설정
+/// * `options` - 동기화 옵션
+pub async fn sync_investor_flows(
+    pool: &PgPool,
+    config: &CollectorConfig,
+    options: InvestorFlowSyncOptions,
+) -> Result<CollectionStats> {
+    let start = Instant::now();
+    let mut stats = CollectionStats::new();
+    let flow_config = &config.investor_flow;
+
+    let tickers = match options.symbols {
+        Some(ref symbols) => symbols
+            .split(',')
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+            .collect(),
+        None => get_target_tickers(pool, flow_config.batch_size).await?,
+    };
+
+    if tickers.is_empty() {
+        tracing::info!("투자자별 순매수를 수집할 KR 종목이 없습니다");
+        return Ok(stats);
+    }
+
+    let krx = KrxDataSource::new();
+    let store = InvestorFlowStore::new(pool.clone());
+
+    // KST 기준 오늘
+    let today = (Utc::now() + Duration::hours(9)).date_naive();
+    let days = options.days.unwrap_or(flow_config.days);
+    let delay = flow_config.request_delay();
+
+    tracing::info!(
+        symbols = tickers.len(),
+        days = days,
+        "투자자별 순매수 동기화 시작"
+    );
+
+    for ticker in &tickers {
+        stats.total += 1;
+
+        let last_date = match store.latest_trade_date(ticker).await {
+            Ok(date) => date,
+            Err(e) => {
+                tracing::warn!(ticker = %ticker, error = %e, "마지막 수집일 조회 실패");
+                None
+            }
+        };
+        let from = sync_start_date(last_date, today, days);
+        if from > today {
+            stats.skipped += 1;
+            continue;
+        }
+
+        let flows = match krx
+            .get_investor_flows(
+                ticker,
+                &from.format("%Y%m%d").to_string(),
+                &today.format("%Y%m%d").to_string(),
+            )
+            .await
+        {
+            Ok(flows) => flows,
+            Err(e) => {
+                stats.errors += 1;
+                tracing::error!(ticker = %ticker, error = %e, "투자자별 순매수 조회 실패");
+                tokio::time::sleep(delay).await;
+                continue;
+            }
+        };
+
+        if flows.is_empty() {
+            stats.empty += 1;
+        } else {
+            match store.save_flows(&flows).await {
+                Ok(_) => {
+                    stats.success += 1;
+                    tracing::debug!(ticker = %ticker, days = flows.len(), "투자자별 순매수 저장");
+                }
+                Err(e) => {
+                    stats.errors += 1;
+                    tracing::error!(ticker = %ticker, error = %e, "투자자별 순매수 저장 실패");
+                }
+            }
+        }
+
+        tokio::time::sleep(delay).await;
+    }
+
+    stats.elapsed = start.elapsed();
+    Ok(stats)
+}
+
+/// 수집 시작일 결정 (마지막 수집일 다음 날, 없으면 `days`일 전).
+pub fn sync_start_date(last_date: Option<NaiveDate>, today: NaiveDate, days: i64) -> NaiveDate {
+    match last_date {
+        Some(last) => last + Duration::days(1),
+        None => today - Duration::days(days),
+    }
+}
+
+/// 수집 대상 KR 종목 조회 (수집이 오래된 순).
+async fn get_target_tickers(pool: &PgPool, limit: i64) -> Result<Vec<String>> {
+    sqlx::query_scalar::<_, String>(
+        r#"
+        SELECT si.ticker
+        FROM symbol_info si
+        LEFT JOIN (
+            SELECT ticker, MAX(trade_date) AS last_date
+            FROM symbol_investor_flow
+            GROUP BY ticker
+        ) f ON f.ticker = si.ticker
+        WHERE si.is_active = true
+          AND si.market = 'KR'
+          AND COALESCE(si.symbol_type, 'STOCK') = 'STOCK'
+        ORDER BY f.last_date NULLS FIRST, si.ticker
+        LIMIT $1
+        "#,
+    )
+    .bind(limit)
+    .fetch_all(pool)
+    .await
+    .map_err(CollectorError::Database)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sync_start_date() {
+        let today = NaiveDate::from_ymd_opt(2026, 3, 10).unwrap();
+
+        assert_eq!(
+            sync_start_date(None, today, 30),
+            NaiveDate::from_ymd_opt(2026, 2, 8).unwrap()
+        );
+        assert_eq!(
+            sync_start_date(NaiveDate::from_ymd_opt(2026, 3, 6), today, 30),
+            NaiveDate::from_ymd_opt(2026, 3, 7).unwrap()
+        );
+        // 오늘까지 수집 완료 → 시작일이 오늘 이후
+        assert!(sync_start_date(Some(today), today, 30) > today);
+    }
+}
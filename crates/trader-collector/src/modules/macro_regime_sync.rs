@@ -0,0 +1,186 @@
+//! 매크로 레짐 종합 지표 동기화 모듈.
+//!
+//! USD/KRW 추세, 미국 10년-2년 금리차, VIX(FRED)와 VKOSPI(KRX), 시장 폭(20일선 상회 비율)으로
+//! 일별 매크로 레짐 점수를 계산하여 `macro_series` 테이블에 이력으로 저장합니다.
+//! 같은 날 다시 실행하면 해당일 값을 갱신합니다. 일부 입력을 가져오지 못해도
+//! 나머지 입력으로 계산합니다.
+
+use chrono::{Duration, NaiveDate, Utc};
+use rust_decimal::prelude::ToPrimitive;
+use sqlx::PgPool;
+use std::time::Instant;
+use trader_core::{MacroRegime, MacroRegimeInputs};
+use trader_data::provider::macro_indicators::{MacroIndicator, MacroIndicatorProvider};
+use trader_data::storage::macro_series::macro_regime_to_points;
+use trader_data::{MacroSeriesStore, MarketBreadthCalculator};
+
+use crate::modules::ohlcv_collect::init_krx_client;
+use crate::{CollectionStats, CollectorConfig, Result};
+
+/// USD/KRW 추세 계산 구간 (거래일).
+const FX_TREND_LOOKBACK: usize = 20;
+/// FRED 조회 기간 (추세 구간과 휴일을 덮도록 여유 있게).
+const FRED_LOOKBACK_DAYS: i64 = 45;
+/// VKOSPI 조회 시 거슬러 올라갈 최대 일수 (주말/휴일).
+const VKOSPI_LOOKBACK_DAYS: i64 = 7;
+
+/// 매크로 레짐 동기화
+///
+/// `PROVIDER_MACRO_REGIME_ENABLED=false`이면 아무 작업도 하지 않습니다.
+pub async fn sync_macro_regime(pool: &PgPool, config: &CollectorConfig) -> Result<CollectionStats> {
+    let start = Instant::now();
+    let mut stats = CollectionStats::new();
+
+    if !config.providers.macro_regime_enabled {
+        tracing::info!("매크로 레짐 계산 비활성화됨 (PROVIDER_MACRO_REGIME_ENABLED=false)");
+        return Ok(stats);
+    }
+
+    stats.total = 1;
+    let today = Utc::now().date_naive();
+    let provider = MacroIndicatorProvider::new();
+    let from = today - Duration::days(FRED_LOOKBACK_DAYS);
+
+    let usd_krw = fetch_indicator(&provider, MacroIndicator::UsdKrw, from, today).await;
+    let inputs = MacroRegimeInputs {
+        usd_krw_trend_pct: trend_pct(&usd_krw, FX_TREND_LOOKBACK),
+        yield_curve_slope: latest_value(
+            &fetch_indicator(&provider, MacroIndicator::YieldCurveSlope, from, today).await,
+        ),
+        vix: latest_value(&fetch_indicator(&provider, MacroIndicator::Vix, from, today).await),
+        vkospi: fetch_vkospi(pool, today).await,
+        breadth_ratio: fetch_breadth_ratio(pool).await,
+    };
+
+    let Some(regime) = MacroRegime::compute(today, inputs) else {
+        stats.empty += 1;
+        tracing::warn!("매크로 레짐 입력값을 하나도 가져오지 못했습니다");
+        stats.elapsed = start.elapsed();
+        return Ok(stats);
+    };
+
+    let store = MacroSeriesStore::new(pool.clone());
+    let points = macro_regime_to_points(&regime, "macro_regime");
+    match store.save_points(&points).await {
+        Ok(_) => {
+            stats.success += 1;
+            tracing::info!(
+                date = %regime.date,
+                score = format!("{:.3}", regime.score),
+                level = %regime.level,
+                inputs = ?regime.inputs,
+                "매크로 레짐 저장 완료"
+            );
+        }
+        Err(e) => {
+            stats.errors += 1;
+            tracing::error!(error = %e, "매크로 레짐 저장 실패");
+        }
+    }
+
+    stats.elapsed = start.elapsed();
+    Ok(stats)
+}
+
+/// FRED 지표 조회 (실패하면 빈 시리즈 - 해당 구성 요소 제외).
+async fn fetch_indicator(
+    provider: &MacroIndicatorProvider,
+    indicator: MacroIndicator,
+    from: NaiveDate,
+    to: NaiveDate,
+) -> Vec<(NaiveDate, f64)> {
+    match provider.fetch(indicator, from, to).await {
+        Ok(values) => values,
+        Err(e) => {
+            tracing::warn!(indicator = ?indicator, error = %e, "매크로 지표 조회 실패");
+            Vec::new()
+        }
+    }
+}
+
+/// VKOSPI 최근 종가 조회 (KRX credential이 없거나 조회 실패 시 None).
+async fn fetch_vkospi(pool: &PgPool, today: NaiveDate) -> Option<f64> {
+    let client = init_krx_client(pool).await?;
+
+    for offset in 0..VKOSPI_LOOKBACK_DAYS {
+        let base_date = (today - Duration::days(offset))
+            .format("%Y%m%d")
+            .to_string();
+        match client.fetch_derivative_index(&base_date).await {
+            Ok(indices) => {
+                let vkospi = indices
+                    .iter()
+                    .find(|i| i.index_name.contains("변동성"))
+                    .and_then(|i| i.close.to_f64());
+                if vkospi.is_some() {
+                    return vkospi;
+                }
+            }
+            Err(e) => {
+                tracing::warn!(base_date = %base_date, error = %e, "VKOSPI 조회 실패");
+                return None;
+            }
+        }
+    }
+
+    tracing::debug!("최근 VKOSPI 데이터 없음");
+    None
+}
+
+/// 전체 시장 20일선 상회 비율 조회 (실패 시 None).
+async fn fetch_breadth_ratio(pool: &PgPool) -> Option<f64> {
+    match MarketBreadthCalculator::new(pool.clone()).calculate().await {
+        Ok(breadth) => breadth.all.to_f64(),
+        Err(e) => {
+            tracing::warn!(error = %e, "시장 폭 계산 실패");
+            None
+        }
+    }
+}
+
+/// 마지막 관측값.
+fn latest_value(values: &[(NaiveDate, f64)]) -> Option<f64> {
+    values.last().map(|(_, v)| *v)
+}
+
+/// 마지막 관측값의 `lookback` 관측 전 대비 변동률 (%).
+///
+/// 관측값이 부족하면 가장 오래된 관측값을 기준으로 하며, 2개 미만이면 None입니다.
+fn trend_pct(values: &[(NaiveDate, f64)], lookback: usize) -> Option<f64> {
+    let (_, last) = values.last()?;
+    let base_idx = values.len().saturating_sub(lookback + 1);
+    if base_idx + 1 >= values.len() {
+        return None;
+    }
+    let (_, base) = values[base_idx];
+    (base > 0.0).then(|| (last / base - 1.0) * 100.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn series(values: &[f64]) -> Vec<(NaiveDate, f64)> {
+        let start = NaiveDate::from_ymd_opt(2026, 3, 2).unwrap();
+        values
+            .iter()
+            .enumerate()
+            .map(|(i, v)| (start + Duration::days(i as i64), *v))
+            .collect()
+    }
+
+    #[test]
+    fn test_trend_pct() {
+        let values = series(&[1300.0, 1310.0, 1320.0, 1339.0]);
+        // 2 관측 전(1310) 대비
+        let trend = trend_pct(&values, 2).unwrap();
+        assert!((trend - (1339.0 / 1310.0 - 1.0) * 100.0).abs() < 1e-9);
+        // 관측값이 부족하면 가장 오래된 값 기준
+        let trend = trend_pct(&values, 20).unwrap();
+        assert!((trend - 3.0).abs() < 1e-9);
+
+        assert_eq!(trend_pct(&series(&[1300.0]), 20), None);
+        assert_eq!(trend_pct(&[], 20), None);
+        assert_eq!(latest_value(&values), Some(1339.0));
+    }
+}
@@ -0,0 +1,398 @@
+//! KIS 과거 분봉 백필 모듈.
+//!
+//! KIS 일별 분봉 조회 API는 1회 호출에 지정 시각 이전 최대 120건만 반환하므로,
+//! 영업일별로 장 마감 시각부터 가장 이른 체결 시간을 커서로 삼아 역방향 페이지네이션합니다.
+//! 수집한 1분봉은 중복 제거 및 빈 구간 보정 후 `ohlcv` 테이블(timeframe `1m`)에 저장합니다.
+//!
+//! # 호출 윈도우
+//!
+//! - 페이지네이션은 하루 단위로 시작/종료됩니다 (09:00 ~ 15:30 KST).
+//! - 하루당 호출 수는 `MINUTE_BACKFILL_MAX_PAGES_PER_DAY`로 제한됩니다.
+//! - 이미 하루치 분봉이 모두 저장된 일자는 건너뜁니다.
+
+use crate::{CollectionStats, CollectorConfig, CollectorError, Result};
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveTime, TimeZone, Utc, Weekday};
+use rust_decimal::Decimal;
+use sqlx::PgPool;
+use std::collections::BTreeMap;
+use std::time::Instant;
+use trader_core::{Kline, Timeframe};
+use trader_data::OhlcvCache;
+use trader_exchange::connector::kis::{KisConfig, KisKrClient, KisOAuth, KrMinuteOhlcv};
+
+/// 정규장 시작 시각 (KST, HHMMSS)
+const SESSION_OPEN: &str = "090000";
+/// 정규장 마감 시각 (KST, HHMMSS)
+const SESSION_CLOSE: &str = "153000";
+/// 장 마감 동시호가 시작 시각 (이후 구간은 빈 분봉을 채우지 않음)
+const CLOSING_AUCTION_START: &str = "152000";
+/// 정규장 하루 1분봉 수 (09:00 ~ 15:20 + 15:30)
+const FULL_DAY_BARS: i64 = 382;
+
+/// 분봉 백필 옵션
+#[derive(Debug, Clone, Default)]
+pub struct MinuteBackfillOptions {
+    /// 백필 일수 (영업일, None이면 설정값 사용)
+    pub days: Option<u32>,
+    /// 백필 기준 종료일 (None이면 전일)
+    pub end_date: Option<NaiveDate>,
+}
+
+/// KIS 과거 1분봉 백필
+///
+/// # Arguments
+///
+/// * `pool` - DB 연결 풀
+/// * `config` - 수집 설정
+/// * `symbols` - 대상 KR 종목코드 (쉼표 구분)
+/// * `options` - 백필 옵션
+pub async fn backfill_minutes(
+    pool: &PgPool,
+    config: &CollectorConfig,
+    symbols: String,
+    options: MinuteBackfillOptions,
+) -> Result<CollectionStats> {
+    let start = Instant::now();
+    let mut stats = CollectionStats::new();
+
+    let tickers: Vec<String> = symbols
+        .split(',')
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .collect();
+    if tickers.is_empty() {
+        return Err(CollectorError::Config(
+            "백필할 종목코드를 지정하세요 (--symbols)".to_string(),
+        ));
+    }
+
+    let client = init_kis_client()?;
+    let cache = OhlcvCache::new(pool.clone());
+    let backfill = &config.minute_backfill;
+
+    let end_date = options
+        .end_date
+        .unwrap_or_else(|| (Utc::now() + Duration::hours(9)).date_naive() - Duration::days(1));
+    let dates = backfill_dates(end_date, options.days.unwrap_or(backfill.days));
+
+    tracing::info!(
+        symbols = tickers.len(),
+        days = dates.len(),
+        from = ?dates.first(),
+        to = ?dates.last(),
+        "분봉 백필 시작"
+    );
+
+    for ticker in &tickers {
+        for date in &dates {
+            stats.total += 1;
+
+            match count_day_bars(pool, ticker, *date).await {
+                Ok(count) if count >= FULL_DAY_BARS => {
+                    stats.skipped += 1;
+                    tracing::debug!(ticker = %ticker, date = %date, "이미 백필된 일자");
+                    continue;
+                }
+                Ok(_) => {}
+                Err(e) => tracing::warn!(ticker = %ticker, error = %e, "기존 분봉 조회 실패"),
+            }
+
+            let bars = match fetch_day_bars(&client, ticker, *date, backfill).await {
+                Ok(bars) => bars,
+                Err(e) => {
+                    stats.errors += 1;
+                    tracing::error!(ticker = %ticker, date = %date, error = %e, "분봉 조회 실패");
+                    continue;
+                }
+            };
+
+            let klines = assemble_day_klines(ticker, *date, bars);
+            if klines.is_empty() {
+                // 휴장일 또는 거래 없음
+                stats.empty += 1;
+                tracing::debug!(ticker = %ticker, date = %date, "분봉 데이터 없음");
+                continue;
+            }
+
+            match cache.save_klines(ticker, Timeframe::M1, &klines).await {
+                Ok(_) => {
+                    stats.success += 1;
+                    stats.total_klines += klines.len();
+                    tracing::info!(
+                        ticker = %ticker,
+                        date = %date,
+                        bars = klines.len(),
+                        "분봉 백필 완료"
+                    );
+                }
+                Err(e) => {
+                    stats.errors += 1;
+                    tracing::error!(ticker = %ticker, date = %date, error = %e, "분봉 저장 실패");
+                }
+            }
+        }
+    }
+
+    stats.elapsed = start.elapsed();
+    Ok(stats)
+}
+
+/// 환경변수 KIS 설정으로 국내 클라이언트 생성
+fn init_kis_client() -> Result<KisKrClient> {
+    let kis_config = KisConfig::from_env().ok_or_else(|| {
+        CollectorError::Config("KIS 계좌 환경변수가 설정되지 않았습니다".to_string())
+    })?;
+    let oauth = KisOAuth::new(kis_config)
+        .map_err(|e| CollectorError::DataSource(format!("KIS OAuth 생성 실패: {}", e)))?;
+    KisKrClient::new(oauth)
+        .map_err(|e| CollectorError::DataSource(format!("KIS 클라이언트 생성 실패: {}", e)))
+}
+
+/// 하루치 분봉을 역방향 페이지네이션으로 조회
+async fn fetch_day_bars(
+    client: &KisKrClient,
+    ticker: &str,
+    date: NaiveDate,
+    config: &crate::config::MinuteBackfillConfig,
+) -> Result<Vec<KrMinuteOhlcv>> {
+    let date_str = date.format("%Y%m%d").to_string();
+    let mut cursor = SESSION_CLOSE.to_string();
+    let mut bars = Vec::new();
+
+    for _ in 0..config.max_pages_per_day {
+        let page = client
+            .get_daily_minute_chart(ticker, &date_str, &cursor)
+            .await
+            .map_err(|e| CollectorError::DataSource(e.to_string()))?;
+        tokio::time::sleep(config.request_delay()).await;
+
+        let next = next_cursor(&page, &cursor);
+        bars.extend(page);
+        match next {
+            Some(next) => cursor = next,
+            None => break,
+        }
+    }
+
+    Ok(bars)
+}
+
+/// 해당 일자에 저장된 1분봉 수 조회
+async fn count_day_bars(pool: &PgPool, ticker: &str, date: NaiveDate) -> Result<i64> {
+    let (Some(from), Some(to)) = (
+        kst_to_utc(date, SESSION_OPEN),
+        kst_to_utc(date, SESSION_CLOSE),
+    ) else {
+        return Ok(0);
+    };
+
+    let count: i64 = sqlx::query_scalar(
+        r#"
+        SELECT COUNT(*) FROM ohlcv
+        WHERE symbol = $1 AND timeframe = '1m'
+          AND open_time >= $2 AND open_time <= $3
+        "#,
+    )
+    .bind(ticker)
+    .bind(from)
+    .bind(to)
+    .fetch_one(pool)
+    .await?;
+
+    Ok(count)
+}
+
+/// 종료일부터 거슬러 올라간 평일 목록 (오래된 순)
+///
+/// 공휴일은 조회 결과가 비어 있는 것으로 처리합니다.
+pub fn backfill_dates(end: NaiveDate, days: u32) -> Vec<NaiveDate> {
+    let mut dates = Vec::with_capacity(days as usize);
+    let mut date = end;
+    while dates.len() < days as usize {
+        if !matches!(date.weekday(), Weekday::Sat | Weekday::Sun) {
+            dates.push(date);
+        }
+        date -= Duration::days(1);
+    }
+    dates.reverse();
+    dates
+}
+
+/// 다음 페이지 조회 기준 시각 계산
+///
+/// 페이지의 가장 이른 체결 시간 1분 전을 반환합니다.
+/// 장 시작에 도달했거나 더 이상 진행되지 않으면 `None`입니다.
+pub fn next_cursor(page: &[KrMinuteOhlcv], cursor: &str) -> Option<String> {
+    let earliest = page.iter().map(|bar| bar.time.as_str()).min()?;
+    if earliest <= SESSION_OPEN || earliest >= cursor {
+        return None;
+    }
+
+    let time = NaiveTime::parse_from_str(earliest, "%H%M%S").ok()?;
+    Some((time - Duration::minutes(1)).format("%H%M%S").to_string())
+}
+
+/// 하루치 분봉 응답을 연속된 1분봉 Kline으로 조립
+///
+/// - 다른 영업일 및 정규장 밖의 분봉은 제외합니다.
+/// - 페이지 경계에서 중복된 분봉은 하나만 남깁니다.
+/// - 체결이 없는 분은 직전 종가로 거래량 0인 분봉을 채웁니다 (마감 동시호가 구간 제외).
+pub fn assemble_day_klines(ticker: &str, date: NaiveDate, bars: Vec<KrMinuteOhlcv>) -> Vec<Kline> {
+    let date_str = date.format("%Y%m%d").to_string();
+    let by_time: BTreeMap<String, KrMinuteOhlcv> = bars
+        .into_iter()
+        .filter(|bar| bar.date.is_empty() || bar.date == date_str)
+        .filter(|bar| bar.time.as_str() >= SESSION_OPEN && bar.time.as_str() <= SESSION_CLOSE)
+        .map(|bar| (bar.time.clone(), bar))
+        .collect();
+
+    let mut klines: Vec<Kline> = Vec::with_capacity(by_time.len());
+    for (time, bar) in by_time {
+        let Some(open_time) = kst_to_utc(date, &time) else {
+            continue;
+        };
+
+        if let Some((prev_time, close)) = klines.last().map(|k| (k.open_time, k.close)) {
+            let fill_until = kst_to_utc(date, CLOSING_AUCTION_START).unwrap_or(open_time);
+            let mut gap_time = prev_time + Duration::minutes(1);
+            while gap_time < open_time && gap_time <= fill_until {
+                klines.push(minute_kline(
+                    ticker,
+                    gap_time,
+                    close,
+                    close,
+                    close,
+                    close,
+                    Decimal::ZERO,
+                ));
+                gap_time += Duration::minutes(1);
+            }
+        }
+
+        klines.push(minute_kline(
+            ticker, open_time, bar.open, bar.high, bar.low, bar.close, bar.volume,
+        ));
+    }
+
+    klines
+}
+
+fn minute_kline(
+    ticker: &str,
+    open_time: DateTime<Utc>,
+    open: Decimal,
+    high: Decimal,
+    low: Decimal,
+    close: Decimal,
+    volume: Decimal,
+) -> Kline {
+    Kline {
+        ticker: ticker.to_string(),
+        timeframe: Timeframe::M1,
+        open_time,
+        open,
+        high,
+        low,
+        close,
+        volume,
+        close_time: open_time + Duration::minutes(1),
+        quote_volume: None,
+        num_trades: None,
+    }
+}
+
+/// KST 일자 + HHMMSS를 UTC 시각으로 변환
+fn kst_to_utc(date: NaiveDate, time: &str) -> Option<DateTime<Utc>> {
+    let time = NaiveTime::parse_from_str(time, "%H%M%S").ok()?;
+    Some(Utc.from_utc_datetime(&date.and_time(time)) - Duration::hours(9))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn bar(date: &str, time: &str, close: Decimal, volume: Decimal) -> KrMinuteOhlcv {
+        KrMinuteOhlcv {
+            date: date.to_string(),
+            time: time.to_string(),
+            open: close,
+            high: close,
+            low: close,
+            close,
+            volume,
+        }
+    }
+
+    #[test]
+    fn test_backfill_dates_skips_weekends() {
+        // 2024-01-08 (월)
+        let end = NaiveDate::from_ymd_opt(2024, 1, 8).unwrap();
+        let dates = backfill_dates(end, 3);
+        assert_eq!(
+            dates,
+            vec![
+                NaiveDate::from_ymd_opt(2024, 1, 4).unwrap(),
+                NaiveDate::from_ymd_opt(2024, 1, 5).unwrap(),
+                NaiveDate::from_ymd_opt(2024, 1, 8).unwrap(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_next_cursor() {
+        let page = vec![
+            bar("20240105", "153000", dec!(100), dec!(1)),
+            bar("20240105", "133100", dec!(100), dec!(1)),
+        ];
+        assert_eq!(next_cursor(&page, "153000"), Some("133000".to_string()));
+
+        // 장 시작 도달
+        let opening = vec![bar("20240105", "090000", dec!(100), dec!(1))];
+        assert_eq!(next_cursor(&opening, "110000"), None);
+
+        // 진행 없음 / 빈 페이지
+        assert_eq!(next_cursor(&page, "133100"), None);
+        assert_eq!(next_cursor(&[], "153000"), None);
+    }
+
+    #[test]
+    fn test_assemble_day_klines_dedups_and_fills_gaps() {
+        let date = NaiveDate::from_ymd_opt(2024, 1, 5).unwrap();
+        let bars = vec![
+            bar("20240105", "090300", dec!(103), dec!(5)),
+            bar("20240105", "090000", dec!(100), dec!(10)),
+            // 페이지 경계 중복
+            bar("20240105", "090300", dec!(103), dec!(5)),
+            // 다른 영업일 / 장외 시간
+            bar("20240104", "090100", dec!(99), dec!(1)),
+            bar("20240105", "160000", dec!(99), dec!(1)),
+        ];
+
+        let klines = assemble_day_klines("005930", date, bars);
+        assert_eq!(klines.len(), 4);
+        assert_eq!(
+            klines[0].open_time,
+            Utc.with_ymd_and_hms(2024, 1, 5, 0, 0, 0).unwrap()
+        );
+        assert_eq!(klines[1].close, dec!(100));
+        assert_eq!(klines[1].volume, Decimal::ZERO);
+        assert_eq!(klines[3].close, dec!(103));
+        assert!(klines
+            .windows(2)
+            .all(|w| w[1].open_time - w[0].open_time == Duration::minutes(1)));
+    }
+
+    #[test]
+    fn test_assemble_day_klines_skips_closing_auction_gap() {
+        let date = NaiveDate::from_ymd_opt(2024, 1, 5).unwrap();
+        let bars = vec![
+            bar("20240105", "152000", dec!(100), dec!(1)),
+            bar("20240105", "153000", dec!(101), dec!(50)),
+        ];
+
+        let klines = assemble_day_klines("005930", date, bars);
+        assert_eq!(klines.len(), 2);
+        assert_eq!(klines[1].close, dec!(101));
+    }
+}
@@ -1,16 +1,30 @@
 //! 데이터 수집 모듈.
 
+pub mod cash_rate_sync;
 pub mod checkpoint;
+pub mod crypto_metrics_sync;
+pub mod custom_index_sync;
+pub mod earnings_sync;
+pub mod etf_sync;
 pub mod fundamental_sync;
 pub mod global_score_sync;
 pub mod indicator_sync;
+pub mod investor_flow_sync;
+pub mod macro_regime_sync;
+pub mod minute_backfill;
 pub mod ohlcv_collect;
 pub mod screening_refresh;
 pub mod symbol_sync;
+pub mod volatility_sync;
 
+pub use cash_rate_sync::sync_cash_rates;
 pub use checkpoint::{
     clear_checkpoint, list_checkpoints, mark_interrupted, CheckpointInfo, CheckpointStatus,
 };
+pub use crypto_metrics_sync::sync_crypto_metrics;
+pub use custom_index_sync::sync_custom_indices;
+pub use earnings_sync::sync_earnings_calendar;
+pub use etf_sync::{sync_etf_data, EtfSyncOptions};
 pub use fundamental_sync::{
     fetch_and_save_naver_fundamental, sync_krx_fundamentals, sync_naver_fundamentals,
     sync_naver_fundamentals_with_options, FundamentalSyncStats, NaverSyncOptions,
@@ -19,6 +33,10 @@ pub use global_score_sync::{
     sync_global_scores, sync_global_scores_with_options, GlobalScoreSyncOptions,
 };
 pub use indicator_sync::{sync_indicators, sync_indicators_with_options, IndicatorSyncOptions};
+pub use investor_flow_sync::{sync_investor_flows, InvestorFlowSyncOptions};
+pub use macro_regime_sync::sync_macro_regime;
+pub use minute_backfill::{backfill_minutes, MinuteBackfillOptions};
 pub use ohlcv_collect::collect_ohlcv;
 pub use screening_refresh::{get_screening_view_stats, refresh_screening_view, ScreeningViewStats};
 pub use symbol_sync::sync_symbols;
+pub use volatility_sync::{sync_symbol_volatility, VolatilitySyncOptions};
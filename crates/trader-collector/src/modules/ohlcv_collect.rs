@@ -540,7 +540,7 @@ fn determine_date_range(config: &CollectorConfig) -> (String, String) {
 /// KRX API 클라이언트 초기화 (credential 시스템 사용).
 ///
 /// credential이 없으면 None 반환 (Yahoo fallback 사용).
-async fn init_krx_client(pool: &PgPool) -> Option<KrxApiClient> {
+pub(crate) async fn init_krx_client(pool: &PgPool) -> Option<KrxApiClient> {
     let master_key = match std::env::var("ENCRYPTION_MASTER_KEY") {
         Ok(key) => key,
         Err(_) => {
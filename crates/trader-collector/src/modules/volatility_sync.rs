@@ -0,0 +1,262 @@
+//! 실현 변동성/베타 동기화 모듈.
+//!
+//! 일봉 종가로 종목별 20/60/252일 실현 변동성과 시장 벤치마크 대비 베타를 계산하여
+//! `symbol_fundamental`에 저장합니다. 데몬은 매 주기 실행되지만 `stale_hours` 이내에
+//! 계산된 종목은 건너뛰므로 종목당 하루 한 번 계산됩니다.
+
+use chrono::{Duration, NaiveDate, Utc};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use sqlx::PgPool;
+use std::collections::{BTreeMap, HashMap};
+use std::time::Instant;
+use tracing::{debug, info, warn};
+use uuid::Uuid;
+
+use trader_analytics::compute_symbol_risk;
+use trader_analytics::realized_risk::REQUIRED_CLOSES;
+use trader_core::REALIZED_VOL_WINDOWS;
+use trader_data::SymbolRiskStore;
+
+use crate::config::{CollectorConfig, VolatilitySyncConfig};
+use crate::error::CollectorError;
+use crate::stats::CollectionStats;
+use crate::Result;
+
+/// 변동성 계산에 필요한 최소 종가 수 (가장 짧은 구간 수익률 + 1).
+const MIN_CLOSES: usize = REALIZED_VOL_WINDOWS[0] + 1;
+
+/// 실현 변동성 동기화 옵션
+#[derive(Debug, Default)]
+pub struct VolatilitySyncOptions {
+    /// 특정 심볼만 처리 (쉼표 구분, None이면 오래된 심볼 전체)
+    pub symbols: Option<String>,
+    /// N시간 이내 계산된 심볼 스킵 (None이면 VOLATILITY_STALE_HOURS)
+    pub stale_hours: Option<i64>,
+}
+
+/// 실현 변동성/베타 동기화 실행.
+///
+/// # 동작
+/// 1. 변동성이 오래된 심볼 목록 조회 (CRYPTO 제외)
+/// 2. 시장별 벤치마크 일봉 종가 조회 (KR: 069500, US: SPY)
+/// 3. 각 심볼의 최근 253개 일봉으로 변동성/베타 계산
+/// 4. DB에 저장
+pub async fn sync_symbol_volatility(
+    pool: &PgPool,
+    config: &CollectorConfig,
+    options: VolatilitySyncOptions,
+) -> Result<CollectionStats> {
+    let start = Instant::now();
+    let mut stats = CollectionStats::new();
+    let settings = &config.volatility;
+
+    let target_symbols = if let Some(ref tickers) = options.symbols {
+        let ticker_list: Vec<&str> = tickers.split(',').map(|s| s.trim()).collect();
+        get_symbols_by_tickers(pool, &ticker_list).await?
+    } else {
+        let hours = options.stale_hours.unwrap_or(settings.stale_hours);
+        get_stale_volatility_symbols(
+            pool,
+            Utc::now() - Duration::hours(hours),
+            settings.batch_size,
+        )
+        .await?
+    };
+
+    if target_symbols.is_empty() {
+        info!("변동성을 계산할 심볼이 없습니다");
+        stats.elapsed = start.elapsed();
+        return Ok(stats);
+    }
+
+    info!("실현 변동성 동기화 시작: {} 심볼", target_symbols.len());
+    stats.total = target_symbols.len();
+
+    let store = SymbolRiskStore::new(pool.clone());
+    // 시장별 벤치마크 종가 (최초 사용 시 조회)
+    let mut benchmarks: HashMap<String, BTreeMap<NaiveDate, f64>> = HashMap::new();
+
+    for (idx, (symbol_info_id, ticker, market)) in target_symbols.iter().enumerate() {
+        if (idx + 1) % 500 == 0 {
+            info!(
+                progress = format!("{}/{}", idx + 1, stats.total),
+                "실현 변동성 동기화 진행 중"
+            );
+        }
+
+        let closes = match get_daily_closes(pool, ticker, REQUIRED_CLOSES as i64).await {
+            Ok(c) if c.len() >= MIN_CLOSES => c,
+            Ok(c) => {
+                debug!(ticker = %ticker, count = c.len(), "일봉 데이터 부족");
+                stats.empty += 1;
+                continue;
+            }
+            Err(e) => {
+                warn!(ticker = %ticker, error = %e, "일봉 조회 실패");
+                stats.errors += 1;
+                continue;
+            }
+        };
+
+        let benchmark = match benchmark_ticker(settings, market) {
+            Some(benchmark_ticker) => {
+                if !benchmarks.contains_key(benchmark_ticker) {
+                    let series = load_benchmark_closes(pool, benchmark_ticker).await;
+                    benchmarks.insert(benchmark_ticker.to_string(), series);
+                }
+                benchmarks
+                    .get(benchmark_ticker)
+                    .map(|series| (benchmark_ticker, series))
+            }
+            None => None,
+        };
+
+        let metrics = compute_symbol_risk(ticker, &closes, benchmark, Utc::now());
+
+        match store.save(*symbol_info_id, &metrics).await {
+            Ok(_) => {
+                debug!(
+                    ticker = %ticker,
+                    vol_20d = ?metrics.realized_vol_20d,
+                    vol_60d = ?metrics.realized_vol_60d,
+                    beta = ?metrics.beta,
+                    "실현 변동성 업데이트 완료"
+                );
+                stats.success += 1;
+            }
+            Err(e) => {
+                warn!(ticker = %ticker, error = %e, "실현 변동성 DB 업데이트 실패");
+                stats.errors += 1;
+            }
+        }
+    }
+
+    stats.elapsed = start.elapsed();
+    Ok(stats)
+}
+
+/// 시장별 베타 벤치마크 티커.
+fn benchmark_ticker<'a>(settings: &'a VolatilitySyncConfig, market: &str) -> Option<&'a str> {
+    match market {
+        "KR" => Some(settings.kr_benchmark.as_str()),
+        "US" => Some(settings.us_benchmark.as_str()),
+        _ => None,
+    }
+}
+
+/// 벤치마크 일봉 종가 조회 (실패하거나 없으면 빈 시리즈 - 베타 미계산).
+async fn load_benchmark_closes(pool: &PgPool, ticker: &str) -> BTreeMap<NaiveDate, f64> {
+    match get_daily_closes(pool, ticker, REQUIRED_CLOSES as i64).await {
+        Ok(series) if series.is_empty() => {
+            warn!(benchmark = ticker, "벤치마크 일봉 없음 - 베타 미계산");
+            series
+        }
+        Ok(series) => series,
+        Err(e) => {
+            warn!(benchmark = ticker, error = %e, "벤치마크 일봉 조회 실패");
+            BTreeMap::new()
+        }
+    }
+}
+
+/// 특정 티커로 심볼 조회.
+async fn get_symbols_by_tickers(
+    pool: &PgPool,
+    tickers: &[&str],
+) -> Result<Vec<(Uuid, String, String)>> {
+    let results = sqlx::query_as::<_, (Uuid, String, String)>(
+        r#"
+        SELECT id, ticker, market
+        FROM symbol_info
+        WHERE ticker = ANY($1)
+          AND is_active = true
+          AND market != 'CRYPTO'
+        "#,
+    )
+    .bind(tickers)
+    .fetch_all(pool)
+    .await
+    .map_err(CollectorError::Database)?;
+
+    Ok(results)
+}
+
+/// 변동성이 계산되지 않았거나 오래된 심볼 조회.
+async fn get_stale_volatility_symbols(
+    pool: &PgPool,
+    older_than: chrono::DateTime<Utc>,
+    limit: i64,
+) -> Result<Vec<(Uuid, String, String)>> {
+    let results = sqlx::query_as::<_, (Uuid, String, String)>(
+        r#"
+        SELECT si.id, si.ticker, si.market
+        FROM symbol_info si
+        LEFT JOIN symbol_fundamental sf ON si.id = sf.symbol_info_id
+        WHERE si.is_active = true
+          AND si.market != 'CRYPTO'
+          AND (sf.volatility_updated_at IS NULL OR sf.volatility_updated_at < $1)
+        ORDER BY sf.volatility_updated_at NULLS FIRST, si.ticker
+        LIMIT $2
+        "#,
+    )
+    .bind(older_than)
+    .bind(limit)
+    .fetch_all(pool)
+    .await
+    .map_err(CollectorError::Database)?;
+
+    Ok(results)
+}
+
+/// 최근 일봉 종가 조회 (날짜순).
+async fn get_daily_closes(
+    pool: &PgPool,
+    ticker: &str,
+    limit: i64,
+) -> Result<BTreeMap<NaiveDate, f64>> {
+    let rows = sqlx::query_as::<_, (chrono::DateTime<Utc>, Decimal)>(
+        r#"
+        SELECT open_time, close
+        FROM ohlcv
+        WHERE symbol = $1 AND timeframe = '1d'
+        ORDER BY open_time DESC
+        LIMIT $2
+        "#,
+    )
+    .bind(ticker)
+    .bind(limit)
+    .fetch_all(pool)
+    .await
+    .map_err(CollectorError::Database)?;
+
+    Ok(rows
+        .into_iter()
+        .filter_map(|(open_time, close)| {
+            close
+                .to_f64()
+                .filter(|c| *c > 0.0)
+                .map(|c| (open_time.date_naive(), c))
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_benchmark_ticker() {
+        let settings = VolatilitySyncConfig {
+            enabled: true,
+            kr_benchmark: "069500".to_string(),
+            us_benchmark: "SPY".to_string(),
+            stale_hours: 20,
+            batch_size: 100,
+        };
+
+        assert_eq!(benchmark_ticker(&settings, "KR"), Some("069500"));
+        assert_eq!(benchmark_ticker(&settings, "US"), Some("SPY"));
+        assert_eq!(benchmark_ticker(&settings, "JP"), None);
+    }
+}
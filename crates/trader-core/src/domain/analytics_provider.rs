@@ -19,6 +19,11 @@ pub use super::macro_environment::MacroEnvironment;
 pub use super::market_breadth::MarketBreadth;
 pub use super::market_regime::MarketRegime;
 
+use super::cash_yield::CashRateSeries;
+use super::crypto_metrics::CryptoMetrics;
+use super::macro_regime::MacroRegime;
+use super::symbol_risk::SymbolRiskMetrics;
+
 // ================================================================================================
 // Error Types
 // ================================================================================================
@@ -255,4 +260,51 @@ pub trait AnalyticsProvider: Send + Sync {
     /// # Returns
     /// 현재 MarketBreadth
     async fn fetch_market_breadth(&self) -> Result<MarketBreadth, AnalyticsError>;
+
+    /// 매크로 레짐 종합 지표 조회.
+    ///
+    /// 수집기가 저장한 최신 값을 조회합니다. 지원하지 않거나 아직 계산된 값이
+    /// 없으면 None을 반환합니다.
+    ///
+    /// # Returns
+    /// 최신 MacroRegime
+    async fn fetch_macro_regime(&self) -> Result<Option<MacroRegime>, AnalyticsError> {
+        Ok(None)
+    }
+
+    /// 암호화폐 온체인/파생상품 지표 조회.
+    ///
+    /// 지표 수집이 비활성화된 구현체는 빈 맵을 반환합니다.
+    ///
+    /// # Returns
+    /// 기초 자산 -> CryptoMetrics 매핑
+    async fn fetch_crypto_metrics(&self) -> Result<HashMap<String, CryptoMetrics>, AnalyticsError> {
+        Ok(HashMap::new())
+    }
+
+    /// 현금성 자산 최신 금리 조회.
+    ///
+    /// 금리 수집이 비활성화된 구현체는 빈 맵을 반환합니다.
+    ///
+    /// # Returns
+    /// 금리 시리즈 -> 연 금리(%) 매핑
+    async fn fetch_cash_yields(&self) -> Result<HashMap<CashRateSeries, f64>, AnalyticsError> {
+        Ok(HashMap::new())
+    }
+
+    /// 실현 변동성/베타 조회.
+    ///
+    /// 수집기가 저장한 값을 조회합니다. 지원하지 않는 구현체는 빈 맵을 반환합니다.
+    ///
+    /// # Arguments
+    /// * `tickers` - 조회할 종목 티커 목록
+    ///
+    /// # Returns
+    /// ticker -> SymbolRiskMetrics 매핑
+    async fn fetch_symbol_risk(
+        &self,
+        _tickers: &[&str],
+    ) -> Result<HashMap<String, SymbolRiskMetrics>, AnalyticsError> {
+        Ok(HashMap::new())
+    }
 }
@@ -0,0 +1,309 @@
+//! 브로커 점검/장애 달력.
+//!
+//! 증권사(KIS 등) API의 예정된 시스템 점검 시간과 감지된 장애를 기록합니다.
+//!
+//! - 예정 점검: 시작/종료 시각이 정해진 구간 (운영자가 등록)
+//! - 감지 장애: 주문 경로 오류로 감지되어 종료 시각 없이 열리고, 복구 시 닫힘
+//!
+//! 실행기는 점검/장애 중인 브로커의 신규 주문을 보류(대기열) 또는 거부하고,
+//! 전략은 컨텍스트의 `broker_unavailable` 플래그로 상태를 확인합니다.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// 점검/장애 구분.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DowntimeSource {
+    /// 예정된 시스템 점검
+    Scheduled,
+    /// 주문 경로에서 감지된 장애
+    Detected,
+}
+
+impl std::fmt::Display for DowntimeSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Scheduled => write!(f, "scheduled"),
+            Self::Detected => write!(f, "detected"),
+        }
+    }
+}
+
+impl std::str::FromStr for DowntimeSource {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "scheduled" => Ok(Self::Scheduled),
+            "detected" => Ok(Self::Detected),
+            other => Err(format!("unknown downtime source: {}", other)),
+        }
+    }
+}
+
+/// 브로커 점검/장애 구간.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BrokerDowntime {
+    pub id: Uuid,
+    /// 브로커 식별자 (예: `kis`)
+    pub broker: String,
+    pub source: DowntimeSource,
+    pub starts_at: DateTime<Utc>,
+    /// 종료 시각 (진행 중인 감지 장애는 None)
+    pub ends_at: Option<DateTime<Utc>>,
+    /// 점검/장애 사유
+    pub reason: String,
+}
+
+impl BrokerDowntime {
+    /// 예정 점검 구간 생성.
+    pub fn scheduled(
+        broker: impl Into<String>,
+        starts_at: DateTime<Utc>,
+        ends_at: DateTime<Utc>,
+        reason: impl Into<String>,
+    ) -> Result<Self, String> {
+        let broker = normalize_broker(&broker.into())?;
+        if ends_at <= starts_at {
+            return Err("maintenance window must end after it starts".to_string());
+        }
+        Ok(Self {
+            id: Uuid::new_v4(),
+            broker,
+            source: DowntimeSource::Scheduled,
+            starts_at,
+            ends_at: Some(ends_at),
+            reason: reason.into().trim().to_string(),
+        })
+    }
+
+    /// 감지 장애 구간 생성 (종료 시각 없음).
+    pub fn detected(broker: &str, reason: impl Into<String>, now: DateTime<Utc>) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            broker: broker.trim().to_lowercase(),
+            source: DowntimeSource::Detected,
+            starts_at: now,
+            ends_at: None,
+            reason: reason.into(),
+        }
+    }
+
+    /// 해당 시각에 진행 중인지 여부.
+    pub fn is_active(&self, now: DateTime<Utc>) -> bool {
+        self.starts_at <= now && self.ends_at.map_or(true, |end| now < end)
+    }
+
+    /// 해당 시각 이전에 끝났는지 여부.
+    pub fn has_ended(&self, now: DateTime<Utc>) -> bool {
+        self.ends_at.is_some_and(|end| end <= now)
+    }
+
+    /// 사용자 표시용 설명 (예: `kis scheduled maintenance until 2026-03-07 21:00 UTC: 정기 점검`).
+    pub fn describe(&self) -> String {
+        let kind = match self.source {
+            DowntimeSource::Scheduled => "scheduled maintenance",
+            DowntimeSource::Detected => "outage",
+        };
+        let until = self
+            .ends_at
+            .map(|end| format!(" until {}", end.format("%Y-%m-%d %H:%M UTC")))
+            .unwrap_or_default();
+        if self.reason.is_empty() {
+            format!("{} {}{}", self.broker, kind, until)
+        } else {
+            format!("{} {}{}: {}", self.broker, kind, until, self.reason)
+        }
+    }
+}
+
+/// 브로커 식별자 정규화 (소문자, 영숫자/`_`/`-`).
+fn normalize_broker(broker: &str) -> Result<String, String> {
+    let broker = broker.trim().to_lowercase();
+    if broker.is_empty()
+        || !broker
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+    {
+        return Err(format!("invalid broker: '{}'", broker));
+    }
+    Ok(broker)
+}
+
+/// 브로커 점검/장애 달력.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BrokerDowntimeCalendar {
+    windows: Vec<BrokerDowntime>,
+}
+
+impl BrokerDowntimeCalendar {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 구간 추가 (같은 ID가 있으면 교체).
+    pub fn insert(&mut self, window: BrokerDowntime) {
+        self.windows.retain(|w| w.id != window.id);
+        self.windows.push(window);
+        self.windows.sort_by_key(|w| w.starts_at);
+    }
+
+    /// 구간 삭제.
+    pub fn remove(&mut self, id: Uuid) -> Option<BrokerDowntime> {
+        let index = self.windows.iter().position(|w| w.id == id)?;
+        Some(self.windows.remove(index))
+    }
+
+    /// 예정 점검 구간 교체 (감지 장애는 유지).
+    pub fn replace_scheduled(&mut self, windows: impl IntoIterator<Item = BrokerDowntime>) {
+        self.windows
+            .retain(|w| w.source != DowntimeSource::Scheduled);
+        self.windows.extend(
+            windows
+                .into_iter()
+                .filter(|w| w.source == DowntimeSource::Scheduled),
+        );
+        self.windows.sort_by_key(|w| w.starts_at);
+    }
+
+    /// 장애 감지 기록.
+    ///
+    /// 이미 진행 중인 감지 장애가 있으면 `None`을 반환합니다 (새로 열린 장애만 반환).
+    pub fn report_outage(
+        &mut self,
+        broker: &str,
+        reason: impl Into<String>,
+        now: DateTime<Utc>,
+    ) -> Option<BrokerDowntime> {
+        let broker = broker.trim().to_lowercase();
+        if self.windows.iter().any(|w| {
+            w.broker == broker && w.source == DowntimeSource::Detected && w.ends_at.is_none()
+        }) {
+            return None;
+        }
+        let window = BrokerDowntime::detected(&broker, reason, now);
+        self.insert(window.clone());
+        Some(window)
+    }
+
+    /// 진행 중인 감지 장애 종료 (종료된 장애 반환).
+    pub fn resolve_outage(&mut self, broker: &str, now: DateTime<Utc>) -> Option<BrokerDowntime> {
+        let broker = broker.trim().to_lowercase();
+        let window = self.windows.iter_mut().find(|w| {
+            w.broker == broker && w.source == DowntimeSource::Detected && w.ends_at.is_none()
+        })?;
+        window.ends_at = Some(now.max(window.starts_at));
+        Some(window.clone())
+    }
+
+    /// 브로커의 진행 중인 점검/장애 (감지 장애 우선, 예정 점검은 가장 늦게 끝나는 구간).
+    pub fn active(&self, broker: &str, now: DateTime<Utc>) -> Option<&BrokerDowntime> {
+        let broker = broker.trim().to_lowercase();
+        let mut active = self
+            .windows
+            .iter()
+            .filter(|w| w.broker == broker && w.is_active(now));
+        let mut best = active.next()?;
+        for window in active {
+            let later = match (window.ends_at, best.ends_at) {
+                (None, _) => true,
+                (Some(_), None) => false,
+                (Some(a), Some(b)) => a > b,
+            };
+            if later {
+                best = window;
+            }
+        }
+        Some(best)
+    }
+
+    /// 브로커 사용 가능 여부.
+    pub fn is_available(&self, broker: &str, now: DateTime<Utc>) -> bool {
+        self.active(broker, now).is_none()
+    }
+
+    /// 진행 중이거나 예정된 구간 (시작 시각순).
+    pub fn upcoming(&self, now: DateTime<Utc>) -> Vec<&BrokerDowntime> {
+        self.windows.iter().filter(|w| !w.has_ended(now)).collect()
+    }
+
+    /// 전체 구간 (시작 시각순).
+    pub fn windows(&self) -> &[BrokerDowntime] {
+        &self.windows
+    }
+
+    /// 끝난 지 `retention`이 지난 구간 정리 (정리한 수 반환).
+    pub fn prune(&mut self, now: DateTime<Utc>, retention: chrono::Duration) -> usize {
+        let before = self.windows.len();
+        self.windows.retain(|w| !w.has_ended(now - retention));
+        before - self.windows.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+
+    fn at(hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 3, 7, hour, minute, 0).unwrap()
+    }
+
+    #[test]
+    fn test_scheduled_window() {
+        assert!(BrokerDowntime::scheduled("kis", at(21, 0), at(20, 0), "").is_err());
+        assert!(BrokerDowntime::scheduled("k i s", at(20, 0), at(21, 0), "").is_err());
+
+        let window = BrokerDowntime::scheduled(" KIS ", at(20, 0), at(21, 0), "정기 점검").unwrap();
+        assert_eq!(window.broker, "kis");
+        assert!(!window.is_active(at(19, 59)));
+        assert!(window.is_active(at(20, 0)));
+        assert!(!window.is_active(at(21, 0)));
+        assert!(window.has_ended(at(21, 0)));
+        assert_eq!(
+            window.describe(),
+            "kis scheduled maintenance until 2026-03-07 21:00 UTC: 정기 점검"
+        );
+    }
+
+    #[test]
+    fn test_calendar_active_and_outage() {
+        let mut calendar = BrokerDowntimeCalendar::new();
+        calendar.insert(BrokerDowntime::scheduled("kis", at(20, 0), at(21, 0), "점검").unwrap());
+        calendar.insert(BrokerDowntime::scheduled("kis", at(20, 30), at(22, 0), "연장").unwrap());
+
+        assert!(calendar.is_available("kis", at(19, 0)));
+        assert!(calendar.is_available("upbit", at(20, 45)));
+        // 겹치는 점검은 가장 늦게 끝나는 구간
+        assert_eq!(calendar.active("kis", at(20, 45)).unwrap().reason, "연장");
+
+        // 감지 장애는 중복으로 열리지 않고 예정 점검보다 우선
+        let outage = calendar
+            .report_outage("kis", "timeout", at(20, 50))
+            .unwrap();
+        assert!(calendar
+            .report_outage("KIS", "timeout", at(20, 55))
+            .is_none());
+        assert_eq!(calendar.active("kis", at(20, 55)).unwrap().id, outage.id);
+
+        let resolved = calendar.resolve_outage("kis", at(21, 10)).unwrap();
+        assert_eq!(resolved.ends_at, Some(at(21, 10)));
+        assert!(calendar.resolve_outage("kis", at(21, 10)).is_none());
+        assert_eq!(calendar.active("kis", at(21, 30)).unwrap().reason, "연장");
+        assert_eq!(calendar.upcoming(at(21, 30)).len(), 1);
+
+        assert_eq!(calendar.prune(at(22, 30), Duration::hours(1)), 2);
+        assert_eq!(calendar.windows().len(), 1);
+
+        // 예정 점검만 교체되고 감지 장애는 유지
+        calendar.report_outage("kis", "timeout", at(23, 0));
+        calendar.replace_scheduled(vec![]);
+        assert_eq!(calendar.windows().len(), 1);
+        assert_eq!(calendar.windows()[0].source, DowntimeSource::Detected);
+        assert!(calendar.resolve_outage("kis", at(23, 5)).is_some());
+        assert!(calendar.remove(calendar.windows()[0].id).is_some());
+        assert!(calendar.windows().is_empty());
+    }
+}
@@ -0,0 +1,201 @@
+//! CashYield - 현금성 자산 금리.
+//!
+//! 국내 CD(91일)/국고채 3년, 미국 T-bill(3개월) 금리를 표현하고,
+//! 백테스트와 자산배분 전략이 유휴 현금에 붙는 이자를 계산할 수 있도록
+//! 일자별 연 금리 곡선을 제공합니다.
+//!
+//! 이자는 ACT/365 단리로 일할 계산하며, 관측값이 없는 날(휴일 등)은 직전 관측값을 사용합니다.
+
+use chrono::NaiveDate;
+use rust_decimal::prelude::FromPrimitive;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// 연간 일수 (ACT/365)
+const DAYS_PER_YEAR: i64 = 365;
+
+/// 현금성 자산 금리 시리즈.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CashRateSeries {
+    /// 국내 CD 91일물
+    KrCd91d,
+    /// 국고채 3년물
+    KrKtb3y,
+    /// 미국 T-bill 3개월물
+    UsTbill3m,
+}
+
+impl CashRateSeries {
+    /// 전체 시리즈
+    pub const ALL: [CashRateSeries; 3] = [Self::KrCd91d, Self::KrKtb3y, Self::UsTbill3m];
+
+    /// 매크로 시계열 키 (`rate.{국가}.{지표}`).
+    pub fn series_key(&self) -> &'static str {
+        match self {
+            Self::KrCd91d => "rate.KR.cd_91d",
+            Self::KrKtb3y => "rate.KR.ktb_3y",
+            Self::UsTbill3m => "rate.US.tbill_3m",
+        }
+    }
+
+    /// 시계열 키에서 시리즈 조회.
+    pub fn from_series_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|s| s.series_key() == key)
+    }
+
+    /// 시장별 기본 현금 금리 (KR → CD 91일, US → T-bill 3개월).
+    pub fn default_for_market(market: &str) -> Option<Self> {
+        match market.to_uppercase().as_str() {
+            "KR" => Some(Self::KrCd91d),
+            "US" => Some(Self::UsTbill3m),
+            _ => None,
+        }
+    }
+}
+
+impl std::str::FromStr for CashRateSeries {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_uppercase().as_str() {
+            "KR_CD_91D" | "CD" => Ok(Self::KrCd91d),
+            "KR_KTB_3Y" | "KTB" => Ok(Self::KrKtb3y),
+            "US_TBILL_3M" | "TBILL" | "BIL" => Ok(Self::UsTbill3m),
+            _ => Err(format!("Unknown cash rate series: {}", s)),
+        }
+    }
+}
+
+/// 일자별 연 금리 곡선 (%, 예: 3.5 = 연 3.5%).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CashYieldCurve {
+    rates: BTreeMap<NaiveDate, f64>,
+}
+
+impl CashYieldCurve {
+    /// 빈 곡선 생성.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 기간 전체에 고정 금리를 적용하는 곡선 생성.
+    pub fn constant(annual_pct: f64) -> Self {
+        Self::from_points([(NaiveDate::MIN, annual_pct)])
+    }
+
+    /// (일자, 연 금리) 관측값으로 곡선 생성.
+    pub fn from_points(points: impl IntoIterator<Item = (NaiveDate, f64)>) -> Self {
+        Self {
+            rates: points.into_iter().collect(),
+        }
+    }
+
+    /// 관측값 추가.
+    pub fn insert(&mut self, date: NaiveDate, annual_pct: f64) {
+        self.rates.insert(date, annual_pct);
+    }
+
+    /// 관측값 수.
+    pub fn len(&self) -> usize {
+        self.rates.len()
+    }
+
+    /// 관측값 유무.
+    pub fn is_empty(&self) -> bool {
+        self.rates.is_empty()
+    }
+
+    /// 해당 일자에 적용되는 연 금리 (직전 관측값, 없으면 None).
+    pub fn rate_on(&self, date: NaiveDate) -> Option<f64> {
+        self.rates.range(..=date).next_back().map(|(_, rate)| *rate)
+    }
+
+    /// 최신 관측값.
+    pub fn latest(&self) -> Option<(NaiveDate, f64)> {
+        self.rates.last_key_value().map(|(d, r)| (*d, *r))
+    }
+
+    /// `from` 다음 날부터 `to`까지 잔고에 붙는 이자 (ACT/365 일할 단리).
+    ///
+    /// 잔고가 0 이하이거나 `to <= from`이면 0입니다. 금리 관측 이전 구간은 0%로 간주합니다.
+    pub fn accrued_interest(&self, balance: Decimal, from: NaiveDate, to: NaiveDate) -> Decimal {
+        if balance <= Decimal::ZERO || to <= from {
+            return Decimal::ZERO;
+        }
+
+        let rate_days: f64 = from
+            .iter_days()
+            .skip(1)
+            .take_while(|d| *d <= to)
+            .filter_map(|d| self.rate_on(d))
+            .sum();
+
+        Decimal::from_f64(rate_days / 100.0 / DAYS_PER_YEAR as f64)
+            .map(|factor| (balance * factor).round_dp(8))
+            .unwrap_or(Decimal::ZERO)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn date(m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_cash_rate_series_keys() {
+        for series in CashRateSeries::ALL {
+            assert_eq!(
+                CashRateSeries::from_series_key(series.series_key()),
+                Some(series)
+            );
+        }
+        assert_eq!(
+            "kr_cd_91d".parse::<CashRateSeries>(),
+            Ok(CashRateSeries::KrCd91d)
+        );
+        assert_eq!(
+            CashRateSeries::default_for_market("US"),
+            Some(CashRateSeries::UsTbill3m)
+        );
+        assert_eq!(CashRateSeries::default_for_market("CRYPTO"), None);
+    }
+
+    #[test]
+    fn test_cash_yield_curve_accrual() {
+        let curve = CashYieldCurve::from_points([(date(1, 1), 3.65), (date(1, 11), 7.3)]);
+
+        assert_eq!(curve.rate_on(date(1, 5)), Some(3.65));
+        assert_eq!(curve.rate_on(date(1, 20)), Some(7.3));
+        assert_eq!(
+            curve.rate_on(NaiveDate::from_ymd_opt(2025, 12, 31).unwrap()),
+            None
+        );
+
+        // 1/2 ~ 1/10: 9일 × 3.65% / 365 = 0.09%
+        assert_eq!(
+            curve.accrued_interest(dec!(1000000), date(1, 1), date(1, 10)),
+            dec!(900)
+        );
+        // 1/10 ~ 1/11: 하루 3.65% + 하루 7.3%
+        assert_eq!(
+            curve.accrued_interest(dec!(1000000), date(1, 9), date(1, 11)),
+            dec!(300)
+        );
+        assert_eq!(
+            curve.accrued_interest(dec!(-100), date(1, 1), date(1, 10)),
+            Decimal::ZERO
+        );
+
+        let constant = CashYieldCurve::constant(3.65);
+        assert_eq!(
+            constant.accrued_interest(dec!(1000000), date(3, 1), date(3, 2)),
+            dec!(100)
+        );
+    }
+}
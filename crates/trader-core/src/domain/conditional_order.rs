@@ -0,0 +1,267 @@
+//! 조건부 주문 정의.
+//!
+//! 서버가 실시간 데이터로 조건을 평가하다가 모든 조건이 충족되면 주문을 실행합니다.
+//!
+//! # 사용 예시
+//!
+//! ```rust,ignore
+//! use trader_core::domain::{ComparisonOperator, ConditionIndicator, OrderCondition};
+//!
+//! // 종가가 80,000원을 상향 돌파하고 RSI(14) < 60이면 매수
+//! let conditions = vec![
+//!     OrderCondition::Price {
+//!         operator: ComparisonOperator::CrossAbove,
+//!         value: dec!(80000),
+//!     },
+//!     OrderCondition::Indicator {
+//!         indicator: ConditionIndicator::Rsi { period: 14 },
+//!         operator: ComparisonOperator::Lt,
+//!         value: dec!(60),
+//!     },
+//! ];
+//! ```
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use super::{ComparisonOperator, TriggerType};
+
+/// 조건에 사용할 지표.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "name", rename_all = "snake_case")]
+pub enum ConditionIndicator {
+    /// RSI (종가 기준)
+    Rsi { period: usize },
+    /// 단순 이동평균
+    Sma { period: usize },
+    /// 지수 이동평균
+    Ema { period: usize },
+}
+
+impl ConditionIndicator {
+    /// 지표 기간.
+    pub fn period(&self) -> usize {
+        match self {
+            Self::Rsi { period } | Self::Sma { period } | Self::Ema { period } => *period,
+        }
+    }
+}
+
+impl fmt::Display for ConditionIndicator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Rsi { period } => write!(f, "RSI({})", period),
+            Self::Sma { period } => write!(f, "SMA({})", period),
+            Self::Ema { period } => write!(f, "EMA({})", period),
+        }
+    }
+}
+
+/// 조건부 주문의 실행 조건.
+///
+/// 하나의 주문에 여러 조건을 지정하면 모두 충족되어야 실행됩니다 (AND).
+/// 크로스 연산자는 직전 캔들과 최신 캔들을 비교합니다.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum OrderCondition {
+    /// 종가 조건
+    Price {
+        /// 비교 연산자
+        operator: ComparisonOperator,
+        /// 기준 가격
+        value: Decimal,
+    },
+
+    /// 지표 조건
+    Indicator {
+        /// 지표
+        indicator: ConditionIndicator,
+        /// 비교 연산자
+        operator: ComparisonOperator,
+        /// 기준 값
+        value: Decimal,
+    },
+
+    /// 진입 트리거 발생 (TriggerCalculator 기준)
+    Trigger {
+        /// 트리거 유형
+        trigger: TriggerType,
+    },
+}
+
+impl OrderCondition {
+    /// 조건 설정 검증.
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            Self::Price { operator, value } => {
+                Self::validate_operator(*operator)?;
+                if *value <= Decimal::ZERO {
+                    return Err("Price condition value must be positive".to_string());
+                }
+            }
+            Self::Indicator {
+                indicator,
+                operator,
+                ..
+            } => {
+                Self::validate_operator(*operator)?;
+                if indicator.period() == 0 {
+                    return Err(format!("{} period must be positive", indicator));
+                }
+            }
+            Self::Trigger { .. } => {}
+        }
+        Ok(())
+    }
+
+    fn validate_operator(operator: ComparisonOperator) -> Result<(), String> {
+        match operator {
+            ComparisonOperator::Between => {
+                Err("Between operator is not supported for order conditions".to_string())
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl fmt::Display for OrderCondition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Price { operator, value } => write!(f, "close {:?} {}", operator, value),
+            Self::Indicator {
+                indicator,
+                operator,
+                value,
+            } => write!(f, "{} {:?} {}", indicator, operator, value),
+            Self::Trigger { trigger } => write!(f, "trigger {:?}", trigger),
+        }
+    }
+}
+
+/// 개별 조건 평가 결과.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConditionCheck {
+    /// 평가한 조건
+    pub condition: OrderCondition,
+    /// 충족 여부
+    pub satisfied: bool,
+    /// 최신 캔들 기준 관측값 (종가 또는 지표 값)
+    pub observed: Option<Decimal>,
+}
+
+/// 조건부 주문 평가 결과.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConditionEvaluation {
+    /// 모든 조건 충족 여부
+    pub satisfied: bool,
+    /// 조건별 평가 결과
+    pub checks: Vec<ConditionCheck>,
+    /// 평가 기준 가격 (최신 종가)
+    pub last_price: Decimal,
+}
+
+/// 조건부 주문 상태.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConditionalOrderStatus {
+    /// 조건 감시 중
+    #[default]
+    Active,
+    /// 조건 충족으로 주문 제출됨
+    Triggered,
+    /// 조건은 충족됐으나 주문 실행 실패
+    Failed,
+    /// 만료 시각 경과
+    Expired,
+    /// 사용자 취소
+    Cancelled,
+}
+
+impl ConditionalOrderStatus {
+    /// DB 저장용 문자열.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Active => "active",
+            Self::Triggered => "triggered",
+            Self::Failed => "failed",
+            Self::Expired => "expired",
+            Self::Cancelled => "cancelled",
+        }
+    }
+
+    /// 더 이상 평가하지 않는 상태인지 여부.
+    pub fn is_terminal(&self) -> bool {
+        !matches!(self, Self::Active)
+    }
+}
+
+impl std::str::FromStr for ConditionalOrderStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "active" => Ok(Self::Active),
+            "triggered" => Ok(Self::Triggered),
+            "failed" => Ok(Self::Failed),
+            "expired" => Ok(Self::Expired),
+            "cancelled" => Ok(Self::Cancelled),
+            _ => Err(format!("Unknown conditional order status: {}", s)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_condition_serde() {
+        let json = r#"[
+            {"type": "price", "operator": "cross_above", "value": 80000},
+            {"type": "indicator", "indicator": {"name": "rsi", "period": 14}, "operator": "lt", "value": 60},
+            {"type": "trigger", "trigger": "volume_spike"}
+        ]"#;
+        let conditions: Vec<OrderCondition> = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            conditions[0],
+            OrderCondition::Price {
+                operator: ComparisonOperator::CrossAbove,
+                value: dec!(80000),
+            }
+        );
+        assert_eq!(
+            conditions[1],
+            OrderCondition::Indicator {
+                indicator: ConditionIndicator::Rsi { period: 14 },
+                operator: ComparisonOperator::Lt,
+                value: dec!(60),
+            }
+        );
+        assert!(conditions.iter().all(|c| c.validate().is_ok()));
+    }
+
+    #[test]
+    fn test_condition_validate() {
+        let between = OrderCondition::Price {
+            operator: ComparisonOperator::Between,
+            value: dec!(100),
+        };
+        assert!(between.validate().is_err());
+
+        let zero_period = OrderCondition::Indicator {
+            indicator: ConditionIndicator::Sma { period: 0 },
+            operator: ComparisonOperator::Gt,
+            value: dec!(1),
+        };
+        assert!(zero_period.validate().is_err());
+
+        assert_eq!(
+            "expired".parse::<ConditionalOrderStatus>().unwrap(),
+            ConditionalOrderStatus::Expired
+        );
+        assert!(ConditionalOrderStatus::Cancelled.is_terminal());
+    }
+}
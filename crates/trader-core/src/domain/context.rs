@@ -12,8 +12,14 @@ use super::analytics_provider::{
     GlobalScoreResult, MacroEnvironment, MarketBreadth, MarketRegime, RouteState, ScreeningResult,
     StructuralFeatures,
 };
+use super::broker_downtime::BrokerDowntime;
+use super::cash_yield::CashRateSeries;
+use super::crypto_metrics::CryptoMetrics;
+use super::macro_regime::MacroRegime;
 use super::market_data::Kline;
 use super::order::{OrderStatusType, Side};
+use super::symbol_risk::SymbolRiskMetrics;
+use super::trading_status::{TradingStatus, TradingStatusEvent};
 use super::trigger::TriggerResult;
 use crate::Timeframe;
 
@@ -294,6 +300,15 @@ pub struct StrategyContext {
     /// 거래소 제약 조건
     pub exchange_constraints: ExchangeConstraints,
 
+    /// 거래정지/VI 중인 종목 (ticker → 최근 상태 이벤트, 정상 종목은 없음)
+    pub trading_statuses: HashMap<String, TradingStatusEvent>,
+
+    /// 브로커 점검/장애 중 여부 (true면 신규 주문이 실행기에서 보류되거나 거부됨)
+    pub broker_unavailable: bool,
+
+    /// 진행 중인 브로커 점검/장애 (사용 가능하면 None)
+    pub broker_downtime: Option<BrokerDowntime>,
+
     // ===== 분석 결과 (1~10분 갱신) =====
     /// Global Score 결과 (ticker → 결과)
     pub global_scores: HashMap<String, GlobalScoreResult>,
@@ -313,9 +328,27 @@ pub struct StrategyContext {
     /// 매크로 환경 (환율, 나스닥 등)
     pub macro_environment: Option<MacroEnvironment>,
 
+    /// 매크로 레짐 종합 지표 (환율 추세, 금리차, 변동성, 시장 폭)
+    ///
+    /// 수집기가 매일 계산한 최신 값으로, 자산배분 전략의 위험 노출 조절에 사용합니다.
+    pub macro_regime: Option<MacroRegime>,
+
     /// 시장 폭 (20일선 상회 비율 등)
     pub market_breadth: Option<MarketBreadth>,
 
+    /// 암호화폐 온체인/파생상품 지표 (기초 자산 → 지표)
+    pub crypto_metrics: HashMap<String, CryptoMetrics>,
+
+    /// 현금성 자산 최신 금리 (시리즈 → 연 %)
+    ///
+    /// 자산배분 전략의 현금 슬리브(BIL 등) 기대수익 산정에 사용합니다.
+    pub cash_yields: HashMap<CashRateSeries, f64>,
+
+    /// 실현 변동성/베타 (ticker → 지표)
+    ///
+    /// 수집기가 매일 계산한 값으로, 변동성 타깃 사이징에 사용합니다.
+    pub symbol_risk: HashMap<String, SymbolRiskMetrics>,
+
     /// 진입 트리거 결과 (ticker → TriggerResult)
     ///
     /// 각 종목의 진입 신호 강도와 트리거 라벨을 제공합니다.
@@ -357,13 +390,20 @@ impl Default for StrategyContext {
             positions: HashMap::new(),
             pending_orders: Vec::new(),
             exchange_constraints: ExchangeConstraints::default(),
+            trading_statuses: HashMap::new(),
+            broker_unavailable: false,
+            broker_downtime: None,
             global_scores: HashMap::new(),
             route_states: HashMap::new(),
             screening_results: HashMap::new(),
             structural_features: HashMap::new(),
             market_regime: HashMap::new(),
             macro_environment: None,
+            macro_regime: None,
             market_breadth: None,
+            crypto_metrics: HashMap::new(),
+            cash_yields: HashMap::new(),
+            symbol_risk: HashMap::new(),
             trigger_results: HashMap::new(),
             klines_by_timeframe: HashMap::new(),
             last_exchange_sync: now,
@@ -448,6 +488,36 @@ impl StrategyContext {
         self.last_exchange_sync = Utc::now();
     }
 
+    /// 종목 거래 상태 업데이트.
+    ///
+    /// 정상 거래로 돌아온 종목은 목록에서 제거합니다.
+    pub fn update_trading_status(&mut self, event: TradingStatusEvent) {
+        if event.status.is_restricted() {
+            self.trading_statuses.insert(event.ticker.clone(), event);
+        } else {
+            self.trading_statuses.remove(&event.ticker);
+        }
+    }
+
+    /// 종목 거래 상태 조회 (기록 없으면 `Normal`).
+    pub fn get_trading_status(&self, ticker: &str) -> TradingStatus {
+        self.trading_statuses
+            .get(ticker)
+            .map(|e| e.status)
+            .unwrap_or_default()
+    }
+
+    /// 종목 거래정지 또는 VI 발동 여부.
+    pub fn is_trading_restricted(&self, ticker: &str) -> bool {
+        self.get_trading_status(ticker).is_restricted()
+    }
+
+    /// 브로커 점검/장애 상태 업데이트 (`None`이면 사용 가능).
+    pub fn update_broker_downtime(&mut self, downtime: Option<BrokerDowntime>) {
+        self.broker_unavailable = downtime.is_some();
+        self.broker_downtime = downtime;
+    }
+
     // =============================================================================
     // 다중 타임프레임 메서드 (Phase 1.4.2)
     // =============================================================================
@@ -609,12 +679,36 @@ impl StrategyContext {
         self.last_analytics_sync = Utc::now();
     }
 
+    /// 매크로 레짐 업데이트.
+    pub fn update_macro_regime(&mut self, regime: MacroRegime) {
+        self.macro_regime = Some(regime);
+        self.last_analytics_sync = Utc::now();
+    }
+
     /// 시장 폭 업데이트.
     pub fn update_market_breadth(&mut self, breadth: MarketBreadth) {
         self.market_breadth = Some(breadth);
         self.last_analytics_sync = Utc::now();
     }
 
+    /// 암호화폐 지표 업데이트.
+    pub fn update_crypto_metrics(&mut self, metrics: HashMap<String, CryptoMetrics>) {
+        self.crypto_metrics = metrics;
+        self.last_analytics_sync = Utc::now();
+    }
+
+    /// 현금성 자산 금리 업데이트.
+    pub fn update_cash_yields(&mut self, yields: HashMap<CashRateSeries, f64>) {
+        self.cash_yields = yields;
+        self.last_analytics_sync = Utc::now();
+    }
+
+    /// 실현 변동성/베타 업데이트.
+    pub fn update_symbol_risk(&mut self, metrics: HashMap<String, SymbolRiskMetrics>) {
+        self.symbol_risk = metrics;
+        self.last_analytics_sync = Utc::now();
+    }
+
     // =============================================================================
     // 분석 결과 조회 헬퍼
     // =============================================================================
@@ -644,11 +738,41 @@ impl StrategyContext {
         self.macro_environment.as_ref()
     }
 
+    /// 매크로 레짐 조회.
+    pub fn get_macro_regime(&self) -> Option<&MacroRegime> {
+        self.macro_regime.as_ref()
+    }
+
     /// 시장 폭 조회.
     pub fn get_market_breadth(&self) -> Option<&MarketBreadth> {
         self.market_breadth.as_ref()
     }
 
+    /// 암호화폐 지표 조회.
+    ///
+    /// `asset`은 기초 자산("BTC") 또는 거래 티커("BTC/USDT") 모두 허용합니다.
+    pub fn get_crypto_metrics(&self, asset: &str) -> Option<&CryptoMetrics> {
+        self.crypto_metrics.get(asset).or_else(|| {
+            self.crypto_metrics
+                .get(super::crypto_metrics::crypto_base_asset(asset))
+        })
+    }
+
+    /// 현금성 자산 연 금리(%) 조회.
+    pub fn get_cash_yield(&self, series: CashRateSeries) -> Option<f64> {
+        self.cash_yields.get(&series).copied()
+    }
+
+    /// 시장 기본 현금 금리(%) 조회 (KR → CD 91일, US → T-bill 3개월).
+    pub fn get_market_cash_yield(&self, market: &str) -> Option<f64> {
+        CashRateSeries::default_for_market(market).and_then(|s| self.get_cash_yield(s))
+    }
+
+    /// 특정 종목의 실현 변동성/베타 조회.
+    pub fn get_symbol_risk(&self, ticker: &str) -> Option<&SymbolRiskMetrics> {
+        self.symbol_risk.get(ticker)
+    }
+
     /// 특정 종목의 진입 트리거 조회.
     ///
     /// # 인자
@@ -755,4 +879,58 @@ mod tests {
         // 총 가치: 1600 + 1550 = 3150
         assert_eq!(ctx.total_position_value(), dec!(3150));
     }
+
+    #[test]
+    fn test_get_crypto_metrics() {
+        let mut ctx = StrategyContext::new();
+        let mut metrics = HashMap::new();
+        metrics.insert("BTC".to_string(), CryptoMetrics::new("BTC", Utc::now()));
+        ctx.update_crypto_metrics(metrics);
+
+        assert!(ctx.get_crypto_metrics("BTC").is_some());
+        assert!(ctx.get_crypto_metrics("BTC/USDT").is_some());
+        assert!(ctx.get_crypto_metrics("ETH/USDT").is_none());
+    }
+
+    #[test]
+    fn test_get_cash_yield() {
+        let mut ctx = StrategyContext::new();
+        let mut yields = HashMap::new();
+        yields.insert(CashRateSeries::KrCd91d, 2.8);
+        ctx.update_cash_yields(yields);
+
+        assert_eq!(ctx.get_cash_yield(CashRateSeries::KrCd91d), Some(2.8));
+        assert_eq!(ctx.get_market_cash_yield("KR"), Some(2.8));
+        assert_eq!(ctx.get_market_cash_yield("US"), None);
+    }
+
+    #[test]
+    fn test_trading_status_tracking() {
+        let mut ctx = StrategyContext::new();
+        assert_eq!(ctx.get_trading_status("005930"), TradingStatus::Normal);
+
+        ctx.update_trading_status(TradingStatusEvent::new("005930", TradingStatus::DynamicVi));
+        assert!(ctx.is_trading_restricted("005930"));
+        assert!(!ctx.is_trading_restricted("000660"));
+
+        // 해제되면 목록에서 제거
+        ctx.update_trading_status(TradingStatusEvent::new("005930", TradingStatus::Normal));
+        assert!(!ctx.is_trading_restricted("005930"));
+        assert!(ctx.trading_statuses.is_empty());
+    }
+
+    #[test]
+    fn test_broker_downtime_flag() {
+        let mut ctx = StrategyContext::new();
+        assert!(!ctx.broker_unavailable);
+
+        let outage = BrokerDowntime::detected("kis", "timeout", Utc::now());
+        ctx.update_broker_downtime(Some(outage.clone()));
+        assert!(ctx.broker_unavailable);
+        assert_eq!(ctx.broker_downtime, Some(outage));
+
+        ctx.update_broker_downtime(None);
+        assert!(!ctx.broker_unavailable);
+        assert!(ctx.broker_downtime.is_none());
+    }
 }
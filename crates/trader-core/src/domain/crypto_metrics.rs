@@ -0,0 +1,168 @@
+//! CryptoMetrics - 암호화폐 온체인/파생상품 지표.
+//!
+//! BTC/ETH 등 주요 자산의 거래소 순유입, 스테이블코인 공급량, 무기한 선물 베이시스를
+//! 전략 컨텍스트와 레짐 판정의 보조 입력으로 제공합니다.
+//!
+//! # 편향 판정
+//!
+//! | 지표 | 강세 | 약세 |
+//! |------|------|------|
+//! | 거래소 순유입 | 순유출 (< 0) | 순유입 (> 0) |
+//! | 스테이블코인 7일 변화율 | >= +1% | <= -1% |
+//! | 무기한 선물 베이시스 | - | >= +0.5% (과열) |
+//! | 펀딩비 | - | >= 0.05% (과열) |
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// 스테이블코인 공급 변화 임계값 (7일, %)
+const STABLECOIN_CHANGE_THRESHOLD: f64 = 1.0;
+/// 선물 과열 베이시스 임계값 (%)
+const OVERHEATED_BASIS_PCT: f64 = 0.5;
+/// 선물 과열 펀딩비 임계값 (%)
+const OVERHEATED_FUNDING_PCT: f64 = 0.05;
+
+/// 암호화폐 지표 편향.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CryptoMetricsBias {
+    /// 강세 (순유출, 유동성 유입)
+    Bullish,
+    /// 중립
+    Neutral,
+    /// 약세 (순유입, 유동성 감소, 선물 과열)
+    Bearish,
+}
+
+/// 자산별 온체인/파생상품 지표.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CryptoMetrics {
+    /// 기초 자산 (예: "BTC", "ETH")
+    pub asset: String,
+
+    /// 거래소 순유입량 (자산 단위, 양수 = 거래소로 유입)
+    pub exchange_netflow: Option<f64>,
+
+    /// 스테이블코인 총 공급량 (USD)
+    pub stablecoin_supply: Option<f64>,
+
+    /// 스테이블코인 공급량 7일 변화율 (%)
+    pub stablecoin_change_7d_pct: Option<f64>,
+
+    /// 무기한 선물 베이시스 ((마크가격 / 현물지수 - 1) × 100, %)
+    pub perp_basis_pct: Option<f64>,
+
+    /// 최근 펀딩비 (%)
+    pub funding_rate_pct: Option<f64>,
+
+    /// 관측 시각
+    pub observed_at: DateTime<Utc>,
+}
+
+impl CryptoMetrics {
+    /// 빈 지표 생성.
+    pub fn new(asset: impl Into<String>, observed_at: DateTime<Utc>) -> Self {
+        Self {
+            asset: asset.into(),
+            exchange_netflow: None,
+            stablecoin_supply: None,
+            stablecoin_change_7d_pct: None,
+            perp_basis_pct: None,
+            funding_rate_pct: None,
+            observed_at,
+        }
+    }
+
+    /// 선물 과열 여부 (베이시스 또는 펀딩비 임계값 초과).
+    pub fn is_derivatives_overheated(&self) -> bool {
+        self.perp_basis_pct
+            .is_some_and(|basis| basis >= OVERHEATED_BASIS_PCT)
+            || self
+                .funding_rate_pct
+                .is_some_and(|funding| funding >= OVERHEATED_FUNDING_PCT)
+    }
+
+    /// 지표 점수 (-3 ~ +2, 양수 = 강세).
+    pub fn score(&self) -> i32 {
+        let mut score = 0;
+
+        if let Some(netflow) = self.exchange_netflow {
+            if netflow < 0.0 {
+                score += 1;
+            } else if netflow > 0.0 {
+                score -= 1;
+            }
+        }
+
+        if let Some(change) = self.stablecoin_change_7d_pct {
+            if change >= STABLECOIN_CHANGE_THRESHOLD {
+                score += 1;
+            } else if change <= -STABLECOIN_CHANGE_THRESHOLD {
+                score -= 1;
+            }
+        }
+
+        if self.is_derivatives_overheated() {
+            score -= 1;
+        }
+
+        score
+    }
+
+    /// 지표 편향 판정.
+    pub fn bias(&self) -> CryptoMetricsBias {
+        match self.score() {
+            s if s >= 2 => CryptoMetricsBias::Bullish,
+            s if s <= -2 => CryptoMetricsBias::Bearish,
+            _ => CryptoMetricsBias::Neutral,
+        }
+    }
+}
+
+/// 암호화폐 티커에서 기초 자산 추출.
+///
+/// `"BTC/USDT"`, `"BTCUSDT"`, `"BTC-USD"` 모두 `"BTC"`를 반환합니다.
+pub fn crypto_base_asset(ticker: &str) -> &str {
+    if let Some((base, _)) = ticker.split_once(['/', '-']) {
+        return base;
+    }
+    for quote in ["USDT", "USDC", "BUSD", "KRW", "USD"] {
+        if let Some(base) = ticker.strip_suffix(quote) {
+            if !base.is_empty() {
+                return base;
+            }
+        }
+    }
+    ticker
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_crypto_metrics_bias() {
+        let mut metrics = CryptoMetrics::new("BTC", Utc::now());
+        assert_eq!(metrics.bias(), CryptoMetricsBias::Neutral);
+
+        // 순유출 + 스테이블코인 증가 → 강세
+        metrics.exchange_netflow = Some(-1500.0);
+        metrics.stablecoin_change_7d_pct = Some(1.5);
+        assert_eq!(metrics.bias(), CryptoMetricsBias::Bullish);
+
+        // 순유입 + 선물 과열 → 약세
+        metrics.exchange_netflow = Some(2000.0);
+        metrics.stablecoin_change_7d_pct = None;
+        metrics.perp_basis_pct = Some(0.8);
+        assert!(metrics.is_derivatives_overheated());
+        assert_eq!(metrics.bias(), CryptoMetricsBias::Bearish);
+    }
+
+    #[test]
+    fn test_crypto_base_asset() {
+        assert_eq!(crypto_base_asset("BTC/USDT"), "BTC");
+        assert_eq!(crypto_base_asset("ETHUSDT"), "ETH");
+        assert_eq!(crypto_base_asset("BTC-USD"), "BTC");
+        assert_eq!(crypto_base_asset("ETH"), "ETH");
+    }
+}
@@ -0,0 +1,576 @@
+//! 사용자 정의 지수 (가중 바스켓).
+//!
+//! 여러 종목을 비중대로 묶은 합성 지수의 일봉을 계산합니다. 계산된 일봉은
+//! `IDX_<코드>` 티커로 일반 종목과 같은 OHLCV 테이블에 저장되어 전략 심볼,
+//! 시장 레짐 입력, 벤치마크로 사용할 수 있습니다.
+//!
+//! 지수는 매 거래일 목표 비중으로 리밸런싱된다고 가정합니다(일간 리밸런싱).
+//! 전일 지수 종가에 구성종목 수익률의 가중합을 곱해 다음 값을 구하며,
+//! 시가/고가/저가도 각 구성종목의 전일 종가 대비 수익률로 같은 방식으로 계산합니다.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+use chrono::NaiveDate;
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// 지수 티커 접두사 (`IDX_<코드>`).
+pub const CUSTOM_INDEX_TICKER_PREFIX: &str = "IDX_";
+
+/// 지수 하나에 포함할 수 있는 최대 구성종목 수.
+pub const MAX_INDEX_CONSTITUENTS: usize = 50;
+
+/// 역변동성 비중 계산에 사용하는 일간 수익률 개수.
+pub const INVERSE_VOLATILITY_WINDOW: usize = 60;
+
+/// 역변동성 비중 계산에 필요한 최소 일간 수익률 개수 (부족하면 동일 비중).
+const MIN_VOLATILITY_SAMPLES: usize = 20;
+
+/// 비중 결정 방식.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IndexWeighting {
+    /// 구성종목별로 지정한 고정 비중
+    Static,
+    /// 동일 비중
+    Equal,
+    /// 직전 60거래일 변동성의 역수에 비례
+    InverseVolatility,
+    /// 최신 시가총액에 비례
+    MarketCap,
+}
+
+impl IndexWeighting {
+    /// DB 저장 문자열.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Static => "static",
+            Self::Equal => "equal",
+            Self::InverseVolatility => "inverse_volatility",
+            Self::MarketCap => "market_cap",
+        }
+    }
+
+    /// 문자열 파싱.
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "static" => Some(Self::Static),
+            "equal" => Some(Self::Equal),
+            "inverse_volatility" => Some(Self::InverseVolatility),
+            "market_cap" => Some(Self::MarketCap),
+            _ => None,
+        }
+    }
+}
+
+/// 지수 구성종목.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IndexConstituent {
+    /// 종목 티커
+    pub ticker: String,
+    /// 고정 비중 (`static` 방식에서만 사용, 합계가 1이 아니면 정규화)
+    #[serde(default)]
+    pub weight: Option<Decimal>,
+}
+
+/// 사용자 정의 지수 정의.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CustomIndexDefinition {
+    /// 지수 코드 (영문 대문자/숫자/밑줄, 2~16자)
+    pub code: String,
+    /// 지수 이름
+    pub name: String,
+    /// 시장 (KR, US 등, 일봉 날짜 기준 시간대 결정)
+    pub market: String,
+    /// 비중 결정 방식
+    pub weighting: IndexWeighting,
+    /// 기준값 (첫 거래일 종가)
+    pub base_value: Decimal,
+    /// 구성종목
+    pub constituents: Vec<IndexConstituent>,
+}
+
+impl CustomIndexDefinition {
+    /// OHLCV/심볼 테이블에 저장되는 지수 티커.
+    pub fn ticker(&self) -> String {
+        custom_index_ticker(&self.code)
+    }
+
+    /// 구성종목 티커 목록.
+    pub fn tickers(&self) -> Vec<String> {
+        self.constituents.iter().map(|c| c.ticker.clone()).collect()
+    }
+
+    /// 정의 검증.
+    pub fn validate(&self) -> Result<(), String> {
+        let code_len = self.code.chars().count();
+        if !(2..=16).contains(&code_len)
+            || !self
+                .code
+                .chars()
+                .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_')
+        {
+            return Err("code must be 2-16 characters of A-Z, 0-9 or _".to_string());
+        }
+        if self.name.trim().is_empty() {
+            return Err("name is required".to_string());
+        }
+        if self.base_value <= Decimal::ZERO {
+            return Err("base_value must be positive".to_string());
+        }
+        if self.constituents.is_empty() {
+            return Err("at least one constituent is required".to_string());
+        }
+        if self.constituents.len() > MAX_INDEX_CONSTITUENTS {
+            return Err(format!(
+                "at most {} constituents are allowed",
+                MAX_INDEX_CONSTITUENTS
+            ));
+        }
+
+        let mut seen = HashSet::new();
+        for constituent in &self.constituents {
+            if constituent.ticker.trim().is_empty() {
+                return Err("constituent ticker is required".to_string());
+            }
+            if constituent.ticker.starts_with(CUSTOM_INDEX_TICKER_PREFIX) {
+                return Err(format!(
+                    "{}: custom indices cannot contain other custom indices",
+                    constituent.ticker
+                ));
+            }
+            if !seen.insert(constituent.ticker.as_str()) {
+                return Err(format!("{}: duplicate constituent", constituent.ticker));
+            }
+            if self.weighting == IndexWeighting::Static {
+                match constituent.weight {
+                    Some(w) if w > Decimal::ZERO => {}
+                    _ => {
+                        return Err(format!(
+                            "{}: static weighting requires a positive weight",
+                            constituent.ticker
+                        ))
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// `static` 방식의 정규화된 고정 비중.
+    pub fn static_weights(&self) -> HashMap<String, Decimal> {
+        normalize_weights(
+            self.constituents
+                .iter()
+                .filter_map(|c| c.weight.map(|w| (c.ticker.clone(), w))),
+        )
+    }
+}
+
+/// 지수 코드로 지수 티커 생성.
+pub fn custom_index_ticker(code: &str) -> String {
+    format!("{}{}", CUSTOM_INDEX_TICKER_PREFIX, code)
+}
+
+/// 합계가 1이 되도록 비중 정규화 (0 이하 비중은 제외).
+pub fn normalize_weights(
+    weights: impl IntoIterator<Item = (String, Decimal)>,
+) -> HashMap<String, Decimal> {
+    let positive: Vec<(String, Decimal)> = weights
+        .into_iter()
+        .filter(|(_, w)| *w > Decimal::ZERO)
+        .collect();
+    let total: Decimal = positive.iter().map(|(_, w)| *w).sum();
+    if total <= Decimal::ZERO {
+        return HashMap::new();
+    }
+    positive
+        .into_iter()
+        .map(|(ticker, w)| (ticker, w / total))
+        .collect()
+}
+
+/// 일봉 (구성종목 입력 및 지수 출력 공용).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IndexDailyBar {
+    /// 거래일
+    pub date: NaiveDate,
+    pub open: Decimal,
+    pub high: Decimal,
+    pub low: Decimal,
+    pub close: Decimal,
+    /// 거래량 (지수 출력에서는 구성종목 거래대금 합계)
+    pub volume: Decimal,
+}
+
+/// 합성 지수 일봉 계산.
+///
+/// 모든 구성종목의 일봉이 처음으로 갖춰진 날을 기준일로 하여 `base_value`에서
+/// 시작합니다. 이후 어떤 구성종목에 일봉이 없는 날(휴장 차이 등)은 그 종목의
+/// 수익률을 0으로 처리합니다.
+///
+/// # Arguments
+/// * `bars` - 구성종목별 일봉 (날짜 정렬 무관)
+/// * `weighting` - 비중 결정 방식
+/// * `fixed_weights` - `static`/`market_cap` 방식의 정규화된 비중
+/// * `base_value` - 기준값
+pub fn compute_index_series(
+    bars: &HashMap<String, Vec<IndexDailyBar>>,
+    weighting: IndexWeighting,
+    fixed_weights: &HashMap<String, Decimal>,
+    base_value: Decimal,
+) -> Vec<IndexDailyBar> {
+    let tickers: Vec<&String> = {
+        let mut t: Vec<&String> = bars.keys().collect();
+        t.sort();
+        t
+    };
+    if tickers.is_empty() || base_value <= Decimal::ZERO {
+        return Vec::new();
+    }
+
+    let by_date: HashMap<&String, BTreeMap<NaiveDate, &IndexDailyBar>> = tickers
+        .iter()
+        .map(|t| (*t, bars[*t].iter().map(|b| (b.date, b)).collect()))
+        .collect();
+    let dates: BTreeSet<NaiveDate> = by_date.values().flat_map(|m| m.keys().copied()).collect();
+
+    // 기준일: 모든 구성종목의 일봉이 처음 갖춰진 날
+    let Some(base_date) = dates
+        .iter()
+        .copied()
+        .find(|d| tickers.iter().all(|t| by_date[*t].contains_key(d)))
+    else {
+        return Vec::new();
+    };
+
+    let mut last_close: HashMap<&String, Decimal> = HashMap::new();
+    let mut returns: HashMap<&String, Vec<f64>> = HashMap::new();
+    let mut series: Vec<IndexDailyBar> = Vec::new();
+
+    for date in dates.range(base_date..) {
+        let weights = target_weights(&tickers, weighting, fixed_weights, &returns);
+
+        let mut open_ret = Decimal::ZERO;
+        let mut high_ret = Decimal::ZERO;
+        let mut low_ret = Decimal::ZERO;
+        let mut close_ret = Decimal::ZERO;
+        let mut volume = Decimal::ZERO;
+
+        for ticker in &tickers {
+            let Some(bar) = by_date[*ticker].get(date) else {
+                continue;
+            };
+            volume += bar.close * bar.volume;
+
+            let Some(prev_close) = last_close
+                .get(ticker)
+                .copied()
+                .filter(|c| *c > Decimal::ZERO)
+            else {
+                continue;
+            };
+            let w = weights.get(*ticker).copied().unwrap_or(Decimal::ZERO);
+            open_ret += w * (bar.open / prev_close - Decimal::ONE);
+            high_ret += w * (bar.high / prev_close - Decimal::ONE);
+            low_ret += w * (bar.low / prev_close - Decimal::ONE);
+            let r = bar.close / prev_close - Decimal::ONE;
+            close_ret += w * r;
+            returns
+                .entry(*ticker)
+                .or_default()
+                .push(r.to_f64().unwrap_or(0.0));
+        }
+
+        let bar = match series.last() {
+            None => {
+                // 기준일: 종가 = 기준값, 시가/고가/저가는 종가 대비 가중 비율
+                let mut open_ratio = Decimal::ZERO;
+                let mut high_ratio = Decimal::ZERO;
+                let mut low_ratio = Decimal::ZERO;
+                for ticker in &tickers {
+                    let b = by_date[*ticker][date];
+                    if b.close <= Decimal::ZERO {
+                        continue;
+                    }
+                    let w = weights.get(*ticker).copied().unwrap_or(Decimal::ZERO);
+                    open_ratio += w * b.open / b.close;
+                    high_ratio += w * b.high / b.close;
+                    low_ratio += w * b.low / b.close;
+                }
+                make_bar(
+                    *date,
+                    base_value * open_ratio,
+                    base_value * high_ratio,
+                    base_value * low_ratio,
+                    base_value,
+                    volume,
+                )
+            }
+            Some(prev) => {
+                let p = prev.close;
+                make_bar(
+                    *date,
+                    p * (Decimal::ONE + open_ret),
+                    p * (Decimal::ONE + high_ret),
+                    p * (Decimal::ONE + low_ret),
+                    p * (Decimal::ONE + close_ret),
+                    volume,
+                )
+            }
+        };
+        series.push(bar);
+
+        for ticker in &tickers {
+            if let Some(b) = by_date[*ticker].get(date) {
+                last_close.insert(*ticker, b.close);
+            }
+        }
+    }
+
+    series
+}
+
+/// 가중합 고가/저가가 시가/종가 범위를 벗어나지 않도록 보정.
+fn make_bar(
+    date: NaiveDate,
+    open: Decimal,
+    high: Decimal,
+    low: Decimal,
+    close: Decimal,
+    volume: Decimal,
+) -> IndexDailyBar {
+    let round = |v: Decimal| v.round_dp(6);
+    IndexDailyBar {
+        date,
+        open: round(open),
+        high: round(high.max(open).max(close)),
+        low: round(low.min(open).min(close)),
+        close: round(close),
+        volume: round(volume),
+    }
+}
+
+/// 해당 거래일의 목표 비중.
+///
+/// 역변동성 방식은 당일 이전 수익률만 사용하며(미래 참조 없음), 표본이 부족한
+/// 종목이 있으면 동일 비중을 사용합니다.
+fn target_weights(
+    tickers: &[&String],
+    weighting: IndexWeighting,
+    fixed_weights: &HashMap<String, Decimal>,
+    returns: &HashMap<&String, Vec<f64>>,
+) -> HashMap<String, Decimal> {
+    let equal = || normalize_weights(tickers.iter().map(|t| ((*t).clone(), Decimal::ONE)));
+
+    match weighting {
+        IndexWeighting::Equal => equal(),
+        IndexWeighting::Static | IndexWeighting::MarketCap => fixed_weights.clone(),
+        IndexWeighting::InverseVolatility => {
+            let mut inverse = Vec::with_capacity(tickers.len());
+            for ticker in tickers {
+                let history = returns.get(*ticker).map(Vec::as_slice).unwrap_or(&[]);
+                let window = &history[history.len().saturating_sub(INVERSE_VOLATILITY_WINDOW)..];
+                if window.len() < MIN_VOLATILITY_SAMPLES {
+                    return equal();
+                }
+                let vol = std_dev(window);
+                if vol <= f64::EPSILON {
+                    return equal();
+                }
+                let Some(w) = Decimal::from_f64(1.0 / vol) else {
+                    return equal();
+                };
+                inverse.push(((*ticker).clone(), w));
+            }
+            normalize_weights(inverse)
+        }
+    }
+}
+
+fn std_dev(values: &[f64]) -> f64 {
+    if values.len() < 2 {
+        return 0.0;
+    }
+    let mean = values.iter().sum::<f64>() / values.len() as f64;
+    let var = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (values.len() - 1) as f64;
+    var.sqrt()
+}
+
+/// 포트폴리오 대비 벤치마크 비교 결과.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BenchmarkComparison {
+    /// 비교에 사용된 공통 거래일 수
+    pub observations: usize,
+    /// 포트폴리오 기간 수익률 (%)
+    pub portfolio_return_pct: f64,
+    /// 벤치마크 기간 수익률 (%)
+    pub benchmark_return_pct: f64,
+    /// 초과 수익률 (%p)
+    pub excess_return_pct: f64,
+    /// 베타 (일간 수익률 기준)
+    pub beta: Option<f64>,
+    /// 상관계수 (일간 수익률 기준)
+    pub correlation: Option<f64>,
+    /// 연환산 추적오차 (%, 252거래일 기준)
+    pub tracking_error_pct: Option<f64>,
+}
+
+/// 일별 포트폴리오 가치와 벤치마크 종가 비교.
+///
+/// 두 시계열에 모두 존재하는 날짜만 사용합니다. 공통 거래일이 2일 미만이면 None.
+pub fn compare_to_benchmark(
+    portfolio: &BTreeMap<NaiveDate, f64>,
+    benchmark: &BTreeMap<NaiveDate, f64>,
+) -> Option<BenchmarkComparison> {
+    let aligned: Vec<(f64, f64)> = portfolio
+        .iter()
+        .filter_map(|(d, p)| benchmark.get(d).map(|b| (*p, *b)))
+        .filter(|(p, b)| *p > 0.0 && *b > 0.0)
+        .collect();
+    if aligned.len() < 2 {
+        return None;
+    }
+
+    let (first, last) = (aligned[0], aligned[aligned.len() - 1]);
+    let portfolio_return_pct = (last.0 / first.0 - 1.0) * 100.0;
+    let benchmark_return_pct = (last.1 / first.1 - 1.0) * 100.0;
+
+    let daily: Vec<(f64, f64)> = aligned
+        .windows(2)
+        .map(|w| (w[1].0 / w[0].0 - 1.0, w[1].1 / w[0].1 - 1.0))
+        .collect();
+
+    let (beta, correlation, tracking_error_pct) = if daily.len() >= 2 {
+        let n = daily.len() as f64;
+        let mean_p = daily.iter().map(|(p, _)| p).sum::<f64>() / n;
+        let mean_b = daily.iter().map(|(_, b)| b).sum::<f64>() / n;
+        let cov = daily
+            .iter()
+            .map(|(p, b)| (p - mean_p) * (b - mean_b))
+            .sum::<f64>()
+            / (n - 1.0);
+        let std_p = std_dev(&daily.iter().map(|(p, _)| *p).collect::<Vec<_>>());
+        let std_b = std_dev(&daily.iter().map(|(_, b)| *b).collect::<Vec<_>>());
+        let diffs: Vec<f64> = daily.iter().map(|(p, b)| p - b).collect();
+
+        let beta = (std_b > f64::EPSILON).then(|| cov / (std_b * std_b));
+        let correlation =
+            (std_p > f64::EPSILON && std_b > f64::EPSILON).then(|| cov / (std_p * std_b));
+        let tracking_error = std_dev(&diffs) * 252f64.sqrt() * 100.0;
+        (beta, correlation, Some(tracking_error))
+    } else {
+        (None, None, None)
+    };
+
+    Some(BenchmarkComparison {
+        observations: aligned.len(),
+        portfolio_return_pct,
+        benchmark_return_pct,
+        excess_return_pct: portfolio_return_pct - benchmark_return_pct,
+        beta,
+        correlation,
+        tracking_error_pct,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn day(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 3, d).unwrap()
+    }
+
+    fn bar(d: u32, close: Decimal) -> IndexDailyBar {
+        IndexDailyBar {
+            date: day(d),
+            open: close,
+            high: close,
+            low: close,
+            close,
+            volume: dec!(10),
+        }
+    }
+
+    #[test]
+    fn test_static_weighted_index_series() {
+        let bars = HashMap::from([
+            (
+                "A".to_string(),
+                vec![bar(2, dec!(100)), bar(3, dec!(110)), bar(4, dec!(110))],
+            ),
+            (
+                "B".to_string(),
+                vec![bar(2, dec!(50)), bar(3, dec!(50)), bar(4, dec!(45))],
+            ),
+        ]);
+        let weights = normalize_weights([("A".to_string(), dec!(3)), ("B".to_string(), dec!(1))]);
+
+        let series = compute_index_series(&bars, IndexWeighting::Static, &weights, dec!(1000));
+
+        assert_eq!(series.len(), 3);
+        assert_eq!(series[0].close, dec!(1000));
+        // A +10% × 0.75 = +7.5%
+        assert_eq!(series[1].close, dec!(1075));
+        // B -10% × 0.25 = -2.5%
+        assert_eq!(series[2].close, dec!(1048.125));
+        assert_eq!(series[1].volume, dec!(1600));
+    }
+
+    #[test]
+    fn test_index_starts_when_all_constituents_available() {
+        let bars = HashMap::from([
+            ("A".to_string(), vec![bar(2, dec!(100)), bar(3, dec!(102))]),
+            ("B".to_string(), vec![bar(3, dec!(20)), bar(4, dec!(22))]),
+        ]);
+
+        let series = compute_index_series(&bars, IndexWeighting::Equal, &HashMap::new(), dec!(100));
+
+        assert_eq!(series.first().map(|b| b.date), Some(day(3)));
+        // 4일: A 휴장(수익률 0), B +10% × 0.5
+        assert_eq!(series[1].close, dec!(105));
+    }
+
+    #[test]
+    fn test_validate_definition() {
+        let mut def = CustomIndexDefinition {
+            code: "SEMI_KR".to_string(),
+            name: "반도체 바스켓".to_string(),
+            market: "KR".to_string(),
+            weighting: IndexWeighting::Static,
+            base_value: dec!(1000),
+            constituents: vec![
+                IndexConstituent {
+                    ticker: "005930".to_string(),
+                    weight: Some(dec!(0.6)),
+                },
+                IndexConstituent {
+                    ticker: "000660".to_string(),
+                    weight: None,
+                },
+            ],
+        };
+        assert!(def.validate().is_err());
+
+        def.weighting = IndexWeighting::Equal;
+        assert!(def.validate().is_ok());
+        assert_eq!(def.ticker(), "IDX_SEMI_KR");
+
+        def.code = "semi".to_string();
+        assert!(def.validate().is_err());
+    }
+
+    #[test]
+    fn test_compare_to_benchmark() {
+        let portfolio = BTreeMap::from([(day(2), 100.0), (day(3), 102.0), (day(4), 104.04)]);
+        let benchmark = BTreeMap::from([(day(2), 50.0), (day(3), 50.5), (day(4), 51.005)]);
+
+        let cmp = compare_to_benchmark(&portfolio, &benchmark).unwrap();
+        assert_eq!(cmp.observations, 3);
+        assert!((cmp.portfolio_return_pct - 4.04).abs() < 1e-9);
+        assert!((cmp.excess_return_pct - 2.03).abs() < 1e-9);
+    }
+}
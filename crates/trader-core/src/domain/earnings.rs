@@ -0,0 +1,252 @@
+//! 실적 발표 일정 및 실적 발표 필터.
+//!
+//! 국내(실적발표/잠정실적 공시)와 미국(earnings) 종목의 실적 발표 예정일을 표현하고,
+//! 전략과 무관하게 적용되는 실적 발표 전후 진입 차단/청산 규칙을 정의합니다.
+//!
+//! 발표 예정일까지 남은 일수는 달력일 기준입니다. 발표 당일은 0일이며,
+//! 이미 지난 발표일은 필터 대상이 아닙니다.
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+/// 실적 발표 시점.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EarningsTiming {
+    /// 장 시작 전 발표
+    BeforeOpen,
+    /// 장 마감 후 발표
+    AfterClose,
+    /// 발표 시점 미확인 (장중 포함)
+    #[default]
+    Unknown,
+}
+
+impl EarningsTiming {
+    /// DB 저장용 문자열.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::BeforeOpen => "before_open",
+            Self::AfterClose => "after_close",
+            Self::Unknown => "unknown",
+        }
+    }
+
+    /// 문자열에서 파싱 (알 수 없는 값은 `Unknown`).
+    pub fn parse(s: &str) -> Self {
+        match s {
+            "before_open" => Self::BeforeOpen,
+            "after_close" => Self::AfterClose,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+/// 종목의 실적 발표 일정.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EarningsEvent {
+    /// 종목코드 (KR: 6자리, US: 티커)
+    pub ticker: String,
+    /// 시장 (KR, US)
+    pub market: String,
+    /// 발표 (예정)일
+    pub announce_date: NaiveDate,
+    /// 발표 시점
+    #[serde(default)]
+    pub timing: EarningsTiming,
+    /// 확정 일정 여부 (false = 전년 동기 발표일 기반 추정)
+    pub confirmed: bool,
+    /// 데이터 소스 (nasdaq, dart, manual)
+    pub source: String,
+}
+
+impl EarningsEvent {
+    /// 기준일부터 발표일까지 남은 달력일 수 (지난 발표는 음수).
+    pub fn days_until(&self, today: NaiveDate) -> i64 {
+        (self.announce_date - today).num_days()
+    }
+}
+
+/// 실적 발표 필터 판정 결과.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EarningsFilterAction {
+    /// 필터 대상 아님
+    Allow,
+    /// 신규 진입 차단 (발표일까지 남은 일수)
+    BlockEntry { days_until: i64 },
+    /// 보유 포지션 청산 (발표일까지 남은 일수)
+    Exit { days_until: i64 },
+}
+
+/// 전략별 실적 발표 필터 설정.
+///
+/// JSON 예: `{"entry_blackout_days": 3, "exit_before_days": 1}`
+/// (발표 3일 전부터 신규 진입 차단, 발표 1일 전부터 보유 포지션 자동 청산)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EarningsFilterConfig {
+    /// 발표 N일 이내 신규 진입 차단 (0 = 차단 안 함)
+    #[serde(default)]
+    pub entry_blackout_days: u32,
+    /// 발표 N일 이내 보유 포지션 자동 청산 (None = 청산 안 함)
+    #[serde(default)]
+    pub exit_before_days: Option<u32>,
+}
+
+impl EarningsFilterConfig {
+    /// 필터 활성화 여부.
+    pub fn is_enabled(&self) -> bool {
+        self.entry_blackout_days > 0 || self.exit_before_days.is_some()
+    }
+
+    /// 신호 판정.
+    ///
+    /// 진입 신호는 진입 차단 기간 또는 청산 기간 안이면 `BlockEntry`입니다.
+    /// 청산 신호는 항상 허용합니다.
+    pub fn evaluate_signal(
+        &self,
+        is_entry: bool,
+        event: &EarningsEvent,
+        today: NaiveDate,
+    ) -> EarningsFilterAction {
+        if !is_entry {
+            return EarningsFilterAction::Allow;
+        }
+        let days_until = event.days_until(today);
+        let blackout = self
+            .entry_blackout_days
+            .max(self.exit_before_days.unwrap_or(0));
+        if self.is_enabled() && (0..=blackout as i64).contains(&days_until) {
+            EarningsFilterAction::BlockEntry { days_until }
+        } else {
+            EarningsFilterAction::Allow
+        }
+    }
+
+    /// 보유 포지션 판정 (청산 기간 안이면 `Exit`).
+    pub fn evaluate_position(
+        &self,
+        event: &EarningsEvent,
+        today: NaiveDate,
+    ) -> EarningsFilterAction {
+        let days_until = event.days_until(today);
+        match self.exit_before_days {
+            Some(days) if (0..=days as i64).contains(&days_until) => {
+                EarningsFilterAction::Exit { days_until }
+            }
+            _ => EarningsFilterAction::Allow,
+        }
+    }
+}
+
+/// 종목별 발표 일정 중 기준일 이후 가장 가까운 일정 선택.
+pub fn next_earnings<'a>(
+    events: impl IntoIterator<Item = &'a EarningsEvent>,
+    today: NaiveDate,
+) -> Option<&'a EarningsEvent> {
+    events
+        .into_iter()
+        .filter(|e| e.announce_date >= today)
+        .min_by_key(|e| (e.announce_date, !e.confirmed))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(date: NaiveDate) -> EarningsEvent {
+        EarningsEvent {
+            ticker: "AAPL".to_string(),
+            market: "US".to_string(),
+            announce_date: date,
+            timing: EarningsTiming::AfterClose,
+            confirmed: true,
+            source: "nasdaq".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_evaluate_signal_blocks_entry_within_window() {
+        let today = NaiveDate::from_ymd_opt(2026, 4, 27).unwrap();
+        let config = EarningsFilterConfig {
+            entry_blackout_days: 3,
+            exit_before_days: None,
+        };
+
+        let near = event(NaiveDate::from_ymd_opt(2026, 4, 30).unwrap());
+        assert_eq!(
+            config.evaluate_signal(true, &near, today),
+            EarningsFilterAction::BlockEntry { days_until: 3 }
+        );
+        // 청산 신호는 허용
+        assert_eq!(
+            config.evaluate_signal(false, &near, today),
+            EarningsFilterAction::Allow
+        );
+
+        // 차단 기간 밖, 이미 지난 발표
+        let far = event(NaiveDate::from_ymd_opt(2026, 5, 1).unwrap());
+        let past = event(NaiveDate::from_ymd_opt(2026, 4, 26).unwrap());
+        assert_eq!(
+            config.evaluate_signal(true, &far, today),
+            EarningsFilterAction::Allow
+        );
+        assert_eq!(
+            config.evaluate_signal(true, &past, today),
+            EarningsFilterAction::Allow
+        );
+
+        // 비활성 설정
+        assert_eq!(
+            EarningsFilterConfig::default().evaluate_signal(true, &near, today),
+            EarningsFilterAction::Allow
+        );
+    }
+
+    #[test]
+    fn test_evaluate_position_exit_window() {
+        let today = NaiveDate::from_ymd_opt(2026, 4, 29).unwrap();
+        let config = EarningsFilterConfig {
+            entry_blackout_days: 0,
+            exit_before_days: Some(1),
+        };
+
+        let tomorrow = event(NaiveDate::from_ymd_opt(2026, 4, 30).unwrap());
+        assert_eq!(
+            config.evaluate_position(&tomorrow, today),
+            EarningsFilterAction::Exit { days_until: 1 }
+        );
+        // 청산 기간 안의 진입도 차단
+        assert_eq!(
+            config.evaluate_signal(true, &tomorrow, today),
+            EarningsFilterAction::BlockEntry { days_until: 1 }
+        );
+
+        let later = event(NaiveDate::from_ymd_opt(2026, 5, 4).unwrap());
+        assert_eq!(
+            config.evaluate_position(&later, today),
+            EarningsFilterAction::Allow
+        );
+    }
+
+    #[test]
+    fn test_next_earnings() {
+        let today = NaiveDate::from_ymd_opt(2026, 4, 27).unwrap();
+        let mut projected = event(NaiveDate::from_ymd_opt(2026, 4, 30).unwrap());
+        projected.confirmed = false;
+        let events = vec![
+            event(NaiveDate::from_ymd_opt(2026, 1, 29).unwrap()),
+            event(NaiveDate::from_ymd_opt(2026, 7, 30).unwrap()),
+            projected,
+            event(NaiveDate::from_ymd_opt(2026, 4, 30).unwrap()),
+        ];
+
+        let next = next_earnings(&events, today).unwrap();
+        assert_eq!(
+            next.announce_date,
+            NaiveDate::from_ymd_opt(2026, 4, 30).unwrap()
+        );
+        // 같은 날이면 확정 일정 우선
+        assert!(next.confirmed);
+        assert!(next_earnings(&events[..1], today).is_none());
+    }
+}
@@ -0,0 +1,96 @@
+//! ETF 구성종목(PDF) 및 NAV 괴리율.
+//!
+//! 국내 ETF의 일별 NAV와 종가로 괴리율(프리미엄/디스카운트)을 계산하고,
+//! 납입자산구성내역(PDF)의 구성종목을 표현합니다.
+//!
+//! 괴리율 = (시장가격 / NAV - 1) × 100. 양수는 프리미엄(고평가), 음수는 디스카운트입니다.
+
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// 레버리지/인버스 ETF 종목명 키워드
+const LEVERAGED_KEYWORDS: &[&str] = &["레버리지", "인버스", "2X", "3X", "LEVERAGE", "INVERSE"];
+
+/// ETF 일별 NAV 관측값.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EtfNavPoint {
+    /// ETF 종목코드
+    pub ticker: String,
+    /// 거래일
+    pub trade_date: NaiveDate,
+    /// 종가
+    pub close_price: Decimal,
+    /// 순자산가치 (NAV)
+    pub nav: Decimal,
+}
+
+impl EtfNavPoint {
+    /// 괴리율 (%). NAV가 0 이하이면 None.
+    pub fn premium_pct(&self) -> Option<f64> {
+        etf_premium_pct(self.close_price, self.nav)
+    }
+}
+
+/// ETF 구성종목 (PDF 항목).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EtfConstituent {
+    /// ETF 종목코드
+    pub etf_ticker: String,
+    /// 기준일
+    pub as_of_date: NaiveDate,
+    /// 구성종목 코드 (현금 등은 KRX 코드 그대로)
+    pub ticker: String,
+    /// 구성종목명
+    pub name: String,
+    /// CU당 구성 수량
+    pub shares: Decimal,
+    /// 평가금액
+    pub value_amount: Decimal,
+    /// 구성 비중 (%)
+    pub weight_pct: Option<Decimal>,
+}
+
+/// 시장가격과 NAV로 괴리율(%) 계산.
+pub fn etf_premium_pct(price: Decimal, nav: Decimal) -> Option<f64> {
+    if nav <= Decimal::ZERO {
+        return None;
+    }
+    ((price / nav - Decimal::ONE) * Decimal::from(100))
+        .to_string()
+        .parse::<f64>()
+        .ok()
+}
+
+/// 종목명으로 레버리지/인버스 ETF 여부 판정.
+pub fn is_leveraged_etf(name: &str) -> bool {
+    let upper = name.to_uppercase();
+    LEVERAGED_KEYWORDS.iter().any(|kw| upper.contains(kw))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_etf_premium_pct() {
+        let point = EtfNavPoint {
+            ticker: "122630".to_string(),
+            trade_date: NaiveDate::from_ymd_opt(2026, 3, 4).unwrap(),
+            close_price: dec!(10150),
+            nav: dec!(10000),
+        };
+        assert!((point.premium_pct().unwrap() - 1.5).abs() < 1e-9);
+        assert!((etf_premium_pct(dec!(9900), dec!(10000)).unwrap() + 1.0).abs() < 1e-9);
+        assert!(etf_premium_pct(dec!(100), Decimal::ZERO).is_none());
+    }
+
+    #[test]
+    fn test_is_leveraged_etf() {
+        assert!(is_leveraged_etf("KODEX 레버리지"));
+        assert!(is_leveraged_etf("KODEX 200선물인버스2X"));
+        assert!(is_leveraged_etf("ProShares UltraPro QQQ 3x"));
+        assert!(!is_leveraged_etf("KODEX 200"));
+    }
+}
@@ -0,0 +1,214 @@
+//! 지수 선물 계약 명세와 증거금 계산.
+//!
+//! KOSPI200 / 미니 KOSPI200 선물은 주식과 달리 체결 시 명목금액 전체가 아닌
+//! 증거금만 묶이고, 손익은 포인트 차이 × 거래승수로 정산됩니다.
+//! 포지션 추적·자본 배분에서 선물 종목을 구분할 때 이 모듈의 계약 명세를 사용합니다.
+
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::domain::Side;
+
+/// KOSPI200 선물 거래승수 (1포인트당 원).
+pub const KOSPI200_FUTURES_MULTIPLIER: Decimal = Decimal::from_parts(250_000, 0, 0, false, 0);
+
+/// 미니 KOSPI200 선물 거래승수 (1포인트당 원).
+pub const MINI_KOSPI200_FUTURES_MULTIPLIER: Decimal = Decimal::from_parts(50_000, 0, 0, false, 0);
+
+/// 기본 위탁증거금률 (명목금액 대비, 0.09 = 9%).
+pub const DEFAULT_FUTURES_INITIAL_MARGIN_RATE: Decimal = Decimal::from_parts(9, 0, 0, false, 2);
+
+/// 기본 유지증거금률 (명목금액 대비, 0.06 = 6%).
+pub const DEFAULT_FUTURES_MAINTENANCE_MARGIN_RATE: Decimal = Decimal::from_parts(6, 0, 0, false, 2);
+
+/// 국내 지수 선물 상품.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FuturesProduct {
+    /// KOSPI200 선물
+    Kospi200,
+    /// 미니 KOSPI200 선물
+    MiniKospi200,
+}
+
+impl FuturesProduct {
+    /// 선물 단축코드로부터 상품 판별.
+    ///
+    /// 구 단축코드("101W9000", "105W9000")와 신 단축코드("A0166000", "A0566000")를
+    /// 모두 인식합니다. 6자리 주식 코드("105560" 등)와 겹치지 않도록 8자리 코드만 받으며,
+    /// 선물 코드가 아니면 `None`을 반환합니다.
+    pub fn from_code(code: &str) -> Option<Self> {
+        let code = code.trim();
+        if code.len() != 8 || !code.is_ascii() {
+            return None;
+        }
+        match &code[..3] {
+            "101" | "A01" => Some(Self::Kospi200),
+            "105" | "A05" => Some(Self::MiniKospi200),
+            _ => None,
+        }
+    }
+
+    /// 거래승수.
+    pub fn multiplier(&self) -> Decimal {
+        match self {
+            Self::Kospi200 => KOSPI200_FUTURES_MULTIPLIER,
+            Self::MiniKospi200 => MINI_KOSPI200_FUTURES_MULTIPLIER,
+        }
+    }
+}
+
+impl fmt::Display for FuturesProduct {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Kospi200 => write!(f, "kospi200"),
+            Self::MiniKospi200 => write!(f, "mini_kospi200"),
+        }
+    }
+}
+
+/// 국내 선물 종목코드 여부.
+pub fn is_kr_futures_code(code: &str) -> bool {
+    FuturesProduct::from_code(code).is_some()
+}
+
+/// 선물 계약 명세.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FuturesContract {
+    /// 종목코드 (예: "101W9000")
+    pub code: String,
+    /// 상품
+    pub product: FuturesProduct,
+    /// 기초자산 (예: "KOSPI200")
+    pub underlying: String,
+    /// 거래승수
+    pub multiplier: Decimal,
+    /// 만기일 (최종거래일, 미상이면 None)
+    pub expiry: Option<NaiveDate>,
+    /// 위탁증거금률 (명목금액 대비)
+    pub initial_margin_rate: Decimal,
+    /// 유지증거금률 (명목금액 대비)
+    pub maintenance_margin_rate: Decimal,
+}
+
+impl FuturesContract {
+    /// 상품 기본값(거래승수, 기본 증거금률)으로 계약 명세 생성.
+    pub fn new(code: impl Into<String>, product: FuturesProduct) -> Self {
+        Self {
+            code: code.into(),
+            product,
+            underlying: "KOSPI200".to_string(),
+            multiplier: product.multiplier(),
+            expiry: None,
+            initial_margin_rate: DEFAULT_FUTURES_INITIAL_MARGIN_RATE,
+            maintenance_margin_rate: DEFAULT_FUTURES_MAINTENANCE_MARGIN_RATE,
+        }
+    }
+
+    /// 단축코드로부터 계약 명세 생성 (선물 코드가 아니면 `None`).
+    pub fn from_code(code: &str) -> Option<Self> {
+        FuturesProduct::from_code(code).map(|product| Self::new(code.trim(), product))
+    }
+
+    /// 만기일 설정.
+    pub fn with_expiry(mut self, expiry: NaiveDate) -> Self {
+        self.expiry = Some(expiry);
+        self
+    }
+
+    /// 증거금률 설정 (거래소/증권사 공지에 맞춰 조정).
+    pub fn with_margin_rates(mut self, initial: Decimal, maintenance: Decimal) -> Self {
+        self.initial_margin_rate = initial;
+        self.maintenance_margin_rate = maintenance;
+        self
+    }
+
+    /// 명목금액 (가격 × 수량 × 거래승수).
+    pub fn notional(&self, price: Decimal, quantity: Decimal) -> Decimal {
+        price * quantity * self.multiplier
+    }
+
+    /// 위탁증거금 (주문 시 필요한 증거금).
+    pub fn initial_margin(&self, price: Decimal, quantity: Decimal) -> Decimal {
+        self.notional(price, quantity) * self.initial_margin_rate
+    }
+
+    /// 유지증거금 (미달 시 추가증거금 발생).
+    pub fn maintenance_margin(&self, price: Decimal, quantity: Decimal) -> Decimal {
+        self.notional(price, quantity) * self.maintenance_margin_rate
+    }
+
+    /// 진입가 대비 청산가 손익 (원).
+    pub fn pnl(&self, side: Side, entry: Decimal, exit: Decimal, quantity: Decimal) -> Decimal {
+        let points = match side {
+            Side::Buy => exit - entry,
+            Side::Sell => entry - exit,
+        };
+        points * quantity * self.multiplier
+    }
+
+    /// 기준일로부터 만기까지 남은 일수 (만기 미상이면 None, 경과 시 0).
+    pub fn days_to_expiry(&self, today: NaiveDate) -> Option<i64> {
+        self.expiry.map(|expiry| (expiry - today).num_days().max(0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_futures_product_from_code() {
+        assert_eq!(
+            FuturesProduct::from_code("101W9000"),
+            Some(FuturesProduct::Kospi200)
+        );
+        assert_eq!(
+            FuturesProduct::from_code("A0566000"),
+            Some(FuturesProduct::MiniKospi200)
+        );
+        assert_eq!(FuturesProduct::from_code("201W3360"), None);
+        assert_eq!(FuturesProduct::from_code("005930"), None);
+        assert_eq!(FuturesProduct::from_code("105560"), None);
+        assert_eq!(FuturesProduct::from_code("101"), None);
+        assert!(is_kr_futures_code("105W9000"));
+        assert!(!is_kr_futures_code("AAPL"));
+    }
+
+    #[test]
+    fn test_futures_contract_margin_and_pnl() {
+        let contract = FuturesContract::from_code("101W9000").unwrap();
+        assert_eq!(contract.multiplier, dec!(250000));
+        // 360pt × 2계약 × 25만 = 1.8억
+        assert_eq!(contract.notional(dec!(360), dec!(2)), dec!(180000000));
+        assert_eq!(
+            contract.initial_margin(dec!(360), dec!(2)),
+            dec!(16200000.00)
+        );
+        assert_eq!(
+            contract.maintenance_margin(dec!(360), dec!(2)),
+            dec!(10800000.00)
+        );
+        assert_eq!(
+            contract.pnl(Side::Buy, dec!(360), dec!(362.5), dec!(2)),
+            dec!(1250000)
+        );
+        assert_eq!(
+            contract.pnl(Side::Sell, dec!(360), dec!(362.5), dec!(2)),
+            dec!(-1250000)
+        );
+
+        let mini = FuturesContract::from_code("105W9000")
+            .unwrap()
+            .with_margin_rates(dec!(0.1), dec!(0.07))
+            .with_expiry(NaiveDate::from_ymd_opt(2026, 12, 10).unwrap());
+        assert_eq!(mini.initial_margin(dec!(360), dec!(1)), dec!(1800000.0));
+        assert_eq!(
+            mini.days_to_expiry(NaiveDate::from_ymd_opt(2026, 12, 1).unwrap()),
+            Some(9)
+        );
+    }
+}
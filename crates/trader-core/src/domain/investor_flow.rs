@@ -0,0 +1,119 @@
+//! InvestorFlow - 국내 주식 투자자별 순매수.
+//!
+//! KRX 정보데이터시스템의 종목별 투자자 유형(외국인/기관/개인/기타법인) 일별 순매수 대금을
+//! 표현하고, 기간 합계와 연속 순매수 일수를 요약합니다.
+//!
+//! 외국인 + 기관 순매수("스마트 머니")는 국내 시장에서 널리 쓰이는 수급 신호입니다.
+
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// 종목별 일별 투자자 순매수 대금 (원, 양수 = 순매수).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InvestorFlow {
+    /// 종목코드 (6자리)
+    pub ticker: String,
+    /// 거래일
+    pub trade_date: NaiveDate,
+    /// 외국인 순매수 대금
+    pub foreign_net: Decimal,
+    /// 기관 합계 순매수 대금
+    pub institution_net: Decimal,
+    /// 개인 순매수 대금
+    pub individual_net: Decimal,
+    /// 기타법인 순매수 대금
+    pub other_corporation_net: Decimal,
+}
+
+impl InvestorFlow {
+    /// 외국인 + 기관 순매수 대금.
+    pub fn smart_money_net(&self) -> Decimal {
+        self.foreign_net + self.institution_net
+    }
+}
+
+/// 기간별 투자자 순매수 요약.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct InvestorFlowSummary {
+    /// 집계 일수
+    pub days: usize,
+    /// 외국인 순매수 합계
+    pub foreign_net: Decimal,
+    /// 기관 순매수 합계
+    pub institution_net: Decimal,
+    /// 개인 순매수 합계
+    pub individual_net: Decimal,
+    /// 외국인 연속 순매수 일수 (최근일 기준)
+    pub foreign_buy_streak: usize,
+    /// 기관 연속 순매수 일수 (최근일 기준)
+    pub institution_buy_streak: usize,
+}
+
+impl InvestorFlowSummary {
+    /// 일별 순매수에서 요약 생성 (입력 순서 무관).
+    pub fn from_flows(flows: &[InvestorFlow]) -> Self {
+        let mut sorted: Vec<&InvestorFlow> = flows.iter().collect();
+        sorted.sort_by_key(|f| f.trade_date);
+
+        let streak = |net: fn(&InvestorFlow) -> Decimal| {
+            sorted
+                .iter()
+                .rev()
+                .take_while(|f| net(f) > Decimal::ZERO)
+                .count()
+        };
+
+        Self {
+            days: sorted.len(),
+            foreign_net: sorted.iter().map(|f| f.foreign_net).sum(),
+            institution_net: sorted.iter().map(|f| f.institution_net).sum(),
+            individual_net: sorted.iter().map(|f| f.individual_net).sum(),
+            foreign_buy_streak: streak(|f| f.foreign_net),
+            institution_buy_streak: streak(|f| f.institution_net),
+        }
+    }
+
+    /// 외국인 + 기관 순매수 합계.
+    pub fn smart_money_net(&self) -> Decimal {
+        self.foreign_net + self.institution_net
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn flow(day: u32, foreign: Decimal, institution: Decimal) -> InvestorFlow {
+        InvestorFlow {
+            ticker: "005930".to_string(),
+            trade_date: NaiveDate::from_ymd_opt(2026, 3, day).unwrap(),
+            foreign_net: foreign,
+            institution_net: institution,
+            individual_net: -(foreign + institution),
+            other_corporation_net: Decimal::ZERO,
+        }
+    }
+
+    #[test]
+    fn test_investor_flow_summary() {
+        // 역순 입력도 날짜순으로 집계
+        let flows = vec![
+            flow(5, dec!(300), dec!(100)),
+            flow(4, dec!(200), dec!(-50)),
+            flow(3, dec!(-100), dec!(80)),
+        ];
+
+        let summary = InvestorFlowSummary::from_flows(&flows);
+        assert_eq!(summary.days, 3);
+        assert_eq!(summary.foreign_net, dec!(400));
+        assert_eq!(summary.institution_net, dec!(130));
+        assert_eq!(summary.individual_net, dec!(-530));
+        assert_eq!(summary.smart_money_net(), dec!(530));
+        assert_eq!(summary.foreign_buy_streak, 2);
+        assert_eq!(summary.institution_buy_streak, 1);
+
+        assert_eq!(InvestorFlowSummary::from_flows(&[]).days, 0);
+    }
+}
@@ -0,0 +1,225 @@
+//! MacroRegime - 매크로 레짐 종합 지표.
+//!
+//! USD/KRW 추세, 장단기 금리차, 변동성 지수(VIX/VKOSPI), 시장 폭을 각각
+//! -1(위험 회피) ~ +1(위험 선호) 점수로 정규화한 뒤 평균하여 종합 점수를 만듭니다.
+//! 수집기가 매일 계산해 `macro_series`에 이력으로 저장하며, 자산배분 전략은
+//! 컨텍스트의 최신 값을 참조합니다.
+//!
+//! 입력이 없는 구성 요소는 제외하고 나머지로 평균합니다.
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// USD/KRW 추세 점수 만점 기준 (20거래일 변동률 %, 원화 약세가 위험 회피).
+pub const FX_TREND_FULL_SCALE_PCT: f64 = 3.0;
+/// 장단기 금리차 점수 만점 기준 (%p, 역전 시 위험 회피).
+pub const YIELD_SLOPE_FULL_SCALE: f64 = 1.0;
+/// 변동성 지수 중립 수준.
+pub const VOLATILITY_NEUTRAL: f64 = 20.0;
+/// 변동성 지수 점수 만점 기준 (중립 대비 포인트).
+pub const VOLATILITY_FULL_SCALE: f64 = 10.0;
+/// 시장 폭 중립 수준 (20일선 상회 비율).
+pub const BREADTH_NEUTRAL: f64 = 0.5;
+/// 시장 폭 점수 만점 기준 (중립 대비 비율).
+pub const BREADTH_FULL_SCALE: f64 = 0.25;
+/// 레짐 판정 임계값 (종합 점수 절댓값).
+pub const REGIME_THRESHOLD: f64 = 0.3;
+
+/// 매크로 레짐 수준.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum MacroRegimeLevel {
+    /// 위험 선호 (종합 점수 >= 0.3)
+    RiskOn,
+    /// 중립
+    #[default]
+    Neutral,
+    /// 위험 회피 (종합 점수 <= -0.3)
+    RiskOff,
+}
+
+impl MacroRegimeLevel {
+    /// 종합 점수로부터 레짐 판정.
+    pub fn from_score(score: f64) -> Self {
+        if score >= REGIME_THRESHOLD {
+            Self::RiskOn
+        } else if score <= -REGIME_THRESHOLD {
+            Self::RiskOff
+        } else {
+            Self::Neutral
+        }
+    }
+
+    /// 위험 회피 레짐 여부.
+    pub fn is_risk_off(self) -> bool {
+        matches!(self, Self::RiskOff)
+    }
+}
+
+impl fmt::Display for MacroRegimeLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Self::RiskOn => "RISK_ON",
+            Self::Neutral => "NEUTRAL",
+            Self::RiskOff => "RISK_OFF",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// 매크로 레짐 원천 입력값.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MacroRegimeInputs {
+    /// USD/KRW 20거래일 변동률 (%)
+    pub usd_krw_trend_pct: Option<f64>,
+    /// 미국 10년-2년 국채 금리차 (%p)
+    pub yield_curve_slope: Option<f64>,
+    /// VIX
+    pub vix: Option<f64>,
+    /// VKOSPI
+    pub vkospi: Option<f64>,
+    /// 20일선 상회 종목 비율 (0.0 ~ 1.0)
+    pub breadth_ratio: Option<f64>,
+}
+
+/// 구성 요소별 점수 (-1.0 ~ +1.0, 양수가 위험 선호).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MacroRegimeComponents {
+    /// 환율 추세 점수
+    pub fx: Option<f64>,
+    /// 금리차 점수
+    pub yield_curve: Option<f64>,
+    /// 변동성 점수 (VIX/VKOSPI 평균)
+    pub volatility: Option<f64>,
+    /// 시장 폭 점수
+    pub breadth: Option<f64>,
+}
+
+impl MacroRegimeComponents {
+    /// 입력값으로 구성 요소 점수 계산.
+    pub fn from_inputs(inputs: &MacroRegimeInputs) -> Self {
+        let volatility = average(
+            [inputs.vix, inputs.vkospi]
+                .into_iter()
+                .flatten()
+                .map(|v| scaled(VOLATILITY_NEUTRAL - v, VOLATILITY_FULL_SCALE)),
+        );
+
+        Self {
+            fx: inputs
+                .usd_krw_trend_pct
+                .map(|pct| scaled(-pct, FX_TREND_FULL_SCALE_PCT)),
+            yield_curve: inputs
+                .yield_curve_slope
+                .map(|slope| scaled(slope, YIELD_SLOPE_FULL_SCALE)),
+            volatility,
+            breadth: inputs
+                .breadth_ratio
+                .map(|ratio| scaled(ratio - BREADTH_NEUTRAL, BREADTH_FULL_SCALE)),
+        }
+    }
+
+    /// 값이 있는 구성 요소의 평균 (없으면 None).
+    pub fn composite(&self) -> Option<f64> {
+        average(
+            [self.fx, self.yield_curve, self.volatility, self.breadth]
+                .into_iter()
+                .flatten(),
+        )
+    }
+}
+
+/// 일별 매크로 레짐.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MacroRegime {
+    /// 기준일
+    pub date: NaiveDate,
+    /// 종합 점수 (-1.0 ~ +1.0)
+    pub score: f64,
+    /// 레짐 수준
+    pub level: MacroRegimeLevel,
+    /// 구성 요소별 점수
+    pub components: MacroRegimeComponents,
+    /// 원천 입력값
+    pub inputs: MacroRegimeInputs,
+}
+
+impl MacroRegime {
+    /// 입력값으로 매크로 레짐 계산 (입력이 하나도 없으면 None).
+    pub fn compute(date: NaiveDate, inputs: MacroRegimeInputs) -> Option<Self> {
+        let components = MacroRegimeComponents::from_inputs(&inputs);
+        let score = components.composite()?;
+        Some(Self {
+            date,
+            score,
+            level: MacroRegimeLevel::from_score(score),
+            components,
+            inputs,
+        })
+    }
+}
+
+/// 중립 대비 편차를 만점 기준으로 나누어 -1 ~ +1로 제한.
+fn scaled(deviation: f64, full_scale: f64) -> f64 {
+    (deviation / full_scale).clamp(-1.0, 1.0)
+}
+
+fn average(values: impl Iterator<Item = f64>) -> Option<f64> {
+    let (sum, count) = values.fold((0.0, 0usize), |(sum, count), v| (sum + v, count + 1));
+    (count > 0).then(|| sum / count as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 3, 4).unwrap()
+    }
+
+    #[test]
+    fn test_macro_regime_compute() {
+        // 원화 강세, 정상 금리 곡선, 낮은 변동성, 넓은 시장 폭 → 위험 선호
+        let regime = MacroRegime::compute(
+            date(),
+            MacroRegimeInputs {
+                usd_krw_trend_pct: Some(-1.5),
+                yield_curve_slope: Some(0.6),
+                vix: Some(14.0),
+                vkospi: Some(16.0),
+                breadth_ratio: Some(0.7),
+            },
+        )
+        .unwrap();
+        assert!((regime.components.fx.unwrap() - 0.5).abs() < 1e-9);
+        assert!((regime.components.volatility.unwrap() - 0.5).abs() < 1e-9);
+        assert!((regime.components.breadth.unwrap() - 0.8).abs() < 1e-9);
+        assert!((regime.score - 0.6).abs() < 1e-9);
+        assert_eq!(regime.level, MacroRegimeLevel::RiskOn);
+
+        // 원화 급락, 금리 역전, VIX 급등 → 위험 회피 (점수는 -1로 제한)
+        let regime = MacroRegime::compute(
+            date(),
+            MacroRegimeInputs {
+                usd_krw_trend_pct: Some(4.0),
+                yield_curve_slope: Some(-0.5),
+                vix: Some(35.0),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(regime.components.fx, Some(-1.0));
+        assert_eq!(regime.components.breadth, None);
+        assert!((regime.score - (-2.5 / 3.0)).abs() < 1e-9);
+        assert_eq!(regime.level, MacroRegimeLevel::RiskOff);
+        assert!(regime.level.is_risk_off());
+    }
+
+    #[test]
+    fn test_macro_regime_requires_inputs() {
+        assert!(MacroRegime::compute(date(), MacroRegimeInputs::default()).is_none());
+        assert_eq!(MacroRegimeLevel::from_score(0.1), MacroRegimeLevel::Neutral);
+        assert_eq!(MacroRegimeLevel::RiskOff.to_string(), "RISK_OFF");
+    }
+}
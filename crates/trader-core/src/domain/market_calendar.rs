@@ -0,0 +1,458 @@
+//! 시장 휴장일/단축 거래일 달력.
+//!
+//! 국내(KRX)와 미국 시장의 연간 휴장일과 개장 지연/조기 폐장일을 표현합니다.
+//! 달력은 연 단위로 적재되며, 적재되지 않은 연도는 "모름"(`None`)으로 응답하여
+//! 호출자가 거래소 API 등 다른 경로로 확인할 수 있게 합니다.
+//!
+//! - 주말은 달력 적재 여부와 관계없이 항상 휴장입니다.
+//! - 시간은 모두 해당 시장의 현지 시각입니다 (KR: KST, US: ET).
+
+use std::collections::{HashMap, HashSet};
+
+use chrono::{Datelike, Duration, NaiveDate, NaiveTime, Weekday};
+use serde::{Deserialize, Serialize};
+
+/// 달력 대상 시장.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CalendarMarket {
+    /// 한국거래소 (KOSPI/KOSDAQ)
+    #[serde(rename = "KR")]
+    Kr,
+    /// 미국 (NYSE/NASDAQ/AMEX)
+    #[serde(rename = "US")]
+    Us,
+}
+
+impl CalendarMarket {
+    /// 시장 코드 (DB 저장값).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Kr => "KR",
+            Self::Us => "US",
+        }
+    }
+
+    /// 정규장 개장/폐장 시각 (현지 시각).
+    pub fn regular_hours(&self) -> (NaiveTime, NaiveTime) {
+        match self {
+            Self::Kr => (hm(9, 0), hm(15, 30)),
+            Self::Us => (hm(9, 30), hm(16, 0)),
+        }
+    }
+}
+
+impl std::fmt::Display for CalendarMarket {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl std::str::FromStr for CalendarMarket {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_uppercase().as_str() {
+            "KR" | "KRX" => Ok(Self::Kr),
+            "US" | "USA" => Ok(Self::Us),
+            _ => Err(format!("Unknown calendar market: {}", s)),
+        }
+    }
+}
+
+/// 특수 거래일 유형.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MarketDayKind {
+    /// 휴장
+    Holiday,
+    /// 조기 폐장 (예: 미국 추수감사절 다음 날 13:00 폐장)
+    EarlyClose,
+    /// 개장 지연 (예: KRX 연초 첫 거래일 10:00 개장)
+    LateOpen,
+}
+
+impl MarketDayKind {
+    /// DB 저장값.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Holiday => "holiday",
+            Self::EarlyClose => "early_close",
+            Self::LateOpen => "late_open",
+        }
+    }
+}
+
+impl std::str::FromStr for MarketDayKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "holiday" => Ok(Self::Holiday),
+            "early_close" => Ok(Self::EarlyClose),
+            "late_open" => Ok(Self::LateOpen),
+            _ => Err(format!("Unknown market day kind: {}", s)),
+        }
+    }
+}
+
+/// 달력 항목 (휴장일 또는 단축 거래일).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MarketCalendarEntry {
+    /// 시장
+    pub market: CalendarMarket,
+    /// 일자 (현지 기준)
+    pub date: NaiveDate,
+    /// 유형
+    pub kind: MarketDayKind,
+    /// 변경된 개장 시각 (없으면 정규장 개장 시각)
+    pub open_time: Option<NaiveTime>,
+    /// 변경된 폐장 시각 (없으면 정규장 폐장 시각)
+    pub close_time: Option<NaiveTime>,
+    /// 사유 (예: "설날", "Thanksgiving Day")
+    pub name: Option<String>,
+}
+
+impl MarketCalendarEntry {
+    /// 휴장일 항목 생성.
+    pub fn holiday(market: CalendarMarket, date: NaiveDate, name: Option<String>) -> Self {
+        Self {
+            market,
+            date,
+            kind: MarketDayKind::Holiday,
+            open_time: None,
+            close_time: None,
+            name,
+        }
+    }
+
+    /// 조기 폐장 항목 생성.
+    pub fn early_close(
+        market: CalendarMarket,
+        date: NaiveDate,
+        close_time: NaiveTime,
+        name: Option<String>,
+    ) -> Self {
+        Self {
+            market,
+            date,
+            kind: MarketDayKind::EarlyClose,
+            open_time: None,
+            close_time: Some(close_time),
+            name,
+        }
+    }
+
+    /// 개장 지연 항목 생성.
+    pub fn late_open(
+        market: CalendarMarket,
+        date: NaiveDate,
+        open_time: NaiveTime,
+        name: Option<String>,
+    ) -> Self {
+        Self {
+            market,
+            date,
+            kind: MarketDayKind::LateOpen,
+            open_time: Some(open_time),
+            close_time: None,
+            name,
+        }
+    }
+
+    /// 휴장 여부.
+    pub fn is_holiday(&self) -> bool {
+        self.kind == MarketDayKind::Holiday
+    }
+}
+
+/// 연 단위로 적재되는 시장 달력.
+#[derive(Debug, Clone, Default)]
+pub struct MarketCalendar {
+    entries: HashMap<(CalendarMarket, NaiveDate), MarketCalendarEntry>,
+    loaded_years: HashSet<(CalendarMarket, i32)>,
+}
+
+impl MarketCalendar {
+    /// 빈 달력 생성.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 한 해의 달력 적재 (같은 시장/연도의 기존 항목은 교체).
+    ///
+    /// 다른 연도의 항목은 무시합니다. 항목이 없어도 "휴장일 없음"으로 적재됩니다.
+    pub fn load_year(
+        &mut self,
+        market: CalendarMarket,
+        year: i32,
+        entries: impl IntoIterator<Item = MarketCalendarEntry>,
+    ) {
+        self.entries
+            .retain(|(m, date), _| *m != market || date.year() != year);
+        for entry in entries {
+            if entry.market == market && entry.date.year() == year {
+                self.entries.insert((market, entry.date), entry);
+            }
+        }
+        self.loaded_years.insert((market, year));
+    }
+
+    /// 해당 일자가 속한 연도가 적재되었는지 여부.
+    pub fn covers(&self, market: CalendarMarket, date: NaiveDate) -> bool {
+        self.loaded_years.contains(&(market, date.year()))
+    }
+
+    /// 적재된 연도 목록 (오름차순).
+    pub fn loaded_years(&self, market: CalendarMarket) -> Vec<i32> {
+        let mut years: Vec<i32> = self
+            .loaded_years
+            .iter()
+            .filter(|(m, _)| *m == market)
+            .map(|(_, year)| *year)
+            .collect();
+        years.sort_unstable();
+        years
+    }
+
+    /// 해당 일자의 달력 항목.
+    pub fn entry(&self, market: CalendarMarket, date: NaiveDate) -> Option<&MarketCalendarEntry> {
+        self.entries.get(&(market, date))
+    }
+
+    /// 휴장일 여부.
+    ///
+    /// 주말은 항상 `Some(true)`, 적재되지 않은 연도의 평일은 `None`입니다.
+    pub fn is_holiday(&self, market: CalendarMarket, date: NaiveDate) -> Option<bool> {
+        if is_weekend(date) {
+            return Some(true);
+        }
+        if !self.covers(market, date) {
+            return None;
+        }
+        Some(self.entry(market, date).is_some_and(|e| e.is_holiday()))
+    }
+
+    /// 해당 일자의 정규장 개장/폐장 시각 (현지 시각).
+    ///
+    /// 휴장일이면 `None`, 적재되지 않은 연도의 평일은 정규장 시각을 반환합니다.
+    pub fn trading_hours(
+        &self,
+        market: CalendarMarket,
+        date: NaiveDate,
+    ) -> Option<(NaiveTime, NaiveTime)> {
+        if self.is_holiday(market, date) == Some(true) {
+            return None;
+        }
+        let (open, close) = market.regular_hours();
+        match self.entry(market, date) {
+            Some(entry) => Some((
+                entry.open_time.unwrap_or(open),
+                entry.close_time.unwrap_or(close),
+            )),
+            None => Some((open, close)),
+        }
+    }
+
+    /// `from` 다음 거래일.
+    ///
+    /// 적재되지 않은 연도에 닿거나 30일 안에 거래일이 없으면 `None`입니다.
+    pub fn next_trading_day(&self, market: CalendarMarket, from: NaiveDate) -> Option<NaiveDate> {
+        let mut date = from;
+        for _ in 0..30 {
+            date += Duration::days(1);
+            match self.is_holiday(market, date) {
+                Some(false) => return Some(date),
+                Some(true) => continue,
+                None => return None,
+            }
+        }
+        None
+    }
+
+    /// 한 해의 달력 항목 (일자 순).
+    pub fn entries_for_year(&self, market: CalendarMarket, year: i32) -> Vec<&MarketCalendarEntry> {
+        let mut entries: Vec<_> = self
+            .entries
+            .values()
+            .filter(|e| e.market == market && e.date.year() == year)
+            .collect();
+        entries.sort_by_key(|e| e.date);
+        entries
+    }
+}
+
+/// 미국 시장 조기 폐장일 (13:00 ET).
+///
+/// NYSE 규칙에 따라 독립기념일 전날, 추수감사절 다음 날, 크리스마스 이브가
+/// 평일이면서 대체 휴일이 아닌 경우 조기 폐장합니다.
+pub fn us_early_closes(year: i32) -> Vec<MarketCalendarEntry> {
+    let close = hm(13, 0);
+    let mut entries = Vec::new();
+
+    // 7/4가 토요일이면 7/3(금)이 대체 휴일이므로 조기 폐장 없음
+    if let Some(date) = NaiveDate::from_ymd_opt(year, 7, 3) {
+        if !is_weekend(date) && date.weekday() != Weekday::Fri {
+            entries.push(MarketCalendarEntry::early_close(
+                CalendarMarket::Us,
+                date,
+                close,
+                Some("Independence Day (observed eve)".to_string()),
+            ));
+        }
+    }
+
+    // 11월 넷째 목요일 다음 날
+    if let Some(thanksgiving) = NaiveDate::from_weekday_of_month_opt(year, 11, Weekday::Thu, 4) {
+        entries.push(MarketCalendarEntry::early_close(
+            CalendarMarket::Us,
+            thanksgiving + Duration::days(1),
+            close,
+            Some("Day after Thanksgiving".to_string()),
+        ));
+    }
+
+    // 12/25가 토요일이면 12/24(금)가 대체 휴일
+    if let Some(date) = NaiveDate::from_ymd_opt(year, 12, 24) {
+        if !is_weekend(date) && date.weekday() != Weekday::Fri {
+            entries.push(MarketCalendarEntry::early_close(
+                CalendarMarket::Us,
+                date,
+                close,
+                Some("Christmas Eve".to_string()),
+            ));
+        }
+    }
+
+    entries
+}
+
+/// KRX 연초 첫 거래일 개장 지연 (10:00 KST).
+///
+/// `holidays`는 해당 연도 휴장일 목록입니다 (1/1 신정 포함).
+pub fn krx_first_trading_day(year: i32, holidays: &[NaiveDate]) -> Option<MarketCalendarEntry> {
+    let mut date = NaiveDate::from_ymd_opt(year, 1, 1)?;
+    while is_weekend(date) || holidays.contains(&date) {
+        date += Duration::days(1);
+        if date.month() != 1 {
+            return None;
+        }
+    }
+    Some(MarketCalendarEntry::late_open(
+        CalendarMarket::Kr,
+        date,
+        hm(10, 0),
+        Some("연초 개장일".to_string()),
+    ))
+}
+
+fn is_weekend(date: NaiveDate) -> bool {
+    matches!(date.weekday(), Weekday::Sat | Weekday::Sun)
+}
+
+fn hm(hour: u32, minute: u32) -> NaiveTime {
+    NaiveTime::from_hms_opt(hour, minute, 0).expect("valid time")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, day).unwrap()
+    }
+
+    #[test]
+    fn test_calendar_coverage() {
+        let mut calendar = MarketCalendar::new();
+        let kr = CalendarMarket::Kr;
+
+        // 적재 전: 평일은 모름, 주말은 휴장
+        assert_eq!(calendar.is_holiday(kr, d(2026, 2, 17)), None);
+        assert_eq!(calendar.is_holiday(kr, d(2026, 2, 14)), Some(true));
+
+        calendar.load_year(
+            kr,
+            2026,
+            vec![
+                MarketCalendarEntry::holiday(kr, d(2026, 2, 17), Some("설날".to_string())),
+                // 다른 연도 항목은 무시
+                MarketCalendarEntry::holiday(kr, d(2027, 1, 1), None),
+            ],
+        );
+        assert_eq!(calendar.is_holiday(kr, d(2026, 2, 17)), Some(true));
+        assert_eq!(calendar.is_holiday(kr, d(2026, 2, 19)), Some(false));
+        assert_eq!(calendar.is_holiday(kr, d(2027, 1, 1)), None);
+        assert_eq!(
+            calendar.is_holiday(CalendarMarket::Us, d(2026, 2, 17)),
+            None
+        );
+        assert_eq!(calendar.loaded_years(kr), vec![2026]);
+
+        // 재적재 시 교체
+        calendar.load_year(kr, 2026, Vec::new());
+        assert_eq!(calendar.is_holiday(kr, d(2026, 2, 17)), Some(false));
+    }
+
+    #[test]
+    fn test_trading_hours_and_next_day() {
+        let us = CalendarMarket::Us;
+        let mut calendar = MarketCalendar::new();
+        let mut entries = us_early_closes(2026);
+        entries.push(MarketCalendarEntry::holiday(us, d(2026, 11, 26), None));
+        calendar.load_year(us, 2026, entries);
+
+        assert_eq!(calendar.trading_hours(us, d(2026, 11, 26)), None);
+        assert_eq!(
+            calendar.trading_hours(us, d(2026, 11, 27)),
+            Some((hm(9, 30), hm(13, 0)))
+        );
+        assert_eq!(
+            calendar.trading_hours(us, d(2026, 11, 30)),
+            Some((hm(9, 30), hm(16, 0)))
+        );
+        assert_eq!(
+            calendar.next_trading_day(us, d(2026, 11, 25)),
+            Some(d(2026, 11, 27))
+        );
+        // 적재되지 않은 연도로 넘어가면 모름
+        assert_eq!(calendar.next_trading_day(us, d(2026, 12, 31)), None);
+    }
+
+    #[test]
+    fn test_us_early_closes() {
+        // 2026: 7/3(금)은 대체 휴일, 12/24(목)은 조기 폐장
+        let dates: Vec<NaiveDate> = us_early_closes(2026).iter().map(|e| e.date).collect();
+        assert_eq!(dates, vec![d(2026, 11, 27), d(2026, 12, 24)]);
+
+        // 2025: 7/3(목), 11/28(금), 12/24(수)
+        let dates: Vec<NaiveDate> = us_early_closes(2025).iter().map(|e| e.date).collect();
+        assert_eq!(dates, vec![d(2025, 7, 3), d(2025, 11, 28), d(2025, 12, 24)]);
+    }
+
+    #[test]
+    fn test_krx_first_trading_day() {
+        // 2026-01-01(목) 신정 → 1/2(금) 10:00 개장
+        let entry = krx_first_trading_day(2026, &[d(2026, 1, 1)]).unwrap();
+        assert_eq!(entry.date, d(2026, 1, 2));
+        assert_eq!(entry.kind, MarketDayKind::LateOpen);
+        assert_eq!(entry.open_time, Some(hm(10, 0)));
+
+        // 2028-01-01(토) → 1/3(월)
+        let entry = krx_first_trading_day(2028, &[]).unwrap();
+        assert_eq!(entry.date, d(2028, 1, 3));
+    }
+
+    #[test]
+    fn test_parse_market_and_kind() {
+        assert_eq!("kr".parse::<CalendarMarket>(), Ok(CalendarMarket::Kr));
+        assert_eq!("USA".parse::<CalendarMarket>(), Ok(CalendarMarket::Us));
+        assert!("JP".parse::<CalendarMarket>().is_err());
+        for kind in [
+            MarketDayKind::Holiday,
+            MarketDayKind::EarlyClose,
+            MarketDayKind::LateOpen,
+        ] {
+            assert_eq!(kind.as_str().parse::<MarketDayKind>(), Ok(kind));
+        }
+    }
+}
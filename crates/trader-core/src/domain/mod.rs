@@ -2,40 +2,80 @@
 
 mod alert;
 mod analytics_provider;
+mod broker_downtime;
 mod calculations;
+mod cash_yield;
+mod conditional_order;
 mod context;
+mod crypto_metrics;
+mod custom_index;
+mod earnings;
+mod etf;
 mod exchange_provider;
+mod futures;
+mod investor_flow;
 mod macro_environment;
+mod macro_regime;
 mod market_breadth;
+mod market_calendar;
 mod market_data;
 mod market_regime;
+mod option;
 mod order;
+mod orderbook_metrics;
 mod position;
 mod route_state;
 mod schema;
 mod signal;
+mod signal_calibration;
 mod statistics;
+mod stream_status;
+mod symbol_risk;
+mod synthetic;
 mod tick_size;
+mod timeframe_alignment;
 mod trade;
+mod trading_cost;
+mod trading_status;
 mod trigger;
 mod watchlist;
 
 pub use alert::*;
 pub use analytics_provider::*;
+pub use broker_downtime::*;
 pub use calculations::*;
+pub use cash_yield::*;
+pub use conditional_order::*;
 pub use context::*;
+pub use crypto_metrics::*;
+pub use custom_index::*;
+pub use earnings::*;
+pub use etf::*;
 pub use exchange_provider::*;
+pub use futures::*;
+pub use investor_flow::*;
 pub use macro_environment::*;
+pub use macro_regime::*;
 pub use market_breadth::*;
+pub use market_calendar::*;
 pub use market_data::*;
 pub use market_regime::*;
+pub use option::*;
 pub use order::*;
+pub use orderbook_metrics::*;
 pub use position::*;
 pub use route_state::*;
 pub use schema::*;
 pub use signal::*;
+pub use signal_calibration::*;
 pub use statistics::*;
+pub use stream_status::*;
+pub use symbol_risk::*;
+pub use synthetic::*;
 pub use tick_size::*;
+pub use timeframe_alignment::*;
 pub use trade::*;
+pub use trading_cost::*;
+pub use trading_status::*;
 pub use trigger::*;
 pub use watchlist::*;
@@ -0,0 +1,325 @@
+//! 옵션 계약, 그릭스, 옵션 체인.
+//!
+//! KOSPI200 옵션 등 지수/주식 옵션의 계약 정보(행사가, 만기, 승수)와
+//! 시세·그릭스를 표현합니다. 커버드콜/칼라 전략은 `OptionChain`에서
+//! 행사가 기준으로 계약을 골라 사용합니다.
+
+use chrono::{Datelike, NaiveDate, Weekday};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// KOSPI200 옵션 거래승수 (1포인트당 원).
+pub const KOSPI200_OPTION_MULTIPLIER: Decimal = Decimal::from_parts(250_000, 0, 0, false, 0);
+
+/// 옵션 유형.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OptionType {
+    /// 콜 (매수 권리)
+    Call,
+    /// 풋 (매도 권리)
+    Put,
+}
+
+impl fmt::Display for OptionType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Call => write!(f, "call"),
+            Self::Put => write!(f, "put"),
+        }
+    }
+}
+
+/// 옵션 그릭스 및 내재변동성.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct OptionGreeks {
+    /// 델타
+    pub delta: f64,
+    /// 감마
+    pub gamma: f64,
+    /// 세타 (1일 기준)
+    pub theta: f64,
+    /// 베가 (변동성 1%p 기준)
+    pub vega: f64,
+    /// 로
+    pub rho: f64,
+    /// 내재변동성 (%)
+    pub implied_volatility: Option<f64>,
+}
+
+/// 옵션 계약 명세.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OptionContract {
+    /// 종목코드 (예: KOSPI200 옵션 단축코드 "201W9360")
+    pub code: String,
+    /// 기초자산 (예: "KOSPI200")
+    pub underlying: String,
+    /// 옵션 유형
+    pub option_type: OptionType,
+    /// 행사가
+    pub strike: Decimal,
+    /// 만기일 (최종거래일)
+    pub expiry: NaiveDate,
+    /// 거래승수
+    pub multiplier: Decimal,
+}
+
+impl OptionContract {
+    /// 기초자산 가격 기준 내재가치 (포인트 단위, 거래승수 미적용).
+    pub fn intrinsic_value(&self, spot: Decimal) -> Decimal {
+        let value = match self.option_type {
+            OptionType::Call => spot - self.strike,
+            OptionType::Put => self.strike - spot,
+        };
+        value.max(Decimal::ZERO)
+    }
+
+    /// 외가격(OTM) 여부.
+    pub fn is_out_of_the_money(&self, spot: Decimal) -> bool {
+        match self.option_type {
+            OptionType::Call => self.strike > spot,
+            OptionType::Put => self.strike < spot,
+        }
+    }
+
+    /// 기준일로부터 만기까지 남은 일수 (만기 경과 시 0).
+    pub fn days_to_expiry(&self, today: NaiveDate) -> i64 {
+        (self.expiry - today).num_days().max(0)
+    }
+}
+
+/// 옵션 시세.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OptionQuote {
+    /// 계약 명세
+    pub contract: OptionContract,
+    /// 현재가
+    pub price: Decimal,
+    /// 매수호가
+    pub bid: Decimal,
+    /// 매도호가
+    pub ask: Decimal,
+    /// 누적 거래량
+    pub volume: Decimal,
+    /// 미결제약정
+    pub open_interest: Decimal,
+    /// 그릭스
+    pub greeks: OptionGreeks,
+}
+
+impl OptionQuote {
+    /// 호가 중간값 (한쪽 호가가 없으면 현재가).
+    pub fn mid_price(&self) -> Decimal {
+        if self.bid > Decimal::ZERO && self.ask > Decimal::ZERO {
+            (self.bid + self.ask) / Decimal::TWO
+        } else {
+            self.price
+        }
+    }
+}
+
+/// 단일 만기의 옵션 체인.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OptionChain {
+    /// 기초자산
+    pub underlying: String,
+    /// 만기일
+    pub expiry: NaiveDate,
+    /// 콜/풋 시세 (행사가 오름차순)
+    pub quotes: Vec<OptionQuote>,
+}
+
+impl OptionChain {
+    /// 옵션 체인 생성 (행사가 오름차순, 같은 행사가는 콜 먼저 정렬).
+    pub fn new(underlying: impl Into<String>, expiry: NaiveDate, quotes: Vec<OptionQuote>) -> Self {
+        let mut quotes = quotes;
+        quotes.sort_by(|a, b| {
+            a.contract.strike.cmp(&b.contract.strike).then_with(|| {
+                (a.contract.option_type == OptionType::Put)
+                    .cmp(&(b.contract.option_type == OptionType::Put))
+            })
+        });
+        Self {
+            underlying: underlying.into(),
+            expiry,
+            quotes,
+        }
+    }
+
+    /// 콜 시세.
+    pub fn calls(&self) -> impl Iterator<Item = &OptionQuote> {
+        self.quotes_of(OptionType::Call)
+    }
+
+    /// 풋 시세.
+    pub fn puts(&self) -> impl Iterator<Item = &OptionQuote> {
+        self.quotes_of(OptionType::Put)
+    }
+
+    /// 행사가 목록 (중복 제거, 오름차순).
+    pub fn strikes(&self) -> Vec<Decimal> {
+        let mut strikes: Vec<Decimal> = self.quotes.iter().map(|q| q.contract.strike).collect();
+        strikes.dedup();
+        strikes
+    }
+
+    /// 특정 행사가의 시세.
+    pub fn quote(&self, option_type: OptionType, strike: Decimal) -> Option<&OptionQuote> {
+        self.quotes_of(option_type)
+            .find(|q| q.contract.strike == strike)
+    }
+
+    /// 기초자산 가격에 가장 가까운 행사가 (등가격, ATM).
+    pub fn at_the_money_strike(&self, spot: Decimal) -> Option<Decimal> {
+        self.strikes()
+            .into_iter()
+            .min_by_key(|strike| (*strike - spot).abs())
+    }
+
+    /// 기초자산 가격 대비 `offset_pct`(%) 떨어진 행사가에 가장 가까운 외가격 옵션.
+    ///
+    /// 커버드콜은 OTM 콜, 칼라의 보호 풋은 OTM 풋을 고를 때 사용합니다.
+    /// 콜은 spot × (1 + offset), 풋은 spot × (1 - offset)을 목표 행사가로 합니다.
+    pub fn out_of_the_money(
+        &self,
+        option_type: OptionType,
+        spot: Decimal,
+        offset_pct: Decimal,
+    ) -> Option<&OptionQuote> {
+        let offset = spot * offset_pct / Decimal::ONE_HUNDRED;
+        let target = match option_type {
+            OptionType::Call => spot + offset,
+            OptionType::Put => spot - offset,
+        };
+        self.quotes_of(option_type)
+            .filter(|q| q.contract.is_out_of_the_money(spot))
+            .min_by_key(|q| (q.contract.strike - target).abs())
+    }
+
+    fn quotes_of(&self, option_type: OptionType) -> impl Iterator<Item = &OptionQuote> {
+        self.quotes
+            .iter()
+            .filter(move |q| q.contract.option_type == option_type)
+    }
+}
+
+/// KOSPI200 월물 옵션의 최종거래일 (해당 월 두 번째 목요일).
+///
+/// 휴장일이면 실제 최종거래일은 직전 영업일로 앞당겨지며, 이 함수는 이를 반영하지 않습니다.
+pub fn kospi200_monthly_expiry(year: i32, month: u32) -> Option<NaiveDate> {
+    NaiveDate::from_weekday_of_month_opt(year, month, Weekday::Thu, 2)
+}
+
+/// 만기일로부터 KIS/KRX 월물 코드 ("YYYYMM").
+pub fn option_maturity_month(expiry: NaiveDate) -> String {
+    format!("{:04}{:02}", expiry.year(), expiry.month())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn expiry() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 3, 12).unwrap()
+    }
+
+    fn quote(option_type: OptionType, strike: Decimal, price: Decimal) -> OptionQuote {
+        OptionQuote {
+            contract: OptionContract {
+                code: format!("{}{}", option_type, strike),
+                underlying: "KOSPI200".to_string(),
+                option_type,
+                strike,
+                expiry: expiry(),
+                multiplier: KOSPI200_OPTION_MULTIPLIER,
+            },
+            price,
+            bid: price - dec!(0.01),
+            ask: price + dec!(0.01),
+            volume: dec!(1000),
+            open_interest: dec!(5000),
+            greeks: OptionGreeks::default(),
+        }
+    }
+
+    fn chain() -> OptionChain {
+        OptionChain::new(
+            "KOSPI200",
+            expiry(),
+            vec![
+                quote(OptionType::Put, dec!(350), dec!(1.20)),
+                quote(OptionType::Call, dec!(360), dec!(3.10)),
+                quote(OptionType::Call, dec!(350), dec!(7.50)),
+                quote(OptionType::Put, dec!(340), dec!(0.45)),
+                quote(OptionType::Call, dec!(370), dec!(0.95)),
+                quote(OptionType::Put, dec!(360), dec!(4.60)),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_option_contract() {
+        let call = quote(OptionType::Call, dec!(360), dec!(3.10)).contract;
+        assert_eq!(call.intrinsic_value(dec!(365)), dec!(5));
+        assert_eq!(call.intrinsic_value(dec!(355)), Decimal::ZERO);
+        assert!(call.is_out_of_the_money(dec!(355)));
+        assert_eq!(
+            call.days_to_expiry(NaiveDate::from_ymd_opt(2026, 3, 2).unwrap()),
+            10
+        );
+        assert_eq!(
+            call.days_to_expiry(NaiveDate::from_ymd_opt(2026, 4, 1).unwrap()),
+            0
+        );
+        assert_eq!(KOSPI200_OPTION_MULTIPLIER, dec!(250000));
+
+        let put = quote(OptionType::Put, dec!(340), dec!(0.45));
+        assert_eq!(put.contract.intrinsic_value(dec!(330)), dec!(10));
+        assert_eq!(put.mid_price(), dec!(0.45));
+    }
+
+    #[test]
+    fn test_option_chain_selection() {
+        let chain = chain();
+        assert_eq!(
+            chain.strikes(),
+            vec![dec!(340), dec!(350), dec!(360), dec!(370)]
+        );
+        assert_eq!(chain.calls().count(), 3);
+        assert_eq!(chain.puts().count(), 3);
+        assert_eq!(chain.quotes[1].contract.option_type, OptionType::Call);
+
+        assert_eq!(chain.at_the_money_strike(dec!(357)), Some(dec!(360)));
+        assert_eq!(
+            chain.quote(OptionType::Put, dec!(350)).map(|q| q.price),
+            Some(dec!(1.20))
+        );
+
+        // 커버드콜: spot 352 기준 +3% (362.56) 근처 OTM 콜
+        let call = chain
+            .out_of_the_money(OptionType::Call, dec!(352), dec!(3))
+            .unwrap();
+        assert_eq!(call.contract.strike, dec!(360));
+        // 칼라 보호 풋: -3% (341.44) 근처 OTM 풋
+        let put = chain
+            .out_of_the_money(OptionType::Put, dec!(352), dec!(3))
+            .unwrap();
+        assert_eq!(put.contract.strike, dec!(340));
+        // OTM 후보가 없으면 None
+        assert!(chain
+            .out_of_the_money(OptionType::Put, dec!(330), dec!(3))
+            .is_none());
+    }
+
+    #[test]
+    fn test_kospi200_monthly_expiry() {
+        assert_eq!(kospi200_monthly_expiry(2026, 3), Some(expiry()));
+        assert_eq!(
+            kospi200_monthly_expiry(2026, 10),
+            NaiveDate::from_ymd_opt(2026, 10, 8)
+        );
+        assert_eq!(option_maturity_month(expiry()), "202603");
+    }
+}
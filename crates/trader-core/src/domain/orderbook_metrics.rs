@@ -0,0 +1,170 @@
+//! OrderBookMetrics - 호가창 미시구조 지표.
+//!
+//! KIS/Binance 호가 스냅샷에서 체결 타이밍 판단에 쓰이는 파생 지표를 계산합니다.
+//!
+//! | 지표 | 계산 |
+//! |------|------|
+//! | 호가 불균형 | (매수 잔량 - 매도 잔량) / (매수 잔량 + 매도 잔량), 상위 N 레벨, -1 ~ +1 |
+//! | 마이크로프라이스 | (매도1 × 매수1 잔량 + 매수1 × 매도1 잔량) / (매수1 잔량 + 매도1 잔량) |
+//! | 깊이 가중 스프레드 | 상위 N 레벨 매도 VWAP - 매수 VWAP (중간가 대비 bp 병기) |
+//!
+//! 불균형이 양수이고 마이크로프라이스가 중간가보다 높으면 단기 상승 압력으로 해석합니다.
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use super::market_data::{OrderBook, OrderBookLevel};
+
+/// 지표 계산에 사용하는 기본 호가 레벨 수
+pub const DEFAULT_METRICS_DEPTH: usize = 5;
+
+/// 호가창 파생 지표.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OrderBookMetrics {
+    /// 거래 심볼 (ticker)
+    pub ticker: String,
+
+    /// 계산에 사용한 호가 레벨 수 (매수/매도 중 적은 쪽 기준)
+    pub depth_levels: usize,
+
+    /// 중간 가격 ((매수1 + 매도1) / 2)
+    pub mid_price: Decimal,
+
+    /// 잔량 가중 마이크로프라이스
+    pub microprice: Decimal,
+
+    /// 상위 N 레벨 호가 불균형 (-1 ~ +1, 양수 = 매수 우위)
+    pub imbalance: Decimal,
+
+    /// 상위 N 레벨 매수 잔량 합계
+    pub bid_depth: Decimal,
+
+    /// 상위 N 레벨 매도 잔량 합계
+    pub ask_depth: Decimal,
+
+    /// 깊이 가중 스프레드 (매도 VWAP - 매수 VWAP, 가격 단위)
+    pub depth_weighted_spread: Decimal,
+
+    /// 깊이 가중 스프레드 (중간가 대비, bp)
+    pub depth_weighted_spread_bps: Decimal,
+
+    /// 호가 스냅샷 시각
+    pub timestamp: DateTime<Utc>,
+}
+
+impl OrderBookMetrics {
+    /// 호가 스냅샷에서 상위 `depth` 레벨 기준 지표를 계산합니다.
+    ///
+    /// 매수/매도 한쪽이 비어 있거나 최우선 잔량이 0이면 None을 반환합니다.
+    pub fn from_orderbook(orderbook: &OrderBook, depth: usize) -> Option<Self> {
+        let depth = depth.max(1);
+        let bids = &orderbook.bids[..orderbook.bids.len().min(depth)];
+        let asks = &orderbook.asks[..orderbook.asks.len().min(depth)];
+
+        let best_bid = bids.first()?;
+        let best_ask = asks.first()?;
+        let top_quantity = best_bid.quantity + best_ask.quantity;
+        if top_quantity <= Decimal::ZERO {
+            return None;
+        }
+
+        let mid_price = (best_bid.price + best_ask.price) / Decimal::from(2);
+        let microprice = (best_ask.price * best_bid.quantity + best_bid.price * best_ask.quantity)
+            / top_quantity;
+
+        let bid_depth: Decimal = bids.iter().map(|l| l.quantity).sum();
+        let ask_depth: Decimal = asks.iter().map(|l| l.quantity).sum();
+        let total_depth = bid_depth + ask_depth;
+        let imbalance = if total_depth.is_zero() {
+            Decimal::ZERO
+        } else {
+            (bid_depth - ask_depth) / total_depth
+        };
+
+        let depth_weighted_spread = match (vwap(asks), vwap(bids)) {
+            (Some(ask_vwap), Some(bid_vwap)) => ask_vwap - bid_vwap,
+            _ => best_ask.price - best_bid.price,
+        };
+        let depth_weighted_spread_bps = if mid_price.is_zero() {
+            Decimal::ZERO
+        } else {
+            depth_weighted_spread / mid_price * Decimal::from(10_000)
+        };
+
+        Some(Self {
+            ticker: orderbook.ticker.clone(),
+            depth_levels: bids.len().min(asks.len()),
+            mid_price,
+            microprice,
+            imbalance,
+            bid_depth,
+            ask_depth,
+            depth_weighted_spread,
+            depth_weighted_spread_bps,
+            timestamp: orderbook.timestamp,
+        })
+    }
+
+    /// 마이크로프라이스와 중간가의 차이 (양수 = 매수 압력).
+    pub fn microprice_skew(&self) -> Decimal {
+        self.microprice - self.mid_price
+    }
+}
+
+/// 호가 레벨들의 잔량 가중 평균 가격.
+fn vwap(levels: &[OrderBookLevel]) -> Option<Decimal> {
+    let quantity: Decimal = levels.iter().map(|l| l.quantity).sum();
+    if quantity <= Decimal::ZERO {
+        return None;
+    }
+    Some(levels.iter().map(|l| l.price * l.quantity).sum::<Decimal>() / quantity)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn level(price: Decimal, quantity: Decimal) -> OrderBookLevel {
+        OrderBookLevel { price, quantity }
+    }
+
+    fn orderbook() -> OrderBook {
+        OrderBook {
+            ticker: "005930".to_string(),
+            bids: vec![level(dec!(100), dec!(300)), level(dec!(99), dec!(100))],
+            asks: vec![level(dec!(101), dec!(100)), level(dec!(102), dec!(100))],
+            timestamp: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_orderbook_metrics() {
+        let metrics =
+            OrderBookMetrics::from_orderbook(&orderbook(), DEFAULT_METRICS_DEPTH).unwrap();
+
+        assert_eq!(metrics.depth_levels, 2);
+        assert_eq!(metrics.mid_price, dec!(100.5));
+        // (101 × 300 + 100 × 100) / 400
+        assert_eq!(metrics.microprice, dec!(100.75));
+        assert_eq!(metrics.microprice_skew(), dec!(0.25));
+        // (400 - 200) / 600
+        assert_eq!(metrics.imbalance.round_dp(4), dec!(0.3333));
+        // 매도 VWAP 101.5 - 매수 VWAP 99.75
+        assert_eq!(metrics.depth_weighted_spread, dec!(1.75));
+        assert_eq!(metrics.depth_weighted_spread_bps.round_dp(2), dec!(174.13));
+    }
+
+    #[test]
+    fn test_orderbook_metrics_depth_and_empty() {
+        let top = OrderBookMetrics::from_orderbook(&orderbook(), 1).unwrap();
+        assert_eq!(top.depth_levels, 1);
+        assert_eq!(top.imbalance, dec!(0.5));
+        assert_eq!(top.depth_weighted_spread, dec!(1));
+
+        let mut one_sided = orderbook();
+        one_sided.asks.clear();
+        assert!(OrderBookMetrics::from_orderbook(&one_sided, 5).is_none());
+    }
+}
@@ -2,6 +2,7 @@
 //!
 //! 이 모듈은 전략이 생성하는 매매 신호 관련 타입을 정의합니다:
 //! - `SignalType` - 신호 유형 (진입, 청산 등)
+//! - `ScaleOut` - 부분 청산(스케일 아웃) 지시
 //! - `Signal` - 매매 신호 엔티티
 //! - `SignalValidation` - 신호 검증 결과
 
@@ -44,6 +45,48 @@ impl std::fmt::Display for SignalType {
     }
 }
 
+/// 부분 청산(스케일 아웃) 지시.
+///
+/// `ReducePosition` 신호에 첨부하여 청산할 수량을 지정합니다.
+/// 지시가 없는 `ReducePosition` 신호의 청산 수량은 실행기 정책을 따릅니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "mode", content = "value", rename_all = "snake_case")]
+pub enum ScaleOut {
+    /// 보유 수량 대비 비율만큼 청산 (0.0 ~ 1.0)
+    Ratio(Decimal),
+    /// 포트폴리오 가치 대비 목표 비중까지 청산 (0.0 ~ 1.0)
+    TargetWeight(Decimal),
+}
+
+impl ScaleOut {
+    /// 청산할 수량을 계산합니다.
+    ///
+    /// 결과는 0 이상 보유 수량 이하로 제한됩니다.
+    /// `portfolio_value`는 목표 비중 모드에서만 사용하며, 가격이 0 이하이면 0을 반환합니다.
+    pub fn close_quantity(
+        &self,
+        held: Decimal,
+        price: Decimal,
+        portfolio_value: Decimal,
+    ) -> Decimal {
+        if held <= Decimal::ZERO {
+            return Decimal::ZERO;
+        }
+
+        let quantity = match *self {
+            ScaleOut::Ratio(ratio) => held * ratio,
+            ScaleOut::TargetWeight(weight) => {
+                if price <= Decimal::ZERO {
+                    return Decimal::ZERO;
+                }
+                let target = portfolio_value * weight.max(Decimal::ZERO) / price;
+                held - target
+            }
+        };
+        quantity.clamp(Decimal::ZERO, held)
+    }
+}
+
 /// 전략이 생성한 트레이딩 신호.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Signal {
@@ -73,6 +116,9 @@ pub struct Signal {
     /// 추가 메타데이터
     #[serde(default)]
     pub metadata: HashMap<String, serde_json::Value>,
+    /// 부분 청산 지시 (`ReducePosition` 신호)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scale_out: Option<ScaleOut>,
 }
 
 impl Signal {
@@ -95,6 +141,7 @@ impl Signal {
             take_profit: None,
             timestamp: Utc::now(),
             metadata: HashMap::new(),
+            scale_out: None,
         }
     }
 
@@ -108,6 +155,16 @@ impl Signal {
         Self::new(strategy_id, ticker, side, SignalType::Exit)
     }
 
+    /// 부분 청산 신호를 생성합니다.
+    pub fn scale_out(
+        strategy_id: impl Into<String>,
+        ticker: String,
+        side: Side,
+        scale_out: ScaleOut,
+    ) -> Self {
+        Self::new(strategy_id, ticker, side, SignalType::ReducePosition).with_scale_out(scale_out)
+    }
+
     /// 신호 강도를 설정합니다.
     pub fn with_strength(mut self, strength: f64) -> Self {
         self.strength = strength.clamp(0.0, 1.0);
@@ -133,6 +190,12 @@ impl Signal {
         self
     }
 
+    /// 부분 청산 지시를 설정합니다.
+    pub fn with_scale_out(mut self, scale_out: ScaleOut) -> Self {
+        self.scale_out = Some(scale_out);
+        self
+    }
+
     /// 강한 신호인지 확인합니다 (강도 >= 0.7).
     pub fn is_strong(&self) -> bool {
         self.strength >= 0.7
@@ -456,6 +519,41 @@ mod tests {
         assert_eq!(signal.strength, 1.0);
     }
 
+    #[test]
+    fn test_scale_out_close_quantity() {
+        use rust_decimal_macros::dec;
+
+        let signal = Signal::scale_out(
+            "magic_split",
+            "005930".to_string(),
+            Side::Sell,
+            ScaleOut::Ratio(dec!(0.25)),
+        );
+        assert_eq!(signal.signal_type, SignalType::ReducePosition);
+
+        let ratio = signal.scale_out.unwrap();
+        assert_eq!(
+            ratio.close_quantity(dec!(100), dec!(70000), dec!(0)),
+            dec!(25)
+        );
+        assert_eq!(
+            ScaleOut::Ratio(dec!(1.5)).close_quantity(dec!(100), dec!(1), dec!(0)),
+            dec!(100)
+        );
+
+        // 포트폴리오 1,000만원 중 10% 목표 → 10주(@100,000) 유지, 20주 청산
+        let weight = ScaleOut::TargetWeight(dec!(0.1));
+        assert_eq!(
+            weight.close_quantity(dec!(30), dec!(100000), dec!(10000000)),
+            dec!(20)
+        );
+        // 이미 목표 비중 이하이면 청산 없음
+        assert_eq!(
+            weight.close_quantity(dec!(5), dec!(100000), dec!(10000000)),
+            dec!(0)
+        );
+    }
+
     #[test]
     fn test_signal_marker_creation() {
         use rust_decimal_macros::dec;
@@ -0,0 +1,427 @@
+//! 신호 신뢰도 보정 (Calibration).
+//!
+//! 전략이 붙이는 `Signal::strength`는 전략마다 기준이 달라 그대로 확률로 쓰기 어렵습니다.
+//! 과거 신호의 적중 여부로 전략별 보정 모델(등위 회귀 또는 Platt 스케일링)을 학습해
+//! 원시 신뢰도를 경험적 적중 확률로 변환하고, 포지션 사이징은 이 확률을 사용합니다.
+//!
+//! 보정 모델이 없는 전략은 원시 신뢰도를 그대로 확률로 사용합니다.
+
+use super::Signal;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// 보정 모델 학습에 필요한 최소 표본 수.
+pub const MIN_CALIBRATION_SAMPLES: usize = 30;
+
+/// Platt 스케일링 Newton 반복 최대 횟수.
+const PLATT_MAX_ITERATIONS: usize = 100;
+/// Platt 스케일링 수렴 판정 기준 (파라미터 변화량).
+const PLATT_TOLERANCE: f64 = 1e-9;
+
+/// 보정 방법.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum CalibrationMethod {
+    /// 등위 회귀 (Pool Adjacent Violators, 단조 증가 계단 함수)
+    #[default]
+    Isotonic,
+    /// Platt 스케일링 (로지스틱 회귀)
+    Platt,
+}
+
+/// 과거 신호 1건의 신뢰도와 적중 여부.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CalibrationSample {
+    /// 원시 신뢰도 (`Signal::strength`, 0.0 ~ 1.0)
+    pub confidence: f64,
+    /// 적중 여부 (예: 해당 거래 손익 > 0)
+    pub hit: bool,
+}
+
+impl CalibrationSample {
+    /// 새 표본 생성.
+    pub fn new(confidence: f64, hit: bool) -> Self {
+        Self { confidence, hit }
+    }
+}
+
+/// 학습된 보정 모델.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "method", rename_all = "snake_case")]
+pub enum CalibrationModel {
+    /// 등위 회귀: 블록별 평균 신뢰도(오름차순)와 적중률, 사이 구간은 선형 보간
+    Isotonic {
+        confidences: Vec<f64>,
+        probabilities: Vec<f64>,
+    },
+    /// Platt 스케일링: p = 1 / (1 + exp(a × 신뢰도 + b))
+    Platt { a: f64, b: f64 },
+}
+
+impl CalibrationModel {
+    /// 표본으로 보정 모델 학습 (표본이 비어 있으면 None).
+    pub fn fit(method: CalibrationMethod, samples: &[CalibrationSample]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+        Some(match method {
+            CalibrationMethod::Isotonic => fit_isotonic(samples),
+            CalibrationMethod::Platt => fit_platt(samples),
+        })
+    }
+
+    /// 원시 신뢰도를 보정된 적중 확률로 변환 (0.0 ~ 1.0).
+    pub fn probability(&self, confidence: f64) -> f64 {
+        let p = match self {
+            Self::Isotonic {
+                confidences,
+                probabilities,
+            } => interpolate(confidences, probabilities, confidence),
+            Self::Platt { a, b } => 1.0 / (1.0 + (a * confidence + b).exp()),
+        };
+        p.clamp(0.0, 1.0)
+    }
+}
+
+/// 전략별 보정 결과.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StrategyCalibration {
+    /// 전략 ID
+    pub strategy_id: String,
+    /// 학습 표본 수
+    pub sample_count: usize,
+    /// 전체 적중률
+    pub base_rate: f64,
+    /// 보정 모델
+    pub model: CalibrationModel,
+}
+
+impl StrategyCalibration {
+    /// 전략 표본으로 보정 학습.
+    ///
+    /// 표본이 `MIN_CALIBRATION_SAMPLES`보다 적거나 적중/실패 중 한쪽만 있으면 None입니다.
+    pub fn fit(
+        strategy_id: impl Into<String>,
+        method: CalibrationMethod,
+        samples: &[CalibrationSample],
+    ) -> Option<Self> {
+        if samples.len() < MIN_CALIBRATION_SAMPLES {
+            return None;
+        }
+        let hits = samples.iter().filter(|s| s.hit).count();
+        if hits == 0 || hits == samples.len() {
+            return None;
+        }
+
+        Some(Self {
+            strategy_id: strategy_id.into(),
+            sample_count: samples.len(),
+            base_rate: hits as f64 / samples.len() as f64,
+            model: CalibrationModel::fit(method, samples)?,
+        })
+    }
+
+    /// 원시 신뢰도를 보정된 적중 확률로 변환.
+    pub fn probability(&self, confidence: f64) -> f64 {
+        self.model.probability(confidence)
+    }
+}
+
+/// 전략별 신호 신뢰도 보정기.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SignalCalibrator {
+    calibrations: HashMap<String, StrategyCalibration>,
+}
+
+impl SignalCalibrator {
+    /// 빈 보정기 생성 (모든 전략이 원시 신뢰도 사용).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// (전략 ID, 표본) 목록으로 전략별 보정 학습.
+    ///
+    /// 표본이 부족한 전략은 보정 없이 원시 신뢰도를 사용합니다.
+    pub fn fit(
+        method: CalibrationMethod,
+        samples: impl IntoIterator<Item = (String, CalibrationSample)>,
+    ) -> Self {
+        let mut by_strategy: HashMap<String, Vec<CalibrationSample>> = HashMap::new();
+        for (strategy_id, sample) in samples {
+            by_strategy.entry(strategy_id).or_default().push(sample);
+        }
+
+        let mut calibrator = Self::new();
+        for (strategy_id, samples) in by_strategy {
+            if let Some(calibration) = StrategyCalibration::fit(strategy_id, method, &samples) {
+                calibrator.insert(calibration);
+            }
+        }
+        calibrator
+    }
+
+    /// 전략 보정 결과 등록 (같은 전략은 교체).
+    pub fn insert(&mut self, calibration: StrategyCalibration) {
+        self.calibrations
+            .insert(calibration.strategy_id.clone(), calibration);
+    }
+
+    /// 전략 보정 결과 조회.
+    pub fn get(&self, strategy_id: &str) -> Option<&StrategyCalibration> {
+        self.calibrations.get(strategy_id)
+    }
+
+    /// 보정된 전략 수.
+    pub fn len(&self) -> usize {
+        self.calibrations.len()
+    }
+
+    /// 보정된 전략이 없는지 여부.
+    pub fn is_empty(&self) -> bool {
+        self.calibrations.is_empty()
+    }
+
+    /// 전략의 원시 신뢰도를 적중 확률로 변환 (보정 모델이 없으면 원시 신뢰도).
+    pub fn probability(&self, strategy_id: &str, confidence: f64) -> f64 {
+        match self.get(strategy_id) {
+            Some(calibration) => calibration.probability(confidence),
+            None => confidence.clamp(0.0, 1.0),
+        }
+    }
+
+    /// 신호의 보정된 적중 확률.
+    pub fn calibrate(&self, signal: &Signal) -> f64 {
+        self.probability(&signal.strategy_id, signal.strength)
+    }
+}
+
+/// Pool Adjacent Violators 알고리즘으로 등위 회귀 학습.
+fn fit_isotonic(samples: &[CalibrationSample]) -> CalibrationModel {
+    let mut sorted: Vec<(f64, f64)> = samples
+        .iter()
+        .map(|s| (s.confidence, if s.hit { 1.0 } else { 0.0 }))
+        .collect();
+    sorted.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    // 블록: (신뢰도 합, 적중 합, 표본 수)
+    let mut blocks: Vec<(f64, f64, f64)> = Vec::with_capacity(sorted.len());
+    for (x, y) in sorted {
+        blocks.push((x, y, 1.0));
+        while blocks.len() > 1 {
+            let (x2, y2, w2) = blocks[blocks.len() - 1];
+            let (x1, y1, w1) = blocks[blocks.len() - 2];
+            if y1 / w1 < y2 / w2 {
+                break;
+            }
+            blocks.truncate(blocks.len() - 2);
+            blocks.push((x1 + x2, y1 + y2, w1 + w2));
+        }
+    }
+
+    CalibrationModel::Isotonic {
+        confidences: blocks.iter().map(|(x, _, w)| x / w).collect(),
+        probabilities: blocks.iter().map(|(_, y, w)| y / w).collect(),
+    }
+}
+
+/// Newton 방법으로 Platt 스케일링 학습.
+///
+/// 과적합을 줄이기 위해 Platt(1999)의 사전 확률 보정 목표값을 사용합니다.
+fn fit_platt(samples: &[CalibrationSample]) -> CalibrationModel {
+    let positives = samples.iter().filter(|s| s.hit).count() as f64;
+    let negatives = samples.len() as f64 - positives;
+    let hi = (positives + 1.0) / (positives + 2.0);
+    let lo = 1.0 / (negatives + 2.0);
+    let data: Vec<(f64, f64)> = samples
+        .iter()
+        .map(|s| (s.confidence, if s.hit { hi } else { lo }))
+        .collect();
+
+    let loss = |a: f64, b: f64| -> f64 {
+        data.iter()
+            .map(|(f, t)| {
+                let z = a * f + b;
+                // -[t ln p + (1-t) ln(1-p)], p = 1/(1+e^z)
+                t * ln_1p_exp(z) + (1.0 - t) * ln_1p_exp(-z)
+            })
+            .sum()
+    };
+
+    let mut a = 0.0;
+    let mut b = ((negatives + 1.0) / (positives + 1.0)).ln();
+    let mut current = loss(a, b);
+
+    for _ in 0..PLATT_MAX_ITERATIONS {
+        let (mut g_a, mut g_b, mut h_aa, mut h_ab, mut h_bb) = (0.0, 0.0, 1e-12, 0.0, 1e-12);
+        for (f, t) in &data {
+            let p = 1.0 / (1.0 + (a * f + b).exp());
+            let d = p * (1.0 - p);
+            g_a += (t - p) * f;
+            g_b += t - p;
+            h_aa += d * f * f;
+            h_ab += d * f;
+            h_bb += d;
+        }
+
+        let det = h_aa * h_bb - h_ab * h_ab;
+        if det.abs() < f64::EPSILON {
+            break;
+        }
+        let step_a = (h_bb * g_a - h_ab * g_b) / det;
+        let step_b = (h_aa * g_b - h_ab * g_a) / det;
+
+        // 손실이 줄어들 때까지 스텝 축소
+        let mut scale = 1.0;
+        let mut improved = false;
+        while scale > 1e-10 {
+            let (next_a, next_b) = (a - scale * step_a, b - scale * step_b);
+            let next = loss(next_a, next_b);
+            if next < current + 1e-12 {
+                a = next_a;
+                b = next_b;
+                current = next;
+                improved = true;
+                break;
+            }
+            scale /= 2.0;
+        }
+
+        if !improved || (scale * step_a).abs().max((scale * step_b).abs()) < PLATT_TOLERANCE {
+            break;
+        }
+    }
+
+    CalibrationModel::Platt { a, b }
+}
+
+/// ln(1 + e^z) (수치 안정 버전).
+fn ln_1p_exp(z: f64) -> f64 {
+    if z > 0.0 {
+        z + (-z).exp().ln_1p()
+    } else {
+        z.exp().ln_1p()
+    }
+}
+
+/// 오름차순 기준점 사이를 선형 보간 (범위 밖은 양 끝 값).
+fn interpolate(xs: &[f64], ys: &[f64], x: f64) -> f64 {
+    let (Some(&first), Some(&last)) = (xs.first(), xs.last()) else {
+        return x;
+    };
+    if x <= first {
+        return ys[0];
+    }
+    if x >= last {
+        return ys[ys.len() - 1];
+    }
+
+    let upper = xs.partition_point(|&v| v < x);
+    let (x0, x1) = (xs[upper - 1], xs[upper]);
+    let (y0, y1) = (ys[upper - 1], ys[upper]);
+    if x1 - x0 <= f64::EPSILON {
+        return y1;
+    }
+    y0 + (y1 - y0) * (x - x0) / (x1 - x0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Side, SignalType};
+
+    /// 신뢰도 구간(0.05 ~ 0.95)별 20건, 실제 적중률 0.30 ~ 0.75 (과신 전략).
+    fn overconfident_samples() -> Vec<CalibrationSample> {
+        let mut samples = Vec::new();
+        for bucket in 0..10 {
+            let confidence = bucket as f64 / 10.0 + 0.05;
+            let hits = 6 + bucket;
+            for i in 0..20 {
+                samples.push(CalibrationSample::new(confidence, i < hits));
+            }
+        }
+        samples
+    }
+
+    #[test]
+    fn test_isotonic_calibration() {
+        let calibration =
+            StrategyCalibration::fit("rsi", CalibrationMethod::Isotonic, &overconfident_samples())
+                .unwrap();
+        assert_eq!(calibration.sample_count, 200);
+
+        // 단조 증가
+        let probs: Vec<f64> = (0..=20)
+            .map(|i| calibration.probability(i as f64 / 20.0))
+            .collect();
+        assert!(probs.windows(2).all(|w| w[0] <= w[1] + 1e-12));
+
+        // 고신뢰 신호는 실제로 덜 맞고, 저신뢰 신호는 더 맞음
+        assert!((calibration.probability(0.95) - 0.75).abs() < 1e-9);
+        assert!((calibration.probability(0.05) - 0.3).abs() < 1e-9);
+        // 0.45(0.50)와 0.55(0.55) 사이 보간
+        assert!((calibration.probability(0.5) - 0.525).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_isotonic_pools_violations() {
+        let model = CalibrationModel::fit(
+            CalibrationMethod::Isotonic,
+            &[
+                CalibrationSample::new(0.2, true),
+                CalibrationSample::new(0.4, false),
+                CalibrationSample::new(0.6, false),
+                CalibrationSample::new(0.8, true),
+            ],
+        )
+        .unwrap();
+        // 0.2(적중)와 0.4, 0.6(실패)이 하나의 블록으로 병합
+        let CalibrationModel::Isotonic {
+            confidences,
+            probabilities,
+        } = &model
+        else {
+            panic!("isotonic model expected");
+        };
+        assert_eq!(confidences.len(), 2);
+        assert!((confidences[0] - 0.4).abs() < 1e-9);
+        assert!((probabilities[0] - 1.0 / 3.0).abs() < 1e-9);
+        assert_eq!(model.probability(0.8), 1.0);
+        assert!((model.probability(0.6) - 2.0 / 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_platt_calibration() {
+        let calibration =
+            StrategyCalibration::fit("rsi", CalibrationMethod::Platt, &overconfident_samples())
+                .unwrap();
+        let CalibrationModel::Platt { a, .. } = calibration.model else {
+            panic!("platt model expected");
+        };
+        // 신뢰도가 높을수록 확률 증가 (a < 0)
+        assert!(a < 0.0);
+        assert!((calibration.probability(0.95) - 0.75).abs() < 0.05);
+        assert!((calibration.probability(0.05) - 0.3).abs() < 0.05);
+    }
+
+    #[test]
+    fn test_signal_calibrator() {
+        let samples = overconfident_samples()
+            .into_iter()
+            .map(|s| ("rsi".to_string(), s))
+            .chain((0..10).map(|i| ("grid".to_string(), CalibrationSample::new(0.9, i % 2 == 0))));
+        let calibrator = SignalCalibrator::fit(CalibrationMethod::Isotonic, samples);
+
+        // 표본 부족 전략은 보정하지 않음
+        assert_eq!(calibrator.len(), 1);
+        assert!(calibrator.get("grid").is_none());
+        assert_eq!(calibrator.probability("grid", 0.9), 0.9);
+
+        let signal = Signal::new("rsi", "005930".to_string(), Side::Buy, SignalType::Entry)
+            .with_strength(0.95);
+        assert!((calibrator.calibrate(&signal) - 0.75).abs() < 1e-9);
+
+        // 한쪽 결과만 있으면 학습하지 않음
+        let all_hits = vec![CalibrationSample::new(0.5, true); MIN_CALIBRATION_SAMPLES];
+        assert!(StrategyCalibration::fit("x", CalibrationMethod::Platt, &all_hits).is_none());
+    }
+}
@@ -0,0 +1,138 @@
+//! 실시간 시세 스트림 연결 상태.
+//!
+//! 거래소 WebSocket이 끊겼다가 재연결되는 동안에는 시세가 들어오지 않으므로,
+//! 어그리게이터와 전략이 데이터 공백(gap)을 인지할 수 있도록 연결 상태 변화를 이벤트로 전달합니다.
+//!
+//! - `Reconnecting`: 연결이 끊겨 백오프 후 재연결 대기 중
+//! - `Recovered`: 재연결·재인증 후 기존 구독 복원 완료 (공백 구간 종료)
+//! - `Failed`: 최대 재시도 횟수 초과로 재연결 포기
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+/// 스트림 연결 상태.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StreamState {
+    /// 재연결 대기 중 (데이터 공백 진행 중)
+    Reconnecting,
+    /// 재연결 및 구독 복원 완료
+    Recovered,
+    /// 재연결 포기
+    Failed,
+}
+
+impl std::fmt::Display for StreamState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Reconnecting => write!(f, "reconnecting"),
+            Self::Recovered => write!(f, "recovered"),
+            Self::Failed => write!(f, "failed"),
+        }
+    }
+}
+
+/// 스트림 연결 상태 변경 이벤트.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StreamStatus {
+    /// 스트림 출처 (예: "kis_kr", "kis_us")
+    pub source: String,
+    /// 연결 상태
+    pub state: StreamState,
+    /// 재연결 시도 횟수 (`Recovered`는 복구까지 걸린 시도 횟수)
+    pub attempt: u32,
+    /// 연결이 끊긴 시각 (데이터 공백 시작)
+    pub disconnected_at: Option<DateTime<Utc>>,
+    /// 복원한 구독 수 (`Recovered`에서만 의미 있음)
+    pub resubscribed: usize,
+    /// 끊김/포기 사유
+    pub reason: Option<String>,
+    /// 이벤트 발생 시각
+    pub timestamp: DateTime<Utc>,
+}
+
+impl StreamStatus {
+    fn new(source: impl Into<String>, state: StreamState, attempt: u32) -> Self {
+        Self {
+            source: source.into(),
+            state,
+            attempt,
+            disconnected_at: None,
+            resubscribed: 0,
+            reason: None,
+            timestamp: Utc::now(),
+        }
+    }
+
+    /// 재연결 대기 이벤트.
+    pub fn reconnecting(
+        source: impl Into<String>,
+        attempt: u32,
+        disconnected_at: DateTime<Utc>,
+        reason: impl Into<String>,
+    ) -> Self {
+        Self {
+            disconnected_at: Some(disconnected_at),
+            reason: Some(reason.into()),
+            ..Self::new(source, StreamState::Reconnecting, attempt)
+        }
+    }
+
+    /// 구독 복원 완료 이벤트.
+    pub fn recovered(
+        source: impl Into<String>,
+        attempt: u32,
+        disconnected_at: DateTime<Utc>,
+        resubscribed: usize,
+    ) -> Self {
+        Self {
+            disconnected_at: Some(disconnected_at),
+            resubscribed,
+            ..Self::new(source, StreamState::Recovered, attempt)
+        }
+    }
+
+    /// 재연결 포기 이벤트.
+    pub fn failed(
+        source: impl Into<String>,
+        attempt: u32,
+        disconnected_at: Option<DateTime<Utc>>,
+        reason: impl Into<String>,
+    ) -> Self {
+        Self {
+            disconnected_at,
+            reason: Some(reason.into()),
+            ..Self::new(source, StreamState::Failed, attempt)
+        }
+    }
+
+    /// 데이터 공백 길이 (끊긴 시각 ~ 이벤트 시각).
+    pub fn gap(&self) -> Option<Duration> {
+        self.disconnected_at
+            .map(|since| (self.timestamp - since).max(Duration::zero()))
+    }
+
+    /// 공백 구간이 끝났는지 여부 (이후 시세는 연속).
+    pub fn is_recovered(&self) -> bool {
+        self.state == StreamState::Recovered
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stream_status_gap() {
+        let since = Utc::now() - Duration::seconds(12);
+        let status = StreamStatus::recovered("kis_kr", 3, since, 5);
+        assert!(status.is_recovered());
+        assert_eq!(status.resubscribed, 5);
+        assert!(status.gap().unwrap() >= Duration::seconds(12));
+
+        let failed = StreamStatus::failed("kis_us", 10, None, "연결 거부");
+        assert_eq!(failed.state, StreamState::Failed);
+        assert!(failed.gap().is_none());
+        assert_eq!(failed.reason.as_deref(), Some("연결 거부"));
+    }
+}
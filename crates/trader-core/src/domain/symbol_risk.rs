@@ -0,0 +1,101 @@
+//! SymbolRiskMetrics - 종목별 실현 변동성과 시장 베타.
+//!
+//! 수집기가 매일 일봉 종가로 계산해 `symbol_fundamental`에 저장하는 값입니다.
+//! 스크리닝, 변동성 타깃 포지션 사이징, 베타 헤지가 같은 값을 공유하도록
+//! 전략 컨텍스트에 제공됩니다.
+//!
+//! 변동성은 일간 로그가 아닌 단순 수익률의 표본 표준편차를 연율화(√252)한 퍼센트입니다.
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+/// 실현 변동성 계산 구간 (거래일).
+pub const REALIZED_VOL_WINDOWS: [usize; 3] = [20, 60, 252];
+
+/// 종목별 실현 변동성/베타.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SymbolRiskMetrics {
+    /// 종목 티커
+    pub ticker: String,
+
+    /// 20일 실현 변동성 (연율화, %)
+    pub realized_vol_20d: Option<f64>,
+
+    /// 60일 실현 변동성 (연율화, %)
+    pub realized_vol_60d: Option<f64>,
+
+    /// 252일 실현 변동성 (연율화, %)
+    pub realized_vol_252d: Option<f64>,
+
+    /// 벤치마크 대비 베타 (최근 252거래일)
+    pub beta: Option<f64>,
+
+    /// 베타 기준 벤치마크 티커 (예: "069500", "SPY")
+    pub beta_benchmark: Option<String>,
+
+    /// 계산 시각
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl SymbolRiskMetrics {
+    /// 사이징 기준 변동성 (%).
+    ///
+    /// 60일 값을 우선 사용하고, 없으면 20일, 252일 순으로 대체합니다.
+    pub fn volatility_pct(&self) -> Option<f64> {
+        self.realized_vol_60d
+            .or(self.realized_vol_20d)
+            .or(self.realized_vol_252d)
+    }
+
+    /// 지정 벤치마크 기준 베타.
+    ///
+    /// 저장된 베타의 벤치마크가 다르면 None을 반환합니다.
+    pub fn beta_against(&self, benchmark: &str) -> Option<f64> {
+        match self.beta_benchmark.as_deref() {
+            Some(b) if b == benchmark => self.beta,
+            _ => None,
+        }
+    }
+
+    /// 계산 시각이 `max_age`보다 오래되었는지 여부 (시각이 없으면 true).
+    pub fn is_stale(&self, now: DateTime<Utc>, max_age: Duration) -> bool {
+        self.updated_at.map_or(true, |at| now - at > max_age)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_volatility_pct_fallback() {
+        let mut metrics = SymbolRiskMetrics {
+            ticker: "005930".to_string(),
+            realized_vol_20d: Some(30.0),
+            realized_vol_252d: Some(25.0),
+            ..Default::default()
+        };
+        assert_eq!(metrics.volatility_pct(), Some(30.0));
+
+        metrics.realized_vol_60d = Some(28.0);
+        assert_eq!(metrics.volatility_pct(), Some(28.0));
+    }
+
+    #[test]
+    fn test_beta_against_and_staleness() {
+        let now = Utc::now();
+        let metrics = SymbolRiskMetrics {
+            ticker: "AAPL".to_string(),
+            beta: Some(1.2),
+            beta_benchmark: Some("SPY".to_string()),
+            updated_at: Some(now - Duration::hours(30)),
+            ..Default::default()
+        };
+
+        assert_eq!(metrics.beta_against("SPY"), Some(1.2));
+        assert_eq!(metrics.beta_against("069500"), None);
+        assert!(metrics.is_stale(now, Duration::days(1)));
+        assert!(!metrics.is_stale(now, Duration::days(3)));
+        assert!(SymbolRiskMetrics::default().is_stale(now, Duration::days(3)));
+    }
+}
@@ -0,0 +1,716 @@
+//! 합성 종목 (스프레드/비율).
+//!
+//! 여러 종목 가격의 산술식으로 정의한 합성 종목입니다. `SYN_<코드>` 티커로 캔들을
+//! 요청하면 구성 종목(leg) 캔들을 캔들 시작 시각 기준으로 맞춰 그때그때 합성 캔들을
+//! 계산합니다. 사용자 정의 지수와 달리 OHLCV 테이블에 저장하지 않습니다.
+//!
+//! 지원하는 식:
+//! - 선형 결합: `005930 - 1.5*000660`, `2*A + B - C` (헤지 비율 스프레드, 버터플라이)
+//! - 비율: `KO / PEP`
+//!
+//! `+`, `-`, `/` 연산자는 공백으로 구분해야 합니다 (`BRK-B`, `BTC/USDT` 같은 티커와
+//! 구분하기 위함). 계수는 `1.5*B` 또는 `1.5 * B`로 씁니다.
+//!
+//! 합성 종목 주문은 실행 시점에 [`SyntheticExpression::decompose`]로 leg별 주문으로
+//! 분해됩니다.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use rust_decimal::{Decimal, RoundingStrategy};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::market_data::Kline;
+use crate::domain::order::Side;
+use crate::domain::signal::Signal;
+
+/// 합성 종목 티커 접두사 (`SYN_<코드>`).
+pub const SYNTHETIC_TICKER_PREFIX: &str = "SYN_";
+
+/// 합성 종목 하나에 포함할 수 있는 최대 leg 수.
+pub const MAX_SYNTHETIC_LEGS: usize = 8;
+
+/// 합성 종목 신호의 분해 정보를 담는 신호 메타데이터 키.
+pub const SYNTHETIC_SIGNAL_KEY: &str = "synthetic";
+
+/// 비율 합성 캔들의 소수 자릿수.
+const RATIO_DECIMALS: u32 = 8;
+
+/// 선형 결합의 leg.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SyntheticLeg {
+    /// 종목 티커
+    pub ticker: String,
+    /// 계수 (음수는 매도 leg)
+    pub coefficient: Decimal,
+}
+
+/// 합성 종목 가격식.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SyntheticExpression {
+    /// Σ 계수 × 가격 (예: `A - 1.5*B`)
+    Linear { legs: Vec<SyntheticLeg> },
+    /// 분자 가격 / 분모 가격
+    Ratio {
+        numerator: String,
+        denominator: String,
+    },
+}
+
+/// 합성 종목 주문을 분해한 leg 주문.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SyntheticLegOrder {
+    /// 종목 티커
+    pub ticker: String,
+    /// 주문 방향
+    pub side: Side,
+    /// 주문 수량 (주식은 정수, 암호화폐는 소수 8자리로 내림)
+    pub quantity: Decimal,
+    /// 분해 기준 가격
+    pub price: Decimal,
+}
+
+impl SyntheticExpression {
+    /// 가격식 파싱.
+    ///
+    /// 티커는 대문자로 정규화됩니다.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut tokens: Vec<String> = text
+            .split_whitespace()
+            .flat_map(split_coefficient)
+            .collect();
+        // 맨 앞의 부호는 붙여 써도 연산자로 취급 (`-A`, `-1.5*A`)
+        if let Some(rest) = tokens.first().and_then(|t| t.strip_prefix('-')) {
+            if !rest.is_empty() {
+                let rest = rest.to_string();
+                tokens.splice(0..1, ["-".to_string(), rest]);
+            }
+        }
+        if tokens.is_empty() {
+            return Err("expression is empty".to_string());
+        }
+
+        if tokens.iter().any(|t| t == "/") {
+            return match tokens.as_slice() {
+                [numerator, slash, denominator] if slash == "/" => Ok(Self::Ratio {
+                    numerator: parse_ticker(numerator)?,
+                    denominator: parse_ticker(denominator)?,
+                }),
+                _ => Err("ratio expressions must be of the form `A / B`".to_string()),
+            };
+        }
+
+        let mut legs = Vec::new();
+        let mut iter = tokens.iter().map(String::as_str).peekable();
+        let mut sign = match iter.peek() {
+            Some(&"-") => {
+                iter.next();
+                -Decimal::ONE
+            }
+            Some(&"+") => {
+                iter.next();
+                Decimal::ONE
+            }
+            _ => Decimal::ONE,
+        };
+        loop {
+            let first = iter
+                .next()
+                .ok_or_else(|| "expression ends with an operator".to_string())?;
+            let (coefficient, ticker) = if iter.peek() == Some(&"*") {
+                iter.next();
+                let ticker = iter
+                    .next()
+                    .ok_or_else(|| format!("missing ticker after `{}*`", first))?;
+                let coefficient = Decimal::from_str(first)
+                    .map_err(|_| format!("invalid coefficient: {}", first))?;
+                (coefficient, ticker)
+            } else {
+                (Decimal::ONE, first)
+            };
+            legs.push(SyntheticLeg {
+                ticker: parse_ticker(ticker)?,
+                coefficient: sign * coefficient,
+            });
+
+            sign = match iter.next() {
+                None => break,
+                Some("+") => Decimal::ONE,
+                Some("-") => -Decimal::ONE,
+                Some(other) => return Err(format!("expected `+` or `-`, found `{}`", other)),
+            };
+        }
+
+        Ok(Self::Linear { legs })
+    }
+
+    /// leg 티커 목록 (식에 나온 순서).
+    pub fn tickers(&self) -> Vec<String> {
+        match self {
+            Self::Linear { legs } => legs.iter().map(|l| l.ticker.clone()).collect(),
+            Self::Ratio {
+                numerator,
+                denominator,
+            } => vec![numerator.clone(), denominator.clone()],
+        }
+    }
+
+    /// 가격식 검증.
+    pub fn validate(&self) -> Result<(), String> {
+        let tickers = self.tickers();
+        if tickers.len() < 2 {
+            return Err("at least two legs are required".to_string());
+        }
+        if tickers.len() > MAX_SYNTHETIC_LEGS {
+            return Err(format!("at most {} legs are allowed", MAX_SYNTHETIC_LEGS));
+        }
+
+        let mut seen = HashSet::new();
+        for ticker in &tickers {
+            if is_synthetic_ticker(ticker) {
+                return Err(format!(
+                    "{}: synthetic instruments cannot contain other synthetic instruments",
+                    ticker
+                ));
+            }
+            if !seen.insert(ticker.as_str()) {
+                return Err(format!("{}: duplicate leg", ticker));
+            }
+        }
+
+        if let Self::Linear { legs } = self {
+            if let Some(leg) = legs.iter().find(|l| l.coefficient.is_zero()) {
+                return Err(format!("{}: coefficient must not be zero", leg.ticker));
+            }
+        }
+        Ok(())
+    }
+
+    /// leg 가격으로 합성 가격 계산 (가격이 빠졌거나 분모가 0이면 None).
+    pub fn value(&self, prices: &HashMap<String, Decimal>) -> Option<Decimal> {
+        match self {
+            Self::Linear { legs } => legs
+                .iter()
+                .map(|l| prices.get(&l.ticker).map(|p| l.coefficient * p))
+                .sum(),
+            Self::Ratio {
+                numerator,
+                denominator,
+            } => {
+                let d = *prices.get(denominator)?;
+                if d.is_zero() {
+                    return None;
+                }
+                Some((prices.get(numerator)? / d).round_dp(RATIO_DECIMALS))
+            }
+        }
+    }
+
+    /// 합성 종목 주문을 leg 주문으로 분해.
+    ///
+    /// - 선형 결합: leg 수량 = |계수| × `units`, 음수 계수 leg는 반대 방향
+    /// - 비율: 분자 `units`주와 같은 금액의 분모를 반대 방향으로 주문 (금액 중립)
+    ///
+    /// # Arguments
+    /// * `side` - 합성 종목 주문 방향 (매수 = 스프레드 매수)
+    /// * `units` - 합성 종목 수량
+    /// * `prices` - leg별 현재가
+    pub fn decompose(
+        &self,
+        side: Side,
+        units: Decimal,
+        prices: &HashMap<String, Decimal>,
+    ) -> Result<Vec<SyntheticLegOrder>, String> {
+        if units <= Decimal::ZERO {
+            return Err("units must be positive".to_string());
+        }
+        let price_of = |ticker: &String| {
+            prices
+                .get(ticker)
+                .copied()
+                .filter(|p| *p > Decimal::ZERO)
+                .ok_or_else(|| format!("{}: no price for leg", ticker))
+        };
+
+        let raw: Vec<(String, Side, Decimal, Decimal)> = match self {
+            Self::Linear { legs } => legs
+                .iter()
+                .map(|leg| {
+                    let leg_side = if leg.coefficient > Decimal::ZERO {
+                        side
+                    } else {
+                        side.opposite()
+                    };
+                    Ok((
+                        leg.ticker.clone(),
+                        leg_side,
+                        leg.coefficient.abs() * units,
+                        price_of(&leg.ticker)?,
+                    ))
+                })
+                .collect::<Result<_, String>>()?,
+            Self::Ratio {
+                numerator,
+                denominator,
+            } => {
+                let n = price_of(numerator)?;
+                let d = price_of(denominator)?;
+                vec![
+                    (numerator.clone(), side, units, n),
+                    (denominator.clone(), side.opposite(), units * n / d, d),
+                ]
+            }
+        };
+
+        raw.into_iter()
+            .map(|(ticker, side, quantity, price)| {
+                let quantity = round_leg_quantity(&ticker, quantity);
+                if quantity.is_zero() {
+                    return Err(format!("{}: leg quantity rounds to zero", ticker));
+                }
+                Ok(SyntheticLegOrder {
+                    ticker,
+                    side,
+                    quantity,
+                    price,
+                })
+            })
+            .collect()
+    }
+}
+
+impl fmt::Display for SyntheticExpression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Linear { legs } => {
+                for (i, leg) in legs.iter().enumerate() {
+                    let negative = leg.coefficient < Decimal::ZERO;
+                    match (i, negative) {
+                        (0, true) => write!(f, "-")?,
+                        (0, false) => {}
+                        (_, true) => write!(f, " - ")?,
+                        (_, false) => write!(f, " + ")?,
+                    }
+                    let magnitude = leg.coefficient.abs().normalize();
+                    if magnitude == Decimal::ONE {
+                        write!(f, "{}", leg.ticker)?;
+                    } else {
+                        write!(f, "{}*{}", magnitude, leg.ticker)?;
+                    }
+                }
+                Ok(())
+            }
+            Self::Ratio {
+                numerator,
+                denominator,
+            } => write!(f, "{} / {}", numerator, denominator),
+        }
+    }
+}
+
+/// `1.5*B` 형태의 토큰을 계수/연산자/티커로 분리.
+fn split_coefficient(token: &str) -> Vec<String> {
+    let mut parts = Vec::new();
+    for (i, part) in token.split('*').enumerate() {
+        if i > 0 {
+            parts.push("*".to_string());
+        }
+        if !part.is_empty() {
+            parts.push(part.to_string());
+        }
+    }
+    parts
+}
+
+fn parse_ticker(token: &str) -> Result<String, String> {
+    if matches!(token, "+" | "-" | "*" | "/") {
+        return Err(format!("expected a ticker, found `{}`", token));
+    }
+    Ok(token.to_uppercase())
+}
+
+/// leg 주문 수량 내림 (암호화폐 `BASE/QUOTE`는 소수 8자리, 그 외는 정수).
+fn round_leg_quantity(ticker: &str, quantity: Decimal) -> Decimal {
+    let dp = if ticker.contains('/') { 8 } else { 0 };
+    quantity
+        .round_dp_with_strategy(dp, RoundingStrategy::ToZero)
+        .normalize()
+}
+
+/// 합성 종목 정의.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SyntheticDefinition {
+    /// 합성 종목 코드 (영문 대문자/숫자/밑줄, 2~16자)
+    pub code: String,
+    /// 이름
+    pub name: String,
+    /// 가격식
+    pub expression: SyntheticExpression,
+}
+
+impl SyntheticDefinition {
+    /// 캔들 조회/전략 신호에 사용하는 합성 종목 티커.
+    pub fn ticker(&self) -> String {
+        synthetic_ticker(&self.code)
+    }
+
+    /// 정의 검증.
+    pub fn validate(&self) -> Result<(), String> {
+        let code_len = self.code.chars().count();
+        if !(2..=16).contains(&code_len)
+            || !self
+                .code
+                .chars()
+                .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_')
+        {
+            return Err("code must be 2-16 characters of A-Z, 0-9 or _".to_string());
+        }
+        if self.name.trim().is_empty() {
+            return Err("name is required".to_string());
+        }
+        self.expression.validate()
+    }
+}
+
+/// 합성 종목 코드로 티커 생성.
+pub fn synthetic_ticker(code: &str) -> String {
+    format!("{}{}", SYNTHETIC_TICKER_PREFIX, code)
+}
+
+/// 합성 종목 티커 여부.
+pub fn is_synthetic_ticker(ticker: &str) -> bool {
+    ticker.starts_with(SYNTHETIC_TICKER_PREFIX)
+}
+
+/// 합성 종목 신호의 분해 정보 (신호 메타데이터 [`SYNTHETIC_SIGNAL_KEY`]).
+///
+/// 실행기는 이 정보만으로 leg 주문을 만들 수 있도록 가격식과 신호 시점의 leg 가격을
+/// 함께 담습니다.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SyntheticSignalSpec {
+    /// 가격식
+    pub expression: String,
+    /// 진입 수량 (합성 종목 단위, 없으면 실행기 기본 수량)
+    #[serde(default)]
+    pub units: Option<Decimal>,
+    /// 신호 시점 leg 가격
+    pub leg_prices: HashMap<String, Decimal>,
+}
+
+impl SyntheticSignalSpec {
+    /// 신호 메타데이터에서 분해 정보 조회.
+    pub fn from_signal(signal: &Signal) -> Result<Self, String> {
+        let value = signal.metadata.get(SYNTHETIC_SIGNAL_KEY).ok_or_else(|| {
+            format!(
+                "{}: synthetic signal has no `{}` metadata",
+                signal.ticker, SYNTHETIC_SIGNAL_KEY
+            )
+        })?;
+        serde_json::from_value(value.clone())
+            .map_err(|e| format!("{}: invalid synthetic metadata: {}", signal.ticker, e))
+    }
+
+    /// 신호 메타데이터 값.
+    pub fn to_value(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or_default()
+    }
+}
+
+/// 합성 캔들 계산.
+///
+/// 모든 leg에 같은 시작 시각의 캔들이 있는 구간만 계산합니다 (휴장일 차이 등으로
+/// 한 leg라도 빠진 캔들은 제외). 고가/저가는 leg 고가/저가로 만들 수 있는 범위의
+/// 경계값이며, 거래량은 leg 거래량으로 체결 가능한 합성 종목 수량입니다.
+///
+/// # Arguments
+/// * `ticker` - 결과 캔들에 기록할 합성 종목 티커
+/// * `expression` - 가격식
+/// * `leg_klines` - leg별 캔들 (정렬 무관)
+pub fn compute_synthetic_klines(
+    ticker: &str,
+    expression: &SyntheticExpression,
+    leg_klines: &HashMap<String, Vec<Kline>>,
+) -> Vec<Kline> {
+    let tickers = expression.tickers();
+    let mut by_time: Vec<BTreeMap<DateTime<Utc>, &Kline>> = Vec::with_capacity(tickers.len());
+    for leg in &tickers {
+        let Some(klines) = leg_klines.get(leg) else {
+            return Vec::new();
+        };
+        by_time.push(klines.iter().map(|k| (k.open_time, k)).collect());
+    }
+    let Some((first, rest)) = by_time.split_first() else {
+        return Vec::new();
+    };
+
+    first
+        .iter()
+        .filter_map(|(open_time, base)| {
+            let mut bars = vec![*base];
+            for leg in rest {
+                bars.push(*leg.get(open_time)?);
+            }
+            combine_bars(ticker, expression, base, &bars)
+        })
+        .collect()
+}
+
+/// 같은 시각의 leg 캔들을 합성 캔들 하나로 결합 (`bars`는 `expression.tickers()` 순서).
+fn combine_bars(
+    ticker: &str,
+    expression: &SyntheticExpression,
+    base: &Kline,
+    bars: &[&Kline],
+) -> Option<Kline> {
+    let (open, high, low, close, volume) = match expression {
+        SyntheticExpression::Linear { legs } => {
+            let mut open = Decimal::ZERO;
+            let mut high = Decimal::ZERO;
+            let mut low = Decimal::ZERO;
+            let mut close = Decimal::ZERO;
+            let mut volume: Option<Decimal> = None;
+            for (leg, bar) in legs.iter().zip(bars) {
+                let c = leg.coefficient;
+                open += c * bar.open;
+                close += c * bar.close;
+                if c > Decimal::ZERO {
+                    high += c * bar.high;
+                    low += c * bar.low;
+                } else {
+                    high += c * bar.low;
+                    low += c * bar.high;
+                }
+                let units = bar.volume / c.abs();
+                volume = Some(volume.map_or(units, |v| v.min(units)));
+            }
+            (open, high, low, close, volume.unwrap_or_default())
+        }
+        SyntheticExpression::Ratio { .. } => {
+            let (n, d) = (bars[0], bars[1]);
+            if [d.open, d.high, d.low, d.close, n.close]
+                .iter()
+                .any(|p| *p <= Decimal::ZERO)
+            {
+                return None;
+            }
+            let volume = n.volume.min(d.volume * d.close / n.close);
+            (
+                (n.open / d.open).round_dp(RATIO_DECIMALS),
+                (n.high / d.low).round_dp(RATIO_DECIMALS),
+                (n.low / d.high).round_dp(RATIO_DECIMALS),
+                (n.close / d.close).round_dp(RATIO_DECIMALS),
+                volume.round_dp(RATIO_DECIMALS),
+            )
+        }
+    };
+
+    Some(Kline {
+        ticker: ticker.to_string(),
+        timeframe: base.timeframe,
+        open_time: base.open_time,
+        open,
+        high: high.max(open).max(close),
+        low: low.min(open).min(close),
+        close,
+        volume,
+        close_time: base.close_time,
+        quote_volume: None,
+        num_trades: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Timeframe;
+    use chrono::{Duration, TimeZone};
+    use rust_decimal_macros::dec;
+
+    fn kline(ticker: &str, day: i64, open: Decimal, close: Decimal) -> Kline {
+        let open_time = Utc.with_ymd_and_hms(2026, 3, 2, 0, 0, 0).unwrap() + Duration::days(day);
+        Kline {
+            ticker: ticker.to_string(),
+            timeframe: Timeframe::D1,
+            open_time,
+            open,
+            high: open.max(close) + dec!(1),
+            low: open.min(close) - dec!(1),
+            close,
+            volume: dec!(300),
+            close_time: open_time + Duration::days(1),
+            quote_volume: None,
+            num_trades: None,
+        }
+    }
+
+    #[test]
+    fn test_parse_and_display() {
+        let spread = SyntheticExpression::parse("005930 - 1.5*000660").unwrap();
+        assert_eq!(
+            spread,
+            SyntheticExpression::Linear {
+                legs: vec![
+                    SyntheticLeg {
+                        ticker: "005930".to_string(),
+                        coefficient: dec!(1),
+                    },
+                    SyntheticLeg {
+                        ticker: "000660".to_string(),
+                        coefficient: dec!(-1.5),
+                    },
+                ],
+            }
+        );
+        assert_eq!(spread.to_string(), "005930 - 1.5*000660");
+
+        // 하이픈/슬래시가 포함된 티커는 연산자로 취급하지 않음
+        let fly = SyntheticExpression::parse("-brk-b + 2 * SPY - btc/usdt").unwrap();
+        assert_eq!(fly.tickers(), vec!["BRK-B", "SPY", "BTC/USDT"]);
+        assert_eq!(fly.to_string(), "-BRK-B + 2*SPY - BTC/USDT");
+
+        let ratio = SyntheticExpression::parse("KO / PEP").unwrap();
+        assert_eq!(ratio.to_string(), "KO / PEP");
+        assert!(ratio.validate().is_ok());
+
+        assert!(SyntheticExpression::parse("A -").is_err());
+        assert!(SyntheticExpression::parse("A B").is_err());
+        assert!(SyntheticExpression::parse("A / B / C").is_err());
+        assert!(SyntheticExpression::parse("x*A").is_err());
+        assert_eq!(
+            SyntheticExpression::parse("-0.5*A + B")
+                .unwrap()
+                .to_string(),
+            "-0.5*A + B"
+        );
+        assert!(SyntheticExpression::parse("A - 0*B")
+            .unwrap()
+            .validate()
+            .is_err());
+        assert!(SyntheticExpression::parse("A - A")
+            .unwrap()
+            .validate()
+            .is_err());
+        assert!(SyntheticExpression::parse("A - SYN_X")
+            .unwrap()
+            .validate()
+            .is_err());
+    }
+
+    #[test]
+    fn test_compute_spread_klines_aligns_legs() {
+        let expression = SyntheticExpression::parse("A - 2*B").unwrap();
+        let legs = HashMap::from([
+            (
+                "A".to_string(),
+                vec![
+                    kline("A", 0, dec!(100), dec!(104)),
+                    kline("A", 1, dec!(104), dec!(110)),
+                    kline("A", 2, dec!(110), dec!(108)),
+                ],
+            ),
+            (
+                "B".to_string(),
+                // 1일차 캔들 없음 (휴장) → 합성 캔들에서 제외
+                vec![
+                    kline("B", 2, dec!(50), dec!(51)),
+                    kline("B", 0, dec!(45), dec!(46)),
+                ],
+            ),
+        ]);
+
+        let klines = compute_synthetic_klines("SYN_AB", &expression, &legs);
+
+        assert_eq!(klines.len(), 2);
+        let first = &klines[0];
+        assert_eq!(first.ticker, "SYN_AB");
+        // 시가 = 100 - 2×45, 종가 = 104 - 2×46
+        assert_eq!(first.open, dec!(10));
+        assert_eq!(first.close, dec!(12));
+        // 고가 = A 고가 - 2×B 저가, 저가 = A 저가 - 2×B 고가
+        assert_eq!(first.high, dec!(105) - dec!(2) * dec!(44));
+        assert_eq!(first.low, dec!(99) - dec!(2) * dec!(47));
+        // 거래량 = min(300/1, 300/2)
+        assert_eq!(first.volume, dec!(150));
+        assert_eq!(klines[1].close, dec!(6)); // 108 - 2×51
+    }
+
+    #[test]
+    fn test_compute_ratio_klines() {
+        let expression = SyntheticExpression::parse("A / B").unwrap();
+        let legs = HashMap::from([
+            ("A".to_string(), vec![kline("A", 0, dec!(100), dec!(120))]),
+            ("B".to_string(), vec![kline("B", 0, dec!(50), dec!(40))]),
+        ]);
+
+        let klines = compute_synthetic_klines("SYN_R", &expression, &legs);
+
+        assert_eq!(klines.len(), 1);
+        assert_eq!(klines[0].open, dec!(2));
+        assert_eq!(klines[0].close, dec!(3));
+        assert_eq!(klines[0].high, (dec!(121) / dec!(39)).round_dp(8));
+        assert_eq!(klines[0].volume, dec!(100)); // min(300, 300×40/120)
+        assert_eq!(klines[0].low, (dec!(99) / dec!(51)).round_dp(8));
+        assert!(klines[0].high >= klines[0].close && klines[0].low <= klines[0].open);
+    }
+
+    #[test]
+    fn test_decompose_legs() {
+        let prices = HashMap::from([("A".to_string(), dec!(100)), ("B".to_string(), dec!(30))]);
+
+        let spread = SyntheticExpression::parse("A - 1.5*B").unwrap();
+        let legs = spread.decompose(Side::Buy, dec!(10), &prices).unwrap();
+        assert_eq!(legs.len(), 2);
+        assert_eq!((legs[0].side, legs[0].quantity), (Side::Buy, dec!(10)));
+        assert_eq!((legs[1].side, legs[1].quantity), (Side::Sell, dec!(15)));
+
+        // 스프레드 매도는 leg 방향이 모두 반대
+        let legs = spread.decompose(Side::Sell, dec!(3), &prices).unwrap();
+        assert_eq!((legs[0].side, legs[0].quantity), (Side::Sell, dec!(3)));
+        assert_eq!((legs[1].side, legs[1].quantity), (Side::Buy, dec!(4)));
+
+        // 비율: 분자 10주(1000) ↔ 분모 1000/30 = 33주 (내림)
+        let ratio = SyntheticExpression::parse("A / B").unwrap();
+        let legs = ratio.decompose(Side::Buy, dec!(10), &prices).unwrap();
+        assert_eq!((legs[1].side, legs[1].quantity), (Side::Sell, dec!(33)));
+
+        assert!(spread
+            .decompose(Side::Buy, dec!(0.5), &prices)
+            .unwrap_err()
+            .contains("rounds to zero"));
+        assert!(spread
+            .decompose(Side::Buy, dec!(1), &HashMap::new())
+            .unwrap_err()
+            .contains("no price"));
+    }
+
+    #[test]
+    fn test_signal_spec_roundtrip() {
+        let spec = SyntheticSignalSpec {
+            expression: "A - 1.5*B".to_string(),
+            units: Some(dec!(10)),
+            leg_prices: HashMap::from([("A".to_string(), dec!(100))]),
+        };
+        let signal = Signal::entry("pairs", "SYN_AB".to_string(), Side::Buy)
+            .with_metadata(SYNTHETIC_SIGNAL_KEY, spec.to_value());
+
+        assert_eq!(SyntheticSignalSpec::from_signal(&signal).unwrap(), spec);
+        let plain = Signal::entry("pairs", "SYN_AB".to_string(), Side::Buy);
+        assert!(SyntheticSignalSpec::from_signal(&plain).is_err());
+    }
+
+    #[test]
+    fn test_value() {
+        let prices = HashMap::from([("A".to_string(), dec!(100)), ("B".to_string(), dec!(40))]);
+        let spread = SyntheticExpression::parse("A - 1.5*B").unwrap();
+        assert_eq!(spread.value(&prices), Some(dec!(40)));
+        let ratio = SyntheticExpression::parse("A / B").unwrap();
+        assert_eq!(ratio.value(&prices), Some(dec!(2.5)));
+        assert_eq!(
+            SyntheticExpression::parse("A - C").unwrap().value(&prices),
+            None
+        );
+    }
+}
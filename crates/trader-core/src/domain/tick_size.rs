@@ -197,6 +197,62 @@ impl TickSizeProvider for BinanceTickSize {
     }
 }
 
+/// Upbit 원화(KRW) 마켓 호가 단위 제공자
+///
+/// 가격 구간별 주문 가격 단위:
+/// - 2,000,000원 이상: 1,000원
+/// - 1,000,000원 이상: 500원
+/// - 500,000원 이상: 100원
+/// - 100,000원 이상: 50원
+/// - 10,000원 이상: 10원
+/// - 1,000원 이상: 1원
+/// - 100원 이상: 0.1원
+/// - 10원 이상: 0.01원
+/// - 1원 이상: 0.001원
+/// - 1원 미만: 0.0001원
+#[derive(Debug, Clone)]
+pub struct UpbitKrwTickSize;
+
+impl UpbitKrwTickSize {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for UpbitKrwTickSize {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TickSizeProvider for UpbitKrwTickSize {
+    fn tick_size(&self, price: Decimal) -> Decimal {
+        use rust_decimal_macros::dec;
+
+        if price >= dec!(2_000_000) {
+            dec!(1_000)
+        } else if price >= dec!(1_000_000) {
+            dec!(500)
+        } else if price >= dec!(500_000) {
+            dec!(100)
+        } else if price >= dec!(100_000) {
+            dec!(50)
+        } else if price >= dec!(10_000) {
+            dec!(10)
+        } else if price >= dec!(1_000) {
+            dec!(1)
+        } else if price >= dec!(100) {
+            dec!(0.1)
+        } else if price >= dec!(10) {
+            dec!(0.01)
+        } else if price >= dec!(1) {
+            dec!(0.001)
+        } else {
+            dec!(0.0001)
+        }
+    }
+}
+
 // 거래소별 팩토리 함수는 trader-exchange 크레이트에서 제공합니다.
 // trader-core는 거래소 중립적인 trait과 구현체만 제공합니다.
 
@@ -306,6 +362,27 @@ mod tests {
         assert_eq!(provider.get_tick_size_for_symbol("UNKNOWN"), dec!(0.01));
     }
 
+    #[test]
+    fn test_upbit_krw_tick_size() {
+        let provider = UpbitKrwTickSize::new();
+
+        assert_eq!(provider.tick_size(dec!(95_000_000)), dec!(1_000));
+        assert_eq!(provider.tick_size(dec!(1_500_000)), dec!(500));
+        assert_eq!(provider.tick_size(dec!(4_321)), dec!(1));
+        assert_eq!(provider.tick_size(dec!(0.5)), dec!(0.0001));
+
+        // 95,123,456원 매수 -> 95,123,000원
+        assert_eq!(
+            provider.round_to_tick(dec!(95_123_456), RoundMethod::Floor),
+            dec!(95_123_000)
+        );
+        // 623.45원 매도 -> 623.5원
+        assert_eq!(
+            provider.round_to_tick(dec!(623.45), RoundMethod::Ceil),
+            dec!(623.5)
+        );
+    }
+
     #[test]
     fn test_round_method() {
         let provider = KrxTickSize::new();
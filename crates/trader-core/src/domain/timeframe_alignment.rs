@@ -0,0 +1,500 @@
+//! 다중 타임프레임 정렬 유틸리티.
+//!
+//! 백테스트와 실시간 전략 엔진에서 미래 데이터 누출(Look-Ahead Bias)을 방지하기 위한
+//! 타임프레임 정렬 로직과, 하위 타임프레임 캔들을 상위 타임프레임으로 집계하는
+//! 로직을 제공합니다.
+//!
+//! # 핵심 개념
+//!
+//! 다중 타임프레임 전략에서 Secondary 타임프레임(예: 1시간봉, 일봉)의 데이터는
+//! Primary 타임프레임(예: 5분봉)의 현재 시점에서 "완료된" 캔들만 사용해야 합니다.
+//!
+//! ## 예시
+//!
+//! - Primary: 5분봉, 현재 시점 10:07
+//! - Secondary: 1시간봉
+//! - **유효한 1시간봉**: 09:00~10:00 (10:00에 완료된 캔들)
+//! - **무효한 1시간봉**: 10:00~11:00 (아직 진행 중)
+//!
+//! # 사용 예시
+//!
+//! ```rust,ignore
+//! use trader_core::TimeframeAligner;
+//! use chrono::Utc;
+//!
+//! let h1_klines = vec![/* ... */];
+//! let current_time = Utc::now();
+//!
+//! // 현재 시점 기준으로 완료된 캔들만 필터링
+//! let valid_klines = TimeframeAligner::get_aligned_klines(&h1_klines, current_time);
+//!
+//! // 가장 최근 완료된 캔들 조회
+//! let latest = TimeframeAligner::find_latest_completed(&h1_klines, current_time);
+//! ```
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use std::collections::HashMap;
+
+use super::market_data::Kline;
+use crate::Timeframe;
+
+/// 타임프레임 정렬 유틸리티.
+///
+/// 백테스트 및 실시간 분석에서 미래 데이터 누출을 방지합니다.
+pub struct TimeframeAligner;
+
+impl TimeframeAligner {
+    /// Secondary 캔들이 특정 시점에서 유효한지 확인.
+    ///
+    /// 캔들의 종료 시간(`close_time`)이 기준 시점 이전이면 유효합니다.
+    ///
+    /// # 인자
+    ///
+    /// * `kline` - 확인할 캔들
+    /// * `reference_time` - 기준 시점 (보통 Primary 캔들의 종료 시간)
+    ///
+    /// # 반환
+    ///
+    /// 캔들이 유효하면 `true`, 아직 완료되지 않았으면 `false`
+    #[inline]
+    pub fn is_valid_at(kline: &Kline, reference_time: DateTime<Utc>) -> bool {
+        kline.close_time <= reference_time
+    }
+
+    /// 기준 시점까지 완료된 캔들만 필터링.
+    ///
+    /// # 인자
+    ///
+    /// * `klines` - Secondary 타임프레임 캔들 목록
+    /// * `reference_time` - 기준 시점
+    ///
+    /// # 반환
+    ///
+    /// 완료된 캔들에 대한 참조 벡터 (시간순 정렬 유지)
+    pub fn get_aligned_klines(klines: &[Kline], reference_time: DateTime<Utc>) -> Vec<&Kline> {
+        klines
+            .iter()
+            .filter(|k| Self::is_valid_at(k, reference_time))
+            .collect()
+    }
+
+    /// 기준 시점에서 가장 최근 완료된 캔들 찾기.
+    ///
+    /// # 인자
+    ///
+    /// * `klines` - Secondary 타임프레임 캔들 목록 (시간순 정렬 가정)
+    /// * `reference_time` - 기준 시점
+    ///
+    /// # 반환
+    ///
+    /// 가장 최근 완료된 캔들에 대한 참조, 없으면 `None`
+    pub fn find_latest_completed(
+        klines: &[Kline],
+        reference_time: DateTime<Utc>,
+    ) -> Option<&Kline> {
+        // 역순으로 탐색하여 첫 번째 유효한 캔들 반환
+        klines
+            .iter()
+            .rev()
+            .find(|k| Self::is_valid_at(k, reference_time))
+    }
+
+    /// 여러 타임프레임의 데이터를 기준 시점에 맞게 정렬.
+    ///
+    /// # 인자
+    ///
+    /// * `secondary_data` - 타임프레임별 캔들 데이터
+    /// * `reference_time` - 기준 시점
+    ///
+    /// # 반환
+    ///
+    /// 타임프레임별 정렬된 캔들 데이터 (복사본)
+    pub fn align_multi_timeframe(
+        secondary_data: &HashMap<Timeframe, Vec<Kline>>,
+        reference_time: DateTime<Utc>,
+    ) -> HashMap<Timeframe, Vec<Kline>> {
+        secondary_data
+            .iter()
+            .map(|(&tf, klines)| {
+                let aligned: Vec<Kline> = klines
+                    .iter()
+                    .filter(|k| Self::is_valid_at(k, reference_time))
+                    .cloned()
+                    .collect();
+                (tf, aligned)
+            })
+            .collect()
+    }
+
+    /// N개의 가장 최근 완료된 캔들 반환.
+    ///
+    /// # 인자
+    ///
+    /// * `klines` - 캔들 목록
+    /// * `reference_time` - 기준 시점
+    /// * `count` - 반환할 캔들 수
+    ///
+    /// # 반환
+    ///
+    /// 가장 최근 완료된 N개 캔들 (시간순)
+    pub fn get_latest_n_completed(
+        klines: &[Kline],
+        reference_time: DateTime<Utc>,
+        count: usize,
+    ) -> Vec<Kline> {
+        let aligned: Vec<&Kline> = Self::get_aligned_klines(klines, reference_time);
+        let start = aligned.len().saturating_sub(count);
+        aligned[start..].iter().map(|&k| k.clone()).collect()
+    }
+
+    /// 특정 타임프레임에서 주어진 시간이 포함된 캔들의 시작 시점 계산 (UTC 기준).
+    ///
+    /// 주봉은 월요일, 월봉은 1일에 시작하며, 나머지는 Unix epoch 기준으로 내림합니다.
+    pub fn get_candle_open_time(time: DateTime<Utc>, timeframe: Timeframe) -> DateTime<Utc> {
+        let date = time.date_naive();
+        let start_of = |date: NaiveDate| date.and_hms_opt(0, 0, 0).map(|t| t.and_utc());
+
+        match timeframe {
+            Timeframe::W1 => {
+                let monday = date - Duration::days(date.weekday().num_days_from_monday() as i64);
+                start_of(monday).unwrap_or(time)
+            }
+            Timeframe::MN1 => date.with_day(1).and_then(start_of).unwrap_or(time),
+            _ => {
+                let duration_secs = timeframe.duration().as_secs() as i64;
+                if duration_secs == 0 {
+                    return time;
+                }
+
+                // 현재 시간을 타임프레임 단위로 내림
+                let aligned_timestamp = time.timestamp().div_euclid(duration_secs) * duration_secs;
+                DateTime::from_timestamp(aligned_timestamp, 0).unwrap_or(time)
+            }
+        }
+    }
+
+    /// 특정 타임프레임의 캔들이 "완료"되는 시점 계산.
+    ///
+    /// 주어진 시간이 포함된 캔들의 종료 시간(다음 캔들의 시작 시간)을 반환합니다.
+    ///
+    /// # 인자
+    ///
+    /// * `time` - 확인할 시점
+    /// * `timeframe` - 타임프레임
+    ///
+    /// # 반환
+    ///
+    /// 해당 캔들이 완료되는 시점
+    pub fn get_candle_close_time(time: DateTime<Utc>, timeframe: Timeframe) -> DateTime<Utc> {
+        let open_time = Self::get_candle_open_time(time, timeframe);
+
+        match timeframe {
+            Timeframe::MN1 => open_time
+                .date_naive()
+                .checked_add_months(chrono::Months::new(1))
+                .and_then(|date| date.and_hms_opt(0, 0, 0))
+                .map(|t| t.and_utc())
+                .unwrap_or(time),
+            _ => {
+                let duration_secs = timeframe.duration().as_secs() as i64;
+                open_time + Duration::seconds(duration_secs)
+            }
+        }
+    }
+
+    /// 하위 타임프레임 캔들을 상위 타임프레임 캔들 목록에 집계.
+    ///
+    /// `source`가 속한 상위 캔들이 목록의 마지막 캔들이면 고가/저가/종가/거래량을 갱신하고,
+    /// 아니면 새 캔들을 추가합니다 (`max_len`을 넘으면 오래된 것부터 제거).
+    /// 진행 중인 하위 캔들이 갱신된 경우 이전 값을 `replaced`로 넘기면 거래량이
+    /// 중복 집계되지 않습니다.
+    ///
+    /// 상위 캔들의 종료 시간은 `source`와 같은 규칙(정확한 경계 또는 경계 직전)을 따릅니다.
+    pub fn roll_up(
+        klines: &mut Vec<Kline>,
+        source: &Kline,
+        replaced: Option<&Kline>,
+        timeframe: Timeframe,
+        max_len: usize,
+    ) {
+        let open_time = Self::get_candle_open_time(source.open_time, timeframe);
+
+        match klines.last_mut() {
+            Some(last) if last.open_time == open_time => {
+                last.high = last.high.max(source.high);
+                last.low = last.low.min(source.low);
+                last.close = source.close;
+                last.volume += source.volume - replaced.map_or(Decimal::ZERO, |k| k.volume);
+                last.quote_volume = match (last.quote_volume, source.quote_volume) {
+                    (Some(total), Some(quote)) => Some(
+                        total + quote - replaced.and_then(|k| k.quote_volume).unwrap_or_default(),
+                    ),
+                    _ => None,
+                };
+                last.num_trades = match (last.num_trades, source.num_trades) {
+                    (Some(total), Some(trades)) => {
+                        Some(total + trades - replaced.and_then(|k| k.num_trades).unwrap_or(0))
+                    }
+                    _ => None,
+                };
+            }
+            Some(last) if last.open_time > open_time => return,
+            _ => {
+                let source_close = Self::get_candle_close_time(source.open_time, source.timeframe);
+                let gap = (source_close - source.close_time).max(Duration::zero());
+                klines.push(Kline {
+                    ticker: source.ticker.clone(),
+                    timeframe,
+                    open_time,
+                    open: source.open,
+                    high: source.high,
+                    low: source.low,
+                    close: source.close,
+                    volume: source.volume,
+                    close_time: Self::get_candle_close_time(source.open_time, timeframe) - gap,
+                    quote_volume: source.quote_volume,
+                    num_trades: source.num_trades,
+                });
+            }
+        }
+
+        if klines.len() > max_len {
+            klines.drain(0..klines.len() - max_len);
+        }
+    }
+
+    /// 두 시점 사이의 캔들 수 계산.
+    ///
+    /// # 인자
+    ///
+    /// * `start` - 시작 시점
+    /// * `end` - 종료 시점
+    /// * `timeframe` - 타임프레임
+    ///
+    /// # 반환
+    ///
+    /// 캔들 수 (정수)
+    pub fn count_candles_between(
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        timeframe: Timeframe,
+    ) -> usize {
+        let duration = timeframe.duration();
+        let duration_secs = duration.as_secs() as i64;
+
+        if duration_secs == 0 {
+            return 0;
+        }
+
+        let diff = end.signed_duration_since(start).num_seconds();
+        (diff / duration_secs).max(0) as usize
+    }
+}
+
+// =============================================================================
+// 테스트
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use rust_decimal_macros::dec;
+
+    fn make_kline(open_time: DateTime<Utc>, close_time: DateTime<Utc>) -> Kline {
+        Kline {
+            ticker: "TEST".to_string(),
+            timeframe: Timeframe::H1,
+            open_time,
+            open: dec!(100),
+            high: dec!(110),
+            low: dec!(90),
+            close: dec!(105),
+            volume: dec!(1000),
+            close_time,
+            quote_volume: None,
+            num_trades: None,
+        }
+    }
+
+    #[test]
+    fn test_is_valid_at() {
+        let kline = make_kline(
+            Utc.with_ymd_and_hms(2024, 1, 1, 9, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2024, 1, 1, 10, 0, 0).unwrap(),
+        );
+
+        // 캔들 종료 시간 이후 → 유효
+        let after = Utc.with_ymd_and_hms(2024, 1, 1, 10, 5, 0).unwrap();
+        assert!(TimeframeAligner::is_valid_at(&kline, after));
+
+        // 캔들 종료 시간과 동일 → 유효
+        let equal = Utc.with_ymd_and_hms(2024, 1, 1, 10, 0, 0).unwrap();
+        assert!(TimeframeAligner::is_valid_at(&kline, equal));
+
+        // 캔들 종료 시간 이전 → 무효
+        let before = Utc.with_ymd_and_hms(2024, 1, 1, 9, 30, 0).unwrap();
+        assert!(!TimeframeAligner::is_valid_at(&kline, before));
+    }
+
+    #[test]
+    fn test_get_aligned_klines() {
+        let klines = vec![
+            make_kline(
+                Utc.with_ymd_and_hms(2024, 1, 1, 8, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2024, 1, 1, 9, 0, 0).unwrap(),
+            ),
+            make_kline(
+                Utc.with_ymd_and_hms(2024, 1, 1, 9, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2024, 1, 1, 10, 0, 0).unwrap(),
+            ),
+            make_kline(
+                Utc.with_ymd_and_hms(2024, 1, 1, 10, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2024, 1, 1, 11, 0, 0).unwrap(),
+            ),
+        ];
+
+        // 10:30 기준: 9시, 10시 캔들만 유효
+        let reference = Utc.with_ymd_and_hms(2024, 1, 1, 10, 30, 0).unwrap();
+        let aligned = TimeframeAligner::get_aligned_klines(&klines, reference);
+
+        assert_eq!(aligned.len(), 2);
+        assert_eq!(
+            aligned[0].close_time,
+            Utc.with_ymd_and_hms(2024, 1, 1, 9, 0, 0).unwrap()
+        );
+        assert_eq!(
+            aligned[1].close_time,
+            Utc.with_ymd_and_hms(2024, 1, 1, 10, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_find_latest_completed() {
+        let klines = vec![
+            make_kline(
+                Utc.with_ymd_and_hms(2024, 1, 1, 8, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2024, 1, 1, 9, 0, 0).unwrap(),
+            ),
+            make_kline(
+                Utc.with_ymd_and_hms(2024, 1, 1, 9, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2024, 1, 1, 10, 0, 0).unwrap(),
+            ),
+            make_kline(
+                Utc.with_ymd_and_hms(2024, 1, 1, 10, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2024, 1, 1, 11, 0, 0).unwrap(),
+            ),
+        ];
+
+        let reference = Utc.with_ymd_and_hms(2024, 1, 1, 10, 30, 0).unwrap();
+        let latest = TimeframeAligner::find_latest_completed(&klines, reference);
+
+        assert!(latest.is_some());
+        assert_eq!(
+            latest.unwrap().close_time,
+            Utc.with_ymd_and_hms(2024, 1, 1, 10, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_candle_open_close_time() {
+        // 2024-01-03(수) 10:30
+        let time = Utc.with_ymd_and_hms(2024, 1, 3, 10, 30, 0).unwrap();
+
+        assert_eq!(
+            TimeframeAligner::get_candle_close_time(time, Timeframe::H1),
+            Utc.with_ymd_and_hms(2024, 1, 3, 11, 0, 0).unwrap()
+        );
+        assert_eq!(
+            TimeframeAligner::get_candle_open_time(time, Timeframe::D1),
+            Utc.with_ymd_and_hms(2024, 1, 3, 0, 0, 0).unwrap()
+        );
+        // 주봉은 월요일 시작
+        assert_eq!(
+            TimeframeAligner::get_candle_open_time(time, Timeframe::W1),
+            Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap()
+        );
+        assert_eq!(
+            TimeframeAligner::get_candle_close_time(time, Timeframe::W1),
+            Utc.with_ymd_and_hms(2024, 1, 8, 0, 0, 0).unwrap()
+        );
+        // 월봉은 달력 월 단위
+        let feb = Utc.with_ymd_and_hms(2024, 2, 29, 10, 0, 0).unwrap();
+        assert_eq!(
+            TimeframeAligner::get_candle_open_time(feb, Timeframe::MN1),
+            Utc.with_ymd_and_hms(2024, 2, 1, 0, 0, 0).unwrap()
+        );
+        assert_eq!(
+            TimeframeAligner::get_candle_close_time(feb, Timeframe::MN1),
+            Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_roll_up() {
+        let h1 = |hour: u32, close: Decimal, volume: Decimal| Kline {
+            close,
+            volume,
+            ..make_kline(
+                Utc.with_ymd_and_hms(2024, 1, 1, hour, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2024, 1, 1, hour, 0, 0).unwrap() + Duration::hours(1),
+            )
+        };
+        let mut h4 = Vec::new();
+
+        TimeframeAligner::roll_up(&mut h4, &h1(8, dec!(101), dec!(10)), None, Timeframe::H4, 2);
+        TimeframeAligner::roll_up(&mut h4, &h1(9, dec!(102), dec!(10)), None, Timeframe::H4, 2);
+        assert_eq!(h4.len(), 1);
+        assert_eq!(
+            h4[0].open_time,
+            Utc.with_ymd_and_hms(2024, 1, 1, 8, 0, 0).unwrap()
+        );
+        assert_eq!(
+            h4[0].close_time,
+            Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap()
+        );
+        assert_eq!(h4[0].timeframe, Timeframe::H4);
+        assert_eq!(h4[0].close, dec!(102));
+        assert_eq!(h4[0].volume, dec!(20));
+
+        // 진행 중인 캔들 갱신: 이전 거래량을 빼고 다시 집계
+        let previous = h1(9, dec!(102), dec!(10));
+        let updated = h1(9, dec!(103), dec!(15));
+        TimeframeAligner::roll_up(&mut h4, &updated, Some(&previous), Timeframe::H4, 2);
+        assert_eq!(h4[0].close, dec!(103));
+        assert_eq!(h4[0].volume, dec!(25));
+
+        // 다음 구간은 새 캔들, 최대 개수 초과 시 오래된 것부터 제거
+        TimeframeAligner::roll_up(&mut h4, &h1(12, dec!(104), dec!(1)), None, Timeframe::H4, 2);
+        TimeframeAligner::roll_up(&mut h4, &h1(16, dec!(105), dec!(1)), None, Timeframe::H4, 2);
+        assert_eq!(h4.len(), 2);
+        assert_eq!(
+            h4[0].open_time,
+            Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap()
+        );
+
+        // 이미 지난 구간의 캔들은 무시
+        TimeframeAligner::roll_up(&mut h4, &h1(9, dec!(1), dec!(1)), None, Timeframe::H4, 2);
+        assert_eq!(h4.len(), 2);
+        assert_eq!(h4[1].close, dec!(105));
+    }
+
+    #[test]
+    fn test_count_candles_between() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 9, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+
+        // 3시간 → 1시간봉 3개
+        assert_eq!(
+            TimeframeAligner::count_candles_between(start, end, Timeframe::H1),
+            3
+        );
+
+        // 3시간 → 15분봉 12개
+        assert_eq!(
+            TimeframeAligner::count_candles_between(start, end, Timeframe::M15),
+            12
+        );
+    }
+}
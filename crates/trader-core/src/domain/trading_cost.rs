@@ -0,0 +1,439 @@
+//! TradingCost - 시장별 거래 비용 모델.
+//!
+//! 단일 수수료율 대신 시장 구조에 맞는 비용을 계산합니다.
+//!
+//! - 국내 주식: 위탁수수료(매수/매도) + 증권거래세·농어촌특별세(매도)
+//! - 미국 주식: 수수료(매수/매도) + SEC fee(매도)
+//! - 암호화폐: 30일 거래대금 구간별 maker/taker 수수료
+//!
+//! 백테스트 엔진과 실거래 손익 정산이 같은 모델을 사용하므로
+//! 국내/해외 전략의 결과를 실제 비용 기준으로 비교할 수 있습니다.
+//! 세율과 요율은 제도 변경에 따라 달라지므로 기본값은 설정으로 덮어쓸 수 있습니다.
+
+use chrono::{DateTime, Duration, Utc};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+use super::{OrderType, Side};
+
+/// 수수료/세율 상한 (10%)
+const MAX_RATE: Decimal = dec!(0.1);
+
+/// 체결 유동성 구분.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Liquidity {
+    /// 호가 제공 (지정가 대기 주문)
+    Maker,
+    /// 호가 소진 (시장가/즉시 체결)
+    Taker,
+}
+
+impl Liquidity {
+    /// 주문 유형으로 유동성 추정 (지정가 → Maker, 그 외 → Taker).
+    ///
+    /// 즉시 체결된 지정가 주문도 Maker로 분류되므로 근사값입니다.
+    pub fn from_order_type(order_type: OrderType) -> Self {
+        match order_type {
+            OrderType::Limit => Self::Maker,
+            _ => Self::Taker,
+        }
+    }
+}
+
+/// 암호화폐 거래대금 구간별 수수료.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CryptoFeeTier {
+    /// 구간 시작 30일 거래대금 (호가 통화 기준)
+    pub min_volume_30d: Decimal,
+    /// Maker 수수료율
+    pub maker_rate: Decimal,
+    /// Taker 수수료율
+    pub taker_rate: Decimal,
+}
+
+/// 체결 1건의 거래 비용.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TradeCost {
+    /// 수수료
+    pub commission: Decimal,
+    /// 세금 및 규제 수수료 (증권거래세, 농특세, SEC fee)
+    pub tax: Decimal,
+}
+
+impl TradeCost {
+    /// 총 비용.
+    pub fn total(&self) -> Decimal {
+        self.commission + self.tax
+    }
+}
+
+/// 시장별 거래 비용 모델.
+///
+/// JSON에서는 `type` 필드로 구분하며, 생략한 요율은 기본값을 사용합니다.
+/// 예: `{"type": "kr_stock"}` (코스피 기본값),
+/// `{"type": "kr_stock", "transaction_tax_rate": 0.002, "rural_tax_rate": 0}` (코스닥)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TradingCostModel {
+    /// 단일 수수료율 (매수/매도 동일, 세금 없음)
+    Flat {
+        /// 수수료율
+        commission_rate: Decimal,
+    },
+    /// 국내 주식
+    KrStock {
+        /// 위탁수수료율 (매수/매도)
+        #[serde(default = "default_kr_commission_rate")]
+        commission_rate: Decimal,
+        /// 증권거래세율 (매도)
+        #[serde(default = "default_kr_transaction_tax_rate")]
+        transaction_tax_rate: Decimal,
+        /// 농어촌특별세율 (매도, 코스피)
+        #[serde(default = "default_kr_rural_tax_rate")]
+        rural_tax_rate: Decimal,
+    },
+    /// 미국 주식
+    UsStock {
+        /// 수수료율 (매수/매도)
+        #[serde(default = "default_us_commission_rate")]
+        commission_rate: Decimal,
+        /// SEC fee 요율 (매도)
+        #[serde(default = "default_sec_fee_rate")]
+        sec_fee_rate: Decimal,
+    },
+    /// 암호화폐
+    Crypto {
+        /// 30일 거래대금 구간별 수수료 (min_volume_30d 오름차순)
+        #[serde(default = "default_crypto_tiers")]
+        tiers: Vec<CryptoFeeTier>,
+    },
+}
+
+fn default_kr_commission_rate() -> Decimal {
+    dec!(0.00015) // 0.015%
+}
+
+fn default_kr_transaction_tax_rate() -> Decimal {
+    dec!(0.0005) // 0.05% (코스피)
+}
+
+fn default_kr_rural_tax_rate() -> Decimal {
+    dec!(0.0015) // 0.15%
+}
+
+fn default_us_commission_rate() -> Decimal {
+    dec!(0.0025) // 0.25%
+}
+
+fn default_sec_fee_rate() -> Decimal {
+    dec!(0.0000278) // $27.80 / 100만 달러
+}
+
+fn default_crypto_tiers() -> Vec<CryptoFeeTier> {
+    [
+        (dec!(0), dec!(0.001), dec!(0.001)),
+        (dec!(1000000), dec!(0.0009), dec!(0.001)),
+        (dec!(5000000), dec!(0.0008), dec!(0.001)),
+        (dec!(20000000), dec!(0.0007), dec!(0.0009)),
+    ]
+    .into_iter()
+    .map(|(min_volume_30d, maker_rate, taker_rate)| CryptoFeeTier {
+        min_volume_30d,
+        maker_rate,
+        taker_rate,
+    })
+    .collect()
+}
+
+impl TradingCostModel {
+    /// 단일 수수료율 모델.
+    pub fn flat(commission_rate: Decimal) -> Self {
+        Self::Flat { commission_rate }
+    }
+
+    /// 코스피 기본 모델 (수수료 0.015%, 거래세 0.05%, 농특세 0.15%).
+    pub fn kr_kospi() -> Self {
+        Self::KrStock {
+            commission_rate: default_kr_commission_rate(),
+            transaction_tax_rate: default_kr_transaction_tax_rate(),
+            rural_tax_rate: default_kr_rural_tax_rate(),
+        }
+    }
+
+    /// 코스닥 기본 모델 (수수료 0.015%, 거래세 0.20%, 농특세 없음).
+    pub fn kr_kosdaq() -> Self {
+        Self::KrStock {
+            commission_rate: default_kr_commission_rate(),
+            transaction_tax_rate: dec!(0.002),
+            rural_tax_rate: Decimal::ZERO,
+        }
+    }
+
+    /// 미국 주식 기본 모델 (수수료 0.25%, SEC fee).
+    pub fn us_stock() -> Self {
+        Self::UsStock {
+            commission_rate: default_us_commission_rate(),
+            sec_fee_rate: default_sec_fee_rate(),
+        }
+    }
+
+    /// 암호화폐 기본 모델 (거래대금 구간별 maker/taker).
+    pub fn crypto() -> Self {
+        Self::Crypto {
+            tiers: default_crypto_tiers(),
+        }
+    }
+
+    /// 이름으로 기본 모델 조회 (KR_KOSPI, KR_KOSDAQ, US_STOCK, CRYPTO).
+    pub fn preset(name: &str) -> Option<Self> {
+        match name.trim().to_uppercase().as_str() {
+            "KR" | "KR_STOCK" | "KR_KOSPI" | "KOSPI" => Some(Self::kr_kospi()),
+            "KR_KOSDAQ" | "KOSDAQ" => Some(Self::kr_kosdaq()),
+            "US" | "US_STOCK" => Some(Self::us_stock()),
+            "CRYPTO" => Some(Self::crypto()),
+            _ => None,
+        }
+    }
+
+    /// 수수료율 변경 (세율 유지, 암호화폐 구간 모델은 변경하지 않음).
+    pub fn with_commission_rate(mut self, rate: Decimal) -> Self {
+        match &mut self {
+            Self::Flat { commission_rate }
+            | Self::KrStock {
+                commission_rate, ..
+            }
+            | Self::UsStock {
+                commission_rate, ..
+            } => *commission_rate = rate,
+            Self::Crypto { .. } => {}
+        }
+        self
+    }
+
+    /// 체결 1건의 비용 계산.
+    ///
+    /// # Arguments
+    ///
+    /// * `side` - 체결 방향 (세금은 매도 체결에만 부과)
+    /// * `notional` - 체결 금액 (가격 × 수량)
+    /// * `liquidity` - Maker/Taker 구분 (암호화폐만 사용)
+    /// * `trailing_volume` - 직전 30일 거래대금 (암호화폐 구간 결정)
+    pub fn trade_cost(
+        &self,
+        side: Side,
+        notional: Decimal,
+        liquidity: Liquidity,
+        trailing_volume: Decimal,
+    ) -> TradeCost {
+        let notional = notional.abs();
+        let sell_rate = |rate: Decimal| match side {
+            Side::Sell => notional * rate,
+            Side::Buy => Decimal::ZERO,
+        };
+
+        match self {
+            Self::Flat { commission_rate } => TradeCost {
+                commission: notional * commission_rate,
+                tax: Decimal::ZERO,
+            },
+            Self::KrStock {
+                commission_rate,
+                transaction_tax_rate,
+                rural_tax_rate,
+            } => TradeCost {
+                commission: notional * commission_rate,
+                tax: sell_rate(transaction_tax_rate + rural_tax_rate),
+            },
+            Self::UsStock {
+                commission_rate,
+                sec_fee_rate,
+            } => TradeCost {
+                commission: notional * commission_rate,
+                tax: sell_rate(*sec_fee_rate),
+            },
+            Self::Crypto { tiers } => {
+                let rate = tiers
+                    .iter()
+                    .rev()
+                    .find(|t| trailing_volume >= t.min_volume_30d)
+                    .or(tiers.first())
+                    .map(|t| match liquidity {
+                        Liquidity::Maker => t.maker_rate,
+                        Liquidity::Taker => t.taker_rate,
+                    })
+                    .unwrap_or_default();
+                TradeCost {
+                    commission: notional * rate,
+                    tax: Decimal::ZERO,
+                }
+            }
+        }
+    }
+
+    /// 요율 검증 (0 ~ 10%, 암호화폐 구간은 1개 이상이며 오름차순).
+    pub fn validate(&self) -> Result<(), String> {
+        let rates: Vec<Decimal> = match self {
+            Self::Flat { commission_rate } => vec![*commission_rate],
+            Self::KrStock {
+                commission_rate,
+                transaction_tax_rate,
+                rural_tax_rate,
+            } => vec![*commission_rate, *transaction_tax_rate, *rural_tax_rate],
+            Self::UsStock {
+                commission_rate,
+                sec_fee_rate,
+            } => vec![*commission_rate, *sec_fee_rate],
+            Self::Crypto { tiers } => {
+                if tiers.is_empty() {
+                    return Err("crypto cost model requires at least one fee tier".to_string());
+                }
+                if tiers
+                    .windows(2)
+                    .any(|w| w[0].min_volume_30d >= w[1].min_volume_30d)
+                {
+                    return Err("fee tiers must be sorted by min_volume_30d".to_string());
+                }
+                tiers
+                    .iter()
+                    .flat_map(|t| [t.maker_rate, t.taker_rate])
+                    .collect()
+            }
+        };
+
+        if rates.iter().any(|r| *r < Decimal::ZERO || *r > MAX_RATE) {
+            return Err("cost rates must be between 0 and 0.1".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// 최근 거래대금 집계 (암호화폐 수수료 구간 판정용).
+#[derive(Debug, Clone)]
+pub struct TradingVolumeWindow {
+    window: Duration,
+    trades: VecDeque<(DateTime<Utc>, Decimal)>,
+    total: Decimal,
+}
+
+impl Default for TradingVolumeWindow {
+    fn default() -> Self {
+        Self::new(30)
+    }
+}
+
+impl TradingVolumeWindow {
+    /// 집계 기간(일)으로 생성.
+    pub fn new(window_days: i64) -> Self {
+        Self {
+            window: Duration::days(window_days),
+            trades: VecDeque::new(),
+            total: Decimal::ZERO,
+        }
+    }
+
+    /// 체결 금액 기록.
+    pub fn record(&mut self, at: DateTime<Utc>, notional: Decimal) {
+        self.trades.push_back((at, notional.abs()));
+        self.total += notional.abs();
+    }
+
+    /// `now` 기준 집계 기간 내 거래대금.
+    pub fn volume(&mut self, now: DateTime<Utc>) -> Decimal {
+        let cutoff = now - self.window;
+        while let Some((at, notional)) = self.trades.front() {
+            if *at > cutoff {
+                break;
+            }
+            self.total -= *notional;
+            self.trades.pop_front();
+        }
+        self.total
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kr_stock_taxes_on_sell_only() {
+        let model = TradingCostModel::kr_kospi();
+
+        let buy = model.trade_cost(Side::Buy, dec!(10000000), Liquidity::Taker, Decimal::ZERO);
+        assert_eq!(buy.commission, dec!(1500));
+        assert_eq!(buy.tax, Decimal::ZERO);
+
+        let sell = model.trade_cost(Side::Sell, dec!(10000000), Liquidity::Taker, Decimal::ZERO);
+        assert_eq!(sell.commission, dec!(1500));
+        assert_eq!(sell.tax, dec!(20000));
+        assert_eq!(sell.total(), dec!(21500));
+
+        let kosdaq = TradingCostModel::kr_kosdaq();
+        let sell = kosdaq.trade_cost(Side::Sell, dec!(10000000), Liquidity::Taker, Decimal::ZERO);
+        assert_eq!(sell.tax, dec!(20000));
+    }
+
+    #[test]
+    fn test_us_stock_sec_fee() {
+        let model = TradingCostModel::us_stock();
+        let sell = model.trade_cost(Side::Sell, dec!(1000000), Liquidity::Taker, Decimal::ZERO);
+        assert_eq!(sell.commission, dec!(2500));
+        assert_eq!(sell.tax, dec!(27.8));
+
+        let discounted = model.with_commission_rate(dec!(0.0007));
+        let sell =
+            discounted.trade_cost(Side::Sell, dec!(1000000), Liquidity::Taker, Decimal::ZERO);
+        assert_eq!(sell.commission, dec!(700));
+        assert_eq!(sell.tax, dec!(27.8));
+    }
+
+    #[test]
+    fn test_crypto_tiers() {
+        let model = TradingCostModel::crypto();
+
+        let base = model.trade_cost(Side::Buy, dec!(10000), Liquidity::Maker, Decimal::ZERO);
+        assert_eq!(base.commission, dec!(10));
+
+        let vip = model.trade_cost(Side::Buy, dec!(10000), Liquidity::Maker, dec!(6000000));
+        assert_eq!(vip.commission, dec!(8));
+        let vip = model.trade_cost(Side::Buy, dec!(10000), Liquidity::Taker, dec!(6000000));
+        assert_eq!(vip.commission, dec!(10));
+    }
+
+    #[test]
+    fn test_deserialize_with_defaults() {
+        let model: TradingCostModel = serde_json::from_str(r#"{"type": "kr_stock"}"#).unwrap();
+        assert_eq!(model, TradingCostModel::kr_kospi());
+
+        let model: TradingCostModel =
+            serde_json::from_str(r#"{"type": "us_stock", "commission_rate": 0.001}"#).unwrap();
+        assert!(matches!(
+            model,
+            TradingCostModel::UsStock { commission_rate, .. } if commission_rate == dec!(0.001)
+        ));
+
+        assert_eq!(
+            TradingCostModel::preset("kosdaq"),
+            Some(TradingCostModel::kr_kosdaq())
+        );
+        assert!(TradingCostModel::flat(dec!(0.2)).validate().is_err());
+        assert!(TradingCostModel::Crypto { tiers: vec![] }
+            .validate()
+            .is_err());
+        assert!(TradingCostModel::crypto().validate().is_ok());
+    }
+
+    #[test]
+    fn test_volume_window() {
+        let now = Utc::now();
+        let mut window = TradingVolumeWindow::new(30);
+        window.record(now - Duration::days(40), dec!(500));
+        window.record(now - Duration::days(5), dec!(300));
+        window.record(now, dec!(200));
+
+        assert_eq!(window.volume(now), dec!(500));
+    }
+}
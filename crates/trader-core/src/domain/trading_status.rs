@@ -0,0 +1,118 @@
+//! 종목별 거래 상태 (거래정지, 변동성 완화장치).
+//!
+//! 한국거래소의 종목 거래정지와 VI(변동성 완화장치) 발동/해제를 표현합니다.
+//!
+//! - 거래정지: 주문 접수 자체가 불가하므로 모든 신규 주문을 보류합니다.
+//! - VI: 2분(정적 VI는 2분 + 임의 연장)간 단일가 매매로 전환됩니다. 지정가 주문은
+//!   단일가 호가에 참여할 수 있지만, 시장가 주문은 체결가를 예측할 수 없으므로 보류합니다.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// 종목 거래 상태.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TradingStatus {
+    /// 정상 거래
+    #[default]
+    Normal,
+    /// 거래정지
+    Halted,
+    /// 정적 VI 발동 (전일 종가/직전 단일가 대비 급변)
+    StaticVi,
+    /// 동적 VI 발동 (직전 체결가 대비 급변)
+    DynamicVi,
+}
+
+impl TradingStatus {
+    /// 정상 거래가 아닌 상태인지 여부.
+    pub fn is_restricted(&self) -> bool {
+        !matches!(self, Self::Normal)
+    }
+
+    /// 거래정지 여부.
+    pub fn is_halted(&self) -> bool {
+        matches!(self, Self::Halted)
+    }
+
+    /// VI 발동 여부.
+    pub fn is_vi(&self) -> bool {
+        matches!(self, Self::StaticVi | Self::DynamicVi)
+    }
+
+    /// 주문 접수 가능 여부 (`is_market_order` = 시장가 주문).
+    pub fn allows_order(&self, is_market_order: bool) -> bool {
+        match self {
+            Self::Normal => true,
+            Self::Halted => false,
+            Self::StaticVi | Self::DynamicVi => !is_market_order,
+        }
+    }
+}
+
+impl std::fmt::Display for TradingStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Normal => write!(f, "normal"),
+            Self::Halted => write!(f, "halted"),
+            Self::StaticVi => write!(f, "static_vi"),
+            Self::DynamicVi => write!(f, "dynamic_vi"),
+        }
+    }
+}
+
+/// 종목 거래 상태 변경 이벤트.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TradingStatusEvent {
+    /// 종목코드
+    pub ticker: String,
+    /// 변경된 거래 상태
+    pub status: TradingStatus,
+    /// 사유 (거래정지 사유 등, 거래소 제공 시)
+    pub reason: Option<String>,
+    /// 수신 시각
+    pub timestamp: DateTime<Utc>,
+}
+
+impl TradingStatusEvent {
+    /// 새 이벤트 생성 (수신 시각 = 현재).
+    pub fn new(ticker: impl Into<String>, status: TradingStatus) -> Self {
+        Self {
+            ticker: ticker.into(),
+            status,
+            reason: None,
+            timestamp: Utc::now(),
+        }
+    }
+
+    /// 사유 설정.
+    pub fn with_reason(mut self, reason: impl Into<String>) -> Self {
+        let reason = reason.into();
+        self.reason = (!reason.trim().is_empty()).then(|| reason.trim().to_string());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_allows_order() {
+        assert!(TradingStatus::Normal.allows_order(true));
+        assert!(!TradingStatus::Halted.allows_order(false));
+        // VI 중에는 지정가만 허용
+        assert!(TradingStatus::DynamicVi.allows_order(false));
+        assert!(!TradingStatus::StaticVi.allows_order(true));
+    }
+
+    #[test]
+    fn test_event_reason_trimmed() {
+        let event = TradingStatusEvent::new("005930", TradingStatus::Halted).with_reason("  ");
+        assert!(event.reason.is_none());
+
+        let event = TradingStatusEvent::new("005930", TradingStatus::Halted)
+            .with_reason("불성실공시법인 지정 ");
+        assert_eq!(event.reason.as_deref(), Some("불성실공시법인 지정"));
+    }
+}
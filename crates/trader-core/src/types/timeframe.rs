@@ -75,6 +75,11 @@ impl Timeframe {
         self.as_secs() / 60
     }
 
+    /// 일봉보다 짧은 장중 타임프레임인지 확인합니다.
+    pub fn is_intraday(&self) -> bool {
+        self.as_secs() < Timeframe::D1.as_secs()
+    }
+
     /// 바이낸스 간격 문자열로 변환합니다.
     pub fn to_binance_interval(&self) -> &'static str {
         match self {
@@ -117,6 +122,53 @@ impl Timeframe {
             _ => None,
         }
     }
+
+    /// TradingView UDF 해상도 문자열로 변환합니다.
+    pub fn to_udf_resolution(&self) -> &'static str {
+        match self {
+            Timeframe::M1 => "1",
+            Timeframe::M3 => "3",
+            Timeframe::M5 => "5",
+            Timeframe::M15 => "15",
+            Timeframe::M30 => "30",
+            Timeframe::H1 => "60",
+            Timeframe::H2 => "120",
+            Timeframe::H4 => "240",
+            Timeframe::H6 => "360",
+            Timeframe::H8 => "480",
+            Timeframe::H12 => "720",
+            Timeframe::D1 => "1D",
+            Timeframe::D3 => "3D",
+            Timeframe::W1 => "1W",
+            Timeframe::MN1 => "1M",
+        }
+    }
+
+    /// TradingView UDF 해상도 문자열에서 파싱합니다.
+    ///
+    /// `D`, `W`, `M`처럼 배수가 생략된 표기도 허용합니다.
+    pub fn from_udf_resolution(s: &str) -> Option<Self> {
+        match s {
+            "D" | "1D" => Some(Timeframe::D1),
+            "W" | "1W" => Some(Timeframe::W1),
+            "M" | "1M" => Some(Timeframe::MN1),
+            "3D" => Some(Timeframe::D3),
+            _ => match s.parse::<u64>().ok()? {
+                1 => Some(Timeframe::M1),
+                3 => Some(Timeframe::M3),
+                5 => Some(Timeframe::M5),
+                15 => Some(Timeframe::M15),
+                30 => Some(Timeframe::M30),
+                60 => Some(Timeframe::H1),
+                120 => Some(Timeframe::H2),
+                240 => Some(Timeframe::H4),
+                360 => Some(Timeframe::H6),
+                480 => Some(Timeframe::H8),
+                720 => Some(Timeframe::H12),
+                _ => None,
+            },
+        }
+    }
 }
 
 impl fmt::Display for Timeframe {
@@ -142,6 +194,8 @@ mod tests {
         assert_eq!(Timeframe::M1.as_secs(), 60);
         assert_eq!(Timeframe::H1.as_secs(), 3600);
         assert_eq!(Timeframe::D1.as_secs(), 86400);
+        assert!(Timeframe::M5.is_intraday());
+        assert!(!Timeframe::D1.is_intraday());
     }
 
     #[test]
@@ -149,4 +203,34 @@ mod tests {
         assert_eq!(Timeframe::M15.to_binance_interval(), "15m");
         assert_eq!(Timeframe::from_binance_interval("4h"), Some(Timeframe::H4));
     }
+
+    #[test]
+    fn test_timeframe_udf_resolution() {
+        for tf in [
+            Timeframe::M1,
+            Timeframe::M3,
+            Timeframe::M5,
+            Timeframe::M15,
+            Timeframe::M30,
+            Timeframe::H1,
+            Timeframe::H2,
+            Timeframe::H4,
+            Timeframe::H6,
+            Timeframe::H8,
+            Timeframe::H12,
+            Timeframe::D1,
+            Timeframe::D3,
+            Timeframe::W1,
+            Timeframe::MN1,
+        ] {
+            assert_eq!(
+                Timeframe::from_udf_resolution(tf.to_udf_resolution()),
+                Some(tf)
+            );
+        }
+        assert_eq!(Timeframe::from_udf_resolution("D"), Some(Timeframe::D1));
+        assert_eq!(Timeframe::from_udf_resolution("W"), Some(Timeframe::W1));
+        assert_eq!(Timeframe::from_udf_resolution("45"), None);
+        assert_eq!(Timeframe::from_udf_resolution("1h"), None);
+    }
 }
@@ -8,6 +8,8 @@
 //! - **시장 시간 체크**: 마감 후 불필요한 API 호출 방지
 //! - **갭 감지**: 누락된 캔들 자동 감지
 //! - **증분 업데이트**: 새 데이터만 가져와 캐시
+//! - **수정주가**: `with_price_adjustment`로 분할/배당 조정 가격 반환 (기본: 원본 가격)
+//! - **합성 종목**: `SYN_<코드>` 티커는 leg 캔들로 그때그때 계산 (캐시에 저장하지 않음)
 //!
 //! # 동작 흐름
 //!
@@ -47,8 +49,10 @@
 use crate::error::{DataError, Result};
 use crate::provider::krx_api::KrxApiClient;
 use crate::provider::SymbolResolver;
+use crate::storage::corporate_action::PriceAdjustment;
 use crate::storage::krx::KrxDataSource;
 use crate::storage::ohlcv::{timeframe_to_string, OhlcvCache};
+use crate::storage::synthetic::SyntheticInstrumentStore;
 use chrono::{DateTime, Datelike, Duration, NaiveDate, TimeZone, Timelike, Utc, Weekday};
 use chrono_tz::Tz;
 use rust_decimal::Decimal;
@@ -58,7 +62,10 @@ use std::sync::Arc;
 use time::OffsetDateTime;
 use tokio::sync::RwLock;
 use tracing::{debug, error, info, instrument, warn};
-use trader_core::{CredentialEncryptor, Kline, Timeframe};
+use trader_core::{
+    compute_synthetic_klines, is_synthetic_ticker, CredentialEncryptor, Kline, SyntheticDefinition,
+    Timeframe, SYNTHETIC_TICKER_PREFIX,
+};
 
 // =============================================================================
 // 상장폐지 감지 상수 및 함수
@@ -147,24 +154,52 @@ impl CachedHistoricalDataProvider {
         }
     }
 
+    /// DB 연결 풀 조회.
+    pub fn pool(&self) -> &PgPool {
+        &self.pool
+    }
+
     /// 캐시 유효 기간 설정.
     pub fn with_freshness(mut self, duration: Duration) -> Self {
         self.cache_freshness = duration;
         self
     }
 
+    /// 가격 조정 방식 설정 (기본: 원본 가격).
+    ///
+    /// 장기 백테스트에서는 `PriceAdjustment::Adjusted`로 분할/배당 갭을 제거합니다.
+    /// 캐시에는 항상 원본 가격이 저장됩니다.
+    pub fn with_price_adjustment(mut self, adjustment: PriceAdjustment) -> Self {
+        self.cache = self.cache.with_price_adjustment(adjustment);
+        self
+    }
+
     /// 캔들 데이터 조회 (캐시 우선, 증분 업데이트).
     ///
     /// # 인자
     /// - `symbol`: canonical 심볼 (예: "005930", "AAPL", "BTC/USDT")
     ///
     /// 내부적으로 SymbolResolver를 통해 데이터 소스에 맞는 심볼로 변환합니다.
-    #[instrument(skip(self))]
+    /// `SYN_<코드>` 합성 종목은 leg 캔들로 계산합니다.
     pub async fn get_klines(
         &self,
         symbol: &str,
         timeframe: Timeframe,
         limit: usize,
+    ) -> Result<Vec<Kline>> {
+        if is_synthetic_ticker(symbol) {
+            return self.get_synthetic_klines(symbol, timeframe, limit).await;
+        }
+        self.load_klines(symbol, timeframe, limit).await
+    }
+
+    /// 일반 종목 캔들 조회 (캐시 우선, 증분 업데이트).
+    #[instrument(skip(self))]
+    async fn load_klines(
+        &self,
+        symbol: &str,
+        timeframe: Timeframe,
+        limit: usize,
     ) -> Result<Vec<Kline>> {
         // SymbolResolver를 통해 데이터 소스 심볼 조회
         let (ticker, _yahoo_symbol, _market) = self.resolve_symbol(symbol).await?;
@@ -251,6 +286,77 @@ impl CachedHistoricalDataProvider {
         Ok(klines)
     }
 
+    /// 합성 종목 정의 조회.
+    async fn synthetic_definition(&self, symbol: &str) -> Result<SyntheticDefinition> {
+        let code = symbol.trim_start_matches(SYNTHETIC_TICKER_PREFIX);
+        SyntheticInstrumentStore::new(self.pool.clone())
+            .get(code)
+            .await?
+            .ok_or_else(|| {
+                DataError::NotFound(format!("합성 종목을 찾을 수 없습니다: {}", symbol))
+            })?
+            .definition()
+    }
+
+    /// 합성 종목 캔들 계산.
+    ///
+    /// leg별로 캔들을 조회한 뒤 시작 시각 기준으로 결합합니다. leg 간 휴장일 차이로
+    /// 빠지는 캔들을 감안해 요청 수보다 조금 더 조회하고 최근 `limit`개만 반환합니다.
+    #[instrument(skip(self))]
+    async fn get_synthetic_klines(
+        &self,
+        symbol: &str,
+        timeframe: Timeframe,
+        limit: usize,
+    ) -> Result<Vec<Kline>> {
+        let definition = self.synthetic_definition(symbol).await?;
+        let fetch_limit = limit + limit / 5 + 5;
+
+        let mut leg_klines = HashMap::new();
+        for leg in definition.expression.tickers() {
+            let klines = self.load_klines(&leg, timeframe, fetch_limit).await?;
+            leg_klines.insert(leg, klines);
+        }
+
+        let mut klines = compute_synthetic_klines(symbol, &definition.expression, &leg_klines);
+        if klines.len() > limit {
+            klines.drain(..klines.len() - limit);
+        }
+        debug!(
+            canonical = %symbol,
+            expression = %definition.expression,
+            returned = klines.len(),
+            "합성 종목 캔들 계산"
+        );
+        Ok(klines)
+    }
+
+    /// 합성 종목 날짜 범위 캔들 계산.
+    #[instrument(skip(self))]
+    async fn get_synthetic_klines_range(
+        &self,
+        symbol: &str,
+        timeframe: Timeframe,
+        start_date: NaiveDate,
+        end_date: NaiveDate,
+    ) -> Result<Vec<Kline>> {
+        let definition = self.synthetic_definition(symbol).await?;
+
+        let mut leg_klines = HashMap::new();
+        for leg in definition.expression.tickers() {
+            let klines = self
+                .load_klines_range(&leg, timeframe, start_date, end_date)
+                .await?;
+            leg_klines.insert(leg, klines);
+        }
+
+        Ok(compute_synthetic_klines(
+            symbol,
+            &definition.expression,
+            &leg_klines,
+        ))
+    }
+
     /// 심볼 정보 조회.
     ///
     /// DB의 symbol_info 테이블에서 조회:
@@ -288,14 +394,31 @@ impl CachedHistoricalDataProvider {
     /// 지정된 기간의 캔들 데이터 (캐시에 저장됨)
     ///
     /// # 인자
-    /// - `symbol`: canonical 심볼 (예: "005930", "AAPL", "BTC/USDT")
-    #[instrument(skip(self))]
+    /// - `symbol`: canonical 심볼 (예: "005930", "AAPL", "BTC/USDT", 합성 종목 "SYN_<코드>")
     pub async fn get_klines_range(
         &self,
         symbol: &str,
         timeframe: Timeframe,
         start_date: NaiveDate,
         end_date: NaiveDate,
+    ) -> Result<Vec<Kline>> {
+        if is_synthetic_ticker(symbol) {
+            return self
+                .get_synthetic_klines_range(symbol, timeframe, start_date, end_date)
+                .await;
+        }
+        self.load_klines_range(symbol, timeframe, start_date, end_date)
+            .await
+    }
+
+    /// 일반 종목 날짜 범위 캔들 조회.
+    #[instrument(skip(self))]
+    async fn load_klines_range(
+        &self,
+        symbol: &str,
+        timeframe: Timeframe,
+        start_date: NaiveDate,
+        end_date: NaiveDate,
     ) -> Result<Vec<Kline>> {
         // SymbolResolver를 통해 데이터 소스 심볼 조회
         let (ticker, _yahoo_symbol, _market) = self.resolve_symbol(symbol).await?;
@@ -453,6 +576,15 @@ impl CachedHistoricalDataProvider {
             "날짜 범위 데이터 캐시 완료"
         );
 
+        // 수정주가 모드는 저장된 원본에 조정을 적용해 반환
+        let raw_klines = if self.cache.price_adjustment() == PriceAdjustment::Adjusted {
+            self.cache
+                .get_cached_klines_range(&ticker, timeframe, start_dt, end_dt)
+                .await?
+        } else {
+            raw_klines
+        };
+
         // 4. canonical 심볼로 Kline 변환
         // Symbol 생성자를 통해 country 필드 자동 추론
         let klines: Vec<Kline> = raw_klines
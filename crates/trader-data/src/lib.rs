@@ -5,6 +5,7 @@
 //! - TimescaleDB 저장소
 //! - Redis 캐싱
 //! - OHLCV 캔들 데이터 캐싱 (증분 업데이트 지원)
+//! - 기업 행위(분할/배당/종목코드 변경) 기반 수정주가
 //! - 데이터 가져오기 유틸리티
 
 pub mod cache;
@@ -29,6 +30,33 @@ pub use storage::timescale::{
 pub use cache::historical::{CacheStats as HistoricalCacheStats, CachedHistoricalDataProvider};
 pub use storage::ohlcv::{OhlcvCache, OhlcvMetadataRecord, OhlcvRecord};
 
+// 매크로 시계열 저장소 재내보내기
+pub use storage::macro_series::{MacroSeriesPoint, MacroSeriesStore};
+
+// 투자자별 순매수 저장소 재내보내기
+pub use storage::investor_flow::InvestorFlowStore;
+
+// ETF NAV/구성종목 저장소 재내보내기
+pub use storage::etf::{EtfPremiumRecord, EtfStore};
+
+// 사용자 정의 지수 저장소 재내보내기
+pub use storage::custom_index::{CustomIndexBuildResult, CustomIndexRecord, CustomIndexStore};
+
+// 합성 종목(스프레드/비율) 저장소 재내보내기
+pub use storage::synthetic::{SyntheticInstrumentRecord, SyntheticInstrumentStore};
+
+// 기업 행위(분할/배당/종목코드 변경) 저장소 재내보내기
+pub use storage::corporate_action::{
+    apply_adjustments, CorporateAction, CorporateActionKind, CorporateActionStore, PriceAdjustment,
+    TickerPredecessor,
+};
+
+// 실적 발표 일정 저장소 재내보내기
+pub use storage::earnings::EarningsCalendarStore;
+
+// 실현 변동성/베타 저장소 재내보내기
+pub use storage::symbol_risk::SymbolRiskStore;
+
 // Fundamental 데이터 수집 재내보내기
 pub use cache::fundamental::{FetchResult, FundamentalData, FundamentalFetcher};
 
@@ -38,7 +66,7 @@ pub use storage::krx::KrxDataSource;
 // 심볼 정보 Provider 재내보내기
 pub use provider::{
     BinanceSymbolProvider, CompositeSymbolProvider, KrxSymbolProvider, SymbolInfoProvider,
-    SymbolMetadata, SymbolResolver, YahooSymbolProvider,
+    SymbolMetadata, SymbolResolver, UpbitSymbolProvider, YahooSymbolProvider,
 };
 
 // Market Breadth 계산 재내보내기
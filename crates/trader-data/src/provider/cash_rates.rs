@@ -0,0 +1,273 @@
+//! 현금성 자산 금리 Provider.
+//!
+//! 유휴 현금 이자 모델링에 쓰이는 단기/국채 금리를 공개 API에서 수집합니다.
+//!
+//! # 데이터 소스
+//!
+//! | 시리즈 | 소스 | 인증 |
+//! |--------|------|------|
+//! | CD 91일, 국고채 3년 | 한국은행 ECOS `817Y002` (시장금리 일별) | `ECOS_API_KEY` |
+//! | 미국 T-bill 3개월 | FRED `DTB3` (CSV) | 불필요 |
+//!
+//! 국내 금리는 API 키가 없으면 수집하지 않습니다. 모든 값은 연 %입니다.
+//!
+//! # 사용 예제
+//!
+//! ```rust,ignore
+//! use trader_core::CashRateSeries;
+//! use trader_data::provider::cash_rates::{CashRateConfig, CashRateProvider};
+//!
+//! let provider = CashRateProvider::new(CashRateConfig::from_env());
+//! let rates = provider.fetch_series(CashRateSeries::UsTbill3m, start, end).await?;
+//! ```
+
+use chrono::NaiveDate;
+use serde::Deserialize;
+use std::time::Duration;
+use tracing::debug;
+use trader_core::CashRateSeries;
+
+const ECOS_URL: &str = "https://ecos.bok.or.kr/api/StatisticSearch";
+pub(crate) const FRED_CSV_URL: &str = "https://fred.stlouisfed.org/graph/fredgraph.csv";
+
+/// ECOS 시장금리(일별) 통계표 코드
+const ECOS_MARKET_RATE_TABLE: &str = "817Y002";
+/// ECOS 1회 조회 최대 건수
+const ECOS_MAX_ROWS: usize = 10000;
+
+/// 현금 금리 수집 설정.
+#[derive(Debug, Clone, Default)]
+pub struct CashRateConfig {
+    /// 한국은행 ECOS API 키 (없으면 국내 금리 미수집)
+    pub ecos_api_key: Option<String>,
+}
+
+impl CashRateConfig {
+    /// 환경변수에서 설정 로드.
+    ///
+    /// - `ECOS_API_KEY`: 한국은행 ECOS Open API 키 (선택)
+    pub fn from_env() -> Self {
+        Self {
+            ecos_api_key: std::env::var("ECOS_API_KEY")
+                .ok()
+                .filter(|key| !key.is_empty()),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct EcosResponse {
+    #[serde(rename = "StatisticSearch")]
+    statistic_search: Option<EcosStatisticSearch>,
+    #[serde(rename = "RESULT")]
+    result: Option<EcosResult>,
+}
+
+#[derive(Debug, Deserialize)]
+struct EcosStatisticSearch {
+    row: Vec<EcosRow>,
+}
+
+#[derive(Debug, Deserialize)]
+struct EcosRow {
+    #[serde(rename = "TIME")]
+    time: String,
+    #[serde(rename = "DATA_VALUE")]
+    data_value: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct EcosResult {
+    #[serde(rename = "CODE")]
+    code: String,
+    #[serde(rename = "MESSAGE")]
+    message: String,
+}
+
+/// 현금 금리 Provider.
+pub struct CashRateProvider {
+    client: reqwest::Client,
+    config: CashRateConfig,
+}
+
+impl CashRateProvider {
+    /// 새 Provider 생성.
+    pub fn new(config: CashRateConfig) -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(20))
+            .user_agent("ZeroQuant/1.0")
+            .build()
+            .unwrap_or_default();
+        Self { client, config }
+    }
+
+    /// 수집 가능한 시리즈 (국내 금리는 ECOS 키 필요).
+    pub fn available_series(&self) -> Vec<CashRateSeries> {
+        CashRateSeries::ALL
+            .into_iter()
+            .filter(|s| ecos_item_code(*s).is_none() || self.config.ecos_api_key.is_some())
+            .collect()
+    }
+
+    /// 기간 내 일별 금리 조회 (일자, 연 %, 오래된 순).
+    pub async fn fetch_series(
+        &self,
+        series: CashRateSeries,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<Vec<(NaiveDate, f64)>, String> {
+        let rates = match ecos_item_code(series) {
+            Some(item) => self.fetch_ecos(item, start, end).await?,
+            None => self.fetch_fred("DTB3", start, end).await?,
+        };
+        debug!(
+            series = series.series_key(),
+            count = rates.len(),
+            "현금 금리 수집"
+        );
+        Ok(rates)
+    }
+
+    /// 한국은행 ECOS 시장금리(일별) 조회.
+    async fn fetch_ecos(
+        &self,
+        item: &str,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<Vec<(NaiveDate, f64)>, String> {
+        let api_key = self
+            .config
+            .ecos_api_key
+            .as_deref()
+            .ok_or_else(|| "ECOS_API_KEY가 설정되지 않았습니다".to_string())?;
+
+        let url = format!(
+            "{ECOS_URL}/{api_key}/json/kr/1/{ECOS_MAX_ROWS}/{ECOS_MARKET_RATE_TABLE}/D/{}/{}/{item}",
+            start.format("%Y%m%d"),
+            end.format("%Y%m%d"),
+        );
+        let body = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .and_then(|r| r.error_for_status())
+            .map_err(|e| format!("ECOS 요청 실패: {e}"))?
+            .text()
+            .await
+            .map_err(|e| format!("ECOS 응답 읽기 실패: {e}"))?;
+
+        parse_ecos_rates(&body)
+    }
+
+    /// FRED 시리즈 CSV 조회.
+    async fn fetch_fred(
+        &self,
+        series_id: &str,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<Vec<(NaiveDate, f64)>, String> {
+        let body = self
+            .client
+            .get(FRED_CSV_URL)
+            .query(&[
+                ("id", series_id.to_string()),
+                ("cosd", start.to_string()),
+                ("coed", end.to_string()),
+            ])
+            .send()
+            .await
+            .and_then(|r| r.error_for_status())
+            .map_err(|e| format!("FRED 요청 실패: {e}"))?
+            .text()
+            .await
+            .map_err(|e| format!("FRED 응답 읽기 실패: {e}"))?;
+
+        Ok(parse_fred_csv(&body))
+    }
+}
+
+/// 시리즈별 ECOS 항목 코드 (ECOS 대상이 아니면 None).
+fn ecos_item_code(series: CashRateSeries) -> Option<&'static str> {
+    match series {
+        CashRateSeries::KrCd91d => Some("010502000"),
+        CashRateSeries::KrKtb3y => Some("010200000"),
+        CashRateSeries::UsTbill3m => None,
+    }
+}
+
+/// ECOS 응답 파싱. 데이터 없음(`INFO-200`)은 빈 결과로 처리합니다.
+fn parse_ecos_rates(body: &str) -> Result<Vec<(NaiveDate, f64)>, String> {
+    let response: EcosResponse =
+        serde_json::from_str(body).map_err(|e| format!("ECOS 응답 파싱 실패: {e}"))?;
+
+    if let Some(result) = response.result {
+        if result.code == "INFO-200" {
+            return Ok(Vec::new());
+        }
+        return Err(format!("ECOS 오류 {}: {}", result.code, result.message));
+    }
+
+    let mut rates: Vec<(NaiveDate, f64)> = response
+        .statistic_search
+        .map(|s| s.row)
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|row| {
+            let date = NaiveDate::parse_from_str(&row.time, "%Y%m%d").ok()?;
+            let value = row.data_value.trim().parse::<f64>().ok()?;
+            Some((date, value))
+        })
+        .collect();
+    rates.sort_by_key(|(date, _)| *date);
+    Ok(rates)
+}
+
+/// FRED CSV 파싱 (헤더 1줄, 결측값 `.`은 건너뜀).
+pub(crate) fn parse_fred_csv(body: &str) -> Vec<(NaiveDate, f64)> {
+    body.lines()
+        .skip(1)
+        .filter_map(|line| {
+            let (date, value) = line.split_once(',')?;
+            let date = NaiveDate::parse_from_str(date.trim(), "%Y-%m-%d").ok()?;
+            let value = value.trim().parse::<f64>().ok()?;
+            Some((date, value))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_ecos_rates() {
+        let body = r#"{"StatisticSearch":{"list_total_count":2,"row":[
+            {"STAT_CODE":"817Y002","ITEM_NAME1":"CD(91일)","UNIT_NAME":"연%","TIME":"20260304","DATA_VALUE":"2.81"},
+            {"STAT_CODE":"817Y002","ITEM_NAME1":"CD(91일)","UNIT_NAME":"연%","TIME":"20260303","DATA_VALUE":"2.80"}
+        ]}}"#;
+        let rates = parse_ecos_rates(body).unwrap();
+        assert_eq!(rates.len(), 2);
+        assert_eq!(
+            rates[0],
+            (NaiveDate::from_ymd_opt(2026, 3, 3).unwrap(), 2.80)
+        );
+
+        let empty = r#"{"RESULT":{"CODE":"INFO-200","MESSAGE":"해당하는 데이터가 없습니다."}}"#;
+        assert!(parse_ecos_rates(empty).unwrap().is_empty());
+
+        let error = r#"{"RESULT":{"CODE":"INFO-100","MESSAGE":"인증키가 유효하지 않습니다."}}"#;
+        assert!(parse_ecos_rates(error).is_err());
+    }
+
+    #[test]
+    fn test_parse_fred_csv() {
+        let body = "observation_date,DTB3\n2026-03-02,4.12\n2026-03-03,.\n2026-03-04,4.10\n";
+        let rates = parse_fred_csv(body);
+        assert_eq!(rates.len(), 2);
+        assert_eq!(
+            rates[1],
+            (NaiveDate::from_ymd_opt(2026, 3, 4).unwrap(), 4.10)
+        );
+    }
+}
@@ -0,0 +1,341 @@
+//! 암호화폐 온체인/파생상품 지표 Provider.
+//!
+//! 공개 API에서 BTC/ETH 보조 지표를 수집합니다.
+//!
+//! # 데이터 소스
+//!
+//! | 지표 | 소스 | 인증 |
+//! |------|------|------|
+//! | 무기한 선물 베이시스, 펀딩비 | Binance USDⓈ-M Futures `premiumIndex` | 불필요 |
+//! | 스테이블코인 총 공급량 | DefiLlama `stablecoincharts/all` | 불필요 |
+//! | 거래소 순유입량 | CryptoQuant `exchange-flows/netflow` | `CRYPTOQUANT_API_KEY` (선택) |
+//!
+//! 순유입량은 API 키가 없으면 수집하지 않으며, 개별 소스 실패는 해당 지표만 비워 둡니다.
+//!
+//! # 사용 예제
+//!
+//! ```rust,ignore
+//! use trader_data::provider::crypto_metrics::{CryptoMetricsConfig, CryptoMetricsProvider};
+//!
+//! let provider = CryptoMetricsProvider::new(CryptoMetricsConfig::from_env());
+//! let metrics = provider.fetch_all().await;
+//! ```
+
+use chrono::{DateTime, TimeZone, Utc};
+use serde::Deserialize;
+use std::time::Duration;
+use tracing::{debug, warn};
+use trader_core::CryptoMetrics;
+
+const BINANCE_FUTURES_URL: &str = "https://fapi.binance.com";
+const DEFILLAMA_STABLECOINS_URL: &str = "https://stablecoins.llama.fi";
+const CRYPTOQUANT_URL: &str = "https://api.cryptoquant.com";
+
+/// 암호화폐 지표 수집 설정.
+#[derive(Debug, Clone)]
+pub struct CryptoMetricsConfig {
+    /// 대상 자산 (기본: BTC, ETH)
+    pub assets: Vec<String>,
+    /// CryptoQuant API 키 (없으면 거래소 순유입량 미수집)
+    pub cryptoquant_api_key: Option<String>,
+}
+
+impl Default for CryptoMetricsConfig {
+    fn default() -> Self {
+        Self {
+            assets: vec!["BTC".to_string(), "ETH".to_string()],
+            cryptoquant_api_key: None,
+        }
+    }
+}
+
+impl CryptoMetricsConfig {
+    /// 환경변수에서 설정 로드.
+    ///
+    /// - `CRYPTO_METRICS_ASSETS`: 대상 자산 (쉼표 구분, 기본: BTC,ETH)
+    /// - `CRYPTOQUANT_API_KEY`: CryptoQuant API 키 (선택)
+    pub fn from_env() -> Self {
+        let mut config = Self::default();
+        if let Ok(assets) = std::env::var("CRYPTO_METRICS_ASSETS") {
+            let assets: Vec<String> = assets
+                .split(',')
+                .map(|s| s.trim().to_uppercase())
+                .filter(|s| !s.is_empty())
+                .collect();
+            if !assets.is_empty() {
+                config.assets = assets;
+            }
+        }
+        config.cryptoquant_api_key = std::env::var("CRYPTOQUANT_API_KEY")
+            .ok()
+            .filter(|key| !key.is_empty());
+        config
+    }
+}
+
+/// 스테이블코인 공급량 요약.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StablecoinSupply {
+    /// 총 공급량 (USD)
+    pub total_usd: f64,
+    /// 7일 변화율 (%)
+    pub change_7d_pct: Option<f64>,
+    /// 기준 시각
+    pub observed_at: DateTime<Utc>,
+}
+
+/// Binance 무기한 선물 프리미엄 인덱스 응답.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PremiumIndex {
+    mark_price: String,
+    index_price: String,
+    last_funding_rate: String,
+}
+
+/// DefiLlama 스테이블코인 차트 항목.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct StablecoinChartPoint {
+    /// Unix timestamp (초, 문자열)
+    date: String,
+    total_circulating_usd: StablecoinPegged,
+}
+
+#[derive(Debug, Deserialize)]
+struct StablecoinPegged {
+    #[serde(rename = "peggedUSD", default)]
+    pegged_usd: f64,
+}
+
+/// CryptoQuant 응답.
+#[derive(Debug, Deserialize)]
+struct CryptoQuantResponse {
+    result: CryptoQuantResult,
+}
+
+#[derive(Debug, Deserialize)]
+struct CryptoQuantResult {
+    data: Vec<CryptoQuantNetflow>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CryptoQuantNetflow {
+    netflow_total: f64,
+}
+
+/// 암호화폐 지표 Provider.
+pub struct CryptoMetricsProvider {
+    client: reqwest::Client,
+    config: CryptoMetricsConfig,
+}
+
+impl CryptoMetricsProvider {
+    /// 새 Provider 생성.
+    pub fn new(config: CryptoMetricsConfig) -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(15))
+            .user_agent("ZeroQuant/1.0")
+            .build()
+            .unwrap_or_default();
+        Self { client, config }
+    }
+
+    /// 대상 자산 전체 지표 수집.
+    ///
+    /// 스테이블코인 공급량은 한 번 조회하여 모든 자산에 적용합니다.
+    pub async fn fetch_all(&self) -> Vec<CryptoMetrics> {
+        let stablecoins = match self.fetch_stablecoin_supply().await {
+            Ok(supply) => Some(supply),
+            Err(e) => {
+                warn!(error = %e, "스테이블코인 공급량 조회 실패");
+                None
+            }
+        };
+
+        let mut results = Vec::with_capacity(self.config.assets.len());
+        for asset in &self.config.assets {
+            let mut metrics = CryptoMetrics::new(asset.clone(), Utc::now());
+
+            if let Some(supply) = stablecoins {
+                metrics.stablecoin_supply = Some(supply.total_usd);
+                metrics.stablecoin_change_7d_pct = supply.change_7d_pct;
+            }
+
+            match self.fetch_perp_basis(asset).await {
+                Ok((basis, funding)) => {
+                    metrics.perp_basis_pct = Some(basis);
+                    metrics.funding_rate_pct = Some(funding);
+                }
+                Err(e) => warn!(asset = %asset, error = %e, "선물 베이시스 조회 실패"),
+            }
+
+            if self.config.cryptoquant_api_key.is_some() {
+                match self.fetch_exchange_netflow(asset).await {
+                    Ok(netflow) => metrics.exchange_netflow = Some(netflow),
+                    Err(e) => warn!(asset = %asset, error = %e, "거래소 순유입량 조회 실패"),
+                }
+            }
+
+            debug!(asset = %asset, score = metrics.score(), "암호화폐 지표 수집");
+            results.push(metrics);
+        }
+
+        results
+    }
+
+    /// 무기한 선물 베이시스(%)와 펀딩비(%) 조회.
+    pub async fn fetch_perp_basis(&self, asset: &str) -> Result<(f64, f64), String> {
+        let url = format!("{BINANCE_FUTURES_URL}/fapi/v1/premiumIndex");
+        let index: PremiumIndex = self
+            .client
+            .get(&url)
+            .query(&[("symbol", format!("{asset}USDT"))])
+            .send()
+            .await
+            .and_then(|r| r.error_for_status())
+            .map_err(|e| format!("Binance 요청 실패: {e}"))?
+            .json()
+            .await
+            .map_err(|e| format!("Binance 응답 파싱 실패: {e}"))?;
+
+        parse_premium_index(&index)
+    }
+
+    /// 스테이블코인 총 공급량 조회.
+    pub async fn fetch_stablecoin_supply(&self) -> Result<StablecoinSupply, String> {
+        let url = format!("{DEFILLAMA_STABLECOINS_URL}/stablecoincharts/all");
+        let points: Vec<StablecoinChartPoint> = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .and_then(|r| r.error_for_status())
+            .map_err(|e| format!("DefiLlama 요청 실패: {e}"))?
+            .json()
+            .await
+            .map_err(|e| format!("DefiLlama 응답 파싱 실패: {e}"))?;
+
+        summarize_stablecoin_supply(&points).ok_or_else(|| "스테이블코인 데이터 없음".to_string())
+    }
+
+    /// 거래소 순유입량 조회 (최근 1일).
+    pub async fn fetch_exchange_netflow(&self, asset: &str) -> Result<f64, String> {
+        let api_key = self
+            .config
+            .cryptoquant_api_key
+            .as_deref()
+            .ok_or_else(|| "CRYPTOQUANT_API_KEY가 설정되지 않았습니다".to_string())?;
+
+        let url = format!(
+            "{CRYPTOQUANT_URL}/v1/{}/exchange-flows/netflow",
+            asset.to_lowercase()
+        );
+        let response: CryptoQuantResponse = self
+            .client
+            .get(&url)
+            .bearer_auth(api_key)
+            .query(&[
+                ("exchange", "all_exchange"),
+                ("window", "day"),
+                ("limit", "1"),
+            ])
+            .send()
+            .await
+            .and_then(|r| r.error_for_status())
+            .map_err(|e| format!("CryptoQuant 요청 실패: {e}"))?
+            .json()
+            .await
+            .map_err(|e| format!("CryptoQuant 응답 파싱 실패: {e}"))?;
+
+        response
+            .result
+            .data
+            .first()
+            .map(|d| d.netflow_total)
+            .ok_or_else(|| "CryptoQuant 데이터 없음".to_string())
+    }
+}
+
+/// 프리미엄 인덱스에서 베이시스(%)와 펀딩비(%) 계산.
+fn parse_premium_index(index: &PremiumIndex) -> Result<(f64, f64), String> {
+    let parse = |field: &str, value: &str| {
+        value
+            .parse::<f64>()
+            .map_err(|_| format!("{field} 파싱 실패: {value}"))
+    };
+    let mark = parse("markPrice", &index.mark_price)?;
+    let spot = parse("indexPrice", &index.index_price)?;
+    let funding = parse("lastFundingRate", &index.last_funding_rate)?;
+
+    if spot <= 0.0 {
+        return Err("indexPrice가 0입니다".to_string());
+    }
+
+    Ok(((mark / spot - 1.0) * 100.0, funding * 100.0))
+}
+
+/// 일별 스테이블코인 차트에서 최신 공급량과 7일 변화율 계산.
+fn summarize_stablecoin_supply(points: &[StablecoinChartPoint]) -> Option<StablecoinSupply> {
+    let latest = points.last()?;
+    let observed_at = latest
+        .date
+        .parse::<i64>()
+        .ok()
+        .and_then(|ts| Utc.timestamp_opt(ts, 0).single())?;
+    let total_usd = latest.total_circulating_usd.pegged_usd;
+
+    let change_7d_pct = points
+        .len()
+        .checked_sub(8)
+        .map(|idx| points[idx].total_circulating_usd.pegged_usd)
+        .filter(|prev| *prev > 0.0)
+        .map(|prev| (total_usd / prev - 1.0) * 100.0);
+
+    Some(StablecoinSupply {
+        total_usd,
+        change_7d_pct,
+        observed_at,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_premium_index() {
+        let index: PremiumIndex = serde_json::from_str(
+            r#"{"symbol":"BTCUSDT","markPrice":"60300.00","indexPrice":"60000.00",
+                "lastFundingRate":"0.00010000","nextFundingTime":0}"#,
+        )
+        .unwrap();
+        let (basis, funding) = parse_premium_index(&index).unwrap();
+        assert!((basis - 0.5).abs() < 1e-9);
+        assert!((funding - 0.01).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_summarize_stablecoin_supply() {
+        let points: Vec<StablecoinChartPoint> = (0..10)
+            .map(|i| StablecoinChartPoint {
+                date: (1_700_000_000 + i * 86_400).to_string(),
+                total_circulating_usd: StablecoinPegged {
+                    pegged_usd: 100.0 + i as f64,
+                },
+            })
+            .collect();
+
+        let supply = summarize_stablecoin_supply(&points).unwrap();
+        assert_eq!(supply.total_usd, 109.0);
+        // 7일 전 = 102
+        assert!((supply.change_7d_pct.unwrap() - (109.0 / 102.0 - 1.0) * 100.0).abs() < 1e-9);
+        assert_eq!(supply.observed_at.timestamp(), 1_700_000_000 + 9 * 86_400);
+
+        assert!(summarize_stablecoin_supply(&points[..3])
+            .unwrap()
+            .change_7d_pct
+            .is_none());
+        assert!(summarize_stablecoin_supply(&[]).is_none());
+    }
+}
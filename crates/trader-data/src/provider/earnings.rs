@@ -0,0 +1,366 @@
+//! 실적 발표 일정 Provider.
+//!
+//! 실적 발표 전후 진입 차단/청산 필터에 쓰이는 종목별 실적 발표 예정일을 수집합니다.
+//!
+//! # 데이터 소스
+//!
+//! | 시장 | 소스 | 인증 | 일정 |
+//! |------|------|------|------|
+//! | US | Nasdaq earnings calendar (일자별 JSON) | 불필요 | 확정/예정 |
+//! | KR | DART 공시검색 `영업(잠정)실적(공정공시)` | `DART_API_KEY` | 전년 동기 발표일 기반 추정 |
+//!
+//! 국내 종목은 발표 예정일이 미리 공시되지 않으므로, 전년 같은 시기의 잠정실적 공시일에
+//! 364일(같은 요일)을 더한 날을 예정일로 추정하고 `confirmed = false`로 저장합니다.
+//! API 키가 없으면 국내 일정은 수집하지 않습니다.
+//!
+//! # 사용 예제
+//!
+//! ```rust,ignore
+//! use trader_data::provider::earnings::{EarningsCalendarConfig, EarningsCalendarProvider};
+//!
+//! let provider = EarningsCalendarProvider::new(EarningsCalendarConfig::from_env());
+//! let events = provider.fetch_us_date(date).await?;
+//! ```
+
+use chrono::{Duration as ChronoDuration, NaiveDate};
+use serde::Deserialize;
+use std::time::Duration;
+use tracing::debug;
+use trader_core::{EarningsEvent, EarningsTiming};
+
+const NASDAQ_EARNINGS_URL: &str = "https://api.nasdaq.com/api/calendar/earnings";
+const DART_LIST_URL: &str = "https://opendart.fss.or.kr/api/list.json";
+
+/// DART 공시 상세유형: 공정공시
+const DART_FAIR_DISCLOSURE: &str = "I002";
+/// DART 1회 조회 최대 건수
+const DART_PAGE_COUNT: u32 = 100;
+/// 잠정실적 공시 보고서명 키워드
+const DART_PRELIMINARY_EARNINGS: &str = "영업(잠정)실적";
+/// 전년 동기 발표일 → 올해 예정일 (52주, 같은 요일)
+const PROJECTION_DAYS: i64 = 364;
+
+/// 실적 발표 일정 수집 설정.
+#[derive(Debug, Clone, Default)]
+pub struct EarningsCalendarConfig {
+    /// DART Open API 키 (없으면 국내 일정 미수집)
+    pub dart_api_key: Option<String>,
+}
+
+impl EarningsCalendarConfig {
+    /// 환경변수에서 설정 로드.
+    ///
+    /// - `DART_API_KEY`: 금융감독원 DART Open API 키 (선택)
+    pub fn from_env() -> Self {
+        Self {
+            dart_api_key: std::env::var("DART_API_KEY")
+                .ok()
+                .filter(|key| !key.is_empty()),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct NasdaqResponse {
+    data: Option<NasdaqData>,
+}
+
+#[derive(Debug, Deserialize)]
+struct NasdaqData {
+    rows: Option<Vec<NasdaqRow>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct NasdaqRow {
+    symbol: String,
+    #[serde(default)]
+    time: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DartListResponse {
+    status: String,
+    message: String,
+    #[serde(default)]
+    total_page: u32,
+    #[serde(default)]
+    list: Vec<DartDisclosure>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DartDisclosure {
+    stock_code: String,
+    report_nm: String,
+    rcept_dt: String,
+}
+
+/// 실적 발표 일정 Provider.
+pub struct EarningsCalendarProvider {
+    client: reqwest::Client,
+    config: EarningsCalendarConfig,
+}
+
+impl EarningsCalendarProvider {
+    /// 새 Provider 생성.
+    pub fn new(config: EarningsCalendarConfig) -> Self {
+        // Nasdaq API는 브라우저 User-Agent가 아니면 응답하지 않음
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(20))
+            .user_agent("Mozilla/5.0 (compatible; ZeroQuant/1.0)")
+            .build()
+            .unwrap_or_default();
+        Self { client, config }
+    }
+
+    /// 국내 일정 수집 가능 여부 (DART 키 필요).
+    pub fn has_kr_source(&self) -> bool {
+        self.config.dart_api_key.is_some()
+    }
+
+    /// 특정 일자의 미국 실적 발표 종목 조회.
+    pub async fn fetch_us_date(&self, date: NaiveDate) -> Result<Vec<EarningsEvent>, String> {
+        let body = self
+            .client
+            .get(NASDAQ_EARNINGS_URL)
+            .query(&[("date", date.to_string())])
+            .header("Accept", "application/json")
+            .send()
+            .await
+            .and_then(|r| r.error_for_status())
+            .map_err(|e| format!("Nasdaq 요청 실패: {e}"))?
+            .text()
+            .await
+            .map_err(|e| format!("Nasdaq 응답 읽기 실패: {e}"))?;
+
+        let events = parse_nasdaq_earnings(&body, date)?;
+        debug!(date = %date, count = events.len(), "미국 실적 발표 일정 수집");
+        Ok(events)
+    }
+
+    /// 기간 내 국내 실적 발표 예정일 추정.
+    ///
+    /// 전년 같은 기간(`from`/`to`에서 364일 전)의 잠정실적 공시를 조회하여
+    /// 종목별 공시일에 364일을 더한 날을 예정일로 반환합니다.
+    pub async fn fetch_kr_projected(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<Vec<EarningsEvent>, String> {
+        let api_key = self
+            .config
+            .dart_api_key
+            .as_deref()
+            .ok_or_else(|| "DART_API_KEY가 설정되지 않았습니다".to_string())?;
+
+        let begin = from - ChronoDuration::days(PROJECTION_DAYS);
+        let end = to - ChronoDuration::days(PROJECTION_DAYS);
+
+        let mut disclosures = Vec::new();
+        let mut page_no = 1;
+        loop {
+            let body = self
+                .client
+                .get(DART_LIST_URL)
+                .query(&[
+                    ("crtfc_key", api_key.to_string()),
+                    ("bgn_de", begin.format("%Y%m%d").to_string()),
+                    ("end_de", end.format("%Y%m%d").to_string()),
+                    ("pblntf_detail_ty", DART_FAIR_DISCLOSURE.to_string()),
+                    ("page_no", page_no.to_string()),
+                    ("page_count", DART_PAGE_COUNT.to_string()),
+                ])
+                .send()
+                .await
+                .and_then(|r| r.error_for_status())
+                .map_err(|e| format!("DART 요청 실패: {e}"))?
+                .text()
+                .await
+                .map_err(|e| format!("DART 응답 읽기 실패: {e}"))?;
+
+            let (page, total_page) = parse_dart_earnings_disclosures(&body)?;
+            disclosures.extend(page);
+            if page_no >= total_page {
+                break;
+            }
+            page_no += 1;
+        }
+
+        let events = project_kr_earnings(&disclosures, from, to);
+        debug!(
+            disclosures = disclosures.len(),
+            projected = events.len(),
+            "국내 실적 발표 예정일 추정"
+        );
+        Ok(events)
+    }
+}
+
+/// Nasdaq 실적 발표 일정 응답 파싱 (휴장일은 `rows`가 null).
+fn parse_nasdaq_earnings(body: &str, date: NaiveDate) -> Result<Vec<EarningsEvent>, String> {
+    let response: NasdaqResponse =
+        serde_json::from_str(body).map_err(|e| format!("Nasdaq 응답 파싱 실패: {e}"))?;
+
+    Ok(response
+        .data
+        .and_then(|d| d.rows)
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|row| !row.symbol.trim().is_empty())
+        .map(|row| EarningsEvent {
+            ticker: row.symbol.trim().to_uppercase(),
+            market: "US".to_string(),
+            announce_date: date,
+            timing: match row.time.as_deref() {
+                Some("time-pre-market") => EarningsTiming::BeforeOpen,
+                Some("time-after-hours") => EarningsTiming::AfterClose,
+                _ => EarningsTiming::Unknown,
+            },
+            confirmed: true,
+            source: "nasdaq".to_string(),
+        })
+        .collect())
+}
+
+/// DART 공시 목록에서 상장사 잠정실적 공시 추출 (종목코드, 공시일), 전체 페이지 수.
+///
+/// 조회 결과 없음(`013`)은 빈 결과로 처리합니다.
+fn parse_dart_earnings_disclosures(body: &str) -> Result<(Vec<(String, NaiveDate)>, u32), String> {
+    let response: DartListResponse =
+        serde_json::from_str(body).map_err(|e| format!("DART 응답 파싱 실패: {e}"))?;
+
+    match response.status.as_str() {
+        "000" => {}
+        "013" => return Ok((Vec::new(), 0)),
+        code => return Err(format!("DART 오류 {}: {}", code, response.message)),
+    }
+
+    let disclosures = response
+        .list
+        .into_iter()
+        .filter(|d| !d.stock_code.trim().is_empty())
+        .filter(|d| d.report_nm.contains(DART_PRELIMINARY_EARNINGS))
+        .filter_map(|d| {
+            let date = NaiveDate::parse_from_str(&d.rcept_dt, "%Y%m%d").ok()?;
+            Some((d.stock_code.trim().to_string(), date))
+        })
+        .collect();
+    Ok((disclosures, response.total_page))
+}
+
+/// 전년 잠정실적 공시일로 기간 내 예정일 추정 (종목별 가장 이른 날짜 하나).
+fn project_kr_earnings(
+    disclosures: &[(String, NaiveDate)],
+    from: NaiveDate,
+    to: NaiveDate,
+) -> Vec<EarningsEvent> {
+    let mut projected: std::collections::BTreeMap<&str, NaiveDate> = Default::default();
+    for (ticker, disclosed) in disclosures {
+        let date = *disclosed + ChronoDuration::days(PROJECTION_DAYS);
+        if date < from || date > to {
+            continue;
+        }
+        projected
+            .entry(ticker.as_str())
+            .and_modify(|d| *d = (*d).min(date))
+            .or_insert(date);
+    }
+
+    projected
+        .into_iter()
+        .map(|(ticker, announce_date)| EarningsEvent {
+            ticker: ticker.to_string(),
+            market: "KR".to_string(),
+            announce_date,
+            timing: EarningsTiming::Unknown,
+            confirmed: false,
+            source: "dart".to_string(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_nasdaq_earnings() {
+        let date = NaiveDate::from_ymd_opt(2026, 4, 30).unwrap();
+        let body = r#"{"data":{"asOf":"Thu, Apr 30, 2026","rows":[
+            {"lastYearRptDt":"5/01/2025","time":"time-after-hours","symbol":"AAPL","name":"Apple Inc."},
+            {"lastYearRptDt":"4/24/2025","time":"time-pre-market","symbol":"MA","name":"Mastercard"},
+            {"lastYearRptDt":"N/A","time":"time-not-supplied","symbol":"XYZ","name":"Block"}
+        ]},"message":null,"status":{"rCode":200}}"#;
+
+        let events = parse_nasdaq_earnings(body, date).unwrap();
+        assert_eq!(events.len(), 3);
+        assert_eq!(events[0].ticker, "AAPL");
+        assert_eq!(events[0].timing, EarningsTiming::AfterClose);
+        assert_eq!(events[1].timing, EarningsTiming::BeforeOpen);
+        assert_eq!(events[2].timing, EarningsTiming::Unknown);
+        assert!(events.iter().all(|e| e.confirmed && e.announce_date == date));
+
+        let holiday = r#"{"data":{"asOf":"Sat, May 2, 2026","rows":null},"message":null}"#;
+        assert!(parse_nasdaq_earnings(holiday, date).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_parse_dart_earnings_disclosures() {
+        let body = r#"{"status":"000","message":"정상","page_no":1,"page_count":100,
+            "total_count":3,"total_page":1,"list":[
+            {"corp_code":"00126380","corp_name":"삼성전자","stock_code":"005930","corp_cls":"Y",
+             "report_nm":"연결재무제표기준영업(잠정)실적(공정공시)","rcept_no":"20250408800001","rcept_dt":"20250408"},
+            {"corp_code":"00164779","corp_name":"SK하이닉스","stock_code":"000660","corp_cls":"Y",
+             "report_nm":"기업설명회(IR)개최(안내공시)","rcept_no":"20250410800002","rcept_dt":"20250410"},
+            {"corp_code":"01234567","corp_name":"비상장사","stock_code":" ","corp_cls":"E",
+             "report_nm":"영업(잠정)실적(공정공시)","rcept_no":"20250411800003","rcept_dt":"20250411"}
+        ]}"#;
+
+        let (disclosures, total_page) = parse_dart_earnings_disclosures(body).unwrap();
+        assert_eq!(total_page, 1);
+        assert_eq!(
+            disclosures,
+            vec![(
+                "005930".to_string(),
+                NaiveDate::from_ymd_opt(2025, 4, 8).unwrap()
+            )]
+        );
+
+        let empty = r#"{"status":"013","message":"조회된 데이타가 없습니다."}"#;
+        assert!(parse_dart_earnings_disclosures(empty).unwrap().0.is_empty());
+
+        let error = r#"{"status":"010","message":"등록되지 않은 키입니다."}"#;
+        assert!(parse_dart_earnings_disclosures(error).is_err());
+    }
+
+    #[test]
+    fn test_project_kr_earnings() {
+        let from = NaiveDate::from_ymd_opt(2026, 4, 1).unwrap();
+        let to = NaiveDate::from_ymd_opt(2026, 4, 30).unwrap();
+        let disclosures = vec![
+            // 2025-04-08(화) → 2026-04-07(화)
+            (
+                "005930".to_string(),
+                NaiveDate::from_ymd_opt(2025, 4, 8).unwrap(),
+            ),
+            // 정정 공시는 가장 이른 날짜만 사용
+            (
+                "005930".to_string(),
+                NaiveDate::from_ymd_opt(2025, 4, 10).unwrap(),
+            ),
+            // 기간 밖
+            (
+                "066570".to_string(),
+                NaiveDate::from_ymd_opt(2025, 3, 20).unwrap(),
+            ),
+        ];
+
+        let events = project_kr_earnings(&disclosures, from, to);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].ticker, "005930");
+        assert_eq!(
+            events[0].announce_date,
+            NaiveDate::from_ymd_opt(2026, 4, 7).unwrap()
+        );
+        assert!(!events[0].confirmed);
+    }
+}
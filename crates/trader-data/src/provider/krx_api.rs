@@ -829,6 +829,16 @@ impl KrxApiClient {
         self.fetch_index_internal("kosdaq_dd_trd", base_date).await
     }
 
+    /// 파생상품지수 조회 (VKOSPI 등 변동성지수 포함).
+    ///
+    /// API: drvprod_dd_trd (지수 카테고리)
+    pub async fn fetch_derivative_index(
+        &self,
+        base_date: &str,
+    ) -> Result<Vec<KrxIndexInfo>, Box<dyn std::error::Error + Send + Sync>> {
+        self.fetch_index_internal("drvprod_dd_trd", base_date).await
+    }
+
     /// 지수 조회 내부 구현.
     async fn fetch_index_internal(
         &self,
@@ -0,0 +1,106 @@
+//! 매크로 레짐 지표 Provider.
+//!
+//! 매크로 레짐 종합 지표의 원천 시계열을 FRED CSV로 수집합니다 (인증 불필요).
+//!
+//! | 지표 | FRED 시리즈 | 단위 |
+//! |------|-------------|------|
+//! | USD/KRW 환율 | `DEXKOUS` | 원/달러 |
+//! | 미국 10년-2년 금리차 | `T10Y2Y` | %p |
+//! | VIX | `VIXCLS` | 포인트 |
+//!
+//! VKOSPI는 KRX Open API 파생상품지수(`KrxApiClient::fetch_derivative_index`)에서 조회합니다.
+//!
+//! # 사용 예제
+//!
+//! ```rust,ignore
+//! use trader_data::provider::macro_indicators::{MacroIndicator, MacroIndicatorProvider};
+//!
+//! let provider = MacroIndicatorProvider::new();
+//! let vix = provider.fetch(MacroIndicator::Vix, start, end).await?;
+//! ```
+
+use chrono::NaiveDate;
+use std::time::Duration;
+use tracing::debug;
+
+use super::cash_rates::{parse_fred_csv, FRED_CSV_URL};
+
+/// 매크로 레짐 원천 지표.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MacroIndicator {
+    /// USD/KRW 환율
+    UsdKrw,
+    /// 미국 10년-2년 국채 금리차
+    YieldCurveSlope,
+    /// VIX
+    Vix,
+}
+
+impl MacroIndicator {
+    /// 전체 지표
+    pub const ALL: [MacroIndicator; 3] = [Self::UsdKrw, Self::YieldCurveSlope, Self::Vix];
+
+    /// FRED 시리즈 ID.
+    pub fn fred_series_id(&self) -> &'static str {
+        match self {
+            Self::UsdKrw => "DEXKOUS",
+            Self::YieldCurveSlope => "T10Y2Y",
+            Self::Vix => "VIXCLS",
+        }
+    }
+}
+
+/// 매크로 레짐 지표 Provider.
+pub struct MacroIndicatorProvider {
+    client: reqwest::Client,
+}
+
+impl Default for MacroIndicatorProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MacroIndicatorProvider {
+    /// 새 Provider 생성.
+    pub fn new() -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(20))
+            .user_agent("ZeroQuant/1.0")
+            .build()
+            .unwrap_or_default();
+        Self { client }
+    }
+
+    /// 기간 내 일별 관측값 조회 (일자, 값, 오래된 순, 결측일 제외).
+    pub async fn fetch(
+        &self,
+        indicator: MacroIndicator,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<Vec<(NaiveDate, f64)>, String> {
+        let body = self
+            .client
+            .get(FRED_CSV_URL)
+            .query(&[
+                ("id", indicator.fred_series_id().to_string()),
+                ("cosd", start.to_string()),
+                ("coed", end.to_string()),
+            ])
+            .send()
+            .await
+            .and_then(|r| r.error_for_status())
+            .map_err(|e| format!("FRED 요청 실패: {e}"))?
+            .text()
+            .await
+            .map_err(|e| format!("FRED 응답 읽기 실패: {e}"))?;
+
+        let values = parse_fred_csv(&body);
+        debug!(
+            series = indicator.fred_series_id(),
+            count = values.len(),
+            "매크로 지표 수집"
+        );
+        Ok(values)
+    }
+}
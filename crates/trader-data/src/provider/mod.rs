@@ -12,19 +12,44 @@
 //! - 시가총액, PER/PBR, ROE, 섹터, KOSPI/KOSDAQ/ETF 구분
 //! - 국내 주식 펀더멘털 데이터 수집
 //!
+//! ## 암호화폐 지표
+//! - `CryptoMetricsProvider`: BTC/ETH 온체인/파생상품 지표 (선택)
+//! - 선물 베이시스/펀딩비 (Binance), 스테이블코인 공급량 (DefiLlama), 거래소 순유입량 (CryptoQuant)
+//!
+//! ## 현금 금리
+//! - `CashRateProvider`: CD 91일/국고채 3년 (한국은행 ECOS), 미국 T-bill 3개월 (FRED)
+//! - 백테스트/자산배분 전략의 유휴 현금 이자 모델링용
+//!
+//! ## 매크로 레짐 지표
+//! - `MacroIndicatorProvider`: USD/KRW, 미국 10년-2년 금리차, VIX (FRED)
+//! - 매크로 레짐 종합 지표 계산용
+//!
+//! ## 실적 발표 일정
+//! - `EarningsCalendarProvider`: 미국 실적 발표 일정 (Nasdaq), 국내 예정일 추정 (DART 잠정실적 공시)
+//! - 실적 발표 전후 진입 차단/청산 필터용
+//!
 //! ## 심볼 정보 Provider
 //! - `KrxSymbolProvider`: 한국거래소(KRX) 종목 정보
 //! - `BinanceSymbolProvider`: Binance 암호화폐 종목 정보
+//! - `UpbitSymbolProvider`: Upbit 원화 마켓 암호화폐 종목 정보
 //! - `YahooSymbolProvider`: Yahoo Finance 미국/글로벌 주식 정보
 //! - `CompositeSymbolProvider`: 모든 Provider 통합
 
+pub mod cash_rates;
+pub mod crypto_metrics;
+pub mod earnings;
 pub mod krx_api;
+pub mod macro_indicators;
 pub mod naver;
 pub mod symbol_info;
 
+pub use cash_rates::{CashRateConfig, CashRateProvider};
+pub use crypto_metrics::{CryptoMetricsConfig, CryptoMetricsProvider, StablecoinSupply};
+pub use earnings::{EarningsCalendarConfig, EarningsCalendarProvider};
 pub use krx_api::{KrxApiClient, KrxEtfInfo, KrxOhlcv, KrxStockInfo, KrxValuation};
+pub use macro_indicators::{MacroIndicator, MacroIndicatorProvider};
 pub use naver::{KrMarketType, NaverError, NaverFinanceFetcher, NaverFundamentalData};
 pub use symbol_info::{
     BinanceSymbolProvider, CompositeSymbolProvider, KrxSymbolProvider, SymbolInfoProvider,
-    SymbolMetadata, SymbolResolver, YahooSymbolProvider,
+    SymbolMetadata, SymbolResolver, UpbitSymbolProvider, YahooSymbolProvider,
 };
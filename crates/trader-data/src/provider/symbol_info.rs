@@ -1,6 +1,6 @@
 //! 심볼 정보 Provider.
 //!
-//! 국내(KRX), 해외(Yahoo Finance), 코인(Binance 등)의
+//! 국내(KRX), 해외(Yahoo Finance), 코인(Binance, Upbit 등)의
 //! 심볼 정보(티커, 회사명)를 제공합니다.
 
 #![allow(clippy::type_complexity)]
@@ -366,6 +366,99 @@ impl SymbolInfoProvider for BinanceSymbolProvider {
     }
 }
 
+// ==================== Upbit Provider ====================
+
+/// Upbit 심볼 정보 Provider.
+///
+/// Upbit 마켓 목록 API에서 원화(KRW) 마켓 종목 정보를 제공합니다.
+pub struct UpbitSymbolProvider;
+
+impl UpbitSymbolProvider {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for UpbitSymbolProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl SymbolInfoProvider for UpbitSymbolProvider {
+    fn name(&self) -> &str {
+        "Upbit"
+    }
+
+    fn supported_markets(&self) -> Vec<&str> {
+        vec!["CRYPTO"]
+    }
+
+    async fn fetch_all(
+        &self,
+    ) -> Result<Vec<SymbolMetadata>, Box<dyn std::error::Error + Send + Sync>> {
+        let client = reqwest::Client::new();
+
+        #[derive(Deserialize)]
+        struct UpbitMarket {
+            market: String,
+            korean_name: String,
+            english_name: String,
+        }
+
+        let response = client
+            .get("https://api.upbit.com/v1/market/all?isDetails=false")
+            .send()
+            .await?;
+
+        let data: Vec<UpbitMarket> = response.json().await?;
+
+        // 마켓 코드(KRW-BTC)를 정규화된 형식(BTC/KRW)으로 변환, 원화 마켓만 사용
+        let symbols: Vec<SymbolMetadata> = data
+            .into_iter()
+            .filter_map(|m| {
+                let base = m.market.strip_prefix("KRW-")?;
+                Some(SymbolMetadata {
+                    ticker: format!("{}/KRW", base),
+                    name: m.korean_name,
+                    name_en: Some(m.english_name),
+                    market: "CRYPTO".to_string(),
+                    exchange: Some("UPBIT".to_string()),
+                    sector: None,
+                    yahoo_symbol: None, // Yahoo Finance는 암호화폐 미지원
+                })
+            })
+            .collect();
+
+        Ok(symbols)
+    }
+
+    async fn search(
+        &self,
+        query: &str,
+        limit: usize,
+    ) -> Result<Vec<SymbolMetadata>, Box<dyn std::error::Error + Send + Sync>> {
+        let all = self.fetch_all().await?;
+        let query_upper = query.to_uppercase();
+
+        let results: Vec<SymbolMetadata> = all
+            .into_iter()
+            .filter(|s| {
+                s.ticker.to_uppercase().contains(&query_upper)
+                    || s.name.contains(query)
+                    || s
+                        .name_en
+                        .as_deref()
+                        .is_some_and(|n| n.to_uppercase().contains(&query_upper))
+            })
+            .take(limit)
+            .collect();
+
+        Ok(results)
+    }
+}
+
 // ==================== Yahoo Finance Provider ====================
 
 /// Yahoo Finance 심볼 정보 Provider.
@@ -684,6 +777,7 @@ impl CompositeSymbolProvider {
             providers: vec![
                 Box::new(KrxSymbolProvider::new()),
                 Box::new(BinanceSymbolProvider::new()),
+                Box::new(UpbitSymbolProvider::new()),
                 Box::new(YahooSymbolProvider::new()),
             ],
         }
@@ -903,7 +997,7 @@ impl SymbolResolver {
     /// * `symbol` - 심볼 (어떤 형식이든 가능)
     ///
     /// # Examples
-    /// ```
+    /// ```ignore
     /// resolver.get_symbol_info("005930").await?;     // Korean stock
     /// resolver.get_symbol_info("005930.KS").await?;  // Yahoo format
     /// resolver.get_symbol_info("AAPL").await?;       // US stock
@@ -0,0 +1,437 @@
+//! 기업 행위(주식 분할, 배당, 종목코드 변경) 저장소와 수정주가 계산.
+//!
+//! `ohlcv` 테이블에는 거래 당시의 원본(수정 전) 가격이 저장됩니다. 장기 백테스트에서
+//! 분할일의 가격 급락이나 배당락일의 갭을 실제 손실로 오인하지 않도록,
+//! `corporate_actions` 테이블의 이벤트로 과거 캔들을 역산 조정합니다.
+//!
+//! - **분할**: 권리락일 이전 가격 ÷ 분할 비율, 거래량 × 분할 비율
+//! - **현금 배당**: 배당락일 이전 가격 × (1 − 배당금 / 직전 종가) (총수익 기준)
+//! - **종목코드 변경**: 새 코드로 조회할 때 변경일 이전 구간을 이전 코드의 캔들로 이어 붙임
+//!
+//! 조정은 조회 시점에 계산하며 저장된 원본 가격은 바꾸지 않습니다.
+//! Yahoo Finance 일봉은 이미 분할 조정되어 있으므로, 해당 종목의 분할은 등록하지 않습니다.
+//!
+//! # 사용 예제
+//!
+//! ```rust,ignore
+//! use trader_data::{CorporateActionStore, OhlcvCache, PriceAdjustment};
+//!
+//! let cache = OhlcvCache::new(pool).with_price_adjustment(PriceAdjustment::Adjusted);
+//! let klines = cache.get_cached_klines_range("069500", Timeframe::D1, start, end).await?;
+//! ```
+
+use crate::error::{DataError, Result};
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use sqlx::postgres::PgPool;
+use sqlx::FromRow;
+use tracing::info;
+use trader_core::Kline;
+
+/// 종목코드 변경 이력을 따라갈 최대 단계 (순환 등록 방지).
+const MAX_TICKER_LINEAGE: usize = 10;
+
+/// 가격 조정 방식.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PriceAdjustment {
+    /// 원본 가격 (거래 당시 가격, 기본값)
+    #[default]
+    Unadjusted,
+    /// 분할/배당 수정주가 (종목코드 변경 이전 이력 포함)
+    Adjusted,
+}
+
+/// 기업 행위 종류.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CorporateActionKind {
+    /// 주식 분할 (구주 1주당 신주 수, 예: 2 = 1:2 분할, 0.1 = 10:1 병합)
+    Split { ratio: Decimal },
+    /// 현금 배당 (1주당 배당금, 가격과 같은 통화)
+    Dividend { amount: Decimal },
+    /// 종목코드 변경 (변경일부터 새 코드로 거래)
+    TickerChange { new_ticker: String },
+}
+
+impl CorporateActionKind {
+    /// DB 저장용 문자열.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Split { .. } => "split",
+            Self::Dividend { .. } => "dividend",
+            Self::TickerChange { .. } => "ticker_change",
+        }
+    }
+}
+
+/// 기업 행위 이벤트.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CorporateAction {
+    /// 종목코드 (종목코드 변경은 이전 코드)
+    pub ticker: String,
+    /// 권리락/배당락/변경 적용일 (이 날짜부터 새 기준으로 거래)
+    pub ex_date: NaiveDate,
+    /// 종류
+    pub kind: CorporateActionKind,
+    /// 출처 (예: "krx", "yahoo", "manual")
+    pub source: String,
+}
+
+/// 기업 행위 레코드.
+#[derive(Debug, Clone, FromRow)]
+struct CorporateActionRecord {
+    ticker: String,
+    ex_date: NaiveDate,
+    action_type: String,
+    ratio: Option<Decimal>,
+    amount: Option<Decimal>,
+    new_ticker: Option<String>,
+    source: String,
+}
+
+impl CorporateActionRecord {
+    fn into_action(self) -> Option<CorporateAction> {
+        let kind = match self.action_type.as_str() {
+            "split" => CorporateActionKind::Split { ratio: self.ratio? },
+            "dividend" => CorporateActionKind::Dividend {
+                amount: self.amount?,
+            },
+            "ticker_change" => CorporateActionKind::TickerChange {
+                new_ticker: self.new_ticker?,
+            },
+            _ => return None,
+        };
+        Some(CorporateAction {
+            ticker: self.ticker,
+            ex_date: self.ex_date,
+            kind,
+            source: self.source,
+        })
+    }
+}
+
+/// 이전 종목코드와 새 코드 적용일.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TickerPredecessor {
+    /// 이전 종목코드
+    pub ticker: String,
+    /// 새 코드 적용일 (이전 코드의 캔들은 이 날짜 전까지만 사용)
+    pub until: NaiveDate,
+}
+
+/// 기업 행위 저장소.
+#[derive(Clone)]
+pub struct CorporateActionStore {
+    pool: PgPool,
+}
+
+impl CorporateActionStore {
+    /// 새 저장소 생성.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// 이벤트 일괄 저장 (종목 + 적용일 + 종류 기준 upsert).
+    pub async fn upsert_actions(&self, actions: &[CorporateAction]) -> Result<usize> {
+        if actions.is_empty() {
+            return Ok(0);
+        }
+
+        let tickers: Vec<&str> = actions.iter().map(|a| a.ticker.as_str()).collect();
+        let dates: Vec<NaiveDate> = actions.iter().map(|a| a.ex_date).collect();
+        let types: Vec<&str> = actions.iter().map(|a| a.kind.as_str()).collect();
+        let ratios: Vec<Option<Decimal>> = actions
+            .iter()
+            .map(|a| match &a.kind {
+                CorporateActionKind::Split { ratio } => Some(*ratio),
+                _ => None,
+            })
+            .collect();
+        let amounts: Vec<Option<Decimal>> = actions
+            .iter()
+            .map(|a| match &a.kind {
+                CorporateActionKind::Dividend { amount } => Some(*amount),
+                _ => None,
+            })
+            .collect();
+        let new_tickers: Vec<Option<&str>> = actions
+            .iter()
+            .map(|a| match &a.kind {
+                CorporateActionKind::TickerChange { new_ticker } => Some(new_ticker.as_str()),
+                _ => None,
+            })
+            .collect();
+        let sources: Vec<&str> = actions.iter().map(|a| a.source.as_str()).collect();
+
+        let result = sqlx::query(
+            r#"
+            INSERT INTO corporate_actions (
+                ticker, ex_date, action_type, ratio, amount, new_ticker, source
+            )
+            SELECT * FROM UNNEST(
+                $1::text[], $2::date[], $3::text[], $4::numeric[], $5::numeric[],
+                $6::text[], $7::text[]
+            )
+            ON CONFLICT (ticker, ex_date, action_type) DO UPDATE SET
+                ratio = EXCLUDED.ratio,
+                amount = EXCLUDED.amount,
+                new_ticker = EXCLUDED.new_ticker,
+                source = EXCLUDED.source
+            "#,
+        )
+        .bind(&tickers)
+        .bind(&dates)
+        .bind(&types)
+        .bind(&ratios)
+        .bind(&amounts)
+        .bind(&new_tickers)
+        .bind(&sources)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DataError::InsertError(e.to_string()))?;
+
+        let saved = result.rows_affected() as usize;
+        info!(count = saved, "기업 행위 저장");
+        Ok(saved)
+    }
+
+    /// 종목들의 이벤트 조회 (적용일 오름차순).
+    pub async fn actions_for(&self, tickers: &[String]) -> Result<Vec<CorporateAction>> {
+        let records: Vec<CorporateActionRecord> = sqlx::query_as(
+            r#"
+            SELECT ticker, ex_date, action_type, ratio, amount, new_ticker, source
+            FROM corporate_actions
+            WHERE ticker = ANY($1)
+            ORDER BY ex_date ASC
+            "#,
+        )
+        .bind(tickers)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DataError::QueryError(e.to_string()))?;
+
+        Ok(records
+            .into_iter()
+            .filter_map(CorporateActionRecord::into_action)
+            .collect())
+    }
+
+    /// 종목코드 변경 이력 조회 (가까운 이전 코드부터).
+    pub async fn ticker_lineage(&self, ticker: &str) -> Result<Vec<TickerPredecessor>> {
+        let mut lineage: Vec<TickerPredecessor> = Vec::new();
+        let mut current = ticker.to_string();
+
+        while lineage.len() < MAX_TICKER_LINEAGE {
+            let previous: Option<(String, NaiveDate)> = sqlx::query_as(
+                r#"
+                SELECT ticker, ex_date
+                FROM corporate_actions
+                WHERE action_type = 'ticker_change' AND new_ticker = $1
+                ORDER BY ex_date DESC
+                LIMIT 1
+                "#,
+            )
+            .bind(&current)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| DataError::QueryError(e.to_string()))?;
+
+            match previous {
+                Some((previous, until))
+                    if previous != ticker && lineage.iter().all(|p| p.ticker != previous) =>
+                {
+                    current = previous.clone();
+                    lineage.push(TickerPredecessor {
+                        ticker: previous,
+                        until,
+                    });
+                }
+                _ => break,
+            }
+        }
+
+        Ok(lineage)
+    }
+}
+
+/// 캔들에 분할/배당 수정주가를 적용합니다.
+///
+/// `klines`는 시간 오름차순이어야 하며, 각 이벤트는 적용일 이전 캔들에만 반영됩니다.
+/// 배당 조정 계수는 배당락일 직전 캔들의 종가로 계산하며,
+/// 직전 캔들이 없거나 배당금이 종가 이상이면 해당 배당은 건너뜁니다.
+pub fn apply_adjustments(klines: &mut [Kline], actions: &[CorporateAction]) {
+    // (적용일, 가격 계수, 거래량 계수)
+    let mut factors: Vec<(NaiveDate, Decimal, Decimal)> = actions
+        .iter()
+        .filter_map(|action| match &action.kind {
+            CorporateActionKind::Split { ratio } if *ratio > Decimal::ZERO => {
+                Some((action.ex_date, Decimal::ONE / *ratio, *ratio))
+            }
+            CorporateActionKind::Dividend { amount } if *amount > Decimal::ZERO => {
+                let prev_close = klines
+                    .iter()
+                    .rev()
+                    .find(|k| k.open_time.date_naive() < action.ex_date)?
+                    .close;
+                (prev_close > *amount).then(|| {
+                    (
+                        action.ex_date,
+                        Decimal::ONE - *amount / prev_close,
+                        Decimal::ONE,
+                    )
+                })
+            }
+            _ => None,
+        })
+        .collect();
+
+    if factors.is_empty() {
+        return;
+    }
+
+    // 최신 이벤트부터 누적하며 과거 캔들로 진행
+    factors.sort_by_key(|f| std::cmp::Reverse(f.0));
+    let mut next = 0;
+    let mut price_factor = Decimal::ONE;
+    let mut volume_factor = Decimal::ONE;
+
+    for kline in klines.iter_mut().rev() {
+        let date = kline.open_time.date_naive();
+        while next < factors.len() && factors[next].0 > date {
+            price_factor *= factors[next].1;
+            volume_factor *= factors[next].2;
+            next += 1;
+        }
+
+        if price_factor != Decimal::ONE {
+            kline.open = (kline.open * price_factor).round_dp(6);
+            kline.high = (kline.high * price_factor).round_dp(6);
+            kline.low = (kline.low * price_factor).round_dp(6);
+            kline.close = (kline.close * price_factor).round_dp(6);
+        }
+        if volume_factor != Decimal::ONE {
+            kline.volume = (kline.volume * volume_factor).round_dp(6);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+    use trader_core::Timeframe;
+
+    fn kline(day: u32, close: i64) -> Kline {
+        let open_time = Utc.with_ymd_and_hms(2024, 3, day, 0, 0, 0).unwrap();
+        let price = Decimal::from(close);
+        Kline {
+            ticker: "TEST".to_string(),
+            timeframe: Timeframe::D1,
+            open_time,
+            open: price,
+            high: price,
+            low: price,
+            close: price,
+            volume: Decimal::from(1000),
+            close_time: open_time + chrono::Duration::days(1),
+            quote_volume: None,
+            num_trades: None,
+        }
+    }
+
+    fn action(day: u32, kind: CorporateActionKind) -> CorporateAction {
+        CorporateAction {
+            ticker: "TEST".to_string(),
+            ex_date: NaiveDate::from_ymd_opt(2024, 3, day).unwrap(),
+            kind,
+            source: "manual".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_split_adjustment() {
+        let mut klines = vec![kline(4, 200), kline(5, 204), kline(6, 100), kline(7, 101)];
+        let actions = [action(
+            6,
+            CorporateActionKind::Split {
+                ratio: Decimal::from(2),
+            },
+        )];
+
+        apply_adjustments(&mut klines, &actions);
+
+        assert_eq!(klines[0].close, Decimal::from(100));
+        assert_eq!(klines[1].close, Decimal::from(102));
+        assert_eq!(klines[1].volume, Decimal::from(2000));
+        // 분할일 이후는 원본 유지
+        assert_eq!(klines[2].close, Decimal::from(100));
+        assert_eq!(klines[2].volume, Decimal::from(1000));
+    }
+
+    #[test]
+    fn test_dividend_adjustment() {
+        let mut klines = vec![kline(4, 10000), kline(5, 10000), kline(6, 9800)];
+        let actions = [action(
+            6,
+            CorporateActionKind::Dividend {
+                amount: Decimal::from(200),
+            },
+        )];
+
+        apply_adjustments(&mut klines, &actions);
+
+        // 계수 = 1 − 200 / 10000 = 0.98 → 배당락 갭 제거
+        assert_eq!(klines[0].close, Decimal::from(9800));
+        assert_eq!(klines[1].close, Decimal::from(9800));
+        assert_eq!(klines[2].close, Decimal::from(9800));
+        assert_eq!(klines[0].volume, Decimal::from(1000));
+    }
+
+    #[test]
+    fn test_cumulative_adjustment() {
+        let mut klines = vec![kline(4, 400), kline(5, 200), kline(6, 198)];
+        let actions = [
+            action(
+                5,
+                CorporateActionKind::Split {
+                    ratio: Decimal::from(2),
+                },
+            ),
+            action(
+                6,
+                CorporateActionKind::Dividend {
+                    amount: Decimal::from(2),
+                },
+            ),
+            // 종목코드 변경은 가격에 영향 없음
+            action(
+                6,
+                CorporateActionKind::TickerChange {
+                    new_ticker: "NEW".to_string(),
+                },
+            ),
+        ];
+
+        apply_adjustments(&mut klines, &actions);
+
+        // 배당 계수 0.99는 모든 과거 캔들에, 분할 계수 0.5는 분할일 이전에만
+        assert_eq!(klines[0].close, Decimal::from(198));
+        assert_eq!(klines[1].close, Decimal::from(198));
+        assert_eq!(klines[2].close, Decimal::from(198));
+    }
+
+    #[test]
+    fn test_dividend_without_prior_kline_is_ignored() {
+        let mut klines = vec![kline(6, 100), kline(7, 101)];
+        let actions = [action(
+            6,
+            CorporateActionKind::Dividend {
+                amount: Decimal::from(5),
+            },
+        )];
+
+        apply_adjustments(&mut klines, &actions);
+
+        assert_eq!(klines[0].close, Decimal::from(100));
+        assert_eq!(klines[1].close, Decimal::from(101));
+    }
+}
@@ -0,0 +1,526 @@
+//! 사용자 정의 지수 저장소.
+//!
+//! `custom_index` 테이블의 지수 정의를 관리하고, 구성종목 일봉으로 계산한 합성 일봉을
+//! `IDX_<코드>` 티커로 `ohlcv` 테이블에 저장합니다. 지수는 `symbol_info`에
+//! `symbol_type = 'INDEX'`로 등록되어 지표 동기화(시장 레짐)와 전략 심볼 조회에
+//! 일반 종목처럼 포함됩니다.
+//!
+//! # 사용 예제
+//!
+//! ```rust,ignore
+//! use trader_data::CustomIndexStore;
+//!
+//! let store = CustomIndexStore::new(pool);
+//! for index in store.list_enabled().await? {
+//!     let result = store.rebuild(&index.definition()?).await?;
+//! }
+//! ```
+
+use std::collections::HashMap;
+
+use crate::error::{DataError, Result};
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::Serialize;
+use sqlx::postgres::PgPool;
+use sqlx::FromRow;
+use tracing::info;
+use trader_core::{
+    compute_index_series, normalize_weights, CustomIndexDefinition, IndexConstituent,
+    IndexDailyBar, IndexWeighting,
+};
+use uuid::Uuid;
+
+/// 지수 정의 레코드.
+#[derive(Debug, Clone, Serialize, FromRow)]
+pub struct CustomIndexRecord {
+    pub id: Uuid,
+    /// 지수 코드
+    pub code: String,
+    pub name: String,
+    pub market: String,
+    /// 비중 결정 방식
+    pub weighting: String,
+    /// 기준값
+    pub base_value: Decimal,
+    /// 구성종목 ([{ticker, weight}])
+    pub constituents: serde_json::Value,
+    pub enabled: bool,
+    /// 마지막 계산 거래일
+    pub last_trade_date: Option<NaiveDate>,
+    /// 마지막 계산 종가
+    pub last_close: Option<Decimal>,
+    /// 마지막 계산 시각
+    pub computed_at: Option<DateTime<Utc>>,
+    /// 마지막 계산 실패 사유
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl CustomIndexRecord {
+    /// 도메인 지수 정의로 변환.
+    pub fn definition(&self) -> Result<CustomIndexDefinition> {
+        let weighting = IndexWeighting::parse(&self.weighting).ok_or_else(|| {
+            DataError::InvalidData(format!("unknown weighting: {}", self.weighting))
+        })?;
+        let constituents: Vec<IndexConstituent> = serde_json::from_value(self.constituents.clone())
+            .map_err(|e| DataError::SerializationError(e.to_string()))?;
+
+        Ok(CustomIndexDefinition {
+            code: self.code.clone(),
+            name: self.name.clone(),
+            market: self.market.clone(),
+            weighting,
+            base_value: self.base_value,
+            constituents,
+        })
+    }
+
+    /// OHLCV/심볼 테이블에 저장되는 지수 티커.
+    pub fn ticker(&self) -> String {
+        trader_core::custom_index_ticker(&self.code)
+    }
+}
+
+/// 지수 재계산 결과.
+#[derive(Debug, Clone, Serialize)]
+pub struct CustomIndexBuildResult {
+    /// 지수 티커
+    pub ticker: String,
+    /// 저장된 일봉 수
+    pub bars: usize,
+    /// 첫 거래일 (기준일)
+    pub first_date: Option<NaiveDate>,
+    /// 마지막 거래일
+    pub last_date: Option<NaiveDate>,
+    /// 마지막 종가
+    pub last_close: Option<Decimal>,
+    /// 일봉이 없어 제외된 구성종목
+    pub missing_constituents: Vec<String>,
+    /// 적용된 고정 비중 (`static`/`market_cap`)
+    pub weights: HashMap<String, Decimal>,
+}
+
+/// 일봉 날짜 기준 시간대 (시장별 현지 날짜).
+fn market_timezone(market: &str) -> &'static str {
+    match market {
+        "KR" => "Asia/Seoul",
+        "US" => "America/New_York",
+        "JP" => "Asia/Tokyo",
+        _ => "UTC",
+    }
+}
+
+const INDEX_COLUMNS: &str =
+    "id, code, name, market, weighting, base_value, constituents, enabled, \
+     last_trade_date, last_close, computed_at, last_error, created_at, updated_at";
+
+/// 사용자 정의 지수 저장소.
+pub struct CustomIndexStore {
+    pool: PgPool,
+}
+
+impl CustomIndexStore {
+    /// 새 저장소 생성.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// 전체 지수 목록 (코드순).
+    pub async fn list(&self) -> Result<Vec<CustomIndexRecord>> {
+        sqlx::query_as::<_, CustomIndexRecord>(&format!(
+            "SELECT {INDEX_COLUMNS} FROM custom_index ORDER BY code"
+        ))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DataError::QueryError(e.to_string()))
+    }
+
+    /// 활성 지수 목록 (코드순).
+    pub async fn list_enabled(&self) -> Result<Vec<CustomIndexRecord>> {
+        sqlx::query_as::<_, CustomIndexRecord>(&format!(
+            "SELECT {INDEX_COLUMNS} FROM custom_index WHERE enabled ORDER BY code"
+        ))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DataError::QueryError(e.to_string()))
+    }
+
+    /// 코드로 지수 조회.
+    pub async fn get(&self, code: &str) -> Result<Option<CustomIndexRecord>> {
+        sqlx::query_as::<_, CustomIndexRecord>(&format!(
+            "SELECT {INDEX_COLUMNS} FROM custom_index WHERE code = $1"
+        ))
+        .bind(code)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DataError::QueryError(e.to_string()))
+    }
+
+    /// 지수 정의 저장 (코드 기준 upsert) 및 심볼 등록.
+    pub async fn upsert(
+        &self,
+        definition: &CustomIndexDefinition,
+        enabled: bool,
+    ) -> Result<CustomIndexRecord> {
+        let constituents = serde_json::to_value(&definition.constituents)
+            .map_err(|e| DataError::SerializationError(e.to_string()))?;
+
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| DataError::InsertError(e.to_string()))?;
+
+        let record = sqlx::query_as::<_, CustomIndexRecord>(&format!(
+            r#"
+            INSERT INTO custom_index (code, name, market, weighting, base_value, constituents, enabled)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            ON CONFLICT (code) DO UPDATE SET
+                name = EXCLUDED.name,
+                market = EXCLUDED.market,
+                weighting = EXCLUDED.weighting,
+                base_value = EXCLUDED.base_value,
+                constituents = EXCLUDED.constituents,
+                enabled = EXCLUDED.enabled,
+                updated_at = NOW()
+            RETURNING {INDEX_COLUMNS}
+            "#
+        ))
+        .bind(&definition.code)
+        .bind(&definition.name)
+        .bind(&definition.market)
+        .bind(definition.weighting.as_str())
+        .bind(definition.base_value)
+        .bind(&constituents)
+        .bind(enabled)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| DataError::InsertError(e.to_string()))?;
+
+        // 지표 동기화/전략 심볼 조회 대상에 포함되도록 심볼 등록 (시장이 바뀌면 이전 등록 제거)
+        sqlx::query(
+            "DELETE FROM symbol_info WHERE ticker = $1 AND symbol_type = 'INDEX' AND market <> $2",
+        )
+        .bind(definition.ticker())
+        .bind(&definition.market)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DataError::InsertError(e.to_string()))?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO symbol_info (ticker, name, market, exchange, symbol_type, is_active)
+            VALUES ($1, $2, $3, 'CUSTOM', 'INDEX', $4)
+            ON CONFLICT (ticker, market) DO UPDATE SET
+                name = EXCLUDED.name,
+                symbol_type = 'INDEX',
+                is_active = EXCLUDED.is_active,
+                updated_at = NOW()
+            "#,
+        )
+        .bind(definition.ticker())
+        .bind(&definition.name)
+        .bind(&definition.market)
+        .bind(enabled)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DataError::InsertError(e.to_string()))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| DataError::InsertError(e.to_string()))?;
+
+        Ok(record)
+    }
+
+    /// 지수 삭제 (합성 일봉과 심볼 등록 포함).
+    pub async fn delete(&self, code: &str) -> Result<bool> {
+        let ticker = trader_core::custom_index_ticker(code);
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| DataError::DeleteError(e.to_string()))?;
+
+        let deleted = sqlx::query("DELETE FROM custom_index WHERE code = $1")
+            .bind(code)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| DataError::DeleteError(e.to_string()))?
+            .rows_affected();
+        if deleted == 0 {
+            return Ok(false);
+        }
+
+        for sql in [
+            "DELETE FROM ohlcv WHERE symbol = $1",
+            "DELETE FROM ohlcv_metadata WHERE symbol = $1",
+            "DELETE FROM symbol_info WHERE ticker = $1 AND symbol_type = 'INDEX'",
+        ] {
+            sqlx::query(sql)
+                .bind(&ticker)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| DataError::DeleteError(e.to_string()))?;
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| DataError::DeleteError(e.to_string()))?;
+        Ok(true)
+    }
+
+    /// 구성종목 일봉 조회 (시장 현지 날짜 기준).
+    pub async fn load_constituent_bars(
+        &self,
+        tickers: &[String],
+        market: &str,
+    ) -> Result<HashMap<String, Vec<IndexDailyBar>>> {
+        let rows = sqlx::query_as::<
+            _,
+            (
+                String,
+                NaiveDate,
+                Decimal,
+                Decimal,
+                Decimal,
+                Decimal,
+                Decimal,
+            ),
+        >(
+            r#"
+            SELECT symbol, (open_time AT TIME ZONE $2)::date AS trade_date,
+                   open, high, low, close, volume
+            FROM ohlcv
+            WHERE symbol = ANY($1) AND timeframe = '1d'
+            ORDER BY symbol, open_time
+            "#,
+        )
+        .bind(tickers)
+        .bind(market_timezone(market))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DataError::QueryError(e.to_string()))?;
+
+        let mut bars: HashMap<String, Vec<IndexDailyBar>> = HashMap::new();
+        for (symbol, date, open, high, low, close, volume) in rows {
+            bars.entry(symbol).or_default().push(IndexDailyBar {
+                date,
+                open,
+                high,
+                low,
+                close,
+                volume,
+            });
+        }
+        Ok(bars)
+    }
+
+    /// 최신 시가총액 기준 정규화 비중 (시가총액이 없는 종목은 제외).
+    pub async fn market_cap_weights(&self, tickers: &[String]) -> Result<HashMap<String, Decimal>> {
+        let rows = sqlx::query_as::<_, (String, Decimal)>(
+            r#"
+            SELECT DISTINCT ON (si.ticker) si.ticker, sf.market_cap
+            FROM symbol_info si
+            JOIN symbol_fundamental sf ON sf.symbol_info_id = si.id
+            WHERE si.ticker = ANY($1) AND sf.market_cap IS NOT NULL
+            ORDER BY si.ticker, sf.updated_at DESC NULLS LAST
+            "#,
+        )
+        .bind(tickers)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DataError::QueryError(e.to_string()))?;
+
+        Ok(normalize_weights(rows))
+    }
+
+    /// 지수 전체 기간 재계산 후 저장.
+    ///
+    /// 일간 리밸런싱 지수는 기준일부터 연쇄 계산되므로 구성종목이나 비중이 바뀌면
+    /// 전체 기간을 다시 계산해야 합니다. 이전 합성 일봉은 교체됩니다.
+    pub async fn rebuild(
+        &self,
+        definition: &CustomIndexDefinition,
+    ) -> Result<CustomIndexBuildResult> {
+        let ticker = definition.ticker();
+        let tickers = definition.tickers();
+
+        let mut bars = self
+            .load_constituent_bars(&tickers, &definition.market)
+            .await?;
+        let missing_constituents: Vec<String> = tickers
+            .iter()
+            .filter(|t| !bars.get(*t).is_some_and(|b| !b.is_empty()))
+            .cloned()
+            .collect();
+        bars.retain(|_, b| !b.is_empty());
+
+        let weights = match definition.weighting {
+            IndexWeighting::Static | IndexWeighting::MarketCap => {
+                let raw = if definition.weighting == IndexWeighting::Static {
+                    definition.static_weights()
+                } else {
+                    self.market_cap_weights(&tickers).await?
+                };
+                // 비중이나 일봉이 없는 종목은 제외하고 남은 비중으로 재정규화
+                bars.retain(|t, _| raw.contains_key(t));
+                normalize_weights(raw.into_iter().filter(|(t, _)| bars.contains_key(t)))
+            }
+            IndexWeighting::Equal | IndexWeighting::InverseVolatility => HashMap::new(),
+        };
+
+        let series =
+            compute_index_series(&bars, definition.weighting, &weights, definition.base_value);
+        if series.is_empty() {
+            return Err(DataError::InvalidData(format!(
+                "{}: no common trading day across constituents",
+                ticker
+            )));
+        }
+        let saved = self.save_series(definition, &series).await?;
+
+        let last = series.last();
+        info!(
+            ticker = %ticker,
+            bars = saved,
+            missing = missing_constituents.len(),
+            "사용자 정의 지수 계산"
+        );
+
+        Ok(CustomIndexBuildResult {
+            ticker,
+            bars: saved,
+            first_date: series.first().map(|b| b.date),
+            last_date: last.map(|b| b.date),
+            last_close: last.map(|b| b.close),
+            missing_constituents,
+            weights,
+        })
+    }
+
+    /// 합성 일봉 저장 (기존 일봉 교체) 및 마지막 계산 결과 갱신.
+    async fn save_series(
+        &self,
+        definition: &CustomIndexDefinition,
+        series: &[IndexDailyBar],
+    ) -> Result<usize> {
+        let ticker = definition.ticker();
+        let dates: Vec<NaiveDate> = series.iter().map(|b| b.date).collect();
+        let opens: Vec<Decimal> = series.iter().map(|b| b.open).collect();
+        let highs: Vec<Decimal> = series.iter().map(|b| b.high).collect();
+        let lows: Vec<Decimal> = series.iter().map(|b| b.low).collect();
+        let closes: Vec<Decimal> = series.iter().map(|b| b.close).collect();
+        let volumes: Vec<Decimal> = series.iter().map(|b| b.volume).collect();
+
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| DataError::InsertError(e.to_string()))?;
+
+        sqlx::query("DELETE FROM ohlcv WHERE symbol = $1 AND timeframe = '1d'")
+            .bind(&ticker)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| DataError::InsertError(e.to_string()))?;
+
+        let result = sqlx::query(
+            r#"
+            INSERT INTO ohlcv (symbol, timeframe, open_time, open, high, low, close, volume, close_time, fetched_at)
+            SELECT $1, '1d', d::timestamp AT TIME ZONE $2, o, h, l, c, v,
+                   (d::timestamp + INTERVAL '1 day' - INTERVAL '1 second') AT TIME ZONE $2, NOW()
+            FROM UNNEST($3::date[], $4::numeric[], $5::numeric[], $6::numeric[], $7::numeric[], $8::numeric[])
+                AS t(d, o, h, l, c, v)
+            "#,
+        )
+        .bind(&ticker)
+        .bind(market_timezone(&definition.market))
+        .bind(&dates)
+        .bind(&opens)
+        .bind(&highs)
+        .bind(&lows)
+        .bind(&closes)
+        .bind(&volumes)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DataError::InsertError(e.to_string()))?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO ohlcv_metadata (symbol, timeframe, first_cached_time, last_cached_time, last_updated_at, total_candles)
+            SELECT symbol, timeframe, MIN(open_time), MAX(open_time), NOW(), COUNT(*)::int
+            FROM ohlcv
+            WHERE symbol = $1 AND timeframe = '1d'
+            GROUP BY symbol, timeframe
+            ON CONFLICT (symbol, timeframe) DO UPDATE SET
+                first_cached_time = EXCLUDED.first_cached_time,
+                last_cached_time = EXCLUDED.last_cached_time,
+                last_updated_at = NOW(),
+                total_candles = EXCLUDED.total_candles
+            "#,
+        )
+        .bind(&ticker)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DataError::InsertError(e.to_string()))?;
+
+        let last = series.last();
+        sqlx::query(
+            r#"
+            UPDATE custom_index
+            SET last_trade_date = $2, last_close = $3, computed_at = NOW(), last_error = NULL
+            WHERE code = $1
+            "#,
+        )
+        .bind(&definition.code)
+        .bind(last.map(|b| b.date))
+        .bind(last.map(|b| b.close))
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DataError::InsertError(e.to_string()))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| DataError::InsertError(e.to_string()))?;
+
+        Ok(result.rows_affected() as usize)
+    }
+
+    /// 계산 실패 사유 기록.
+    pub async fn record_error(&self, code: &str, error: &str) -> Result<()> {
+        sqlx::query("UPDATE custom_index SET last_error = $2, computed_at = NOW() WHERE code = $1")
+            .bind(code)
+            .bind(error)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| DataError::InsertError(e.to_string()))?;
+        Ok(())
+    }
+
+    /// 지수 일봉 종가 조회 (시장 현지 날짜 기준, 오래된 순).
+    pub async fn closes(
+        &self,
+        code: &str,
+        market: &str,
+        since: Option<NaiveDate>,
+    ) -> Result<Vec<(NaiveDate, Decimal)>> {
+        sqlx::query_as::<_, (NaiveDate, Decimal)>(
+            r#"
+            SELECT (open_time AT TIME ZONE $2)::date AS trade_date, close
+            FROM ohlcv
+            WHERE symbol = $1 AND timeframe = '1d'
+              AND ($3::date IS NULL OR (open_time AT TIME ZONE $2)::date >= $3)
+            ORDER BY open_time
+            "#,
+        )
+        .bind(trader_core::custom_index_ticker(code))
+        .bind(market_timezone(market))
+        .bind(since)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DataError::QueryError(e.to_string()))
+    }
+}
@@ -0,0 +1,200 @@
+//! 실적 발표 일정 저장소.
+//!
+//! 수집기가 적재한 종목별 실적 발표 (예정)일을 `earnings_calendar` 테이블에 저장하고 조회합니다.
+//! 수집 소스별로 기간 단위 스냅샷을 교체하므로, 발표일이 바뀐 종목의 이전 일정은 남지 않습니다.
+//!
+//! # 사용 예제
+//!
+//! ```rust,ignore
+//! use trader_data::EarningsCalendarStore;
+//!
+//! let store = EarningsCalendarStore::new(pool);
+//! store.replace_events("nasdaq", from, to, &events).await?;
+//! let upcoming = store.upcoming(today, today + Duration::days(30), None).await?;
+//! ```
+
+use crate::error::{DataError, Result};
+use chrono::NaiveDate;
+use sqlx::postgres::PgPool;
+use sqlx::FromRow;
+use tracing::info;
+use trader_core::{EarningsEvent, EarningsTiming};
+
+/// 실적 발표 일정 레코드.
+#[derive(Debug, Clone, FromRow)]
+struct EarningsEventRecord {
+    ticker: String,
+    market: String,
+    announce_date: NaiveDate,
+    timing: String,
+    confirmed: bool,
+    source: String,
+}
+
+impl From<EarningsEventRecord> for EarningsEvent {
+    fn from(r: EarningsEventRecord) -> Self {
+        Self {
+            ticker: r.ticker,
+            market: r.market,
+            announce_date: r.announce_date,
+            timing: EarningsTiming::parse(&r.timing),
+            confirmed: r.confirmed,
+            source: r.source,
+        }
+    }
+}
+
+/// 실적 발표 일정 저장소.
+pub struct EarningsCalendarStore {
+    pool: PgPool,
+}
+
+impl EarningsCalendarStore {
+    /// 새 저장소 생성.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// 소스의 기간 내 일정을 새 스냅샷으로 교체.
+    pub async fn replace_events(
+        &self,
+        source: &str,
+        from: NaiveDate,
+        to: NaiveDate,
+        events: &[EarningsEvent],
+    ) -> Result<usize> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| DataError::InsertError(e.to_string()))?;
+
+        sqlx::query(
+            r#"
+            DELETE FROM earnings_calendar
+            WHERE source = $1 AND announce_date BETWEEN $2 AND $3
+            "#,
+        )
+        .bind(source)
+        .bind(from)
+        .bind(to)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DataError::InsertError(e.to_string()))?;
+
+        let saved = Self::insert_events(&mut tx, events).await?;
+
+        tx.commit()
+            .await
+            .map_err(|e| DataError::InsertError(e.to_string()))?;
+
+        info!(source, count = saved, "실적 발표 일정 저장");
+        Ok(saved)
+    }
+
+    /// 일정 일괄 저장 (종목 + 시장 + 발표일 기준 upsert).
+    pub async fn upsert_events(&self, events: &[EarningsEvent]) -> Result<usize> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| DataError::InsertError(e.to_string()))?;
+        let saved = Self::insert_events(&mut tx, events).await?;
+        tx.commit()
+            .await
+            .map_err(|e| DataError::InsertError(e.to_string()))?;
+        Ok(saved)
+    }
+
+    async fn insert_events(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        events: &[EarningsEvent],
+    ) -> Result<usize> {
+        if events.is_empty() {
+            return Ok(0);
+        }
+
+        let tickers: Vec<&str> = events.iter().map(|e| e.ticker.as_str()).collect();
+        let markets: Vec<&str> = events.iter().map(|e| e.market.as_str()).collect();
+        let dates: Vec<NaiveDate> = events.iter().map(|e| e.announce_date).collect();
+        let timings: Vec<&str> = events.iter().map(|e| e.timing.as_str()).collect();
+        let confirmed: Vec<bool> = events.iter().map(|e| e.confirmed).collect();
+        let sources: Vec<&str> = events.iter().map(|e| e.source.as_str()).collect();
+
+        let result = sqlx::query(
+            r#"
+            INSERT INTO earnings_calendar (
+                ticker, market, announce_date, timing, confirmed, source, fetched_at
+            )
+            SELECT *, NOW() FROM UNNEST(
+                $1::text[], $2::text[], $3::date[], $4::text[], $5::bool[], $6::text[]
+            )
+            ON CONFLICT (ticker, market, announce_date) DO UPDATE SET
+                timing = EXCLUDED.timing,
+                confirmed = EXCLUDED.confirmed,
+                source = EXCLUDED.source,
+                fetched_at = NOW()
+            "#,
+        )
+        .bind(&tickers)
+        .bind(&markets)
+        .bind(&dates)
+        .bind(&timings)
+        .bind(&confirmed)
+        .bind(&sources)
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| DataError::InsertError(e.to_string()))?;
+
+        Ok(result.rows_affected() as usize)
+    }
+
+    /// 기간 내 발표 일정 조회 (발표일 순).
+    pub async fn upcoming(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+        market: Option<&str>,
+    ) -> Result<Vec<EarningsEvent>> {
+        let records = sqlx::query_as::<_, EarningsEventRecord>(
+            r#"
+            SELECT ticker, market, announce_date, timing, confirmed, source
+            FROM earnings_calendar
+            WHERE announce_date BETWEEN $1 AND $2
+              AND ($3::text IS NULL OR market = $3)
+            ORDER BY announce_date, ticker
+            "#,
+        )
+        .bind(from)
+        .bind(to)
+        .bind(market)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DataError::QueryError(e.to_string()))?;
+
+        Ok(records.into_iter().map(EarningsEvent::from).collect())
+    }
+
+    /// 종목의 발표 일정 삭제.
+    pub async fn delete_event(
+        &self,
+        ticker: &str,
+        market: &str,
+        announce_date: NaiveDate,
+    ) -> Result<bool> {
+        let result = sqlx::query(
+            r#"
+            DELETE FROM earnings_calendar
+            WHERE ticker = $1 AND market = $2 AND announce_date = $3
+            "#,
+        )
+        .bind(ticker)
+        .bind(market)
+        .bind(announce_date)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DataError::QueryError(e.to_string()))?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
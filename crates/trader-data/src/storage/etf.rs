@@ -0,0 +1,261 @@
+//! ETF NAV/구성종목 저장소.
+//!
+//! KRX에서 수집한 ETF 일별 NAV와 괴리율을 `etf_nav_history`에,
+//! 구성종목(PDF)을 `etf_constituent`에 저장하고 조회합니다.
+//!
+//! # 사용 예제
+//!
+//! ```rust,ignore
+//! use trader_data::EtfStore;
+//!
+//! let store = EtfStore::new(pool);
+//! store.save_nav_points(&points).await?;
+//! let premiums = store.latest_premiums().await?;
+//! ```
+
+use crate::error::{DataError, Result};
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::Serialize;
+use sqlx::postgres::PgPool;
+use sqlx::FromRow;
+use tracing::info;
+use trader_core::{is_leveraged_etf, EtfConstituent, EtfNavPoint};
+
+/// ETF 괴리율 레코드.
+#[derive(Debug, Clone, Serialize, FromRow)]
+pub struct EtfPremiumRecord {
+    /// ETF 종목코드
+    pub ticker: String,
+    /// ETF 종목명 (symbol_info 미등록이면 종목코드)
+    pub name: String,
+    /// 거래일
+    pub trade_date: NaiveDate,
+    /// 종가
+    pub close_price: Decimal,
+    /// NAV
+    pub nav: Decimal,
+    /// 괴리율 (%)
+    pub premium_pct: f64,
+}
+
+impl EtfPremiumRecord {
+    /// 레버리지/인버스 ETF 여부 (종목명 기준).
+    pub fn is_leveraged(&self) -> bool {
+        is_leveraged_etf(&self.name)
+    }
+}
+
+/// ETF 구성종목 레코드.
+#[derive(Debug, Clone, FromRow)]
+struct EtfConstituentRecord {
+    etf_ticker: String,
+    as_of_date: NaiveDate,
+    constituent_ticker: String,
+    constituent_name: String,
+    shares: Decimal,
+    value_amount: Decimal,
+    weight_pct: Option<Decimal>,
+}
+
+impl From<EtfConstituentRecord> for EtfConstituent {
+    fn from(r: EtfConstituentRecord) -> Self {
+        Self {
+            etf_ticker: r.etf_ticker,
+            as_of_date: r.as_of_date,
+            ticker: r.constituent_ticker,
+            name: r.constituent_name,
+            shares: r.shares,
+            value_amount: r.value_amount,
+            weight_pct: r.weight_pct,
+        }
+    }
+}
+
+/// ETF NAV/구성종목 저장소.
+pub struct EtfStore {
+    pool: PgPool,
+}
+
+impl EtfStore {
+    /// 새 저장소 생성.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// 일별 NAV 일괄 저장 (종목 + 거래일 기준 upsert, 괴리율 함께 저장).
+    pub async fn save_nav_points(&self, points: &[EtfNavPoint]) -> Result<usize> {
+        let points: Vec<(&EtfNavPoint, f64)> = points
+            .iter()
+            .filter_map(|p| p.premium_pct().map(|premium| (p, premium)))
+            .collect();
+        if points.is_empty() {
+            return Ok(0);
+        }
+
+        let tickers: Vec<&str> = points.iter().map(|(p, _)| p.ticker.as_str()).collect();
+        let dates: Vec<NaiveDate> = points.iter().map(|(p, _)| p.trade_date).collect();
+        let closes: Vec<Decimal> = points.iter().map(|(p, _)| p.close_price).collect();
+        let navs: Vec<Decimal> = points.iter().map(|(p, _)| p.nav).collect();
+        let premiums: Vec<f64> = points.iter().map(|(_, premium)| *premium).collect();
+
+        let result = sqlx::query(
+            r#"
+            INSERT INTO etf_nav_history (ticker, trade_date, close_price, nav, premium_pct, fetched_at)
+            SELECT *, NOW() FROM UNNEST(
+                $1::text[], $2::date[], $3::numeric[], $4::numeric[], $5::float8[]
+            )
+            ON CONFLICT (ticker, trade_date) DO UPDATE SET
+                close_price = EXCLUDED.close_price,
+                nav = EXCLUDED.nav,
+                premium_pct = EXCLUDED.premium_pct,
+                fetched_at = NOW()
+            "#,
+        )
+        .bind(&tickers)
+        .bind(&dates)
+        .bind(&closes)
+        .bind(&navs)
+        .bind(&premiums)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DataError::InsertError(e.to_string()))?;
+
+        info!(count = result.rows_affected(), "ETF NAV 저장");
+        Ok(result.rows_affected() as usize)
+    }
+
+    /// 구성종목 스냅샷 저장 (동일 ETF + 기준일 스냅샷은 교체).
+    pub async fn save_constituents(
+        &self,
+        etf_ticker: &str,
+        as_of_date: NaiveDate,
+        constituents: &[EtfConstituent],
+    ) -> Result<usize> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| DataError::InsertError(e.to_string()))?;
+
+        sqlx::query("DELETE FROM etf_constituent WHERE etf_ticker = $1 AND as_of_date = $2")
+            .bind(etf_ticker)
+            .bind(as_of_date)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| DataError::InsertError(e.to_string()))?;
+
+        let codes: Vec<&str> = constituents.iter().map(|c| c.ticker.as_str()).collect();
+        let names: Vec<&str> = constituents.iter().map(|c| c.name.as_str()).collect();
+        let shares: Vec<Decimal> = constituents.iter().map(|c| c.shares).collect();
+        let values: Vec<Decimal> = constituents.iter().map(|c| c.value_amount).collect();
+        let weights: Vec<Option<Decimal>> = constituents.iter().map(|c| c.weight_pct).collect();
+
+        let result = sqlx::query(
+            r#"
+            INSERT INTO etf_constituent (
+                etf_ticker, as_of_date, constituent_ticker, constituent_name,
+                shares, value_amount, weight_pct
+            )
+            SELECT $1, $2, * FROM UNNEST(
+                $3::text[], $4::text[], $5::numeric[], $6::numeric[], $7::numeric[]
+            )
+            ON CONFLICT (etf_ticker, as_of_date, constituent_ticker) DO NOTHING
+            "#,
+        )
+        .bind(etf_ticker)
+        .bind(as_of_date)
+        .bind(&codes)
+        .bind(&names)
+        .bind(&shares)
+        .bind(&values)
+        .bind(&weights)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DataError::InsertError(e.to_string()))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| DataError::InsertError(e.to_string()))?;
+
+        Ok(result.rows_affected() as usize)
+    }
+
+    /// ETF의 최근 N 거래일 괴리율 추이 조회 (오래된 순).
+    pub async fn get_premium_history(
+        &self,
+        ticker: &str,
+        days: i64,
+    ) -> Result<Vec<EtfPremiumRecord>> {
+        sqlx::query_as::<_, EtfPremiumRecord>(
+            r#"
+            SELECT * FROM (
+                SELECT h.ticker, COALESCE(si.name, h.ticker) AS name, h.trade_date,
+                       h.close_price, h.nav, h.premium_pct
+                FROM etf_nav_history h
+                LEFT JOIN symbol_info si ON si.ticker = h.ticker AND si.market = 'KR'
+                WHERE h.ticker = $1
+                ORDER BY h.trade_date DESC
+                LIMIT $2
+            ) recent
+            ORDER BY trade_date
+            "#,
+        )
+        .bind(ticker)
+        .bind(days)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DataError::QueryError(e.to_string()))
+    }
+
+    /// ETF별 최신 괴리율 조회 (괴리율 큰 순).
+    pub async fn latest_premiums(&self) -> Result<Vec<EtfPremiumRecord>> {
+        sqlx::query_as::<_, EtfPremiumRecord>(
+            r#"
+            SELECT * FROM (
+                SELECT DISTINCT ON (h.ticker)
+                       h.ticker, COALESCE(si.name, h.ticker) AS name, h.trade_date,
+                       h.close_price, h.nav, h.premium_pct
+                FROM etf_nav_history h
+                LEFT JOIN symbol_info si ON si.ticker = h.ticker AND si.market = 'KR'
+                ORDER BY h.ticker, h.trade_date DESC
+            ) latest
+            ORDER BY premium_pct DESC
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DataError::QueryError(e.to_string()))
+    }
+
+    /// ETF의 최신 구성종목 조회 (비중 큰 순).
+    pub async fn latest_constituents(&self, etf_ticker: &str) -> Result<Vec<EtfConstituent>> {
+        let records = sqlx::query_as::<_, EtfConstituentRecord>(
+            r#"
+            SELECT etf_ticker, as_of_date, constituent_ticker, constituent_name,
+                   shares, value_amount, weight_pct
+            FROM etf_constituent
+            WHERE etf_ticker = $1
+              AND as_of_date = (SELECT MAX(as_of_date) FROM etf_constituent WHERE etf_ticker = $1)
+            ORDER BY value_amount DESC
+            "#,
+        )
+        .bind(etf_ticker)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DataError::QueryError(e.to_string()))?;
+
+        Ok(records.into_iter().map(EtfConstituent::from).collect())
+    }
+
+    /// ETF의 마지막 NAV 수집 거래일 조회.
+    pub async fn latest_nav_date(&self, ticker: &str) -> Result<Option<NaiveDate>> {
+        sqlx::query_scalar::<_, Option<NaiveDate>>(
+            "SELECT MAX(trade_date) FROM etf_nav_history WHERE ticker = $1",
+        )
+        .bind(ticker)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| DataError::QueryError(e.to_string()))
+    }
+}
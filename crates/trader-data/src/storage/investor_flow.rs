@@ -0,0 +1,138 @@
+//! 투자자별 순매수 저장소.
+//!
+//! KRX에서 수집한 종목별 일별 외국인/기관/개인 순매수 대금을
+//! `symbol_investor_flow` 테이블에 저장하고 조회합니다.
+//!
+//! # 사용 예제
+//!
+//! ```rust,ignore
+//! use trader_data::InvestorFlowStore;
+//!
+//! let store = InvestorFlowStore::new(pool);
+//! store.save_flows(&flows).await?;
+//! let recent = store.get_recent_flows("005930", 20).await?;
+//! ```
+
+use crate::error::{DataError, Result};
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use sqlx::postgres::PgPool;
+use sqlx::FromRow;
+use tracing::info;
+use trader_core::InvestorFlow;
+
+/// 투자자별 순매수 레코드.
+#[derive(Debug, Clone, FromRow)]
+struct InvestorFlowRecord {
+    ticker: String,
+    trade_date: NaiveDate,
+    foreign_net: Decimal,
+    institution_net: Decimal,
+    individual_net: Decimal,
+    other_corporation_net: Decimal,
+}
+
+impl From<InvestorFlowRecord> for InvestorFlow {
+    fn from(r: InvestorFlowRecord) -> Self {
+        Self {
+            ticker: r.ticker,
+            trade_date: r.trade_date,
+            foreign_net: r.foreign_net,
+            institution_net: r.institution_net,
+            individual_net: r.individual_net,
+            other_corporation_net: r.other_corporation_net,
+        }
+    }
+}
+
+/// 투자자별 순매수 저장소.
+pub struct InvestorFlowStore {
+    pool: PgPool,
+}
+
+impl InvestorFlowStore {
+    /// 새 저장소 생성.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// 일별 순매수 일괄 저장 (종목 + 거래일 기준 upsert).
+    pub async fn save_flows(&self, flows: &[InvestorFlow]) -> Result<usize> {
+        if flows.is_empty() {
+            return Ok(0);
+        }
+
+        let tickers: Vec<&str> = flows.iter().map(|f| f.ticker.as_str()).collect();
+        let dates: Vec<NaiveDate> = flows.iter().map(|f| f.trade_date).collect();
+        let foreign: Vec<Decimal> = flows.iter().map(|f| f.foreign_net).collect();
+        let institution: Vec<Decimal> = flows.iter().map(|f| f.institution_net).collect();
+        let individual: Vec<Decimal> = flows.iter().map(|f| f.individual_net).collect();
+        let other: Vec<Decimal> = flows.iter().map(|f| f.other_corporation_net).collect();
+
+        let result = sqlx::query(
+            r#"
+            INSERT INTO symbol_investor_flow (
+                ticker, trade_date, foreign_net, institution_net,
+                individual_net, other_corporation_net, fetched_at
+            )
+            SELECT *, NOW() FROM UNNEST(
+                $1::text[], $2::date[], $3::numeric[], $4::numeric[], $5::numeric[], $6::numeric[]
+            )
+            ON CONFLICT (ticker, trade_date) DO UPDATE SET
+                foreign_net = EXCLUDED.foreign_net,
+                institution_net = EXCLUDED.institution_net,
+                individual_net = EXCLUDED.individual_net,
+                other_corporation_net = EXCLUDED.other_corporation_net,
+                fetched_at = NOW()
+            "#,
+        )
+        .bind(&tickers)
+        .bind(&dates)
+        .bind(&foreign)
+        .bind(&institution)
+        .bind(&individual)
+        .bind(&other)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DataError::InsertError(e.to_string()))?;
+
+        info!(count = result.rows_affected(), "투자자별 순매수 저장");
+        Ok(result.rows_affected() as usize)
+    }
+
+    /// 최근 N 거래일 순매수 조회 (오래된 순).
+    pub async fn get_recent_flows(&self, ticker: &str, days: i64) -> Result<Vec<InvestorFlow>> {
+        let records = sqlx::query_as::<_, InvestorFlowRecord>(
+            r#"
+            SELECT ticker, trade_date, foreign_net, institution_net,
+                   individual_net, other_corporation_net
+            FROM (
+                SELECT *
+                FROM symbol_investor_flow
+                WHERE ticker = $1
+                ORDER BY trade_date DESC
+                LIMIT $2
+            ) recent
+            ORDER BY trade_date
+            "#,
+        )
+        .bind(ticker)
+        .bind(days)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DataError::QueryError(e.to_string()))?;
+
+        Ok(records.into_iter().map(InvestorFlow::from).collect())
+    }
+
+    /// 종목의 마지막 수집 거래일 조회.
+    pub async fn latest_trade_date(&self, ticker: &str) -> Result<Option<NaiveDate>> {
+        sqlx::query_scalar::<_, Option<NaiveDate>>(
+            "SELECT MAX(trade_date) FROM symbol_investor_flow WHERE ticker = $1",
+        )
+        .bind(ticker)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| DataError::QueryError(e.to_string()))
+    }
+}
@@ -1,6 +1,6 @@
 //! KRX(한국거래소) 데이터 소스.
 //!
-//! KRX 정보데이터시스템에서 국내 주식 OHLCV 데이터를 조회합니다.
+//! KRX 정보데이터시스템에서 국내 주식 OHLCV, 투자자별 순매수, ETF NAV/PDF 데이터를 조회합니다.
 //!
 //! # 사용 예제
 //!
@@ -9,6 +9,7 @@
 //!
 //! let krx = KrxDataSource::new();
 //! let klines = krx.get_ohlcv("005930", "20260101", "20260129").await?;
+//! let flows = krx.get_investor_flows("005930", "20260101", "20260129").await?;
 //! ```
 
 use crate::error::{DataError, Result};
@@ -17,7 +18,7 @@ use rust_decimal::Decimal;
 use serde::Deserialize;
 use std::str::FromStr;
 use tracing::{debug, info};
-use trader_core::{Kline, Timeframe};
+use trader_core::{EtfConstituent, EtfNavPoint, InvestorFlow, Kline, Timeframe};
 
 /// KRX API 기본 URL.
 const KRX_API_URL: &str = "https://data.krx.co.kr/comm/bldAttendant/getJsonData.cmd";
@@ -29,6 +30,15 @@ const BLD_STOCK_OHLCV: &str = "dbms/MDC/STAT/standard/MDCSTAT01701";
 #[allow(dead_code)] // 향후 전종목 시세 조회 기능에서 사용 예정
 const BLD_MARKET_OHLCV: &str = "dbms/MDC/STAT/standard/MDCSTAT01501";
 
+/// KRX 개별종목 투자자별 거래실적 조회 bld (일별 추이).
+const BLD_INVESTOR_FLOW: &str = "dbms/MDC/STAT/standard/MDCSTAT02303";
+
+/// KRX ETF 개별종목 시세 추이 조회 bld (종가, NAV).
+const BLD_ETF_NAV: &str = "dbms/MDC/STAT/standard/MDCSTAT04501";
+
+/// KRX ETF PDF(구성종목) 조회 bld.
+const BLD_ETF_PDF: &str = "dbms/MDC/STAT/standard/MDCSTAT05001";
+
 /// KRX 정보데이터시스템 API 응답 구조.
 ///
 /// 참고: KRX 정보데이터시스템은 "output" 키를 사용하고,
@@ -77,6 +87,84 @@ struct KrxOhlcvRecord {
     value: String,
 }
 
+/// KRX 투자자별 순매수 응답 구조.
+#[derive(Debug, Deserialize)]
+struct KrxInvestorFlowResponse {
+    #[serde(default)]
+    output: Vec<KrxInvestorFlowRecord>,
+}
+
+/// KRX 투자자별 순매수 레코드 (순매수 거래대금, 요약 보기).
+#[derive(Debug, Deserialize)]
+struct KrxInvestorFlowRecord {
+    /// 거래일자 (YYYY/MM/DD)
+    #[serde(rename = "TRD_DD", default)]
+    trd_dd: String,
+
+    /// 기관 합계
+    #[serde(rename = "TRDVAL1", default)]
+    institution: String,
+
+    /// 기타법인
+    #[serde(rename = "TRDVAL2", default)]
+    other_corporation: String,
+
+    /// 개인
+    #[serde(rename = "TRDVAL3", default)]
+    individual: String,
+
+    /// 외국인 합계
+    #[serde(rename = "TRDVAL4", default)]
+    foreign: String,
+}
+
+/// KRX `output` 배열 응답 (레코드 타입 제네릭).
+#[derive(Debug, Deserialize)]
+struct KrxOutput<T> {
+    #[serde(default = "Vec::new")]
+    output: Vec<T>,
+}
+
+/// KRX ETF 시세 추이 레코드.
+#[derive(Debug, Deserialize)]
+struct KrxEtfNavRecord {
+    /// 거래일자 (YYYY/MM/DD)
+    #[serde(rename = "TRD_DD", default)]
+    trd_dd: String,
+
+    /// 종가
+    #[serde(rename = "TDD_CLSPRC", default)]
+    close: String,
+
+    /// NAV
+    #[serde(rename = "LST_NAV", default)]
+    nav: String,
+}
+
+/// KRX ETF PDF 레코드.
+#[derive(Debug, Deserialize)]
+struct KrxEtfPdfRecord {
+    /// 구성종목 코드
+    #[serde(rename = "COMPST_ISU_CD", default)]
+    code: String,
+
+    /// 구성종목명
+    #[serde(rename = "COMPST_ISU_NM", default)]
+    name: String,
+
+    /// CU당 구성 수량
+    #[serde(rename = "COMPST_ISU_CU1_SHRS", default)]
+    shares: String,
+
+    /// 평가금액
+    #[serde(rename = "VALU_AMT", default)]
+    value_amount: String,
+
+    /// 구성 비중 (%)
+    #[serde(rename = "COMPST_RTO", default)]
+    weight: String,
+}
+
 /// KRX 데이터 소스.
 pub struct KrxDataSource {
     client: reqwest::Client,
@@ -172,6 +260,162 @@ impl KrxDataSource {
         Ok(klines)
     }
 
+    /// 개별 종목 투자자별 일별 순매수 대금 조회.
+    ///
+    /// # 인자
+    /// - `stock_code`: 종목코드 (6자리, 예: "005930")
+    /// - `start_date`: 시작일 (YYYYMMDD)
+    /// - `end_date`: 종료일 (YYYYMMDD)
+    pub async fn get_investor_flows(
+        &self,
+        stock_code: &str,
+        start_date: &str,
+        end_date: &str,
+    ) -> Result<Vec<InvestorFlow>> {
+        debug!(
+            stock_code = stock_code,
+            start = start_date,
+            end = end_date,
+            "KRX 투자자별 순매수 조회"
+        );
+
+        let isin_cd = format!("KR7{}003", stock_code);
+
+        let params = [
+            ("bld", BLD_INVESTOR_FLOW),
+            ("isuCd", &isin_cd),
+            ("strtDd", start_date),
+            ("endDd", end_date),
+            ("inqTpCd", "2"),   // 일별 추이
+            ("trdVolVal", "2"), // 거래대금
+            ("askBid", "3"),    // 순매수
+            ("detailView", "0"),
+        ];
+
+        let response = self
+            .client
+            .post(KRX_API_URL)
+            .header(
+                "Referer",
+                "https://data.krx.co.kr/contents/MDC/MDI/outerLoader/index.cmd",
+            )
+            .form(&params)
+            .send()
+            .await
+            .map_err(|e| DataError::FetchError(format!("KRX API 호출 실패: {}", e)))?;
+
+        if !response.status().is_success() {
+            return Err(DataError::FetchError(format!(
+                "KRX API 오류: {}",
+                response.status()
+            )));
+        }
+
+        let text = response
+            .text()
+            .await
+            .map_err(|e| DataError::FetchError(format!("응답 읽기 실패: {}", e)))?;
+
+        let flows = parse_investor_flows(stock_code, &text)?;
+
+        info!(
+            stock_code = stock_code,
+            count = flows.len(),
+            "KRX 투자자별 순매수 조회 완료"
+        );
+
+        Ok(flows)
+    }
+
+    /// ETF 일별 종가/NAV 조회.
+    ///
+    /// # 인자
+    /// - `etf_code`: ETF 종목코드 (6자리, 예: "122630")
+    /// - `start_date`: 시작일 (YYYYMMDD)
+    /// - `end_date`: 종료일 (YYYYMMDD)
+    pub async fn get_etf_nav_history(
+        &self,
+        etf_code: &str,
+        start_date: &str,
+        end_date: &str,
+    ) -> Result<Vec<EtfNavPoint>> {
+        let isin_cd = format!("KR7{}003", etf_code);
+        let params = [
+            ("bld", BLD_ETF_NAV),
+            ("isuCd", isin_cd.as_str()),
+            ("strtDd", start_date),
+            ("endDd", end_date),
+        ];
+
+        let text = self.post_form(&params).await?;
+        let points = parse_etf_nav_history(etf_code, &text)?;
+
+        info!(
+            etf_code = etf_code,
+            count = points.len(),
+            "KRX ETF NAV 조회 완료"
+        );
+
+        Ok(points)
+    }
+
+    /// ETF PDF(구성종목) 조회.
+    ///
+    /// # 인자
+    /// - `etf_code`: ETF 종목코드 (6자리)
+    /// - `date`: 기준일 (YYYYMMDD)
+    pub async fn get_etf_constituents(
+        &self,
+        etf_code: &str,
+        date: &str,
+    ) -> Result<Vec<EtfConstituent>> {
+        let isin_cd = format!("KR7{}003", etf_code);
+        let params = [
+            ("bld", BLD_ETF_PDF),
+            ("isuCd", isin_cd.as_str()),
+            ("trdDd", date),
+        ];
+
+        let text = self.post_form(&params).await?;
+        let as_of_date = parse_krx_date(date)?.date_naive();
+        let constituents = parse_etf_constituents(etf_code, as_of_date, &text)?;
+
+        info!(
+            etf_code = etf_code,
+            count = constituents.len(),
+            "KRX ETF PDF 조회 완료"
+        );
+
+        Ok(constituents)
+    }
+
+    /// KRX 정보데이터시스템 폼 요청 후 응답 본문 반환.
+    async fn post_form(&self, params: &[(&str, &str)]) -> Result<String> {
+        let response = self
+            .client
+            .post(KRX_API_URL)
+            .header(
+                "Referer",
+                "https://data.krx.co.kr/contents/MDC/MDI/outerLoader/index.cmd",
+            )
+            .form(params)
+            .send()
+            .await
+            .map_err(|e| DataError::FetchError(format!("KRX API 호출 실패: {}", e)))?;
+
+        if !response.status().is_success() {
+            return Err(DataError::FetchError(format!(
+                "KRX API 오류: {}",
+                response.status()
+            )));
+        }
+
+        response
+            .text()
+            .await
+            .map_err(|e| DataError::FetchError(format!("응답 읽기 실패: {}", e)))
+    }
+
     /// KRX 레코드를 Kline으로 변환.
     fn convert_to_klines(
         &self,
@@ -213,7 +457,7 @@ impl KrxDataSource {
         }
 
         // 날짜순 정렬 (오래된 것부터)
-        klines.sort_by(|a, b| a.open_time.cmp(&b.open_time));
+        klines.sort_by_key(|k| k.open_time);
 
         Ok(klines)
     }
@@ -225,6 +469,92 @@ impl Default for KrxDataSource {
     }
 }
 
+/// 투자자별 순매수 응답 파싱 (날짜순 정렬).
+fn parse_investor_flows(stock_code: &str, text: &str) -> Result<Vec<InvestorFlow>> {
+    let response: KrxInvestorFlowResponse = serde_json::from_str(text).map_err(|e| {
+        DataError::ParseError(format!(
+            "JSON 파싱 실패: {} - {}",
+            e,
+            &text[..text.len().min(200)]
+        ))
+    })?;
+
+    let mut flows = response
+        .output
+        .iter()
+        .map(|record| {
+            Ok(InvestorFlow {
+                ticker: stock_code.to_string(),
+                trade_date: parse_krx_date(&record.trd_dd)?.date_naive(),
+                foreign_net: parse_krx_number(&record.foreign)?,
+                institution_net: parse_krx_number(&record.institution)?,
+                individual_net: parse_krx_number(&record.individual)?,
+                other_corporation_net: parse_krx_number(&record.other_corporation)?,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    flows.sort_by_key(|f| f.trade_date);
+    Ok(flows)
+}
+
+/// KRX `output` 응답 JSON 파싱.
+fn parse_krx_output<T: serde::de::DeserializeOwned>(text: &str) -> Result<Vec<T>> {
+    serde_json::from_str::<KrxOutput<T>>(text)
+        .map(|r| r.output)
+        .map_err(|e| {
+            DataError::ParseError(format!(
+                "JSON 파싱 실패: {} - {}",
+                e,
+                &text[..text.len().min(200)]
+            ))
+        })
+}
+
+/// ETF 시세 추이 응답 파싱 (NAV 없는 날 제외, 날짜순 정렬).
+fn parse_etf_nav_history(etf_code: &str, text: &str) -> Result<Vec<EtfNavPoint>> {
+    let mut points = Vec::new();
+    for record in parse_krx_output::<KrxEtfNavRecord>(text)? {
+        let nav = parse_krx_number(&record.nav)?;
+        let close_price = parse_krx_number(&record.close)?;
+        if nav.is_zero() || close_price.is_zero() {
+            continue;
+        }
+        points.push(EtfNavPoint {
+            ticker: etf_code.to_string(),
+            trade_date: parse_krx_date(&record.trd_dd)?.date_naive(),
+            close_price,
+            nav,
+        });
+    }
+
+    points.sort_by_key(|p| p.trade_date);
+    Ok(points)
+}
+
+/// ETF PDF 응답 파싱.
+fn parse_etf_constituents(
+    etf_code: &str,
+    as_of_date: NaiveDate,
+    text: &str,
+) -> Result<Vec<EtfConstituent>> {
+    parse_krx_output::<KrxEtfPdfRecord>(text)?
+        .into_iter()
+        .map(|record| {
+            let weight = parse_krx_number(&record.weight)?;
+            Ok(EtfConstituent {
+                etf_ticker: etf_code.to_string(),
+                as_of_date,
+                ticker: record.code,
+                name: record.name,
+                shares: parse_krx_number(&record.shares)?,
+                value_amount: parse_krx_number(&record.value_amount)?,
+                weight_pct: (!weight.is_zero()).then_some(weight),
+            })
+        })
+        .collect()
+}
+
 /// KRX 날짜 문자열 파싱.
 fn parse_krx_date(s: &str) -> Result<DateTime<Utc>> {
     // YYYY/MM/DD 형식
@@ -276,4 +606,46 @@ mod tests {
         assert_eq!(parse_krx_number("").unwrap(), Decimal::ZERO);
         assert_eq!(parse_krx_number("-").unwrap(), Decimal::ZERO);
     }
+
+    #[test]
+    fn test_parse_investor_flows() {
+        let text = r#"{"output":[
+            {"TRD_DD":"2026/03/04","TRDVAL1":"-1,200,000","TRDVAL2":"50,000",
+             "TRDVAL3":"-3,850,000","TRDVAL4":"5,000,000","TRDVAL_TOT":"0"},
+            {"TRD_DD":"2026/03/03","TRDVAL1":"800,000","TRDVAL2":"0",
+             "TRDVAL3":"-500,000","TRDVAL4":"-300,000","TRDVAL_TOT":"0"}
+        ]}"#;
+
+        let flows = parse_investor_flows("005930", text).unwrap();
+        assert_eq!(flows.len(), 2);
+        assert_eq!(flows[0].trade_date.to_string(), "2026-03-03");
+        assert_eq!(flows[1].foreign_net, Decimal::from(5_000_000));
+        assert_eq!(flows[1].institution_net, Decimal::from(-1_200_000));
+        assert_eq!(flows[1].individual_net, Decimal::from(-3_850_000));
+        assert_eq!(flows[1].other_corporation_net, Decimal::from(50_000));
+    }
+
+    #[test]
+    fn test_parse_etf_nav_and_pdf() {
+        let nav_text = r#"{"output":[
+            {"TRD_DD":"2026/03/04","TDD_CLSPRC":"10,150","LST_NAV":"10,000.00"},
+            {"TRD_DD":"2026/03/03","TDD_CLSPRC":"9,980","LST_NAV":"-"}
+        ]}"#;
+        let points = parse_etf_nav_history("122630", nav_text).unwrap();
+        assert_eq!(points.len(), 1);
+        assert!((points[0].premium_pct().unwrap() - 1.5).abs() < 1e-9);
+
+        let pdf_text = r#"{"output":[
+            {"COMPST_ISU_CD":"005930","COMPST_ISU_NM":"삼성전자","COMPST_ISU_CU1_SHRS":"1,234",
+             "VALU_AMT":"90,000,000","COMPST_AMT":"0","COMPST_RTO":"25.31"},
+            {"COMPST_ISU_CD":"KRD010010001","COMPST_ISU_NM":"원화예금","COMPST_ISU_CU1_SHRS":"0",
+             "VALU_AMT":"1,000,000","COMPST_AMT":"0","COMPST_RTO":"-"}
+        ]}"#;
+        let date = NaiveDate::from_ymd_opt(2026, 3, 4).unwrap();
+        let constituents = parse_etf_constituents("069500", date, pdf_text).unwrap();
+        assert_eq!(constituents.len(), 2);
+        assert_eq!(constituents[0].shares, Decimal::from(1234));
+        assert_eq!(constituents[0].weight_pct, Some(Decimal::new(2531, 2)));
+        assert_eq!(constituents[1].weight_pct, None);
+    }
 }
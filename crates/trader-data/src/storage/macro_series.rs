@@ -0,0 +1,563 @@
+//! 매크로 시계열 저장소.
+//!
+//! 시리즈 키(`{도메인}.{자산}.{지표}`)별 관측값을 `macro_series` 테이블에 저장하고,
+//! 암호화폐 지표는 자산별 [`CryptoMetrics`]로, 현금 금리는 [`CashYieldCurve`]로,
+//! 매크로 레짐은 일별 [`MacroRegime`]으로 재구성하여 제공합니다.
+//!
+//! # 사용 예제
+//!
+//! ```rust,ignore
+//! use trader_data::MacroSeriesStore;
+//!
+//! let store = MacroSeriesStore::new(pool);
+//! store.save_points(&points).await?;
+//! let metrics = store.latest_crypto_metrics().await?;
+//! ```
+
+use crate::error::{DataError, Result};
+use chrono::{DateTime, NaiveDate, Utc};
+use sqlx::postgres::PgPool;
+use sqlx::FromRow;
+use std::collections::{BTreeMap, HashMap};
+use tracing::info;
+use trader_core::{
+    CashRateSeries, CashYieldCurve, CryptoMetrics, MacroRegime, MacroRegimeComponents,
+    MacroRegimeInputs, MacroRegimeLevel,
+};
+
+/// 현금 금리 시리즈 키 접두사
+pub const RATE_SERIES_PREFIX: &str = "rate.";
+
+/// 암호화폐 시리즈 키 접두사
+pub const CRYPTO_SERIES_PREFIX: &str = "crypto.";
+/// 자산 공통 시리즈의 자산 이름 (예: 스테이블코인 공급량)
+pub const CRYPTO_ALL_ASSETS: &str = "ALL";
+
+/// 암호화폐 지표 이름.
+pub mod crypto_metric {
+    /// 거래소 순유입량
+    pub const EXCHANGE_NETFLOW: &str = "exchange_netflow";
+    /// 스테이블코인 총 공급량
+    pub const STABLECOIN_SUPPLY: &str = "stablecoin_supply";
+    /// 스테이블코인 공급량 7일 변화율
+    pub const STABLECOIN_CHANGE_7D_PCT: &str = "stablecoin_change_7d_pct";
+    /// 무기한 선물 베이시스
+    pub const PERP_BASIS_PCT: &str = "perp_basis_pct";
+    /// 펀딩비
+    pub const FUNDING_RATE_PCT: &str = "funding_rate_pct";
+}
+
+/// 매크로 레짐 시리즈 키 접두사
+pub const REGIME_SERIES_PREFIX: &str = "regime.";
+
+/// 매크로 레짐 시리즈 키.
+pub mod regime_series {
+    /// 종합 점수
+    pub const SCORE: &str = "regime.composite.score";
+    /// 환율 추세 점수
+    pub const FX: &str = "regime.component.fx";
+    /// 금리차 점수
+    pub const YIELD_CURVE: &str = "regime.component.yield_curve";
+    /// 변동성 점수
+    pub const VOLATILITY: &str = "regime.component.volatility";
+    /// 시장 폭 점수
+    pub const BREADTH: &str = "regime.component.breadth";
+    /// USD/KRW 20거래일 변동률 (%)
+    pub const USD_KRW_TREND_PCT: &str = "regime.input.usd_krw_trend_pct";
+    /// 미국 10년-2년 금리차 (%p)
+    pub const YIELD_CURVE_SLOPE: &str = "regime.input.yield_curve_slope";
+    /// VIX
+    pub const VIX: &str = "regime.input.vix";
+    /// VKOSPI
+    pub const VKOSPI: &str = "regime.input.vkospi";
+    /// 20일선 상회 종목 비율
+    pub const BREADTH_RATIO: &str = "regime.input.breadth_ratio";
+}
+
+/// 암호화폐 시리즈 키 생성.
+pub fn crypto_series_key(asset: &str, metric: &str) -> String {
+    format!("{CRYPTO_SERIES_PREFIX}{asset}.{metric}")
+}
+
+/// 매크로 시계열 관측값.
+#[derive(Debug, Clone, PartialEq, FromRow)]
+pub struct MacroSeriesPoint {
+    /// 시리즈 키
+    pub series_key: String,
+    /// 관측 시각
+    pub observed_at: DateTime<Utc>,
+    /// 관측값
+    pub value: f64,
+    /// 데이터 출처
+    pub source: String,
+}
+
+impl MacroSeriesPoint {
+    /// 새 관측값 생성.
+    pub fn new(
+        series_key: impl Into<String>,
+        observed_at: DateTime<Utc>,
+        value: f64,
+        source: impl Into<String>,
+    ) -> Self {
+        Self {
+            series_key: series_key.into(),
+            observed_at,
+            value,
+            source: source.into(),
+        }
+    }
+}
+
+/// 매크로 시계열 저장소.
+pub struct MacroSeriesStore {
+    pool: PgPool,
+}
+
+impl MacroSeriesStore {
+    /// 새 저장소 생성.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// 관측값 일괄 저장 (시리즈 키 + 관측 시각 기준 upsert).
+    pub async fn save_points(&self, points: &[MacroSeriesPoint]) -> Result<usize> {
+        if points.is_empty() {
+            return Ok(0);
+        }
+
+        let keys: Vec<&str> = points.iter().map(|p| p.series_key.as_str()).collect();
+        let times: Vec<DateTime<Utc>> = points.iter().map(|p| p.observed_at).collect();
+        let values: Vec<f64> = points.iter().map(|p| p.value).collect();
+        let sources: Vec<&str> = points.iter().map(|p| p.source.as_str()).collect();
+
+        let result = sqlx::query(
+            r#"
+            INSERT INTO macro_series (series_key, observed_at, value, source, fetched_at)
+            SELECT *, NOW() FROM UNNEST($1::text[], $2::timestamptz[], $3::float8[], $4::text[])
+            ON CONFLICT (series_key, observed_at) DO UPDATE SET
+                value = EXCLUDED.value,
+                source = EXCLUDED.source,
+                fetched_at = NOW()
+            "#,
+        )
+        .bind(&keys)
+        .bind(&times)
+        .bind(&values)
+        .bind(&sources)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DataError::InsertError(e.to_string()))?;
+
+        info!(count = result.rows_affected(), "매크로 시계열 저장");
+        Ok(result.rows_affected() as usize)
+    }
+
+    /// 접두사에 해당하는 시리즈별 최신 관측값 조회.
+    pub async fn latest_points(&self, prefix: &str) -> Result<Vec<MacroSeriesPoint>> {
+        sqlx::query_as::<_, MacroSeriesPoint>(
+            r#"
+            SELECT DISTINCT ON (series_key) series_key, observed_at, value, source
+            FROM macro_series
+            WHERE series_key LIKE $1 || '%'
+            ORDER BY series_key, observed_at DESC
+            "#,
+        )
+        .bind(prefix)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DataError::QueryError(e.to_string()))
+    }
+
+    /// 시리즈 관측값 조회 (기간, 오래된 순).
+    pub async fn get_range(
+        &self,
+        series_key: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<MacroSeriesPoint>> {
+        sqlx::query_as::<_, MacroSeriesPoint>(
+            r#"
+            SELECT series_key, observed_at, value, source
+            FROM macro_series
+            WHERE series_key = $1 AND observed_at >= $2 AND observed_at <= $3
+            ORDER BY observed_at
+            "#,
+        )
+        .bind(series_key)
+        .bind(start)
+        .bind(end)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DataError::QueryError(e.to_string()))
+    }
+
+    /// 현금 금리 시리즈의 마지막 관측 시각 조회.
+    pub async fn latest_observed_at(&self, series_key: &str) -> Result<Option<DateTime<Utc>>> {
+        sqlx::query_scalar::<_, Option<DateTime<Utc>>>(
+            "SELECT MAX(observed_at) FROM macro_series WHERE series_key = $1",
+        )
+        .bind(series_key)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| DataError::QueryError(e.to_string()))
+    }
+
+    /// 기간의 현금 금리 곡선 조회.
+    ///
+    /// 기간 시작 이전의 마지막 관측값도 포함하여 첫날부터 금리가 적용되도록 합니다.
+    pub async fn get_cash_yield_curve(
+        &self,
+        series: CashRateSeries,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<CashYieldCurve> {
+        let points = sqlx::query_as::<_, MacroSeriesPoint>(
+            r#"
+            SELECT series_key, observed_at, value, source
+            FROM macro_series
+            WHERE series_key = $1
+              AND observed_at <= $3
+              AND observed_at >= COALESCE(
+                  (SELECT MAX(observed_at) FROM macro_series WHERE series_key = $1 AND observed_at <= $2),
+                  $2
+              )
+            ORDER BY observed_at
+            "#,
+        )
+        .bind(series.series_key())
+        .bind(start)
+        .bind(end)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DataError::QueryError(e.to_string()))?;
+
+        Ok(CashYieldCurve::from_points(
+            points.iter().map(|p| (p.observed_at.date_naive(), p.value)),
+        ))
+    }
+
+    /// 시리즈별 최신 현금 금리 조회 (연 %).
+    pub async fn latest_cash_yields(&self) -> Result<HashMap<CashRateSeries, f64>> {
+        let points = self.latest_points(RATE_SERIES_PREFIX).await?;
+        Ok(points
+            .iter()
+            .filter_map(|p| CashRateSeries::from_series_key(&p.series_key).map(|s| (s, p.value)))
+            .collect())
+    }
+
+    /// 기간의 일별 매크로 레짐 이력 조회 (오래된 순).
+    pub async fn macro_regime_history(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<Vec<MacroRegime>> {
+        let (Some(start), Some(end)) = (start.and_hms_opt(0, 0, 0), end.and_hms_opt(23, 59, 59))
+        else {
+            return Ok(Vec::new());
+        };
+
+        let points = sqlx::query_as::<_, MacroSeriesPoint>(
+            r#"
+            SELECT series_key, observed_at, value, source
+            FROM macro_series
+            WHERE series_key LIKE $1 || '%' AND observed_at >= $2 AND observed_at <= $3
+            ORDER BY observed_at
+            "#,
+        )
+        .bind(REGIME_SERIES_PREFIX)
+        .bind(start.and_utc())
+        .bind(end.and_utc())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DataError::QueryError(e.to_string()))?;
+
+        Ok(macro_regimes_from_points(&points))
+    }
+
+    /// 최신 매크로 레짐 조회 (계산된 값이 없으면 None).
+    pub async fn latest_macro_regime(&self) -> Result<Option<MacroRegime>> {
+        let points = sqlx::query_as::<_, MacroSeriesPoint>(
+            r#"
+            SELECT series_key, observed_at, value, source
+            FROM macro_series
+            WHERE series_key LIKE $1 || '%'
+              AND observed_at = (SELECT MAX(observed_at) FROM macro_series WHERE series_key = $2)
+            "#,
+        )
+        .bind(REGIME_SERIES_PREFIX)
+        .bind(regime_series::SCORE)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DataError::QueryError(e.to_string()))?;
+
+        Ok(macro_regimes_from_points(&points).pop())
+    }
+
+    /// 최신 암호화폐 지표를 자산별로 조회.
+    pub async fn latest_crypto_metrics(&self) -> Result<HashMap<String, CryptoMetrics>> {
+        let points = self.latest_points(CRYPTO_SERIES_PREFIX).await?;
+        Ok(crypto_metrics_from_points(&points))
+    }
+}
+
+/// 암호화폐 지표를 시리즈 관측값으로 변환.
+///
+/// 값이 없는 지표는 제외합니다. 스테이블코인 지표는 자산 공통(`ALL`) 시리즈로 저장합니다.
+pub fn crypto_metrics_to_points(metrics: &CryptoMetrics, source: &str) -> Vec<MacroSeriesPoint> {
+    let asset_series = [
+        (crypto_metric::EXCHANGE_NETFLOW, metrics.exchange_netflow),
+        (crypto_metric::PERP_BASIS_PCT, metrics.perp_basis_pct),
+        (crypto_metric::FUNDING_RATE_PCT, metrics.funding_rate_pct),
+    ]
+    .into_iter()
+    .map(|(metric, value)| (metrics.asset.as_str(), metric, value));
+
+    let shared_series = [
+        (crypto_metric::STABLECOIN_SUPPLY, metrics.stablecoin_supply),
+        (
+            crypto_metric::STABLECOIN_CHANGE_7D_PCT,
+            metrics.stablecoin_change_7d_pct,
+        ),
+    ]
+    .into_iter()
+    .map(|(metric, value)| (CRYPTO_ALL_ASSETS, metric, value));
+
+    asset_series
+        .chain(shared_series)
+        .filter_map(|(asset, metric, value)| {
+            value.map(|v| {
+                MacroSeriesPoint::new(
+                    crypto_series_key(asset, metric),
+                    metrics.observed_at,
+                    v,
+                    source,
+                )
+            })
+        })
+        .collect()
+}
+
+/// 일별 현금 금리를 시리즈 관측값으로 변환 (관측 시각은 해당일 00:00 UTC).
+pub fn cash_rates_to_points(
+    series: CashRateSeries,
+    rates: &[(NaiveDate, f64)],
+    source: &str,
+) -> Vec<MacroSeriesPoint> {
+    rates
+        .iter()
+        .filter_map(|(date, value)| {
+            let observed_at = date.and_hms_opt(0, 0, 0)?.and_utc();
+            Some(MacroSeriesPoint::new(
+                series.series_key(),
+                observed_at,
+                *value,
+                source,
+            ))
+        })
+        .collect()
+}
+
+/// 매크로 레짐을 시리즈 관측값으로 변환 (관측 시각은 기준일 00:00 UTC).
+///
+/// 종합 점수, 구성 요소 점수, 원천 입력값 중 값이 있는 것만 저장합니다.
+pub fn macro_regime_to_points(regime: &MacroRegime, source: &str) -> Vec<MacroSeriesPoint> {
+    let Some(observed_at) = regime.date.and_hms_opt(0, 0, 0).map(|t| t.and_utc()) else {
+        return Vec::new();
+    };
+    let components = &regime.components;
+    let inputs = &regime.inputs;
+
+    [
+        (regime_series::SCORE, Some(regime.score)),
+        (regime_series::FX, components.fx),
+        (regime_series::YIELD_CURVE, components.yield_curve),
+        (regime_series::VOLATILITY, components.volatility),
+        (regime_series::BREADTH, components.breadth),
+        (regime_series::USD_KRW_TREND_PCT, inputs.usd_krw_trend_pct),
+        (regime_series::YIELD_CURVE_SLOPE, inputs.yield_curve_slope),
+        (regime_series::VIX, inputs.vix),
+        (regime_series::VKOSPI, inputs.vkospi),
+        (regime_series::BREADTH_RATIO, inputs.breadth_ratio),
+    ]
+    .into_iter()
+    .filter_map(|(key, value)| value.map(|v| MacroSeriesPoint::new(key, observed_at, v, source)))
+    .collect()
+}
+
+/// 시리즈 관측값을 일별 매크로 레짐으로 재구성 (오래된 순).
+///
+/// 종합 점수가 없는 날은 제외합니다.
+pub fn macro_regimes_from_points(points: &[MacroSeriesPoint]) -> Vec<MacroRegime> {
+    let mut by_date: BTreeMap<NaiveDate, Vec<&MacroSeriesPoint>> = BTreeMap::new();
+    for point in points {
+        by_date
+            .entry(point.observed_at.date_naive())
+            .or_default()
+            .push(point);
+    }
+
+    by_date
+        .into_iter()
+        .filter_map(|(date, day_points)| {
+            let mut score = None;
+            let mut components = MacroRegimeComponents::default();
+            let mut inputs = MacroRegimeInputs::default();
+
+            for point in day_points {
+                let value = Some(point.value);
+                match point.series_key.as_str() {
+                    regime_series::SCORE => score = value,
+                    regime_series::FX => components.fx = value,
+                    regime_series::YIELD_CURVE => components.yield_curve = value,
+                    regime_series::VOLATILITY => components.volatility = value,
+                    regime_series::BREADTH => components.breadth = value,
+                    regime_series::USD_KRW_TREND_PCT => inputs.usd_krw_trend_pct = value,
+                    regime_series::YIELD_CURVE_SLOPE => inputs.yield_curve_slope = value,
+                    regime_series::VIX => inputs.vix = value,
+                    regime_series::VKOSPI => inputs.vkospi = value,
+                    regime_series::BREADTH_RATIO => inputs.breadth_ratio = value,
+                    _ => {}
+                }
+            }
+
+            score.map(|score| MacroRegime {
+                date,
+                score,
+                level: MacroRegimeLevel::from_score(score),
+                components,
+                inputs,
+            })
+        })
+        .collect()
+}
+
+/// 시리즈 관측값을 자산별 암호화폐 지표로 재구성.
+///
+/// 자산 공통(`ALL`) 시리즈는 모든 자산에 적용되며, 관측 시각은 자산 시리즈 중 최신 값을 사용합니다.
+pub fn crypto_metrics_from_points(points: &[MacroSeriesPoint]) -> HashMap<String, CryptoMetrics> {
+    let mut by_asset: HashMap<String, CryptoMetrics> = HashMap::new();
+    let mut shared: Vec<(&str, &MacroSeriesPoint)> = Vec::new();
+
+    for point in points {
+        let Some((asset, metric)) = point
+            .series_key
+            .strip_prefix(CRYPTO_SERIES_PREFIX)
+            .and_then(|rest| rest.split_once('.'))
+        else {
+            continue;
+        };
+
+        if asset == CRYPTO_ALL_ASSETS {
+            shared.push((metric, point));
+            continue;
+        }
+
+        let metrics = by_asset
+            .entry(asset.to_string())
+            .or_insert_with(|| CryptoMetrics::new(asset, point.observed_at));
+        metrics.observed_at = metrics.observed_at.max(point.observed_at);
+        apply_metric(metrics, metric, point.value);
+    }
+
+    for metrics in by_asset.values_mut() {
+        for (metric, point) in &shared {
+            apply_metric(metrics, metric, point.value);
+        }
+    }
+
+    by_asset
+}
+
+fn apply_metric(metrics: &mut CryptoMetrics, metric: &str, value: f64) {
+    match metric {
+        crypto_metric::EXCHANGE_NETFLOW => metrics.exchange_netflow = Some(value),
+        crypto_metric::STABLECOIN_SUPPLY => metrics.stablecoin_supply = Some(value),
+        crypto_metric::STABLECOIN_CHANGE_7D_PCT => metrics.stablecoin_change_7d_pct = Some(value),
+        crypto_metric::PERP_BASIS_PCT => metrics.perp_basis_pct = Some(value),
+        crypto_metric::FUNDING_RATE_PCT => metrics.funding_rate_pct = Some(value),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_crypto_metrics_round_trip() {
+        let now = Utc::now();
+        let mut btc = CryptoMetrics::new("BTC", now);
+        btc.perp_basis_pct = Some(0.12);
+        btc.funding_rate_pct = Some(0.01);
+        btc.stablecoin_supply = Some(160_000_000_000.0);
+        btc.stablecoin_change_7d_pct = Some(0.8);
+
+        let mut points = crypto_metrics_to_points(&btc, "binance");
+        assert_eq!(points.len(), 4);
+        assert!(points
+            .iter()
+            .any(|p| p.series_key == "crypto.ALL.stablecoin_supply"));
+        assert!(points
+            .iter()
+            .any(|p| p.series_key == "crypto.BTC.perp_basis_pct"));
+
+        let mut eth = CryptoMetrics::new("ETH", now);
+        eth.exchange_netflow = Some(-1200.0);
+        points.extend(crypto_metrics_to_points(&eth, "cryptoquant"));
+
+        let restored = crypto_metrics_from_points(&points);
+        assert_eq!(restored.len(), 2);
+        assert_eq!(restored["BTC"].perp_basis_pct, Some(0.12));
+        assert_eq!(restored["ETH"].exchange_netflow, Some(-1200.0));
+        // 자산 공통 시리즈는 모든 자산에 적용
+        assert_eq!(restored["ETH"].stablecoin_supply, Some(160_000_000_000.0));
+    }
+
+    #[test]
+    fn test_macro_regime_round_trip() {
+        let date = NaiveDate::from_ymd_opt(2026, 3, 4).unwrap();
+        let regime = MacroRegime::compute(
+            date,
+            MacroRegimeInputs {
+                usd_krw_trend_pct: Some(1.2),
+                vix: Some(24.0),
+                breadth_ratio: Some(0.4),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        let mut points = macro_regime_to_points(&regime, "macro_regime");
+        // 점수 1 + 구성 요소 3 + 입력값 3
+        assert_eq!(points.len(), 7);
+        assert!(points
+            .iter()
+            .all(|p| p.series_key.starts_with(REGIME_SERIES_PREFIX)));
+
+        // 종합 점수가 없는 날은 제외
+        let next_day = (date + chrono::Duration::days(1))
+            .and_hms_opt(0, 0, 0)
+            .unwrap();
+        points.push(MacroSeriesPoint::new(
+            regime_series::VIX,
+            next_day.and_utc(),
+            18.0,
+            "macro_regime",
+        ));
+
+        let restored = macro_regimes_from_points(&points);
+        assert_eq!(restored, vec![regime]);
+    }
+
+    #[test]
+    fn test_cash_rates_to_points() {
+        let date = NaiveDate::from_ymd_opt(2026, 3, 4).unwrap();
+        let points = cash_rates_to_points(CashRateSeries::KrCd91d, &[(date, 2.81)], "ecos");
+
+        assert_eq!(points.len(), 1);
+        assert_eq!(points[0].series_key, "rate.KR.cd_91d");
+        assert!(points[0].series_key.starts_with(RATE_SERIES_PREFIX));
+        assert_eq!(points[0].observed_at.date_naive(), date);
+    }
+}
@@ -1,6 +1,14 @@
 //! 데이터 저장소 구현.
 
+pub mod corporate_action;
+pub mod custom_index;
+pub mod earnings;
+pub mod etf;
+pub mod investor_flow;
 pub mod krx;
+pub mod macro_series;
 pub mod ohlcv;
 pub mod redis;
+pub mod symbol_risk;
+pub mod synthetic;
 pub mod timescale;
@@ -18,9 +18,18 @@
 //! let cache = OhlcvCache::new(pool).await?;
 //! let klines = cache.get_klines("AAPL", Timeframe::D1, 100).await?;
 //! ```
+//!
+//! # 수정주가
+//!
+//! 기본은 원본 가격입니다. `with_price_adjustment(PriceAdjustment::Adjusted)`로 생성하면
+//! 조회 결과에 분할/배당 수정주가를 적용하고, 종목코드 변경 이전 이력을 이어 붙입니다.
+//! 자세한 내용은 [`corporate_action`](super::corporate_action) 모듈을 참고하세요.
 
+use super::corporate_action::{
+    apply_adjustments, CorporateActionStore, PriceAdjustment, TickerPredecessor,
+};
 use crate::error::{DataError, Result};
-use chrono::{DateTime, Duration, Utc};
+use chrono::{DateTime, Duration, NaiveDate, TimeZone, Utc};
 use rust_decimal::Decimal;
 use sqlx::postgres::PgPool;
 use sqlx::FromRow;
@@ -122,12 +131,28 @@ pub struct OhlcvMetadataRecord {
 #[derive(Clone)]
 pub struct OhlcvCache {
     pool: PgPool,
+    /// 조회 시 가격 조정 방식
+    price_adjustment: PriceAdjustment,
 }
 
 impl OhlcvCache {
     /// 새로운 캐시 서비스 생성.
     pub fn new(pool: PgPool) -> Self {
-        Self { pool }
+        Self {
+            pool,
+            price_adjustment: PriceAdjustment::default(),
+        }
+    }
+
+    /// 가격 조정 방식 설정 (기본: 원본 가격).
+    pub fn with_price_adjustment(mut self, adjustment: PriceAdjustment) -> Self {
+        self.price_adjustment = adjustment;
+        self
+    }
+
+    /// 현재 가격 조정 방식.
+    pub fn price_adjustment(&self) -> PriceAdjustment {
+        self.price_adjustment
     }
 
     /// 캐시에서 캔들 데이터 조회.
@@ -162,6 +187,10 @@ impl OhlcvCache {
         let mut klines: Vec<Kline> = records.into_iter().map(|r| r.to_kline()).collect();
         klines.reverse();
 
+        if self.price_adjustment == PriceAdjustment::Adjusted {
+            klines = self.adjust_latest(symbol, &tf_str, klines, limit).await?;
+        }
+
         debug!(
             symbol = symbol,
             timeframe = %tf_str,
@@ -199,8 +228,131 @@ impl OhlcvCache {
         .await
         .map_err(|e| DataError::QueryError(e.to_string()))?;
 
-        let klines: Vec<Kline> = records.into_iter().map(|r| r.to_kline()).collect();
+        let mut klines: Vec<Kline> = records.into_iter().map(|r| r.to_kline()).collect();
+
+        if self.price_adjustment == PriceAdjustment::Adjusted {
+            klines = self
+                .adjust_range(symbol, &tf_str, klines, start, end)
+                .await?;
+        }
+
+        Ok(klines)
+    }
+
+    /// 최신 `limit`개 조회 결과에 수정주가 적용.
+    ///
+    /// 캔들이 부족하면 이전 종목코드의 변경일 이전 캔들로 채웁니다.
+    async fn adjust_latest(
+        &self,
+        symbol: &str,
+        tf_str: &str,
+        mut klines: Vec<Kline>,
+        limit: usize,
+    ) -> Result<Vec<Kline>> {
+        let store = CorporateActionStore::new(self.pool.clone());
+        let lineage = store.ticker_lineage(symbol).await?;
+
+        for predecessor in &lineage {
+            if klines.len() >= limit {
+                break;
+            }
+            let until = lineage_cutoff(predecessor.until, klines.first());
+            let records: Vec<OhlcvRecord> = sqlx::query_as(
+                r#"
+                SELECT symbol, timeframe, open_time, open, high, low, close, volume, close_time, fetched_at
+                FROM ohlcv
+                WHERE symbol = $1 AND timeframe = $2 AND open_time < $3
+                ORDER BY open_time DESC
+                LIMIT $4
+                "#,
+            )
+            .bind(&predecessor.ticker)
+            .bind(tf_str)
+            .bind(until)
+            .bind((limit - klines.len()) as i64)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DataError::QueryError(e.to_string()))?;
+
+            let mut older: Vec<Kline> = records.iter().rev().map(|r| r.to_kline()).collect();
+            older.append(&mut klines);
+            klines = older;
+        }
+
+        self.apply_actions(&store, symbol, &lineage_tickers(symbol, &lineage), klines)
+            .await
+    }
+
+    /// 시간 범위 조회 결과에 수정주가 적용.
+    ///
+    /// 범위 안에 종목코드 변경일이 있으면 변경일 이전 구간을 이전 코드의 캔들로 채웁니다.
+    async fn adjust_range(
+        &self,
+        symbol: &str,
+        tf_str: &str,
+        mut klines: Vec<Kline>,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<Kline>> {
+        let store = CorporateActionStore::new(self.pool.clone());
+        let lineage = store.ticker_lineage(symbol).await?;
+
+        for predecessor in &lineage {
+            let until = lineage_cutoff(predecessor.until, klines.first()).min(end);
+            if until <= start {
+                break;
+            }
+            let records: Vec<OhlcvRecord> = sqlx::query_as(
+                r#"
+                SELECT symbol, timeframe, open_time, open, high, low, close, volume, close_time, fetched_at
+                FROM ohlcv
+                WHERE symbol = $1 AND timeframe = $2 AND open_time >= $3 AND open_time < $4
+                ORDER BY open_time ASC
+                "#,
+            )
+            .bind(&predecessor.ticker)
+            .bind(tf_str)
+            .bind(start)
+            .bind(until)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DataError::QueryError(e.to_string()))?;
+
+            let mut older: Vec<Kline> = records.iter().map(|r| r.to_kline()).collect();
+            older.append(&mut klines);
+            klines = older;
+        }
 
+        self.apply_actions(&store, symbol, &lineage_tickers(symbol, &lineage), klines)
+            .await
+    }
+
+    /// 현재/이전 종목코드의 분할·배당을 적용하고 심볼을 현재 코드로 통일합니다.
+    async fn apply_actions(
+        &self,
+        store: &CorporateActionStore,
+        symbol: &str,
+        tickers: &[String],
+        mut klines: Vec<Kline>,
+    ) -> Result<Vec<Kline>> {
+        if klines.is_empty() {
+            return Ok(klines);
+        }
+
+        let actions = store.actions_for(tickers).await?;
+        apply_adjustments(&mut klines, &actions);
+        for kline in &mut klines {
+            if kline.ticker != symbol {
+                kline.ticker = symbol.to_string();
+            }
+        }
+
+        debug!(
+            symbol = symbol,
+            actions = actions.len(),
+            count = klines.len(),
+            "수정주가 적용"
+        );
         Ok(klines)
     }
 
@@ -452,6 +604,19 @@ pub fn timeframe_to_string(timeframe: Timeframe) -> String {
     }
 }
 
+/// 이전 종목코드 캔들을 사용할 마지막 시각 (변경일 0시와 이미 채운 첫 캔들 중 이른 쪽).
+fn lineage_cutoff(until: NaiveDate, first: Option<&Kline>) -> DateTime<Utc> {
+    let cutoff = Utc.from_utc_datetime(&until.and_hms_opt(0, 0, 0).unwrap_or_default());
+    first.map_or(cutoff, |k| k.open_time.min(cutoff))
+}
+
+/// 수정주가 계산에 사용할 종목코드 목록 (현재 코드 + 이전 코드).
+fn lineage_tickers(symbol: &str, lineage: &[TickerPredecessor]) -> Vec<String> {
+    std::iter::once(symbol.to_string())
+        .chain(lineage.iter().map(|p| p.ticker.clone()))
+        .collect()
+}
+
 /// Timeframe의 Duration 계산.
 fn timeframe_to_duration(timeframe: Timeframe) -> Duration {
     match timeframe {
@@ -0,0 +1,125 @@
+//! 실현 변동성/베타 저장소.
+//!
+//! 수집기가 계산한 종목별 20/60/252일 실현 변동성과 시장 베타를 `symbol_fundamental`에
+//! 저장하고, 전략 컨텍스트·스크리닝·헤지 오버레이가 같은 값을 조회하도록 제공합니다.
+//!
+//! # 사용 예제
+//!
+//! ```rust,ignore
+//! use trader_data::SymbolRiskStore;
+//!
+//! let store = SymbolRiskStore::new(pool);
+//! store.save(symbol_info_id, &metrics).await?;
+//! let risk = store.load(&["005930", "000660"]).await?;
+//! ```
+
+use crate::error::{DataError, Result};
+use chrono::{DateTime, Utc};
+use sqlx::postgres::PgPool;
+use sqlx::FromRow;
+use std::collections::HashMap;
+use trader_core::SymbolRiskMetrics;
+use uuid::Uuid;
+
+/// 실현 변동성/베타 레코드.
+#[derive(Debug, Clone, FromRow)]
+struct SymbolRiskRecord {
+    ticker: String,
+    realized_vol_20d: Option<f64>,
+    realized_vol_60d: Option<f64>,
+    realized_vol_252d: Option<f64>,
+    beta: Option<f64>,
+    beta_benchmark: Option<String>,
+    volatility_updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<SymbolRiskRecord> for SymbolRiskMetrics {
+    fn from(r: SymbolRiskRecord) -> Self {
+        Self {
+            ticker: r.ticker,
+            realized_vol_20d: r.realized_vol_20d,
+            realized_vol_60d: r.realized_vol_60d,
+            realized_vol_252d: r.realized_vol_252d,
+            beta: r.beta,
+            beta_benchmark: r.beta_benchmark,
+            updated_at: r.volatility_updated_at,
+        }
+    }
+}
+
+/// 실현 변동성/베타 저장소.
+pub struct SymbolRiskStore {
+    pool: PgPool,
+}
+
+impl SymbolRiskStore {
+    /// 새 저장소 생성.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// 종목의 실현 변동성/베타 저장.
+    ///
+    /// 계산되지 않은 값(None)도 그대로 덮어써 오래된 값이 남지 않도록 합니다.
+    pub async fn save(&self, symbol_info_id: Uuid, metrics: &SymbolRiskMetrics) -> Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO symbol_fundamental (
+                symbol_info_id, realized_vol_20d, realized_vol_60d, realized_vol_252d,
+                beta, beta_benchmark, volatility_updated_at, fetched_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, NOW())
+            ON CONFLICT (symbol_info_id) DO UPDATE SET
+                realized_vol_20d = EXCLUDED.realized_vol_20d,
+                realized_vol_60d = EXCLUDED.realized_vol_60d,
+                realized_vol_252d = EXCLUDED.realized_vol_252d,
+                beta = EXCLUDED.beta,
+                beta_benchmark = EXCLUDED.beta_benchmark,
+                volatility_updated_at = EXCLUDED.volatility_updated_at
+            "#,
+        )
+        .bind(symbol_info_id)
+        .bind(metrics.realized_vol_20d)
+        .bind(metrics.realized_vol_60d)
+        .bind(metrics.realized_vol_252d)
+        .bind(metrics.beta)
+        .bind(&metrics.beta_benchmark)
+        .bind(metrics.updated_at.unwrap_or_else(Utc::now))
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DataError::QueryError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    /// 티커별 실현 변동성/베타 조회.
+    ///
+    /// 계산된 적이 없는 종목은 결과에 포함되지 않습니다.
+    pub async fn load(&self, tickers: &[&str]) -> Result<HashMap<String, SymbolRiskMetrics>> {
+        if tickers.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let records = sqlx::query_as::<_, SymbolRiskRecord>(
+            r#"
+            SELECT DISTINCT ON (si.ticker)
+                si.ticker, sf.realized_vol_20d, sf.realized_vol_60d, sf.realized_vol_252d,
+                sf.beta, sf.beta_benchmark, sf.volatility_updated_at
+            FROM symbol_info si
+            JOIN symbol_fundamental sf ON sf.symbol_info_id = si.id
+            WHERE si.ticker = ANY($1)
+              AND sf.volatility_updated_at IS NOT NULL
+            ORDER BY si.ticker, sf.volatility_updated_at DESC
+            "#,
+        )
+        .bind(tickers)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DataError::QueryError(e.to_string()))?;
+
+        Ok(records
+            .into_iter()
+            .map(|r| (r.ticker.clone(), SymbolRiskMetrics::from(r)))
+            .collect())
+    }
+}
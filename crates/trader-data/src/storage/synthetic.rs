@@ -0,0 +1,128 @@
+//! 합성 종목 저장소.
+//!
+//! `synthetic_instrument` 테이블의 합성 종목(스프레드/비율) 정의를 관리합니다.
+//! 합성 캔들은 저장하지 않으며, `SYN_<코드>` 티커로 캔들을 요청하면
+//! [`CachedHistoricalDataProvider`](crate::CachedHistoricalDataProvider)가 leg 캔들로
+//! 그때그때 계산합니다.
+//!
+//! # 사용 예제
+//!
+//! ```rust,ignore
+//! use trader_data::SyntheticInstrumentStore;
+//!
+//! let store = SyntheticInstrumentStore::new(pool);
+//! if let Some(record) = store.get("SEC_HYNIX").await? {
+//!     let definition = record.definition()?;
+//! }
+//! ```
+
+use crate::error::{DataError, Result};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::postgres::PgPool;
+use sqlx::FromRow;
+use trader_core::{SyntheticDefinition, SyntheticExpression};
+use uuid::Uuid;
+
+/// 합성 종목 정의 레코드.
+#[derive(Debug, Clone, Serialize, FromRow)]
+pub struct SyntheticInstrumentRecord {
+    pub id: Uuid,
+    /// 합성 종목 코드
+    pub code: String,
+    pub name: String,
+    /// 가격식 (예: `005930 - 1.5*000660`, `KO / PEP`)
+    pub expression: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl SyntheticInstrumentRecord {
+    /// 도메인 합성 종목 정의로 변환.
+    pub fn definition(&self) -> Result<SyntheticDefinition> {
+        let expression = SyntheticExpression::parse(&self.expression).map_err(|e| {
+            DataError::InvalidData(format!("{}: invalid expression: {}", self.code, e))
+        })?;
+
+        Ok(SyntheticDefinition {
+            code: self.code.clone(),
+            name: self.name.clone(),
+            expression,
+        })
+    }
+
+    /// 캔들 조회/전략 신호에 사용하는 합성 종목 티커.
+    pub fn ticker(&self) -> String {
+        trader_core::synthetic_ticker(&self.code)
+    }
+}
+
+const SYNTHETIC_COLUMNS: &str = "id, code, name, expression, created_at, updated_at";
+
+/// 합성 종목 저장소.
+pub struct SyntheticInstrumentStore {
+    pool: PgPool,
+}
+
+impl SyntheticInstrumentStore {
+    /// 새 저장소 생성.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// 전체 합성 종목 목록 (코드순).
+    pub async fn list(&self) -> Result<Vec<SyntheticInstrumentRecord>> {
+        sqlx::query_as::<_, SyntheticInstrumentRecord>(&format!(
+            "SELECT {SYNTHETIC_COLUMNS} FROM synthetic_instrument ORDER BY code"
+        ))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DataError::QueryError(e.to_string()))
+    }
+
+    /// 코드로 합성 종목 조회.
+    pub async fn get(&self, code: &str) -> Result<Option<SyntheticInstrumentRecord>> {
+        sqlx::query_as::<_, SyntheticInstrumentRecord>(&format!(
+            "SELECT {SYNTHETIC_COLUMNS} FROM synthetic_instrument WHERE code = $1"
+        ))
+        .bind(code)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DataError::QueryError(e.to_string()))
+    }
+
+    /// 합성 종목 정의 저장 (코드 기준 upsert, 가격식은 정규화된 형태로 저장).
+    pub async fn upsert(
+        &self,
+        definition: &SyntheticDefinition,
+    ) -> Result<SyntheticInstrumentRecord> {
+        sqlx::query_as::<_, SyntheticInstrumentRecord>(&format!(
+            r#"
+            INSERT INTO synthetic_instrument (code, name, expression)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (code) DO UPDATE SET
+                name = EXCLUDED.name,
+                expression = EXCLUDED.expression,
+                updated_at = NOW()
+            RETURNING {SYNTHETIC_COLUMNS}
+            "#
+        ))
+        .bind(&definition.code)
+        .bind(&definition.name)
+        .bind(definition.expression.to_string())
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| DataError::InsertError(e.to_string()))
+    }
+
+    /// 합성 종목 삭제.
+    pub async fn delete(&self, code: &str) -> Result<bool> {
+        let deleted = sqlx::query("DELETE FROM synthetic_instrument WHERE code = $1")
+            .bind(code)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| DataError::DeleteError(e.to_string()))?
+            .rows_affected();
+        Ok(deleted > 0)
+    }
+}
@@ -1,138 +1,32 @@
-//! Binance 거래소 커넥터.
+//! Binance Spot REST 클라이언트.
 //!
-//! Binance Spot용 REST API 및 WebSocket 연결 구현.
-//! 메인넷과 테스트넷 모두 지원.
+//! 계좌/시세 조회와 주문(제출, 취소, 조회)을 `Exchange` trait로 제공합니다.
+//! 모든 요청은 [`BinanceRateLimiter`]로 가중치를 예약한 뒤 전송합니다.
 
 #![allow(dead_code)] // API 응답 필드 전체 매핑 (일부만 사용)
 
+use super::config::BinanceConfig;
+use super::rate_limit::{depth_weight, BinanceRateLimiter};
 use crate::traits::{AccountInfo, Balance, Exchange, ExchangeResult};
 use crate::ExchangeError;
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use hmac::{Hmac, Mac};
-use reqwest::Client;
+use reqwest::{Client, Method};
 use rust_decimal::Decimal;
 use serde::Deserialize;
 use sha2::Sha256;
-use std::fmt;
 use std::sync::Arc;
 use std::time::{SystemTime, UNIX_EPOCH};
 use tracing::{debug, error, info, warn};
 use trader_core::{
-    Kline, MarketType, OrderBook, OrderBookLevel, OrderRequest, OrderStatus, OrderType, Position,
-    RoundMethod, Side, Symbol, TickSizeProvider, Ticker, Timeframe, TradeTick,
+    Kline, MarketType, OrderBook, OrderBookLevel, OrderRequest, OrderStatus, OrderStatusType,
+    OrderType, Position, RoundMethod, Side, Symbol, TickSizeProvider, Ticker, TimeInForce,
+    Timeframe, TradeTick,
 };
 
 type HmacSha256 = Hmac<Sha256>;
 
-// ============================================================================
-// 설정
-// ============================================================================
-
-/// Binance 클라이언트 설정.
-///
-/// # 보안
-/// - `Debug` 구현은 민감 정보(`api_key`, `api_secret`)를 마스킹합니다.
-#[derive(Clone)]
-pub struct BinanceConfig {
-    /// API 키
-    pub api_key: String,
-    /// API 시크릿
-    pub api_secret: String,
-    /// 테스트넷 사용
-    pub testnet: bool,
-    /// 요청 타임아웃 (초)
-    pub timeout_secs: u64,
-    /// 수신 윈도우 (밀리초)
-    pub recv_window: u64,
-}
-
-impl fmt::Debug for BinanceConfig {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let masked_key = if self.api_key.len() > 8 {
-            format!(
-                "{}...{}",
-                &self.api_key[..4],
-                &self.api_key[self.api_key.len() - 4..]
-            )
-        } else {
-            "***REDACTED***".to_string()
-        };
-
-        f.debug_struct("BinanceConfig")
-            .field("api_key", &masked_key)
-            .field("api_secret", &"***REDACTED***")
-            .field("testnet", &self.testnet)
-            .field("timeout_secs", &self.timeout_secs)
-            .field("recv_window", &self.recv_window)
-            .finish()
-    }
-}
-
-impl BinanceConfig {
-    /// 새 설정 생성.
-    pub fn new(api_key: String, api_secret: String) -> Self {
-        Self {
-            api_key,
-            api_secret,
-            testnet: false,
-            timeout_secs: 30,
-            recv_window: 5000,
-        }
-    }
-
-    /// 테스트넷 사용.
-    pub fn with_testnet(mut self, testnet: bool) -> Self {
-        self.testnet = testnet;
-        self
-    }
-
-    /// 환경 변수에서 생성.
-    pub fn from_env() -> Option<Self> {
-        let testnet = std::env::var("BINANCE_TESTNET")
-            .map(|v| v.to_lowercase() == "true")
-            .unwrap_or(false);
-
-        let (api_key, api_secret) = if testnet {
-            (
-                std::env::var("BINANCE_TESTNET_API_KEY").ok()?,
-                std::env::var("BINANCE_TESTNET_API_SECRET").ok()?,
-            )
-        } else {
-            (
-                std::env::var("BINANCE_API_KEY").ok()?,
-                std::env::var("BINANCE_API_SECRET").ok()?,
-            )
-        };
-
-        Some(Self {
-            api_key,
-            api_secret,
-            testnet,
-            timeout_secs: 30,
-            recv_window: 5000,
-        })
-    }
-
-    /// REST API 기본 URL 반환.
-    pub fn rest_base_url(&self) -> &str {
-        if self.testnet {
-            "https://testnet.binance.vision"
-        } else {
-            "https://api.binance.com"
-        }
-    }
-
-    /// WebSocket 기본 URL 반환.
-    pub fn ws_base_url(&self) -> &str {
-        if self.testnet {
-            "wss://testnet.binance.vision/ws"
-        } else {
-            "wss://stream.binance.com:9443/ws"
-        }
-    }
-}
-
 // ============================================================================
 // API 응답 타입
 // ============================================================================
@@ -220,15 +114,25 @@ struct BinanceOrderResponse {
     order_id: i64,
     client_order_id: String,
     transact_time: Option<i64>,
+    update_time: Option<i64>,
     price: String,
     orig_qty: String,
     executed_qty: String,
+    /// 누적 체결 금액 (호가 자산 기준, 평균 체결가 계산용)
+    #[serde(default)]
+    cummulative_quote_qty: Option<String>,
     status: String,
     #[serde(rename = "type")]
     order_type: String,
     side: String,
 }
 
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BinanceListenKey {
+    listen_key: String,
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct BinanceError {
@@ -247,6 +151,8 @@ pub struct BinanceClient {
     connected: bool,
     /// 호가 단위 제공자 (옵션, 설정 시 주문 가격 자동 라운딩)
     tick_size_provider: Option<Arc<dyn TickSizeProvider>>,
+    /// 요청 한도 관리자 (같은 계정의 클라이언트끼리 공유 가능)
+    rate_limiter: Arc<BinanceRateLimiter>,
 }
 
 impl BinanceClient {
@@ -267,9 +173,29 @@ impl BinanceClient {
             client,
             connected: false,
             tick_size_provider: None,
+            rate_limiter: Arc::new(BinanceRateLimiter::default()),
         })
     }
 
+    /// 요청 한도 관리자를 설정합니다.
+    ///
+    /// 같은 API 키로 여러 클라이언트를 만들 때 하나의 관리자를 공유해야
+    /// 계정 단위 주문 한도를 함께 집계합니다.
+    pub fn with_rate_limiter(mut self, rate_limiter: Arc<BinanceRateLimiter>) -> Self {
+        self.rate_limiter = rate_limiter;
+        self
+    }
+
+    /// 클라이언트 설정 참조.
+    pub fn config(&self) -> &BinanceConfig {
+        &self.config
+    }
+
+    /// 요청 한도 관리자 참조.
+    pub fn rate_limiter(&self) -> &Arc<BinanceRateLimiter> {
+        &self.rate_limiter
+    }
+
     /// 호가 단위 제공자를 설정합니다.
     ///
     /// 설정 시 주문 가격이 자동으로 호가 단위로 라운딩됩니다.
@@ -283,6 +209,40 @@ impl BinanceClient {
         self
     }
 
+    /// 사용자 데이터 스트림 listenKey 발급 (유효 기간 60분).
+    pub async fn create_listen_key(&self) -> ExchangeResult<String> {
+        let resp: BinanceListenKey = self
+            .api_key_request(Method::POST, "/api/v3/userDataStream", &[], 2)
+            .await?;
+        Ok(resp.listen_key)
+    }
+
+    /// listenKey 유효 기간 연장 (30분마다 호출 권장).
+    pub async fn keepalive_listen_key(&self, listen_key: &str) -> ExchangeResult<()> {
+        let _: serde_json::Value = self
+            .api_key_request(
+                Method::PUT,
+                "/api/v3/userDataStream",
+                &[("listenKey", listen_key.to_string())],
+                2,
+            )
+            .await?;
+        Ok(())
+    }
+
+    /// listenKey 폐기.
+    pub async fn close_listen_key(&self, listen_key: &str) -> ExchangeResult<()> {
+        let _: serde_json::Value = self
+            .api_key_request(
+                Method::DELETE,
+                "/api/v3/userDataStream",
+                &[("listenKey", listen_key.to_string())],
+                2,
+            )
+            .await?;
+        Ok(())
+    }
+
     /// 환경 변수에서 생성.
     ///
     /// 환경 변수가 설정되지 않았거나 클라이언트 생성에 실패하면 `None`을 반환합니다.
@@ -320,6 +280,7 @@ impl BinanceClient {
         &self,
         endpoint: &str,
         params: &[(&str, String)],
+        weight: u32,
     ) -> ExchangeResult<T> {
         let url = format!("{}{}", self.config.rest_base_url(), endpoint);
         let query = Self::build_query(params);
@@ -332,6 +293,7 @@ impl BinanceClient {
 
         debug!("GET {}", full_url);
 
+        self.rate_limiter.acquire(weight).await;
         let response = self
             .client
             .get(&full_url)
@@ -347,6 +309,7 @@ impl BinanceClient {
         &self,
         endpoint: &str,
         params: &[(&str, String)],
+        weight: u32,
     ) -> ExchangeResult<T> {
         let url = format!("{}{}", self.config.rest_base_url(), endpoint);
 
@@ -360,6 +323,7 @@ impl BinanceClient {
 
         debug!("GET (signed) {}", endpoint);
 
+        self.rate_limiter.acquire(weight).await;
         let response = self
             .client
             .get(&full_url)
@@ -371,11 +335,12 @@ impl BinanceClient {
         self.handle_response(response).await
     }
 
-    /// 서명된 POST 요청.
+    /// 서명된 POST 요청 (주문 API, 주문 수 한도 포함).
     async fn signed_post<T: for<'de> Deserialize<'de>>(
         &self,
         endpoint: &str,
         params: &[(&str, String)],
+        weight: u32,
     ) -> ExchangeResult<T> {
         let url = format!("{}{}", self.config.rest_base_url(), endpoint);
 
@@ -389,6 +354,7 @@ impl BinanceClient {
 
         debug!("POST (signed) {}", endpoint);
 
+        self.rate_limiter.acquire_order(weight).await;
         let response = self
             .client
             .post(&url)
@@ -407,6 +373,7 @@ impl BinanceClient {
         &self,
         endpoint: &str,
         params: &[(&str, String)],
+        weight: u32,
     ) -> ExchangeResult<T> {
         let url = format!("{}{}", self.config.rest_base_url(), endpoint);
 
@@ -420,6 +387,7 @@ impl BinanceClient {
 
         debug!("DELETE (signed) {}", endpoint);
 
+        self.rate_limiter.acquire(weight).await;
         let response = self
             .client
             .delete(&full_url)
@@ -431,12 +399,51 @@ impl BinanceClient {
         self.handle_response(response).await
     }
 
+    /// API 키 헤더만 필요한 요청 (사용자 데이터 스트림 listenKey 관리).
+    async fn api_key_request<T: for<'de> Deserialize<'de>>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: &[(&str, String)],
+        weight: u32,
+    ) -> ExchangeResult<T> {
+        let url = format!("{}{}", self.config.rest_base_url(), endpoint);
+        let query = Self::build_query(params);
+
+        debug!("{} (api key) {}", method, endpoint);
+
+        self.rate_limiter.acquire(weight).await;
+        let response = self
+            .client
+            .request(method, &url)
+            .header("X-MBX-APIKEY", &self.config.api_key)
+            .header("Content-Type", "application/x-www-form-urlencoded")
+            .body(query)
+            .send()
+            .await
+            .map_err(|e| ExchangeError::NetworkError(e.to_string()))?;
+
+        self.handle_response(response).await
+    }
+
     /// API 응답 처리.
     async fn handle_response<T: for<'de> Deserialize<'de>>(
         &self,
         response: reqwest::Response,
     ) -> ExchangeResult<T> {
         let status = response.status();
+        self.rate_limiter
+            .update_from_headers(response.headers())
+            .await;
+
+        // 429: 한도 초과, 418: 한도 초과 후 계속 요청하여 IP 차단
+        if status.as_u16() == 429 || status.as_u16() == 418 {
+            self.rate_limiter
+                .block_from_headers(response.headers())
+                .await;
+            return Err(ExchangeError::RateLimited);
+        }
+
         let body = response
             .text()
             .await
@@ -480,7 +487,7 @@ impl BinanceClient {
     }
 
     /// Binance 심볼 형식을 내부 Symbol로 변환.
-    fn to_symbol(binance_symbol: &str) -> Symbol {
+    pub(super) fn to_symbol(binance_symbol: &str) -> Symbol {
         // 일반적인 호가 자산
         let quotes = ["USDT", "BUSD", "BTC", "ETH", "BNB", "USDC"];
 
@@ -501,43 +508,69 @@ impl BinanceClient {
     }
 
     /// 문자열에서 Decimal 파싱.
-    fn parse_decimal(s: &str) -> Decimal {
+    pub(super) fn parse_decimal(s: &str) -> Decimal {
         s.parse().unwrap_or(Decimal::ZERO)
     }
 
-    /// Binance 주문 상태를 내부 OrderStatus로 변환.
-    fn parse_order_status(resp: &BinanceOrderResponse) -> OrderStatus {
-        let status = match resp.status.as_str() {
-            "NEW" => trader_core::OrderStatusType::Open,
-            "PARTIALLY_FILLED" => trader_core::OrderStatusType::PartiallyFilled,
-            "FILLED" => trader_core::OrderStatusType::Filled,
-            "CANCELED" => trader_core::OrderStatusType::Cancelled,
-            "REJECTED" => trader_core::OrderStatusType::Rejected,
-            "EXPIRED" => trader_core::OrderStatusType::Expired,
-            _ => trader_core::OrderStatusType::Open,
-        };
+    /// Binance 주문 상태 문자열을 내부 상태로 변환.
+    pub(super) fn parse_status_type(status: &str) -> OrderStatusType {
+        match status {
+            "NEW" => OrderStatusType::Open,
+            "PARTIALLY_FILLED" => OrderStatusType::PartiallyFilled,
+            "FILLED" => OrderStatusType::Filled,
+            "CANCELED" => OrderStatusType::Cancelled,
+            "REJECTED" => OrderStatusType::Rejected,
+            "EXPIRED" | "EXPIRED_IN_MATCH" => OrderStatusType::Expired,
+            _ => OrderStatusType::Open,
+        }
+    }
 
-        let side = match resp.side.as_str() {
-            "BUY" => Some(trader_core::Side::Buy),
-            "SELL" => Some(trader_core::Side::Sell),
+    /// Binance 주문 방향 문자열을 내부 방향으로 변환.
+    pub(super) fn parse_side(side: &str) -> Option<Side> {
+        match side {
+            "BUY" => Some(Side::Buy),
+            "SELL" => Some(Side::Sell),
             _ => None,
-        };
+        }
+    }
+
+    /// 누적 체결 금액 / 체결 수량으로 평균 체결가 계산.
+    pub(super) fn average_fill_price(executed_qty: Decimal, quote_qty: Decimal) -> Option<Decimal> {
+        if executed_qty > Decimal::ZERO && quote_qty > Decimal::ZERO {
+            Some(quote_qty / executed_qty)
+        } else {
+            None
+        }
+    }
+
+    /// Binance 주문 상태를 내부 OrderStatus로 변환.
+    fn parse_order_status(resp: &BinanceOrderResponse) -> OrderStatus {
+        let executed_qty = Self::parse_decimal(&resp.executed_qty);
+        let quote_qty = resp
+            .cummulative_quote_qty
+            .as_deref()
+            .map(Self::parse_decimal)
+            .unwrap_or(Decimal::ZERO);
+        let price = Self::parse_decimal(&resp.price);
+
+        let updated_at = resp
+            .update_time
+            .or(resp.transact_time)
+            .and_then(DateTime::from_timestamp_millis)
+            .unwrap_or_else(Utc::now);
 
         OrderStatus {
             order_id: resp.order_id.to_string(),
             client_order_id: Some(resp.client_order_id.clone()),
-            ticker: Some(resp.symbol.clone()),
-            side,
+            ticker: Some(Self::to_symbol(&resp.symbol).to_string()),
+            side: Self::parse_side(&resp.side),
             quantity: Some(Self::parse_decimal(&resp.orig_qty)),
-            price: Some(Self::parse_decimal(&resp.price)),
-            status,
-            filled_quantity: Self::parse_decimal(&resp.executed_qty),
-            average_price: if Self::parse_decimal(&resp.executed_qty) > Decimal::ZERO {
-                Some(Self::parse_decimal(&resp.price))
-            } else {
-                None
-            },
-            updated_at: Utc::now(),
+            // 시장가 주문은 가격 "0"
+            price: (price > Decimal::ZERO).then_some(price),
+            status: Self::parse_status_type(&resp.status),
+            filled_quantity: executed_qty,
+            average_price: Self::average_fill_price(executed_qty, quote_qty),
+            updated_at,
         }
     }
 }
@@ -567,7 +600,7 @@ impl Exchange for BinanceClient {
         );
 
         // 서버 시간 조회로 연결 테스트
-        let _: BinanceServerTime = self.public_get("/api/v3/time", &[]).await?;
+        let _: BinanceServerTime = self.public_get("/api/v3/time", &[], 1).await?;
 
         self.connected = true;
         info!("Connected to Binance successfully");
@@ -581,7 +614,7 @@ impl Exchange for BinanceClient {
     }
 
     async fn get_account(&self) -> ExchangeResult<AccountInfo> {
-        let resp: BinanceAccountInfo = self.signed_get("/api/v3/account", &[]).await?;
+        let resp: BinanceAccountInfo = self.signed_get("/api/v3/account", &[], 20).await?;
 
         let balances = resp
             .balances
@@ -619,7 +652,7 @@ impl Exchange for BinanceClient {
     async fn get_ticker(&self, symbol: &str) -> ExchangeResult<Ticker> {
         let binance_symbol = Self::from_symbol(symbol);
         let resp: BinanceTicker = self
-            .public_get("/api/v3/ticker/24hr", &[("symbol", binance_symbol)])
+            .public_get("/api/v3/ticker/24hr", &[("symbol", binance_symbol)], 2)
             .await?;
 
         Ok(Ticker {
@@ -638,12 +671,13 @@ impl Exchange for BinanceClient {
 
     async fn get_order_book(&self, symbol: &str, limit: Option<u32>) -> ExchangeResult<OrderBook> {
         let binance_symbol = Self::from_symbol(symbol);
-        let limit_str = limit.unwrap_or(100).to_string();
+        let limit = limit.unwrap_or(100);
 
         let resp: BinanceOrderBook = self
             .public_get(
                 "/api/v3/depth",
-                &[("symbol", binance_symbol), ("limit", limit_str)],
+                &[("symbol", binance_symbol), ("limit", limit.to_string())],
+                depth_weight(limit),
             )
             .await?;
 
@@ -685,6 +719,7 @@ impl Exchange for BinanceClient {
             .public_get(
                 "/api/v3/trades",
                 &[("symbol", binance_symbol), ("limit", limit_str)],
+                25,
             )
             .await?;
 
@@ -723,6 +758,7 @@ impl Exchange for BinanceClient {
                     ("interval", interval.to_string()),
                     ("limit", limit_str),
                 ],
+                2,
             )
             .await?;
 
@@ -745,8 +781,7 @@ impl Exchange for BinanceClient {
     }
 
     async fn place_order(&self, request: &OrderRequest) -> ExchangeResult<String> {
-        // TODO: SymbolResolver로 ticker → Symbol 변환 후 from_symbol 사용
-        let binance_symbol = request.ticker.clone();
+        let binance_symbol = Self::from_symbol(&request.ticker);
 
         let side = match request.side {
             Side::Buy => "BUY",
@@ -760,8 +795,17 @@ impl Exchange for BinanceClient {
             OrderType::StopLossLimit => "STOP_LOSS_LIMIT",
             OrderType::TakeProfit => "TAKE_PROFIT",
             OrderType::TakeProfitLimit => "TAKE_PROFIT_LIMIT",
-            OrderType::TrailingStop => "TRAILING_STOP_MARKET",
+            OrderType::TrailingStop => {
+                // Spot은 별도 주문 유형 없이 trailingDelta로만 지원 (BIPS 단위)
+                return Err(ExchangeError::NotSupported(
+                    "Binance Spot은 트레일링 스탑 주문을 지원하지 않습니다".to_string(),
+                ));
+            }
         };
+        let is_limit_type = matches!(
+            request.order_type,
+            OrderType::Limit | OrderType::StopLossLimit | OrderType::TakeProfitLimit
+        );
 
         // 호가 단위 라운딩 헬퍼 (클로저)
         let round_price = |price: Decimal, is_buy: bool| -> Decimal {
@@ -795,20 +839,35 @@ impl Exchange for BinanceClient {
             ("symbol", binance_symbol),
             ("side", side.to_string()),
             ("type", order_type.to_string()),
-            ("quantity", request.quantity.to_string()),
+            ("quantity", request.quantity.normalize().to_string()),
+            // 체결 내역까지 포함한 응답
+            ("newOrderRespType", "FULL".to_string()),
         ];
 
-        // 지정가 주문에 가격 추가 (라운딩 적용)
-        if let Some(price) = request.price {
+        // 지정가 계열 주문에만 가격/유효 기간 추가 (라운딩 적용)
+        if is_limit_type {
+            let price = request.price.ok_or_else(|| {
+                ExchangeError::OrderRejected(format!("{} order requires price", order_type))
+            })?;
+            let time_in_force = match request.time_in_force {
+                TimeInForce::GTC => "GTC",
+                TimeInForce::IOC => "IOC",
+                TimeInForce::FOK => "FOK",
+                TimeInForce::GTD => {
+                    return Err(ExchangeError::NotSupported(
+                        "Binance Spot은 GTD 주문을 지원하지 않습니다".to_string(),
+                    ));
+                }
+            };
             let rounded_price = round_price(price, is_buy);
-            params.push(("price", rounded_price.to_string()));
-            params.push(("timeInForce", "GTC".to_string()));
+            params.push(("price", rounded_price.normalize().to_string()));
+            params.push(("timeInForce", time_in_force.to_string()));
         }
 
         // 스톱 가격이 있으면 추가 (라운딩 적용)
         if let Some(stop_price) = request.stop_price {
             let rounded_stop = round_price(stop_price, is_buy);
-            params.push(("stopPrice", rounded_stop.to_string()));
+            params.push(("stopPrice", rounded_stop.normalize().to_string()));
         }
 
         // 클라이언트 주문 ID가 있으면 추가
@@ -821,7 +880,7 @@ impl Exchange for BinanceClient {
             side, order_type, request.quantity, request.ticker, request.price
         );
 
-        let resp: BinanceOrderResponse = self.signed_post("/api/v3/order", &params).await?;
+        let resp: BinanceOrderResponse = self.signed_post("/api/v3/order", &params, 1).await?;
 
         info!("Order placed successfully: {}", resp.order_id);
         Ok(resp.order_id.to_string())
@@ -835,7 +894,7 @@ impl Exchange for BinanceClient {
             ("orderId", order_id.to_string()),
         ];
 
-        let _: BinanceOrderResponse = self.signed_delete("/api/v3/order", &params).await?;
+        let _: BinanceOrderResponse = self.signed_delete("/api/v3/order", &params, 1).await?;
 
         info!("Order {} cancelled", order_id);
         Ok(())
@@ -849,20 +908,22 @@ impl Exchange for BinanceClient {
             ("orderId", order_id.to_string()),
         ];
 
-        let resp: BinanceOrderResponse = self.signed_get("/api/v3/order", &params).await?;
+        let resp: BinanceOrderResponse = self.signed_get("/api/v3/order", &params, 4).await?;
 
         Ok(Self::parse_order_status(&resp))
     }
 
     async fn get_open_orders(&self, symbol: Option<&str>) -> ExchangeResult<Vec<OrderStatus>> {
-        let params: Vec<(&str, String)> = if let Some(s) = symbol {
-            vec![("symbol", Self::from_symbol(s))]
+        // 심볼 미지정 시 전체 조회 (가중치 80)
+        let (params, weight): (Vec<(&str, String)>, u32) = if let Some(s) = symbol {
+            (vec![("symbol", Self::from_symbol(s))], 6)
         } else {
-            vec![]
+            (vec![], 80)
         };
 
-        let resp: Vec<BinanceOrderResponse> =
-            self.signed_get("/api/v3/openOrders", &params).await?;
+        let resp: Vec<BinanceOrderResponse> = self
+            .signed_get("/api/v3/openOrders", &params, weight)
+            .await?;
 
         Ok(resp.iter().map(Self::parse_order_status).collect())
     }
@@ -876,6 +937,7 @@ impl Exchange for BinanceClient {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rust_decimal_macros::dec;
 
     #[test]
     fn test_symbol_conversion() {
@@ -904,4 +966,55 @@ mod tests {
             "c8db56825ae71d6d79447849e617115f4a920fa2acdcab2b053c4b2838bd6b71"
         );
     }
+
+    #[test]
+    fn test_parse_order_status_average_price() {
+        let resp: BinanceOrderResponse = serde_json::from_str(
+            r#"{
+                "symbol": "BTCUSDT", "orderId": 28, "clientOrderId": "6gCrw2kRUAF9CvJDGP16IP",
+                "transactTime": 1507725176595, "price": "0.00000000",
+                "origQty": "2.00000000", "executedQty": "2.00000000",
+                "cummulativeQuoteQty": "100001.00000000", "status": "FILLED",
+                "type": "MARKET", "side": "SELL"
+            }"#,
+        )
+        .unwrap();
+
+        let status = BinanceClient::parse_order_status(&resp);
+        assert_eq!(status.ticker.as_deref(), Some("BTC/USDT"));
+        assert_eq!(status.side, Some(Side::Sell));
+        assert_eq!(status.status, OrderStatusType::Filled);
+        // 시장가 주문은 가격 없음, 평균 체결가는 누적 체결 금액 / 체결 수량
+        assert_eq!(status.price, None);
+        assert_eq!(status.average_price, Some(dec!(50000.5)));
+        assert_eq!(status.updated_at.timestamp_millis(), 1507725176595);
+    }
+
+    #[tokio::test]
+    async fn test_place_order_rejects_unsupported_types() {
+        let client =
+            BinanceClient::new(BinanceConfig::new("key".to_string(), "secret".to_string()))
+                .unwrap();
+
+        let mut trailing = OrderRequest::market_sell("BTC/USDT".to_string(), dec!(1));
+        trailing.order_type = OrderType::TrailingStop;
+        assert!(matches!(
+            client.place_order(&trailing).await,
+            Err(ExchangeError::NotSupported(_))
+        ));
+
+        let mut gtd = OrderRequest::limit_buy("BTC/USDT".to_string(), dec!(1), dec!(30000));
+        gtd.time_in_force = TimeInForce::GTD;
+        assert!(matches!(
+            client.place_order(&gtd).await,
+            Err(ExchangeError::NotSupported(_))
+        ));
+
+        let mut no_price = OrderRequest::limit_buy("BTC/USDT".to_string(), dec!(1), dec!(30000));
+        no_price.price = None;
+        assert!(matches!(
+            client.place_order(&no_price).await,
+            Err(ExchangeError::OrderRejected(_))
+        ));
+    }
 }
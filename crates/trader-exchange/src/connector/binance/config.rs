@@ -0,0 +1,107 @@
+//! Binance 클라이언트 설정.
+
+use std::fmt;
+
+/// Binance 클라이언트 설정.
+///
+/// # 보안
+/// - `Debug` 구현은 민감 정보(`api_key`, `api_secret`)를 마스킹합니다.
+#[derive(Clone)]
+pub struct BinanceConfig {
+    /// API 키
+    pub api_key: String,
+    /// API 시크릿
+    pub api_secret: String,
+    /// 테스트넷 사용
+    pub testnet: bool,
+    /// 요청 타임아웃 (초)
+    pub timeout_secs: u64,
+    /// 수신 윈도우 (밀리초)
+    pub recv_window: u64,
+}
+
+impl fmt::Debug for BinanceConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let masked_key = if self.api_key.len() > 8 {
+            format!(
+                "{}...{}",
+                &self.api_key[..4],
+                &self.api_key[self.api_key.len() - 4..]
+            )
+        } else {
+            "***REDACTED***".to_string()
+        };
+
+        f.debug_struct("BinanceConfig")
+            .field("api_key", &masked_key)
+            .field("api_secret", &"***REDACTED***")
+            .field("testnet", &self.testnet)
+            .field("timeout_secs", &self.timeout_secs)
+            .field("recv_window", &self.recv_window)
+            .finish()
+    }
+}
+
+impl BinanceConfig {
+    /// 새 설정 생성.
+    pub fn new(api_key: String, api_secret: String) -> Self {
+        Self {
+            api_key,
+            api_secret,
+            testnet: false,
+            timeout_secs: 30,
+            recv_window: 5000,
+        }
+    }
+
+    /// 테스트넷 사용.
+    pub fn with_testnet(mut self, testnet: bool) -> Self {
+        self.testnet = testnet;
+        self
+    }
+
+    /// 환경 변수에서 생성.
+    pub fn from_env() -> Option<Self> {
+        let testnet = std::env::var("BINANCE_TESTNET")
+            .map(|v| v.to_lowercase() == "true")
+            .unwrap_or(false);
+
+        let (api_key, api_secret) = if testnet {
+            (
+                std::env::var("BINANCE_TESTNET_API_KEY").ok()?,
+                std::env::var("BINANCE_TESTNET_API_SECRET").ok()?,
+            )
+        } else {
+            (
+                std::env::var("BINANCE_API_KEY").ok()?,
+                std::env::var("BINANCE_API_SECRET").ok()?,
+            )
+        };
+
+        Some(Self {
+            api_key,
+            api_secret,
+            testnet,
+            timeout_secs: 30,
+            recv_window: 5000,
+        })
+    }
+
+    /// REST API 기본 URL 반환.
+    pub fn rest_base_url(&self) -> &str {
+        if self.testnet {
+            "https://testnet.binance.vision"
+        } else {
+            "https://api.binance.com"
+        }
+    }
+
+    /// WebSocket 기본 URL 반환.
+    pub fn ws_base_url(&self) -> &str {
+        if self.testnet {
+            "wss://testnet.binance.vision/ws"
+        } else {
+            "wss://stream.binance.com:9443/ws"
+        }
+    }
+}
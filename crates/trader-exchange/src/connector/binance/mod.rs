@@ -0,0 +1,49 @@
+//! Binance Spot 거래소 연동 모듈.
+//!
+//! # 기능
+//!
+//! - REST 주문 (시장가/지정가/스톱 계열, IOC/FOK), 주문 취소/조회
+//! - 계좌 잔고 조회
+//! - 사용자 데이터 스트림 (주문 체결, 잔고 변경 실시간 수신)
+//! - 요청 가중치/주문 수 한도 관리 (429/418 대응)
+//! - 테스트넷 지원 (`BINANCE_TESTNET=true`)
+//!
+//! 시장 데이터 WebSocket은 [`crate::websocket::BinanceMarketStream`]을 사용합니다.
+//!
+//! # API 문서
+//!
+//! 공식 API 문서: <https://developers.binance.com/docs/binance-spot-api-docs>
+//!
+//! # 사용 예제
+//!
+//! ```rust,ignore
+//! use std::sync::Arc;
+//! use trader_core::OrderRequest;
+//! use trader_exchange::connector::binance::{BinanceClient, BinanceConfig, BinanceUserStream};
+//! use trader_exchange::{Exchange, UserStream};
+//!
+//! let config = BinanceConfig::new("api_key".to_string(), "api_secret".to_string())
+//!     .with_testnet(true);
+//! let mut client = BinanceClient::new(config)?;
+//! client.connect().await?;
+//!
+//! let order = OrderRequest::limit_buy("BTC/USDT".to_string(), dec!(0.001), dec!(30000));
+//! let order_id = client.place_order(&order).await?;
+//!
+//! // 체결 이벤트 수신
+//! let mut stream = BinanceUserStream::new(Arc::new(client));
+//! stream.start().await?;
+//! while let Some(event) = stream.next_event().await {
+//!     println!("{:?}", event);
+//! }
+//! ```
+
+pub mod client;
+pub mod config;
+pub mod rate_limit;
+pub mod user_stream;
+
+pub use client::BinanceClient;
+pub use config::BinanceConfig;
+pub use rate_limit::{BinanceRateLimiter, RateLimitConfig};
+pub use user_stream::BinanceUserStream;
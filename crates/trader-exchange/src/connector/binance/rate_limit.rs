@@ -0,0 +1,302 @@
+//! Binance 요청 한도 관리.
+//!
+//! Binance Spot은 IP 단위 요청 가중치(분당)와 계정 단위 주문 수(10초당)를 제한합니다.
+//! 한도를 넘기면 HTTP 429가, 429 이후에도 계속 요청하면 HTTP 418(IP 차단)이 반환되므로
+//! 요청 전에 로컬에서 가중치를 예약하고, 응답 헤더로 서버 집계와 동기화합니다.
+//!
+//! - `X-MBX-USED-WEIGHT-1M`: 현재 분 윈도우의 사용 가중치 (서버 집계)
+//! - `Retry-After`: 429/418 응답 시 대기 시간 (초)
+
+use reqwest::header::HeaderMap;
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+use tracing::warn;
+
+/// 가중치 윈도우 (1분).
+const WEIGHT_WINDOW: Duration = Duration::from_secs(60);
+
+/// 주문 수 윈도우 (10초).
+const ORDER_WINDOW: Duration = Duration::from_secs(10);
+
+/// 요청 한도 설정.
+#[derive(Debug, Clone)]
+pub struct RateLimitConfig {
+    /// 분당 요청 가중치 한도 (Binance Spot 기본: 6000)
+    pub weight_per_minute: u32,
+    /// 10초당 주문 수 한도 (Binance Spot 기본: 100)
+    pub orders_per_10s: u32,
+    /// 한도 대비 사용 비율 (예: 0.8 = 한도의 80%까지만 사용, 다른 프로세스 몫 확보)
+    pub safety_ratio: f64,
+}
+
+impl Default for RateLimitConfig {
+    fn default() -> Self {
+        Self {
+            weight_per_minute: 6000,
+            orders_per_10s: 100,
+            safety_ratio: 0.8,
+        }
+    }
+}
+
+impl RateLimitConfig {
+    fn effective(limit: u32, ratio: f64) -> u32 {
+        ((limit as f64 * ratio.clamp(0.1, 1.0)) as u32).max(1)
+    }
+}
+
+/// 고정 윈도우 카운터.
+#[derive(Debug)]
+struct WindowCounter {
+    window: Duration,
+    limit: u32,
+    started_at: Instant,
+    used: u32,
+}
+
+impl WindowCounter {
+    fn new(window: Duration, limit: u32, now: Instant) -> Self {
+        Self {
+            window,
+            limit,
+            started_at: now,
+            used: 0,
+        }
+    }
+
+    fn roll(&mut self, now: Instant) {
+        if now.duration_since(self.started_at) >= self.window {
+            self.started_at = now;
+            self.used = 0;
+        }
+    }
+
+    /// `cost`만큼 예약. 한도를 넘으면 다음 윈도우까지 대기 시간 반환.
+    fn reserve(&mut self, cost: u32, now: Instant) -> Result<(), Duration> {
+        self.roll(now);
+        // 단일 요청이 한도보다 크면 빈 윈도우에서는 허용
+        if self.used > 0 && self.used + cost > self.limit {
+            return Err(self.window - now.duration_since(self.started_at));
+        }
+        self.used += cost;
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+struct LimiterState {
+    weight: WindowCounter,
+    orders: WindowCounter,
+    /// 429/418 응답 후 요청 금지 해제 시각
+    blocked_until: Option<Instant>,
+}
+
+/// Binance 요청 한도 관리자.
+///
+/// 같은 API 키(계정)와 IP를 쓰는 클라이언트/사용자 스트림이 `Arc`로 공유합니다.
+#[derive(Debug)]
+pub struct BinanceRateLimiter {
+    state: Mutex<LimiterState>,
+}
+
+impl Default for BinanceRateLimiter {
+    fn default() -> Self {
+        Self::new(RateLimitConfig::default())
+    }
+}
+
+impl BinanceRateLimiter {
+    /// 새 한도 관리자 생성.
+    pub fn new(config: RateLimitConfig) -> Self {
+        let now = Instant::now();
+        Self {
+            state: Mutex::new(LimiterState {
+                weight: WindowCounter::new(
+                    WEIGHT_WINDOW,
+                    RateLimitConfig::effective(config.weight_per_minute, config.safety_ratio),
+                    now,
+                ),
+                orders: WindowCounter::new(
+                    ORDER_WINDOW,
+                    RateLimitConfig::effective(config.orders_per_10s, config.safety_ratio),
+                    now,
+                ),
+                blocked_until: None,
+            }),
+        }
+    }
+
+    /// 요청 가중치 예약 (한도 초과 시 다음 윈도우까지 대기).
+    pub async fn acquire(&self, weight: u32) {
+        self.acquire_inner(weight, false).await;
+    }
+
+    /// 주문 요청 예약 (가중치 + 주문 수).
+    pub async fn acquire_order(&self, weight: u32) {
+        self.acquire_inner(weight, true).await;
+    }
+
+    async fn acquire_inner(&self, weight: u32, is_order: bool) {
+        loop {
+            let wait = {
+                let mut state = self.state.lock().await;
+                let now = Instant::now();
+                match state.blocked_until {
+                    Some(until) if until > now => Some(until - now),
+                    _ => {
+                        state.blocked_until = None;
+                        Self::reserve(&mut state, weight, is_order, now).err()
+                    }
+                }
+            };
+
+            match wait {
+                None => return,
+                Some(delay) => {
+                    warn!(
+                        delay_ms = delay.as_millis() as u64,
+                        weight, is_order, "Binance 요청 한도 도달, 대기"
+                    );
+                    tokio::time::sleep(delay).await;
+                }
+            }
+        }
+    }
+
+    fn reserve(
+        state: &mut LimiterState,
+        weight: u32,
+        is_order: bool,
+        now: Instant,
+    ) -> Result<(), Duration> {
+        if is_order {
+            state.orders.roll(now);
+            if state.orders.used > 0 && state.orders.used + 1 > state.orders.limit {
+                return Err(ORDER_WINDOW - now.duration_since(state.orders.started_at));
+            }
+        }
+        state.weight.reserve(weight, now)?;
+        if is_order {
+            state.orders.used += 1;
+        }
+        Ok(())
+    }
+
+    /// 응답 헤더의 서버 집계 사용량을 반영합니다 (로컬 집계보다 클 때만).
+    pub async fn update_from_headers(&self, headers: &HeaderMap) {
+        let Some(used) = header_u32(headers, "x-mbx-used-weight-1m") else {
+            return;
+        };
+        let mut state = self.state.lock().await;
+        state.weight.roll(Instant::now());
+        state.weight.used = state.weight.used.max(used);
+    }
+
+    /// 429/418 응답을 반영하여 `Retry-After` 동안 요청을 멈춥니다.
+    pub async fn block_from_headers(&self, headers: &HeaderMap) {
+        let delay = header_u32(headers, "retry-after")
+            .map(|secs| Duration::from_secs(secs as u64))
+            .unwrap_or(WEIGHT_WINDOW);
+        let mut state = self.state.lock().await;
+        let until = Instant::now() + delay;
+        state.blocked_until = Some(state.blocked_until.map_or(until, |b| b.max(until)));
+        warn!(
+            retry_after_secs = delay.as_secs(),
+            "Binance 요청 한도 초과 응답, 요청 중지"
+        );
+    }
+
+    /// 현재 윈도우 사용 가중치.
+    pub async fn used_weight(&self) -> u32 {
+        let mut state = self.state.lock().await;
+        state.weight.roll(Instant::now());
+        state.weight.used
+    }
+}
+
+fn header_u32(headers: &HeaderMap, name: &str) -> Option<u32> {
+    headers.get(name)?.to_str().ok()?.trim().parse().ok()
+}
+
+/// 호가창 조회 가중치 (`limit`별).
+pub fn depth_weight(limit: u32) -> u32 {
+    match limit {
+        0..=100 => 5,
+        101..=500 => 25,
+        501..=1000 => 50,
+        _ => 250,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    #[test]
+    fn test_window_counter_blocks_until_next_window() {
+        let start = Instant::now();
+        let mut counter = WindowCounter::new(Duration::from_secs(60), 10, start);
+
+        assert!(counter.reserve(6, start).is_ok());
+        assert!(counter.reserve(4, start).is_ok());
+
+        let wait = counter
+            .reserve(1, start + Duration::from_secs(15))
+            .unwrap_err();
+        assert_eq!(wait, Duration::from_secs(45));
+
+        // 다음 윈도우에서 초기화
+        assert!(counter.reserve(10, start + Duration::from_secs(60)).is_ok());
+    }
+
+    #[test]
+    fn test_window_counter_allows_oversized_request_in_empty_window() {
+        let start = Instant::now();
+        let mut counter = WindowCounter::new(Duration::from_secs(60), 10, start);
+        assert!(counter.reserve(50, start).is_ok());
+        assert!(counter.reserve(1, start).is_err());
+    }
+
+    #[test]
+    fn test_order_limit() {
+        let now = Instant::now();
+        let limiter = BinanceRateLimiter::new(RateLimitConfig {
+            weight_per_minute: 1000,
+            orders_per_10s: 2,
+            safety_ratio: 1.0,
+        });
+        let mut state = limiter.state.try_lock().unwrap();
+
+        assert!(BinanceRateLimiter::reserve(&mut state, 1, true, now).is_ok());
+        assert!(BinanceRateLimiter::reserve(&mut state, 1, true, now).is_ok());
+        assert!(BinanceRateLimiter::reserve(&mut state, 1, true, now).is_err());
+        // 주문이 아닌 요청은 가중치만 확인
+        assert!(BinanceRateLimiter::reserve(&mut state, 1, false, now).is_ok());
+        assert_eq!(state.weight.used, 3);
+    }
+
+    #[tokio::test]
+    async fn test_update_from_headers() {
+        let limiter = BinanceRateLimiter::default();
+        limiter.acquire(10).await;
+
+        let mut headers = HeaderMap::new();
+        headers.insert("x-mbx-used-weight-1m", HeaderValue::from_static("1200"));
+        limiter.update_from_headers(&headers).await;
+        assert_eq!(limiter.used_weight().await, 1200);
+
+        // 서버 집계가 더 작으면 로컬 집계 유지
+        headers.insert("x-mbx-used-weight-1m", HeaderValue::from_static("5"));
+        limiter.update_from_headers(&headers).await;
+        assert_eq!(limiter.used_weight().await, 1200);
+    }
+
+    #[test]
+    fn test_depth_weight() {
+        assert_eq!(depth_weight(100), 5);
+        assert_eq!(depth_weight(500), 25);
+        assert_eq!(depth_weight(1000), 50);
+        assert_eq!(depth_weight(5000), 250);
+    }
+}
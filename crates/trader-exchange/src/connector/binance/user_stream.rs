@@ -0,0 +1,467 @@
+//! Binance 사용자 데이터 스트림.
+//!
+//! listenKey로 WebSocket에 접속하여 주문 체결(`executionReport`)과
+//! 잔고 변경(`outboundAccountPosition`)을 [`UserEvent`]로 전달합니다.
+//!
+//! - listenKey는 60분 후 만료되므로 30분마다 keepalive를 보냅니다.
+//! - 연결이 끊기면 지수 백오프로 재접속하고, `listenKeyExpired` 수신 시 키를 재발급합니다.
+//!
+//! # 사용 예제
+//!
+//! ```rust,ignore
+//! use std::sync::Arc;
+//! use trader_exchange::connector::binance::{BinanceClient, BinanceUserStream};
+//!
+//! let client = Arc::new(BinanceClient::from_env().expect("BINANCE_API_KEY 필요"));
+//! let mut stream = BinanceUserStream::new(client);
+//! stream.start().await?;
+//!
+//! while let Some(event) = stream.next_event().await {
+//!     println!("{:?}", event);
+//! }
+//! ```
+
+use super::client::BinanceClient;
+use crate::traits::{Balance, ExchangeResult, UserEvent, UserStream};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use futures::StreamExt;
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{mpsc, RwLock};
+use tokio::task::JoinHandle;
+use tokio_tungstenite::{connect_async, tungstenite::Message};
+use tracing::{debug, error, info, warn};
+use trader_core::OrderStatus;
+
+/// listenKey keepalive 간격 (Binance 권장: 30분).
+const KEEPALIVE_INTERVAL: Duration = Duration::from_secs(30 * 60);
+
+/// 재접속 최초 대기 시간.
+const RECONNECT_BASE_DELAY: Duration = Duration::from_secs(1);
+
+/// 재접속 최대 대기 시간.
+const RECONNECT_MAX_DELAY: Duration = Duration::from_secs(60);
+
+// ============================================================================
+// WebSocket 메시지 타입
+// ============================================================================
+
+/// 이벤트 유형만 먼저 확인하기 위한 헤더.
+#[derive(Debug, Deserialize)]
+struct WsEventHeader {
+    #[serde(rename = "e")]
+    event_type: String,
+}
+
+/// 주문 체결/상태 변경 이벤트.
+#[derive(Debug, Deserialize)]
+struct WsExecutionReport {
+    #[serde(rename = "s")]
+    symbol: String,
+    #[serde(rename = "c")]
+    client_order_id: String,
+    /// 취소 시 원 주문의 클라이언트 ID (그 외에는 빈 문자열)
+    #[serde(rename = "C", default)]
+    orig_client_order_id: String,
+    #[serde(rename = "S")]
+    side: String,
+    #[serde(rename = "q")]
+    quantity: String,
+    #[serde(rename = "p")]
+    price: String,
+    #[serde(rename = "X")]
+    status: String,
+    #[serde(rename = "i")]
+    order_id: i64,
+    #[serde(rename = "z")]
+    cumulative_filled_qty: String,
+    #[serde(rename = "Z")]
+    cumulative_quote_qty: String,
+    #[serde(rename = "T")]
+    transaction_time: i64,
+}
+
+/// 계좌 잔고 변경 이벤트 (변경된 자산만 포함).
+#[derive(Debug, Deserialize)]
+struct WsAccountPosition {
+    #[serde(rename = "B")]
+    balances: Vec<WsBalance>,
+}
+
+#[derive(Debug, Deserialize)]
+struct WsBalance {
+    #[serde(rename = "a")]
+    asset: String,
+    #[serde(rename = "f")]
+    free: String,
+    #[serde(rename = "l")]
+    locked: String,
+}
+
+/// 파싱된 사용자 스트림 메시지.
+#[derive(Debug)]
+enum StreamMessage {
+    /// 전달할 이벤트 (관심 없는 메시지는 빈 목록)
+    Events(Vec<UserEvent>),
+    /// listenKey 만료 (재발급 후 재접속 필요)
+    ListenKeyExpired,
+}
+
+/// WebSocket 텍스트 메시지를 사용자 이벤트로 변환합니다.
+fn parse_message(text: &str) -> StreamMessage {
+    let Ok(header) = serde_json::from_str::<WsEventHeader>(text) else {
+        return StreamMessage::Events(Vec::new());
+    };
+
+    match header.event_type.as_str() {
+        "executionReport" => match serde_json::from_str::<WsExecutionReport>(text) {
+            Ok(report) => StreamMessage::Events(vec![UserEvent::OrderUpdate(
+                execution_report_to_status(&report),
+            )]),
+            Err(e) => {
+                warn!("executionReport 파싱 실패: {}", e);
+                StreamMessage::Events(Vec::new())
+            }
+        },
+        "outboundAccountPosition" => match serde_json::from_str::<WsAccountPosition>(text) {
+            Ok(position) => StreamMessage::Events(
+                position
+                    .balances
+                    .into_iter()
+                    .map(|b| {
+                        UserEvent::BalanceUpdate(Balance {
+                            asset: b.asset,
+                            free: BinanceClient::parse_decimal(&b.free),
+                            locked: BinanceClient::parse_decimal(&b.locked),
+                        })
+                    })
+                    .collect(),
+            ),
+            Err(e) => {
+                warn!("outboundAccountPosition 파싱 실패: {}", e);
+                StreamMessage::Events(Vec::new())
+            }
+        },
+        "listenKeyExpired" => StreamMessage::ListenKeyExpired,
+        // balanceUpdate(입출금 델타) 등은 outboundAccountPosition으로 반영됨
+        _ => StreamMessage::Events(Vec::new()),
+    }
+}
+
+fn execution_report_to_status(report: &WsExecutionReport) -> OrderStatus {
+    let filled = BinanceClient::parse_decimal(&report.cumulative_filled_qty);
+    let quote = BinanceClient::parse_decimal(&report.cumulative_quote_qty);
+    let price = BinanceClient::parse_decimal(&report.price);
+    let client_order_id = if report.orig_client_order_id.is_empty() {
+        report.client_order_id.clone()
+    } else {
+        report.orig_client_order_id.clone()
+    };
+
+    OrderStatus {
+        order_id: report.order_id.to_string(),
+        client_order_id: Some(client_order_id),
+        ticker: Some(BinanceClient::to_symbol(&report.symbol).to_string()),
+        side: BinanceClient::parse_side(&report.side),
+        quantity: Some(BinanceClient::parse_decimal(&report.quantity)),
+        price: (price > Decimal::ZERO).then_some(price),
+        status: BinanceClient::parse_status_type(&report.status),
+        filled_quantity: filled,
+        average_price: BinanceClient::average_fill_price(filled, quote),
+        updated_at: DateTime::from_timestamp_millis(report.transaction_time)
+            .unwrap_or_else(Utc::now),
+    }
+}
+
+/// 재접속 대기 시간 (지수 백오프, 최대 `RECONNECT_MAX_DELAY`).
+fn reconnect_delay(attempt: u32) -> Duration {
+    RECONNECT_BASE_DELAY
+        .saturating_mul(2u32.saturating_pow(attempt.min(16)))
+        .min(RECONNECT_MAX_DELAY)
+}
+
+// ============================================================================
+// Binance 사용자 스트림
+// ============================================================================
+
+/// Binance 사용자 데이터 스트림.
+pub struct BinanceUserStream {
+    client: Arc<BinanceClient>,
+    listen_key: Arc<RwLock<Option<String>>>,
+    event_tx: mpsc::Sender<UserEvent>,
+    event_rx: mpsc::Receiver<UserEvent>,
+    tasks: Vec<JoinHandle<()>>,
+}
+
+impl BinanceUserStream {
+    /// 새 사용자 스트림 생성 (`start` 호출 전까지 접속하지 않음).
+    pub fn new(client: Arc<BinanceClient>) -> Self {
+        let (event_tx, event_rx) = mpsc::channel(1000);
+        Self {
+            client,
+            listen_key: Arc::new(RwLock::new(None)),
+            event_tx,
+            event_rx,
+            tasks: Vec::new(),
+        }
+    }
+
+    /// 실행 중 여부.
+    pub fn is_running(&self) -> bool {
+        self.tasks.iter().any(|task| !task.is_finished())
+    }
+
+    /// 수신 태스크: 접속 → 메시지 전달, 끊기면 백오프 후 재접속.
+    async fn run_reader(
+        client: Arc<BinanceClient>,
+        listen_key: Arc<RwLock<Option<String>>>,
+        tx: mpsc::Sender<UserEvent>,
+    ) {
+        let mut attempt = 0u32;
+
+        loop {
+            let key = match Self::current_or_new_key(&client, &listen_key).await {
+                Ok(key) => key,
+                Err(e) => {
+                    error!("Binance listenKey 발급 실패: {}", e);
+                    tokio::time::sleep(reconnect_delay(attempt)).await;
+                    attempt = attempt.saturating_add(1);
+                    continue;
+                }
+            };
+
+            let url = format!("{}/{}", client.config().ws_base_url(), key);
+            match connect_async(url.as_str()).await {
+                Ok((ws, _)) => {
+                    info!("Binance 사용자 데이터 스트림 연결");
+                    attempt = 0;
+                    let (_write, mut read) = ws.split();
+
+                    while let Some(msg) = read.next().await {
+                        match msg {
+                            Ok(Message::Text(text)) => match parse_message(&text) {
+                                StreamMessage::Events(events) => {
+                                    for event in events {
+                                        if tx.send(event).await.is_err() {
+                                            debug!("사용자 이벤트 수신자 종료, 스트림 중지");
+                                            return;
+                                        }
+                                    }
+                                }
+                                StreamMessage::ListenKeyExpired => {
+                                    warn!("Binance listenKey 만료, 재발급 후 재접속");
+                                    *listen_key.write().await = None;
+                                    break;
+                                }
+                            },
+                            Ok(Message::Close(frame)) => {
+                                warn!("Binance 사용자 스트림 종료 수신: {:?}", frame);
+                                break;
+                            }
+                            Ok(_) => {}
+                            Err(e) => {
+                                error!("Binance 사용자 스트림 에러: {}", e);
+                                break;
+                            }
+                        }
+                    }
+                }
+                Err(e) => error!("Binance 사용자 스트림 연결 실패: {}", e),
+            }
+
+            if tx.is_closed() {
+                return;
+            }
+
+            let delay = reconnect_delay(attempt);
+            warn!("{}초 후 Binance 사용자 스트림 재접속", delay.as_secs());
+            tokio::time::sleep(delay).await;
+            attempt = attempt.saturating_add(1);
+        }
+    }
+
+    /// keepalive 태스크: 주기적으로 listenKey 유효 기간 연장.
+    async fn run_keepalive(client: Arc<BinanceClient>, listen_key: Arc<RwLock<Option<String>>>) {
+        let mut interval = tokio::time::interval(KEEPALIVE_INTERVAL);
+        // 첫 tick은 즉시 발생하므로 건너뜀
+        interval.tick().await;
+
+        loop {
+            interval.tick().await;
+            let Some(key) = listen_key.read().await.clone() else {
+                continue;
+            };
+            if let Err(e) = client.keepalive_listen_key(&key).await {
+                // 만료된 키는 다음 재접속 때 재발급
+                warn!("Binance listenKey keepalive 실패: {}", e);
+                *listen_key.write().await = None;
+            }
+        }
+    }
+
+    async fn current_or_new_key(
+        client: &BinanceClient,
+        listen_key: &RwLock<Option<String>>,
+    ) -> ExchangeResult<String> {
+        if let Some(key) = listen_key.read().await.clone() {
+            return Ok(key);
+        }
+        let key = client.create_listen_key().await?;
+        *listen_key.write().await = Some(key.clone());
+        Ok(key)
+    }
+}
+
+#[async_trait]
+impl UserStream for BinanceUserStream {
+    async fn start(&mut self) -> ExchangeResult<()> {
+        if self.is_running() {
+            return Ok(());
+        }
+
+        // 인증 오류는 재시도 루프에 들어가기 전에 바로 반환
+        Self::current_or_new_key(&self.client, &self.listen_key).await?;
+
+        self.tasks.push(tokio::spawn(Self::run_reader(
+            self.client.clone(),
+            self.listen_key.clone(),
+            self.event_tx.clone(),
+        )));
+        self.tasks.push(tokio::spawn(Self::run_keepalive(
+            self.client.clone(),
+            self.listen_key.clone(),
+        )));
+
+        info!("Binance 사용자 데이터 스트림 시작");
+        Ok(())
+    }
+
+    async fn stop(&mut self) -> ExchangeResult<()> {
+        for task in self.tasks.drain(..) {
+            task.abort();
+        }
+
+        if let Some(key) = self.listen_key.write().await.take() {
+            self.client.close_listen_key(&key).await?;
+        }
+
+        info!("Binance 사용자 데이터 스트림 중지");
+        Ok(())
+    }
+
+    async fn next_event(&mut self) -> Option<UserEvent> {
+        self.event_rx.recv().await
+    }
+}
+
+impl Drop for BinanceUserStream {
+    fn drop(&mut self) {
+        for task in &self.tasks {
+            task.abort();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+    use trader_core::{OrderStatusType, Side};
+
+    #[test]
+    fn test_parse_execution_report() {
+        let text = r#"{
+            "e": "executionReport", "E": 1499405658658, "s": "ETHBTC",
+            "c": "mUvoqJxFIILMdfAW5iGSOW", "S": "BUY", "o": "LIMIT", "f": "GTC",
+            "q": "2.00000000", "p": "0.10264410", "P": "0.00000000",
+            "x": "TRADE", "X": "PARTIALLY_FILLED", "i": 4293153,
+            "l": "1.00000000", "z": "1.00000000", "L": "0.10000000",
+            "n": "0", "N": null, "T": 1499405658657, "t": 1, "C": "",
+            "Z": "0.10000000"
+        }"#;
+
+        let StreamMessage::Events(events) = parse_message(text) else {
+            panic!("이벤트가 아님");
+        };
+        assert_eq!(events.len(), 1);
+        let UserEvent::OrderUpdate(status) = &events[0] else {
+            panic!("주문 업데이트가 아님");
+        };
+
+        assert_eq!(status.order_id, "4293153");
+        assert_eq!(
+            status.client_order_id.as_deref(),
+            Some("mUvoqJxFIILMdfAW5iGSOW")
+        );
+        assert_eq!(status.ticker.as_deref(), Some("ETH/BTC"));
+        assert_eq!(status.side, Some(Side::Buy));
+        assert_eq!(status.status, OrderStatusType::PartiallyFilled);
+        assert_eq!(status.filled_quantity, dec!(1));
+        assert_eq!(status.average_price, Some(dec!(0.1)));
+    }
+
+    #[test]
+    fn test_parse_cancel_uses_original_client_id() {
+        let text = r#"{
+            "e": "executionReport", "s": "BTCUSDT", "c": "cancel-req",
+            "C": "my-order-1", "S": "SELL", "q": "0.5", "p": "0",
+            "X": "CANCELED", "i": 7, "z": "0", "Z": "0", "T": 1700000000000
+        }"#;
+
+        let StreamMessage::Events(events) = parse_message(text) else {
+            panic!("이벤트가 아님");
+        };
+        let UserEvent::OrderUpdate(status) = &events[0] else {
+            panic!("주문 업데이트가 아님");
+        };
+        assert_eq!(status.client_order_id.as_deref(), Some("my-order-1"));
+        assert_eq!(status.status, OrderStatusType::Cancelled);
+        assert_eq!(status.price, None);
+        assert_eq!(status.average_price, None);
+    }
+
+    #[test]
+    fn test_parse_account_position() {
+        let text = r#"{
+            "e": "outboundAccountPosition", "E": 1564034571105, "u": 1564034571073,
+            "B": [
+                {"a": "ETH", "f": "10000.000000", "l": "0.000000"},
+                {"a": "USDT", "f": "250.5", "l": "49.5"}
+            ]
+        }"#;
+
+        let StreamMessage::Events(events) = parse_message(text) else {
+            panic!("이벤트가 아님");
+        };
+        assert_eq!(events.len(), 2);
+        let UserEvent::BalanceUpdate(balance) = &events[1] else {
+            panic!("잔고 업데이트가 아님");
+        };
+        assert_eq!(balance.asset, "USDT");
+        assert_eq!(balance.total(), dec!(300));
+    }
+
+    #[test]
+    fn test_parse_listen_key_expired_and_unknown() {
+        let expired = r#"{"e": "listenKeyExpired", "E": 1576653824250, "listenKey": "abc"}"#;
+        assert!(matches!(
+            parse_message(expired),
+            StreamMessage::ListenKeyExpired
+        ));
+
+        let delta = r#"{"e": "balanceUpdate", "a": "BTC", "d": "100.0"}"#;
+        assert!(matches!(parse_message(delta), StreamMessage::Events(e) if e.is_empty()));
+        assert!(matches!(parse_message("not json"), StreamMessage::Events(e) if e.is_empty()));
+    }
+
+    #[test]
+    fn test_reconnect_delay() {
+        assert_eq!(reconnect_delay(0), Duration::from_secs(1));
+        assert_eq!(reconnect_delay(3), Duration::from_secs(8));
+        assert_eq!(reconnect_delay(10), RECONNECT_MAX_DELAY);
+        assert_eq!(reconnect_delay(u32::MAX), RECONNECT_MAX_DELAY);
+    }
+}
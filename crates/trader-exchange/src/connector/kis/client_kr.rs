@@ -7,31 +7,40 @@
 //!
 //! - 현재가 조회
 //! - 호가 조회
+//! - KOSPI200 옵션 체인 조회
 //! - 현금 매수/매도 주문
 
 #![allow(dead_code)] // KIS API 응답 필드 전체 매핑
 //! - 주문 정정/취소
 //! - 잔고 조회
 //! - 매수가능금액 조회
+//! - KOSPI200 / 미니 KOSPI200 선물 주문·취소 및 증거금 조회
 
 use super::auth::KisOAuth;
 use super::config::{KisAccountType, KisEnvironment};
-use super::tr_id;
+use super::{futures_order_type, tr_id};
 use crate::retry::RetryConfig;
 use crate::ExchangeError;
+use chrono::NaiveDate;
 use reqwest::Client;
+use rust_decimal::prelude::ToPrimitive;
 use rust_decimal::Decimal;
 use serde::Deserialize;
 use std::sync::Arc;
 use tracing::{debug, error, info, warn};
 use trader_core::{
-    ExecutionHistory, ExecutionRecord, OrderStatusType, RoundMethod, Side, TickSizeProvider,
+    option_maturity_month, ExecutionHistory, ExecutionRecord, OptionChain, OptionContract,
+    OptionGreeks, OptionQuote, OptionType, OrderStatusType, RoundMethod, Side, TickSizeProvider,
+    KOSPI200_OPTION_MULTIPLIER,
 };
 
 /// KIS API Rate Limit 에러 메시지 코드.
 /// KIS는 HTTP 500과 함께 이 코드를 반환합니다.
 const KIS_RATE_LIMIT_MSG_CODE: &str = "EGW00201";
 
+/// KOSPI200 옵션 기초자산 이름.
+const KOSPI200_UNDERLYING: &str = "KOSPI200";
+
 /// KIS API 응답이 Rate Limit 에러인지 확인.
 ///
 /// KIS API는 초당 거래건수 초과 시 HTTP 500 + JSON body로 에러를 반환합니다:
@@ -444,6 +453,42 @@ impl KisKrClient {
         Ok(resp.output1)
     }
 
+    /// KOSPI200 옵션 체인 조회 (옵션 전광판 콜/풋).
+    ///
+    /// 지정한 만기 월물의 전체 행사가에 대한 콜/풋 시세와 그릭스를 조회합니다.
+    ///
+    /// # 인자
+    /// * `expiry` - 만기일 (예: `kospi200_monthly_expiry(2026, 3)`), 월물("YYYYMM") 단위로 조회
+    pub async fn get_kospi200_option_chain(
+        &self,
+        expiry: NaiveDate,
+    ) -> Result<OptionChain, ExchangeError> {
+        let tr_id = self.get_tr_id(tr_id::KR_OPTION_BOARD_REAL, tr_id::KR_OPTION_BOARD_PAPER);
+        let url = format!(
+            "{}/uapi/domestic-futureoption/v1/quotations/display-board-callput",
+            self.oauth.config().rest_base_url()
+        );
+        let maturity = option_maturity_month(expiry);
+
+        self.execute_get_with_retry(
+            &url,
+            tr_id,
+            &[
+                ("FID_COND_MRKT_DIV_CODE", "O"),
+                ("FID_COND_SCR_DIV_CODE", "20503"),
+                ("FID_MRKT_CLS_CODE", "CO"),
+                ("FID_MTRT_CNT", &maturity),
+                ("FID_COND_MRKT_CLS_CODE", ""),
+                ("FID_MRKT_CLS_CODE1", "PO"),
+            ],
+            |body| {
+                debug!("KR option board response: {}", body);
+                parse_option_chain_response(body, expiry)
+            },
+        )
+        .await
+    }
+
     // ========================================
     // Trading APIs (주문)
     // ========================================
@@ -729,6 +774,146 @@ impl KisKrClient {
         Ok(resp.output)
     }
 
+    // ========================================
+    // Futures Trading APIs (선물 주문)
+    // ========================================
+
+    /// KOSPI200 / 미니 KOSPI200 선물 주간 주문.
+    ///
+    /// # 인자
+    /// * `futures_code` - 선물 단축코드 (예: "101W9000", "105W9000")
+    /// * `side` - 매수/매도 (매도는 신규 매도 또는 매수 포지션 청산)
+    /// * `quantity` - 주문 계약 수
+    /// * `price` - 주문 가격 (포인트, `None`이면 시장가)
+    pub async fn place_futures_order(
+        &self,
+        futures_code: &str,
+        side: Side,
+        quantity: u32,
+        price: Option<Decimal>,
+    ) -> Result<KrOrderResponse, ExchangeError> {
+        if self.oauth.config().account_type == KisAccountType::RealIsa {
+            return Err(ExchangeError::NotSupported(
+                "ISA 계좌는 선물 거래를 지원하지 않습니다.".to_string(),
+            ));
+        }
+
+        let tr_id = self.get_tr_id(tr_id::KR_FUTURES_ORDER_REAL, tr_id::KR_FUTURES_ORDER_PAPER);
+        let url = format!(
+            "{}/uapi/domestic-futureoption/v1/trading/order",
+            self.oauth.config().rest_base_url()
+        );
+
+        let order_type = match price {
+            Some(_) => futures_order_type::LIMIT,
+            None => futures_order_type::MARKET,
+        };
+        let body = serde_json::json!({
+            "ORD_PRCS_DVSN_CD": "02",
+            "CANO": self.oauth.config().cano(),
+            "ACNT_PRDT_CD": self.oauth.config().acnt_prdt_cd(),
+            "SLL_BUY_DVSN_CD": futures_side_code(side),
+            "SHTN_PDNO": futures_code,
+            "ORD_QTY": quantity.to_string(),
+            "UNIT_PRICE": price.unwrap_or(Decimal::ZERO).to_string(),
+            "NMPR_TYPE_CD": order_type,
+            "KRX_NMPR_CNDT_CD": "0",
+            "ORD_DVSN_CD": order_type,
+        });
+
+        info!(
+            "Placing KR futures {} order: {} x {} @ {}",
+            side,
+            futures_code,
+            quantity,
+            price.map_or_else(|| "MARKET".to_string(), |p| p.to_string())
+        );
+
+        let resp = self.submit_futures_order(&url, tr_id, &body).await?;
+
+        info!(
+            "KR futures order placed successfully: order_no={}, code={}",
+            resp.odno, futures_code
+        );
+
+        Ok(resp)
+    }
+
+    /// 선물 주문 취소.
+    ///
+    /// # 인자
+    /// * `order_no` - 원주문번호
+    /// * `quantity` - 취소 수량 (0 = 잔량 전부)
+    pub async fn cancel_futures_order(
+        &self,
+        order_no: &str,
+        quantity: u32,
+    ) -> Result<KrOrderResponse, ExchangeError> {
+        let tr_id = self.get_tr_id(
+            tr_id::KR_FUTURES_CANCEL_REAL,
+            tr_id::KR_FUTURES_CANCEL_PAPER,
+        );
+        let url = format!(
+            "{}/uapi/domestic-futureoption/v1/trading/order-rvsecncl",
+            self.oauth.config().rest_base_url()
+        );
+
+        let body = serde_json::json!({
+            "ORD_PRCS_DVSN_CD": "02",
+            "CANO": self.oauth.config().cano(),
+            "ACNT_PRDT_CD": self.oauth.config().acnt_prdt_cd(),
+            "RVSE_CNCL_DVSN_CD": "02",
+            "ORGN_ODNO": order_no,
+            "ORD_QTY": quantity.to_string(),
+            "UNIT_PRICE": "0",
+            "NMPR_TYPE_CD": futures_order_type::LIMIT,
+            "KRX_NMPR_CNDT_CD": "0",
+            "RMN_QTY_YN": if quantity == 0 { "Y" } else { "N" },
+            "ORD_DVSN_CD": futures_order_type::LIMIT,
+        });
+
+        info!("KR futures order CANCEL: order_no={}", order_no);
+
+        self.submit_futures_order(&url, tr_id, &body).await
+    }
+
+    /// 선물 주문/취소 요청 전송 (주문은 중복 체결 위험이 있어 재시도하지 않음).
+    async fn submit_futures_order(
+        &self,
+        url: &str,
+        tr_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<KrOrderResponse, ExchangeError> {
+        let hashkey = self.oauth.generate_hashkey(body).await?;
+        let headers = self.oauth.build_headers(tr_id, Some(&hashkey)).await?;
+
+        let response = self
+            .client
+            .post(url)
+            .headers(headers)
+            .json(body)
+            .send()
+            .await
+            .map_err(|e| ExchangeError::NetworkError(e.to_string()))?;
+
+        let status = response.status();
+        let response_body = response
+            .text()
+            .await
+            .map_err(|e| ExchangeError::NetworkError(e.to_string()))?;
+
+        if !status.is_success() {
+            error!("KR futures order failed: {} - {}", status, response_body);
+            return Err(ExchangeError::ApiError {
+                code: status.as_u16() as i32,
+                message: response_body,
+            });
+        }
+
+        debug!("KR futures order response: {}", response_body);
+        parse_order_response(&response_body)
+    }
+
     // ========================================
     // Account APIs (계좌)
     // ========================================
@@ -808,6 +993,39 @@ impl KisKrClient {
         })
     }
 
+    /// 선물옵션 잔고 및 증거금 조회.
+    ///
+    /// 보유 선물 포지션과 예탁금/위탁증거금/유지증거금/주문가능금액을 함께 반환합니다.
+    /// 추가증거금이 발생하면 `KrFuturesMargin::additional_margin`이 0보다 큽니다.
+    pub async fn get_futures_margin(&self) -> Result<KrFuturesBalance, ExchangeError> {
+        let tr_id = self.get_tr_id(
+            tr_id::KR_FUTURES_BALANCE_REAL,
+            tr_id::KR_FUTURES_BALANCE_PAPER,
+        );
+        let url = format!(
+            "{}/uapi/domestic-futureoption/v1/trading/inquire-balance",
+            self.oauth.config().rest_base_url()
+        );
+
+        self.execute_get_with_retry(
+            &url,
+            tr_id,
+            &[
+                ("CANO", self.oauth.config().cano()),
+                ("ACNT_PRDT_CD", self.oauth.config().acnt_prdt_cd()),
+                ("MGNA_DVSN", "01"),
+                ("EXCC_STAT_CD", "1"),
+                ("CTX_AREA_FK200", ""),
+                ("CTX_AREA_NK200", ""),
+            ],
+            |body| {
+                debug!("KR futures balance response: {}", body);
+                parse_futures_balance_response(body)
+            },
+        )
+        .await
+    }
+
     // ========================================
     // Chart Data APIs (차트/캔들 데이터)
     // ========================================
@@ -964,6 +1182,85 @@ impl KisKrClient {
         Ok(filtered)
     }
 
+    /// 국내 주식 일별 분봉 조회 (과거 일자).
+    ///
+    /// 지정한 일자의 `time` 이전(포함) 1분봉을 최신순으로 최대 120건 반환합니다.
+    /// 하루 전체를 받으려면 가장 이른 체결 시간을 기준으로 반복 호출해야 합니다.
+    /// 모의투자 환경에서는 지원되지 않습니다.
+    ///
+    /// # 인자
+    /// * `stock_code` - 종목코드
+    /// * `date` - 조회 일자 (YYYYMMDD)
+    /// * `time` - 조회 기준 시간 (HHMMSS)
+    pub async fn get_daily_minute_chart(
+        &self,
+        stock_code: &str,
+        date: &str,
+        time: &str,
+    ) -> Result<Vec<KrMinuteOhlcv>, ExchangeError> {
+        let tr_id = self.get_tr_id(
+            tr_id::KR_DAILY_MINUTE_PRICE_REAL,
+            tr_id::KR_DAILY_MINUTE_PRICE_PAPER,
+        );
+        let url = format!(
+            "{}/uapi/domestic-stock/v1/quotations/inquire-time-dailychartprice",
+            self.oauth.config().rest_base_url()
+        );
+
+        let headers = self.oauth.build_headers(tr_id, None).await?;
+
+        let response = self
+            .client
+            .get(&url)
+            .headers(headers)
+            .query(&[
+                ("FID_COND_MRKT_DIV_CODE", "J"),
+                ("FID_INPUT_ISCD", stock_code),
+                ("FID_INPUT_HOUR_1", time),
+                ("FID_INPUT_DATE_1", date),
+                ("FID_PW_DATA_INCU_YN", "N"),
+                ("FID_FAKE_TICK_INCU_YN", ""),
+            ])
+            .send()
+            .await
+            .map_err(|e| ExchangeError::NetworkError(e.to_string()))?;
+
+        let status = response.status();
+        let body = response
+            .text()
+            .await
+            .map_err(|e| ExchangeError::NetworkError(e.to_string()))?;
+
+        if !status.is_success() {
+            error!(
+                "KR daily minute chart inquiry failed: {} - {}",
+                status, body
+            );
+            return Err(ExchangeError::ApiError {
+                code: status.as_u16() as i32,
+                message: body,
+            });
+        }
+
+        debug!("KR daily minute chart response: {}", body);
+
+        let resp: KisKrMinuteChartResponse = serde_json::from_str(&body).map_err(|e| {
+            ExchangeError::ParseError(format!(
+                "Failed to parse daily minute chart response: {}",
+                e
+            ))
+        })?;
+
+        if resp.rt_cd != "0" {
+            return Err(ExchangeError::ApiError {
+                code: resp.msg_cd.parse().unwrap_or(-1),
+                message: resp.msg1,
+            });
+        }
+
+        Ok(resp.output2)
+    }
+
     // ========================================
     // Account APIs (계좌)
     // ========================================
@@ -1302,6 +1599,118 @@ pub struct KrOrderBook {
     pub total_bid_qty: Decimal,
 }
 
+/// KOSPI200 옵션 전광판 시세 (행사가별 콜 또는 풋 1건).
+#[derive(Debug, Clone, Deserialize)]
+pub struct KrOptionQuote {
+    /// 옵션 단축종목코드
+    #[serde(rename = "optn_shrn_iscd")]
+    pub code: String,
+    /// 행사가
+    #[serde(rename = "acpr", deserialize_with = "deserialize_decimal")]
+    pub strike: Decimal,
+    /// 현재가
+    #[serde(rename = "optn_prpr", deserialize_with = "deserialize_decimal")]
+    pub current_price: Decimal,
+    /// 전일대비
+    #[serde(
+        rename = "optn_prdy_vrss",
+        default,
+        deserialize_with = "deserialize_decimal"
+    )]
+    pub change: Decimal,
+    /// 전일대비율
+    #[serde(
+        rename = "optn_prdy_ctrt",
+        default,
+        deserialize_with = "deserialize_decimal"
+    )]
+    pub change_rate: Decimal,
+    /// 매수호가
+    #[serde(
+        rename = "optn_bidp",
+        default,
+        deserialize_with = "deserialize_decimal"
+    )]
+    pub bid_price: Decimal,
+    /// 매도호가
+    #[serde(
+        rename = "optn_askp",
+        default,
+        deserialize_with = "deserialize_decimal"
+    )]
+    pub ask_price: Decimal,
+    /// 누적 거래량
+    #[serde(rename = "acml_vol", default, deserialize_with = "deserialize_decimal")]
+    pub volume: Decimal,
+    /// 미결제약정 수량
+    #[serde(
+        rename = "hts_otst_stpl_qty",
+        default,
+        deserialize_with = "deserialize_decimal"
+    )]
+    pub open_interest: Decimal,
+    /// 델타
+    #[serde(
+        rename = "delta_val",
+        default,
+        deserialize_with = "deserialize_decimal"
+    )]
+    pub delta: Decimal,
+    /// 감마
+    #[serde(rename = "gama", default, deserialize_with = "deserialize_decimal")]
+    pub gamma: Decimal,
+    /// 세타
+    #[serde(rename = "theta", default, deserialize_with = "deserialize_decimal")]
+    pub theta: Decimal,
+    /// 베가
+    #[serde(rename = "vega", default, deserialize_with = "deserialize_decimal")]
+    pub vega: Decimal,
+    /// 로
+    #[serde(rename = "rho", default, deserialize_with = "deserialize_decimal")]
+    pub rho: Decimal,
+    /// 내재변동성 (%)
+    #[serde(
+        rename = "hts_ints_vltl",
+        default,
+        deserialize_with = "deserialize_decimal"
+    )]
+    pub implied_volatility: Decimal,
+    /// 이론가
+    #[serde(rename = "hts_thpr", default, deserialize_with = "deserialize_decimal")]
+    pub theoretical_price: Decimal,
+}
+
+impl KrOptionQuote {
+    /// 도메인 옵션 시세로 변환 (내재변동성이 0이면 미산출로 간주).
+    pub fn to_option_quote(&self, option_type: OptionType, expiry: NaiveDate) -> OptionQuote {
+        OptionQuote {
+            contract: OptionContract {
+                code: self.code.clone(),
+                underlying: KOSPI200_UNDERLYING.to_string(),
+                option_type,
+                strike: self.strike,
+                expiry,
+                multiplier: KOSPI200_OPTION_MULTIPLIER,
+            },
+            price: self.current_price,
+            bid: self.bid_price,
+            ask: self.ask_price,
+            volume: self.volume,
+            open_interest: self.open_interest,
+            greeks: OptionGreeks {
+                delta: self.delta.to_f64().unwrap_or_default(),
+                gamma: self.gamma.to_f64().unwrap_or_default(),
+                theta: self.theta.to_f64().unwrap_or_default(),
+                vega: self.vega.to_f64().unwrap_or_default(),
+                rho: self.rho.to_f64().unwrap_or_default(),
+                implied_volatility: (self.implied_volatility > Decimal::ZERO)
+                    .then(|| self.implied_volatility.to_f64())
+                    .flatten(),
+            },
+        }
+    }
+}
+
 /// 국내 주문 응답.
 #[derive(Debug, Clone, Deserialize)]
 pub struct KrOrderResponse {
@@ -1371,6 +1780,145 @@ pub struct KrBalance {
     pub summary: Option<KrAccountSummary>,
 }
 
+/// 국내 선물 보유 포지션.
+#[derive(Debug, Clone, Deserialize)]
+pub struct KrFuturesPosition {
+    /// 선물 단축코드
+    #[serde(rename = "shtn_pdno")]
+    pub code: String,
+    /// 종목명
+    #[serde(rename = "prdt_name", default)]
+    pub name: String,
+    /// 매도매수구분 ("01" = 매도, "02" = 매수)
+    #[serde(rename = "sll_buy_dvsn_cd", default)]
+    pub side_code: String,
+    /// 잔고수량 (계약)
+    #[serde(rename = "cblc_qty", deserialize_with = "deserialize_decimal")]
+    pub quantity: Decimal,
+    /// 평균 체결단가 (포인트)
+    #[serde(
+        rename = "ccld_avg_unpr1",
+        default,
+        deserialize_with = "deserialize_decimal"
+    )]
+    pub avg_price: Decimal,
+    /// 현재가 (포인트)
+    #[serde(rename = "idx_clpr", default, deserialize_with = "deserialize_decimal")]
+    pub current_price: Decimal,
+    /// 평가금액
+    #[serde(rename = "evlu_amt", default, deserialize_with = "deserialize_decimal")]
+    pub eval_amount: Decimal,
+    /// 평가손익금액
+    #[serde(
+        rename = "evlu_pfls_amt",
+        default,
+        deserialize_with = "deserialize_decimal"
+    )]
+    pub profit_loss: Decimal,
+    /// 청산가능수량
+    #[serde(
+        rename = "lqd_psbl_qty",
+        default,
+        deserialize_with = "deserialize_decimal"
+    )]
+    pub liquidatable_qty: Decimal,
+}
+
+impl KrFuturesPosition {
+    /// 포지션 방향 (매수 = 롱, 매도 = 숏).
+    pub fn side(&self) -> Option<Side> {
+        match self.side_code.as_str() {
+            "02" => Some(Side::Buy),
+            "01" => Some(Side::Sell),
+            _ => None,
+        }
+    }
+}
+
+/// 국내 선물옵션 계좌 증거금 현황.
+#[derive(Debug, Clone, Deserialize)]
+pub struct KrFuturesMargin {
+    /// 예수금 현금
+    #[serde(
+        rename = "dnca_cash",
+        default,
+        deserialize_with = "deserialize_decimal"
+    )]
+    pub cash: Decimal,
+    /// 총 예탁금액
+    #[serde(
+        rename = "tot_dncl_amt",
+        default,
+        deserialize_with = "deserialize_decimal"
+    )]
+    pub total_deposit: Decimal,
+    /// 위탁증거금 총액
+    #[serde(
+        rename = "brkg_mgna_tota",
+        default,
+        deserialize_with = "deserialize_decimal"
+    )]
+    pub initial_margin: Decimal,
+    /// 유지증거금 총액
+    #[serde(
+        rename = "mntn_mgna_tota",
+        default,
+        deserialize_with = "deserialize_decimal"
+    )]
+    pub maintenance_margin: Decimal,
+    /// 추가증거금 총액 (마진콜 금액)
+    #[serde(
+        rename = "add_mgna_tota",
+        default,
+        deserialize_with = "deserialize_decimal"
+    )]
+    pub additional_margin: Decimal,
+    /// 주문가능 총액
+    #[serde(
+        rename = "ord_psbl_tota",
+        default,
+        deserialize_with = "deserialize_decimal"
+    )]
+    pub orderable_amount: Decimal,
+    /// 인출가능 총액
+    #[serde(
+        rename = "wdrw_psbl_tot_amt",
+        default,
+        deserialize_with = "deserialize_decimal"
+    )]
+    pub withdrawable_amount: Decimal,
+    /// 추정 예탁자산
+    #[serde(
+        rename = "prsm_dpast",
+        default,
+        deserialize_with = "deserialize_decimal"
+    )]
+    pub estimated_assets: Decimal,
+    /// 선물 평가손익
+    #[serde(
+        rename = "futr_evlu_pfls_amt",
+        default,
+        deserialize_with = "deserialize_decimal"
+    )]
+    pub futures_profit_loss: Decimal,
+}
+
+impl KrFuturesMargin {
+    /// 추가증거금(마진콜) 발생 여부.
+    pub fn is_margin_call(&self) -> bool {
+        self.additional_margin > Decimal::ZERO
+    }
+}
+
+/// 국내 선물옵션 계좌 잔고.
+#[derive(Debug, Clone)]
+pub struct KrFuturesBalance {
+    /// 보유 선물 포지션
+    pub positions: Vec<KrFuturesPosition>,
+    /// 증거금 현황
+    pub margin: Option<KrFuturesMargin>,
+}
+
 /// 국내 주식 일/주/월/년봉 데이터.
 #[derive(Debug, Clone, Deserialize)]
 pub struct KrOhlcv {
@@ -1418,6 +1966,9 @@ pub struct KrOhlcv {
 /// 국내 주식 분봉 데이터.
 #[derive(Debug, Clone, Deserialize)]
 pub struct KrMinuteOhlcv {
+    /// 영업 일자 (YYYYMMDD, 응답에 없으면 빈 문자열)
+    #[serde(rename = "stck_bsop_date", default)]
+    pub date: String,
     /// 체결 시간 (HHMMSS)
     #[serde(rename = "stck_cntg_hour")]
     pub time: String,
@@ -1631,6 +2182,19 @@ struct KisKrOrderBookResponse {
     output1: KrOrderBook,
 }
 
+#[derive(Debug, Deserialize)]
+struct KisKrOptionBoardResponse {
+    rt_cd: String,
+    msg_cd: String,
+    msg1: String,
+    /// 콜옵션
+    #[serde(default)]
+    output1: Vec<KrOptionQuote>,
+    /// 풋옵션
+    #[serde(default)]
+    output2: Vec<KrOptionQuote>,
+}
+
 #[derive(Debug, Deserialize)]
 struct KisKrOrderApiResponse {
     rt_cd: String,
@@ -1648,6 +2212,16 @@ struct KisKrBalanceResponse {
     output2: Option<Vec<KrAccountSummary>>,
 }
 
+#[derive(Debug, Deserialize)]
+struct KisKrFuturesBalanceResponse {
+    rt_cd: String,
+    msg_cd: String,
+    msg1: String,
+    #[serde(default)]
+    output1: Vec<KrFuturesPosition>,
+    output2: Option<KrFuturesMargin>,
+}
+
 #[derive(Debug, Deserialize)]
 struct KisKrBuyPowerResponse {
     rt_cd: String,
@@ -1704,6 +2278,82 @@ where
         .map_err(|_| serde::de::Error::custom(format!("Invalid decimal: {}", s)))
 }
 
+/// 선물 매도매수구분 코드 ("01" = 매도, "02" = 매수).
+fn futures_side_code(side: Side) -> &'static str {
+    match side {
+        Side::Buy => "02",
+        Side::Sell => "01",
+    }
+}
+
+/// 주문 응답 파싱 (`rt_cd`가 "0"이 아니면 API 오류).
+fn parse_order_response(body: &str) -> Result<KrOrderResponse, ExchangeError> {
+    let resp: KisKrOrderApiResponse = serde_json::from_str(body)
+        .map_err(|e| ExchangeError::ParseError(format!("Failed to parse order response: {}", e)))?;
+
+    if resp.rt_cd != "0" {
+        return Err(ExchangeError::ApiError {
+            code: resp.msg_cd.parse().unwrap_or(-1),
+            message: resp.msg1,
+        });
+    }
+
+    Ok(resp.output)
+}
+
+/// 선물옵션 잔고 응답을 포지션/증거금으로 변환 (잔고 0 포지션 제외).
+fn parse_futures_balance_response(body: &str) -> Result<KrFuturesBalance, ExchangeError> {
+    let resp: KisKrFuturesBalanceResponse = serde_json::from_str(body).map_err(|e| {
+        ExchangeError::ParseError(format!("Failed to parse futures balance response: {}", e))
+    })?;
+
+    if resp.rt_cd != "0" {
+        return Err(ExchangeError::ApiError {
+            code: resp.msg_cd.parse().unwrap_or(-1),
+            message: resp.msg1,
+        });
+    }
+
+    Ok(KrFuturesBalance {
+        positions: resp
+            .output1
+            .into_iter()
+            .filter(|p| !p.quantity.is_zero())
+            .collect(),
+        margin: resp.output2,
+    })
+}
+
+/// 옵션 전광판 응답을 옵션 체인으로 변환.
+fn parse_option_chain_response(
+    body: &str,
+    expiry: NaiveDate,
+) -> Result<OptionChain, ExchangeError> {
+    let resp: KisKrOptionBoardResponse = serde_json::from_str(body).map_err(|e| {
+        ExchangeError::ParseError(format!("Failed to parse option board response: {}", e))
+    })?;
+
+    if resp.rt_cd != "0" {
+        return Err(ExchangeError::ApiError {
+            code: resp.msg_cd.parse().unwrap_or(-1),
+            message: resp.msg1,
+        });
+    }
+
+    let quotes = resp
+        .output1
+        .iter()
+        .map(|q| q.to_option_quote(OptionType::Call, expiry))
+        .chain(
+            resp.output2
+                .iter()
+                .map(|q| q.to_option_quote(OptionType::Put, expiry)),
+        )
+        .collect();
+
+    Ok(OptionChain::new(KOSPI200_UNDERLYING, expiry, quotes))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1731,4 +2381,118 @@ mod tests {
         let result: Test = serde_json::from_str(json).unwrap();
         assert_eq!(result.value, Decimal::ZERO);
     }
+
+    #[test]
+    fn test_parse_option_chain_response() {
+        let json = r#"{
+            "rt_cd": "0",
+            "msg_cd": "MCA00000",
+            "msg1": "정상처리 되었습니다.",
+            "output1": [
+                {"optn_shrn_iscd": "201W3362", "acpr": "362.50", "optn_prpr": "2.15",
+                 "optn_bidp": "2.14", "optn_askp": "2.16", "acml_vol": "48211",
+                 "hts_otst_stpl_qty": "35120", "delta_val": "0.3512", "gama": "0.0421",
+                 "theta": "-0.1180", "vega": "0.2875", "rho": "0.0310", "hts_ints_vltl": "17.85"},
+                {"optn_shrn_iscd": "201W3360", "acpr": "360.00", "optn_prpr": "3.10",
+                 "optn_bidp": "", "optn_askp": "", "acml_vol": "0",
+                 "hts_otst_stpl_qty": "0", "hts_ints_vltl": "0"}
+            ],
+            "output2": [
+                {"optn_shrn_iscd": "301W3360", "acpr": "360.00", "optn_prpr": "4.60",
+                 "optn_bidp": "4.55", "optn_askp": "4.65", "acml_vol": "30120",
+                 "hts_otst_stpl_qty": "28870", "delta_val": "-0.5120", "hts_ints_vltl": "19.02"}
+            ]
+        }"#;
+        let expiry = NaiveDate::from_ymd_opt(2026, 3, 12).unwrap();
+
+        let chain = parse_option_chain_response(json, expiry).unwrap();
+        assert_eq!(chain.underlying, "KOSPI200");
+        assert_eq!(chain.calls().count(), 2);
+        assert_eq!(chain.puts().count(), 1);
+        assert_eq!(
+            chain.strikes(),
+            vec![Decimal::new(360, 0), Decimal::new(36250, 2)]
+        );
+
+        let call = chain
+            .quote(OptionType::Call, Decimal::new(36250, 2))
+            .unwrap();
+        assert_eq!(call.contract.code, "201W3362");
+        assert_eq!(call.contract.multiplier, KOSPI200_OPTION_MULTIPLIER);
+        assert_eq!(call.open_interest, Decimal::new(35120, 0));
+        assert!((call.greeks.delta - 0.3512).abs() < 1e-9);
+        assert_eq!(call.greeks.implied_volatility, Some(17.85));
+
+        // 호가/내재변동성이 비어 있는 행사가
+        let call = chain.quote(OptionType::Call, Decimal::new(360, 0)).unwrap();
+        assert_eq!(call.bid, Decimal::ZERO);
+        assert_eq!(call.greeks.implied_volatility, None);
+        assert_eq!(call.mid_price(), Decimal::new(310, 2));
+
+        let put = chain.quote(OptionType::Put, Decimal::new(360, 0)).unwrap();
+        assert!((put.greeks.delta + 0.512).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_parse_option_chain_error() {
+        let json =
+            r#"{"rt_cd": "1", "msg_cd": "40580000", "msg1": "모의투자 미지원 서비스입니다."}"#;
+        let expiry = NaiveDate::from_ymd_opt(2026, 3, 12).unwrap();
+        assert!(matches!(
+            parse_option_chain_response(json, expiry),
+            Err(ExchangeError::ApiError { code: 40580000, .. })
+        ));
+    }
+
+    #[test]
+    fn test_parse_futures_balance_response() {
+        let json = r#"{
+            "rt_cd": "0",
+            "msg_cd": "KIOK0510",
+            "msg1": "조회가 완료되었습니다",
+            "output1": [
+                {"shtn_pdno": "101W9000", "prdt_name": "F 202609", "sll_buy_dvsn_cd": "02",
+                 "cblc_qty": "2", "ccld_avg_unpr1": "360.25", "idx_clpr": "362.50",
+                 "evlu_amt": "181250000", "evlu_pfls_amt": "1125000", "lqd_psbl_qty": "2"},
+                {"shtn_pdno": "105W9000", "sll_buy_dvsn_cd": "01", "cblc_qty": "0"}
+            ],
+            "output2": {
+                "dnca_cash": "50000000", "tot_dncl_amt": "50000000",
+                "brkg_mgna_tota": "16222500", "mntn_mgna_tota": "10815000",
+                "add_mgna_tota": "0", "ord_psbl_tota": "34902500",
+                "wdrw_psbl_tot_amt": "33777500", "prsm_dpast": "51125000",
+                "futr_evlu_pfls_amt": "1125000"
+            }
+        }"#;
+
+        let balance = parse_futures_balance_response(json).unwrap();
+        assert_eq!(balance.positions.len(), 1);
+        let position = &balance.positions[0];
+        assert_eq!(position.code, "101W9000");
+        assert_eq!(position.side(), Some(Side::Buy));
+        assert_eq!(position.quantity, Decimal::new(2, 0));
+        assert_eq!(position.avg_price, Decimal::new(36025, 2));
+
+        let margin = balance.margin.unwrap();
+        assert_eq!(margin.initial_margin, Decimal::new(16_222_500, 0));
+        assert_eq!(margin.orderable_amount, Decimal::new(34_902_500, 0));
+        assert!(!margin.is_margin_call());
+    }
+
+    #[test]
+    fn test_parse_futures_order_response() {
+        let json = r#"{"rt_cd": "0", "msg_cd": "APBK0013", "msg1": "주문 전송 완료 되었습니다.",
+            "output": {"ACNT_NAME": "", "ITEM_NAME": "F 202609", "ORD_TMD": "091512", "ODNO": "0000012345"}}"#;
+        let resp = parse_order_response(json).unwrap();
+        assert_eq!(resp.odno, "0000012345");
+        assert_eq!(resp.order_time, "091512");
+        assert_eq!(futures_side_code(Side::Sell), "01");
+
+        let json = r#"{"rt_cd": "1", "msg_cd": "40310000", "msg1": "주문가능금액을 초과 했습니다",
+            "output": {"ORD_TMD": "", "ODNO": ""}}"#;
+        assert!(matches!(
+            parse_order_response(json),
+            Err(ExchangeError::ApiError { code: 40310000, .. })
+        ));
+    }
 }
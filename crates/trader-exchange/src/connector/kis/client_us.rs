@@ -10,7 +10,9 @@
 //! - 매수/매도 주문
 //! - 주문 정정/취소
 //! - 잔고 조회
+//! - 원화→USD 환전 주문
 //! - 주/야간 구분 확인
+//! - 정규장 외 세션(주간거래, 프리마켓, 애프터마켓) 시세 조회 및 지정가 주문
 //!
 //! # 거래소 코드 (EXCD)
 //!
@@ -21,6 +23,8 @@
 use super::auth::KisOAuth;
 use super::config::KisEnvironment;
 use super::exchange_code;
+use super::order_type;
+use super::session::{daytime_exchange_code, UsTradingSession};
 use super::tr_id;
 use crate::ExchangeError;
 use reqwest::Client;
@@ -28,7 +32,7 @@ use rust_decimal::Decimal;
 use serde::Deserialize;
 use std::sync::Arc;
 use tracing::{debug, error, info, warn};
-use trader_core::{RoundMethod, TickSizeProvider};
+use trader_core::{RoundMethod, Side, TickSizeProvider};
 
 /// 미국 시장 세션 유형.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -142,6 +146,18 @@ impl KisUsClient {
         }
     }
 
+    /// 거래 세션에 맞는 시세 조회 거래소 코드.
+    ///
+    /// 주간거래 세션에는 주간거래 거래소 코드(BAQ/BAY/BAA)를 사용합니다.
+    pub fn quote_exchange_code(symbol: &str, session: UsTradingSession) -> &'static str {
+        let code = Self::get_exchange_code(symbol);
+        if session.is_kis_daytime() {
+            daytime_exchange_code(code)
+        } else {
+            code
+        }
+    }
+
     // ========================================
     // Market Data APIs (시세 조회)
     // ========================================
@@ -204,6 +220,18 @@ impl KisUsClient {
         Ok(resp.output)
     }
 
+    /// 거래 세션에 맞는 거래소 코드로 현재가 조회.
+    ///
+    /// 주간거래 세션에는 정규 거래소 시세가 갱신되지 않으므로 주간거래 시세를 조회합니다.
+    pub async fn get_session_price(
+        &self,
+        symbol: &str,
+        session: UsTradingSession,
+    ) -> Result<StockPrice, ExchangeError> {
+        self.get_price(symbol, Some(Self::quote_exchange_code(symbol, session)))
+            .await
+    }
+
     /// 해외주식 기간별 시세 조회.
     ///
     /// # 인자
@@ -295,8 +323,16 @@ impl KisUsClient {
         order_type: &str,
         exchange_code: Option<&str>,
     ) -> Result<UsOrderResponse, ExchangeError> {
-        self.place_order(symbol, quantity, price, order_type, exchange_code, true)
-            .await
+        self.place_order(
+            symbol,
+            quantity,
+            price,
+            order_type,
+            exchange_code,
+            true,
+            false,
+        )
+        .await
     }
 
     /// 해외주식 매도 주문.
@@ -308,11 +344,75 @@ impl KisUsClient {
         order_type: &str,
         exchange_code: Option<&str>,
     ) -> Result<UsOrderResponse, ExchangeError> {
-        self.place_order(symbol, quantity, price, order_type, exchange_code, false)
-            .await
+        self.place_order(
+            symbol,
+            quantity,
+            price,
+            order_type,
+            exchange_code,
+            false,
+            false,
+        )
+        .await
+    }
+
+    /// 거래 세션 지정 지정가 주문.
+    ///
+    /// 정규장 외 세션은 호가가 얇아 시장가 주문을 받지 않으므로 지정가만 지원합니다.
+    /// - 프리마켓/애프터마켓/정규장: 일반 주문 TR
+    /// - 주간거래: 주간거래 전용 TR (실전투자 전용)
+    ///
+    /// # 인자
+    /// * `symbol` - 종목 심볼
+    /// * `side` - 주문 방향
+    /// * `quantity` - 주문 수량
+    /// * `price` - 지정가 (0보다 커야 함)
+    /// * `session` - 주문할 거래 세션
+    /// * `exchange_code` - 거래소 코드. None이면 자동 감지.
+    pub async fn place_session_limit_order(
+        &self,
+        symbol: &str,
+        side: Side,
+        quantity: u32,
+        price: Decimal,
+        session: UsTradingSession,
+        exchange_code: Option<&str>,
+    ) -> Result<UsOrderResponse, ExchangeError> {
+        if !session.is_open() {
+            return Err(ExchangeError::OrderRejected(format!(
+                "US market is closed ({})",
+                session
+            )));
+        }
+        if price <= Decimal::ZERO {
+            return Err(ExchangeError::OrderRejected(format!(
+                "{} session orders require a positive limit price",
+                session
+            )));
+        }
+        let daytime = session.is_kis_daytime();
+        if daytime && self.oauth.config().environment == KisEnvironment::Paper {
+            return Err(ExchangeError::NotSupported(
+                "Daytime session orders are not available in paper trading".to_string(),
+            ));
+        }
+
+        self.place_order(
+            symbol,
+            quantity,
+            price,
+            order_type::LIMIT,
+            exchange_code,
+            side == Side::Buy,
+            daytime,
+        )
+        .await
     }
 
     /// 내부 주문 실행.
+    ///
+    /// `daytime`이면 주간거래 전용 TR로 주문합니다.
+    #[allow(clippy::too_many_arguments)]
     async fn place_order(
         &self,
         symbol: &str,
@@ -321,6 +421,7 @@ impl KisUsClient {
         order_type: &str,
         exchange_code: Option<&str>,
         is_buy: bool,
+        daytime: bool,
     ) -> Result<UsOrderResponse, ExchangeError> {
         // 호가 단위 라운딩 (시장가 주문이 아닌 경우)
         let rounded_price = if !price.is_zero() {
@@ -350,10 +451,11 @@ impl KisUsClient {
             price
         };
 
-        let tr_id = if is_buy {
-            self.get_tr_id(tr_id::US_BUY_REAL, tr_id::US_BUY_PAPER)
-        } else {
-            self.get_tr_id(tr_id::US_SELL_REAL, tr_id::US_SELL_PAPER)
+        let tr_id = match (daytime, is_buy) {
+            (true, true) => tr_id::US_DAYTIME_BUY_REAL,
+            (true, false) => tr_id::US_DAYTIME_SELL_REAL,
+            (false, true) => self.get_tr_id(tr_id::US_BUY_REAL, tr_id::US_BUY_PAPER),
+            (false, false) => self.get_tr_id(tr_id::US_SELL_REAL, tr_id::US_SELL_PAPER),
         };
 
         let url = format!(
@@ -382,13 +484,14 @@ impl KisUsClient {
         let headers = self.oauth.build_headers(tr_id, Some(&hashkey)).await?;
 
         info!(
-            "Placing US {} order: {} x {} @ {} ({}, type: {})",
+            "Placing US {} order: {} x {} @ {} ({}, type: {}, daytime: {})",
             if is_buy { "BUY" } else { "SELL" },
             symbol,
             quantity,
             price,
             excd,
-            order_type
+            order_type,
+            daytime
         );
 
         let response = self
@@ -616,6 +719,175 @@ impl KisUsClient {
         })
     }
 
+    /// 해외주식 매수가능금액 조회.
+    ///
+    /// 외화 주문가능금액과 원화 환전 후 주문가능금액, 적용 환율을 반환합니다.
+    ///
+    /// # 인자
+    /// * `symbol` - 종목 심볼 (예: "AAPL")
+    /// * `price` - 주문 단가 (USD)
+    ///
+    /// # 참고
+    /// - TR ID: TTTS3007R (실전), VTTS3007R (모의)
+    /// - 엔드포인트: /uapi/overseas-stock/v1/trading/inquire-psamount
+    pub async fn get_purchasable_amount(
+        &self,
+        symbol: &str,
+        price: Decimal,
+    ) -> Result<UsPurchasableAmount, ExchangeError> {
+        let tr_id = self.get_tr_id(
+            tr_id::US_PURCHASABLE_AMOUNT_REAL,
+            tr_id::US_PURCHASABLE_AMOUNT_PAPER,
+        );
+        let url = format!(
+            "{}/uapi/overseas-stock/v1/trading/inquire-psamount",
+            self.oauth.config().rest_base_url()
+        );
+
+        let headers = self.oauth.build_headers(tr_id, None).await?;
+        // 계좌 조회 API는 4자리 거래소 코드 사용
+        let excd = match Self::get_exchange_code(symbol) {
+            exchange_code::NYSE => "NYSE",
+            exchange_code::AMEX => "AMEX",
+            _ => "NASD",
+        };
+        let price = price.to_string();
+
+        let response = self
+            .client
+            .get(&url)
+            .headers(headers)
+            .query(&[
+                ("CANO", self.oauth.config().cano()),
+                ("ACNT_PRDT_CD", self.oauth.config().acnt_prdt_cd()),
+                ("OVRS_EXCG_CD", excd),
+                ("OVRS_ORD_UNPR", price.as_str()),
+                ("ITEM_CD", symbol),
+            ])
+            .send()
+            .await
+            .map_err(|e| ExchangeError::NetworkError(e.to_string()))?;
+
+        let status = response.status();
+        let body = response
+            .text()
+            .await
+            .map_err(|e| ExchangeError::NetworkError(e.to_string()))?;
+
+        if !status.is_success() {
+            error!(
+                "US purchasable amount inquiry failed: {} - {}",
+                status, body
+            );
+            return Err(ExchangeError::ApiError {
+                code: status.as_u16() as i32,
+                message: body,
+            });
+        }
+
+        debug!("US purchasable amount response: {}", body);
+
+        let resp: KisUsPurchasableResponse = serde_json::from_str(&body).map_err(|e| {
+            ExchangeError::ParseError(format!(
+                "Failed to parse purchasable amount response: {}",
+                e
+            ))
+        })?;
+
+        if resp.rt_cd != "0" {
+            return Err(ExchangeError::ApiError {
+                code: resp.msg_cd.parse().unwrap_or(-1),
+                message: resp.msg1,
+            });
+        }
+
+        Ok(resp.output)
+    }
+
+    /// 원화→USD 환전 주문.
+    ///
+    /// 원화 예수금으로 `usd_amount`만큼 USD를 매수합니다 (센트 단위로 올림).
+    ///
+    /// # 참고
+    /// - TR ID: TTTS6036U (실전), VTTS6036U (모의)
+    /// - 엔드포인트: /uapi/overseas-stock/v1/trading/foreign-exchange
+    pub async fn place_fx_conversion_order(
+        &self,
+        usd_amount: Decimal,
+    ) -> Result<UsFxConversionOrder, ExchangeError> {
+        if usd_amount <= Decimal::ZERO {
+            return Err(ExchangeError::InvalidQuantity(format!(
+                "FX conversion amount must be positive: {}",
+                usd_amount
+            )));
+        }
+        let usd_amount =
+            usd_amount.round_dp_with_strategy(2, rust_decimal::RoundingStrategy::AwayFromZero);
+
+        let tr_id = self.get_tr_id(tr_id::US_FX_CONVERSION_REAL, tr_id::US_FX_CONVERSION_PAPER);
+        let url = format!(
+            "{}/uapi/overseas-stock/v1/trading/foreign-exchange",
+            self.oauth.config().rest_base_url()
+        );
+
+        let body = serde_json::json!({
+            "CANO": self.oauth.config().cano(),
+            "ACNT_PRDT_CD": self.oauth.config().acnt_prdt_cd(),
+            "SLL_CRCY_CD": "KRW",
+            "BUY_CRCY_CD": "USD",
+            "BUY_AMT": usd_amount.to_string(),
+        });
+
+        let hashkey = self.oauth.generate_hashkey(&body).await?;
+        let headers = self.oauth.build_headers(tr_id, Some(&hashkey)).await?;
+
+        info!("Placing KRW→USD conversion order: {} USD", usd_amount);
+
+        let response = self
+            .client
+            .post(&url)
+            .headers(headers)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|e| ExchangeError::NetworkError(e.to_string()))?;
+
+        let status = response.status();
+        let response_body = response
+            .text()
+            .await
+            .map_err(|e| ExchangeError::NetworkError(e.to_string()))?;
+
+        if !status.is_success() {
+            error!("FX conversion order failed: {} - {}", status, response_body);
+            return Err(ExchangeError::ApiError {
+                code: status.as_u16() as i32,
+                message: response_body,
+            });
+        }
+
+        debug!("FX conversion order response: {}", response_body);
+
+        let resp: KisUsFxConversionResponse =
+            serde_json::from_str(&response_body).map_err(|e| {
+                ExchangeError::ParseError(format!("Failed to parse FX conversion response: {}", e))
+            })?;
+
+        if resp.rt_cd != "0" {
+            return Err(ExchangeError::ApiError {
+                code: resp.msg_cd.parse().unwrap_or(-1),
+                message: resp.msg1,
+            });
+        }
+
+        info!(
+            "FX conversion order placed: order_no={}, usd={}",
+            resp.output.odno, usd_amount
+        );
+
+        Ok(resp.output)
+    }
+
     /// 해외 주식 미체결 주문 조회.
     ///
     /// 당일 미체결 주문만 조회합니다.
@@ -912,6 +1184,40 @@ pub struct UsBalance {
     pub summary: Option<UsAccountSummary>,
 }
 
+/// 해외주식 매수가능금액.
+#[derive(Debug, Clone, Deserialize)]
+pub struct UsPurchasableAmount {
+    /// 거래통화코드
+    #[serde(rename = "tr_crcy_cd", default)]
+    pub currency: String,
+    /// 주문가능외화금액
+    #[serde(rename = "ord_psbl_frcr_amt", deserialize_with = "deserialize_decimal")]
+    pub foreign_orderable: Decimal,
+    /// 환전이후주문가능금액 (원화 환전분 포함)
+    #[serde(
+        rename = "echm_af_ord_psbl_amt",
+        deserialize_with = "deserialize_decimal"
+    )]
+    pub orderable_after_exchange: Decimal,
+    /// 최대주문가능수량
+    #[serde(rename = "max_ord_psbl_qty", deserialize_with = "deserialize_decimal")]
+    pub max_quantity: Decimal,
+    /// 환율
+    #[serde(rename = "exrt", deserialize_with = "deserialize_decimal")]
+    pub exchange_rate: Decimal,
+}
+
+/// 원화→USD 환전 주문 응답.
+#[derive(Debug, Clone, Deserialize)]
+pub struct UsFxConversionOrder {
+    /// 환전 주문번호
+    #[serde(rename = "ODNO")]
+    pub odno: String,
+    /// 주문시간
+    #[serde(rename = "ORD_TMD", default)]
+    pub order_time: String,
+}
+
 /// 주/야간 세션 정보.
 #[derive(Debug, Clone, Deserialize)]
 pub struct UsDayNightInfo {
@@ -1030,6 +1336,22 @@ struct KisUsBalanceResponse {
     output2: Option<UsAccountSummary>,
 }
 
+#[derive(Debug, Deserialize)]
+struct KisUsPurchasableResponse {
+    rt_cd: String,
+    msg_cd: String,
+    msg1: String,
+    output: UsPurchasableAmount,
+}
+
+#[derive(Debug, Deserialize)]
+struct KisUsFxConversionResponse {
+    rt_cd: String,
+    msg_cd: String,
+    msg1: String,
+    output: UsFxConversionOrder,
+}
+
 #[derive(Debug, Deserialize)]
 struct KisUsDayNightResponse {
     rt_cd: String,
@@ -1119,6 +1441,22 @@ mod tests {
         assert_eq!(result.value, Decimal::ZERO);
     }
 
+    #[test]
+    fn test_purchasable_amount_parsing() {
+        let json = r#"{
+            "tr_crcy_cd": "USD",
+            "ord_psbl_frcr_amt": "120.50",
+            "echm_af_ord_psbl_amt": "2450.75",
+            "max_ord_psbl_qty": "13",
+            "exrt": "1385.2000000"
+        }"#;
+        let amount: UsPurchasableAmount = serde_json::from_str(json).unwrap();
+        assert_eq!(amount.currency, "USD");
+        assert_eq!(amount.foreign_orderable, Decimal::new(12050, 2));
+        assert_eq!(amount.orderable_after_exchange, Decimal::new(245075, 2));
+        assert_eq!(amount.exchange_rate, Decimal::new(13852, 1));
+    }
+
     #[test]
     fn test_market_session_enum() {
         assert_eq!(UsMarketSession::Day, UsMarketSession::Day);
@@ -0,0 +1,340 @@
+//! KIS 실시간 체결통보.
+//!
+//! 국내(`H0STCNI0`/`H0STCNI9`)와 해외(`H0GSCNI0`/`H0GSCNI9`) 체결통보 채널은 HTS ID를
+//! tr_key로 구독하며, 본문이 AES-256-CBC로 암호화되어 전송됩니다.
+//! 복호화 키와 IV는 구독 성공 응답(`body.output.key`, `body.output.iv`)으로 한 번 내려오므로
+//! 연결마다 새로 받아 보관해야 합니다.
+//!
+//! # 메시지 형식
+//!
+//! ```text
+//! 1|H0STCNI0|001|<base64 암호문>
+//! ```
+//!
+//! 복호화한 본문은 `^` 구분 필드이며, 주문번호로 내부 주문과 매칭합니다.
+
+use aes::cipher::{generic_array::GenericArray, BlockDecrypt, KeyInit};
+use aes::Aes256;
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use chrono::Utc;
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use std::str::FromStr;
+use tracing::warn;
+use trader_core::Side;
+
+use crate::traits::FillReport;
+use crate::ExchangeError;
+
+/// AES 블록 크기 (바이트).
+const BLOCK_SIZE: usize = 16;
+
+/// 체결통보 본문 복호화기 (AES-256-CBC, PKCS7 패딩).
+#[derive(Clone)]
+pub(crate) struct ExecutionCipher {
+    key: [u8; 32],
+    iv: [u8; BLOCK_SIZE],
+}
+
+impl std::fmt::Debug for ExecutionCipher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ExecutionCipher")
+            .field("key", &"[REDACTED]")
+            .finish()
+    }
+}
+
+/// 구독 성공 응답.
+#[derive(Debug, Deserialize)]
+struct SubscribeResponse {
+    header: SubscribeResponseHeader,
+    body: Option<SubscribeResponseBody>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SubscribeResponseHeader {
+    tr_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct SubscribeResponseBody {
+    output: Option<SubscribeResponseOutput>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SubscribeResponseOutput {
+    iv: Option<String>,
+    key: Option<String>,
+}
+
+impl ExecutionCipher {
+    /// 키/IV 문자열로 생성 (길이가 맞지 않으면 `None`).
+    pub(crate) fn new(key: &str, iv: &str) -> Option<Self> {
+        Some(Self {
+            key: key.as_bytes().try_into().ok()?,
+            iv: iv.as_bytes().try_into().ok()?,
+        })
+    }
+
+    /// 구독 응답(JSON)에서 키/IV 추출.
+    ///
+    /// `tr_ids`에 해당하는 채널의 응답이고 키/IV가 포함된 경우에만 `Some`을 반환합니다.
+    pub(crate) fn from_subscribe_response(text: &str, tr_ids: &[&str]) -> Option<Self> {
+        let response: SubscribeResponse = serde_json::from_str(text).ok()?;
+        if !tr_ids.contains(&response.header.tr_id.as_str()) {
+            return None;
+        }
+        let output = response.body?.output?;
+        Self::new(&output.key?, &output.iv?)
+    }
+
+    /// base64 암호문 복호화.
+    pub(crate) fn decrypt(&self, data: &str) -> Result<String, ExchangeError> {
+        let mut buf = STANDARD.decode(data.trim()).map_err(|e| {
+            ExchangeError::ParseError(format!("체결통보 base64 디코딩 실패: {}", e))
+        })?;
+        if buf.is_empty() || buf.len() % BLOCK_SIZE != 0 {
+            return Err(ExchangeError::ParseError(format!(
+                "체결통보 암호문 길이 오류: {}",
+                buf.len()
+            )));
+        }
+
+        let cipher = Aes256::new(GenericArray::from_slice(&self.key));
+        let mut prev = self.iv;
+        for chunk in buf.chunks_exact_mut(BLOCK_SIZE) {
+            let mut current = [0u8; BLOCK_SIZE];
+            current.copy_from_slice(chunk);
+            cipher.decrypt_block(GenericArray::from_mut_slice(chunk));
+            for (byte, p) in chunk.iter_mut().zip(prev.iter()) {
+                *byte ^= p;
+            }
+            prev = current;
+        }
+
+        // PKCS7 패딩 제거
+        let pad = usize::from(*buf.last().unwrap_or(&0));
+        if pad == 0
+            || pad > BLOCK_SIZE
+            || buf[buf.len() - pad..]
+                .iter()
+                .any(|&b| usize::from(b) != pad)
+        {
+            return Err(ExchangeError::ParseError(
+                "체결통보 패딩 오류 (키/IV 불일치)".to_string(),
+            ));
+        }
+        buf.truncate(buf.len() - pad);
+
+        String::from_utf8(buf)
+            .map_err(|e| ExchangeError::ParseError(format!("체결통보 UTF-8 변환 실패: {}", e)))
+    }
+}
+
+/// 실시간 체결통보.
+///
+/// 주문 접수/정정/취소/거부 통보와 체결 통보가 같은 채널로 전달됩니다.
+/// 실제 체결분은 [`is_fill`](Self::is_fill)로 구분합니다.
+#[derive(Debug, Clone)]
+pub struct KisExecutionNotice {
+    /// 계좌번호
+    pub account_no: String,
+    /// 주문번호 (거래소 주문 ID)
+    pub order_no: String,
+    /// 원주문번호 (정정/취소 시)
+    pub original_order_no: String,
+    /// 종목코드 (국내) 또는 티커 (해외)
+    pub ticker: String,
+    /// 매도/매수 구분
+    pub side: Side,
+    /// 체결 수량 (체결 통보가 아니면 주문 수량이 올 수 있음)
+    pub filled_quantity: Decimal,
+    /// 체결 단가
+    pub fill_price: Decimal,
+    /// 주문 수량
+    pub order_quantity: Decimal,
+    /// 체결 시각 (HHMMSS)
+    pub fill_time: String,
+    /// 체결 통보 여부 (CNTG_YN = 2, 아니면 접수/정정/취소 통보)
+    pub filled: bool,
+    /// 주문 거부 여부 (RFUS_YN = 1)
+    pub rejected: bool,
+}
+
+impl KisExecutionNotice {
+    /// 실제 체결 통보인지 여부.
+    pub fn is_fill(&self) -> bool {
+        self.filled && !self.rejected && self.filled_quantity > Decimal::ZERO
+    }
+
+    /// 체결 통보를 [`FillReport`]로 변환 (체결이 아니면 `None`).
+    pub fn to_fill_report(&self) -> Option<FillReport> {
+        self.is_fill().then(|| FillReport {
+            exchange_order_id: self.order_no.clone(),
+            ticker: self.ticker.clone(),
+            side: self.side,
+            quantity: self.filled_quantity,
+            price: self.fill_price,
+            timestamp: Utc::now(),
+        })
+    }
+}
+
+/// 매도매수구분 (01: 매도, 02: 매수).
+fn parse_side(code: &str) -> Option<Side> {
+    match code.trim() {
+        "01" => Some(Side::Sell),
+        "02" => Some(Side::Buy),
+        _ => None,
+    }
+}
+
+fn parse_decimal(value: &str) -> Decimal {
+    Decimal::from_str(value.trim()).unwrap_or_default()
+}
+
+/// 국내 체결통보 파싱 (`H0STCNI0`).
+///
+/// 데이터 형식: 고객ID^계좌번호^주문번호^원주문번호^매도매수구분^정정구분^주문종류^
+/// 주문조건^종목코드^체결수량^체결단가^체결시간^거부여부^체결여부^접수여부^지점번호^주문수량^...
+pub(crate) fn parse_kr_notice(data: &str) -> Option<KisExecutionNotice> {
+    let fields: Vec<&str> = data.split('^').collect();
+
+    if fields.len() < 17 {
+        warn!("국내 체결통보 필드 부족: {}", fields.len());
+        return None;
+    }
+
+    Some(KisExecutionNotice {
+        account_no: fields[1].trim().to_string(),
+        order_no: fields[2].trim().to_string(),
+        original_order_no: fields[3].trim().to_string(),
+        side: parse_side(fields[4])?,
+        ticker: fields[8].trim().to_string(),
+        filled_quantity: parse_decimal(fields[9]),
+        fill_price: parse_decimal(fields[10]),
+        fill_time: fields[11].trim().to_string(),
+        rejected: fields[12].trim() == "1",
+        filled: fields[13].trim() == "2",
+        order_quantity: parse_decimal(fields[16]),
+    })
+}
+
+/// 해외 체결통보 파싱 (`H0GSCNI0`).
+///
+/// 데이터 형식: 고객ID^계좌번호^주문번호^원주문번호^매도매수구분^정정구분^주문종류2^
+/// 종목코드^체결수량^체결단가^체결시간^거부여부^체결여부^접수여부^지점번호^주문수량^...
+pub(crate) fn parse_us_notice(data: &str) -> Option<KisExecutionNotice> {
+    let fields: Vec<&str> = data.split('^').collect();
+
+    if fields.len() < 16 {
+        warn!("해외 체결통보 필드 부족: {}", fields.len());
+        return None;
+    }
+
+    Some(KisExecutionNotice {
+        account_no: fields[1].trim().to_string(),
+        order_no: fields[2].trim().to_string(),
+        original_order_no: fields[3].trim().to_string(),
+        side: parse_side(fields[4])?,
+        ticker: fields[7].trim().to_string(),
+        filled_quantity: parse_decimal(fields[8]),
+        fill_price: parse_decimal(fields[9]),
+        fill_time: fields[10].trim().to_string(),
+        rejected: fields[11].trim() == "1",
+        filled: fields[12].trim() == "2",
+        order_quantity: parse_decimal(fields[15]),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aes::cipher::BlockEncrypt;
+
+    const KEY: &str = "abcdefghijklmnopqrstuvwxyz123456";
+    const IV: &str = "1234567890abcdef";
+
+    /// 테스트용 AES-256-CBC 암호화 (PKCS7).
+    fn encrypt(plain: &str) -> String {
+        let cipher = Aes256::new(GenericArray::from_slice(KEY.as_bytes()));
+        let mut buf = plain.as_bytes().to_vec();
+        let pad = BLOCK_SIZE - buf.len() % BLOCK_SIZE;
+        buf.extend(std::iter::repeat_n(pad as u8, pad));
+
+        let mut prev: [u8; BLOCK_SIZE] = IV.as_bytes().try_into().unwrap();
+        for chunk in buf.chunks_exact_mut(BLOCK_SIZE) {
+            for (byte, p) in chunk.iter_mut().zip(prev.iter()) {
+                *byte ^= p;
+            }
+            cipher.encrypt_block(GenericArray::from_mut_slice(chunk));
+            prev.copy_from_slice(chunk);
+        }
+        STANDARD.encode(buf)
+    }
+
+    const KR_FILL: &str = "myhtsid^5012345601^0000012345^^02^0^00^0^005930^10^70100^093015^0^2^2^\
+                           00950^10^^^^^^삼성전자^70100";
+
+    #[test]
+    fn test_decrypt_round_trip() {
+        let cipher = ExecutionCipher::new(KEY, IV).unwrap();
+        let encrypted = encrypt(KR_FILL);
+        assert_eq!(cipher.decrypt(&encrypted).unwrap(), KR_FILL);
+
+        // 키가 다르면 패딩 검증 또는 UTF-8 변환에서 실패
+        let wrong = ExecutionCipher::new("00000000000000000000000000000000", IV).unwrap();
+        assert!(wrong.decrypt(&encrypted).is_err());
+        assert!(cipher.decrypt("not base64!").is_err());
+    }
+
+    #[test]
+    fn test_cipher_from_subscribe_response() {
+        let response = format!(
+            r#"{{"header":{{"tr_id":"H0STCNI9","tr_key":"myhtsid","encrypt":"N"}},
+               "body":{{"rt_cd":"0","msg_cd":"OPSP0000","msg1":"SUBSCRIBE SUCCESS",
+               "output":{{"iv":"{}","key":"{}"}}}}}}"#,
+            IV, KEY
+        );
+        assert!(ExecutionCipher::from_subscribe_response(&response, &["H0STCNI9"]).is_some());
+        assert!(ExecutionCipher::from_subscribe_response(&response, &["H0GSCNI9"]).is_none());
+
+        let pingpong = r#"{"header":{"tr_id":"PINGPONG","datetime":"20260310093000"}}"#;
+        assert!(ExecutionCipher::from_subscribe_response(pingpong, &["H0STCNI9"]).is_none());
+    }
+
+    #[test]
+    fn test_parse_kr_notice() {
+        let notice = parse_kr_notice(KR_FILL).unwrap();
+        assert_eq!(notice.order_no, "0000012345");
+        assert_eq!(notice.ticker, "005930");
+        assert_eq!(notice.side, Side::Buy);
+        assert_eq!(notice.filled_quantity, Decimal::from(10));
+        assert_eq!(notice.fill_price, Decimal::from(70100));
+        assert!(notice.is_fill());
+
+        let report = notice.to_fill_report().unwrap();
+        assert_eq!(report.exchange_order_id, "0000012345");
+        assert_eq!(report.quantity, Decimal::from(10));
+
+        // 접수 통보는 체결로 취급하지 않음
+        let accepted = KR_FILL.replacen("^0^2^2^", "^0^1^2^", 1);
+        let notice = parse_kr_notice(&accepted).unwrap();
+        assert!(!notice.is_fill());
+        assert!(notice.to_fill_report().is_none());
+
+        assert!(parse_kr_notice("myhtsid^5012345601").is_none());
+    }
+
+    #[test]
+    fn test_parse_us_notice() {
+        let data = "myhtsid^5012345601^0000054321^^01^0^00^AAPL^3^189.5500^223015^0^2^2^\
+                    00950^5^^^^^^APPLE INC";
+        let notice = parse_us_notice(data).unwrap();
+        assert_eq!(notice.ticker, "AAPL");
+        assert_eq!(notice.side, Side::Sell);
+        assert_eq!(notice.fill_price, Decimal::new(1895500, 4));
+        assert_eq!(notice.order_quantity, Decimal::from(5));
+        assert!(notice.is_fill());
+    }
+}
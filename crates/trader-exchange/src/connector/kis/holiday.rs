@@ -5,6 +5,10 @@
 //! - 미국 시장 (NYSE, NASDAQ, AMEX)
 //! - 기타 해외 시장
 //!
+//! [`HolidayChecker::with_calendar`]로 DB에서 적재한 [`MarketCalendar`]를 연결하면
+//! 달력에 적재된 연도는 API 호출 없이 판정하고, 적재되지 않은 연도만 API로 조회합니다.
+//! 연간 달력 갱신은 [`HolidayChecker::fetch_year`]로 한 해 전체를 조회합니다.
+//!
 //! # API 엔드포인트
 //! - 국내: `/uapi/domestic-stock/v1/quotations/chk-holiday` (tr_id: CTCA0903R)
 //! - 해외: `/uapi/overseas-stock/v1/quotations/countries-holiday` (tr_id: CTOS5011R)
@@ -13,15 +17,19 @@
 #![allow(unused_comparisons)] // minute >= 0 비교 (문서화 목적)
 
 use super::auth::KisOAuth;
+use super::session::{us_trading_session_at, UsTradingSession};
 use crate::ExchangeError;
 use chrono::{Datelike, NaiveDate, Timelike, Utc, Weekday};
 use reqwest::Client;
 use serde::Deserialize;
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::RwLock;
 use tracing::{debug, info, warn};
+use trader_core::{
+    krx_first_trading_day, us_early_closes, CalendarMarket, MarketCalendar, MarketCalendarEntry,
+};
 
 /// 휴장일 API용 거래 ID.
 pub mod tr_id {
@@ -135,8 +143,10 @@ struct HolidayCache {
 ///
 /// API 호출을 최소화하기 위한 캐싱을 제공합니다.
 pub struct HolidayChecker {
-    oauth: KisOAuth,
+    oauth: Arc<KisOAuth>,
     client: Client,
+    /// DB에서 적재한 연간 달력 (적재된 연도는 API 대신 사용)
+    calendar: Option<Arc<RwLock<MarketCalendar>>>,
     /// 국내 시장 휴장일 캐시
     kr_cache: Arc<RwLock<Option<HolidayCache>>>,
     /// 미국 시장 휴장일 캐시
@@ -149,6 +159,14 @@ impl HolidayChecker {
     /// # Errors
     /// HTTP 클라이언트 생성에 실패하면 `ExchangeError::NetworkError`를 반환합니다.
     pub fn new(oauth: KisOAuth) -> Result<Self, ExchangeError> {
+        Self::with_shared_oauth(Arc::new(oauth))
+    }
+
+    /// 공유된 OAuth로 휴장일 확인기 생성.
+    ///
+    /// # Errors
+    /// HTTP 클라이언트 생성에 실패하면 `ExchangeError::NetworkError`를 반환합니다.
+    pub fn with_shared_oauth(oauth: Arc<KisOAuth>) -> Result<Self, ExchangeError> {
         let client = Client::builder()
             .timeout(Duration::from_secs(oauth.config().timeout_secs))
             .build()
@@ -159,11 +177,26 @@ impl HolidayChecker {
         Ok(Self {
             oauth,
             client,
+            calendar: None,
             kr_cache: Arc::new(RwLock::new(None)),
             us_cache: Arc::new(RwLock::new(None)),
         })
     }
 
+    /// 연간 시장 달력 연결.
+    ///
+    /// 달력에 적재된 연도는 API를 호출하지 않고 달력으로 판정합니다.
+    pub fn with_calendar(mut self, calendar: Arc<RwLock<MarketCalendar>>) -> Self {
+        self.calendar = Some(calendar);
+        self
+    }
+
+    /// 연결된 달력으로 휴장일 판정 (달력이 없거나 적재되지 않은 연도면 `None`).
+    async fn calendar_holiday(&self, market: CalendarMarket, date: NaiveDate) -> Option<bool> {
+        let calendar = self.calendar.as_ref()?;
+        calendar.read().await.is_holiday(market, date)
+    }
+
     /// 주어진 날짜가 국내 시장 휴장일인지 확인.
     ///
     /// 해당 날짜에 시장이 휴장이면 `true`를 반환합니다.
@@ -173,6 +206,10 @@ impl HolidayChecker {
             return Ok(true);
         }
 
+        if let Some(is_holiday) = self.calendar_holiday(CalendarMarket::Kr, date).await {
+            return Ok(is_holiday);
+        }
+
         // 캐시 확인
         let year_month = date.format("%Y%m").to_string();
         {
@@ -210,6 +247,10 @@ impl HolidayChecker {
             return Ok(true);
         }
 
+        if let Some(is_holiday) = self.calendar_holiday(CalendarMarket::Us, date).await {
+            return Ok(is_holiday);
+        }
+
         // 캐시 확인
         let year_month = date.format("%Y%m").to_string();
         {
@@ -222,9 +263,11 @@ impl HolidayChecker {
         }
 
         // 캐시 미스 - API 호출
-        let holidays = self
+        let holidays: HashSet<NaiveDate> = self
             .fetch_overseas_holidays(country_code::USA, &year_month)
-            .await?;
+            .await?
+            .into_keys()
+            .collect();
         let is_holiday = holidays.contains(&date);
 
         // 캐시 업데이트
@@ -277,31 +320,37 @@ impl HolidayChecker {
         Ok(is_open)
     }
 
-    /// 현재 미국 시장 개장 여부 확인 (거래 시간 고려).
+    /// 현재 미국 주식 거래 세션 조회 (휴장일 반영).
     ///
-    /// 정규 거래 시간: 09:30 - 16:00 EST
-    /// 프리마켓: 04:00 - 09:30 EST
-    /// 애프터마켓: 16:00 - 20:00 EST
-    pub async fn is_us_market_open(&self, include_extended: bool) -> Result<bool, ExchangeError> {
-        // 휴장일 체크
-        if self.is_us_holiday_today().await? {
-            return Ok(false);
+    /// 세션이 속한 거래일(주간거래는 다음 거래일)이 휴장일이면 `Closed`를 반환합니다.
+    pub async fn us_trading_session(&self) -> Result<UsTradingSession, ExchangeError> {
+        let now = Utc::now();
+        let session = us_trading_session_at(now);
+        if !session.is_open() {
+            return Ok(session);
         }
 
-        // EST 현재 시간 확인 (UTC-5)
-        let est_now = Utc::now() - chrono::Duration::hours(5);
-        let hour = est_now.hour();
-        let minute = est_now.minute();
-
-        if include_extended {
-            // 확장 거래시간 포함: 04:00 ~ 20:00
-            let is_open = (4..20).contains(&hour);
-            Ok(is_open)
-        } else {
-            // 정규 거래시간만: 09:30 ~ 16:00
-            let is_open = (hour == 9 && minute >= 30) || (10..16).contains(&hour);
-            Ok(is_open)
+        if self.is_us_holiday(session.trading_date(now)).await? {
+            return Ok(UsTradingSession::Closed);
         }
+        Ok(session)
+    }
+
+    /// 현재 미국 시장 개장 여부 확인 (거래 시간 고려).
+    ///
+    /// 정규 거래 시간: 09:30 - 16:00 ET
+    /// 프리마켓: 04:00 - 09:30 ET
+    /// 애프터마켓: 16:00 - 20:00 ET
+    ///
+    /// 주간거래 세션은 포함하지 않습니다. 서머타임은 자동 반영됩니다.
+    pub async fn is_us_market_open(&self, include_extended: bool) -> Result<bool, ExchangeError> {
+        let session = self.us_trading_session().await?;
+
+        Ok(match session {
+            UsTradingSession::Regular => true,
+            UsTradingSession::PreMarket | UsTradingSession::AfterHours => include_extended,
+            UsTradingSession::Daytime | UsTradingSession::Closed => false,
+        })
     }
 
     /// 국내 시장의 다음 거래일 조회.
@@ -418,12 +467,12 @@ impl HolidayChecker {
         Ok(holidays)
     }
 
-    /// KIS API에서 해외 휴장일 조회.
+    /// KIS API에서 해외 휴장일 조회 (휴장일 → 휴장사유명).
     async fn fetch_overseas_holidays(
         &self,
         country: &str,
         year_month: &str,
-    ) -> Result<HashSet<NaiveDate>, ExchangeError> {
+    ) -> Result<HashMap<NaiveDate, String>, ExchangeError> {
         let url = format!(
             "{}/uapi/overseas-stock/v1/quotations/countries-holiday",
             self.oauth.config().rest_base_url()
@@ -498,7 +547,7 @@ impl HolidayChecker {
                     "No holiday data available for {} in {}",
                     country, year_month
                 );
-                return Ok(HashSet::new());
+                return Ok(HashMap::new());
             }
             return Err(ExchangeError::ApiError {
                 code: resp.msg_cd.parse().unwrap_or(-1),
@@ -507,11 +556,11 @@ impl HolidayChecker {
         }
 
         // 해당 국가의 휴장일만 추출
-        let mut holidays = HashSet::new();
+        let mut holidays = HashMap::new();
         for item in resp.output {
             if item.country_code == country {
                 if let Ok(date) = NaiveDate::parse_from_str(&item.holiday_date, "%Y%m%d") {
-                    holidays.insert(date);
+                    holidays.insert(date, item.holiday_name.clone());
                     debug!(
                         "{} Holiday: {} ({})",
                         country, item.holiday_date, item.holiday_name
@@ -565,10 +614,76 @@ impl HolidayChecker {
         let holidays = self
             .fetch_overseas_holidays(country_code::USA, &year_month)
             .await?;
-        let mut sorted: Vec<_> = holidays.into_iter().collect();
+        let mut sorted: Vec<_> = holidays.into_keys().collect();
         sorted.sort();
         Ok(sorted)
     }
+
+    /// 한 해 전체 달력 조회 (월별 API 호출 12회).
+    ///
+    /// API로 조회한 평일 휴장일에 규칙으로 정해지는 단축 거래일을 더합니다.
+    /// - KR: 연초 첫 거래일 10:00 개장
+    /// - US: 독립기념일 전날, 추수감사절 다음 날, 크리스마스 이브 13:00 폐장
+    ///
+    /// 수능일 개장 지연처럼 규칙으로 정할 수 없는 단축 거래일은 포함하지 않습니다.
+    pub async fn fetch_year(
+        &self,
+        market: CalendarMarket,
+        year: i32,
+    ) -> Result<Vec<MarketCalendarEntry>, ExchangeError> {
+        let mut holidays: BTreeMap<NaiveDate, Option<String>> = BTreeMap::new();
+        for month in 1..=12 {
+            let year_month = format!("{}{:02}", year, month);
+            match market {
+                CalendarMarket::Kr => {
+                    for date in self.fetch_kr_holidays(&year_month).await? {
+                        holidays.insert(date, None);
+                    }
+                }
+                CalendarMarket::Us => {
+                    for (date, name) in self
+                        .fetch_overseas_holidays(country_code::USA, &year_month)
+                        .await?
+                    {
+                        holidays.insert(date, Some(name).filter(|n| !n.is_empty()));
+                    }
+                }
+            }
+        }
+
+        // 주말은 달력에서 항상 휴장이므로 평일 휴장일만 저장
+        holidays.retain(|date, _| {
+            date.year() == year && !matches!(date.weekday(), Weekday::Sat | Weekday::Sun)
+        });
+
+        let mut entries: Vec<MarketCalendarEntry> = holidays
+            .iter()
+            .map(|(date, name)| MarketCalendarEntry::holiday(market, *date, name.clone()))
+            .collect();
+
+        let special_days: Vec<MarketCalendarEntry> = match market {
+            CalendarMarket::Kr => {
+                let dates: Vec<NaiveDate> = holidays.keys().copied().collect();
+                krx_first_trading_day(year, &dates).into_iter().collect()
+            }
+            CalendarMarket::Us => us_early_closes(year),
+        };
+        entries.extend(
+            special_days
+                .into_iter()
+                .filter(|entry| !holidays.contains_key(&entry.date)),
+        );
+        entries.sort_by_key(|entry| entry.date);
+
+        info!(
+            market = %market,
+            year,
+            holidays = holidays.len(),
+            entries = entries.len(),
+            "Fetched market calendar"
+        );
+        Ok(entries)
+    }
 }
 
 /// 시장 상태 열거형.
@@ -595,6 +710,18 @@ impl std::fmt::Display for MarketStatus {
     }
 }
 
+impl From<UsTradingSession> for MarketStatus {
+    fn from(session: UsTradingSession) -> Self {
+        match session {
+            UsTradingSession::Regular => MarketStatus::Open,
+            UsTradingSession::PreMarket => MarketStatus::PreMarket,
+            UsTradingSession::AfterHours => MarketStatus::AfterHours,
+            // 주간거래는 미국 거래소 기준으로는 장외 시간
+            UsTradingSession::Daytime | UsTradingSession::Closed => MarketStatus::Closed,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -634,6 +761,22 @@ mod tests {
         assert_eq!(MarketStatus::AfterHours.to_string(), "After-Hours");
     }
 
+    #[test]
+    fn test_market_status_from_session() {
+        assert_eq!(
+            MarketStatus::from(UsTradingSession::Regular),
+            MarketStatus::Open
+        );
+        assert_eq!(
+            MarketStatus::from(UsTradingSession::PreMarket),
+            MarketStatus::PreMarket
+        );
+        assert_eq!(
+            MarketStatus::from(UsTradingSession::Daytime),
+            MarketStatus::Closed
+        );
+    }
+
     #[test]
     fn test_last_day_of_month() {
         // 2월 윤년
@@ -7,8 +7,10 @@
 //!
 //! - OAuth 2.0 인증 및 자동 토큰 갱신
 //! - 국내 주식/ETF 거래
+//! - KOSPI200 / 미니 KOSPI200 선물 주문, 증거금 조회, 실시간 선물 시세
 //! - KIS를 통한 해외 주식/ETF 거래
-//! - WebSocket을 통한 실시간 시세 수신
+//! - WebSocket을 통한 실시간 시세 수신 (지수 백오프 재연결 및 구독 복원)
+//! - WebSocket 실시간 체결통보 (AES 복호화 후 주문 체결 푸시)
 //! - 모의투자 지원
 //!
 //! # API 문서
@@ -40,21 +42,34 @@ pub mod auth;
 pub mod client_kr;
 pub mod client_us;
 pub mod config;
+pub mod execution;
 pub mod holiday;
+pub mod reconnect;
+pub mod session;
 pub mod websocket_kr;
 pub mod websocket_us;
 
 pub use auth::{KisOAuth, TokenState};
 pub use client_kr::{
-    KisKrClient, KrAccountSummary, KrBalance, KrBuyPower, KrHolding, KrMinuteOhlcv, KrOhlcv,
-    KrOrderBook, KrOrderExecution, KrOrderHistory, KrOrderResponse, StockPrice,
+    KisKrClient, KrAccountSummary, KrBalance, KrBuyPower, KrFuturesBalance, KrFuturesMargin,
+    KrFuturesPosition, KrHolding, KrMinuteOhlcv, KrOhlcv, KrOptionQuote, KrOrderBook,
+    KrOrderExecution, KrOrderHistory, KrOrderResponse, StockPrice,
 };
 pub use client_us::{
-    KisUsClient, UsBalance, UsHolding, UsMarketSession, UsOhlcv, UsOrderExecution, UsOrderResponse,
+    KisUsClient, UsBalance, UsFxConversionOrder, UsHolding, UsMarketSession, UsOhlcv,
+    UsOrderExecution, UsOrderResponse, UsPurchasableAmount,
 };
 pub use config::{KisAccountType, KisConfig, KisEnvironment};
+pub use execution::KisExecutionNotice;
 pub use holiday::{HolidayChecker, MarketStatus};
-pub use websocket_kr::{KisKrWebSocket, KrRealtimeMessage, KrRealtimeOrderbook, KrRealtimeTrade};
+pub use reconnect::default_reconnect_policy;
+pub use session::{
+    current_us_trading_session, daytime_exchange_code, us_trading_session_at, UsTradingSession,
+};
+pub use websocket_kr::{
+    KisKrWebSocket, KrRealtimeFuturesTrade, KrRealtimeMarketStatus, KrRealtimeMessage,
+    KrRealtimeOrderbook, KrRealtimeTrade,
+};
 pub use websocket_us::{KisUsWebSocket, UsRealtimeMessage, UsRealtimeOrderbook, UsRealtimeTrade};
 
 /// KIS 거래 ID (tr_id) 상수 모음.
@@ -90,6 +105,11 @@ pub mod tr_id {
     /// 국내 주식 분봉 조회 (모의)
     pub const KR_MINUTE_PRICE_PAPER: &str = "FHKST03010100";
 
+    /// 국내 주식 일별 분봉 조회 (실전)
+    pub const KR_DAILY_MINUTE_PRICE_REAL: &str = "FHKST03010230";
+    /// 국내 주식 일별 분봉 조회 (모의, 미지원)
+    pub const KR_DAILY_MINUTE_PRICE_PAPER: &str = "FHKST03010230";
+
     /// 국내 주식 현금 매수 (실전)
     pub const KR_BUY_REAL: &str = "TTTC0802U";
     /// 국내 주식 현금 매수 (모의)
@@ -129,6 +149,30 @@ pub mod tr_id {
     /// 국내 주식 일별 주문체결 조회 (실전 - ISA/연금저축 등 특수계좌, 1년 이내)
     pub const KR_ORDER_HISTORY_ISA_REAL: &str = "CTSC9115R";
 
+    // ========================================
+    // Korean Futures/Options (국내 선물옵션)
+    // ========================================
+
+    /// 국내 옵션 전광판 콜/풋 조회 (실전)
+    pub const KR_OPTION_BOARD_REAL: &str = "FHPIF05030100";
+    /// 국내 옵션 전광판 콜/풋 조회 (모의)
+    pub const KR_OPTION_BOARD_PAPER: &str = "FHPIF05030100";
+
+    /// 국내 선물옵션 주간 주문 (실전)
+    pub const KR_FUTURES_ORDER_REAL: &str = "TTTO1101U";
+    /// 국내 선물옵션 주간 주문 (모의)
+    pub const KR_FUTURES_ORDER_PAPER: &str = "VTTO1101U";
+
+    /// 국내 선물옵션 정정/취소 (실전)
+    pub const KR_FUTURES_CANCEL_REAL: &str = "TTTO1103U";
+    /// 국내 선물옵션 정정/취소 (모의)
+    pub const KR_FUTURES_CANCEL_PAPER: &str = "VTTO1103U";
+
+    /// 국내 선물옵션 잔고/증거금 조회 (실전)
+    pub const KR_FUTURES_BALANCE_REAL: &str = "CTFO6118R";
+    /// 국내 선물옵션 잔고/증거금 조회 (모의)
+    pub const KR_FUTURES_BALANCE_PAPER: &str = "VTFO6118R";
+
     // ========================================
     // US Stock (해외 주식 - 미국)
     // ========================================
@@ -168,6 +212,11 @@ pub mod tr_id {
     /// 해외 주식 주문 취소 (모의)
     pub const US_CANCEL_PAPER: &str = "VTTT1004U";
 
+    /// 해외 주식 주간거래 매수 (실전 전용, 모의투자 미지원)
+    pub const US_DAYTIME_BUY_REAL: &str = "TTTS6036U";
+    /// 해외 주식 주간거래 매도 (실전 전용, 모의투자 미지원)
+    pub const US_DAYTIME_SELL_REAL: &str = "TTTS6037U";
+
     /// 해외 주식 잔고 (실전)
     pub const US_BALANCE_REAL: &str = "JTTT3012R";
     /// 해외 주식 잔고 (모의)
@@ -188,6 +237,16 @@ pub mod tr_id {
     /// 해외 주식 체결 내역 조회 (모의)
     pub const US_ORDER_EXECUTION_PAPER: &str = "VTTS3035R";
 
+    /// 해외 주식 매수가능금액 조회 (실전)
+    pub const US_PURCHASABLE_AMOUNT_REAL: &str = "TTTS3007R";
+    /// 해외 주식 매수가능금액 조회 (모의)
+    pub const US_PURCHASABLE_AMOUNT_PAPER: &str = "VTTS3007R";
+
+    /// 해외 주식 원화→외화 환전 주문 (실전)
+    pub const US_FX_CONVERSION_REAL: &str = "TTTS6036U";
+    /// 해외 주식 원화→외화 환전 주문 (모의)
+    pub const US_FX_CONVERSION_PAPER: &str = "VTTS6036U";
+
     // ========================================
     // WebSocket Real-time (실시간 시세)
     // ========================================
@@ -196,10 +255,24 @@ pub mod tr_id {
     pub const WS_KR_TRADE: &str = "H0STCNT0";
     /// 국내 주식 실시간 호가
     pub const WS_KR_ORDERBOOK: &str = "H0STASP0";
+    /// 국내 주식 실시간 장운영정보 (거래정지, VI 발동/해제)
+    pub const WS_KR_MARKET_STATUS: &str = "H0STMKO0";
+    /// 지수선물 실시간 체결가
+    pub const WS_KR_FUTURES_TRADE: &str = "H0IFCNT0";
+    /// 지수선물 실시간 호가
+    pub const WS_KR_FUTURES_ORDERBOOK: &str = "H0IFASP0";
     /// 해외 주식 실시간 체결
     pub const WS_US_TRADE: &str = "HDFSCNT0";
     /// 해외 주식 실시간 호가
     pub const WS_US_ORDERBOOK: &str = "HDFSASP0";
+    /// 국내 주식 실시간 체결통보 (실전)
+    pub const WS_KR_EXECUTION_REAL: &str = "H0STCNI0";
+    /// 국내 주식 실시간 체결통보 (모의)
+    pub const WS_KR_EXECUTION_PAPER: &str = "H0STCNI9";
+    /// 해외 주식 실시간 체결통보 (실전)
+    pub const WS_US_EXECUTION_REAL: &str = "H0GSCNI0";
+    /// 해외 주식 실시간 체결통보 (모의)
+    pub const WS_US_EXECUTION_PAPER: &str = "H0GSCNI9";
 }
 
 /// KIS API에서 사용하는 거래소 코드.
@@ -210,6 +283,12 @@ pub mod exchange_code {
     pub const NASDAQ: &str = "NAS";
     /// 미국 AMEX
     pub const AMEX: &str = "AMS";
+    /// 미국 NASDAQ 주간거래
+    pub const DAYTIME_NASDAQ: &str = "BAQ";
+    /// 미국 NYSE 주간거래
+    pub const DAYTIME_NYSE: &str = "BAY";
+    /// 미국 AMEX 주간거래
+    pub const DAYTIME_AMEX: &str = "BAA";
     /// 한국 KRX (KOSPI + KOSDAQ)
     pub const KRX: &str = "KRX";
 }
@@ -251,3 +330,15 @@ pub mod order_type {
     /// FOK 최유리
     pub const FOK_BEST: &str = "16";
 }
+
+/// KIS 국내 선물옵션 주문구분 (`ORD_DVSN_CD`).
+pub mod futures_order_type {
+    /// 지정가
+    pub const LIMIT: &str = "01";
+    /// 시장가
+    pub const MARKET: &str = "02";
+    /// 조건부 지정가
+    pub const CONDITIONAL_LIMIT: &str = "03";
+    /// 최유리 지정가
+    pub const BEST_LIMIT: &str = "04";
+}
@@ -0,0 +1,159 @@
+//! KIS WebSocket 재연결 상태 추적.
+//!
+//! 국내/해외 WebSocket 클라이언트가 공유하는 재연결 정책입니다.
+//! 연결이 끊기면 지수 백오프로 재시도하고, 재연결·구독 복원이 끝나면 공백 구간을 닫습니다.
+//! 연결이 한 번 복구되면 시도 횟수를 초기화하므로, 장시간 운영 중 간헐적인 끊김이
+//! 누적되어 재연결을 포기하는 일이 없습니다.
+
+use chrono::{DateTime, Utc};
+use std::time::Duration;
+use trader_core::StreamStatus;
+
+use crate::retry::RetryConfig;
+
+/// 기본 재연결 정책 (1초부터 2배씩, 최대 60초, 10회).
+pub fn default_reconnect_policy() -> RetryConfig {
+    RetryConfig {
+        max_retries: 10,
+        base_delay: Duration::from_secs(1),
+        max_delay: Duration::from_secs(60),
+        use_exponential_backoff: true,
+        backoff_multiplier: 2.0,
+        add_jitter: true,
+    }
+}
+
+/// 재연결 시도 및 데이터 공백 구간 추적.
+#[derive(Debug)]
+pub(crate) struct ReconnectTracker {
+    source: &'static str,
+    policy: RetryConfig,
+    /// 마지막 복구 이후 재연결 시도 횟수
+    attempt: u32,
+    /// 연결이 끊긴 시각 (복구되면 None)
+    disconnected_at: Option<DateTime<Utc>>,
+}
+
+impl ReconnectTracker {
+    pub(crate) fn new(source: &'static str, policy: RetryConfig) -> Self {
+        Self {
+            source,
+            policy,
+            attempt: 0,
+            disconnected_at: None,
+        }
+    }
+
+    /// 재연결 정책 교체.
+    pub(crate) fn set_policy(&mut self, policy: RetryConfig) {
+        self.policy = policy;
+    }
+
+    /// 연결 및 구독 복원 완료.
+    ///
+    /// 끊김 이후의 복구라면 `Recovered` 이벤트를 반환하고 시도 횟수를 초기화합니다.
+    pub(crate) fn on_connected(&mut self, resubscribed: usize) -> Option<StreamStatus> {
+        let attempt = std::mem::take(&mut self.attempt);
+        self.disconnected_at
+            .take()
+            .map(|since| StreamStatus::recovered(self.source, attempt, since, resubscribed))
+    }
+
+    /// 연결 끊김 또는 연결 실패 기록 후 다음 재연결 계획.
+    ///
+    /// 재시도 가능하면 대기 시간과 `Reconnecting` 이벤트를,
+    /// 최대 재시도 횟수를 넘었으면 `Failed` 이벤트를 `Err`로 반환합니다.
+    pub(crate) fn on_disconnected(
+        &mut self,
+        reason: &str,
+    ) -> Result<(Duration, StreamStatus), StreamStatus> {
+        let since = *self.disconnected_at.get_or_insert_with(Utc::now);
+        self.attempt += 1;
+
+        if self.attempt > self.policy.max_retries {
+            return Err(StreamStatus::failed(
+                self.source,
+                self.attempt - 1,
+                Some(since),
+                reason,
+            ));
+        }
+
+        let delay = self.policy.backoff_delay(self.attempt - 1);
+        Ok((
+            delay,
+            StreamStatus::reconnecting(self.source, self.attempt, since, reason),
+        ))
+    }
+
+    /// 최대 재시도 횟수.
+    pub(crate) fn max_attempts(&self) -> u32 {
+        self.policy.max_retries
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use trader_core::StreamState;
+
+    fn policy(max_retries: u32) -> RetryConfig {
+        RetryConfig {
+            max_retries,
+            add_jitter: false,
+            ..default_reconnect_policy()
+        }
+    }
+
+    #[test]
+    fn test_backoff_grows_and_recovers() {
+        let mut tracker = ReconnectTracker::new("kis_kr", policy(5));
+        // 최초 연결은 공백이 아님
+        assert!(tracker.on_connected(3).is_none());
+
+        let (delay, status) = tracker.on_disconnected("연결 끊김").unwrap();
+        assert_eq!(delay, Duration::from_secs(1));
+        assert_eq!(status.state, StreamState::Reconnecting);
+        assert_eq!(status.attempt, 1);
+        let since = status.disconnected_at;
+
+        let (delay, status) = tracker.on_disconnected("연결 실패").unwrap();
+        assert_eq!(delay, Duration::from_secs(2));
+        assert_eq!(status.attempt, 2);
+        // 공백 시작 시각은 최초 끊김 기준 유지
+        assert_eq!(status.disconnected_at, since);
+
+        let recovered = tracker.on_connected(3).unwrap();
+        assert!(recovered.is_recovered());
+        assert_eq!(recovered.attempt, 2);
+        assert_eq!(recovered.resubscribed, 3);
+        assert_eq!(recovered.disconnected_at, since);
+
+        // 복구 후에는 시도 횟수 초기화
+        let (delay, status) = tracker.on_disconnected("연결 끊김").unwrap();
+        assert_eq!(delay, Duration::from_secs(1));
+        assert_eq!(status.attempt, 1);
+    }
+
+    #[test]
+    fn test_gives_up_after_max_attempts() {
+        let mut tracker = ReconnectTracker::new("kis_us", policy(2));
+        assert!(tracker.on_disconnected("a").is_ok());
+        assert!(tracker.on_disconnected("b").is_ok());
+
+        let failed = tracker.on_disconnected("c").unwrap_err();
+        assert_eq!(failed.state, StreamState::Failed);
+        assert_eq!(failed.attempt, 2);
+        assert_eq!(failed.source, "kis_us");
+    }
+
+    #[test]
+    fn test_backoff_capped() {
+        let mut tracker = ReconnectTracker::new("kis_kr", policy(20));
+        let mut last = Duration::ZERO;
+        for _ in 0..10 {
+            last = tracker.on_disconnected("x").unwrap().0;
+        }
+        assert_eq!(last, Duration::from_secs(60));
+    }
+}
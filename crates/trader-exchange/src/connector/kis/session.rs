@@ -0,0 +1,211 @@
+//! 미국 주식 거래 세션 구분.
+//!
+//! 한국투자증권은 미국 주식을 두 가지 원장으로 거래합니다:
+//! - **주간거래** (KST 낮 시간, 미국 동부시간 20:00-04:00): 대체거래소(ATS)를 통한 거래,
+//!   별도 주문 TR과 거래소 코드(BAQ/BAY/BAA)를 사용
+//! - **야간거래** (KST 밤 시간): 미국 거래소의 프리마켓, 정규장, 애프터마켓
+//!
+//! 세션 경계는 미국 동부시간(`America/New_York`) 기준이므로 서머타임이 자동 반영됩니다.
+//! 휴장일은 고려하지 않으며, 휴장일 확인은 [`super::HolidayChecker`]에서 합니다.
+
+use chrono::{DateTime, Datelike, NaiveDate, Timelike, Utc, Weekday};
+use chrono_tz::America::New_York;
+use serde::{Deserialize, Serialize};
+
+use super::exchange_code;
+
+/// 프리마켓 시작 (04:00 ET, 분 단위).
+const PRE_MARKET_OPEN: u32 = 4 * 60;
+/// 정규장 시작 (09:30 ET).
+const REGULAR_OPEN: u32 = 9 * 60 + 30;
+/// 정규장 마감 (16:00 ET).
+const REGULAR_CLOSE: u32 = 16 * 60;
+/// 애프터마켓 마감 및 주간거래 시작 (20:00 ET).
+const AFTER_HOURS_CLOSE: u32 = 20 * 60;
+
+/// 미국 주식 거래 세션.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UsTradingSession {
+    /// 주간거래 (20:00-04:00 ET, KIS 주간 원장)
+    Daytime,
+    /// 프리마켓 (04:00-09:30 ET)
+    PreMarket,
+    /// 정규장 (09:30-16:00 ET)
+    Regular,
+    /// 애프터마켓 (16:00-20:00 ET)
+    AfterHours,
+    /// 거래 불가 (주말)
+    Closed,
+}
+
+impl UsTradingSession {
+    /// 정규장 외 거래 세션(주간거래, 프리마켓, 애프터마켓) 여부.
+    pub fn is_extended(&self) -> bool {
+        matches!(self, Self::Daytime | Self::PreMarket | Self::AfterHours)
+    }
+
+    /// 주문 가능 세션 여부.
+    pub fn is_open(&self) -> bool {
+        !matches!(self, Self::Closed)
+    }
+
+    /// KIS 주간 원장 세션 여부 (주간거래 전용 TR 사용).
+    pub fn is_kis_daytime(&self) -> bool {
+        matches!(self, Self::Daytime)
+    }
+
+    /// 세션이 속한 미국 거래일.
+    ///
+    /// 20:00 ET 이후 시작하는 주간거래는 다음 거래일 정규장의 사전 거래로 봅니다.
+    pub fn trading_date(&self, now: DateTime<Utc>) -> NaiveDate {
+        let local = now.with_timezone(&New_York);
+        let date = local.date_naive();
+        if *self == Self::Daytime
+            && minute_of_day(local.hour(), local.minute()) >= AFTER_HOURS_CLOSE
+        {
+            date.succ_opt().unwrap_or(date)
+        } else {
+            date
+        }
+    }
+}
+
+impl std::fmt::Display for UsTradingSession {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Daytime => write!(f, "Daytime"),
+            Self::PreMarket => write!(f, "Pre-Market"),
+            Self::Regular => write!(f, "Regular"),
+            Self::AfterHours => write!(f, "After-Hours"),
+            Self::Closed => write!(f, "Closed"),
+        }
+    }
+}
+
+fn minute_of_day(hour: u32, minute: u32) -> u32 {
+    hour * 60 + minute
+}
+
+/// 주어진 시각의 미국 주식 거래 세션 (휴장일 미반영).
+///
+/// 주간거래는 일~목요일 20:00 ET부터 다음 날 04:00 ET까지 열립니다.
+pub fn us_trading_session_at(now: DateTime<Utc>) -> UsTradingSession {
+    let local = now.with_timezone(&New_York);
+    let weekday = local.weekday();
+    let minute = minute_of_day(local.hour(), local.minute());
+    let is_weekday = !matches!(weekday, Weekday::Sat | Weekday::Sun);
+
+    if minute >= AFTER_HOURS_CLOSE {
+        // 일~목 저녁은 다음 거래일 주간거래
+        if matches!(weekday, Weekday::Fri | Weekday::Sat) {
+            UsTradingSession::Closed
+        } else {
+            UsTradingSession::Daytime
+        }
+    } else if !is_weekday {
+        UsTradingSession::Closed
+    } else if minute < PRE_MARKET_OPEN {
+        UsTradingSession::Daytime
+    } else if minute < REGULAR_OPEN {
+        UsTradingSession::PreMarket
+    } else if minute < REGULAR_CLOSE {
+        UsTradingSession::Regular
+    } else {
+        UsTradingSession::AfterHours
+    }
+}
+
+/// 현재 미국 주식 거래 세션 (휴장일 미반영).
+pub fn current_us_trading_session() -> UsTradingSession {
+    us_trading_session_at(Utc::now())
+}
+
+/// 정규 거래소 코드를 주간거래 거래소 코드로 변환.
+///
+/// 주간거래 시세 조회는 `BAQ`(나스닥), `BAY`(뉴욕), `BAA`(아멕스) 코드를 사용합니다.
+pub fn daytime_exchange_code(code: &str) -> &'static str {
+    match code {
+        exchange_code::NYSE | "NYSE" => exchange_code::DAYTIME_NYSE,
+        exchange_code::AMEX | "AMEX" => exchange_code::DAYTIME_AMEX,
+        _ => exchange_code::DAYTIME_NASDAQ,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn et(y: i32, m: u32, d: u32, h: u32, min: u32) -> DateTime<Utc> {
+        New_York
+            .with_ymd_and_hms(y, m, d, h, min, 0)
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    #[test]
+    fn test_session_boundaries() {
+        // 2026-03-10 화요일 (서머타임 적용 후)
+        assert_eq!(
+            us_trading_session_at(et(2026, 3, 10, 3, 59)),
+            UsTradingSession::Daytime
+        );
+        assert_eq!(
+            us_trading_session_at(et(2026, 3, 10, 4, 0)),
+            UsTradingSession::PreMarket
+        );
+        assert_eq!(
+            us_trading_session_at(et(2026, 3, 10, 9, 30)),
+            UsTradingSession::Regular
+        );
+        assert_eq!(
+            us_trading_session_at(et(2026, 3, 10, 16, 0)),
+            UsTradingSession::AfterHours
+        );
+        assert_eq!(
+            us_trading_session_at(et(2026, 3, 10, 20, 0)),
+            UsTradingSession::Daytime
+        );
+
+        // 서머타임 전후 모두 정규장 시작은 09:30 ET (UTC 14:30 / 13:30)
+        let winter = Utc.with_ymd_and_hms(2026, 1, 13, 14, 30, 0).unwrap();
+        let summer = Utc.with_ymd_and_hms(2026, 7, 14, 13, 30, 0).unwrap();
+        assert_eq!(us_trading_session_at(winter), UsTradingSession::Regular);
+        assert_eq!(us_trading_session_at(summer), UsTradingSession::Regular);
+    }
+
+    #[test]
+    fn test_weekend_sessions() {
+        // 금요일 20:00 이후와 토요일은 휴장, 일요일 20:00부터 주간거래
+        assert_eq!(
+            us_trading_session_at(et(2026, 3, 13, 21, 0)),
+            UsTradingSession::Closed
+        );
+        assert_eq!(
+            us_trading_session_at(et(2026, 3, 14, 12, 0)),
+            UsTradingSession::Closed
+        );
+        assert_eq!(
+            us_trading_session_at(et(2026, 3, 15, 19, 59)),
+            UsTradingSession::Closed
+        );
+        let sunday_evening = et(2026, 3, 15, 20, 0);
+        let session = us_trading_session_at(sunday_evening);
+        assert_eq!(session, UsTradingSession::Daytime);
+        assert_eq!(
+            session.trading_date(sunday_evening),
+            NaiveDate::from_ymd_opt(2026, 3, 16).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_daytime_exchange_code() {
+        assert_eq!(daytime_exchange_code(exchange_code::NASDAQ), "BAQ");
+        assert_eq!(daytime_exchange_code(exchange_code::NYSE), "BAY");
+        assert_eq!(daytime_exchange_code(exchange_code::AMEX), "BAA");
+        assert!(UsTradingSession::PreMarket.is_extended());
+        assert!(!UsTradingSession::Regular.is_extended());
+        assert!(!UsTradingSession::Closed.is_open());
+    }
+}
@@ -1,11 +1,15 @@
 //! KIS 국내 주식 실시간 시세 WebSocket 클라이언트.
 //!
-//! 한국투자증권 WebSocket API를 통해 국내 주식의 실시간 체결가와 호가를 수신합니다.
+//! 한국투자증권 WebSocket API를 통해 국내 주식의 실시간 체결가, 호가, 장운영정보를 수신합니다.
 //!
 //! # 지원 채널
 //!
 //! - `H0STCNT0`: 실시간 체결가
 //! - `H0STASP0`: 실시간 호가
+//! - `H0STMKO0`: 실시간 장운영정보 (거래정지, VI 발동/해제 시 전송)
+//! - `H0IFCNT0`: 지수선물 실시간 체결가 (KOSPI200 / 미니 KOSPI200)
+//! - `H0IFASP0`: 지수선물 실시간 호가 (1~5호가)
+//! - `H0STCNI0`/`H0STCNI9`: 실시간 체결통보 (실전/모의, HTS ID로 구독, AES 암호화)
 //!
 //! # 사용 예제
 //!
@@ -26,7 +30,11 @@
 //! ```
 
 use super::auth::KisOAuth;
+use super::config::KisEnvironment;
+use super::execution::{parse_kr_notice, ExecutionCipher, KisExecutionNotice};
+use super::reconnect::{default_reconnect_policy, ReconnectTracker};
 use super::tr_id;
+use crate::retry::RetryConfig;
 use crate::ExchangeError;
 use futures::{SinkExt, StreamExt};
 use rust_decimal::Decimal;
@@ -37,12 +45,7 @@ use tokio::sync::mpsc;
 use tokio::time::interval;
 use tokio_tungstenite::{connect_async, tungstenite::Message};
 use tracing::{debug, error, info, warn};
-
-/// 재연결 최대 시도 횟수.
-const MAX_RECONNECT_ATTEMPTS: u32 = 3;
-
-/// 재연결 대기 시간 (초).
-const RECONNECT_DELAY_SECS: u64 = 5;
+use trader_core::StreamStatus;
 
 /// Ping 간격 (초).
 const PING_INTERVAL_SECS: u64 = 30;
@@ -68,6 +71,37 @@ pub struct KrRealtimeTrade {
     pub change_rate: Decimal,
 }
 
+/// 지수선물 실시간 체결 데이터.
+#[derive(Debug, Clone)]
+pub struct KrRealtimeFuturesTrade {
+    /// 선물 단축코드
+    pub symbol: String,
+    /// 현재가 (포인트)
+    pub price: Decimal,
+    /// 시가
+    pub open: Decimal,
+    /// 고가
+    pub high: Decimal,
+    /// 저가
+    pub low: Decimal,
+    /// 최종 체결량 (계약)
+    pub volume: i64,
+    /// 누적거래량 (계약)
+    pub acc_volume: i64,
+    /// 체결시간 (HHMMSS)
+    pub trade_time: String,
+    /// 전일대비 부호 (1:상한, 2:상승, 3:보합, 4:하한, 5:하락)
+    pub sign: String,
+    /// 전일대비
+    pub change: Decimal,
+    /// 등락률
+    pub change_rate: Decimal,
+    /// 시장 베이시스 (선물 - KOSPI200)
+    pub market_basis: Decimal,
+    /// 미결제약정 수량
+    pub open_interest: i64,
+}
+
 /// 국내 주식 실시간 호가 데이터.
 #[derive(Debug, Clone)]
 pub struct KrRealtimeOrderbook {
@@ -85,6 +119,33 @@ pub struct KrRealtimeOrderbook {
     pub orderbook_time: String,
 }
 
+/// 국내 주식 실시간 장운영정보.
+///
+/// 거래정지나 VI(변동성 완화장치) 상태가 바뀔 때 전송됩니다.
+#[derive(Debug, Clone)]
+pub struct KrRealtimeMarketStatus {
+    /// 종목코드
+    pub symbol: String,
+    /// 거래정지 여부 (TRHT_YN)
+    pub trading_halted: bool,
+    /// 거래정지 사유 (TR_SUSP_REAS_CNTT)
+    pub halt_reason: String,
+    /// VI 적용 구분 (VI_CLS_CODE, N:해제, 1:정적, 2:동적, 3:정적+동적)
+    pub vi_code: String,
+}
+
+impl KrRealtimeMarketStatus {
+    /// VI 발동 중 여부.
+    pub fn is_vi_active(&self) -> bool {
+        matches!(self.vi_code.as_str(), "1" | "2" | "3")
+    }
+
+    /// 정적 VI 포함 여부 (정적+동적 동시 발동 포함).
+    pub fn is_static_vi(&self) -> bool {
+        matches!(self.vi_code.as_str(), "1" | "3")
+    }
+}
+
 /// 국내 실시간 메시지 타입.
 #[derive(Debug, Clone)]
 pub enum KrRealtimeMessage {
@@ -92,8 +153,16 @@ pub enum KrRealtimeMessage {
     Trade(KrRealtimeTrade),
     /// 호가
     Orderbook(KrRealtimeOrderbook),
+    /// 장운영정보 (거래정지, VI)
+    MarketStatus(KrRealtimeMarketStatus),
+    /// 지수선물 체결가 (선물 호가는 `Orderbook`으로 전달)
+    FuturesTrade(KrRealtimeFuturesTrade),
+    /// 주문 체결통보 (접수/정정/취소/거부 통보 포함)
+    Execution(KisExecutionNotice),
     /// 연결 상태 변경
     ConnectionStatus(bool),
+    /// 재연결 진행/복구/포기 (데이터 공백 구간 알림)
+    StreamStatus(StreamStatus),
     /// 에러
     Error(String),
 }
@@ -132,7 +201,15 @@ pub struct KisKrWebSocket {
     rx: Option<mpsc::Receiver<KrRealtimeMessage>>,
     subscribed_trades: Vec<String>,
     subscribed_orderbooks: Vec<String>,
+    subscribed_market_statuses: Vec<String>,
+    subscribed_futures_trades: Vec<String>,
+    subscribed_futures_orderbooks: Vec<String>,
+    /// 체결통보 구독 여부 (tr_key = HTS ID)
+    subscribed_executions: bool,
+    /// 체결통보 복호화 키 (연결마다 구독 응답으로 수신)
+    execution_cipher: Option<ExecutionCipher>,
     is_connected: Arc<tokio::sync::RwLock<bool>>,
+    reconnect: ReconnectTracker,
 }
 
 impl KisKrWebSocket {
@@ -145,10 +222,22 @@ impl KisKrWebSocket {
             rx: Some(rx),
             subscribed_trades: Vec::new(),
             subscribed_orderbooks: Vec::new(),
+            subscribed_market_statuses: Vec::new(),
+            subscribed_futures_trades: Vec::new(),
+            subscribed_futures_orderbooks: Vec::new(),
+            subscribed_executions: false,
+            execution_cipher: None,
             is_connected: Arc::new(tokio::sync::RwLock::new(false)),
+            reconnect: ReconnectTracker::new("kis_kr", default_reconnect_policy()),
         }
     }
 
+    /// 재연결 정책 설정 (기본: 1초부터 지수 백오프, 최대 60초, 10회).
+    pub fn with_reconnect_policy(mut self, policy: RetryConfig) -> Self {
+        self.reconnect.set_policy(policy);
+        self
+    }
+
     /// 메시지 수신 채널 가져오기.
     pub fn take_receiver(&mut self) -> Option<mpsc::Receiver<KrRealtimeMessage>> {
         self.rx.take()
@@ -161,47 +250,57 @@ impl KisKrWebSocket {
 
     /// WebSocket 연결 및 메시지 수신 시작.
     ///
+    /// 연결이 끊기면 지수 백오프로 재연결하고, 접속키를 재발급받아 기존 구독을 모두 복원합니다.
+    /// 재연결 진행/복구/포기는 `StreamStatus` 메시지로 알립니다.
     /// 이 메서드는 별도 태스크에서 실행해야 합니다.
     pub async fn connect(&mut self) -> Result<(), ExchangeError> {
-        let mut reconnect_attempts = 0;
-
         loop {
-            match self.connect_internal().await {
+            let e = match self.connect_internal().await {
                 Ok(_) => {
                     // 정상 종료
                     info!("KIS KR WebSocket 연결 종료");
-                    break;
+                    return Ok(());
                 }
-                Err(e) => {
-                    error!("KIS KR WebSocket 에러: {}", e);
-                    reconnect_attempts += 1;
-
-                    if reconnect_attempts > MAX_RECONNECT_ATTEMPTS {
-                        error!("최대 재연결 시도 횟수 초과 ({}회)", MAX_RECONNECT_ATTEMPTS);
-                        if let Some(tx) = &self.tx {
-                            let _ = tx
-                                .send(KrRealtimeMessage::Error(format!(
-                                    "최대 재연결 시도 횟수 초과: {}",
-                                    e
-                                )))
-                                .await;
-                        }
-                        return Err(e);
-                    }
+                Err(e) => e,
+            };
+            error!("KIS KR WebSocket 에러: {}", e);
 
+            match self.reconnect.on_disconnected(&e.to_string()) {
+                Ok((delay, status)) => {
                     warn!(
-                        "{}초 후 재연결 시도 ({}/{})",
-                        RECONNECT_DELAY_SECS, reconnect_attempts, MAX_RECONNECT_ATTEMPTS
+                        "{:.1}초 후 재연결 시도 ({}/{})",
+                        delay.as_secs_f64(),
+                        status.attempt,
+                        self.reconnect.max_attempts()
                     );
-                    tokio::time::sleep(Duration::from_secs(RECONNECT_DELAY_SECS)).await;
+                    self.send(KrRealtimeMessage::StreamStatus(status)).await;
+                    tokio::time::sleep(delay).await;
 
-                    // WebSocket 키 초기화 (재발급 필요)
+                    // WebSocket 키 초기화 (재인증)
                     self.oauth.clear_websocket_key().await;
                 }
+                Err(status) => {
+                    error!(
+                        "최대 재연결 시도 횟수 초과 ({}회)",
+                        self.reconnect.max_attempts()
+                    );
+                    self.send(KrRealtimeMessage::StreamStatus(status)).await;
+                    self.send(KrRealtimeMessage::Error(format!(
+                        "최대 재연결 시도 횟수 초과: {}",
+                        e
+                    )))
+                    .await;
+                    return Err(e);
+                }
             }
         }
+    }
 
-        Ok(())
+    /// 수신 채널로 메시지 전송.
+    async fn send(&self, message: KrRealtimeMessage) {
+        if let Some(tx) = &self.tx {
+            let _ = tx.send(message).await;
+        }
     }
 
     /// 내부 연결 로직.
@@ -231,9 +330,15 @@ impl KisKrWebSocket {
 
         info!("KIS KR WebSocket 연결 성공");
 
+        // 복호화 키는 구독 응답으로 다시 받음
+        self.execution_cipher = None;
+
         // 기존 구독 복원
         let trades = self.subscribed_trades.clone();
         let orderbooks = self.subscribed_orderbooks.clone();
+        let market_statuses = self.subscribed_market_statuses.clone();
+        let futures_trades = self.subscribed_futures_trades.clone();
+        let futures_orderbooks = self.subscribed_futures_orderbooks.clone();
 
         for symbol in &trades {
             let msg =
@@ -255,6 +360,74 @@ impl KisKrWebSocket {
             debug!("호가 구독 복원: {}", symbol);
         }
 
+        for symbol in &market_statuses {
+            let msg = self.create_subscribe_message(
+                &approval_key,
+                tr_id::WS_KR_MARKET_STATUS,
+                symbol,
+                true,
+            );
+            write
+                .send(Message::Text(msg))
+                .await
+                .map_err(|e| ExchangeError::NetworkError(e.to_string()))?;
+            debug!("장운영정보 구독 복원: {}", symbol);
+        }
+
+        for symbol in &futures_trades {
+            let msg = self.create_subscribe_message(
+                &approval_key,
+                tr_id::WS_KR_FUTURES_TRADE,
+                symbol,
+                true,
+            );
+            write
+                .send(Message::Text(msg))
+                .await
+                .map_err(|e| ExchangeError::NetworkError(e.to_string()))?;
+            debug!("선물 체결가 구독 복원: {}", symbol);
+        }
+
+        for symbol in &futures_orderbooks {
+            let msg = self.create_subscribe_message(
+                &approval_key,
+                tr_id::WS_KR_FUTURES_ORDERBOOK,
+                symbol,
+                true,
+            );
+            write
+                .send(Message::Text(msg))
+                .await
+                .map_err(|e| ExchangeError::NetworkError(e.to_string()))?;
+            debug!("선물 호가 구독 복원: {}", symbol);
+        }
+
+        let execution_key = self.execution_tr_key();
+        if let Some(hts_id) = &execution_key {
+            let msg =
+                self.create_subscribe_message(&approval_key, self.execution_tr_id(), hts_id, true);
+            write
+                .send(Message::Text(msg))
+                .await
+                .map_err(|e| ExchangeError::NetworkError(e.to_string()))?;
+            debug!("체결통보 구독 복원");
+        }
+
+        // 끊김 이후 복구라면 공백 구간 종료 알림
+        let resubscribed = trades.len()
+            + orderbooks.len()
+            + market_statuses.len()
+            + futures_trades.len()
+            + futures_orderbooks.len()
+            + usize::from(execution_key.is_some());
+        if let Some(status) = self.reconnect.on_connected(resubscribed) {
+            info!(
+                attempt = status.attempt,
+                resubscribed, "KIS KR WebSocket 재연결 및 구독 복원 완료"
+            );
+            self.send(KrRealtimeMessage::StreamStatus(status)).await;
+        }
+
         // Ping 타이머
         let mut ping_interval = interval(Duration::from_secs(PING_INTERVAL_SECS));
 
@@ -336,15 +509,39 @@ impl KisKrWebSocket {
         serde_json::to_string(&request).unwrap_or_default()
     }
 
+    /// 체결통보 tr_id (실전/모의).
+    fn execution_tr_id(&self) -> &'static str {
+        match self.oauth.config().environment {
+            KisEnvironment::Real => tr_id::WS_KR_EXECUTION_REAL,
+            KisEnvironment::Paper => tr_id::WS_KR_EXECUTION_PAPER,
+        }
+    }
+
+    /// 체결통보 구독 tr_key (구독 중이고 HTS ID가 있을 때).
+    fn execution_tr_key(&self) -> Option<String> {
+        if !self.subscribed_executions {
+            return None;
+        }
+        self.oauth.config().hts_id.clone()
+    }
+
     /// 수신 메시지 처리.
-    async fn handle_message(&self, text: &str) {
+    async fn handle_message(&mut self, text: &str) {
         // KIS WebSocket 메시지는 | 구분자로 분리됨
         // 형식: 0|H0STCNT0|001|005930^...
         let parts: Vec<&str> = text.split('|').collect();
 
         if parts.len() < 4 {
-            // JSON 응답 (구독 확인 등)
-            debug!("JSON 응답: {}", text);
+            // JSON 응답 (구독 확인 등). 체결통보 구독 응답에는 복호화 키가 포함됨
+            if let Some(cipher) = ExecutionCipher::from_subscribe_response(
+                text,
+                &[tr_id::WS_KR_EXECUTION_REAL, tr_id::WS_KR_EXECUTION_PAPER],
+            ) {
+                info!("KIS KR 체결통보 구독 완료");
+                self.execution_cipher = Some(cipher);
+            } else {
+                debug!("JSON 응답: {}", text);
+            }
             return;
         }
 
@@ -368,12 +565,61 @@ impl KisKrWebSocket {
                     }
                 }
             }
+            "H0STMKO0" => {
+                // 실시간 장운영정보
+                if let Some(status) = self.parse_market_status_data(data) {
+                    if let Some(tx) = &self.tx {
+                        let _ = tx.send(KrRealtimeMessage::MarketStatus(status)).await;
+                    }
+                }
+            }
+            "H0IFCNT0" => {
+                // 지수선물 실시간 체결
+                if let Some(trade) = self.parse_futures_trade_data(data) {
+                    if let Some(tx) = &self.tx {
+                        let _ = tx.send(KrRealtimeMessage::FuturesTrade(trade)).await;
+                    }
+                }
+            }
+            "H0IFASP0" => {
+                // 지수선물 실시간 호가
+                if let Some(orderbook) = self.parse_futures_orderbook_data(data) {
+                    if let Some(tx) = &self.tx {
+                        let _ = tx.send(KrRealtimeMessage::Orderbook(orderbook)).await;
+                    }
+                }
+            }
+            tr_id::WS_KR_EXECUTION_REAL | tr_id::WS_KR_EXECUTION_PAPER => {
+                // 실시간 체결통보 (암호화 여부는 첫 필드로 구분)
+                if let Some(notice) = self.parse_execution_data(parts[0] == "1", data) {
+                    self.send(KrRealtimeMessage::Execution(notice)).await;
+                }
+            }
             _ => {
                 debug!("알 수 없는 tr_id: {}", tr_id);
             }
         }
     }
 
+    /// 체결통보 복호화 및 파싱.
+    fn parse_execution_data(&self, encrypted: bool, data: &str) -> Option<KisExecutionNotice> {
+        if !encrypted {
+            return parse_kr_notice(data);
+        }
+
+        let Some(cipher) = &self.execution_cipher else {
+            warn!("체결통보 복호화 키 없음 (구독 응답 미수신)");
+            return None;
+        };
+        match cipher.decrypt(data) {
+            Ok(plain) => parse_kr_notice(&plain),
+            Err(e) => {
+                warn!("체결통보 복호화 실패: {}", e);
+                None
+            }
+        }
+    }
+
     /// 체결 데이터 파싱.
     ///
     /// 데이터 형식: 종목코드^체결시간^체결가^...
@@ -440,6 +686,91 @@ impl KisKrWebSocket {
         })
     }
 
+    /// 지수선물 체결 데이터 파싱.
+    ///
+    /// 데이터 형식: 선물단축코드^영업시간^전일대비^전일대비부호^전일대비율^현재가^
+    /// 시가^고가^저가^최종거래량^누적거래량^누적거래대금^이론가^시장베이시스^괴리율^
+    /// 근월물약정가^원월물약정가^스프레드^미결제약정수량^...
+    fn parse_futures_trade_data(&self, data: &str) -> Option<KrRealtimeFuturesTrade> {
+        let fields: Vec<&str> = data.split('^').collect();
+
+        if fields.len() < 19 {
+            warn!("선물 체결 데이터 필드 부족: {}", fields.len());
+            return None;
+        }
+
+        Some(KrRealtimeFuturesTrade {
+            symbol: fields[0].to_string(),
+            trade_time: fields[1].to_string(),
+            change: fields[2].parse().unwrap_or(Decimal::ZERO),
+            sign: fields[3].to_string(),
+            change_rate: fields[4].parse().unwrap_or(Decimal::ZERO),
+            price: fields[5].parse().unwrap_or(Decimal::ZERO),
+            open: fields[6].parse().unwrap_or(Decimal::ZERO),
+            high: fields[7].parse().unwrap_or(Decimal::ZERO),
+            low: fields[8].parse().unwrap_or(Decimal::ZERO),
+            volume: fields[9].parse().unwrap_or(0),
+            acc_volume: fields[10].parse().unwrap_or(0),
+            market_basis: fields[13].parse().unwrap_or(Decimal::ZERO),
+            open_interest: fields[18].parse().unwrap_or(0),
+        })
+    }
+
+    /// 지수선물 호가 데이터 파싱.
+    ///
+    /// 데이터 형식: 선물단축코드^영업시간^매도호가1~5^매수호가1~5^매도호가건수1~5^
+    /// 매수호가건수1~5^매도호가잔량1~5^매수호가잔량1~5^...
+    fn parse_futures_orderbook_data(&self, data: &str) -> Option<KrRealtimeOrderbook> {
+        let fields: Vec<&str> = data.split('^').collect();
+
+        if fields.len() < 32 {
+            warn!("선물 호가 데이터 필드 부족: {}", fields.len());
+            return None;
+        }
+
+        let prices = |start: usize| -> Vec<Decimal> {
+            fields[start..start + 5]
+                .iter()
+                .map(|f| f.parse().unwrap_or(Decimal::ZERO))
+                .collect()
+        };
+        let volumes = |start: usize| -> Vec<i64> {
+            fields[start..start + 5]
+                .iter()
+                .map(|f| f.parse().unwrap_or(0))
+                .collect()
+        };
+
+        Some(KrRealtimeOrderbook {
+            symbol: fields[0].to_string(),
+            orderbook_time: fields[1].to_string(),
+            ask_prices: prices(2),
+            bid_prices: prices(7),
+            ask_volumes: volumes(22),
+            bid_volumes: volumes(27),
+        })
+    }
+
+    /// 장운영정보 데이터 파싱.
+    ///
+    /// 데이터 형식: 종목코드^거래정지여부^거래정지사유^장운영구분^예상장운영구분^
+    /// 임의연장구분^동시호가배분처리구분^종목상태구분^VI적용구분^...
+    fn parse_market_status_data(&self, data: &str) -> Option<KrRealtimeMarketStatus> {
+        let fields: Vec<&str> = data.split('^').collect();
+
+        if fields.len() < 9 {
+            warn!("장운영정보 데이터 필드 부족: {}", fields.len());
+            return None;
+        }
+
+        Some(KrRealtimeMarketStatus {
+            symbol: fields[0].to_string(),
+            trading_halted: fields[1] == "Y",
+            halt_reason: fields[2].trim().to_string(),
+            vi_code: fields[8].trim().to_string(),
+        })
+    }
+
     /// 실시간 체결가 구독 추가.
     pub fn add_trade_subscription(&mut self, symbol: &str) {
         if !self.subscribed_trades.contains(&symbol.to_string()) {
@@ -454,6 +785,46 @@ impl KisKrWebSocket {
         }
     }
 
+    /// 실시간 장운영정보 구독 추가.
+    pub fn add_market_status_subscription(&mut self, symbol: &str) {
+        if !self
+            .subscribed_market_statuses
+            .contains(&symbol.to_string())
+        {
+            self.subscribed_market_statuses.push(symbol.to_string());
+        }
+    }
+
+    /// 지수선물 실시간 체결가 구독 추가.
+    pub fn add_futures_trade_subscription(&mut self, symbol: &str) {
+        if !self.subscribed_futures_trades.contains(&symbol.to_string()) {
+            self.subscribed_futures_trades.push(symbol.to_string());
+        }
+    }
+
+    /// 지수선물 실시간 호가 구독 추가.
+    pub fn add_futures_orderbook_subscription(&mut self, symbol: &str) {
+        if !self
+            .subscribed_futures_orderbooks
+            .contains(&symbol.to_string())
+        {
+            self.subscribed_futures_orderbooks.push(symbol.to_string());
+        }
+    }
+
+    /// 실시간 체결통보 구독 추가.
+    ///
+    /// 계좌 단위 구독이므로 `KisConfig::hts_id`가 설정되어 있어야 합니다.
+    pub fn add_execution_subscription(&mut self) -> Result<(), ExchangeError> {
+        if self.oauth.config().hts_id.is_none() {
+            return Err(ExchangeError::NotSupported(
+                "체결통보 구독에는 HTS ID(KIS_HTS_ID)가 필요합니다".to_string(),
+            ));
+        }
+        self.subscribed_executions = true;
+        Ok(())
+    }
+
     /// 체결가 구독 제거.
     pub fn remove_trade_subscription(&mut self, symbol: &str) {
         self.subscribed_trades.retain(|s| s != symbol);
@@ -463,6 +834,26 @@ impl KisKrWebSocket {
     pub fn remove_orderbook_subscription(&mut self, symbol: &str) {
         self.subscribed_orderbooks.retain(|s| s != symbol);
     }
+
+    /// 장운영정보 구독 제거.
+    pub fn remove_market_status_subscription(&mut self, symbol: &str) {
+        self.subscribed_market_statuses.retain(|s| s != symbol);
+    }
+
+    /// 지수선물 체결가 구독 제거.
+    pub fn remove_futures_trade_subscription(&mut self, symbol: &str) {
+        self.subscribed_futures_trades.retain(|s| s != symbol);
+    }
+
+    /// 지수선물 호가 구독 제거.
+    pub fn remove_futures_orderbook_subscription(&mut self, symbol: &str) {
+        self.subscribed_futures_orderbooks.retain(|s| s != symbol);
+    }
+
+    /// 체결통보 구독 제거.
+    pub fn remove_execution_subscription(&mut self) {
+        self.subscribed_executions = false;
+    }
 }
 
 #[cfg(test)]
@@ -486,6 +877,115 @@ mod tests {
         assert_eq!(trade.price, Decimal::new(70000, 0));
     }
 
+    #[test]
+    fn test_parse_market_status_data() {
+        let oauth = create_mock_oauth();
+        let ws = KisKrWebSocket::new(oauth);
+
+        // 동적 VI 발동
+        let status = ws
+            .parse_market_status_data("005930^N^^20^^^^57^2^N^1")
+            .unwrap();
+        assert_eq!(status.symbol, "005930");
+        assert!(!status.trading_halted);
+        assert!(status.is_vi_active());
+        assert!(!status.is_static_vi());
+
+        // 거래정지
+        let status = ws
+            .parse_market_status_data("035720^Y^불성실공시^20^^^^58^N^N^1")
+            .unwrap();
+        assert!(status.trading_halted);
+        assert_eq!(status.halt_reason, "불성실공시");
+        assert!(!status.is_vi_active());
+
+        assert!(ws.parse_market_status_data("005930^N").is_none());
+    }
+
+    #[test]
+    fn test_parse_futures_trade_data() {
+        let oauth = create_mock_oauth();
+        let ws = KisKrWebSocket::new(oauth);
+
+        let data = "101W9000^091530^2.15^2^0.60^362.50^360.00^363.10^359.85^3^81234^\
+                    7345123^362.41^1.32^0.02^0^0^0^285120^-412";
+        let trade = ws.parse_futures_trade_data(data).unwrap();
+        assert_eq!(trade.symbol, "101W9000");
+        assert_eq!(trade.price, Decimal::new(36250, 2));
+        assert_eq!(trade.high, Decimal::new(36310, 2));
+        assert_eq!(trade.volume, 3);
+        assert_eq!(trade.acc_volume, 81234);
+        assert_eq!(trade.market_basis, Decimal::new(132, 2));
+        assert_eq!(trade.open_interest, 285120);
+
+        assert!(ws
+            .parse_futures_trade_data("101W9000^091530^2.15")
+            .is_none());
+    }
+
+    #[test]
+    fn test_parse_futures_orderbook_data() {
+        let oauth = create_mock_oauth();
+        let ws = KisKrWebSocket::new(oauth);
+
+        let data = "101W9000^091530^\
+                    362.55^362.60^362.65^362.70^362.75^\
+                    362.50^362.45^362.40^362.35^362.30^\
+                    10^11^12^13^14^20^21^22^23^24^\
+                    31^32^33^34^35^41^42^43^44^45^500^600";
+        let orderbook = ws.parse_futures_orderbook_data(data).unwrap();
+        assert_eq!(orderbook.ask_prices.len(), 5);
+        assert_eq!(orderbook.ask_prices[0], Decimal::new(36255, 2));
+        assert_eq!(orderbook.bid_prices[0], Decimal::new(36250, 2));
+        assert_eq!(orderbook.ask_volumes, vec![31, 32, 33, 34, 35]);
+        assert_eq!(orderbook.bid_volumes[4], 45);
+    }
+
+    #[test]
+    fn test_execution_subscription_requires_hts_id() {
+        let mut ws = KisKrWebSocket::new(create_mock_oauth());
+        assert!(ws.add_execution_subscription().is_err());
+        assert!(ws.execution_tr_key().is_none());
+
+        let oauth = {
+            use super::super::config::{KisAccountType, KisConfig};
+            let config = KisConfig::new(
+                "test_app_key".to_string(),
+                "test_app_secret".to_string(),
+                "12345678-01".to_string(),
+                KisAccountType::Paper,
+            )
+            .with_hts_id("myhtsid".to_string());
+            KisOAuth::new(config).unwrap()
+        };
+        let mut ws = KisKrWebSocket::new(oauth);
+        ws.add_execution_subscription().unwrap();
+        assert_eq!(ws.execution_tr_id(), "H0STCNI9");
+        assert_eq!(ws.execution_tr_key().as_deref(), Some("myhtsid"));
+    }
+
+    #[tokio::test]
+    async fn test_handle_execution_message() {
+        let mut ws = KisKrWebSocket::new(create_mock_oauth());
+        let mut rx = ws.take_receiver().unwrap();
+
+        // 평문 체결통보 (모의투자 일부 응답)
+        let data = "myhtsid^5012345601^0000012345^^01^0^00^0^005930^5^70000^093015^0^2^2^\
+                    00950^5";
+        ws.handle_message(&format!("0|H0STCNI9|001|{}", data)).await;
+        match rx.try_recv().unwrap() {
+            KrRealtimeMessage::Execution(notice) => {
+                assert_eq!(notice.order_no, "0000012345");
+                assert!(notice.is_fill());
+            }
+            other => panic!("unexpected message: {:?}", other),
+        }
+
+        // 복호화 키 수신 전 암호문은 무시
+        ws.handle_message("1|H0STCNI9|001|AAAA").await;
+        assert!(rx.try_recv().is_err());
+    }
+
     #[test]
     fn test_subscribe_message_format() {
         let oauth = create_mock_oauth();
@@ -6,6 +6,7 @@
 //!
 //! - `HDFSCNT0`: 해외 실시간 체결
 //! - `HDFSASP0`: 해외 실시간 호가
+//! - `H0GSCNI0`/`H0GSCNI9`: 해외 실시간 체결통보 (실전/모의, HTS ID로 구독, AES 암호화)
 //!
 //! # 거래소 코드
 //!
@@ -30,7 +31,11 @@
 //! ```
 
 use super::auth::KisOAuth;
+use super::config::KisEnvironment;
+use super::execution::{parse_us_notice, ExecutionCipher, KisExecutionNotice};
+use super::reconnect::{default_reconnect_policy, ReconnectTracker};
 use super::tr_id;
+use crate::retry::RetryConfig;
 use crate::ExchangeError;
 use futures::{SinkExt, StreamExt};
 use rust_decimal::Decimal;
@@ -40,12 +45,7 @@ use std::time::Duration;
 use tokio::sync::mpsc;
 use tokio_tungstenite::{connect_async, tungstenite::Message};
 use tracing::{debug, error, info, warn};
-
-/// 재연결 최대 시도 횟수.
-const MAX_RECONNECT_ATTEMPTS: u32 = 3;
-
-/// 재연결 대기 시간 (초).
-const RECONNECT_DELAY_SECS: u64 = 5;
+use trader_core::StreamStatus;
 
 /// Ping 간격 (초).
 const PING_INTERVAL_SECS: u64 = 30;
@@ -97,8 +97,12 @@ pub enum UsRealtimeMessage {
     Trade(UsRealtimeTrade),
     /// 호가
     Orderbook(UsRealtimeOrderbook),
+    /// 주문 체결통보 (접수/정정/취소/거부 통보 포함)
+    Execution(KisExecutionNotice),
     /// 연결 상태 변경
     ConnectionStatus(bool),
+    /// 재연결 진행/복구/포기 (데이터 공백 구간 알림)
+    StreamStatus(StreamStatus),
     /// 에러
     Error(String),
 }
@@ -144,7 +148,12 @@ pub struct KisUsWebSocket {
     rx: Option<mpsc::Receiver<UsRealtimeMessage>>,
     subscribed_trades: Vec<SubscriptionInfo>,
     subscribed_orderbooks: Vec<SubscriptionInfo>,
+    /// 체결통보 구독 여부 (tr_key = HTS ID)
+    subscribed_executions: bool,
+    /// 체결통보 복호화 키 (연결마다 구독 응답으로 수신)
+    execution_cipher: Option<ExecutionCipher>,
     is_connected: Arc<tokio::sync::RwLock<bool>>,
+    reconnect: ReconnectTracker,
 }
 
 impl KisUsWebSocket {
@@ -157,10 +166,19 @@ impl KisUsWebSocket {
             rx: Some(rx),
             subscribed_trades: Vec::new(),
             subscribed_orderbooks: Vec::new(),
+            subscribed_executions: false,
+            execution_cipher: None,
             is_connected: Arc::new(tokio::sync::RwLock::new(false)),
+            reconnect: ReconnectTracker::new("kis_us", default_reconnect_policy()),
         }
     }
 
+    /// 재연결 정책 설정 (기본: 1초부터 지수 백오프, 최대 60초, 10회).
+    pub fn with_reconnect_policy(mut self, policy: RetryConfig) -> Self {
+        self.reconnect.set_policy(policy);
+        self
+    }
+
     /// 메시지 수신 채널 가져오기.
     pub fn take_receiver(&mut self) -> Option<mpsc::Receiver<UsRealtimeMessage>> {
         self.rx.take()
@@ -179,45 +197,56 @@ impl KisUsWebSocket {
     }
 
     /// WebSocket 연결 및 메시지 수신 시작.
+    ///
+    /// 연결이 끊기면 지수 백오프로 재연결하고, 접속키를 재발급받아 기존 구독을 모두 복원합니다.
+    /// 재연결 진행/복구/포기는 `StreamStatus` 메시지로 알립니다.
     pub async fn connect(&mut self) -> Result<(), ExchangeError> {
-        let mut reconnect_attempts = 0;
-
         loop {
-            match self.connect_internal().await {
+            let e = match self.connect_internal().await {
                 Ok(_) => {
                     info!("KIS US WebSocket 연결 종료");
-                    break;
+                    return Ok(());
                 }
-                Err(e) => {
-                    error!("KIS US WebSocket 에러: {}", e);
-                    reconnect_attempts += 1;
-
-                    if reconnect_attempts > MAX_RECONNECT_ATTEMPTS {
-                        error!("최대 재연결 시도 횟수 초과 ({}회)", MAX_RECONNECT_ATTEMPTS);
-                        if let Some(tx) = &self.tx {
-                            let _ = tx
-                                .send(UsRealtimeMessage::Error(format!(
-                                    "최대 재연결 시도 횟수 초과: {}",
-                                    e
-                                )))
-                                .await;
-                        }
-                        return Err(e);
-                    }
+                Err(e) => e,
+            };
+            error!("KIS US WebSocket 에러: {}", e);
 
+            match self.reconnect.on_disconnected(&e.to_string()) {
+                Ok((delay, status)) => {
                     warn!(
-                        "{}초 후 재연결 시도 ({}/{})",
-                        RECONNECT_DELAY_SECS, reconnect_attempts, MAX_RECONNECT_ATTEMPTS
+                        "{:.1}초 후 재연결 시도 ({}/{})",
+                        delay.as_secs_f64(),
+                        status.attempt,
+                        self.reconnect.max_attempts()
                     );
-                    tokio::time::sleep(Duration::from_secs(RECONNECT_DELAY_SECS)).await;
+                    self.send(UsRealtimeMessage::StreamStatus(status)).await;
+                    tokio::time::sleep(delay).await;
 
-                    // WebSocket 키 초기화
+                    // WebSocket 키 초기화 (재인증)
                     self.oauth.clear_websocket_key().await;
                 }
+                Err(status) => {
+                    error!(
+                        "최대 재연결 시도 횟수 초과 ({}회)",
+                        self.reconnect.max_attempts()
+                    );
+                    self.send(UsRealtimeMessage::StreamStatus(status)).await;
+                    self.send(UsRealtimeMessage::Error(format!(
+                        "최대 재연결 시도 횟수 초과: {}",
+                        e
+                    )))
+                    .await;
+                    return Err(e);
+                }
             }
         }
+    }
 
-        Ok(())
+    /// 수신 채널로 메시지 전송.
+    async fn send(&self, message: UsRealtimeMessage) {
+        if let Some(tx) = &self.tx {
+            let _ = tx.send(message).await;
+        }
     }
 
     /// 내부 연결 로직.
@@ -247,6 +276,9 @@ impl KisUsWebSocket {
 
         info!("KIS US WebSocket 연결 성공");
 
+        // 복호화 키는 구독 응답으로 다시 받음
+        self.execution_cipher = None;
+
         // 기존 구독 복원
         let trades = self.subscribed_trades.clone();
         let orderbooks = self.subscribed_orderbooks.clone();
@@ -279,6 +311,28 @@ impl KisUsWebSocket {
             );
         }
 
+        let execution_key = self.execution_tr_key();
+        if let Some(hts_id) = &execution_key {
+            let msg =
+                self.create_subscribe_message(&approval_key, self.execution_tr_id(), hts_id, true);
+            write
+                .send(Message::Text(msg))
+                .await
+                .map_err(|e| ExchangeError::NetworkError(e.to_string()))?;
+            debug!("해외 체결통보 구독 복원");
+        }
+
+        // 끊김 이후 복구라면 공백 구간 종료 알림
+        let resubscribed = trades.len() + orderbooks.len() + usize::from(execution_key.is_some());
+        if let Some(status) = self.reconnect.on_connected(resubscribed) {
+            info!(
+                attempt = status.attempt,
+                resubscribed = status.resubscribed,
+                "KIS US WebSocket 재연결 및 구독 복원 완료"
+            );
+            self.send(UsRealtimeMessage::StreamStatus(status)).await;
+        }
+
         // Ping 타이머
         let mut ping_interval = tokio::time::interval(Duration::from_secs(PING_INTERVAL_SECS));
 
@@ -358,13 +412,38 @@ impl KisUsWebSocket {
         serde_json::to_string(&request).unwrap_or_default()
     }
 
+    /// 체결통보 tr_id (실전/모의).
+    fn execution_tr_id(&self) -> &'static str {
+        match self.oauth.config().environment {
+            KisEnvironment::Real => tr_id::WS_US_EXECUTION_REAL,
+            KisEnvironment::Paper => tr_id::WS_US_EXECUTION_PAPER,
+        }
+    }
+
+    /// 체결통보 구독 tr_key (구독 중이고 HTS ID가 있을 때).
+    fn execution_tr_key(&self) -> Option<String> {
+        if !self.subscribed_executions {
+            return None;
+        }
+        self.oauth.config().hts_id.clone()
+    }
+
     /// 수신 메시지 처리.
-    async fn handle_message(&self, text: &str) {
+    async fn handle_message(&mut self, text: &str) {
         // KIS WebSocket 메시지 형식: 0|HDFSCNT0|001|DNASAAPL^...
         let parts: Vec<&str> = text.split('|').collect();
 
         if parts.len() < 4 {
-            debug!("JSON 응답: {}", text);
+            // 체결통보 구독 응답에는 복호화 키가 포함됨
+            if let Some(cipher) = ExecutionCipher::from_subscribe_response(
+                text,
+                &[tr_id::WS_US_EXECUTION_REAL, tr_id::WS_US_EXECUTION_PAPER],
+            ) {
+                info!("KIS US 체결통보 구독 완료");
+                self.execution_cipher = Some(cipher);
+            } else {
+                debug!("JSON 응답: {}", text);
+            }
             return;
         }
 
@@ -386,12 +465,36 @@ impl KisUsWebSocket {
                     }
                 }
             }
+            tr_id::WS_US_EXECUTION_REAL | tr_id::WS_US_EXECUTION_PAPER => {
+                if let Some(notice) = self.parse_execution_data(parts[0] == "1", data) {
+                    self.send(UsRealtimeMessage::Execution(notice)).await;
+                }
+            }
             _ => {
                 debug!("알 수 없는 tr_id: {}", tr_id);
             }
         }
     }
 
+    /// 체결통보 복호화 및 파싱.
+    fn parse_execution_data(&self, encrypted: bool, data: &str) -> Option<KisExecutionNotice> {
+        if !encrypted {
+            return parse_us_notice(data);
+        }
+
+        let Some(cipher) = &self.execution_cipher else {
+            warn!("해외 체결통보 복호화 키 없음 (구독 응답 미수신)");
+            return None;
+        };
+        match cipher.decrypt(data) {
+            Ok(plain) => parse_us_notice(&plain),
+            Err(e) => {
+                warn!("해외 체결통보 복호화 실패: {}", e);
+                None
+            }
+        }
+    }
+
     /// 체결 데이터 파싱.
     ///
     /// 해외 실시간 체결 데이터 필드 (KIS API 문서 참조)
@@ -491,6 +594,19 @@ impl KisUsWebSocket {
         }
     }
 
+    /// 해외 실시간 체결통보 구독 추가.
+    ///
+    /// 계좌 단위 구독이므로 `KisConfig::hts_id`가 설정되어 있어야 합니다.
+    pub fn add_execution_subscription(&mut self) -> Result<(), ExchangeError> {
+        if self.oauth.config().hts_id.is_none() {
+            return Err(ExchangeError::NotSupported(
+                "체결통보 구독에는 HTS ID(KIS_HTS_ID)가 필요합니다".to_string(),
+            ));
+        }
+        self.subscribed_executions = true;
+        Ok(())
+    }
+
     /// 체결가 구독 제거.
     pub fn remove_trade_subscription(&mut self, symbol: &str, exchange_code: &str) {
         self.subscribed_trades
@@ -502,6 +618,11 @@ impl KisUsWebSocket {
         self.subscribed_orderbooks
             .retain(|s| !(s.symbol == symbol && s.exchange_code == exchange_code));
     }
+
+    /// 체결통보 구독 제거.
+    pub fn remove_execution_subscription(&mut self) {
+        self.subscribed_executions = false;
+    }
 }
 
 #[cfg(test)]
@@ -2,9 +2,11 @@
 
 pub mod binance;
 pub mod kis;
+pub mod upbit;
 
 pub use binance::*;
 pub use kis::{
     KisConfig, KisEnvironment, KisKrClient, KisOAuth, KrBalance, KrBuyPower, KrHolding,
     KrOrderBook, KrOrderResponse, StockPrice,
 };
+pub use upbit::{UpbitClient, UpbitConfig, UpbitMarketStream};
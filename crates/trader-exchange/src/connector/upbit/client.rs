@@ -0,0 +1,915 @@
+//! Upbit REST 클라이언트.
+//!
+//! 원화(KRW) 마켓의 시세 조회와 주문(제출, 취소, 조회), 잔고 조회를 `Exchange` trait로 제공합니다.
+//! 인증이 필요한 요청은 Access Key와 요청 파라미터 해시(SHA512)를 담은 JWT(HS256)로 서명합니다.
+
+#![allow(dead_code)] // API 응답 필드 전체 매핑 (일부만 사용)
+
+use super::config::UpbitConfig;
+use crate::traits::{AccountInfo, Balance, Exchange, ExchangeResult};
+use crate::ExchangeError;
+use async_trait::async_trait;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use chrono::{DateTime, NaiveDateTime, Utc};
+use hmac::{Hmac, Mac};
+use reqwest::{Client, Method};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256, Sha512};
+use std::sync::Arc;
+use tracing::{debug, error, info, warn};
+use trader_core::{
+    Kline, OrderBook, OrderBookLevel, OrderRequest, OrderStatus, OrderStatusType, OrderType,
+    Position, RoundMethod, Side, TickSizeProvider, Ticker, TimeInForce, Timeframe, TradeTick,
+    UpbitKrwTickSize,
+};
+use uuid::Uuid;
+
+type HmacSha256 = Hmac<Sha256>;
+
+// ============================================================================
+// API 응답 타입
+// ============================================================================
+
+#[derive(Debug, Deserialize)]
+struct UpbitAccount {
+    currency: String,
+    balance: Decimal,
+    locked: Decimal,
+    avg_buy_price: Decimal,
+    unit_currency: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct UpbitTicker {
+    market: String,
+    trade_price: Decimal,
+    high_price: Decimal,
+    low_price: Decimal,
+    signed_change_price: Decimal,
+    signed_change_rate: Decimal,
+    acc_trade_volume_24h: Decimal,
+    timestamp: i64,
+}
+
+#[derive(Debug, Deserialize)]
+pub(super) struct UpbitOrderBookUnit {
+    pub ask_price: Decimal,
+    pub bid_price: Decimal,
+    pub ask_size: Decimal,
+    pub bid_size: Decimal,
+}
+
+#[derive(Debug, Deserialize)]
+struct UpbitOrderBook {
+    market: String,
+    timestamp: i64,
+    orderbook_units: Vec<UpbitOrderBookUnit>,
+}
+
+#[derive(Debug, Deserialize)]
+struct UpbitTrade {
+    timestamp: i64,
+    trade_price: Decimal,
+    trade_volume: Decimal,
+    ask_bid: String,
+    sequential_id: i64,
+}
+
+#[derive(Debug, Deserialize)]
+struct UpbitCandle {
+    market: String,
+    /// 캔들 시작 시각 (UTC, 타임존 표기 없음)
+    candle_date_time_utc: String,
+    opening_price: Decimal,
+    high_price: Decimal,
+    low_price: Decimal,
+    trade_price: Decimal,
+    candle_acc_trade_price: Decimal,
+    candle_acc_trade_volume: Decimal,
+}
+
+#[derive(Debug, Deserialize)]
+struct UpbitOrderTrade {
+    price: Decimal,
+    volume: Decimal,
+    funds: Decimal,
+}
+
+#[derive(Debug, Deserialize)]
+struct UpbitOrder {
+    uuid: String,
+    side: String,
+    ord_type: String,
+    /// 지정가 주문은 주문 가격, 시장가 매수(`price`)는 주문 총액
+    price: Option<Decimal>,
+    state: String,
+    market: String,
+    created_at: String,
+    /// 시장가 매수는 수량 없음
+    volume: Option<Decimal>,
+    remaining_volume: Option<Decimal>,
+    executed_volume: Decimal,
+    #[serde(default)]
+    identifier: Option<String>,
+    /// 개별 주문 조회에서만 포함
+    #[serde(default)]
+    trades: Vec<UpbitOrderTrade>,
+}
+
+#[derive(Debug, Deserialize)]
+struct UpbitErrorBody {
+    error: UpbitErrorDetail,
+}
+
+#[derive(Debug, Deserialize)]
+struct UpbitErrorDetail {
+    name: String,
+    message: String,
+}
+
+/// JWT 페이로드.
+#[derive(Debug, Serialize)]
+struct UpbitJwtClaims<'a> {
+    access_key: &'a str,
+    nonce: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    query_hash: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    query_hash_alg: Option<&'static str>,
+}
+
+// ============================================================================
+// Upbit 클라이언트
+// ============================================================================
+
+/// Upbit 거래소 클라이언트.
+pub struct UpbitClient {
+    config: UpbitConfig,
+    client: Client,
+    connected: bool,
+    /// 호가 단위 제공자 (기본: 원화 마켓 호가 단위)
+    tick_size_provider: Arc<dyn TickSizeProvider>,
+}
+
+impl UpbitClient {
+    /// 새 Upbit 클라이언트 생성.
+    ///
+    /// # Errors
+    /// HTTP 클라이언트 생성에 실패하면 `ExchangeError::NetworkError`를 반환합니다.
+    pub fn new(config: UpbitConfig) -> Result<Self, ExchangeError> {
+        let client = Client::builder()
+            .timeout(std::time::Duration::from_secs(config.timeout_secs))
+            .build()
+            .map_err(|e| {
+                ExchangeError::NetworkError(format!("HTTP 클라이언트 생성 실패: {}", e))
+            })?;
+
+        Ok(Self {
+            config,
+            client,
+            connected: false,
+            tick_size_provider: Arc::new(UpbitKrwTickSize::new()),
+        })
+    }
+
+    /// 환경 변수에서 생성.
+    ///
+    /// 환경 변수가 설정되지 않았거나 클라이언트 생성에 실패하면 `None`을 반환합니다.
+    pub fn from_env() -> Option<Self> {
+        UpbitConfig::from_env().and_then(|config| Self::new(config).ok())
+    }
+
+    /// 호가 단위 제공자를 설정합니다.
+    ///
+    /// 지정가 주문 가격은 매수 Floor(내림), 매도 Ceil(올림)으로 라운딩됩니다.
+    pub fn with_tick_size_provider(mut self, provider: Arc<dyn TickSizeProvider>) -> Self {
+        self.tick_size_provider = provider;
+        self
+    }
+
+    /// 클라이언트 설정 참조.
+    pub fn config(&self) -> &UpbitConfig {
+        &self.config
+    }
+
+    /// 파라미터에서 쿼리 문자열 생성 (JWT `query_hash` 대상과 동일한 형식).
+    fn build_query(params: &[(&str, String)]) -> String {
+        params
+            .iter()
+            .map(|(k, v)| format!("{}={}", k, v))
+            .collect::<Vec<_>>()
+            .join("&")
+    }
+
+    /// 요청 파라미터로 `Authorization` 헤더 값 생성.
+    fn authorization(&self, query: &str) -> ExchangeResult<String> {
+        if !self.config.has_credentials() {
+            return Err(ExchangeError::Unauthorized(
+                "Upbit Access Key/Secret Key가 설정되지 않았습니다".to_string(),
+            ));
+        }
+
+        let claims = UpbitJwtClaims {
+            access_key: &self.config.access_key,
+            nonce: Uuid::new_v4().to_string(),
+            query_hash: (!query.is_empty()).then(|| hex::encode(Sha512::digest(query))),
+            query_hash_alg: (!query.is_empty()).then_some("SHA512"),
+        };
+        let payload =
+            serde_json::to_vec(&claims).map_err(|e| ExchangeError::ParseError(e.to_string()))?;
+
+        Ok(format!("Bearer {}", self.sign_jwt(&payload)))
+    }
+
+    /// HS256 JWT 생성.
+    fn sign_jwt(&self, payload: &[u8]) -> String {
+        let header = URL_SAFE_NO_PAD.encode(br#"{"alg":"HS256","typ":"JWT"}"#);
+        let signing_input = format!("{}.{}", header, URL_SAFE_NO_PAD.encode(payload));
+
+        let mut mac =
+            HmacSha256::new_from_slice(self.config.secret_key.as_bytes()).expect("Invalid key");
+        mac.update(signing_input.as_bytes());
+        let signature = URL_SAFE_NO_PAD.encode(mac.finalize().into_bytes());
+
+        format!("{}.{}", signing_input, signature)
+    }
+
+    /// 공개 API 요청 (인증 불필요).
+    async fn public_get<T: for<'de> Deserialize<'de>>(
+        &self,
+        endpoint: &str,
+        params: &[(&str, String)],
+    ) -> ExchangeResult<T> {
+        let url = format!("{}{}", self.config.rest_base_url(), endpoint);
+        let query = Self::build_query(params);
+
+        let full_url = if query.is_empty() {
+            url
+        } else {
+            format!("{}?{}", url, query)
+        };
+
+        debug!("GET {}", full_url);
+
+        let response = self
+            .client
+            .get(&full_url)
+            .send()
+            .await
+            .map_err(|e| ExchangeError::NetworkError(e.to_string()))?;
+
+        Self::handle_response(response).await
+    }
+
+    /// 인증 API 요청.
+    ///
+    /// GET/DELETE는 파라미터를 쿼리 문자열로, POST는 JSON 본문으로 보냅니다.
+    async fn private_request<T: for<'de> Deserialize<'de>>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: &[(&str, String)],
+    ) -> ExchangeResult<T> {
+        let url = format!("{}{}", self.config.rest_base_url(), endpoint);
+        let query = Self::build_query(params);
+        let authorization = self.authorization(&query)?;
+
+        debug!("{} (auth) {}", method, endpoint);
+
+        let request = if method == Method::POST {
+            let body: serde_json::Map<String, serde_json::Value> = params
+                .iter()
+                .map(|(k, v)| (k.to_string(), serde_json::Value::String(v.clone())))
+                .collect();
+            self.client.post(&url).json(&body)
+        } else if query.is_empty() {
+            self.client.request(method, &url)
+        } else {
+            self.client.request(method, format!("{}?{}", url, query))
+        };
+
+        let response = request
+            .header("Authorization", authorization)
+            .send()
+            .await
+            .map_err(|e| ExchangeError::NetworkError(e.to_string()))?;
+
+        Self::handle_response(response).await
+    }
+
+    /// API 응답 처리.
+    async fn handle_response<T: for<'de> Deserialize<'de>>(
+        response: reqwest::Response,
+    ) -> ExchangeResult<T> {
+        let status = response.status();
+
+        // 남은 요청 수 (예: "group=order; min=1799; sec=7")
+        if let Some(remaining) = response
+            .headers()
+            .get("remaining-req")
+            .and_then(|v| v.to_str().ok())
+        {
+            debug!("Upbit Remaining-Req: {}", remaining);
+        }
+
+        if status.as_u16() == 429 {
+            warn!("Upbit 요청 한도 초과");
+            return Err(ExchangeError::RateLimited);
+        }
+
+        let body = response
+            .text()
+            .await
+            .map_err(|e| ExchangeError::NetworkError(e.to_string()))?;
+
+        if status.is_success() {
+            serde_json::from_str(&body).map_err(|e| {
+                error!("Failed to parse response: {} - Body: {}", e, body);
+                ExchangeError::ParseError(e.to_string())
+            })
+        } else if let Ok(error) = serde_json::from_str::<UpbitErrorBody>(&body) {
+            Err(Self::map_error(
+                status.as_u16(),
+                &error.error.name,
+                &error.error.message,
+            ))
+        } else {
+            Err(ExchangeError::ApiError {
+                code: status.as_u16() as i32,
+                message: body,
+            })
+        }
+    }
+
+    /// Upbit 에러 이름을 ExchangeError로 매핑.
+    fn map_error(status: u16, name: &str, message: &str) -> ExchangeError {
+        match name {
+            "insufficient_funds_bid" | "insufficient_funds_ask" => {
+                ExchangeError::InsufficientBalance(message.to_string())
+            }
+            "under_min_total_bid"
+            | "under_min_total_ask"
+            | "invalid_volume"
+            | "invalid_volume_bid"
+            | "invalid_volume_ask" => ExchangeError::InvalidQuantity(message.to_string()),
+            "order_not_found" => ExchangeError::OrderNotFound(message.to_string()),
+            "market_does_not_exist" => ExchangeError::SymbolNotFound(message.to_string()),
+            "invalid_access_key"
+            | "jwt_verification"
+            | "expired_access_key"
+            | "no_authorization_i_p"
+            | "out_of_scope" => ExchangeError::Unauthorized(message.to_string()),
+            _ => ExchangeError::ApiError {
+                code: status as i32,
+                message: format!("{}: {}", name, message),
+            },
+        }
+    }
+
+    /// 내부 ticker를 Upbit 마켓 코드로 변환 ("BTC/KRW" -> "KRW-BTC").
+    pub(super) fn to_market_code(ticker: &str) -> String {
+        match ticker.split_once('/') {
+            Some((base, quote)) => format!("{}-{}", quote, base).to_uppercase(),
+            None if ticker.contains('-') => ticker.to_uppercase(),
+            // 호가 자산 생략 시 원화 마켓
+            None => format!("KRW-{}", ticker.to_uppercase()),
+        }
+    }
+
+    /// Upbit 마켓 코드를 내부 ticker로 변환 ("KRW-BTC" -> "BTC/KRW").
+    pub(super) fn from_market_code(market: &str) -> String {
+        match market.split_once('-') {
+            Some((quote, base)) => format!("{}/{}", base, quote),
+            None => market.to_string(),
+        }
+    }
+
+    /// 체결 방향 ("ASK": 매도 체결, "BID": 매수 체결).
+    pub(super) fn parse_ask_bid(ask_bid: &str) -> Side {
+        if ask_bid.eq_ignore_ascii_case("ASK") {
+            Side::Sell
+        } else {
+            Side::Buy
+        }
+    }
+
+    /// 호가 단위 목록을 OrderBook으로 변환.
+    pub(super) fn to_order_book(
+        ticker: String,
+        units: &[UpbitOrderBookUnit],
+        timestamp: i64,
+    ) -> OrderBook {
+        OrderBook {
+            ticker,
+            bids: units
+                .iter()
+                .map(|u| OrderBookLevel {
+                    price: u.bid_price,
+                    quantity: u.bid_size,
+                })
+                .collect(),
+            asks: units
+                .iter()
+                .map(|u| OrderBookLevel {
+                    price: u.ask_price,
+                    quantity: u.ask_size,
+                })
+                .collect(),
+            timestamp: DateTime::from_timestamp_millis(timestamp).unwrap_or_else(Utc::now),
+        }
+    }
+
+    /// 타임프레임별 캔들 API 경로.
+    fn candle_endpoint(timeframe: Timeframe) -> ExchangeResult<&'static str> {
+        Ok(match timeframe {
+            Timeframe::M1 => "/candles/minutes/1",
+            Timeframe::M3 => "/candles/minutes/3",
+            Timeframe::M5 => "/candles/minutes/5",
+            Timeframe::M15 => "/candles/minutes/15",
+            Timeframe::M30 => "/candles/minutes/30",
+            Timeframe::H1 => "/candles/minutes/60",
+            Timeframe::H4 => "/candles/minutes/240",
+            Timeframe::D1 => "/candles/days",
+            Timeframe::W1 => "/candles/weeks",
+            Timeframe::MN1 => "/candles/months",
+            other => {
+                return Err(ExchangeError::NotSupported(format!(
+                    "Upbit은 {:?} 캔들을 지원하지 않습니다",
+                    other
+                )))
+            }
+        })
+    }
+
+    /// Upbit 주문 상태를 내부 상태로 변환.
+    fn parse_status_type(state: &str, executed: Decimal) -> OrderStatusType {
+        match state {
+            "wait" | "watch" if executed > Decimal::ZERO => OrderStatusType::PartiallyFilled,
+            "wait" | "watch" => OrderStatusType::Open,
+            "done" => OrderStatusType::Filled,
+            "cancel" => OrderStatusType::Cancelled,
+            _ => OrderStatusType::Open,
+        }
+    }
+
+    /// Upbit 주문을 내부 OrderStatus로 변환.
+    fn parse_order_status(order: &UpbitOrder) -> OrderStatus {
+        let (filled_volume, funds) = order
+            .trades
+            .iter()
+            .fold((Decimal::ZERO, Decimal::ZERO), |(volume, funds), trade| {
+                (volume + trade.volume, funds + trade.funds)
+            });
+        let average_price = (filled_volume > Decimal::ZERO).then(|| funds / filled_volume);
+
+        let updated_at = DateTime::parse_from_rfc3339(&order.created_at)
+            .map(|dt| dt.with_timezone(&Utc))
+            .unwrap_or_else(|_| Utc::now());
+
+        OrderStatus {
+            order_id: order.uuid.clone(),
+            client_order_id: order.identifier.clone(),
+            ticker: Some(Self::from_market_code(&order.market)),
+            side: match order.side.as_str() {
+                "bid" => Some(Side::Buy),
+                "ask" => Some(Side::Sell),
+                _ => None,
+            },
+            quantity: order.volume,
+            // 시장가 매수(`price`)의 price는 주문 총액이므로 가격으로 쓰지 않음
+            price: order.price.filter(|_| order.ord_type == "limit"),
+            status: Self::parse_status_type(&order.state, order.executed_volume),
+            filled_quantity: order.executed_volume,
+            average_price,
+            updated_at,
+        }
+    }
+
+    /// 최우선 매도호가 조회 (시장가 매수 금액 환산용).
+    async fn best_ask(&self, ticker: &str) -> ExchangeResult<Decimal> {
+        let book = self.get_order_book(ticker, Some(1)).await?;
+        book.asks
+            .first()
+            .map(|level| level.price)
+            .ok_or_else(|| ExchangeError::ParseError(format!("{} 매도호가 없음", ticker)))
+    }
+}
+
+#[async_trait]
+impl Exchange for UpbitClient {
+    fn name(&self) -> &str {
+        "upbit"
+    }
+
+    async fn is_connected(&self) -> bool {
+        self.connected
+    }
+
+    async fn connect(&mut self) -> ExchangeResult<()> {
+        info!("Connecting to Upbit...");
+
+        // 인증 키가 있으면 잔고 조회로 키까지 확인, 없으면 시세 조회로 연결만 확인
+        if self.config.has_credentials() {
+            let _: Vec<UpbitAccount> = self.private_request(Method::GET, "/accounts", &[]).await?;
+        } else {
+            let _: Vec<UpbitTicker> = self
+                .public_get("/ticker", &[("markets", "KRW-BTC".to_string())])
+                .await?;
+        }
+
+        self.connected = true;
+        info!("Connected to Upbit successfully");
+        Ok(())
+    }
+
+    async fn disconnect(&mut self) -> ExchangeResult<()> {
+        self.connected = false;
+        info!("Disconnected from Upbit");
+        Ok(())
+    }
+
+    async fn get_account(&self) -> ExchangeResult<AccountInfo> {
+        let accounts: Vec<UpbitAccount> =
+            self.private_request(Method::GET, "/accounts", &[]).await?;
+
+        let balances = accounts
+            .into_iter()
+            .filter(|a| a.balance > Decimal::ZERO || a.locked > Decimal::ZERO)
+            .map(|a| Balance {
+                asset: a.currency,
+                free: a.balance,
+                locked: a.locked,
+            })
+            .collect();
+
+        Ok(AccountInfo {
+            balances,
+            can_trade: true,
+            can_withdraw: true,
+            can_deposit: true,
+        })
+    }
+
+    async fn get_balance(&self, asset: &str) -> ExchangeResult<Balance> {
+        let account = self.get_account().await?;
+
+        account
+            .balances
+            .into_iter()
+            .find(|b| b.asset.eq_ignore_ascii_case(asset))
+            .ok_or_else(|| ExchangeError::AssetNotFound(asset.to_string()))
+    }
+
+    async fn get_ticker(&self, symbol: &str) -> ExchangeResult<Ticker> {
+        let market = Self::to_market_code(symbol);
+        let resp: Vec<UpbitTicker> = self
+            .public_get("/ticker", &[("markets", market.clone())])
+            .await?;
+        let ticker = resp
+            .into_iter()
+            .next()
+            .ok_or(ExchangeError::SymbolNotFound(market))?;
+
+        // 현재가 API는 호가를 제공하지 않음 - 현재가로 대체
+        Ok(Ticker {
+            ticker: symbol.to_string(),
+            bid: ticker.trade_price,
+            ask: ticker.trade_price,
+            last: ticker.trade_price,
+            volume_24h: ticker.acc_trade_volume_24h,
+            high_24h: ticker.high_price,
+            low_24h: ticker.low_price,
+            change_24h: ticker.signed_change_price,
+            change_24h_percent: ticker.signed_change_rate * Decimal::ONE_HUNDRED,
+            timestamp: DateTime::from_timestamp_millis(ticker.timestamp).unwrap_or_else(Utc::now),
+        })
+    }
+
+    async fn get_order_book(&self, symbol: &str, limit: Option<u32>) -> ExchangeResult<OrderBook> {
+        let market = Self::to_market_code(symbol);
+        let resp: Vec<UpbitOrderBook> = self
+            .public_get("/orderbook", &[("markets", market.clone())])
+            .await?;
+        let book = resp
+            .into_iter()
+            .next()
+            .ok_or(ExchangeError::SymbolNotFound(market))?;
+
+        let mut order_book =
+            Self::to_order_book(symbol.to_string(), &book.orderbook_units, book.timestamp);
+        if let Some(limit) = limit {
+            order_book.bids.truncate(limit as usize);
+            order_book.asks.truncate(limit as usize);
+        }
+        Ok(order_book)
+    }
+
+    async fn get_recent_trades(
+        &self,
+        symbol: &str,
+        limit: Option<u32>,
+    ) -> ExchangeResult<Vec<TradeTick>> {
+        let market = Self::to_market_code(symbol);
+        let count = limit.unwrap_or(200).min(500).to_string();
+
+        let resp: Vec<UpbitTrade> = self
+            .public_get("/trades/ticks", &[("market", market), ("count", count)])
+            .await?;
+
+        Ok(resp
+            .into_iter()
+            .map(|t| TradeTick {
+                ticker: symbol.to_string(),
+                id: t.sequential_id.to_string(),
+                price: t.trade_price,
+                quantity: t.trade_volume,
+                side: Self::parse_ask_bid(&t.ask_bid),
+                timestamp: DateTime::from_timestamp_millis(t.timestamp).unwrap_or_else(Utc::now),
+            })
+            .collect())
+    }
+
+    async fn get_klines(
+        &self,
+        symbol: &str,
+        timeframe: Timeframe,
+        limit: Option<u32>,
+    ) -> ExchangeResult<Vec<Kline>> {
+        let endpoint = Self::candle_endpoint(timeframe)?;
+        let market = Self::to_market_code(symbol);
+        let count = limit.unwrap_or(200).min(200).to_string();
+
+        let resp: Vec<UpbitCandle> = self
+            .public_get(endpoint, &[("market", market), ("count", count)])
+            .await?;
+
+        let duration = chrono::Duration::from_std(timeframe.duration())
+            .unwrap_or_else(|_| chrono::Duration::zero());
+
+        // 최신 캔들부터 내려오므로 시간 순으로 뒤집음
+        Ok(resp
+            .into_iter()
+            .rev()
+            .filter_map(|c| {
+                let open_time =
+                    NaiveDateTime::parse_from_str(&c.candle_date_time_utc, "%Y-%m-%dT%H:%M:%S")
+                        .ok()?
+                        .and_utc();
+                Some(Kline {
+                    ticker: symbol.to_string(),
+                    timeframe,
+                    open_time,
+                    open: c.opening_price,
+                    high: c.high_price,
+                    low: c.low_price,
+                    close: c.trade_price,
+                    volume: c.candle_acc_trade_volume,
+                    close_time: open_time + duration,
+                    quote_volume: Some(c.candle_acc_trade_price),
+                    num_trades: None,
+                })
+            })
+            .collect())
+    }
+
+    async fn place_order(&self, request: &OrderRequest) -> ExchangeResult<String> {
+        let market = Self::to_market_code(&request.ticker);
+        let is_buy = matches!(request.side, Side::Buy);
+        let side = if is_buy { "bid" } else { "ask" };
+
+        let mut params = vec![("market", market), ("side", side.to_string())];
+
+        match request.order_type {
+            OrderType::Limit => {
+                let price = request.price.ok_or_else(|| {
+                    ExchangeError::OrderRejected("limit order requires price".to_string())
+                })?;
+                let time_in_force = match request.time_in_force {
+                    TimeInForce::GTC => None,
+                    TimeInForce::IOC => Some("ioc"),
+                    TimeInForce::FOK => Some("fok"),
+                    TimeInForce::GTD => {
+                        return Err(ExchangeError::NotSupported(
+                            "Upbit은 GTD 주문을 지원하지 않습니다".to_string(),
+                        ));
+                    }
+                };
+
+                // 매수: Floor (내림), 매도: Ceil (올림)
+                let method = if is_buy {
+                    RoundMethod::Floor
+                } else {
+                    RoundMethod::Ceil
+                };
+                let rounded = self.tick_size_provider.round_to_tick(price, method);
+                if rounded != price {
+                    warn!(
+                        "주문 가격 호가 단위 조정: {} -> {} (종목: {})",
+                        price, rounded, request.ticker
+                    );
+                }
+
+                params.push(("ord_type", "limit".to_string()));
+                params.push(("volume", request.quantity.normalize().to_string()));
+                params.push(("price", rounded.normalize().to_string()));
+                if let Some(tif) = time_in_force {
+                    params.push(("time_in_force", tif.to_string()));
+                }
+            }
+            OrderType::Market if is_buy => {
+                // 시장가 매수는 주문 총액 지정 - 수량 × 기준가(지정 가격 또는 최우선 매도호가)
+                let reference = match request.price {
+                    Some(price) => price,
+                    None => self.best_ask(&request.ticker).await?,
+                };
+                let total = (request.quantity * reference).floor();
+                params.push(("ord_type", "price".to_string()));
+                params.push(("price", total.to_string()));
+            }
+            OrderType::Market => {
+                params.push(("ord_type", "market".to_string()));
+                params.push(("volume", request.quantity.normalize().to_string()));
+            }
+            other => {
+                return Err(ExchangeError::NotSupported(format!(
+                    "Upbit은 {:?} 주문을 지원하지 않습니다",
+                    other
+                )));
+            }
+        }
+
+        if let Some(ref client_id) = request.client_order_id {
+            params.push(("identifier", client_id.clone()));
+        }
+
+        info!(
+            "Placing {} {:?} order for {} {} @ {:?}",
+            side, request.order_type, request.quantity, request.ticker, request.price
+        );
+
+        let resp: UpbitOrder = self
+            .private_request(Method::POST, "/orders", &params)
+            .await?;
+
+        info!("Order placed successfully: {}", resp.uuid);
+        Ok(resp.uuid)
+    }
+
+    async fn cancel_order(&self, _symbol: &str, order_id: &str) -> ExchangeResult<()> {
+        let _: UpbitOrder = self
+            .private_request(Method::DELETE, "/order", &[("uuid", order_id.to_string())])
+            .await?;
+
+        info!("Order {} cancelled", order_id);
+        Ok(())
+    }
+
+    async fn get_order(&self, _symbol: &str, order_id: &str) -> ExchangeResult<OrderStatus> {
+        let resp: UpbitOrder = self
+            .private_request(Method::GET, "/order", &[("uuid", order_id.to_string())])
+            .await?;
+
+        Ok(Self::parse_order_status(&resp))
+    }
+
+    async fn get_open_orders(&self, symbol: Option<&str>) -> ExchangeResult<Vec<OrderStatus>> {
+        let mut params = vec![("state", "wait".to_string())];
+        if let Some(s) = symbol {
+            params.insert(0, ("market", Self::to_market_code(s)));
+        }
+
+        let resp: Vec<UpbitOrder> = self
+            .private_request(Method::GET, "/orders/open", &params)
+            .await?;
+
+        Ok(resp.iter().map(Self::parse_order_status).collect())
+    }
+
+    async fn get_positions(&self) -> ExchangeResult<Vec<Position>> {
+        // 현물은 포지션이 없음, 빈 값 반환
+        Ok(vec![])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn client() -> UpbitClient {
+        UpbitClient::new(UpbitConfig::new("access".to_string(), "secret".to_string())).unwrap()
+    }
+
+    #[test]
+    fn test_market_code_conversion() {
+        assert_eq!(UpbitClient::to_market_code("BTC/KRW"), "KRW-BTC");
+        assert_eq!(UpbitClient::to_market_code("eth"), "KRW-ETH");
+        assert_eq!(UpbitClient::to_market_code("KRW-XRP"), "KRW-XRP");
+        assert_eq!(UpbitClient::from_market_code("KRW-BTC"), "BTC/KRW");
+    }
+
+    #[test]
+    fn test_jwt_signature() {
+        let token = client().sign_jwt(br#"{"access_key":"access","nonce":"n"}"#);
+        let parts: Vec<&str> = token.split('.').collect();
+        assert_eq!(parts.len(), 3);
+        assert_eq!(parts[0], "eyJhbGciOiJIUzI1NiIsInR5cCI6IkpXVCJ9");
+
+        // 서명은 "header.payload"의 HMAC-SHA256
+        let mut mac = HmacSha256::new_from_slice(b"secret").unwrap();
+        mac.update(format!("{}.{}", parts[0], parts[1]).as_bytes());
+        assert_eq!(
+            URL_SAFE_NO_PAD.decode(parts[2]).unwrap(),
+            mac.finalize().into_bytes().to_vec()
+        );
+    }
+
+    #[test]
+    fn test_authorization_query_hash() {
+        let header = client().authorization("market=KRW-BTC&side=bid").unwrap();
+        let token = header.strip_prefix("Bearer ").unwrap();
+        let payload = token.split('.').nth(1).unwrap();
+        let claims: serde_json::Value =
+            serde_json::from_slice(&URL_SAFE_NO_PAD.decode(payload).unwrap()).unwrap();
+
+        assert_eq!(claims["access_key"], "access");
+        assert_eq!(claims["query_hash_alg"], "SHA512");
+        assert_eq!(
+            claims["query_hash"],
+            hex::encode(Sha512::digest("market=KRW-BTC&side=bid"))
+        );
+
+        // 키 없이 인증 API 호출 불가
+        let public = UpbitClient::new(UpbitConfig::default()).unwrap();
+        assert!(matches!(
+            public.authorization(""),
+            Err(ExchangeError::Unauthorized(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_order_status() {
+        let order: UpbitOrder = serde_json::from_str(
+            r#"{
+                "uuid": "9ca023a5-851b-4fec-9f0a-48cd83c2eaae", "side": "bid",
+                "ord_type": "limit", "price": "95000000", "state": "wait",
+                "market": "KRW-BTC", "created_at": "2024-03-01T12:00:00+09:00",
+                "volume": "0.01", "remaining_volume": "0.004", "executed_volume": "0.006",
+                "trades": [
+                    {"price": "94900000", "volume": "0.002", "funds": "189800"},
+                    {"price": "95000000", "volume": "0.004", "funds": "380000"}
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let status = UpbitClient::parse_order_status(&order);
+        assert_eq!(status.ticker.as_deref(), Some("BTC/KRW"));
+        assert_eq!(status.side, Some(Side::Buy));
+        assert_eq!(status.status, OrderStatusType::PartiallyFilled);
+        assert_eq!(status.price, Some(dec!(95000000)));
+        assert_eq!(status.filled_quantity, dec!(0.006));
+        assert_eq!(
+            status.average_price.map(|p| p.round_dp(2)),
+            Some(dec!(94966666.67))
+        );
+        assert_eq!(status.updated_at.to_rfc3339(), "2024-03-01T03:00:00+00:00");
+    }
+
+    #[test]
+    fn test_map_error() {
+        assert!(matches!(
+            UpbitClient::map_error(400, "insufficient_funds_bid", "잔고 부족"),
+            ExchangeError::InsufficientBalance(_)
+        ));
+        assert!(matches!(
+            UpbitClient::map_error(401, "jwt_verification", "JWT 검증 실패"),
+            ExchangeError::Unauthorized(_)
+        ));
+        assert!(matches!(
+            UpbitClient::map_error(400, "unknown", "x"),
+            ExchangeError::ApiError { code: 400, .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_place_order_rejects_unsupported_types() {
+        let client = client();
+
+        let mut stop = OrderRequest::market_sell("BTC/KRW".to_string(), dec!(1));
+        stop.order_type = OrderType::StopLoss;
+        assert!(matches!(
+            client.place_order(&stop).await,
+            Err(ExchangeError::NotSupported(_))
+        ));
+
+        let mut gtd = OrderRequest::limit_buy("BTC/KRW".to_string(), dec!(1), dec!(95000000));
+        gtd.time_in_force = TimeInForce::GTD;
+        assert!(matches!(
+            client.place_order(&gtd).await,
+            Err(ExchangeError::NotSupported(_))
+        ));
+    }
+}
@@ -0,0 +1,86 @@
+//! Upbit 클라이언트 설정.
+
+use std::fmt;
+
+/// Upbit REST API 기본 URL.
+const REST_BASE_URL: &str = "https://api.upbit.com/v1";
+
+/// Upbit WebSocket URL.
+const WS_BASE_URL: &str = "wss://api.upbit.com/websocket/v1";
+
+/// Upbit 클라이언트 설정.
+///
+/// 시세 조회와 WebSocket 시세 스트림은 키 없이 사용할 수 있으므로
+/// `Default`는 빈 키(공개 API 전용)로 생성합니다.
+///
+/// # 보안
+/// - `Debug` 구현은 민감 정보(`access_key`, `secret_key`)를 마스킹합니다.
+#[derive(Clone)]
+pub struct UpbitConfig {
+    /// Access Key
+    pub access_key: String,
+    /// Secret Key
+    pub secret_key: String,
+    /// 요청 타임아웃 (초)
+    pub timeout_secs: u64,
+}
+
+impl fmt::Debug for UpbitConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let masked_key = if self.access_key.len() > 8 {
+            format!(
+                "{}...{}",
+                &self.access_key[..4],
+                &self.access_key[self.access_key.len() - 4..]
+            )
+        } else {
+            "***REDACTED***".to_string()
+        };
+
+        f.debug_struct("UpbitConfig")
+            .field("access_key", &masked_key)
+            .field("secret_key", &"***REDACTED***")
+            .field("timeout_secs", &self.timeout_secs)
+            .finish()
+    }
+}
+
+impl Default for UpbitConfig {
+    fn default() -> Self {
+        Self::new(String::new(), String::new())
+    }
+}
+
+impl UpbitConfig {
+    /// 새 설정 생성.
+    pub fn new(access_key: String, secret_key: String) -> Self {
+        Self {
+            access_key,
+            secret_key,
+            timeout_secs: 30,
+        }
+    }
+
+    /// 환경 변수(`UPBIT_ACCESS_KEY`, `UPBIT_SECRET_KEY`)에서 생성.
+    pub fn from_env() -> Option<Self> {
+        Some(Self::new(
+            std::env::var("UPBIT_ACCESS_KEY").ok()?,
+            std::env::var("UPBIT_SECRET_KEY").ok()?,
+        ))
+    }
+
+    /// 인증 키 설정 여부 (주문/잔고 API 사용 가능 여부).
+    pub fn has_credentials(&self) -> bool {
+        !self.access_key.is_empty() && !self.secret_key.is_empty()
+    }
+
+    /// REST API 기본 URL 반환.
+    pub fn rest_base_url(&self) -> &str {
+        REST_BASE_URL
+    }
+
+    /// WebSocket URL 반환.
+    pub fn ws_base_url(&self) -> &str {
+        WS_BASE_URL
+    }
+}
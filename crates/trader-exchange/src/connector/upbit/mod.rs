@@ -0,0 +1,38 @@
+//! Upbit 원화(KRW) 마켓 암호화폐 거래소 연동 모듈.
+//!
+//! # 기능
+//!
+//! - REST 주문 (지정가/시장가, IOC/FOK), 주문 취소/조회
+//! - 계좌 잔고 조회
+//! - 시세 조회 (현재가, 호가, 체결, 캔들)
+//! - 시세 WebSocket (현재가, 체결, 호가 실시간 수신, 연결 후 구독 변경 가능)
+//! - JWT(HS256) 요청 서명 (`UPBIT_ACCESS_KEY`, `UPBIT_SECRET_KEY`)
+//!
+//! 내부 ticker는 `BTC/KRW` 형식이며 Upbit 마켓 코드(`KRW-BTC`)로 변환하여 요청합니다.
+//!
+//! # API 문서
+//!
+//! 공식 API 문서: <https://docs.upbit.com/reference>
+//!
+//! # 사용 예제
+//!
+//! ```rust,ignore
+//! use trader_core::OrderRequest;
+//! use trader_exchange::connector::upbit::{UpbitClient, UpbitConfig};
+//! use trader_exchange::Exchange;
+//!
+//! let mut client = UpbitClient::from_env().expect("UPBIT_ACCESS_KEY 필요");
+//! client.connect().await?;
+//!
+//! let balance = client.get_balance("KRW").await?;
+//! let order = OrderRequest::limit_buy("BTC/KRW".to_string(), dec!(0.001), dec!(95000000));
+//! let order_id = client.place_order(&order).await?;
+//! ```
+
+pub mod client;
+pub mod config;
+pub mod websocket;
+
+pub use client::UpbitClient;
+pub use config::UpbitConfig;
+pub use websocket::UpbitMarketStream;
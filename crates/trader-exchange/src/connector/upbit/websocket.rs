@@ -0,0 +1,545 @@
+//! Upbit 시세 WebSocket 스트림.
+//!
+//! 현재가(`ticker`), 체결(`trade`), 호가(`orderbook`)를 [`MarketEvent`]로 전달합니다.
+//!
+//! - Upbit은 구독 요청을 보낼 때마다 이전 구독을 대체하므로, 구독이 바뀌면 전체 목록을 다시 보냅니다.
+//!   KIS와 달리 연결 후에도 구독을 추가/해제할 수 있습니다.
+//! - 120초 동안 메시지가 없으면 서버가 연결을 끊으므로 주기적으로 Ping을 보냅니다.
+//! - 연결이 끊기면 지수 백오프로 재접속하고 구독을 복원합니다.
+//!
+//! # 사용 예제
+//!
+//! ```rust,ignore
+//! use trader_exchange::connector::upbit::{UpbitConfig, UpbitMarketStream};
+//! use trader_exchange::MarketStream;
+//!
+//! let mut stream = UpbitMarketStream::new(UpbitConfig::default());
+//! stream.subscribe_ticker("BTC/KRW").await?;
+//! stream.start().await?;
+//!
+//! while let Some(event) = stream.next_event().await {
+//!     println!("{:?}", event);
+//! }
+//! ```
+
+use super::client::{UpbitClient, UpbitOrderBookUnit};
+use super::config::UpbitConfig;
+use crate::traits::{ExchangeResult, MarketEvent, MarketStream};
+use crate::ExchangeError;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use futures::{SinkExt, StreamExt};
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use std::collections::BTreeSet;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{mpsc, RwLock};
+use tokio::task::JoinHandle;
+use tokio_tungstenite::{connect_async, tungstenite::Message};
+use tracing::{debug, error, info, warn};
+use trader_core::{Ticker, Timeframe, TradeTick};
+use uuid::Uuid;
+
+/// Ping 간격 (서버 유휴 연결 종료: 120초).
+const PING_INTERVAL: Duration = Duration::from_secs(60);
+
+/// 재접속 최초 대기 시간.
+const RECONNECT_BASE_DELAY: Duration = Duration::from_secs(1);
+
+/// 재접속 최대 대기 시간.
+const RECONNECT_MAX_DELAY: Duration = Duration::from_secs(60);
+
+// ============================================================================
+// WebSocket 메시지 타입
+// ============================================================================
+
+/// 메시지 유형만 먼저 확인하기 위한 헤더.
+#[derive(Debug, Deserialize)]
+struct WsHeader {
+    #[serde(rename = "type")]
+    kind: Option<String>,
+    error: Option<WsError>,
+}
+
+#[derive(Debug, Deserialize)]
+struct WsError {
+    name: String,
+    message: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct WsTicker {
+    code: String,
+    trade_price: Decimal,
+    high_price: Decimal,
+    low_price: Decimal,
+    signed_change_price: Decimal,
+    signed_change_rate: Decimal,
+    acc_trade_volume_24h: Decimal,
+    timestamp: i64,
+}
+
+#[derive(Debug, Deserialize)]
+struct WsTrade {
+    code: String,
+    trade_price: Decimal,
+    trade_volume: Decimal,
+    ask_bid: String,
+    trade_timestamp: i64,
+    sequential_id: i64,
+}
+
+#[derive(Debug, Deserialize)]
+struct WsOrderBook {
+    code: String,
+    timestamp: i64,
+    orderbook_units: Vec<UpbitOrderBookUnit>,
+}
+
+/// WebSocket 메시지(JSON)를 시장 이벤트로 변환합니다.
+fn parse_message(text: &str) -> Option<MarketEvent> {
+    let header = serde_json::from_str::<WsHeader>(text).ok()?;
+    if let Some(error) = header.error {
+        return Some(MarketEvent::Error(format!(
+            "Upbit {}: {}",
+            error.name, error.message
+        )));
+    }
+
+    let parsed = match header.kind.as_deref()? {
+        "ticker" => serde_json::from_str::<WsTicker>(text).map(|t| {
+            // 현재가 스트림은 호가를 제공하지 않음 - 현재가로 대체
+            MarketEvent::Ticker(Ticker {
+                ticker: UpbitClient::from_market_code(&t.code),
+                bid: t.trade_price,
+                ask: t.trade_price,
+                last: t.trade_price,
+                volume_24h: t.acc_trade_volume_24h,
+                high_24h: t.high_price,
+                low_24h: t.low_price,
+                change_24h: t.signed_change_price,
+                change_24h_percent: t.signed_change_rate * Decimal::ONE_HUNDRED,
+                timestamp: DateTime::from_timestamp_millis(t.timestamp).unwrap_or_else(Utc::now),
+            })
+        }),
+        "trade" => serde_json::from_str::<WsTrade>(text).map(|t| {
+            MarketEvent::Trade(TradeTick {
+                ticker: UpbitClient::from_market_code(&t.code),
+                id: t.sequential_id.to_string(),
+                price: t.trade_price,
+                quantity: t.trade_volume,
+                side: UpbitClient::parse_ask_bid(&t.ask_bid),
+                timestamp: DateTime::from_timestamp_millis(t.trade_timestamp)
+                    .unwrap_or_else(Utc::now),
+            })
+        }),
+        "orderbook" => serde_json::from_str::<WsOrderBook>(text).map(|ob| {
+            MarketEvent::OrderBook(UpbitClient::to_order_book(
+                UpbitClient::from_market_code(&ob.code),
+                &ob.orderbook_units,
+                ob.timestamp,
+            ))
+        }),
+        _ => return None,
+    };
+
+    parsed
+        .map_err(|e| warn!("Upbit 시세 메시지 파싱 실패: {}", e))
+        .ok()
+}
+
+/// 재접속 대기 시간 (지수 백오프, 최대 `RECONNECT_MAX_DELAY`).
+fn reconnect_delay(attempt: u32) -> Duration {
+    RECONNECT_BASE_DELAY
+        .saturating_mul(2u32.saturating_pow(attempt.min(16)))
+        .min(RECONNECT_MAX_DELAY)
+}
+
+// ============================================================================
+// 구독 상태
+// ============================================================================
+
+/// 유형별 구독 마켓 코드.
+#[derive(Debug, Default, Clone)]
+struct Subscriptions {
+    ticker: BTreeSet<String>,
+    trade: BTreeSet<String>,
+    orderbook: BTreeSet<String>,
+}
+
+impl Subscriptions {
+    fn is_empty(&self) -> bool {
+        self.ticker.is_empty() && self.trade.is_empty() && self.orderbook.is_empty()
+    }
+
+    fn remove(&mut self, code: &str) -> bool {
+        let removed = [
+            self.ticker.remove(code),
+            self.trade.remove(code),
+            self.orderbook.remove(code),
+        ];
+        removed.contains(&true)
+    }
+
+    /// 전체 구독 요청 메시지 (구독이 없으면 `None`).
+    fn request(&self, ticket: &str) -> Option<String> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let mut request = vec![serde_json::json!({ "ticket": ticket })];
+        for (kind, codes) in [
+            ("ticker", &self.ticker),
+            ("trade", &self.trade),
+            ("orderbook", &self.orderbook),
+        ] {
+            if !codes.is_empty() {
+                request.push(serde_json::json!({ "type": kind, "codes": codes }));
+            }
+        }
+        request.push(serde_json::json!({ "format": "DEFAULT" }));
+
+        Some(serde_json::Value::Array(request).to_string())
+    }
+}
+
+// ============================================================================
+// Upbit 시세 스트림
+// ============================================================================
+
+/// Upbit WebSocket 시세 스트림.
+pub struct UpbitMarketStream {
+    config: UpbitConfig,
+    subscriptions: Arc<RwLock<Subscriptions>>,
+    /// 실행 중일 때 구독 변경 알림 채널
+    resubscribe_tx: Option<mpsc::UnboundedSender<()>>,
+    event_tx: mpsc::Sender<MarketEvent>,
+    event_rx: mpsc::Receiver<MarketEvent>,
+    task: Option<JoinHandle<()>>,
+}
+
+impl UpbitMarketStream {
+    /// 새 시세 스트림 생성 (`start` 호출 전까지 접속하지 않음).
+    pub fn new(config: UpbitConfig) -> Self {
+        let (event_tx, event_rx) = mpsc::channel(1000);
+        Self {
+            config,
+            subscriptions: Arc::new(RwLock::new(Subscriptions::default())),
+            resubscribe_tx: None,
+            event_tx,
+            event_rx,
+            task: None,
+        }
+    }
+
+    /// WebSocket 연결 시작 (별도 태스크에서 실행).
+    pub async fn start(&mut self) -> ExchangeResult<()> {
+        if self.is_running() {
+            return Ok(());
+        }
+
+        let (resubscribe_tx, resubscribe_rx) = mpsc::unbounded_channel();
+        self.resubscribe_tx = Some(resubscribe_tx);
+        self.task = Some(tokio::spawn(Self::run(
+            self.config.ws_base_url().to_string(),
+            self.subscriptions.clone(),
+            resubscribe_rx,
+            self.event_tx.clone(),
+        )));
+
+        info!("Upbit MarketStream 시작됨");
+        Ok(())
+    }
+
+    /// 연결 중지.
+    pub fn stop(&mut self) {
+        if let Some(task) = self.task.take() {
+            task.abort();
+        }
+        self.resubscribe_tx = None;
+    }
+
+    /// 실행 중 여부.
+    pub fn is_running(&self) -> bool {
+        self.task.as_ref().is_some_and(|task| !task.is_finished())
+    }
+
+    /// 구독 변경 후 실행 중이면 전체 구독을 다시 보내도록 알림.
+    async fn update<F: FnOnce(&mut Subscriptions)>(&mut self, f: F) {
+        f(&mut *self.subscriptions.write().await);
+        if let Some(tx) = &self.resubscribe_tx {
+            let _ = tx.send(());
+        }
+    }
+
+    /// 연결 태스크: 접속 → 구독 → 메시지 전달, 끊기면 백오프 후 재접속.
+    async fn run(
+        url: String,
+        subscriptions: Arc<RwLock<Subscriptions>>,
+        mut resubscribe_rx: mpsc::UnboundedReceiver<()>,
+        tx: mpsc::Sender<MarketEvent>,
+    ) {
+        let ticket = Uuid::new_v4().to_string();
+        let mut attempt = 0u32;
+
+        loop {
+            match connect_async(url.as_str()).await {
+                Ok((ws, _)) => {
+                    info!("Upbit WebSocket 연결");
+                    attempt = 0;
+                    if tx.send(MarketEvent::Connected).await.is_err() {
+                        return;
+                    }
+
+                    let (mut write, mut read) = ws.split();
+                    let mut ping = tokio::time::interval(PING_INTERVAL);
+                    // 연결 직후 (재접속 포함) 전체 구독 전송
+                    let mut pending_subscribe = true;
+
+                    loop {
+                        if pending_subscribe {
+                            pending_subscribe = false;
+                            let request = subscriptions.read().await.request(&ticket);
+                            if let Some(request) = request {
+                                if let Err(e) = write.send(Message::Text(request)).await {
+                                    error!("Upbit 구독 요청 실패: {}", e);
+                                    break;
+                                }
+                            }
+                        }
+
+                        tokio::select! {
+                            msg = read.next() => match msg {
+                                Some(Ok(Message::Binary(data))) => {
+                                    if let Some(event) = std::str::from_utf8(&data)
+                                        .ok()
+                                        .and_then(parse_message)
+                                    {
+                                        if tx.send(event).await.is_err() {
+                                            debug!("시세 이벤트 수신자 종료, 스트림 중지");
+                                            return;
+                                        }
+                                    }
+                                }
+                                Some(Ok(Message::Text(text))) => {
+                                    if let Some(event) = parse_message(&text) {
+                                        if tx.send(event).await.is_err() {
+                                            debug!("시세 이벤트 수신자 종료, 스트림 중지");
+                                            return;
+                                        }
+                                    }
+                                }
+                                Some(Ok(Message::Close(frame))) => {
+                                    warn!("Upbit WebSocket 종료 수신: {:?}", frame);
+                                    break;
+                                }
+                                Some(Ok(_)) => {}
+                                Some(Err(e)) => {
+                                    error!("Upbit WebSocket 에러: {}", e);
+                                    break;
+                                }
+                                None => break,
+                            },
+                            signal = resubscribe_rx.recv() => match signal {
+                                Some(()) => pending_subscribe = true,
+                                // 스트림이 drop됨
+                                None => return,
+                            },
+                            _ = ping.tick() => {
+                                if let Err(e) = write.send(Message::Ping(Vec::new())).await {
+                                    error!("Upbit Ping 전송 실패: {}", e);
+                                    break;
+                                }
+                            }
+                        }
+                    }
+
+                    if tx.send(MarketEvent::Disconnected).await.is_err() {
+                        return;
+                    }
+                }
+                Err(e) => error!("Upbit WebSocket 연결 실패: {}", e),
+            }
+
+            if tx.is_closed() {
+                return;
+            }
+
+            let delay = reconnect_delay(attempt);
+            warn!("{}초 후 Upbit WebSocket 재접속", delay.as_secs());
+            tokio::time::sleep(delay).await;
+            attempt = attempt.saturating_add(1);
+        }
+    }
+}
+
+#[async_trait]
+impl MarketStream for UpbitMarketStream {
+    async fn subscribe_ticker(&mut self, symbol: &str) -> ExchangeResult<()> {
+        let code = UpbitClient::to_market_code(symbol);
+        info!("Upbit 현재가 구독: {}", code);
+        self.update(|s| {
+            s.ticker.insert(code);
+        })
+        .await;
+        Ok(())
+    }
+
+    async fn subscribe_kline(
+        &mut self,
+        _symbol: &str,
+        _timeframe: Timeframe,
+    ) -> ExchangeResult<()> {
+        // 캔들은 REST get_klines 폴링으로 대체
+        warn!("Upbit 시세 스트림은 캔들스틱을 지원하지 않습니다");
+        Err(ExchangeError::NotSupported(
+            "Upbit does not support real-time kline streaming".to_string(),
+        ))
+    }
+
+    async fn subscribe_order_book(&mut self, symbol: &str) -> ExchangeResult<()> {
+        let code = UpbitClient::to_market_code(symbol);
+        info!("Upbit 호가 구독: {}", code);
+        self.update(|s| {
+            s.orderbook.insert(code);
+        })
+        .await;
+        Ok(())
+    }
+
+    async fn subscribe_trades(&mut self, symbol: &str) -> ExchangeResult<()> {
+        let code = UpbitClient::to_market_code(symbol);
+        info!("Upbit 체결 구독: {}", code);
+        self.update(|s| {
+            s.trade.insert(code);
+        })
+        .await;
+        Ok(())
+    }
+
+    async fn unsubscribe(&mut self, symbol: &str) -> ExchangeResult<()> {
+        let code = UpbitClient::to_market_code(symbol);
+        let mut removed = false;
+        self.update(|s| removed = s.remove(&code)).await;
+        if removed {
+            info!("Upbit 구독 해제: {}", code);
+        }
+        Ok(())
+    }
+
+    async fn next_event(&mut self) -> Option<MarketEvent> {
+        self.event_rx.recv().await
+    }
+}
+
+impl Drop for UpbitMarketStream {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+    use trader_core::Side;
+
+    #[test]
+    fn test_parse_ticker() {
+        let text = r#"{
+            "type": "ticker", "code": "KRW-BTC", "opening_price": 94000000,
+            "high_price": 96000000, "low_price": 93500000, "trade_price": 95000000,
+            "signed_change_price": 1000000, "signed_change_rate": 0.0106382979,
+            "acc_trade_volume_24h": 2345.678, "timestamp": 1709262000000,
+            "stream_type": "REALTIME"
+        }"#;
+
+        let Some(MarketEvent::Ticker(ticker)) = parse_message(text) else {
+            panic!("현재가 이벤트가 아님");
+        };
+        assert_eq!(ticker.ticker, "BTC/KRW");
+        assert_eq!(ticker.last, dec!(95000000));
+        assert_eq!(ticker.change_24h_percent, dec!(1.06382979));
+        assert_eq!(ticker.volume_24h, dec!(2345.678));
+    }
+
+    #[test]
+    fn test_parse_trade_and_orderbook() {
+        let trade = r#"{
+            "type": "trade", "code": "KRW-ETH", "trade_price": 5000000,
+            "trade_volume": 0.25, "ask_bid": "ASK", "trade_timestamp": 1709262000123,
+            "sequential_id": 17092620001230000
+        }"#;
+        let Some(MarketEvent::Trade(tick)) = parse_message(trade) else {
+            panic!("체결 이벤트가 아님");
+        };
+        assert_eq!(tick.ticker, "ETH/KRW");
+        assert_eq!(tick.side, Side::Sell);
+        assert_eq!(tick.quantity, dec!(0.25));
+
+        let orderbook = r#"{
+            "type": "orderbook", "code": "KRW-BTC", "timestamp": 1709262000000,
+            "orderbook_units": [
+                {"ask_price": 95010000, "bid_price": 95000000, "ask_size": 0.5, "bid_size": 1.2},
+                {"ask_price": 95020000, "bid_price": 94990000, "ask_size": 0.3, "bid_size": 0.7}
+            ]
+        }"#;
+        let Some(MarketEvent::OrderBook(book)) = parse_message(orderbook) else {
+            panic!("호가 이벤트가 아님");
+        };
+        assert_eq!(book.bids.len(), 2);
+        assert_eq!(book.bids[0].price, dec!(95000000));
+        assert_eq!(book.asks[1].quantity, dec!(0.3));
+    }
+
+    #[test]
+    fn test_parse_error_and_unknown() {
+        let error = r#"{"error": {"name": "INVALID_AUTH", "message": "인증 실패"}}"#;
+        assert!(matches!(parse_message(error), Some(MarketEvent::Error(_))));
+        assert!(parse_message(r#"{"status": "UP"}"#).is_none());
+        assert!(parse_message("not json").is_none());
+    }
+
+    #[test]
+    fn test_subscription_request() {
+        let mut subs = Subscriptions::default();
+        assert!(subs.request("t").is_none());
+
+        subs.ticker.insert("KRW-BTC".to_string());
+        subs.ticker.insert("KRW-ETH".to_string());
+        subs.orderbook.insert("KRW-BTC".to_string());
+
+        let request: serde_json::Value = serde_json::from_str(&subs.request("t").unwrap()).unwrap();
+        assert_eq!(
+            request,
+            serde_json::json!([
+                {"ticket": "t"},
+                {"type": "ticker", "codes": ["KRW-BTC", "KRW-ETH"]},
+                {"type": "orderbook", "codes": ["KRW-BTC"]},
+                {"format": "DEFAULT"}
+            ])
+        );
+
+        assert!(subs.remove("KRW-BTC"));
+        assert!(!subs.remove("KRW-BTC"));
+        assert_eq!(subs.ticker.len(), 1);
+        assert!(subs.orderbook.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_before_start() {
+        let mut stream = UpbitMarketStream::new(UpbitConfig::default());
+        stream.subscribe_ticker("BTC/KRW").await.unwrap();
+        stream.subscribe_trades("ETH/KRW").await.unwrap();
+        assert!(matches!(
+            stream.subscribe_kline("BTC/KRW", Timeframe::M1).await,
+            Err(ExchangeError::NotSupported(_))
+        ));
+
+        let subs = stream.subscriptions.read().await;
+        assert!(subs.ticker.contains("KRW-BTC"));
+        assert!(subs.trade.contains("KRW-ETH"));
+        assert!(!stream.is_running());
+    }
+}
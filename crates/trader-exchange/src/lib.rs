@@ -2,11 +2,13 @@
 //!
 //! 이 크레이트는 다음을 제공합니다:
 //! - Exchange trait: 통합 거래소 인터페이스
-//! - Binance 커넥터 (REST + WebSocket)
+//! - Binance 커넥터 (REST 주문/잔고, 시세 WebSocket, 사용자 데이터 스트림)
+//! - Upbit 커넥터 (원화 마켓 REST 주문/잔고, JWT 서명, 시세 WebSocket)
 //! - 시뮬레이션 거래소 (백테스팅 및 모의투자용)
 //! - 시장 데이터 정규화
 //! - Rate limiting 및 에러 처리
 //! - Circuit breaker: 장애 허용을 위한 회로 차단기
+//! - 거래소 커넥터 적합성 테스트 키트 (주문 수명주기, 멱등 취소, 잔고 일관성, 재연결)
 
 pub mod circuit_breaker;
 pub mod connector;
@@ -16,6 +18,7 @@ pub mod provider;
 pub mod retry;
 pub mod simulated;
 pub mod stream;
+pub mod testkit;
 pub mod traits;
 pub mod websocket;
 pub mod yahoo;
@@ -31,8 +34,10 @@ pub use retry::{
     with_retry, with_retry_context, with_retry_if, RetryConfig, RetryContext, RetryStats,
 };
 pub use simulated::{
-    DataFeed, DataFeedConfig, FillType, MatchingEngine, OrderMatch, SimulatedConfig,
-    SimulatedExchange, SimulatedMarketStream, SimulatedUserStream,
+    DataFeed, DataFeedConfig, FillModelConfig, FillType, FixedRateSlippage, MatchingEngine,
+    OrderMatch, SimulatedConfig, SimulatedExchange, SimulatedMarketStream, SimulatedUserStream,
+    SlippageContext, SlippageModel, SlippageModelConfig, SpreadSlippage, TickSizeRule,
+    VolumeParticipationSlippage,
 };
 pub use stream::{KisKrMarketStream, KisUsMarketStream, UnifiedMarketStream};
 pub use traits::*;
@@ -91,6 +91,18 @@ impl RetryConfig {
             .map(Duration::from_millis)
             .unwrap_or(self.base_delay);
 
+        self.delay_from(base, attempt)
+    }
+
+    /// 기본 대기 시간 기준 백오프 (`attempt` = 0부터 시작하는 재시도 순번).
+    ///
+    /// WebSocket 재연결처럼 에러별 대기 힌트가 없는 경우에 사용합니다.
+    pub fn backoff_delay(&self, attempt: u32) -> Duration {
+        self.delay_from(self.base_delay, attempt)
+    }
+
+    /// 기준 대기 시간에 지수 백오프, 최대값 제한, 지터 적용.
+    fn delay_from(&self, base: Duration, attempt: u32) -> Duration {
         // 지수 백오프 적용
         let delay = if self.use_exponential_backoff && attempt > 0 {
             let multiplier = self.backoff_multiplier.powi(attempt as i32);
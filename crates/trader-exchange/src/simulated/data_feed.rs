@@ -1,6 +1,7 @@
 //! 시뮬레이션 거래소를 위한 데이터 피드.
 //!
 //! 백테스팅을 위한 과거 데이터 로딩 및 재생을 관리합니다.
+//! 모의투자에서는 `push_kline()`으로 실시간 캔들을 직접 공급할 수 있습니다.
 
 #![allow(dead_code)] // 테스트/디버깅용 유틸리티 함수
 
@@ -14,6 +15,9 @@ use trader_core::{Kline, Ticker, Timeframe};
 
 use crate::ExchangeError;
 
+/// 실시간 캔들 최대 보관 수 (심볼별).
+const MAX_LIVE_KLINES: usize = 1000;
+
 /// 데이터 피드 설정.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DataFeedConfig {
@@ -189,6 +193,40 @@ impl DataFeed {
         }
     }
 
+    /// 실시간 캔들을 추가하고 재생 위치를 해당 캔들로 옮깁니다.
+    ///
+    /// 캔들 타임프레임과 무관하게 기본 타임프레임 데이터로 저장해 현재가 조회에 반영하며,
+    /// 심볼별로 최근 `MAX_LIVE_KLINES`개만 보관합니다.
+    pub fn push_kline(&mut self, kline: Kline) {
+        let ticker = kline.ticker.clone();
+        let open_time = kline.open_time;
+        let close_time = kline.close_time;
+
+        let data = self
+            .data
+            .entry((ticker.clone(), self.config.default_timeframe))
+            .or_default();
+        data.insert(
+            open_time,
+            DataEntry {
+                ticker: Self::kline_to_ticker(&kline),
+                kline,
+            },
+        );
+        while data.len() > MAX_LIVE_KLINES {
+            data.pop_first();
+        }
+
+        self.playback_position.insert(ticker, open_time);
+        if self.start_time.map_or(true, |start| open_time < start) {
+            self.start_time = Some(open_time);
+        }
+        if self.end_time.map_or(true, |end| close_time > end) {
+            self.end_time = Some(close_time);
+        }
+        self.current_time = Some(close_time);
+    }
+
     /// 재생을 처음으로 리셋합니다.
     pub fn reset(&mut self) {
         self.playback_position.clear();
@@ -399,17 +437,25 @@ pub fn generate_sample_klines(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use trader_testkit::{is_valid_ohlc, seed_from_str, KlineGenerator};
 
     fn create_test_symbol() -> String {
         "BTC/USDT".to_string()
     }
 
+    /// 재현 가능한 테스트 캔들.
+    fn create_test_klines(symbol: &str, count: usize) -> Vec<Kline> {
+        KlineGenerator::new(symbol, seed_from_str(symbol))
+            .timeframe(Timeframe::M1)
+            .start_price(dec!(50000))
+            .generate(count)
+    }
+
     #[test]
     fn test_load_klines() {
         let mut feed = DataFeed::new(DataFeedConfig::default());
         let symbol = create_test_symbol();
-        let klines =
-            generate_sample_klines(symbol.clone(), Timeframe::M1, 100, dec!(50000), dec!(0.02));
+        let klines = create_test_klines(&symbol, 100);
 
         feed.load_klines(symbol.clone(), Timeframe::M1, klines);
 
@@ -421,8 +467,7 @@ mod tests {
     fn test_playback() {
         let mut feed = DataFeed::new(DataFeedConfig::default());
         let symbol = create_test_symbol();
-        let klines =
-            generate_sample_klines(symbol.clone(), Timeframe::M1, 10, dec!(50000), dec!(0.02));
+        let klines = create_test_klines(&symbol, 10);
 
         feed.load_klines(symbol.clone(), Timeframe::M1, klines);
 
@@ -447,8 +492,7 @@ mod tests {
     fn test_historical_klines() {
         let mut feed = DataFeed::new(DataFeedConfig::default());
         let symbol = create_test_symbol();
-        let klines =
-            generate_sample_klines(symbol.clone(), Timeframe::M1, 100, dec!(50000), dec!(0.02));
+        let klines = create_test_klines(&symbol, 100);
 
         feed.load_klines(symbol.clone(), Timeframe::M1, klines);
 
@@ -462,6 +506,23 @@ mod tests {
         assert_eq!(historical.len(), 20);
     }
 
+    #[test]
+    fn test_generate_sample_klines() {
+        let klines = generate_sample_klines(
+            create_test_symbol(),
+            Timeframe::M5,
+            50,
+            dec!(100),
+            dec!(0.02),
+        );
+
+        assert_eq!(klines.len(), 50);
+        assert!(klines.iter().all(is_valid_ohlc));
+        assert!(klines
+            .windows(2)
+            .all(|w| w[1].open_time - w[0].open_time == Duration::minutes(5)));
+    }
+
     #[test]
     fn test_loop_data() {
         let config = DataFeedConfig {
@@ -470,8 +531,7 @@ mod tests {
         };
         let mut feed = DataFeed::new(config);
         let symbol = create_test_symbol();
-        let klines =
-            generate_sample_klines(symbol.clone(), Timeframe::M1, 5, dec!(50000), dec!(0.02));
+        let klines = create_test_klines(&symbol, 5);
 
         feed.load_klines(symbol.clone(), Timeframe::M1, klines);
 
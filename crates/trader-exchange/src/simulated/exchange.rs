@@ -19,7 +19,9 @@ use crate::traits::{AccountInfo, Balance, Exchange, ExchangeResult, MarketEvent,
 use crate::ExchangeError;
 
 use super::data_feed::{DataFeed, DataFeedConfig};
+use super::fill_model::FillModelConfig;
 use super::matching_engine::{FillType, MatchingEngine, OrderMatch};
+use super::slippage::{SlippageModelConfig, TickSizeRule};
 use super::stream::{EventBroadcaster, SimulatedMarketStream, SimulatedUserStream};
 
 /// 시뮬레이션 거래소 설정.
@@ -31,6 +33,15 @@ pub struct SimulatedConfig {
     pub fee_rate: Decimal,
     /// 시장가 주문의 슬리피지율
     pub slippage_rate: Decimal,
+    /// 슬리피지 모델 (없으면 `slippage_rate` 고정 비율)
+    #[serde(default)]
+    pub slippage_model: Option<SlippageModelConfig>,
+    /// 호가 단위 규칙 (체결가 라운딩, 스프레드 슬리피지에 사용)
+    #[serde(default)]
+    pub tick_size: Option<TickSizeRule>,
+    /// 부분 체결 및 지정가 대기열 설정 (기본값은 조건 도달 시 전량 체결)
+    #[serde(default)]
+    pub fill_model: FillModelConfig,
     /// 포지션 추적 활성화 여부
     pub enable_positions: bool,
     /// 데이터 피드 설정
@@ -46,6 +57,9 @@ impl Default for SimulatedConfig {
             initial_balances,
             fee_rate: dec!(0.001),       // 0.1%
             slippage_rate: dec!(0.0005), // 0.05%
+            slippage_model: None,
+            tick_size: None,
+            fill_model: FillModelConfig::default(),
             enable_positions: false,
             data_feed_config: DataFeedConfig::default(),
         }
@@ -70,6 +84,37 @@ impl SimulatedConfig {
         self.slippage_rate = rate;
         self
     }
+
+    /// 슬리피지 모델을 설정합니다.
+    pub fn with_slippage_model(mut self, model: SlippageModelConfig) -> Self {
+        self.slippage_model = Some(model);
+        self
+    }
+
+    /// 호가 단위 규칙을 설정합니다.
+    pub fn with_tick_size(mut self, rule: TickSizeRule) -> Self {
+        self.tick_size = Some(rule);
+        self
+    }
+
+    /// 부분 체결 및 대기열 설정을 지정합니다.
+    pub fn with_fill_model(mut self, config: FillModelConfig) -> Self {
+        self.fill_model = config;
+        self
+    }
+
+    /// 설정으로 매칭 엔진 생성.
+    fn build_matching_engine(&self) -> MatchingEngine {
+        let mut engine =
+            MatchingEngine::new(self.fee_rate, self.slippage_rate).with_fill_model(self.fill_model);
+        if let Some(model) = &self.slippage_model {
+            engine = engine.with_slippage_model(model.build());
+        }
+        if let Some(rule) = &self.tick_size {
+            engine = engine.with_tick_size_provider(rule.build());
+        }
+        engine
+    }
 }
 
 /// 내부 계정 상태.
@@ -139,6 +184,8 @@ struct OrderState {
     filled_quantity: Decimal,
     /// 평균 체결 가격
     average_price: Option<Decimal>,
+    /// 매수 잔고 잠금 단가 (지정가 또는 주문 시점 현재가)
+    lock_price: Decimal,
     /// 생성 시각
     created_at: DateTime<Utc>,
     /// 갱신 시각
@@ -184,10 +231,15 @@ pub struct SimulatedExchange {
 
 impl SimulatedExchange {
     /// ticker String에서 quote 통화를 추출합니다 (예: "BTC/USDT" -> "USDT").
-    fn parse_quote(ticker: &str) -> String {
+    pub fn parse_quote(ticker: &str) -> String {
         ticker.split('/').nth(1).unwrap_or("USDT").to_string()
     }
 
+    /// ticker String에서 base 자산을 추출합니다 (예: "BTC/USDT" -> "BTC", "005930" -> "005930").
+    pub fn parse_base(ticker: &str) -> String {
+        ticker.split('/').next().unwrap_or(ticker).to_string()
+    }
+
     /// ticker String을 Symbol로 변환합니다 (시뮬레이션용).
     fn ticker_to_symbol(ticker: &str) -> Symbol {
         let parts: Vec<&str> = ticker.split('/').collect();
@@ -202,7 +254,7 @@ impl SimulatedExchange {
     pub fn new(config: SimulatedConfig) -> Self {
         let account = AccountState::new(&config.initial_balances);
         let data_feed = DataFeed::new(config.data_feed_config.clone());
-        let matching_engine = MatchingEngine::new(config.fee_rate, config.slippage_rate);
+        let matching_engine = config.build_matching_engine();
 
         Self {
             config,
@@ -253,15 +305,38 @@ impl SimulatedExchange {
             feed.next_kline(symbol, timeframe)?
         };
 
+        self.settle_kline(symbol, &kline).await;
+        Some(kline)
+    }
+
+    /// 실시간 캔들을 반영합니다 (모의투자).
+    ///
+    /// 과거 데이터 재생 대신 외부 시세로 캔들을 추가하고, 대기 주문을 이 캔들로 매칭합니다.
+    /// 틱 시세는 시가=고가=저가=종가인 캔들로 전달합니다.
+    ///
+    /// # Returns
+    ///
+    /// 이 캔들로 체결되거나 만료된 주문 매칭 결과
+    pub async fn feed_kline(&self, kline: Kline) -> Vec<OrderMatch> {
+        {
+            let mut feed = self.data_feed.write().await;
+            feed.push_kline(kline.clone());
+        }
+        let symbol = kline.ticker.clone();
+        self.settle_kline(&symbol, &kline).await
+    }
+
+    /// 대기 주문을 캔들로 매칭하고 시장 이벤트를 브로드캐스트합니다.
+    async fn settle_kline(&self, symbol: &str, kline: &Kline) -> Vec<OrderMatch> {
         // 대기 중인 주문 처리
         let matches = {
             let mut engine = self.matching_engine.write().await;
-            engine.process_kline(&symbol.to_string(), &kline)
+            engine.process_kline(&symbol.to_string(), kline)
         };
 
         // 주문 매칭 결과를 계정에 적용
-        for order_match in matches {
-            self.apply_order_match(&order_match).await;
+        for order_match in &matches {
+            self.apply_order_match(order_match).await;
         }
 
         // 시장 이벤트 브로드캐스트
@@ -270,12 +345,12 @@ impl SimulatedExchange {
             .await;
 
         // 티커 업데이트도 브로드캐스트
-        let ticker = self.kline_to_ticker(&kline);
+        let ticker = self.kline_to_ticker(kline);
         self.market_broadcaster
             .broadcast(MarketEvent::Ticker(ticker))
             .await;
 
-        Some(kline)
+        matches
     }
 
     /// 데이터가 소진될 때까지 시뮬레이션을 실행합니다.
@@ -355,6 +430,8 @@ impl SimulatedExchange {
     }
 
     /// 주문 매칭 결과를 계정에 적용합니다.
+    ///
+    /// 체결 수량과 만료 잔량만큼 잔고 잠금을 해제하고, 누적 체결 수량과 평균 체결가를 갱신합니다.
     async fn apply_order_match(&self, order_match: &OrderMatch) {
         let mut account = self.account.write().await;
 
@@ -377,58 +454,57 @@ impl SimulatedExchange {
                 (ticker.as_str(), "USDT") // 기본값
             };
 
+            let filled = order_match.filled_quantity;
+            let released = filled + order_match.expired_quantity;
+
             match request.side {
                 Side::Buy => {
-                    // 견적 통화 차감, 기준 통화 추가
-                    let total_cost = order_match.filled_quantity * order_match.fill_price
-                        + order_match.commission;
-                    account.update_balance(quote, -total_cost, dec!(0));
-                    account.update_balance(base, order_match.filled_quantity, dec!(0));
+                    // 잠금 해제 후 견적 통화 차감, 기준 통화 추가
+                    let unlocked = released * order_state.lock_price;
+                    let total_cost = filled * order_match.fill_price + order_match.commission;
+                    account.update_balance(quote, unlocked - total_cost, -unlocked);
+                    account.update_balance(base, filled, dec!(0));
                 }
                 Side::Sell => {
-                    // 기준 통화 차감, 견적 통화 추가
-                    let total_received = order_match.filled_quantity * order_match.fill_price
-                        - order_match.commission;
-                    account.update_balance(base, -order_match.filled_quantity, dec!(0));
+                    // 잠금 해제 후 기준 통화 차감 (만료 잔량은 반환), 견적 통화 추가
+                    let total_received = filled * order_match.fill_price - order_match.commission;
+                    account.update_balance(base, released - filled, -released);
                     account.update_balance(quote, total_received, dec!(0));
                 }
             }
 
             // 주문 상태 업데이트
-            {
+            let updated_status = {
                 let mut orders = self.orders.write().await;
-                if let Some(state) = orders.get_mut(&order_match.order_id) {
-                    state.status_type = if order_match.fill_type == FillType::Full {
+                orders.get_mut(&order_match.order_id).map(|state| {
+                    let total_filled = state.filled_quantity + filled;
+                    if filled > dec!(0) {
+                        let prev_value =
+                            state.filled_quantity * state.average_price.unwrap_or(dec!(0));
+                        state.average_price =
+                            Some((prev_value + filled * order_match.fill_price) / total_filled);
+                    }
+                    state.filled_quantity = total_filled;
+                    state.status_type = if order_match.expired_quantity > dec!(0) {
+                        OrderStatusType::Expired
+                    } else if total_filled >= state.request.quantity {
                         OrderStatusType::Filled
-                    } else {
+                    } else if total_filled > dec!(0) {
                         OrderStatusType::PartiallyFilled
+                    } else {
+                        OrderStatusType::Open
                     };
-                    state.filled_quantity = order_match.filled_quantity;
-                    state.average_price = Some(order_match.fill_price);
                     state.updated_at = order_match.timestamp;
-                }
-            }
+                    state.to_order_status()
+                })
+            };
 
             // 사용자 이벤트 브로드캐스트 - 주문 업데이트
-            let updated_status = OrderStatus {
-                order_id: order_match.order_id.clone(),
-                client_order_id: request.client_order_id.clone(),
-                ticker: Some(request.ticker.clone()),
-                side: Some(request.side),
-                quantity: Some(request.quantity),
-                price: request.price, // 이미 Option<Price>
-                status: if order_match.fill_type == FillType::Full {
-                    OrderStatusType::Filled
-                } else {
-                    OrderStatusType::PartiallyFilled
-                },
-                filled_quantity: order_match.filled_quantity,
-                average_price: Some(order_match.fill_price),
-                updated_at: order_match.timestamp,
-            };
-            self.user_broadcaster
-                .broadcast(UserEvent::OrderUpdate(updated_status))
-                .await;
+            if let Some(updated_status) = updated_status {
+                self.user_broadcaster
+                    .broadcast(UserEvent::OrderUpdate(updated_status))
+                    .await;
+            }
 
             // 잔고 업데이트 브로드캐스트
             let quote_balance = account.get_balance(quote);
@@ -437,8 +513,10 @@ impl SimulatedExchange {
                 .await;
         }
 
-        // 주문 이력에 추가
-        account.order_history.push(order_match.clone());
+        // 체결이 있으면 주문 이력에 추가
+        if order_match.fill_type != FillType::None {
+            account.order_history.push(order_match.clone());
+        }
     }
 
     /// Kline을 티커로 변환합니다.
@@ -477,22 +555,20 @@ impl SimulatedExchange {
         }
 
         match request.order_type {
-            OrderType::Limit => {
-                if request.price.is_none() {
-                    return Err(ExchangeError::OrderRejected(
-                        "Limit order requires price".into(),
-                    ));
-                }
+            OrderType::Limit if request.price.is_none() => {
+                return Err(ExchangeError::OrderRejected(
+                    "Limit order requires price".into(),
+                ));
             }
             OrderType::StopLoss
             | OrderType::StopLossLimit
             | OrderType::TakeProfit
-            | OrderType::TakeProfitLimit => {
-                if request.stop_price.is_none() {
-                    return Err(ExchangeError::OrderRejected(
-                        "Stop order requires stop price".into(),
-                    ));
-                }
+            | OrderType::TakeProfitLimit
+                if request.stop_price.is_none() =>
+            {
+                return Err(ExchangeError::OrderRejected(
+                    "Stop order requires stop price".into(),
+                ));
             }
             _ => {}
         }
@@ -658,11 +734,12 @@ impl Exchange for SimulatedExchange {
                     }
                 }
                 Side::Sell => {
-                    let balance = account.get_balance(&request.ticker);
+                    let base = Self::parse_base(&request.ticker);
+                    let balance = account.get_balance(&base);
                     if balance.free < request.quantity {
                         return Err(ExchangeError::InsufficientBalance(format!(
                             "Need {} {}, have {}",
-                            request.quantity, request.ticker, balance.free
+                            request.quantity, base, balance.free
                         )));
                     }
                 }
@@ -682,7 +759,11 @@ impl Exchange for SimulatedExchange {
                     );
                 }
                 Side::Sell => {
-                    account.update_balance(&request.ticker, -request.quantity, request.quantity);
+                    account.update_balance(
+                        &Self::parse_base(&request.ticker),
+                        -request.quantity,
+                        request.quantity,
+                    );
                 }
             }
         }
@@ -699,24 +780,14 @@ impl Exchange for SimulatedExchange {
 
         let order_id = order_match.order_id.clone();
 
-        // 초기 상태 결정
-        let initial_status = match order_match.fill_type {
-            FillType::Full => OrderStatusType::Filled,
-            FillType::Partial => OrderStatusType::PartiallyFilled,
-            FillType::None => OrderStatusType::Open,
-        };
-
-        // 주문 상태 생성
+        // 주문 상태 생성 (체결 결과는 아래에서 적용)
         let order_state = OrderState {
             request: request.clone(),
             order_id: order_id.clone(),
-            status_type: initial_status,
-            filled_quantity: order_match.filled_quantity,
-            average_price: if order_match.fill_type != FillType::None {
-                Some(order_match.fill_price)
-            } else {
-                None
-            },
+            status_type: OrderStatusType::Open,
+            filled_quantity: dec!(0),
+            average_price: None,
+            lock_price: request.price.unwrap_or(current_price),
             created_at: order_match.timestamp,
             updated_at: order_match.timestamp,
         };
@@ -727,30 +798,8 @@ impl Exchange for SimulatedExchange {
             orders.insert(order_id.clone(), order_state);
         }
 
-        // 즉시 체결된 경우, 매칭 적용
-        if order_match.fill_type != FillType::None {
-            // 먼저 잔고 잠금 해제 (위에서 잠갔으므로)
-            {
-                let mut account = self.account.write().await;
-                match request.side {
-                    Side::Buy => {
-                        let required = request.quantity * request.price.unwrap_or(current_price);
-                        account.update_balance(
-                            &Self::parse_quote(&request.ticker),
-                            required,
-                            -required,
-                        );
-                    }
-                    Side::Sell => {
-                        account.update_balance(
-                            &request.ticker,
-                            request.quantity,
-                            -request.quantity,
-                        );
-                    }
-                }
-            }
-
+        // 즉시 체결되었거나 유효 기간 조건으로 만료된 경우, 매칭 적용
+        if order_match.fill_type != FillType::None || order_match.expired_quantity > dec!(0) {
             self.apply_order_match(&order_match).await;
         }
 
@@ -775,12 +824,13 @@ impl Exchange for SimulatedExchange {
                 state.status_type = OrderStatusType::Cancelled;
                 state.updated_at = Utc::now();
 
-                // 잔고 잠금 해제
+                // 미체결 잔량의 잔고 잠금 해제
                 let mut account = self.account.write().await;
                 let request = &state.request;
+                let remaining = request.quantity - state.filled_quantity;
                 match request.side {
                     Side::Buy => {
-                        let locked = request.quantity * request.price.unwrap_or(dec!(0));
+                        let locked = remaining * state.lock_price;
                         account.update_balance(
                             &Self::parse_quote(&request.ticker),
                             locked,
@@ -789,9 +839,9 @@ impl Exchange for SimulatedExchange {
                     }
                     Side::Sell => {
                         account.update_balance(
-                            &request.ticker,
-                            request.quantity,
-                            -request.quantity,
+                            &Self::parse_base(&request.ticker),
+                            remaining,
+                            -remaining,
                         );
                     }
                 }
@@ -867,7 +917,7 @@ mod tests {
         let config = SimulatedConfig::default();
         let exchange = SimulatedExchange::new(config);
         let symbol = create_test_symbol();
-        let ticker = symbol.to_string();
+        let _ticker = symbol.to_string();
 
         // 샘플 데이터 로드
         let klines = generate_sample_klines(
@@ -937,6 +987,44 @@ mod tests {
         assert!(btc_balance.free > dec!(0));
     }
 
+    #[test]
+    fn test_config_slippage_model() {
+        // 기존 설정(JSON)은 모델 없이 고정 비율 슬리피지
+        let json = serde_json::to_value(SimulatedConfig::default()).unwrap();
+        let mut legacy = json.as_object().unwrap().clone();
+        legacy.remove("slippage_model");
+        legacy.remove("tick_size");
+        let config: SimulatedConfig = serde_json::from_value(legacy.into()).unwrap();
+        assert!(config.slippage_model.is_none());
+
+        let config = SimulatedConfig::default()
+            .with_slippage_model(SlippageModelConfig::Spread {
+                spread_ticks: dec!(2),
+                fallback_rate: dec!(0.0005),
+            })
+            .with_tick_size(TickSizeRule::Krx);
+        let json = serde_json::to_string(&config).unwrap();
+        let parsed: SimulatedConfig = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.slippage_model, config.slippage_model);
+        assert_eq!(parsed.tick_size, Some(TickSizeRule::Krx));
+
+        // 70,000원 시장가 매수: 호가 단위 100원, 스프레드 2호가의 절반
+        let mut engine = parsed.build_matching_engine();
+        let request = OrderRequest {
+            ticker: "005930".to_string(),
+            side: Side::Buy,
+            order_type: OrderType::Market,
+            quantity: dec!(10),
+            price: None,
+            stop_price: None,
+            time_in_force: TimeInForce::GTC,
+            client_order_id: None,
+            strategy_id: None,
+        };
+        let fill = engine.submit_order(&request, dec!(70000), Utc::now());
+        assert_eq!(fill.fill_price, dec!(70100));
+    }
+
     #[tokio::test]
     async fn test_limit_order_pending() {
         let config = SimulatedConfig::default().with_initial_balance("USDT", dec!(100000));
@@ -984,6 +1072,138 @@ mod tests {
         assert_eq!(open_orders.len(), 1);
     }
 
+    /// 가격 100, 거래량 100인 캔들로 부분 체결 테스트용 거래소 생성.
+    async fn create_partial_fill_exchange() -> SimulatedExchange {
+        let config = SimulatedConfig::default()
+            .with_initial_balance("USDT", dec!(1000000))
+            .with_fee_rate(dec!(0))
+            .with_slippage_rate(dec!(0))
+            .with_fill_model(FillModelConfig::default().with_max_participation(dec!(0.1)));
+        let exchange = SimulatedExchange::new(config);
+
+        let start = Utc::now();
+        let klines = (0..5)
+            .map(|i| Kline {
+                ticker: "005930".to_string(),
+                timeframe: Timeframe::M1,
+                open_time: start + chrono::Duration::minutes(i),
+                close_time: start + chrono::Duration::minutes(i + 1),
+                open: dec!(100),
+                high: dec!(100),
+                low: dec!(100),
+                close: dec!(100),
+                volume: dec!(100),
+                quote_volume: None,
+                num_trades: None,
+            })
+            .collect();
+        exchange
+            .load_klines("005930".to_string(), Timeframe::M1, klines)
+            .await;
+        exchange.step("005930", Timeframe::M1).await;
+        exchange
+    }
+
+    #[tokio::test]
+    async fn test_partial_fill_across_klines() {
+        let exchange = create_partial_fill_exchange().await;
+        let request = OrderRequest::market_buy("005930".to_string(), dec!(25));
+
+        // 캔들 거래량 100의 10%만 즉시 체결, 잔량은 잠금 유지
+        let order_id = exchange.place_order(&request).await.unwrap();
+        let status = exchange.get_order("005930", &order_id).await.unwrap();
+        assert_eq!(status.status, OrderStatusType::PartiallyFilled);
+        assert_eq!(status.filled_quantity, dec!(10));
+        let usdt = exchange.get_balance("USDT").await.unwrap();
+        assert_eq!(usdt.free, dec!(997500));
+        assert_eq!(usdt.locked, dec!(1500));
+
+        exchange.step("005930", Timeframe::M1).await;
+        exchange.step("005930", Timeframe::M1).await;
+
+        let status = exchange.get_order("005930", &order_id).await.unwrap();
+        assert_eq!(status.status, OrderStatusType::Filled);
+        assert_eq!(status.filled_quantity, dec!(25));
+        assert_eq!(status.average_price, Some(dec!(100)));
+        let usdt = exchange.get_balance("USDT").await.unwrap();
+        assert_eq!(usdt.free, dec!(997500));
+        assert_eq!(usdt.locked, dec!(0));
+        assert_eq!(exchange.get_balance("005930").await.unwrap().free, dec!(25));
+    }
+
+    #[tokio::test]
+    async fn test_ioc_order_expires_remainder() {
+        let exchange = create_partial_fill_exchange().await;
+        let mut request = OrderRequest::market_buy("005930".to_string(), dec!(25));
+        request.order_type = OrderType::Limit;
+        request.price = Some(dec!(100));
+        request.time_in_force = TimeInForce::IOC;
+
+        let order_id = exchange.place_order(&request).await.unwrap();
+        let status = exchange.get_order("005930", &order_id).await.unwrap();
+        assert_eq!(status.status, OrderStatusType::Expired);
+        assert_eq!(status.filled_quantity, dec!(10));
+        assert!(exchange
+            .get_open_orders(Some("005930"))
+            .await
+            .unwrap()
+            .is_empty());
+
+        // 만료된 잔량의 잠금 해제
+        let usdt = exchange.get_balance("USDT").await.unwrap();
+        assert_eq!(usdt.free, dec!(999000));
+        assert_eq!(usdt.locked, dec!(0));
+    }
+
+    #[tokio::test]
+    async fn test_feed_live_kline_fills_pending_orders() {
+        let config = SimulatedConfig::default()
+            .with_initial_balance("USDT", dec!(100000))
+            .with_fee_rate(dec!(0))
+            .with_slippage_rate(dec!(0));
+        let exchange = SimulatedExchange::new(config);
+        let start = Utc::now();
+        let kline = |minute: i64, price: Decimal| Kline {
+            ticker: "BTC/USDT".to_string(),
+            timeframe: Timeframe::M1,
+            open_time: start + chrono::Duration::minutes(minute),
+            open: price,
+            high: price,
+            low: price,
+            close: price,
+            volume: dec!(100),
+            close_time: start + chrono::Duration::minutes(minute + 1),
+            quote_volume: None,
+            num_trades: None,
+        };
+
+        // 시세가 들어오기 전에는 주문 불가
+        let buy = OrderRequest::market_buy("BTC/USDT".to_string(), dec!(1));
+        assert!(exchange.place_order(&buy).await.is_err());
+
+        exchange.feed_kline(kline(0, dec!(50000))).await;
+        exchange.place_order(&buy).await.unwrap();
+        assert_eq!(exchange.get_balance("BTC").await.unwrap().free, dec!(1));
+
+        // 지정가 매도는 base 자산을 잠그고 다음 실시간 캔들에서 체결
+        let mut sell = OrderRequest::market_sell("BTC/USDT".to_string(), dec!(1));
+        sell.order_type = OrderType::Limit;
+        sell.price = Some(dec!(51000));
+        let order_id = exchange.place_order(&sell).await.unwrap();
+        assert_eq!(exchange.get_balance("BTC").await.unwrap().locked, dec!(1));
+
+        let matches = exchange.feed_kline(kline(1, dec!(51500))).await;
+        assert_eq!(matches.len(), 1);
+        let status = exchange.get_order("BTC/USDT", &order_id).await.unwrap();
+        assert_eq!(status.status, OrderStatusType::Filled);
+        let btc = exchange.get_balance("BTC").await.unwrap();
+        assert_eq!((btc.free, btc.locked), (dec!(0), dec!(0)));
+        assert_eq!(
+            exchange.get_balance("USDT").await.unwrap().free,
+            dec!(101000)
+        );
+    }
+
     #[tokio::test]
     async fn test_cancel_order() {
         let config = SimulatedConfig::default().with_initial_balance("USDT", dec!(100000));
@@ -0,0 +1,138 @@
+//! 시뮬레이션 부분 체결 및 지정가 대기열 모델.
+//!
+//! 캔들 하나에서 체결될 수 있는 물량을 제한하여 대량 주문과 그리드 전략의
+//! 체결이 실제 거래소(KIS, Binance)처럼 여러 캔들에 나뉘도록 합니다.
+//! - 거래량 참여율: 심볼별로 캔들 거래량 × `max_participation`까지만 체결 (시간 우선 순서로 배분)
+//! - 대기열 위치: 새 지정가 주문 앞에 직전 캔들 거래량 × `queue_ahead_ratio`만큼 대기 물량이 있다고
+//!   가정하고, 가격을 터치만 한 캔들의 거래량으로 이를 먼저 소진한 뒤 체결합니다.
+//!   가격을 관통한 캔들은 해당 호가가 모두 소진된 것으로 봅니다.
+//!
+//! 기본값은 두 기능 모두 꺼져 있어 기존처럼 조건 도달 시 전량 체결됩니다.
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use trader_core::{Kline, Side};
+
+/// 부분 체결 및 대기열 설정.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FillModelConfig {
+    /// 캔들 거래량 대비 최대 체결 비율 (예: 10%의 경우 0.1, 없으면 제한 없음)
+    pub max_participation: Option<Decimal>,
+    /// 새 지정가 주문 앞 대기 물량 (직전 캔들 거래량 대비 비율, 0이면 대기열 추정 안 함)
+    pub queue_ahead_ratio: Decimal,
+}
+
+impl FillModelConfig {
+    /// 거래량 참여율 제한을 설정합니다.
+    pub fn with_max_participation(mut self, rate: Decimal) -> Self {
+        self.max_participation = Some(rate);
+        self
+    }
+
+    /// 대기열 추정 비율을 설정합니다.
+    pub fn with_queue_ahead_ratio(mut self, ratio: Decimal) -> Self {
+        self.queue_ahead_ratio = ratio;
+        self
+    }
+
+    /// 대기열 추정 사용 여부.
+    pub fn tracks_queue(&self) -> bool {
+        self.queue_ahead_ratio > Decimal::ZERO
+    }
+
+    /// 캔들 하나에서 체결 가능한 총 물량 (제한이 없거나 캔들을 모르면 `None`).
+    pub fn volume_budget(&self, kline: Option<&Kline>) -> Option<Decimal> {
+        let rate = self.max_participation?;
+        let kline = kline?;
+        Some((kline.volume * rate).max(Decimal::ZERO))
+    }
+
+    /// 새 지정가 주문 앞의 대기 물량 추정치.
+    pub fn initial_queue_ahead(&self, kline: Option<&Kline>) -> Decimal {
+        if !self.tracks_queue() {
+            return Decimal::ZERO;
+        }
+        kline
+            .map(|k| k.volume * self.queue_ahead_ratio)
+            .unwrap_or(Decimal::ZERO)
+    }
+}
+
+/// 캔들이 지정가를 관통했는지 여부 (터치만 한 경우 `false`).
+pub fn trades_through(side: Side, limit_price: Decimal, kline: &Kline) -> bool {
+    match side {
+        Side::Buy => kline.low < limit_price,
+        Side::Sell => kline.high > limit_price,
+    }
+}
+
+/// 가격을 터치만 한 캔들에서 대기열을 소진하고 남은 호가 물량.
+///
+/// `queue_ahead`를 캔들 거래량만큼 줄이고, 대기열을 넘어선 거래량을 반환합니다.
+pub fn consume_queue(queue_ahead: &mut Decimal, volume: Decimal) -> Decimal {
+    let volume = volume.max(Decimal::ZERO);
+    let consumed = (*queue_ahead).min(volume);
+    *queue_ahead -= consumed;
+    volume - consumed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use rust_decimal_macros::dec;
+    use trader_core::Timeframe;
+
+    fn kline(low: Decimal, high: Decimal, volume: Decimal) -> Kline {
+        let now = Utc::now();
+        Kline {
+            ticker: "005930".to_string(),
+            timeframe: Timeframe::M1,
+            open_time: now,
+            close_time: now,
+            open: low,
+            high,
+            low,
+            close: high,
+            volume,
+            quote_volume: None,
+            num_trades: None,
+        }
+    }
+
+    #[test]
+    fn test_default_is_unlimited() {
+        let config = FillModelConfig::default();
+        let bar = kline(dec!(100), dec!(110), dec!(1000));
+        assert_eq!(config.volume_budget(Some(&bar)), None);
+        assert_eq!(config.initial_queue_ahead(Some(&bar)), dec!(0));
+        assert!(!config.tracks_queue());
+
+        let parsed: FillModelConfig = serde_json::from_str("{}").unwrap();
+        assert_eq!(parsed, config);
+    }
+
+    #[test]
+    fn test_budget_and_queue() {
+        let config = FillModelConfig::default()
+            .with_max_participation(dec!(0.1))
+            .with_queue_ahead_ratio(dec!(0.5));
+        let bar = kline(dec!(100), dec!(110), dec!(1000));
+        assert_eq!(config.volume_budget(Some(&bar)), Some(dec!(100)));
+        assert_eq!(config.volume_budget(None), None);
+        assert_eq!(config.initial_queue_ahead(Some(&bar)), dec!(500));
+
+        assert!(trades_through(Side::Buy, dec!(105), &bar));
+        assert!(!trades_through(Side::Buy, dec!(100), &bar));
+        assert!(!trades_through(Side::Sell, dec!(110), &bar));
+
+        // 대기 물량 500 중 300 소진, 남은 호가 물량 없음
+        let mut queue = dec!(500);
+        assert_eq!(consume_queue(&mut queue, dec!(300)), dec!(0));
+        assert_eq!(queue, dec!(200));
+        // 남은 200 소진 후 100이 내 주문 몫
+        assert_eq!(consume_queue(&mut queue, dec!(300)), dec!(100));
+        assert_eq!(queue, dec!(0));
+    }
+}
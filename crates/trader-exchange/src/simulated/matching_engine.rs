@@ -5,7 +5,12 @@ use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 use std::collections::HashMap;
 use std::sync::Arc;
-use trader_core::{Kline, OrderRequest, OrderType, RoundMethod, Side, TickSizeProvider};
+use trader_core::{
+    Kline, OrderRequest, OrderType, RoundMethod, Side, TickSizeProvider, TimeInForce,
+};
+
+use super::fill_model::{consume_queue, trades_through, FillModelConfig};
+use super::slippage::{FixedRateSlippage, SlippageContext, SlippageModel};
 
 /// 주문 체결 유형.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -35,6 +40,8 @@ pub struct OrderMatch {
     pub commission_asset: String,
     /// 체결 타임스탬프
     pub timestamp: DateTime<Utc>,
+    /// 유효 기간 조건(IOC/FOK)으로 취소된 잔량
+    pub expired_quantity: Decimal,
 }
 
 /// 매칭 엔진의 대기 주문.
@@ -58,6 +65,10 @@ pub struct PendingOrder {
     pub stop_price: Option<Decimal>,
     /// 생성 타임스탬프
     pub created_at: DateTime<Utc>,
+    /// 앞에 대기 중인 물량 추정치 (지정가 주문용)
+    pub queue_ahead: Decimal,
+    /// 스탑/이익실현 조건 도달 여부 (부분 체결 후 잔량 처리용)
+    pub triggered: bool,
 }
 
 /// 시뮬레이션 거래소를 위한 주문 매칭 엔진.
@@ -66,26 +77,46 @@ pub struct MatchingEngine {
     pending_orders: HashMap<String, Vec<PendingOrder>>,
     /// 수수료율 (예: 0.1%의 경우 0.001)
     fee_rate: Decimal,
-    /// 슬리피지율 (예: 0.05%의 경우 0.0005)
-    slippage_rate: Decimal,
+    /// 시장가 체결 슬리피지 모델
+    slippage_model: Arc<dyn SlippageModel>,
     /// 호가 단위 제공자 (옵션)
     tick_size_provider: Option<Arc<dyn TickSizeProvider>>,
+    /// 부분 체결 및 대기열 설정
+    fill_model: FillModelConfig,
+    /// 심볼별 마지막 처리 캔들 (시장가 주문 슬리피지의 거래량 참조)
+    last_klines: HashMap<String, Kline>,
     /// 주문 ID 카운터
     next_order_id: u64,
 }
 
 impl MatchingEngine {
-    /// 새로운 매칭 엔진을 생성합니다.
+    /// 새로운 매칭 엔진을 생성합니다 (고정 비율 슬리피지).
     pub fn new(fee_rate: Decimal, slippage_rate: Decimal) -> Self {
         Self {
             pending_orders: HashMap::new(),
             fee_rate,
-            slippage_rate,
+            slippage_model: Arc::new(FixedRateSlippage {
+                rate: slippage_rate,
+            }),
             tick_size_provider: None,
+            fill_model: FillModelConfig::default(),
+            last_klines: HashMap::new(),
             next_order_id: 1,
         }
     }
 
+    /// 슬리피지 모델을 설정합니다.
+    pub fn with_slippage_model(mut self, model: Arc<dyn SlippageModel>) -> Self {
+        self.slippage_model = model;
+        self
+    }
+
+    /// 부분 체결 및 대기열 설정을 지정합니다.
+    pub fn with_fill_model(mut self, config: FillModelConfig) -> Self {
+        self.fill_model = config;
+        self
+    }
+
     /// ticker String에서 quote 통화를 추출합니다 (예: "BTC/USDT" -> "USDT").
     fn parse_quote(ticker: &str) -> String {
         ticker.split('/').nth(1).unwrap_or("USDT").to_string()
@@ -106,6 +137,31 @@ impl MatchingEngine {
         }
     }
 
+    /// 기준가에서 슬리피지를 적용한 체결가 (매수는 더하고 매도는 뺌).
+    fn apply_slippage(
+        &self,
+        side: Side,
+        quantity: Decimal,
+        price: Decimal,
+        kline: Option<&Kline>,
+    ) -> Decimal {
+        let ctx = SlippageContext {
+            side,
+            quantity,
+            price,
+            kline,
+            tick_size: self
+                .tick_size_provider
+                .as_ref()
+                .map(|provider| provider.tick_size(price)),
+        };
+        let slippage = self.slippage_model.slippage(&ctx).max(Decimal::ZERO);
+        match side {
+            Side::Buy => price + slippage,
+            Side::Sell => price - slippage,
+        }
+    }
+
     /// 다음 주문 ID를 생성합니다.
     pub fn generate_order_id(&mut self) -> String {
         let id = self.next_order_id;
@@ -113,8 +169,64 @@ impl MatchingEngine {
         format!("SIM-{:010}", id)
     }
 
+    /// 시장가 체결 가격 (슬리피지 적용 후 매수는 올림, 매도는 내림으로 호가 단위 라운딩).
+    fn market_fill_price(
+        &self,
+        side: Side,
+        quantity: Decimal,
+        price: Decimal,
+        kline: Option<&Kline>,
+    ) -> Decimal {
+        let raw_fill_price = self.apply_slippage(side, quantity, price, kline);
+        match side {
+            Side::Buy => self.round_price(raw_fill_price, RoundMethod::Ceil),
+            Side::Sell => self.round_price(raw_fill_price, RoundMethod::Floor),
+        }
+    }
+
+    /// 체결 결과를 생성합니다 (수수료 포함).
+    ///
+    /// 체결 후 잔량과 만료 잔량이 모두 없으면 전량 체결로 봅니다.
+    fn build_match(
+        &self,
+        order: &PendingOrder,
+        filled_quantity: Decimal,
+        fill_price: Decimal,
+        expired_quantity: Decimal,
+        timestamp: DateTime<Utc>,
+    ) -> OrderMatch {
+        let fill_type = if filled_quantity <= dec!(0) {
+            FillType::None
+        } else if order.remaining_quantity <= dec!(0) && expired_quantity <= dec!(0) {
+            FillType::Full
+        } else {
+            FillType::Partial
+        };
+        let fill_price = if fill_type == FillType::None {
+            dec!(0)
+        } else {
+            fill_price
+        };
+
+        OrderMatch {
+            order_id: order.order_id.clone(),
+            fill_type,
+            filled_quantity,
+            fill_price,
+            commission: filled_quantity * fill_price * self.fee_rate,
+            commission_asset: Self::parse_quote(&order.symbol),
+            timestamp,
+            expired_quantity,
+        }
+    }
+
     /// 새로운 주문을 제출합니다.
-    /// 시장가 주문의 경우 즉시 매칭 결과를 반환합니다.
+    ///
+    /// 시장가 주문과 시장가에 도달한 지정가 주문은 즉시 체결되며, 부분 체결 설정이 있으면
+    /// 직전 캔들 거래량 한도까지만 체결됩니다. 남은 수량은 유효 기간 조건에 따라 처리됩니다.
+    /// - GTC/GTD: 대기 주문으로 남아 이후 캔들에서 체결
+    /// - IOC: 남은 수량 취소
+    /// - FOK: 전량 체결할 수 없으면 주문 전체 취소
     pub fn submit_order(
         &mut self,
         request: &OrderRequest,
@@ -122,34 +234,31 @@ impl MatchingEngine {
         timestamp: DateTime<Utc>,
     ) -> OrderMatch {
         let order_id = self.generate_order_id();
+        let last_kline = self.last_klines.get(&request.ticker).cloned();
+
+        let mut pending = PendingOrder {
+            order_id,
+            symbol: request.ticker.clone(),
+            side: request.side,
+            order_type: request.order_type,
+            original_quantity: request.quantity,
+            remaining_quantity: request.quantity,
+            price: request.price,
+            stop_price: request.stop_price,
+            created_at: timestamp,
+            queue_ahead: dec!(0),
+            triggered: false,
+        };
 
-        match request.order_type {
+        let immediate_price = match request.order_type {
             OrderType::Market => {
-                // 시장가 주문은 슬리피지와 함께 즉시 체결됩니다
-                let slippage = current_price * self.slippage_rate;
-                let raw_fill_price = match request.side {
-                    Side::Buy => current_price + slippage,
-                    Side::Sell => current_price - slippage,
-                };
-
-                // 호가 단위로 라운딩 (매수는 올림, 매도는 내림)
-                let fill_price = match request.side {
-                    Side::Buy => self.round_price(raw_fill_price, RoundMethod::Ceil),
-                    Side::Sell => self.round_price(raw_fill_price, RoundMethod::Floor),
-                };
-
-                let commission = request.quantity * fill_price * self.fee_rate;
-                let commission_asset = Self::parse_quote(&request.ticker);
-
-                OrderMatch {
-                    order_id,
-                    fill_type: FillType::Full,
-                    filled_quantity: request.quantity,
-                    fill_price,
-                    commission,
-                    commission_asset,
-                    timestamp,
-                }
+                // 시장가 주문은 슬리피지와 함께 즉시 체결됩니다 (마지막 캔들 거래량 참조)
+                Some(self.market_fill_price(
+                    request.side,
+                    request.quantity,
+                    current_price,
+                    last_kline.as_ref(),
+                ))
             }
             OrderType::Limit => {
                 // 지정가 주문: 즉시 체결 가능 여부 확인
@@ -160,6 +269,7 @@ impl MatchingEngine {
                     Side::Buy => self.round_price(raw_limit_price, RoundMethod::Floor),
                     Side::Sell => self.round_price(raw_limit_price, RoundMethod::Ceil),
                 };
+                pending.price = Some(limit_price);
 
                 let can_fill = match request.side {
                     Side::Buy => current_price <= limit_price,
@@ -168,194 +278,158 @@ impl MatchingEngine {
 
                 if can_fill {
                     // 지정가로 체결 (트레이더에게 더 유리함)
-                    let commission = request.quantity * limit_price * self.fee_rate;
-                    let commission_asset = Self::parse_quote(&request.ticker);
-
-                    OrderMatch {
-                        order_id,
-                        fill_type: FillType::Full,
-                        filled_quantity: request.quantity,
-                        fill_price: limit_price,
-                        commission,
-                        commission_asset,
-                        timestamp,
-                    }
+                    Some(limit_price)
                 } else {
-                    // 대기 주문에 추가
-                    let pending = PendingOrder {
-                        order_id: order_id.clone(),
-                        symbol: request.ticker.clone(),
-                        side: request.side,
-                        order_type: request.order_type,
-                        original_quantity: request.quantity,
-                        remaining_quantity: request.quantity,
-                        price: Some(limit_price),
-                        stop_price: None,
-                        created_at: timestamp,
-                    };
-
-                    self.pending_orders
-                        .entry(request.ticker.clone())
-                        .or_default()
-                        .push(pending);
-
-                    OrderMatch {
-                        order_id,
-                        fill_type: FillType::None,
-                        filled_quantity: dec!(0),
-                        fill_price: dec!(0),
-                        commission: dec!(0),
-                        commission_asset: Self::parse_quote(&request.ticker),
-                        timestamp,
-                    }
+                    // 호가 대기열 뒤에 줄을 섭니다
+                    pending.queue_ahead = self.fill_model.initial_queue_ahead(last_kline.as_ref());
+                    None
                 }
             }
-            OrderType::StopLoss | OrderType::StopLossLimit => {
-                // 대기 주문에 추가 (스탑 가격 도달 시 트리거됨)
-                let pending = PendingOrder {
-                    order_id: order_id.clone(),
-                    symbol: request.ticker.clone(),
-                    side: request.side,
-                    order_type: request.order_type,
-                    original_quantity: request.quantity,
-                    remaining_quantity: request.quantity,
-                    price: request.price,
-                    stop_price: request.stop_price,
-                    created_at: timestamp,
-                };
+            // 스탑/이익실현 주문은 가격 도달 시 트리거됩니다
+            // (트레일링 스탑은 완전히 구현되지 않음 - 대기만 함)
+            OrderType::StopLoss
+            | OrderType::StopLossLimit
+            | OrderType::TakeProfit
+            | OrderType::TakeProfitLimit
+            | OrderType::TrailingStop => None,
+        };
 
-                self.pending_orders
-                    .entry(request.ticker.clone())
-                    .or_default()
-                    .push(pending);
-
-                OrderMatch {
-                    order_id,
-                    fill_type: FillType::None,
-                    filled_quantity: dec!(0),
-                    fill_price: dec!(0),
-                    commission: dec!(0),
-                    commission_asset: Self::parse_quote(&request.ticker),
-                    timestamp,
-                }
-            }
-            OrderType::TakeProfit | OrderType::TakeProfitLimit => {
-                // 대기 주문에 추가 (이익실현 가격 도달 시 트리거됨)
-                let pending = PendingOrder {
-                    order_id: order_id.clone(),
-                    symbol: request.ticker.clone(),
-                    side: request.side,
-                    order_type: request.order_type,
-                    original_quantity: request.quantity,
-                    remaining_quantity: request.quantity,
-                    price: request.price,
-                    stop_price: request.stop_price,
-                    created_at: timestamp,
-                };
+        let immediate_or_cancel =
+            matches!(request.time_in_force, TimeInForce::IOC | TimeInForce::FOK);
 
-                self.pending_orders
-                    .entry(request.ticker.clone())
-                    .or_default()
-                    .push(pending);
-
-                OrderMatch {
-                    order_id,
-                    fill_type: FillType::None,
-                    filled_quantity: dec!(0),
-                    fill_price: dec!(0),
-                    commission: dec!(0),
-                    commission_asset: Self::parse_quote(&request.ticker),
-                    timestamp,
-                }
+        let Some(fill_price) = immediate_price else {
+            if request.order_type == OrderType::Limit && immediate_or_cancel {
+                // 즉시 체결할 수 없는 IOC/FOK 지정가 주문은 취소
+                let expired = pending.remaining_quantity;
+                pending.remaining_quantity = dec!(0);
+                return self.build_match(&pending, dec!(0), dec!(0), expired, timestamp);
             }
-            OrderType::TrailingStop => {
-                // 트레일링 스탑은 완전히 구현되지 않음 - 손절매로 처리
-                let pending = PendingOrder {
-                    order_id: order_id.clone(),
-                    symbol: request.ticker.clone(),
-                    side: request.side,
-                    order_type: request.order_type,
-                    original_quantity: request.quantity,
-                    remaining_quantity: request.quantity,
-                    price: request.price,
-                    stop_price: request.stop_price,
-                    created_at: timestamp,
-                };
 
-                self.pending_orders
-                    .entry(request.ticker.clone())
-                    .or_default()
-                    .push(pending);
-
-                OrderMatch {
-                    order_id,
-                    fill_type: FillType::None,
-                    filled_quantity: dec!(0),
-                    fill_price: dec!(0),
-                    commission: dec!(0),
-                    commission_asset: Self::parse_quote(&request.ticker),
-                    timestamp,
-                }
-            }
+            let order_match = self.build_match(&pending, dec!(0), dec!(0), dec!(0), timestamp);
+            self.pending_orders
+                .entry(request.ticker.clone())
+                .or_default()
+                .push(pending);
+            return order_match;
+        };
+
+        let fillable = self
+            .fill_model
+            .volume_budget(last_kline.as_ref())
+            .map_or(request.quantity, |budget| budget.min(request.quantity));
+
+        if request.time_in_force == TimeInForce::FOK && fillable < request.quantity {
+            // 전량 체결할 수 없으면 주문 전체 취소
+            pending.remaining_quantity = dec!(0);
+            return self.build_match(&pending, dec!(0), dec!(0), request.quantity, timestamp);
+        }
+
+        pending.remaining_quantity -= fillable;
+        let expired = if immediate_or_cancel {
+            std::mem::take(&mut pending.remaining_quantity)
+        } else {
+            dec!(0)
+        };
+
+        let order_match = self.build_match(&pending, fillable, fill_price, expired, timestamp);
+
+        // 잔량은 대기 주문으로 남김 (시장가 잔량은 다음 캔들 시가에 체결)
+        if pending.remaining_quantity > dec!(0) {
+            self.pending_orders
+                .entry(request.ticker.clone())
+                .or_default()
+                .push(pending);
         }
+
+        order_match
     }
 
     /// 새로운 Kline을 처리하고 주문 체결을 확인합니다.
     /// 매칭된 주문 목록을 반환합니다.
+    ///
+    /// 부분 체결 설정이 있으면 캔들 거래량 한도를 대기 주문에 시간 우선으로 배분하고,
+    /// 다 채우지 못한 주문은 잔량으로 남깁니다.
     pub fn process_kline(&mut self, symbol: &String, kline: &Kline) -> Vec<OrderMatch> {
+        self.last_klines.insert(symbol.clone(), kline.clone());
+
+        let Some(mut orders) = self.pending_orders.remove(symbol) else {
+            return Vec::new();
+        };
+
         let mut matches = Vec::new();
-        let mut to_remove = Vec::new();
-
-        if let Some(orders) = self.pending_orders.get(symbol) {
-            for (idx, order) in orders.iter().enumerate() {
-                if let Some(match_result) = self.try_match_order(order, kline) {
-                    if match_result.fill_type == FillType::Full {
-                        to_remove.push(idx);
-                    }
-                    matches.push(match_result);
+        let mut budget = self.fill_model.volume_budget(Some(kline));
+
+        for order in orders.iter_mut() {
+            let Some(fill_price) = self.trigger_price(order, kline) else {
+                continue;
+            };
+            order.triggered = true;
+
+            let mut fillable = order.remaining_quantity;
+            if let Some(budget) = budget {
+                fillable = fillable.min(budget);
+            }
+
+            // 가격을 터치만 했으면 앞선 대기 물량부터 체결
+            if order.order_type == OrderType::Limit && self.fill_model.tracks_queue() {
+                if trades_through(order.side, fill_price, kline) {
+                    order.queue_ahead = dec!(0);
+                } else {
+                    let level_volume = consume_queue(&mut order.queue_ahead, kline.volume);
+                    fillable = fillable.min(level_volume);
                 }
             }
-        }
 
-        // 체결된 주문 제거 (인덱스 유지를 위해 역순으로)
-        if let Some(orders) = self.pending_orders.get_mut(symbol) {
-            for idx in to_remove.into_iter().rev() {
-                orders.remove(idx);
+            if fillable <= dec!(0) {
+                continue;
+            }
+
+            order.remaining_quantity -= fillable;
+            if let Some(budget) = budget.as_mut() {
+                *budget -= fillable;
             }
+            matches.push(self.build_match(order, fillable, fill_price, dec!(0), kline.close_time));
+        }
+
+        // 체결 완료된 주문 제거
+        orders.retain(|order| order.remaining_quantity > dec!(0));
+        if !orders.is_empty() {
+            self.pending_orders.insert(symbol.clone(), orders);
         }
 
         matches
     }
 
-    /// 대기 주문을 Kline과 매칭 시도합니다.
-    fn try_match_order(&self, order: &PendingOrder, kline: &Kline) -> Option<OrderMatch> {
+    /// 대기 주문이 Kline에서 체결될 가격 (체결 조건에 도달하지 않으면 `None`).
+    fn trigger_price(&self, order: &PendingOrder, kline: &Kline) -> Option<Decimal> {
         let high = kline.high;
         let low = kline.low;
 
+        // 지정가 도달 여부
+        let limit_touched = |limit_price: Decimal| match order.side {
+            Side::Buy => low <= limit_price,
+            Side::Sell => high >= limit_price,
+        };
+
         match order.order_type {
+            OrderType::Market => {
+                // 시장가 잔량은 캔들 시가에 슬리피지를 적용하여 체결
+                Some(self.market_fill_price(
+                    order.side,
+                    order.remaining_quantity,
+                    kline.open,
+                    Some(kline),
+                ))
+            }
             OrderType::Limit => {
                 let limit_price = order.price?;
-
-                let should_fill = match order.side {
-                    Side::Buy => low <= limit_price,
-                    Side::Sell => high >= limit_price,
-                };
-
-                if should_fill {
-                    let commission = order.remaining_quantity * limit_price * self.fee_rate;
-
-                    Some(OrderMatch {
-                        order_id: order.order_id.clone(),
-                        fill_type: FillType::Full,
-                        filled_quantity: order.remaining_quantity,
-                        fill_price: limit_price,
-                        commission,
-                        commission_asset: order.symbol.clone(),
-                        timestamp: kline.close_time,
-                    })
-                } else {
-                    None
-                }
+                limit_touched(limit_price).then_some(limit_price)
+            }
+            OrderType::StopLoss | OrderType::StopLossLimit if order.triggered => {
+                self.triggered_fill_price(order, kline, limit_touched)
+            }
+            OrderType::TakeProfit | OrderType::TakeProfitLimit if order.triggered => {
+                self.triggered_fill_price(order, kline, limit_touched)
             }
             OrderType::StopLoss | OrderType::StopLossLimit => {
                 let stop_price = order.stop_price?;
@@ -368,31 +442,20 @@ impl MatchingEngine {
                     Side::Buy => high >= stop_price,
                 };
 
-                if should_trigger {
-                    // 스탑 가격으로 체결 (시장 스탑의 경우 슬리피지 포함)
-                    let fill_price = if order.order_type == OrderType::StopLoss {
-                        let slippage = stop_price * self.slippage_rate;
-                        match order.side {
-                            Side::Sell => stop_price - slippage,
-                            Side::Buy => stop_price + slippage,
-                        }
-                    } else {
-                        order.price.unwrap_or(stop_price)
-                    };
-
-                    let commission = order.remaining_quantity * fill_price * self.fee_rate;
-
-                    Some(OrderMatch {
-                        order_id: order.order_id.clone(),
-                        fill_type: FillType::Full,
-                        filled_quantity: order.remaining_quantity,
-                        fill_price,
-                        commission,
-                        commission_asset: Self::parse_quote(&order.symbol),
-                        timestamp: kline.close_time,
-                    })
+                if !should_trigger {
+                    return None;
+                }
+
+                // 스탑 가격으로 체결 (시장 스탑의 경우 슬리피지 포함)
+                if order.order_type == OrderType::StopLoss {
+                    Some(self.apply_slippage(
+                        order.side,
+                        order.remaining_quantity,
+                        stop_price,
+                        Some(kline),
+                    ))
                 } else {
-                    None
+                    Some(order.price.unwrap_or(stop_price))
                 }
             }
             OrderType::TakeProfit | OrderType::TakeProfitLimit => {
@@ -406,35 +469,46 @@ impl MatchingEngine {
                     Side::Buy => low <= stop_price,
                 };
 
-                if should_trigger {
-                    let fill_price = if order.order_type == OrderType::TakeProfit {
-                        stop_price
-                    } else {
-                        order.price.unwrap_or(stop_price)
-                    };
-
-                    let commission = order.remaining_quantity * fill_price * self.fee_rate;
-
-                    Some(OrderMatch {
-                        order_id: order.order_id.clone(),
-                        fill_type: FillType::Full,
-                        filled_quantity: order.remaining_quantity,
-                        fill_price,
-                        commission,
-                        commission_asset: Self::parse_quote(&order.symbol),
-                        timestamp: kline.close_time,
-                    })
+                if !should_trigger {
+                    return None;
+                }
+
+                if order.order_type == OrderType::TakeProfit {
+                    Some(stop_price)
                 } else {
-                    None
+                    Some(order.price.unwrap_or(stop_price))
                 }
             }
-            OrderType::Market | OrderType::TrailingStop => {
-                // 시장가 주문은 대기 상태가 아니어야 합니다
+            OrderType::TrailingStop => {
+                // 트레일링 스탑은 대기만 합니다
                 None
             }
         }
     }
 
+    /// 이미 트리거된 스탑/이익실현 주문 잔량의 체결 가격.
+    ///
+    /// 시장가형은 캔들 시가에 슬리피지를 적용하고, 지정가형은 지정가 도달 시 지정가로 체결합니다.
+    fn triggered_fill_price(
+        &self,
+        order: &PendingOrder,
+        kline: &Kline,
+        limit_touched: impl Fn(Decimal) -> bool,
+    ) -> Option<Decimal> {
+        match order.order_type {
+            OrderType::StopLoss | OrderType::TakeProfit => Some(self.market_fill_price(
+                order.side,
+                order.remaining_quantity,
+                kline.open,
+                Some(kline),
+            )),
+            _ => {
+                let limit_price = order.price.or(order.stop_price)?;
+                limit_touched(limit_price).then_some(limit_price)
+            }
+        }
+    }
+
     /// 대기 주문을 취소합니다.
     pub fn cancel_order(&mut self, symbol: &String, order_id: &str) -> bool {
         if let Some(orders) = self.pending_orders.get_mut(symbol) {
@@ -473,6 +547,7 @@ impl MatchingEngine {
     /// 모든 대기 주문을 초기화합니다.
     pub fn clear(&mut self) {
         self.pending_orders.clear();
+        self.last_klines.clear();
     }
 }
 
@@ -641,6 +716,231 @@ mod tests {
         assert!(matches[0].fill_price < dec!(48000));
     }
 
+    #[test]
+    fn test_market_order_volume_participation_slippage() {
+        use crate::simulated::VolumeParticipationSlippage;
+
+        let mut engine = MatchingEngine::new(dec!(0), dec!(0)).with_slippage_model(Arc::new(
+            VolumeParticipationSlippage {
+                base_rate: dec!(0),
+                impact_coefficient: dec!(0.01),
+                max_rate: dec!(0.05),
+            },
+        ));
+        let symbol = create_test_symbol();
+        let request = |quantity| OrderRequest {
+            ticker: symbol.to_string(),
+            side: Side::Buy,
+            order_type: OrderType::Market,
+            quantity,
+            price: None,
+            stop_price: None,
+            time_in_force: TimeInForce::GTC,
+            client_order_id: None,
+            strategy_id: None,
+        };
+
+        // 캔들 거래량을 모르면 슬리피지 없음
+        let first = engine.submit_order(&request(dec!(1)), dec!(50000), Utc::now());
+        assert_eq!(first.fill_price, dec!(50000));
+
+        // 거래량 100 대비 1% 참여 → 0.1% 슬리피지, 더 큰 주문은 더 불리
+        engine.process_kline(
+            &symbol,
+            &create_test_kline(50000.0, 50000.0, 50000.0, 50000.0),
+        );
+        let small = engine.submit_order(&request(dec!(1)), dec!(50000), Utc::now());
+        let large = engine.submit_order(&request(dec!(25)), dec!(50000), Utc::now());
+        assert!((small.fill_price - dec!(50050)).abs() < dec!(0.01));
+        assert!(large.fill_price > small.fill_price);
+    }
+
+    #[test]
+    fn test_stop_loss_spread_slippage() {
+        use crate::simulated::SpreadSlippage;
+        use trader_core::KrxTickSize;
+
+        let mut engine = MatchingEngine::new(dec!(0), dec!(0))
+            .with_tick_size_provider(Arc::new(KrxTickSize::new()))
+            .with_slippage_model(Arc::new(SpreadSlippage {
+                spread_ticks: dec!(2),
+                fallback_rate: dec!(0),
+            }));
+        let symbol = create_test_symbol();
+        let request = OrderRequest {
+            ticker: symbol.to_string(),
+            side: Side::Sell,
+            order_type: OrderType::StopLoss,
+            quantity: dec!(10),
+            price: None,
+            stop_price: Some(dec!(48000)),
+            time_in_force: TimeInForce::GTC,
+            client_order_id: None,
+            strategy_id: None,
+        };
+        engine.submit_order(&request, dec!(50000), Utc::now());
+
+        // 48,000원 호가 단위 50원, 스프레드 2호가의 절반 → 50원 불리하게 체결
+        let kline = create_test_kline(49000.0, 49500.0, 47500.0, 47800.0);
+        let matches = engine.process_kline(&symbol, &kline);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].fill_price, dec!(47950));
+    }
+
+    fn limit_buy(quantity: Decimal, price: Decimal, time_in_force: TimeInForce) -> OrderRequest {
+        OrderRequest {
+            ticker: create_test_symbol(),
+            side: Side::Buy,
+            order_type: OrderType::Limit,
+            quantity,
+            price: Some(price),
+            stop_price: None,
+            time_in_force,
+            client_order_id: None,
+            strategy_id: None,
+        }
+    }
+
+    #[test]
+    fn test_partial_fill_volume_participation() {
+        // 캔들 거래량 100의 10%까지만 체결
+        let mut engine = MatchingEngine::new(dec!(0), dec!(0))
+            .with_fill_model(FillModelConfig::default().with_max_participation(dec!(0.1)));
+        let symbol = create_test_symbol();
+
+        let result = engine.submit_order(
+            &limit_buy(dec!(25), dec!(49000), TimeInForce::GTC),
+            dec!(50000),
+            Utc::now(),
+        );
+        assert_eq!(result.fill_type, FillType::None);
+
+        let kline = create_test_kline(50000.0, 50500.0, 48500.0, 49500.0);
+        let first = engine.process_kline(&symbol, &kline);
+        assert_eq!(first[0].fill_type, FillType::Partial);
+        assert_eq!(first[0].filled_quantity, dec!(10));
+        assert_eq!(
+            engine
+                .get_order(&symbol, &result.order_id)
+                .unwrap()
+                .remaining_quantity,
+            dec!(15)
+        );
+
+        engine.process_kline(&symbol, &kline);
+        let last = engine.process_kline(&symbol, &kline);
+        assert_eq!(last[0].fill_type, FillType::Full);
+        assert_eq!(last[0].filled_quantity, dec!(5));
+        assert!(engine.get_pending_orders(Some(&symbol)).is_empty());
+    }
+
+    #[test]
+    fn test_limit_queue_position() {
+        // 새 주문 앞에 직전 캔들 거래량(100)만큼 대기 물량이 있다고 가정
+        let mut engine = MatchingEngine::new(dec!(0), dec!(0))
+            .with_fill_model(FillModelConfig::default().with_queue_ahead_ratio(dec!(1)));
+        let symbol = create_test_symbol();
+        engine.process_kline(
+            &symbol,
+            &create_test_kline(50000.0, 50500.0, 49500.0, 50000.0),
+        );
+
+        let result = engine.submit_order(
+            &limit_buy(dec!(5), dec!(49000), TimeInForce::GTC),
+            dec!(50000),
+            Utc::now(),
+        );
+        let order = engine.get_order(&symbol, &result.order_id).unwrap();
+        assert_eq!(order.queue_ahead, dec!(100));
+
+        // 지정가를 터치만 한 캔들: 거래량 100이 대기열을 소진
+        let touch = create_test_kline(49500.0, 49800.0, 49000.0, 49200.0);
+        assert!(engine.process_kline(&symbol, &touch).is_empty());
+        let order = engine.get_order(&symbol, &result.order_id).unwrap();
+        assert_eq!(order.queue_ahead, dec!(0));
+
+        // 대기열을 모두 지나면 체결
+        let matches = engine.process_kline(&symbol, &touch);
+        assert_eq!(matches[0].fill_type, FillType::Full);
+        assert_eq!(matches[0].filled_quantity, dec!(5));
+
+        // 지정가를 관통한 캔들은 대기열과 무관하게 체결
+        let result = engine.submit_order(
+            &limit_buy(dec!(5), dec!(49000), TimeInForce::GTC),
+            dec!(50000),
+            Utc::now(),
+        );
+        let through = create_test_kline(49500.0, 49800.0, 48500.0, 49200.0);
+        let matches = engine.process_kline(&symbol, &through);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].order_id, result.order_id);
+    }
+
+    #[test]
+    fn test_time_in_force() {
+        let mut engine = MatchingEngine::new(dec!(0), dec!(0))
+            .with_fill_model(FillModelConfig::default().with_max_participation(dec!(0.1)));
+        let symbol = create_test_symbol();
+
+        // 즉시 체결할 수 없는 IOC 지정가는 대기하지 않고 만료
+        let ioc = engine.submit_order(
+            &limit_buy(dec!(1), dec!(49000), TimeInForce::IOC),
+            dec!(50000),
+            Utc::now(),
+        );
+        assert_eq!(ioc.fill_type, FillType::None);
+        assert_eq!(ioc.expired_quantity, dec!(1));
+        assert!(engine.get_pending_orders(Some(&symbol)).is_empty());
+
+        // 직전 캔들 거래량 100 → 즉시 체결 한도 10
+        engine.process_kline(
+            &symbol,
+            &create_test_kline(50000.0, 50000.0, 50000.0, 50000.0),
+        );
+
+        let ioc = engine.submit_order(
+            &limit_buy(dec!(25), dec!(51000), TimeInForce::IOC),
+            dec!(50000),
+            Utc::now(),
+        );
+        assert_eq!(ioc.fill_type, FillType::Partial);
+        assert_eq!(ioc.filled_quantity, dec!(10));
+        assert_eq!(ioc.expired_quantity, dec!(15));
+
+        let fok = engine.submit_order(
+            &limit_buy(dec!(25), dec!(51000), TimeInForce::FOK),
+            dec!(50000),
+            Utc::now(),
+        );
+        assert_eq!(fok.fill_type, FillType::None);
+        assert_eq!(fok.expired_quantity, dec!(25));
+        assert!(engine.get_pending_orders(Some(&symbol)).is_empty());
+
+        // GTC 시장가 잔량은 다음 캔들 시가에 이어서 체결
+        let market = OrderRequest {
+            ticker: symbol.clone(),
+            side: Side::Buy,
+            order_type: OrderType::Market,
+            quantity: dec!(15),
+            price: None,
+            stop_price: None,
+            time_in_force: TimeInForce::GTC,
+            client_order_id: None,
+            strategy_id: None,
+        };
+        let gtc = engine.submit_order(&market, dec!(50000), Utc::now());
+        assert_eq!(gtc.fill_type, FillType::Partial);
+        assert_eq!(gtc.filled_quantity, dec!(10));
+
+        let matches = engine.process_kline(
+            &symbol,
+            &create_test_kline(50100.0, 50200.0, 50000.0, 50100.0),
+        );
+        assert_eq!(matches[0].fill_type, FillType::Full);
+        assert_eq!(matches[0].filled_quantity, dec!(5));
+        assert_eq!(matches[0].fill_price, dec!(50100));
+    }
+
     #[test]
     fn test_cancel_order() {
         let mut engine = MatchingEngine::new(dec!(0.001), dec!(0.0005));
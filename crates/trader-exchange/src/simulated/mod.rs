@@ -4,6 +4,8 @@
 //! - 파일 또는 메모리에서 과거 데이터(Kline) 로드
 //! - 주문 매칭 및 체결 시뮬레이션
 //! - 계정 잔고 및 포지션 추적
+//! - 슬리피지 모델 선택 (고정 비율, 거래량 참여율, 스프레드)
+//! - 부분 체결, 지정가 대기열 위치 추정, 유효 기간 조건(IOC/FOK/GTC)
 //! - 전략 테스트를 위한 시장 이벤트 생성
 //!
 //! # 예제
@@ -25,10 +27,17 @@
 
 mod data_feed;
 mod exchange;
+mod fill_model;
 mod matching_engine;
+mod slippage;
 mod stream;
 
 pub use data_feed::{DataFeed, DataFeedConfig};
 pub use exchange::{SimulatedConfig, SimulatedExchange};
+pub use fill_model::FillModelConfig;
 pub use matching_engine::{FillType, MatchingEngine, OrderMatch};
+pub use slippage::{
+    FixedRateSlippage, SlippageContext, SlippageModel, SlippageModelConfig, SpreadSlippage,
+    TickSizeRule, VolumeParticipationSlippage,
+};
 pub use stream::{SimulatedMarketStream, SimulatedUserStream};
@@ -0,0 +1,273 @@
+//! 시뮬레이션 체결 슬리피지 모델.
+//!
+//! 시장가 주문과 시장가 스탑 주문의 체결가를 기준가에서 얼마나 불리하게 밀어낼지 결정합니다.
+//! - [`FixedRateSlippage`]: 기준가의 고정 비율 (기존 동작)
+//! - [`VolumeParticipationSlippage`]: 캔들 거래량 대비 주문 비중의 제곱근에 비례하는 시장 충격
+//! - [`SpreadSlippage`]: 호가 단위로 표현한 스프레드의 절반을 건너는 비용
+//!
+//! [`SlippageModelConfig`]로 [`super::SimulatedConfig`]에서 모델을 선택합니다.
+
+use std::sync::Arc;
+
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use trader_core::{BinanceTickSize, Kline, KrxTickSize, Side, TickSizeProvider, UsEquityTickSize};
+
+/// 슬리피지 계산 입력.
+#[derive(Debug, Clone, Copy)]
+pub struct SlippageContext<'a> {
+    /// 주문 방향
+    pub side: Side,
+    /// 주문 수량
+    pub quantity: Decimal,
+    /// 기준가 (현재가 또는 스탑 가격)
+    pub price: Decimal,
+    /// 체결 시점의 캔들 (거래량 참조, 없을 수 있음)
+    pub kline: Option<&'a Kline>,
+    /// 기준가의 호가 단위 (호가 단위 제공자가 없으면 `None`)
+    pub tick_size: Option<Decimal>,
+}
+
+/// 체결 슬리피지 모델.
+pub trait SlippageModel: Send + Sync + std::fmt::Debug {
+    /// 단위당 슬리피지 금액 (0 이상).
+    ///
+    /// 매수는 기준가에 더하고 매도는 기준가에서 뺍니다.
+    fn slippage(&self, ctx: &SlippageContext<'_>) -> Decimal;
+}
+
+/// 기준가의 고정 비율 슬리피지.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FixedRateSlippage {
+    /// 슬리피지율 (예: 0.05%의 경우 0.0005)
+    pub rate: Decimal,
+}
+
+impl SlippageModel for FixedRateSlippage {
+    fn slippage(&self, ctx: &SlippageContext<'_>) -> Decimal {
+        ctx.price * self.rate.max(Decimal::ZERO)
+    }
+}
+
+/// 거래량 참여율 기반 슬리피지 (제곱근 시장 충격 모형).
+///
+/// `rate = base_rate + impact_coefficient × √(주문 수량 / 캔들 거래량)`을 `max_rate`로 제한합니다.
+/// 캔들이나 거래량이 없으면 `base_rate`만 적용합니다.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VolumeParticipationSlippage {
+    /// 최소 슬리피지율
+    pub base_rate: Decimal,
+    /// 충격 계수 (참여율 100%일 때 추가되는 슬리피지율)
+    pub impact_coefficient: Decimal,
+    /// 최대 슬리피지율
+    pub max_rate: Decimal,
+}
+
+impl VolumeParticipationSlippage {
+    /// 참여율에 따른 슬리피지율.
+    pub fn rate(&self, quantity: Decimal, volume: Option<Decimal>) -> Decimal {
+        let impact = volume
+            .filter(|v| *v > Decimal::ZERO)
+            .and_then(|v| (quantity.abs() / v).to_f64())
+            .and_then(|participation| Decimal::from_f64(participation.sqrt()))
+            .map(|sqrt| self.impact_coefficient * sqrt)
+            .unwrap_or(Decimal::ZERO);
+
+        (self.base_rate + impact)
+            .min(self.max_rate)
+            .max(Decimal::ZERO)
+    }
+}
+
+impl SlippageModel for VolumeParticipationSlippage {
+    fn slippage(&self, ctx: &SlippageContext<'_>) -> Decimal {
+        ctx.price * self.rate(ctx.quantity, ctx.kline.map(|k| k.volume))
+    }
+}
+
+/// 스프레드 기반 슬리피지.
+///
+/// 스프레드를 `spread_ticks`호가로 보고 그 절반을 체결 비용으로 적용합니다.
+/// 호가 단위를 알 수 없으면 `fallback_rate`를 적용합니다.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpreadSlippage {
+    /// 호가 단위로 표현한 스프레드
+    pub spread_ticks: Decimal,
+    /// 호가 단위가 없을 때의 슬리피지율
+    pub fallback_rate: Decimal,
+}
+
+impl SlippageModel for SpreadSlippage {
+    fn slippage(&self, ctx: &SlippageContext<'_>) -> Decimal {
+        match ctx.tick_size.filter(|tick| *tick > Decimal::ZERO) {
+            Some(tick) => tick * self.spread_ticks.max(Decimal::ZERO) / dec!(2),
+            None => ctx.price * self.fallback_rate.max(Decimal::ZERO),
+        }
+    }
+}
+
+/// 슬리피지 모델 설정 (직렬화 가능).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SlippageModelConfig {
+    /// 고정 비율
+    Fixed { rate: Decimal },
+    /// 거래량 참여율 기반
+    VolumeParticipation {
+        base_rate: Decimal,
+        impact_coefficient: Decimal,
+        max_rate: Decimal,
+    },
+    /// 스프레드 기반
+    Spread {
+        spread_ticks: Decimal,
+        fallback_rate: Decimal,
+    },
+}
+
+impl SlippageModelConfig {
+    /// 설정으로 모델 생성.
+    pub fn build(&self) -> Arc<dyn SlippageModel> {
+        match *self {
+            Self::Fixed { rate } => Arc::new(FixedRateSlippage { rate }),
+            Self::VolumeParticipation {
+                base_rate,
+                impact_coefficient,
+                max_rate,
+            } => Arc::new(VolumeParticipationSlippage {
+                base_rate,
+                impact_coefficient,
+                max_rate,
+            }),
+            Self::Spread {
+                spread_ticks,
+                fallback_rate,
+            } => Arc::new(SpreadSlippage {
+                spread_ticks,
+                fallback_rate,
+            }),
+        }
+    }
+}
+
+/// 시뮬레이션 호가 단위 규칙 (직렬화 가능).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TickSizeRule {
+    /// KRX 가격대별 호가 단위
+    Krx,
+    /// 미국 주식 $0.01
+    UsEquity,
+    /// 고정 호가 단위 (암호화폐 등)
+    Fixed { tick_size: Decimal },
+}
+
+impl TickSizeRule {
+    /// 규칙으로 호가 단위 제공자 생성.
+    pub fn build(&self) -> Arc<dyn TickSizeProvider> {
+        match self {
+            Self::Krx => Arc::new(KrxTickSize::new()),
+            Self::UsEquity => Arc::new(UsEquityTickSize::new()),
+            Self::Fixed { tick_size } => Arc::new(BinanceTickSize::new(
+                std::collections::HashMap::new(),
+                Some(*tick_size),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use trader_core::Timeframe;
+
+    fn kline(volume: Decimal) -> Kline {
+        let now = Utc::now();
+        Kline {
+            ticker: "005930".to_string(),
+            timeframe: Timeframe::M1,
+            open_time: now,
+            close_time: now,
+            open: dec!(70000),
+            high: dec!(70000),
+            low: dec!(70000),
+            close: dec!(70000),
+            volume,
+            quote_volume: None,
+            num_trades: None,
+        }
+    }
+
+    fn ctx(quantity: Decimal, kline: Option<&Kline>, tick: Option<Decimal>) -> SlippageContext<'_> {
+        SlippageContext {
+            side: Side::Buy,
+            quantity,
+            price: dec!(70000),
+            kline,
+            tick_size: tick,
+        }
+    }
+
+    #[test]
+    fn test_fixed_rate() {
+        let model = FixedRateSlippage { rate: dec!(0.001) };
+        assert_eq!(model.slippage(&ctx(dec!(10), None, None)), dec!(70));
+    }
+
+    #[test]
+    fn test_volume_participation() {
+        let model = VolumeParticipationSlippage {
+            base_rate: dec!(0.0002),
+            impact_coefficient: dec!(0.01),
+            max_rate: dec!(0.005),
+        };
+
+        // 거래량 정보가 없으면 기본 슬리피지
+        assert_eq!(model.rate(dec!(100), None), dec!(0.0002));
+
+        // 참여율 1% → √0.01 = 0.1 → 0.0002 + 0.001
+        let bar = kline(dec!(10000));
+        let rate = model.rate(dec!(100), Some(bar.volume));
+        assert!((rate - dec!(0.0012)).abs() < dec!(0.0000001));
+        let slippage = model.slippage(&ctx(dec!(100), Some(&bar), None));
+        assert!((slippage - dec!(84)).abs() < dec!(0.01));
+
+        // 주문이 클수록 슬리피지가 커지되 최대치로 제한
+        assert!(model.rate(dec!(400), Some(bar.volume)) > rate);
+        assert_eq!(model.rate(dec!(100000), Some(bar.volume)), dec!(0.005));
+    }
+
+    #[test]
+    fn test_spread_slippage() {
+        let model = SpreadSlippage {
+            spread_ticks: dec!(1),
+            fallback_rate: dec!(0.0005),
+        };
+        // 70,000원 호가 단위 100원 → 반 호가 50원
+        assert_eq!(
+            model.slippage(&ctx(dec!(1), None, Some(dec!(100)))),
+            dec!(50)
+        );
+        assert_eq!(model.slippage(&ctx(dec!(1), None, None)), dec!(35));
+    }
+
+    #[test]
+    fn test_config_build_and_serde() {
+        let config: SlippageModelConfig = serde_json::from_str(
+            r#"{"type":"volume_participation","base_rate":0.0002,"impact_coefficient":0.01,"max_rate":0.005}"#,
+        )
+        .unwrap();
+        let model = config.build();
+        assert_eq!(model.slippage(&ctx(dec!(1), None, None)), dec!(14));
+
+        let rule: TickSizeRule = serde_json::from_str(r#"{"type":"krx"}"#).unwrap();
+        assert_eq!(rule.build().tick_size(dec!(70000)), dec!(100));
+        let rule = TickSizeRule::Fixed {
+            tick_size: dec!(0.1),
+        };
+        assert_eq!(rule.build().tick_size(dec!(50000)), dec!(0.1));
+    }
+}
@@ -50,7 +50,14 @@ impl SimulatedMarketStream {
             }
             MarketEvent::OrderBook(ob) => self.order_book_subscriptions.contains(&ob.ticker),
             MarketEvent::Trade(trade) => self.trade_subscriptions.contains(&trade.ticker),
-            MarketEvent::Connected | MarketEvent::Disconnected | MarketEvent::Error(_) => true,
+            MarketEvent::TradingStatus(status) => {
+                self.ticker_subscriptions.contains(&status.ticker)
+                    || self.trade_subscriptions.contains(&status.ticker)
+            }
+            MarketEvent::Connected
+            | MarketEvent::Disconnected
+            | MarketEvent::StreamStatus(_)
+            | MarketEvent::Error(_) => true,
         }
     }
 }
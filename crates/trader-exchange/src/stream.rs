@@ -40,15 +40,23 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::{mpsc, RwLock};
 use tracing::{debug, error, info, warn};
-use trader_core::{OrderBook, OrderBookLevel, Side, Symbol, Ticker, Timeframe, TradeTick};
+use trader_core::{
+    is_kr_futures_code, OrderBook, OrderBookLevel, Side, Symbol, Ticker, Timeframe, TradeTick,
+    TradingStatus, TradingStatusEvent,
+};
 
 use crate::connector::kis::{
-    KisKrWebSocket, KisOAuth, KisUsClient, KisUsWebSocket, KrRealtimeMessage, KrRealtimeOrderbook,
+    KisExecutionNotice, KisKrWebSocket, KisOAuth, KisUsClient, KisUsWebSocket,
+    KrRealtimeFuturesTrade, KrRealtimeMarketStatus, KrRealtimeMessage, KrRealtimeOrderbook,
     KrRealtimeTrade, UsRealtimeMessage, UsRealtimeOrderbook, UsRealtimeTrade,
 };
-use crate::traits::{ExchangeResult, MarketEvent, MarketStream};
+use crate::connector::upbit::{UpbitConfig, UpbitMarketStream};
+use crate::traits::{ExchangeResult, MarketEvent, MarketStream, UserEvent};
 use crate::ExchangeError;
 
+/// 체결통보 전달 채널 크기.
+const FILL_CHANNEL_CAPACITY: usize = 1000;
+
 // ============================================================================
 // KIS 국내 MarketStream
 // ============================================================================
@@ -64,6 +72,8 @@ use crate::ExchangeError;
 pub struct KisKrMarketStream {
     ws: Arc<RwLock<KisKrWebSocket>>,
     rx: Option<mpsc::Receiver<KrRealtimeMessage>>,
+    /// 체결통보 전달 채널 (`subscribe_executions()` 호출 시)
+    fill_tx: Option<mpsc::Sender<UserEvent>>,
     subscribed_symbols: HashMap<String, SubscriptionType>,
     started: bool,
 }
@@ -84,6 +94,7 @@ impl KisKrMarketStream {
         Self {
             ws: Arc::new(RwLock::new(ws)),
             rx,
+            fill_tx: None,
             subscribed_symbols: HashMap::new(),
             started: false,
         }
@@ -116,6 +127,31 @@ impl KisKrMarketStream {
         self.started
     }
 
+    /// 실시간 체결통보 구독.
+    ///
+    /// KIS는 접속키당 WebSocket 연결 하나를 사용하므로 체결통보도 시세와 같은 연결로 받습니다.
+    /// 체결분은 반환된 채널로 `UserEvent::Fill`로 전달되며, `next_event()`가 메시지를 읽는
+    /// 동안 함께 처리됩니다. `start()` 전에 호출해야 합니다.
+    pub async fn subscribe_executions(&mut self) -> ExchangeResult<mpsc::Receiver<UserEvent>> {
+        let (tx, rx) = mpsc::channel(FILL_CHANNEL_CAPACITY);
+        self.forward_executions_to(tx).await?;
+        Ok(rx)
+    }
+
+    /// 체결통보 구독 후 지정한 채널로 전달.
+    async fn forward_executions_to(&mut self, tx: mpsc::Sender<UserEvent>) -> ExchangeResult<()> {
+        if self.started {
+            return Err(ExchangeError::NotSupported(
+                "Dynamic subscription not supported after connection".to_string(),
+            ));
+        }
+
+        self.ws.write().await.add_execution_subscription()?;
+        self.fill_tx = Some(tx);
+        info!("KR 체결통보 구독 설정");
+        Ok(())
+    }
+
     /// 종목코드에서 Symbol 생성 (국내).
     #[allow(dead_code)]
     fn code_to_symbol(code: &str) -> Symbol {
@@ -144,6 +180,22 @@ impl KisKrMarketStream {
         }
     }
 
+    /// KrRealtimeFuturesTrade를 Ticker로 변환 (선물은 당일 고가/저가 제공).
+    fn futures_trade_to_ticker(trade: &KrRealtimeFuturesTrade) -> Ticker {
+        Ticker {
+            ticker: trade.symbol.clone(),
+            bid: trade.price - dec!(0.05), // 근사값 (선물 호가단위 0.05pt)
+            ask: trade.price + dec!(0.05), // 근사값
+            last: trade.price,
+            volume_24h: Decimal::from(trade.acc_volume),
+            high_24h: trade.high,
+            low_24h: trade.low,
+            change_24h: trade.change,
+            change_24h_percent: trade.change_rate,
+            timestamp: Utc::now(),
+        }
+    }
+
     /// KrRealtimeTrade를 TradeTick으로 변환.
     #[allow(dead_code)]
     fn trade_to_tick(trade: &KrRealtimeTrade) -> TradeTick {
@@ -164,6 +216,24 @@ impl KisKrMarketStream {
         }
     }
 
+    /// KrRealtimeMarketStatus를 거래 상태 이벤트로 변환.
+    ///
+    /// 거래정지가 VI보다 우선하며, 정적+동적 VI 동시 발동은 정적 VI로 봅니다.
+    fn market_status_to_event(status: &KrRealtimeMarketStatus) -> TradingStatusEvent {
+        let trading_status = if status.trading_halted {
+            TradingStatus::Halted
+        } else if status.is_static_vi() {
+            TradingStatus::StaticVi
+        } else if status.is_vi_active() {
+            TradingStatus::DynamicVi
+        } else {
+            TradingStatus::Normal
+        };
+
+        TradingStatusEvent::new(status.symbol.clone(), trading_status)
+            .with_reason(status.halt_reason.clone())
+    }
+
     /// KrRealtimeOrderbook을 OrderBook으로 변환.
     fn orderbook_to_book(ob: &KrRealtimeOrderbook) -> OrderBook {
         let bids: Vec<OrderBookLevel> = ob
@@ -208,8 +278,14 @@ impl MarketStream for KisKrMarketStream {
         let code = symbol.to_string();
         let mut ws = self.ws.write().await;
 
-        // 체결가 구독으로 Ticker 정보 수신
-        ws.add_trade_subscription(&code);
+        if is_kr_futures_code(&code) {
+            // 지수선물은 별도 체결가 채널 사용 (장운영정보 채널은 주식 전용)
+            ws.add_futures_trade_subscription(&code);
+        } else {
+            // 체결가 구독으로 Ticker 정보 수신, 장운영정보로 거래정지/VI 감지
+            ws.add_trade_subscription(&code);
+            ws.add_market_status_subscription(&code);
+        }
 
         self.subscribed_symbols
             .entry(code.clone())
@@ -248,7 +324,11 @@ impl MarketStream for KisKrMarketStream {
         let code = symbol.to_string();
         let mut ws = self.ws.write().await;
 
-        ws.add_orderbook_subscription(&code);
+        if is_kr_futures_code(&code) {
+            ws.add_futures_orderbook_subscription(&code);
+        } else {
+            ws.add_orderbook_subscription(&code);
+        }
 
         self.subscribed_symbols
             .entry(code.clone())
@@ -280,9 +360,15 @@ impl MarketStream for KisKrMarketStream {
         let mut ws = self.ws.write().await;
 
         if let Some(sub_type) = self.subscribed_symbols.remove(&code) {
+            if is_kr_futures_code(&code) {
+                ws.remove_futures_trade_subscription(&code);
+                ws.remove_futures_orderbook_subscription(&code);
+                return Ok(());
+            }
             match sub_type {
                 SubscriptionType::Trade | SubscriptionType::Both => {
                     ws.remove_trade_subscription(&code);
+                    ws.remove_market_status_subscription(&code);
                 }
                 SubscriptionType::Orderbook => {
                     ws.remove_orderbook_subscription(&code);
@@ -299,15 +385,44 @@ impl MarketStream for KisKrMarketStream {
     async fn next_event(&mut self) -> Option<MarketEvent> {
         let rx = self.rx.as_mut()?;
 
-        match rx.recv().await {
+        loop {
+            let message = rx.recv().await;
+            if let Some(KrRealtimeMessage::Execution(notice)) = &message {
+                // 체결통보는 시세 이벤트가 아니므로 별도 채널로 전달하고 다음 메시지 대기
+                forward_execution(self.fill_tx.as_ref(), notice).await;
+                continue;
+            }
+            return Self::to_market_event(message);
+        }
+    }
+}
+
+impl KisKrMarketStream {
+    /// 실시간 메시지를 시장 이벤트로 변환 (체결통보 제외).
+    fn to_market_event(message: Option<KrRealtimeMessage>) -> Option<MarketEvent> {
+        match message {
             Some(KrRealtimeMessage::Trade(trade)) => {
                 debug!("KR Trade: {} @ {}", trade.symbol, trade.price);
                 Some(MarketEvent::Ticker(Self::trade_to_ticker(&trade)))
             }
+            Some(KrRealtimeMessage::FuturesTrade(trade)) => {
+                debug!("KR Futures Trade: {} @ {}", trade.symbol, trade.price);
+                Some(MarketEvent::Ticker(Self::futures_trade_to_ticker(&trade)))
+            }
             Some(KrRealtimeMessage::Orderbook(ob)) => {
                 debug!("KR Orderbook: {}", ob.symbol);
                 Some(MarketEvent::OrderBook(Self::orderbook_to_book(&ob)))
             }
+            Some(KrRealtimeMessage::MarketStatus(status)) => {
+                let event = Self::market_status_to_event(&status);
+                info!(
+                    symbol = %event.ticker,
+                    status = %event.status,
+                    reason = ?event.reason,
+                    "KR 거래 상태 변경"
+                );
+                Some(MarketEvent::TradingStatus(event))
+            }
             Some(KrRealtimeMessage::ConnectionStatus(connected)) => {
                 if connected {
                     info!("KIS KR WebSocket 연결됨");
@@ -317,11 +432,20 @@ impl MarketStream for KisKrMarketStream {
                     Some(MarketEvent::Disconnected)
                 }
             }
+            Some(KrRealtimeMessage::StreamStatus(status)) => {
+                info!(
+                    source = %status.source,
+                    state = %status.state,
+                    attempt = status.attempt,
+                    "KIS KR 스트림 상태 변경"
+                );
+                Some(MarketEvent::StreamStatus(status))
+            }
             Some(KrRealtimeMessage::Error(msg)) => {
                 error!("KIS KR WebSocket 에러: {}", msg);
                 Some(MarketEvent::Error(msg))
             }
-            None => None,
+            Some(KrRealtimeMessage::Execution(_)) | None => None,
         }
     }
 }
@@ -341,6 +465,8 @@ struct UsSubscriptionInfo {
 pub struct KisUsMarketStream {
     ws: Arc<RwLock<KisUsWebSocket>>,
     rx: Option<mpsc::Receiver<UsRealtimeMessage>>,
+    /// 체결통보 전달 채널 (`subscribe_executions()` 호출 시)
+    fill_tx: Option<mpsc::Sender<UserEvent>>,
     subscribed_symbols: HashMap<String, UsSubscriptionInfo>,
     started: bool,
 }
@@ -354,6 +480,7 @@ impl KisUsMarketStream {
         Self {
             ws: Arc::new(RwLock::new(ws)),
             rx,
+            fill_tx: None,
             subscribed_symbols: HashMap::new(),
             started: false,
         }
@@ -379,6 +506,29 @@ impl KisUsMarketStream {
         Ok(())
     }
 
+    /// 해외 실시간 체결통보 구독.
+    ///
+    /// 국내 스트림의 [`KisKrMarketStream::subscribe_executions`]와 같은 방식으로 동작합니다.
+    pub async fn subscribe_executions(&mut self) -> ExchangeResult<mpsc::Receiver<UserEvent>> {
+        let (tx, rx) = mpsc::channel(FILL_CHANNEL_CAPACITY);
+        self.forward_executions_to(tx).await?;
+        Ok(rx)
+    }
+
+    /// 체결통보 구독 후 지정한 채널로 전달.
+    async fn forward_executions_to(&mut self, tx: mpsc::Sender<UserEvent>) -> ExchangeResult<()> {
+        if self.started {
+            return Err(ExchangeError::NotSupported(
+                "Dynamic subscription not supported after connection".to_string(),
+            ));
+        }
+
+        self.ws.write().await.add_execution_subscription()?;
+        self.fill_tx = Some(tx);
+        info!("US 체결통보 구독 설정");
+        Ok(())
+    }
+
     /// 티커에서 Symbol 생성 (해외).
     #[allow(dead_code)]
     fn ticker_to_symbol(ticker: &str) -> Symbol {
@@ -528,7 +678,21 @@ impl MarketStream for KisUsMarketStream {
     async fn next_event(&mut self) -> Option<MarketEvent> {
         let rx = self.rx.as_mut()?;
 
-        match rx.recv().await {
+        loop {
+            let message = rx.recv().await;
+            if let Some(UsRealtimeMessage::Execution(notice)) = &message {
+                forward_execution(self.fill_tx.as_ref(), notice).await;
+                continue;
+            }
+            return Self::to_market_event(message);
+        }
+    }
+}
+
+impl KisUsMarketStream {
+    /// 실시간 메시지를 시장 이벤트로 변환 (체결통보 제외).
+    fn to_market_event(message: Option<UsRealtimeMessage>) -> Option<MarketEvent> {
+        match message {
             Some(UsRealtimeMessage::Trade(trade)) => {
                 debug!("US Trade: {} @ {}", trade.symbol, trade.price);
                 Some(MarketEvent::Ticker(Self::trade_to_ticker(&trade)))
@@ -546,26 +710,65 @@ impl MarketStream for KisUsMarketStream {
                     Some(MarketEvent::Disconnected)
                 }
             }
+            Some(UsRealtimeMessage::StreamStatus(status)) => {
+                info!(
+                    source = %status.source,
+                    state = %status.state,
+                    attempt = status.attempt,
+                    "KIS US 스트림 상태 변경"
+                );
+                Some(MarketEvent::StreamStatus(status))
+            }
             Some(UsRealtimeMessage::Error(msg)) => {
                 error!("KIS US WebSocket 에러: {}", msg);
                 Some(MarketEvent::Error(msg))
             }
-            None => None,
+            Some(UsRealtimeMessage::Execution(_)) | None => None,
         }
     }
 }
 
+/// KIS 체결통보를 사용자 이벤트 채널로 전달.
+///
+/// 접수/정정/취소 통보는 로그만 남기고, 실제 체결분만 `UserEvent::Fill`로 보냅니다.
+/// 체결 누락을 막기 위해 채널이 가득 차면 소비될 때까지 대기합니다.
+async fn forward_execution(fill_tx: Option<&mpsc::Sender<UserEvent>>, notice: &KisExecutionNotice) {
+    if notice.rejected {
+        warn!(order_no = %notice.order_no, ticker = %notice.ticker, "KIS 주문 거부 통보");
+    }
+    let Some(report) = notice.to_fill_report() else {
+        debug!(order_no = %notice.order_no, "KIS 주문 접수 통보");
+        return;
+    };
+
+    info!(
+        order_no = %report.exchange_order_id,
+        ticker = %report.ticker,
+        quantity = %report.quantity,
+        price = %report.price,
+        "KIS 체결통보 수신"
+    );
+    if let Some(tx) = fill_tx {
+        let _ = tx.send(UserEvent::Fill(report)).await;
+    }
+}
+
 // ============================================================================
 // 통합 MarketStream (여러 거래소 지원)
 // ============================================================================
 
 /// 여러 거래소를 통합하는 MarketStream.
 ///
-/// 국내(KR)와 해외(US) 시장을 모두 지원하며,
+/// 국내(KR), 해외(US) 주식과 Upbit 원화 마켓 암호화폐를 지원하며,
 /// 심볼에 따라 적절한 스트림으로 라우팅합니다.
+///
+/// - 6자리 숫자 종목코드: KIS 국내
+/// - `BTC/KRW`, `KRW-BTC` 형식: Upbit
+/// - 그 외: KIS 해외
 pub struct UnifiedMarketStream {
     kr_stream: Option<KisKrMarketStream>,
     us_stream: Option<KisUsMarketStream>,
+    upbit_stream: Option<UpbitMarketStream>,
     started: bool,
 }
 
@@ -575,6 +778,7 @@ impl UnifiedMarketStream {
         Self {
             kr_stream: None,
             us_stream: None,
+            upbit_stream: None,
             started: false,
         }
     }
@@ -591,6 +795,32 @@ impl UnifiedMarketStream {
         self
     }
 
+    /// Upbit 원화 마켓 스트림 추가 (시세 스트림은 인증 키 불필요).
+    pub fn with_upbit_stream(mut self, config: UpbitConfig) -> Self {
+        self.upbit_stream = Some(UpbitMarketStream::new(config));
+        self
+    }
+
+    /// KIS 국내/해외 실시간 체결통보 구독 (`KisConfig::hts_id` 필요).
+    ///
+    /// 두 스트림의 체결을 하나의 채널로 합쳐 반환합니다. `start_all()` 전에 호출해야 합니다.
+    pub async fn subscribe_executions(&mut self) -> ExchangeResult<mpsc::Receiver<UserEvent>> {
+        if self.kr_stream.is_none() && self.us_stream.is_none() {
+            return Err(ExchangeError::NotSupported(
+                "Execution notices require a KIS stream".to_string(),
+            ));
+        }
+
+        let (tx, rx) = mpsc::channel(FILL_CHANNEL_CAPACITY);
+        if let Some(ref mut kr) = self.kr_stream {
+            kr.forward_executions_to(tx.clone()).await?;
+        }
+        if let Some(ref mut us) = self.us_stream {
+            us.forward_executions_to(tx).await?;
+        }
+        Ok(rx)
+    }
+
     /// 모든 스트림 시작.
     pub async fn start_all(&mut self) -> ExchangeResult<()> {
         if self.started {
@@ -603,6 +833,9 @@ impl UnifiedMarketStream {
         if let Some(ref mut us) = self.us_stream {
             us.start().await?;
         }
+        if let Some(ref mut upbit) = self.upbit_stream {
+            upbit.start().await?;
+        }
 
         self.started = true;
         info!("UnifiedMarketStream 시작됨");
@@ -611,8 +844,45 @@ impl UnifiedMarketStream {
 
     /// 심볼이 국내인지 해외인지 판단.
     fn is_korean_symbol(ticker: &str) -> bool {
-        // 6자리 숫자 = 국내 주식
-        ticker.len() == 6 && ticker.chars().all(|c| c.is_ascii_digit())
+        // 6자리 숫자 = 국내 주식 ("005930/KRW" 표기 포함), 지수선물 단축코드 포함
+        let code = Self::korean_code(ticker);
+        (code.len() == 6 && code.chars().all(|c| c.is_ascii_digit())) || is_kr_futures_code(code)
+    }
+
+    /// 국내 종목코드 ("005930/KRW" -> "005930").
+    fn korean_code(ticker: &str) -> &str {
+        ticker.strip_suffix("/KRW").unwrap_or(ticker)
+    }
+
+    /// 심볼이 Upbit 원화 마켓 암호화폐인지 판단 (`BTC/KRW`, `KRW-BTC`).
+    fn is_upbit_symbol(ticker: &str) -> bool {
+        let base = ticker
+            .strip_suffix("/KRW")
+            .or_else(|| ticker.strip_prefix("KRW-"));
+        // "005930/KRW" 같은 국내 주식 표기는 제외
+        base.is_some_and(|base| !base.is_empty() && !base.chars().all(|c| c.is_ascii_digit()))
+    }
+
+    /// 심볼을 담당하는 스트림과 해당 스트림에 넘길 심볼.
+    fn stream_for<'a>(&mut self, symbol: &'a str) -> Option<(&mut dyn MarketStream, &'a str)> {
+        if Self::is_korean_symbol(symbol) {
+            let code = Self::korean_code(symbol);
+            self.kr_stream
+                .as_mut()
+                .map(|s| (s as &mut dyn MarketStream, code))
+        } else if Self::is_upbit_symbol(symbol) {
+            self.upbit_stream
+                .as_mut()
+                .map(|s| (s as &mut dyn MarketStream, symbol))
+        } else {
+            self.us_stream
+                .as_mut()
+                .map(|s| (s as &mut dyn MarketStream, symbol))
+        }
+    }
+
+    fn no_stream(symbol: &str) -> ExchangeError {
+        ExchangeError::NotSupported(format!("No stream available for symbol: {}", symbol))
     }
 }
 
@@ -622,89 +892,94 @@ impl Default for UnifiedMarketStream {
     }
 }
 
+/// 스트림이 없으면 영원히 대기 (select에서 제외).
+async fn next_from<S: MarketStream>(stream: &mut Option<S>) -> Option<MarketEvent> {
+    match stream {
+        Some(stream) => stream.next_event().await,
+        None => std::future::pending().await,
+    }
+}
+
 #[async_trait]
 impl MarketStream for UnifiedMarketStream {
     async fn subscribe_ticker(&mut self, symbol: &str) -> ExchangeResult<()> {
-        if Self::is_korean_symbol(symbol) {
-            if let Some(ref mut kr) = self.kr_stream {
-                return kr.subscribe_ticker(symbol).await;
-            }
-        } else if let Some(ref mut us) = self.us_stream {
-            return us.subscribe_ticker(symbol).await;
+        match self.stream_for(symbol) {
+            Some((stream, symbol)) => stream.subscribe_ticker(symbol).await,
+            None => Err(Self::no_stream(symbol)),
         }
-        Err(ExchangeError::NotSupported(format!(
-            "No stream available for symbol: {}",
-            symbol
-        )))
     }
 
     async fn subscribe_kline(&mut self, symbol: &str, timeframe: Timeframe) -> ExchangeResult<()> {
-        if Self::is_korean_symbol(symbol) {
-            if let Some(ref mut kr) = self.kr_stream {
-                return kr.subscribe_kline(symbol, timeframe).await;
-            }
-        } else if let Some(ref mut us) = self.us_stream {
-            return us.subscribe_kline(symbol, timeframe).await;
+        match self.stream_for(symbol) {
+            Some((stream, symbol)) => stream.subscribe_kline(symbol, timeframe).await,
+            None => Err(Self::no_stream(symbol)),
         }
-        Err(ExchangeError::NotSupported(format!(
-            "No stream available for symbol: {}",
-            symbol
-        )))
     }
 
     async fn subscribe_order_book(&mut self, symbol: &str) -> ExchangeResult<()> {
-        if Self::is_korean_symbol(symbol) {
-            if let Some(ref mut kr) = self.kr_stream {
-                return kr.subscribe_order_book(symbol).await;
-            }
-        } else if let Some(ref mut us) = self.us_stream {
-            return us.subscribe_order_book(symbol).await;
+        match self.stream_for(symbol) {
+            Some((stream, symbol)) => stream.subscribe_order_book(symbol).await,
+            None => Err(Self::no_stream(symbol)),
         }
-        Err(ExchangeError::NotSupported(format!(
-            "No stream available for symbol: {}",
-            symbol
-        )))
     }
 
     async fn subscribe_trades(&mut self, symbol: &str) -> ExchangeResult<()> {
-        if Self::is_korean_symbol(symbol) {
-            if let Some(ref mut kr) = self.kr_stream {
-                return kr.subscribe_trades(symbol).await;
-            }
-        } else if let Some(ref mut us) = self.us_stream {
-            return us.subscribe_trades(symbol).await;
+        match self.stream_for(symbol) {
+            Some((stream, symbol)) => stream.subscribe_trades(symbol).await,
+            None => Err(Self::no_stream(symbol)),
         }
-        Err(ExchangeError::NotSupported(format!(
-            "No stream available for symbol: {}",
-            symbol
-        )))
     }
 
     async fn unsubscribe(&mut self, symbol: &str) -> ExchangeResult<()> {
-        if Self::is_korean_symbol(symbol) {
-            if let Some(ref mut kr) = self.kr_stream {
-                return kr.unsubscribe(symbol).await;
-            }
-        } else if let Some(ref mut us) = self.us_stream {
-            return us.unsubscribe(symbol).await;
+        match self.stream_for(symbol) {
+            Some((stream, symbol)) => stream.unsubscribe(symbol).await,
+            None => Ok(()),
         }
-        Ok(())
     }
 
     async fn next_event(&mut self) -> Option<MarketEvent> {
-        // 간단한 라운드로빈: KR 먼저, 그 다음 US
-        // TODO: tokio::select!로 동시 수신 구현
-        if let Some(ref mut kr) = self.kr_stream {
-            if let Some(event) = kr.next_event().await {
-                return Some(event);
-            }
+        enum Source {
+            Kr,
+            Us,
+            Upbit,
         }
-        if let Some(ref mut us) = self.us_stream {
-            if let Some(event) = us.next_event().await {
-                return Some(event);
+
+        // 먼저 도착한 이벤트 반환, 종료된 스트림은 제외
+        loop {
+            if self.kr_stream.is_none() && self.us_stream.is_none() && self.upbit_stream.is_none() {
+                return None;
+            }
+
+            let received = {
+                let Self {
+                    kr_stream,
+                    us_stream,
+                    upbit_stream,
+                    ..
+                } = self;
+                tokio::select! {
+                    event = next_from(kr_stream) => event.ok_or(Source::Kr),
+                    event = next_from(us_stream) => event.ok_or(Source::Us),
+                    event = next_from(upbit_stream) => event.ok_or(Source::Upbit),
+                }
+            };
+
+            match received {
+                Ok(event) => return Some(event),
+                Err(Source::Kr) => {
+                    warn!("KIS KR 스트림 종료");
+                    self.kr_stream = None;
+                }
+                Err(Source::Us) => {
+                    warn!("KIS US 스트림 종료");
+                    self.us_stream = None;
+                }
+                Err(Source::Upbit) => {
+                    warn!("Upbit 스트림 종료");
+                    self.upbit_stream = None;
+                }
             }
         }
-        None
     }
 }
 
@@ -712,9 +987,92 @@ impl MarketStream for UnifiedMarketStream {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_market_status_to_event() {
+        let status = |halted: bool, vi_code: &str| KrRealtimeMarketStatus {
+            symbol: "005930".to_string(),
+            trading_halted: halted,
+            halt_reason: String::new(),
+            vi_code: vi_code.to_string(),
+        };
+
+        let to_status =
+            |s: &KrRealtimeMarketStatus| KisKrMarketStream::market_status_to_event(s).status;
+        assert_eq!(to_status(&status(false, "N")), TradingStatus::Normal);
+        assert_eq!(to_status(&status(false, "2")), TradingStatus::DynamicVi);
+        assert_eq!(to_status(&status(false, "3")), TradingStatus::StaticVi);
+        assert_eq!(to_status(&status(true, "2")), TradingStatus::Halted);
+    }
+
+    #[tokio::test]
+    async fn test_forward_execution_only_fills() {
+        let notice = |filled: bool| KisExecutionNotice {
+            account_no: "5012345601".to_string(),
+            order_no: "0000012345".to_string(),
+            original_order_no: String::new(),
+            ticker: "005930".to_string(),
+            side: Side::Buy,
+            filled_quantity: Decimal::from(10),
+            fill_price: Decimal::from(70100),
+            order_quantity: Decimal::from(10),
+            fill_time: "093015".to_string(),
+            filled,
+            rejected: false,
+        };
+        let (tx, mut rx) = mpsc::channel(4);
+
+        forward_execution(Some(&tx), &notice(false)).await;
+        assert!(rx.try_recv().is_err());
+
+        forward_execution(Some(&tx), &notice(true)).await;
+        match rx.try_recv().unwrap() {
+            UserEvent::Fill(report) => {
+                assert_eq!(report.exchange_order_id, "0000012345");
+                assert_eq!(report.price, Decimal::from(70100));
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+    }
+
     #[test]
     fn test_korean_symbol_detection() {
         assert!(UnifiedMarketStream::is_korean_symbol("005930/KRW"));
         assert!(!UnifiedMarketStream::is_korean_symbol("AAPL/USD"));
+        assert!(UnifiedMarketStream::is_korean_symbol("101W9000"));
+    }
+
+    #[test]
+    fn test_futures_trade_to_ticker() {
+        let trade = KrRealtimeFuturesTrade {
+            symbol: "101W9000".to_string(),
+            price: dec!(362.50),
+            open: dec!(360.00),
+            high: dec!(363.10),
+            low: dec!(359.85),
+            volume: 3,
+            acc_volume: 81234,
+            trade_time: "091530".to_string(),
+            sign: "2".to_string(),
+            change: dec!(2.15),
+            change_rate: dec!(0.60),
+            market_basis: dec!(1.32),
+            open_interest: 285120,
+        };
+
+        let ticker = KisKrMarketStream::futures_trade_to_ticker(&trade);
+        assert_eq!(ticker.ticker, "101W9000");
+        assert_eq!(ticker.high_24h, dec!(363.10));
+        assert_eq!(ticker.low_24h, dec!(359.85));
+        assert_eq!(ticker.bid, dec!(362.45));
+        assert_eq!(ticker.volume_24h, dec!(81234));
+    }
+
+    #[test]
+    fn test_upbit_symbol_detection() {
+        assert!(UnifiedMarketStream::is_upbit_symbol("BTC/KRW"));
+        assert!(UnifiedMarketStream::is_upbit_symbol("KRW-ETH"));
+        assert!(!UnifiedMarketStream::is_upbit_symbol("005930/KRW"));
+        assert!(!UnifiedMarketStream::is_upbit_symbol("BTC/USDT"));
+        assert!(!UnifiedMarketStream::is_upbit_symbol("AAPL"));
     }
 }
@@ -1,8 +1,10 @@
 //! 거래소 trait 정의.
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use trader_core::{
-    Kline, OrderBook, OrderRequest, OrderStatus, Position, Ticker, Timeframe, TradeTick,
+    Kline, OrderBook, OrderRequest, OrderStatus, Position, Side, StreamStatus, Ticker, Timeframe,
+    TradeTick, TradingStatusEvent,
 };
 
 use crate::ExchangeError;
@@ -120,19 +122,44 @@ pub enum MarketEvent {
     OrderBook(OrderBook),
     /// 체결 틱
     Trade(TradeTick),
+    /// 종목 거래 상태 변경 (거래정지, VI 발동/해제)
+    TradingStatus(TradingStatusEvent),
     /// 연결 상태 변경
     Connected,
     /// 연결 해제
     Disconnected,
+    /// 재연결 진행/복구/포기 (데이터 공백 구간 알림)
+    StreamStatus(StreamStatus),
     /// 에러 발생
     Error(String),
 }
 
+/// 거래소가 푸시한 개별 체결.
+///
+/// 주문 상태 전체가 아니라 한 번의 체결분만 담습니다 (예: KIS 실시간 체결통보).
+#[derive(Debug, Clone)]
+pub struct FillReport {
+    /// 거래소 주문 ID
+    pub exchange_order_id: String,
+    /// 종목 ticker
+    pub ticker: String,
+    /// 주문 방향
+    pub side: Side,
+    /// 이번 체결 수량
+    pub quantity: rust_decimal::Decimal,
+    /// 이번 체결 가격
+    pub price: rust_decimal::Decimal,
+    /// 체결 수신 시각
+    pub timestamp: DateTime<Utc>,
+}
+
 /// 사용자 데이터 스트림 이벤트.
 #[derive(Debug, Clone)]
 pub enum UserEvent {
     /// 주문 업데이트
     OrderUpdate(OrderStatus),
+    /// 개별 체결
+    Fill(FillReport),
     /// 잔고 업데이트
     BalanceUpdate(Balance),
     /// 포지션 업데이트 (선물)
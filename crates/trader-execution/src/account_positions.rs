@@ -0,0 +1,337 @@
+//! 다중 계좌 포지션 추적과 계좌 간 상계(netting).
+//!
+//! 모의투자, 실전 일반, 실전 ISA처럼 여러 계좌를 동시에 운용할 때 계좌마다
+//! 별도 `PositionTracker`를 두고, 같은 종목의 계좌별 포지션을 합친 상계 뷰를 제공합니다.
+//! 한 계좌의 롱과 다른 계좌의 숏은 순수량에서 서로 상쇄되며, 계좌별 내역은
+//! `NettedPosition::legs`에 그대로 남습니다.
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use trader_core::{Position, PositionSummary, Side};
+
+use crate::position_tracker::PositionTracker;
+
+/// 계좌별 포지션 (상계 입력).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountPositionLeg {
+    /// 계좌 키 (예: "paper", "real_isa")
+    pub account: String,
+    pub ticker: String,
+    pub side: Side,
+    pub quantity: Decimal,
+    /// 평균 진입가
+    pub entry_price: Decimal,
+    /// 현재가 (없으면 진입가로 평가)
+    pub current_price: Option<Decimal>,
+    pub unrealized_pnl: Decimal,
+    pub realized_pnl: Decimal,
+}
+
+impl AccountPositionLeg {
+    /// 트래커 포지션에서 생성.
+    pub fn from_position(account: impl Into<String>, position: &Position) -> Self {
+        Self {
+            account: account.into(),
+            ticker: position.ticker.clone(),
+            side: position.side,
+            quantity: position.quantity,
+            entry_price: position.entry_price,
+            current_price: Some(position.current_price),
+            unrealized_pnl: position.unrealized_pnl,
+            realized_pnl: position.realized_pnl,
+        }
+    }
+
+    /// 방향을 반영한 수량 (롱 +, 숏 -).
+    pub fn signed_quantity(&self) -> Decimal {
+        match self.side {
+            Side::Buy => self.quantity,
+            Side::Sell => -self.quantity,
+        }
+    }
+
+    fn mark_price(&self) -> Decimal {
+        self.current_price.unwrap_or(self.entry_price)
+    }
+}
+
+/// 종목별 계좌 간 상계 포지션.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NettedPosition {
+    pub ticker: String,
+    /// 순방향 (계좌 간 완전히 상쇄되면 `None`)
+    pub side: Option<Side>,
+    /// 순수량 (롱 +, 숏 -)
+    pub net_quantity: Decimal,
+    /// 계좌 롱 수량 합
+    pub long_quantity: Decimal,
+    /// 계좌 숏 수량 합
+    pub short_quantity: Decimal,
+    /// 순방향 계좌들의 수량 가중 평균 진입가 (완전 상쇄면 `None`)
+    pub average_entry_price: Option<Decimal>,
+    /// 현재가 (계좌 중 현재가가 있는 첫 값)
+    pub current_price: Option<Decimal>,
+    /// 순 평가금액 (순수량 × 현재가)
+    pub net_market_value: Decimal,
+    /// 총 익스포저 (계좌별 평가금액 절대값 합)
+    pub gross_exposure: Decimal,
+    pub unrealized_pnl: Decimal,
+    pub realized_pnl: Decimal,
+    /// 계좌별 내역 (계좌 키 순)
+    pub legs: Vec<AccountPositionLeg>,
+}
+
+/// 계좌별 포지션을 종목 단위로 상계한다 (종목 코드 순).
+pub fn net_positions(legs: impl IntoIterator<Item = AccountPositionLeg>) -> Vec<NettedPosition> {
+    let mut by_ticker: BTreeMap<String, Vec<AccountPositionLeg>> = BTreeMap::new();
+    for leg in legs {
+        if leg.quantity > Decimal::ZERO {
+            by_ticker.entry(leg.ticker.clone()).or_default().push(leg);
+        }
+    }
+
+    by_ticker
+        .into_iter()
+        .map(|(ticker, mut legs)| {
+            legs.sort_by(|a, b| a.account.cmp(&b.account));
+
+            let net_quantity: Decimal = legs.iter().map(|l| l.signed_quantity()).sum();
+            let side = if net_quantity > Decimal::ZERO {
+                Some(Side::Buy)
+            } else if net_quantity < Decimal::ZERO {
+                Some(Side::Sell)
+            } else {
+                None
+            };
+            let quantity_of = |side: Side| -> Decimal {
+                legs.iter()
+                    .filter(|l| l.side == side)
+                    .map(|l| l.quantity)
+                    .sum()
+            };
+            let average_entry_price = side.map(|side| {
+                let cost: Decimal = legs
+                    .iter()
+                    .filter(|l| l.side == side)
+                    .map(|l| l.entry_price * l.quantity)
+                    .sum();
+                cost / quantity_of(side)
+            });
+            let current_price = legs.iter().find_map(|l| l.current_price);
+
+            NettedPosition {
+                side,
+                net_quantity,
+                long_quantity: quantity_of(Side::Buy),
+                short_quantity: quantity_of(Side::Sell),
+                average_entry_price,
+                current_price,
+                net_market_value: legs
+                    .iter()
+                    .map(|l| l.signed_quantity() * l.mark_price())
+                    .sum(),
+                gross_exposure: legs.iter().map(|l| l.quantity * l.mark_price()).sum(),
+                unrealized_pnl: legs.iter().map(|l| l.unrealized_pnl).sum(),
+                realized_pnl: legs.iter().map(|l| l.realized_pnl).sum(),
+                ticker,
+                legs,
+            }
+        })
+        .collect()
+}
+
+/// 계좌별 `PositionTracker` 묶음.
+///
+/// 계좌마다 종목당 오픈 포지션 하나라는 `PositionTracker` 규칙을 그대로 쓰므로
+/// 같은 종목을 여러 계좌에서 반대 방향으로 보유할 수 있습니다.
+#[derive(Debug)]
+pub struct MultiAccountPositionTracker {
+    exchange: String,
+    accounts: HashMap<String, PositionTracker>,
+}
+
+impl MultiAccountPositionTracker {
+    /// 새 다중 계좌 트래커를 생성한다.
+    pub fn new(exchange: impl Into<String>) -> Self {
+        Self {
+            exchange: exchange.into(),
+            accounts: HashMap::new(),
+        }
+    }
+
+    /// 계좌 트래커를 등록한다 (초기 자금 등을 설정한 트래커를 쓸 때).
+    pub fn insert_account(&mut self, account: impl Into<String>, tracker: PositionTracker) {
+        let account = account.into();
+        let tracker = tracker.with_account(account.clone());
+        self.accounts.insert(account, tracker);
+    }
+
+    /// 계좌 트래커 (없으면 `None`).
+    pub fn account(&self, account: &str) -> Option<&PositionTracker> {
+        self.accounts.get(account)
+    }
+
+    /// 계좌 트래커를 가져온다 (없으면 생성).
+    pub fn account_mut(&mut self, account: &str) -> &mut PositionTracker {
+        let exchange = &self.exchange;
+        self.accounts
+            .entry(account.to_string())
+            .or_insert_with(|| PositionTracker::new(exchange.clone()).with_account(account))
+    }
+
+    /// 등록된 계좌 키 (정렬).
+    pub fn accounts(&self) -> Vec<&str> {
+        let mut accounts: Vec<&str> = self.accounts.keys().map(String::as_str).collect();
+        accounts.sort_unstable();
+        accounts
+    }
+
+    /// 모든 계좌의 포지션 가격을 갱신한다.
+    pub fn update_prices(&mut self, prices: &HashMap<String, Decimal>) {
+        for tracker in self.accounts.values_mut() {
+            tracker.update_prices(prices);
+        }
+    }
+
+    /// 계좌별 오픈 포지션 (계좌 키 순).
+    pub fn legs(&self) -> Vec<AccountPositionLeg> {
+        let mut legs: Vec<AccountPositionLeg> = self
+            .accounts
+            .iter()
+            .flat_map(|(account, tracker)| {
+                tracker
+                    .get_open_positions()
+                    .into_iter()
+                    .map(move |p| AccountPositionLeg::from_position(account.clone(), p))
+            })
+            .collect();
+        legs.sort_by(|a, b| (&a.account, &a.ticker).cmp(&(&b.account, &b.ticker)));
+        legs
+    }
+
+    /// 종목별 계좌 간 상계 뷰.
+    pub fn netted_positions(&self) -> Vec<NettedPosition> {
+        net_positions(self.legs())
+    }
+
+    /// 계좌별 포지션 요약.
+    pub fn summary_by_account(&self) -> BTreeMap<String, PositionSummary> {
+        self.accounts
+            .iter()
+            .map(|(account, tracker)| (account.clone(), tracker.get_summary()))
+            .collect()
+    }
+
+    /// 전체 계좌 합산 요약 (상계 전).
+    pub fn combined_summary(&self) -> PositionSummary {
+        let positions: Vec<Position> = self
+            .accounts
+            .values()
+            .flat_map(|tracker| tracker.get_open_positions().into_iter().cloned())
+            .collect();
+        PositionSummary::from_positions(&positions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal::prelude::FromPrimitive;
+
+    /// 숫자로부터 Decimal을 생성하는 헬퍼 매크로
+    macro_rules! dec {
+        ($val:expr) => {
+            Decimal::from_f64($val as f64).unwrap()
+        };
+    }
+
+    fn leg(
+        account: &str,
+        ticker: &str,
+        side: Side,
+        qty: Decimal,
+        entry: Decimal,
+    ) -> AccountPositionLeg {
+        AccountPositionLeg {
+            account: account.to_string(),
+            ticker: ticker.to_string(),
+            side,
+            quantity: qty,
+            entry_price: entry,
+            current_price: Some(dec!(110)),
+            unrealized_pnl: Decimal::ZERO,
+            realized_pnl: Decimal::ZERO,
+        }
+    }
+
+    #[test]
+    fn test_net_positions_offsets_opposite_accounts() {
+        let netted = net_positions(vec![
+            leg("real_general", "005930", Side::Buy, dec!(10), dec!(100)),
+            leg("real_isa", "005930", Side::Buy, dec!(30), dec!(120)),
+            leg("paper", "005930", Side::Sell, dec!(15), dec!(105)),
+            leg("paper", "AAPL", Side::Buy, dec!(5), dec!(200)),
+        ]);
+
+        assert_eq!(netted.len(), 2);
+        let samsung = &netted[0];
+        assert_eq!(samsung.ticker, "005930");
+        assert_eq!(samsung.side, Some(Side::Buy));
+        assert_eq!(samsung.net_quantity, dec!(25));
+        assert_eq!(samsung.long_quantity, dec!(40));
+        assert_eq!(samsung.short_quantity, dec!(15));
+        // 롱 계좌만의 가중 평균: (10×100 + 30×120) / 40
+        assert_eq!(samsung.average_entry_price, Some(dec!(115)));
+        assert_eq!(samsung.net_market_value, dec!(2750));
+        assert_eq!(samsung.gross_exposure, dec!(6050));
+        let accounts: Vec<&str> = samsung.legs.iter().map(|l| l.account.as_str()).collect();
+        assert_eq!(accounts, vec!["paper", "real_general", "real_isa"]);
+    }
+
+    #[test]
+    fn test_net_positions_fully_hedged() {
+        let netted = net_positions(vec![
+            leg("real_general", "005930", Side::Buy, dec!(10), dec!(100)),
+            leg("paper", "005930", Side::Sell, dec!(10), dec!(100)),
+        ]);
+
+        assert_eq!(netted[0].side, None);
+        assert_eq!(netted[0].net_quantity, Decimal::ZERO);
+        assert_eq!(netted[0].average_entry_price, None);
+        assert_eq!(netted[0].net_market_value, Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_multi_account_tracker_keeps_accounts_separate() {
+        let mut tracker = MultiAccountPositionTracker::new("kis");
+        tracker
+            .account_mut("real_general")
+            .open_position("005930".to_string(), Side::Buy, dec!(10), dec!(100), None)
+            .unwrap();
+        // 같은 종목이라도 다른 계좌에는 별도 포지션
+        tracker
+            .account_mut("real_isa")
+            .open_position("005930".to_string(), Side::Buy, dec!(5), dec!(130), None)
+            .unwrap();
+        tracker.update_prices(&HashMap::from([("005930".to_string(), dec!(120))]));
+
+        assert_eq!(tracker.accounts(), vec!["real_general", "real_isa"]);
+        assert_eq!(
+            tracker.account("real_isa").unwrap().account(),
+            Some("real_isa")
+        );
+
+        let netted = tracker.netted_positions();
+        assert_eq!(netted.len(), 1);
+        assert_eq!(netted[0].net_quantity, dec!(15));
+        assert_eq!(netted[0].average_entry_price, Some(dec!(110)));
+        assert_eq!(netted[0].unrealized_pnl, dec!(150));
+
+        let by_account = tracker.summary_by_account();
+        assert_eq!(by_account["real_general"].total_unrealized_pnl, dec!(200));
+        assert_eq!(tracker.combined_summary().total_positions, 2);
+    }
+}
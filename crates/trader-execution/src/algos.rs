@@ -0,0 +1,866 @@
+//! 알고리즘 분할 주문 (TWAP / VWAP / 아이스버그).
+//!
+//! 대량 주문(부모 주문)을 여러 개의 자식 주문으로 나눠 시간에 걸쳐
+//! `Exchange` trait으로 제출합니다. 국내 주식 리밸런싱처럼 한 번에 내면
+//! 호가를 크게 움직이는 주문의 시장 충격을 줄이기 위한 용도입니다.
+//!
+//! - TWAP: 실행 구간을 균등한 시간 간격으로 나눠 같은 수량씩 제출
+//! - VWAP: 구간별 거래량 프로파일 비중대로 수량을 배분해 제출
+//! - 아이스버그: 노출 수량만큼만 주문하고, 체결되면 다음 조각을 제출
+//!
+//! 실행 상태는 [`AlgoHandle`]로 조회하며, 취소하면 남은 조각 제출을 멈추고
+//! 미체결 자식 주문을 모두 취소합니다.
+//!
+//! # 예시
+//!
+//! ```rust,ignore
+//! let order = ParentOrder::new("005930", Side::Buy, dec!(3000), AlgoStrategy::Twap {
+//!     duration_secs: 1800,
+//!     slices: 30,
+//! });
+//! let (executor, handle) = AlgoExecutor::new(exchange, order)?;
+//! tokio::spawn(executor.run());
+//!
+//! let progress = handle.progress().await;
+//! handle.cancel(); // 남은 조각 중단 + 미체결 자식 주문 일괄 취소
+//! ```
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use rust_decimal::{Decimal, RoundingStrategy};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tokio::sync::{watch, RwLock};
+use tokio::time::Instant;
+use tracing::{debug, info, warn};
+use trader_core::{OrderRequest, OrderStatusType, Side};
+use trader_exchange::Exchange;
+use uuid::Uuid;
+
+/// 자식 주문 상태 조회 기본 주기.
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// 알고리즘 주문 에러 타입.
+#[derive(Debug, Error)]
+pub enum AlgoError {
+    #[error("Invalid algo order: {0}")]
+    InvalidOrder(String),
+}
+
+/// 분할 실행 방식.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AlgoStrategy {
+    /// 시간 가중 분할 (균등 간격, 균등 수량)
+    Twap { duration_secs: u64, slices: u32 },
+    /// 거래량 가중 분할 (구간별 거래량 비중으로 수량 배분)
+    Vwap {
+        duration_secs: u64,
+        volume_profile: Vec<Decimal>,
+    },
+    /// 아이스버그 (노출 수량만큼씩 순차 제출)
+    Iceberg { display_quantity: Decimal },
+}
+
+/// 분할 실행할 부모 주문.
+#[derive(Debug, Clone)]
+pub struct ParentOrder {
+    /// 종목 코드
+    pub ticker: String,
+    /// 매수/매도
+    pub side: Side,
+    /// 전체 주문 수량
+    pub quantity: Decimal,
+    /// 자식 주문 지정가 (없으면 시장가)
+    pub limit_price: Option<Decimal>,
+    /// 전략 ID (자식 주문에 전달)
+    pub strategy_id: Option<String>,
+    /// 분할 실행 방식
+    pub algo: AlgoStrategy,
+}
+
+impl ParentOrder {
+    /// 시장가 부모 주문 생성.
+    pub fn new(
+        ticker: impl Into<String>,
+        side: Side,
+        quantity: Decimal,
+        algo: AlgoStrategy,
+    ) -> Self {
+        Self {
+            ticker: ticker.into(),
+            side,
+            quantity,
+            limit_price: None,
+            strategy_id: None,
+            algo,
+        }
+    }
+
+    /// 자식 주문을 지정가로 제출.
+    pub fn with_limit_price(mut self, price: Decimal) -> Self {
+        self.limit_price = Some(price);
+        self
+    }
+
+    /// 전략 ID 설정.
+    pub fn with_strategy(mut self, strategy_id: impl Into<String>) -> Self {
+        self.strategy_id = Some(strategy_id.into());
+        self
+    }
+
+    /// 자식 주문 요청 생성.
+    fn child_request(&self, quantity: Decimal, client_id: String) -> OrderRequest {
+        let ticker = self.ticker.clone();
+        let request = match (self.side, self.limit_price) {
+            (Side::Buy, Some(price)) => OrderRequest::limit_buy(ticker, quantity, price),
+            (Side::Sell, Some(price)) => OrderRequest::limit_sell(ticker, quantity, price),
+            (Side::Buy, None) => OrderRequest::market_buy(ticker, quantity),
+            (Side::Sell, None) => OrderRequest::market_sell(ticker, quantity),
+        };
+        let request = request.with_client_id(client_id);
+        match &self.strategy_id {
+            Some(strategy_id) => request.with_strategy(strategy_id.clone()),
+            None => request,
+        }
+    }
+}
+
+/// 계획된 자식 주문 조각.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AlgoSlice {
+    /// 실행 시작 시점부터의 제출 시점
+    pub offset: Duration,
+    /// 조각 수량
+    pub quantity: Decimal,
+}
+
+/// 부모 주문을 자식 주문 조각으로 분할.
+///
+/// 주식은 1주 단위, 암호화폐(`BASE/QUOTE`)는 소수점 8자리 단위로 나누며
+/// 단수는 누적 비중 기준으로 배분해 조각 합이 전체 수량과 정확히 일치합니다.
+/// 수량이 0인 조각은 제외합니다. 아이스버그는 노출 수량 단위 조각을 반환하며
+/// 제출 시점은 앞 조각의 체결에 따라 정해지므로 `offset`은 모두 0입니다.
+pub fn plan_slices(order: &ParentOrder) -> Result<Vec<AlgoSlice>, AlgoError> {
+    if order.quantity <= Decimal::ZERO {
+        return Err(AlgoError::InvalidOrder(format!(
+            "quantity must be positive: {}",
+            order.quantity
+        )));
+    }
+    let dp = quantity_dp(&order.ticker);
+
+    match &order.algo {
+        AlgoStrategy::Twap {
+            duration_secs,
+            slices,
+        } => {
+            if *slices == 0 {
+                return Err(AlgoError::InvalidOrder(
+                    "TWAP requires at least one slice".to_string(),
+                ));
+            }
+            let weights = vec![Decimal::ONE; *slices as usize];
+            Ok(split_by_weights(
+                order.quantity,
+                &weights,
+                *duration_secs,
+                dp,
+            ))
+        }
+        AlgoStrategy::Vwap {
+            duration_secs,
+            volume_profile,
+        } => {
+            if volume_profile.is_empty() || volume_profile.iter().any(|v| *v < Decimal::ZERO) {
+                return Err(AlgoError::InvalidOrder(
+                    "VWAP volume profile must be non-empty and non-negative".to_string(),
+                ));
+            }
+            if volume_profile.iter().sum::<Decimal>() <= Decimal::ZERO {
+                return Err(AlgoError::InvalidOrder(
+                    "VWAP volume profile has no volume".to_string(),
+                ));
+            }
+            Ok(split_by_weights(
+                order.quantity,
+                volume_profile,
+                *duration_secs,
+                dp,
+            ))
+        }
+        AlgoStrategy::Iceberg { display_quantity } => {
+            let display = display_quantity.round_dp_with_strategy(dp, RoundingStrategy::ToZero);
+            if display <= Decimal::ZERO {
+                return Err(AlgoError::InvalidOrder(format!(
+                    "iceberg display quantity too small: {}",
+                    display_quantity
+                )));
+            }
+            let mut slices = Vec::new();
+            let mut remaining = order.quantity;
+            while remaining > Decimal::ZERO {
+                let quantity = remaining.min(display);
+                slices.push(AlgoSlice {
+                    offset: Duration::ZERO,
+                    quantity,
+                });
+                remaining -= quantity;
+            }
+            Ok(slices)
+        }
+    }
+}
+
+/// 주문 수량 소수점 자릿수 (주식 0, 암호화폐 8).
+fn quantity_dp(ticker: &str) -> u32 {
+    if ticker.contains('/') {
+        8
+    } else {
+        0
+    }
+}
+
+/// 비중대로 수량을 나누고 구간을 균등한 시간 간격에 배치.
+fn split_by_weights(
+    total: Decimal,
+    weights: &[Decimal],
+    duration_secs: u64,
+    dp: u32,
+) -> Vec<AlgoSlice> {
+    let weight_sum: Decimal = weights.iter().sum();
+    let duration = Duration::from_secs(duration_secs);
+    let count = weights.len() as u32;
+
+    let mut slices = Vec::new();
+    let mut cumulative_weight = Decimal::ZERO;
+    let mut allocated = Decimal::ZERO;
+    for (i, weight) in weights.iter().enumerate() {
+        cumulative_weight += weight;
+        let target = if i + 1 == weights.len() {
+            total
+        } else {
+            (total * cumulative_weight / weight_sum)
+                .round_dp_with_strategy(dp, RoundingStrategy::ToZero)
+        };
+        let quantity = target - allocated;
+        if quantity > Decimal::ZERO {
+            slices.push(AlgoSlice {
+                offset: duration * i as u32 / count,
+                quantity: quantity.normalize(),
+            });
+            allocated = target;
+        }
+    }
+    slices
+}
+
+/// 알고리즘 실행 상태.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AlgoStatus {
+    /// 실행 중
+    Running,
+    /// 전량 체결 완료
+    Completed,
+    /// 사용자 취소 (미체결 자식 주문 취소됨)
+    Cancelled,
+    /// 주문 제출 실패 또는 미체결 잔량으로 종료
+    Failed,
+}
+
+impl AlgoStatus {
+    /// 최종 상태 여부.
+    pub fn is_final(&self) -> bool {
+        !matches!(self, AlgoStatus::Running)
+    }
+}
+
+/// 제출된 자식 주문.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AlgoChildOrder {
+    /// 거래소 주문 ID
+    pub exchange_order_id: String,
+    /// 주문 수량
+    pub quantity: Decimal,
+    /// 체결 수량
+    pub filled_quantity: Decimal,
+    /// 거래소 주문 상태
+    pub status: OrderStatusType,
+    /// 제출 시각
+    pub submitted_at: DateTime<Utc>,
+}
+
+/// 알고리즘 주문 진행 상황.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AlgoProgress {
+    /// 알고리즘 주문 ID
+    pub algo_id: Uuid,
+    /// 종목 코드
+    pub ticker: String,
+    /// 매수/매도
+    pub side: Side,
+    /// 실행 상태
+    pub status: AlgoStatus,
+    /// 부모 주문 수량
+    pub total_quantity: Decimal,
+    /// 제출된 자식 주문 수량 합계
+    pub submitted_quantity: Decimal,
+    /// 체결된 수량 합계
+    pub filled_quantity: Decimal,
+    /// 계획된 조각 수
+    pub planned_slices: usize,
+    /// 제출된 자식 주문
+    pub children: Vec<AlgoChildOrder>,
+    /// 시작 시각
+    pub started_at: DateTime<Utc>,
+    /// 종료 시각
+    pub finished_at: Option<DateTime<Utc>>,
+    /// 실패 사유
+    pub error: Option<String>,
+}
+
+impl AlgoProgress {
+    /// 미체결 잔량.
+    pub fn remaining_quantity(&self) -> Decimal {
+        (self.total_quantity - self.filled_quantity).max(Decimal::ZERO)
+    }
+
+    /// 체결률 (0~1).
+    pub fn fill_ratio(&self) -> Decimal {
+        if self.total_quantity.is_zero() {
+            return Decimal::ZERO;
+        }
+        self.filled_quantity / self.total_quantity
+    }
+
+    /// 아직 최종 상태가 아닌 자식 주문이 있는지 확인.
+    pub fn has_open_children(&self) -> bool {
+        self.children.iter().any(|c| !c.status.is_final())
+    }
+
+    fn recompute_filled(&mut self) {
+        self.filled_quantity = self.children.iter().map(|c| c.filled_quantity).sum();
+    }
+}
+
+/// 실행 중인 알고리즘 주문의 조회/취소 핸들.
+#[derive(Debug, Clone)]
+pub struct AlgoHandle {
+    algo_id: Uuid,
+    progress: Arc<RwLock<AlgoProgress>>,
+    cancel_tx: Arc<watch::Sender<bool>>,
+}
+
+impl AlgoHandle {
+    /// 알고리즘 주문 ID.
+    pub fn algo_id(&self) -> Uuid {
+        self.algo_id
+    }
+
+    /// 현재 진행 상황 조회.
+    pub async fn progress(&self) -> AlgoProgress {
+        self.progress.read().await.clone()
+    }
+
+    /// 실행 취소.
+    ///
+    /// 남은 조각 제출을 멈추고 미체결 자식 주문을 모두 취소합니다.
+    /// 취소 완료 여부는 `progress()`의 상태로 확인합니다.
+    pub fn cancel(&self) {
+        self.cancel_tx.send_replace(true);
+    }
+
+    /// 취소 요청 여부.
+    pub fn is_cancel_requested(&self) -> bool {
+        *self.cancel_tx.borrow()
+    }
+}
+
+/// 실행 중단 사유.
+enum Halt {
+    Cancelled,
+    Failed(String),
+}
+
+/// 알고리즘 주문 실행기.
+///
+/// `run()`을 별도 task로 실행하고, 진행 상황 조회와 취소는 [`AlgoHandle`]로 합니다.
+pub struct AlgoExecutor {
+    exchange: Arc<dyn Exchange>,
+    order: ParentOrder,
+    slices: Vec<AlgoSlice>,
+    progress: Arc<RwLock<AlgoProgress>>,
+    cancel_rx: watch::Receiver<bool>,
+    poll_interval: Duration,
+    next_child_seq: u32,
+}
+
+impl AlgoExecutor {
+    /// 실행기와 핸들 생성.
+    ///
+    /// 분할 계획이 유효하지 않으면 에러를 반환합니다.
+    pub fn new(
+        exchange: Arc<dyn Exchange>,
+        order: ParentOrder,
+    ) -> Result<(Self, AlgoHandle), AlgoError> {
+        let slices = plan_slices(&order)?;
+        let algo_id = Uuid::new_v4();
+        let progress = Arc::new(RwLock::new(AlgoProgress {
+            algo_id,
+            ticker: order.ticker.clone(),
+            side: order.side,
+            status: AlgoStatus::Running,
+            total_quantity: order.quantity,
+            submitted_quantity: Decimal::ZERO,
+            filled_quantity: Decimal::ZERO,
+            planned_slices: slices.len(),
+            children: Vec::new(),
+            started_at: Utc::now(),
+            finished_at: None,
+            error: None,
+        }));
+        let (cancel_tx, cancel_rx) = watch::channel(false);
+
+        let handle = AlgoHandle {
+            algo_id,
+            progress: progress.clone(),
+            cancel_tx: Arc::new(cancel_tx),
+        };
+        let executor = Self {
+            exchange,
+            order,
+            slices,
+            progress,
+            cancel_rx,
+            poll_interval: DEFAULT_POLL_INTERVAL,
+            next_child_seq: 0,
+        };
+        Ok((executor, handle))
+    }
+
+    /// 자식 주문 상태 조회 주기 설정.
+    pub fn with_poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+
+    /// 분할 실행 후 최종 진행 상황 반환.
+    pub async fn run(mut self) -> AlgoProgress {
+        let algo_id = self.progress.read().await.algo_id;
+        info!(
+            algo_id = %algo_id,
+            ticker = %self.order.ticker,
+            side = ?self.order.side,
+            quantity = %self.order.quantity,
+            slices = self.slices.len(),
+            "Algo order started"
+        );
+
+        let outcome = match self.order.algo {
+            AlgoStrategy::Iceberg { .. } => self.run_iceberg().await,
+            AlgoStrategy::Twap { .. } | AlgoStrategy::Vwap { .. } => self.run_scheduled().await,
+        };
+
+        let (status, error) = match outcome {
+            Ok(()) => (AlgoStatus::Completed, None),
+            Err(halt) => {
+                self.cancel_open_children().await;
+                match halt {
+                    Halt::Cancelled => (AlgoStatus::Cancelled, None),
+                    Halt::Failed(reason) => (AlgoStatus::Failed, Some(reason)),
+                }
+            }
+        };
+
+        let mut progress = self.progress.write().await;
+        progress.status = status;
+        progress.error = error;
+        progress.finished_at = Some(Utc::now());
+        info!(
+            algo_id = %algo_id,
+            status = ?progress.status,
+            filled = %progress.filled_quantity,
+            total = %progress.total_quantity,
+            "Algo order finished"
+        );
+        progress.clone()
+    }
+
+    /// TWAP/VWAP: 계획된 시점마다 조각을 제출하고 전량 체결을 기다림.
+    async fn run_scheduled(&mut self) -> Result<(), Halt> {
+        let start = Instant::now();
+        let slices = std::mem::take(&mut self.slices);
+        for slice in &slices {
+            self.wait_until(start + slice.offset).await?;
+            self.submit_child(slice.quantity).await?;
+        }
+
+        loop {
+            self.refresh_children().await;
+            let progress = self.progress.read().await;
+            if !progress.has_open_children() {
+                if progress.remaining_quantity().is_zero() {
+                    return Ok(());
+                }
+                return Err(Halt::Failed(format!(
+                    "child orders closed with {} unfilled",
+                    progress.remaining_quantity()
+                )));
+            }
+            drop(progress);
+            self.wait_until(Instant::now() + self.poll_interval).await?;
+        }
+    }
+
+    /// 아이스버그: 노출 수량 주문이 체결되면 다음 조각 제출.
+    async fn run_iceberg(&mut self) -> Result<(), Halt> {
+        let slices = std::mem::take(&mut self.slices);
+        let display = slices.first().map(|s| s.quantity).unwrap_or_default();
+
+        loop {
+            self.refresh_children().await;
+            let progress = self.progress.read().await;
+            if progress.remaining_quantity().is_zero() {
+                return Ok(());
+            }
+            if progress.has_open_children() {
+                drop(progress);
+                self.wait_until(Instant::now() + self.poll_interval).await?;
+                continue;
+            }
+            // 직전 조각이 체결 없이 닫혔으면 (거래소 취소/거부) 중단
+            if let Some(last) = progress.children.last() {
+                if last.status != OrderStatusType::Filled {
+                    return Err(Halt::Failed(format!(
+                        "child order {} closed as {:?}",
+                        last.exchange_order_id, last.status
+                    )));
+                }
+            }
+            let quantity = progress.remaining_quantity().min(display);
+            drop(progress);
+
+            if *self.cancel_rx.borrow() {
+                return Err(Halt::Cancelled);
+            }
+            self.submit_child(quantity).await?;
+        }
+    }
+
+    /// 지정 시점까지 대기하며 자식 주문 상태를 주기적으로 갱신.
+    ///
+    /// 대기 중 취소 요청이 오면 `Halt::Cancelled`를 반환합니다.
+    async fn wait_until(&mut self, deadline: Instant) -> Result<(), Halt> {
+        loop {
+            if *self.cancel_rx.borrow() {
+                return Err(Halt::Cancelled);
+            }
+            let now = Instant::now();
+            if now >= deadline {
+                return Ok(());
+            }
+
+            let wake = deadline.min(now + self.poll_interval);
+            tokio::select! {
+                _ = tokio::time::sleep_until(wake) => {}
+                changed = self.cancel_rx.changed() => {
+                    // 핸들이 모두 drop되면 취소할 수 없으므로 끝까지 실행
+                    if changed.is_err() {
+                        tokio::time::sleep_until(wake).await;
+                    }
+                }
+            }
+            if Instant::now() < deadline {
+                self.refresh_children().await;
+            }
+        }
+    }
+
+    /// 자식 주문 제출.
+    async fn submit_child(&mut self, quantity: Decimal) -> Result<(), Halt> {
+        let algo_id = self.progress.read().await.algo_id;
+        self.next_child_seq += 1;
+        let client_id = format!("{}-{}", algo_id.simple(), self.next_child_seq);
+        let request = self.order.child_request(quantity, client_id);
+
+        let order_id = self.exchange.place_order(&request).await.map_err(|e| {
+            warn!(algo_id = %algo_id, error = %e, "Failed to place algo child order");
+            Halt::Failed(format!("child order rejected: {}", e))
+        })?;
+        debug!(
+            algo_id = %algo_id,
+            order_id = %order_id,
+            quantity = %quantity,
+            "Algo child order placed"
+        );
+
+        let mut progress = self.progress.write().await;
+        progress.submitted_quantity += quantity;
+        progress.children.push(AlgoChildOrder {
+            exchange_order_id: order_id,
+            quantity,
+            filled_quantity: Decimal::ZERO,
+            status: OrderStatusType::Open,
+            submitted_at: Utc::now(),
+        });
+        Ok(())
+    }
+
+    /// 미체결 자식 주문의 거래소 상태 갱신.
+    async fn refresh_children(&self) {
+        let open_ids: Vec<String> = {
+            let progress = self.progress.read().await;
+            progress
+                .children
+                .iter()
+                .filter(|c| !c.status.is_final())
+                .map(|c| c.exchange_order_id.clone())
+                .collect()
+        };
+
+        for order_id in open_ids {
+            match self.exchange.get_order(&self.order.ticker, &order_id).await {
+                Ok(status) => {
+                    let mut progress = self.progress.write().await;
+                    if let Some(child) = progress
+                        .children
+                        .iter_mut()
+                        .find(|c| c.exchange_order_id == order_id)
+                    {
+                        child.status = status.status;
+                        child.filled_quantity = status.filled_quantity;
+                    }
+                    progress.recompute_filled();
+                }
+                Err(e) => {
+                    warn!(order_id = %order_id, error = %e, "Failed to refresh algo child order");
+                }
+            }
+        }
+    }
+
+    /// 미체결 자식 주문 일괄 취소.
+    async fn cancel_open_children(&self) {
+        let open_ids: Vec<String> = {
+            let progress = self.progress.read().await;
+            progress
+                .children
+                .iter()
+                .filter(|c| !c.status.is_final())
+                .map(|c| c.exchange_order_id.clone())
+                .collect()
+        };
+
+        for order_id in &open_ids {
+            if let Err(e) = self
+                .exchange
+                .cancel_order(&self.order.ticker, order_id)
+                .await
+            {
+                warn!(order_id = %order_id, error = %e, "Failed to cancel algo child order");
+            }
+        }
+        // 취소 직전 부분 체결을 반영
+        self.refresh_children().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal::prelude::FromPrimitive;
+    use trader_core::{Kline, Timeframe};
+    use trader_exchange::{SimulatedConfig, SimulatedExchange};
+
+    /// 숫자로부터 Decimal을 생성하는 헬퍼 매크로
+    macro_rules! dec {
+        ($val:expr) => {
+            Decimal::from_f64($val as f64).unwrap()
+        };
+    }
+
+    /// 가격 50000으로 고정된 BTC/USDT 캔들을 가진 거래소 생성.
+    async fn create_exchange() -> Arc<dyn Exchange> {
+        let config = SimulatedConfig::default().with_initial_balance("USDT", dec!(1000000));
+        let exchange = SimulatedExchange::new(config);
+
+        let start = Utc::now();
+        let klines = (0..5)
+            .map(|i| Kline {
+                ticker: "BTC/USDT".to_string(),
+                timeframe: Timeframe::M1,
+                open_time: start + chrono::Duration::minutes(i),
+                close_time: start + chrono::Duration::minutes(i + 1),
+                open: dec!(50000),
+                high: dec!(50000),
+                low: dec!(50000),
+                close: dec!(50000),
+                volume: dec!(100),
+                quote_volume: None,
+                num_trades: None,
+            })
+            .collect();
+        exchange
+            .load_klines("BTC/USDT".to_string(), Timeframe::M1, klines)
+            .await;
+        exchange.step("BTC/USDT", Timeframe::M1).await;
+        Arc::new(exchange)
+    }
+
+    fn quantities(slices: &[AlgoSlice]) -> Vec<Decimal> {
+        slices.iter().map(|s| s.quantity).collect()
+    }
+
+    #[test]
+    fn test_plan_twap_and_vwap_slices() {
+        let twap = ParentOrder::new(
+            "005930",
+            Side::Buy,
+            dec!(100),
+            AlgoStrategy::Twap {
+                duration_secs: 60,
+                slices: 3,
+            },
+        );
+        let slices = plan_slices(&twap).unwrap();
+        assert_eq!(quantities(&slices), vec![dec!(33), dec!(33), dec!(34)]);
+        assert_eq!(slices[1].offset, Duration::from_secs(20));
+        assert_eq!(slices[2].offset, Duration::from_secs(40));
+
+        // 주식은 1주 단위: 0주 조각은 건너뜀
+        let small = ParentOrder::new(
+            "005930",
+            Side::Sell,
+            dec!(2),
+            AlgoStrategy::Twap {
+                duration_secs: 50,
+                slices: 5,
+            },
+        );
+        let slices = plan_slices(&small).unwrap();
+        assert_eq!(quantities(&slices), vec![dec!(1), dec!(1)]);
+        assert_eq!(slices[0].offset, Duration::from_secs(20));
+
+        // VWAP은 거래량 비중대로 배분
+        let vwap = ParentOrder::new(
+            "005930",
+            Side::Buy,
+            dec!(10),
+            AlgoStrategy::Vwap {
+                duration_secs: 30,
+                volume_profile: vec![dec!(3), dec!(1), dec!(1)],
+            },
+        );
+        let slices = plan_slices(&vwap).unwrap();
+        assert_eq!(quantities(&slices), vec![dec!(6), dec!(2), dec!(2)]);
+        assert_eq!(slices.iter().map(|s| s.quantity).sum::<Decimal>(), dec!(10));
+    }
+
+    #[test]
+    fn test_plan_rejects_invalid_orders() {
+        let no_slices = ParentOrder::new(
+            "005930",
+            Side::Buy,
+            dec!(100),
+            AlgoStrategy::Twap {
+                duration_secs: 60,
+                slices: 0,
+            },
+        );
+        assert!(plan_slices(&no_slices).is_err());
+
+        let empty_profile = ParentOrder::new(
+            "005930",
+            Side::Buy,
+            dec!(100),
+            AlgoStrategy::Vwap {
+                duration_secs: 60,
+                volume_profile: vec![Decimal::ZERO, Decimal::ZERO],
+            },
+        );
+        assert!(plan_slices(&empty_profile).is_err());
+
+        // 주식 아이스버그 노출 수량은 1주 이상
+        let tiny_display = ParentOrder::new(
+            "005930",
+            Side::Buy,
+            dec!(100),
+            AlgoStrategy::Iceberg {
+                display_quantity: dec!(0.5),
+            },
+        );
+        assert!(plan_slices(&tiny_display).is_err());
+
+        let iceberg = ParentOrder::new(
+            "005930",
+            Side::Buy,
+            dec!(25),
+            AlgoStrategy::Iceberg {
+                display_quantity: dec!(10),
+            },
+        );
+        let slices = plan_slices(&iceberg).unwrap();
+        assert_eq!(quantities(&slices), vec![dec!(10), dec!(10), dec!(5)]);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_twap_submits_slices_over_time() {
+        let exchange = create_exchange().await;
+        let order = ParentOrder::new(
+            "BTC/USDT",
+            Side::Buy,
+            dec!(3),
+            AlgoStrategy::Twap {
+                duration_secs: 60,
+                slices: 3,
+            },
+        );
+        let (executor, handle) = AlgoExecutor::new(exchange, order).unwrap();
+        let task = tokio::spawn(executor.run());
+
+        tokio::time::sleep(Duration::from_secs(30)).await;
+        let progress = handle.progress().await;
+        assert_eq!(progress.status, AlgoStatus::Running);
+        assert_eq!(progress.children.len(), 2);
+        assert_eq!(progress.filled_quantity, dec!(2));
+
+        let progress = task.await.unwrap();
+        assert_eq!(progress.status, AlgoStatus::Completed);
+        assert_eq!(progress.children.len(), 3);
+        assert_eq!(progress.filled_quantity, dec!(3));
+        assert_eq!(progress.fill_ratio(), Decimal::ONE);
+        assert!(progress.finished_at.is_some());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_cancel_cancels_open_child_orders() {
+        let exchange = create_exchange().await;
+        // 현재가보다 낮은 지정가라 체결되지 않고 대기
+        let order = ParentOrder::new(
+            "BTC/USDT",
+            Side::Buy,
+            dec!(3),
+            AlgoStrategy::Iceberg {
+                display_quantity: dec!(1),
+            },
+        )
+        .with_limit_price(dec!(40000));
+        let (executor, handle) = AlgoExecutor::new(exchange.clone(), order).unwrap();
+        let task = tokio::spawn(executor.run());
+
+        tokio::time::sleep(Duration::from_secs(5)).await;
+        let progress = handle.progress().await;
+        assert_eq!(progress.children.len(), 1);
+        assert_eq!(progress.submitted_quantity, dec!(1));
+
+        handle.cancel();
+        let progress = task.await.unwrap();
+        assert_eq!(progress.status, AlgoStatus::Cancelled);
+        assert_eq!(progress.children.len(), 1);
+        assert_eq!(progress.children[0].status, OrderStatusType::Cancelled);
+        assert!(exchange.get_open_orders(None).await.unwrap().is_empty());
+    }
+}
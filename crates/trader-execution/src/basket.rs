@@ -0,0 +1,312 @@
+//! 바스켓 주문 (다종목 동시 실행).
+//!
+//! 여러 종목의 주문을 하나의 바스켓으로 묶어 동시에 제출합니다.
+//! 각 종목(leg)은 수량 또는 목표 비중으로 지정하며, 목표 비중은 바스켓 총액과
+//! 현재 보유 수량으로 매수/매도 수량을 계산합니다 (리밸런싱, 자산배분 전략용).
+//!
+//! 일부 leg가 실패했을 때의 처리는 [`BasketFailurePolicy`]로 정합니다.
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use trader_core::{OrderRequest, OrderType, Side, TimeInForce};
+use uuid::Uuid;
+
+use crate::dca::quantity_for_amount;
+
+/// 일부 leg 실패 시 처리 방식.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BasketFailurePolicy {
+    /// 실패한 leg만 제외하고 나머지는 유지
+    #[default]
+    BestEffort,
+    /// 하나라도 실패하면 접수된 leg를 모두 취소
+    CancelOnFailure,
+    /// 제출 전 모든 leg를 미리보기하여 하나라도 거부되면 전체 미제출
+    AllOrNone,
+}
+
+/// leg 주문 목표.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum BasketTarget {
+    /// 지정 수량 주문
+    Quantity { side: Side, quantity: Decimal },
+    /// 바스켓 총액 대비 목표 비중 (0~1)
+    Weight { weight: Decimal },
+}
+
+/// 바스켓의 종목별 주문.
+#[derive(Debug, Clone)]
+pub struct BasketLeg {
+    /// 종목 코드
+    pub ticker: String,
+    /// 주문 목표
+    pub target: BasketTarget,
+    /// 주문 유형 (시장가 또는 지정가)
+    pub order_type: OrderType,
+    /// 지정가 (지정가 주문 필수)
+    pub limit_price: Option<Decimal>,
+    /// 현재가 (비중 계산 및 리스크 검증 기준)
+    pub current_price: Decimal,
+}
+
+/// 바스켓 주문 요청.
+#[derive(Debug, Clone)]
+pub struct BasketRequest {
+    /// 종목별 주문
+    pub legs: Vec<BasketLeg>,
+    /// 바스켓 총액 (목표 비중 leg에 필수)
+    pub total_value: Option<Decimal>,
+    /// 일부 실패 시 처리 방식
+    pub policy: BasketFailurePolicy,
+    /// 전략 ID (전략 예산/비용 모델 적용)
+    pub strategy_id: Option<String>,
+}
+
+/// leg 처리 상태.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BasketLegStatus {
+    /// 주문 접수됨
+    Submitted,
+    /// 리스크 검사 등으로 거부됨
+    Rejected,
+    /// 목표와 같거나 바스켓 중단으로 제출하지 않음
+    Skipped,
+    /// 다른 leg 실패로 접수 후 취소됨
+    Cancelled,
+}
+
+/// leg 처리 결과.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BasketLegResult {
+    /// 종목 코드
+    pub ticker: String,
+    /// 주문 방향 (계산되지 않았으면 None)
+    pub side: Option<Side>,
+    /// 주문 수량
+    pub quantity: Decimal,
+    /// 처리 상태
+    pub status: BasketLegStatus,
+    /// 내부 주문 ID
+    pub order_id: Option<Uuid>,
+    /// 실패/건너뜀 사유
+    pub error: Option<String>,
+    /// 실행 노트 (예산 조정, 서킷 대기 등)
+    pub notes: Vec<String>,
+}
+
+impl BasketLegResult {
+    /// 주문 없이 종료된 leg 결과.
+    pub fn unsubmitted(
+        ticker: impl Into<String>,
+        order: Option<&OrderRequest>,
+        status: BasketLegStatus,
+        error: impl Into<String>,
+    ) -> Self {
+        Self {
+            ticker: ticker.into(),
+            side: order.map(|o| o.side),
+            quantity: order.map(|o| o.quantity).unwrap_or(Decimal::ZERO),
+            status,
+            order_id: None,
+            error: Some(error.into()),
+            notes: Vec::new(),
+        }
+    }
+}
+
+/// 바스켓 주문 결과.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BasketResult {
+    /// 바스켓 ID (leg 주문의 `client_order_id` 접두사)
+    pub basket_id: Uuid,
+    /// 적용된 실패 처리 방식
+    pub policy: BasketFailurePolicy,
+    /// 거부/취소된 leg 없이 처리되었는지 여부
+    pub success: bool,
+    /// 접수된 leg 수
+    pub submitted: usize,
+    /// 거부되거나 취소된 leg 수
+    pub failed: usize,
+    /// leg별 결과 (요청 순서)
+    pub legs: Vec<BasketLegResult>,
+}
+
+impl BasketResult {
+    /// leg 결과로 바스켓 결과 구성.
+    pub fn new(basket_id: Uuid, policy: BasketFailurePolicy, legs: Vec<BasketLegResult>) -> Self {
+        let submitted = legs
+            .iter()
+            .filter(|l| l.status == BasketLegStatus::Submitted)
+            .count();
+        let failed = legs
+            .iter()
+            .filter(|l| {
+                matches!(
+                    l.status,
+                    BasketLegStatus::Rejected | BasketLegStatus::Cancelled
+                )
+            })
+            .count();
+        Self {
+            basket_id,
+            policy,
+            success: failed == 0,
+            submitted,
+            failed,
+            legs,
+        }
+    }
+}
+
+/// leg를 주문 요청으로 변환.
+///
+/// 목표 비중 leg는 `total_value × weight`와 현재 평가액의 차이만큼 매수/매도합니다.
+/// 이미 목표와 같아 주문할 수량이 없으면 `Ok(None)`을 반환합니다.
+///
+/// # Arguments
+///
+/// * `leg` - 변환할 leg
+/// * `total_value` - 바스켓 총액 (목표 비중 leg에 필수)
+/// * `current_quantity` - 현재 순보유 수량 (롱 양수, 숏 음수)
+/// * `strategy_id` - 전략 ID
+/// * `client_order_id` - 클라이언트 주문 ID
+pub fn resolve_leg(
+    leg: &BasketLeg,
+    total_value: Option<Decimal>,
+    current_quantity: Decimal,
+    strategy_id: Option<&str>,
+    client_order_id: String,
+) -> Result<Option<OrderRequest>, String> {
+    if leg.current_price <= Decimal::ZERO {
+        return Err("current price must be positive".to_string());
+    }
+
+    let (side, quantity) = match &leg.target {
+        BasketTarget::Quantity { side, quantity } => (*side, *quantity),
+        BasketTarget::Weight { weight } => {
+            let total_value =
+                total_value.ok_or_else(|| "total value is required for weights".to_string())?;
+            let reference_price = leg.limit_price.unwrap_or(leg.current_price);
+            let delta = total_value * weight - current_quantity * reference_price;
+            let side = if delta >= Decimal::ZERO {
+                Side::Buy
+            } else {
+                Side::Sell
+            };
+            let mut quantity = quantity_for_amount(&leg.ticker, delta.abs(), reference_price);
+            // 롱 포지션 축소는 보유 수량까지만 매도
+            if side == Side::Sell && current_quantity > Decimal::ZERO {
+                quantity = quantity.min(current_quantity);
+            }
+            (side, quantity)
+        }
+    };
+
+    if quantity <= Decimal::ZERO {
+        return Ok(None);
+    }
+
+    let price = match leg.order_type {
+        OrderType::Market => None,
+        OrderType::Limit => Some(
+            leg.limit_price
+                .filter(|p| *p > Decimal::ZERO)
+                .ok_or_else(|| "limit price is required for limit orders".to_string())?,
+        ),
+        other => return Err(format!("Unsupported basket order type: {}", other)),
+    };
+
+    Ok(Some(OrderRequest {
+        ticker: leg.ticker.clone(),
+        side,
+        order_type: leg.order_type,
+        quantity,
+        price,
+        stop_price: None,
+        time_in_force: TimeInForce::GTC,
+        client_order_id: Some(client_order_id),
+        strategy_id: strategy_id.map(str::to_string),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal::prelude::FromPrimitive;
+
+    /// 숫자로부터 Decimal을 생성하는 헬퍼 매크로
+    macro_rules! dec {
+        ($val:expr) => {
+            Decimal::from_f64($val as f64).unwrap()
+        };
+    }
+
+    fn weight_leg(ticker: &str, weight: f64, price: f64) -> BasketLeg {
+        BasketLeg {
+            ticker: ticker.to_string(),
+            target: BasketTarget::Weight {
+                weight: dec!(weight),
+            },
+            order_type: OrderType::Market,
+            limit_price: None,
+            current_price: dec!(price),
+        }
+    }
+
+    #[test]
+    fn test_resolve_weight_leg() {
+        // 목표 600만원, 보유 50주 × 70,000 = 350만원 → 35주 매수
+        let leg = weight_leg("005930", 0.6, 70000.0);
+        let order = resolve_leg(&leg, Some(dec!(10000000)), dec!(50), None, "b-0".into())
+            .unwrap()
+            .unwrap();
+        assert_eq!(order.side, Side::Buy);
+        assert_eq!(order.quantity, dec!(35));
+
+        // 목표 0 → 보유 수량 전부 매도
+        let leg = weight_leg("005930", 0.0, 70000.0);
+        let order = resolve_leg(&leg, Some(dec!(10000000)), dec!(50), None, "b-1".into())
+            .unwrap()
+            .unwrap();
+        assert_eq!(order.side, Side::Sell);
+        assert_eq!(order.quantity, dec!(50));
+
+        // 목표와 차이가 1주 미만이면 주문 없음
+        let leg = weight_leg("005930", 0.35, 70000.0);
+        assert!(
+            resolve_leg(&leg, Some(dec!(10000000)), dec!(50), None, "b-2".into())
+                .unwrap()
+                .is_none()
+        );
+
+        // 총액 없이 비중 지정 불가
+        assert!(resolve_leg(&leg, None, dec!(0), None, "b-3".into()).is_err());
+    }
+
+    #[test]
+    fn test_basket_result_summary() {
+        let submitted = BasketLegResult {
+            ticker: "AAPL".to_string(),
+            side: Some(Side::Buy),
+            quantity: dec!(1),
+            status: BasketLegStatus::Submitted,
+            order_id: Some(Uuid::new_v4()),
+            error: None,
+            notes: Vec::new(),
+        };
+        let rejected =
+            BasketLegResult::unsubmitted("MSFT", None, BasketLegStatus::Rejected, "limit");
+
+        let result = BasketResult::new(
+            Uuid::new_v4(),
+            BasketFailurePolicy::BestEffort,
+            vec![submitted, rejected],
+        );
+        assert!(!result.success);
+        assert_eq!(result.submitted, 1);
+        assert_eq!(result.failed, 1);
+    }
+}
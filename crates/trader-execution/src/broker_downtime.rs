@@ -0,0 +1,245 @@
+//! 브로커 점검/장애 중 주문 처리 (degraded mode).
+//!
+//! [`BrokerDowntimeCalendar`]에 진행 중인 점검/장애가 있으면 신규 주문을
+//! 거래소로 보내지 않고 설정에 따라 처리합니다.
+//!
+//! - `Reject`: 점검/장애 사유와 종료 예정 시각을 담아 즉시 거부
+//! - `Queue`: 대기열에 보관 후 브로커가 복구되면 방출
+//!
+//! 달력은 API 서버의 점검 일정/장애 감지 모니터와 공유됩니다.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use tokio::sync::RwLock;
+use trader_core::{BrokerDowntime, BrokerDowntimeCalendar};
+use uuid::Uuid;
+
+/// 점검/장애 중 신규 주문 처리 방식.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DowntimePolicy {
+    /// 즉시 거부
+    #[default]
+    Reject,
+    /// 대기열에 보관 후 복구 시 방출
+    Queue,
+}
+
+/// 브로커 점검/장애 처리 설정.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BrokerDowntimeConfig {
+    /// 주문을 제출하는 브로커 식별자 (달력 구간의 `broker`와 비교)
+    #[serde(default = "default_broker")]
+    pub broker: String,
+    /// 점검/장애 중 신규 주문 처리 방식
+    #[serde(default)]
+    pub policy: DowntimePolicy,
+    /// 최대 대기 주문 수 (초과 시 거부)
+    #[serde(default = "default_max_queued")]
+    pub max_queued: usize,
+}
+
+fn default_broker() -> String {
+    "kis".to_string()
+}
+
+fn default_max_queued() -> usize {
+    100
+}
+
+impl Default for BrokerDowntimeConfig {
+    fn default() -> Self {
+        Self {
+            broker: default_broker(),
+            policy: DowntimePolicy::default(),
+            max_queued: default_max_queued(),
+        }
+    }
+}
+
+/// 점검/장애 검사 결과.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DowntimeAdmission {
+    /// 브로커 정상, 제출 허용
+    Allowed,
+    /// 대기열에 보관됨 (대기 순번, 1부터)
+    Queued {
+        position: usize,
+        downtime: BrokerDowntime,
+    },
+    /// 거부됨
+    Rejected(String),
+}
+
+/// 브로커 점검/장애 가드.
+pub struct BrokerDowntimeGuard {
+    config: BrokerDowntimeConfig,
+    calendar: Arc<RwLock<BrokerDowntimeCalendar>>,
+    queue: Mutex<VecDeque<Uuid>>,
+}
+
+impl Default for BrokerDowntimeGuard {
+    fn default() -> Self {
+        Self::new(
+            BrokerDowntimeConfig::default(),
+            Arc::new(RwLock::new(BrokerDowntimeCalendar::new())),
+        )
+    }
+}
+
+impl BrokerDowntimeGuard {
+    /// 새 가드 생성.
+    pub fn new(
+        config: BrokerDowntimeConfig,
+        calendar: Arc<RwLock<BrokerDowntimeCalendar>>,
+    ) -> Self {
+        Self {
+            config,
+            calendar,
+            queue: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    /// 설정 반환.
+    pub fn config(&self) -> &BrokerDowntimeConfig {
+        &self.config
+    }
+
+    /// 공유 점검/장애 달력.
+    pub fn calendar(&self) -> &Arc<RwLock<BrokerDowntimeCalendar>> {
+        &self.calendar
+    }
+
+    /// 진행 중인 점검/장애.
+    pub async fn active(&self, now: DateTime<Utc>) -> Option<BrokerDowntime> {
+        self.calendar
+            .read()
+            .await
+            .active(&self.config.broker, now)
+            .cloned()
+    }
+
+    /// 신규 주문 허용 여부 판정.
+    pub async fn admit(&self, order_id: Uuid, now: DateTime<Utc>) -> DowntimeAdmission {
+        let Some(downtime) = self.active(now).await else {
+            return DowntimeAdmission::Allowed;
+        };
+
+        let mut queue = self.queue.lock().unwrap();
+        match self.config.policy {
+            DowntimePolicy::Queue if queue.len() < self.config.max_queued => {
+                queue.push_back(order_id);
+                DowntimeAdmission::Queued {
+                    position: queue.len(),
+                    downtime,
+                }
+            }
+            DowntimePolicy::Queue => DowntimeAdmission::Rejected(format!(
+                "Broker unavailable ({}); downtime queue is full ({})",
+                downtime.describe(),
+                self.config.max_queued
+            )),
+            DowntimePolicy::Reject => DowntimeAdmission::Rejected(format!(
+                "Broker unavailable ({}); order rejected",
+                downtime.describe()
+            )),
+        }
+    }
+
+    /// 대기열에서 주문 제거 (취소된 주문 등).
+    pub fn dequeue(&self, order_id: Uuid) -> bool {
+        let mut queue = self.queue.lock().unwrap();
+        let before = queue.len();
+        queue.retain(|id| *id != order_id);
+        queue.len() != before
+    }
+
+    /// 대기 중인 주문 수.
+    pub fn queued(&self) -> usize {
+        self.queue.lock().unwrap().len()
+    }
+
+    /// 브로커가 복구되었으면 대기열 주문을 모두 방출.
+    pub async fn take_released(&self, now: DateTime<Utc>) -> Vec<Uuid> {
+        if self.queued() == 0 || self.active(now).await.is_some() {
+            return Vec::new();
+        }
+        self.queue.lock().unwrap().drain(..).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 3, 7, hour, minute, 0).unwrap()
+    }
+
+    fn guard(policy: DowntimePolicy) -> BrokerDowntimeGuard {
+        let mut calendar = BrokerDowntimeCalendar::new();
+        calendar
+            .insert(BrokerDowntime::scheduled("kis", at(20, 0), at(21, 0), "정기 점검").unwrap());
+        BrokerDowntimeGuard::new(
+            BrokerDowntimeConfig {
+                broker: "kis".to_string(),
+                policy,
+                max_queued: 1,
+            },
+            Arc::new(RwLock::new(calendar)),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_reject_during_maintenance() {
+        let guard = guard(DowntimePolicy::Reject);
+
+        assert_eq!(
+            guard.admit(Uuid::new_v4(), at(19, 0)).await,
+            DowntimeAdmission::Allowed
+        );
+        match guard.admit(Uuid::new_v4(), at(20, 30)).await {
+            DowntimeAdmission::Rejected(reason) => {
+                assert!(reason.contains("scheduled maintenance until 2026-03-07 21:00 UTC"));
+                assert!(reason.contains("정기 점검"));
+            }
+            other => panic!("unexpected admission: {:?}", other),
+        }
+        assert_eq!(guard.queued(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_queue_and_release_after_outage() {
+        let guard = guard(DowntimePolicy::Queue);
+        guard
+            .calendar()
+            .write()
+            .await
+            .report_outage("kis", "connection refused", at(10, 0));
+
+        let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+        assert!(matches!(
+            guard.admit(a, at(10, 5)).await,
+            DowntimeAdmission::Queued { position: 1, .. }
+        ));
+        assert!(matches!(
+            guard.admit(b, at(10, 5)).await,
+            DowntimeAdmission::Rejected(_)
+        ));
+
+        // 복구 전에는 방출되지 않음
+        assert!(guard.take_released(at(10, 10)).await.is_empty());
+
+        guard
+            .calendar()
+            .write()
+            .await
+            .resolve_outage("kis", at(10, 20));
+        assert_eq!(guard.take_released(at(10, 20)).await, vec![a]);
+        assert_eq!(guard.queued(), 0);
+        assert!(!guard.dequeue(a));
+    }
+}
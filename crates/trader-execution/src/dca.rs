@@ -0,0 +1,312 @@
+//! 정액 적립식(DCA) 투자 계획.
+//!
+//! 정해진 주기마다 고정 금액(KRW/USD)을 선택 종목에 비중대로 나눠 매수합니다.
+//! 가격 조건에 따라 투자 금액을 조정하는 변형을 지원합니다.
+//!
+//! - 정액 적립 (`fixed`): 매 회차 같은 금액
+//! - 가치 평균법 (`value_averaging`): 목표 평가액(회차 × 금액) 대비 부족분만큼 매수
+//! - 하락 가중 (`dip_boost`): 최근 고점 대비 일정 비율 이상 하락 시 금액 확대
+//!
+//! 실제 주문은 `OrderExecutor::process_order_request()`로 일반 주문과 같은
+//! 리스크/실행 경로를 거칩니다.
+
+use chrono::{DateTime, Duration, Months, Utc};
+use rust_decimal::{Decimal, RoundingStrategy};
+use serde::{Deserialize, Serialize};
+
+/// 적립 주기.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DcaCadence {
+    /// 매일
+    Daily,
+    /// 매주
+    Weekly,
+    /// 격주
+    Biweekly,
+    /// 매월
+    Monthly,
+}
+
+impl DcaCadence {
+    /// 다음 실행 시각 계산.
+    pub fn next_after(&self, at: DateTime<Utc>) -> DateTime<Utc> {
+        match self {
+            Self::Daily => at + Duration::days(1),
+            Self::Weekly => at + Duration::weeks(1),
+            Self::Biweekly => at + Duration::weeks(2),
+            Self::Monthly => at
+                .checked_add_months(Months::new(1))
+                .unwrap_or(at + Duration::days(30)),
+        }
+    }
+
+    /// DB 저장용 문자열.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Daily => "daily",
+            Self::Weekly => "weekly",
+            Self::Biweekly => "biweekly",
+            Self::Monthly => "monthly",
+        }
+    }
+}
+
+impl std::str::FromStr for DcaCadence {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "daily" => Ok(Self::Daily),
+            "weekly" => Ok(Self::Weekly),
+            "biweekly" => Ok(Self::Biweekly),
+            "monthly" => Ok(Self::Monthly),
+            _ => Err(format!("Unknown DCA cadence: {}", s)),
+        }
+    }
+}
+
+/// 투자 금액 결정 방식.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum DcaMode {
+    /// 매 회차 같은 금액
+    #[default]
+    Fixed,
+    /// 가치 평균법: 목표 평가액 = 회차 금액 × (실행 회차 + 1)
+    ValueAveraging,
+    /// 하락 가중: 최근 고점 대비 `drawdown_pct`% 이상 하락 시 금액 × `multiplier`
+    DipBoost {
+        /// 하락 기준 (%)
+        drawdown_pct: Decimal,
+        /// 금액 배수
+        multiplier: Decimal,
+    },
+}
+
+impl DcaMode {
+    /// 설정 검증.
+    pub fn validate(&self) -> Result<(), String> {
+        if let Self::DipBoost {
+            drawdown_pct,
+            multiplier,
+        } = self
+        {
+            if *drawdown_pct <= Decimal::ZERO || *drawdown_pct >= Decimal::ONE_HUNDRED {
+                return Err("drawdown_pct must be between 0 and 100".to_string());
+            }
+            if *multiplier < Decimal::ONE {
+                return Err("multiplier must be at least 1".to_string());
+            }
+        }
+        Ok(())
+    }
+}
+
+/// 종목별 투자 비중.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DcaAllocation {
+    /// 종목 코드
+    pub ticker: String,
+    /// 비중 (합계 대비 상대값)
+    pub weight: Decimal,
+}
+
+/// 회차 금액을 비중대로 배분.
+///
+/// 비중 합계가 0 이하이면 빈 목록을 반환합니다.
+pub fn split_amount(amount: Decimal, allocations: &[DcaAllocation]) -> Vec<(String, Decimal)> {
+    let total: Decimal = allocations
+        .iter()
+        .map(|a| a.weight.max(Decimal::ZERO))
+        .sum();
+    if total <= Decimal::ZERO {
+        return Vec::new();
+    }
+    allocations
+        .iter()
+        .filter(|a| a.weight > Decimal::ZERO)
+        .map(|a| (a.ticker.clone(), amount * a.weight / total))
+        .collect()
+}
+
+/// 금액 결정에 필요한 종목 상태.
+#[derive(Debug, Clone, Default)]
+pub struct DcaSymbolState {
+    /// 현재가
+    pub price: Decimal,
+    /// 최근 고점 (하락 가중용)
+    pub recent_high: Option<Decimal>,
+    /// 적립으로 보유 중인 수량 (가치 평균법용)
+    pub accumulated_quantity: Decimal,
+    /// 이전 실행 회차 수 (가치 평균법용)
+    pub executed_periods: u32,
+}
+
+/// 회차 투자 금액 계산 결과.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DcaContribution {
+    /// 기본 금액 (비중 배분 후)
+    pub base_amount: Decimal,
+    /// 실제 투자 금액
+    pub amount: Decimal,
+    /// 기본 금액 대비 배수
+    pub multiplier: Decimal,
+    /// 금액 결정 사유
+    pub reason: String,
+}
+
+/// 회차 투자 금액 계산.
+///
+/// # Arguments
+///
+/// * `mode` - 금액 결정 방식
+/// * `base_amount` - 이 종목의 회차 기본 금액
+/// * `max_multiplier` - 기본 금액 대비 최대 배수 (가치 평균법 상한)
+/// * `state` - 종목 상태
+pub fn plan_contribution(
+    mode: &DcaMode,
+    base_amount: Decimal,
+    max_multiplier: Decimal,
+    state: &DcaSymbolState,
+) -> DcaContribution {
+    let cap = base_amount * max_multiplier.max(Decimal::ONE);
+
+    let (amount, reason) = match mode {
+        DcaMode::Fixed => (base_amount, "fixed".to_string()),
+        DcaMode::ValueAveraging => {
+            let target = base_amount * Decimal::from(state.executed_periods + 1);
+            let current = state.accumulated_quantity * state.price;
+            let needed = (target - current).max(Decimal::ZERO).min(cap);
+            (
+                needed,
+                format!("value averaging: target {} vs current {}", target, current),
+            )
+        }
+        DcaMode::DipBoost {
+            drawdown_pct,
+            multiplier,
+        } => match state.recent_high {
+            Some(high) if high > Decimal::ZERO => {
+                let drawdown = (high - state.price) / high * Decimal::ONE_HUNDRED;
+                if drawdown >= *drawdown_pct {
+                    (
+                        (base_amount * multiplier).min(cap),
+                        format!("dip boost: {}% below recent high", drawdown.round_dp(2)),
+                    )
+                } else {
+                    (base_amount, "fixed (no dip)".to_string())
+                }
+            }
+            _ => (base_amount, "fixed (no price history)".to_string()),
+        },
+    };
+
+    let multiplier = if base_amount > Decimal::ZERO {
+        (amount / base_amount).round_dp(4)
+    } else {
+        Decimal::ZERO
+    };
+
+    DcaContribution {
+        base_amount,
+        amount,
+        multiplier,
+        reason,
+    }
+}
+
+/// 투자 금액을 주문 수량으로 변환.
+///
+/// 주식은 정수 주(내림), 암호화폐(`BASE/QUOTE`)는 소수점 8자리까지 내림합니다.
+pub fn quantity_for_amount(ticker: &str, amount: Decimal, price: Decimal) -> Decimal {
+    if price <= Decimal::ZERO || amount <= Decimal::ZERO {
+        return Decimal::ZERO;
+    }
+    let dp = if ticker.contains('/') { 8 } else { 0 };
+    (amount / price)
+        .round_dp_with_strategy(dp, RoundingStrategy::ToZero)
+        .normalize()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use rust_decimal::prelude::FromPrimitive;
+
+    /// 숫자로부터 Decimal을 생성하는 헬퍼 매크로
+    macro_rules! dec {
+        ($val:expr) => {
+            Decimal::from_f64($val as f64).unwrap()
+        };
+    }
+
+    #[test]
+    fn test_plan_contribution_modes() {
+        let state = DcaSymbolState {
+            price: dec!(80),
+            recent_high: Some(dec!(100)),
+            accumulated_quantity: dec!(10),
+            executed_periods: 2,
+        };
+
+        let fixed = plan_contribution(&DcaMode::Fixed, dec!(1000), dec!(3), &state);
+        assert_eq!(fixed.amount, dec!(1000));
+
+        // 목표 3000, 평가액 800 → 2200 매수
+        let va = plan_contribution(&DcaMode::ValueAveraging, dec!(1000), dec!(3), &state);
+        assert_eq!(va.amount, dec!(2200));
+        assert_eq!(va.multiplier, dec!(2.2));
+
+        // 20% 하락 → 2배
+        let dip = DcaMode::DipBoost {
+            drawdown_pct: dec!(15),
+            multiplier: dec!(2),
+        };
+        assert_eq!(
+            plan_contribution(&dip, dec!(1000), dec!(3), &state).amount,
+            dec!(2000)
+        );
+        let shallow = DcaSymbolState {
+            price: dec!(95),
+            ..state
+        };
+        assert_eq!(
+            plan_contribution(&dip, dec!(1000), dec!(3), &shallow).amount,
+            dec!(1000)
+        );
+    }
+
+    #[test]
+    fn test_split_and_quantity() {
+        let allocations = vec![
+            DcaAllocation {
+                ticker: "005930".to_string(),
+                weight: dec!(3),
+            },
+            DcaAllocation {
+                ticker: "BTC/KRW".to_string(),
+                weight: dec!(1),
+            },
+        ];
+        let split = split_amount(dec!(400000), &allocations);
+        assert_eq!(split[0].1, dec!(300000));
+        assert_eq!(split[1].1, dec!(100000));
+
+        assert_eq!(
+            quantity_for_amount("005930", dec!(300000), dec!(71000)),
+            dec!(4)
+        );
+        assert_eq!(
+            quantity_for_amount("BTC/KRW", dec!(100000), dec!(150000000)),
+            Decimal::new(66666, 8)
+        );
+
+        let start = Utc.with_ymd_and_hms(2026, 1, 31, 0, 0, 0).unwrap();
+        assert_eq!(
+            DcaCadence::Monthly.next_after(start),
+            Utc.with_ymd_and_hms(2026, 2, 28, 0, 0, 0).unwrap()
+        );
+    }
+}
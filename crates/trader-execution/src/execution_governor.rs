@@ -0,0 +1,446 @@
+//! 시장 상황 기반 실행 조절 (execution governor).
+//!
+//! 실현 변동성이 급등하거나 호가 스프레드가 벌어지면 진입 주문을 조절합니다.
+//! 체결 품질이 나쁜 구간에 평소처럼 주문하면 불리한 가격에 체결되므로,
+//! 조절 단계에 따라 다음을 적용하고 그 결정을 기록합니다.
+//!
+//! - 경계(Elevated): 진입 수량 축소, 지정가 주문은 현재가에서 더 멀리 (오프셋 확대)
+//! - 위험(Extreme): 진입 주문 보류 (청산 주문은 계속 허용)
+//!
+//! 변동성은 단기/장기 실현 변동성 비율로, 스프레드는 호가창 깊이 가중 스프레드(bp)로 판단합니다.
+//! 청산 주문과 조절을 끈 전략의 주문은 조절하지 않습니다.
+
+use chrono::{DateTime, Duration, Utc};
+use rust_decimal::{Decimal, RoundingStrategy};
+use serde::{Deserialize, Serialize};
+use trader_core::types::MarketType;
+use trader_core::{OrderRequest, OrderType, Side};
+
+use crate::liquidity_cap::quantity_scale;
+
+/// 실행 조절 설정.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExecutionGovernorConfig {
+    /// 조절 활성화 여부
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    /// 경계 단계 변동성 비율 (단기/장기 실현 변동성, 기본값: 2배)
+    #[serde(default = "default_elevated_volatility_ratio")]
+    pub elevated_volatility_ratio: Decimal,
+    /// 위험 단계 변동성 비율 (기본값: 3배)
+    #[serde(default = "default_extreme_volatility_ratio")]
+    pub extreme_volatility_ratio: Decimal,
+    /// 경계 단계 스프레드 (bp, 기본값: 30bp)
+    #[serde(default = "default_elevated_spread_bps")]
+    pub elevated_spread_bps: Decimal,
+    /// 위험 단계 스프레드 (bp, 기본값: 100bp)
+    #[serde(default = "default_extreme_spread_bps")]
+    pub extreme_spread_bps: Decimal,
+    /// 경계 단계 진입 수량 비율 (기본값: 50%)
+    #[serde(default = "default_size_factor")]
+    pub size_factor: Decimal,
+    /// 경계 단계 지정가 오프셋 (현재가 대비 bp, 기본값: 20bp)
+    #[serde(default = "default_limit_offset_bps")]
+    pub limit_offset_bps: Decimal,
+    /// 시장 상황 유효 시간 (초, 이보다 오래된 값은 무시, 기본값: 300초)
+    #[serde(default = "default_max_age_secs")]
+    pub max_age_secs: i64,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+fn default_elevated_volatility_ratio() -> Decimal {
+    Decimal::TWO
+}
+
+fn default_extreme_volatility_ratio() -> Decimal {
+    Decimal::new(3, 0)
+}
+
+fn default_elevated_spread_bps() -> Decimal {
+    Decimal::new(30, 0)
+}
+
+fn default_extreme_spread_bps() -> Decimal {
+    Decimal::ONE_HUNDRED
+}
+
+fn default_size_factor() -> Decimal {
+    Decimal::new(5, 1)
+}
+
+fn default_limit_offset_bps() -> Decimal {
+    Decimal::new(20, 0)
+}
+
+fn default_max_age_secs() -> i64 {
+    300
+}
+
+impl Default for ExecutionGovernorConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_enabled(),
+            elevated_volatility_ratio: default_elevated_volatility_ratio(),
+            extreme_volatility_ratio: default_extreme_volatility_ratio(),
+            elevated_spread_bps: default_elevated_spread_bps(),
+            extreme_spread_bps: default_extreme_spread_bps(),
+            size_factor: default_size_factor(),
+            limit_offset_bps: default_limit_offset_bps(),
+            max_age_secs: default_max_age_secs(),
+        }
+    }
+}
+
+/// 종목의 최근 시장 상황.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MarketCondition {
+    /// 시장 유형 (수량 라운딩 기준)
+    pub market_type: MarketType,
+    /// 단기/장기 실현 변동성 비율 (없으면 변동성 조절 안 함)
+    pub volatility_ratio: Option<Decimal>,
+    /// 깊이 가중 스프레드 (bp, 없으면 스프레드 조절 안 함)
+    pub spread_bps: Option<Decimal>,
+    /// 갱신 시각
+    pub as_of: DateTime<Utc>,
+}
+
+/// 실행 조절 단계.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GovernorRegime {
+    /// 평상시
+    Normal,
+    /// 경계 (수량 축소, 지정가 오프셋 확대)
+    Elevated,
+    /// 위험 (진입 주문 보류)
+    Extreme,
+}
+
+impl std::fmt::Display for GovernorRegime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GovernorRegime::Normal => write!(f, "normal"),
+            GovernorRegime::Elevated => write!(f, "elevated"),
+            GovernorRegime::Extreme => write!(f, "extreme"),
+        }
+    }
+}
+
+impl ExecutionGovernorConfig {
+    /// 시장 상황의 조절 단계와 판단 근거.
+    pub fn classify(&self, condition: &MarketCondition) -> (GovernorRegime, Vec<String>) {
+        let mut regime = GovernorRegime::Normal;
+        let mut reasons = Vec::new();
+
+        if let Some(ratio) = condition.volatility_ratio {
+            let level = if ratio >= self.extreme_volatility_ratio {
+                GovernorRegime::Extreme
+            } else if ratio >= self.elevated_volatility_ratio {
+                GovernorRegime::Elevated
+            } else {
+                GovernorRegime::Normal
+            };
+            if level > GovernorRegime::Normal {
+                reasons.push(format!(
+                    "realized volatility {}x of baseline",
+                    ratio.round_dp(2).normalize()
+                ));
+                regime = regime.max(level);
+            }
+        }
+
+        if let Some(spread) = condition.spread_bps {
+            let level = if spread >= self.extreme_spread_bps {
+                GovernorRegime::Extreme
+            } else if spread >= self.elevated_spread_bps {
+                GovernorRegime::Elevated
+            } else {
+                GovernorRegime::Normal
+            };
+            if level > GovernorRegime::Normal {
+                reasons.push(format!("spread {}bp", spread.round_dp(1).normalize()));
+                regime = regime.max(level);
+            }
+        }
+
+        (regime, reasons)
+    }
+}
+
+/// 실행 조절 결정 내역 (신호 메타데이터 `execution_governor`로 기록).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GovernorDecision {
+    /// 조절 단계
+    pub regime: GovernorRegime,
+    /// 판단 근거 (예: "spread 45bp")
+    pub reasons: Vec<String>,
+    /// 단기/장기 실현 변동성 비율
+    pub volatility_ratio: Option<Decimal>,
+    /// 깊이 가중 스프레드 (bp)
+    pub spread_bps: Option<Decimal>,
+    /// 원래 주문 수량
+    pub requested_quantity: Decimal,
+    /// 적용 수량 (보류 시 0)
+    pub quantity: Decimal,
+    /// 원래 지정가
+    pub requested_price: Option<Decimal>,
+    /// 적용 지정가
+    pub price: Option<Decimal>,
+}
+
+impl GovernorDecision {
+    /// 조절 안내 문구.
+    pub fn throttle_note(&self) -> String {
+        let mut note = format!(
+            "Execution throttled ({}: {}): quantity {} -> {}",
+            self.regime,
+            self.reasons.join(", "),
+            self.requested_quantity,
+            self.quantity
+        );
+        if let (Some(from), Some(to)) = (self.requested_price, self.price) {
+            if from != to {
+                note.push_str(&format!(", limit {} -> {}", from, to));
+            }
+        }
+        note
+    }
+
+    /// 보류 사유 문구.
+    pub fn pause_reason(&self, ticker: &str) -> String {
+        format!(
+            "Entry orders for {} paused by execution governor ({}: {})",
+            ticker,
+            self.regime,
+            self.reasons.join(", ")
+        )
+    }
+}
+
+/// 실행 조절 검사 결과.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GovernorCheck {
+    /// 검사 대상 아님 (비활성화, 청산 주문, 조절 제외 전략, 시장 상황 없음/만료)
+    NotApplicable,
+    /// 평상시라 그대로 허용
+    Normal,
+    /// 수량 축소/지정가 조정 후 허용
+    Throttled(GovernorDecision),
+    /// 진입 주문 보류
+    Paused(GovernorDecision),
+}
+
+impl GovernorCheck {
+    /// 기록할 결정 내역.
+    pub fn decision(&self) -> Option<&GovernorDecision> {
+        match self {
+            GovernorCheck::NotApplicable | GovernorCheck::Normal => None,
+            GovernorCheck::Throttled(d) | GovernorCheck::Paused(d) => Some(d),
+        }
+    }
+}
+
+/// 시장 상황 기준 실행 조절 검사.
+///
+/// # Arguments
+///
+/// * `order` - 검사할 주문
+/// * `condition` - 종목의 최근 시장 상황 (없으면 검사 생략)
+/// * `is_entry` - 진입 주문 여부 (청산 주문은 조절하지 않음)
+/// * `opted_out` - 주문 전략이 조절을 끈 경우
+/// * `current_price` - 현재 시장 가격 (지정가 오프셋 기준)
+/// * `now` - 현재 시각 (시장 상황 만료 판단)
+/// * `config` - 실행 조절 설정
+pub fn check_execution_governor(
+    order: &OrderRequest,
+    condition: Option<&MarketCondition>,
+    is_entry: bool,
+    opted_out: bool,
+    current_price: Decimal,
+    now: DateTime<Utc>,
+    config: &ExecutionGovernorConfig,
+) -> GovernorCheck {
+    let Some(condition) = condition else {
+        return GovernorCheck::NotApplicable;
+    };
+    if !config.enabled || !is_entry || opted_out {
+        return GovernorCheck::NotApplicable;
+    }
+    if now - condition.as_of > Duration::seconds(config.max_age_secs) {
+        return GovernorCheck::NotApplicable;
+    }
+
+    let (regime, reasons) = config.classify(condition);
+    let mut decision = GovernorDecision {
+        regime,
+        reasons,
+        volatility_ratio: condition.volatility_ratio,
+        spread_bps: condition.spread_bps,
+        requested_quantity: order.quantity,
+        quantity: order.quantity,
+        requested_price: order.price,
+        price: order.price,
+    };
+
+    match regime {
+        GovernorRegime::Normal => GovernorCheck::Normal,
+        GovernorRegime::Extreme => {
+            decision.quantity = Decimal::ZERO;
+            GovernorCheck::Paused(decision)
+        }
+        GovernorRegime::Elevated => {
+            decision.quantity = (order.quantity * config.size_factor)
+                .round_dp_with_strategy(
+                    quantity_scale(condition.market_type),
+                    RoundingStrategy::ToZero,
+                )
+                .normalize();
+            if decision.quantity <= Decimal::ZERO {
+                decision.quantity = Decimal::ZERO;
+                return GovernorCheck::Paused(decision);
+            }
+
+            // 지정가는 현재가에서 오프셋만큼 떨어진 가격보다 공격적이지 않게 조정
+            if order.order_type == OrderType::Limit {
+                if let Some(price) = order.price {
+                    let offset = current_price * config.limit_offset_bps / Decimal::new(10_000, 0);
+                    decision.price = Some(match order.side {
+                        Side::Buy => price.min(current_price - offset),
+                        Side::Sell => price.max(current_price + offset),
+                    });
+                }
+            }
+
+            GovernorCheck::Throttled(decision)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal::prelude::FromPrimitive;
+
+    /// 숫자로부터 Decimal을 생성하는 헬퍼 매크로
+    macro_rules! dec {
+        ($val:expr) => {
+            Decimal::from_f64($val as f64).unwrap()
+        };
+    }
+
+    fn condition(
+        volatility_ratio: Option<Decimal>,
+        spread_bps: Option<Decimal>,
+    ) -> MarketCondition {
+        MarketCondition {
+            market_type: MarketType::KrStock,
+            volatility_ratio,
+            spread_bps,
+            as_of: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_classify_regimes() {
+        let config = ExecutionGovernorConfig::default();
+
+        let (regime, reasons) = config.classify(&condition(Some(dec!(1.2)), Some(dec!(10))));
+        assert_eq!(regime, GovernorRegime::Normal);
+        assert!(reasons.is_empty());
+
+        let (regime, reasons) = config.classify(&condition(Some(dec!(2.5)), Some(dec!(10))));
+        assert_eq!(regime, GovernorRegime::Elevated);
+        assert_eq!(reasons, vec!["realized volatility 2.5x of baseline"]);
+
+        // 둘 중 더 높은 단계 적용
+        let (regime, reasons) = config.classify(&condition(Some(dec!(2.5)), Some(dec!(150))));
+        assert_eq!(regime, GovernorRegime::Extreme);
+        assert_eq!(reasons.len(), 2);
+    }
+
+    #[test]
+    fn test_elevated_scales_and_widens_limit() {
+        let config = ExecutionGovernorConfig::default();
+        let order = OrderRequest::limit_buy("005930".into(), dec!(15), dec!(70000));
+        let cond = condition(None, Some(dec!(40)));
+
+        let check = check_execution_governor(
+            &order,
+            Some(&cond),
+            true,
+            false,
+            dec!(70000),
+            Utc::now(),
+            &config,
+        );
+        let GovernorCheck::Throttled(decision) = check else {
+            panic!("expected throttled: {:?}", check);
+        };
+        assert_eq!(decision.quantity, dec!(7));
+        // 70000 - 20bp = 69860
+        assert_eq!(decision.price, Some(dec!(69860)));
+        assert!(decision.throttle_note().contains("limit 70000 -> 69860"));
+    }
+
+    #[test]
+    fn test_extreme_pauses_entries_only() {
+        let config = ExecutionGovernorConfig::default();
+        let order = OrderRequest::market_buy("005930".into(), dec!(10));
+        let cond = condition(Some(dec!(4)), None);
+        let now = Utc::now();
+
+        let check =
+            check_execution_governor(&order, Some(&cond), true, false, dec!(70000), now, &config);
+        assert!(matches!(check, GovernorCheck::Paused(_)));
+        assert!(check
+            .decision()
+            .unwrap()
+            .pause_reason("005930")
+            .contains("extreme"));
+
+        // 청산 주문, 조절 제외 전략, 만료된 상황은 대상 아님
+        for (is_entry, opted_out, at) in [
+            (false, false, now),
+            (true, true, now),
+            (
+                true,
+                false,
+                now + Duration::seconds(config.max_age_secs + 1),
+            ),
+        ] {
+            assert_eq!(
+                check_execution_governor(
+                    &order,
+                    Some(&cond),
+                    is_entry,
+                    opted_out,
+                    dec!(70000),
+                    at,
+                    &config
+                ),
+                GovernorCheck::NotApplicable
+            );
+        }
+    }
+
+    #[test]
+    fn test_elevated_below_minimum_pauses() {
+        let config = ExecutionGovernorConfig::default();
+        let order = OrderRequest::market_buy("005930".into(), dec!(1));
+        let cond = condition(Some(dec!(2)), None);
+
+        let check = check_execution_governor(
+            &order,
+            Some(&cond),
+            true,
+            false,
+            dec!(70000),
+            Utc::now(),
+            &config,
+        );
+        assert!(matches!(check, GovernorCheck::Paused(_)));
+    }
+}
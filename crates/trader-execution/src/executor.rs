@@ -7,24 +7,68 @@
 //! - PositionTracker를 통한 포지션 추적
 //! - 브라켓 주문 (손절/익절) 자동 관리
 //! - OCO(One-Cancels-Other) 주문 관리
+//! - 전략별 자본 예산 적용 및 정산
+//! - 일괄 신호 처리 시 매수 여력 예측 및 진입 주문 비례 축소
+//! - 거래소별 주문 서킷 브레이커 (연속 거부/오류 시 주문 차단)
+//! - 브로커 점검/장애 중 신규 주문 거부 또는 대기열 보관
+//! - 유동성(ADV, 스프레드) 기반 진입 주문 수량 상한
+//! - 변동성/스프레드 기반 진입 주문 조절 (전략별 제외 가능)
+//! - 리스크 프로필 (전역/전략별 실행 조절, 수량 없는 진입 신호 사이징)
+//! - 체결/포지션 변경 이벤트 브로드캐스트 (외부 알림용)
 //! - 실행 추적 및 보고
 
+use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use thiserror::Error;
-use tokio::sync::RwLock;
+use tokio::sync::{broadcast, RwLock};
 use tracing::{debug, info, warn};
 use trader_core::{
-    Order, OrderRequest, OrderStatus, OrderStatusType, OrderType, Position, Side, Signal,
-    SignalType, TimeInForce,
+    is_synthetic_ticker, BrokerDowntimeCalendar, FuturesContract, Liquidity, Order, OrderRequest,
+    OrderStatus, OrderStatusType, OrderType, Position, Side, Signal, SignalType, TickSizeProvider,
+    TimeInForce, TradeCost, TradingCostModel, TradingStatus, TradingStatusEvent,
+    TradingVolumeWindow,
+};
+use trader_exchange::circuit_breaker::CircuitState;
+use trader_exchange::connector::kis::current_us_trading_session;
+use trader_exchange::{Exchange, ExchangeError as VenueError, FillReport, RetryConfig};
+use trader_risk::{
+    check_curfew, CapitalCheck, CurfewCheck, CurfewConfig, CurfewOverride, RiskManager,
 };
-use trader_risk::RiskManager;
 use uuid::Uuid;
 
+use crate::basket::{
+    resolve_leg, BasketFailurePolicy, BasketLegResult, BasketLegStatus, BasketRequest, BasketResult,
+};
+use crate::broker_downtime::{
+    BrokerDowntimeConfig, BrokerDowntimeGuard, DowntimeAdmission, DowntimePolicy,
+};
+use crate::execution_governor::{
+    check_execution_governor, ExecutionGovernorConfig, GovernorCheck, MarketCondition,
+};
+use crate::extended_hours::{check_extended_hours, ExtendedHoursCheck, ExtendedHoursConfig};
+use crate::funding::FundingForecast;
+use crate::idempotency::{
+    client_order_id_for, submit_idempotent, IntentAdmission, OrderIntent, OrderIntentGuard,
+    OrderIntentStore,
+};
+use crate::invariants::InvariantHook;
+use crate::liquidity_cap::{
+    check_liquidity_cap, LiquidityCapCheck, LiquidityCapConfig, LiquiditySnapshot,
+};
+use crate::order_circuit::{
+    CircuitAdmission, OpenCircuitPolicy, OrderCircuitConfig, OrderCircuitGuard,
+};
 use crate::order_manager::{OrderFill, OrderManager};
-use crate::position_tracker::PositionTracker;
+use crate::position_tracker::{PositionTracker, PositionTrackerError};
+use crate::preview::{
+    round_order_to_tick, BuyingPower, OrderPreview, PreviewOrder, PreviewOrderRole,
+};
+use crate::risk_profile::{ExecutionThrottles, RiskProfile};
+use crate::strategy_portfolio::{StrategyPortfolioSnapshot, StrategyPortfolios};
+use crate::synthetic::plan_synthetic_basket;
 
 /// 실행 오류 유형.
 #[derive(Debug, Error)]
@@ -49,6 +93,9 @@ pub enum ExecutionError {
 
     #[error("Bracket order error: {0}")]
     BracketOrderError(String),
+
+    #[error("Order circuit open: {0}")]
+    CircuitOpen(String),
 }
 
 // ==================== 브라켓 주문 관리 ====================
@@ -349,6 +396,52 @@ impl Default for ConversionConfig {
     }
 }
 
+/// 실행 이벤트 채널 버퍼 크기.
+const EXECUTION_EVENT_CAPACITY: usize = 256;
+
+/// 체결 처리 결과 이벤트.
+///
+/// `handle_fill()`이 주문/포지션 상태를 갱신한 뒤 발행하며,
+/// 웹훅 등 외부 알림 서비스가 구독합니다.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ExecutionEvent {
+    /// 주문 체결 (부분 체결 포함)
+    OrderFilled {
+        order_id: Uuid,
+        strategy_id: Option<String>,
+        ticker: String,
+        side: Side,
+        /// 이번 체결 수량
+        fill_quantity: Decimal,
+        /// 이번 체결 가격
+        fill_price: Decimal,
+        /// 누적 체결 수량
+        filled_quantity: Decimal,
+        /// 주문 수량
+        order_quantity: Decimal,
+        /// 주문 완전 체결 여부
+        complete: bool,
+        timestamp: DateTime<Utc>,
+    },
+    /// 체결로 인한 포지션 변경
+    PositionChanged {
+        strategy_id: Option<String>,
+        ticker: String,
+        side: Side,
+        /// 체결 후 보유 수량 (청산 시 0)
+        quantity: Decimal,
+        entry_price: Decimal,
+        /// 누적 실현 손익
+        realized_pnl: Decimal,
+        /// 이번 체결로 신규 진입했는지 여부
+        opened: bool,
+        /// 포지션 청산 여부
+        closed: bool,
+        timestamp: DateTime<Utc>,
+    },
+}
+
 /// Signal에 대한 실행 결과.
 #[derive(Debug, Clone)]
 pub struct ExecutionResult {
@@ -368,6 +461,10 @@ pub struct ExecutionResult {
     pub error: Option<String>,
     /// 실행 노트/경고
     pub notes: Vec<String>,
+    /// 주문 서킷이 열려 있어 대기열에 보관되었는지 여부
+    pub queued: bool,
+    /// 신호 메타데이터에 기록할 실행 결정 (예: `liquidity_cap`)
+    pub metadata: HashMap<String, serde_json::Value>,
 }
 
 impl ExecutionResult {
@@ -382,6 +479,8 @@ impl ExecutionResult {
             success: true,
             error: None,
             notes: vec![],
+            queued: false,
+            metadata: HashMap::new(),
         }
     }
 
@@ -396,6 +495,8 @@ impl ExecutionResult {
             success: false,
             error: Some(error.into()),
             notes: vec![],
+            queued: false,
+            metadata: HashMap::new(),
         }
     }
 
@@ -417,12 +518,31 @@ impl ExecutionResult {
         self
     }
 
+    /// 대기열 보관 표시.
+    pub fn with_queued(mut self) -> Self {
+        self.queued = true;
+        self
+    }
+
     /// 노트 추가.
     pub fn with_note(mut self, note: impl Into<String>) -> Self {
         self.notes.push(note.into());
         self
     }
 
+    /// 메타데이터 추가.
+    pub fn with_metadata(mut self, key: impl Into<String>, value: serde_json::Value) -> Self {
+        self.metadata.insert(key.into(), value);
+        self
+    }
+
+    /// 실행 결정을 신호 메타데이터에 병합.
+    pub fn apply_metadata(&self, signal: &mut Signal) {
+        for (key, value) in &self.metadata {
+            signal.metadata.insert(key.clone(), value.clone());
+        }
+    }
+
     /// 내부 주문 ID 조회.
     pub fn order_id(&self) -> Option<Uuid> {
         self.order_id
@@ -557,6 +677,40 @@ impl SignalConverter {
     }
 }
 
+/// 진입 주문에 배정된 전략 자본.
+#[derive(Debug, Clone)]
+struct CapitalReservation {
+    /// 전략 ID
+    strategy_id: String,
+    /// 아직 체결되지 않은 수량
+    remaining_quantity: Decimal,
+    /// 수량당 배정 금액 (지정가 또는 신호 시점 현재가, 선물은 계약당 위탁증거금)
+    reference_price: Decimal,
+}
+
+/// 전략 예산에 배정할 수량당 금액 (선물은 계약당 위탁증거금, 그 외는 가격).
+fn capital_unit_cost(futures: Option<&FuturesContract>, price: Decimal) -> Decimal {
+    match futures {
+        Some(contract) => contract.initial_margin(price, Decimal::ONE),
+        None => price,
+    }
+}
+
+/// 종목의 순 보유 수량 (롱 양수, 숏 음수).
+fn net_position_quantity<'a>(
+    positions: impl IntoIterator<Item = &'a Position>,
+    ticker: &str,
+) -> Decimal {
+    positions
+        .into_iter()
+        .filter(|p| p.ticker == ticker)
+        .map(|p| match p.side {
+            Side::Buy => p.quantity,
+            Side::Sell => -p.quantity,
+        })
+        .sum()
+}
+
 /// 신호 처리 및 실행 관리를 위한 주문 executor.
 ///
 /// 다음을 통합하는 핵심 컴포넌트:
@@ -565,6 +719,12 @@ impl SignalConverter {
 /// - OrderManager: 주문 생명주기 추적 (생성 -> 체결 -> 완료)
 /// - PositionTracker: 포지션 관리 및 손익 계산
 /// - BracketOrderManager: 브라켓 주문 (손절/익절) 관리
+/// - CapitalLedger (RiskManager 내부): 전략별 예산 적용 및 정산
+/// - OrderCircuitGuard: 거래소별 연속 거부/오류 시 신규 주문 차단
+/// - TradingCostModel: 체결별 수수료/세금을 실현 손익에서 차감
+/// - LiquidityGate: 최근 ADV/스프레드 기준 진입 주문 수량 상한
+/// - ExecutionGovernor: 변동성 급등/스프레드 확대 시 진입 주문 축소 또는 보류
+/// - RiskProfile: 전역/전략별 실행 조절 설정 및 진입 수량 사이징
 ///
 /// # 거래소 중립성
 /// 이 executor는 거래소에 독립적으로 설계되었습니다.
@@ -579,8 +739,50 @@ pub struct OrderExecutor {
     order_manager: Arc<RwLock<OrderManager>>,
     /// 포지션 관리를 위한 포지션 추적기
     position_tracker: Arc<RwLock<PositionTracker>>,
+    /// 전략별 가상 계좌 (체결을 주문의 전략에 귀속)
+    strategy_portfolios: Arc<RwLock<StrategyPortfolios>>,
     /// 브라켓 주문 관리자
     bracket_manager: Arc<RwLock<BracketOrderManager>>,
+    /// 주문 ID별 전략 자본 배정
+    capital_reservations: Arc<RwLock<HashMap<Uuid, CapitalReservation>>>,
+    /// 거래소별 주문 서킷 브레이커
+    order_circuit: Arc<OrderCircuitGuard>,
+    /// 브로커 점검/장애 가드
+    broker_downtime: Arc<BrokerDowntimeGuard>,
+    /// 멱등성 키별 주문 의도 (중복 제출 방지)
+    order_intents: Arc<OrderIntentGuard>,
+    /// 체결/포지션 변경 이벤트 발행기
+    events: broadcast::Sender<ExecutionEvent>,
+    /// 전략별 거래 비용 모델
+    cost_models: Arc<RwLock<HashMap<String, TradingCostModel>>>,
+    /// 전략별 모델이 없을 때 사용할 기본 비용 모델 (없으면 비용 미차감)
+    default_cost_model: Option<TradingCostModel>,
+    /// 최근 30일 체결 금액 (암호화폐 수수료 구간 결정)
+    trade_volume: Arc<RwLock<TradingVolumeWindow>>,
+    /// 정규장 외 거래 설정
+    extended_hours: ExtendedHoursConfig,
+    /// 정규장 외 거래를 허용한 전략 ID
+    extended_hours_strategies: Arc<RwLock<HashSet<String>>>,
+    /// 장중 커퓨 설정
+    curfew: CurfewConfig,
+    /// 전략별 커퓨 오버라이드 (strategy_id -> 오버라이드)
+    curfew_overrides: Arc<RwLock<HashMap<String, CurfewOverride>>>,
+    /// 거래정지/VI 중인 종목 (ticker -> 거래 상태)
+    trading_statuses: Arc<RwLock<HashMap<String, TradingStatus>>>,
+    /// 유동성 수량 상한 설정
+    liquidity_cap: LiquidityCapConfig,
+    /// 종목별 최근 유동성 (ticker -> ADV/스프레드)
+    liquidity_snapshots: Arc<RwLock<HashMap<String, LiquiditySnapshot>>>,
+    /// 실행 조절 설정
+    execution_governor: ExecutionGovernorConfig,
+    /// 종목별 최근 시장 상황 (ticker -> 변동성 비율/스프레드)
+    market_conditions: Arc<RwLock<HashMap<String, MarketCondition>>>,
+    /// 실행 조절을 끈 전략 ID
+    governor_opt_outs: Arc<RwLock<HashSet<String>>>,
+    /// 전역 리스크 프로필 (없으면 기본 실행 조절 설정, 사이징 미적용)
+    risk_profile: Arc<RwLock<Option<RiskProfile>>>,
+    /// 전략별 리스크 프로필 (전역 프로필보다 우선)
+    strategy_risk_profiles: Arc<RwLock<HashMap<String, RiskProfile>>>,
     /// 실행 설정
     config: ConversionConfig,
     /// 거래소 식별자
@@ -596,17 +798,87 @@ impl OrderExecutor {
         config: ConversionConfig,
         exchange: String,
     ) -> Self {
+        let (events, _) = broadcast::channel(EXECUTION_EVENT_CAPACITY);
         Self {
             converter: SignalConverter::new(config.clone()),
             risk_manager,
             order_manager,
             position_tracker,
+            strategy_portfolios: Arc::new(RwLock::new(StrategyPortfolios::new(exchange.clone()))),
             bracket_manager: Arc::new(RwLock::new(BracketOrderManager::new())),
+            capital_reservations: Arc::new(RwLock::new(HashMap::new())),
+            order_circuit: Arc::new(OrderCircuitGuard::default()),
+            broker_downtime: Arc::new(BrokerDowntimeGuard::default()),
+            order_intents: Arc::new(OrderIntentGuard::default()),
+            events,
+            cost_models: Arc::new(RwLock::new(HashMap::new())),
+            default_cost_model: None,
+            trade_volume: Arc::new(RwLock::new(TradingVolumeWindow::default())),
+            extended_hours: ExtendedHoursConfig::default(),
+            extended_hours_strategies: Arc::new(RwLock::new(HashSet::new())),
+            curfew: CurfewConfig::default(),
+            curfew_overrides: Arc::new(RwLock::new(HashMap::new())),
+            trading_statuses: Arc::new(RwLock::new(HashMap::new())),
+            liquidity_cap: LiquidityCapConfig::default(),
+            liquidity_snapshots: Arc::new(RwLock::new(HashMap::new())),
+            execution_governor: ExecutionGovernorConfig::default(),
+            market_conditions: Arc::new(RwLock::new(HashMap::new())),
+            governor_opt_outs: Arc::new(RwLock::new(HashSet::new())),
+            risk_profile: Arc::new(RwLock::new(None)),
+            strategy_risk_profiles: Arc::new(RwLock::new(HashMap::new())),
             config,
             exchange,
         }
     }
 
+    /// 주문 서킷 브레이커 설정 적용.
+    pub fn with_order_circuit(mut self, config: OrderCircuitConfig) -> Self {
+        self.order_circuit = Arc::new(OrderCircuitGuard::new(config));
+        self
+    }
+
+    /// 브로커 점검/장애 처리 설정 적용.
+    ///
+    /// `calendar`는 점검 일정/장애 감지 모니터와 공유하는 달력입니다.
+    pub fn with_broker_downtime(
+        mut self,
+        config: BrokerDowntimeConfig,
+        calendar: Arc<RwLock<BrokerDowntimeCalendar>>,
+    ) -> Self {
+        self.broker_downtime = Arc::new(BrokerDowntimeGuard::new(config, calendar));
+        self
+    }
+
+    /// 기본 거래 비용 모델 설정.
+    pub fn with_cost_model(mut self, model: TradingCostModel) -> Self {
+        self.default_cost_model = Some(model);
+        self
+    }
+
+    /// 정규장 외 거래 설정 적용.
+    pub fn with_extended_hours(mut self, config: ExtendedHoursConfig) -> Self {
+        self.extended_hours = config;
+        self
+    }
+
+    /// 장중 커퓨 설정 적용.
+    pub fn with_curfew(mut self, config: CurfewConfig) -> Self {
+        self.curfew = config;
+        self
+    }
+
+    /// 유동성 수량 상한 설정 적용.
+    pub fn with_liquidity_cap(mut self, config: LiquidityCapConfig) -> Self {
+        self.liquidity_cap = config;
+        self
+    }
+
+    /// 실행 조절 설정 적용.
+    pub fn with_execution_governor(mut self, config: ExecutionGovernorConfig) -> Self {
+        self.execution_governor = config;
+        self
+    }
+
     /// 기본 설정으로 생성.
     pub fn with_risk_manager(risk_manager: Arc<RwLock<RiskManager>>, exchange: &str) -> Self {
         let order_manager = Arc::new(RwLock::new(OrderManager::new()));
@@ -641,56 +913,615 @@ impl OrderExecutor {
     /// OrderManager에 등록함. 실제 거래소 제출은
     /// `submit_order()`를 통해 수행해야 함.
     ///
+    /// 진입 신호는 전략 자본 원장의 가용 예산에 맞게 수량이 축소되거나
+    /// 거부되며, 통과한 주문 금액은 해당 전략 예산에 배정됨.
+    ///
     /// # 인자
     /// * `signal` - 처리할 트레이딩 신호
     /// * `current_price` - 현재 시장 가격
     pub async fn process_signal(&self, signal: &Signal, current_price: Decimal) -> ExecutionResult {
-        // Signal을 주문 요청으로 변환
-        let order_request = match self.converter.convert(signal, current_price, None) {
+        if is_synthetic_ticker(&signal.ticker) {
+            return self.process_synthetic_signal(signal).await;
+        }
+
+        // Signal을 주문 요청으로 변환 (부분 청산/프로필 사이징 수량 사용)
+        let quantity = self.signal_quantity(signal, current_price).await;
+        let order_request = match self.converter.convert(signal, current_price, quantity) {
             Ok(o) => o,
             Err(e) => return ExecutionResult::failure(signal.id, e.to_string()),
         };
 
+        let is_entry = SignalConverter::is_entry_signal(&signal.signal_type);
+        self.execute_order_request(signal.id, order_request, is_entry, current_price)
+            .await
+    }
+
+    /// 합성 종목 신호 처리.
+    ///
+    /// 신호 메타데이터의 가격식으로 leg 주문을 만들어 [`process_basket()`](Self::process_basket)으로
+    /// 실행합니다 (전체 미제출 정책). 결과의 `order_id`는 첫 번째로 접수된 leg 주문이며,
+    /// leg별 결과는 메타데이터 `synthetic_legs`에 기록합니다.
+    pub async fn process_synthetic_signal(&self, signal: &Signal) -> ExecutionResult {
+        let positions: HashMap<String, Decimal> = {
+            let tracker = self.position_tracker.read().await;
+            let open = tracker.get_open_positions();
+            open.iter()
+                .map(|p| {
+                    (
+                        p.ticker.clone(),
+                        net_position_quantity(open.iter().copied(), &p.ticker),
+                    )
+                })
+                .collect()
+        };
+        let request =
+            match plan_synthetic_basket(signal, self.converter.config.default_quantity, &positions)
+            {
+                Ok(request) => request,
+                Err(e) => return ExecutionResult::failure(signal.id, e),
+            };
+
+        let basket = self.process_basket(signal.id, request).await;
+        let mut result = ExecutionResult::failure(
+            signal.id,
+            format!(
+                "Synthetic basket failed: {} of {} legs rejected or cancelled",
+                basket.failed,
+                basket.legs.len()
+            ),
+        );
+        if basket.success {
+            result.success = true;
+            result.error = None;
+        }
+        result.order_id = basket.legs.iter().find_map(|leg| leg.order_id);
+        result.notes = basket
+            .legs
+            .iter()
+            .map(|leg| match &leg.error {
+                Some(error) => format!("{} {:?}: {}", leg.ticker, leg.status, error),
+                None => format!("{} {:?} {}", leg.ticker, leg.status, leg.quantity),
+            })
+            .collect();
+        result.metadata.insert(
+            "synthetic_legs".to_string(),
+            serde_json::to_value(&basket.legs).unwrap_or_default(),
+        );
+        result
+    }
+
+    /// 신호의 주문 수량 계산.
+    ///
+    /// 부분 청산 신호는 보유 수량 기준으로, 리스크 프로필이 적용된 진입 신호는
+    /// 프로필의 사이징 정책으로 계산합니다. 그 외에는 None을 반환하여 기본 수량을 사용합니다.
+    async fn signal_quantity(&self, signal: &Signal, current_price: Decimal) -> Option<Decimal> {
+        if signal.scale_out.is_some() {
+            return self.scale_out_quantity(signal, current_price).await;
+        }
+        if !SignalConverter::is_entry_signal(&signal.signal_type) {
+            return None;
+        }
+        let profile = self.risk_profile_for(Some(&signal.strategy_id)).await?;
+        let balance = self.risk_manager.read().await.balance();
+        Some(profile.sizing.entry_quantity(
+            &signal.ticker,
+            balance,
+            signal.suggested_price.unwrap_or(current_price),
+            signal.stop_loss,
+            &profile.risk,
+        ))
+    }
+
+    /// 부분 청산 신호의 청산 수량 계산.
+    ///
+    /// 보유 수량과 리스크 관리자의 계좌 잔고(목표 비중 기준 포트폴리오 가치)로 계산합니다.
+    /// 부분 청산 지시가 없는 신호는 None을 반환하여 기본 수량을 사용합니다.
+    async fn scale_out_quantity(&self, signal: &Signal, current_price: Decimal) -> Option<Decimal> {
+        let scale_out = signal.scale_out?;
+        let held = {
+            let tracker = self.position_tracker.read().await;
+            net_position_quantity(tracker.get_open_positions(), &signal.ticker).abs()
+        };
+        let portfolio_value = self.risk_manager.read().await.balance();
+        Some(scale_out.close_quantity(held, current_price, portfolio_value))
+    }
+
+    /// 신호 없이 주문 요청을 직접 처리.
+    ///
+    /// 조건부 주문처럼 서버에서 생성한 주문을 `process_signal()`과 같은 경로
+    /// (리스크 검증, 전략 예산, 주문 서킷, 브라켓 주문)로 등록합니다.
+    /// 보유 포지션이 없거나 같은 방향이면 진입 주문으로 간주합니다.
+    ///
+    /// # 인자
+    /// * `request_id` - 실행 결과를 식별할 ID (`ExecutionResult::signal_id`)
+    /// * `request` - 처리할 주문 요청
+    /// * `current_price` - 현재 시장 가격
+    pub async fn process_order_request(
+        &self,
+        request_id: Uuid,
+        request: OrderRequest,
+        current_price: Decimal,
+    ) -> ExecutionResult {
+        if request.quantity <= Decimal::ZERO {
+            return ExecutionResult::failure(request_id, "Order quantity must be positive");
+        }
+
+        let position_quantity = {
+            let tracker = self.position_tracker.read().await;
+            net_position_quantity(tracker.get_open_positions(), &request.ticker)
+        };
+        let is_entry = match request.side {
+            Side::Buy => position_quantity >= Decimal::ZERO,
+            Side::Sell => position_quantity <= Decimal::ZERO,
+        };
+
+        self.execute_order_request(request_id, request, is_entry, current_price)
+            .await
+    }
+
+    /// 바스켓 주문 처리.
+    ///
+    /// 모든 leg를 주문 요청으로 변환한 뒤 `process_order_request()`로 동시에 제출합니다.
+    /// leg 주문의 `client_order_id`는 `basket_{바스켓 ID}_{leg 번호}`입니다.
+    ///
+    /// - `BestEffort`: 실패한 leg만 제외
+    /// - `CancelOnFailure`: 하나라도 실패하면 접수된 leg를 취소
+    /// - `AllOrNone`: 제출 전 모든 leg를 미리보기하여 하나라도 거부되면 전체 미제출
+    ///
+    /// # 인자
+    /// * `basket_id` - 바스켓 ID
+    /// * `request` - 바스켓 주문 요청
+    pub async fn process_basket(&self, basket_id: Uuid, request: BasketRequest) -> BasketResult {
+        let BasketRequest {
+            legs,
+            total_value,
+            policy,
+            strategy_id,
+        } = request;
+
+        let positions: Vec<Position> = {
+            let tracker = self.position_tracker.read().await;
+            tracker.get_open_positions().into_iter().cloned().collect()
+        };
+
+        // leg별 주문 요청 변환 (변환 실패/목표 도달 leg는 바로 결과 확정)
+        let mut results: Vec<Option<BasketLegResult>> = Vec::with_capacity(legs.len());
+        let mut planned: Vec<(usize, OrderRequest, Decimal)> = Vec::new();
+        for (index, leg) in legs.iter().enumerate() {
+            let current_quantity = net_position_quantity(positions.iter(), &leg.ticker);
+            match resolve_leg(
+                leg,
+                total_value,
+                current_quantity,
+                strategy_id.as_deref(),
+                format!("basket_{}_{}", basket_id, index),
+            ) {
+                Ok(Some(order)) => {
+                    planned.push((index, order, leg.current_price));
+                    results.push(None);
+                }
+                Ok(None) => results.push(Some(BasketLegResult::unsubmitted(
+                    &leg.ticker,
+                    None,
+                    BasketLegStatus::Skipped,
+                    "Already at target",
+                ))),
+                Err(e) => results.push(Some(BasketLegResult::unsubmitted(
+                    &leg.ticker,
+                    None,
+                    BasketLegStatus::Rejected,
+                    e,
+                ))),
+            }
+        }
+
+        let has_invalid_leg = results
+            .iter()
+            .flatten()
+            .any(|r| r.status == BasketLegStatus::Rejected);
+
+        // 전량 또는 전무: 미리보기에서 하나라도 거부되면 제출하지 않음
+        let mut rejections = HashMap::new();
+        if policy == BasketFailurePolicy::AllOrNone {
+            for (index, order, price) in &planned {
+                let preview = self.preview_order(order, *price, None, None).await;
+                if !preview.accepted {
+                    rejections.insert(*index, preview.rejections.join("; "));
+                }
+            }
+        }
+
+        // 변환 실패 leg가 있으면 BestEffort 외에는 제출 전에 중단
+        if (has_invalid_leg && policy != BasketFailurePolicy::BestEffort) || !rejections.is_empty()
+        {
+            for (index, order, _) in &planned {
+                results[*index] = Some(match rejections.remove(index) {
+                    Some(reason) => BasketLegResult::unsubmitted(
+                        &order.ticker,
+                        Some(order),
+                        BasketLegStatus::Rejected,
+                        reason,
+                    ),
+                    None => BasketLegResult::unsubmitted(
+                        &order.ticker,
+                        Some(order),
+                        BasketLegStatus::Skipped,
+                        "Basket aborted: another leg was rejected",
+                    ),
+                });
+            }
+            return BasketResult::new(basket_id, policy, results.into_iter().flatten().collect());
+        }
+
+        // 동시 제출
+        let executions = futures::future::join_all(planned.iter().map(|(_, order, price)| {
+            self.process_order_request(Uuid::new_v4(), order.clone(), *price)
+        }))
+        .await;
+
+        let mut any_failed = has_invalid_leg;
+        for ((index, order, _), execution) in planned.iter().zip(executions) {
+            any_failed |= !execution.success;
+            results[*index] = Some(BasketLegResult {
+                ticker: order.ticker.clone(),
+                side: Some(order.side),
+                quantity: execution
+                    .order
+                    .as_ref()
+                    .map(|o| o.quantity)
+                    .unwrap_or(order.quantity),
+                status: if execution.success {
+                    BasketLegStatus::Submitted
+                } else {
+                    BasketLegStatus::Rejected
+                },
+                order_id: execution.order_id,
+                error: execution.error,
+                notes: execution.notes,
+            });
+        }
+
+        // 실패 시 취소: 접수된 leg 취소
+        if policy == BasketFailurePolicy::CancelOnFailure && any_failed {
+            for result in results.iter_mut().flatten() {
+                let Some(order_id) = result.order_id else {
+                    continue;
+                };
+                if result.status != BasketLegStatus::Submitted {
+                    continue;
+                }
+                match self
+                    .cancel_order(order_id, Some("Basket leg failed".to_string()))
+                    .await
+                {
+                    Ok(()) => result.status = BasketLegStatus::Cancelled,
+                    Err(e) => result.notes.push(format!("Cancel failed: {}", e)),
+                }
+            }
+        }
+
+        let result = BasketResult::new(basket_id, policy, results.into_iter().flatten().collect());
+        info!(
+            basket_id = %basket_id,
+            submitted = result.submitted,
+            failed = result.failed,
+            "Basket processed"
+        );
+        result
+    }
+
+    /// 주문 요청 검증 및 등록 (`process_signal()`, `process_order_request()` 공통).
+    async fn execute_order_request(
+        &self,
+        request_id: Uuid,
+        mut order_request: OrderRequest,
+        is_entry: bool,
+        current_price: Decimal,
+    ) -> ExecutionResult {
+        // 멱등성 키 (클라이언트 주문 ID가 없으면 요청 ID로 생성)
+        if order_request.client_order_id.is_none() {
+            order_request.client_order_id = Some(client_order_id_for(request_id));
+        }
+
         // PositionTracker에서 현재 포지션 조회
         let positions: Vec<Position> = {
             let tracker = self.position_tracker.read().await;
             tracker.get_open_positions().into_iter().cloned().collect()
         };
 
+        // 종목 거래정지/VI 검사
+        if let Some(reason) = self.check_trading_status(&order_request).await {
+            return ExecutionResult::failure(request_id, reason);
+        }
+
+        // 미국 주식 정규장 외 세션 검사 (전략 허용 여부, 지정가, 수량 축소)
+        let mut session_note = None;
+        match self
+            .check_extended_hours(&order_request, is_entry, current_price)
+            .await
+        {
+            ExtendedHoursCheck::Rejected(reason) => {
+                return ExecutionResult::failure(request_id, reason);
+            }
+            ExtendedHoursCheck::Scaled { quantity } => {
+                session_note = Some(format!(
+                    "Quantity scaled from {} to {} for {} session liquidity",
+                    order_request.quantity,
+                    quantity,
+                    current_us_trading_session()
+                ));
+                order_request.quantity = quantity;
+            }
+            ExtendedHoursCheck::Approved | ExtendedHoursCheck::NotApplicable => {}
+        }
+
+        // 장중 커퓨 (진입 주문만, 동시호가/장 시작 직후 차단 또는 수량 축소)
+        let mut curfew_note = None;
+        match self.check_curfew(&order_request, is_entry).await {
+            CurfewCheck::Blocked(reason) => {
+                return ExecutionResult::failure(request_id, reason);
+            }
+            CurfewCheck::Scaled { quantity, window } => {
+                curfew_note = Some(format!(
+                    "Quantity scaled from {} to {} during curfew window '{}'",
+                    order_request.quantity, quantity, window
+                ));
+                order_request.quantity = quantity;
+            }
+            CurfewCheck::NotApplicable => {}
+        }
+
+        // 유동성 수량 상한 (진입 주문만, 결정은 메타데이터로 기록)
+        let liquidity_check = self.check_liquidity_cap(&order_request, is_entry).await;
+        let liquidity_metadata = liquidity_check
+            .decision()
+            .and_then(|d| serde_json::to_value(d).ok());
+        let mut liquidity_note = None;
+        match liquidity_check {
+            LiquidityCapCheck::Rejected(decision) => {
+                let mut result = ExecutionResult::failure(
+                    request_id,
+                    decision.rejection_reason(&order_request.ticker),
+                );
+                if let Some(value) = liquidity_metadata {
+                    result = result.with_metadata("liquidity_cap", value);
+                }
+                return result;
+            }
+            LiquidityCapCheck::Capped(decision) => {
+                liquidity_note = Some(decision.cap_note());
+                order_request.quantity = decision.quantity;
+            }
+            LiquidityCapCheck::Approved(_) | LiquidityCapCheck::NotApplicable => {}
+        }
+
+        // 실행 조절 (진입 주문만, 변동성 급등/스프레드 확대 시 축소 또는 보류)
+        let governor_check = self
+            .check_execution_governor(&order_request, is_entry, current_price)
+            .await;
+        let governor_metadata = governor_check
+            .decision()
+            .and_then(|d| serde_json::to_value(d).ok());
+        let mut governor_note = None;
+        match governor_check {
+            GovernorCheck::Paused(decision) => {
+                let reason = decision.pause_reason(&order_request.ticker);
+                warn!(
+                    ticker = %order_request.ticker,
+                    strategy_id = ?order_request.strategy_id,
+                    regime = %decision.regime,
+                    "{}",
+                    reason
+                );
+                let mut result = ExecutionResult::failure(request_id, reason);
+                if let Some(value) = governor_metadata {
+                    result = result.with_metadata("execution_governor", value);
+                }
+                return result;
+            }
+            GovernorCheck::Throttled(decision) => {
+                let note = decision.throttle_note();
+                info!(
+                    ticker = %order_request.ticker,
+                    strategy_id = ?order_request.strategy_id,
+                    regime = %decision.regime,
+                    "{}",
+                    note
+                );
+                order_request.quantity = decision.quantity;
+                order_request.price = decision.price;
+                governor_note = Some(note);
+            }
+            GovernorCheck::Normal | GovernorCheck::NotApplicable => {}
+        }
+
+        // 선물은 명목금액 대신 계약당 위탁증거금으로 전략 예산 배정
+        let unit_cost = {
+            let futures = self.futures_contract(&order_request.ticker).await;
+            capital_unit_cost(
+                futures.as_ref(),
+                order_request.price.unwrap_or(current_price),
+            )
+        };
+
         // 리스크 관리자로 검증
         let mut risk_manager = self.risk_manager.write().await;
 
         let validation =
             match risk_manager.validate_order(&order_request, &positions, current_price) {
                 Ok(v) => v,
-                Err(e) => return ExecutionResult::failure(signal.id, e.to_string()),
+                Err(e) => return ExecutionResult::failure(request_id, e.to_string()),
             };
 
         if !validation.is_valid {
             // 수정된 주문 제안이 있는지 확인
             if let Some(modified) = validation.modified_order {
-                return ExecutionResult::failure(signal.id, validation.messages.join("; "))
+                return ExecutionResult::failure(request_id, validation.messages.join("; "))
                     .with_note(format!("Suggested adjusted order: {:?}", modified));
             }
-            return ExecutionResult::failure(signal.id, validation.messages.join("; "));
+            return ExecutionResult::failure(request_id, validation.messages.join("; "));
+        }
+
+        // 전략 예산 검사 (진입 주문만)
+        let mut budget_note = None;
+        let mut budget_managed = false;
+        if is_entry {
+            match risk_manager.check_strategy_capital_at(&order_request, unit_cost) {
+                CapitalCheck::Rejected(reason) => {
+                    return ExecutionResult::failure(request_id, reason);
+                }
+                CapitalCheck::Scaled {
+                    quantity,
+                    available,
+                } => {
+                    budget_note = Some(format!(
+                        "Quantity scaled from {} to {} by strategy budget (available {})",
+                        order_request.quantity, quantity, available
+                    ));
+                    order_request.quantity = quantity;
+                    budget_managed = true;
+                }
+                CapitalCheck::Approved => budget_managed = true,
+                CapitalCheck::Unmanaged => {}
+            }
+        }
+
+        // 동시 신호가 같은 예산을 중복 사용하지 않도록 잠금 해제 전에 배정
+        let reservation = if budget_managed {
+            order_request
+                .strategy_id
+                .clone()
+                .map(|strategy_id| CapitalReservation {
+                    strategy_id,
+                    remaining_quantity: order_request.quantity,
+                    reference_price: unit_cost,
+                })
+        } else {
+            None
+        };
+        if let Some(ref r) = reservation {
+            risk_manager
+                .capital_ledger_mut()
+                .reserve(&r.strategy_id, r.remaining_quantity * r.reference_price);
         }
 
+        // 주문 의도 저장소와 점검 확인은 DB를 거칠 수 있으므로 대기 전에 리스크 잠금 해제
         drop(risk_manager);
 
-        // OrderRequest에서 Order를 생성하고 OrderManager에 등록
+        // OrderRequest에서 Order를 생성
         let order = Order::from_request(order_request.clone(), &self.exchange);
         let order_id = order.id;
 
+        // 중복 제출 검사 (같은 키의 주문이 진행 중이면 새 주문을 만들지 않음)
+        let intent = OrderIntent::new(order_id, &order_request, Utc::now());
+        if let IntentAdmission::Duplicate(existing) = self.order_intents.claim(intent).await {
+            self.release_reservation(reservation.as_ref()).await;
+            warn!(
+                key = %existing.key,
+                order_id = %existing.order_id,
+                state = %existing.state,
+                "Duplicate order intent rejected"
+            );
+            let mut result = ExecutionResult::failure(request_id, existing.duplicate_reason());
+            if let Ok(value) = serde_json::to_value(&existing) {
+                result = result.with_metadata("order_intent", value);
+            }
+            return result;
+        }
+
+        // 브로커 점검/장애 검사 (진행 중이면 거부 또는 대기열 보관)
+        let downtime = self.broker_downtime.admit(order_id, Utc::now()).await;
+        if let DowntimeAdmission::Rejected(ref reason) = downtime {
+            self.order_intents.release(order_id).await;
+            self.release_reservation(reservation.as_ref()).await;
+            return ExecutionResult::failure(request_id, reason.clone());
+        }
+
+        // 주문 서킷 검사 (Open 상태면 거부 또는 대기열 보관)
+        // 점검/장애 대기열에 보관된 주문은 복구 후 제출되므로 서킷에 넣지 않음
+        let admission = if downtime == DowntimeAdmission::Allowed {
+            self.order_circuit.admit(
+                &self.exchange,
+                order_id,
+                order_request.strategy_id.as_deref(),
+            )
+        } else {
+            CircuitAdmission::Allowed
+        };
+        if let CircuitAdmission::Rejected(ref reason) = admission {
+            self.order_intents.release(order_id).await;
+            self.release_reservation(reservation.as_ref()).await;
+            return ExecutionResult::failure(request_id, reason.clone());
+        }
+
+        // OrderManager에 등록
         {
             let mut order_manager = self.order_manager.write().await;
             if let Err(e) = order_manager.add_order(order) {
-                return ExecutionResult::failure(signal.id, e.to_string());
+                self.order_circuit.dequeue(&self.exchange, order_id);
+                self.broker_downtime.dequeue(order_id);
+                self.order_intents.release(order_id).await;
+                self.release_reservation(reservation.as_ref()).await;
+                return ExecutionResult::failure(request_id, e.to_string());
             }
         }
 
+        if let Some(r) = reservation {
+            self.capital_reservations.write().await.insert(order_id, r);
+        }
+
         // 성공 결과 구성
         let mut result =
-            ExecutionResult::success(signal.id, order_request.clone()).with_order_id(order_id);
+            ExecutionResult::success(request_id, order_request.clone()).with_order_id(order_id);
+
+        if let Some(note) = session_note {
+            result = result.with_note(note);
+        }
+
+        if let Some(note) = curfew_note {
+            result = result.with_note(note);
+        }
+
+        if let Some(note) = liquidity_note {
+            result = result.with_note(note);
+        }
+
+        if let Some(value) = liquidity_metadata {
+            result = result.with_metadata("liquidity_cap", value);
+        }
+
+        if let Some(note) = governor_note {
+            result = result.with_note(note);
+        }
+
+        if let Some(value) = governor_metadata {
+            result = result.with_metadata("execution_governor", value);
+        }
+
+        if let Some(note) = budget_note {
+            result = result.with_note(note);
+        }
+
+        match admission {
+            CircuitAdmission::Queued(position) => {
+                result = result.with_queued().with_note(format!(
+                    "Order circuit open; queued at position {}",
+                    position
+                ));
+            }
+            CircuitAdmission::Probe => {
+                result = result.with_note("Order circuit half-open; submitting as recovery probe");
+            }
+            _ => {}
+        }
+
+        if let DowntimeAdmission::Queued { position, downtime } = downtime {
+            result = result.with_queued().with_note(format!(
+                "Broker unavailable ({}); queued at position {}",
+                downtime.describe(),
+                position
+            ));
+        }
 
         // 경고가 있으면 추가
         for msg in validation.messages {
@@ -698,29 +1529,28 @@ impl OrderExecutor {
         }
 
         // 진입 신호의 경우 설정에 따라 손절 및 익절 생성
-        if SignalConverter::is_entry_signal(&signal.signal_type)
-            && (self.config.auto_stop_loss || self.config.auto_take_profit) {
-                // 브라켓 주문 생성을 위한 임시 포지션 생성
-                let mock_position = Position::new(
-                    "temp",
-                    signal.ticker.clone(),
-                    signal.side,
-                    order_request.quantity,
-                    current_price,
-                );
+        if is_entry && (self.config.auto_stop_loss || self.config.auto_take_profit) {
+            // 브라켓 주문 생성을 위한 임시 포지션 생성
+            let mock_position = Position::new(
+                "temp",
+                order_request.ticker.clone(),
+                order_request.side,
+                order_request.quantity,
+                current_price,
+            );
 
-                let risk_manager = self.risk_manager.read().await;
+            let risk_manager = self.risk_manager.read().await;
 
-                if self.config.auto_stop_loss {
-                    let sl_order = risk_manager.generate_stop_loss(&mock_position, None);
-                    result = result.with_stop_loss(sl_order.to_order_request());
-                }
+            if self.config.auto_stop_loss {
+                let sl_order = risk_manager.generate_stop_loss(&mock_position, None);
+                result = result.with_stop_loss(sl_order.to_order_request());
+            }
 
-                if self.config.auto_take_profit {
-                    let tp_order = risk_manager.generate_take_profit(&mock_position, None);
-                    result = result.with_take_profit(tp_order.to_order_request());
-                }
+            if self.config.auto_take_profit {
+                let tp_order = risk_manager.generate_take_profit(&mock_position, None);
+                result = result.with_take_profit(tp_order.to_order_request());
             }
+        }
 
         // 브라켓 주문 등록 (손절/익절이 있는 경우)
         if let Some(order_id) = result.order_id {
@@ -737,43 +1567,468 @@ impl OrderExecutor {
         result
     }
 
-    /// 거래소에 주문 제출.
+    /// 주문 등록 전에 거부된 주문의 전략 자본 배정 반환.
+    async fn release_reservation(&self, reservation: Option<&CapitalReservation>) {
+        if let Some(r) = reservation {
+            self.risk_manager
+                .write()
+                .await
+                .capital_ledger_mut()
+                .release(&r.strategy_id, r.remaining_quantity * r.reference_price);
+        }
+    }
+
+    /// 주문 미리보기 (모의 실행).
     ///
-    /// OrderManager의 주문 상태를 업데이트하며,
-    /// 일반적으로 거래소 커넥터에 주문을 전송함.
+    /// `process_signal()`과 같은 리스크 검사, 전략 예산 검사, 주문 서킷 확인,
+    /// 브라켓 주문 생성을 거치지만 주문 등록, 예산 배정, 거래소 전송은 하지 않습니다.
+    /// 보유 포지션이 없거나 같은 방향이면 진입 주문으로 간주합니다.
     ///
     /// # 인자
-    /// * `order_id` - 내부 주문 ID
-    /// * `exchange_order_id` - 거래소에서 할당한 주문 ID
-    pub async fn submit_order(
+    /// * `request` - 미리볼 주문 요청
+    /// * `current_price` - 현재 시장 가격
+    /// * `tick_size` - 호가 단위 제공자 (없으면 가격 조정 안 함)
+    /// * `cost_model` - 비용 모델 (없으면 전략/기본 비용 모델 사용)
+    pub async fn preview_order(
         &self,
-        order_id: Uuid,
-        exchange_order_id: String,
-    ) -> Result<(), ExecutionError> {
-        let mut order_manager = self.order_manager.write().await;
+        request: &OrderRequest,
+        current_price: Decimal,
+        tick_size: Option<&dyn TickSizeProvider>,
+        cost_model: Option<TradingCostModel>,
+    ) -> OrderPreview {
+        let mut order = request.clone();
+        let mut rejections = Vec::new();
+        let mut warnings = Vec::new();
+        let mut suggested_order = None;
+
+        let mut price_adjustments = match tick_size {
+            Some(provider) => round_order_to_tick(PreviewOrderRole::Entry, &mut order, provider),
+            None => Vec::new(),
+        };
+        let reference_price = order.price.unwrap_or(current_price);
 
-        // 업데이트용 OrderStatus 생성 (거래소 제출됨 = Open)
-        let status = OrderStatus {
-            order_id: exchange_order_id.clone(),
-            client_order_id: None,
-            ticker: None,
-            side: None,
-            quantity: None,
-            price: None,
-            status: OrderStatusType::Open,
-            filled_quantity: Decimal::ZERO,
-            average_price: None,
-            updated_at: chrono::Utc::now(),
+        let positions: Vec<Position> = {
+            let tracker = self.position_tracker.read().await;
+            tracker.get_open_positions().into_iter().cloned().collect()
+        };
+        let position_quantity = net_position_quantity(positions.iter(), &order.ticker);
+        let is_entry = match order.side {
+            Side::Buy => position_quantity >= Decimal::ZERO,
+            Side::Sell => position_quantity <= Decimal::ZERO,
         };
 
-        // 주문 상태 업데이트
-        order_manager
-            .update_status(order_id, &status)
-            .map_err(|e| ExecutionError::ExecutionFailed(e.to_string()))?;
+        // 종목 거래정지/VI 검사
+        if let Some(reason) = self.check_trading_status(&order).await {
+            rejections.push(reason);
+        }
+
+        // 정규장 외 세션 검사
+        match self
+            .check_extended_hours(&order, is_entry, current_price)
+            .await
+        {
+            ExtendedHoursCheck::Rejected(reason) => rejections.push(reason),
+            ExtendedHoursCheck::Scaled { quantity } => {
+                warnings.push(format!(
+                    "Quantity scaled from {} to {} for {} session liquidity",
+                    order.quantity,
+                    quantity,
+                    current_us_trading_session()
+                ));
+                order.quantity = quantity;
+            }
+            ExtendedHoursCheck::Approved | ExtendedHoursCheck::NotApplicable => {}
+        }
+
+        // 장중 커퓨
+        match self.check_curfew(&order, is_entry).await {
+            CurfewCheck::Blocked(reason) => rejections.push(reason),
+            CurfewCheck::Scaled { quantity, window } => {
+                warnings.push(format!(
+                    "Quantity scaled from {} to {} during curfew window '{}'",
+                    order.quantity, quantity, window
+                ));
+                order.quantity = quantity;
+            }
+            CurfewCheck::NotApplicable => {}
+        }
+
+        // 유동성 수량 상한
+        let liquidity_check = self.check_liquidity_cap(&order, is_entry).await;
+        match liquidity_check {
+            LiquidityCapCheck::Rejected(decision) => {
+                rejections.push(decision.rejection_reason(&order.ticker))
+            }
+            LiquidityCapCheck::Capped(decision) => {
+                warnings.push(decision.cap_note());
+                order.quantity = decision.quantity;
+            }
+            LiquidityCapCheck::Approved(_) | LiquidityCapCheck::NotApplicable => {}
+        }
+
+        // 실행 조절
+        match self
+            .check_execution_governor(&order, is_entry, current_price)
+            .await
+        {
+            GovernorCheck::Paused(decision) => {
+                rejections.push(decision.pause_reason(&order.ticker))
+            }
+            GovernorCheck::Throttled(decision) => {
+                warnings.push(decision.throttle_note());
+                order.quantity = decision.quantity;
+                order.price = decision.price;
+            }
+            GovernorCheck::Normal | GovernorCheck::NotApplicable => {}
+        }
+
+        // 리스크 검사 및 전략 예산 검사 (배정 없이 확인만)
+        let balance = {
+            let mut risk_manager = self.risk_manager.write().await;
+
+            match risk_manager.validate_order(&order, &positions, current_price) {
+                Ok(validation) if validation.is_valid => warnings.extend(validation.messages),
+                Ok(validation) => {
+                    rejections.extend(validation.messages);
+                    suggested_order = validation.modified_order;
+                }
+                Err(e) => rejections.push(e.to_string()),
+            }
+
+            if is_entry {
+                match risk_manager.check_strategy_capital(&order, current_price) {
+                    CapitalCheck::Rejected(reason) => rejections.push(reason),
+                    CapitalCheck::Scaled {
+                        quantity,
+                        available,
+                    } => {
+                        warnings.push(format!(
+                            "Quantity scaled from {} to {} by strategy budget (available {})",
+                            order.quantity, quantity, available
+                        ));
+                        order.quantity = quantity;
+                    }
+                    CapitalCheck::Approved | CapitalCheck::Unmanaged => {}
+                }
+            }
+
+            risk_manager.balance()
+        };
+
+        // 주문 서킷 상태
+        let circuit_state = self.order_circuit.state(&self.exchange);
+        match circuit_state {
+            CircuitState::Open => match self.order_circuit.config().open_policy {
+                OpenCircuitPolicy::Reject => {
+                    rejections.push(format!("Order circuit open for {}", self.exchange))
+                }
+                OpenCircuitPolicy::Queue => {
+                    warnings.push("Order circuit open; order would be queued".to_string())
+                }
+            },
+            CircuitState::HalfOpen => warnings
+                .push("Order circuit half-open; order would be submitted as recovery probe".into()),
+            CircuitState::Closed => {}
+        }
+
+        // 같은 클라이언트 주문 ID의 주문이 진행 중이면 중복 제출
+        if let Some(existing) = order
+            .client_order_id
+            .as_deref()
+            .and_then(|key| self.order_intents.get(key))
+            .filter(|intent| intent.blocks_resubmit())
+        {
+            rejections.push(existing.duplicate_reason());
+        }
+
+        // 브로커 점검/장애
+        if let Some(downtime) = self.broker_downtime.active(Utc::now()).await {
+            match self.broker_downtime.config().policy {
+                DowntimePolicy::Reject => {
+                    rejections.push(format!("Broker unavailable: {}", downtime.describe()))
+                }
+                DowntimePolicy::Queue => warnings.push(format!(
+                    "Broker unavailable ({}); order would be queued",
+                    downtime.describe()
+                )),
+            }
+        }
+
+        // 예상 비용
+        let notional = order.quantity * reference_price;
+        let model = match cost_model {
+            Some(model) => Some(model),
+            None => self.strategy_cost_model(order.strategy_id.as_deref()).await,
+        };
+        let estimated_cost = match model {
+            Some(model) => {
+                let trailing_volume = self.trade_volume.write().await.volume(Utc::now());
+                Some(model.trade_cost(
+                    order.side,
+                    notional,
+                    Liquidity::from_order_type(order.order_type),
+                    trailing_volume,
+                ))
+            }
+            None => None,
+        };
+
+        // 매수 여력 (지정가 미체결 매수 주문 금액 차감)
+        let committed: Decimal = self
+            .get_active_orders()
+            .await
+            .iter()
+            .filter(|o| o.side == Side::Buy)
+            .filter_map(|o| o.price.map(|price| price * o.remaining_quantity()))
+            .sum();
+        let buying_power = BuyingPower::compute(
+            balance,
+            committed,
+            position_quantity,
+            order.side,
+            order.quantity,
+            reference_price,
+            estimated_cost.map(|c| c.total()).unwrap_or(Decimal::ZERO),
+        );
+        if !buying_power.sufficient {
+            rejections.push(format!(
+                "Insufficient buying power: required {}, available {}",
+                buying_power.required, buying_power.available
+            ));
+        }
+
+        // 진입 주문의 브라켓 주문 (process_signal과 동일 기준)
+        let mut orders = vec![PreviewOrder {
+            role: PreviewOrderRole::Entry,
+            order: order.clone(),
+        }];
+        if is_entry && (self.config.auto_stop_loss || self.config.auto_take_profit) {
+            let mock_position = Position::new(
+                "preview",
+                order.ticker.clone(),
+                order.side,
+                order.quantity,
+                current_price,
+            );
+            let risk_manager = self.risk_manager.read().await;
+
+            let mut brackets = Vec::new();
+            if self.config.auto_stop_loss {
+                brackets.push((
+                    PreviewOrderRole::StopLoss,
+                    risk_manager
+                        .generate_stop_loss(&mock_position, None)
+                        .to_order_request(),
+                ));
+            }
+            if self.config.auto_take_profit {
+                brackets.push((
+                    PreviewOrderRole::TakeProfit,
+                    risk_manager
+                        .generate_take_profit(&mock_position, None)
+                        .to_order_request(),
+                ));
+            }
+
+            for (role, mut child) in brackets {
+                if let Some(provider) = tick_size {
+                    price_adjustments.extend(round_order_to_tick(role, &mut child, provider));
+                }
+                orders.push(PreviewOrder { role, order: child });
+            }
+        }
+
+        OrderPreview {
+            accepted: rejections.is_empty(),
+            rejections,
+            warnings,
+            suggested_order,
+            reference_price,
+            notional,
+            estimated_cost,
+            buying_power,
+            price_adjustments,
+            order_circuit: circuit_state.to_string(),
+            orders,
+        }
+    }
+
+    /// 거래소에 주문 제출.
+    ///
+    /// OrderManager의 주문 상태를 업데이트하며,
+    /// 일반적으로 거래소 커넥터에 주문을 전송함.
+    ///
+    /// # 인자
+    /// * `order_id` - 내부 주문 ID
+    /// * `exchange_order_id` - 거래소에서 할당한 주문 ID
+    pub async fn submit_order(
+        &self,
+        order_id: Uuid,
+        exchange_order_id: String,
+    ) -> Result<(), ExecutionError> {
+        let mut order_manager = self.order_manager.write().await;
+
+        // 업데이트용 OrderStatus 생성 (거래소 제출됨 = Open)
+        let status = OrderStatus {
+            order_id: exchange_order_id.clone(),
+            client_order_id: None,
+            ticker: None,
+            side: None,
+            quantity: None,
+            price: None,
+            status: OrderStatusType::Open,
+            filled_quantity: Decimal::ZERO,
+            average_price: None,
+            updated_at: chrono::Utc::now(),
+        };
+
+        // 주문 상태 업데이트
+        order_manager
+            .update_status(order_id, &status)
+            .map_err(|e| ExecutionError::ExecutionFailed(e.to_string()))?;
+
+        if let Some(order) = order_manager.get_order(order_id) {
+            self.order_circuit
+                .record_success(&order.exchange, order.strategy_id.as_deref());
+        }
+        drop(order_manager);
+
+        self.order_intents
+            .mark_submitted(order_id, &exchange_order_id)
+            .await;
+
+        Ok(())
+    }
+
+    /// 거래소 주문 제출 실패 처리.
+    ///
+    /// 주문을 거부 상태로 전환하고 배정된 전략 자본을 반환하며,
+    /// 실패를 주문 서킷에 기록합니다. 연속 거부나 오류 폭주가
+    /// 임계치에 도달하면 해당 거래소의 신규 주문이 차단됩니다.
+    /// 타임아웃/연결 오류는 접수 여부를 알 수 없으므로 같은 멱등성 키의 재주문을 계속 막습니다.
+    ///
+    /// # 인자
+    /// * `order_id` - 내부 주문 ID
+    /// * `error` - 거래소 커넥터가 반환한 오류
+    pub async fn handle_submit_failure(
+        &self,
+        order_id: Uuid,
+        error: &VenueError,
+    ) -> Result<(), ExecutionError> {
+        let order = {
+            let mut order_manager = self.order_manager.write().await;
+            let order = order_manager.get_order(order_id).cloned().ok_or_else(|| {
+                ExecutionError::ExecutionFailed(format!("Order {} not found", order_id))
+            })?;
+            order_manager
+                .reject_order(order_id, error.to_string())
+                .map_err(|e| ExecutionError::ExecutionFailed(e.to_string()))?;
+            order
+        };
+
+        self.order_circuit
+            .record_error(&order.exchange, order.strategy_id.as_deref(), error);
+        self.order_intents.mark_failed(order_id, error).await;
+
+        if let Some(r) = self.capital_reservations.write().await.remove(&order_id) {
+            self.risk_manager
+                .write()
+                .await
+                .capital_ledger_mut()
+                .release(&r.strategy_id, r.remaining_quantity * r.reference_price);
+        }
 
         Ok(())
     }
 
+    /// 멱등성을 보장하는 거래소 제출 (재시도 포함).
+    ///
+    /// 등록된 주문을 `exchange`로 제출하고 `retry` 설정에 따라 재시도합니다.
+    /// 재시도 전에는 이전 시도로 이미 접수된 주문이 있는지 먼저 확인하므로
+    /// 응답 타임아웃 후 재시도해도 거래소에 중복 주문이 생기지 않습니다.
+    /// 결과는 `submit_order()` 또는 `handle_submit_failure()`로 반영합니다.
+    ///
+    /// # 인자
+    /// * `exchange` - 주문을 보낼 거래소 커넥터
+    /// * `order_id` - 내부 주문 ID (제출 전 `Pending` 상태)
+    /// * `retry` - 재시도 설정
+    ///
+    /// # 반환
+    /// 거래소 주문 ID
+    pub async fn submit_with_retry(
+        &self,
+        exchange: &dyn Exchange,
+        order_id: Uuid,
+        retry: &RetryConfig,
+    ) -> Result<String, ExecutionError> {
+        let order = self.get_order(order_id).await.ok_or_else(|| {
+            ExecutionError::ExecutionFailed(format!("Order {} not found", order_id))
+        })?;
+        if order.status != OrderStatusType::Pending {
+            return Err(ExecutionError::ExecutionFailed(format!(
+                "Order {} already {:?}",
+                order_id, order.status
+            )));
+        }
+
+        let request = OrderRequest {
+            ticker: order.ticker.clone(),
+            side: order.side,
+            order_type: order.order_type,
+            quantity: order.quantity,
+            price: order.price,
+            stop_price: order.stop_price,
+            time_in_force: order.time_in_force,
+            client_order_id: order.client_order_id.clone(),
+            strategy_id: order.strategy_id.clone(),
+        };
+        // 실행기를 거치지 않고 등록된 주문은 주문 생성 시각 기준으로 대조
+        let intent = self
+            .order_intents
+            .find_by_order(order_id)
+            .unwrap_or_else(|| OrderIntent::new(order_id, &request, order.created_at));
+
+        match submit_idempotent(exchange, &request, &intent, retry).await {
+            Ok(submitted) => {
+                if submitted.recovered {
+                    info!(
+                        order_id = %order_id,
+                        exchange_order_id = %submitted.exchange_order_id,
+                        attempts = submitted.attempts,
+                        "Recovered order accepted before timeout"
+                    );
+                }
+                self.submit_order(order_id, submitted.exchange_order_id.clone())
+                    .await?;
+                Ok(submitted.exchange_order_id)
+            }
+            Err(e) => {
+                self.handle_submit_failure(order_id, &e).await?;
+                Err(ExecutionError::ExchangeError(e.to_string()))
+            }
+        }
+    }
+
+    /// 주문 서킷/브로커 복구 후 제출 가능한 대기 주문 조회.
+    ///
+    /// 서킷이 Closed로 복구되었거나 브로커 점검/장애가 끝난 경우에만
+    /// 해당 대기열을 비우고 아직 활성 상태인 주문을 대기 순서대로 반환합니다.
+    pub async fn take_released_orders(&self) -> Vec<Order> {
+        let mut released = self.order_circuit.take_released(&self.exchange);
+        released.extend(self.broker_downtime.take_released(Utc::now()).await);
+        if released.is_empty() {
+            return Vec::new();
+        }
+
+        let order_manager = self.order_manager.read().await;
+        released
+            .into_iter()
+            .filter_map(|id| order_manager.get_order(id))
+            .filter(|o| !o.status.is_final())
+            .cloned()
+            .collect()
+    }
+
     /// 거래소로부터 주문 체결 처리.
     ///
     /// 체결 정보로 OrderManager를 업데이트하고
@@ -807,94 +2062,690 @@ impl OrderExecutor {
         }
 
         // 체결에 따라 PositionTracker 업데이트
-        {
+        let (existing_position, mut updated_position) = {
             let mut position_tracker = self.position_tracker.write().await;
+            let existing = position_tracker
+                .get_position_for_symbol(&order.ticker)
+                .cloned();
+
+            // apply_fill은 새 포지션, 기존 포지션, 완전 청산된 포지션 모두 처리
+            let updated = match position_tracker.apply_fill(&order, &fill) {
+                Ok(position) => Some(position),
+                Err(e) => {
+                    // 오류 로그만 남기고 실패 처리하지 않음 - 포지션이 이미 청산되었을 수 있음
+                    warn!("Failed to apply fill to position: {}", e);
+                    None
+                }
+            };
+
+            (existing, updated)
+        };
 
-            // apply_fill은 새 포지션과 기존 포지션 모두 처리
-            if let Err(e) = position_tracker.apply_fill(&order, &fill) {
-                // 오류 로그만 남기고 실패 처리하지 않음 - 포지션이 이미 청산되었을 수 있음
-                warn!("Failed to apply fill to position: {}", e);
+        // 전략 가상 계좌에 체결 귀속
+        let strategy_position_id = {
+            let mut portfolios = self.strategy_portfolios.write().await;
+            match portfolios.apply_fill(&order, &fill) {
+                Ok(position) => Some(position.id),
+                Err(e) => {
+                    warn!(
+                        strategy_id = ?order.strategy_id,
+                        "Failed to apply fill to strategy portfolio: {}", e
+                    );
+                    None
+                }
+            }
+        };
+
+        // 전략 자본 정산
+        self.settle_strategy_capital(&order, &fill, existing_position.as_ref())
+            .await;
+
+        // 거래 비용 차감
+        let position_id = updated_position.as_ref().map(|p| p.id);
+        if let Some(cost) = self
+            .charge_trading_cost(&order, &fill, position_id, strategy_position_id)
+            .await
+        {
+            if let Some(position) = updated_position.as_mut() {
+                position.realized_pnl -= cost.total();
             }
         }
 
+        let opened = existing_position.is_none();
+        self.publish_fill_events(order_id, &order, &fill, updated_position, opened)
+            .await;
+
         Ok(())
     }
 
-    /// 거래소로부터 주문 체결 처리 (브라켓 주문 포함).
-    ///
-    /// 기본 `handle_fill`에 추가로 브라켓 주문 관리를 수행합니다.
-    /// 메인 주문 체결 시 손절/익절 주문을 반환하고,
-    /// 손절/익절 체결 시 OCO 취소 대상을 반환합니다.
+    /// 거래소가 푸시한 체결 처리 (예: KIS 실시간 체결통보).
     ///
-    /// # Returns
-    /// * `BracketFillResult` - 제출할 브라켓 주문 또는 취소할 주문 정보
+    /// 거래소 주문 ID로 내부 주문을 찾아 [`handle_fill`](Self::handle_fill)로 기록합니다.
+    /// 이 실행기가 제출하지 않은 주문(HTS 수동 주문 등)이거나 이미 종료된 주문이면
+    /// `Ok(None)`을 반환합니다.
+    pub async fn handle_exchange_fill(
+        &self,
+        report: &FillReport,
+    ) -> Result<Option<Uuid>, ExecutionError> {
+        let (order_id, is_complete) = {
+            let order_manager = self.order_manager.read().await;
+            let Some(order) = order_manager.get_order_by_exchange_id(&report.exchange_order_id)
+            else {
+                debug!(
+                    exchange_order_id = %report.exchange_order_id,
+                    "Fill for unknown exchange order ignored"
+                );
+                return Ok(None);
+            };
+            if order.status.is_final() {
+                debug!(order_id = %order.id, "Fill for final order ignored");
+                return Ok(None);
+            }
+            (
+                order.id,
+                order.filled_quantity + report.quantity >= order.quantity,
+            )
+        };
+
+        let fill = OrderFill {
+            order_id,
+            quantity: report.quantity,
+            price: report.price,
+            commission: None,
+            commission_asset: None,
+            timestamp: report.timestamp,
+        };
+        self.handle_fill(order_id, fill, is_complete).await?;
+        Ok(Some(order_id))
+    }
+
+    /// 체결/포지션 변경 이벤트 발행.
     ///
-    /// # 거래소 중립성
-    /// 반환된 주문은 호출자가 적절한 거래소 커넥터를 통해 제출/취소해야 합니다.
-    pub async fn handle_fill_with_brackets(
+    /// 구독자가 없으면 이벤트는 버려집니다.
+    async fn publish_fill_events(
         &self,
         order_id: Uuid,
-        fill: OrderFill,
-        is_complete: bool,
-    ) -> Result<BracketFillResult, ExecutionError> {
-        // 기본 체결 처리
-        self.handle_fill(order_id, fill.clone(), is_complete)
-            .await?;
-
-        // 완전 체결이 아니면 브라켓 처리 안 함
-        if !is_complete {
-            return Ok(BracketFillResult::None);
+        order: &Order,
+        fill: &OrderFill,
+        position: Option<Position>,
+        opened: bool,
+    ) {
+        if self.events.receiver_count() == 0 {
+            return;
         }
 
-        // 브라켓 주문 확인
-        let mut bracket_manager = self.bracket_manager.write().await;
+        let (filled_quantity, complete) = {
+            let order_manager = self.order_manager.read().await;
+            order_manager
+                .get_order(order_id)
+                .map(|o| (o.filled_quantity, o.status == OrderStatusType::Filled))
+                .unwrap_or((fill.quantity, false))
+        };
 
-        // 1. 메인 주문 체결인지 확인
-        if let Some((stop_loss, take_profit)) = bracket_manager.on_parent_filled(order_id) {
-            info!(
-                order_id = %order_id,
-                has_stop_loss = stop_loss.is_some(),
-                has_take_profit = take_profit.is_some(),
-                "메인 주문 체결, 브라켓 주문 제출 필요"
-            );
-            return Ok(BracketFillResult::SubmitBracket {
-                parent_order_id: order_id,
-                stop_loss,
-                take_profit,
+        let _ = self.events.send(ExecutionEvent::OrderFilled {
+            order_id,
+            strategy_id: order.strategy_id.clone(),
+            ticker: order.ticker.clone(),
+            side: order.side,
+            fill_quantity: fill.quantity,
+            fill_price: fill.price,
+            filled_quantity,
+            order_quantity: order.quantity,
+            complete,
+            timestamp: fill.timestamp,
+        });
+
+        if let Some(position) = position {
+            let closed = position.closed_at.is_some() || position.quantity <= Decimal::ZERO;
+            let _ = self.events.send(ExecutionEvent::PositionChanged {
+                strategy_id: position.strategy_id.clone(),
+                ticker: position.ticker.clone(),
+                side: position.side,
+                quantity: if closed {
+                    Decimal::ZERO
+                } else {
+                    position.quantity
+                },
+                entry_price: position.entry_price,
+                realized_pnl: position.realized_pnl,
+                opened: opened && !closed,
+                closed,
+                timestamp: fill.timestamp,
             });
         }
+    }
 
-        // 2. 자식 주문(손절/익절) 체결인지 확인 -> OCO 처리
-        if let Some(cancel_order_id) = bracket_manager.on_child_filled(order_id) {
-            info!(
-                filled_order_id = %order_id,
-                cancel_order_id = %cancel_order_id,
-                "브라켓 주문 체결, OCO 취소 필요"
+    /// 체결에 따라 전략 자본 원장 정산.
+    ///
+    /// - 진입 주문 체결: 배정 기준 가격의 예약분을 실제 체결가 기준으로 전환
+    /// - 반대 방향 체결 (청산): 청산 수량의 진입 원가를 반환하고 실현 손익 기록
+    ///
+    /// 선물은 원가 대신 계약당 위탁증거금을 배정하고, 손익에 거래승수를 적용합니다.
+    async fn settle_strategy_capital(
+        &self,
+        order: &Order,
+        fill: &OrderFill,
+        existing_position: Option<&Position>,
+    ) {
+        let futures = self.futures_contract(&order.ticker).await;
+
+        let reserved = {
+            let mut reservations = self.capital_reservations.write().await;
+            match reservations.get_mut(&order.id) {
+                Some(r) => {
+                    let filled = fill.quantity.min(r.remaining_quantity);
+                    r.remaining_quantity -= filled;
+                    let settled = (r.strategy_id.clone(), filled, r.reference_price);
+                    if r.remaining_quantity <= Decimal::ZERO {
+                        reservations.remove(&order.id);
+                    }
+                    Some(settled)
+                }
+                None => None,
+            }
+        };
+
+        let mut risk_manager = self.risk_manager.write().await;
+        let ledger = risk_manager.capital_ledger_mut();
+
+        if let Some((strategy_id, filled, reference_price)) = reserved {
+            ledger.release(&strategy_id, filled * reference_price);
+            ledger.reserve(
+                &strategy_id,
+                filled * capital_unit_cost(futures.as_ref(), fill.price),
             );
-            return Ok(BracketFillResult::CancelOther {
-                filled_order_id: order_id,
-                cancel_order_id,
-            });
+            return;
         }
 
-        Ok(BracketFillResult::None)
+        let Some(position) =
+            existing_position.filter(|p| p.side != order.side && p.quantity > Decimal::ZERO)
+        else {
+            return;
+        };
+        let Some(strategy_id) = order
+            .strategy_id
+            .as_deref()
+            .or(position.strategy_id.as_deref())
+        else {
+            return;
+        };
+
+        let closed = fill.quantity.min(position.quantity);
+        let multiplier = futures.as_ref().map_or(Decimal::ONE, |c| c.multiplier);
+        let pnl = match position.side {
+            Side::Buy => (fill.price - position.entry_price) * closed,
+            Side::Sell => (position.entry_price - fill.price) * closed,
+        } * multiplier;
+        ledger.release(
+            strategy_id,
+            closed * capital_unit_cost(futures.as_ref(), position.entry_price),
+        );
+        ledger.record_pnl(strategy_id, pnl);
     }
 
-    /// 브라켓 자식 주문 ID 등록.
+    /// 전략의 미국 주식 정규장 외 거래(주간거래, 프리마켓, 애프터마켓) 허용 여부 설정.
+    pub async fn set_strategy_extended_hours(&self, strategy_id: &str, allowed: bool) {
+        let mut strategies = self.extended_hours_strategies.write().await;
+        if allowed {
+            strategies.insert(strategy_id.to_string());
+        } else {
+            strategies.remove(strategy_id);
+        }
+    }
+
+    /// 전략의 정규장 외 거래 허용 여부.
     ///
-    /// 손절/익절 주문을 거래소에 제출한 후 호출하여
-    /// 내부 주문 ID를 등록합니다.
-    pub async fn register_bracket_child(
-        &self,
-        parent_order_id: Uuid,
-        child_order_id: Uuid,
-        is_stop_loss: bool,
-    ) {
-        let mut bracket_manager = self.bracket_manager.write().await;
-        bracket_manager.register_child_order(parent_order_id, child_order_id, is_stop_loss);
+    /// 전략 없는 수동 주문은 사용자가 직접 낸 주문이므로 허용합니다.
+    pub async fn strategy_allows_extended_hours(&self, strategy_id: Option<&str>) -> bool {
+        match strategy_id {
+            Some(id) => self.extended_hours_strategies.read().await.contains(id),
+            None => true,
+        }
     }
 
-    /// 활성 브라켓 주문 수 조회.
+    /// 현재 미국 주식 세션 기준 정규장 외 거래 검사.
+    async fn check_extended_hours(
+        &self,
+        order: &OrderRequest,
+        is_entry: bool,
+        current_price: Decimal,
+    ) -> ExtendedHoursCheck {
+        let session = current_us_trading_session();
+        if !session.is_extended() {
+            return ExtendedHoursCheck::NotApplicable;
+        }
+        let allowed = self
+            .strategy_allows_extended_hours(order.strategy_id.as_deref())
+            .await;
+        check_extended_hours(
+            order,
+            session,
+            allowed,
+            is_entry,
+            &self.extended_hours,
+            current_price,
+        )
+    }
+
+    /// 전략별 커퓨 오버라이드 설정 (`None`이면 기본 구간 적용).
+    pub async fn set_strategy_curfew(&self, strategy_id: &str, curfew: Option<CurfewOverride>) {
+        let mut overrides = self.curfew_overrides.write().await;
+        match curfew {
+            Some(curfew) => {
+                overrides.insert(strategy_id.to_string(), curfew);
+            }
+            None => {
+                overrides.remove(strategy_id);
+            }
+        }
+    }
+
+    /// 장중 커퓨 설정 조회.
+    pub fn curfew(&self) -> &CurfewConfig {
+        &self.curfew
+    }
+
+    /// 현재 시각 기준 커퓨 검사 (전략 오버라이드 반영).
+    ///
+    /// 전략 없는 수동 주문은 기본 구간만 적용합니다.
+    async fn check_curfew(&self, order: &OrderRequest, is_entry: bool) -> CurfewCheck {
+        if !self.curfew.enabled || !is_entry {
+            return CurfewCheck::NotApplicable;
+        }
+        let overrides = self.curfew_overrides.read().await;
+        let strategy_override = order
+            .strategy_id
+            .as_deref()
+            .and_then(|id| overrides.get(id));
+        check_curfew(
+            &order.ticker,
+            order.quantity,
+            is_entry,
+            Utc::now(),
+            &self.curfew,
+            strategy_override,
+        )
+    }
+
+    /// 선물 계약 명세 등록 (증거금률·만기를 기본값과 다르게 쓸 때).
+    ///
+    /// 등록하지 않은 국내 지수선물 코드도 기본 명세(거래승수, 기본 증거금률)로 처리됩니다.
+    pub async fn register_futures_contract(
+        &self,
+        contract: FuturesContract,
+    ) -> Result<(), PositionTrackerError> {
+        self.position_tracker
+            .write()
+            .await
+            .register_futures_contract(contract.clone())?;
+        self.strategy_portfolios
+            .write()
+            .await
+            .register_futures_contract(contract)
+    }
+
+    /// 종목의 선물 계약 명세 (선물이 아니면 `None`).
+    async fn futures_contract(&self, ticker: &str) -> Option<FuturesContract> {
+        self.position_tracker.read().await.futures_contract(ticker)
+    }
+
+    /// 주문/포지션 장부의 회계 불변식 위반 훅 설정 (예: 모니터링 에러 기록).
+    pub async fn set_invariant_hook(&self, hook: InvariantHook) {
+        self.order_manager
+            .write()
+            .await
+            .set_invariant_hook(hook.clone());
+        self.position_tracker.write().await.set_invariant_hook(hook);
+    }
+
+    /// 종목 거래 상태(거래정지, VI) 반영.
+    pub async fn set_trading_status(&self, event: &TradingStatusEvent) {
+        let mut statuses = self.trading_statuses.write().await;
+        if event.status.is_restricted() {
+            statuses.insert(event.ticker.clone(), event.status);
+        } else {
+            statuses.remove(&event.ticker);
+        }
+    }
+
+    /// 종목의 최근 유동성(ADV, 스프레드) 반영.
+    pub async fn set_liquidity_snapshot(&self, ticker: &str, snapshot: LiquiditySnapshot) {
+        self.liquidity_snapshots
+            .write()
+            .await
+            .insert(ticker.to_string(), snapshot);
+    }
+
+    /// 종목의 최근 유동성 조회.
+    pub async fn liquidity_snapshot(&self, ticker: &str) -> Option<LiquiditySnapshot> {
+        self.liquidity_snapshots.read().await.get(ticker).cloned()
+    }
+
+    /// 유동성 수량 상한 설정 조회.
+    pub fn liquidity_cap(&self) -> &LiquidityCapConfig {
+        &self.liquidity_cap
+    }
+
+    /// 최근 유동성 기준 진입 주문 수량 상한 검사 (리스크 프로필 설정 반영).
+    async fn check_liquidity_cap(&self, order: &OrderRequest, is_entry: bool) -> LiquidityCapCheck {
+        let throttles = self.throttles_for(order.strategy_id.as_deref()).await;
+        let snapshots = self.liquidity_snapshots.read().await;
+        check_liquidity_cap(
+            order,
+            snapshots.get(&order.ticker),
+            is_entry,
+            &throttles.liquidity_cap,
+        )
+    }
+
+    /// 종목의 최근 시장 상황(변동성 비율, 스프레드) 반영.
+    pub async fn set_market_condition(&self, ticker: &str, condition: MarketCondition) {
+        self.market_conditions
+            .write()
+            .await
+            .insert(ticker.to_string(), condition);
+    }
+
+    /// 종목의 최근 시장 상황 조회.
+    pub async fn market_condition(&self, ticker: &str) -> Option<MarketCondition> {
+        self.market_conditions.read().await.get(ticker).cloned()
+    }
+
+    /// 실행 조절 설정 조회.
+    pub fn execution_governor(&self) -> &ExecutionGovernorConfig {
+        &self.execution_governor
+    }
+
+    /// 전략의 실행 조절 제외 여부 설정.
+    pub async fn set_strategy_governor_opt_out(&self, strategy_id: &str, opt_out: bool) {
+        let mut opt_outs = self.governor_opt_outs.write().await;
+        if opt_out {
+            opt_outs.insert(strategy_id.to_string());
+        } else {
+            opt_outs.remove(strategy_id);
+        }
+    }
+
+    /// 전역 리스크 프로필 설정 (`None`이면 기본 실행 조절 설정 사용, 사이징 미적용).
+    ///
+    /// 프로필의 리스크 한도는 적용하지 않으므로 호출자가 리스크 관리자에 따로 반영해야 합니다.
+    pub async fn set_risk_profile(&self, profile: Option<RiskProfile>) {
+        *self.risk_profile.write().await = profile;
+    }
+
+    /// 전역 리스크 프로필 조회.
+    pub async fn risk_profile(&self) -> Option<RiskProfile> {
+        self.risk_profile.read().await.clone()
+    }
+
+    /// 전략별 리스크 프로필 설정 (`None`이면 전역 프로필 사용).
+    pub async fn set_strategy_risk_profile(&self, strategy_id: &str, profile: Option<RiskProfile>) {
+        let mut profiles = self.strategy_risk_profiles.write().await;
+        match profile {
+            Some(profile) => {
+                profiles.insert(strategy_id.to_string(), profile);
+            }
+            None => {
+                profiles.remove(strategy_id);
+            }
+        }
+    }
+
+    /// 전략에 적용되는 리스크 프로필 조회 (전략 프로필 → 전역 프로필 순).
+    pub async fn risk_profile_for(&self, strategy_id: Option<&str>) -> Option<RiskProfile> {
+        if let Some(id) = strategy_id {
+            if let Some(profile) = self.strategy_risk_profiles.read().await.get(id) {
+                return Some(profile.clone());
+            }
+        }
+        self.risk_profile.read().await.clone()
+    }
+
+    /// 전략에 적용되는 실행 조절 설정 (프로필이 없으면 기본 설정).
+    pub async fn throttles_for(&self, strategy_id: Option<&str>) -> ExecutionThrottles {
+        match self.risk_profile_for(strategy_id).await {
+            Some(profile) => profile.throttles,
+            None => ExecutionThrottles {
+                liquidity_cap: self.liquidity_cap.clone(),
+                execution_governor: self.execution_governor.clone(),
+            },
+        }
+    }
+
+    /// 현재 시장 상황 기준 실행 조절 검사 (전략 제외 여부 반영).
+    ///
+    /// 전략 없는 수동 주문도 조절 대상입니다.
+    async fn check_execution_governor(
+        &self,
+        order: &OrderRequest,
+        is_entry: bool,
+        current_price: Decimal,
+    ) -> GovernorCheck {
+        let opted_out = match order.strategy_id.as_deref() {
+            Some(id) => self.governor_opt_outs.read().await.contains(id),
+            None => false,
+        };
+        let throttles = self.throttles_for(order.strategy_id.as_deref()).await;
+        let conditions = self.market_conditions.read().await;
+        check_execution_governor(
+            order,
+            conditions.get(&order.ticker),
+            is_entry,
+            opted_out,
+            current_price,
+            Utc::now(),
+            &throttles.execution_governor,
+        )
+    }
+
+    /// 종목 거래 상태 조회.
+    pub async fn trading_status(&self, ticker: &str) -> TradingStatus {
+        self.trading_statuses
+            .read()
+            .await
+            .get(ticker)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// 종목 거래 상태 기준 주문 보류 검사 (보류 사유 반환).
+    ///
+    /// 거래정지 종목은 모든 주문을, VI 발동 종목은 시장가 주문을 보류합니다.
+    /// VI 중 지정가 주문은 단일가 매매에 참여할 수 있으므로 허용합니다.
+    async fn check_trading_status(&self, order: &OrderRequest) -> Option<String> {
+        let status = self.trading_status(&order.ticker).await;
+        let is_market_order = matches!(
+            order.order_type,
+            OrderType::Market
+                | OrderType::StopLoss
+                | OrderType::TakeProfit
+                | OrderType::TrailingStop
+        );
+        if status.allows_order(is_market_order) {
+            return None;
+        }
+        Some(if status.is_halted() {
+            format!(
+                "{} is halted; order held until trading resumes",
+                order.ticker
+            )
+        } else {
+            format!(
+                "{} is under volatility interruption ({}); market orders are held",
+                order.ticker, status
+            )
+        })
+    }
+
+    /// 전략의 거래 비용 모델 설정 (`None`이면 기본 모델 사용).
+    pub async fn set_strategy_cost_model(
+        &self,
+        strategy_id: &str,
+        model: Option<TradingCostModel>,
+    ) {
+        let mut models = self.cost_models.write().await;
+        match model {
+            Some(model) => {
+                models.insert(strategy_id.to_string(), model);
+            }
+            None => {
+                models.remove(strategy_id);
+            }
+        }
+    }
+
+    /// 전략에 적용되는 거래 비용 모델 조회.
+    pub async fn strategy_cost_model(&self, strategy_id: Option<&str>) -> Option<TradingCostModel> {
+        let models = self.cost_models.read().await;
+        strategy_id
+            .and_then(|id| models.get(id))
+            .or(self.default_cost_model.as_ref())
+            .cloned()
+    }
+
+    /// 체결 거래 비용을 포지션 실현 손익, 전략 가상 계좌, 전략 자본 원장에 반영.
+    ///
+    /// 거래소가 호가 통화로 수수료를 보고하면 (`commission_asset` 없음)
+    /// 모델 수수료 대신 실제 수수료를 사용하고, 세금은 모델로 계산합니다.
+    /// 적용할 모델이 없으면 아무것도 차감하지 않습니다.
+    async fn charge_trading_cost(
+        &self,
+        order: &Order,
+        fill: &OrderFill,
+        position_id: Option<Uuid>,
+        strategy_position_id: Option<Uuid>,
+    ) -> Option<TradeCost> {
+        let model = self
+            .strategy_cost_model(order.strategy_id.as_deref())
+            .await?;
+
+        let multiplier = self
+            .futures_contract(&order.ticker)
+            .await
+            .map_or(Decimal::ONE, |c| c.multiplier);
+        let notional = fill.price * fill.quantity * multiplier;
+        let trailing_volume = {
+            let mut volume = self.trade_volume.write().await;
+            let trailing = volume.volume(fill.timestamp);
+            volume.record(fill.timestamp, notional);
+            trailing
+        };
+
+        let mut cost = model.trade_cost(
+            order.side,
+            notional,
+            Liquidity::from_order_type(order.order_type),
+            trailing_volume,
+        );
+        if let (Some(commission), None) = (fill.commission, fill.commission_asset.as_ref()) {
+            cost.commission = commission;
+        }
+
+        if let Some(position_id) = position_id {
+            self.position_tracker
+                .write()
+                .await
+                .charge_cost(position_id, cost.total());
+        }
+        if let Some(position_id) = strategy_position_id {
+            self.strategy_portfolios.write().await.charge_cost(
+                order.strategy_id.as_deref(),
+                position_id,
+                cost.total(),
+            );
+        }
+        if let Some(strategy_id) = order.strategy_id.as_deref() {
+            self.risk_manager
+                .write()
+                .await
+                .capital_ledger_mut()
+                .record_pnl(strategy_id, -cost.total());
+        }
+
+        debug!(
+            order_id = %order.id,
+            commission = %cost.commission,
+            tax = %cost.tax,
+            "Trading cost charged"
+        );
+        Some(cost)
+    }
+
+    /// 거래소로부터 주문 체결 처리 (브라켓 주문 포함).
+    ///
+    /// 기본 `handle_fill`에 추가로 브라켓 주문 관리를 수행합니다.
+    /// 메인 주문 체결 시 손절/익절 주문을 반환하고,
+    /// 손절/익절 체결 시 OCO 취소 대상을 반환합니다.
+    ///
+    /// # Returns
+    /// * `BracketFillResult` - 제출할 브라켓 주문 또는 취소할 주문 정보
+    ///
+    /// # 거래소 중립성
+    /// 반환된 주문은 호출자가 적절한 거래소 커넥터를 통해 제출/취소해야 합니다.
+    pub async fn handle_fill_with_brackets(
+        &self,
+        order_id: Uuid,
+        fill: OrderFill,
+        is_complete: bool,
+    ) -> Result<BracketFillResult, ExecutionError> {
+        // 기본 체결 처리
+        self.handle_fill(order_id, fill.clone(), is_complete)
+            .await?;
+
+        // 완전 체결이 아니면 브라켓 처리 안 함
+        if !is_complete {
+            return Ok(BracketFillResult::None);
+        }
+
+        // 브라켓 주문 확인
+        let mut bracket_manager = self.bracket_manager.write().await;
+
+        // 1. 메인 주문 체결인지 확인
+        if let Some((stop_loss, take_profit)) = bracket_manager.on_parent_filled(order_id) {
+            info!(
+                order_id = %order_id,
+                has_stop_loss = stop_loss.is_some(),
+                has_take_profit = take_profit.is_some(),
+                "메인 주문 체결, 브라켓 주문 제출 필요"
+            );
+            return Ok(BracketFillResult::SubmitBracket {
+                parent_order_id: order_id,
+                stop_loss,
+                take_profit,
+            });
+        }
+
+        // 2. 자식 주문(손절/익절) 체결인지 확인 -> OCO 처리
+        if let Some(cancel_order_id) = bracket_manager.on_child_filled(order_id) {
+            info!(
+                filled_order_id = %order_id,
+                cancel_order_id = %cancel_order_id,
+                "브라켓 주문 체결, OCO 취소 필요"
+            );
+            return Ok(BracketFillResult::CancelOther {
+                filled_order_id: order_id,
+                cancel_order_id,
+            });
+        }
+
+        Ok(BracketFillResult::None)
+    }
+
+    /// 브라켓 자식 주문 ID 등록.
+    ///
+    /// 손절/익절 주문을 거래소에 제출한 후 호출하여
+    /// 내부 주문 ID를 등록합니다.
+    pub async fn register_bracket_child(
+        &self,
+        parent_order_id: Uuid,
+        child_order_id: Uuid,
+        is_stop_loss: bool,
+    ) {
+        let mut bracket_manager = self.bracket_manager.write().await;
+        bracket_manager.register_child_order(parent_order_id, child_order_id, is_stop_loss);
+    }
+
+    /// 활성 브라켓 주문 수 조회.
     pub async fn active_bracket_count(&self) -> usize {
         let bracket_manager = self.bracket_manager.read().await;
         bracket_manager.active_count()
@@ -910,10 +2761,26 @@ impl OrderExecutor {
         order_id: Uuid,
         reason: Option<String>,
     ) -> Result<(), ExecutionError> {
-        let mut order_manager = self.order_manager.write().await;
-        order_manager
-            .cancel_order(order_id, reason)
-            .map_err(|e| ExecutionError::ExecutionFailed(e.to_string()))
+        {
+            let mut order_manager = self.order_manager.write().await;
+            order_manager
+                .cancel_order(order_id, reason)
+                .map_err(|e| ExecutionError::ExecutionFailed(e.to_string()))?;
+        }
+
+        self.order_circuit.dequeue(&self.exchange, order_id);
+        self.broker_downtime.dequeue(order_id);
+
+        // 미체결 수량에 배정된 전략 자본 반환
+        if let Some(r) = self.capital_reservations.write().await.remove(&order_id) {
+            self.risk_manager
+                .write()
+                .await
+                .capital_ledger_mut()
+                .release(&r.strategy_id, r.remaining_quantity * r.reference_price);
+        }
+
+        Ok(())
     }
 
     /// 모든 포지션의 시장 가격 업데이트.
@@ -923,6 +2790,8 @@ impl OrderExecutor {
     pub async fn update_market_prices(&self, prices: &std::collections::HashMap<String, Decimal>) {
         let mut position_tracker = self.position_tracker.write().await;
         position_tracker.update_prices(prices);
+        drop(position_tracker);
+        self.strategy_portfolios.write().await.update_prices(prices);
     }
 
     /// 모든 포지션의 총 미실현 손익 조회.
@@ -974,42 +2843,233 @@ impl OrderExecutor {
         &self.order_manager
     }
 
+    /// 리스크 관리자 참조 조회.
+    pub fn risk_manager(&self) -> &Arc<RwLock<RiskManager>> {
+        &self.risk_manager
+    }
+
+    /// 체결/포지션 변경 이벤트 구독.
+    pub fn subscribe_events(&self) -> broadcast::Receiver<ExecutionEvent> {
+        self.events.subscribe()
+    }
+
+    /// 주문 서킷 브레이커 접근.
+    pub fn order_circuit(&self) -> &Arc<OrderCircuitGuard> {
+        &self.order_circuit
+    }
+
+    /// 브로커 점검/장애 가드 접근.
+    pub fn broker_downtime(&self) -> &Arc<BrokerDowntimeGuard> {
+        &self.broker_downtime
+    }
+
+    /// 주문 의도(멱등성 키) 가드 접근.
+    pub fn order_intents(&self) -> &Arc<OrderIntentGuard> {
+        &self.order_intents
+    }
+
+    /// 주문 의도 영속 저장소 등록 (DB 기반 중복 제거).
+    pub async fn set_order_intent_store(&self, store: Arc<dyn OrderIntentStore>) {
+        self.order_intents.set_store(Some(store)).await;
+    }
+
     /// 포지션 추적기 참조 조회.
     pub fn position_tracker(&self) -> &Arc<RwLock<PositionTracker>> {
         &self.position_tracker
     }
 
+    /// 전략별 가상 계좌 참조 조회.
+    pub fn strategy_portfolios(&self) -> &Arc<RwLock<StrategyPortfolios>> {
+        &self.strategy_portfolios
+    }
+
+    /// 전략 가상 계좌 스냅샷 (자본 원장의 할당 자본 반영).
+    ///
+    /// 체결이 없었던 전략도 자본 원장에 계정이 있으면 스냅샷을 반환합니다.
+    pub async fn strategy_portfolio(&self, strategy_id: &str) -> Option<StrategyPortfolioSnapshot> {
+        let allocated = self
+            .risk_manager
+            .read()
+            .await
+            .capital_ledger()
+            .get(strategy_id)
+            .map(|account| account.allocated);
+
+        let mut portfolios = self.strategy_portfolios.write().await;
+        if let Some(allocated) = allocated {
+            portfolios.set_capital(strategy_id, allocated);
+        }
+        portfolios.snapshot(strategy_id)
+    }
+
+    /// 모든 전략 가상 계좌 스냅샷 (자본 원장의 할당 자본 반영).
+    pub async fn strategy_portfolio_snapshots(&self) -> Vec<StrategyPortfolioSnapshot> {
+        let allocations: Vec<(String, Decimal)> = self
+            .risk_manager
+            .read()
+            .await
+            .capital_ledger()
+            .accounts()
+            .into_iter()
+            .map(|account| (account.strategy_id.clone(), account.allocated))
+            .collect();
+
+        let mut portfolios = self.strategy_portfolios.write().await;
+        for (strategy_id, allocated) in &allocations {
+            portfolios.set_capital(strategy_id, *allocated);
+        }
+        portfolios.snapshots()
+    }
+
     /// 여러 신호 처리.
+    ///
+    /// 리밸런싱처럼 여러 신호를 한 번에 처리할 때는 진입 주문 전체의 필요 금액을 먼저
+    /// 합산합니다. 주문 가능 금액을 초과하면 진입 주문 수량을 같은 비율로 축소하여,
+    /// 뒤에 처리되는 주문만 거래소에서 잔고 부족으로 거부되지 않도록 합니다.
     pub async fn process_signals(
         &self,
         signals: &[Signal],
         prices: &std::collections::HashMap<String, Decimal>,
     ) -> Vec<ExecutionResult> {
-        let mut results = Vec::with_capacity(signals.len());
-
-        for signal in signals {
-            let symbol_str = signal.ticker.clone();
-            if let Some(&price) = prices.get(&symbol_str) {
-                results.push(self.process_signal(signal, price).await);
-            } else {
-                results.push(ExecutionResult::failure(
+        // 신호를 주문 요청으로 변환 (변환 실패 신호는 바로 결과 확정)
+        let mut results: Vec<Option<ExecutionResult>> = Vec::with_capacity(signals.len());
+        let mut planned: Vec<(usize, OrderRequest, bool, Decimal)> = Vec::new();
+        for (index, signal) in signals.iter().enumerate() {
+            if is_synthetic_ticker(&signal.ticker) {
+                results.push(Some(self.process_synthetic_signal(signal).await));
+                continue;
+            }
+            let Some(&price) = prices.get(&signal.ticker) else {
+                results.push(Some(ExecutionResult::failure(
                     signal.id,
-                    format!("No price data for symbol: {}", symbol_str),
-                ));
+                    format!("No price data for symbol: {}", signal.ticker),
+                )));
+                continue;
+            };
+            let quantity = self.signal_quantity(signal, price).await;
+            match self.converter.convert(signal, price, quantity) {
+                Ok(order) => {
+                    let is_entry = SignalConverter::is_entry_signal(&signal.signal_type);
+                    planned.push((index, order, is_entry, price));
+                    results.push(None);
+                }
+                Err(e) => results.push(Some(ExecutionResult::failure(signal.id, e.to_string()))),
             }
         }
 
-        results
-    }
+        // 진입 주문 전체의 매수 여력 예측
+        let entries: Vec<(OrderRequest, Decimal)> = planned
+            .iter()
+            .filter(|(_, _, is_entry, _)| *is_entry)
+            .map(|(_, order, _, price)| (order.clone(), *price))
+            .collect();
+        let forecast = self.forecast_funding(&entries).await;
+        if forecast.is_scaled() {
+            warn!(
+                required = %forecast.required,
+                available = %forecast.available,
+                scale = %forecast.scale,
+                "Batch entry orders exceed buying power; scaling proportionally"
+            );
+        }
 
-    /// 리스크 관리자 잔액 업데이트.
-    pub async fn update_balance(&self, balance: Decimal) {
-        let mut rm = self.risk_manager.write().await;
-        rm.update_balance(balance);
+        for (index, mut order, is_entry, price) in planned {
+            let signal_id = signals[index].id;
+            let mut funding_note = None;
+            if is_entry && forecast.is_scaled() {
+                let quantity = forecast.scale_quantity(&order.ticker, order.quantity);
+                if quantity <= Decimal::ZERO {
+                    results[index] = Some(ExecutionResult::failure(
+                        signal_id,
+                        format!(
+                            "Insufficient buying power for batch: required {}, available {}",
+                            forecast.required, forecast.available
+                        ),
+                    ));
+                    continue;
+                }
+                funding_note = Some(format!(
+                    "Quantity scaled from {} to {} by batch buying power (required {}, available {})",
+                    order.quantity, quantity, forecast.required, forecast.available
+                ));
+                order.quantity = quantity;
+            }
+
+            let mut result = self
+                .execute_order_request(signal_id, order, is_entry, price)
+                .await;
+            if let Some(note) = funding_note {
+                result.notes.insert(0, note);
+            }
+            results[index] = Some(result);
+        }
+
+        results.into_iter().flatten().collect()
     }
 
-    /// 거래 손익 기록.
-    pub async fn record_pnl(&self, symbol: &str, amount: Decimal) {
+    /// 진입 주문 묶음의 매수 여력 예측.
+    ///
+    /// 주문별 필요 금액은 `preview_order()`의 매수 여력 계산과 같은 기준
+    /// (매수 대금 또는 신규 숏 증거금 + 예상 비용)으로 계산하며, 주문 가능 금액에서
+    /// 미체결 지정가 매수 주문 금액을 차감합니다.
+    ///
+    /// # 인자
+    /// * `orders` - 진입 주문 요청과 현재 가격 목록
+    pub async fn forecast_funding(&self, orders: &[(OrderRequest, Decimal)]) -> FundingForecast {
+        let positions: Vec<Position> = {
+            let tracker = self.position_tracker.read().await;
+            tracker.get_open_positions().into_iter().cloned().collect()
+        };
+        let balance = self.risk_manager.read().await.balance();
+        let committed: Decimal = self
+            .get_active_orders()
+            .await
+            .iter()
+            .filter(|o| o.side == Side::Buy)
+            .filter_map(|o| o.price.map(|price| price * o.remaining_quantity()))
+            .sum();
+
+        let mut requirements = Vec::with_capacity(orders.len());
+        for (order, current_price) in orders {
+            let reference_price = order.price.unwrap_or(*current_price);
+            let notional = order.quantity * reference_price;
+            let cost = match self.strategy_cost_model(order.strategy_id.as_deref()).await {
+                Some(model) => {
+                    let trailing_volume = self.trade_volume.write().await.volume(Utc::now());
+                    model
+                        .trade_cost(
+                            order.side,
+                            notional,
+                            Liquidity::from_order_type(order.order_type),
+                            trailing_volume,
+                        )
+                        .total()
+                }
+                None => Decimal::ZERO,
+            };
+            let buying_power = BuyingPower::compute(
+                balance,
+                committed,
+                net_position_quantity(positions.iter(), &order.ticker),
+                order.side,
+                order.quantity,
+                reference_price,
+                cost,
+            );
+            requirements.push(buying_power.required);
+        }
+
+        FundingForecast::compute(balance - committed, &requirements)
+    }
+
+    /// 리스크 관리자 잔액 업데이트.
+    pub async fn update_balance(&self, balance: Decimal) {
+        let mut rm = self.risk_manager.write().await;
+        rm.update_balance(balance);
+    }
+
+    /// 거래 손익 기록.
+    pub async fn record_pnl(&self, symbol: &str, amount: Decimal) {
         let mut rm = self.risk_manager.write().await;
         rm.record_pnl(symbol, amount);
     }
@@ -1035,7 +3095,6 @@ impl OrderExecutor {
 mod tests {
     use super::*;
     use rust_decimal::prelude::FromPrimitive;
-    use trader_core::Symbol;
     use trader_risk::RiskConfig;
 
     /// 정수로부터 Decimal을 생성하는 헬퍼 매크로
@@ -1053,10 +3112,18 @@ mod tests {
         let config = RiskConfig::default();
         let risk_manager = RiskManager::new(config, dec!(10000));
 
-        let mut exec_config = ConversionConfig::default();
-        exec_config.default_quantity = default_quantity;
+        let exec_config = ConversionConfig {
+            default_quantity,
+            ..Default::default()
+        };
 
-        OrderExecutor::new_complete(risk_manager, "test_exchange", exec_config)
+        // 실행 시각에 따라 결과가 달라지지 않도록 커퓨는 비활성화
+        OrderExecutor::new_complete(risk_manager, "test_exchange", exec_config).with_curfew(
+            CurfewConfig {
+                enabled: false,
+                ..Default::default()
+            },
+        )
     }
 
     #[test]
@@ -1076,9 +3143,11 @@ mod tests {
 
     #[test]
     fn test_signal_converter_limit_order() {
-        let mut config = ConversionConfig::default();
-        config.use_market_orders = false;
-        config.slippage_tolerance_pct = 0.1;
+        let config = ConversionConfig {
+            use_market_orders: false,
+            slippage_tolerance_pct: 0.1,
+            ..Default::default()
+        };
 
         let converter = SignalConverter::new(config);
         let signal = create_test_signal(Side::Buy, SignalType::Entry);
@@ -1102,222 +3171,1218 @@ mod tests {
             .convert(&signal, dec!(50000), Some(dec!(0.1)))
             .unwrap();
 
-        assert_eq!(order.side, Side::Sell);
-        assert_eq!(order.order_type, OrderType::Market);
+        assert_eq!(order.side, Side::Sell);
+        assert_eq!(order.order_type, OrderType::Market);
+    }
+
+    #[test]
+    fn test_signal_converter_scale_order() {
+        let converter = SignalConverter::default_config();
+        let signal = create_test_signal(Side::Sell, SignalType::Scale);
+
+        let order = converter
+            .convert(&signal, dec!(50000), Some(dec!(0.1)))
+            .unwrap();
+
+        assert_eq!(order.side, Side::Sell);
+        assert_eq!(order.order_type, OrderType::Market);
+    }
+
+    #[test]
+    fn test_signal_converter_low_strength_rejected() {
+        let config = ConversionConfig {
+            min_strength: 0.7,
+            ..Default::default()
+        };
+
+        let converter = SignalConverter::new(config);
+        let mut signal = create_test_signal(Side::Buy, SignalType::Entry);
+        signal = signal.with_strength(0.5); // 임계값 미만
+
+        let result = converter.convert(&signal, dec!(50000), Some(dec!(0.1)));
+
+        assert!(result.is_err());
+        assert!(matches!(
+            result.unwrap_err(),
+            ExecutionError::InvalidSignal(_)
+        ));
+    }
+
+    #[test]
+    fn test_signal_converter_zero_quantity_rejected() {
+        let converter = SignalConverter::default_config();
+        let signal = create_test_signal(Side::Buy, SignalType::Entry);
+
+        let result = converter.convert(&signal, dec!(50000), Some(Decimal::ZERO));
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_execution_result_builder() {
+        let signal_id = Uuid::new_v4();
+        let order = OrderRequest::market_buy("BTC/USDT".to_string(), dec!(0.1));
+        let order_id = Uuid::new_v4();
+
+        let result = ExecutionResult::success(signal_id, order)
+            .with_order_id(order_id)
+            .with_note("Test note")
+            .with_stop_loss(OrderRequest::market_sell("BTC/USDT".to_string(), dec!(0.1)));
+
+        assert!(result.success);
+        assert!(result.order.is_some());
+        assert!(result.order_id.is_some());
+        assert!(result.stop_loss.is_some());
+        assert!(!result.notes.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_order_executor_process_signal() {
+        let executor = create_test_executor(dec!(0.01));
+        let signal = create_test_signal(Side::Buy, SignalType::Entry);
+
+        let result = executor.process_signal(&signal, dec!(50000)).await;
+
+        assert!(result.success);
+        assert!(result.order.is_some());
+        assert!(result.order_id.is_some()); // 주문이 등록되어야 함
+
+        let order = result.order.unwrap();
+        assert_eq!(order.side, Side::Buy);
+        assert_eq!(order.quantity, dec!(0.01));
+    }
+
+    #[tokio::test]
+    async fn test_order_executor_auto_bracket_orders() {
+        let config = RiskConfig::default();
+        let risk_manager = RiskManager::new(config, dec!(10000));
+
+        let exec_config = ConversionConfig {
+            default_quantity: dec!(0.01),
+            auto_stop_loss: true,
+            auto_take_profit: true,
+            ..Default::default()
+        };
+
+        let executor = OrderExecutor::new_complete(risk_manager, "test", exec_config);
+        let signal = create_test_signal(Side::Buy, SignalType::Entry);
+
+        let result = executor.process_signal(&signal, dec!(50000)).await;
+
+        assert!(result.success);
+        assert!(result.stop_loss.is_some());
+        assert!(result.take_profit.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_order_executor_risk_check_failure() {
+        // 포지션 한도를 초과하는 큰 기본 수량 ($10000의 10% = $1000)
+        let executor = create_test_executor(dec!(1.0)); // $50000 상당, 10% 한도 초과
+
+        // 과다 매수 시도 (포지션 한도 초과)
+        let signal = create_test_signal(Side::Buy, SignalType::Entry);
+
+        let result = executor.process_signal(&signal, dec!(50000)).await;
+
+        assert!(!result.success);
+        assert!(result.error.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_order_executor_order_tracking() {
+        let executor = create_test_executor(dec!(0.01));
+        let signal = create_test_signal(Side::Buy, SignalType::Entry);
+
+        let result = executor.process_signal(&signal, dec!(50000)).await;
+        assert!(result.success);
+
+        let order_id = result.order_id.unwrap();
+
+        // 주문이 추적 가능해야 함
+        let order = executor.get_order(order_id).await;
+        assert!(order.is_some());
+
+        let order = order.unwrap();
+        assert_eq!(order.status, OrderStatusType::Pending);
+    }
+
+    #[tokio::test]
+    async fn test_order_executor_submit_and_fill() {
+        let executor = create_test_executor(dec!(0.01));
+        let signal = create_test_signal(Side::Buy, SignalType::Entry);
+
+        // 신호 처리
+        let result = executor.process_signal(&signal, dec!(50000)).await;
+        assert!(result.success);
+
+        let order_id = result.order_id.unwrap();
+
+        // 주문 제출
+        executor
+            .submit_order(order_id, "EX123".to_string())
+            .await
+            .unwrap();
+
+        // 주문이 이제 Open 상태인지 확인
+        let order = executor.get_order(order_id).await.unwrap();
+        assert_eq!(order.status, OrderStatusType::Open);
+        assert_eq!(order.exchange_order_id, Some("EX123".to_string()));
+
+        // 체결 처리
+        let fill = OrderFill {
+            order_id,
+            quantity: dec!(0.01),
+            price: dec!(50000),
+            commission: Some(dec!(0.5)),
+            commission_asset: None,
+            timestamp: chrono::Utc::now(),
+        };
+
+        executor.handle_fill(order_id, fill, true).await.unwrap();
+
+        // 주문이 Filled 상태인지 확인
+        let order = executor.get_order(order_id).await.unwrap();
+        assert_eq!(order.status, OrderStatusType::Filled);
+
+        // 포지션이 생성되어야 함
+        let position = executor.get_position("BTC/USDT").await;
+        assert!(position.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_handle_exchange_fill_by_exchange_order_id() {
+        let executor = create_test_executor(dec!(0.01));
+        let signal = create_test_signal(Side::Buy, SignalType::Entry);
+        let order_id = executor
+            .process_signal(&signal, dec!(50000))
+            .await
+            .order_id
+            .unwrap();
+        executor
+            .submit_order(order_id, "EX123".to_string())
+            .await
+            .unwrap();
+
+        let report = |exchange_order_id: &str, quantity| FillReport {
+            exchange_order_id: exchange_order_id.to_string(),
+            ticker: "BTC/USDT".to_string(),
+            side: Side::Buy,
+            quantity,
+            price: dec!(50000),
+            timestamp: chrono::Utc::now(),
+        };
+
+        // 알 수 없는 거래소 주문은 무시
+        let handled = executor
+            .handle_exchange_fill(&report("OTHER", dec!(0.01)))
+            .await
+            .unwrap();
+        assert!(handled.is_none());
+
+        // 부분 체결 후 잔량 체결
+        let handled = executor
+            .handle_exchange_fill(&report("EX123", dec!(0.004)))
+            .await
+            .unwrap();
+        assert_eq!(handled, Some(order_id));
+        let order = executor.get_order(order_id).await.unwrap();
+        assert_eq!(order.status, OrderStatusType::PartiallyFilled);
+
+        executor
+            .handle_exchange_fill(&report("EX123", dec!(0.006)))
+            .await
+            .unwrap();
+        let order = executor.get_order(order_id).await.unwrap();
+        assert_eq!(order.status, OrderStatusType::Filled);
+        assert_eq!(order.filled_quantity, dec!(0.01));
+
+        // 종료된 주문에 대한 중복 통보는 무시
+        let handled = executor
+            .handle_exchange_fill(&report("EX123", dec!(0.01)))
+            .await
+            .unwrap();
+        assert!(handled.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_order_executor_cancel_order() {
+        let executor = create_test_executor(dec!(0.01));
+        let signal = create_test_signal(Side::Buy, SignalType::Entry);
+
+        let result = executor.process_signal(&signal, dec!(50000)).await;
+        let order_id = result.order_id.unwrap();
+
+        // 주문 제출
+        executor
+            .submit_order(order_id, "EX123".to_string())
+            .await
+            .unwrap();
+
+        // 주문 취소
+        executor
+            .cancel_order(order_id, Some("User requested".to_string()))
+            .await
+            .unwrap();
+
+        // 주문이 Cancelled 상태인지 확인
+        let order = executor.get_order(order_id).await.unwrap();
+        assert_eq!(order.status, OrderStatusType::Cancelled);
+    }
+
+    #[tokio::test]
+    async fn test_order_executor_active_orders() {
+        let executor = create_test_executor(dec!(0.01));
+
+        // 여러 주문 생성
+        for _ in 0..3 {
+            let signal = create_test_signal(Side::Buy, SignalType::Entry);
+            let result = executor.process_signal(&signal, dec!(50000)).await;
+            let order_id = result.order_id.unwrap();
+            executor
+                .submit_order(order_id, format!("EX{}", order_id))
+                .await
+                .unwrap();
+        }
+
+        let active_orders = executor.get_active_orders().await;
+        assert_eq!(active_orders.len(), 3);
+    }
+
+    async fn allocate_strategy(executor: &OrderExecutor, amount: Decimal) {
+        executor
+            .risk_manager()
+            .write()
+            .await
+            .capital_ledger_mut()
+            .allocate("test_strategy", amount)
+            .unwrap();
+    }
+
+    async fn strategy_capital(executor: &OrderExecutor) -> trader_risk::StrategyCapital {
+        executor
+            .risk_manager()
+            .read()
+            .await
+            .capital_ledger()
+            .get("test_strategy")
+            .cloned()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_strategy_budget_scales_and_rejects_entries() {
+        let executor = create_test_executor(dec!(5));
+        allocate_strategy(&executor, dec!(300)).await;
+
+        // 5주 × 100 = 500 > 예산 300 → 3주로 축소
+        let signal = create_test_signal(Side::Buy, SignalType::Entry);
+        let result = executor.process_signal(&signal, dec!(100)).await;
+        assert!(result.success);
+        assert_eq!(result.order.as_ref().unwrap().quantity, dec!(3));
+        assert!(result.notes.iter().any(|n| n.contains("strategy budget")));
+        assert_eq!(strategy_capital(&executor).await.in_use, dec!(300));
+
+        // 예산 소진 → 거부
+        let signal = create_test_signal(Side::Buy, SignalType::Entry);
+        let result = executor.process_signal(&signal, dec!(100)).await;
+        assert!(!result.success);
+    }
+
+    #[tokio::test]
+    async fn test_strategy_budget_released_on_cancel() {
+        let executor = create_test_executor(dec!(2));
+        allocate_strategy(&executor, dec!(1000)).await;
+
+        let signal = create_test_signal(Side::Buy, SignalType::Entry);
+        let order_id = executor
+            .process_signal(&signal, dec!(100))
+            .await
+            .order_id
+            .unwrap();
+        assert_eq!(strategy_capital(&executor).await.in_use, dec!(200));
+
+        executor.cancel_order(order_id, None).await.unwrap();
+        assert_eq!(strategy_capital(&executor).await.in_use, dec!(0));
+    }
+
+    #[tokio::test]
+    async fn test_strategy_capital_settled_on_fills() {
+        let executor = create_test_executor(dec!(3));
+        allocate_strategy(&executor, dec!(1000)).await;
+
+        // 진입: 현재가 100 기준 배정 → 체결가 110으로 전환
+        let entry = create_test_signal(Side::Buy, SignalType::Entry);
+        let entry_id = executor
+            .process_signal(&entry, dec!(100))
+            .await
+            .order_id
+            .unwrap();
+        let fill = OrderFill {
+            order_id: entry_id,
+            quantity: dec!(3),
+            price: dec!(110),
+            commission: None,
+            commission_asset: None,
+            timestamp: chrono::Utc::now(),
+        };
+        executor.handle_fill(entry_id, fill, true).await.unwrap();
+        assert_eq!(strategy_capital(&executor).await.in_use, dec!(330));
+
+        // 청산: 진입 원가 반환 + 실현 손익 기록
+        let exit = create_test_signal(Side::Sell, SignalType::Exit);
+        let exit_id = executor
+            .process_signal(&exit, dec!(120))
+            .await
+            .order_id
+            .unwrap();
+        let fill = OrderFill {
+            order_id: exit_id,
+            quantity: dec!(3),
+            price: dec!(120),
+            commission: None,
+            commission_asset: None,
+            timestamp: chrono::Utc::now(),
+        };
+        executor.handle_fill(exit_id, fill, true).await.unwrap();
+
+        let capital = strategy_capital(&executor).await;
+        assert_eq!(capital.in_use, dec!(0));
+        assert_eq!(capital.realized_pnl, dec!(30));
+        assert_eq!(capital.available(), dec!(1030));
+    }
+
+    #[tokio::test]
+    async fn test_futures_budget_uses_initial_margin() {
+        let risk_manager = RiskManager::new(RiskConfig::default(), dec!(100000000));
+        let exec_config = ConversionConfig {
+            default_quantity: dec!(5),
+            ..Default::default()
+        };
+        let executor = OrderExecutor::new_complete(risk_manager, "test_exchange", exec_config)
+            .with_curfew(CurfewConfig {
+                enabled: false,
+                ..Default::default()
+            });
+        allocate_strategy(&executor, dec!(20000000)).await;
+
+        // 계약당 위탁증거금 360 × 25만 × 9% = 810만 → 예산 2천만으로 2계약
+        let entry = Signal::new(
+            "test_strategy",
+            "101W9000".to_string(),
+            Side::Buy,
+            SignalType::Entry,
+        );
+        let result = executor.process_signal(&entry, dec!(360)).await;
+        assert!(result.success);
+        let entry_id = result.order_id.unwrap();
+        assert_eq!(result.order.as_ref().unwrap().quantity, dec!(2));
+        assert_eq!(strategy_capital(&executor).await.in_use, dec!(16200000));
+
+        let fill = OrderFill {
+            order_id: entry_id,
+            quantity: dec!(2),
+            price: dec!(360),
+            commission: None,
+            commission_asset: None,
+            timestamp: chrono::Utc::now(),
+        };
+        executor.handle_fill(entry_id, fill, true).await.unwrap();
+        let tracker = executor.position_tracker().read().await;
+        assert_eq!(tracker.cash(), Decimal::ZERO);
+        drop(tracker);
+
+        // 청산: 2pt × 2계약 × 25만 = 100만
+        let exit = Signal::new(
+            "test_strategy",
+            "101W9000".to_string(),
+            Side::Sell,
+            SignalType::Exit,
+        );
+        let exit_id = executor
+            .process_signal(&exit, dec!(362))
+            .await
+            .order_id
+            .unwrap();
+        let fill = OrderFill {
+            order_id: exit_id,
+            quantity: dec!(2),
+            price: dec!(362),
+            commission: None,
+            commission_asset: None,
+            timestamp: chrono::Utc::now(),
+        };
+        executor.handle_fill(exit_id, fill, true).await.unwrap();
+
+        let capital = strategy_capital(&executor).await;
+        assert_eq!(capital.in_use, dec!(0));
+        assert_eq!(capital.realized_pnl, dec!(1000000));
+        assert_eq!(executor.get_realized_pnl().await, dec!(1000000));
+    }
+
+    #[tokio::test]
+    async fn test_preview_order_does_not_register() {
+        let executor = create_test_executor(dec!(1));
+        let request = OrderRequest {
+            ticker: "BTC/USDT".to_string(),
+            side: Side::Buy,
+            order_type: OrderType::Limit,
+            quantity: dec!(3),
+            price: Some(dec!(100.3)),
+            stop_price: None,
+            time_in_force: TimeInForce::GTC,
+            client_order_id: None,
+            strategy_id: None,
+        };
+        let krx = trader_core::KrxTickSize::new();
+
+        let preview = executor
+            .preview_order(
+                &request,
+                dec!(100),
+                Some(&krx),
+                Some(TradingCostModel::kr_kospi()),
+            )
+            .await;
+        assert!(preview.accepted, "{:?}", preview.rejections);
+        // 매수 지정가는 호가 단위로 내림
+        assert_eq!(preview.orders[0].order.price, Some(dec!(100)));
+        assert_eq!(preview.price_adjustments[0].original, dec!(100.3));
+        assert_eq!(preview.notional, dec!(300));
+        assert_eq!(preview.estimated_cost.unwrap().total(), dec!(0.045));
+        assert_eq!(preview.buying_power.required, dec!(300.045));
+        let roles: Vec<_> = preview.orders.iter().map(|o| o.role).collect();
+        assert_eq!(
+            roles,
+            vec![
+                PreviewOrderRole::Entry,
+                PreviewOrderRole::StopLoss,
+                PreviewOrderRole::TakeProfit
+            ]
+        );
+
+        // 포지션 한도 초과 → 거부 및 조정 주문 제안
+        let oversized = OrderRequest {
+            quantity: dec!(50),
+            ..request
+        };
+        let preview = executor
+            .preview_order(&oversized, dec!(100), None, None)
+            .await;
+        assert!(!preview.accepted);
+        assert!(preview.suggested_order.is_some());
+        assert!(preview.estimated_cost.is_none());
+
+        // 미리보기는 주문을 등록하지 않음
+        assert!(executor.get_active_orders().await.is_empty());
     }
 
-    #[test]
-    fn test_signal_converter_scale_order() {
-        let converter = SignalConverter::default_config();
-        let signal = create_test_signal(Side::Sell, SignalType::Scale);
+    #[tokio::test]
+    async fn test_process_signals_scales_batch_to_buying_power() {
+        let config = RiskConfig {
+            max_position_pct: 100.0,
+            max_total_exposure_pct: 100.0,
+            ..RiskConfig::default()
+        };
+        let exec_config = ConversionConfig {
+            default_quantity: dec!(1),
+            ..ConversionConfig::default()
+        };
+        let executor = OrderExecutor::new_complete(
+            RiskManager::new(config, dec!(10000)),
+            "test_exchange",
+            exec_config,
+        );
 
-        let order = converter
-            .convert(&signal, dec!(50000), Some(dec!(0.1)))
-            .unwrap();
+        // 필요 금액 20000 > 잔고 10000 → 진입 주문 전체 50% 축소
+        let mut signals = Vec::new();
+        let mut prices = HashMap::new();
+        for (ticker, price) in [("AAA/USDT", 6000), ("BBB/USDT", 6000), ("CCC/USDT", 8000)] {
+            signals.push(
+                Signal::new(
+                    "test_strategy",
+                    ticker.to_string(),
+                    Side::Buy,
+                    SignalType::Entry,
+                )
+                .with_strength(0.8)
+                .with_prices(Some(dec!(price)), None, None),
+            );
+            prices.insert(ticker.to_string(), dec!(price));
+        }
 
-        assert_eq!(order.side, Side::Sell);
-        assert_eq!(order.order_type, OrderType::Market);
-    }
+        let results = executor.process_signals(&signals, &prices).await;
+        assert_eq!(results.len(), 3);
+        for result in &results {
+            assert!(result.success, "{:?}", result.error);
+            assert_eq!(result.order.as_ref().unwrap().quantity, dec!(0.5));
+            assert!(result.notes[0].contains("batch buying power"));
+        }
 
-    #[test]
-    fn test_signal_converter_low_strength_rejected() {
-        let mut config = ConversionConfig::default();
-        config.min_strength = 0.7;
+        // 잔고 내 신호는 축소하지 않음
+        let forecast = executor
+            .forecast_funding(&[(results[0].order.clone().unwrap(), dec!(6000))])
+            .await;
+        assert!(!forecast.is_scaled());
+    }
 
-        let converter = SignalConverter::new(config);
-        let mut signal = create_test_signal(Side::Buy, SignalType::Entry);
-        signal = signal.with_strength(0.5); // 임계값 미만
+    #[tokio::test]
+    async fn test_process_order_request() {
+        let executor = create_test_executor(dec!(1));
+        let request_id = Uuid::new_v4();
+        let request = OrderRequest {
+            ticker: "BTC/USDT".to_string(),
+            side: Side::Buy,
+            order_type: OrderType::Limit,
+            quantity: dec!(2),
+            price: Some(dec!(100)),
+            stop_price: None,
+            time_in_force: TimeInForce::GTC,
+            client_order_id: Some(format!("cond_{}", request_id)),
+            strategy_id: None,
+        };
 
-        let result = converter.convert(&signal, dec!(50000), Some(dec!(0.1)));
+        let result = executor
+            .process_order_request(request_id, request.clone(), dec!(100))
+            .await;
+        assert!(result.success, "{:?}", result.error);
+        assert_eq!(result.signal_id, request_id);
+        assert_eq!(result.order.as_ref().unwrap().quantity, dec!(2));
+        // 포지션이 없으므로 진입 주문 → 브라켓 생성
+        assert!(result.stop_loss.is_some());
+        assert_eq!(executor.get_active_orders().await.len(), 1);
 
-        assert!(result.is_err());
-        assert!(matches!(
-            result.unwrap_err(),
-            ExecutionError::InvalidSignal(_)
-        ));
+        let zero = OrderRequest {
+            quantity: Decimal::ZERO,
+            ..request
+        };
+        let result = executor
+            .process_order_request(request_id, zero, dec!(100))
+            .await;
+        assert!(!result.success);
     }
 
-    #[test]
-    fn test_signal_converter_zero_quantity_rejected() {
-        let converter = SignalConverter::default_config();
-        let signal = create_test_signal(Side::Buy, SignalType::Entry);
+    #[tokio::test]
+    async fn test_trading_status_holds_orders() {
+        let executor = create_test_executor(dec!(1));
+        let limit = OrderRequest::limit_buy("005930".to_string(), dec!(1), dec!(100));
+        let market = OrderRequest::market_buy("005930".to_string(), dec!(1));
 
-        let result = converter.convert(&signal, dec!(50000), Some(Decimal::ZERO));
+        // VI: 시장가 보류, 지정가 허용
+        executor
+            .set_trading_status(&TradingStatusEvent::new("005930", TradingStatus::DynamicVi))
+            .await;
+        let result = executor
+            .process_order_request(Uuid::new_v4(), market.clone(), dec!(100))
+            .await;
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("volatility interruption"));
+        let preview = executor.preview_order(&limit, dec!(100), None, None).await;
+        assert!(preview.accepted, "{:?}", preview.rejections);
 
-        assert!(result.is_err());
+        // 거래정지: 모든 주문 보류
+        executor
+            .set_trading_status(&TradingStatusEvent::new("005930", TradingStatus::Halted))
+            .await;
+        let preview = executor.preview_order(&limit, dec!(100), None, None).await;
+        assert!(!preview.accepted);
+        assert!(preview.rejections[0].contains("halted"));
+
+        // 거래 재개
+        executor
+            .set_trading_status(&TradingStatusEvent::new("005930", TradingStatus::Normal))
+            .await;
+        let result = executor
+            .process_order_request(Uuid::new_v4(), market, dec!(100))
+            .await;
+        assert!(result.success, "{:?}", result.error);
     }
 
-    #[test]
-    fn test_execution_result_builder() {
-        let signal_id = Uuid::new_v4();
-        let order = OrderRequest::market_buy("BTC/USDT".to_string(), dec!(0.1));
-        let order_id = Uuid::new_v4();
+    #[tokio::test]
+    async fn test_liquidity_cap_limits_entry_quantity() {
+        use trader_core::types::MarketType;
+
+        let executor = create_test_executor(dec!(1));
+        let snapshot = |volume| LiquiditySnapshot {
+            market_type: MarketType::KrStock,
+            avg_daily_volume: volume,
+            avg_daily_amount: dec!(1_000_000),
+            spread_pct: None,
+            as_of: Utc::now(),
+        };
 
-        let result = ExecutionResult::success(signal_id, order)
-            .with_order_id(order_id)
-            .with_note("Test note")
-            .with_stop_loss(OrderRequest::market_sell("BTC/USDT".to_string(), dec!(0.1)));
+        // 거래대금 기준 미달 → ADV 2000주의 0.5% = 10주로 축소
+        executor
+            .set_liquidity_snapshot("123456", snapshot(dec!(2000)))
+            .await;
+        let order = OrderRequest::market_buy("123456".to_string(), dec!(50));
+        let result = executor
+            .process_order_request(Uuid::new_v4(), order.clone(), dec!(10))
+            .await;
+        assert!(result.success, "{:?}", result.error);
+        assert_eq!(result.order.as_ref().unwrap().quantity, dec!(10));
+        assert!(result.notes.iter().any(|n| n.contains("capped")));
+
+        let mut signal = Signal::new(
+            "test_strategy",
+            "123456".to_string(),
+            Side::Buy,
+            SignalType::Entry,
+        );
+        result.apply_metadata(&mut signal);
+        assert_eq!(signal.metadata["liquidity_cap"]["level"], "fail");
 
-        assert!(result.success);
-        assert!(result.order.is_some());
-        assert!(result.order_id.is_some());
-        assert!(result.stop_loss.is_some());
-        assert!(!result.notes.is_empty());
+        // 상한이 1주 미만이면 사유와 결정을 남기고 거부
+        executor
+            .set_liquidity_snapshot("123456", snapshot(dec!(100)))
+            .await;
+        let result = executor
+            .process_order_request(Uuid::new_v4(), order, dec!(10))
+            .await;
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("Liquidity cap"));
+        assert!(result.metadata.contains_key("liquidity_cap"));
     }
 
     #[tokio::test]
-    async fn test_order_executor_process_signal() {
-        let executor = create_test_executor(dec!(0.01));
-        let signal = create_test_signal(Side::Buy, SignalType::Entry);
+    async fn test_execution_governor_throttles_and_pauses_entries() {
+        use crate::execution_governor::GovernorRegime;
+        use trader_core::types::MarketType;
+
+        let executor = create_test_executor(dec!(1));
+        let condition = |volatility_ratio, spread_bps| MarketCondition {
+            market_type: MarketType::KrStock,
+            volatility_ratio,
+            spread_bps,
+            as_of: Utc::now(),
+        };
 
-        let result = executor.process_signal(&signal, dec!(50000)).await;
+        // 스프레드 확대 (경계) → 수량 50%, 지정가는 현재가 -20bp 이하로
+        executor
+            .set_market_condition("005930", condition(None, Some(dec!(50))))
+            .await;
+        let mut order = OrderRequest::limit_buy("005930".to_string(), dec!(10), dec!(50));
+        order.strategy_id = Some("governed".to_string());
+        let result = executor
+            .process_order_request(Uuid::new_v4(), order.clone(), dec!(50))
+            .await;
+        assert!(result.success, "{:?}", result.error);
+        let placed = result.order.as_ref().unwrap();
+        assert_eq!(placed.quantity, dec!(5));
+        assert_eq!(placed.price, Some(dec!(49.9)));
+        assert!(result.notes.iter().any(|n| n.contains("throttled")));
+        assert_eq!(
+            result.metadata["execution_governor"]["regime"],
+            serde_json::json!(GovernorRegime::Elevated)
+        );
 
-        assert!(result.success);
-        assert!(result.order.is_some());
-        assert!(result.order_id.is_some()); // 주문이 등록되어야 함
+        // 변동성 급등 (위험) → 진입 보류
+        executor
+            .set_market_condition("005930", condition(Some(dec!(3.5)), None))
+            .await;
+        let result = executor
+            .process_order_request(Uuid::new_v4(), order.clone(), dec!(50))
+            .await;
+        assert!(!result.success);
+        assert!(result
+            .error
+            .unwrap()
+            .contains("paused by execution governor"));
+        assert!(result.metadata.contains_key("execution_governor"));
 
-        let order = result.order.unwrap();
-        assert_eq!(order.side, Side::Buy);
-        assert_eq!(order.quantity, dec!(0.01));
+        // 조절을 끈 전략은 그대로 실행
+        executor
+            .set_strategy_governor_opt_out("governed", true)
+            .await;
+        let result = executor
+            .process_order_request(Uuid::new_v4(), order, dec!(50))
+            .await;
+        assert!(result.success, "{:?}", result.error);
+        assert_eq!(result.order.as_ref().unwrap().quantity, dec!(10));
+        assert!(!result.metadata.contains_key("execution_governor"));
     }
 
     #[tokio::test]
-    async fn test_order_executor_auto_bracket_orders() {
-        let config = RiskConfig::default();
-        let risk_manager = RiskManager::new(config, dec!(10000));
+    async fn test_risk_profile_sizing_and_throttles() {
+        use crate::risk_profile::RiskProfileName;
 
-        let mut exec_config = ConversionConfig::default();
-        exec_config.default_quantity = dec!(0.01);
-        exec_config.auto_stop_loss = true;
-        exec_config.auto_take_profit = true;
-
-        let executor = OrderExecutor::new_complete(risk_manager, "test", exec_config);
+        let executor = create_test_executor(dec!(0.005));
         let signal = create_test_signal(Side::Buy, SignalType::Entry);
 
+        // 프로필이 없으면 기본 수량
         let result = executor.process_signal(&signal, dec!(50000)).await;
+        assert!(result.success, "{:?}", result.error);
+        assert_eq!(result.order.as_ref().unwrap().quantity, dec!(0.005));
 
-        assert!(result.success);
-        assert!(result.stop_loss.is_some());
-        assert!(result.take_profit.is_some());
+        // 보수적 프로필: 잔고 10,000의 0.5% 위험 / 손절폭 1.5% → 3,333, 최대 포지션 5% (500) 제한
+        let conservative = RiskProfile::preset(RiskProfileName::Conservative);
+        executor
+            .set_strategy_risk_profile("test_strategy", Some(conservative.clone()))
+            .await;
+        let signal = create_test_signal(Side::Buy, SignalType::Entry);
+        let result = executor.process_signal(&signal, dec!(50000)).await;
+        assert!(result.success, "{:?}", result.error);
+        assert_eq!(result.order.as_ref().unwrap().quantity, dec!(0.01));
+
+        // 전략 프로필이 전역 프로필보다 우선
+        executor
+            .set_risk_profile(Some(RiskProfile::preset(RiskProfileName::Aggressive)))
+            .await;
+        assert_eq!(
+            executor
+                .throttles_for(Some("test_strategy"))
+                .await
+                .liquidity_cap
+                .max_adv_fraction,
+            conservative.throttles.liquidity_cap.max_adv_fraction
+        );
+        assert_eq!(
+            executor
+                .throttles_for(Some("other"))
+                .await
+                .liquidity_cap
+                .max_adv_fraction,
+            Decimal::new(10, 2)
+        );
+
+        // 프로필 해제 시 기본 실행 조절 설정
+        executor.set_risk_profile(None).await;
+        executor
+            .set_strategy_risk_profile("test_strategy", None)
+            .await;
+        assert_eq!(
+            executor
+                .throttles_for(Some("test_strategy"))
+                .await
+                .liquidity_cap
+                .max_adv_fraction,
+            executor.liquidity_cap().max_adv_fraction
+        );
     }
 
     #[tokio::test]
-    async fn test_order_executor_risk_check_failure() {
-        // 포지션 한도를 초과하는 큰 기본 수량 ($10000의 10% = $1000)
-        let executor = create_test_executor(dec!(1.0)); // $50000 상당, 10% 한도 초과
+    async fn test_curfew_blocks_entries_with_strategy_override() {
+        use chrono::NaiveTime;
+        use trader_risk::{CurfewAction, CurfewMarket, CurfewWindow};
+
+        // 하루 전체를 덮는 국내 주식 차단 구간
+        let executor = create_test_executor(dec!(1)).with_curfew(CurfewConfig {
+            enabled: true,
+            windows: vec![CurfewWindow::new(
+                "all_day",
+                CurfewMarket::Krx,
+                NaiveTime::MIN,
+                NaiveTime::from_hms_nano_opt(23, 59, 59, 999_999_999).unwrap(),
+                CurfewAction::Block,
+            )],
+        });
+        let order =
+            OrderRequest::market_buy("005930".to_string(), dec!(1)).with_strategy("test_strategy");
+
+        let result = executor
+            .process_order_request(Uuid::new_v4(), order.clone(), dec!(100))
+            .await;
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("curfew window 'all_day'"));
 
-        // 과다 매수 시도 (포지션 한도 초과)
-        let signal = create_test_signal(Side::Buy, SignalType::Entry);
+        // 전략 오버라이드로 구간 제외
+        executor
+            .set_strategy_curfew(
+                "test_strategy",
+                Some(CurfewOverride {
+                    skip_windows: vec!["all_day".to_string()],
+                    ..Default::default()
+                }),
+            )
+            .await;
+        let result = executor
+            .process_order_request(Uuid::new_v4(), order, dec!(100))
+            .await;
+        assert!(result.success, "{:?}", result.error);
+    }
 
-        let result = executor.process_signal(&signal, dec!(50000)).await;
+    #[tokio::test]
+    async fn test_process_basket_failure_policies() {
+        use crate::basket::{BasketFailurePolicy, BasketLeg, BasketRequest, BasketTarget};
+
+        let basket = |policy| BasketRequest {
+            legs: vec![
+                BasketLeg {
+                    ticker: "BTC/USDT".to_string(),
+                    target: BasketTarget::Quantity {
+                        side: Side::Buy,
+                        quantity: dec!(1),
+                    },
+                    order_type: OrderType::Market,
+                    limit_price: None,
+                    current_price: dec!(100),
+                },
+                // 포지션 한도 초과로 거부되는 leg
+                BasketLeg {
+                    ticker: "ETH/USDT".to_string(),
+                    target: BasketTarget::Quantity {
+                        side: Side::Buy,
+                        quantity: dec!(90),
+                    },
+                    order_type: OrderType::Market,
+                    limit_price: None,
+                    current_price: dec!(100),
+                },
+            ],
+            total_value: None,
+            policy,
+            strategy_id: None,
+        };
 
+        let executor = create_test_executor(dec!(1));
+        let result = executor
+            .process_basket(Uuid::new_v4(), basket(BasketFailurePolicy::BestEffort))
+            .await;
+        assert_eq!(result.submitted, 1);
+        assert_eq!(result.failed, 1);
+        assert_eq!(result.legs[1].status, BasketLegStatus::Rejected);
+        assert_eq!(executor.get_active_orders().await.len(), 1);
+
+        let executor = create_test_executor(dec!(1));
+        let result = executor
+            .process_basket(Uuid::new_v4(), basket(BasketFailurePolicy::CancelOnFailure))
+            .await;
+        assert_eq!(result.legs[0].status, BasketLegStatus::Cancelled);
+        assert!(executor.get_active_orders().await.is_empty());
+
+        let executor = create_test_executor(dec!(1));
+        let result = executor
+            .process_basket(Uuid::new_v4(), basket(BasketFailurePolicy::AllOrNone))
+            .await;
         assert!(!result.success);
-        assert!(result.error.is_some());
+        assert_eq!(result.legs[0].status, BasketLegStatus::Skipped);
+        assert_eq!(result.legs[1].status, BasketLegStatus::Rejected);
+        assert!(executor.get_active_orders().await.is_empty());
     }
 
     #[tokio::test]
-    async fn test_order_executor_order_tracking() {
-        let executor = create_test_executor(dec!(0.01));
-        let signal = create_test_signal(Side::Buy, SignalType::Entry);
-
-        let result = executor.process_signal(&signal, dec!(50000)).await;
-        assert!(result.success);
+    async fn test_process_synthetic_signal_submits_legs() {
+        use trader_core::{SyntheticSignalSpec, SYNTHETIC_SIGNAL_KEY};
+
+        let spec = SyntheticSignalSpec {
+            expression: "BTC/USDT - 2*ETH/USDT".to_string(),
+            units: Some(dec!(1)),
+            leg_prices: HashMap::from([
+                ("BTC/USDT".to_string(), dec!(100)),
+                ("ETH/USDT".to_string(), dec!(50)),
+            ]),
+        };
+        let signal = Signal::entry("test_strategy", "SYN_BTCETH".to_string(), Side::Buy)
+            .with_metadata(SYNTHETIC_SIGNAL_KEY, spec.to_value());
 
-        let order_id = result.order_id.unwrap();
+        let executor = create_test_executor(dec!(1));
+        let result = executor.process_signal(&signal, Decimal::ZERO).await;
+        assert!(result.success, "{:?}", result.error);
+        assert!(result.order_id.is_some());
+        assert!(result.metadata.contains_key("synthetic_legs"));
 
-        // 주문이 추적 가능해야 함
-        let order = executor.get_order(order_id).await;
-        assert!(order.is_some());
+        let orders = executor.get_active_orders().await;
+        assert_eq!(orders.len(), 2);
+        let eth = orders.iter().find(|o| o.ticker == "ETH/USDT").unwrap();
+        assert_eq!(eth.side, Side::Sell);
+        assert_eq!(eth.quantity, dec!(2));
 
-        let order = order.unwrap();
-        assert_eq!(order.status, OrderStatusType::Pending);
+        // 메타데이터 없는 합성 종목 신호는 주문하지 않음
+        let plain = Signal::entry("test_strategy", "SYN_BTCETH".to_string(), Side::Buy);
+        let result = executor.process_signal(&plain, dec!(100)).await;
+        assert!(!result.success);
+        assert_eq!(executor.get_active_orders().await.len(), 2);
     }
 
     #[tokio::test]
-    async fn test_order_executor_submit_and_fill() {
-        let executor = create_test_executor(dec!(0.01));
-        let signal = create_test_signal(Side::Buy, SignalType::Entry);
-
-        // 신호 처리
-        let result = executor.process_signal(&signal, dec!(50000)).await;
-        assert!(result.success);
-
-        let order_id = result.order_id.unwrap();
-
-        // 주문 제출
+    async fn test_trading_cost_charged_on_fills() {
+        let executor = create_test_executor(dec!(3));
+        allocate_strategy(&executor, dec!(1000)).await;
         executor
-            .submit_order(order_id, "EX123".to_string())
+            .set_strategy_cost_model("test_strategy", Some(TradingCostModel::kr_kospi()))
+            .await;
+
+        let entry = create_test_signal(Side::Buy, SignalType::Entry);
+        let entry_id = executor
+            .process_signal(&entry, dec!(100))
             .await
+            .order_id
             .unwrap();
-
-        // 주문이 이제 Open 상태인지 확인
-        let order = executor.get_order(order_id).await.unwrap();
-        assert_eq!(order.status, OrderStatusType::Open);
-        assert_eq!(order.exchange_order_id, Some("EX123".to_string()));
-
-        // 체결 처리
         let fill = OrderFill {
-            order_id,
-            quantity: dec!(0.01),
-            price: dec!(50000),
-            commission: Some(dec!(0.5)),
+            order_id: entry_id,
+            quantity: dec!(3),
+            price: dec!(100),
+            commission: None,
             commission_asset: None,
             timestamp: chrono::Utc::now(),
         };
+        executor.handle_fill(entry_id, fill, true).await.unwrap();
+        // 매수: 수수료만 (300 × 0.015%)
+        assert_eq!(strategy_capital(&executor).await.realized_pnl, dec!(-0.045));
 
-        executor.handle_fill(order_id, fill, true).await.unwrap();
-
-        // 주문이 Filled 상태인지 확인
-        let order = executor.get_order(order_id).await.unwrap();
-        assert_eq!(order.status, OrderStatusType::Filled);
-
-        // 포지션이 생성되어야 함
-        let position = executor.get_position("BTC/USDT").await;
-        assert!(position.is_some());
+        let exit = create_test_signal(Side::Sell, SignalType::Exit);
+        let exit_id = executor
+            .process_signal(&exit, dec!(110))
+            .await
+            .order_id
+            .unwrap();
+        let fill = OrderFill {
+            order_id: exit_id,
+            quantity: dec!(3),
+            price: dec!(110),
+            commission: Some(dec!(0.1)),
+            commission_asset: None,
+            timestamp: chrono::Utc::now(),
+        };
+        executor.handle_fill(exit_id, fill, true).await.unwrap();
+
+        // 매도: 보고된 수수료 0.1 + 거래세/농특세 330 × 0.2% = 0.66
+        let expected = dec!(30) - dec!(0.045) - dec!(0.1) - dec!(0.66);
+        assert_eq!(strategy_capital(&executor).await.realized_pnl, expected);
+        let position = executor
+            .position_tracker
+            .read()
+            .await
+            .get_closed_positions()[0]
+            .clone();
+        assert_eq!(position.realized_pnl, expected);
     }
 
     #[tokio::test]
-    async fn test_order_executor_cancel_order() {
-        let executor = create_test_executor(dec!(0.01));
-        let signal = create_test_signal(Side::Buy, SignalType::Entry);
-
-        let result = executor.process_signal(&signal, dec!(50000)).await;
-        let order_id = result.order_id.unwrap();
+    async fn test_fill_events_published() {
+        let executor = create_test_executor(dec!(2));
+        let mut events = executor.subscribe_events();
 
-        // 주문 제출
-        executor
-            .submit_order(order_id, "EX123".to_string())
+        let entry = create_test_signal(Side::Buy, SignalType::Entry);
+        let entry_id = executor
+            .process_signal(&entry, dec!(100))
             .await
+            .order_id
             .unwrap();
+        let fill = OrderFill {
+            order_id: entry_id,
+            quantity: dec!(2),
+            price: dec!(100),
+            commission: None,
+            commission_asset: None,
+            timestamp: chrono::Utc::now(),
+        };
+        executor.handle_fill(entry_id, fill, true).await.unwrap();
+
+        match events.recv().await.unwrap() {
+            ExecutionEvent::OrderFilled {
+                order_id,
+                filled_quantity,
+                complete,
+                ..
+            } => {
+                assert_eq!(order_id, entry_id);
+                assert_eq!(filled_quantity, dec!(2));
+                assert!(complete);
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
+        match events.recv().await.unwrap() {
+            ExecutionEvent::PositionChanged {
+                quantity,
+                opened,
+                closed,
+                ..
+            } => {
+                assert_eq!(quantity, dec!(2));
+                assert!(opened);
+                assert!(!closed);
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
 
-        // 주문 취소
-        executor
-            .cancel_order(order_id, Some("User requested".to_string()))
+        // 완전 청산 시 closed 포지션 이벤트
+        let exit = create_test_signal(Side::Sell, SignalType::Exit);
+        let exit_id = executor
+            .process_signal(&exit, dec!(110))
             .await
+            .order_id
             .unwrap();
+        let fill = OrderFill {
+            order_id: exit_id,
+            quantity: dec!(2),
+            price: dec!(110),
+            commission: None,
+            commission_asset: None,
+            timestamp: chrono::Utc::now(),
+        };
+        executor.handle_fill(exit_id, fill, true).await.unwrap();
 
-        // 주문이 Cancelled 상태인지 확인
-        let order = executor.get_order(order_id).await.unwrap();
-        assert_eq!(order.status, OrderStatusType::Cancelled);
+        assert!(matches!(
+            events.recv().await.unwrap(),
+            ExecutionEvent::OrderFilled { .. }
+        ));
+        match events.recv().await.unwrap() {
+            ExecutionEvent::PositionChanged {
+                quantity,
+                realized_pnl,
+                closed,
+                ..
+            } => {
+                assert_eq!(quantity, dec!(0));
+                assert_eq!(realized_pnl, dec!(20));
+                assert!(closed);
+            }
+            other => panic!("unexpected event: {:?}", other),
+        }
     }
 
     #[tokio::test]
-    async fn test_order_executor_active_orders() {
-        let executor = create_test_executor(dec!(0.01));
+    async fn test_order_circuit_opens_on_rejections_and_queues() {
+        use crate::order_circuit::OpenCircuitPolicy;
+        use trader_exchange::{CircuitBreakerConfig, CircuitState};
 
-        // 여러 주문 생성
-        for _ in 0..3 {
+        let executor = create_test_executor(dec!(0.01)).with_order_circuit(OrderCircuitConfig {
+            breaker: CircuitBreakerConfig::new(2, 60, 1),
+            open_policy: OpenCircuitPolicy::Queue,
+            max_queued: 10,
+        });
+
+        for _ in 0..2 {
             let signal = create_test_signal(Side::Buy, SignalType::Entry);
-            let result = executor.process_signal(&signal, dec!(50000)).await;
-            let order_id = result.order_id.unwrap();
+            let order_id = executor
+                .process_signal(&signal, dec!(50000))
+                .await
+                .order_id
+                .unwrap();
             executor
-                .submit_order(order_id, format!("EX{}", order_id))
+                .handle_submit_failure(
+                    order_id,
+                    &VenueError::OrderRejected("price out of range".into()),
+                )
                 .await
                 .unwrap();
+            let order = executor.get_order(order_id).await.unwrap();
+            assert_eq!(order.status, OrderStatusType::Rejected);
         }
+        assert_eq!(
+            executor.order_circuit().state("test_exchange"),
+            CircuitState::Open
+        );
 
-        let active_orders = executor.get_active_orders().await;
-        assert_eq!(active_orders.len(), 3);
+        // Open 상태에서는 대기열에 보관
+        let signal = create_test_signal(Side::Buy, SignalType::Entry);
+        let result = executor.process_signal(&signal, dec!(50000)).await;
+        assert!(result.success);
+        assert!(result.queued);
+        assert!(executor.take_released_orders().await.is_empty());
+
+        // 복구 후 대기 주문 방출
+        executor.order_circuit().reset("test_exchange");
+        let released = executor.take_released_orders().await;
+        assert_eq!(released.len(), 1);
+        assert_eq!(Some(released[0].id), result.order_id);
+    }
+
+    #[tokio::test]
+    async fn test_broker_downtime_rejects_or_queues_orders() {
+        use crate::broker_downtime::{BrokerDowntimeConfig, DowntimePolicy};
+        use trader_core::BrokerDowntimeCalendar;
+
+        let calendar = Arc::new(RwLock::new(BrokerDowntimeCalendar::new()));
+        let executor = create_test_executor(dec!(0.01))
+            .with_broker_downtime(BrokerDowntimeConfig::default(), Arc::clone(&calendar));
+
+        calendar
+            .write()
+            .await
+            .report_outage("kis", "connection reset", Utc::now());
+        let signal = create_test_signal(Side::Buy, SignalType::Entry);
+        let result = executor.process_signal(&signal, dec!(50000)).await;
+        assert!(!result.success);
+        assert!(result
+            .error
+            .unwrap()
+            .contains("kis outage: connection reset"));
+
+        let order = OrderRequest::market_buy("BTC/USDT".to_string(), dec!(0.01));
+        let preview = executor
+            .preview_order(&order, dec!(50000), None, None)
+            .await;
+        assert!(!preview.accepted);
+
+        // 대기열 정책: 복구 후 방출
+        let executor = create_test_executor(dec!(0.01)).with_broker_downtime(
+            BrokerDowntimeConfig {
+                policy: DowntimePolicy::Queue,
+                ..Default::default()
+            },
+            Arc::clone(&calendar),
+        );
+        let result = executor.process_signal(&signal, dec!(50000)).await;
+        assert!(result.success);
+        assert!(result.queued);
+        assert!(executor.take_released_orders().await.is_empty());
+
+        calendar.write().await.resolve_outage("kis", Utc::now());
+        let released = executor.take_released_orders().await;
+        assert_eq!(released.len(), 1);
+        assert_eq!(Some(released[0].id), result.order_id);
+    }
+
+    #[tokio::test]
+    async fn test_duplicate_order_intent_rejected() {
+        let executor = create_test_executor(dec!(0.01));
+        let signal = create_test_signal(Side::Buy, SignalType::Entry);
+
+        let first = executor.process_signal(&signal, dec!(50000)).await;
+        assert!(first.success);
+        let order_id = first.order_id.unwrap();
+        let key = format!("sig_{}", signal.id);
+
+        // 같은 신호 재처리 → 중복 주문 거부
+        let duplicate = executor.process_signal(&signal, dec!(50000)).await;
+        assert!(!duplicate.success);
+        assert!(duplicate.error.unwrap().contains("Duplicate order intent"));
+        assert!(duplicate.metadata.contains_key("order_intent"));
+
+        let order =
+            OrderRequest::market_buy("BTC/USDT".to_string(), dec!(0.01)).with_client_id(&key);
+        let preview = executor
+            .preview_order(&order, dec!(50000), None, None)
+            .await;
+        assert!(!preview.accepted);
+
+        // 타임아웃은 접수 여부를 알 수 없으므로 계속 차단
+        executor
+            .handle_submit_failure(order_id, &VenueError::Timeout("kis".into()))
+            .await
+            .unwrap();
+        assert!(!executor.process_signal(&signal, dec!(50000)).await.success);
+        assert_eq!(executor.order_intents().in_flight().len(), 1);
+
+        // 요청 ID로 생성한 키도 같은 요청 ID면 중복
+        let request_id = Uuid::new_v4();
+        let request = OrderRequest::market_buy("BTC/USDT".to_string(), dec!(0.01));
+        let result = executor
+            .process_order_request(request_id, request.clone(), dec!(50000))
+            .await;
+        assert_eq!(
+            result.order.unwrap().client_order_id,
+            Some(client_order_id_for(request_id))
+        );
+        let result = executor
+            .process_order_request(request_id, request, dec!(50000))
+            .await;
+        assert!(!result.success);
+    }
+
+    #[tokio::test]
+    async fn test_duplicate_order_intent_releases_budget() {
+        let executor = create_test_executor(dec!(2));
+        allocate_strategy(&executor, dec!(1000)).await;
+        let signal = create_test_signal(Side::Buy, SignalType::Entry);
+
+        assert!(executor.process_signal(&signal, dec!(100)).await.success);
+        assert_eq!(strategy_capital(&executor).await.in_use, dec!(200));
+
+        // 중복 판정은 예산 배정 후 리스크 잠금 밖에서 하므로 거부 시 배정분 반환
+        assert!(!executor.process_signal(&signal, dec!(100)).await.success);
+        assert_eq!(strategy_capital(&executor).await.in_use, dec!(200));
     }
 
     #[test]
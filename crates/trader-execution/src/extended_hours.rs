@@ -0,0 +1,300 @@
+//! 미국 주식 정규장 외 거래 리스크 검사.
+//!
+//! 주간거래, 프리마켓, 애프터마켓은 호가가 얇고 스프레드가 넓어
+//! 전략별로 명시적으로 허용(opt-in)한 경우에만 주문을 받습니다.
+//! 허용된 전략의 주문도 다음 제한을 받습니다:
+//!
+//! - 지정가 주문만 허용 (시장가는 얇은 호가에서 큰 슬리피지 발생)
+//! - 현재가 대비 지정가 괴리 제한
+//! - 진입 주문 수량을 세션별 비율로 축소
+
+use rust_decimal::{Decimal, RoundingStrategy};
+use serde::{Deserialize, Serialize};
+use trader_core::{OrderRequest, OrderType};
+use trader_exchange::connector::kis::UsTradingSession;
+
+/// 정규장 외 거래 설정.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExtendedHoursConfig {
+    /// 검사 활성화 여부 (비활성화 시 세션과 무관하게 통과)
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    /// 프리마켓/애프터마켓 진입 수량 비율 (기본값: 0.5)
+    #[serde(default = "default_size_factor")]
+    pub size_factor: Decimal,
+    /// 주간거래 진입 수량 비율 (기본값: 0.25)
+    #[serde(default = "default_daytime_size_factor")]
+    pub daytime_size_factor: Decimal,
+    /// 현재가 대비 최대 지정가 괴리율 (%, 기본값: 2%)
+    #[serde(default = "default_max_price_deviation_pct")]
+    pub max_price_deviation_pct: Decimal,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+fn default_size_factor() -> Decimal {
+    Decimal::new(5, 1)
+}
+
+fn default_daytime_size_factor() -> Decimal {
+    Decimal::new(25, 2)
+}
+
+fn default_max_price_deviation_pct() -> Decimal {
+    Decimal::from(2)
+}
+
+impl Default for ExtendedHoursConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_enabled(),
+            size_factor: default_size_factor(),
+            daytime_size_factor: default_daytime_size_factor(),
+            max_price_deviation_pct: default_max_price_deviation_pct(),
+        }
+    }
+}
+
+impl ExtendedHoursConfig {
+    /// 세션별 진입 수량 비율.
+    pub fn size_factor_for(&self, session: UsTradingSession) -> Decimal {
+        match session {
+            UsTradingSession::Daytime => self.daytime_size_factor,
+            UsTradingSession::PreMarket | UsTradingSession::AfterHours => self.size_factor,
+            UsTradingSession::Regular | UsTradingSession::Closed => Decimal::ONE,
+        }
+    }
+}
+
+/// 정규장 외 거래 검사 결과.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExtendedHoursCheck {
+    /// 검사 대상 아님 (정규장, 휴장, 미국 주식 아님)
+    NotApplicable,
+    /// 그대로 허용
+    Approved,
+    /// 유동성을 고려해 수량 축소 후 허용
+    Scaled { quantity: Decimal },
+    /// 거부
+    Rejected(String),
+}
+
+/// 미국 주식 종목 여부 (영문 심볼, 예: `AAPL`, `BRK.B`).
+///
+/// 국내 종목(6자리 코드)과 암호화폐(`BTC/USDT`)는 제외합니다.
+pub fn is_us_equity(ticker: &str) -> bool {
+    ticker
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic())
+        && ticker
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '.' || c == '-')
+}
+
+/// 정규장 외 세션 주문 검사.
+///
+/// # Arguments
+///
+/// * `order` - 검사할 주문
+/// * `session` - 현재 미국 주식 거래 세션
+/// * `allowed` - 전략의 정규장 외 거래 허용 여부
+/// * `is_entry` - 진입 주문 여부 (청산 주문은 수량을 축소하지 않음)
+/// * `config` - 정규장 외 거래 설정
+/// * `current_price` - 현재가
+pub fn check_extended_hours(
+    order: &OrderRequest,
+    session: UsTradingSession,
+    allowed: bool,
+    is_entry: bool,
+    config: &ExtendedHoursConfig,
+    current_price: Decimal,
+) -> ExtendedHoursCheck {
+    if !config.enabled || !session.is_extended() || !is_us_equity(&order.ticker) {
+        return ExtendedHoursCheck::NotApplicable;
+    }
+
+    if !allowed {
+        return ExtendedHoursCheck::Rejected(format!(
+            "{} session trading is not enabled for this strategy",
+            session
+        ));
+    }
+
+    let Some(price) = order.price.filter(|_| order.order_type == OrderType::Limit) else {
+        return ExtendedHoursCheck::Rejected(format!(
+            "{} session accepts limit orders only",
+            session
+        ));
+    };
+
+    if current_price > Decimal::ZERO {
+        let deviation_pct = ((price - current_price) / current_price * Decimal::ONE_HUNDRED).abs();
+        if deviation_pct > config.max_price_deviation_pct {
+            return ExtendedHoursCheck::Rejected(format!(
+                "Limit price deviates {:.2}% from current price (max {}% in {} session)",
+                deviation_pct, config.max_price_deviation_pct, session
+            ));
+        }
+    }
+
+    if !is_entry {
+        return ExtendedHoursCheck::Approved;
+    }
+
+    let factor = config.size_factor_for(session);
+    let quantity = (order.quantity * factor)
+        .round_dp_with_strategy(0, RoundingStrategy::ToZero)
+        .normalize();
+    if quantity <= Decimal::ZERO {
+        return ExtendedHoursCheck::Rejected(format!(
+            "Order quantity {} is too small for {} session liquidity limit",
+            order.quantity, session
+        ));
+    }
+    if quantity < order.quantity {
+        ExtendedHoursCheck::Scaled { quantity }
+    } else {
+        ExtendedHoursCheck::Approved
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal::prelude::FromPrimitive;
+
+    /// 숫자로부터 Decimal을 생성하는 헬퍼 매크로
+    macro_rules! dec {
+        ($val:expr) => {
+            Decimal::from_f64($val as f64).unwrap()
+        };
+    }
+
+    #[test]
+    fn test_extended_hours_requires_opt_in_and_limit() {
+        let config = ExtendedHoursConfig::default();
+        let limit = OrderRequest::limit_buy("AAPL".into(), dec!(10), dec!(200));
+        let market = OrderRequest::market_buy("AAPL".into(), dec!(10));
+
+        // 정규장과 국내 종목은 검사 대상 아님
+        assert_eq!(
+            check_extended_hours(
+                &market,
+                UsTradingSession::Regular,
+                false,
+                true,
+                &config,
+                dec!(200)
+            ),
+            ExtendedHoursCheck::NotApplicable
+        );
+        let kr = OrderRequest::market_buy("005930".into(), dec!(10));
+        assert_eq!(
+            check_extended_hours(
+                &kr,
+                UsTradingSession::PreMarket,
+                false,
+                true,
+                &config,
+                dec!(70000)
+            ),
+            ExtendedHoursCheck::NotApplicable
+        );
+
+        // 미허용 전략, 시장가, 과도한 괴리는 거부
+        assert!(matches!(
+            check_extended_hours(
+                &limit,
+                UsTradingSession::PreMarket,
+                false,
+                true,
+                &config,
+                dec!(200)
+            ),
+            ExtendedHoursCheck::Rejected(_)
+        ));
+        assert!(matches!(
+            check_extended_hours(
+                &market,
+                UsTradingSession::AfterHours,
+                true,
+                true,
+                &config,
+                dec!(200)
+            ),
+            ExtendedHoursCheck::Rejected(_)
+        ));
+        assert!(matches!(
+            check_extended_hours(
+                &limit,
+                UsTradingSession::AfterHours,
+                true,
+                true,
+                &config,
+                dec!(190)
+            ),
+            ExtendedHoursCheck::Rejected(_)
+        ));
+    }
+
+    #[test]
+    fn test_extended_hours_scales_entries() {
+        let config = ExtendedHoursConfig::default();
+        let order = OrderRequest::limit_buy("AAPL".into(), dec!(10), dec!(200));
+
+        assert_eq!(
+            check_extended_hours(
+                &order,
+                UsTradingSession::PreMarket,
+                true,
+                true,
+                &config,
+                dec!(199)
+            ),
+            ExtendedHoursCheck::Scaled { quantity: dec!(5) }
+        );
+        // 주간거래는 더 크게 축소 (10 × 0.25 = 2.5 → 2)
+        assert_eq!(
+            check_extended_hours(
+                &order,
+                UsTradingSession::Daytime,
+                true,
+                true,
+                &config,
+                dec!(200)
+            ),
+            ExtendedHoursCheck::Scaled { quantity: dec!(2) }
+        );
+        // 청산 주문은 축소하지 않음
+        let exit = OrderRequest::limit_sell("AAPL".into(), dec!(10), dec!(200));
+        assert_eq!(
+            check_extended_hours(
+                &exit,
+                UsTradingSession::Daytime,
+                true,
+                false,
+                &config,
+                dec!(200)
+            ),
+            ExtendedHoursCheck::Approved
+        );
+        // 축소 후 1주 미만이면 거부
+        let small = OrderRequest::limit_buy("AAPL".into(), dec!(1), dec!(200));
+        assert!(matches!(
+            check_extended_hours(
+                &small,
+                UsTradingSession::AfterHours,
+                true,
+                true,
+                &config,
+                dec!(200)
+            ),
+            ExtendedHoursCheck::Rejected(_)
+        ));
+        assert!(is_us_equity("BRK.B"));
+        assert!(!is_us_equity("BTC/USDT"));
+    }
+}
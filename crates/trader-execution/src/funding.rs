@@ -0,0 +1,94 @@
+//! 일괄 주문 매수 여력 예측.
+//!
+//! 리밸런싱처럼 여러 진입 주문을 한 번에 제출할 때, 주문별로 매수 여력을 확인하면
+//! 앞선 주문이 자금을 소진해 뒤의 주문만 거래소에서 거부됩니다.
+//! 제출 전에 전체 필요 금액을 합산하고, 주문 가능 금액을 초과하면 모든 진입 주문을
+//! 같은 비율로 축소하여 목표 비중의 상대 크기를 유지합니다.
+//! `OrderExecutor::process_signals()`가 사용합니다.
+
+use rust_decimal::{Decimal, RoundingStrategy};
+use serde::{Deserialize, Serialize};
+
+/// 일괄 주문 매수 여력 예측 결과.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FundingForecast {
+    /// 주문 가능 금액 (잔고 - 미체결 매수 주문 금액)
+    pub available: Decimal,
+    /// 진입 주문 전체에 필요한 금액 (주문 대금 + 예상 비용)
+    pub required: Decimal,
+    /// 진입 주문 수량에 적용할 비율 (0 ~ 1)
+    pub scale: Decimal,
+}
+
+impl FundingForecast {
+    /// 필요 금액과 주문 가능 금액으로 축소 비율 계산.
+    ///
+    /// # Arguments
+    ///
+    /// * `available` - 주문 가능 금액
+    /// * `requirements` - 진입 주문별 필요 금액
+    pub fn compute(available: Decimal, requirements: &[Decimal]) -> Self {
+        let available = available.max(Decimal::ZERO);
+        let required: Decimal = requirements.iter().copied().sum();
+        let scale = if required <= available {
+            Decimal::ONE
+        } else {
+            available / required
+        };
+
+        Self {
+            available,
+            required,
+            scale,
+        }
+    }
+
+    /// 진입 주문 축소가 필요한지 여부.
+    pub fn is_scaled(&self) -> bool {
+        self.scale < Decimal::ONE
+    }
+
+    /// 비율을 적용한 주문 수량.
+    ///
+    /// 비율 적용 후에도 필요 금액이 주문 가능 금액을 넘지 않도록 내림합니다.
+    /// 주식은 정수 주, 암호화폐(`BASE/QUOTE`)는 소수점 8자리까지 남깁니다.
+    pub fn scale_quantity(&self, ticker: &str, quantity: Decimal) -> Decimal {
+        if !self.is_scaled() {
+            return quantity;
+        }
+        let dp = if ticker.contains('/') { 8 } else { 0 };
+        (quantity * self.scale)
+            .round_dp_with_strategy(dp, RoundingStrategy::ToZero)
+            .normalize()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal::prelude::FromPrimitive;
+
+    /// 숫자로부터 Decimal을 생성하는 헬퍼 매크로
+    macro_rules! dec {
+        ($val:expr) => {
+            Decimal::from_f64($val as f64).unwrap()
+        };
+    }
+
+    #[test]
+    fn test_forecast_scales_proportionally() {
+        let forecast = FundingForecast::compute(dec!(1000), &[dec!(500), dec!(500)]);
+        assert!(!forecast.is_scaled());
+        assert_eq!(forecast.scale_quantity("005930", dec!(7)), dec!(7));
+
+        let forecast = FundingForecast::compute(dec!(1000), &[dec!(1500), dec!(500)]);
+        assert!(forecast.is_scaled());
+        assert_eq!(forecast.required, dec!(2000));
+        assert_eq!(forecast.scale, dec!(0.5));
+        assert_eq!(forecast.scale_quantity("005930", dec!(15)), dec!(7));
+        assert_eq!(forecast.scale_quantity("BTC/USDT", dec!(0.3)), dec!(0.15));
+
+        let forecast = FundingForecast::compute(dec!(-10), &[dec!(100)]);
+        assert_eq!(forecast.scale, Decimal::ZERO);
+    }
+}
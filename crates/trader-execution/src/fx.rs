@@ -0,0 +1,300 @@
+//! 해외 주식 주문 전 자동 환전.
+//!
+//! 미국 주식 매수 주문에 필요한 USD가 부족하면 부족분(+ 여유분)만큼 원화를 환전합니다.
+//! 증권사 적용 환율이 기준 환율 대비 허용 스프레드를 넘으면 환전하지 않고,
+//! 수동 승인 모드에서는 환전과 주문을 승인 대기로 보류합니다.
+
+use rust_decimal::{Decimal, RoundingStrategy};
+use serde::{Deserialize, Serialize};
+
+/// 환전 승인 방식.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FxApprovalMode {
+    /// 스프레드 한도 안이면 즉시 환전
+    Auto,
+    /// 환전마다 승인 필요 (주문 보류)
+    #[default]
+    Manual,
+}
+
+impl FxApprovalMode {
+    /// DB 저장용 문자열.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Auto => "auto",
+            Self::Manual => "manual",
+        }
+    }
+}
+
+impl std::str::FromStr for FxApprovalMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "auto" => Ok(Self::Auto),
+            "manual" => Ok(Self::Manual),
+            other => Err(format!("Unknown FX approval mode: {}", other)),
+        }
+    }
+}
+
+/// 환전 기록 상태.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FxConversionStatus {
+    /// 승인 대기 (주문 보류)
+    Pending,
+    /// USD 충분, 환전 없이 주문 제출
+    NotNeeded,
+    /// 별도 환전 주문 없이 주문 제출 (KIS 통합증거금 위임, 50번 마이그레이션 이전 기록)
+    #[serde(rename = "deferred")]
+    DeferredToIntegratedMargin,
+    /// 환전 주문 접수 후 주문 제출
+    Executed,
+    /// 스프레드/잔고 한도 초과 또는 수동 거절
+    Rejected,
+    /// 주문 제출 실패
+    Failed,
+}
+
+impl FxConversionStatus {
+    /// DB 저장용 문자열.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::NotNeeded => "not_needed",
+            Self::DeferredToIntegratedMargin => "deferred",
+            Self::Executed => "executed",
+            Self::Rejected => "rejected",
+            Self::Failed => "failed",
+        }
+    }
+}
+
+impl std::str::FromStr for FxConversionStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pending" => Ok(Self::Pending),
+            "not_needed" => Ok(Self::NotNeeded),
+            "deferred" => Ok(Self::DeferredToIntegratedMargin),
+            "executed" => Ok(Self::Executed),
+            "rejected" => Ok(Self::Rejected),
+            "failed" => Ok(Self::Failed),
+            other => Err(format!("Unknown FX conversion status: {}", other)),
+        }
+    }
+}
+
+/// 자동 환전 정책.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FxConversionPolicy {
+    /// 승인 방식
+    pub mode: FxApprovalMode,
+    /// 기준 환율 대비 허용 스프레드 (bp)
+    pub max_spread_bps: Decimal,
+    /// 부족분에 더해 환전할 여유분 (%)
+    pub buffer_pct: Decimal,
+}
+
+/// 환전 판단에 사용하는 계좌/환율 정보.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FxFundingSnapshot {
+    /// 주문 가능 USD
+    pub usd_available: Decimal,
+    /// 원화 환전분을 포함한 주문 가능 USD
+    pub usd_available_after_conversion: Decimal,
+    /// 증권사 적용 환율 (KRW/USD)
+    pub rate: Decimal,
+    /// 기준 환율 (시장 환율, 없으면 스프레드 검사 생략)
+    pub reference_rate: Option<Decimal>,
+}
+
+impl FxFundingSnapshot {
+    /// 기준 환율 대비 적용 환율 스프레드 (bp).
+    pub fn spread_bps(&self) -> Option<Decimal> {
+        self.reference_rate
+            .filter(|reference| *reference > Decimal::ZERO)
+            .map(|reference| {
+                ((self.rate - reference) / reference * Decimal::from(10_000)).round_dp(2)
+            })
+    }
+}
+
+/// 환전 판단 결과.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FxConversionDecision {
+    /// USD 충분, 환전 불필요
+    NotNeeded,
+    /// 즉시 환전
+    Execute,
+    /// 승인 대기 (주문 보류)
+    AwaitApproval,
+    /// 스프레드 한도 초과
+    SpreadTooWide,
+    /// 환전해도 주문 금액 부족
+    InsufficientFunds,
+}
+
+impl FxConversionDecision {
+    /// 주문을 그대로 진행할 수 있는지 여부.
+    pub fn allows_order(&self) -> bool {
+        matches!(self, Self::NotNeeded | Self::Execute)
+    }
+}
+
+/// 환전 계획.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FxConversionPlan {
+    pub decision: FxConversionDecision,
+    /// 주문에 필요한 USD
+    pub required_usd: Decimal,
+    /// 부족 USD
+    pub shortfall_usd: Decimal,
+    /// 환전할 USD (여유분 포함, 센트 단위 올림)
+    pub usd_amount: Decimal,
+    /// 환전에 드는 원화 (원 단위 올림)
+    pub krw_amount: Decimal,
+    /// 적용 환율
+    pub rate: Decimal,
+    /// 기준 환율 대비 스프레드 (bp)
+    pub spread_bps: Option<Decimal>,
+}
+
+/// 주문 전 환전 계획 계산.
+///
+/// # 인자
+/// * `required_usd` - 주문에 필요한 USD (수량 × 가격)
+/// * `snapshot` - 주문 가능 금액과 환율
+/// * `policy` - 자동 환전 정책
+pub fn plan_fx_conversion(
+    required_usd: Decimal,
+    snapshot: &FxFundingSnapshot,
+    policy: &FxConversionPolicy,
+) -> FxConversionPlan {
+    let shortfall_usd = (required_usd - snapshot.usd_available).max(Decimal::ZERO);
+    let spread_bps = snapshot.spread_bps();
+    let mut plan = FxConversionPlan {
+        decision: FxConversionDecision::NotNeeded,
+        required_usd,
+        shortfall_usd,
+        usd_amount: Decimal::ZERO,
+        krw_amount: Decimal::ZERO,
+        rate: snapshot.rate,
+        spread_bps,
+    };
+    if shortfall_usd.is_zero() {
+        return plan;
+    }
+
+    let buffered = shortfall_usd * (Decimal::ONE + policy.buffer_pct / Decimal::ONE_HUNDRED);
+    // 여유분은 환전 가능 범위 안에서만 더함
+    let convertible =
+        (snapshot.usd_available_after_conversion - snapshot.usd_available).max(Decimal::ZERO);
+    plan.usd_amount = buffered
+        .min(convertible.max(shortfall_usd))
+        .round_dp_with_strategy(2, RoundingStrategy::AwayFromZero);
+    plan.krw_amount =
+        (plan.usd_amount * snapshot.rate).round_dp_with_strategy(0, RoundingStrategy::AwayFromZero);
+
+    plan.decision = if snapshot.rate <= Decimal::ZERO
+        || snapshot.usd_available_after_conversion < required_usd
+    {
+        FxConversionDecision::InsufficientFunds
+    } else if spread_bps.is_some_and(|spread| spread > policy.max_spread_bps) {
+        FxConversionDecision::SpreadTooWide
+    } else if policy.mode == FxApprovalMode::Manual {
+        FxConversionDecision::AwaitApproval
+    } else {
+        FxConversionDecision::Execute
+    };
+    plan
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal::prelude::FromPrimitive;
+
+    /// 숫자로부터 Decimal을 생성하는 헬퍼 매크로
+    macro_rules! dec {
+        ($val:expr) => {
+            Decimal::from_f64($val as f64).unwrap()
+        };
+    }
+
+    fn snapshot(usd: f64, after: f64, reference: Option<f64>) -> FxFundingSnapshot {
+        FxFundingSnapshot {
+            usd_available: dec!(usd),
+            usd_available_after_conversion: dec!(after),
+            rate: dec!(1400),
+            reference_rate: reference.map(|r| dec!(r)),
+        }
+    }
+
+    fn policy(mode: FxApprovalMode) -> FxConversionPolicy {
+        FxConversionPolicy {
+            mode,
+            max_spread_bps: dec!(50),
+            buffer_pct: dec!(1),
+        }
+    }
+
+    #[test]
+    fn test_plan_fx_conversion_auto() {
+        // 1,000 USD 필요, 400 USD 보유 → 600 + 1% = 606 USD 환전
+        let plan = plan_fx_conversion(
+            dec!(1000),
+            &snapshot(400.0, 5000.0, Some(1395.0)),
+            &policy(FxApprovalMode::Auto),
+        );
+        assert_eq!(plan.decision, FxConversionDecision::Execute);
+        assert_eq!(plan.shortfall_usd, dec!(600));
+        assert_eq!(plan.usd_amount, dec!(606));
+        assert_eq!(plan.krw_amount, dec!(848400));
+        assert_eq!(plan.spread_bps, Some(dec!(35.84)));
+        assert!(plan.decision.allows_order());
+
+        // USD 충분
+        let plan = plan_fx_conversion(
+            dec!(300),
+            &snapshot(400.0, 5000.0, None),
+            &policy(FxApprovalMode::Auto),
+        );
+        assert_eq!(plan.decision, FxConversionDecision::NotNeeded);
+        assert!(plan.usd_amount.is_zero());
+    }
+
+    #[test]
+    fn test_plan_fx_conversion_limits() {
+        // 스프레드 한도 초과 (1400 vs 1380 → 144.93bp)
+        let plan = plan_fx_conversion(
+            dec!(1000),
+            &snapshot(400.0, 5000.0, Some(1380.0)),
+            &policy(FxApprovalMode::Auto),
+        );
+        assert_eq!(plan.decision, FxConversionDecision::SpreadTooWide);
+        assert!(!plan.decision.allows_order());
+
+        // 원화 환전분을 더해도 부족
+        let plan = plan_fx_conversion(
+            dec!(1000),
+            &snapshot(400.0, 900.0, None),
+            &policy(FxApprovalMode::Auto),
+        );
+        assert_eq!(plan.decision, FxConversionDecision::InsufficientFunds);
+
+        // 여유분은 환전 가능 금액까지만
+        let plan = plan_fx_conversion(
+            dec!(1000),
+            &snapshot(400.0, 1003.0, None),
+            &policy(FxApprovalMode::Manual),
+        );
+        assert_eq!(plan.decision, FxConversionDecision::AwaitApproval);
+        assert_eq!(plan.usd_amount, dec!(603));
+    }
+}
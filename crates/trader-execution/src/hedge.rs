@@ -0,0 +1,386 @@
+//! 포트폴리오 베타 헤지 오버레이.
+//!
+//! 보유 종목의 벤치마크 대비 베타로 순 주식 베타 노출을 계산하고,
+//! 인버스 ETF(매수) 또는 지수 선물(매도) 포지션으로 목표 베타 밴드 안에 유지합니다.
+//!
+//! - 베타 노출: Σ (평가금액 × 베타)
+//! - 순 베타: (베타 노출 + 헤지 상품 베타 × 헤지 평가금액) / 평가 자산
+//! - 순 베타가 `목표 ± 밴드`를 벗어날 때만 목표 베타에 맞춰 헤지 수량을 조정
+//!
+//! 헤지 상품 베타가 음수(인버스 ETF)이면 매수 수량, 양수(지수 선물)이면 매도 수량으로
+//! 헤지합니다. 선물은 계약 승수를 반영한 계약당 가격을 사용해야 합니다.
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::dca::quantity_for_amount;
+
+/// 헤지 목표 설정.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HedgeSettings {
+    /// 헤지 상품 티커 (예: 114800 KODEX 인버스)
+    pub hedge_ticker: String,
+    /// 헤지 상품의 벤치마크 대비 베타 (인버스 1배: -1, 인버스 2배: -2, 선물: 1)
+    pub hedge_beta: Decimal,
+    /// 목표 순 베타 (0이면 완전 헤지)
+    pub target_beta: Decimal,
+    /// 허용 밴드 (순 베타가 목표 ± 밴드 안이면 조정하지 않음)
+    pub band: Decimal,
+    /// 평가 자산 대비 최대 헤지 평가금액 비율
+    pub max_hedge_ratio: Decimal,
+}
+
+impl HedgeSettings {
+    /// 설정 검증.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.hedge_ticker.trim().is_empty() {
+            return Err("hedge_ticker is required".to_string());
+        }
+        if self.hedge_beta.is_zero() {
+            return Err("hedge_beta must not be zero".to_string());
+        }
+        if self.band < Decimal::ZERO {
+            return Err("band must not be negative".to_string());
+        }
+        if self.max_hedge_ratio <= Decimal::ZERO {
+            return Err("max_hedge_ratio must be positive".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// 보유 종목 하나의 베타 노출.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HedgeExposure {
+    pub ticker: String,
+    /// 평가금액 (숏 포지션은 음수)
+    pub market_value: Decimal,
+    /// 벤치마크 대비 베타
+    pub beta: Decimal,
+}
+
+impl HedgeExposure {
+    /// 베타 노출 (평가금액 × 베타).
+    pub fn beta_exposure(&self) -> Decimal {
+        self.market_value * self.beta
+    }
+}
+
+/// 헤지 조정 계획.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HedgePlan {
+    /// 헤지 제외 베타 노출 합계
+    pub equity_beta_exposure: Decimal,
+    /// 현재 헤지 포지션의 베타 노출
+    pub hedge_beta_exposure: Decimal,
+    /// 조정 전 순 베타
+    pub net_beta_before: Decimal,
+    /// 조정 후 순 베타 (수량 반올림/상한 반영)
+    pub net_beta_after: Decimal,
+    /// 현재 헤지 수량 (매도 헤지는 음수)
+    pub current_quantity: Decimal,
+    /// 목표 헤지 수량
+    pub target_quantity: Decimal,
+    /// 주문 수량 (양수: 매수, 음수: 매도, 0: 조정 없음)
+    pub order_quantity: Decimal,
+    /// 판단 사유
+    pub reason: String,
+}
+
+impl HedgePlan {
+    /// 주문이 필요한지 여부.
+    pub fn needs_rebalance(&self) -> bool {
+        !self.order_quantity.is_zero()
+    }
+}
+
+/// 헤지 조정 계획 계산.
+///
+/// # 인자
+/// * `settings` - 헤지 목표 설정
+/// * `exposures` - 헤지 상품을 제외한 보유 종목 베타 노출
+/// * `current_quantity` - 현재 헤지 수량 (매도 헤지는 음수)
+/// * `hedge_price` - 헤지 상품 현재가
+/// * `equity` - 평가 자산
+pub fn plan_hedge(
+    settings: &HedgeSettings,
+    exposures: &[HedgeExposure],
+    current_quantity: Decimal,
+    hedge_price: Decimal,
+    equity: Decimal,
+) -> HedgePlan {
+    let equity_beta_exposure: Decimal = exposures.iter().map(HedgeExposure::beta_exposure).sum();
+    let hedge_exposure_for = |quantity: Decimal| settings.hedge_beta * quantity * hedge_price;
+    let net_beta_for = |quantity: Decimal| {
+        if equity > Decimal::ZERO {
+            (equity_beta_exposure + hedge_exposure_for(quantity)) / equity
+        } else {
+            Decimal::ZERO
+        }
+    };
+
+    let net_beta_before = net_beta_for(current_quantity);
+    let mut plan = HedgePlan {
+        equity_beta_exposure,
+        hedge_beta_exposure: hedge_exposure_for(current_quantity),
+        net_beta_before,
+        net_beta_after: net_beta_before,
+        current_quantity,
+        target_quantity: current_quantity,
+        order_quantity: Decimal::ZERO,
+        reason: String::new(),
+    };
+
+    if equity <= Decimal::ZERO || hedge_price <= Decimal::ZERO || settings.hedge_beta.is_zero() {
+        plan.reason = "No equity or hedge price".to_string();
+        return plan;
+    }
+
+    if (net_beta_before - settings.target_beta).abs() <= settings.band {
+        plan.reason = format!(
+            "Net beta {:.3} within band {} ± {}",
+            net_beta_before, settings.target_beta, settings.band
+        );
+        return plan;
+    }
+
+    // 목표 베타를 맞추는 헤지 수량 (인버스 ETF는 매수만, 선물은 매도만 허용)
+    let raw_target = (settings.target_beta * equity - equity_beta_exposure)
+        / (settings.hedge_beta * hedge_price);
+    let direction = if settings.hedge_beta < Decimal::ZERO {
+        Decimal::ONE
+    } else {
+        -Decimal::ONE
+    };
+    let amount = (raw_target * direction * hedge_price)
+        .max(Decimal::ZERO)
+        .min(settings.max_hedge_ratio * equity);
+    let target_quantity =
+        quantity_for_amount(&settings.hedge_ticker, amount, hedge_price) * direction;
+
+    plan.target_quantity = target_quantity;
+    plan.order_quantity = target_quantity - current_quantity;
+    plan.net_beta_after = net_beta_for(target_quantity);
+    plan.reason = format!(
+        "Net beta {:.3} outside band {} ± {}, target {:.3}",
+        net_beta_before, settings.target_beta, settings.band, plan.net_beta_after
+    );
+    plan
+}
+
+/// 헤지 체결 (매도는 음수 수량).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HedgeFill {
+    pub quantity: Decimal,
+    pub price: Decimal,
+}
+
+/// 헤지 포지션 손익 (평균 단가 기준).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct HedgePnl {
+    /// 현재 헤지 수량 (매도 헤지는 음수)
+    pub quantity: Decimal,
+    /// 평균 진입 단가
+    pub average_price: Decimal,
+    /// 실현 손익
+    pub realized_pnl: Decimal,
+    /// 미실현 손익 (평가 가격 기준)
+    pub unrealized_pnl: Decimal,
+}
+
+impl HedgePnl {
+    /// 총 손익.
+    pub fn total_pnl(&self) -> Decimal {
+        self.realized_pnl + self.unrealized_pnl
+    }
+}
+
+/// 체결 이력으로 헤지 손익 계산.
+///
+/// 같은 방향 체결은 평균 단가에 합산하고, 반대 방향 체결은 줄어든 수량만큼
+/// 실현 손익으로 계산합니다. 방향이 뒤집히면 남은 수량을 체결가로 새로 진입합니다.
+pub fn hedge_pnl(fills: &[HedgeFill], mark_price: Decimal) -> HedgePnl {
+    let mut pnl = HedgePnl::default();
+
+    for fill in fills.iter().filter(|f| !f.quantity.is_zero()) {
+        let same_direction = pnl.quantity.is_zero()
+            || pnl.quantity.is_sign_positive() == fill.quantity.is_sign_positive();
+
+        if same_direction {
+            let total = pnl.quantity + fill.quantity;
+            pnl.average_price =
+                (pnl.average_price * pnl.quantity + fill.price * fill.quantity) / total;
+            pnl.quantity = total;
+            continue;
+        }
+
+        let closed = fill.quantity.abs().min(pnl.quantity.abs());
+        let sign = if pnl.quantity > Decimal::ZERO {
+            Decimal::ONE
+        } else {
+            -Decimal::ONE
+        };
+        pnl.realized_pnl += (fill.price - pnl.average_price) * closed * sign;
+        pnl.quantity += fill.quantity;
+
+        if pnl.quantity.is_zero() {
+            pnl.average_price = Decimal::ZERO;
+        } else if pnl.quantity.is_sign_positive() == fill.quantity.is_sign_positive() {
+            pnl.average_price = fill.price;
+        }
+    }
+
+    pnl.unrealized_pnl = (mark_price - pnl.average_price) * pnl.quantity;
+    pnl
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal::prelude::FromPrimitive;
+
+    /// 숫자로부터 Decimal을 생성하는 헬퍼 매크로
+    macro_rules! dec {
+        ($val:expr) => {
+            Decimal::from_f64($val as f64).unwrap()
+        };
+    }
+
+    fn inverse_settings() -> HedgeSettings {
+        HedgeSettings {
+            hedge_ticker: "114800".to_string(),
+            hedge_beta: dec!(-1),
+            target_beta: dec!(0.3),
+            band: dec!(0.1),
+            max_hedge_ratio: dec!(1),
+        }
+    }
+
+    fn exposure(ticker: &str, market_value: Decimal, beta: Decimal) -> HedgeExposure {
+        HedgeExposure {
+            ticker: ticker.to_string(),
+            market_value,
+            beta,
+        }
+    }
+
+    #[test]
+    fn test_plan_hedge_inverse_etf() {
+        let settings = inverse_settings();
+        let exposures = vec![
+            exposure("005930", dec!(6_000_000), dec!(1.2)),
+            exposure("035720", dec!(2_000_000), dec!(0.9)),
+        ];
+
+        // 베타 노출 9,000,000 / 자산 10,000,000 = 0.9 → 목표 0.3까지 6,000,000 헤지
+        let plan = plan_hedge(
+            &settings,
+            &exposures,
+            Decimal::ZERO,
+            dec!(5000),
+            dec!(10_000_000),
+        );
+        assert_eq!(plan.equity_beta_exposure, dec!(9_000_000));
+        assert_eq!(plan.net_beta_before, dec!(0.9));
+        assert_eq!(plan.target_quantity, dec!(1200));
+        assert_eq!(plan.order_quantity, dec!(1200));
+        assert_eq!(plan.net_beta_after, dec!(0.3));
+
+        // 밴드 안이면 조정하지 않음
+        let plan = plan_hedge(
+            &settings,
+            &exposures,
+            dec!(1100),
+            dec!(5000),
+            dec!(10_000_000),
+        );
+        assert_eq!(plan.net_beta_before, dec!(0.35));
+        assert!(!plan.needs_rebalance());
+
+        // 노출이 줄어 과헤지되면 헤지를 축소하고, 목표 이하면 전량 청산
+        let plan = plan_hedge(
+            &settings,
+            &exposures[1..],
+            dec!(1200),
+            dec!(5000),
+            dec!(10_000_000),
+        );
+        assert_eq!(plan.target_quantity, Decimal::ZERO);
+        assert_eq!(plan.order_quantity, dec!(-1200));
+    }
+
+    #[test]
+    fn test_plan_hedge_futures_and_cap() {
+        let settings = HedgeSettings {
+            hedge_ticker: "101S".to_string(),
+            hedge_beta: dec!(1),
+            target_beta: Decimal::ZERO,
+            band: dec!(0.05),
+            max_hedge_ratio: dec!(0.5),
+        };
+        let exposures = vec![exposure("069500", dec!(10_000_000), dec!(1))];
+
+        // 완전 헤지에는 -10계약이 필요하지만 자산의 50%로 제한 → -5계약 매도
+        let plan = plan_hedge(
+            &settings,
+            &exposures,
+            Decimal::ZERO,
+            dec!(1_000_000),
+            dec!(10_000_000),
+        );
+        assert_eq!(plan.target_quantity, dec!(-5));
+        assert_eq!(plan.order_quantity, dec!(-5));
+        assert_eq!(plan.net_beta_after, dec!(0.5));
+
+        // 평가 자산이 없으면 조정하지 않음
+        let plan = plan_hedge(
+            &settings,
+            &exposures,
+            Decimal::ZERO,
+            dec!(1_000_000),
+            Decimal::ZERO,
+        );
+        assert!(!plan.needs_rebalance());
+    }
+
+    #[test]
+    fn test_hedge_pnl() {
+        let fills = [
+            HedgeFill {
+                quantity: dec!(100),
+                price: dec!(5000),
+            },
+            HedgeFill {
+                quantity: dec!(100),
+                price: dec!(6000),
+            },
+            HedgeFill {
+                quantity: dec!(-50),
+                price: dec!(6500),
+            },
+        ];
+        let pnl = hedge_pnl(&fills, dec!(5000));
+        assert_eq!(pnl.quantity, dec!(150));
+        assert_eq!(pnl.average_price, dec!(5500));
+        assert_eq!(pnl.realized_pnl, dec!(50_000));
+        assert_eq!(pnl.unrealized_pnl, dec!(-75_000));
+        assert_eq!(pnl.total_pnl(), dec!(-25_000));
+
+        // 매도 헤지 → 방향 전환
+        let fills = [
+            HedgeFill {
+                quantity: dec!(-2),
+                price: dec!(100),
+            },
+            HedgeFill {
+                quantity: dec!(3),
+                price: dec!(90),
+            },
+        ];
+        let pnl = hedge_pnl(&fills, dec!(95));
+        assert_eq!(pnl.realized_pnl, dec!(20));
+        assert_eq!(pnl.quantity, dec!(1));
+        assert_eq!(pnl.average_price, dec!(90));
+        assert_eq!(pnl.unrealized_pnl, dec!(5));
+    }
+}
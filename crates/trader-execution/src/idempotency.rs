@@ -0,0 +1,645 @@
+//! 주문 멱등성 키와 중복 제출 방지.
+//!
+//! 모든 주문 요청에 클라이언트 주문 ID(멱등성 키)를 부여하고, 같은 키의 주문 의도(intent)가
+//! 이미 진행 중이면 새 주문을 만들지 않습니다.
+//!
+//! - 키: 요청의 `client_order_id` (신호 `sig_…`, 바스켓 `basket_…`, 조건부 `cond_…`),
+//!   없으면 요청 ID로 만든 `zq_{요청 ID}`
+//! - 타임아웃/네트워크 오류로 결과를 알 수 없는 제출은 `Unknown`으로 남겨 같은 키의
+//!   재제출을 막습니다. 명확한 거부(`Failed`)만 같은 키로 다시 주문할 수 있습니다.
+//! - [`submit_idempotent`]는 재시도 전에 거래소 미체결 주문에서 이전 시도로 이미 접수된
+//!   주문을 먼저 찾습니다. KIS처럼 클라이언트 주문 ID를 돌려주지 않는 거래소는
+//!   종목/방향/수량과 제출 시각으로 대조합니다.
+//! - [`OrderIntentStore`]를 등록하면 의도를 DB에 기록하여 서버 재시작이나 여러 인스턴스
+//!   사이에서도 같은 키의 중복 주문을 막습니다.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tokio::sync::RwLock;
+use tracing::{info, warn};
+use trader_core::{OrderRequest, OrderStatus, Side};
+use trader_exchange::{with_retry_context, Exchange, ExchangeError, RetryConfig};
+use uuid::Uuid;
+
+/// 주문 의도 상태.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IntentState {
+    /// 등록됨, 거래소 접수 확인 전
+    Pending,
+    /// 거래소 접수 확인 (거래소 주문 ID 있음)
+    Submitted,
+    /// 타임아웃/네트워크 오류로 접수 여부를 알 수 없음
+    Unknown,
+    /// 거래소가 명확히 거부 (같은 키로 재주문 가능)
+    Failed,
+}
+
+impl IntentState {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Submitted => "submitted",
+            Self::Unknown => "unknown",
+            Self::Failed => "failed",
+        }
+    }
+}
+
+impl std::fmt::Display for IntentState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl std::str::FromStr for IntentState {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pending" => Ok(Self::Pending),
+            "submitted" => Ok(Self::Submitted),
+            "unknown" => Ok(Self::Unknown),
+            "failed" => Ok(Self::Failed),
+            other => Err(format!("unknown intent state: {}", other)),
+        }
+    }
+}
+
+/// 주문 의도 (멱등성 키 하나에 대응하는 주문).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OrderIntent {
+    /// 멱등성 키 (클라이언트 주문 ID)
+    pub key: String,
+    /// 내부 주문 ID
+    pub order_id: Uuid,
+    pub ticker: String,
+    pub side: Side,
+    pub quantity: Decimal,
+    pub price: Option<Decimal>,
+    pub state: IntentState,
+    /// 거래소 주문 ID (접수 확인 후)
+    pub exchange_order_id: Option<String>,
+    /// 마지막 제출 오류
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl OrderIntent {
+    /// 주문 요청으로부터 의도 생성 (`client_order_id`가 없으면 빈 키).
+    pub fn new(order_id: Uuid, request: &OrderRequest, now: DateTime<Utc>) -> Self {
+        Self {
+            key: request.client_order_id.clone().unwrap_or_default(),
+            order_id,
+            ticker: request.ticker.clone(),
+            side: request.side,
+            quantity: request.quantity,
+            price: request.price,
+            state: IntentState::Pending,
+            exchange_order_id: None,
+            last_error: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// 같은 키의 새 주문을 막는지 여부 (명확히 거부된 의도만 재사용 가능).
+    pub fn blocks_resubmit(&self) -> bool {
+        self.state != IntentState::Failed
+    }
+
+    /// 중복 주문 거부 사유.
+    pub fn duplicate_reason(&self) -> String {
+        match &self.exchange_order_id {
+            Some(exchange_order_id) => format!(
+                "Duplicate order intent '{}': order {} already {} as {}",
+                self.key, self.order_id, self.state, exchange_order_id
+            ),
+            None => format!(
+                "Duplicate order intent '{}': order {} is {}",
+                self.key, self.order_id, self.state
+            ),
+        }
+    }
+}
+
+/// 요청 ID로 클라이언트 주문 ID 생성.
+///
+/// 같은 요청 ID로 다시 처리하면 같은 키가 나오므로 재시도가 중복 주문이 되지 않습니다.
+pub fn client_order_id_for(request_id: Uuid) -> String {
+    format!("zq_{}", request_id.simple())
+}
+
+/// 결과를 알 수 없는 제출 오류인지 여부.
+///
+/// 타임아웃/연결 오류는 요청이 거래소에 도달했을 수 있으므로 거부로 보지 않습니다.
+pub fn is_outcome_unknown(error: &ExchangeError) -> bool {
+    matches!(
+        error,
+        ExchangeError::Timeout(_) | ExchangeError::NetworkError(_) | ExchangeError::Disconnected(_)
+    )
+}
+
+/// 주문 의도 등록 결과.
+#[derive(Debug, Clone, PartialEq)]
+pub enum IntentAdmission {
+    /// 새 의도로 등록됨
+    New,
+    /// 같은 키의 의도가 이미 진행 중
+    Duplicate(OrderIntent),
+}
+
+/// 주문 의도 영속 저장소 (DB 기반 중복 제거).
+#[async_trait]
+pub trait OrderIntentStore: Send + Sync {
+    /// 의도 선점. 같은 키의 유효한 의도가 이미 있으면 그 의도를 반환합니다.
+    async fn claim(&self, intent: &OrderIntent) -> Result<Option<OrderIntent>, String>;
+
+    /// 의도 상태 갱신.
+    async fn update(&self, intent: &OrderIntent) -> Result<(), String>;
+
+    /// 의도 삭제 (거래소에 보내지 않은 주문).
+    async fn release(&self, key: &str) -> Result<(), String>;
+}
+
+/// 주문 의도 가드.
+///
+/// 메모리에서 먼저 중복을 검사하고, 저장소가 등록되어 있으면 DB에서도 선점합니다.
+/// 저장소 오류 시에는 메모리 검사만으로 진행합니다.
+pub struct OrderIntentGuard {
+    intents: Mutex<HashMap<String, OrderIntent>>,
+    store: RwLock<Option<Arc<dyn OrderIntentStore>>>,
+    /// 의도를 메모리에 유지하는 기간
+    retention: chrono::Duration,
+}
+
+impl Default for OrderIntentGuard {
+    fn default() -> Self {
+        Self::new(chrono::Duration::days(1))
+    }
+}
+
+impl OrderIntentGuard {
+    /// 새 가드 생성.
+    pub fn new(retention: chrono::Duration) -> Self {
+        Self {
+            intents: Mutex::new(HashMap::new()),
+            store: RwLock::new(None),
+            retention,
+        }
+    }
+
+    /// 영속 저장소 등록 (None이면 해제).
+    pub async fn set_store(&self, store: Option<Arc<dyn OrderIntentStore>>) {
+        *self.store.write().await = store;
+    }
+
+    async fn store(&self) -> Option<Arc<dyn OrderIntentStore>> {
+        self.store.read().await.clone()
+    }
+
+    /// 의도 등록.
+    ///
+    /// 같은 키의 의도가 진행 중이면 새로 등록하지 않고 기존 의도를 반환합니다.
+    pub async fn claim(&self, intent: OrderIntent) -> IntentAdmission {
+        {
+            let mut intents = self.intents.lock().unwrap();
+            let cutoff = intent.created_at - self.retention;
+            intents.retain(|_, i| i.updated_at >= cutoff);
+            if let Some(existing) = intents.get(&intent.key).filter(|i| i.blocks_resubmit()) {
+                return IntentAdmission::Duplicate(existing.clone());
+            }
+            intents.insert(intent.key.clone(), intent.clone());
+        }
+
+        if let Some(store) = self.store().await {
+            match store.claim(&intent).await {
+                Ok(Some(existing)) => {
+                    let mut intents = self.intents.lock().unwrap();
+                    intents.insert(existing.key.clone(), existing.clone());
+                    return IntentAdmission::Duplicate(existing);
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    warn!(key = %intent.key, error = %e, "Failed to persist order intent");
+                }
+            }
+        }
+        IntentAdmission::New
+    }
+
+    /// 키로 의도 조회.
+    pub fn get(&self, key: &str) -> Option<OrderIntent> {
+        self.intents.lock().unwrap().get(key).cloned()
+    }
+
+    /// 내부 주문 ID로 의도 조회.
+    pub fn find_by_order(&self, order_id: Uuid) -> Option<OrderIntent> {
+        self.intents
+            .lock()
+            .unwrap()
+            .values()
+            .find(|i| i.order_id == order_id)
+            .cloned()
+    }
+
+    /// 접수 여부를 확인하지 못한 의도 (`Pending`, `Unknown`).
+    pub fn in_flight(&self) -> Vec<OrderIntent> {
+        let mut intents: Vec<OrderIntent> = self
+            .intents
+            .lock()
+            .unwrap()
+            .values()
+            .filter(|i| matches!(i.state, IntentState::Pending | IntentState::Unknown))
+            .cloned()
+            .collect();
+        intents.sort_by_key(|i| i.created_at);
+        intents
+    }
+
+    /// 주문의 의도 상태 변경 후 저장소에 반영.
+    async fn transition(&self, order_id: Uuid, apply: impl FnOnce(&mut OrderIntent)) {
+        let updated = {
+            let mut intents = self.intents.lock().unwrap();
+            let Some(intent) = intents.values_mut().find(|i| i.order_id == order_id) else {
+                return;
+            };
+            apply(intent);
+            intent.updated_at = Utc::now();
+            intent.clone()
+        };
+
+        if let Some(store) = self.store().await {
+            if let Err(e) = store.update(&updated).await {
+                warn!(key = %updated.key, error = %e, "Failed to update order intent");
+            }
+        }
+    }
+
+    /// 거래소 접수 확인.
+    pub async fn mark_submitted(&self, order_id: Uuid, exchange_order_id: &str) {
+        self.transition(order_id, |intent| {
+            intent.state = IntentState::Submitted;
+            intent.exchange_order_id = Some(exchange_order_id.to_string());
+            intent.last_error = None;
+        })
+        .await;
+    }
+
+    /// 제출 실패 기록 (결과를 알 수 없는 오류는 `Unknown`, 그 외는 `Failed`).
+    pub async fn mark_failed(&self, order_id: Uuid, error: &ExchangeError) {
+        let state = if is_outcome_unknown(error) {
+            IntentState::Unknown
+        } else {
+            IntentState::Failed
+        };
+        self.transition(order_id, |intent| {
+            intent.state = state;
+            intent.last_error = Some(error.to_string());
+        })
+        .await;
+    }
+
+    /// 거래소에 보내지 않은 주문의 의도 삭제 (같은 키로 다시 주문 가능).
+    pub async fn release(&self, order_id: Uuid) -> bool {
+        let released = {
+            let mut intents = self.intents.lock().unwrap();
+            let key = intents
+                .values()
+                .find(|i| i.order_id == order_id)
+                .map(|i| i.key.clone());
+            key.and_then(|key| intents.remove(&key))
+        };
+        let Some(intent) = released else {
+            return false;
+        };
+
+        if let Some(store) = self.store().await {
+            if let Err(e) = store.release(&intent.key).await {
+                warn!(key = %intent.key, error = %e, "Failed to release order intent");
+            }
+        }
+        true
+    }
+}
+
+/// 멱등 제출 결과.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IdempotentSubmit {
+    /// 거래소 주문 ID
+    pub exchange_order_id: String,
+    /// 총 시도 횟수
+    pub attempts: u32,
+    /// 재제출하지 않고 이전 시도로 접수된 주문을 찾은 경우
+    pub recovered: bool,
+}
+
+/// 거래소 미체결 주문에서 의도와 같은 주문 찾기.
+///
+/// 클라이언트 주문 ID가 일치하는 주문을 우선하고, 거래소가 클라이언트 주문 ID를
+/// 돌려주지 않으면(KIS) 의도 생성 이후에 갱신된 같은 종목/방향/수량의 주문 중
+/// 가장 최근 주문을 반환합니다. 호가 단위로 가격이 조정될 수 있어 가격은 비교하지 않습니다.
+pub fn match_submitted(orders: &[OrderStatus], intent: &OrderIntent) -> Option<String> {
+    if let Some(order) = orders
+        .iter()
+        .find(|o| o.client_order_id.as_deref() == Some(intent.key.as_str()))
+    {
+        return Some(order.order_id.clone());
+    }
+
+    orders
+        .iter()
+        .filter(|o| {
+            o.client_order_id.is_none()
+                && o.ticker.as_deref().map_or(true, |t| t == intent.ticker)
+                && o.side == Some(intent.side)
+                && o.quantity == Some(intent.quantity)
+                && o.updated_at >= intent.created_at
+        })
+        .max_by_key(|o| o.updated_at)
+        .map(|o| o.order_id.clone())
+}
+
+/// 멱등 주문 제출.
+///
+/// `with_retry_context`로 재시도하되, 재시도(또는 이전 결과가 `Unknown`인 의도)에서는
+/// 다시 보내기 전에 거래소 미체결 주문에서 이미 접수된 주문을 찾습니다. 찾으면
+/// 재제출하지 않고 그 주문 ID를 반환합니다. 조회가 실패하면 주문하지 않고 재시도합니다.
+///
+/// 미체결 주문만 조회하므로 즉시 전량 체결된 시장가 주문은 찾지 못합니다.
+/// 이 경우 체결통보/사후 대사로 확인해야 합니다.
+pub async fn submit_idempotent(
+    exchange: &dyn Exchange,
+    request: &OrderRequest,
+    intent: &OrderIntent,
+    retry: &RetryConfig,
+) -> Result<IdempotentSubmit, ExchangeError> {
+    let lookup_first = intent.state == IntentState::Unknown;
+    let ((exchange_order_id, recovered), stats) = with_retry_context(retry, |ctx| async move {
+        if ctx.attempt > 0 || lookup_first {
+            let open = exchange.get_open_orders(Some(&request.ticker)).await?;
+            if let Some(order_id) = match_submitted(&open, intent) {
+                info!(
+                    key = %intent.key,
+                    exchange_order_id = %order_id,
+                    attempt = ctx.attempt,
+                    "Found order accepted by earlier attempt; skipping resubmit"
+                );
+                return Ok((order_id, true));
+            }
+        }
+        exchange
+            .place_order(request)
+            .await
+            .map(|order_id| (order_id, false))
+    })
+    .await?;
+
+    Ok(IdempotentSubmit {
+        exchange_order_id,
+        attempts: stats.total_attempts,
+        recovered,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal::prelude::FromPrimitive;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::time::Duration;
+    use trader_core::{Kline, OrderBook, OrderStatusType, Ticker, Timeframe, TradeTick};
+    use trader_exchange::{
+        AccountInfo, Balance, ExchangeResult, SimulatedConfig, SimulatedExchange,
+    };
+
+    /// 숫자로부터 Decimal을 생성하는 헬퍼 매크로
+    macro_rules! dec {
+        ($val:expr) => {
+            Decimal::from_f64($val as f64).unwrap()
+        };
+    }
+
+    fn intent(key: &str, now: DateTime<Utc>) -> OrderIntent {
+        let request = OrderRequest::limit_buy("005930".to_string(), dec!(10), dec!(70000))
+            .with_client_id(key);
+        OrderIntent::new(Uuid::new_v4(), &request, now)
+    }
+
+    fn open_order(order_id: &str, client_order_id: Option<&str>, at: DateTime<Utc>) -> OrderStatus {
+        OrderStatus {
+            order_id: order_id.to_string(),
+            client_order_id: client_order_id.map(str::to_string),
+            ticker: Some("005930".to_string()),
+            side: Some(Side::Buy),
+            quantity: Some(dec!(10)),
+            price: Some(dec!(70000)),
+            status: OrderStatusType::Open,
+            filled_quantity: Decimal::ZERO,
+            average_price: None,
+            updated_at: at,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_guard_blocks_duplicates_until_rejected() {
+        let guard = OrderIntentGuard::default();
+        let now = Utc::now();
+        let first = intent("sig_1", now);
+
+        assert_eq!(guard.claim(first.clone()).await, IntentAdmission::New);
+        assert!(matches!(
+            guard.claim(intent("sig_1", now)).await,
+            IntentAdmission::Duplicate(ref i) if i.order_id == first.order_id
+        ));
+
+        // 타임아웃은 접수 여부를 알 수 없으므로 계속 차단
+        guard
+            .mark_failed(first.order_id, &ExchangeError::Timeout("kis".into()))
+            .await;
+        assert_eq!(guard.in_flight().len(), 1);
+        let IntentAdmission::Duplicate(existing) = guard.claim(intent("sig_1", now)).await else {
+            panic!("expected duplicate");
+        };
+        assert!(existing.duplicate_reason().contains("is unknown"));
+
+        // 명확한 거부 후에는 같은 키로 재주문 가능
+        guard
+            .mark_failed(
+                first.order_id,
+                &ExchangeError::OrderRejected("price".into()),
+            )
+            .await;
+        let retry = intent("sig_1", now);
+        assert_eq!(guard.claim(retry.clone()).await, IntentAdmission::New);
+        guard.mark_submitted(retry.order_id, "0000123").await;
+        assert_eq!(guard.get("sig_1").unwrap().state, IntentState::Submitted);
+        assert!(guard.in_flight().is_empty());
+
+        assert!(guard.release(retry.order_id).await);
+        assert!(guard.find_by_order(retry.order_id).is_none());
+    }
+
+    #[test]
+    fn test_match_submitted_by_client_id_or_attributes() {
+        let now = Utc::now();
+        let intent = intent("sig_1", now);
+
+        let echoed = vec![
+            open_order("A", Some("sig_other"), now),
+            open_order("B", Some("sig_1"), now),
+        ];
+        assert_eq!(match_submitted(&echoed, &intent), Some("B".to_string()));
+
+        // 클라이언트 주문 ID가 없는 거래소(KIS): 의도 이후의 같은 주문
+        let kis = vec![
+            open_order("OLD", None, now - chrono::Duration::minutes(5)),
+            open_order("NEW", None, now + chrono::Duration::seconds(1)),
+        ];
+        assert_eq!(match_submitted(&kis, &intent), Some("NEW".to_string()));
+        assert_eq!(match_submitted(&kis[..1], &intent), None);
+    }
+
+    /// 주문은 접수하지만 첫 응답을 타임아웃으로 잃는 거래소 (KIS처럼 클라이언트 주문 ID 미반환).
+    struct LostResponseExchange {
+        inner: SimulatedExchange,
+        timed_out: AtomicBool,
+    }
+
+    #[async_trait]
+    impl Exchange for LostResponseExchange {
+        fn name(&self) -> &str {
+            "LostResponseExchange"
+        }
+
+        async fn is_connected(&self) -> bool {
+            self.inner.is_connected().await
+        }
+
+        async fn connect(&mut self) -> ExchangeResult<()> {
+            self.inner.connect().await
+        }
+
+        async fn disconnect(&mut self) -> ExchangeResult<()> {
+            self.inner.disconnect().await
+        }
+
+        async fn get_account(&self) -> ExchangeResult<AccountInfo> {
+            self.inner.get_account().await
+        }
+
+        async fn get_balance(&self, asset: &str) -> ExchangeResult<Balance> {
+            self.inner.get_balance(asset).await
+        }
+
+        async fn get_ticker(&self, symbol: &str) -> ExchangeResult<Ticker> {
+            self.inner.get_ticker(symbol).await
+        }
+
+        async fn get_order_book(
+            &self,
+            symbol: &str,
+            limit: Option<u32>,
+        ) -> ExchangeResult<OrderBook> {
+            self.inner.get_order_book(symbol, limit).await
+        }
+
+        async fn get_recent_trades(
+            &self,
+            symbol: &str,
+            limit: Option<u32>,
+        ) -> ExchangeResult<Vec<TradeTick>> {
+            self.inner.get_recent_trades(symbol, limit).await
+        }
+
+        async fn get_klines(
+            &self,
+            symbol: &str,
+            timeframe: Timeframe,
+            limit: Option<u32>,
+        ) -> ExchangeResult<Vec<Kline>> {
+            self.inner.get_klines(symbol, timeframe, limit).await
+        }
+
+        async fn place_order(&self, request: &OrderRequest) -> ExchangeResult<String> {
+            let order_id = self.inner.place_order(request).await?;
+            if !self.timed_out.swap(true, Ordering::SeqCst) {
+                return Err(ExchangeError::Timeout("order response lost".into()));
+            }
+            Ok(order_id)
+        }
+
+        async fn cancel_order(&self, symbol: &str, order_id: &str) -> ExchangeResult<()> {
+            self.inner.cancel_order(symbol, order_id).await
+        }
+
+        async fn get_order(&self, symbol: &str, order_id: &str) -> ExchangeResult<OrderStatus> {
+            self.inner.get_order(symbol, order_id).await
+        }
+
+        async fn get_open_orders(&self, symbol: Option<&str>) -> ExchangeResult<Vec<OrderStatus>> {
+            let mut orders = self.inner.get_open_orders(symbol).await?;
+            for order in &mut orders {
+                order.client_order_id = None;
+            }
+            Ok(orders)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_retry_after_timeout_does_not_duplicate() {
+        let config = SimulatedConfig::default().with_initial_balance("USDT", dec!(1000000));
+        let inner = SimulatedExchange::new(config);
+        let start = Utc::now();
+        let klines = (0..2)
+            .map(|i| Kline {
+                ticker: "BTC/USDT".to_string(),
+                timeframe: Timeframe::M1,
+                open_time: start + chrono::Duration::minutes(i),
+                close_time: start + chrono::Duration::minutes(i + 1),
+                open: dec!(50000),
+                high: dec!(50000),
+                low: dec!(50000),
+                close: dec!(50000),
+                volume: dec!(100),
+                quote_volume: None,
+                num_trades: None,
+            })
+            .collect();
+        inner
+            .load_klines("BTC/USDT".to_string(), Timeframe::M1, klines)
+            .await;
+        inner.step("BTC/USDT", Timeframe::M1).await;
+        let exchange = LostResponseExchange {
+            inner,
+            timed_out: AtomicBool::new(false),
+        };
+
+        // 체결되지 않고 미체결로 남는 지정가 주문
+        let request = OrderRequest::limit_buy("BTC/USDT".to_string(), dec!(0.1), dec!(40000))
+            .with_client_id(client_order_id_for(Uuid::new_v4()));
+        let intent = OrderIntent::new(Uuid::new_v4(), &request, Utc::now());
+        let retry = RetryConfig {
+            max_retries: 2,
+            base_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(1),
+            add_jitter: false,
+            ..Default::default()
+        };
+
+        let submitted = submit_idempotent(&exchange, &request, &intent, &retry)
+            .await
+            .unwrap();
+        assert!(submitted.recovered);
+        assert_eq!(submitted.attempts, 2);
+
+        let open = exchange.get_open_orders(Some("BTC/USDT")).await.unwrap();
+        assert_eq!(open.len(), 1);
+        assert_eq!(open[0].order_id, submitted.exchange_order_id);
+    }
+}
@@ -0,0 +1,224 @@
+//! 회계 불변식 검사.
+//!
+//! 주문/포지션 장부가 항상 지켜야 하는 성질:
+//! - 수량은 음수가 될 수 없음 (주문 수량, 체결 수량, 포지션 수량)
+//! - 주문 체결 수량은 주문 수량을 넘을 수 없음
+//! - 미실현 손익 = (현재가 - 평균 진입가) × 수량 (숏은 부호 반대)
+//! - 누적 실현 손익 = 포지션별 실현 손익의 합
+//! - 현금 + 포지션 평가액 = 초기 자금 + 실현 손익 + 미실현 손익
+//!
+//! `OrderManager`와 `PositionTracker`는 체결을 반영할 때마다 검사하고, 위반이 있으면
+//! [`InvariantMonitor`]로 보고합니다. 디버그 빌드에서는 기본적으로 panic하여 테스트에서
+//! 바로 드러나고, 운영 빌드에서는 에러 로그와 등록된 훅(모니터링 에러 기록)만 남깁니다.
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::sync::Arc;
+use tracing::error;
+
+/// 불변식 종류.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InvariantKind {
+    /// 음수 수량
+    NegativeQuantity,
+    /// 주문 수량을 초과한 체결
+    Overfill,
+    /// 미실현 손익이 가격/수량과 불일치
+    UnrealizedPnlMismatch,
+    /// 누적 실현 손익이 포지션별 합계와 불일치
+    RealizedPnlMismatch,
+    /// 현금 + 포지션 평가액이 자산 총액과 불일치
+    EquityMismatch,
+}
+
+impl InvariantKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::NegativeQuantity => "negative_quantity",
+            Self::Overfill => "overfill",
+            Self::UnrealizedPnlMismatch => "unrealized_pnl_mismatch",
+            Self::RealizedPnlMismatch => "realized_pnl_mismatch",
+            Self::EquityMismatch => "equity_mismatch",
+        }
+    }
+}
+
+impl fmt::Display for InvariantKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// 불변식 위반 내역.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InvariantViolation {
+    pub kind: InvariantKind,
+    /// 관련 엔티티 (티커, 주문 ID, 장부 이름 등)
+    pub entity: String,
+    /// 기대값/실제값 등 상세 설명
+    pub detail: String,
+    pub timestamp: DateTime<Utc>,
+}
+
+impl InvariantViolation {
+    pub fn new(kind: InvariantKind, entity: impl Into<String>, detail: impl Into<String>) -> Self {
+        Self {
+            kind,
+            entity: entity.into(),
+            detail: detail.into(),
+            timestamp: Utc::now(),
+        }
+    }
+}
+
+impl fmt::Display for InvariantViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}] {}: {}", self.kind, self.entity, self.detail)
+    }
+}
+
+/// 위반 발생 시 호출되는 훅 (예: 모니터링 에러 기록).
+pub type InvariantHook = Arc<dyn Fn(&InvariantViolation) + Send + Sync>;
+
+/// 불변식 위반 보고기.
+#[derive(Clone)]
+pub struct InvariantMonitor {
+    hook: Option<InvariantHook>,
+    panic_on_violation: bool,
+    tolerance: Decimal,
+}
+
+impl Default for InvariantMonitor {
+    fn default() -> Self {
+        Self {
+            hook: None,
+            panic_on_violation: cfg!(debug_assertions),
+            // 평균 진입가 나눗셈의 반올림 오차 허용
+            tolerance: Decimal::new(1, 6),
+        }
+    }
+}
+
+impl fmt::Debug for InvariantMonitor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InvariantMonitor")
+            .field("hook", &self.hook.is_some())
+            .field("panic_on_violation", &self.panic_on_violation)
+            .field("tolerance", &self.tolerance)
+            .finish()
+    }
+}
+
+impl InvariantMonitor {
+    /// 기본 설정 (디버그 빌드에서만 panic, 허용 오차 0.000001).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 위반 훅 설정.
+    pub fn with_hook(mut self, hook: InvariantHook) -> Self {
+        self.hook = Some(hook);
+        self
+    }
+
+    /// 위반 시 panic 여부 설정.
+    pub fn with_panic_on_violation(mut self, enabled: bool) -> Self {
+        self.panic_on_violation = enabled;
+        self
+    }
+
+    /// 금액 비교 허용 오차 설정.
+    pub fn with_tolerance(mut self, tolerance: Decimal) -> Self {
+        self.tolerance = tolerance.abs();
+        self
+    }
+
+    /// 위반 훅 교체.
+    pub fn set_hook(&mut self, hook: InvariantHook) {
+        self.hook = Some(hook);
+    }
+
+    /// 금액 비교 허용 오차.
+    pub fn tolerance(&self) -> Decimal {
+        self.tolerance
+    }
+
+    /// 두 금액이 허용 오차 안에서 같은지 확인.
+    pub fn approx_eq(&self, a: Decimal, b: Decimal) -> bool {
+        (a - b).abs() <= self.tolerance
+    }
+
+    /// 위반 내역 보고.
+    ///
+    /// 에러 로그와 훅은 항상 실행되며, panic은 그 다음에 발생합니다.
+    pub fn report(&self, violations: &[InvariantViolation]) {
+        if violations.is_empty() {
+            return;
+        }
+
+        for violation in violations {
+            error!(
+                kind = %violation.kind,
+                entity = %violation.entity,
+                "회계 불변식 위반: {}",
+                violation.detail
+            );
+            if let Some(hook) = &self.hook {
+                hook(violation);
+            }
+        }
+
+        if self.panic_on_violation {
+            let messages: Vec<String> = violations.iter().map(|v| v.to_string()).collect();
+            panic!("accounting invariant violated: {}", messages.join("; "));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn test_report_calls_hook_without_panic() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = calls.clone();
+        let monitor = InvariantMonitor::new()
+            .with_panic_on_violation(false)
+            .with_hook(Arc::new(move |_: &InvariantViolation| {
+                counter.fetch_add(1, Ordering::SeqCst);
+            }));
+
+        monitor.report(&[]);
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+
+        monitor.report(&[
+            InvariantViolation::new(InvariantKind::Overfill, "order-1", "filled 2 > 1"),
+            InvariantViolation::new(InvariantKind::NegativeQuantity, "BTC/USDT", "-1"),
+        ]);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "accounting invariant violated")]
+    fn test_report_panics_when_enabled() {
+        InvariantMonitor::new()
+            .with_panic_on_violation(true)
+            .report(&[InvariantViolation::new(
+                InvariantKind::EquityMismatch,
+                "ledger",
+                "1 != 2",
+            )]);
+    }
+
+    #[test]
+    fn test_approx_eq() {
+        let monitor = InvariantMonitor::new().with_tolerance(Decimal::new(1, 2));
+        assert!(monitor.approx_eq(Decimal::new(1000, 2), Decimal::new(1001, 2)));
+        assert!(!monitor.approx_eq(Decimal::new(1000, 2), Decimal::new(1002, 2)));
+    }
+}
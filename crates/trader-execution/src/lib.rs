@@ -4,6 +4,25 @@
 //! - 시그널을 주문으로 변환하는 주문 실행기
 //! - 주문 상태 관리 및 추적
 //! - PnL 계산을 포함한 포지션 추적
+//! - 다중 계좌(모의/실전 일반/ISA) 포지션 추적과 계좌 간 상계 뷰
+//! - 전략별 가상 계좌 (자본, 손익, 익스포저, 낙폭을 전략 단위로 추적)
+//! - 회계 불변식 검사 (수량, 손익, 현금/자산 총액 일치)
+//! - 거래소별 주문 서킷 브레이커
+//! - 브로커 점검/장애 중 주문 거부 또는 대기열 보관 (degraded mode)
+//! - 주문 멱등성 키와 중복 제출 방지 (재시도 시 기존 접수 주문 재사용)
+//! - 주문 미리보기 (모의 실행)
+//! - 미국 주식 정규장 외 거래 리스크 검사
+//! - 유동성(ADV, 스프레드) 기반 진입 주문 수량 상한
+//! - 변동성 급등/스프레드 확대 시 진입 주문 조절 (수량 축소, 지정가 오프셋 확대, 보류)
+//! - 기본 리스크 프로필 (리스크 한도, 포지션 사이징, 실행 조절 묶음)
+//! - 바스켓 주문 (다종목 동시 실행)
+//! - 대량 주문 분할 실행 (TWAP, VWAP, 아이스버그)
+//! - 일괄 주문 매수 여력 예측
+//! - 정액 적립식(DCA) 투자 금액 계산
+//! - 포트폴리오 베타 헤지 수량 계산
+//! - 해외 주식 주문 전 자동 환전 계획
+//! - 브로커 잔고/포지션/체결과 로컬 상태의 사후 대사 및 보정 계획
+//! - 합성 종목(스프레드/비율) 신호의 leg 바스켓 주문 분해
 //! - 오류 복구 및 재시도 로직
 //!
 //! # 예제
@@ -18,13 +37,94 @@
 //! // 주문 및 포지션 처리
 //! ```
 
+pub mod account_positions;
+pub mod algos;
+pub mod basket;
+pub mod broker_downtime;
+pub mod dca;
+pub mod execution_governor;
 pub mod executor;
+pub mod extended_hours;
+pub mod funding;
+pub mod fx;
+pub mod hedge;
+pub mod idempotency;
+pub mod invariants;
+pub mod liquidity_cap;
+pub mod order_circuit;
 pub mod order_manager;
 pub mod position_tracker;
+pub mod preview;
+pub mod reconciliation;
+pub mod risk_profile;
+pub mod strategy_portfolio;
+pub mod synthetic;
 
 // 주요 타입 재내보내기
+pub use account_positions::{
+    net_positions, AccountPositionLeg, MultiAccountPositionTracker, NettedPosition,
+};
+pub use algos::{
+    plan_slices, AlgoChildOrder, AlgoError, AlgoExecutor, AlgoHandle, AlgoProgress, AlgoSlice,
+    AlgoStatus, AlgoStrategy, ParentOrder,
+};
+pub use basket::{
+    resolve_leg, BasketFailurePolicy, BasketLeg, BasketLegResult, BasketLegStatus, BasketRequest,
+    BasketResult, BasketTarget,
+};
+pub use broker_downtime::{
+    BrokerDowntimeConfig, BrokerDowntimeGuard, DowntimeAdmission, DowntimePolicy,
+};
+pub use dca::{
+    plan_contribution, quantity_for_amount, split_amount, DcaAllocation, DcaCadence,
+    DcaContribution, DcaMode, DcaSymbolState,
+};
+pub use execution_governor::{
+    check_execution_governor, ExecutionGovernorConfig, GovernorCheck, GovernorDecision,
+    GovernorRegime, MarketCondition,
+};
 pub use executor::{
-    ConversionConfig, ExecutionError, ExecutionResult, OrderExecutor, SignalConverter,
+    ConversionConfig, ExecutionError, ExecutionEvent, ExecutionResult, OrderExecutor,
+    SignalConverter,
+};
+pub use extended_hours::{
+    check_extended_hours, is_us_equity, ExtendedHoursCheck, ExtendedHoursConfig,
+};
+pub use funding::FundingForecast;
+pub use fx::{
+    plan_fx_conversion, FxApprovalMode, FxConversionDecision, FxConversionPlan, FxConversionPolicy,
+    FxConversionStatus, FxFundingSnapshot,
+};
+pub use hedge::{
+    hedge_pnl, plan_hedge, HedgeExposure, HedgeFill, HedgePlan, HedgePnl, HedgeSettings,
+};
+pub use idempotency::{
+    client_order_id_for, is_outcome_unknown, match_submitted, submit_idempotent, IdempotentSubmit,
+    IntentAdmission, IntentState, OrderIntent, OrderIntentGuard, OrderIntentStore,
+};
+pub use invariants::{InvariantHook, InvariantKind, InvariantMonitor, InvariantViolation};
+pub use liquidity_cap::{
+    check_liquidity_cap, LiquidityCapCheck, LiquidityCapConfig, LiquidityCapDecision,
+    LiquiditySnapshot,
+};
+pub use order_circuit::{
+    CircuitAdmission, OpenCircuitPolicy, OrderCircuitConfig, OrderCircuitEvent, OrderCircuitGuard,
+    OrderCircuitStatus,
 };
 pub use order_manager::{OrderEvent, OrderFill, OrderManager, OrderManagerError, OrderStats};
 pub use position_tracker::{PositionEvent, PositionTracker, PositionTrackerError};
+pub use preview::{BuyingPower, OrderPreview, PreviewOrder, PreviewOrderRole, PriceAdjustment};
+pub use reconciliation::{
+    normalize_order_id, plan_position_corrections, reconcile, Discrepancy, DiscrepancyKind,
+    ExecutionSnapshot, HoldingSnapshot, LocalSource, OrderSnapshot, PositionCorrection,
+    ReconciliationInput, ReconciliationReport, ReconciliationTolerance,
+};
+pub use risk_profile::{
+    diff_settings, settings_value, ExecutionThrottles, PositionSizingPolicy, RiskProfile,
+    RiskProfileName, SettingChange, SizingMethod,
+};
+pub use strategy_portfolio::{
+    strategy_key, StrategyPortfolioSnapshot, StrategyPortfolios, StrategySubPortfolio,
+    MANUAL_STRATEGY_ID,
+};
+pub use synthetic::plan_synthetic_basket;
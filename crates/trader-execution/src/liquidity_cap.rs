@@ -0,0 +1,318 @@
+//! 유동성 기반 주문 수량 상한.
+//!
+//! 최근 평균 거래량(ADV)과 호가 스프레드로 진입 주문 수량의 상한을 계산합니다.
+//! 소형주처럼 거래가 얇은 종목에 과도한 수량을 내면 체결되지 않거나
+//! 큰 시장 충격을 주므로, 주문을 거부하는 대신 수량을 줄이고 그 결정을 기록합니다.
+//!
+//! - `LiquidityGate`의 거래대금 단계(Pass/Relaxed/Fail)별로 ADV 대비 허용 비율 적용
+//! - 스프레드가 기준보다 넓으면 기준/스프레드 비율만큼 상한 추가 축소
+//! - 청산 주문은 축소하지 않음 (포지션이 묶이지 않도록)
+
+use chrono::{DateTime, Utc};
+use rust_decimal::{Decimal, RoundingStrategy};
+use serde::{Deserialize, Serialize};
+use trader_analytics::{LiquidityGate, LiquidityLevel};
+use trader_core::types::MarketType;
+use trader_core::OrderRequest;
+
+/// 유동성 수량 상한 설정.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LiquidityCapConfig {
+    /// 검사 활성화 여부
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    /// 거래대금 기본 기준 통과 종목의 ADV 대비 최대 수량 비율 (기본값: 5%)
+    #[serde(default = "default_max_adv_fraction")]
+    pub max_adv_fraction: Decimal,
+    /// 완화 기준 통과 종목의 ADV 대비 최대 수량 비율 (기본값: 2%)
+    #[serde(default = "default_relaxed_adv_fraction")]
+    pub relaxed_adv_fraction: Decimal,
+    /// 기준 미달 종목의 ADV 대비 최대 수량 비율 (기본값: 0.5%)
+    #[serde(default = "default_illiquid_adv_fraction")]
+    pub illiquid_adv_fraction: Decimal,
+    /// 상한을 줄이기 시작하는 스프레드 (%, 기본값: 0.3%)
+    #[serde(default = "default_reference_spread_pct")]
+    pub reference_spread_pct: Decimal,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+fn default_max_adv_fraction() -> Decimal {
+    Decimal::new(5, 2)
+}
+
+fn default_relaxed_adv_fraction() -> Decimal {
+    Decimal::new(2, 2)
+}
+
+fn default_illiquid_adv_fraction() -> Decimal {
+    Decimal::new(5, 3)
+}
+
+fn default_reference_spread_pct() -> Decimal {
+    Decimal::new(3, 1)
+}
+
+impl Default for LiquidityCapConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_enabled(),
+            max_adv_fraction: default_max_adv_fraction(),
+            relaxed_adv_fraction: default_relaxed_adv_fraction(),
+            illiquid_adv_fraction: default_illiquid_adv_fraction(),
+            reference_spread_pct: default_reference_spread_pct(),
+        }
+    }
+}
+
+impl LiquidityCapConfig {
+    /// 유동성 단계별 ADV 대비 허용 비율.
+    pub fn adv_fraction_for(&self, level: LiquidityLevel) -> Decimal {
+        match level {
+            LiquidityLevel::Pass => self.max_adv_fraction,
+            LiquidityLevel::Relaxed => self.relaxed_adv_fraction,
+            LiquidityLevel::Fail => self.illiquid_adv_fraction,
+        }
+    }
+}
+
+/// 종목의 최근 유동성 스냅샷.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LiquiditySnapshot {
+    /// 시장 유형 (`LiquidityGate` 기준 선택)
+    pub market_type: MarketType,
+    /// 최근 평균 일 거래량 (수량)
+    pub avg_daily_volume: Decimal,
+    /// 최근 평균 일 거래대금
+    pub avg_daily_amount: Decimal,
+    /// 호가 스프레드 (%, 없으면 스프레드 조정 안 함)
+    pub spread_pct: Option<Decimal>,
+    /// 산출 시각
+    pub as_of: DateTime<Utc>,
+}
+
+/// 수량 상한 결정 내역 (신호 메타데이터 `liquidity_cap`으로 기록).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LiquidityCapDecision {
+    /// 거래대금 단계 (pass, relaxed, fail)
+    pub level: String,
+    /// 적용한 ADV 대비 비율
+    pub adv_fraction: Decimal,
+    /// 스프레드 조정 계수 (1 = 조정 없음)
+    pub spread_factor: Decimal,
+    /// 허용 최대 수량
+    pub max_quantity: Decimal,
+    /// 원래 주문 수량
+    pub requested_quantity: Decimal,
+    /// 적용 수량 (거부 시 0)
+    pub quantity: Decimal,
+}
+
+impl LiquidityCapDecision {
+    /// 수량이 축소되었는지 여부.
+    pub fn is_capped(&self) -> bool {
+        self.quantity < self.requested_quantity
+    }
+
+    /// 수량 축소 안내 문구.
+    pub fn cap_note(&self) -> String {
+        format!(
+            "Quantity capped from {} to {} by liquidity ({} of ADV, {} liquidity, spread factor {})",
+            self.requested_quantity, self.quantity, self.adv_fraction, self.level, self.spread_factor
+        )
+    }
+
+    /// 거부 사유 문구.
+    pub fn rejection_reason(&self, ticker: &str) -> String {
+        format!(
+            "Liquidity cap {} ({} of ADV, {} liquidity) is below the minimum order size for {}",
+            self.max_quantity, self.adv_fraction, self.level, ticker
+        )
+    }
+}
+
+/// 유동성 수량 상한 검사 결과.
+#[derive(Debug, Clone, PartialEq)]
+pub enum LiquidityCapCheck {
+    /// 검사 대상 아님 (비활성화, 청산 주문, 스냅샷 없음)
+    NotApplicable,
+    /// 상한 이내라 그대로 허용
+    Approved(LiquidityCapDecision),
+    /// 상한으로 수량 축소 후 허용
+    Capped(LiquidityCapDecision),
+    /// 상한이 최소 주문 단위보다 작아 거부
+    Rejected(LiquidityCapDecision),
+}
+
+impl LiquidityCapCheck {
+    /// 기록할 결정 내역.
+    pub fn decision(&self) -> Option<&LiquidityCapDecision> {
+        match self {
+            LiquidityCapCheck::NotApplicable => None,
+            LiquidityCapCheck::Approved(d)
+            | LiquidityCapCheck::Capped(d)
+            | LiquidityCapCheck::Rejected(d) => Some(d),
+        }
+    }
+}
+
+fn level_label(level: LiquidityLevel) -> &'static str {
+    match level {
+        LiquidityLevel::Pass => "pass",
+        LiquidityLevel::Relaxed => "relaxed",
+        LiquidityLevel::Fail => "fail",
+    }
+}
+
+/// 시장별 수량 소수 자릿수 (주식은 정수 주, 암호화폐/외환은 소수 허용).
+pub(crate) fn quantity_scale(market_type: MarketType) -> u32 {
+    match market_type {
+        MarketType::Crypto | MarketType::Forex => 8,
+        _ => 0,
+    }
+}
+
+/// 유동성 기준 진입 주문 수량 상한 검사.
+///
+/// # Arguments
+///
+/// * `order` - 검사할 주문
+/// * `snapshot` - 종목의 최근 유동성 (없으면 검사 생략)
+/// * `is_entry` - 진입 주문 여부 (청산 주문은 축소하지 않음)
+/// * `config` - 수량 상한 설정
+pub fn check_liquidity_cap(
+    order: &OrderRequest,
+    snapshot: Option<&LiquiditySnapshot>,
+    is_entry: bool,
+    config: &LiquidityCapConfig,
+) -> LiquidityCapCheck {
+    let Some(snapshot) = snapshot else {
+        return LiquidityCapCheck::NotApplicable;
+    };
+    if !config.enabled || !is_entry || snapshot.avg_daily_volume <= Decimal::ZERO {
+        return LiquidityCapCheck::NotApplicable;
+    }
+
+    let level =
+        LiquidityGate::for_market(snapshot.market_type).check_level(snapshot.avg_daily_amount);
+    let adv_fraction = config.adv_fraction_for(level);
+
+    // 스프레드가 기준보다 넓으면 기준/스프레드 비율만큼 축소
+    let spread_factor = match snapshot.spread_pct {
+        Some(spread) if spread > config.reference_spread_pct && spread > Decimal::ZERO => {
+            config.reference_spread_pct / spread
+        }
+        _ => Decimal::ONE,
+    };
+
+    let max_quantity = (snapshot.avg_daily_volume * adv_fraction * spread_factor)
+        .round_dp_with_strategy(
+            quantity_scale(snapshot.market_type),
+            RoundingStrategy::ToZero,
+        )
+        .normalize();
+    let quantity = order.quantity.min(max_quantity);
+
+    let decision = LiquidityCapDecision {
+        level: level_label(level).to_string(),
+        adv_fraction,
+        spread_factor: spread_factor.round_dp(4).normalize(),
+        max_quantity,
+        requested_quantity: order.quantity,
+        quantity: quantity.max(Decimal::ZERO),
+    };
+
+    if quantity <= Decimal::ZERO {
+        LiquidityCapCheck::Rejected(decision)
+    } else if decision.is_capped() {
+        LiquidityCapCheck::Capped(decision)
+    } else {
+        LiquidityCapCheck::Approved(decision)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal::prelude::FromPrimitive;
+
+    /// 숫자로부터 Decimal을 생성하는 헬퍼 매크로
+    macro_rules! dec {
+        ($val:expr) => {
+            Decimal::from_f64($val as f64).unwrap()
+        };
+    }
+
+    fn snapshot(volume: Decimal, amount: Decimal, spread: Option<Decimal>) -> LiquiditySnapshot {
+        LiquiditySnapshot {
+            market_type: MarketType::KrStock,
+            avg_daily_volume: volume,
+            avg_daily_amount: amount,
+            spread_pct: spread,
+            as_of: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_liquid_stock_within_cap() {
+        let config = LiquidityCapConfig::default();
+        let order = OrderRequest::market_buy("005930".into(), dec!(100));
+        // 거래대금 200억원 (기본 기준 통과), ADV 100만주 → 상한 5만주
+        let snap = snapshot(dec!(1_000_000), dec!(20_000_000_000_i64), None);
+
+        let check = check_liquidity_cap(&order, Some(&snap), true, &config);
+        let LiquidityCapCheck::Approved(decision) = check else {
+            panic!("expected approved: {:?}", check);
+        };
+        assert_eq!(decision.level, "pass");
+        assert_eq!(decision.max_quantity, dec!(50000));
+        assert_eq!(decision.quantity, dec!(100));
+    }
+
+    #[test]
+    fn test_illiquid_small_cap_is_capped() {
+        let config = LiquidityCapConfig::default();
+        let order = OrderRequest::market_buy("123456".into(), dec!(1000));
+        // 거래대금 10억원 (기준 미달), ADV 20만주 → 0.5% = 1000주, 스프레드 0.6% → 절반
+        let snap = snapshot(dec!(200_000), dec!(1_000_000_000), Some(dec!(0.6)));
+
+        let check = check_liquidity_cap(&order, Some(&snap), true, &config);
+        let LiquidityCapCheck::Capped(decision) = check else {
+            panic!("expected capped: {:?}", check);
+        };
+        assert_eq!(decision.level, "fail");
+        assert_eq!(decision.spread_factor, dec!(0.5));
+        assert_eq!(decision.max_quantity, dec!(500));
+        assert_eq!(decision.quantity, dec!(500));
+        assert!(decision.is_capped());
+    }
+
+    #[test]
+    fn test_cap_below_one_share_rejects() {
+        let config = LiquidityCapConfig::default();
+        let order = OrderRequest::market_buy("123456".into(), dec!(10));
+        let snap = snapshot(dec!(100), dec!(1_000_000), None);
+
+        let check = check_liquidity_cap(&order, Some(&snap), true, &config);
+        assert!(matches!(check, LiquidityCapCheck::Rejected(_)));
+        assert_eq!(check.decision().unwrap().quantity, Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_exit_and_missing_snapshot_not_applicable() {
+        let config = LiquidityCapConfig::default();
+        let order = OrderRequest::market_sell("123456".into(), dec!(1000));
+        let snap = snapshot(dec!(100), dec!(1_000_000), None);
+
+        assert_eq!(
+            check_liquidity_cap(&order, Some(&snap), false, &config),
+            LiquidityCapCheck::NotApplicable
+        );
+        assert_eq!(
+            check_liquidity_cap(&order, None, true, &config),
+            LiquidityCapCheck::NotApplicable
+        );
+    }
+}
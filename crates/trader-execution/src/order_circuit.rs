@@ -0,0 +1,516 @@
+//! 거래소(venue)별 주문 서킷 브레이커.
+//!
+//! `trader-exchange`의 [`CircuitBreaker`]를 주문 제출 경로에 적용합니다.
+//!
+//! - 연속 주문 거부가 `failure_threshold`에 도달하면 Circuit Open
+//! - 네트워크/타임아웃/요청 한도 오류가 카테고리별 임계치에 도달하면 Circuit Open
+//! - Open 상태에서는 신규 주문을 거부하거나 대기열에 보관
+//! - `reset_timeout_ms` 경과 후 HalfOpen으로 전이되어 단일 탐색(probe) 주문만 허용
+//! - 탐색 주문이 성공하면 Closed로 복구되고 대기열 주문이 방출됨
+//!
+//! 상태 전이는 [`OrderCircuitEvent`]로 발행되어 모니터링/WebSocket에 전달됩니다.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+use tokio::sync::broadcast;
+use trader_exchange::{
+    CategoryThresholds, CircuitBreaker, CircuitBreakerConfig, CircuitState, ErrorCategory,
+    ExchangeError,
+};
+use uuid::Uuid;
+
+/// 이벤트 채널 버퍼 크기.
+const EVENT_CHANNEL_CAPACITY: usize = 64;
+
+/// Circuit이 열려 있을 때 신규 주문 처리 방식.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OpenCircuitPolicy {
+    /// 즉시 거부
+    #[default]
+    Reject,
+    /// 대기열에 보관 후 복구 시 방출
+    Queue,
+}
+
+/// 주문 서킷 브레이커 설정.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderCircuitConfig {
+    /// 기반 서킷 브레이커 설정
+    ///
+    /// `failure_threshold`는 연속 주문 거부 허용 횟수,
+    /// `category_thresholds`는 오류 유형별 허용 횟수로 사용됩니다.
+    #[serde(default)]
+    pub breaker: CircuitBreakerConfig,
+    /// Open 상태의 신규 주문 처리 방식
+    #[serde(default)]
+    pub open_policy: OpenCircuitPolicy,
+    /// 거래소별 최대 대기 주문 수 (초과 시 거부)
+    #[serde(default = "default_max_queued")]
+    pub max_queued: usize,
+}
+
+fn default_max_queued() -> usize {
+    100
+}
+
+impl Default for OrderCircuitConfig {
+    fn default() -> Self {
+        Self {
+            breaker: CircuitBreakerConfig::default()
+                .with_category_thresholds(CategoryThresholds::default()),
+            open_policy: OpenCircuitPolicy::default(),
+            max_queued: default_max_queued(),
+        }
+    }
+}
+
+/// 신규 주문 허용 판정 결과.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CircuitAdmission {
+    /// 정상 허용
+    Allowed,
+    /// HalfOpen 상태의 복구 탐색 주문으로 허용
+    Probe,
+    /// 대기열에 보관됨 (대기 순번, 1부터)
+    Queued(usize),
+    /// 거부됨
+    Rejected(String),
+}
+
+impl CircuitAdmission {
+    /// 즉시 제출 가능한지 여부.
+    pub fn is_allowed(&self) -> bool {
+        matches!(self, CircuitAdmission::Allowed | CircuitAdmission::Probe)
+    }
+}
+
+/// 주문 서킷 상태 전이 이벤트.
+#[derive(Debug, Clone, Serialize)]
+pub struct OrderCircuitEvent {
+    /// 거래소 식별자
+    pub venue: String,
+    /// 이전 상태
+    pub from: String,
+    /// 새 상태
+    pub to: String,
+    /// 전이 사유
+    pub reason: String,
+    /// 전이를 유발한 주문의 전략 ID
+    pub strategy_id: Option<String>,
+    /// 대기 중인 주문 수
+    pub queued_orders: usize,
+    pub timestamp: DateTime<Utc>,
+}
+
+/// 거래소별 주문 서킷 상태 요약 (모니터링용).
+#[derive(Debug, Clone, Serialize)]
+pub struct OrderCircuitStatus {
+    /// 거래소 식별자
+    pub venue: String,
+    /// 현재 상태 (closed, open, half_open)
+    pub state: String,
+    /// 현재 연속 실패 횟수
+    pub failure_count: u32,
+    /// 총 실패 횟수
+    pub total_failures: u64,
+    /// 총 성공 횟수
+    pub total_successes: u64,
+    /// Circuit Open 횟수
+    pub open_count: u64,
+    /// 현재 상태 유지 시간 (초)
+    pub state_duration_secs: u64,
+    /// Circuit을 연 에러 카테고리 (연속 거부로 열린 경우 None)
+    pub tripped_by: Option<ErrorCategory>,
+    /// 마지막 실패 사유
+    pub last_failure: Option<String>,
+    /// 복구 탐색 주문 진행 여부
+    pub probe_in_flight: bool,
+    /// 대기 중인 주문 수
+    pub queued_orders: usize,
+}
+
+/// 거래소 하나의 서킷 상태.
+struct VenueCircuit {
+    breaker: CircuitBreaker,
+    /// 마지막으로 관측한 상태 (전이 감지용)
+    observed: CircuitState,
+    probe_in_flight: bool,
+    last_failure: Option<String>,
+    queue: VecDeque<Uuid>,
+}
+
+/// 거래소별 주문 서킷 브레이커 관리자.
+pub struct OrderCircuitGuard {
+    config: OrderCircuitConfig,
+    venues: Mutex<HashMap<String, VenueCircuit>>,
+    events: broadcast::Sender<OrderCircuitEvent>,
+}
+
+impl Default for OrderCircuitGuard {
+    fn default() -> Self {
+        Self::new(OrderCircuitConfig::default())
+    }
+}
+
+impl OrderCircuitGuard {
+    /// 새 관리자 생성.
+    pub fn new(config: OrderCircuitConfig) -> Self {
+        let (events, _) = broadcast::channel(EVENT_CHANNEL_CAPACITY);
+        Self {
+            config,
+            venues: Mutex::new(HashMap::new()),
+            events,
+        }
+    }
+
+    /// 설정 반환.
+    pub fn config(&self) -> &OrderCircuitConfig {
+        &self.config
+    }
+
+    /// 상태 전이 이벤트 구독.
+    pub fn subscribe(&self) -> broadcast::Receiver<OrderCircuitEvent> {
+        self.events.subscribe()
+    }
+
+    /// 신규 주문 허용 여부 판정.
+    ///
+    /// Open 상태이거나 HalfOpen에서 탐색 주문이 진행 중이면
+    /// `open_policy`에 따라 대기열에 보관하거나 거부합니다.
+    pub fn admit(
+        &self,
+        venue: &str,
+        order_id: Uuid,
+        strategy_id: Option<&str>,
+    ) -> CircuitAdmission {
+        self.with_venue(venue, strategy_id, |circuit| {
+            match circuit.breaker.state() {
+                CircuitState::Closed => return (CircuitAdmission::Allowed, None),
+                CircuitState::HalfOpen if !circuit.probe_in_flight => {
+                    circuit.probe_in_flight = true;
+                    return (
+                        CircuitAdmission::Probe,
+                        Some("reset timeout elapsed, probing recovery".to_string()),
+                    );
+                }
+                _ => {}
+            }
+
+            let admission = match self.config.open_policy {
+                OpenCircuitPolicy::Queue if circuit.queue.len() < self.config.max_queued => {
+                    circuit.queue.push_back(order_id);
+                    CircuitAdmission::Queued(circuit.queue.len())
+                }
+                OpenCircuitPolicy::Queue => CircuitAdmission::Rejected(format!(
+                    "Order circuit for '{}' is open and queue is full ({})",
+                    venue, self.config.max_queued
+                )),
+                OpenCircuitPolicy::Reject => CircuitAdmission::Rejected(format!(
+                    "Order circuit for '{}' is {}",
+                    venue,
+                    circuit.breaker.state()
+                )),
+            };
+            (admission, None)
+        })
+    }
+
+    /// 주문 제출 성공 기록.
+    pub fn record_success(&self, venue: &str, strategy_id: Option<&str>) {
+        self.with_venue(venue, strategy_id, |circuit| {
+            circuit.probe_in_flight = false;
+            circuit.breaker.record_success();
+            ((), Some("order accepted".to_string()))
+        })
+    }
+
+    /// 거래소의 주문 거부 기록.
+    ///
+    /// 연속 거부 횟수가 `failure_threshold`에 도달하면 Circuit이 열립니다.
+    pub fn record_rejection(&self, venue: &str, strategy_id: Option<&str>, reason: &str) {
+        self.with_venue(venue, strategy_id, |circuit| {
+            circuit.probe_in_flight = false;
+            circuit.last_failure = Some(reason.to_string());
+            circuit.breaker.record_failure();
+            ((), Some(format!("order rejected: {}", reason)))
+        })
+    }
+
+    /// 주문 제출 중 발생한 거래소 오류 기록.
+    ///
+    /// 네트워크/타임아웃/요청 한도 오류는 카테고리별 임계치로 집계하고,
+    /// 그 외 오류는 주문 거부로 취급합니다.
+    pub fn record_error(&self, venue: &str, strategy_id: Option<&str>, error: &ExchangeError) {
+        let reason = error.to_string();
+        self.with_venue(venue, strategy_id, |circuit| {
+            circuit.probe_in_flight = false;
+            circuit.last_failure = Some(reason.clone());
+            match ErrorCategory::from_error(error) {
+                Some(category) => circuit.breaker.record_failure_with_category(category),
+                None => circuit.breaker.record_failure(),
+            }
+            ((), Some(format!("order error: {}", reason)))
+        })
+    }
+
+    /// 대기열에서 주문 제거 (취소된 주문 등).
+    pub fn dequeue(&self, venue: &str, order_id: Uuid) -> bool {
+        let mut venues = self.venues.lock().unwrap();
+        let Some(circuit) = venues.get_mut(venue) else {
+            return false;
+        };
+        let before = circuit.queue.len();
+        circuit.queue.retain(|id| *id != order_id);
+        circuit.queue.len() != before
+    }
+
+    /// Circuit이 닫혀 있으면 대기열 주문을 모두 방출.
+    pub fn take_released(&self, venue: &str) -> Vec<Uuid> {
+        self.with_venue(venue, None, |circuit| {
+            if circuit.breaker.state() == CircuitState::Closed {
+                (circuit.queue.drain(..).collect(), None)
+            } else {
+                (Vec::new(), None)
+            }
+        })
+    }
+
+    /// 모든 거래소의 상태를 점검하여 타임아웃에 따른 전이 이벤트 발행.
+    ///
+    /// Open → HalfOpen 전이는 상태 조회 시점에 일어나므로
+    /// 주기적으로 호출해야 복구 탐색 가능 상태가 즉시 알려집니다.
+    pub fn poll(&self) {
+        let venues: Vec<String> = self.venues.lock().unwrap().keys().cloned().collect();
+        for venue in venues {
+            self.with_venue(&venue, None, |circuit| {
+                circuit.breaker.state();
+                (
+                    (),
+                    Some("reset timeout elapsed, probing recovery".to_string()),
+                )
+            });
+        }
+    }
+
+    /// 수동으로 Circuit 리셋.
+    ///
+    /// # Returns
+    /// 해당 거래소의 서킷이 존재했는지 여부
+    pub fn reset(&self, venue: &str) -> bool {
+        if !self.venues.lock().unwrap().contains_key(venue) {
+            return false;
+        }
+        self.with_venue(venue, None, |circuit| {
+            circuit.probe_in_flight = false;
+            circuit.breaker.reset();
+            ((), Some("manual reset".to_string()))
+        });
+        true
+    }
+
+    /// 특정 거래소의 현재 상태.
+    pub fn state(&self, venue: &str) -> CircuitState {
+        if !self.venues.lock().unwrap().contains_key(venue) {
+            return CircuitState::Closed;
+        }
+        self.with_venue(venue, None, |circuit| (circuit.breaker.state(), None))
+    }
+
+    /// 모든 거래소의 상태 요약 (거래소 이름순).
+    pub fn statuses(&self) -> Vec<OrderCircuitStatus> {
+        self.poll();
+
+        let venues = self.venues.lock().unwrap();
+        let mut statuses: Vec<OrderCircuitStatus> = venues
+            .iter()
+            .map(|(venue, circuit)| {
+                let metrics = circuit.breaker.metrics();
+                OrderCircuitStatus {
+                    venue: venue.clone(),
+                    state: metrics.state.to_string(),
+                    failure_count: metrics.failure_count,
+                    total_failures: metrics.total_failures,
+                    total_successes: metrics.total_successes,
+                    open_count: metrics.open_count,
+                    state_duration_secs: metrics.time_in_current_state.as_secs(),
+                    tripped_by: metrics.tripped_by,
+                    last_failure: circuit.last_failure.clone(),
+                    probe_in_flight: circuit.probe_in_flight,
+                    queued_orders: circuit.queue.len(),
+                }
+            })
+            .collect();
+        statuses.sort_by(|a, b| a.venue.cmp(&b.venue));
+        statuses
+    }
+
+    /// 거래소 서킷에 작업을 적용하고 상태가 바뀌었으면 이벤트 발행.
+    ///
+    /// 클로저는 결과와 함께 전이 사유를 반환합니다 (None이면 상태 이름 사용).
+    fn with_venue<R>(
+        &self,
+        venue: &str,
+        strategy_id: Option<&str>,
+        f: impl FnOnce(&mut VenueCircuit) -> (R, Option<String>),
+    ) -> R {
+        let mut venues = self.venues.lock().unwrap();
+        let circuit = venues
+            .entry(venue.to_string())
+            .or_insert_with(|| VenueCircuit {
+                breaker: CircuitBreaker::new(
+                    format!("orders:{}", venue),
+                    self.config.breaker.clone(),
+                ),
+                observed: CircuitState::Closed,
+                probe_in_flight: false,
+                last_failure: None,
+                queue: VecDeque::new(),
+            });
+
+        let (result, reason) = f(circuit);
+
+        // metrics()는 타임아웃 전이를 일으키지 않으므로 작업 직후 상태를 그대로 관측
+        let current = circuit.breaker.metrics().state;
+        if current != circuit.observed {
+            if current != CircuitState::HalfOpen {
+                circuit.probe_in_flight = false;
+            }
+            let event = OrderCircuitEvent {
+                venue: venue.to_string(),
+                from: circuit.observed.to_string(),
+                to: current.to_string(),
+                reason: reason.unwrap_or_else(|| current.to_string()),
+                strategy_id: strategy_id.map(str::to_string),
+                queued_orders: circuit.queue.len(),
+                timestamp: Utc::now(),
+            };
+            circuit.observed = current;
+            // 구독자가 없으면 전송 실패는 무시
+            let _ = self.events.send(event);
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn guard(policy: OpenCircuitPolicy, reset_timeout_secs: u64) -> OrderCircuitGuard {
+        OrderCircuitGuard::new(OrderCircuitConfig {
+            breaker: CircuitBreakerConfig::new(3, reset_timeout_secs, 1)
+                .with_category_thresholds(CategoryThresholds::default()),
+            open_policy: policy,
+            max_queued: 2,
+        })
+    }
+
+    #[test]
+    fn test_consecutive_rejections_open_circuit() {
+        let guard = guard(OpenCircuitPolicy::Reject, 60);
+        let mut events = guard.subscribe();
+
+        guard.record_rejection("kis", None, "insufficient margin");
+        guard.record_success("kis", None);
+        guard.record_rejection("kis", None, "r1");
+        guard.record_rejection("kis", None, "r2");
+        assert_eq!(guard.state("kis"), CircuitState::Closed);
+
+        guard.record_rejection("kis", Some("s1"), "r3");
+        assert_eq!(guard.state("kis"), CircuitState::Open);
+
+        let event = events.try_recv().unwrap();
+        assert_eq!(event.from, "closed");
+        assert_eq!(event.to, "open");
+        assert_eq!(event.strategy_id.as_deref(), Some("s1"));
+
+        let admission = guard.admit("kis", Uuid::new_v4(), None);
+        assert!(matches!(admission, CircuitAdmission::Rejected(_)));
+
+        // 다른 거래소는 영향 없음
+        assert_eq!(
+            guard.admit("binance", Uuid::new_v4(), None),
+            CircuitAdmission::Allowed
+        );
+    }
+
+    #[test]
+    fn test_error_burst_uses_category_threshold() {
+        let guard = guard(OpenCircuitPolicy::Reject, 60);
+
+        for _ in 0..4 {
+            guard.record_error("kis", None, &ExchangeError::RateLimited);
+        }
+        // RateLimit 기본 임계치는 10회
+        assert_eq!(guard.state("kis"), CircuitState::Closed);
+
+        for _ in 0..5 {
+            guard.record_error("kis", None, &ExchangeError::Timeout("slow".into()));
+        }
+        assert_eq!(guard.state("kis"), CircuitState::Open);
+
+        let status = &guard.statuses()[0];
+        assert_eq!(status.tripped_by, Some(ErrorCategory::Timeout));
+        assert!(status.last_failure.as_deref().unwrap().contains("slow"));
+    }
+
+    #[test]
+    fn test_queue_and_release_after_probe() {
+        let guard = guard(OpenCircuitPolicy::Queue, 0);
+        for i in 0..3 {
+            guard.record_rejection("kis", None, &format!("r{}", i));
+        }
+
+        // reset_timeout 0 → 즉시 HalfOpen, 첫 주문은 탐색 주문
+        let probe = Uuid::new_v4();
+        assert_eq!(guard.admit("kis", probe, None), CircuitAdmission::Probe);
+
+        let (a, b, c) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        assert_eq!(guard.admit("kis", a, None), CircuitAdmission::Queued(1));
+        assert_eq!(guard.admit("kis", b, None), CircuitAdmission::Queued(2));
+        assert!(matches!(
+            guard.admit("kis", c, None),
+            CircuitAdmission::Rejected(_)
+        ));
+
+        // 복구 전에는 방출되지 않음
+        assert!(guard.take_released("kis").is_empty());
+        assert!(guard.dequeue("kis", b));
+
+        guard.record_success("kis", None);
+        assert_eq!(guard.state("kis"), CircuitState::Closed);
+        assert_eq!(guard.take_released("kis"), vec![a]);
+        assert_eq!(guard.statuses()[0].queued_orders, 0);
+    }
+
+    #[test]
+    fn test_failed_probe_reopens_and_reset() {
+        let guard = guard(OpenCircuitPolicy::Reject, 0);
+        let mut events = guard.subscribe();
+        for i in 0..3 {
+            guard.record_rejection("kis", None, &format!("r{}", i));
+        }
+        assert_eq!(
+            guard.admit("kis", Uuid::new_v4(), None),
+            CircuitAdmission::Probe
+        );
+        guard.record_rejection("kis", None, "still failing");
+
+        let transitions: Vec<(String, String)> = std::iter::from_fn(|| events.try_recv().ok())
+            .map(|e| (e.from, e.to))
+            .collect();
+        assert_eq!(transitions[0], ("closed".to_string(), "open".to_string()));
+        assert!(transitions.contains(&("half_open".to_string(), "open".to_string())));
+
+        assert!(guard.reset("kis"));
+        assert!(!guard.reset("unknown"));
+        assert_eq!(
+            guard.admit("kis", Uuid::new_v4(), None),
+            CircuitAdmission::Allowed
+        );
+    }
+}
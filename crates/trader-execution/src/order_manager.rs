@@ -4,6 +4,7 @@
 //! - 주문 생명주기 추적
 //! - 주문 장부 유지 관리
 //! - 주문 이벤트 처리
+//! - 체결 수량 불변식 검사 (음수 수량, 초과 체결)
 //! - 조회 기능
 
 use chrono::{DateTime, Utc};
@@ -14,6 +15,8 @@ use thiserror::Error;
 use trader_core::{Order, OrderRequest, OrderStatus, OrderStatusType, Side};
 use uuid::Uuid;
 
+use crate::invariants::{InvariantHook, InvariantKind, InvariantMonitor, InvariantViolation};
+
 /// 주문 관리자 에러 타입.
 #[derive(Debug, Error)]
 pub enum OrderManagerError {
@@ -140,6 +143,8 @@ pub struct OrderManager {
     fills: Vec<OrderFill>,
     /// 최대 이력 크기
     max_history_size: usize,
+    /// 회계 불변식 위반 보고기
+    invariants: InvariantMonitor,
 }
 
 impl Default for OrderManager {
@@ -160,6 +165,7 @@ impl OrderManager {
             events: Vec::new(),
             fills: Vec::new(),
             max_history_size: 10000,
+            invariants: InvariantMonitor::default(),
         }
     }
 
@@ -171,6 +177,17 @@ impl OrderManager {
         }
     }
 
+    /// 회계 불변식 보고기를 설정한다.
+    pub fn with_invariant_monitor(mut self, monitor: InvariantMonitor) -> Self {
+        self.invariants = monitor;
+        self
+    }
+
+    /// 불변식 위반 훅을 설정한다.
+    pub fn set_invariant_hook(&mut self, hook: InvariantHook) {
+        self.invariants.set_hook(hook);
+    }
+
     // ==================== 주문 생성 ====================
 
     /// 요청으로부터 새 주문을 생성하고 추적한다.
@@ -331,6 +348,7 @@ impl OrderManager {
             }
         }
 
+        self.verify_order(order_id, None);
         Ok(())
     }
 
@@ -402,6 +420,8 @@ impl OrderManager {
             }
         }
 
+        self.verify_order(fill.order_id, Some(&fill));
+
         // 체결 저장
         self.fills.push(fill);
         self.trim_history();
@@ -623,8 +643,37 @@ impl OrderManager {
         }
     }
 
+    // ==================== 불변식 ====================
+
+    /// 모든 주문의 수량 불변식을 검사하고 위반 내역을 반환한다.
+    ///
+    /// - 주문 수량, 체결 수량 ≥ 0
+    /// - 체결 수량 ≤ 주문 수량
+    pub fn check_invariants(&self) -> Vec<InvariantViolation> {
+        self.orders.values().flat_map(check_order).collect()
+    }
+
     // ==================== 내부 ====================
 
+    /// 주문 하나(와 방금 반영한 체결)의 불변식을 검사해 보고한다.
+    fn verify_order(&self, order_id: Uuid, fill: Option<&OrderFill>) {
+        let mut violations = self
+            .orders
+            .get(&order_id)
+            .map(check_order)
+            .unwrap_or_default();
+
+        if let Some(fill) = fill.filter(|f| f.quantity < Decimal::ZERO) {
+            violations.push(InvariantViolation::new(
+                InvariantKind::NegativeQuantity,
+                order_id.to_string(),
+                format!("fill quantity {}", fill.quantity),
+            ));
+        }
+
+        self.invariants.report(&violations);
+    }
+
     fn record_event(&mut self, event: OrderEvent) {
         self.events.push(event);
         self.trim_history();
@@ -674,6 +723,35 @@ impl OrderManager {
     }
 }
 
+/// 주문 수량 불변식 검사.
+fn check_order(order: &Order) -> Vec<InvariantViolation> {
+    let mut violations = Vec::new();
+    let entity = order.id.to_string();
+
+    if order.quantity < Decimal::ZERO || order.filled_quantity < Decimal::ZERO {
+        violations.push(InvariantViolation::new(
+            InvariantKind::NegativeQuantity,
+            &entity,
+            format!(
+                "{} quantity {}, filled {}",
+                order.ticker, order.quantity, order.filled_quantity
+            ),
+        ));
+    }
+    if order.filled_quantity > order.quantity {
+        violations.push(InvariantViolation::new(
+            InvariantKind::Overfill,
+            &entity,
+            format!(
+                "{} filled {} > quantity {}",
+                order.ticker, order.filled_quantity, order.quantity
+            ),
+        ));
+    }
+
+    violations
+}
+
 /// 주문 통계.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OrderStats {
@@ -693,7 +771,7 @@ pub struct OrderStats {
 mod tests {
     use super::*;
     use rust_decimal::prelude::FromPrimitive;
-    use trader_core::{OrderRequest, Symbol};
+    use trader_core::OrderRequest;
 
     /// Decimal 생성을 위한 헬퍼 매크로
     macro_rules! dec {
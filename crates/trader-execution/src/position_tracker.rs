@@ -2,7 +2,10 @@
 //!
 //! 제공 기능:
 //! - 주문 체결에 따른 실시간 포지션 업데이트
+//! - 부분 청산(비율/목표 비중) 및 평균단가 기준 실현 손익
 //! - 손익(PnL) 추적 및 계산
+//! - 체결 현금 흐름 장부와 회계 불변식 검사
+//! - 지수선물 포지션 (거래승수 반영 손익, 명목금액 없이 손익만 현금 정산)
 //! - 포지션 조회 및 집계
 
 use chrono::{DateTime, Utc};
@@ -10,9 +13,10 @@ use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use thiserror::Error;
-use trader_core::{Order, Position, PositionSummary, Side};
+use trader_core::{FuturesContract, Order, Position, PositionSummary, ScaleOut, Side};
 use uuid::Uuid;
 
+use crate::invariants::{InvariantHook, InvariantKind, InvariantMonitor, InvariantViolation};
 use crate::order_manager::OrderFill;
 
 /// 포지션 트래커 에러 타입.
@@ -51,11 +55,13 @@ pub enum PositionEvent {
         new_total: Decimal,
         timestamp: DateTime<Utc>,
     },
-    /// 포지션 감소
+    /// 포지션 감소 (마지막 청산 체결 포함)
     Decreased {
         position_id: Uuid,
         quantity: Decimal,
         price: Decimal,
+        /// 청산 시점 평균 진입가
+        entry_price: Decimal,
         realized_pnl: Decimal,
         remaining: Decimal,
         timestamp: DateTime<Utc>,
@@ -115,8 +121,39 @@ pub struct PositionTracker {
     events: Vec<PositionEvent>,
     /// 거래소 이름
     exchange: String,
+    /// 계좌 키 (다중 계좌 운용 시, 예: "real_isa")
+    account: Option<String>,
     /// 최대 히스토리 크기
     max_history_size: usize,
+    /// 초기 자금
+    initial_cash: Decimal,
+    /// 체결 현금 흐름 반영 후 현금 (매수/숏 커버 시 감소, 매도/숏 진입 시 증가)
+    cash: Decimal,
+    /// 누적 실현 손익 (거래 비용 차감 후)
+    realized_total: Decimal,
+    /// 히스토리 정리로 제거된 종료 포지션의 실현 손익 합
+    archived_realized: Decimal,
+    /// 회계 불변식 위반 보고기
+    invariants: InvariantMonitor,
+    /// 심볼별 선물 계약 명세 (미등록 국내 지수선물 코드는 기본 명세 사용)
+    futures_contracts: HashMap<String, FuturesContract>,
+}
+
+/// 포지션 방향을 반영한 금액 (롱 +, 숏 -).
+fn signed(side: Side, value: Decimal) -> Decimal {
+    match side {
+        Side::Buy => value,
+        Side::Sell => -value,
+    }
+}
+
+/// 선물 포지션의 미실현 손익을 거래승수 기준(원)으로 환산한다.
+///
+/// `Position`은 포인트 단위로 손익을 계산하므로 가격/수량 변경 직후 호출한다.
+fn scale_unrealized(position: &mut Position, multiplier: Option<Decimal>) {
+    if let Some(multiplier) = multiplier {
+        position.unrealized_pnl *= multiplier;
+    }
 }
 
 impl PositionTracker {
@@ -129,7 +166,14 @@ impl PositionTracker {
             closed_positions: Vec::new(),
             events: Vec::new(),
             exchange: exchange.into(),
+            account: None,
             max_history_size: 10000,
+            initial_cash: Decimal::ZERO,
+            cash: Decimal::ZERO,
+            realized_total: Decimal::ZERO,
+            archived_realized: Decimal::ZERO,
+            invariants: InvariantMonitor::default(),
+            futures_contracts: HashMap::new(),
         }
     }
 
@@ -141,6 +185,85 @@ impl PositionTracker {
         }
     }
 
+    /// 초기 자금을 설정한다 (생성 직후 호출).
+    ///
+    /// 설정하지 않으면 0에서 시작하며, 현금이 음수이면 외부 자금으로 매수한 것으로 본다.
+    pub fn with_initial_cash(mut self, cash: Decimal) -> Self {
+        self.set_initial_cash(cash);
+        self
+    }
+
+    /// 초기 자금을 변경한다 (차액만큼 현금에 반영, 전략 자본 입출금 등).
+    pub fn set_initial_cash(&mut self, cash: Decimal) {
+        self.cash += cash - self.initial_cash;
+        self.initial_cash = cash;
+    }
+
+    /// 초기 자금을 가져온다.
+    pub fn initial_cash(&self) -> Decimal {
+        self.initial_cash
+    }
+
+    /// 계좌 키를 설정한다 (`MultiAccountPositionTracker`가 계좌별 트래커를 만들 때 사용).
+    pub fn with_account(mut self, account: impl Into<String>) -> Self {
+        self.account = Some(account.into());
+        self
+    }
+
+    /// 계좌 키 (단일 계좌 운용이면 `None`).
+    pub fn account(&self) -> Option<&str> {
+        self.account.as_deref()
+    }
+
+    /// 회계 불변식 보고기를 설정한다.
+    pub fn with_invariant_monitor(mut self, monitor: InvariantMonitor) -> Self {
+        self.invariants = monitor;
+        self
+    }
+
+    /// 불변식 위반 훅을 설정한다.
+    pub fn set_invariant_hook(&mut self, hook: InvariantHook) {
+        self.invariants.set_hook(hook);
+    }
+
+    /// 선물 계약 명세를 등록한다 (증거금률·만기를 기본값과 다르게 쓸 때).
+    ///
+    /// 해당 종목에 이미 오픈 포지션이 있으면 거래승수가 달라질 수 있어 등록을 거부한다.
+    pub fn register_futures_contract(
+        &mut self,
+        contract: FuturesContract,
+    ) -> Result<(), PositionTrackerError> {
+        if self.positions_by_symbol.contains_key(&contract.code) {
+            return Err(PositionTrackerError::InvalidOperation(format!(
+                "Cannot register futures contract {} while a position is open",
+                contract.code
+            )));
+        }
+        self.futures_contracts
+            .insert(contract.code.clone(), contract);
+        Ok(())
+    }
+
+    /// 심볼의 선물 계약 명세 (등록된 명세 우선, 국내 지수선물 코드는 기본 명세).
+    pub fn futures_contract(&self, symbol: &str) -> Option<FuturesContract> {
+        self.futures_contracts
+            .get(symbol)
+            .cloned()
+            .or_else(|| FuturesContract::from_code(symbol))
+    }
+
+    /// 선물 심볼의 거래승수 (선물이 아니면 `None`).
+    fn futures_multiplier(&self, symbol: &str) -> Option<Decimal> {
+        self.futures_contract(symbol).map(|c| c.multiplier)
+    }
+
+    /// 포지션 종목의 거래승수 (선물이 아니면 `None`).
+    fn position_multiplier(&self, position_id: Uuid) -> Option<Decimal> {
+        self.positions
+            .get(&position_id)
+            .and_then(|p| self.futures_multiplier(&p.ticker))
+    }
+
     // ==================== 포지션 생성 ====================
 
     /// 새 포지션을 오픈한다.
@@ -171,7 +294,10 @@ impl PositionTracker {
         let position_id = position.id;
         let now = Utc::now();
 
-        // 포지션 저장
+        // 포지션 저장 (선물은 증거금만 묶이므로 명목금액 현금 흐름 없음)
+        if self.futures_contract(&symbol_str).is_none() {
+            self.cash -= signed(side, quantity * price);
+        }
         self.positions.insert(position_id, position.clone());
         self.positions_by_symbol
             .insert(symbol_str.clone(), position_id);
@@ -195,6 +321,7 @@ impl PositionTracker {
         });
 
         self.trim_history();
+        self.verify_invariants();
 
         Ok(position)
     }
@@ -222,10 +349,9 @@ impl PositionTracker {
                 // 반대 방향 - 포지션 감소
                 self.reduce_position_internal(pos_id, fill.quantity, fill.price)?;
             }
+            self.verify_invariants();
 
-            self.positions
-                .get(&pos_id)
-                .cloned()
+            self.find_position(pos_id)
                 .ok_or(PositionTrackerError::PositionNotFound(pos_id))
         } else {
             // 포지션 없음 - 새로 오픈
@@ -255,6 +381,7 @@ impl PositionTracker {
             .ok_or_else(|| PositionTrackerError::SymbolPositionNotFound(symbol.to_string()))?;
 
         self.increase_position_internal(pos_id, quantity, price)?;
+        self.verify_invariants();
         self.positions
             .get(&pos_id)
             .cloned()
@@ -275,21 +402,84 @@ impl PositionTracker {
             .ok_or_else(|| PositionTrackerError::SymbolPositionNotFound(symbol.to_string()))?;
 
         let pnl = self.reduce_position_internal(pos_id, quantity, price)?;
-        // 열린 포지션에서 먼저 찾고, 없으면 종료된 포지션에서 찾기
+        self.verify_invariants();
         let position = self
-            .positions
-            .get(&pos_id)
-            .cloned()
-            .or_else(|| {
-                self.closed_positions
-                    .iter()
-                    .find(|p| p.id == pos_id)
-                    .cloned()
-            })
+            .find_position(pos_id)
             .ok_or(PositionTrackerError::PositionNotFound(pos_id))?;
         Ok((position, pnl))
     }
 
+    /// 보유 수량의 일부 비율을 청산한다.
+    ///
+    /// `ratio`는 0 초과 1 이하이며, 1이면 전량 청산과 같다.
+    /// 남은 수량의 평균 진입가는 유지되고 청산분의 손익만 실현된다.
+    pub fn close_partial(
+        &mut self,
+        symbol: &str,
+        ratio: Decimal,
+        price: Decimal,
+    ) -> Result<(Position, Decimal), PositionTrackerError> {
+        if ratio <= Decimal::ZERO || ratio > Decimal::ONE {
+            return Err(PositionTrackerError::InvalidOperation(format!(
+                "Close ratio must be in (0, 1]: {}",
+                ratio
+            )));
+        }
+
+        self.scale_out(symbol, ScaleOut::Ratio(ratio), Decimal::ZERO, price)
+    }
+
+    /// 포트폴리오 가치 대비 목표 비중까지 청산한다.
+    ///
+    /// 현재 비중이 이미 목표 이하이면 `InvalidOperation`을 반환한다.
+    pub fn close_to_weight(
+        &mut self,
+        symbol: &str,
+        target_weight: Decimal,
+        portfolio_value: Decimal,
+        price: Decimal,
+    ) -> Result<(Position, Decimal), PositionTrackerError> {
+        if target_weight < Decimal::ZERO || target_weight > Decimal::ONE {
+            return Err(PositionTrackerError::InvalidOperation(format!(
+                "Target weight must be in [0, 1]: {}",
+                target_weight
+            )));
+        }
+
+        self.scale_out(
+            symbol,
+            ScaleOut::TargetWeight(target_weight),
+            portfolio_value,
+            price,
+        )
+    }
+
+    /// 부분 청산 지시에 따라 포지션을 감소시킨다.
+    ///
+    /// 청산 수량이 0이면 `InvalidOperation`을 반환한다.
+    pub fn scale_out(
+        &mut self,
+        symbol: &str,
+        scale_out: ScaleOut,
+        portfolio_value: Decimal,
+        price: Decimal,
+    ) -> Result<(Position, Decimal), PositionTrackerError> {
+        let held = self
+            .get_position_for_symbol(symbol)
+            .map(|p| p.quantity)
+            .ok_or_else(|| PositionTrackerError::SymbolPositionNotFound(symbol.to_string()))?;
+
+        let quantity = scale_out.close_quantity(held, price, portfolio_value);
+        if quantity.is_zero() {
+            return Err(PositionTrackerError::InvalidOperation(format!(
+                "Nothing to close for {} ({:?})",
+                symbol, scale_out
+            )));
+        }
+
+        self.reduce_position(symbol, quantity, price)
+    }
+
     /// 포지션을 완전히 종료한다.
     pub fn close_position(
         &mut self,
@@ -317,13 +507,18 @@ impl PositionTracker {
         quantity: Decimal,
         price: Decimal,
     ) -> Result<(), PositionTrackerError> {
+        let multiplier = self.position_multiplier(position_id);
         let position = self
             .positions
             .get_mut(&position_id)
             .ok_or(PositionTrackerError::PositionNotFound(position_id))?;
 
         position.add(quantity, price);
+        scale_unrealized(position, multiplier);
         let new_total = position.quantity;
+        if multiplier.is_none() {
+            self.cash -= signed(position.side, quantity * price);
+        }
         let now = Utc::now();
 
         self.events.push(PositionEvent::Increased {
@@ -344,6 +539,7 @@ impl PositionTracker {
         quantity: Decimal,
         price: Decimal,
     ) -> Result<Decimal, PositionTrackerError> {
+        let multiplier = self.position_multiplier(position_id);
         let position = self
             .positions
             .get_mut(&position_id)
@@ -356,10 +552,34 @@ impl PositionTracker {
             ));
         }
 
-        let pnl = position.reduce(quantity, price);
+        let entry_price = position.entry_price;
+        let mut pnl = position.reduce(quantity, price);
         let remaining = position.quantity;
+        match multiplier {
+            // 선물: 포인트 손익 × 거래승수만 현금 정산
+            Some(multiplier) => {
+                let points = pnl;
+                pnl = points * multiplier;
+                position.realized_pnl += pnl - points;
+                scale_unrealized(position, Some(multiplier));
+                self.cash += pnl;
+            }
+            None => self.cash += signed(position.side, quantity * price),
+        }
+        self.realized_total += pnl;
         let now = Utc::now();
 
+        // 마지막 청산분도 체결 단위 기록을 남김
+        self.events.push(PositionEvent::Decreased {
+            position_id,
+            quantity,
+            price,
+            entry_price,
+            realized_pnl: pnl,
+            remaining,
+            timestamp: now,
+        });
+
         if position.is_closed() {
             // 포지션 완전 종료
             let final_pnl = position.realized_pnl;
@@ -389,15 +609,6 @@ impl PositionTracker {
                 final_pnl,
                 timestamp: now,
             });
-        } else {
-            self.events.push(PositionEvent::Decreased {
-                position_id,
-                quantity,
-                price,
-                realized_pnl: pnl,
-                remaining,
-                timestamp: now,
-            });
         }
 
         self.trim_history();
@@ -409,12 +620,31 @@ impl PositionTracker {
         &mut self,
         symbol: &str,
         new_price: Decimal,
+    ) -> Result<(), PositionTrackerError> {
+        self.update_price_internal(symbol, new_price)?;
+        self.verify_invariants();
+        Ok(())
+    }
+
+    /// 모든 포지션의 가격을 업데이트한다.
+    pub fn update_prices(&mut self, prices: &HashMap<String, Decimal>) {
+        for (symbol, price) in prices {
+            let _ = self.update_price_internal(symbol, *price);
+        }
+        self.verify_invariants();
+    }
+
+    fn update_price_internal(
+        &mut self,
+        symbol: &str,
+        new_price: Decimal,
     ) -> Result<(), PositionTrackerError> {
         let pos_id = self
             .positions_by_symbol
             .get(symbol)
             .copied()
             .ok_or_else(|| PositionTrackerError::SymbolPositionNotFound(symbol.to_string()))?;
+        let multiplier = self.futures_multiplier(symbol);
 
         let position = self
             .positions
@@ -423,6 +653,7 @@ impl PositionTracker {
 
         let old_price = position.current_price;
         position.update_price(new_price);
+        scale_unrealized(position, multiplier);
         let unrealized_pnl = position.unrealized_pnl;
 
         self.events.push(PositionEvent::PriceUpdated {
@@ -437,10 +668,29 @@ impl PositionTracker {
         Ok(())
     }
 
-    /// 모든 포지션의 가격을 업데이트한다.
-    pub fn update_prices(&mut self, prices: &HashMap<String, Decimal>) {
-        for (symbol, price) in prices {
-            let _ = self.update_price(symbol, *price);
+    /// 거래 비용(수수료/세금)을 포지션 실현 손익에서 차감한다.
+    ///
+    /// 청산 체결로 종료 목록으로 이동한 포지션도 대상이며,
+    /// 포지션을 찾지 못하면 `false`를 반환한다.
+    pub fn charge_cost(&mut self, position_id: Uuid, cost: Decimal) -> bool {
+        let position = match self.positions.get_mut(&position_id) {
+            Some(position) => Some(position),
+            None => self
+                .closed_positions
+                .iter_mut()
+                .rev()
+                .find(|p| p.id == position_id),
+        };
+
+        match position {
+            Some(position) => {
+                position.realized_pnl -= cost;
+                self.cash -= cost;
+                self.realized_total -= cost;
+                self.verify_invariants();
+                true
+            }
+            None => false,
         }
     }
 
@@ -511,9 +761,48 @@ impl PositionTracker {
         open_realized + closed_realized
     }
 
-    /// 총 명목 익스포저를 가져온다.
+    /// 누적 실현 손익을 가져온다 (히스토리에서 정리된 종료 포지션 포함).
+    pub fn cumulative_realized_pnl(&self) -> Decimal {
+        self.realized_total
+    }
+
+    /// 총 명목 익스포저를 가져온다 (선물은 거래승수 반영).
     pub fn total_exposure(&self) -> Decimal {
-        self.positions.values().map(|p| p.notional_value()).sum()
+        self.positions
+            .values()
+            .map(|p| {
+                p.notional_value() * self.futures_multiplier(&p.ticker).unwrap_or(Decimal::ONE)
+            })
+            .sum()
+    }
+
+    /// 체결 현금 흐름을 반영한 현금을 가져온다.
+    pub fn cash(&self) -> Decimal {
+        self.cash
+    }
+
+    /// 현금 + 포지션 평가액 (숏은 평가액을 부채로 차감, 선물은 미실현 손익만 반영).
+    pub fn equity(&self) -> Decimal {
+        self.cash
+            + self
+                .positions
+                .values()
+                .map(|p| match self.futures_multiplier(&p.ticker) {
+                    Some(_) => p.unrealized_pnl,
+                    None => signed(p.side, p.notional_value()),
+                })
+                .sum::<Decimal>()
+    }
+
+    /// 선물 포지션의 총 위탁증거금 (현재가 기준).
+    pub fn futures_margin_requirement(&self) -> Decimal {
+        self.positions
+            .values()
+            .filter_map(|p| {
+                self.futures_contract(&p.ticker)
+                    .map(|c| c.initial_margin(p.current_price, p.quantity))
+            })
+            .sum()
     }
 
     /// 전략별 포트폴리오 손익을 가져온다.
@@ -555,8 +844,96 @@ impl PositionTracker {
             .collect()
     }
 
+    // ==================== 불변식 ====================
+
+    /// 회계 불변식을 검사하고 위반 내역을 반환한다.
+    ///
+    /// - 포지션 수량 ≥ 0
+    /// - 포지션 미실현 손익 = 방향 × (현재가 - 평균 진입가) × 수량 (× 선물 거래승수)
+    /// - 누적 실현 손익 = 오픈/종료 포지션 실현 손익 합 (정리된 히스토리 포함)
+    /// - 현금 + 포지션 평가액 = 초기 자금 + 누적 실현 손익 + 미실현 손익
+    pub fn check_invariants(&self) -> Vec<InvariantViolation> {
+        let mut violations = Vec::new();
+
+        for position in self.positions.values() {
+            if position.quantity < Decimal::ZERO {
+                violations.push(InvariantViolation::new(
+                    InvariantKind::NegativeQuantity,
+                    &position.ticker,
+                    format!("position quantity {}", position.quantity),
+                ));
+            }
+
+            let multiplier = self
+                .futures_multiplier(&position.ticker)
+                .unwrap_or(Decimal::ONE);
+            let expected = signed(
+                position.side,
+                (position.current_price - position.entry_price) * position.quantity * multiplier,
+            );
+            if !self.invariants.approx_eq(position.unrealized_pnl, expected) {
+                violations.push(InvariantViolation::new(
+                    InvariantKind::UnrealizedPnlMismatch,
+                    &position.ticker,
+                    format!(
+                        "unrealized {} != expected {}",
+                        position.unrealized_pnl, expected
+                    ),
+                ));
+            }
+        }
+
+        let position_realized: Decimal = self
+            .positions
+            .values()
+            .chain(self.closed_positions.iter())
+            .map(|p| p.realized_pnl)
+            .sum();
+        let realized = self.archived_realized + position_realized;
+        if !self.invariants.approx_eq(self.realized_total, realized) {
+            violations.push(InvariantViolation::new(
+                InvariantKind::RealizedPnlMismatch,
+                &self.exchange,
+                format!(
+                    "ledger realized {} != positions realized {}",
+                    self.realized_total, realized
+                ),
+            ));
+        }
+
+        let expected_equity = self.initial_cash + self.realized_total + self.total_unrealized_pnl();
+        let equity = self.equity();
+        if !self.invariants.approx_eq(equity, expected_equity) {
+            violations.push(InvariantViolation::new(
+                InvariantKind::EquityMismatch,
+                &self.exchange,
+                format!(
+                    "cash {} + positions = {} != initial + pnl {}",
+                    self.cash, equity, expected_equity
+                ),
+            ));
+        }
+
+        violations
+    }
+
     // ==================== 내부 ====================
 
+    fn verify_invariants(&self) {
+        self.invariants.report(&self.check_invariants());
+    }
+
+    /// 오픈 포지션 또는 종료된 포지션을 ID로 찾는다.
+    fn find_position(&self, position_id: Uuid) -> Option<Position> {
+        self.positions.get(&position_id).cloned().or_else(|| {
+            self.closed_positions
+                .iter()
+                .rev()
+                .find(|p| p.id == position_id)
+                .cloned()
+        })
+    }
+
     fn trim_history(&mut self) {
         if self.events.len() > self.max_history_size {
             let drain_count = self.events.len() - self.max_history_size;
@@ -564,14 +941,26 @@ impl PositionTracker {
         }
         if self.closed_positions.len() > self.max_history_size {
             let drain_count = self.closed_positions.len() - self.max_history_size;
-            self.closed_positions.drain(0..drain_count);
+            let archived: Decimal = self
+                .closed_positions
+                .drain(0..drain_count)
+                .map(|p| p.realized_pnl)
+                .sum();
+            self.archived_realized += archived;
         }
     }
 
     /// 오래된 종료 포지션을 정리한다.
     pub fn cleanup_old_positions(&mut self, older_than: DateTime<Utc>) {
-        self.closed_positions
-            .retain(|p| p.closed_at.map(|t| t >= older_than).unwrap_or(true));
+        let mut archived = Decimal::ZERO;
+        self.closed_positions.retain(|p| {
+            let keep = p.closed_at.map(|t| t >= older_than).unwrap_or(true);
+            if !keep {
+                archived += p.realized_pnl;
+            }
+            keep
+        });
+        self.archived_realized += archived;
     }
 }
 
@@ -684,6 +1073,98 @@ mod tests {
         assert_eq!(tracker.get_closed_positions().len(), 1);
     }
 
+    #[test]
+    fn test_close_partial_scale_out() {
+        let mut tracker = PositionTracker::new("binance");
+        let symbol = create_test_symbol();
+
+        tracker
+            .open_position(symbol, Side::Buy, dec!(1.0), dec!(50000), None)
+            .unwrap();
+        tracker
+            .add_to_position("BTC/USDT", dec!(1.0), dec!(40000))
+            .unwrap();
+
+        // 평균가 45000 기준 50% 청산: (50000 - 45000) * 1.0 = 5000
+        let (position, pnl) = tracker
+            .close_partial("BTC/USDT", dec!(0.5), dec!(50000))
+            .unwrap();
+        assert_eq!(pnl, dec!(5000));
+        assert_eq!(position.quantity, dec!(1.0));
+        assert_eq!(position.entry_price, dec!(45000));
+
+        // 남은 수량 전량 청산: (44000 - 45000) * 1.0 = -1000
+        let (position, pnl) = tracker
+            .close_partial("BTC/USDT", dec!(1.0), dec!(44000))
+            .unwrap();
+        assert_eq!(pnl, dec!(-1000));
+        assert!(position.is_closed());
+        assert_eq!(position.realized_pnl, dec!(4000));
+        assert_eq!(tracker.total_realized_pnl(), dec!(4000));
+
+        // 체결별 청산 기록 (마지막 청산 포함) + 종료 이벤트
+        let decreases: Vec<_> = tracker
+            .get_position_events(position.id)
+            .into_iter()
+            .filter_map(|e| match e {
+                PositionEvent::Decreased {
+                    quantity,
+                    entry_price,
+                    realized_pnl,
+                    ..
+                } => Some((*quantity, *entry_price, *realized_pnl)),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            decreases,
+            vec![
+                (dec!(1.0), dec!(45000), dec!(5000)),
+                (dec!(1.0), dec!(45000), dec!(-1000)),
+            ]
+        );
+        assert!(matches!(
+            tracker.get_events().last(),
+            Some(PositionEvent::Closed { .. })
+        ));
+
+        tracker
+            .open_position(
+                "ETH/USDT".to_string(),
+                Side::Buy,
+                dec!(1.0),
+                dec!(3000),
+                None,
+            )
+            .unwrap();
+        assert!(matches!(
+            tracker.close_partial("ETH/USDT", dec!(0), dec!(3000)),
+            Err(PositionTrackerError::InvalidOperation(_))
+        ));
+    }
+
+    #[test]
+    fn test_close_to_weight() {
+        let mut tracker = PositionTracker::new("krx");
+
+        tracker
+            .open_position("005930".to_string(), Side::Buy, dec!(30), dec!(90000), None)
+            .unwrap();
+
+        // 포트폴리오 1,000만원 중 10% → 10주(@100,000)만 유지
+        let (position, pnl) = tracker
+            .close_to_weight("005930", dec!(0.1), dec!(10000000), dec!(100000))
+            .unwrap();
+        assert_eq!(position.quantity, dec!(10));
+        assert_eq!(pnl, dec!(200000));
+
+        // 이미 목표 비중 이하
+        assert!(matches!(
+            tracker.close_to_weight("005930", dec!(0.1), dec!(10000000), dec!(100000)),
+            Err(PositionTrackerError::InvalidOperation(_))
+        ));
+    }
+
     #[test]
     fn test_short_position_pnl() {
         let mut tracker = PositionTracker::new("binance");
@@ -945,4 +1426,92 @@ mod tests {
         assert_eq!(position.quantity, dec!(0.1));
         assert_eq!(position.realized_pnl, dec!(500)); // 손익: (55000-50000)*0.1
     }
+
+    #[test]
+    fn test_apply_fill_close_position() {
+        let mut tracker = PositionTracker::new("binance");
+        let symbol = "BTC/USDT".to_string();
+
+        tracker
+            .open_position(symbol.clone(), Side::Buy, dec!(0.1), dec!(50000), None)
+            .unwrap();
+
+        let order = trader_core::Order::from_request(
+            trader_core::OrderRequest::market_sell(symbol.clone(), dec!(0.1)),
+            "binance",
+        );
+
+        let fill = OrderFill {
+            order_id: order.id,
+            quantity: dec!(0.1),
+            price: dec!(55000),
+            commission: None,
+            commission_asset: None,
+            timestamp: Utc::now(),
+        };
+
+        // 전량 청산 체결은 종료된 포지션을 반환
+        let position = tracker.apply_fill(&order, &fill).unwrap();
+
+        assert!(position.is_closed());
+        assert_eq!(position.realized_pnl, dec!(500));
+        assert!(!tracker.has_position("BTC/USDT"));
+    }
+
+    #[test]
+    fn test_futures_position_multiplier() {
+        let mut tracker = PositionTracker::new("kis").with_initial_cash(dec!(50000000));
+        let code = "101W9000".to_string();
+
+        tracker
+            .open_position(code.clone(), Side::Buy, dec!(2), dec!(360), None)
+            .unwrap();
+        // 선물은 명목금액을 지불하지 않음
+        assert_eq!(tracker.cash(), dec!(50000000));
+        assert_eq!(tracker.total_exposure(), dec!(180000000));
+        assert_eq!(tracker.futures_margin_requirement(), dec!(16200000));
+
+        tracker.update_price(&code, dec!(362.5)).unwrap();
+        let position = tracker.get_position_for_symbol(&code).unwrap();
+        assert_eq!(position.unrealized_pnl, dec!(1250000));
+        assert_eq!(tracker.equity(), dec!(51250000));
+
+        // 1계약 청산: 2.5pt × 25만 = 62.5만 정산
+        let (_, pnl) = tracker
+            .reduce_position(&code, dec!(1), dec!(362.5))
+            .unwrap();
+        assert_eq!(pnl, dec!(625000));
+        assert_eq!(tracker.cash(), dec!(50625000));
+        let position = tracker.get_position_for_symbol(&code).unwrap();
+        assert_eq!(position.realized_pnl, dec!(625000));
+        assert_eq!(position.unrealized_pnl, dec!(625000));
+        assert!(tracker.check_invariants().is_empty());
+
+        // 미니 선물 숏: 등록된 명세의 증거금률 사용
+        tracker
+            .register_futures_contract(
+                trader_core::FuturesContract::from_code("105W9000")
+                    .unwrap()
+                    .with_margin_rates(dec!(0.1), dec!(0.07)),
+            )
+            .unwrap();
+        tracker
+            .open_position("105W9000".to_string(), Side::Sell, dec!(1), dec!(360), None)
+            .unwrap();
+        tracker.update_price("105W9000", dec!(358)).unwrap();
+        assert_eq!(
+            tracker
+                .get_position_for_symbol("105W9000")
+                .unwrap()
+                .unrealized_pnl,
+            dec!(100000)
+        );
+        assert!(tracker.check_invariants().is_empty());
+        assert!(tracker
+            .register_futures_contract(trader_core::FuturesContract::from_code("105W9000").unwrap())
+            .is_err());
+
+        // 주식 코드는 선물로 보지 않음
+        assert!(tracker.futures_contract("105560").is_none());
+    }
 }
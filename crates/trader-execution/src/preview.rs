@@ -0,0 +1,247 @@
+//! 주문 미리보기 (모의 실행).
+//!
+//! 주문을 실제로 등록하거나 거래소에 전송하지 않고 실행 경로를 그대로 통과시켜
+//! 리스크 검사 결과, 호가 단위 조정, 예상 비용, 매수 여력, 함께 생성될 하위 주문
+//! (손절/익절 브라켓)을 계산합니다. `OrderExecutor::preview_order()`가 생성합니다.
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use trader_core::{OrderRequest, RoundMethod, Side, TickSizeProvider, TradeCost};
+
+/// 미리보기에서 생성되는 주문의 역할.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PreviewOrderRole {
+    /// 원 주문
+    Entry,
+    /// 체결 후 제출될 손절 주문
+    StopLoss,
+    /// 체결 후 제출될 익절 주문
+    TakeProfit,
+}
+
+/// 미리보기에서 생성되는 주문.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PreviewOrder {
+    /// 주문 역할
+    pub role: PreviewOrderRole,
+    /// 거래소에 전송될 주문 (호가 단위 조정 후)
+    pub order: OrderRequest,
+}
+
+/// 호가 단위 가격 조정 내역.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PriceAdjustment {
+    /// 주문 역할
+    pub role: PreviewOrderRole,
+    /// 조정된 필드 (`price` 또는 `stop_price`)
+    pub field: String,
+    /// 원래 가격
+    pub original: Decimal,
+    /// 호가 단위로 조정된 가격
+    pub adjusted: Decimal,
+}
+
+/// 매수 여력 및 증거금 계산 결과.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BuyingPower {
+    /// 계좌 잔고
+    pub cash_balance: Decimal,
+    /// 미체결 매수 주문에 묶인 금액
+    pub committed_to_open_orders: Decimal,
+    /// 주문 가능 금액
+    pub available: Decimal,
+    /// 현재 보유 수량 (롱 양수, 숏 음수)
+    pub position_quantity: Decimal,
+    /// 이 주문에 필요한 금액 (매수 대금 또는 신규 숏 증거금 + 예상 비용)
+    pub required: Decimal,
+    /// 주문 가능 여부
+    pub sufficient: bool,
+}
+
+impl BuyingPower {
+    /// 매수 여력 계산.
+    ///
+    /// 매수는 주문 금액과 비용 전체가 필요합니다. 매도는 보유 롱 수량을 초과하는
+    /// 부분만 신규 숏으로 보고 해당 금액을 증거금으로 요구합니다.
+    ///
+    /// # Arguments
+    ///
+    /// * `cash_balance` - 계좌 잔고
+    /// * `committed` - 미체결 매수 주문에 묶인 금액
+    /// * `position_quantity` - 현재 보유 수량 (롱 양수, 숏 음수)
+    /// * `side` - 주문 방향
+    /// * `quantity` - 주문 수량
+    /// * `price` - 기준 가격
+    /// * `cost` - 예상 거래 비용
+    pub fn compute(
+        cash_balance: Decimal,
+        committed: Decimal,
+        position_quantity: Decimal,
+        side: Side,
+        quantity: Decimal,
+        price: Decimal,
+        cost: Decimal,
+    ) -> Self {
+        let available = (cash_balance - committed).max(Decimal::ZERO);
+        let required = match side {
+            Side::Buy => quantity * price + cost,
+            Side::Sell => {
+                let closing = position_quantity.max(Decimal::ZERO).min(quantity);
+                (quantity - closing) * price + cost
+            }
+        };
+
+        Self {
+            cash_balance,
+            committed_to_open_orders: committed,
+            available,
+            position_quantity,
+            required,
+            sufficient: required <= available,
+        }
+    }
+}
+
+/// 주문 미리보기 결과.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderPreview {
+    /// 실제 제출 시 수락 여부
+    pub accepted: bool,
+    /// 거부 사유
+    pub rejections: Vec<String>,
+    /// 경고 및 실행 노트
+    pub warnings: Vec<String>,
+    /// 리스크 관리자가 제안한 조정 주문 (한도 초과 시)
+    pub suggested_order: Option<OrderRequest>,
+    /// 기준 가격 (지정가 또는 현재가)
+    pub reference_price: Decimal,
+    /// 주문 금액
+    pub notional: Decimal,
+    /// 예상 거래 비용 (비용 모델이 없으면 None)
+    pub estimated_cost: Option<TradeCost>,
+    /// 매수 여력
+    pub buying_power: BuyingPower,
+    /// 호가 단위 조정 내역
+    pub price_adjustments: Vec<PriceAdjustment>,
+    /// 거래소 주문 서킷 상태 (closed, open, half_open)
+    pub order_circuit: String,
+    /// 전송될 주문 목록 (원 주문 및 브라켓 주문)
+    pub orders: Vec<PreviewOrder>,
+}
+
+/// 주문의 가격 필드를 호가 단위로 조정.
+///
+/// 매수는 내림, 매도는 올림으로 조정하여 의도보다 불리한 가격에 체결되지 않도록 합니다.
+///
+/// # Returns
+///
+/// 실제로 값이 바뀐 필드의 조정 내역
+pub fn round_order_to_tick(
+    role: PreviewOrderRole,
+    order: &mut OrderRequest,
+    provider: &dyn TickSizeProvider,
+) -> Vec<PriceAdjustment> {
+    let method = match order.side {
+        Side::Buy => RoundMethod::Floor,
+        Side::Sell => RoundMethod::Ceil,
+    };
+
+    let mut adjustments = Vec::new();
+    for (field, value) in [
+        ("price", &mut order.price),
+        ("stop_price", &mut order.stop_price),
+    ] {
+        let Some(original) = *value else {
+            continue;
+        };
+        let adjusted = provider.round_to_tick(original, method).normalize();
+        if adjusted != original {
+            *value = Some(adjusted);
+            adjustments.push(PriceAdjustment {
+                role,
+                field: field.to_string(),
+                original,
+                adjusted,
+            });
+        }
+    }
+    adjustments
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal::prelude::FromPrimitive;
+    use trader_core::{KrxTickSize, OrderType, TimeInForce};
+
+    /// 숫자로부터 Decimal을 생성하는 헬퍼 매크로
+    macro_rules! dec {
+        ($val:expr) => {
+            Decimal::from_f64($val as f64).unwrap()
+        };
+    }
+
+    fn limit(side: Side, price: Decimal) -> OrderRequest {
+        OrderRequest {
+            ticker: "005930".to_string(),
+            side,
+            order_type: OrderType::Limit,
+            quantity: dec!(10),
+            price: Some(price),
+            stop_price: None,
+            time_in_force: TimeInForce::GTC,
+            client_order_id: None,
+            strategy_id: None,
+        }
+    }
+
+    #[test]
+    fn test_round_order_to_tick() {
+        let krx = KrxTickSize::new();
+
+        let mut buy = limit(Side::Buy, dec!(35432));
+        let adjustments = round_order_to_tick(PreviewOrderRole::Entry, &mut buy, &krx);
+        assert_eq!(buy.price, Some(dec!(35400)));
+        assert_eq!(adjustments.len(), 1);
+        assert_eq!(adjustments[0].original, dec!(35432));
+
+        let mut sell = limit(Side::Sell, dec!(35432));
+        round_order_to_tick(PreviewOrderRole::Entry, &mut sell, &krx);
+        assert_eq!(sell.price, Some(dec!(35450)));
+
+        // 이미 호가 단위에 맞으면 조정 없음
+        let mut valid = limit(Side::Buy, dec!(70000));
+        assert!(round_order_to_tick(PreviewOrderRole::Entry, &mut valid, &krx).is_empty());
+    }
+
+    #[test]
+    fn test_buying_power() {
+        // 매수: 대금 + 비용, 미체결 매수 금액 차감
+        let bp = BuyingPower::compute(
+            dec!(1000000),
+            dec!(300000),
+            Decimal::ZERO,
+            Side::Buy,
+            dec!(10),
+            dec!(70000),
+            dec!(105),
+        );
+        assert_eq!(bp.available, dec!(700000));
+        assert_eq!(bp.required, dec!(700105));
+        assert!(!bp.sufficient);
+
+        // 매도: 보유 수량까지는 청산, 초과분만 숏 증거금
+        let bp = BuyingPower::compute(
+            dec!(100000),
+            Decimal::ZERO,
+            dec!(8),
+            Side::Sell,
+            dec!(10),
+            dec!(70000),
+            Decimal::ZERO,
+        );
+        assert_eq!(bp.required, dec!(140000));
+        assert!(!bp.sufficient);
+    }
+}
@@ -0,0 +1,806 @@
+//! 사후 체결 대사 (post-trade reconciliation).
+//!
+//! 브로커가 보고하는 보유 포지션, 미체결 주문, 체결 내역, 예수금을 로컬 상태
+//! (`PositionTracker`, DB `positions`/`orders`)와 비교해 불일치를 찾습니다.
+//!
+//! - 포지션: 브로커에만 있음 / 로컬에만 있음 / 방향·수량·평균가 불일치
+//! - 주문: 로컬이 모르는 미체결 주문 / 브로커에 없는 로컬 활성 주문 / 체결 수량 불일치
+//! - 예수금: 허용 오차를 넘는 차이
+//!
+//! 이 모듈은 비교와 보정 계획만 담당합니다. 브로커 조회, 알림, DB 반영은 호출자가 합니다.
+//! 주문 번호는 앞자리 0을 무시하고 비교합니다 (KIS `0000012345` == `12345`).
+
+use std::collections::{BTreeMap, HashMap};
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use trader_core::Side;
+
+use crate::position_tracker::{PositionTracker, PositionTrackerError};
+
+/// 보유 포지션 스냅샷 (브로커/로컬 공통).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HoldingSnapshot {
+    pub ticker: String,
+    pub side: Side,
+    pub quantity: Decimal,
+    /// 평균 진입가
+    pub avg_price: Decimal,
+}
+
+impl HoldingSnapshot {
+    /// 새 스냅샷 생성.
+    pub fn new(
+        ticker: impl Into<String>,
+        side: Side,
+        quantity: Decimal,
+        avg_price: Decimal,
+    ) -> Self {
+        Self {
+            ticker: ticker.into(),
+            side,
+            quantity,
+            avg_price,
+        }
+    }
+}
+
+/// 주문 스냅샷 (브로커/로컬 공통).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OrderSnapshot {
+    /// 거래소 주문 번호
+    pub exchange_order_id: String,
+    pub ticker: String,
+    pub quantity: Decimal,
+    pub filled_quantity: Decimal,
+    /// 미체결(활성) 상태 여부
+    pub is_open: bool,
+}
+
+/// 브로커 체결 내역 한 건.
+///
+/// 같은 주문의 체결은 여러 건일 수 있으며 대사 시 주문 번호별로 합산합니다.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExecutionSnapshot {
+    pub exchange_order_id: String,
+    pub ticker: String,
+    pub quantity: Decimal,
+    pub price: Decimal,
+}
+
+/// 대사 허용 오차.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReconciliationTolerance {
+    /// 수량 허용 오차 (절대값)
+    pub quantity: Decimal,
+    /// 평균가 허용 오차 (%)
+    pub price_pct: Decimal,
+    /// 예수금 허용 오차 (절대값)
+    pub cash: Decimal,
+}
+
+impl Default for ReconciliationTolerance {
+    fn default() -> Self {
+        Self {
+            quantity: Decimal::ZERO,
+            price_pct: Decimal::new(5, 1),
+            cash: Decimal::ONE,
+        }
+    }
+}
+
+/// 브로커와 비교한 로컬 상태의 출처.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LocalSource {
+    /// 실행기의 `PositionTracker`
+    Tracker,
+    /// DB `positions`/`orders` 테이블
+    Database,
+}
+
+impl LocalSource {
+    /// 문자열 표현.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Tracker => "tracker",
+            Self::Database => "database",
+        }
+    }
+}
+
+/// 불일치 종류.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiscrepancyKind {
+    /// 브로커에는 있으나 로컬에 없는 포지션
+    MissingLocally,
+    /// 로컬에는 있으나 브로커에 없는 포지션
+    MissingAtBroker,
+    /// 포지션 방향 불일치
+    SideMismatch,
+    /// 보유 수량 불일치
+    QuantityMismatch,
+    /// 평균 진입가 불일치
+    AvgPriceMismatch,
+    /// 로컬이 모르는 브로커 미체결 주문
+    UnknownOpenOrder,
+    /// 브로커에 미체결로 남아 있지 않은 로컬 활성 주문
+    StaleLocalOrder,
+    /// 로컬 주문과 연결되지 않는 브로커 체결 (HTS 수동 주문 등)
+    UnknownExecution,
+    /// 주문 체결 수량 불일치
+    FillMismatch,
+    /// 예수금 불일치
+    CashMismatch,
+}
+
+impl DiscrepancyKind {
+    /// 문자열 표현.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::MissingLocally => "missing_locally",
+            Self::MissingAtBroker => "missing_at_broker",
+            Self::SideMismatch => "side_mismatch",
+            Self::QuantityMismatch => "quantity_mismatch",
+            Self::AvgPriceMismatch => "avg_price_mismatch",
+            Self::UnknownOpenOrder => "unknown_open_order",
+            Self::StaleLocalOrder => "stale_local_order",
+            Self::UnknownExecution => "unknown_execution",
+            Self::FillMismatch => "fill_mismatch",
+            Self::CashMismatch => "cash_mismatch",
+        }
+    }
+
+    /// 포지션 관련 불일치 여부.
+    pub fn is_position(&self) -> bool {
+        matches!(
+            self,
+            Self::MissingLocally
+                | Self::MissingAtBroker
+                | Self::SideMismatch
+                | Self::QuantityMismatch
+                | Self::AvgPriceMismatch
+        )
+    }
+}
+
+/// 불일치 한 건.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Discrepancy {
+    pub kind: DiscrepancyKind,
+    pub source: LocalSource,
+    /// 포지션은 티커, 주문/체결은 거래소 주문 번호, 예수금은 `cash`
+    pub key: String,
+    pub ticker: String,
+    /// 브로커 값 (수량, 평균가, 체결 수량, 예수금 중 종류에 맞는 값)
+    pub broker_value: Option<Decimal>,
+    /// 로컬 값
+    pub local_value: Option<Decimal>,
+}
+
+impl Discrepancy {
+    /// 한 줄 요약.
+    pub fn describe(&self) -> String {
+        let value = |v: Option<Decimal>| v.map_or_else(|| "-".to_string(), |v| v.to_string());
+        format!(
+            "[{}] {} {}: broker={} local={}",
+            self.source.as_str(),
+            self.kind.as_str(),
+            self.key,
+            value(self.broker_value),
+            value(self.local_value)
+        )
+    }
+}
+
+/// 대사 입력.
+#[derive(Debug, Clone, Default)]
+pub struct ReconciliationInput {
+    pub broker_positions: Vec<HoldingSnapshot>,
+    pub broker_open_orders: Vec<OrderSnapshot>,
+    pub broker_executions: Vec<ExecutionSnapshot>,
+    /// 브로커 예수금 (`None`이면 예수금 비교 생략)
+    pub broker_cash: Option<Decimal>,
+    pub tracker_positions: Vec<HoldingSnapshot>,
+    /// 추적기 현금 (`None`이면 예수금 비교 생략)
+    pub tracker_cash: Option<Decimal>,
+    /// DB 열린 포지션 (`None`이면 DB 포지션 비교 생략)
+    pub db_positions: Option<Vec<HoldingSnapshot>>,
+    /// DB 주문 (활성 주문 + 브로커 체결과 같은 주문 번호의 주문)
+    pub db_orders: Vec<OrderSnapshot>,
+}
+
+/// 대사 결과.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ReconciliationReport {
+    pub discrepancies: Vec<Discrepancy>,
+    pub positions_checked: usize,
+    pub orders_checked: usize,
+    pub executions_checked: usize,
+}
+
+impl ReconciliationReport {
+    /// 불일치가 없는지 여부.
+    pub fn is_clean(&self) -> bool {
+        self.discrepancies.is_empty()
+    }
+
+    /// 특정 출처의 불일치.
+    pub fn for_source(&self, source: LocalSource) -> impl Iterator<Item = &Discrepancy> {
+        self.discrepancies
+            .iter()
+            .filter(move |d| d.source == source)
+    }
+
+    /// 불일치 집합의 지문.
+    ///
+    /// 값이 아닌 종류/출처/키만 사용하므로, 같은 불일치가 이어지는 동안 반복 알림을
+    /// 막는 데 씁니다.
+    pub fn fingerprint(&self) -> String {
+        let mut keys: Vec<String> = self
+            .discrepancies
+            .iter()
+            .map(|d| format!("{}:{}:{}", d.source.as_str(), d.kind.as_str(), d.key))
+            .collect();
+        keys.sort();
+        keys.join(",")
+    }
+}
+
+/// 주문 번호 정규화 (앞자리 0 제거).
+pub fn normalize_order_id(id: &str) -> &str {
+    let trimmed = id.trim().trim_start_matches('0');
+    if trimmed.is_empty() {
+        "0"
+    } else {
+        trimmed
+    }
+}
+
+/// 브로커 상태와 로컬 상태를 비교.
+pub fn reconcile(
+    input: &ReconciliationInput,
+    tolerance: &ReconciliationTolerance,
+) -> ReconciliationReport {
+    let mut report = ReconciliationReport {
+        positions_checked: input.broker_positions.len(),
+        orders_checked: input.broker_open_orders.len(),
+        executions_checked: input.broker_executions.len(),
+        ..Default::default()
+    };
+
+    compare_positions(
+        &input.broker_positions,
+        &input.tracker_positions,
+        LocalSource::Tracker,
+        tolerance,
+        &mut report.discrepancies,
+    );
+    if let Some(db_positions) = &input.db_positions {
+        compare_positions(
+            &input.broker_positions,
+            db_positions,
+            LocalSource::Database,
+            tolerance,
+            &mut report.discrepancies,
+        );
+    }
+    compare_orders(input, tolerance, &mut report.discrepancies);
+
+    if let (Some(broker), Some(local)) = (input.broker_cash, input.tracker_cash) {
+        if (broker - local).abs() > tolerance.cash {
+            report.discrepancies.push(Discrepancy {
+                kind: DiscrepancyKind::CashMismatch,
+                source: LocalSource::Tracker,
+                key: "cash".to_string(),
+                ticker: String::new(),
+                broker_value: Some(broker),
+                local_value: Some(local),
+            });
+        }
+    }
+
+    report
+}
+
+fn compare_positions(
+    broker: &[HoldingSnapshot],
+    local: &[HoldingSnapshot],
+    source: LocalSource,
+    tolerance: &ReconciliationTolerance,
+    out: &mut Vec<Discrepancy>,
+) {
+    let broker_map: BTreeMap<&str, &HoldingSnapshot> = broker
+        .iter()
+        .filter(|h| !h.quantity.is_zero())
+        .map(|h| (h.ticker.as_str(), h))
+        .collect();
+    let local_map: BTreeMap<&str, &HoldingSnapshot> = local
+        .iter()
+        .filter(|h| !h.quantity.is_zero())
+        .map(|h| (h.ticker.as_str(), h))
+        .collect();
+
+    let position = |kind, holding: &HoldingSnapshot, broker_value, local_value| Discrepancy {
+        kind,
+        source,
+        key: holding.ticker.clone(),
+        ticker: holding.ticker.clone(),
+        broker_value,
+        local_value,
+    };
+
+    for (ticker, b) in &broker_map {
+        let Some(l) = local_map.get(ticker) else {
+            out.push(position(
+                DiscrepancyKind::MissingLocally,
+                b,
+                Some(b.quantity),
+                None,
+            ));
+            continue;
+        };
+
+        if b.side != l.side {
+            out.push(position(
+                DiscrepancyKind::SideMismatch,
+                b,
+                Some(b.quantity),
+                Some(l.quantity),
+            ));
+            continue;
+        }
+        if (b.quantity - l.quantity).abs() > tolerance.quantity {
+            out.push(position(
+                DiscrepancyKind::QuantityMismatch,
+                b,
+                Some(b.quantity),
+                Some(l.quantity),
+            ));
+        }
+        if b.avg_price > Decimal::ZERO {
+            let diff_pct = (b.avg_price - l.avg_price).abs() / b.avg_price * Decimal::ONE_HUNDRED;
+            if diff_pct > tolerance.price_pct {
+                out.push(position(
+                    DiscrepancyKind::AvgPriceMismatch,
+                    b,
+                    Some(b.avg_price),
+                    Some(l.avg_price),
+                ));
+            }
+        }
+    }
+
+    for (ticker, l) in &local_map {
+        if !broker_map.contains_key(ticker) {
+            out.push(position(
+                DiscrepancyKind::MissingAtBroker,
+                l,
+                None,
+                Some(l.quantity),
+            ));
+        }
+    }
+}
+
+fn compare_orders(
+    input: &ReconciliationInput,
+    tolerance: &ReconciliationTolerance,
+    out: &mut Vec<Discrepancy>,
+) {
+    let local: HashMap<&str, &OrderSnapshot> = input
+        .db_orders
+        .iter()
+        .map(|o| (normalize_order_id(&o.exchange_order_id), o))
+        .collect();
+    let broker_open: HashMap<&str, &OrderSnapshot> = input
+        .broker_open_orders
+        .iter()
+        .map(|o| (normalize_order_id(&o.exchange_order_id), o))
+        .collect();
+
+    let order = |kind, key: &str, ticker: &str, broker_value, local_value| Discrepancy {
+        kind,
+        source: LocalSource::Database,
+        key: key.to_string(),
+        ticker: ticker.to_string(),
+        broker_value,
+        local_value,
+    };
+
+    // 주문 번호별 브로커 체결 수량 (체결 내역 합산, 미체결 주문의 부분 체결 수량으로 보완)
+    let mut broker_fills: BTreeMap<&str, (&ExecutionSnapshot, Decimal)> = BTreeMap::new();
+    for execution in &input.broker_executions {
+        broker_fills
+            .entry(normalize_order_id(&execution.exchange_order_id))
+            .and_modify(|(_, quantity)| *quantity += execution.quantity)
+            .or_insert((execution, execution.quantity));
+    }
+
+    let mut open_ids: Vec<&&str> = broker_open.keys().collect();
+    open_ids.sort();
+    for id in open_ids {
+        let b = broker_open[*id];
+        if !local.contains_key(*id) {
+            out.push(order(
+                DiscrepancyKind::UnknownOpenOrder,
+                &b.exchange_order_id,
+                &b.ticker,
+                Some(b.quantity),
+                None,
+            ));
+        }
+    }
+
+    let mut local_ids: Vec<&&str> = local.keys().collect();
+    local_ids.sort();
+    for id in local_ids {
+        let l = local[*id];
+        let broker_filled = broker_fills
+            .get(*id)
+            .map(|(_, quantity)| *quantity)
+            .into_iter()
+            .chain(broker_open.get(*id).map(|b| b.filled_quantity))
+            .max();
+
+        if l.is_open && !broker_open.contains_key(*id) {
+            out.push(order(
+                DiscrepancyKind::StaleLocalOrder,
+                &l.exchange_order_id,
+                &l.ticker,
+                broker_filled,
+                Some(l.filled_quantity),
+            ));
+            continue;
+        }
+        if let Some(filled) = broker_filled {
+            if (filled - l.filled_quantity).abs() > tolerance.quantity {
+                out.push(order(
+                    DiscrepancyKind::FillMismatch,
+                    &l.exchange_order_id,
+                    &l.ticker,
+                    Some(filled),
+                    Some(l.filled_quantity),
+                ));
+            }
+        }
+    }
+
+    for (id, (execution, quantity)) in &broker_fills {
+        if !local.contains_key(id) {
+            out.push(order(
+                DiscrepancyKind::UnknownExecution,
+                &execution.exchange_order_id,
+                &execution.ticker,
+                Some(*quantity),
+                None,
+            ));
+        }
+    }
+}
+
+/// 추적기 포지션 보정 동작.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum PositionCorrection {
+    /// 새 포지션 오픈
+    Open {
+        ticker: String,
+        side: Side,
+        quantity: Decimal,
+        price: Decimal,
+    },
+    /// 기존 포지션 수량 증가
+    Increase {
+        ticker: String,
+        quantity: Decimal,
+        price: Decimal,
+    },
+    /// 기존 포지션 수량 감소
+    Reduce {
+        ticker: String,
+        quantity: Decimal,
+        price: Decimal,
+    },
+    /// 포지션 전량 종료
+    Close { ticker: String, price: Decimal },
+}
+
+impl PositionCorrection {
+    /// 대상 티커.
+    pub fn ticker(&self) -> &str {
+        match self {
+            Self::Open { ticker, .. }
+            | Self::Increase { ticker, .. }
+            | Self::Reduce { ticker, .. }
+            | Self::Close { ticker, .. } => ticker,
+        }
+    }
+
+    /// 추적기에 보정 적용.
+    ///
+    /// 추적기의 현금/자산 불변식을 유지하기 위해 일반 체결과 같은 경로로 반영합니다.
+    pub fn apply(&self, tracker: &mut PositionTracker) -> Result<(), PositionTrackerError> {
+        match self {
+            Self::Open {
+                ticker,
+                side,
+                quantity,
+                price,
+            } => tracker
+                .open_position(ticker.clone(), *side, *quantity, *price, None)
+                .map(|_| ()),
+            Self::Increase {
+                ticker,
+                quantity,
+                price,
+            } => tracker
+                .add_to_position(ticker, *quantity, *price)
+                .map(|_| ()),
+            Self::Reduce {
+                ticker,
+                quantity,
+                price,
+            } => tracker
+                .reduce_position(ticker, *quantity, *price)
+                .map(|_| ()),
+            Self::Close { ticker, price } => tracker.close_position(ticker, *price).map(|_| ()),
+        }
+    }
+}
+
+/// 추적기 포지션을 브로커 보유 현황에 맞추는 보정 계획.
+///
+/// 수량과 방향만 맞춥니다. 새로 잡거나 늘리는 수량은 브로커 평균가로, 줄이거나 닫는
+/// 수량은 로컬 평균가로 반영해 보정 자체가 실현 손익을 만들지 않게 합니다.
+/// 평균가만 다른 경우는 보정하지 않고 보고만 합니다.
+pub fn plan_position_corrections(
+    broker: &[HoldingSnapshot],
+    local: &[HoldingSnapshot],
+    tolerance: &ReconciliationTolerance,
+) -> Vec<PositionCorrection> {
+    let broker_map: BTreeMap<&str, &HoldingSnapshot> = broker
+        .iter()
+        .filter(|h| !h.quantity.is_zero())
+        .map(|h| (h.ticker.as_str(), h))
+        .collect();
+    let local_map: BTreeMap<&str, &HoldingSnapshot> = local
+        .iter()
+        .filter(|h| !h.quantity.is_zero())
+        .map(|h| (h.ticker.as_str(), h))
+        .collect();
+
+    let open = |b: &HoldingSnapshot| PositionCorrection::Open {
+        ticker: b.ticker.clone(),
+        side: b.side,
+        quantity: b.quantity,
+        price: b.avg_price,
+    };
+    let close = |l: &HoldingSnapshot| PositionCorrection::Close {
+        ticker: l.ticker.clone(),
+        price: l.avg_price,
+    };
+
+    let mut corrections = Vec::new();
+    for (ticker, b) in &broker_map {
+        match local_map.get(ticker) {
+            None => corrections.push(open(b)),
+            Some(l) if l.side != b.side => {
+                corrections.push(close(l));
+                corrections.push(open(b));
+            }
+            Some(l) => {
+                let diff = b.quantity - l.quantity;
+                if diff.abs() <= tolerance.quantity {
+                    continue;
+                }
+                if diff > Decimal::ZERO {
+                    corrections.push(PositionCorrection::Increase {
+                        ticker: b.ticker.clone(),
+                        quantity: diff,
+                        price: b.avg_price,
+                    });
+                } else {
+                    corrections.push(PositionCorrection::Reduce {
+                        ticker: l.ticker.clone(),
+                        quantity: -diff,
+                        price: l.avg_price,
+                    });
+                }
+            }
+        }
+    }
+    for (ticker, l) in &local_map {
+        if !broker_map.contains_key(ticker) {
+            corrections.push(close(l));
+        }
+    }
+
+    corrections
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal::prelude::FromPrimitive;
+
+    /// 숫자로부터 Decimal을 생성하는 헬퍼 매크로
+    macro_rules! dec {
+        ($val:expr) => {
+            Decimal::from_f64($val as f64).unwrap()
+        };
+    }
+
+    fn holding(ticker: &str, quantity: Decimal, avg_price: Decimal) -> HoldingSnapshot {
+        HoldingSnapshot::new(ticker, Side::Buy, quantity, avg_price)
+    }
+
+    fn order(id: &str, filled: Decimal, is_open: bool) -> OrderSnapshot {
+        OrderSnapshot {
+            exchange_order_id: id.to_string(),
+            ticker: "005930".to_string(),
+            quantity: dec!(10),
+            filled_quantity: filled,
+            is_open,
+        }
+    }
+
+    fn kinds(report: &ReconciliationReport, source: LocalSource) -> Vec<(DiscrepancyKind, String)> {
+        report
+            .for_source(source)
+            .map(|d| (d.kind, d.key.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn test_matching_state_is_clean() {
+        let positions = vec![holding("005930", dec!(10), dec!(70000))];
+        let input = ReconciliationInput {
+            broker_positions: positions.clone(),
+            tracker_positions: positions.clone(),
+            db_positions: Some(positions),
+            broker_open_orders: vec![order("0000012345", dec!(0), true)],
+            db_orders: vec![order("12345", dec!(0), true)],
+            ..Default::default()
+        };
+
+        let report = reconcile(&input, &ReconciliationTolerance::default());
+        assert!(report.is_clean(), "{:?}", report.discrepancies);
+        assert_eq!(report.fingerprint(), "");
+    }
+
+    #[test]
+    fn test_position_discrepancies() {
+        let input = ReconciliationInput {
+            broker_positions: vec![
+                holding("005930", dec!(10), dec!(70000)),
+                holding("000660", dec!(5), dec!(120000)),
+                holding("035420", dec!(3), dec!(200000)),
+            ],
+            tracker_positions: vec![
+                holding("005930", dec!(8), dec!(70000)),
+                holding("035420", dec!(3), dec!(190000)),
+                holding("051910", dec!(2), dec!(400000)),
+            ],
+            ..Default::default()
+        };
+
+        let report = reconcile(&input, &ReconciliationTolerance::default());
+        assert_eq!(
+            kinds(&report, LocalSource::Tracker),
+            vec![
+                (DiscrepancyKind::MissingLocally, "000660".to_string()),
+                (DiscrepancyKind::QuantityMismatch, "005930".to_string()),
+                (DiscrepancyKind::AvgPriceMismatch, "035420".to_string()),
+                (DiscrepancyKind::MissingAtBroker, "051910".to_string()),
+            ]
+        );
+        // DB 포지션을 주지 않으면 DB 비교는 생략
+        assert_eq!(report.for_source(LocalSource::Database).count(), 0);
+    }
+
+    #[test]
+    fn test_order_and_execution_discrepancies() {
+        let input = ReconciliationInput {
+            broker_open_orders: vec![order("0000000002", dec!(0), true)],
+            broker_executions: vec![
+                ExecutionSnapshot {
+                    exchange_order_id: "0000000001".to_string(),
+                    ticker: "005930".to_string(),
+                    quantity: dec!(4),
+                    price: dec!(70000),
+                },
+                ExecutionSnapshot {
+                    exchange_order_id: "0000000001".to_string(),
+                    ticker: "005930".to_string(),
+                    quantity: dec!(6),
+                    price: dec!(70100),
+                },
+                ExecutionSnapshot {
+                    exchange_order_id: "0000000009".to_string(),
+                    ticker: "000660".to_string(),
+                    quantity: dec!(1),
+                    price: dec!(120000),
+                },
+            ],
+            db_orders: vec![order("1", dec!(4), false), order("3", dec!(0), true)],
+            ..Default::default()
+        };
+
+        let report = reconcile(&input, &ReconciliationTolerance::default());
+        assert_eq!(
+            kinds(&report, LocalSource::Database),
+            vec![
+                (DiscrepancyKind::UnknownOpenOrder, "0000000002".to_string()),
+                (DiscrepancyKind::FillMismatch, "1".to_string()),
+                (DiscrepancyKind::StaleLocalOrder, "3".to_string()),
+                (DiscrepancyKind::UnknownExecution, "0000000009".to_string()),
+            ]
+        );
+        let fill = &report.discrepancies[1];
+        assert_eq!(fill.broker_value, Some(dec!(10)));
+        assert_eq!(fill.local_value, Some(dec!(4)));
+    }
+
+    #[test]
+    fn test_cash_mismatch_respects_tolerance() {
+        let mut input = ReconciliationInput {
+            broker_cash: Some(dec!(1_000_000)),
+            tracker_cash: Some(dec!(1_000_000.5)),
+            ..Default::default()
+        };
+        assert!(reconcile(&input, &ReconciliationTolerance::default()).is_clean());
+
+        input.tracker_cash = Some(dec!(990_000));
+        let report = reconcile(&input, &ReconciliationTolerance::default());
+        assert_eq!(report.discrepancies[0].kind, DiscrepancyKind::CashMismatch);
+    }
+
+    #[test]
+    fn test_corrections_align_tracker_with_broker() {
+        let mut tracker = PositionTracker::new("KIS-KR").with_initial_cash(dec!(10_000_000));
+        tracker
+            .open_position("005930".to_string(), Side::Buy, dec!(8), dec!(70000), None)
+            .unwrap();
+        tracker
+            .open_position("051910".to_string(), Side::Buy, dec!(2), dec!(400000), None)
+            .unwrap();
+        tracker
+            .open_position("035420".to_string(), Side::Buy, dec!(5), dec!(200000), None)
+            .unwrap();
+
+        let broker = vec![
+            holding("005930", dec!(10), dec!(70000)),
+            holding("000660", dec!(5), dec!(120000)),
+            holding("035420", dec!(3), dec!(200000)),
+        ];
+        let local: Vec<HoldingSnapshot> = tracker
+            .get_open_positions()
+            .iter()
+            .map(|p| HoldingSnapshot::new(p.ticker.clone(), p.side, p.quantity, p.entry_price))
+            .collect();
+
+        let tolerance = ReconciliationTolerance::default();
+        let corrections = plan_position_corrections(&broker, &local, &tolerance);
+        assert_eq!(corrections.len(), 4);
+        for correction in &corrections {
+            correction.apply(&mut tracker).unwrap();
+        }
+
+        let after: Vec<HoldingSnapshot> = tracker
+            .get_open_positions()
+            .iter()
+            .map(|p| HoldingSnapshot::new(p.ticker.clone(), p.side, p.quantity, p.entry_price))
+            .collect();
+        let input = ReconciliationInput {
+            broker_positions: broker,
+            tracker_positions: after,
+            ..Default::default()
+        };
+        assert!(reconcile(&input, &tolerance).is_clean());
+        // 로컬 평균가로 줄이고 닫으므로 보정이 손익을 만들지 않음
+        assert_eq!(tracker.total_realized_pnl(), Decimal::ZERO);
+        assert!(tracker.check_invariants().is_empty());
+    }
+}
@@ -0,0 +1,439 @@
+//! 기본 리스크 프로필 (보수적/표준/공격적).
+//!
+//! 리스크 한도(`RiskConfig`), 포지션 사이징 방식, 실행 조절(유동성 수량 상한,
+//! 실행 조절 단계 기준)을 이름 붙은 묶음으로 제공합니다. 세부 설정을 하나하나
+//! 조정하지 않고 프로필만 골라 전역 또는 전략별로 적용할 수 있으며,
+//! [`diff_settings`]로 프로필 적용 시 바뀌는 항목을 미리 확인할 수 있습니다.
+
+use rust_decimal::prelude::FromPrimitive;
+use rust_decimal::{Decimal, RoundingStrategy};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fmt;
+use std::str::FromStr;
+use trader_risk::RiskConfig;
+
+use crate::execution_governor::ExecutionGovernorConfig;
+use crate::liquidity_cap::LiquidityCapConfig;
+
+/// 리스크 프로필 이름.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RiskProfileName {
+    /// 보수적 (낮은 한도, 강한 실행 조절)
+    Conservative,
+    /// 표준 (기본 설정)
+    Standard,
+    /// 공격적 (높은 한도, 약한 실행 조절)
+    Aggressive,
+}
+
+impl RiskProfileName {
+    /// 전체 프로필 (보수적 → 공격적 순).
+    pub const ALL: [RiskProfileName; 3] = [
+        RiskProfileName::Conservative,
+        RiskProfileName::Standard,
+        RiskProfileName::Aggressive,
+    ];
+
+    /// 문자열 표현.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RiskProfileName::Conservative => "conservative",
+            RiskProfileName::Standard => "standard",
+            RiskProfileName::Aggressive => "aggressive",
+        }
+    }
+}
+
+impl fmt::Display for RiskProfileName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for RiskProfileName {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "conservative" => Ok(RiskProfileName::Conservative),
+            "standard" => Ok(RiskProfileName::Standard),
+            "aggressive" => Ok(RiskProfileName::Aggressive),
+            other => Err(format!("Unknown risk profile: {}", other)),
+        }
+    }
+}
+
+/// 포지션 사이징 방식.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SizingMethod {
+    /// 고정 비율 위험 (손절 시 잔고의 `risk_per_trade_pct`만큼 손실)
+    FixedFractional,
+    /// 잔고 비율 (잔고의 `equity_pct`만큼 매수)
+    PercentOfEquity,
+}
+
+/// 수량 없는 진입 신호의 포지션 사이징 정책.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PositionSizingPolicy {
+    /// 사이징 방식
+    pub method: SizingMethod,
+    /// 거래당 위험 비율 (%, `FixedFractional`)
+    pub risk_per_trade_pct: f64,
+    /// 거래당 투입 비율 (%, `PercentOfEquity`)
+    pub equity_pct: f64,
+}
+
+impl PositionSizingPolicy {
+    /// 진입 수량 계산.
+    ///
+    /// 금액 기준 상한은 리스크 설정의 최대 포지션 비율이며, 주식은 정수 주,
+    /// 암호화폐(`BTC/USDT` 형식)는 소수 8자리로 내림합니다.
+    ///
+    /// # Arguments
+    ///
+    /// * `ticker` - 종목 (수량 자릿수 결정)
+    /// * `balance` - 계좌 잔고
+    /// * `price` - 진입 가격
+    /// * `stop_loss` - 신호의 손절가 (없으면 기본 손절 비율 사용)
+    /// * `risk` - 적용 중인 리스크 설정
+    pub fn entry_quantity(
+        &self,
+        ticker: &str,
+        balance: Decimal,
+        price: Decimal,
+        stop_loss: Option<Decimal>,
+        risk: &RiskConfig,
+    ) -> Decimal {
+        if balance <= Decimal::ZERO || price <= Decimal::ZERO {
+            return Decimal::ZERO;
+        }
+
+        let notional = match self.method {
+            SizingMethod::FixedFractional => {
+                let stop_distance = stop_loss
+                    .map(|stop| (price - stop).abs())
+                    .filter(|distance| !distance.is_zero())
+                    .unwrap_or_else(|| price * pct(risk.get_stop_loss_pct(ticker)));
+                if stop_distance.is_zero() {
+                    return Decimal::ZERO;
+                }
+                balance * pct(self.risk_per_trade_pct) / stop_distance * price
+            }
+            SizingMethod::PercentOfEquity => balance * pct(self.equity_pct),
+        };
+        let notional = notional.min(balance * pct(risk.get_max_position_pct(ticker)));
+
+        let dp = if ticker.contains('/') { 8 } else { 0 };
+        (notional / price)
+            .round_dp_with_strategy(dp, RoundingStrategy::ToZero)
+            .max(Decimal::ZERO)
+    }
+}
+
+/// 퍼센트 값을 비율로 변환 (5.0 → 0.05).
+fn pct(value: f64) -> Decimal {
+    Decimal::from_f64(value).unwrap_or(Decimal::ZERO) / Decimal::ONE_HUNDRED
+}
+
+/// 실행 조절 설정 묶음.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ExecutionThrottles {
+    /// 유동성 수량 상한
+    pub liquidity_cap: LiquidityCapConfig,
+    /// 실행 조절 단계 기준
+    pub execution_governor: ExecutionGovernorConfig,
+}
+
+/// 이름 붙은 리스크 프로필.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RiskProfile {
+    /// 프로필 이름
+    pub name: RiskProfileName,
+    /// 설명
+    pub description: String,
+    /// 리스크 한도
+    pub risk: RiskConfig,
+    /// 포지션 사이징
+    pub sizing: PositionSizingPolicy,
+    /// 실행 조절
+    pub throttles: ExecutionThrottles,
+}
+
+impl RiskProfile {
+    /// 기본 프로필 생성.
+    pub fn preset(name: RiskProfileName) -> Self {
+        match name {
+            RiskProfileName::Conservative => Self {
+                name,
+                description: "낮은 포지션/손실 한도, 트레일링 손절, 강한 실행 조절".to_string(),
+                risk: RiskConfig::conservative(),
+                sizing: PositionSizingPolicy {
+                    method: SizingMethod::FixedFractional,
+                    risk_per_trade_pct: 0.5,
+                    equity_pct: 3.0,
+                },
+                throttles: ExecutionThrottles {
+                    liquidity_cap: LiquidityCapConfig {
+                        max_adv_fraction: Decimal::new(2, 2),
+                        relaxed_adv_fraction: Decimal::new(1, 2),
+                        illiquid_adv_fraction: Decimal::new(25, 4),
+                        ..LiquidityCapConfig::default()
+                    },
+                    execution_governor: ExecutionGovernorConfig {
+                        elevated_volatility_ratio: Decimal::new(15, 1),
+                        extreme_volatility_ratio: Decimal::new(25, 1),
+                        elevated_spread_bps: Decimal::new(20, 0),
+                        extreme_spread_bps: Decimal::new(60, 0),
+                        size_factor: Decimal::new(3, 1),
+                        limit_offset_bps: Decimal::new(30, 0),
+                        ..ExecutionGovernorConfig::default()
+                    },
+                },
+            },
+            RiskProfileName::Standard => Self {
+                name,
+                description: "기본 리스크 한도와 실행 조절".to_string(),
+                risk: RiskConfig::default(),
+                sizing: PositionSizingPolicy {
+                    method: SizingMethod::FixedFractional,
+                    risk_per_trade_pct: 1.0,
+                    equity_pct: 5.0,
+                },
+                throttles: ExecutionThrottles::default(),
+            },
+            RiskProfileName::Aggressive => Self {
+                name,
+                description: "높은 포지션/손실 한도, 잔고 비율 사이징, 약한 실행 조절".to_string(),
+                risk: RiskConfig::aggressive(),
+                sizing: PositionSizingPolicy {
+                    method: SizingMethod::PercentOfEquity,
+                    risk_per_trade_pct: 2.0,
+                    equity_pct: 10.0,
+                },
+                throttles: ExecutionThrottles {
+                    liquidity_cap: LiquidityCapConfig {
+                        max_adv_fraction: Decimal::new(10, 2),
+                        relaxed_adv_fraction: Decimal::new(5, 2),
+                        illiquid_adv_fraction: Decimal::new(1, 2),
+                        ..LiquidityCapConfig::default()
+                    },
+                    execution_governor: ExecutionGovernorConfig {
+                        elevated_volatility_ratio: Decimal::new(25, 1),
+                        extreme_volatility_ratio: Decimal::new(4, 0),
+                        elevated_spread_bps: Decimal::new(50, 0),
+                        extreme_spread_bps: Decimal::new(150, 0),
+                        size_factor: Decimal::new(7, 1),
+                        limit_offset_bps: Decimal::new(10, 0),
+                        ..ExecutionGovernorConfig::default()
+                    },
+                },
+            },
+        }
+    }
+
+    /// 전체 기본 프로필.
+    pub fn presets() -> Vec<Self> {
+        RiskProfileName::ALL.into_iter().map(Self::preset).collect()
+    }
+
+    /// 비교용 설정 값 (리스크 한도, 사이징, 실행 조절).
+    pub fn settings(&self) -> Value {
+        settings_value(&self.risk, Some(&self.sizing), &self.throttles)
+    }
+}
+
+/// 비교용 설정 값 생성 (`sizing`이 없으면 사이징 미적용).
+pub fn settings_value(
+    risk: &RiskConfig,
+    sizing: Option<&PositionSizingPolicy>,
+    throttles: &ExecutionThrottles,
+) -> Value {
+    serde_json::json!({
+        "risk": risk,
+        "sizing": sizing,
+        "throttles": throttles,
+    })
+}
+
+/// 설정 변경 항목.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SettingChange {
+    /// 설정 경로 (예: "risk.max_position_pct")
+    pub field: String,
+    /// 변경 전 값
+    pub from: Value,
+    /// 변경 후 값
+    pub to: Value,
+}
+
+/// 두 설정 값의 차이 (경로 순).
+///
+/// 객체는 하위 항목 단위로 비교하고, 그 외 값(배열 포함)은 통째로 비교합니다.
+pub fn diff_settings(before: &Value, after: &Value) -> Vec<SettingChange> {
+    let mut changes = Vec::new();
+    collect_changes("", before, after, &mut changes);
+    changes.sort_by(|a, b| a.field.cmp(&b.field));
+    changes
+}
+
+fn collect_changes(path: &str, before: &Value, after: &Value, changes: &mut Vec<SettingChange>) {
+    match (before, after) {
+        (Value::Object(b), Value::Object(a)) => {
+            let mut keys: Vec<&String> = b.keys().chain(a.keys()).collect();
+            keys.sort();
+            keys.dedup();
+            for key in keys {
+                let child = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", path, key)
+                };
+                collect_changes(
+                    &child,
+                    b.get(key).unwrap_or(&Value::Null),
+                    a.get(key).unwrap_or(&Value::Null),
+                    changes,
+                );
+            }
+        }
+        _ if before != after => changes.push(SettingChange {
+            field: path.to_string(),
+            from: before.clone(),
+            to: after.clone(),
+        }),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 숫자로부터 Decimal을 생성하는 헬퍼 매크로
+    macro_rules! dec {
+        ($val:expr) => {
+            Decimal::from_f64($val as f64).unwrap()
+        };
+    }
+
+    #[test]
+    fn test_profile_name_parsing() {
+        assert_eq!(
+            "Conservative".parse::<RiskProfileName>().unwrap(),
+            RiskProfileName::Conservative
+        );
+        assert_eq!(
+            serde_json::to_value(RiskProfileName::Aggressive).unwrap(),
+            serde_json::json!("aggressive")
+        );
+        // "default"/"custom"은 프로필이 아니라 전역 설정/직접 설정을 의미
+        assert!("default".parse::<RiskProfileName>().is_err());
+        assert!("custom".parse::<RiskProfileName>().is_err());
+    }
+
+    #[test]
+    fn test_presets_are_ordered_by_risk() {
+        let [conservative, standard, aggressive] =
+            RiskProfileName::ALL.map(RiskProfile::preset);
+
+        assert!(conservative.risk.max_position_pct < standard.risk.max_position_pct);
+        assert!(standard.risk.max_position_pct < aggressive.risk.max_position_pct);
+        assert!(
+            conservative.throttles.liquidity_cap.max_adv_fraction
+                < aggressive.throttles.liquidity_cap.max_adv_fraction
+        );
+        assert!(
+            conservative.throttles.execution_governor.extreme_spread_bps
+                < aggressive.throttles.execution_governor.extreme_spread_bps
+        );
+        for profile in [conservative, standard, aggressive] {
+            assert!(profile.risk.validate().is_ok(), "{}", profile.name);
+        }
+    }
+
+    #[test]
+    fn test_diff_settings() {
+        let standard = RiskProfile::preset(RiskProfileName::Standard);
+        assert!(diff_settings(&standard.settings(), &standard.settings()).is_empty());
+
+        let conservative = RiskProfile::preset(RiskProfileName::Conservative);
+        let changes = diff_settings(&standard.settings(), &conservative.settings());
+        let max_position = changes
+            .iter()
+            .find(|c| c.field == "risk.max_position_pct")
+            .unwrap();
+        assert_eq!(max_position.from, serde_json::json!(10.0));
+        assert_eq!(max_position.to, serde_json::json!(5.0));
+        assert!(changes
+            .iter()
+            .any(|c| c.field == "throttles.execution_governor.size_factor"));
+        // 같은 값은 포함하지 않음
+        assert!(!changes.iter().any(|c| c.field == "sizing.method"));
+        // 경로 순 정렬
+        assert!(changes.windows(2).all(|w| w[0].field <= w[1].field));
+    }
+
+    #[test]
+    fn test_diff_settings_without_sizing() {
+        let standard = RiskProfile::preset(RiskProfileName::Standard);
+        let current = settings_value(&standard.risk, None, &standard.throttles);
+
+        let changes = diff_settings(&current, &standard.settings());
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].field, "sizing");
+        assert_eq!(changes[0].from, Value::Null);
+    }
+
+    #[test]
+    fn test_fixed_fractional_sizing() {
+        let risk = RiskConfig::default();
+        let sizing = PositionSizingPolicy {
+            method: SizingMethod::FixedFractional,
+            risk_per_trade_pct: 1.0,
+            equity_pct: 5.0,
+        };
+
+        // 잔고 1,000만원의 1% = 10만원 위험, 손절폭 2,000원 → 50주 (500만원)
+        // 최대 포지션 10% (100만원) 제한 → 10주
+        let qty = sizing.entry_quantity(
+            "005930",
+            dec!(10_000_000),
+            dec!(100_000),
+            Some(dec!(98_000)),
+            &risk,
+        );
+        assert_eq!(qty, dec!(10));
+
+        // 손절폭이 넓으면 위험 기준 수량이 더 작음: 10만원 / 20,000원 = 5주
+        let qty = sizing.entry_quantity(
+            "005930",
+            dec!(10_000_000),
+            dec!(100_000),
+            Some(dec!(80_000)),
+            &risk,
+        );
+        assert_eq!(qty, dec!(5));
+    }
+
+    #[test]
+    fn test_percent_of_equity_sizing() {
+        let risk = RiskConfig::default();
+        let sizing = PositionSizingPolicy {
+            method: SizingMethod::PercentOfEquity,
+            risk_per_trade_pct: 1.0,
+            equity_pct: 5.0,
+        };
+
+        // 10,000 USDT의 5% = 500 USDT / 30,000 = 0.01666666 BTC (8자리 내림)
+        let qty = sizing.entry_quantity("BTC/USDT", dec!(10_000), dec!(30_000), None, &risk);
+        assert_eq!(qty, Decimal::new(1_666_666, 8));
+
+        assert_eq!(
+            sizing.entry_quantity("BTC/USDT", Decimal::ZERO, dec!(30_000), None, &risk),
+            Decimal::ZERO
+        );
+    }
+}
@@ -0,0 +1,410 @@
+//! 전략별 가상 서브 포트폴리오.
+//!
+//! 주문의 `strategy_id`로 체결을 원 전략에 귀속시키고, 전략마다 별도 `PositionTracker`를
+//! 가상 계좌로 두어 자본, 손익, 익스포저, 낙폭을 전략 단위로 추적합니다.
+//! 실제 계좌 포지션은 실행기의 트래커가 종목별로 관리하므로, 여러 전략이 같은 종목을
+//! 거래하면 실계좌 포지션은 하나로 합쳐지지만 가상 계좌에서는 전략별로 나뉩니다.
+//! 전략 ID가 없는 수동 주문은 [`MANUAL_STRATEGY_ID`] 계좌로 모입니다.
+
+use chrono::{DateTime, Utc};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use trader_core::{FuturesContract, Order, Position};
+use uuid::Uuid;
+
+use crate::order_manager::OrderFill;
+use crate::position_tracker::{PositionTracker, PositionTrackerError};
+
+/// 전략 ID가 없는 주문(수동 주문)을 모으는 가상 계좌 키.
+pub const MANUAL_STRATEGY_ID: &str = "manual";
+
+/// 주문의 전략 ID에 해당하는 가상 계좌 키.
+pub fn strategy_key(strategy_id: Option<&str>) -> &str {
+    strategy_id.unwrap_or(MANUAL_STRATEGY_ID)
+}
+
+/// 비율(%) 계산 (분모가 0 이하이면 0).
+fn pct(value: Decimal, base: Decimal) -> f64 {
+    if base <= Decimal::ZERO {
+        return 0.0;
+    }
+    (value / base * Decimal::ONE_HUNDRED)
+        .to_f64()
+        .unwrap_or(0.0)
+}
+
+/// 전략 가상 계좌 스냅샷 (API/자산 곡선 기록용).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StrategyPortfolioSnapshot {
+    pub strategy_id: String,
+    /// 할당 자본
+    pub capital: Decimal,
+    /// 체결 현금 흐름 반영 후 현금
+    pub cash: Decimal,
+    /// 자기자본 (현금 + 포지션 평가액)
+    pub equity: Decimal,
+    /// 누적 실현 손익 (거래 비용 차감 후)
+    pub realized_pnl: Decimal,
+    pub unrealized_pnl: Decimal,
+    /// 누적 거래 비용
+    pub fees: Decimal,
+    /// 총 명목 익스포저
+    pub exposure: Decimal,
+    /// 자기자본 대비 익스포저 (%)
+    pub exposure_pct: f64,
+    /// 할당 자본 대비 수익률 (%, 자본이 없으면 `None`)
+    pub return_pct: Option<f64>,
+    /// 최고 자기자본
+    pub peak_equity: Decimal,
+    /// 현재 낙폭 (최고 자기자본 - 자기자본)
+    pub drawdown: Decimal,
+    pub drawdown_pct: f64,
+    pub max_drawdown: Decimal,
+    pub max_drawdown_pct: f64,
+    pub fill_count: u64,
+    /// 열린 포지션
+    pub positions: Vec<Position>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// 전략 하나의 가상 계좌.
+#[derive(Debug)]
+pub struct StrategySubPortfolio {
+    strategy_id: String,
+    tracker: PositionTracker,
+    /// 누적 거래 비용
+    fees: Decimal,
+    fill_count: u64,
+    /// 최고 자기자본 (자본 입출금분은 보정)
+    peak_equity: Decimal,
+    max_drawdown: Decimal,
+    max_drawdown_pct: f64,
+    updated_at: DateTime<Utc>,
+}
+
+impl StrategySubPortfolio {
+    fn new(strategy_id: &str, exchange: &str, capital: Decimal) -> Self {
+        Self {
+            strategy_id: strategy_id.to_string(),
+            tracker: PositionTracker::new(exchange)
+                .with_account(strategy_id)
+                .with_initial_cash(capital),
+            fees: Decimal::ZERO,
+            fill_count: 0,
+            peak_equity: capital,
+            max_drawdown: Decimal::ZERO,
+            max_drawdown_pct: 0.0,
+            updated_at: Utc::now(),
+        }
+    }
+
+    /// 전략 ID.
+    pub fn strategy_id(&self) -> &str {
+        &self.strategy_id
+    }
+
+    /// 가상 계좌의 포지션 트래커.
+    pub fn tracker(&self) -> &PositionTracker {
+        &self.tracker
+    }
+
+    /// 할당 자본.
+    pub fn capital(&self) -> Decimal {
+        self.tracker.initial_cash()
+    }
+
+    /// 자기자본 (현금 + 포지션 평가액).
+    pub fn equity(&self) -> Decimal {
+        self.tracker.equity()
+    }
+
+    /// 현재 낙폭.
+    pub fn drawdown(&self) -> Decimal {
+        (self.peak_equity - self.equity()).max(Decimal::ZERO)
+    }
+
+    /// 할당 자본 변경. 입출금은 손익이 아니므로 최고 자기자본도 같은 만큼 옮깁니다.
+    fn set_capital(&mut self, capital: Decimal) {
+        let delta = capital - self.capital();
+        if delta.is_zero() {
+            return;
+        }
+        self.tracker.set_initial_cash(capital);
+        self.peak_equity += delta;
+        self.mark();
+    }
+
+    /// 자기자본 변화에 따른 최고치/낙폭 갱신.
+    fn mark(&mut self) {
+        let equity = self.equity();
+        if equity > self.peak_equity {
+            self.peak_equity = equity;
+        }
+        let drawdown = self.drawdown();
+        if drawdown > self.max_drawdown {
+            self.max_drawdown = drawdown;
+        }
+        self.max_drawdown_pct = self.max_drawdown_pct.max(pct(drawdown, self.peak_equity));
+        self.updated_at = Utc::now();
+    }
+
+    /// 스냅샷 생성.
+    pub fn snapshot(&self) -> StrategyPortfolioSnapshot {
+        let capital = self.capital();
+        let equity = self.equity();
+        let drawdown = self.drawdown();
+        let exposure = self.tracker.total_exposure();
+        let mut positions: Vec<Position> = self
+            .tracker
+            .get_open_positions()
+            .into_iter()
+            .cloned()
+            .collect();
+        positions.sort_by(|a, b| a.ticker.cmp(&b.ticker));
+
+        StrategyPortfolioSnapshot {
+            strategy_id: self.strategy_id.clone(),
+            capital,
+            cash: self.tracker.cash(),
+            equity,
+            realized_pnl: self.tracker.cumulative_realized_pnl(),
+            unrealized_pnl: self.tracker.total_unrealized_pnl(),
+            fees: self.fees,
+            exposure,
+            exposure_pct: pct(exposure, equity),
+            return_pct: (capital > Decimal::ZERO).then(|| pct(equity - capital, capital)),
+            peak_equity: self.peak_equity,
+            drawdown,
+            drawdown_pct: pct(drawdown, self.peak_equity),
+            max_drawdown: self.max_drawdown,
+            max_drawdown_pct: self.max_drawdown_pct,
+            fill_count: self.fill_count,
+            positions,
+            updated_at: self.updated_at,
+        }
+    }
+}
+
+/// 전략별 가상 계좌 장부.
+#[derive(Debug)]
+pub struct StrategyPortfolios {
+    exchange: String,
+    portfolios: HashMap<String, StrategySubPortfolio>,
+    /// 새 가상 계좌에도 등록할 선물 계약 명세
+    futures_contracts: Vec<FuturesContract>,
+}
+
+impl StrategyPortfolios {
+    /// 새 장부 생성.
+    pub fn new(exchange: impl Into<String>) -> Self {
+        Self {
+            exchange: exchange.into(),
+            portfolios: HashMap::new(),
+            futures_contracts: Vec::new(),
+        }
+    }
+
+    fn portfolio_mut(&mut self, strategy_id: &str) -> &mut StrategySubPortfolio {
+        let exchange = &self.exchange;
+        let contracts = &self.futures_contracts;
+        self.portfolios
+            .entry(strategy_id.to_string())
+            .or_insert_with(|| {
+                let mut portfolio = StrategySubPortfolio::new(strategy_id, exchange, Decimal::ZERO);
+                for contract in contracts {
+                    let _ = portfolio
+                        .tracker
+                        .register_futures_contract(contract.clone());
+                }
+                portfolio
+            })
+    }
+
+    /// 선물 계약 명세 등록 (모든 가상 계좌에 적용).
+    pub fn register_futures_contract(
+        &mut self,
+        contract: FuturesContract,
+    ) -> Result<(), PositionTrackerError> {
+        for portfolio in self.portfolios.values_mut() {
+            portfolio
+                .tracker
+                .register_futures_contract(contract.clone())?;
+        }
+        self.futures_contracts.retain(|c| c.code != contract.code);
+        self.futures_contracts.push(contract);
+        Ok(())
+    }
+
+    /// 전략 할당 자본 설정 (가상 계좌가 없으면 생성).
+    pub fn set_capital(&mut self, strategy_id: &str, capital: Decimal) {
+        self.portfolio_mut(strategy_id).set_capital(capital);
+    }
+
+    /// 체결을 주문의 전략 가상 계좌에 반영.
+    pub fn apply_fill(
+        &mut self,
+        order: &Order,
+        fill: &OrderFill,
+    ) -> Result<Position, PositionTrackerError> {
+        let portfolio = self.portfolio_mut(strategy_key(order.strategy_id.as_deref()));
+        let position = portfolio.tracker.apply_fill(order, fill)?;
+        portfolio.fill_count += 1;
+        portfolio.mark();
+        Ok(position)
+    }
+
+    /// 거래 비용 차감 (`position_id`는 가상 계좌 포지션 ID).
+    pub fn charge_cost(&mut self, strategy_id: Option<&str>, position_id: Uuid, cost: Decimal) {
+        let portfolio = self.portfolio_mut(strategy_key(strategy_id));
+        portfolio.tracker.charge_cost(position_id, cost);
+        portfolio.fees += cost;
+        portfolio.mark();
+    }
+
+    /// 모든 가상 계좌의 시장 가격 갱신.
+    pub fn update_prices(&mut self, prices: &HashMap<String, Decimal>) {
+        for portfolio in self.portfolios.values_mut() {
+            if portfolio.tracker.open_position_count() == 0 {
+                continue;
+            }
+            portfolio.tracker.update_prices(prices);
+            portfolio.mark();
+        }
+    }
+
+    /// 전략 가상 계좌 조회.
+    pub fn get(&self, strategy_id: &str) -> Option<&StrategySubPortfolio> {
+        self.portfolios.get(strategy_id)
+    }
+
+    /// 전략 가상 계좌 제거.
+    pub fn remove(&mut self, strategy_id: &str) -> Option<StrategySubPortfolio> {
+        self.portfolios.remove(strategy_id)
+    }
+
+    /// 전략 스냅샷.
+    pub fn snapshot(&self, strategy_id: &str) -> Option<StrategyPortfolioSnapshot> {
+        self.get(strategy_id).map(StrategySubPortfolio::snapshot)
+    }
+
+    /// 모든 전략 스냅샷 (전략 ID 순).
+    pub fn snapshots(&self) -> Vec<StrategyPortfolioSnapshot> {
+        let mut snapshots: Vec<_> = self
+            .portfolios
+            .values()
+            .map(StrategySubPortfolio::snapshot)
+            .collect();
+        snapshots.sort_by(|a, b| a.strategy_id.cmp(&b.strategy_id));
+        snapshots
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal::prelude::FromPrimitive;
+    use trader_core::{OrderRequest, Side};
+
+    macro_rules! dec {
+        ($val:expr) => {
+            Decimal::from_f64($val as f64).unwrap()
+        };
+    }
+
+    fn fill(
+        strategy_id: Option<&str>,
+        side: Side,
+        quantity: i64,
+        price: i64,
+    ) -> (Order, OrderFill) {
+        let mut request = match side {
+            Side::Buy => OrderRequest::market_buy("005930".to_string(), dec!(quantity)),
+            Side::Sell => OrderRequest::market_sell("005930".to_string(), dec!(quantity)),
+        };
+        if let Some(id) = strategy_id {
+            request = request.with_strategy(id);
+        }
+        let order = Order::from_request(request, "KIS-KR");
+        let fill = OrderFill {
+            order_id: order.id,
+            quantity: dec!(quantity),
+            price: dec!(price),
+            commission: None,
+            commission_asset: None,
+            timestamp: Utc::now(),
+        };
+        (order, fill)
+    }
+
+    #[test]
+    fn test_fills_are_attributed_per_strategy() {
+        let mut book = StrategyPortfolios::new("KIS-KR");
+        book.set_capital("momentum", dec!(1_000_000));
+        book.set_capital("mean_rev", dec!(500_000));
+
+        // 같은 종목을 두 전략이 반대 방향으로 거래해도 가상 계좌는 분리됨
+        let (order, f) = fill(Some("momentum"), Side::Buy, 10, 70_000);
+        book.apply_fill(&order, &f).unwrap();
+        let (order, f) = fill(Some("mean_rev"), Side::Buy, 5, 71_000);
+        book.apply_fill(&order, &f).unwrap();
+        let (order, f) = fill(None, Side::Buy, 1, 70_000);
+        let manual = book.apply_fill(&order, &f).unwrap();
+        book.charge_cost(None, manual.id, dec!(100));
+
+        let mut prices = HashMap::new();
+        prices.insert("005930".to_string(), dec!(72_000));
+        book.update_prices(&prices);
+
+        let momentum = book.snapshot("momentum").unwrap();
+        assert_eq!(momentum.positions.len(), 1);
+        assert_eq!(momentum.unrealized_pnl, dec!(20_000));
+        assert_eq!(momentum.equity, dec!(1_020_000));
+        assert_eq!(momentum.exposure, dec!(720_000));
+        assert_eq!(momentum.return_pct, Some(2.0));
+
+        let mean_rev = book.snapshot("mean_rev").unwrap();
+        assert_eq!(mean_rev.unrealized_pnl, dec!(5_000));
+
+        let manual = book.snapshot(MANUAL_STRATEGY_ID).unwrap();
+        assert_eq!(manual.fees, dec!(100));
+        assert_eq!(manual.realized_pnl, dec!(-100));
+        assert_eq!(manual.return_pct, None);
+        assert_eq!(book.snapshots().len(), 3);
+    }
+
+    #[test]
+    fn test_drawdown_tracking_ignores_capital_changes() {
+        let mut book = StrategyPortfolios::new("KIS-KR");
+        book.set_capital("momentum", dec!(1_000_000));
+        let (order, f) = fill(Some("momentum"), Side::Buy, 10, 70_000);
+        book.apply_fill(&order, &f).unwrap();
+
+        let mut prices = HashMap::new();
+        prices.insert("005930".to_string(), dec!(80_000));
+        book.update_prices(&prices);
+        prices.insert("005930".to_string(), dec!(75_000));
+        book.update_prices(&prices);
+
+        let snapshot = book.snapshot("momentum").unwrap();
+        assert_eq!(snapshot.peak_equity, dec!(1_100_000));
+        assert_eq!(snapshot.drawdown, dec!(50_000));
+        assert_eq!(snapshot.max_drawdown, dec!(50_000));
+
+        // 출금은 낙폭이 아님
+        book.set_capital("momentum", dec!(900_000));
+        let snapshot = book.snapshot("momentum").unwrap();
+        assert_eq!(snapshot.peak_equity, dec!(1_000_000));
+        assert_eq!(snapshot.drawdown, dec!(50_000));
+
+        // 청산 후 실현 손익
+        let (order, f) = fill(Some("momentum"), Side::Sell, 10, 75_000);
+        book.apply_fill(&order, &f).unwrap();
+        let snapshot = book.snapshot("momentum").unwrap();
+        assert!(snapshot.positions.is_empty());
+        assert_eq!(snapshot.realized_pnl, dec!(50_000));
+        assert_eq!(snapshot.equity, dec!(950_000));
+        assert_eq!(snapshot.fill_count, 2);
+    }
+}
@@ -0,0 +1,187 @@
+//! 합성 종목 신호의 leg 주문 분해.
+//!
+//! `SYN_<코드>` 티커의 신호는 거래소에 직접 주문할 수 없으므로, 신호 메타데이터의
+//! [`SyntheticSignalSpec`]으로 leg별 수량 주문을 만들어 바스켓으로 실행합니다.
+//!
+//! - 진입: 가격식 계수(비율식은 신호 시점 가격비)로 leg 수량/방향 계산
+//! - 청산: 가격식 leg의 현재 보유 수량을 모두 반대 방향으로 주문
+//!
+//! leg 하나만 체결되면 의도하지 않은 방향성 포지션이 남으므로 바스켓 정책은
+//! [`BasketFailurePolicy::AllOrNone`]을 사용합니다.
+
+use std::collections::HashMap;
+
+use rust_decimal::Decimal;
+use trader_core::{OrderType, Side, Signal, SignalType, SyntheticExpression, SyntheticSignalSpec};
+
+use crate::basket::{BasketFailurePolicy, BasketLeg, BasketRequest, BasketTarget};
+
+/// 합성 종목 신호를 leg 바스켓 주문으로 변환.
+///
+/// # Arguments
+///
+/// * `signal` - `SYN_` 티커 신호 (메타데이터에 [`SyntheticSignalSpec`] 필요)
+/// * `default_units` - 신호에 진입 수량이 없을 때 사용할 합성 종목 단위 수량
+/// * `positions` - leg 종목별 순보유 수량 (롱 양수, 숏 음수)
+pub fn plan_synthetic_basket(
+    signal: &Signal,
+    default_units: Decimal,
+    positions: &HashMap<String, Decimal>,
+) -> Result<BasketRequest, String> {
+    let spec = SyntheticSignalSpec::from_signal(signal)?;
+    let expression = SyntheticExpression::parse(&spec.expression)?;
+    expression.validate()?;
+
+    let legs = match signal.signal_type {
+        SignalType::Entry | SignalType::AddToPosition => {
+            let units = spec.units.unwrap_or(default_units);
+            if units <= Decimal::ZERO {
+                return Err(format!(
+                    "{}: synthetic entry units must be positive",
+                    signal.ticker
+                ));
+            }
+            expression
+                .decompose(signal.side, units, &spec.leg_prices)?
+                .into_iter()
+                .map(|leg| BasketLeg {
+                    ticker: leg.ticker,
+                    target: BasketTarget::Quantity {
+                        side: leg.side,
+                        quantity: leg.quantity,
+                    },
+                    order_type: OrderType::Market,
+                    limit_price: None,
+                    current_price: leg.price,
+                })
+                .collect::<Vec<_>>()
+        }
+        SignalType::Exit | SignalType::ReducePosition => {
+            let legs: Vec<BasketLeg> = expression
+                .tickers()
+                .into_iter()
+                .filter_map(|ticker| {
+                    let held = positions.get(&ticker).copied().unwrap_or(Decimal::ZERO);
+                    if held.is_zero() {
+                        return None;
+                    }
+                    let side = if held > Decimal::ZERO {
+                        Side::Sell
+                    } else {
+                        Side::Buy
+                    };
+                    let price = spec.leg_prices.get(&ticker).copied().unwrap_or_default();
+                    Some(BasketLeg {
+                        ticker,
+                        target: BasketTarget::Quantity {
+                            side,
+                            quantity: held.abs(),
+                        },
+                        order_type: OrderType::Market,
+                        limit_price: None,
+                        current_price: price,
+                    })
+                })
+                .collect();
+            if legs.is_empty() {
+                return Err(format!("{}: no synthetic legs are held", signal.ticker));
+            }
+            legs
+        }
+        _ => {
+            return Err(format!(
+                "{}: unsupported synthetic signal type {:?}",
+                signal.ticker, signal.signal_type
+            ))
+        }
+    };
+
+    Ok(BasketRequest {
+        legs,
+        total_value: None,
+        policy: BasketFailurePolicy::AllOrNone,
+        strategy_id: Some(signal.strategy_id.clone()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal::prelude::FromPrimitive;
+    use trader_core::SYNTHETIC_SIGNAL_KEY;
+
+    macro_rules! dec {
+        ($val:expr) => {
+            Decimal::from_f64($val as f64).unwrap()
+        };
+    }
+
+    fn spec(expression: &str, units: Option<Decimal>) -> SyntheticSignalSpec {
+        SyntheticSignalSpec {
+            expression: expression.to_string(),
+            units,
+            leg_prices: HashMap::from([
+                ("005930".to_string(), dec!(70000)),
+                ("000660".to_string(), dec!(140000)),
+            ]),
+        }
+    }
+
+    fn quantity_of(leg: &BasketLeg) -> (Side, Decimal) {
+        match leg.target {
+            BasketTarget::Quantity { side, quantity } => (side, quantity),
+            BasketTarget::Weight { .. } => panic!("unexpected weight leg"),
+        }
+    }
+
+    #[test]
+    fn test_plan_entry_decomposes_legs() {
+        let signal = Signal::entry("pairs", "SYN_SEC".to_string(), Side::Sell).with_metadata(
+            SYNTHETIC_SIGNAL_KEY,
+            spec("005930 - 0.5*000660", Some(dec!(10))).to_value(),
+        );
+
+        let request = plan_synthetic_basket(&signal, dec!(1), &HashMap::new()).unwrap();
+        assert_eq!(request.policy, BasketFailurePolicy::AllOrNone);
+        assert_eq!(request.strategy_id.as_deref(), Some("pairs"));
+        assert_eq!(request.legs.len(), 2);
+        assert_eq!(request.legs[0].ticker, "005930");
+        assert_eq!(quantity_of(&request.legs[0]), (Side::Sell, dec!(10)));
+        assert_eq!(request.legs[1].ticker, "000660");
+        assert_eq!(quantity_of(&request.legs[1]), (Side::Buy, dec!(5)));
+        assert_eq!(request.legs[1].current_price, dec!(140000));
+
+        // 진입 수량이 없으면 기본 수량 사용
+        let signal = Signal::entry("pairs", "SYN_SEC".to_string(), Side::Buy).with_metadata(
+            SYNTHETIC_SIGNAL_KEY,
+            spec("005930 / 000660", None).to_value(),
+        );
+        let request = plan_synthetic_basket(&signal, dec!(4), &HashMap::new()).unwrap();
+        assert_eq!(quantity_of(&request.legs[0]), (Side::Buy, dec!(4)));
+        assert_eq!(quantity_of(&request.legs[1]), (Side::Sell, dec!(2)));
+    }
+
+    #[test]
+    fn test_plan_exit_closes_held_legs() {
+        let signal = Signal::exit("pairs", "SYN_SEC".to_string(), Side::Buy).with_metadata(
+            SYNTHETIC_SIGNAL_KEY,
+            spec("005930 - 000660", None).to_value(),
+        );
+        let positions = HashMap::from([
+            ("005930".to_string(), dec!(-10)),
+            ("000660".to_string(), dec!(10)),
+        ]);
+
+        let request = plan_synthetic_basket(&signal, dec!(1), &positions).unwrap();
+        assert_eq!(quantity_of(&request.legs[0]), (Side::Buy, dec!(10)));
+        assert_eq!(quantity_of(&request.legs[1]), (Side::Sell, dec!(10)));
+
+        assert!(plan_synthetic_basket(&signal, dec!(1), &HashMap::new()).is_err());
+    }
+
+    #[test]
+    fn test_plan_requires_metadata() {
+        let signal = Signal::entry("pairs", "SYN_SEC".to_string(), Side::Buy);
+        assert!(plan_synthetic_basket(&signal, dec!(1), &HashMap::new()).is_err());
+    }
+}
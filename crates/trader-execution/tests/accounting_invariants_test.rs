@@ -0,0 +1,223 @@
+//! 회계 경로 속성 기반 테스트.
+//!
+//! 무작위 체결/가격 변동/거래 비용 시퀀스를 `PositionTracker`와 `OrderManager`에 적용하고,
+//! 매 단계마다 다음 성질이 유지되는지 확인합니다:
+//! - 현금 + 포지션 평가액 = 초기 자금 + 실현 손익 + 미실현 손익
+//! - 트래커 현금 = 체결 현금 흐름을 따로 합산한 값
+//! - 포지션/주문 수량은 음수가 되지 않고, 체결 수량은 주문 수량을 넘지 않음
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use chrono::Utc;
+use proptest::prelude::*;
+use rust_decimal::Decimal;
+use trader_core::{Order, OrderRequest, OrderStatusType, Side};
+use trader_execution::{
+    InvariantKind, InvariantMonitor, InvariantViolation, OrderFill, OrderManager, PositionTracker,
+};
+
+const TICKERS: [&str; 2] = ["005930", "AAPL"];
+
+#[derive(Debug, Clone)]
+enum Op {
+    /// 체결 (반대 방향 체결은 보유 수량까지만)
+    Fill {
+        ticker: usize,
+        buy: bool,
+        quantity: u32,
+        price_cents: i64,
+    },
+    /// 시세 변경
+    Price { ticker: usize, price_cents: i64 },
+    /// 거래 비용 차감
+    Cost { ticker: usize, cost_cents: i64 },
+}
+
+fn op_strategy() -> impl Strategy<Value = Op> {
+    prop_oneof![
+        3 => (0..TICKERS.len(), any::<bool>(), 1u32..50, 100i64..1_000_000).prop_map(
+            |(ticker, buy, quantity, price_cents)| Op::Fill {
+                ticker,
+                buy,
+                quantity,
+                price_cents,
+            }
+        ),
+        1 => (0..TICKERS.len(), 100i64..1_000_000)
+            .prop_map(|(ticker, price_cents)| Op::Price { ticker, price_cents }),
+        1 => (0..TICKERS.len(), 0i64..10_000)
+            .prop_map(|(ticker, cost_cents)| Op::Cost { ticker, cost_cents }),
+    ]
+}
+
+fn fill_for(order: &Order, quantity: Decimal, price: Decimal) -> OrderFill {
+    OrderFill {
+        order_id: order.id,
+        quantity,
+        price,
+        commission: None,
+        commission_asset: None,
+        timestamp: Utc::now(),
+    }
+}
+
+/// 연산을 적용하고 발생한 현금 흐름을 반환 (매수 -, 매도 +, 비용 -).
+fn apply(tracker: &mut PositionTracker, op: &Op) -> Decimal {
+    match *op {
+        Op::Fill {
+            ticker,
+            buy,
+            quantity,
+            price_cents,
+        } => {
+            let ticker = TICKERS[ticker].to_string();
+            let side = if buy { Side::Buy } else { Side::Sell };
+            let price = Decimal::new(price_cents, 2);
+            let mut quantity = Decimal::from(quantity);
+
+            // 반대 방향 체결은 보유 수량을 넘길 수 없음 (초과분은 거래소에서 거부됨)
+            if let Some(position) = tracker.get_position_for_symbol(&ticker) {
+                if position.side != side {
+                    quantity = quantity.min(position.quantity);
+                }
+            }
+
+            let request = match side {
+                Side::Buy => OrderRequest::market_buy(ticker, quantity),
+                Side::Sell => OrderRequest::market_sell(ticker, quantity),
+            };
+            let order = Order::from_request(request, "test");
+            tracker
+                .apply_fill(&order, &fill_for(&order, quantity, price))
+                .unwrap();
+
+            match side {
+                Side::Buy => -(quantity * price),
+                Side::Sell => quantity * price,
+            }
+        }
+        Op::Price {
+            ticker,
+            price_cents,
+        } => {
+            let _ = tracker.update_price(TICKERS[ticker], Decimal::new(price_cents, 2));
+            Decimal::ZERO
+        }
+        Op::Cost { ticker, cost_cents } => {
+            let cost = Decimal::new(cost_cents, 2);
+            match tracker
+                .get_position_for_symbol(TICKERS[ticker])
+                .map(|p| p.id)
+            {
+                Some(id) => {
+                    assert!(tracker.charge_cost(id, cost));
+                    -cost
+                }
+                None => Decimal::ZERO,
+            }
+        }
+    }
+}
+
+fn approx_eq(a: Decimal, b: Decimal) -> bool {
+    (a - b).abs() <= Decimal::new(1, 6)
+}
+
+proptest! {
+    #[test]
+    fn prop_position_tracker_equity_identity(
+        initial_cash in 0i64..100_000_000,
+        ops in prop::collection::vec(op_strategy(), 1..80),
+    ) {
+        let initial_cash = Decimal::new(initial_cash, 2);
+        // 작은 히스토리로 종료 포지션 정리 경로까지 포함
+        let mut tracker = PositionTracker::with_history_size("test", 5)
+            .with_initial_cash(initial_cash);
+        let mut cash = initial_cash;
+
+        for op in &ops {
+            cash += apply(&mut tracker, op);
+
+            let violations = tracker.check_invariants();
+            prop_assert!(violations.is_empty(), "{:?} after {:?}", violations, op);
+            prop_assert_eq!(tracker.cash(), cash);
+            prop_assert!(tracker
+                .get_all_positions()
+                .iter()
+                .all(|p| p.quantity >= Decimal::ZERO));
+            prop_assert!(approx_eq(
+                tracker.equity(),
+                initial_cash + tracker.cumulative_realized_pnl() + tracker.total_unrealized_pnl()
+            ));
+        }
+
+        // 전량 청산 후에는 현금 = 초기 자금 + 누적 실현 손익
+        let open: Vec<(String, Decimal)> = tracker
+            .get_all_positions()
+            .iter()
+            .map(|p| (p.ticker.clone(), p.current_price))
+            .collect();
+        for (ticker, price) in open {
+            tracker.close_position(&ticker, price).unwrap();
+        }
+        prop_assert_eq!(tracker.open_position_count(), 0);
+        prop_assert!(approx_eq(
+            tracker.cash(),
+            initial_cash + tracker.cumulative_realized_pnl()
+        ));
+    }
+
+    #[test]
+    fn prop_order_manager_fills_never_exceed_quantity(
+        quantity in 1u32..1_000,
+        fills in prop::collection::vec((1u32..300, 100i64..100_000), 1..20),
+    ) {
+        let mut manager = OrderManager::new();
+        let request = OrderRequest::market_buy("005930".to_string(), Decimal::from(quantity));
+        let order = manager.create_order(request, "test").unwrap();
+
+        for (fill_quantity, price_cents) in fills {
+            let current = manager.get_order(order.id).unwrap().clone();
+            if current.status.is_final() {
+                break;
+            }
+            let fill_quantity = Decimal::from(fill_quantity).min(current.remaining_quantity());
+            manager
+                .record_fill(fill_for(&current, fill_quantity, Decimal::new(price_cents, 2)))
+                .unwrap();
+
+            let updated = manager.get_order(order.id).unwrap();
+            prop_assert!(updated.filled_quantity >= Decimal::ZERO);
+            prop_assert!(updated.filled_quantity <= updated.quantity);
+            prop_assert_eq!(
+                updated.status == OrderStatusType::Filled,
+                updated.filled_quantity == updated.quantity
+            );
+            prop_assert!(manager.check_invariants().is_empty());
+        }
+    }
+}
+
+#[test]
+fn test_overfill_reported_to_hook() {
+    let violations = Arc::new(AtomicUsize::new(0));
+    let counter = violations.clone();
+    let mut manager = OrderManager::new().with_invariant_monitor(
+        InvariantMonitor::new()
+            .with_panic_on_violation(false)
+            .with_hook(Arc::new(move |v: &InvariantViolation| {
+                assert_eq!(v.kind, InvariantKind::Overfill);
+                counter.fetch_add(1, Ordering::SeqCst);
+            })),
+    );
+
+    let request = OrderRequest::market_buy("005930".to_string(), Decimal::from(10));
+    let order = manager.create_order(request, "test").unwrap();
+    manager
+        .record_fill(fill_for(&order, Decimal::from(12), Decimal::from(100)))
+        .unwrap();
+
+    assert_eq!(violations.load(Ordering::SeqCst), 1);
+    assert_eq!(manager.check_invariants().len(), 1);
+}
@@ -0,0 +1,594 @@
+//! 시나리오 실행기.
+//!
+//! 시나리오마다 새 컴포넌트를 부팅합니다.
+//!
+//! - `StrategyEngine`: 스크립트 전략 등록/시작, 출력 채널로 신호 수신
+//! - `OrderExecutor` + `RiskManager`: 신호 → 주문 변환과 리스크 검증
+//! - `SimulatedExchange`: 주문 제출과 체결
+//! - `AppState`: 체결통보 반영(`apply_user_event`)과 REST API 검증
+//! - `NotificationManager`: 체결 알림 전송 (기록용 전송기)
+//!
+//! 캔들마다 거래소 → 엔진 → 실행기 → 거래소 순서로 진행하고, 거래소 체결 내역을
+//! 실거래와 같은 `UserEvent::Fill`로 실행기에 전달합니다. 실행기 이벤트로 매매일지와
+//! 알림을 만든 뒤 마지막에 API 응답과 기대 결과를 대조합니다.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use rust_decimal::Decimal;
+use serde::Serialize;
+use serde_json::{json, Value};
+use tokio::sync::{broadcast, mpsc};
+use tower::ServiceExt;
+use trader_analytics::JournalTradeInput;
+use trader_api::services::apply_user_event;
+use trader_api::services::notification_digest::fill_notification;
+use trader_api::AppState;
+use trader_core::{Kline, MarketData, Side, Signal};
+use trader_exchange::{
+    FillReport, FillType, RetryConfig, SimulatedConfig, SimulatedExchange, UserEvent,
+};
+use trader_execution::{ConversionConfig, ExecutionEvent, OrderExecutor};
+use trader_notification::{
+    Notification, NotificationManager, NotificationResult, NotificationSender,
+};
+use trader_risk::{RiskConfig, RiskManager};
+use trader_strategy::{EngineConfig, StrategyEngine};
+use uuid::Uuid;
+
+use crate::scenario::{Expectations, Scenario};
+use crate::strategy::ScriptedStrategy;
+
+/// 실행기/시장 데이터에 기록되는 거래소 이름.
+const EXCHANGE_NAME: &str = "simulated";
+
+/// 시나리오 전략 ID.
+const STRATEGY_ID: &str = "integration";
+
+/// 단계별 처리 건수.
+#[derive(Debug, Clone, Default, Serialize, PartialEq, Eq)]
+pub struct FlowCounts {
+    pub signals: usize,
+    pub orders: usize,
+    pub rejected: usize,
+    pub fills: usize,
+    pub journal_entries: usize,
+    pub notifications: usize,
+}
+
+/// 시나리오 실행 결과.
+#[derive(Debug, Clone, Serialize)]
+pub struct ScenarioReport {
+    pub scenario: String,
+    pub counts: FlowCounts,
+    /// 종료 시 보유 수량 (매도 포지션은 음수)
+    pub final_position: Decimal,
+    /// 전략 가상 계좌 누적 실현 손익
+    pub realized_pnl: Decimal,
+    pub journal: Vec<JournalTradeInput>,
+    /// 실행기 거부/제출 실패 사유
+    pub rejections: Vec<String>,
+    /// 기대 결과 불일치 및 API 검증 실패
+    pub failures: Vec<String>,
+}
+
+impl ScenarioReport {
+    pub fn passed(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+/// 전송한 알림을 기록하는 전송기.
+#[derive(Clone, Default)]
+struct RecordingSender {
+    sent: Arc<Mutex<Vec<Notification>>>,
+}
+
+#[async_trait]
+impl NotificationSender for RecordingSender {
+    async fn send(&self, notification: &Notification) -> NotificationResult<()> {
+        self.sent
+            .lock()
+            .expect("notification log poisoned")
+            .push(notification.clone());
+        Ok(())
+    }
+
+    fn is_enabled(&self) -> bool {
+        true
+    }
+
+    fn name(&self) -> &str {
+        "recording"
+    }
+}
+
+/// 매매일지에 아직 반영되지 않은 체결.
+struct PendingFill {
+    order_id: Uuid,
+    side: Side,
+    quantity: Decimal,
+    price: Decimal,
+    executed_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// 부팅된 컴포넌트와 시나리오 진행 상태.
+struct Harness {
+    state: Arc<AppState>,
+    exchange: SimulatedExchange,
+    signals: mpsc::Receiver<Signal>,
+    events: broadcast::Receiver<ExecutionEvent>,
+    notifier: NotificationManager,
+    sent: Arc<Mutex<Vec<Notification>>>,
+    /// 거래소 주문 ID → 주문 방향
+    submitted: HashMap<String, Side>,
+    /// 처리한 거래소 주문 이력 수
+    history_cursor: usize,
+    /// 내부 주문 ID별 수수료
+    fees: HashMap<Uuid, Decimal>,
+    pending_fill: Option<PendingFill>,
+    /// 종목별 현재 포지션의 직전 누적 실현 손익
+    position_realized: HashMap<String, Decimal>,
+    retry: RetryConfig,
+    ticker: String,
+    counts: FlowCounts,
+    journal: Vec<JournalTradeInput>,
+    rejections: Vec<String>,
+    failures: Vec<String>,
+}
+
+impl Harness {
+    /// 시나리오 설정으로 컴포넌트 부팅.
+    async fn boot(scenario: &Scenario) -> anyhow::Result<Self> {
+        let exchange = SimulatedExchange::new(SimulatedConfig::default().with_initial_balance(
+            &SimulatedExchange::parse_quote(&scenario.ticker),
+            scenario.initial_cash,
+        ));
+
+        let mut engine = StrategyEngine::new(EngineConfig::default());
+        let signals = engine
+            .take_signal_receiver()
+            .ok_or_else(|| anyhow::anyhow!("signal receiver already taken"))?;
+        engine
+            .register_strategy(
+                STRATEGY_ID,
+                Box::new(ScriptedStrategy::new(STRATEGY_ID, scenario.signals.clone())),
+                json!({}),
+                Some(scenario.name.clone()),
+            )
+            .await?;
+        engine.start_strategy(STRATEGY_ID).await?;
+
+        // 손절/익절 자동 주문은 시나리오 체결 수를 바꾸므로 끔
+        let conversion = ConversionConfig {
+            default_quantity: scenario.order_quantity,
+            auto_stop_loss: false,
+            auto_take_profit: false,
+            ..Default::default()
+        };
+        let executor = OrderExecutor::new_complete(
+            RiskManager::new(RiskConfig::default(), scenario.initial_cash),
+            EXCHANGE_NAME,
+            conversion,
+        );
+        let events = executor.subscribe_events();
+
+        let state = Arc::new(AppState::new(
+            engine,
+            RiskManager::new(RiskConfig::default(), scenario.initial_cash),
+            executor,
+        ));
+
+        let recorder = RecordingSender::default();
+        let sent = recorder.sent.clone();
+        let mut notifier = NotificationManager::new();
+        notifier.add_sender(recorder);
+
+        Ok(Self {
+            state,
+            exchange,
+            signals,
+            events,
+            notifier,
+            sent,
+            submitted: HashMap::new(),
+            history_cursor: 0,
+            fees: HashMap::new(),
+            pending_fill: None,
+            position_realized: HashMap::new(),
+            // 로컬 시뮬레이션이라 재시도하지 않음
+            retry: RetryConfig {
+                max_retries: 0,
+                ..Default::default()
+            },
+            ticker: scenario.ticker.clone(),
+            counts: FlowCounts::default(),
+            journal: Vec::new(),
+            rejections: Vec::new(),
+            failures: Vec::new(),
+        })
+    }
+
+    /// 캔들 하나 진행.
+    async fn step(&mut self, kline: Kline) {
+        // 대기 주문을 먼저 이 캔들로 매칭
+        self.exchange.feed_kline(kline.clone()).await;
+        self.sync_fills().await;
+
+        let price = kline.close;
+        let data = MarketData::from_kline(EXCHANGE_NAME, kline);
+        if let Err(e) = self
+            .state
+            .strategy_engine
+            .read()
+            .await
+            .process_market_data(data)
+            .await
+        {
+            self.failures.push(format!("engine error: {}", e));
+        }
+
+        // 엔진 출력 채널로 전달된 신호만 실행 (실서버 신호 경로와 동일)
+        while let Ok(signal) = self.signals.try_recv() {
+            self.counts.signals += 1;
+            self.execute(&signal, price).await;
+        }
+    }
+
+    /// 신호를 주문으로 변환해 거래소에 제출.
+    async fn execute(&mut self, signal: &Signal, price: Decimal) {
+        let executor = self.state.executor.read().await;
+        let result = executor.process_signal(signal, price).await;
+        let Some(order_id) = result.order_id.filter(|_| result.success) else {
+            self.counts.rejected += 1;
+            self.rejections.push(
+                result
+                    .error
+                    .unwrap_or_else(|| "no order created".to_string()),
+            );
+            return;
+        };
+
+        match executor
+            .submit_with_retry(&self.exchange, order_id, &self.retry)
+            .await
+        {
+            Ok(exchange_order_id) => {
+                self.counts.orders += 1;
+                self.submitted.insert(exchange_order_id, signal.side);
+            }
+            Err(e) => {
+                self.counts.rejected += 1;
+                self.rejections.push(format!("submit failed: {}", e));
+            }
+        }
+        drop(executor);
+
+        self.sync_fills().await;
+    }
+
+    /// 새 거래소 체결 내역을 체결통보로 실행기에 반영.
+    async fn sync_fills(&mut self) {
+        let history = self.exchange.get_order_history().await;
+        let new_matches = history.get(self.history_cursor..).unwrap_or_default();
+        self.history_cursor = history.len();
+
+        for order_match in new_matches {
+            if order_match.fill_type == FillType::None
+                || order_match.filled_quantity <= Decimal::ZERO
+            {
+                continue;
+            }
+            let Some(&side) = self.submitted.get(&order_match.order_id) else {
+                continue;
+            };
+
+            let event = UserEvent::Fill(FillReport {
+                exchange_order_id: order_match.order_id.clone(),
+                ticker: self.ticker.clone(),
+                side,
+                quantity: order_match.filled_quantity,
+                price: order_match.fill_price,
+                timestamp: order_match.timestamp,
+            });
+            match apply_user_event(&self.state, &event).await {
+                Some(order_id) => {
+                    self.counts.fills += 1;
+                    *self.fees.entry(order_id).or_default() += order_match.commission;
+                }
+                None => self.failures.push(format!(
+                    "fill for exchange order {} was not applied",
+                    order_match.order_id
+                )),
+            }
+            self.drain_events().await;
+        }
+    }
+
+    /// 실행기 이벤트로 알림 전송과 매매일지 기록.
+    async fn drain_events(&mut self) {
+        while let Ok(event) = self.events.try_recv() {
+            if let Some(notification) = fill_notification(&event) {
+                if let Err(e) = self.notifier.notify(&notification).await {
+                    self.failures.push(format!("notification failed: {}", e));
+                }
+            }
+
+            match event {
+                ExecutionEvent::OrderFilled {
+                    order_id,
+                    side,
+                    fill_quantity,
+                    fill_price,
+                    timestamp,
+                    ..
+                } => {
+                    self.pending_fill = Some(PendingFill {
+                        order_id,
+                        side,
+                        quantity: fill_quantity,
+                        price: fill_price,
+                        executed_at: timestamp,
+                    });
+                }
+                ExecutionEvent::PositionChanged {
+                    ticker,
+                    side,
+                    realized_pnl,
+                    opened,
+                    closed,
+                    ..
+                } => {
+                    let Some(fill) = self.pending_fill.take() else {
+                        continue;
+                    };
+                    if opened {
+                        self.position_realized.insert(ticker.clone(), Decimal::ZERO);
+                    }
+                    let previous = self
+                        .position_realized
+                        .get(&ticker)
+                        .copied()
+                        .unwrap_or_default();
+                    if closed {
+                        self.position_realized.remove(&ticker);
+                    } else {
+                        self.position_realized.insert(ticker.clone(), realized_pnl);
+                    }
+
+                    // 포지션과 같은 방향 체결은 진입, 반대 방향은 청산
+                    let is_open = fill.side == side;
+                    self.journal.push(JournalTradeInput {
+                        exchange: EXCHANGE_NAME.to_string(),
+                        symbol: ticker,
+                        symbol_name: None,
+                        side: fill.side,
+                        order_type: "MARKET".to_string(),
+                        quantity: fill.quantity,
+                        price: fill.price,
+                        fee: self.fees.get(&fill.order_id).copied().unwrap_or_default(),
+                        position_effect: if is_open { "OPEN" } else { "CLOSE" }.to_string(),
+                        realized_pnl: (!is_open).then(|| realized_pnl - previous),
+                        strategy_id: STRATEGY_ID.to_string(),
+                        strategy_name: None,
+                        executed_at: fill.executed_at,
+                        memo: Some("integration".to_string()),
+                    });
+                }
+            }
+        }
+    }
+
+    /// REST API로 엔진/실행기 상태 확인.
+    async fn verify_api(&mut self) {
+        let app = trader_api::routes::create_api_router().with_state(self.state.clone());
+
+        let strategy = get_json(&app, &format!("/api/v1/strategies/{}", STRATEGY_ID)).await;
+        match strategy {
+            Ok(body) if body["running"] == json!(true) => {}
+            Ok(body) => self
+                .failures
+                .push(format!("API reports strategy not running: {}", body)),
+            Err(e) => self.failures.push(e),
+        }
+
+        let performance = get_json(
+            &app,
+            &format!("/api/v1/strategies/{}/performance", STRATEGY_ID),
+        )
+        .await;
+        match performance {
+            Ok(body) => {
+                let fill_count = body["portfolio"]["fill_count"].as_u64().unwrap_or(0);
+                if fill_count != self.counts.fills as u64 {
+                    self.failures.push(format!(
+                        "API performance fill_count {} != applied fills {}",
+                        fill_count, self.counts.fills
+                    ));
+                }
+            }
+            Err(e) => self.failures.push(e),
+        }
+    }
+
+    /// 결과 집계와 기대 결과 대조.
+    async fn finish(mut self, scenario: &Scenario) -> ScenarioReport {
+        self.verify_api().await;
+        self.counts.journal_entries = self.journal.len();
+        self.counts.notifications = self.sent.lock().expect("notification log poisoned").len();
+
+        let portfolio = {
+            let executor = self.state.executor.read().await;
+            executor.strategy_portfolio(STRATEGY_ID).await
+        };
+        let realized_pnl = portfolio
+            .as_ref()
+            .map(|p| p.realized_pnl)
+            .unwrap_or_default();
+        let final_position = portfolio
+            .map(|p| {
+                p.positions
+                    .iter()
+                    .filter(|position| position.ticker == scenario.ticker)
+                    .map(|position| match position.side {
+                        Side::Buy => position.quantity,
+                        Side::Sell => -position.quantity,
+                    })
+                    .sum()
+            })
+            .unwrap_or_default();
+
+        let mut failures = self.failures;
+        failures.extend(check_expectations(
+            &scenario.expect,
+            &self.counts,
+            final_position,
+            realized_pnl,
+        ));
+
+        ScenarioReport {
+            scenario: scenario.name.clone(),
+            counts: self.counts,
+            final_position,
+            realized_pnl,
+            journal: self.journal,
+            rejections: self.rejections,
+            failures,
+        }
+    }
+}
+
+/// API GET 요청 후 JSON 본문 반환.
+async fn get_json(app: &axum::Router, uri: &str) -> Result<Value, String> {
+    let response = app
+        .clone()
+        .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+        .await
+        .map_err(|e| format!("GET {} failed: {}", uri, e))?;
+    let status = response.status();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .map_err(|e| format!("GET {} body read failed: {}", uri, e))?;
+    if status != StatusCode::OK {
+        return Err(format!(
+            "GET {} returned {}: {}",
+            uri,
+            status,
+            String::from_utf8_lossy(&body)
+        ));
+    }
+    serde_json::from_slice(&body).map_err(|e| format!("GET {} invalid JSON: {}", uri, e))
+}
+
+/// 기대 결과 대조.
+fn check_expectations(
+    expect: &Expectations,
+    counts: &FlowCounts,
+    final_position: Decimal,
+    realized_pnl: Decimal,
+) -> Vec<String> {
+    let mut failures = Vec::new();
+    for (name, expected, actual) in [
+        ("signals", expect.signals, counts.signals),
+        ("orders", expect.orders, counts.orders),
+        ("rejected", expect.rejected, counts.rejected),
+        ("fills", expect.fills, counts.fills),
+        (
+            "journal_entries",
+            expect.journal_entries,
+            counts.journal_entries,
+        ),
+        ("notifications", expect.notifications, counts.notifications),
+    ] {
+        if let Some(expected) = expected {
+            if expected != actual {
+                failures.push(format!("{}: expected {}, got {}", name, expected, actual));
+            }
+        }
+    }
+    if let Some(expected) = expect.final_position {
+        if expected != final_position {
+            failures.push(format!(
+                "final_position: expected {}, got {}",
+                expected, final_position
+            ));
+        }
+    }
+    if let Some(expected) = expect.realized_pnl_positive {
+        if (realized_pnl > Decimal::ZERO) != expected {
+            failures.push(format!(
+                "realized_pnl_positive: expected {}, got realized_pnl {}",
+                expected, realized_pnl
+            ));
+        }
+    }
+    failures
+}
+
+/// 시나리오 실행.
+///
+/// 부팅 실패는 `Err`로, 흐름 중 오류와 기대 결과 불일치는 보고서의 `failures`로 반환합니다.
+pub async fn run_scenario(scenario: &Scenario) -> anyhow::Result<ScenarioReport> {
+    let mut harness = Harness::boot(scenario).await?;
+    for kline in scenario.klines() {
+        harness.step(kline).await;
+    }
+    Ok(harness.finish(scenario).await)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[tokio::test]
+    async fn test_builtin_scenarios_pass() {
+        for scenario in Scenario::builtin() {
+            let report = run_scenario(&scenario).await.unwrap();
+            assert!(
+                report.passed(),
+                "{} failed: {:?}",
+                scenario.name,
+                report.failures
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_round_trip_journal_pairs_open_and_close() {
+        let report = run_scenario(&Scenario::builtin()[0]).await.unwrap();
+
+        let effects: Vec<_> = report
+            .journal
+            .iter()
+            .map(|entry| (entry.side, entry.position_effect.as_str()))
+            .collect();
+        assert_eq!(effects, vec![(Side::Buy, "OPEN"), (Side::Sell, "CLOSE")]);
+        assert!(report.journal[0].fee > Decimal::ZERO);
+        assert_eq!(report.journal[0].realized_pnl, None);
+        assert_eq!(report.journal[1].realized_pnl, Some(report.realized_pnl));
+        assert!(report.realized_pnl > dec!(0));
+    }
+
+    #[test]
+    fn test_check_expectations_reports_mismatches() {
+        let expect = Expectations {
+            fills: Some(2),
+            final_position: Some(Decimal::ZERO),
+            realized_pnl_positive: Some(true),
+            ..Default::default()
+        };
+        let counts = FlowCounts {
+            fills: 1,
+            ..Default::default()
+        };
+
+        let failures = check_expectations(&expect, &counts, dec!(0.01), dec!(-1));
+        assert_eq!(failures.len(), 3);
+        assert!(failures[0].starts_with("fills"));
+        assert!(check_expectations(&Expectations::default(), &counts, dec!(1), dec!(0)).is_empty());
+    }
+}
@@ -0,0 +1,32 @@
+//! 시뮬레이션 거래소 기반 크로스 crate 통합 시나리오 하네스.
+//!
+//! 전략 엔진, 리스크 관리자, 주문 실행기, API 상태를 시뮬레이션 거래소에 연결하고
+//! 스크립트된 시세로 신호 → 주문 → 체결 → 매매일지 → 알림 흐름을 끝까지 검증합니다.
+//! 실거래 브로커에 연결하기 전에 여러 crate에 걸친 변경을 로컬에서 확인하는 용도입니다.
+//!
+//! # 사용 예시
+//!
+//! ```bash
+//! # 내장 시나리오 전체 실행
+//! cargo run -p trader-integration
+//!
+//! # 내장 시나리오 목록
+//! cargo run -p trader-integration -- --list
+//!
+//! # JSON 시나리오 파일 실행
+//! cargo run -p trader-integration -- --scenario my_flow.json
+//! ```
+//!
+//! # 모듈
+//!
+//! - `scenario`: 시세/신호 스크립트, 기대 결과, 내장 시나리오
+//! - `strategy`: 스크립트된 캔들 순서에서 신호를 내는 전략
+//! - `harness`: 컴포넌트 부팅, 시나리오 실행, 결과 검증
+
+pub mod harness;
+pub mod scenario;
+pub mod strategy;
+
+pub use harness::{run_scenario, FlowCounts, ScenarioReport};
+pub use scenario::{Expectations, PriceScript, Scenario, ScriptedSignal, DEFAULT_TICKER};
+pub use strategy::ScriptedStrategy;
@@ -0,0 +1,141 @@
+//! 통합 시나리오 실행기 CLI.
+//!
+//! # 사용 예시
+//!
+//! ```bash
+//! # 내장 시나리오 전체 실행 (실패 시 종료 코드 1)
+//! trader-integration
+//!
+//! # 내장 시나리오 하나만 실행
+//! trader-integration --only round_trip
+//!
+//! # JSON 시나리오 파일 실행, 결과를 JSON으로 출력
+//! trader-integration --scenario my_flow.json --json
+//! ```
+
+use std::path::PathBuf;
+
+use anyhow::Context;
+use clap::Parser;
+use trader_integration::{run_scenario, Scenario, ScenarioReport};
+
+#[derive(Parser)]
+#[command(name = "trader-integration")]
+#[command(about = "시뮬레이션 거래소 기반 크로스 crate 통합 시나리오 실행기", long_about = None)]
+#[command(version)]
+struct Cli {
+    /// 시나리오 JSON 파일 (여러 번 지정 가능, 지정하면 내장 시나리오 대신 실행)
+    #[arg(short, long)]
+    scenario: Vec<PathBuf>,
+
+    /// 실행할 내장 시나리오 이름 (여러 번 지정 가능)
+    #[arg(short, long)]
+    only: Vec<String>,
+
+    /// 내장 시나리오 목록 출력
+    #[arg(long)]
+    list: bool,
+
+    /// 결과를 JSON으로 출력
+    #[arg(long)]
+    json: bool,
+}
+
+/// 실행할 시나리오 결정.
+fn load_scenarios(cli: &Cli) -> anyhow::Result<Vec<Scenario>> {
+    if !cli.scenario.is_empty() {
+        return cli
+            .scenario
+            .iter()
+            .map(|path| {
+                let content = std::fs::read_to_string(path)
+                    .with_context(|| format!("failed to read {}", path.display()))?;
+                serde_json::from_str(&content)
+                    .with_context(|| format!("invalid scenario file {}", path.display()))
+            })
+            .collect();
+    }
+
+    let builtin = Scenario::builtin();
+    if cli.only.is_empty() {
+        return Ok(builtin);
+    }
+    if let Some(unknown) = cli
+        .only
+        .iter()
+        .find(|name| !builtin.iter().any(|s| &s.name == *name))
+    {
+        anyhow::bail!("unknown builtin scenario: {}", unknown);
+    }
+    Ok(builtin
+        .into_iter()
+        .filter(|s| cli.only.contains(&s.name))
+        .collect())
+}
+
+fn print_report(report: &ScenarioReport) {
+    let counts = &report.counts;
+    println!(
+        "{} {} (signals={}, orders={}, rejected={}, fills={}, journal={}, notifications={}, \
+         position={}, realized_pnl={})",
+        if report.passed() { "PASS" } else { "FAIL" },
+        report.scenario,
+        counts.signals,
+        counts.orders,
+        counts.rejected,
+        counts.fills,
+        counts.journal_entries,
+        counts.notifications,
+        report.final_position,
+        report.realized_pnl,
+    );
+    for rejection in &report.rejections {
+        println!("    rejected: {}", rejection);
+    }
+    for failure in &report.failures {
+        println!("    failure: {}", failure);
+    }
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    tracing_subscriber::fmt()
+        .with_env_filter(
+            tracing_subscriber::EnvFilter::try_from_default_env()
+                .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("warn")),
+        )
+        .init();
+
+    let cli = Cli::parse();
+
+    if cli.list {
+        for scenario in Scenario::builtin() {
+            println!("{:<20} {}", scenario.name, scenario.description);
+        }
+        return Ok(());
+    }
+
+    let mut reports = Vec::new();
+    for scenario in load_scenarios(&cli)? {
+        let report = run_scenario(&scenario)
+            .await
+            .with_context(|| format!("scenario {} failed to boot", scenario.name))?;
+        if !cli.json {
+            print_report(&report);
+        }
+        reports.push(report);
+    }
+
+    if cli.json {
+        println!("{}", serde_json::to_string_pretty(&reports)?);
+    }
+
+    let failed = reports.iter().filter(|r| !r.passed()).count();
+    if failed > 0 {
+        if !cli.json {
+            println!("{} of {} scenarios failed", failed, reports.len());
+        }
+        std::process::exit(1);
+    }
+    Ok(())
+}
@@ -0,0 +1,302 @@
+//! 통합 시나리오 정의.
+//!
+//! 시나리오는 시세 스크립트, 전략이 낼 신호, 기대 결과로 구성됩니다. 내장 시나리오 외에
+//! 같은 구조의 JSON 파일로 새 흐름을 추가할 수 있습니다.
+//!
+//! ```json
+//! {
+//!   "name": "round_trip",
+//!   "ticker": "BTC/USDT",
+//!   "order_quantity": "0.01",
+//!   "prices": { "kind": "closes", "closes": ["50000", "50200", "50500"] },
+//!   "signals": [{ "bar": 1, "side": "buy", "signal_type": "entry" }],
+//!   "expect": { "fills": 1, "journal_entries": 1 }
+//! }
+//! ```
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use trader_core::{Kline, Side, SignalType, Timeframe};
+use trader_testkit::KlineGenerator;
+
+/// 기본 시나리오 종목.
+///
+/// 장 운영시간/야간 주문 제한이 없는 암호화폐 종목을 사용합니다.
+pub const DEFAULT_TICKER: &str = "BTC/USDT";
+
+/// 시세 스크립트.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum PriceScript {
+    /// 종가 목록 (시가는 직전 종가, 고가/저가는 시가와 종가 범위)
+    Closes { closes: Vec<Decimal> },
+    /// testkit 합성 캔들
+    Generated {
+        scenario: trader_testkit::Scenario,
+        seed: u64,
+        bars: usize,
+    },
+}
+
+/// 스크립트 신호.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScriptedSignal {
+    /// 신호를 낼 캔들 순서 (0부터)
+    pub bar: usize,
+    pub side: Side,
+    pub signal_type: SignalType,
+    /// 신호 강도 (기본 1.0)
+    #[serde(default = "default_strength")]
+    pub strength: f64,
+}
+
+/// 기대 결과.
+///
+/// 지정하지 않은 항목은 검증하지 않습니다.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Expectations {
+    /// 엔진이 출력 채널로 보낸 신호 수
+    pub signals: Option<usize>,
+    /// 실행기가 생성해 거래소에 제출한 주문 수
+    pub orders: Option<usize>,
+    /// 실행기가 거부한 신호 수
+    pub rejected: Option<usize>,
+    /// 실행기에 반영된 체결 수
+    pub fills: Option<usize>,
+    /// 매매일지 항목 수
+    pub journal_entries: Option<usize>,
+    /// 전송된 알림 수
+    pub notifications: Option<usize>,
+    /// 시나리오 종료 시 보유 수량 (매도 포지션은 음수)
+    pub final_position: Option<Decimal>,
+    /// 누적 실현 손익이 양수인지 여부
+    pub realized_pnl_positive: Option<bool>,
+}
+
+/// 통합 시나리오.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Scenario {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default = "default_ticker")]
+    pub ticker: String,
+    #[serde(default = "default_timeframe")]
+    pub timeframe: Timeframe,
+    /// 시뮬레이션 거래소와 리스크 관리자의 초기 자본 (호가 자산 단위)
+    #[serde(default = "default_initial_cash")]
+    pub initial_cash: Decimal,
+    /// 신호당 주문 수량 (실행기 기본 수량)
+    pub order_quantity: Decimal,
+    pub prices: PriceScript,
+    #[serde(default)]
+    pub signals: Vec<ScriptedSignal>,
+    #[serde(default)]
+    pub expect: Expectations,
+}
+
+fn default_strength() -> f64 {
+    1.0
+}
+
+fn default_ticker() -> String {
+    DEFAULT_TICKER.to_string()
+}
+
+fn default_timeframe() -> Timeframe {
+    Timeframe::H1
+}
+
+fn default_initial_cash() -> Decimal {
+    dec!(10000)
+}
+
+/// 스크립트 캔들 시작 시각.
+fn script_start() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 1, 5, 0, 0, 0).unwrap()
+}
+
+impl Scenario {
+    /// 내장 시나리오.
+    ///
+    /// - `round_trip`: 진입 → 청산 왕복 (체결 2건, 일지 2건, 알림 2건, 이익 실현)
+    /// - `risk_rejection`: 포지션 한도(잔고 10%) 초과 진입 거부
+    /// - `generated_trend`: testkit 추세 캔들 위에서 같은 흐름 반복
+    pub fn builtin() -> Vec<Scenario> {
+        let buy = |bar| ScriptedSignal {
+            bar,
+            side: Side::Buy,
+            signal_type: SignalType::Entry,
+            strength: 1.0,
+        };
+        let sell = |bar| ScriptedSignal {
+            bar,
+            side: Side::Sell,
+            signal_type: SignalType::Exit,
+            strength: 1.0,
+        };
+
+        vec![
+            Scenario {
+                name: "round_trip".to_string(),
+                description: "Entry and exit on a rising market".to_string(),
+                ticker: default_ticker(),
+                timeframe: default_timeframe(),
+                initial_cash: default_initial_cash(),
+                order_quantity: dec!(0.01),
+                prices: PriceScript::Closes {
+                    closes: vec![
+                        dec!(50000),
+                        dec!(50200),
+                        dec!(50500),
+                        dec!(50800),
+                        dec!(51000),
+                        dec!(51200),
+                    ],
+                },
+                signals: vec![buy(1), sell(4)],
+                expect: Expectations {
+                    signals: Some(2),
+                    orders: Some(2),
+                    rejected: Some(0),
+                    fills: Some(2),
+                    journal_entries: Some(2),
+                    notifications: Some(2),
+                    final_position: Some(Decimal::ZERO),
+                    realized_pnl_positive: Some(true),
+                },
+            },
+            Scenario {
+                name: "risk_rejection".to_string(),
+                description: "Entry above the position size limit is rejected".to_string(),
+                ticker: default_ticker(),
+                timeframe: default_timeframe(),
+                initial_cash: default_initial_cash(),
+                // 0.05 BTC ≈ 2,500 USDT > 잔고 10%
+                order_quantity: dec!(0.05),
+                prices: PriceScript::Closes {
+                    closes: vec![dec!(50000), dec!(50100), dec!(50200)],
+                },
+                signals: vec![buy(1)],
+                expect: Expectations {
+                    signals: Some(1),
+                    orders: Some(0),
+                    rejected: Some(1),
+                    fills: Some(0),
+                    journal_entries: Some(0),
+                    notifications: Some(0),
+                    final_position: Some(Decimal::ZERO),
+                    realized_pnl_positive: None,
+                },
+            },
+            Scenario {
+                name: "generated_trend".to_string(),
+                description: "Round trip on synthetic trending klines".to_string(),
+                ticker: default_ticker(),
+                timeframe: default_timeframe(),
+                initial_cash: default_initial_cash(),
+                order_quantity: dec!(0.05),
+                prices: PriceScript::Generated {
+                    scenario: trader_testkit::Scenario::Trending,
+                    seed: 7,
+                    bars: 60,
+                },
+                signals: vec![buy(10), sell(40)],
+                expect: Expectations {
+                    signals: Some(2),
+                    orders: Some(2),
+                    rejected: Some(0),
+                    fills: Some(2),
+                    journal_entries: Some(2),
+                    notifications: Some(2),
+                    final_position: Some(Decimal::ZERO),
+                    realized_pnl_positive: None,
+                },
+            },
+        ]
+    }
+
+    /// 시세 스크립트로 캔들 생성.
+    pub fn klines(&self) -> Vec<Kline> {
+        match &self.prices {
+            PriceScript::Closes { closes } => {
+                let step =
+                    Duration::from_std(self.timeframe.duration()).unwrap_or(Duration::hours(1));
+                let mut open_time = script_start();
+                let mut prev_close = None;
+                closes
+                    .iter()
+                    .map(|&close| {
+                        let open = prev_close.unwrap_or(close);
+                        prev_close = Some(close);
+                        let kline = Kline::new(
+                            self.ticker.clone(),
+                            self.timeframe,
+                            open_time,
+                            open,
+                            open.max(close),
+                            open.min(close),
+                            close,
+                            dec!(100),
+                            open_time + step,
+                        );
+                        open_time += step;
+                        kline
+                    })
+                    .collect()
+            }
+            PriceScript::Generated {
+                scenario,
+                seed,
+                bars,
+            } => KlineGenerator::new(self.ticker.clone(), *seed)
+                .scenario(*scenario)
+                .timeframe(self.timeframe)
+                .start_time(script_start())
+                .generate(*bars),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_closes_script_builds_contiguous_klines() {
+        let scenario = &Scenario::builtin()[0];
+        let klines = scenario.klines();
+
+        assert_eq!(klines.len(), 6);
+        assert_eq!(klines[0].open, dec!(50000));
+        assert_eq!(klines[1].open, dec!(50000));
+        assert_eq!(klines[1].high, dec!(50200));
+        assert_eq!(klines[1].open_time, klines[0].close_time);
+        assert!(klines.iter().all(trader_testkit::is_valid_ohlc));
+    }
+
+    #[test]
+    fn test_scenario_json_defaults() {
+        let scenario: Scenario = serde_json::from_str(
+            r#"{
+                "name": "custom",
+                "order_quantity": "0.01",
+                "prices": { "kind": "generated", "scenario": "crash", "seed": 1, "bars": 20 },
+                "signals": [{ "bar": 3, "side": "buy", "signal_type": "entry" }],
+                "expect": { "fills": 1 }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(scenario.ticker, DEFAULT_TICKER);
+        assert_eq!(scenario.timeframe, Timeframe::H1);
+        assert_eq!(scenario.initial_cash, dec!(10000));
+        assert_eq!(scenario.signals[0].strength, 1.0);
+        assert_eq!(scenario.expect.fills, Some(1));
+        assert_eq!(scenario.expect.orders, None);
+        assert_eq!(scenario.klines().len(), 20);
+    }
+}
@@ -0,0 +1,103 @@
+//! 스크립트 전략.
+
+use async_trait::async_trait;
+use serde_json::{json, Value};
+use trader_core::{MarketData, MarketDataType, Order, Position, Signal};
+use trader_strategy::Strategy;
+
+use crate::scenario::ScriptedSignal;
+
+/// 지정한 캔들 순서에서 미리 정한 신호를 내는 전략.
+///
+/// 지표 계산 없이 신호 시점을 고정하므로 엔진 이후 단계(주문, 체결, 일지, 알림)를
+/// 결정적으로 검증할 수 있습니다. 캔들 이외의 시장 데이터는 무시합니다.
+pub struct ScriptedStrategy {
+    strategy_id: String,
+    signals: Vec<ScriptedSignal>,
+    bar: usize,
+}
+
+impl ScriptedStrategy {
+    pub fn new(strategy_id: impl Into<String>, signals: Vec<ScriptedSignal>) -> Self {
+        Self {
+            strategy_id: strategy_id.into(),
+            signals,
+            bar: 0,
+        }
+    }
+}
+
+#[async_trait]
+impl Strategy for ScriptedStrategy {
+    fn name(&self) -> &str {
+        "Scripted"
+    }
+
+    fn version(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn description(&self) -> &str {
+        "Emits scripted signals at fixed bar indexes (integration harness)"
+    }
+
+    async fn initialize(
+        &mut self,
+        _config: Value,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        self.bar = 0;
+        Ok(())
+    }
+
+    async fn on_market_data(
+        &mut self,
+        data: &MarketData,
+    ) -> Result<Vec<Signal>, Box<dyn std::error::Error + Send + Sync>> {
+        if !matches!(data.data, MarketDataType::Kline(_)) {
+            return Ok(Vec::new());
+        }
+        let bar = self.bar;
+        self.bar += 1;
+
+        Ok(self
+            .signals
+            .iter()
+            .filter(|s| s.bar == bar)
+            .map(|s| {
+                Signal::new(
+                    self.strategy_id.clone(),
+                    data.ticker.clone(),
+                    s.side,
+                    s.signal_type,
+                )
+                .with_strength(s.strength)
+            })
+            .collect())
+    }
+
+    async fn on_order_filled(
+        &mut self,
+        _order: &Order,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        Ok(())
+    }
+
+    async fn on_position_update(
+        &mut self,
+        _position: &Position,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        Ok(())
+    }
+
+    async fn shutdown(&mut self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        Ok(())
+    }
+
+    fn get_state(&self) -> Value {
+        json!({
+            "name": self.name(),
+            "bar": self.bar,
+            "scripted_signals": self.signals.len(),
+        })
+    }
+}
@@ -0,0 +1,366 @@
+//! 알림 다이제스트 모드.
+//!
+//! 채널별로 낮은 우선순위 알림(개별 체결, 경미한 경고 등)을 즉시 보내지 않고 모아 두었다가
+//! 하루 한 번 요약 메시지로 전송합니다. 기준 우선순위 이상의 알림은 그대로 즉시 전송됩니다.
+//!
+//! # 환경 변수 (채널 접두사, 예: `TELEGRAM`)
+//!
+//! - `{CHANNEL}_DIGEST_ENABLED` - 다이제스트 모드 활성화 (기본값: false)
+//! - `{CHANNEL}_DIGEST_IMMEDIATE_PRIORITY` - 즉시 전송 최소 우선순위 (기본값: high)
+//! - `{CHANNEL}_DIGEST_SEND_AT` - 요약 전송 시각, KST `HH:MM` (기본값: 18:00)
+//! - `{CHANNEL}_DIGEST_MAX_LINES` - 요약에 나열할 최대 알림 수 (기본값: 30)
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveTime, Utc};
+
+use crate::types::{
+    Notification, NotificationEvent, NotificationPriority, NotificationResult, NotificationSender,
+};
+
+/// 다이제스트 설정.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DigestSettings {
+    /// 다이제스트 모드 활성화 여부
+    pub enabled: bool,
+    /// 이 우선순위 이상은 즉시 전송
+    pub immediate_priority: NotificationPriority,
+    /// 요약 전송 시각 (`utc_offset` 기준)
+    pub send_at: NaiveTime,
+    /// 요약 날짜/시각 기준 시간대 (기본값: KST)
+    pub utc_offset: FixedOffset,
+    /// 요약에 나열할 최대 알림 수
+    pub max_lines: usize,
+}
+
+impl Default for DigestSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            immediate_priority: NotificationPriority::High,
+            send_at: NaiveTime::from_hms_opt(18, 0, 0).expect("valid time"),
+            utc_offset: FixedOffset::east_opt(9 * 3600).expect("valid offset"),
+            max_lines: 30,
+        }
+    }
+}
+
+impl DigestSettings {
+    /// 채널 접두사가 붙은 환경 변수에서 설정을 읽습니다.
+    ///
+    /// # Arguments
+    /// * `channel` - 환경 변수 접두사 (예: `TELEGRAM`)
+    pub fn from_env(channel: &str) -> Self {
+        let var = |name: &str| std::env::var(format!("{}_DIGEST_{}", channel, name)).ok();
+        let defaults = Self::default();
+
+        Self {
+            enabled: var("ENABLED")
+                .map(|v| matches!(v.to_lowercase().as_str(), "true" | "1"))
+                .unwrap_or(defaults.enabled),
+            immediate_priority: var("IMMEDIATE_PRIORITY")
+                .and_then(|v| match v.to_lowercase().as_str() {
+                    "low" => Some(NotificationPriority::Low),
+                    "normal" => Some(NotificationPriority::Normal),
+                    "high" => Some(NotificationPriority::High),
+                    "critical" => Some(NotificationPriority::Critical),
+                    _ => None,
+                })
+                .unwrap_or(defaults.immediate_priority),
+            send_at: var("SEND_AT")
+                .and_then(|v| NaiveTime::parse_from_str(&v, "%H:%M").ok())
+                .unwrap_or(defaults.send_at),
+            max_lines: var("MAX_LINES")
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(defaults.max_lines),
+            ..defaults
+        }
+    }
+
+    /// 알림을 다이제스트로 보류할지 여부.
+    ///
+    /// 일일 요약과 다이제스트 자체는 항상 즉시 전송합니다.
+    pub fn should_defer(&self, notification: &Notification) -> bool {
+        self.enabled
+            && notification.priority < self.immediate_priority
+            && !matches!(
+                notification.event,
+                NotificationEvent::DailySummary { .. } | NotificationEvent::Digest { .. }
+            )
+    }
+
+    /// 현재 시각 기준 요약 날짜.
+    pub fn local_date(&self, now: DateTime<Utc>) -> NaiveDate {
+        now.with_timezone(&self.utc_offset).date_naive()
+    }
+
+    /// 요약 전송 시각이 되었는지 확인하고, 되었다면 요약 날짜를 반환합니다.
+    ///
+    /// # Arguments
+    /// * `last_sent` - 마지막으로 요약을 보낸 날짜 (하루 한 번만 전송)
+    pub fn due_date(&self, now: DateTime<Utc>, last_sent: Option<NaiveDate>) -> Option<NaiveDate> {
+        let local = now.with_timezone(&self.utc_offset);
+        let date = local.date_naive();
+        (local.time() >= self.send_at && last_sent != Some(date)).then_some(date)
+    }
+}
+
+/// 보류된 알림을 묶어 다이제스트 알림을 만듭니다.
+///
+/// 보류된 알림이 없으면 `None`을 반환합니다.
+pub fn build_digest(
+    date: NaiveDate,
+    pending: &[Notification],
+    settings: &DigestSettings,
+) -> Option<Notification> {
+    if pending.is_empty() {
+        return None;
+    }
+
+    let mut counts: HashMap<&'static str, usize> = HashMap::new();
+    for notification in pending {
+        *counts.entry(notification.event.kind()).or_default() += 1;
+    }
+    let mut counts: Vec<(String, usize)> = counts
+        .into_iter()
+        .map(|(kind, count)| (kind.to_string(), count))
+        .collect();
+    counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+
+    let mut sorted: Vec<&Notification> = pending.iter().collect();
+    sorted.sort_by_key(|n| n.timestamp);
+    let lines = sorted
+        .iter()
+        .take(settings.max_lines)
+        .map(|n| {
+            format!(
+                "{} {}",
+                n.timestamp
+                    .with_timezone(&settings.utc_offset)
+                    .format("%H:%M"),
+                n.event.summary()
+            )
+        })
+        .collect::<Vec<_>>();
+
+    Some(
+        Notification::new(NotificationEvent::Digest {
+            date: date.to_string(),
+            total: pending.len(),
+            counts,
+            omitted: pending.len() - lines.len(),
+            lines,
+        })
+        .with_priority(NotificationPriority::Normal),
+    )
+}
+
+/// 보류 알림 버퍼 (복제 시 같은 버퍼를 공유).
+#[derive(Debug, Clone, Default)]
+pub struct DigestBuffer {
+    pending: Arc<Mutex<Vec<Notification>>>,
+}
+
+impl DigestBuffer {
+    /// 새 버퍼를 생성합니다.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 알림을 보류합니다.
+    pub fn push(&self, notification: Notification) {
+        self.pending
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(notification);
+    }
+
+    /// 보류된 알림을 모두 꺼냅니다.
+    pub fn drain(&self) -> Vec<Notification> {
+        std::mem::take(&mut *self.pending.lock().unwrap_or_else(|e| e.into_inner()))
+    }
+
+    /// 보류된 알림 수.
+    pub fn len(&self) -> usize {
+        self.pending.lock().unwrap_or_else(|e| e.into_inner()).len()
+    }
+
+    /// 보류된 알림이 없는지 여부.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// 다이제스트 모드를 적용하는 전송기 래퍼.
+///
+/// 보류 대상 알림은 버퍼에 쌓고, [`NotificationSender::flush`] 호출 시
+/// 요약 알림 하나로 내부 전송기에 전달합니다.
+pub struct DigestSender<S> {
+    inner: S,
+    settings: DigestSettings,
+    buffer: DigestBuffer,
+}
+
+impl<S: NotificationSender> DigestSender<S> {
+    /// 새 다이제스트 전송기를 생성합니다.
+    pub fn new(inner: S, settings: DigestSettings) -> Self {
+        Self {
+            inner,
+            settings,
+            buffer: DigestBuffer::new(),
+        }
+    }
+
+    /// 다른 전송기와 버퍼를 공유합니다.
+    pub fn with_buffer(mut self, buffer: DigestBuffer) -> Self {
+        self.buffer = buffer;
+        self
+    }
+
+    /// 보류된 알림 수.
+    pub fn pending(&self) -> usize {
+        self.buffer.len()
+    }
+}
+
+#[async_trait]
+impl<S: NotificationSender> NotificationSender for DigestSender<S> {
+    async fn send(&self, notification: &Notification) -> NotificationResult<()> {
+        if self.settings.should_defer(notification) {
+            self.buffer.push(notification.clone());
+            return Ok(());
+        }
+        self.inner.send(notification).await
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.inner.is_enabled()
+    }
+
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    async fn flush(&self) -> NotificationResult<()> {
+        let pending = self.buffer.drain();
+        let date = self.settings.local_date(Utc::now());
+        match build_digest(date, &pending, &self.settings) {
+            Some(digest) => self.inner.send(&digest).await,
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use rust_decimal::Decimal;
+
+    /// 전송된 알림을 기록하는 테스트 전송기.
+    #[derive(Default)]
+    struct RecordingSender {
+        sent: Mutex<Vec<Notification>>,
+    }
+
+    #[async_trait]
+    impl NotificationSender for RecordingSender {
+        async fn send(&self, notification: &Notification) -> NotificationResult<()> {
+            self.sent.lock().unwrap().push(notification.clone());
+            Ok(())
+        }
+
+        fn is_enabled(&self) -> bool {
+            true
+        }
+
+        fn name(&self) -> &str {
+            "recording"
+        }
+    }
+
+    fn fill(symbol: &str) -> Notification {
+        Notification::new(NotificationEvent::OrderFilled {
+            symbol: symbol.to_string(),
+            side: "buy".to_string(),
+            quantity: Decimal::new(10, 0),
+            price: Decimal::new(71000, 0),
+            order_id: "1".to_string(),
+        })
+        .with_priority(NotificationPriority::Low)
+    }
+
+    fn enabled() -> DigestSettings {
+        DigestSettings {
+            enabled: true,
+            max_lines: 2,
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_digest_sender_defers_low_priority() {
+        let sender = DigestSender::new(RecordingSender::default(), enabled());
+
+        sender.send(&fill("005930")).await.unwrap();
+        sender.send(&fill("000660")).await.unwrap();
+        sender.send(&fill("035720")).await.unwrap();
+        let alert = Notification::new(NotificationEvent::SystemError {
+            error_code: "E1".to_string(),
+            message: "down".to_string(),
+        })
+        .with_priority(NotificationPriority::Critical);
+        sender.send(&alert).await.unwrap();
+
+        // 긴급 알림만 즉시 전송
+        assert_eq!(sender.pending(), 3);
+        assert_eq!(sender.inner.sent.lock().unwrap().len(), 1);
+
+        sender.flush().await.unwrap();
+        assert_eq!(sender.pending(), 0);
+        let sent = sender.inner.sent.lock().unwrap().clone();
+        assert_eq!(sent.len(), 2);
+        let NotificationEvent::Digest {
+            total,
+            counts,
+            lines,
+            omitted,
+            ..
+        } = &sent[1].event
+        else {
+            panic!("expected digest");
+        };
+        assert_eq!(*total, 3);
+        assert_eq!(counts, &vec![("order_filled".to_string(), 3)]);
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].ends_with("체결 005930 buy 10 @ 71000"));
+        assert_eq!(*omitted, 1);
+
+        // 보류 알림이 없으면 전송하지 않음
+        sender.flush().await.unwrap();
+        assert_eq!(sender.inner.sent.lock().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_digest_disabled_sends_immediately() {
+        let sender = DigestSender::new(RecordingSender::default(), DigestSettings::default());
+        sender.send(&fill("005930")).await.unwrap();
+        assert_eq!(sender.pending(), 0);
+        assert_eq!(sender.inner.sent.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_due_date() {
+        let settings = enabled();
+        // 08:59 UTC = 17:59 KST
+        let before = Utc.with_ymd_and_hms(2026, 3, 5, 8, 59, 0).unwrap();
+        let after = Utc.with_ymd_and_hms(2026, 3, 5, 9, 0, 0).unwrap();
+        let date = NaiveDate::from_ymd_opt(2026, 3, 5).unwrap();
+
+        assert_eq!(settings.due_date(before, None), None);
+        assert_eq!(settings.due_date(after, None), Some(date));
+        assert_eq!(settings.due_date(after, Some(date)), None);
+    }
+}
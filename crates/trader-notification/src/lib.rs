@@ -5,6 +5,11 @@
 //! 지원 채널:
 //! - Telegram
 //! - Discord (webhook)
+//! - 아웃바운드 웹훅 (HMAC 서명, 재시도)
+//! - SMS/음성 전화 (Twilio, 긴급 알림 전용)
+//!
+//! 채널별 다이제스트 모드를 켜면 낮은 우선순위 알림은 모아 두었다가
+//! 하루 한 번 요약 메시지로 전송합니다 ([`DigestSender`]).
 //!
 //! # 텔레그램 봇 명령어
 //!
@@ -16,9 +21,15 @@
 //! - `/attack` - ATTACK 상태 종목
 
 pub mod bot_handler;
+pub mod digest;
+pub mod sms;
 pub mod telegram;
 pub mod types;
+pub mod webhook;
 
 pub use bot_handler::*;
+pub use digest::*;
+pub use sms::*;
 pub use telegram::*;
 pub use types::*;
+pub use webhook::*;
@@ -0,0 +1,329 @@
+//! SMS/음성 전화 긴급 알림 (Twilio).
+//!
+//! 긴급(`Critical`) 알림만 전송합니다 (주문 서킷 차단, 리스크 한도 초과, 시스템 오류 등).
+//! 야간에 텔레그램 알림을 놓치지 않도록, 조용한 시간대(`SMS_QUIET_HOURS`)에는
+//! SMS 대신 음성 전화로 전달하여 휴대폰 무음/방해 금지 설정을 넘어 알립니다.
+//!
+//! # 환경 변수
+//!
+//! - `TWILIO_ACCOUNT_SID`, `TWILIO_AUTH_TOKEN` - Twilio 계정 인증 정보
+//! - `TWILIO_FROM_NUMBER` - 발신 번호 (E.164, 예: `+12025550123`)
+//! - `SMS_ALERT_TO` - 수신 번호 목록 (쉼표 구분, E.164)
+//! - `SMS_ENABLED` - 전송 활성화 여부 (기본값: true)
+//! - `SMS_VOICE_ENABLED` - 조용한 시간대 음성 전화 전환 (기본값: false)
+//! - `SMS_QUIET_HOURS` - 조용한 시간대, KST `HH:MM-HH:MM` (예: `23:00-07:00`)
+
+use async_trait::async_trait;
+use chrono::{DateTime, FixedOffset, NaiveTime, Utc};
+use tracing::{info, warn};
+
+use crate::types::{
+    Notification, NotificationError, NotificationEvent, NotificationPriority, NotificationResult,
+    NotificationSender,
+};
+
+/// SMS 본문 최대 길이 (문자 수).
+const MAX_SMS_CHARS: usize = 300;
+
+/// 조용한 시간대 (자정을 넘는 구간 지원).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuietHours {
+    /// 시작 시각 (포함)
+    pub start: NaiveTime,
+    /// 종료 시각 (미포함)
+    pub end: NaiveTime,
+    /// 시각 기준 시간대 (기본값: KST)
+    pub utc_offset: FixedOffset,
+}
+
+impl QuietHours {
+    /// `HH:MM-HH:MM` 형식을 KST 기준으로 파싱합니다.
+    pub fn parse(value: &str) -> Option<Self> {
+        let (start, end) = value.split_once('-')?;
+        Some(Self {
+            start: NaiveTime::parse_from_str(start.trim(), "%H:%M").ok()?,
+            end: NaiveTime::parse_from_str(end.trim(), "%H:%M").ok()?,
+            utc_offset: FixedOffset::east_opt(9 * 3600)?,
+        })
+    }
+
+    /// 주어진 시각이 조용한 시간대에 속하는지 여부.
+    pub fn contains(&self, now: DateTime<Utc>) -> bool {
+        let time = now.with_timezone(&self.utc_offset).time();
+        if self.start <= self.end {
+            time >= self.start && time < self.end
+        } else {
+            time >= self.start || time < self.end
+        }
+    }
+}
+
+/// 긴급 알림 전달 방식.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SmsDelivery {
+    /// 문자 메시지
+    Sms,
+    /// 음성 전화 (메시지를 읽어 줌)
+    Voice,
+}
+
+/// SMS/음성 알림 설정.
+#[derive(Debug, Clone)]
+pub struct SmsConfig {
+    /// Twilio Account SID
+    pub account_sid: String,
+    /// Twilio Auth Token
+    pub auth_token: String,
+    /// 발신 번호
+    pub from_number: String,
+    /// 수신 번호 목록
+    pub to_numbers: Vec<String>,
+    /// 전송 활성화 여부
+    pub enabled: bool,
+    /// 조용한 시간대에 음성 전화로 전환할지 여부
+    pub voice_enabled: bool,
+    /// 조용한 시간대
+    pub quiet_hours: Option<QuietHours>,
+}
+
+impl SmsConfig {
+    /// 환경 변수에서 설정을 생성합니다.
+    ///
+    /// Twilio 인증 정보나 수신 번호가 없으면 `None`을 반환합니다.
+    pub fn from_env() -> Option<Self> {
+        let account_sid = std::env::var("TWILIO_ACCOUNT_SID").ok()?;
+        let auth_token = std::env::var("TWILIO_AUTH_TOKEN").ok()?;
+        let from_number = std::env::var("TWILIO_FROM_NUMBER").ok()?;
+        let to_numbers: Vec<String> = std::env::var("SMS_ALERT_TO")
+            .ok()?
+            .split(',')
+            .map(|n| n.trim().to_string())
+            .filter(|n| !n.is_empty())
+            .collect();
+        if to_numbers.is_empty() {
+            return None;
+        }
+
+        let flag = |key: &str, default: bool| {
+            std::env::var(key)
+                .map(|v| matches!(v.to_lowercase().as_str(), "true" | "1"))
+                .unwrap_or(default)
+        };
+
+        Some(Self {
+            account_sid,
+            auth_token,
+            from_number,
+            to_numbers,
+            enabled: flag("SMS_ENABLED", true),
+            voice_enabled: flag("SMS_VOICE_ENABLED", false),
+            quiet_hours: std::env::var("SMS_QUIET_HOURS")
+                .ok()
+                .and_then(|v| QuietHours::parse(&v)),
+        })
+    }
+
+    /// 주어진 시각의 전달 방식.
+    pub fn delivery_at(&self, now: DateTime<Utc>) -> SmsDelivery {
+        let quiet = self.quiet_hours.is_some_and(|q| q.contains(now));
+        if self.voice_enabled && quiet {
+            SmsDelivery::Voice
+        } else {
+            SmsDelivery::Sms
+        }
+    }
+}
+
+/// 긴급 알림 본문 (요약 + 상세 메시지).
+pub fn format_critical_text(notification: &Notification) -> String {
+    let detail = match &notification.event {
+        NotificationEvent::SystemError { message, .. }
+        | NotificationEvent::RiskAlert { message, .. }
+        | NotificationEvent::Custom { message, .. } => Some(message.as_str()),
+        NotificationEvent::StrategyStopped { reason, .. } => Some(reason.as_str()),
+        _ => None,
+    };
+
+    let mut text = format!("[ZeroQuant 긴급] {}", notification.event.summary());
+    if let Some(detail) = detail.filter(|d| !d.is_empty()) {
+        text.push_str(" - ");
+        text.push_str(detail);
+    }
+    text.chars().take(MAX_SMS_CHARS).collect()
+}
+
+/// 음성 안내용 TwiML (두 번 반복해서 읽음).
+fn voice_twiml(text: &str) -> String {
+    let escaped = text
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;");
+    format!(
+        "<Response><Say language=\"ko-KR\" loop=\"2\">{}</Say></Response>",
+        escaped
+    )
+}
+
+/// Twilio SMS/음성 긴급 알림 전송기.
+pub struct SmsSender {
+    config: SmsConfig,
+    client: reqwest::Client,
+}
+
+impl SmsSender {
+    /// 새 전송기를 생성합니다.
+    pub fn new(config: SmsConfig) -> Self {
+        Self {
+            config,
+            client: reqwest::Client::new(),
+        }
+    }
+
+    /// 환경 변수에서 전송기를 생성합니다.
+    pub fn from_env() -> Option<Self> {
+        SmsConfig::from_env().map(Self::new)
+    }
+
+    /// Twilio REST API 호출.
+    async fn post(&self, resource: &str, params: &[(&str, &str)]) -> NotificationResult<()> {
+        let url = format!(
+            "https://api.twilio.com/2010-04-01/Accounts/{}/{}.json",
+            self.config.account_sid, resource
+        );
+        let response = self
+            .client
+            .post(&url)
+            .basic_auth(&self.config.account_sid, Some(&self.config.auth_token))
+            .form(params)
+            .send()
+            .await?;
+
+        let status = response.status();
+        if status.is_success() {
+            return Ok(());
+        }
+        if status.as_u16() == 429 {
+            return Err(NotificationError::RateLimited(60));
+        }
+        let body = response.text().await.unwrap_or_default();
+        Err(NotificationError::SendFailed(format!(
+            "Twilio HTTP {}: {}",
+            status, body
+        )))
+    }
+
+    /// 한 수신자에게 전달합니다.
+    async fn deliver(&self, to: &str, text: &str, delivery: SmsDelivery) -> NotificationResult<()> {
+        let from = self.config.from_number.as_str();
+        match delivery {
+            SmsDelivery::Sms => {
+                self.post("Messages", &[("To", to), ("From", from), ("Body", text)])
+                    .await
+            }
+            SmsDelivery::Voice => {
+                let twiml = voice_twiml(text);
+                self.post("Calls", &[("To", to), ("From", from), ("Twiml", &twiml)])
+                    .await
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl NotificationSender for SmsSender {
+    async fn send(&self, notification: &Notification) -> NotificationResult<()> {
+        // 긴급 알림 전용
+        if notification.priority < NotificationPriority::Critical {
+            return Ok(());
+        }
+
+        let text = format_critical_text(notification);
+        let delivery = self.config.delivery_at(Utc::now());
+        let mut last_error = None;
+        for to in &self.config.to_numbers {
+            match self.deliver(to, &text, delivery).await {
+                Ok(()) => info!(to = %to, ?delivery, "Critical alert delivered"),
+                Err(e) => {
+                    warn!(to = %to, ?delivery, error = %e, "Critical alert delivery failed");
+                    last_error = Some(e);
+                }
+            }
+        }
+
+        last_error.map_or(Ok(()), Err)
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.config.enabled && !self.config.to_numbers.is_empty()
+    }
+
+    fn name(&self) -> &str {
+        "sms"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn config(voice_enabled: bool, quiet_hours: &str) -> SmsConfig {
+        SmsConfig {
+            account_sid: "AC123".to_string(),
+            auth_token: "token".to_string(),
+            from_number: "+12025550123".to_string(),
+            to_numbers: vec!["+821012345678".to_string()],
+            enabled: true,
+            voice_enabled,
+            quiet_hours: QuietHours::parse(quiet_hours),
+        }
+    }
+
+    #[test]
+    fn test_quiet_hours_voice_escalation() {
+        let sms = config(true, "23:00-07:00");
+        // 15:00 UTC = 00:00 KST (조용한 시간대)
+        let night = Utc.with_ymd_and_hms(2026, 3, 5, 15, 0, 0).unwrap();
+        // 22:00 UTC = 07:00 KST (종료 시각은 미포함)
+        let morning = Utc.with_ymd_and_hms(2026, 3, 5, 22, 0, 0).unwrap();
+        assert_eq!(sms.delivery_at(night), SmsDelivery::Voice);
+        assert_eq!(sms.delivery_at(morning), SmsDelivery::Sms);
+
+        // 음성 전환이 꺼져 있으면 항상 SMS
+        assert_eq!(
+            config(false, "23:00-07:00").delivery_at(night),
+            SmsDelivery::Sms
+        );
+        assert!(QuietHours::parse("23:00").is_none());
+    }
+
+    #[test]
+    fn test_format_critical_text() {
+        let notification = Notification::new(NotificationEvent::SystemError {
+            error_code: "ORDER_CIRCUIT_OPEN".to_string(),
+            message: "kis_kr 주문 차단: 인증 실패".to_string(),
+        })
+        .with_priority(NotificationPriority::Critical);
+
+        assert_eq!(
+            format_critical_text(&notification),
+            "[ZeroQuant 긴급] 시스템 오류 ORDER_CIRCUIT_OPEN - kis_kr 주문 차단: 인증 실패"
+        );
+        assert_eq!(
+            voice_twiml("A<B"),
+            "<Response><Say language=\"ko-KR\" loop=\"2\">A&lt;B</Say></Response>"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_non_critical_is_skipped() {
+        // 긴급이 아니면 전송하지 않음 (네트워크 호출 없음)
+        let sender = SmsSender::new(config(false, ""));
+        let notification = Notification::new(NotificationEvent::Custom {
+            title: "info".to_string(),
+            message: "hello".to_string(),
+        })
+        .with_priority(NotificationPriority::High);
+        assert!(sender.send(&notification).await.is_ok());
+    }
+}
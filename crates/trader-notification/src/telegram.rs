@@ -388,6 +388,132 @@ impl TelegramSender {
                      <i>{recommendation}</i>"
                 )
             }
+
+            NotificationEvent::BacktestRegression {
+                template_name,
+                strategy_id,
+                total_return_pct,
+                max_drawdown_pct,
+                regressions,
+            } => {
+                let details: String = regressions.iter().map(|r| format!("• {r}\n")).collect();
+
+                format!(
+                    "📉 <b>백테스트 성과 악화: {template_name}</b>\n\n\
+                     전략: <code>{strategy_id}</code>\n\
+                     총 수익률: {total_return_pct:.2}%\n\
+                     최대 낙폭: {max_drawdown_pct:.2}%\n\n\
+                     {details}"
+                )
+            }
+
+            NotificationEvent::CorrelationShift {
+                symbol_a,
+                symbol_b,
+                kind,
+                short_corr,
+                long_corr,
+                short_window,
+                long_window,
+            } => {
+                let (emoji, title) = match kind.as_str() {
+                    "breakdown" => ("🔀", "상관관계 붕괴"),
+                    _ => ("🔗", "상관관계 급등"),
+                };
+
+                format!(
+                    "{emoji} <b>{title}: {symbol_a} / {symbol_b}</b>\n\n\
+                     단기({short_window}일): {short_corr:+.2}\n\
+                     장기({long_window}일): {long_corr:+.2}\n\
+                     변화: {:+.2}",
+                    short_corr - long_corr
+                )
+            }
+
+            NotificationEvent::WatchlistAlert {
+                watchlist,
+                symbol,
+                rule,
+                threshold,
+                value,
+                price,
+            } => {
+                let condition = match rule.as_str() {
+                    "price_above" => format!("가격 {threshold} 상향 돌파"),
+                    "price_below" => format!("가격 {threshold} 하향 돌파"),
+                    "pct_move" => format!("기준가 대비 {value:+.2}% 변동 (기준 ±{threshold}%)"),
+                    "rsi_above" => format!("RSI {value:.1} (기준 {threshold} 상향 돌파)"),
+                    "rsi_below" => format!("RSI {value:.1} (기준 {threshold} 하향 돌파)"),
+                    other => format!("{other} {value} (기준 {threshold})"),
+                };
+
+                format!(
+                    "🔔 <b>관심종목 알림: {symbol}</b>\n\n\
+                     {condition}\n\
+                     현재가: {price}\n\
+                     그룹: {watchlist}"
+                )
+            }
+
+            NotificationEvent::ReconciliationMismatch {
+                exchange,
+                total,
+                corrected,
+                lines,
+                omitted,
+            } => {
+                let lines = lines
+                    .iter()
+                    .map(|line| format!("• {line}"))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                let omitted = if *omitted > 0 {
+                    format!("\n… 외 {omitted}건")
+                } else {
+                    String::new()
+                };
+                let corrected = if *corrected > 0 {
+                    format!("\n자동 보정: {corrected}건")
+                } else {
+                    String::new()
+                };
+
+                format!(
+                    "🧾 <b>대사 불일치: {exchange}</b>\n\n\
+                     불일치 {total}건{corrected}\n\n\
+                     {lines}{omitted}"
+                )
+            }
+
+            NotificationEvent::Digest {
+                date,
+                total,
+                counts,
+                lines,
+                omitted,
+            } => {
+                let counts = counts
+                    .iter()
+                    .map(|(kind, count)| format!("{kind} {count}"))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let lines = lines
+                    .iter()
+                    .map(|line| format!("• {line}"))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                let omitted = if *omitted > 0 {
+                    format!("\n… 외 {omitted}건")
+                } else {
+                    String::new()
+                };
+
+                format!(
+                    "🗂 <b>알림 다이제스트: {date}</b>\n\n\
+                     총 {total}건 ({counts})\n\n\
+                     {lines}{omitted}"
+                )
+            }
         };
 
         let timestamp = notification.timestamp.format("%Y-%m-%d %H:%M:%S UTC");
@@ -505,6 +631,22 @@ impl NotificationManager {
         Ok(())
     }
 
+    /// 모든 전송기의 보류 중인 다이제스트를 전송합니다.
+    pub async fn flush(&self) -> NotificationResult<()> {
+        let mut last_error = None;
+
+        for sender in &self.senders {
+            if sender.is_enabled() {
+                if let Err(e) = sender.flush().await {
+                    error!("Failed to flush digest via {}: {}", sender.name(), e);
+                    last_error = Some(e);
+                }
+            }
+        }
+
+        last_error.map_or(Ok(()), Err)
+    }
+
     /// 주문 체결 알림을 전송합니다.
     pub async fn notify_order_filled(
         &self,
@@ -727,6 +869,97 @@ impl NotificationManager {
 
         self.notify(&notification).await
     }
+
+    /// 정기 백테스트 성과 악화 알림을 전송합니다.
+    pub async fn notify_backtest_regression(
+        &self,
+        template_name: &str,
+        strategy_id: &str,
+        total_return_pct: Decimal,
+        max_drawdown_pct: Decimal,
+        regressions: Vec<String>,
+    ) -> NotificationResult<()> {
+        let notification = Notification::new(NotificationEvent::BacktestRegression {
+            template_name: template_name.to_string(),
+            strategy_id: strategy_id.to_string(),
+            total_return_pct,
+            max_drawdown_pct,
+            regressions,
+        })
+        .with_priority(NotificationPriority::High);
+
+        self.notify(&notification).await
+    }
+
+    /// 시장 간 상관관계 레짐 변화 알림을 전송합니다.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn notify_correlation_shift(
+        &self,
+        symbol_a: &str,
+        symbol_b: &str,
+        kind: &str,
+        short_corr: f64,
+        long_corr: f64,
+        short_window: usize,
+        long_window: usize,
+    ) -> NotificationResult<()> {
+        let notification = Notification::new(NotificationEvent::CorrelationShift {
+            symbol_a: symbol_a.to_string(),
+            symbol_b: symbol_b.to_string(),
+            kind: kind.to_string(),
+            short_corr,
+            long_corr,
+            short_window,
+            long_window,
+        })
+        .with_priority(NotificationPriority::High);
+
+        self.notify(&notification).await
+    }
+
+    /// 관심종목 알림 규칙 발동 알림을 전송합니다.
+    pub async fn notify_watchlist_alert(
+        &self,
+        watchlist: &str,
+        symbol: &str,
+        rule: &str,
+        threshold: Decimal,
+        value: Decimal,
+        price: Decimal,
+    ) -> NotificationResult<()> {
+        let notification = Notification::new(NotificationEvent::WatchlistAlert {
+            watchlist: watchlist.to_string(),
+            symbol: symbol.to_string(),
+            rule: rule.to_string(),
+            threshold,
+            value,
+            price,
+        })
+        .with_priority(NotificationPriority::High);
+
+        self.notify(&notification).await
+    }
+
+    /// 사후 대사 불일치 알림을 전송합니다.
+    pub async fn notify_reconciliation_mismatch(
+        &self,
+        exchange: &str,
+        total: usize,
+        corrected: usize,
+        lines: Vec<String>,
+        omitted: usize,
+    ) -> NotificationResult<()> {
+        let notification = Notification::new(NotificationEvent::ReconciliationMismatch {
+            exchange: exchange.to_string(),
+            total,
+            corrected,
+            lines,
+            omitted,
+        })
+        .with_priority(NotificationPriority::High);
+
+        self.notify(&notification).await
+    }
 }
 
 impl Default for NotificationManager {
@@ -778,4 +1011,89 @@ mod tests {
         assert!(message.contains("💰")); // Profit emoji
         assert!(message.contains("+100"));
     }
+
+    #[test]
+    fn test_format_backtest_regression() {
+        let config = TelegramConfig::new("test_token".to_string(), "123456".to_string());
+        let sender = TelegramSender::new(config);
+
+        let notification = Notification::new(NotificationEvent::BacktestRegression {
+            template_name: "HAA 주간".to_string(),
+            strategy_id: "haa".to_string(),
+            total_return_pct: Decimal::new(1234, 2),
+            max_drawdown_pct: Decimal::new(2500, 2),
+            regressions: vec!["최대 낙폭 신기록: 25.00% (이전 최대 20.00%)".to_string()],
+        });
+
+        let message = sender.format_message(&notification);
+        assert!(message.contains("백테스트 성과 악화: HAA 주간"));
+        assert!(message.contains("12.34%"));
+        assert!(message.contains("• 최대 낙폭 신기록"));
+    }
+
+    #[test]
+    fn test_format_watchlist_alert() {
+        let config = TelegramConfig::new("test_token".to_string(), "123456".to_string());
+        let sender = TelegramSender::new(config);
+
+        let notification = Notification::new(NotificationEvent::WatchlistAlert {
+            watchlist: "반도체".to_string(),
+            symbol: "005930".to_string(),
+            rule: "pct_move".to_string(),
+            threshold: Decimal::new(5, 0),
+            value: Decimal::new(-5321, 3),
+            price: Decimal::new(66_000, 0),
+        });
+
+        let message = sender.format_message(&notification);
+        assert!(message.contains("관심종목 알림: 005930"));
+        assert!(message.contains("기준가 대비 -5.32% 변동 (기준 ±5%)"));
+        assert!(message.contains("그룹: 반도체"));
+    }
+
+    #[test]
+    fn test_format_reconciliation_mismatch() {
+        let config = TelegramConfig::new("test_token".to_string(), "123456".to_string());
+        let sender = TelegramSender::new(config);
+
+        let notification = Notification::new(NotificationEvent::ReconciliationMismatch {
+            exchange: "KIS-KR".to_string(),
+            total: 3,
+            corrected: 1,
+            lines: vec![
+                "[tracker] quantity_mismatch 005930: broker=10 local=8".to_string(),
+                "[database] unknown_execution 0000012345: broker=5 local=-".to_string(),
+            ],
+            omitted: 1,
+        });
+
+        let message = sender.format_message(&notification);
+        assert!(message.contains("대사 불일치: KIS-KR"));
+        assert!(message.contains("불일치 3건\n자동 보정: 1건"));
+        assert!(message.contains("• [tracker] quantity_mismatch 005930: broker=10 local=8"));
+        assert!(message.contains("… 외 1건"));
+    }
+
+    #[test]
+    fn test_format_digest() {
+        let config = TelegramConfig::new("test_token".to_string(), "123456".to_string());
+        let sender = TelegramSender::new(config);
+
+        let notification = Notification::new(NotificationEvent::Digest {
+            date: "2026-03-05".to_string(),
+            total: 12,
+            counts: vec![
+                ("order_filled".to_string(), 10),
+                ("risk_alert".to_string(), 2),
+            ],
+            lines: vec!["체결 005930 buy 10 @ 71000".to_string()],
+            omitted: 11,
+        });
+
+        let message = sender.format_message(&notification);
+        assert!(message.contains("알림 다이제스트: 2026-03-05"));
+        assert!(message.contains("총 12건 (order_filled 10, risk_alert 2)"));
+        assert!(message.contains("• 체결 005930 buy 10 @ 71000"));
+        assert!(message.contains("외 11건"));
+    }
 }
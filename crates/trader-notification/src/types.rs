@@ -6,7 +6,9 @@ use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
 /// 알림 우선순위 레벨.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+///
+/// 선언 순서대로 정렬됩니다 (`Low < Normal < High < Critical`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 #[derive(Default)]
 pub enum NotificationPriority {
@@ -132,6 +134,166 @@ pub enum NotificationEvent {
         kosdaq_ratio: String,
         recommendation: String,
     },
+    /// 정기 백테스트 성과 악화 알림
+    BacktestRegression {
+        template_name: String,
+        strategy_id: String,
+        total_return_pct: Decimal,
+        max_drawdown_pct: Decimal,
+        /// 악화 항목 설명 (예: "최대 낙폭 신기록: 25.00% (이전 최대 20.00%)")
+        regressions: Vec<String>,
+    },
+    /// 시장 간 상관관계 레짐 변화 알림
+    CorrelationShift {
+        symbol_a: String,
+        symbol_b: String,
+        /// 변화 유형 (breakdown, spike)
+        kind: String,
+        short_corr: f64,
+        long_corr: f64,
+        short_window: usize,
+        long_window: usize,
+    },
+    /// 관심종목 가격/지표 알림 규칙 발동
+    WatchlistAlert {
+        watchlist: String,
+        symbol: String,
+        /// 규칙 유형 (price_above, price_below, pct_move, rsi_above, rsi_below)
+        rule: String,
+        threshold: Decimal,
+        /// 발동 시 평가값 (가격, 기준가 대비 변동률 %, RSI)
+        value: Decimal,
+        /// 발동 시 종가
+        price: Decimal,
+    },
+    /// 브로커와 로컬 상태의 사후 대사 불일치
+    ReconciliationMismatch {
+        exchange: String,
+        /// 불일치 건수
+        total: usize,
+        /// 자동 보정한 건수
+        corrected: usize,
+        /// 불일치 한 줄 요약
+        lines: Vec<String>,
+        /// 줄 수 제한으로 생략된 불일치 수
+        omitted: usize,
+    },
+    /// 알림 다이제스트 (보류된 낮은 우선순위 알림 묶음)
+    Digest {
+        date: String,
+        /// 보류된 알림 수
+        total: usize,
+        /// 이벤트 종류별 건수 (건수 내림차순)
+        counts: Vec<(String, usize)>,
+        /// 알림 한 줄 요약 (시간순)
+        lines: Vec<String>,
+        /// 줄 수 제한으로 생략된 알림 수
+        omitted: usize,
+    },
+}
+
+impl NotificationEvent {
+    /// 이벤트 종류 (직렬화 태그와 동일).
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::OrderFilled { .. } => "order_filled",
+            Self::PositionOpened { .. } => "position_opened",
+            Self::PositionClosed { .. } => "position_closed",
+            Self::StopLossTriggered { .. } => "stop_loss_triggered",
+            Self::TakeProfitTriggered { .. } => "take_profit_triggered",
+            Self::DailySummary { .. } => "daily_summary",
+            Self::RiskAlert { .. } => "risk_alert",
+            Self::StrategyStarted { .. } => "strategy_started",
+            Self::StrategyStopped { .. } => "strategy_stopped",
+            Self::SystemError { .. } => "system_error",
+            Self::SignalAlert { .. } => "signal_alert",
+            Self::Custom { .. } => "custom",
+            Self::RouteStateChanged { .. } => "route_state_changed",
+            Self::MacroAlert { .. } => "macro_alert",
+            Self::MarketBreadthAlert { .. } => "market_breadth_alert",
+            Self::BacktestRegression { .. } => "backtest_regression",
+            Self::CorrelationShift { .. } => "correlation_shift",
+            Self::WatchlistAlert { .. } => "watchlist_alert",
+            Self::ReconciliationMismatch { .. } => "reconciliation_mismatch",
+            Self::Digest { .. } => "digest",
+        }
+    }
+
+    /// 다이제스트용 한 줄 요약.
+    pub fn summary(&self) -> String {
+        match self {
+            Self::OrderFilled {
+                symbol,
+                side,
+                quantity,
+                price,
+                ..
+            } => format!("체결 {} {} {} @ {}", symbol, side, quantity, price),
+            Self::PositionOpened {
+                symbol,
+                side,
+                quantity,
+                entry_price,
+            } => format!("진입 {} {} {} @ {}", symbol, side, quantity, entry_price),
+            Self::PositionClosed {
+                symbol,
+                pnl,
+                pnl_percent,
+                ..
+            } => format!("청산 {} 손익 {} ({}%)", symbol, pnl, pnl_percent),
+            Self::StopLossTriggered { symbol, loss, .. } => {
+                format!("손절 {} 손실 {}", symbol, loss)
+            }
+            Self::TakeProfitTriggered { symbol, profit, .. } => {
+                format!("익절 {} 수익 {}", symbol, profit)
+            }
+            Self::DailySummary {
+                date, total_pnl, ..
+            } => format!("일일 요약 {} 손익 {}", date, total_pnl),
+            Self::RiskAlert { alert_type, .. } => format!("리스크 경고 {}", alert_type),
+            Self::StrategyStarted { strategy_name, .. } => format!("전략 시작 {}", strategy_name),
+            Self::StrategyStopped {
+                strategy_name,
+                reason,
+                ..
+            } => format!("전략 중지 {} ({})", strategy_name, reason),
+            Self::SystemError { error_code, .. } => format!("시스템 오류 {}", error_code),
+            Self::SignalAlert {
+                signal_type,
+                symbol,
+                strategy_name,
+                ..
+            } => format!("신호 {} {} ({})", signal_type, symbol, strategy_name),
+            Self::Custom { title, .. } => title.clone(),
+            Self::RouteStateChanged {
+                symbol,
+                previous_state,
+                new_state,
+                ..
+            } => format!("{} {} → {}", symbol, previous_state, new_state),
+            Self::MacroAlert { risk_level, .. } => format!("매크로 {}", risk_level),
+            Self::MarketBreadthAlert { temperature, .. } => format!("시장 온도 {}", temperature),
+            Self::BacktestRegression { template_name, .. } => {
+                format!("백테스트 성과 악화 {}", template_name)
+            }
+            Self::CorrelationShift {
+                symbol_a,
+                symbol_b,
+                kind,
+                ..
+            } => format!("상관관계 {} {}/{}", kind, symbol_a, symbol_b),
+            Self::WatchlistAlert {
+                symbol,
+                rule,
+                value,
+                ..
+            } => format!("관심종목 알림 {} {} ({})", symbol, rule, value),
+            Self::ReconciliationMismatch {
+                exchange, total, ..
+            } => format!("대사 불일치 {} ({}건)", exchange, total),
+            Self::Digest { date, total, .. } => format!("다이제스트 {} ({}건)", date, total),
+        }
+    }
 }
 
 /// 알림 메시지.
@@ -208,4 +370,9 @@ pub trait NotificationSender: Send + Sync {
 
     /// 전송기 이름을 반환합니다.
     fn name(&self) -> &str;
+
+    /// 보류 중인 다이제스트를 전송합니다 (다이제스트 미사용 전송기는 아무 일도 하지 않음).
+    async fn flush(&self) -> NotificationResult<()> {
+        Ok(())
+    }
 }
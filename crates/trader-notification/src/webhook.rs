@@ -0,0 +1,371 @@
+//! 아웃바운드 웹훅 전송.
+//!
+//! 체결, 포지션 변경, 전략 상태 전이 등의 이벤트를 외부 시스템
+//! (Slack 워크플로, n8n, 사용자 서비스 등)으로 HTTP POST 합니다.
+//!
+//! # 요청 형식
+//!
+//! ```json
+//! {
+//!   "id": "0b6f...",
+//!   "event": "order.filled",
+//!   "timestamp": "2026-01-01T00:00:00Z",
+//!   "data": { ... }
+//! }
+//! ```
+//!
+//! 비밀 키가 설정된 웹훅은 본문의 HMAC-SHA256 서명을
+//! `X-Webhook-Signature: sha256=<hex>` 헤더로 전송합니다.
+
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use std::time::{Duration, Instant};
+use tracing::{debug, warn};
+
+/// 이벤트 타입 헤더.
+pub const WEBHOOK_EVENT_HEADER: &str = "X-Webhook-Event";
+
+/// 이벤트 ID 헤더 (수신 측 중복 제거용).
+pub const WEBHOOK_ID_HEADER: &str = "X-Webhook-Id";
+
+/// HMAC 서명 헤더.
+pub const WEBHOOK_SIGNATURE_HEADER: &str = "X-Webhook-Signature";
+
+/// 외부로 전송되는 웹훅 이벤트.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookEvent {
+    /// 이벤트 ID (재시도 시에도 동일)
+    pub id: String,
+    /// 이벤트 타입 (예: `order.filled`, `position.closed`, `strategy.started`)
+    pub event: String,
+    /// 발생 시각
+    pub timestamp: DateTime<Utc>,
+    /// 이벤트 데이터
+    pub data: serde_json::Value,
+}
+
+impl WebhookEvent {
+    /// 새 이벤트를 생성합니다.
+    pub fn new(event: impl Into<String>, data: serde_json::Value) -> Self {
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            event: event.into(),
+            timestamp: Utc::now(),
+            data,
+        }
+    }
+}
+
+/// 웹훅 수신 대상.
+#[derive(Debug, Clone)]
+pub struct WebhookEndpoint {
+    /// 수신 URL
+    pub url: String,
+    /// HMAC 서명 키 (없으면 서명하지 않음)
+    pub secret: Option<String>,
+    /// 구독 이벤트 필터 (비어 있으면 전체)
+    pub events: Vec<String>,
+}
+
+impl WebhookEndpoint {
+    /// 이벤트 타입이 필터와 일치하는지 확인합니다.
+    ///
+    /// `*`는 전체, `order.*`처럼 끝이 `.*`인 패턴은 접두사 일치로 처리합니다.
+    pub fn accepts(&self, event: &str) -> bool {
+        self.events.is_empty()
+            || self
+                .events
+                .iter()
+                .any(|pattern| pattern == "*" || Self::pattern_matches(pattern, event))
+    }
+
+    /// 이벤트 타입을 명시적으로 구독했는지 확인합니다.
+    ///
+    /// 빈 필터와 `*`는 제외합니다. 캔들처럼 빈도가 높은 이벤트는
+    /// `kline.closed`나 `kline.*`로 구독한 웹훅에만 전송할 때 사용합니다.
+    pub fn accepts_explicitly(&self, event: &str) -> bool {
+        self.events
+            .iter()
+            .any(|pattern| Self::pattern_matches(pattern, event))
+    }
+
+    fn pattern_matches(pattern: &str, event: &str) -> bool {
+        pattern == event
+            || pattern
+                .strip_suffix(".*")
+                .is_some_and(|prefix| event.starts_with(&format!("{prefix}.")))
+    }
+}
+
+/// 재시도 정책.
+#[derive(Debug, Clone)]
+pub struct WebhookRetryPolicy {
+    /// 최대 시도 횟수 (첫 시도 포함)
+    pub max_attempts: u32,
+    /// 첫 재시도 대기 시간
+    pub initial_backoff: Duration,
+    /// 최대 대기 시간
+    pub max_backoff: Duration,
+    /// 요청 타임아웃
+    pub timeout: Duration,
+}
+
+impl Default for WebhookRetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(30),
+            timeout: Duration::from_secs(10),
+        }
+    }
+}
+
+impl WebhookRetryPolicy {
+    /// `attempt`번째 시도 실패 후 대기 시간 (지수 백오프).
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        self.initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff)
+    }
+}
+
+/// 웹훅 전송 결과.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookDelivery {
+    /// 성공 여부 (2xx 응답)
+    pub success: bool,
+    /// 시도 횟수
+    pub attempts: u32,
+    /// 마지막 HTTP 상태 코드
+    pub status_code: Option<u16>,
+    /// 마지막 실패 사유
+    pub error: Option<String>,
+    /// 전체 소요 시간 (ms, 재시도 대기 포함)
+    pub duration_ms: u64,
+}
+
+/// 응답 상태 코드가 재시도 대상인지 확인합니다.
+///
+/// 서버 오류(5xx), 요청 한도 초과(429), 타임아웃(408)만 재시도합니다.
+pub fn is_retryable_status(status: u16) -> bool {
+    status >= 500 || status == 429 || status == 408
+}
+
+/// 본문의 HMAC-SHA256 서명을 `sha256=<hex>` 형식으로 계산합니다.
+pub fn sign_webhook_payload(secret: &str, body: &[u8]) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any size");
+    mac.update(body);
+    format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+}
+
+/// 아웃바운드 웹훅 전송기.
+#[derive(Clone)]
+pub struct WebhookClient {
+    client: reqwest::Client,
+    retry: WebhookRetryPolicy,
+}
+
+impl WebhookClient {
+    /// 새 웹훅 전송기를 생성합니다.
+    pub fn new(retry: WebhookRetryPolicy) -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(retry.timeout)
+            .build()
+            .unwrap_or_default();
+        Self { client, retry }
+    }
+
+    /// 이벤트를 전송합니다.
+    ///
+    /// 네트워크 오류 및 재시도 대상 응답은 지수 백오프로 재시도하며,
+    /// 최종 결과를 반환합니다 (에러를 반환하지 않음).
+    pub async fn deliver(
+        &self,
+        endpoint: &WebhookEndpoint,
+        event: &WebhookEvent,
+    ) -> WebhookDelivery {
+        let started = Instant::now();
+        let body = match serde_json::to_vec(event) {
+            Ok(body) => body,
+            Err(e) => {
+                return WebhookDelivery {
+                    success: false,
+                    attempts: 0,
+                    status_code: None,
+                    error: Some(format!("직렬화 실패: {e}")),
+                    duration_ms: 0,
+                }
+            }
+        };
+        let signature = endpoint
+            .secret
+            .as_deref()
+            .filter(|s| !s.is_empty())
+            .map(|secret| sign_webhook_payload(secret, &body));
+
+        let mut attempts = 0;
+        let mut status_code = None;
+        let mut error = None;
+
+        while attempts < self.retry.max_attempts.max(1) {
+            if attempts > 0 {
+                tokio::time::sleep(self.retry.backoff(attempts)).await;
+            }
+            attempts += 1;
+
+            let mut request = self
+                .client
+                .post(&endpoint.url)
+                .header(reqwest::header::CONTENT_TYPE, "application/json")
+                .header(WEBHOOK_EVENT_HEADER, &event.event)
+                .header(WEBHOOK_ID_HEADER, &event.id)
+                .body(body.clone());
+            if let Some(signature) = &signature {
+                request = request.header(WEBHOOK_SIGNATURE_HEADER, signature);
+            }
+
+            let retryable = match request.send().await {
+                Ok(response) => {
+                    let status = response.status().as_u16();
+                    status_code = Some(status);
+                    if response.status().is_success() {
+                        debug!(url = %endpoint.url, event = %event.event, attempts, "Webhook delivered");
+                        return WebhookDelivery {
+                            success: true,
+                            attempts,
+                            status_code,
+                            error: None,
+                            duration_ms: started.elapsed().as_millis() as u64,
+                        };
+                    }
+                    let text = response.text().await.unwrap_or_default();
+                    error = Some(format!("HTTP {status}: {}", truncate(&text, 200)));
+                    is_retryable_status(status)
+                }
+                Err(e) => {
+                    status_code = None;
+                    error = Some(e.to_string());
+                    true
+                }
+            };
+
+            warn!(
+                url = %endpoint.url,
+                event = %event.event,
+                attempts,
+                error = ?error,
+                "Webhook delivery failed"
+            );
+
+            if !retryable {
+                break;
+            }
+        }
+
+        WebhookDelivery {
+            success: false,
+            attempts,
+            status_code,
+            error,
+            duration_ms: started.elapsed().as_millis() as u64,
+        }
+    }
+}
+
+impl Default for WebhookClient {
+    fn default() -> Self {
+        Self::new(WebhookRetryPolicy::default())
+    }
+}
+
+fn truncate(text: &str, max_chars: usize) -> &str {
+    match text.char_indices().nth(max_chars) {
+        Some((idx, _)) => &text[..idx],
+        None => text,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn endpoint(events: &[&str]) -> WebhookEndpoint {
+        WebhookEndpoint {
+            url: "http://localhost/hook".to_string(),
+            secret: None,
+            events: events.iter().map(|e| e.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_event_filter() {
+        assert!(endpoint(&[]).accepts("order.filled"));
+        assert!(endpoint(&["*"]).accepts("strategy.started"));
+        assert!(endpoint(&["order.filled"]).accepts("order.filled"));
+        assert!(!endpoint(&["order.filled"]).accepts("order.partially_filled"));
+        assert!(endpoint(&["position.*"]).accepts("position.closed"));
+        assert!(!endpoint(&["position.*"]).accepts("positions.closed"));
+        assert!(!endpoint(&["strategy.*"]).accepts("order.filled"));
+    }
+
+    #[test]
+    fn test_explicit_event_filter() {
+        assert!(!endpoint(&[]).accepts_explicitly("kline.closed"));
+        assert!(!endpoint(&["*"]).accepts_explicitly("kline.closed"));
+        assert!(endpoint(&["kline.closed"]).accepts_explicitly("kline.closed"));
+        assert!(endpoint(&["order.*", "kline.*"]).accepts_explicitly("kline.closed"));
+        assert!(!endpoint(&["order.*"]).accepts_explicitly("kline.closed"));
+    }
+
+    #[test]
+    fn test_signature_and_backoff() {
+        // RFC 4231 테스트 케이스 2
+        assert_eq!(
+            sign_webhook_payload("Jefe", b"what do ya want for nothing?"),
+            "sha256=5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+
+        let retry = WebhookRetryPolicy {
+            max_attempts: 5,
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(5),
+            timeout: Duration::from_secs(1),
+        };
+        assert_eq!(retry.backoff(1), Duration::from_secs(1));
+        assert_eq!(retry.backoff(2), Duration::from_secs(2));
+        assert_eq!(retry.backoff(3), Duration::from_secs(4));
+        assert_eq!(retry.backoff(4), Duration::from_secs(5));
+
+        assert!(is_retryable_status(503));
+        assert!(is_retryable_status(429));
+        assert!(!is_retryable_status(400));
+        assert!(!is_retryable_status(404));
+    }
+
+    #[tokio::test]
+    async fn test_deliver_unreachable_retries() {
+        let client = WebhookClient::new(WebhookRetryPolicy {
+            max_attempts: 2,
+            initial_backoff: Duration::from_millis(1),
+            max_backoff: Duration::from_millis(1),
+            timeout: Duration::from_millis(200),
+        });
+        let mut target = endpoint(&[]);
+        target.url = "http://127.0.0.1:9/hook".to_string();
+
+        let delivery = client
+            .deliver(
+                &target,
+                &WebhookEvent::new("webhook.test", serde_json::json!({})),
+            )
+            .await;
+        assert!(!delivery.success);
+        assert_eq!(delivery.attempts, 2);
+        assert!(delivery.error.is_some());
+    }
+}
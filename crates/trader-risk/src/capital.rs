@@ -0,0 +1,685 @@
+//! 전략별 자본 원장.
+//!
+//! 각 전략에 독립된 예산(할당 자본)을 부여하고 사용량을 추적합니다:
+//! - 전략별 할당 자본, 사용 중 자본, 실현 손익 관리
+//! - 가용 예산을 초과하는 주문의 축소 또는 거부
+//! - 전략 자본 입금/출금 및 전략 간 이체 이력
+//!
+//! 원장에 등록되지 않은 전략은 예산 제한 없이 계좌 전체 잔고 기준으로
+//! 기존 리스크 검사만 받습니다.
+//!
+//! # 예제
+//!
+//! ```rust,ignore
+//! use trader_risk::{CapitalLedger, CapitalCheck};
+//!
+//! let mut ledger = CapitalLedger::default();
+//! ledger.allocate("rsi_kr", dec!(1_000_000))?;
+//!
+//! match ledger.check_order("rsi_kr", dec!(10), dec!(70000), dec!(10)) {
+//!     CapitalCheck::Scaled { quantity, .. } => { /* 수량 축소 */ }
+//!     CapitalCheck::Rejected(reason) => { /* 주문 거부 */ }
+//!     _ => { /* 그대로 진행 */ }
+//! }
+//! ```
+
+use chrono::{DateTime, Utc};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use thiserror::Error;
+
+/// 보관할 최대 이체 이력 수.
+const MAX_TRANSFER_HISTORY: usize = 1000;
+
+/// 자본 원장 오류.
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum CapitalError {
+    #[error("Capital account not found: {0}")]
+    AccountNotFound(String),
+
+    #[error("Amount must be positive: {0}")]
+    InvalidAmount(Decimal),
+
+    #[error(
+        "Insufficient capital for {strategy_id}: requested {requested}, available {available}"
+    )]
+    InsufficientCapital {
+        strategy_id: String,
+        requested: Decimal,
+        available: Decimal,
+    },
+
+    #[error("Cannot transfer capital to the same strategy: {0}")]
+    SameAccount(String),
+}
+
+/// 가용 예산을 초과하는 주문 처리 방식.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum BudgetPolicy {
+    /// 가용 예산에 맞게 수량 축소 (기본값)
+    #[default]
+    Scale,
+    /// 주문 거부
+    Reject,
+}
+
+/// 전략 자본 계정.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StrategyCapital {
+    /// 전략 ID
+    pub strategy_id: String,
+    /// 할당 자본 (입금/출금/이체 누계)
+    pub allocated: Decimal,
+    /// 포지션 및 미체결 진입 주문에 묶인 자본
+    pub in_use: Decimal,
+    /// 원장 등록 이후 실현 손익
+    pub realized_pnl: Decimal,
+    /// 마지막 변경 시각
+    pub updated_at: DateTime<Utc>,
+}
+
+impl StrategyCapital {
+    /// 새 자본 계정 생성.
+    pub fn new(strategy_id: impl Into<String>, allocated: Decimal) -> Self {
+        Self {
+            strategy_id: strategy_id.into(),
+            allocated,
+            in_use: Decimal::ZERO,
+            realized_pnl: Decimal::ZERO,
+            updated_at: Utc::now(),
+        }
+    }
+
+    /// 전략 자기자본 (할당 자본 + 실현 손익).
+    pub fn equity(&self) -> Decimal {
+        self.allocated + self.realized_pnl
+    }
+
+    /// 신규 진입에 사용할 수 있는 자본.
+    pub fn available(&self) -> Decimal {
+        (self.equity() - self.in_use).max(Decimal::ZERO)
+    }
+
+    /// 자기자본 대비 사용 중 자본 비율 (%).
+    pub fn utilization_pct(&self) -> f64 {
+        let equity = self.equity();
+        if equity <= Decimal::ZERO {
+            return if self.in_use > Decimal::ZERO {
+                100.0
+            } else {
+                0.0
+            };
+        }
+        (self.in_use / equity * Decimal::ONE_HUNDRED)
+            .to_f64()
+            .unwrap_or(0.0)
+    }
+
+    fn touch(&mut self) {
+        self.updated_at = Utc::now();
+    }
+}
+
+/// 전략 예산 검사 결과.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CapitalCheck {
+    /// 원장에 등록되지 않은 전략 (예산 제한 없음)
+    Unmanaged,
+    /// 가용 예산 내 주문
+    Approved,
+    /// 가용 예산에 맞게 수량 축소
+    Scaled {
+        /// 축소된 수량
+        quantity: Decimal,
+        /// 검사 시점의 가용 예산
+        available: Decimal,
+    },
+    /// 주문 거부
+    Rejected(String),
+}
+
+impl CapitalCheck {
+    /// 주문 진행 가능 여부 (축소 포함).
+    pub fn is_allowed(&self) -> bool {
+        !matches!(self, CapitalCheck::Rejected(_))
+    }
+}
+
+/// 자본 이동 유형.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CapitalTransferKind {
+    /// 외부 → 전략 (증액)
+    Deposit,
+    /// 전략 → 외부 (감액)
+    Withdraw,
+    /// 전략 → 전략
+    Transfer,
+}
+
+impl CapitalTransferKind {
+    /// 문자열 표현 (DB 저장용).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CapitalTransferKind::Deposit => "deposit",
+            CapitalTransferKind::Withdraw => "withdraw",
+            CapitalTransferKind::Transfer => "transfer",
+        }
+    }
+}
+
+/// 자본 이동 기록.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CapitalTransfer {
+    /// 이동 유형
+    pub kind: CapitalTransferKind,
+    /// 출금 전략 (입금이면 None)
+    pub from_strategy: Option<String>,
+    /// 입금 전략 (출금이면 None)
+    pub to_strategy: Option<String>,
+    /// 금액
+    pub amount: Decimal,
+    /// 메모
+    pub memo: Option<String>,
+    /// 시각
+    pub timestamp: DateTime<Utc>,
+}
+
+/// 전체 원장 요약.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CapitalSummary {
+    /// 등록된 전략 수
+    pub account_count: usize,
+    /// 총 할당 자본
+    pub total_allocated: Decimal,
+    /// 총 사용 중 자본
+    pub total_in_use: Decimal,
+    /// 총 가용 자본
+    pub total_available: Decimal,
+    /// 총 실현 손익
+    pub total_realized_pnl: Decimal,
+}
+
+/// 전략별 자본 원장.
+#[derive(Debug, Clone, Default)]
+pub struct CapitalLedger {
+    /// 전략 ID별 자본 계정
+    accounts: HashMap<String, StrategyCapital>,
+    /// 예산 초과 처리 방식
+    policy: BudgetPolicy,
+    /// 최근 자본 이동 이력 (오래된 순)
+    history: VecDeque<CapitalTransfer>,
+}
+
+impl CapitalLedger {
+    /// 예산 초과 처리 방식을 지정하여 생성.
+    pub fn new(policy: BudgetPolicy) -> Self {
+        Self {
+            policy,
+            ..Default::default()
+        }
+    }
+
+    /// 예산 초과 처리 방식 조회.
+    pub fn policy(&self) -> BudgetPolicy {
+        self.policy
+    }
+
+    /// 예산 초과 처리 방식 변경.
+    pub fn set_policy(&mut self, policy: BudgetPolicy) {
+        self.policy = policy;
+    }
+
+    // ==================== 계정 관리 ====================
+
+    /// 전략 할당 자본 설정 (계정이 없으면 생성).
+    ///
+    /// 사용 중 자본과 실현 손익은 유지됩니다.
+    pub fn allocate(
+        &mut self,
+        strategy_id: &str,
+        allocated: Decimal,
+    ) -> Result<&StrategyCapital, CapitalError> {
+        if allocated < Decimal::ZERO {
+            return Err(CapitalError::InvalidAmount(allocated));
+        }
+
+        let account = self
+            .accounts
+            .entry(strategy_id.to_string())
+            .or_insert_with(|| StrategyCapital::new(strategy_id, Decimal::ZERO));
+        account.allocated = allocated;
+        account.touch();
+
+        Ok(account)
+    }
+
+    /// 전략 계정 제거 (이후 예산 제한 없음).
+    pub fn remove(&mut self, strategy_id: &str) -> Option<StrategyCapital> {
+        self.accounts.remove(strategy_id)
+    }
+
+    /// 전략 계정 조회.
+    pub fn get(&self, strategy_id: &str) -> Option<&StrategyCapital> {
+        self.accounts.get(strategy_id)
+    }
+
+    /// 전략이 원장에서 관리되는지 확인.
+    pub fn is_managed(&self, strategy_id: &str) -> bool {
+        self.accounts.contains_key(strategy_id)
+    }
+
+    /// 모든 계정 조회 (전략 ID 순).
+    pub fn accounts(&self) -> Vec<&StrategyCapital> {
+        let mut accounts: Vec<_> = self.accounts.values().collect();
+        accounts.sort_by(|a, b| a.strategy_id.cmp(&b.strategy_id));
+        accounts
+    }
+
+    /// 전체 원장 요약.
+    pub fn summary(&self) -> CapitalSummary {
+        self.accounts.values().fold(
+            CapitalSummary {
+                account_count: self.accounts.len(),
+                ..Default::default()
+            },
+            |mut summary, account| {
+                summary.total_allocated += account.allocated;
+                summary.total_in_use += account.in_use;
+                summary.total_available += account.available();
+                summary.total_realized_pnl += account.realized_pnl;
+                summary
+            },
+        )
+    }
+
+    // ==================== 입출금/이체 ====================
+
+    /// 전략 자본 증액 (계정이 없으면 생성).
+    pub fn deposit(
+        &mut self,
+        strategy_id: &str,
+        amount: Decimal,
+        memo: Option<String>,
+    ) -> Result<&StrategyCapital, CapitalError> {
+        Self::ensure_positive(amount)?;
+
+        self.push_history(CapitalTransfer {
+            kind: CapitalTransferKind::Deposit,
+            from_strategy: None,
+            to_strategy: Some(strategy_id.to_string()),
+            amount,
+            memo,
+            timestamp: Utc::now(),
+        });
+
+        let account = self
+            .accounts
+            .entry(strategy_id.to_string())
+            .or_insert_with(|| StrategyCapital::new(strategy_id, Decimal::ZERO));
+        account.allocated += amount;
+        account.touch();
+
+        Ok(account)
+    }
+
+    /// 전략 자본 감액.
+    ///
+    /// 사용 중인 자본은 출금할 수 없으며, 가용 자본 범위 내에서만 허용됩니다.
+    pub fn withdraw(
+        &mut self,
+        strategy_id: &str,
+        amount: Decimal,
+        memo: Option<String>,
+    ) -> Result<&StrategyCapital, CapitalError> {
+        Self::ensure_positive(amount)?;
+        self.ensure_available(strategy_id, amount)?;
+
+        self.push_history(CapitalTransfer {
+            kind: CapitalTransferKind::Withdraw,
+            from_strategy: Some(strategy_id.to_string()),
+            to_strategy: None,
+            amount,
+            memo,
+            timestamp: Utc::now(),
+        });
+
+        let account = self
+            .accounts
+            .get_mut(strategy_id)
+            .ok_or_else(|| CapitalError::AccountNotFound(strategy_id.to_string()))?;
+        account.allocated -= amount;
+        account.touch();
+
+        Ok(account)
+    }
+
+    /// 전략 간 자본 이체.
+    ///
+    /// 받는 전략의 계정이 없으면 새로 생성합니다.
+    pub fn transfer(
+        &mut self,
+        from: &str,
+        to: &str,
+        amount: Decimal,
+        memo: Option<String>,
+    ) -> Result<(), CapitalError> {
+        if from == to {
+            return Err(CapitalError::SameAccount(from.to_string()));
+        }
+        Self::ensure_positive(amount)?;
+        self.ensure_available(from, amount)?;
+
+        if let Some(source) = self.accounts.get_mut(from) {
+            source.allocated -= amount;
+            source.touch();
+        }
+
+        let target = self
+            .accounts
+            .entry(to.to_string())
+            .or_insert_with(|| StrategyCapital::new(to, Decimal::ZERO));
+        target.allocated += amount;
+        target.touch();
+
+        self.push_history(CapitalTransfer {
+            kind: CapitalTransferKind::Transfer,
+            from_strategy: Some(from.to_string()),
+            to_strategy: Some(to.to_string()),
+            amount,
+            memo,
+            timestamp: Utc::now(),
+        });
+
+        Ok(())
+    }
+
+    /// 최근 자본 이동 이력 조회 (최신순).
+    ///
+    /// `strategy_id`가 지정되면 해당 전략이 관련된 기록만 반환합니다.
+    pub fn history(&self, strategy_id: Option<&str>, limit: usize) -> Vec<&CapitalTransfer> {
+        self.history
+            .iter()
+            .rev()
+            .filter(|t| match strategy_id {
+                Some(id) => {
+                    t.from_strategy.as_deref() == Some(id) || t.to_strategy.as_deref() == Some(id)
+                }
+                None => true,
+            })
+            .take(limit)
+            .collect()
+    }
+
+    // ==================== 주문 예산 ====================
+
+    /// 가용 예산 기준으로 진입 주문 검사.
+    ///
+    /// # Arguments
+    /// * `strategy_id` - 주문을 생성한 전략
+    /// * `quantity` - 주문 수량
+    /// * `price` - 주문 기준 가격
+    /// * `min_order_value` - 축소 후 허용되는 최소 주문 금액
+    ///
+    /// 축소 시 수량은 원래 주문 수량의 소수 자릿수에 맞춰 내림합니다.
+    pub fn check_order(
+        &self,
+        strategy_id: &str,
+        quantity: Decimal,
+        price: Decimal,
+        min_order_value: Decimal,
+    ) -> CapitalCheck {
+        let Some(account) = self.accounts.get(strategy_id) else {
+            return CapitalCheck::Unmanaged;
+        };
+
+        let required = quantity * price;
+        let available = account.available();
+
+        if required <= available {
+            return CapitalCheck::Approved;
+        }
+
+        if self.policy == BudgetPolicy::Reject || price <= Decimal::ZERO {
+            return CapitalCheck::Rejected(format!(
+                "Strategy budget exceeded for {}: required {}, available {}",
+                strategy_id, required, available
+            ));
+        }
+
+        let scaled = (available / price).trunc_with_scale(quantity.scale());
+        if scaled <= Decimal::ZERO || scaled * price < min_order_value {
+            return CapitalCheck::Rejected(format!(
+                "Strategy budget exhausted for {}: available {} below minimum order value {}",
+                strategy_id, available, min_order_value
+            ));
+        }
+
+        CapitalCheck::Scaled {
+            quantity: scaled,
+            available,
+        }
+    }
+
+    /// 진입 주문/체결에 자본 배정 (미관리 전략은 무시).
+    pub fn reserve(&mut self, strategy_id: &str, amount: Decimal) {
+        if let Some(account) = self.accounts.get_mut(strategy_id) {
+            account.in_use += amount.max(Decimal::ZERO);
+            account.touch();
+        }
+    }
+
+    /// 청산/취소로 자본 반환 (미관리 전략은 무시).
+    pub fn release(&mut self, strategy_id: &str, amount: Decimal) {
+        if let Some(account) = self.accounts.get_mut(strategy_id) {
+            account.in_use = (account.in_use - amount.max(Decimal::ZERO)).max(Decimal::ZERO);
+            account.touch();
+        }
+    }
+
+    /// 전략 실현 손익 기록 (미관리 전략은 무시).
+    pub fn record_pnl(&mut self, strategy_id: &str, pnl: Decimal) {
+        if let Some(account) = self.accounts.get_mut(strategy_id) {
+            account.realized_pnl += pnl;
+            account.touch();
+        }
+    }
+
+    // ==================== 내부 헬퍼 ====================
+
+    fn ensure_positive(amount: Decimal) -> Result<(), CapitalError> {
+        if amount <= Decimal::ZERO {
+            return Err(CapitalError::InvalidAmount(amount));
+        }
+        Ok(())
+    }
+
+    fn ensure_available(&self, strategy_id: &str, amount: Decimal) -> Result<(), CapitalError> {
+        let account = self
+            .accounts
+            .get(strategy_id)
+            .ok_or_else(|| CapitalError::AccountNotFound(strategy_id.to_string()))?;
+
+        let available = account.available();
+        if amount > available {
+            return Err(CapitalError::InsufficientCapital {
+                strategy_id: strategy_id.to_string(),
+                requested: amount,
+                available,
+            });
+        }
+        Ok(())
+    }
+
+    fn push_history(&mut self, transfer: CapitalTransfer) {
+        if self.history.len() >= MAX_TRANSFER_HISTORY {
+            self.history.pop_front();
+        }
+        self.history.push_back(transfer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn ledger_with(strategy_id: &str, allocated: Decimal) -> CapitalLedger {
+        let mut ledger = CapitalLedger::default();
+        ledger.allocate(strategy_id, allocated).unwrap();
+        ledger
+    }
+
+    #[test]
+    fn test_unmanaged_strategy_is_not_limited() {
+        let ledger = CapitalLedger::default();
+        let check = ledger.check_order("unknown", dec!(100), dec!(1000), dec!(10));
+        assert_eq!(check, CapitalCheck::Unmanaged);
+    }
+
+    #[test]
+    fn test_order_within_budget_is_approved() {
+        let ledger = ledger_with("rsi", dec!(10000));
+        let check = ledger.check_order("rsi", dec!(5), dec!(1000), dec!(10));
+        assert_eq!(check, CapitalCheck::Approved);
+    }
+
+    #[test]
+    fn test_order_over_budget_is_scaled() {
+        let mut ledger = ledger_with("rsi", dec!(10000));
+        ledger.reserve("rsi", dec!(7500));
+
+        // 가용 2500 → 1000원짜리 2주로 축소
+        let check = ledger.check_order("rsi", dec!(5), dec!(1000), dec!(10));
+        assert_eq!(
+            check,
+            CapitalCheck::Scaled {
+                quantity: dec!(2),
+                available: dec!(2500),
+            }
+        );
+    }
+
+    #[test]
+    fn test_scaling_keeps_order_precision() {
+        let ledger = ledger_with("grid", dec!(1000));
+        let check = ledger.check_order("grid", dec!(0.04), dec!(50000), dec!(10));
+        assert_eq!(
+            check,
+            CapitalCheck::Scaled {
+                quantity: dec!(0.02),
+                available: dec!(1000),
+            }
+        );
+    }
+
+    #[test]
+    fn test_order_over_budget_is_rejected_by_policy() {
+        let mut ledger = ledger_with("rsi", dec!(1000));
+        ledger.set_policy(BudgetPolicy::Reject);
+
+        let check = ledger.check_order("rsi", dec!(2), dec!(1000), dec!(10));
+        assert!(!check.is_allowed());
+    }
+
+    #[test]
+    fn test_exhausted_budget_is_rejected() {
+        let mut ledger = ledger_with("rsi", dec!(1000));
+        ledger.reserve("rsi", dec!(995));
+
+        let check = ledger.check_order("rsi", dec!(1), dec!(100), dec!(10));
+        assert!(matches!(check, CapitalCheck::Rejected(_)));
+    }
+
+    #[test]
+    fn test_reserve_release_and_pnl() {
+        let mut ledger = ledger_with("rsi", dec!(10000));
+        ledger.reserve("rsi", dec!(4000));
+        ledger.release("rsi", dec!(4000));
+        ledger.record_pnl("rsi", dec!(500));
+
+        let account = ledger.get("rsi").unwrap();
+        assert_eq!(account.in_use, dec!(0));
+        assert_eq!(account.equity(), dec!(10500));
+        assert_eq!(account.available(), dec!(10500));
+
+        // 반환이 사용량을 초과해도 음수가 되지 않음
+        ledger.release("rsi", dec!(100));
+        assert_eq!(ledger.get("rsi").unwrap().in_use, dec!(0));
+    }
+
+    #[test]
+    fn test_utilization_pct() {
+        let mut ledger = ledger_with("rsi", dec!(10000));
+        ledger.reserve("rsi", dec!(2500));
+        assert!((ledger.get("rsi").unwrap().utilization_pct() - 25.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_deposit_creates_account() {
+        let mut ledger = CapitalLedger::default();
+        let account = ledger.deposit("new", dec!(5000), None).unwrap();
+        assert_eq!(account.allocated, dec!(5000));
+        assert!(ledger.is_managed("new"));
+        assert!(ledger.deposit("new", dec!(0), None).is_err());
+    }
+
+    #[test]
+    fn test_withdraw_limited_to_available() {
+        let mut ledger = ledger_with("rsi", dec!(10000));
+        ledger.reserve("rsi", dec!(8000));
+
+        let err = ledger.withdraw("rsi", dec!(3000), None).unwrap_err();
+        assert!(matches!(err, CapitalError::InsufficientCapital { .. }));
+
+        let account = ledger.withdraw("rsi", dec!(2000), None).unwrap();
+        assert_eq!(account.allocated, dec!(8000));
+        assert_eq!(account.available(), dec!(0));
+
+        assert!(matches!(
+            ledger.withdraw("missing", dec!(1), None),
+            Err(CapitalError::AccountNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_transfer_between_strategies() {
+        let mut ledger = ledger_with("a", dec!(10000));
+        ledger.allocate("b", dec!(1000)).unwrap();
+
+        ledger
+            .transfer("a", "b", dec!(4000), Some("rebalance".to_string()))
+            .unwrap();
+
+        assert_eq!(ledger.get("a").unwrap().allocated, dec!(6000));
+        assert_eq!(ledger.get("b").unwrap().allocated, dec!(5000));
+        assert!(matches!(
+            ledger.transfer("a", "a", dec!(1), None),
+            Err(CapitalError::SameAccount(_))
+        ));
+        assert!(ledger.transfer("a", "b", dec!(7000), None).is_err());
+
+        let summary = ledger.summary();
+        assert_eq!(summary.account_count, 2);
+        assert_eq!(summary.total_allocated, dec!(11000));
+    }
+
+    #[test]
+    fn test_history_filter_and_order() {
+        let mut ledger = CapitalLedger::default();
+        ledger.deposit("a", dec!(1000), None).unwrap();
+        ledger.deposit("b", dec!(1000), None).unwrap();
+        ledger.transfer("a", "b", dec!(100), None).unwrap();
+
+        let all = ledger.history(None, 10);
+        assert_eq!(all.len(), 3);
+        assert_eq!(all[0].kind, CapitalTransferKind::Transfer);
+
+        let only_a = ledger.history(Some("a"), 10);
+        assert_eq!(only_a.len(), 2);
+        assert_eq!(ledger.history(None, 1).len(), 1);
+    }
+}
@@ -52,6 +52,14 @@ pub struct RiskConfig {
     #[serde(default = "default_trailing_stop_pct")]
     pub trailing_stop_pct: f64,
 
+    /// ETF 매수 허용 최대 괴리율 (NAV 대비 프리미엄, 기본값: 3%)
+    #[serde(default = "default_max_etf_premium_pct")]
+    pub max_etf_premium_pct: f64,
+
+    /// 레버리지/인버스 ETF 매수 허용 최대 괴리율 (기본값: 1%)
+    #[serde(default = "default_max_leveraged_etf_premium_pct")]
+    pub max_leveraged_etf_premium_pct: f64,
+
     /// 심볼별 리스크 설정 (전역 설정을 재정의함)
     #[serde(default)]
     pub symbol_configs: HashMap<String, SymbolRiskConfig>,
@@ -119,6 +127,14 @@ fn default_trailing_stop_pct() -> f64 {
     1.5
 }
 
+fn default_max_etf_premium_pct() -> f64 {
+    3.0
+}
+
+fn default_max_leveraged_etf_premium_pct() -> f64 {
+    1.0
+}
+
 fn default_true() -> bool {
     true
 }
@@ -136,6 +152,8 @@ impl Default for RiskConfig {
             max_concurrent_positions: default_max_concurrent_positions(),
             enable_trailing_stop: false,
             trailing_stop_pct: default_trailing_stop_pct(),
+            max_etf_premium_pct: default_max_etf_premium_pct(),
+            max_leveraged_etf_premium_pct: default_max_leveraged_etf_premium_pct(),
             symbol_configs: HashMap::new(),
         }
     }
@@ -172,6 +190,8 @@ impl RiskConfig {
             max_concurrent_positions: 5,
             enable_trailing_stop: true,
             trailing_stop_pct: 1.0,
+            max_etf_premium_pct: 2.0,
+            max_leveraged_etf_premium_pct: 0.5,
             symbol_configs: HashMap::new(),
         }
     }
@@ -189,6 +209,8 @@ impl RiskConfig {
             max_concurrent_positions: 20,
             enable_trailing_stop: false,
             trailing_stop_pct: 2.0,
+            max_etf_premium_pct: 5.0,
+            max_leveraged_etf_premium_pct: 2.0,
             symbol_configs: HashMap::new(),
         }
     }
@@ -234,6 +256,15 @@ impl RiskConfig {
             .unwrap_or(true)
     }
 
+    /// ETF 유형에 따른 허용 최대 괴리율을 가져옵니다.
+    pub fn get_max_etf_premium_pct(&self, leveraged: bool) -> f64 {
+        if leveraged {
+            self.max_leveraged_etf_premium_pct
+        } else {
+            self.max_etf_premium_pct
+        }
+    }
+
     /// 심볼별 설정을 추가하거나 업데이트합니다.
     pub fn set_symbol_config(&mut self, symbol: impl Into<String>, config: SymbolRiskConfig) {
         self.symbol_configs.insert(symbol.into(), config);
@@ -271,6 +302,90 @@ impl RiskConfig {
             ));
         }
 
+        if self.max_total_exposure_pct <= 0.0 || self.max_total_exposure_pct > 100.0 {
+            return Err(ConfigValidationError::InvalidValue(
+                "max_total_exposure_pct must be between 0 and 100".into(),
+            ));
+        }
+
+        if self.max_position_pct > self.max_total_exposure_pct {
+            return Err(ConfigValidationError::InvalidValue(
+                "max_position_pct must not exceed max_total_exposure_pct".into(),
+            ));
+        }
+
+        if self.volatility_threshold <= 0.0 {
+            return Err(ConfigValidationError::InvalidValue(
+                "volatility_threshold must be greater than 0".into(),
+            ));
+        }
+
+        if self.max_concurrent_positions == 0 {
+            return Err(ConfigValidationError::InvalidValue(
+                "max_concurrent_positions must be at least 1".into(),
+            ));
+        }
+
+        if self.enable_trailing_stop && self.trailing_stop_pct <= 0.0 {
+            return Err(ConfigValidationError::InvalidValue(
+                "trailing_stop_pct must be greater than 0 when trailing stop is enabled".into(),
+            ));
+        }
+
+        if self.max_etf_premium_pct <= 0.0 || self.max_leveraged_etf_premium_pct <= 0.0 {
+            return Err(ConfigValidationError::InvalidValue(
+                "ETF premium limits must be greater than 0".into(),
+            ));
+        }
+
+        for (symbol, symbol_config) in &self.symbol_configs {
+            symbol_config.validate().map_err(|e| match e {
+                ConfigValidationError::InvalidValue(msg) => {
+                    ConfigValidationError::InvalidValue(format!("{}: {}", symbol, msg))
+                }
+                other => other,
+            })?;
+        }
+
+        Ok(())
+    }
+}
+
+impl SymbolRiskConfig {
+    /// 재정의 값을 검증합니다 (설정된 값만 검사).
+    pub fn validate(&self) -> Result<(), ConfigValidationError> {
+        if let Some(pct) = self.max_position_pct {
+            if pct <= 0.0 || pct > 100.0 {
+                return Err(ConfigValidationError::InvalidValue(
+                    "max_position_pct must be between 0 and 100".into(),
+                ));
+            }
+        }
+
+        if let Some(pct) = self.stop_loss_pct {
+            if pct <= 0.0 || pct > 50.0 {
+                return Err(ConfigValidationError::InvalidValue(
+                    "stop_loss_pct must be between 0 and 50".into(),
+                ));
+            }
+        }
+
+        if let Some(pct) = self.take_profit_pct {
+            if pct <= 0.0 {
+                return Err(ConfigValidationError::InvalidValue(
+                    "take_profit_pct must be greater than 0".into(),
+                ));
+            }
+        }
+
+        if let Some(threshold) = self.volatility_threshold {
+            if threshold <= 0.0 {
+                return Err(ConfigValidationError::InvalidValue(
+                    "volatility_threshold must be greater than 0".into(),
+                ));
+            }
+        }
+
         Ok(())
     }
 }
@@ -355,16 +470,49 @@ mod tests {
         assert!(config.validate().is_ok());
 
         // 유효하지 않은 max_position_pct
-        let mut invalid = RiskConfig::default();
-        invalid.max_position_pct = 150.0;
+        let invalid = RiskConfig {
+            max_position_pct: 150.0,
+            ..Default::default()
+        };
         assert!(invalid.validate().is_err());
 
         // 유효하지 않은 stop_loss_pct
-        let mut invalid = RiskConfig::default();
-        invalid.default_stop_loss_pct = -1.0;
+        let invalid = RiskConfig {
+            default_stop_loss_pct: -1.0,
+            ..Default::default()
+        };
         assert!(invalid.validate().is_err());
     }
 
+    #[test]
+    fn test_config_validation_symbol_overrides() {
+        assert!(RiskConfig::conservative().validate().is_ok());
+        assert!(RiskConfig::aggressive().validate().is_ok());
+
+        let mut config = RiskConfig::default();
+        config.set_symbol_config(
+            "005930",
+            SymbolRiskConfig {
+                stop_loss_pct: Some(80.0),
+                ..Default::default()
+            },
+        );
+        let err = config.validate().unwrap_err().to_string();
+        assert!(err.contains("005930"));
+
+        let config = RiskConfig {
+            max_concurrent_positions: 0,
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+
+        let config = RiskConfig {
+            max_position_pct: 60.0,
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+    }
+
     #[test]
     fn test_config_serialization() {
         let config = RiskConfig::default();
@@ -0,0 +1,167 @@
+//! 리스크 설정 변경 승인 워크플로우.
+//!
+//! 2인 승인 모드가 켜져 있으면 리스크 설정 변경은 즉시 적용되지 않고
+//! 대기열에 등록되며, 요청자가 아닌 다른 사용자가 승인해야 적용됩니다.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::config::{ConfigValidationError, RiskConfig};
+
+/// 승인 워크플로우 오류.
+#[derive(Debug, Clone, Error)]
+pub enum ApprovalError {
+    #[error("Pending change not found: {0}")]
+    NotFound(Uuid),
+
+    #[error("Change {0} must be approved by someone other than the requester")]
+    SelfApproval(Uuid),
+
+    #[error(transparent)]
+    Invalid(#[from] ConfigValidationError),
+}
+
+/// 승인 대기 중인 리스크 설정 변경.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PendingConfigChange {
+    /// 변경 요청 ID
+    pub id: Uuid,
+    /// 제안된 설정
+    pub proposed: RiskConfig,
+    /// 요청자
+    pub requested_by: String,
+    /// 변경 사유
+    pub reason: Option<String>,
+    /// 요청 시각
+    pub requested_at: DateTime<Utc>,
+}
+
+/// 리스크 설정 변경 승인 대기열.
+#[derive(Debug, Clone, Default)]
+pub struct ConfigApprovalQueue {
+    require_approval: bool,
+    pending: Vec<PendingConfigChange>,
+}
+
+impl ConfigApprovalQueue {
+    /// 새 대기열 생성.
+    pub fn new(require_approval: bool) -> Self {
+        Self {
+            require_approval,
+            pending: Vec::new(),
+        }
+    }
+
+    /// 2인 승인 모드 여부.
+    pub fn requires_approval(&self) -> bool {
+        self.require_approval
+    }
+
+    /// 승인 대기 중인 변경 목록 (요청순).
+    pub fn pending(&self) -> &[PendingConfigChange] {
+        &self.pending
+    }
+
+    /// 변경 요청 등록.
+    ///
+    /// 제안된 설정은 등록 시점에 검증됩니다.
+    pub fn submit(
+        &mut self,
+        proposed: RiskConfig,
+        requested_by: impl Into<String>,
+        reason: Option<String>,
+    ) -> Result<PendingConfigChange, ConfigValidationError> {
+        proposed.validate()?;
+
+        let change = PendingConfigChange {
+            id: Uuid::new_v4(),
+            proposed,
+            requested_by: requested_by.into(),
+            reason,
+            requested_at: Utc::now(),
+        };
+        self.pending.push(change.clone());
+        Ok(change)
+    }
+
+    /// 변경 승인.
+    ///
+    /// 요청자 본인은 승인할 수 없습니다. 승인된 변경은 다시 검증된 뒤
+    /// 대기열에서 제거되어 반환되며, 호출자가 RiskManager에 적용해야 합니다.
+    pub fn approve(
+        &mut self,
+        id: Uuid,
+        approver: &str,
+    ) -> Result<PendingConfigChange, ApprovalError> {
+        let index = self.index_of(id)?;
+        if self.pending[index].requested_by == approver {
+            return Err(ApprovalError::SelfApproval(id));
+        }
+        self.pending[index].proposed.validate()?;
+        Ok(self.pending.remove(index))
+    }
+
+    /// 변경 거절 (요청자 본인의 철회 포함).
+    pub fn reject(&mut self, id: Uuid) -> Result<PendingConfigChange, ApprovalError> {
+        let index = self.index_of(id)?;
+        Ok(self.pending.remove(index))
+    }
+
+    fn index_of(&self, id: Uuid) -> Result<usize, ApprovalError> {
+        self.pending
+            .iter()
+            .position(|c| c.id == id)
+            .ok_or(ApprovalError::NotFound(id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_two_person_approval() {
+        let mut queue = ConfigApprovalQueue::new(true);
+        assert!(queue.requires_approval());
+
+        let proposed = RiskConfig {
+            max_daily_loss_pct: 2.0,
+            ..Default::default()
+        };
+        let change = queue
+            .submit(proposed, "alice", Some("변동성 확대".to_string()))
+            .unwrap();
+        assert_eq!(queue.pending().len(), 1);
+
+        assert!(matches!(
+            queue.approve(change.id, "alice"),
+            Err(ApprovalError::SelfApproval(_))
+        ));
+
+        let approved = queue.approve(change.id, "bob").unwrap();
+        assert_eq!(approved.proposed.max_daily_loss_pct, 2.0);
+        assert!(queue.pending().is_empty());
+        assert!(matches!(
+            queue.approve(change.id, "bob"),
+            Err(ApprovalError::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_invalid_proposal_and_reject() {
+        let mut queue = ConfigApprovalQueue::new(true);
+
+        let invalid = RiskConfig {
+            max_position_pct: 0.0,
+            ..Default::default()
+        };
+        assert!(queue.submit(invalid, "alice", None).is_err());
+        assert!(queue.pending().is_empty());
+
+        let change = queue.submit(RiskConfig::default(), "alice", None).unwrap();
+        assert_eq!(queue.reject(change.id).unwrap().id, change.id);
+        assert!(queue.pending().is_empty());
+    }
+}
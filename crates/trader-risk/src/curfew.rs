@@ -0,0 +1,458 @@
+//! 장중 매매 금지 구간(커퓨).
+//!
+//! 장 시작 직후나 동시호가처럼 호가가 불안정한 시간대에는 신규 진입을 차단하거나
+//! 수량을 축소합니다. 구간은 시장 현지 시각(KRX: KST, 미국: ET) 기준이므로
+//! 미국 서머타임이 자동 반영되며, 청산 주문은 검사하지 않습니다.
+//!
+//! 기본 구간:
+//! - KRX 장 시작 동시호가 (08:30-09:00 KST): 진입 차단
+//! - KRX 장 시작 직후 5분 (09:00-09:05 KST): 진입 수량 50% 축소
+//! - KRX 장 마감 동시호가 (15:20-15:30 KST): 진입 차단
+//! - 미국 정규장 개장 직후 5분 (09:30-09:35 ET): 진입 차단
+//!
+//! 전략별로 [`CurfewOverride`]를 지정해 검사를 제외하거나, 기본 구간 일부를 건너뛰거나,
+//! 전략 전용 구간을 추가할 수 있습니다.
+
+use chrono::{DateTime, NaiveTime, Utc};
+use chrono_tz::America::New_York;
+use chrono_tz::Asia::Seoul;
+use rust_decimal::{Decimal, RoundingStrategy};
+use serde::{Deserialize, Serialize};
+
+use crate::config::ConfigValidationError;
+
+/// 커퓨 구간이 적용되는 시장.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CurfewMarket {
+    /// 국내 주식 (KST 기준)
+    Krx,
+    /// 미국 주식 (ET 기준)
+    Us,
+}
+
+impl CurfewMarket {
+    /// 종목 코드로 시장 판별.
+    ///
+    /// 6자리 숫자는 국내 주식, 영문 심볼(`AAPL`, `BRK.B`)은 미국 주식으로 보며
+    /// 암호화폐(`BTC/USDT`)는 None을 반환합니다.
+    pub fn for_ticker(ticker: &str) -> Option<Self> {
+        if ticker.len() == 6 && ticker.chars().all(|c| c.is_ascii_digit()) {
+            return Some(Self::Krx);
+        }
+        let is_us = ticker
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic())
+            && ticker
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '.' || c == '-');
+        is_us.then_some(Self::Us)
+    }
+
+    /// 시장 현지 시각.
+    pub fn local_time(&self, now: DateTime<Utc>) -> NaiveTime {
+        match self {
+            Self::Krx => now.with_timezone(&Seoul).time(),
+            Self::Us => now.with_timezone(&New_York).time(),
+        }
+    }
+}
+
+/// 커퓨 구간의 진입 주문 처리 방식.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum CurfewAction {
+    /// 신규 진입 차단
+    Block,
+    /// 진입 수량을 비율만큼 축소 (0 < factor <= 1)
+    SizeDown { factor: Decimal },
+}
+
+/// 커퓨 구간 (시장 현지 시각 `[start, end)`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CurfewWindow {
+    /// 구간 이름 (전략별 제외 지정에 사용, 예: `krx_open`)
+    pub name: String,
+    /// 적용 시장
+    pub market: CurfewMarket,
+    /// 시작 시각 (현지, 포함)
+    pub start: NaiveTime,
+    /// 종료 시각 (현지, 미포함)
+    pub end: NaiveTime,
+    /// 진입 주문 처리 방식
+    pub action: CurfewAction,
+}
+
+impl CurfewWindow {
+    /// 새 커퓨 구간 생성.
+    pub fn new(
+        name: impl Into<String>,
+        market: CurfewMarket,
+        start: NaiveTime,
+        end: NaiveTime,
+        action: CurfewAction,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            market,
+            start,
+            end,
+            action,
+        }
+    }
+
+    /// 주어진 시각이 구간에 속하는지 여부.
+    pub fn contains(&self, market: CurfewMarket, now: DateTime<Utc>) -> bool {
+        if self.market != market {
+            return false;
+        }
+        let local = market.local_time(now);
+        self.start <= local && local < self.end
+    }
+
+    /// 구간 설정 검증 (시작 < 종료, 축소 비율 범위).
+    pub fn validate(&self) -> Result<(), ConfigValidationError> {
+        if self.name.trim().is_empty() {
+            return Err(ConfigValidationError::InvalidValue(
+                "curfew window name must not be empty".into(),
+            ));
+        }
+        if self.start >= self.end {
+            return Err(ConfigValidationError::InvalidValue(format!(
+                "curfew window '{}' must start before it ends",
+                self.name
+            )));
+        }
+        if let CurfewAction::SizeDown { factor } = self.action {
+            if factor <= Decimal::ZERO || factor > Decimal::ONE {
+                return Err(ConfigValidationError::InvalidValue(format!(
+                    "curfew window '{}' size-down factor must be between 0 and 1",
+                    self.name
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+fn hm(hour: u32, minute: u32) -> NaiveTime {
+    NaiveTime::from_hms_opt(hour, minute, 0).expect("valid curfew time")
+}
+
+/// 장중 커퓨 설정.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CurfewConfig {
+    /// 검사 활성화 여부 (비활성화 시 모든 주문 통과)
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    /// 모든 전략에 적용되는 구간
+    #[serde(default = "CurfewConfig::default_windows")]
+    pub windows: Vec<CurfewWindow>,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+impl Default for CurfewConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_enabled(),
+            windows: Self::default_windows(),
+        }
+    }
+}
+
+impl CurfewConfig {
+    /// 기본 커퓨 구간 (KRX 동시호가/장 시작 직후, 미국 개장 직후).
+    pub fn default_windows() -> Vec<CurfewWindow> {
+        vec![
+            CurfewWindow::new(
+                "krx_opening_auction",
+                CurfewMarket::Krx,
+                hm(8, 30),
+                hm(9, 0),
+                CurfewAction::Block,
+            ),
+            CurfewWindow::new(
+                "krx_open",
+                CurfewMarket::Krx,
+                hm(9, 0),
+                hm(9, 5),
+                CurfewAction::SizeDown {
+                    factor: Decimal::new(5, 1),
+                },
+            ),
+            CurfewWindow::new(
+                "krx_closing_auction",
+                CurfewMarket::Krx,
+                hm(15, 20),
+                hm(15, 30),
+                CurfewAction::Block,
+            ),
+            CurfewWindow::new(
+                "us_open_auction",
+                CurfewMarket::Us,
+                hm(9, 30),
+                hm(9, 35),
+                CurfewAction::Block,
+            ),
+        ]
+    }
+
+    /// 설정 검증.
+    pub fn validate(&self) -> Result<(), ConfigValidationError> {
+        self.windows.iter().try_for_each(CurfewWindow::validate)
+    }
+
+    /// 전략 오버라이드를 반영해 주어진 시각에 적용되는 구간 목록.
+    pub fn active_windows<'a>(
+        &'a self,
+        ticker: &str,
+        now: DateTime<Utc>,
+        strategy_override: Option<&'a CurfewOverride>,
+    ) -> Vec<&'a CurfewWindow> {
+        let Some(market) = CurfewMarket::for_ticker(ticker) else {
+            return Vec::new();
+        };
+        if strategy_override.is_some_and(|o| o.exempt) {
+            return Vec::new();
+        }
+
+        let skipped = |window: &CurfewWindow| {
+            strategy_override.is_some_and(|o| o.skip_windows.contains(&window.name))
+        };
+        self.windows
+            .iter()
+            .filter(|w| !skipped(w))
+            .chain(strategy_override.into_iter().flat_map(|o| &o.extra_windows))
+            .filter(|w| w.contains(market, now))
+            .collect()
+    }
+}
+
+/// 전략별 커퓨 오버라이드.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CurfewOverride {
+    /// 커퓨 검사 제외 (전체 구간)
+    #[serde(default)]
+    pub exempt: bool,
+    /// 적용하지 않을 기본 구간 이름
+    #[serde(default)]
+    pub skip_windows: Vec<String>,
+    /// 전략 전용 추가 구간
+    #[serde(default)]
+    pub extra_windows: Vec<CurfewWindow>,
+}
+
+impl CurfewOverride {
+    /// 오버라이드 검증 (추가 구간 설정).
+    pub fn validate(&self) -> Result<(), ConfigValidationError> {
+        self.extra_windows
+            .iter()
+            .try_for_each(CurfewWindow::validate)
+    }
+}
+
+/// 커퓨 검사 결과.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CurfewCheck {
+    /// 검사 대상 아님 (구간 밖, 청산 주문, 비활성화)
+    NotApplicable,
+    /// 구간 내 진입 수량 축소 후 허용
+    Scaled { quantity: Decimal, window: String },
+    /// 구간 내 진입 차단
+    Blocked(String),
+}
+
+/// 진입 주문 커퓨 검사.
+///
+/// 여러 구간이 겹치면 차단이 우선하고, 축소 구간끼리는 가장 작은 비율을 적용합니다.
+///
+/// # Arguments
+///
+/// * `ticker` - 종목 코드
+/// * `quantity` - 주문 수량
+/// * `is_entry` - 진입 주문 여부 (청산 주문은 검사하지 않음)
+/// * `now` - 검사 시각
+/// * `config` - 커퓨 설정
+/// * `strategy_override` - 전략별 오버라이드
+pub fn check_curfew(
+    ticker: &str,
+    quantity: Decimal,
+    is_entry: bool,
+    now: DateTime<Utc>,
+    config: &CurfewConfig,
+    strategy_override: Option<&CurfewOverride>,
+) -> CurfewCheck {
+    if !config.enabled || !is_entry {
+        return CurfewCheck::NotApplicable;
+    }
+
+    let windows = config.active_windows(ticker, now, strategy_override);
+    if let Some(window) = windows.iter().find(|w| w.action == CurfewAction::Block) {
+        return CurfewCheck::Blocked(format!(
+            "New entries are blocked during curfew window '{}' ({}-{})",
+            window.name,
+            window.start.format("%H:%M"),
+            window.end.format("%H:%M")
+        ));
+    }
+
+    let Some((window, factor)) = windows
+        .iter()
+        .filter_map(|w| match w.action {
+            CurfewAction::SizeDown { factor } => Some((w, factor)),
+            CurfewAction::Block => None,
+        })
+        .min_by(|a, b| a.1.cmp(&b.1))
+    else {
+        return CurfewCheck::NotApplicable;
+    };
+
+    let scaled = (quantity * factor)
+        .round_dp_with_strategy(0, RoundingStrategy::ToZero)
+        .normalize();
+    if scaled <= Decimal::ZERO {
+        return CurfewCheck::Blocked(format!(
+            "Order quantity {} is too small for curfew window '{}' size-down",
+            quantity, window.name
+        ));
+    }
+    if scaled < quantity {
+        CurfewCheck::Scaled {
+            quantity: scaled,
+            window: window.name.clone(),
+        }
+    } else {
+        CurfewCheck::NotApplicable
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use rust_decimal_macros::dec;
+
+    /// KST 시각 (2024-06-03 월요일).
+    fn kst(hour: u32, minute: u32) -> DateTime<Utc> {
+        Seoul
+            .with_ymd_and_hms(2024, 6, 3, hour, minute, 0)
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    /// ET 시각 (2024-06-03 월요일, 서머타임).
+    fn et(hour: u32, minute: u32) -> DateTime<Utc> {
+        New_York
+            .with_ymd_and_hms(2024, 6, 3, hour, minute, 0)
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    #[test]
+    fn test_default_windows() {
+        let config = CurfewConfig::default();
+        assert!(config.validate().is_ok());
+
+        // 장 마감 동시호가는 진입 차단, 청산은 통과
+        assert!(matches!(
+            check_curfew("005930", dec!(10), true, kst(15, 25), &config, None),
+            CurfewCheck::Blocked(_)
+        ));
+        assert_eq!(
+            check_curfew("005930", dec!(10), false, kst(15, 25), &config, None),
+            CurfewCheck::NotApplicable
+        );
+
+        // 장 시작 직후 5분은 수량 50% 축소 (9:05부터 정상)
+        assert_eq!(
+            check_curfew("005930", dec!(11), true, kst(9, 2), &config, None),
+            CurfewCheck::Scaled {
+                quantity: dec!(5),
+                window: "krx_open".to_string()
+            }
+        );
+        assert_eq!(
+            check_curfew("005930", dec!(11), true, kst(9, 5), &config, None),
+            CurfewCheck::NotApplicable
+        );
+
+        // 미국 개장 직후 (ET 기준), 같은 시각 국내 종목과 암호화폐는 대상 아님
+        assert!(matches!(
+            check_curfew("AAPL", dec!(10), true, et(9, 31), &config, None),
+            CurfewCheck::Blocked(_)
+        ));
+        assert_eq!(
+            check_curfew("005930", dec!(10), true, et(9, 31), &config, None),
+            CurfewCheck::NotApplicable
+        );
+        assert_eq!(
+            check_curfew("BTC/USDT", dec!(1), true, kst(15, 25), &config, None),
+            CurfewCheck::NotApplicable
+        );
+
+        // 축소 후 1주 미만이면 차단
+        assert!(matches!(
+            check_curfew("005930", dec!(1), true, kst(9, 0), &config, None),
+            CurfewCheck::Blocked(_)
+        ));
+    }
+
+    #[test]
+    fn test_strategy_override() {
+        let config = CurfewConfig::default();
+
+        let exempt = CurfewOverride {
+            exempt: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            check_curfew(
+                "005930",
+                dec!(10),
+                true,
+                kst(15, 25),
+                &config,
+                Some(&exempt)
+            ),
+            CurfewCheck::NotApplicable
+        );
+
+        // 장 시작 축소 구간만 건너뛰고, 전략 전용 구간(14:50-15:20 차단) 추가
+        let custom = CurfewOverride {
+            exempt: false,
+            skip_windows: vec!["krx_open".to_string()],
+            extra_windows: vec![CurfewWindow::new(
+                "pre_close",
+                CurfewMarket::Krx,
+                hm(14, 50),
+                hm(15, 20),
+                CurfewAction::Block,
+            )],
+        };
+        assert!(custom.validate().is_ok());
+        assert_eq!(
+            check_curfew("005930", dec!(10), true, kst(9, 1), &config, Some(&custom)),
+            CurfewCheck::NotApplicable
+        );
+        assert!(matches!(
+            check_curfew("005930", dec!(10), true, kst(15, 0), &config, Some(&custom)),
+            CurfewCheck::Blocked(_)
+        ));
+
+        let invalid = CurfewOverride {
+            extra_windows: vec![CurfewWindow::new(
+                "bad",
+                CurfewMarket::Us,
+                hm(10, 0),
+                hm(9, 0),
+                CurfewAction::SizeDown { factor: dec!(1.5) },
+            )],
+            ..Default::default()
+        };
+        assert!(invalid.validate().is_err());
+    }
+}
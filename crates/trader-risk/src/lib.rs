@@ -6,6 +6,9 @@
 //! - Stop-loss/Take-profit 관리
 //! - 일일 손실 한도
 //! - 변동성 필터
+//! - 장중 매매 금지 구간 (커퓨)
+//! - 전략별 자본 예산 (자본 원장)
+//! - 리스크 설정 변경 승인 (2인 승인)
 //!
 //! # 예제
 //!
@@ -22,7 +25,10 @@
 //! }
 //! ```
 
+pub mod capital;
 pub mod config;
+pub mod config_approval;
+pub mod curfew;
 pub mod limits;
 pub mod manager;
 pub mod position_sizing;
@@ -30,9 +36,18 @@ pub mod stop_loss;
 pub mod trailing_stop;
 
 // 주요 타입 재내보내기
+pub use capital::{
+    BudgetPolicy, CapitalCheck, CapitalError, CapitalLedger, CapitalSummary, CapitalTransfer,
+    CapitalTransferKind, StrategyCapital,
+};
 pub use config::{ConfigValidationError, RiskConfig, SymbolRiskConfig};
+pub use config_approval::{ApprovalError, ConfigApprovalQueue, PendingConfigChange};
+pub use curfew::{
+    check_curfew, CurfewAction, CurfewCheck, CurfewConfig, CurfewMarket, CurfewOverride,
+    CurfewWindow,
+};
 pub use limits::{DailyLimitStatus, DailyLossTracker, PnLRecord, RiskLimits};
-pub use manager::{RiskManager, RiskValidation};
+pub use manager::{EtfPremiumData, RiskManager, RiskValidation};
 pub use position_sizing::{PositionSizer, SizingValidation};
 pub use stop_loss::{StopOrder, StopOrderGenerator, StopType, TrailingStopState};
 pub use trailing_stop::{
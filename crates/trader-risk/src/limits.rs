@@ -529,6 +529,26 @@ impl DailyLossTracker {
         self.starting_balance = balance;
     }
 
+    /// 일일 손실 한도 비율 변경.
+    ///
+    /// 오늘의 손익 기록은 유지하고 절대값 한도를 현재 시작 잔액 기준으로 재계산합니다.
+    /// 현재 손실이 새 한도에 이미 도달했으면 즉시 거래를 중지하며,
+    /// 한도 완화로 중지가 자동 해제되지는 않습니다 (`override_pause` 사용).
+    pub fn update_max_daily_loss_pct(&mut self, max_daily_loss_pct: f64) {
+        self.check_and_reset();
+
+        self.max_daily_loss_pct = max_daily_loss_pct;
+        self.max_daily_loss = Decimal::from_f64_retain(
+            max_daily_loss_pct * self.starting_balance.to_f64().unwrap_or(0.0) / 100.0,
+        )
+        .unwrap_or(Decimal::ZERO);
+
+        if self.daily_total < Decimal::ZERO && self.daily_total.abs() >= self.get_effective_limit()
+        {
+            self.trading_paused = true;
+        }
+    }
+
     /// 거래 일시 중지 재정의 (관리자 기능).
     pub fn override_pause(&mut self, allow_trading: bool) {
         self.trading_paused = !allow_trading;
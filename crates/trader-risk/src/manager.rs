@@ -5,14 +5,16 @@
 //! - 일일 손실 한도 추적
 //! - Stop-loss/Take-profit 주문 생성
 //! - 변동성 필터링
+//! - 전략별 자본 예산 검사
 
-use crate::config::RiskConfig;
+use crate::capital::{CapitalCheck, CapitalLedger};
+use crate::config::{ConfigValidationError, RiskConfig};
 use crate::limits::DailyLossTracker;
 use crate::position_sizing::PositionSizer;
 use crate::stop_loss::{StopOrder, StopOrderGenerator, TrailingStopState};
 use rust_decimal::Decimal;
 use std::collections::HashMap;
-use trader_core::{OrderRequest, Position, TraderResult};
+use trader_core::{OrderRequest, Position, Side, TraderResult};
 
 /// 리스크 검증 결과.
 #[derive(Debug, Clone)]
@@ -68,6 +70,17 @@ pub struct VolatilityData {
     pub last_updated: chrono::DateTime<chrono::Utc>,
 }
 
+/// ETF의 NAV 대비 괴리율 데이터.
+#[derive(Debug, Clone)]
+pub struct EtfPremiumData {
+    /// 괴리율 (%, 양수 = 프리미엄)
+    pub premium_pct: f64,
+    /// 레버리지/인버스 ETF 여부
+    pub leveraged: bool,
+    /// 마지막 업데이트 타임스탬프
+    pub last_updated: chrono::DateTime<chrono::Utc>,
+}
+
 /// 주문 검증 및 리스크 관리를 위한 리스크 매니저.
 pub struct RiskManager {
     /// 리스크 설정
@@ -82,8 +95,12 @@ pub struct RiskManager {
     balance: Decimal,
     /// 심볼별 변동성 데이터
     volatility_data: HashMap<String, VolatilityData>,
+    /// ETF별 괴리율 데이터
+    etf_premiums: HashMap<String, EtfPremiumData>,
     /// 활성 Trailing Stop (position_id -> state)
     trailing_stops: HashMap<String, TrailingStopState>,
+    /// 전략별 자본 원장
+    capital_ledger: CapitalLedger,
 }
 
 impl RiskManager {
@@ -100,7 +117,9 @@ impl RiskManager {
             stop_generator,
             balance: starting_balance,
             volatility_data: HashMap::new(),
+            etf_premiums: HashMap::new(),
             trailing_stops: HashMap::new(),
+            capital_ledger: CapitalLedger::default(),
         }
     }
 
@@ -125,6 +144,22 @@ impl RiskManager {
         &self.config
     }
 
+    /// 리스크 설정 교체 (즉시 적용).
+    ///
+    /// 검증에 실패하면 기존 설정을 유지합니다. 일일 손익 기록, 변동성 데이터,
+    /// Trailing Stop 및 자본 원장은 그대로 유지됩니다.
+    pub fn update_config(&mut self, config: RiskConfig) -> Result<(), ConfigValidationError> {
+        config.validate()?;
+
+        self.position_sizer = PositionSizer::new(config.clone());
+        self.stop_generator = StopOrderGenerator::new(config.clone());
+        self.daily_tracker
+            .update_max_daily_loss_pct(config.max_daily_loss_pct);
+        self.config = config;
+
+        Ok(())
+    }
+
     // ==================== Order Validation ====================
 
     /// 모든 리스크 한도에 대해 주문 검증.
@@ -160,6 +195,22 @@ impl RiskManager {
             )));
         }
 
+        // 사용자 정의 지수(합성 일봉)는 신호/벤치마크 전용이라 주문할 수 없음
+        if symbol.starts_with(trader_core::CUSTOM_INDEX_TICKER_PREFIX) {
+            return Ok(RiskValidation::invalid(format!(
+                "Custom index is not tradable: {}",
+                symbol
+            )));
+        }
+
+        // 합성 종목(스프레드/비율)은 leg 주문으로 분해해서 실행해야 함
+        if trader_core::is_synthetic_ticker(&symbol) {
+            return Ok(RiskValidation::invalid(format!(
+                "Synthetic instrument is not tradable; decompose into legs: {}",
+                symbol
+            )));
+        }
+
         // Check 3: Volatility filter
         if let Some(volatility) = self.volatility_data.get(&symbol) {
             if volatility.current_volatility > self.config.volatility_threshold {
@@ -178,7 +229,24 @@ impl RiskManager {
             }
         }
 
-        // Check 4: Position sizing limits
+        // Check 4: ETF premium filter (매수만)
+        if order.side == Side::Buy {
+            if let Some(etf) = self.etf_premiums.get(&symbol) {
+                let max_premium = self.config.get_max_etf_premium_pct(etf.leveraged);
+                if etf.premium_pct > max_premium {
+                    return Ok(RiskValidation::invalid(format!(
+                        "ETF premium too high: {:.2}% exceeds limit {:.2}%",
+                        etf.premium_pct, max_premium
+                    )));
+                }
+
+                if etf.premium_pct > max_premium * 0.7 {
+                    warnings.push(format!("Elevated ETF premium: {:.2}%", etf.premium_pct));
+                }
+            }
+        }
+
+        // Check 5: Position sizing limits
         let sizing_result =
             self.position_sizer
                 .validate_order(order, positions, self.balance, current_price);
@@ -204,7 +272,7 @@ impl RiskManager {
             return Ok(validation);
         }
 
-        // Check 5: Daily limit status warning
+        // Check 6: Daily limit status warning
         let daily_status = self.daily_tracker.get_status();
         if let Some(warning) = daily_status.warning {
             warnings.push(warning);
@@ -224,6 +292,49 @@ impl RiskManager {
         self.daily_tracker.can_trade()
     }
 
+    // ==================== Strategy Capital ====================
+
+    /// 전략 가용 예산 기준으로 진입 주문 검사.
+    ///
+    /// 전략 ID가 없거나 원장에 등록되지 않은 전략의 주문은 `Unmanaged`를 반환합니다.
+    /// 지정가 주문은 지정가, 시장가 주문은 현재가를 기준으로 필요 자본을 계산합니다.
+    pub fn check_strategy_capital(
+        &self,
+        order: &OrderRequest,
+        current_price: Decimal,
+    ) -> CapitalCheck {
+        self.check_strategy_capital_at(order, order.price.unwrap_or(current_price))
+    }
+
+    /// 단위 수량당 필요 자본을 직접 지정해 진입 주문 검사.
+    ///
+    /// 선물처럼 명목금액 대신 계약당 위탁증거금만 묶이는 상품에 사용합니다.
+    pub fn check_strategy_capital_at(
+        &self,
+        order: &OrderRequest,
+        unit_cost: Decimal,
+    ) -> CapitalCheck {
+        match order.strategy_id.as_deref() {
+            Some(strategy_id) => self.capital_ledger.check_order(
+                strategy_id,
+                order.quantity,
+                unit_cost,
+                self.config.min_order_size,
+            ),
+            None => CapitalCheck::Unmanaged,
+        }
+    }
+
+    /// 자본 원장 참조 조회.
+    pub fn capital_ledger(&self) -> &CapitalLedger {
+        &self.capital_ledger
+    }
+
+    /// 자본 원장 가변 참조 조회.
+    pub fn capital_ledger_mut(&mut self) -> &mut CapitalLedger {
+        &mut self.capital_ledger
+    }
+
     // ==================== Daily Loss Tracking ====================
 
     /// 수익 또는 손실 기록.
@@ -366,6 +477,25 @@ impl RiskManager {
         self.volatility_data.get(symbol)
     }
 
+    // ==================== ETF Premium ====================
+
+    /// ETF의 NAV 대비 괴리율 업데이트.
+    pub fn update_etf_premium(&mut self, symbol: &str, premium_pct: f64, leveraged: bool) {
+        self.etf_premiums.insert(
+            symbol.to_string(),
+            EtfPremiumData {
+                premium_pct,
+                leveraged,
+                last_updated: chrono::Utc::now(),
+            },
+        );
+    }
+
+    /// ETF의 괴리율 데이터 조회.
+    pub fn get_etf_premium(&self, symbol: &str) -> Option<&EtfPremiumData> {
+        self.etf_premiums.get(symbol)
+    }
+
     // ==================== Position Sizing ====================
 
     /// 심볼의 최대 포지션 크기 계산.
@@ -504,6 +634,46 @@ mod tests {
         assert!(manager.is_daily_limit_reached());
     }
 
+    #[test]
+    fn test_update_config_applies_immediately() {
+        let mut manager = RiskManager::new(RiskConfig::default(), dec!(10000));
+        let order = OrderRequest::market_buy("BTC/USDT".to_string(), dec!(0.01));
+        assert!(
+            manager
+                .validate_order(&order, &[], dec!(50000))
+                .unwrap()
+                .is_valid
+        );
+
+        let invalid = RiskConfig {
+            max_daily_loss_pct: 0.0,
+            ..Default::default()
+        };
+        assert!(manager.update_config(invalid).is_err());
+        assert_eq!(manager.config().max_daily_loss_pct, 3.0);
+
+        // 포지션 한도 즉시 반영 ($500 주문 > 1% of $10000)
+        let mut tightened = RiskConfig {
+            max_position_pct: 1.0,
+            ..Default::default()
+        };
+        manager.update_config(tightened.clone()).unwrap();
+        assert!(
+            !manager
+                .validate_order(&order, &[], dec!(50000))
+                .unwrap()
+                .is_valid
+        );
+
+        // 일일 손실 한도를 1.5%($150)로 강화하면 기존 손실 $200으로 즉시 중지
+        manager.record_pnl("BTC/USDT", dec!(-200));
+        assert!(manager.can_trade());
+        tightened.max_daily_loss_pct = 1.5;
+        manager.update_config(tightened).unwrap();
+        assert!(!manager.can_trade());
+        assert_eq!(manager.daily_pnl(), dec!(-200));
+    }
+
     #[test]
     fn test_generate_bracket_orders() {
         let config = RiskConfig::default();
@@ -522,8 +692,10 @@ mod tests {
 
     #[test]
     fn test_volatility_filter() {
-        let mut config = RiskConfig::default();
-        config.volatility_threshold = 5.0; // 5% threshold
+        let config = RiskConfig {
+            volatility_threshold: 5.0, // 5% threshold
+            ..Default::default()
+        };
         let mut manager = RiskManager::new(config, dec!(10000));
 
         // No volatility data = allowed
@@ -540,8 +712,10 @@ mod tests {
 
     #[test]
     fn test_validate_order_high_volatility() {
-        let mut config = RiskConfig::default();
-        config.volatility_threshold = 5.0;
+        let config = RiskConfig {
+            volatility_threshold: 5.0,
+            ..Default::default()
+        };
         let mut manager = RiskManager::new(config, dec!(10000));
 
         // Set high volatility
@@ -556,6 +730,48 @@ mod tests {
         assert!(result.messages[0].contains("volatility"));
     }
 
+    #[test]
+    fn test_validate_order_etf_premium() {
+        let config = RiskConfig::default();
+        let mut manager = RiskManager::new(config, dec!(10000));
+
+        // 레버리지 ETF: 1% 한도 초과 시 매수 거부
+        manager.update_etf_premium("122630", 1.5, true);
+        let buy = OrderRequest::market_buy("122630".to_string(), dec!(1));
+        let result = manager.validate_order(&buy, &[], dec!(100)).unwrap();
+        assert!(!result.is_valid);
+        assert!(result.messages[0].contains("ETF premium"));
+
+        // 매도는 괴리율과 무관
+        let sell = OrderRequest::market_sell("122630".to_string(), dec!(1));
+        let result = manager.validate_order(&sell, &[], dec!(100)).unwrap();
+        assert!(result.is_valid);
+
+        // 일반 ETF: 3% 한도 이내면 경고만
+        manager.update_etf_premium("069500", 2.5, false);
+        let buy = OrderRequest::market_buy("069500".to_string(), dec!(1));
+        let result = manager.validate_order(&buy, &[], dec!(100)).unwrap();
+        assert!(result.is_valid);
+        assert!(result.messages[0].contains("Elevated ETF premium"));
+    }
+
+    #[test]
+    fn test_validate_order_rejects_custom_index() {
+        let mut manager = RiskManager::new(RiskConfig::default(), dec!(10000));
+
+        let order = OrderRequest::market_buy("IDX_SEMI_KR".to_string(), dec!(1));
+        let result = manager.validate_order(&order, &[], dec!(1000)).unwrap();
+
+        assert!(!result.is_valid);
+        assert!(result.messages[0].contains("not tradable"));
+
+        let order = OrderRequest::market_buy("SYN_SEC_HYNIX".to_string(), dec!(1));
+        let result = manager.validate_order(&order, &[], dec!(1000)).unwrap();
+
+        assert!(!result.is_valid);
+        assert!(result.messages[0].contains("not tradable"));
+    }
+
     #[test]
     fn test_trailing_stop_management() {
         let config = RiskConfig::default();
@@ -628,6 +844,34 @@ mod tests {
         assert_eq!(suggested.quantity, dec!(0.02));
     }
 
+    #[test]
+    fn test_check_strategy_capital() {
+        let mut manager = RiskManager::new(RiskConfig::default(), dec!(100000));
+        manager
+            .capital_ledger_mut()
+            .allocate("rsi", dec!(1000))
+            .unwrap();
+
+        let mut order = OrderRequest::market_buy("005930".to_string(), dec!(3));
+        assert_eq!(
+            manager.check_strategy_capital(&order, dec!(500)),
+            CapitalCheck::Unmanaged
+        );
+
+        order.strategy_id = Some("rsi".to_string());
+        assert_eq!(
+            manager.check_strategy_capital(&order, dec!(500)),
+            CapitalCheck::Scaled {
+                quantity: dec!(2),
+                available: dec!(1000),
+            }
+        );
+        assert_eq!(
+            manager.check_strategy_capital(&order, dec!(300)),
+            CapitalCheck::Approved
+        );
+    }
+
     #[test]
     fn test_daily_reset() {
         let config = RiskConfig::default();
@@ -4,12 +4,13 @@
 //! - 계좌 잔고 기반 최대 허용 포지션 크기 계산
 //! - 리스크 한도 대비 주문 크기 검증
 //! - 다양한 방법(고정 비율, Kelly)을 사용한 최적 포지션 크기 계산
+//! - 보정된 신호 적중 확률 기반 Kelly 사이징
 
 use crate::config::RiskConfig;
 use crate::manager::RiskValidation;
 use rust_decimal::prelude::ToPrimitive;
 use rust_decimal::Decimal;
-use trader_core::{OrderRequest, Position};
+use trader_core::{OrderRequest, Position, Signal, SignalCalibrator};
 
 /// 정밀도를 위해 정수 연산을 사용하여 퍼센트를 금액으로 변환.
 /// 예시: pct_to_amount(1000, 10.0) = 100 (1000의 10%)
@@ -315,6 +316,32 @@ impl PositionSizer {
         kelly_size.min(max_size)
     }
 
+    /// 보정된 신호 적중 확률을 승률로 사용하여 Kelly 포지션 크기를 계산.
+    ///
+    /// 신호의 원시 `strength` 대신 `calibrator`가 전략별 과거 적중률로 변환한
+    /// 확률을 사용합니다. 보정 모델이 없는 전략은 원시 강도를 그대로 사용합니다.
+    ///
+    /// # 인자
+    /// * `balance` - 총 계좌 잔고
+    /// * `signal` - 진입 신호
+    /// * `calibrator` - 전략별 신뢰도 보정기
+    /// * `avg_win` - 평균 수익 거래 금액
+    /// * `avg_loss` - 평균 손실 거래 금액
+    ///
+    /// # 반환값
+    /// 기준 통화로 된 권장 포지션 크기 (최대 허용량으로 제한)
+    pub fn calculate_calibrated_kelly(
+        &self,
+        balance: Decimal,
+        signal: &Signal,
+        calibrator: &SignalCalibrator,
+        avg_win: Decimal,
+        avg_loss: Decimal,
+    ) -> Decimal {
+        let win_rate = calibrator.calibrate(signal);
+        self.calculate_kelly(balance, win_rate, avg_win, avg_loss, &signal.ticker)
+    }
+
     /// 한도 내에 맞는 조정된 주문 크기를 제안.
     ///
     /// # 인자
@@ -482,8 +509,10 @@ mod tests {
 
     #[test]
     fn test_validate_max_concurrent_positions() {
-        let mut config = RiskConfig::default();
-        config.max_concurrent_positions = 2;
+        let config = RiskConfig {
+            max_concurrent_positions: 2,
+            ..Default::default()
+        };
         let sizer = PositionSizer::new(config);
 
         let btc_symbol = Symbol::crypto("BTC", "USDT");
@@ -523,6 +552,53 @@ mod tests {
         assert_eq!(qty, dec!(0.1));
     }
 
+    #[test]
+    fn test_calibrated_kelly_sizing() {
+        use trader_core::{CalibrationMethod, CalibrationSample, SignalType};
+
+        let sizer = PositionSizer::new(RiskConfig::default());
+        let balance = dec!(100000);
+
+        // 강도 0.9 신호의 실제 적중률 40%, 강도 0.3 신호는 20%
+        let samples = (0..40).flat_map(|i| {
+            [
+                ("rsi".to_string(), CalibrationSample::new(0.9, i < 16)),
+                ("rsi".to_string(), CalibrationSample::new(0.3, i < 8)),
+            ]
+        });
+        let calibrator = SignalCalibrator::fit(CalibrationMethod::Isotonic, samples);
+
+        let signal = |strategy_id: &str| {
+            Signal::new(
+                strategy_id,
+                "BTC/USDT".to_string(),
+                Side::Buy,
+                SignalType::Entry,
+            )
+            .with_strength(0.9)
+        };
+
+        // 보정 확률 0.4, 손익비 2 → Kelly 0.1, half-Kelly 5%
+        let size = sizer.calculate_calibrated_kelly(
+            balance,
+            &signal("rsi"),
+            &calibrator,
+            dec!(200),
+            dec!(100),
+        );
+        assert_eq!(size, dec!(5000));
+
+        // 보정 모델이 없는 전략은 원시 강도 사용 → 최대 허용 크기(10%)로 제한
+        let size = sizer.calculate_calibrated_kelly(
+            balance,
+            &signal("grid"),
+            &calibrator,
+            dec!(200),
+            dec!(100),
+        );
+        assert_eq!(size, dec!(10000));
+    }
+
     #[test]
     fn test_suggest_adjusted_size() {
         let config = RiskConfig::default();
@@ -0,0 +1,435 @@
+//! 전략 경쟁 (모의투자 리더보드).
+//!
+//! 여러 전략 인스턴스를 같은 실시간 시장 데이터로 모의 운용하고,
+//! 참가자별 가상 계좌 평가액 시계열로 위험 조정 수익률 순위를 계산합니다.
+//!
+//! 참가자 인스턴스는 섀도 인스턴스와 마찬가지로 신호를 출력 채널로 보내지 않으며,
+//! 신호가 발생한 시점의 시장 가격으로 가상 계좌에서 즉시 체결됩니다.
+//!
+//! # 체결 규칙
+//!
+//! - `Entry`/`AddToPosition` 매수: 평가액 × `position_size_pct` × 신호 강도만큼 매수
+//! - `Exit` 또는 매도 `Entry`: 보유 수량 전량 매도 (공매도 없음)
+//! - `ReducePosition`: 부분 청산 지시(`scale_out`) 수량 매도, 지시가 없으면 보유 수량의 절반 매도
+//! - 체결마다 `fee_bps` 만큼 수수료 차감
+
+use chrono::{DateTime, Utc};
+use rust_decimal::prelude::*;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use trader_core::{Side, Signal, SignalType};
+
+/// 연환산 기준 일수 (24시간 시장 포함).
+const DAYS_PER_YEAR: f64 = 365.25;
+
+/// 위험 조정 수익률 계산에 필요한 최소 평가액 표본 수.
+pub const MIN_LEADERBOARD_SAMPLES: usize = 3;
+
+/// 가상 계좌 설정.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaperBookConfig {
+    /// 초기 자본
+    pub initial_capital: Decimal,
+    /// 진입 1회당 평가액 대비 투자 비율 (0 ~ 1)
+    #[serde(default = "default_position_size_pct")]
+    pub position_size_pct: Decimal,
+    /// 체결 수수료 (bp)
+    #[serde(default = "default_fee_bps")]
+    pub fee_bps: Decimal,
+}
+
+fn default_position_size_pct() -> Decimal {
+    Decimal::new(2, 1)
+}
+
+fn default_fee_bps() -> Decimal {
+    Decimal::from(5)
+}
+
+impl Default for PaperBookConfig {
+    fn default() -> Self {
+        Self {
+            initial_capital: Decimal::from(10_000_000),
+            position_size_pct: default_position_size_pct(),
+            fee_bps: default_fee_bps(),
+        }
+    }
+}
+
+/// 가상 계좌 보유 종목.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaperPosition {
+    /// 보유 수량
+    pub quantity: Decimal,
+    /// 평균 매입가
+    pub avg_price: Decimal,
+    /// 최근 시장 가격
+    pub last_price: Decimal,
+}
+
+/// 참가자 가상 계좌.
+#[derive(Debug, Clone)]
+pub struct PaperBook {
+    config: PaperBookConfig,
+    cash: Decimal,
+    positions: HashMap<String, PaperPosition>,
+    trades: u64,
+    last_data_at: Option<DateTime<Utc>>,
+}
+
+impl PaperBook {
+    /// 초기 자본으로 새 계좌 생성.
+    pub fn new(config: PaperBookConfig) -> Self {
+        Self {
+            cash: config.initial_capital,
+            config,
+            positions: HashMap::new(),
+            trades: 0,
+            last_data_at: None,
+        }
+    }
+
+    /// 현금 잔고.
+    pub fn cash(&self) -> Decimal {
+        self.cash
+    }
+
+    /// 체결 횟수.
+    pub fn trades(&self) -> u64 {
+        self.trades
+    }
+
+    /// 보유 종목.
+    pub fn positions(&self) -> &HashMap<String, PaperPosition> {
+        &self.positions
+    }
+
+    /// 현재 평가액 (현금 + 보유 종목 시가 평가).
+    pub fn equity(&self) -> Decimal {
+        self.cash
+            + self
+                .positions
+                .values()
+                .map(|p| p.quantity * p.last_price)
+                .sum::<Decimal>()
+    }
+
+    /// 시장 가격 반영 및 신호 체결.
+    ///
+    /// # Arguments
+    ///
+    /// * `ticker` - 시장 데이터 종목
+    /// * `price` - 시장 데이터의 현재 가격 (없으면 평가만 생략)
+    /// * `signals` - 참가자 인스턴스가 생성한 신호
+    /// * `at` - 시장 데이터 시각
+    pub fn apply(
+        &mut self,
+        ticker: &str,
+        price: Option<Decimal>,
+        signals: &[Signal],
+        at: DateTime<Utc>,
+    ) {
+        self.last_data_at = Some(at);
+        if let Some(price) = price.filter(|p| *p > Decimal::ZERO) {
+            if let Some(position) = self.positions.get_mut(ticker) {
+                position.last_price = price;
+            }
+        }
+
+        for signal in signals {
+            // 다른 종목 신호는 제안가가 있을 때만 체결
+            let fill_price = if signal.ticker == ticker {
+                price.or(signal.suggested_price)
+            } else {
+                signal.suggested_price
+            };
+            let Some(fill_price) = fill_price.filter(|p| *p > Decimal::ZERO) else {
+                continue;
+            };
+            self.fill(signal, fill_price);
+        }
+    }
+
+    /// 마지막으로 반영한 시장 데이터 시각.
+    pub fn last_data_at(&self) -> Option<DateTime<Utc>> {
+        self.last_data_at
+    }
+
+    fn fill(&mut self, signal: &Signal, price: Decimal) {
+        let held = self
+            .positions
+            .get(&signal.ticker)
+            .map(|p| p.quantity)
+            .unwrap_or(Decimal::ZERO);
+
+        match (signal.signal_type, signal.side) {
+            (SignalType::Entry | SignalType::AddToPosition | SignalType::Scale, Side::Buy) => {
+                let strength = Decimal::from_f64(signal.strength.clamp(0.0, 1.0))
+                    .filter(|s| *s > Decimal::ZERO)
+                    .unwrap_or(Decimal::ONE);
+                let budget =
+                    (self.equity() * self.config.position_size_pct * strength).min(self.cash);
+                let quantity = budget / (price * (Decimal::ONE + self.fee_rate()));
+                if quantity > Decimal::ZERO {
+                    self.buy(&signal.ticker, quantity, price);
+                }
+            }
+            (SignalType::Exit, _) | (SignalType::Entry | SignalType::Scale, Side::Sell)
+                if held > Decimal::ZERO =>
+            {
+                self.sell(&signal.ticker, held, price);
+            }
+            (SignalType::ReducePosition, _) => {
+                let quantity = match signal.scale_out {
+                    Some(scale_out) => scale_out.close_quantity(held, price, self.equity()),
+                    None => held / Decimal::TWO,
+                };
+                if quantity > Decimal::ZERO {
+                    self.sell(&signal.ticker, quantity, price);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn fee_rate(&self) -> Decimal {
+        self.config.fee_bps / Decimal::from(10_000)
+    }
+
+    fn buy(&mut self, ticker: &str, quantity: Decimal, price: Decimal) {
+        let cost = quantity * price;
+        self.cash -= cost + cost * self.fee_rate();
+        self.trades += 1;
+
+        let position = self
+            .positions
+            .entry(ticker.to_string())
+            .or_insert(PaperPosition {
+                quantity: Decimal::ZERO,
+                avg_price: price,
+                last_price: price,
+            });
+        let total = position.quantity + quantity;
+        position.avg_price = (position.avg_price * position.quantity + price * quantity) / total;
+        position.quantity = total;
+        position.last_price = price;
+    }
+
+    fn sell(&mut self, ticker: &str, quantity: Decimal, price: Decimal) {
+        let proceeds = quantity * price;
+        self.cash += proceeds - proceeds * self.fee_rate();
+        self.trades += 1;
+
+        if let Some(position) = self.positions.get_mut(ticker) {
+            position.quantity -= quantity;
+            position.last_price = price;
+            if position.quantity <= Decimal::ZERO {
+                self.positions.remove(ticker);
+            }
+        }
+    }
+}
+
+/// 참가자 현재 상태 (평가액 기록용).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompetitorSnapshot {
+    /// 참가자 ID
+    pub competitor_id: String,
+    /// 경쟁 ID
+    pub competition_id: String,
+    /// 참가자 인스턴스 실행 여부 (초기화 실패 시 false)
+    pub running: bool,
+    /// 현재 평가액
+    pub equity: Decimal,
+    /// 현금 잔고
+    pub cash: Decimal,
+    /// 보유 종목 수
+    pub open_positions: usize,
+    /// 누적 체결 횟수
+    pub trades: u64,
+    /// 마지막 시장 데이터 시각
+    pub last_data_at: Option<DateTime<Utc>>,
+    /// 마지막 에러
+    pub last_error: Option<String>,
+}
+
+/// 평가액 표본.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct EquitySample {
+    pub at: DateTime<Utc>,
+    pub equity: Decimal,
+}
+
+/// 구간 성과 지표.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RiskAdjustedReturn {
+    /// 구간 수익률 (%)
+    pub total_return_pct: f64,
+    /// 연환산 변동성 (%)
+    pub volatility_pct: f64,
+    /// 연환산 샤프 비율 (무위험 수익률 0, 변동성이 0이면 None)
+    pub sharpe: Option<f64>,
+    /// 최대 낙폭 (%)
+    pub max_drawdown_pct: f64,
+    /// 사용한 표본 수
+    pub samples: usize,
+}
+
+/// 평가액 시계열의 위험 조정 수익률 계산.
+///
+/// 표본은 시간순이어야 하며, 연환산은 평균 표본 간격을 기준으로 합니다.
+/// 표본이 `MIN_LEADERBOARD_SAMPLES`개 미만이면 None.
+pub fn risk_adjusted_return(samples: &[EquitySample]) -> Option<RiskAdjustedReturn> {
+    if samples.len() < MIN_LEADERBOARD_SAMPLES {
+        return None;
+    }
+    let equities: Vec<f64> = samples
+        .iter()
+        .map(|s| s.equity.to_f64().unwrap_or(0.0))
+        .collect();
+    if equities.iter().any(|e| *e <= 0.0) {
+        return None;
+    }
+
+    let returns: Vec<f64> = equities.windows(2).map(|w| w[1] / w[0] - 1.0).collect();
+    let n = returns.len() as f64;
+    let mean = returns.iter().sum::<f64>() / n;
+    let variance = returns.iter().map(|r| (r - mean).powi(2)).sum::<f64>() / n;
+    let std_dev = variance.sqrt();
+
+    let span_secs = (samples[samples.len() - 1].at - samples[0].at).num_seconds() as f64;
+    let periods_per_year = if span_secs > 0.0 {
+        DAYS_PER_YEAR * 86_400.0 / (span_secs / n)
+    } else {
+        1.0
+    };
+
+    let mut peak = equities[0];
+    let mut max_drawdown = 0.0_f64;
+    for equity in &equities {
+        peak = peak.max(*equity);
+        max_drawdown = max_drawdown.max((peak - equity) / peak);
+    }
+
+    Some(RiskAdjustedReturn {
+        total_return_pct: (equities[equities.len() - 1] / equities[0] - 1.0) * 100.0,
+        volatility_pct: std_dev * periods_per_year.sqrt() * 100.0,
+        sharpe: (std_dev > 0.0).then(|| mean / std_dev * periods_per_year.sqrt()),
+        max_drawdown_pct: max_drawdown * 100.0,
+        samples: samples.len(),
+    })
+}
+
+/// 리더보드 정렬 (샤프 비율 내림차순, 같으면 수익률 내림차순).
+///
+/// 지표를 계산할 수 없는 참가자는 맨 뒤로 보냅니다.
+pub fn rank_by_risk_adjusted_return<T>(
+    entries: &mut [T],
+    metrics: impl Fn(&T) -> Option<&RiskAdjustedReturn>,
+) {
+    entries.sort_by(|a, b| {
+        let key = |m: Option<&RiskAdjustedReturn>| {
+            m.map(|m| (m.sharpe.unwrap_or(f64::NEG_INFINITY), m.total_return_pct))
+        };
+        match (key(metrics(a)), key(metrics(b))) {
+            (Some(a), Some(b)) => b.0.total_cmp(&a.0).then_with(|| b.1.total_cmp(&a.1)),
+            (Some(_), None) => std::cmp::Ordering::Less,
+            (None, Some(_)) => std::cmp::Ordering::Greater,
+            (None, None) => std::cmp::Ordering::Equal,
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+    use rust_decimal_macros::dec;
+
+    fn signal(ticker: &str, side: Side, signal_type: SignalType) -> Signal {
+        Signal::new("rsi_1", ticker.to_string(), side, signal_type).with_strength(1.0)
+    }
+
+    #[test]
+    fn test_paper_book_fills() {
+        let mut book = PaperBook::new(PaperBookConfig {
+            initial_capital: dec!(1000000),
+            position_size_pct: dec!(0.5),
+            fee_bps: dec!(0),
+        });
+        let t0 = Utc::now();
+
+        book.apply(
+            "005930",
+            Some(dec!(1000)),
+            &[signal("005930", Side::Buy, SignalType::Entry)],
+            t0,
+        );
+        assert_eq!(book.positions()["005930"].quantity, dec!(500));
+        assert_eq!(book.cash(), dec!(500000));
+
+        // 가격 상승 평가
+        book.apply("005930", Some(dec!(1200)), &[], t0);
+        assert_eq!(book.equity(), dec!(1100000));
+
+        // 절반 축소 후 전량 청산
+        book.apply(
+            "005930",
+            Some(dec!(1200)),
+            &[signal("005930", Side::Sell, SignalType::ReducePosition)],
+            t0,
+        );
+        assert_eq!(book.positions()["005930"].quantity, dec!(250));
+        book.apply(
+            "005930",
+            Some(dec!(1100)),
+            &[signal("005930", Side::Sell, SignalType::Exit)],
+            t0,
+        );
+        assert!(book.positions().is_empty());
+        assert_eq!(book.cash(), dec!(1075000));
+        assert_eq!(book.trades(), 3);
+
+        // 미보유 종목 매도 신호는 무시 (공매도 없음)
+        book.apply(
+            "000660",
+            Some(dec!(100)),
+            &[signal("000660", Side::Sell, SignalType::Entry)],
+            t0,
+        );
+        assert_eq!(book.trades(), 3);
+    }
+
+    #[test]
+    fn test_risk_adjusted_ranking() {
+        let t0 = Utc::now();
+        let series = |values: &[i64]| -> Vec<EquitySample> {
+            values
+                .iter()
+                .enumerate()
+                .map(|(i, v)| EquitySample {
+                    at: t0 + Duration::days(i as i64),
+                    equity: Decimal::from(*v),
+                })
+                .collect()
+        };
+
+        // 꾸준한 상승 vs 더 크지만 변동성 큰 상승
+        let steady = risk_adjusted_return(&series(&[100, 101, 102, 103, 104])).unwrap();
+        let volatile = risk_adjusted_return(&series(&[100, 120, 90, 125, 110])).unwrap();
+        assert!(volatile.total_return_pct > steady.total_return_pct);
+        assert!(steady.sharpe.unwrap() > volatile.sharpe.unwrap());
+        assert!((volatile.max_drawdown_pct - 25.0).abs() < 1e-9);
+        assert!(risk_adjusted_return(&series(&[100, 101])).is_none());
+
+        let mut entries = vec![
+            ("volatile", Some(volatile)),
+            ("new", None),
+            ("steady", Some(steady)),
+        ];
+        rank_by_risk_adjusted_return(&mut entries, |(_, m)| m.as_ref());
+        let order: Vec<&str> = entries.iter().map(|(name, _)| *name).collect();
+        assert_eq!(order, vec!["steady", "volatile", "new"]);
+    }
+}
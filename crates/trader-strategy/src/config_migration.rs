@@ -0,0 +1,339 @@
+//! 저장된 전략 설정 마이그레이션.
+//!
+//! 전략 설정 스키마(필드 이름, 단위, 구조)가 바뀌면 DB에 저장된 이전 설정은
+//! 새 코드에서 역직렬화에 실패하거나 의미가 달라집니다. 전략은
+//! [`Strategy::config_version`]으로 현재 스키마 버전을 선언하고
+//! [`Strategy::config_migrations`]로 한 버전씩 올리는 [`ConfigMigration`] 단계를 제공합니다.
+//! [`upgrade_config`]는 저장된 버전부터 현재 버전까지 단계를 순서대로 적용합니다.
+//!
+//! # 예제
+//!
+//! ```rust,ignore
+//! fn config_version(&self) -> u32 {
+//!     2
+//! }
+//!
+//! fn config_migrations(&self) -> Vec<Box<dyn ConfigMigration>> {
+//!     vec![Box::new(MigrationStep {
+//!         source_version: 1,
+//!         description: "rename `symbol` to `ticker`",
+//!         migrate: |mut config| {
+//!             if let Some(symbol) = config.as_object_mut().and_then(|c| c.remove("symbol")) {
+//!                 config["ticker"] = symbol;
+//!             }
+//!             Ok(config)
+//!         },
+//!     })]
+//! }
+//! ```
+
+use serde_json::Value;
+use thiserror::Error;
+use tracing::info;
+
+use crate::Strategy;
+
+/// 설정 마이그레이션 에러.
+#[derive(Debug, Error, PartialEq)]
+pub enum ConfigMigrationError {
+    #[error("저장된 설정 버전({stored})이 현재 버전({current})보다 높음")]
+    NewerVersion { stored: u32, current: u32 },
+
+    #[error("설정 버전 {0} → {next} 마이그레이션 단계 없음", next = .0 + 1)]
+    MissingStep(u32),
+
+    #[error("설정 버전 {from} 마이그레이션 실패: {reason}")]
+    Failed { from: u32, reason: String },
+}
+
+/// 설정을 한 버전 올리는 마이그레이션 단계.
+pub trait ConfigMigration: Send + Sync {
+    /// 변환 전 버전 (변환 후 버전은 `source_version() + 1`).
+    fn source_version(&self) -> u32;
+
+    /// 변환 내용 설명 (로그용).
+    fn description(&self) -> &str;
+
+    /// 이전 버전 설정을 다음 버전 설정으로 변환.
+    fn migrate(&self, config: Value) -> Result<Value, String>;
+}
+
+/// 함수 포인터로 정의하는 마이그레이션 단계.
+#[derive(Debug, Clone, Copy)]
+pub struct MigrationStep {
+    /// 변환 전 버전
+    pub source_version: u32,
+    /// 변환 내용 설명
+    pub description: &'static str,
+    /// 변환 함수
+    pub migrate: fn(Value) -> Result<Value, String>,
+}
+
+impl ConfigMigration for MigrationStep {
+    fn source_version(&self) -> u32 {
+        self.source_version
+    }
+
+    fn description(&self) -> &str {
+        self.description
+    }
+
+    fn migrate(&self, config: Value) -> Result<Value, String> {
+        (self.migrate)(config)
+    }
+}
+
+/// 설정 마이그레이션 결과.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigUpgrade {
+    /// 현재 버전 설정
+    pub config: Value,
+    /// 저장되어 있던 버전
+    pub from_version: u32,
+    /// 현재 버전
+    pub to_version: u32,
+    /// 적용된 단계 설명 (적용 순서)
+    pub applied: Vec<String>,
+}
+
+impl ConfigUpgrade {
+    /// 설정이 변환되었는지 여부 (저장소에 다시 기록해야 함).
+    pub fn is_upgraded(&self) -> bool {
+        self.from_version != self.to_version
+    }
+}
+
+/// 저장된 버전의 설정을 전략의 현재 설정 버전으로 변환.
+///
+/// 단계는 `stored_version`부터 한 버전씩 적용되며, 각 단계는 로그로 남습니다.
+/// 중간 단계가 없거나 실패하면 변환을 중단하고 에러를 반환합니다 (일부만 변환된
+/// 설정은 반환하지 않음).
+pub fn upgrade_config(
+    strategy: &dyn Strategy,
+    stored_version: u32,
+    config: Value,
+) -> Result<ConfigUpgrade, ConfigMigrationError> {
+    let current = strategy.config_version();
+    if stored_version > current {
+        return Err(ConfigMigrationError::NewerVersion {
+            stored: stored_version,
+            current,
+        });
+    }
+
+    let migrations = if stored_version < current {
+        strategy.config_migrations()
+    } else {
+        Vec::new()
+    };
+
+    let mut config = config;
+    let mut applied = Vec::new();
+    for version in stored_version..current {
+        let step = migrations
+            .iter()
+            .find(|m| m.source_version() == version)
+            .ok_or(ConfigMigrationError::MissingStep(version))?;
+
+        config = step
+            .migrate(config)
+            .map_err(|reason| ConfigMigrationError::Failed {
+                from: version,
+                reason,
+            })?;
+
+        info!(
+            strategy = strategy.name(),
+            from = version,
+            to = version + 1,
+            migration = step.description(),
+            "Migrated strategy config"
+        );
+        applied.push(step.description().to_string());
+    }
+
+    Ok(ConfigUpgrade {
+        config,
+        from_version: stored_version,
+        to_version: current,
+        applied,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use serde_json::json;
+    use trader_core::{MarketData, Order, Position, Signal};
+
+    /// v1: `symbol`, `threshold`(%) → v2: `ticker` → v3: `threshold`(비율)
+    struct VersionedStrategy {
+        migrations: Vec<MigrationStep>,
+    }
+
+    impl VersionedStrategy {
+        fn new() -> Self {
+            Self {
+                migrations: vec![
+                    MigrationStep {
+                        source_version: 2,
+                        description: "convert `threshold` from percent to ratio",
+                        migrate: |mut config| {
+                            let pct = config["threshold"]
+                                .as_f64()
+                                .ok_or("`threshold` must be a number")?;
+                            config["threshold"] = json!(pct / 100.0);
+                            Ok(config)
+                        },
+                    },
+                    MigrationStep {
+                        source_version: 1,
+                        description: "rename `symbol` to `ticker`",
+                        migrate: |mut config| {
+                            let symbol = config
+                                .as_object_mut()
+                                .and_then(|c| c.remove("symbol"))
+                                .ok_or("missing `symbol`")?;
+                            config["ticker"] = symbol;
+                            Ok(config)
+                        },
+                    },
+                ],
+            }
+        }
+    }
+
+    #[async_trait]
+    impl Strategy for VersionedStrategy {
+        fn name(&self) -> &str {
+            "versioned"
+        }
+
+        fn version(&self) -> &str {
+            "3.0.0"
+        }
+
+        fn description(&self) -> &str {
+            "Strategy with a versioned config schema"
+        }
+
+        async fn initialize(
+            &mut self,
+            _config: Value,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        async fn on_market_data(
+            &mut self,
+            _data: &MarketData,
+        ) -> Result<Vec<Signal>, Box<dyn std::error::Error + Send + Sync>> {
+            Ok(vec![])
+        }
+
+        async fn on_order_filled(
+            &mut self,
+            _order: &Order,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        async fn on_position_update(
+            &mut self,
+            _position: &Position,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        async fn shutdown(&mut self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        fn get_state(&self) -> Value {
+            json!({})
+        }
+
+        fn config_version(&self) -> u32 {
+            3
+        }
+
+        fn config_migrations(&self) -> Vec<Box<dyn ConfigMigration>> {
+            self.migrations
+                .iter()
+                .map(|m| Box::new(*m) as Box<dyn ConfigMigration>)
+                .collect()
+        }
+    }
+
+    #[test]
+    fn test_upgrade_applies_steps_in_order() {
+        let strategy = VersionedStrategy::new();
+        let upgrade = upgrade_config(
+            &strategy,
+            1,
+            json!({ "symbol": "005930", "threshold": 5.0 }),
+        )
+        .unwrap();
+
+        assert!(upgrade.is_upgraded());
+        assert_eq!(upgrade.from_version, 1);
+        assert_eq!(upgrade.to_version, 3);
+        assert_eq!(
+            upgrade.config,
+            json!({ "ticker": "005930", "threshold": 0.05 })
+        );
+        assert_eq!(
+            upgrade.applied,
+            vec![
+                "rename `symbol` to `ticker`",
+                "convert `threshold` from percent to ratio"
+            ]
+        );
+
+        // 중간 버전부터는 남은 단계만 적용
+        let upgrade = upgrade_config(
+            &strategy,
+            2,
+            json!({ "ticker": "005930", "threshold": 5.0 }),
+        )
+        .unwrap();
+        assert_eq!(upgrade.applied.len(), 1);
+        assert_eq!(upgrade.config["threshold"], 0.05);
+    }
+
+    #[test]
+    fn test_upgrade_current_version_is_noop() {
+        let strategy = VersionedStrategy::new();
+        let config = json!({ "ticker": "005930", "threshold": 0.05 });
+        let upgrade = upgrade_config(&strategy, 3, config.clone()).unwrap();
+
+        assert!(!upgrade.is_upgraded());
+        assert_eq!(upgrade.config, config);
+        assert!(upgrade.applied.is_empty());
+    }
+
+    #[test]
+    fn test_upgrade_errors() {
+        let strategy = VersionedStrategy::new();
+
+        assert_eq!(
+            upgrade_config(&strategy, 4, json!({})).unwrap_err(),
+            ConfigMigrationError::NewerVersion {
+                stored: 4,
+                current: 3
+            }
+        );
+        assert_eq!(
+            upgrade_config(&strategy, 0, json!({})).unwrap_err(),
+            ConfigMigrationError::MissingStep(0)
+        );
+        assert_eq!(
+            upgrade_config(&strategy, 1, json!({ "threshold": 5.0 })).unwrap_err(),
+            ConfigMigrationError::Failed {
+                from: 1,
+                reason: "missing `symbol`".to_string()
+            }
+        );
+    }
+}
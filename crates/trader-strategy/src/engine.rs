@@ -3,18 +3,29 @@
 //! 엔진은 전략 생명주기를 관리하고, 시장 데이터를 전략에 라우팅하며,
 //! 전략으로부터 트레이딩 신호를 수집합니다.
 
+use crate::competition::{CompetitorSnapshot, PaperBook, PaperBookConfig};
+use crate::shadow::{ShadowConfig, ShadowReport, ShadowTracker};
+use crate::state_store::{StrategyState, StrategyStateStore};
+use crate::symbol_lock::{SymbolClaim, SymbolLockBook, SymbolLockConfig, SymbolLockDecision};
+use crate::trading_window::{
+    is_weekend, market_local_datetime, HolidaySource, TradingWindowConfig,
+};
+use crate::warmup::{warmup_tickers, WarmupDataSource};
 use crate::Strategy;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use thiserror::Error;
 use tokio::sync::{broadcast, mpsc, RwLock};
 use tracing::{debug, error, info, warn};
 use trader_core::{
-    domain::{MarketDataType, StrategyContext},
-    Kline, MarketData, Order, Position, Signal, Timeframe,
+    domain::{MarketDataType, MultiTimeframeConfig, StrategyContext},
+    next_earnings, BrokerDowntime, CalendarMarket, EarningsEvent, EarningsFilterAction,
+    EarningsFilterConfig, Kline, MarketData, Order, Position, Side, Signal, StreamStatus,
+    Timeframe, TimeframeAligner, TradingStatusEvent,
 };
 
 /// 전략 엔진 에러.
@@ -35,6 +46,9 @@ pub enum EngineError {
     #[error("전략이 이미 실행 중: {0}")]
     AlreadyRunning(String),
 
+    #[error("신호 거부됨: {0}")]
+    SignalRejected(String),
+
     #[error("채널 에러: {0}")]
     ChannelError(String),
 
@@ -56,6 +70,52 @@ pub struct StrategyInstance {
     custom_name: Option<String>,
     /// 전략 컨텍스트 (다중 타임프레임 데이터 등)
     context: Arc<RwLock<StrategyContext>>,
+    /// 섀도 비교 인스턴스 (실거래 신호와 비교용)
+    shadow: Option<Box<ShadowInstance>>,
+    /// 실적 발표 필터 (None이면 미적용)
+    earnings_filter: Option<EarningsFilterConfig>,
+    /// 보유 포지션 (ticker -> 포지션 방향, 자동 청산 판정용)
+    open_positions: HashMap<String, Side>,
+    /// 실적 발표 자동 청산 신호를 이미 생성한 (ticker, 발표일)
+    earnings_exits: HashSet<(String, NaiveDate)>,
+    /// 종목 잠금 설정 (None이면 미적용)
+    symbol_lock: Option<SymbolLockConfig>,
+    /// 모의투자 모드 (신호를 출력 채널 대신 `process_paper_trading()` 결과로 반환)
+    paper_trading: bool,
+    /// 마지막으로 저장(또는 등록 시 조회)한 전략 상태 (시작 시 복원, 변경 감지용)
+    saved_state: Option<Vec<u8>>,
+    /// 일시정지 여부 (실행 상태는 유지하되 시장 데이터와 외부 신호를 전달하지 않음)
+    paused: bool,
+    /// 거래 시간대 (None이면 제한 없음)
+    trading_window: Option<TradingWindowConfig>,
+    /// 거래 시간대 밖 청산 신호를 이미 생성한 종목 (구간에 다시 들어오면 초기화)
+    window_exits: HashSet<String>,
+    /// 피드가 직접 공급하는 (종목, Secondary 타임프레임) (Primary 캔들로 집계하지 않음)
+    fed_timeframes: HashSet<(String, Timeframe)>,
+}
+
+/// 섀도 인스턴스.
+///
+/// 실거래 인스턴스와 같은 설정으로 초기화되어 같은 시장 데이터를 받지만,
+/// 생성한 신호는 출력 채널로 전송되지 않고 비교에만 사용됩니다.
+struct ShadowInstance {
+    /// 섀도 전략 인스턴스
+    instance: StrategyInstance,
+    /// 실거래/섀도 비교기
+    tracker: ShadowTracker,
+}
+
+/// 전략 경쟁 참가자 인스턴스.
+///
+/// 실거래 전략과 같은 시장 데이터를 받지만 생성한 신호는 출력 채널로 전송되지 않고
+/// 참가자의 가상 계좌에서만 체결됩니다.
+struct CompetitorInstance {
+    /// 소속 경쟁 ID
+    competition_id: String,
+    /// 참가자 전략 인스턴스
+    instance: StrategyInstance,
+    /// 가상 계좌
+    book: PaperBook,
 }
 
 /// 전략 통계.
@@ -71,6 +131,33 @@ pub struct StrategyStats {
     pub last_signal_time: Option<DateTime<Utc>>,
     /// 마지막 에러 메시지
     pub last_error: Option<String>,
+    /// 실적 발표 필터로 차단된 진입 신호 수
+    #[serde(default)]
+    pub earnings_blocked: u64,
+    /// 실적 발표 필터가 생성한 청산 신호 수
+    #[serde(default)]
+    pub earnings_exits: u64,
+    /// 종목 잠금으로 차단된 진입 신호 수
+    #[serde(default)]
+    pub symbol_lock_blocked: u64,
+    /// 시작 시 워밍업으로 재생한 캔들 수
+    #[serde(default)]
+    pub warmup_bars: u64,
+    /// 저장된 전략 상태를 마지막으로 복원한 시간
+    #[serde(default)]
+    pub state_restored_at: Option<DateTime<Utc>>,
+    /// 전략 상태를 마지막으로 저장한 시간
+    #[serde(default)]
+    pub state_saved_at: Option<DateTime<Utc>>,
+    /// 거래 시간대 밖이라 전략에 전달하지 않은 시장 데이터 수
+    #[serde(default)]
+    pub window_skipped: u64,
+    /// 거래 시간대 밖에서 생성한 청산 신호 수
+    #[serde(default)]
+    pub window_exits: u64,
+    /// 일시정지한 시간 (일시정지 중이 아니면 None)
+    #[serde(default)]
+    pub paused_at: Option<DateTime<Utc>>,
     /// 전략 시작 시간
     pub started_at: Option<DateTime<Utc>>,
     /// 총 실행 시간(초)
@@ -92,6 +179,12 @@ pub struct StrategyStatus {
     pub stats: StrategyStats,
     /// 현재 전략 상태
     pub state: Value,
+    /// 모의투자 모드 여부
+    #[serde(default)]
+    pub paper_trading: bool,
+    /// 일시정지 여부
+    #[serde(default)]
+    pub paused: bool,
 }
 
 /// 엔진 설정.
@@ -170,6 +263,33 @@ pub struct StrategyEngine {
 
     /// 중복 제거를 위한 최근 신호 (signal_id -> timestamp)
     recent_signals: Arc<RwLock<HashMap<String, DateTime<Utc>>>>,
+
+    /// 종목별 다음 실적 발표 일정 (ticker -> 일정)
+    earnings_calendar: Arc<RwLock<HashMap<String, EarningsEvent>>>,
+
+    /// 거래정지/VI 중인 종목 (ticker -> 최근 상태 이벤트)
+    trading_statuses: Arc<RwLock<HashMap<String, TradingStatusEvent>>>,
+
+    /// 진행 중인 브로커 점검/장애 (사용 가능하면 None)
+    broker_downtime: Arc<RwLock<Option<BrokerDowntime>>>,
+
+    /// 전략 경쟁 참가자 (competitor_id -> 인스턴스)
+    competitors: Arc<RwLock<HashMap<String, CompetitorInstance>>>,
+
+    /// 전략 간 종목 점유 현황
+    symbol_locks: Arc<RwLock<SymbolLockBook>>,
+
+    /// 워밍업 캔들 공급자 (없으면 워밍업 생략)
+    warmup_source: Option<Arc<dyn WarmupDataSource>>,
+
+    /// 전략 상태 저장소 (없으면 상태를 메모리에만 유지)
+    state_store: Option<Arc<dyn StrategyStateStore>>,
+
+    /// 휴장일 판정 소스 (없으면 주말만 휴장)
+    holiday_source: Option<Arc<dyn HolidaySource>>,
+
+    /// 시장별 현지 날짜의 거래일 여부 캐시
+    trading_days: Arc<RwLock<HashMap<(CalendarMarket, NaiveDate), bool>>>,
 }
 
 impl StrategyEngine {
@@ -186,9 +306,42 @@ impl StrategyEngine {
             signal_rx: Some(signal_rx),
             running: Arc::new(RwLock::new(false)),
             recent_signals: Arc::new(RwLock::new(HashMap::new())),
+            earnings_calendar: Arc::new(RwLock::new(HashMap::new())),
+            trading_statuses: Arc::new(RwLock::new(HashMap::new())),
+            broker_downtime: Arc::new(RwLock::new(None)),
+            competitors: Arc::new(RwLock::new(HashMap::new())),
+            symbol_locks: Arc::new(RwLock::new(SymbolLockBook::new())),
+            warmup_source: None,
+            state_store: None,
+            holiday_source: None,
+            trading_days: Arc::new(RwLock::new(HashMap::new())),
         }
     }
 
+    /// 워밍업 캔들 공급자 설정.
+    ///
+    /// 설정하면 전략 시작 시 `warmup_config()`로 선언한 과거 캔들을 조회하여
+    /// 실시간 데이터 처리 전에 지표 상태를 채웁니다.
+    pub fn set_warmup_source(&mut self, source: Arc<dyn WarmupDataSource>) {
+        self.warmup_source = Some(source);
+    }
+
+    /// 전략 상태 저장소 설정.
+    ///
+    /// 설정하면 전략의 `save_state()` 결과가 바뀔 때마다 저장하고, 등록 시 조회한
+    /// 마지막 상태를 시작 시(워밍업 다음) 복원합니다. 이후 등록하는 전략부터 적용됩니다.
+    pub fn set_state_store(&mut self, store: Arc<dyn StrategyStateStore>) {
+        self.state_store = Some(store);
+    }
+
+    /// 휴장일 판정 소스 설정.
+    ///
+    /// 거래 시간대의 `skip_holidays`가 공휴일까지 판정하려면 설정해야 합니다
+    /// (없으면 주말만 휴장으로 봄).
+    pub fn set_holiday_source(&mut self, source: Arc<dyn HolidaySource>) {
+        self.holiday_source = Some(source);
+    }
+
     /// 신호 수신기 가져오기 (한 번만 호출 가능).
     pub fn take_signal_receiver(&mut self) -> Option<mpsc::Receiver<Signal>> {
         self.signal_rx.take()
@@ -207,13 +360,45 @@ impl StrategyEngine {
     /// * `config` - 전략 설정 (JSON)
     /// * `custom_name` - 사용자 지정 이름 (없으면 전략 기본 이름 사용)
     pub async fn register_strategy(
+        &self,
+        id: impl Into<String>,
+        strategy: Box<dyn Strategy>,
+        config: Value,
+        custom_name: Option<String>,
+    ) -> Result<(), EngineError> {
+        self.register_strategy_with_lock(id, strategy, config, custom_name, None)
+            .await
+    }
+
+    /// 종목 잠금 설정과 함께 전략 등록.
+    ///
+    /// 잠금이 설정된 전략은 진입 신호를 낼 때 종목을 점유하며, 다른 전략의 점유와
+    /// 충돌하는 진입 신호는 출력 채널로 전송되지 않습니다.
+    ///
+    /// # Arguments
+    /// * `symbol_lock` - 종목 잠금 설정 (None이면 미적용)
+    pub async fn register_strategy_with_lock(
         &self,
         id: impl Into<String>,
         mut strategy: Box<dyn Strategy>,
         config: Value,
         custom_name: Option<String>,
+        symbol_lock: Option<SymbolLockConfig>,
     ) -> Result<(), EngineError> {
         let id = id.into();
+
+        // 저장된 상태 조회는 락 밖에서 수행 (실패하면 상태 없이 등록)
+        let saved_state = match &self.state_store {
+            Some(store) => match store.load(&id).await {
+                Ok(state) => state.map(|state| state.data),
+                Err(e) => {
+                    warn!(strategy_id = %id, error = %e, "Failed to load saved strategy state");
+                    None
+                }
+            },
+            None => None,
+        };
+
         let mut strategies = self.strategies.write().await;
 
         if strategies.len() >= self.config.max_strategies {
@@ -234,11 +419,13 @@ impl StrategyEngine {
         info!(
             strategy_id = %id,
             strategy_name = %display_name,
+            symbol_lock = ?symbol_lock,
+            has_saved_state = saved_state.is_some(),
             "Registering strategy"
         );
 
-        // 전략 컨텍스트 생성 및 주입
-        let context = Arc::new(RwLock::new(StrategyContext::default()));
+        // 전략 컨텍스트 생성 및 주입 (현재 거래정지/VI 종목, 브로커 점검/장애 포함)
+        let context = Arc::new(RwLock::new(self.initial_context().await));
         strategy.set_context(Arc::clone(&context));
 
         strategies.insert(
@@ -250,6 +437,17 @@ impl StrategyEngine {
                 stats: StrategyStats::default(),
                 custom_name,
                 context,
+                shadow: None,
+                earnings_filter: None,
+                open_positions: HashMap::new(),
+                earnings_exits: HashSet::new(),
+                symbol_lock,
+                paper_trading: false,
+                saved_state,
+                paused: false,
+                trading_window: None,
+                window_exits: HashSet::new(),
+                fed_timeframes: HashSet::new(),
             },
         );
 
@@ -272,13 +470,58 @@ impl StrategyEngine {
         strategies
             .remove(id)
             .ok_or_else(|| EngineError::StrategyNotFound(id.to_string()))?;
+        self.symbol_locks.write().await.release_all(id);
+        drop(strategies);
+
+        if let Some(store) = &self.state_store {
+            if let Err(e) = store.delete(id).await {
+                warn!(strategy_id = %id, error = %e, "Failed to delete saved strategy state");
+            }
+        }
 
         info!(strategy_id = %id, "Unregistered strategy");
         Ok(())
     }
 
     /// 전략 시작.
+    ///
+    /// 초기화 후 워밍업 캔들 공급자가 있으면 전략이 선언한 과거 캔들로 컨텍스트와
+    /// 지표 상태를 채운 뒤 실행 상태로 전환합니다. 워밍업 실패는 경고만 남기고 시작합니다.
+    /// 저장된 전략 상태가 있으면 워밍업 다음에 복원합니다 (복원 실패도 경고만 남김).
     pub async fn start_strategy(&self, id: &str) -> Result<(), EngineError> {
+        let warmup_plan = {
+            let mut strategies = self.strategies.write().await;
+
+            let instance = strategies
+                .get_mut(id)
+                .ok_or_else(|| EngineError::StrategyNotFound(id.to_string()))?;
+
+            if instance.running {
+                return Err(EngineError::AlreadyRunning(id.to_string()));
+            }
+
+            // 전략 초기화
+            instance
+                .strategy
+                .initialize(instance.config.clone())
+                .await
+                .map_err(|e| EngineError::InitializationFailed(e.to_string()))?;
+
+            instance
+                .strategy
+                .warmup_config()
+                .filter(|config| !config.timeframes.is_empty())
+                .map(|config| (config, warmup_tickers(&instance.config)))
+        };
+
+        // 과거 캔들 조회는 락 밖에서 수행 (다른 전략의 실시간 처리를 막지 않음)
+        let warmup_data = match (&self.warmup_source, warmup_plan) {
+            (Some(source), Some((config, tickers))) => {
+                Self::fetch_warmup_data(id, source.as_ref(), &config, &tickers).await
+            }
+            _ => Vec::new(),
+        };
+
         let mut strategies = self.strategies.write().await;
 
         let instance = strategies
@@ -289,26 +532,157 @@ impl StrategyEngine {
             return Err(EngineError::AlreadyRunning(id.to_string()));
         }
 
-        // 전략 초기화
-        instance
-            .strategy
-            .initialize(instance.config.clone())
-            .await
-            .map_err(|e| EngineError::InitializationFailed(e.to_string()))?;
-
+        instance.stats.warmup_bars = Self::apply_warmup(id, instance, &warmup_data).await;
+        if let Some(data) = instance.saved_state.clone() {
+            Self::restore_state(id, instance, &data);
+        }
         instance.running = true;
         instance.stats.started_at = Some(Utc::now());
 
+        if let Some(shadow) = instance.shadow.as_mut() {
+            Self::initialize_shadow(id, shadow, &instance.config).await;
+            if shadow.instance.running {
+                shadow.instance.stats.warmup_bars =
+                    Self::apply_warmup(id, &mut shadow.instance, &warmup_data).await;
+            }
+        }
+
         info!(
             strategy_id = %id,
             strategy_name = instance.strategy.name(),
+            warmup_bars = instance.stats.warmup_bars,
             "Started strategy"
         );
 
         Ok(())
     }
 
+    /// 티커별 워밍업 캔들 조회 (실패한 티커는 건너뜀).
+    async fn fetch_warmup_data(
+        id: &str,
+        source: &dyn WarmupDataSource,
+        config: &MultiTimeframeConfig,
+        tickers: &[String],
+    ) -> Vec<(String, HashMap<Timeframe, Vec<Kline>>)> {
+        let mut data = Vec::with_capacity(tickers.len());
+
+        for ticker in tickers {
+            match source.fetch_klines(ticker, config).await {
+                Ok(klines) => data.push((ticker.clone(), klines)),
+                Err(e) => warn!(
+                    strategy_id = %id,
+                    ticker = %ticker,
+                    error = %e,
+                    "Failed to fetch warmup klines"
+                ),
+            }
+        }
+
+        data
+    }
+
+    /// 워밍업 캔들을 컨텍스트에 채우고 전략에 재생. 재생한 캔들 수를 반환합니다.
+    async fn apply_warmup(
+        id: &str,
+        instance: &mut StrategyInstance,
+        data: &[(String, HashMap<Timeframe, Vec<Kline>>)],
+    ) -> u64 {
+        let mut replayed = 0u64;
+
+        for (ticker, klines) in data {
+            let by_timeframe = klines
+                .iter()
+                .map(|(timeframe, klines)| (*timeframe, klines.clone()))
+                .collect();
+            instance
+                .context
+                .write()
+                .await
+                .update_multi_timeframe_klines(ticker, by_timeframe);
+
+            match instance.strategy.warmup(ticker, klines).await {
+                Ok(count) => replayed += count as u64,
+                Err(e) => {
+                    instance.stats.last_error = Some(e.to_string());
+                    warn!(
+                        strategy_id = %id,
+                        ticker = %ticker,
+                        error = %e,
+                        "Strategy warmup failed"
+                    );
+                }
+            }
+        }
+
+        replayed
+    }
+
+    /// 저장된 상태를 전략에 복원.
+    fn restore_state(id: &str, instance: &mut StrategyInstance, data: &[u8]) {
+        match instance.strategy.load_state(data) {
+            Ok(()) => {
+                instance.stats.state_restored_at = Some(Utc::now());
+                info!(strategy_id = %id, bytes = data.len(), "Restored strategy state");
+            }
+            Err(e) => {
+                instance.stats.last_error = Some(e.to_string());
+                warn!(strategy_id = %id, error = %e, "Failed to restore strategy state");
+            }
+        }
+    }
+
+    /// 바뀐 전략 상태를 저장소에 저장 (`target`이 있으면 해당 전략만).
+    ///
+    /// 상태는 읽기 락에서 수집하고 저장은 락 밖에서 수행합니다. 저장에 실패한 상태는
+    /// 다음 호출에서 다시 시도합니다.
+    async fn persist_states(&self, target: Option<&str>) {
+        let Some(store) = &self.state_store else {
+            return;
+        };
+
+        let changed: Vec<StrategyState> = {
+            let strategies = self.strategies.read().await;
+            strategies
+                .iter()
+                .filter(|(id, _)| target.map_or(true, |target| target == id.as_str()))
+                .filter_map(|(id, instance)| match instance.strategy.save_state() {
+                    Ok(data) => {
+                        let changed =
+                            !data.is_empty() && instance.saved_state.as_ref() != Some(&data);
+                        changed.then(|| StrategyState {
+                            strategy_id: id.clone(),
+                            data,
+                            saved_at: Utc::now(),
+                        })
+                    }
+                    Err(e) => {
+                        warn!(strategy_id = %id, error = %e, "Failed to serialize strategy state");
+                        None
+                    }
+                })
+                .collect()
+        };
+
+        for state in changed {
+            if let Err(e) = store.save(&state).await {
+                warn!(
+                    strategy_id = %state.strategy_id,
+                    error = %e,
+                    "Failed to persist strategy state"
+                );
+                continue;
+            }
+
+            if let Some(instance) = self.strategies.write().await.get_mut(&state.strategy_id) {
+                instance.stats.state_saved_at = Some(state.saved_at);
+                instance.saved_state = Some(state.data);
+            }
+        }
+    }
+
     /// 전략 중지.
+    ///
+    /// 종료 후 전략 상태를 저장하여 다음 시작 시 이어서 진행합니다.
     pub async fn stop_strategy(&self, id: &str) -> Result<(), EngineError> {
         let mut strategies = self.strategies.write().await;
 
@@ -330,6 +704,8 @@ impl StrategyEngine {
         }
 
         instance.running = false;
+        instance.paused = false;
+        instance.stats.paused_at = None;
 
         // 실행 시간 업데이트
         if let Some(started) = instance.stats.started_at {
@@ -337,10 +713,59 @@ impl StrategyEngine {
             instance.stats.total_runtime_secs += runtime.num_seconds() as u64;
         }
 
+        drop(strategies);
+        self.persist_states(Some(id)).await;
+
         info!(strategy_id = %id, "Stopped strategy");
         Ok(())
     }
 
+    /// 전략 일시정지.
+    ///
+    /// 실행 상태(전략 내부 상태, 보유 포지션 추적, 종목 점유)는 유지한 채 시장 데이터와
+    /// 외부 신호를 전달하지 않습니다. 체결/포지션 알림은 계속 전달합니다.
+    /// 이미 일시정지 중이면 아무것도 하지 않습니다.
+    pub async fn pause_strategy(&self, id: &str) -> Result<(), EngineError> {
+        let mut strategies = self.strategies.write().await;
+        let instance = strategies
+            .get_mut(id)
+            .ok_or_else(|| EngineError::StrategyNotFound(id.to_string()))?;
+
+        if !instance.running {
+            return Err(EngineError::NotRunning(id.to_string()));
+        }
+        if instance.paused {
+            return Ok(());
+        }
+
+        instance.paused = true;
+        instance.stats.paused_at = Some(Utc::now());
+        info!(strategy_id = %id, "Paused strategy");
+        Ok(())
+    }
+
+    /// 일시정지한 전략 재개.
+    ///
+    /// 일시정지 중이 아니면 아무것도 하지 않습니다.
+    pub async fn resume_strategy(&self, id: &str) -> Result<(), EngineError> {
+        let mut strategies = self.strategies.write().await;
+        let instance = strategies
+            .get_mut(id)
+            .ok_or_else(|| EngineError::StrategyNotFound(id.to_string()))?;
+
+        if !instance.running {
+            return Err(EngineError::NotRunning(id.to_string()));
+        }
+        if !instance.paused {
+            return Ok(());
+        }
+
+        instance.paused = false;
+        instance.stats.paused_at = None;
+        info!(strategy_id = %id, "Resumed strategy");
+        Ok(())
+    }
+
     /// 전략 상태 조회.
     pub async fn get_strategy_status(&self, id: &str) -> Result<StrategyStatus, EngineError> {
         let strategies = self.strategies.read().await;
@@ -362,6 +787,8 @@ impl StrategyEngine {
             running: instance.running,
             stats: instance.stats.clone(),
             state: instance.strategy.get_state(),
+            paper_trading: instance.paper_trading,
+            paused: instance.paused,
         })
     }
 
@@ -424,6 +851,8 @@ impl StrategyEngine {
                     running: instance.running,
                     stats: instance.stats.clone(),
                     state: instance.strategy.get_state(),
+                    paper_trading: instance.paper_trading,
+                    paused: instance.paused,
                 },
             );
         }
@@ -450,7 +879,7 @@ impl StrategyEngine {
             .get(id)
             .ok_or_else(|| EngineError::StrategyNotFound(id.to_string()))?;
 
-        Ok(instance.strategy.multi_timeframe_config())
+        Ok(instance.strategy.metadata().timeframes)
     }
 
     /// 전략 컨텍스트 참조 반환.
@@ -506,27 +935,58 @@ impl StrategyEngine {
     /// - Primary TF 데이터: 모든 TF 데이터와 함께 `on_multi_timeframe_data()` 호출
     pub async fn process_market_data(&self, data: MarketData) -> Result<Vec<Signal>, EngineError> {
         let mut all_signals = Vec::new();
+        let trading_days = self.window_trading_days(data.timestamp).await;
         let mut strategies = self.strategies.write().await;
+        let earnings_calendar = self.earnings_calendar.read().await;
+        let mut symbol_locks = self.symbol_locks.write().await;
+        let today = data.timestamp.date_naive();
 
         for (id, instance) in strategies.iter_mut() {
-            if !instance.running {
+            // 모의투자 전략은 process_paper_trading()에서만 처리
+            if !instance.running || instance.paused || instance.paper_trading {
+                continue;
+            }
+
+            // 거래 시간대 밖이면 전략에 전달하지 않음 (설정 시 보유 포지션 청산)
+            if let Some(exits) = Self::apply_trading_window(id, instance, &data, &trading_days) {
+                let exits = Self::apply_symbol_lock(id, instance, exits, &mut symbol_locks);
+                for signal in exits {
+                    instance.stats.signals_generated += 1;
+                    instance.stats.last_signal_time = Some(Utc::now());
+                    all_signals.push(signal);
+                }
                 continue;
             }
 
             // 다중 타임프레임 전략 처리
-            let signals_result =
-                if let Some(mtf_config) = instance.strategy.multi_timeframe_config() {
-                    self.process_multi_timeframe_data(instance, &data, &mtf_config)
-                        .await
-                } else {
-                    // 일반 전략: 기존 방식대로 처리
-                    instance.strategy.on_market_data(&data).await
-                };
+            let signals_result = if let Some(mtf_config) = instance.strategy.metadata().timeframes {
+                self.process_multi_timeframe_data(instance, &data, &mtf_config)
+                    .await
+            } else {
+                // 일반 전략: 기존 방식대로 처리
+                instance.strategy.on_market_data(&data).await
+            };
+
+            // 섀도 비교용 실거래 신호 (중복 제거 전)
+            let live_signals = match (&signals_result, &instance.shadow) {
+                (Ok(signals), Some(_)) => signals.clone(),
+                _ => Vec::new(),
+            };
 
             match signals_result {
                 Ok(signals) => {
                     instance.stats.market_data_processed += 1;
 
+                    let signals = Self::apply_earnings_filter(
+                        id,
+                        instance,
+                        signals,
+                        &data.ticker,
+                        &earnings_calendar,
+                        today,
+                    );
+                    let signals = Self::apply_symbol_lock(id, instance, signals, &mut symbol_locks);
+
                     for signal in signals {
                         instance.stats.signals_generated += 1;
                         instance.stats.last_signal_time = Some(Utc::now());
@@ -551,8 +1011,53 @@ impl StrategyEngine {
                     );
                 }
             }
+
+            // 섀도 인스턴스에 같은 데이터 공급 후 신호 비교 (섀도 신호는 전송하지 않음)
+            if let Some(shadow) = instance.shadow.as_mut() {
+                if shadow.instance.running {
+                    let shadow_result = if let Some(mtf_config) =
+                        shadow.instance.strategy.metadata().timeframes
+                    {
+                        self.process_multi_timeframe_data(&mut shadow.instance, &data, &mtf_config)
+                            .await
+                    } else {
+                        shadow.instance.strategy.on_market_data(&data).await
+                    };
+
+                    let shadow_signals = match shadow_result {
+                        Ok(signals) => {
+                            shadow.instance.stats.market_data_processed += 1;
+                            shadow.instance.stats.signals_generated += signals.len() as u64;
+                            signals
+                        }
+                        Err(e) => {
+                            shadow.instance.stats.last_error = Some(e.to_string());
+                            warn!(
+                                strategy_id = %id,
+                                error = %e,
+                                "Shadow strategy error processing market data"
+                            );
+                            Vec::new()
+                        }
+                    };
+
+                    shadow.tracker.record_signals(
+                        &live_signals,
+                        &shadow_signals,
+                        data.get_price(),
+                        data.timestamp,
+                    );
+                }
+            }
         }
 
+        drop(symbol_locks);
+        drop(earnings_calendar);
+        drop(strategies);
+
+        // 경쟁 참가자에 같은 데이터 공급 후 가상 계좌에서 체결 (신호는 전송하지 않음)
+        self.process_competitors(&data).await;
+
         // 활성화된 경우 신호 중복 제거
         if self.config.deduplicate_signals {
             all_signals = self.deduplicate_signals(all_signals).await;
@@ -568,6 +1073,8 @@ impl StrategyEngine {
         // 시장 데이터도 브로드캐스트
         let _ = self.market_data_tx.send(data);
 
+        self.persist_states(None).await;
+
         Ok(all_signals)
     }
 
@@ -576,7 +1083,9 @@ impl StrategyEngine {
     /// - Kline 데이터가 아니면 기존 방식대로 `on_market_data()` 호출
     /// - Kline 데이터면:
     ///   - 컨텍스트에 캔들 데이터 업데이트
-    ///   - Primary TF인 경우에만 `on_multi_timeframe_data()` 호출
+    ///   - Primary TF 캔들은 피드가 직접 주지 않는 상위 TF 버퍼에 집계
+    ///   - Primary TF인 경우에만 `on_multi_timeframe_data()` 호출 (Primary 캔들 종료 시점까지
+    ///     완료된 Secondary 캔들만 전달)
     ///   - Secondary TF인 경우 빈 벡터 반환 (캐시만 업데이트)
     async fn process_multi_timeframe_data(
         &self,
@@ -593,6 +1102,9 @@ impl StrategyEngine {
         let incoming_tf = kline.timeframe;
         let ticker = &kline.ticker;
 
+        // Primary TF 확인
+        let primary_tf = mtf_config.get_primary_timeframe();
+
         // 컨텍스트에 캔들 데이터 업데이트
         {
             let mut ctx = instance.context.write().await;
@@ -602,12 +1114,13 @@ impl StrategyEngine {
             let mut klines = ctx.get_klines(ticker, incoming_tf).to_vec();
 
             // 새 캔들 추가 (시간순 정렬 유지)
+            let mut replaced = None;
             if klines.last().map(|k| k.open_time) != Some(kline.open_time) {
                 klines.push(kline.clone());
             } else {
                 // 같은 시간의 캔들이면 업데이트
                 if let Some(last) = klines.last_mut() {
-                    *last = kline.clone();
+                    replaced = Some(std::mem::replace(last, kline.clone()));
                 }
             }
 
@@ -617,10 +1130,34 @@ impl StrategyEngine {
             }
 
             ctx.update_klines(ticker, incoming_tf, klines);
-        }
 
-        // Primary TF 확인
-        let primary_tf = mtf_config.get_primary_timeframe();
+            if primary_tf.is_none() || Some(incoming_tf) == primary_tf {
+                // 피드가 직접 주지 않는 상위 TF는 Primary 캔들로 집계
+                for (&timeframe, &count) in &mtf_config.timeframes {
+                    if timeframe.duration() <= incoming_tf.duration()
+                        || instance
+                            .fed_timeframes
+                            .contains(&(ticker.clone(), timeframe))
+                    {
+                        continue;
+                    }
+                    let mut klines = ctx.get_klines(ticker, timeframe).to_vec();
+                    TimeframeAligner::roll_up(
+                        &mut klines,
+                        &kline,
+                        replaced.as_ref(),
+                        timeframe,
+                        count,
+                    );
+                    ctx.update_klines(ticker, timeframe, klines);
+                }
+            } else {
+                // 직접 들어오는 Secondary TF는 집계하지 않음
+                instance
+                    .fed_timeframes
+                    .insert((ticker.clone(), incoming_tf));
+            }
+        }
 
         // Primary TF가 아니면 캐시만 업데이트하고 신호 생성 안 함
         if primary_tf.is_some() && Some(incoming_tf) != primary_tf {
@@ -645,7 +1182,8 @@ impl StrategyEngine {
                 }
             }
 
-            data_map
+            // 진행 중인 Secondary 캔들 제외 (백테스트와 같은 미래 데이터 누출 방지)
+            TimeframeAligner::align_multi_timeframe(&data_map, kline.close_time)
         };
 
         debug!(
@@ -697,67 +1235,636 @@ impl StrategyEngine {
         unique_signals
     }
 
-    /// 전략에 주문 체결 알림.
-    pub async fn notify_order_filled(&self, order: &Order) -> Result<(), EngineError> {
-        let mut strategies = self.strategies.write().await;
+    /// 실적 발표 필터 적용.
+    ///
+    /// 발표 예정일이 가까운 종목의 진입 신호를 차단하고, 청산 기간에 들어온 보유 포지션에
+    /// 청산 신호를 추가합니다 (발표일당 한 번). 차단/청산 사유는 로그와 통계에 남기며,
+    /// 생성한 청산 신호에는 `earnings_filter` 메타데이터를 붙여 신호 기록에서 구분합니다.
+    fn apply_earnings_filter(
+        id: &str,
+        instance: &mut StrategyInstance,
+        signals: Vec<Signal>,
+        data_ticker: &str,
+        calendar: &HashMap<String, EarningsEvent>,
+        today: NaiveDate,
+    ) -> Vec<Signal> {
+        let Some(filter) = instance.earnings_filter.filter(|f| f.is_enabled()) else {
+            return signals;
+        };
 
-        for (id, instance) in strategies.iter_mut() {
-            if !instance.running {
+        let mut filtered = Vec::with_capacity(signals.len());
+        for signal in signals {
+            let Some(event) = calendar.get(&signal.ticker) else {
+                filtered.push(signal);
                 continue;
-            }
-
-            if let Err(e) = instance.strategy.on_order_filled(order).await {
-                instance.stats.last_error = Some(e.to_string());
-                error!(
-                    strategy_id = %id,
-                    error = %e,
-                    "Strategy error handling order fill"
-                );
-            } else {
-                instance.stats.orders_filled += 1;
+            };
+            match filter.evaluate_signal(signal.is_entry(), event, today) {
+                EarningsFilterAction::BlockEntry { days_until } => {
+                    instance.stats.earnings_blocked += 1;
+                    info!(
+                        strategy_id = %id,
+                        ticker = %signal.ticker,
+                        side = ?signal.side,
+                        earnings_date = %event.announce_date,
+                        days_until,
+                        confirmed = event.confirmed,
+                        "Entry signal blocked by earnings filter"
+                    );
+                }
+                _ => filtered.push(signal),
             }
         }
 
-        Ok(())
-    }
-
-    /// 전략에 포지션 업데이트 알림.
-    pub async fn notify_position_update(&self, position: &Position) -> Result<(), EngineError> {
-        let mut strategies = self.strategies.write().await;
+        // 보유 포지션 자동 청산 (전략이 이미 청산 신호를 낸 경우 제외)
+        let Some(event) = calendar.get(data_ticker) else {
+            return filtered;
+        };
+        let Some(&position_side) = instance.open_positions.get(data_ticker) else {
+            return filtered;
+        };
+        let already_exiting = filtered
+            .iter()
+            .any(|s| s.ticker == data_ticker && s.is_exit());
+        let exit_key = (data_ticker.to_string(), event.announce_date);
+        if already_exiting || instance.earnings_exits.contains(&exit_key) {
+            return filtered;
+        }
 
-        for (id, instance) in strategies.iter_mut() {
-            if !instance.running {
-                continue;
-            }
+        if let EarningsFilterAction::Exit { days_until } = filter.evaluate_position(event, today) {
+            let exit_side = match position_side {
+                Side::Buy => Side::Sell,
+                Side::Sell => Side::Buy,
+            };
+            let signal = Signal::exit(id, data_ticker.to_string(), exit_side).with_metadata(
+                "earnings_filter",
+                serde_json::json!({
+                    "action": "exit",
+                    "earnings_date": event.announce_date,
+                    "days_until": days_until,
+                    "timing": event.timing,
+                    "confirmed": event.confirmed,
+                }),
+            );
 
-            if let Err(e) = instance.strategy.on_position_update(position).await {
-                instance.stats.last_error = Some(e.to_string());
-                error!(
-                    strategy_id = %id,
-                    error = %e,
-                    "Strategy error handling position update"
-                );
-            }
+            instance.stats.earnings_exits += 1;
+            instance.earnings_exits.insert(exit_key);
+            info!(
+                strategy_id = %id,
+                ticker = %data_ticker,
+                side = ?exit_side,
+                earnings_date = %event.announce_date,
+                days_until,
+                "Exit signal generated by earnings filter"
+            );
+            filtered.push(signal);
         }
 
-        Ok(())
+        filtered
     }
 
-    /// 엔진 메인 루프 시작.
-    pub async fn run(&self) -> Result<(), EngineError> {
-        {
-            let mut running = self.running.write().await;
-            if *running {
-                return Err(EngineError::AlreadyRunning("Engine".to_string()));
-            }
-            *running = true;
+    /// 거래 시간대 적용.
+    ///
+    /// 구간 안이거나 거래 시간대가 없으면 `None`을 반환하여 전략이 데이터를 처리하게 합니다.
+    /// 구간 밖이면 전략 대신 반환한 신호 목록을 사용합니다: `flatten_outside`가 켜져 있고
+    /// 데이터 종목을 보유 중이면 청산 신호를 한 번 생성하고 (`trading_window` 메타데이터),
+    /// 그 외에는 빈 목록입니다.
+    fn apply_trading_window(
+        id: &str,
+        instance: &mut StrategyInstance,
+        data: &MarketData,
+        trading_days: &HashMap<CalendarMarket, bool>,
+    ) -> Option<Vec<Signal>> {
+        let Some(window) = &instance.trading_window else {
+            return None;
+        };
+        let trading_day = trading_days.get(&window.market).copied().unwrap_or(true);
+        if window.is_open(data.timestamp, trading_day) {
+            instance.window_exits.clear();
+            return None;
         }
 
-        info!("Strategy engine started");
-
-        let mut market_data_rx = self.market_data_tx.subscribe();
+        instance.stats.window_skipped += 1;
+        if !window.flatten_outside || instance.window_exits.contains(&data.ticker) {
+            return Some(Vec::new());
+        }
+        let Some(&position_side) = instance.open_positions.get(&data.ticker) else {
+            return Some(Vec::new());
+        };
 
-        loop {
+        let exit_side = match position_side {
+            Side::Buy => Side::Sell,
+            Side::Sell => Side::Buy,
+        };
+        let local_time = window.local_datetime(data.timestamp);
+        let signal = Signal::exit(id, data.ticker.clone(), exit_side).with_metadata(
+            "trading_window",
+            serde_json::json!({
+                "action": "exit",
+                "market": window.market,
+                "local_time": local_time,
+                "trading_day": trading_day,
+            }),
+        );
+
+        instance.stats.window_exits += 1;
+        instance.window_exits.insert(data.ticker.clone());
+        info!(
+            strategy_id = %id,
+            ticker = %data.ticker,
+            side = ?exit_side,
+            local_time = %local_time,
+            trading_day,
+            "Exit signal generated outside trading window"
+        );
+        Some(vec![signal])
+    }
+
+    /// 거래 시간대가 설정된 전략의 기준 시장별 거래일 여부.
+    async fn window_trading_days(&self, now: DateTime<Utc>) -> HashMap<CalendarMarket, bool> {
+        let markets: HashSet<CalendarMarket> = {
+            let strategies = self.strategies.read().await;
+            strategies
+                .values()
+                .filter_map(|instance| instance.trading_window.as_ref())
+                .filter(|window| window.skip_holidays)
+                .map(|window| window.market)
+                .collect()
+        };
+
+        let mut trading_days = HashMap::with_capacity(markets.len());
+        for market in markets {
+            let date = market_local_datetime(market, now).date();
+            trading_days.insert(market, self.is_trading_day(market, date).await);
+        }
+        trading_days
+    }
+
+    /// 시장 현지 날짜의 거래일 여부.
+    ///
+    /// 주말은 항상 휴장이며, 휴장일 판정 소스가 없으면 평일은 거래일로 봅니다.
+    /// 소스 조회에 실패하면 거래일로 보고 (캐시하지 않음) 다음 데이터에서 다시 조회합니다.
+    pub async fn is_trading_day(&self, market: CalendarMarket, date: NaiveDate) -> bool {
+        if is_weekend(date) {
+            return false;
+        }
+        let Some(source) = &self.holiday_source else {
+            return true;
+        };
+        if let Some(&trading_day) = self.trading_days.read().await.get(&(market, date)) {
+            return trading_day;
+        }
+
+        match source.is_holiday(market, date).await {
+            Ok(holiday) => {
+                self.trading_days
+                    .write()
+                    .await
+                    .insert((market, date), !holiday);
+                !holiday
+            }
+            Err(e) => {
+                warn!(
+                    market = ?market,
+                    date = %date,
+                    error = %e,
+                    "Failed to check market holiday, assuming trading day"
+                );
+                true
+            }
+        }
+    }
+
+    /// 종목 잠금 적용.
+    ///
+    /// 다른 전략의 점유와 충돌하는 진입 신호를 차단하고, 허용된 진입 신호로 종목을 점유합니다.
+    /// 반대 방향 보유와 상쇄되는 신호(`netting`)에는 `symbol_lock` 메타데이터를 붙입니다.
+    fn apply_symbol_lock(
+        id: &str,
+        instance: &mut StrategyInstance,
+        signals: Vec<Signal>,
+        book: &mut SymbolLockBook,
+    ) -> Vec<Signal> {
+        let Some(lock) = instance.symbol_lock else {
+            return signals;
+        };
+
+        let mut allowed = Vec::with_capacity(signals.len());
+        for signal in signals {
+            match book.acquire(id, &lock, &signal) {
+                SymbolLockDecision::Allow => allowed.push(signal),
+                SymbolLockDecision::Net { against } => {
+                    debug!(
+                        strategy_id = %id,
+                        ticker = %signal.ticker,
+                        against = ?against,
+                        "Entry signal nets against other strategies"
+                    );
+                    allowed.push(signal.with_metadata(
+                        "symbol_lock",
+                        serde_json::json!({ "policy": lock.policy, "net_against": against }),
+                    ));
+                }
+                SymbolLockDecision::Reject(conflict) => {
+                    instance.stats.symbol_lock_blocked += 1;
+                    info!(
+                        strategy_id = %id,
+                        ticker = %signal.ticker,
+                        side = ?signal.side,
+                        policy = ?lock.policy,
+                        conflict = %conflict,
+                        "Entry signal blocked by symbol lock"
+                    );
+                }
+            }
+        }
+        allowed
+    }
+
+    /// 외부 신호(웹훅 등)를 특정 전략으로 전달.
+    ///
+    /// 전략이 페이로드를 신호로 변환하면 해당 신호는 전략 인스턴스 ID로 귀속되어
+    /// 시장 데이터에서 생성된 신호와 동일하게 출력 채널로 전송됩니다.
+    /// 모의투자 전략은 거부되며 `process_paper_external_signal()`로 처리해야 합니다.
+    pub async fn process_external_signal(
+        &self,
+        id: &str,
+        payload: &Value,
+    ) -> Result<Vec<Signal>, EngineError> {
+        let signals = self.external_signals(id, payload, false).await?;
+
+        for signal in &signals {
+            if let Err(e) = self.signal_tx.send(signal.clone()).await {
+                error!(error = %e, "Failed to send signal to channel");
+            }
+        }
+
+        self.persist_states(Some(id)).await;
+
+        Ok(signals)
+    }
+
+    /// 외부 신호(웹훅 등)를 모의투자 전략으로 전달.
+    ///
+    /// 신호는 출력 채널로 전송하지 않고 반환하며, 호출자가 가상 계좌에서 체결해야 합니다.
+    /// 실거래 전략은 거부됩니다.
+    pub async fn process_paper_external_signal(
+        &self,
+        id: &str,
+        payload: &Value,
+    ) -> Result<Vec<Signal>, EngineError> {
+        let signals = self.external_signals(id, payload, true).await?;
+        self.persist_states(Some(id)).await;
+        Ok(signals)
+    }
+
+    /// 외부 신호를 전략 신호로 변환 (모의투자 여부가 일치하는 전략만).
+    async fn external_signals(
+        &self,
+        id: &str,
+        payload: &Value,
+        paper_trading: bool,
+    ) -> Result<Vec<Signal>, EngineError> {
+        let now = Utc::now();
+        let trading_days = self.window_trading_days(now).await;
+        let signals = {
+            let mut strategies = self.strategies.write().await;
+            let instance = strategies
+                .get_mut(id)
+                .ok_or_else(|| EngineError::StrategyNotFound(id.to_string()))?;
+
+            if !instance.running {
+                return Err(EngineError::NotRunning(id.to_string()));
+            }
+            if instance.paused {
+                return Err(EngineError::SignalRejected(format!(
+                    "strategy {id} is paused"
+                )));
+            }
+            if instance.paper_trading != paper_trading {
+                let mode = if instance.paper_trading {
+                    "in"
+                } else {
+                    "not in"
+                };
+                return Err(EngineError::SignalRejected(format!(
+                    "strategy {id} is {mode} paper trading mode"
+                )));
+            }
+            if let Some(window) = &instance.trading_window {
+                let trading_day = trading_days.get(&window.market).copied().unwrap_or(true);
+                if !window.is_open(now, trading_day) {
+                    instance.stats.window_skipped += 1;
+                    return Err(EngineError::SignalRejected(format!(
+                        "strategy {id} is outside its trading window"
+                    )));
+                }
+            }
+
+            let mut signals = match instance.strategy.on_external_signal(payload).await {
+                Ok(signals) => signals,
+                Err(e) => {
+                    instance.stats.last_error = Some(e.to_string());
+                    return Err(EngineError::SignalRejected(e.to_string()));
+                }
+            };
+            for signal in &mut signals {
+                signal.strategy_id = id.to_string();
+            }
+            let signals = Self::apply_symbol_lock(
+                id,
+                instance,
+                signals,
+                &mut *self.symbol_locks.write().await,
+            );
+
+            for signal in &signals {
+                instance.stats.signals_generated += 1;
+                instance.stats.last_signal_time = Some(Utc::now());
+
+                debug!(
+                    strategy_id = %id,
+                    signal_type = %signal.signal_type,
+                    ticker = %signal.ticker,
+                    side = ?signal.side,
+                    "Strategy accepted external signal"
+                );
+            }
+            signals
+        };
+
+        Ok(signals)
+    }
+
+    /// 전략에 주문 체결 알림.
+    pub async fn notify_order_filled(&self, order: &Order) -> Result<(), EngineError> {
+        let mut strategies = self.strategies.write().await;
+
+        for (id, instance) in strategies.iter_mut() {
+            if !instance.running {
+                continue;
+            }
+
+            if let Err(e) = instance.strategy.on_order_filled(order).await {
+                instance.stats.last_error = Some(e.to_string());
+                error!(
+                    strategy_id = %id,
+                    error = %e,
+                    "Strategy error handling order fill"
+                );
+            } else {
+                instance.stats.orders_filled += 1;
+            }
+        }
+        drop(strategies);
+
+        self.persist_states(None).await;
+
+        Ok(())
+    }
+
+    /// 전략에 포지션 업데이트 알림.
+    pub async fn notify_position_update(&self, position: &Position) -> Result<(), EngineError> {
+        let mut strategies = self.strategies.write().await;
+        let mut symbol_locks = self.symbol_locks.write().await;
+
+        for (id, instance) in strategies.iter_mut() {
+            // 실적 발표 자동 청산 판정용 보유 포지션 추적
+            if position.strategy_id.as_deref() == Some(id.as_str()) {
+                if position.is_open() {
+                    instance
+                        .open_positions
+                        .insert(position.ticker.clone(), position.side);
+                } else {
+                    instance.open_positions.remove(&position.ticker);
+                }
+
+                // 종목 점유는 실제 포지션 기준으로 갱신 (청산 시 해제)
+                if let Some(lock) = instance.symbol_lock {
+                    if position.is_open() {
+                        symbol_locks.claim(id, lock.policy, &position.ticker, position.side);
+                    } else {
+                        symbol_locks.release(id, &position.ticker);
+                    }
+                }
+            }
+
+            if !instance.running {
+                continue;
+            }
+
+            if let Err(e) = instance.strategy.on_position_update(position).await {
+                instance.stats.last_error = Some(e.to_string());
+                error!(
+                    strategy_id = %id,
+                    error = %e,
+                    "Strategy error handling position update"
+                );
+            }
+        }
+        drop(symbol_locks);
+        drop(strategies);
+
+        self.persist_states(None).await;
+
+        Ok(())
+    }
+
+    /// 종목 거래 상태 변경(거래정지, VI) 반영.
+    ///
+    /// 모든 전략(섀도 포함)의 컨텍스트를 갱신하고, 실행 중인 전략의
+    /// `on_trading_status()`를 호출합니다.
+    ///
+    /// # Returns
+    ///
+    /// 콜백을 받은 실행 중 전략 수
+    pub async fn update_trading_status(&self, event: &TradingStatusEvent) -> usize {
+        {
+            let mut statuses = self.trading_statuses.write().await;
+            if event.status.is_restricted() {
+                statuses.insert(event.ticker.clone(), event.clone());
+            } else if statuses.remove(&event.ticker).is_none() {
+                // 기록 없는 종목의 정상 상태 알림은 무시
+                return 0;
+            }
+        }
+
+        let mut notified = 0;
+        let mut strategies = self.strategies.write().await;
+
+        for (id, instance) in strategies.iter_mut() {
+            Self::apply_trading_status(id, instance, event).await;
+            if let Some(shadow) = instance.shadow.as_mut() {
+                Self::apply_trading_status(id, &mut shadow.instance, event).await;
+            }
+            if instance.running {
+                notified += 1;
+            }
+        }
+
+        info!(
+            ticker = %event.ticker,
+            status = %event.status,
+            notified,
+            "Trading status updated"
+        );
+        notified
+    }
+
+    /// 전략 인스턴스에 거래 상태 반영 (컨텍스트 갱신 후 실행 중이면 콜백 호출).
+    async fn apply_trading_status(
+        id: &str,
+        instance: &mut StrategyInstance,
+        event: &TradingStatusEvent,
+    ) {
+        instance
+            .context
+            .write()
+            .await
+            .update_trading_status(event.clone());
+
+        if !instance.running {
+            return;
+        }
+        if let Err(e) = instance.strategy.on_trading_status(event).await {
+            instance.stats.last_error = Some(e.to_string());
+            error!(
+                strategy_id = %id,
+                error = %e,
+                "Strategy error handling trading status"
+            );
+        }
+    }
+
+    /// 실시간 시세 스트림 상태 변경을 실행 중인 전략에 전달.
+    ///
+    /// # Returns
+    ///
+    /// 콜백을 받은 실행 중 전략 수
+    pub async fn update_stream_status(&self, status: &StreamStatus) -> usize {
+        let mut notified = 0;
+        let mut strategies = self.strategies.write().await;
+
+        for (id, instance) in strategies.iter_mut() {
+            Self::apply_stream_status(id, instance, status).await;
+            if let Some(shadow) = instance.shadow.as_mut() {
+                Self::apply_stream_status(id, &mut shadow.instance, status).await;
+            }
+            if instance.running {
+                notified += 1;
+            }
+        }
+
+        info!(
+            source = %status.source,
+            state = %status.state,
+            notified,
+            "Stream status delivered to strategies"
+        );
+        notified
+    }
+
+    /// 실행 중인 전략 인스턴스에 스트림 상태 콜백 호출.
+    async fn apply_stream_status(id: &str, instance: &mut StrategyInstance, status: &StreamStatus) {
+        if !instance.running {
+            return;
+        }
+        if let Err(e) = instance.strategy.on_stream_status(status).await {
+            instance.stats.last_error = Some(e.to_string());
+            error!(
+                strategy_id = %id,
+                error = %e,
+                "Strategy error handling stream status"
+            );
+        }
+    }
+
+    /// 거래정지/VI 중인 종목 목록.
+    pub async fn get_trading_statuses(&self) -> Vec<TradingStatusEvent> {
+        self.trading_statuses
+            .read()
+            .await
+            .values()
+            .cloned()
+            .collect()
+    }
+
+    /// 브로커 점검/장애 상태 반영.
+    ///
+    /// 모든 전략(섀도 포함)의 컨텍스트 `broker_unavailable` 플래그를 갱신합니다.
+    /// 전략은 주문 실패 대신 이 플래그로 브로커 점검/장애를 확인할 수 있습니다.
+    ///
+    /// # Returns
+    ///
+    /// 컨텍스트를 갱신한 전략 수 (상태가 바뀌지 않았으면 0)
+    pub async fn update_broker_downtime(&self, downtime: Option<BrokerDowntime>) -> usize {
+        {
+            let mut current = self.broker_downtime.write().await;
+            if *current == downtime {
+                return 0;
+            }
+            current.clone_from(&downtime);
+        }
+
+        let strategies = self.strategies.read().await;
+        for instance in strategies.values() {
+            instance
+                .context
+                .write()
+                .await
+                .update_broker_downtime(downtime.clone());
+            if let Some(shadow) = instance.shadow.as_ref() {
+                shadow
+                    .instance
+                    .context
+                    .write()
+                    .await
+                    .update_broker_downtime(downtime.clone());
+            }
+        }
+
+        match &downtime {
+            Some(d) => warn!(
+                broker = %d.broker,
+                source = %d.source,
+                strategies = strategies.len(),
+                "Broker unavailable: {}",
+                d.describe()
+            ),
+            None => info!(strategies = strategies.len(), "Broker available again"),
+        }
+        strategies.len()
+    }
+
+    /// 진행 중인 브로커 점검/장애.
+    pub async fn broker_downtime(&self) -> Option<BrokerDowntime> {
+        self.broker_downtime.read().await.clone()
+    }
+
+    /// 새 전략의 초기 컨텍스트 (현재 거래정지/VI 종목, 브로커 점검/장애 포함).
+    async fn initial_context(&self) -> StrategyContext {
+        let mut context = StrategyContext {
+            trading_statuses: self.trading_statuses.read().await.clone(),
+            ..StrategyContext::default()
+        };
+        context.update_broker_downtime(self.broker_downtime.read().await.clone());
+        context
+    }
+
+    /// 엔진 메인 루프 시작.
+    pub async fn run(&self) -> Result<(), EngineError> {
+        {
+            let mut running = self.running.write().await;
+            if *running {
+                return Err(EngineError::AlreadyRunning("Engine".to_string()));
+            }
+            *running = true;
+        }
+
+        info!("Strategy engine started");
+
+        let mut market_data_rx = self.market_data_tx.subscribe();
+
+        loop {
             tokio::select! {
                 result = market_data_rx.recv() => {
                     match result {
@@ -896,28 +2003,546 @@ impl StrategyEngine {
         // 새 설정 저장 (name 필드 제외)
         instance.config = config_for_strategy.clone();
 
-        // 실행 중이면 전략 재초기화
+        // 실행 중이면 전략 재초기화 (진행 중인 상태는 유지)
         if instance.running {
             info!(strategy_id = %id, "Hot reloading strategy configuration");
 
+            let snapshot = instance.strategy.save_state().unwrap_or_default();
+
             instance
                 .strategy
                 .initialize(config_for_strategy)
                 .await
                 .map_err(|e| EngineError::InitializationFailed(e.to_string()))?;
+
+            if !snapshot.is_empty() {
+                Self::restore_state(id, instance, &snapshot);
+            }
+
+            if let Some(shadow) = instance.shadow.as_mut() {
+                Self::initialize_shadow(id, shadow, &instance.config).await;
+            }
         }
 
         Ok(())
     }
-}
 
-/// 엔진 통계.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct EngineStats {
-    /// 등록된 전략 총 수
-    pub total_strategies: usize,
-    /// 현재 실행 중인 전략 수
-    pub running_strategies: usize,
+    /// 전략의 실적 발표 필터 설정 (`None`이면 해제).
+    pub async fn set_strategy_earnings_filter(
+        &self,
+        id: &str,
+        filter: Option<EarningsFilterConfig>,
+    ) -> Result<(), EngineError> {
+        let mut strategies = self.strategies.write().await;
+        let instance = strategies
+            .get_mut(id)
+            .ok_or_else(|| EngineError::StrategyNotFound(id.to_string()))?;
+
+        instance.earnings_filter = filter;
+        info!(strategy_id = %id, filter = ?filter, "Updated earnings filter");
+        Ok(())
+    }
+
+    /// 전략의 실적 발표 필터 조회.
+    pub async fn get_strategy_earnings_filter(
+        &self,
+        id: &str,
+    ) -> Result<Option<EarningsFilterConfig>, EngineError> {
+        let strategies = self.strategies.read().await;
+        strategies
+            .get(id)
+            .map(|instance| instance.earnings_filter)
+            .ok_or_else(|| EngineError::StrategyNotFound(id.to_string()))
+    }
+
+    /// 전략의 거래 시간대 설정 (`None`이면 해제).
+    pub async fn set_strategy_trading_window(
+        &self,
+        id: &str,
+        window: Option<TradingWindowConfig>,
+    ) -> Result<(), EngineError> {
+        let mut strategies = self.strategies.write().await;
+        let instance = strategies
+            .get_mut(id)
+            .ok_or_else(|| EngineError::StrategyNotFound(id.to_string()))?;
+
+        info!(strategy_id = %id, window = ?window, "Updated trading window");
+        instance.trading_window = window;
+        instance.window_exits.clear();
+        Ok(())
+    }
+
+    /// 전략의 거래 시간대 조회.
+    pub async fn get_strategy_trading_window(
+        &self,
+        id: &str,
+    ) -> Result<Option<TradingWindowConfig>, EngineError> {
+        let strategies = self.strategies.read().await;
+        strategies
+            .get(id)
+            .map(|instance| instance.trading_window.clone())
+            .ok_or_else(|| EngineError::StrategyNotFound(id.to_string()))
+    }
+
+    /// 전략 모의투자 모드 설정.
+    ///
+    /// 모의투자 중인 전략은 `process_market_data()`에서 제외되어 신호가 출력 채널로
+    /// 전송되지 않으며, 종목 잠금도 점유하지 않습니다 (켜는 시점에 점유 중인 종목은 해제).
+    pub async fn set_strategy_paper_trading(
+        &self,
+        id: &str,
+        enabled: bool,
+    ) -> Result<(), EngineError> {
+        let mut strategies = self.strategies.write().await;
+        let instance = strategies
+            .get_mut(id)
+            .ok_or_else(|| EngineError::StrategyNotFound(id.to_string()))?;
+
+        instance.paper_trading = enabled;
+        if enabled {
+            self.symbol_locks.write().await.release_all(id);
+        }
+        info!(strategy_id = %id, enabled, "Updated paper trading mode");
+        Ok(())
+    }
+
+    /// 모의투자 모드인 전략 ID 목록.
+    pub async fn paper_trading_strategies(&self) -> Vec<String> {
+        let strategies = self.strategies.read().await;
+        strategies
+            .iter()
+            .filter(|(_, instance)| instance.paper_trading)
+            .map(|(id, _)| id.clone())
+            .collect()
+    }
+
+    /// 모의투자 전략에 시장 데이터 공급.
+    ///
+    /// 실행 중인 모의투자 전략만 처리하며, 거래 시간대와 실적 발표 필터는 실거래와 같게
+    /// 적용합니다.
+    /// 신호는 출력 채널로 전송하지 않고 (전략 ID, 신호) 목록으로 반환합니다.
+    pub async fn process_paper_trading(&self, data: &MarketData) -> Vec<(String, Signal)> {
+        let trading_days = self.window_trading_days(data.timestamp).await;
+        let mut strategies = self.strategies.write().await;
+        let earnings_calendar = self.earnings_calendar.read().await;
+        let today = data.timestamp.date_naive();
+        let mut paper_signals = Vec::new();
+
+        for (id, instance) in strategies.iter_mut() {
+            if !instance.running || instance.paused || !instance.paper_trading {
+                continue;
+            }
+
+            if let Some(exits) = Self::apply_trading_window(id, instance, data, &trading_days) {
+                for signal in exits {
+                    instance.stats.signals_generated += 1;
+                    instance.stats.last_signal_time = Some(Utc::now());
+                    paper_signals.push((id.clone(), signal));
+                }
+                continue;
+            }
+
+            let result = if let Some(mtf_config) = instance.strategy.metadata().timeframes {
+                self.process_multi_timeframe_data(instance, data, &mtf_config)
+                    .await
+            } else {
+                instance.strategy.on_market_data(data).await
+            };
+
+            match result {
+                Ok(signals) => {
+                    instance.stats.market_data_processed += 1;
+                    let signals = Self::apply_earnings_filter(
+                        id,
+                        instance,
+                        signals,
+                        &data.ticker,
+                        &earnings_calendar,
+                        today,
+                    );
+                    for signal in signals {
+                        instance.stats.signals_generated += 1;
+                        instance.stats.last_signal_time = Some(Utc::now());
+                        paper_signals.push((id.clone(), signal));
+                    }
+                }
+                Err(e) => {
+                    instance.stats.last_error = Some(e.to_string());
+                    warn!(
+                        strategy_id = %id,
+                        error = %e,
+                        "Paper trading strategy error processing market data"
+                    );
+                }
+            }
+        }
+        drop(earnings_calendar);
+        drop(strategies);
+
+        self.persist_states(None).await;
+
+        paper_signals
+    }
+
+    /// 전략의 종목 잠금 설정 조회.
+    pub async fn get_strategy_symbol_lock(
+        &self,
+        id: &str,
+    ) -> Result<Option<SymbolLockConfig>, EngineError> {
+        let strategies = self.strategies.read().await;
+        strategies
+            .get(id)
+            .map(|instance| instance.symbol_lock)
+            .ok_or_else(|| EngineError::StrategyNotFound(id.to_string()))
+    }
+
+    /// 종목을 점유 중인 전략 목록.
+    pub async fn symbol_lock_holders(&self, ticker: &str) -> Vec<SymbolClaim> {
+        self.symbol_locks.read().await.holders(ticker).to_vec()
+    }
+
+    /// 실적 발표 일정 교체.
+    ///
+    /// 종목별로 기준일 이후 가장 가까운 일정만 유지합니다.
+    ///
+    /// # Returns
+    ///
+    /// 일정이 등록된 종목 수
+    pub async fn update_earnings_calendar(
+        &self,
+        events: &[EarningsEvent],
+        today: NaiveDate,
+    ) -> usize {
+        let mut by_ticker: HashMap<&str, Vec<&EarningsEvent>> = HashMap::new();
+        for event in events {
+            by_ticker
+                .entry(event.ticker.as_str())
+                .or_default()
+                .push(event);
+        }
+
+        let calendar: HashMap<String, EarningsEvent> = by_ticker
+            .into_iter()
+            .filter_map(|(ticker, events)| {
+                next_earnings(events, today).map(|e| (ticker.to_string(), e.clone()))
+            })
+            .collect();
+
+        let count = calendar.len();
+        *self.earnings_calendar.write().await = calendar;
+        count
+    }
+
+    /// 종목의 다음 실적 발표 일정 조회.
+    pub async fn get_next_earnings(&self, ticker: &str) -> Option<EarningsEvent> {
+        self.earnings_calendar.read().await.get(ticker).cloned()
+    }
+
+    /// 섀도 비교 인스턴스 연결.
+    ///
+    /// `strategy`는 실거래 전략과 같은 타입의 새 인스턴스여야 합니다.
+    /// 이미 섀도가 있으면 교체하며, 실거래 전략이 실행 중이면 같은 설정으로 바로 초기화합니다.
+    pub async fn attach_shadow(
+        &self,
+        id: &str,
+        mut strategy: Box<dyn Strategy>,
+        config: ShadowConfig,
+    ) -> Result<(), EngineError> {
+        let mut strategies = self.strategies.write().await;
+
+        let instance = strategies
+            .get_mut(id)
+            .ok_or_else(|| EngineError::StrategyNotFound(id.to_string()))?;
+
+        let context = Arc::new(RwLock::new(self.initial_context().await));
+        strategy.set_context(Arc::clone(&context));
+
+        let mut shadow = Box::new(ShadowInstance {
+            instance: StrategyInstance {
+                strategy,
+                config: instance.config.clone(),
+                running: false,
+                stats: StrategyStats::default(),
+                custom_name: None,
+                context,
+                shadow: None,
+                earnings_filter: None,
+                open_positions: HashMap::new(),
+                earnings_exits: HashSet::new(),
+                symbol_lock: None,
+                paper_trading: false,
+                saved_state: None,
+                paused: false,
+                trading_window: None,
+                window_exits: HashSet::new(),
+                fed_timeframes: HashSet::new(),
+            },
+            tracker: ShadowTracker::new(config),
+        });
+
+        if instance.running {
+            Self::initialize_shadow(id, &mut shadow, &instance.config).await;
+        }
+
+        instance.shadow = Some(shadow);
+        info!(strategy_id = %id, "Attached shadow comparison instance");
+        Ok(())
+    }
+
+    /// 섀도 비교 인스턴스 해제.
+    ///
+    /// # Returns
+    ///
+    /// 섀도가 연결되어 있었으면 true
+    pub async fn detach_shadow(&self, id: &str) -> Result<bool, EngineError> {
+        let mut strategies = self.strategies.write().await;
+
+        let instance = strategies
+            .get_mut(id)
+            .ok_or_else(|| EngineError::StrategyNotFound(id.to_string()))?;
+
+        let detached = instance.shadow.take().is_some();
+        if detached {
+            info!(strategy_id = %id, "Detached shadow comparison instance");
+        }
+        Ok(detached)
+    }
+
+    /// 섀도가 연결된 전략 ID 목록.
+    pub async fn shadowed_strategies(&self) -> Vec<String> {
+        let strategies = self.strategies.read().await;
+        strategies
+            .iter()
+            .filter(|(_, instance)| instance.shadow.is_some())
+            .map(|(id, _)| id.clone())
+            .collect()
+    }
+
+    /// 전략의 섀도 비교 리포트 (섀도가 없으면 None).
+    pub async fn shadow_report(&self, id: &str) -> Option<ShadowReport> {
+        let strategies = self.strategies.read().await;
+        strategies
+            .get(id)?
+            .shadow
+            .as_ref()
+            .map(|shadow| shadow.tracker.report(id))
+    }
+
+    /// 모든 섀도 비교 리포트.
+    pub async fn shadow_reports(&self) -> Vec<ShadowReport> {
+        let strategies = self.strategies.read().await;
+        strategies
+            .iter()
+            .filter_map(|(id, instance)| {
+                instance
+                    .shadow
+                    .as_ref()
+                    .map(|shadow| shadow.tracker.report(id))
+            })
+            .collect()
+    }
+
+    /// 실거래 체결을 섀도 체결가와 비교.
+    ///
+    /// # Returns
+    ///
+    /// 체결가 괴리 (bp). 섀도가 없거나 매칭된 신호가 없으면 None.
+    pub async fn record_live_fill(
+        &self,
+        id: &str,
+        ticker: &str,
+        side: Side,
+        price: Decimal,
+        at: DateTime<Utc>,
+    ) -> Option<f64> {
+        let mut strategies = self.strategies.write().await;
+        strategies
+            .get_mut(id)?
+            .shadow
+            .as_mut()?
+            .tracker
+            .record_live_fill(ticker, side, price, at)
+    }
+
+    /// 전략 경쟁 참가자 추가.
+    ///
+    /// 참가자는 등록 즉시 `config`로 초기화되어 다음 시장 데이터부터 모의 운용됩니다.
+    /// 초기화에 실패해도 참가자는 남겨두며, 스냅샷의 `last_error`로 확인할 수 있습니다.
+    pub async fn add_competitor(
+        &self,
+        competitor_id: impl Into<String>,
+        competition_id: impl Into<String>,
+        mut strategy: Box<dyn Strategy>,
+        config: Value,
+        book: PaperBookConfig,
+    ) -> Result<(), EngineError> {
+        let competitor_id = competitor_id.into();
+        let mut competitors = self.competitors.write().await;
+
+        if competitors.contains_key(&competitor_id) {
+            return Err(EngineError::StrategyAlreadyExists(competitor_id));
+        }
+
+        let context = Arc::new(RwLock::new(self.initial_context().await));
+        strategy.set_context(Arc::clone(&context));
+
+        let mut instance = StrategyInstance {
+            strategy,
+            config: config.clone(),
+            running: false,
+            stats: StrategyStats::default(),
+            custom_name: None,
+            context,
+            shadow: None,
+            earnings_filter: None,
+            open_positions: HashMap::new(),
+            earnings_exits: HashSet::new(),
+            symbol_lock: None,
+            paper_trading: false,
+            saved_state: None,
+            paused: false,
+            trading_window: None,
+            window_exits: HashSet::new(),
+            fed_timeframes: HashSet::new(),
+        };
+
+        match instance.strategy.initialize(config).await {
+            Ok(()) => {
+                instance.running = true;
+                instance.stats.started_at = Some(Utc::now());
+            }
+            Err(e) => {
+                instance.stats.last_error = Some(e.to_string());
+                warn!(competitor_id = %competitor_id, error = %e, "Competitor initialization failed");
+            }
+        }
+
+        let competition_id = competition_id.into();
+        info!(
+            competitor_id = %competitor_id,
+            competition_id = %competition_id,
+            "Added competition entrant"
+        );
+        competitors.insert(
+            competitor_id,
+            CompetitorInstance {
+                competition_id,
+                instance,
+                book: PaperBook::new(book),
+            },
+        );
+        Ok(())
+    }
+
+    /// 전략 경쟁 참가자 제거.
+    ///
+    /// # Returns
+    ///
+    /// 참가자가 있었으면 true
+    pub async fn remove_competitor(&self, competitor_id: &str) -> bool {
+        let removed = self.competitors.write().await.remove(competitor_id);
+        match removed {
+            Some(mut competitor) => {
+                if let Err(e) = competitor.instance.strategy.shutdown().await {
+                    warn!(competitor_id = %competitor_id, error = %e, "Error during competitor shutdown");
+                }
+                info!(competitor_id = %competitor_id, "Removed competition entrant");
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// 경쟁 참가자 현재 상태 (`competition_id`가 None이면 전체).
+    pub async fn competitor_snapshots(
+        &self,
+        competition_id: Option<&str>,
+    ) -> Vec<CompetitorSnapshot> {
+        let competitors = self.competitors.read().await;
+        competitors
+            .iter()
+            .filter(|(_, c)| competition_id.map_or(true, |id| c.competition_id == id))
+            .map(|(id, c)| CompetitorSnapshot {
+                competitor_id: id.clone(),
+                competition_id: c.competition_id.clone(),
+                running: c.instance.running,
+                equity: c.book.equity(),
+                cash: c.book.cash(),
+                open_positions: c.book.positions().len(),
+                trades: c.book.trades(),
+                last_data_at: c.book.last_data_at(),
+                last_error: c.instance.stats.last_error.clone(),
+            })
+            .collect()
+    }
+
+    /// 경쟁 참가자에 시장 데이터 공급 및 가상 체결.
+    async fn process_competitors(&self, data: &MarketData) {
+        let mut competitors = self.competitors.write().await;
+        let price = data.get_price();
+
+        for (id, competitor) in competitors.iter_mut() {
+            let instance = &mut competitor.instance;
+            if !instance.running {
+                continue;
+            }
+
+            let result = if let Some(mtf_config) = instance.strategy.metadata().timeframes {
+                self.process_multi_timeframe_data(instance, data, &mtf_config)
+                    .await
+            } else {
+                instance.strategy.on_market_data(data).await
+            };
+
+            let signals = match result {
+                Ok(signals) => {
+                    instance.stats.market_data_processed += 1;
+                    instance.stats.signals_generated += signals.len() as u64;
+                    signals
+                }
+                Err(e) => {
+                    instance.stats.last_error = Some(e.to_string());
+                    warn!(
+                        competitor_id = %id,
+                        error = %e,
+                        "Competitor error processing market data"
+                    );
+                    Vec::new()
+                }
+            };
+
+            competitor
+                .book
+                .apply(&data.ticker, price, &signals, data.timestamp);
+        }
+    }
+
+    /// 섀도 인스턴스를 실거래 설정으로 (재)초기화.
+    ///
+    /// 섀도 초기화 실패는 실거래에 영향을 주지 않도록 로그만 남기고 섀도를 중지합니다.
+    async fn initialize_shadow(id: &str, shadow: &mut ShadowInstance, config: &Value) {
+        shadow.instance.config = config.clone();
+        match shadow.instance.strategy.initialize(config.clone()).await {
+            Ok(()) => {
+                shadow.instance.running = true;
+                shadow.instance.stats.started_at = Some(Utc::now());
+            }
+            Err(e) => {
+                shadow.instance.running = false;
+                shadow.instance.stats.last_error = Some(e.to_string());
+                warn!(strategy_id = %id, error = %e, "Shadow strategy initialization failed");
+            }
+        }
+    }
+}
+
+/// 엔진 통계.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EngineStats {
+    /// 등록된 전략 총 수
+    pub total_strategies: usize,
+    /// 현재 실행 중인 전략 수
+    pub running_strategies: usize,
     /// 모든 전략에서 생성된 총 신호 수
     pub total_signals_generated: u64,
     /// 총 체결된 주문 수
@@ -935,6 +2560,9 @@ mod tests {
     struct TestStrategy {
         name: String,
         signal_count: u32,
+        trading_status_count: u32,
+        stream_status_count: u32,
+        warmup: Option<MultiTimeframeConfig>,
     }
 
     impl TestStrategy {
@@ -942,6 +2570,9 @@ mod tests {
             Self {
                 name: name.to_string(),
                 signal_count: 0,
+                trading_status_count: 0,
+                stream_status_count: 0,
+                warmup: None,
             }
         }
     }
@@ -996,28 +2627,416 @@ mod tests {
             Ok(())
         }
 
+        async fn on_trading_status(
+            &mut self,
+            _event: &TradingStatusEvent,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            self.trading_status_count += 1;
+            Ok(())
+        }
+
+        async fn on_stream_status(
+            &mut self,
+            _status: &trader_core::StreamStatus,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            self.stream_status_count += 1;
+            Ok(())
+        }
+
         async fn shutdown(&mut self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
             Ok(())
         }
 
         fn get_state(&self) -> Value {
             serde_json::json!({
-                "signal_count": self.signal_count
+                "signal_count": self.signal_count,
+                "trading_status_count": self.trading_status_count,
+                "stream_status_count": self.stream_status_count
             })
         }
-    }
 
-    #[tokio::test]
-    async fn test_engine_register_strategy() {
-        let engine = StrategyEngine::new(EngineConfig::default());
+        fn warmup_config(&self) -> Option<MultiTimeframeConfig> {
+            self.warmup.clone()
+        }
 
-        let strategy = Box::new(TestStrategy::new("test"));
-        engine
-            .register_strategy("test1", strategy, serde_json::json!({}), None)
-            .await
-            .unwrap();
+        fn save_state(&self) -> Result<Vec<u8>, Box<dyn std::error::Error + Send + Sync>> {
+            Ok(self.signal_count.to_le_bytes().to_vec())
+        }
 
-        let strategies = engine.list_strategies().await;
+        fn load_state(
+            &mut self,
+            data: &[u8],
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            self.signal_count = u32::from_le_bytes(data.try_into()?);
+            Ok(())
+        }
+    }
+
+    /// 메모리 기반 전략 상태 저장소.
+    #[derive(Default)]
+    struct MemoryStateStore {
+        states: std::sync::Mutex<HashMap<String, StrategyState>>,
+    }
+
+    #[async_trait]
+    impl StrategyStateStore for MemoryStateStore {
+        async fn load(
+            &self,
+            strategy_id: &str,
+        ) -> Result<Option<StrategyState>, Box<dyn std::error::Error + Send + Sync>> {
+            Ok(self.states.lock().unwrap().get(strategy_id).cloned())
+        }
+
+        async fn save(
+            &self,
+            state: &StrategyState,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            self.states
+                .lock()
+                .unwrap()
+                .insert(state.strategy_id.clone(), state.clone());
+            Ok(())
+        }
+
+        async fn delete(
+            &self,
+            strategy_id: &str,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            self.states.lock().unwrap().remove(strategy_id);
+            Ok(())
+        }
+    }
+
+    /// 타임프레임별로 요청한 개수만큼 캔들을 돌려주는 워밍업 공급자.
+    struct TestWarmupSource;
+
+    #[async_trait]
+    impl WarmupDataSource for TestWarmupSource {
+        async fn fetch_klines(
+            &self,
+            ticker: &str,
+            config: &MultiTimeframeConfig,
+        ) -> Result<HashMap<Timeframe, Vec<Kline>>, Box<dyn std::error::Error + Send + Sync>>
+        {
+            if ticker == "UNKNOWN" {
+                return Err("no data".into());
+            }
+            let start = Utc::now() - chrono::Duration::days(400);
+            Ok(config
+                .timeframes
+                .iter()
+                .map(|(&timeframe, &count)| {
+                    let klines = (0..count as i64)
+                        .map(|i| {
+                            let open_time = start + chrono::Duration::days(i);
+                            Kline::new(
+                                format!("{}.KS", ticker),
+                                timeframe,
+                                open_time,
+                                rust_decimal_macros::dec!(100),
+                                rust_decimal_macros::dec!(100),
+                                rust_decimal_macros::dec!(100),
+                                rust_decimal_macros::dec!(100),
+                                rust_decimal_macros::dec!(1),
+                                open_time + chrono::Duration::days(1),
+                            )
+                        })
+                        .collect();
+                    (timeframe, klines)
+                })
+                .collect())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_start_strategy_warmup() {
+        let mut engine = StrategyEngine::new(EngineConfig::default());
+        engine.set_warmup_source(Arc::new(TestWarmupSource));
+
+        let mut strategy = TestStrategy::new("warmup");
+        strategy.warmup = Some(
+            MultiTimeframeConfig::new()
+                .with_timeframe(Timeframe::D1, 25)
+                .with_timeframe(Timeframe::W1, 4)
+                .with_primary(Timeframe::D1),
+        );
+        engine
+            .register_strategy(
+                "warmup1",
+                Box::new(strategy),
+                serde_json::json!({ "symbols": ["005930", "UNKNOWN"] }),
+                None,
+            )
+            .await
+            .unwrap();
+
+        engine.start_strategy("warmup1").await.unwrap();
+
+        // 일봉 25개 재생, 조회 실패한 티커는 건너뜀
+        let status = engine.get_strategy_status("warmup1").await.unwrap();
+        assert!(status.running);
+        assert_eq!(status.stats.warmup_bars, 25);
+        assert_eq!(status.state["signal_count"], 25);
+        // 워밍업 중 생성된 신호는 집계/전송하지 않음
+        assert_eq!(status.stats.signals_generated, 0);
+
+        let context = engine.get_strategy_context("warmup1").await.unwrap();
+        let ctx = context.read().await;
+        assert_eq!(ctx.get_klines("005930", Timeframe::D1).len(), 25);
+        assert_eq!(ctx.get_klines("005930", Timeframe::W1).len(), 4);
+
+        // 워밍업을 선언하지 않은 전략은 그대로 시작
+        engine
+            .register_strategy(
+                "plain",
+                Box::new(TestStrategy::new("plain")),
+                serde_json::json!({ "ticker": "005930" }),
+                None,
+            )
+            .await
+            .unwrap();
+        engine.start_strategy("plain").await.unwrap();
+        let status = engine.get_strategy_status("plain").await.unwrap();
+        assert_eq!(status.stats.warmup_bars, 0);
+    }
+
+    /// 일봉 + 주봉을 메타데이터로 선언하고 전달받은 완료 주봉 수를 기록하는 전략.
+    struct WeeklyStrategy {
+        weekly_seen: Arc<std::sync::Mutex<Vec<usize>>>,
+    }
+
+    #[async_trait]
+    impl Strategy for WeeklyStrategy {
+        fn name(&self) -> &str {
+            "weekly"
+        }
+
+        fn version(&self) -> &str {
+            "1.0.0"
+        }
+
+        fn description(&self) -> &str {
+            "Daily strategy with a weekly trend buffer"
+        }
+
+        fn metadata(&self) -> crate::StrategyMetadata {
+            crate::StrategyMetadata {
+                name: self.name().to_string(),
+                version: self.version().to_string(),
+                description: self.description().to_string(),
+                required_config: Vec::new(),
+                supported_tickers: Vec::new(),
+                timeframes: Some(
+                    MultiTimeframeConfig::new()
+                        .with_timeframe(Timeframe::D1, 30)
+                        .with_timeframe(Timeframe::W1, 4)
+                        .with_primary(Timeframe::D1),
+                ),
+            }
+        }
+
+        async fn initialize(
+            &mut self,
+            _config: Value,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        async fn on_market_data(
+            &mut self,
+            _data: &MarketData,
+        ) -> Result<Vec<Signal>, Box<dyn std::error::Error + Send + Sync>> {
+            Ok(vec![])
+        }
+
+        async fn on_multi_timeframe_data(
+            &mut self,
+            _primary_data: &MarketData,
+            secondary_data: &HashMap<Timeframe, Vec<Kline>>,
+        ) -> Result<Vec<Signal>, Box<dyn std::error::Error + Send + Sync>> {
+            let weeks = secondary_data.get(&Timeframe::W1).map_or(0, Vec::len);
+            self.weekly_seen.lock().unwrap().push(weeks);
+            Ok(vec![])
+        }
+
+        async fn on_order_filled(
+            &mut self,
+            _order: &Order,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        async fn on_position_update(
+            &mut self,
+            _position: &Position,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        async fn shutdown(&mut self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        fn get_state(&self) -> Value {
+            serde_json::json!({})
+        }
+    }
+
+    #[tokio::test]
+    async fn test_declared_timeframes_build_aligned_buffers() {
+        let engine = StrategyEngine::new(EngineConfig::default());
+        let weekly_seen = Arc::new(std::sync::Mutex::new(Vec::new()));
+        engine
+            .register_strategy(
+                "weekly",
+                Box::new(WeeklyStrategy {
+                    weekly_seen: Arc::clone(&weekly_seen),
+                }),
+                serde_json::json!({}),
+                None,
+            )
+            .await
+            .unwrap();
+        engine.start_strategy("weekly").await.unwrap();
+
+        // 2024-01-01(월)부터 일봉 10개 (주봉은 일봉으로 집계)
+        let kline = |timeframe: Timeframe, open_time: DateTime<Utc>, days: i64| {
+            Kline::new(
+                "005930".to_string(),
+                timeframe,
+                open_time,
+                rust_decimal_macros::dec!(100),
+                rust_decimal_macros::dec!(100) + Decimal::from(days),
+                rust_decimal_macros::dec!(100),
+                rust_decimal_macros::dec!(100),
+                rust_decimal_macros::dec!(1) * Decimal::from(days),
+                open_time + chrono::Duration::days(days),
+            )
+        };
+        let monday: DateTime<Utc> = "2024-01-01T00:00:00Z".parse().unwrap();
+        for day in 0..10 {
+            let open_time = monday + chrono::Duration::days(day);
+            let data = MarketData::from_kline("test", kline(Timeframe::D1, open_time, 1));
+            engine.process_market_data(data).await.unwrap();
+        }
+
+        let context = engine.get_strategy_context("weekly").await.unwrap();
+        let weekly = context
+            .read()
+            .await
+            .get_klines("005930", Timeframe::W1)
+            .to_vec();
+        assert_eq!(weekly.len(), 2);
+        assert_eq!(weekly[0].open_time, monday);
+        assert_eq!(weekly[0].close_time, monday + chrono::Duration::days(7));
+        assert_eq!(weekly[0].volume, rust_decimal_macros::dec!(7));
+        assert_eq!(weekly[1].volume, rust_decimal_macros::dec!(3));
+
+        // 주봉은 일요일 일봉이 끝나야 완료되어 전달됨 (진행 중인 주봉 제외)
+        assert_eq!(
+            *weekly_seen.lock().unwrap(),
+            vec![0, 0, 0, 0, 0, 0, 1, 1, 1, 1]
+        );
+
+        // 피드가 주봉을 직접 주면 더 이상 일봉으로 집계하지 않음
+        let second_week = monday + chrono::Duration::days(7);
+        let data = MarketData::from_kline("test", kline(Timeframe::W1, second_week, 7));
+        engine.process_market_data(data).await.unwrap();
+        let data = MarketData::from_kline(
+            "test",
+            kline(Timeframe::D1, monday + chrono::Duration::days(10), 1),
+        );
+        engine.process_market_data(data).await.unwrap();
+
+        let weekly = context
+            .read()
+            .await
+            .get_klines("005930", Timeframe::W1)
+            .to_vec();
+        assert_eq!(weekly.len(), 2);
+        assert_eq!(weekly[1].volume, rust_decimal_macros::dec!(7));
+    }
+
+    /// 1분봉 `bars`개를 엔진에 공급하고 출력된 신호를 반환.
+    async fn feed_klines(engine: &StrategyEngine, bars: i64) -> Vec<Signal> {
+        let start = Utc::now();
+        let mut signals = Vec::new();
+        for i in 0..bars {
+            let open_time = start + chrono::Duration::minutes(i);
+            let price = rust_decimal_macros::dec!(100);
+            let kline = Kline::new(
+                "005930".to_string(),
+                Timeframe::M1,
+                open_time,
+                price,
+                price,
+                price,
+                price,
+                rust_decimal_macros::dec!(1),
+                open_time + chrono::Duration::minutes(1),
+            );
+            let data = MarketData::from_kline("test", kline);
+            signals.extend(engine.process_market_data(data).await.unwrap());
+        }
+        signals
+    }
+
+    #[tokio::test]
+    async fn test_strategy_state_survives_restart() {
+        let store = Arc::new(MemoryStateStore::default());
+
+        // 첫 실행: 7개 처리 후 상태 저장
+        let mut engine = StrategyEngine::new(EngineConfig::default());
+        engine.set_state_store(store.clone());
+        engine
+            .register_strategy("s1", Box::new(TestStrategy::new("s1")), Value::Null, None)
+            .await
+            .unwrap();
+        engine.start_strategy("s1").await.unwrap();
+        assert!(feed_klines(&engine, 7).await.is_empty());
+
+        let saved = store.load("s1").await.unwrap().unwrap();
+        assert_eq!(saved.data, 7u32.to_le_bytes());
+        let status = engine.get_strategy_status("s1").await.unwrap();
+        assert!(status.stats.state_saved_at.is_some());
+        assert!(status.stats.state_restored_at.is_none());
+
+        // 재시작: 새 엔진에 같은 ID로 등록하면 초기화 후 저장된 상태에서 이어서 진행
+        let mut engine = StrategyEngine::new(EngineConfig::default());
+        engine.set_state_store(store.clone());
+        engine
+            .register_strategy("s1", Box::new(TestStrategy::new("s1")), Value::Null, None)
+            .await
+            .unwrap();
+        engine.start_strategy("s1").await.unwrap();
+        let status = engine.get_strategy_status("s1").await.unwrap();
+        assert_eq!(status.state["signal_count"], 7);
+        assert!(status.stats.state_restored_at.is_some());
+
+        // 10번째 데이터에서 신호 (상태가 없었다면 신호 없음)
+        assert_eq!(feed_klines(&engine, 3).await.len(), 1);
+
+        // 등록 해제 시 저장된 상태도 삭제
+        engine.stop_strategy("s1").await.unwrap();
+        assert_eq!(
+            store.load("s1").await.unwrap().unwrap().data,
+            10u32.to_le_bytes()
+        );
+        engine.unregister_strategy("s1").await.unwrap();
+        assert!(store.load("s1").await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_engine_register_strategy() {
+        let engine = StrategyEngine::new(EngineConfig::default());
+
+        let strategy = Box::new(TestStrategy::new("test"));
+        engine
+            .register_strategy("test1", strategy, serde_json::json!({}), None)
+            .await
+            .unwrap();
+
+        let strategies = engine.list_strategies().await;
         assert_eq!(strategies.len(), 1);
         assert!(strategies.contains(&"test1".to_string()));
     }
@@ -1064,6 +3083,217 @@ mod tests {
         assert!(!status.running);
     }
 
+    #[tokio::test]
+    async fn test_shadow_comparison() {
+        let engine = StrategyEngine::new(EngineConfig {
+            deduplicate_signals: false,
+            ..Default::default()
+        });
+        let shadow_config = ShadowConfig {
+            match_window_secs: 60,
+            ..Default::default()
+        };
+
+        for id in ["aligned", "drifted"] {
+            engine
+                .register_strategy(
+                    id,
+                    Box::new(TestStrategy::new(id)),
+                    serde_json::json!({}),
+                    None,
+                )
+                .await
+                .unwrap();
+        }
+        engine
+            .attach_shadow(
+                "aligned",
+                Box::new(TestStrategy::new("aligned")),
+                shadow_config.clone(),
+            )
+            .await
+            .unwrap();
+        // 섀도가 5캔들 앞서 신호를 내는 구현 편차
+        let mut drifted = TestStrategy::new("drifted");
+        drifted.signal_count = 5;
+        engine
+            .attach_shadow("drifted", Box::new(drifted), shadow_config)
+            .await
+            .unwrap();
+
+        engine.start_strategy("aligned").await.unwrap();
+        engine.start_strategy("drifted").await.unwrap();
+
+        let start = Utc::now();
+        let mut sent = 0;
+        for i in 0..20 {
+            let open_time = start + chrono::Duration::minutes(i);
+            let kline = Kline::new(
+                "005930".to_string(),
+                Timeframe::M1,
+                open_time,
+                rust_decimal_macros::dec!(100),
+                rust_decimal_macros::dec!(100),
+                rust_decimal_macros::dec!(100),
+                rust_decimal_macros::dec!(100),
+                rust_decimal_macros::dec!(1),
+                open_time + chrono::Duration::minutes(1),
+            );
+            sent += engine
+                .process_market_data(MarketData::from_kline("test", kline))
+                .await
+                .unwrap()
+                .len();
+        }
+        // 섀도 신호는 출력되지 않음
+        assert_eq!(sent, 4);
+
+        let aligned = engine.shadow_report("aligned").await.unwrap();
+        assert_eq!(aligned.stats.matched_signals, 2);
+        assert!(!aligned.has_drift());
+
+        let drifted = engine.shadow_report("drifted").await.unwrap();
+        assert_eq!(drifted.stats.matched_signals, 0);
+        assert_eq!(drifted.stats.missing_in_live, 2);
+        assert_eq!(drifted.stats.missing_in_shadow, 1);
+        assert!(drifted.has_drift());
+
+        assert_eq!(engine.shadow_reports().await.len(), 2);
+        assert!(engine.detach_shadow("drifted").await.unwrap());
+        assert!(engine.shadow_report("drifted").await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_competitors_trade_on_paper_book() {
+        let engine = StrategyEngine::new(EngineConfig::default());
+        let book = PaperBookConfig {
+            initial_capital: rust_decimal_macros::dec!(1000000),
+            position_size_pct: rust_decimal_macros::dec!(0.1),
+            fee_bps: Decimal::ZERO,
+        };
+        for id in ["entrant_a", "entrant_b"] {
+            engine
+                .add_competitor(
+                    id,
+                    "league",
+                    Box::new(TestStrategy::new(id)),
+                    serde_json::json!({}),
+                    book.clone(),
+                )
+                .await
+                .unwrap();
+        }
+        assert!(engine
+            .add_competitor(
+                "entrant_a",
+                "league",
+                Box::new(TestStrategy::new("entrant_a")),
+                serde_json::json!({}),
+                book,
+            )
+            .await
+            .is_err());
+
+        let start = Utc::now();
+        let mut sent = 0;
+        for i in 0..10 {
+            let open_time = start + chrono::Duration::minutes(i);
+            let price = rust_decimal_macros::dec!(100);
+            let kline = Kline::new(
+                "005930".to_string(),
+                Timeframe::M1,
+                open_time,
+                price,
+                price,
+                price,
+                price,
+                rust_decimal_macros::dec!(1),
+                open_time + chrono::Duration::minutes(1),
+            );
+            sent += engine
+                .process_market_data(MarketData::from_kline("test", kline))
+                .await
+                .unwrap()
+                .len();
+        }
+        // 참가자 신호는 출력되지 않고 가상 계좌에서만 체결
+        assert_eq!(sent, 0);
+
+        let snapshots = engine.competitor_snapshots(Some("league")).await;
+        assert_eq!(snapshots.len(), 2);
+        for snapshot in &snapshots {
+            assert!(snapshot.running);
+            assert_eq!(snapshot.trades, 1);
+            assert_eq!(snapshot.open_positions, 1);
+            assert_eq!(snapshot.cash, rust_decimal_macros::dec!(900000));
+            assert_eq!(snapshot.equity, rust_decimal_macros::dec!(1000000));
+        }
+        assert!(engine.competitor_snapshots(Some("other")).await.is_empty());
+
+        assert!(engine.remove_competitor("entrant_a").await);
+        assert!(!engine.remove_competitor("entrant_a").await);
+        assert_eq!(engine.competitor_snapshots(None).await.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_paper_trading_signals_bypass_output_channel() {
+        let engine = StrategyEngine::new(EngineConfig {
+            deduplicate_signals: false,
+            ..Default::default()
+        });
+        for id in ["live", "paper"] {
+            engine
+                .register_strategy(
+                    id,
+                    Box::new(TestStrategy::new(id)),
+                    serde_json::json!({}),
+                    None,
+                )
+                .await
+                .unwrap();
+            engine.start_strategy(id).await.unwrap();
+        }
+        engine
+            .set_strategy_paper_trading("paper", true)
+            .await
+            .unwrap();
+        assert!(engine
+            .set_strategy_paper_trading("missing", true)
+            .await
+            .is_err());
+        assert_eq!(engine.paper_trading_strategies().await, vec!["paper"]);
+
+        let start = Utc::now();
+        let (mut sent, mut paper) = (Vec::new(), Vec::new());
+        for i in 0..10 {
+            let open_time = start + chrono::Duration::minutes(i);
+            let price = rust_decimal_macros::dec!(100);
+            let kline = Kline::new(
+                "005930".to_string(),
+                Timeframe::M1,
+                open_time,
+                price,
+                price,
+                price,
+                price,
+                rust_decimal_macros::dec!(1),
+                open_time + chrono::Duration::minutes(1),
+            );
+            let data = MarketData::from_kline("test", kline);
+            paper.extend(engine.process_paper_trading(&data).await);
+            sent.extend(engine.process_market_data(data).await.unwrap());
+        }
+
+        // 실거래 전략 신호만 출력되고, 모의투자 신호는 전략 ID와 함께 반환
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].strategy_id, "live");
+        assert_eq!(paper.len(), 1);
+        assert_eq!(paper[0].0, "paper");
+        let status = engine.get_strategy_status("paper").await.unwrap();
+        assert!(status.paper_trading);
+        assert_eq!(status.stats.market_data_processed, 10);
+    }
+
     #[tokio::test]
     async fn test_duplicate_strategy_error() {
         let engine = StrategyEngine::new(EngineConfig::default());
@@ -1082,4 +3312,611 @@ mod tests {
 
         assert!(matches!(result, Err(EngineError::StrategyAlreadyExists(_))));
     }
+
+    #[tokio::test]
+    async fn test_process_external_signal() {
+        use crate::strategies::WebhookStrategy;
+
+        let mut engine = StrategyEngine::new(EngineConfig::default());
+        let mut signal_rx = engine.take_signal_receiver().unwrap();
+
+        engine
+            .register_strategy(
+                "tv_1",
+                Box::new(WebhookStrategy::new()),
+                serde_json::json!({}),
+                None,
+            )
+            .await
+            .unwrap();
+
+        let payload = serde_json::json!({ "ticker": "AAPL", "action": "buy", "price": 190 });
+
+        // 실행 중이 아니면 거부
+        let result = engine.process_external_signal("tv_1", &payload).await;
+        assert!(matches!(result, Err(EngineError::NotRunning(_))));
+
+        engine.start_strategy("tv_1").await.unwrap();
+        let signals = engine
+            .process_external_signal("tv_1", &payload)
+            .await
+            .unwrap();
+        assert_eq!(signals.len(), 1);
+        assert_eq!(signals[0].strategy_id, "tv_1");
+        assert_eq!(signal_rx.recv().await.unwrap().id, signals[0].id);
+
+        let invalid = serde_json::json!({ "ticker": "AAPL", "action": "hold" });
+        let result = engine.process_external_signal("tv_1", &invalid).await;
+        assert!(matches!(result, Err(EngineError::SignalRejected(_))));
+
+        let result = engine.process_external_signal("missing", &payload).await;
+        assert!(matches!(result, Err(EngineError::StrategyNotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_paper_external_signal_bypasses_output_channel() {
+        use crate::strategies::WebhookStrategy;
+
+        let mut engine = StrategyEngine::new(EngineConfig::default());
+        let mut signal_rx = engine.take_signal_receiver().unwrap();
+        engine
+            .register_strategy(
+                "tv_paper",
+                Box::new(WebhookStrategy::new()),
+                serde_json::json!({}),
+                None,
+            )
+            .await
+            .unwrap();
+        engine.start_strategy("tv_paper").await.unwrap();
+        engine
+            .set_strategy_paper_trading("tv_paper", true)
+            .await
+            .unwrap();
+
+        let payload = serde_json::json!({ "ticker": "AAPL", "action": "buy", "price": 190 });
+
+        // 모의투자 전략은 실거래 경로에서 거부
+        let result = engine.process_external_signal("tv_paper", &payload).await;
+        assert!(matches!(result, Err(EngineError::SignalRejected(_))));
+
+        let signals = engine
+            .process_paper_external_signal("tv_paper", &payload)
+            .await
+            .unwrap();
+        assert_eq!(signals.len(), 1);
+        assert_eq!(signals[0].strategy_id, "tv_paper");
+        assert!(signal_rx.try_recv().is_err());
+
+        // 실거래 전략은 모의투자 경로에서 거부
+        engine
+            .set_strategy_paper_trading("tv_paper", false)
+            .await
+            .unwrap();
+        let result = engine
+            .process_paper_external_signal("tv_paper", &payload)
+            .await;
+        assert!(matches!(result, Err(EngineError::SignalRejected(_))));
+    }
+
+    #[tokio::test]
+    async fn test_earnings_filter() {
+        let engine = StrategyEngine::new(EngineConfig {
+            deduplicate_signals: false,
+            ..Default::default()
+        });
+        engine
+            .register_strategy(
+                "earn",
+                Box::new(TestStrategy::new("earn")),
+                serde_json::json!({}),
+                None,
+            )
+            .await
+            .unwrap();
+        engine.start_strategy("earn").await.unwrap();
+        engine
+            .set_strategy_earnings_filter(
+                "earn",
+                Some(EarningsFilterConfig {
+                    entry_blackout_days: 3,
+                    exit_before_days: Some(1),
+                }),
+            )
+            .await
+            .unwrap();
+
+        let start: DateTime<Utc> = "2026-04-27T01:00:00Z".parse().unwrap();
+        let today = start.date_naive();
+        let earnings = |days: i64| EarningsEvent {
+            ticker: "005930".to_string(),
+            market: "KR".to_string(),
+            announce_date: today + chrono::Duration::days(days),
+            timing: trader_core::EarningsTiming::Unknown,
+            confirmed: false,
+            source: "dart".to_string(),
+        };
+        let kline_data = |i: i64| {
+            let open_time = start + chrono::Duration::seconds(i);
+            MarketData::from_kline(
+                "test",
+                Kline::new(
+                    "005930".to_string(),
+                    Timeframe::M1,
+                    open_time,
+                    rust_decimal_macros::dec!(100),
+                    rust_decimal_macros::dec!(100),
+                    rust_decimal_macros::dec!(100),
+                    rust_decimal_macros::dec!(100),
+                    rust_decimal_macros::dec!(1),
+                    open_time,
+                ),
+            )
+        };
+
+        // 발표 2일 전: 10번째 캔들의 진입 신호 차단
+        let loaded = engine
+            .update_earnings_calendar(&[earnings(-80), earnings(2), earnings(90)], today)
+            .await;
+        assert_eq!(loaded, 1);
+        assert_eq!(
+            engine
+                .get_next_earnings("005930")
+                .await
+                .unwrap()
+                .announce_date,
+            today + chrono::Duration::days(2)
+        );
+        let mut sent = 0;
+        for i in 0..10 {
+            sent += engine
+                .process_market_data(kline_data(i))
+                .await
+                .unwrap()
+                .len();
+        }
+        assert_eq!(sent, 0);
+
+        // 발표 1일 전 보유 포지션: 청산 신호 1회 생성
+        let mut position = Position::new(
+            "test",
+            "005930".to_string(),
+            Side::Buy,
+            rust_decimal_macros::dec!(10),
+            rust_decimal_macros::dec!(100),
+        );
+        position.strategy_id = Some("earn".to_string());
+        engine.notify_position_update(&position).await.unwrap();
+        engine.update_earnings_calendar(&[earnings(1)], today).await;
+
+        let signals = engine.process_market_data(kline_data(10)).await.unwrap();
+        assert_eq!(signals.len(), 1);
+        assert!(signals[0].is_exit());
+        assert_eq!(signals[0].side, Side::Sell);
+        assert!(signals[0].metadata.contains_key("earnings_filter"));
+        assert!(engine
+            .process_market_data(kline_data(11))
+            .await
+            .unwrap()
+            .is_empty());
+
+        let stats = engine.get_strategy_status("earn").await.unwrap().stats;
+        assert_eq!(stats.earnings_blocked, 1);
+        assert_eq!(stats.earnings_exits, 1);
+    }
+
+    /// 고정 휴장일 목록.
+    struct FixedHolidays(Vec<NaiveDate>);
+
+    #[async_trait]
+    impl HolidaySource for FixedHolidays {
+        async fn is_holiday(
+            &self,
+            _market: CalendarMarket,
+            date: NaiveDate,
+        ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>> {
+            Ok(self.0.contains(&date))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_trading_window() {
+        let children_day = NaiveDate::from_ymd_opt(2026, 5, 5).unwrap();
+        let mut engine = StrategyEngine::new(EngineConfig {
+            deduplicate_signals: false,
+            ..Default::default()
+        });
+        engine.set_holiday_source(Arc::new(FixedHolidays(vec![children_day])));
+        engine
+            .register_strategy(
+                "window",
+                Box::new(TestStrategy::new("window")),
+                serde_json::json!({}),
+                None,
+            )
+            .await
+            .unwrap();
+        engine.start_strategy("window").await.unwrap();
+        engine
+            .set_strategy_trading_window(
+                "window",
+                Some(TradingWindowConfig {
+                    market: CalendarMarket::Kr,
+                    start: chrono::NaiveTime::from_hms_opt(9, 5, 0).unwrap(),
+                    end: chrono::NaiveTime::from_hms_opt(15, 20, 0).unwrap(),
+                    skip_holidays: true,
+                    flatten_outside: true,
+                }),
+            )
+            .await
+            .unwrap();
+
+        assert!(
+            engine
+                .is_trading_day(CalendarMarket::Kr, children_day.pred_opt().unwrap())
+                .await
+        );
+        assert!(
+            !engine
+                .is_trading_day(CalendarMarket::Kr, children_day)
+                .await
+        );
+        assert!(
+            !engine
+                .is_trading_day(
+                    CalendarMarket::Kr,
+                    NaiveDate::from_ymd_opt(2026, 5, 9).unwrap()
+                )
+                .await
+        );
+
+        let kline_data = |open_time: DateTime<Utc>| {
+            MarketData::from_kline(
+                "test",
+                Kline::new(
+                    "005930".to_string(),
+                    Timeframe::M1,
+                    open_time,
+                    rust_decimal_macros::dec!(100),
+                    rust_decimal_macros::dec!(100),
+                    rust_decimal_macros::dec!(100),
+                    rust_decimal_macros::dec!(100),
+                    rust_decimal_macros::dec!(1),
+                    open_time,
+                ),
+            )
+        };
+        let signal_count = |status: StrategyStatus| status.state["signal_count"].as_u64().unwrap();
+
+        // 2026-05-04(월) 10:00 KST: 구간 안이므로 전략이 처리
+        let open: DateTime<Utc> = "2026-05-04T01:00:00Z".parse().unwrap();
+        let mut sent = 0;
+        for i in 0..10 {
+            sent += engine
+                .process_market_data(kline_data(open + chrono::Duration::seconds(i)))
+                .await
+                .unwrap()
+                .len();
+        }
+        assert_eq!(sent, 1);
+
+        // 16:00 KST 보유 포지션: 청산 신호 1회 생성, 전략에는 전달하지 않음
+        let mut position = Position::new(
+            "test",
+            "005930".to_string(),
+            Side::Buy,
+            rust_decimal_macros::dec!(10),
+            rust_decimal_macros::dec!(100),
+        );
+        position.strategy_id = Some("window".to_string());
+        engine.notify_position_update(&position).await.unwrap();
+
+        let after_close: DateTime<Utc> = "2026-05-04T07:00:00Z".parse().unwrap();
+        let signals = engine
+            .process_market_data(kline_data(after_close))
+            .await
+            .unwrap();
+        assert_eq!(signals.len(), 1);
+        assert!(signals[0].is_exit());
+        assert_eq!(signals[0].side, Side::Sell);
+        assert!(signals[0].metadata.contains_key("trading_window"));
+        assert!(engine
+            .process_market_data(kline_data(after_close + chrono::Duration::minutes(1)))
+            .await
+            .unwrap()
+            .is_empty());
+
+        // 휴장일은 구간 시각이어도 밖
+        let holiday: DateTime<Utc> = "2026-05-05T01:00:00Z".parse().unwrap();
+        assert!(engine
+            .process_market_data(kline_data(holiday))
+            .await
+            .unwrap()
+            .is_empty());
+
+        let status = engine.get_strategy_status("window").await.unwrap();
+        assert_eq!(status.stats.window_skipped, 3);
+        assert_eq!(status.stats.window_exits, 1);
+        assert_eq!(signal_count(status), 10);
+
+        // 해제하면 다시 전략이 처리
+        engine
+            .set_strategy_trading_window("window", None)
+            .await
+            .unwrap();
+        engine
+            .process_market_data(kline_data(holiday))
+            .await
+            .unwrap();
+        let status = engine.get_strategy_status("window").await.unwrap();
+        assert_eq!(signal_count(status), 11);
+    }
+
+    #[tokio::test]
+    async fn test_pause_resume_strategy() {
+        let engine = StrategyEngine::new(EngineConfig::default());
+        engine
+            .register_strategy(
+                "pause",
+                Box::new(TestStrategy::new("pause")),
+                serde_json::json!({}),
+                None,
+            )
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            engine.pause_strategy("pause").await,
+            Err(EngineError::NotRunning(_))
+        ));
+        engine.start_strategy("pause").await.unwrap();
+        engine.pause_strategy("pause").await.unwrap();
+        engine.pause_strategy("pause").await.unwrap();
+
+        let status = engine.get_strategy_status("pause").await.unwrap();
+        assert!(status.running);
+        assert!(status.paused);
+        assert!(status.stats.paused_at.is_some());
+
+        let data = MarketData::from_kline(
+            "test",
+            Kline::new(
+                "005930".to_string(),
+                Timeframe::M1,
+                Utc::now(),
+                rust_decimal_macros::dec!(100),
+                rust_decimal_macros::dec!(100),
+                rust_decimal_macros::dec!(100),
+                rust_decimal_macros::dec!(100),
+                rust_decimal_macros::dec!(1),
+                Utc::now(),
+            ),
+        );
+        engine.process_market_data(data.clone()).await.unwrap();
+        assert_eq!(
+            engine
+                .get_strategy_status("pause")
+                .await
+                .unwrap()
+                .stats
+                .market_data_processed,
+            0
+        );
+        assert!(matches!(
+            engine
+                .process_external_signal("pause", &serde_json::json!({}))
+                .await,
+            Err(EngineError::SignalRejected(_))
+        ));
+
+        engine.resume_strategy("pause").await.unwrap();
+        engine.process_market_data(data).await.unwrap();
+        let status = engine.get_strategy_status("pause").await.unwrap();
+        assert!(!status.paused);
+        assert!(status.stats.paused_at.is_none());
+        assert_eq!(status.stats.market_data_processed, 1);
+
+        // 중지하면 일시정지도 해제
+        engine.pause_strategy("pause").await.unwrap();
+        engine.stop_strategy("pause").await.unwrap();
+        assert!(!engine.get_strategy_status("pause").await.unwrap().paused);
+    }
+
+    #[tokio::test]
+    async fn test_symbol_lock() {
+        use crate::symbol_lock::SymbolLockPolicy;
+
+        let engine = StrategyEngine::new(EngineConfig {
+            deduplicate_signals: false,
+            ..Default::default()
+        });
+        let lock = SymbolLockConfig {
+            policy: SymbolLockPolicy::Exclusive,
+            max_symbols: None,
+        };
+        for id in ["a", "b"] {
+            engine
+                .register_strategy_with_lock(
+                    id,
+                    Box::new(TestStrategy::new(id)),
+                    serde_json::json!({}),
+                    None,
+                    Some(lock),
+                )
+                .await
+                .unwrap();
+            engine.start_strategy(id).await.unwrap();
+        }
+        assert_eq!(
+            engine.get_strategy_symbol_lock("a").await.unwrap(),
+            Some(lock)
+        );
+
+        let start: DateTime<Utc> = "2026-04-27T01:00:00Z".parse().unwrap();
+        let price = rust_decimal_macros::dec!(100);
+        let mut signals = Vec::new();
+        for i in 0..10 {
+            let open_time = start + chrono::Duration::seconds(i);
+            let kline = Kline::new(
+                "005930".to_string(),
+                Timeframe::M1,
+                open_time,
+                price,
+                price,
+                price,
+                price,
+                rust_decimal_macros::dec!(1),
+                open_time,
+            );
+            signals.extend(
+                engine
+                    .process_market_data(MarketData::from_kline("test", kline))
+                    .await
+                    .unwrap(),
+            );
+        }
+        // 두 전략이 같은 종목에 진입 신호를 냈지만 먼저 점유한 전략만 전송
+        assert_eq!(signals.len(), 1);
+        let owner = signals[0].strategy_id.clone();
+        let holders = engine.symbol_lock_holders("005930").await;
+        assert_eq!(holders.len(), 1);
+        assert_eq!(holders[0].strategy_id, owner);
+
+        let statuses = engine.get_all_statuses().await;
+        let blocked: u64 = statuses.values().map(|s| s.stats.symbol_lock_blocked).sum();
+        assert_eq!(blocked, 1);
+
+        // 포지션 청산 시 점유 해제
+        let mut position = Position::new(
+            "test",
+            "005930".to_string(),
+            Side::Buy,
+            rust_decimal_macros::dec!(0),
+            price,
+        );
+        position.strategy_id = Some(owner);
+        engine.notify_position_update(&position).await.unwrap();
+        assert!(engine.symbol_lock_holders("005930").await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_trading_status_updates_context() {
+        use trader_core::TradingStatus;
+
+        let engine = StrategyEngine::new(EngineConfig::default());
+        engine
+            .register_strategy(
+                "vi",
+                Box::new(TestStrategy::new("vi")),
+                serde_json::json!({}),
+                None,
+            )
+            .await
+            .unwrap();
+        engine.start_strategy("vi").await.unwrap();
+
+        let notified = engine
+            .update_trading_status(&TradingStatusEvent::new("005930", TradingStatus::DynamicVi))
+            .await;
+        assert_eq!(notified, 1);
+
+        let context = engine.get_strategy_context("vi").await.unwrap();
+        assert!(context.read().await.is_trading_restricted("005930"));
+        let status = engine.get_strategy_status("vi").await.unwrap();
+        assert_eq!(status.state["trading_status_count"], 1);
+
+        // 이후 등록된 전략도 현재 거래정지/VI 종목을 컨텍스트로 받음
+        engine
+            .register_strategy(
+                "late",
+                Box::new(TestStrategy::new("late")),
+                serde_json::json!({}),
+                None,
+            )
+            .await
+            .unwrap();
+        let context = engine.get_strategy_context("late").await.unwrap();
+        assert!(context.read().await.is_trading_restricted("005930"));
+
+        // VI 해제
+        engine
+            .update_trading_status(&TradingStatusEvent::new("005930", TradingStatus::Normal))
+            .await;
+        assert!(engine.get_trading_statuses().await.is_empty());
+        let context = engine.get_strategy_context("vi").await.unwrap();
+        assert!(!context.read().await.is_trading_restricted("005930"));
+
+        // 기록 없는 종목의 정상 상태 알림은 무시
+        assert_eq!(
+            engine
+                .update_trading_status(&TradingStatusEvent::new("000660", TradingStatus::Normal))
+                .await,
+            0
+        );
+    }
+
+    #[tokio::test]
+    async fn test_broker_downtime_updates_contexts() {
+        let engine = StrategyEngine::new(EngineConfig::default());
+        engine
+            .register_strategy(
+                "grid",
+                Box::new(TestStrategy::new("grid")),
+                serde_json::json!({}),
+                None,
+            )
+            .await
+            .unwrap();
+
+        let outage = BrokerDowntime::detected("kis", "timeout", Utc::now());
+        assert_eq!(engine.update_broker_downtime(Some(outage.clone())).await, 1);
+        // 같은 상태는 다시 반영하지 않음
+        assert_eq!(engine.update_broker_downtime(Some(outage.clone())).await, 0);
+
+        let context = engine.get_strategy_context("grid").await.unwrap();
+        assert!(context.read().await.broker_unavailable);
+
+        // 장애 중 등록된 전략도 플래그를 받음
+        engine
+            .register_strategy(
+                "late",
+                Box::new(TestStrategy::new("late")),
+                serde_json::json!({}),
+                None,
+            )
+            .await
+            .unwrap();
+        let context = engine.get_strategy_context("late").await.unwrap();
+        assert_eq!(context.read().await.broker_downtime, Some(outage));
+
+        assert_eq!(engine.update_broker_downtime(None).await, 2);
+        let context = engine.get_strategy_context("grid").await.unwrap();
+        assert!(!context.read().await.broker_unavailable);
+        assert!(engine.broker_downtime().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_stream_status_notifies_running_strategies() {
+        let engine = StrategyEngine::new(EngineConfig::default());
+        for id in ["running", "stopped"] {
+            engine
+                .register_strategy(
+                    id,
+                    Box::new(TestStrategy::new(id)),
+                    serde_json::json!({}),
+                    None,
+                )
+                .await
+                .unwrap();
+        }
+        engine.start_strategy("running").await.unwrap();
+
+        let status = StreamStatus::recovered("kis_kr", 2, Utc::now(), 4);
+        assert_eq!(engine.update_stream_status(&status).await, 1);
+
+        let running = engine.get_strategy_status("running").await.unwrap();
+        assert_eq!(running.state["stream_status_count"], 1);
+        let stopped = engine.get_strategy_status("stopped").await.unwrap();
+        assert_eq!(stopped.state["stream_status_count"], 0);
+    }
 }
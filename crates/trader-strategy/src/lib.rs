@@ -30,25 +30,53 @@
 //! }
 //! ```
 
+pub mod competition;
+pub mod config_migration;
 pub mod engine;
 pub mod macros;
 pub mod plugin;
+pub mod recommend;
 pub mod registry;
 pub mod schema_composer;
 pub mod schema_registry;
+pub mod shadow;
+pub mod state_store;
 pub mod strategies;
+pub mod symbol_lock;
+pub mod trading_window;
 pub mod traits;
+pub mod warmup;
 
 // 주요 타입 재내보내기
+pub use competition::{
+    rank_by_risk_adjusted_return, risk_adjusted_return, CompetitorSnapshot, EquitySample,
+    PaperBook, PaperBookConfig, PaperPosition, RiskAdjustedReturn,
+};
+pub use config_migration::{
+    upgrade_config, ConfigMigration, ConfigMigrationError, ConfigUpgrade, MigrationStep,
+};
 pub use engine::{
     EngineConfig, EngineError, EngineStats, StrategyEngine, StrategyStats, StrategyStatus,
 };
 pub use plugin::{BuiltinStrategyFactory, LoaderConfig, PluginError, PluginLoader, PluginMetadata};
+#[cfg(feature = "wasm")]
+pub use plugin::{WasmLimits, WasmPlugin, WasmStrategy, WASM_ABI_VERSION};
+pub use recommend::{is_recommendable, parameter_candidates, rank_fits, StrategyFit};
 pub use registry::{StrategyCategory, StrategyMeta, StrategyRegistry};
 pub use schema_composer::SchemaComposer;
 pub use schema_registry::FragmentRegistry;
+pub use shadow::{
+    DiscrepancyKind, ShadowConfig, ShadowDiscrepancy, ShadowReport, ShadowStats, ShadowTracker,
+};
+pub use state_store::{StrategyState, StrategyStateStore};
 pub use strategies::{MeanReversionConfig, MeanReversionStrategy, MeanReversionVariant};
+pub use symbol_lock::{
+    SymbolClaim, SymbolLockBook, SymbolLockConfig, SymbolLockConflict, SymbolLockDecision,
+    SymbolLockPolicy,
+};
+pub use trading_window::{HolidaySource, TradingWindowConfig};
 pub use traits::{Strategy, StrategyMetadata};
+pub use warmup::WarmupDataSource;
 
 // 프로시저 매크로 재내보내기
 pub use trader_strategy_macro::StrategyConfig;
@@ -1,8 +1,11 @@
 //! 동적 전략 플러그인 로더.
 //!
 //! 동적 라이브러리(Windows의 .dll, Linux의 .so)에서 전략 플러그인을 로드합니다.
-//! 플러그인 업데이트 시 핫 리로딩을 지원합니다.
+//! `wasm` feature를 켜면 `.wasm` 파일은 샌드박스된 WASM 플러그인으로 로드합니다
+//! ([`crate::plugin::wasm`] 참고). 플러그인 업데이트 시 핫 리로딩을 지원합니다.
 
+#[cfg(feature = "wasm")]
+use crate::plugin::wasm::{WasmLimits, WasmPlugin};
 use crate::Strategy;
 use libloading::Library;
 use serde::{Deserialize, Serialize};
@@ -32,6 +35,9 @@ pub enum PluginError {
     #[error("이미 로드된 플러그인: {0}")]
     AlreadyLoaded(String),
 
+    #[error("플러그인 실행 실패: {0}")]
+    ExecutionFailed(String),
+
     #[error("IO 에러: {0}")]
     IoError(#[from] std::io::Error),
 }
@@ -168,6 +174,38 @@ impl LoadedPlugin {
     }
 }
 
+/// 로더가 관리하는 플러그인 (동적 라이브러리 또는 WASM).
+enum PluginHandle {
+    Native(LoadedPlugin),
+    #[cfg(feature = "wasm")]
+    Wasm(WasmPlugin),
+}
+
+impl PluginHandle {
+    fn metadata(&self) -> &PluginMetadata {
+        match self {
+            Self::Native(plugin) => plugin.metadata(),
+            #[cfg(feature = "wasm")]
+            Self::Wasm(plugin) => plugin.metadata(),
+        }
+    }
+
+    fn path(&self) -> &Path {
+        match self {
+            Self::Native(plugin) => plugin.path(),
+            #[cfg(feature = "wasm")]
+            Self::Wasm(plugin) => plugin.path(),
+        }
+    }
+}
+
+/// WASM 플러그인 파일 확장자.
+const WASM_EXTENSION: &str = "wasm";
+
+fn is_wasm_path(path: &Path) -> bool {
+    path.extension() == Some(OsStr::new(WASM_EXTENSION))
+}
+
 /// 플러그인 설정.
 #[derive(Debug, Clone, Deserialize)]
 pub struct PluginConfig {
@@ -205,6 +243,11 @@ pub struct LoaderConfig {
     /// 검색할 플러그인 파일 확장자
     #[serde(default = "default_extension")]
     pub extension: String,
+
+    /// WASM 플러그인 자원 제한
+    #[cfg(feature = "wasm")]
+    #[serde(default)]
+    pub wasm_limits: WasmLimits,
 }
 
 fn default_plugins_dir() -> PathBuf {
@@ -236,6 +279,8 @@ impl Default for LoaderConfig {
             plugins_dir: default_plugins_dir(),
             hot_reload: false,
             extension: default_extension(),
+            #[cfg(feature = "wasm")]
+            wasm_limits: WasmLimits::default(),
         }
     }
 }
@@ -246,7 +291,7 @@ pub struct PluginLoader {
     config: LoaderConfig,
 
     /// 이름별 로드된 플러그인
-    plugins: Arc<RwLock<HashMap<String, LoadedPlugin>>>,
+    plugins: Arc<RwLock<HashMap<String, PluginHandle>>>,
 }
 
 impl PluginLoader {
@@ -279,7 +324,11 @@ impl PluginLoader {
             )));
         }
 
-        let plugin = unsafe { LoadedPlugin::load(&full_path)? };
+        let plugin = if is_wasm_path(&full_path) {
+            self.load_wasm(&full_path)?
+        } else {
+            PluginHandle::Native(unsafe { LoadedPlugin::load(&full_path)? })
+        };
         let metadata = plugin.metadata().clone();
         let name = metadata.name.clone();
 
@@ -294,6 +343,19 @@ impl PluginLoader {
         Ok(metadata)
     }
 
+    #[cfg(feature = "wasm")]
+    fn load_wasm(&self, path: &Path) -> Result<PluginHandle, PluginError> {
+        WasmPlugin::load(path, self.config.wasm_limits.clone()).map(PluginHandle::Wasm)
+    }
+
+    #[cfg(not(feature = "wasm"))]
+    fn load_wasm(&self, path: &Path) -> Result<PluginHandle, PluginError> {
+        Err(PluginError::LoadError(format!(
+            "{}: WASM plugins require the `wasm` feature",
+            path.display()
+        )))
+    }
+
     /// 이름으로 플러그인 언로드.
     pub async fn unload_plugin(&self, name: &str) -> Result<(), PluginError> {
         let mut plugins = self.plugins.write().await;
@@ -318,7 +380,11 @@ impl PluginLoader {
             .get(plugin_name)
             .ok_or_else(|| PluginError::PluginNotFound(plugin_name.to_string()))?;
 
-        unsafe { plugin.create_strategy() }
+        match plugin {
+            PluginHandle::Native(plugin) => unsafe { plugin.create_strategy() },
+            #[cfg(feature = "wasm")]
+            PluginHandle::Wasm(plugin) => Ok(plugin.create_strategy()),
+        }
     }
 
     /// 로드된 플러그인의 메타데이터 반환.
@@ -355,7 +421,9 @@ impl PluginLoader {
             let entry = entry?;
             let path = entry.path();
 
-            if path.is_file() && path.extension() == Some(extension) {
+            let is_plugin = path.extension() == Some(extension)
+                || (cfg!(feature = "wasm") && is_wasm_path(&path));
+            if path.is_file() && is_plugin {
                 match self.load_plugin(&path).await {
                     Ok(metadata) => {
                         info!(
@@ -1,5 +1,9 @@
 //! 플러그인 로더 시스템.
 
 pub mod loader;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
 pub use loader::*;
+#[cfg(feature = "wasm")]
+pub use wasm::{WasmLimits, WasmPlugin, WasmStrategy, WASM_ABI_VERSION};
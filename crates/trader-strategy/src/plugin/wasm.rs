@@ -0,0 +1,971 @@
+//! WASM 전략 플러그인.
+//!
+//! 워크스페이스를 다시 빌드하지 않고 외부 전략을 추가할 수 있도록 WASM 모듈로 컴파일된
+//! 전략을 wasmtime 샌드박스에서 실행합니다. 플러그인은 아래 호스트 ABI만 사용할 수 있으며
+//! (WASI 없음: 파일, 네트워크, 시계 접근 불가) 인스턴스마다 메모리와 호출당 연료가 제한됩니다.
+//!
+//! # 호스트 ABI (버전 1)
+//!
+//! 모든 데이터는 UTF-8 JSON으로 주고받으며, 금액/수량은 문자열 십진수입니다.
+//! 응답을 반환하는 함수는 `(ptr << 32) | len` 형태의 `i64`를 반환하고, `0`은 빈 응답입니다.
+//! 응답이 `{"error": "..."}` 객체이면 호출 실패로 처리합니다.
+//!
+//! 플러그인이 내보내야 하는 항목:
+//!
+//! | 이름 | 시그니처 | 설명 |
+//! |------|----------|------|
+//! | `memory` | 메모리 | 선형 메모리 |
+//! | `trader_abi_version` | `() -> i32` | [`WASM_ABI_VERSION`] 반환 |
+//! | `trader_alloc` | `(len: i32) -> i32` | 호스트가 입력을 쓸 버퍼 할당 |
+//! | `trader_init` | `(ptr: i32, len: i32) -> i64` | 전략 설정(JSON) 전달 |
+//! | `trader_on_market_data` | `(ptr: i32, len: i32) -> i64` | 시장 데이터 입력, 신호 배열 응답 |
+//!
+//! 선택 항목: `trader_metadata() -> i64` ([`PluginMetadata`] JSON),
+//! `trader_on_order_filled(ptr, len) -> i64`, `trader_on_position_update(ptr, len) -> i64`,
+//! `trader_save_state() -> i64` (임의 바이트), `trader_load_state(ptr, len) -> i64`.
+//!
+//! 호스트가 제공하는 함수 (모듈 `trader`): `log(level: i32, ptr: i32, len: i32)`
+//! (0=debug, 1=info, 2=warn, 3=error).
+//!
+//! 시장 데이터 입력:
+//!
+//! ```json
+//! {
+//!   "ticker": "BTC/USDT", "exchange": "binance", "timestamp": 1767571200000,
+//!   "kind": "kline", "price": "50200",
+//!   "kline": { "timeframe": "1h", "open": "50000", "high": "50300", "low": "49900",
+//!              "close": "50200", "volume": "12.5" }
+//! }
+//! ```
+//!
+//! 신호 응답 (`ticker`를 생략하면 입력 종목):
+//!
+//! ```json
+//! [{ "side": "buy", "signal_type": "entry", "strength": 0.8, "price": "50200",
+//!    "stop_loss": "49000", "metadata": { "reason": "breakout" } }]
+//! ```
+
+use crate::plugin::{PluginError, PluginMetadata};
+use crate::Strategy;
+use async_trait::async_trait;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use tracing::{debug, error, info, warn};
+use trader_core::{MarketData, MarketDataType, Order, Position, Side, Signal, SignalType};
+use wasmtime::{
+    Caller, Config, Engine, Extern, Instance, Linker, Memory, Module, Store, StoreLimits,
+    StoreLimitsBuilder, TypedFunc,
+};
+
+/// 지원하는 호스트 ABI 버전.
+pub const WASM_ABI_VERSION: i32 = 1;
+
+/// 호스트 함수 모듈 이름.
+const HOST_MODULE: &str = "trader";
+
+/// 플러그인 로그 메시지 최대 길이 (바이트).
+const MAX_LOG_BYTES: usize = 4096;
+
+/// WASM 플러그인 자원 제한.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WasmLimits {
+    /// 인스턴스 최대 선형 메모리 (바이트)
+    pub max_memory_bytes: usize,
+    /// 호출당 최대 연료 (실행 명령 수에 비례, 소진 시 호출 중단)
+    pub fuel_per_call: u64,
+    /// 최대 WASM 스택 크기 (바이트)
+    pub max_stack_bytes: usize,
+    /// 응답 최대 크기 (바이트)
+    pub max_response_bytes: usize,
+    /// 호출당 최대 신호 수
+    pub max_signals_per_call: usize,
+}
+
+impl Default for WasmLimits {
+    fn default() -> Self {
+        Self {
+            max_memory_bytes: 64 * 1024 * 1024,
+            fuel_per_call: 50_000_000,
+            max_stack_bytes: 512 * 1024,
+            max_response_bytes: 1024 * 1024,
+            max_signals_per_call: 100,
+        }
+    }
+}
+
+/// 인스턴스별 호스트 상태.
+struct HostState {
+    /// 플러그인 이름 (로그용)
+    plugin: String,
+    limits: StoreLimits,
+}
+
+/// 컴파일된 모듈과 실행 환경 (전략 인스턴스 간 공유).
+struct WasmModule {
+    engine: Engine,
+    module: Module,
+    linker: Linker<HostState>,
+    limits: WasmLimits,
+    metadata: PluginMetadata,
+}
+
+/// 로드된 WASM 플러그인.
+pub struct WasmPlugin {
+    /// 플러그인 파일 경로
+    path: PathBuf,
+    module: Arc<WasmModule>,
+}
+
+impl WasmPlugin {
+    /// 파일에서 WASM 플러그인 로드.
+    ///
+    /// 모듈을 컴파일하고 시험 인스턴스로 ABI 버전, 필수 내보내기, 자원 제한을 검증합니다.
+    pub fn load<P: AsRef<Path>>(path: P, limits: WasmLimits) -> Result<Self, PluginError> {
+        let path = path.as_ref().to_path_buf();
+        info!(path = %path.display(), "Loading WASM plugin");
+
+        let bytes = std::fs::read(&path)?;
+        let default_name = path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or("unknown");
+        let mut plugin = Self::from_bytes(default_name, &bytes, limits)?;
+        plugin.path = path;
+        Ok(plugin)
+    }
+
+    /// 메모리의 WASM 바이너리에서 플러그인 생성.
+    ///
+    /// 플러그인이 `trader_metadata`를 내보내지 않으면 `default_name`을 이름으로 사용합니다.
+    pub fn from_bytes(
+        default_name: &str,
+        bytes: &[u8],
+        limits: WasmLimits,
+    ) -> Result<Self, PluginError> {
+        let mut config = Config::new();
+        config.consume_fuel(true);
+        config.max_wasm_stack(limits.max_stack_bytes);
+        let engine = Engine::new(&config).map_err(|e| PluginError::LoadError(e.to_string()))?;
+        let module = Module::new(&engine, bytes)
+            .map_err(|e| PluginError::InvalidPlugin(format!("{}: {}", default_name, e)))?;
+
+        // 샌드박스: 호스트 ABI 외의 가져오기(WASI 등) 거부
+        for import in module.imports() {
+            if import.module() != HOST_MODULE || import.name() != "log" {
+                return Err(PluginError::InvalidPlugin(format!(
+                    "{}: unsupported import {}::{}",
+                    default_name,
+                    import.module(),
+                    import.name()
+                )));
+            }
+        }
+
+        let mut linker = Linker::new(&engine);
+        linker
+            .func_wrap(HOST_MODULE, "log", host_log)
+            .map_err(|e| PluginError::LoadError(e.to_string()))?;
+
+        let mut module = WasmModule {
+            engine,
+            module,
+            linker,
+            limits,
+            metadata: PluginMetadata {
+                name: default_name.to_string(),
+                version: "1.0.0".to_string(),
+                description: "WASM strategy plugin".to_string(),
+                required_config: Vec::new(),
+                supported_tickers: Vec::new(),
+            },
+        };
+
+        // 시험 인스턴스로 ABI 검증 및 메타데이터 조회
+        let mut probe = GuestInstance::new(&module)?;
+        let version = probe
+            .instance
+            .get_typed_func::<(), i32>(&mut probe.store, "trader_abi_version")
+            .and_then(|f| f.call(&mut probe.store, ()))
+            .map_err(|e| PluginError::SymbolNotFound(format!("trader_abi_version: {}", e)))?;
+        if version != WASM_ABI_VERSION {
+            return Err(PluginError::InvalidPlugin(format!(
+                "{}: ABI version {} (host supports {})",
+                default_name, version, WASM_ABI_VERSION
+            )));
+        }
+        for export in ["trader_init", "trader_on_market_data"] {
+            probe
+                .instance
+                .get_typed_func::<(i32, i32), i64>(&mut probe.store, export)
+                .map_err(|_| PluginError::SymbolNotFound(export.to_string()))?;
+        }
+        if let Some(response) = probe
+            .call("trader_metadata", None, &module.limits)?
+            .filter(|response| !response.is_empty())
+        {
+            module.metadata = parse_metadata(&response, module.metadata)?;
+        }
+
+        info!(
+            name = %module.metadata.name,
+            version = %module.metadata.version,
+            "WASM plugin loaded successfully"
+        );
+
+        Ok(Self {
+            path: PathBuf::new(),
+            module: Arc::new(module),
+        })
+    }
+
+    /// 이 플러그인에서 새 전략 인스턴스 생성.
+    ///
+    /// 인스턴스는 `initialize()` 호출 시 격리된 저장소에서 생성됩니다.
+    pub fn create_strategy(&self) -> Box<dyn Strategy> {
+        Box::new(WasmStrategy {
+            module: Arc::clone(&self.module),
+            guest: Mutex::new(None),
+            status: Mutex::new(GuestStatus::default()),
+        })
+    }
+
+    /// 플러그인 메타데이터 반환.
+    pub fn metadata(&self) -> &PluginMetadata {
+        &self.module.metadata
+    }
+
+    /// 플러그인 경로 반환.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+/// 플러그인 메타데이터 응답.
+#[derive(Deserialize)]
+struct GuestMetadata {
+    name: Option<String>,
+    version: Option<String>,
+    description: Option<String>,
+    #[serde(default)]
+    required_config: Vec<String>,
+    #[serde(default)]
+    supported_tickers: Vec<String>,
+}
+
+fn parse_metadata(
+    response: &[u8],
+    defaults: PluginMetadata,
+) -> Result<PluginMetadata, PluginError> {
+    let guest: GuestMetadata = serde_json::from_slice(response)
+        .map_err(|e| PluginError::InvalidPlugin(format!("invalid metadata: {}", e)))?;
+    Ok(PluginMetadata {
+        name: guest.name.unwrap_or(defaults.name),
+        version: guest.version.unwrap_or(defaults.version),
+        description: guest.description.unwrap_or(defaults.description),
+        required_config: guest.required_config,
+        supported_tickers: guest.supported_tickers,
+    })
+}
+
+/// 플러그인 로그 호스트 함수.
+fn host_log(mut caller: Caller<'_, HostState>, level: i32, ptr: i32, len: i32) {
+    let Some(Extern::Memory(memory)) = caller.get_export("memory") else {
+        return;
+    };
+    let mut buf = vec![0u8; (len.max(0) as usize).min(MAX_LOG_BYTES)];
+    if memory.read(&caller, ptr as u32 as usize, &mut buf).is_err() {
+        return;
+    }
+    let message = String::from_utf8_lossy(&buf);
+    let plugin = caller.data().plugin.as_str();
+    match level {
+        0 => debug!(plugin = %plugin, "{}", message),
+        1 => info!(plugin = %plugin, "{}", message),
+        2 => warn!(plugin = %plugin, "{}", message),
+        _ => error!(plugin = %plugin, "{}", message),
+    }
+}
+
+/// 실행 중인 플러그인 인스턴스.
+struct GuestInstance {
+    store: Store<HostState>,
+    instance: Instance,
+    memory: Memory,
+    alloc: TypedFunc<i32, i32>,
+    /// 누적 연료 소비량
+    fuel_consumed: u64,
+}
+
+impl GuestInstance {
+    fn new(module: &WasmModule) -> Result<Self, PluginError> {
+        let limits = StoreLimitsBuilder::new()
+            .memory_size(module.limits.max_memory_bytes)
+            .instances(1)
+            .memories(1)
+            .tables(1)
+            .trap_on_grow_failure(true)
+            .build();
+        let mut store = Store::new(
+            &module.engine,
+            HostState {
+                plugin: module.metadata.name.clone(),
+                limits,
+            },
+        );
+        store.limiter(|state| &mut state.limits);
+        store
+            .set_fuel(module.limits.fuel_per_call)
+            .map_err(|e| PluginError::LoadError(e.to_string()))?;
+
+        let instance = module
+            .linker
+            .instantiate(&mut store, &module.module)
+            .map_err(|e| PluginError::InvalidPlugin(e.to_string()))?;
+        let memory = instance
+            .get_memory(&mut store, "memory")
+            .ok_or_else(|| PluginError::SymbolNotFound("memory".to_string()))?;
+        let alloc = instance
+            .get_typed_func::<i32, i32>(&mut store, "trader_alloc")
+            .map_err(|_| PluginError::SymbolNotFound("trader_alloc".to_string()))?;
+
+        Ok(Self {
+            store,
+            instance,
+            memory,
+            alloc,
+            fuel_consumed: 0,
+        })
+    }
+
+    /// 내보낸 함수 호출.
+    ///
+    /// 함수가 없으면 `Ok(None)`, 빈 응답이면 `Ok(Some(vec![]))`를 반환합니다.
+    /// 트랩(연료 소진, 메모리 한도 초과 등)은 `ExecutionFailed`로 반환합니다.
+    fn call(
+        &mut self,
+        export: &str,
+        input: Option<&[u8]>,
+        limits: &WasmLimits,
+    ) -> Result<Option<Vec<u8>>, PluginError> {
+        let exec_err =
+            |e: wasmtime::Error| PluginError::ExecutionFailed(format!("{}: {}", export, e));
+
+        self.store
+            .set_fuel(limits.fuel_per_call)
+            .map_err(exec_err)?;
+
+        let packed = match input {
+            Some(input) => {
+                let Ok(func) = self
+                    .instance
+                    .get_typed_func::<(i32, i32), i64>(&mut self.store, export)
+                else {
+                    return Ok(None);
+                };
+                let len = i32::try_from(input.len()).map_err(|_| {
+                    PluginError::ExecutionFailed(format!("{}: input too large", export))
+                })?;
+                let ptr = self.alloc.call(&mut self.store, len).map_err(exec_err)?;
+                self.memory
+                    .write(&mut self.store, ptr as u32 as usize, input)
+                    .map_err(|e| {
+                        PluginError::ExecutionFailed(format!("{}: invalid buffer: {}", export, e))
+                    })?;
+                func.call(&mut self.store, (ptr, len))
+            }
+            None => {
+                let Ok(func) = self
+                    .instance
+                    .get_typed_func::<(), i64>(&mut self.store, export)
+                else {
+                    return Ok(None);
+                };
+                func.call(&mut self.store, ())
+            }
+        };
+
+        let remaining = self.store.get_fuel().unwrap_or(0);
+        self.fuel_consumed += limits.fuel_per_call.saturating_sub(remaining);
+        let packed = packed.map_err(exec_err)?;
+
+        if packed == 0 {
+            return Ok(Some(Vec::new()));
+        }
+        let ptr = (packed as u64 >> 32) as usize;
+        let len = (packed as u64 & 0xFFFF_FFFF) as usize;
+        if len > limits.max_response_bytes {
+            return Err(PluginError::ExecutionFailed(format!(
+                "{}: response of {} bytes exceeds limit {}",
+                export, len, limits.max_response_bytes
+            )));
+        }
+        let mut response = vec![0u8; len];
+        self.memory
+            .read(&self.store, ptr, &mut response)
+            .map_err(|e| {
+                PluginError::ExecutionFailed(format!("{}: invalid response: {}", export, e))
+            })?;
+        Ok(Some(response))
+    }
+}
+
+/// 응답이 `{"error": ...}`이면 에러로 변환.
+fn check_error(export: &str, response: &[u8]) -> Result<(), PluginError> {
+    if response.first() != Some(&b'{') {
+        return Ok(());
+    }
+    match serde_json::from_slice::<Value>(response) {
+        Ok(Value::Object(obj)) => match obj.get("error") {
+            Some(error) => Err(PluginError::ExecutionFailed(format!(
+                "{}: {}",
+                export,
+                error
+                    .as_str()
+                    .map(str::to_string)
+                    .unwrap_or_else(|| error.to_string())
+            ))),
+            None => Ok(()),
+        },
+        _ => Ok(()),
+    }
+}
+
+/// 플러그인 입력: 캔들.
+#[derive(Serialize)]
+struct GuestKline {
+    timeframe: String,
+    #[serde(with = "rust_decimal::serde::str")]
+    open: Decimal,
+    #[serde(with = "rust_decimal::serde::str")]
+    high: Decimal,
+    #[serde(with = "rust_decimal::serde::str")]
+    low: Decimal,
+    #[serde(with = "rust_decimal::serde::str")]
+    close: Decimal,
+    #[serde(with = "rust_decimal::serde::str")]
+    volume: Decimal,
+}
+
+/// 플러그인 입력: 시장 데이터.
+#[derive(Serialize)]
+struct GuestMarketData<'a> {
+    ticker: &'a str,
+    exchange: &'a str,
+    /// Unix 밀리초
+    timestamp: i64,
+    kind: &'static str,
+    #[serde(with = "rust_decimal::serde::str_option")]
+    price: Option<Decimal>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    kline: Option<GuestKline>,
+}
+
+impl<'a> From<&'a MarketData> for GuestMarketData<'a> {
+    fn from(data: &'a MarketData) -> Self {
+        let (kind, kline) = match &data.data {
+            MarketDataType::Kline(k) => (
+                "kline",
+                Some(GuestKline {
+                    timeframe: k.timeframe.to_string(),
+                    open: k.open,
+                    high: k.high,
+                    low: k.low,
+                    close: k.close,
+                    volume: k.volume,
+                }),
+            ),
+            MarketDataType::Ticker(_) => ("ticker", None),
+            MarketDataType::OrderBook(_) => ("order_book", None),
+            MarketDataType::Trade(_) => ("trade", None),
+        };
+        Self {
+            ticker: &data.ticker,
+            exchange: &data.exchange,
+            timestamp: data.timestamp.timestamp_millis(),
+            kind,
+            price: data.get_price(),
+            kline,
+        }
+    }
+}
+
+/// 플러그인 입력: 주문 체결.
+#[derive(Serialize)]
+struct GuestOrderFill<'a> {
+    ticker: &'a str,
+    side: Side,
+    #[serde(with = "rust_decimal::serde::str")]
+    quantity: Decimal,
+    #[serde(with = "rust_decimal::serde::str_option")]
+    price: Option<Decimal>,
+}
+
+/// 플러그인 입력: 포지션.
+#[derive(Serialize)]
+struct GuestPosition<'a> {
+    ticker: &'a str,
+    side: Side,
+    #[serde(with = "rust_decimal::serde::str")]
+    quantity: Decimal,
+    #[serde(with = "rust_decimal::serde::str")]
+    entry_price: Decimal,
+}
+
+/// 플러그인 응답: 신호.
+#[derive(Deserialize)]
+struct GuestSignal {
+    #[serde(default)]
+    ticker: Option<String>,
+    side: Side,
+    signal_type: SignalType,
+    #[serde(default = "default_strength")]
+    strength: f64,
+    #[serde(default, with = "rust_decimal::serde::str_option")]
+    price: Option<Decimal>,
+    #[serde(default, with = "rust_decimal::serde::str_option")]
+    stop_loss: Option<Decimal>,
+    #[serde(default, with = "rust_decimal::serde::str_option")]
+    take_profit: Option<Decimal>,
+    #[serde(default)]
+    metadata: HashMap<String, Value>,
+}
+
+fn default_strength() -> f64 {
+    1.0
+}
+
+/// 인스턴스 실행 현황 (모니터링용).
+#[derive(Debug, Default)]
+struct GuestStatus {
+    calls: u64,
+    fuel_consumed: u64,
+    /// 트랩으로 인스턴스가 종료되었을 때의 에러
+    terminated: Option<String>,
+}
+
+/// WASM 플러그인 전략.
+///
+/// 초기화할 때마다 새 인스턴스를 생성합니다. 호출 중 트랩이 발생하면 인스턴스를 폐기하고
+/// 다음 초기화(전략 재시작)까지 신호를 내지 않습니다.
+pub struct WasmStrategy {
+    module: Arc<WasmModule>,
+    guest: Mutex<Option<GuestInstance>>,
+    status: Mutex<GuestStatus>,
+}
+
+impl WasmStrategy {
+    /// 인스턴스 함수 호출. 인스턴스가 없으면 `Ok(None)`을 반환합니다.
+    fn call_guest(
+        &self,
+        export: &str,
+        input: Option<&[u8]>,
+    ) -> Result<Option<Vec<u8>>, PluginError> {
+        let mut guest = self.guest.lock().unwrap_or_else(|e| e.into_inner());
+        let Some(instance) = guest.as_mut() else {
+            return Ok(None);
+        };
+
+        let result = instance.call(export, input, &self.module.limits);
+
+        let mut status = self.status.lock().unwrap_or_else(|e| e.into_inner());
+        status.calls += 1;
+        status.fuel_consumed = instance.fuel_consumed;
+        if let Err(e) = &result {
+            warn!(
+                plugin = %self.module.metadata.name,
+                error = %e,
+                "WASM plugin trapped, discarding instance"
+            );
+            status.terminated = Some(e.to_string());
+            *guest = None;
+        }
+
+        let response = result?;
+        if let Some(response) = &response {
+            check_error(export, response)?;
+        }
+        Ok(response)
+    }
+}
+
+#[async_trait]
+impl Strategy for WasmStrategy {
+    fn name(&self) -> &str {
+        &self.module.metadata.name
+    }
+
+    fn version(&self) -> &str {
+        &self.module.metadata.version
+    }
+
+    fn description(&self) -> &str {
+        &self.module.metadata.description
+    }
+
+    async fn initialize(
+        &mut self,
+        config: Value,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let instance = GuestInstance::new(&self.module)?;
+        *self.guest.lock().unwrap_or_else(|e| e.into_inner()) = Some(instance);
+        *self.status.lock().unwrap_or_else(|e| e.into_inner()) = GuestStatus::default();
+
+        self.call_guest("trader_init", Some(&serde_json::to_vec(&config)?))?;
+        Ok(())
+    }
+
+    async fn on_market_data(
+        &mut self,
+        data: &MarketData,
+    ) -> Result<Vec<Signal>, Box<dyn std::error::Error + Send + Sync>> {
+        let input = serde_json::to_vec(&GuestMarketData::from(data))?;
+        let Some(response) = self.call_guest("trader_on_market_data", Some(&input))? else {
+            return Ok(vec![]);
+        };
+        if response.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let guest_signals: Vec<GuestSignal> = serde_json::from_slice(&response)
+            .map_err(|e| PluginError::ExecutionFailed(format!("invalid signals: {}", e)))?;
+        if guest_signals.len() > self.module.limits.max_signals_per_call {
+            return Err(PluginError::ExecutionFailed(format!(
+                "{} signals exceed limit {}",
+                guest_signals.len(),
+                self.module.limits.max_signals_per_call
+            ))
+            .into());
+        }
+
+        Ok(guest_signals
+            .into_iter()
+            .map(|s| {
+                let mut signal = Signal::new(
+                    self.module.metadata.name.clone(),
+                    s.ticker.unwrap_or_else(|| data.ticker.clone()),
+                    s.side,
+                    s.signal_type,
+                )
+                .with_strength(s.strength)
+                .with_prices(s.price, s.stop_loss, s.take_profit);
+                signal.metadata = s.metadata;
+                signal
+            })
+            .collect())
+    }
+
+    async fn on_order_filled(
+        &mut self,
+        order: &Order,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let input = serde_json::to_vec(&GuestOrderFill {
+            ticker: &order.ticker,
+            side: order.side,
+            quantity: order.filled_quantity,
+            price: order.average_fill_price,
+        })?;
+        self.call_guest("trader_on_order_filled", Some(&input))?;
+        Ok(())
+    }
+
+    async fn on_position_update(
+        &mut self,
+        position: &Position,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let input = serde_json::to_vec(&GuestPosition {
+            ticker: &position.ticker,
+            side: position.side,
+            quantity: position.quantity,
+            entry_price: position.entry_price,
+        })?;
+        self.call_guest("trader_on_position_update", Some(&input))?;
+        Ok(())
+    }
+
+    async fn shutdown(&mut self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        *self.guest.lock().unwrap_or_else(|e| e.into_inner()) = None;
+        Ok(())
+    }
+
+    fn get_state(&self) -> Value {
+        let running = self
+            .guest
+            .lock()
+            .map(|guest| guest.is_some())
+            .unwrap_or(false);
+        let status = self.status.lock().unwrap_or_else(|e| e.into_inner());
+        json!({
+            "name": self.name(),
+            "version": self.version(),
+            "abi_version": WASM_ABI_VERSION,
+            "running": running,
+            "calls": status.calls,
+            "fuel_consumed": status.fuel_consumed,
+            "terminated": status.terminated,
+        })
+    }
+
+    fn save_state(&self) -> Result<Vec<u8>, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(self
+            .call_guest("trader_save_state", None)?
+            .unwrap_or_default())
+    }
+
+    fn load_state(&mut self, data: &[u8]) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        self.call_guest("trader_load_state", Some(data))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plugin::{LoaderConfig, PluginLoader};
+    use chrono::Utc;
+    use rust_decimal_macros::dec;
+    use trader_core::{Kline, Timeframe};
+
+    const METADATA: &str = r#"{"name":"wasm_breakout","version":"0.2.0","description":"test"}"#;
+    const SIGNALS: &str = r#"[{"side":"buy","signal_type":"entry","strength":0.5,"price":"101.5","metadata":{"source":"wasm"}}]"#;
+
+    /// `offset`의 데이터 세그먼트와 그 위치를 가리키는 응답 값.
+    fn segment(offset: usize, data: &str) -> (String, String) {
+        (
+            format!(
+                r#"(data (i32.const {}) "{}")"#,
+                offset,
+                data.replace('"', "\\\"")
+            ),
+            format!(
+                "(i64.or (i64.shl (i64.const {}) (i64.const 32)) (i64.const {}))",
+                offset,
+                data.len()
+            ),
+        )
+    }
+
+    /// 두 번째 시장 데이터마다 매수 신호를 내는 플러그인.
+    fn breakout_wat() -> String {
+        let (metadata_data, metadata) = segment(1024, METADATA);
+        let (signals_data, signals) = segment(2048, SIGNALS);
+        format!(
+            r#"(module
+              (import "trader" "log" (func $log (param i32 i32 i32)))
+              (memory (export "memory") 1)
+              (global $count (mut i32) (i32.const 0))
+              {metadata_data}
+              {signals_data}
+              (func (export "trader_abi_version") (result i32) (i32.const 1))
+              (func (export "trader_alloc") (param i32) (result i32) (i32.const 4096))
+              (func (export "trader_metadata") (result i64) {metadata})
+              (func (export "trader_init") (param i32 i32) (result i64)
+                (global.set $count (i32.const 0))
+                (call $log (i32.const 1) (local.get 0) (local.get 1))
+                (i64.const 0))
+              (func (export "trader_on_market_data") (param i32 i32) (result i64)
+                (global.set $count (i32.add (global.get $count) (i32.const 1)))
+                (if (result i64) (i32.rem_u (global.get $count) (i32.const 2))
+                  (then (i64.const 0))
+                  (else {signals})))
+              (func (export "trader_save_state") (result i64)
+                (i32.store (i32.const 3072) (global.get $count))
+                (i64.or (i64.shl (i64.const 3072) (i64.const 32)) (i64.const 4)))
+              (func (export "trader_load_state") (param i32 i32) (result i64)
+                (global.set $count (i32.load (local.get 0)))
+                (i64.const 0)))"#
+        )
+    }
+
+    /// 필수 내보내기만 있는 플러그인 (`on_market_data` 본문과 추가 항목 지정).
+    fn minimal_wat(header: &str, abi_version: i32, on_market_data: &str) -> String {
+        format!(
+            r#"(module
+              {header}
+              (func (export "trader_abi_version") (result i32) (i32.const {abi_version}))
+              (func (export "trader_alloc") (param i32) (result i32) (i32.const 4096))
+              (func (export "trader_init") (param i32 i32) (result i64) (i64.const 0))
+              (func (export "trader_on_market_data") (param i32 i32) (result i64)
+                {on_market_data}))"#
+        )
+    }
+
+    fn load(wat: &str) -> Result<WasmPlugin, PluginError> {
+        WasmPlugin::from_bytes("test", &wat::parse_str(wat).unwrap(), WasmLimits::default())
+    }
+
+    fn kline(close: Decimal) -> MarketData {
+        let now = Utc::now();
+        MarketData::from_kline(
+            "test",
+            Kline::new(
+                "005930".to_string(),
+                Timeframe::D1,
+                now,
+                close,
+                close,
+                close,
+                close,
+                dec!(1000),
+                now,
+            ),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_wasm_strategy_emits_signals() {
+        let plugin = load(&breakout_wat()).unwrap();
+        assert_eq!(plugin.metadata().name, "wasm_breakout");
+        assert_eq!(plugin.metadata().version, "0.2.0");
+
+        let mut strategy = plugin.create_strategy();
+        assert_eq!(strategy.name(), "wasm_breakout");
+        // 초기화 전에는 인스턴스가 없으므로 신호 없음
+        assert!(strategy
+            .on_market_data(&kline(dec!(100)))
+            .await
+            .unwrap()
+            .is_empty());
+
+        strategy
+            .initialize(json!({ "ticker": "005930" }))
+            .await
+            .unwrap();
+        assert!(strategy
+            .on_market_data(&kline(dec!(100)))
+            .await
+            .unwrap()
+            .is_empty());
+        let signals = strategy.on_market_data(&kline(dec!(101))).await.unwrap();
+
+        assert_eq!(signals.len(), 1);
+        assert_eq!(signals[0].strategy_id, "wasm_breakout");
+        assert_eq!(signals[0].ticker, "005930");
+        assert_eq!(signals[0].side, Side::Buy);
+        assert_eq!(signals[0].signal_type, SignalType::Entry);
+        assert_eq!(signals[0].strength, 0.5);
+        assert_eq!(signals[0].suggested_price, Some(dec!(101.5)));
+        assert_eq!(signals[0].metadata["source"], "wasm");
+
+        let state = strategy.get_state();
+        assert_eq!(state["calls"], 3);
+        assert!(state["fuel_consumed"].as_u64().unwrap() > 0);
+    }
+
+    #[tokio::test]
+    async fn test_wasm_strategy_state_round_trip() {
+        let plugin = load(&breakout_wat()).unwrap();
+        let mut strategy = plugin.create_strategy();
+        strategy.initialize(json!({})).await.unwrap();
+        strategy.on_market_data(&kline(dec!(100))).await.unwrap();
+        let saved = strategy.save_state().unwrap();
+        assert_eq!(saved, 1u32.to_le_bytes());
+
+        // 재시작한 인스턴스가 저장된 카운터에서 이어서 진행
+        let mut restarted = plugin.create_strategy();
+        restarted.initialize(json!({})).await.unwrap();
+        restarted.load_state(&saved).unwrap();
+        let signals = restarted.on_market_data(&kline(dec!(100))).await.unwrap();
+        assert_eq!(signals.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_wasm_fuel_exhaustion_terminates_instance() {
+        let plugin = load(&minimal_wat(
+            r#"(memory (export "memory") 1)"#,
+            1,
+            "(loop $spin (br $spin)) (i64.const 0)",
+        ))
+        .unwrap();
+        let mut strategy = plugin.create_strategy();
+        strategy.initialize(json!({})).await.unwrap();
+
+        let err = strategy
+            .on_market_data(&kline(dec!(100)))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("trader_on_market_data"));
+        assert!(strategy.get_state()["terminated"].is_string());
+        assert!(!strategy.get_state()["running"].as_bool().unwrap());
+
+        // 종료된 인스턴스는 신호를 내지 않고, 재초기화하면 새 인스턴스로 실행
+        assert!(strategy
+            .on_market_data(&kline(dec!(100)))
+            .await
+            .unwrap()
+            .is_empty());
+        strategy.initialize(json!({})).await.unwrap();
+        assert!(strategy.get_state()["running"].as_bool().unwrap());
+    }
+
+    #[test]
+    fn test_wasm_sandbox_rejects_invalid_plugins() {
+        // WASI 등 호스트 ABI 외의 가져오기
+        let wasi = minimal_wat(
+            r#"(import "wasi_snapshot_preview1" "fd_write"
+                 (func (param i32 i32 i32 i32) (result i32)))
+               (memory (export "memory") 1)"#,
+            1,
+            "(i64.const 0)",
+        );
+        assert!(matches!(load(&wasi), Err(PluginError::InvalidPlugin(_))));
+
+        // 메모리 한도(64MiB) 초과
+        let huge = minimal_wat(r#"(memory (export "memory") 2048)"#, 1, "(i64.const 0)");
+        assert!(matches!(load(&huge), Err(PluginError::InvalidPlugin(_))));
+
+        // 지원하지 않는 ABI 버전
+        let future = minimal_wat(r#"(memory (export "memory") 1)"#, 2, "(i64.const 0)");
+        assert!(matches!(load(&future), Err(PluginError::InvalidPlugin(_))));
+    }
+
+    #[tokio::test]
+    async fn test_wasm_error_response() {
+        let (data, response) = segment(1024, r#"{"error":"missing ticker"}"#);
+        let plugin = load(&minimal_wat(
+            &format!(r#"(memory (export "memory") 1) {}"#, data),
+            1,
+            &response,
+        ))
+        .unwrap();
+        let mut strategy = plugin.create_strategy();
+        strategy.initialize(json!({})).await.unwrap();
+
+        let err = strategy
+            .on_market_data(&kline(dec!(100)))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("missing ticker"));
+        // 플러그인이 보고한 에러는 인스턴스를 유지
+        assert!(strategy.get_state()["running"].as_bool().unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_loader_scans_wasm_plugins() {
+        let dir = std::env::temp_dir().join(format!("wasm_plugins_{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(
+            dir.join("breakout.wasm"),
+            wat::parse_str(breakout_wat()).unwrap(),
+        )
+        .unwrap();
+
+        let loader = PluginLoader::new(LoaderConfig {
+            plugins_dir: dir.clone(),
+            ..Default::default()
+        });
+        let loaded = loader.scan_and_load().await.unwrap();
+        assert_eq!(loaded.len(), 1);
+        assert_eq!(loaded[0].name, "wasm_breakout");
+
+        let mut strategy = loader.create_strategy("wasm_breakout").await.unwrap();
+        strategy.initialize(json!({})).await.unwrap();
+        assert_eq!(strategy.version(), "0.2.0");
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
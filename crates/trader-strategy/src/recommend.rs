@@ -0,0 +1,192 @@
+//! 종목별 전략 추천.
+//!
+//! 단일 종목에 적용할 수 있는 내장 전략을 고르고, SDUI 스키마의 정수 파라미터(기간/룩백)를
+//! 기본값 주변으로 변형한 후보 설정을 만듭니다. 후보별 백테스트 결과는 위험 조정 점수
+//! (샤프 비율 - 최대 낙폭/100)로 비교하여 전략마다 가장 좋은 설정만 남깁니다.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use trader_core::{FieldType, MarketType};
+
+use crate::registry::{StrategyCategory, StrategyMeta};
+
+/// 기본값 대비 파라미터 변형 배율.
+const VARIANT_FACTORS: [f64; 2] = [0.7, 1.4];
+
+/// 종목 하나로 추천 백테스트가 가능한 전략인지 여부.
+///
+/// 지정 종목 세트가 있는 자산배분/로테이션 전략, 다중 타임프레임 전략,
+/// 실시간(틱/1분봉) 전략은 제외합니다.
+pub fn is_recommendable(meta: &StrategyMeta, market: MarketType) -> bool {
+    meta.default_tickers.len() <= 1
+        && meta.secondary_timeframes.is_empty()
+        && meta.category != StrategyCategory::Realtime
+        && meta.supported_markets.contains(&market)
+}
+
+/// 추천 백테스트용 파라미터 후보.
+///
+/// 첫 번째 후보는 스키마 기본값이며, 이후 후보는 정수 필드 하나씩을
+/// 기본값의 0.7배/1.4배(최소/최대값 범위 안)로 바꾼 설정입니다.
+///
+/// # Arguments
+/// * `max_variants` - 기본값 외 최대 변형 후보 수
+pub fn parameter_candidates(meta: &StrategyMeta, max_variants: usize) -> Vec<Value> {
+    let Some(schema) = meta.ui_schema_factory.map(|factory| factory()) else {
+        return vec![Value::Object(Map::new())];
+    };
+
+    let mut base = Map::new();
+    let mut tunable = Vec::new();
+    for field in &schema.custom_fields {
+        if field.hidden || matches!(field.field_type, FieldType::Symbol | FieldType::Symbols) {
+            continue;
+        }
+        let Some(mut default) = field.default.clone() else {
+            continue;
+        };
+        if field.field_type == FieldType::Integer {
+            // 스키마 기본값은 실수(10.0)로 생성되므로 정수로 맞춤
+            if let Some(value) = default.as_f64() {
+                default = Value::from(value.round() as i64);
+                if field.condition.is_none() {
+                    tunable.push((field.name.clone(), value, field.min, field.max));
+                }
+            }
+        }
+        base.insert(field.name.clone(), default);
+    }
+
+    let mut candidates = vec![Value::Object(base.clone())];
+    for (name, default, min, max) in tunable {
+        for factor in VARIANT_FACTORS {
+            if candidates.len() > max_variants {
+                return candidates;
+            }
+            let mut value = (default * factor).round();
+            if let Some(min) = min {
+                value = value.max(min);
+            }
+            if let Some(max) = max {
+                value = value.min(max);
+            }
+            if value == default || value < 1.0 {
+                continue;
+            }
+            let mut params = base.clone();
+            params.insert(name.clone(), Value::from(value as i64));
+            candidates.push(Value::Object(params));
+        }
+    }
+    candidates
+}
+
+/// 전략/파라미터 후보 하나의 백테스트 성과.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StrategyFit {
+    pub strategy_id: String,
+    /// 백테스트에 사용한 파라미터
+    pub parameters: Value,
+    /// 총 수익률 (%)
+    pub total_return_pct: f64,
+    pub sharpe_ratio: f64,
+    /// 최대 낙폭 (%)
+    pub max_drawdown_pct: f64,
+    pub total_trades: usize,
+    /// 승률 (%)
+    pub win_rate_pct: f64,
+}
+
+impl StrategyFit {
+    /// 위험 조정 점수 (샤프 비율 - 최대 낙폭/100).
+    pub fn score(&self) -> f64 {
+        self.sharpe_ratio - self.max_drawdown_pct.abs() / 100.0
+    }
+}
+
+/// 전략별 최적 후보를 점수순으로 정렬.
+///
+/// 거래 수가 `min_trades` 미만인 후보는 제외하고, 전략마다 점수가 가장 높은
+/// 후보 하나만 남깁니다 (동점이면 수익률순).
+pub fn rank_fits(fits: Vec<StrategyFit>, min_trades: usize, top: usize) -> Vec<StrategyFit> {
+    let mut best: HashMap<String, StrategyFit> = HashMap::new();
+    for fit in fits.into_iter().filter(|f| f.total_trades >= min_trades) {
+        match best.get(&fit.strategy_id) {
+            Some(current) if compare_fits(current, &fit).is_le() => {}
+            _ => {
+                best.insert(fit.strategy_id.clone(), fit);
+            }
+        }
+    }
+
+    let mut ranked: Vec<StrategyFit> = best.into_values().collect();
+    ranked.sort_by(compare_fits);
+    ranked.truncate(top);
+    ranked
+}
+
+/// 점수 내림차순 비교 (동점이면 수익률 내림차순).
+fn compare_fits(a: &StrategyFit, b: &StrategyFit) -> std::cmp::Ordering {
+    b.score()
+        .total_cmp(&a.score())
+        .then_with(|| b.total_return_pct.total_cmp(&a.total_return_pct))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::StrategyRegistry;
+
+    fn fit(strategy_id: &str, sharpe: f64, mdd: f64, trades: usize) -> StrategyFit {
+        StrategyFit {
+            strategy_id: strategy_id.to_string(),
+            parameters: Value::Null,
+            total_return_pct: sharpe * 10.0,
+            sharpe_ratio: sharpe,
+            max_drawdown_pct: mdd,
+            total_trades: trades,
+            win_rate_pct: 50.0,
+        }
+    }
+
+    #[test]
+    fn test_parameter_candidates() {
+        let meta = StrategyRegistry::find("sma_crossover").unwrap();
+        assert!(is_recommendable(meta, MarketType::Stock));
+
+        let candidates = parameter_candidates(meta, 4);
+        assert_eq!(candidates.len(), 5);
+        assert_eq!(candidates[0]["short_period"], 10);
+        assert_eq!(candidates[0]["long_period"], 20);
+        assert!(candidates[0].get("ticker").is_none());
+        assert_eq!(candidates[1]["short_period"], 7);
+        assert_eq!(candidates[2]["short_period"], 14);
+        assert_eq!(candidates[3]["long_period"], 14);
+        assert_eq!(candidates[4]["long_period"], 28);
+
+        // 지정 종목 세트가 있는 자산배분 전략은 제외
+        let haa = StrategyRegistry::find("haa").unwrap();
+        assert!(!is_recommendable(haa, MarketType::Stock));
+    }
+
+    #[test]
+    fn test_rank_fits() {
+        let ranked = rank_fits(
+            vec![
+                fit("rsi", 0.8, 10.0, 12),
+                fit("rsi", 1.2, 30.0, 15),
+                fit("sma_crossover", 1.0, 5.0, 8),
+                fit("bollinger", 3.0, 5.0, 1),
+            ],
+            3,
+            5,
+        );
+        // 거래 수 부족 제외, 전략별 최고 점수만
+        assert_eq!(ranked.len(), 2);
+        assert_eq!(ranked[0].strategy_id, "sma_crossover");
+        assert_eq!(ranked[1].strategy_id, "rsi");
+        assert_eq!(ranked[1].sharpe_ratio, 1.2);
+    }
+}
@@ -76,6 +76,7 @@ impl SchemaComposer {
     }
 
     /// Fragment를 섹션으로 변환합니다.
+    #[allow(dead_code)]
     fn fragment_to_section(&self, fragment: &SchemaFragment, required: bool) -> Value {
         json!({
             "id": fragment.id,
@@ -89,6 +90,7 @@ impl SchemaComposer {
     }
 
     /// 커스텀 필드를 섹션으로 변환합니다.
+    #[allow(dead_code)]
     fn custom_fields_section(&self, fields: &[FieldSchema]) -> Value {
         json!({
             "id": "custom",
@@ -232,7 +234,7 @@ mod tests {
 
         assert!(catalog["fragments"].is_array());
         let fragments = catalog["fragments"].as_array().unwrap();
-        assert!(fragments.len() > 0);
+        assert!(!fragments.is_empty());
 
         // RSI fragment가 있는지 확인
         let rsi_fragment = fragments.iter().find(|f| f["id"] == "indicator.rsi");
@@ -100,6 +100,7 @@ impl FragmentRegistry {
         self.register(create_route_state_filter());
         self.register(create_market_regime_filter());
         self.register(create_volume_filter());
+        self.register(create_weekly_trend_filter());
 
         // RiskManagement 카테고리
         self.register(create_stop_loss_fragment());
@@ -442,6 +443,45 @@ fn create_volume_filter() -> SchemaFragment {
         ])
 }
 
+fn create_weekly_trend_filter() -> SchemaFragment {
+    SchemaFragment::new(
+        "filter.weekly_trend",
+        "주봉 추세 필터",
+        FragmentCategory::Filter,
+    )
+    .with_description("주봉 이동평균 위/아래에서만 진입")
+    .with_fields(vec![
+        FieldSchema {
+            name: "enabled".to_string(),
+            field_type: FieldType::Boolean,
+            label: "주봉 추세 필터 활성화".to_string(),
+            default: Some(json!(false)),
+            required: true,
+            ..Default::default()
+        },
+        FieldSchema {
+            name: "period".to_string(),
+            field_type: FieldType::Integer,
+            label: "주봉 MA 기간 (주)".to_string(),
+            default: Some(json!(20)),
+            min: Some(2.0),
+            max: Some(104.0),
+            condition: Some("enabled == true".to_string()),
+            ..Default::default()
+        },
+        FieldSchema {
+            name: "direction".to_string(),
+            field_type: FieldType::Select,
+            label: "진입 허용 방향".to_string(),
+            description: Some("above: 주봉 MA 위에서만, below: 주봉 MA 아래에서만".to_string()),
+            default: Some(json!("above")),
+            options: vec!["above".to_string(), "below".to_string()],
+            condition: Some("enabled == true".to_string()),
+            ..Default::default()
+        },
+    ])
+}
+
 // ============================================================================
 // RiskManagement Fragments
 // ============================================================================
@@ -865,4 +905,14 @@ mod tests {
         assert_eq!(rsi.fields[1].name, "overbought");
         assert_eq!(rsi.fields[2].name, "oversold");
     }
+
+    #[test]
+    fn test_weekly_trend_filter_fragment() {
+        let registry = FragmentRegistry::with_builtins();
+        let fragment = registry.get("filter.weekly_trend").unwrap();
+
+        assert_eq!(fragment.category, FragmentCategory::Filter);
+        let names: Vec<&str> = fragment.fields.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, vec!["enabled", "period", "direction"]);
+    }
 }
@@ -0,0 +1,478 @@
+//! 섀도 비교 (실거래 vs 백테스트).
+//!
+//! 실거래 전략과 같은 설정의 섀도 인스턴스에 동일한 시장 데이터를 공급하고,
+//! 두 인스턴스의 신호와 체결가를 비교해 구현 편차(drift)를 탐지합니다.
+//!
+//! 섀도 인스턴스는 백테스트와 마찬가지로 시장 데이터만 받으며 체결/포지션 알림은
+//! 받지 않습니다. 섀도 체결가는 신호가 발생한 시점의 시장 가격(캔들 종가)입니다.
+//!
+//! # 탐지 항목
+//!
+//! - `missing_in_live`: 섀도에서만 발생한 신호
+//! - `missing_in_shadow`: 실거래에서만 발생한 신호
+//! - `fill_price_deviation`: 섀도 체결가 대비 실거래 체결가 괴리가 허용치 초과
+
+use chrono::{DateTime, Duration, Utc};
+use rust_decimal::prelude::*;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use tracing::warn;
+use trader_core::{Side, Signal, SignalType};
+
+/// 체결 대기 중인 매칭 신호 최대 보관 수
+const MAX_AWAITING_FILLS: usize = 1000;
+
+/// 섀도 비교 설정.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShadowConfig {
+    /// 신호 매칭 허용 시간 (초, 이 시간 안에 상대 신호가 없으면 불일치)
+    #[serde(default = "default_match_window_secs")]
+    pub match_window_secs: i64,
+    /// 체결가 괴리 허용치 (bp)
+    #[serde(default = "default_price_tolerance_bps")]
+    pub price_tolerance_bps: f64,
+    /// 보관할 최근 불일치 수
+    #[serde(default = "default_max_discrepancies")]
+    pub max_discrepancies: usize,
+}
+
+fn default_match_window_secs() -> i64 {
+    300
+}
+
+fn default_price_tolerance_bps() -> f64 {
+    100.0
+}
+
+fn default_max_discrepancies() -> usize {
+    200
+}
+
+impl Default for ShadowConfig {
+    fn default() -> Self {
+        Self {
+            match_window_secs: default_match_window_secs(),
+            price_tolerance_bps: default_price_tolerance_bps(),
+            max_discrepancies: default_max_discrepancies(),
+        }
+    }
+}
+
+/// 불일치 유형.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiscrepancyKind {
+    /// 섀도에서만 발생한 신호
+    MissingInLive,
+    /// 실거래에서만 발생한 신호
+    MissingInShadow,
+    /// 체결가 괴리 허용치 초과
+    FillPriceDeviation,
+}
+
+/// 실거래/섀도 불일치 기록.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShadowDiscrepancy {
+    /// 불일치 유형
+    pub kind: DiscrepancyKind,
+    /// 종목
+    pub ticker: String,
+    /// 방향
+    pub side: Side,
+    /// 신호 유형 (체결가 괴리는 None)
+    pub signal_type: Option<SignalType>,
+    /// 실거래 가격 (신호 제안가 또는 체결가)
+    pub live_price: Option<Decimal>,
+    /// 섀도 가격 (신호 시점 시장 가격)
+    pub shadow_price: Option<Decimal>,
+    /// 체결가 괴리 (bp, 실거래에 불리한 방향이 양수)
+    pub deviation_bps: Option<f64>,
+    /// 신호/체결 시각
+    pub occurred_at: DateTime<Utc>,
+    /// 탐지 시각
+    pub detected_at: DateTime<Utc>,
+}
+
+/// 섀도 비교 누적 통계.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ShadowStats {
+    /// 실거래 신호 수
+    pub live_signals: u64,
+    /// 섀도 신호 수
+    pub shadow_signals: u64,
+    /// 양쪽에서 일치한 신호 수
+    pub matched_signals: u64,
+    /// 섀도에서만 발생한 신호 수
+    pub missing_in_live: u64,
+    /// 실거래에서만 발생한 신호 수
+    pub missing_in_shadow: u64,
+    /// 체결가를 비교한 실거래 체결 수
+    pub fills_compared: u64,
+    /// 허용치를 초과한 체결 수
+    pub fill_deviations: u64,
+    /// 평균 체결가 괴리 (bp)
+    pub avg_fill_deviation_bps: f64,
+    /// 최대 체결가 괴리 절대값 (bp)
+    pub max_fill_deviation_bps: f64,
+}
+
+impl ShadowStats {
+    /// 신호 일치율 (판정이 끝난 신호 기준, 판정된 신호가 없으면 None).
+    pub fn signal_match_rate(&self) -> Option<f64> {
+        let decided = self.matched_signals + self.missing_in_live + self.missing_in_shadow;
+        (decided > 0).then(|| self.matched_signals as f64 / decided as f64)
+    }
+}
+
+/// 전략별 섀도 비교 리포트.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShadowReport {
+    /// 전략 ID
+    pub strategy_id: String,
+    /// 섀도 시작 시각
+    pub started_at: DateTime<Utc>,
+    /// 마지막으로 비교한 시장 데이터 시각
+    pub last_data_at: Option<DateTime<Utc>>,
+    /// 누적 통계
+    pub stats: ShadowStats,
+    /// 신호 일치율
+    pub signal_match_rate: Option<f64>,
+    /// 매칭 대기 중인 실거래 신호 수
+    pub pending_live: usize,
+    /// 매칭 대기 중인 섀도 신호 수
+    pub pending_shadow: usize,
+    /// 최근 불일치 (최신순)
+    pub recent_discrepancies: Vec<ShadowDiscrepancy>,
+}
+
+impl ShadowReport {
+    /// 불일치 발생 여부.
+    pub fn has_drift(&self) -> bool {
+        self.stats.missing_in_live + self.stats.missing_in_shadow + self.stats.fill_deviations > 0
+    }
+}
+
+/// 비교용 신호 요약.
+#[derive(Debug, Clone)]
+struct SignalRecord {
+    ticker: String,
+    side: Side,
+    signal_type: SignalType,
+    price: Option<Decimal>,
+    at: DateTime<Utc>,
+}
+
+impl SignalRecord {
+    fn new(signal: &Signal, price: Option<Decimal>, at: DateTime<Utc>) -> Self {
+        Self {
+            ticker: signal.ticker.clone(),
+            side: signal.side,
+            signal_type: signal.signal_type,
+            price,
+            at,
+        }
+    }
+
+    fn same_signal(&self, other: &Self) -> bool {
+        self.ticker == other.ticker
+            && self.side == other.side
+            && self.signal_type == other.signal_type
+    }
+}
+
+/// 실거래/섀도 신호 및 체결 비교기.
+#[derive(Debug)]
+pub struct ShadowTracker {
+    config: ShadowConfig,
+    started_at: DateTime<Utc>,
+    last_data_at: Option<DateTime<Utc>>,
+    pending_live: VecDeque<SignalRecord>,
+    pending_shadow: VecDeque<SignalRecord>,
+    /// 양쪽에서 일치했고 실거래 체결을 기다리는 섀도 신호
+    awaiting_fill: VecDeque<SignalRecord>,
+    stats: ShadowStats,
+    discrepancies: VecDeque<ShadowDiscrepancy>,
+}
+
+impl ShadowTracker {
+    /// 새 비교기 생성.
+    pub fn new(config: ShadowConfig) -> Self {
+        Self {
+            config,
+            started_at: Utc::now(),
+            last_data_at: None,
+            pending_live: VecDeque::new(),
+            pending_shadow: VecDeque::new(),
+            awaiting_fill: VecDeque::new(),
+            stats: ShadowStats::default(),
+            discrepancies: VecDeque::new(),
+        }
+    }
+
+    /// 비교 설정.
+    pub fn config(&self) -> &ShadowConfig {
+        &self.config
+    }
+
+    /// 같은 시장 데이터에 대한 실거래/섀도 신호 기록 및 매칭.
+    ///
+    /// # Arguments
+    ///
+    /// * `live` - 실거래 인스턴스가 생성한 신호
+    /// * `shadow` - 섀도 인스턴스가 생성한 신호
+    /// * `price` - 시장 데이터의 현재 가격 (섀도 체결가)
+    /// * `at` - 시장 데이터 시각
+    pub fn record_signals(
+        &mut self,
+        live: &[Signal],
+        shadow: &[Signal],
+        price: Option<Decimal>,
+        at: DateTime<Utc>,
+    ) {
+        self.last_data_at = Some(at);
+        self.stats.live_signals += live.len() as u64;
+        self.stats.shadow_signals += shadow.len() as u64;
+
+        let mut live: Vec<SignalRecord> = live
+            .iter()
+            .map(|s| SignalRecord::new(s, s.suggested_price.or(price), at))
+            .collect();
+
+        for signal in shadow {
+            let record = SignalRecord::new(signal, price.or(signal.suggested_price), at);
+            if let Some(i) = live.iter().position(|l| l.same_signal(&record)) {
+                live.remove(i);
+                self.matched(record);
+            } else if let Some(i) = self
+                .pending_live
+                .iter()
+                .position(|l| l.same_signal(&record))
+            {
+                self.pending_live.remove(i);
+                self.matched(record);
+            } else {
+                self.pending_shadow.push_back(record);
+            }
+        }
+
+        for record in live {
+            match self
+                .pending_shadow
+                .iter()
+                .position(|s| s.same_signal(&record))
+            {
+                Some(i) => {
+                    let shadow = self.pending_shadow.remove(i).expect("index in range");
+                    self.matched(shadow);
+                }
+                None => self.pending_live.push_back(record),
+            }
+        }
+
+        self.expire_pending(at);
+    }
+
+    /// 실거래 체결을 섀도 체결가와 비교.
+    ///
+    /// 같은 종목/방향으로 매칭된 가장 오래된 신호와 비교하며, 분할 체결은 첫 체결만 비교합니다.
+    ///
+    /// # Returns
+    ///
+    /// 체결가 괴리 (bp, 실거래에 불리한 방향이 양수). 비교 대상이 없으면 None.
+    pub fn record_live_fill(
+        &mut self,
+        ticker: &str,
+        side: Side,
+        price: Decimal,
+        at: DateTime<Utc>,
+    ) -> Option<f64> {
+        let index = self
+            .awaiting_fill
+            .iter()
+            .position(|s| s.ticker == ticker && s.side == side)?;
+        let shadow = self.awaiting_fill.remove(index)?;
+        let shadow_price = shadow.price.filter(|p| *p > Decimal::ZERO)?;
+
+        let diff = match side {
+            Side::Buy => price - shadow_price,
+            Side::Sell => shadow_price - price,
+        };
+        let deviation_bps = (diff / shadow_price * Decimal::from(10_000))
+            .to_f64()
+            .unwrap_or(0.0);
+
+        let n = self.stats.fills_compared as f64;
+        self.stats.fills_compared += 1;
+        self.stats.avg_fill_deviation_bps =
+            (self.stats.avg_fill_deviation_bps * n + deviation_bps) / (n + 1.0);
+        self.stats.max_fill_deviation_bps =
+            self.stats.max_fill_deviation_bps.max(deviation_bps.abs());
+
+        if deviation_bps.abs() > self.config.price_tolerance_bps {
+            self.stats.fill_deviations += 1;
+            self.push_discrepancy(ShadowDiscrepancy {
+                kind: DiscrepancyKind::FillPriceDeviation,
+                ticker: ticker.to_string(),
+                side,
+                signal_type: None,
+                live_price: Some(price),
+                shadow_price: Some(shadow_price),
+                deviation_bps: Some(deviation_bps),
+                occurred_at: at,
+                detected_at: Utc::now(),
+            });
+        }
+
+        Some(deviation_bps)
+    }
+
+    /// 비교 리포트 생성.
+    pub fn report(&self, strategy_id: &str) -> ShadowReport {
+        ShadowReport {
+            strategy_id: strategy_id.to_string(),
+            started_at: self.started_at,
+            last_data_at: self.last_data_at,
+            stats: self.stats.clone(),
+            signal_match_rate: self.stats.signal_match_rate(),
+            pending_live: self.pending_live.len(),
+            pending_shadow: self.pending_shadow.len(),
+            recent_discrepancies: self.discrepancies.iter().rev().cloned().collect(),
+        }
+    }
+
+    fn matched(&mut self, shadow: SignalRecord) {
+        self.stats.matched_signals += 1;
+        self.awaiting_fill.push_back(shadow);
+        if self.awaiting_fill.len() > MAX_AWAITING_FILLS {
+            self.awaiting_fill.pop_front();
+        }
+    }
+
+    /// 매칭 허용 시간이 지난 대기 신호를 불일치로 판정.
+    fn expire_pending(&mut self, now: DateTime<Utc>) {
+        let cutoff = now - Duration::seconds(self.config.match_window_secs);
+
+        while self.pending_shadow.front().is_some_and(|s| s.at < cutoff) {
+            let record = self.pending_shadow.pop_front().expect("checked front");
+            self.stats.missing_in_live += 1;
+            self.push_signal_discrepancy(DiscrepancyKind::MissingInLive, record);
+        }
+        while self.pending_live.front().is_some_and(|s| s.at < cutoff) {
+            let record = self.pending_live.pop_front().expect("checked front");
+            self.stats.missing_in_shadow += 1;
+            self.push_signal_discrepancy(DiscrepancyKind::MissingInShadow, record);
+        }
+    }
+
+    fn push_signal_discrepancy(&mut self, kind: DiscrepancyKind, record: SignalRecord) {
+        let (live_price, shadow_price) = match kind {
+            DiscrepancyKind::MissingInLive => (None, record.price),
+            _ => (record.price, None),
+        };
+        self.push_discrepancy(ShadowDiscrepancy {
+            kind,
+            ticker: record.ticker,
+            side: record.side,
+            signal_type: Some(record.signal_type),
+            live_price,
+            shadow_price,
+            deviation_bps: None,
+            occurred_at: record.at,
+            detected_at: Utc::now(),
+        });
+    }
+
+    fn push_discrepancy(&mut self, discrepancy: ShadowDiscrepancy) {
+        warn!(
+            kind = ?discrepancy.kind,
+            ticker = %discrepancy.ticker,
+            side = ?discrepancy.side,
+            deviation_bps = ?discrepancy.deviation_bps,
+            "Shadow comparison discrepancy"
+        );
+        self.discrepancies.push_back(discrepancy);
+        while self.discrepancies.len() > self.config.max_discrepancies {
+            self.discrepancies.pop_front();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn signal(ticker: &str, side: Side) -> Signal {
+        Signal::new("rsi_1", ticker.to_string(), side, SignalType::Entry)
+    }
+
+    #[test]
+    fn test_signal_matching_and_expiry() {
+        let mut tracker = ShadowTracker::new(ShadowConfig::default());
+        let t0 = Utc::now();
+
+        // 동일 신호 → 일치
+        tracker.record_signals(
+            &[signal("005930", Side::Buy)],
+            &[signal("005930", Side::Buy)],
+            Some(dec!(70000)),
+            t0,
+        );
+        // 섀도만 신호 → 다음 캔들에서 실거래가 따라오면 일치
+        tracker.record_signals(&[], &[signal("000660", Side::Buy)], Some(dec!(1)), t0);
+        tracker.record_signals(
+            &[signal("000660", Side::Buy)],
+            &[],
+            Some(dec!(1)),
+            t0 + Duration::seconds(60),
+        );
+        // 실거래만 신호 → 허용 시간 경과 후 불일치
+        tracker.record_signals(&[signal("035720", Side::Sell)], &[], Some(dec!(1)), t0);
+        tracker.record_signals(&[], &[], None, t0 + Duration::seconds(301));
+
+        let report = tracker.report("rsi_1");
+        assert_eq!(report.stats.matched_signals, 2);
+        assert_eq!(report.stats.missing_in_shadow, 1);
+        assert_eq!(report.stats.missing_in_live, 0);
+        assert_eq!(report.recent_discrepancies.len(), 1);
+        assert_eq!(
+            report.recent_discrepancies[0].kind,
+            DiscrepancyKind::MissingInShadow
+        );
+        assert!(report.has_drift());
+    }
+
+    #[test]
+    fn test_fill_price_deviation() {
+        let mut tracker = ShadowTracker::new(ShadowConfig {
+            price_tolerance_bps: 50.0,
+            ..Default::default()
+        });
+        let t0 = Utc::now();
+        tracker.record_signals(
+            &[signal("005930", Side::Buy), signal("000660", Side::Sell)],
+            &[signal("005930", Side::Buy), signal("000660", Side::Sell)],
+            Some(dec!(10000)),
+            t0,
+        );
+
+        // 매수 10bp 불리 → 허용치 이내
+        let bps = tracker.record_live_fill("005930", Side::Buy, dec!(10010), t0);
+        assert_eq!(bps, Some(10.0));
+        // 매도 100bp 불리 → 괴리
+        let bps = tracker.record_live_fill("000660", Side::Sell, dec!(9900), t0);
+        assert_eq!(bps, Some(100.0));
+        // 매칭 신호 없는 체결은 비교하지 않음
+        assert_eq!(
+            tracker.record_live_fill("005930", Side::Buy, dec!(1), t0),
+            None
+        );
+
+        let report = tracker.report("rsi_1");
+        assert_eq!(report.stats.fills_compared, 2);
+        assert_eq!(report.stats.fill_deviations, 1);
+        assert_eq!(report.stats.avg_fill_deviation_bps, 55.0);
+        assert_eq!(report.stats.max_fill_deviation_bps, 100.0);
+    }
+}
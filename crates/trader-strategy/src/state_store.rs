@@ -0,0 +1,42 @@
+//! 전략 내부 상태 영속화.
+//!
+//! 분할 매수 차수, 물타기 라운드처럼 전략이 메모리에 들고 있는 진행 상태를
+//! [`Strategy::save_state`](crate::Strategy::save_state) 바이트로 저장합니다.
+//! 엔진에 [`StrategyStateStore`]가 설정되어 있으면 상태가 바뀔 때마다 저장하고,
+//! 재시작 후 같은 ID로 등록된 전략을 시작할 때 워밍업 다음에 복원합니다.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+/// 저장된 전략 상태.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StrategyState {
+    /// 전략 인스턴스 ID
+    pub strategy_id: String,
+    /// `save_state()` 결과
+    pub data: Vec<u8>,
+    /// 저장 시각
+    pub saved_at: DateTime<Utc>,
+}
+
+/// 전략 상태 저장소.
+#[async_trait]
+pub trait StrategyStateStore: Send + Sync {
+    /// 전략의 마지막 저장 상태 조회.
+    async fn load(
+        &self,
+        strategy_id: &str,
+    ) -> Result<Option<StrategyState>, Box<dyn std::error::Error + Send + Sync>>;
+
+    /// 전략 상태 저장 (기존 상태 덮어쓰기).
+    async fn save(
+        &self,
+        state: &StrategyState,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+
+    /// 전략 상태 삭제 (전략 등록 해제 시).
+    async fn delete(
+        &self,
+        strategy_id: &str,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+}
@@ -914,7 +914,7 @@ impl AssetAllocationStrategy {
             })
             .collect();
 
-        ranked.sort_by(|a, b| b.1.cmp(&a.1));
+        ranked.sort_by_key(|r| std::cmp::Reverse(r.1));
 
         let top_n = match self.current_mode {
             PortfolioMode::Offensive => config.offensive_top_n.min(ranked.len()),
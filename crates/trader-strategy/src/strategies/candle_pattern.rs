@@ -24,7 +24,9 @@
 //! 2. 패턴 강도 평가 (Volume, Trend 확인)
 //! 3. 다중 패턴 확인 시 강화 신호
 
-use crate::strategies::common::{deserialize_ticker, ExitConfig};
+use crate::strategies::common::{
+    deserialize_ticker, ExitConfig, WeeklyTrendFilter, WeeklyTrendFilterConfig,
+};
 use crate::Strategy;
 use trader_strategy_macro::StrategyConfig;
 use async_trait::async_trait;
@@ -169,6 +171,11 @@ pub struct CandlePatternConfig {
     #[serde(default)]
     #[fragment("risk.exit_config")]
     pub exit_config: ExitConfig,
+
+    /// 주봉 추세 필터 (주봉 MA 위/아래에서만 매수).
+    #[serde(default)]
+    #[fragment("filter.weekly_trend", optional)]
+    pub weekly_trend_filter: WeeklyTrendFilterConfig,
 }
 
 fn default_ticker() -> String {
@@ -213,6 +220,7 @@ impl Default for CandlePatternConfig {
             enabled_patterns: Vec::new(),
             min_global_score: default_min_global_score(),
             exit_config: ExitConfig::default(),
+            weekly_trend_filter: WeeklyTrendFilterConfig::default(),
         }
     }
 }
@@ -297,6 +305,13 @@ impl CandlePatternStrategy {
                 return false;
             }
         }
+
+        // 주봉 추세 체크
+        let weekly_filter = WeeklyTrendFilter::new(config.weekly_trend_filter.clone());
+        if !weekly_filter.allows_entry(&ctx_lock, ticker) {
+            debug!(ticker = %ticker, "[CandlePattern] 주봉 추세 불일치 - 진입 제한");
+            return false;
+        }
         true
     }
 
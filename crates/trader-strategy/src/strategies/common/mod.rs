@@ -4,9 +4,9 @@
 //!
 //! - **defaults**: 전략 기본 상수 (지표, 리스크, 그리드, 모멘텀, 배분)
 //! - **indicators**: 기술적 지표 계산 (RSI, SMA, EMA, BB, MACD, ATR)
-//! - **position_sizing**: 포지션 크기 계산 (Kelly, FixedRatio, ATR 기반)
+//! - **position_sizing**: 포지션 크기 계산 (Kelly, FixedRatio, ATR, 변동성 타깃 기반)
 //! - **risk_checks**: 리스크 검증 및 관리
-//! - **signal_filters**: 신호 필터링 및 확인
+//! - **signal_filters**: 신호 필터링 및 확인 (거래량, 추세, 주봉 MA 추세)
 //! - **모멘텀**: 자산 배분 전략을 위한 다기간 모멘텀 스코어링
 //! - **리밸런싱**: 포트폴리오 리밸런싱 계산
 //! - **serde_helpers**: SDUI와 전략 설정 간 타입 변환
@@ -51,7 +51,7 @@ pub use indicators::{
 
 pub use position_sizing::{
     AtrPositionSizer, FixedRatioSizer, GlobalScorePositionSizer, KellyPositionSizer, PositionSize,
-    PositionSizer,
+    PositionSizer, VolatilityTargetSizer,
 };
 
 pub use global_score_utils::{
@@ -67,8 +67,9 @@ pub use screening_integration::{
 pub use risk_checks::{DefaultRiskChecker, RiskCheckError, RiskChecker, RiskManager, RiskParams};
 
 pub use signal_filters::{
-    CompositeFilter, ConfirmationPattern, FilteredSignal, SignalContext, SignalFilter,
-    SignalStrength, TrendFilter, VolumeFilter,
+    weekly_closes, weekly_ma_distance_pct, CompositeFilter, ConfirmationPattern, FilteredSignal,
+    SignalContext, SignalFilter, SignalStrength, TrendFilter, VolumeFilter, WeeklyTrendDirection,
+    WeeklyTrendFilter, WeeklyTrendFilterConfig,
 };
 
 pub use exit_config::ExitConfig;
@@ -2,9 +2,11 @@
 //!
 //! 이 모듈은 자금 관리를 위한 다양한 포지션 사이징 방법을 제공합니다.
 
+use rust_decimal::prelude::FromPrimitive;
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
+use trader_core::domain::StrategyContext;
 
 /// 포지션 사이징 결과.
 #[derive(Debug, Clone)]
@@ -305,6 +307,80 @@ impl PositionSizer for GlobalScorePositionSizer {
     }
 }
 
+/// 변동성 타깃 포지션 사이저.
+///
+/// 종목의 실현 변동성이 높을수록 비중을 줄여 포지션별 위험을 목표 변동성에 맞춥니다.
+/// 비율 = 목표 변동성 / 실현 변동성 (최대 비율로 제한)
+///
+/// 실현 변동성은 수집기가 매일 계산해 `StrategyContext`에 동기화한 값을 사용합니다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VolatilityTargetSizer {
+    /// 목표 변동성 (연율화 %, 예: 15 = 15%)
+    pub target_vol_pct: Decimal,
+    /// 최대 자본 비율 (0.0 ~ 1.0)
+    pub max_ratio: Decimal,
+    /// 변동성 데이터가 없을 때 사용할 자본 비율 (0.0 ~ 1.0)
+    pub fallback_ratio: Decimal,
+}
+
+impl VolatilityTargetSizer {
+    pub fn new(target_vol_pct: Decimal, max_ratio: Decimal, fallback_ratio: Decimal) -> Self {
+        let max_ratio = max_ratio.min(dec!(1)).max(dec!(0));
+        Self {
+            target_vol_pct: target_vol_pct.max(dec!(0)),
+            max_ratio,
+            fallback_ratio: fallback_ratio.min(max_ratio).max(dec!(0)),
+        }
+    }
+
+    /// 실현 변동성(연율화 %)으로 포지션 크기 계산.
+    ///
+    /// 변동성이 없거나 0 이하이면 `fallback_ratio`를 사용합니다.
+    pub fn calculate_with_volatility(
+        &self,
+        capital: Decimal,
+        realized_vol_pct: Option<f64>,
+    ) -> PositionSize {
+        let ratio = realized_vol_pct
+            .and_then(Decimal::from_f64)
+            .filter(|vol| *vol > Decimal::ZERO)
+            .map(|vol| (self.target_vol_pct / vol).min(self.max_ratio))
+            .unwrap_or(self.fallback_ratio);
+
+        PositionSize {
+            size: capital * ratio,
+            method: "VolatilityTarget".to_string(),
+        }
+    }
+
+    /// 전략 컨텍스트의 종목 실현 변동성으로 포지션 크기 계산.
+    ///
+    /// 60일 변동성을 우선 사용합니다 (`SymbolRiskMetrics::volatility_pct`).
+    pub fn calculate_for_ticker(
+        &self,
+        capital: Decimal,
+        context: &StrategyContext,
+        ticker: &str,
+    ) -> PositionSize {
+        let vol = context
+            .get_symbol_risk(ticker)
+            .and_then(|risk| risk.volatility_pct());
+        self.calculate_with_volatility(capital, vol)
+    }
+}
+
+impl PositionSizer for VolatilityTargetSizer {
+    fn calculate_size(
+        &self,
+        capital: Decimal,
+        _entry_price: Decimal,
+        _stop_loss: Option<Decimal>,
+    ) -> PositionSize {
+        // 기본 구현: 변동성 정보가 없으면 대체 비율 사용
+        self.calculate_with_volatility(capital, None)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -398,4 +474,47 @@ mod tests {
         let result5 = sizer.calculate_with_score(capital, 50.0);
         assert_eq!(result5.size, dec!(500)); // 10000 * 0.1 * 0.5
     }
+
+    #[test]
+    fn test_volatility_target_sizer() {
+        let sizer = VolatilityTargetSizer::new(dec!(15), dec!(0.3), dec!(0.1));
+        let capital = dec!(10000);
+
+        // 30% 변동성 → 15 / 30 = 0.5 → 최대 비율 0.3으로 제한
+        assert_eq!(
+            sizer.calculate_with_volatility(capital, Some(30.0)).size,
+            dec!(3000)
+        );
+        // 60% 변동성 → 15 / 60 = 0.25
+        assert_eq!(
+            sizer.calculate_with_volatility(capital, Some(60.0)).size,
+            dec!(2500)
+        );
+        // 변동성 없음 → 대체 비율
+        assert_eq!(
+            sizer.calculate_with_volatility(capital, None).size,
+            dec!(1000)
+        );
+
+        let mut context = StrategyContext::default();
+        context.update_symbol_risk(
+            [(
+                "005930".to_string(),
+                trader_core::domain::SymbolRiskMetrics {
+                    ticker: "005930".to_string(),
+                    realized_vol_60d: Some(75.0),
+                    ..Default::default()
+                },
+            )]
+            .into(),
+        );
+        assert_eq!(
+            sizer.calculate_for_ticker(capital, &context, "005930").size,
+            dec!(2000)
+        );
+        assert_eq!(
+            sizer.calculate_for_ticker(capital, &context, "AAPL").size,
+            dec!(1000)
+        );
+    }
 }
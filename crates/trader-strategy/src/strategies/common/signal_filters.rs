@@ -2,9 +2,12 @@
 //!
 //! 이 모듈은 전략에서 생성된 신호를 필터링하고 검증하는 기능을 제공합니다.
 
+use chrono::Datelike;
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
+use trader_core::domain::StrategyContext;
+use trader_core::{Kline, Timeframe};
 
 /// 신호 강도.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -58,6 +61,8 @@ pub struct SignalContext {
     pub macd_histogram: Option<Decimal>,
     /// 추세 방향 (1: 상승, -1: 하락, 0: 중립)
     pub trend: i8,
+    /// 주봉 MA 대비 이격도 (%)
+    pub weekly_ma_distance_pct: Option<Decimal>,
 }
 
 /// 거래량 필터.
@@ -176,6 +181,193 @@ impl SignalFilter for TrendFilter {
     }
 }
 
+/// 주봉 MA 기준 진입 방향.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WeeklyTrendDirection {
+    /// 주봉 종가가 주봉 MA 위일 때만 진입 (추세 추종)
+    #[default]
+    Above,
+    /// 주봉 종가가 주봉 MA 아래일 때만 진입 (역추세)
+    Below,
+}
+
+/// 주봉 추세 필터 설정.
+///
+/// `#[fragment("filter.weekly_trend", optional)]`와 함께 전략 설정에 포함하면
+/// 어떤 전략이든 주봉 MA 위/아래에서만 진입하도록 제한할 수 있습니다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WeeklyTrendFilterConfig {
+    /// 필터 활성화 (기본값: false)
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// 주봉 MA 기간 (주) (기본값: 20)
+    #[serde(default = "default_weekly_ma_period")]
+    pub period: usize,
+
+    /// 진입 허용 방향 (기본값: above)
+    #[serde(default)]
+    pub direction: WeeklyTrendDirection,
+}
+
+fn default_weekly_ma_period() -> usize {
+    20
+}
+
+impl Default for WeeklyTrendFilterConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            period: default_weekly_ma_period(),
+            direction: WeeklyTrendDirection::default(),
+        }
+    }
+}
+
+/// 일봉을 주 단위(ISO 주, 월요일 시작)로 묶어 주봉 종가 목록으로 변환.
+///
+/// 각 주의 마지막 거래일 종가를 사용하며, 진행 중인 마지막 주도 포함합니다.
+/// `daily_klines`는 날짜 오름차순이어야 합니다.
+pub fn weekly_closes(daily_klines: &[Kline]) -> Vec<Decimal> {
+    let mut closes: Vec<Decimal> = Vec::new();
+    let mut current_week: Option<(i32, u32)> = None;
+
+    for kline in daily_klines {
+        let iso_week = kline.open_time.date_naive().iso_week();
+        let week = (iso_week.year(), iso_week.week());
+
+        if current_week == Some(week) {
+            if let Some(last) = closes.last_mut() {
+                *last = kline.close;
+            }
+        } else {
+            closes.push(kline.close);
+            current_week = Some(week);
+        }
+    }
+
+    closes
+}
+
+/// 마지막 주봉 종가의 주봉 MA 대비 이격도 (%).
+///
+/// 주봉 수가 `period`보다 적으면 None을 반환합니다.
+pub fn weekly_ma_distance_pct(weekly_closes: &[Decimal], period: usize) -> Option<Decimal> {
+    if period == 0 || weekly_closes.len() < period {
+        return None;
+    }
+
+    let recent = &weekly_closes[weekly_closes.len() - period..];
+    let ma = recent.iter().sum::<Decimal>() / Decimal::from(period);
+    if ma <= Decimal::ZERO {
+        return None;
+    }
+
+    let close = *weekly_closes.last()?;
+    Some((close - ma) / ma * dec!(100))
+}
+
+/// 주봉 추세 필터.
+///
+/// 주봉 종가가 주봉 MA 위(또는 아래)에 있을 때만 진입 신호를 허용합니다.
+/// 주봉 MA를 계산할 데이터가 없으면 신호를 그대로 통과시킵니다.
+#[derive(Debug, Clone, Default)]
+pub struct WeeklyTrendFilter {
+    /// 필터 설정
+    pub config: WeeklyTrendFilterConfig,
+}
+
+impl WeeklyTrendFilter {
+    pub fn new(config: WeeklyTrendFilterConfig) -> Self {
+        Self { config }
+    }
+
+    /// 전략 컨텍스트의 캔들로 주봉 MA 이격도 계산.
+    ///
+    /// 주봉(W1) 캔들이 충분하면 그대로 사용하고, 없으면 일봉(D1)을 주봉으로 묶어 계산합니다.
+    pub fn distance_from_context(&self, ctx: &StrategyContext, ticker: &str) -> Option<Decimal> {
+        let weekly = ctx.get_klines(ticker, Timeframe::W1);
+        let closes: Vec<Decimal> = if weekly.len() >= self.config.period {
+            weekly.iter().map(|k| k.close).collect()
+        } else {
+            weekly_closes(ctx.get_klines(ticker, Timeframe::D1))
+        };
+        weekly_ma_distance_pct(&closes, self.config.period)
+    }
+
+    /// 전략 컨텍스트 기준 진입 허용 여부.
+    pub fn allows_entry(&self, ctx: &StrategyContext, ticker: &str) -> bool {
+        if !self.config.enabled {
+            return true;
+        }
+        self.check(self.distance_from_context(ctx, ticker)).is_valid
+    }
+
+    /// 주봉 MA 이격도로 진입 허용 여부 판정.
+    fn check(&self, distance_pct: Option<Decimal>) -> FilteredSignal {
+        let Some(distance) = distance_pct else {
+            // 주봉 데이터가 없으면 통과
+            return FilteredSignal {
+                is_valid: true,
+                strength: SignalStrength::Medium,
+                reason: None,
+            };
+        };
+
+        let aligned = match self.config.direction {
+            WeeklyTrendDirection::Above => distance > Decimal::ZERO,
+            WeeklyTrendDirection::Below => distance < Decimal::ZERO,
+        };
+
+        if !aligned {
+            return FilteredSignal {
+                is_valid: false,
+                strength: SignalStrength::Weak,
+                reason: Some(format!(
+                    "주봉 MA{} 추세 불일치: 이격도 {:.2}%",
+                    self.config.period, distance
+                )),
+            };
+        }
+
+        // 이격도가 클수록 추세가 뚜렷함
+        let strength = if distance.abs() >= dec!(5) {
+            SignalStrength::Strong
+        } else {
+            SignalStrength::Medium
+        };
+
+        FilteredSignal {
+            is_valid: true,
+            strength,
+            reason: None,
+        }
+    }
+}
+
+impl SignalFilter for WeeklyTrendFilter {
+    fn filter(&self, signal: bool, context: &SignalContext) -> FilteredSignal {
+        if !self.config.enabled {
+            return FilteredSignal {
+                is_valid: signal,
+                strength: SignalStrength::Medium,
+                reason: None,
+            };
+        }
+
+        if !signal {
+            return FilteredSignal {
+                is_valid: false,
+                strength: SignalStrength::Weak,
+                reason: Some("원본 신호 없음".to_string()),
+            };
+        }
+
+        self.check(context.weekly_ma_distance_pct)
+    }
+}
+
 /// 복합 필터.
 ///
 /// 여러 필터를 순차적으로 적용합니다.
@@ -283,6 +475,7 @@ mod tests {
             rsi: None,
             macd_histogram: None,
             trend: 1,
+            weekly_ma_distance_pct: None,
         };
 
         let result = filter.filter(true, &context);
@@ -300,6 +493,7 @@ mod tests {
             rsi: None,
             macd_histogram: None,
             trend: 1,
+            weekly_ma_distance_pct: None,
         };
 
         let result = filter.filter(true, &context);
@@ -316,6 +510,7 @@ mod tests {
             rsi: None,
             macd_histogram: None,
             trend: 1, // 상승 추세
+            weekly_ma_distance_pct: None,
         };
 
         let result = filter.filter(true, &context); // 매수 신호
@@ -332,6 +527,7 @@ mod tests {
             rsi: None,
             macd_histogram: None,
             trend: -1, // 하락 추세
+            weekly_ma_distance_pct: None,
         };
 
         let result = filter.filter(true, &context); // 매수 신호
@@ -351,6 +547,7 @@ mod tests {
             rsi: None,
             macd_histogram: None,
             trend: 1,
+            weekly_ma_distance_pct: None,
         };
 
         let result = filter.filter(true, &context);
@@ -369,4 +566,81 @@ mod tests {
         pattern.add_signal(false); // 리셋
         assert!(!pattern.add_signal(true)); // 다시 1부터 시작
     }
+
+    fn daily_kline(day: u32, close: Decimal) -> Kline {
+        use chrono::{TimeZone, Utc};
+        let open_time = Utc.with_ymd_and_hms(2024, 1, day, 0, 0, 0).unwrap();
+        Kline {
+            ticker: "TEST".to_string(),
+            timeframe: Timeframe::D1,
+            open_time,
+            open: close,
+            high: close,
+            low: close,
+            close,
+            volume: dec!(1000),
+            close_time: open_time + chrono::Duration::hours(6),
+            quote_volume: None,
+            num_trades: None,
+        }
+    }
+
+    #[test]
+    fn test_weekly_closes() {
+        // 2024-01-01(월) ~ 01-05(금), 01-08(월) ~ 01-09(화)
+        let klines: Vec<Kline> = [1, 2, 3, 4, 5, 8, 9]
+            .iter()
+            .map(|&day| daily_kline(day, Decimal::from(100 + day)))
+            .collect();
+
+        assert_eq!(weekly_closes(&klines), vec![dec!(105), dec!(109)]);
+        assert!(weekly_closes(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_weekly_ma_distance_pct() {
+        let closes = vec![dec!(90), dec!(100), dec!(110), dec!(120)];
+        // 최근 3주 MA = 110, 종가 120 → +9.09%
+        let distance = weekly_ma_distance_pct(&closes, 3).unwrap();
+        assert!((distance - dec!(9.0909)).abs() < dec!(0.001));
+        assert!(weekly_ma_distance_pct(&closes, 5).is_none());
+    }
+
+    #[test]
+    fn test_weekly_trend_filter() {
+        let context = |distance: Option<Decimal>| SignalContext {
+            current_price: dec!(100),
+            volume: dec!(1000),
+            avg_volume: None,
+            rsi: None,
+            macd_histogram: None,
+            trend: 0,
+            weekly_ma_distance_pct: distance,
+        };
+
+        let above = WeeklyTrendFilter::new(WeeklyTrendFilterConfig {
+            enabled: true,
+            ..Default::default()
+        });
+        assert!(above.filter(true, &context(Some(dec!(2)))).is_valid);
+        assert_eq!(
+            above.filter(true, &context(Some(dec!(8)))).strength,
+            SignalStrength::Strong
+        );
+        assert!(!above.filter(true, &context(Some(dec!(-1)))).is_valid);
+        // 주봉 데이터 없으면 통과
+        assert!(above.filter(true, &context(None)).is_valid);
+
+        let below = WeeklyTrendFilter::new(WeeklyTrendFilterConfig {
+            enabled: true,
+            direction: WeeklyTrendDirection::Below,
+            ..Default::default()
+        });
+        assert!(below.filter(true, &context(Some(dec!(-3)))).is_valid);
+        assert!(!below.filter(true, &context(Some(dec!(3)))).is_valid);
+
+        // 비활성화 시 원본 신호 유지
+        let disabled = WeeklyTrendFilter::default();
+        assert!(disabled.filter(true, &context(Some(dec!(-10)))).is_valid);
+    }
 }
@@ -24,6 +24,8 @@
 //!
 //! - `RouteState`: Attack/Armed에서만 진입
 //! - `GlobalScore`: 최소 점수 이상일 때만 진입
+//! - 스크리닝 결과: VolumeSurge는 `market_interest_day` 프리셋 결과가 있으면
+//!   포함된 종목만 진입 (백테스트에서는 리밸런싱 시점의 과거 스크리닝 스냅샷)
 //!
 //! # 사용 예시
 //!
@@ -49,7 +51,6 @@
 //! });
 //! ```
 
-use crate::strategies::common::deserialize_ticker;
 use crate::Strategy;
 use async_trait::async_trait;
 use chrono::{DateTime, Timelike, Utc};
@@ -65,6 +66,9 @@ use tracing::{debug, info};
 use trader_core::domain::{RouteState, StrategyContext};
 use trader_core::{MarketData, MarketDataType, Order, Position, Side, Signal};
 
+/// VolumeSurge 진입 유니버스로 사용하는 스크리닝 프리셋 이름.
+pub const MARKET_INTEREST_PRESET: &str = "market_interest_day";
+
 /// 일간 트레이딩 전략 변형.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[derive(Default)]
@@ -708,6 +712,16 @@ impl DayTradingStrategy {
             return true;
         };
 
+        // 스크리닝 유니버스 체크 (VolumeSurge, 스크리닝 결과가 있을 때만)
+        if config.variant == DayTradingVariant::VolumeSurge {
+            if let Some(results) = ctx_lock.screening_results.get(MARKET_INTEREST_PRESET) {
+                if !results.iter().any(|r| r.passed && r.ticker == *ticker) {
+                    debug!(ticker = %ticker, "스크리닝 유니버스 미포함");
+                    return false;
+                }
+            }
+        }
+
         // RouteState 체크
         if let Some(route_state) = ctx_lock.get_route_state(ticker) {
             match route_state {
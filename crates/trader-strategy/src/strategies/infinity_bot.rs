@@ -617,6 +617,34 @@ impl Strategy for InfinityBotStrategy {
             "last_entry_price": self.last_entry_price.map(|d| d.to_string()),
         })
     }
+
+    /// 라운드 진행 상태 저장 (가격 히스토리는 워밍업으로 다시 채움).
+    fn save_state(&self) -> Result<Vec<u8>, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(serde_json::to_vec(&PersistedState {
+            state: self.state.clone(),
+            last_entry_price: self.last_entry_price,
+        })?)
+    }
+
+    fn load_state(&mut self, data: &[u8]) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let persisted: PersistedState = serde_json::from_slice(data)?;
+        self.state = persisted.state;
+        self.last_entry_price = persisted.last_entry_price;
+
+        info!(
+            rounds = self.state.current_round,
+            total_qty = %self.state.total_quantity,
+            "InfinityBot 상태 복원"
+        );
+        Ok(())
+    }
+}
+
+/// 영속화 상태.
+#[derive(Serialize, Deserialize)]
+struct PersistedState {
+    state: InfinityBotState,
+    last_entry_price: Option<Decimal>,
 }
 
 // ============================================================================
@@ -674,10 +702,12 @@ mod tests {
 
     #[test]
     fn test_current_return() {
-        let mut state = InfinityBotState::default();
-        state.invested_amount = dec!(10000);
-        state.total_quantity = dec!(10);
-        state.avg_price = Some(dec!(1000));
+        let state = InfinityBotState {
+            invested_amount: dec!(10000),
+            total_quantity: dec!(10),
+            avg_price: Some(dec!(1000)),
+            ..Default::default()
+        };
 
         // 10% 수익
         let ret = state.current_return(dec!(1100));
@@ -701,4 +731,42 @@ mod tests {
         assert!(strategy.config.is_some());
         assert_eq!(strategy.state.current_round, 0);
     }
+
+    #[tokio::test]
+    async fn test_state_survives_restart() {
+        let config = json!({ "ticker": "005930" });
+
+        let mut strategy = InfinityBotStrategy::new();
+        strategy.initialize(config.clone()).await.unwrap();
+        strategy.state.current_round = 2;
+        strategy.state.rounds = vec![
+            RoundInfo {
+                round: 1,
+                entry_price: dec!(1000),
+                quantity: dec!(10),
+                timestamp: 1,
+            },
+            RoundInfo {
+                round: 2,
+                entry_price: dec!(980),
+                quantity: dec!(10),
+                timestamp: 2,
+            },
+        ];
+        strategy.state.invested_amount = dec!(19800);
+        strategy.state.total_quantity = dec!(20);
+        strategy.state.avg_price = strategy.state.calculate_avg_price();
+        strategy.last_entry_price = Some(dec!(980));
+        let saved = strategy.save_state().unwrap();
+
+        // 재시작: 초기화로 상태가 비워진 뒤 저장된 상태 복원
+        let mut restarted = InfinityBotStrategy::new();
+        restarted.initialize(config).await.unwrap();
+        restarted.load_state(&saved).unwrap();
+
+        assert_eq!(restarted.state.current_round, 2);
+        assert_eq!(restarted.state.rounds.len(), 2);
+        assert_eq!(restarted.state.avg_price, Some(dec!(990)));
+        assert_eq!(restarted.last_entry_price, Some(dec!(980)));
+    }
 }
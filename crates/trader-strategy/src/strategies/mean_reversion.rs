@@ -28,9 +28,11 @@ use trader_strategy_macro::StrategyConfig;
 use std::collections::VecDeque;
 use std::sync::Arc;
 use tokio::sync::RwLock;
-use tracing::{debug, info};
+use tracing::{debug, info, warn};
 use trader_core::domain::{RouteState, StrategyContext};
-use trader_core::{MarketData, MarketDataType, Order, Position, Side, Signal, SignalType};
+use trader_core::{
+    MarketData, MarketDataType, Order, Position, ScaleOut, Side, Signal, SignalType,
+};
 
 // ================================================================================================
 // 설정 타입
@@ -646,7 +648,7 @@ impl MeanReversionConfig {
 // ================================================================================================
 
 /// 포지션 상태.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 struct PositionState {
     /// 포지션 방향.
     side: Option<Side>,
@@ -659,7 +661,7 @@ struct PositionState {
 }
 
 /// 분할 레벨 상태.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 struct SplitLevelState {
     /// 매수 여부.
     is_bought: bool,
@@ -670,7 +672,7 @@ struct SplitLevelState {
 }
 
 /// 그리드 레벨 상태.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct GridLevel {
     /// 매수 가격 (이 가격에서 매수).
     buy_price: Decimal,
@@ -681,7 +683,7 @@ struct GridLevel {
 }
 
 /// 그리드 레벨 상태.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 enum GridLevelState {
     /// 매수 대기 (가격이 buy_price에 도달하면 매수).
     WaitingBuy,
@@ -689,6 +691,17 @@ enum GridLevelState {
     WaitingSell,
 }
 
+/// 영속화 상태 (재시작 후 복원할 포지션/그리드/분할 진행 상태).
+#[derive(Debug, Serialize, Deserialize)]
+struct PersistedState {
+    position: PositionState,
+    cooldown_counter: usize,
+    grid_levels: Vec<GridLevel>,
+    grid_base_price: Decimal,
+    split_states: Vec<SplitLevelState>,
+    split_entry_date: Option<String>,
+}
+
 /// 분할 매수 액션.
 #[derive(Debug, Clone, Copy)]
 enum SplitAction {
@@ -1480,18 +1493,36 @@ impl MeanReversionStrategy {
                     }
                 }
                 SplitAction::Sell => {
-                    signals.push(
+                    // 다른 차수가 남아있으면 해당 차수 비중만 부분 청산
+                    let held: Decimal = self
+                        .split_states
+                        .iter()
+                        .filter(|s| s.is_bought)
+                        .map(|s| s.quantity)
+                        .sum();
+                    let level_quantity = self.split_states[i].quantity;
+                    let signal = if held > level_quantity {
+                        Signal::scale_out(
+                            "mean_reversion",
+                            ticker.clone(),
+                            Side::Sell,
+                            ScaleOut::Ratio(level_quantity / held),
+                        )
+                    } else {
                         Signal::new(
                             "mean_reversion",
                             ticker.clone(),
                             Side::Sell,
                             SignalType::Exit,
                         )
-                        .with_strength(0.9)
-                        .with_prices(Some(price), None, None)
-                        .with_metadata("variant", json!("split"))
-                        .with_metadata("level", json!(i + 1))
-                        .with_metadata("profit_rate", json!(value.to_string())),
+                    };
+                    signals.push(
+                        signal
+                            .with_strength(0.9)
+                            .with_prices(Some(price), None, None)
+                            .with_metadata("variant", json!("split"))
+                            .with_metadata("level", json!(i + 1))
+                            .with_metadata("profit_rate", json!(value.to_string())),
                     );
                     self.split_states[i].is_bought = false;
                     self.split_states[i].entry_price = Decimal::ZERO;
@@ -1742,6 +1773,51 @@ impl Strategy for MeanReversionStrategy {
         })
     }
 
+    /// 포지션, 그리드 레벨, 분할 차수 상태 저장 (지표는 워밍업으로 다시 계산).
+    fn save_state(&self) -> Result<Vec<u8>, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(serde_json::to_vec(&PersistedState {
+            position: self.position.clone(),
+            cooldown_counter: self.cooldown_counter,
+            grid_levels: self.grid_levels.clone(),
+            grid_base_price: self.grid_base_price,
+            split_states: self.split_states.clone(),
+            split_entry_date: self.split_entry_date.clone(),
+        })?)
+    }
+
+    fn load_state(&mut self, data: &[u8]) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let persisted: PersistedState = serde_json::from_slice(data)?;
+
+        // 분할 레벨 구성이 바뀌었으면 차수 상태는 복원하지 않음
+        let split_levels = match self.config.as_ref().map(|c| &c.entry_signal) {
+            Some(EntrySignalConfig::Split { levels }) => Some(levels.len()),
+            _ => None,
+        };
+        if split_levels.map_or(true, |len| len == persisted.split_states.len()) {
+            self.split_states = persisted.split_states;
+            self.split_entry_date = persisted.split_entry_date;
+        } else {
+            warn!(
+                saved = persisted.split_states.len(),
+                configured = ?split_levels,
+                "[MeanReversion] 분할 레벨 수가 달라 차수 상태를 복원하지 않음"
+            );
+        }
+
+        self.position = persisted.position;
+        self.cooldown_counter = persisted.cooldown_counter;
+        self.grid_levels = persisted.grid_levels;
+        self.grid_base_price = persisted.grid_base_price;
+
+        info!(
+            has_position = self.has_position(),
+            bought_splits = self.split_states.iter().filter(|s| s.is_bought).count(),
+            grid_levels = self.grid_levels.len(),
+            "[MeanReversion] 상태 복원"
+        );
+        Ok(())
+    }
+
     fn set_context(&mut self, context: Arc<RwLock<StrategyContext>>) {
         self.context = Some(context);
         info!("[MeanReversion] StrategyContext 주입 완료");
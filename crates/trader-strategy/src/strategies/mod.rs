@@ -21,6 +21,8 @@
 //! - **Pension Bot**: 연금 자동화 정적+동적 자산배분.
 //! - **US 3X Leverage**: 미국 3배 레버리지/인버스 ETF 조합 전략.
 //! - **RSI Multi TF**: RSI 다중 타임프레임 전략.
+//! - **Webhook**: TradingView 등 외부 알림 신호 실행.
+//! - **Pairs**: 합성 스프레드/비율 z-score 평균회귀 (leg 바스켓 주문).
 //!
 //! ## 한국 지수 전략
 //!
@@ -48,12 +50,14 @@ pub mod infinity_bot;
 pub mod market_bothside;
 pub mod momentum_power;
 pub mod momentum_surge;
+pub mod pairs;
 pub mod pension_bot;
 pub mod range_trading;
 pub mod rsi_multi_tf;
 pub mod sector_vb;
 pub mod small_cap_quant;
 pub mod us_3x_leverage;
+pub mod webhook;
 
 // 그룹 전략 re-exports
 pub use asset_allocation::{
@@ -62,7 +66,7 @@ pub use asset_allocation::{
 };
 pub use day_trading::{
     BreakoutConfig, CrossoverConfig, DayTradingConfig, DayTradingStrategy, DayTradingVariant,
-    ExitConfig as DayTradingExitConfig, VolumeSurgeConfig,
+    ExitConfig as DayTradingExitConfig, VolumeSurgeConfig, MARKET_INTEREST_PRESET,
 };
 pub use mean_reversion::{
     EntrySignalConfig, ExitConfig, MeanReversionConfig, MeanReversionStrategy, SplitLevel,
@@ -84,9 +88,11 @@ pub use infinity_bot::*;
 pub use market_bothside::*;
 pub use momentum_power::*;
 pub use momentum_surge::*;
+pub use pairs::*;
 pub use pension_bot::*;
 pub use range_trading::*;
 pub use rsi_multi_tf::*;
 pub use sector_vb::*;
 pub use small_cap_quant::*;
 pub use us_3x_leverage::*;
+pub use webhook::*;
@@ -0,0 +1,544 @@
+//! Pairs Strategy - 합성 스프레드 평균회귀 전략
+//!
+//! ## 핵심 아이디어
+//!
+//! 두 종목 이상의 가격식(스프레드 `A - 1.5*B` 또는 비율 `A / B`)을 하나의 합성 종목으로
+//! 보고, 합성 가격의 z-score가 크게 벌어지면 반대 방향으로 진입해 평균 회귀를 노립니다.
+//!
+//! - z-score > `entry_z` → 합성 종목 매도 (스프레드 축소 기대)
+//! - z-score < -`entry_z` → 합성 종목 매수 (스프레드 확대 기대)
+//! - |z-score| < `exit_z` → 청산 (평균 회귀)
+//! - |z-score| > `stop_z` → 손절 (관계 붕괴), |z-score|가 `entry_z` 아래로 돌아올 때까지 재진입 안 함
+//!
+//! ## 실행
+//!
+//! 신호는 `SYN_<코드>` 티커로 생성되며, 메타데이터의 [`SyntheticSignalSpec`]
+//! (가격식, 진입 단위, 신호 시점 leg 가격)으로 실행기가 leg 주문을 바스켓으로 분해합니다.
+//!
+//! 합성 가격은 모든 leg의 같은 시각 캔들 종가가 모였을 때만 계산합니다 (시세/체결 틱은 무시).
+
+use crate::Strategy;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use tracing::{debug, info};
+use trader_core::{
+    synthetic_ticker, MarketData, MarketDataType, Order, Position, Side, Signal,
+    SyntheticDefinition, SyntheticExpression, SyntheticSignalSpec, SYNTHETIC_SIGNAL_KEY,
+};
+use trader_strategy_macro::StrategyConfig;
+
+// ============================================================================
+// 설정 (Config)
+// ============================================================================
+
+/// Pairs 전략 설정
+#[derive(Debug, Clone, Serialize, Deserialize, StrategyConfig)]
+#[strategy(
+    id = "pairs",
+    name = "페어 트레이딩",
+    description = "합성 스프레드/비율의 z-score 평균회귀 매매",
+    category = "Daily"
+)]
+pub struct PairsConfig {
+    /// 가격식 (예: `005930 - 1.5*000660`, `KO / PEP`)
+    #[serde(default = "default_expression")]
+    #[schema(label = "가격식", field_type = "string", default = "005930 - 000660")]
+    pub expression: String,
+
+    /// 합성 종목 코드 (신호 티커 `SYN_<코드>`)
+    #[serde(default = "default_code")]
+    #[schema(label = "합성 종목 코드", field_type = "string", default = "PAIRS")]
+    pub code: String,
+
+    /// z-score 계산 기간 (캔들 수)
+    #[serde(default = "default_lookback")]
+    #[schema(label = "z-score 기간", min = 10, max = 500, default = 60)]
+    pub lookback: usize,
+
+    /// 진입 z-score
+    #[serde(default = "default_entry_z")]
+    #[schema(label = "진입 z-score", min = 0.5, max = 5, default = 2.0)]
+    pub entry_z: Decimal,
+
+    /// 청산 z-score (평균 회귀)
+    #[serde(default = "default_exit_z")]
+    #[schema(label = "청산 z-score", min = 0, max = 3, default = 0.5)]
+    pub exit_z: Decimal,
+
+    /// 손절 z-score (관계 붕괴)
+    #[serde(default = "default_stop_z")]
+    #[schema(label = "손절 z-score", min = 1, max = 10, default = 4.0)]
+    pub stop_z: Decimal,
+
+    /// 진입 단위 (비율식은 분자 leg 수량)
+    #[serde(default = "default_units")]
+    #[schema(label = "진입 단위", min = 0, max = 1000000, default = 1)]
+    pub units: Decimal,
+}
+
+fn default_expression() -> String {
+    "005930 - 000660".to_string()
+}
+fn default_code() -> String {
+    "PAIRS".to_string()
+}
+fn default_lookback() -> usize {
+    60
+}
+fn default_entry_z() -> Decimal {
+    Decimal::TWO
+}
+fn default_exit_z() -> Decimal {
+    Decimal::new(5, 1)
+}
+fn default_stop_z() -> Decimal {
+    Decimal::from(4)
+}
+fn default_units() -> Decimal {
+    Decimal::ONE
+}
+
+impl Default for PairsConfig {
+    fn default() -> Self {
+        Self {
+            expression: default_expression(),
+            code: default_code(),
+            lookback: default_lookback(),
+            entry_z: default_entry_z(),
+            exit_z: default_exit_z(),
+            stop_z: default_stop_z(),
+            units: default_units(),
+        }
+    }
+}
+
+// ============================================================================
+// 전략 구현
+// ============================================================================
+
+/// Pairs Strategy
+pub struct PairsStrategy {
+    config: Option<PairsConfig>,
+    expression: Option<SyntheticExpression>,
+    /// leg별 캔들 종가 (합성 가격 계산 대기)
+    pending: HashMap<String, BTreeMap<DateTime<Utc>, Decimal>>,
+    /// 합성 가격 이력
+    spreads: VecDeque<Decimal>,
+    /// 현재 합성 종목 포지션 방향
+    position: Option<Side>,
+    /// 손절 후 재진입 대기 여부
+    stopped_out: bool,
+    /// 마지막 z-score
+    last_z: Option<f64>,
+}
+
+impl PairsStrategy {
+    pub fn new() -> Self {
+        Self {
+            config: None,
+            expression: None,
+            pending: HashMap::new(),
+            spreads: VecDeque::new(),
+            position: None,
+            stopped_out: false,
+            last_z: None,
+        }
+    }
+
+    /// leg 종가 기록 후 모든 leg가 모인 시각의 leg 가격 반환.
+    fn record_close(
+        &mut self,
+        ticker: &str,
+        open_time: DateTime<Utc>,
+        close: Decimal,
+    ) -> Option<HashMap<String, Decimal>> {
+        let tickers = self.expression.as_ref()?.tickers();
+        if !tickers.iter().any(|t| t == ticker) {
+            return None;
+        }
+        self.pending
+            .entry(ticker.to_string())
+            .or_default()
+            .insert(open_time, close);
+
+        let prices: HashMap<String, Decimal> = tickers
+            .iter()
+            .filter_map(|t| {
+                let close = self.pending.get(t)?.get(&open_time)?;
+                Some((t.clone(), *close))
+            })
+            .collect();
+        if prices.len() < tickers.len() {
+            return None;
+        }
+
+        // 이미 합성한 시각 이전의 대기 종가 정리
+        for closes in self.pending.values_mut() {
+            closes.retain(|time, _| *time > open_time);
+        }
+        Some(prices)
+    }
+
+    /// 최근 `lookback`개 합성 가격 기준 z-score.
+    fn z_score(&self, lookback: usize) -> Option<f64> {
+        if lookback < 2 || self.spreads.len() < lookback {
+            return None;
+        }
+        let window: Vec<f64> = self
+            .spreads
+            .iter()
+            .skip(self.spreads.len() - lookback)
+            .filter_map(|s| s.to_f64())
+            .collect();
+        let n = window.len() as f64;
+        let mean = window.iter().sum::<f64>() / n;
+        let variance = window.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (n - 1.0);
+        let std = variance.sqrt();
+        if std <= f64::EPSILON {
+            return None;
+        }
+        Some((window.last()? - mean) / std)
+    }
+
+    /// 새 합성 가격으로 진입/청산 판단.
+    fn evaluate(&mut self, leg_prices: HashMap<String, Decimal>) -> Option<Signal> {
+        let config = self.config.as_ref()?;
+        let spread = self.expression.as_ref()?.value(&leg_prices)?;
+
+        self.spreads.push_back(spread);
+        while self.spreads.len() > config.lookback {
+            self.spreads.pop_front();
+        }
+        let z = self.z_score(config.lookback)?;
+        self.last_z = Some(z);
+
+        let entry_z = config.entry_z.to_f64().unwrap_or(2.0);
+        let exit_z = config.exit_z.to_f64().unwrap_or(0.5);
+        let stop_z = config.stop_z.to_f64().unwrap_or(4.0);
+        let ticker = synthetic_ticker(&config.code);
+        let spec = SyntheticSignalSpec {
+            expression: config.expression.clone(),
+            units: Some(config.units),
+            leg_prices,
+        };
+
+        let signal = match self.position {
+            None => {
+                if self.stopped_out {
+                    if z.abs() < entry_z {
+                        self.stopped_out = false;
+                    }
+                    return None;
+                }
+                let side = if z > entry_z {
+                    Side::Sell
+                } else if z < -entry_z {
+                    Side::Buy
+                } else {
+                    return None;
+                };
+                self.position = Some(side);
+                Signal::entry("pairs", ticker, side).with_metadata("reason", json!("divergence"))
+            }
+            Some(side) => {
+                let reason = if z.abs() > stop_z {
+                    self.stopped_out = true;
+                    "stop"
+                } else if z.abs() < exit_z {
+                    "mean_revert"
+                } else {
+                    return None;
+                };
+                self.position = None;
+                Signal::exit("pairs", ticker, side.opposite())
+                    .with_metadata("reason", json!(reason))
+            }
+        };
+
+        Some(
+            signal
+                .with_metadata(SYNTHETIC_SIGNAL_KEY, spec.to_value())
+                .with_metadata("spread", json!(spread))
+                .with_metadata("z_score", json!(z)),
+        )
+    }
+}
+
+impl Default for PairsStrategy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ============================================================================
+// Strategy Trait 구현
+// ============================================================================
+
+#[async_trait]
+impl Strategy for PairsStrategy {
+    fn name(&self) -> &str {
+        "Pairs"
+    }
+
+    fn version(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn description(&self) -> &str {
+        "합성 스프레드/비율의 z-score 평균회귀 매매"
+    }
+
+    async fn initialize(
+        &mut self,
+        config: Value,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let cfg: PairsConfig = serde_json::from_value(config)?;
+        let definition = SyntheticDefinition {
+            code: cfg.code.trim().to_uppercase(),
+            name: cfg.code.clone(),
+            expression: SyntheticExpression::parse(&cfg.expression)?,
+        };
+        definition.validate()?;
+        if cfg.exit_z >= cfg.entry_z || cfg.stop_z <= cfg.entry_z {
+            return Err("z-score thresholds must satisfy exit_z < entry_z < stop_z".into());
+        }
+
+        info!(
+            expression = %definition.expression,
+            ticker = %definition.ticker(),
+            lookback = cfg.lookback,
+            "Pairs 전략 초기화"
+        );
+
+        self.config = Some(PairsConfig {
+            code: definition.code,
+            ..cfg
+        });
+        self.expression = Some(definition.expression);
+        self.pending.clear();
+        self.spreads.clear();
+        self.position = None;
+        self.stopped_out = false;
+        self.last_z = None;
+
+        Ok(())
+    }
+
+    async fn on_market_data(
+        &mut self,
+        data: &MarketData,
+    ) -> Result<Vec<Signal>, Box<dyn std::error::Error + Send + Sync>> {
+        let MarketDataType::Kline(kline) = &data.data else {
+            return Ok(vec![]);
+        };
+        let Some(leg_prices) = self.record_close(&data.ticker, kline.open_time, kline.close) else {
+            return Ok(vec![]);
+        };
+
+        match self.evaluate(leg_prices) {
+            Some(signal) => {
+                info!(
+                    ticker = %signal.ticker,
+                    side = ?signal.side,
+                    signal_type = %signal.signal_type,
+                    z_score = ?self.last_z,
+                    "Pairs 신호 생성"
+                );
+                Ok(vec![signal])
+            }
+            None => Ok(vec![]),
+        }
+    }
+
+    async fn on_order_filled(
+        &mut self,
+        order: &Order,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        debug!(
+            ticker = %order.ticker,
+            side = ?order.side,
+            qty = %order.quantity,
+            "Pairs leg 주문 체결"
+        );
+        Ok(())
+    }
+
+    async fn on_position_update(
+        &mut self,
+        position: &Position,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        debug!(
+            ticker = %position.ticker,
+            qty = %position.quantity,
+            "Pairs leg 포지션 업데이트"
+        );
+        Ok(())
+    }
+
+    async fn shutdown(&mut self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        info!("Pairs 전략 종료");
+        Ok(())
+    }
+
+    fn get_state(&self) -> Value {
+        json!({
+            "config": self.config,
+            "position": self.position,
+            "stopped_out": self.stopped_out,
+            "z_score": self.last_z,
+            "spread": self.spreads.back(),
+            "observations": self.spreads.len(),
+        })
+    }
+}
+
+// ============================================================================
+// 레지스트리 등록
+// ============================================================================
+
+use crate::register_strategy;
+
+register_strategy! {
+    id: "pairs",
+    aliases: ["pairs_trading", "spread_trading"],
+    name: "페어 트레이딩",
+    description: "합성 스프레드/비율의 z-score 평균회귀 매매",
+    timeframe: "1d",
+    tickers: ["005930", "000660"],
+    category: Daily,
+    markets: [Stock, Crypto],
+    type: PairsStrategy,
+    config: PairsConfig
+}
+
+// ============================================================================
+// 테스트
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+    use rust_decimal_macros::dec;
+    use trader_core::{Kline, SignalType, Timeframe};
+
+    fn kline_data(ticker: &str, day: i64, close: Decimal) -> MarketData {
+        let open_time = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap() + Duration::days(day);
+        MarketData::from_kline(
+            "krx",
+            Kline {
+                ticker: ticker.to_string(),
+                timeframe: Timeframe::D1,
+                open_time,
+                open: close,
+                high: close,
+                low: close,
+                close,
+                volume: dec!(1000),
+                close_time: open_time + Duration::days(1),
+                quote_volume: None,
+                num_trades: None,
+            },
+        )
+    }
+
+    async fn strategy() -> PairsStrategy {
+        let mut strategy = PairsStrategy::new();
+        strategy
+            .initialize(json!({
+                "expression": "A - B",
+                "code": "ab",
+                "lookback": 10,
+                "entry_z": 2.0,
+                "exit_z": 0.5,
+                "stop_z": 4.0,
+                "units": 5
+            }))
+            .await
+            .unwrap();
+        strategy
+    }
+
+    /// 두 leg 캔들을 전달하고 생성된 신호 반환.
+    async fn feed(strategy: &mut PairsStrategy, day: i64, a: Decimal, b: Decimal) -> Vec<Signal> {
+        // 한 leg만 도착하면 합성 가격을 계산하지 않음
+        let first = strategy
+            .on_market_data(&kline_data("A", day, a))
+            .await
+            .unwrap();
+        assert!(first.is_empty());
+        strategy
+            .on_market_data(&kline_data("B", day, b))
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_spread_divergence_entry_and_exit() {
+        let mut strategy = strategy().await;
+
+        // 스프레드 0/1 반복 (평균 0.5)
+        for day in 0..10 {
+            let spread = Decimal::from(day % 2);
+            assert!(feed(&mut strategy, day, dec!(100) + spread, dec!(100))
+                .await
+                .is_empty());
+        }
+
+        // 스프레드 급등 → 합성 종목 매도 진입
+        let signals = feed(&mut strategy, 10, dec!(103), dec!(100)).await;
+        assert_eq!(signals.len(), 1);
+        let entry = &signals[0];
+        assert_eq!(entry.ticker, "SYN_AB");
+        assert_eq!(entry.side, Side::Sell);
+        assert_eq!(entry.signal_type, SignalType::Entry);
+
+        let spec = SyntheticSignalSpec::from_signal(entry).unwrap();
+        assert_eq!(spec.units, Some(dec!(5)));
+        assert_eq!(spec.leg_prices["A"], dec!(103));
+        assert_eq!(spec.leg_prices["B"], dec!(100));
+
+        // 평균 회귀 → 청산
+        let mut exit = Vec::new();
+        for day in 11..20 {
+            exit = feed(&mut strategy, day, dec!(100.5), dec!(100)).await;
+            if !exit.is_empty() {
+                break;
+            }
+        }
+        assert_eq!(exit.len(), 1);
+        assert_eq!(exit[0].signal_type, SignalType::Exit);
+        assert_eq!(exit[0].side, Side::Buy);
+        assert_eq!(exit[0].metadata["reason"], "mean_revert");
+    }
+
+    #[tokio::test]
+    async fn test_ignores_unrelated_tickers() {
+        let mut strategy = strategy().await;
+        assert!(strategy
+            .on_market_data(&kline_data("C", 0, dec!(100)))
+            .await
+            .unwrap()
+            .is_empty());
+        assert!(strategy.pending.is_empty());
+        assert_eq!(strategy.get_state()["observations"], 0);
+    }
+
+    #[tokio::test]
+    async fn test_rejects_invalid_config() {
+        let mut strategy = PairsStrategy::new();
+        assert!(strategy
+            .initialize(json!({ "expression": "A" }))
+            .await
+            .is_err());
+        assert!(strategy
+            .initialize(json!({ "expression": "A - B", "exit_z": 3.0 }))
+            .await
+            .is_err());
+    }
+}
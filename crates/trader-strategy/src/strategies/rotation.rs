@@ -323,6 +323,7 @@ fn default_sector_top_n() -> usize {
     3
 }
 
+#[allow(dead_code)]
 fn default_use_momentum_filter() -> bool {
     true
 }
@@ -1252,7 +1253,7 @@ impl RotationStrategy {
             .collect();
 
         // 모멘텀 내림차순 정렬
-        assets.sort_by(|a, b| b.score.cmp(&a.score));
+        assets.sort_by_key(|a| std::cmp::Reverse(a.score));
 
         // 순위 부여
         for (i, asset) in assets.iter_mut().enumerate() {
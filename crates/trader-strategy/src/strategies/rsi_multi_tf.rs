@@ -775,6 +775,7 @@ mod tests {
     use super::*;
     use chrono::{TimeZone, Utc};
 
+    #[allow(dead_code)]
     fn create_kline(close: Decimal, open_time_hour: u32) -> Kline {
         Kline {
             ticker: "BTC/USDT".to_string(),
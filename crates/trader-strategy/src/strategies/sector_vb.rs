@@ -492,7 +492,7 @@ impl SectorVbStrategy {
             })
             .collect();
 
-        ranked.sort_by(|a, b| b.returns.cmp(&a.returns));
+        ranked.sort_by_key(|r| std::cmp::Reverse(r.returns));
 
         if let Some(top) = ranked.first() {
             self.selected_sector = Some(top.ticker.clone());
@@ -16,6 +16,8 @@
 //! ## 정렬 및 선택
 //! - 시가총액 오름차순 정렬 (소형주 우선)
 //! - 상위 N개 종목 선택 (기본 20개)
+//! - 필터/정렬은 StrategyContext의 `small_cap_quant` 스크리닝 결과를 사용
+//!   (백테스트에서는 리밸런싱 시점의 과거 스크리닝 스냅샷)
 //!
 //! ## 매매 로직
 //! - 코스닥 소형지수가 20일 MA 위 → 매수 유지
@@ -40,6 +42,9 @@ use trader_strategy_macro::StrategyConfig;
 
 use crate::strategies::common::ExitConfig;
 
+/// 종목 선정에 사용하는 스크리닝 프리셋 이름.
+pub const SMALL_CAP_QUANT_PRESET: &str = "small_cap_quant";
+
 /// 소형주 퀀트 전략 설정.
 #[derive(Debug, Clone, Serialize, Deserialize, StrategyConfig)]
 #[strategy(
@@ -350,7 +355,6 @@ impl SmallCapQuantStrategy {
     }
 
     /// RouteState와 GlobalScore 기반 진입 조건 체크
-    #[allow(dead_code)]
     fn can_enter(&self, ticker: &str) -> bool {
         let context = match &self.context {
             Some(ctx) => ctx,
@@ -415,8 +419,32 @@ impl SmallCapQuantStrategy {
         signals
     }
 
+    /// 스크리닝 결과에서 매수 대상 종목 선정.
+    ///
+    /// 스크리닝 결과 순서(시가총액 오름차순)를 유지하며 GlobalScore 기준을 넘는
+    /// 종목을 최대 `target_count`개 선택합니다. 컨텍스트나 스크리닝 결과가 없으면 빈 목록입니다.
+    fn select_target_stocks(&self) -> Vec<String> {
+        let (Some(config), Some(context)) = (self.config.as_ref(), self.context.as_ref()) else {
+            return Vec::new();
+        };
+        let Ok(ctx) = context.try_read() else {
+            return Vec::new();
+        };
+
+        ctx.screening_results
+            .get(SMALL_CAP_QUANT_PRESET)
+            .map(|results| {
+                results
+                    .iter()
+                    .filter(|r| r.passed && r.overall_score >= config.min_global_score)
+                    .take(config.target_count)
+                    .map(|r| r.ticker.clone())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
     /// 매수 시그널 생성.
-    #[allow(dead_code)]
     fn generate_buy_signals(&self, target_stocks: &[String]) -> Vec<Signal> {
         let config = match self.config.as_ref() {
             Some(c) => c,
@@ -550,10 +578,9 @@ impl Strategy for SmallCapQuantStrategy {
                         self.generate_sell_all_signals()
                     }
                     MarketState::AboveMA => {
-                        // MA 위 → 매수 (실제 구현에서는 종목 선정 필요)
-                        // 여기서는 holdings에 있는 종목 기준으로 처리
-                        // 실제로는 외부에서 필터링된 종목 목록을 받아야 함
-                        Vec::new() // TODO: 종목 선정 로직 필요
+                        // MA 위 → 스크리닝 결과 상위 종목 매수
+                        let targets = self.select_target_stocks();
+                        self.generate_buy_signals(&targets)
                     }
                     _ => Vec::new(),
                 };
@@ -714,6 +741,52 @@ mod tests {
         assert!(!small_stock.passes_filter(&config));
     }
 
+    #[tokio::test]
+    async fn test_select_target_stocks_from_screening() {
+        use trader_core::domain::ScreeningResult;
+
+        let mut strategy = SmallCapQuantStrategy::new();
+        strategy
+            .initialize(json!({ "target_count": 2, "min_global_score": "60" }))
+            .await
+            .unwrap();
+
+        // 컨텍스트 없으면 선정 종목 없음
+        assert!(strategy.select_target_stocks().is_empty());
+
+        let result = |ticker: &str, score: Decimal| ScreeningResult {
+            ticker: ticker.to_string(),
+            preset_name: SMALL_CAP_QUANT_PRESET.to_string(),
+            passed: true,
+            overall_score: score,
+            route_state: RouteState::Armed,
+            criteria_results: HashMap::new(),
+            timestamp: Utc::now(),
+            sector_rs: None,
+            sector_rank: None,
+            trigger_score: None,
+            trigger_label: None,
+        };
+
+        let mut ctx = StrategyContext::default();
+        ctx.update_screening(
+            SMALL_CAP_QUANT_PRESET.to_string(),
+            vec![
+                result("111111", dec!(70)),
+                result("222222", dec!(50)),
+                result("333333", dec!(65)),
+                result("444444", dec!(90)),
+            ],
+        );
+        strategy.set_context(Arc::new(RwLock::new(ctx)));
+
+        // 스크리닝 순서 유지, 점수 미달 제외, target_count 제한
+        assert_eq!(
+            strategy.select_target_stocks(),
+            vec!["111111".to_string(), "333333".to_string()]
+        );
+    }
+
     #[test]
     fn test_index_ma_calculation() {
         let mut index = IndexData::new();
@@ -0,0 +1,477 @@
+//! Webhook Strategy - 외부 신호 실행 전략
+//!
+//! ## 핵심 아이디어
+//!
+//! TradingView 알림이나 사용자 정의 시스템이 생성한 신호를 웹훅으로 받아
+//! 봇의 리스크/실행 스택을 그대로 거쳐 실행합니다. 이 전략은 시장 데이터로
+//! 신호를 만들지 않으며, `on_external_signal()`로 전달된 페이로드만 신호로 변환합니다.
+//!
+//! ## 페이로드 형식
+//!
+//! ```json
+//! {
+//!   "strategy_id": "tv_webhook",
+//!   "ticker": "{{ticker}}",
+//!   "action": "{{strategy.order.action}}",
+//!   "price": {{close}},
+//!   "stop_loss": 68000,
+//!   "comment": "{{strategy.order.comment}}"
+//! }
+//! ```
+//!
+//! - `strategy_id`: 알림을 받을 전략 인스턴스 ID (생성된 신호도 이 ID로 귀속)
+//! - `action`: `buy`/`long` → 매수 진입, `sell` → 매도 청산,
+//!   `short` → 매도 진입 (`allow_short` 필요), `exit`/`close`/`flat` → 청산
+//! - `signal_type`을 지정하면 `action`에서 추론한 유형 대신 사용
+//! - `ticker`의 거래소 접두사(`KRX:005930`)는 제거됨
+//!
+//! ## 안전장치
+//!
+//! - 허용 종목 제한 (`allowed_tickers`)
+//! - 오래된 알림 거부 (`max_signal_age_secs`)
+//! - 동일 종목/방향 반복 알림 쿨다운 (`cooldown_secs`)
+
+use crate::strategies::common::deserialize_tickers;
+use crate::Strategy;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use tracing::{debug, info, warn};
+use trader_core::{MarketData, Order, Position, Side, Signal, SignalType};
+use trader_strategy_macro::StrategyConfig;
+
+// ============================================================================
+// 설정 (Config)
+// ============================================================================
+
+/// Webhook 전략 설정
+#[derive(Debug, Clone, Serialize, Deserialize, StrategyConfig)]
+#[strategy(
+    id = "webhook",
+    name = "Webhook 신호",
+    description = "TradingView 등 외부 알림을 신호로 변환하여 실행",
+    category = "Realtime"
+)]
+pub struct WebhookConfig {
+    /// 허용 종목 (빈 목록 = 전체 허용)
+    #[serde(default, deserialize_with = "deserialize_tickers")]
+    #[schema(label = "허용 종목", field_type = "symbols")]
+    pub allowed_tickers: Vec<String>,
+
+    /// 공매도 진입 허용 여부
+    #[serde(default)]
+    #[schema(label = "공매도 진입 허용")]
+    pub allow_short: bool,
+
+    /// 기본 신호 강도 (페이로드에 없을 때)
+    #[serde(default = "default_strength")]
+    #[schema(label = "기본 신호 강도", min = 0.0, max = 1.0, default = 1.0)]
+    pub default_strength: f64,
+
+    /// 알림 유효 시간 (초, 0 = 제한 없음)
+    #[serde(default = "default_max_signal_age_secs")]
+    #[schema(label = "알림 유효 시간 (초)", min = 0, max = 3600, default = 60)]
+    pub max_signal_age_secs: u64,
+
+    /// 동일 종목/방향 재알림 쿨다운 (초, 0 = 없음)
+    #[serde(default = "default_cooldown_secs")]
+    #[schema(label = "재알림 쿨다운 (초)", min = 0, max = 3600, default = 5)]
+    pub cooldown_secs: u64,
+}
+
+fn default_strength() -> f64 {
+    1.0
+}
+fn default_max_signal_age_secs() -> u64 {
+    60
+}
+fn default_cooldown_secs() -> u64 {
+    5
+}
+
+impl Default for WebhookConfig {
+    fn default() -> Self {
+        Self {
+            allowed_tickers: Vec::new(),
+            allow_short: false,
+            default_strength: default_strength(),
+            max_signal_age_secs: default_max_signal_age_secs(),
+            cooldown_secs: default_cooldown_secs(),
+        }
+    }
+}
+
+// ============================================================================
+// 페이로드
+// ============================================================================
+
+/// 외부 알림 페이로드 (TradingView/사용자 정의 공통).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookSignalPayload {
+    /// 종목 코드 (`KRX:005930` 형식 허용)
+    pub ticker: String,
+    /// 액션 (buy, sell, long, short, exit, close, flat)
+    pub action: String,
+    /// 신호 유형 (지정 시 action에서 추론한 유형 대신 사용)
+    #[serde(default)]
+    pub signal_type: Option<SignalType>,
+    /// 알림 시점 가격
+    #[serde(default)]
+    pub price: Option<Decimal>,
+    /// 손절가
+    #[serde(default)]
+    pub stop_loss: Option<Decimal>,
+    /// 익절가
+    #[serde(default)]
+    pub take_profit: Option<Decimal>,
+    /// 신호 강도 (0.0 ~ 1.0)
+    #[serde(default)]
+    pub strength: Option<f64>,
+    /// 알림 생성 시각 (유효 시간 검사용)
+    #[serde(default)]
+    pub timestamp: Option<DateTime<Utc>>,
+    /// 알림 메모
+    #[serde(default)]
+    pub comment: Option<String>,
+}
+
+impl WebhookSignalPayload {
+    /// 거래소 접두사를 제거한 종목 코드.
+    pub fn normalized_ticker(&self) -> String {
+        let ticker = self.ticker.trim();
+        ticker
+            .rsplit_once(':')
+            .map(|(_, t)| t)
+            .unwrap_or(ticker)
+            .to_uppercase()
+    }
+
+    /// action에서 주문 방향과 신호 유형 추론.
+    fn resolve(&self, allow_short: bool) -> Result<(Side, SignalType), String> {
+        let (side, inferred) = match self.action.trim().to_lowercase().as_str() {
+            "buy" | "long" => (Side::Buy, SignalType::Entry),
+            "sell" | "exit" | "close" | "flat" | "close_long" => (Side::Sell, SignalType::Exit),
+            "short" if allow_short => (Side::Sell, SignalType::Entry),
+            "short" => return Err("short entries are disabled for this strategy".to_string()),
+            "cover" | "close_short" => (Side::Buy, SignalType::Exit),
+            other => return Err(format!("unsupported action: {}", other)),
+        };
+        Ok((side, self.signal_type.unwrap_or(inferred)))
+    }
+}
+
+// ============================================================================
+// 전략 구현
+// ============================================================================
+
+/// Webhook Strategy
+pub struct WebhookStrategy {
+    config: Option<WebhookConfig>,
+    /// 종목/방향별 마지막 신호 시각 (쿨다운용)
+    last_signals: HashMap<(String, Side), DateTime<Utc>>,
+    /// 수신한 알림 수
+    received: u64,
+    /// 거부한 알림 수
+    rejected: u64,
+    /// 마지막 거부 사유
+    last_rejection: Option<String>,
+}
+
+impl WebhookStrategy {
+    pub fn new() -> Self {
+        Self {
+            config: None,
+            last_signals: HashMap::new(),
+            received: 0,
+            rejected: 0,
+            last_rejection: None,
+        }
+    }
+
+    /// 페이로드를 검증하여 신호로 변환.
+    fn convert(&mut self, payload: &Value, now: DateTime<Utc>) -> Result<Signal, String> {
+        let config = self
+            .config
+            .as_ref()
+            .ok_or_else(|| "strategy not initialized".to_string())?;
+
+        let payload: WebhookSignalPayload = serde_json::from_value(payload.clone())
+            .map_err(|e| format!("invalid webhook payload: {}", e))?;
+
+        let ticker = payload.normalized_ticker();
+        if ticker.is_empty() {
+            return Err("ticker is empty".to_string());
+        }
+        if !config.allowed_tickers.is_empty()
+            && !config
+                .allowed_tickers
+                .iter()
+                .any(|t| t.eq_ignore_ascii_case(&ticker))
+        {
+            return Err(format!("ticker not allowed: {}", ticker));
+        }
+
+        if let (Some(sent_at), true) = (payload.timestamp, config.max_signal_age_secs > 0) {
+            let age = now.signed_duration_since(sent_at).num_seconds();
+            if age > config.max_signal_age_secs as i64 {
+                return Err(format!(
+                    "stale alert: {}s old (max {}s)",
+                    age, config.max_signal_age_secs
+                ));
+            }
+        }
+
+        let (side, signal_type) = payload.resolve(config.allow_short)?;
+
+        let key = (ticker.clone(), side);
+        if config.cooldown_secs > 0 {
+            if let Some(last) = self.last_signals.get(&key) {
+                let elapsed = now.signed_duration_since(*last).num_seconds();
+                if elapsed < config.cooldown_secs as i64 {
+                    return Err(format!(
+                        "duplicate alert for {} {:?} within {}s cooldown",
+                        ticker, side, config.cooldown_secs
+                    ));
+                }
+            }
+        }
+
+        let mut signal = Signal::new("webhook", ticker, side, signal_type)
+            .with_strength(payload.strength.unwrap_or(config.default_strength))
+            .with_prices(payload.price, payload.stop_loss, payload.take_profit)
+            .with_metadata("source", json!("webhook"))
+            .with_metadata("action", json!(payload.action));
+        if let Some(comment) = &payload.comment {
+            signal = signal.with_metadata("comment", json!(comment));
+        }
+
+        self.last_signals.insert(key, now);
+        Ok(signal)
+    }
+}
+
+impl Default for WebhookStrategy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ============================================================================
+// Strategy Trait 구현
+// ============================================================================
+
+#[async_trait]
+impl Strategy for WebhookStrategy {
+    fn name(&self) -> &str {
+        "Webhook"
+    }
+
+    fn version(&self) -> &str {
+        "1.0.0"
+    }
+
+    fn description(&self) -> &str {
+        "TradingView 등 외부 알림을 신호로 변환하여 실행"
+    }
+
+    async fn initialize(
+        &mut self,
+        config: Value,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let cfg: WebhookConfig = serde_json::from_value(config)?;
+
+        info!(
+            allowed_tickers = cfg.allowed_tickers.len(),
+            allow_short = cfg.allow_short,
+            "Webhook 전략 초기화"
+        );
+
+        self.config = Some(cfg);
+        self.last_signals.clear();
+        self.received = 0;
+        self.rejected = 0;
+        self.last_rejection = None;
+
+        Ok(())
+    }
+
+    async fn on_market_data(
+        &mut self,
+        _data: &MarketData,
+    ) -> Result<Vec<Signal>, Box<dyn std::error::Error + Send + Sync>> {
+        // 신호는 외부 알림으로만 생성
+        Ok(vec![])
+    }
+
+    async fn on_external_signal(
+        &mut self,
+        payload: &Value,
+    ) -> Result<Vec<Signal>, Box<dyn std::error::Error + Send + Sync>> {
+        self.received += 1;
+
+        match self.convert(payload, Utc::now()) {
+            Ok(signal) => {
+                info!(
+                    ticker = %signal.ticker,
+                    side = ?signal.side,
+                    signal_type = %signal.signal_type,
+                    "Webhook 신호 수신"
+                );
+                Ok(vec![signal])
+            }
+            Err(reason) => {
+                self.rejected += 1;
+                self.last_rejection = Some(reason.clone());
+                warn!(reason = %reason, "Webhook 알림 거부");
+                Err(reason.into())
+            }
+        }
+    }
+
+    async fn on_order_filled(
+        &mut self,
+        order: &Order,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        info!(
+            ticker = %order.ticker,
+            side = ?order.side,
+            qty = %order.quantity,
+            "Webhook 주문 체결"
+        );
+        Ok(())
+    }
+
+    async fn on_position_update(
+        &mut self,
+        position: &Position,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        debug!(
+            ticker = %position.ticker,
+            qty = %position.quantity,
+            "Webhook 포지션 업데이트"
+        );
+        Ok(())
+    }
+
+    async fn shutdown(&mut self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        info!("Webhook 전략 종료");
+        Ok(())
+    }
+
+    fn get_state(&self) -> Value {
+        json!({
+            "config": self.config,
+            "received": self.received,
+            "rejected": self.rejected,
+            "last_rejection": self.last_rejection,
+        })
+    }
+}
+
+// ============================================================================
+// 레지스트리 등록
+// ============================================================================
+
+use crate::register_strategy;
+
+register_strategy! {
+    id: "webhook",
+    aliases: ["tradingview", "external_signal"],
+    name: "Webhook 신호",
+    description: "TradingView 등 외부 알림을 신호로 변환하여 실행",
+    timeframe: "1m",
+    tickers: [],
+    category: Realtime,
+    markets: [Crypto, Stock],
+    type: WebhookStrategy,
+    config: WebhookConfig
+}
+
+// ============================================================================
+// 테스트
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+    use rust_decimal_macros::dec;
+
+    async fn strategy(config: Value) -> WebhookStrategy {
+        let mut strategy = WebhookStrategy::new();
+        strategy.initialize(config).await.unwrap();
+        strategy
+    }
+
+    #[tokio::test]
+    async fn test_tradingview_payload_to_signal() {
+        let mut strategy = strategy(json!({ "allowed_tickers": ["005930"] })).await;
+
+        let signals = strategy
+            .on_external_signal(&json!({
+                "ticker": "KRX:005930",
+                "action": "buy",
+                "price": 71000,
+                "stop_loss": "69000",
+                "comment": "breakout"
+            }))
+            .await
+            .unwrap();
+
+        assert_eq!(signals.len(), 1);
+        let signal = &signals[0];
+        assert_eq!(signal.ticker, "005930");
+        assert_eq!(signal.side, Side::Buy);
+        assert_eq!(signal.signal_type, SignalType::Entry);
+        assert_eq!(signal.suggested_price, Some(dec!(71000)));
+        assert_eq!(signal.stop_loss, Some(dec!(69000)));
+        assert_eq!(signal.metadata["comment"], "breakout");
+
+        // 쿨다운 내 동일 알림 거부
+        assert!(strategy
+            .on_external_signal(&json!({ "ticker": "005930", "action": "buy" }))
+            .await
+            .is_err());
+
+        // 청산은 다른 방향이므로 허용
+        let exit = strategy
+            .on_external_signal(&json!({ "ticker": "005930", "action": "close" }))
+            .await
+            .unwrap();
+        assert_eq!(exit[0].signal_type, SignalType::Exit);
+        assert_eq!(exit[0].side, Side::Sell);
+    }
+
+    #[tokio::test]
+    async fn test_rejects_disallowed_and_stale_alerts() {
+        let mut strategy = strategy(json!({ "allowed_tickers": ["AAPL"] })).await;
+        let now = Utc::now();
+
+        assert!(strategy
+            .convert(&json!({ "ticker": "TSLA", "action": "buy" }), now)
+            .unwrap_err()
+            .contains("not allowed"));
+        assert!(strategy
+            .convert(&json!({ "ticker": "AAPL", "action": "short" }), now)
+            .unwrap_err()
+            .contains("short"));
+        assert!(strategy
+            .convert(&json!({ "ticker": "AAPL", "action": "hold" }), now)
+            .unwrap_err()
+            .contains("unsupported"));
+
+        let stale = json!({
+            "ticker": "AAPL",
+            "action": "buy",
+            "timestamp": now - Duration::seconds(120),
+        });
+        assert!(strategy.convert(&stale, now).unwrap_err().contains("stale"));
+
+        assert_eq!(strategy.get_state()["received"], 0);
+        assert!(strategy.on_external_signal(&stale).await.is_err());
+        assert_eq!(strategy.get_state()["rejected"], 1);
+    }
+}
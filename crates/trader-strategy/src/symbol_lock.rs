@@ -0,0 +1,314 @@
+//! 종목 잠금 (전략 간 종목 소유권).
+//!
+//! 여러 전략이 같은 종목의 포지션을 두고 경쟁하지 않도록, 진입 신호를 낸 전략이
+//! 종목을 점유하고 포지션이 청산되면 해제합니다. 잠금은 선택 사항이며 설정이 없는
+//! 전략의 신호는 검사하지 않고 점유도 하지 않습니다.
+//!
+//! # 충돌 정책
+//!
+//! - `exclusive`: 한 전략만 종목을 보유할 수 있음 (기본값)
+//! - `shared_long_only`: 여러 전략이 함께 보유할 수 있으나 매수 진입만 허용
+//! - `netting`: 반대 방향 보유도 허용하고, 상쇄 대상 전략을 신호 메타데이터에 표시
+//!
+//! 요청 전략과 기존 보유 전략의 정책 중 가장 엄격한 정책이 적용됩니다
+//! (`exclusive` > `shared_long_only` > `netting`).
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use trader_core::{Side, Signal, SignalType};
+
+/// 종목 충돌 정책 (선언 순서가 엄격도 순서).
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum SymbolLockPolicy {
+    /// 반대 방향 보유 허용 (순포지션으로 상쇄)
+    Netting,
+    /// 공동 보유 허용, 매수 진입만 가능
+    SharedLongOnly,
+    /// 단독 보유
+    #[default]
+    Exclusive,
+}
+
+/// 전략별 종목 잠금 설정.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SymbolLockConfig {
+    /// 충돌 정책
+    #[serde(default)]
+    pub policy: SymbolLockPolicy,
+    /// 동시에 보유할 수 있는 최대 종목 수 (None이면 제한 없음)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_symbols: Option<usize>,
+}
+
+/// 종목 점유 기록.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SymbolClaim {
+    pub strategy_id: String,
+    /// 보유(예정) 방향
+    pub side: Side,
+    /// 점유 전략의 충돌 정책
+    pub policy: SymbolLockPolicy,
+}
+
+/// 진입 신호가 거부된 사유.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SymbolLockConflict {
+    /// 다른 전략이 종목을 보유 중
+    Owned { owner: String },
+    /// 매수 전용 종목에 매도 진입
+    ShortNotAllowed,
+    /// 전략의 최대 보유 종목 수 도달
+    MaxSymbols { limit: usize },
+}
+
+impl fmt::Display for SymbolLockConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Owned { owner } => write!(f, "owned by {}", owner),
+            Self::ShortNotAllowed => write!(f, "short entry not allowed (shared_long_only)"),
+            Self::MaxSymbols { limit } => write!(f, "max symbols reached ({})", limit),
+        }
+    }
+}
+
+/// 종목 잠금 판정 결과.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SymbolLockDecision {
+    /// 허용
+    Allow,
+    /// 허용 (반대 방향으로 보유 중인 전략과 상쇄)
+    Net { against: Vec<String> },
+    /// 거부
+    Reject(SymbolLockConflict),
+}
+
+/// 종목별 점유 현황.
+#[derive(Debug, Default)]
+pub struct SymbolLockBook {
+    /// ticker -> 점유 기록
+    claims: HashMap<String, Vec<SymbolClaim>>,
+}
+
+impl SymbolLockBook {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 종목을 점유 중인 전략 목록.
+    pub fn holders(&self, ticker: &str) -> &[SymbolClaim] {
+        self.claims.get(ticker).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// 전략이 점유 중인 종목 수.
+    pub fn held_count(&self, strategy_id: &str) -> usize {
+        self.claims
+            .values()
+            .filter(|claims| claims.iter().any(|c| c.strategy_id == strategy_id))
+            .count()
+    }
+
+    /// 신호를 점유 상태에 비추어 판정합니다 (점유 상태는 바꾸지 않음).
+    ///
+    /// 보유를 늘리는 신호(진입, 추가 매수)만 검사하며 청산/축소/알림 신호는 항상 허용합니다.
+    pub fn evaluate(
+        &self,
+        strategy_id: &str,
+        config: &SymbolLockConfig,
+        signal: &Signal,
+    ) -> SymbolLockDecision {
+        if !matches!(
+            signal.signal_type,
+            SignalType::Entry | SignalType::AddToPosition
+        ) {
+            return SymbolLockDecision::Allow;
+        }
+
+        let holders = self.holders(&signal.ticker);
+        let already_held = holders.iter().any(|c| c.strategy_id == strategy_id);
+        if let Some(limit) = config.max_symbols {
+            if !already_held && self.held_count(strategy_id) >= limit {
+                return SymbolLockDecision::Reject(SymbolLockConflict::MaxSymbols { limit });
+            }
+        }
+
+        let others: Vec<&SymbolClaim> = holders
+            .iter()
+            .filter(|c| c.strategy_id != strategy_id)
+            .collect();
+        let policy = others
+            .iter()
+            .map(|c| c.policy)
+            .fold(config.policy, Ord::max);
+
+        match policy {
+            SymbolLockPolicy::Exclusive => match others.first() {
+                Some(owner) => SymbolLockDecision::Reject(SymbolLockConflict::Owned {
+                    owner: owner.strategy_id.clone(),
+                }),
+                None => SymbolLockDecision::Allow,
+            },
+            SymbolLockPolicy::SharedLongOnly if signal.side == Side::Sell => {
+                SymbolLockDecision::Reject(SymbolLockConflict::ShortNotAllowed)
+            }
+            SymbolLockPolicy::SharedLongOnly => SymbolLockDecision::Allow,
+            SymbolLockPolicy::Netting => {
+                let against: Vec<String> = others
+                    .iter()
+                    .filter(|c| c.side != signal.side)
+                    .map(|c| c.strategy_id.clone())
+                    .collect();
+                if against.is_empty() {
+                    SymbolLockDecision::Allow
+                } else {
+                    SymbolLockDecision::Net { against }
+                }
+            }
+        }
+    }
+
+    /// 신호를 판정하고, 허용되면 종목을 점유합니다.
+    pub fn acquire(
+        &mut self,
+        strategy_id: &str,
+        config: &SymbolLockConfig,
+        signal: &Signal,
+    ) -> SymbolLockDecision {
+        let decision = self.evaluate(strategy_id, config, signal);
+        let claims = matches!(
+            signal.signal_type,
+            SignalType::Entry | SignalType::AddToPosition
+        );
+        if claims && !matches!(decision, SymbolLockDecision::Reject(_)) {
+            self.claim(strategy_id, config.policy, &signal.ticker, signal.side);
+        }
+        decision
+    }
+
+    /// 종목 점유 (이미 점유 중이면 방향/정책 갱신).
+    pub fn claim(&mut self, strategy_id: &str, policy: SymbolLockPolicy, ticker: &str, side: Side) {
+        let claims = self.claims.entry(ticker.to_string()).or_default();
+        match claims.iter_mut().find(|c| c.strategy_id == strategy_id) {
+            Some(claim) => {
+                claim.side = side;
+                claim.policy = policy;
+            }
+            None => claims.push(SymbolClaim {
+                strategy_id: strategy_id.to_string(),
+                side,
+                policy,
+            }),
+        }
+    }
+
+    /// 종목 점유 해제.
+    pub fn release(&mut self, strategy_id: &str, ticker: &str) {
+        if let Some(claims) = self.claims.get_mut(ticker) {
+            claims.retain(|c| c.strategy_id != strategy_id);
+            if claims.is_empty() {
+                self.claims.remove(ticker);
+            }
+        }
+    }
+
+    /// 전략의 모든 점유 해제.
+    pub fn release_all(&mut self, strategy_id: &str) {
+        self.claims.retain(|_, claims| {
+            claims.retain(|c| c.strategy_id != strategy_id);
+            !claims.is_empty()
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lock(policy: SymbolLockPolicy) -> SymbolLockConfig {
+        SymbolLockConfig {
+            policy,
+            max_symbols: None,
+        }
+    }
+
+    #[test]
+    fn test_exclusive_and_release() {
+        let mut book = SymbolLockBook::new();
+        let config = lock(SymbolLockPolicy::Exclusive);
+        let buy = Signal::entry("a", "005930".to_string(), Side::Buy);
+
+        assert_eq!(book.acquire("a", &config, &buy), SymbolLockDecision::Allow);
+        // 같은 전략의 추가 진입은 허용, 다른 전략은 거부
+        assert_eq!(book.acquire("a", &config, &buy), SymbolLockDecision::Allow);
+        assert_eq!(
+            book.acquire("b", &lock(SymbolLockPolicy::Netting), &buy),
+            SymbolLockDecision::Reject(SymbolLockConflict::Owned {
+                owner: "a".to_string()
+            })
+        );
+        // 청산 신호는 검사하지 않음
+        let exit = Signal::exit("b", "005930".to_string(), Side::Sell);
+        assert_eq!(book.acquire("b", &config, &exit), SymbolLockDecision::Allow);
+
+        book.release("a", "005930");
+        assert_eq!(book.acquire("b", &config, &buy), SymbolLockDecision::Allow);
+        assert_eq!(book.holders("005930")[0].strategy_id, "b");
+    }
+
+    #[test]
+    fn test_shared_long_only_and_netting() {
+        let mut book = SymbolLockBook::new();
+        let shared = lock(SymbolLockPolicy::SharedLongOnly);
+        let netting = lock(SymbolLockPolicy::Netting);
+        let buy = Signal::entry("a", "AAPL".to_string(), Side::Buy);
+        let sell = Signal::entry("b", "AAPL".to_string(), Side::Sell);
+
+        assert_eq!(book.acquire("a", &shared, &buy), SymbolLockDecision::Allow);
+        assert_eq!(book.acquire("b", &shared, &buy), SymbolLockDecision::Allow);
+        // 매수 전용 보유자가 있으면 netting 전략의 매도 진입도 거부
+        assert_eq!(
+            book.acquire("c", &netting, &sell),
+            SymbolLockDecision::Reject(SymbolLockConflict::ShortNotAllowed)
+        );
+
+        let mut book = SymbolLockBook::new();
+        assert_eq!(book.acquire("a", &netting, &buy), SymbolLockDecision::Allow);
+        assert_eq!(
+            book.acquire("b", &netting, &sell),
+            SymbolLockDecision::Net {
+                against: vec!["a".to_string()]
+            }
+        );
+        assert_eq!(book.holders("AAPL").len(), 2);
+    }
+
+    #[test]
+    fn test_max_symbols() {
+        let mut book = SymbolLockBook::new();
+        let config = SymbolLockConfig {
+            policy: SymbolLockPolicy::Exclusive,
+            max_symbols: Some(1),
+        };
+        let first = Signal::entry("a", "005930".to_string(), Side::Buy);
+        let second = Signal::entry("a", "000660".to_string(), Side::Buy);
+
+        assert_eq!(
+            book.acquire("a", &config, &first),
+            SymbolLockDecision::Allow
+        );
+        assert_eq!(
+            book.acquire("a", &config, &second),
+            SymbolLockDecision::Reject(SymbolLockConflict::MaxSymbols { limit: 1 })
+        );
+
+        book.release_all("a");
+        assert_eq!(book.held_count("a"), 0);
+        assert_eq!(
+            book.acquire("a", &config, &second),
+            SymbolLockDecision::Allow
+        );
+    }
+}
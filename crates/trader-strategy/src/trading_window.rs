@@ -0,0 +1,155 @@
+//! 전략별 거래 시간대.
+//!
+//! 전략이 동작하는 시간을 시장 현지 시각 구간(예: 09:05-15:20 KST)으로 제한합니다.
+//! 구간 밖에서 들어온 시장 데이터는 전략에 전달하지 않으며, 휴장일(주말 포함)은
+//! 하루 종일 구간 밖으로 봅니다. `flatten_outside`를 켜면 구간 밖에서 보유 포지션에
+//! 청산 신호를 냅니다.
+//!
+//! 휴장일은 엔진에 설정한 [`HolidaySource`]로 판정하며, 설정하지 않으면 주말만 휴장으로 봅니다.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Datelike, NaiveDate, NaiveDateTime, NaiveTime, Utc, Weekday};
+use chrono_tz::America::New_York;
+use chrono_tz::Asia::Seoul;
+use serde::{Deserialize, Serialize};
+use trader_core::CalendarMarket;
+
+/// 휴장일 판정 소스.
+#[async_trait]
+pub trait HolidaySource: Send + Sync {
+    /// 시장 현지 날짜가 휴장일인지 여부.
+    async fn is_holiday(
+        &self,
+        market: CalendarMarket,
+        date: NaiveDate,
+    ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>>;
+}
+
+/// 전략 거래 시간대 설정.
+///
+/// JSON 예: `{"market": "KR", "start": "09:05:00", "end": "15:20:00", "flatten_outside": true}`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TradingWindowConfig {
+    /// 기준 시장 (현지 시각과 휴장일 판정 기준)
+    #[serde(default = "default_market")]
+    pub market: CalendarMarket,
+    /// 시작 시각 (현지, 포함)
+    pub start: NaiveTime,
+    /// 종료 시각 (현지, 미포함)
+    pub end: NaiveTime,
+    /// 휴장일(주말 포함)에는 동작하지 않음
+    #[serde(default = "default_true")]
+    pub skip_holidays: bool,
+    /// 구간 밖에서 보유 포지션 청산
+    #[serde(default)]
+    pub flatten_outside: bool,
+}
+
+fn default_market() -> CalendarMarket {
+    CalendarMarket::Kr
+}
+
+fn default_true() -> bool {
+    true
+}
+
+impl TradingWindowConfig {
+    /// 설정 검증 (시작 < 종료).
+    pub fn validate(&self) -> Result<(), String> {
+        if self.start >= self.end {
+            return Err(format!(
+                "trading window must start before it ends ({} >= {})",
+                self.start, self.end
+            ));
+        }
+        Ok(())
+    }
+
+    /// 기준 시장 현지 일시.
+    pub fn local_datetime(&self, now: DateTime<Utc>) -> NaiveDateTime {
+        market_local_datetime(self.market, now)
+    }
+
+    /// 현지 시각이 구간에 속하는지 여부 (휴장일 미고려).
+    pub fn contains_time(&self, now: DateTime<Utc>) -> bool {
+        let local = self.local_datetime(now).time();
+        self.start <= local && local < self.end
+    }
+
+    /// 구간 안인지 여부.
+    ///
+    /// `trading_day`는 현지 날짜가 거래일인지 여부이며, `skip_holidays`가 꺼져 있으면
+    /// 무시합니다.
+    pub fn is_open(&self, now: DateTime<Utc>, trading_day: bool) -> bool {
+        (trading_day || !self.skip_holidays) && self.contains_time(now)
+    }
+}
+
+/// 시장 현지 일시 (KR: KST, US: ET).
+pub fn market_local_datetime(market: CalendarMarket, now: DateTime<Utc>) -> NaiveDateTime {
+    match market {
+        CalendarMarket::Kr => now.with_timezone(&Seoul).naive_local(),
+        CalendarMarket::Us => now.with_timezone(&New_York).naive_local(),
+    }
+}
+
+/// 주말 여부.
+pub fn is_weekend(date: NaiveDate) -> bool {
+    matches!(date.weekday(), Weekday::Sat | Weekday::Sun)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn hm(hour: u32, minute: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(hour, minute, 0).unwrap()
+    }
+
+    #[test]
+    fn test_window_uses_market_local_time() {
+        let window: TradingWindowConfig =
+            serde_json::from_value(serde_json::json!({ "start": "09:05:00", "end": "15:20:00" }))
+                .unwrap();
+        assert_eq!(window.market, CalendarMarket::Kr);
+        assert!(window.skip_holidays);
+        assert!(!window.flatten_outside);
+
+        // 2026-03-10(화) 00:05 UTC = 09:05 KST
+        let open = Utc.with_ymd_and_hms(2026, 3, 10, 0, 5, 0).unwrap();
+        assert!(window.is_open(open, true));
+        assert!(!window.is_open(open - chrono::Duration::minutes(1), true));
+        // 15:20 KST는 미포함
+        assert!(!window.is_open(Utc.with_ymd_and_hms(2026, 3, 10, 6, 20, 0).unwrap(), true));
+        // 휴장일
+        assert!(!window.is_open(open, false));
+
+        let us = TradingWindowConfig {
+            market: CalendarMarket::Us,
+            start: hm(9, 30),
+            end: hm(16, 0),
+            skip_holidays: false,
+            flatten_outside: false,
+        };
+        // 서머타임 (EDT, UTC-4): 13:30 UTC = 09:30 ET
+        assert!(us.is_open(Utc.with_ymd_and_hms(2026, 7, 1, 13, 30, 0).unwrap(), false));
+        // 표준시 (EST, UTC-5): 13:30 UTC = 08:30 ET
+        assert!(!us.is_open(Utc.with_ymd_and_hms(2026, 1, 6, 13, 30, 0).unwrap(), true));
+    }
+
+    #[test]
+    fn test_window_validation() {
+        let mut window = TradingWindowConfig {
+            market: CalendarMarket::Kr,
+            start: hm(9, 5),
+            end: hm(15, 20),
+            skip_holidays: true,
+            flatten_outside: true,
+        };
+        assert!(window.validate().is_ok());
+
+        window.end = hm(9, 5);
+        assert!(window.validate().is_err());
+    }
+}
@@ -1,5 +1,6 @@
 //! Strategy trait 정의.
 
+use crate::config_migration::ConfigMigration;
 use async_trait::async_trait;
 use serde_json::Value;
 use std::collections::HashMap;
@@ -7,7 +8,7 @@ use std::sync::Arc;
 use tokio::sync::RwLock;
 use trader_core::{
     domain::MultiTimeframeConfig, Kline, MarketData, Order, Position, Signal, StrategyContext,
-    Timeframe,
+    StreamStatus, Timeframe, TradingStatusEvent,
 };
 
 /// 트레이딩 전략 구현을 위한 Strategy trait.
@@ -49,6 +50,38 @@ pub trait Strategy: Send + Sync {
         position: &Position,
     ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
 
+    /// 종목 거래 상태 변경(거래정지, VI 발동/해제) 시 호출.
+    ///
+    /// 엔진은 컨텍스트의 `trading_statuses`를 먼저 갱신한 뒤 호출합니다.
+    /// 거래정지/VI 중 주문은 실행기에서 보류되므로, 전략은 재진입 대기 등
+    /// 자체 상태 관리가 필요할 때만 구현하면 됩니다.
+    ///
+    /// # 기본 구현
+    ///
+    /// 아무 작업도 하지 않습니다.
+    async fn on_trading_status(
+        &mut self,
+        _event: &TradingStatusEvent,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        Ok(())
+    }
+
+    /// 실시간 시세 스트림 재연결 상태 변경 시 호출.
+    ///
+    /// `Reconnecting` 동안에는 시세가 들어오지 않고, `Recovered`를 받으면
+    /// `gap()` 구간의 시세가 누락된 상태로 다시 이어집니다. 캔들이나 지표를 직접 누적하는
+    /// 전략은 공백 이후 상태를 재계산하거나 첫 신호를 건너뛰는 용도로 구현합니다.
+    ///
+    /// # 기본 구현
+    ///
+    /// 아무 작업도 하지 않습니다.
+    async fn on_stream_status(
+        &mut self,
+        _status: &StreamStatus,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        Ok(())
+    }
+
     /// 전략 종료 및 리소스 정리.
     async fn shutdown(&mut self) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
 
@@ -94,6 +127,27 @@ pub trait Strategy: Send + Sync {
         None
     }
 
+    /// 전략 메타데이터 반환.
+    ///
+    /// `timeframes`에 필요한 타임프레임(예: 일봉 + 주봉 + 1시간봉)을 선언하면 엔진이
+    /// 컨텍스트에 타임프레임별 캔들 버퍼를 유지합니다. 기본 타임프레임 캔들이 들어올 때
+    /// 피드가 직접 주지 않는 상위 타임프레임 캔들을 집계하고, `on_multi_timeframe_data()`에는
+    /// 기본 캔들 종료 시점까지 완료된 캔들만 전달하므로 전략이 직접 리샘플링할 필요가 없습니다.
+    ///
+    /// # 기본 구현
+    ///
+    /// 이름/버전/설명과 `multi_timeframe_config()`로 구성합니다.
+    fn metadata(&self) -> StrategyMetadata {
+        StrategyMetadata {
+            name: self.name().to_string(),
+            version: self.version().to_string(),
+            description: self.description().to_string(),
+            required_config: Vec::new(),
+            supported_tickers: Vec::new(),
+            timeframes: self.multi_timeframe_config().filter(|c| !c.is_empty()),
+        }
+    }
+
     /// 다중 타임프레임 데이터로 신호 생성.
     ///
     /// 여러 타임프레임의 데이터를 동시에 분석하여 매매 신호를 생성합니다.
@@ -141,6 +195,75 @@ pub trait Strategy: Send + Sync {
         self.on_market_data(primary_data).await
     }
 
+    // =========================================================================
+    // 워밍업 (시작 시 과거 캔들로 지표 상태 준비)
+    // =========================================================================
+
+    /// 시작 시 미리 받아야 하는 과거 캔들 설정.
+    ///
+    /// 타임프레임별 캔들 개수(예: 일봉 200개 + 주봉 60개)를 선언하면 엔진이
+    /// 초기화 직후 데이터를 조회하여 컨텍스트에 채우고 [`Strategy::warmup`]을 호출합니다.
+    /// `initialize()` 이후에 조회되므로 설정값에 따라 달라져도 됩니다.
+    ///
+    /// # 기본 구현
+    ///
+    /// 메타데이터에 선언한 타임프레임(`metadata().timeframes`)을 그대로 사용합니다.
+    fn warmup_config(&self) -> Option<MultiTimeframeConfig> {
+        self.metadata().timeframes
+    }
+
+    /// 워밍업 캔들로 지표 상태 준비.
+    ///
+    /// 실시간 데이터를 처리하기 전에 티커별로 한 번 호출됩니다.
+    /// 재생한 캔들 수를 반환합니다.
+    ///
+    /// # 기본 구현
+    ///
+    /// 재생 타임프레임(기본 타임프레임, 없으면 가장 짧은 타임프레임)의 캔들을
+    /// 시간순으로 `on_market_data()`에 전달하고 생성된 신호는 버립니다.
+    /// 포지션은 주문 체결로만 바뀌므로 지표와 가격 이력만 채워집니다.
+    async fn warmup(
+        &mut self,
+        ticker: &str,
+        klines: &HashMap<Timeframe, Vec<Kline>>,
+    ) -> Result<usize, Box<dyn std::error::Error + Send + Sync>> {
+        let config = self.warmup_config();
+        let Some(timeframe) = crate::warmup::replay_timeframe(config.as_ref(), klines) else {
+            return Ok(0);
+        };
+
+        let mut bars: Vec<&Kline> = klines
+            .get(&timeframe)
+            .map(|k| k.iter().collect())
+            .unwrap_or_default();
+        bars.sort_by_key(|k| k.open_time);
+
+        for kline in &bars {
+            // 데이터 소스 심볼 표기와 무관하게 전략이 구독한 티커로 전달
+            let mut kline = (*kline).clone();
+            kline.ticker = ticker.to_string();
+            self.on_market_data(&MarketData::from_kline("warmup", kline))
+                .await?;
+        }
+
+        Ok(bars.len())
+    }
+
+    /// 외부 신호 수신 시 호출 (웹훅 등).
+    ///
+    /// 외부 알림 페이로드를 검증하여 신호로 변환합니다.
+    /// 페이로드를 거부하려면 에러를 반환합니다.
+    ///
+    /// # 기본 구현
+    ///
+    /// 외부 신호를 사용하지 않는 전략은 무시합니다 (빈 벡터 반환).
+    async fn on_external_signal(
+        &mut self,
+        _payload: &Value,
+    ) -> Result<Vec<Signal>, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(vec![])
+    }
+
     /// 현재 전략 상태를 JSON으로 반환 (디버깅/모니터링용).
     fn get_state(&self) -> Value;
 
@@ -153,6 +276,20 @@ pub trait Strategy: Send + Sync {
     fn load_state(&mut self, _data: &[u8]) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
         Ok(())
     }
+
+    /// 현재 설정 스키마 버전.
+    ///
+    /// 설정 필드 이름이나 형식을 바꿀 때 올리고, 이전 버전 설정을 변환하는 단계를
+    /// [`Strategy::config_migrations`]에 추가합니다. 저장된 설정은 로드 시
+    /// [`upgrade_config`](crate::config_migration::upgrade_config)로 변환됩니다.
+    fn config_version(&self) -> u32 {
+        1
+    }
+
+    /// 이전 버전 설정을 한 버전씩 올리는 마이그레이션 단계.
+    fn config_migrations(&self) -> Vec<Box<dyn ConfigMigration>> {
+        Vec::new()
+    }
 }
 
 /// 등록을 위한 전략 메타데이터.
@@ -168,4 +305,6 @@ pub struct StrategyMetadata {
     pub required_config: Vec<String>,
     /// 지원 티커 (빈 값 = 전체)
     pub supported_tickers: Vec<String>,
+    /// 필요 타임프레임과 타임프레임별 캔들 개수 (None = 단일 타임프레임)
+    pub timeframes: Option<MultiTimeframeConfig>,
 }
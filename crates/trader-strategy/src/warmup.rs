@@ -0,0 +1,128 @@
+//! 전략 워밍업 데이터 공급.
+//!
+//! 전략이 [`Strategy::warmup_config`](crate::Strategy::warmup_config)로 선언한 과거 캔들
+//! (예: 일봉 200개 + 주봉 60개)을 시작 시 조회하여 지표 상태를 미리 채웁니다.
+//! 엔진에 [`WarmupDataSource`]가 설정되어 있으면 전략 초기화 직후,
+//! 실시간 데이터를 처리하기 전에 워밍업을 수행합니다.
+
+use async_trait::async_trait;
+use serde_json::Value;
+use std::collections::HashMap;
+use trader_core::{domain::MultiTimeframeConfig, Kline, Timeframe};
+
+/// 워밍업 캔들 공급자.
+#[async_trait]
+pub trait WarmupDataSource: Send + Sync {
+    /// 티커의 타임프레임별 최근 캔들 조회 (설정의 타임프레임별 개수만큼).
+    async fn fetch_klines(
+        &self,
+        ticker: &str,
+        config: &MultiTimeframeConfig,
+    ) -> Result<HashMap<Timeframe, Vec<Kline>>, Box<dyn std::error::Error + Send + Sync>>;
+}
+
+/// 전략 설정에서 워밍업 대상 티커 추출.
+///
+/// `ticker`, `symbol` 문자열과 `symbols`, `tickers` 배열을 순서대로 모읍니다 (중복 제거).
+pub fn warmup_tickers(config: &Value) -> Vec<String> {
+    let mut tickers: Vec<String> = Vec::new();
+    let mut push = |ticker: &str| {
+        let ticker = ticker.trim();
+        if !ticker.is_empty() && !tickers.iter().any(|t| t == ticker) {
+            tickers.push(ticker.to_string());
+        }
+    };
+
+    for key in ["ticker", "symbol"] {
+        if let Some(ticker) = config.get(key).and_then(|v| v.as_str()) {
+            push(ticker);
+        }
+    }
+    for key in ["symbols", "tickers"] {
+        if let Some(items) = config.get(key).and_then(|v| v.as_array()) {
+            items.iter().filter_map(|v| v.as_str()).for_each(&mut push);
+        }
+    }
+
+    tickers
+}
+
+/// 워밍업 재생에 사용할 타임프레임.
+///
+/// 설정의 기본 타임프레임을 우선하고, 없으면 캔들이 있는 가장 짧은 타임프레임을 사용합니다.
+pub fn replay_timeframe(
+    config: Option<&MultiTimeframeConfig>,
+    klines: &HashMap<Timeframe, Vec<Kline>>,
+) -> Option<Timeframe> {
+    config
+        .and_then(|c| c.primary_timeframe)
+        .filter(|tf| klines.get(tf).is_some_and(|k| !k.is_empty()))
+        .or_else(|| {
+            klines
+                .iter()
+                .filter(|(_, k)| !k.is_empty())
+                .map(|(tf, _)| *tf)
+                .min_by_key(|tf| tf.as_secs())
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use rust_decimal_macros::dec;
+
+    fn kline(timeframe: Timeframe) -> Kline {
+        let now = Utc::now();
+        Kline::new(
+            "005930".to_string(),
+            timeframe,
+            now,
+            dec!(100),
+            dec!(100),
+            dec!(100),
+            dec!(100),
+            dec!(1),
+            now,
+        )
+    }
+
+    #[test]
+    fn test_warmup_tickers() {
+        let config = serde_json::json!({
+            "ticker": "005930",
+            "symbols": ["000660", "005930", ""],
+            "tickers": ["AAPL"]
+        });
+        assert_eq!(warmup_tickers(&config), vec!["005930", "000660", "AAPL"]);
+        assert!(warmup_tickers(&serde_json::json!({})).is_empty());
+    }
+
+    #[test]
+    fn test_replay_timeframe() {
+        let mut klines = HashMap::new();
+        klines.insert(Timeframe::D1, vec![kline(Timeframe::D1)]);
+        klines.insert(Timeframe::W1, vec![kline(Timeframe::W1)]);
+        klines.insert(Timeframe::H1, Vec::new());
+
+        // 기본 타임프레임이 없으면 캔들이 있는 가장 짧은 타임프레임
+        assert_eq!(replay_timeframe(None, &klines), Some(Timeframe::D1));
+
+        let config = MultiTimeframeConfig::new()
+            .with_timeframe(Timeframe::D1, 200)
+            .with_timeframe(Timeframe::W1, 60)
+            .with_primary(Timeframe::W1);
+        assert_eq!(
+            replay_timeframe(Some(&config), &klines),
+            Some(Timeframe::W1)
+        );
+
+        // 기본 타임프레임 캔들이 비어 있으면 대체
+        let config = config.with_primary(Timeframe::H1);
+        assert_eq!(
+            replay_timeframe(Some(&config), &klines),
+            Some(Timeframe::D1)
+        );
+        assert_eq!(replay_timeframe(None, &HashMap::new()), None);
+    }
+}
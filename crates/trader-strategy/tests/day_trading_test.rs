@@ -14,7 +14,7 @@ use trader_core::{
 };
 use trader_strategy::strategies::day_trading::{
     BreakoutConfig, CrossoverConfig, DayTradingConfig, DayTradingStrategy, DayTradingVariant,
-    ExitConfig as DayTradingExitConfig, VolumeSurgeConfig,
+    VolumeSurgeConfig,
 };
 use trader_strategy::Strategy;
 use uuid::Uuid;
@@ -350,7 +350,7 @@ async fn test_ma_period_affects_entry_timing() {
 
     // 한 번 더 데이터 추가 (MA 위 조건 확인)
     let above_ma = create_kline("005930", dec!(1050), 1000000 + 6 * 86400);
-    let signals = strategy.on_market_data(&above_ma).await.unwrap();
+    let _signals = strategy.on_market_data(&above_ma).await.unwrap();
 
     // 상승 추세 + MA 위이면 진입
     let state = strategy.get_state();
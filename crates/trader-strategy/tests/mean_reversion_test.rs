@@ -11,7 +11,9 @@ use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 use serde_json::json;
 use trader_core::types::Timeframe;
-use trader_core::{Kline, MarketData, MarketDataType, Position, Side, Ticker};
+use trader_core::{
+    Kline, MarketData, MarketDataType, Position, ScaleOut, Side, SignalType, Ticker,
+};
 use trader_strategy::strategies::mean_reversion::{
     BollingerConfig, EntrySignalConfig, ExitConfig, MeanReversionConfig, MeanReversionStrategy,
     SplitLevel, StrategyVariant as MeanReversionVariant,
@@ -800,6 +802,109 @@ mod magic_split_tests {
             "5% 손실 시 2차수 매수 신호가 발생해야 함"
         );
     }
+
+    /// 테스트 4: 일부 차수만 익절 시 부분 청산 신호
+    #[tokio::test]
+    async fn test_magic_split_partial_take_profit_scales_out() {
+        let mut strategy = MeanReversionStrategy::new();
+        let levels = vec![
+            SplitLevel {
+                trigger_rate: dec!(0),
+                target_rate: dec!(10),
+                amount: dec!(100000),
+            },
+            SplitLevel {
+                trigger_rate: dec!(-5),
+                target_rate: dec!(4),
+                amount: dec!(100000),
+            },
+        ];
+        // MagicSplit 설정은 최상위 `levels`로 분할 레벨을 받음
+        let config_json = json!({
+            "variant": "magic_split",
+            "ticker": "005930",
+            "levels": levels,
+            "max_positions": 2,
+            "min_global_score": 0,
+        });
+        strategy.initialize(config_json).await.unwrap();
+
+        // 1차수 50000원, 2차수 40000원 진입
+        for price in [dec!(50000), dec!(40000)] {
+            strategy
+                .on_market_data(&create_kline_data("005930", price))
+                .await
+                .unwrap();
+        }
+
+        // 2차수만 목표 수익(4%) 도달 → 2차수 비중만 청산
+        let signals = strategy
+            .on_market_data(&create_kline_data("005930", dec!(42000)))
+            .await
+            .unwrap();
+        let sell = signals
+            .iter()
+            .find(|s| s.side == Side::Sell)
+            .expect("2차수 익절 신호가 발생해야 함");
+
+        // 보유 수량: 1차수 2주 + 2차수 2.5주 → 2차수 비중 2.5 / 4.5
+        assert_eq!(sell.signal_type, SignalType::ReducePosition);
+        assert_eq!(sell.scale_out, Some(ScaleOut::Ratio(dec!(2.5) / dec!(4.5))));
+    }
+
+    /// 테스트 5: 재시작 후 저장된 차수 상태에서 이어서 진행
+    #[tokio::test]
+    async fn test_magic_split_resumes_after_restart() {
+        let config = MeanReversionConfig {
+            variant: MeanReversionVariant::MagicSplit,
+            ticker: "005930".to_string(),
+            amount: dec!(100000),
+            entry_signal: EntrySignalConfig::Split {
+                levels: vec![
+                    SplitLevel {
+                        trigger_rate: dec!(0),
+                        target_rate: dec!(10),
+                        amount: dec!(100000),
+                    },
+                    SplitLevel {
+                        trigger_rate: dec!(-5),
+                        target_rate: dec!(8),
+                        amount: dec!(150000),
+                    },
+                ],
+            },
+            exit_config: ExitConfig::default(),
+            max_positions: 2,
+            min_global_score: dec!(0),
+        };
+        let config_json = serde_json::to_value(config).unwrap();
+
+        // 1차수 진입 (50000원) 후 상태 저장
+        let mut strategy = MeanReversionStrategy::new();
+        strategy.initialize(config_json.clone()).await.unwrap();
+        strategy
+            .on_market_data(&create_kline_data("005930", dec!(50000)))
+            .await
+            .unwrap();
+        let saved = strategy.save_state().unwrap();
+
+        // 재시작한 인스턴스에 상태 복원
+        let mut restarted = MeanReversionStrategy::new();
+        restarted.initialize(config_json).await.unwrap();
+        restarted.load_state(&saved).unwrap();
+
+        // 5% 하락 시 1차수를 다시 사지 않고 2차수만 진입
+        let signals = restarted
+            .on_market_data(&create_kline_data("005930", dec!(47500)))
+            .await
+            .unwrap();
+        let levels: Vec<_> = signals
+            .iter()
+            .filter(|s| s.side == Side::Buy)
+            .map(|s| s.metadata.get("level").cloned())
+            .collect();
+        assert_eq!(levels, vec![Some(json!(2))]);
+    }
 }
 
 // ================================================================================================
@@ -11,7 +11,7 @@ use rust_decimal_macros::dec;
 use serde_json::json;
 use trader_core::{Kline, MarketData, MarketDataType, Position, Side, Timeframe};
 use trader_strategy::strategies::momentum_power::{
-    MomentumPowerConfig, MomentumPowerMarket, MomentumPowerMode, MomentumPowerStrategy,
+    MomentumPowerConfig, MomentumPowerMarket, MomentumPowerStrategy,
 };
 use trader_strategy::Strategy;
 
@@ -64,6 +64,7 @@ async fn feed_rising_prices(
 }
 
 /// 하락 추세 가격 데이터 입력.
+#[allow(dead_code)]
 async fn feed_falling_prices(
     strategy: &mut MomentumPowerStrategy,
     ticker: &str,
@@ -353,7 +354,7 @@ mod signal_generation_tests {
         // 선행 조건: 신호가 있어야 함
         if !all_signals.is_empty() {
             // US 시장 자산: UPRO, TLT, BIL
-            let valid_tickers = vec!["UPRO/USD", "TLT/USD", "BIL/USD"];
+            let valid_tickers = ["UPRO/USD", "TLT/USD", "BIL/USD"];
             for signal in &all_signals {
                 assert!(
                     valid_tickers.contains(&signal.ticker.as_str()),
@@ -413,10 +414,10 @@ mod rebalance_condition_tests {
 
         // 같은 주기 내 추가 데이터 (5일 주기 설정)
         let data1 = create_kline_at("UPRO", dec!(80), 15);
-        let signals1 = strategy.on_market_data(&data1).await.unwrap();
+        let _signals1 = strategy.on_market_data(&data1).await.unwrap();
 
         let data2 = create_kline_at("UPRO", dec!(82), 16);
-        let signals2 = strategy.on_market_data(&data2).await.unwrap();
+        let _signals2 = strategy.on_market_data(&data2).await.unwrap();
 
         // 첫 번째 이후에는 신호가 적어야 함 (이미 리밸런싱됨)
         // 참고: 모드 변경이 있으면 신호가 생성될 수 있음
@@ -178,7 +178,7 @@ async fn test_config_defaults() {
 
     assert_eq!(config.div_num, 15);
     assert_eq!(config.target_period, 20);
-    assert_eq!(config.use_ma_filter, true);
+    assert!(config.use_ma_filter);
     assert_eq!(config.buy_ma_period, 20);
     assert_eq!(config.sell_ma_period, 5);
     assert_eq!(config.min_global_score, dec!(50));
@@ -343,7 +343,7 @@ async fn test_ma_filter_blocks_buy_below_ma() {
     strategy.on_market_data(&price1).await.unwrap();
 
     let price2 = create_simple_kline("005930", dec!(108), 1000000 + 7 * 86400);
-    let signals = strategy.on_market_data(&price2).await.unwrap();
+    let _signals = strategy.on_market_data(&price2).await.unwrap();
 
     // MA 필터 동작 확인
     // 참고: 실제 전략의 MA 필터 로직에 따라 결과가 달라질 수 있음
@@ -721,7 +721,7 @@ async fn test_reinitialize_and_trade() {
 
     // 두 번째 사이클 (다른 가격대)
     let cycle2: Vec<Decimal> = vec![dec!(200), dec!(205), dec!(210), dec!(215), dec!(220)];
-    let signals = feed_prices(&mut strategy, "005930", &cycle2, 2000000).await;
+    let _signals = feed_prices(&mut strategy, "005930", &cycle2, 2000000).await;
 
     // 재초기화 후에도 정상 동작
     let state = strategy.get_state();
@@ -0,0 +1,93 @@
+//! 합성 시나리오 기반 속성 테스트.
+//!
+//! `trader-testkit`의 시나리오(추세, 평균 회귀, 갭, 급락)와 여러 시드로 생성한 캔들을
+//! 전략에 흘려 넣고, 어떤 경로에서도 지켜져야 하는 성질을 검증합니다:
+//! - 데이터 처리 중 에러가 발생하지 않음
+//! - 신호의 티커가 설정한 티커와 일치
+//! - 신호 강도는 0~1, 제안 가격/손절/익절은 양수
+
+use trader_core::{Kline, MarketData, MarketDataType, Signal};
+use trader_strategy::strategies::mean_reversion::{MeanReversionConfig, MeanReversionStrategy};
+use trader_strategy::Strategy;
+use trader_testkit::{KlineGenerator, Scenario};
+
+const TICKER: &str = "005930";
+const SEEDS: u64 = 8;
+const BARS: usize = 300;
+
+fn to_market_data(kline: Kline) -> MarketData {
+    MarketData {
+        exchange: "test".to_string(),
+        ticker: kline.ticker.clone(),
+        timestamp: kline.close_time,
+        data: MarketDataType::Kline(kline),
+    }
+}
+
+fn assert_signal_sane(signal: &Signal, context: &str) {
+    assert_eq!(signal.ticker, TICKER, "{context}");
+    assert!(
+        (0.0..=1.0).contains(&signal.strength),
+        "{context}: strength={}",
+        signal.strength
+    );
+    for price in [signal.suggested_price, signal.stop_loss, signal.take_profit]
+        .into_iter()
+        .flatten()
+    {
+        assert!(price.is_sign_positive(), "{context}: price={price}");
+    }
+}
+
+/// 설정별로 모든 시나리오 × 시드 조합을 실행.
+async fn run_all_scenarios(name: &str, config: MeanReversionConfig) {
+    let config_json = serde_json::to_value(config).unwrap();
+
+    for scenario in Scenario::ALL {
+        for seed in 0..SEEDS {
+            let context = format!("{name} {scenario:?} seed={seed}");
+            let mut strategy = MeanReversionStrategy::new();
+            strategy
+                .initialize(config_json.clone())
+                .await
+                .unwrap_or_else(|e| panic!("{context}: 초기화 실패: {e}"));
+
+            let klines = KlineGenerator::new(TICKER, seed)
+                .scenario(scenario)
+                .generate(BARS);
+            for kline in klines {
+                let signals = strategy
+                    .on_market_data(&to_market_data(kline))
+                    .await
+                    .unwrap_or_else(|e| panic!("{context}: 데이터 처리 실패: {e}"));
+                for signal in &signals {
+                    assert_signal_sane(signal, &context);
+                }
+            }
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_mean_reversion_rsi_scenarios() {
+    run_all_scenarios("rsi", MeanReversionConfig::rsi_default(TICKER)).await;
+}
+
+#[tokio::test]
+async fn test_mean_reversion_bollinger_scenarios() {
+    run_all_scenarios("bollinger", MeanReversionConfig::bollinger_default(TICKER)).await;
+}
+
+#[tokio::test]
+async fn test_mean_reversion_grid_scenarios() {
+    run_all_scenarios("grid", MeanReversionConfig::grid_default(TICKER)).await;
+}
+
+#[tokio::test]
+async fn test_mean_reversion_magic_split_scenarios() {
+    run_all_scenarios(
+        "magic_split",
+        MeanReversionConfig::magic_split_default(TICKER),
+    )
+    .await;
+}
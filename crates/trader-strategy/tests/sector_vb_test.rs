@@ -213,7 +213,7 @@ async fn test_breakout_calculation() {
         1,
     );
 
-    let signals = strategy.on_market_data(&today_data).await.unwrap();
+    let _signals = strategy.on_market_data(&today_data).await.unwrap();
 
     // 매수 신호 확인 (돌파 조건 충족 시)
     let state = strategy.get_state();
@@ -260,7 +260,7 @@ async fn test_no_breakout_below_target() {
     let signals = strategy.on_market_data(&today_data).await.unwrap();
 
     // 돌파 실패 시 매수 신호 없음
-    let buy_signals: Vec<_> = signals.iter().filter(|s| s.side == Side::Buy).collect();
+    let _buy_signals: Vec<_> = signals.iter().filter(|s| s.side == Side::Buy).collect();
     // 돌파 조건 미충족 시 신호 없을 수 있음
 }
 
@@ -367,7 +367,7 @@ async fn test_position_cleared_on_zero_quantity() {
     let zero_position = create_position("091160", dec!(0), dec!(0));
     strategy.on_position_update(&zero_position).await.unwrap();
 
-    let state = strategy.get_state();
+    let _state = strategy.get_state();
     // positions에서 삭제되거나 수량이 0이어야 함
 }
 
@@ -576,7 +576,7 @@ async fn test_full_trading_day_scenario() {
         1,
     );
 
-    let signals = strategy.on_market_data(&today_data).await.unwrap();
+    let _signals = strategy.on_market_data(&today_data).await.unwrap();
 
     // 전략이 정상 동작
     let state = strategy.get_state();
@@ -287,7 +287,7 @@ async fn test_position_cleared_on_zero_quantity() {
     let zero_position = create_position("TQQQ", dec!(0), dec!(0));
     strategy.on_position_update(&zero_position).await.unwrap();
 
-    let state = strategy.get_state();
+    let _state = strategy.get_state();
     // TQQQ가 제거되었거나 수량이 0이어야 함
 }
 
@@ -370,7 +370,7 @@ async fn test_max_drawdown_protection() {
     let signals = strategy.on_market_data(&crash_data).await.unwrap();
 
     // 최대 드로다운 초과 시 청산 신호
-    let sell_signals: Vec<_> = signals.iter().filter(|s| s.side == Side::Sell).collect();
+    let _sell_signals: Vec<_> = signals.iter().filter(|s| s.side == Side::Sell).collect();
     // 드로다운 보호 기능 동작 여부
 }
 
@@ -497,7 +497,7 @@ async fn test_full_trading_cycle() {
     // Phase 2: 상승 추세
     for day in 10..25 {
         let tqqq_price = dec!(50) + Decimal::from((day - 10) * 3);
-        let sqqq_price = dec!(30) - Decimal::from((day - 10) * 1);
+        let sqqq_price = dec!(30) - Decimal::from(day - 10);
         let sqqq_price = if sqqq_price < dec!(15) {
             dec!(15)
         } else {
@@ -0,0 +1,160 @@
+//! 고정 재무 데이터 픽스처.
+//!
+//! 스크리닝/팩터 전략 테스트용 재무 지표입니다. 실제 종목 코드를 쓰지만 수치는
+//! 대략적인 규모만 맞춘 예시 값이므로 실제 데이터로 사용하면 안 됩니다.
+
+use rust_decimal::prelude::FromPrimitive;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::rng::{seed_from_str, SeededRng};
+
+/// 종목 재무 지표.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Fundamentals {
+    pub ticker: String,
+    pub name: String,
+    /// KR / US
+    pub market: String,
+    pub sector: String,
+    /// 시가총액 (현지 통화, 억 단위)
+    pub market_cap: Decimal,
+    pub per: Decimal,
+    pub pbr: Decimal,
+    /// ROE (%)
+    pub roe: Decimal,
+    pub eps: Decimal,
+    pub bps: Decimal,
+    /// 영업이익 (현지 통화, 억 단위)
+    pub operating_profit: Decimal,
+    /// 배당수익률 (%)
+    pub dividend_yield: Decimal,
+}
+
+/// (ticker, name, market, sector, 시가총액, PER, PBR, ROE, EPS, BPS, 영업이익, 배당수익률)
+type Row = (
+    &'static str,
+    &'static str,
+    &'static str,
+    &'static str,
+    i64,
+    i64,
+    i64,
+    i64,
+    i64,
+    i64,
+    i64,
+    i64,
+);
+
+/// 비율 지표는 소수 둘째 자리까지 100배 정수로 표기.
+#[rustfmt::skip]
+const CANNED: [Row; 8] = [
+    ("005930", "삼성전자", "KR", "반도체", 4_300_000, 1_450, 130, 900, 4_950, 55_000, 65_000, 200),
+    ("000660", "SK하이닉스", "KR", "반도체", 1_300_000, 2_100, 190, 900, 8_500, 95_000, 30_000, 60),
+    ("035420", "NAVER", "KR", "인터넷", 300_000, 2_300, 130, 600, 8_000, 140_000, 15_000, 50),
+    ("105560", "KB금융", "KR", "금융", 300_000, 650, 50, 850, 11_500, 150_000, 60_000, 450),
+    ("068270", "셀트리온", "KR", "바이오", 400_000, 6_500, 300, 450, 2_800, 60_000, 5_000, 30),
+    ("AAPL", "Apple", "US", "Technology", 30_000, 2_900, 4_500, 15_000, 640, 410, 1_150, 50),
+    ("MSFT", "Microsoft", "US", "Technology", 31_000, 3_500, 1_200, 3_600, 1_180, 3_450, 1_090, 70),
+    ("JPM", "JPMorgan Chase", "US", "Financials", 5_500, 1_200, 190, 1_700, 1_660, 10_500, 0, 230),
+];
+
+fn decimal(value: i64, scale: u32) -> Decimal {
+    Decimal::new(value, scale)
+}
+
+fn from_row(row: &Row) -> Fundamentals {
+    let (ticker, name, market, sector, cap, per, pbr, roe, eps, bps, op, dividend) = *row;
+    Fundamentals {
+        ticker: ticker.to_string(),
+        name: name.to_string(),
+        market: market.to_string(),
+        sector: sector.to_string(),
+        market_cap: decimal(cap, 0),
+        per: decimal(per, 2),
+        pbr: decimal(pbr, 2),
+        roe: decimal(roe, 2),
+        eps: decimal(eps, 0),
+        bps: decimal(bps, 0),
+        operating_profit: decimal(op, 0),
+        dividend_yield: decimal(dividend, 2),
+    }
+}
+
+/// 고정 재무 데이터 전체 (KR 대형주 5종목, US 3종목).
+pub fn canned_fundamentals() -> Vec<Fundamentals> {
+    CANNED.iter().map(from_row).collect()
+}
+
+/// 티커의 고정 재무 데이터.
+pub fn fundamentals_for(ticker: &str) -> Option<Fundamentals> {
+    CANNED.iter().find(|row| row.0 == ticker).map(from_row)
+}
+
+/// 임의 티커의 재무 데이터 생성 (같은 티커와 시드면 항상 같은 값).
+///
+/// EPS/BPS는 PER/PBR과 가상의 주가(10,000)로부터 역산하여 지표 간 관계를 유지합니다.
+pub fn generate_fundamentals(ticker: &str, seed: u64) -> Fundamentals {
+    let mut rng = SeededRng::new(seed ^ seed_from_str(ticker));
+    let sectors = ["반도체", "인터넷", "금융", "바이오", "자동차", "화학"];
+    let sector = sectors[(rng.next_u64() % sectors.len() as u64) as usize];
+
+    let per = rng.range(4.0, 40.0);
+    let pbr = rng.range(0.3, 5.0);
+    let price = 10_000.0;
+    let eps = price / per;
+    let bps = price / pbr;
+    let roe = eps / bps * 100.0;
+    let market_cap = rng.range(500.0, 500_000.0);
+
+    let dec = |v: f64, dp: u32| Decimal::from_f64(v).unwrap_or_default().round_dp(dp);
+    Fundamentals {
+        ticker: ticker.to_string(),
+        name: format!("{} (synthetic)", ticker),
+        market: if ticker.chars().all(|c| c.is_ascii_digit()) {
+            "KR"
+        } else {
+            "US"
+        }
+        .to_string(),
+        sector: sector.to_string(),
+        market_cap: dec(market_cap, 0),
+        per: dec(per, 2),
+        pbr: dec(pbr, 2),
+        roe: dec(roe, 2),
+        eps: dec(eps, 0),
+        bps: dec(bps, 0),
+        operating_profit: dec(market_cap / per * rng.range(0.8, 1.5), 0),
+        dividend_yield: dec(rng.range(0.0, 5.0), 2),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_canned_fundamentals() {
+        let all = canned_fundamentals();
+        assert_eq!(all.len(), 8);
+        assert!(all
+            .iter()
+            .all(|f| f.per > Decimal::ZERO && f.pbr > Decimal::ZERO));
+
+        let samsung = fundamentals_for("005930").unwrap();
+        assert_eq!(samsung.market, "KR");
+        assert_eq!(samsung.per, Decimal::new(1450, 2));
+        assert!(fundamentals_for("UNKNOWN").is_none());
+    }
+
+    #[test]
+    fn test_generate_fundamentals_deterministic() {
+        let a = generate_fundamentals("123456", 1);
+        assert_eq!(a, generate_fundamentals("123456", 1));
+        assert_ne!(a, generate_fundamentals("654321", 1));
+        assert_eq!(a.market, "KR");
+        assert_eq!(generate_fundamentals("XYZ", 1).market, "US");
+        assert!(a.roe > Decimal::ZERO);
+    }
+}
@@ -0,0 +1,306 @@
+//! 시나리오별 합성 OHLCV 생성.
+//!
+//! 같은 시드와 설정이면 항상 같은 캔들을 생성합니다. 시작 시각도 고정값이 기본이므로
+//! 테스트 결과가 실행 시각에 따라 달라지지 않습니다.
+//!
+//! # 시나리오
+//!
+//! - `RandomWalk`: 추세 없는 무작위 보행
+//! - `Trending`: 꾸준한 상승 추세
+//! - `MeanReverting`: 시작 가격 주변으로 회귀 (Ornstein-Uhlenbeck)
+//! - `Gapping`: 무작위 보행 중 간헐적인 시가 갭 (±3~8%)
+//! - `Crash`: 완만한 상승 후 짧은 기간 약 35% 급락, 이후 고변동 반등
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use trader_core::{Kline, Timeframe};
+
+use crate::rng::SeededRng;
+
+/// 가격 경로 시나리오.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Scenario {
+    #[default]
+    RandomWalk,
+    Trending,
+    MeanReverting,
+    Gapping,
+    Crash,
+}
+
+impl Scenario {
+    /// 전체 시나리오 (시나리오 전수 테스트용).
+    pub const ALL: [Scenario; 5] = [
+        Scenario::RandomWalk,
+        Scenario::Trending,
+        Scenario::MeanReverting,
+        Scenario::Gapping,
+        Scenario::Crash,
+    ];
+}
+
+/// 합성 캔들 생성기.
+///
+/// # 예제
+///
+/// ```rust
+/// use trader_testkit::{KlineGenerator, Scenario};
+///
+/// let klines = KlineGenerator::new("005930", 42)
+///     .scenario(Scenario::Crash)
+///     .generate(250);
+/// assert_eq!(klines.len(), 250);
+/// ```
+#[derive(Debug, Clone)]
+pub struct KlineGenerator {
+    ticker: String,
+    seed: u64,
+    scenario: Scenario,
+    timeframe: Timeframe,
+    start_time: DateTime<Utc>,
+    start_price: Decimal,
+    volatility: f64,
+    drift: Option<f64>,
+    base_volume: f64,
+    decimals: u32,
+}
+
+impl KlineGenerator {
+    /// 기본 설정: 일봉, 2024-01-02 00:00 UTC 시작, 시작가 10,000, 봉당 변동성 2%.
+    pub fn new(ticker: impl Into<String>, seed: u64) -> Self {
+        Self {
+            ticker: ticker.into(),
+            seed,
+            scenario: Scenario::default(),
+            timeframe: Timeframe::D1,
+            start_time: Utc.with_ymd_and_hms(2024, 1, 2, 0, 0, 0).unwrap(),
+            start_price: Decimal::from(10_000),
+            volatility: 0.02,
+            drift: None,
+            base_volume: 1_000_000.0,
+            decimals: 2,
+        }
+    }
+
+    pub fn scenario(mut self, scenario: Scenario) -> Self {
+        self.scenario = scenario;
+        self
+    }
+
+    pub fn timeframe(mut self, timeframe: Timeframe) -> Self {
+        self.timeframe = timeframe;
+        self
+    }
+
+    /// 첫 캔들 시작 시각.
+    pub fn start_time(mut self, start_time: DateTime<Utc>) -> Self {
+        self.start_time = start_time;
+        self
+    }
+
+    pub fn start_price(mut self, start_price: Decimal) -> Self {
+        self.start_price = start_price;
+        self
+    }
+
+    /// 봉당 수익률 표준편차 (예: 0.02 = 2%).
+    pub fn volatility(mut self, volatility: f64) -> Self {
+        self.volatility = volatility.max(0.0);
+        self
+    }
+
+    /// 봉당 평균 수익률 (시나리오 기본값 대신 사용).
+    pub fn drift(mut self, drift: f64) -> Self {
+        self.drift = Some(drift);
+        self
+    }
+
+    /// 평균 거래량.
+    pub fn base_volume(mut self, base_volume: f64) -> Self {
+        self.base_volume = base_volume.max(0.0);
+        self
+    }
+
+    /// 가격 소수 자릿수 (기본값: 2).
+    pub fn decimals(mut self, decimals: u32) -> Self {
+        self.decimals = decimals;
+        self
+    }
+
+    /// 캔들 `count`개 생성.
+    pub fn generate(&self, count: usize) -> Vec<Kline> {
+        let mut rng = SeededRng::new(self.seed);
+        let step = Duration::from_std(self.timeframe.duration()).unwrap_or(Duration::days(1));
+        let start_price = self.start_price.to_f64().unwrap_or(10_000.0).max(0.01);
+        let vol = self.volatility;
+
+        // Crash: 60% 지점부터 전체의 5% (최소 3봉) 동안 급락
+        let crash_start = count * 6 / 10;
+        let crash_len = (count / 20).max(3);
+        let crash_step = 0.65_f64.powf(1.0 / crash_len as f64) - 1.0;
+
+        let mut klines = Vec::with_capacity(count);
+        let mut prev_close = start_price;
+        for i in 0..count {
+            let mut open = prev_close;
+            let ret = match self.scenario {
+                Scenario::RandomWalk => self.drift.unwrap_or(0.0) + vol * rng.normal(),
+                Scenario::Trending => self.drift.unwrap_or(0.002) + vol * rng.normal(),
+                Scenario::MeanReverting => {
+                    // 로그 가격의 OU 과정: 평균(시작가)으로 15%씩 회귀
+                    let gap = (start_price / prev_close).ln();
+                    let log_ret = self.drift.unwrap_or(0.0) + 0.15 * gap + vol * rng.normal();
+                    log_ret.exp() - 1.0
+                }
+                Scenario::Gapping => {
+                    if i > 0 && rng.chance(0.05) {
+                        let size = rng.range(0.03, 0.08);
+                        let sign = if rng.chance(0.5) { 1.0 } else { -1.0 };
+                        open = prev_close * (1.0 + sign * size);
+                    }
+                    self.drift.unwrap_or(0.0) + vol * rng.normal()
+                }
+                Scenario::Crash => {
+                    if (crash_start..crash_start + crash_len).contains(&i) {
+                        crash_step + vol * 0.25 * rng.normal()
+                    } else if i >= crash_start + crash_len {
+                        // 급락 후 고변동 반등
+                        self.drift.unwrap_or(0.002) + vol * 2.0 * rng.normal()
+                    } else {
+                        self.drift.unwrap_or(0.001) + vol * rng.normal()
+                    }
+                }
+            };
+
+            let close = (open * (1.0 + ret)).max(0.01);
+            let high = open.max(close) * (1.0 + (vol * 0.5 * rng.normal()).abs());
+            let low = (open.min(close) * (1.0 - (vol * 0.5 * rng.normal()).abs())).max(0.01);
+            let activity = if vol > 0.0 { ret.abs() / vol } else { 0.0 };
+            let volume = self.base_volume * (1.0 + activity) * rng.range(0.7, 1.3);
+
+            let open_time = self.start_time + step * i as i32;
+            let close = self.price(close);
+            let volume = Decimal::from_f64(volume.round()).unwrap_or_default();
+            klines.push(Kline {
+                ticker: self.ticker.clone(),
+                timeframe: self.timeframe,
+                open_time,
+                close_time: open_time + step,
+                open: self.price(open),
+                high: self.price(high),
+                low: self.price(low),
+                close,
+                volume,
+                quote_volume: Some((volume * close).round_dp(self.decimals)),
+                num_trades: None,
+            });
+
+            // 반올림된 가격으로 이어가야 다음 봉 시가와 정확히 일치
+            prev_close = close.to_f64().unwrap_or(prev_close);
+        }
+
+        klines
+    }
+
+    fn price(&self, value: f64) -> Decimal {
+        let min = Decimal::new(1, self.decimals);
+        Decimal::from_f64(value)
+            .map(|d| d.round_dp(self.decimals))
+            .unwrap_or(min)
+            .max(min)
+    }
+}
+
+/// 캔들의 OHLC 일관성 검사 (저가 ≤ 시가/종가 ≤ 고가, 양수 가격, 음이 아닌 거래량).
+pub fn is_valid_ohlc(kline: &Kline) -> bool {
+    kline.low > Decimal::ZERO
+        && kline.low <= kline.open.min(kline.close)
+        && kline.high >= kline.open.max(kline.close)
+        && kline.volume >= Decimal::ZERO
+        && kline.close_time > kline.open_time
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn closes(klines: &[Kline]) -> Vec<f64> {
+        klines.iter().map(|k| k.close.to_f64().unwrap()).collect()
+    }
+
+    #[test]
+    fn test_deterministic() {
+        for scenario in Scenario::ALL {
+            let generator = KlineGenerator::new("TEST", 7).scenario(scenario);
+            let a = generator.generate(300);
+            let b = generator.generate(300);
+            assert_eq!(closes(&a), closes(&b));
+            assert_eq!(a[299].open_time, b[299].open_time);
+
+            let c = KlineGenerator::new("TEST", 8)
+                .scenario(scenario)
+                .generate(300);
+            assert_ne!(closes(&a), closes(&c));
+        }
+    }
+
+    #[test]
+    fn test_ohlc_invariants() {
+        for scenario in Scenario::ALL {
+            for seed in 0..20 {
+                let klines = KlineGenerator::new("TEST", seed)
+                    .scenario(scenario)
+                    .volatility(0.05)
+                    .generate(200);
+                assert!(klines.iter().all(is_valid_ohlc), "{scenario:?} seed={seed}");
+                assert!(klines.windows(2).all(|w| w[1].open_time == w[0].close_time));
+            }
+        }
+    }
+
+    #[test]
+    fn test_scenario_shapes() {
+        let n = 500;
+        let start = 10_000.0;
+
+        // 추세: 대부분의 시드에서 상승 마감
+        let up = (0..20)
+            .filter(|&seed| {
+                let k = KlineGenerator::new("T", seed)
+                    .scenario(Scenario::Trending)
+                    .generate(n);
+                closes(&k)[n - 1] > start
+            })
+            .count();
+        assert!(up >= 15);
+
+        // 평균 회귀: 시작가 근처에 머무름
+        let k = KlineGenerator::new("T", 1)
+            .scenario(Scenario::MeanReverting)
+            .generate(n);
+        assert!(closes(&k).iter().all(|c| (c / start).ln().abs() < 0.5));
+
+        // 급락: 급락 구간 직전 대비 30% 이상 하락
+        let k = KlineGenerator::new("T", 1)
+            .scenario(Scenario::Crash)
+            .volatility(0.01)
+            .generate(n);
+        let c = closes(&k);
+        let before = c[n * 6 / 10 - 1];
+        let bottom = c[n * 6 / 10 + n / 20 - 1];
+        assert!(bottom / before < 0.7);
+
+        // 갭: 전일 종가 대비 3% 이상 벌어진 시가가 존재
+        let k = KlineGenerator::new("T", 1)
+            .scenario(Scenario::Gapping)
+            .generate(n);
+        let gaps = k
+            .windows(2)
+            .filter(|w| ((w[1].open - w[0].close) / w[0].close).abs() >= Decimal::new(3, 2))
+            .count();
+        assert!(gaps >= 5);
+    }
+}
@@ -0,0 +1,20 @@
+//! 테스트와 샘플 데이터용 결정적 시장 데이터 픽스처.
+//!
+//! 시드 기반 합성 OHLCV와 고정 재무 데이터를 제공합니다. 같은 입력이면 항상 같은
+//! 데이터를 생성하므로 여러 crate의 전략/분석 테스트에서 재현 가능한 입력으로 사용합니다.
+//!
+//! # 모듈
+//!
+//! - `klines`: 시나리오별(추세, 평균 회귀, 갭, 급락) 캔들 생성
+//! - `fundamentals`: 고정/합성 재무 지표
+//! - `rng`: 버전 독립적인 시드 난수 생성기
+
+pub mod fundamentals;
+pub mod klines;
+pub mod rng;
+
+pub use fundamentals::{
+    canned_fundamentals, fundamentals_for, generate_fundamentals, Fundamentals,
+};
+pub use klines::{is_valid_ohlc, KlineGenerator, Scenario};
+pub use rng::{seed_from_str, SeededRng};
@@ -0,0 +1,96 @@
+//! 시드 기반 난수 생성기.
+//!
+//! 외부 난수 crate의 버전이 바뀌어도 같은 시드가 같은 수열을 내도록
+//! SplitMix64를 직접 구현합니다. 암호학적 용도로 사용하면 안 됩니다.
+
+/// 문자열로부터 시드 생성 (FNV-1a).
+///
+/// 종목 코드처럼 고정된 이름마다 서로 다르면서 재현 가능한 시드가 필요할 때 사용합니다.
+pub fn seed_from_str(s: &str) -> u64 {
+    s.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, b| {
+        (hash ^ b as u64).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// SplitMix64 난수 생성기.
+#[derive(Debug, Clone)]
+pub struct SeededRng {
+    state: u64,
+}
+
+impl SeededRng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// 다음 64비트 정수.
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// [0, 1) 구간 실수.
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// [low, high) 구간 실수.
+    pub fn range(&mut self, low: f64, high: f64) -> f64 {
+        low + (high - low) * self.next_f64()
+    }
+
+    /// 표준 정규분포 표본 (Box-Muller).
+    pub fn normal(&mut self) -> f64 {
+        // ln(0) 방지
+        let u1 = self.next_f64().max(f64::MIN_POSITIVE);
+        let u2 = self.next_f64();
+        (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+    }
+
+    /// 확률 `p`로 true.
+    pub fn chance(&mut self, p: f64) -> bool {
+        self.next_f64() < p
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_same_seed_same_sequence() {
+        let mut a = SeededRng::new(42);
+        let mut b = SeededRng::new(42);
+        for _ in 0..100 {
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+
+        let mut c = SeededRng::new(43);
+        assert_ne!(SeededRng::new(42).next_u64(), c.next_u64());
+    }
+
+    #[test]
+    fn test_distribution_ranges() {
+        let mut rng = SeededRng::new(7);
+        let samples: Vec<f64> = (0..10_000).map(|_| rng.next_f64()).collect();
+        assert!(samples.iter().all(|v| (0.0..1.0).contains(v)));
+
+        let mean = samples.iter().sum::<f64>() / samples.len() as f64;
+        assert!((mean - 0.5).abs() < 0.02);
+
+        let normals: Vec<f64> = (0..10_000).map(|_| rng.normal()).collect();
+        let mean = normals.iter().sum::<f64>() / normals.len() as f64;
+        let var = normals.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / normals.len() as f64;
+        assert!(mean.abs() < 0.05);
+        assert!((var - 1.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_seed_from_str() {
+        assert_eq!(seed_from_str("005930"), seed_from_str("005930"));
+        assert_ne!(seed_from_str("005930"), seed_from_str("000660"));
+    }
+}
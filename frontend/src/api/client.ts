@@ -30,6 +30,7 @@ import type {
   SymbolPnLItem,
   SymbolPnLResponse,
   SyncResponse as JournalSyncResponseGenerated,
+  JournalExportQuery,
   // Screening 타입
   ScreeningRequest as GeneratedScreeningRequest,
   ScreeningResponse as GeneratedScreeningResponse,
@@ -183,6 +184,29 @@ export const getMarketStatus = async (market: 'KR' | 'US'): Promise<MarketStatus
   return response.data;
 };
 
+/** 휴장일/단축 거래일 항목 (시각은 시장 현지 시각) */
+export interface MarketCalendarEntry {
+  market: 'KR' | 'US';
+  date: string;
+  kind: 'holiday' | 'early_close' | 'late_open';
+  open_time: string | null;
+  close_time: string | null;
+  name: string | null;
+}
+
+export interface MarketCalendarResponse {
+  market: 'KR' | 'US';
+  year: number;
+  /** false면 아직 동기화되지 않아 주말 외 휴장일을 알 수 없음 */
+  loaded: boolean;
+  entries: MarketCalendarEntry[];
+}
+
+export const getMarketCalendar = async (market: 'KR' | 'US', year?: number): Promise<MarketCalendarResponse> => {
+  const response = await api.get(`/market/${market}/calendar`, { params: { year } });
+  return response.data;
+};
+
 // ==================== 시장 온도 (Market Breadth) ====================
 
 /** 시장 온도 응답 */
@@ -209,6 +233,48 @@ export const getMarketBreadth = async (): Promise<MarketBreadthResponse> => {
   return response.data;
 };
 
+/** 일별 매크로 레짐 */
+export interface MacroRegimeView {
+  /** 기준일 (YYYY-MM-DD) */
+  date: string;
+  /** 종합 점수 (-1.0 위험 회피 ~ +1.0 위험 선호) */
+  score: number;
+  /** 레짐 */
+  level: 'RISK_ON' | 'NEUTRAL' | 'RISK_OFF';
+  /** 환율 추세 점수 */
+  fxScore: number | null;
+  /** 금리차 점수 */
+  yieldCurveScore: number | null;
+  /** 변동성 점수 */
+  volatilityScore: number | null;
+  /** 시장 폭 점수 */
+  breadthScore: number | null;
+  /** USD/KRW 20거래일 변동률 (%) */
+  usdKrwTrendPct: number | null;
+  /** 미국 10년-2년 금리차 (%p) */
+  yieldCurveSlope: number | null;
+  /** VIX */
+  vix: number | null;
+  /** VKOSPI */
+  vkospi: number | null;
+  /** 20일선 상회 종목 비율 (0.0 ~ 1.0) */
+  breadthRatio: number | null;
+}
+
+/** 매크로 레짐 응답 */
+export interface MacroRegimeResponse {
+  /** 최신 매크로 레짐 */
+  latest: MacroRegimeView | null;
+  /** 일별 이력 (오래된 순) */
+  history: MacroRegimeView[];
+}
+
+/** 매크로 레짐 종합 지표 및 이력 조회 */
+export const getMacroRegime = async (days = 90): Promise<MacroRegimeResponse> => {
+  const response = await api.get('/market/macro-regime', { params: { days } });
+  return response.data;
+};
+
 // ==================== 캔들스틱 데이터 ====================
 
 export interface CandleData {
@@ -414,6 +480,34 @@ export interface StrategyDetailResponse {
   };
   state: Record<string, unknown>;
   config: Record<string, unknown>;
+  /** 운용 용량 추정 (DB 미연결 시 없음) */
+  capacity?: StrategyCapacity;
+}
+
+/** 종목별 운용 용량 */
+export interface SymbolCapacity {
+  ticker: string;
+  weight: number;
+  avg_daily_amount: number;
+  max_adv_fraction: number;
+  max_daily_trade_value: number;
+  slippage_bound: boolean;
+  capacity: number | null;
+  slippage_rate_at_allocation: number | null;
+}
+
+/** 전략 운용 용량 추정 (ADV 참여율/회전율/슬리피지 기준) */
+export interface StrategyCapacity {
+  capacity: number | null;
+  daily_turnover: number;
+  binding_ticker: string | null;
+  allocated_capital: number | null;
+  utilization: number | null;
+  status: 'ok' | 'warning' | 'exceeded' | 'unknown';
+  warning: string | null;
+  symbols: SymbolCapacity[];
+  turnover_source: 'executions' | 'assumed';
+  lookback_days: number;
 }
 
 // 전략 상세 조회
@@ -496,6 +590,18 @@ export interface BacktestRequest {
   parameters?: Record<string, unknown>;
   /** 다중 타임프레임 설정 (옵션) */
   multi_timeframe_config?: MultiTimeframeConfig;
+  /** 캔들 타임프레임 (기본 '1d', 분봉은 정규장 세션으로 시뮬레이션) */
+  timeframe?: Timeframe;
+  /** 장중 백테스트에서 세션 마감 시 미청산 포지션 청산 */
+  flatten_at_close?: boolean;
+  /** 결과를 연결할 등록 전략 ID (옵션) */
+  registered_strategy_id?: string;
+  /** 결과 자동 저장 여부 (기본 true) */
+  persist?: boolean;
+  /** 저장된 데이터가 없을 때 샘플 데이터 허용 (데모용, 기본 false) */
+  allow_synthetic?: boolean;
+  /** 심볼별 최소 데이터 커버리지 % (기본 90) */
+  min_coverage_pct?: number;
 }
 
 // 다중 자산 백테스트 요청 (Simple Power, HAA, XAA, Stock Rotation 등)
@@ -510,6 +616,14 @@ export interface BacktestMultiRequest {
   parameters?: Record<string, unknown>;
   /** 다중 타임프레임 설정 (옵션) */
   multi_timeframe_config?: MultiTimeframeConfig;
+  /** 결과를 연결할 등록 전략 ID (옵션) */
+  registered_strategy_id?: string;
+  /** 결과 자동 저장 여부 (기본 true) */
+  persist?: boolean;
+  /** 저장된 데이터가 없을 때 샘플 데이터 허용 (데모용, 기본 false) */
+  allow_synthetic?: boolean;
+  /** 심볼별 최소 데이터 커버리지 % (기본 90) */
+  min_coverage_pct?: number;
 }
 
 // 다중 자산 백테스트 결과 (심볼별 데이터 포인트 포함)
@@ -732,7 +846,10 @@ export interface BacktestConfigSummary {
 export interface BacktestResult {
   id: string;
   success: boolean;
-  strategy_id: string;
+  /** 전략 ID (저장된 결과 중 등록 전략 없이 실행한 결과는 null) */
+  strategy_id: string | null;
+  /** 전략 타입 (저장된 결과) */
+  strategy_type?: string;
   symbol: string;
   start_date: string;
   end_date: string;
@@ -742,6 +859,31 @@ export interface BacktestResult {
   config_summary: BacktestConfigSummary;
   /** 백테스트에 사용된 타임프레임 설정 (다중 TF 백테스트 시) */
   timeframes_used?: MultiTimeframeConfig;
+  /** 실행 시 DB에 저장되었는지 여부 (true면 id가 저장된 결과 ID) */
+  persisted?: boolean;
+  /** 실행 전 데이터 커버리지 리포트 */
+  data_coverage?: DataCoverageReport;
+}
+
+export type KlineSource = 'stored' | 'synthetic' | 'leverage_backfill';
+
+export interface SymbolCoverage {
+  symbol: string;
+  first_date?: string;
+  last_date?: string;
+  bars: number;
+  expected_periods: number;
+  coverage_pct: number;
+  gaps: { start: string; end: string; missing_periods: number }[];
+  sources: Partial<Record<KlineSource, number>>;
+  adjusted: boolean;
+}
+
+export interface DataCoverageReport {
+  timeframe: string;
+  start: string;
+  end: string;
+  symbols: SymbolCoverage[];
 }
 
 export const runBacktest = async (request: BacktestRequest): Promise<BacktestResult> => {
@@ -843,6 +985,8 @@ export interface SaveBacktestResultRequest {
   success: boolean;
   /** 백테스트에 사용된 타임프레임 설정 (다중 TF 백테스트 시) */
   timeframes_used?: MultiTimeframeConfig;
+  /** 백테스트에 사용된 전략 파라미터 (라이브 승격 시 사용) */
+  parameters?: Record<string, unknown>;
 }
 
 /** 백테스트 결과 저장 응답 */
@@ -855,6 +999,12 @@ export interface SaveBacktestResultResponse {
 export interface ListBacktestResultsQuery {
   strategy_id?: string;
   strategy_type?: string;
+  symbol?: string;
+  /** 실행일 시작 (YYYY-MM-DD) */
+  from?: string;
+  /** 실행일 종료 (YYYY-MM-DD) */
+  to?: string;
+  sort_by?: 'created_at' | 'total_return' | 'sharpe_ratio' | 'max_drawdown';
   limit?: number;
   offset?: number;
 }
@@ -870,12 +1020,143 @@ export const deleteBacktestResult = async (id: string): Promise<void> => {
   await api.delete(`/backtest/results/${id}`);
 };
 
+/** 백테스트 승격 요청 */
+export interface PromoteBacktestRequest {
+  name?: string;
+  override_params?: Record<string, unknown>;
+  risk_profile?: 'conservative' | 'standard' | 'default' | 'aggressive' | 'custom';
+  risk_config?: Record<string, unknown>;
+  allocated_capital?: number;
+  max_drawdown_pct?: number;
+  min_trades?: number;
+  min_coverage_pct?: number;
+  /** 검사만 수행 (전략 미등록) */
+  dry_run?: boolean;
+}
+
+/** 승격 검사 결과 */
+export interface PromotionCheck {
+  name: string;
+  passed: boolean;
+  /** 실패 시 승격 차단 여부 (false면 경고) */
+  blocking: boolean;
+  detail: string;
+}
+
+/** 백테스트 승격 응답 */
+export interface PromoteBacktestResponse {
+  backtest_result_id: string;
+  promoted: boolean;
+  dry_run: boolean;
+  strategy_id: string | null;
+  name: string;
+  strategy_type: string;
+  symbols: string[];
+  timeframe: string;
+  parameters: Record<string, unknown>;
+  parameter_source: 'backtest' | 'strategy' | 'default';
+  checks: PromotionCheck[];
+  message: string;
+}
+
+/** 백테스트 결과를 라이브 전략으로 승격 (차단 검사 실패 시 422) */
+export const promoteBacktestResult = async (id: string, request: PromoteBacktestRequest = {}): Promise<PromoteBacktestResponse> => {
+  const response = await api.post(`/backtest/results/${id}/promote`, request);
+  return response.data;
+};
+
 /** 저장된 백테스트 결과 목록 조회 (쿼리 파라미터 지원) */
 export const listBacktestResults = async (query?: ListBacktestResultsQuery): Promise<{ results: BacktestResult[]; total: number }> => {
   const response = await api.get('/backtest/results', { params: query });
   return response.data;
 };
 
+/** 몬테카를로 분포 요약 (평균 및 백분위) */
+export interface MonteCarloDistribution {
+  mean: number;
+  p5: number;
+  p25: number;
+  p50: number;
+  p75: number;
+  p95: number;
+}
+
+export interface MonteCarloRequest {
+  result_id: string;
+  method?: 'trades' | 'daily_returns';
+  iterations?: number;
+  ruin_threshold_pct?: number;
+  seed?: number;
+}
+
+export interface MonteCarloResponse {
+  result_id: string;
+  strategy_id: string;
+  symbol: string;
+  initial_capital: number;
+  original_final_equity?: number;
+  method: 'trades' | 'daily_returns';
+  iterations: number;
+  sample_size: number;
+  cagr_pct: MonteCarloDistribution;
+  max_drawdown_pct: MonteCarloDistribution;
+  final_equity: MonteCarloDistribution;
+  probability_of_loss_pct: number;
+  ruin_threshold_pct: number;
+  risk_of_ruin_pct: number;
+}
+
+/** 저장된 백테스트 결과 몬테카를로 시뮬레이션 (CAGR/MDD 신뢰구간, 파산 확률) */
+export const runBacktestMonteCarlo = async (request: MonteCarloRequest): Promise<MonteCarloResponse> => {
+  const response = await api.post('/backtest/monte-carlo', request);
+  return response.data;
+};
+
+/** 수수료/슬리피지 조합별 재실행 성과 */
+export interface CostSensitivityPoint {
+  commission_rate: number;
+  slippage_rate: number;
+  /** 편도 비용 (bp) */
+  cost_bps: number;
+  total_return_pct: number;
+  annualized_return_pct: number;
+  sharpe_ratio: number;
+  max_drawdown_pct: number;
+  profit_factor: number;
+  total_trades: number;
+}
+
+export interface CostSensitivityQuery {
+  /** 콤마 구분 수수료율 (예: "0,0.0005,0.001") */
+  commission_rates?: string;
+  /** 콤마 구분 슬리피지율 */
+  slippage_rates?: string;
+  refresh?: boolean;
+}
+
+export interface CostSensitivityResponse {
+  result_id: string;
+  strategy_id: string;
+  symbol: string;
+  cached: boolean;
+  baseline_commission_rate: number;
+  baseline_slippage_rate: number;
+  baseline_cost_bps: number;
+  points: CostSensitivityPoint[];
+  /** 편도 비용 1bp당 총 수익률 변화 (%p) */
+  return_change_per_bp?: number | null;
+  /** 총 수익률이 0이 되는 편도 비용 (bp) */
+  breakeven_cost_bps?: number | null;
+  cost_headroom_bps?: number | null;
+  profitable_pct: number;
+}
+
+/** 저장된 백테스트 결과 수수료/슬리피지 민감도 분석 (처음 호출 시 격자 재실행) */
+export const getBacktestCostSensitivity = async (id: string, query?: CostSensitivityQuery): Promise<CostSensitivityResponse> => {
+  const response = await api.get(`/backtest/results/${id}/sensitivity`, { params: query });
+  return response.data;
+};
+
 // ==================== 시뮬레이션 ====================
 
 /** 시뮬레이션 상태 enum */
@@ -1641,6 +1922,18 @@ export const clearJournalCache = async (): Promise<ClearCacheResponse> => {
   return response.data;
 };
 
+/** 매매일지 내보내기 (체결 내역 또는 보유 포지션 파일) */
+export const exportJournal = async (
+  target: 'executions' | 'positions',
+  query: Partial<JournalExportQuery> = {}
+): Promise<Blob> => {
+  const response = await api.get(`/journal/export/${target}`, {
+    params: query,
+    responseType: 'blob',
+  });
+  return response.data;
+};
+
 // ==================== 기간별 손익 API ====================
 
 /** 주별 손익 항목 */
@@ -1959,6 +2252,75 @@ export const findWatchlistsContainingSymbol = async (symbol: string, market: str
   return response.data;
 };
 
+/** 관심종목 알림 규칙 유형 */
+export type WatchlistAlertRule = 'price_above' | 'price_below' | 'pct_move' | 'rsi_above' | 'rsi_below';
+
+/** 관심종목 알림 규칙 */
+export interface WatchlistAlert {
+  id: string;
+  watchlist_id: string;
+  watchlist_name: string;
+  item_id: string;
+  symbol: string;
+  market: string;
+  owner_id: string | null;
+  rule_type: WatchlistAlertRule;
+  threshold: number;
+  timeframe: string;
+  rsi_period: number;
+  cooldown_minutes: number;
+  enabled: boolean;
+  last_value: number | null;
+  reference_price: number | null;
+  last_evaluated_at: string | null;
+  last_error: string | null;
+  last_triggered_at: string | null;
+  trigger_count: number;
+  created_at: string;
+  updated_at: string;
+}
+
+/** 새 관심종목 알림 규칙 */
+export interface NewWatchlistAlert {
+  symbol: string;
+  market?: string;
+  rule: WatchlistAlertRule;
+  threshold: number;
+  timeframe?: string;
+  rsi_period?: number;
+  cooldown_minutes?: number;
+}
+
+/** 관심종목 알림 규칙 목록 응답 */
+export interface WatchlistAlertListResponse {
+  alerts: WatchlistAlert[];
+  total: number;
+}
+
+/** 관심종목 알림 규칙 조회 */
+export const getWatchlistAlerts = async (watchlistId: string): Promise<WatchlistAlertListResponse> => {
+  const response = await api.get(`/watchlist/${watchlistId}/alerts`);
+  return response.data;
+};
+
+/** 관심종목 알림 규칙 일괄 생성 */
+export const createWatchlistAlerts = async (watchlistId: string, alerts: NewWatchlistAlert[]): Promise<WatchlistAlertListResponse> => {
+  const response = await api.post(`/watchlist/${watchlistId}/alerts`, { alerts });
+  return response.data;
+};
+
+/** 관심종목 알림 규칙 일괄 활성화/비활성화 (ids 생략 시 전체) */
+export const setWatchlistAlertsEnabled = async (watchlistId: string, enabled: boolean, ids: string[] = []): Promise<{ affected: number }> => {
+  const response = await api.patch(`/watchlist/${watchlistId}/alerts`, { ids, enabled });
+  return response.data;
+};
+
+/** 관심종목 알림 규칙 일괄 삭제 */
+export const deleteWatchlistAlerts = async (watchlistId: string, ids: string[]): Promise<{ affected: number }> => {
+  const response = await api.delete(`/watchlist/${watchlistId}/alerts`, { data: { ids } });
+  return response.data;
+};
+
 // ==================== 스크리닝 (Screening) ====================
 // 타입은 types/generated/screening에서 import됨
 
@@ -2198,7 +2560,7 @@ export interface SignalSearchResponse {
 /** 백테스트 신호 응답 */
 export interface BacktestSignalsResponse {
   backtest_id: string;
-  strategy_id: string;
+  strategy_id: string | null;
   strategy_type: string;
   symbol: string;
   total_trades: number;
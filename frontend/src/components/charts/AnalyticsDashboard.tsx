@@ -347,7 +347,7 @@ export function AnalyticsDashboard(props: AnalyticsDashboardProps) {
                 <For each={backtestResults()}>
                   {(result: BacktestResult) => (
                     <option value={result.id}>
-                      {result.strategy_id} - {result.symbol}
+                      {result.strategy_id ?? result.strategy_type} - {result.symbol}
                     </option>
                   )}
                 </For>
@@ -569,7 +569,7 @@ export function AnalyticsDashboard(props: AnalyticsDashboardProps) {
         }`} />
         {dataSource() === 'portfolio'
           ? `실제 포트폴리오 데이터 (${equityChartData().length}개 포인트)`
-          : `백테스트 시뮬레이션: ${selectedBacktest()?.strategy_id} - ${selectedBacktest()?.symbol}`
+          : `백테스트 시뮬레이션: ${selectedBacktest()?.strategy_id ?? selectedBacktest()?.strategy_type} - ${selectedBacktest()?.symbol}`
         }
       </div>
     </div>
@@ -320,7 +320,7 @@ export function PortfolioEquityChart(props: PortfolioEquityChartProps) {
                 <For each={backtestResults()}>
                   {(result: BacktestResult) => (
                     <option value={result.id}>
-                      {result.strategy_id} - {result.symbol} ({result.start_date} ~ {result.end_date})
+                      {result.strategy_id ?? result.strategy_type} - {result.symbol} ({result.start_date} ~ {result.end_date})
                     </option>
                   )}
                 </For>
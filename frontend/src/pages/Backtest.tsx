@@ -1077,6 +1077,7 @@ export function Backtest() {
           initial_capital: parseInt(form.initialCapital, 10),
           slippage_rate: slippage,
           multi_timeframe_config: multiTfConfigToUse,
+          registered_strategy_id: form.selectedStrategy,
         }
 
         const result = await runMultiBacktest(request)
@@ -1097,13 +1098,24 @@ export function Backtest() {
           initial_capital: parseInt(form.initialCapital, 10),
           slippage_rate: slippage,
           multi_timeframe_config: multiTfConfigToUse,
+          registered_strategy_id: form.selectedStrategy,
         }
 
         resultToSave = await runBacktest(request)
         symbolStr = symbols[0]
       }
 
-      // DB에 결과 저장
+      // 실행 시 서버에서 이미 저장된 경우 반환된 ID 사용
+      if (resultToSave.persisted) {
+        const storedResult: StoredBacktestResult = {
+          ...resultToSave,
+          dbId: resultToSave.id,
+        }
+        setResults('items', items => [storedResult, ...items])
+        return
+      }
+
+      // DB에 결과 저장 (서버 저장이 건너뛰어진 경우)
       try {
         const saveResponse = await saveBacktestResult({
           strategy_id: form.selectedStrategy,  // 등록된 전략 ID
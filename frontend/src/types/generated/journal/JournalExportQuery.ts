@@ -0,0 +1,30 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * 내보내기 쿼리 파라미터.
+ */
+export type JournalExportQuery = { 
+/**
+ * 형식 (csv, ofx, kr_broker, kr_ledger; 기본 csv)
+ */
+format: string | null, 
+/**
+ * 표시 통화 (native, KRW, USD; 기본 native)
+ */
+currency: string | null, 
+/**
+ * 고정 USD/KRW 환율 (미지정 시 체결일 USDKRW 종가)
+ */
+fx_rate: string | null, 
+/**
+ * 시작 날짜 (YYYY-MM-DD, 한국 시간 기준, 포함)
+ */
+start_date: string | null, 
+/**
+ * 종료 날짜 (YYYY-MM-DD, 한국 시간 기준, 포함)
+ */
+end_date: string | null, 
+/**
+ * 종목 필터 (체결 내역만)
+ */
+symbol: string | null, };
@@ -5,6 +5,7 @@ export type { DailyPnLQuery } from './DailyPnLQuery';
 export type { DailyPnLResponse } from './DailyPnLResponse';
 export type { ExecutionResponse } from './ExecutionResponse';
 export type { ExecutionsListResponse } from './ExecutionsListResponse';
+export type { JournalExportQuery } from './JournalExportQuery';
 export type { JournalPositionResponse } from './JournalPositionResponse';
 export type { JournalPositionsResponse } from './JournalPositionsResponse';
 export type { ListExecutionsQuery } from './ListExecutionsQuery';
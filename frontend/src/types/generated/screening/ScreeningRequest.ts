@@ -15,4 +15,4 @@ exchange: string | null,
 /**
  * 섹터 필터
  */
-sector: string | null, min_market_cap: string | null, max_market_cap: string | null, min_per: string | null, max_per: string | null, min_pbr: string | null, max_pbr: string | null, min_roe: string | null, max_roe: string | null, min_roa: string | null, max_roa: string | null, min_dividend_yield: string | null, max_dividend_yield: string | null, max_debt_ratio: string | null, min_revenue_growth: string | null, min_earnings_growth: string | null, max_distance_from_52w_high: string | null, min_distance_from_52w_low: string | null, min_volume_ratio: string | null, min_low_trend: string | null, min_vol_quality: string | null, min_breakout_score: string | null, only_alive_consolidation: boolean | null, filter_route_state: string | null, filter_ttm_squeeze: boolean | null, min_ttm_squeeze_cnt: string | null, sort_by: string | null, sort_order: string | null, limit: number | null, offset: number | null, };
+sector: string | null, min_market_cap: string | null, max_market_cap: string | null, min_per: string | null, max_per: string | null, min_pbr: string | null, max_pbr: string | null, min_roe: string | null, max_roe: string | null, min_roa: string | null, max_roa: string | null, min_dividend_yield: string | null, max_dividend_yield: string | null, max_debt_ratio: string | null, min_revenue_growth: string | null, min_earnings_growth: string | null, max_distance_from_52w_high: string | null, min_distance_from_52w_low: string | null, min_volume_ratio: string | null, min_low_trend: string | null, min_vol_quality: string | null, min_breakout_score: string | null, only_alive_consolidation: boolean | null, filter_route_state: string | null, filter_ttm_squeeze: boolean | null, min_ttm_squeeze_cnt: string | null, min_realized_vol: string | null, max_realized_vol: string | null, min_beta: string | null, max_beta: string | null, sort_by: string | null, sort_order: string | null, limit: number | null, offset: number | null, };
@@ -19,4 +19,4 @@ macd_histogram: number | null,
 /**
  * 크로스 상태 ("golden" = 골든크로스, "dead" = 데드크로스, null = 없음)
  */
-macd_cross: string | null, route_state: string | null, regime: string | null, sector_rs: string | null, sector_rank: number | null, ttm_squeeze: boolean | null, ttm_squeeze_cnt: number | null, trigger_score: number | null, trigger_label: string | null, overall_score: string | null, grade: string | null, confidence: string | null, };
+macd_cross: string | null, route_state: string | null, regime: string | null, sector_rs: string | null, sector_rank: number | null, ttm_squeeze: boolean | null, ttm_squeeze_cnt: number | null, realized_vol_20d: number | null, realized_vol_60d: number | null, realized_vol_252d: number | null, beta: number | null, trigger_score: number | null, trigger_label: string | null, overall_score: string | null, grade: string | null, confidence: string | null, };
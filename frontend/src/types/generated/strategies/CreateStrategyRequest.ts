@@ -25,7 +25,8 @@ risk_config: Record<string, unknown> | null,
  */
 allocated_capital: number | null, 
 /**
- * 리스크 프로필 (conservative, default, aggressive, custom)
+ * 리스크 프로필 (conservative, standard, aggressive: 기본 프로필 적용,
+ * default: 전역 설정 사용, custom: 직접 설정)
  */
 risk_profile: string | null, 
 /**
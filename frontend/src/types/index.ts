@@ -67,12 +67,23 @@ export interface PortfolioSummary {
   marginUsed: number;
 }
 
+export interface BrokerDowntime {
+  id: string;
+  broker: string;
+  source: 'scheduled' | 'detected';
+  starts_at: string;
+  ends_at: string | null;
+  reason: string;
+}
+
 export interface MarketStatus {
   market: 'KR' | 'US';
   isOpen: boolean;
   nextOpen?: string;
   nextClose?: string;
   session?: 'Regular' | 'PreMarket' | 'AfterHours';
+  brokerAvailable?: boolean;
+  brokerDowntime?: BrokerDowntime;
 }
 
 // WebSocket message types
@@ -84,6 +95,7 @@ export type WsMessage =
   | WsAuthResult
   | WsError
   | WsTicker
+  | WsOrderBookMetrics
   | WsOrderUpdate
   | WsPositionUpdate
   | WsStrategyUpdate;
@@ -133,6 +145,21 @@ export interface WsTicker {
   timestamp: number;
 }
 
+/** 호가창 파생 지표 (상위 5레벨 기준) */
+export interface WsOrderBookMetrics {
+  type: 'order_book_metrics';
+  symbol: string;
+  depth_levels: number;
+  mid_price: number;
+  microprice: number;
+  imbalance: number;  // -1 ~ +1, 양수 = 매수 우위
+  bid_depth: number;
+  ask_depth: number;
+  depth_weighted_spread: number;
+  depth_weighted_spread_bps: number;
+  timestamp: number;
+}
+
 export interface WsOrderUpdate {
   type: 'order_update';
   order_id: string;
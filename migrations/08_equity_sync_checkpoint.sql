@@ -0,0 +1,40 @@
+-- =====================================================
+-- 08_equity_sync_checkpoint.sql
+-- 자산 곡선 증분 동기화 체크포인트
+-- =====================================================
+--
+-- 자산 곡선 동기화(sync-equity)를 증분 방식으로 수행하기 위한
+-- 계좌별 체크포인트를 저장합니다.
+--
+-- - synced_through: 자산 곡선이 확정된 마지막 일자
+-- - 새 체결이 synced_through 이전 일자로 들어오면 (소급 체결)
+--   해당 일자부터만 재계산합니다.
+-- - 동기화 도중 실패하면 체크포인트는 갱신되지 않으므로
+--   다음 실행 시 같은 지점부터 재개됩니다.
+--
+-- =====================================================
+
+CREATE TABLE IF NOT EXISTS equity_sync_checkpoint (
+    credential_id UUID PRIMARY KEY REFERENCES exchange_credentials(id) ON DELETE CASCADE,
+
+    -- 계산 방식 (market_prices, cash_flow) - 방식이 바뀌면 전체 재계산
+    sync_mode VARCHAR(20) NOT NULL,
+
+    -- 체크포인트
+    synced_through DATE NOT NULL,                   -- 자산 곡선이 확정된 마지막 일자
+    execution_count INT NOT NULL DEFAULT 0,         -- 체크포인트 시점의 체결 수
+    last_execution_at TIMESTAMPTZ,                  -- 체크포인트 시점의 마지막 체결 시각
+
+    -- 마지막 동기화 결과
+    last_recompute_from DATE,                       -- 마지막 재계산 시작일
+    last_sync_status VARCHAR(20) NOT NULL DEFAULT 'success',  -- success, failed
+    last_sync_message TEXT,
+
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+COMMENT ON TABLE equity_sync_checkpoint IS '자산 곡선 증분 동기화 체크포인트 (계좌별)';
+COMMENT ON COLUMN equity_sync_checkpoint.synced_through IS '자산 곡선이 확정된 마지막 일자 - 다음 동기화의 재계산 기준';
+COMMENT ON COLUMN equity_sync_checkpoint.sync_mode IS '계산 방식: market_prices(종가 기반), cash_flow(현금 흐름 기반)';
+COMMENT ON COLUMN equity_sync_checkpoint.last_recompute_from IS '마지막 동기화에서 재계산을 시작한 일자 (소급 체결 추적용)';
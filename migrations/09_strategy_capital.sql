@@ -0,0 +1,36 @@
+-- =====================================================
+-- 09_strategy_capital.sql
+-- 전략별 자본 원장 (입출금/이체 이력)
+-- =====================================================
+--
+-- 전략별 할당 자본은 strategies.allocated_capital에 저장되며,
+-- 이 파일은 할당 자본의 변경 이력(입금, 출금, 전략 간 이체)을 기록합니다.
+--
+-- - deposit:  외부 → 전략 (to_strategy만 존재)
+-- - withdraw: 전략 → 외부 (from_strategy만 존재)
+-- - transfer: 전략 → 전략 (양쪽 모두 존재)
+--
+-- =====================================================
+
+CREATE TABLE IF NOT EXISTS strategy_capital_transfer (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+
+    kind VARCHAR(20) NOT NULL,                      -- deposit, withdraw, transfer
+    from_strategy VARCHAR(100) REFERENCES strategies(id) ON DELETE SET NULL,
+    to_strategy VARCHAR(100) REFERENCES strategies(id) ON DELETE SET NULL,
+    amount DECIMAL(30, 15) NOT NULL CHECK (amount > 0),
+    memo TEXT,
+
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT chk_capital_transfer_kind CHECK (kind IN ('deposit', 'withdraw', 'transfer'))
+);
+
+CREATE INDEX IF NOT EXISTS idx_capital_transfer_from
+    ON strategy_capital_transfer(from_strategy, created_at DESC);
+CREATE INDEX IF NOT EXISTS idx_capital_transfer_to
+    ON strategy_capital_transfer(to_strategy, created_at DESC);
+
+COMMENT ON TABLE strategy_capital_transfer IS '전략 할당 자본 변경 이력 (입금/출금/전략 간 이체)';
+COMMENT ON COLUMN strategy_capital_transfer.kind IS '이동 유형: deposit(증액), withdraw(감액), transfer(전략 간 이체)';
+COMMENT ON COLUMN strategy_capital_transfer.amount IS '이동 금액 (항상 양수)';
@@ -0,0 +1,54 @@
+-- =====================================================
+-- 10_outbound_webhooks.sql
+-- 아웃바운드 웹훅 (체결/포지션/전략 이벤트 외부 전송)
+-- =====================================================
+--
+-- outbound_webhook:          수신 URL, 서명 키, 이벤트 필터
+-- outbound_webhook_delivery: 전송 결과 로그 (재시도 횟수, 응답 코드, 오류)
+--
+-- 이벤트 필터(events)가 비어 있으면 모든 이벤트를 전송합니다.
+-- `order.*` 처럼 접두사 패턴을 사용할 수 있습니다.
+--
+-- =====================================================
+
+CREATE TABLE IF NOT EXISTS outbound_webhook (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+
+    name VARCHAR(100) NOT NULL UNIQUE,
+    url TEXT NOT NULL,
+    secret TEXT,                                    -- HMAC-SHA256 서명 키 (선택)
+    events TEXT[] NOT NULL DEFAULT '{}',            -- 예: {order.filled, position.*}
+    enabled BOOLEAN NOT NULL DEFAULT TRUE,
+
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE TRIGGER update_outbound_webhook_updated_at BEFORE UPDATE ON outbound_webhook
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
+
+CREATE TABLE IF NOT EXISTS outbound_webhook_delivery (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+
+    webhook_id UUID NOT NULL REFERENCES outbound_webhook(id) ON DELETE CASCADE,
+    event_id VARCHAR(64) NOT NULL,
+    event_type VARCHAR(100) NOT NULL,
+    payload JSONB NOT NULL,
+
+    success BOOLEAN NOT NULL,
+    attempts INTEGER NOT NULL,
+    status_code INTEGER,
+    error TEXT,
+    duration_ms BIGINT NOT NULL DEFAULT 0,
+
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_webhook_delivery_webhook
+    ON outbound_webhook_delivery(webhook_id, created_at DESC);
+CREATE INDEX IF NOT EXISTS idx_webhook_delivery_failed
+    ON outbound_webhook_delivery(created_at DESC) WHERE NOT success;
+
+COMMENT ON TABLE outbound_webhook IS '아웃바운드 웹훅 설정 (체결/포지션/전략 이벤트 외부 전송)';
+COMMENT ON COLUMN outbound_webhook.events IS '구독 이벤트 필터 (비어 있으면 전체, order.* 형식 접두사 패턴 지원)';
+COMMENT ON TABLE outbound_webhook_delivery IS '웹훅 전송 로그 (재시도 포함 최종 결과)';
@@ -0,0 +1,38 @@
+-- =====================================================
+-- 11_macro_series.sql
+-- 매크로 시계열 (암호화폐 온체인/파생상품 지표 등)
+-- =====================================================
+--
+-- macro_series: 시리즈 키별 관측값 (시리즈 키 + 관측 시각 기준 upsert)
+--
+-- 시리즈 키 형식: `{도메인}.{자산}.{지표}`
+--   - crypto.BTC.exchange_netflow   거래소 순유입량 (BTC)
+--   - crypto.ALL.stablecoin_supply  스테이블코인 총 공급량 (USD)
+--   - crypto.ETH.perp_basis_pct     무기한 선물 베이시스 (%)
+--   - crypto.ETH.funding_rate_pct   펀딩비 (%)
+--
+-- =====================================================
+
+CREATE TABLE IF NOT EXISTS macro_series (
+    series_key VARCHAR(100) NOT NULL,
+    observed_at TIMESTAMPTZ NOT NULL,
+
+    value DOUBLE PRECISION NOT NULL,
+    source VARCHAR(50) NOT NULL,                    -- 수집 모듈 (예: crypto_metrics)
+
+    fetched_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    PRIMARY KEY (series_key, observed_at)
+);
+
+-- TimescaleDB Hypertable 변환 (1개월 단위 청크)
+SELECT create_hypertable('macro_series', 'observed_at',
+    chunk_time_interval => INTERVAL '1 month',
+    if_not_exists => TRUE
+);
+
+CREATE INDEX IF NOT EXISTS idx_macro_series_key_time
+    ON macro_series(series_key, observed_at DESC);
+
+COMMENT ON TABLE macro_series IS '매크로 시계열 관측값 (암호화폐 온체인/파생상품 지표 등)';
+COMMENT ON COLUMN macro_series.series_key IS '시리즈 키 ({도메인}.{자산}.{지표}, 예: crypto.BTC.perp_basis_pct)';
@@ -0,0 +1,32 @@
+-- =====================================================
+-- 12_investor_flow.sql
+-- 국내 주식 투자자별 순매수 (외국인/기관/개인)
+-- =====================================================
+--
+-- symbol_investor_flow: 종목별 일별 투자자 유형 순매수 대금 (KRX 정보데이터시스템)
+--
+-- 순매수 대금 = 매수 거래대금 - 매도 거래대금 (원, 양수 = 순매수)
+--
+-- =====================================================
+
+CREATE TABLE IF NOT EXISTS symbol_investor_flow (
+    ticker VARCHAR(20) NOT NULL,                    -- 종목코드 (6자리)
+    trade_date DATE NOT NULL,
+
+    foreign_net DECIMAL(20, 0) NOT NULL DEFAULT 0,            -- 외국인 순매수 대금
+    institution_net DECIMAL(20, 0) NOT NULL DEFAULT 0,        -- 기관 합계 순매수 대금
+    individual_net DECIMAL(20, 0) NOT NULL DEFAULT 0,         -- 개인 순매수 대금
+    other_corporation_net DECIMAL(20, 0) NOT NULL DEFAULT 0,  -- 기타법인 순매수 대금
+
+    source VARCHAR(20) NOT NULL DEFAULT 'KRX',
+    fetched_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    PRIMARY KEY (ticker, trade_date)
+);
+
+CREATE INDEX IF NOT EXISTS idx_symbol_investor_flow_date
+    ON symbol_investor_flow(trade_date DESC);
+
+COMMENT ON TABLE symbol_investor_flow IS '종목별 일별 투자자 유형 순매수 대금 (외국인/기관/개인/기타법인)';
+COMMENT ON COLUMN symbol_investor_flow.foreign_net IS '외국인 순매수 대금 (원, 양수 = 순매수)';
+COMMENT ON COLUMN symbol_investor_flow.institution_net IS '기관 합계 순매수 대금 (원, 양수 = 순매수)';
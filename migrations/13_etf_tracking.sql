@@ -0,0 +1,49 @@
+-- =====================================================
+-- 13_etf_tracking.sql
+-- 국내 ETF NAV 괴리율 및 구성종목(PDF)
+-- =====================================================
+--
+-- etf_nav_history: ETF 일별 종가/NAV/괴리율 (KRX 정보데이터시스템)
+-- etf_constituent: ETF 납입자산구성내역 (기준일별 스냅샷)
+--
+-- 괴리율 = (종가 / NAV - 1) × 100 (양수 = 프리미엄)
+--
+-- =====================================================
+
+CREATE TABLE IF NOT EXISTS etf_nav_history (
+    ticker VARCHAR(20) NOT NULL,                    -- ETF 종목코드
+    trade_date DATE NOT NULL,
+
+    close_price DECIMAL(20, 4) NOT NULL,
+    nav DECIMAL(20, 4) NOT NULL,
+    premium_pct DOUBLE PRECISION NOT NULL,          -- 괴리율 (%)
+
+    fetched_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    PRIMARY KEY (ticker, trade_date)
+);
+
+CREATE INDEX IF NOT EXISTS idx_etf_nav_history_date
+    ON etf_nav_history(trade_date DESC);
+
+CREATE TABLE IF NOT EXISTS etf_constituent (
+    etf_ticker VARCHAR(20) NOT NULL,
+    as_of_date DATE NOT NULL,
+    constituent_ticker VARCHAR(20) NOT NULL,        -- 구성종목 코드 (현금 등은 KRX 코드)
+
+    constituent_name VARCHAR(200) NOT NULL,
+    shares DECIMAL(24, 4) NOT NULL DEFAULT 0,       -- CU당 구성 수량
+    value_amount DECIMAL(24, 2) NOT NULL DEFAULT 0, -- 평가금액
+    weight_pct DECIMAL(10, 4),                      -- 구성 비중 (%)
+
+    fetched_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    PRIMARY KEY (etf_ticker, as_of_date, constituent_ticker)
+);
+
+CREATE INDEX IF NOT EXISTS idx_etf_constituent_ticker
+    ON etf_constituent(constituent_ticker, as_of_date DESC);
+
+COMMENT ON TABLE etf_nav_history IS 'ETF 일별 종가/NAV 및 괴리율 (리스크 프리미엄 필터용)';
+COMMENT ON COLUMN etf_nav_history.premium_pct IS '괴리율 (%, 종가 / NAV - 1, 양수 = 프리미엄)';
+COMMENT ON TABLE etf_constituent IS 'ETF 구성종목 (PDF, 기준일별 스냅샷)';
@@ -0,0 +1,47 @@
+-- =====================================================
+-- 14_backtest_templates.sql
+-- 백테스트 템플릿 (이름 붙은 백테스트 설정)
+-- =====================================================
+--
+-- backtest_template: 전략, 파라미터, 유니버스, 기간, 비용 설정
+--
+-- 템플릿 ID로 단건 실행하거나 배치 백테스트(run-batch)에서 참조합니다.
+-- end_date가 NULL이면 실행일까지의 최신 데이터로 백테스트합니다.
+--
+-- =====================================================
+
+CREATE TABLE IF NOT EXISTS backtest_template (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+
+    name VARCHAR(100) NOT NULL UNIQUE,
+    description TEXT,
+
+    -- 전략/유니버스
+    strategy_id VARCHAR(100) NOT NULL,
+    symbols TEXT[] NOT NULL,                        -- 1개면 단일 자산, 2개 이상이면 다중 자산
+    parameters JSONB,
+
+    -- 기간
+    start_date DATE NOT NULL,
+    end_date DATE,                                  -- NULL이면 실행일
+
+    -- 자본/비용
+    initial_capital DECIMAL(20, 2) NOT NULL,
+    commission_rate DECIMAL(10, 6),                 -- NULL이면 기본값 (0.1%)
+    slippage_rate DECIMAL(10, 6),                   -- NULL이면 기본값 (0.05%)
+    cash_yield_series VARCHAR(20),                  -- 유휴 현금 금리 (KR_CD_91D, US_TBILL_3M 등)
+
+    last_run_at TIMESTAMPTZ,
+
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_backtest_template_strategy
+    ON backtest_template(strategy_id);
+
+CREATE TRIGGER update_backtest_template_updated_at BEFORE UPDATE ON backtest_template
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
+
+COMMENT ON TABLE backtest_template IS '백테스트 템플릿 (재실행 가능한 이름 붙은 백테스트 설정)';
+COMMENT ON COLUMN backtest_template.end_date IS '종료일 (NULL이면 실행일까지)';
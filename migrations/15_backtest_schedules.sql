@@ -0,0 +1,48 @@
+-- =====================================================
+-- 15_backtest_schedules.sql
+-- 백테스트 템플릿 정기 실행 및 결과 시계열
+-- =====================================================
+--
+-- backtest_template:     정기 실행 주기(schedule_interval_days)와 다음 실행 시각
+-- backtest_template_run: 정기 실행 결과 (템플릿별 성과 지표 시계열)
+--
+-- 스케줄러는 next_run_at이 지난 템플릿을 최신 데이터로 재실행하고,
+-- 과거 실행 대비 성과가 크게 악화되면(최대 낙폭 신기록 등) 알림을 보냅니다.
+--
+-- =====================================================
+
+ALTER TABLE backtest_template
+    ADD COLUMN IF NOT EXISTS schedule_interval_days INTEGER,   -- NULL이면 정기 실행 안 함 (예: 7 = 매주)
+    ADD COLUMN IF NOT EXISTS next_run_at TIMESTAMPTZ;
+
+CREATE INDEX IF NOT EXISTS idx_backtest_template_next_run
+    ON backtest_template(next_run_at) WHERE schedule_interval_days IS NOT NULL;
+
+CREATE TABLE IF NOT EXISTS backtest_template_run (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+
+    template_id UUID NOT NULL REFERENCES backtest_template(id) ON DELETE CASCADE,
+    start_date DATE NOT NULL,
+    end_date DATE NOT NULL,
+
+    success BOOLEAN NOT NULL,
+    error TEXT,
+
+    -- 주요 지표 (비교용)
+    total_return_pct DECIMAL(20, 6),
+    max_drawdown_pct DECIMAL(20, 6),
+    sharpe_ratio DECIMAL(20, 6),
+    total_trades INTEGER,
+    metrics JSONB,                                  -- 전체 성과 지표
+
+    regressions TEXT[] NOT NULL DEFAULT '{}',       -- 이전 실행 대비 악화 항목
+
+    run_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_backtest_template_run_template
+    ON backtest_template_run(template_id, run_at DESC);
+
+COMMENT ON COLUMN backtest_template.schedule_interval_days IS '정기 실행 주기 (일, NULL이면 비활성)';
+COMMENT ON TABLE backtest_template_run IS '백테스트 템플릿 정기 실행 결과 (성과 지표 시계열)';
+COMMENT ON COLUMN backtest_template_run.regressions IS '이전 실행 대비 성과 악화 항목 (알림 발송 사유)';
@@ -0,0 +1,21 @@
+-- =====================================================
+-- 16_strategy_cost_model.sql
+-- 전략별 거래 비용 모델
+-- =====================================================
+--
+-- strategies.cost_model: 시장별 거래 비용 모델 (JSON)
+--   {"type": "kr_stock"}                      국내 주식 (수수료 + 증권거래세 + 농특세)
+--   {"type": "us_stock"}                      미국 주식 (수수료 + SEC fee)
+--   {"type": "crypto", "tiers": [...]}        암호화폐 (30일 거래대금 구간별 maker/taker)
+--   {"type": "flat", "commission_rate": ...}  단일 수수료율
+--
+-- 실거래 체결 시 실행기가 이 모델로 수수료/세금을 계산해
+-- 포지션 실현 손익과 전략 자본 원장에서 차감합니다.
+-- NULL이면 서버 기본 모델(DEFAULT_COST_MODEL)을 사용합니다.
+--
+-- =====================================================
+
+ALTER TABLE strategies
+    ADD COLUMN IF NOT EXISTS cost_model JSONB;
+
+COMMENT ON COLUMN strategies.cost_model IS '거래 비용 모델 (NULL이면 서버 기본 모델)';
@@ -0,0 +1,57 @@
+-- =====================================================
+-- 17_conditional_orders.sql
+-- 서버 측 조건부 주문
+-- =====================================================
+--
+-- conditional_order: 조건 충족 시 실행할 주문과 조건 목록
+--
+-- 조건부 주문 서비스가 active 주문의 조건을 최신 캔들로 주기적으로 평가하고,
+-- 모든 조건이 충족되면 주문 실행기로 주문을 제출합니다.
+--   conditions 예시:
+--   [{"type": "price", "operator": "cross_above", "value": 80000},
+--    {"type": "indicator", "indicator": {"name": "rsi", "period": 14},
+--     "operator": "lt", "value": 60}]
+-- expires_at이 지난 active 주문은 expired로 전환됩니다.
+--
+-- =====================================================
+
+CREATE TABLE IF NOT EXISTS conditional_order (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+
+    -- 주문
+    ticker VARCHAR(50) NOT NULL,
+    market VARCHAR(20) NOT NULL,                    -- KR, US, CRYPTO
+    side VARCHAR(10) NOT NULL,                      -- buy, sell
+    order_type VARCHAR(20) NOT NULL,                -- market, limit
+    quantity DECIMAL(30, 15) NOT NULL,
+    limit_price DECIMAL(30, 15),                    -- 지정가 주문 가격
+    strategy_id VARCHAR(100),                       -- 전략 예산/비용 모델 적용 대상
+
+    -- 조건 (모두 충족 시 실행)
+    conditions JSONB NOT NULL,
+    timeframe VARCHAR(10) NOT NULL DEFAULT '1d',    -- 조건 평가 캔들 타임프레임
+
+    -- 상태
+    status VARCHAR(20) NOT NULL DEFAULT 'active',   -- active, triggered, failed, expired, cancelled
+    expires_at TIMESTAMPTZ,                         -- NULL이면 만료 없음
+    last_evaluated_at TIMESTAMPTZ,
+    last_error TEXT,
+    triggered_at TIMESTAMPTZ,
+    order_id UUID,                                  -- 실행된 내부 주문 ID
+
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_conditional_order_active
+    ON conditional_order(expires_at) WHERE status = 'active';
+
+CREATE INDEX IF NOT EXISTS idx_conditional_order_ticker
+    ON conditional_order(ticker, created_at DESC);
+
+CREATE TRIGGER update_conditional_order_updated_at BEFORE UPDATE ON conditional_order
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
+
+COMMENT ON TABLE conditional_order IS '서버 측 조건부 주문 (조건 충족 시 자동 실행)';
+COMMENT ON COLUMN conditional_order.conditions IS '실행 조건 목록 (JSON, AND 결합)';
+COMMENT ON COLUMN conditional_order.expires_at IS '만료 시각 (NULL이면 만료 없음)';
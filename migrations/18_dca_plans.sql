@@ -0,0 +1,73 @@
+-- =====================================================
+-- 18_dca_plans.sql
+-- 정액 적립식(DCA) 투자 계획 및 실행 이력
+-- =====================================================
+--
+-- dca_plan:      적립 금액, 통화, 종목 비중, 주기, 금액 결정 방식
+-- dca_execution: 회차별 종목 매수 내역 (매매일지 적립식 리포트 원천)
+--
+-- DCA 스케줄러는 next_run_at이 지난 활성 계획을 실행하여 종목별 주문을
+-- 일반 주문과 같은 리스크/실행 경로로 제출하고 결과를 dca_execution에 기록합니다.
+--   mode 예시:
+--   {"type": "fixed"}
+--   {"type": "value_averaging"}
+--   {"type": "dip_boost", "drawdown_pct": 10, "multiplier": 2}
+--
+-- =====================================================
+
+CREATE TABLE IF NOT EXISTS dca_plan (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+
+    name VARCHAR(100) NOT NULL UNIQUE,
+
+    -- 적립 금액
+    amount DECIMAL(20, 2) NOT NULL,                 -- 회차 총 투자 금액
+    currency VARCHAR(10) NOT NULL,                  -- KRW, USD
+    allocations JSONB NOT NULL,                     -- [{"ticker": "005930", "weight": 1}]
+
+    -- 주기 및 방식
+    cadence VARCHAR(20) NOT NULL,                   -- daily, weekly, biweekly, monthly
+    mode JSONB NOT NULL DEFAULT '{"type": "fixed"}',
+    max_multiplier DECIMAL(10, 4) NOT NULL DEFAULT 3, -- 기본 금액 대비 최대 배수
+    strategy_id VARCHAR(100),                       -- 전략 예산/비용 모델 적용 대상
+
+    enabled BOOLEAN NOT NULL DEFAULT TRUE,
+    next_run_at TIMESTAMPTZ NOT NULL,
+    last_run_at TIMESTAMPTZ,
+
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_dca_plan_next_run
+    ON dca_plan(next_run_at) WHERE enabled;
+
+CREATE TRIGGER update_dca_plan_updated_at BEFORE UPDATE ON dca_plan
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
+
+CREATE TABLE IF NOT EXISTS dca_execution (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+
+    plan_id UUID NOT NULL REFERENCES dca_plan(id) ON DELETE CASCADE,
+    ticker VARCHAR(50) NOT NULL,
+
+    price DECIMAL(30, 15) NOT NULL,                 -- 기준 가격
+    base_amount DECIMAL(20, 2) NOT NULL,            -- 비중 배분 금액
+    amount DECIMAL(20, 2) NOT NULL,                 -- 실제 투자 금액 (방식 반영)
+    multiplier DECIMAL(10, 4) NOT NULL,
+    quantity DECIMAL(30, 15) NOT NULL,
+    reason TEXT,                                    -- 금액 결정 사유
+
+    success BOOLEAN NOT NULL,
+    error TEXT,
+    order_id UUID,                                  -- 내부 주문 ID
+
+    executed_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_dca_execution_plan
+    ON dca_execution(plan_id, executed_at DESC);
+
+COMMENT ON TABLE dca_plan IS '정액 적립식 투자 계획';
+COMMENT ON COLUMN dca_plan.mode IS '금액 결정 방식 (fixed, value_averaging, dip_boost)';
+COMMENT ON TABLE dca_execution IS '정액 적립식 회차별 종목 매수 내역';
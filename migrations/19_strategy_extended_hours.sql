@@ -0,0 +1,16 @@
+-- =====================================================
+-- 19_strategy_extended_hours.sql
+-- 전략별 미국 주식 정규장 외 거래 허용
+-- =====================================================
+--
+-- strategies.extended_hours: 주간거래(KIS 주간 원장), 프리마켓, 애프터마켓 주문 허용 여부
+--
+-- 정규장 외 세션은 호가가 얇아 기본값은 허용하지 않습니다.
+-- 허용된 전략도 실행기에서 지정가 주문만 받고, 진입 수량을 세션별 비율로 축소합니다.
+--
+-- =====================================================
+
+ALTER TABLE strategies
+    ADD COLUMN IF NOT EXISTS extended_hours BOOLEAN NOT NULL DEFAULT FALSE;
+
+COMMENT ON COLUMN strategies.extended_hours IS '미국 주식 정규장 외(주간거래/프리마켓/애프터마켓) 거래 허용';
@@ -0,0 +1,43 @@
+-- =====================================================
+-- 20_earnings_calendar.sql
+-- 실적 발표 일정 및 전략별 실적 발표 필터
+-- =====================================================
+--
+-- earnings_calendar: 종목별 실적 발표 (예정)일
+--   - US: Nasdaq earnings calendar (확정/예정 일정)
+--   - KR: DART 잠정실적 공시 기반 추정 (전년 동기 공시일 + 364일, confirmed = FALSE)
+--   - manual: API로 직접 등록한 일정
+--
+-- strategies.earnings_filter: 전략별 실적 발표 필터 (NULL = 미적용)
+--   예: {"entry_blackout_days": 3, "exit_before_days": 1}
+--   전략 엔진이 발표 N일 이내 신규 진입 신호를 차단하고,
+--   청산 기간 안에 들어온 보유 포지션에 청산 신호를 생성합니다.
+--
+-- =====================================================
+
+CREATE TABLE IF NOT EXISTS earnings_calendar (
+    ticker VARCHAR(20) NOT NULL,
+    market VARCHAR(10) NOT NULL,                    -- KR, US
+    announce_date DATE NOT NULL,
+
+    timing VARCHAR(20) NOT NULL DEFAULT 'unknown',  -- before_open, after_close, unknown
+    confirmed BOOLEAN NOT NULL DEFAULT TRUE,        -- FALSE = 추정 일정
+    source VARCHAR(20) NOT NULL,                    -- nasdaq, dart, manual
+    fetched_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    PRIMARY KEY (ticker, market, announce_date)
+);
+
+CREATE INDEX IF NOT EXISTS idx_earnings_calendar_date
+    ON earnings_calendar(announce_date);
+
+CREATE INDEX IF NOT EXISTS idx_earnings_calendar_source_date
+    ON earnings_calendar(source, announce_date);
+
+COMMENT ON TABLE earnings_calendar IS '종목별 실적 발표 (예정)일';
+COMMENT ON COLUMN earnings_calendar.confirmed IS '확정 일정 여부 (FALSE = 전년 동기 발표일 기반 추정)';
+
+ALTER TABLE strategies
+    ADD COLUMN IF NOT EXISTS earnings_filter JSONB;
+
+COMMENT ON COLUMN strategies.earnings_filter IS '실적 발표 필터 (발표 전 진입 차단 일수, 자동 청산 일수), NULL = 미적용';
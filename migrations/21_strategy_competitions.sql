@@ -0,0 +1,79 @@
+-- =====================================================
+-- 21_strategy_competitions.sql
+-- 전략 경쟁 (모의투자 리더보드)
+-- =====================================================
+--
+-- strategy_competition:        경쟁 설정 (가상 계좌, 기간, 자동 승격)
+-- strategy_competition_entry:  참가 전략 (전략 타입 + 설정)
+-- strategy_competition_equity: 참가자별 가상 계좌 평가액 시계열
+--
+-- 경쟁 러너는 진행 중인 경쟁의 참가자를 전략 엔진에 모의 인스턴스로 연결하고,
+-- 주기적으로 평가액을 기록합니다. 리더보드는 기록된 평가액으로 구간별
+-- 위험 조정 수익률(샤프 비율)을 계산하므로 서버 재시작 후에도 유지됩니다.
+-- auto_promote가 켜진 경쟁은 종료 시 1위 참가자를 실거래 전략으로 등록합니다.
+--
+-- =====================================================
+
+CREATE TABLE IF NOT EXISTS strategy_competition (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+
+    name VARCHAR(100) NOT NULL UNIQUE,
+    description TEXT,
+
+    -- 가상 계좌
+    initial_capital DECIMAL(20, 2) NOT NULL,
+    position_size_pct DECIMAL(10, 4) NOT NULL DEFAULT 0.2, -- 진입 1회당 평가액 대비 비율
+    fee_bps DECIMAL(10, 4) NOT NULL DEFAULT 5,
+
+    -- 기간 및 상태
+    status VARCHAR(20) NOT NULL DEFAULT 'running',  -- running, finished
+    started_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    ends_at TIMESTAMPTZ,                            -- NULL = 수동 종료
+    finished_at TIMESTAMPTZ,
+
+    -- 종료 시 1위 자동 승격
+    auto_promote BOOLEAN NOT NULL DEFAULT FALSE,
+    promotion_horizon_days INTEGER,                 -- 승격 판정 구간 (NULL = 전체 기간)
+
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_strategy_competition_running
+    ON strategy_competition(ends_at) WHERE status = 'running';
+
+CREATE TRIGGER update_strategy_competition_updated_at BEFORE UPDATE ON strategy_competition
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
+
+CREATE TABLE IF NOT EXISTS strategy_competition_entry (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+
+    competition_id UUID NOT NULL REFERENCES strategy_competition(id) ON DELETE CASCADE,
+    name VARCHAR(100) NOT NULL,
+    strategy_type VARCHAR(50) NOT NULL,
+    config JSONB NOT NULL DEFAULT '{}',
+
+    promoted_strategy_id VARCHAR(100),              -- 승격된 실거래 전략 ID
+    promoted_at TIMESTAMPTZ,
+
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    UNIQUE (competition_id, name)
+);
+
+CREATE TABLE IF NOT EXISTS strategy_competition_equity (
+    entry_id UUID NOT NULL REFERENCES strategy_competition_entry(id) ON DELETE CASCADE,
+    recorded_at TIMESTAMPTZ NOT NULL,
+
+    equity DECIMAL(30, 8) NOT NULL,
+    cash DECIMAL(30, 8) NOT NULL,
+    open_positions INTEGER NOT NULL DEFAULT 0,
+    trades BIGINT NOT NULL DEFAULT 0,
+
+    PRIMARY KEY (entry_id, recorded_at)
+);
+
+COMMENT ON TABLE strategy_competition IS '전략 경쟁 (같은 실시간 데이터로 모의 운용하는 전략 리그)';
+COMMENT ON COLUMN strategy_competition.auto_promote IS '종료 시 리더보드 1위를 실거래 전략으로 등록';
+COMMENT ON TABLE strategy_competition_entry IS '전략 경쟁 참가 전략';
+COMMENT ON TABLE strategy_competition_equity IS '전략 경쟁 참가자별 가상 계좌 평가액 시계열';
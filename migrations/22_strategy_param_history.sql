@@ -0,0 +1,38 @@
+-- =====================================================
+-- 22_strategy_param_history.sql
+-- 전략 파라미터 변경 이력
+-- =====================================================
+--
+-- strategy_param_change: 전략 설정/리스크/심볼 등 파라미터 변경 기록
+--
+-- 전략 변경 API가 변경 전후 파라미터 스냅샷과 변경된 키, 변경자를 기록합니다.
+-- 이력 조회 API는 변경 시점을 실현 손익 시계열에 마커로 표시하고,
+-- 변경 전후 구간의 성과를 비교해 파라미터 조정 효과를 보여줍니다.
+--
+-- =====================================================
+
+CREATE TABLE IF NOT EXISTS strategy_param_change (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+
+    strategy_id VARCHAR(100) NOT NULL REFERENCES strategies(id) ON DELETE CASCADE,
+
+    -- 변경 유형 (config, risk, cost_model, extended_hours, earnings_filter, symbols, timeframes)
+    change_type VARCHAR(30) NOT NULL,
+    changed_by VARCHAR(100) NOT NULL,
+
+    -- 변경 전후 파라미터 스냅샷 및 변경 내역 ([{key, old, new}])
+    old_params JSONB NOT NULL,
+    new_params JSONB NOT NULL,
+    changes JSONB NOT NULL DEFAULT '[]',
+
+    -- 변경 시점 전략 실행 여부
+    strategy_running BOOLEAN NOT NULL DEFAULT FALSE,
+
+    changed_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_strategy_param_change_strategy
+    ON strategy_param_change (strategy_id, changed_at DESC);
+
+COMMENT ON TABLE strategy_param_change IS '전략 파라미터 변경 이력 (변경자, 변경 전후 값)';
+COMMENT ON COLUMN strategy_param_change.changes IS '변경된 키 목록 [{key, old, new}] (config는 config.<키> 단위)';
@@ -0,0 +1,44 @@
+-- =====================================================
+-- 23_custom_indices.sql
+-- 사용자 정의 지수 (가중 바스켓)
+-- =====================================================
+--
+-- custom_index: 지수 정의 (구성종목, 비중 결정 방식, 기준값)
+--
+-- 수집기(또는 재계산 API)가 구성종목 일봉으로 합성 일봉을 계산해
+-- `IDX_<코드>` 티커로 ohlcv 테이블에 저장하고, symbol_info에
+-- symbol_type = 'INDEX'로 등록합니다. 덕분에 지수를 일반 종목처럼
+-- 전략 심볼, 시장 레짐 입력(지표 동기화), 벤치마크로 사용할 수 있습니다.
+-- OHLCV 수집 대상(symbol_type STOCK/ETF)에는 포함되지 않습니다.
+--
+-- =====================================================
+
+CREATE TABLE IF NOT EXISTS custom_index (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+
+    code VARCHAR(16) NOT NULL UNIQUE,               -- 지수 코드 (티커: IDX_<코드>)
+    name VARCHAR(200) NOT NULL,
+    market VARCHAR(20) NOT NULL DEFAULT 'KR',       -- 일봉 날짜 기준 시장
+
+    -- 비중 결정 방식 (static, equal, inverse_volatility, market_cap)
+    weighting VARCHAR(30) NOT NULL DEFAULT 'equal',
+    base_value DECIMAL(20, 4) NOT NULL DEFAULT 1000,
+
+    -- 구성종목 ([{ticker, weight}], weight는 static 방식에서만 사용)
+    constituents JSONB NOT NULL DEFAULT '[]',
+
+    enabled BOOLEAN NOT NULL DEFAULT TRUE,
+
+    -- 마지막 계산 결과
+    last_trade_date DATE,
+    last_close DECIMAL(30, 6),
+    computed_at TIMESTAMPTZ,
+    last_error TEXT,
+
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+COMMENT ON TABLE custom_index IS '사용자 정의 지수 (가중 바스켓, 합성 일봉은 ohlcv에 IDX_<코드>로 저장)';
+COMMENT ON COLUMN custom_index.weighting IS '비중 결정 방식: static(고정), equal(동일), inverse_volatility(역변동성), market_cap(시가총액)';
+COMMENT ON COLUMN custom_index.constituents IS '구성종목 [{ticker, weight}]';
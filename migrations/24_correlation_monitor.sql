@@ -0,0 +1,83 @@
+-- =====================================================
+-- 24_correlation_monitor.sql
+-- 시장 간 상관관계 모니터링
+-- =====================================================
+--
+-- correlation_monitor_asset: 모니터링 대상 자산 (보유 종목은 자동 포함)
+-- correlation_snapshot: 자산 쌍별 단기/장기 롤링 상관계수 이력 (일자 기준 upsert)
+-- correlation_alert: 상관관계 레짐 변화(붕괴/급등) 알림 이력
+--
+-- 기본 대상(KOSPI, S&P 500, USD/KRW, BTC)은 symbol_info에 INDEX/FX/CRYPTO로
+-- 등록하여 Yahoo Finance 일봉을 캐시 경유로 조회할 수 있게 합니다.
+-- OHLCV 수집 대상(symbol_type STOCK/ETF)에는 포함되지 않습니다.
+--
+-- =====================================================
+
+CREATE TABLE IF NOT EXISTS correlation_monitor_asset (
+    ticker VARCHAR(20) PRIMARY KEY,                 -- symbol_info.ticker
+    label VARCHAR(100) NOT NULL,                    -- 표시 이름
+    enabled BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+COMMENT ON TABLE correlation_monitor_asset IS '상관관계 모니터링 대상 자산 (보유 종목은 자동 포함)';
+
+CREATE TABLE IF NOT EXISTS correlation_snapshot (
+    as_of_date DATE NOT NULL,                       -- 마지막 공통 관측일
+    symbol_a VARCHAR(20) NOT NULL,                  -- 쌍의 첫 번째 티커 (사전순)
+    symbol_b VARCHAR(20) NOT NULL,
+
+    short_window INTEGER NOT NULL,
+    long_window INTEGER NOT NULL,
+    short_corr DOUBLE PRECISION NOT NULL,
+    long_corr DOUBLE PRECISION NOT NULL,
+    observations INTEGER NOT NULL,                  -- 공통 수익률 관측 수
+
+    computed_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    PRIMARY KEY (symbol_a, symbol_b, as_of_date)
+);
+
+CREATE INDEX IF NOT EXISTS idx_correlation_snapshot_date
+    ON correlation_snapshot(as_of_date DESC);
+
+COMMENT ON TABLE correlation_snapshot IS '자산 쌍별 단기/장기 롤링 상관계수 이력';
+
+CREATE TABLE IF NOT EXISTS correlation_alert (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+
+    as_of_date DATE NOT NULL,
+    symbol_a VARCHAR(20) NOT NULL,
+    symbol_b VARCHAR(20) NOT NULL,
+    kind VARCHAR(20) NOT NULL,                      -- breakdown, spike
+    short_corr DOUBLE PRECISION NOT NULL,
+    long_corr DOUBLE PRECISION NOT NULL,
+    change DOUBLE PRECISION NOT NULL,               -- 단기 - 장기
+
+    detected_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    -- 같은 관측일에 같은 쌍/유형은 한 번만 알림
+    CONSTRAINT unique_correlation_alert UNIQUE (symbol_a, symbol_b, kind, as_of_date)
+);
+
+CREATE INDEX IF NOT EXISTS idx_correlation_alert_detected
+    ON correlation_alert(detected_at DESC);
+
+COMMENT ON TABLE correlation_alert IS '상관관계 레짐 변화 알림 (breakdown: 붕괴, spike: 급등)';
+
+-- 기본 모니터링 자산
+INSERT INTO symbol_info (ticker, name, market, exchange, yahoo_symbol, symbol_type, is_active)
+VALUES
+    ('KOSPI', 'KOSPI 지수', 'KR', 'INDEX', '^KS11', 'INDEX', true),
+    ('SPX', 'S&P 500 지수', 'US', 'INDEX', '^GSPC', 'INDEX', true),
+    ('USDKRW', 'USD/KRW 환율', 'FX', 'FX', 'KRW=X', 'FX', true),
+    ('BTC-USD', '비트코인 (USD)', 'CRYPTO', 'CRYPTO', 'BTC-USD', 'CRYPTO', true)
+ON CONFLICT (ticker, market) DO NOTHING;
+
+INSERT INTO correlation_monitor_asset (ticker, label)
+VALUES
+    ('KOSPI', 'KOSPI'),
+    ('SPX', 'S&P 500'),
+    ('USDKRW', 'USD/KRW'),
+    ('BTC-USD', 'BTC')
+ON CONFLICT (ticker) DO NOTHING;
@@ -0,0 +1,65 @@
+-- =====================================================
+-- 25_hedge_overlay.sql
+-- 포트폴리오 베타 헤지 오버레이
+-- =====================================================
+--
+-- hedge_overlay_config: 헤지 설정 (단일 행, 활성화/헤지 상품/목표 베타/밴드)
+-- hedge_rebalance: 일별 헤지 조정 이력 (순 베타, 헤지 수량, 주문 결과)
+--
+-- 헤지 수량과 손익은 hedge_rebalance의 성공 주문으로 집계하여
+-- 같은 상품을 보유한 전략 포지션과 분리합니다.
+--
+-- =====================================================
+
+CREATE TABLE IF NOT EXISTS hedge_overlay_config (
+    id SMALLINT PRIMARY KEY DEFAULT 1 CHECK (id = 1),   -- 단일 행
+
+    enabled BOOLEAN NOT NULL DEFAULT FALSE,
+    benchmark_ticker VARCHAR(20) NOT NULL DEFAULT '069500',  -- 베타 기준 지수/ETF
+    hedge_ticker VARCHAR(20) NOT NULL DEFAULT '114800',      -- 헤지 상품
+    hedge_beta DECIMAL(10, 4) NOT NULL DEFAULT -1,          -- 인버스 1배: -1, 2배: -2, 선물: 1
+    target_beta DECIMAL(10, 4) NOT NULL DEFAULT 0.3,        -- 목표 순 베타
+    band DECIMAL(10, 4) NOT NULL DEFAULT 0.1,               -- 허용 밴드 (목표 ± 밴드)
+    max_hedge_ratio DECIMAL(10, 4) NOT NULL DEFAULT 1,      -- 평가 자산 대비 최대 헤지 비율
+    beta_lookback INTEGER NOT NULL DEFAULT 60,              -- 베타 계산 일수
+
+    last_rebalanced_on DATE,                                -- 마지막 조정 확인일
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+COMMENT ON TABLE hedge_overlay_config IS '포트폴리오 베타 헤지 설정 (단일 행)';
+
+INSERT INTO hedge_overlay_config (id) VALUES (1) ON CONFLICT (id) DO NOTHING;
+
+CREATE TABLE IF NOT EXISTS hedge_rebalance (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+
+    as_of_date DATE NOT NULL,
+    hedge_ticker VARCHAR(20) NOT NULL,
+    hedge_price DECIMAL(30, 15) NOT NULL,
+    equity DECIMAL(30, 15) NOT NULL,                        -- 평가 자산
+    equity_beta_exposure DECIMAL(30, 15) NOT NULL,          -- Σ 평가금액 × 베타 (헤지 제외)
+    net_beta_before DECIMAL(20, 10) NOT NULL,
+    net_beta_after DECIMAL(20, 10) NOT NULL,
+    quantity_before DECIMAL(30, 15) NOT NULL,               -- 매도 헤지는 음수
+    target_quantity DECIMAL(30, 15) NOT NULL,
+    order_quantity DECIMAL(30, 15) NOT NULL,                -- 양수: 매수, 음수: 매도, 0: 조정 없음
+    exposures JSONB NOT NULL DEFAULT '[]',                  -- 종목별 평가금액/베타
+    reason TEXT,
+
+    success BOOLEAN NOT NULL DEFAULT FALSE,
+    error TEXT,
+    order_id UUID,
+
+    executed_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_hedge_rebalance_executed
+    ON hedge_rebalance(executed_at DESC);
+
+COMMENT ON TABLE hedge_rebalance IS '일별 헤지 조정 이력 (헤지 손익 별도 집계)';
+
+-- 기본 헤지 상품
+INSERT INTO symbol_info (ticker, name, market, exchange, yahoo_symbol, symbol_type, is_active)
+VALUES ('114800', 'KODEX 인버스', 'KR', 'KRX', '114800.KS', 'ETF', true)
+ON CONFLICT (ticker, market) DO NOTHING;
@@ -0,0 +1,46 @@
+-- =====================================================
+-- 26_fx_conversion.sql
+-- 해외 주식 주문 전 자동 환전
+-- =====================================================
+--
+-- fx_conversion: 환전 요청/실행 이력 (환율, 스프레드, 승인 상태, 보류 주문)
+--
+-- KIS 통합증거금 계좌는 주문 시점에 원화를 USD로 환전하여 체결하므로
+-- 별도 환전 주문은 전송하지 않고, 환전 판단과 결과를 이 테이블에 기록합니다.
+-- 수동 승인 모드에서는 원 주문을 order_payload에 보관하고 승인 시 제출합니다.
+--
+-- =====================================================
+
+CREATE TABLE IF NOT EXISTS fx_conversion (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+
+    source VARCHAR(50) NOT NULL,                            -- 주문 출처 (conditional_order, dca 등)
+    ticker VARCHAR(20) NOT NULL,
+    order_payload JSONB NOT NULL,                           -- 보류/제출 주문 (OrderRequest + 가격)
+
+    required_usd DECIMAL(30, 15) NOT NULL,                  -- 주문에 필요한 USD
+    available_usd DECIMAL(30, 15) NOT NULL,                 -- 주문 가능 USD
+    usd_amount DECIMAL(30, 15) NOT NULL,                    -- 환전 USD (여유분 포함)
+    krw_amount DECIMAL(30, 15) NOT NULL,                    -- 환전 원화
+    rate DECIMAL(20, 6) NOT NULL,                           -- 증권사 적용 환율
+    reference_rate DECIMAL(20, 6),                          -- 기준 환율 (USDKRW 종가)
+    spread_bps DECIMAL(12, 2),                              -- 기준 환율 대비 스프레드
+
+    mode VARCHAR(10) NOT NULL,                              -- auto / manual
+    status VARCHAR(20) NOT NULL DEFAULT 'pending'
+        CHECK (status IN ('pending', 'executed', 'rejected', 'failed')),
+    reason TEXT,                                            -- 거절/실패 사유
+    order_id UUID,                                          -- 제출된 주문 ID
+
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    decided_at TIMESTAMPTZ                                  -- 실행/거절 시각
+);
+
+CREATE INDEX IF NOT EXISTS idx_fx_conversion_created
+    ON fx_conversion(created_at DESC);
+
+CREATE INDEX IF NOT EXISTS idx_fx_conversion_pending
+    ON fx_conversion(created_at)
+    WHERE status = 'pending';
+
+COMMENT ON TABLE fx_conversion IS '해외 주식 주문 전 환전 이력 (수동 승인 대기 포함)';
@@ -0,0 +1,18 @@
+-- =====================================================
+-- 27_strategy_symbol_lock.sql
+-- 전략별 종목 잠금 (전략 간 종목 소유권)
+-- =====================================================
+--
+-- strategies.symbol_lock: 종목 잠금 설정 (NULL = 미적용)
+--   예: {"policy": "exclusive", "max_symbols": 5}
+--   policy: exclusive (단독 보유), shared_long_only (공동 보유, 매수만), netting (반대 방향 허용)
+--
+-- 전략 등록 시 엔진에 전달되며, 진입 신호를 낸 전략이 종목을 점유하고
+-- 포지션이 청산되면 해제합니다. 다른 전략의 점유와 충돌하는 진입 신호는 차단됩니다.
+--
+-- =====================================================
+
+ALTER TABLE strategies
+    ADD COLUMN IF NOT EXISTS symbol_lock JSONB;
+
+COMMENT ON COLUMN strategies.symbol_lock IS '종목 잠금 (충돌 정책, 최대 보유 종목 수), NULL = 미적용';
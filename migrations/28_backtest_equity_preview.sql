@@ -0,0 +1,19 @@
+-- =====================================================
+-- 28_backtest_equity_preview.sql
+-- 백테스트 자산 곡선 미리보기
+-- =====================================================
+--
+-- backtest_results.equity_curve_preview: LTTB로 최대 500점까지 줄인 자산 곡선
+-- backtest_results.equity_curve_points: 전체 해상도 자산 곡선 점 수
+--
+-- 목록 조회와 차트 로드는 미리보기를 사용하고, 전체 해상도(equity_curve)는
+-- 요청할 때만 읽습니다. 기존 결과는 미리보기가 NULL이며 전체 곡선으로 대체됩니다.
+--
+-- =====================================================
+
+ALTER TABLE backtest_results
+    ADD COLUMN IF NOT EXISTS equity_curve_preview JSONB,
+    ADD COLUMN IF NOT EXISTS equity_curve_points INTEGER;
+
+COMMENT ON COLUMN backtest_results.equity_curve_preview IS '차트용 자산 곡선 미리보기 (LTTB 다운샘플링, 최대 500점), NULL = 전체 곡선 사용';
+COMMENT ON COLUMN backtest_results.equity_curve_points IS '전체 해상도 자산 곡선 점 수';
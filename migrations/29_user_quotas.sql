@@ -0,0 +1,48 @@
+-- =====================================================
+-- 29_user_quotas.sql
+-- 사용자별 리소스 할당량
+-- =====================================================
+--
+-- user_quotas: 사용자별 등급과 개별 한도 (NULL = 등급 기본값 사용)
+-- strategies.owner_id / backtest_results.owner_id: 리소스를 만든 사용자 (JWT sub)
+--
+-- 등급 기본값 (동시 백테스트 / 등록 전략 / 분당 요청 / 저장 용량):
+--   free: 1 / 3 / 120 / 100MB
+--   standard: 2 / 10 / 600 / 1GB (QUOTA_DEFAULT_TIER 미설정 시 기본 등급)
+--   pro: 5 / 50 / 1200 / 10GB
+--   unlimited: 제한 없음
+--
+-- 행이 없는 사용자는 기본 등급이 적용됩니다. owner_id가 NULL인 기존 리소스와
+-- 스케줄러가 만든 결과는 어느 사용자의 사용량에도 포함되지 않습니다.
+--
+-- =====================================================
+
+CREATE TABLE IF NOT EXISTS user_quotas (
+    user_id VARCHAR(100) PRIMARY KEY,                       -- JWT sub
+
+    tier VARCHAR(20) NOT NULL DEFAULT 'standard'
+        CHECK (tier IN ('free', 'standard', 'pro', 'unlimited')),
+    max_concurrent_backtests INTEGER,                       -- 동시 실행 백테스트 수
+    max_strategies INTEGER,                                 -- 등록 전략 수
+    requests_per_minute INTEGER,                            -- API 분당 요청 수
+    storage_mb BIGINT,                                      -- 백테스트 결과 저장 용량 (MB)
+
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+ALTER TABLE strategies
+    ADD COLUMN IF NOT EXISTS owner_id VARCHAR(100);
+
+ALTER TABLE backtest_results
+    ADD COLUMN IF NOT EXISTS owner_id VARCHAR(100);
+
+CREATE INDEX IF NOT EXISTS idx_strategies_owner
+    ON strategies(owner_id) WHERE owner_id IS NOT NULL;
+
+CREATE INDEX IF NOT EXISTS idx_backtest_results_owner
+    ON backtest_results(owner_id) WHERE owner_id IS NOT NULL;
+
+COMMENT ON TABLE user_quotas IS '사용자별 리소스 할당량 (등급 + 개별 한도)';
+COMMENT ON COLUMN strategies.owner_id IS '전략을 등록한 사용자 (JWT sub), NULL = 할당량 미집계';
+COMMENT ON COLUMN backtest_results.owner_id IS '결과를 저장한 사용자 (JWT sub), NULL = 할당량 미집계';
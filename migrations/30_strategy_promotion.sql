@@ -0,0 +1,42 @@
+-- =====================================================
+-- 30_strategy_promotion.sql
+-- 백테스트 → 라이브 전략 승격
+-- =====================================================
+--
+-- backtest_results.parameters: 백테스트에 사용된 전략 파라미터
+-- strategy_promotion: 라이브 전략과 원본 백테스트 결과의 연결
+--
+-- 승격 API는 저장된 백테스트 결과의 데이터 커버리지(기간 내 일봉 수)와
+-- 리스크 준수(최대 낙폭, 거래 수, 리스크 설정 유효성)를 검사한 뒤
+-- 같은 파라미터로 라이브 전략을 등록(중지 상태)하고, 검사 결과와
+-- 백테스트 성과 지표를 함께 기록해 이후 실거래 성과와 비교합니다.
+--
+-- =====================================================
+
+ALTER TABLE backtest_results
+    ADD COLUMN IF NOT EXISTS parameters JSONB;
+
+COMMENT ON COLUMN backtest_results.parameters IS '백테스트에 사용된 전략 파라미터 (NULL이면 원본 전략 설정 사용)';
+
+CREATE TABLE IF NOT EXISTS strategy_promotion (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+
+    -- 승격으로 생성된 라이브 전략 (전략당 원본 백테스트는 하나)
+    strategy_id VARCHAR(100) NOT NULL UNIQUE REFERENCES strategies(id) ON DELETE CASCADE,
+    backtest_result_id UUID NOT NULL REFERENCES backtest_results(id),
+
+    -- 승격 시점 스냅샷
+    parameters JSONB NOT NULL,                      -- 라이브 전략에 적용된 파라미터
+    parameter_source VARCHAR(20) NOT NULL,          -- backtest, strategy, default
+    backtest_metrics JSONB NOT NULL,                -- 원본 백테스트 성과 지표
+    checks JSONB NOT NULL DEFAULT '[]',             -- 승격 검사 결과 [{name, passed, blocking, detail}]
+
+    promoted_by VARCHAR(100) NOT NULL,
+    promoted_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_strategy_promotion_backtest
+    ON strategy_promotion (backtest_result_id);
+
+COMMENT ON TABLE strategy_promotion IS '백테스트 결과에서 승격된 라이브 전략 연결 (성과 비교용)';
+COMMENT ON COLUMN strategy_promotion.checks IS '승격 검사 결과 (데이터 커버리지, 리스크 준수)';
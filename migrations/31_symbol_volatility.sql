@@ -0,0 +1,83 @@
+-- =====================================================
+-- 31_symbol_volatility.sql
+-- 종목별 실현 변동성 / 시장 베타
+-- =====================================================
+--
+-- symbol_fundamental.realized_vol_*: 일봉 종가 기준 20/60/252일 실현 변동성 (연율화, %)
+-- symbol_fundamental.beta: 벤치마크 대비 베타 (최근 252거래일)
+--
+-- 수집기(sync-volatility)가 매일 한 번 계산해 저장하며,
+-- 스크리닝 필터, 변동성 타깃 사이징, 베타 헤지가 같은 값을 사용합니다.
+-- =====================================================
+
+ALTER TABLE symbol_fundamental
+    ADD COLUMN IF NOT EXISTS realized_vol_20d DOUBLE PRECISION,
+    ADD COLUMN IF NOT EXISTS realized_vol_60d DOUBLE PRECISION,
+    ADD COLUMN IF NOT EXISTS realized_vol_252d DOUBLE PRECISION,
+    ADD COLUMN IF NOT EXISTS beta DOUBLE PRECISION,
+    ADD COLUMN IF NOT EXISTS beta_benchmark VARCHAR(20),
+    ADD COLUMN IF NOT EXISTS volatility_updated_at TIMESTAMPTZ;
+
+CREATE INDEX IF NOT EXISTS idx_symbol_fundamental_realized_vol_60d
+    ON symbol_fundamental(realized_vol_60d)
+    WHERE realized_vol_60d IS NOT NULL;
+
+COMMENT ON COLUMN symbol_fundamental.realized_vol_20d IS '20일 실현 변동성 (연율화, %)';
+COMMENT ON COLUMN symbol_fundamental.realized_vol_60d IS '60일 실현 변동성 (연율화, %)';
+COMMENT ON COLUMN symbol_fundamental.realized_vol_252d IS '252일 실현 변동성 (연율화, %)';
+COMMENT ON COLUMN symbol_fundamental.beta IS '벤치마크 대비 베타 (최근 252거래일 일간 수익률)';
+COMMENT ON COLUMN symbol_fundamental.beta_benchmark IS '베타 기준 벤치마크 티커 (예: 069500, SPY)';
+COMMENT ON COLUMN symbol_fundamental.volatility_updated_at IS '실현 변동성/베타 계산 시각';
+
+-- v_symbol_with_fundamental 뷰에 변동성/베타 컬럼 추가 (기존 컬럼 순서 유지, 끝에 추가)
+CREATE OR REPLACE VIEW v_symbol_with_fundamental AS
+SELECT
+    si.id,
+    si.ticker,
+    si.name,
+    si.name_en,
+    si.market,
+    si.exchange,
+    si.sector,
+    si.yahoo_symbol,
+    si.is_active,
+    -- Fundamental 데이터
+    sf.market_cap,
+    sf.per,
+    sf.pbr,
+    sf.eps,
+    sf.bps,
+    sf.dividend_yield,
+    sf.roe,
+    sf.roa,
+    sf.operating_margin,
+    sf.debt_ratio,
+    sf.week_52_high,
+    sf.week_52_low,
+    sf.avg_volume_10d,
+    sf.revenue,
+    sf.operating_income,
+    sf.net_income,
+    sf.revenue_growth_yoy,
+    sf.earnings_growth_yoy,
+    -- 전략 관련 컬럼 (025, 026, 027)
+    sf.route_state,
+    sf.ttm_squeeze,
+    sf.ttm_squeeze_cnt,
+    sf.regime,
+    -- 메타데이터
+    sf.data_source AS fundamental_source,
+    sf.fetched_at AS fundamental_fetched_at,
+    sf.updated_at AS fundamental_updated_at,
+    -- 실현 변동성 / 베타 (31)
+    sf.realized_vol_20d,
+    sf.realized_vol_60d,
+    sf.realized_vol_252d,
+    sf.beta,
+    sf.beta_benchmark,
+    sf.volatility_updated_at
+FROM symbol_info si
+LEFT JOIN symbol_fundamental sf ON si.id = sf.symbol_info_id
+WHERE si.is_active = true;
+
+COMMENT ON VIEW v_symbol_with_fundamental IS '심볼 기본정보와 펀더멘털 통합 조회용 뷰 (route_state, ttm_squeeze, regime, 실현 변동성/베타 포함)';
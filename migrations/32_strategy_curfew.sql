@@ -0,0 +1,20 @@
+-- =====================================================
+-- 32_strategy_curfew.sql
+-- 전략별 장중 커퓨(매매 금지 구간) 오버라이드
+-- =====================================================
+--
+-- strategies.curfew_override: 실행기 기본 커퓨 구간에 대한 전략별 오버라이드
+--
+-- 기본 구간(KRX 동시호가, 장 시작 직후 5분, 미국 개장 직후 5분)은 모든 전략에
+-- 적용되며, NULL이면 기본 구간을 그대로 따릅니다.
+-- 형식: {"exempt": false, "skip_windows": ["krx_open"],
+--        "extra_windows": [{"name": "pre_close", "market": "krx",
+--                           "start": "14:50:00", "end": "15:20:00",
+--                           "action": {"type": "block"}}]}
+--
+-- =====================================================
+
+ALTER TABLE strategies
+    ADD COLUMN IF NOT EXISTS curfew_override JSONB;
+
+COMMENT ON COLUMN strategies.curfew_override IS '장중 커퓨 전략별 오버라이드 (제외, 기본 구간 건너뛰기, 추가 구간)';
@@ -0,0 +1,45 @@
+-- =====================================================
+-- 33_orderbook_metrics.sql
+-- 호가창 파생 지표 기록 (체결 타이밍 연구용)
+-- =====================================================
+--
+-- orderbook_metrics: 실시간 호가(KIS/Binance)에서 계산한 미시구조 지표
+--   - imbalance: 상위 N 레벨 호가 불균형 (-1 ~ +1, 양수 = 매수 우위)
+--   - microprice: 최우선 잔량 가중 가격
+--   - depth_weighted_spread(_bps): 상위 N 레벨 매도 VWAP - 매수 VWAP
+--
+-- ORDERBOOK_RECORD_ENABLED=true일 때만 기록되며, 심볼별 최소 간격으로 샘플링합니다.
+-- =====================================================
+
+CREATE TABLE IF NOT EXISTS orderbook_metrics (
+    ticker VARCHAR(50) NOT NULL,
+    recorded_at TIMESTAMPTZ NOT NULL,               -- 호가 스냅샷 시각
+
+    depth_levels SMALLINT NOT NULL,
+    mid_price NUMERIC(30, 15) NOT NULL,
+    microprice NUMERIC(30, 15) NOT NULL,
+    imbalance NUMERIC(12, 8) NOT NULL,
+    bid_depth NUMERIC(30, 15) NOT NULL,
+    ask_depth NUMERIC(30, 15) NOT NULL,
+    depth_weighted_spread NUMERIC(30, 15) NOT NULL,
+    depth_weighted_spread_bps NUMERIC(12, 4) NOT NULL,
+
+    PRIMARY KEY (ticker, recorded_at)
+);
+
+-- TimescaleDB Hypertable 변환 (1일 단위 청크)
+SELECT create_hypertable('orderbook_metrics', 'recorded_at',
+    chunk_time_interval => INTERVAL '1 day',
+    if_not_exists => TRUE
+);
+
+CREATE INDEX IF NOT EXISTS idx_orderbook_metrics_ticker_time
+    ON orderbook_metrics(ticker, recorded_at DESC);
+
+-- 연구용 원자료이므로 90일만 보존
+SELECT add_retention_policy('orderbook_metrics', INTERVAL '90 days', if_not_exists => TRUE);
+
+COMMENT ON TABLE orderbook_metrics IS '호가창 파생 지표 (불균형, 마이크로프라이스, 깊이 가중 스프레드) 샘플';
+COMMENT ON COLUMN orderbook_metrics.imbalance IS '상위 N 레벨 호가 불균형 (-1 ~ +1, 양수 = 매수 우위)';
+COMMENT ON COLUMN orderbook_metrics.microprice IS '최우선 잔량 가중 가격 (매도1 × 매수1 잔량 + 매수1 × 매도1 잔량) / 잔량 합';
+COMMENT ON COLUMN orderbook_metrics.depth_weighted_spread_bps IS '깊이 가중 스프레드 (중간가 대비, bp)';
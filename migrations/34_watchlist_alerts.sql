@@ -0,0 +1,61 @@
+-- =====================================================
+-- 34_watchlist_alerts.sql
+-- 관심종목 아이템별 가격/지표 알림 규칙
+-- =====================================================
+--
+-- watchlist_alert: 관심종목 아이템에 연결된 사용자별 알림 규칙
+--
+-- 알림 평가 서비스가 활성 규칙을 최신 캔들로 주기적으로 평가하고,
+-- 조건이 충족되면 알림 채널(텔레그램, 긴급 SMS)로 전송합니다.
+--   rule_type:
+--     price_above / price_below: 종가가 threshold를 상향/하향 돌파
+--     pct_move: 기준가 대비 변동률 절대값이 threshold(%) 이상 (발동 후 기준가 갱신)
+--     rsi_above / rsi_below: RSI(rsi_period)가 threshold를 상향/하향 돌파
+-- 돌파 판정은 직전 평가값(last_value)과 비교하므로 첫 평가에서는 발동하지 않습니다.
+-- 아이템이나 그룹이 삭제되면 규칙도 함께 삭제됩니다.
+--
+-- =====================================================
+
+CREATE TABLE IF NOT EXISTS watchlist_alert (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+
+    -- 관계
+    watchlist_id UUID NOT NULL REFERENCES watchlist(id) ON DELETE CASCADE,
+    item_id UUID NOT NULL REFERENCES watchlist_item(id) ON DELETE CASCADE,
+    owner_id VARCHAR(100),                          -- 규칙을 만든 사용자 (JWT sub)
+
+    -- 규칙
+    rule_type VARCHAR(20) NOT NULL
+        CHECK (rule_type IN ('price_above', 'price_below', 'pct_move', 'rsi_above', 'rsi_below')),
+    threshold DECIMAL(30, 15) NOT NULL,             -- 가격, 변동률(%), RSI 값
+    timeframe VARCHAR(10) NOT NULL DEFAULT '1d',    -- 평가 캔들 타임프레임
+    rsi_period INTEGER NOT NULL DEFAULT 14,
+    cooldown_minutes INTEGER NOT NULL DEFAULT 60,   -- 발동 후 재알림 대기
+    enabled BOOLEAN NOT NULL DEFAULT TRUE,
+
+    -- 평가 상태
+    last_value DECIMAL(30, 15),                     -- 직전 평가값 (가격 또는 RSI)
+    reference_price DECIMAL(30, 15),                -- pct_move 기준가
+    last_evaluated_at TIMESTAMPTZ,
+    last_error TEXT,
+    last_triggered_at TIMESTAMPTZ,
+    trigger_count INTEGER NOT NULL DEFAULT 0,
+
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_watchlist_alert_watchlist
+    ON watchlist_alert(watchlist_id, owner_id);
+
+CREATE INDEX IF NOT EXISTS idx_watchlist_alert_enabled
+    ON watchlist_alert(item_id) WHERE enabled = TRUE;
+
+CREATE TRIGGER update_watchlist_alert_updated_at BEFORE UPDATE ON watchlist_alert
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
+
+COMMENT ON TABLE watchlist_alert IS '관심종목 아이템별 가격/지표 알림 규칙';
+COMMENT ON COLUMN watchlist_alert.owner_id IS '규칙 소유자 (JWT sub, NULL이면 인증 없이 생성)';
+COMMENT ON COLUMN watchlist_alert.threshold IS '임계값 (가격, 변동률 %, RSI)';
+COMMENT ON COLUMN watchlist_alert.last_value IS '직전 평가값 (돌파 판정 기준)';
+COMMENT ON COLUMN watchlist_alert.reference_price IS 'pct_move 기준가 (발동 시 갱신)';
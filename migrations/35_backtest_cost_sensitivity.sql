@@ -0,0 +1,17 @@
+-- =====================================================
+-- 35_backtest_cost_sensitivity.sql
+-- 백테스트 수수료/슬리피지 민감도 분석
+-- =====================================================
+--
+-- backtest_results.sensitivity: 수수료 × 슬리피지 격자로 재실행한 민감도 분석 결과
+--
+-- 결과 조회 시 `/api/v1/backtest/results/{id}/sensitivity`가 처음 호출되면
+-- 저장된 설정으로 격자 재실행 후 결과를 기록하고, 이후에는 저장된 분석을 반환합니다.
+-- 저장된 결과 응답(백테스트 리포트)에도 함께 포함됩니다.
+--
+-- =====================================================
+
+ALTER TABLE backtest_results
+    ADD COLUMN IF NOT EXISTS sensitivity JSONB;
+
+COMMENT ON COLUMN backtest_results.sensitivity IS '수수료/슬리피지 민감도 분석 (조합별 성과, 비용 1bp당 수익률 감소, 손익분기 비용), NULL = 미실행';
@@ -0,0 +1,42 @@
+-- =====================================================
+-- 36_market_calendar.sql
+-- 시장 휴장일/단축 거래일 달력 (KRX, 미국)
+-- =====================================================
+--
+-- market_calendar: 시장별 평일 휴장일과 개장 지연/조기 폐장일
+--
+-- 달력 동기화 서비스가 KIS 휴장일 API로 올해/내년 달력을 연 단위로 조회하여
+-- source = 'kis' 행을 교체하고, HolidayChecker와 시장 상태 API가 적재된 달력을 사용합니다.
+--   kind:
+--     holiday: 휴장
+--     early_close: 조기 폐장 (close_time, 예: 미국 추수감사절 다음 날 13:00)
+--     late_open: 개장 지연 (open_time, 예: KRX 연초 첫 거래일 10:00)
+-- 수능일처럼 규칙으로 정할 수 없는 단축 거래일은 source = 'manual'로 직접 추가합니다.
+-- manual 행은 동기화가 덮어쓰지 않으며, 같은 일자의 kis 행보다 우선합니다.
+-- 주말은 저장하지 않으며 항상 휴장으로 판정합니다. 시각은 시장 현지 시각입니다.
+--
+-- =====================================================
+
+CREATE TABLE IF NOT EXISTS market_calendar (
+    market VARCHAR(10) NOT NULL CHECK (market IN ('KR', 'US')),
+    calendar_date DATE NOT NULL,
+    source VARCHAR(20) NOT NULL DEFAULT 'kis' CHECK (source IN ('kis', 'manual')),
+
+    kind VARCHAR(20) NOT NULL CHECK (kind IN ('holiday', 'early_close', 'late_open')),
+    open_time TIME,                                 -- late_open 개장 시각
+    close_time TIME,                                -- early_close 폐장 시각
+    name VARCHAR(100),                              -- 휴장 사유
+
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    PRIMARY KEY (market, calendar_date, source)
+);
+
+CREATE TRIGGER update_market_calendar_updated_at BEFORE UPDATE ON market_calendar
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
+
+COMMENT ON TABLE market_calendar IS '시장 휴장일/단축 거래일 달력 (평일만 저장)';
+COMMENT ON COLUMN market_calendar.source IS 'kis: 동기화 서비스, manual: 직접 입력 (우선)';
+COMMENT ON COLUMN market_calendar.open_time IS '변경된 개장 시각 (현지, NULL이면 정규장)';
+COMMENT ON COLUMN market_calendar.close_time IS '변경된 폐장 시각 (현지, NULL이면 정규장)';
@@ -0,0 +1,17 @@
+-- =====================================================
+-- 37_strategy_execution_governor.sql
+-- 전략별 실행 조절(execution governor) 제외
+-- =====================================================
+--
+-- strategies.execution_governor_opt_out: 실행 조절 제외 여부
+--
+-- 실행기는 실현 변동성이 급등하거나 호가 스프레드가 벌어진 종목의 진입 주문을
+-- 축소(지정가 오프셋 확대)하거나 보류합니다. 변동성 돌파처럼 급변 구간에
+-- 진입해야 하는 전략은 이 값을 TRUE로 설정해 조절에서 제외할 수 있습니다.
+--
+-- =====================================================
+
+ALTER TABLE strategies
+    ADD COLUMN IF NOT EXISTS execution_governor_opt_out BOOLEAN NOT NULL DEFAULT FALSE;
+
+COMMENT ON COLUMN strategies.execution_governor_opt_out IS '변동성 급등/스프레드 확대 시 진입 주문 조절 제외';
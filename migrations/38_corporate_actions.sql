@@ -0,0 +1,48 @@
+-- =====================================================
+-- 38_corporate_actions.sql
+-- 기업 행위 (주식 분할, 현금 배당, 종목코드 변경)
+-- =====================================================
+--
+-- corporate_actions: 종목별 기업 행위 이벤트
+--
+-- ohlcv에는 거래 당시의 원본 가격이 저장되며, OhlcvCache/CachedHistoricalDataProvider를
+-- 수정주가 모드(PriceAdjustment::Adjusted)로 사용하면 조회 시점에 이 테이블로 과거 캔들을 조정합니다.
+--   action_type:
+--     split: 주식 분할/병합 (ratio = 구주 1주당 신주 수, 예: 2 = 1:2 분할, 0.1 = 10:1 병합)
+--     dividend: 현금 배당 (amount = 1주당 배당금, 가격과 같은 통화)
+--     ticker_change: 종목코드 변경 (ticker = 이전 코드, new_ticker = 새 코드)
+-- ex_date는 권리락/배당락/변경 적용일이며, 이 날짜 이전 캔들에만 조정이 반영됩니다.
+-- Yahoo Finance 일봉은 이미 분할 조정되어 있으므로 해당 종목의 split은 등록하지 않습니다.
+--
+-- =====================================================
+
+CREATE TABLE IF NOT EXISTS corporate_actions (
+    ticker VARCHAR(20) NOT NULL,
+    ex_date DATE NOT NULL,
+    action_type VARCHAR(20) NOT NULL CHECK (action_type IN ('split', 'dividend', 'ticker_change')),
+
+    ratio NUMERIC(20, 10) CHECK (ratio > 0),        -- split 비율
+    amount NUMERIC(20, 8) CHECK (amount >= 0),      -- dividend 1주당 배당금
+    new_ticker VARCHAR(20),                         -- ticker_change 새 코드
+    source VARCHAR(20) NOT NULL DEFAULT 'manual',
+
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    PRIMARY KEY (ticker, ex_date, action_type),
+    CHECK (
+        (action_type = 'split' AND ratio IS NOT NULL)
+        OR (action_type = 'dividend' AND amount IS NOT NULL)
+        OR (action_type = 'ticker_change' AND new_ticker IS NOT NULL)
+    )
+);
+
+-- 새 코드로 이전 코드 이력 조회
+CREATE INDEX IF NOT EXISTS idx_corporate_actions_new_ticker
+    ON corporate_actions (new_ticker)
+    WHERE action_type = 'ticker_change';
+
+COMMENT ON TABLE corporate_actions IS '기업 행위 (분할/배당/종목코드 변경, 수정주가 계산용)';
+COMMENT ON COLUMN corporate_actions.ex_date IS '권리락/배당락/변경 적용일 (이전 캔들에만 조정 반영)';
+COMMENT ON COLUMN corporate_actions.ratio IS '구주 1주당 신주 수 (split)';
+COMMENT ON COLUMN corporate_actions.amount IS '1주당 현금 배당금 (dividend)';
+COMMENT ON COLUMN corporate_actions.new_ticker IS '새 종목코드 (ticker_change, ticker는 이전 코드)';
@@ -0,0 +1,40 @@
+-- =====================================================
+-- 39_symbol_underlying.sql
+-- 종목 → 기초자산 매핑 (포트폴리오 통합 보기)
+-- =====================================================
+--
+-- symbol_underlying: 같은 기초자산을 추종/대표하는 종목 묶음
+--
+-- 포트폴리오 보유 종목과 익스포저 분석은 이 매핑으로 기초자산별로 묶이며, 계좌/상장 시장별 내역을 함께 제공합니다.
+-- 매핑이 없는 종목은 티커 자체가 기초자산 키가 되므로, 여러 계좌의 같은 종목은 별도 등록 없이 통합됩니다.
+--   예: 국내 상장 S&P500 ETF(360750)와 미국 상장 SPY/VOO/IVV → SPX
+--       미국 ADR(TSM)과 대만 원주(2330.TW) → TSM
+--
+-- =====================================================
+
+CREATE TABLE IF NOT EXISTS symbol_underlying (
+    ticker VARCHAR(20) PRIMARY KEY,                 -- 대문자 티커
+    underlying VARCHAR(50) NOT NULL,                -- 기초자산 키
+    underlying_name VARCHAR(200),                   -- 표시 이름
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_symbol_underlying_underlying
+    ON symbol_underlying (underlying);
+
+-- 대표 지수 추종 ETF 기본 매핑
+INSERT INTO symbol_underlying (ticker, underlying, underlying_name) VALUES
+    ('SPY', 'SPX', 'S&P 500'),
+    ('VOO', 'SPX', 'S&P 500'),
+    ('IVV', 'SPX', 'S&P 500'),
+    ('360750', 'SPX', 'S&P 500'),       -- TIGER 미국S&P500
+    ('379800', 'SPX', 'S&P 500'),       -- KODEX 미국S&P500TR
+    ('QQQ', 'NDX', 'NASDAQ 100'),
+    ('QQQM', 'NDX', 'NASDAQ 100'),
+    ('133690', 'NDX', 'NASDAQ 100'),    -- TIGER 미국나스닥100
+    ('379810', 'NDX', 'NASDAQ 100')     -- KODEX 미국나스닥100TR
+ON CONFLICT (ticker) DO NOTHING;
+
+COMMENT ON TABLE symbol_underlying IS '종목 → 기초자산 매핑 (교차 상장/동일 지수 추종 종목 통합)';
+COMMENT ON COLUMN symbol_underlying.underlying IS '기초자산 키 (같은 키의 종목은 포트폴리오에서 한 묶음)';
@@ -0,0 +1,30 @@
+-- =====================================================
+-- 40_synthetic_instruments.sql
+-- 합성 종목 (스프레드/비율)
+-- =====================================================
+--
+-- synthetic_instrument: 여러 종목 가격의 산술식으로 정의한 합성 종목
+--
+-- `SYN_<코드>` 티커로 캔들을 요청하면 데이터 제공자가 leg 캔들을 시작 시각
+-- 기준으로 맞춰 그때그때 합성 캔들을 계산합니다. 사용자 정의 지수(23)와 달리
+-- 합성 캔들은 ohlcv에 저장하지 않습니다.
+--   예: 005930 - 1.5*000660  (헤지 비율 스프레드)
+--       KO / PEP             (비율 스프레드)
+--
+-- 합성 종목 신호는 주문 실행 시점에 leg별 주문으로 분해됩니다.
+--
+-- =====================================================
+
+CREATE TABLE IF NOT EXISTS synthetic_instrument (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+
+    code VARCHAR(16) NOT NULL UNIQUE,               -- 합성 종목 코드 (티커: SYN_<코드>)
+    name VARCHAR(200) NOT NULL,
+    expression TEXT NOT NULL,                       -- 정규화된 가격식
+
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+COMMENT ON TABLE synthetic_instrument IS '합성 종목 (스프레드/비율, 캔들은 SYN_<코드> 요청 시 leg 캔들로 계산)';
+COMMENT ON COLUMN synthetic_instrument.expression IS '가격식: 선형 결합(A - 1.5*B) 또는 비율(A / B), 연산자는 공백으로 구분';
@@ -0,0 +1,37 @@
+-- =====================================================
+-- 41_broker_downtime.sql
+-- 브로커 점검/장애 달력
+-- =====================================================
+--
+-- broker_downtime: 증권사 API의 예정된 시스템 점검과 감지된 장애 구간
+--
+-- - scheduled: 운영자가 등록한 점검 시간 (시작/종료 필수)
+-- - detected: 주문 경로의 네트워크/타임아웃 오류로 감지된 장애
+--             (진행 중이면 ends_at이 NULL, 복구 시 종료 시각 기록)
+--
+-- 점검/장애 중에는 실행기가 신규 주문을 거부하거나 대기열에 보관하고,
+-- 전략 컨텍스트에 broker_unavailable 플래그가 설정됩니다.
+--
+-- =====================================================
+
+CREATE TABLE IF NOT EXISTS broker_downtime (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+
+    broker VARCHAR(32) NOT NULL,                    -- 브로커 식별자 (예: kis)
+    source VARCHAR(16) NOT NULL
+        CHECK (source IN ('scheduled', 'detected')),
+    starts_at TIMESTAMPTZ NOT NULL,
+    ends_at TIMESTAMPTZ,                            -- 진행 중인 감지 장애는 NULL
+    reason TEXT NOT NULL DEFAULT '',
+
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CHECK (ends_at IS NULL OR ends_at >= starts_at),
+    CHECK (source = 'detected' OR ends_at IS NOT NULL)
+);
+
+CREATE INDEX IF NOT EXISTS idx_broker_downtime_broker_starts
+    ON broker_downtime (broker, starts_at DESC);
+
+COMMENT ON TABLE broker_downtime IS '브로커 점검(scheduled)/장애(detected) 구간 (점검 중 신규 주문 거부 또는 대기열 보관)';
+COMMENT ON COLUMN broker_downtime.ends_at IS '종료 시각 (진행 중인 감지 장애는 NULL)';
@@ -0,0 +1,40 @@
+-- =====================================================
+-- 42_order_intents.sql
+-- 주문 의도 (멱등성 키 기반 중복 제출 방지)
+-- =====================================================
+--
+-- order_intents: 클라이언트 주문 ID(멱등성 키)별 진행 중인 주문
+--
+-- - pending: 등록됨, 거래소 접수 확인 전
+-- - submitted: 거래소 접수 확인 (exchange_order_id 기록)
+-- - unknown: 타임아웃/연결 오류로 접수 여부를 알 수 없음 (같은 키 재주문 차단)
+-- - failed: 거래소가 명확히 거부 (같은 키로 재주문 가능)
+--
+-- 실행기는 주문을 만들기 전에 키를 선점하므로 재시도, 서버 재시작,
+-- 여러 인스턴스에서도 같은 키의 주문이 중복 생성되지 않습니다.
+--
+-- =====================================================
+
+CREATE TABLE IF NOT EXISTS order_intents (
+    idempotency_key VARCHAR(100) PRIMARY KEY,       -- 클라이언트 주문 ID (sig_/cond_/basket_/zq_)
+
+    order_id UUID NOT NULL,                         -- 내부 주문 ID
+    ticker VARCHAR(50) NOT NULL,
+    side VARCHAR(10) NOT NULL,
+    quantity DECIMAL(30, 15) NOT NULL,
+    price DECIMAL(30, 15),
+    state VARCHAR(16) NOT NULL DEFAULT 'pending'
+        CHECK (state IN ('pending', 'submitted', 'unknown', 'failed')),
+    exchange_order_id VARCHAR(100),
+    last_error TEXT,
+
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_order_intents_in_flight
+    ON order_intents (created_at)
+    WHERE state IN ('pending', 'unknown');
+
+COMMENT ON TABLE order_intents IS '멱등성 키별 주문 의도 (재시도/재시작 시 중복 주문 방지)';
+COMMENT ON COLUMN order_intents.state IS 'pending, submitted, unknown(접수 여부 불명, 재주문 차단), failed(재주문 가능)';
@@ -0,0 +1,37 @@
+-- =====================================================
+-- 43_strategy_equity_history.sql
+-- 전략별 가상 계좌 자산 곡선
+-- =====================================================
+--
+-- strategy_equity_history: 실행기의 전략별 가상 계좌 스냅샷
+--
+-- 체결은 주문의 strategy_id로 원 전략에 귀속되며, 전략마다 할당 자본,
+-- 실현/미실현 손익, 익스포저, 낙폭을 주기적으로 기록합니다.
+-- 전략 ID가 없는 수동 주문은 'manual'로 기록됩니다.
+--
+-- =====================================================
+
+CREATE TABLE IF NOT EXISTS strategy_equity_history (
+    strategy_id VARCHAR(100) NOT NULL,
+    snapshot_time TIMESTAMPTZ NOT NULL,
+
+    capital DECIMAL(30, 15) NOT NULL,               -- 할당 자본
+    equity DECIMAL(30, 15) NOT NULL,                -- 자기자본 (현금 + 포지션 평가액)
+    realized_pnl DECIMAL(30, 15) NOT NULL,          -- 누적 실현 손익 (거래 비용 차감 후)
+    unrealized_pnl DECIMAL(30, 15) NOT NULL,
+    fees DECIMAL(30, 15) NOT NULL DEFAULT 0,        -- 누적 거래 비용
+    exposure DECIMAL(30, 15) NOT NULL,              -- 총 명목 익스포저
+    drawdown_pct DOUBLE PRECISION NOT NULL DEFAULT 0,
+    open_positions INT NOT NULL DEFAULT 0,
+
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    PRIMARY KEY (strategy_id, snapshot_time)
+);
+
+CREATE INDEX IF NOT EXISTS idx_strategy_equity_history_time
+    ON strategy_equity_history (snapshot_time DESC);
+
+COMMENT ON TABLE strategy_equity_history IS '전략별 가상 계좌 자산 곡선 (자본, 손익, 익스포저, 낙폭)';
+COMMENT ON COLUMN strategy_equity_history.strategy_id IS '전략 ID (수동 주문은 manual)';
+COMMENT ON COLUMN strategy_equity_history.drawdown_pct IS '스냅샷 시점의 최고 자기자본 대비 낙폭 (%)';
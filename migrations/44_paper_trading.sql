@@ -0,0 +1,40 @@
+-- =====================================================
+-- 44_paper_trading.sql
+-- 실시간 시세 기반 모의투자 (섀도 트레이딩)
+-- =====================================================
+--
+-- strategies.paper_trading: 모의투자 모드 (true면 신호를 실거래 대신
+--   SimulatedExchange 가상 계좌에서 체결)
+-- paper_trades: 모의투자 체결 내역 (전략별 가상 계좌)
+--
+-- 가상 계좌 잔고는 메모리에만 유지되며 서버 재시작 시 초기 자본으로 다시 시작합니다.
+-- 체결 내역은 재시작 후에도 남아 실거래 전환 전 성과 검증에 사용합니다.
+--
+-- =====================================================
+
+ALTER TABLE strategies
+    ADD COLUMN IF NOT EXISTS paper_trading BOOLEAN NOT NULL DEFAULT FALSE;
+
+COMMENT ON COLUMN strategies.paper_trading IS '모의투자 모드 (실시간 시세로 운용, 주문은 가상 계좌에서 체결)';
+
+CREATE TABLE IF NOT EXISTS paper_trades (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    strategy_id VARCHAR(100) NOT NULL,
+
+    order_id VARCHAR(100) NOT NULL,                 -- 가상 계좌 주문 ID
+    signal_id UUID,                                 -- 원본 신호 ID
+    ticker VARCHAR(50) NOT NULL,
+    side VARCHAR(10) NOT NULL,
+    signal_type VARCHAR(30) NOT NULL,
+    quantity DECIMAL(30, 15) NOT NULL,
+    price DECIMAL(30, 15) NOT NULL,
+    commission DECIMAL(30, 15) NOT NULL DEFAULT 0,
+
+    executed_at TIMESTAMPTZ NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_paper_trades_strategy_time
+    ON paper_trades (strategy_id, executed_at DESC);
+
+COMMENT ON TABLE paper_trades IS '모의투자 체결 내역 (실시간 시세, 시뮬레이션 거래소 체결)';
@@ -0,0 +1,21 @@
+-- =====================================================
+-- 45_strategy_state.sql
+-- 전략 내부 상태 영속화 (서버 재시작 후 진행 상태 복원)
+-- =====================================================
+--
+-- strategy_states: 전략 인스턴스별 마지막 내부 상태 (Strategy::save_state 결과)
+--
+-- 분할 매수 차수(magic_split), 물타기 라운드(infinity_bot)처럼 메모리에만 있던
+-- 진행 상태를 상태가 바뀔 때마다 저장하고, 재시작 후 전략을 시작할 때 복원합니다.
+-- 전략 삭제 시 함께 삭제됩니다.
+--
+-- =====================================================
+
+CREATE TABLE IF NOT EXISTS strategy_states (
+    strategy_id VARCHAR(100) PRIMARY KEY REFERENCES strategies(id) ON DELETE CASCADE,
+    state BYTEA NOT NULL,                           -- 전략별 직렬화 형식 (내장 전략은 JSON)
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+COMMENT ON TABLE strategy_states IS '전략 내부 상태 (재시작 시 분할 매수/라운드 진행 상태 복원)';
+COMMENT ON COLUMN strategy_states.state IS 'Strategy::save_state() 결과 바이트';
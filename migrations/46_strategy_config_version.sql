@@ -0,0 +1,17 @@
+-- =====================================================
+-- 46_strategy_config_version.sql
+-- 전략 설정 스키마 버전
+-- =====================================================
+--
+-- strategies.config_version: 저장된 config JSON의 스키마 버전
+--
+-- 전략 설정 스키마가 바뀌면 서버 시작 시 전략을 로드하면서 저장된 버전부터
+-- 현재 버전까지 전략이 제공하는 마이그레이션 단계를 적용하고, 변환된 설정과
+-- 버전을 다시 기록합니다. 기존 행은 최초 스키마(1)로 간주합니다.
+--
+-- =====================================================
+
+ALTER TABLE strategies
+    ADD COLUMN IF NOT EXISTS config_version INT NOT NULL DEFAULT 1;
+
+COMMENT ON COLUMN strategies.config_version IS '설정(config) 스키마 버전 (로드 시 현재 버전으로 마이그레이션)';
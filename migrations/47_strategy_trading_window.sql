@@ -0,0 +1,19 @@
+-- =====================================================
+-- 47_strategy_trading_window.sql
+-- 전략별 거래 시간대
+-- =====================================================
+--
+-- strategies.trading_window: 전략이 동작하는 시장 현지 시각 구간 (NULL = 제한 없음)
+--
+-- 예: {"market": "KR", "start": "09:05:00", "end": "15:20:00",
+--      "skip_holidays": true, "flatten_outside": true}
+--
+-- 구간 밖(휴장일 포함)에서는 시장 데이터와 외부 신호를 전략에 전달하지 않으며,
+-- flatten_outside가 켜져 있으면 보유 포지션에 청산 신호를 생성합니다.
+--
+-- =====================================================
+
+ALTER TABLE strategies
+    ADD COLUMN IF NOT EXISTS trading_window JSONB;
+
+COMMENT ON COLUMN strategies.trading_window IS '거래 시간대 (기준 시장, 현지 시작/종료 시각, 휴장일 제외, 구간 밖 청산), NULL = 제한 없음';
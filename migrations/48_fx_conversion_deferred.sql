@@ -0,0 +1,19 @@
+-- =====================================================
+-- 48_fx_conversion_deferred.sql
+-- 환전 기록 상태 추가
+-- =====================================================
+--
+-- 별도 환전 주문은 전송하지 않으므로, 통합증거금 계좌에서 환전을 주문 시점에 맡기고
+-- 주문을 제출한 건은 'executed'가 아닌 'deferred'로 기록합니다.
+-- 'executed'는 환전 주문 체결을 확인한 건에만 사용합니다 (이전 기록은 그대로 유지).
+--
+-- =====================================================
+
+ALTER TABLE fx_conversion
+    DROP CONSTRAINT IF EXISTS fx_conversion_status_check;
+
+ALTER TABLE fx_conversion
+    ADD CONSTRAINT fx_conversion_status_check
+    CHECK (status IN ('pending', 'not_needed', 'deferred', 'executed', 'rejected', 'failed'));
+
+COMMENT ON COLUMN fx_conversion.status IS 'pending / not_needed / deferred (통합증거금 위임) / executed / rejected / failed';
@@ -0,0 +1,15 @@
+-- =====================================================
+-- 49_backtest_results_nullable_strategy.sql
+-- 등록 전략 없이 실행한 백테스트 결과의 전략 ID
+-- =====================================================
+--
+-- 등록 전략 없이 실행한 백테스트 결과는 strategy_id를 NULL로 저장하고
+-- strategy_type으로만 구분합니다. 전략 타입 이름을 strategy_id에 넣으면
+-- 같은 ID의 등록 전략 결과와 섞여 strategy_id 필터가 잘못된 결과를 반환합니다.
+--
+-- =====================================================
+
+ALTER TABLE backtest_results
+    ALTER COLUMN strategy_id DROP NOT NULL;
+
+COMMENT ON COLUMN backtest_results.strategy_id IS '등록 전략 ID (strategies.id), 등록 전략 없이 실행한 결과는 NULL';
@@ -0,0 +1,15 @@
+-- =====================================================
+-- 50_fx_conversion_order.sql
+-- 환전 주문번호
+-- =====================================================
+--
+-- 환전 실행 판단(자동 모드 또는 수동 승인)은 KIS 원화→USD 환전 주문을 먼저 보내고,
+-- 환전 주문이 접수된 뒤 원 주문을 제출합니다. 접수된 환전 주문번호를 기록합니다.
+--
+-- =====================================================
+
+ALTER TABLE fx_conversion
+    ADD COLUMN IF NOT EXISTS fx_order_no VARCHAR(20);
+
+COMMENT ON COLUMN fx_conversion.fx_order_no IS 'KIS 환전 주문번호 (executed 건)';
+COMMENT ON COLUMN fx_conversion.status IS 'pending / not_needed / deferred (통합증거금 위임, 50번 이전) / executed (환전 주문 접수) / rejected / failed';